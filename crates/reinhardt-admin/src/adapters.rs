@@ -27,8 +27,11 @@ pub use crate::types::{
 // re-exported as RequestExportFormat to distinguish from core::export::ExportFormat
 // which defines the full set of export formats with file I/O capabilities.
 pub use crate::types::{
-	AdminError, BulkDeleteRequest, BulkDeleteResponse, ColumnInfo, DashboardResponse,
-	DetailResponse, ExportFormat as RequestExportFormat, ExportResponse, FieldInfo, FieldType,
-	FieldsResponse, FilterChoice, FilterInfo, FilterType, ImportResponse, ListQueryParams,
-	ListResponse, LoginResponse, ModelInfo, MutationRequest, MutationResponse,
+	AdminError, ApiIndexResponse, ApiModelInfo, BulkDeleteRequest, BulkDeleteResponse,
+	BulkUpdateRequest, BulkUpdateResponse, BulkUpdateRow, ColumnInfo, DashboardResponse,
+	DashboardWidget, DetailResponse, ExportFormat as RequestExportFormat, ExportResponse,
+	FieldInfo, FieldType, FieldsResponse, FilterChoice, FilterInfo, FilterType, ImportResponse,
+	ListQueryParams, ListResponse, LoginResponse, ModelInfo, ModelPermissions, MutationRequest,
+	MutationResponse, RecentAction, RetentionModelInfo, RetentionReportResponse,
+	UserDataExportResponse, UserDataRequest,
 };
@@ -4,27 +4,40 @@
 //! including:
 //! - ModelAdmin trait and configuration
 //! - AdminSite registry
+//! - Configuration files for the admin site and dashboard
 //! - Database operations
 //! - Import/Export functionality
 
+pub mod attachments;
+pub mod config_file;
 pub mod database;
+pub mod dynamic_settings;
 pub mod export;
+pub mod generic_inline;
 pub mod import;
 pub mod model_admin;
+pub mod navigation;
+pub mod redirects;
 pub mod router;
 pub mod site;
 // Re-exports
 pub use crate::types::{
-	AdminError, AdminResult, BulkDeleteRequest, BulkDeleteResponse, ColumnInfo, DashboardResponse,
-	DetailResponse, ExportFormat as TypesExportFormat, FieldInfo, FieldType, FilterChoice,
-	FilterInfo, FilterType, ImportResponse, ListQueryParams, ListResponse, ModelInfo,
-	MutationRequest, MutationResponse,
+	AdminError, AdminResult, BulkDeleteRequest, BulkDeleteResponse, BulkUpdateRequest,
+	BulkUpdateResponse, ColumnInfo, DashboardResponse, DetailResponse,
+	ExportFormat as TypesExportFormat, FieldInfo, FieldType, FilterChoice, FilterInfo, FilterType,
+	ImportResponse, ListQueryParams, ListResponse, ModelInfo, MutationRequest, MutationResponse,
 };
+pub use attachments::{attachments_inline, register_attachments};
+pub use config_file::AdminConfigFile;
 pub use database::{AdminDatabase, AdminDatabaseKey, AdminRecord};
+pub use dynamic_settings::register_dynamic_settings;
 pub use export::{CsvExporter, ExportBuilder, ExportConfig, ExportFormat, JsonExporter};
+pub use generic_inline::GenericInline;
 pub use import::{
 	CsvImporter, ImportBuilder, ImportConfig, ImportError, ImportFormat, ImportResult, JsonImporter,
 };
 pub use model_admin::{AdminUser, ModelAdmin, ModelAdminConfig, ModelAdminConfigBuilder};
+pub use navigation::{MenuLink, NavGroup, NavigationRegistry};
+pub use redirects::register_redirects;
 pub use router::{admin_csp_exempt_paths, admin_routes_with_di, admin_static_routes};
 pub use site::{AdminSite, AdminSiteConfig, AdminSiteKey};
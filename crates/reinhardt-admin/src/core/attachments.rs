@@ -0,0 +1,100 @@
+//! Admin registration for model attachments
+//!
+//! Attachments live in the `attachments_attachment` table managed by
+//! `reinhardt_db::contenttypes::attachments`. [`register_attachments`]
+//! exposes the table in the admin, and [`attachments_inline`] lists the
+//! attachments of a record below its detail view, with a drop zone posting
+//! new files to the attachment endpoint of `reinhardt_rest::attachments`.
+
+use super::{AdminResult, AdminSite, GenericInline, ModelAdminConfig};
+use reinhardt_db::contenttypes::attachments::ATTACHMENT_TABLE;
+
+/// Name under which the attachments table is registered
+pub const ATTACHMENT_MODEL_NAME: &str = "Attachment";
+
+/// Admin configuration of the attachments table
+///
+/// The file itself cannot be changed from the admin: upload a new
+/// attachment instead.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_admin::core::ModelAdmin;
+/// use reinhardt_admin::core::attachments::attachment_admin;
+///
+/// let admin = attachment_admin();
+/// assert_eq!(admin.table_name(), "attachments_attachment");
+/// assert!(admin.search_fields().contains(&"original_name"));
+/// ```
+pub fn attachment_admin() -> ModelAdminConfig {
+	ModelAdminConfig::builder()
+		.model_name(ATTACHMENT_MODEL_NAME)
+		.table_name(ATTACHMENT_TABLE)
+		.app_label("attachments")
+		.list_display(vec![
+			"original_name",
+			"mime_type",
+			"size",
+			"content_type_id",
+			"object_id",
+			"uploaded_by",
+			"uploaded_at",
+		])
+		.list_filter(vec!["content_type_id", "mime_type"])
+		.search_fields(vec!["original_name", "object_id", "uploaded_by"])
+		.fields(vec!["original_name", "metadata"])
+		.ordering(vec!["-uploaded_at"])
+		.build()
+		.expect("attachment admin configuration is valid")
+}
+
+/// Register the attachments table with `site`
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_admin::core::AdminSite;
+/// use reinhardt_admin::core::attachments::register_attachments;
+///
+/// let site = AdminSite::new("Admin");
+/// register_attachments(&site).unwrap();
+/// assert!(site.is_registered("Attachment"));
+/// ```
+pub fn register_attachments(site: &AdminSite) -> AdminResult<()> {
+	site.register(ATTACHMENT_MODEL_NAME, attachment_admin())
+}
+
+/// Inline listing the attachments of a record
+///
+/// `upload_url` is where the attachment endpoint is mounted, e.g.
+/// `/api/attachments`.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_admin::core::ModelAdminConfig;
+/// use reinhardt_admin::core::attachments::attachments_inline;
+///
+/// let admin = ModelAdminConfig::builder()
+///     .model_name("Post")
+///     .app_label("blog")
+///     .generic_inlines(vec![attachments_inline("/api/attachments")])
+///     .build()
+///     .unwrap();
+/// ```
+pub fn attachments_inline(upload_url: impl Into<String>) -> GenericInline {
+	GenericInline::new(ATTACHMENT_MODEL_NAME)
+		.table_name(ATTACHMENT_TABLE)
+		.label("Attachments")
+		.fields(vec![
+			"id",
+			"original_name",
+			"mime_type",
+			"size",
+			"uploaded_by",
+			"uploaded_at",
+		])
+		.text_object_ids()
+		.upload_url(upload_url)
+}
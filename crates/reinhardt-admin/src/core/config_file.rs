@@ -0,0 +1,812 @@
+//! Admin configuration as code
+//!
+//! [`AdminConfigFile`] describes the parts of an [`AdminSite`] that are
+//! usually adjusted per project — site titles, list options of the registered
+//! models, sidebar navigation and the dashboard layout — in a TOML or JSON
+//! file that can be reviewed and versioned with the rest of the code.
+//!
+//! [`AdminSite::export_config`] captures the current configuration and
+//! [`AdminSite::apply_config`] applies a file on top of the models registered
+//! in code. Set `config_file` in [`AdminSettings`] to apply a file when the
+//! admin routes are built.
+//!
+//! ```toml
+//! version = 1
+//!
+//! [site]
+//! site_header = "Acme administration"
+//!
+//! [models.Post]
+//! list_display = ["id", "title", "published"]
+//! list_filter = ["published"]
+//!
+//! [[navigation.groups]]
+//! label = "Blog"
+//! models = ["Post"]
+//!
+//! [[dashboard.widgets]]
+//! id = "recent"
+//! kind = "recent_actions"
+//! ```
+//!
+//! [`AdminSettings`]: crate::settings::AdminSettings
+
+use crate::core::model_admin::AdminUser;
+use crate::core::navigation::{MenuLink, NavGroup};
+use crate::core::{AdminSite, GenericInline, ModelAdmin, ModelAdminConfig};
+use crate::types::{AdminError, AdminResult, DashboardWidget};
+use async_trait::async_trait;
+use reinhardt_db::orm::Filter;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Latest version of the configuration file format
+pub const CONFIG_FILE_VERSION: u32 = 1;
+
+fn default_version() -> u32 {
+	CONFIG_FILE_VERSION
+}
+
+/// Declarative admin configuration
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_admin::core::AdminSite;
+/// use reinhardt_admin::core::config_file::AdminConfigFile;
+///
+/// let config = AdminConfigFile::from_toml_str(
+///     r#"
+///     [models.Tag]
+///     list_display = ["id", "name"]
+///     permissions = ["view"]
+///     "#,
+/// )
+/// .unwrap();
+///
+/// let site = AdminSite::new("Admin");
+/// site.apply_config(&config).unwrap();
+/// assert_eq!(site.get_model_admin("Tag").unwrap().list_display(), vec!["id", "name"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AdminConfigFile {
+	/// Format version, see [`CONFIG_FILE_VERSION`]
+	#[serde(default = "default_version")]
+	pub version: u32,
+	/// Site titles and defaults
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub site: Option<SiteSection>,
+	/// Options of each model, by model name
+	#[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+	pub models: BTreeMap<String, ModelSection>,
+	/// Sidebar groups and custom links
+	#[serde(default, skip_serializing_if = "NavigationSection::is_empty")]
+	pub navigation: NavigationSection,
+	/// Dashboard layout; when present, replaces the widgets set in code
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub dashboard: Option<DashboardSection>,
+}
+
+impl Default for AdminConfigFile {
+	fn default() -> Self {
+		Self {
+			version: CONFIG_FILE_VERSION,
+			site: None,
+			models: BTreeMap::new(),
+			navigation: NavigationSection::default(),
+			dashboard: None,
+		}
+	}
+}
+
+/// Site-level settings; unset values keep the current configuration
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SiteSection {
+	/// Site title shown in browser tab
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub site_title: Option<String>,
+	/// Header text shown at the top of admin pages
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub site_header: Option<String>,
+	/// Index page title
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub index_title: Option<String>,
+	/// Items per page in list views
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub list_per_page: Option<usize>,
+	/// Enable search functionality
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub enable_search: Option<bool>,
+	/// Enable filtering functionality
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub enable_filters: Option<bool>,
+}
+
+/// Options of one model; unset values keep what the model admin defines
+///
+/// `table_name`, `app_label`, `pk_field` and `permissions` describe models
+/// registered by the file itself. For models registered in code they must
+/// be left unset, or match the code for the first three.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ModelSection {
+	/// Database table name
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub table_name: Option<String>,
+	/// Application label
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub app_label: Option<String>,
+	/// Primary key field name
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub pk_field: Option<String>,
+	/// Fields to display in list view
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub list_display: Option<Vec<String>>,
+	/// Fields that can be used for filtering
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub list_filter: Option<Vec<String>>,
+	/// Fields that can be searched
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub search_fields: Option<Vec<String>>,
+	/// Fields to display in forms
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub fields: Option<Vec<String>>,
+	/// Read-only fields
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub readonly_fields: Option<Vec<String>>,
+	/// Ordering for list view (prefix with "-" for descending)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub ordering: Option<Vec<String>>,
+	/// Fields editable inline in list view
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub list_editable: Option<Vec<String>>,
+	/// Number of items per page
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub list_per_page: Option<usize>,
+	/// Actions granted to every staff user: `view`, `add`, `change`, `delete`
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub permissions: Option<Vec<String>>,
+}
+
+/// Sidebar groups and custom links
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NavigationSection {
+	/// Groups, replacing any group with the same label
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub groups: Vec<NavGroup>,
+	/// Custom links, added unless an identical link exists
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub links: Vec<MenuLink>,
+}
+
+impl NavigationSection {
+	fn is_empty(&self) -> bool {
+		self.groups.is_empty() && self.links.is_empty()
+	}
+}
+
+/// Dashboard layout
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DashboardSection {
+	/// Widgets shown on the dashboard
+	#[serde(default)]
+	pub widgets: Vec<DashboardWidget>,
+}
+
+/// Serialization format of a configuration file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+	/// TOML document
+	Toml,
+	/// JSON document
+	Json,
+}
+
+impl ConfigFormat {
+	/// Format matching the extension of `path`
+	pub fn from_path(path: &Path) -> AdminResult<Self> {
+		match path.extension().and_then(|ext| ext.to_str()) {
+			Some("toml") => Ok(Self::Toml),
+			Some("json") => Ok(Self::Json),
+			_ => Err(AdminError::ValidationError(format!(
+				"Unsupported admin config file '{}': expected a .toml or .json extension",
+				path.display()
+			))),
+		}
+	}
+}
+
+impl AdminConfigFile {
+	/// Parse a TOML document
+	pub fn from_toml_str(source: &str) -> AdminResult<Self> {
+		toml::from_str(source)
+			.map_err(|e| AdminError::ValidationError(format!("Invalid admin config: {}", e)))
+	}
+
+	/// Parse a JSON document
+	pub fn from_json_str(source: &str) -> AdminResult<Self> {
+		serde_json::from_str(source)
+			.map_err(|e| AdminError::ValidationError(format!("Invalid admin config: {}", e)))
+	}
+
+	/// Render as a TOML document
+	pub fn to_toml_string(&self) -> AdminResult<String> {
+		toml::to_string_pretty(self).map_err(|e| {
+			AdminError::ValidationError(format!("Failed to serialize admin config: {}", e))
+		})
+	}
+
+	/// Render as a pretty-printed JSON document
+	pub fn to_json_string(&self) -> AdminResult<String> {
+		serde_json::to_string_pretty(self).map_err(|e| {
+			AdminError::ValidationError(format!("Failed to serialize admin config: {}", e))
+		})
+	}
+
+	/// Read a file, choosing the format from its extension
+	pub fn load(path: impl AsRef<Path>) -> AdminResult<Self> {
+		let path = path.as_ref();
+		let format = ConfigFormat::from_path(path)?;
+		let source = std::fs::read_to_string(path).map_err(|e| {
+			AdminError::ValidationError(format!(
+				"Failed to read admin config '{}': {}",
+				path.display(),
+				e
+			))
+		})?;
+		match format {
+			ConfigFormat::Toml => Self::from_toml_str(&source),
+			ConfigFormat::Json => Self::from_json_str(&source),
+		}
+	}
+
+	/// Write a file, choosing the format from its extension
+	pub fn save(&self, path: impl AsRef<Path>) -> AdminResult<()> {
+		let path = path.as_ref();
+		let contents = match ConfigFormat::from_path(path)? {
+			ConfigFormat::Toml => self.to_toml_string()?,
+			ConfigFormat::Json => self.to_json_string()?,
+		};
+		std::fs::write(path, contents).map_err(|e| {
+			AdminError::ValidationError(format!(
+				"Failed to write admin config '{}': {}",
+				path.display(),
+				e
+			))
+		})
+	}
+
+	/// Capture the configuration of `site`
+	///
+	/// Permissions are not exported: they are decided by the model admins at
+	/// request time.
+	pub fn capture(site: &AdminSite) -> Self {
+		let config = site.config();
+		let models = site
+			.registered_models()
+			.into_iter()
+			.filter_map(|name| {
+				let admin = site.get_model_admin(&name).ok()?;
+				Some((name, ModelSection::capture(admin.as_ref())))
+			})
+			.collect();
+		let navigation = site.navigation();
+		let widgets = site.dashboard_widgets();
+
+		Self {
+			version: CONFIG_FILE_VERSION,
+			site: Some(SiteSection {
+				site_title: Some(config.site_title),
+				site_header: Some(config.site_header),
+				index_title: Some(config.index_title),
+				list_per_page: Some(config.list_per_page),
+				enable_search: Some(config.enable_search),
+				enable_filters: Some(config.enable_filters),
+			}),
+			models,
+			navigation: NavigationSection {
+				groups: navigation.groups().to_vec(),
+				links: navigation.links().to_vec(),
+			},
+			dashboard: (!widgets.is_empty()).then_some(DashboardSection { widgets }),
+		}
+	}
+
+	/// Apply the configuration to `site`
+	///
+	/// The whole file is validated before `site` is changed, so an invalid
+	/// file leaves the site untouched.
+	///
+	/// # Errors
+	///
+	/// Returns [`AdminError::ValidationError`] if the file has a newer
+	/// version, a model section conflicts with the admin registered in code,
+	/// `list_editable` names a field missing from `list_display`, or two
+	/// dashboard widgets share an ID.
+	pub fn apply_to(&self, site: &AdminSite) -> AdminResult<()> {
+		if self.version > CONFIG_FILE_VERSION {
+			return Err(AdminError::ValidationError(format!(
+				"Admin config version {} is newer than the supported version {}",
+				self.version, CONFIG_FILE_VERSION
+			)));
+		}
+
+		let mut replaced = Vec::new();
+		let mut added = Vec::new();
+		for (name, section) in &self.models {
+			match site.get_model_admin(name) {
+				Ok(inner) => replaced.push((name, section.overlay(name, inner)?)),
+				Err(_) => added.push((name, section.build(name)?)),
+			}
+		}
+		if let Some(dashboard) = &self.dashboard {
+			let mut ids = HashSet::new();
+			for widget in &dashboard.widgets {
+				if !ids.insert(widget.id.as_str()) {
+					return Err(AdminError::ValidationError(format!(
+						"Dashboard widget '{}' is defined more than once",
+						widget.id
+					)));
+				}
+				if widget.span == 0 {
+					return Err(AdminError::ValidationError(format!(
+						"Dashboard widget '{}' must span at least one column",
+						widget.id
+					)));
+				}
+			}
+		}
+
+		if let Some(section) = &self.site {
+			site.configure(|config| section.apply(config));
+		}
+		for (name, admin) in replaced {
+			site.replace_model_admin(name, Arc::new(admin))?;
+		}
+		for (name, admin) in added {
+			site.register(name.clone(), admin)?;
+		}
+		for group in &self.navigation.groups {
+			site.add_nav_group(group.clone());
+		}
+		let existing = site.navigation();
+		for link in &self.navigation.links {
+			if !existing.links().contains(link) {
+				site.add_menu_link(link.clone());
+			}
+		}
+		if let Some(dashboard) = &self.dashboard {
+			site.set_dashboard_widgets(dashboard.widgets.clone());
+		}
+		Ok(())
+	}
+}
+
+impl SiteSection {
+	fn apply(&self, config: &mut crate::core::AdminSiteConfig) {
+		if let Some(site_title) = &self.site_title {
+			config.site_title = site_title.clone();
+		}
+		if let Some(site_header) = &self.site_header {
+			config.site_header = site_header.clone();
+		}
+		if let Some(index_title) = &self.index_title {
+			config.index_title = index_title.clone();
+		}
+		if let Some(list_per_page) = self.list_per_page {
+			config.list_per_page = list_per_page;
+		}
+		if let Some(enable_search) = self.enable_search {
+			config.enable_search = enable_search;
+		}
+		if let Some(enable_filters) = self.enable_filters {
+			config.enable_filters = enable_filters;
+		}
+	}
+}
+
+fn owned(fields: Vec<&str>) -> Vec<String> {
+	fields.into_iter().map(str::to_string).collect()
+}
+
+impl ModelSection {
+	fn capture(admin: &dyn ModelAdmin) -> Self {
+		Self {
+			table_name: None,
+			app_label: None,
+			pk_field: None,
+			list_display: Some(owned(admin.list_display())),
+			list_filter: Some(owned(admin.list_filter())),
+			search_fields: Some(owned(admin.search_fields())),
+			fields: admin.fields().map(owned),
+			readonly_fields: Some(owned(admin.readonly_fields())),
+			ordering: Some(owned(admin.ordering())),
+			list_editable: Some(owned(admin.list_editable())),
+			list_per_page: admin.list_per_page(),
+			permissions: None,
+		}
+	}
+
+	/// Wrap the admin registered in code, overriding the configured options
+	fn overlay(&self, name: &str, inner: Arc<dyn ModelAdmin>) -> AdminResult<ConfiguredModelAdmin> {
+		let conflicts = [
+			("table_name", &self.table_name, inner.table_name()),
+			("app_label", &self.app_label, inner.app_label()),
+			("pk_field", &self.pk_field, inner.pk_field()),
+		];
+		for (option, configured, actual) in conflicts {
+			if let Some(configured) = configured
+				&& configured != actual
+			{
+				return Err(AdminError::ValidationError(format!(
+					"{} of model '{}' is '{}' in code and cannot be changed to '{}'",
+					option, name, actual, configured
+				)));
+			}
+		}
+		if self.permissions.is_some() {
+			return Err(AdminError::ValidationError(format!(
+				"permissions of model '{}' are defined in code and cannot be configured",
+				name
+			)));
+		}
+
+		let admin = ConfiguredModelAdmin {
+			inner,
+			options: self.clone(),
+		};
+		validate_list_editable(name, &admin.list_display(), &admin.list_editable())?;
+		Ok(admin)
+	}
+
+	/// Build the admin of a model registered by the file
+	fn build(&self, name: &str) -> AdminResult<ModelAdminConfig> {
+		let mut builder = ModelAdminConfig::builder().model_name(name);
+		if let Some(table_name) = &self.table_name {
+			builder = builder.table_name(table_name);
+		}
+		if let Some(app_label) = &self.app_label {
+			builder = builder.app_label(app_label);
+		}
+		if let Some(pk_field) = &self.pk_field {
+			builder = builder.pk_field(pk_field);
+		}
+		if let Some(fields) = &self.list_display {
+			builder = builder.list_display(fields.clone());
+		}
+		if let Some(fields) = &self.list_filter {
+			builder = builder.list_filter(fields.clone());
+		}
+		if let Some(fields) = &self.search_fields {
+			builder = builder.search_fields(fields.clone());
+		}
+		if let Some(fields) = &self.fields {
+			builder = builder.fields(fields.clone());
+		}
+		if let Some(fields) = &self.readonly_fields {
+			builder = builder.readonly_fields(fields.clone());
+		}
+		if let Some(fields) = &self.ordering {
+			builder = builder.ordering(fields.clone());
+		}
+		if let Some(fields) = &self.list_editable {
+			builder = builder.list_editable(fields.clone());
+		}
+		if let Some(count) = self.list_per_page {
+			builder = builder.list_per_page(count);
+		}
+		for permission in self.permissions.iter().flatten() {
+			builder = match permission.as_str() {
+				"view" => builder.allow_view(true),
+				"add" => builder.allow_add(true),
+				"change" => builder.allow_change(true),
+				"delete" => builder.allow_delete(true),
+				other => {
+					return Err(AdminError::ValidationError(format!(
+						"Unknown permission '{}' for model '{}': expected view, add, change or delete",
+						other, name
+					)));
+				}
+			};
+		}
+		builder.build()
+	}
+}
+
+fn validate_list_editable(
+	name: &str,
+	list_display: &[&str],
+	list_editable: &[&str],
+) -> AdminResult<()> {
+	match list_editable.iter().find(|f| !list_display.contains(f)) {
+		Some(field) => Err(AdminError::ValidationError(format!(
+			"list_editable field '{}' of model '{}' must also be in list_display",
+			field, name
+		))),
+		None => Ok(()),
+	}
+}
+
+fn configured<'a>(options: &'a Option<Vec<String>>, default: Vec<&'a str>) -> Vec<&'a str> {
+	match options {
+		Some(fields) => fields.iter().map(String::as_str).collect(),
+		None => default,
+	}
+}
+
+/// Model admin registered in code with options overridden by a config file
+struct ConfiguredModelAdmin {
+	inner: Arc<dyn ModelAdmin>,
+	options: ModelSection,
+}
+
+#[async_trait]
+impl ModelAdmin for ConfiguredModelAdmin {
+	fn model_name(&self) -> &str {
+		self.inner.model_name()
+	}
+
+	fn table_name(&self) -> &str {
+		self.inner.table_name()
+	}
+
+	fn pk_field(&self) -> &str {
+		self.inner.pk_field()
+	}
+
+	fn app_label(&self) -> &str {
+		self.inner.app_label()
+	}
+
+	fn queryset_filters(&self) -> Vec<Filter> {
+		self.inner.queryset_filters()
+	}
+
+	fn list_display(&self) -> Vec<&str> {
+		configured(&self.options.list_display, self.inner.list_display())
+	}
+
+	fn list_filter(&self) -> Vec<&str> {
+		configured(&self.options.list_filter, self.inner.list_filter())
+	}
+
+	fn search_fields(&self) -> Vec<&str> {
+		configured(&self.options.search_fields, self.inner.search_fields())
+	}
+
+	fn fields(&self) -> Option<Vec<&str>> {
+		match &self.options.fields {
+			Some(fields) => Some(fields.iter().map(String::as_str).collect()),
+			None => self.inner.fields(),
+		}
+	}
+
+	fn readonly_fields(&self) -> Vec<&str> {
+		configured(&self.options.readonly_fields, self.inner.readonly_fields())
+	}
+
+	fn ordering(&self) -> Vec<&str> {
+		configured(&self.options.ordering, self.inner.ordering())
+	}
+
+	fn natural_ordering_fields(&self) -> Vec<&str> {
+		self.inner.natural_ordering_fields()
+	}
+
+	fn list_editable(&self) -> Vec<&str> {
+		configured(&self.options.list_editable, self.inner.list_editable())
+	}
+
+	fn rich_text_fields(&self) -> Vec<&str> {
+		self.inner.rich_text_fields()
+	}
+
+	fn list_per_page(&self) -> Option<usize> {
+		self.options
+			.list_per_page
+			.or_else(|| self.inner.list_per_page())
+	}
+
+	fn generic_inlines(&self) -> Vec<GenericInline> {
+		self.inner.generic_inlines()
+	}
+
+	fn field_choices(&self, field: &str) -> Option<Vec<(String, String)>> {
+		self.inner.field_choices(field)
+	}
+
+	fn field_translations(&self, field: &str) -> Vec<(String, String)> {
+		self.inner.field_translations(field)
+	}
+
+	async fn has_view_permission(&self, user: &dyn AdminUser) -> bool {
+		self.inner.has_view_permission(user).await
+	}
+
+	async fn has_add_permission(&self, user: &dyn AdminUser) -> bool {
+		self.inner.has_add_permission(user).await
+	}
+
+	async fn has_change_permission(&self, user: &dyn AdminUser) -> bool {
+		self.inner.has_change_permission(user).await
+	}
+
+	async fn has_delete_permission(&self, user: &dyn AdminUser) -> bool {
+		self.inner.has_delete_permission(user).await
+	}
+}
+
+#[cfg(all(test, server))]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn site_with_post() -> AdminSite {
+		let site = AdminSite::new("Admin");
+		let post = ModelAdminConfig::builder()
+			.model_name("Post")
+			.table_name("blog_post")
+			.list_display(vec!["id", "title"])
+			.allow_all(true)
+			.build()
+			.unwrap();
+		site.register("Post", post).unwrap();
+		site
+	}
+
+	#[rstest]
+	fn test_apply_overrides_options_of_registered_model() {
+		// Arrange
+		let site = site_with_post();
+		let config = AdminConfigFile::from_toml_str(
+			r#"
+			[models.Post]
+			list_display = ["id", "title", "published"]
+			list_filter = ["published"]
+			list_editable = ["published"]
+			"#,
+		)
+		.unwrap();
+
+		// Act
+		site.apply_config(&config).unwrap();
+
+		// Assert
+		let admin = site.get_model_admin("Post").unwrap();
+		assert_eq!(admin.list_display(), vec!["id", "title", "published"]);
+		assert_eq!(admin.list_filter(), vec!["published"]);
+		assert_eq!(admin.list_editable(), vec!["published"]);
+		assert_eq!(admin.table_name(), "blog_post");
+		assert_eq!(admin.ordering(), vec!["-id"]);
+	}
+
+	#[rstest]
+	#[case(
+		r#"[models.Post]
+		table_name = "posts""#
+	)]
+	#[case(
+		r#"[models.Post]
+		permissions = ["delete"]"#
+	)]
+	#[case(
+		r#"[models.Post]
+		list_editable = ["published"]"#
+	)]
+	#[case(
+		r#"[[dashboard.widgets]]
+		id = "a"
+		kind = "recent_actions"
+		[[dashboard.widgets]]
+		id = "a"
+		kind = "model_counts""#
+	)]
+	fn test_invalid_config_leaves_site_untouched(#[case] source: &str) {
+		// Arrange
+		let site = site_with_post();
+		let config = AdminConfigFile::from_toml_str(&format!(
+			"[site]\nsite_header = \"Changed\"\n{}",
+			source
+		))
+		.unwrap();
+
+		// Act
+		let result = site.apply_config(&config);
+
+		// Assert
+		assert!(matches!(result, Err(AdminError::ValidationError(_))));
+		assert_eq!(site.config().site_header, "Administration");
+		assert!(site.dashboard_widgets().is_empty());
+	}
+
+	#[rstest]
+	fn test_apply_registers_new_model_with_permissions() {
+		// Arrange
+		let site = AdminSite::new("Admin");
+		let config = AdminConfigFile::from_json_str(
+			r#"{"models": {"Tag": {"table_name": "blog_tag", "permissions": ["view"]}}}"#,
+		)
+		.unwrap();
+
+		// Act
+		site.apply_config(&config).unwrap();
+
+		// Assert
+		let admin = site.get_model_admin("Tag").unwrap();
+		assert_eq!(admin.table_name(), "blog_tag");
+	}
+
+	#[rstest]
+	fn test_unknown_keys_are_rejected() {
+		// Arrange
+		let source = "[models.Post]\nlist_dispaly = [\"id\"]";
+
+		// Act
+		let result = AdminConfigFile::from_toml_str(source);
+
+		// Assert
+		assert!(matches!(result, Err(AdminError::ValidationError(_))));
+	}
+
+	#[rstest]
+	#[case("admin.toml")]
+	#[case("admin.json")]
+	fn test_exported_config_round_trips(#[case] file_name: &str) {
+		// Arrange
+		let site = site_with_post();
+		site.add_nav_group(NavGroup::new("Blog").icon("book").models(["Post"]));
+		site.add_menu_link(MenuLink::new("Reports", "/admin/reports/").superuser_only());
+		let mut widget = DashboardWidget::new("recent", "recent_actions");
+		widget.title = Some("Recent actions".to_string());
+		widget.options.insert("limit".to_string(), 5.into());
+		site.add_dashboard_widget(widget);
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join(file_name);
+
+		// Act
+		site.export_config().save(&path).unwrap();
+		let loaded = AdminConfigFile::load(&path).unwrap();
+		let restored = site_with_post();
+		restored.apply_config(&loaded).unwrap();
+
+		// Assert
+		assert_eq!(loaded, site.export_config());
+		assert_eq!(restored.export_config(), site.export_config());
+	}
+
+	#[rstest]
+	fn test_reapplying_does_not_duplicate_links() {
+		// Arrange
+		let site = AdminSite::new("Admin");
+		let config = AdminConfigFile::from_toml_str(
+			r#"
+			[[navigation.links]]
+			label = "Reports"
+			url = "/admin/reports/"
+			"#,
+		)
+		.unwrap();
+
+		// Act
+		site.apply_config(&config).unwrap();
+		site.apply_config(&config).unwrap();
+
+		// Assert
+		assert_eq!(site.navigation().links().len(), 1);
+	}
+
+	#[rstest]
+	fn test_unsupported_extension_is_rejected() {
+		// Act
+		let result = AdminConfigFile::load("admin.yaml");
+
+		// Assert
+		assert!(matches!(result, Err(AdminError::ValidationError(_))));
+	}
+}
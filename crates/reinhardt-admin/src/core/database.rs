@@ -9,11 +9,11 @@ use reinhardt_core::macros::injectable;
 use reinhardt_db::migrations::FieldType as DbFieldType;
 use reinhardt_db::orm::execution::convert_values;
 use reinhardt_db::orm::{
-	DatabaseConnection, Filter, FilterCondition, FilterOperator, FilterValue, Model,
+	DatabaseConnection, Filter, FilterCondition, FilterOperator, FilterValue, Model, OrderBy,
 };
 use reinhardt_di::{DiResult, FactoryOutput, Injectable, InjectionContext};
 use reinhardt_query::prelude::{
-	Alias, BinOper, CaseStatement, ColumnRef, Condition, Expr, ExprTrait, IntoValue, Order,
+	Alias, BinOper, CaseStatement, ColumnRef, Condition, Expr, ExprTrait, IntoValue,
 	PostgresQueryBuilder, Query, QueryStatementBuilder, SimpleExpr, Value,
 };
 use serde::{Deserialize, Serialize};
@@ -27,6 +27,9 @@ const SENSITIVE_FIELDS: &[&str] = &["password_hash", "password_salt"];
 /// String values are inspected for ISO 8601 date/time patterns and converted
 /// to the appropriate chrono type so that PostgreSQL accepts them for
 /// `timestamptz`, `date`, and `time` columns without an explicit cast.
+///
+/// Datetimes without an offset come from `datetime-local` inputs and are
+/// interpreted in the current time zone of the request.
 fn json_to_sea_value(value: serde_json::Value) -> Value {
 	match value {
 		serde_json::Value::String(s) => {
@@ -38,6 +41,9 @@ fn json_to_sea_value(value: serde_json::Value) -> Value {
 				chrono::NaiveDateTime::parse_from_str(&s, "%Y-%m-%dT%H:%M:%S%.fZ")
 			{
 				Value::ChronoDateTimeUtc(Some(Box::new(dt.and_utc())))
+			// Naive datetime (e.g. "2026-04-02T16:45" from a datetime-local input)
+			} else if let Some(dt) = parse_local_datetime(&s) {
+				dt
 			// Date only
 			} else if s.len() == 10 {
 				if let Ok(d) = chrono::NaiveDate::parse_from_str(&s, "%Y-%m-%d") {
@@ -77,6 +83,25 @@ fn json_to_sea_value(value: serde_json::Value) -> Value {
 		_ => Value::String(Some(Box::new(value.to_string()))),
 	}
 }
+
+/// Parses a datetime without offset as a wall-clock time in the current time zone.
+fn parse_local_datetime(s: &str) -> Option<Value> {
+	use reinhardt_utils::utils_core::timezone;
+
+	let naive = ["%Y-%m-%dT%H:%M", "%Y-%m-%dT%H:%M:%S%.f"]
+		.iter()
+		.find_map(|format| chrono::NaiveDateTime::parse_from_str(s, format).ok())?;
+	// Without time zone support the wall-clock time is stored as is
+	let utc = if timezone::use_tz() {
+		timezone::make_aware(naive, timezone::get_current_timezone())
+			.ok()?
+			.with_timezone(&chrono::Utc)
+	} else {
+		naive.and_utc()
+	};
+	Some(Value::ChronoDateTimeUtc(Some(Box::new(utc))))
+}
+
 use std::sync::Arc;
 
 /// Dummy record type for admin panel CRUD operations
@@ -813,7 +838,7 @@ impl AdminDatabase {
 	/// * `table_name` - The name of the table to query
 	/// * `filter_condition` - Optional composite filter condition (AND/OR logic)
 	/// * `additional_filters` - Additional simple filters to AND with the condition
-	/// * `sort_by` - Optional sort field (prefix with "-" for descending, e.g., "created_at" or "-created_at")
+	/// * `sort_by` - Optional sort term, e.g. `OrderBy::field("-created_at")` or `OrderBy::natural("name")`
 	/// * `offset` - Number of items to skip for pagination
 	/// * `limit` - Maximum number of items to return
 	pub async fn list_with_condition<M: Model>(
@@ -821,7 +846,7 @@ impl AdminDatabase {
 		table_name: &str,
 		filter_condition: Option<&FilterCondition>,
 		additional_filters: Vec<Filter>,
-		sort_by: Option<&str>,
+		sort_by: Option<&OrderBy>,
 		offset: u64,
 		limit: u64,
 	) -> AdminResult<Vec<HashMap<String, serde_json::Value>>> {
@@ -842,19 +867,9 @@ impl AdminDatabase {
 		}
 
 		// Apply sorting (if specified)
-		if let Some(sort_str) = sort_by {
-			let (field, is_desc) = if let Some(stripped) = sort_str.strip_prefix('-') {
-				(stripped, true)
-			} else {
-				(sort_str, false)
-			};
-
-			let col = Alias::new(field);
-			if is_desc {
-				query.order_by(col, Order::Desc);
-			} else {
-				query.order_by(col, Order::Asc);
-			}
+		if let Some(sort) = sort_by {
+			let (expr, order) = sort.to_query_order();
+			query.order_by_expr(expr, order);
 		}
 
 		// Apply pagination
@@ -896,7 +911,7 @@ impl AdminDatabase {
 		table_name: &str,
 		filter_condition: Option<&FilterCondition>,
 		additional_filters: Vec<Filter>,
-		sort_by: Option<&str>,
+		sort_by: Option<&OrderBy>,
 		offset: u64,
 		limit: u64,
 	) -> AdminResult<(Vec<HashMap<String, serde_json::Value>>, u64)> {
@@ -919,19 +934,9 @@ impl AdminDatabase {
 			query.cond_where(combined);
 		}
 
-		if let Some(sort_str) = sort_by {
-			let (field, is_desc) = if let Some(stripped) = sort_str.strip_prefix('-') {
-				(stripped, true)
-			} else {
-				(sort_str, false)
-			};
-
-			let col = Alias::new(field);
-			if is_desc {
-				query.order_by(col, Order::Desc);
-			} else {
-				query.order_by(col, Order::Asc);
-			}
+		if let Some(sort) = sort_by {
+			let (expr, order) = sort.to_query_order();
+			query.order_by_expr(expr, order);
 		}
 
 		query.limit(limit).offset(offset);
@@ -1106,6 +1111,60 @@ impl AdminDatabase {
 		pk_field: Option<&str>,
 		data: HashMap<String, serde_json::Value>,
 	) -> AdminResult<u64> {
+		let pk_field = pk_field.unwrap_or("id");
+		let pk = self
+			.create_returning_pk::<M>(table_name, Some(pk_field), data)
+			.await?;
+
+		// Extract the ID from the returned primary key value
+		match pk {
+			serde_json::Value::Number(n) => n.as_u64().ok_or_else(|| {
+				AdminError::DatabaseError(format!(
+					"RETURNING clause for '{}' returned non-unsigned-integer: {}",
+					pk_field, n
+				))
+			}),
+			serde_json::Value::String(_) => {
+				// UUID and other string-based PKs: return 1 as affected count
+				// (the actual PK value is a string, not representable as u64)
+				Ok(1)
+			}
+			_ => Err(AdminError::DatabaseError(format!(
+				"RETURNING clause did not return expected primary key field '{}'",
+				pk_field
+			))),
+		}
+	}
+
+	/// Create a new item and return the primary key assigned by the database
+	///
+	/// Unlike [`create`](Self::create), string primary keys such as UUIDs are
+	/// returned as-is.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_admin::core::{AdminDatabase, AdminRecord};
+	/// use reinhardt_db::orm::DatabaseConnection;
+	/// use std::collections::HashMap;
+	///
+	/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+	/// let conn = DatabaseConnection::connect("postgres://localhost/test").await?;
+	/// let db = AdminDatabase::new(conn);
+	///
+	/// let mut data = HashMap::new();
+	/// data.insert("name".to_string(), serde_json::json!("Alice"));
+	///
+	/// let pk = db.create_returning_pk::<AdminRecord>("admin_records", Some("id"), data).await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub async fn create_returning_pk<M: Model>(
+		&self,
+		table_name: &str,
+		pk_field: Option<&str>,
+		data: HashMap<String, serde_json::Value>,
+	) -> AdminResult<serde_json::Value> {
 		let pk_field = pk_field.unwrap_or("id");
 		let mut query = Query::insert()
 			.into_table(Alias::new(table_name))
@@ -1145,24 +1204,12 @@ impl AdminDatabase {
 			.await
 			.map_err(|e| AdminError::DatabaseError(e.to_string()))?;
 
-		// Extract the ID from the returned row using the primary key field
-		match row.data.get(pk_field) {
-			Some(serde_json::Value::Number(n)) => n.as_u64().ok_or_else(|| {
-				AdminError::DatabaseError(format!(
-					"RETURNING clause for '{}' returned non-unsigned-integer: {}",
-					pk_field, n
-				))
-			}),
-			Some(serde_json::Value::String(_)) => {
-				// UUID and other string-based PKs: return 1 as affected count
-				// (the actual PK value is a string, not representable as u64)
-				Ok(1)
-			}
-			_ => Err(AdminError::DatabaseError(format!(
+		row.data.get(pk_field).cloned().ok_or_else(|| {
+			AdminError::DatabaseError(format!(
 				"RETURNING clause did not return expected primary key field '{}'",
 				pk_field
-			))),
-		}
+			))
+		})
 	}
 
 	/// Update an existing item
@@ -2303,6 +2350,8 @@ mod tests {
 			field: None,
 			alias: None,
 			distinct: false,
+			filter: None,
+			table: None,
 		};
 
 		// Act
@@ -2330,6 +2379,8 @@ mod tests {
 			field: Some("price".to_string()),
 			alias: None,
 			distinct: false,
+			filter: None,
+			table: None,
 		};
 
 		// Act
@@ -2362,6 +2413,8 @@ mod tests {
 			field: Some("category".to_string()),
 			alias: None,
 			distinct: false, // AggregateFunc::CountDistinct implies DISTINCT
+			filter: None,
+			table: None,
 		};
 
 		// Act
@@ -2394,6 +2447,8 @@ mod tests {
 			field: Some("price); DROP TABLE users; --".to_string()),
 			alias: None,
 			distinct: false,
+			filter: None,
+			table: None,
 		};
 
 		// Act
@@ -0,0 +1,276 @@
+//! Admin registration for the dynamic settings table
+//!
+//! Dynamic settings stored with `reinhardt_conf`'s `DatabaseBackend` live in
+//! the `settings` table, one JSON-encoded value per key.
+//! [`register_dynamic_settings`] exposes that table in the admin so that
+//! operators can change values without a deployment; values are edited as
+//! JSON (`true`, `50`, `"text"`) and checked against the declared
+//! [`SettingDefinition`](reinhardt_conf::settings::dynamic::SettingDefinition)
+//! before they are saved. Processes reading through a cached
+//! `DynamicSettings` see the change once their cache TTL expires, so the
+//! table cannot be registered for settings cached without a TTL.
+
+use super::{AdminError, AdminResult, AdminSite, AdminUser, ModelAdmin, ModelAdminConfig};
+use async_trait::async_trait;
+use reinhardt_conf::settings::dynamic::DynamicSettings;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Table used by the database backend of dynamic settings
+pub const DYNAMIC_SETTINGS_TABLE: &str = "settings";
+
+/// Name under which the dynamic settings table is registered
+pub const DYNAMIC_SETTING_MODEL_NAME: &str = "DynamicSetting";
+
+/// Admin of the dynamic settings table
+///
+/// Rejects values that are not JSON, or that do not match the type a
+/// setting was declared with in `settings`.
+pub struct DynamicSettingsAdmin {
+	config: ModelAdminConfig,
+	settings: Arc<DynamicSettings>,
+}
+
+impl DynamicSettingsAdmin {
+	/// Check the edited `value` of the setting `key`
+	fn validate_setting(&self, key: &str, value: &serde_json::Value) -> AdminResult<()> {
+		let value = match value {
+			serde_json::Value::String(text) => serde_json::from_str(text).map_err(|_| {
+				AdminError::ValidationError(format!(
+					"Value of setting '{}' must be JSON, e.g. true, 50 or \"text\"",
+					key
+				))
+			})?,
+			value => value.clone(),
+		};
+		match self.settings.definition(key) {
+			Some(definition) if !definition.accepts(&value) => {
+				Err(AdminError::ValidationError(format!(
+					"Value of setting '{}' must be a {}",
+					key, definition.type_name
+				)))
+			}
+			_ => Ok(()),
+		}
+	}
+}
+
+/// Admin of the dynamic settings table, validating values against `settings`
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_admin::core::ModelAdmin;
+/// use reinhardt_admin::core::dynamic_settings::dynamic_settings_admin;
+/// use reinhardt_conf::settings::backends::MemoryBackend;
+/// use reinhardt_conf::settings::dynamic::DynamicSettings;
+/// use std::sync::Arc;
+///
+/// let settings = Arc::new(DynamicSettings::new(Arc::new(MemoryBackend::new())));
+/// let admin = dynamic_settings_admin(settings);
+/// assert_eq!(admin.table_name(), "settings");
+/// assert_eq!(admin.pk_field(), "key");
+/// ```
+pub fn dynamic_settings_admin(settings: Arc<DynamicSettings>) -> DynamicSettingsAdmin {
+	let config = ModelAdminConfig::builder()
+		.model_name(DYNAMIC_SETTING_MODEL_NAME)
+		.table_name(DYNAMIC_SETTINGS_TABLE)
+		.app_label("dynamic_settings")
+		.pk_field("key")
+		.list_display(vec!["key", "value", "expire_date"])
+		.search_fields(vec!["key"])
+		.fields(vec!["key", "value", "expire_date"])
+		.ordering(vec!["key"])
+		.list_editable(vec!["value"])
+		.build()
+		.expect("dynamic settings admin configuration is valid");
+	DynamicSettingsAdmin { config, settings }
+}
+
+/// Register the dynamic settings table with `site`
+///
+/// # Errors
+///
+/// Returns `AdminError::ValidationError` if `settings` caches values
+/// without a TTL, since edits made in the admin would never be read.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_admin::core::AdminSite;
+/// use reinhardt_admin::core::dynamic_settings::register_dynamic_settings;
+/// use reinhardt_conf::settings::backends::MemoryBackend;
+/// use reinhardt_conf::settings::dynamic::DynamicSettings;
+/// use std::sync::Arc;
+///
+/// let site = AdminSite::new("Admin");
+/// let settings = Arc::new(DynamicSettings::new(Arc::new(MemoryBackend::new())));
+/// register_dynamic_settings(&site, settings).unwrap();
+/// assert!(site.is_registered("DynamicSetting"));
+/// ```
+pub fn register_dynamic_settings(
+	site: &AdminSite,
+	settings: Arc<DynamicSettings>,
+) -> AdminResult<()> {
+	if settings.cache_never_expires() {
+		return Err(AdminError::ValidationError(
+			"Dynamic settings edited in the admin need a cache TTL; pass one to enable_cache"
+				.to_string(),
+		));
+	}
+	site.register(DYNAMIC_SETTING_MODEL_NAME, dynamic_settings_admin(settings))
+}
+
+#[async_trait]
+impl ModelAdmin for DynamicSettingsAdmin {
+	fn model_name(&self) -> &str {
+		self.config.model_name()
+	}
+
+	fn table_name(&self) -> &str {
+		self.config.table_name()
+	}
+
+	fn pk_field(&self) -> &str {
+		self.config.pk_field()
+	}
+
+	fn app_label(&self) -> &str {
+		self.config.app_label()
+	}
+
+	fn list_display(&self) -> Vec<&str> {
+		self.config.list_display()
+	}
+
+	fn search_fields(&self) -> Vec<&str> {
+		self.config.search_fields()
+	}
+
+	fn fields(&self) -> Option<Vec<&str>> {
+		self.config.fields()
+	}
+
+	fn ordering(&self) -> Vec<&str> {
+		self.config.ordering()
+	}
+
+	fn list_editable(&self) -> Vec<&str> {
+		self.config.list_editable()
+	}
+
+	fn validate_values(
+		&self,
+		pk: Option<&str>,
+		data: &HashMap<String, serde_json::Value>,
+	) -> AdminResult<()> {
+		let Some(value) = data.get("value") else {
+			return Ok(());
+		};
+		let key = data
+			.get(self.pk_field())
+			.and_then(serde_json::Value::as_str)
+			.or(pk)
+			.ok_or_else(|| AdminError::ValidationError("Setting key is required".to_string()))?;
+		self.validate_setting(key, value)
+	}
+
+	async fn has_view_permission(&self, user: &dyn AdminUser) -> bool {
+		self.config.has_view_permission(user).await
+	}
+
+	async fn has_add_permission(&self, user: &dyn AdminUser) -> bool {
+		self.config.has_add_permission(user).await
+	}
+
+	async fn has_change_permission(&self, user: &dyn AdminUser) -> bool {
+		self.config.has_change_permission(user).await
+	}
+
+	async fn has_delete_permission(&self, user: &dyn AdminUser) -> bool {
+		self.config.has_delete_permission(user).await
+	}
+}
+
+#[cfg(all(test, server))]
+mod tests {
+	use super::*;
+	use reinhardt_conf::settings::backends::MemoryBackend;
+	use rstest::rstest;
+	use serde_json::json;
+
+	fn admin() -> DynamicSettingsAdmin {
+		let settings = DynamicSettings::new(Arc::new(MemoryBackend::new()));
+		settings
+			.declare("shop.max_cart_items", &50u32, "Maximum items per cart")
+			.unwrap();
+		dynamic_settings_admin(Arc::new(settings))
+	}
+
+	#[rstest]
+	#[case::declared_type(Some("shop.max_cart_items"), json!({ "value": "75" }))]
+	#[case::undeclared_key(Some("shop.banner"), json!({ "value": "\"Sale\"" }))]
+	#[case::create(None, json!({ "key": "shop.max_cart_items", "value": "10" }))]
+	#[case::value_untouched(Some("shop.max_cart_items"), json!({ "expire_date": null }))]
+	fn test_validate_values_accepts(#[case] pk: Option<&str>, #[case] data: serde_json::Value) {
+		// Arrange
+		let admin = admin();
+		let data: HashMap<String, serde_json::Value> = serde_json::from_value(data).unwrap();
+
+		// Act
+		let result = admin.validate_values(pk, &data);
+
+		// Assert
+		assert!(result.is_ok());
+	}
+
+	#[rstest]
+	#[case::wrong_type(
+		Some("shop.max_cart_items"),
+		json!({ "value": "\"many\"" }),
+		"Validation error: Value of setting 'shop.max_cart_items' must be a u32"
+	)]
+	#[case::not_json(
+		Some("shop.banner"),
+		json!({ "value": "Sale" }),
+		"Validation error: Value of setting 'shop.banner' must be JSON, e.g. true, 50 or \"text\""
+	)]
+	#[case::create_wrong_type(
+		None,
+		json!({ "key": "shop.max_cart_items", "value": "-1" }),
+		"Validation error: Value of setting 'shop.max_cart_items' must be a u32"
+	)]
+	fn test_validate_values_rejects(
+		#[case] pk: Option<&str>,
+		#[case] data: serde_json::Value,
+		#[case] message: &str,
+	) {
+		// Arrange
+		let admin = admin();
+		let data: HashMap<String, serde_json::Value> = serde_json::from_value(data).unwrap();
+
+		// Act
+		let error = admin.validate_values(pk, &data).unwrap_err();
+
+		// Assert
+		assert_eq!(error.to_string(), message);
+	}
+
+	#[rstest]
+	fn test_register_rejects_cache_without_ttl() {
+		// Arrange
+		let site = AdminSite::new("Admin");
+		let mut settings = DynamicSettings::new(Arc::new(MemoryBackend::new()));
+		settings.enable_cache(10, None);
+
+		// Act
+		let result = register_dynamic_settings(&site, Arc::new(settings));
+
+		// Assert
+		assert_eq!(
+			result.unwrap_err().to_string(),
+			"Validation error: Dynamic settings edited in the admin need a cache TTL; pass one to enable_cache"
+		);
+		assert!(!site.is_registered(DYNAMIC_SETTING_MODEL_NAME));
+	}
+}
@@ -0,0 +1,325 @@
+//! Generic inlines
+//!
+//! Shows the children attached to a record through a generic foreign key
+//! (comments, attachments, tags, ...) below its detail view. Children are
+//! matched by the content type of the parent model, looked up in
+//! `django_content_type`, and by the parent's primary key.
+//!
+//! # Examples
+//!
+//! ```
+//! use reinhardt_admin::core::{GenericInline, ModelAdminConfig};
+//!
+//! let admin = ModelAdminConfig::builder()
+//!     .model_name("Post")
+//!     .app_label("blog")
+//!     .generic_inlines(vec![
+//!         GenericInline::new("Comment")
+//!             .table_name("comments")
+//!             .fields(vec!["id", "author", "body"])
+//!             .max_num(20),
+//!     ])
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use crate::core::{AdminDatabase, AdminRecord, ModelAdmin};
+use crate::types::{AdminResult, GenericInlineRows};
+use reinhardt_db::orm::{Filter, FilterOperator, FilterValue};
+
+/// Table storing persisted content types
+const CONTENT_TYPE_TABLE: &str = "django_content_type";
+
+/// Default number of children shown per inline
+const DEFAULT_MAX_NUM: usize = 50;
+
+/// Children of a model attached through a generic foreign key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenericInline {
+	model_name: String,
+	table_name: Option<String>,
+	label: Option<String>,
+	ct_field: String,
+	fk_field: String,
+	fields: Vec<String>,
+	max_num: usize,
+	text_object_ids: bool,
+	upload_url: Option<String>,
+}
+
+impl GenericInline {
+	/// Creates an inline for the child model with the given admin name.
+	///
+	/// The generic foreign key columns default to `content_type_id` and
+	/// `object_id`.
+	pub fn new(model_name: impl Into<String>) -> Self {
+		Self {
+			model_name: model_name.into(),
+			table_name: None,
+			label: None,
+			ct_field: "content_type_id".to_string(),
+			fk_field: "object_id".to_string(),
+			fields: Vec::new(),
+			max_num: DEFAULT_MAX_NUM,
+			text_object_ids: false,
+			upload_url: None,
+		}
+	}
+
+	/// Sets the child table; defaults to the model name.
+	pub fn table_name(mut self, table_name: impl Into<String>) -> Self {
+		self.table_name = Some(table_name.into());
+		self
+	}
+
+	/// Sets the heading shown above the inline; defaults to the model name.
+	pub fn label(mut self, label: impl Into<String>) -> Self {
+		self.label = Some(label.into());
+		self
+	}
+
+	/// Sets the content type column of the child table.
+	pub fn ct_field(mut self, field: impl Into<String>) -> Self {
+		self.ct_field = field.into();
+		self
+	}
+
+	/// Sets the object id column of the child table.
+	pub fn fk_field(mut self, field: impl Into<String>) -> Self {
+		self.fk_field = field.into();
+		self
+	}
+
+	/// Sets the columns shown for each child; all columns when empty.
+	pub fn fields(mut self, fields: Vec<impl Into<String>>) -> Self {
+		self.fields = fields.into_iter().map(Into::into).collect();
+		self
+	}
+
+	/// Sets the maximum number of children shown.
+	pub fn max_num(mut self, max_num: usize) -> Self {
+		self.max_num = max_num;
+		self
+	}
+
+	/// Compares the object id column as text.
+	///
+	/// Needed when the column is a string, e.g. to hold UUID keys, on
+	/// databases that do not compare strings with integers.
+	pub fn text_object_ids(mut self) -> Self {
+		self.text_object_ids = true;
+		self
+	}
+
+	/// Enables drag-and-drop uploads to the attachment endpoint at `base_url`.
+	///
+	/// Files dropped on the inline are posted to
+	/// `{base_url}/{app_label}/{model}/{object_id}`.
+	pub fn upload_url(mut self, base_url: impl Into<String>) -> Self {
+		self.upload_url = Some(base_url.into().trim_end_matches('/').to_string());
+		self
+	}
+
+	/// Returns the admin name of the child model.
+	pub fn model_name(&self) -> &str {
+		&self.model_name
+	}
+
+	/// Returns the child table.
+	pub fn get_table_name(&self) -> &str {
+		self.table_name.as_deref().unwrap_or(&self.model_name)
+	}
+
+	/// Returns the heading shown above the inline.
+	pub fn get_label(&self) -> &str {
+		self.label.as_deref().unwrap_or(&self.model_name)
+	}
+
+	/// Returns the content type column of the child table.
+	pub fn get_ct_field(&self) -> &str {
+		&self.ct_field
+	}
+
+	/// Returns the object id column of the child table.
+	pub fn get_fk_field(&self) -> &str {
+		&self.fk_field
+	}
+
+	/// Filters selecting the children of one parent record.
+	fn child_filters(&self, content_type_id: i64, object_id: &str) -> Vec<Filter> {
+		let object_id = match object_id.parse::<i64>() {
+			Ok(id) if !self.text_object_ids => FilterValue::Integer(id),
+			_ => FilterValue::String(object_id.to_string()),
+		};
+		vec![
+			Filter::new(
+				self.ct_field.clone(),
+				FilterOperator::Eq,
+				FilterValue::Integer(content_type_id),
+			),
+			Filter::new(self.fk_field.clone(), FilterOperator::Eq, object_id),
+		]
+	}
+
+	/// Keeps the configured columns of a child row.
+	fn project(
+		&self,
+		mut row: std::collections::HashMap<String, serde_json::Value>,
+	) -> std::collections::HashMap<String, serde_json::Value> {
+		if !self.fields.is_empty() {
+			row.retain(|name, _| self.fields.contains(name));
+		}
+		row
+	}
+}
+
+/// Loads the generic inline rows of one record.
+///
+/// Returns no rows when the parent model has no persisted content type.
+pub(crate) async fn load_generic_inlines(
+	db: &AdminDatabase,
+	model_admin: &dyn ModelAdmin,
+	object_id: &str,
+) -> AdminResult<Vec<GenericInlineRows>> {
+	let inlines = model_admin.generic_inlines();
+	if inlines.is_empty() {
+		return Ok(Vec::new());
+	}
+
+	let content_type_id = content_type_id(db, model_admin).await?;
+	let mut result = Vec::with_capacity(inlines.len());
+	for inline in inlines {
+		let rows = match content_type_id {
+			Some(ct_id) => db
+				.list::<AdminRecord>(
+					inline.get_table_name(),
+					inline.child_filters(ct_id, object_id),
+					0,
+					inline.max_num as u64,
+				)
+				.await?
+				.into_iter()
+				.map(|row| inline.project(row))
+				.collect(),
+			None => Vec::new(),
+		};
+		result.push(GenericInlineRows {
+			model_name: inline.model_name.clone(),
+			label: inline.get_label().to_string(),
+			fields: inline.fields.clone(),
+			rows,
+			upload_url: inline.upload_url.as_ref().map(|base| {
+				format!(
+					"{}/{}/{}/{}",
+					base,
+					model_admin.app_label(),
+					model_admin.model_name(),
+					object_id
+				)
+			}),
+		});
+	}
+	Ok(result)
+}
+
+/// Looks up the content type id of the admin's model.
+async fn content_type_id(
+	db: &AdminDatabase,
+	model_admin: &dyn ModelAdmin,
+) -> AdminResult<Option<i64>> {
+	let mut filters = vec![Filter::new(
+		"model".to_string(),
+		FilterOperator::IExact,
+		FilterValue::String(model_admin.model_name().to_string()),
+	)];
+	if !model_admin.app_label().is_empty() {
+		filters.push(Filter::new(
+			"app_label".to_string(),
+			FilterOperator::Eq,
+			FilterValue::String(model_admin.app_label().to_string()),
+		));
+	}
+	let rows = db
+		.list::<AdminRecord>(CONTENT_TYPE_TABLE, filters, 0, 1)
+		.await?;
+	Ok(rows
+		.first()
+		.and_then(|row| row.get("id"))
+		.and_then(|id| id.as_i64()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use serde_json::json;
+	use std::collections::HashMap;
+
+	#[rstest]
+	fn test_generic_inline_defaults() {
+		// Arrange & Act
+		let inline = GenericInline::new("Comment");
+
+		// Assert
+		assert_eq!(inline.get_table_name(), "Comment");
+		assert_eq!(inline.get_label(), "Comment");
+		assert_eq!(inline.get_ct_field(), "content_type_id");
+		assert_eq!(inline.get_fk_field(), "object_id");
+		assert_eq!(inline.max_num, DEFAULT_MAX_NUM);
+	}
+
+	#[rstest]
+	#[case("42", FilterValue::Integer(42))]
+	#[case("a1b2", FilterValue::String("a1b2".to_string()))]
+	fn test_child_filters_match_content_type_and_object(
+		#[case] object_id: &str,
+		#[case] expected: FilterValue,
+	) {
+		// Arrange
+		let inline = GenericInline::new("Attachment")
+			.ct_field("target_ct")
+			.fk_field("target_id");
+
+		// Act
+		let filters = inline.child_filters(7, object_id);
+
+		// Assert
+		assert_eq!(filters.len(), 2);
+		assert_eq!(filters[0].field, "target_ct");
+		assert!(matches!(filters[0].value, FilterValue::Integer(7)));
+		assert_eq!(filters[1].field, "target_id");
+		assert_eq!(format!("{:?}", filters[1].value), format!("{:?}", expected));
+	}
+
+	#[rstest]
+	fn test_text_object_ids_keep_numeric_ids_as_strings() {
+		// Arrange
+		let inline = GenericInline::new("Attachment").text_object_ids();
+
+		// Act
+		let filters = inline.child_filters(7, "42");
+
+		// Assert
+		assert!(matches!(&filters[1].value, FilterValue::String(id) if id == "42"));
+	}
+
+	#[rstest]
+	fn test_project_keeps_configured_fields() {
+		// Arrange
+		let inline = GenericInline::new("Comment").fields(vec!["id", "body"]);
+		let row: HashMap<String, serde_json::Value> = [
+			("id".to_string(), json!(1)),
+			("body".to_string(), json!("Nice post")),
+			("object_id".to_string(), json!(42)),
+		]
+		.into_iter()
+		.collect();
+
+		// Act
+		let projected = inline.project(row);
+
+		// Assert
+		assert_eq!(projected.len(), 2);
+		assert!(!projected.contains_key("object_id"));
+	}
+}
@@ -6,9 +6,11 @@
 use crate::types::{AdminError, AdminResult};
 use csv::ReaderBuilder;
 use rayon::prelude::*;
+use reinhardt_core::security::upload_scan::UploadScanner;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io::Cursor;
+use std::sync::Arc;
 
 /// Import format
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -605,6 +607,7 @@ impl TsvImporter {
 pub struct ImportBuilder {
 	config: ImportConfig,
 	data: Vec<u8>,
+	scanner: Option<Arc<dyn UploadScanner>>,
 }
 
 impl ImportBuilder {
@@ -613,6 +616,7 @@ impl ImportBuilder {
 		Self {
 			config: ImportConfig::new(model_name, format),
 			data: Vec::new(),
+			scanner: None,
 		}
 	}
 
@@ -656,8 +660,21 @@ impl ImportBuilder {
 		self
 	}
 
+	/// Scan the uploaded data before parsing
+	pub fn scanner(mut self, scanner: Arc<dyn UploadScanner>) -> Self {
+		self.scanner = Some(scanner);
+		self
+	}
+
 	/// Parse data
+	///
+	/// When a scanner is set, data it flags or cannot scan is rejected
+	/// before any record is parsed.
 	pub fn parse(self) -> AdminResult<Vec<HashMap<String, String>>> {
+		if let Some(scanner) = &self.scanner {
+			scan_import_data(scanner.as_ref(), self.config.model_name(), &self.data)?;
+		}
+
 		let mut records = match self.config.format() {
 			ImportFormat::CSV => CsvImporter::import(&self.data, self.config.should_skip_header())?,
 			ImportFormat::JSON => JsonImporter::import(&self.data)?,
@@ -688,6 +705,23 @@ impl ImportBuilder {
 	}
 }
 
+/// Reject import data that `scanner` flags or cannot scan
+pub(crate) fn scan_import_data(
+	scanner: &dyn UploadScanner,
+	model_name: &str,
+	data: &[u8],
+) -> AdminResult<()> {
+	match scanner.scan(model_name, data) {
+		Ok(verdict) if !verdict.is_flagged() => Ok(()),
+		Ok(_) => Err(AdminError::ValidationError(
+			"Import file was rejected by the upload scanner".to_string(),
+		)),
+		Err(_) => Err(AdminError::ValidationError(
+			"Import file could not be scanned".to_string(),
+		)),
+	}
+}
+
 #[cfg(all(test, server))]
 mod tests {
 	use super::*;
@@ -783,6 +817,20 @@ mod tests {
 		assert_eq!(records.len(), 2);
 	}
 
+	#[test]
+	fn test_import_builder_rejects_flagged_data() {
+		use reinhardt_core::security::upload_scan::FileTypeScanner;
+
+		let result = ImportBuilder::new("User", ImportFormat::CSV)
+			.data(b"#!/bin/sh\nid,name".to_vec())
+			.scanner(Arc::new(FileTypeScanner::new()))
+			.parse();
+
+		assert!(
+			matches!(result, Err(AdminError::ValidationError(ref msg)) if msg.contains("scanner"))
+		);
+	}
+
 	#[test]
 	fn test_import_builder_with_mapping() {
 		let csv_data = b"id,username\n1,alice\n2,bob";
@@ -2,8 +2,13 @@
 //!
 //! This module defines how models are displayed and managed in the admin interface.
 
+use crate::core::GenericInline;
 use crate::types::{AdminError, AdminResult};
 use async_trait::async_trait;
+use reinhardt_core::choices::Choices;
+use reinhardt_db::orm::Filter;
+use reinhardt_db::orm::translation::TranslatedModel;
+use std::collections::HashMap;
 
 /// Object-safe trait for admin permission checks.
 ///
@@ -78,6 +83,24 @@ pub trait ModelAdmin: Send + Sync {
 		"id"
 	}
 
+	/// Get the app label of the model
+	///
+	/// Used with the model name to find the model's content type. When empty,
+	/// the content type is matched by model name only.
+	fn app_label(&self) -> &str {
+		""
+	}
+
+	/// Filters applied to every list view query
+	///
+	/// Mirrors the default queryset of the model's manager, so rows hidden
+	/// by `Model::objects()` (e.g. soft-deleted ones) are hidden in the admin
+	/// as well. `#[admin(model, for = ...)]` derives this from the model's
+	/// default manager; by default no rows are excluded.
+	fn queryset_filters(&self) -> Vec<Filter> {
+		Vec::new()
+	}
+
 	/// Fields to display in list view
 	fn list_display(&self) -> Vec<&str> {
 		vec!["id"]
@@ -108,11 +131,74 @@ pub trait ModelAdmin: Send + Sync {
 		vec!["-id"]
 	}
 
+	/// Fields sorted in natural order in list view (`"item2"` before `"item10"`)
+	///
+	/// Sorting by one of these fields orders by the `natural` ICU collation,
+	/// created with `migrations::operations::postgres::collations::natural()`.
+	fn natural_ordering_fields(&self) -> Vec<&str> {
+		vec![]
+	}
+
+	/// Fields editable inline in list view
+	///
+	/// Each field should also appear in [`list_display`](Self::list_display).
+	/// Read-only fields and the primary key are never editable, and inputs are
+	/// only rendered for users with change permission.
+	fn list_editable(&self) -> Vec<&str> {
+		vec![]
+	}
+
+	/// Fields holding HTML from a rich text editor
+	///
+	/// Submitted values of these fields are cleaned with
+	/// `SanitizePolicy::rich_text()` instead of being HTML-escaped, so the
+	/// editor's formatting survives while scripts and event handlers are
+	/// removed.
+	fn rich_text_fields(&self) -> Vec<&str> {
+		vec![]
+	}
+
 	/// Number of items per page (None = use site default)
 	fn list_per_page(&self) -> Option<usize> {
 		None
 	}
 
+	/// Children attached through generic foreign keys, shown in detail view
+	fn generic_inlines(&self) -> Vec<GenericInline> {
+		vec![]
+	}
+
+	/// `(value, label)` choices for `field`, e.g. `Some(Status::pairs())`
+	///
+	/// Overrides the choices inferred from the column type in list filters,
+	/// list columns and forms. Called per request, so labels follow the
+	/// active language.
+	fn field_choices(&self, _field: &str) -> Option<Vec<(String, String)>> {
+		None
+	}
+
+	/// `(language, column)` pairs storing translated `field`
+	///
+	/// Forms show translated fields as one tab per language. Empty for
+	/// fields that are not translated.
+	fn field_translations(&self, _field: &str) -> Vec<(String, String)> {
+		Vec::new()
+	}
+
+	/// Check submitted values before they are saved
+	///
+	/// Called for creates (`pk` is `None`), updates and inline list edits
+	/// after the field allowlist checks. Return
+	/// `AdminError::ValidationError` to reject the change; by default every
+	/// value is accepted.
+	fn validate_values(
+		&self,
+		_pk: Option<&str>,
+		_data: &HashMap<String, serde_json::Value>,
+	) -> AdminResult<()> {
+		Ok(())
+	}
+
 	/// Check if user has permission to view this model
 	///
 	/// Default implementation denies all access (deny-by-default).
@@ -175,6 +261,7 @@ pub trait ModelAdmin: Send + Sync {
 pub struct ModelAdminConfig {
 	model_name: String,
 	table_name: Option<String>,
+	app_label: Option<String>,
 	pk_field: String,
 	list_display: Vec<String>,
 	list_filter: Vec<String>,
@@ -182,7 +269,13 @@ pub struct ModelAdminConfig {
 	fields: Option<Vec<String>>,
 	readonly_fields: Vec<String>,
 	ordering: Vec<String>,
+	natural_ordering_fields: Vec<String>,
+	list_editable: Vec<String>,
+	rich_text_fields: Vec<String>,
 	list_per_page: Option<usize>,
+	generic_inlines: Vec<GenericInline>,
+	field_choices: HashMap<String, fn() -> Vec<(String, String)>>,
+	field_translations: HashMap<String, Vec<(String, String)>>,
 	allow_view: bool,
 	allow_add: bool,
 	allow_change: bool,
@@ -204,6 +297,7 @@ impl ModelAdminConfig {
 		Self {
 			model_name: model_name.into(),
 			table_name: None,
+			app_label: None,
 			pk_field: "id".into(),
 			list_display: vec!["id".into()],
 			list_filter: vec![],
@@ -211,7 +305,13 @@ impl ModelAdminConfig {
 			fields: None,
 			readonly_fields: vec![],
 			ordering: vec!["-id".into()],
+			natural_ordering_fields: vec![],
+			list_editable: vec![],
+			rich_text_fields: vec![],
 			list_per_page: None,
+			generic_inlines: vec![],
+			field_choices: HashMap::new(),
+			field_translations: HashMap::new(),
 			allow_view: false,
 			allow_add: false,
 			allow_change: false,
@@ -253,6 +353,30 @@ impl ModelAdminConfig {
 		self.search_fields = fields.into_iter().map(Into::into).collect();
 		self
 	}
+
+	/// Label the values of `field` with the choices of `C`
+	pub fn with_choices<C: Choices>(mut self, field: impl Into<String>) -> Self {
+		self.field_choices.insert(field.into(), C::pairs);
+		self
+	}
+
+	/// Edit the translated fields of `T` with one tab per language
+	pub fn with_translations<T: TranslatedModel>(mut self) -> Self {
+		self.field_translations = translation_columns::<T>();
+		self
+	}
+}
+
+/// `(language, column)` pairs of every translated field of `T`
+fn translation_columns<T: TranslatedModel>() -> HashMap<String, Vec<(String, String)>> {
+	let mut translations: HashMap<String, Vec<(String, String)>> = HashMap::new();
+	for (field, language, column) in T::translation_columns() {
+		translations
+			.entry(field.to_string())
+			.or_default()
+			.push((language.to_string(), column));
+	}
+	translations
 }
 
 #[async_trait]
@@ -271,6 +395,10 @@ impl ModelAdmin for ModelAdminConfig {
 		&self.pk_field
 	}
 
+	fn app_label(&self) -> &str {
+		self.app_label.as_deref().unwrap_or("")
+	}
+
 	fn list_display(&self) -> Vec<&str> {
 		self.list_display.iter().map(|s| s.as_str()).collect()
 	}
@@ -297,10 +425,40 @@ impl ModelAdmin for ModelAdminConfig {
 		self.ordering.iter().map(|s| s.as_str()).collect()
 	}
 
+	fn natural_ordering_fields(&self) -> Vec<&str> {
+		self.natural_ordering_fields
+			.iter()
+			.map(|s| s.as_str())
+			.collect()
+	}
+
+	fn list_editable(&self) -> Vec<&str> {
+		self.list_editable.iter().map(|s| s.as_str()).collect()
+	}
+
+	fn rich_text_fields(&self) -> Vec<&str> {
+		self.rich_text_fields.iter().map(|s| s.as_str()).collect()
+	}
+
 	fn list_per_page(&self) -> Option<usize> {
 		self.list_per_page
 	}
 
+	fn generic_inlines(&self) -> Vec<GenericInline> {
+		self.generic_inlines.clone()
+	}
+
+	fn field_choices(&self, field: &str) -> Option<Vec<(String, String)>> {
+		self.field_choices.get(field).map(|pairs| pairs())
+	}
+
+	fn field_translations(&self, field: &str) -> Vec<(String, String)> {
+		self.field_translations
+			.get(field)
+			.cloned()
+			.unwrap_or_default()
+	}
+
 	async fn has_view_permission(&self, _user: &dyn AdminUser) -> bool {
 		self.allow_view
 	}
@@ -323,6 +481,7 @@ impl ModelAdmin for ModelAdminConfig {
 pub struct ModelAdminConfigBuilder {
 	model_name: Option<String>,
 	table_name: Option<String>,
+	app_label: Option<String>,
 	pk_field: Option<String>,
 	list_display: Option<Vec<String>>,
 	list_filter: Option<Vec<String>>,
@@ -330,7 +489,13 @@ pub struct ModelAdminConfigBuilder {
 	fields: Option<Vec<String>>,
 	readonly_fields: Option<Vec<String>>,
 	ordering: Option<Vec<String>>,
+	natural_ordering_fields: Option<Vec<String>>,
+	list_editable: Option<Vec<String>>,
+	rich_text_fields: Option<Vec<String>>,
 	list_per_page: Option<usize>,
+	generic_inlines: Option<Vec<GenericInline>>,
+	field_choices: HashMap<String, fn() -> Vec<(String, String)>>,
+	field_translations: HashMap<String, Vec<(String, String)>>,
 	allow_view: Option<bool>,
 	allow_add: Option<bool>,
 	allow_change: Option<bool>,
@@ -352,6 +517,14 @@ impl ModelAdminConfigBuilder {
 		self
 	}
 
+	/// Set the app label of the model
+	///
+	/// Used to find the model's content type for generic inlines.
+	pub fn app_label(mut self, app_label: impl Into<String>) -> Self {
+		self.app_label = Some(app_label.into());
+		self
+	}
+
 	/// Set the primary key field name
 	///
 	/// If not set, defaults to "id".
@@ -396,12 +569,85 @@ impl ModelAdminConfigBuilder {
 		self
 	}
 
+	/// Set fields sorted in natural order
+	pub fn natural_ordering_fields(mut self, fields: Vec<impl Into<String>>) -> Self {
+		self.natural_ordering_fields = Some(fields.into_iter().map(Into::into).collect());
+		self
+	}
+
+	/// Set fields editable inline in list view
+	///
+	/// Each field must also be in `list_display`; this is checked by [`build`](Self::build).
+	pub fn list_editable(mut self, fields: Vec<impl Into<String>>) -> Self {
+		self.list_editable = Some(fields.into_iter().map(Into::into).collect());
+		self
+	}
+
+	/// Set fields holding rich text editor HTML
+	pub fn rich_text_fields(mut self, fields: Vec<impl Into<String>>) -> Self {
+		self.rich_text_fields = Some(fields.into_iter().map(Into::into).collect());
+		self
+	}
+
 	/// Set items per page
 	pub fn list_per_page(mut self, count: usize) -> Self {
 		self.list_per_page = Some(count);
 		self
 	}
 
+	/// Set children attached through generic foreign keys
+	pub fn generic_inlines(mut self, inlines: Vec<GenericInline>) -> Self {
+		self.generic_inlines = Some(inlines);
+		self
+	}
+
+	/// Label the values of `field` with the choices of `C`
+	///
+	/// # Examples
+	///
+	/// ```ignore
+	/// #[derive(Choices)]
+	/// enum Status {
+	///     Draft,
+	///     Published,
+	/// }
+	///
+	/// let admin = ModelAdminConfig::builder()
+	///     .model_name("Article")
+	///     .choices::<Status>("status")
+	///     .build()
+	///     .unwrap();
+	/// assert_eq!(admin.field_choices("status").unwrap().len(), 2);
+	/// ```
+	pub fn choices<C: Choices>(mut self, field: impl Into<String>) -> Self {
+		self.field_choices.insert(field.into(), C::pairs);
+		self
+	}
+
+	/// Edit the translated fields of `T` with one tab per language
+	///
+	/// # Examples
+	///
+	/// ```ignore
+	/// let admin = ModelAdminConfig::builder()
+	///     .model_name("Article")
+	///     .fields(vec!["title", "body"])
+	///     .translations::<Article>()
+	///     .build()
+	///     .unwrap();
+	/// assert_eq!(
+	///     admin.field_translations("title"),
+	///     vec![
+	///         ("en".to_string(), "title".to_string()),
+	///         ("ja".to_string(), "title_ja".to_string()),
+	///     ]
+	/// );
+	/// ```
+	pub fn translations<T: TranslatedModel>(mut self) -> Self {
+		self.field_translations = translation_columns::<T>();
+		self
+	}
+
 	/// Set view permission
 	///
 	/// If not set, defaults to `false` (deny-by-default).
@@ -461,23 +707,39 @@ impl ModelAdminConfigBuilder {
 	///
 	/// # Errors
 	///
-	/// Returns `AdminError::ValidationError` if `model_name` is not set.
+	/// Returns `AdminError::ValidationError` if `model_name` is not set or a
+	/// `list_editable` field is missing from `list_display`.
 	pub fn build(self) -> AdminResult<ModelAdminConfig> {
 		let model_name = self
 			.model_name
 			.ok_or_else(|| AdminError::ValidationError("model_name is required".to_string()))?;
+		let list_display = self.list_display.unwrap_or_else(|| vec!["id".into()]);
+		let list_editable = self.list_editable.unwrap_or_default();
+		if let Some(field) = list_editable.iter().find(|f| !list_display.contains(*f)) {
+			return Err(AdminError::ValidationError(format!(
+				"list_editable field '{}' must also be in list_display",
+				field
+			)));
+		}
 
 		Ok(ModelAdminConfig {
 			model_name,
 			table_name: self.table_name,
+			app_label: self.app_label,
 			pk_field: self.pk_field.unwrap_or_else(|| "id".into()),
-			list_display: self.list_display.unwrap_or_else(|| vec!["id".into()]),
+			list_display,
 			list_filter: self.list_filter.unwrap_or_default(),
 			search_fields: self.search_fields.unwrap_or_default(),
 			fields: self.fields,
 			readonly_fields: self.readonly_fields.unwrap_or_default(),
 			ordering: self.ordering.unwrap_or_else(|| vec!["-id".into()]),
+			natural_ordering_fields: self.natural_ordering_fields.unwrap_or_default(),
+			list_editable,
+			rich_text_fields: self.rich_text_fields.unwrap_or_default(),
 			list_per_page: self.list_per_page,
+			generic_inlines: self.generic_inlines.unwrap_or_default(),
+			field_choices: self.field_choices,
+			field_translations: self.field_translations,
 			allow_view: self.allow_view.unwrap_or(false),
 			allow_add: self.allow_add.unwrap_or(false),
 			allow_change: self.allow_change.unwrap_or(false),
@@ -536,6 +798,63 @@ mod tests {
 		assert_eq!(admin.list_filter(), Vec::<&str>::new());
 	}
 
+	#[derive(Debug, Clone, Copy)]
+	enum Status {
+		Draft,
+		Published,
+	}
+
+	impl Choices for Status {
+		fn choices() -> &'static [Self] {
+			&[Self::Draft, Self::Published]
+		}
+
+		fn value(&self) -> &'static str {
+			match self {
+				Self::Draft => "draft",
+				Self::Published => "published",
+			}
+		}
+
+		fn label(&self) -> &'static str {
+			match self {
+				Self::Draft => "Draft",
+				Self::Published => "Published",
+			}
+		}
+
+		fn from_value(value: &str) -> Option<Self> {
+			match value {
+				"draft" => Some(Self::Draft),
+				"published" => Some(Self::Published),
+				_ => None,
+			}
+		}
+	}
+
+	#[rstest]
+	fn test_model_admin_config_field_choices() {
+		// Arrange
+		let admin = ModelAdminConfig::builder()
+			.model_name("Article")
+			.choices::<Status>("status")
+			.build()
+			.unwrap();
+
+		// Act
+		let choices = admin.field_choices("status");
+
+		// Assert
+		assert_eq!(
+			choices,
+			Some(vec![
+				("draft".to_string(), "Draft".to_string()),
+				("published".to_string(), "Published".to_string()),
+			])
+		);
+		assert_eq!(admin.field_choices("title"), None);
+	}
+
 	#[rstest]
 	fn test_model_admin_config_builder() {
 		let admin = ModelAdminConfig::builder()
@@ -566,6 +885,47 @@ mod tests {
 		assert_eq!(admin.search_fields(), vec!["title", "content"]);
 	}
 
+	#[rstest]
+	fn test_builder_sets_list_editable() {
+		// Arrange & Act
+		let admin = ModelAdminConfig::builder()
+			.model_name("User")
+			.list_display(vec!["id", "username", "is_active"])
+			.list_editable(vec!["is_active"])
+			.build()
+			.unwrap();
+
+		// Assert
+		assert_eq!(admin.list_editable(), vec!["is_active"]);
+	}
+
+	#[rstest]
+	fn test_builder_sets_rich_text_fields() {
+		// Arrange & Act
+		let admin = ModelAdminConfig::builder()
+			.model_name("Article")
+			.rich_text_fields(vec!["body"])
+			.build()
+			.unwrap();
+
+		// Assert
+		assert_eq!(admin.rich_text_fields(), vec!["body"]);
+		assert!(ModelAdminConfig::new("Article").rich_text_fields().is_empty());
+	}
+
+	#[rstest]
+	fn test_builder_rejects_list_editable_not_displayed() {
+		// Arrange & Act
+		let result = ModelAdminConfig::builder()
+			.model_name("User")
+			.list_display(vec!["id", "username"])
+			.list_editable(vec!["email"])
+			.build();
+
+		// Assert
+		assert!(matches!(result, Err(AdminError::ValidationError(msg)) if msg.contains("email")));
+	}
+
 	#[rstest]
 	fn test_builder_without_model_name_returns_error() {
 		// Arrange & Act
@@ -0,0 +1,425 @@
+//! Admin navigation registry
+//!
+//! Groups registered models into sidebar sections and lets projects add
+//! links to custom views. The registry is resolved per user: models the user
+//! cannot view and links restricted to superusers are omitted.
+//!
+//! # Examples
+//!
+//! ```
+//! use reinhardt_admin::core::AdminSite;
+//! use reinhardt_admin::core::navigation::{MenuLink, NavGroup};
+//!
+//! let site = AdminSite::new("Admin");
+//! site.add_nav_group(
+//!     NavGroup::new("Blog")
+//!         .icon("book")
+//!         .order(10)
+//!         .models(["Post", "Comment"]),
+//! );
+//! site.add_menu_link(
+//!     MenuLink::new("Sales report", "/admin/reports/sales/")
+//!         .group("Reports")
+//!         .superuser_only(),
+//! );
+//! ```
+
+use crate::core::{AdminSite, AdminUser};
+use crate::types::{NavItem, NavSection};
+use serde::{Deserialize, Serialize};
+
+/// Label of the section holding models that are not assigned to a group.
+pub const DEFAULT_GROUP_LABEL: &str = "Models";
+
+/// A named group of models shown as one sidebar section.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NavGroup {
+	label: String,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	icon: Option<String>,
+	#[serde(default)]
+	order: i32,
+	#[serde(default)]
+	models: Vec<String>,
+}
+
+impl NavGroup {
+	/// Creates an empty group.
+	pub fn new(label: impl Into<String>) -> Self {
+		Self {
+			label: label.into(),
+			icon: None,
+			order: 0,
+			models: Vec::new(),
+		}
+	}
+
+	/// Sets the icon name shown next to the section title.
+	pub fn icon(mut self, icon: impl Into<String>) -> Self {
+		self.icon = Some(icon.into());
+		self
+	}
+
+	/// Sets the sort position; lower values are shown first.
+	pub fn order(mut self, order: i32) -> Self {
+		self.order = order;
+		self
+	}
+
+	/// Sets the models in this group, in display order.
+	pub fn models<I, S>(mut self, models: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: Into<String>,
+	{
+		self.models = models.into_iter().map(Into::into).collect();
+		self
+	}
+
+	/// Returns the group label.
+	pub fn label(&self) -> &str {
+		&self.label
+	}
+}
+
+/// A custom link added to the admin sidebar.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MenuLink {
+	label: String,
+	url: String,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	icon: Option<String>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	group: Option<String>,
+	#[serde(default)]
+	order: i32,
+	#[serde(default)]
+	superuser_only: bool,
+}
+
+impl MenuLink {
+	/// Creates a link visible to every staff user.
+	pub fn new(label: impl Into<String>, url: impl Into<String>) -> Self {
+		Self {
+			label: label.into(),
+			url: url.into(),
+			icon: None,
+			group: None,
+			order: 0,
+			superuser_only: false,
+		}
+	}
+
+	/// Sets the icon name.
+	pub fn icon(mut self, icon: impl Into<String>) -> Self {
+		self.icon = Some(icon.into());
+		self
+	}
+
+	/// Places the link in the section with this label, creating it if needed.
+	///
+	/// Links without a group are placed in the default section.
+	pub fn group(mut self, group: impl Into<String>) -> Self {
+		self.group = Some(group.into());
+		self
+	}
+
+	/// Sets the position among the section's links; lower values are shown first.
+	pub fn order(mut self, order: i32) -> Self {
+		self.order = order;
+		self
+	}
+
+	/// Restricts the link to superusers.
+	pub fn superuser_only(mut self) -> Self {
+		self.superuser_only = true;
+		self
+	}
+
+	fn is_visible_to(&self, user: &dyn AdminUser) -> bool {
+		!self.superuser_only || user.is_superuser()
+	}
+}
+
+/// Registered navigation groups and custom links.
+#[derive(Debug, Clone, Default)]
+pub struct NavigationRegistry {
+	groups: Vec<NavGroup>,
+	links: Vec<MenuLink>,
+}
+
+impl NavigationRegistry {
+	/// Adds a group, replacing any existing group with the same label.
+	pub fn add_group(&mut self, group: NavGroup) {
+		self.groups.retain(|g| g.label != group.label);
+		self.groups.push(group);
+	}
+
+	/// Adds a custom link.
+	pub fn add_link(&mut self, link: MenuLink) {
+		self.links.push(link);
+	}
+
+	/// Returns the registered groups.
+	pub fn groups(&self) -> &[NavGroup] {
+		&self.groups
+	}
+
+	/// Returns the registered links.
+	pub fn links(&self) -> &[MenuLink] {
+		&self.links
+	}
+
+	/// Builds the sidebar sections visible to `user`.
+	///
+	/// Grouped models keep their configured order. Models not assigned to any
+	/// group are listed alphabetically in the [`DEFAULT_GROUP_LABEL`] section,
+	/// which is placed after groups with a non-negative order. Sections left
+	/// without items are omitted.
+	pub async fn resolve(&self, site: &AdminSite, user: &dyn AdminUser) -> Vec<NavSection> {
+		let mut visible_models = Vec::new();
+		for name in site.registered_models() {
+			if let Ok(model_admin) = site.get_model_admin(&name)
+				&& model_admin.has_view_permission(user).await
+			{
+				visible_models.push(name);
+			}
+		}
+		let model_item = |name: &str| NavItem {
+			label: name.to_string(),
+			url: format!("{}/{}/", site.url_prefix(), name.to_lowercase()),
+			icon: None,
+			is_model: true,
+		};
+
+		let mut groups: Vec<&NavGroup> = self.groups.iter().collect();
+		groups.sort_by_key(|g| g.order);
+
+		let mut sections: Vec<(i32, NavSection)> = groups
+			.iter()
+			.map(|group| {
+				let items = group
+					.models
+					.iter()
+					.filter(|model| visible_models.contains(*model))
+					.map(|model| model_item(model))
+					.collect();
+				(
+					group.order,
+					NavSection {
+						label: group.label.clone(),
+						icon: group.icon.clone(),
+						items,
+					},
+				)
+			})
+			.collect();
+
+		let mut ungrouped: Vec<&String> = visible_models
+			.iter()
+			.filter(|model| !self.groups.iter().any(|g| g.models.contains(*model)))
+			.collect();
+		ungrouped.sort();
+		if !sections.iter().any(|(_, s)| s.label == DEFAULT_GROUP_LABEL) {
+			sections.push((
+				i32::MAX,
+				NavSection {
+					label: DEFAULT_GROUP_LABEL.to_string(),
+					icon: None,
+					items: Vec::new(),
+				},
+			));
+		}
+		if let Some((_, section)) = sections
+			.iter_mut()
+			.find(|(_, s)| s.label == DEFAULT_GROUP_LABEL)
+		{
+			section
+				.items
+				.extend(ungrouped.into_iter().map(|m| model_item(m)));
+		}
+
+		let mut links: Vec<&MenuLink> = self
+			.links
+			.iter()
+			.filter(|link| link.is_visible_to(user))
+			.collect();
+		links.sort_by_key(|link| link.order);
+		for link in links {
+			let label = link.group.as_deref().unwrap_or(DEFAULT_GROUP_LABEL);
+			let item = NavItem {
+				label: link.label.clone(),
+				url: link.url.clone(),
+				icon: link.icon.clone(),
+				is_model: false,
+			};
+			match sections.iter_mut().find(|(_, s)| s.label == label) {
+				Some((_, section)) => section.items.push(item),
+				None => sections.push((
+					i32::MAX,
+					NavSection {
+						label: label.to_string(),
+						icon: None,
+						items: vec![item],
+					},
+				)),
+			}
+		}
+
+		// Stable sort keeps registration order among equal positions.
+		sections.sort_by_key(|(order, _)| *order);
+		sections
+			.into_iter()
+			.map(|(_, section)| section)
+			.filter(|section| !section.items.is_empty())
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::core::ModelAdminConfig;
+	use rstest::rstest;
+
+	struct TestUser {
+		superuser: bool,
+	}
+
+	impl AdminUser for TestUser {
+		fn is_active(&self) -> bool {
+			true
+		}
+
+		fn is_staff(&self) -> bool {
+			true
+		}
+
+		fn is_superuser(&self) -> bool {
+			self.superuser
+		}
+
+		fn get_username(&self) -> &str {
+			"tester"
+		}
+	}
+
+	fn site_with_models(models: &[&str]) -> AdminSite {
+		let site = AdminSite::new("Admin");
+		for model in models {
+			let config = ModelAdminConfig::builder()
+				.model_name(*model)
+				.build()
+				.unwrap();
+			site.register(*model, config).unwrap();
+		}
+		site
+	}
+
+	fn labels(section: &NavSection) -> Vec<&str> {
+		section.items.iter().map(|i| i.label.as_str()).collect()
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_resolve_groups_models_and_collects_ungrouped() {
+		// Arrange
+		let site = site_with_models(&["Post", "Comment", "User", "Group"]);
+		let mut registry = NavigationRegistry::default();
+		registry.add_group(
+			NavGroup::new("Blog")
+				.icon("book")
+				.order(1)
+				.models(["Post", "Comment"]),
+		);
+		let user = TestUser { superuser: true };
+
+		// Act
+		let sections = registry.resolve(&site, &user).await;
+
+		// Assert
+		assert_eq!(sections.len(), 2);
+		assert_eq!(sections[0].label, "Blog");
+		assert_eq!(sections[0].icon.as_deref(), Some("book"));
+		assert_eq!(labels(&sections[0]), vec!["Post", "Comment"]);
+		assert_eq!(sections[1].label, DEFAULT_GROUP_LABEL);
+		assert_eq!(labels(&sections[1]), vec!["Group", "User"]);
+		assert_eq!(sections[0].items[0].url, "/admin/post/");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_resolve_orders_groups() {
+		// Arrange
+		let site = site_with_models(&["Post", "User"]);
+		let mut registry = NavigationRegistry::default();
+		registry.add_group(NavGroup::new("Blog").order(20).models(["Post"]));
+		registry.add_group(NavGroup::new("Accounts").order(10).models(["User"]));
+		let user = TestUser { superuser: true };
+
+		// Act
+		let sections = registry.resolve(&site, &user).await;
+
+		// Assert
+		let order: Vec<&str> = sections.iter().map(|s| s.label.as_str()).collect();
+		assert_eq!(order, vec!["Accounts", "Blog"]);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_resolve_hides_superuser_links_from_staff() {
+		// Arrange
+		let site = site_with_models(&[]);
+		let mut registry = NavigationRegistry::default();
+		registry.add_link(MenuLink::new("Stats", "/admin/stats/").group("Reports"));
+		registry.add_link(
+			MenuLink::new("Audit", "/admin/audit/")
+				.group("Reports")
+				.superuser_only(),
+		);
+		let staff = TestUser { superuser: false };
+		let superuser = TestUser { superuser: true };
+
+		// Act
+		let staff_sections = registry.resolve(&site, &staff).await;
+		let superuser_sections = registry.resolve(&site, &superuser).await;
+
+		// Assert
+		assert_eq!(labels(&staff_sections[0]), vec!["Stats"]);
+		assert_eq!(labels(&superuser_sections[0]), vec!["Stats", "Audit"]);
+		assert!(!superuser_sections[0].items[1].is_model);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_resolve_omits_empty_sections() {
+		// Arrange
+		let site = site_with_models(&["User"]);
+		let mut registry = NavigationRegistry::default();
+		registry.add_group(NavGroup::new("Blog").models(["Post"]));
+		let user = TestUser { superuser: true };
+
+		// Act
+		let sections = registry.resolve(&site, &user).await;
+
+		// Assert
+		assert_eq!(sections.len(), 1);
+		assert_eq!(sections[0].label, DEFAULT_GROUP_LABEL);
+	}
+
+	#[rstest]
+	fn test_add_group_replaces_same_label() {
+		// Arrange
+		let mut registry = NavigationRegistry::default();
+
+		// Act
+		registry.add_group(NavGroup::new("Blog").models(["Post"]));
+		registry.add_group(NavGroup::new("Blog").models(["Comment"]));
+
+		// Assert
+		assert_eq!(registry.groups().len(), 1);
+		assert_eq!(registry.groups()[0].models, vec!["Comment".to_string()]);
+	}
+}
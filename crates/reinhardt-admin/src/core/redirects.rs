@@ -0,0 +1,56 @@
+//! Admin registration for the redirects table
+//!
+//! Redirects live in the `redirects_redirect` table managed by
+//! `reinhardt_db::orm::redirects`. [`register_redirects`] exposes it in the
+//! admin so that editors can add, change and bulk-import redirects without a
+//! deployment; the import endpoint accepts the same CSV columns as
+//! `RedirectStore::import_csv`.
+
+use super::{AdminResult, AdminSite, ModelAdminConfig};
+use reinhardt_db::orm::redirects::REDIRECT_TABLE;
+
+/// Name under which the redirects table is registered
+pub const REDIRECT_MODEL_NAME: &str = "Redirect";
+
+/// Admin configuration of the redirects table
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_admin::core::ModelAdmin;
+/// use reinhardt_admin::core::redirects::redirect_admin;
+///
+/// let admin = redirect_admin();
+/// assert_eq!(admin.table_name(), "redirects_redirect");
+/// assert!(admin.search_fields().contains(&"old_path"));
+/// ```
+pub fn redirect_admin() -> ModelAdminConfig {
+	ModelAdminConfig::builder()
+		.model_name(REDIRECT_MODEL_NAME)
+		.table_name(REDIRECT_TABLE)
+		.app_label("redirects")
+		.list_display(vec!["id", "site_id", "old_path", "new_path", "permanent"])
+		.list_filter(vec!["site_id", "permanent"])
+		.search_fields(vec!["old_path", "new_path"])
+		.fields(vec!["site_id", "old_path", "new_path", "permanent"])
+		.ordering(vec!["site_id", "old_path"])
+		.list_editable(vec!["new_path", "permanent"])
+		.build()
+		.expect("redirect admin configuration is valid")
+}
+
+/// Register the redirects table with `site`
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_admin::core::AdminSite;
+/// use reinhardt_admin::core::redirects::register_redirects;
+///
+/// let site = AdminSite::new("Admin");
+/// register_redirects(&site).unwrap();
+/// assert!(site.is_registered("Redirect"));
+/// ```
+pub fn register_redirects(site: &AdminSite) -> AdminResult<()> {
+	site.register(REDIRECT_MODEL_NAME, redirect_admin())
+}
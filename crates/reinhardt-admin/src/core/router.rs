@@ -144,6 +144,102 @@ admin_endpoint!(
 	admin_spa_handler
 );
 
+#[cfg(server)]
+use crate::server::api::{
+	api_action, api_create, api_delete, api_detail, api_download, api_fields, api_index, api_list,
+	api_openapi, api_update,
+};
+
+#[cfg(server)]
+admin_endpoint!(
+	AdminApiIndexEndpoint,
+	"/api/",
+	GET,
+	"admin_api_index",
+	api_index
+);
+
+#[cfg(server)]
+admin_endpoint!(
+	AdminApiOpenApiEndpoint,
+	"/api/openapi.json",
+	GET,
+	"admin_api_openapi",
+	api_openapi
+);
+
+#[cfg(server)]
+admin_endpoint!(
+	AdminApiListEndpoint,
+	"/api/{model}/",
+	GET,
+	"admin_api_list",
+	api_list
+);
+
+#[cfg(server)]
+admin_endpoint!(
+	AdminApiCreateEndpoint,
+	"/api/{model}/",
+	POST,
+	"admin_api_create",
+	api_create
+);
+
+#[cfg(server)]
+admin_endpoint!(
+	AdminApiFieldsEndpoint,
+	"/api/{model}/fields/",
+	GET,
+	"admin_api_fields",
+	api_fields
+);
+
+#[cfg(server)]
+admin_endpoint!(
+	AdminApiDetailEndpoint,
+	"/api/{model}/{id}/",
+	GET,
+	"admin_api_detail",
+	api_detail
+);
+
+#[cfg(server)]
+admin_endpoint!(
+	AdminApiUpdateEndpoint,
+	"/api/{model}/{id}/",
+	PUT,
+	"admin_api_update",
+	api_update
+);
+
+#[cfg(server)]
+admin_endpoint!(
+	AdminApiDeleteEndpoint,
+	"/api/{model}/{id}/",
+	DELETE,
+	"admin_api_delete",
+	api_delete
+);
+
+#[cfg(server)]
+admin_endpoint!(
+	AdminApiDownloadEndpoint,
+	"/api/{model}/{id}/download/{field}/",
+	GET,
+	"admin_api_download",
+	api_download
+);
+
+#[cfg(server)]
+admin_endpoint!(
+	AdminApiActionEndpoint,
+	"/api/{model}/actions/{action}/",
+	POST,
+	"admin_api_action",
+	api_action
+);
+
 /// Resolves an admin static file path to its final URL.
 ///
 /// Uses the global static resolver (initialized by the application) for
@@ -419,8 +515,9 @@ fn build_admin_router(
 	let router = {
 		use crate::server::{
 			bulk_delete_records, create_record, delete_record, export_data, get_dashboard,
-			get_detail, get_fields, get_list, import_data, login::admin_login,
-			login::admin_login_with_header, logout::admin_logout, update_record,
+			get_detail, get_fields, get_list, get_retention_report, import_data,
+			login::admin_login, login::admin_login_with_header, logout::admin_logout,
+			update_record,
 		};
 		router
 			.server_fn(get_dashboard::marker)
@@ -436,6 +533,17 @@ fn build_admin_router(
 			.server_fn(admin_login::marker)
 			.server_fn(admin_login_with_header::marker)
 			.server_fn(admin_logout::marker)
+			.server_fn(get_retention_report::marker)
+			.endpoint(|| AdminApiIndexEndpoint)
+			.endpoint(|| AdminApiOpenApiEndpoint)
+			.endpoint(|| AdminApiListEndpoint)
+			.endpoint(|| AdminApiCreateEndpoint)
+			.endpoint(|| AdminApiFieldsEndpoint)
+			.endpoint(|| AdminApiDetailEndpoint)
+			.endpoint(|| AdminApiUpdateEndpoint)
+			.endpoint(|| AdminApiDeleteEndpoint)
+			.endpoint(|| AdminApiDownloadEndpoint)
+			.endpoint(|| AdminApiActionEndpoint)
 			.endpoint(|| AdminSpaRootEndpoint)
 			.endpoint(|| AdminSpaCatchAllEndpoint)
 	};
@@ -458,6 +566,14 @@ fn build_admin_router(
 /// `AdminDatabase` is **not** registered here; it is lazily constructed
 /// from `DatabaseConnection` at first request via its `Injectable` impl.
 ///
+/// If `config_file` is set in [`AdminSettings`], that file is applied to the
+/// site first, see [`AdminSite::load_config_file`].
+///
+/// # Panics
+///
+/// Panics if the configured admin config file cannot be read or is invalid,
+/// so a broken file is reported at startup rather than ignored.
+///
 /// # Examples
 ///
 /// ```rust,no_run
@@ -483,6 +599,8 @@ fn build_admin_router(
 /// ```
 ///
 /// [`AdminSite::set_user_type`]: AdminSite::set_user_type
+/// [`AdminSite::load_config_file`]: AdminSite::load_config_file
+/// [`AdminSettings`]: crate::settings::AdminSettings
 /// [`AdminDefaultUser`]: crate::server::user::AdminDefaultUser
 /// [`DiRegistrationList`]: reinhardt_di::DiRegistrationList
 /// [`UnifiedRouter`]: reinhardt_urls::routers::UnifiedRouter
@@ -490,6 +608,12 @@ fn build_admin_router(
 pub fn admin_routes_with_di(
 	site: Arc<AdminSite>,
 ) -> (ServerRouter, reinhardt_di::DiRegistrationList) {
+	if let Some(path) = &crate::settings::get_admin_settings().config_file
+		&& let Err(e) = site.load_config_file(path)
+	{
+		panic!("Failed to apply admin config file '{}': {}", path, e);
+	}
+
 	let mut registrations = reinhardt_di::DiRegistrationList::new();
 
 	// Register the user loader for admin authentication.
@@ -599,6 +723,11 @@ mod tests {
 			"/api/server_fn/admin_login",
 			"/api/server_fn/admin_login_with_header",
 			"/api/server_fn/admin_logout",
+			"/api/server_fn/get_retention_report",
+			"/api/",
+			"/api/openapi.json",
+			"/api/{model}/fields/",
+			"/api/{model}/actions/{action}/",
 			"/",
 			"/{*tail}",
 		];
@@ -608,8 +737,8 @@ mod tests {
 		let routes = router.get_all_routes();
 		let paths: Vec<&str> = routes.iter().map(|(path, _, _, _)| path.as_str()).collect();
 
-		// Assert - 13 server functions + 2 GET routes should be registered
-		assert_eq!(routes.len(), 15);
+		// Assert - 14 server functions + 9 JSON API routes + 2 GET routes should be registered
+		assert_eq!(routes.len(), 25);
 		for expected in &expected_paths {
 			assert_eq!(
 				paths.iter().filter(|p| p == &expected).count(),
@@ -4,13 +4,16 @@
 //! routing, authentication, and rendering functionality.
 
 use crate::core::ModelAdmin;
+use crate::core::config_file::AdminConfigFile;
 use crate::core::model_admin::AdminUser;
+use crate::core::navigation::{MenuLink, NavGroup, NavigationRegistry};
 use crate::server::admin_auth::{AdminLoginAuthenticator, AdminUserLoader};
-use crate::types::{AdminError, AdminResult};
+use crate::types::{AdminError, AdminResult, DashboardWidget, NavSection};
 use async_trait::async_trait;
 use dashmap::DashMap;
 use parking_lot::RwLock;
 use reinhardt_core::macros::injectable;
+use reinhardt_core::security::upload_scan::UploadScanner;
 use reinhardt_di::{DiResult, FactoryOutput, Injectable, InjectionContext};
 use std::sync::Arc;
 
@@ -42,6 +45,12 @@ pub struct AdminSite {
 	/// Favicon data (PNG, ICO, etc.)
 	favicon_data: Arc<RwLock<Option<Vec<u8>>>>,
 
+	/// Sidebar groups and custom menu links
+	navigation: Arc<RwLock<NavigationRegistry>>,
+
+	/// Dashboard widgets, in insertion order
+	dashboard_widgets: Arc<RwLock<Vec<DashboardWidget>>>,
+
 	/// Type-erased user loader for admin authentication.
 	///
 	/// When `None`, [`AdminDefaultUser`] is used as a fallback.
@@ -60,6 +69,9 @@ pub struct AdminSite {
 	///
 	/// When `None`, admin login is disabled.
 	jwt_secret: Option<Vec<u8>>,
+
+	/// Scanner run over import uploads before they are parsed.
+	upload_scanner: Option<Arc<dyn UploadScanner>>,
 }
 
 /// Provider key for the admin site dependency.
@@ -118,9 +130,12 @@ impl AdminSite {
 			registry: Arc::new(DashMap::new()),
 			config: Arc::new(RwLock::new(AdminSiteConfig::default())),
 			favicon_data: Arc::new(RwLock::new(None)),
+			navigation: Arc::new(RwLock::new(NavigationRegistry::default())),
+			dashboard_widgets: Arc::new(RwLock::new(Vec::new())),
 			user_loader: None,
 			login_authenticator: None,
 			jwt_secret: None,
+			upload_scanner: None,
 		}
 	}
 
@@ -180,6 +195,119 @@ impl AdminSite {
 		self.favicon_data.read().clone()
 	}
 
+	/// Add a sidebar group of models
+	///
+	/// A group with the same label replaces the previous one. Models not
+	/// assigned to any group are listed in the default "Models" section.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_admin::core::AdminSite;
+	/// use reinhardt_admin::core::navigation::NavGroup;
+	///
+	/// let admin = AdminSite::new("Admin");
+	/// admin.add_nav_group(NavGroup::new("Accounts").icon("users").models(["User", "Group"]));
+	/// assert_eq!(admin.navigation().groups().len(), 1);
+	/// ```
+	pub fn add_nav_group(&self, group: NavGroup) {
+		self.navigation.write().add_group(group);
+	}
+
+	/// Add a custom link to the sidebar
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_admin::core::AdminSite;
+	/// use reinhardt_admin::core::navigation::MenuLink;
+	///
+	/// let admin = AdminSite::new("Admin");
+	/// admin.add_menu_link(MenuLink::new("Reports", "/admin/reports/").superuser_only());
+	/// assert_eq!(admin.navigation().links().len(), 1);
+	/// ```
+	pub fn add_menu_link(&self, link: MenuLink) {
+		self.navigation.write().add_link(link);
+	}
+
+	/// Get the navigation registry (cloned)
+	pub fn navigation(&self) -> NavigationRegistry {
+		self.navigation.read().clone()
+	}
+
+	/// Build the sidebar sections visible to `user`
+	pub async fn navigation_for(&self, user: &dyn AdminUser) -> Vec<NavSection> {
+		// Clone so the lock is not held across permission checks
+		let registry = self.navigation();
+		registry.resolve(self, user).await
+	}
+
+	/// Add a dashboard widget, replacing any widget with the same ID
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_admin::core::AdminSite;
+	/// use reinhardt_admin::types::DashboardWidget;
+	///
+	/// let admin = AdminSite::new("Admin");
+	/// admin.add_dashboard_widget(DashboardWidget::new("recent", "recent_actions"));
+	/// assert_eq!(admin.dashboard_widgets().len(), 1);
+	/// ```
+	pub fn add_dashboard_widget(&self, widget: DashboardWidget) {
+		let mut widgets = self.dashboard_widgets.write();
+		widgets.retain(|w| w.id != widget.id);
+		widgets.push(widget);
+	}
+
+	/// Replace all dashboard widgets
+	pub fn set_dashboard_widgets(&self, widgets: Vec<DashboardWidget>) {
+		*self.dashboard_widgets.write() = widgets;
+	}
+
+	/// Get the dashboard widgets in layout order: by column, then by order
+	pub fn dashboard_widgets(&self) -> Vec<DashboardWidget> {
+		let mut widgets = self.dashboard_widgets.read().clone();
+		widgets.sort_by_key(|w| (w.column, w.order));
+		widgets
+	}
+
+	/// Export the site configuration, see [`config_file`](crate::core::config_file)
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_admin::core::AdminSite;
+	///
+	/// let admin = AdminSite::new("Admin");
+	/// let toml = admin.export_config().to_toml_string().unwrap();
+	/// assert!(toml.contains("site_title"));
+	/// ```
+	pub fn export_config(&self) -> AdminConfigFile {
+		AdminConfigFile::capture(self)
+	}
+
+	/// Apply a configuration file on top of the models registered in code
+	///
+	/// See [`AdminConfigFile::apply_to`] for the validation performed.
+	pub fn apply_config(&self, config: &AdminConfigFile) -> AdminResult<()> {
+		config.apply_to(self)
+	}
+
+	/// Load a `.toml` or `.json` configuration file and apply it
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use reinhardt_admin::core::AdminSite;
+	///
+	/// let admin = AdminSite::new("Admin");
+	/// admin.load_config_file("config/admin.toml").unwrap();
+	/// ```
+	pub fn load_config_file(&self, path: impl AsRef<std::path::Path>) -> AdminResult<()> {
+		self.apply_config(&AdminConfigFile::load(path)?)
+	}
+
 	/// Configure the admin site
 	///
 	/// # Examples
@@ -286,6 +414,31 @@ impl AdminSite {
 		self.jwt_secret.as_deref()
 	}
 
+	/// Sets the scanner run over import uploads.
+	///
+	/// Import files the scanner flags, or that cannot be scanned, are
+	/// rejected before any record is created.
+	///
+	/// # Example
+	///
+	/// ```ignore
+	/// use reinhardt_admin::core::AdminSite;
+	/// use reinhardt_core::security::upload_scan::FileTypeScanner;
+	/// use std::sync::Arc;
+	///
+	/// let mut site = AdminSite::new("Admin");
+	/// site.set_upload_scanner(Arc::new(FileTypeScanner::new()));
+	/// ```
+	pub fn set_upload_scanner(&mut self, scanner: Arc<dyn UploadScanner>) -> &mut Self {
+		self.upload_scanner = Some(scanner);
+		self
+	}
+
+	/// Returns the upload scanner, if configured.
+	pub(crate) fn upload_scanner(&self) -> Option<Arc<dyn UploadScanner>> {
+		self.upload_scanner.clone()
+	}
+
 	/// Register a model with the admin site
 	///
 	/// # Examples
@@ -349,6 +502,23 @@ impl AdminSite {
 		Ok(())
 	}
 
+	/// Replace the admin of a registered model, keeping its registered name
+	pub(crate) fn replace_model_admin(
+		&self,
+		model_name: &str,
+		admin: Arc<dyn ModelAdmin>,
+	) -> AdminResult<()> {
+		let needle = model_name.to_lowercase();
+		let key = self
+			.registry
+			.iter()
+			.find(|entry| entry.key().to_lowercase() == needle)
+			.map(|entry| entry.key().clone())
+			.ok_or_else(|| AdminError::ModelNotRegistered(model_name.into()))?;
+		self.registry.insert(key, admin);
+		Ok(())
+	}
+
 	/// Check if a model is registered
 	///
 	/// # Examples
@@ -386,6 +556,42 @@ impl AdminSite {
 			.ok_or_else(|| AdminError::ModelNotRegistered(model_name.into()))
 	}
 
+	/// Find the registered model a relation points to
+	///
+	/// `target` is either a table name, as stored on foreign keys, or a model
+	/// reference such as `"auth.User"`. Returns the registered model name and
+	/// its admin.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_admin::core::{AdminSite, ModelAdminConfig};
+	///
+	/// let site = AdminSite::new("Admin");
+	/// let config = ModelAdminConfig::builder()
+	///     .model_name("User")
+	///     .table_name("auth_user")
+	///     .build()
+	///     .unwrap();
+	/// site.register("User", config).unwrap();
+	///
+	/// assert_eq!(site.find_related_model("auth_user").unwrap().0, "User");
+	/// assert_eq!(site.find_related_model("auth.User").unwrap().0, "User");
+	/// assert!(site.find_related_model("blog_post").is_none());
+	/// ```
+	pub fn find_related_model(&self, target: &str) -> Option<(String, Arc<dyn ModelAdmin>)> {
+		let model = target.rsplit('.').next().unwrap_or(target).to_lowercase();
+		self.registry
+			.iter()
+			.find(|entry| entry.value().table_name() == target)
+			.or_else(|| {
+				self.registry
+					.iter()
+					.find(|entry| entry.key().to_lowercase() == model)
+			})
+			.map(|entry| (entry.key().clone(), Arc::clone(entry.value())))
+	}
+
 	/// Get all registered model names
 	///
 	/// # Examples
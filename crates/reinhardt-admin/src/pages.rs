@@ -10,6 +10,7 @@
 //! - `components/common` - Common reusable components (buttons, modals, etc.)
 //! - `components/features` - Feature-specific components (dashboard, list, form, etc.)
 //! - `router` - Client-side routing
+//! - `headless` - Alternative client backed by the headless JSON API
 //!
 //! # Components
 //!
@@ -35,6 +36,7 @@
 mod main;
 
 pub mod components;
+pub mod headless;
 pub mod router;
 
 // Re-exports
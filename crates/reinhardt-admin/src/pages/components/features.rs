@@ -2,21 +2,30 @@
 //!
 //! Provides feature-specific UI components:
 //! - `Dashboard` - Dashboard view
+//! - `RecentActions` - Recent actions panel with undo
 //! - `ListView` - List view with filters and pagination
 //! - `DetailView` - Detail view for a single record
-//! - `ModelForm` - Form for creating/editing records
+//! - `GenericInlines` - Children attached through generic foreign keys
+//! - `ModelForm` - Form for creating/editing records, with an "add related"
+//!   pane for foreign keys
+//! - `EditLockBanner` - "Being edited by" notice with a takeover button
 //! - `Filters` - Filter panel
 //! - `DataTable` - Data table component
 
 #[cfg(client)]
-use crate::server::{create_record, delete_record, update_record};
-use crate::types::{FilterInfo, FilterType, ModelInfo};
+use crate::server::{
+	acquire_edit_lock, bulk_update_records, create_record, delete_record, get_fields,
+	release_edit_lock, undo_delete, update_record,
+};
+use crate::types::{
+	FilterInfo, FilterType, GenericInlineRows, ModelInfo, NavSection, RecentAction,
+};
 use reinhardt_pages::Signal;
 use reinhardt_pages::component::Page;
 use reinhardt_pages::page;
 use std::collections::HashMap;
 
-fn reverse_admin_url(route_name: &str, params: &[(&str, &str)]) -> String {
+pub(crate) fn reverse_admin_url(route_name: &str, params: &[(&str, &str)]) -> String {
 	crate::pages::router::try_with_router(|router| router.reverse(route_name, params))
 		.unwrap_or_else(|| crate::pages::router::init_router().reverse(route_name, params))
 		.unwrap_or_else(|err| panic!("failed to reverse admin route `{}`: {}", route_name, err))
@@ -64,6 +73,158 @@ pub fn dashboard(site_name: &str, models: &[ModelInfo]) -> Page {
 	})(site_name, grid)
 }
 
+/// Grouped dashboard component
+///
+/// Displays one heading and card grid per navigation section, as returned
+/// in `DashboardResponse::navigation`.
+///
+/// # Example
+///
+/// ```ignore
+/// use reinhardt_admin::pages::components::features::grouped_dashboard;
+///
+/// grouped_dashboard(&data.site_header, &data.navigation)
+/// ```
+pub fn grouped_dashboard(site_name: &str, sections: &[NavSection]) -> Page {
+	let site_name = site_name.to_string();
+	let section_views: Vec<Page> = sections
+		.iter()
+		.map(|section| {
+			let title = section.label.clone();
+			let cards: Vec<Page> = section
+				.items
+				.iter()
+				.map(|item| model_card(&item.label, &item.url))
+				.collect();
+
+			page!(|title: String, cards: Vec<Page>| {
+				section {
+					class: "mb-8",
+					h2 {
+						class: "font-display text-lg font-semibold text-slate-700 mb-3",
+						{ title }
+					}
+					div {
+						class: "grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-4",
+						{ cards }
+					}
+				}
+			})(title, cards)
+		})
+		.collect();
+
+	page!(|site_name: String, section_views: Vec<Page>| {
+		div {
+			class: "dashboard animate__animated animate__fadeIn",
+			h1 {
+				class: "font-display text-2xl font-bold text-slate-900 mb-6",
+				{ format!("{} Dashboard", site_name) }
+			}
+			{ section_views }
+		}
+	})(site_name, section_views)
+}
+
+/// Recent actions panel
+///
+/// Lists the entries of `DashboardResponse::recent_actions`, newest first,
+/// with an "Undo" button on deletes that can still be restored.
+///
+/// # Example
+///
+/// ```ignore
+/// use reinhardt_admin::pages::components::features::recent_actions_panel;
+///
+/// recent_actions_panel(&data.recent_actions)
+/// ```
+pub fn recent_actions_panel(actions: &[RecentAction]) -> Page {
+	let body = if actions.is_empty() {
+		page!(|| {
+			p {
+				class: "text-sm text-slate-500",
+				"No recent actions."
+			}
+		})()
+	} else {
+		let items: Vec<Page> = actions.iter().map(recent_action_item).collect();
+		page!(|items: Vec<Page>| {
+			ul {
+				class: "divide-y divide-slate-100",
+				{ items }
+			}
+		})(items)
+	};
+
+	page!(|body: Page| {
+		section {
+			class: "recent-actions admin-card p-4 mt-8",
+			aria_label: "Recent actions",
+			h2 {
+				class: "font-display text-lg font-semibold text-slate-700 mb-3",
+				"Recent actions"
+			}
+			{ body }
+		}
+	})(body)
+}
+
+/// Generates one entry of the recent actions panel
+fn recent_action_item(action: &RecentAction) -> Page {
+	let summary = match &action.record_id {
+		Some(id) => format!("{} {} #{}", action.action, action.model_name, id),
+		None => format!("{} {}", action.action, action.model_name),
+	};
+	let meta = format!("{} · {}", action.user_id, action.timestamp);
+	let restored = Signal::new(action.undone);
+	let can_undo = action.can_undo;
+	let action_id = action.id;
+	let status = Page::reactive(move || {
+		if restored.get() {
+			page!(|| {
+				span {
+					class: "admin-badge text-xs text-emerald-700",
+					"Restored"
+				}
+			})()
+		} else if can_undo {
+			let restored = restored.clone();
+			page!(|restored: Signal<bool>| {
+				button {
+					type: "button",
+					class: "admin-btn admin-btn-outline admin-btn-sm",
+					@click: move |_| {
+						#[cfg(client)]
+						crate::pages::components::features::undo_recent_delete(
+							action_id,
+							restored.clone(),
+						);
+					},
+					"Undo"
+				}
+			})(restored)
+		} else {
+			Page::Empty
+		}
+	});
+
+	page!(|summary: String, meta: String, status: Page| {
+		li {
+			class: "flex items-center justify-between gap-4 py-2",
+			div {
+				p {
+					class: "text-sm text-slate-800",
+					{ summary }
+				}
+				p {
+					class: "text-xs text-slate-500",
+					{ meta }
+				}
+			}
+			{ status }
+		}
+	})(summary, meta, status)
+}
+
 /// Generates a grid of model cards
 fn models_grid(models: &[ModelInfo]) -> Page {
 	if models.is_empty() {
@@ -124,8 +285,16 @@ pub struct Column {
 	pub label: String,
 	/// Whether this column is sortable
 	pub sortable: bool,
+	/// Input rendered in each row when the column is editable inline
+	pub editable: Option<crate::types::FormFieldSpec>,
+	/// `(value, label)` pairs; values are displayed by their label
+	pub choices: Vec<(String, String)>,
 }
 
+/// Separates the record ID from the field name in inline edit input names
+/// (`"{id}:{field}"`). Field names never contain it, so IDs may.
+const LIST_EDIT_SEPARATOR: char = ':';
+
 /// List view data structure
 #[derive(Debug, Clone)]
 pub struct ListViewData {
@@ -147,7 +316,9 @@ pub struct ListViewData {
 
 /// List view component
 ///
-/// Displays a paginated list of records with filters and search.
+/// Displays a paginated list of records with filters and search. When any
+/// column is editable, the table becomes a form whose Save button submits
+/// all rows in one request; rejected rows show their error inline.
 ///
 /// # Example
 ///
@@ -159,8 +330,8 @@ pub struct ListViewData {
 /// let data = ListViewData {
 ///     model_name: "User".to_string(),
 ///     columns: vec![
-///         Column { field: "id".to_string(), label: "ID".to_string(), sortable: true },
-///         Column { field: "username".to_string(), label: "Username".to_string(), sortable: true },
+///         Column { field: "id".to_string(), label: "ID".to_string(), sortable: true, editable: None, choices: vec![] },
+///         Column { field: "username".to_string(), label: "Username".to_string(), sortable: true, editable: None, choices: vec![] },
 ///     ],
 ///     records: vec![/* ... */],
 ///     current_page: 1,
@@ -183,7 +354,18 @@ pub fn list_view(
 		data.total_count, data.model_name, data.current_page, data.total_pages
 	);
 	let filters_page = filters(&data.filters, filters_signal);
-	let table_page = data_table(&data.columns, &data.records, &data.model_name);
+	let row_errors = Signal::new(HashMap::new());
+	let table_page = data_table(
+		&data.columns,
+		&data.records,
+		&data.model_name,
+		row_errors.clone(),
+	);
+	let table_page = if data.columns.iter().any(|c| c.editable.is_some()) {
+		list_edit_form(&data.model_name, table_page, row_errors)
+	} else {
+		table_page
+	};
 	let pagination_page =
 		crate::pages::components::common::pagination(current_page_signal, data.total_pages);
 	let add_url = admin_model_url("create", &data.model_name);
@@ -230,11 +412,46 @@ pub fn list_view(
 	)
 }
 
+/// Wraps an editable data table in a form that saves all rows at once
+fn list_edit_form(
+	model_name: &str,
+	table_page: Page,
+	row_errors: Signal<HashMap<String, String>>,
+) -> Page {
+	let submit_model = model_name.to_string();
+
+	page!(|table_page: Page, submit_model: String, row_errors: Signal<HashMap<String, String>>| {
+		form {
+			class: "list-edit-form",
+			method: "post",
+			@submit: move |event| {
+				event.prevent_default();
+				#[cfg(client)]
+				crate::pages::components::features::submit_list_edits(
+					event,
+					submit_model.clone(),
+					row_errors.clone(),
+				);
+			},
+			{ table_page }
+			div {
+				class: "mt-4 flex justify-end",
+				button {
+					class: "admin-btn admin-btn-primary",
+					type: "submit",
+					"Save"
+				}
+			}
+		}
+	})(table_page, submit_model, row_errors)
+}
+
 /// Generates a data table
 fn data_table(
 	columns: &[Column],
 	records: &[std::collections::HashMap<String, String>],
 	model_name: &str,
+	row_errors: Signal<HashMap<String, String>>,
 ) -> Page {
 	let header_cells: Vec<Page> = columns
 		.iter()
@@ -257,7 +474,7 @@ fn data_table(
 
 	let body_rows: Vec<Page> = records
 		.iter()
-		.map(|record| table_row(columns, record, model_name))
+		.map(|record| table_row(columns, record, model_name, row_errors.clone()))
 		.collect();
 
 	let tbody = page!(|body_rows: Vec<Page>| {
@@ -281,25 +498,56 @@ fn table_row(
 	columns: &[Column],
 	record: &std::collections::HashMap<String, String>,
 	model_name: &str,
+	row_errors: Signal<HashMap<String, String>>,
 ) -> Page {
+	let record_id = record.get("id").cloned().unwrap_or_else(|| "0".to_string());
 	let data_cells: Vec<Page> = columns
 		.iter()
 		.map(|col| {
-			let value = record
-				.get(&col.field)
-				.cloned()
-				.unwrap_or_else(|| "-".to_string());
-			page!(|value: String| {
-				td { { value } }
-			})(value)
+			let value = record.get(&col.field).cloned();
+			let content = match &col.editable {
+				Some(spec) => list_edit_input(&record_id, &col.field, spec, value),
+				None => {
+					let value = match value {
+						Some(value) => col
+							.choices
+							.iter()
+							.find(|(choice, _)| *choice == value)
+							.map_or(value, |(_, label)| label.clone()),
+						None => "-".to_string(),
+					};
+					page!(|value: String| {
+						span { { value } }
+					})(value)
+				}
+			};
+			page!(|content: Page| {
+				td { { content } }
+			})(content)
 		})
 		.collect();
 
-	let record_id = record.get("id").cloned().unwrap_or_else(|| "0".to_string());
 	let actions = action_buttons(model_name, &record_id);
-	let actions_cell = page!(|actions: Page| {
-		td { { actions } }
-	})(actions);
+	let error_id = record_id.clone();
+	let row_error = Page::reactive(move || match row_errors.get().get(&error_id) {
+		Some(message) => {
+			let message = message.clone();
+			page!(|message: String| {
+				p {
+					class: "admin-field-error text-xs text-red-600 mt-1",
+					role: "alert",
+					{ message }
+				}
+			})(message)
+		}
+		None => Page::Empty,
+	});
+	let actions_cell = page!(|actions: Page, row_error: Page| {
+		td {
+			{ actions }
+			{ row_error }
+		}
+	})(actions, row_error);
 
 	page!(|data_cells: Vec<Page>, actions_cell: Page| {
 		tr {
@@ -309,6 +557,96 @@ fn table_row(
 	})(data_cells, actions_cell)
 }
 
+/// Renders the inline edit input for one cell of an editable column
+fn list_edit_input(
+	record_id: &str,
+	field: &str,
+	spec: &crate::types::FormFieldSpec,
+	value: Option<String>,
+) -> Page {
+	let name = format!("{}{}{}", record_id, LIST_EDIT_SEPARATOR, field);
+	let input_id = format!("list-edit-{}-{}", record_id, field);
+	let value = value.unwrap_or_default();
+
+	// Checkboxes are always submitted, so render the current state explicitly
+	// to avoid clearing unchanged rows on save.
+	if matches!(spec, crate::types::FormFieldSpec::Input { html_type } if html_type == "checkbox") {
+		return if value == "true" {
+			page!(|input_id: String, name: String| {
+				input {
+					class: "admin-checkbox",
+					type: "checkbox",
+					id: input_id,
+					name: name,
+					checked: true,
+				}
+			})(input_id, name)
+		} else {
+			page!(|input_id: String, name: String| {
+				input {
+					class: "admin-checkbox",
+					type: "checkbox",
+					id: input_id,
+					name: name,
+				}
+			})(input_id, name)
+		};
+	}
+
+	form_element(
+		&FormField {
+			name,
+			label: field.to_string(),
+			spec: spec.clone(),
+			required: false,
+			value,
+			language: None,
+		},
+		&input_id,
+	)
+}
+
+/// Groups inline edit values named `"{id}:{field}"` into one row per record
+#[cfg(any(client, test))]
+fn group_list_edits(data: HashMap<String, serde_json::Value>) -> Vec<crate::types::BulkUpdateRow> {
+	let mut rows: std::collections::BTreeMap<String, HashMap<String, serde_json::Value>> =
+		std::collections::BTreeMap::new();
+	for (name, value) in data {
+		if let Some((id, field)) = name.rsplit_once(LIST_EDIT_SEPARATOR) {
+			rows.entry(id.to_string())
+				.or_default()
+				.insert(field.to_string(), value);
+		}
+	}
+	rows.into_iter()
+		.map(|(id, data)| crate::types::BulkUpdateRow { id, data })
+		.collect()
+}
+
+#[cfg(client)]
+fn submit_list_edits(
+	event: web_sys::Event,
+	model_name: String,
+	row_errors: Signal<HashMap<String, String>>,
+) {
+	let mutation = collect_mutation_request(&event);
+	let request = crate::types::BulkUpdateRequest {
+		csrf_token: mutation.csrf_token,
+		rows: group_list_edits(mutation.data),
+	};
+	reinhardt_pages::platform::spawn_task(async move {
+		match bulk_update_records(model_name, request).await {
+			Ok(response) => {
+				row_errors.set(response.errors);
+				if !response.success {
+					report_admin_error(&response.message);
+				}
+			}
+			Err(e) => report_admin_error(&format!("Save failed: {}", e)),
+		}
+	});
+}
+
 /// Generates action buttons for a record
 fn action_buttons(model_name: &str, record_id: &str) -> Page {
 	use reinhardt_pages::component::Component;
@@ -346,6 +684,8 @@ pub struct FormField {
 	pub required: bool,
 	/// Current field value (for edit forms)
 	pub value: String,
+	/// Language of a translated field's column, `None` for regular fields
+	pub language: Option<String>,
 }
 
 /// Detail view component
@@ -466,6 +806,234 @@ fn detail_table(record: &std::collections::HashMap<String, String>) -> Page {
 	})(rows)
 }
 
+/// Binary downloads component
+///
+/// Lists a download link for each binary field of a record
+/// (`DetailResponse::downloads`). Renders nothing when the record has no
+/// binary content.
+///
+/// # Example
+///
+/// ```ignore
+/// use reinhardt_admin::pages::components::features::downloads_panel;
+///
+/// downloads_panel(&response.downloads)
+/// ```
+pub fn downloads_panel(downloads: &HashMap<String, String>) -> Page {
+	if downloads.is_empty() {
+		return Page::Empty;
+	}
+	let mut fields: Vec<(&String, &String)> = downloads.iter().collect();
+	fields.sort();
+	let items: Vec<Page> = fields
+		.into_iter()
+		.map(|(field, url)| {
+			let label = format!("Download {}", field);
+			let url = url.clone();
+			page!(|url: String, label: String| {
+				li {
+					a {
+						class: "admin-btn admin-btn-outline admin-btn-sm",
+						href: url,
+						download: "",
+						{ label }
+					}
+				}
+			})(url, label)
+		})
+		.collect();
+	page!(|items: Vec<Page>| {
+		section {
+			class: "downloads admin-card p-4 mt-8",
+			aria_label: "Downloads",
+			h2 {
+				class: "font-display text-lg font-semibold text-slate-700 mb-3",
+				"Downloads"
+			}
+			ul {
+				class: "flex flex-wrap gap-2",
+				{ items }
+			}
+		}
+	})(items)
+}
+
+/// Generic inlines component
+///
+/// Lists the children attached to a record through generic foreign keys
+/// (`DetailResponse::inlines`), one table per inline, with a link to each
+/// child's detail view. Inlines with an upload URL also get a zone that
+/// files can be dropped on to attach them to the record.
+///
+/// # Example
+///
+/// ```ignore
+/// use reinhardt_admin::pages::components::features::generic_inlines_panel;
+///
+/// generic_inlines_panel(&response.inlines)
+/// ```
+pub fn generic_inlines_panel(inlines: &[GenericInlineRows]) -> Page {
+	let sections: Vec<Page> = inlines.iter().map(generic_inline_section).collect();
+	page!(|sections: Vec<Page>| {
+		div {
+			class: "generic-inlines",
+			{ sections }
+		}
+	})(sections)
+}
+
+/// Generates the table of one generic inline
+fn generic_inline_section(inline: &GenericInlineRows) -> Page {
+	use crate::pages::router::json_value_to_display_string;
+	use reinhardt_pages::component::Component;
+	use reinhardt_pages::router::Link;
+
+	let columns: Vec<String> = if inline.fields.is_empty() {
+		let mut columns: Vec<String> = inline
+			.rows
+			.iter()
+			.flat_map(|row| row.keys().cloned())
+			.collect();
+		columns.sort();
+		columns.dedup();
+		columns
+	} else {
+		inline.fields.clone()
+	};
+
+	let title = inline.label.clone();
+	let body = if inline.rows.is_empty() {
+		page!(|| {
+			p {
+				class: "text-sm text-slate-500",
+				"No related records."
+			}
+		})()
+	} else {
+		let header_cells: Vec<Page> = columns
+			.iter()
+			.map(|column| {
+				let label = column.clone();
+				page!(|label: String| {
+					th { { label } }
+				})(label)
+			})
+			.chain(std::iter::once(page!(|| {
+				th { "Actions" }
+			})()))
+			.collect();
+		let body_rows: Vec<Page> = inline
+			.rows
+			.iter()
+			.map(|row| {
+				let cells: Vec<Page> = columns
+					.iter()
+					.map(|column| {
+						let value = row
+							.get(column)
+							.map(json_value_to_display_string)
+							.unwrap_or_else(|| "-".to_string());
+						page!(|value: String| {
+							td { { value } }
+						})(value)
+					})
+					.collect();
+				let view_link = match row.get("id") {
+					Some(id) => Link::new(
+						admin_record_url(
+							"detail",
+							&inline.model_name,
+							&json_value_to_display_string(id),
+						),
+						"View",
+					)
+					.class("admin-btn admin-btn-outline admin-btn-sm")
+					.render(),
+					None => Page::Empty,
+				};
+				page!(|cells: Vec<Page>, view_link: Page| {
+					tr {
+						{ cells }
+						td { { view_link } }
+					}
+				})(cells, view_link)
+			})
+			.collect();
+		page!(|header_cells: Vec<Page>, body_rows: Vec<Page>| {
+			div {
+				class: "overflow-x-auto rounded-lg border border-slate-200",
+				table {
+					class: "admin-table",
+					thead {
+						tr { { header_cells } }
+					}
+					tbody { { body_rows } }
+				}
+			}
+		})(header_cells, body_rows)
+	};
+
+	let aria_label = title.clone();
+	let dropzone = match &inline.upload_url {
+		Some(upload_url) => attachment_dropzone(upload_url),
+		None => Page::Empty,
+	};
+
+	page!(|title: String, aria_label: String, body: Page, dropzone: Page| {
+		section {
+			class: "generic-inline admin-card p-4 mt-8",
+			aria_label: aria_label,
+			h2 {
+				class: "font-display text-lg font-semibold text-slate-700 mb-3",
+				{ title }
+			}
+			{ body }
+			{ dropzone }
+		}
+	})(title, aria_label, body, dropzone)
+}
+
+/// Generates the drag-and-drop upload zone of a generic inline
+///
+/// Dropped or picked files are posted one at a time to `upload_url`, and
+/// the page is reloaded once all of them are stored so that the new rows
+/// show up.
+fn attachment_dropzone(upload_url: &str) -> Page {
+	let drop_url = upload_url.to_string();
+	let pick_url = upload_url.to_string();
+
+	page!(|drop_url: String, pick_url: String| {
+		div {
+			class: "attachment-dropzone mt-4 rounded-lg border-2 border-dashed border-slate-300 p-6 text-center",
+			@dragover: move |event| {
+				event.prevent_default();
+			},
+			@drop: move |event| {
+				event.prevent_default();
+				#[cfg(client)]
+				crate::pages::components::features::upload_dropped_files(event, drop_url.clone());
+			},
+			p {
+				class: "text-sm text-slate-500 mb-2",
+				"Drop files here to attach them"
+			}
+			label {
+				class: "admin-btn admin-btn-outline admin-btn-sm",
+				"Choose files"
+				input {
+					type: "file",
+					class: "sr-only",
+					multiple: true,
+					@change: move |event| {
+						#[cfg(client)]
+						crate::pages::components::features::upload_picked_files(event, pick_url.clone());
+					},
+				}
+			}
+		}
+	})(drop_url, pick_url)
+}
+
 /// Model form component
 ///
 /// Displays a form for creating or editing a record.
@@ -483,6 +1051,7 @@ fn detail_table(record: &std::collections::HashMap<String, String>) -> Page {
 ///         spec: FormFieldSpec::Input { html_type: "text".to_string() },
 ///         required: true,
 ///         value: "".to_string(),
+///         language: None,
 ///     },
 /// ];
 /// model_form("User", &fields, None)
@@ -505,7 +1074,26 @@ pub fn model_form(model_name: &str, fields: &[FormField], record_id: Option<&str
 
 	let list_url = admin_model_url("list", model_name);
 
-	let form_fields: Vec<Page> = fields.iter().map(form_group).collect();
+	// Foreign keys the user may add to get an "add related" button; their
+	// create panes are rendered beside the form, since forms cannot nest.
+	let mut panes = Vec::new();
+	// Consecutive columns of a translated field share one set of language tabs.
+	let form_fields: Vec<Page> = fields
+		.chunk_by(|a, b| a.language.is_some() && b.language.is_some() && a.label == b.label)
+		.map(|group| {
+			let field = &group[0];
+			if field.language.is_some() {
+				return translation_tabs(group);
+			}
+			match RelatedPicker::for_field(field) {
+				Some(picker) => {
+					panes.push(related_create_pane(picker.clone()));
+					related_field_group(field, picker)
+				}
+				None => form_group(field),
+			}
+		})
+		.collect();
 	let form_groups = page!(|form_fields: Vec<Page>| {
 		div {
 			class: "admin-card p-6",
@@ -523,38 +1111,48 @@ pub fn model_form(model_name: &str, fields: &[FormField], record_id: Option<&str
 	 action_url: String,
 	 form_groups: Page,
 	 cancel_link: Page,
+	 panes: Vec<Page>,
 	 submit_model: String,
 	 submit_record_id: Option<String>,
 	 submit_return_url: String| {
 		div {
-			class: "model-form max-w-2xl animate__animated animate__fadeIn",
+			class: "model-form animate__animated animate__fadeIn",
 			h1 {
 				class: "font-display text-2xl font-bold text-slate-900 mb-6",
 				{ form_title }
 			}
-			form {
-				method: "post",
-				action: action_url,
-				@submit: move |event| {
-					event.prevent_default();
-					#[cfg(client)]
-					crate::pages::components::features::submit_model_form(
-						event,
-						submit_model.clone(),
-						submit_record_id.clone(),
-						submit_return_url.clone(),
-					);
-				},
-				{ form_groups }
-				div {
-					class: "mt-6 flex gap-2",
-					button {
-						class: "admin-btn admin-btn-primary",
-						type: "submit",
-						"Save"
+			div {
+				class: "flex flex-col lg:flex-row gap-6 items-start",
+				form {
+					class: "flex-1 max-w-2xl",
+					method: "post",
+					action: action_url,
+					@submit: move |event| {
+						event.prevent_default();
+						#[cfg(client)]
+						crate::pages::components::features::submit_model_form(
+							event,
+							submit_model.clone(),
+							submit_record_id.clone(),
+							submit_return_url.clone(),
+						);
+					},
+					fieldset {
+						id: "admin-model-form-fieldset",
+						class: "min-w-0 border-0 p-0 m-0",
+						{ form_groups }
+						div {
+							class: "mt-6 flex gap-2",
+							button {
+								class: "admin-btn admin-btn-primary",
+								type: "submit",
+								"Save"
+							}
+							{ cancel_link }
+						}
 					}
-					{ cancel_link }
 				}
+				{ panes }
 			}
 		}
 	})(
@@ -562,30 +1160,176 @@ pub fn model_form(model_name: &str, fields: &[FormField], record_id: Option<&str
 		action_url,
 		form_groups,
 		cancel_link,
+		panes,
 		submit_model,
 		submit_record_id,
 		submit_return_url,
 	)
 }
 
-#[cfg(client)]
-fn submit_model_form(
-	event: web_sys::Event,
-	model_name: String,
-	record_id: Option<String>,
-	return_url: String,
-) {
-	let request = collect_mutation_request(&event);
-	reinhardt_pages::platform::spawn_task(async move {
-		let result = if let Some(id) = record_id {
-			update_record(model_name, id, request).await
-		} else {
-			create_record(model_name, request).await
+/// Edit lock banner component
+///
+/// Shows who else is editing the record while `locked_by` is set, with a
+/// button to take the lock over. Pair it with `start_edit_lock`, which
+/// keeps `locked_by` up to date in the browser and makes [`model_form`]
+/// read-only while another user holds the lock.
+///
+/// # Example
+///
+/// ```ignore
+/// use reinhardt_admin::pages::components::features::edit_lock_banner;
+/// use reinhardt_pages::Signal;
+///
+/// let locked_by = Signal::new(Some("alice".to_string()));
+/// edit_lock_banner("Article", "42", locked_by)
+/// ```
+pub fn edit_lock_banner(
+	model_name: &str,
+	record_id: &str,
+	locked_by: Signal<Option<String>>,
+) -> Page {
+	let model_name = model_name.to_string();
+	let record_id = record_id.to_string();
+	Page::reactive(move || {
+		let Some(username) = locked_by.get() else {
+			return Page::Empty;
 		};
+		let message = format!(
+			"This {} is being edited by {}. The form is read-only until they finish.",
+			model_name, username
+		);
+		page!(|message: String,
+		 model_name: String,
+		 record_id: String,
+		 locked_by: Signal<Option<String>>| {
+			div {
+				class: "admin-card p-4 mb-6 border-l-4 border-amber-500 bg-amber-50 flex items-center justify-between gap-4",
+				p {
+					class: "text-sm text-amber-900",
+					{ message }
+				}
+				button {
+					type: "button",
+					class: "admin-btn admin-btn-outline admin-btn-sm",
+					@click: move |_| {
+						#[cfg(client)]
+						crate::pages::components::features::take_over_edit_lock(
+							model_name.clone(),
+							record_id.clone(),
+							locked_by.clone(),
+						);
+					},
+					"Take over"
+				}
+			}
+		})(
+			message,
+			model_name.clone(),
+			record_id.clone(),
+			locked_by.clone(),
+		)
+	})
+}
 
-		match result {
-			Ok(_) => navigate_or_set_href(&return_url),
-			Err(e) => report_admin_error(&format!("Save failed: {}", e)),
+/// ID of the fieldset wrapping the controls of [`model_form`]
+#[cfg(client)]
+const MODEL_FORM_FIELDSET_ID: &str = "admin-model-form-fieldset";
+
+/// Takes the edit lock on a record and keeps it alive while the form is open
+///
+/// Renews the lock every `heartbeat_secs`, sets `locked_by` to the user
+/// holding it when it is someone else and disables the form controls in
+/// the meantime. Once the form leaves the page, the lock is released.
+#[cfg(client)]
+pub fn start_edit_lock(model_name: String, record_id: String, locked_by: Signal<Option<String>>) {
+	reinhardt_pages::platform::spawn_task(async move {
+		loop {
+			let csrf_token = reinhardt_pages::csrf::get_csrf_token().unwrap_or_default();
+			let lock =
+				match acquire_edit_lock(model_name.clone(), record_id.clone(), false, csrf_token)
+					.await
+				{
+					Ok(lock) => lock,
+					Err(e) => {
+						web_sys::console::error_1(&format!("Edit lock failed: {}", e).into());
+						return;
+					}
+				};
+			set_model_form_read_only(!lock.acquired);
+			locked_by.set(lock.locked_by);
+
+			sleep_secs(lock.heartbeat_secs).await;
+			let form_open = web_sys::window()
+				.and_then(|w| w.document())
+				.and_then(|d| d.get_element_by_id(MODEL_FORM_FIELDSET_ID))
+				.is_some();
+			if !form_open {
+				let csrf_token = reinhardt_pages::csrf::get_csrf_token().unwrap_or_default();
+				let _ = release_edit_lock(model_name, record_id, csrf_token).await;
+				return;
+			}
+		}
+	});
+}
+
+#[cfg(client)]
+fn take_over_edit_lock(model_name: String, record_id: String, locked_by: Signal<Option<String>>) {
+	let csrf_token = reinhardt_pages::csrf::get_csrf_token().unwrap_or_default();
+	reinhardt_pages::platform::spawn_task(async move {
+		match acquire_edit_lock(model_name, record_id, true, csrf_token).await {
+			Ok(lock) => {
+				set_model_form_read_only(!lock.acquired);
+				locked_by.set(lock.locked_by);
+			}
+			Err(e) => report_admin_error(&format!("Takeover failed: {}", e)),
+		}
+	});
+}
+
+#[cfg(client)]
+fn set_model_form_read_only(read_only: bool) {
+	let Some(fieldset) = web_sys::window()
+		.and_then(|w| w.document())
+		.and_then(|d| d.get_element_by_id(MODEL_FORM_FIELDSET_ID))
+	else {
+		return;
+	};
+	if read_only {
+		let _ = fieldset.set_attribute("disabled", "");
+	} else {
+		let _ = fieldset.remove_attribute("disabled");
+	}
+}
+
+#[cfg(client)]
+async fn sleep_secs(secs: u64) {
+	let ms = i32::try_from(secs.saturating_mul(1000)).unwrap_or(i32::MAX);
+	let promise = js_sys::Promise::new(&mut |resolve, _| {
+		if let Some(window) = web_sys::window() {
+			let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms);
+		}
+	});
+	let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+#[cfg(client)]
+fn submit_model_form(
+	event: web_sys::Event,
+	model_name: String,
+	record_id: Option<String>,
+	return_url: String,
+) {
+	let request = collect_mutation_request(&event);
+	reinhardt_pages::platform::spawn_task(async move {
+		let result = if let Some(id) = record_id {
+			update_record(model_name, id, request).await
+		} else {
+			create_record(model_name, request).await
+		};
+
+		match result {
+			Ok(_) => navigate_or_set_href(&return_url),
+			Err(e) => report_admin_error(&format!("Save failed: {}", e)),
 		}
 	});
 }
@@ -609,7 +1353,169 @@ fn delete_model_record(model_name: String, record_id: String, return_url: String
 }
 
 #[cfg(client)]
-fn collect_mutation_request(event: &web_sys::Event) -> crate::types::MutationRequest {
+fn undo_recent_delete(action_id: u64, restored: Signal<bool>) {
+	let csrf_token = reinhardt_pages::csrf::get_csrf_token().unwrap_or_default();
+	reinhardt_pages::platform::spawn_task(async move {
+		match undo_delete(action_id, csrf_token).await {
+			Ok(_) => restored.set(true),
+			Err(e) => report_admin_error(&format!("Undo failed: {}", e)),
+		}
+	});
+}
+
+#[cfg(client)]
+fn upload_dropped_files(event: web_sys::Event, upload_url: String) {
+	use wasm_bindgen::JsCast;
+
+	let files = event
+		.dyn_into::<web_sys::DragEvent>()
+		.ok()
+		.and_then(|event| event.data_transfer())
+		.and_then(|transfer| transfer.files());
+	if let Some(files) = files {
+		upload_attachment_files(files, upload_url);
+	}
+}
+
+#[cfg(client)]
+fn upload_picked_files(event: web_sys::Event, upload_url: String) {
+	use wasm_bindgen::JsCast;
+
+	let files = event
+		.target()
+		.and_then(|target| target.dyn_into::<web_sys::HtmlInputElement>().ok())
+		.and_then(|input| input.files());
+	if let Some(files) = files {
+		upload_attachment_files(files, upload_url);
+	}
+}
+
+#[cfg(client)]
+fn upload_attachment_files(files: web_sys::FileList, upload_url: String) {
+	let files: Vec<web_sys::File> = (0..files.length())
+		.filter_map(|index| files.item(index))
+		.collect();
+	if files.is_empty() {
+		return;
+	}
+
+	let csrf_token = reinhardt_pages::csrf::get_csrf_token().unwrap_or_default();
+	reinhardt_pages::platform::spawn_task(async move {
+		let client = reqwest::Client::new();
+		for file in files {
+			let name = file.name();
+			let content = match wasm_bindgen_futures::JsFuture::from(file.array_buffer()).await {
+				Ok(buffer) => js_sys::Uint8Array::new(&buffer).to_vec(),
+				Err(_) => {
+					report_admin_error(&format!("Upload failed: could not read {}", name));
+					return;
+				}
+			};
+			let content_type = match file.type_() {
+				content_type if content_type.is_empty() => "application/octet-stream".to_string(),
+				content_type => content_type,
+			};
+			let disposition = format!(
+				"attachment; filename*=UTF-8''{}",
+				String::from(js_sys::encode_uri_component(&name))
+			);
+			let result = client
+				.post(&upload_url)
+				.fetch_credentials_include()
+				.header("Content-Disposition", disposition)
+				.header("Content-Type", content_type)
+				.header(crate::server::security::PAGES_CSRF_HEADER_NAME, &csrf_token)
+				.body(content)
+				.send()
+				.await;
+			match result {
+				Ok(response) if response.status().is_success() => {}
+				Ok(response) => {
+					report_admin_error(&format!(
+						"Upload of {} failed: {}",
+						name,
+						response.status()
+					));
+					return;
+				}
+				Err(e) => {
+					report_admin_error(&format!("Upload of {} failed: {}", name, e));
+					return;
+				}
+			}
+		}
+		if let Some(window) = web_sys::window() {
+			let _ = window.location().reload();
+		}
+	});
+}
+
+#[cfg(client)]
+fn open_related_pane(picker: RelatedPicker) {
+	reinhardt_pages::platform::spawn_task(async move {
+		match get_fields(picker.related_model.clone(), None).await {
+			Ok(response) => {
+				let fields = response
+					.fields
+					.into_iter()
+					.filter(|field| !field.readonly)
+					.map(|field| FormField {
+						spec: crate::types::FormFieldSpec::from(&field.field_type),
+						name: field.name,
+						label: field.label,
+						required: field.required,
+						value: String::new(),
+						language: field.language,
+					})
+					.collect();
+				picker.pane_fields.set(Some(fields));
+			}
+			Err(e) => report_admin_error(&format!(
+				"Failed to load {} form: {}",
+				picker.related_model, e
+			)),
+		}
+	});
+}
+
+#[cfg(client)]
+fn submit_related_create(event: web_sys::Event, picker: RelatedPicker) {
+	use crate::pages::router::json_value_to_display_string;
+
+	let request = collect_mutation_request(&event);
+	let label = picker
+		.label_field
+		.as_ref()
+		.and_then(|field| request.data.get(field))
+		.map(json_value_to_display_string);
+	reinhardt_pages::platform::spawn_task(async move {
+		match create_record(picker.related_model.clone(), request).await {
+			Ok(response) => {
+				let Some(pk) = response
+					.data
+					.as_ref()
+					.and_then(|data| data.values().next())
+					.map(json_value_to_display_string)
+				else {
+					report_admin_error("Save failed: the new record has no primary key");
+					return;
+				};
+				let label = label
+					.filter(|l| !l.is_empty())
+					.unwrap_or_else(|| pk.clone());
+				picker
+					.choices
+					.update(|choices| choices.push((pk.clone(), label)));
+				picker.selected.set(pk);
+				picker.pane_fields.set(None);
+			}
+			Err(e) => report_admin_error(&format!("Save failed: {}", e)),
+		}
+	});
+}
+
+#[cfg(client)]
+pub(crate) fn collect_mutation_request(event: &web_sys::Event) -> crate::types::MutationRequest {
 	use wasm_bindgen::JsCast;
 
 	let mut data = HashMap::new();
@@ -718,7 +1624,7 @@ fn form_value_to_json(name: &str, value: &str, prefer_number: bool) -> serde_jso
 }
 
 #[cfg(client)]
-fn navigate_or_set_href(url: &str) {
+pub(crate) fn navigate_or_set_href(url: &str) {
 	if reinhardt_pages::navigate(url.to_string(), reinhardt_pages::NavigationType::Push).is_err()
 		&& let Some(window) = web_sys::window()
 	{
@@ -727,7 +1633,7 @@ fn navigate_or_set_href(url: &str) {
 }
 
 #[cfg(client)]
-fn report_admin_error(message: &str) {
+pub(crate) fn report_admin_error(message: &str) {
 	web_sys::console::error_1(&message.into());
 	if let Some(window) = web_sys::window() {
 		let _ = window.alert_with_message(message);
@@ -735,8 +1641,13 @@ fn report_admin_error(message: &str) {
 }
 
 /// Generates a form group (label + input) for a field
-fn form_group(field: &FormField) -> Page {
-	let input_id = format!("field-{}", field.name);
+pub(crate) fn form_group(field: &FormField) -> Page {
+	labeled_form_group(field, &format!("field-{}", field.name))
+}
+
+/// Generates a form group whose input uses the given element ID
+fn labeled_form_group(field: &FormField, input_id: &str) -> Page {
+	let input_id = input_id.to_string();
 	let label = field.label.clone();
 	let input = form_element(field, &input_id);
 
@@ -753,6 +1664,291 @@ fn form_group(field: &FormField) -> Page {
 	})(input_id, label, input)
 }
 
+/// Element ID prefix of a translated field's tab and panel for `language`
+fn translation_tab_id(field: &str, language: &str) -> String {
+	format!("translation-{}-{}", field, language)
+}
+
+fn translation_tab_class(selected: bool) -> &'static str {
+	if selected {
+		"admin-btn admin-btn-primary admin-btn-sm"
+	} else {
+		"admin-btn admin-btn-outline admin-btn-sm"
+	}
+}
+
+fn translation_panel_class(selected: bool) -> &'static str {
+	if selected {
+		"translation-panel"
+	} else {
+		"translation-panel hidden"
+	}
+}
+
+/// Renders the columns of a translated field as one tab per language
+///
+/// The first column is the default language's. Hidden panels stay in the
+/// form so that every language is submitted on save.
+fn translation_tabs(fields: &[FormField]) -> Page {
+	let group = fields[0].name.clone();
+	let label = fields[0].label.clone();
+	let languages: Vec<String> = fields
+		.iter()
+		.filter_map(|field| field.language.clone())
+		.collect();
+
+	let tabs: Vec<Page> = languages
+		.iter()
+		.enumerate()
+		.map(|(index, language)| {
+			let tab_id = format!("{}-tab", translation_tab_id(&group, language));
+			let class = translation_tab_class(index == 0).to_string();
+			let text = language.clone();
+			let group = group.clone();
+			let language = language.clone();
+			let languages = languages.clone();
+			page!(|tab_id: String,
+			 class: String,
+			 text: String,
+			 group: String,
+			 language: String,
+			 languages: Vec<String>| {
+				button {
+					id: tab_id,
+					class: class,
+					type: "button",
+					role: "tab",
+					@click: move |_| {
+						#[cfg(client)]
+						crate::pages::components::features::show_translation_tab(
+							&group,
+							&languages,
+							&language,
+						);
+					},
+					{ text }
+				}
+			})(tab_id, class, text, group, language, languages)
+		})
+		.collect();
+
+	let panels: Vec<Page> = fields
+		.iter()
+		.enumerate()
+		.map(|(index, field)| {
+			let language = field.language.as_deref().unwrap_or_default();
+			let panel_id = format!("{}-panel", translation_tab_id(&group, language));
+			let class = translation_panel_class(index == 0).to_string();
+			let input = labeled_form_group(field, &format!("field-{}", field.name));
+			page!(|panel_id: String, class: String, input: Page| {
+				div {
+					id: panel_id,
+					class: class,
+					role: "tabpanel",
+					{ input }
+				}
+			})(panel_id, class, input)
+		})
+		.collect();
+
+	page!(|label: String, tabs: Vec<Page>, panels: Vec<Page>| {
+		div {
+			class: "translation-tabs mb-4",
+			div {
+				class: "flex gap-1 mb-2",
+				role: "tablist",
+				aria_label: label,
+				{ tabs }
+			}
+			{ panels }
+		}
+	})(label, tabs, panels)
+}
+
+/// Shows the panel of `active` and hides the other languages of `group`
+#[cfg(client)]
+fn show_translation_tab(group: &str, languages: &[String], active: &str) {
+	let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+		return;
+	};
+	for language in languages {
+		let selected = language == active;
+		let prefix = translation_tab_id(group, language);
+		if let Some(tab) = document.get_element_by_id(&format!("{}-tab", prefix)) {
+			let _ = tab.set_attribute("class", translation_tab_class(selected));
+		}
+		if let Some(panel) = document.get_element_by_id(&format!("{}-panel", prefix)) {
+			let _ = panel.set_attribute("class", translation_panel_class(selected));
+		}
+	}
+}
+
+/// Label of the empty option of foreign key selects
+const EMPTY_CHOICE_LABEL: &str = "---------";
+
+/// State shared by a foreign key select and its "add related" pane
+#[derive(Clone)]
+struct RelatedPicker {
+	input_id: String,
+	name: String,
+	required: bool,
+	related_model: String,
+	label_field: Option<String>,
+	choices: Signal<Vec<(String, String)>>,
+	selected: Signal<String>,
+	/// Fields of the related model's create form; `Some` while the pane is open
+	pane_fields: Signal<Option<Vec<FormField>>>,
+}
+
+impl RelatedPicker {
+	/// Returns the picker for foreign key fields that allow adding records
+	fn for_field(field: &FormField) -> Option<Self> {
+		let crate::types::FormFieldSpec::ForeignKey {
+			related_model,
+			choices,
+			label_field,
+			can_add: true,
+		} = &field.spec
+		else {
+			return None;
+		};
+		Some(Self {
+			input_id: format!("field-{}", field.name),
+			name: field.name.clone(),
+			required: field.required,
+			related_model: related_model.clone(),
+			label_field: label_field.clone(),
+			choices: Signal::new(choices.clone()),
+			selected: Signal::new(field.value.clone()),
+			pane_fields: Signal::new(None),
+		})
+	}
+}
+
+/// Generates a foreign key form group with an "add related" button
+fn related_field_group(field: &FormField, picker: RelatedPicker) -> Page {
+	let input_id = picker.input_id.clone();
+	let label = field.label.clone();
+	let select = Page::reactive({
+		let picker = picker.clone();
+		move || {
+			let options = render_option_elements(
+				&with_empty_choice(&picker.choices.get()),
+				&[picker.selected.get().as_str()],
+			);
+			render_select(
+				picker.input_id.clone(),
+				picker.name.clone(),
+				options,
+				picker.required,
+			)
+		}
+	});
+	let add_title = format!("Add another {}", picker.related_model);
+	let add_aria_label = add_title.clone();
+
+	page!(|input_id: String,
+	 label: String,
+	 select: Page,
+	 add_title: String,
+	 add_aria_label: String,
+	 picker: RelatedPicker| {
+		div {
+			class: "mb-4",
+			label {
+				for: input_id,
+				class: "admin-label",
+				{ label }
+			}
+			div {
+				class: "flex items-center gap-2",
+				{ select }
+				button {
+					type: "button",
+					class: "admin-btn admin-btn-outline admin-btn-sm related-add",
+					title: add_title,
+					aria_label: add_aria_label,
+					@click: move |_| {
+						#[cfg(client)]
+						crate::pages::components::features::open_related_pane(picker.clone());
+					},
+					"+"
+				}
+			}
+		}
+	})(input_id, label, select, add_title, add_aria_label, picker)
+}
+
+/// Generates the slide-over pane used to create a related record
+///
+/// The pane is shown while `pane_fields` holds the related model's fields.
+/// Saving selects the new record in the originating select.
+fn related_create_pane(picker: RelatedPicker) -> Page {
+	Page::reactive(move || match picker.pane_fields.get() {
+		Some(fields) => related_pane_view(&picker, &fields),
+		None => Page::Empty,
+	})
+}
+
+fn related_pane_view(picker: &RelatedPicker, fields: &[FormField]) -> Page {
+	let title = format!("Add {}", picker.related_model);
+	let dialog_label = title.clone();
+	let groups: Vec<Page> = fields
+		.iter()
+		.map(|field| {
+			labeled_form_group(
+				field,
+				&format!("{}-related-{}", picker.input_id, field.name),
+			)
+		})
+		.collect();
+	let submit_picker = picker.clone();
+	let close_picker = picker.clone();
+
+	page!(|title: String,
+	 dialog_label: String,
+	 groups: Vec<Page>,
+	 submit_picker: RelatedPicker,
+	 close_picker: RelatedPicker| {
+		aside {
+			class: "related-pane admin-card p-6 w-full lg:w-96 animate__animated animate__slideInRight",
+			role: "dialog",
+			aria_label: dialog_label,
+			h2 {
+				class: "font-display text-lg font-semibold text-slate-700 mb-4",
+				{ title }
+			}
+			form {
+				@submit: move |event| {
+					event.prevent_default();
+					#[cfg(client)]
+					crate::pages::components::features::submit_related_create(
+						event,
+						submit_picker.clone(),
+					);
+				},
+				{ groups }
+				div {
+					class: "mt-6 flex gap-2",
+					button {
+						class: "admin-btn admin-btn-primary",
+						type: "submit",
+						"Save"
+					}
+					button {
+						class: "admin-btn admin-btn-secondary",
+						type: "button",
+						@click: move |_| {
+							close_picker.pane_fields.set(None);
+						},
+						"Cancel"
+					}
+				}
+			}
+		}
+	})(title, dialog_label, groups, submit_picker, close_picker)
+}
+
 /// Render `<option>` elements for a list of `(value, label)` choices,
 /// marking each option whose value appears in `selected` as `selected`.
 ///
@@ -839,26 +2035,11 @@ fn form_element(field: &FormField, input_id: &str) -> Page {
 		}
 		FormFieldSpec::Select { choices } => {
 			let options = render_option_elements(choices, &[value.as_str()]);
-			if required {
-				page!(|input_id: String, name: String, options: Vec<Page>| {
-					select {
-						class: "admin-select",
-						id: input_id,
-						name: name,
-						required: true,
-						{ options }
-					}
-				})(input_id, name, options)
-			} else {
-				page!(|input_id: String, name: String, options: Vec<Page>| {
-					select {
-						class: "admin-select",
-						id: input_id,
-						name: name,
-						{ options }
-					}
-				})(input_id, name, options)
-			}
+			render_select(input_id, name, options, required)
+		}
+		FormFieldSpec::ForeignKey { choices, .. } => {
+			let options = render_option_elements(&with_empty_choice(choices), &[value.as_str()]);
+			render_select(input_id, name, options, required)
 		}
 		FormFieldSpec::MultiSelect { choices } => {
 			let selected = parse_multi_value(&value);
@@ -889,6 +2070,37 @@ fn form_element(field: &FormField, input_id: &str) -> Page {
 	}
 }
 
+/// Render a single-value `<select>` element with pre-rendered options.
+fn render_select(input_id: String, name: String, options: Vec<Page>, required: bool) -> Page {
+	if required {
+		page!(|input_id: String, name: String, options: Vec<Page>| {
+			select {
+				class: "admin-select",
+				id: input_id,
+				name: name,
+				required: true,
+				{ options }
+			}
+		})(input_id, name, options)
+	} else {
+		page!(|input_id: String, name: String, options: Vec<Page>| {
+			select {
+				class: "admin-select",
+				id: input_id,
+				name: name,
+				{ options }
+			}
+		})(input_id, name, options)
+	}
+}
+
+/// Prepends the empty "no selection" option used by foreign key selects.
+fn with_empty_choice(choices: &[(String, String)]) -> Vec<(String, String)> {
+	std::iter::once((String::new(), EMPTY_CHOICE_LABEL.to_string()))
+		.chain(choices.iter().cloned())
+		.collect()
+}
+
 /// Render an `<input>` element with the given HTML `type`.
 fn render_input(
 	html_type: String,
@@ -1116,7 +2328,13 @@ pub fn filters(
 
 #[cfg(all(test, server))]
 mod tests {
-	use super::{detail_table, form_value_to_json, form_values_to_json_array};
+	use super::{
+		Column, FormField, ListViewData, detail_table, downloads_panel, form_value_to_json,
+		form_values_to_json_array, generic_inlines_panel, group_list_edits, list_view, model_form,
+		recent_actions_panel,
+	};
+	use crate::types::{FormFieldSpec, GenericInlineRows, RecentAction};
+	use reinhardt_pages::Signal;
 	use rstest::rstest;
 	use serde_json::json;
 	use std::collections::HashMap;
@@ -1200,4 +2418,286 @@ mod tests {
 			json!(["read", "write", "delete"])
 		);
 	}
+
+	fn editable_list_data() -> ListViewData {
+		let mut record = HashMap::new();
+		record.insert("id".to_string(), "1".to_string());
+		record.insert("name".to_string(), "Alice".to_string());
+		record.insert("is_active".to_string(), "true".to_string());
+
+		ListViewData {
+			model_name: "User".to_string(),
+			columns: vec![
+				Column {
+					field: "id".to_string(),
+					label: "ID".to_string(),
+					sortable: true,
+					editable: None,
+					choices: vec![],
+				},
+				Column {
+					field: "name".to_string(),
+					label: "Name".to_string(),
+					sortable: true,
+					editable: Some(FormFieldSpec::Input {
+						html_type: "text".to_string(),
+					}),
+					choices: vec![],
+				},
+				Column {
+					field: "is_active".to_string(),
+					label: "Is Active".to_string(),
+					sortable: true,
+					editable: Some(FormFieldSpec::Input {
+						html_type: "checkbox".to_string(),
+					}),
+					choices: vec![],
+				},
+			],
+			records: vec![record],
+			current_page: 1,
+			total_pages: 1,
+			total_count: 1,
+			filters: vec![],
+		}
+	}
+
+	#[rstest]
+	fn test_list_view_renders_inline_edit_inputs() {
+		// Arrange
+		let data = editable_list_data();
+
+		// Act
+		let html =
+			list_view(&data, Signal::new(1u64), Signal::new(HashMap::new())).render_to_string();
+
+		// Assert
+		assert!(html.contains("list-edit-form"));
+		assert!(html.contains("name=\"1:name\""));
+		assert!(html.contains("value=\"Alice\""));
+		assert!(html.contains("name=\"1:is_active\""));
+		assert!(html.contains("checked"));
+		assert!(!html.contains("name=\"1:id\""));
+	}
+
+	#[rstest]
+	fn test_list_view_without_editable_columns_has_no_form() {
+		// Arrange
+		let mut data = editable_list_data();
+		for column in &mut data.columns {
+			column.editable = None;
+		}
+
+		// Act
+		let html =
+			list_view(&data, Signal::new(1u64), Signal::new(HashMap::new())).render_to_string();
+
+		// Assert
+		assert!(!html.contains("list-edit-form"));
+		assert!(html.contains("Alice"));
+	}
+
+	#[rstest]
+	fn test_group_list_edits_splits_rows_by_id() {
+		// Arrange
+		let data = HashMap::from([
+			("1:name".to_string(), json!("Alice")),
+			("1:is_active".to_string(), json!(true)),
+			("a:b:name".to_string(), json!("Bob")),
+			("ignored".to_string(), json!("x")),
+		]);
+
+		// Act
+		let rows = group_list_edits(data);
+
+		// Assert
+		assert_eq!(rows.len(), 2);
+		assert_eq!(rows[0].id, "1");
+		assert_eq!(rows[0].data.get("name"), Some(&json!("Alice")));
+		assert_eq!(rows[0].data.get("is_active"), Some(&json!(true)));
+		assert_eq!(rows[1].id, "a:b");
+		assert_eq!(rows[1].data.get("name"), Some(&json!("Bob")));
+	}
+
+	fn recent_action(id: u64, action: &str, can_undo: bool) -> RecentAction {
+		RecentAction {
+			id,
+			timestamp: "2024-01-01T00:00:00Z".to_string(),
+			user_id: "user-42".to_string(),
+			action: action.to_string(),
+			model_name: "Article".to_string(),
+			record_id: Some(id.to_string()),
+			undone: false,
+			can_undo,
+		}
+	}
+
+	#[rstest]
+	fn test_recent_actions_panel_shows_undo_only_for_undoable_actions() {
+		// Arrange
+		let actions = vec![
+			recent_action(2, "DELETE", true),
+			recent_action(1, "UPDATE", false),
+		];
+
+		// Act
+		let html = recent_actions_panel(&actions).render_to_string();
+
+		// Assert
+		assert!(html.contains("DELETE Article #2"));
+		assert!(html.contains("UPDATE Article #1"));
+		assert_eq!(html.matches("Undo").count(), 1);
+	}
+
+	#[rstest]
+	fn test_recent_actions_panel_empty_state() {
+		// Act
+		let html = recent_actions_panel(&[]).render_to_string();
+
+		// Assert
+		assert!(html.contains("No recent actions."));
+		assert!(!html.contains("<ul"));
+	}
+
+	#[rstest]
+	fn test_generic_inlines_panel_renders_rows_and_empty_state() {
+		// Arrange
+		let inlines = vec![
+			GenericInlineRows {
+				model_name: "Comment".to_string(),
+				label: "Comments".to_string(),
+				fields: vec!["body".to_string()],
+				rows: vec![
+					[("body".to_string(), json!("Nice post"))]
+						.into_iter()
+						.collect(),
+				],
+				upload_url: None,
+			},
+			GenericInlineRows {
+				model_name: "Attachment".to_string(),
+				label: "Attachments".to_string(),
+				fields: Vec::new(),
+				rows: Vec::new(),
+				upload_url: Some("/api/attachments/blog/Post/1".to_string()),
+			},
+		];
+
+		// Act
+		let html = generic_inlines_panel(&inlines).render_to_string();
+
+		// Assert
+		assert!(html.contains("Comments"));
+		assert!(html.contains("Nice post"));
+		assert!(html.contains("Attachments"));
+		assert!(html.contains("No related records."));
+		assert_eq!(html.matches("attachment-dropzone").count(), 1);
+	}
+
+	#[rstest]
+	fn test_downloads_panel_links_binary_fields() {
+		// Arrange
+		let downloads: HashMap<String, String> = [(
+			"avatar".to_string(),
+			"/admin/api/user/1/download/avatar/".to_string(),
+		)]
+		.into_iter()
+		.collect();
+
+		// Act
+		let html = downloads_panel(&downloads).render_to_string();
+		let empty = downloads_panel(&HashMap::new()).render_to_string();
+
+		// Assert
+		assert!(html.contains("href=\"/admin/api/user/1/download/avatar/\""));
+		assert!(html.contains("Download avatar"));
+		assert!(empty.is_empty());
+	}
+
+	fn author_field(can_add: bool) -> FormField {
+		FormField {
+			name: "author_id".to_string(),
+			label: "Author".to_string(),
+			spec: FormFieldSpec::ForeignKey {
+				related_model: "User".to_string(),
+				choices: vec![
+					("1".to_string(), "alice".to_string()),
+					("2".to_string(), "bob".to_string()),
+				],
+				label_field: Some("username".to_string()),
+				can_add,
+			},
+			required: false,
+			value: "2".to_string(),
+			language: None,
+		}
+	}
+
+	#[rstest]
+	fn test_model_form_foreign_key_renders_add_related_button() {
+		// Arrange
+		let fields = vec![author_field(true)];
+
+		// Act
+		let html = model_form("Post", &fields, None).render_to_string();
+
+		// Assert
+		assert!(html.contains("id=\"field-author_id\""));
+		assert!(html.contains("---------"));
+		assert!(html.contains("alice"));
+		assert!(html.contains("Add another User"));
+		assert!(html.contains("related-add"));
+	}
+
+	#[rstest]
+	fn test_model_form_foreign_key_without_add_permission_has_no_button() {
+		// Arrange
+		let fields = vec![author_field(false)];
+
+		// Act
+		let html = model_form("Post", &fields, None).render_to_string();
+
+		// Assert
+		assert!(html.contains("id=\"field-author_id\""));
+		assert!(html.contains("bob"));
+		assert!(!html.contains("related-add"));
+	}
+
+	#[rstest]
+	fn test_related_pane_hidden_until_opened() {
+		// Arrange
+		let fields = vec![author_field(true)];
+
+		// Act
+		let html = model_form("Post", &fields, None).render_to_string();
+
+		// Assert
+		assert!(!html.contains("related-pane"));
+	}
+
+	#[rstest]
+	fn test_model_form_groups_translated_columns_into_tabs() {
+		// Arrange
+		let field = |name: &str, language: &str| FormField {
+			name: name.to_string(),
+			label: "Title".to_string(),
+			spec: FormFieldSpec::Input {
+				html_type: "text".to_string(),
+			},
+			required: false,
+			value: String::new(),
+			language: Some(language.to_string()),
+		};
+		let fields = vec![field("title", "en"), field("title_ja", "ja")];
+
+		// Act
+		let html = model_form("Article", &fields, None).render_to_string();
+
+		// Assert
+		assert!(html.contains("id=\"translation-title-en-tab\""));
+		assert!(html.contains("id=\"translation-title-ja-tab\""));
+		assert!(html.contains("name=\"title_ja\""));
+		assert!(html.contains("translation-panel hidden"));
+		assert_eq!(html.matches("role=\"tabpanel\"").count(), 2);
+	}
 }
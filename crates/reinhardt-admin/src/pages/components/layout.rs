@@ -3,9 +3,12 @@
 //! Provides layout components for the admin panel:
 //! - `Header` - Top navigation bar
 //! - `Sidebar` - Side navigation menu
+//! - `SidebarSections` - Side navigation menu grouped into sections
+//! - `Breadcrumbs` - Trail of links to the current page
 //! - `Footer` - Footer component
 //! - `MainLayout` - Main layout wrapper
 
+use crate::types::{Breadcrumb, NavSection};
 use reinhardt_pages::component::Page;
 use reinhardt_pages::page;
 
@@ -129,6 +132,148 @@ pub fn sidebar(models: &[ModelInfo], current_path: Option<&str>) -> Page {
 	})(nav_items)
 }
 
+/// Grouped sidebar component
+///
+/// Displays the side navigation menu built from the sections returned by
+/// `AdminSite::navigation_for`, one heading per section.
+///
+/// # Example
+///
+/// ```ignore
+/// use reinhardt_admin::pages::components::layout::sidebar_sections;
+///
+/// sidebar_sections(&dashboard.navigation, Some("/admin/post/"))
+/// ```
+pub fn sidebar_sections(sections: &[NavSection], current_path: Option<&str>) -> Page {
+	use reinhardt_pages::component::Component;
+	use reinhardt_pages::router::Link;
+
+	let section_views: Vec<Page> = sections
+		.iter()
+		.map(|section| {
+			let title = match &section.icon {
+				Some(icon) => format!("{} {}", icon, section.label),
+				None => section.label.clone(),
+			};
+			let items: Vec<Page> = section
+				.items
+				.iter()
+				.map(|item| {
+					let item_class = if is_active_path(&item.url, current_path) {
+						"block px-4 py-2.5 text-sm no-underline border-l-3 border-transparent admin-nav-active"
+					} else {
+						"block px-4 py-2.5 text-sm text-slate-400 no-underline border-l-3 border-transparent hover:text-white hover:bg-slate-800"
+					};
+					let link = Link::new(item.url.clone(), item.label.clone())
+						.class(item_class)
+						.render();
+
+					page!(|link: Page| {
+						li {
+							class: "list-none",
+							{ link }
+						}
+					})(link)
+				})
+				.collect();
+
+			page!(|title: String, items: Vec<Page>| {
+				div {
+					class: "mb-4",
+					div {
+						class: "px-4 pb-2 mb-1 border-b border-slate-800",
+						span {
+							class: "text-xs font-semibold uppercase tracking-wider text-slate-500",
+							{ title }
+						}
+					}
+					ul {
+						class: "flex flex-col gap-0.5 px-0 m-0",
+						{ items }
+					}
+				}
+			})(title, items)
+		})
+		.collect();
+
+	page!(|section_views: Vec<Page>| {
+		div {
+			class: "admin-sidebar bg-slate-900 border-r border-slate-800 animate__animated animate__fadeInLeft",
+			style: "width: 240px; height: 100vh; position: fixed; top: 56px; left: 0; overflow-y: auto; padding-top: 1rem;",
+			{ section_views }
+		}
+	})(section_views)
+}
+
+/// Breadcrumbs component
+///
+/// Renders the trail as links separated by `›`. Entries without a URL
+/// (normally the current page) are rendered as plain text.
+///
+/// # Example
+///
+/// ```ignore
+/// use reinhardt_admin::pages::components::layout::breadcrumbs;
+/// use reinhardt_admin::pages::router::breadcrumbs_for_path;
+///
+/// breadcrumbs(&breadcrumbs_for_path("/admin/users/42/change/"))
+/// ```
+pub fn breadcrumbs(trail: &[Breadcrumb]) -> Page {
+	use reinhardt_pages::component::Component;
+	use reinhardt_pages::router::Link;
+
+	if trail.is_empty() {
+		return Page::Empty;
+	}
+
+	let last = trail.len() - 1;
+	let entries: Vec<Page> = trail
+		.iter()
+		.enumerate()
+		.map(|(index, crumb)| {
+			let content = match &crumb.url {
+				Some(url) if index != last => Link::new(url.clone(), crumb.label.clone())
+					.class("text-slate-500 no-underline hover:text-slate-900")
+					.render(),
+				_ => {
+					let label = crumb.label.clone();
+					page!(|label: String| {
+						span {
+							class: "text-slate-900 font-medium",
+							aria_current: "page",
+							{ label }
+						}
+					})(label)
+				}
+			};
+			let separator = if index == last { "" } else { "›" }.to_string();
+
+			page!(|content: Page, separator: String| {
+				li {
+					class: "flex items-center gap-2 list-none",
+					{ content }
+					span {
+						class: "text-slate-400",
+						aria_hidden: "true",
+						{ separator }
+					}
+				}
+			})(content, separator)
+		})
+		.collect();
+
+	page!(|entries: Vec<Page>| {
+		nav {
+			class: "admin-breadcrumbs mb-4 text-sm",
+			aria_label: "Breadcrumb",
+			ol {
+				class: "flex flex-wrap items-center gap-2 px-0 m-0",
+				{ entries }
+			}
+		}
+	})(entries)
+}
+
 /// Footer component
 ///
 /// Displays the footer with copyright and version information.
@@ -204,7 +349,8 @@ pub fn main_layout(
 mod tests {
 	use rstest::rstest;
 
-	use super::is_active_path;
+	use super::{breadcrumbs, is_active_path, sidebar_sections};
+	use crate::types::{Breadcrumb, NavItem, NavSection};
 
 	// ==================== is_active_path tests ====================
 
@@ -298,4 +444,63 @@ mod tests {
 		// Assert
 		assert!(!result);
 	}
+
+	// ==================== breadcrumbs tests ====================
+
+	#[rstest]
+	fn test_breadcrumbs_link_all_but_last() {
+		// Arrange
+		let trail = vec![
+			Breadcrumb {
+				label: "Home".to_string(),
+				url: Some("/admin/".to_string()),
+			},
+			Breadcrumb {
+				label: "users".to_string(),
+				url: Some("/admin/users/".to_string()),
+			},
+		];
+
+		// Act
+		let html = breadcrumbs(&trail).render_to_string();
+
+		// Assert
+		assert!(html.contains("href=\"/admin/\""));
+		assert!(!html.contains("href=\"/admin/users/\""));
+		assert!(html.contains("aria-current=\"page\""));
+	}
+
+	#[rstest]
+	fn test_breadcrumbs_empty_trail_renders_nothing() {
+		// Act
+		let html = breadcrumbs(&[]).render_to_string();
+
+		// Assert
+		assert!(html.is_empty());
+	}
+
+	// ==================== sidebar_sections tests ====================
+
+	#[rstest]
+	fn test_sidebar_sections_renders_headings_and_links() {
+		// Arrange
+		let sections = vec![NavSection {
+			label: "Blog".to_string(),
+			icon: None,
+			items: vec![NavItem {
+				label: "Post".to_string(),
+				url: "/admin/post/".to_string(),
+				icon: None,
+				is_model: true,
+			}],
+		}];
+
+		// Act
+		let html = sidebar_sections(&sections, Some("/admin/post/")).render_to_string();
+
+		// Assert
+		assert!(html.contains("Blog"));
+		assert!(html.contains("href=\"/admin/post/\""));
+		assert!(html.contains("admin-nav-active"));
+	}
 }
@@ -0,0 +1,1103 @@
+//! Admin client built on the headless JSON API
+//!
+//! An alternative to the server-function SPA in [`router`](crate::pages::router):
+//! every view talks to the REST endpoints served under `/admin/api/` (see
+//! [`crate::server::api`] on the server), so the same pages work against any
+//! deployment that exposes the JSON API, including one mounted on another
+//! origin.
+//!
+//! - [`AdminApiClient`] - typed HTTP client for the JSON API
+//! - [`index_page`], [`list_page`], [`change_page`], [`delete_page`] - views
+//! - [`headless_router`] - client router wiring the views under a mount path
+//!
+//! Change forms are generated from the `fields/` endpoint. Model fields are
+//! only known at runtime, so they are rendered with the admin form components
+//! rather than the statically-declared `form!` macro.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use reinhardt_admin::pages::headless::{AdminApiClient, headless_router};
+//!
+//! let client = AdminApiClient::new("/admin/api");
+//! let router = headless_router(client, "/console");
+//! ```
+
+use crate::pages::components::common::pagination;
+use crate::pages::components::features::{FormField, form_group};
+use crate::types::{
+	ApiIndexResponse, ApiModelInfo, BulkDeleteResponse, ColumnInfo, DetailResponse, FieldsResponse,
+	FormFieldSpec, ListResponse, MutationResponse,
+};
+use reinhardt_pages::component::{Component, Page};
+use reinhardt_pages::page;
+use reinhardt_pages::router::Link;
+use reinhardt_pages::{ResourceState, Signal, use_resource};
+use reinhardt_urls::routers::ClientRouter;
+use reinhardt_urls::routers::client_router::Path;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// Characters escaped in URL path segments.
+const PATH_SEGMENT: &percent_encoding::AsciiSet = &percent_encoding::CONTROLS
+	.add(b' ')
+	.add(b'"')
+	.add(b'#')
+	.add(b'%')
+	.add(b'/')
+	.add(b'?')
+	.add(b'<')
+	.add(b'>')
+	.add(b'`');
+
+/// Error returned by [`AdminApiClient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdminApiError {
+	/// HTTP status code, or `0` when the request never reached the server
+	pub status: u16,
+	/// Error detail reported by the server
+	pub detail: String,
+}
+
+impl AdminApiError {
+	fn network(detail: impl fmt::Display) -> Self {
+		Self {
+			status: 0,
+			detail: detail.to_string(),
+		}
+	}
+
+	/// Parses an error response body (`{"detail": "..."}`).
+	pub fn from_response(status: u16, body: &str) -> Self {
+		let detail = serde_json::from_str::<Value>(body)
+			.ok()
+			.and_then(|v| v.get("detail").and_then(Value::as_str).map(str::to_string))
+			.unwrap_or_else(|| body.to_string());
+		Self { status, detail }
+	}
+
+	/// Whether the request failed because the user is not signed in.
+	pub fn is_unauthorized(&self) -> bool {
+		self.status == 401
+	}
+}
+
+impl fmt::Display for AdminApiError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.status == 0 {
+			write!(f, "{}", self.detail)
+		} else {
+			write!(f, "{} ({})", self.detail, self.status)
+		}
+	}
+}
+
+impl std::error::Error for AdminApiError {}
+
+/// Query parameters for the list endpoint.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListQuery {
+	/// Page number (1-indexed)
+	pub page: Option<u64>,
+	/// Items per page
+	pub page_size: Option<u64>,
+	/// Search term applied to the model's `search_fields`
+	pub search: Option<String>,
+	/// Ordering field, prefixed with `-` for descending
+	pub ordering: Option<String>,
+	/// Filter values keyed by `list_filter` field
+	pub filters: HashMap<String, String>,
+}
+
+impl ListQuery {
+	/// Returns the query string pairs, with filters sorted by name.
+	pub fn to_pairs(&self) -> Vec<(String, String)> {
+		let mut pairs = Vec::new();
+		if let Some(page) = self.page {
+			pairs.push(("page".to_string(), page.to_string()));
+		}
+		if let Some(page_size) = self.page_size {
+			pairs.push(("page_size".to_string(), page_size.to_string()));
+		}
+		if let Some(search) = self.search.as_ref().filter(|s| !s.is_empty()) {
+			pairs.push(("search".to_string(), search.clone()));
+		}
+		if let Some(ordering) = &self.ordering {
+			pairs.push(("ordering".to_string(), ordering.clone()));
+		}
+		let mut filters: Vec<_> = self
+			.filters
+			.iter()
+			.filter(|(_, value)| !value.is_empty())
+			.map(|(k, v)| (k.clone(), v.clone()))
+			.collect();
+		filters.sort();
+		pairs.extend(filters);
+		pairs
+	}
+}
+
+/// HTTP client for the admin JSON API.
+///
+/// Cloning is cheap; clones share the CSRF token issued by [`index`](Self::index).
+#[derive(Clone)]
+pub struct AdminApiClient {
+	base_url: String,
+	csrf_token: Arc<Mutex<Option<String>>>,
+	http: reqwest::Client,
+}
+
+impl AdminApiClient {
+	/// Creates a client for the API mounted at `base_url` (e.g. `/admin/api`).
+	///
+	/// Relative URLs are resolved against the page origin in the browser.
+	pub fn new(base_url: impl Into<String>) -> Self {
+		Self {
+			base_url: base_url.into().trim_end_matches('/').to_string(),
+			csrf_token: Arc::new(Mutex::new(None)),
+			http: reqwest::Client::new(),
+		}
+	}
+
+	/// Returns the API base URL.
+	pub fn base_url(&self) -> &str {
+		&self.base_url
+	}
+
+	/// Builds the URL of an API resource from its path segments.
+	pub fn url(&self, segments: &[&str]) -> String {
+		let mut url = self.base_url.clone();
+		for segment in segments {
+			url.push('/');
+			url.push_str(&percent_encoding::utf8_percent_encode(segment, PATH_SEGMENT).to_string());
+		}
+		url.push('/');
+		url
+	}
+
+	/// Builds a model resource URL; model names are lowercased to match the server routes.
+	fn model_url(&self, model_name: &str, rest: &[&str]) -> String {
+		let model = model_name.to_lowercase();
+		let mut segments = vec![model.as_str()];
+		segments.extend_from_slice(rest);
+		self.url(&segments)
+	}
+
+	/// Fetches the site index and stores the CSRF token it issues.
+	pub async fn index(&self) -> Result<ApiIndexResponse, AdminApiError> {
+		let response: ApiIndexResponse =
+			self.send(reqwest::Method::GET, self.url(&[]), None).await?;
+		if let Some(token) = &response.csrf_token {
+			*self.csrf_token.lock().unwrap_or_else(|e| e.into_inner()) = Some(token.clone());
+		}
+		Ok(response)
+	}
+
+	/// Lists records of a model.
+	pub async fn list(
+		&self,
+		model_name: &str,
+		query: &ListQuery,
+	) -> Result<ListResponse, AdminApiError> {
+		let mut url = self.model_url(model_name, &[]);
+		let pairs = query.to_pairs();
+		if !pairs.is_empty() {
+			url.push('?');
+			url.push_str(&serde_urlencoded::to_string(&pairs).map_err(AdminApiError::network)?);
+		}
+		self.send(reqwest::Method::GET, url, None).await
+	}
+
+	/// Retrieves a single record.
+	pub async fn detail(
+		&self,
+		model_name: &str,
+		id: &str,
+	) -> Result<DetailResponse, AdminApiError> {
+		self.send(
+			reqwest::Method::GET,
+			self.model_url(model_name, &[id]),
+			None,
+		)
+		.await
+	}
+
+	/// Fetches form field definitions, with current values when `id` is given.
+	pub async fn fields(
+		&self,
+		model_name: &str,
+		id: Option<&str>,
+	) -> Result<FieldsResponse, AdminApiError> {
+		let mut url = self.model_url(model_name, &["fields"]);
+		if let Some(id) = id {
+			url.push('?');
+			url.push_str(
+				&serde_urlencoded::to_string([("id", id)]).map_err(AdminApiError::network)?,
+			);
+		}
+		self.send(reqwest::Method::GET, url, None).await
+	}
+
+	/// Creates a record.
+	pub async fn create(
+		&self,
+		model_name: &str,
+		data: &HashMap<String, Value>,
+	) -> Result<MutationResponse, AdminApiError> {
+		let body = serde_json::to_string(data).map_err(AdminApiError::network)?;
+		self.send(
+			reqwest::Method::POST,
+			self.model_url(model_name, &[]),
+			Some(body),
+		)
+		.await
+	}
+
+	/// Updates a record.
+	pub async fn update(
+		&self,
+		model_name: &str,
+		id: &str,
+		data: &HashMap<String, Value>,
+	) -> Result<MutationResponse, AdminApiError> {
+		let body = serde_json::to_string(data).map_err(AdminApiError::network)?;
+		self.send(
+			reqwest::Method::PUT,
+			self.model_url(model_name, &[id]),
+			Some(body),
+		)
+		.await
+	}
+
+	/// Deletes a record.
+	pub async fn delete(
+		&self,
+		model_name: &str,
+		id: &str,
+	) -> Result<MutationResponse, AdminApiError> {
+		self.send(
+			reqwest::Method::DELETE,
+			self.model_url(model_name, &[id]),
+			None,
+		)
+		.await
+	}
+
+	/// Runs a bulk action (such as `delete_selected`) on the given records.
+	pub async fn run_action(
+		&self,
+		model_name: &str,
+		action: &str,
+		ids: &[String],
+	) -> Result<BulkDeleteResponse, AdminApiError> {
+		let body = serde_json::json!({ "ids": ids }).to_string();
+		self.send(
+			reqwest::Method::POST,
+			self.model_url(model_name, &["actions", action]),
+			Some(body),
+		)
+		.await
+	}
+
+	fn current_csrf_token(&self) -> Option<String> {
+		self.csrf_token
+			.lock()
+			.unwrap_or_else(|e| e.into_inner())
+			.clone()
+			.or_else(reinhardt_pages::csrf::get_csrf_token)
+	}
+
+	async fn send<T: DeserializeOwned>(
+		&self,
+		method: reqwest::Method,
+		url: String,
+		body: Option<String>,
+	) -> Result<T, AdminApiError> {
+		let is_mutation = method != reqwest::Method::GET;
+		let mut request = self
+			.http
+			.request(method, absolute_url(&url))
+			.header("Accept", "application/json");
+		if is_mutation && let Some(token) = self.current_csrf_token() {
+			request = request.header(reinhardt_pages::csrf::CSRF_HEADER_NAME, token);
+		}
+		if let Some(body) = body {
+			request = request
+				.header("Content-Type", "application/json")
+				.body(body);
+		}
+
+		let response = request.send().await.map_err(AdminApiError::network)?;
+		let status = response.status().as_u16();
+		let text = response.text().await.map_err(AdminApiError::network)?;
+		if !(200..300).contains(&status) {
+			return Err(AdminApiError::from_response(status, &text));
+		}
+		serde_json::from_str(&text).map_err(AdminApiError::network)
+	}
+}
+
+/// Resolves a relative URL against the page origin.
+#[cfg(client)]
+fn absolute_url(url: &str) -> String {
+	if url.starts_with('/')
+		&& let Some(origin) = web_sys::window().and_then(|w| w.location().origin().ok())
+	{
+		return format!("{}{}", origin, url);
+	}
+	url.to_string()
+}
+
+/// Resolves a relative URL against the page origin.
+#[cfg(server)]
+fn absolute_url(url: &str) -> String {
+	url.to_string()
+}
+
+fn mount_url(mount: &str, segments: &[&str]) -> String {
+	let mut url = mount.trim_end_matches('/').to_string();
+	for segment in segments {
+		url.push('/');
+		url.push_str(segment);
+	}
+	url.push('/');
+	url
+}
+
+fn display_value(value: &Value) -> String {
+	match value {
+		Value::String(s) => s.clone(),
+		Value::Null => String::new(),
+		Value::Array(values) => values
+			.iter()
+			.map(display_value)
+			.collect::<Vec<_>>()
+			.join(", "),
+		other => other.to_string(),
+	}
+}
+
+fn status_view(message: String) -> Page {
+	page!(|message: String| {
+		div {
+			class: "text-center py-12 text-slate-500",
+			{ message }
+		}
+	})(message)
+}
+
+fn error_view(error: &AdminApiError) -> Page {
+	let message = if error.is_unauthorized() {
+		"Your session has expired. Please sign in again.".to_string()
+	} else {
+		error.to_string()
+	};
+	page!(|message: String| {
+		div {
+			class: "admin-alert admin-alert-danger mt-8",
+			role: "alert",
+			{ message }
+		}
+	})(message)
+}
+
+/// Renders the model index from an [`ApiIndexResponse`].
+pub fn index_view(mount: &str, data: &ApiIndexResponse) -> Page {
+	let title = data.site_header.clone();
+	let cards: Vec<Page> = data.models.iter().map(|m| model_card(mount, m)).collect();
+	page!(|title: String, cards: Vec<Page>| {
+		div {
+			class: "p-6 md:p-8 max-w-7xl mx-auto",
+			h1 {
+				class: "font-display text-2xl font-bold text-slate-900 mb-6",
+				{ title }
+			}
+			div {
+				class: "grid gap-4 md:grid-cols-3",
+				{ cards }
+			}
+		}
+	})(title, cards)
+}
+
+fn model_card(mount: &str, model: &ApiModelInfo) -> Page {
+	let segment = model.name.to_lowercase();
+	let list_link = Link::new(mount_url(mount, &[&segment]), model.name.clone())
+		.class("font-semibold text-slate-900")
+		.render();
+	let add_link = if model.permissions.add {
+		Link::new(mount_url(mount, &[&segment, "add"]), "Add")
+			.class("admin-btn admin-btn-outline admin-btn-sm")
+			.render()
+	} else {
+		Page::Empty
+	};
+	page!(|list_link: Page, add_link: Page| {
+		div {
+			class: "admin-card p-4 flex items-center justify-between",
+			{ list_link }
+			{ add_link }
+		}
+	})(list_link, add_link)
+}
+
+/// Renders one page of records from a [`ListResponse`].
+pub fn list_view(mount: &str, data: &ListResponse, page_signal: Signal<u64>) -> Page {
+	let segment = data.model_name.to_lowercase();
+	let columns: Vec<ColumnInfo> = data.columns.clone().unwrap_or_else(|| {
+		vec![ColumnInfo {
+			field: "id".to_string(),
+			label: "ID".to_string(),
+			sortable: true,
+			editable: None,
+			choices: Vec::new(),
+		}]
+	});
+
+	let header_cells: Vec<Page> = columns
+		.iter()
+		.map(|column| {
+			let label = column.label.clone();
+			page!(|label: String| {
+				th { { label } }
+			})(label)
+		})
+		.chain(std::iter::once(page!(|| {
+			th { "Actions" }
+		})()))
+		.collect();
+
+	let rows: Vec<Page> = data
+		.results
+		.iter()
+		.map(|record| {
+			let cells: Vec<Page> = columns
+				.iter()
+				.map(|column| {
+					let value = record
+						.get(&column.field)
+						.map(|value| column.display(&display_value(value)).to_string())
+						.unwrap_or_default();
+					page!(|value: String| {
+						td { { value } }
+					})(value)
+				})
+				.collect();
+			let id = record.get("id").map(display_value).unwrap_or_default();
+			let edit_link = Link::new(mount_url(mount, &[&segment, &id, "change"]), "Edit")
+				.class("admin-btn admin-btn-outline admin-btn-sm")
+				.render();
+			let delete_link = Link::new(mount_url(mount, &[&segment, &id, "delete"]), "Delete")
+				.class("admin-btn admin-btn-outline admin-btn-sm")
+				.render();
+			page!(|cells: Vec<Page>, edit_link: Page, delete_link: Page| {
+				tr {
+					{ cells }
+					td {
+						div {
+							class: "flex gap-1",
+							{ edit_link }
+							{ delete_link }
+						}
+					}
+				}
+			})(cells, edit_link, delete_link)
+		})
+		.collect();
+
+	let title = data.model_name.clone();
+	let summary = format!(
+		"{} records (page {} of {})",
+		data.count,
+		data.page,
+		data.total_pages.max(1)
+	);
+	let add_link = Link::new(
+		mount_url(mount, &[&segment, "add"]),
+		format!("Add {}", title),
+	)
+	.class("admin-btn admin-btn-primary")
+	.render();
+	let pager = pagination(page_signal, data.total_pages.max(1));
+
+	page!(|title: String,
+	 summary: String,
+	 add_link: Page,
+	 header_cells: Vec<Page>,
+	 rows: Vec<Page>,
+	 pager: Page| {
+		div {
+			class: "list-view",
+			div {
+				class: "mb-6 flex items-center justify-between gap-4",
+				h1 {
+					class: "font-display text-2xl font-bold text-slate-900",
+					{ title }
+				}
+				{ add_link }
+			}
+			div {
+				class: "text-sm text-slate-500 mb-4",
+				{ summary }
+			}
+			div {
+				class: "overflow-x-auto rounded-lg border border-slate-200",
+				table {
+					class: "admin-table",
+					thead {
+						tr { { header_cells } }
+					}
+					tbody { { rows } }
+				}
+			}
+			{ pager }
+		}
+	})(title, summary, add_link, header_cells, rows, pager)
+}
+
+/// Converts a [`FieldsResponse`] into form fields prefilled with current values.
+pub fn form_fields(data: &FieldsResponse) -> Vec<FormField> {
+	data.fields
+		.iter()
+		.map(|info| FormField {
+			name: info.name.clone(),
+			label: info.label.clone(),
+			spec: FormFieldSpec::from(&info.field_type),
+			required: info.required,
+			value: data
+				.values
+				.as_ref()
+				.and_then(|values| values.get(&info.name))
+				.map(display_value)
+				.unwrap_or_default(),
+			language: info.language.clone(),
+		})
+		.collect()
+}
+
+/// Renders a create or change form that submits through the JSON API.
+pub fn change_form(
+	client: AdminApiClient,
+	mount: &str,
+	model_name: &str,
+	fields: &[FormField],
+	record_id: Option<&str>,
+) -> Page {
+	let title = match record_id {
+		Some(_) => format!("Change {}", model_name),
+		None => format!("Add {}", model_name),
+	};
+	let list_url = mount_url(mount, &[&model_name.to_lowercase()]);
+	let groups: Vec<Page> = fields.iter().map(form_group).collect();
+	let cancel_link = Link::new(list_url.clone(), "Cancel")
+		.class("admin-btn admin-btn-secondary")
+		.render();
+	let submit_model = model_name.to_string();
+	let submit_id = record_id.map(str::to_string);
+
+	page!(|title: String,
+	 groups: Vec<Page>,
+	 cancel_link: Page,
+	 client: AdminApiClient,
+	 submit_model: String,
+	 submit_id: Option<String>,
+	 list_url: String| {
+		div {
+			class: "model-form max-w-2xl",
+			h1 {
+				class: "font-display text-2xl font-bold text-slate-900 mb-6",
+				{ title }
+			}
+			form {
+				@submit: move |event| {
+					event.prevent_default();
+					#[cfg(client)]
+					submit_change_form(
+						&event,
+						client.clone(),
+						submit_model.clone(),
+						submit_id.clone(),
+						list_url.clone(),
+					);
+				},
+				div {
+					class: "admin-card p-6",
+					{ groups }
+				}
+				div {
+					class: "mt-6 flex gap-2",
+					button {
+						class: "admin-btn admin-btn-primary",
+						type: "submit",
+						"Save"
+					}
+					{ cancel_link }
+				}
+			}
+		}
+	})(
+		title,
+		groups,
+		cancel_link,
+		client,
+		submit_model,
+		submit_id,
+		list_url,
+	)
+}
+
+#[cfg(client)]
+fn submit_change_form(
+	event: &web_sys::Event,
+	client: AdminApiClient,
+	model_name: String,
+	record_id: Option<String>,
+	return_url: String,
+) {
+	use crate::pages::components::features::{
+		collect_mutation_request, navigate_or_set_href, report_admin_error,
+	};
+
+	let data = collect_mutation_request(event).data;
+	reinhardt_pages::platform::spawn_task(async move {
+		let result = match record_id {
+			Some(id) => client.update(&model_name, &id, &data).await,
+			None => client.create(&model_name, &data).await,
+		};
+		match result {
+			Ok(_) => navigate_or_set_href(&return_url),
+			Err(e) => report_admin_error(&format!("Save failed: {}", e)),
+		}
+	});
+}
+
+/// Renders the delete confirmation for a record.
+pub fn delete_confirmation(
+	client: AdminApiClient,
+	mount: &str,
+	model_name: &str,
+	record_id: &str,
+) -> Page {
+	let message = format!(
+		"Are you sure you want to delete {} \"{}\"?",
+		model_name, record_id
+	);
+	let list_url = mount_url(mount, &[&model_name.to_lowercase()]);
+	let cancel_link = Link::new(list_url.clone(), "Cancel")
+		.class("admin-btn admin-btn-secondary")
+		.render();
+	let delete_model = model_name.to_string();
+	let delete_id = record_id.to_string();
+
+	page!(|message: String,
+	 cancel_link: Page,
+	 client: AdminApiClient,
+	 delete_model: String,
+	 delete_id: String,
+	 list_url: String| {
+		div {
+			class: "max-w-2xl",
+			p {
+				class: "mb-6",
+				{ message }
+			}
+			div {
+				class: "flex gap-2",
+				button {
+					class: "admin-btn admin-btn-danger",
+					type: "button",
+					@click: move |_| {
+						#[cfg(client)]
+						{
+							use crate::pages::components::features::{
+								navigate_or_set_href, report_admin_error,
+							};
+							let client = client.clone();
+							let model_name = delete_model.clone();
+							let id = delete_id.clone();
+							let return_url = list_url.clone();
+							reinhardt_pages::platform::spawn_task(async move {
+								match client.delete(&model_name, &id).await {
+									Ok(_) => navigate_or_set_href(&return_url),
+									Err(e) => report_admin_error(&format!("Delete failed: {}", e)),
+								}
+							});
+						}
+					},
+					"Yes, delete"
+				}
+				{ cancel_link }
+			}
+		}
+	})(
+		message,
+		cancel_link,
+		client,
+		delete_model,
+		delete_id,
+		list_url,
+	)
+}
+
+fn resource_page<T, F>(state: ResourceState<T, AdminApiError>, render: F) -> Page
+where
+	F: FnOnce(T) -> Page,
+{
+	match state {
+		ResourceState::Loading => status_view("Loading...".to_string()),
+		ResourceState::Success(data) => render(data),
+		ResourceState::Error(error) => error_view(&error),
+	}
+}
+
+/// Index page listing the models the current user can access.
+pub fn index_page(client: AdminApiClient, mount: String) -> Page {
+	let resource = use_resource(
+		move || {
+			let client = client.clone();
+			async move { client.index().await }
+		},
+		(),
+	);
+	Page::reactive(move || resource_page(resource.get(), |data| index_view(&mount, &data)))
+}
+
+/// Paginated list page for a model.
+pub fn list_page(client: AdminApiClient, mount: String, model_name: String) -> Page {
+	let page_signal = Signal::new(1u64);
+	let resource = use_resource(
+		{
+			let page_signal = page_signal.clone();
+			move || {
+				let client = client.clone();
+				let model_name = model_name.clone();
+				let query = ListQuery {
+					page: Some(page_signal.get()),
+					..Default::default()
+				};
+				async move { client.list(&model_name, &query).await }
+			}
+		},
+		(page_signal.clone(),),
+	);
+	Page::reactive(move || {
+		resource_page(resource.get(), |data| {
+			list_view(&mount, &data, page_signal.clone())
+		})
+	})
+}
+
+/// Create (`record_id` is `None`) or change page for a model.
+pub fn change_page(
+	client: AdminApiClient,
+	mount: String,
+	model_name: String,
+	record_id: Option<String>,
+) -> Page {
+	let resource = use_resource(
+		{
+			let client = client.clone();
+			let model_name = model_name.clone();
+			let record_id = record_id.clone();
+			move || {
+				let client = client.clone();
+				let model_name = model_name.clone();
+				let record_id = record_id.clone();
+				async move { client.fields(&model_name, record_id.as_deref()).await }
+			}
+		},
+		(),
+	);
+	Page::reactive(move || {
+		resource_page(resource.get(), |data| {
+			change_form(
+				client.clone(),
+				&mount,
+				&model_name,
+				&form_fields(&data),
+				record_id.as_deref(),
+			)
+		})
+	})
+}
+
+/// Delete confirmation page for a record.
+pub fn delete_page(
+	client: AdminApiClient,
+	mount: String,
+	model_name: String,
+	record_id: String,
+) -> Page {
+	delete_confirmation(client, &mount, &model_name, &record_id)
+}
+
+/// Builds a client router serving the API-backed admin under `mount`.
+///
+/// Routes (relative to `mount`):
+///
+/// - `/` - model index
+/// - `/{model}/add/` - create form
+/// - `/{model}/{id}/change/` - change form
+/// - `/{model}/{id}/delete/` - delete confirmation
+/// - `/{model}/` - list
+///
+/// Literal segments are registered before the dynamic list route so that
+/// `add` is never captured as a model id.
+pub fn headless_router(client: AdminApiClient, mount: &str) -> ClientRouter {
+	let mount = mount.trim_end_matches('/').to_string();
+	let index_client = client.clone();
+	let index_mount = mount.clone();
+	let create_client = client.clone();
+	let create_mount = mount.clone();
+	let change_client = client.clone();
+	let change_mount = mount.clone();
+	let delete_client = client.clone();
+	let delete_mount = mount.clone();
+	let list_mount = mount.clone();
+
+	ClientRouter::new()
+		.route("api_index", &format!("{}/", mount), move || {
+			index_page(index_client.clone(), index_mount.clone())
+		})
+		.route_path(
+			"api_create",
+			&format!("{}/{{model}}/add/", mount),
+			move |Path(model_name): Path<String>| {
+				change_page(
+					create_client.clone(),
+					create_mount.clone(),
+					model_name,
+					None,
+				)
+			},
+		)
+		.route_path(
+			"api_change",
+			&format!("{}/{{model}}/{{id}}/change/", mount),
+			move |Path(model_name): Path<String>, Path(id): Path<String>| {
+				change_page(
+					change_client.clone(),
+					change_mount.clone(),
+					model_name,
+					Some(id),
+				)
+			},
+		)
+		.route_path(
+			"api_delete",
+			&format!("{}/{{model}}/{{id}}/delete/", mount),
+			move |Path(model_name): Path<String>, Path(id): Path<String>| {
+				delete_page(delete_client.clone(), delete_mount.clone(), model_name, id)
+			},
+		)
+		.route_path(
+			"api_list",
+			&format!("{}/{{model}}/", mount),
+			move |Path(model_name): Path<String>| {
+				list_page(client.clone(), list_mount.clone(), model_name)
+			},
+		)
+}
+
+#[cfg(all(test, server))]
+mod tests {
+	use super::*;
+	use crate::types::{FieldInfo, FieldType, ModelPermissions};
+	use rstest::rstest;
+	use serde_json::json;
+
+	#[rstest]
+	fn test_client_url_encodes_segments() {
+		// Arrange
+		let client = AdminApiClient::new("/admin/api/");
+
+		// Act
+		let url = client.model_url("BlogPost", &["a b/c-d"]);
+
+		// Assert
+		assert_eq!(client.base_url(), "/admin/api");
+		assert_eq!(url, "/admin/api/blogpost/a%20b%2Fc-d/");
+	}
+
+	#[rstest]
+	fn test_list_query_pairs_skip_empty_values() {
+		// Arrange
+		let query = ListQuery {
+			page: Some(2),
+			search: Some(String::new()),
+			ordering: Some("-id".to_string()),
+			filters: HashMap::from([
+				("status".to_string(), "draft".to_string()),
+				("author".to_string(), String::new()),
+			]),
+			..Default::default()
+		};
+
+		// Act
+		let pairs = query.to_pairs();
+
+		// Assert
+		assert_eq!(
+			pairs,
+			vec![
+				("page".to_string(), "2".to_string()),
+				("ordering".to_string(), "-id".to_string()),
+				("status".to_string(), "draft".to_string()),
+			]
+		);
+	}
+
+	#[rstest]
+	#[case(r#"{"detail":"Permission denied"}"#, "Permission denied")]
+	#[case("Bad Gateway", "Bad Gateway")]
+	fn test_api_error_from_response(#[case] body: &str, #[case] expected: &str) {
+		// Act
+		let error = AdminApiError::from_response(403, body);
+
+		// Assert
+		assert_eq!(error.status, 403);
+		assert_eq!(error.detail, expected);
+	}
+
+	#[rstest]
+	fn test_index_view_links_models_under_mount() {
+		// Arrange
+		let data = ApiIndexResponse {
+			site_name: "Admin".to_string(),
+			site_header: "Administration".to_string(),
+			models: vec![ApiModelInfo {
+				name: "Article".to_string(),
+				url: "/admin/api/article/".to_string(),
+				list_display: vec![],
+				list_filter: vec![],
+				search_fields: vec![],
+				ordering: vec![],
+				actions: vec![],
+				permissions: ModelPermissions {
+					view: true,
+					add: true,
+					..Default::default()
+				},
+			}],
+			csrf_token: None,
+		};
+
+		// Act
+		let html = index_view("/console", &data).render_to_string();
+
+		// Assert
+		assert!(html.contains("/console/article/"));
+		assert!(html.contains("/console/article/add/"));
+	}
+
+	#[rstest]
+	fn test_list_view_renders_rows_with_actions() {
+		// Arrange
+		let data = ListResponse {
+			model_name: "Article".to_string(),
+			count: 1,
+			page: 1,
+			page_size: 25,
+			total_pages: 1,
+			results: vec![HashMap::from([
+				("id".to_string(), json!(7)),
+				("title".to_string(), json!("Hello")),
+			])],
+			available_filters: None,
+			columns: Some(vec![ColumnInfo {
+				field: "title".to_string(),
+				label: "Title".to_string(),
+				sortable: true,
+				editable: None,
+				choices: vec![],
+			}]),
+		};
+
+		// Act
+		let html = list_view("/console", &data, Signal::new(1)).render_to_string();
+
+		// Assert
+		assert!(html.contains("Hello"));
+		assert!(html.contains("/console/article/7/change/"));
+		assert!(html.contains("/console/article/7/delete/"));
+	}
+
+	#[rstest]
+	fn test_list_view_displays_choice_labels() {
+		// Arrange
+		let data = ListResponse {
+			model_name: "Article".to_string(),
+			count: 1,
+			page: 1,
+			page_size: 25,
+			total_pages: 1,
+			results: vec![HashMap::from([
+				("id".to_string(), json!(7)),
+				("status".to_string(), json!("in_review")),
+			])],
+			available_filters: None,
+			columns: Some(vec![ColumnInfo {
+				field: "status".to_string(),
+				label: "Status".to_string(),
+				sortable: true,
+				editable: None,
+				choices: vec![("in_review".to_string(), "Under review".to_string())],
+			}]),
+		};
+
+		// Act
+		let html = list_view("/console", &data, Signal::new(1)).render_to_string();
+
+		// Assert
+		assert!(html.contains("Under review"));
+		assert!(!html.contains("in_review"));
+	}
+
+	#[rstest]
+	fn test_form_fields_prefill_values() {
+		// Arrange
+		let data = FieldsResponse {
+			model_name: "Article".to_string(),
+			fields: vec![FieldInfo {
+				name: "title".to_string(),
+				label: "Title".to_string(),
+				field_type: FieldType::Text,
+				required: true,
+				readonly: false,
+				help_text: None,
+				placeholder: None,
+				language: None,
+			}],
+			values: Some(HashMap::from([("title".to_string(), json!("Hello"))])),
+		};
+
+		// Act
+		let fields = form_fields(&data);
+
+		// Assert
+		assert_eq!(fields.len(), 1);
+		assert_eq!(fields[0].value, "Hello");
+		assert!(fields[0].required);
+	}
+
+	#[rstest]
+	fn test_headless_router_registers_routes() {
+		// Arrange
+		let client = AdminApiClient::new("/admin/api");
+
+		// Act
+		let router = headless_router(client, "/console/");
+
+		// Assert
+		assert_eq!(router.route_count(), 5);
+		assert_eq!(
+			router
+				.reverse("api_change", &[("model", "article"), ("id", "7")])
+				.unwrap(),
+			"/console/article/7/change/"
+		);
+	}
+}
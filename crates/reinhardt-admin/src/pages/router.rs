@@ -14,10 +14,18 @@
 // so file-scope suppression is preferred over per-usage `#[allow(deprecated)]` attribute spam.
 use crate::pages::components::features::{
 	Column, FormField, ListViewData, dashboard, detail_view, list_view, model_form,
+	reverse_admin_url,
 };
+#[cfg(client)]
+use crate::pages::components::features::{
+	downloads_panel, edit_lock_banner, generic_inlines_panel, grouped_dashboard,
+	recent_actions_panel, start_edit_lock,
+};
+use crate::pages::components::layout::breadcrumbs;
 pub use crate::pages::components::login;
 #[cfg(client)]
 use crate::server::{get_dashboard, get_detail, get_fields, get_list};
+use crate::types::Breadcrumb;
 #[cfg(client)]
 use crate::types::ListQueryParams;
 #[cfg(server)]
@@ -69,14 +77,68 @@ pub enum AdminRoute {
 	Login,
 }
 
+impl AdminRoute {
+	/// Returns the breadcrumb trail for this route.
+	///
+	/// The trail starts at the dashboard and ends with the current page,
+	/// which has no URL. Login and not-found pages have no breadcrumbs.
+	///
+	/// # Example
+	///
+	/// ```no_run
+	/// use reinhardt_admin::pages::router::AdminRoute;
+	///
+	/// let trail = AdminRoute::Edit {
+	///     model_name: "users".to_string(),
+	///     id: "42".to_string(),
+	/// }
+	/// .breadcrumbs();
+	/// assert_eq!(trail.len(), 4); // Home › users › 42 › Change
+	/// ```
+	pub fn breadcrumbs(&self) -> Vec<Breadcrumb> {
+		fn crumb(label: &str, url: Option<String>) -> Breadcrumb {
+			Breadcrumb {
+				label: label.to_string(),
+				url,
+			}
+		}
+		let home = || crumb("Home", Some(reverse_admin_url("dashboard", &[])));
+		let model_list =
+			|model: &str| crumb(model, Some(reverse_admin_url("list", &[("model", model)])));
+
+		match self {
+			AdminRoute::Dashboard => vec![crumb("Home", None)],
+			AdminRoute::List { model_name } => vec![home(), crumb(model_name, None)],
+			AdminRoute::Create { model_name } => {
+				vec![home(), model_list(model_name), crumb("Add", None)]
+			}
+			AdminRoute::Detail { model_name, id } => {
+				vec![home(), model_list(model_name), crumb(id, None)]
+			}
+			AdminRoute::Edit { model_name, id } => vec![
+				home(),
+				model_list(model_name),
+				crumb(
+					id,
+					Some(reverse_admin_url(
+						"detail",
+						&[("model", model_name), ("id", id)],
+					)),
+				),
+				crumb("Change", None),
+			],
+			AdminRoute::NotFound | AdminRoute::Login => Vec::new(),
+		}
+	}
+}
+
 // Global Router instance
 // Initialized by init_global_router() and accessed via with_router()
 thread_local! {
 	static ROUTER: RefCell<Option<ClientRouter>> = const { RefCell::new(None) };
 }
 
-#[cfg(any(client, test))]
-fn json_value_to_display_string(value: &serde_json::Value) -> String {
+pub(crate) fn json_value_to_display_string(value: &serde_json::Value) -> String {
 	match value {
 		serde_json::Value::String(value) => value.clone(),
 		serde_json::Value::Number(value) => value.to_string(),
@@ -188,6 +250,7 @@ where
 /// Dashboard view component for router
 #[cfg(client)]
 fn dashboard_view() -> Page {
+	let crumbs = breadcrumbs(&AdminRoute::Dashboard.breadcrumbs());
 	let dashboard_resource = use_resource(
 		|| async { get_dashboard().await.map_err(|e| e.to_string()) },
 		(),
@@ -204,18 +267,42 @@ fn dashboard_view() -> Page {
 					urls.login_url = format!("{}/", data.login_url.trim_end_matches('/'));
 					urls.logout_url = format!("{}/", data.logout_url.trim_end_matches('/'));
 				});
-				dashboard(&data.site_header, &data.models)
+				let overview = if data.navigation.is_empty() {
+					dashboard(&data.site_header, &data.models)
+				} else {
+					grouped_dashboard(&data.site_header, &data.navigation)
+				};
+				let recent = recent_actions_panel(&data.recent_actions);
+				page!(|overview: Page, recent: Page| {
+					div {
+						{ overview }
+						{ recent }
+					}
+				})(overview, recent)
 			}
 			ResourceState::Error(err) => error_view(&err),
 		}
 	});
 
-	page!(|reactive_content: Page| {
+	page!(|crumbs: Page, reactive_content: Page| {
 		div {
 			class: "dashboard-container p-6 md:p-8 max-w-7xl mx-auto",
+			{ crumbs }
 			{ reactive_content }
 		}
-	})(reactive_content)
+	})(crumbs, reactive_content)
+}
+
+/// Prepends the route's breadcrumbs to a non-WASM fallback view
+#[cfg(server)]
+fn with_breadcrumbs(route: AdminRoute, content: Page) -> Page {
+	let crumbs = breadcrumbs(&route.breadcrumbs());
+	page!(|crumbs: Page, content: Page| {
+		div {
+			{ crumbs }
+			{ content }
+		}
+	})(crumbs, content)
 }
 
 /// Dashboard view component for router (non-WASM fallback)
@@ -233,7 +320,7 @@ fn dashboard_view() -> Page {
 		},
 	];
 
-	dashboard("Administration", &models)
+	with_breadcrumbs(AdminRoute::Dashboard, dashboard("Administration", &models))
 }
 
 /// List view component for router
@@ -241,6 +328,13 @@ fn dashboard_view() -> Page {
 fn list_view_component(model_name: String) -> Page {
 	use reinhardt_pages::use_effect;
 
+	let crumbs = breadcrumbs(
+		&AdminRoute::List {
+			model_name: model_name.clone(),
+		}
+		.breadcrumbs(),
+	);
+
 	let list_resource = use_resource(
 		move || {
 			let model_name = model_name.clone();
@@ -292,9 +386,14 @@ fn list_view_component(model_name: String) -> Page {
 						.map(|cols| {
 							cols.into_iter()
 								.map(|c| Column {
+									editable: c
+										.editable
+										.as_ref()
+										.map(crate::types::FormFieldSpec::from),
 									field: c.field,
 									label: c.label,
 									sortable: c.sortable,
+									choices: c.choices,
 								})
 								.collect()
 						})
@@ -303,6 +402,8 @@ fn list_view_component(model_name: String) -> Page {
 								field: "id".to_string(),
 								label: "ID".to_string(),
 								sortable: true,
+								editable: None,
+								choices: vec![],
 							}]
 						}),
 					records: response
@@ -326,12 +427,13 @@ fn list_view_component(model_name: String) -> Page {
 		}
 	});
 
-	page!(|reactive_content: Page| {
+	page!(|crumbs: Page, reactive_content: Page| {
 		div {
 			class: "list-container p-6 md:p-8 max-w-7xl mx-auto",
+			{ crumbs }
 			{ reactive_content }
 		}
-	})(reactive_content)
+	})(crumbs, reactive_content)
 }
 
 /// List view component for router (non-WASM fallback)
@@ -347,11 +449,15 @@ fn list_view_component(model_name: String) -> Page {
 				field: "id".to_string(),
 				label: "ID".to_string(),
 				sortable: true,
+				editable: None,
+				choices: vec![],
 			},
 			Column {
 				field: "name".to_string(),
 				label: "Name".to_string(),
 				sortable: true,
+				editable: None,
+				choices: vec![],
 			},
 		],
 		records: vec![],
@@ -363,12 +469,22 @@ fn list_view_component(model_name: String) -> Page {
 
 	let page_signal = Signal::new(1u64);
 	let filters_signal = Signal::new(HashMap::new());
-	list_view(&data, page_signal, filters_signal)
+	with_breadcrumbs(
+		AdminRoute::List { model_name },
+		list_view(&data, page_signal, filters_signal),
+	)
 }
 
 /// Detail view component for router
 #[cfg(client)]
 fn detail_view_component(model_name: String, record_id: String) -> Page {
+	let crumbs = breadcrumbs(
+		&AdminRoute::Detail {
+			model_name: model_name.clone(),
+			id: record_id.clone(),
+		}
+		.breadcrumbs(),
+	);
 	let model_name_for_view = model_name.clone();
 	let record_id_for_view = record_id.clone();
 	let detail_resource = use_resource(
@@ -396,18 +512,28 @@ fn detail_view_component(model_name: String, record_id: String) -> Page {
 					.iter()
 					.map(|(k, v)| (k.clone(), json_value_to_display_string(v)))
 					.collect();
-				detail_view(&model_name, &record_id, &data)
+				let detail = detail_view(&model_name, &record_id, &data);
+				let downloads = downloads_panel(&response.downloads);
+				let inlines = generic_inlines_panel(&response.inlines);
+				page!(|detail: Page, downloads: Page, inlines: Page| {
+					div {
+						{ detail }
+						{ downloads }
+						{ inlines }
+					}
+				})(detail, downloads, inlines)
 			}
 			ResourceState::Error(err) => error_view(&err),
 		}
 	});
 
-	page!(|reactive_content: Page| {
+	page!(|crumbs: Page, reactive_content: Page| {
 		div {
 			class: "detail-container p-6 md:p-8 max-w-7xl mx-auto",
+			{ crumbs }
 			{ reactive_content }
 		}
-	})(reactive_content)
+	})(crumbs, reactive_content)
 }
 
 /// Detail view component for router (non-WASM fallback)
@@ -418,12 +544,25 @@ fn detail_view_component(model_name: String, record_id: String) -> Page {
 	record.insert("id".to_string(), record_id.clone());
 	record.insert("name".to_string(), "Sample Record".to_string());
 
-	detail_view(&model_name, &record_id, &record)
+	let content = detail_view(&model_name, &record_id, &record);
+	with_breadcrumbs(
+		AdminRoute::Detail {
+			model_name,
+			id: record_id,
+		},
+		content,
+	)
 }
 
 /// Create form view component for router
 #[cfg(client)]
 fn create_view_component(model_name: String) -> Page {
+	let crumbs = breadcrumbs(
+		&AdminRoute::Create {
+			model_name: model_name.clone(),
+		}
+		.breadcrumbs(),
+	);
 	let model_name_for_view = model_name.clone();
 	let fields_resource = use_resource(
 		move || {
@@ -452,6 +591,7 @@ fn create_view_component(model_name: String) -> Page {
 						label: field_info.label,
 						required: field_info.required,
 						value: String::new(),
+						language: field_info.language,
 					})
 					.collect();
 				model_form(&model_name, &fields, None)
@@ -460,12 +600,13 @@ fn create_view_component(model_name: String) -> Page {
 		}
 	});
 
-	page!(|reactive_content: Page| {
+	page!(|crumbs: Page, reactive_content: Page| {
 		div {
 			class: "form-container p-6 md:p-8 max-w-7xl mx-auto",
+			{ crumbs }
 			{ reactive_content }
 		}
-	})(reactive_content)
+	})(crumbs, reactive_content)
 }
 
 /// Create form view component for router (non-WASM fallback)
@@ -481,6 +622,7 @@ fn create_view_component(model_name: String) -> Page {
 			},
 			required: true,
 			value: String::new(),
+			language: None,
 		},
 		FormField {
 			name: "email".to_string(),
@@ -490,15 +632,29 @@ fn create_view_component(model_name: String) -> Page {
 			},
 			required: true,
 			value: String::new(),
+			language: None,
 		},
 	];
 
-	model_form(&model_name, &fields, None)
+	let content = model_form(&model_name, &fields, None);
+	with_breadcrumbs(AdminRoute::Create { model_name }, content)
 }
 
 /// Edit form view component for router
 #[cfg(client)]
 fn edit_view_component(model_name: String, record_id: String) -> Page {
+	let crumbs = breadcrumbs(
+		&AdminRoute::Edit {
+			model_name: model_name.clone(),
+			id: record_id.clone(),
+		}
+		.breadcrumbs(),
+	);
+	// Hold the edit lock for as long as the form is open
+	let locked_by = Signal::new(None);
+	let lock_banner = edit_lock_banner(&model_name, &record_id, locked_by.clone());
+	start_edit_lock(model_name.clone(), record_id.clone(), locked_by);
+
 	let model_name_for_view = model_name.clone();
 	let record_id_for_view = record_id.clone();
 	let fields_resource = use_resource(
@@ -551,6 +707,7 @@ fn edit_view_component(model_name: String, record_id: String) -> Page {
 							label: field_info.label,
 							required: field_info.required,
 							value,
+							language: field_info.language,
 						}
 					})
 					.collect();
@@ -560,12 +717,14 @@ fn edit_view_component(model_name: String, record_id: String) -> Page {
 		}
 	});
 
-	page!(|reactive_content: Page| {
+	page!(|crumbs: Page, lock_banner: Page, reactive_content: Page| {
 		div {
 			class: "form-container p-6 md:p-8 max-w-7xl mx-auto",
+			{ crumbs }
+			{ lock_banner }
 			{ reactive_content }
 		}
-	})(reactive_content)
+	})(crumbs, lock_banner, reactive_content)
 }
 
 /// Edit form view component for router (non-WASM fallback)
@@ -581,6 +740,7 @@ fn edit_view_component(model_name: String, record_id: String) -> Page {
 			},
 			required: true,
 			value: "Existing Value".to_string(),
+			language: None,
 		},
 		FormField {
 			name: "email".to_string(),
@@ -590,10 +750,18 @@ fn edit_view_component(model_name: String, record_id: String) -> Page {
 			},
 			required: true,
 			value: "user@example.com".to_string(),
+			language: None,
 		},
 	];
 
-	model_form(&model_name, &fields, Some(&record_id))
+	let content = model_form(&model_name, &fields, Some(&record_id));
+	with_breadcrumbs(
+		AdminRoute::Edit {
+			model_name,
+			id: record_id,
+		},
+		content,
+	)
 }
 
 /// Not found view component for router
@@ -994,4 +1162,62 @@ mod tests {
 		let route_match = route_match.unwrap();
 		assert_eq!(route_match.route.name(), Some("login"));
 	}
+
+	#[test]
+	fn test_dashboard_breadcrumbs() {
+		// Arrange
+		let route = AdminRoute::Dashboard;
+
+		// Act
+		let trail = route.breadcrumbs();
+
+		// Assert
+		assert_eq!(trail.len(), 1);
+		assert_eq!(trail[0].label, "Home");
+		assert_eq!(trail[0].url, None);
+	}
+
+	#[test]
+	fn test_edit_breadcrumbs_link_to_parents() {
+		// Arrange
+		let route = AdminRoute::Edit {
+			model_name: "users".to_string(),
+			id: "42".to_string(),
+		};
+
+		// Act
+		let trail = route.breadcrumbs();
+
+		// Assert
+		let labels: Vec<&str> = trail.iter().map(|c| c.label.as_str()).collect();
+		assert_eq!(labels, vec!["Home", "users", "42", "Change"]);
+		assert_eq!(trail[0].url.as_deref(), Some("/admin/"));
+		assert_eq!(trail[1].url.as_deref(), Some("/admin/users/"));
+		assert_eq!(trail[2].url.as_deref(), Some("/admin/users/42/"));
+		assert_eq!(trail[3].url, None);
+	}
+
+	#[test]
+	fn test_create_breadcrumbs() {
+		// Arrange
+		let route = AdminRoute::Create {
+			model_name: "posts".to_string(),
+		};
+
+		// Act
+		let trail = route.breadcrumbs();
+
+		// Assert
+		let labels: Vec<&str> = trail.iter().map(|c| c.label.as_str()).collect();
+		assert_eq!(labels, vec!["Home", "posts", "Add"]);
+	}
+
+	#[test]
+	fn test_login_has_no_breadcrumbs() {
+		// Act
+		let trail = AdminRoute::Login.breadcrumbs();
+
+		// Assert
+		assert!(trail.is_empty());
+	}
 }
@@ -12,8 +12,11 @@
 //! - `create` - Create operations
 //! - `update` - Update operations
 //! - `delete` - Delete operations (including bulk delete)
+//! - `lock` - Edit locks for the change view
 //! - `export` - Export operations
 //! - `import` - Import operations
+//! - `retention` - Upcoming data retention report
+//! - `api` - REST/JSON endpoints over the same operations for custom frontends
 //!
 //! # Server Functions
 //!
@@ -36,6 +39,9 @@
 // Allow missing docs for all server function submodules.
 #[cfg(server)]
 pub(crate) mod admin_auth;
+/// Headless JSON API mirroring the admin server functions.
+#[cfg(server)]
+pub mod api;
 #[allow(missing_docs)]
 pub mod create;
 #[allow(missing_docs)]
@@ -58,9 +64,15 @@ pub mod limits;
 #[allow(missing_docs)]
 pub mod list;
 #[allow(missing_docs)]
+pub mod lock;
+#[allow(missing_docs)]
 pub mod login;
 #[allow(missing_docs)]
 pub mod logout;
+#[allow(missing_docs)]
+pub mod personal_data;
+#[allow(missing_docs)]
+pub mod retention;
 mod serde_helpers;
 #[allow(missing_docs)]
 pub mod update;
@@ -78,6 +90,10 @@ pub mod security;
 
 // Server-side only modules
 #[cfg(server)]
+pub mod edit_locks;
+#[cfg(server)]
+pub mod recent_actions;
+#[cfg(server)]
 pub mod type_inference;
 #[cfg(server)]
 pub mod validation;
@@ -93,6 +109,9 @@ pub use export::*;
 pub use fields::*;
 pub use import::*;
 pub use list::*;
+pub use lock::*;
+pub use personal_data::*;
+pub use retention::*;
 pub use update::*;
 #[cfg(server)]
 pub use user::AdminDefaultUser;
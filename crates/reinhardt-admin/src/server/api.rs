@@ -0,0 +1,1126 @@
+//! Headless JSON API for the admin panel
+//!
+//! Exposes the admin's CRUD, filter, action and permission operations as
+//! conventional REST endpoints so that custom frontends (including a
+//! reinhardt-pages SPA) can be built on the same [`ModelAdmin`] configuration
+//! as the bundled admin.
+//!
+//! Every endpoint delegates to the corresponding server function, so
+//! authentication, permission checks, CSRF validation, input limits and audit
+//! logging behave identically for both transports.
+//!
+//! # Endpoints
+//!
+//! Paths are relative to the admin mount point (e.g. `/admin/`):
+//!
+//! | Method | Path | Operation |
+//! |--------|------|-----------|
+//! | GET | `api/` | Site info, models and per-model permissions |
+//! | GET | `api/openapi.json` | OpenAPI 3 document for the endpoints below |
+//! | GET | `api/{model}/` | Paginated list (`page`, `page_size`, `search`, `ordering`, filters) |
+//! | POST | `api/{model}/` | Create a record |
+//! | GET | `api/{model}/fields/` | Form field definitions |
+//! | GET | `api/{model}/{id}/` | Retrieve a record |
+//! | PUT | `api/{model}/{id}/` | Update a record |
+//! | DELETE | `api/{model}/{id}/` | Delete a record |
+//! | POST | `api/{model}/actions/{action}/` | Run a bulk action on `{"ids": [...]}` |
+//! | GET | `api/{model}/{id}/download/{field}/` | Download the content of a binary field |
+//!
+//! Mutating requests carry the CSRF token in the `X-CSRFToken` header; the
+//! token is issued by `GET api/` together with the `csrftoken` cookie.
+//!
+//! Errors are returned as `{"detail": "..."}` with the HTTP status reported by
+//! the underlying server function.
+//!
+//! [`ModelAdmin`]: crate::core::ModelAdmin
+
+use super::admin_auth::AdminAuthenticatedUser;
+use super::security::{build_csrf_cookie, extract_csrf_header, generate_csrf_token};
+use super::type_inference::{
+	find_model_by_table_name, get_field_metadata, resolve_admin_field_type,
+};
+use crate::core::{AdminDatabase, AdminDatabaseKey, AdminSite, AdminSiteKey, ModelAdmin};
+use crate::types::{
+	ApiIndexResponse, ApiModelInfo, BulkDeleteRequest, FieldType, ListQueryParams,
+	ModelPermissions, MutationRequest, UserDataRequest,
+};
+use hyper::StatusCode;
+use reinhardt_di::{Depends, DiError, Injectable, InjectionContext};
+use reinhardt_http::{Request, Response, Result, SharedResponseCookies};
+use reinhardt_pages::server_fn::{ServerFnError, ServerFnRequest};
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Name of the built-in bulk action that deletes the selected records.
+pub const DELETE_SELECTED_ACTION: &str = "delete_selected";
+
+/// Name of the action exporting the personal data of the selected users.
+pub const EXPORT_USER_DATA_ACTION: &str = "export_user_data";
+
+/// Name of the action anonymizing the personal data of the selected users.
+pub const ANONYMIZE_USER_ACTION: &str = "anonymize_user";
+
+/// Query parameters of the list endpoint that are not treated as filters.
+const RESERVED_LIST_PARAMS: &[&str] = &["page", "page_size", "search", "ordering", "sort_by"];
+
+/// Dependencies shared by every API endpoint, resolved from the request's DI context.
+struct ApiContext {
+	site: Depends<AdminSiteKey, AdminSite>,
+	db: Depends<AdminDatabaseKey, AdminDatabase>,
+	http_request: ServerFnRequest,
+	user: AdminAuthenticatedUser,
+	cookies: SharedResponseCookies,
+}
+
+impl ApiContext {
+	async fn resolve(request: &Request) -> std::result::Result<Self, ServerFnError> {
+		let shared = request
+			.get_di_context::<Arc<InjectionContext>>()
+			.ok_or_else(|| ServerFnError::server(500, "DI context not set"))?;
+
+		// Handlers that set cookies (CSRF issuance) write into this jar; the
+		// request clone below shares the extension store with `request`.
+		let cookies = SharedResponseCookies::new();
+		request.extensions.insert(cookies.clone());
+
+		let ctx = Arc::new(shared.fork_for_request(request.clone_for_di()));
+		let site = Depends::<AdminSiteKey, AdminSite>::resolve_from_registry(&ctx, true)
+			.await
+			.map_err(di_error)?;
+		let db = Depends::<AdminDatabaseKey, AdminDatabase>::resolve_from_registry(&ctx, true)
+			.await
+			.map_err(di_error)?;
+		let user = AdminAuthenticatedUser::inject(&ctx)
+			.await
+			.map_err(di_error)?;
+
+		Ok(Self {
+			site,
+			db,
+			http_request: ServerFnRequest(Arc::new(request.clone_for_di())),
+			user,
+			cookies,
+		})
+	}
+
+	/// Returns the CSRF token sent in the `X-CSRFToken` header, or an empty
+	/// string so the server function reports the validation failure.
+	fn csrf_token(&self) -> String {
+		extract_csrf_header(&self.http_request.inner().headers).unwrap_or_default()
+	}
+}
+
+/// Maps a DI resolution failure to the status a client should see.
+fn di_error(error: DiError) -> ServerFnError {
+	match error {
+		DiError::Authentication(_) => ServerFnError::server(401, "Authentication required"),
+		DiError::Authorization(_) => ServerFnError::server(403, "Permission denied"),
+		DiError::ResolutionFailed { source, .. } => di_error(*source),
+		other => {
+			tracing::error!(error = %other, "Admin API dependency resolution failed");
+			ServerFnError::server(500, "Internal server error")
+		}
+	}
+}
+
+/// Converts a server function error into a JSON error response.
+pub(crate) fn error_response(error: &ServerFnError) -> Response {
+	let status = match error {
+		ServerFnError::Server { status, .. } => {
+			StatusCode::from_u16(*status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+		}
+		ServerFnError::Application(_) | ServerFnError::Deserialization(_) => {
+			StatusCode::BAD_REQUEST
+		}
+		ServerFnError::Network(_) | ServerFnError::Serialization(_) => {
+			StatusCode::INTERNAL_SERVER_ERROR
+		}
+	};
+	let body = json!({ "detail": error.message() }).to_string();
+	Response::new(status)
+		.with_header("Content-Type", "application/json")
+		.with_body(body)
+}
+
+/// Serializes an API result, applying cookies set by the server function.
+fn into_response<T: Serialize>(
+	status: StatusCode,
+	result: std::result::Result<T, ServerFnError>,
+	cookies: Option<&SharedResponseCookies>,
+) -> Result<Response> {
+	let value = match result {
+		Ok(value) => value,
+		Err(error) => return Ok(error_response(&error)),
+	};
+	let mut response = Response::new(status).with_json(&value)?;
+	if let Some(jar) = cookies {
+		for cookie in jar.take().cookies() {
+			response = response.append_header("Set-Cookie", cookie);
+		}
+	}
+	Ok(response)
+}
+
+fn path_param(request: &Request, name: &str) -> std::result::Result<String, ServerFnError> {
+	request
+		.path_params
+		.get(name)
+		.cloned()
+		.ok_or_else(|| ServerFnError::server(404, format!("Missing path parameter '{}'", name)))
+}
+
+/// Resolves a model name from its URL segment, which may be lowercased.
+fn model_name_from_segment(site: &AdminSite, segment: &str) -> String {
+	site.registered_models()
+		.into_iter()
+		.find(|name| name.eq_ignore_ascii_case(segment))
+		.unwrap_or_else(|| segment.to_string())
+}
+
+/// Parses the request body as a JSON object.
+fn json_object(request: &Request) -> std::result::Result<Map<String, Value>, ServerFnError> {
+	match request.json::<Value>() {
+		Ok(Value::Object(map)) => Ok(map),
+		Ok(_) => Err(ServerFnError::server(
+			400,
+			"Request body must be a JSON object",
+		)),
+		Err(e) => Err(ServerFnError::server(
+			400,
+			format!("Invalid JSON body: {}", e),
+		)),
+	}
+}
+
+/// Builds list parameters from the query string.
+///
+/// Unreserved parameters become filters and pass through the same validation
+/// as the server function transport.
+fn list_params(
+	query: &HashMap<String, String>,
+) -> std::result::Result<ListQueryParams, ServerFnError> {
+	let parse_number = |key: &str| -> std::result::Result<Option<u64>, ServerFnError> {
+		query
+			.get(key)
+			.map(|v| {
+				v.parse::<u64>().map_err(|_| {
+					ServerFnError::server(400, format!("'{}' must be a positive integer", key))
+				})
+			})
+			.transpose()
+	};
+	let filters: Map<String, Value> = query
+		.iter()
+		.filter(|(key, _)| !RESERVED_LIST_PARAMS.contains(&key.as_str()))
+		.map(|(key, value)| (key.clone(), Value::String(value.clone())))
+		.collect();
+	let mut params: ListQueryParams = serde_json::from_value(json!({ "filters": filters }))
+		.map_err(|e| ServerFnError::server(400, e.to_string()))?;
+	params.page = parse_number("page")?;
+	params.page_size = parse_number("page_size")?;
+	params.search = query.get("search").cloned();
+	params.sort_by = query
+		.get("ordering")
+		.or_else(|| query.get("sort_by"))
+		.cloned();
+	Ok(params)
+}
+
+async fn model_permissions(
+	model_admin: &dyn ModelAdmin,
+	user: &dyn crate::core::AdminUser,
+) -> ModelPermissions {
+	ModelPermissions {
+		view: model_admin.has_view_permission(user).await,
+		add: model_admin.has_add_permission(user).await,
+		change: model_admin.has_change_permission(user).await,
+		delete: model_admin.has_delete_permission(user).await,
+	}
+}
+
+/// Bulk actions exposed for a model given the user's permissions.
+///
+/// The personal data actions are only offered on data subject (user) models.
+fn available_actions(permissions: &ModelPermissions, data_subject: bool) -> Vec<String> {
+	let mut actions = Vec::new();
+	if permissions.delete {
+		actions.push(DELETE_SELECTED_ACTION.to_string());
+	}
+	if data_subject && permissions.view {
+		actions.push(EXPORT_USER_DATA_ACTION.to_string());
+	}
+	if data_subject && permissions.change {
+		actions.push(ANONYMIZE_USER_ACTION.to_string());
+	}
+	actions
+}
+
+fn api_model_url(site: &AdminSite, model_name: &str) -> String {
+	format!("{}/api/{}/", site.url_prefix(), model_name.to_lowercase())
+}
+
+/// URL of the download endpoint for a binary field of a record.
+pub(crate) fn api_download_url(
+	site: &AdminSite,
+	model_name: &str,
+	id: &str,
+	field: &str,
+) -> String {
+	let id = percent_encoding::utf8_percent_encode(id, percent_encoding::NON_ALPHANUMERIC);
+	format!(
+		"{}{}/download/{}/",
+		api_model_url(site, model_name),
+		id,
+		field
+	)
+}
+
+/// `GET api/`
+pub(crate) async fn api_index(request: Request) -> Result<Response> {
+	let ctx = match ApiContext::resolve(&request).await {
+		Ok(ctx) => ctx,
+		Err(error) => return Ok(error_response(&error)),
+	};
+	let auth = super::error::AdminAuth::from_request(&ctx.http_request);
+	if let Err(error) = auth.require_staff() {
+		return Ok(error_response(&error));
+	}
+
+	let mut models = Vec::new();
+	for name in ctx.site.registered_models() {
+		let Ok(model_admin) = ctx.site.get_model_admin(&name) else {
+			continue;
+		};
+		let permissions = model_permissions(model_admin.as_ref(), ctx.user.0.as_ref()).await;
+		if !permissions.view {
+			continue;
+		}
+		models.push(ApiModelInfo {
+			url: api_model_url(&ctx.site, &name),
+			list_display: to_strings(model_admin.list_display()),
+			list_filter: to_strings(model_admin.list_filter()),
+			search_fields: to_strings(model_admin.search_fields()),
+			ordering: to_strings(model_admin.ordering()),
+			actions: available_actions(
+				&permissions,
+				super::personal_data::is_data_subject_table(model_admin.table_name()),
+			),
+			permissions,
+			name,
+		});
+	}
+	models.sort_by(|a, b| a.name.cmp(&b.name));
+
+	let csrf_token = generate_csrf_token();
+	ctx.http_request.add_response_cookie(build_csrf_cookie(
+		&csrf_token,
+		ctx.http_request.inner().is_secure,
+	));
+
+	let response = ApiIndexResponse {
+		site_name: ctx.site.name().to_string(),
+		site_header: crate::settings::get_admin_settings().site_header.clone(),
+		models,
+		csrf_token: Some(csrf_token),
+	};
+	into_response(StatusCode::OK, Ok(response), Some(&ctx.cookies))
+}
+
+/// `GET api/openapi.json`
+pub(crate) async fn api_openapi(request: Request) -> Result<Response> {
+	let ctx = match ApiContext::resolve(&request).await {
+		Ok(ctx) => ctx,
+		Err(error) => return Ok(error_response(&error)),
+	};
+	let auth = super::error::AdminAuth::from_request(&ctx.http_request);
+	if let Err(error) = auth.require_staff() {
+		return Ok(error_response(&error));
+	}
+	into_response(StatusCode::OK, Ok(openapi_document(&ctx.site)), None)
+}
+
+/// `GET api/{model}/`
+pub(crate) async fn api_list(request: Request) -> Result<Response> {
+	let result = async {
+		let ctx = ApiContext::resolve(&request).await?;
+		let model_name = model_name_from_segment(&ctx.site, &path_param(&request, "model")?);
+		let params = list_params(&request.query_params)?;
+		super::get_list(model_name, params, ctx.site, ctx.db, ctx.user).await
+	}
+	.await;
+	into_response(StatusCode::OK, result, None)
+}
+
+/// `POST api/{model}/`
+pub(crate) async fn api_create(request: Request) -> Result<Response> {
+	let result = async {
+		let ctx = ApiContext::resolve(&request).await?;
+		let model_name = model_name_from_segment(&ctx.site, &path_param(&request, "model")?);
+		let data = json_object(&request)?.into_iter().collect();
+		let body = MutationRequest {
+			csrf_token: ctx.csrf_token(),
+			data,
+		};
+		super::create_record(
+			model_name,
+			body,
+			ctx.site,
+			ctx.db,
+			ctx.http_request,
+			ctx.user,
+		)
+		.await
+	}
+	.await;
+	into_response(StatusCode::CREATED, result, None)
+}
+
+/// `GET api/{model}/fields/`
+pub(crate) async fn api_fields(request: Request) -> Result<Response> {
+	let result = async {
+		let ctx = ApiContext::resolve(&request).await?;
+		let model_name = model_name_from_segment(&ctx.site, &path_param(&request, "model")?);
+		let id = request.query_params.get("id").cloned();
+		super::get_fields(model_name, id, ctx.site, ctx.db, ctx.http_request, ctx.user).await
+	}
+	.await;
+	into_response(StatusCode::OK, result, None)
+}
+
+/// `GET api/{model}/{id}/`
+pub(crate) async fn api_detail(request: Request) -> Result<Response> {
+	let result = async {
+		let ctx = ApiContext::resolve(&request).await?;
+		let model_name = model_name_from_segment(&ctx.site, &path_param(&request, "model")?);
+		let id = path_param(&request, "id")?;
+		super::get_detail(model_name, id, ctx.site, ctx.db, ctx.http_request, ctx.user).await
+	}
+	.await;
+	into_response(StatusCode::OK, result, None)
+}
+
+/// `GET api/{model}/{id}/download/{field}/`
+///
+/// Returns the content of a binary field as an attachment. The record is
+/// loaded through [`get_detail`](super::get_detail), so the view permission
+/// applies.
+pub(crate) async fn api_download(request: Request) -> Result<Response> {
+	let result = async {
+		let ctx = ApiContext::resolve(&request).await?;
+		let model_name = model_name_from_segment(&ctx.site, &path_param(&request, "model")?);
+		let id = path_param(&request, "id")?;
+		let field = path_param(&request, "field")?;
+		let model_admin = ctx
+			.site
+			.get_model_admin(&model_name)
+			.map_err(|e| ServerFnError::server(404, e.to_string()))?;
+		let detail = super::get_detail(
+			model_name.clone(),
+			id.clone(),
+			ctx.site,
+			ctx.db,
+			ctx.http_request,
+			ctx.user,
+		)
+		.await?;
+		if resolve_admin_field_type(model_admin.as_ref(), &field) != Some(FieldType::File) {
+			return Err(ServerFnError::server(
+				404,
+				format!("'{}' is not a binary field of {}", field, model_name),
+			));
+		}
+		let content = match detail.data.get(&field) {
+			Some(Value::String(encoded)) => decode_binary(encoded)?,
+			_ => {
+				return Err(ServerFnError::server(
+					404,
+					format!("'{}' has no content", field),
+				));
+			}
+		};
+		Ok((
+			format!("{}-{}-{}.bin", model_name.to_lowercase(), id, field),
+			content,
+		))
+	}
+	.await;
+	let (filename, content) = match result {
+		Ok(download) => download,
+		Err(error) => return Ok(error_response(&error)),
+	};
+	let filename: String = filename
+		.chars()
+		.map(|c| {
+			if c.is_ascii_alphanumeric() || "-_.".contains(c) {
+				c
+			} else {
+				'_'
+			}
+		})
+		.collect();
+	Ok(Response::ok()
+		.with_header("Content-Type", "application/octet-stream")
+		.with_header(
+			"Content-Disposition",
+			&format!("attachment; filename=\"{}\"", filename),
+		)
+		.with_header("X-Content-Type-Options", "nosniff")
+		.with_body(content))
+}
+
+/// Decodes a binary column value, which the database layer renders as base64.
+fn decode_binary(encoded: &str) -> std::result::Result<Vec<u8>, ServerFnError> {
+	use base64::Engine;
+	base64::engine::general_purpose::STANDARD
+		.decode(encoded)
+		.map_err(|_| ServerFnError::server(500, "Stored binary value is not valid base64"))
+}
+
+/// `PUT api/{model}/{id}/`
+pub(crate) async fn api_update(request: Request) -> Result<Response> {
+	let result = async {
+		let ctx = ApiContext::resolve(&request).await?;
+		let model_name = model_name_from_segment(&ctx.site, &path_param(&request, "model")?);
+		let id = path_param(&request, "id")?;
+		let data = json_object(&request)?.into_iter().collect();
+		let body = MutationRequest {
+			csrf_token: ctx.csrf_token(),
+			data,
+		};
+		super::update_record(
+			model_name,
+			id,
+			body,
+			ctx.site,
+			ctx.db,
+			ctx.http_request,
+			ctx.user,
+		)
+		.await
+	}
+	.await;
+	into_response(StatusCode::OK, result, None)
+}
+
+/// `DELETE api/{model}/{id}/`
+pub(crate) async fn api_delete(request: Request) -> Result<Response> {
+	let result = async {
+		let ctx = ApiContext::resolve(&request).await?;
+		let model_name = model_name_from_segment(&ctx.site, &path_param(&request, "model")?);
+		let id = path_param(&request, "id")?;
+		let csrf_token = ctx.csrf_token();
+		super::delete_record(
+			model_name,
+			id,
+			csrf_token,
+			ctx.site,
+			ctx.db,
+			ctx.http_request,
+			ctx.user,
+		)
+		.await
+	}
+	.await;
+	into_response(StatusCode::OK, result, None)
+}
+
+/// `POST api/{model}/actions/{action}/`
+pub(crate) async fn api_action(request: Request) -> Result<Response> {
+	let result = async {
+		let ctx = ApiContext::resolve(&request).await?;
+		let model_name = model_name_from_segment(&ctx.site, &path_param(&request, "model")?);
+		let action = path_param(&request, "action")?;
+		if ![
+			DELETE_SELECTED_ACTION,
+			EXPORT_USER_DATA_ACTION,
+			ANONYMIZE_USER_ACTION,
+		]
+		.contains(&action.as_str())
+		{
+			return Err(ServerFnError::server(
+				404,
+				format!("Unknown action '{}'", action),
+			));
+		}
+		let ids: Vec<String> = match json_object(&request)?.remove("ids") {
+			Some(ids) => serde_json::from_value::<Vec<Value>>(ids)
+				.map_err(|_| ServerFnError::server(400, "'ids' must be an array"))?
+				.into_iter()
+				.map(|id| match id {
+					Value::String(s) => s,
+					other => other.to_string(),
+				})
+				.collect(),
+			None => return Err(ServerFnError::server(400, "'ids' is required")),
+		};
+		let csrf_token = ctx.csrf_token();
+		let response = match action.as_str() {
+			EXPORT_USER_DATA_ACTION => serde_json::to_value(
+				super::export_user_data_records(
+					model_name,
+					UserDataRequest { csrf_token, ids },
+					ctx.site,
+					ctx.db,
+					ctx.http_request,
+					ctx.user,
+				)
+				.await?,
+			),
+			ANONYMIZE_USER_ACTION => serde_json::to_value(
+				super::anonymize_user_records(
+					model_name,
+					UserDataRequest { csrf_token, ids },
+					ctx.site,
+					ctx.db,
+					ctx.http_request,
+					ctx.user,
+				)
+				.await?,
+			),
+			_ => serde_json::to_value(
+				super::bulk_delete_records(
+					model_name,
+					BulkDeleteRequest { csrf_token, ids },
+					ctx.site,
+					ctx.db,
+					ctx.http_request,
+					ctx.user,
+				)
+				.await?,
+			),
+		};
+		response.map_err(|e| ServerFnError::serialization(e.to_string()))
+	}
+	.await;
+	into_response(StatusCode::OK, result, None)
+}
+
+fn to_strings(values: Vec<&str>) -> Vec<String> {
+	values.into_iter().map(str::to_string).collect()
+}
+
+/// JSON Schema for a single admin field.
+fn field_schema(field_type: &FieldType) -> Value {
+	match field_type {
+		FieldType::Number => json!({ "type": "number" }),
+		FieldType::Boolean => json!({ "type": "boolean" }),
+		FieldType::Email => json!({ "type": "string", "format": "email" }),
+		FieldType::Date => json!({ "type": "string", "format": "date" }),
+		FieldType::DateTime => json!({ "type": "string", "format": "date-time" }),
+		FieldType::Select { choices } => {
+			json!({ "type": "string", "enum": choices.iter().map(|(v, _)| v).collect::<Vec<_>>() })
+		}
+		FieldType::MultiSelect { choices } => json!({
+			"type": "array",
+			"items": { "type": "string", "enum": choices.iter().map(|(v, _)| v).collect::<Vec<_>>() },
+		}),
+		FieldType::Text | FieldType::TextArea | FieldType::File | FieldType::Hidden => {
+			json!({ "type": "string" })
+		}
+		FieldType::ForeignKey { related_model, .. } => {
+			json!({ "type": "string", "x-related-model": related_model })
+		}
+	}
+}
+
+/// JSON Schema describing a model's records.
+///
+/// Table and column comments registered from the model's doc comments
+/// become the schema's `description`s.
+fn model_schema(model_admin: &dyn ModelAdmin) -> Value {
+	let table_name = model_admin.table_name();
+	let mut fields = model_admin
+		.fields()
+		.unwrap_or_else(|| model_admin.list_display());
+	let pk_field = model_admin.pk_field();
+	if !fields.contains(&pk_field) {
+		fields.insert(0, pk_field);
+	}
+	let readonly = model_admin.readonly_fields();
+	let properties: Map<String, Value> = fields
+		.into_iter()
+		.map(|field| {
+			let mut schema = resolve_admin_field_type(model_admin, field)
+				.map(|field_type| field_schema(&field_type))
+				.unwrap_or_else(|| json!({}));
+			if let Value::Object(map) = &mut schema {
+				if field == pk_field || readonly.contains(&field) {
+					map.insert("readOnly".to_string(), Value::Bool(true));
+				}
+				if let Some(comment) = get_field_metadata(table_name, field)
+					.and_then(|meta| meta.comment().map(str::to_string))
+				{
+					map.insert("description".to_string(), Value::String(comment));
+				}
+			}
+			(field.to_string(), schema)
+		})
+		.collect();
+	let mut schema = json!({ "type": "object", "properties": properties });
+	if let Some(comment) = find_model_by_table_name(table_name)
+		.and_then(|model| model.table_comment().map(str::to_string))
+	{
+		schema["description"] = Value::String(comment);
+	}
+	schema
+}
+
+fn json_content(schema: Value) -> Value {
+	json!({ "content": { "application/json": { "schema": schema } } })
+}
+
+fn response_ref(name: &str, description: &str) -> Value {
+	let mut response = json_content(json!({ "$ref": format!("#/components/schemas/{}", name) }));
+	response["description"] = Value::String(description.to_string());
+	response
+}
+
+/// Builds the OpenAPI 3 document describing the JSON API for every registered model.
+pub fn openapi_document(site: &AdminSite) -> Value {
+	let base = format!("{}/api", site.url_prefix());
+	let csrf = json!({ "name": "X-CSRFToken", "in": "header", "required": true, "schema": { "type": "string" } });
+	let error = json!({ "$ref": "#/components/responses/Error" });
+	let mutation = response_ref("MutationResponse", "Mutation result");
+
+	let mut paths = Map::new();
+	let mut schemas = Map::new();
+	paths.insert(
+		format!("{}/", base),
+		json!({ "get": {
+			"operationId": "admin_api_index",
+			"summary": "List models and permissions",
+			"responses": { "200": { "description": "Admin index" }, "401": error },
+		}}),
+	);
+
+	let mut models = site.registered_models();
+	models.sort();
+	for name in models {
+		let Ok(model_admin) = site.get_model_admin(&name) else {
+			continue;
+		};
+		let segment = name.to_lowercase();
+		let schema_ref = json!({ "$ref": format!("#/components/schemas/{}", name) });
+		schemas.insert(name.clone(), model_schema(model_admin.as_ref()));
+
+		let mut list_parameters = vec![
+			json!({ "name": "page", "in": "query", "schema": { "type": "integer", "minimum": 1 } }),
+			json!({ "name": "page_size", "in": "query", "schema": { "type": "integer", "minimum": 1 } }),
+			json!({ "name": "ordering", "in": "query", "schema": { "type": "string" } }),
+		];
+		if !model_admin.search_fields().is_empty() {
+			list_parameters
+				.push(json!({ "name": "search", "in": "query", "schema": { "type": "string" } }));
+		}
+		for field in model_admin.list_filter() {
+			list_parameters
+				.push(json!({ "name": field, "in": "query", "schema": { "type": "string" } }));
+		}
+		let id_param =
+			json!({ "name": "id", "in": "path", "required": true, "schema": { "type": "string" } });
+		let mut list_response = json_content(json!({
+			"type": "object",
+			"properties": {
+				"count": { "type": "integer" },
+				"page": { "type": "integer" },
+				"page_size": { "type": "integer" },
+				"total_pages": { "type": "integer" },
+				"results": { "type": "array", "items": schema_ref.clone() },
+			},
+		}));
+		list_response["description"] = Value::String(format!("Page of {} records", name));
+		let mut detail_response = json_content(json!({
+			"type": "object",
+			"properties": {
+				"model_name": { "type": "string" },
+				"data": schema_ref.clone(),
+				"inlines": {
+					"type": "array",
+					"items": { "$ref": "#/components/schemas/GenericInlineRows" },
+				},
+				"downloads": {
+					"type": "object",
+					"additionalProperties": { "type": "string" },
+				},
+			},
+		}));
+		detail_response["description"] = Value::String(format!("{} record", name));
+		let mut request_body = json_content(schema_ref.clone());
+		request_body["required"] = Value::Bool(true);
+
+		paths.insert(
+			format!("{}/{}/", base, segment),
+			json!({
+				"get": {
+					"operationId": format!("admin_{}_list", segment),
+					"tags": [name],
+					"parameters": list_parameters,
+					"responses": { "200": list_response, "403": error },
+				},
+				"post": {
+					"operationId": format!("admin_{}_create", segment),
+					"tags": [name],
+					"parameters": [csrf],
+					"requestBody": request_body,
+					"responses": { "201": mutation, "400": error, "403": error },
+				},
+			}),
+		);
+		paths.insert(
+			format!("{}/{}/fields/", base, segment),
+			json!({ "get": {
+				"operationId": format!("admin_{}_fields", segment),
+				"tags": [name],
+				"parameters": [{ "name": "id", "in": "query", "schema": { "type": "string" } }],
+				"responses": { "200": { "description": "Form field definitions" }, "403": error },
+			}}),
+		);
+		paths.insert(
+			format!("{}/{}/{{id}}/", base, segment),
+			json!({
+				"parameters": [id_param],
+				"get": {
+					"operationId": format!("admin_{}_retrieve", segment),
+					"tags": [name],
+					"responses": { "200": detail_response, "404": error },
+				},
+				"put": {
+					"operationId": format!("admin_{}_update", segment),
+					"tags": [name],
+					"parameters": [csrf],
+					"requestBody": request_body,
+					"responses": { "200": mutation, "400": error, "403": error, "404": error },
+				},
+				"delete": {
+					"operationId": format!("admin_{}_delete", segment),
+					"tags": [name],
+					"parameters": [csrf],
+					"responses": { "200": mutation, "403": error, "404": error },
+				},
+			}),
+		);
+		paths.insert(
+			format!("{}/{}/{{id}}/download/{{field}}/", base, segment),
+			json!({ "get": {
+				"operationId": format!("admin_{}_download", segment),
+				"tags": [name],
+				"parameters": [
+					id_param,
+					{ "name": "field", "in": "path", "required": true, "schema": { "type": "string" } },
+				],
+				"responses": {
+					"200": {
+						"description": "Binary field content",
+						"content": { "application/octet-stream": { "schema": { "type": "string", "format": "binary" } } },
+					},
+					"404": error,
+				},
+			}}),
+		);
+		paths.insert(
+			format!("{}/{}/actions/{}/", base, segment, DELETE_SELECTED_ACTION),
+			json!({ "post": {
+				"operationId": format!("admin_{}_{}", segment, DELETE_SELECTED_ACTION),
+				"tags": [name],
+				"parameters": [csrf],
+				"requestBody": json_content(json!({
+					"type": "object",
+					"required": ["ids"],
+					"properties": { "ids": { "type": "array", "items": { "type": "string" } } },
+				})),
+				"responses": {
+					"200": response_ref("BulkDeleteResponse", "Bulk delete result"),
+					"403": error,
+				},
+			}}),
+		);
+	}
+
+	schemas.insert(
+		"MutationResponse".to_string(),
+		json!({
+			"type": "object",
+			"properties": {
+				"success": { "type": "boolean" },
+				"message": { "type": "string" },
+				"affected": { "type": "integer" },
+				"data": { "type": "object" },
+			},
+		}),
+	);
+	schemas.insert(
+		"GenericInlineRows".to_string(),
+		json!({
+			"type": "object",
+			"properties": {
+				"model_name": { "type": "string" },
+				"label": { "type": "string" },
+				"fields": { "type": "array", "items": { "type": "string" } },
+				"rows": { "type": "array", "items": { "type": "object" } },
+				"upload_url": { "type": "string" },
+			},
+		}),
+	);
+	schemas.insert(
+		"BulkDeleteResponse".to_string(),
+		json!({
+			"type": "object",
+			"properties": {
+				"success": { "type": "boolean" },
+				"deleted": { "type": "integer" },
+				"message": { "type": "string" },
+			},
+		}),
+	);
+
+	json!({
+		"openapi": "3.0.3",
+		"info": { "title": format!("{} API", site.name()), "version": env!("CARGO_PKG_VERSION") },
+		"paths": paths,
+		"components": {
+			"schemas": schemas,
+			"responses": {
+				"Error": {
+					"description": "Error",
+					"content": { "application/json": { "schema": {
+						"type": "object",
+						"properties": { "detail": { "type": "string" } },
+					}}},
+				},
+			},
+		},
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::core::ModelAdminConfig;
+	use reinhardt_db::migrations::{
+		FieldMetadata, FieldType as DbFieldType, ModelMetadata, global_registry,
+	};
+	use rstest::rstest;
+
+	fn site_with_user_admin() -> AdminSite {
+		let site = AdminSite::new("Test Admin");
+		let config = ModelAdminConfig::builder()
+			.model_name("User")
+			.table_name("users")
+			.list_display(vec!["id", "username"])
+			.list_filter(vec!["is_active"])
+			.search_fields(vec!["username"])
+			.build()
+			.unwrap();
+		site.register("User", config).unwrap();
+		site
+	}
+
+	#[rstest]
+	fn test_openapi_document_describes_model_endpoints() {
+		// Arrange
+		let site = site_with_user_admin();
+
+		// Act
+		let doc = openapi_document(&site);
+
+		// Assert
+		assert_eq!(doc["openapi"], "3.0.3");
+		let paths = doc["paths"].as_object().unwrap();
+		assert!(paths.contains_key("/admin/api/"));
+		assert!(paths["/admin/api/user/"]["get"].is_object());
+		assert!(paths["/admin/api/user/"]["post"].is_object());
+		assert!(paths["/admin/api/user/{id}/"]["put"].is_object());
+		assert!(paths["/admin/api/user/{id}/"]["delete"].is_object());
+		assert!(paths["/admin/api/user/actions/delete_selected/"]["post"].is_object());
+		assert!(doc["components"]["schemas"]["User"]["properties"]["username"].is_object());
+	}
+
+	#[rstest]
+	fn test_openapi_list_parameters_include_filters_and_search() {
+		// Arrange
+		let site = site_with_user_admin();
+
+		// Act
+		let doc = openapi_document(&site);
+
+		// Assert
+		let names: Vec<&str> = doc["paths"]["/admin/api/user/"]["get"]["parameters"]
+			.as_array()
+			.unwrap()
+			.iter()
+			.map(|p| p["name"].as_str().unwrap())
+			.collect();
+		assert!(names.contains(&"search"));
+		assert!(names.contains(&"is_active"));
+		assert!(names.contains(&"ordering"));
+	}
+
+	#[rstest]
+	fn test_openapi_marks_primary_key_read_only() {
+		// Arrange
+		let site = site_with_user_admin();
+
+		// Act
+		let doc = openapi_document(&site);
+
+		// Assert
+		assert_eq!(
+			doc["components"]["schemas"]["User"]["properties"]["id"]["readOnly"],
+			true
+		);
+	}
+
+	#[rstest]
+	fn test_openapi_describes_fields_with_database_comments() {
+		// Arrange
+		let mut metadata = ModelMetadata::new("shop", "Product", "shop_product");
+		metadata.set_option(
+			"db_table_comment".to_string(),
+			"Items listed in the catalog".to_string(),
+		);
+		metadata.add_field(
+			"sku".to_string(),
+			FieldMetadata::new(DbFieldType::VarChar(32))
+				.with_param("db_comment", "Stock keeping unit"),
+		);
+		global_registry().register_model(metadata);
+		let site = AdminSite::new("Test Admin");
+		let config = ModelAdminConfig::builder()
+			.model_name("Product")
+			.table_name("shop_product")
+			.list_display(vec!["id", "sku"])
+			.build()
+			.unwrap();
+		site.register("Product", config).unwrap();
+
+		// Act
+		let doc = openapi_document(&site);
+
+		// Assert
+		let schema = &doc["components"]["schemas"]["Product"];
+		assert_eq!(schema["description"], "Items listed in the catalog");
+		assert_eq!(
+			schema["properties"]["sku"]["description"],
+			"Stock keeping unit"
+		);
+		assert!(schema["properties"]["id"].get("description").is_none());
+	}
+
+	#[rstest]
+	fn test_list_params_splits_reserved_keys_from_filters() {
+		// Arrange
+		let query = HashMap::from([
+			("page".to_string(), "2".to_string()),
+			("ordering".to_string(), "-id".to_string()),
+			("search".to_string(), "alice".to_string()),
+			("is_active".to_string(), "true".to_string()),
+		]);
+
+		// Act
+		let params = list_params(&query).unwrap();
+
+		// Assert
+		assert_eq!(params.page, Some(2));
+		assert_eq!(params.sort_by.as_deref(), Some("-id"));
+		assert_eq!(params.search.as_deref(), Some("alice"));
+		assert_eq!(params.filters.len(), 1);
+		assert_eq!(params.filters["is_active"], "true");
+	}
+
+	#[rstest]
+	#[case("page", "abc")]
+	#[case("page_size", "-1")]
+	fn test_list_params_rejects_invalid_numbers(#[case] key: &str, #[case] value: &str) {
+		// Arrange
+		let query = HashMap::from([(key.to_string(), value.to_string())]);
+
+		// Act
+		let result = list_params(&query);
+
+		// Assert
+		assert!(matches!(
+			result,
+			Err(ServerFnError::Server { status: 400, .. })
+		));
+	}
+
+	#[rstest]
+	fn test_list_params_rejects_invalid_filter_keys() {
+		// Arrange
+		let query = HashMap::from([("bad key!".to_string(), "x".to_string())]);
+
+		// Act
+		let result = list_params(&query);
+
+		// Assert
+		assert!(matches!(
+			result,
+			Err(ServerFnError::Server { status: 400, .. })
+		));
+	}
+
+	#[rstest]
+	#[case(ServerFnError::server(404, "missing"), StatusCode::NOT_FOUND)]
+	#[case(ServerFnError::server(403, "denied"), StatusCode::FORBIDDEN)]
+	#[case(ServerFnError::application("invalid"), StatusCode::BAD_REQUEST)]
+	#[case(ServerFnError::network("down"), StatusCode::INTERNAL_SERVER_ERROR)]
+	fn test_error_response_status(#[case] error: ServerFnError, #[case] expected: StatusCode) {
+		// Act
+		let response = error_response(&error);
+
+		// Assert
+		assert_eq!(response.status, expected);
+		let body: Value = serde_json::from_slice(&response.body).unwrap();
+		assert_eq!(body["detail"], error.message());
+	}
+
+	#[rstest]
+	fn test_di_error_maps_authentication_to_unauthorized() {
+		// Arrange
+		let error = DiError::ResolutionFailed {
+			path: vec!["AdminAuthenticatedUser".to_string()],
+			source: Box::new(DiError::Authentication("no user".to_string())),
+		};
+
+		// Act
+		let mapped = di_error(error);
+
+		// Assert
+		assert!(matches!(mapped, ServerFnError::Server { status: 401, .. }));
+	}
+
+	#[rstest]
+	fn test_available_actions_requires_delete_permission() {
+		// Arrange
+		let allowed = ModelPermissions {
+			delete: true,
+			..Default::default()
+		};
+
+		// Act & Assert
+		assert_eq!(
+			available_actions(&allowed, false),
+			vec![DELETE_SELECTED_ACTION]
+		);
+		assert!(available_actions(&ModelPermissions::default(), false).is_empty());
+	}
+
+	#[rstest]
+	fn test_available_actions_offers_personal_data_actions_on_user_models() {
+		// Arrange
+		let permissions = ModelPermissions {
+			view: true,
+			change: true,
+			..Default::default()
+		};
+
+		// Act & Assert
+		assert_eq!(
+			available_actions(&permissions, true),
+			vec![EXPORT_USER_DATA_ACTION, ANONYMIZE_USER_ACTION]
+		);
+		assert!(available_actions(&permissions, false).is_empty());
+	}
+}
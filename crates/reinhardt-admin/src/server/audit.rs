@@ -7,9 +7,13 @@
 //! Audit log entries include:
 //! - Timestamp of the operation
 //! - User identifier (from authentication state)
+//! - Client IP address (from [`Request::client_ip`](reinhardt_http::Request::client_ip))
 //! - Operation type (create, update, delete, bulk_delete)
 //! - Target model and record ID
 //! - Summary of changed fields (for updates)
+//!
+//! Successful operations are also kept in the in-memory
+//! [recent actions log](super::recent_actions) shown on the dashboard.
 
 use std::collections::HashMap;
 use std::fmt;
@@ -29,6 +33,8 @@ pub enum AuditAction {
 	Export,
 	/// Data was imported
 	Import,
+	/// Personal data of users was anonymized
+	Anonymize,
 }
 
 impl fmt::Display for AuditAction {
@@ -40,6 +46,7 @@ impl fmt::Display for AuditAction {
 			AuditAction::BulkDelete => write!(f, "BULK_DELETE"),
 			AuditAction::Export => write!(f, "EXPORT"),
 			AuditAction::Import => write!(f, "IMPORT"),
+			AuditAction::Anonymize => write!(f, "ANONYMIZE"),
 		}
 	}
 }
@@ -51,6 +58,8 @@ pub struct AuditEntry {
 	pub timestamp: String,
 	/// User identifier (user ID or "anonymous")
 	pub user_id: String,
+	/// Client IP address the operation was requested from
+	pub ip_address: Option<String>,
 	/// Type of operation performed
 	pub action: AuditAction,
 	/// Name of the model affected
@@ -73,6 +82,10 @@ impl fmt::Display for AuditEntry {
 			self.timestamp, self.user_id, self.action, self.model_name,
 		)?;
 
+		if let Some(ref ip) = self.ip_address {
+			write!(f, " ip={}", ip)?;
+		}
+
 		if let Some(ref id) = self.record_id {
 			write!(f, " record_id={}", id)?;
 		}
@@ -96,6 +109,7 @@ impl fmt::Display for AuditEntry {
 /// # Arguments
 ///
 /// * `user_id` - The authenticated user's identifier
+/// * `ip_address` - The client IP address, if known
 /// * `model_name` - The model being created
 /// * `data` - The fields being set on the new record
 /// * `success` - Whether the operation succeeded
@@ -108,10 +122,11 @@ impl fmt::Display for AuditEntry {
 ///
 /// let mut data = HashMap::new();
 /// data.insert("name".to_string(), serde_json::json!("Alice"));
-/// log_create("user-42", "User", &data, true);
+/// log_create("user-42", Some("203.0.113.7"), "User", &data, true);
 /// ```
 pub fn log_create(
 	user_id: &str,
+	ip_address: Option<&str>,
 	model_name: &str,
 	data: &HashMap<String, serde_json::Value>,
 	success: bool,
@@ -119,6 +134,7 @@ pub fn log_create(
 	let entry = AuditEntry {
 		timestamp: chrono::Utc::now().to_rfc3339(),
 		user_id: user_id.to_string(),
+		ip_address: ip_address.map(str::to_string),
 		action: AuditAction::Create,
 		model_name: model_name.to_string(),
 		record_id: None,
@@ -138,6 +154,7 @@ pub fn log_create(
 /// # Arguments
 ///
 /// * `user_id` - The authenticated user's identifier
+/// * `ip_address` - The client IP address, if known
 /// * `model_name` - The model being updated
 /// * `record_id` - The primary key of the record being updated
 /// * `data` - The fields being modified
@@ -151,10 +168,11 @@ pub fn log_create(
 ///
 /// let mut data = HashMap::new();
 /// data.insert("email".to_string(), serde_json::json!("new@example.com"));
-/// log_update("user-42", "User", "123", &data, true);
+/// log_update("user-42", Some("203.0.113.7"), "User", "123", &data, true);
 /// ```
 pub fn log_update(
 	user_id: &str,
+	ip_address: Option<&str>,
 	model_name: &str,
 	record_id: &str,
 	data: &HashMap<String, serde_json::Value>,
@@ -163,6 +181,7 @@ pub fn log_update(
 	let entry = AuditEntry {
 		timestamp: chrono::Utc::now().to_rfc3339(),
 		user_id: user_id.to_string(),
+		ip_address: ip_address.map(str::to_string),
 		action: AuditAction::Update,
 		model_name: model_name.to_string(),
 		record_id: Some(record_id.to_string()),
@@ -179,6 +198,7 @@ pub fn log_update(
 /// # Arguments
 ///
 /// * `user_id` - The authenticated user's identifier
+/// * `ip_address` - The client IP address, if known
 /// * `model_name` - The model being deleted from
 /// * `record_id` - The primary key of the deleted record
 /// * `success` - Whether the operation succeeded
@@ -188,12 +208,19 @@ pub fn log_update(
 /// ```
 /// use reinhardt_admin::server::audit::log_delete;
 ///
-/// log_delete("user-42", "User", "123", true);
+/// log_delete("user-42", Some("203.0.113.7"), "User", "123", true);
 /// ```
-pub fn log_delete(user_id: &str, model_name: &str, record_id: &str, success: bool) {
+pub fn log_delete(
+	user_id: &str,
+	ip_address: Option<&str>,
+	model_name: &str,
+	record_id: &str,
+	success: bool,
+) {
 	let entry = AuditEntry {
 		timestamp: chrono::Utc::now().to_rfc3339(),
 		user_id: user_id.to_string(),
+		ip_address: ip_address.map(str::to_string),
 		action: AuditAction::Delete,
 		model_name: model_name.to_string(),
 		record_id: Some(record_id.to_string()),
@@ -205,11 +232,49 @@ pub fn log_delete(user_id: &str, model_name: &str, record_id: &str, success: boo
 	emit_audit_log(&entry);
 }
 
+/// Logs a successful delete together with a snapshot of the removed row.
+///
+/// The snapshot is kept in the [recent actions log](super::recent_actions)
+/// so the deletion can be undone within the configured window.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_admin::server::audit::log_delete_with_snapshot;
+/// use std::collections::HashMap;
+///
+/// let mut row = HashMap::new();
+/// row.insert("id".to_string(), serde_json::json!(123));
+/// log_delete_with_snapshot("user-42", Some("203.0.113.7"), "User", "123", row);
+/// ```
+pub fn log_delete_with_snapshot(
+	user_id: &str,
+	ip_address: Option<&str>,
+	model_name: &str,
+	record_id: &str,
+	snapshot: HashMap<String, serde_json::Value>,
+) {
+	let entry = AuditEntry {
+		timestamp: chrono::Utc::now().to_rfc3339(),
+		user_id: user_id.to_string(),
+		ip_address: ip_address.map(str::to_string),
+		action: AuditAction::Delete,
+		model_name: model_name.to_string(),
+		record_id: Some(record_id.to_string()),
+		changed_fields: None,
+		success: true,
+		affected_count: Some(1),
+	};
+
+	emit_audit_log_with_snapshot(&entry, Some(snapshot));
+}
+
 /// Logs a bulk delete operation to the audit trail.
 ///
 /// # Arguments
 ///
 /// * `user_id` - The authenticated user's identifier
+/// * `ip_address` - The client IP address, if known
 /// * `model_name` - The model being deleted from
 /// * `record_ids` - The primary keys of the deleted records
 /// * `affected` - Number of records actually deleted
@@ -220,10 +285,11 @@ pub fn log_delete(user_id: &str, model_name: &str, record_id: &str, success: boo
 /// ```
 /// use reinhardt_admin::server::audit::log_bulk_delete;
 ///
-/// log_bulk_delete("user-42", "User", &["1".to_string(), "2".to_string()], 2, true);
+/// log_bulk_delete("user-42", Some("203.0.113.7"), "User", &["1".to_string(), "2".to_string()], 2, true);
 /// ```
 pub fn log_bulk_delete(
 	user_id: &str,
+	ip_address: Option<&str>,
 	model_name: &str,
 	record_ids: &[String],
 	affected: u64,
@@ -232,6 +298,7 @@ pub fn log_bulk_delete(
 	let entry = AuditEntry {
 		timestamp: chrono::Utc::now().to_rfc3339(),
 		user_id: user_id.to_string(),
+		ip_address: ip_address.map(str::to_string),
 		action: AuditAction::BulkDelete,
 		model_name: model_name.to_string(),
 		record_id: Some(
@@ -245,13 +312,60 @@ pub fn log_bulk_delete(
 	emit_audit_log(&entry);
 }
 
+/// Logs a personal data export or anonymization to the audit trail.
+///
+/// # Arguments
+///
+/// * `user_id` - The authenticated user's identifier
+/// * `ip_address` - The client IP address, if known
+/// * `action` - [`AuditAction::Export`] or [`AuditAction::Anonymize`]
+/// * `model_name` - The user model the action ran on
+/// * `record_ids` - IDs of the users whose data was processed
+/// * `affected` - Number of rows exported or anonymized across all models
+/// * `success` - Whether the operation succeeded
+pub fn log_user_data(
+	user_id: &str,
+	ip_address: Option<&str>,
+	action: AuditAction,
+	model_name: &str,
+	record_ids: &[String],
+	affected: u64,
+	success: bool,
+) {
+	let entry = AuditEntry {
+		timestamp: chrono::Utc::now().to_rfc3339(),
+		user_id: user_id.to_string(),
+		ip_address: ip_address.map(str::to_string),
+		action,
+		model_name: model_name.to_string(),
+		record_id: Some(
+			serde_json::to_string(&record_ids).unwrap_or_else(|_| record_ids.join(",")),
+		),
+		changed_fields: None,
+		success,
+		affected_count: Some(affected),
+	};
+
+	emit_audit_log(&entry);
+}
+
+/// Emits an audit log entry that carries no row snapshot.
+fn emit_audit_log(entry: &AuditEntry) {
+	emit_audit_log_with_snapshot(entry, None);
+}
+
 /// Emits an audit log entry via the tracing infrastructure.
 ///
 /// Uses `info!` level for successful operations and `warn!` level for failures.
+/// Successful operations are also recorded in the recent actions log.
 #[cfg(server)]
-fn emit_audit_log(entry: &AuditEntry) {
+fn emit_audit_log_with_snapshot(
+	entry: &AuditEntry,
+	snapshot: Option<HashMap<String, serde_json::Value>>,
+) {
 	if entry.success {
 		tracing::info!("{}", entry);
+		super::recent_actions::RecentActionLog::global().record(entry.clone(), snapshot);
 	} else {
 		tracing::warn!("{}", entry);
 	}
@@ -259,7 +373,11 @@ fn emit_audit_log(entry: &AuditEntry) {
 
 /// No-op audit log on WASM targets (tracing is server-only).
 #[cfg(client)]
-fn emit_audit_log(_entry: &AuditEntry) {}
+fn emit_audit_log_with_snapshot(
+	_entry: &AuditEntry,
+	_snapshot: Option<HashMap<String, serde_json::Value>>,
+) {
+}
 
 #[cfg(all(test, server))]
 mod tests {
@@ -306,6 +424,12 @@ mod tests {
 		assert_eq!(AuditAction::Import.to_string(), "IMPORT");
 	}
 
+	#[rstest]
+	fn test_audit_action_anonymize_display() {
+		// Assert
+		assert_eq!(AuditAction::Anonymize.to_string(), "ANONYMIZE");
+	}
+
 	// ============================================================
 	// AuditEntry Display tests
 	// ============================================================
@@ -316,6 +440,7 @@ mod tests {
 		let entry = AuditEntry {
 			timestamp: "2024-01-01T00:00:00Z".to_string(),
 			user_id: "user-42".to_string(),
+			ip_address: Some("203.0.113.7".to_string()),
 			action: AuditAction::Create,
 			model_name: "User".to_string(),
 			record_id: None,
@@ -330,6 +455,7 @@ mod tests {
 		// Assert
 		assert!(output.contains("[ADMIN_AUDIT]"));
 		assert!(output.contains("user=user-42"));
+		assert!(output.contains("ip=203.0.113.7"));
 		assert!(output.contains("action=CREATE"));
 		assert!(output.contains("model=User"));
 		assert!(output.contains("changed_fields=[name, email]"));
@@ -417,7 +543,7 @@ mod tests {
 		data.insert("email".to_string(), serde_json::json!("alice@example.com"));
 
 		// Act - just verify no panic; logging goes to the log infrastructure
-		log_create("user-42", "User", &data, true);
+		log_create("user-42", None, "User", &data, true);
 	}
 
 	#[rstest]
@@ -427,13 +553,13 @@ mod tests {
 		data.insert("email".to_string(), serde_json::json!("new@example.com"));
 
 		// Act
-		log_update("user-42", "User", "123", &data, true);
+		log_update("user-42", None, "User", "123", &data, true);
 	}
 
 	#[rstest]
 	fn test_log_delete_constructs_correct_entry() {
 		// Act
-		log_delete("user-42", "User", "123", true);
+		log_delete("user-42", None, "User", "123", true);
 	}
 
 	#[rstest]
@@ -446,6 +572,7 @@ mod tests {
 		let entry = AuditEntry {
 			timestamp: chrono::Utc::now().to_rfc3339(),
 			user_id: "user-42".to_string(),
+			ip_address: None,
 			action: AuditAction::BulkDelete,
 			model_name: "User".to_string(),
 			record_id: Some(serde_json::to_string(&ids).unwrap_or_else(|_| ids.join(","))),
@@ -466,7 +593,7 @@ mod tests {
 		let data = HashMap::new();
 
 		// Act
-		log_create("user-42", "User", &data, false);
+		log_create("user-42", None, "User", &data, false);
 	}
 
 	// ============================================================
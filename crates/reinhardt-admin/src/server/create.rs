@@ -19,14 +19,17 @@ use super::audit;
 #[cfg(server)]
 use super::error::{AdminAuth, MapServerFnError, ModelPermission};
 #[cfg(server)]
-use super::security::{require_csrf_token, sanitize_mutation_values};
+use super::security::{require_csrf_token, sanitize_mutation_values_with_rich_text};
 #[cfg(server)]
 use super::validation::validate_mutation_data;
+#[cfg(server)]
+use std::collections::HashMap;
 
 /// Create a new model instance
 ///
 /// Inserts a new record into the database using the provided field data.
-/// Returns the number of affected rows (typically 1) on success.
+/// Returns the number of affected rows (typically 1) and the new primary key
+/// in `data`, keyed by the primary key field, on success.
 ///
 /// # Server Function
 ///
@@ -75,10 +78,13 @@ pub async fn create_record(
 
 	// Validate input data before database operation
 	validate_mutation_data(&request.data, model_admin.as_ref(), false).map_server_fn_error()?;
+	model_admin
+		.validate_values(None, &request.data)
+		.map_server_fn_error()?;
 
 	// Sanitize string values to prevent stored XSS
 	let mut sanitized_data = request.data;
-	sanitize_mutation_values(&mut sanitized_data);
+	sanitize_mutation_values_with_rich_text(&mut sanitized_data, &model_admin.rich_text_fields());
 
 	// Inject current timestamp for auto_now and auto_now_add fields.
 	// These fields are typically readonly in the admin form, so the client
@@ -87,22 +93,31 @@ pub async fn create_record(
 	inject_auto_timestamps(&mut sanitized_data, table_name);
 
 	let user_id = auth.user_id().unwrap_or("unknown").to_string();
+	let client_ip = auth.client_ip().map(|ip| ip.to_string());
 
 	let result = db
-		.create::<AdminRecord>(table_name, Some(pk_field), sanitized_data.clone())
+		.create_returning_pk::<AdminRecord>(table_name, Some(pk_field), sanitized_data.clone())
 		.await
 		.map_server_fn_error();
 
 	let success = result.is_ok();
-	audit::log_create(&user_id, &model_name, &sanitized_data, success);
-
-	let affected = result?;
-
+	audit::log_create(
+		&user_id,
+		client_ip.as_deref(),
+		&model_name,
+		&sanitized_data,
+		success,
+	);
+
+	let pk = result?;
+
+	// Return the new primary key so callers such as the related-object popup
+	// can select the created record
 	Ok(MutationResponse {
 		success: true,
 		message: format!("{} created successfully", model_name),
-		affected: Some(affected),
-		data: None,
+		affected: Some(1),
+		data: Some(HashMap::from([(pk_field.to_string(), pk)])),
 	})
 }
 
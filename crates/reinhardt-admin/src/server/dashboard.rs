@@ -2,14 +2,20 @@
 //!
 //! Provides dashboard data retrieval functionality.
 
+#[cfg(server)]
+use super::recent_actions::RecentActionLog;
 use crate::adapters::{AdminSite, DashboardResponse, ModelInfo};
 #[cfg(server)]
+use crate::adapters::{AdminUser, RecentAction};
+#[cfg(server)]
 use crate::core::AdminSiteKey;
 #[cfg(server)]
 use reinhardt_di::Depends;
 #[cfg(server)]
 use reinhardt_pages::server_fn::ServerFnRequest;
 use reinhardt_pages::server_fn::{ServerFnError, server_fn};
+#[cfg(server)]
+use std::collections::HashMap;
 
 #[cfg(server)]
 use super::admin_auth::AdminAuthenticatedUser;
@@ -20,7 +26,9 @@ use super::security::{build_csrf_cookie, generate_csrf_token};
 
 /// Get dashboard data
 ///
-/// Returns dashboard information including registered models and site metadata.
+/// Returns dashboard information including registered models, the sidebar
+/// navigation and recent actions visible to the current user, and site
+/// metadata.
 ///
 /// # Server Function
 ///
@@ -44,7 +52,7 @@ use super::security::{build_csrf_cookie, generate_csrf_token};
 pub async fn get_dashboard(
 	#[inject] site: Depends<AdminSiteKey, AdminSite>,
 	#[inject] http_request: ServerFnRequest,
-	#[inject] AdminAuthenticatedUser(_user): AdminAuthenticatedUser,
+	#[inject] AdminAuthenticatedUser(user): AdminAuthenticatedUser,
 ) -> Result<DashboardResponse, ServerFnError> {
 	// Authentication and authorization check (Fixes #3679)
 	// AdminAuthenticatedUser injection performs DB lookup to verify is_active and is_staff.
@@ -62,6 +70,13 @@ pub async fn get_dashboard(
 		})
 		.collect();
 
+	// Sidebar sections filtered by the user's view permissions
+	let navigation = site.navigation_for(user.as_ref()).await;
+
+	let admin_settings = crate::settings::get_admin_settings();
+	let recent_actions =
+		recent_actions_for(&site, user.as_ref(), admin_settings.undo_window_secs).await;
+
 	// Build dashboard response with CSRF token for mutation requests
 	let csrf_token = generate_csrf_token();
 
@@ -72,8 +87,6 @@ pub async fn get_dashboard(
 	let cookie_value = build_csrf_cookie(&csrf_token, is_secure);
 	http_request.add_response_cookie(cookie_value);
 
-	let admin_settings = crate::settings::get_admin_settings();
-
 	Ok(DashboardResponse {
 		site_name: site.name().to_string(),
 		site_header: admin_settings.site_header.clone(),
@@ -81,10 +94,57 @@ pub async fn get_dashboard(
 		login_url: admin_settings.login_url.clone(),
 		logout_url: admin_settings.logout_url.clone(),
 		models,
+		navigation,
+		recent_actions,
+		widgets: site.dashboard_widgets(),
 		csrf_token: Some(csrf_token),
 	})
 }
 
+/// Number of entries shown in the dashboard's recent actions panel.
+#[cfg(server)]
+const RECENT_ACTIONS_LIMIT: usize = 10;
+
+/// Collects the latest actions on models `user` can view.
+///
+/// An action is reported as undoable only if the user may also add records
+/// to the model, since undoing a delete re-inserts the row.
+#[cfg(server)]
+async fn recent_actions_for(
+	site: &AdminSite,
+	user: &dyn AdminUser,
+	undo_window_secs: u64,
+) -> Vec<RecentAction> {
+	let window = chrono::Duration::seconds(i64::try_from(undo_window_secs).unwrap_or(i64::MAX));
+	let now = chrono::Utc::now();
+	let mut permissions: HashMap<String, (bool, bool)> = HashMap::new();
+	let mut actions = Vec::new();
+	for action in RecentActionLog::global().recent(RECENT_ACTIONS_LIMIT * 5) {
+		let model_name = &action.entry.model_name;
+		if !permissions.contains_key(model_name) {
+			let allowed = match site.get_model_admin(model_name) {
+				Ok(model_admin) => (
+					model_admin.has_view_permission(user).await,
+					model_admin.has_add_permission(user).await,
+				),
+				Err(_) => (false, false),
+			};
+			permissions.insert(model_name.clone(), allowed);
+		}
+		let (can_view, can_add) = permissions[model_name];
+		if !can_view {
+			continue;
+		}
+		let mut dto = action.to_dto(window, now);
+		dto.can_undo &= can_add;
+		actions.push(dto);
+		if actions.len() == RECENT_ACTIONS_LIMIT {
+			break;
+		}
+	}
+	actions
+}
+
 #[cfg(all(test, server))]
 mod tests {
 	use super::*;
@@ -1,6 +1,7 @@
 //! Delete operation Server Functions
 //!
-//! Provides delete operations for admin models (single and bulk).
+//! Provides delete operations for admin models (single and bulk) and undo
+//! for recent single-record deletes.
 
 #[cfg(server)]
 use super::admin_auth::AdminAuthenticatedUser;
@@ -21,6 +22,8 @@ use super::error::{AdminAuth, MapServerFnError, ModelPermission};
 #[cfg(server)]
 use super::limits::MAX_BULK_DELETE_IDS;
 #[cfg(server)]
+use super::recent_actions::{RecentActionLog, RestoredRecord, UndoError};
+#[cfg(server)]
 use super::security::require_csrf_token;
 
 /// Delete a single model instance by ID
@@ -69,6 +72,15 @@ pub async fn delete_record(
 	let pk_field = model_admin.pk_field();
 
 	let user_id = auth.user_id().unwrap_or("unknown").to_string();
+	let client_ip = auth.client_ip().map(|ip| ip.to_string());
+
+	// Capture the row so the deletion can be undone from the recent actions
+	// panel. A failed lookup only disables undo for this deletion.
+	let snapshot = db
+		.get::<AdminRecord>(table_name, pk_field, &id)
+		.await
+		.ok()
+		.flatten();
 
 	let result = db
 		.delete::<AdminRecord>(table_name, pk_field, &id)
@@ -78,7 +90,7 @@ pub async fn delete_record(
 	// Check for database errors first, logging failure before returning
 	let affected = match result {
 		Err(e) => {
-			audit::log_delete(&user_id, &model_name, &id, false);
+			audit::log_delete(&user_id, client_ip.as_deref(), &model_name, &id, false);
 			return Err(e);
 		}
 		Ok(n) => n,
@@ -87,14 +99,19 @@ pub async fn delete_record(
 	// Return 404 error when no record was found with the given ID.
 	// Only log success=true after confirming the record was actually deleted.
 	if affected == 0 {
-		audit::log_delete(&user_id, &model_name, &id, false);
+		audit::log_delete(&user_id, client_ip.as_deref(), &model_name, &id, false);
 		return Err(ServerFnError::server(
 			404,
 			format!("{} not found", model_name),
 		));
 	}
 
-	audit::log_delete(&user_id, &model_name, &id, true);
+	match snapshot {
+		Some(row) => {
+			audit::log_delete_with_snapshot(&user_id, client_ip.as_deref(), &model_name, &id, row)
+		}
+		None => audit::log_delete(&user_id, client_ip.as_deref(), &model_name, &id, true),
+	}
 
 	Ok(MutationResponse {
 		success: true,
@@ -154,6 +171,7 @@ pub async fn bulk_delete_records(
 	let pk_field = model_admin.pk_field();
 
 	let user_id = auth.user_id().unwrap_or("unknown").to_string();
+	let client_ip = auth.client_ip().map(|ip| ip.to_string());
 
 	let ids = request.ids;
 	if ids.len() > MAX_BULK_DELETE_IDS {
@@ -171,7 +189,14 @@ pub async fn bulk_delete_records(
 
 	let success = result.is_ok();
 	let affected_count = result.as_ref().copied().unwrap_or(0);
-	audit::log_bulk_delete(&user_id, &model_name, &ids, affected_count, success);
+	audit::log_bulk_delete(
+		&user_id,
+		client_ip.as_deref(),
+		&model_name,
+		&ids,
+		affected_count,
+		success,
+	);
 
 	let affected = result?;
 
@@ -181,3 +206,111 @@ pub async fn bulk_delete_records(
 		message: format!("Deleted {} {} items", affected, model_name),
 	})
 }
+
+/// Restore a record removed by a recent delete action
+///
+/// Re-inserts the row snapshot captured when the record was deleted and
+/// sends the `post_save` signal with a [`RestoredRecord`] payload. Undo is
+/// only available for single-record deletes performed within
+/// [`AdminSettings::undo_window_secs`], and each action can be undone once.
+///
+/// [`RestoredRecord`]: super::recent_actions::RestoredRecord
+/// [`AdminSettings::undo_window_secs`]: crate::settings::AdminSettings::undo_window_secs
+///
+/// # Server Function
+///
+/// This function is automatically exposed as an HTTP endpoint by the `#[server_fn]` macro.
+/// AdminSite and AdminDatabase dependencies are automatically injected via the DI system.
+///
+/// # Authentication
+///
+/// Requires staff (admin) permission and add permission for the model.
+///
+/// # Example
+///
+/// ```ignore
+/// use reinhardt_admin::server::undo_delete;
+///
+/// // Client-side usage (automatically generates HTTP request)
+/// let response = undo_delete(17, "token".to_string()).await?;
+/// println!("{}", response.message);
+/// ```
+#[server_fn]
+pub async fn undo_delete(
+	action_id: u64,
+	csrf_token: String,
+	#[inject] site: Depends<AdminSiteKey, AdminSite>,
+	#[inject] db: Depends<AdminDatabaseKey, AdminDatabase>,
+	#[inject] http_request: ServerFnRequest,
+	#[inject] AdminAuthenticatedUser(user): AdminAuthenticatedUser,
+) -> Result<crate::types::MutationResponse, ServerFnError> {
+	// CSRF token validation (double-submit cookie pattern)
+	require_csrf_token(&csrf_token, &http_request.inner().headers)?;
+
+	let auth = AdminAuth::from_request(&http_request);
+	auth.require_staff()?;
+
+	let log = RecentActionLog::global();
+	let action = log
+		.get(action_id)
+		.ok_or_else(|| ServerFnError::server(404, UndoError::NotFound.to_string()))?;
+
+	// Restoring a row is an insert, so it requires add permission
+	let model_name = action.entry.model_name.clone();
+	let model_admin = site.get_model_admin(&model_name).map_server_fn_error()?;
+	auth.require_model_permission(model_admin.as_ref(), user.as_ref(), ModelPermission::Add)
+		.await?;
+
+	let window_secs = crate::settings::get_admin_settings().undo_window_secs;
+	let window = chrono::Duration::seconds(i64::try_from(window_secs).unwrap_or(i64::MAX));
+	let action = log
+		.claim_undo(action_id, window, chrono::Utc::now())
+		.map_err(|e| match e {
+			UndoError::NotFound => ServerFnError::server(404, e.to_string()),
+			_ => ServerFnError::server(409, e.to_string()),
+		})?;
+	let data = action.snapshot.unwrap_or_default();
+	let record_id = action.entry.record_id.unwrap_or_default();
+
+	let table_name = model_admin.table_name();
+	let pk_field = model_admin.pk_field();
+	let user_id = auth.user_id().unwrap_or("unknown").to_string();
+	let client_ip = auth.client_ip().map(|ip| ip.to_string());
+
+	let result = db
+		.create::<AdminRecord>(table_name, Some(pk_field), data.clone())
+		.await
+		.map_server_fn_error();
+	if let Err(e) = result {
+		// Keep the action undoable so the user can retry
+		log.release_undo(action_id);
+		audit::log_create(&user_id, client_ip.as_deref(), &model_name, &data, false);
+		return Err(e);
+	}
+
+	audit::log_create(&user_id, client_ip.as_deref(), &model_name, &data, true);
+
+	let restored = RestoredRecord {
+		model_name: model_name.clone(),
+		table_name: table_name.to_string(),
+		record_id,
+		data,
+	};
+	if let Err(e) = reinhardt_core::signals::post_save::<RestoredRecord>()
+		.send(restored)
+		.await
+	{
+		tracing::warn!(
+			"post_save receiver failed after restoring {}: {}",
+			model_name,
+			e
+		);
+	}
+
+	Ok(MutationResponse {
+		success: true,
+		message: format!("{} restored successfully", model_name),
+		affected: Some(1),
+		data: None,
+	})
+}
@@ -6,6 +6,8 @@
 use super::admin_auth::AdminAuthenticatedUser;
 use crate::adapters::{AdminDatabase, AdminRecord, AdminSite, DetailResponse};
 #[cfg(server)]
+use crate::core::generic_inline::load_generic_inlines;
+#[cfg(server)]
 use crate::core::{AdminDatabaseKey, AdminSiteKey};
 #[cfg(server)]
 use reinhardt_di::Depends;
@@ -13,13 +15,20 @@ use reinhardt_di::Depends;
 use reinhardt_pages::server_fn::ServerFnRequest;
 use reinhardt_pages::server_fn::{ServerFnError, server_fn};
 
+#[cfg(server)]
+use super::api::api_download_url;
 #[cfg(server)]
 use super::error::{AdminAuth, MapServerFnError, ModelPermission};
+#[cfg(server)]
+use super::type_inference::{localize_datetimes, resolve_admin_field_type};
+#[cfg(server)]
+use crate::types::FieldType;
 
 /// Get detail view data for a single model instance
 ///
 /// Retrieves a single record by model name and ID, returning all fields
-/// as a HashMap of field names to JSON values.
+/// as a HashMap of field names to JSON values, along with the children of
+/// each configured [`GenericInline`](crate::core::GenericInline).
 ///
 /// # Server Function
 ///
@@ -56,7 +65,7 @@ pub async fn get_detail(
 	let table_name = model_admin.table_name();
 	let pk_field = model_admin.pk_field();
 
-	let data = db
+	let mut data = db
 		.get::<AdminRecord>(table_name, pk_field, &id)
 		.await
 		.map_server_fn_error()?
@@ -64,5 +73,28 @@ pub async fn get_detail(
 			ServerFnError::server(404, format!("{} with id '{}' not found", model_name, id))
 		})?;
 
-	Ok(DetailResponse { model_name, data })
+	localize_datetimes(model_admin.as_ref(), std::slice::from_mut(&mut data));
+
+	let inlines = load_generic_inlines(&db, model_admin.as_ref(), &id)
+		.await
+		.map_server_fn_error()?;
+
+	let downloads = data
+		.iter()
+		.filter(|(field, value)| {
+			!value.is_null()
+				&& resolve_admin_field_type(model_admin.as_ref(), field) == Some(FieldType::File)
+		})
+		.map(|(field, _)| {
+			let url = api_download_url(&site, &model_name, &id, field);
+			(field.clone(), url)
+		})
+		.collect();
+
+	Ok(DetailResponse {
+		model_name,
+		data,
+		inlines,
+		downloads,
+	})
 }
@@ -0,0 +1,224 @@
+//! Soft locks on records open in the change view
+//!
+//! When a user opens the edit form of a record, the admin takes a lock on it
+//! so other users see who is editing and get a read-only form instead of
+//! silently overwriting each other's changes. Locks are held in memory and
+//! expire after [`AdminSettings::edit_lock_ttl_secs`] unless the editing
+//! browser renews them with a heartbeat; saving or leaving the form releases
+//! the lock.
+//!
+//! [`AdminSettings::edit_lock_ttl_secs`]: crate::settings::AdminSettings::edit_lock_ttl_secs
+
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+static EDIT_LOCKS: LazyLock<EditLockRegistry> = LazyLock::new(EditLockRegistry::new);
+
+/// A lock held by one user on one record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditLock {
+	/// Name of the model the record belongs to
+	pub model_name: String,
+	/// Primary key of the locked record
+	pub record_id: String,
+	/// Identifier of the user holding the lock
+	pub user_id: String,
+	/// Name shown to other users in the "being edited" banner
+	pub username: String,
+	/// When the lock lapses unless renewed
+	pub expires_at: DateTime<Utc>,
+}
+
+impl EditLock {
+	/// Returns `true` if the lock has lapsed at `now`.
+	pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+		now >= self.expires_at
+	}
+}
+
+/// Outcome of [`EditLockRegistry::acquire`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LockOutcome {
+	/// The caller holds the lock until the contained expiry
+	Acquired(EditLock),
+	/// Another user holds an unexpired lock
+	Held(EditLock),
+}
+
+/// Locks keyed by model name and record ID.
+#[derive(Debug, Default)]
+pub struct EditLockRegistry {
+	locks: RwLock<HashMap<(String, String), EditLock>>,
+}
+
+impl EditLockRegistry {
+	/// Creates an empty registry.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the process-wide registry used by the admin server functions.
+	pub fn global() -> &'static EditLockRegistry {
+		&EDIT_LOCKS
+	}
+
+	/// Stores `lock`, taking or renewing the lock on its record.
+	///
+	/// A lock held by the same user is replaced, which is how heartbeats
+	/// extend the expiry. A live lock held by someone else is returned as
+	/// [`LockOutcome::Held`] unless `takeover` is set, in which case it is
+	/// replaced as well.
+	pub fn acquire(&self, lock: EditLock, takeover: bool, now: DateTime<Utc>) -> LockOutcome {
+		let mut locks = self.locks.write();
+		let key = (lock.model_name.clone(), lock.record_id.clone());
+		if let Some(existing) = locks.get(&key)
+			&& existing.user_id != lock.user_id
+			&& !existing.is_expired(now)
+			&& !takeover
+		{
+			return LockOutcome::Held(existing.clone());
+		}
+		locks.insert(key, lock.clone());
+		LockOutcome::Acquired(lock)
+	}
+
+	/// Returns the live lock on a record held by a user other than `user_id`.
+	///
+	/// Saves are rejected while such a lock exists.
+	pub fn held_by_other(
+		&self,
+		model_name: &str,
+		record_id: &str,
+		user_id: &str,
+		now: DateTime<Utc>,
+	) -> Option<EditLock> {
+		self.locks
+			.read()
+			.get(&(model_name.to_string(), record_id.to_string()))
+			.filter(|lock| lock.user_id != user_id && !lock.is_expired(now))
+			.cloned()
+	}
+
+	/// Releases the lock on a record if `user_id` holds it.
+	///
+	/// Returns `true` if a lock was removed.
+	pub fn release(&self, model_name: &str, record_id: &str, user_id: &str) -> bool {
+		let mut locks = self.locks.write();
+		let key = (model_name.to_string(), record_id.to_string());
+		if locks.get(&key).is_some_and(|lock| lock.user_id == user_id) {
+			locks.remove(&key);
+			return true;
+		}
+		false
+	}
+
+	/// Drops every lock that has lapsed at `now`.
+	pub fn purge_expired(&self, now: DateTime<Utc>) {
+		self.locks.write().retain(|_, lock| !lock.is_expired(now));
+	}
+}
+
+#[cfg(all(test, server))]
+mod tests {
+	use super::*;
+	use chrono::Duration;
+	use rstest::rstest;
+
+	fn acquire(
+		registry: &EditLockRegistry,
+		user_id: &str,
+		takeover: bool,
+		now: DateTime<Utc>,
+	) -> LockOutcome {
+		let lock = EditLock {
+			model_name: "Article".to_string(),
+			record_id: "1".to_string(),
+			user_id: user_id.to_string(),
+			username: format!("{}-name", user_id),
+			expires_at: now + Duration::seconds(60),
+		};
+		registry.acquire(lock, takeover, now)
+	}
+
+	#[rstest]
+	fn test_acquire_reports_holder_to_other_users() {
+		// Arrange
+		let registry = EditLockRegistry::new();
+		let now = Utc::now();
+		acquire(&registry, "alice", false, now);
+
+		// Act
+		let outcome = acquire(&registry, "bob", false, now);
+
+		// Assert
+		match outcome {
+			LockOutcome::Held(lock) => assert_eq!(lock.username, "alice-name"),
+			other => panic!("expected Held, got {:?}", other),
+		}
+		assert!(registry.held_by_other("Article", "1", "bob", now).is_some());
+		assert!(
+			registry
+				.held_by_other("Article", "1", "alice", now)
+				.is_none()
+		);
+	}
+
+	#[rstest]
+	fn test_heartbeat_extends_own_lock() {
+		// Arrange
+		let registry = EditLockRegistry::new();
+		let now = Utc::now();
+		acquire(&registry, "alice", false, now);
+
+		// Act
+		let later = now + Duration::seconds(45);
+		let outcome = acquire(&registry, "alice", false, later);
+
+		// Assert
+		assert_eq!(
+			outcome,
+			LockOutcome::Acquired(EditLock {
+				model_name: "Article".to_string(),
+				record_id: "1".to_string(),
+				user_id: "alice".to_string(),
+				username: "alice-name".to_string(),
+				expires_at: later + Duration::seconds(60),
+			})
+		);
+	}
+
+	#[rstest]
+	#[case::takeover(true, Duration::zero())]
+	#[case::expired(false, Duration::seconds(61))]
+	fn test_acquire_replaces_lock(#[case] takeover: bool, #[case] elapsed: Duration) {
+		// Arrange
+		let registry = EditLockRegistry::new();
+		let now = Utc::now();
+		acquire(&registry, "alice", false, now);
+
+		// Act
+		let outcome = acquire(&registry, "bob", takeover, now + elapsed);
+
+		// Assert
+		assert!(matches!(outcome, LockOutcome::Acquired(lock) if lock.user_id == "bob"));
+	}
+
+	#[rstest]
+	fn test_release_only_removes_own_lock() {
+		// Arrange
+		let registry = EditLockRegistry::new();
+		let now = Utc::now();
+		acquire(&registry, "alice", false, now);
+
+		// Act
+		let by_other = registry.release("Article", "1", "bob");
+		let by_holder = registry.release("Article", "1", "alice");
+
+		// Assert
+		assert!(!by_other);
+		assert!(by_holder);
+		assert!(registry.held_by_other("Article", "1", "bob", now).is_none());
+	}
+}
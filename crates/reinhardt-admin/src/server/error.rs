@@ -6,6 +6,7 @@
 use crate::types::AdminError;
 use reinhardt_http::AuthState;
 use reinhardt_pages::server_fn::{ServerFnError, ServerFnRequest};
+use std::net::IpAddr;
 use std::sync::Arc;
 
 /// Extension trait for converting AdminError to ServerFnError
@@ -69,6 +70,8 @@ pub enum ModelPermission {
 pub struct AdminAuth {
 	/// The authentication state from the request
 	auth_state: Option<AuthState>,
+	/// Client IP address resolved by [`Request::client_ip`](reinhardt_http::Request::client_ip)
+	client_ip: Option<IpAddr>,
 }
 
 impl AdminAuth {
@@ -83,7 +86,11 @@ impl AdminAuth {
 	/// A new AdminAuth instance
 	pub fn from_request(request: &ServerFnRequest) -> Self {
 		let auth_state = request.inner().extensions.get::<AuthState>();
-		Self { auth_state }
+		let client_ip = request.inner().client_ip();
+		Self {
+			auth_state,
+			client_ip,
+		}
 	}
 
 	/// Creates a new AdminAuth from an `Arc<Request>`.
@@ -97,7 +104,11 @@ impl AdminAuth {
 	/// A new AdminAuth instance
 	pub fn from_arc_request(request: &Arc<reinhardt_http::Request>) -> Self {
 		let auth_state = request.extensions.get::<AuthState>();
-		Self { auth_state }
+		let client_ip = request.client_ip();
+		Self {
+			auth_state,
+			client_ip,
+		}
 	}
 
 	/// Returns the AuthState if available.
@@ -134,6 +145,11 @@ impl AdminAuth {
 		self.auth_state.as_ref().is_some_and(|s| s.is_active())
 	}
 
+	/// Returns the client IP address of the request, if known.
+	pub fn client_ip(&self) -> Option<IpAddr> {
+		self.client_ip
+	}
+
 	/// Returns the user ID if authenticated.
 	pub fn user_id(&self) -> Option<&str> {
 		self.auth_state.as_ref().map(|s| s.user_id())
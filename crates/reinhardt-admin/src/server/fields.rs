@@ -17,14 +17,24 @@ use reinhardt_pages::server_fn::{ServerFnError, server_fn};
 #[cfg(server)]
 use super::error::{AdminAuth, MapServerFnError, ModelPermission};
 #[cfg(server)]
-use crate::server::type_inference::{get_field_metadata, infer_admin_field_type, infer_required};
+use super::limits::MAX_RELATED_CHOICES;
+#[cfg(server)]
+use crate::adapters::AdminUser;
+#[cfg(server)]
+use crate::server::type_inference::{
+	get_field_metadata, get_relation_target, infer_required, resolve_admin_field_type,
+};
 #[cfg(server)]
 use reinhardt_utils::utils_core::text::humanize_field_name;
+#[cfg(server)]
+use std::collections::HashMap;
 
 /// Get field definitions for dynamic form generation
 ///
 /// Retrieves field metadata for creating or editing model instances.
 /// When `id` is provided, also retrieves the existing field values for editing.
+/// Foreign keys to registered models the user can view are returned as
+/// [`FieldType::ForeignKey`] with the related records as choices.
 ///
 /// # Server Function
 ///
@@ -65,21 +75,31 @@ pub async fn get_fields(
 
 	// Build field metadata with type inference from global registry
 	let table_name = model_admin.table_name();
-	let fields = field_names
-		.iter()
-		.map(|&name| {
-			let is_readonly = readonly_fields.contains(&name);
-
-			// Try to get field metadata from the global model registry
-			let (field_type, required) = get_field_metadata(table_name, name)
-				.map(|meta| {
-					let admin_type = infer_admin_field_type(&meta.field_type);
-					let is_required = infer_required(&meta);
-					(admin_type, is_required)
-				})
-				.unwrap_or_else(|| (FieldType::Text, false));
-
-			FieldInfo {
+	let mut fields = Vec::with_capacity(field_names.len());
+	for &name in &field_names {
+		let is_readonly = readonly_fields.contains(&name);
+
+		// Try to get field metadata from the global model registry
+		let required = get_field_metadata(table_name, name)
+			.map(|meta| infer_required(&meta))
+			.unwrap_or(false);
+		let mut field_type =
+			resolve_admin_field_type(model_admin.as_ref(), name).unwrap_or(FieldType::Text);
+
+		// Foreign keys to registered models the user can view become a select
+		// over the related records
+		if !is_readonly
+			&& let Some(related) =
+				related_field_type(&site, &db, user.as_ref(), table_name, name).await
+		{
+			field_type = related;
+		}
+
+		// Translated fields get one input per language; only the default
+		// language (stored in the field's own column) can be required.
+		let translations = model_admin.field_translations(name);
+		if translations.is_empty() {
+			fields.push(FieldInfo {
 				name: name.to_string(),
 				label: humanize_field_name(name),
 				field_type,
@@ -87,9 +107,23 @@ pub async fn get_fields(
 				readonly: is_readonly,
 				help_text: None,
 				placeholder: None,
-			}
-		})
-		.collect();
+				language: None,
+			});
+			continue;
+		}
+		for (language, column) in translations {
+			fields.push(FieldInfo {
+				required: required && column == name,
+				name: column,
+				label: humanize_field_name(name),
+				field_type: field_type.clone(),
+				readonly: is_readonly,
+				help_text: None,
+				placeholder: None,
+				language: Some(language),
+			});
+		}
+	}
 
 	// Fetch existing values if editing
 	let values = if let Some(id) = id {
@@ -106,3 +140,115 @@ pub async fn get_fields(
 		values,
 	})
 }
+
+/// Builds the [`FieldType::ForeignKey`] for a relation field.
+///
+/// Returns `None` if the field is not a relation, the related model is not
+/// registered, or the user cannot view it. Creating related records from the
+/// form is offered only with add permission on the related model.
+#[cfg(server)]
+async fn related_field_type(
+	site: &AdminSite,
+	db: &AdminDatabase,
+	user: &dyn AdminUser,
+	table_name: &str,
+	field_name: &str,
+) -> Option<FieldType> {
+	let target = get_relation_target(table_name, field_name)?;
+	let (related_model, related_admin) = site.find_related_model(&target)?;
+	if !related_admin.has_view_permission(user).await {
+		return None;
+	}
+
+	let pk_field = related_admin.pk_field();
+	let label_field = related_admin
+		.list_display()
+		.into_iter()
+		.find(|field| *field != pk_field)
+		.map(str::to_string);
+	let rows = db
+		.list::<AdminRecord>(
+			related_admin.table_name(),
+			Vec::new(),
+			0,
+			MAX_RELATED_CHOICES,
+		)
+		.await
+		.ok()?;
+	let choices = rows
+		.iter()
+		.filter_map(|row| related_choice(row, pk_field, label_field.as_deref()))
+		.collect();
+
+	Some(FieldType::ForeignKey {
+		related_model,
+		choices,
+		label_field,
+		can_add: related_admin.has_add_permission(user).await,
+	})
+}
+
+/// Converts a related row into a `(primary key, label)` choice.
+///
+/// The label falls back to the primary key when the label field is missing
+/// or null.
+#[cfg(server)]
+fn related_choice(
+	row: &HashMap<String, serde_json::Value>,
+	pk_field: &str,
+	label_field: Option<&str>,
+) -> Option<(String, String)> {
+	let pk = choice_text(row.get(pk_field)?)?;
+	let label = label_field
+		.and_then(|field| row.get(field))
+		.and_then(choice_text)
+		.unwrap_or_else(|| pk.clone());
+	Some((pk, label))
+}
+
+#[cfg(server)]
+fn choice_text(value: &serde_json::Value) -> Option<String> {
+	match value {
+		serde_json::Value::Null => None,
+		serde_json::Value::String(s) => Some(s.clone()),
+		other => Some(other.to_string()),
+	}
+}
+
+#[cfg(all(test, server))]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use serde_json::json;
+
+	#[rstest]
+	#[case::label_field(Some("name"), ("7", "Alice"))]
+	#[case::no_label_field(None, ("7", "7"))]
+	#[case::null_label(Some("nickname"), ("7", "7"))]
+	fn test_related_choice(#[case] label_field: Option<&str>, #[case] expected: (&str, &str)) {
+		// Arrange
+		let row = HashMap::from([
+			("id".to_string(), json!(7)),
+			("name".to_string(), json!("Alice")),
+			("nickname".to_string(), json!(null)),
+		]);
+
+		// Act
+		let choice = related_choice(&row, "id", label_field);
+
+		// Assert
+		assert_eq!(
+			choice,
+			Some((expected.0.to_string(), expected.1.to_string()))
+		);
+	}
+
+	#[rstest]
+	fn test_related_choice_requires_primary_key() {
+		// Arrange
+		let row = HashMap::from([("name".to_string(), json!("Alice"))]);
+
+		// Act & Assert
+		assert_eq!(related_choice(&row, "id", Some("name")), None);
+	}
+}
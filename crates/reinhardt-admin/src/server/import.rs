@@ -74,6 +74,20 @@ pub async fn import_data(
 		)));
 	}
 
+	// Scan the upload before parsing; the scanner may block on network I/O
+	let data = match site.upload_scanner() {
+		Some(scanner) => {
+			let name = model_name.clone();
+			tokio::task::spawn_blocking(move || {
+				crate::core::import::scan_import_data(scanner.as_ref(), &name, &data).map(|_| data)
+			})
+			.await
+			.map_err(|_| ServerFnError::application("Import file could not be scanned"))?
+			.map_server_fn_error()?
+		}
+		None => data,
+	};
+
 	let table_name = model_admin.table_name();
 	let pk_field = model_admin.pk_field();
 
@@ -28,6 +28,31 @@ pub const MAX_IMPORT_RECORDS: usize = 1_000;
 /// Default: 1,000 IDs
 pub const MAX_BULK_DELETE_IDS: usize = 1_000;
 
+/// Maximum number of users exported or anonymized in a single request
+///
+/// Each user touches every model holding personal data, so this is kept
+/// far below [`MAX_BULK_DELETE_IDS`].
+/// Default: 50 users
+pub const MAX_USER_DATA_IDS: usize = 50;
+
+/// Maximum window, in days, of the upcoming data retention report
+///
+/// Each day of the window widens the range counted on every model declaring
+/// a retention period.
+/// Default: 366 days
+pub const MAX_RETENTION_REPORT_DAYS: u32 = 366;
+
+/// Maximum number of rows that can be saved in a single inline list edit
+///
+/// Matches [`MAX_PAGE_SIZE`], since only rows on the current page are edited.
+pub const MAX_BULK_UPDATE_ROWS: usize = 500;
+
+/// Maximum number of related records offered as foreign key choices
+///
+/// Larger related tables are truncated to keep form payloads bounded.
+/// Default: 200 records
+pub const MAX_RELATED_CHOICES: u64 = 200;
+
 /// Maximum page size for list views
 ///
 /// This prevents memory exhaustion from large page requests.
@@ -139,4 +164,10 @@ mod tests {
 		// Assert
 		assert_eq!(MAX_BULK_DELETE_IDS, 1_000);
 	}
+
+	#[rstest]
+	fn bulk_update_rows_limit_covers_a_full_page() {
+		// Act & Assert
+		assert!(MAX_BULK_UPDATE_ROWS as u64 >= MAX_PAGE_SIZE);
+	}
 }
@@ -5,13 +5,13 @@
 #[cfg(server)]
 use super::admin_auth::AdminAuthenticatedUser;
 use crate::adapters::{
-	AdminDatabase, AdminRecord, AdminSite, ColumnInfo, FilterInfo, FilterType, ListResponse,
-	ModelAdmin,
+	AdminDatabase, AdminRecord, AdminSite, ColumnInfo, FieldType, FilterInfo, FilterType,
+	ListResponse, ModelAdmin,
 };
 #[cfg(server)]
 use crate::core::{AdminDatabaseKey, AdminSiteKey};
 #[cfg(server)]
-use reinhardt_db::orm::{Filter, FilterCondition, FilterOperator, FilterValue};
+use reinhardt_db::orm::{Filter, FilterCondition, FilterOperator, FilterValue, OrderBy};
 #[cfg(server)]
 use reinhardt_di::Depends;
 use reinhardt_pages::server_fn::{ServerFnError, server_fn};
@@ -23,24 +23,20 @@ use super::error::MapServerFnError;
 use super::limits::MAX_PAGE_SIZE;
 #[cfg(server)]
 use crate::server::type_inference::{
-	get_field_metadata, infer_admin_field_type, infer_filter_type,
+	infer_filter_type, localize_datetimes, resolve_admin_field_type,
 };
 #[cfg(server)]
 use reinhardt_utils::utils_core::text::humanize_field_name;
 
 #[cfg(server)]
 fn build_filters(model_admin: &Arc<dyn ModelAdmin>) -> Vec<FilterInfo> {
-	let table_name = model_admin.table_name();
 	model_admin
 		.list_filter()
 		.iter()
 		.map(|field| {
-			// Infer filter type from field metadata in global registry
-			let filter_type = get_field_metadata(table_name, field)
-				.map(|meta| {
-					let admin_type = infer_admin_field_type(&meta.field_type);
-					infer_filter_type(&admin_type)
-				})
+			// Infer filter type from declared choices or field metadata
+			let filter_type = resolve_admin_field_type(model_admin.as_ref(), field)
+				.map(|admin_type| infer_filter_type(&admin_type))
 				.unwrap_or(FilterType::Boolean);
 
 			FilterInfo {
@@ -53,15 +49,39 @@ fn build_filters(model_admin: &Arc<dyn ModelAdmin>) -> Vec<FilterInfo> {
 		.collect()
 }
 
+/// Builds list columns; `list_editable` columns carry an input type when
+/// `can_change` is set and the field is neither read-only nor the primary key.
 #[cfg(server)]
-fn build_columns(model_admin: &Arc<dyn ModelAdmin>) -> Vec<ColumnInfo> {
+fn build_columns(model_admin: &Arc<dyn ModelAdmin>, can_change: bool) -> Vec<ColumnInfo> {
+	let editable_fields = if can_change {
+		model_admin.list_editable()
+	} else {
+		Vec::new()
+	};
+	let readonly_fields = model_admin.readonly_fields();
+	let pk_field = model_admin.pk_field();
+
 	model_admin
 		.list_display()
 		.iter()
-		.map(|field| ColumnInfo {
-			field: field.to_string(),
-			label: humanize_field_name(field),
-			sortable: true,
+		.map(|field| {
+			let field_type = resolve_admin_field_type(model_admin.as_ref(), field);
+			let choices = match &field_type {
+				Some(FieldType::Select { choices }) => choices.clone(),
+				_ => Vec::new(),
+			};
+			let editable = (editable_fields.contains(field)
+				&& !readonly_fields.contains(field)
+				&& *field != pk_field)
+				.then(|| field_type.unwrap_or(FieldType::Text));
+
+			ColumnInfo {
+				field: field.to_string(),
+				label: humanize_field_name(field),
+				sortable: true,
+				editable,
+				choices,
+			}
 		})
 		.collect()
 }
@@ -135,10 +155,10 @@ pub async fn get_list(
 		}
 	}
 
-	// Build additional filters (AND logic)
+	// Build additional filters (AND logic), starting from the default queryset
 	// Only accept filter fields that are explicitly defined in model_admin.list_filter()
 	let allowed_filter_fields = model_admin.list_filter();
-	let mut additional_filters = Vec::new();
+	let mut additional_filters = model_admin.queryset_filters();
 	for (field, value) in params.filters.iter() {
 		if !allowed_filter_fields.contains(&field.as_str()) {
 			return Err(ServerFnError::server(
@@ -163,6 +183,7 @@ pub async fn get_list(
 		.or_else(|| model_admin.ordering().first().copied());
 
 	// Validate sort_by against allowed fields to prevent arbitrary column access
+	let mut order_by = None;
 	if let Some(sort_field) = sort_by {
 		let raw_field = sort_field.strip_prefix('-').unwrap_or(sort_field);
 		let allowed_sort_fields = model_admin.list_display();
@@ -175,6 +196,13 @@ pub async fn get_list(
 				),
 			));
 		}
+		order_by = Some(
+			if model_admin.natural_ordering_fields().contains(&raw_field) {
+				OrderBy::natural(sort_field)
+			} else {
+				OrderBy::field(sort_field)
+			},
+		);
 	}
 
 	// Calculate pagination with upper bound enforcement
@@ -191,18 +219,20 @@ pub async fn get_list(
 	let offset = (page - 1) * page_size;
 
 	// Fetch page data and total count in one query for the common non-empty page path.
-	let (results, count) = db
+	let (mut results, count) = db
 		.list_with_condition_and_count::<AdminRecord>(
 			model_admin.table_name(),
 			filter_condition.as_ref(),
 			additional_filters,
-			sort_by,
+			order_by.as_ref(),
 			offset,
 			page_size,
 		)
 		.await
 		.map_server_fn_error()?;
 
+	localize_datetimes(model_admin.as_ref(), &mut results);
+
 	// Calculate total pages
 	let total_pages = if count > 0 {
 		count.div_ceil(page_size)
@@ -218,6 +248,57 @@ pub async fn get_list(
 		total_pages,
 		results,
 		available_filters: Some(build_filters(&model_admin)),
-		columns: Some(build_columns(&model_admin)),
+		columns: Some(build_columns(
+			&model_admin,
+			model_admin.has_change_permission(user.as_ref()).await,
+		)),
 	})
 }
+
+#[cfg(all(test, server))]
+mod tests {
+	use super::*;
+	use crate::core::ModelAdminConfig;
+	use rstest::rstest;
+
+	fn editable_admin() -> Arc<dyn ModelAdmin> {
+		Arc::new(
+			ModelAdminConfig::builder()
+				.model_name("User")
+				.list_display(vec!["id", "username", "is_active", "created_at"])
+				.list_editable(vec!["id", "is_active", "created_at"])
+				.readonly_fields(vec!["created_at"])
+				.build()
+				.unwrap(),
+		)
+	}
+
+	#[rstest]
+	fn test_build_columns_marks_list_editable_fields() {
+		// Arrange
+		let admin = editable_admin();
+
+		// Act
+		let columns = build_columns(&admin, true);
+
+		// Assert
+		let editable: Vec<&str> = columns
+			.iter()
+			.filter(|c| c.editable.is_some())
+			.map(|c| c.field.as_str())
+			.collect();
+		assert_eq!(editable, vec!["is_active"]);
+	}
+
+	#[rstest]
+	fn test_build_columns_without_change_permission_is_read_only() {
+		// Arrange
+		let admin = editable_admin();
+
+		// Act
+		let columns = build_columns(&admin, false);
+
+		// Assert
+		assert!(columns.iter().all(|c| c.editable.is_none()));
+	}
+}
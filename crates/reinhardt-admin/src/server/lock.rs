@@ -0,0 +1,139 @@
+//! Edit lock Server Functions
+//!
+//! Take, renew and release the soft lock on a record open in the change view.
+
+#[cfg(server)]
+use super::admin_auth::AdminAuthenticatedUser;
+use crate::adapters::AdminSite;
+#[cfg(server)]
+use crate::core::AdminSiteKey;
+use crate::types::{EditLockResponse, MutationResponse};
+#[cfg(server)]
+use reinhardt_di::Depends;
+#[cfg(server)]
+use reinhardt_pages::server_fn::ServerFnRequest;
+use reinhardt_pages::server_fn::{ServerFnError, server_fn};
+
+#[cfg(server)]
+use super::edit_locks::{EditLock, EditLockRegistry, LockOutcome};
+#[cfg(server)]
+use super::error::{AdminAuth, MapServerFnError, ModelPermission};
+#[cfg(server)]
+use super::security::require_csrf_token;
+
+/// Take or renew the edit lock on a record
+///
+/// The change view calls this when the form opens and then every
+/// `heartbeat_secs` to keep the lock alive. If another user holds a live
+/// lock, the response reports them in `locked_by` and the form should be
+/// shown read-only; passing `takeover: true` replaces their lock instead.
+///
+/// # Server Function
+///
+/// This function is automatically exposed as an HTTP endpoint by the `#[server_fn]` macro.
+/// The AdminSite dependency is automatically injected via the DI system.
+///
+/// # Authentication
+///
+/// Requires staff (admin) permission and change permission for the model.
+///
+/// # Example
+///
+/// ```ignore
+/// use reinhardt_admin::server::acquire_edit_lock;
+///
+/// // Client-side usage (automatically generates HTTP request)
+/// let lock = acquire_edit_lock("Article".to_string(), "42".to_string(), false, "token".to_string()).await?;
+/// if let Some(user) = lock.locked_by {
+///     println!("Being edited by {}", user);
+/// }
+/// ```
+#[server_fn]
+pub async fn acquire_edit_lock(
+	model_name: String,
+	id: String,
+	takeover: bool,
+	csrf_token: String,
+	#[inject] site: Depends<AdminSiteKey, AdminSite>,
+	#[inject] http_request: ServerFnRequest,
+	#[inject] AdminAuthenticatedUser(user): AdminAuthenticatedUser,
+) -> Result<EditLockResponse, ServerFnError> {
+	// CSRF token validation (double-submit cookie pattern)
+	require_csrf_token(&csrf_token, &http_request.inner().headers)?;
+
+	let auth = AdminAuth::from_request(&http_request);
+	let model_admin = site.get_model_admin(&model_name).map_server_fn_error()?;
+	auth.require_model_permission(model_admin.as_ref(), user.as_ref(), ModelPermission::Change)
+		.await?;
+
+	let ttl_secs = crate::settings::get_admin_settings().edit_lock_ttl_secs;
+	let ttl = chrono::Duration::seconds(i64::try_from(ttl_secs).unwrap_or(i64::MAX));
+	let now = chrono::Utc::now();
+
+	let registry = EditLockRegistry::global();
+	registry.purge_expired(now);
+	let lock = EditLock {
+		model_name,
+		record_id: id,
+		user_id: auth.user_id().unwrap_or("unknown").to_string(),
+		username: user.get_username().to_string(),
+		expires_at: now + ttl,
+	};
+	let (acquired, lock) = match registry.acquire(lock, takeover, now) {
+		LockOutcome::Acquired(lock) => (true, lock),
+		LockOutcome::Held(lock) => (false, lock),
+	};
+
+	Ok(EditLockResponse {
+		acquired,
+		locked_by: (!acquired).then_some(lock.username),
+		expires_at: lock.expires_at.to_rfc3339(),
+		heartbeat_secs: (ttl_secs / 2).max(1),
+	})
+}
+
+/// Release the edit lock on a record
+///
+/// Called when the user leaves the change view without saving. Releasing a
+/// lock held by someone else is a no-op.
+///
+/// # Server Function
+///
+/// This function is automatically exposed as an HTTP endpoint by the `#[server_fn]` macro.
+///
+/// # Authentication
+///
+/// Requires staff (admin) permission.
+///
+/// # Example
+///
+/// ```ignore
+/// use reinhardt_admin::server::release_edit_lock;
+///
+/// // Client-side usage (automatically generates HTTP request)
+/// release_edit_lock("Article".to_string(), "42".to_string(), "token".to_string()).await?;
+/// ```
+#[server_fn]
+pub async fn release_edit_lock(
+	model_name: String,
+	id: String,
+	csrf_token: String,
+	#[inject] http_request: ServerFnRequest,
+	#[inject] AdminAuthenticatedUser(_user): AdminAuthenticatedUser,
+) -> Result<MutationResponse, ServerFnError> {
+	// CSRF token validation (double-submit cookie pattern)
+	require_csrf_token(&csrf_token, &http_request.inner().headers)?;
+
+	let auth = AdminAuth::from_request(&http_request);
+	auth.require_staff()?;
+
+	let user_id = auth.user_id().unwrap_or("unknown");
+	let released = EditLockRegistry::global().release(&model_name, &id, user_id);
+
+	Ok(MutationResponse {
+		success: released,
+		message: format!("{} lock released", model_name),
+		affected: None,
+		data: None,
+	})
+}
@@ -0,0 +1,260 @@
+//! Personal data Server Functions
+//!
+//! Admin actions exporting and anonymizing the personal data of selected
+//! users, backed by [`reinhardt_db::orm::personal_data`]. Both actions are
+//! offered on the user model, i.e. the model whose own primary key is its
+//! `data_subject`.
+
+#[cfg(server)]
+use super::admin_auth::AdminAuthenticatedUser;
+use crate::adapters::{AdminDatabase, AdminSite, UserDataExportResponse, UserDataRequest};
+#[cfg(server)]
+use crate::core::{AdminDatabaseKey, AdminSiteKey};
+use crate::types::MutationResponse;
+#[cfg(server)]
+use reinhardt_di::Depends;
+#[cfg(server)]
+use reinhardt_pages::server_fn::ServerFnRequest;
+use reinhardt_pages::server_fn::{ServerFnError, server_fn};
+
+#[cfg(server)]
+use super::audit::{self, AuditAction};
+#[cfg(server)]
+use super::error::{AdminAuth, MapServerFnError, ModelPermission};
+#[cfg(server)]
+use super::limits::MAX_USER_DATA_IDS;
+#[cfg(server)]
+use super::security::require_csrf_token;
+#[cfg(server)]
+use reinhardt_db::orm::personal_data::{
+	Anonymizer, export_user_data_with_conn, registered_personal_data_models,
+};
+
+/// Whether the personal data actions apply to the model stored in `table_name`
+///
+/// True for models registered with `#[model(data_subject = ...)]` pointing
+/// at their own primary key.
+#[cfg(server)]
+pub(crate) fn is_data_subject_table(table_name: &str) -> bool {
+	registered_personal_data_models()
+		.iter()
+		.any(|model| model.table == table_name && model.subject_column == model.primary_key)
+}
+
+/// Export the personal data of the selected users
+///
+/// Returns one archive per user ID, covering every model registered for
+/// personal data.
+///
+/// # Server Function
+///
+/// This function is automatically exposed as an HTTP endpoint by the `#[server_fn]` macro.
+/// AdminSite and AdminDatabase dependencies are automatically injected via the DI system.
+///
+/// # Authentication
+///
+/// Requires staff (admin) permission and view permission for the user model.
+#[server_fn]
+pub async fn export_user_data_records(
+	model_name: String,
+	request: UserDataRequest,
+	#[inject] site: Depends<AdminSiteKey, AdminSite>,
+	#[inject] db: Depends<AdminDatabaseKey, AdminDatabase>,
+	#[inject] http_request: ServerFnRequest,
+	#[inject] AdminAuthenticatedUser(user): AdminAuthenticatedUser,
+) -> Result<UserDataExportResponse, ServerFnError> {
+	require_csrf_token(&request.csrf_token, &http_request.inner().headers)?;
+
+	let auth = AdminAuth::from_request(&http_request);
+	let model_admin = site.get_model_admin(&model_name).map_server_fn_error()?;
+	auth.require_model_permission(model_admin.as_ref(), user.as_ref(), ModelPermission::View)
+		.await?;
+	require_data_subject(model_admin.table_name(), &request.ids)?;
+
+	let user_id = auth.user_id().unwrap_or("unknown").to_string();
+	let client_ip = auth.client_ip().map(|ip| ip.to_string());
+
+	let mut exports = std::collections::HashMap::new();
+	let mut rows = 0;
+	for id in &request.ids {
+		let export = match export_user_data_with_conn(db.connection(), subject_value(id)).await {
+			Ok(export) => export,
+			Err(e) => {
+				audit::log_user_data(
+					&user_id,
+					client_ip.as_deref(),
+					AuditAction::Export,
+					&model_name,
+					&request.ids,
+					rows,
+					false,
+				);
+				return Err(ServerFnError::application(e.to_string()));
+			}
+		};
+		rows += export.total_rows() as u64;
+		let json = serde_json::to_value(&export)
+			.map_err(|e| ServerFnError::serialization(e.to_string()))?;
+		exports.insert(id.clone(), json);
+	}
+
+	audit::log_user_data(
+		&user_id,
+		client_ip.as_deref(),
+		AuditAction::Export,
+		&model_name,
+		&request.ids,
+		rows,
+		true,
+	);
+
+	Ok(UserDataExportResponse { exports })
+}
+
+/// Anonymize the personal data of the selected users
+///
+/// Each user is anonymized in its own transaction; the response reports the
+/// number of rows overwritten across all models.
+///
+/// # Server Function
+///
+/// This function is automatically exposed as an HTTP endpoint by the `#[server_fn]` macro.
+/// AdminSite and AdminDatabase dependencies are automatically injected via the DI system.
+///
+/// # Authentication
+///
+/// Requires staff (admin) permission and change permission for the user model.
+#[server_fn]
+pub async fn anonymize_user_records(
+	model_name: String,
+	request: UserDataRequest,
+	#[inject] site: Depends<AdminSiteKey, AdminSite>,
+	#[inject] db: Depends<AdminDatabaseKey, AdminDatabase>,
+	#[inject] http_request: ServerFnRequest,
+	#[inject] AdminAuthenticatedUser(user): AdminAuthenticatedUser,
+) -> Result<MutationResponse, ServerFnError> {
+	require_csrf_token(&request.csrf_token, &http_request.inner().headers)?;
+
+	let auth = AdminAuth::from_request(&http_request);
+	let model_admin = site.get_model_admin(&model_name).map_server_fn_error()?;
+	auth.require_model_permission(model_admin.as_ref(), user.as_ref(), ModelPermission::Change)
+		.await?;
+	require_data_subject(model_admin.table_name(), &request.ids)?;
+
+	let user_id = auth.user_id().unwrap_or("unknown").to_string();
+	let client_ip = auth.client_ip().map(|ip| ip.to_string());
+
+	let anonymizer = Anonymizer::new(db.connection());
+	let mut rows = 0;
+	for id in &request.ids {
+		match anonymizer.run(subject_value(id)).await {
+			Ok(report) => rows += report.total_rows() as u64,
+			Err(e) => {
+				audit::log_user_data(
+					&user_id,
+					client_ip.as_deref(),
+					AuditAction::Anonymize,
+					&model_name,
+					&request.ids,
+					rows,
+					false,
+				);
+				return Err(ServerFnError::application(e.to_string()));
+			}
+		}
+	}
+
+	audit::log_user_data(
+		&user_id,
+		client_ip.as_deref(),
+		AuditAction::Anonymize,
+		&model_name,
+		&request.ids,
+		rows,
+		true,
+	);
+
+	Ok(MutationResponse {
+		success: true,
+		message: format!(
+			"Anonymized {} row(s) for {} {}",
+			rows,
+			request.ids.len(),
+			model_name
+		),
+		affected: Some(rows),
+		data: None,
+	})
+}
+
+/// Reject requests for models that are not data subjects or with too many IDs
+#[cfg(server)]
+fn require_data_subject(table_name: &str, ids: &[String]) -> Result<(), ServerFnError> {
+	if !is_data_subject_table(table_name) {
+		return Err(ServerFnError::server(
+			400,
+			"Personal data actions are only available on the user model",
+		));
+	}
+	if ids.len() > MAX_USER_DATA_IDS {
+		return Err(ServerFnError::application(format!(
+			"Too many IDs for a personal data action: {} exceeds maximum of {}",
+			ids.len(),
+			MAX_USER_DATA_IDS
+		)));
+	}
+	Ok(())
+}
+
+/// Selected ID as the subject value: integers stay numbers
+#[cfg(server)]
+fn subject_value(id: &str) -> serde_json::Value {
+	match id.parse::<i64>() {
+		Ok(n) => serde_json::Value::from(n),
+		Err(_) => serde_json::Value::from(id),
+	}
+}
+
+#[cfg(all(test, server))]
+mod tests {
+	use super::*;
+	use reinhardt_db::orm::personal_data::{PersonalDataModel, register_personal_data};
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_is_data_subject_table_requires_own_primary_key() {
+		// Arrange
+		register_personal_data(PersonalDataModel {
+			app_label: "accounts",
+			model: "Member",
+			table: "accounts_member",
+			subject_column: "id",
+			primary_key: "id",
+			fields: Vec::new(),
+		});
+		register_personal_data(PersonalDataModel {
+			app_label: "accounts",
+			model: "Note",
+			table: "accounts_note",
+			subject_column: "member_id",
+			primary_key: "id",
+			fields: Vec::new(),
+		});
+
+		// Act & Assert
+		assert!(is_data_subject_table("accounts_member"));
+		assert!(!is_data_subject_table("accounts_note"));
+		assert!(!is_data_subject_table("accounts_other"));
+	}
+
+	#[rstest]
+	#[case("42", serde_json::json!(42))]
+	#[case("8d3c1c1e", serde_json::json!("8d3c1c1e"))]
+	fn test_subject_value_keeps_integer_ids_numeric(
+		#[case] id: &str,
+		#[case] expected: serde_json::Value,
+	) {
+		// Act & Assert
+		assert_eq!(subject_value(id), expected);
+	}
+}
@@ -0,0 +1,354 @@
+//! Recent admin actions
+//!
+//! Keeps the most recent successful audit entries in memory so the dashboard
+//! can show a "recent actions" panel. Delete entries may carry a snapshot of
+//! the removed row, which allows the deletion to be undone within the window
+//! configured by [`AdminSettings::undo_window_secs`].
+//!
+//! [`AdminSettings::undo_window_secs`]: crate::settings::AdminSettings::undo_window_secs
+
+use super::audit::{AuditAction, AuditEntry};
+use crate::types::RecentAction;
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::RwLock;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::LazyLock;
+
+/// Number of actions retained by the global log.
+pub const DEFAULT_RECENT_ACTIONS_CAPACITY: usize = 200;
+
+static RECENT_ACTIONS: LazyLock<RecentActionLog> =
+	LazyLock::new(|| RecentActionLog::new(DEFAULT_RECENT_ACTIONS_CAPACITY));
+
+/// An audit entry retained by [`RecentActionLog`].
+#[derive(Debug, Clone)]
+pub struct RecordedAction {
+	/// Identifier assigned by the log, unique for the process lifetime
+	pub id: u64,
+	/// When the action was recorded
+	pub recorded_at: DateTime<Utc>,
+	/// The audit entry describing the action
+	pub entry: AuditEntry,
+	/// Full row captured before a delete, if available
+	pub snapshot: Option<HashMap<String, serde_json::Value>>,
+	/// Whether the action has been undone
+	pub undone: bool,
+}
+
+impl RecordedAction {
+	/// Returns `true` if the action is a delete with a snapshot that has not
+	/// been undone and is still within `window` of `now`.
+	pub fn is_undoable(&self, window: Duration, now: DateTime<Utc>) -> bool {
+		self.entry.action == AuditAction::Delete
+			&& self.snapshot.is_some()
+			&& !self.undone
+			&& now - self.recorded_at <= window
+	}
+
+	/// Converts the action into the DTO sent to the admin UI.
+	pub fn to_dto(&self, window: Duration, now: DateTime<Utc>) -> RecentAction {
+		RecentAction {
+			id: self.id,
+			timestamp: self.entry.timestamp.clone(),
+			user_id: self.entry.user_id.clone(),
+			action: self.entry.action.to_string(),
+			model_name: self.entry.model_name.clone(),
+			record_id: self.entry.record_id.clone(),
+			undone: self.undone,
+			can_undo: self.is_undoable(window, now),
+		}
+	}
+}
+
+/// Reasons an undo request is rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UndoError {
+	/// No action with the given ID is retained
+	NotFound,
+	/// The action is not a delete with a captured snapshot
+	NotUndoable,
+	/// The action was already undone
+	AlreadyUndone,
+	/// The undo window has elapsed
+	Expired,
+}
+
+impl fmt::Display for UndoError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			UndoError::NotFound => write!(f, "Action not found"),
+			UndoError::NotUndoable => write!(f, "Action cannot be undone"),
+			UndoError::AlreadyUndone => write!(f, "Action has already been undone"),
+			UndoError::Expired => write!(f, "Undo window has expired"),
+		}
+	}
+}
+
+impl std::error::Error for UndoError {}
+
+/// Bounded, newest-last log of recent admin actions.
+#[derive(Debug)]
+pub struct RecentActionLog {
+	capacity: usize,
+	inner: RwLock<LogState>,
+}
+
+#[derive(Debug, Default)]
+struct LogState {
+	next_id: u64,
+	actions: VecDeque<RecordedAction>,
+}
+
+impl RecentActionLog {
+	/// Creates an empty log retaining at most `capacity` actions.
+	pub fn new(capacity: usize) -> Self {
+		Self {
+			capacity,
+			inner: RwLock::new(LogState {
+				next_id: 1,
+				actions: VecDeque::with_capacity(capacity),
+			}),
+		}
+	}
+
+	/// Returns the process-wide log fed by the audit trail.
+	pub fn global() -> &'static RecentActionLog {
+		&RECENT_ACTIONS
+	}
+
+	/// Records an action, evicting the oldest one when full.
+	///
+	/// Returns the identifier assigned to the action.
+	pub fn record(
+		&self,
+		entry: AuditEntry,
+		snapshot: Option<HashMap<String, serde_json::Value>>,
+	) -> u64 {
+		let mut state = self.inner.write();
+		let id = state.next_id;
+		state.next_id += 1;
+		if self.capacity == 0 {
+			return id;
+		}
+		while state.actions.len() >= self.capacity {
+			state.actions.pop_front();
+		}
+		state.actions.push_back(RecordedAction {
+			id,
+			recorded_at: Utc::now(),
+			entry,
+			snapshot,
+			undone: false,
+		});
+		id
+	}
+
+	/// Returns up to `limit` actions, newest first.
+	pub fn recent(&self, limit: usize) -> Vec<RecordedAction> {
+		self.inner
+			.read()
+			.actions
+			.iter()
+			.rev()
+			.take(limit)
+			.cloned()
+			.collect()
+	}
+
+	/// Returns the action with the given ID, if still retained.
+	pub fn get(&self, id: u64) -> Option<RecordedAction> {
+		self.inner
+			.read()
+			.actions
+			.iter()
+			.find(|a| a.id == id)
+			.cloned()
+	}
+
+	/// Marks an undoable action as undone and returns it.
+	///
+	/// The check and the update happen under one lock so concurrent undo
+	/// requests cannot restore the same row twice. Call [`release_undo`] if
+	/// the restore subsequently fails.
+	///
+	/// [`release_undo`]: Self::release_undo
+	pub fn claim_undo(
+		&self,
+		id: u64,
+		window: Duration,
+		now: DateTime<Utc>,
+	) -> Result<RecordedAction, UndoError> {
+		let mut state = self.inner.write();
+		let action = state
+			.actions
+			.iter_mut()
+			.find(|a| a.id == id)
+			.ok_or(UndoError::NotFound)?;
+		if action.entry.action != AuditAction::Delete || action.snapshot.is_none() {
+			return Err(UndoError::NotUndoable);
+		}
+		if action.undone {
+			return Err(UndoError::AlreadyUndone);
+		}
+		if now - action.recorded_at > window {
+			return Err(UndoError::Expired);
+		}
+		action.undone = true;
+		Ok(action.clone())
+	}
+
+	/// Clears the undone flag set by [`claim_undo`](Self::claim_undo).
+	pub fn release_undo(&self, id: u64) {
+		if let Some(action) = self.inner.write().actions.iter_mut().find(|a| a.id == id) {
+			action.undone = false;
+		}
+	}
+}
+
+/// Payload sent with the `post_save` signal when a deleted row is restored.
+///
+/// The admin works on dynamic records, so receivers connect to
+/// `post_save::<RestoredRecord>()` rather than to a concrete model type.
+#[derive(Debug, Clone)]
+pub struct RestoredRecord {
+	/// Name of the model the row belongs to
+	pub model_name: String,
+	/// Database table the row was inserted into
+	pub table_name: String,
+	/// Primary key of the restored row
+	pub record_id: String,
+	/// Column values that were re-inserted
+	pub data: HashMap<String, serde_json::Value>,
+}
+
+#[cfg(all(test, server))]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn entry(action: AuditAction, record_id: &str) -> AuditEntry {
+		AuditEntry {
+			timestamp: "2024-01-01T00:00:00Z".to_string(),
+			user_id: "user-42".to_string(),
+			ip_address: None,
+			action,
+			model_name: "Article".to_string(),
+			record_id: Some(record_id.to_string()),
+			changed_fields: None,
+			success: true,
+			affected_count: Some(1),
+		}
+	}
+
+	fn snapshot() -> Option<HashMap<String, serde_json::Value>> {
+		Some(HashMap::from([
+			("id".to_string(), serde_json::json!(1)),
+			("title".to_string(), serde_json::json!("Hello")),
+		]))
+	}
+
+	#[rstest]
+	fn test_recent_returns_newest_first_and_evicts_oldest() {
+		// Arrange
+		let log = RecentActionLog::new(2);
+
+		// Act
+		log.record(entry(AuditAction::Create, "1"), None);
+		log.record(entry(AuditAction::Update, "2"), None);
+		log.record(entry(AuditAction::Delete, "3"), None);
+		let recent = log.recent(10);
+
+		// Assert
+		let ids: Vec<_> = recent
+			.iter()
+			.map(|a| a.entry.record_id.as_deref().unwrap())
+			.collect();
+		assert_eq!(ids, vec!["3", "2"]);
+	}
+
+	#[rstest]
+	fn test_claim_undo_marks_action_once() {
+		// Arrange
+		let log = RecentActionLog::new(10);
+		let id = log.record(entry(AuditAction::Delete, "1"), snapshot());
+		let window = Duration::seconds(300);
+
+		// Act
+		let first = log.claim_undo(id, window, Utc::now());
+		let second = log.claim_undo(id, window, Utc::now());
+
+		// Assert
+		assert!(first.unwrap().snapshot.is_some());
+		assert_eq!(second.unwrap_err(), UndoError::AlreadyUndone);
+		assert!(log.get(id).unwrap().undone);
+	}
+
+	#[rstest]
+	fn test_release_undo_allows_retry() {
+		// Arrange
+		let log = RecentActionLog::new(10);
+		let id = log.record(entry(AuditAction::Delete, "1"), snapshot());
+		let window = Duration::seconds(300);
+		log.claim_undo(id, window, Utc::now()).unwrap();
+
+		// Act
+		log.release_undo(id);
+
+		// Assert
+		assert!(log.claim_undo(id, window, Utc::now()).is_ok());
+	}
+
+	#[rstest]
+	#[case::no_snapshot(AuditAction::Delete, None, UndoError::NotUndoable)]
+	#[case::not_delete(AuditAction::Update, snapshot(), UndoError::NotUndoable)]
+	fn test_claim_undo_rejects_non_undoable(
+		#[case] action: AuditAction,
+		#[case] snapshot: Option<HashMap<String, serde_json::Value>>,
+		#[case] expected: UndoError,
+	) {
+		// Arrange
+		let log = RecentActionLog::new(10);
+		let id = log.record(entry(action, "1"), snapshot);
+
+		// Act
+		let result = log.claim_undo(id, Duration::seconds(300), Utc::now());
+
+		// Assert
+		assert_eq!(result.unwrap_err(), expected);
+	}
+
+	#[rstest]
+	fn test_claim_undo_rejects_expired_and_unknown() {
+		// Arrange
+		let log = RecentActionLog::new(10);
+		let id = log.record(entry(AuditAction::Delete, "1"), snapshot());
+		let later = Utc::now() + Duration::seconds(301);
+
+		// Act
+		let expired = log.claim_undo(id, Duration::seconds(300), later);
+		let unknown = log.claim_undo(id + 1, Duration::seconds(300), Utc::now());
+
+		// Assert
+		assert_eq!(expired.unwrap_err(), UndoError::Expired);
+		assert_eq!(unknown.unwrap_err(), UndoError::NotFound);
+	}
+
+	#[rstest]
+	fn test_to_dto_reports_undo_availability() {
+		// Arrange
+		let log = RecentActionLog::new(10);
+		let id = log.record(entry(AuditAction::Delete, "7"), snapshot());
+		let action = log.get(id).unwrap();
+
+		// Act
+		let open = action.to_dto(Duration::seconds(300), Utc::now());
+		let closed = action.to_dto(Duration::zero(), Utc::now() + Duration::seconds(1));
+
+		// Assert
+		assert_eq!(open.action, "DELETE");
+		assert_eq!(open.record_id.as_deref(), Some("7"));
+		assert!(open.can_undo);
+		assert!(!closed.can_undo);
+	}
+}
@@ -0,0 +1,74 @@
+//! Data retention Server Function
+//!
+//! Reports the rows of models declaring `#[model(retain = "...")]` that will
+//! be deleted or anonymized soon, backed by
+//! [`reinhardt_db::orm::retention`].
+
+#[cfg(server)]
+use super::admin_auth::AdminAuthenticatedUser;
+#[cfg(server)]
+use crate::adapters::RetentionModelInfo;
+use crate::adapters::{AdminDatabase, RetentionReportResponse};
+#[cfg(server)]
+use crate::core::AdminDatabaseKey;
+#[cfg(server)]
+use reinhardt_di::Depends;
+#[cfg(server)]
+use reinhardt_pages::server_fn::ServerFnRequest;
+use reinhardt_pages::server_fn::{ServerFnError, server_fn};
+
+#[cfg(server)]
+use super::error::AdminAuth;
+#[cfg(server)]
+use super::limits::MAX_RETENTION_REPORT_DAYS;
+#[cfg(server)]
+use reinhardt_db::orm::retention::upcoming_expirations;
+
+/// Get the rows expiring within the next `within_days` days
+///
+/// Lists every model with a retention period, including those with no rows
+/// expiring in the window.
+///
+/// # Server Function
+///
+/// This function is automatically exposed as an HTTP endpoint by the `#[server_fn]` macro.
+/// The AdminDatabase dependency is automatically injected via the DI system.
+///
+/// # Authentication
+///
+/// Requires staff (admin) permission to access the admin panel.
+#[server_fn]
+pub async fn get_retention_report(
+	within_days: u32,
+	#[inject] db: Depends<AdminDatabaseKey, AdminDatabase>,
+	#[inject] http_request: ServerFnRequest,
+	#[inject] AdminAuthenticatedUser(_user): AdminAuthenticatedUser,
+) -> Result<RetentionReportResponse, ServerFnError> {
+	let auth = AdminAuth::from_request(&http_request);
+	auth.require_staff()?;
+
+	if within_days == 0 || within_days > MAX_RETENTION_REPORT_DAYS {
+		return Err(ServerFnError::application(format!(
+			"Retention report window must be between 1 and {} days",
+			MAX_RETENTION_REPORT_DAYS
+		)));
+	}
+
+	let within = std::time::Duration::from_secs(u64::from(within_days) * 86_400);
+	let upcoming = upcoming_expirations(db.connection(), within)
+		.await
+		.map_err(|e| ServerFnError::application(e.to_string()))?;
+
+	Ok(RetentionReportResponse {
+		within_days,
+		models: upcoming
+			.into_iter()
+			.map(|expiration| RetentionModelInfo {
+				model: expiration.model,
+				action: expiration.action.to_string(),
+				retain_days: expiration.period.as_secs() / 86_400,
+				rows: expiration.rows,
+			})
+			.collect(),
+	})
+}
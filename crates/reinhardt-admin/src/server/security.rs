@@ -483,8 +483,44 @@ pub fn require_csrf_header(
 /// ```
 #[cfg(server)]
 pub fn sanitize_mutation_values(data: &mut HashMap<String, serde_json::Value>) {
-	for value in data.values_mut() {
-		sanitize_json_value(value);
+	sanitize_mutation_values_with_rich_text(data, &[]);
+}
+
+/// Sanitizes mutation data, keeping allowed markup in rich text fields.
+///
+/// String values of `rich_text_fields` are cleaned with
+/// [`SanitizePolicy::rich_text`](reinhardt_core::security::SanitizePolicy::rich_text),
+/// which keeps formatting but removes scripts, event handlers and unsafe
+/// URLs. All other values are escaped as by [`sanitize_mutation_values`].
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_admin::server::security::sanitize_mutation_values_with_rich_text;
+/// use std::collections::HashMap;
+///
+/// let mut data = HashMap::new();
+/// data.insert("body".to_string(), serde_json::json!("<p onclick=\"x()\">Hi</p>"));
+/// data.insert("title".to_string(), serde_json::json!("<b>Title</b>"));
+///
+/// sanitize_mutation_values_with_rich_text(&mut data, &["body"]);
+///
+/// assert_eq!(data["body"], "<p>Hi</p>");
+/// assert_eq!(data["title"], "&lt;b&gt;Title&lt;/b&gt;");
+/// ```
+#[cfg(server)]
+pub fn sanitize_mutation_values_with_rich_text(
+	data: &mut HashMap<String, serde_json::Value>,
+	rich_text_fields: &[&str],
+) {
+	let policy = reinhardt_core::security::SanitizePolicy::rich_text();
+	for (field, value) in data.iter_mut() {
+		match value {
+			serde_json::Value::String(s) if rich_text_fields.contains(&field.as_str()) => {
+				*s = policy.clean(s);
+			}
+			_ => sanitize_json_value(value),
+		}
 	}
 }
 
@@ -926,6 +962,24 @@ mod tests {
 		assert!(name.contains("&lt;script&gt;"));
 	}
 
+	#[rstest]
+	fn test_sanitize_mutation_values_with_rich_text_keeps_markup() {
+		// Arrange
+		let mut data = HashMap::new();
+		data.insert(
+			"body".to_string(),
+			serde_json::json!("<h2>Intro</h2><img src=x onerror=alert(1)><script>x</script>"),
+		);
+		data.insert("title".to_string(), serde_json::json!("<h2>Intro</h2>"));
+
+		// Act
+		sanitize_mutation_values_with_rich_text(&mut data, &["body"]);
+
+		// Assert
+		assert_eq!(data["body"], "<h2>Intro</h2><img src=\"x\">");
+		assert_eq!(data["title"], "&lt;h2&gt;Intro&lt;/h2&gt;");
+	}
+
 	#[rstest]
 	fn test_sanitize_mutation_values_preserves_non_string_values() {
 		// Arrange
@@ -14,10 +14,13 @@
 //! admin_types::FieldType           →  admin_types::FilterType
 //! ```
 
+use crate::core::ModelAdmin;
 use crate::types::{FieldType as AdminFieldType, FilterChoice, FilterType};
 use reinhardt_db::migrations::{
 	FieldMetadata, FieldType as DbFieldType, ModelMetadata, global_registry,
 };
+use reinhardt_utils::utils_core::timezone;
+use std::collections::HashMap;
 
 /// Infers the admin UI field type from a database field type.
 ///
@@ -308,10 +311,15 @@ fn humanize_value(value: &str) -> String {
 /// ```
 pub fn find_model_by_table_name(table_name: &str) -> Option<ModelMetadata> {
 	let registry = global_registry();
-	registry
+	let matches: Vec<ModelMetadata> = registry
 		.get_models()
 		.into_iter()
-		.find(|m| m.table_name == table_name)
+		.filter(|m| m.table_name == table_name)
+		.collect();
+	// Proxy models share their concrete model's table, so prefer the
+	// concrete model's field metadata when both are registered.
+	let concrete = matches.iter().position(|m| !m.is_proxy()).unwrap_or(0);
+	matches.into_iter().nth(concrete)
 }
 
 /// Gets field metadata for a specific field from a model.
@@ -346,6 +354,83 @@ pub fn get_field_metadata(table_name: &str, field_name: &str) -> Option<FieldMet
 	})
 }
 
+/// Resolves the admin field type of `field_name` on a registered model.
+///
+/// Choices declared with [`ModelAdmin::field_choices`] take precedence and
+/// yield a `Select`; otherwise the type is inferred from the field metadata.
+pub fn resolve_admin_field_type(
+	model_admin: &dyn ModelAdmin,
+	field_name: &str,
+) -> Option<AdminFieldType> {
+	if let Some(choices) = model_admin.field_choices(field_name) {
+		return Some(AdminFieldType::Select { choices });
+	}
+	get_field_metadata(model_admin.table_name(), field_name)
+		.map(|meta| infer_admin_field_type(&meta.field_type))
+}
+
+/// Renders the datetime fields of `records` in the current time zone.
+///
+/// Datetimes are stored in UTC; RFC 3339 values are rewritten with the offset
+/// of the time zone activated for the request. Other values are left as is.
+pub fn localize_datetimes(
+	model_admin: &dyn ModelAdmin,
+	records: &mut [HashMap<String, serde_json::Value>],
+) {
+	let Some(first) = records.first() else {
+		return;
+	};
+	let datetime_fields: Vec<String> = first
+		.keys()
+		.filter(|field| {
+			resolve_admin_field_type(model_admin, field) == Some(AdminFieldType::DateTime)
+		})
+		.cloned()
+		.collect();
+	for record in records.iter_mut() {
+		for field in &datetime_fields {
+			let Some(serde_json::Value::String(text)) = record.get_mut(field) else {
+				continue;
+			};
+			if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(text) {
+				*text = timezone::to_current_timezone(&dt.with_timezone(&chrono::Utc)).to_rfc3339();
+			}
+		}
+	}
+}
+
+/// Returns the target of a foreign key or one-to-one field.
+///
+/// `field_name` may be the relation name (`author`) or its column name
+/// (`author_id`). Foreign keys yield the target table name; one-to-one
+/// fields yield the target model reference (e.g. `"app.User"`).
+///
+/// # Examples
+///
+/// ```ignore
+/// use reinhardt_admin::server::type_inference::get_relation_target;
+///
+/// assert_eq!(get_relation_target("blog_post", "author_id").as_deref(), Some("auth_user"));
+/// ```
+pub fn get_relation_target(table_name: &str, field_name: &str) -> Option<String> {
+	let model = find_model_by_table_name(table_name)?;
+	let meta = model.fields.get(field_name).or_else(|| {
+		field_name
+			.strip_suffix("_id")
+			.and_then(|relation| model.fields.get(relation))
+	})?;
+	relation_target(&meta.field_type)
+}
+
+/// Extracts the target of a relationship field type.
+fn relation_target(field_type: &DbFieldType) -> Option<String> {
+	match field_type {
+		DbFieldType::ForeignKey { to_table, .. } => Some(to_table.clone()),
+		DbFieldType::OneToOne { to, .. } => Some(to.clone()),
+		_ => None,
+	}
+}
+
 #[cfg(all(test, server))]
 mod tests {
 	use super::*;
@@ -638,6 +723,31 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_relation_target() {
+		use reinhardt_db::migrations::ForeignKeyAction;
+
+		assert_eq!(
+			relation_target(&DbFieldType::ForeignKey {
+				to_table: "auth_user".to_string(),
+				to_field: "id".to_string(),
+				on_delete: ForeignKeyAction::Cascade,
+			})
+			.as_deref(),
+			Some("auth_user")
+		);
+		assert_eq!(
+			relation_target(&DbFieldType::OneToOne {
+				to: "auth.User".to_string(),
+				on_delete: ForeignKeyAction::Cascade,
+				on_update: ForeignKeyAction::Cascade,
+			})
+			.as_deref(),
+			Some("auth.User")
+		);
+		assert_eq!(relation_target(&DbFieldType::Integer), None);
+	}
+
 	#[test]
 	fn test_infer_admin_field_type_custom() {
 		assert_eq!(
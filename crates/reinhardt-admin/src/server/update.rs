@@ -1,13 +1,14 @@
 //! Update operation Server Function
 //!
-//! Provides update operations for admin models.
+//! Provides update operations for admin models, including saving inline
+//! edits from the list view.
 
 #[cfg(server)]
 use super::admin_auth::AdminAuthenticatedUser;
 use crate::adapters::{AdminDatabase, AdminRecord, AdminSite};
 #[cfg(server)]
 use crate::core::{AdminDatabaseKey, AdminSiteKey};
-use crate::types::MutationResponse;
+use crate::types::{BulkUpdateResponse, MutationResponse};
 #[cfg(server)]
 use reinhardt_di::Depends;
 #[cfg(server)]
@@ -17,17 +18,25 @@ use reinhardt_pages::server_fn::{ServerFnError, server_fn};
 #[cfg(server)]
 use super::audit;
 #[cfg(server)]
+use super::edit_locks::EditLockRegistry;
+#[cfg(server)]
 use super::error::{AdminAuth, MapServerFnError, ModelPermission};
 #[cfg(server)]
-use super::security::{require_csrf_token, sanitize_mutation_values};
+use super::limits::MAX_BULK_UPDATE_ROWS;
+#[cfg(server)]
+use super::security::{require_csrf_token, sanitize_mutation_values_with_rich_text};
 #[cfg(server)]
-use super::validation::validate_mutation_data;
+use super::validation::{validate_list_edit_data, validate_mutation_data};
 
 /// Update an existing model instance
 ///
 /// Updates a record in the database by ID using the provided field data.
 /// Returns the number of affected rows (typically 1) on success.
 ///
+/// Saving is refused with 409 while another user holds the record's edit
+/// lock (see [`acquire_edit_lock`](super::acquire_edit_lock)); a successful
+/// save releases the caller's own lock.
+///
 /// # Server Function
 ///
 /// This function is automatically exposed as an HTTP endpoint by the `#[server_fn]` macro.
@@ -76,15 +85,27 @@ pub async fn update_record(
 
 	// Validate input data before database operation
 	validate_mutation_data(&request.data, model_admin.as_ref(), true).map_server_fn_error()?;
+	model_admin
+		.validate_values(Some(&id), &request.data)
+		.map_server_fn_error()?;
 
 	// Sanitize string values to prevent stored XSS
 	let mut sanitized_data = request.data;
-	sanitize_mutation_values(&mut sanitized_data);
+	sanitize_mutation_values_with_rich_text(&mut sanitized_data, &model_admin.rich_text_fields());
 
 	// Inject current timestamp for auto_now fields (updated on every save)
 	super::create::inject_auto_now_timestamps(&mut sanitized_data, table_name);
 
 	let user_id = auth.user_id().unwrap_or("unknown").to_string();
+	let client_ip = auth.client_ip().map(|ip| ip.to_string());
+
+	let locks = EditLockRegistry::global();
+	if let Some(lock) = locks.held_by_other(&model_name, &id, &user_id, chrono::Utc::now()) {
+		return Err(ServerFnError::server(
+			409,
+			format!("{} is being edited by {}", model_name, lock.username),
+		));
+	}
 
 	let result = db
 		.update::<AdminRecord>(table_name, pk_field, &id, sanitized_data.clone())
@@ -94,7 +115,14 @@ pub async fn update_record(
 	// Check for database errors first, logging failure before returning
 	let affected = match result {
 		Err(e) => {
-			audit::log_update(&user_id, &model_name, &id, &sanitized_data, false);
+			audit::log_update(
+				&user_id,
+				client_ip.as_deref(),
+				&model_name,
+				&id,
+				&sanitized_data,
+				false,
+			);
 			return Err(e);
 		}
 		Ok(n) => n,
@@ -103,14 +131,29 @@ pub async fn update_record(
 	// Return 404 error when no record was found with the given ID.
 	// Only log success=true after confirming the record was actually updated.
 	if affected == 0 {
-		audit::log_update(&user_id, &model_name, &id, &sanitized_data, false);
+		audit::log_update(
+			&user_id,
+			client_ip.as_deref(),
+			&model_name,
+			&id,
+			&sanitized_data,
+			false,
+		);
 		return Err(ServerFnError::server(
 			404,
 			format!("{} not found", model_name),
 		));
 	}
 
-	audit::log_update(&user_id, &model_name, &id, &sanitized_data, true);
+	audit::log_update(
+		&user_id,
+		client_ip.as_deref(),
+		&model_name,
+		&id,
+		&sanitized_data,
+		true,
+	);
+	locks.release(&model_name, &id, &user_id);
 
 	Ok(MutationResponse {
 		success: true,
@@ -119,3 +162,164 @@ pub async fn update_record(
 		data: None,
 	})
 }
+
+/// Save inline edits from the list view
+///
+/// Updates the `list_editable` fields of several records in one request.
+/// Every row is validated before anything is written; if any row is invalid,
+/// nothing is saved and the response carries one error message per rejected
+/// row. Database errors during saving are reported the same way, so rows
+/// saved before the failure stay saved.
+///
+/// # Server Function
+///
+/// This function is automatically exposed as an HTTP endpoint by the `#[server_fn]` macro.
+/// AdminSite and AdminDatabase dependencies are automatically injected via the DI system.
+///
+/// # Authentication
+///
+/// Requires staff (admin) permission and change permission for the model.
+///
+/// # Example
+///
+/// ```ignore
+/// use reinhardt_admin::server::bulk_update_records;
+/// use reinhardt_admin::types::{BulkUpdateRequest, BulkUpdateRow};
+/// use std::collections::HashMap;
+///
+/// let mut data = HashMap::new();
+/// data.insert("is_active".to_string(), serde_json::json!(false));
+///
+/// let request = BulkUpdateRequest {
+///     csrf_token: "token".to_string(),
+///     rows: vec![BulkUpdateRow { id: "42".to_string(), data }],
+/// };
+/// let response = bulk_update_records("User".to_string(), request).await?;
+/// for (id, error) in &response.errors {
+///     println!("Row {}: {}", id, error);
+/// }
+/// ```
+#[server_fn]
+pub async fn bulk_update_records(
+	model_name: String,
+	request: crate::types::BulkUpdateRequest,
+	#[inject] site: Depends<AdminSiteKey, AdminSite>,
+	#[inject] db: Depends<AdminDatabaseKey, AdminDatabase>,
+	#[inject] http_request: ServerFnRequest,
+	#[inject] AdminAuthenticatedUser(user): AdminAuthenticatedUser,
+) -> Result<crate::types::BulkUpdateResponse, ServerFnError> {
+	use std::collections::HashMap;
+
+	// CSRF token validation (double-submit cookie pattern)
+	require_csrf_token(&request.csrf_token, &http_request.inner().headers)?;
+
+	// Authentication and authorization check
+	let auth = AdminAuth::from_request(&http_request);
+	let model_admin = site.get_model_admin(&model_name).map_server_fn_error()?;
+	auth.require_model_permission(model_admin.as_ref(), user.as_ref(), ModelPermission::Change)
+		.await?;
+
+	if request.rows.len() > MAX_BULK_UPDATE_ROWS {
+		return Err(ServerFnError::application(format!(
+			"Too many rows for bulk update: {} exceeds maximum of {}",
+			request.rows.len(),
+			MAX_BULK_UPDATE_ROWS
+		)));
+	}
+
+	// Validate every row before writing anything
+	let mut errors = HashMap::new();
+	for row in &request.rows {
+		if let Err(e) = validate_list_edit_data(&row.data, model_admin.as_ref())
+			.and_then(|()| model_admin.validate_values(Some(&row.id), &row.data))
+		{
+			errors.insert(row.id.clone(), e.to_string());
+		}
+	}
+	if !errors.is_empty() {
+		return Ok(BulkUpdateResponse {
+			success: false,
+			updated: 0,
+			message: format!("{} row(s) contain errors; nothing was saved", errors.len()),
+			errors,
+		});
+	}
+
+	let table_name = model_admin.table_name();
+	let pk_field = model_admin.pk_field();
+	let user_id = auth.user_id().unwrap_or("unknown").to_string();
+	let client_ip = auth.client_ip().map(|ip| ip.to_string());
+	let mut updated = 0;
+
+	for row in request.rows {
+		if row.data.is_empty() {
+			continue;
+		}
+
+		// Sanitize string values to prevent stored XSS
+		let mut sanitized_data = row.data;
+		sanitize_mutation_values_with_rich_text(
+			&mut sanitized_data,
+			&model_admin.rich_text_fields(),
+		);
+		super::create::inject_auto_now_timestamps(&mut sanitized_data, table_name);
+
+		match db
+			.update::<AdminRecord>(table_name, pk_field, &row.id, sanitized_data.clone())
+			.await
+		{
+			Ok(0) => {
+				audit::log_update(
+					&user_id,
+					client_ip.as_deref(),
+					&model_name,
+					&row.id,
+					&sanitized_data,
+					false,
+				);
+				errors.insert(row.id, format!("{} not found", model_name));
+			}
+			Ok(affected) => {
+				audit::log_update(
+					&user_id,
+					client_ip.as_deref(),
+					&model_name,
+					&row.id,
+					&sanitized_data,
+					true,
+				);
+				updated += affected;
+			}
+			Err(e) => {
+				audit::log_update(
+					&user_id,
+					client_ip.as_deref(),
+					&model_name,
+					&row.id,
+					&sanitized_data,
+					false,
+				);
+				errors.insert(row.id, e.to_string());
+			}
+		}
+	}
+
+	let success = errors.is_empty();
+	let message = if success {
+		format!("{} {} record(s) updated", updated, model_name)
+	} else {
+		format!(
+			"{} {} record(s) updated, {} failed",
+			updated,
+			model_name,
+			errors.len()
+		)
+	};
+
+	Ok(BulkUpdateResponse {
+		success,
+		updated,
+		errors,
+		message,
+	})
+}
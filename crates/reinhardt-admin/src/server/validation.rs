@@ -7,6 +7,7 @@
 //!
 //! - **Field allowlist**: Only fields defined in `ModelAdmin.fields()` or `list_display()` are allowed
 //! - **Readonly enforcement**: Fields in `readonly_fields()` cannot be modified
+//! - **List edits**: Inline list edits may only touch fields in `list_editable()`
 //! - **Type validation**: Values are checked for basic type compatibility
 //! - **Size limits**: Payload size and field counts are limited to prevent DoS
 
@@ -96,13 +97,63 @@ pub fn validate_mutation_data(
 	Ok(())
 }
 
+/// Validates one row of an inline list edit.
+///
+/// Applies the same size, read-only and primary key checks as
+/// [`validate_mutation_data`] for an update, but only fields listed in
+/// `ModelAdmin.list_editable()` are accepted.
+///
+/// # Errors
+///
+/// Returns `AdminError::ValidationError` if validation fails.
+pub fn validate_list_edit_data(
+	data: &HashMap<String, serde_json::Value>,
+	model_admin: &dyn ModelAdmin,
+) -> Result<(), AdminError> {
+	validate_field_count(data)?;
+	validate_payload_size(data)?;
+
+	let editable_fields = model_admin.list_editable();
+	let readonly_fields = model_admin.readonly_fields();
+	let pk_field = model_admin.pk_field();
+
+	for (field_name, value) in data {
+		if !editable_fields.contains(&field_name.as_str()) {
+			return Err(AdminError::ValidationError(format!(
+				"Field '{}' is not editable in the list view",
+				field_name
+			)));
+		}
+		if readonly_fields.contains(&field_name.as_str()) || field_name == pk_field {
+			return Err(AdminError::ValidationError(format!(
+				"Field '{}' is read-only and cannot be modified",
+				field_name
+			)));
+		}
+		validate_value_size(field_name, value)?;
+	}
+
+	Ok(())
+}
+
 /// Gets the list of allowed fields from model admin.
 ///
-/// Falls back to `list_display()` if `fields()` returns None.
-fn get_allowed_fields(model_admin: &dyn ModelAdmin) -> Vec<&str> {
-	model_admin
+/// Falls back to `list_display()` if `fields()` returns None. Translated
+/// fields also allow their per-language columns.
+fn get_allowed_fields(model_admin: &dyn ModelAdmin) -> Vec<String> {
+	let mut allowed_fields = Vec::new();
+	for field in model_admin
 		.fields()
 		.unwrap_or_else(|| model_admin.list_display())
+	{
+		allowed_fields.push(field.to_string());
+		for (_, column) in model_admin.field_translations(field) {
+			if !allowed_fields.contains(&column) {
+				allowed_fields.push(column);
+			}
+		}
+	}
+	allowed_fields
 }
 
 /// Validates that the number of fields doesn't exceed the limit.
@@ -134,8 +185,8 @@ fn validate_payload_size(data: &HashMap<String, serde_json::Value>) -> Result<()
 }
 
 /// Validates that a field is in the allowed list.
-fn validate_field_allowed(field_name: &str, allowed_fields: &[&str]) -> Result<(), AdminError> {
-	if !allowed_fields.contains(&field_name) {
+fn validate_field_allowed(field_name: &str, allowed_fields: &[String]) -> Result<(), AdminError> {
+	if !allowed_fields.iter().any(|field| field == field_name) {
 		return Err(AdminError::ValidationError(format!(
 			"Field '{}' is not allowed. Allowed fields: {:?}",
 			field_name, allowed_fields
@@ -323,6 +374,116 @@ mod tests {
 		assert!(validate_mutation_data(&data, &admin, false).is_ok());
 	}
 
+	#[rstest]
+	fn test_validate_allows_translation_columns() {
+		// Arrange
+		let admin = ModelAdminConfig::builder()
+			.model_name("Article")
+			.fields(vec!["title"])
+			.translations::<TranslatedArticle>()
+			.build()
+			.unwrap();
+		let mut data = HashMap::new();
+		data.insert("title".to_string(), serde_json::json!("Hello"));
+		data.insert("title_ja".to_string(), serde_json::json!("こんにちは"));
+
+		// Act
+		let result = validate_mutation_data(&data, &admin, false);
+
+		// Assert
+		assert!(result.is_ok());
+	}
+
+	#[derive(Clone, serde::Serialize, serde::Deserialize)]
+	struct TranslatedArticle {
+		id: Option<i64>,
+	}
+
+	#[derive(Clone)]
+	struct TranslatedArticleFields;
+
+	impl reinhardt_db::orm::model::FieldSelector for TranslatedArticleFields {
+		fn with_alias(self, _alias: &str) -> Self {
+			self
+		}
+	}
+
+	impl reinhardt_db::orm::Model for TranslatedArticle {
+		type PrimaryKey = i64;
+		type Fields = TranslatedArticleFields;
+		type Objects = reinhardt_db::orm::Manager<Self>;
+
+		fn table_name() -> &'static str {
+			"articles"
+		}
+
+		fn new_fields() -> Self::Fields {
+			TranslatedArticleFields
+		}
+
+		fn primary_key(&self) -> Option<Self::PrimaryKey> {
+			self.id
+		}
+
+		fn set_primary_key(&mut self, key: Self::PrimaryKey) {
+			self.id = Some(key);
+		}
+	}
+
+	impl reinhardt_db::orm::translation::TranslatedModel for TranslatedArticle {
+		fn translated_fields() -> &'static [&'static str] {
+			&["title"]
+		}
+
+		fn languages() -> &'static [&'static str] {
+			&["en", "ja"]
+		}
+	}
+
+	fn create_list_editable_admin() -> ModelAdminConfig {
+		ModelAdminConfig::builder()
+			.model_name("TestModel")
+			.list_display(vec!["id", "name", "is_active", "created_at"])
+			.list_editable(vec!["name", "is_active", "created_at"])
+			.readonly_fields(vec!["created_at"])
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	fn test_validate_list_edit_accepts_editable_fields() {
+		// Arrange
+		let admin = create_list_editable_admin();
+		let mut data = HashMap::new();
+		data.insert("name".to_string(), serde_json::json!("Alice"));
+		data.insert("is_active".to_string(), serde_json::json!(false));
+
+		// Act
+		let result = validate_list_edit_data(&data, &admin);
+
+		// Assert
+		assert!(result.is_ok());
+	}
+
+	#[rstest]
+	#[case::not_editable("id")]
+	#[case::readonly("created_at")]
+	#[case::unknown("email")]
+	fn test_validate_list_edit_rejects_field(#[case] field: &str) {
+		// Arrange
+		let admin = create_list_editable_admin();
+		let mut data = HashMap::new();
+		data.insert(field.to_string(), serde_json::json!("x"));
+
+		// Act
+		let result = validate_list_edit_data(&data, &admin);
+
+		// Assert
+		let err = result.unwrap_err();
+		assert!(matches!(err, AdminError::ValidationError(_)));
+		assert!(err.to_string().contains(field));
+	}
+
 	// ==================== Boundary value: field count ====================
 
 	#[rstest]
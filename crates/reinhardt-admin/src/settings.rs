@@ -34,6 +34,14 @@ mod inner {
 		"/admin/logout".to_string()
 	}
 
+	fn default_undo_window_secs() -> u64 {
+		300
+	}
+
+	fn default_edit_lock_ttl_secs() -> u64 {
+		60
+	}
+
 	fn default_self_only() -> Vec<String> {
 		vec!["'self'".to_string()]
 	}
@@ -212,6 +220,21 @@ mod inner {
 		/// URL path for the admin logout page.
 		#[serde(default = "default_logout_url")]
 		pub logout_url: String,
+		/// Seconds during which a deleted record can be restored from the
+		/// recent actions panel. `0` disables undo.
+		#[serde(default = "default_undo_window_secs")]
+		pub undo_window_secs: u64,
+		/// Seconds an edit lock survives without a heartbeat from the
+		/// browser holding it. The change view renews its lock at half this
+		/// interval.
+		#[serde(default = "default_edit_lock_ttl_secs")]
+		pub edit_lock_ttl_secs: u64,
+		/// Path of a `.toml` or `.json` admin configuration file applied
+		/// when the admin routes are built.
+		///
+		/// See [`AdminConfigFile`](crate::core::AdminConfigFile).
+		#[serde(default)]
+		pub config_file: Option<String>,
 		/// Content Security Policy settings.
 		#[serde(default)]
 		pub csp: AdminCspSettings,
@@ -228,6 +251,9 @@ mod inner {
 				list_per_page: default_list_per_page(),
 				login_url: default_login_url(),
 				logout_url: default_logout_url(),
+				undo_window_secs: default_undo_window_secs(),
+				edit_lock_ttl_secs: default_edit_lock_ttl_secs(),
+				config_file: None,
 				csp: AdminCspSettings::default(),
 				security: AdminSecuritySettings::default(),
 			}
@@ -400,6 +426,9 @@ mod inner {
 			assert_eq!(settings.list_per_page, 100);
 			assert_eq!(settings.login_url, "/admin/login");
 			assert_eq!(settings.logout_url, "/admin/logout");
+			assert_eq!(settings.undo_window_secs, 300);
+			assert_eq!(settings.edit_lock_ttl_secs, 60);
+			assert_eq!(settings.config_file, None);
 		}
 
 		#[rstest]
@@ -11,6 +11,152 @@ pub struct ModelInfo {
 	pub list_url: String,
 }
 
+/// Permissions the current user holds on a model
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModelPermissions {
+	/// Whether records can be listed and viewed
+	pub view: bool,
+	/// Whether records can be created
+	pub add: bool,
+	/// Whether records can be updated
+	pub change: bool,
+	/// Whether records can be deleted
+	pub delete: bool,
+}
+
+/// Model description exposed by the headless JSON API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiModelInfo {
+	/// Model name
+	pub name: String,
+	/// Collection URL of the model in the JSON API
+	pub url: String,
+	/// Fields shown as list columns
+	pub list_display: Vec<String>,
+	/// Fields accepted as list filters
+	pub list_filter: Vec<String>,
+	/// Fields searched by the `search` query parameter
+	pub search_fields: Vec<String>,
+	/// Default ordering
+	pub ordering: Vec<String>,
+	/// Bulk actions available on the model
+	pub actions: Vec<String>,
+	/// Permissions of the current user
+	pub permissions: ModelPermissions,
+}
+
+/// Widget placed on the admin dashboard
+///
+/// The layout is a grid of columns: widgets are stacked by `order` within
+/// their `column` and may span several columns. `kind` names the widget
+/// rendered by the frontend (e.g. `"recent_actions"`), and `options` carries
+/// its kind-specific settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DashboardWidget {
+	/// Unique key of the widget
+	pub id: String,
+	/// Widget type rendered by the frontend
+	pub kind: String,
+	/// Title shown above the widget
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub title: Option<String>,
+	/// Grid column the widget is placed in, starting at 0
+	#[serde(default)]
+	pub column: u32,
+	/// Position within the column; lower values are shown first
+	#[serde(default)]
+	pub order: i32,
+	/// Number of grid columns the widget spans
+	#[serde(default = "default_widget_span")]
+	pub span: u32,
+	/// Kind-specific settings
+	#[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+	pub options: serde_json::Map<String, serde_json::Value>,
+}
+
+fn default_widget_span() -> u32 {
+	1
+}
+
+impl DashboardWidget {
+	/// Widget of `kind` spanning one column, placed first in column 0
+	pub fn new(id: impl Into<String>, kind: impl Into<String>) -> Self {
+		Self {
+			id: id.into(),
+			kind: kind.into(),
+			title: None,
+			column: 0,
+			order: 0,
+			span: default_widget_span(),
+			options: serde_json::Map::new(),
+		}
+	}
+}
+
+/// Sidebar section grouping models and custom links
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NavSection {
+	/// Section title
+	pub label: String,
+	/// Icon name shown next to the title
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub icon: Option<String>,
+	/// Entries in display order
+	pub items: Vec<NavItem>,
+}
+
+/// Single sidebar entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NavItem {
+	/// Display label
+	pub label: String,
+	/// Target URL
+	pub url: String,
+	/// Icon name
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub icon: Option<String>,
+	/// Whether the entry links to a registered model's list view
+	#[serde(default)]
+	pub is_model: bool,
+}
+
+/// Single breadcrumb entry
+///
+/// The last breadcrumb of a trail usually has no URL and represents the
+/// current page.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Breadcrumb {
+	/// Display label
+	pub label: String,
+	/// Target URL, `None` for the current page
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub url: Option<String>,
+}
+
+/// An entry of the dashboard's "recent actions" panel
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentAction {
+	/// Identifier used to request an undo
+	pub id: u64,
+	/// When the action happened (ISO 8601)
+	pub timestamp: String,
+	/// Identifier of the user who performed the action
+	pub user_id: String,
+	/// Action kind (e.g., "CREATE", "DELETE")
+	pub action: String,
+	/// Name of the affected model
+	pub model_name: String,
+	/// Primary key of the affected record(s)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub record_id: Option<String>,
+	/// Whether the action has been undone
+	#[serde(default)]
+	pub undone: bool,
+	/// Whether the action can still be undone by the current user
+	#[serde(default)]
+	pub can_undo: bool,
+}
+
 /// Field metadata for dynamic form generation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FieldInfo {
@@ -28,6 +174,12 @@ pub struct FieldInfo {
 	pub help_text: Option<String>,
 	/// Placeholder text for input
 	pub placeholder: Option<String>,
+	/// Language of this column when it stores one translation of a field
+	///
+	/// Consecutive fields with the same label and a language are shown as
+	/// one field with a tab per language.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub language: Option<String>,
 }
 
 /// Field type for form rendering
@@ -62,6 +214,19 @@ pub enum FieldType {
 	File,
 	/// Hidden field
 	Hidden,
+	/// Select over the records of a related model (foreign key).
+	ForeignKey {
+		/// Registered name of the related model.
+		related_model: String,
+		/// Related records as `(primary key, label)` pairs.
+		choices: Vec<(String, String)>,
+		/// Related model field used to label new records, if any.
+		#[serde(default)]
+		label_field: Option<String>,
+		/// Whether the user may create related records from the form.
+		#[serde(default)]
+		can_add: bool,
+	},
 }
 
 /// Rendering specification for a form field.
@@ -99,6 +264,20 @@ pub enum FormFieldSpec {
 	File,
 	/// `<input type="hidden">` for hidden values.
 	Hidden,
+	/// `<select>` over related records, with an optional "add related"
+	/// button that opens a create pane for the related model.
+	ForeignKey {
+		/// Registered name of the related model.
+		related_model: String,
+		/// Related records as `(primary key, label)` pairs.
+		choices: Vec<(String, String)>,
+		/// Related model field used to label new records, if any.
+		#[serde(default)]
+		label_field: Option<String>,
+		/// Whether the "add related" button is shown.
+		#[serde(default)]
+		can_add: bool,
+	},
 }
 
 impl From<&FieldType> for FormFieldSpec {
@@ -131,6 +310,17 @@ impl From<&FieldType> for FormFieldSpec {
 			},
 			FieldType::File => FormFieldSpec::File,
 			FieldType::Hidden => FormFieldSpec::Hidden,
+			FieldType::ForeignKey {
+				related_model,
+				choices,
+				label_field,
+				can_add,
+			} => FormFieldSpec::ForeignKey {
+				related_model: related_model.clone(),
+				choices: choices.clone(),
+				label_field: label_field.clone(),
+				can_add: *can_add,
+			},
 		}
 	}
 }
@@ -189,4 +379,22 @@ pub struct ColumnInfo {
 	pub label: String,
 	/// Whether column is sortable
 	pub sortable: bool,
+	/// Input type when the column is editable inline (`list_editable`)
+	///
+	/// `None` when the column is read-only for the current user.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub editable: Option<FieldType>,
+	/// `(value, label)` pairs used to display stored values by their label
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub choices: Vec<(String, String)>,
+}
+
+impl ColumnInfo {
+	/// Text shown for `value` in this column: its choice label, if any
+	pub fn display<'a>(&'a self, value: &'a str) -> &'a str {
+		self.choices
+			.iter()
+			.find(|(choice, _)| choice == value)
+			.map_or(value, |(_, label)| label.as_str())
+	}
 }
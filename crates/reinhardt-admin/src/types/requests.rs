@@ -123,6 +123,38 @@ pub struct BulkDeleteRequest {
 	pub ids: Vec<String>,
 }
 
+/// Request body for the personal data actions (export and anonymize)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserDataRequest {
+	/// CSRF token for mutation verification (double-submit cookie pattern).
+	pub csrf_token: String,
+	/// IDs of the selected users
+	pub ids: Vec<String>,
+}
+
+/// Changed values for one row of an inline-edited list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkUpdateRow {
+	/// Primary key of the record
+	pub id: String,
+	/// Field values to update
+	pub data: HashMap<String, serde_json::Value>,
+}
+
+/// Request body for saving inline list edits
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BulkUpdateRequest {
+	/// CSRF token for mutation verification (double-submit cookie pattern).
+	///
+	/// The client must send the CSRF token received from the dashboard response
+	/// in this field. The server validates this value against the `csrftoken`
+	/// cookie set by the dashboard endpoint. An attacker on a different origin
+	/// cannot read the cookie, preventing CSRF attacks.
+	pub csrf_token: String,
+	/// Rows to update
+	pub rows: Vec<BulkUpdateRow>,
+}
+
 /// Export format
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -1,6 +1,8 @@
 //! Response types for admin panel API
 
-use crate::types::models::{ColumnInfo, FilterInfo, ModelInfo};
+use crate::types::models::{
+	ApiModelInfo, ColumnInfo, DashboardWidget, FilterInfo, ModelInfo, NavSection, RecentAction,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -19,6 +21,29 @@ pub struct DashboardResponse {
 	pub logout_url: String,
 	/// Registered models with their metadata
 	pub models: Vec<ModelInfo>,
+	/// Sidebar sections visible to the current user
+	#[serde(default)]
+	pub navigation: Vec<NavSection>,
+	/// Latest admin actions on models visible to the current user
+	#[serde(default)]
+	pub recent_actions: Vec<RecentAction>,
+	/// Dashboard widgets in layout order
+	#[serde(default)]
+	pub widgets: Vec<DashboardWidget>,
+	/// CSRF token for mutation requests (POST, PUT, DELETE)
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub csrf_token: Option<String>,
+}
+
+/// Response for the headless JSON API index endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiIndexResponse {
+	/// Site name
+	pub site_name: String,
+	/// Header text shown in admin navigation bar
+	pub site_header: String,
+	/// Registered models visible to the current user
+	pub models: Vec<ApiModelInfo>,
 	/// CSRF token for mutation requests (POST, PUT, DELETE)
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub csrf_token: Option<String>,
@@ -54,6 +79,28 @@ pub struct DetailResponse {
 	pub model_name: String,
 	/// Item data
 	pub data: HashMap<String, serde_json::Value>,
+	/// Children attached through generic foreign keys, one entry per inline
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub inlines: Vec<GenericInlineRows>,
+	/// Download URLs of the binary fields that have content, keyed by field name
+	#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+	pub downloads: HashMap<String, String>,
+}
+
+/// Rows of one generic inline shown below a detail view
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenericInlineRows {
+	/// Admin name of the child model
+	pub model_name: String,
+	/// Heading shown above the rows
+	pub label: String,
+	/// Columns to display (empty = all columns of the rows)
+	pub fields: Vec<String>,
+	/// Child records
+	pub rows: Vec<HashMap<String, serde_json::Value>>,
+	/// Endpoint accepting files dropped on the inline, when uploads are enabled
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub upload_url: Option<String>,
 }
 
 /// Response for create/update/delete
@@ -71,6 +118,49 @@ pub struct MutationResponse {
 	pub data: Option<HashMap<String, serde_json::Value>>,
 }
 
+/// State of the edit lock on a record open in the change view
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditLockResponse {
+	/// Whether the current user holds the lock
+	pub acquired: bool,
+	/// Username of the user holding the lock, when it is someone else
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub locked_by: Option<String>,
+	/// When the lock lapses unless renewed (RFC 3339)
+	pub expires_at: String,
+	/// Seconds between heartbeats that keep the lock alive
+	pub heartbeat_secs: u64,
+}
+
+/// Response for the personal data export action
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserDataExportResponse {
+	/// Export archive of each selected user, keyed by user ID
+	pub exports: HashMap<String, serde_json::Value>,
+}
+
+/// Rows of one model expiring within the retention report window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetentionModelInfo {
+	/// `app_label.Model` key of the model
+	pub model: String,
+	/// What happens to expired rows: `"delete"` or `"anonymize"`
+	pub action: String,
+	/// Retention period of the model, in days
+	pub retain_days: u64,
+	/// Rows expiring within the window
+	pub rows: u64,
+}
+
+/// Response for the upcoming data retention report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetentionReportResponse {
+	/// Window of the report, in days
+	pub within_days: u32,
+	/// Models declaring a retention period
+	pub models: Vec<RetentionModelInfo>,
+}
+
 /// Response for bulk delete
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BulkDeleteResponse {
@@ -82,6 +172,20 @@ pub struct BulkDeleteResponse {
 	pub message: String,
 }
 
+/// Response for saving inline list edits
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkUpdateResponse {
+	/// Whether every row was saved
+	pub success: bool,
+	/// Number of updated rows
+	pub updated: u64,
+	/// Validation or database errors keyed by record ID
+	#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+	pub errors: HashMap<String, String>,
+	/// Message
+	pub message: String,
+}
+
 /// Response for import endpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImportResponse {
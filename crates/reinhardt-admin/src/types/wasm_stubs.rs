@@ -64,6 +64,11 @@ mod wasm_only {
 			"id"
 		}
 
+		/// Get the app label of the model.
+		fn app_label(&self) -> &str {
+			""
+		}
+
 		/// Fields to display in list view.
 		fn list_display(&self) -> Vec<&str> {
 			vec!["id"]
@@ -94,6 +99,21 @@ mod wasm_only {
 			vec!["-id"]
 		}
 
+		/// Fields sorted in natural order in list view.
+		fn natural_ordering_fields(&self) -> Vec<&str> {
+			vec![]
+		}
+
+		/// Fields editable inline in list view.
+		fn list_editable(&self) -> Vec<&str> {
+			vec![]
+		}
+
+		/// Fields holding rich text editor HTML.
+		fn rich_text_fields(&self) -> Vec<&str> {
+			vec![]
+		}
+
 		/// Number of items per page.
 		fn list_per_page(&self) -> Option<usize> {
 			None
@@ -131,6 +131,7 @@ fn test_model_form_create_mode() {
 		},
 		required: true,
 		value: String::new(),
+		language: None,
 	}];
 
 	let page = model_form("User", &fields, None);
@@ -155,6 +156,7 @@ fn test_model_form_edit_mode() {
 		},
 		required: true,
 		value: "john_doe".to_string(),
+		language: None,
 	}];
 
 	let page = model_form("User", &fields, Some("42"));
@@ -176,6 +178,7 @@ fn test_model_form_renders_textarea_for_text_area_spec() {
 		spec: FormFieldSpec::TextArea,
 		required: false,
 		value: "Hello world".to_string(),
+		language: None,
 	}];
 
 	let page = model_form("Profile", &fields, None);
@@ -204,6 +207,7 @@ fn test_model_form_renders_select_with_inline_options() {
 		},
 		required: true,
 		value: "active".to_string(),
+		language: None,
 	}];
 
 	let page = model_form("Account", &fields, None);
@@ -244,6 +248,7 @@ fn test_model_form_renders_multiselect_with_multiple_selections() {
 		// Multi-select wire format is comma-separated values; both `read`
 		// and `write` should end up marked selected.
 		value: "read,write".to_string(),
+		language: None,
 	}];
 
 	let page = model_form("Role", &fields, None);
@@ -277,11 +282,15 @@ fn test_list_view_renders_table_with_data() {
 				field: "id".to_string(),
 				label: "ID".to_string(),
 				sortable: true,
+				editable: None,
+				choices: vec![],
 			},
 			Column {
 				field: "name".to_string(),
 				label: "Name".to_string(),
 				sortable: true,
+				editable: None,
+				choices: vec![],
 			},
 		],
 		records: vec![record],
@@ -322,6 +331,7 @@ fn textarea_renders_as_textarea_element() {
 		spec: FormFieldSpec::TextArea,
 		required: false,
 		value: "hello world".to_string(),
+		language: None,
 	}];
 
 	// Act
@@ -355,6 +365,7 @@ fn textarea_required_renders_required_attr() {
 		spec: FormFieldSpec::TextArea,
 		required: true,
 		value: String::new(),
+		language: None,
 	}];
 
 	// Act
@@ -389,6 +400,7 @@ fn select_renders_options_with_selected_current_value() {
 		},
 		required: false,
 		value: "published".to_string(),
+		language: None,
 	}];
 
 	// Act
@@ -455,6 +467,7 @@ fn select_required_renders_required_attr() {
 		},
 		required: true,
 		value: String::new(),
+		language: None,
 	}];
 
 	// Act
@@ -488,6 +501,7 @@ fn multiselect_renders_as_select_with_multiple_attr() {
 		},
 		required: false,
 		value: String::new(),
+		language: None,
 	}];
 
 	// Act
@@ -526,6 +540,7 @@ fn multiselect_required_renders_required_attr() {
 		},
 		required: true,
 		value: String::new(),
+		language: None,
 	}];
 
 	// Act
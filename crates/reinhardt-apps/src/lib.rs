@@ -15,6 +15,7 @@
 //! - **[`ApplicationBuilder`]**: Builder pattern for fluent application construction
 //! - **Model Discovery**: Automatic model and migration discovery via [`discovery`] module
 //! - **Signals**: Application lifecycle signals via [`signals`] module
+//! - **Lifecycle Hooks**: Async startup and shutdown hooks via [`lifecycle`] module
 //! - **Validation**: Registry validation for circular dependencies and duplicates
 //!
 //! ## Modules
@@ -22,6 +23,7 @@
 //! - [`apps`]: Core [`AppConfig`] and [`Apps`] registry
 //! - [`builder`]: [`ApplicationBuilder`] for fluent application construction
 //! - [`discovery`]: Automatic model, migration, and relationship discovery
+//! - [`lifecycle`]: Startup and shutdown hooks and readiness
 //! - [`registry`]: Global model and relationship registry ([`MODELS`], [`RELATIONSHIPS`])
 //! - [`signals`]: Application lifecycle signals
 //! - [`validation`]: Registry validation utilities
@@ -92,7 +94,7 @@ pub mod signals;
 //
 // - `registry` uses `linkme::distributed_slice`, whose link-section
 //   constructors are not supported on `wasm32-unknown-unknown`.
-// - `builder`, `discovery`, `hooks`, `validation` depend on
+// - `builder`, `discovery`, `hooks`, `lifecycle`, `validation` depend on
 //   `reinhardt-server`, `reinhardt-conf`, `reinhardt-utils`, or
 //   `reinhardt-di`, none of which compile on wasm32 today (they pull in
 //   tokio's `net` feature → mio).
@@ -103,6 +105,8 @@ pub mod discovery;
 #[cfg(native)]
 pub mod hooks;
 #[cfg(native)]
+pub mod lifecycle;
+#[cfg(native)]
 pub mod registry;
 #[cfg(native)]
 pub mod validation;
@@ -155,6 +159,10 @@ pub use builder::{
 	RouteConfig,
 };
 
+// Re-export from lifecycle module (native-only).
+#[cfg(native)]
+pub use lifecycle::{Lifecycle, LifecycleError, lifecycle};
+
 // Re-export from registry module (native-only).
 #[cfg(native)]
 pub use registry::{
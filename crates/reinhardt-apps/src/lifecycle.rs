@@ -0,0 +1,407 @@
+//! Application startup and shutdown hooks
+//!
+//! Apps register async hooks that run when the server starts (warming
+//! caches, verifying migrations, connecting to brokers) and when it stops.
+//! Startup hooks run in `installed_apps` order, so an app's hooks run after
+//! those of the apps listed before it, and shutdown hooks run in reverse.
+//!
+//! [`Lifecycle::readiness_probe`] reports the service as unready until every
+//! startup hook has completed, and again once shutdown begins.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use reinhardt_apps::lifecycle::lifecycle;
+//!
+//! lifecycle().on_startup("catalog", "warm product cache", || async {
+//!     // Load the hot products into the cache
+//!     Ok(())
+//! });
+//! lifecycle().on_shutdown("catalog", "flush metrics", || async { Ok(()) });
+//! ```
+
+use std::error::Error;
+use std::future::Future;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use reinhardt_utils::staticfiles::health::{HealthCheck, HealthCheckResult};
+use tokio::sync::watch;
+
+/// Result of a lifecycle hook
+pub type HookResult = Result<(), Box<dyn Error + Send + Sync>>;
+
+type HookFn = Arc<dyn Fn() -> BoxFuture<'static, HookResult> + Send + Sync>;
+
+#[derive(Clone)]
+struct Hook {
+	app: String,
+	name: String,
+	run: HookFn,
+}
+
+/// Errors raised while running lifecycle hooks
+#[derive(Debug, thiserror::Error)]
+pub enum LifecycleError {
+	/// A hook is registered for an app missing from `installed_apps`
+	#[error("Lifecycle hook '{hook}' is registered for app '{app}', which is not installed")]
+	AppNotInstalled {
+		/// App label the hook was registered for
+		app: String,
+		/// Hook name
+		hook: String,
+	},
+
+	/// A startup hook failed
+	#[error("Startup hook '{hook}' of app '{app}' failed: {source}")]
+	StartupFailed {
+		/// App label
+		app: String,
+		/// Hook name
+		hook: String,
+		/// Error returned by the hook
+		source: Box<dyn Error + Send + Sync>,
+	},
+
+	/// A shutdown hook failed
+	#[error("Shutdown hook '{hook}' of app '{app}' failed: {source}")]
+	ShutdownFailed {
+		/// App label
+		app: String,
+		/// Hook name
+		hook: String,
+		/// Error returned by the hook
+		source: Box<dyn Error + Send + Sync>,
+	},
+}
+
+/// Registry and runner of startup and shutdown hooks
+pub struct Lifecycle {
+	startup: Mutex<Vec<Hook>>,
+	shutdown: Mutex<Vec<Hook>>,
+	/// Apps that were started, in `installed_apps` order
+	started: Mutex<Vec<String>>,
+	ready: watch::Sender<bool>,
+}
+
+impl Default for Lifecycle {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Lifecycle {
+	/// Create an empty lifecycle
+	///
+	/// Most applications use the global [`lifecycle()`] instead.
+	pub fn new() -> Self {
+		Self {
+			startup: Mutex::new(Vec::new()),
+			shutdown: Mutex::new(Vec::new()),
+			started: Mutex::new(Vec::new()),
+			ready: watch::Sender::new(false),
+		}
+	}
+
+	/// Register a hook run when the server starts
+	///
+	/// `app` is the label of the app the hook belongs to. Hooks of the same
+	/// app run in registration order.
+	pub fn on_startup<F, Fut>(&self, app: impl Into<String>, name: impl Into<String>, hook: F)
+	where
+		F: Fn() -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = HookResult> + Send + 'static,
+	{
+		Self::push(&self.startup, app.into(), name.into(), hook);
+	}
+
+	/// Register a hook run when the server stops
+	///
+	/// Hooks of the same app run in registration order.
+	pub fn on_shutdown<F, Fut>(&self, app: impl Into<String>, name: impl Into<String>, hook: F)
+	where
+		F: Fn() -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = HookResult> + Send + 'static,
+	{
+		Self::push(&self.shutdown, app.into(), name.into(), hook);
+	}
+
+	fn push<F, Fut>(hooks: &Mutex<Vec<Hook>>, app: String, name: String, hook: F)
+	where
+		F: Fn() -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = HookResult> + Send + 'static,
+	{
+		let run: HookFn = Arc::new(move || -> BoxFuture<'static, HookResult> { Box::pin(hook()) });
+		hooks
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.push(Hook { app, name, run });
+	}
+
+	/// Run the startup hooks in `installed_apps` order and mark the service ready
+	///
+	/// Entries of `installed_apps` match hooks by full name or by their last
+	/// path segment, so `"myproject.apps.catalog"` runs the hooks of
+	/// `"catalog"`. If a hook fails, the shutdown hooks of the apps that
+	/// already started are run before the error is returned.
+	pub async fn startup(&self, installed_apps: &[String]) -> Result<(), LifecycleError> {
+		let hooks = ordered(&snapshot(&self.startup), installed_apps)?;
+		ordered(&snapshot(&self.shutdown), installed_apps)?;
+
+		for (position, hook) in hooks {
+			if let Err(source) = (hook.run)().await {
+				// The failing app may have acquired resources in its earlier
+				// hooks, so its shutdown hooks run too.
+				*self.started.lock().unwrap_or_else(PoisonError::into_inner) =
+					installed_apps[..=position].to_vec();
+				let _ = self.run_shutdown().await;
+				return Err(LifecycleError::StartupFailed {
+					app: hook.app,
+					hook: hook.name,
+					source,
+				});
+			}
+		}
+		*self.started.lock().unwrap_or_else(PoisonError::into_inner) = installed_apps.to_vec();
+
+		self.ready.send_replace(true);
+		Ok(())
+	}
+
+	/// Mark the service unready and run the shutdown hooks in reverse app order
+	///
+	/// Only the hooks of apps that were started run. Every hook runs even if
+	/// an earlier one fails; the failures are returned.
+	pub async fn shutdown(&self) -> Vec<LifecycleError> {
+		self.ready.send_replace(false);
+		self.run_shutdown().await
+	}
+
+	async fn run_shutdown(&self) -> Vec<LifecycleError> {
+		let started =
+			std::mem::take(&mut *self.started.lock().unwrap_or_else(PoisonError::into_inner));
+		let mut hooks: Vec<_> = snapshot(&self.shutdown)
+			.into_iter()
+			.filter_map(|hook| Some((app_position(&started, &hook.app)?, hook)))
+			.collect();
+		hooks.sort_by_key(|(position, _)| *position);
+		let mut errors = Vec::new();
+		for (_, hook) in hooks.into_iter().rev() {
+			if let Err(source) = (hook.run)().await {
+				errors.push(LifecycleError::ShutdownFailed {
+					app: hook.app,
+					hook: hook.name,
+					source,
+				});
+			}
+		}
+		errors
+	}
+
+	/// Whether every startup hook has completed and shutdown has not begun
+	pub fn is_ready(&self) -> bool {
+		*self.ready.borrow()
+	}
+
+	/// Wait until the service is ready
+	pub async fn wait_ready(&self) {
+		let mut ready = self.ready.subscribe();
+		let _ = ready.wait_for(|ready| *ready).await;
+	}
+
+	/// Subscribe to readiness changes
+	pub fn subscribe(&self) -> watch::Receiver<bool> {
+		self.ready.subscribe()
+	}
+
+	/// Readiness probe reporting `unhealthy` until startup completes
+	///
+	/// Register it with `HealthProbes::readiness` so `/readyz` fails while
+	/// the service starts and shuts down.
+	pub fn readiness_probe(&self) -> ReadinessProbe {
+		ReadinessProbe {
+			ready: self.ready.subscribe(),
+		}
+	}
+}
+
+/// Health check following the readiness of a [`Lifecycle`]
+pub struct ReadinessProbe {
+	ready: watch::Receiver<bool>,
+}
+
+#[async_trait]
+impl HealthCheck for ReadinessProbe {
+	async fn check(&self) -> HealthCheckResult {
+		if *self.ready.borrow() {
+			HealthCheckResult::healthy("startup")
+		} else {
+			HealthCheckResult::unhealthy("startup", "startup hooks have not completed")
+		}
+	}
+}
+
+static LIFECYCLE: OnceLock<Lifecycle> = OnceLock::new();
+
+/// The global lifecycle, run by `runserver`
+pub fn lifecycle() -> &'static Lifecycle {
+	LIFECYCLE.get_or_init(Lifecycle::new)
+}
+
+fn snapshot(hooks: &Mutex<Vec<Hook>>) -> Vec<Hook> {
+	hooks.lock().unwrap_or_else(PoisonError::into_inner).clone()
+}
+
+/// Label of an `installed_apps` entry: its last path segment
+fn app_label(app: &str) -> &str {
+	app.rsplit(['.', ':']).next().unwrap_or(app)
+}
+
+/// Position of `app` in `installed_apps`
+fn app_position(installed_apps: &[String], app: &str) -> Option<usize> {
+	installed_apps
+		.iter()
+		.position(|installed| installed == app || app_label(installed) == app_label(app))
+}
+
+/// Pair `hooks` with the position of their app, sorted by it
+fn ordered(
+	hooks: &[Hook],
+	installed_apps: &[String],
+) -> Result<Vec<(usize, Hook)>, LifecycleError> {
+	let mut positioned = Vec::with_capacity(hooks.len());
+	for hook in hooks {
+		let position = app_position(installed_apps, &hook.app).ok_or_else(|| {
+			LifecycleError::AppNotInstalled {
+				app: hook.app.clone(),
+				hook: hook.name.clone(),
+			}
+		})?;
+		positioned.push((position, hook.clone()));
+	}
+	// Stable sort keeps the registration order within an app
+	positioned.sort_by_key(|(position, _)| *position);
+	Ok(positioned)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	type Log = Arc<Mutex<Vec<String>>>;
+
+	fn record(lifecycle: &Lifecycle, log: &Log, app: &str, startup: bool) {
+		let log = Arc::clone(log);
+		let entry = format!("{} {app}", if startup { "start" } else { "stop" });
+		let hook = move || {
+			let log = Arc::clone(&log);
+			let entry = entry.clone();
+			async move {
+				log.lock().unwrap().push(entry);
+				Ok(())
+			}
+		};
+		if startup {
+			lifecycle.on_startup(app, "record", hook);
+		} else {
+			lifecycle.on_shutdown(app, "record", hook);
+		}
+	}
+
+	fn installed(apps: &[&str]) -> Vec<String> {
+		apps.iter().map(|app| app.to_string()).collect()
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_hooks_run_in_installed_apps_order() {
+		// Arrange
+		let lifecycle = Lifecycle::new();
+		let log = Log::default();
+		record(&lifecycle, &log, "orders", true);
+		record(&lifecycle, &log, "auth", true);
+		record(&lifecycle, &log, "orders", false);
+		record(&lifecycle, &log, "auth", false);
+
+		// Act
+		lifecycle
+			.startup(&installed(&["project.apps.auth", "project.apps.orders"]))
+			.await
+			.unwrap();
+		let ready = lifecycle.is_ready();
+		let errors = lifecycle.shutdown().await;
+
+		// Assert
+		assert!(ready);
+		assert!(errors.is_empty());
+		assert!(!lifecycle.is_ready());
+		assert_eq!(
+			*log.lock().unwrap(),
+			["start auth", "start orders", "stop orders", "stop auth"]
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_failed_startup_shuts_down_started_apps() {
+		// Arrange
+		let lifecycle = Lifecycle::new();
+		let log = Log::default();
+		record(&lifecycle, &log, "auth", true);
+		lifecycle.on_startup("orders", "connect broker", || async {
+			Err("broker unreachable".into())
+		});
+		record(&lifecycle, &log, "auth", false);
+		record(&lifecycle, &log, "billing", false);
+
+		// Act
+		let result = lifecycle
+			.startup(&installed(&["auth", "orders", "billing"]))
+			.await;
+
+		// Assert
+		assert!(matches!(
+			result,
+			Err(LifecycleError::StartupFailed { ref hook, .. }) if hook == "connect broker"
+		));
+		assert!(!lifecycle.is_ready());
+		assert_eq!(*log.lock().unwrap(), ["start auth", "stop auth"]);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_hook_for_unknown_app_is_rejected() {
+		// Arrange
+		let lifecycle = Lifecycle::new();
+		lifecycle.on_startup("search", "build index", || async { Ok(()) });
+
+		// Act
+		let result = lifecycle.startup(&installed(&["auth"])).await;
+
+		// Assert
+		assert!(matches!(
+			result,
+			Err(LifecycleError::AppNotInstalled { .. })
+		));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_readiness_probe_follows_startup() {
+		// Arrange
+		let lifecycle = Lifecycle::new();
+		let probe = lifecycle.readiness_probe();
+
+		// Act
+		let before = probe.check().await;
+		lifecycle.startup(&[]).await.unwrap();
+		lifecycle.wait_ready().await;
+		let after = probe.check().await;
+
+		// Assert
+		assert!(before.is_unhealthy());
+		assert!(after.is_healthy());
+	}
+}
@@ -0,0 +1,93 @@
+//! Audit actor middleware
+//!
+//! Attributes ORM changes made while handling a request to the authenticated
+//! user, so that entries recorded for `#[model(audited)]` models carry the
+//! acting user.
+
+use async_trait::async_trait;
+use reinhardt_core::exception::Result;
+use reinhardt_db::orm::audit::with_actor;
+use reinhardt_http::{AuthState, Handler, Middleware, Request, Response};
+use std::sync::Arc;
+
+/// Middleware recording the authenticated user as the audit actor
+///
+/// Reads the [`AuthState`] placed in the request extensions by the
+/// authentication middleware and runs the rest of the chain inside
+/// [`with_actor`]. It must therefore be registered after the authentication
+/// middleware. Anonymous requests run with no actor.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_auth::audit_actor::AuditActorMiddleware;
+///
+/// let middleware = AuditActorMiddleware::new();
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AuditActorMiddleware;
+
+impl AuditActorMiddleware {
+	/// Create the middleware
+	pub fn new() -> Self {
+		Self
+	}
+}
+
+#[async_trait]
+impl Middleware for AuditActorMiddleware {
+	async fn process(&self, request: Request, next: Arc<dyn Handler>) -> Result<Response> {
+		let actor = AuthState::from_extensions(&request.extensions)
+			.filter(AuthState::is_authenticated)
+			.map(|state| state.user_id().to_string());
+		with_actor(actor, next.handle(request)).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bytes::Bytes;
+	use hyper::{Method, StatusCode};
+	use reinhardt_db::orm::audit::current_actor;
+	use rstest::rstest;
+
+	struct ActorEcho;
+
+	#[async_trait]
+	impl Handler for ActorEcho {
+		async fn handle(&self, _request: Request) -> Result<Response> {
+			let actor = current_actor().unwrap_or_else(|| "none".to_string());
+			Ok(Response::new(StatusCode::OK).with_body(Bytes::from(actor)))
+		}
+	}
+
+	fn request(auth_state: AuthState) -> Request {
+		let request = Request::builder()
+			.method(Method::POST)
+			.uri("/articles")
+			.body(Bytes::new())
+			.build()
+			.unwrap();
+		request.extensions.insert(auth_state);
+		request
+	}
+
+	#[rstest]
+	#[case::authenticated(AuthState::authenticated("42", false, true), "42")]
+	#[case::anonymous(AuthState::anonymous(), "none")]
+	#[tokio::test]
+	async fn actor_follows_auth_state(#[case] auth_state: AuthState, #[case] expected: &str) {
+		// Arrange
+		let middleware = AuditActorMiddleware::new();
+
+		// Act
+		let response = middleware
+			.process(request(auth_state), Arc::new(ActorEcho))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.body, Bytes::from(expected.to_string()));
+	}
+}
@@ -3,6 +3,8 @@
 //! Does NOT perform a database query. Use [`CurrentUser`](crate::CurrentUser) when the full
 //! user model object is needed.
 
+#[cfg(feature = "params")]
+use crate::lazy_user::resolve_auth_state;
 use async_trait::async_trait;
 use reinhardt_di::{DiError, DiResult, Injectable, InjectionContext};
 use reinhardt_http::AuthState;
@@ -50,13 +52,16 @@ impl Injectable for AuthInfo {
 			)
 		})?;
 
-		let auth_state: AuthState = request.extensions.get().ok_or_else(|| {
-			DiError::NotFound(
-				"AuthInfo: No AuthState found in request extensions. \
-				 Ensure authentication middleware is configured."
-					.to_string(),
-			)
-		})?;
+		let auth_state: AuthState =
+			resolve_auth_state(&request.extensions)
+				.await
+				.ok_or_else(|| {
+					DiError::NotFound(
+						"AuthInfo: No AuthState found in request extensions. \
+					 Ensure authentication middleware is configured."
+							.to_string(),
+					)
+				})?;
 
 		if !auth_state.is_authenticated() {
 			return Err(DiError::Authentication(
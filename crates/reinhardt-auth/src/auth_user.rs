@@ -4,10 +4,16 @@
 //! with `Path`, `Json`, and other Reinhardt extractors.
 
 use crate::BaseUser;
+#[cfg(feature = "params")]
+use crate::lazy_user::resolve_auth_state;
 use async_trait::async_trait;
 use reinhardt_db::orm::{CustomManager, DatabaseConnection, Model};
+#[cfg(feature = "params")]
+use reinhardt_di::params::{ParamContext, ParamError, ParamResult, extract::FromRequest};
 use reinhardt_di::{DiError, DiResult, Injectable, InjectionContext};
 use reinhardt_http::AuthState;
+#[cfg(feature = "params")]
+use reinhardt_http::Request;
 use std::sync::Arc;
 
 /// Authenticated user extractor that loads the full user model from database.
@@ -25,17 +31,22 @@ use std::sync::Arc;
 ///
 /// #[get("/profile/")]
 /// pub async fn profile(
-///     #[inject] CurrentUser(user): CurrentUser<DefaultUser>,
+///     CurrentUser(user): CurrentUser<DefaultUser>,
 /// ) -> ViewResult<Response> {
 ///     let username = user.get_username();
 ///     // ...
 /// }
 /// ```
 ///
+/// The extractor works both as a plain handler parameter (through
+/// `FromRequest`) and as an `#[inject]` parameter. The loaded user is cached
+/// in the request extensions, so extracting it again later in the same
+/// request does not repeat the query.
+///
 /// # Failure
 ///
 /// Returns an injection error when:
-/// - No `AuthState` in request extensions (HTTP 401)
+/// - No `AuthState` or `LazyUser` in request extensions (HTTP 401)
 /// - `user_id` parse failure (HTTP 401, not nil UUID fallback)
 /// - `DatabaseConnection` not registered in DI (HTTP 503)
 /// - Database query failure (HTTP 500)
@@ -55,10 +66,18 @@ where
 		DiError::NotFound("CurrentUser: No HTTP request available in InjectionContext".to_string())
 	})?;
 
-	// Get AuthState from request extensions.
-	let auth_state: AuthState = request.extensions.get().ok_or_else(|| {
-		DiError::NotFound("CurrentUser: No AuthState found in request extensions".to_string())
-	})?;
+	// Reuse a user already loaded earlier in this request.
+	if let Some(CurrentUser(user)) = request.extensions.get::<CurrentUser<U>>() {
+		return Ok(user);
+	}
+
+	// Get AuthState from request extensions, forcing a LazyUser if the
+	// middleware deferred the lookup.
+	let auth_state: AuthState = resolve_auth_state(&request.extensions)
+		.await
+		.ok_or_else(|| {
+			DiError::NotFound("CurrentUser: No AuthState found in request extensions".to_string())
+		})?;
 
 	if !auth_state.is_authenticated() {
 		return Err(DiError::Authentication(
@@ -95,7 +114,7 @@ where
 			}
 		})?;
 
-	U::objects()
+	let user = U::objects()
 		.get(model_pk)
 		.first_with_db(&db)
 		.await
@@ -111,7 +130,10 @@ where
 				"CurrentUser: User not found in database"
 			);
 			DiError::NotFound("CurrentUser: User not found".to_string())
-		})
+		})?;
+
+	request.extensions.insert(CurrentUser(user.clone()));
+	Ok(user)
 }
 
 #[cfg(feature = "params")]
@@ -128,6 +150,40 @@ where
 	}
 }
 
+#[cfg(feature = "params")]
+#[async_trait]
+impl<U> FromRequest for CurrentUser<U>
+where
+	U: BaseUser + Model + Clone + Send + Sync + 'static,
+	<U as BaseUser>::PrimaryKey: std::str::FromStr + ToString + Send + Sync,
+	<<U as BaseUser>::PrimaryKey as std::str::FromStr>::Err: std::fmt::Debug,
+	<U as Model>::PrimaryKey: From<<U as BaseUser>::PrimaryKey>,
+{
+	async fn from_request(req: &Request, _ctx: &ParamContext) -> ParamResult<Self> {
+		// A missing DI context is a router misconfiguration, not an
+		// unauthenticated request, so it surfaces as HTTP 500.
+		let shared_ctx = req
+			.get_di_context::<Arc<InjectionContext>>()
+			.ok_or_else(|| {
+				ParamError::Internal(
+					"CurrentUser: DI context not available on the request. \
+					 Ensure the router is configured with `.with_di_context()`."
+						.to_string(),
+				)
+			})?;
+		let di_ctx = shared_ctx.fork_for_request(req.clone_for_di());
+		resolve_current_user(&di_ctx)
+			.await
+			.map(CurrentUser)
+			.map_err(|err| match err {
+				DiError::Authentication(msg) | DiError::NotFound(msg) => {
+					ParamError::Authentication(msg)
+				}
+				other => ParamError::Internal(other.to_string()),
+			})
+	}
+}
+
 #[cfg(not(feature = "params"))]
 #[async_trait]
 impl<U> Injectable for CurrentUser<U>
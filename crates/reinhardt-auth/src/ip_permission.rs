@@ -155,6 +155,12 @@ impl IpWhitelistPermission {
 	}
 
 	fn extract_client_ip(&self, context: &PermissionContext) -> Option<IpAddr> {
+		// Without a proxy list of its own, use the request-wide client IP
+		// resolution so this permission agrees with throttling and logging
+		if self.trusted_proxies.is_empty() {
+			return context.request.client_ip();
+		}
+
 		// Only trust forwarding headers if the direct connection is from a trusted proxy
 		if self.is_trusted_proxy(context) {
 			// Try X-Forwarded-For header first
@@ -348,6 +354,12 @@ impl IpBlacklistPermission {
 	}
 
 	fn extract_client_ip(&self, context: &PermissionContext) -> Option<IpAddr> {
+		// Without a proxy list of its own, use the request-wide client IP
+		// resolution so this permission agrees with throttling and logging
+		if self.trusted_proxies.is_empty() {
+			return context.request.client_ip();
+		}
+
 		// Only trust forwarding headers if the direct connection is from a trusted proxy
 		if self.is_trusted_proxy(context) {
 			// Try X-Forwarded-For header first
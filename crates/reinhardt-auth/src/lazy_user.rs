@@ -0,0 +1,309 @@
+//! Request-scoped handle that loads the current user on first access.
+//!
+//! Authentication middleware running in lazy mode stores a [`LazyUser`] in
+//! the request extensions instead of resolving the user up front. The
+//! session/database lookup only happens when a handler, guard or extractor
+//! first asks for the user, and the result is cached for the rest of the
+//! request, so requests that never look at the user never pay for the query.
+
+use crate::AuthIdentity;
+use async_trait::async_trait;
+#[cfg(feature = "params")]
+use reinhardt_di::params::{ParamContext, ParamError, ParamResult, extract::FromRequest};
+use reinhardt_di::{DiError, DiResult, Injectable, InjectionContext};
+use reinhardt_http::AuthState;
+#[cfg(feature = "params")]
+use reinhardt_http::Request;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use tokio::sync::OnceCell;
+
+type UserLoader = Pin<Box<dyn Future<Output = Option<Box<dyn AuthIdentity>>> + Send>>;
+
+struct LazyUserInner {
+	loader: Mutex<Option<UserLoader>>,
+	user: OnceCell<Option<Arc<dyn AuthIdentity>>>,
+}
+
+/// Deferred, cached lookup of the user making the current request.
+///
+/// Cloning the handle is cheap and every clone shares the same cache, so the
+/// loader runs at most once per request no matter how many middleware,
+/// guards or extractors ask for the user.
+///
+/// This is the identity-level counterpart of [`CurrentUser`](crate::CurrentUser):
+/// `LazyUser` yields the [`AuthIdentity`] returned by the authentication
+/// backend, while `CurrentUser<U>` loads the concrete user model and itself
+/// falls back to a `LazyUser` when the middleware did not resolve an
+/// [`AuthState`] eagerly.
+///
+/// # Usage
+///
+/// ```rust,ignore
+/// use reinhardt_auth::LazyUser;
+///
+/// #[get("/")]
+/// pub async fn index(user: LazyUser) -> ViewResult<Response> {
+///     // No query has been made yet; anonymous visitors that never reach
+///     // this branch never trigger one.
+///     if show_greeting {
+///         if let Some(user) = user.get().await {
+///             // ...
+///         }
+///     }
+///     // ...
+/// }
+/// ```
+#[derive(Clone)]
+pub struct LazyUser {
+	inner: Arc<LazyUserInner>,
+}
+
+impl LazyUser {
+	/// Creates a handle that runs `loader` the first time the user is requested.
+	pub fn new<F>(loader: F) -> Self
+	where
+		F: Future<Output = Option<Box<dyn AuthIdentity>>> + Send + 'static,
+	{
+		Self {
+			inner: Arc::new(LazyUserInner {
+				loader: Mutex::new(Some(Box::pin(loader))),
+				user: OnceCell::new(),
+			}),
+		}
+	}
+
+	/// Creates a handle for a user that has already been loaded.
+	pub fn resolved(user: Option<Box<dyn AuthIdentity>>) -> Self {
+		Self {
+			inner: Arc::new(LazyUserInner {
+				loader: Mutex::new(None),
+				user: OnceCell::new_with(Some(user.map(Arc::from))),
+			}),
+		}
+	}
+
+	/// Creates a handle for an anonymous request, which never runs a lookup.
+	pub fn anonymous() -> Self {
+		Self::resolved(None)
+	}
+
+	/// Returns the current user, running the loader on first access.
+	///
+	/// Returns `None` for anonymous requests and when the lookup fails.
+	pub async fn get(&self) -> Option<Arc<dyn AuthIdentity>> {
+		self.inner
+			.user
+			.get_or_init(|| async {
+				let loader = self
+					.inner
+					.loader
+					.lock()
+					.unwrap_or_else(|e| e.into_inner())
+					.take();
+				match loader {
+					Some(loader) => loader.await.map(Arc::from),
+					None => None,
+				}
+			})
+			.await
+			.clone()
+	}
+
+	/// Returns the [`AuthState`] for the current user, loading it if needed.
+	pub async fn auth_state(&self) -> AuthState {
+		match self.get().await {
+			Some(user) if user.is_authenticated() => {
+				AuthState::authenticated(user.id(), user.is_admin(), user.is_account_active())
+			}
+			_ => AuthState::anonymous(),
+		}
+	}
+
+	/// Returns `true` once the user has been looked up.
+	pub fn is_loaded(&self) -> bool {
+		self.inner.user.initialized()
+	}
+}
+
+impl fmt::Debug for LazyUser {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let user = self
+			.inner
+			.user
+			.get()
+			.map(|user| user.as_ref().map(|user| user.id()));
+		f.debug_struct("LazyUser")
+			.field("loaded", &user.is_some())
+			.field("user_id", &user.flatten())
+			.finish()
+	}
+}
+
+/// Resolves the [`AuthState`] of a request.
+///
+/// Prefers an `AuthState` stored by eager middleware and otherwise forces the
+/// request's [`LazyUser`], caching the resulting `AuthState` in the request
+/// extensions so later lookups do not repeat the work.
+pub async fn resolve_auth_state(extensions: &reinhardt_http::Extensions) -> Option<AuthState> {
+	if let Some(state) = extensions.get::<AuthState>() {
+		return Some(state);
+	}
+	let lazy = extensions.get::<LazyUser>()?;
+	let state = lazy.auth_state().await;
+	extensions.insert(state.clone());
+	Some(state)
+}
+
+#[cfg(feature = "params")]
+#[async_trait]
+impl Injectable for LazyUser {
+	async fn inject(ctx: &InjectionContext) -> DiResult<Self> {
+		let request = ctx.get_http_request().ok_or_else(|| {
+			DiError::NotFound(
+				"LazyUser: No HTTP request available in InjectionContext. \
+				 Ensure the router is configured with .with_di_context()"
+					.to_string(),
+			)
+		})?;
+
+		request.extensions.get::<LazyUser>().ok_or_else(|| {
+			DiError::NotFound(
+				"LazyUser: No LazyUser found in request extensions. \
+				 Ensure authentication middleware is configured."
+					.to_string(),
+			)
+		})
+	}
+}
+
+#[cfg(feature = "params")]
+#[async_trait]
+impl FromRequest for LazyUser {
+	async fn from_request(req: &Request, _ctx: &ParamContext) -> ParamResult<Self> {
+		// Missing middleware is a configuration error, not an anonymous
+		// request, so it surfaces as HTTP 500.
+		req.extensions.get::<LazyUser>().ok_or_else(|| {
+			ParamError::Internal(
+				"LazyUser: No LazyUser found in request extensions. \
+				 Ensure authentication middleware is configured."
+					.to_string(),
+			)
+		})
+	}
+}
+
+#[cfg(not(feature = "params"))]
+#[async_trait]
+impl Injectable for LazyUser {
+	async fn inject(_ctx: &InjectionContext) -> DiResult<Self> {
+		Err(DiError::NotFound(
+			"LazyUser requires the 'params' feature to be enabled".to_string(),
+		))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use std::sync::atomic::{AtomicUsize, Ordering};
+
+	struct TestIdentity;
+
+	impl AuthIdentity for TestIdentity {
+		fn id(&self) -> String {
+			"42".to_string()
+		}
+
+		fn is_authenticated(&self) -> bool {
+			true
+		}
+
+		fn is_admin(&self) -> bool {
+			false
+		}
+
+		fn is_account_active(&self) -> bool {
+			true
+		}
+	}
+
+	fn counting_user(calls: Arc<AtomicUsize>) -> LazyUser {
+		LazyUser::new(async move {
+			calls.fetch_add(1, Ordering::SeqCst);
+			Some(Box::new(TestIdentity) as Box<dyn AuthIdentity>)
+		})
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_loader_runs_once_across_clones() {
+		// Arrange
+		let calls = Arc::new(AtomicUsize::new(0));
+		let user = counting_user(calls.clone());
+		let clone = user.clone();
+
+		// Act
+		let first = user.get().await;
+		let second = clone.get().await;
+
+		// Assert
+		assert_eq!(first.unwrap().id(), "42");
+		assert_eq!(second.unwrap().id(), "42");
+		assert_eq!(calls.load(Ordering::SeqCst), 1);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_loader_deferred_until_first_access() {
+		// Arrange
+		let calls = Arc::new(AtomicUsize::new(0));
+		let extensions = reinhardt_http::Extensions::new();
+		extensions.insert(counting_user(calls.clone()));
+
+		// Act
+		let stored = extensions.get::<LazyUser>().unwrap();
+		let loaded_before = stored.is_loaded();
+		let state = resolve_auth_state(&extensions).await;
+
+		// Assert
+		assert!(!loaded_before);
+		assert_eq!(calls.load(Ordering::SeqCst), 1);
+		assert_eq!(state, Some(AuthState::authenticated("42", false, true)));
+		assert_eq!(extensions.get::<AuthState>(), state);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_anonymous_user_resolves_to_anonymous_state() {
+		// Arrange
+		let user = LazyUser::anonymous();
+
+		// Act
+		let state = user.auth_state().await;
+
+		// Assert
+		assert!(user.is_loaded());
+		assert!(state.is_anonymous());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_resolve_auth_state_prefers_eager_state() {
+		// Arrange
+		let calls = Arc::new(AtomicUsize::new(0));
+		let extensions = reinhardt_http::Extensions::new();
+		extensions.insert(AuthState::authenticated("7", true, true));
+		extensions.insert(counting_user(calls.clone()));
+
+		// Act
+		let state = resolve_auth_state(&extensions).await;
+
+		// Assert
+		assert_eq!(state, Some(AuthState::authenticated("7", true, true)));
+		assert_eq!(calls.load(Ordering::SeqCst), 0);
+	}
+}
@@ -46,6 +46,7 @@
 //! | `token` | disabled | Token-based authentication |
 //! | `argon2-hasher` | disabled | Argon2 password hashing (alternative to bcrypt) |
 //! | `social` | disabled | Social authentication (OAuth2/OIDC providers) |
+//! | `oidc-provider` | disabled | OpenID Connect provider for first-party clients |
 //! | `database` | disabled | Database-backed user/group storage via ORM |
 //!
 //! ## Security Note: Client-Side vs Server-Side Checks
@@ -79,6 +80,10 @@ pub use reinhardt_auth_macros::guard;
 pub mod auth_user;
 pub use auth_user::CurrentUser;
 
+// Lazily loaded, request-cached user handle
+pub mod lazy_user;
+pub use lazy_user::{LazyUser, resolve_auth_state};
+
 // Startup validation for auth extractors
 pub mod auth_extractors;
 pub use auth_extractors::validate_auth_extractors;
@@ -114,6 +119,8 @@ pub use repository::{SimpleUserRepository, UserRepository};
 
 /// Advanced permission classes (role-based, object-level).
 pub mod advanced_permissions;
+/// Audit actor middleware for ORM change capture.
+pub mod audit_actor;
 /// Base user manager trait for CRUD operations.
 pub mod base_user_manager;
 /// HTTP Basic authentication backend.
@@ -139,9 +146,15 @@ pub mod model_permissions;
 pub mod oauth2;
 /// Object-level permission checking.
 pub mod object_permissions;
+/// OpenID Connect provider (authorization code + PKCE, JWKS, consent).
+#[cfg(feature = "oidc-provider")]
+pub mod oidc_provider;
 /// Database-backed permission model.
 #[cfg(feature = "database")]
 pub mod permission;
+/// Content types and default permissions synced after migrations.
+#[cfg(feature = "database")]
+pub mod permission_catalog;
 /// Rate-limiting permission class.
 #[cfg(feature = "rate-limit")]
 pub mod rate_limit_permission;
@@ -155,6 +168,8 @@ pub mod session;
 /// Social authentication providers (Google, GitHub, Apple, Microsoft).
 #[cfg(feature = "social")]
 pub mod social;
+/// Tenant middleware for row-level multi-tenancy.
+pub mod tenancy;
 /// Time-based permission class (time windows, date ranges).
 pub mod time_based_permission;
 /// Token blacklist for revocation.
@@ -173,6 +188,7 @@ pub mod user_management;
 pub mod settings;
 
 pub use advanced_permissions::{ObjectPermission as AdvancedObjectPermission, RoleBasedPermission};
+pub use audit_actor::AuditActorMiddleware;
 pub use base_user_manager::BaseUserManager;
 #[cfg_attr(docsrs, doc(cfg(feature = "basic")))]
 #[cfg(feature = "basic")]
@@ -198,6 +214,8 @@ pub use oauth2::{
 pub use object_permissions::{ObjectPermission, ObjectPermissionChecker, ObjectPermissionManager};
 #[cfg(feature = "database")]
 pub use permission::AuthPermission;
+#[cfg(feature = "database")]
+pub use permission_catalog::{CatalogPlan, PermissionCatalog};
 pub use permission_operators::{AndPermission, NotPermission, OrPermission};
 // Re-export the error type used by `BaseUserManager` so downstream code (and the
 // `#[user]` macro's auto-generated manager impl) can reference it without
@@ -217,6 +235,7 @@ pub use social::{
 	MicrosoftProvider, OAuthProvider, OAuthToken, PkceFlow, ProviderConfig, SocialAuthBackend,
 	SocialAuthError, StandardClaims, StateStore, TokenResponse, UserInfoMapper,
 };
+pub use tenancy::TenantMiddleware;
 
 #[cfg(feature = "rate-limit")]
 pub use rate_limit_permission::{RateLimitPermission, RateLimitPermissionBuilder};
@@ -0,0 +1,122 @@
+//! OpenID Connect provider
+//!
+//! Lets an application act as the identity provider of first-party SPAs and
+//! satellite services: registered clients obtain ID and access tokens with
+//! the authorization code flow (with PKCE), and verify them with the keys
+//! published at the JWKS endpoint.
+//!
+//! - [`clients`]: client registration, public (PKCE) and confidential clients
+//! - [`keys`]: rotating ES256 signing keys and the JWKS document
+//! - [`claims`]: ID token claims mapped from the user, filtered by scope
+//! - [`provider`]: authorization, consent, token, userinfo and discovery
+//!
+//! ## Example
+//!
+//! ```ignore
+//! use reinhardt_auth::oidc_provider::{OidcClient, OidcProvider, OidcProviderConfig, UserClaims};
+//!
+//! let provider = OidcProvider::new(OidcProviderConfig::new("https://id.example.com"));
+//! provider
+//!     .register_client(
+//!         OidcClient::public("dashboard", "Dashboard")
+//!             .with_redirect_uri("https://dashboard.example.com/callback"),
+//!     )
+//!     .await?;
+//!
+//! // GET /oauth/authorize, once the user is logged in
+//! let request: AuthorizationRequest = serde_urlencoded::from_str(query)?;
+//! match provider.authorize(&request, &UserClaims::from_user(user.id.to_string(), &user)).await? {
+//!     Authorization::Redirect(url) => Response::temporary_redirect(url),
+//!     Authorization::ConsentRequired(screen) => render_consent(screen),
+//! }
+//! ```
+
+pub mod claims;
+pub mod clients;
+pub mod keys;
+pub mod provider;
+
+pub use claims::UserClaims;
+pub use clients::{ClientStore, InMemoryClientStore, OidcClient};
+pub use keys::{KeyRing, SigningKey};
+pub use provider::{
+	AccessTokenClaims, Authorization, AuthorizationRequest, ConsentScreen, OidcProvider,
+	OidcProviderConfig, OidcTokenResponse, ScopeDescription, TokenRequest,
+};
+
+use thiserror::Error;
+
+/// OAuth 2.0 / OpenID Connect error, named after its `error` code
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum OidcError {
+	/// The request is missing a parameter or is otherwise malformed.
+	#[error("invalid_request: {0}")]
+	InvalidRequest(String),
+	/// The client is unknown or failed to authenticate.
+	#[error("invalid_client: {0}")]
+	InvalidClient(String),
+	/// The authorization code is invalid, expired or was issued to another
+	/// client, or the PKCE verifier does not match.
+	#[error("invalid_grant: {0}")]
+	InvalidGrant(String),
+	/// The grant type is not supported.
+	#[error("unsupported_grant_type: {0}")]
+	UnsupportedGrantType(String),
+	/// The response type is not supported.
+	#[error("unsupported_response_type: {0}")]
+	UnsupportedResponseType(String),
+	/// A requested scope is not allowed for the client.
+	#[error("invalid_scope: {0}")]
+	InvalidScope(String),
+	/// The user denied the authorization request.
+	#[error("access_denied: {0}")]
+	AccessDenied(String),
+	/// The access token is invalid or expired.
+	#[error("invalid_token: {0}")]
+	InvalidToken(String),
+	/// The provider failed to process the request.
+	#[error("server_error: {0}")]
+	ServerError(String),
+}
+
+impl OidcError {
+	/// The `error` code of the error
+	pub fn code(&self) -> &'static str {
+		match self {
+			Self::InvalidRequest(_) => "invalid_request",
+			Self::InvalidClient(_) => "invalid_client",
+			Self::InvalidGrant(_) => "invalid_grant",
+			Self::UnsupportedGrantType(_) => "unsupported_grant_type",
+			Self::UnsupportedResponseType(_) => "unsupported_response_type",
+			Self::InvalidScope(_) => "invalid_scope",
+			Self::AccessDenied(_) => "access_denied",
+			Self::InvalidToken(_) => "invalid_token",
+			Self::ServerError(_) => "server_error",
+		}
+	}
+
+	/// The `error_description` of the error
+	pub fn description(&self) -> &str {
+		match self {
+			Self::InvalidRequest(description)
+			| Self::InvalidClient(description)
+			| Self::InvalidGrant(description)
+			| Self::UnsupportedGrantType(description)
+			| Self::UnsupportedResponseType(description)
+			| Self::InvalidScope(description)
+			| Self::AccessDenied(description)
+			| Self::InvalidToken(description)
+			| Self::ServerError(description) => description,
+		}
+	}
+
+	/// HTTP status of the error on the token and userinfo endpoints
+	pub fn status(&self) -> u16 {
+		match self {
+			Self::InvalidClient(_) | Self::InvalidToken(_) => 401,
+			Self::AccessDenied(_) => 403,
+			Self::ServerError(_) => 500,
+			_ => 400,
+		}
+	}
+}
@@ -0,0 +1,162 @@
+//! Claims about the user
+//!
+//! [`UserClaims`] holds every claim the provider may release about a user.
+//! Only the claims of the scopes granted to a client end up in its ID token
+//! and userinfo response.
+
+use crate::core::FullUser;
+use serde_json::{Map, Value};
+
+/// Claims released for the standard OpenID Connect scopes
+pub const STANDARD_SCOPE_CLAIMS: &[(&str, &[&str])] = &[
+	(
+		"profile",
+		&[
+			"name",
+			"family_name",
+			"given_name",
+			"middle_name",
+			"nickname",
+			"preferred_username",
+			"profile",
+			"picture",
+			"website",
+			"gender",
+			"birthdate",
+			"zoneinfo",
+			"locale",
+			"updated_at",
+		],
+	),
+	("email", &["email", "email_verified"]),
+	("address", &["address"]),
+	("phone", &["phone_number", "phone_number_verified"]),
+];
+
+/// The subject and claims of a user
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserClaims {
+	subject: String,
+	claims: Map<String, Value>,
+}
+
+impl UserClaims {
+	/// Claims of the user identified by `subject`
+	///
+	/// The subject must be stable and unique, typically the primary key.
+	pub fn new(subject: impl Into<String>) -> Self {
+		Self {
+			subject: subject.into(),
+			claims: Map::new(),
+		}
+	}
+
+	/// Profile and email claims of `user`
+	///
+	/// Empty names and emails are left out.
+	pub fn from_user<U: FullUser>(subject: impl Into<String>, user: &U) -> Self {
+		let mut claims = Self::new(subject).claim("preferred_username", user.username());
+		for (name, value) in [
+			("name", user.get_full_name()),
+			("given_name", user.first_name().to_string()),
+			("family_name", user.last_name().to_string()),
+			("email", user.email().to_string()),
+		] {
+			if !value.is_empty() {
+				claims = claims.claim(name, value);
+			}
+		}
+		claims
+	}
+
+	/// Add or replace a claim
+	pub fn claim(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+		self.claims.insert(name.into(), value.into());
+		self
+	}
+
+	/// Subject of the claims
+	pub fn subject(&self) -> &str {
+		&self.subject
+	}
+
+	/// Value of the claim `name`
+	pub fn get(&self, name: &str) -> Option<&Value> {
+		self.claims.get(name)
+	}
+
+	/// Claims released for `scopes`, given the claims of each scope
+	pub(crate) fn released(
+		&self,
+		scopes: &[String],
+		scope_claims: &[(String, Vec<String>)],
+	) -> Map<String, Value> {
+		self.claims
+			.iter()
+			.filter(|(name, _)| {
+				scope_claims.iter().any(|(scope, claims)| {
+					scopes.contains(scope) && claims.iter().any(|claim| claim == *name)
+				})
+			})
+			.map(|(name, value)| (name.clone(), value.clone()))
+			.collect()
+	}
+}
+
+/// Claims of each standard scope, as owned values
+pub(crate) fn standard_scope_claims() -> Vec<(String, Vec<String>)> {
+	STANDARD_SCOPE_CLAIMS
+		.iter()
+		.map(|(scope, claims)| {
+			(
+				scope.to_string(),
+				claims.iter().map(|claim| claim.to_string()).collect(),
+			)
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn claims() -> UserClaims {
+		UserClaims::new("42")
+			.claim("name", "Alice Smith")
+			.claim("email", "alice@example.com")
+			.claim("email_verified", true)
+			.claim("department", "Sales")
+	}
+
+	#[rstest]
+	#[case(&["openid"], &[])]
+	#[case(&["openid", "profile"], &["name"])]
+	#[case(&["openid", "email"], &["email", "email_verified"])]
+	fn test_released_claims_follow_scopes(#[case] scopes: &[&str], #[case] expected: &[&str]) {
+		// Arrange
+		let scopes: Vec<String> = scopes.iter().map(|scope| scope.to_string()).collect();
+
+		// Act
+		let released = claims().released(&scopes, &standard_scope_claims());
+
+		// Assert
+		let mut names: Vec<&str> = released.keys().map(String::as_str).collect();
+		names.sort_unstable();
+		assert_eq!(names, expected);
+	}
+
+	#[rstest]
+	fn test_custom_scope_releases_custom_claims() {
+		// Arrange
+		let mut scope_claims = standard_scope_claims();
+		scope_claims.push(("org".to_string(), vec!["department".to_string()]));
+
+		// Act
+		let released = claims().released(&["org".to_string()], &scope_claims);
+
+		// Assert
+		assert_eq!(released.get("department"), Some(&Value::from("Sales")));
+		assert_eq!(released.len(), 1);
+	}
+}
@@ -0,0 +1,217 @@
+//! Client registration
+
+use super::OidcError;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use subtle::ConstantTimeEq;
+use tokio::sync::RwLock;
+
+/// An application allowed to obtain tokens from the provider
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OidcClient {
+	/// Client ID
+	pub client_id: String,
+	/// Client secret; `None` for public clients such as SPAs, which must use
+	/// PKCE instead
+	pub client_secret: Option<String>,
+	/// Name shown on the consent screen
+	pub name: String,
+	/// Allowed redirect URIs, compared exactly
+	pub redirect_uris: Vec<String>,
+	/// Scopes the client may request
+	pub scopes: Vec<String>,
+	/// Whether the client belongs to the application itself; first-party
+	/// clients are not shown a consent screen
+	pub first_party: bool,
+}
+
+impl OidcClient {
+	/// Public client, authenticated with PKCE
+	pub fn public(client_id: impl Into<String>, name: impl Into<String>) -> Self {
+		Self {
+			client_id: client_id.into(),
+			client_secret: None,
+			name: name.into(),
+			redirect_uris: Vec::new(),
+			scopes: vec![
+				"openid".to_string(),
+				"profile".to_string(),
+				"email".to_string(),
+			],
+			first_party: false,
+		}
+	}
+
+	/// Confidential client, authenticated with `client_secret`
+	pub fn confidential(
+		client_id: impl Into<String>,
+		client_secret: impl Into<String>,
+		name: impl Into<String>,
+	) -> Self {
+		Self {
+			client_secret: Some(client_secret.into()),
+			..Self::public(client_id, name)
+		}
+	}
+
+	/// Allow `redirect_uri`
+	pub fn with_redirect_uri(mut self, redirect_uri: impl Into<String>) -> Self {
+		self.redirect_uris.push(redirect_uri.into());
+		self
+	}
+
+	/// Replace the scopes the client may request
+	pub fn with_scopes<I, S>(mut self, scopes: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: Into<String>,
+	{
+		self.scopes = scopes.into_iter().map(Into::into).collect();
+		self
+	}
+
+	/// Mark the client as first-party, skipping the consent screen
+	pub fn first_party(mut self) -> Self {
+		self.first_party = true;
+		self
+	}
+
+	/// Whether the client has no secret
+	pub fn is_public(&self) -> bool {
+		self.client_secret.is_none()
+	}
+
+	/// Whether `redirect_uri` is registered for the client
+	pub fn allows_redirect_uri(&self, redirect_uri: &str) -> bool {
+		self.redirect_uris
+			.iter()
+			.any(|allowed| allowed == redirect_uri)
+	}
+
+	/// Whether `scope` may be requested by the client
+	pub fn allows_scope(&self, scope: &str) -> bool {
+		self.scopes.iter().any(|allowed| allowed == scope)
+	}
+
+	/// Check `secret` against the secret of a confidential client
+	///
+	/// Public clients authenticate without a secret. Uses constant-time
+	/// comparison.
+	pub fn authenticate(&self, secret: Option<&str>) -> Result<(), OidcError> {
+		match (&self.client_secret, secret) {
+			(None, _) => Ok(()),
+			(Some(expected), Some(secret))
+				if bool::from(expected.as_bytes().ct_eq(secret.as_bytes())) =>
+			{
+				Ok(())
+			}
+			_ => Err(OidcError::InvalidClient(
+				"Invalid client credentials".to_string(),
+			)),
+		}
+	}
+}
+
+/// Storage of registered clients
+#[async_trait]
+pub trait ClientStore: Send + Sync {
+	/// Register or replace a client
+	async fn register(&self, client: OidcClient) -> Result<(), OidcError>;
+
+	/// Get a client by ID
+	async fn get(&self, client_id: &str) -> Result<Option<OidcClient>, OidcError>;
+
+	/// Remove a client
+	async fn remove(&self, client_id: &str) -> Result<(), OidcError>;
+}
+
+/// In-memory client store
+#[derive(Default)]
+pub struct InMemoryClientStore {
+	clients: RwLock<HashMap<String, OidcClient>>,
+}
+
+impl InMemoryClientStore {
+	/// Create an empty client store
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+#[async_trait]
+impl ClientStore for InMemoryClientStore {
+	async fn register(&self, client: OidcClient) -> Result<(), OidcError> {
+		self.clients
+			.write()
+			.await
+			.insert(client.client_id.clone(), client);
+		Ok(())
+	}
+
+	async fn get(&self, client_id: &str) -> Result<Option<OidcClient>, OidcError> {
+		Ok(self.clients.read().await.get(client_id).cloned())
+	}
+
+	async fn remove(&self, client_id: &str) -> Result<(), OidcError> {
+		self.clients.write().await.remove(client_id);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case(None, true)]
+	#[case(Some("s3cret"), true)]
+	fn test_public_client_authenticates_without_secret(
+		#[case] secret: Option<&str>,
+		#[case] expected: bool,
+	) {
+		// Arrange
+		let client = OidcClient::public("spa", "SPA");
+
+		// Act
+		let result = client.authenticate(secret);
+
+		// Assert
+		assert_eq!(result.is_ok(), expected);
+	}
+
+	#[rstest]
+	#[case(None, false)]
+	#[case(Some("wrong"), false)]
+	#[case(Some("s3cret"), true)]
+	fn test_confidential_client_requires_secret(
+		#[case] secret: Option<&str>,
+		#[case] expected: bool,
+	) {
+		// Arrange
+		let client = OidcClient::confidential("billing", "s3cret", "Billing");
+
+		// Act
+		let result = client.authenticate(secret);
+
+		// Assert
+		assert_eq!(result.is_ok(), expected);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_in_memory_store_registers_clients() {
+		// Arrange
+		let store = InMemoryClientStore::new();
+		let client = OidcClient::public("spa", "SPA").with_redirect_uri("https://spa.test/cb");
+
+		// Act
+		store.register(client).await.unwrap();
+		let found = store.get("spa").await.unwrap();
+
+		// Assert
+		assert!(found.unwrap().allows_redirect_uri("https://spa.test/cb"));
+		assert!(store.get("other").await.unwrap().is_none());
+	}
+}
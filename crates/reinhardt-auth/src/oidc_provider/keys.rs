@@ -0,0 +1,264 @@
+//! Rotating signing keys
+//!
+//! Tokens are signed with ES256 by the newest key of a [`KeyRing`]. After a
+//! rotation the previous keys stay published in the JWKS document for the
+//! retention period, so tokens signed before the rotation keep verifying.
+
+use super::OidcError;
+use crate::social::oidc::{Jwk, JwkSet};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use p256::pkcs8::EncodePrivateKey;
+use rand::Rng;
+use std::sync::{Arc, RwLock};
+use uuid::Uuid;
+
+/// Algorithm of the signing keys
+pub const SIGNING_ALGORITHM: Algorithm = Algorithm::ES256;
+
+/// An ES256 key pair identified by its key ID
+pub struct SigningKey {
+	kid: String,
+	created_at: DateTime<Utc>,
+	encoding: EncodingKey,
+	decoding: DecodingKey,
+	jwk: Jwk,
+}
+
+impl SigningKey {
+	/// Generate a new P-256 key pair
+	pub fn generate() -> Result<Self, OidcError> {
+		let secret = loop {
+			let mut bytes = [0u8; 32];
+			rand::rng().fill(&mut bytes);
+			// Almost every 32-byte string is a valid scalar; retry otherwise.
+			if let Ok(secret) = p256::SecretKey::from_bytes(&bytes.into()) {
+				break secret;
+			}
+		};
+		let der = secret
+			.to_pkcs8_der()
+			.map_err(|e| OidcError::ServerError(format!("Failed to encode signing key: {}", e)))?;
+		let point = secret.public_key().to_encoded_point(false);
+		let (Some(x), Some(y)) = (point.x(), point.y()) else {
+			return Err(OidcError::ServerError(
+				"Signing key has no affine coordinates".to_string(),
+			));
+		};
+		let x = URL_SAFE_NO_PAD.encode(x);
+		let y = URL_SAFE_NO_PAD.encode(y);
+		let decoding = DecodingKey::from_ec_components(&x, &y)
+			.map_err(|e| OidcError::ServerError(e.to_string()))?;
+		let kid = Uuid::new_v4().simple().to_string();
+
+		Ok(Self {
+			jwk: Jwk {
+				kty: "EC".to_string(),
+				kid: Some(kid.clone()),
+				use_: Some("sig".to_string()),
+				alg: Some("ES256".to_string()),
+				n: None,
+				e: None,
+				crv: Some("P-256".to_string()),
+				x: Some(x),
+				y: Some(y),
+			},
+			kid,
+			created_at: Utc::now(),
+			encoding: EncodingKey::from_ec_der(der.as_bytes()),
+			decoding,
+		})
+	}
+
+	/// Key ID, sent in the `kid` header of the tokens it signs
+	pub fn kid(&self) -> &str {
+		&self.kid
+	}
+
+	/// When the key was generated
+	pub fn created_at(&self) -> DateTime<Utc> {
+		self.created_at
+	}
+
+	/// Public key as a JWK
+	pub fn jwk(&self) -> &Jwk {
+		&self.jwk
+	}
+
+	pub(crate) fn encoding_key(&self) -> &EncodingKey {
+		&self.encoding
+	}
+
+	pub(crate) fn decoding_key(&self) -> &DecodingKey {
+		&self.decoding
+	}
+}
+
+impl std::fmt::Debug for SigningKey {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("SigningKey")
+			.field("kid", &self.kid)
+			.field("created_at", &self.created_at)
+			.finish_non_exhaustive()
+	}
+}
+
+/// The signing keys of the provider, newest first
+pub struct KeyRing {
+	keys: RwLock<Vec<Arc<SigningKey>>>,
+	retention: Duration,
+}
+
+impl KeyRing {
+	/// Key ring with one fresh key, keeping retired keys for a day
+	pub fn new() -> Result<Self, OidcError> {
+		Ok(Self {
+			keys: RwLock::new(vec![Arc::new(SigningKey::generate()?)]),
+			retention: Duration::days(1),
+		})
+	}
+
+	/// Keep retired keys published for `retention`
+	///
+	/// Should exceed the lifetime of the tokens.
+	pub fn with_retention(mut self, retention: Duration) -> Self {
+		self.retention = retention;
+		self
+	}
+
+	/// Key signing new tokens
+	pub fn active(&self) -> Arc<SigningKey> {
+		Arc::clone(&self.keys.read().unwrap_or_else(|e| e.into_inner())[0])
+	}
+
+	/// Key with ID `kid`, active or retained
+	pub fn find(&self, kid: &str) -> Option<Arc<SigningKey>> {
+		self.keys
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.iter()
+			.find(|key| key.kid == kid)
+			.cloned()
+	}
+
+	/// Sign new tokens with a fresh key and drop the keys retired for longer
+	/// than the retention period. Returns the ID of the new key.
+	pub fn rotate(&self) -> Result<String, OidcError> {
+		let key = Arc::new(SigningKey::generate()?);
+		let kid = key.kid.clone();
+		let mut keys = self.keys.write().unwrap_or_else(|e| e.into_inner());
+		keys.insert(0, key);
+		// A key is retired when its successor is created.
+		let now = Utc::now();
+		let retired_at: Vec<_> = keys.iter().map(|key| key.created_at).collect();
+		let mut index = 0;
+		keys.retain(|_| {
+			index += 1;
+			index == 1 || now - retired_at[index - 2] < self.retention
+		});
+		Ok(kid)
+	}
+
+	/// Rotate when the active key is older than `max_age`
+	///
+	/// Meant to be called periodically, e.g. from a scheduled task. Returns
+	/// whether a rotation happened.
+	pub fn rotate_if_older_than(&self, max_age: Duration) -> Result<bool, OidcError> {
+		if Utc::now() - self.active().created_at < max_age {
+			return Ok(false);
+		}
+		self.rotate().map(|_| true)
+	}
+
+	/// Public keys to publish at the JWKS endpoint
+	pub fn jwks(&self) -> JwkSet {
+		JwkSet {
+			keys: self
+				.keys
+				.read()
+				.unwrap_or_else(|e| e.into_inner())
+				.iter()
+				.map(|key| key.jwk.clone())
+				.collect(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use jsonwebtoken::{Header, Validation, decode, encode};
+	use rstest::rstest;
+	use serde::{Deserialize, Serialize};
+
+	#[derive(Debug, Serialize, Deserialize, PartialEq)]
+	struct TestClaims {
+		sub: String,
+		exp: i64,
+	}
+
+	#[rstest]
+	fn test_generated_key_signs_and_verifies() {
+		// Arrange
+		let key = SigningKey::generate().unwrap();
+		let claims = TestClaims {
+			sub: "42".to_string(),
+			exp: (Utc::now() + Duration::minutes(5)).timestamp(),
+		};
+
+		// Act
+		let token = encode(&Header::new(SIGNING_ALGORITHM), &claims, key.encoding_key()).unwrap();
+		let decoded = decode::<TestClaims>(
+			&token,
+			&key.jwk().to_decoding_key().unwrap(),
+			&Validation::new(SIGNING_ALGORITHM),
+		)
+		.unwrap();
+
+		// Assert
+		assert_eq!(decoded.claims, claims);
+	}
+
+	#[rstest]
+	fn test_rotation_keeps_retired_keys_within_retention() {
+		// Arrange
+		let ring = KeyRing::new().unwrap();
+		let first = ring.active().kid().to_string();
+
+		// Act
+		let second = ring.rotate().unwrap();
+
+		// Assert
+		assert_eq!(ring.active().kid(), second);
+		assert!(ring.find(&first).is_some());
+		assert_eq!(ring.jwks().keys.len(), 2);
+	}
+
+	#[rstest]
+	fn test_rotation_drops_keys_past_retention() {
+		// Arrange
+		let ring = KeyRing::new().unwrap().with_retention(Duration::zero());
+		let first = ring.active().kid().to_string();
+
+		// Act
+		ring.rotate().unwrap();
+
+		// Assert
+		assert!(ring.find(&first).is_none());
+		assert_eq!(ring.jwks().keys.len(), 1);
+	}
+
+	#[rstest]
+	fn test_rotate_if_older_than_skips_fresh_keys() {
+		// Arrange
+		let ring = KeyRing::new().unwrap();
+
+		// Act
+		let rotated = ring.rotate_if_older_than(Duration::days(30)).unwrap();
+
+		// Assert
+		assert!(!rotated);
+	}
+}
@@ -0,0 +1,1059 @@
+//! Authorization code flow, tokens and discovery
+
+use super::OidcError;
+use super::claims::{UserClaims, standard_scope_claims};
+use super::clients::{ClientStore, InMemoryClientStore, OidcClient};
+use super::keys::{KeyRing, SIGNING_ALGORITHM};
+use crate::social::oidc::{JwkSet, OIDCDiscovery};
+use base64::{Engine, engine::general_purpose::STANDARD, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Duration, Utc};
+use hyper::StatusCode;
+use jsonwebtoken::{Header, Validation, decode, decode_header, encode};
+use rand::Rng;
+use reinhardt_http::{Request, Response};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+use subtle::ConstantTimeEq;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+/// Endpoints and token lifetimes of the provider
+#[derive(Debug, Clone)]
+pub struct OidcProviderConfig {
+	/// Issuer URL, e.g. `https://id.example.com`
+	pub issuer: String,
+	/// Path of the authorization endpoint
+	pub authorization_path: String,
+	/// Path of the token endpoint
+	pub token_path: String,
+	/// Path of the userinfo endpoint
+	pub userinfo_path: String,
+	/// Path of the JWKS endpoint
+	pub jwks_path: String,
+	/// Lifetime of authorization codes
+	pub code_ttl: Duration,
+	/// Lifetime of ID and access tokens
+	pub token_ttl: Duration,
+}
+
+impl OidcProviderConfig {
+	/// Configuration for `issuer` with the default endpoint paths, 10-minute
+	/// authorization codes and 1-hour tokens
+	pub fn new(issuer: impl Into<String>) -> Self {
+		Self {
+			issuer: issuer.into().trim_end_matches('/').to_string(),
+			authorization_path: "/oauth/authorize".to_string(),
+			token_path: "/oauth/token".to_string(),
+			userinfo_path: "/oauth/userinfo".to_string(),
+			jwks_path: "/.well-known/jwks.json".to_string(),
+			code_ttl: Duration::minutes(10),
+			token_ttl: Duration::hours(1),
+		}
+	}
+
+	/// Set the lifetime of authorization codes
+	pub fn with_code_ttl(mut self, ttl: Duration) -> Self {
+		self.code_ttl = ttl;
+		self
+	}
+
+	/// Set the lifetime of ID and access tokens
+	pub fn with_token_ttl(mut self, ttl: Duration) -> Self {
+		self.token_ttl = ttl;
+		self
+	}
+
+	fn url(&self, path: &str) -> String {
+		format!("{}{}", self.issuer, path)
+	}
+}
+
+/// Query parameters of the authorization endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorizationRequest {
+	/// Must be `code`
+	pub response_type: String,
+	/// Requesting client
+	pub client_id: String,
+	/// Where to send the user back, registered for the client
+	pub redirect_uri: String,
+	/// Space-separated scopes, including `openid`
+	pub scope: String,
+	/// Opaque value returned to the client
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub state: Option<String>,
+	/// Value copied into the ID token
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub nonce: Option<String>,
+	/// PKCE challenge, required for public clients
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub code_challenge: Option<String>,
+	/// PKCE method; only `S256` is supported
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub code_challenge_method: Option<String>,
+}
+
+impl AuthorizationRequest {
+	/// Requested scopes, without duplicates
+	pub fn scopes(&self) -> Vec<String> {
+		let mut scopes: Vec<String> = Vec::new();
+		for scope in self.scope.split_whitespace() {
+			if !scopes.iter().any(|known| known == scope) {
+				scopes.push(scope.to_string());
+			}
+		}
+		scopes
+	}
+}
+
+/// A scope as shown on the consent screen
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScopeDescription {
+	/// Scope name
+	pub scope: String,
+	/// What the client gets access to
+	pub description: String,
+}
+
+/// What the consent screen asks the user to approve
+///
+/// Render it, then call [`OidcProvider::consent`] with the answer.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConsentScreen {
+	/// Requesting client
+	pub client_id: String,
+	/// Name of the requesting client
+	pub client_name: String,
+	/// Requested scopes
+	pub scopes: Vec<ScopeDescription>,
+	/// The request, to submit back with the answer
+	pub request: AuthorizationRequest,
+}
+
+/// Outcome of an authorization request
+#[derive(Debug, Clone)]
+pub enum Authorization {
+	/// Redirect the user to this URL, carrying a code or an error
+	Redirect(String),
+	/// Ask the user to approve the request
+	ConsentRequired(ConsentScreen),
+}
+
+/// Form parameters of the token endpoint
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TokenRequest {
+	/// Must be `authorization_code`
+	pub grant_type: String,
+	/// Authorization code
+	#[serde(default)]
+	pub code: Option<String>,
+	/// Redirect URI of the authorization request
+	#[serde(default)]
+	pub redirect_uri: Option<String>,
+	/// Client ID, when not sent with HTTP Basic authentication
+	#[serde(default)]
+	pub client_id: Option<String>,
+	/// Secret of confidential clients
+	#[serde(default)]
+	pub client_secret: Option<String>,
+	/// PKCE verifier
+	#[serde(default)]
+	pub code_verifier: Option<String>,
+}
+
+/// Successful response of the token endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OidcTokenResponse {
+	/// Access token, a JWT verifiable with the JWKS
+	pub access_token: String,
+	/// Always `Bearer`
+	pub token_type: String,
+	/// Lifetime of the tokens in seconds
+	pub expires_in: i64,
+	/// ID token
+	pub id_token: String,
+	/// Granted scopes
+	pub scope: String,
+}
+
+/// Claims of the access tokens
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccessTokenClaims {
+	/// Issuer
+	pub iss: String,
+	/// Subject
+	pub sub: String,
+	/// Client the token was issued to
+	pub aud: String,
+	/// Granted scopes, space-separated
+	pub scope: String,
+	/// Expiration time
+	pub exp: i64,
+	/// Issue time
+	pub iat: i64,
+	/// Token ID
+	pub jti: String,
+}
+
+/// An authorization code waiting to be exchanged
+struct PendingCode {
+	client_id: String,
+	redirect_uri: String,
+	scopes: Vec<String>,
+	nonce: Option<String>,
+	code_challenge: Option<String>,
+	user: UserClaims,
+	auth_time: DateTime<Utc>,
+	expires_at: DateTime<Utc>,
+}
+
+/// What the userinfo endpoint releases for an access token
+struct IssuedToken {
+	user: UserClaims,
+	scopes: Vec<String>,
+	expires_at: DateTime<Utc>,
+}
+
+/// OpenID Connect provider
+///
+/// Authorization codes, consents and the userinfo of issued access tokens
+/// are kept in memory.
+pub struct OidcProvider {
+	config: OidcProviderConfig,
+	clients: Arc<dyn ClientStore>,
+	keys: Arc<KeyRing>,
+	scope_claims: Vec<(String, Vec<String>)>,
+	scope_descriptions: HashMap<String, String>,
+	codes: Mutex<HashMap<String, PendingCode>>,
+	consents: Mutex<HashMap<(String, String), BTreeSet<String>>>,
+	issued: Mutex<HashMap<String, IssuedToken>>,
+}
+
+impl OidcProvider {
+	/// Provider with an in-memory client store and a fresh key ring
+	pub fn new(config: OidcProviderConfig) -> Result<Self, OidcError> {
+		let scope_descriptions = [
+			("openid", "Know who you are"),
+			("profile", "See your name and profile"),
+			("email", "See your email address"),
+			("address", "See your postal address"),
+			("phone", "See your phone number"),
+		]
+		.into_iter()
+		.map(|(scope, description)| (scope.to_string(), description.to_string()))
+		.collect();
+		Ok(Self {
+			config,
+			clients: Arc::new(InMemoryClientStore::new()),
+			keys: Arc::new(KeyRing::new()?),
+			scope_claims: standard_scope_claims(),
+			scope_descriptions,
+			codes: Mutex::new(HashMap::new()),
+			consents: Mutex::new(HashMap::new()),
+			issued: Mutex::new(HashMap::new()),
+		})
+	}
+
+	/// Use `clients` to look up registered clients
+	pub fn with_client_store(mut self, clients: Arc<dyn ClientStore>) -> Self {
+		self.clients = clients;
+		self
+	}
+
+	/// Sign tokens with `keys`
+	pub fn with_key_ring(mut self, keys: Arc<KeyRing>) -> Self {
+		self.keys = keys;
+		self
+	}
+
+	/// Define a custom scope releasing `claims`
+	pub fn with_scope(
+		mut self,
+		scope: impl Into<String>,
+		description: impl Into<String>,
+		claims: &[&str],
+	) -> Self {
+		let scope = scope.into();
+		self.scope_descriptions
+			.insert(scope.clone(), description.into());
+		self.scope_claims.push((
+			scope,
+			claims.iter().map(|claim| claim.to_string()).collect(),
+		));
+		self
+	}
+
+	/// Configuration of the provider
+	pub fn config(&self) -> &OidcProviderConfig {
+		&self.config
+	}
+
+	/// Signing keys of the provider
+	pub fn keys(&self) -> &Arc<KeyRing> {
+		&self.keys
+	}
+
+	/// Register or replace a client
+	pub async fn register_client(&self, client: OidcClient) -> Result<(), OidcError> {
+		self.clients.register(client).await
+	}
+
+	/// Handle an authorization request of the logged-in `user`
+	///
+	/// Fails when the client or the redirect URI is unknown, in which case
+	/// the user must not be redirected. Other invalid requests redirect with
+	/// an error. First-party clients and requests already approved by the
+	/// user receive a code right away.
+	pub async fn authorize(
+		&self,
+		request: &AuthorizationRequest,
+		user: &UserClaims,
+	) -> Result<Authorization, OidcError> {
+		let client = self.redirectable_client(request).await?;
+		let scopes = match validate_request(&client, request) {
+			Ok(scopes) => scopes,
+			Err(error) => return error_redirect(request, &error).map(Authorization::Redirect),
+		};
+
+		let approved = client.first_party
+			|| self
+				.consents
+				.lock()
+				.await
+				.get(&(user.subject().to_string(), client.client_id.clone()))
+				.is_some_and(|granted| scopes.iter().all(|scope| granted.contains(scope)));
+		if approved {
+			return self
+				.issue_code(request, scopes, user)
+				.await
+				.map(Authorization::Redirect);
+		}
+
+		Ok(Authorization::ConsentRequired(ConsentScreen {
+			client_id: client.client_id.clone(),
+			client_name: client.name.clone(),
+			scopes: scopes
+				.iter()
+				.map(|scope| ScopeDescription {
+					scope: scope.clone(),
+					description: self
+						.scope_descriptions
+						.get(scope)
+						.cloned()
+						.unwrap_or_else(|| scope.clone()),
+				})
+				.collect(),
+			request: request.clone(),
+		}))
+	}
+
+	/// Record the answer of `user` to a consent screen
+	///
+	/// Returns the URL to redirect the user to: with a code when `approved`,
+	/// with an `access_denied` error otherwise.
+	pub async fn consent(
+		&self,
+		request: &AuthorizationRequest,
+		user: &UserClaims,
+		approved: bool,
+	) -> Result<String, OidcError> {
+		let client = self.redirectable_client(request).await?;
+		let scopes = match validate_request(&client, request) {
+			Ok(scopes) => scopes,
+			Err(error) => return error_redirect(request, &error),
+		};
+		if !approved {
+			return error_redirect(
+				request,
+				&OidcError::AccessDenied("The user denied the request".to_string()),
+			);
+		}
+
+		self.consents
+			.lock()
+			.await
+			.entry((user.subject().to_string(), client.client_id.clone()))
+			.or_default()
+			.extend(scopes.iter().cloned());
+		self.issue_code(request, scopes, user).await
+	}
+
+	/// Forget the consents `subject` gave to `client_id`
+	pub async fn revoke_consent(&self, subject: &str, client_id: &str) {
+		self.consents
+			.lock()
+			.await
+			.remove(&(subject.to_string(), client_id.to_string()));
+	}
+
+	async fn redirectable_client(
+		&self,
+		request: &AuthorizationRequest,
+	) -> Result<OidcClient, OidcError> {
+		let client = self
+			.clients
+			.get(&request.client_id)
+			.await?
+			.ok_or_else(|| OidcError::InvalidClient("Unknown client".to_string()))?;
+		if !client.allows_redirect_uri(&request.redirect_uri) {
+			return Err(OidcError::InvalidRequest(
+				"redirect_uri is not registered for the client".to_string(),
+			));
+		}
+		Ok(client)
+	}
+
+	async fn issue_code(
+		&self,
+		request: &AuthorizationRequest,
+		scopes: Vec<String>,
+		user: &UserClaims,
+	) -> Result<String, OidcError> {
+		let code = random_token();
+		let now = Utc::now();
+		let mut codes = self.codes.lock().await;
+		codes.retain(|_, pending| pending.expires_at > now);
+		codes.insert(
+			code.clone(),
+			PendingCode {
+				client_id: request.client_id.clone(),
+				redirect_uri: request.redirect_uri.clone(),
+				scopes,
+				nonce: request.nonce.clone(),
+				code_challenge: request.code_challenge.clone(),
+				user: user.clone(),
+				auth_time: now,
+				expires_at: now + self.config.code_ttl,
+			},
+		);
+		drop(codes);
+
+		let mut params = vec![("code", code)];
+		if let Some(state) = &request.state {
+			params.push(("state", state.clone()));
+		}
+		redirect_url(&request.redirect_uri, &params)
+	}
+
+	/// Exchange an authorization code for tokens
+	pub async fn exchange(&self, request: &TokenRequest) -> Result<OidcTokenResponse, OidcError> {
+		if request.grant_type != "authorization_code" {
+			return Err(OidcError::UnsupportedGrantType(format!(
+				"Unsupported grant type '{}'",
+				request.grant_type
+			)));
+		}
+		let client_id = request
+			.client_id
+			.as_deref()
+			.ok_or_else(|| OidcError::InvalidClient("Missing client_id".to_string()))?;
+		let client = self
+			.clients
+			.get(client_id)
+			.await?
+			.ok_or_else(|| OidcError::InvalidClient("Unknown client".to_string()))?;
+		client.authenticate(request.client_secret.as_deref())?;
+
+		let code = request
+			.code
+			.as_deref()
+			.ok_or_else(|| OidcError::InvalidRequest("Missing code".to_string()))?;
+		let pending = self
+			.codes
+			.lock()
+			.await
+			.remove(code)
+			.ok_or_else(|| OidcError::InvalidGrant("Invalid authorization code".to_string()))?;
+		if pending.expires_at <= Utc::now() {
+			return Err(OidcError::InvalidGrant(
+				"Authorization code expired".to_string(),
+			));
+		}
+		if pending.client_id != client.client_id {
+			return Err(OidcError::InvalidGrant(
+				"Authorization code was not issued to this client".to_string(),
+			));
+		}
+		if request.redirect_uri.as_deref() != Some(pending.redirect_uri.as_str()) {
+			return Err(OidcError::InvalidGrant(
+				"redirect_uri does not match the authorization request".to_string(),
+			));
+		}
+		if let Some(challenge) = &pending.code_challenge {
+			let verifier = request
+				.code_verifier
+				.as_deref()
+				.ok_or_else(|| OidcError::InvalidGrant("Missing code_verifier".to_string()))?;
+			let computed = URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()));
+			if !bool::from(computed.as_bytes().ct_eq(challenge.as_bytes())) {
+				return Err(OidcError::InvalidGrant(
+					"code_verifier does not match the code_challenge".to_string(),
+				));
+			}
+		}
+
+		self.issue_tokens(&client, pending).await
+	}
+
+	async fn issue_tokens(
+		&self,
+		client: &OidcClient,
+		pending: PendingCode,
+	) -> Result<OidcTokenResponse, OidcError> {
+		let now = Utc::now();
+		let expires_at = now + self.config.token_ttl;
+		let key = self.keys.active();
+		let mut header = Header::new(SIGNING_ALGORITHM);
+		header.kid = Some(key.kid().to_string());
+
+		let mut id_claims = pending.user.released(&pending.scopes, &self.scope_claims);
+		id_claims.insert("iss".to_string(), self.config.issuer.clone().into());
+		id_claims.insert("sub".to_string(), pending.user.subject().into());
+		id_claims.insert("aud".to_string(), client.client_id.clone().into());
+		id_claims.insert("exp".to_string(), expires_at.timestamp().into());
+		id_claims.insert("iat".to_string(), now.timestamp().into());
+		id_claims.insert(
+			"auth_time".to_string(),
+			pending.auth_time.timestamp().into(),
+		);
+		if let Some(nonce) = &pending.nonce {
+			id_claims.insert("nonce".to_string(), nonce.clone().into());
+		}
+		let id_token = encode(&header, &id_claims, key.encoding_key())
+			.map_err(|e| OidcError::ServerError(format!("Failed to sign ID token: {}", e)))?;
+
+		let scope = pending.scopes.join(" ");
+		let access_claims = AccessTokenClaims {
+			iss: self.config.issuer.clone(),
+			sub: pending.user.subject().to_string(),
+			aud: client.client_id.clone(),
+			scope: scope.clone(),
+			exp: expires_at.timestamp(),
+			iat: now.timestamp(),
+			jti: Uuid::new_v4().to_string(),
+		};
+		let access_token = encode(&header, &access_claims, key.encoding_key())
+			.map_err(|e| OidcError::ServerError(format!("Failed to sign access token: {}", e)))?;
+
+		let mut issued = self.issued.lock().await;
+		issued.retain(|_, token| token.expires_at > now);
+		issued.insert(
+			access_claims.jti,
+			IssuedToken {
+				user: pending.user,
+				scopes: pending.scopes,
+				expires_at,
+			},
+		);
+
+		Ok(OidcTokenResponse {
+			access_token,
+			token_type: "Bearer".to_string(),
+			expires_in: self.config.token_ttl.num_seconds(),
+			id_token,
+			scope,
+		})
+	}
+
+	/// Verify the signature, issuer and expiry of an access token
+	pub fn verify_access_token(&self, token: &str) -> Result<AccessTokenClaims, OidcError> {
+		let invalid = |message: &str| OidcError::InvalidToken(message.to_string());
+		let header = decode_header(token).map_err(|_| invalid("Malformed access token"))?;
+		let key = header
+			.kid
+			.as_deref()
+			.and_then(|kid| self.keys.find(kid))
+			.ok_or_else(|| invalid("Unknown signing key"))?;
+		let mut validation = Validation::new(SIGNING_ALGORITHM);
+		validation.set_issuer(&[&self.config.issuer]);
+		validation.validate_aud = false;
+		decode::<AccessTokenClaims>(token, key.decoding_key(), &validation)
+			.map(|data| data.claims)
+			.map_err(|_| invalid("Invalid or expired access token"))
+	}
+
+	/// Claims released to the holder of `access_token`
+	pub async fn userinfo(&self, access_token: &str) -> Result<Map<String, Value>, OidcError> {
+		let claims = self.verify_access_token(access_token)?;
+		let issued = self.issued.lock().await;
+		let token = issued
+			.get(&claims.jti)
+			.ok_or_else(|| OidcError::InvalidToken("Unknown access token".to_string()))?;
+		let mut userinfo = token.user.released(&token.scopes, &self.scope_claims);
+		userinfo.insert("sub".to_string(), claims.sub.into());
+		Ok(userinfo)
+	}
+
+	/// Public keys of the provider
+	pub fn jwks(&self) -> JwkSet {
+		self.keys.jwks()
+	}
+
+	/// Discovery document, served at `/.well-known/openid-configuration`
+	pub fn discovery(&self) -> OIDCDiscovery {
+		let mut claims_supported = vec![
+			"sub".to_string(),
+			"iss".to_string(),
+			"aud".to_string(),
+			"exp".to_string(),
+			"iat".to_string(),
+			"auth_time".to_string(),
+			"nonce".to_string(),
+		];
+		for (_, claims) in &self.scope_claims {
+			claims_supported.extend(claims.iter().cloned());
+		}
+		let mut scopes_supported = vec!["openid".to_string()];
+		scopes_supported.extend(self.scope_claims.iter().map(|(scope, _)| scope.clone()));
+
+		OIDCDiscovery {
+			issuer: self.config.issuer.clone(),
+			authorization_endpoint: self.config.url(&self.config.authorization_path),
+			token_endpoint: self.config.url(&self.config.token_path),
+			jwks_uri: self.config.url(&self.config.jwks_path),
+			userinfo_endpoint: Some(self.config.url(&self.config.userinfo_path)),
+			scopes_supported: Some(scopes_supported),
+			response_types_supported: Some(vec!["code".to_string()]),
+			grant_types_supported: Some(vec!["authorization_code".to_string()]),
+			subject_types_supported: Some(vec!["public".to_string()]),
+			id_token_signing_alg_values_supported: Some(vec!["ES256".to_string()]),
+			claims_supported: Some(claims_supported),
+		}
+	}
+
+	/// Response of the discovery endpoint
+	pub fn discovery_response(&self) -> Response {
+		json_response(Response::ok(), &self.discovery())
+	}
+
+	/// Response of the JWKS endpoint
+	pub fn jwks_response(&self) -> Response {
+		json_response(Response::ok(), &self.jwks())
+	}
+
+	/// Response of the token endpoint
+	///
+	/// Reads the form-encoded parameters of `request`; client credentials may
+	/// also be sent with HTTP Basic authentication.
+	pub async fn token_response(&self, request: &Request) -> Response {
+		let result = match token_request(request) {
+			Ok(token_request) => self.exchange(&token_request).await,
+			Err(error) => Err(error),
+		};
+		match result {
+			Ok(tokens) => json_response(Response::ok(), &tokens)
+				.with_header("Cache-Control", "no-store")
+				.with_header("Pragma", "no-cache"),
+			Err(error) => error_response(&error),
+		}
+	}
+
+	/// Response of the userinfo endpoint for the bearer token of `request`
+	pub async fn userinfo_response(&self, request: &Request) -> Response {
+		let token = request
+			.get_header("authorization")
+			.and_then(|value| value.strip_prefix("Bearer ").map(str::to_string));
+		let result = match token {
+			Some(token) => self.userinfo(&token).await,
+			None => Err(OidcError::InvalidToken("Missing bearer token".to_string())),
+		};
+		match result {
+			Ok(userinfo) => json_response(Response::ok(), &userinfo),
+			Err(error) => error_response(&error).with_header(
+				"WWW-Authenticate",
+				&format!("Bearer error=\"{}\"", error.code()),
+			),
+		}
+	}
+}
+
+/// Check the parts of an authorization request reported to the client by
+/// redirect, returning the requested scopes
+fn validate_request(
+	client: &OidcClient,
+	request: &AuthorizationRequest,
+) -> Result<Vec<String>, OidcError> {
+	if request.response_type != "code" {
+		return Err(OidcError::UnsupportedResponseType(
+			"Only the code response type is supported".to_string(),
+		));
+	}
+	let scopes = request.scopes();
+	if !scopes.iter().any(|scope| scope == "openid") {
+		return Err(OidcError::InvalidScope(
+			"The openid scope is required".to_string(),
+		));
+	}
+	if let Some(scope) = scopes.iter().find(|scope| !client.allows_scope(scope)) {
+		return Err(OidcError::InvalidScope(format!(
+			"Scope '{}' is not allowed for the client",
+			scope
+		)));
+	}
+	match (
+		&request.code_challenge,
+		request.code_challenge_method.as_deref(),
+	) {
+		(Some(_), Some("S256")) => {}
+		(Some(_), _) => {
+			return Err(OidcError::InvalidRequest(
+				"Only the S256 code_challenge_method is supported".to_string(),
+			));
+		}
+		(None, _) if client.is_public() => {
+			return Err(OidcError::InvalidRequest(
+				"PKCE is required for public clients".to_string(),
+			));
+		}
+		(None, _) => {}
+	}
+	Ok(scopes)
+}
+
+fn redirect_url(redirect_uri: &str, params: &[(&str, String)]) -> Result<String, OidcError> {
+	let mut url = url::Url::parse(redirect_uri)
+		.map_err(|e| OidcError::InvalidRequest(format!("Invalid redirect_uri: {}", e)))?;
+	{
+		let mut query = url.query_pairs_mut();
+		for (name, value) in params {
+			query.append_pair(name, value);
+		}
+	}
+	Ok(url.into())
+}
+
+fn error_redirect(request: &AuthorizationRequest, error: &OidcError) -> Result<String, OidcError> {
+	let mut params = vec![
+		("error", error.code().to_string()),
+		("error_description", error.description().to_string()),
+	];
+	if let Some(state) = &request.state {
+		params.push(("state", state.clone()));
+	}
+	redirect_url(&request.redirect_uri, &params)
+}
+
+fn random_token() -> String {
+	let mut bytes = [0u8; 32];
+	rand::rng().fill(&mut bytes);
+	URL_SAFE_NO_PAD.encode(bytes)
+}
+
+fn token_request(request: &Request) -> Result<TokenRequest, OidcError> {
+	let body = request
+		.read_body()
+		.map_err(|e| OidcError::InvalidRequest(format!("Failed to read body: {}", e)))?;
+	let params: HashMap<String, String> = url::form_urlencoded::parse(&body).into_owned().collect();
+	let mut token_request = TokenRequest {
+		grant_type: params.get("grant_type").cloned().unwrap_or_default(),
+		code: params.get("code").cloned(),
+		redirect_uri: params.get("redirect_uri").cloned(),
+		client_id: params.get("client_id").cloned(),
+		client_secret: params.get("client_secret").cloned(),
+		code_verifier: params.get("code_verifier").cloned(),
+	};
+
+	if let Some(credentials) = request
+		.get_header("authorization")
+		.and_then(|value| value.strip_prefix("Basic ").map(str::to_string))
+	{
+		let decoded = STANDARD
+			.decode(credentials.trim())
+			.ok()
+			.and_then(|bytes| String::from_utf8(bytes).ok())
+			.ok_or_else(|| OidcError::InvalidClient("Malformed Basic credentials".to_string()))?;
+		let (client_id, client_secret) = decoded
+			.split_once(':')
+			.ok_or_else(|| OidcError::InvalidClient("Malformed Basic credentials".to_string()))?;
+		token_request.client_id = Some(client_id.to_string());
+		token_request.client_secret = Some(client_secret.to_string());
+	}
+	Ok(token_request)
+}
+
+fn json_response<T: Serialize>(response: Response, body: &T) -> Response {
+	response
+		.with_json(body)
+		.unwrap_or_else(|_| Response::internal_server_error())
+}
+
+fn error_response(error: &OidcError) -> Response {
+	let status = StatusCode::from_u16(error.status()).unwrap_or(StatusCode::BAD_REQUEST);
+	json_response(
+		Response::new(status),
+		&serde_json::json!({
+			"error": error.code(),
+			"error_description": error.description(),
+		}),
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	const REDIRECT_URI: &str = "https://spa.example.com/callback";
+	const VERIFIER: &str = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+
+	fn challenge() -> String {
+		URL_SAFE_NO_PAD.encode(Sha256::digest(VERIFIER.as_bytes()))
+	}
+
+	fn user() -> UserClaims {
+		UserClaims::new("42")
+			.claim("name", "Alice Smith")
+			.claim("email", "alice@example.com")
+	}
+
+	fn request(client_id: &str, scope: &str) -> AuthorizationRequest {
+		AuthorizationRequest {
+			response_type: "code".to_string(),
+			client_id: client_id.to_string(),
+			redirect_uri: REDIRECT_URI.to_string(),
+			scope: scope.to_string(),
+			state: Some("xyz".to_string()),
+			nonce: Some("n-0S6".to_string()),
+			code_challenge: Some(challenge()),
+			code_challenge_method: Some("S256".to_string()),
+		}
+	}
+
+	async fn provider() -> OidcProvider {
+		let provider =
+			OidcProvider::new(OidcProviderConfig::new("https://id.example.com/")).unwrap();
+		provider
+			.register_client(
+				OidcClient::public("spa", "Dashboard")
+					.with_redirect_uri(REDIRECT_URI)
+					.first_party(),
+			)
+			.await
+			.unwrap();
+		provider
+			.register_client(
+				OidcClient::confidential("partner", "s3cret", "Partner")
+					.with_redirect_uri(REDIRECT_URI),
+			)
+			.await
+			.unwrap();
+		provider
+	}
+
+	fn query_param(url: &str, name: &str) -> Option<String> {
+		url::Url::parse(url)
+			.unwrap()
+			.query_pairs()
+			.find(|(key, _)| key == name)
+			.map(|(_, value)| value.into_owned())
+	}
+
+	fn redirect(authorization: Authorization) -> String {
+		match authorization {
+			Authorization::Redirect(url) => url,
+			Authorization::ConsentRequired(_) => panic!("expected a redirect"),
+		}
+	}
+
+	fn exchange_request(code: String, verifier: &str) -> TokenRequest {
+		TokenRequest {
+			grant_type: "authorization_code".to_string(),
+			code: Some(code),
+			redirect_uri: Some(REDIRECT_URI.to_string()),
+			client_id: Some("spa".to_string()),
+			client_secret: None,
+			code_verifier: Some(verifier.to_string()),
+		}
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_code_flow_with_pkce_issues_verifiable_tokens() {
+		// Arrange
+		let provider = provider().await;
+		let url = redirect(
+			provider
+				.authorize(&request("spa", "openid email"), &user())
+				.await
+				.unwrap(),
+		);
+		let code = query_param(&url, "code").unwrap();
+
+		// Act
+		let tokens = provider
+			.exchange(&exchange_request(code, VERIFIER))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(query_param(&url, "state").as_deref(), Some("xyz"));
+		let kid = decode_header(&tokens.id_token).unwrap().kid.unwrap();
+		let jwk = provider.jwks().find_key(&kid).cloned().unwrap();
+		let mut validation = Validation::new(SIGNING_ALGORITHM);
+		validation.set_audience(&["spa"]);
+		let id_token = decode::<Map<String, Value>>(
+			&tokens.id_token,
+			&jwk.to_decoding_key().unwrap(),
+			&validation,
+		)
+		.unwrap()
+		.claims;
+		assert_eq!(id_token["iss"], "https://id.example.com");
+		assert_eq!(id_token["sub"], "42");
+		assert_eq!(id_token["nonce"], "n-0S6");
+		assert_eq!(id_token["email"], "alice@example.com");
+		assert!(id_token.get("name").is_none());
+		let userinfo = provider.userinfo(&tokens.access_token).await.unwrap();
+		assert_eq!(userinfo["sub"], "42");
+		assert_eq!(userinfo["email"], "alice@example.com");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_code_is_single_use_and_checks_verifier() {
+		// Arrange
+		let provider = provider().await;
+		let url = redirect(
+			provider
+				.authorize(&request("spa", "openid"), &user())
+				.await
+				.unwrap(),
+		);
+		let code = query_param(&url, "code").unwrap();
+
+		// Act
+		let wrong_verifier = provider
+			.exchange(&exchange_request(code.clone(), "not-the-verifier"))
+			.await;
+		let reused = provider.exchange(&exchange_request(code, VERIFIER)).await;
+
+		// Assert
+		assert!(matches!(wrong_verifier, Err(OidcError::InvalidGrant(_))));
+		assert!(matches!(reused, Err(OidcError::InvalidGrant(_))));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_public_client_without_pkce_is_redirected_with_error() {
+		// Arrange
+		let provider = provider().await;
+		let mut request = request("spa", "openid");
+		request.code_challenge = None;
+
+		// Act
+		let url = redirect(provider.authorize(&request, &user()).await.unwrap());
+
+		// Assert
+		assert_eq!(
+			query_param(&url, "error").as_deref(),
+			Some("invalid_request")
+		);
+		assert!(query_param(&url, "code").is_none());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_unknown_redirect_uri_is_not_redirected() {
+		// Arrange
+		let provider = provider().await;
+		let mut request = request("spa", "openid");
+		request.redirect_uri = "https://evil.example.com/".to_string();
+
+		// Act
+		let result = provider.authorize(&request, &user()).await;
+
+		// Assert
+		assert!(matches!(result, Err(OidcError::InvalidRequest(_))));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_third_party_client_requires_consent_once() {
+		// Arrange
+		let provider = provider().await;
+		let request = request("partner", "openid profile");
+
+		// Act
+		let first = provider.authorize(&request, &user()).await.unwrap();
+		let url = provider.consent(&request, &user(), true).await.unwrap();
+		let second = provider.authorize(&request, &user()).await.unwrap();
+
+		// Assert
+		let Authorization::ConsentRequired(screen) = first else {
+			panic!("expected a consent screen");
+		};
+		assert_eq!(screen.client_name, "Partner");
+		assert_eq!(screen.scopes[1].description, "See your name and profile");
+		assert!(query_param(&url, "code").is_some());
+		assert!(matches!(second, Authorization::Redirect(_)));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_denied_consent_redirects_with_access_denied() {
+		// Arrange
+		let provider = provider().await;
+
+		// Act
+		let url = provider
+			.consent(&request("partner", "openid"), &user(), false)
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(query_param(&url, "error").as_deref(), Some("access_denied"));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_access_token_survives_key_rotation() {
+		// Arrange
+		let provider = provider().await;
+		let url = redirect(
+			provider
+				.authorize(&request("spa", "openid"), &user())
+				.await
+				.unwrap(),
+		);
+		let code = query_param(&url, "code").unwrap();
+		let tokens = provider
+			.exchange(&exchange_request(code, VERIFIER))
+			.await
+			.unwrap();
+
+		// Act
+		provider.keys().rotate().unwrap();
+		let claims = provider.verify_access_token(&tokens.access_token);
+
+		// Assert
+		assert_eq!(claims.unwrap().aud, "spa");
+		assert_eq!(provider.jwks().keys.len(), 2);
+	}
+
+	#[rstest]
+	fn test_discovery_lists_endpoints() {
+		// Arrange
+		let provider =
+			OidcProvider::new(OidcProviderConfig::new("https://id.example.com")).unwrap();
+
+		// Act
+		let discovery = provider.discovery();
+
+		// Assert
+		assert_eq!(
+			discovery.token_endpoint,
+			"https://id.example.com/oauth/token"
+		);
+		assert_eq!(
+			discovery.jwks_uri,
+			"https://id.example.com/.well-known/jwks.json"
+		);
+		assert_eq!(
+			discovery.id_token_signing_alg_values_supported,
+			Some(vec!["ES256".to_string()])
+		);
+	}
+}
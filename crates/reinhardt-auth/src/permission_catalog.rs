@@ -0,0 +1,508 @@
+//! Content type and default permission catalog
+//!
+//! Every model of a migrated app gets a row in [`CONTENT_TYPE_TABLE`] and its
+//! four default permissions (`add`, `change`, `delete` and `view`) in
+//! [`PERMISSION_TABLE`], so that model permissions, group assignments and the
+//! admin all see the same catalog without manual seeding. Content types and
+//! default permissions of models that were removed from the app are deleted;
+//! custom permissions are never touched.
+//!
+//! This is the equivalent of Django's `create_contenttypes` and
+//! `create_permissions` receivers: `manage migrate` syncs the catalog of
+//! every migrated app from its `post_migrate` signal.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use reinhardt_auth::permission_catalog::PermissionCatalog;
+//!
+//! let plan = PermissionCatalog::new(connection).sync_app("blog").await?;
+//! println!("Created permissions: {:?}", plan.create_permissions);
+//! ```
+
+use reinhardt_apps::registry::get_models_for_app;
+use reinhardt_db::backends::DatabaseConnection;
+use reinhardt_db::backends::sql_build_helpers::build_inline_sql;
+use reinhardt_db::backends::types::TransactionExecutor;
+use reinhardt_db::contenttypes::permissions::PermissionAction;
+use reinhardt_db::migrations::{Result, SchemaEditor};
+use reinhardt_query::prelude::{Alias, ColumnDef, Expr, ExprTrait, Query};
+use std::collections::BTreeSet;
+use uuid::Uuid;
+
+/// Table storing content types
+pub const CONTENT_TYPE_TABLE: &str = "django_content_type";
+
+/// Table storing permissions ([`AuthPermission`](crate::AuthPermission))
+pub const PERMISSION_TABLE: &str = "auth_permission";
+
+/// Codenames of the default permissions of `model`, e.g. `add_article`
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_auth::permission_catalog::default_codenames;
+///
+/// let codenames = default_codenames("article");
+/// assert!(codenames.contains(&"change_article".to_string()));
+/// assert_eq!(codenames.len(), 4);
+/// ```
+pub fn default_codenames(model: &str) -> Vec<String> {
+	PermissionAction::all()
+		.iter()
+		.map(|action| format!("{}_{}", action.as_str(), model))
+		.collect()
+}
+
+/// Changes bringing the catalog of one app up to date
+///
+/// Model names are stored lowercase, as in content types.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CatalogPlan {
+	/// Models without a content type
+	pub create_content_types: Vec<String>,
+	/// Default permission codenames missing for existing models
+	pub create_permissions: Vec<String>,
+	/// Content types of models that no longer exist
+	pub remove_content_types: Vec<String>,
+	/// Default permission codenames of models that no longer exist
+	pub remove_permissions: Vec<String>,
+}
+
+impl CatalogPlan {
+	/// Plan the changes for an app whose models are `models`
+	///
+	/// `content_types` and `permissions` are the model names and permission
+	/// codenames currently stored for the app.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_auth::permission_catalog::CatalogPlan;
+	///
+	/// let plan = CatalogPlan::new(&["Article"], &["comment".to_string()], &[]);
+	/// assert_eq!(plan.create_content_types, vec!["article"]);
+	/// assert_eq!(plan.remove_content_types, vec!["comment"]);
+	/// assert_eq!(plan.create_permissions.len(), 4);
+	/// ```
+	pub fn new(models: &[&str], content_types: &[String], permissions: &[String]) -> Self {
+		let models: BTreeSet<String> = models.iter().map(|m| m.to_lowercase()).collect();
+		let content_types: BTreeSet<&str> = content_types.iter().map(String::as_str).collect();
+		let permissions: BTreeSet<&str> = permissions.iter().map(String::as_str).collect();
+
+		let create_content_types = models
+			.iter()
+			.filter(|model| !content_types.contains(model.as_str()))
+			.cloned()
+			.collect();
+		let create_permissions = models
+			.iter()
+			.flat_map(|model| default_codenames(model))
+			.filter(|codename| !permissions.contains(codename.as_str()))
+			.collect();
+		let remove_content_types: Vec<String> = content_types
+			.iter()
+			.filter(|model| !models.contains(**model))
+			.map(|model| model.to_string())
+			.collect();
+		let remove_permissions = remove_content_types
+			.iter()
+			.flat_map(|model| default_codenames(model))
+			.filter(|codename| permissions.contains(codename.as_str()))
+			.collect();
+
+		Self {
+			create_content_types,
+			create_permissions,
+			remove_content_types,
+			remove_permissions,
+		}
+	}
+
+	/// Whether the catalog is already up to date
+	pub fn is_empty(&self) -> bool {
+		self.create_content_types.is_empty()
+			&& self.create_permissions.is_empty()
+			&& self.remove_content_types.is_empty()
+			&& self.remove_permissions.is_empty()
+	}
+}
+
+/// Syncs the content types and default permissions of apps with their models
+pub struct PermissionCatalog {
+	connection: DatabaseConnection,
+}
+
+impl PermissionCatalog {
+	/// Create a catalog writing through `connection`
+	pub fn new(connection: DatabaseConnection) -> Self {
+		Self { connection }
+	}
+
+	/// Sync the catalog of `app_label` with its registered models
+	///
+	/// Returns the applied changes.
+	pub async fn sync_app(&self, app_label: &str) -> Result<CatalogPlan> {
+		let models: Vec<&str> = get_models_for_app(app_label)
+			.iter()
+			.map(|model| model.model_name)
+			.collect();
+		self.sync_models(app_label, &models).await
+	}
+
+	/// Sync the catalog of `app_label` with `models`
+	///
+	/// The content type table is created if it does not exist. Permissions
+	/// are left alone while [`PERMISSION_TABLE`] has not been migrated yet.
+	/// All changes are written in one transaction.
+	pub async fn sync_models(&self, app_label: &str, models: &[&str]) -> Result<CatalogPlan> {
+		self.ensure_content_type_table().await?;
+		let has_permissions = SchemaEditor::new(
+			self.connection.clone(),
+			false,
+			self.connection.database_type(),
+		)
+		.await?
+		.table_exists(PERMISSION_TABLE)
+		.await?;
+
+		let content_types = self.stored(CONTENT_TYPE_TABLE, "model", app_label).await?;
+		let permissions = if has_permissions {
+			self.stored(PERMISSION_TABLE, "codename", app_label).await?
+		} else {
+			Vec::new()
+		};
+
+		let mut plan = CatalogPlan::new(models, &content_types, &permissions);
+		if !has_permissions {
+			plan.create_permissions.clear();
+		}
+		if plan.is_empty() {
+			return Ok(plan);
+		}
+
+		let mut tx = self.connection.begin().await?;
+		match self.apply(tx.as_mut(), app_label, &plan).await {
+			Ok(()) => tx.commit().await?,
+			Err(e) => {
+				let _ = tx.rollback().await;
+				return Err(e);
+			}
+		}
+		Ok(plan)
+	}
+
+	/// Create [`CONTENT_TYPE_TABLE`] if it does not exist
+	pub async fn ensure_content_type_table(&self) -> Result<()> {
+		let db_type = self.connection.database_type();
+		let table = Query::create_table()
+			.table(Alias::new(CONTENT_TYPE_TABLE))
+			.if_not_exists()
+			.col(
+				ColumnDef::new("id")
+					.integer()
+					.not_null(true)
+					.auto_increment(true)
+					.primary_key(true),
+			)
+			.col(ColumnDef::new("app_label").string_len(100).not_null(true))
+			.col(ColumnDef::new("model").string_len(100).not_null(true))
+			.to_owned();
+		self.connection
+			.execute(&build_inline_sql(db_type, &table), vec![])
+			.await?;
+
+		let index = Query::create_index()
+			.if_not_exists()
+			.unique()
+			.name("django_content_type_app_label_model_unique")
+			.table(Alias::new(CONTENT_TYPE_TABLE))
+			.col(Alias::new("app_label"))
+			.col(Alias::new("model"))
+			.to_owned();
+		self.connection
+			.execute(&build_inline_sql(db_type, &index), vec![])
+			.await?;
+		Ok(())
+	}
+
+	/// Values of `column` in the rows of `table` belonging to `app_label`
+	async fn stored(&self, table: &str, column: &str, app_label: &str) -> Result<Vec<String>> {
+		let stmt = Query::select()
+			.column(Alias::new(column))
+			.from(Alias::new(table))
+			.and_where(Expr::col(Alias::new("app_label")).eq(app_label))
+			.to_owned();
+		let rows = self
+			.connection
+			.fetch_all(
+				&build_inline_sql(self.connection.database_type(), &stmt),
+				vec![],
+			)
+			.await?;
+		let mut values = Vec::with_capacity(rows.len());
+		for row in rows {
+			values.push(row.get::<String>(column)?);
+		}
+		Ok(values)
+	}
+
+	/// Write `plan` for `app_label`
+	async fn apply(
+		&self,
+		tx: &mut dyn TransactionExecutor,
+		app_label: &str,
+		plan: &CatalogPlan,
+	) -> Result<()> {
+		let db_type = self.connection.database_type();
+
+		for model in &plan.create_content_types {
+			let stmt = Query::insert()
+				.into_table(Alias::new(CONTENT_TYPE_TABLE))
+				.columns([Alias::new("app_label"), Alias::new("model")])
+				.values_panic([app_label.to_string(), model.clone()])
+				.to_owned();
+			tx.execute(&build_inline_sql(db_type, &stmt), vec![])
+				.await?;
+		}
+
+		for codename in &plan.create_permissions {
+			let name = match codename.split_once('_') {
+				Some((action, model)) => format!("Can {} {}", action, model),
+				None => codename.clone(),
+			};
+			let stmt = Query::insert()
+				.into_table(Alias::new(PERMISSION_TABLE))
+				.columns([
+					Alias::new("id"),
+					Alias::new("name"),
+					Alias::new("codename"),
+					Alias::new("app_label"),
+				])
+				.values_panic([
+					Uuid::new_v4().to_string(),
+					name,
+					codename.clone(),
+					app_label.to_string(),
+				])
+				.to_owned();
+			tx.execute(&build_inline_sql(db_type, &stmt), vec![])
+				.await?;
+		}
+
+		if !plan.remove_permissions.is_empty() {
+			let stmt = Query::delete()
+				.from_table(Alias::new(PERMISSION_TABLE))
+				.and_where(Expr::col(Alias::new("app_label")).eq(app_label))
+				.and_where(
+					Expr::col(Alias::new("codename"))
+						.is_in(plan.remove_permissions.iter().cloned()),
+				)
+				.to_owned();
+			tx.execute(&build_inline_sql(db_type, &stmt), vec![])
+				.await?;
+		}
+
+		if !plan.remove_content_types.is_empty() {
+			let stmt = Query::delete()
+				.from_table(Alias::new(CONTENT_TYPE_TABLE))
+				.and_where(Expr::col(Alias::new("app_label")).eq(app_label))
+				.and_where(
+					Expr::col(Alias::new("model")).is_in(plan.remove_content_types.iter().cloned()),
+				)
+				.to_owned();
+			tx.execute(&build_inline_sql(db_type, &stmt), vec![])
+				.await?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn strings(values: &[&str]) -> Vec<String> {
+		values.iter().map(|v| v.to_string()).collect()
+	}
+
+	#[rstest]
+	fn test_plan_creates_missing_rows() {
+		// Arrange
+		let content_types = strings(&["article"]);
+		let permissions = strings(&["add_article", "view_article"]);
+
+		// Act
+		let plan = CatalogPlan::new(&["Article", "Comment"], &content_types, &permissions);
+
+		// Assert
+		assert_eq!(plan.create_content_types, vec!["comment"]);
+		assert!(
+			plan.create_permissions
+				.contains(&"change_article".to_string())
+		);
+		assert!(
+			plan.create_permissions
+				.contains(&"delete_comment".to_string())
+		);
+		assert!(!plan.create_permissions.contains(&"add_article".to_string()));
+		assert_eq!(plan.create_permissions.len(), 6);
+		assert!(plan.remove_content_types.is_empty());
+	}
+
+	#[rstest]
+	fn test_plan_removes_stale_models_but_keeps_custom_permissions() {
+		// Arrange
+		let content_types = strings(&["article", "tag"]);
+		let permissions = strings(&["view_tag", "add_tag", "publish_article", "merge_tag"]);
+
+		// Act
+		let plan = CatalogPlan::new(&["article"], &content_types, &permissions);
+
+		// Assert
+		assert_eq!(plan.remove_content_types, vec!["tag"]);
+		assert_eq!(plan.remove_permissions, vec!["view_tag", "add_tag"]);
+	}
+
+	/// Catalog over a fresh SQLite database with an `auth_permission`
+	/// table holding `permissions` for the `blog` app
+	async fn catalog_with_permissions(permissions: &[&str]) -> PermissionCatalog {
+		let connection = DatabaseConnection::connect_sqlite("sqlite::memory:")
+			.await
+			.expect("failed to open sqlite :memory: connection");
+		connection
+			.execute(
+				"CREATE TABLE auth_permission (id TEXT PRIMARY KEY, name TEXT NOT NULL, \
+				 codename TEXT NOT NULL, app_label TEXT NOT NULL)",
+				vec![],
+			)
+			.await
+			.unwrap();
+		for (i, codename) in permissions.iter().enumerate() {
+			connection
+				.execute(
+					&format!(
+						"INSERT INTO auth_permission (id, name, codename, app_label) \
+						 VALUES ('{}', '{}', '{}', 'blog')",
+						i, codename, codename
+					),
+					vec![],
+				)
+				.await
+				.unwrap();
+		}
+		PermissionCatalog::new(connection)
+	}
+
+	/// Sorted values of `column` stored in `table` for the `blog` app
+	async fn stored_sorted(catalog: &PermissionCatalog, table: &str, column: &str) -> Vec<String> {
+		let mut values = catalog.stored(table, column, "blog").await.unwrap();
+		values.sort();
+		values
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_sync_inserts_default_permissions_into_existing_table() {
+		// Arrange
+		let catalog = catalog_with_permissions(&[]).await;
+
+		// Act
+		let plan = catalog.sync_models("blog", &["Article"]).await.unwrap();
+
+		// Assert
+		assert_eq!(plan.create_content_types, vec!["article"]);
+		assert_eq!(plan.create_permissions, default_codenames("article"));
+		assert_eq!(
+			stored_sorted(&catalog, PERMISSION_TABLE, "codename").await,
+			strings(&[
+				"add_article",
+				"change_article",
+				"delete_article",
+				"view_article"
+			])
+		);
+		assert_eq!(
+			stored_sorted(&catalog, CONTENT_TYPE_TABLE, "model").await,
+			strings(&["article"])
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_sync_completes_partial_catalog_and_keeps_custom_permissions() {
+		// Arrange
+		let catalog = catalog_with_permissions(&["add_article", "publish_article"]).await;
+
+		// Act
+		let plan = catalog.sync_models("blog", &["Article"]).await.unwrap();
+		let rerun = catalog.sync_models("blog", &["Article"]).await.unwrap();
+
+		// Assert
+		assert_eq!(
+			plan.create_permissions,
+			strings(&["view_article", "change_article", "delete_article"])
+		);
+		assert_eq!(rerun, CatalogPlan::default());
+		assert_eq!(
+			stored_sorted(&catalog, PERMISSION_TABLE, "codename").await,
+			strings(&[
+				"add_article",
+				"change_article",
+				"delete_article",
+				"publish_article",
+				"view_article",
+			])
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_sync_deletes_stale_default_permissions() {
+		// Arrange
+		let catalog =
+			catalog_with_permissions(&["view_tag", "add_tag", "change_tag", "merge_tag"]).await;
+		catalog
+			.sync_models("blog", &["Article", "Tag"])
+			.await
+			.unwrap();
+
+		// Act
+		let plan = catalog.sync_models("blog", &["Article"]).await.unwrap();
+
+		// Assert
+		assert_eq!(plan.remove_content_types, vec!["tag"]);
+		assert_eq!(
+			plan.remove_permissions,
+			strings(&["view_tag", "add_tag", "change_tag", "delete_tag"])
+		);
+		assert_eq!(
+			stored_sorted(&catalog, CONTENT_TYPE_TABLE, "model").await,
+			strings(&["article"])
+		);
+		assert_eq!(
+			stored_sorted(&catalog, PERMISSION_TABLE, "codename").await,
+			strings(&[
+				"add_article",
+				"change_article",
+				"delete_article",
+				"merge_tag",
+				"view_article",
+			])
+		);
+	}
+
+	#[rstest]
+	fn test_plan_is_empty_when_catalog_is_current() {
+		// Arrange
+		let content_types = strings(&["article"]);
+		let permissions = default_codenames("article");
+
+		// Act
+		let plan = CatalogPlan::new(&["Article"], &content_types, &permissions);
+
+		// Assert
+		assert!(plan.is_empty());
+	}
+}
@@ -167,11 +167,11 @@ impl<B: ThrottleBackend> RateLimitPermission<B> {
 
 	/// Extract IP address from request
 	///
-	/// Delegates to `Request::get_client_ip()` which only trusts proxy headers
-	/// (X-Forwarded-For, X-Real-IP) when the request originates from a configured
-	/// trusted proxy. Falls back to the actual connection IP otherwise.
+	/// Delegates to `Request::client_ip()`, which only trusts proxy headers
+	/// (Forwarded, X-Forwarded-For, X-Real-IP) when the request originates from
+	/// a configured trusted proxy. Falls back to the actual connection IP otherwise.
 	fn extract_ip(&self, context: &PermissionContext) -> Option<String> {
-		context.request.get_client_ip().map(|ip| ip.to_string())
+		context.request.client_ip().map(|ip| ip.to_string())
 	}
 
 	/// Extract user ID from context
@@ -113,7 +113,7 @@ pub use analytics::PrometheusAnalytics;
 
 #[cfg(feature = "replication")]
 pub use replication::{ReplicatedSessionBackend, ReplicationConfig, ReplicationStrategy};
-pub use session::Session;
+pub use session::{Session, SessionNamespace};
 pub use tenant::{TenantConfig, TenantSessionBackend, TenantSessionOperations};
 
 #[cfg(feature = "middleware")]
@@ -22,6 +22,7 @@
 //!     httponly: true,
 //!     samesite: SameSite::Lax,
 //!     max_age: Some(Duration::from_secs(3600)),
+//!     max_payload_size: Some(64 * 1024),
 //! };
 //!
 //! // Create middleware
@@ -112,6 +113,7 @@ impl SameSite {
 ///     httponly: true,
 ///     samesite: SameSite::Strict,
 ///     max_age: Some(Duration::from_secs(7200)),
+///     max_payload_size: Some(16 * 1024),
 /// };
 /// ```
 #[derive(Debug, Clone)]
@@ -130,6 +132,9 @@ pub struct HttpSessionConfig {
 	pub samesite: SameSite,
 	/// Maximum age for the cookie
 	pub max_age: Option<Duration>,
+	/// Session payload size in bytes above which saving logs a warning
+	/// (None = no limit)
+	pub max_payload_size: Option<usize>,
 }
 
 #[cfg(feature = "middleware")]
@@ -145,6 +150,7 @@ impl Default for HttpSessionConfig {
 	/// assert_eq!(config.cookie_name, "sessionid");
 	/// assert_eq!(config.cookie_path, "/");
 	/// assert_eq!(config.samesite, SameSite::Lax);
+	/// assert_eq!(config.max_payload_size, Some(64 * 1024));
 	/// ```
 	fn default() -> Self {
 		Self {
@@ -155,6 +161,7 @@ impl Default for HttpSessionConfig {
 			httponly: true,
 			samesite: SameSite::Lax,
 			max_age: None,
+			max_payload_size: Some(64 * 1024),
 		}
 	}
 }
@@ -252,13 +259,14 @@ impl<B: SessionBackend + 'static> Middleware for SessionMiddleware<B> {
 		// Load session from cookie
 		let session_key = self.get_session_key_from_cookie(&request);
 
-		let session: Session<B> = if let Some(key) = session_key {
+		let mut session: Session<B> = if let Some(key) = session_key {
 			Session::from_key(self.backend.clone(), key)
 				.await
 				.unwrap_or_else(|_| Session::new(self.backend.clone()))
 		} else {
 			Session::new(self.backend.clone())
 		};
+		session.set_size_limit(self.config.max_payload_size);
 
 		// Store session in request extensions wrapped in Arc<RwLock> for shared access
 		let shared_session = Arc::new(RwLock::new(session));
@@ -415,6 +423,7 @@ mod tests {
 			httponly: true,
 			samesite: SameSite::Strict,
 			max_age: Some(Duration::from_secs(3600)),
+			max_payload_size: None,
 		};
 		let middleware = SessionMiddleware::new(backend, config);
 
@@ -6,6 +6,9 @@ use serde_json::Value;
 use std::collections::HashMap;
 use uuid::Uuid;
 
+/// Separator between an app namespace and the key inside it
+pub const NAMESPACE_SEPARATOR: char = ':';
+
 /// Django-style session object with dictionary-like interface
 ///
 /// # Example
@@ -48,6 +51,8 @@ pub struct Session<B: SessionBackend> {
 	last_activity: Option<chrono::DateTime<chrono::Utc>>,
 	/// Session timeout in seconds (default: 1800 = 30 minutes)
 	timeout: u64,
+	/// Payload size in bytes above which saving logs a warning
+	size_limit: Option<usize>,
 }
 
 impl<B: SessionBackend> Session<B> {
@@ -71,6 +76,7 @@ impl<B: SessionBackend> Session<B> {
 			is_accessed: false,
 			last_activity: Some(chrono::Utc::now()),
 			timeout: 1800, // 30 minutes (Django default)
+			size_limit: None,
 		}
 	}
 
@@ -105,6 +111,7 @@ impl<B: SessionBackend> Session<B> {
 			is_accessed: true,
 			last_activity: Some(chrono::Utc::now()),
 			timeout: 1800, // 30 minutes (Django default)
+			size_limit: None,
 		})
 	}
 
@@ -140,6 +147,9 @@ impl<B: SessionBackend> Session<B> {
 
 	/// Set a value in the session
 	///
+	/// The session is only marked as modified when the stored value changes,
+	/// so writing back an identical value does not trigger a backend save.
+	///
 	/// # Example
 	///
 	/// ```rust
@@ -160,8 +170,10 @@ impl<B: SessionBackend> Session<B> {
 		T: Serialize,
 	{
 		let json_value = serde_json::to_value(value)?;
-		self.data.insert(key.to_string(), json_value);
-		self.is_modified = true;
+		if self.data.get(key) != Some(&json_value) {
+			self.data.insert(key.to_string(), json_value);
+			self.is_modified = true;
+		}
 		self.is_accessed = true;
 		self.last_activity = Some(chrono::Utc::now());
 		Ok(())
@@ -188,9 +200,12 @@ impl<B: SessionBackend> Session<B> {
 	/// # }
 	/// ```
 	pub fn delete(&mut self, key: &str) -> Option<Value> {
-		self.is_modified = true;
 		self.is_accessed = true;
-		self.data.remove(key)
+		let removed = self.data.remove(key);
+		if removed.is_some() {
+			self.is_modified = true;
+		}
+		removed
 	}
 
 	/// Check if a key exists in the session
@@ -391,6 +406,16 @@ impl<B: SessionBackend> Session<B> {
 		}
 
 		let key = self.get_or_create_key().to_string();
+		if let Some(limit) = self.size_limit {
+			let size = self.payload_size();
+			if size > limit {
+				tracing::warn!(
+					size,
+					limit,
+					"Session payload exceeds the configured size limit; consider storing less data in the session"
+				);
+			}
+		}
 		self.backend
 			.save(&key, &self.data, Some(self.timeout))
 			.await?;
@@ -619,6 +644,80 @@ impl<B: SessionBackend> Session<B> {
 		self.is_modified = false;
 	}
 
+	/// Access the keys of one app through a namespace
+	///
+	/// Keys are stored as `<app>:<key>`, so apps sharing a session cannot
+	/// overwrite each other's data by picking the same key.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use reinhardt_auth::sessions::Session;
+	/// use reinhardt_auth::sessions::backends::InMemorySessionBackend;
+	///
+	/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+	/// let backend = InMemorySessionBackend::new();
+	/// let mut session = Session::new(backend);
+	///
+	/// session.namespace("cart").set("items", vec![1, 2])?;
+	/// session.namespace("wishlist").set("items", vec![3])?;
+	///
+	/// let items: Vec<i32> = session.namespace("cart").get("items")?.unwrap();
+	/// assert_eq!(items, vec![1, 2]);
+	/// assert!(session.contains_key("wishlist:items"));
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn namespace<'a>(&'a mut self, app: &'a str) -> SessionNamespace<'a, B> {
+		SessionNamespace { session: self, app }
+	}
+
+	/// Set the payload size (in bytes) above which [`save`](Self::save) logs a warning
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use reinhardt_auth::sessions::Session;
+	/// use reinhardt_auth::sessions::backends::InMemorySessionBackend;
+	///
+	/// let backend = InMemorySessionBackend::new();
+	/// let mut session = Session::new(backend);
+	///
+	/// session.set_size_limit(Some(4096));
+	/// assert_eq!(session.size_limit(), Some(4096));
+	/// ```
+	pub fn set_size_limit(&mut self, limit: Option<usize>) {
+		self.size_limit = limit;
+	}
+
+	/// Get the payload size warning limit in bytes
+	pub fn size_limit(&self) -> Option<usize> {
+		self.size_limit
+	}
+
+	/// Size in bytes of the session data serialized as JSON
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use reinhardt_auth::sessions::Session;
+	/// use reinhardt_auth::sessions::backends::InMemorySessionBackend;
+	///
+	/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+	/// let backend = InMemorySessionBackend::new();
+	/// let mut session = Session::new(backend);
+	///
+	/// session.set("a", 1)?;
+	/// assert_eq!(session.payload_size(), r#"{"a":1}"#.len());
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn payload_size(&self) -> usize {
+		serde_json::to_vec(&self.data)
+			.map(|bytes| bytes.len())
+			.unwrap_or(0)
+	}
+
 	/// Set session timeout in seconds
 	///
 	/// # Example
@@ -754,6 +853,66 @@ impl<B: SessionBackend> Session<B> {
 	}
 }
 
+/// Session view scoped to one app's keys
+///
+/// Created by [`Session::namespace`].
+pub struct SessionNamespace<'a, B: SessionBackend> {
+	session: &'a mut Session<B>,
+	app: &'a str,
+}
+
+impl<B: SessionBackend> SessionNamespace<'_, B> {
+	fn full_key(&self, key: &str) -> String {
+		format!("{}{}{}", self.app, NAMESPACE_SEPARATOR, key)
+	}
+
+	/// Get a value from this namespace
+	pub fn get<T>(&mut self, key: &str) -> Result<Option<T>, serde_json::Error>
+	where
+		T: for<'de> Deserialize<'de>,
+	{
+		let key = self.full_key(key);
+		self.session.get(&key)
+	}
+
+	/// Set a value in this namespace
+	pub fn set<T>(&mut self, key: &str, value: T) -> Result<(), serde_json::Error>
+	where
+		T: Serialize,
+	{
+		let key = self.full_key(key);
+		self.session.set(&key, value)
+	}
+
+	/// Delete a key from this namespace
+	pub fn delete(&mut self, key: &str) -> Option<Value> {
+		let key = self.full_key(key);
+		self.session.delete(&key)
+	}
+
+	/// Check if a key exists in this namespace
+	pub fn contains_key(&self, key: &str) -> bool {
+		self.session.contains_key(&self.full_key(key))
+	}
+
+	/// Keys in this namespace, without the app prefix
+	pub fn keys(&mut self) -> Vec<String> {
+		let prefix = self.full_key("");
+		self.session
+			.keys()
+			.into_iter()
+			.filter_map(|key| key.strip_prefix(&prefix).map(str::to_string))
+			.collect()
+	}
+
+	/// Remove every key in this namespace
+	pub fn clear(&mut self) {
+		for key in self.keys() {
+			self.delete(&key);
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -1121,4 +1280,40 @@ mod tests {
 		// Session key should not be created since save didn't actually persist
 		assert!(session.session_key().is_none());
 	}
+
+	#[tokio::test]
+	async fn test_session_set_unchanged_value_does_not_mark_modified() {
+		let backend = InMemorySessionBackend::new();
+		let mut session = Session::new(backend);
+
+		session.set("user_id", 42).unwrap();
+		session.mark_unmodified();
+
+		session.set("user_id", 42).unwrap();
+		session.delete("missing");
+		assert!(!session.is_modified());
+
+		session.set("user_id", 43).unwrap();
+		assert!(session.is_modified());
+	}
+
+	#[tokio::test]
+	async fn test_session_namespaces_are_isolated() {
+		let backend = InMemorySessionBackend::new();
+		let mut session = Session::new(backend);
+
+		session.namespace("cart").set("items", vec![1, 2]).unwrap();
+		session.namespace("wishlist").set("items", vec![3]).unwrap();
+		session.namespace("cart").set("coupon", "SAVE10").unwrap();
+
+		let items: Vec<i32> = session.namespace("wishlist").get("items").unwrap().unwrap();
+		assert_eq!(items, vec![3]);
+		let mut cart_keys = session.namespace("cart").keys();
+		cart_keys.sort();
+		assert_eq!(cart_keys, vec!["coupon".to_string(), "items".to_string()]);
+
+		session.namespace("cart").clear();
+		assert!(!session.namespace("cart").contains_key("items"));
+		assert!(session.namespace("wishlist").contains_key("items"));
+	}
 }
@@ -7,17 +7,32 @@ use std::collections::HashMap;
 use super::pkce::CodeVerifier;
 use crate::social::core::{OAuth2Client, ProviderConfig, SocialAuthError, TokenResponse};
 use crate::social::url_validation::validate_endpoint_url;
+use reinhardt_utils::circuit_breaker::{CircuitBreaker, CircuitBreakerError};
 
 /// Token exchange flow handler
 pub struct TokenExchangeFlow {
 	client: OAuth2Client,
 	config: ProviderConfig,
+	circuit_breaker: Option<CircuitBreaker>,
 }
 
 impl TokenExchangeFlow {
 	/// Creates a new token exchange flow
 	pub fn new(client: OAuth2Client, config: ProviderConfig) -> Self {
-		Self { client, config }
+		Self {
+			client,
+			config,
+			circuit_breaker: None,
+		}
+	}
+
+	/// Guards token requests with a circuit breaker, keyed by the token endpoint host
+	///
+	/// While a provider's circuit is open, [`exchange`](Self::exchange) fails
+	/// with [`SocialAuthError::Network`] without contacting the provider.
+	pub fn with_circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> Self {
+		self.circuit_breaker = Some(circuit_breaker);
+		self
 	}
 
 	/// Exchanges authorization code for access token
@@ -51,15 +66,42 @@ impl TokenExchangeFlow {
 			params.insert("code_verifier", verifier.as_str());
 		}
 
-		let response = self
+		let request = self
 			.client
 			.client()
 			.post(token_endpoint)
 			.header("Accept", "application/json")
 			.form(&params)
-			.send()
-			.await
-			.map_err(|e| SocialAuthError::Network(e.to_string()))?;
+			.send();
+
+		let response = match &self.circuit_breaker {
+			Some(circuit_breaker) => {
+				let target = url::Url::parse(token_endpoint)
+					.ok()
+					.and_then(|url| url.host_str().map(str::to_string))
+					.unwrap_or_else(|| token_endpoint.to_string());
+				// Provider-side failures (5xx) count against the circuit;
+				// rejected grants (4xx) do not.
+				circuit_breaker
+					.call(&target, async {
+						let response = request.await?;
+						if response.status().is_server_error() {
+							return response.error_for_status();
+						}
+						Ok::<_, reqwest::Error>(response)
+					})
+					.await
+					.map_err(|e| match e {
+						CircuitBreakerError::Open(open) => {
+							SocialAuthError::Network(open.to_string())
+						}
+						CircuitBreakerError::Inner(e) => SocialAuthError::Network(e.to_string()),
+					})?
+			}
+			None => request
+				.await
+				.map_err(|e| SocialAuthError::Network(e.to_string()))?,
+		};
 
 		if !response.status().is_success() {
 			let status = response.status();
@@ -100,4 +142,30 @@ mod tests {
 		// Just verify it constructs without panic
 		assert!(std::mem::size_of_val(&flow) > 0);
 	}
+
+	#[tokio::test]
+	async fn test_exchange_fails_fast_when_circuit_open() {
+		use reinhardt_utils::circuit_breaker::CircuitBreakerPolicy;
+
+		let breaker = CircuitBreaker::in_memory(
+			"oauth_token",
+			CircuitBreakerPolicy::default().with_minimum_calls(1),
+		);
+		breaker.record_failure("oauth.example.com").await;
+		let config = ProviderConfig::google(
+			"test_client".to_string(),
+			"test_secret".to_string(),
+			"https://example.com/callback".to_string(),
+		);
+		let flow =
+			TokenExchangeFlow::new(OAuth2Client::new(), config).with_circuit_breaker(breaker);
+
+		let result = flow
+			.exchange("https://oauth.example.com/token", "code", None)
+			.await;
+
+		assert!(
+			matches!(result, Err(SocialAuthError::Network(msg)) if msg.contains("oauth.example.com"))
+		);
+	}
 }
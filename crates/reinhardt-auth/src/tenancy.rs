@@ -0,0 +1,221 @@
+//! Tenant middleware
+//!
+//! Resolves the tenant of each request and runs the rest of the chain inside
+//! [`with_tenant`], so that queries on `#[model(tenant_field = "...")]`
+//! models only see that tenant's rows.
+
+use async_trait::async_trait;
+use hyper::header::{HOST, HeaderName};
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_db::orm::tenancy::{TenantId, with_tenant};
+use reinhardt_http::{Handler, Middleware, Request, Response};
+use std::sync::Arc;
+
+/// Strategy resolving the tenant a request belongs to
+///
+/// Implemented by [`HeaderTenantResolver`], [`SubdomainTenantResolver`] and
+/// any `Fn(&Request) -> Option<TenantId>` closure.
+pub trait TenantResolver: Send + Sync {
+	/// The tenant of `request`, if any
+	fn resolve(&self, request: &Request) -> Option<TenantId>;
+}
+
+impl<F> TenantResolver for F
+where
+	F: Fn(&Request) -> Option<TenantId> + Send + Sync,
+{
+	fn resolve(&self, request: &Request) -> Option<TenantId> {
+		self(request)
+	}
+}
+
+/// Resolves the tenant from a request header (`X-Tenant-ID` by default)
+///
+/// Only suitable behind a proxy or gateway that sets the header itself;
+/// otherwise clients can pick their tenant.
+#[derive(Debug, Clone)]
+pub struct HeaderTenantResolver {
+	header: HeaderName,
+}
+
+impl HeaderTenantResolver {
+	/// Resolve the tenant from `header`
+	pub fn new(header: HeaderName) -> Self {
+		Self { header }
+	}
+}
+
+impl Default for HeaderTenantResolver {
+	fn default() -> Self {
+		Self::new(HeaderName::from_static("x-tenant-id"))
+	}
+}
+
+impl TenantResolver for HeaderTenantResolver {
+	fn resolve(&self, request: &Request) -> Option<TenantId> {
+		let value = request.headers.get(&self.header)?.to_str().ok()?.trim();
+		(!value.is_empty()).then(|| TenantId::parse(value))
+	}
+}
+
+/// Resolves the tenant from the subdomain of the `Host` header
+///
+/// With base domain `example.com`, `acme.example.com` resolves to tenant
+/// `acme`; the bare base domain and other hosts resolve to no tenant.
+#[derive(Debug, Clone)]
+pub struct SubdomainTenantResolver {
+	base_domain: String,
+}
+
+impl SubdomainTenantResolver {
+	/// Resolve tenants from subdomains of `base_domain`
+	pub fn new(base_domain: impl Into<String>) -> Self {
+		Self {
+			base_domain: base_domain.into().trim_start_matches('.').to_lowercase(),
+		}
+	}
+}
+
+impl TenantResolver for SubdomainTenantResolver {
+	fn resolve(&self, request: &Request) -> Option<TenantId> {
+		let host = request.headers.get(HOST)?.to_str().ok()?;
+		let host = host.split(':').next()?.to_lowercase();
+		let subdomain = host
+			.strip_suffix(&self.base_domain)?
+			.strip_suffix('.')
+			.filter(|subdomain| !subdomain.is_empty() && !subdomain.contains('.'))?;
+		Some(TenantId::parse(subdomain))
+	}
+}
+
+/// Middleware scoping each request to its tenant
+///
+/// Requests without a resolvable tenant run outside of any tenant scope,
+/// where tenant-bound models match no rows and reject writes; call
+/// [`require_tenant`](Self::require_tenant) to answer them with 404 instead.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_auth::tenancy::{SubdomainTenantResolver, TenantMiddleware};
+///
+/// let middleware = TenantMiddleware::new(SubdomainTenantResolver::new("example.com"))
+///     .require_tenant();
+/// ```
+#[derive(Clone)]
+pub struct TenantMiddleware {
+	resolver: Arc<dyn TenantResolver>,
+	required: bool,
+}
+
+impl TenantMiddleware {
+	/// Create the middleware resolving tenants with `resolver`
+	pub fn new(resolver: impl TenantResolver + 'static) -> Self {
+		Self {
+			resolver: Arc::new(resolver),
+			required: false,
+		}
+	}
+
+	/// Reject requests without a resolvable tenant with 404 Not Found
+	pub fn require_tenant(mut self) -> Self {
+		self.required = true;
+		self
+	}
+}
+
+#[async_trait]
+impl Middleware for TenantMiddleware {
+	async fn process(&self, request: Request, next: Arc<dyn Handler>) -> Result<Response> {
+		match self.resolver.resolve(&request) {
+			Some(tenant) => with_tenant(tenant, next.handle(request)).await,
+			None if self.required => Err(Error::NotFound("Unknown tenant".to_string())),
+			None => next.handle(request).await,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bytes::Bytes;
+	use hyper::{Method, StatusCode};
+	use reinhardt_db::orm::tenancy::current_tenant;
+	use rstest::rstest;
+
+	struct TenantEcho;
+
+	#[async_trait]
+	impl Handler for TenantEcho {
+		async fn handle(&self, _request: Request) -> Result<Response> {
+			let tenant = current_tenant().map_or_else(|| "none".to_string(), |t| t.to_string());
+			Ok(Response::new(StatusCode::OK).with_body(Bytes::from(tenant)))
+		}
+	}
+
+	fn request(header: HeaderName, value: &str) -> Request {
+		Request::builder()
+			.method(Method::GET)
+			.uri("/invoices")
+			.header(header, value)
+			.body(Bytes::new())
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	#[case::subdomain("acme.example.com", "acme")]
+	#[case::with_port("acme.example.com:8000", "acme")]
+	#[case::base_domain("example.com", "none")]
+	#[case::nested_subdomain("a.b.example.com", "none")]
+	#[case::other_domain("acme.example.org", "none")]
+	#[tokio::test]
+	async fn tenant_follows_subdomain(#[case] host: &str, #[case] expected: &str) {
+		// Arrange
+		let middleware = TenantMiddleware::new(SubdomainTenantResolver::new("example.com"));
+
+		// Act
+		let response = middleware
+			.process(request(HOST, host), Arc::new(TenantEcho))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.body, Bytes::from(expected.to_string()));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn tenant_follows_header() {
+		// Arrange
+		let middleware = TenantMiddleware::new(HeaderTenantResolver::default());
+
+		// Act
+		let response = middleware
+			.process(
+				request(HeaderName::from_static("x-tenant-id"), "42"),
+				Arc::new(TenantEcho),
+			)
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.body, Bytes::from("42"));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn required_tenant_rejects_unresolved_requests() {
+		// Arrange
+		let middleware =
+			TenantMiddleware::new(SubdomainTenantResolver::new("example.com")).require_tenant();
+
+		// Act
+		let result = middleware
+			.process(request(HOST, "example.com"), Arc::new(TenantEcho))
+			.await;
+
+		// Assert
+		assert!(matches!(result, Err(Error::NotFound(_))));
+	}
+}
@@ -0,0 +1,360 @@
+//! Asset build steps for CSS/JS toolchains (Tailwind, PostCSS, esbuild, ...).
+//!
+//! Steps are external commands registered through `inventory` and run by the
+//! built-in commands:
+//!
+//! - `collectstatic` runs every step once before collecting, and collects
+//!   each step's [`output_dir`](AssetBuildStep::output_dir) alongside the
+//!   configured static directories so the outputs end up in the manifest.
+//! - `runserver` runs every step once at startup. With hot-reload enabled the
+//!   steps run in watch mode instead, for as long as the server runs.
+//!
+//! ```rust,ignore
+//! use reinhardt::commands::{AssetBuildStep, AssetBuildStepRegistration};
+//!
+//! reinhardt::inventory::submit! {
+//!     AssetBuildStepRegistration::new(|| {
+//!         AssetBuildStep::tailwind("static/src/app.css", "static/css/app.css").arg("--minify")
+//!     })
+//! }
+//!
+//! reinhardt::inventory::submit! {
+//!     AssetBuildStepRegistration::new(|| {
+//!         AssetBuildStep::esbuild("frontend/main.ts", "build/js").output_dir("build")
+//!     })
+//! }
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+
+/// Errors raised while running asset build steps.
+#[derive(Debug, thiserror::Error)]
+pub enum AssetBuildError {
+	/// The step's program could not be started.
+	#[error("Asset build step '{step}' could not start `{program}`: {source}")]
+	Spawn {
+		/// Name of the step.
+		step: String,
+		/// Program the step runs.
+		program: String,
+		/// Underlying I/O error.
+		source: std::io::Error,
+	},
+	/// The step's program exited unsuccessfully.
+	#[error("Asset build step '{step}' failed: {status}")]
+	Failed {
+		/// Name of the step.
+		step: String,
+		/// Exit status of the program.
+		status: ExitStatus,
+	},
+}
+
+/// An external command producing static assets.
+#[derive(Debug, Clone)]
+pub struct AssetBuildStep {
+	name: String,
+	program: String,
+	args: Vec<String>,
+	watch_args: Vec<String>,
+	cwd: Option<PathBuf>,
+	output_dir: Option<PathBuf>,
+}
+
+impl AssetBuildStep {
+	/// Create a step running `program`.
+	pub fn new(name: impl Into<String>, program: impl Into<String>) -> Self {
+		Self {
+			name: name.into(),
+			program: program.into(),
+			args: Vec::new(),
+			watch_args: Vec::new(),
+			cwd: None,
+			output_dir: None,
+		}
+	}
+
+	/// Tailwind CLI compiling `input` into `output`, watched with `--watch`.
+	pub fn tailwind(input: impl AsRef<Path>, output: impl AsRef<Path>) -> Self {
+		Self::new("tailwind", "tailwindcss")
+			.arg("-i")
+			.arg(input.as_ref().to_string_lossy())
+			.arg("-o")
+			.arg(output.as_ref().to_string_lossy())
+			.watch_arg("--watch")
+	}
+
+	/// esbuild bundling `entry` into `outdir`, watched with `--watch`.
+	pub fn esbuild(entry: impl AsRef<Path>, outdir: impl AsRef<Path>) -> Self {
+		Self::new("esbuild", "esbuild")
+			.arg(entry.as_ref().to_string_lossy())
+			.arg("--bundle")
+			.arg(format!("--outdir={}", outdir.as_ref().display()))
+			.watch_arg("--watch")
+	}
+
+	/// Append an argument used in both one-shot and watch mode.
+	pub fn arg(mut self, arg: impl Into<String>) -> Self {
+		self.args.push(arg.into());
+		self
+	}
+
+	/// Append an argument used only in watch mode.
+	///
+	/// Steps without watch arguments run once when started in watch mode.
+	pub fn watch_arg(mut self, arg: impl Into<String>) -> Self {
+		self.watch_args.push(arg.into());
+		self
+	}
+
+	/// Run the program in `dir` instead of the current directory.
+	pub fn cwd(mut self, dir: impl Into<PathBuf>) -> Self {
+		self.cwd = Some(dir.into());
+		self
+	}
+
+	/// Collect `dir` as an additional static files directory.
+	///
+	/// Only needed when the step writes outside the configured static
+	/// directories; relative paths are resolved against [`cwd`](Self::cwd).
+	pub fn output_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+		self.output_dir = Some(dir.into());
+		self
+	}
+
+	/// Name of the step, used in logs and errors.
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Directory holding the step's outputs, if collected separately.
+	pub fn resolved_output_dir(&self) -> Option<PathBuf> {
+		let dir = self.output_dir.as_ref()?;
+		Some(match &self.cwd {
+			Some(cwd) if dir.is_relative() => cwd.join(dir),
+			_ => dir.clone(),
+		})
+	}
+
+	/// Build the command for one-shot (`watch == false`) or watch mode.
+	pub fn command(&self, watch: bool) -> Command {
+		let mut cmd = Command::new(&self.program);
+		cmd.args(&self.args);
+		if watch {
+			cmd.args(&self.watch_args);
+		}
+		if let Some(cwd) = &self.cwd {
+			cmd.current_dir(cwd);
+		}
+		cmd
+	}
+
+	/// Run the step once and wait for it to finish.
+	pub fn run(&self) -> Result<(), AssetBuildError> {
+		let status = self
+			.command(false)
+			.stdin(Stdio::null())
+			.status()
+			.map_err(|source| self.spawn_error(source))?;
+		if status.success() {
+			Ok(())
+		} else {
+			Err(AssetBuildError::Failed {
+				step: self.name.clone(),
+				status,
+			})
+		}
+	}
+
+	/// Start the step in watch mode.
+	///
+	/// The process is killed when the returned [`AssetWatcher`] is dropped.
+	/// Its stdin stays open because some watchers (Tailwind, esbuild) exit
+	/// once stdin is closed.
+	pub fn watch(&self) -> Result<AssetWatcher, AssetBuildError> {
+		let child = tokio::process::Command::from(self.command(true))
+			.stdin(Stdio::piped())
+			.kill_on_drop(true)
+			.spawn()
+			.map_err(|source| self.spawn_error(source))?;
+		Ok(AssetWatcher {
+			name: self.name.clone(),
+			child,
+		})
+	}
+
+	fn spawn_error(&self, source: std::io::Error) -> AssetBuildError {
+		AssetBuildError::Spawn {
+			step: self.name.clone(),
+			program: self.program.clone(),
+			source,
+		}
+	}
+}
+
+/// A step running in watch mode, killed on drop.
+#[derive(Debug)]
+pub struct AssetWatcher {
+	name: String,
+	child: tokio::process::Child,
+}
+
+impl AssetWatcher {
+	/// Name of the watched step.
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// OS process id of the watcher, `None` once it has exited.
+	pub fn id(&self) -> Option<u32> {
+		self.child.id()
+	}
+}
+
+/// Compile-time registration entry for asset build steps.
+///
+/// Submit one with `inventory::submit!` per step.
+pub struct AssetBuildStepRegistration {
+	/// Factory producing the step.
+	pub create: fn() -> AssetBuildStep,
+}
+
+impl AssetBuildStepRegistration {
+	/// Register the step produced by `create`.
+	pub const fn new(create: fn() -> AssetBuildStep) -> Self {
+		Self { create }
+	}
+}
+
+inventory::collect!(AssetBuildStepRegistration);
+
+/// All asset build steps registered via `inventory`.
+pub fn registered_asset_steps() -> Vec<AssetBuildStep> {
+	inventory::iter::<AssetBuildStepRegistration>
+		.into_iter()
+		.map(|reg| (reg.create)())
+		.collect()
+}
+
+/// Run `steps` once, in order, stopping at the first failure.
+pub fn run_asset_steps(steps: &[AssetBuildStep]) -> Result<(), AssetBuildError> {
+	steps.iter().try_for_each(AssetBuildStep::run)
+}
+
+/// Start every step of `steps` in watch mode.
+///
+/// Watchers already started are killed when a later step fails to start.
+pub fn spawn_asset_watchers(
+	steps: &[AssetBuildStep],
+) -> Result<Vec<AssetWatcher>, AssetBuildError> {
+	steps.iter().map(AssetBuildStep::watch).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn args(cmd: &Command) -> Vec<String> {
+		cmd.get_args()
+			.map(|arg| arg.to_string_lossy().into_owned())
+			.collect()
+	}
+
+	#[rstest]
+	fn test_tailwind_preset_adds_watch_flag_only_in_watch_mode() {
+		// Arrange
+		let step = AssetBuildStep::tailwind("static/src/app.css", "static/css/app.css");
+
+		// Act
+		let once = step.command(false);
+		let watch = step.command(true);
+
+		// Assert
+		assert_eq!(once.get_program(), "tailwindcss");
+		assert_eq!(
+			args(&once),
+			["-i", "static/src/app.css", "-o", "static/css/app.css"]
+		);
+		assert_eq!(args(&watch).last().map(String::as_str), Some("--watch"));
+	}
+
+	#[rstest]
+	fn test_esbuild_preset_bundles_into_outdir() {
+		// Arrange
+		let step = AssetBuildStep::esbuild("frontend/main.ts", "build/js").arg("--minify");
+
+		// Act
+		let cmd = step.command(false);
+
+		// Assert
+		assert_eq!(cmd.get_program(), "esbuild");
+		assert_eq!(
+			args(&cmd),
+			[
+				"frontend/main.ts",
+				"--bundle",
+				"--outdir=build/js",
+				"--minify"
+			]
+		);
+	}
+
+	#[rstest]
+	#[case(None, "build", Some("build"))]
+	#[case(Some("frontend"), "build", Some("frontend/build"))]
+	#[case(Some("frontend"), "/srv/build", Some("/srv/build"))]
+	fn test_output_dir_resolves_against_cwd(
+		#[case] cwd: Option<&str>,
+		#[case] output_dir: &str,
+		#[case] expected: Option<&str>,
+	) {
+		// Arrange
+		let mut step = AssetBuildStep::new("postcss", "postcss").output_dir(output_dir);
+		if let Some(cwd) = cwd {
+			step = step.cwd(cwd);
+		}
+
+		// Act
+		let resolved = step.resolved_output_dir();
+
+		// Assert
+		assert_eq!(resolved, expected.map(PathBuf::from));
+	}
+
+	#[rstest]
+	fn test_run_reports_missing_program() {
+		// Arrange
+		let step = AssetBuildStep::new("missing", "reinhardt-no-such-asset-tool");
+
+		// Act
+		let result = step.run();
+
+		// Assert
+		assert!(matches!(
+			result,
+			Err(AssetBuildError::Spawn { ref step, .. }) if step == "missing"
+		));
+	}
+
+	#[cfg(unix)]
+	#[rstest]
+	fn test_run_asset_steps_stops_at_failing_step() {
+		// Arrange
+		let steps = [
+			AssetBuildStep::new("ok", "true"),
+			AssetBuildStep::new("broken", "false"),
+			AssetBuildStep::new("missing", "reinhardt-no-such-asset-tool"),
+		];
+
+		// Act
+		let result = run_asset_steps(&steps);
+
+		// Assert
+		assert!(matches!(
+			result,
+			Err(AssetBuildError::Failed { ref step, .. }) if step == "broken"
+		));
+	}
+}
@@ -0,0 +1,1449 @@
+//! Implementation of the `backup` and `restore` management commands.
+//!
+//! A backup is a single timestamped artifact, e.g.
+//! `backups/backup-20261016T013000Z.tar.gz`, written to the storage backend
+//! configured under `[backup.storage]`. The gzipped tar archive holds:
+//!
+//! - `manifest.json`: creation time, database vendor and dump format
+//! - the database dump: `database.sql` (`pg_dump`/`mysqldump`),
+//!   `database.sqlite3` (a `VACUUM INTO` snapshot) or `database.json`
+//!   (portable fixtures, see [`DumpFormat`])
+//! - `media/`: the media root, unless `include_media` is disabled
+//!
+//! With an `encryption_key` the archive is sealed with AES-256-GCM and the
+//! artifact gets an `.enc` suffix. Only the newest `keep` artifacts are kept;
+//! since storage backends cannot list files, the artifacts written so far are
+//! tracked in `{prefix}/index.json`.
+//!
+//! ```toml
+//! [backup]
+//! format = "native"
+//! keep = 14
+//! encryption_key = "<base64 of 32 random bytes>"
+//!
+//! [backup.storage]
+//! backend = "local"
+//! local = { base_path = "/var/backups/myproject" }
+//! ```
+//!
+//! Scheduled backups register [`BackupRunner::run`] as the action of the
+//! `maintenance.backup` job of the `reinhardt-tasks` maintenance pack:
+//!
+//! ```rust,ignore
+//! let runner = Arc::new(BackupRunner::new(BackupSettings::from_disk()?, database_url).await?);
+//! let pack = MaintenancePack::new(maintenance_settings).with_backup(move || {
+//!     let runner = runner.clone();
+//!     async move {
+//!         runner
+//!             .run()
+//!             .await
+//!             .map(|report| report.removed.len() as u64)
+//!             .map_err(|e| TaskError::ExecutionFailed(e.to_string()))
+//!     }
+//! });
+//! ```
+
+use crate::builtin::{connect_database, get_database_url_from_settings, load_settings_from_disk};
+use crate::{BaseCommand, CommandArgument, CommandContext, CommandOption, CommandResult};
+use aes_gcm::{
+	Aes256Gcm, Nonce,
+	aead::{Aead, KeyInit, OsRng, rand_core::RngCore},
+};
+use async_trait::async_trait;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use reinhardt_conf::settings::secret_types::SecretString;
+use reinhardt_core::macros::settings;
+use reinhardt_db::backends::{DatabaseConnection, DatabaseType, QueryValue, TransactionExecutor};
+use reinhardt_storages::{
+	BackendType, StorageBackend, StorageSettings, create_storage_from_settings,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Name of the manifest inside a backup archive.
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// Directory of the media files inside a backup archive.
+const MEDIA_DIR: &str = "media";
+
+/// Name of the retention index under the backup prefix.
+const INDEX_FILE: &str = "index.json";
+
+/// Header of encrypted artifacts, followed by the nonce and the ciphertext.
+const ENCRYPTION_MAGIC: &[u8] = b"RHBK1";
+
+/// Version of the archive layout written by this module.
+const ARCHIVE_VERSION: u32 = 1;
+
+fn default_storage() -> StorageSettings {
+	local_storage("backups")
+}
+fn default_keep() -> usize {
+	7
+}
+fn default_prefix() -> String {
+	"backups".to_string()
+}
+fn default_include_media() -> bool {
+	true
+}
+fn default_media_root() -> PathBuf {
+	PathBuf::from("media")
+}
+
+/// Local storage settings rooted at `base_path`.
+fn local_storage(base_path: &str) -> StorageSettings {
+	// `StorageSettings` is non-exhaustive, so it is built the way it is read.
+	serde_json::from_value(serde_json::json!({
+		"backend": "local",
+		"local": { "base_path": base_path },
+	}))
+	.unwrap_or_default()
+}
+
+/// How the database is dumped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DumpFormat {
+	/// The vendor's own tooling: `pg_dump`/`psql`, `mysqldump`/`mysql`, or
+	/// a copy of the SQLite file. Fast and complete, but only restorable on
+	/// the same vendor.
+	#[default]
+	Native,
+	/// Every table's rows as JSON. Needs no external tools and restores on
+	/// any vendor whose schema has been migrated, but carries no schema of
+	/// its own.
+	Fixtures,
+}
+
+impl DumpFormat {
+	/// Name used in settings and on the command line.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Native => "native",
+			Self::Fixtures => "fixtures",
+		}
+	}
+}
+
+impl fmt::Display for DumpFormat {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl FromStr for DumpFormat {
+	type Err = crate::CommandError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"native" => Ok(Self::Native),
+			"fixtures" => Ok(Self::Fixtures),
+			other => Err(crate::CommandError::InvalidArguments(format!(
+				"Unknown dump format '{}' (expected 'native' or 'fixtures')",
+				other
+			))),
+		}
+	}
+}
+
+/// Backup settings fragment.
+///
+/// Maps to the `[backup]` section; see the [module documentation](self).
+#[settings(fragment = true, section = "backup")]
+#[non_exhaustive]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BackupSettings {
+	/// Where artifacts are written. A local `backups` directory by default.
+	#[setting(node)]
+	#[serde(default = "default_storage")]
+	pub storage: StorageSettings,
+	/// How the database is dumped.
+	#[serde(default)]
+	pub format: DumpFormat,
+	/// Base64-encoded 32-byte AES-256-GCM key. Artifacts are not encrypted
+	/// when unset.
+	#[serde(default)]
+	pub encryption_key: Option<SecretString>,
+	/// Number of artifacts kept; older ones are deleted after each backup.
+	/// `0` keeps every artifact.
+	#[serde(default = "default_keep")]
+	pub keep: usize,
+	/// Path prefix of the artifacts in the storage backend.
+	#[serde(default = "default_prefix")]
+	pub prefix: String,
+	/// Whether the media root is archived.
+	#[serde(default = "default_include_media")]
+	pub include_media: bool,
+	/// Directory of the uploaded media files.
+	#[serde(default = "default_media_root")]
+	pub media_root: PathBuf,
+}
+
+impl Default for BackupSettings {
+	fn default() -> Self {
+		Self {
+			storage: default_storage(),
+			format: DumpFormat::default(),
+			encryption_key: None,
+			keep: default_keep(),
+			prefix: default_prefix(),
+			include_media: default_include_media(),
+			media_root: default_media_root(),
+		}
+	}
+}
+
+impl BackupSettings {
+	/// Read the `[backup]` section of the project's `settings/*.toml`.
+	///
+	/// Missing sections and keys fall back to their defaults.
+	pub fn from_disk() -> CommandResult<Self> {
+		let merged = load_settings_from_disk()?;
+		match merged.get_raw("backup") {
+			Some(value) => serde_json::from_value(value.clone()).map_err(|e| {
+				crate::CommandError::ExecutionError(format!("Invalid [backup] settings: {}", e))
+			}),
+			None => Ok(Self::default()),
+		}
+	}
+
+	/// Set the dump format.
+	pub fn with_format(mut self, format: DumpFormat) -> Self {
+		self.format = format;
+		self
+	}
+
+	/// Set whether the media root is archived.
+	pub fn with_media(mut self, include_media: bool) -> Self {
+		self.include_media = include_media;
+		self
+	}
+
+	/// Storage path of `name`.
+	fn path_of(&self, name: &str) -> String {
+		match self.prefix.trim_matches('/') {
+			"" => name.to_string(),
+			prefix => format!("{}/{}", prefix, name),
+		}
+	}
+}
+
+/// Description of a backup, stored as `manifest.json` in its archive.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupManifest {
+	/// Archive layout version.
+	pub version: u32,
+	/// When the backup was taken.
+	pub created_at: DateTime<Utc>,
+	/// Vendor of the backed up database.
+	pub database: DatabaseType,
+	/// How the database was dumped.
+	pub format: DumpFormat,
+	/// Name of the database dump inside the archive.
+	pub database_file: String,
+	/// Number of media files in the archive.
+	pub media_files: usize,
+}
+
+/// Outcome of [`BackupRunner::run`].
+#[derive(Debug, Clone)]
+pub struct BackupReport {
+	/// Storage path of the new artifact.
+	pub name: String,
+	/// Size of the artifact in bytes.
+	pub size: usize,
+	/// What the artifact contains.
+	pub manifest: BackupManifest,
+	/// Artifacts deleted by retention rotation.
+	pub removed: Vec<String>,
+}
+
+/// Runs backups and restores against one database and storage backend.
+pub struct BackupRunner {
+	settings: BackupSettings,
+	database_url: String,
+	storage: Arc<dyn StorageBackend>,
+}
+
+impl fmt::Debug for BackupRunner {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("BackupRunner")
+			.field("settings", &self.settings)
+			.finish_non_exhaustive()
+	}
+}
+
+impl BackupRunner {
+	/// Create a runner writing to the storage backend of `settings`.
+	///
+	/// The base directory of a local storage backend is created if missing.
+	pub async fn new(
+		settings: BackupSettings,
+		database_url: impl Into<String>,
+	) -> CommandResult<Self> {
+		if let (BackendType::Local, Some(local)) =
+			(settings.storage.backend, &settings.storage.local)
+		{
+			std::fs::create_dir_all(&local.base_path)?;
+		}
+		let storage = create_storage_from_settings(&settings.storage)
+			.await
+			.map_err(|e| {
+				crate::CommandError::ExecutionError(format!("Failed to open backup storage: {}", e))
+			})?;
+		Ok(Self::with_storage(settings, database_url, storage))
+	}
+
+	/// Create a runner writing to `storage` instead of `settings.storage`.
+	pub fn with_storage(
+		settings: BackupSettings,
+		database_url: impl Into<String>,
+		storage: Arc<dyn StorageBackend>,
+	) -> Self {
+		Self {
+			settings,
+			database_url: database_url.into(),
+			storage,
+		}
+	}
+
+	/// Dump the database, archive the media root, store the artifact and
+	/// rotate old ones.
+	pub async fn run(&self) -> CommandResult<BackupReport> {
+		let created_at = Utc::now();
+		let database = database_type(&self.database_url)?;
+		let (database_file, dump) = match self.settings.format {
+			DumpFormat::Native => dump_native(database, &self.database_url).await?,
+			DumpFormat::Fixtures => (
+				"database.json".to_string(),
+				dump_fixtures(&self.database_url).await?,
+			),
+		};
+		let manifest = BackupManifest {
+			version: ARCHIVE_VERSION,
+			created_at,
+			database,
+			format: self.settings.format,
+			database_file,
+			media_files: 0,
+		};
+		let media_root = self
+			.settings
+			.include_media
+			.then_some(self.settings.media_root.as_path());
+		let (archive, manifest) = build_archive(manifest, &dump, media_root)?;
+
+		let key = self.encryption_key()?;
+		let artifact = match &key {
+			Some(key) => encrypt(key, &archive)?,
+			None => archive,
+		};
+		let name = self
+			.settings
+			.path_of(&artifact_name(created_at, key.is_some()));
+		self.storage
+			.save(&name, &artifact)
+			.await
+			.map_err(storage_error)?;
+		let removed = rotate(
+			self.storage.as_ref(),
+			&self.settings.path_of(INDEX_FILE),
+			&name,
+			self.settings.keep,
+		)
+		.await?;
+
+		Ok(BackupReport {
+			name,
+			size: artifact.len(),
+			manifest,
+			removed,
+		})
+	}
+
+	/// Restore the artifact `name`, or the newest one when `None`.
+	///
+	/// The database is restored first; media files are only written once it
+	/// succeeded. Media files missing from the backup are left in place.
+	pub async fn restore(&self, name: Option<&str>) -> CommandResult<BackupManifest> {
+		let name = match name {
+			Some(name) if name.contains('/') => name.to_string(),
+			Some(name) => self.settings.path_of(name),
+			None => self.artifacts().await?.pop().ok_or_else(|| {
+				crate::CommandError::ExecutionError("No backup to restore".to_string())
+			})?,
+		};
+		let artifact = self.storage.open(&name).await.map_err(storage_error)?;
+		let archive = if name.ends_with(".enc") {
+			let key = self.encryption_key()?.ok_or_else(|| {
+				crate::CommandError::InvalidArguments(format!(
+					"{} is encrypted but no backup encryption_key is configured",
+					name
+				))
+			})?;
+			decrypt(&key, &artifact)?
+		} else {
+			artifact
+		};
+
+		let (manifest, dump) = read_archive(&archive)?;
+		let database = database_type(&self.database_url)?;
+		match manifest.format {
+			DumpFormat::Native if manifest.database != database => {
+				return Err(crate::CommandError::ExecutionError(format!(
+					"{} holds a native {:?} dump, which cannot be restored into {:?}",
+					name, manifest.database, database
+				)));
+			}
+			DumpFormat::Native => restore_native(database, &self.database_url, dump).await?,
+			DumpFormat::Fixtures => restore_fixtures(&self.database_url, &dump).await?,
+		}
+		if self.settings.include_media {
+			extract_media(&archive, &self.settings.media_root)?;
+		}
+		Ok(manifest)
+	}
+
+	/// Storage paths of the kept artifacts, oldest first.
+	pub async fn artifacts(&self) -> CommandResult<Vec<String>> {
+		read_index(self.storage.as_ref(), &self.settings.path_of(INDEX_FILE)).await
+	}
+
+	fn encryption_key(&self) -> CommandResult<Option<[u8; 32]>> {
+		self.settings
+			.encryption_key
+			.as_ref()
+			.map(|key| decode_key(key.expose_secret()))
+			.transpose()
+	}
+}
+
+/// File name of an artifact taken at `created_at`.
+pub fn artifact_name(created_at: DateTime<Utc>, encrypted: bool) -> String {
+	format!(
+		"backup-{}.tar.gz{}",
+		created_at.format("%Y%m%dT%H%M%SZ"),
+		if encrypted { ".enc" } else { "" }
+	)
+}
+
+fn storage_error(e: reinhardt_storages::StorageError) -> crate::CommandError {
+	crate::CommandError::ExecutionError(format!("Backup storage error: {}", e))
+}
+
+fn database_error(e: impl fmt::Display) -> crate::CommandError {
+	crate::CommandError::ExecutionError(format!("Database error: {}", e))
+}
+
+fn database_type(url: &str) -> CommandResult<DatabaseType> {
+	if url.starts_with("postgres://") || url.starts_with("postgresql://") {
+		Ok(DatabaseType::Postgres)
+	} else if url.starts_with("mysql://") || url.starts_with("mariadb://") {
+		Ok(DatabaseType::Mysql)
+	} else if url.starts_with("sqlite:") {
+		Ok(DatabaseType::Sqlite)
+	} else {
+		Err(crate::CommandError::InvalidArguments(format!(
+			"Unsupported database URL: {}",
+			crate::builtin::sanitize_database_url(url)
+		)))
+	}
+}
+
+// --- encryption -----------------------------------------------------------
+
+fn decode_key(encoded: &str) -> CommandResult<[u8; 32]> {
+	base64::engine::general_purpose::STANDARD
+		.decode(encoded.trim())
+		.ok()
+		.and_then(|key| <[u8; 32]>::try_from(key).ok())
+		.ok_or_else(|| {
+			crate::CommandError::InvalidArguments(
+				"Backup encryption_key must be 32 bytes encoded as base64".to_string(),
+			)
+		})
+}
+
+fn encrypt(key: &[u8; 32], plaintext: &[u8]) -> CommandResult<Vec<u8>> {
+	let cipher = Aes256Gcm::new_from_slice(key)
+		.map_err(|e| crate::CommandError::ExecutionError(format!("Invalid key: {}", e)))?;
+	let mut nonce = [0u8; 12];
+	OsRng.fill_bytes(&mut nonce);
+	let ciphertext = cipher
+		.encrypt(&Nonce::from(nonce), plaintext)
+		.map_err(|e| crate::CommandError::ExecutionError(format!("Encryption failed: {}", e)))?;
+
+	let mut artifact = Vec::with_capacity(ENCRYPTION_MAGIC.len() + nonce.len() + ciphertext.len());
+	artifact.extend_from_slice(ENCRYPTION_MAGIC);
+	artifact.extend_from_slice(&nonce);
+	artifact.extend_from_slice(&ciphertext);
+	Ok(artifact)
+}
+
+fn decrypt(key: &[u8; 32], artifact: &[u8]) -> CommandResult<Vec<u8>> {
+	let invalid = || {
+		crate::CommandError::ExecutionError(
+			"Cannot decrypt backup: wrong encryption_key or corrupted artifact".to_string(),
+		)
+	};
+	let body = artifact
+		.strip_prefix(ENCRYPTION_MAGIC)
+		.filter(|body| body.len() > 12)
+		.ok_or_else(invalid)?;
+	let (nonce, ciphertext) = body.split_at(12);
+	Aes256Gcm::new_from_slice(key)
+		.map_err(|_| invalid())?
+		.decrypt(Nonce::from_slice(nonce), ciphertext)
+		.map_err(|_| invalid())
+}
+
+// --- archive --------------------------------------------------------------
+
+/// Build the gzipped tar archive, returning it with the completed manifest.
+fn build_archive(
+	mut manifest: BackupManifest,
+	dump: &[u8],
+	media_root: Option<&Path>,
+) -> CommandResult<(Vec<u8>, BackupManifest)> {
+	let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+	let mtime = manifest.created_at.timestamp().max(0) as u64;
+
+	manifest.media_files = 0;
+	if let Some(root) = media_root.filter(|root| root.is_dir()) {
+		for entry in walkdir::WalkDir::new(root).sort_by_file_name() {
+			let entry = entry.map_err(|e| {
+				crate::CommandError::ExecutionError(format!("Failed to read media: {}", e))
+			})?;
+			if !entry.file_type().is_file() {
+				continue;
+			}
+			let relative = entry
+				.path()
+				.strip_prefix(root)
+				.expect("walkdir yields paths under its root");
+			builder.append_path_with_name(entry.path(), Path::new(MEDIA_DIR).join(relative))?;
+			manifest.media_files += 1;
+		}
+	}
+
+	let manifest_json = serde_json::to_vec_pretty(&manifest)
+		.map_err(|e| crate::CommandError::ExecutionError(e.to_string()))?;
+	for (name, data) in [
+		(MANIFEST_FILE, manifest_json.as_slice()),
+		(manifest.database_file.as_str(), dump),
+	] {
+		let mut header = tar::Header::new_gnu();
+		header.set_size(data.len() as u64);
+		header.set_mode(0o600);
+		header.set_mtime(mtime);
+		header.set_cksum();
+		builder.append_data(&mut header, name, data)?;
+	}
+
+	let archive = builder.into_inner()?.finish()?;
+	Ok((archive, manifest))
+}
+
+/// Read the manifest and the database dump of an archive.
+fn read_archive(archive: &[u8]) -> CommandResult<(BackupManifest, Vec<u8>)> {
+	let mut manifest: Option<BackupManifest> = None;
+	let mut files = BTreeMap::new();
+	let mut archive = tar::Archive::new(GzDecoder::new(archive));
+	for entry in archive.entries()? {
+		let mut entry = entry?;
+		let path = entry.path()?.into_owned();
+		if path.starts_with(MEDIA_DIR) {
+			continue;
+		}
+		let mut data = Vec::new();
+		entry.read_to_end(&mut data)?;
+		if path == Path::new(MANIFEST_FILE) {
+			manifest = Some(serde_json::from_slice(&data).map_err(|e| {
+				crate::CommandError::ExecutionError(format!("Invalid backup manifest: {}", e))
+			})?);
+		} else {
+			files.insert(path, data);
+		}
+	}
+
+	let manifest = manifest.ok_or_else(|| {
+		crate::CommandError::ExecutionError("Backup archive has no manifest".to_string())
+	})?;
+	if manifest.version > ARCHIVE_VERSION {
+		return Err(crate::CommandError::ExecutionError(format!(
+			"Backup archive version {} is newer than the supported version {}",
+			manifest.version, ARCHIVE_VERSION
+		)));
+	}
+	let dump = files
+		.remove(Path::new(&manifest.database_file))
+		.ok_or_else(|| {
+			crate::CommandError::ExecutionError(format!(
+				"Backup archive has no {}",
+				manifest.database_file
+			))
+		})?;
+	Ok((manifest, dump))
+}
+
+/// Path of a media archive entry relative to the media root.
+///
+/// Returns `None` for entries outside `media/` and for paths that could
+/// escape the media root.
+fn media_path(entry: &Path) -> Option<PathBuf> {
+	let relative = entry.strip_prefix(MEDIA_DIR).ok()?;
+	let safe = relative.components().next().is_some()
+		&& relative
+			.components()
+			.all(|component| matches!(component, Component::Normal(_)));
+	safe.then(|| relative.to_path_buf())
+}
+
+/// Write the media files of an archive under `media_root`.
+fn extract_media(archive: &[u8], media_root: &Path) -> CommandResult<usize> {
+	let mut extracted = 0;
+	let mut archive = tar::Archive::new(GzDecoder::new(archive));
+	for entry in archive.entries()? {
+		let mut entry = entry?;
+		let path = entry.path()?.into_owned();
+		if !path.starts_with(MEDIA_DIR) || !entry.header().entry_type().is_file() {
+			continue;
+		}
+		let relative = media_path(&path).ok_or_else(|| {
+			crate::CommandError::ExecutionError(format!(
+				"Refusing to extract unsafe media path {}",
+				path.display()
+			))
+		})?;
+		let target = media_root.join(relative);
+		if let Some(parent) = target.parent() {
+			std::fs::create_dir_all(parent)?;
+		}
+		let mut data = Vec::new();
+		entry.read_to_end(&mut data)?;
+		std::fs::write(target, data)?;
+		extracted += 1;
+	}
+	Ok(extracted)
+}
+
+// --- retention ------------------------------------------------------------
+
+async fn read_index(storage: &dyn StorageBackend, index: &str) -> CommandResult<Vec<String>> {
+	if !storage.exists(index).await.map_err(storage_error)? {
+		return Ok(Vec::new());
+	}
+	let data = storage.open(index).await.map_err(storage_error)?;
+	serde_json::from_slice(&data).map_err(|e| {
+		crate::CommandError::ExecutionError(format!("Invalid backup index {}: {}", index, e))
+	})
+}
+
+/// Record `name` in the index and delete the artifacts beyond the newest
+/// `keep`, returning the deleted ones.
+async fn rotate(
+	storage: &dyn StorageBackend,
+	index: &str,
+	name: &str,
+	keep: usize,
+) -> CommandResult<Vec<String>> {
+	let mut artifacts = read_index(storage, index).await?;
+	artifacts.retain(|existing| existing != name);
+	artifacts.push(name.to_string());
+
+	let expired = match keep {
+		0 => 0,
+		keep => artifacts.len().saturating_sub(keep),
+	};
+	let removed: Vec<String> = artifacts.drain(..expired).collect();
+	for artifact in &removed {
+		match storage.delete(artifact).await {
+			Ok(()) | Err(reinhardt_storages::StorageError::NotFound(_)) => {}
+			Err(e) => return Err(storage_error(e)),
+		}
+	}
+
+	let data = serde_json::to_vec_pretty(&artifacts)
+		.map_err(|e| crate::CommandError::ExecutionError(e.to_string()))?;
+	storage.save(index, &data).await.map_err(storage_error)?;
+	Ok(removed)
+}
+
+// --- native dumps ---------------------------------------------------------
+
+/// Run an external tool, returning its standard output.
+async fn run_tool(
+	program: &str,
+	args: &[String],
+	password: Option<(&str, String)>,
+	input: Option<Vec<u8>>,
+) -> CommandResult<Vec<u8>> {
+	use std::process::Stdio;
+	use tokio::io::AsyncWriteExt;
+
+	let mut command = tokio::process::Command::new(program);
+	command
+		.args(args)
+		.stdin(if input.is_some() {
+			Stdio::piped()
+		} else {
+			Stdio::null()
+		})
+		.stdout(Stdio::piped())
+		.stderr(Stdio::piped());
+	if let Some((variable, password)) = password {
+		command.env(variable, password);
+	}
+	let mut child = command.spawn().map_err(|e| {
+		crate::CommandError::ExecutionError(format!(
+			"Failed to run {} (is it installed and on PATH?): {}",
+			program, e
+		))
+	})?;
+
+	// Feed stdin concurrently so a chatty tool cannot fill its stdout pipe
+	// while we are still writing.
+	let writer = match (input, child.stdin.take()) {
+		(Some(input), Some(mut stdin)) => {
+			Some(tokio::spawn(async move { stdin.write_all(&input).await }))
+		}
+		_ => None,
+	};
+	let output = child.wait_with_output().await?;
+	if !output.status.success() {
+		return Err(crate::CommandError::ExecutionError(format!(
+			"{} failed: {}",
+			program,
+			String::from_utf8_lossy(&output.stderr).trim()
+		)));
+	}
+	if let Some(writer) = writer {
+		writer
+			.await
+			.map_err(|e| crate::CommandError::ExecutionError(e.to_string()))??;
+	}
+	Ok(output.stdout)
+}
+
+/// `url` without its password, and the password.
+fn split_password(url: &str) -> CommandResult<(url::Url, Option<String>)> {
+	let mut parsed = url::Url::parse(url).map_err(|e| {
+		crate::CommandError::InvalidArguments(format!("Invalid database URL: {}", e))
+	})?;
+	let password = parsed.password().map(str::to_string);
+	let _ = parsed.set_password(None);
+	Ok((parsed, password))
+}
+
+/// Connection arguments of `mysqldump`/`mysql` followed by the database
+/// name, and the password passed through `MYSQL_PWD`.
+fn mysql_args(url: &str) -> CommandResult<(Vec<String>, Option<String>)> {
+	let (parsed, password) = split_password(url)?;
+	let mut args = vec![
+		"--host".to_string(),
+		parsed.host_str().unwrap_or("localhost").to_string(),
+		"--port".to_string(),
+		parsed.port().unwrap_or(3306).to_string(),
+	];
+	if !parsed.username().is_empty() {
+		args.extend(["--user".to_string(), parsed.username().to_string()]);
+	}
+	let database = parsed.path().trim_start_matches('/');
+	if database.is_empty() {
+		return Err(crate::CommandError::InvalidArguments(
+			"MySQL database URL has no database name".to_string(),
+		));
+	}
+	args.push(database.to_string());
+	Ok((args, password))
+}
+
+/// File path of a SQLite database URL.
+fn sqlite_path(url: &str) -> CommandResult<PathBuf> {
+	let path = url
+		.strip_prefix("sqlite://")
+		.or_else(|| url.strip_prefix("sqlite:"))
+		.unwrap_or(url);
+	let path = path.split('?').next().unwrap_or_default();
+	if path.is_empty() || path == ":memory:" {
+		return Err(crate::CommandError::InvalidArguments(
+			"In-memory SQLite databases cannot be backed up".to_string(),
+		));
+	}
+	Ok(PathBuf::from(path))
+}
+
+/// Temporary file removed when dropped, whichever path the dump takes.
+struct TempSnapshot(PathBuf);
+
+impl Drop for TempSnapshot {
+	fn drop(&mut self) {
+		// Drop cannot report errors; a missing file means the snapshot was
+		// never written.
+		let _ = std::fs::remove_file(&self.0);
+	}
+}
+
+/// Remove `path`, treating a missing file as already removed.
+async fn remove_if_exists(path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+	match tokio::fs::remove_file(path).await {
+		Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+		_ => Ok(()),
+	}
+}
+
+/// Dump with the vendor's tooling, returning the dump's file name and data.
+async fn dump_native(database: DatabaseType, url: &str) -> CommandResult<(String, Vec<u8>)> {
+	match database {
+		DatabaseType::Postgres => {
+			let (url, password) = split_password(url)?;
+			let args = ["--clean", "--if-exists", "--no-owner", "--no-privileges"]
+				.into_iter()
+				.map(str::to_string)
+				.chain([format!("--dbname={}", url)])
+				.collect::<Vec<_>>();
+			let dump =
+				run_tool("pg_dump", &args, password.map(|p| ("PGPASSWORD", p)), None).await?;
+			Ok(("database.sql".to_string(), dump))
+		}
+		DatabaseType::Mysql => {
+			let (connection, password) = mysql_args(url)?;
+			let args = ["--single-transaction", "--routines", "--triggers"]
+				.into_iter()
+				.map(str::to_string)
+				.chain(connection)
+				.collect::<Vec<_>>();
+			let dump =
+				run_tool("mysqldump", &args, password.map(|p| ("MYSQL_PWD", p)), None).await?;
+			Ok(("database.sql".to_string(), dump))
+		}
+		DatabaseType::Sqlite => {
+			sqlite_path(url)?;
+			// `VACUUM INTO` gives a consistent snapshot even while the
+			// application keeps writing.
+			let snapshot = TempSnapshot(std::env::temp_dir().join(format!(
+				"reinhardt-backup-{}-{}.sqlite3",
+				std::process::id(),
+				Utc::now().timestamp_nanos_opt().unwrap_or_default()
+			)));
+			let (_, connection) = connect_database(url).await?;
+			connection
+				.execute(
+					&format!(
+						"VACUUM INTO '{}'",
+						snapshot.0.display().to_string().replace('\'', "''")
+					),
+					vec![],
+				)
+				.await
+				.map_err(database_error)?;
+			let dump = tokio::fs::read(&snapshot.0).await?;
+			Ok(("database.sqlite3".to_string(), dump))
+		}
+	}
+}
+
+/// Restore a dump taken by [`dump_native`].
+async fn restore_native(database: DatabaseType, url: &str, dump: Vec<u8>) -> CommandResult<()> {
+	match database {
+		DatabaseType::Postgres => {
+			let (url, password) = split_password(url)?;
+			let args = ["--quiet", "--single-transaction", "--set=ON_ERROR_STOP=1"]
+				.into_iter()
+				.map(str::to_string)
+				.chain([format!("--dbname={}", url)])
+				.collect::<Vec<_>>();
+			run_tool(
+				"psql",
+				&args,
+				password.map(|p| ("PGPASSWORD", p)),
+				Some(dump),
+			)
+			.await?;
+		}
+		DatabaseType::Mysql => {
+			let (args, password) = mysql_args(url)?;
+			run_tool(
+				"mysql",
+				&args,
+				password.map(|p| ("MYSQL_PWD", p)),
+				Some(dump),
+			)
+			.await?;
+		}
+		DatabaseType::Sqlite => {
+			let path = sqlite_path(url)?;
+			let staging = PathBuf::from(format!("{}.restore", path.display()));
+			tokio::fs::write(&staging, dump).await?;
+			// A leftover write-ahead log would be replayed onto the restored file.
+			for suffix in ["-wal", "-shm"] {
+				remove_if_exists(format!("{}{}", path.display(), suffix)).await?;
+			}
+			tokio::fs::rename(&staging, &path).await?;
+		}
+	}
+	Ok(())
+}
+
+// --- fixtures -------------------------------------------------------------
+
+/// Rows of one table in a fixtures dump.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct TableFixture {
+	table: String,
+	rows: Vec<BTreeMap<String, QueryValue>>,
+}
+
+fn quote_identifier(database: DatabaseType, identifier: &str) -> String {
+	match database {
+		DatabaseType::Mysql => format!("`{}`", identifier.replace('`', "``")),
+		DatabaseType::Postgres | DatabaseType::Sqlite => {
+			format!("\"{}\"", identifier.replace('"', "\"\""))
+		}
+	}
+}
+
+fn list_tables_sql(database: DatabaseType) -> &'static str {
+	match database {
+		DatabaseType::Postgres => {
+			"SELECT tablename::text AS name FROM pg_tables \
+			 WHERE schemaname = current_schema() ORDER BY tablename"
+		}
+		DatabaseType::Mysql => {
+			"SELECT CAST(table_name AS CHAR) AS name FROM information_schema.tables \
+			 WHERE table_schema = DATABASE() AND table_type = 'BASE TABLE' ORDER BY table_name"
+		}
+		DatabaseType::Sqlite => {
+			"SELECT name FROM sqlite_master \
+			 WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name"
+		}
+	}
+}
+
+/// `INSERT` statement for `columns` of `table`.
+fn insert_sql(database: DatabaseType, table: &str, columns: &[&String]) -> String {
+	let names = columns
+		.iter()
+		.map(|column| quote_identifier(database, column))
+		.collect::<Vec<_>>()
+		.join(", ");
+	let placeholders = (1..=columns.len())
+		.map(|n| match database {
+			DatabaseType::Postgres => format!("${}", n),
+			DatabaseType::Mysql | DatabaseType::Sqlite => "?".to_string(),
+		})
+		.collect::<Vec<_>>()
+		.join(", ");
+	format!(
+		"INSERT INTO {} ({}) VALUES ({})",
+		quote_identifier(database, table),
+		names,
+		placeholders
+	)
+}
+
+async fn dump_fixtures(url: &str) -> CommandResult<Vec<u8>> {
+	let (database, connection) = connect_database(url).await?;
+	let tables = connection
+		.fetch_all(list_tables_sql(database), vec![])
+		.await
+		.map_err(database_error)?;
+
+	let mut fixtures = Vec::new();
+	for table in tables {
+		let Some(QueryValue::String(table)) = table.data.get("name").cloned() else {
+			continue;
+		};
+		let rows = connection
+			.fetch_all(
+				&format!("SELECT * FROM {}", quote_identifier(database, &table)),
+				vec![],
+			)
+			.await
+			.map_err(database_error)?;
+		fixtures.push(TableFixture {
+			table,
+			rows: rows
+				.into_iter()
+				.map(|row| row.data.into_iter().collect())
+				.collect(),
+		});
+	}
+	serde_json::to_vec(&fixtures).map_err(|e| crate::CommandError::ExecutionError(e.to_string()))
+}
+
+/// Replace the rows of every table in the fixtures, in one transaction.
+async fn restore_fixtures(url: &str, dump: &[u8]) -> CommandResult<()> {
+	let fixtures: Vec<TableFixture> = serde_json::from_slice(dump).map_err(|e| {
+		crate::CommandError::ExecutionError(format!("Invalid fixtures dump: {}", e))
+	})?;
+	let (database, connection) = connect_database(url).await?;
+	let mut transaction = connection.begin().await.map_err(database_error)?;
+	match load_fixtures(transaction.as_mut(), database, &fixtures).await {
+		Ok(()) => transaction.commit().await.map_err(database_error)?,
+		Err(e) => {
+			let _ = transaction.rollback().await;
+			return Err(e);
+		}
+	}
+	if database == DatabaseType::Postgres {
+		reset_sequences(&connection, &fixtures).await?;
+	}
+	Ok(())
+}
+
+async fn load_fixtures(
+	transaction: &mut dyn TransactionExecutor,
+	database: DatabaseType,
+	fixtures: &[TableFixture],
+) -> CommandResult<()> {
+	// Tables are replaced in name order, so foreign keys are only checked
+	// once every table is loaded (as far as the vendor allows).
+	let defer_constraints = match database {
+		DatabaseType::Postgres => "SET CONSTRAINTS ALL DEFERRED",
+		DatabaseType::Mysql => "SET FOREIGN_KEY_CHECKS = 0",
+		DatabaseType::Sqlite => "PRAGMA defer_foreign_keys = ON",
+	};
+	transaction
+		.execute(defer_constraints, vec![])
+		.await
+		.map_err(database_error)?;
+
+	for fixture in fixtures.iter().rev() {
+		transaction
+			.execute(
+				&format!("DELETE FROM {}", quote_identifier(database, &fixture.table)),
+				vec![],
+			)
+			.await
+			.map_err(database_error)?;
+	}
+	for fixture in fixtures {
+		for row in &fixture.rows {
+			let columns: Vec<&String> = row.keys().collect();
+			transaction
+				.execute(
+					&insert_sql(database, &fixture.table, &columns),
+					row.values().cloned().collect(),
+				)
+				.await
+				.map_err(database_error)?;
+		}
+	}
+
+	if database == DatabaseType::Mysql {
+		transaction
+			.execute("SET FOREIGN_KEY_CHECKS = 1", vec![])
+			.await
+			.map_err(database_error)?;
+	}
+	Ok(())
+}
+
+/// Move PostgreSQL serial sequences past the restored ids.
+async fn reset_sequences(
+	connection: &DatabaseConnection,
+	fixtures: &[TableFixture],
+) -> CommandResult<()> {
+	for fixture in fixtures {
+		let Some(row) = fixture.rows.first() else {
+			continue;
+		};
+		let table = quote_identifier(DatabaseType::Postgres, &fixture.table);
+		for column in row.keys() {
+			let sequence = connection
+				.fetch_one(
+					"SELECT pg_get_serial_sequence($1, $2) AS sequence",
+					vec![
+						QueryValue::String(table.clone()),
+						QueryValue::String(column.clone()),
+					],
+				)
+				.await
+				.map_err(database_error)?;
+			let Some(QueryValue::String(sequence)) = sequence.data.get("sequence").cloned() else {
+				continue;
+			};
+			connection
+				.execute(
+					&format!(
+						"SELECT setval($1::regclass, COALESCE(MAX({}), 0) + 1, false) FROM {}",
+						quote_identifier(DatabaseType::Postgres, column),
+						table
+					),
+					vec![QueryValue::String(sequence)],
+				)
+				.await
+				.map_err(database_error)?;
+		}
+	}
+	Ok(())
+}
+
+// --- commands -------------------------------------------------------------
+
+/// Database URL from `--database`, the `DATABASE_URL` environment variable,
+/// the composed settings, or `settings/*.toml`, in that order.
+fn database_url(ctx: &CommandContext) -> CommandResult<String> {
+	if let Some(url) = ctx.option("database") {
+		return Ok(url.clone());
+	}
+	if let Ok(url) = std::env::var("DATABASE_URL") {
+		return Ok(url);
+	}
+	match ctx.settings.as_ref() {
+		Some(settings) => {
+			DatabaseConnection::database_url_from(settings.as_ref(), None).map_err(|e| {
+				crate::CommandError::ExecutionError(format!("Failed to get database URL: {}", e))
+			})
+		}
+		None => get_database_url_from_settings(),
+	}
+}
+
+/// Backup settings with the command line overrides applied.
+fn backup_settings(ctx: &CommandContext) -> CommandResult<BackupSettings> {
+	let mut settings = BackupSettings::from_disk()?;
+	if let Some(format) = ctx.option("format") {
+		settings = settings.with_format(format.parse()?);
+	}
+	if ctx.has_option("no-media") {
+		settings = settings.with_media(false);
+	}
+	Ok(settings)
+}
+
+/// Back up the database and media storage.
+pub struct BackupCommand;
+
+#[async_trait]
+impl BaseCommand for BackupCommand {
+	fn name(&self) -> &str {
+		"backup"
+	}
+
+	fn description(&self) -> &str {
+		"Back up the database and media files to the backup storage"
+	}
+
+	fn options(&self) -> Vec<CommandOption> {
+		vec![
+			CommandOption::option(None, "database", "Database URL (default: DATABASE_URL)"),
+			CommandOption::option(None, "format", "Dump format: native or fixtures"),
+			CommandOption::flag(None, "no-media", "Do not archive the media files"),
+		]
+	}
+
+	async fn execute(&self, ctx: &CommandContext) -> CommandResult<()> {
+		let runner = BackupRunner::new(backup_settings(ctx)?, database_url(ctx)?).await?;
+		let report = runner.run().await?;
+		for removed in &report.removed {
+			ctx.verbose(&format!("  - removed {}", removed));
+		}
+		ctx.success(&format!(
+			"Backed up the {:?} database ({} dump) and {} media file(s) to {} ({} bytes)",
+			report.manifest.database,
+			report.manifest.format,
+			report.manifest.media_files,
+			report.name,
+			report.size
+		));
+		Ok(())
+	}
+}
+
+/// Restore the database and media storage from a backup.
+pub struct RestoreCommand;
+
+#[async_trait]
+impl BaseCommand for RestoreCommand {
+	fn name(&self) -> &str {
+		"restore"
+	}
+
+	fn description(&self) -> &str {
+		"Restore the database and media files from a backup"
+	}
+
+	fn arguments(&self) -> Vec<CommandArgument> {
+		vec![CommandArgument::optional(
+			"backup",
+			"Backup to restore (default: the newest one)",
+		)]
+	}
+
+	fn options(&self) -> Vec<CommandOption> {
+		vec![
+			CommandOption::option(None, "database", "Database URL (default: DATABASE_URL)"),
+			CommandOption::flag(None, "no-media", "Do not restore the media files"),
+			CommandOption::flag(Some('y'), "yes", "Do not ask for confirmation"),
+		]
+	}
+
+	async fn execute(&self, ctx: &CommandContext) -> CommandResult<()> {
+		let settings = backup_settings(ctx)?;
+		let url = database_url(ctx)?;
+		let backup = ctx.arg(0).map(String::as_str);
+		let target = if settings.include_media {
+			"database and media files"
+		} else {
+			"database"
+		};
+		if !ctx.has_option("yes")
+			&& !ctx.confirm(
+				&format!(
+					"Replace the current {} with backup {}?",
+					target,
+					backup.unwrap_or("(newest)")
+				),
+				false,
+			)? {
+			ctx.info("Restore cancelled.");
+			return Ok(());
+		}
+
+		let runner = BackupRunner::new(settings, url).await?;
+		let manifest = runner.restore(backup).await?;
+		ctx.success(&format!(
+			"Restored the backup taken at {} ({} dump, {} media file(s))",
+			manifest.created_at.to_rfc3339(),
+			manifest.format,
+			manifest.media_files
+		));
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chrono::TimeZone;
+	use rstest::rstest;
+
+	fn created_at() -> DateTime<Utc> {
+		Utc.with_ymd_and_hms(2026, 10, 16, 1, 30, 0).unwrap()
+	}
+
+	#[rstest]
+	#[case(false, "backup-20261016T013000Z.tar.gz")]
+	#[case(true, "backup-20261016T013000Z.tar.gz.enc")]
+	fn test_artifact_name(#[case] encrypted: bool, #[case] expected: &str) {
+		// Act
+		let name = artifact_name(created_at(), encrypted);
+
+		// Assert
+		assert_eq!(name, expected);
+	}
+
+	#[rstest]
+	fn test_encryption_roundtrip_and_wrong_key() {
+		// Arrange
+		let key = [7u8; 32];
+
+		// Act
+		let artifact = encrypt(&key, b"archive").unwrap();
+
+		// Assert
+		assert!(artifact.starts_with(ENCRYPTION_MAGIC));
+		assert_eq!(decrypt(&key, &artifact).unwrap(), b"archive");
+		assert!(decrypt(&[8u8; 32], &artifact).is_err());
+	}
+
+	#[rstest]
+	fn test_decode_key_requires_32_bytes() {
+		// Arrange
+		let valid = base64::engine::general_purpose::STANDARD.encode([1u8; 32]);
+		let short = base64::engine::general_purpose::STANDARD.encode([1u8; 16]);
+
+		// Act & Assert
+		assert_eq!(decode_key(&valid).unwrap(), [1u8; 32]);
+		assert!(decode_key(&short).is_err());
+		assert!(decode_key("not base64!").is_err());
+	}
+
+	#[rstest]
+	fn test_archive_roundtrip_with_media() {
+		// Arrange
+		let media = tempfile::tempdir().unwrap();
+		std::fs::create_dir_all(media.path().join("avatars")).unwrap();
+		std::fs::write(media.path().join("avatars/alice.png"), b"png").unwrap();
+		let restored = tempfile::tempdir().unwrap();
+		let manifest = BackupManifest {
+			version: ARCHIVE_VERSION,
+			created_at: created_at(),
+			database: DatabaseType::Postgres,
+			format: DumpFormat::Native,
+			database_file: "database.sql".to_string(),
+			media_files: 0,
+		};
+
+		// Act
+		let (archive, written) =
+			build_archive(manifest, b"CREATE TABLE t ();", Some(media.path())).unwrap();
+		let (manifest, dump) = read_archive(&archive).unwrap();
+		let extracted = extract_media(&archive, restored.path()).unwrap();
+
+		// Assert
+		assert_eq!(written.media_files, 1);
+		assert_eq!(manifest, written);
+		assert_eq!(dump, b"CREATE TABLE t ();");
+		assert_eq!(extracted, 1);
+		assert_eq!(
+			std::fs::read(restored.path().join("avatars/alice.png")).unwrap(),
+			b"png"
+		);
+	}
+
+	#[rstest]
+	#[case("media/avatars/a.png", Some("avatars/a.png"))]
+	#[case("media/../etc/passwd", None)]
+	#[case("media", None)]
+	#[case("database.sql", None)]
+	fn test_media_path_rejects_escapes(#[case] entry: &str, #[case] expected: Option<&str>) {
+		// Act
+		let path = media_path(Path::new(entry));
+
+		// Assert
+		assert_eq!(path, expected.map(PathBuf::from));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_rotate_keeps_newest_artifacts() {
+		// Arrange
+		let dir = tempfile::tempdir().unwrap();
+		let settings = local_storage(dir.path().to_str().unwrap());
+		let storage = create_storage_from_settings(&settings).await.unwrap();
+		let names = ["b/1.tar.gz", "b/2.tar.gz", "b/3.tar.gz"];
+		let mut removed = Vec::new();
+
+		// Act
+		for name in names {
+			storage.save(name, b"artifact").await.unwrap();
+			removed.extend(
+				rotate(storage.as_ref(), "b/index.json", name, 2)
+					.await
+					.unwrap(),
+			);
+		}
+
+		// Assert
+		assert_eq!(removed, vec!["b/1.tar.gz".to_string()]);
+		assert!(!storage.exists("b/1.tar.gz").await.unwrap());
+		assert_eq!(
+			read_index(storage.as_ref(), "b/index.json").await.unwrap(),
+			vec!["b/2.tar.gz".to_string(), "b/3.tar.gz".to_string()]
+		);
+	}
+
+	#[rstest]
+	#[case("sqlite://db.sqlite3", Some("db.sqlite3"))]
+	#[case("sqlite:///var/db/app.sqlite3?mode=rwc", Some("/var/db/app.sqlite3"))]
+	#[case("sqlite::memory:", None)]
+	fn test_sqlite_path(#[case] url: &str, #[case] expected: Option<&str>) {
+		// Act
+		let path = sqlite_path(url).ok();
+
+		// Assert
+		assert_eq!(path, expected.map(PathBuf::from));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_sqlite_snapshot_removed_when_dump_fails() {
+		// Arrange
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("snapshot.sqlite3");
+		std::fs::write(&path, b"snapshot").unwrap();
+
+		// Act
+		let result: CommandResult<()> = async {
+			let _snapshot = TempSnapshot(path.clone());
+			Err(crate::CommandError::ExecutionError(
+				"VACUUM failed".to_string(),
+			))
+		}
+		.await;
+
+		// Assert
+		assert!(result.is_err());
+		assert!(!path.exists());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_remove_if_exists_ignores_missing_file() {
+		// Arrange
+		let dir = tempfile::tempdir().unwrap();
+		let missing = dir.path().join("db.sqlite3-wal");
+
+		// Act
+		let result = remove_if_exists(&missing).await;
+
+		// Assert
+		assert!(result.is_ok());
+	}
+
+	#[rstest]
+	fn test_mysql_args_keep_password_off_the_command_line() {
+		// Act
+		let (args, password) = mysql_args("mysql://app:s3cret@db:3307/shop").unwrap();
+
+		// Assert
+		assert_eq!(
+			args,
+			vec!["--host", "db", "--port", "3307", "--user", "app", "shop"]
+		);
+		assert_eq!(password.as_deref(), Some("s3cret"));
+	}
+
+	#[rstest]
+	#[case(
+		DatabaseType::Postgres,
+		r#"INSERT INTO "users" ("id", "name") VALUES ($1, $2)"#
+	)]
+	#[case(
+		DatabaseType::Mysql,
+		"INSERT INTO `users` (`id`, `name`) VALUES (?, ?)"
+	)]
+	fn test_insert_sql(#[case] database: DatabaseType, #[case] expected: &str) {
+		// Arrange
+		let (id, name) = ("id".to_string(), "name".to_string());
+
+		// Act
+		let sql = insert_sql(database, "users", &[&id, &name]);
+
+		// Assert
+		assert_eq!(sql, expected);
+	}
+
+	#[rstest]
+	fn test_settings_defaults() {
+		// Act
+		let settings: BackupSettings = serde_json::from_value(serde_json::json!({
+			"format": "fixtures",
+			"prefix": "/nightly/",
+		}))
+		.unwrap();
+
+		// Assert
+		assert_eq!(settings.format, DumpFormat::Fixtures);
+		assert_eq!(settings.keep, 7);
+		assert!(settings.include_media);
+		assert_eq!(settings.path_of("index.json"), "nightly/index.json");
+		assert_eq!(
+			settings.storage.local.unwrap().base_path,
+			"backups".to_string()
+		);
+	}
+}
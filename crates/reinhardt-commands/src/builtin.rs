@@ -59,6 +59,11 @@ impl BaseCommand for MigrateCommand {
 				"migrations-dir",
 				"Root directory containing migration files (default: ./migrations)",
 			),
+			CommandOption::flag(
+				None,
+				"no-seed",
+				"Do not apply seed data from the post_migrate receiver",
+			),
 			CommandOption::option(Some('d'), "database", "Database to migrate")
 				.with_default("default"),
 		]
@@ -513,7 +518,7 @@ impl BaseCommand for MigrateCommand {
 					return Ok(());
 				}
 
-				let mut executor = DatabaseMigrationExecutor::new(connection);
+				let mut executor = DatabaseMigrationExecutor::new(connection.clone());
 				let result = executor.apply_migrations(&to_apply).await.map_err(|e| {
 					crate::CommandError::ExecutionError(format!(
 						"Failed to apply migrations: {:?}",
@@ -529,6 +534,7 @@ impl BaseCommand for MigrateCommand {
 					app,
 					target_name
 				));
+				send_post_migrate(ctx, connection, &to_apply, &result.applied).await?;
 				return Ok(());
 			}
 
@@ -618,14 +624,16 @@ impl BaseCommand for MigrateCommand {
 				ctx.info("Applying migrations:");
 
 				// Create migration executor
-				let mut executor = DatabaseMigrationExecutor::new(connection);
+				let mut executor = DatabaseMigrationExecutor::new(connection.clone());
 
-				// Apply migrations
+				// Apply migrations, then let post_migrate receivers (seeds) run
 				match executor.apply_migrations(&migrations_to_apply[..]).await {
 					Ok(result) => {
 						for applied_id in &result.applied {
 							ctx.success(&format!("  ✓ Applied: {}", applied_id));
 						}
+						send_post_migrate(ctx, connection, &migrations_to_apply, &result.applied)
+							.await?;
 					}
 					Err(e) => {
 						return Err(crate::CommandError::ExecutionError(format!(
@@ -654,6 +662,506 @@ impl BaseCommand for MigrateCommand {
 	}
 }
 
+/// Seed data command
+///
+/// Applies the seed data declared by apps, skipping seeds whose checksum is
+/// unchanged since their last run.
+pub struct SeedCommand;
+
+#[async_trait]
+impl BaseCommand for SeedCommand {
+	fn name(&self) -> &str {
+		"seed"
+	}
+
+	fn description(&self) -> &str {
+		"Apply declared seed data"
+	}
+
+	fn arguments(&self) -> Vec<CommandArgument> {
+		vec![CommandArgument::optional("app", "App whose seeds to apply")]
+	}
+
+	fn options(&self) -> Vec<CommandOption> {
+		vec![
+			CommandOption::flag(None, "force", "Re-apply seeds even when unchanged"),
+			CommandOption::option(Some('d'), "database", "Database URL"),
+		]
+	}
+
+	async fn execute(&self, ctx: &CommandContext) -> CommandResult<()> {
+		#[cfg(feature = "migrations")]
+		{
+			use reinhardt_db::migrations::seeds::{SeedRunner, registered_seeds, seeds_for_app};
+
+			let seeds = match ctx.arg(0) {
+				Some(app) => seeds_for_app(app),
+				None => registered_seeds(),
+			};
+			if seeds.is_empty() {
+				ctx.info("No seed data declared.");
+				return Ok(());
+			}
+
+			let database_url = ctx
+				.option("database")
+				.map(|s| s.to_string())
+				.or_else(|| std::env::var("DATABASE_URL").ok())
+				.ok_or_else(|| {
+					crate::CommandError::ExecutionError(
+						"No database URL provided. Use --database option or set DATABASE_URL environment variable".to_string()
+					)
+				})?;
+			let (_, connection) = connect_database(&database_url).await?;
+
+			let report = SeedRunner::new(connection)
+				.force(ctx.has_option("force"))
+				.run(&seeds)
+				.await
+				.map_err(|e| {
+					crate::CommandError::ExecutionError(format!("Failed to apply seeds: {}", e))
+				})?;
+			for id in &report.applied {
+				ctx.success(&format!("  ✓ Seeded: {}", id));
+			}
+			for id in &report.skipped {
+				ctx.verbose(&format!("  - Unchanged: {}", id));
+			}
+			ctx.success(&format!(
+				"Applied {} seed(s), {} unchanged",
+				report.applied.len(),
+				report.skipped.len()
+			));
+			Ok(())
+		}
+
+		#[cfg(not(feature = "migrations"))]
+		{
+			ctx.warning("Migrations feature not enabled");
+			ctx.info("To use seed, enable the 'migrations' feature");
+			Ok(())
+		}
+	}
+}
+
+/// Personal data export command
+///
+/// Writes every row belonging to a user, across all models declaring
+/// `#[model(data_subject = "...")]`, as a JSON archive.
+pub struct ExportUserDataCommand;
+
+#[async_trait]
+impl BaseCommand for ExportUserDataCommand {
+	fn name(&self) -> &str {
+		"export_user_data"
+	}
+
+	fn description(&self) -> &str {
+		"Export the personal data stored about a user"
+	}
+
+	fn arguments(&self) -> Vec<CommandArgument> {
+		vec![CommandArgument::required("user", "Id of the user")]
+	}
+
+	fn options(&self) -> Vec<CommandOption> {
+		vec![CommandOption::option(
+			Some('o'),
+			"output",
+			"File to write the archive to (default: stdout)",
+		)]
+	}
+
+	async fn execute(&self, ctx: &CommandContext) -> CommandResult<()> {
+		#[cfg(feature = "reinhardt-db")]
+		{
+			use reinhardt_db::orm::personal_data::export_user_data;
+
+			let subject = subject_arg(ctx)?;
+			let export = export_user_data(subject).await.map_err(|e| {
+				crate::CommandError::ExecutionError(format!("Failed to export user data: {}", e))
+			})?;
+			let json = export.to_json().map_err(|e| {
+				crate::CommandError::ExecutionError(format!("Failed to serialize export: {}", e))
+			})?;
+
+			match ctx.option("output") {
+				Some(path) => {
+					std::fs::write(path, json)?;
+					ctx.success(&format!(
+						"Exported {} row(s) from {} model(s) to {}",
+						export.total_rows(),
+						export.models.len(),
+						path
+					));
+				}
+				None => println!("{}", json),
+			}
+			Ok(())
+		}
+
+		#[cfg(not(feature = "reinhardt-db"))]
+		{
+			ctx.warning("Database feature not enabled");
+			ctx.info("To use export_user_data, enable the 'reinhardt-db' feature");
+			Ok(())
+		}
+	}
+}
+
+/// Personal data anonymization command
+///
+/// Overwrites the `#[field(personal_data)]` fields of every row belonging to
+/// a user in one transaction.
+pub struct AnonymizeUserCommand;
+
+#[async_trait]
+impl BaseCommand for AnonymizeUserCommand {
+	fn name(&self) -> &str {
+		"anonymize_user"
+	}
+
+	fn description(&self) -> &str {
+		"Irreversibly anonymize the personal data stored about a user"
+	}
+
+	fn arguments(&self) -> Vec<CommandArgument> {
+		vec![CommandArgument::required("user", "Id of the user")]
+	}
+
+	fn options(&self) -> Vec<CommandOption> {
+		vec![
+			CommandOption::option(None, "salt", "Salt mixed into hashed values"),
+			CommandOption::flag(Some('y'), "yes", "Do not ask for confirmation"),
+		]
+	}
+
+	async fn execute(&self, ctx: &CommandContext) -> CommandResult<()> {
+		#[cfg(feature = "reinhardt-db")]
+		{
+			use reinhardt_db::orm::personal_data::Anonymizer;
+
+			let subject = subject_arg(ctx)?;
+			if !ctx.confirm(
+				&format!(
+					"Irreversibly anonymize all personal data of user {}?",
+					subject
+				),
+				false,
+			)? {
+				ctx.info("Anonymization cancelled.");
+				return Ok(());
+			}
+
+			let connection = reinhardt_db::orm::get_connection().await.map_err(|e| {
+				crate::CommandError::ExecutionError(format!("Database connection failed: {}", e))
+			})?;
+			let report = Anonymizer::new(&connection)
+				.salt(ctx.option("salt").map(String::as_str).unwrap_or_default())
+				.run(subject)
+				.await
+				.map_err(|e| {
+					crate::CommandError::ExecutionError(format!("Failed to anonymize user: {}", e))
+				})?;
+			for (model, rows) in &report.models {
+				ctx.verbose(&format!("  - {}: {} row(s)", model, rows));
+			}
+			ctx.success(&format!(
+				"Anonymized {} row(s) across {} model(s)",
+				report.total_rows(),
+				report.models.len()
+			));
+			Ok(())
+		}
+
+		#[cfg(not(feature = "reinhardt-db"))]
+		{
+			ctx.warning("Database feature not enabled");
+			ctx.info("To use anonymize_user, enable the 'reinhardt-db' feature");
+			Ok(())
+		}
+	}
+}
+
+/// Counter repair command
+///
+/// Recomputes the `#[field(counter_of = "...")]` fields from the rows they
+/// count and reports how many drifted.
+pub struct RecountCommand;
+
+#[async_trait]
+impl BaseCommand for RecountCommand {
+	fn name(&self) -> &str {
+		"recount"
+	}
+
+	fn description(&self) -> &str {
+		"Recompute denormalized counter fields from the rows they count"
+	}
+
+	fn arguments(&self) -> Vec<CommandArgument> {
+		vec![CommandArgument::optional(
+			"target",
+			"Only recount app_label.Model or app_label.Model.field",
+		)]
+	}
+
+	async fn execute(&self, ctx: &CommandContext) -> CommandResult<()> {
+		#[cfg(feature = "reinhardt-db")]
+		{
+			let connection = reinhardt_db::orm::get_connection().await.map_err(|e| {
+				crate::CommandError::ExecutionError(format!("Database connection failed: {}", e))
+			})?;
+			let report = reinhardt_db::orm::counters::recount_with_conn(
+				&connection,
+				ctx.arg(0).map(String::as_str),
+			)
+			.await
+			.map_err(|e| {
+				crate::CommandError::ExecutionError(format!("Failed to recount counters: {}", e))
+			})?;
+			for (counter, rows) in &report.counters {
+				ctx.verbose(&format!("  - {}: {} row(s) repaired", counter, rows));
+			}
+			ctx.success(&format!(
+				"Recounted {} counter(s), repaired {} row(s)",
+				report.counters.len(),
+				report.total_rows()
+			));
+			Ok(())
+		}
+
+		#[cfg(not(feature = "reinhardt-db"))]
+		{
+			ctx.warning("Database feature not enabled");
+			ctx.info("To use recount, enable the 'reinhardt-db' feature");
+			Ok(())
+		}
+	}
+}
+
+/// Data retention command
+///
+/// Deletes or anonymizes the rows of models declaring
+/// `#[model(retain = "...")]` once their retention period is over.
+pub struct EnforceRetentionCommand;
+
+#[async_trait]
+impl BaseCommand for EnforceRetentionCommand {
+	fn name(&self) -> &str {
+		"enforce_retention"
+	}
+
+	fn description(&self) -> &str {
+		"Delete or anonymize rows past their model's retention period"
+	}
+
+	fn options(&self) -> Vec<CommandOption> {
+		vec![
+			CommandOption::flag(None, "dry-run", "Only report how many rows have expired"),
+			CommandOption::option(
+				None,
+				"batch-size",
+				"Rows processed per batch (default: 500)",
+			),
+			CommandOption::option(None, "salt", "Salt mixed into hashed values"),
+		]
+	}
+
+	async fn execute(&self, ctx: &CommandContext) -> CommandResult<()> {
+		#[cfg(feature = "reinhardt-db")]
+		{
+			use reinhardt_db::orm::retention::RetentionEnforcer;
+
+			let dry_run = ctx.has_option("dry-run");
+			let connection = reinhardt_db::orm::get_connection().await.map_err(|e| {
+				crate::CommandError::ExecutionError(format!("Database connection failed: {}", e))
+			})?;
+			let mut enforcer = RetentionEnforcer::new(&connection)
+				.dry_run(dry_run)
+				.salt(ctx.option("salt").map(String::as_str).unwrap_or_default());
+			if let Some(raw) = ctx.option("batch-size") {
+				let batch_size = raw.parse::<usize>().map_err(|_| {
+					crate::CommandError::InvalidArguments(format!("Invalid batch size: {}", raw))
+				})?;
+				enforcer = enforcer.batch_size(batch_size);
+			}
+			let report = enforcer.run().await.map_err(|e| {
+				crate::CommandError::ExecutionError(format!("Failed to enforce retention: {}", e))
+			})?;
+			for (model, outcome) in &report.models {
+				ctx.verbose(&format!(
+					"  - {}: {} row(s) to {}",
+					model, outcome.rows, outcome.action
+				));
+			}
+			if dry_run {
+				ctx.info(&format!(
+					"Dry run: {} expired row(s) across {} model(s)",
+					report.total_rows(),
+					report.models.len()
+				));
+			} else {
+				ctx.success(&format!(
+					"Processed {} expired row(s) across {} model(s)",
+					report.total_rows(),
+					report.models.len()
+				));
+			}
+			Ok(())
+		}
+
+		#[cfg(not(feature = "reinhardt-db"))]
+		{
+			ctx.warning("Database feature not enabled");
+			ctx.info("To use enforce_retention, enable the 'reinhardt-db' feature");
+			Ok(())
+		}
+	}
+}
+
+/// User id argument as a JSON value: integers stay numbers, anything else
+/// (UUIDs, usernames) is passed as a string
+#[cfg(feature = "reinhardt-db")]
+fn subject_arg(ctx: &CommandContext) -> CommandResult<serde_json::Value> {
+	let user = ctx
+		.arg(0)
+		.ok_or_else(|| crate::CommandError::InvalidArguments("user id is required".to_string()))?;
+	Ok(match user.parse::<i64>() {
+		Ok(id) => serde_json::Value::from(id),
+		Err(_) => serde_json::Value::from(user.as_str()),
+	})
+}
+
+/// Dispatch UID of the `post_migrate` receiver applying seed data
+#[cfg(feature = "migrations")]
+const SEED_RECEIVER_UID: &str = "reinhardt.seeds";
+
+/// Connect the `post_migrate` receiver applying the migrated app's seeds
+///
+/// Replaces any receiver connected by a previous call.
+#[cfg(feature = "migrations")]
+pub fn connect_seed_receiver(connection: DatabaseConnection) {
+	use reinhardt_core::signals::{MigrationEvent, SignalError, post_migrate};
+	use reinhardt_db::migrations::seeds::{SeedRunner, seeds_for_app};
+	use std::sync::Arc;
+
+	post_migrate().connect_with_options(
+		move |event: Arc<MigrationEvent>| {
+			let connection = connection.clone();
+			async move {
+				let seeds = seeds_for_app(&event.app_name);
+				if seeds.is_empty() {
+					return Ok(());
+				}
+				SeedRunner::new(connection)
+					.run(&seeds)
+					.await
+					.map(|_| ())
+					.map_err(|e| {
+						SignalError::new(format!("Failed to seed app '{}': {}", event.app_name, e))
+					})
+			}
+		},
+		None,
+		Some(SEED_RECEIVER_UID.to_string()),
+		0,
+	);
+}
+
+/// Dispatch UID of the `post_migrate` receiver syncing content types and permissions
+#[cfg(all(feature = "migrations", feature = "auth"))]
+const CATALOG_RECEIVER_UID: &str = "reinhardt.permission_catalog";
+
+/// Connect the `post_migrate` receiver creating the migrated app's content
+/// types and default permissions and removing those of deleted models
+///
+/// Runs before the seed receiver so that seeds can refer to the permissions.
+/// Replaces any receiver connected by a previous call.
+#[cfg(all(feature = "migrations", feature = "auth"))]
+pub fn connect_permission_catalog_receiver(connection: DatabaseConnection) {
+	use reinhardt_auth::PermissionCatalog;
+	use reinhardt_core::signals::{MigrationEvent, SignalError, post_migrate};
+	use std::sync::Arc;
+
+	post_migrate().connect_with_options(
+		move |event: Arc<MigrationEvent>| {
+			let connection = connection.clone();
+			async move {
+				PermissionCatalog::new(connection)
+					.sync_app(&event.app_name)
+					.await
+					.map(|_| ())
+					.map_err(|e| {
+						SignalError::new(format!(
+							"Failed to sync permissions of app '{}': {}",
+							event.app_name, e
+						))
+					})
+			}
+		},
+		None,
+		Some(CATALOG_RECEIVER_UID.to_string()),
+		10,
+	);
+}
+
+/// Send `post_migrate` once per app in `migrations`
+///
+/// Content types and default permissions are synced by the receiver
+/// connected here when the `auth` feature is enabled, and seeds are applied
+/// unless `--no-seed` is set.
+#[cfg(feature = "migrations")]
+async fn send_post_migrate(
+	ctx: &CommandContext,
+	connection: DatabaseConnection,
+	migrations: &[reinhardt_db::migrations::Migration],
+	applied: &[String],
+) -> CommandResult<()> {
+	use reinhardt_core::signals::{MigrationEvent, post_migrate};
+
+	#[cfg(feature = "auth")]
+	connect_permission_catalog_receiver(connection.clone());
+	if ctx.has_option("no-seed") {
+		post_migrate().disconnect(SEED_RECEIVER_UID);
+	} else {
+		connect_seed_receiver(connection);
+	}
+
+	let mut apps: Vec<&str> = Vec::new();
+	for migration in migrations {
+		if !apps.contains(&migration.app_label.as_str()) {
+			apps.push(&migration.app_label);
+		}
+	}
+
+	for app in apps {
+		let prefix = format!("{}.", app);
+		let plan: Vec<String> = applied
+			.iter()
+			.filter(|id| id.starts_with(&prefix))
+			.cloned()
+			.collect();
+		let last = migrations
+			.iter()
+			.rev()
+			.find(|m| m.app_label == app)
+			.map(|m| m.name.clone())
+			.unwrap_or_default();
+		ctx.verbose(&format!("Sending post_migrate for {}", app));
+		post_migrate()
+			.send(MigrationEvent::new(app, last).with_plan(plan))
+			.await
+			.map_err(|e| {
+				crate::CommandError::ExecutionError(format!(
+					"post_migrate receiver failed for '{}': {}",
+					app, e
+				))
+			})?;
+	}
+	Ok(())
+}
+
 /// Sort migrations with the same dependency rules used by the migration executor.
 #[cfg(feature = "migrations")]
 fn dependency_ordered_migrations<'a>(
@@ -2067,6 +2575,20 @@ impl BaseCommand for RunServerCommand {
 			}
 		}
 
+		// Build registered assets (Tailwind, esbuild, ...) once. The
+		// autoreload parent runs them in watch mode instead, so neither it nor
+		// its children build them here.
+		let runs_asset_watchers =
+			cfg!(all(feature = "server", feature = "autoreload")) && !noreload;
+		if !runs_asset_watchers
+			&& std::env::var_os("REINHARDT_IS_AUTORELOAD_CHILD").is_none()
+			&& let Err(e) = crate::asset_pipeline::run_asset_steps(
+				&crate::asset_pipeline::registered_asset_steps(),
+			) {
+			ctx.error(&format!("Asset build failed: {}", e));
+			return Ok(());
+		}
+
 		// Find available port early (before displaying banner)
 		#[cfg(feature = "server")]
 		let actual_address = {
@@ -2486,6 +3008,18 @@ impl RunServerCommand {
 			}
 		}
 
+		// Run the startup hooks of installed apps (readiness stays false
+		// until they all complete)
+		let installed_apps = ctx
+			.settings
+			.as_ref()
+			.map(|settings| settings.core().installed_apps.clone())
+			.unwrap_or_default();
+		reinhardt_apps::lifecycle()
+			.startup(&installed_apps)
+			.await
+			.map_err(|e| crate::CommandError::ExecutionError(e.to_string()))?;
+
 		// Create HTTP server with DI context and logging middleware
 		let mut server = HttpServer::new(router)
 			.with_di_context(di_context)
@@ -2628,19 +3162,31 @@ impl RunServerCommand {
 			}
 			#[cfg(not(feature = "autoreload"))]
 			{
-				server
-					.listen_with_shutdown(addr, ShutdownCoordinator::clone(&coordinator))
-					.await
-					.map_err(|e| crate::CommandError::ExecutionError(e.to_string()))
+				Self::listen_until_shutdown(ctx, server, addr, &coordinator).await
 			}
 		} else {
-			server
-				.listen_with_shutdown(addr, ShutdownCoordinator::clone(&coordinator))
-				.await
-				.map_err(|e| crate::CommandError::ExecutionError(e.to_string()))
+			Self::listen_until_shutdown(ctx, server, addr, &coordinator).await
 		}
 	}
 
+	/// Serve until shutdown, then run the shutdown hooks of installed apps.
+	#[cfg(feature = "server")]
+	async fn listen_until_shutdown(
+		ctx: &CommandContext,
+		server: reinhardt_server::HttpServer,
+		addr: std::net::SocketAddr,
+		coordinator: &reinhardt_server::ShutdownCoordinator,
+	) -> CommandResult<()> {
+		let result = server
+			.listen_with_shutdown(addr, coordinator.clone())
+			.await
+			.map_err(|e| crate::CommandError::ExecutionError(e.to_string()));
+		for error in reinhardt_apps::lifecycle().shutdown().await {
+			ctx.warning(&error.to_string());
+		}
+		result
+	}
+
 	/// Start the browser-facing HMR WebSocket listener for autoreload mode.
 	#[cfg(all(feature = "server", feature = "autoreload", feature = "pages"))]
 	async fn start_autoreload_hmr(
@@ -2798,6 +3344,16 @@ impl RunServerCommand {
 		}
 		ctx.info("  on failure: keep watching (Ctrl+C to quit)");
 
+		// Keep registered asset build steps running in watch mode for the
+		// lifetime of the watcher loop; dropping them kills the processes.
+		let asset_watchers = crate::asset_pipeline::spawn_asset_watchers(
+			&crate::asset_pipeline::registered_asset_steps(),
+		)
+		.map_err(|e| crate::CommandError::ExecutionError(e.to_string()))?;
+		for watcher in &asset_watchers {
+			ctx.info(&format!("  assets: {} (watch mode)", watcher.name()));
+		}
+
 		// Set up Ctrl+C handler.
 		let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
 		let ctx_clone = ctx.clone();
@@ -2861,13 +3417,11 @@ impl RunServerCommand {
 			hmr_tx,
 		};
 
-		crate::debounced_watcher::run_watcher(ctx, &cfg, shutdown_rx, child, respawn)
+		let result = crate::debounced_watcher::run_watcher(ctx, &cfg, shutdown_rx, child, respawn)
 			.await
-			.map_err(|e| {
-				crate::CommandError::ExecutionError(format!("File watcher error: {}", e))
-			})?;
-
-		Ok(())
+			.map_err(|e| crate::CommandError::ExecutionError(format!("File watcher error: {}", e)));
+		drop(asset_watchers);
+		result
 	}
 
 	/// Collect diagnostic state about the candidate spawn target.
@@ -3716,7 +4270,7 @@ fn sync_database_url_to_env(
 /// Replaces `user:password@` with `***@` to prevent credential leakage
 /// in logs and startup banners.
 #[cfg(feature = "reinhardt-db")]
-fn sanitize_database_url(url: &str) -> String {
+pub(crate) fn sanitize_database_url(url: &str) -> String {
 	if url == "sqlite::memory:" {
 		return url.to_string();
 	}
@@ -3792,15 +4346,13 @@ pub(crate) async fn initialize_orm_database(
 	Ok(())
 }
 
-/// Helper function to get DATABASE_URL from settings files only (ignoring env var).
-///
-/// Used for startup validation to detect configuration mismatches between
-/// the `DATABASE_URL` environment variable and `settings/*.toml` files.
+/// Load the project's `settings/*.toml` for the active `REINHARDT_ENV` profile.
 ///
-/// Visibility is `pub(crate)` so the in-crate regression tests for issue
-/// #4247 can call the real loader without going through a public API.
+/// Only the files and `REINHARDT_`-prefixed environment variables are read;
+/// commands use it to find configuration before the application is running.
 #[cfg(feature = "reinhardt-db")]
-pub(crate) fn get_database_url_from_settings() -> Result<String, crate::CommandError> {
+pub(crate) fn load_settings_from_disk()
+-> Result<reinhardt_conf::settings::builder::MergedSettings, crate::CommandError> {
 	use std::env;
 
 	let profile_str = env::var("REINHARDT_ENV").unwrap_or_else(|_| "local".to_string());
@@ -3809,7 +4361,7 @@ pub(crate) fn get_database_url_from_settings() -> Result<String, crate::CommandE
 	let base_dir = env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
 	let settings_dir = base_dir.join("settings");
 
-	let merged = reinhardt_conf::settings::builder::SettingsBuilder::new()
+	reinhardt_conf::settings::builder::SettingsBuilder::new()
 		.profile(profile)
 		.add_source(
 			reinhardt_conf::settings::sources::DefaultSource::new()
@@ -3842,7 +4394,19 @@ pub(crate) fn get_database_url_from_settings() -> Result<String, crate::CommandE
 		.build()
 		.map_err(|e| {
 			crate::CommandError::ExecutionError(format!("Failed to load settings: {}", e))
-		})?;
+		})
+}
+
+/// Helper function to get DATABASE_URL from settings files only (ignoring env var).
+///
+/// Used for startup validation to detect configuration mismatches between
+/// the `DATABASE_URL` environment variable and `settings/*.toml` files.
+///
+/// Visibility is `pub(crate)` so the in-crate regression tests for issue
+/// #4247 can call the real loader without going through a public API.
+#[cfg(feature = "reinhardt-db")]
+pub(crate) fn get_database_url_from_settings() -> Result<String, crate::CommandError> {
+	let merged = load_settings_from_disk()?;
 
 	// Locate the database configuration. Prefer the legacy/Django-style
 	// top-level `[database]` block, then fall back to the canonical
@@ -3900,7 +4464,9 @@ pub(crate) fn get_database_url_from_settings() -> Result<String, crate::CommandE
 
 /// Helper function to connect to database
 #[cfg(feature = "reinhardt-db")]
-async fn connect_database(url: &str) -> CommandResult<(DatabaseType, DatabaseConnection)> {
+pub(crate) async fn connect_database(
+	url: &str,
+) -> CommandResult<(DatabaseType, DatabaseConnection)> {
 	let db_type = if url.starts_with("postgres://") || url.starts_with("postgresql://") {
 		DatabaseType::Postgres
 	} else if url.starts_with("sqlite://")
@@ -4030,16 +4596,18 @@ impl BaseCommand for CheckDiCommand {
 	}
 }
 
-/// Database schema introspection command
+/// Database schema introspection command (`inspectdb`)
 ///
-/// Generates Reinhardt ORM models from existing database schema.
-pub struct IntrospectCommand;
+/// Generates Reinhardt ORM models from existing database schema: tables,
+/// columns, primary keys, foreign keys and indexes become `#[model]` structs,
+/// with `TODO` notes where the mapping is ambiguous.
+pub struct InspectDbCommand;
 
 #[cfg(feature = "migrations")]
 #[async_trait]
-impl BaseCommand for IntrospectCommand {
+impl BaseCommand for InspectDbCommand {
 	fn name(&self) -> &str {
-		"introspect"
+		"inspectdb"
 	}
 
 	fn description(&self) -> &str {
@@ -4068,6 +4636,11 @@ impl BaseCommand for IntrospectCommand {
 			CommandOption::flag(None, "force", "Overwrite existing files"),
 			CommandOption::flag(Some('v'), "verbose", "Show detailed output"),
 			CommandOption::flag(None, "single-file", "Generate all models in a single file"),
+			CommandOption::flag(
+				None,
+				"managed",
+				"Let migrations manage the generated models (default: managed = false)",
+			),
 		]
 	}
 
@@ -4120,6 +4693,10 @@ impl BaseCommand for IntrospectCommand {
 			config.output.single_file = true;
 		}
 
+		if ctx.has_option("managed") {
+			config.generation.managed = true;
+		}
+
 		// Handle include/exclude patterns
 		if let Some(include) = ctx.option("include") {
 			config.tables.include = vec![include.to_string()];
@@ -4262,16 +4839,16 @@ impl BaseCommand for IntrospectCommand {
 			}
 		}
 
-		ctx.success("✓ Introspection complete");
+		ctx.success("✓ inspectdb complete");
 		Ok(())
 	}
 }
 
 #[cfg(not(feature = "migrations"))]
 #[async_trait]
-impl BaseCommand for IntrospectCommand {
+impl BaseCommand for InspectDbCommand {
 	fn name(&self) -> &str {
-		"introspect"
+		"inspectdb"
 	}
 
 	fn description(&self) -> &str {
@@ -4288,9 +4865,97 @@ impl BaseCommand for IntrospectCommand {
 
 	async fn execute(&self, ctx: &CommandContext) -> CommandResult<()> {
 		ctx.warning("Migrations feature is not enabled");
-		ctx.info("To use introspect, enable the 'migrations' feature");
+		ctx.info("To use inspectdb, enable the 'migrations' feature");
+		Err(crate::CommandError::ExecutionError(
+			"inspectdb command requires 'migrations' feature to be enabled".to_string(),
+		))
+	}
+}
+
+/// Model diagram and schema reference command (`graph_models`)
+///
+/// Renders the models registered in the global model registry as a Graphviz
+/// DOT or Mermaid ER diagram, or as a markdown schema reference.
+pub struct GraphModelsCommand;
+
+#[cfg(feature = "migrations")]
+#[async_trait]
+impl BaseCommand for GraphModelsCommand {
+	fn name(&self) -> &str {
+		"graph_models"
+	}
+
+	fn description(&self) -> &str {
+		"Render registered models as a DOT/Mermaid diagram or markdown schema reference"
+	}
+
+	fn arguments(&self) -> Vec<CommandArgument> {
+		vec![CommandArgument::optional(
+			"app",
+			"App labels to include (default: all apps)",
+		)]
+	}
+
+	fn options(&self) -> Vec<CommandOption> {
+		vec![
+			CommandOption::option(
+				Some('f'),
+				"format",
+				"Output format: dot, mermaid or markdown",
+			)
+			.with_default("dot"),
+			CommandOption::option(Some('o'), "output", "Write to this file instead of stdout"),
+		]
+	}
+
+	async fn execute(&self, ctx: &CommandContext) -> CommandResult<()> {
+		use crate::CommandError;
+		use reinhardt_db::migrations::{ModelGraph, ModelGraphFormat, global_registry};
+
+		let format: ModelGraphFormat = ctx
+			.option("format")
+			.map(String::as_str)
+			.unwrap_or("dot")
+			.parse()
+			.map_err(CommandError::InvalidArguments)?;
+
+		let graph = ModelGraph::from_registry(global_registry()).with_app_labels(&ctx.args);
+		if graph.models().is_empty() {
+			ctx.warning("No registered models found");
+			return Ok(());
+		}
+		ctx.verbose(&format!("Rendering {} models", graph.models().len()));
+
+		let rendered = graph.render(format);
+		if let Some(path) = ctx.option("output") {
+			std::fs::write(path, &rendered).map_err(|e| {
+				CommandError::ExecutionError(format!("Failed to write {}: {}", path, e))
+			})?;
+			ctx.success(&format!("Wrote {}", path));
+		} else {
+			print!("{}", rendered);
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(not(feature = "migrations"))]
+#[async_trait]
+impl BaseCommand for GraphModelsCommand {
+	fn name(&self) -> &str {
+		"graph_models"
+	}
+
+	fn description(&self) -> &str {
+		"Render registered models as a DOT/Mermaid diagram or markdown schema reference"
+	}
+
+	async fn execute(&self, ctx: &CommandContext) -> CommandResult<()> {
+		ctx.warning("Migrations feature is not enabled");
+		ctx.info("To use graph_models, enable the 'migrations' feature");
 		Err(crate::CommandError::ExecutionError(
-			"introspect command requires 'migrations' feature to be enabled".to_string(),
+			"graph_models command requires 'migrations' feature to be enabled".to_string(),
 		))
 	}
 }
@@ -3,16 +3,22 @@
 //! This module provides a unified interface for executing commands from generated `manage.rs` files.
 //! It handles argument parsing, command context creation, and command execution.
 
-#[cfg(feature = "migrations")]
-use crate::MakeMigrationsCommand;
 use crate::base::BaseCommand;
 use crate::collectstatic::{CollectStaticCommand, CollectStaticOptions};
 use crate::local_infra::InfraSubcommand;
 use crate::registry::CommandRegistry;
-use crate::{CheckCommand, CommandContext, MigrateCommand, RunServerCommand, ShellCommand};
+use crate::{
+	AnonymizeUserCommand, CheckCommand, CommandContext, EnforceRetentionCommand,
+	ExportUserDataCommand, MigrateCommand, RecountCommand, RunServerCommand, SeedCommand,
+	ShellCommand,
+};
+#[cfg(feature = "backup")]
+use crate::{BackupCommand, RestoreCommand};
+#[cfg(feature = "migrations")]
+use crate::{GraphModelsCommand, InspectDbCommand, MakeMigrationsCommand};
 #[cfg(feature = "introspect")]
 use clap::ValueEnum;
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 use reinhardt_conf::HasCommonSettings;
 use reinhardt_conf::settings::builder::SettingsBuilder;
 use reinhardt_conf::settings::profile::Profile;
@@ -96,6 +102,67 @@ pub enum Commands {
 		migration_dir: PathBuf,
 	},
 
+	/// Generate model structs from an existing database
+	#[cfg(feature = "migrations")]
+	Inspectdb {
+		/// Database URL to introspect (default: DATABASE_URL)
+		#[arg(short = 'd', long, value_name = "DATABASE")]
+		database: Option<String>,
+
+		/// Output directory for generated files
+		#[arg(short = 'o', long, value_name = "DIR")]
+		output: Option<PathBuf>,
+
+		/// App label for generated models
+		#[arg(short = 'a', long, value_name = "APP_LABEL")]
+		app_label: Option<String>,
+
+		/// Path to configuration TOML file
+		#[arg(short = 'c', long, value_name = "FILE")]
+		config: Option<PathBuf>,
+
+		/// Regex pattern for tables to include
+		#[arg(long, value_name = "PATTERN")]
+		include: Option<String>,
+
+		/// Regex pattern for tables to exclude
+		#[arg(long, value_name = "PATTERN")]
+		exclude: Option<String>,
+
+		/// Print the generated code without writing files
+		#[arg(long)]
+		dry_run: bool,
+
+		/// Overwrite existing files
+		#[arg(long)]
+		force: bool,
+
+		/// Generate all models in a single file
+		#[arg(long)]
+		single_file: bool,
+
+		/// Let migrations manage the generated models (default: managed = false)
+		#[arg(long)]
+		managed: bool,
+	},
+
+	/// Render registered models as a diagram or markdown schema reference
+	#[cfg(feature = "migrations")]
+	#[command(name = "graph_models")]
+	GraphModels {
+		/// App labels to include (default: all apps)
+		#[arg(value_name = "APP_LABEL")]
+		app_labels: Vec<String>,
+
+		/// Output format: dot, mermaid or markdown
+		#[arg(short = 'f', long, default_value = "dot")]
+		format: String,
+
+		/// Write to this file instead of stdout
+		#[arg(short = 'o', long, value_name = "FILE")]
+		output: Option<PathBuf>,
+	},
+
 	/// Apply database migrations
 	Migrate {
 		/// App label to migrate
@@ -125,6 +192,112 @@ pub enum Commands {
 		/// Root directory containing migration files (default: ./migrations)
 		#[arg(long, value_name = "DIR")]
 		migrations_dir: Option<PathBuf>,
+
+		/// Do not apply seed data after migrating
+		#[arg(long)]
+		no_seed: bool,
+	},
+
+	/// Apply seed data declared by apps
+	Seed {
+		/// App label whose seeds to apply (default: all apps)
+		#[arg(value_name = "APP_LABEL")]
+		app_label: Option<String>,
+
+		/// Database connection string
+		#[arg(long, value_name = "DATABASE")]
+		database: Option<String>,
+
+		/// Re-apply seeds even when their checksum is unchanged
+		#[arg(long)]
+		force: bool,
+	},
+
+	/// Export the personal data stored about a user as JSON
+	#[command(name = "export_user_data")]
+	ExportUserData {
+		/// Id of the user whose data to export
+		#[arg(value_name = "USER_ID")]
+		user: String,
+
+		/// File to write the archive to (default: stdout)
+		#[arg(short, long, value_name = "FILE")]
+		output: Option<PathBuf>,
+	},
+
+	/// Irreversibly anonymize the personal data stored about a user
+	#[command(name = "anonymize_user")]
+	AnonymizeUser {
+		/// Id of the user whose data to anonymize
+		#[arg(value_name = "USER_ID")]
+		user: String,
+
+		/// Salt mixed into hashed values
+		#[arg(long, value_name = "SALT")]
+		salt: Option<String>,
+
+		/// Do not ask for confirmation
+		#[arg(short, long)]
+		yes: bool,
+	},
+
+	/// Recompute denormalized counter fields from the rows they count
+	Recount {
+		/// Only recount app_label.Model or app_label.Model.field
+		#[arg(value_name = "TARGET")]
+		target: Option<String>,
+	},
+
+	/// Delete or anonymize rows past their model's retention period
+	#[command(name = "enforce_retention")]
+	EnforceRetention {
+		/// Only report how many rows have expired
+		#[arg(long)]
+		dry_run: bool,
+
+		/// Rows processed per batch
+		#[arg(long, value_name = "ROWS")]
+		batch_size: Option<usize>,
+
+		/// Salt mixed into hashed values
+		#[arg(long, value_name = "SALT")]
+		salt: Option<String>,
+	},
+
+	/// Back up the database and media files to the backup storage
+	#[cfg(feature = "backup")]
+	Backup {
+		/// Database URL (default: DATABASE_URL)
+		#[arg(long, value_name = "URL")]
+		database: Option<String>,
+
+		/// Dump format: native or fixtures
+		#[arg(long, value_name = "FORMAT")]
+		format: Option<String>,
+
+		/// Do not archive the media files
+		#[arg(long)]
+		no_media: bool,
+	},
+
+	/// Restore the database and media files from a backup
+	#[cfg(feature = "backup")]
+	Restore {
+		/// Backup to restore (default: the newest one)
+		#[arg(value_name = "BACKUP")]
+		backup: Option<String>,
+
+		/// Database URL (default: DATABASE_URL)
+		#[arg(long, value_name = "URL")]
+		database: Option<String>,
+
+		/// Do not restore the media files
+		#[arg(long)]
+		no_media: bool,
+
+		/// Do not ask for confirmation
+		#[arg(short, long)]
+		yes: bool,
 	},
 
 	/// Manage local development infrastructure containers
@@ -240,6 +413,21 @@ pub enum Commands {
 		index: Option<String>,
 	},
 
+	/// Render routes to static HTML in STATIC_ROOT
+	Prerender {
+		/// Routes to render (default: every parameterless GET route)
+		#[arg(value_name = "ROUTE")]
+		routes: Vec<String>,
+
+		/// Output directory (default: STATIC_ROOT)
+		#[arg(short = 'o', long, value_name = "DIR")]
+		output: Option<PathBuf>,
+
+		/// Render without writing any file
+		#[arg(long)]
+		dry_run: bool,
+	},
+
 	/// Display all registered server URL patterns
 	Showurls {
 		/// Show only named URLs
@@ -493,9 +681,20 @@ async fn execute_with_registry_and_optional_settings(
 	registry: CommandRegistry,
 	settings: Option<Arc<dyn HasCommonSettings>>,
 ) -> Result<(), Box<dyn std::error::Error>> {
+	let mut registry = registry;
+	registry.register_discovered();
+
 	// Attempt normal clap parsing first. If it fails (e.g., unknown subcommand),
 	// fall back to checking the registry for a matching custom command.
-	let (command, verbosity) = match Cli::try_parse() {
+	// Custom commands are listed below the built-in ones in `--help`.
+	let mut cli = Cli::command();
+	if let Some(summary) = registry.help_summary() {
+		cli = cli.after_help(summary);
+	}
+	let parsed = cli
+		.try_get_matches()
+		.and_then(|matches| Cli::from_arg_matches(&matches));
+	let (command, verbosity) = match parsed {
 		Ok(cli) => (cli.command, cli.verbosity),
 		Err(clap_err) => {
 			// Only intercept "unknown subcommand" errors; re-raise others (--help, --version, etc.)
@@ -546,6 +745,8 @@ fn requires_router(command: &Commands) -> bool {
 	match command {
 		#[cfg(feature = "routers")]
 		Commands::Showurls { .. } => true,
+		#[cfg(feature = "routers")]
+		Commands::Prerender { .. } => true,
 		#[cfg(feature = "introspect")]
 		Commands::Introspect { .. } => true,
 		#[cfg(feature = "openapi")]
@@ -564,6 +765,11 @@ fn requires_database(command: &Commands) -> bool {
 	match command {
 		Commands::Runserver { .. } => true,
 		Commands::Migrate { .. } => true,
+		Commands::Seed { .. } => true,
+		Commands::ExportUserData { .. } => true,
+		Commands::AnonymizeUser { .. } => true,
+		Commands::Recount { .. } => true,
+		Commands::EnforceRetention { .. } => true,
 		#[cfg(feature = "auth")]
 		Commands::Createsuperuser { .. } => true,
 		_ => false,
@@ -678,6 +884,40 @@ async fn run_command_core(
 			)
 			.await
 		}
+		#[cfg(feature = "migrations")]
+		Commands::Inspectdb {
+			database,
+			output,
+			app_label,
+			config,
+			include,
+			exclude,
+			dry_run,
+			force,
+			single_file,
+			managed,
+		} => {
+			execute_inspectdb(InspectDbParams {
+				database,
+				output,
+				app_label,
+				config,
+				include,
+				exclude,
+				dry_run,
+				force,
+				single_file,
+				managed,
+				verbosity,
+			})
+			.await
+		}
+		#[cfg(feature = "migrations")]
+		Commands::GraphModels {
+			app_labels,
+			format,
+			output,
+		} => execute_graph_models(app_labels, format, output, verbosity).await,
 		Commands::Migrate {
 			app_label,
 			migration_name,
@@ -686,6 +926,7 @@ async fn run_command_core(
 			fake_initial,
 			plan,
 			migrations_dir,
+			no_seed,
 		} => {
 			execute_migrate(MigrateParams {
 				app_label,
@@ -695,10 +936,41 @@ async fn run_command_core(
 				fake_initial,
 				plan,
 				migrations_dir,
+				no_seed,
 				verbosity,
 			})
 			.await
 		}
+		Commands::Seed {
+			app_label,
+			database,
+			force,
+		} => execute_seed(app_label, database, force, verbosity).await,
+		Commands::ExportUserData { user, output } => {
+			execute_export_user_data(user, output, verbosity).await
+		}
+		Commands::AnonymizeUser { user, salt, yes } => {
+			execute_anonymize_user(user, salt, yes, verbosity).await
+		}
+		Commands::Recount { target } => execute_recount(target, verbosity).await,
+		Commands::EnforceRetention {
+			dry_run,
+			batch_size,
+			salt,
+		} => execute_enforce_retention(dry_run, batch_size, salt, verbosity).await,
+		#[cfg(feature = "backup")]
+		Commands::Backup {
+			database,
+			format,
+			no_media,
+		} => execute_backup(database, format, no_media, verbosity).await,
+		#[cfg(feature = "backup")]
+		Commands::Restore {
+			backup,
+			database,
+			no_media,
+			yes,
+		} => execute_restore(backup, database, no_media, yes, verbosity).await,
 		Commands::Infra { command } => {
 			crate::local_infra::InfraCommand::execute(
 				command,
@@ -753,6 +1025,11 @@ async fn run_command_core(
 			index,
 		} => execute_collectstatic(clear, no_input, dry_run, link, ignore, index, verbosity).await,
 		Commands::Showurls { names } => execute_showurls(names, verbosity).await,
+		Commands::Prerender {
+			routes,
+			output,
+			dry_run,
+		} => execute_prerender(routes, output, dry_run, verbosity).await,
 		#[cfg(feature = "introspect")]
 		Commands::Introspect { format, section } => execute_introspect(format, section, verbosity).await,
 		#[cfg(feature = "openapi")]
@@ -780,7 +1057,7 @@ async fn run_command_core(
 			.await
 		}
 		Commands::Custom { name, args } => {
-			execute_custom_command(&name, &args, verbosity, &registry).await
+			execute_custom_command(&name, &args, verbosity, &registry, settings).await
 		}
 	}
 }
@@ -853,6 +1130,7 @@ async fn execute_custom_command(
 	args: &[String],
 	verbosity: u8,
 	registry: &CommandRegistry,
+	settings: Option<Arc<dyn HasCommonSettings>>,
 ) -> Result<(), Box<dyn std::error::Error>> {
 	let cmd = registry.get(name).ok_or_else(|| {
 		format!(
@@ -867,6 +1145,10 @@ async fn execute_custom_command(
 	for arg in args {
 		ctx.add_arg(arg.clone());
 	}
+	// Give custom commands the same composed settings as the built-in ones.
+	if let Some(settings) = settings {
+		ctx = ctx.with_settings(settings);
+	}
 
 	cmd.execute(&ctx).await.map_err(|e| e.into())
 }
@@ -925,6 +1207,90 @@ async fn execute_makemigrations(
 	cmd.execute(&ctx).await.map_err(|e| e.into())
 }
 
+/// Parameters for the inspectdb command
+#[cfg(feature = "migrations")]
+#[derive(Debug)]
+struct InspectDbParams {
+	database: Option<String>,
+	output: Option<PathBuf>,
+	app_label: Option<String>,
+	config: Option<PathBuf>,
+	include: Option<String>,
+	exclude: Option<String>,
+	dry_run: bool,
+	force: bool,
+	single_file: bool,
+	managed: bool,
+	verbosity: u8,
+}
+
+/// Execute the inspectdb command
+#[cfg(feature = "migrations")]
+async fn execute_inspectdb(params: InspectDbParams) -> Result<(), Box<dyn std::error::Error>> {
+	let mut ctx = CommandContext::default();
+	ctx.set_verbosity(params.verbosity);
+
+	if let Some(db) = params.database {
+		ctx.set_option("database".to_string(), db);
+	}
+	if let Some(dir) = params.output {
+		ctx.set_option("output".to_string(), dir.to_string_lossy().to_string());
+	}
+	if let Some(label) = params.app_label {
+		ctx.set_option("app-label".to_string(), label);
+	}
+	if let Some(path) = params.config {
+		ctx.set_option("config".to_string(), path.to_string_lossy().to_string());
+	}
+	if let Some(pattern) = params.include {
+		ctx.set_option("include".to_string(), pattern);
+	}
+	if let Some(pattern) = params.exclude {
+		ctx.set_option("exclude".to_string(), pattern);
+	}
+	if params.dry_run {
+		ctx.set_option("dry-run".to_string(), "true".to_string());
+	}
+	if params.force {
+		ctx.set_option("force".to_string(), "true".to_string());
+	}
+	if params.single_file {
+		ctx.set_option("single-file".to_string(), "true".to_string());
+	}
+	if params.managed {
+		ctx.set_option("managed".to_string(), "true".to_string());
+	}
+	if params.verbosity > 0 {
+		ctx.set_option("verbose".to_string(), "true".to_string());
+	}
+
+	let cmd = InspectDbCommand;
+	cmd.execute(&ctx).await.map_err(|e| e.into())
+}
+
+/// Execute the graph_models command
+#[cfg(feature = "migrations")]
+async fn execute_graph_models(
+	app_labels: Vec<String>,
+	format: String,
+	output: Option<PathBuf>,
+	verbosity: u8,
+) -> Result<(), Box<dyn std::error::Error>> {
+	let mut ctx = CommandContext::default();
+	ctx.set_verbosity(verbosity);
+
+	for label in app_labels {
+		ctx.add_arg(label);
+	}
+	ctx.set_option("format".to_string(), format);
+	if let Some(path) = output {
+		ctx.set_option("output".to_string(), path.to_string_lossy().to_string());
+	}
+
+	let cmd = GraphModelsCommand;
+	cmd.execute(&ctx).await.map_err(|e| e.into())
+}
+
 /// Parameters for the migrate command
 #[derive(Debug)]
 struct MigrateParams {
@@ -935,6 +1301,7 @@ struct MigrateParams {
 	fake_initial: bool,
 	plan: bool,
 	migrations_dir: Option<PathBuf>,
+	no_seed: bool,
 	verbosity: u8,
 }
 
@@ -969,10 +1336,168 @@ async fn execute_migrate(params: MigrateParams) -> Result<(), Box<dyn std::error
 		);
 	}
 
+	if params.no_seed {
+		ctx.set_option("no-seed".to_string(), "true".to_string());
+	}
+
 	let cmd = MigrateCommand;
 	cmd.execute(&ctx).await.map_err(|e| e.into())
 }
 
+/// Execute the seed command
+async fn execute_seed(
+	app_label: Option<String>,
+	database: Option<String>,
+	force: bool,
+	verbosity: u8,
+) -> Result<(), Box<dyn std::error::Error>> {
+	let mut ctx = CommandContext::default();
+	ctx.set_verbosity(verbosity);
+
+	if let Some(app) = app_label {
+		ctx.add_arg(app);
+	}
+	if let Some(db) = database {
+		ctx.set_option("database".to_string(), db);
+	}
+	if force {
+		ctx.set_option("force".to_string(), "true".to_string());
+	}
+
+	let cmd = SeedCommand;
+	cmd.execute(&ctx).await.map_err(|e| e.into())
+}
+
+/// Execute the export_user_data command
+async fn execute_export_user_data(
+	user: String,
+	output: Option<PathBuf>,
+	verbosity: u8,
+) -> Result<(), Box<dyn std::error::Error>> {
+	let mut ctx = CommandContext::default();
+	ctx.set_verbosity(verbosity);
+	ctx.add_arg(user);
+
+	if let Some(path) = output {
+		ctx.set_option("output".to_string(), path.to_string_lossy().to_string());
+	}
+
+	let cmd = ExportUserDataCommand;
+	cmd.execute(&ctx).await.map_err(|e| e.into())
+}
+
+/// Execute the anonymize_user command
+async fn execute_anonymize_user(
+	user: String,
+	salt: Option<String>,
+	yes: bool,
+	verbosity: u8,
+) -> Result<(), Box<dyn std::error::Error>> {
+	let mut ctx = CommandContext::default();
+	ctx.set_verbosity(verbosity);
+	ctx.add_arg(user);
+
+	if let Some(salt) = salt {
+		ctx.set_option("salt".to_string(), salt);
+	}
+	if yes {
+		ctx.set_option("yes".to_string(), "true".to_string());
+	}
+
+	let cmd = AnonymizeUserCommand;
+	cmd.execute(&ctx).await.map_err(|e| e.into())
+}
+
+/// Execute the recount command
+async fn execute_recount(
+	target: Option<String>,
+	verbosity: u8,
+) -> Result<(), Box<dyn std::error::Error>> {
+	let mut ctx = CommandContext::default();
+	ctx.set_verbosity(verbosity);
+	if let Some(target) = target {
+		ctx.add_arg(target);
+	}
+
+	let cmd = RecountCommand;
+	cmd.execute(&ctx).await.map_err(|e| e.into())
+}
+
+/// Execute the enforce_retention command
+async fn execute_enforce_retention(
+	dry_run: bool,
+	batch_size: Option<usize>,
+	salt: Option<String>,
+	verbosity: u8,
+) -> Result<(), Box<dyn std::error::Error>> {
+	let mut ctx = CommandContext::default();
+	ctx.set_verbosity(verbosity);
+
+	if dry_run {
+		ctx.set_option("dry-run".to_string(), "true".to_string());
+	}
+	if let Some(batch_size) = batch_size {
+		ctx.set_option("batch-size".to_string(), batch_size.to_string());
+	}
+	if let Some(salt) = salt {
+		ctx.set_option("salt".to_string(), salt);
+	}
+
+	let cmd = EnforceRetentionCommand;
+	cmd.execute(&ctx).await.map_err(|e| e.into())
+}
+
+/// Execute the backup command
+#[cfg(feature = "backup")]
+async fn execute_backup(
+	database: Option<String>,
+	format: Option<String>,
+	no_media: bool,
+	verbosity: u8,
+) -> Result<(), Box<dyn std::error::Error>> {
+	let mut ctx = CommandContext::default();
+	ctx.set_verbosity(verbosity);
+
+	if let Some(database) = database {
+		ctx.set_option("database".to_string(), database);
+	}
+	if let Some(format) = format {
+		ctx.set_option("format".to_string(), format);
+	}
+	if no_media {
+		ctx.set_option("no-media".to_string(), "true".to_string());
+	}
+
+	let cmd = BackupCommand;
+	cmd.execute(&ctx).await.map_err(|e| e.into())
+}
+
+/// Execute the restore command
+#[cfg(feature = "backup")]
+async fn execute_restore(
+	backup: Option<String>,
+	database: Option<String>,
+	no_media: bool,
+	yes: bool,
+	verbosity: u8,
+) -> Result<(), Box<dyn std::error::Error>> {
+	let mut ctx = CommandContext::new(backup.into_iter().collect());
+	ctx.set_verbosity(verbosity);
+
+	if let Some(database) = database {
+		ctx.set_option("database".to_string(), database);
+	}
+	if no_media {
+		ctx.set_option("no-media".to_string(), "true".to_string());
+	}
+	if yes {
+		ctx.set_option("yes".to_string(), "true".to_string());
+	}
+
+	let cmd = RestoreCommand;
+	cmd.execute(&ctx).await.map_err(|e| e.into())
+}
+
 /// Options for the runserver command
 struct RunServerOptions {
 	address: String,
@@ -1090,12 +1615,65 @@ async fn execute_collectstatic(
 	index: Option<String>,
 	verbosity: u8,
 ) -> Result<(), Box<dyn std::error::Error>> {
+	let base_dir =
+		env::current_dir().map_err(|e| format!("Failed to get current directory: {e}"))?;
+	let config = load_static_files_config(&base_dir)?;
+
+	// Create options
+	let options = CollectStaticOptions {
+		clear,
+		no_input,
+		dry_run,
+		interactive: !no_input,
+		link,
+		ignore_patterns: ignore,
+		verbosity,
+		enable_hashing: true,
+		fast_compare: false,
+	};
+
+	// Resolve index source path
+	// Refs #2869: Auto-detect index.html from project root for collectstatic
+	let index_source = match &index {
+		Some(path) => Some(PathBuf::from(path)),
+		None => {
+			// Auto-detect from project root
+			let candidate = base_dir.join("index.html");
+			if candidate.exists() {
+				Some(candidate)
+			} else {
+				None
+			}
+		}
+	};
+
+	// Create and execute command in blocking context
+	let mut cmd = CollectStaticCommand::new(config, options);
+	cmd.set_index_source(index_source);
+	let result = tokio::task::spawn_blocking(move || {
+		// Call the sync execute() method directly (not the BaseCommand trait method)
+		CollectStaticCommand::execute(&mut cmd)
+	})
+	.await;
+
+	match result {
+		Ok(Ok(_stats)) => Ok(()),
+		Ok(Err(e)) => Err(Box::new(e) as Box<dyn std::error::Error>),
+		Err(e) => Err(Box::new(e) as Box<dyn std::error::Error>),
+	}
+}
+
+/// Load the static files configuration from the project settings
+///
+/// Merges `settings/base.toml` and the profile file selected by
+/// `REINHARDT_ENV` over the framework defaults.
+fn load_static_files_config(
+	base_dir: &std::path::Path,
+) -> Result<StaticFilesConfig, Box<dyn std::error::Error>> {
 	// Load settings from TOML files
 	let profile_str = env::var("REINHARDT_ENV").unwrap_or_else(|_| "local".to_string());
 	let profile = Profile::parse(&profile_str);
 
-	let base_dir =
-		env::current_dir().map_err(|e| format!("Failed to get current directory: {e}"))?;
 	let settings_dir = base_dir.join("settings");
 
 	// Generate a random secret key for the default to avoid shipping a
@@ -1167,55 +1745,12 @@ async fn execute_collectstatic(
 		.ok()
 		.map(PathBuf::from)
 		.unwrap_or_else(|| base_dir.join("staticfiles"));
-	let config = StaticFilesConfig {
+	Ok(StaticFilesConfig {
 		static_root,
 		static_url: merged.get_or("static_url", "/static/".to_string()),
 		staticfiles_dirs: merged.get_or("staticfiles_dirs", Vec::new()),
 		media_url: None,
-	};
-
-	// Create options
-	let options = CollectStaticOptions {
-		clear,
-		no_input,
-		dry_run,
-		interactive: !no_input,
-		link,
-		ignore_patterns: ignore,
-		verbosity,
-		enable_hashing: true,
-		fast_compare: false,
-	};
-
-	// Resolve index source path
-	// Refs #2869: Auto-detect index.html from project root for collectstatic
-	let index_source = match &index {
-		Some(path) => Some(PathBuf::from(path)),
-		None => {
-			// Auto-detect from project root
-			let candidate = base_dir.join("index.html");
-			if candidate.exists() {
-				Some(candidate)
-			} else {
-				None
-			}
-		}
-	};
-
-	// Create and execute command in blocking context
-	let mut cmd = CollectStaticCommand::new(config, options);
-	cmd.set_index_source(index_source);
-	let result = tokio::task::spawn_blocking(move || {
-		// Call the sync execute() method directly (not the BaseCommand trait method)
-		CollectStaticCommand::execute(&mut cmd)
 	})
-	.await;
-
-	match result {
-		Ok(Ok(_stats)) => Ok(()),
-		Ok(Err(e)) => Err(Box::new(e) as Box<dyn std::error::Error>),
-		Err(e) => Err(Box::new(e) as Box<dyn std::error::Error>),
-	}
 }
 
 /// Execute the showurls command
@@ -1240,6 +1775,46 @@ async fn execute_showurls(_names: bool, _verbosity: u8) -> Result<(), Box<dyn st
 		.into())
 }
 
+/// Execute the prerender command
+#[cfg(feature = "routers")]
+async fn execute_prerender(
+	routes: Vec<String>,
+	output: Option<PathBuf>,
+	dry_run: bool,
+	verbosity: u8,
+) -> Result<(), Box<dyn std::error::Error>> {
+	use crate::prerender::{PrerenderCommand, PrerenderOptions};
+
+	let base_dir =
+		env::current_dir().map_err(|e| format!("Failed to get current directory: {e}"))?;
+	let config = load_static_files_config(&base_dir)?;
+
+	let mut ctx = CommandContext::default();
+	ctx.set_verbosity(verbosity);
+
+	let cmd = PrerenderCommand::new(PrerenderOptions {
+		routes,
+		output_dir: output.unwrap_or(config.static_root),
+		static_url: config.static_url,
+		dry_run,
+		verbosity,
+	});
+	cmd.execute(&ctx).await.map_err(|e| e.into())
+}
+
+#[cfg(not(feature = "routers"))]
+async fn execute_prerender(
+	_routes: Vec<String>,
+	_output: Option<PathBuf>,
+	_dry_run: bool,
+	_verbosity: u8,
+) -> Result<(), Box<dyn std::error::Error>> {
+	Err("prerender command requires 'routers' feature. \
+		Enable it in your Cargo.toml: \
+		reinhardt-commands = { version = \"0.1.0\", features = [\"routers\"] }"
+		.into())
+}
+
 /// Execute the introspect command
 #[cfg(feature = "introspect")]
 async fn execute_introspect(
@@ -1598,6 +2173,24 @@ mod tests {
 		assert!(result);
 	}
 
+	#[cfg(feature = "routers")]
+	#[rstest]
+	fn test_requires_router_for_prerender() {
+		// Arrange
+		let cli =
+			Cli::try_parse_from(["manage", "prerender", "/", "/about/", "--dry-run"]).unwrap();
+
+		// Act
+		let result = requires_router(&cli.command);
+
+		// Assert
+		assert!(result);
+		assert!(matches!(
+			cli.command,
+			Commands::Prerender { ref routes, dry_run: true, .. } if routes.len() == 2
+		));
+	}
+
 	#[cfg(feature = "openapi")]
 	#[rstest]
 	fn test_requires_router_for_generateopenapi() {
@@ -1626,6 +2219,7 @@ mod tests {
 			fake_initial: false,
 			plan: false,
 			migrations_dir: None,
+			no_seed: false,
 		};
 
 		// Act
@@ -2112,6 +2706,103 @@ mod tests {
 		assert!(result);
 	}
 
+	#[cfg(feature = "reinhardt-db")]
+	#[rstest]
+	fn test_requires_database_for_anonymize_user() {
+		use clap::Parser;
+
+		// Arrange
+		let cli = Cli::parse_from(["manage", "anonymize_user", "42", "--yes"]);
+
+		// Act
+		let result = requires_database(&cli.command);
+
+		// Assert
+		assert!(result);
+		assert!(matches!(
+			cli.command,
+			Commands::AnonymizeUser { ref user, yes: true, .. } if user == "42"
+		));
+	}
+
+	#[cfg(feature = "reinhardt-db")]
+	#[rstest]
+	fn test_requires_database_for_recount() {
+		use clap::Parser;
+
+		// Arrange
+		let cli = Cli::parse_from(["manage", "recount", "blog.Post"]);
+
+		// Act
+		let result = requires_database(&cli.command);
+
+		// Assert
+		assert!(result);
+		assert!(matches!(
+			cli.command,
+			Commands::Recount { target: Some(ref target) } if target == "blog.Post"
+		));
+	}
+
+	#[cfg(feature = "reinhardt-db")]
+	#[rstest]
+	fn test_requires_database_for_enforce_retention() {
+		use clap::Parser;
+
+		// Arrange
+		let cli = Cli::parse_from([
+			"manage",
+			"enforce_retention",
+			"--dry-run",
+			"--batch-size",
+			"100",
+		]);
+
+		// Act
+		let result = requires_database(&cli.command);
+
+		// Assert
+		assert!(result);
+		assert!(matches!(
+			cli.command,
+			Commands::EnforceRetention {
+				dry_run: true,
+				batch_size: Some(100),
+				salt: None,
+			}
+		));
+	}
+
+	#[cfg(feature = "backup")]
+	#[rstest]
+	fn test_restore_runs_without_orm_initialization() {
+		use clap::Parser;
+
+		// Arrange
+		let cli = Cli::parse_from([
+			"manage",
+			"restore",
+			"backup-20261016T013000Z.tar.gz",
+			"--yes",
+		]);
+
+		// Act
+		let result = requires_database(&cli.command);
+
+		// Assert
+		// The restore replaces the database, so no pool may hold it open.
+		assert!(!result);
+		assert!(matches!(
+			cli.command,
+			Commands::Restore {
+				backup: Some(ref backup),
+				database: None,
+				no_media: false,
+				yes: true,
+			} if backup == "backup-20261016T013000Z.tar.gz"
+		));
+	}
+
 	#[cfg(feature = "reinhardt-db")]
 	#[rstest]
 	fn test_requires_database_for_migrate() {
@@ -2124,6 +2815,7 @@ mod tests {
 			fake_initial: false,
 			plan: false,
 			migrations_dir: None,
+			no_seed: false,
 		};
 
 		// Act
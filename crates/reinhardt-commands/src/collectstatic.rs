@@ -197,6 +197,18 @@ impl CollectStaticCommand {
 			}
 		}
 
+		// Run registered asset build steps (Tailwind, esbuild, ...) so their
+		// outputs exist before the source directories are scanned.
+		let asset_steps = crate::asset_pipeline::registered_asset_steps();
+		if !self.options.dry_run {
+			for step in &asset_steps {
+				if self.options.verbosity > 0 {
+					println!("Running asset build step '{}'", step.name());
+				}
+				step.run().map_err(io::Error::other)?;
+			}
+		}
+
 		// Collect files from all source directories
 		// Start with manually configured directories
 		let mut all_dirs = self.config.staticfiles_dirs.clone();
@@ -221,6 +233,16 @@ impl CollectStaticCommand {
 			}
 		}
 
+		// Collect asset build outputs written outside the static directories
+		for output_dir in asset_steps
+			.iter()
+			.filter_map(|step| step.resolved_output_dir())
+		{
+			if !all_dirs.iter().any(|dir| output_dir.starts_with(dir)) {
+				all_dirs.push(output_dir);
+			}
+		}
+
 		let finder = StaticFilesFinder::new(all_dirs.clone());
 		let all_files = finder.find_all();
 
@@ -30,9 +30,10 @@ pub const DEBOUNCE_WINDOW: Duration = Duration::from_millis(120);
 ///
 /// The accept rules are intentionally narrow:
 /// * Event kind must be `Modify`, `Create`, or `Remove`.
-/// * At least one path must end in `.rs` or `.toml`, or have the exact
-///   file name `Cargo.lock` (matched via `Path::file_name`, so unrelated
-///   `.lock` files and `Cargo.lock.bak` do not slip through).
+/// * At least one path must end in `.rs`, `.toml` or `.css`, or have the
+///   exact file name `Cargo.lock` (matched via `Path::file_name`, so
+///   unrelated `.lock` files and `Cargo.lock.bak` do not slip through).
+///   Stylesheets are hot-swapped rather than rebuilt.
 /// * Paths inside `target/` or `.git/`, and editor sidecar files
 ///   (`~`, `.swp`, `.tmp`), are rejected.
 pub fn is_relevant_change(event: &Event) -> bool {
@@ -54,10 +55,19 @@ pub fn is_relevant_change(event: &Event) -> bool {
 			&& !path_str.ends_with('~')
 			&& !path_str.ends_with(".swp")
 			&& !path_str.ends_with(".tmp")
-			&& (path_str.ends_with(".rs") || path_str.ends_with(".toml") || is_cargo_lock)
+			&& (path_str.ends_with(".rs")
+				|| path_str.ends_with(".toml")
+				|| is_cargo_lock
+				|| is_stylesheet(p))
 	})
 }
 
+/// Whether `path` is a stylesheet, which the browser can hot-swap without
+/// any rebuild.
+fn is_stylesheet(path: &std::path::Path) -> bool {
+	path.extension().is_some_and(|ext| ext == "css")
+}
+
 /// Block until a relevant event arrives, then keep collecting any further
 /// events that arrive within `window` of *now*.
 ///
@@ -153,7 +163,7 @@ pub fn rebuild_targets_for_paths(paths: &[PathBuf], config: &WatcherConfig) -> R
 
 	if !pages_enabled {
 		return RebuildTargets {
-			server: !paths.is_empty(),
+			server: paths.iter().any(|path| !is_stylesheet(path)),
 			wasm: false,
 		};
 	}
@@ -164,7 +174,7 @@ pub fn rebuild_targets_for_paths(paths: &[PathBuf], config: &WatcherConfig) -> R
 		wasm: false,
 	};
 
-	for path in paths {
+	for path in paths.iter().filter(|path| !is_stylesheet(path)) {
 		match classify_pages_path(path) {
 			PagesPathClass::WasmOnly => {
 				targets.wasm |= wasm_enabled;
@@ -270,6 +280,65 @@ fn notify_browser_reload(hmr_tx: Option<&broadcast::Sender<String>>, reason: &st
 	}
 }
 
+#[cfg(feature = "pages")]
+fn notify_build_error(hmr_tx: Option<&broadcast::Sender<String>>, message: &str) {
+	let Some(tx) = hmr_tx else {
+		return;
+	};
+	let msg = reinhardt_pages::hmr::HmrMessage::BuildError {
+		message: message.to_string(),
+	};
+	if let Ok(json) = msg.to_json() {
+		let _ = tx.send(json);
+	}
+}
+
+/// Diagnostics of a failed server rebuild, forwarded to the browser overlay.
+#[cfg(feature = "pages")]
+fn server_failure_message(
+	outcome: &crate::server_rebuild_pipeline::ServerRebuildOutcome,
+) -> Option<&str> {
+	use crate::server_rebuild_pipeline::ServerRebuildOutcome;
+	match outcome {
+		ServerRebuildOutcome::Ok { .. } => None,
+		ServerRebuildOutcome::BuildFailed { stderr_tail, .. } => Some(stderr_tail),
+		ServerRebuildOutcome::SpawnFailed { message, .. } => Some(message),
+	}
+}
+
+/// Push changed stylesheets to the browser as CSS hot swaps.
+///
+/// Paths are sent relative to the watched `static/` or `src/` directory that
+/// contains them, which is how the client matches them against the `href`
+/// of the page's `<link rel="stylesheet">` tags. Returns whether any
+/// stylesheet was pushed.
+#[cfg(feature = "pages")]
+fn notify_css_hot_swap(
+	hmr_tx: Option<&broadcast::Sender<String>>,
+	paths: &[PathBuf],
+	roots: &SourceRoots,
+) -> bool {
+	let Some(tx) = hmr_tx else {
+		return false;
+	};
+	let mut sent = false;
+	for path in paths.iter().filter(|path| is_stylesheet(path)) {
+		let relative = roots
+			.static_dirs
+			.iter()
+			.chain(&roots.src_dirs)
+			.find_map(|dir| path.strip_prefix(dir).ok())
+			.unwrap_or(path);
+		let msg = reinhardt_pages::hmr::HmrMessage::CssUpdate {
+			path: normalized_path(relative),
+		};
+		if let Ok(json) = msg.to_json() {
+			sent |= tx.send(json).is_ok();
+		}
+	}
+	sent
+}
+
 #[cfg(feature = "pages")]
 fn notify_static_page_patch(
 	hmr_tx: Option<&broadcast::Sender<String>>,
@@ -310,9 +379,15 @@ pub async fn run_rebuild_for_paths(
 		"[hot-reload] change detected ({} path(s))",
 		paths.len()
 	));
+	#[cfg(feature = "pages")]
+	if notify_css_hot_swap(config.hmr_tx.as_ref(), &paths, &config.roots) {
+		ctx.info("[hot-reload] stylesheet change pushed without rebuilding");
+	}
 	let targets = rebuild_targets_for_paths(&paths, config);
 	if !targets.has_work() {
-		ctx.info("[hot-reload] no rebuild target matched; waiting for next change");
+		if paths.iter().any(|path| !is_stylesheet(path)) {
+			ctx.info("[hot-reload] no rebuild target matched; waiting for next change");
+		}
 		return;
 	}
 	#[cfg(feature = "pages")]
@@ -330,10 +405,11 @@ pub async fn run_rebuild_for_paths(
 					crate::wasm_rebuild_pipeline::WasmRebuildPipeline::format_log_line(&outcome)
 				{
 					eprintln!("{}", line);
-					if matches!(
-						outcome,
-						crate::wasm_rebuild_pipeline::WasmRebuildOutcome::Failed { .. }
-					) {
+					if let crate::wasm_rebuild_pipeline::WasmRebuildOutcome::Failed {
+						error, ..
+					} = &outcome
+					{
+						notify_build_error(config.hmr_tx.as_ref(), &error.to_string());
 						eprintln!("[hot-reload] watching for next change...");
 					}
 				}
@@ -356,6 +432,10 @@ pub async fn run_rebuild_for_paths(
 		);
 		let (wasm_ok, (server_outcome, new_child)) = tokio::join!(wasm_fut, server_fut);
 		let server_ok = server_rebuild_succeeded(&server_outcome);
+		#[cfg(feature = "pages")]
+		if let Some(message) = server_failure_message(&server_outcome) {
+			notify_build_error(config.hmr_tx.as_ref(), message);
+		}
 		if let Some(child) = new_child {
 			*current_child = child;
 		}
@@ -394,6 +474,10 @@ pub async fn run_rebuild_for_paths(
 			)
 			.await;
 		let server_ok = server_rebuild_succeeded(&server_outcome);
+		#[cfg(feature = "pages")]
+		if let Some(message) = server_failure_message(&server_outcome) {
+			notify_build_error(config.hmr_tx.as_ref(), message);
+		}
 		if let Some(child) = new_child {
 			*current_child = child;
 		}
@@ -467,6 +551,11 @@ pub async fn run_watcher(
 			watcher.watch(dir, RecursiveMode::Recursive)?;
 		}
 	}
+	for dir in &config.roots.static_dirs {
+		if dir.exists() {
+			watcher.watch(dir, RecursiveMode::Recursive)?;
+		}
+	}
 	for manifest in &config.roots.manifest_files {
 		if manifest.exists() {
 			watcher.watch(manifest, RecursiveMode::NonRecursive)?;
@@ -549,6 +638,11 @@ mod tests {
 		false
 	)]
 	#[case::generic_lock_rejected(EventKind::Modify(ModifyKind::Any), "/project/foo.lock", false)]
+	#[case::stylesheet_modify(
+		EventKind::Modify(ModifyKind::Any),
+		"/project/static/css/style.css",
+		true
+	)]
 	fn is_relevant_change_filter_cases(
 		#[case] kind: EventKind,
 		#[case] path: &str,
@@ -645,6 +739,7 @@ mod tests {
 				src_dirs: vec![PathBuf::from("/project/src")],
 				manifest_files: vec![PathBuf::from("/project/Cargo.toml")],
 				lockfile: Some(PathBuf::from("/project/Cargo.lock")),
+				static_dirs: vec![PathBuf::from("/project/static")],
 			},
 			debounce_window: DEBOUNCE_WINDOW,
 			server_address: None,
@@ -747,6 +842,78 @@ mod tests {
 		);
 	}
 
+	#[cfg(feature = "pages")]
+	#[test]
+	fn rebuild_targets_skip_stylesheets() {
+		// Arrange
+		let config = pages_config(false);
+
+		// Act
+		let actual =
+			rebuild_targets_for_paths(&[PathBuf::from("/project/static/css/style.css")], &config);
+
+		// Assert
+		assert_eq!(
+			actual,
+			RebuildTargets {
+				server: false,
+				wasm: false,
+			},
+		);
+	}
+
+	#[cfg(feature = "pages")]
+	#[test]
+	fn notify_css_hot_swap_sends_path_relative_to_static_dir() {
+		// Arrange
+		let config = pages_config(false);
+		let (tx, mut rx) = broadcast::channel::<String>(8);
+
+		// Act
+		let sent = notify_css_hot_swap(
+			Some(&tx),
+			&[
+				PathBuf::from("/project/static/css/style.css"),
+				PathBuf::from("/project/src/lib.rs"),
+			],
+			&config.roots,
+		);
+
+		// Assert
+		assert!(sent, "stylesheet change should be pushed");
+		let json = rx.try_recv().expect("css message should be broadcast");
+		assert_eq!(
+			reinhardt_pages::hmr::HmrMessage::from_json(&json).unwrap(),
+			reinhardt_pages::hmr::HmrMessage::CssUpdate {
+				path: "css/style.css".to_string()
+			}
+		);
+		assert!(rx.try_recv().is_err(), "only stylesheets are pushed");
+	}
+
+	#[cfg(feature = "pages")]
+	#[test]
+	fn notify_build_error_sends_diagnostics() {
+		// Arrange
+		let (tx, mut rx) = broadcast::channel::<String>(8);
+		let outcome = crate::server_rebuild_pipeline::ServerRebuildOutcome::BuildFailed {
+			duration: Duration::from_secs(1),
+			stderr_tail: "error[E0308]: mismatched types".to_string(),
+		};
+
+		// Act
+		notify_build_error(Some(&tx), server_failure_message(&outcome).unwrap());
+
+		// Assert
+		let json = rx.try_recv().expect("build error should be broadcast");
+		assert_eq!(
+			reinhardt_pages::hmr::HmrMessage::from_json(&json).unwrap(),
+			reinhardt_pages::hmr::HmrMessage::BuildError {
+				message: "error[E0308]: mismatched types".to_string()
+			}
+		);
+	}
+
 	#[cfg(feature = "pages")]
 	#[test]
 	fn notify_browser_reload_without_channel_is_noop() {
@@ -0,0 +1,310 @@
+//! Management commands contributed by apps and third-party crates.
+//!
+//! A crate registers a command once, next to its definition, and every
+//! project that links the crate can run it as `manage <name>` without
+//! adding it to a [`CommandRegistry`](crate::CommandRegistry) by hand. Registrations are collected in
+//! the [`MANAGEMENT_COMMANDS`] distributed slice and merged into the registry
+//! by [`execute_from_command_line`](crate::execute_from_command_line) and its
+//! variants; commands registered explicitly take precedence over discovered
+//! ones with the same name.
+//!
+//! The easiest way to write such a command is [`ClapCommand`]: arguments are
+//! a `clap` derive struct, `run` is async, and `manage <name> --help` as well
+//! as the command list shown by `manage --help` come from the same clap
+//! metadata. The project's composed settings are available through
+//! [`CommandContext::settings`].
+//!
+//! ```rust,ignore
+//! use reinhardt::commands::{ClapCommand, CommandContext, CommandResult, register_command};
+//!
+//! /// Remove expired shopping carts
+//! #[derive(clap::Parser)]
+//! struct PurgeCartsArgs {
+//!     /// Age in days after which a cart is considered expired
+//!     #[arg(long, default_value_t = 30)]
+//!     days: u32,
+//!     /// Only report what would be removed
+//!     #[arg(long)]
+//!     dry_run: bool,
+//! }
+//!
+//! #[derive(Default)]
+//! struct PurgeCarts;
+//!
+//! #[async_trait::async_trait]
+//! impl ClapCommand for PurgeCarts {
+//!     const NAME: &'static str = "purge_carts";
+//!     type Args = PurgeCartsArgs;
+//!
+//!     async fn run(&self, args: PurgeCartsArgs, ctx: &CommandContext) -> CommandResult<()> {
+//!         ctx.info(&format!("Purging carts older than {} days", args.days));
+//!         Ok(())
+//!     }
+//! }
+//!
+//! register_command!(PurgeCarts);
+//! ```
+//!
+//! Commands implementing [`BaseCommand`] directly are registered with
+//! [`CommandRegistration::new`]:
+//!
+//! ```rust,ignore
+//! use reinhardt::commands::{CommandRegistration, MANAGEMENT_COMMANDS};
+//!
+//! #[linkme::distributed_slice(MANAGEMENT_COMMANDS)]
+//! static REBUILD_INDEX: CommandRegistration =
+//!     CommandRegistration::new("rebuild_index", || Box::new(RebuildIndexCommand));
+//! ```
+
+use crate::{BaseCommand, CommandContext, CommandError, CommandResult};
+use async_trait::async_trait;
+use clap::error::ErrorKind;
+use clap::{CommandFactory, FromArgMatches, Parser};
+use linkme::distributed_slice;
+
+#[doc(hidden)]
+pub use linkme as __linkme;
+
+/// Compile-time registration entry for a management command.
+pub struct CommandRegistration {
+	/// Name the command is invoked with.
+	pub name: &'static str,
+	/// Factory producing the command.
+	pub create: fn() -> Box<dyn BaseCommand>,
+}
+
+impl CommandRegistration {
+	/// Register the [`BaseCommand`] produced by `create` under `name`.
+	pub const fn new(name: &'static str, create: fn() -> Box<dyn BaseCommand>) -> Self {
+		Self { name, create }
+	}
+
+	/// Register a [`ClapCommand`] under its [`NAME`](ClapCommand::NAME).
+	pub const fn clap<C: ClapCommand>() -> Self {
+		Self::new(C::NAME, create_clap_command::<C>)
+	}
+}
+
+fn create_clap_command<C: ClapCommand>() -> Box<dyn BaseCommand> {
+	Box::new(ClapCommandAdapter::new(C::default()))
+}
+
+/// Distributed slice collecting management commands at compile-time.
+#[distributed_slice]
+pub static MANAGEMENT_COMMANDS: [CommandRegistration];
+
+/// All management commands registered in [`MANAGEMENT_COMMANDS`].
+pub fn registered_commands() -> Vec<Box<dyn BaseCommand>> {
+	MANAGEMENT_COMMANDS
+		.iter()
+		.map(|reg| (reg.create)())
+		.collect()
+}
+
+/// Register a [`ClapCommand`] so that `manage` discovers it.
+///
+/// Expands to a [`MANAGEMENT_COMMANDS`] entry; invoke it once per command at
+/// module level.
+#[macro_export]
+macro_rules! register_command {
+	($command:ty) => {
+		const _: () = {
+			#[$crate::discovery::__linkme::distributed_slice(
+				$crate::discovery::MANAGEMENT_COMMANDS
+			)]
+			#[linkme(crate = $crate::discovery::__linkme)]
+			static REGISTRATION: $crate::discovery::CommandRegistration =
+				$crate::discovery::CommandRegistration::clap::<$command>();
+		};
+	};
+}
+
+/// Management command whose arguments are parsed with `clap` derive.
+///
+/// The command's one-line description is the `about` of [`Args`](Self::Args),
+/// i.e. the doc comment on the derive struct.
+#[async_trait]
+pub trait ClapCommand: Default + Send + Sync + 'static {
+	/// Name the command is invoked with (`manage <NAME>`).
+	const NAME: &'static str;
+
+	/// Arguments of the command.
+	type Args: Parser + Send;
+
+	/// Run the command with the parsed arguments.
+	async fn run(&self, args: Self::Args, ctx: &CommandContext) -> CommandResult<()>;
+
+	/// Whether this command requires system checks to run.
+	fn requires_system_checks(&self) -> bool {
+		true
+	}
+}
+
+/// Adapts a [`ClapCommand`] to [`BaseCommand`].
+///
+/// The raw arguments following the command name are parsed into
+/// [`ClapCommand::Args`]; `--help` prints clap's help for the command.
+pub struct ClapCommandAdapter<C: ClapCommand> {
+	command: C,
+	cli: clap::Command,
+	description: String,
+	help: String,
+}
+
+impl<C: ClapCommand> ClapCommandAdapter<C> {
+	/// Wrap `command`.
+	pub fn new(command: C) -> Self {
+		let mut cli = C::Args::command().name(C::NAME).bin_name(C::NAME);
+		let description = cli
+			.get_about()
+			.map(|about| about.to_string())
+			.unwrap_or_default();
+		let help = cli.render_long_help().to_string();
+		Self {
+			command,
+			cli,
+			description,
+			help,
+		}
+	}
+
+	fn parse_args(&self, ctx: &CommandContext) -> Result<Option<C::Args>, clap::Error> {
+		let argv = std::iter::once(C::NAME.to_string()).chain(ctx.args.iter().cloned());
+		match self.cli.clone().try_get_matches_from(argv) {
+			Ok(matches) => C::Args::from_arg_matches(&matches).map(Some),
+			Err(err)
+				if matches!(
+					err.kind(),
+					ErrorKind::DisplayHelp | ErrorKind::DisplayVersion
+				) =>
+			{
+				print!("{}", err);
+				Ok(None)
+			}
+			Err(err) => Err(err),
+		}
+	}
+}
+
+#[async_trait]
+impl<C: ClapCommand> BaseCommand for ClapCommandAdapter<C> {
+	fn name(&self) -> &str {
+		C::NAME
+	}
+
+	fn description(&self) -> &str {
+		if self.description.is_empty() {
+			"No description available"
+		} else {
+			&self.description
+		}
+	}
+
+	fn help(&self) -> &str {
+		&self.help
+	}
+
+	fn requires_system_checks(&self) -> bool {
+		self.command.requires_system_checks()
+	}
+
+	async fn execute(&self, ctx: &CommandContext) -> CommandResult<()> {
+		let args = self
+			.parse_args(ctx)
+			.map_err(|e| CommandError::InvalidArguments(e.to_string()))?;
+		match args {
+			Some(args) => self.command.run(args, ctx).await,
+			None => Ok(()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::CommandRegistry;
+	use rstest::rstest;
+	use std::sync::Mutex;
+
+	static GREETINGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+	/// Print a greeting
+	#[derive(Parser)]
+	struct GreetArgs {
+		/// Who to greet
+		name: String,
+		/// Shout the greeting
+		#[arg(long)]
+		loud: bool,
+	}
+
+	#[derive(Default)]
+	struct GreetCommand;
+
+	#[async_trait]
+	impl ClapCommand for GreetCommand {
+		const NAME: &'static str = "discovery_test_greet";
+		type Args = GreetArgs;
+
+		async fn run(&self, args: GreetArgs, ctx: &CommandContext) -> CommandResult<()> {
+			let mut greeting = format!("hello {}", args.name);
+			if args.loud {
+				greeting = greeting.to_uppercase();
+			}
+			if ctx.settings.is_none() {
+				greeting.push_str(" (no settings)");
+			}
+			GREETINGS.lock().unwrap().push(greeting);
+			Ok(())
+		}
+	}
+
+	register_command!(GreetCommand);
+
+	#[rstest]
+	fn test_registered_command_is_discovered() {
+		// Arrange
+		let mut registry = CommandRegistry::new();
+
+		// Act
+		registry.register_discovered();
+
+		// Assert
+		let command = registry.get("discovery_test_greet").unwrap();
+		assert_eq!(command.description(), "Print a greeting");
+		assert!(command.help().contains("--loud"));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_adapter_parses_clap_args() {
+		// Arrange
+		let command = ClapCommandAdapter::new(GreetCommand);
+		let ctx = CommandContext::new(vec!["--loud".to_string(), "world".to_string()]);
+
+		// Act
+		command.execute(&ctx).await.unwrap();
+
+		// Assert
+		assert!(
+			GREETINGS
+				.lock()
+				.unwrap()
+				.contains(&"HELLO WORLD (no settings)".to_string())
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_adapter_rejects_invalid_args() {
+		// Arrange
+		let command = ClapCommandAdapter::new(GreetCommand);
+		let ctx = CommandContext::new(vec!["--unknown".to_string()]);
+
+		// Act
+		let result = command.execute(&ctx).await;
+
+		// Assert
+		assert!(matches!(result, Err(CommandError::InvalidArguments(_))));
+	}
+}
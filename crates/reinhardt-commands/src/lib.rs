@@ -8,12 +8,13 @@
 //! - **BaseCommand**: Trait for creating custom commands
 //! - **Standard Commands**: migrate, shell, runserver, etc.
 //! - **Argument Parsing**: Clap-based argument handling
-//! - **Command Registry**: Automatic command discovery
+//! - **Command Registry**: Automatic discovery of commands registered by apps and crates
 //! - **Interactive Mode**: Support for interactive prompts
 //! - **Colored Output**: Rich terminal output
 //! - **AST-Based Code Generation**: Robust code generation using Abstract Syntax Trees
 //! - **Auto-Reload**: Built-in hot-reload for the development server (server + wasm)
 //! - **Tera Template Engine**: Powerful template rendering for project/app generation
+//! - **Backups**: `backup`/`restore` of the database and media files (`backup` feature)
 //!
 //! ## Example
 //!
@@ -120,6 +121,11 @@
 //!
 //! See [`runserver_hooks`] for the full hot-reload runbook and failure modes.
 
+/// Registered asset build steps (Tailwind, esbuild, ...) for runserver and collectstatic.
+pub mod asset_pipeline;
+/// Database and media backup commands (backup, restore).
+#[cfg(feature = "backup")]
+pub mod backup;
 /// Base command trait and argument/option definitions.
 pub mod base;
 /// Built-in management commands (migrate, runserver, shell, etc.).
@@ -137,6 +143,8 @@ pub(crate) mod createsuperuser;
 #[cfg(feature = "autoreload")]
 #[doc(hidden)]
 pub mod debounced_watcher;
+/// Management commands registered by apps and third-party crates.
+pub mod discovery;
 /// Embedded Tera templates for project/app scaffolding.
 pub mod embedded_templates;
 /// Code formatting utilities for generated code.
@@ -158,6 +166,9 @@ mod page_hot_patch;
 /// Plugin management commands.
 #[cfg(feature = "plugins")]
 pub mod plugin_commands;
+/// Static HTML export of rendered routes.
+#[cfg(feature = "routers")]
+pub mod prerender;
 /// Project dependency configuration commands.
 pub mod project_config;
 /// Command registry for discovery and dispatch.
@@ -221,12 +232,22 @@ pub mod __hot_reload_test_api {
 
 use thiserror::Error;
 
+pub use asset_pipeline::{
+	AssetBuildError, AssetBuildStep, AssetBuildStepRegistration, AssetWatcher,
+	registered_asset_steps, run_asset_steps, spawn_asset_watchers,
+};
+#[cfg(feature = "backup")]
+pub use backup::{BackupCommand, BackupRunner, BackupSettings, RestoreCommand};
 pub use base::{BaseCommand, CommandArgument, CommandOption};
-#[cfg(feature = "migrations")]
-pub use builtin::MakeMigrationsCommand;
 #[cfg(feature = "routers")]
 pub use builtin::ShowUrlsCommand;
-pub use builtin::{CheckCommand, CheckDiCommand, MigrateCommand, RunServerCommand, ShellCommand};
+pub use builtin::{
+	AnonymizeUserCommand, CheckCommand, CheckDiCommand, EnforceRetentionCommand,
+	ExportUserDataCommand, MigrateCommand, RecountCommand, RunServerCommand, SeedCommand,
+	ShellCommand,
+};
+#[cfg(feature = "migrations")]
+pub use builtin::{GraphModelsCommand, InspectDbCommand, MakeMigrationsCommand};
 #[cfg(feature = "server")]
 pub use cli::start_server;
 pub use cli::{
@@ -236,11 +257,16 @@ pub use cli::{
 };
 pub use collectstatic::{CollectStaticCommand, CollectStaticOptions, CollectStaticStats};
 pub use context::CommandContext;
+pub use discovery::{
+	ClapCommand, ClapCommandAdapter, CommandRegistration, MANAGEMENT_COMMANDS, registered_commands,
+};
 pub use i18n_commands::{CompileMessagesCommand, MakeMessagesCommand};
 #[cfg(feature = "introspect")]
 pub use introspect::IntrospectCommand;
 pub use mail_commands::SendTestEmailCommand;
 pub use output::OutputWrapper;
+#[cfg(feature = "routers")]
+pub use prerender::{PrerenderCommand, PrerenderManifest, PrerenderOptions};
 pub use project_config::{ConfigureCommand, ReinhardtDependencySelection};
 pub use registry::CommandRegistry;
 #[cfg(feature = "server")]
@@ -0,0 +1,348 @@
+//! Prerender command
+//!
+//! Renders selected routes to static HTML through the application handler,
+//! so marketing and documentation pages can be served from `STATIC_ROOT`
+//! (or any CDN in front of it) without a running app server.
+//!
+//! Each route is requested with `GET` exactly as a browser would, which runs
+//! the same middleware and `page!` SSR as a live request. Successful HTML
+//! responses are written as `<route>/index.html`; everything else is skipped
+//! with a warning. A `prerender-manifest.json` next to the pages lists every
+//! written page and the static assets it references, for the deployment step
+//! to check against the `collectstatic` output.
+
+use crate::{BaseCommand, CommandContext, CommandError, CommandResult};
+use async_trait::async_trait;
+use hyper::Method;
+use reinhardt_http::Handler;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File name of the manifest written next to the prerendered pages.
+pub const PRERENDER_MANIFEST: &str = "prerender-manifest.json";
+
+/// Options for the `prerender` management command.
+#[derive(Debug, Clone)]
+pub struct PrerenderOptions {
+	/// Routes to render; empty renders every parameterless `GET` route.
+	pub routes: Vec<String>,
+	/// Directory the pages and manifest are written to.
+	pub output_dir: PathBuf,
+	/// URL prefix of static assets, used to collect asset references.
+	pub static_url: String,
+	/// Whether to render without writing any file.
+	pub dry_run: bool,
+	/// Verbosity level (0 = quiet, 1 = normal, 2+ = verbose).
+	pub verbosity: u8,
+}
+
+impl Default for PrerenderOptions {
+	fn default() -> Self {
+		Self {
+			routes: Vec::new(),
+			output_dir: PathBuf::from("staticfiles"),
+			static_url: "/static/".to_string(),
+			dry_run: false,
+			verbosity: 1,
+		}
+	}
+}
+
+/// Manifest of a `prerender` run, written as [`PRERENDER_MANIFEST`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct PrerenderManifest {
+	/// Route to the written file, relative to the output directory.
+	pub pages: BTreeMap<String, String>,
+	/// Static asset URLs referenced by the pages.
+	pub assets: BTreeSet<String>,
+	/// Routes that did not produce an HTML page, with the reason.
+	pub skipped: BTreeMap<String, String>,
+}
+
+/// Management command rendering routes to static HTML files.
+pub struct PrerenderCommand {
+	options: PrerenderOptions,
+}
+
+impl PrerenderCommand {
+	/// Creates a new command with the given options.
+	pub fn new(options: PrerenderOptions) -> Self {
+		Self { options }
+	}
+
+	/// Render `routes` through `handler` and write the pages and manifest.
+	pub async fn run<H>(&self, handler: &H, routes: &[String]) -> CommandResult<PrerenderManifest>
+	where
+		H: Handler + ?Sized,
+	{
+		let mut manifest = PrerenderManifest::default();
+
+		for route in routes {
+			let request = reinhardt_http::Request::builder()
+				.method(Method::GET)
+				.uri(route.as_str())
+				.header(hyper::header::ACCEPT, "text/html")
+				.build()
+				.map_err(|e| {
+					CommandError::InvalidArguments(format!("Invalid route '{}': {}", route, e))
+				})?;
+			let response = match handler.handle(request).await {
+				Ok(response) => response,
+				Err(e) => reinhardt_http::Response::from(e),
+			};
+
+			let is_html = response
+				.headers
+				.get(hyper::header::CONTENT_TYPE)
+				.and_then(|value| value.to_str().ok())
+				.is_some_and(|value| value.starts_with("text/html"));
+			if !response.status.is_success() || !is_html {
+				let reason = if response.status.is_success() {
+					"response is not HTML".to_string()
+				} else {
+					format!("status {}", response.status)
+				};
+				if self.options.verbosity > 0 {
+					eprintln!("Skipping {}: {}", route, reason);
+				}
+				manifest.skipped.insert(route.clone(), reason);
+				continue;
+			}
+
+			let html = String::from_utf8_lossy(&response.body);
+			manifest
+				.assets
+				.extend(referenced_assets(&html, &self.options.static_url));
+
+			let relative = output_file_for(route);
+			if !self.options.dry_run {
+				let destination = self.options.output_dir.join(&relative);
+				if let Some(parent) = destination.parent() {
+					fs::create_dir_all(parent)?;
+				}
+				fs::write(&destination, response.body.as_ref())?;
+			}
+			if self.options.verbosity > 1 {
+				println!("✓ Rendered {} -> {}", route, relative.display());
+			}
+			manifest
+				.pages
+				.insert(route.clone(), relative.to_string_lossy().replace('\\', "/"));
+		}
+
+		if !self.options.dry_run {
+			fs::create_dir_all(&self.options.output_dir)?;
+			let json = serde_json::to_string_pretty(&manifest)
+				.map_err(|e| CommandError::ExecutionError(e.to_string()))?;
+			fs::write(self.options.output_dir.join(PRERENDER_MANIFEST), json)?;
+		}
+
+		if self.options.verbosity > 0 {
+			println!("\n{} pages prerendered", manifest.pages.len());
+			if !manifest.skipped.is_empty() {
+				println!("{} routes skipped", manifest.skipped.len());
+			}
+		}
+
+		Ok(manifest)
+	}
+}
+
+#[async_trait]
+impl BaseCommand for PrerenderCommand {
+	fn name(&self) -> &str {
+		"prerender"
+	}
+
+	fn description(&self) -> &str {
+		"Render routes to static HTML files"
+	}
+
+	async fn execute(&self, _ctx: &CommandContext) -> CommandResult<()> {
+		let router = reinhardt_urls::routers::get_router().ok_or_else(|| {
+			CommandError::ExecutionError(
+				"No router registered. Call reinhardt_urls::routers::register_router() first."
+					.to_string(),
+			)
+		})?;
+		let routes = if self.options.routes.is_empty() {
+			router
+				.get_all_routes()
+				.into_iter()
+				.filter(|(path, _, _, methods)| is_prerenderable(path, methods))
+				.map(|(path, _, _, _)| path)
+				.collect::<BTreeSet<_>>()
+				.into_iter()
+				.collect()
+		} else {
+			self.options.routes.clone()
+		};
+		self.run(router.as_ref(), &routes).await.map(|_| ())
+	}
+}
+
+/// Whether a route can be rendered without arguments
+///
+/// The path must have no parameters or wildcards, and the route must answer
+/// `GET` (an empty method list accepts every method).
+pub fn is_prerenderable(path: &str, methods: &[Method]) -> bool {
+	!path.contains(['{', '}', '*', '<']) && (methods.is_empty() || methods.contains(&Method::GET))
+}
+
+/// File a route is written to, relative to the output directory
+///
+/// `/` becomes `index.html` and `/docs/intro/` becomes
+/// `docs/intro/index.html`; a last segment with an extension, such as
+/// `/robots.txt`, is kept as the file name.
+pub fn output_file_for(route: &str) -> PathBuf {
+	let path = route.split(['?', '#']).next().unwrap_or_default();
+	let segments: Vec<&str> = path
+		.split('/')
+		.filter(|segment| !segment.is_empty() && *segment != "." && *segment != "..")
+		.collect();
+	let mut file: PathBuf = segments.iter().collect();
+	let has_extension = !path.ends_with('/')
+		&& segments
+			.last()
+			.is_some_and(|segment| Path::new(segment).extension().is_some());
+	if !has_extension {
+		file.push("index.html");
+	}
+	file
+}
+
+/// Static asset URLs referenced from `src` and `href` attributes of `html`
+fn referenced_assets(html: &str, static_url: &str) -> Vec<String> {
+	let mut assets = Vec::new();
+	for attribute in ["src=", "href="] {
+		let mut rest = html;
+		while let Some(index) = rest.find(attribute) {
+			rest = &rest[index + attribute.len()..];
+			let Some(quote) = rest.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+				continue;
+			};
+			let value = &rest[1..];
+			let Some(end) = value.find(quote) else {
+				break;
+			};
+			let url = &value[..end];
+			if url.starts_with(static_url) {
+				assets.push(url.to_string());
+			}
+		}
+	}
+	assets
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reinhardt_http::{Request, Response};
+	use rstest::rstest;
+
+	struct SiteHandler;
+
+	#[async_trait]
+	impl Handler for SiteHandler {
+		async fn handle(&self, request: Request) -> reinhardt_http::Result<Response> {
+			match request.uri.path() {
+				"/" => Ok(Response::ok()
+					.with_header("Content-Type", "text/html; charset=utf-8")
+					.with_body(
+						r#"<html><head><link href="/static/app.css"></head><body>Home</body></html>"#,
+					)),
+				"/docs/intro/" => Ok(Response::ok()
+					.with_header("Content-Type", "text/html; charset=utf-8")
+					.with_body(r#"<script src='/static/app.js'></script><a href="/">Home</a>"#)),
+				"/api/health" => Ok(Response::ok()
+					.with_header("Content-Type", "application/json")
+					.with_body("{}")),
+				path => Err(reinhardt_http::Error::NotFound(path.to_string())),
+			}
+		}
+	}
+
+	#[rstest]
+	#[case("/", "index.html")]
+	#[case("/docs/intro/", "docs/intro/index.html")]
+	#[case("/about", "about/index.html")]
+	#[case("/robots.txt", "robots.txt")]
+	#[case("/../etc/passwd/", "etc/passwd/index.html")]
+	fn test_output_file_for(#[case] route: &str, #[case] expected: &str) {
+		// Act
+		let file = output_file_for(route);
+
+		// Assert
+		assert_eq!(file, PathBuf::from(expected));
+	}
+
+	#[rstest]
+	#[case("/about/", vec![Method::GET], true)]
+	#[case("/about/", vec![], true)]
+	#[case("/contact/", vec![Method::POST], false)]
+	#[case("/users/{id}/", vec![Method::GET], false)]
+	#[case("/files/{*path}", vec![Method::GET], false)]
+	fn test_is_prerenderable(
+		#[case] path: &str,
+		#[case] methods: Vec<Method>,
+		#[case] expected: bool,
+	) {
+		// Act / Assert
+		assert_eq!(is_prerenderable(path, &methods), expected);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_run_writes_html_pages_and_manifest() {
+		// Arrange
+		let output = tempfile::tempdir().unwrap();
+		let command = PrerenderCommand::new(PrerenderOptions {
+			output_dir: output.path().to_path_buf(),
+			verbosity: 0,
+			..PrerenderOptions::default()
+		});
+		let routes = ["/", "/docs/intro/", "/api/health", "/missing/"].map(String::from);
+
+		// Act
+		let manifest = command.run(&SiteHandler, &routes).await.unwrap();
+
+		// Assert
+		let home = fs::read_to_string(output.path().join("index.html")).unwrap();
+		assert!(home.contains("Home"));
+		assert!(output.path().join("docs/intro/index.html").exists());
+		assert_eq!(manifest.pages.len(), 2);
+		assert_eq!(
+			manifest.assets,
+			BTreeSet::from(["/static/app.css".to_string(), "/static/app.js".to_string()])
+		);
+		assert_eq!(manifest.skipped["/api/health"], "response is not HTML");
+		assert!(manifest.skipped["/missing/"].contains("404"));
+		let written: serde_json::Value = serde_json::from_str(
+			&fs::read_to_string(output.path().join(PRERENDER_MANIFEST)).unwrap(),
+		)
+		.unwrap();
+		assert_eq!(written["pages"]["/docs/intro/"], "docs/intro/index.html");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_dry_run_writes_nothing() {
+		// Arrange
+		let output = tempfile::tempdir().unwrap();
+		let command = PrerenderCommand::new(PrerenderOptions {
+			output_dir: output.path().join("site"),
+			dry_run: true,
+			verbosity: 0,
+			..PrerenderOptions::default()
+		});
+
+		// Act
+		let manifest = command.run(&SiteHandler, &["/".to_string()]).await.unwrap();
+
+		// Assert
+		assert_eq!(manifest.pages["/"], "index.html");
+		assert!(!output.path().join("site").exists());
+	}
+}
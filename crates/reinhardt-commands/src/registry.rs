@@ -1,6 +1,7 @@
 //! Command registry
 
 use crate::BaseCommand;
+use crate::discovery::registered_commands;
 use std::collections::HashMap;
 
 /// Registry that stores and provides access to management commands by name.
@@ -31,6 +32,36 @@ impl CommandRegistry {
 	pub fn list(&self) -> Vec<&str> {
 		self.commands.keys().map(|s| s.as_str()).collect()
 	}
+
+	/// Registers every command collected in
+	/// [`MANAGEMENT_COMMANDS`](crate::discovery::MANAGEMENT_COMMANDS).
+	///
+	/// Commands already registered under the same name are kept, so an
+	/// explicit registration overrides a discovered one.
+	pub fn register_discovered(&mut self) {
+		for command in registered_commands() {
+			self.commands
+				.entry(command.name().to_string())
+				.or_insert(command);
+		}
+	}
+
+	/// Returns a help section listing the registered commands with their
+	/// descriptions, sorted by name, or `None` if the registry is empty.
+	pub fn help_summary(&self) -> Option<String> {
+		if self.commands.is_empty() {
+			return None;
+		}
+		let mut names: Vec<&str> = self.list();
+		names.sort_unstable();
+		let width = names.iter().map(|name| name.len()).max().unwrap_or(0);
+		let mut summary = String::from("Custom Commands:");
+		for name in names {
+			let description = self.commands[name].description();
+			summary.push_str(&format!("\n  {:<width$}  {}", name, description));
+		}
+		Some(summary)
+	}
 }
 
 impl Default for CommandRegistry {
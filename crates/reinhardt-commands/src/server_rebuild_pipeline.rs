@@ -27,9 +27,8 @@ pub enum ServerRebuildOutcome {
 		/// Wall-clock time for the failed build.
 		duration: Duration,
 		/// Last lines of stderr from the failed build, joined by `\n`.
-		// Field is read by the watcher when it forwards diagnostics to a UI
-		// channel in a later task; suppress the dead-code warning until then.
-		#[allow(dead_code)]
+		// Only read when forwarding diagnostics to the Pages browser overlay.
+		#[cfg_attr(not(feature = "pages"), allow(dead_code))]
 		stderr_tail: String,
 	},
 	/// Building or respawning the child process failed at the OS level.
@@ -13,6 +13,9 @@
 //! * `manifest_path` itself as a single-file watch target (catches edits to
 //!   `Cargo.toml` that should trigger a rebuild).
 //!
+//! The anchor package's `static/` directory is collected as well, for
+//! stylesheet hot-swapping.
+//!
 //! Output vectors are sorted and de-duplicated so callers (and tests) get a
 //! deterministic order.
 
@@ -31,6 +34,9 @@ pub struct SourceRoots {
 	/// path-dep source changes. `None` only when `from_metadata` cannot
 	/// anchor on the supplied manifest. See issue #4214.
 	pub lockfile: Option<PathBuf>,
+	/// The anchor package's `static/` directory, watched recursively so
+	/// stylesheet edits can be hot-swapped in the browser without a rebuild.
+	pub static_dirs: Vec<PathBuf>,
 }
 
 impl SourceRoots {
@@ -55,9 +61,15 @@ impl SourceRoots {
 				src_dirs: Vec::new(),
 				manifest_files: Vec::new(),
 				lockfile: None,
+				static_dirs: Vec::new(),
 			};
 		};
 
+		let static_dirs = PathBuf::from(anchor.manifest_path.as_str())
+			.parent()
+			.map(|dir| vec![dir.join("static")])
+			.unwrap_or_default();
+
 		let mut visited: BTreeSet<String> = BTreeSet::new();
 		let mut queue: VecDeque<&cargo_metadata::Package> = VecDeque::new();
 		let mut src_dirs: BTreeSet<PathBuf> = BTreeSet::new();
@@ -93,6 +105,7 @@ impl SourceRoots {
 			src_dirs: src_dirs.into_iter().collect(),
 			manifest_files: manifest_files.into_iter().collect(),
 			lockfile: Some(PathBuf::from(metadata.workspace_root.as_str()).join("Cargo.lock")),
+			static_dirs,
 		}
 	}
 }
@@ -135,6 +148,10 @@ mod tests {
 			roots.lockfile,
 			Some(PathBuf::from("/fixtures/single_crate/Cargo.lock"))
 		);
+		assert_eq!(
+			roots.static_dirs,
+			vec![PathBuf::from("/fixtures/single_crate/static")]
+		);
 	}
 
 	#[rstest]
@@ -207,5 +224,6 @@ mod tests {
 		assert!(roots.src_dirs.is_empty());
 		assert!(roots.manifest_files.is_empty());
 		assert_eq!(roots.lockfile, None);
+		assert!(roots.static_dirs.is_empty());
 	}
 }
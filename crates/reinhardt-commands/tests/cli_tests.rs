@@ -1507,3 +1507,135 @@ fn test_makemigrations_merge_with_name() {
 		_ => panic!("Expected Makemigrations command"),
 	}
 }
+
+/// Test: Parse Inspectdb command options
+///
+/// Category: Happy Path
+/// Verifies that inspectdb options are parsed and `managed` defaults to false.
+#[cfg(feature = "migrations")]
+#[rstest]
+fn test_inspectdb_options() {
+	// Arrange
+	let cli = Cli::try_parse_from([
+		"manage",
+		"inspectdb",
+		"--database",
+		"sqlite://legacy.db",
+		"-o",
+		"src/legacy",
+		"--exclude",
+		"^audit_",
+		"--dry-run",
+	])
+	.unwrap();
+
+	// Act & Assert
+	match cli.command {
+		Commands::Inspectdb {
+			database,
+			output,
+			exclude,
+			dry_run,
+			managed,
+			..
+		} => {
+			assert_eq!(database, Some("sqlite://legacy.db".to_string()));
+			assert_eq!(output, Some(PathBuf::from("src/legacy")));
+			assert_eq!(exclude, Some("^audit_".to_string()));
+			assert!(dry_run, "--dry-run flag should be true");
+			assert!(!managed, "generated models should be unmanaged by default");
+		}
+		_ => panic!("Expected Inspectdb command"),
+	}
+}
+
+/// Test: Parse graph_models command
+///
+/// Category: Happy Path
+/// Verifies that app labels, format and output are parsed for graph_models.
+#[cfg(feature = "migrations")]
+#[rstest]
+fn test_graph_models_options() {
+	// Arrange
+	let cli = Cli::try_parse_from([
+		"manage",
+		"graph_models",
+		"blog",
+		"auth",
+		"--format",
+		"mermaid",
+		"-o",
+		"docs/models.mmd",
+	])
+	.unwrap();
+
+	// Act & Assert
+	match cli.command {
+		Commands::GraphModels {
+			app_labels,
+			format,
+			output,
+		} => {
+			assert_eq!(app_labels, vec!["blog".to_string(), "auth".to_string()]);
+			assert_eq!(format, "mermaid");
+			assert_eq!(output, Some(PathBuf::from("docs/models.mmd")));
+		}
+		_ => panic!("Expected GraphModels command"),
+	}
+}
+
+/// Test: inspectdb against a SQLite database
+///
+/// Category: Integration
+/// Verifies that `--dry-run` introspects the schema without writing files,
+/// while a regular run writes one module per table.
+#[cfg(all(feature = "migrations", feature = "sqlite"))]
+#[rstest]
+#[case::dry_run(true, &[])]
+#[case::write(false, &["mod.rs", "posts.rs", "users.rs"])]
+#[tokio::test]
+async fn test_inspectdb_sqlite_fixture(#[case] dry_run: bool, #[case] expected_files: &[&str]) {
+	// Arrange
+	let dir = tempfile::tempdir().unwrap();
+	let db_path = dir.path().join("legacy.db");
+	let pool = sqlx::sqlite::SqlitePoolOptions::new()
+		.connect(&format!("sqlite://{}?mode=rwc", db_path.display()))
+		.await
+		.unwrap();
+	for statement in [
+		"CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)",
+		"CREATE TABLE posts (id INTEGER PRIMARY KEY, \
+		 author_id INTEGER REFERENCES users(id) ON DELETE CASCADE, title TEXT NOT NULL)",
+	] {
+		sqlx::query(statement).execute(&pool).await.unwrap();
+	}
+	pool.close().await;
+	let output = dir.path().join("models");
+	let mut args = vec![
+		"manage".to_string(),
+		"inspectdb".to_string(),
+		"--database".to_string(),
+		format!("sqlite://{}", db_path.display()),
+		"--output".to_string(),
+		output.display().to_string(),
+	];
+	if dry_run {
+		args.push("--dry-run".to_string());
+	}
+	let cli = Cli::try_parse_from(args).unwrap();
+
+	// Act
+	let result = reinhardt_commands::run_command(cli.command, 0).await;
+
+	// Assert
+	assert!(result.is_ok(), "inspectdb failed: {:?}", result.err());
+	let mut files: Vec<String> = std::fs::read_dir(&output)
+		.map(|entries| {
+			entries
+				.map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+				.collect()
+		})
+		.unwrap_or_default();
+	files.sort();
+	assert_eq!(files, expected_files);
+}
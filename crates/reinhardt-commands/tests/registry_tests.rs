@@ -4,7 +4,9 @@
 //! retrieval, and listing of commands.
 
 use async_trait::async_trait;
-use reinhardt_commands::{BaseCommand, CommandContext, CommandRegistry, CommandResult};
+use reinhardt_commands::{
+	BaseCommand, ClapCommand, CommandContext, CommandRegistry, CommandResult, register_command,
+};
 use rstest::{fixture, rstest};
 
 // =============================================================================
@@ -409,6 +411,85 @@ fn test_registry_get_does_not_modify_state(populated_registry: CommandRegistry)
 	);
 }
 
+// =============================================================================
+// Discovery Tests
+// =============================================================================
+
+/// Ping the discovery test command
+#[derive(clap::Parser)]
+struct DiscoveredArgs {
+	/// Number of pings
+	#[arg(long, default_value_t = 1)]
+	count: u32,
+}
+
+#[derive(Default)]
+struct DiscoveredCommand;
+
+#[async_trait]
+impl ClapCommand for DiscoveredCommand {
+	const NAME: &'static str = "registry_test_discovered";
+	type Args = DiscoveredArgs;
+
+	async fn run(&self, _args: DiscoveredArgs, _ctx: &CommandContext) -> CommandResult<()> {
+		Ok(())
+	}
+}
+
+register_command!(DiscoveredCommand);
+
+/// Test that commands registered with `register_command!` are discovered
+#[rstest]
+fn test_registry_register_discovered(mut empty_registry: CommandRegistry) {
+	// Act
+	empty_registry.register_discovered();
+
+	// Assert
+	let cmd = empty_registry.get("registry_test_discovered").unwrap();
+	assert_eq!(cmd.description(), "Ping the discovery test command");
+}
+
+/// Test that an explicit registration overrides a discovered command
+#[rstest]
+fn test_registry_explicit_registration_overrides_discovered(mut empty_registry: CommandRegistry) {
+	// Arrange
+	empty_registry.register(Box::new(MockCommand::with_description(
+		"registry_test_discovered",
+		"Project override",
+	)));
+
+	// Act
+	empty_registry.register_discovered();
+
+	// Assert
+	let cmd = empty_registry.get("registry_test_discovered").unwrap();
+	assert_eq!(cmd.description(), "Project override");
+}
+
+/// Test the aggregated help section
+#[rstest]
+fn test_registry_help_summary(mut empty_registry: CommandRegistry) {
+	// Arrange
+	empty_registry.register(Box::new(MockCommand::with_description("zeta", "Last")));
+	empty_registry.register(Box::new(MockCommand::with_description("alpha", "First")));
+
+	// Act
+	let summary = empty_registry.help_summary();
+
+	// Assert
+	assert_eq!(
+		summary.as_deref(),
+		Some("Custom Commands:\n  alpha  First\n  zeta   Last")
+	);
+}
+
+/// Test that an empty registry has no help section
+#[rstest]
+fn test_registry_help_summary_empty(empty_registry: CommandRegistry) {
+	// Act / Assert
+	assert!(empty_registry.help_summary().is_none());
+}
+
 // =============================================================================
 // Sanity Tests
 // =============================================================================
@@ -407,6 +407,7 @@ async fn hr_3_no_wasm_rebuild_flag_skips_wasm_pipeline() {
 			src_dirs: Vec::new(),
 			manifest_files: Vec::new(),
 			lockfile: None,
+			static_dirs: Vec::new(),
 		},
 		debounce_window: DEBOUNCE_WINDOW,
 		server_address: None,
@@ -627,6 +628,7 @@ async fn hr_7_run_watcher_processes_events() {
 			src_dirs: vec![fixture.path().join("src")],
 			manifest_files: vec![fixture.path().join("Cargo.toml")],
 			lockfile: None,
+			static_dirs: Vec::new(),
 		},
 		debounce_window: DEBOUNCE_WINDOW,
 		server_address: None,
@@ -752,6 +754,7 @@ async fn hr_9_run_watcher_broadcasts_reload_after_server_rebuild() {
 			src_dirs: vec![fixture.path().join("src")],
 			manifest_files: vec![fixture.path().join("Cargo.toml")],
 			lockfile: None,
+			static_dirs: Vec::new(),
 		},
 		debounce_window: DEBOUNCE_WINDOW,
 		server_address: Some(addr),
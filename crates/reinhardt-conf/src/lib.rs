@@ -46,6 +46,8 @@
 //!   - `encryption`: AES-GCM encryption for sensitive settings values
 //!   - `audit`: Change tracking and audit log for setting modifications
 //!   - `hot_reload`: File system watcher for live settings reload
+//!   - `snapshot`: `SettingsSnapshot`, settings shared across requests and swapped
+//!     atomically on reload (file change or `SIGHUP`)
 //!
 //! ## Feature Flags
 //!
@@ -92,10 +94,13 @@ pub use settings::{
 	cache::CacheSettings, cache::HasCacheSettings, contacts::ContactSettings,
 	contacts::HasContactSettings, core_settings::CoreSettings, core_settings::HasCoreSettings,
 	cors::CorsSettings, cors::HasCorsSettings, email::EmailSettings, email::HasEmailSettings,
-	fragment::HasCommonSettings, fragment::HasSettings, fragment::SettingsFragment,
-	i18n::HasI18nSettings, i18n::I18nSettings, logging::HasLoggingSettings,
-	logging::LoggingSettings, media::HasMediaSettings, media::MediaSettings,
-	security::HasSecuritySettings, security::SecuritySettings, session::HasSessionSettings,
-	session::SessionSettings, static_files::HasStaticSettings, static_files::StaticSettings,
+	forms::FormsSettings, forms::HasFormsSettings, fragment::HasCommonSettings,
+	fragment::HasSettings, fragment::SettingsFragment, i18n::HasI18nSettings, i18n::I18nSettings,
+	json::HasJsonSettings, json::JsonSettings,
+	logging::HasLoggingSettings, logging::LoggingSettings, media::HasMediaSettings,
+	media::MediaSettings, security::HasSecuritySettings, security::SecuritySettings,
+	session::HasSessionSettings, session::SessionSettings, sites::HasSitesSettings,
+	sites::SitesSettings, static_files::HasStaticSettings, static_files::StaticSettings,
 	template_settings::HasTemplateSettings, template_settings::TemplateSettings,
+	well_known::HasWellKnownSettings, well_known::WellKnownSettings,
 };
@@ -7,6 +7,8 @@
 // `TemplateConfig` during the 0.2 compatibility window.
 #![allow(deprecated)]
 
+/// Typed settings for individual apps.
+pub mod app_settings;
 pub mod builder;
 pub mod cache;
 /// Trait for composed settings structs generated by the `#[settings(...)]` macro.
@@ -18,9 +20,11 @@ pub mod email;
 pub mod env;
 pub mod env_loader;
 pub mod env_parser;
+pub mod forms;
 pub mod fragment;
 pub mod i18n;
 pub mod interpolation;
+pub mod json;
 pub mod logging;
 pub mod media;
 pub(crate) mod merge;
@@ -35,11 +39,14 @@ pub mod schema;
 pub mod secret_types;
 pub mod security;
 pub mod session;
+pub mod sites;
+pub mod snapshot;
 pub mod sources;
 pub mod static_files;
 pub mod template_settings;
 pub mod typed_deserializer;
 pub mod validation;
+pub mod well_known;
 
 // Dynamic settings (async feature required)
 #[cfg(feature = "async")]
@@ -121,6 +128,9 @@ pub use database_config::DatabaseConfig;
 // Re-export policy types
 pub use policy::{FieldPolicy, FieldRequirement};
 
+// Re-export the shared settings snapshot
+pub use snapshot::SettingsSnapshot;
+
 // Re-export ComposedSettings trait
 pub use composed::ComposedSettings;
 
@@ -0,0 +1,205 @@
+//! Typed settings for individual apps
+//!
+//! An app describes its configuration as a struct deriving [`AppSettings`]
+//! (the derive macro from `reinhardt_macros`). Each struct owns one settings
+//! namespace: a TOML section and an environment variable prefix.
+//!
+//! ```rust,ignore
+//! use reinhardt_conf::settings::app_settings::AppSettings;
+//! use reinhardt_conf::settings::secret_types::SecretString;
+//!
+//! #[derive(Clone, AppSettings)]
+//! #[app_settings(section = "payments", env_prefix = "PAYMENTS_")]
+//! pub struct PaymentsSettings {
+//!     #[app_setting(secret)]
+//!     pub api_key: String,
+//!     #[app_setting(default = "EUR".to_string())]
+//!     pub currency: String,
+//!     #[app_setting(default = 3, validate = retries_in_range)]
+//!     pub max_retries: u32,
+//!     pub webhook_url: Option<String>,
+//! }
+//!
+//! let payments = settings.app::<PaymentsSettings>()?;
+//! ```
+//!
+//! Values come from the `[payments]` section of the merged settings,
+//! overridden by environment variables named after the prefix and the field
+//! (`PAYMENTS_API_KEY`, `PAYMENTS_MAX_RETRIES`, ...). Environment values are
+//! parsed as JSON when the field type needs it, so `PAYMENTS_MAX_RETRIES=5`
+//! fills a `u32`.
+//!
+//! [`MergedSettings::app`](super::builder::MergedSettings::app) loads,
+//! validates and caches the typed instance, so later calls for the same type
+//! share one `Arc`.
+
+use indexmap::IndexMap;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::fmt::Debug;
+
+/// Placeholder printed instead of secret values in `Debug` output
+pub const REDACTED: &str = "***";
+
+/// Error loading an app's settings
+#[non_exhaustive]
+#[derive(Debug, thiserror::Error)]
+pub enum AppSettingsError {
+	/// A field without default was not provided by any source.
+	#[error(
+		"missing required field `{field}` in section `[{section}]`. \
+		 Provide it via TOML or environment variable"
+	)]
+	MissingField {
+		/// The settings section name.
+		section: &'static str,
+		/// The field name that is missing.
+		field: &'static str,
+	},
+
+	/// A value could not be converted to the field type.
+	#[error("invalid value for `{section}.{field}`: {error}")]
+	Deserialize {
+		/// The settings section name.
+		section: &'static str,
+		/// The field name holding the value.
+		field: &'static str,
+		/// The underlying deserialization error.
+		error: serde_json::Error,
+	},
+
+	/// A validator rejected the loaded settings.
+	#[error("invalid settings in section `[{section}]`: {message}")]
+	Validation {
+		/// The settings section name.
+		section: &'static str,
+		/// The validator's message.
+		message: String,
+	},
+}
+
+/// Typed settings of one app
+///
+/// Implemented by `#[derive(AppSettings)]`; see the [module
+/// documentation](self) for the supported attributes.
+pub trait AppSettings: Clone + Debug + Send + Sync + 'static {
+	/// TOML section holding the app's settings (e.g. `"payments"`).
+	fn section() -> &'static str;
+
+	/// Prefix of the environment variables overriding the section
+	/// (e.g. `"PAYMENTS_"`).
+	fn env_prefix() -> &'static str;
+
+	/// Names of all fields, as used in TOML.
+	fn field_names() -> &'static [&'static str];
+
+	/// Names of the fields redacted from `Debug` output.
+	fn secret_fields() -> &'static [&'static str] {
+		&[]
+	}
+
+	/// Build the settings from the section's values, applying defaults.
+	fn from_values(values: &Map<String, Value>) -> Result<Self, AppSettingsError>;
+
+	/// Check the loaded settings.
+	///
+	/// Default implementation: no-op (always valid).
+	fn validate(&self) -> Result<(), AppSettingsError> {
+		Ok(())
+	}
+
+	/// Load and validate the settings from merged settings data and the
+	/// process environment.
+	fn load(merged: &IndexMap<String, Value>) -> Result<Self, AppSettingsError> {
+		let mut values = match merged.get(Self::section()) {
+			Some(Value::Object(section)) => section.clone(),
+			_ => Map::new(),
+		};
+		for field in Self::field_names() {
+			let var = format!("{}{}", Self::env_prefix(), field.to_uppercase());
+			if let Ok(raw) = std::env::var(&var) {
+				values.insert((*field).to_string(), Value::String(raw));
+			}
+		}
+
+		let settings = Self::from_values(&values)?;
+		settings.validate()?;
+		Ok(settings)
+	}
+}
+
+/// Read `field` from `values`, `None` when it is absent or null
+///
+/// String values that do not fit `T` directly are parsed as JSON, so values
+/// coming from environment variables fill numbers, booleans and lists. Used
+/// by the code generated for `#[derive(AppSettings)]`.
+pub fn field_value<T: DeserializeOwned>(
+	values: &Map<String, Value>,
+	section: &'static str,
+	field: &'static str,
+) -> Result<Option<T>, AppSettingsError> {
+	let value = match values.get(field) {
+		None | Some(Value::Null) => return Ok(None),
+		Some(value) => value,
+	};
+	serde_json::from_value(value.clone())
+		.or_else(|error| match value {
+			Value::String(raw) => serde_json::from_str(raw).map_err(|_| error),
+			_ => Err(error),
+		})
+		.map(Some)
+		.map_err(|error| AppSettingsError::Deserialize {
+			section,
+			field,
+			error,
+		})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use serde_json::json;
+
+	fn values(value: Value) -> Map<String, Value> {
+		value.as_object().unwrap().clone()
+	}
+
+	#[rstest]
+	#[case(json!({"retries": 5}), Some(5))]
+	#[case(json!({"retries": "5"}), Some(5))]
+	#[case(json!({"retries": null}), None)]
+	#[case(json!({}), None)]
+	fn test_field_value_parses_strings(#[case] input: Value, #[case] expected: Option<u32>) {
+		// Act
+		let value = field_value::<u32>(&values(input), "payments", "retries").unwrap();
+
+		// Assert
+		assert_eq!(value, expected);
+	}
+
+	#[rstest]
+	fn test_field_value_keeps_numeric_strings() {
+		// Act
+		let value = field_value::<String>(&values(json!({"code": "0042"})), "payments", "code");
+
+		// Assert
+		assert_eq!(value.unwrap().as_deref(), Some("0042"));
+	}
+
+	#[rstest]
+	fn test_field_value_reports_invalid_values() {
+		// Act
+		let result = field_value::<u32>(&values(json!({"retries": "many"})), "payments", "retries");
+
+		// Assert
+		assert!(matches!(
+			result,
+			Err(AppSettingsError::Deserialize {
+				section: "payments",
+				field: "retries",
+				..
+			})
+		));
+	}
+}
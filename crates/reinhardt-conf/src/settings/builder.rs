@@ -3,12 +3,16 @@
 //! Provides a builder pattern for constructing settings from multiple sources
 //! with priority-based merging.
 
+use super::app_settings::{AppSettings, AppSettingsError};
 use super::composed::ComposedSettings;
 use super::profile::Profile;
 use super::sources::{ConfigSource, DotEnvSource, EnvSource, SourceError};
 use indexmap::IndexMap;
+use parking_lot::RwLock;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// Strategy for merging multiple configuration sources.
@@ -356,6 +360,7 @@ impl SettingsBuilder {
 			data: Arc::new(merged),
 			profile: self.profile,
 			typed_coercion: self.typed_coercion,
+			apps: Arc::default(),
 		})
 	}
 }
@@ -420,12 +425,16 @@ impl Default for SettingsBuilder {
 	}
 }
 
+/// Typed app settings loaded so far, keyed by their type
+type AppSettingsCache = RwLock<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>;
+
 /// Merged settings result
 #[derive(Clone)]
 pub struct MergedSettings {
 	data: Arc<IndexMap<String, Value>>,
 	profile: Option<Profile>,
 	typed_coercion: bool,
+	apps: Arc<AppSettingsCache>,
 }
 
 impl MergedSettings {
@@ -663,6 +672,46 @@ impl MergedSettings {
 	pub fn as_map(&self) -> &IndexMap<String, Value> {
 		&self.data
 	}
+
+	/// Get the typed settings of an app
+	///
+	/// The first call loads `T` from its section and environment prefix and
+	/// validates it; later calls (also on clones of these settings) return
+	/// the cached instance.
+	///
+	/// # Examples
+	///
+	/// ```rust,ignore
+	/// let payments = settings.app::<PaymentsSettings>()?;
+	/// println!("charging in {}", payments.currency);
+	/// ```
+	pub fn app<T: AppSettings>(&self) -> Result<Arc<T>, AppSettingsError> {
+		if let Some(cached) = self.apps.read().get(&TypeId::of::<T>()) {
+			return Ok(Arc::clone(cached)
+				.downcast::<T>()
+				.expect("app settings cache is keyed by type"));
+		}
+
+		let settings: Arc<dyn Any + Send + Sync> = Arc::new(T::load(&self.data)?);
+		let entry = self
+			.apps
+			.write()
+			.entry(TypeId::of::<T>())
+			.or_insert(settings)
+			.clone();
+		Ok(entry
+			.downcast::<T>()
+			.expect("app settings cache is keyed by type"))
+	}
+
+	/// Load and validate the typed settings of an app up front
+	///
+	/// Call it at startup so invalid app settings fail there instead of on
+	/// the first [`app`](Self::app) call.
+	pub fn register_app<T: AppSettings>(self) -> Result<Self, AppSettingsError> {
+		self.app::<T>()?;
+		Ok(self)
+	}
 }
 
 /// Error type for building settings
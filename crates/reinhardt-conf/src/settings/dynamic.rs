@@ -7,8 +7,13 @@
 //! - **Multiple Backends**: Memory, Redis, Database
 //! - **Caching**: Optional in-memory caching with TTL
 //! - **Observer Pattern**: Subscribe to configuration changes
+//! - **Declared Settings**: Typed keys with defaults declared in code
 //! - **Thread-Safe**: Arc + RwLock for concurrent access
 //!
+//! Application code usually reads values through the process-wide instance
+//! returned by [`dynamic_settings`], installed at startup with
+//! [`set_dynamic_settings`].
+//!
 //! ## Example
 //!
 //! ```rust
@@ -40,8 +45,8 @@
 use async_trait::async_trait;
 use parking_lot::RwLock;
 use serde::{Serialize, de::DeserializeOwned};
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, OnceLock};
 
 #[cfg(feature = "caching")]
 use moka::future::Cache;
@@ -182,6 +187,31 @@ impl SubscriptionId {
 	}
 }
 
+/// A dynamic setting declared in code
+///
+/// Declared settings have a default used until a value is stored, and
+/// [`DynamicSettings::set`] rejects values that do not match the declared
+/// type.
+#[derive(Debug, Clone)]
+pub struct SettingDefinition {
+	/// Setting key
+	pub key: String,
+	/// Value returned while no value is stored
+	pub default: serde_json::Value,
+	/// Description for the people editing the setting
+	pub help_text: String,
+	/// Name of the declared Rust type
+	pub type_name: &'static str,
+	accepts: fn(&serde_json::Value) -> bool,
+}
+
+impl SettingDefinition {
+	/// Whether `value` can be read as the declared type
+	pub fn accepts(&self, value: &serde_json::Value) -> bool {
+		(self.accepts)(value)
+	}
+}
+
 /// Observer callback type
 type ObserverCallback = Box<dyn Fn(&str, Option<&serde_json::Value>) + Send + Sync>;
 
@@ -260,8 +290,13 @@ pub struct DynamicSettings {
 	#[cfg(feature = "caching")]
 	cache: Option<Cache<String, CachedValue>>,
 
+	#[cfg(feature = "caching")]
+	cache_ttl: Option<Duration>,
+
 	observers: Arc<RwLock<HashMap<SubscriptionId, ObserverCallback>>>,
 
+	definitions: Arc<RwLock<BTreeMap<String, SettingDefinition>>>,
+
 	#[cfg(feature = "hot-reload")]
 	hot_reload: Option<Arc<tokio::sync::Mutex<super::hot_reload::HotReloadManager>>>,
 }
@@ -284,7 +319,10 @@ impl DynamicSettings {
 			backend,
 			#[cfg(feature = "caching")]
 			cache: None,
+			#[cfg(feature = "caching")]
+			cache_ttl: None,
 			observers: Arc::new(RwLock::new(HashMap::new())),
+			definitions: Arc::new(RwLock::new(BTreeMap::new())),
 			#[cfg(feature = "hot-reload")]
 			hot_reload: None,
 		}
@@ -295,7 +333,8 @@ impl DynamicSettings {
 	/// ## Arguments
 	///
 	/// * `capacity` - Maximum number of cached items
-	/// * `default_ttl` - Default time-to-live for cached items
+	/// * `default_ttl` - Time-to-live of values read from the backend, after
+	///   which changes made by other processes (e.g. in the admin) are seen
 	///
 	/// ## Example
 	///
@@ -313,13 +352,79 @@ impl DynamicSettings {
 	/// settings.enable_cache(100, Some(Duration::from_secs(300)));
 	/// ```
 	#[cfg(feature = "caching")]
-	pub fn enable_cache(&mut self, capacity: u64, _default_ttl: Option<Duration>) {
+	pub fn enable_cache(&mut self, capacity: u64, default_ttl: Option<Duration>) {
 		self.cache = Some(Cache::builder().max_capacity(capacity).build());
+		self.cache_ttl = default_ttl;
+	}
+
+	/// Whether cached values are kept until evicted
+	///
+	/// Such a cache never sees values changed by other processes, e.g. in
+	/// the admin. Always `false` without the `caching` feature.
+	pub fn cache_never_expires(&self) -> bool {
+		#[cfg(feature = "caching")]
+		{
+			self.cache.is_some() && self.cache_ttl.is_none()
+		}
+		#[cfg(not(feature = "caching"))]
+		{
+			false
+		}
+	}
+
+	/// Declare a setting with its default value and help text
+	///
+	/// Declaring a key again replaces its definition.
+	///
+	/// ## Example
+	///
+	/// ```rust
+	/// # futures::executor::block_on(async {
+	/// use reinhardt_conf::settings::dynamic::DynamicSettings;
+	/// use reinhardt_conf::settings::backends::MemoryBackend;
+	/// use std::sync::Arc;
+	///
+	/// let settings = DynamicSettings::new(Arc::new(MemoryBackend::new()));
+	/// settings
+	///     .declare("shop.max_cart_items", &50u32, "Maximum items per cart")
+	///     .unwrap();
+	///
+	/// let max: u32 = settings.get("shop.max_cart_items").await.unwrap().unwrap();
+	/// assert_eq!(max, 50);
+	/// assert!(settings.set("shop.max_cart_items", &"many", None).await.is_err());
+	/// # });
+	/// ```
+	pub fn declare<T: Serialize + DeserializeOwned>(
+		&self,
+		key: &str,
+		default: &T,
+		help_text: &str,
+	) -> DynamicResult<()> {
+		let definition = SettingDefinition {
+			key: key.to_string(),
+			default: serde_json::to_value(default)?,
+			help_text: help_text.to_string(),
+			type_name: std::any::type_name::<T>(),
+			accepts: |value| serde_json::from_value::<T>(value.clone()).is_ok(),
+		};
+		self.definitions.write().insert(key.to_string(), definition);
+		Ok(())
+	}
+
+	/// Definition of a declared setting
+	pub fn definition(&self, key: &str) -> Option<SettingDefinition> {
+		self.definitions.read().get(key).cloned()
+	}
+
+	/// Definitions of all declared settings, ordered by key
+	pub fn definitions(&self) -> Vec<SettingDefinition> {
+		self.definitions.read().values().cloned().collect()
 	}
 
 	/// Get a setting value with type safety
 	///
-	/// Returns `None` if the key doesn't exist or has expired.
+	/// Falls back to the declared default when no value is stored. Returns
+	/// `None` if the key is neither stored nor declared.
 	///
 	/// ## Example
 	///
@@ -364,10 +469,14 @@ impl DynamicSettings {
 		#[cfg(feature = "caching")]
 		if let (Some(cache), Some(val)) = (&self.cache, &value) {
 			cache
-				.insert(key.to_string(), CachedValue::new(val.clone(), None))
+				.insert(
+					key.to_string(),
+					CachedValue::new(val.clone(), self.cache_ttl),
+				)
 				.await;
 		}
 
+		let value = value.or_else(|| self.definition(key).map(|definition| definition.default));
 		match value {
 			Some(v) => serde_json::from_value(v)
 				.map(Some)
@@ -378,6 +487,9 @@ impl DynamicSettings {
 
 	/// Set a setting value with optional TTL
 	///
+	/// Fails with [`DynamicError::InvalidType`] if the key was declared with
+	/// another type.
+	///
 	/// ## Example
 	///
 	/// ```rust
@@ -401,6 +513,11 @@ impl DynamicSettings {
 		ttl: Option<u64>,
 	) -> DynamicResult<()> {
 		let json_value = serde_json::to_value(value)?;
+		if let Some(definition) = self.definition(key)
+			&& !definition.accepts(&json_value)
+		{
+			return Err(DynamicError::InvalidType);
+		}
 
 		// Set in backend
 		self.backend.set(key, &json_value, ttl).await?;
@@ -738,6 +855,40 @@ impl DynamicSettings {
 	}
 }
 
+static DYNAMIC_SETTINGS: OnceLock<DynamicSettings> = OnceLock::new();
+
+/// Install the process-wide dynamic settings
+///
+/// Only the first call takes effect; returns `false` if dynamic settings were
+/// already installed or read through [`dynamic_settings`].
+pub fn set_dynamic_settings(settings: DynamicSettings) -> bool {
+	DYNAMIC_SETTINGS.set(settings).is_ok()
+}
+
+/// The process-wide dynamic settings
+///
+/// Uses an in-memory backend when [`set_dynamic_settings`] was not called,
+/// so that declared defaults can be read in tests and tools.
+///
+/// ## Example
+///
+/// ```rust
+/// # futures::executor::block_on(async {
+/// use reinhardt_conf::settings::dynamic::dynamic_settings;
+///
+/// dynamic_settings()
+///     .declare("site.banner", &String::new(), "Banner shown on every page")
+///     .unwrap();
+///
+/// let banner: String = dynamic_settings().get("site.banner").await.unwrap().unwrap();
+/// assert_eq!(banner, "");
+/// # });
+/// ```
+pub fn dynamic_settings() -> &'static DynamicSettings {
+	DYNAMIC_SETTINGS
+		.get_or_init(|| DynamicSettings::new(Arc::new(super::backends::MemoryBackend::new())))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -879,6 +1030,42 @@ mod tests {
 		assert!(keys.contains(&"key3".to_string()));
 	}
 
+	#[tokio::test]
+	async fn test_declared_default_until_set() {
+		let backend = Arc::new(TestBackend::new());
+		let settings = DynamicSettings::new(backend);
+		settings
+			.declare("shop.max_items", &50u32, "Maximum items per cart")
+			.unwrap();
+
+		let default: u32 = settings.get("shop.max_items").await.unwrap().unwrap();
+		assert_eq!(default, 50);
+		assert!(!settings.exists("shop.max_items").await.unwrap());
+
+		settings.set("shop.max_items", &10u32, None).await.unwrap();
+		let value: u32 = settings.get("shop.max_items").await.unwrap().unwrap();
+		assert_eq!(value, 10);
+
+		settings.delete("shop.max_items").await.unwrap();
+		let value: u32 = settings.get("shop.max_items").await.unwrap().unwrap();
+		assert_eq!(value, 50);
+	}
+
+	#[tokio::test]
+	async fn test_set_rejects_values_of_another_type() {
+		let backend = Arc::new(TestBackend::new());
+		let settings = DynamicSettings::new(backend);
+		settings
+			.declare("maintenance", &false, "Show the maintenance page")
+			.unwrap();
+
+		let result = settings.set("maintenance", &"yes", None).await;
+
+		assert!(matches!(result, Err(DynamicError::InvalidType)));
+		assert!(!settings.exists("maintenance").await.unwrap());
+		assert_eq!(settings.definitions()[0].key, "maintenance");
+	}
+
 	#[cfg(feature = "caching")]
 	#[tokio::test]
 	async fn test_caching() {
@@ -912,4 +1099,17 @@ mod tests {
 		let value3: String = settings.get("cached_key").await.unwrap().unwrap();
 		assert_eq!(value3, "modified");
 	}
+
+	#[cfg(feature = "caching")]
+	#[test]
+	fn test_cache_never_expires_without_ttl() {
+		let mut settings = DynamicSettings::new(Arc::new(TestBackend::new()));
+		assert!(!settings.cache_never_expires());
+
+		settings.enable_cache(10, Some(Duration::from_secs(60)));
+		assert!(!settings.cache_never_expires());
+
+		settings.enable_cache(10, None);
+		assert!(settings.cache_never_expires());
+	}
 }
@@ -0,0 +1,80 @@
+//! Forms settings fragment
+//!
+//! Selects the renderer used by `form!` and `reinhardt-forms` to style form
+//! fields.
+//!
+//! ```toml
+//! [forms]
+//! renderer = "bootstrap"
+//! ```
+//!
+//! Built-in renderers are `default` (`reinhardt-*` classes), `bootstrap` and
+//! `tailwind`; see `reinhardt_core::form_renderer`.
+
+use reinhardt_core::form_renderer::{FormRenderer, renderer_by_name, set_form_renderer};
+use reinhardt_core::macros::settings;
+use serde::{Deserialize, Serialize};
+
+fn default_renderer() -> String {
+	"default".to_string()
+}
+
+/// Form rendering configuration.
+#[settings(fragment = true, section = "forms")]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormsSettings {
+	/// Name of the built-in form renderer.
+	#[serde(default = "default_renderer")]
+	pub renderer: String,
+}
+
+impl FormsSettings {
+	/// The configured built-in renderer, or `None` if the name is unknown.
+	pub fn form_renderer(&self) -> Option<Box<dyn FormRenderer>> {
+		renderer_by_name(&self.renderer)
+	}
+
+	/// Install the configured renderer for every form.
+	///
+	/// Returns `false` if the renderer name is unknown or a renderer was
+	/// already installed.
+	pub fn install_renderer(&self) -> bool {
+		self.form_renderer().is_some_and(set_form_renderer)
+	}
+}
+
+impl Default for FormsSettings {
+	fn default() -> Self {
+		Self {
+			renderer: default_renderer(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::settings::fragment::SettingsFragment;
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_forms_settings_section() {
+		// Arrange / Act / Assert
+		assert_eq!(FormsSettings::section(), "forms");
+	}
+
+	#[rstest]
+	#[case::default("{}", Some("default"))]
+	#[case::bootstrap(r#"{"renderer": "bootstrap"}"#, Some("bootstrap"))]
+	#[case::unknown(r#"{"renderer": "foundation"}"#, None)]
+	fn test_forms_settings_resolves_renderer(#[case] json: &str, #[case] expected: Option<&str>) {
+		// Arrange
+		let settings: FormsSettings = serde_json::from_str(json).unwrap();
+
+		// Act
+		let renderer = settings.form_renderer();
+
+		// Assert
+		assert_eq!(renderer.map(|renderer| renderer.name()), expected);
+	}
+}
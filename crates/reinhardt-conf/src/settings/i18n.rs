@@ -7,7 +7,8 @@ use serde::{Deserialize, Serialize};
 
 /// Internationalization and localization settings.
 ///
-/// Django compatibility fields. Currently reserved for future i18n support.
+/// Django compatibility fields. `time_zone` and `use_tz` form the time zone
+/// policy, installed with [`I18nSettings::apply_timezone_policy`].
 #[settings(fragment = true, section = "i18n")]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct I18nSettings {
@@ -20,7 +21,8 @@ pub struct I18nSettings {
 	/// Enable internationalization.
 	#[serde(default = "default_true")]
 	pub use_i18n: bool,
-	/// Use timezone-aware datetimes.
+	/// Use timezone-aware datetimes: store UTC and convert to the current
+	/// time zone for display.
 	#[serde(default = "default_true")]
 	pub use_tz: bool,
 }
@@ -37,6 +39,29 @@ fn default_true() -> bool {
 	true
 }
 
+impl I18nSettings {
+	/// Install `use_tz` and `time_zone` as the process-wide time zone policy
+	///
+	/// # Errors
+	///
+	/// Returns an error if `time_zone` is not a valid IANA time zone name.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_conf::settings::i18n::I18nSettings;
+	/// use reinhardt_utils::utils_core::timezone;
+	///
+	/// let settings = I18nSettings::default();
+	/// settings.apply_timezone_policy().unwrap();
+	/// assert!(timezone::use_tz());
+	/// assert_eq!(timezone::get_default_timezone().name(), "UTC");
+	/// ```
+	pub fn apply_timezone_policy(&self) -> Result<(), String> {
+		reinhardt_utils::utils_core::timezone::set_timezone_policy(self.use_tz, &self.time_zone)
+	}
+}
+
 impl Default for I18nSettings {
 	fn default() -> Self {
 		Self {
@@ -71,4 +96,19 @@ mod tests {
 		assert!(settings.use_i18n);
 		assert!(settings.use_tz);
 	}
+
+	#[rstest]
+	fn test_apply_timezone_policy_rejects_unknown_zone() {
+		// Arrange
+		let settings = I18nSettings {
+			time_zone: "Mars/Olympus".to_string(),
+			..I18nSettings::default()
+		};
+
+		// Act
+		let result = settings.apply_timezone_policy();
+
+		// Assert
+		assert!(result.unwrap_err().contains("Mars/Olympus"));
+	}
 }
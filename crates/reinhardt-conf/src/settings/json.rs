@@ -0,0 +1,110 @@
+//! JSON settings fragment
+//!
+//! Project-wide defaults for JSON rendered by REST views and
+//! `JsonSerializer`.
+//!
+//! ```toml
+//! [json]
+//! key_case = "camel_case"
+//! datetime_format = "epoch_seconds"
+//! strict = true
+//! # pretty = true  # defaults to the value of `debug`
+//! ```
+//!
+//! Individual views can still override these options; see
+//! `reinhardt_core::serializers::json_options`.
+
+use reinhardt_core::macros::settings;
+use reinhardt_core::serializers::json_options::{DateTimeFormat, JsonOptions, KeyCase};
+use serde::{Deserialize, Serialize};
+
+/// JSON rendering configuration.
+#[settings(fragment = true, section = "json")]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonSettings {
+	/// Case applied to object keys in responses and undone on parse.
+	#[serde(default)]
+	pub key_case: KeyCase,
+	/// Whether responses are indented. Follows `debug` when unset.
+	#[serde(default)]
+	pub pretty: Option<bool>,
+	/// Format used for datetime values.
+	#[serde(default)]
+	pub datetime_format: DateTimeFormat,
+	/// Whether `NaN` and infinite floats are rejected instead of written as `null`.
+	#[serde(default)]
+	pub strict: bool,
+}
+
+impl JsonSettings {
+	/// The configured options, pretty-printing in debug mode unless `pretty`
+	/// is set explicitly.
+	pub fn json_options(&self, debug: bool) -> JsonOptions {
+		JsonOptions::new()
+			.key_case(self.key_case)
+			.pretty(self.pretty.unwrap_or(debug))
+			.datetime_format(self.datetime_format)
+			.strict(self.strict)
+	}
+
+	/// Install the configured options as the project-wide default.
+	///
+	/// Returns `false` if options were already installed.
+	pub fn install(&self, debug: bool) -> bool {
+		JsonOptions::set_global(self.json_options(debug))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::settings::fragment::SettingsFragment;
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_json_settings_section() {
+		// Arrange / Act / Assert
+		assert_eq!(JsonSettings::section(), "json");
+	}
+
+	#[rstest]
+	#[case::debug_default("{}", true, true)]
+	#[case::release_default("{}", false, false)]
+	#[case::explicit_off(r#"{"pretty": false}"#, true, false)]
+	#[case::explicit_on(r#"{"pretty": true}"#, false, true)]
+	fn test_json_settings_pretty_follows_debug(
+		#[case] json: &str,
+		#[case] debug: bool,
+		#[case] expected: bool,
+	) {
+		// Arrange
+		let settings: JsonSettings = serde_json::from_str(json).unwrap();
+
+		// Act
+		let options = settings.json_options(debug);
+
+		// Assert
+		assert_eq!(options.pretty, expected);
+	}
+
+	#[rstest]
+	fn test_json_settings_parses_options() {
+		// Arrange
+		let json =
+			r#"{"key_case": "camel_case", "datetime_format": "epoch_millis", "strict": true}"#;
+
+		// Act
+		let options = serde_json::from_str::<JsonSettings>(json)
+			.unwrap()
+			.json_options(false);
+
+		// Assert
+		assert_eq!(
+			options,
+			JsonOptions::new()
+				.key_case(KeyCase::CamelCase)
+				.datetime_format(DateTimeFormat::EpochMillis)
+				.strict(true)
+		);
+	}
+}
@@ -42,7 +42,10 @@ pub use super::TemplateConfig;
 pub use super::backends::{memory::MemoryBackend, *};
 
 #[cfg(feature = "async")]
-pub use super::dynamic::{DynamicBackend, DynamicError, DynamicResult, DynamicSettings};
+pub use super::dynamic::{
+	DynamicBackend, DynamicError, DynamicResult, DynamicSettings, SettingDefinition,
+	dynamic_settings,
+};
 
 #[cfg(feature = "async")]
 pub use super::secrets::{
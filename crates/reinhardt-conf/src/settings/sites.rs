@@ -0,0 +1,69 @@
+//! Sites settings fragment
+//!
+//! Identifies the site a deployment serves when one codebase runs several
+//! sites, like Django's `SITE_ID`.
+//!
+//! ```toml
+//! [sites]
+//! site_id = 1
+//! domain = "example.com"
+//! name = "Example"
+//! ```
+//!
+//! The site is used whenever the `Host` header of a request does not match a
+//! registered site, and when building absolute URLs outside of a request.
+
+use reinhardt_core::macros::settings;
+use serde::{Deserialize, Serialize};
+
+fn default_site_id() -> u64 {
+	1
+}
+
+/// Default site configuration.
+#[settings(fragment = true, section = "sites")]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SitesSettings {
+	/// ID of the default site.
+	#[serde(default = "default_site_id")]
+	pub site_id: u64,
+	/// Domain of the default site, optionally with a port.
+	#[serde(default)]
+	pub domain: Option<String>,
+	/// Human-readable name of the default site.
+	#[serde(default)]
+	pub name: Option<String>,
+}
+
+impl Default for SitesSettings {
+	fn default() -> Self {
+		Self {
+			site_id: default_site_id(),
+			domain: None,
+			name: None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::settings::fragment::SettingsFragment;
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_sites_settings_section() {
+		// Arrange / Act / Assert
+		assert_eq!(SitesSettings::section(), "sites");
+	}
+
+	#[rstest]
+	fn test_sites_settings_defaults_to_first_site() {
+		// Arrange / Act
+		let settings: SitesSettings = serde_json::from_str("{}").unwrap();
+
+		// Assert
+		assert_eq!(settings, SitesSettings::default());
+		assert_eq!(settings.site_id, 1);
+	}
+}
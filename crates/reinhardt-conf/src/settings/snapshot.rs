@@ -0,0 +1,279 @@
+//! Shared, atomically reloadable settings snapshots
+//!
+//! A [`SettingsSnapshot`] holds the current settings behind an
+//! [`ArcSwap`], so every request can read them without locking or cloning,
+//! while a reload swaps in a freshly loaded value in one atomic step.
+//! Readers that already hold the previous value keep using it until they
+//! drop it; readers that arrive after the swap see the new one.
+//!
+//! ## Example
+//!
+//! ```
+//! use reinhardt_conf::settings::snapshot::SettingsSnapshot;
+//! use std::sync::Arc;
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct AppSettings {
+//!     debug: bool,
+//! }
+//!
+//! let snapshot = Arc::new(SettingsSnapshot::new(AppSettings { debug: false }));
+//! assert!(!snapshot.load().debug);
+//!
+//! // Reload, e.g. from a file watcher or SIGHUP handler
+//! snapshot
+//!     .reload(|| Ok::<_, String>(AppSettings { debug: true }))
+//!     .unwrap();
+//! assert!(snapshot.load().debug);
+//! assert_eq!(snapshot.generation(), 1);
+//! ```
+
+use arc_swap::ArcSwap;
+use parking_lot::Mutex;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(feature = "hot-reload")]
+use super::hot_reload::HotReloadManager;
+
+/// A borrowed view of the current settings, returned by [`SettingsSnapshot::load`]
+pub type SnapshotGuard<T> = arc_swap::Guard<Arc<T>>;
+
+/// The current settings, shared across requests and reloadable in place
+pub struct SettingsSnapshot<T> {
+	current: ArcSwap<T>,
+	generation: AtomicU64,
+	last_error: Mutex<Option<String>>,
+}
+
+impl<T> SettingsSnapshot<T> {
+	/// Create a snapshot holding `value`
+	pub fn new(value: T) -> Self {
+		Self::from_arc(Arc::new(value))
+	}
+
+	/// Create a snapshot from an already shared value
+	pub fn from_arc(value: Arc<T>) -> Self {
+		Self {
+			current: ArcSwap::new(value),
+			generation: AtomicU64::new(0),
+			last_error: Mutex::new(None),
+		}
+	}
+
+	/// Borrow the current settings
+	///
+	/// This is the per-request accessor: it neither locks nor touches the
+	/// reference count in the common case. Hold the guard only for as long as
+	/// the settings are needed; use [`get`](Self::get) to keep them longer.
+	pub fn load(&self) -> SnapshotGuard<T> {
+		self.current.load()
+	}
+
+	/// Get an owned handle to the current settings
+	pub fn get(&self) -> Arc<T> {
+		self.current.load_full()
+	}
+
+	/// Number of times the settings have been replaced
+	///
+	/// Consumers that derive state from the settings (for example a
+	/// middleware built from a fragment) compare this against the generation
+	/// they were built from to know when to rebuild.
+	pub fn generation(&self) -> u64 {
+		self.generation.load(Ordering::Acquire)
+	}
+
+	/// Replace the settings, returning the previous value
+	pub fn replace(&self, value: T) -> Arc<T> {
+		let previous = self.current.swap(Arc::new(value));
+		self.generation.fetch_add(1, Ordering::AcqRel);
+		previous
+	}
+
+	/// Load new settings with `loader` and swap them in
+	///
+	/// On failure the current settings stay in place and the error is kept
+	/// for [`last_reload_error`](Self::last_reload_error). Returns the new
+	/// generation on success.
+	pub fn reload<E, F>(&self, loader: F) -> Result<u64, E>
+	where
+		F: FnOnce() -> Result<T, E>,
+		E: fmt::Display,
+	{
+		match loader() {
+			Ok(value) => {
+				self.replace(value);
+				*self.last_error.lock() = None;
+				Ok(self.generation())
+			}
+			Err(e) => {
+				*self.last_error.lock() = Some(e.to_string());
+				Err(e)
+			}
+		}
+	}
+
+	/// The error from the most recent reload, if it failed
+	pub fn last_reload_error(&self) -> Option<String> {
+		self.last_error.lock().clone()
+	}
+}
+
+#[cfg(feature = "hot-reload")]
+impl<T: Send + Sync + 'static> SettingsSnapshot<T> {
+	/// Reload the settings with `loader` whenever `manager` reports a change
+	///
+	/// The callback only holds a weak reference, so it does not keep the
+	/// snapshot alive. Failed reloads leave the current settings in place.
+	///
+	/// ## Example
+	///
+	/// ```rust,no_run
+	/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+	/// use reinhardt_conf::settings::hot_reload::HotReloadManager;
+	/// use reinhardt_conf::settings::snapshot::SettingsSnapshot;
+	/// use std::path::Path;
+	/// use std::sync::Arc;
+	///
+	/// let load = || std::fs::read_to_string("config.toml");
+	/// let snapshot = Arc::new(SettingsSnapshot::new(load()?));
+	///
+	/// let manager = HotReloadManager::new();
+	/// snapshot.reload_on_change(&manager, load);
+	/// manager.watch(Path::new("config.toml")).await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn reload_on_change<E, F>(self: &Arc<Self>, manager: &HotReloadManager, loader: F)
+	where
+		F: Fn() -> Result<T, E> + Send + Sync + 'static,
+		E: fmt::Display,
+	{
+		let snapshot = Arc::downgrade(self);
+		manager.on_reload(Arc::new(move |_path| {
+			if let Some(snapshot) = snapshot.upgrade() {
+				// The error is recorded on the snapshot
+				let _ = snapshot.reload(&loader);
+			}
+		}));
+	}
+
+	/// Reload the settings with `loader` every time the process receives
+	/// `SIGHUP`
+	///
+	/// Spawns a task on the current Tokio runtime that runs until the
+	/// snapshot is dropped.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the signal handler cannot be registered.
+	#[cfg(unix)]
+	pub fn reload_on_sighup<E, F>(
+		self: &Arc<Self>,
+		loader: F,
+	) -> std::io::Result<tokio::task::JoinHandle<()>>
+	where
+		F: Fn() -> Result<T, E> + Send + Sync + 'static,
+		E: fmt::Display,
+	{
+		use tokio::signal::unix::{SignalKind, signal};
+
+		let mut hangup = signal(SignalKind::hangup())?;
+		let snapshot = Arc::downgrade(self);
+		Ok(tokio::spawn(async move {
+			while hangup.recv().await.is_some() {
+				let Some(snapshot) = snapshot.upgrade() else {
+					break;
+				};
+				// The error is recorded on the snapshot
+				let _ = snapshot.reload(&loader);
+			}
+		}))
+	}
+}
+
+impl<T: fmt::Debug> fmt::Debug for SettingsSnapshot<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("SettingsSnapshot")
+			.field("current", &*self.load())
+			.field("generation", &self.generation())
+			.finish()
+	}
+}
+
+impl<T: Default> Default for SettingsSnapshot<T> {
+	fn default() -> Self {
+		Self::new(T::default())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_load_shares_the_same_value() {
+		// Arrange
+		let snapshot = SettingsSnapshot::new(String::from("v1"));
+
+		// Act
+		let first = snapshot.get();
+		let second = snapshot.get();
+
+		// Assert
+		assert!(Arc::ptr_eq(&first, &second));
+		assert_eq!(snapshot.generation(), 0);
+	}
+
+	#[rstest]
+	fn test_replace_keeps_existing_readers_on_old_value() {
+		// Arrange
+		let snapshot = SettingsSnapshot::new(String::from("v1"));
+		let held = snapshot.get();
+
+		// Act
+		let previous = snapshot.replace(String::from("v2"));
+
+		// Assert
+		assert_eq!(*held, "v1");
+		assert!(Arc::ptr_eq(&held, &previous));
+		assert_eq!(**snapshot.load(), "v2");
+		assert_eq!(snapshot.generation(), 1);
+	}
+
+	#[rstest]
+	fn test_failed_reload_keeps_current_value() {
+		// Arrange
+		let snapshot = SettingsSnapshot::new(String::from("v1"));
+
+		// Act
+		let result = snapshot.reload(|| Err::<String, _>("invalid TOML"));
+
+		// Assert
+		assert_eq!(result, Err("invalid TOML"));
+		assert_eq!(**snapshot.load(), "v1");
+		assert_eq!(snapshot.generation(), 0);
+		assert_eq!(
+			snapshot.last_reload_error().as_deref(),
+			Some("invalid TOML")
+		);
+	}
+
+	#[rstest]
+	fn test_successful_reload_clears_error() {
+		// Arrange
+		let snapshot = SettingsSnapshot::new(String::from("v1"));
+		let _ = snapshot.reload(|| Err::<String, _>("invalid TOML"));
+
+		// Act
+		let generation = snapshot.reload(|| Ok::<_, String>(String::from("v2")));
+
+		// Assert
+		assert_eq!(generation, Ok(1));
+		assert_eq!(**snapshot.load(), "v2");
+		assert_eq!(snapshot.last_reload_error(), None);
+	}
+}
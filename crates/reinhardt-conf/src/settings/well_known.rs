@@ -0,0 +1,264 @@
+//! Well-known files settings fragment
+//!
+//! Configures the `/robots.txt` and `/.well-known/security.txt` responses.
+//!
+//! ```toml
+//! [well_known.robots]
+//! disallow = ["/admin/"]
+//! sitemaps = ["https://example.com/sitemap.xml"]
+//!
+//! [well_known.security_txt]
+//! contact = ["mailto:security@example.com"]
+//! expires = "2027-01-01T00:00:00Z"
+//! ```
+//!
+//! Unless `disallow_all` is set explicitly, `robots.txt` blocks every crawler
+//! outside the production profile, so staging deployments stay out of search
+//! indexes without extra configuration.
+
+use super::fragment::SettingsValidation;
+use super::profile::Profile;
+use super::validation::{ValidationError, ValidationResult};
+use chrono::{DateTime, Utc};
+use reinhardt_core::macros::settings;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+fn default_enabled() -> bool {
+	true
+}
+
+/// `robots.txt` policy.
+///
+/// Nested inside [`WellKnownSettings`]; it is never loaded from its own section.
+#[settings(fragment = true)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RobotsSettings {
+	/// Serve `/robots.txt`.
+	#[serde(default = "default_enabled")]
+	pub enabled: bool,
+	/// Disallow every crawler from the whole site.
+	///
+	/// When unset, the site is disallowed in every profile but production.
+	#[serde(default)]
+	pub disallow_all: Option<bool>,
+	/// Paths crawlers may visit, for all user agents.
+	#[serde(default)]
+	pub allow: Vec<String>,
+	/// Paths crawlers must not visit, for all user agents.
+	#[serde(default)]
+	pub disallow: Vec<String>,
+	/// Absolute sitemap URLs advertised to crawlers.
+	#[serde(default)]
+	pub sitemaps: Vec<String>,
+	/// Template file replacing the generated body.
+	///
+	/// `{{ default }}` in the template expands to the generated rules.
+	#[serde(default)]
+	pub template: Option<PathBuf>,
+}
+
+impl Default for RobotsSettings {
+	fn default() -> Self {
+		Self {
+			enabled: true,
+			disallow_all: None,
+			allow: Vec::new(),
+			disallow: Vec::new(),
+			sitemaps: Vec::new(),
+			template: None,
+		}
+	}
+}
+
+impl RobotsSettings {
+	/// Whether the whole site is disallowed under `profile`.
+	pub fn disallows_all(&self, profile: &Profile) -> bool {
+		self.disallow_all.unwrap_or(!profile.is_production())
+	}
+}
+
+/// `security.txt` fields (RFC 9116).
+///
+/// Nested inside [`WellKnownSettings`]; it is never loaded from its own section.
+/// The file is only served once at least one contact is configured.
+#[settings(fragment = true)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecurityTxtSettings {
+	/// Contact URIs (`mailto:`, `https://` or `tel:`) for reporting vulnerabilities.
+	#[serde(default)]
+	pub contact: Vec<String>,
+	/// Expiry date of the file, in RFC 3339 format.
+	#[serde(default)]
+	pub expires: Option<String>,
+	/// URLs of the keys to encrypt reports with.
+	#[serde(default)]
+	pub encryption: Vec<String>,
+	/// URLs of the page acknowledging reporters.
+	#[serde(default)]
+	pub acknowledgments: Vec<String>,
+	/// Languages reports may be written in, e.g. `en`.
+	#[serde(default)]
+	pub preferred_languages: Vec<String>,
+	/// Canonical URLs of this file.
+	#[serde(default)]
+	pub canonical: Vec<String>,
+	/// URLs of the vulnerability disclosure policy.
+	#[serde(default)]
+	pub policy: Vec<String>,
+	/// URLs of security-related job openings.
+	#[serde(default)]
+	pub hiring: Vec<String>,
+	/// Template file replacing the generated body.
+	///
+	/// `{{ default }}` in the template expands to the generated fields.
+	#[serde(default)]
+	pub template: Option<PathBuf>,
+}
+
+impl SecurityTxtSettings {
+	/// Whether `security.txt` should be served.
+	pub fn is_configured(&self) -> bool {
+		!self.contact.is_empty() || self.template.is_some()
+	}
+
+	/// Parsed expiry date.
+	pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+		self.expires
+			.as_deref()
+			.and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+			.map(|value| value.with_timezone(&Utc))
+	}
+}
+
+/// Well-known files configuration fragment.
+///
+/// Drives the `/robots.txt` and `/.well-known/security.txt` handlers.
+#[settings(fragment = true, section = "well_known", validate = false)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WellKnownSettings {
+	/// `robots.txt` policy (`[well_known.robots]`).
+	#[setting(node)]
+	#[serde(default)]
+	pub robots: RobotsSettings,
+	/// `security.txt` fields (`[well_known.security_txt]`).
+	#[setting(node)]
+	#[serde(default)]
+	pub security_txt: SecurityTxtSettings,
+}
+
+impl SettingsValidation for WellKnownSettings {
+	fn validate(&self, profile: &Profile) -> ValidationResult {
+		let security_txt = &self.security_txt;
+		if security_txt.contact.is_empty() {
+			return Ok(());
+		}
+
+		let mut errors = Vec::new();
+		for contact in &security_txt.contact {
+			if !["mailto:", "https://", "tel:"]
+				.iter()
+				.any(|scheme| contact.starts_with(scheme))
+			{
+				errors.push(ValidationError::InvalidValue {
+					key: "well_known.security_txt.contact".to_string(),
+					message: format!("'{}' must be a mailto:, https:// or tel: URI", contact),
+				});
+			}
+		}
+		match (&security_txt.expires, security_txt.expires_at()) {
+			(None, _) => errors.push(ValidationError::MissingRequired(
+				"well_known.security_txt.expires".to_string(),
+			)),
+			(Some(value), None) => errors.push(ValidationError::InvalidValue {
+				key: "well_known.security_txt.expires".to_string(),
+				message: format!("'{}' is not an RFC 3339 date", value),
+			}),
+			(Some(_), Some(expires)) if profile.is_production() && expires <= Utc::now() => errors
+				.push(ValidationError::Constraint(
+					"well_known.security_txt.expires is in the past".to_string(),
+				)),
+			_ => {}
+		}
+
+		match errors.len() {
+			0 => Ok(()),
+			1 => Err(errors.remove(0)),
+			_ => Err(ValidationError::Multiple(errors)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::settings::fragment::SettingsFragment;
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_well_known_section_name() {
+		// Arrange / Act
+		let section = WellKnownSettings::section();
+
+		// Assert
+		assert_eq!(section, "well_known");
+	}
+
+	#[rstest]
+	#[case(Profile::Development, None, true)]
+	#[case(Profile::Staging, None, true)]
+	#[case(Profile::Production, None, false)]
+	#[case(Profile::Staging, Some(false), false)]
+	#[case(Profile::Production, Some(true), true)]
+	fn test_robots_disallows_all_per_profile(
+		#[case] profile: Profile,
+		#[case] disallow_all: Option<bool>,
+		#[case] expected: bool,
+	) {
+		// Arrange
+		let robots = RobotsSettings {
+			disallow_all,
+			..Default::default()
+		};
+
+		// Act / Assert
+		assert_eq!(robots.disallows_all(&profile), expected);
+	}
+
+	#[rstest]
+	fn test_validate_skips_unconfigured_security_txt() {
+		// Arrange
+		let settings = WellKnownSettings::default();
+
+		// Act / Assert
+		assert!(SettingsFragment::validate(&settings, &Profile::Production).is_ok());
+	}
+
+	#[rstest]
+	#[case(vec!["mailto:security@example.com"], Some("2099-01-01T00:00:00Z"), true)]
+	#[case(vec!["security@example.com"], Some("2099-01-01T00:00:00Z"), false)]
+	#[case(vec!["https://example.com/report"], None, false)]
+	#[case(vec!["tel:+1-201-555-0123"], Some("next year"), false)]
+	#[case(vec!["mailto:security@example.com"], Some("2000-01-01T00:00:00Z"), false)]
+	fn test_validate_security_txt(
+		#[case] contact: Vec<&str>,
+		#[case] expires: Option<&str>,
+		#[case] valid: bool,
+	) {
+		// Arrange
+		let settings = WellKnownSettings {
+			security_txt: SecurityTxtSettings {
+				contact: contact.into_iter().map(String::from).collect(),
+				expires: expires.map(String::from),
+				..Default::default()
+			},
+			..Default::default()
+		};
+
+		// Act
+		let result = SettingsFragment::validate(&settings, &Profile::Production);
+
+		// Assert
+		assert_eq!(result.is_ok(), valid);
+	}
+}
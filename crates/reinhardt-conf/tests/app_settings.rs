@@ -0,0 +1,134 @@
+// Integration tests for typed app settings loaded through MergedSettings::app.
+
+use reinhardt_conf::settings::app_settings::{AppSettings, AppSettingsError};
+use reinhardt_conf::settings::builder::{MergedSettings, SettingsBuilder};
+use reinhardt_conf::settings::sources::DefaultSource;
+use reinhardt_macros::AppSettings;
+use rstest::rstest;
+use serde_json::json;
+use serial_test::serial;
+use std::sync::Arc;
+
+fn retries_in_range(value: &u32) -> Result<(), String> {
+	if *value <= 10 {
+		Ok(())
+	} else {
+		Err(format!("{} exceeds 10", value))
+	}
+}
+
+#[derive(Clone, AppSettings)]
+#[app_settings(section = "payments", env_prefix = "PAYMENTS_TEST_")]
+struct PaymentsSettings {
+	#[app_setting(secret)]
+	api_key: String,
+	#[app_setting(default = "EUR".to_string())]
+	currency: String,
+	#[app_setting(default = 3, validate = retries_in_range)]
+	max_retries: u32,
+	webhook_url: Option<String>,
+}
+
+fn settings(section: serde_json::Value) -> MergedSettings {
+	SettingsBuilder::new()
+		.add_source(DefaultSource::new().with_value("payments", section))
+		.build()
+		.unwrap()
+}
+
+#[rstest]
+#[serial(env)]
+fn app_settings_apply_defaults() {
+	// Arrange
+	let settings = settings(json!({"api_key": "sk_live_123"}));
+
+	// Act
+	let payments = settings.app::<PaymentsSettings>().unwrap();
+
+	// Assert
+	assert_eq!(payments.api_key, "sk_live_123");
+	assert_eq!(payments.currency, "EUR");
+	assert_eq!(payments.max_retries, 3);
+	assert_eq!(payments.webhook_url, None);
+}
+
+#[rstest]
+#[serial(env)]
+fn app_settings_are_overridden_by_env_prefix() {
+	// Arrange
+	let settings = settings(json!({"api_key": "sk_live_123", "max_retries": 2}));
+	// SAFETY: serialized with other environment tests
+	unsafe { std::env::set_var("PAYMENTS_TEST_MAX_RETRIES", "5") };
+
+	// Act
+	let payments = settings.app::<PaymentsSettings>();
+	unsafe { std::env::remove_var("PAYMENTS_TEST_MAX_RETRIES") };
+
+	// Assert
+	assert_eq!(payments.unwrap().max_retries, 5);
+}
+
+#[rstest]
+#[serial(env)]
+fn app_settings_are_cached_per_type() {
+	// Arrange
+	let settings = settings(json!({"api_key": "sk_live_123"}));
+	let first = settings.app::<PaymentsSettings>().unwrap();
+
+	// Act
+	let second = settings.clone().app::<PaymentsSettings>().unwrap();
+
+	// Assert
+	assert!(Arc::ptr_eq(&first, &second));
+}
+
+#[rstest]
+#[serial(env)]
+fn app_settings_report_missing_required_field() {
+	// Arrange
+	let settings = settings(json!({"currency": "USD"}));
+
+	// Act
+	let result = settings.app::<PaymentsSettings>();
+
+	// Assert
+	assert!(matches!(
+		result,
+		Err(AppSettingsError::MissingField {
+			section: "payments",
+			field: "api_key",
+		})
+	));
+}
+
+#[rstest]
+#[serial(env)]
+fn app_settings_run_field_validators() {
+	// Arrange
+	let settings = settings(json!({"api_key": "sk_live_123", "max_retries": 50}));
+
+	// Act
+	let result = settings.register_app::<PaymentsSettings>();
+
+	// Assert
+	assert!(matches!(
+		result,
+		Err(AppSettingsError::Validation { ref message, .. }) if message == "max_retries: 50 exceeds 10"
+	));
+}
+
+#[rstest]
+#[serial(env)]
+fn app_settings_redact_secrets_in_debug() {
+	// Arrange
+	let settings = settings(json!({"api_key": "sk_live_123"}));
+	let payments = settings.app::<PaymentsSettings>().unwrap();
+
+	// Act
+	let debug = format!("{:?}", payments);
+
+	// Assert
+	assert!(!debug.contains("sk_live_123"));
+	assert!(debug.contains("api_key: \"***\""));
+	assert_eq!(PaymentsSettings::secret_fields(), &["api_key"]);
+}
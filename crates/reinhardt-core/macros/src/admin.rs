@@ -78,6 +78,12 @@ pub(crate) struct AdminModelConfig {
 	pub readonly_fields: Option<Vec<Ident>>,
 	/// Ordering specification
 	pub ordering: Option<Vec<OrderingSpec>>,
+	/// Fields sorted in natural order
+	pub natural_ordering: Option<Vec<Ident>>,
+	/// Fields editable inline in list view
+	pub list_editable: Option<Vec<Ident>>,
+	/// Fields holding rich text editor HTML
+	pub rich_text_fields: Option<Vec<Ident>>,
 	/// Number of items per page
 	pub list_per_page: Option<usize>,
 	/// Individual permission flags
@@ -115,6 +121,9 @@ impl Parse for AdminModelConfig {
 		let mut fields: Option<Vec<Ident>> = None;
 		let mut readonly_fields: Option<Vec<Ident>> = None;
 		let mut ordering: Option<Vec<OrderingSpec>> = None;
+		let mut natural_ordering: Option<Vec<Ident>> = None;
+		let mut list_editable: Option<Vec<Ident>> = None;
+		let mut rich_text_fields: Option<Vec<Ident>> = None;
 		let mut list_per_page: Option<usize> = None;
 		let mut allow_view: Option<bool> = None;
 		let mut allow_add: Option<bool> = None;
@@ -162,6 +171,15 @@ impl Parse for AdminModelConfig {
 				"ordering" => {
 					ordering = Some(parse_ordering_array(input)?);
 				}
+				"natural_ordering" => {
+					natural_ordering = Some(parse_ident_array(input)?);
+				}
+				"list_editable" => {
+					list_editable = Some(parse_ident_array(input)?);
+				}
+				"rich_text_fields" => {
+					rich_text_fields = Some(parse_ident_array(input)?);
+				}
 				"list_per_page" => {
 					let lit: LitInt = input.parse()?;
 					list_per_page = Some(lit.base10_parse()?);
@@ -203,7 +221,7 @@ impl Parse for AdminModelConfig {
 					return Err(syn::Error::new(
 						key.span(),
 						format!(
-							"unknown attribute `{}` for model admin\n\n  = help: valid attributes are: for, name, list_display, list_filter, search_fields, fields, readonly_fields, ordering, list_per_page, allow_view, allow_add, allow_change, allow_delete, permissions",
+							"unknown attribute `{}` for model admin\n\n  = help: valid attributes are: for, name, list_display, list_filter, search_fields, fields, readonly_fields, ordering, natural_ordering, list_editable, rich_text_fields, list_per_page, allow_view, allow_add, allow_change, allow_delete, permissions",
 							unknown
 						),
 					));
@@ -231,6 +249,20 @@ impl Parse for AdminModelConfig {
 			)
 		})?;
 
+		// Inline-editable fields must be shown in the list view
+		if let Some(ref editable) = list_editable {
+			let displayed = list_display.as_deref().unwrap_or_default();
+			if let Some(field) = editable.iter().find(|f| !displayed.contains(*f)) {
+				return Err(syn::Error::new(
+					field.span(),
+					format!(
+						"`list_editable` field `{}` is not in `list_display`\n\n  = help: add `{}` to `list_display` or remove it from `list_editable`",
+						field, field
+					),
+				));
+			}
+		}
+
 		Ok(AdminModelConfig {
 			model_type,
 			name,
@@ -240,6 +272,9 @@ impl Parse for AdminModelConfig {
 			fields,
 			readonly_fields,
 			ordering,
+			natural_ordering,
+			list_editable,
+			rich_text_fields,
 			list_per_page,
 			allow_view,
 			allow_add,
@@ -310,6 +345,15 @@ pub(crate) fn admin_impl(args: TokenStream, input: ItemStruct) -> Result<TokenSt
 	if let Some(ref ordering) = config.ordering {
 		all_fields.extend(ordering.iter().map(|o| &o.field));
 	}
+	if let Some(ref fields) = config.natural_ordering {
+		all_fields.extend(fields.iter());
+	}
+	if let Some(ref fields) = config.list_editable {
+		all_fields.extend(fields.iter());
+	}
+	if let Some(ref fields) = config.rich_text_fields {
+		all_fields.extend(fields.iter());
+	}
 
 	// Generate field validation code
 	let field_checks: Vec<TokenStream> = all_fields
@@ -329,6 +373,15 @@ pub(crate) fn admin_impl(args: TokenStream, input: ItemStruct) -> Result<TokenSt
 		}
 	};
 
+	// Restrict the changelist to the model's default manager queryset
+	let queryset_filters_impl = quote! {
+		fn queryset_filters(&self) -> Vec<#orm_crate::Filter> {
+			#orm_crate::CustomManager::all(&<#model_type as #orm_crate::Model>::objects())
+				.filters()
+				.to_vec()
+		}
+	};
+
 	// Generate list_display method
 	let list_display_impl = if let Some(ref fields) = config.list_display {
 		let field_strs: Vec<String> = fields.iter().map(|f| f.to_string()).collect();
@@ -407,6 +460,42 @@ pub(crate) fn admin_impl(args: TokenStream, input: ItemStruct) -> Result<TokenSt
 		quote! {}
 	};
 
+	// Generate natural_ordering_fields method
+	let natural_ordering_impl = if let Some(ref fields) = config.natural_ordering {
+		let field_strs: Vec<String> = fields.iter().map(|f| f.to_string()).collect();
+		quote! {
+			fn natural_ordering_fields(&self) -> Vec<&str> {
+				vec![#(#field_strs),*]
+			}
+		}
+	} else {
+		quote! {}
+	};
+
+	// Generate list_editable method
+	let list_editable_impl = if let Some(ref fields) = config.list_editable {
+		let field_strs: Vec<String> = fields.iter().map(|f| f.to_string()).collect();
+		quote! {
+			fn list_editable(&self) -> Vec<&str> {
+				vec![#(#field_strs),*]
+			}
+		}
+	} else {
+		quote! {}
+	};
+
+	// Generate rich_text_fields method
+	let rich_text_fields_impl = if let Some(ref fields) = config.rich_text_fields {
+		let field_strs: Vec<String> = fields.iter().map(|f| f.to_string()).collect();
+		quote! {
+			fn rich_text_fields(&self) -> Vec<&str> {
+				vec![#(#field_strs),*]
+			}
+		}
+	} else {
+		quote! {}
+	};
+
 	// Generate list_per_page method
 	let list_per_page_impl = if let Some(count) = config.list_per_page {
 		quote! {
@@ -467,12 +556,16 @@ pub(crate) fn admin_impl(args: TokenStream, input: ItemStruct) -> Result<TokenSt
 			}
 
 			#table_name_impl
+			#queryset_filters_impl
 			#list_display_impl
 			#list_filter_impl
 			#search_fields_impl
 			#fields_impl
 			#readonly_fields_impl
 			#ordering_impl
+			#natural_ordering_impl
+			#list_editable_impl
+			#rich_text_fields_impl
 			#list_per_page_impl
 			#permission_impls
 		}
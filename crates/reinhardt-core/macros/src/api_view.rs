@@ -1,5 +1,9 @@
 //! api_view macro implementation
 
+use crate::crate_paths::get_reinhardt_http_crate;
+use crate::routes::{
+	detect_extractors, detect_inject_params, generate_wrapper_with_both, validate_extractors,
+};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Expr, ExprLit, ItemFn, Lit, Meta, Result, Token, parse::Parser, punctuated::Punctuated};
@@ -7,9 +11,15 @@ use syn::{Expr, ExprLit, ItemFn, Lit, Meta, Result, Token, parse::Parser, punctu
 ///
 /// This function is used internally by the `#[api_view]` attribute macro.
 /// Users should not call this function directly.
+///
+/// Functions taking extractor parameters (`Json<T>`, `Query<T>`, `Path<T>`,
+/// `CurrentUser<U>`, session values, or any `#[extract]` parameter) or
+/// `#[inject]` parameters are turned into a handler taking the `Request`;
+/// the parameters are extracted before the body runs.
 pub(crate) fn api_view_impl(args: TokenStream, input: ItemFn) -> Result<TokenStream> {
 	let mut methods = Vec::new();
 	let mut methods_lit = None;
+	let mut pre_validate = false;
 
 	// Parse method arguments
 	let meta_list = Punctuated::<Meta, Token![,]>::parse_terminated.parse2(args)?;
@@ -39,6 +49,20 @@ pub(crate) fn api_view_impl(args: TokenStream, input: ItemFn) -> Result<TokenStr
 					));
 				}
 			}
+			Meta::NameValue(nv) if nv.path.is_ident("pre_validate") => {
+				if let Expr::Lit(ExprLit {
+					lit: Lit::Bool(lit),
+					..
+				}) = &nv.value
+				{
+					pre_validate = lit.value;
+				} else {
+					return Err(syn::Error::new_spanned(
+						&nv.value,
+						"pre_validate must be a boolean (true or false)",
+					));
+				}
+			}
 			Meta::Path(path) if path.is_ident("methods") => {
 				return Err(syn::Error::new_spanned(
 					path,
@@ -80,6 +104,24 @@ pub(crate) fn api_view_impl(args: TokenStream, input: ItemFn) -> Result<TokenStr
 	let generics = &input.sig.generics;
 	let where_clause = &input.sig.generics.where_clause;
 
+	let extractors = detect_extractors(fn_inputs);
+	let inject_params = detect_inject_params(fn_inputs);
+	if !extractors.is_empty() || !inject_params.is_empty() {
+		validate_extractors(&extractors)?;
+		let http_crate = get_reinhardt_http_crate();
+		let (original_fn, wrapper_body) =
+			generate_wrapper_with_both(&input, &extractors, &inject_params, pre_validate);
+
+		return Ok(quote! {
+			#original_fn
+
+			#(#fn_attrs)*
+			#fn_vis #asyncness fn #fn_name(req: #http_crate::Request) #fn_output {
+				#wrapper_body
+			}
+		});
+	}
+
 	Ok(quote! {
 		#(#fn_attrs)*
 		#fn_vis #asyncness fn #fn_name #generics (#fn_inputs) #fn_output #where_clause {
@@ -87,3 +129,47 @@ pub(crate) fn api_view_impl(args: TokenStream, input: ItemFn) -> Result<TokenStr
 		}
 	})
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use syn::parse_quote;
+
+	#[rstest]
+	fn extractor_parameters_generate_request_handler() {
+		// Arrange
+		let input: ItemFn = parse_quote! {
+			async fn create(Json(body): Json<NewItem>, #[extract] tenant: Tenant) -> Result<Response> {
+				Ok(Response::ok())
+			}
+		};
+
+		// Act
+		let output = api_view_impl(quote! { methods = "POST" }, input)
+			.unwrap()
+			.to_string();
+
+		// Assert
+		assert!(output.contains("fn create_original"));
+		assert!(output.contains("FromRequest"));
+		assert!(output.contains("fn create (req :"));
+		assert!(!output.contains("# [extract]"));
+	}
+
+	#[rstest]
+	fn plain_parameters_keep_signature() {
+		// Arrange
+		let input: ItemFn = parse_quote! {
+			async fn list(req: Request) -> Result<Response> {
+				Ok(Response::ok())
+			}
+		};
+
+		// Act
+		let output = api_view_impl(quote! {}, input).unwrap().to_string();
+
+		// Assert
+		assert!(!output.contains("list_original"));
+	}
+}
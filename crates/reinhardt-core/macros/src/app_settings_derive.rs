@@ -0,0 +1,284 @@
+//! Derive macro for typed app settings
+//!
+//! Implements `AppSettings` and a redacting `Debug` from
+//! `#[app_settings(section = "...", env_prefix = "...", validate = path)]`
+//! on the struct and `#[app_setting(default [= expr], secret, validate = path)]`
+//! on its fields.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Expr, Fields, LitStr, Path, Type};
+
+use crate::crate_paths::get_reinhardt_conf_crate;
+
+#[derive(Default)]
+struct FieldOptions {
+	default: Option<Option<Expr>>,
+	secret: bool,
+	validate: Option<Path>,
+}
+
+fn parse_field_options(field: &syn::Field) -> syn::Result<FieldOptions> {
+	let mut options = FieldOptions::default();
+	for attr in field
+		.attrs
+		.iter()
+		.filter(|attr| attr.path().is_ident("app_setting"))
+	{
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("default") {
+				options.default = Some(if meta.input.peek(syn::Token![=]) {
+					Some(meta.value()?.parse()?)
+				} else {
+					None
+				});
+				Ok(())
+			} else if meta.path.is_ident("secret") {
+				options.secret = true;
+				Ok(())
+			} else if meta.path.is_ident("validate") {
+				options.validate = Some(meta.value()?.parse()?);
+				Ok(())
+			} else {
+				Err(meta
+					.error("expected `default`, `default = expr`, `secret`, or `validate = path`"))
+			}
+		})?;
+	}
+	Ok(options)
+}
+
+fn is_option(ty: &Type) -> bool {
+	matches!(
+		ty,
+		Type::Path(type_path)
+			if type_path.qself.is_none()
+				&& type_path.path.segments.last().is_some_and(|s| s.ident == "Option")
+	)
+}
+
+pub(crate) fn derive_app_settings_impl(input: DeriveInput) -> syn::Result<TokenStream> {
+	let name = &input.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+	let conf_crate = get_reinhardt_conf_crate();
+
+	let fields = match &input.data {
+		Data::Struct(data) => match &data.fields {
+			Fields::Named(fields) => &fields.named,
+			_ => {
+				return Err(syn::Error::new_spanned(
+					&input,
+					"#[derive(AppSettings)] requires named fields",
+				));
+			}
+		},
+		_ => {
+			return Err(syn::Error::new_spanned(
+				&input,
+				"#[derive(AppSettings)] can only be used on structs",
+			));
+		}
+	};
+
+	let mut section: Option<String> = None;
+	let mut env_prefix: Option<String> = None;
+	let mut struct_validate: Option<Path> = None;
+	for attr in input
+		.attrs
+		.iter()
+		.filter(|attr| attr.path().is_ident("app_settings"))
+	{
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("section") {
+				section = Some(meta.value()?.parse::<LitStr>()?.value());
+				Ok(())
+			} else if meta.path.is_ident("env_prefix") {
+				env_prefix = Some(meta.value()?.parse::<LitStr>()?.value());
+				Ok(())
+			} else if meta.path.is_ident("validate") {
+				struct_validate = Some(meta.value()?.parse()?);
+				Ok(())
+			} else {
+				Err(meta.error(
+					"expected `section = \"...\"`, `env_prefix = \"...\"`, or `validate = path`",
+				))
+			}
+		})?;
+	}
+	let Some(section) = section else {
+		return Err(syn::Error::new_spanned(
+			&input,
+			"#[derive(AppSettings)] requires #[app_settings(section = \"...\")]",
+		));
+	};
+	let env_prefix = env_prefix.unwrap_or_else(|| format!("{}_", section.to_uppercase()));
+
+	let app_settings = quote!(#conf_crate::settings::app_settings);
+	let mut field_names = Vec::new();
+	let mut secret_fields = Vec::new();
+	let mut initializers = Vec::new();
+	let mut validations = Vec::new();
+	let mut debug_fields = Vec::new();
+
+	for field in fields {
+		let ident = field.ident.as_ref().expect("named field");
+		let key = ident.to_string().trim_start_matches("r#").to_string();
+		let ty = &field.ty;
+		let options = parse_field_options(field)?;
+
+		let fallback = match &options.default {
+			Some(Some(expr)) => quote!(#expr),
+			Some(None) => quote!(::core::default::Default::default()),
+			None if is_option(ty) => quote!(::core::option::Option::None),
+			None => quote! {
+				return ::core::result::Result::Err(#app_settings::AppSettingsError::MissingField {
+					section: #section,
+					field: #key,
+				})
+			},
+		};
+		initializers.push(quote! {
+			#ident: match #app_settings::field_value::<#ty>(values, #section, #key)? {
+				::core::option::Option::Some(value) => value,
+				::core::option::Option::None => #fallback,
+			}
+		});
+
+		if let Some(validator) = &options.validate {
+			validations.push(quote! {
+				if let ::core::result::Result::Err(message) = #validator(&self.#ident) {
+					return ::core::result::Result::Err(#app_settings::AppSettingsError::Validation {
+						section: #section,
+						message: ::std::format!("{}: {}", #key, message),
+					});
+				}
+			});
+		}
+
+		debug_fields.push(if options.secret {
+			quote!(.field(#key, &#app_settings::REDACTED))
+		} else {
+			quote!(.field(#key, &self.#ident))
+		});
+		if options.secret {
+			secret_fields.push(key.clone());
+		}
+		field_names.push(key);
+	}
+
+	if let Some(validator) = &struct_validate {
+		validations.push(quote! {
+			if let ::core::result::Result::Err(message) = #validator(self) {
+				return ::core::result::Result::Err(#app_settings::AppSettingsError::Validation {
+					section: #section,
+					message: ::std::string::ToString::to_string(&message),
+				});
+			}
+		});
+	}
+
+	let name_str = name.to_string();
+	Ok(quote! {
+		impl #impl_generics #app_settings::AppSettings for #name #ty_generics #where_clause {
+			fn section() -> &'static str {
+				#section
+			}
+
+			fn env_prefix() -> &'static str {
+				#env_prefix
+			}
+
+			fn field_names() -> &'static [&'static str] {
+				&[#(#field_names),*]
+			}
+
+			fn secret_fields() -> &'static [&'static str] {
+				&[#(#secret_fields),*]
+			}
+
+			fn from_values(
+				values: &#conf_crate::serde_json::Map<::std::string::String, #conf_crate::serde_json::Value>,
+			) -> ::core::result::Result<Self, #app_settings::AppSettingsError> {
+				::core::result::Result::Ok(Self {
+					#(#initializers),*
+				})
+			}
+
+			fn validate(&self) -> ::core::result::Result<(), #app_settings::AppSettingsError> {
+				#(#validations)*
+				::core::result::Result::Ok(())
+			}
+		}
+
+		impl #impl_generics ::core::fmt::Debug for #name #ty_generics #where_clause {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+				f.debug_struct(#name_str)
+					#(#debug_fields)*
+					.finish()
+			}
+		}
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	fn requires_section() {
+		// Arrange
+		let input: DeriveInput = syn::parse_quote! {
+			struct PaymentsSettings {
+				api_key: String,
+			}
+		};
+
+		// Act
+		let result = derive_app_settings_impl(input);
+
+		// Assert
+		assert!(result.is_err());
+	}
+
+	#[rstest]
+	fn redacts_secret_fields_and_defaults_env_prefix() {
+		// Arrange
+		let input: DeriveInput = syn::parse_quote! {
+			#[app_settings(section = "payments")]
+			struct PaymentsSettings {
+				#[app_setting(secret)]
+				api_key: String,
+				#[app_setting(default = 3)]
+				max_retries: u32,
+			}
+		};
+
+		// Act
+		let output = derive_app_settings_impl(input).unwrap().to_string();
+
+		// Assert
+		assert!(output.contains("\"PAYMENTS_\""));
+		assert!(output.contains("app_settings :: REDACTED"));
+		assert!(!output.contains("self . api_key"));
+		assert!(output.contains("self . max_retries"));
+	}
+
+	#[rstest]
+	fn rejects_unknown_field_option() {
+		// Arrange
+		let input: DeriveInput = syn::parse_quote! {
+			#[app_settings(section = "payments")]
+			struct PaymentsSettings {
+				#[app_setting(hidden)]
+				api_key: String,
+			}
+		};
+
+		// Act
+		let result = derive_app_settings_impl(input);
+
+		// Assert
+		assert!(result.is_err());
+	}
+}
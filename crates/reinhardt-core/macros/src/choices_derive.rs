@@ -0,0 +1,201 @@
+//! Derive macro implementation for `Choices`
+
+use crate::crate_paths::get_reinhardt_core_crate;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, LitStr, Result, Variant};
+
+/// Value and label of one variant
+struct VariantConfig {
+	value: String,
+	label: String,
+}
+
+/// Convert a PascalCase identifier to snake_case (`InReview` → `in_review`)
+///
+/// Acronyms are kept together (`HTTPError` → `http_error`).
+fn to_snake_case(name: &str) -> String {
+	let chars: Vec<char> = name.chars().collect();
+	let mut result = String::new();
+	for (i, &ch) in chars.iter().enumerate() {
+		if ch.is_ascii_uppercase() && i > 0 {
+			let prev = chars[i - 1];
+			let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_ascii_lowercase());
+			if prev.is_ascii_lowercase()
+				|| prev.is_ascii_digit()
+				|| (prev.is_ascii_uppercase() && next_is_lower)
+			{
+				result.push('_');
+			}
+		}
+		result.push(ch.to_ascii_lowercase());
+	}
+	result
+}
+
+/// Convert a PascalCase identifier to a sentence (`InReview` → `In review`)
+fn to_label(name: &str) -> String {
+	let snake = to_snake_case(name);
+	let mut words = snake.split('_').filter(|word| !word.is_empty());
+	let mut label = String::new();
+	if let Some(first) = words.next() {
+		let mut chars = first.chars();
+		if let Some(c) = chars.next() {
+			label.push(c.to_ascii_uppercase());
+			label.extend(chars);
+		}
+	}
+	for word in words {
+		label.push(' ');
+		label.push_str(word);
+	}
+	label
+}
+
+/// Parse `#[choice(value = "...", label = "...")]` on a variant
+fn parse_variant_config(variant: &Variant) -> Result<VariantConfig> {
+	let name = variant.ident.to_string();
+	let mut config = VariantConfig {
+		value: to_snake_case(&name),
+		label: to_label(&name),
+	};
+
+	for attr in &variant.attrs {
+		if attr.path().is_ident("choice") {
+			attr.parse_nested_meta(|meta| {
+				if meta.path.is_ident("value") {
+					config.value = meta.value()?.parse::<LitStr>()?.value();
+					Ok(())
+				} else if meta.path.is_ident("label") {
+					config.label = meta.value()?.parse::<LitStr>()?.value();
+					Ok(())
+				} else {
+					Err(meta.error("expected `value = \"...\"` or `label = \"...\"`"))
+				}
+			})?;
+		}
+	}
+
+	Ok(config)
+}
+
+pub(crate) fn derive_choices_impl(input: DeriveInput) -> Result<TokenStream> {
+	let name = &input.ident;
+	let core_crate = get_reinhardt_core_crate();
+
+	let data_enum = match &input.data {
+		Data::Enum(data_enum) => data_enum,
+		_ => {
+			return Err(syn::Error::new_spanned(
+				name,
+				"#[derive(Choices)] can only be used on enums",
+			));
+		}
+	};
+	if !input.generics.params.is_empty() {
+		return Err(syn::Error::new_spanned(
+			&input.generics,
+			"#[derive(Choices)] does not support generic enums",
+		));
+	}
+
+	let mut variants = Vec::new();
+	let mut values = Vec::new();
+	let mut labels = Vec::new();
+	let mut seen = Vec::new();
+	for variant in &data_enum.variants {
+		if !matches!(variant.fields, Fields::Unit) {
+			return Err(syn::Error::new_spanned(
+				variant,
+				"#[derive(Choices)] variants cannot have fields",
+			));
+		}
+		let config = parse_variant_config(variant)?;
+		if seen.contains(&config.value) {
+			return Err(syn::Error::new_spanned(
+				variant,
+				format!("duplicate choice value `{}`", config.value),
+			));
+		}
+		seen.push(config.value.clone());
+		variants.push(&variant.ident);
+		values.push(config.value);
+		labels.push(config.label);
+	}
+
+	Ok(quote! {
+		impl #core_crate::choices::Choices for #name {
+			fn choices() -> &'static [Self] {
+				&[#(#name::#variants),*]
+			}
+
+			fn value(&self) -> &'static str {
+				match self {
+					#(#name::#variants => #values,)*
+				}
+			}
+
+			fn label(&self) -> &'static str {
+				match self {
+					#(#name::#variants => #labels,)*
+				}
+			}
+
+			fn from_value(value: &str) -> ::std::option::Option<Self> {
+				match value {
+					#(#values => ::std::option::Option::Some(#name::#variants),)*
+					_ => ::std::option::Option::None,
+				}
+			}
+		}
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case("Draft", "draft", "Draft")]
+	#[case("InReview", "in_review", "In review")]
+	#[case("HTTPError", "http_error", "Http error")]
+	fn default_value_and_label(#[case] name: &str, #[case] value: &str, #[case] label: &str) {
+		assert_eq!(to_snake_case(name), value);
+		assert_eq!(to_label(name), label);
+	}
+
+	#[rstest]
+	fn rejects_variant_with_fields() {
+		// Arrange
+		let input: DeriveInput = syn::parse_quote! {
+			enum Status {
+				Draft(u8),
+			}
+		};
+
+		// Act
+		let result = derive_choices_impl(input);
+
+		// Assert
+		assert!(result.is_err());
+	}
+
+	#[rstest]
+	fn rejects_duplicate_values() {
+		// Arrange
+		let input: DeriveInput = syn::parse_quote! {
+			enum Status {
+				Draft,
+				#[choice(value = "draft")]
+				Other,
+			}
+		};
+
+		// Act
+		let result = derive_choices_impl(input);
+
+		// Assert
+		assert!(result.is_err());
+	}
+}
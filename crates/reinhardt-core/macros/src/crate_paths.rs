@@ -905,3 +905,20 @@ pub(crate) fn get_hyper_crate() -> TokenStream {
 	// Final fallback
 	quote!(::hyper)
 }
+
+/// Resolves the path to the `reinhardt_search` crate dynamically.
+///
+/// The facade does not re-export the search crate, so only a direct
+/// dependency is looked up.
+pub(crate) fn get_reinhardt_search_crate() -> TokenStream {
+	use proc_macro_crate::{FoundCrate, crate_name};
+
+	match crate_name("reinhardt-search") {
+		Ok(FoundCrate::Itself) => quote!(crate),
+		Ok(FoundCrate::Name(name)) => {
+			let ident = syn::Ident::new(&name, proc_macro2::Span::call_site());
+			quote!(::#ident)
+		}
+		Err(_) => quote!(::reinhardt_search),
+	}
+}
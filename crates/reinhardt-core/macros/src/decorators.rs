@@ -0,0 +1,656 @@
+//! View decorator attribute macros
+//!
+//! `#[require_http_methods]`, `#[login_required]`, `#[cache_control]` and
+//! `#[deprecation]` rewrite the handler body to call the runtime helpers in
+//! `reinhardt_http::decorators`. They compose with each other and with the
+//! route macros; the outermost attribute runs first.
+
+use crate::crate_paths::{get_inventory_crate, get_reinhardt_core_crate, get_reinhardt_http_crate};
+use crate::validate_request::find_request_param;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+	Attribute, Error, Expr, ExprLit, Ident, ItemFn, Lit, LitStr, Meta, Result, ReturnType, Token,
+	parse::Parser, punctuated::Punctuated,
+};
+
+/// Names of the decorator attributes
+const DECORATORS: [&str; 4] = [
+	"require_http_methods",
+	"login_required",
+	"cache_control",
+	"deprecation",
+];
+
+/// HTTP methods accepted by `#[require_http_methods]`
+const HTTP_METHODS: [&str; 9] = [
+	"GET", "HEAD", "POST", "PUT", "PATCH", "DELETE", "OPTIONS", "TRACE", "CONNECT",
+];
+
+/// Boolean `Cache-Control` directives
+const CACHE_CONTROL_FLAGS: [&str; 8] = [
+	"public",
+	"private",
+	"no_cache",
+	"no_store",
+	"no_transform",
+	"must_revalidate",
+	"proxy_revalidate",
+	"immutable",
+];
+
+/// `Cache-Control` directives taking a number of seconds
+const CACHE_CONTROL_VALUES: [&str; 4] = [
+	"max_age",
+	"s_maxage",
+	"stale_while_revalidate",
+	"stale_if_error",
+];
+
+/// Find the `Request` parameter the decorator reads from
+fn request_param(input: &ItemFn, decorator: &str) -> Result<Ident> {
+	if input.sig.asyncness.is_none() {
+		return Err(Error::new_spanned(
+			&input.sig,
+			format!("#[{}] can only be applied to async functions", decorator),
+		));
+	}
+	find_request_param(input).cloned().ok_or_else(|| {
+		Error::new_spanned(
+			&input.sig,
+			format!(
+				"#[{}] requires a Request parameter. Add a `req: Request` parameter to this function.",
+				decorator
+			),
+		)
+	})
+}
+
+/// Run `check` before the handler body, returning its response if it yields one
+fn prepend_check(mut input: ItemFn, check: TokenStream) -> Result<TokenStream> {
+	let http_crate = get_reinhardt_http_crate();
+	let fn_block = &input.block;
+	let block = quote! {
+		{
+			if let Some(__decorator_response) = #check {
+				return #http_crate::decorators::ViewResponse::from_response(__decorator_response);
+			}
+			#fn_block
+		}
+	};
+	input.block = Box::new(syn::parse2(block)?);
+	Ok(quote! { #input })
+}
+
+/// Parse a `key = "value"` option of a decorator
+fn string_option(nv: &syn::MetaNameValue, decorator: &str) -> Result<LitStr> {
+	match &nv.value {
+		Expr::Lit(ExprLit {
+			lit: Lit::Str(lit), ..
+		}) => Ok(lit.clone()),
+		other => Err(Error::new_spanned(
+			other,
+			format!("{} options must be string literals", decorator),
+		)),
+	}
+}
+
+/// Implementation of the `require_http_methods` attribute macro
+///
+/// Returns `405 Method Not Allowed` with an `Allow` header for requests
+/// whose method is not listed. Methods are validated at compile time.
+pub(crate) fn require_http_methods_impl(args: TokenStream, input: ItemFn) -> Result<TokenStream> {
+	let methods = Punctuated::<LitStr, Token![,]>::parse_terminated.parse2(args)?;
+	if methods.is_empty() {
+		return Err(Error::new(
+			Span::call_site(),
+			"require_http_methods requires at least one method, e.g. #[require_http_methods(\"GET\", \"POST\")]",
+		));
+	}
+	let mut normalized = Vec::new();
+	for method in &methods {
+		let value = method.value().to_ascii_uppercase();
+		if !HTTP_METHODS.contains(&value.as_str()) {
+			return Err(Error::new(
+				method.span(),
+				format!(
+					"unknown HTTP method '{}', expected one of {}",
+					method.value(),
+					HTTP_METHODS.join(", ")
+				),
+			));
+		}
+		if normalized.contains(&value) {
+			return Err(Error::new(method.span(), "duplicate HTTP method"));
+		}
+		normalized.push(value);
+	}
+
+	let request = request_param(&input, "require_http_methods")?;
+	let http_crate = get_reinhardt_http_crate();
+	prepend_check(
+		input,
+		quote! {
+			#http_crate::decorators::require_http_methods(&#request, &[#(#normalized),*])
+		},
+	)
+}
+
+/// Implementation of the `login_required` attribute macro
+///
+/// Redirects requests without an authenticated `AuthState` to the login
+/// page, passing the original URL in the `next` query parameter.
+pub(crate) fn login_required_impl(args: TokenStream, input: ItemFn) -> Result<TokenStream> {
+	let meta_list = Punctuated::<Meta, Token![,]>::parse_terminated.parse2(args)?;
+
+	let mut login_url = None;
+	let mut redirect_field_name = None;
+	for meta in meta_list {
+		let Meta::NameValue(nv) = &meta else {
+			return Err(Error::new_spanned(
+				&meta,
+				"expected `key = \"value\"` in login_required macro",
+			));
+		};
+		let slot = if nv.path.is_ident("login_url") {
+			&mut login_url
+		} else if nv.path.is_ident("redirect_field_name") {
+			&mut redirect_field_name
+		} else {
+			return Err(Error::new_spanned(
+				&nv.path,
+				"unknown login_required option, expected `login_url` or `redirect_field_name`",
+			));
+		};
+		if slot.is_some() {
+			return Err(Error::new_spanned(
+				&nv.path,
+				"duplicate login_required option",
+			));
+		}
+		*slot = Some(string_option(nv, "login_required")?);
+	}
+
+	let request = request_param(&input, "login_required")?;
+	let http_crate = get_reinhardt_http_crate();
+	let login_url = match login_url {
+		Some(lit) => quote! { #lit },
+		None => quote! { #http_crate::redirect::DEFAULT_LOGIN_URL },
+	};
+	let redirect_field_name = match redirect_field_name {
+		Some(lit) => quote! { #lit },
+		None => quote! { #http_crate::redirect::REDIRECT_FIELD_NAME },
+	};
+	prepend_check(
+		input,
+		quote! {
+			#http_crate::decorators::login_required(&#request, #login_url, #redirect_field_name)
+		},
+	)
+}
+
+/// Implementation of the `cache_control` attribute macro
+///
+/// Merges the declared directives into the `Cache-Control` header of the
+/// handler's response. Error results are passed through untouched.
+pub(crate) fn cache_control_impl(args: TokenStream, mut input: ItemFn) -> Result<TokenStream> {
+	let meta_list = Punctuated::<Meta, Token![,]>::parse_terminated.parse2(args)?;
+	if meta_list.is_empty() {
+		return Err(Error::new(
+			Span::call_site(),
+			"cache_control requires at least one directive, e.g. #[cache_control(max_age = 300, public)]",
+		));
+	}
+
+	let mut seen: Vec<String> = Vec::new();
+	let mut fields = Vec::new();
+	for meta in &meta_list {
+		let (path, value) = match meta {
+			Meta::Path(path) => (path, None),
+			Meta::NameValue(nv) => (&nv.path, Some(&nv.value)),
+			Meta::List(_) => {
+				return Err(Error::new_spanned(
+					meta,
+					"expected a directive such as `public` or `max_age = 300` in cache_control macro",
+				));
+			}
+		};
+		let name = path.get_ident().map(Ident::to_string).unwrap_or_default();
+		if seen.contains(&name) {
+			return Err(Error::new_spanned(
+				path,
+				"duplicate cache_control directive",
+			));
+		}
+
+		match value {
+			None if CACHE_CONTROL_FLAGS.contains(&name.as_str()) => {
+				let field = Ident::new(&name, Span::call_site());
+				fields.push(quote! { #field: true });
+			}
+			Some(Expr::Lit(ExprLit {
+				lit: Lit::Int(seconds),
+				..
+			})) if CACHE_CONTROL_VALUES.contains(&name.as_str()) => {
+				let field = Ident::new(&name, Span::call_site());
+				let seconds = seconds.base10_parse::<u64>()?;
+				fields.push(quote! { #field: Some(#seconds) });
+			}
+			_ if CACHE_CONTROL_VALUES.contains(&name.as_str()) => {
+				return Err(Error::new_spanned(
+					meta,
+					format!(
+						"`{}` must be a number of seconds, e.g. `{} = 300`",
+						name, name
+					),
+				));
+			}
+			_ if CACHE_CONTROL_FLAGS.contains(&name.as_str()) => {
+				return Err(Error::new_spanned(
+					meta,
+					format!("`{}` is a flag and takes no value", name),
+				));
+			}
+			_ => {
+				return Err(Error::new_spanned(
+					path,
+					format!(
+						"unknown cache_control directive, expected one of {}, {}",
+						CACHE_CONTROL_FLAGS.join(", "),
+						CACHE_CONTROL_VALUES.join(", ")
+					),
+				));
+			}
+		}
+		seen.push(name);
+	}
+	if seen.iter().any(|name| name == "public") && seen.iter().any(|name| name == "private") {
+		return Err(Error::new(
+			Span::call_site(),
+			"cache_control directives `public` and `private` are mutually exclusive",
+		));
+	}
+
+	if input.sig.asyncness.is_none() {
+		return Err(Error::new_spanned(
+			&input.sig,
+			"#[cache_control] can only be applied to async functions",
+		));
+	}
+	let ReturnType::Type(_, output) = &input.sig.output else {
+		return Err(Error::new_spanned(
+			&input.sig,
+			"#[cache_control] requires a handler returning `Response` or `Result<Response, E>`",
+		));
+	};
+
+	let http_crate = get_reinhardt_http_crate();
+	let fn_block = &input.block;
+	let block = quote! {
+		{
+			let __cache_control = #http_crate::decorators::CacheControl {
+				#(#fields,)*
+				..::core::default::Default::default()
+			};
+			// `return` in the handler body leaves this block, not the handler,
+			// so the directives also apply to early returns.
+			let __decorated_response: #output = async move #fn_block.await;
+			#http_crate::decorators::ViewResponse::map_response(__decorated_response, |response| {
+				#http_crate::decorators::patch_cache_control(response, &__cache_control)
+			})
+		}
+	};
+	input.block = Box::new(syn::parse2(block)?);
+	Ok(quote! { #input })
+}
+
+/// Parse a `YYYY-MM-DD` date option of `#[deprecation]`
+fn deprecation_date(lit: &LitStr) -> Result<chrono::NaiveDate> {
+	chrono::NaiveDate::parse_from_str(&lit.value(), "%Y-%m-%d").map_err(|_| {
+		Error::new(
+			lit.span(),
+			format!("invalid date '{}', expected YYYY-MM-DD", lit.value()),
+		)
+	})
+}
+
+/// Implementation of the `deprecation` attribute macro
+///
+/// Adds the `Deprecation`, `Sunset` and successor `Link` headers to the
+/// handler's response, counts each call, and registers a
+/// `DeprecationMetadata` entry so the OpenAPI operation is marked
+/// deprecated. Dates are validated at compile time.
+pub(crate) fn deprecation_impl(args: TokenStream, mut input: ItemFn) -> Result<TokenStream> {
+	let meta_list = Punctuated::<Meta, Token![,]>::parse_terminated.parse2(args)?;
+
+	let mut since = None;
+	let mut sunset = None;
+	let mut link = None;
+	let mut log = false;
+	for meta in &meta_list {
+		if meta.path().is_ident("log") {
+			if !matches!(meta, Meta::Path(_)) {
+				return Err(Error::new_spanned(
+					meta,
+					"`log` is a flag and takes no value",
+				));
+			}
+			if log {
+				return Err(Error::new_spanned(meta, "duplicate deprecation option"));
+			}
+			log = true;
+			continue;
+		}
+		let Meta::NameValue(nv) = meta else {
+			return Err(Error::new_spanned(
+				meta,
+				"expected `key = \"value\"` in deprecation macro",
+			));
+		};
+		let slot = if nv.path.is_ident("since") {
+			&mut since
+		} else if nv.path.is_ident("sunset") {
+			&mut sunset
+		} else if nv.path.is_ident("link") {
+			&mut link
+		} else {
+			return Err(Error::new_spanned(
+				&nv.path,
+				"unknown deprecation option, expected `since`, `sunset`, `link` or `log`",
+			));
+		};
+		if slot.is_some() {
+			return Err(Error::new_spanned(&nv.path, "duplicate deprecation option"));
+		}
+		*slot = Some(string_option(nv, "deprecation")?);
+	}
+
+	let since = since.ok_or_else(|| {
+		Error::new(
+			Span::call_site(),
+			"deprecation requires `since`, e.g. #[deprecation(since = \"2024-01-01\")]",
+		)
+	})?;
+	let since_date = deprecation_date(&since)?;
+	if let Some(sunset) = &sunset
+		&& deprecation_date(sunset)? < since_date
+	{
+		return Err(Error::new(
+			sunset.span(),
+			"deprecation `sunset` must not be earlier than `since`",
+		));
+	}
+
+	if input.sig.asyncness.is_none() {
+		return Err(Error::new_spanned(
+			&input.sig,
+			"#[deprecation] can only be applied to async functions",
+		));
+	}
+	let ReturnType::Type(_, output) = &input.sig.output else {
+		return Err(Error::new_spanned(
+			&input.sig,
+			"#[deprecation] requires a handler returning `Response` or `Result<Response, E>`",
+		));
+	};
+
+	let http_crate = get_reinhardt_http_crate();
+	let core_crate = get_reinhardt_core_crate();
+	let inventory_crate = get_inventory_crate();
+	let fn_name = &input.sig.ident;
+	let sunset = match sunset {
+		Some(lit) => quote! { Some(#lit) },
+		None => quote! { None },
+	};
+	let link = match link {
+		Some(lit) => quote! { Some(#lit) },
+		None => quote! { None },
+	};
+	let request = match find_request_param(&input) {
+		Some(request) => quote! { Some(&#request) },
+		None => quote! { None },
+	};
+	let fn_block = &input.block;
+	let block = quote! {
+		{
+			#inventory_crate::submit! {
+				#core_crate::endpoint::DeprecationMetadata {
+					since: #since,
+					sunset: #sunset,
+					link: #link,
+					function_name: stringify!(#fn_name),
+					module_path: module_path!(),
+				}
+			}
+			let __deprecation = #http_crate::decorators::Deprecation {
+				since: #since,
+				sunset: #sunset,
+				link: #link,
+				endpoint: concat!(module_path!(), "::", stringify!(#fn_name)),
+				log: #log,
+			};
+			#http_crate::decorators::record_deprecated_call(&__deprecation, #request);
+			let __decorated_response: #output = async move #fn_block.await;
+			#http_crate::decorators::ViewResponse::map_response(__decorated_response, |response| {
+				#http_crate::decorators::patch_deprecation(response, &__deprecation)
+			})
+		}
+	};
+	input.block = Box::new(syn::parse2(block)?);
+	Ok(quote! { #input })
+}
+
+/// Whether `attr` is one of the decorator attributes
+fn decorator_name(attr: &Attribute) -> Option<&'static str> {
+	let ident = &attr.path().segments.last()?.ident;
+	DECORATORS.into_iter().find(|name| ident == name)
+}
+
+/// Expand decorator attributes placed below a route macro
+///
+/// Route macros rename the handler they wrap, so the decorators are applied
+/// to the handler here instead of being left on the renamed function.
+/// Attributes are expanded innermost first, preserving their written order.
+pub(crate) fn expand_decorator_attrs(mut input: ItemFn) -> Result<ItemFn> {
+	while let Some(index) = input
+		.attrs
+		.iter()
+		.rposition(|attr| decorator_name(attr).is_some())
+	{
+		let attr = input.attrs.remove(index);
+		let args = match &attr.meta {
+			Meta::Path(_) => TokenStream::new(),
+			Meta::List(list) => list.tokens.clone(),
+			Meta::NameValue(_) => {
+				return Err(Error::new_spanned(
+					&attr.meta,
+					"expected decorator arguments in parentheses",
+				));
+			}
+		};
+		let expanded = match decorator_name(&attr) {
+			Some("require_http_methods") => require_http_methods_impl(args, input)?,
+			Some("login_required") => login_required_impl(args, input)?,
+			Some("deprecation") => deprecation_impl(args, input)?,
+			_ => cache_control_impl(args, input)?,
+		};
+		input = syn::parse2(expanded)?;
+	}
+	Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use syn::parse_quote;
+
+	fn handler() -> ItemFn {
+		parse_quote! {
+			pub async fn articles(req: Request) -> ViewResult<Response> {
+				Ok(Response::ok())
+			}
+		}
+	}
+
+	#[rstest]
+	fn test_require_http_methods_checks_request() {
+		// Act
+		let output = require_http_methods_impl(quote! { "get", "POST" }, handler())
+			.unwrap()
+			.to_string();
+
+		// Assert
+		assert!(
+			output.contains("decorators :: require_http_methods (& req , & [\"GET\" , \"POST\"])")
+		);
+		assert!(output.contains("ViewResponse :: from_response"));
+	}
+
+	#[rstest]
+	fn test_login_required_uses_defaults() {
+		// Act
+		let output = login_required_impl(quote! {}, handler())
+			.unwrap()
+			.to_string();
+
+		// Assert
+		assert!(output.contains("decorators :: login_required (& req"));
+		assert!(output.contains("redirect :: DEFAULT_LOGIN_URL"));
+		assert!(output.contains("redirect :: REDIRECT_FIELD_NAME"));
+	}
+
+	#[rstest]
+	fn test_login_required_custom_login_url() {
+		// Act
+		let output = login_required_impl(quote! { login_url = "/login" }, handler())
+			.unwrap()
+			.to_string();
+
+		// Assert
+		assert!(output.contains("(& req , \"/login\" ,"));
+	}
+
+	#[rstest]
+	fn test_cache_control_patches_response() {
+		// Act
+		let output = cache_control_impl(quote! { max_age = 300, public }, handler())
+			.unwrap()
+			.to_string();
+
+		// Assert
+		assert!(output.contains("max_age : Some (300u64)"));
+		assert!(output.contains("public : true"));
+		assert!(output.contains("let __decorated_response : ViewResult < Response > = async move"));
+		assert!(output.contains("patch_cache_control"));
+	}
+
+	#[rstest]
+	fn test_deprecation_registers_and_patches_response() {
+		// Act
+		let output = deprecation_impl(
+			quote! { since = "2024-01-01", sunset = "2024-12-31", link = "/api/v2/articles/", log },
+			handler(),
+		)
+		.unwrap()
+		.to_string();
+
+		// Assert
+		assert!(output.contains("endpoint :: DeprecationMetadata"));
+		assert!(output.contains("function_name : stringify ! (articles)"));
+		assert!(output.contains("sunset : Some (\"2024-12-31\")"));
+		assert!(output.contains("log : true"));
+		assert!(output.contains("record_deprecated_call (& __deprecation , Some (& req))"));
+		assert!(output.contains("patch_deprecation"));
+	}
+
+	#[rstest]
+	#[case::missing_since(quote! { sunset = "2024-12-31" }, "requires `since`")]
+	#[case::invalid_date(quote! { since = "01/01/2024" }, "expected YYYY-MM-DD")]
+	#[case::sunset_before_since(
+		quote! { since = "2024-06-01", sunset = "2024-01-01" },
+		"must not be earlier"
+	)]
+	#[case::unknown_option(quote! { since = "2024-01-01", until = "2025-01-01" }, "unknown deprecation option")]
+	#[case::log_with_value(quote! { since = "2024-01-01", log = "yes" }, "takes no value")]
+	fn test_deprecation_rejects_invalid_usage(#[case] args: TokenStream, #[case] message: &str) {
+		// Act
+		let err = deprecation_impl(args, handler()).unwrap_err();
+
+		// Assert
+		assert!(err.to_string().contains(message), "{}", err);
+	}
+
+	#[rstest]
+	fn test_expand_decorator_attrs_consumes_attributes() {
+		// Arrange
+		let input: ItemFn = parse_quote! {
+			#[login_required]
+			#[cache_control(no_cache)]
+			#[doc = "Articles"]
+			pub async fn articles(req: Request) -> ViewResult<Response> {
+				Ok(Response::ok())
+			}
+		};
+
+		// Act
+		let output = expand_decorator_attrs(input).unwrap();
+
+		// Assert
+		assert_eq!(output.attrs.len(), 1);
+		let body = quote! { #output }.to_string();
+		// The login check runs before the cache-control wrapper.
+		let login = body.find("decorators :: login_required").unwrap();
+		let cache = body.find("CacheControl").unwrap();
+		assert!(login < cache);
+	}
+
+	#[rstest]
+	#[case::unknown_method(
+		quote! { "FETCH" },
+		"unknown HTTP method"
+	)]
+	#[case::duplicate_method(
+		quote! { "GET", "get" },
+		"duplicate HTTP method"
+	)]
+	#[case::no_methods(quote! {}, "at least one method")]
+	fn test_require_http_methods_rejects_invalid_usage(
+		#[case] args: TokenStream,
+		#[case] message: &str,
+	) {
+		// Act
+		let err = require_http_methods_impl(args, handler()).unwrap_err();
+
+		// Assert
+		assert!(err.to_string().contains(message), "{}", err);
+	}
+
+	#[rstest]
+	#[case::public_and_private(quote! { public, private }, "mutually exclusive")]
+	#[case::unknown_directive(quote! { max_stale = 10 }, "unknown cache_control directive")]
+	#[case::flag_with_value(quote! { public = true }, "takes no value")]
+	#[case::value_without_seconds(quote! { max_age }, "number of seconds")]
+	#[case::empty(quote! {}, "at least one directive")]
+	fn test_cache_control_rejects_invalid_usage(#[case] args: TokenStream, #[case] message: &str) {
+		// Act
+		let err = cache_control_impl(args, handler()).unwrap_err();
+
+		// Assert
+		assert!(err.to_string().contains(message), "{}", err);
+	}
+
+	#[rstest]
+	fn test_login_required_requires_request_param() {
+		// Arrange
+		let input: ItemFn = parse_quote! {
+			async fn articles(id: i64) -> ViewResult<Response> { todo!() }
+		};
+
+		// Act
+		let err = login_required_impl(quote! {}, input).unwrap_err();
+
+		// Assert
+		assert!(err.to_string().contains("requires a Request parameter"));
+	}
+}
@@ -9,6 +9,7 @@
 //! - `#[action]` - Define custom ViewSet action
 //! - `#[get]`, `#[post]`, etc. - HTTP method decorators
 //! - `#[permission_required]` - Permission decorator
+//! - `#[require_http_methods]`, `#[login_required]`, `#[cache_control]`, `#[deprecation]` - View decorators
 //!
 
 #![warn(missing_docs)]
@@ -21,10 +22,13 @@ mod admin;
 mod api_view;
 mod app_config_attribute;
 mod app_config_derive;
+mod app_settings_derive;
 mod apply_update_attribute;
 mod apply_update_derive;
+mod choices_derive;
 mod collect_migrations;
 mod crate_paths;
+mod decorators;
 mod dto;
 mod flatten_imports;
 mod hook;
@@ -48,16 +52,19 @@ mod rel;
 mod routes;
 mod routes_registration;
 mod schema;
+mod searchable_derive;
 mod settings_compose;
 mod settings_fragment;
 pub(crate) mod settings_parser;
 mod settings_schema;
 mod streaming;
 mod streaming_patterns;
+mod throttle;
 mod use_inject;
 mod user_attribute;
 mod user_field_mapping;
 mod validate_derive;
+mod validate_request;
 
 use action::action_impl;
 use admin::admin_impl;
@@ -65,6 +72,7 @@ use api_view::api_view_impl;
 use app_config_attribute::app_config_attribute_impl;
 use apply_update_attribute::apply_update_attribute_impl;
 use apply_update_derive::apply_update_derive_impl;
+use choices_derive::derive_choices_impl;
 use http_error_derive::derive_http_error_impl;
 use injectable_fn::injectable_fn_impl;
 use injectable_struct::injectable_struct_impl;
@@ -76,7 +84,7 @@ use path_macro::path_impl;
 use permissions::permission_required_impl;
 use query_fields::derive_query_fields_impl;
 use receiver::receiver_impl;
-use routes::{delete_impl, get_impl, patch_impl, post_impl, put_impl};
+use routes::{csrf_exempt_impl, delete_impl, get_impl, patch_impl, post_impl, put_impl};
 use routes_registration::routes_impl;
 mod viewset_macro;
 mod websocket;
@@ -85,6 +93,24 @@ use use_inject::use_inject_impl;
 use user_attribute::user_attribute_impl;
 
 /// Decorator for function-based API views
+///
+/// Parameters typed as extractors (`Json<T>`, `Query<T>`, `Path<T>`,
+/// `CurrentUser<U>`, session values) are extracted from the request before
+/// the body runs, as with the route macros. Any other type implementing
+/// `FromRequest` can be used by marking the parameter `#[extract]`.
+/// Extraction failures return 400, or 401 when authentication is missing.
+/// With `pre_validate = true`, extracted values are also validated.
+///
+/// ```rust,ignore
+/// #[api_view(methods = "POST", pre_validate = true)]
+/// async fn create_article(
+///     CurrentUser(user): CurrentUser<User>,
+///     Json(body): Json<NewArticle>,
+///     #[extract] tenant: Tenant,
+/// ) -> ViewResult<Response> {
+///     // ...
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn api_view(args: TokenStream, input: TokenStream) -> TokenStream {
 	let input = parse_macro_input!(input as ItemFn);
@@ -167,6 +193,215 @@ pub fn delete(args: TokenStream, input: TokenStream) -> TokenStream {
 		.into()
 }
 
+/// Exempt a route handler from CSRF validation.
+///
+/// Combine with a route macro; the attribute may sit above or below it and is
+/// equivalent to passing `csrf_exempt = true` to the route macro. The router
+/// marks requests to exempt handlers, and `CsrfMiddleware` installed on the
+/// router skips validation for them. Use this for endpoints authenticated by
+/// other means, such as signed webhooks.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[post("/hooks/stripe/", name = "stripe-webhook")]
+/// #[csrf_exempt]
+/// pub async fn stripe_webhook(req: Request) -> ViewResult<Response> {
+///     // verify the webhook signature instead
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn csrf_exempt(args: TokenStream, input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as ItemFn);
+
+	csrf_exempt_impl(args.into(), input)
+		.unwrap_or_else(|e| e.to_compile_error())
+		.into()
+}
+
+/// Assign a handler to a throttle scope.
+///
+/// Registers the scope and its rates so that
+/// `reinhardt_rest::throttling::registered_scoped_throttle()` can build a
+/// `ScopedRateThrottle` for every declared scope, and documents the limit as
+/// an `x-throttle` extension on the OpenAPI operation. Rates use the
+/// `"<count>/<sec|min|hour|day>"` notation and are validated at compile time.
+///
+/// # Arguments
+///
+/// - `scope` — throttle scope name; handlers sharing a scope share its limit
+/// - `rate` — default rate for every caller
+/// - `anon_rate` — optional override for anonymous callers
+/// - `user_rate` — optional override for authenticated callers
+///
+/// Works on route handlers (above or below the route macro), `#[api_view]`
+/// functions and viewset `#[action]` methods.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[get("/search/", name = "search")]
+/// #[throttle(scope = "burst", rate = "10/min", anon_rate = "3/min")]
+/// pub async fn search(req: Request) -> ViewResult<Response> {
+///     todo!()
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn throttle(args: TokenStream, input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as ItemFn);
+	throttle::throttle_impl(args.into(), input)
+		.unwrap_or_else(|e| e.to_compile_error())
+		.into()
+}
+
+/// Deserialize and validate the query string and JSON body before a handler runs.
+///
+/// The handler must be `async` and take a `Request` parameter. Each declared
+/// type is extracted from the request and checked with its `Validate`
+/// implementation (usually `#[derive(Validate)]`), then bound as `query` or
+/// `body` for the handler body. Validation failures return
+/// `Error::FieldValidation`, which renders as a `422` response whose `errors`
+/// object maps each field to its messages; malformed input is rejected with
+/// the usual `400` parameter error.
+///
+/// The declared types are also recorded for OpenAPI generation: the body
+/// type becomes the request body schema and the fields of the query type
+/// become query parameters.
+///
+/// # Arguments
+///
+/// - `query` — type deserialized from the query string
+/// - `body` — type deserialized from the JSON request body
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[post("/posts/", name = "create-post")]
+/// #[validate_request(query = ListParams, body = CreatePost)]
+/// pub async fn create_post(req: Request) -> ViewResult<Response> {
+///     let post = Post::create(&body.title, query.draft).await?;
+///     Response::ok().with_json(&post)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn validate_request(args: TokenStream, input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as ItemFn);
+	validate_request::validate_request_impl(args.into(), input)
+		.unwrap_or_else(|e| e.to_compile_error())
+		.into()
+}
+
+/// Restrict a handler to the listed HTTP methods.
+///
+/// Other methods get a `405 Method Not Allowed` response whose `Allow`
+/// header lists the accepted methods. Method names are checked at compile
+/// time. The handler must be `async` and take a `Request` parameter.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[require_http_methods("GET", "POST")]
+/// pub async fn comments(req: Request) -> ViewResult<Response> {
+///     todo!()
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn require_http_methods(args: TokenStream, input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as ItemFn);
+	decorators::require_http_methods_impl(args.into(), input)
+		.unwrap_or_else(|e| e.to_compile_error())
+		.into()
+}
+
+/// Redirect anonymous users to the login page.
+///
+/// Requests without an authenticated `AuthState` are redirected to
+/// `login_url` (default `/accounts/login/`) with the original path and query
+/// in the `next` query parameter. The handler must be `async` and take a
+/// `Request` parameter.
+///
+/// # Arguments
+///
+/// - `login_url` — optional login page URL
+/// - `redirect_field_name` — optional query parameter name (default `next`)
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[get("/account/", name = "account")]
+/// #[login_required(login_url = "/login")]
+/// pub async fn account(req: Request) -> ViewResult<Response> {
+///     todo!()
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn login_required(args: TokenStream, input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as ItemFn);
+	decorators::login_required_impl(args.into(), input)
+		.unwrap_or_else(|e| e.to_compile_error())
+		.into()
+}
+
+/// Add `Cache-Control` directives to the handler's response.
+///
+/// Flags (`public`, `private`, `no_cache`, `no_store`, `no_transform`,
+/// `must_revalidate`, `proxy_revalidate`, `immutable`) are written bare;
+/// `max_age`, `s_maxage`, `stale_while_revalidate` and `stale_if_error` take
+/// a number of seconds. Directives already set by the handler are kept
+/// unless overridden, and an existing `max-age` is only lowered. The handler
+/// must be `async` and return `Response` or `Result<Response, E>`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[get("/articles/", name = "articles")]
+/// #[cache_control(max_age = 300, public)]
+/// pub async fn articles(req: Request) -> ViewResult<Response> {
+///     todo!()
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn cache_control(args: TokenStream, input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as ItemFn);
+	decorators::cache_control_impl(args.into(), input)
+		.unwrap_or_else(|e| e.to_compile_error())
+		.into()
+}
+
+/// Mark the handler as deprecated.
+///
+/// Responses get a `Deprecation` header with the `since` date, a `Sunset`
+/// header with the optional `sunset` date, and a `Link` to the optional
+/// replacement with `rel="successor-version"`. The OpenAPI operation is
+/// marked deprecated, and calls are counted in
+/// `reinhardt_http::decorators::deprecated_call_counts`. With `log`, each
+/// call is also logged as a warning. The handler must be `async` and return
+/// `Response` or `Result<Response, E>`.
+///
+/// # Arguments
+///
+/// - `since` — deprecation date, `YYYY-MM-DD`
+/// - `sunset` — optional removal date, `YYYY-MM-DD`
+/// - `link` — optional URL of the replacement endpoint
+/// - `log` — optional flag logging every call
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[get("/v1/articles/", name = "articles_v1")]
+/// #[deprecation(since = "2024-01-01", sunset = "2024-12-31", link = "/v2/articles/", log)]
+/// pub async fn articles_v1(req: Request) -> ViewResult<Response> {
+///     todo!()
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn deprecation(args: TokenStream, input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as ItemFn);
+	decorators::deprecation_impl(args.into(), input)
+		.unwrap_or_else(|e| e.to_compile_error())
+		.into()
+}
+
 /// Producer handler decorator — auto-publishes return value to a Kafka topic.
 ///
 /// # Arguments
@@ -607,6 +842,34 @@ pub fn derive_schema(input: TokenStream) -> TokenStream {
 		.into()
 }
 
+/// Derive macro implementing `Choices` for fieldless enums
+///
+/// Each variant maps to a machine value and a human-readable label. The value
+/// defaults to the snake_case variant name and the label to the variant name
+/// split into words.
+///
+/// # Variant Attributes
+///
+/// - `#[choice(value = "...")]`: Machine value stored and exchanged
+/// - `#[choice(label = "...")]`: Label shown to users (translated at display time)
+///
+/// ```ignore
+/// #[derive(Debug, Clone, Copy, Choices)]
+/// enum Status {
+///     Draft,
+///     #[choice(value = "review", label = "Under review")]
+///     InReview,
+/// }
+/// ```
+#[proc_macro_derive(Choices, attributes(choice))]
+pub fn derive_choices(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as syn::DeriveInput);
+
+	derive_choices_impl(input)
+		.unwrap_or_else(|e| e.to_compile_error())
+		.into()
+}
+
 /// Implements HTTP status and client-message mapping for application error enums.
 #[proc_macro_derive(HttpError, attributes(http_error))]
 pub fn derive_http_error(input: TokenStream) -> TokenStream {
@@ -725,6 +988,40 @@ pub fn injectable(args: TokenStream, input: TokenStream) -> TokenStream {
 ///
 /// Same as `#[derive(Model)]`. See [`derive_model`] for details.
 ///
+/// # Abstract Base Models
+///
+/// `#[model(abstract)]` declares a set of fields shared by several models.
+/// An abstract model has no table, no migrations and no `Model`
+/// implementation; concrete models inherit its fields with
+/// `#[model_base(...)]`, placed after `#[model(...)]`:
+///
+/// ```rust,ignore
+/// #[model(abstract)]
+/// pub struct Timestamped {
+///     #[field(auto_now_add = true)]
+///     pub created_at: DateTime<Utc>,
+///     #[field(auto_now = true)]
+///     pub updated_at: DateTime<Utc>,
+/// }
+///
+/// #[model(app_label = "blog", table_name = "posts")]
+/// #[model_base(Timestamped)]
+/// pub struct Post {
+///     #[field(primary_key = true)]
+///     pub id: Option<i64>,
+///     #[field(max_length = 200)]
+///     pub title: String,
+/// }
+/// ```
+///
+/// Inherited fields are appended to the concrete model and are part of its
+/// field metadata, migrations and `QueryFields`. Other arguments of the
+/// abstract model (e.g. `app_label`, `constraints`, `audited`) apply to the
+/// concrete model unless it sets them itself; `table_name` is not allowed.
+/// Several bases can be listed (`#[model_base(Timestamped, SoftDelete)]`).
+/// Abstract models can be used anywhere in the defining crate, by path or
+/// through a `use` of the abstract model.
+///
 #[proc_macro_attribute]
 pub fn model(args: TokenStream, input: TokenStream) -> TokenStream {
 	let input = parse_macro_input!(input as ItemStruct);
@@ -779,6 +1076,22 @@ pub fn user(args: TokenStream, input: TokenStream) -> TokenStream {
 /// - `app_label`: Application label (default: "default")
 /// - `table_name`: Database table name (default: struct name in snake_case)
 /// - `constraints`: List of unique constraints (e.g., `unique(fields = ["field1", "field2"], name = "name")`)
+/// - `audited`: Record creates, updates and deletes with field diffs in the ORM audit store
+/// - `data_subject`: Field or column linking rows to their user (e.g., `data_subject = "author_id"`);
+///   registers the model for personal data export and anonymization
+/// - `retain`: How long rows are kept (e.g., `retain = "90d"`, units `s`, `m`, `h`, `d`, `w`,
+///   `y`), counted from the `auto_now_add` field or the field named by `retain_by`;
+///   `on_expire = delete` (the default) or `on_expire = anonymize` selects what retention
+///   enforcement does with expired rows
+/// - `tenant_field`: Field or column holding the owning tenant (e.g., `tenant_field = "tenant_id"`);
+///   queries and writes are restricted to the tenant of the current `with_tenant` scope
+/// - `manager`: Custom default manager returned by `Model::objects()`
+/// - `managers`: Alternative entry points (e.g. `managers(published = PublishedManager)`
+///   generates `Model::published()` returning that manager's default queryset)
+/// - `proxy`: Proxy model reusing another model's `table_name` with its own Rust
+///   behavior and options; no migrations are generated for it
+/// - `managed`: Set `managed = false` for legacy tables or database views; the model
+///   is registered but the autodetector never creates, alters or drops its table
 ///
 /// # Field Attributes
 ///
@@ -790,6 +1103,20 @@ pub fn user(args: TokenStream, input: TokenStream) -> TokenStream {
 /// - `default`: Default value
 /// - `db_column`: Custom database column name
 /// - `editable`: Whether field is editable (default: true)
+/// - `slug_from`: Fill this slug field from another field on save when it is
+///   empty (e.g., `slug_from = "title"`); with `unique`, a numeric suffix is
+///   appended on collision
+/// - `personal_data`: Mark the field as personal data, anonymized with `"null"` (the
+///   default for bare `personal_data`), `"hash"` or `"fake"` (e.g., `personal_data = "hash"`)
+/// - `counter_of`: Keep this integer field equal to the number of rows of the
+///   relation with the given `related_name` (e.g., `counter_of = "comments"`);
+///   saves, deletes and bulk creates of the related model update it atomically
+///
+/// # Database Comments
+///
+/// Doc comments on the struct and its fields are registered as the table and
+/// column comments; migrations emit them as `COMMENT ON TABLE` / `COMMENT ON
+/// COLUMN` on backends that support it.
 ///
 /// # Supported Types
 ///
@@ -815,6 +1142,7 @@ pub fn user(args: TokenStream, input: TokenStream) -> TokenStream {
 	attributes(
 		model,
 		model_config,
+		model_base_config,
 		field,
 		rel,
 		fk_id_field,
@@ -957,6 +1285,10 @@ pub fn collect_migrations(input: TokenStream) -> TokenStream {
 /// - `fields = [field1, field2, ...]` - Fields to display in forms (default: all)
 /// - `readonly_fields = [field1, field2, ...]` - Read-only fields (default: `[]`)
 /// - `ordering = [(field1, asc/desc), ...]` - Default ordering (default: `[(id, desc)]`)
+/// - `list_editable = [field1, ...]` - Fields editable inline in list view; each must also be
+///   in `list_display` (default: `[]`)
+/// - `rich_text_fields = [field1, ...]` - Fields holding rich text editor HTML, sanitized
+///   with an allowlist instead of escaped (default: `[]`)
 /// - `list_per_page = N` - Items per page (default: site default)
 ///
 /// # Compile-time Field Validation
@@ -1017,6 +1349,87 @@ pub fn derive_apply_update(input: TokenStream) -> TokenStream {
 		.into()
 }
 
+/// Derive macro for typed app settings
+///
+/// Implements `AppSettings` so the struct can be loaded with
+/// `settings.app::<T>()`, and a `Debug` that redacts secret fields.
+///
+/// # Struct Attributes
+///
+/// - `#[app_settings(section = "payments")]` - TOML section to read (required)
+/// - `env_prefix = "PAYMENTS_"` - Prefix of overriding environment variables
+///   (default: the upper-cased section followed by `_`)
+/// - `validate = path` - `fn(&Self) -> Result<(), impl ToString>` run after loading
+///
+/// # Field Attributes
+///
+/// - `#[app_setting(default)]` - Use `Default::default()` when missing
+/// - `#[app_setting(default = expr)]` - Use `expr` when missing
+/// - `#[app_setting(secret)]` - Print `***` instead of the value in `Debug`
+/// - `#[app_setting(validate = path)]` - `fn(&FieldType) -> Result<(), impl Display>`
+///
+/// `Option<T>` fields are optional; other fields without default are required.
+///
+/// ```rust,ignore
+/// #[derive(Clone, AppSettings)]
+/// #[app_settings(section = "payments")]
+/// pub struct PaymentsSettings {
+///     #[app_setting(secret)]
+///     pub api_key: String,
+///     #[app_setting(default = "EUR".to_string())]
+///     pub currency: String,
+/// }
+/// ```
+///
+#[proc_macro_derive(AppSettings, attributes(app_settings, app_setting))]
+pub fn derive_app_settings(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as syn::DeriveInput);
+
+	app_settings_derive::derive_app_settings_impl(input)
+		.unwrap_or_else(|e| e.to_compile_error())
+		.into()
+}
+
+/// Derive macro for search index schemas
+///
+/// Implements `reinhardt_search::Searchable`, describing the search index of
+/// the struct. Only fields annotated with `#[search(...)]` are indexed.
+///
+/// # Struct Attributes
+///
+/// - `#[searchable(index = "articles")]` - Index name (required)
+/// - `id = "id"` - Field holding the document id (default: `id`)
+///
+/// # Field Attributes
+///
+/// - `#[search(text)]`, `keyword`, `number`, `boolean` or `date` - How the
+///   field is indexed (required)
+/// - `facet` - Allow facet counts (implies `filterable`)
+/// - `filterable` - Allow filters
+/// - `sortable` - Allow sorting
+/// - `boost = 2.0` - Relevance weight of matches
+///
+/// ```rust,ignore
+/// #[derive(Serialize, Searchable)]
+/// #[searchable(index = "articles")]
+/// pub struct Article {
+///     pub id: i64,
+///     #[search(text, boost = 2.0)]
+///     pub title: String,
+///     #[search(keyword, facet)]
+///     pub category: String,
+/// }
+/// ```
+///
+#[proc_macro_derive(Searchable, attributes(searchable, search))]
+pub fn derive_searchable(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as syn::DeriveInput);
+
+	searchable_derive::derive_searchable_impl(input)
+		.unwrap_or_else(|e| e.to_compile_error())
+		.into()
+}
+
 /// Derive macro for struct-level validation
 ///
 /// Implements the `Validate` trait using `#[validate(...)]` field attributes
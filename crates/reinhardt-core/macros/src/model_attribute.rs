@@ -1,9 +1,9 @@
 //! Attribute macro implementation for `#[model(...)]`
 
 use crate::crate_paths::get_reinhardt_crate;
-use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{Attribute, Field, ItemStruct, Result, Type};
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::{Attribute, Field, ItemStruct, Result, Type, Visibility};
 
 /// Extract target type from ForeignKeyField<T> or OneToOneField<T>
 fn extract_fk_target_type(ty: &Type) -> Option<&Type> {
@@ -18,10 +18,177 @@ fn extract_fk_target_type(ty: &Type) -> Option<&Type> {
 	None
 }
 
+/// Split `#[model(...)]` arguments at top-level commas.
+///
+/// Bracketed and parenthesized values (e.g. `constraints = [...]`) are single
+/// token trees, so commas inside them do not split.
+fn split_model_args(args: TokenStream) -> Vec<TokenStream> {
+	let mut segments = vec![TokenStream::new()];
+	for tt in args {
+		match &tt {
+			TokenTree::Punct(punct) if punct.as_char() == ',' => {
+				segments.push(TokenStream::new());
+			}
+			_ => segments
+				.last_mut()
+				.expect("segments is never empty")
+				.extend([tt]),
+		}
+	}
+	segments.retain(|segment| !segment.is_empty());
+	segments
+}
+
+/// Name of a `#[model(...)]` argument segment (`abstract`, `app_label`, ...).
+fn model_arg_name(segment: &TokenStream) -> Option<String> {
+	match segment.clone().into_iter().next()? {
+		TokenTree::Ident(ident) => Some(ident.to_string()),
+		_ => None,
+	}
+}
+
+/// Expand `#[model(abstract, ...)]`.
+///
+/// An abstract model has no table and no `Model` implementation. Instead it
+/// expands to a `macro_rules!` macro, re-exported under the model's name in
+/// the macro namespace, which appends the abstract model's fields to a
+/// concrete model. `#[model_base(Timestamped)]` on a concrete model invokes
+/// it, so inherited fields go through the regular derive and show up in
+/// field metadata, migrations and `QueryFields` like declared ones.
+///
+/// The remaining arguments (e.g. `app_label`, `constraints`) are forwarded as
+/// a `#[model_base_config(...)]` attribute and act as defaults for the
+/// concrete model.
+fn abstract_model_impl(args: TokenStream, input: ItemStruct) -> Result<TokenStream> {
+	let base_name = &input.ident;
+
+	if !input.generics.params.is_empty() {
+		return Err(syn::Error::new_spanned(
+			&input.generics,
+			"abstract models cannot be generic",
+		));
+	}
+	let syn::Fields::Named(ref fields) = input.fields else {
+		return Err(syn::Error::new_spanned(
+			&input,
+			"abstract models must have named fields",
+		));
+	};
+
+	let mut inherited_args = Vec::new();
+	for segment in split_model_args(args) {
+		match model_arg_name(&segment).as_deref() {
+			Some("abstract") => {}
+			Some("table_name") => {
+				return Err(syn::Error::new_spanned(
+					segment,
+					"abstract models have no table; set `table_name` on the concrete model",
+				));
+			}
+			_ => inherited_args.push(segment),
+		}
+	}
+	let base_config = if inherited_args.is_empty() {
+		quote! {}
+	} else {
+		quote! { #[model_base_config(#(#inherited_args),*)] }
+	};
+
+	let base_fields = fields.named.iter().map(|field| quote! { #field, });
+	let docs = input
+		.attrs
+		.iter()
+		.filter(|attr| attr.path().is_ident("doc"));
+	let macro_name = format_ident!("__reinhardt_model_base_{}", base_name);
+	// `macro_rules!` macros can only be re-exported within the crate
+	let use_vis = match &input.vis {
+		Visibility::Public(_) => quote! { pub(crate) },
+		vis => quote! { #vis },
+	};
+
+	Ok(quote! {
+		#[doc(hidden)]
+		macro_rules! #macro_name {
+			($(#[$meta:meta])* $vis:vis struct $name:ident { $($body:tt)* }) => {
+				$(#[$meta])*
+				#base_config
+				$vis struct $name {
+					$($body)*
+					#(#base_fields)*
+				}
+			};
+		}
+
+		#(#docs)*
+		#[allow(unused_imports)]
+		#use_vis use #macro_name as #base_name;
+	})
+}
+
+/// Expand `#[model_base(A, B, ...)]` by handing the struct to the macro
+/// generated for `A`, which re-applies `#[model(...)]` with `A`'s fields
+/// appended. Remaining bases are applied on the next round.
+fn model_base_impl(
+	args: TokenStream,
+	mut input: ItemStruct,
+	base_attr: Attribute,
+) -> Result<TokenStream> {
+	let reinhardt = get_reinhardt_crate();
+
+	let bases = base_attr.parse_args_with(
+		syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+	)?;
+	let mut bases = bases.into_iter();
+	let Some(first) = bases.next() else {
+		return Err(syn::Error::new_spanned(
+			base_attr,
+			"#[model_base(...)] requires at least one abstract model",
+		));
+	};
+	let rest: Vec<syn::Path> = bases.collect();
+	if !rest.is_empty() {
+		input
+			.attrs
+			.insert(0, syn::parse_quote! { #[model_base(#(#rest),*)] });
+	}
+
+	if !input.generics.params.is_empty() {
+		return Err(syn::Error::new_spanned(
+			&input.generics,
+			"#[model_base(...)] cannot be used on generic models",
+		));
+	}
+	let syn::Fields::Named(ref mut fields) = input.fields else {
+		return Err(syn::Error::new_spanned(
+			&input,
+			"#[model_base(...)] requires named fields",
+		));
+	};
+	// Inherited fields are appended after the declared ones
+	if !fields.named.empty_or_trailing() {
+		fields.named.push_punct(Default::default());
+	}
+
+	let model_path = quote!(#reinhardt::macros::model);
+	Ok(quote! {
+		#first! {
+			#[#model_path(#args)]
+			#input
+		}
+	})
+}
+
 pub(crate) fn model_attribute_impl(
 	args: TokenStream,
 	mut input: ItemStruct,
 ) -> Result<TokenStream> {
+	if split_model_args(args.clone())
+		.iter()
+		.any(|segment| segment.to_string() == "abstract")
+	{
+		return abstract_model_impl(args, input);
+	}
+
 	// Get dynamic crate paths for code generation
 	let reinhardt = get_reinhardt_crate();
 
@@ -45,6 +212,21 @@ pub(crate) fn model_attribute_impl(
 		false
 	});
 
+	if let Some(idx) = input
+		.attrs
+		.iter()
+		.position(|attr| attr.path().is_ident("model_base"))
+	{
+		if has_derive_model {
+			return Err(syn::Error::new_spanned(
+				&input.attrs[idx],
+				"#[model_base(...)] is not supported together with #[derive(Model)]",
+			));
+		}
+		let base_attr = input.attrs.remove(idx);
+		return model_base_impl(args, input, base_attr);
+	}
+
 	if has_derive_model {
 		// Already has #[derive(Model)], just return input unchanged
 		// The derive macro will read #[model(...)] helper attribute
@@ -310,6 +492,26 @@ pub(crate) fn model_attribute_impl(
 	};
 	input.attrs.insert(config_insert_pos, config_attr);
 
+	// Inherited `#[model_base_config(...)]` attributes are derive helpers and
+	// must follow the derive that introduces them
+	let base_config_attrs: Vec<Attribute> = input
+		.attrs
+		.iter()
+		.filter(|attr| attr.path().is_ident("model_base_config"))
+		.cloned()
+		.collect();
+	input
+		.attrs
+		.retain(|attr| !attr.path().is_ident("model_base_config"));
+	let config_pos = input
+		.attrs
+		.iter()
+		.position(|attr| attr.path().is_ident("model_config"))
+		.expect("model_config was just inserted");
+	for (offset, attr) in base_config_attrs.into_iter().enumerate() {
+		input.attrs.insert(config_pos + 1 + offset, attr);
+	}
+
 	// Note: We don't generate auto-imports here because:
 	// 1. Each #[model] usage would generate duplicate imports in the same module
 	// 2. The Model derive macro uses absolute paths (::reinhardt::db::orm::Model etc.)
@@ -318,3 +520,64 @@ pub(crate) fn model_attribute_impl(
 
 	Ok(quote! { #input })
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_abstract_model_expands_to_field_macro() {
+		let args = quote! { abstract, app_label = "blog" };
+		let input: ItemStruct = syn::parse_quote! {
+			pub struct Timestamped {
+				#[field(auto_now_add = true)]
+				pub created_at: DateTime<Utc>,
+			}
+		};
+
+		let output = model_attribute_impl(args, input).unwrap().to_string();
+
+		assert!(output.contains("macro_rules ! __reinhardt_model_base_Timestamped"));
+		assert!(output.contains("# [model_base_config (app_label = \"blog\")]"));
+		assert!(
+			output.contains("# [field (auto_now_add = true)] pub created_at : DateTime < Utc > ,")
+		);
+		assert!(
+			output.contains("pub (crate) use __reinhardt_model_base_Timestamped as Timestamped")
+		);
+		assert!(!output.contains("derive"));
+	}
+
+	#[test]
+	fn test_abstract_model_rejects_table_name() {
+		let args = quote! { abstract, table_name = "timestamped" };
+		let input: ItemStruct = syn::parse_quote! {
+			pub struct Timestamped {
+				pub created_at: DateTime<Utc>,
+			}
+		};
+
+		let error = model_attribute_impl(args, input).unwrap_err();
+
+		assert!(error.to_string().contains("abstract models have no table"));
+	}
+
+	#[test]
+	fn test_model_base_invokes_each_base_in_turn() {
+		let args = quote! { app_label = "blog", table_name = "posts" };
+		let input: ItemStruct = syn::parse_quote! {
+			#[model_base(Timestamped, crate::base::SoftDelete)]
+			pub struct Post {
+				#[field(primary_key = true)]
+				pub id: i64
+			}
+		};
+
+		let output = model_attribute_impl(args, input).unwrap().to_string();
+
+		assert!(output.starts_with("Timestamped ! {"));
+		assert!(output.contains("model (app_label = \"blog\" , table_name = \"posts\")"));
+		assert!(output.contains("# [model_base (crate :: base :: SoftDelete)]"));
+		assert!(output.contains("pub id : i64 ,"));
+	}
+}
@@ -56,11 +56,29 @@ struct ModelAttributesParsed {
 	unique_together: Vec<Vec<String>>, // Multiple Django-style unique_together constraints
 	/// Optional custom manager path: `manager = MyManager` (Issue #3980).
 	manager: Option<syn::Path>,
+	/// Alternative manager entry points: `managers(published = PublishedManager)`.
+	managers: Vec<(Ident, syn::Path)>,
 	/// Whether to generate Info companion struct (Issue #4194).
 	/// `None` means not specified (defaults to `true` in `ModelConfig`).
 	info: Option<bool>,
 	/// Whether this model is only available on the native/server side.
 	server_only: bool,
+	/// Whether changes are recorded in the ORM audit store.
+	audited: bool,
+	/// Column linking rows to the user they describe: `data_subject = "author_id"`.
+	data_subject: Option<String>,
+	/// How long rows are kept: `retain = "90d"`.
+	retain: Option<String>,
+	/// What happens to expired rows: `on_expire = delete` or `anonymize`.
+	on_expire: Option<String>,
+	/// Timestamp field the retention period starts from: `retain_by = "created_at"`.
+	retain_by: Option<String>,
+	/// Column holding the owning tenant: `tenant_field = "tenant_id"`.
+	tenant_field: Option<String>,
+	/// `managed = false`: migrations do not create or alter the table.
+	managed: Option<bool>,
+	/// Whether this is a proxy model sharing another model's table.
+	proxy: bool,
 	/// Whether the original model has `#[derive(serde::Serialize)]`.
 	/// Passed from the attribute macro since derive macros cannot see `#[derive()]`.
 	serde_serialize: bool,
@@ -142,11 +160,41 @@ struct ModelConfig {
 	/// When `Some`, the macro sets `type Objects = MyManager` in the generated
 	/// `Model` impl so that `objects()` returns the custom manager directly.
 	manager: Option<syn::Path>,
+	/// Alternative manager entry points from `managers(name = Manager, ...)`.
+	///
+	/// Each entry generates `Model::name()` returning the default queryset of
+	/// that manager, next to the default `objects()`.
+	managers: Vec<(Ident, syn::Path)>,
 	/// Whether to generate an `{Model}Info` companion struct (Issue #4194).
 	/// Defaults to `true`. Set `#[model(info = false)]` to opt out.
 	info: bool,
 	/// Whether this model should skip shared data/info output.
 	server_only: bool,
+	/// Whether `Model::audited()` returns `true` (`#[model(audited)]`).
+	audited: bool,
+	/// Column linking rows to their data subject (`#[model(data_subject = "...")]`).
+	/// Models setting it are registered for personal data export and
+	/// anonymization.
+	data_subject: Option<String>,
+	/// Retention period (`#[model(retain = "90d")]`); models setting it are
+	/// registered for retention enforcement.
+	retain: Option<String>,
+	/// Action on expired rows (`#[model(on_expire = anonymize)]`).
+	on_expire: Option<String>,
+	/// Field the retention period starts from (`#[model(retain_by = "...")]`);
+	/// defaults to the `auto_now_add` field.
+	retain_by: Option<String>,
+	/// Column holding the owning tenant (`#[model(tenant_field = "...")]`).
+	/// Queries and writes on the model are restricted to the current tenant.
+	tenant_field: Option<String>,
+	/// Doc comment of the model struct, stored as the table comment.
+	table_comment: Option<String>,
+	/// Whether migrations manage the table; `false` for legacy tables and
+	/// views (`#[model(managed = false)]`).
+	managed: bool,
+	/// Whether this is a proxy model (`#[model(proxy)]`): same table as
+	/// another model, registered without migrations of its own.
+	proxy: bool,
 	/// Whether the original model derives `serde::Serialize`.
 	serde_serialize: bool,
 	/// Whether the original model derives `serde::Deserialize`.
@@ -160,8 +208,17 @@ impl ModelConfig {
 		let mut table_name = None;
 		let mut constraints = Vec::new();
 		let mut manager: Option<syn::Path> = None;
+		let mut managers: Vec<(Ident, syn::Path)> = Vec::new();
 		let mut info: Option<bool> = None;
 		let mut server_only = false;
+		let mut audited = false;
+		let mut data_subject: Option<String> = None;
+		let mut retain: Option<String> = None;
+		let mut on_expire: Option<String> = None;
+		let mut retain_by: Option<String> = None;
+		let mut tenant_field: Option<String> = None;
+		let mut managed: Option<bool> = None;
+		let mut proxy = false;
 		let mut serde_serialize = false;
 		let mut serde_deserialize = false;
 
@@ -206,12 +263,37 @@ impl ModelConfig {
 				}
 				manager = Some(m);
 			}
+			managers.extend(model_attr.managers);
 			if let Some(i) = model_attr.info {
 				info = Some(i);
 			}
 			if model_attr.server_only {
 				server_only = true;
 			}
+			if model_attr.audited {
+				audited = true;
+			}
+			if let Some(ds) = model_attr.data_subject {
+				data_subject = Some(ds);
+			}
+			if let Some(r) = model_attr.retain {
+				retain = Some(r);
+			}
+			if let Some(oe) = model_attr.on_expire {
+				on_expire = Some(oe);
+			}
+			if let Some(rb) = model_attr.retain_by {
+				retain_by = Some(rb);
+			}
+			if let Some(tf) = model_attr.tenant_field {
+				tenant_field = Some(tf);
+			}
+			if let Some(m) = model_attr.managed {
+				managed = Some(m);
+			}
+			if model_attr.proxy {
+				proxy = true;
+			}
 			if model_attr.serde_serialize {
 				serde_serialize = true;
 			}
@@ -220,6 +302,53 @@ impl ModelConfig {
 			}
 		}
 
+		// Settings inherited from abstract base models (`#[model_base(...)]`)
+		// apply only where the concrete model does not set them, in the order
+		// the bases were listed. Constraints are added to the model's own.
+		for attr in attrs {
+			if !attr.path().is_ident("model_base_config") {
+				continue;
+			}
+
+			let base_attr = attr
+				.parse_args_with(|input: syn::parse::ParseStream| {
+					Self::parse_model_attributes(input)
+				})
+				.map_err(|e| {
+					syn::Error::new_spanned(attr, format!("parse_args_with failed: {}", e))
+				})?;
+
+			if base_attr.table_name.is_some() {
+				return Err(syn::Error::new_spanned(
+					attr,
+					"abstract base models cannot set table_name",
+				));
+			}
+			constraints.extend(base_attr.constraints.unwrap_or_default());
+			for fields in base_attr.unique_together {
+				constraints.push(ConstraintSpec::Unique {
+					fields,
+					name: None,
+					condition: None,
+				});
+			}
+			app_label = app_label.or(base_attr.app_label);
+			manager = manager.or(base_attr.manager);
+			for (name, path) in base_attr.managers {
+				if !managers.iter().any(|(existing, _)| *existing == name) {
+					managers.push((name, path));
+				}
+			}
+			info = info.or(base_attr.info);
+			server_only |= base_attr.server_only;
+			audited |= base_attr.audited;
+			data_subject = data_subject.or(base_attr.data_subject);
+			retain = retain.or(base_attr.retain);
+			on_expire = on_expire.or(base_attr.on_expire);
+			retain_by = retain_by.or(base_attr.retain_by);
+			tenant_field = tenant_field.or(base_attr.tenant_field);
+		}
+
 		let table_name = table_name.ok_or_else(|| {
 			syn::Error::new_spanned(
 				struct_name,
@@ -232,8 +361,18 @@ impl ModelConfig {
 			table_name,
 			constraints,
 			manager,
+			managers,
 			info: info.unwrap_or(true),
 			server_only,
+			audited,
+			data_subject,
+			retain,
+			on_expire,
+			retain_by,
+			tenant_field,
+			table_comment: crate::schema::extract_doc_comment(attrs),
+			managed: managed.unwrap_or(true),
+			proxy,
 			serde_serialize,
 			serde_deserialize,
 		})
@@ -248,12 +387,27 @@ impl ModelConfig {
 		let mut constraints = None;
 		let mut unique_together = Vec::new();
 		let mut manager: Option<syn::Path> = None;
+		let mut managers: Vec<(Ident, syn::Path)> = Vec::new();
 		let mut info: Option<bool> = None;
 		let mut server_only = false;
+		let mut audited = false;
+		let mut data_subject = None;
+		let mut retain = None;
+		let mut on_expire = None;
+		let mut retain_by = None;
+		let mut tenant_field = None;
+		let mut managed: Option<bool> = None;
+		let mut proxy = false;
 		let mut serde_serialize = false;
 		let mut serde_deserialize = false;
 
 		while !input.is_empty() {
+			if input.peek(Token![abstract]) {
+				return Err(input.error(
+					"abstract models are only supported by the #[model(abstract)] attribute macro, \
+					 not #[derive(Model)]",
+				));
+			}
 			let ident: Ident = input.parse()?;
 
 			// Bare flags (no `= value`)
@@ -281,6 +435,50 @@ impl ModelConfig {
 					break;
 				}
 				continue;
+			} else if ident == "audited" {
+				audited = true;
+				if input.peek(Token![,]) {
+					input.parse::<Token![,]>()?;
+				} else {
+					break;
+				}
+				continue;
+			} else if ident == "proxy" {
+				proxy = true;
+				if input.peek(Token![,]) {
+					input.parse::<Token![,]>()?;
+				} else {
+					break;
+				}
+				continue;
+			} else if ident == "managers" {
+				// Alternative entry points: managers(published = PublishedManager, ...)
+				let content;
+				parenthesized!(content in input);
+				while !content.is_empty() {
+					let name: Ident = content.parse()?;
+					content.parse::<Token![=]>()?;
+					let path: syn::Path = content.parse()?;
+					if name == "objects" || managers.iter().any(|(existing, _)| *existing == name) {
+						return Err(syn::Error::new_spanned(
+							&name,
+							format!("manager entry point `{}` is already defined", name),
+						));
+					}
+					managers.push((name, path));
+
+					if content.peek(Token![,]) {
+						content.parse::<Token![,]>()?;
+					} else {
+						break;
+					}
+				}
+				if input.peek(Token![,]) {
+					input.parse::<Token![,]>()?;
+				} else {
+					break;
+				}
+				continue;
 			}
 
 			input.parse::<Token![=]>()?;
@@ -291,6 +489,26 @@ impl ModelConfig {
 			} else if ident == "table_name" {
 				let value: LitStr = input.parse()?;
 				table_name = Some(value.value());
+			} else if ident == "data_subject" {
+				let value: LitStr = input.parse()?;
+				data_subject = Some(value.value());
+			} else if ident == "retain" {
+				let value: LitStr = input.parse()?;
+				retain = Some(value.value());
+			} else if ident == "on_expire" {
+				// Bare `on_expire = delete` or quoted `on_expire = "delete"`
+				let value = if input.peek(LitStr) {
+					input.parse::<LitStr>()?.value()
+				} else {
+					input.parse::<Ident>()?.to_string()
+				};
+				on_expire = Some(value);
+			} else if ident == "retain_by" {
+				let value: LitStr = input.parse()?;
+				retain_by = Some(value.value());
+			} else if ident == "tenant_field" {
+				let value: LitStr = input.parse()?;
+				tenant_field = Some(value.value());
 			} else if ident == "manager" {
 				// Custom object manager type: `manager = MyManager` (Issue #3980).
 				let path: syn::Path = input.parse()?;
@@ -298,6 +516,9 @@ impl ModelConfig {
 			} else if ident == "info" {
 				let value: LitBool = input.parse()?;
 				info = Some(value.value());
+			} else if ident == "managed" {
+				let value: LitBool = input.parse()?;
+				managed = Some(value.value());
 			} else if ident == "unique_together" {
 				// Tuple syntax: unique_together = ("field1", "field2")
 				use syn::punctuated::Punctuated;
@@ -343,8 +564,17 @@ impl ModelConfig {
 			constraints,
 			unique_together,
 			manager,
+			managers,
 			info,
 			server_only,
+			audited,
+			data_subject,
+			retain,
+			on_expire,
+			retain_by,
+			tenant_field,
+			managed,
+			proxy,
 			serde_serialize,
 			serde_deserialize,
 		})
@@ -483,6 +713,12 @@ struct FieldConfig {
 	// Time-related fields
 	auto_now_add: Option<bool>,
 	auto_now: Option<bool>,
+	/// Field the slug is generated from on save (`slug_from = "title"`)
+	slug_from: Option<String>,
+	/// Anonymization strategy of a personal data field (`personal_data = "hash"`)
+	personal_data: Option<String>,
+	/// `related_name` of the relation a counter field counts (`counter_of = "comments"`)
+	counter_of: Option<String>,
 	// Relationship fields
 	foreign_key: Option<ForeignKeySpec>,
 
@@ -605,8 +841,13 @@ impl FieldConfig {
 					config.blank = Some(value.value);
 					Ok(())
 				} else if meta.path.is_ident("unique") {
-					let value: syn::LitBool = meta.value()?.parse()?;
-					config.unique = Some(value.value);
+					// Bare `unique` is shorthand for `unique = true`
+					let value = if meta.input.peek(syn::Token![=]) {
+						meta.value()?.parse::<syn::LitBool>()?.value
+					} else {
+						true
+					};
+					config.unique = Some(value);
 					Ok(())
 				} else if meta.path.is_ident("default") {
 					// Parse as Expr to support bool, int, string, etc.
@@ -659,6 +900,30 @@ impl FieldConfig {
 					let value: syn::LitBool = meta.value()?.parse()?;
 					config.auto_now = Some(value.value);
 					Ok(())
+				} else if meta.path.is_ident("personal_data") {
+					// Bare `personal_data` is shorthand for `personal_data = "null"`
+					let strategy = if meta.input.peek(syn::Token![=]) {
+						let value: syn::LitStr = meta.value()?.parse()?;
+						if !matches!(value.value().as_str(), "null" | "hash" | "fake") {
+							return Err(syn::Error::new_spanned(
+								&value,
+								"personal_data must be \"null\", \"hash\" or \"fake\"",
+							));
+						}
+						value.value()
+					} else {
+						"null".to_string()
+					};
+					config.personal_data = Some(strategy);
+					Ok(())
+				} else if meta.path.is_ident("counter_of") {
+					let value: syn::LitStr = meta.value()?.parse()?;
+					config.counter_of = Some(value.value());
+					Ok(())
+				} else if meta.path.is_ident("slug_from") {
+					let value: syn::LitStr = meta.value()?.parse()?;
+					config.slug_from = Some(value.value());
+					Ok(())
 				} else if meta.path.is_ident("foreign_key") {
 					// Try parsing as Type first (direct type specification)
 					if let Ok(ty) = meta.value()?.parse::<syn::Type>() {
@@ -1064,6 +1329,8 @@ struct FieldInfo {
 	/// Whether this is an auto-generated FK _id field (marked with `#[fk_id_field]`)
 	/// These fields should have getters but not setters
 	is_fk_id_field: bool,
+	/// Doc comment of the field, stored as the column comment.
+	doc: Option<String>,
 }
 
 /// Foreign key / One-to-one field information for automatic ID field generation
@@ -1087,6 +1354,9 @@ struct ForeignKeyFieldInfo {
 /// Generate field metadata string from Rust type
 fn field_type_to_metadata_string(ty: &Type, _config: &FieldConfig) -> Result<String> {
 	let (_is_option, inner_ty) = extract_option_type(ty);
+	if is_byte_vec(inner_ty) {
+		return Ok("reinhardt.orm.models.BinaryField".to_string());
+	}
 
 	match inner_ty {
 		Type::Path(type_path) => {
@@ -1185,6 +1455,11 @@ fn map_type_to_field_type(ty: &Type, config: &FieldConfig) -> Result<TokenStream
 	// Extract the inner type if it's Option<T>
 	let (_is_option, inner_ty) = extract_option_type(ty);
 
+	// Vec<u8> is a binary column on every backend, not an array
+	if is_byte_vec(inner_ty) {
+		return Ok(quote! { #migrations_crate::FieldType::Binary });
+	}
+
 	let field_type = match inner_ty {
 		Type::Path(type_path) => {
 			let last_segment = type_path
@@ -1412,6 +1687,19 @@ fn parse_base_type_string(
 	Ok(field_type)
 }
 
+/// Whether `ty` is `Vec<u8>`
+fn is_byte_vec(ty: &Type) -> bool {
+	if let Type::Path(type_path) = ty
+		&& let Some(last_segment) = type_path.path.segments.last()
+		&& last_segment.ident == "Vec"
+		&& let syn::PathArguments::AngleBracketed(args) = &last_segment.arguments
+		&& let Some(syn::GenericArgument::Type(Type::Path(inner))) = args.args.first()
+	{
+		return inner.path.is_ident("u8");
+	}
+	false
+}
+
 /// Extract `Option<T>` and return (is_option, inner_type)
 fn extract_option_type(ty: &Type) -> (bool, &Type) {
 	if let Type::Path(type_path) = ty
@@ -1480,8 +1768,9 @@ fn generate_field_accessors(struct_name: &syn::Ident, field_infos: &[FieldInfo])
 ///
 /// The generated accessor method internally calls `ManyToManyAccessor::new()`
 /// with the field name, providing compile-time field name validation and
-/// improved IDE support.
-///
+/// improved IDE support. Fields declared with a through model
+/// (`#[rel(many_to_many, through = "Membership")]`) return a
+/// `ThroughAccessor` instead.
 ///
 /// # Generated Code Characteristics
 ///
@@ -1517,6 +1806,24 @@ fn generate_m2m_accessor_methods(
 				field_name_str
 			);
 
+			// Fields with a through model get an accessor that also exposes
+			// the through-model rows
+			if let Some(through_model) = field.rel.as_ref().and_then(|r| r.through_model()) {
+				return Some(quote! {
+					#[doc = #doc_comment]
+					pub fn #method_name(
+						&self,
+						db: #orm_crate::connection::DatabaseConnection
+					) -> #orm_crate::ThroughAccessor<#struct_name, #target_ty, #through_model> {
+						#orm_crate::ThroughAccessor::new(
+							self,
+							#field_name_str,
+							db
+						)
+					}
+				});
+			}
+
 			Some(quote! {
 				#[doc = #doc_comment]
 				pub fn #method_name(
@@ -1544,6 +1851,43 @@ fn generate_m2m_accessor_methods(
 	}
 }
 
+/// Generate alternative manager entry points from `#[model(managers(...))]`.
+///
+/// `managers(published = PublishedManager)` generates `Post::published()`,
+/// which returns the default queryset (`CustomManager::all`) of
+/// `PublishedManager`. The result is a regular `QuerySet`, so every QuerySet
+/// method can be chained onto it.
+fn generate_manager_entry_points(
+	struct_name: &syn::Ident,
+	managers: &[(Ident, syn::Path)],
+) -> TokenStream {
+	if managers.is_empty() {
+		return quote! {};
+	}
+
+	let orm_crate = get_reinhardt_orm_crate();
+	let entry_points = managers.iter().map(|(name, path)| {
+		let doc_comment = format!(
+			"Get the default queryset of the `{}` manager",
+			quote! { #path }.to_string().replace(' ', "")
+		);
+		quote! {
+			#[doc = #doc_comment]
+			pub fn #name() -> #orm_crate::QuerySet<Self> {
+				#orm_crate::CustomManager::all(
+					&<#path as #orm_crate::CustomManager>::new()
+				)
+			}
+		}
+	});
+
+	quote! {
+		impl #struct_name {
+			#(#entry_points)*
+		}
+	}
+}
+
 /// Generate accessor methods for ForeignKey and OneToOne relationships.
 ///
 /// The generated accessor method loads the related instance from the database
@@ -1818,6 +2162,15 @@ pub(crate) fn model_derive_impl(mut input: DeriveInput) -> Result<TokenStream> {
 	let model_config = ModelConfig::from_attrs(&input.attrs, struct_name)?;
 	let app_label = &model_config.app_label;
 	let table_name = &model_config.table_name;
+	let audited_impl = if model_config.audited {
+		quote! {
+			fn audited() -> bool {
+				true
+			}
+		}
+	} else {
+		quote! {}
+	};
 
 	// Only support structs
 	let fields = match &input.data {
@@ -1899,7 +2252,7 @@ pub(crate) fn model_derive_impl(mut input: DeriveInput) -> Result<TokenStream> {
 
 		// Collect relationship fields for later processing
 		if let Some(ref rel_attr) = rel {
-			rel_fields.push((name.clone(), rel_attr.clone()));
+			rel_fields.push((name.clone(), ty.clone(), rel_attr.clone()));
 		}
 
 		field_infos.push(FieldInfo {
@@ -1910,9 +2263,15 @@ pub(crate) fn model_derive_impl(mut input: DeriveInput) -> Result<TokenStream> {
 			injected_relation_serde_skip,
 			rel,
 			is_fk_id_field,
+			doc: crate::schema::extract_doc_comment(&field.attrs),
 		});
 	}
 
+	// Validate the join columns of ManyToMany fields backed by a through model
+	for (_, ty, rel_attr) in &rel_fields {
+		resolve_through_model(struct_name, ty, rel_attr)?;
+	}
+
 	// Extract ForeignKeyField and OneToOneField information
 	let mut fk_field_infos: Vec<ForeignKeyFieldInfo> = Vec::new();
 	for field_info in &field_infos {
@@ -1971,6 +2330,63 @@ pub(crate) fn model_derive_impl(mut input: DeriveInput) -> Result<TokenStream> {
 	// Determine if this is a composite primary key
 	let is_composite_pk = pk_fields.len() > 1;
 
+	// Collect slug fields, checking that each source field exists
+	let mut slug_field_items = Vec::new();
+	for field_info in &field_infos {
+		let Some(source) = &field_info.config.slug_from else {
+			continue;
+		};
+		if !field_infos
+			.iter()
+			.any(|f| f.name == source.as_str() && !f.config.skip)
+		{
+			return Err(syn::Error::new_spanned(
+				&field_info.name,
+				format!("slug_from refers to unknown field `{}`", source),
+			));
+		}
+		let field = field_info.name.to_string();
+		let column = field_info
+			.config
+			.db_column
+			.clone()
+			.unwrap_or_else(|| field.clone());
+		let unique = field_info.config.unique.unwrap_or(false);
+		let max_length = match field_info.config.max_length {
+			Some(max_length) => {
+				let max_length = max_length as usize;
+				quote! { Some(#max_length) }
+			}
+			None => quote! { None },
+		};
+		slug_field_items.push(quote! {
+			#orm_crate::slug::SlugField {
+				field: #field,
+				column: #column,
+				source: #source,
+				unique: #unique,
+				max_length: #max_length,
+			}
+		});
+	}
+	let (personal_data_impl, personal_data_registration) =
+		generate_personal_data(struct_name, generics, &model_config, &field_infos)?;
+	let tenant_field_impl = generate_tenant_field(struct_name, &model_config, &field_infos)?;
+	let (retention_impl, retention_registration) =
+		generate_retention(struct_name, generics, &model_config, &field_infos)?;
+	let (counter_fields_impl, counter_registrations) =
+		generate_counters(struct_name, generics, &field_infos, &fk_field_infos);
+
+	let slug_fields_impl = if slug_field_items.is_empty() {
+		quote! {}
+	} else {
+		quote! {
+			fn slug_fields() -> Vec<#orm_crate::slug::SlugField> {
+				vec![#(#slug_field_items),*]
+			}
+		}
+	};
+
 	// Find all indexed fields
 	let indexed_fields: Vec<_> = field_infos
 		.iter()
@@ -2092,6 +2508,7 @@ pub(crate) fn model_derive_impl(mut input: DeriveInput) -> Result<TokenStream> {
 		struct_name,
 		app_label,
 		table_name,
+		&model_config,
 		&field_infos,
 		&fk_field_infos,
 		&unique_constraint_names,
@@ -2205,7 +2622,8 @@ pub(crate) fn model_derive_impl(mut input: DeriveInput) -> Result<TokenStream> {
 	let fk_accessor_methods = generate_fk_accessor_methods(struct_name, &field_infos);
 
 	// Generate relationship metadata
-	let relationship_metadata = generate_relationship_metadata(&rel_fields, app_label, struct_name);
+	let relationship_metadata =
+		generate_relationship_metadata(&rel_fields, app_label, struct_name)?;
 
 	// Generate new() as zero-arg alias of build()
 	let new_fn_impl = generate_new_alias(struct_name, &field_infos, &fk_id_field_names);
@@ -2271,6 +2689,7 @@ pub(crate) fn model_derive_impl(mut input: DeriveInput) -> Result<TokenStream> {
 		Some(path) => quote! { #path },
 		None => quote! { #orm_crate::Manager<Self> },
 	};
+	let manager_entry_points = generate_manager_entry_points(struct_name, &model_config.managers);
 
 	// Generate the Model implementation
 	let expanded = quote! {
@@ -2310,6 +2729,10 @@ pub(crate) fn model_derive_impl(mut input: DeriveInput) -> Result<TokenStream> {
 			#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
 			#fk_static_accessor_methods
 
+			// Generate alternative manager entry points
+			#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+			#manager_entry_points
+
 			#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
 			impl #generics #orm_crate::Model for #struct_name #generics #where_clause {
 			type PrimaryKey = #pk_type;
@@ -2379,6 +2802,18 @@ pub(crate) fn model_derive_impl(mut input: DeriveInput) -> Result<TokenStream> {
 			}
 
 			#relationship_metadata
+
+			#audited_impl
+
+			#slug_fields_impl
+
+			#personal_data_impl
+
+			#counter_fields_impl
+
+			#retention_impl
+
+			#tenant_field_impl
 			}
 
 
@@ -2387,6 +2822,15 @@ pub(crate) fn model_derive_impl(mut input: DeriveInput) -> Result<TokenStream> {
 			// Register relationships in RELATIONSHIPS distributed slice
 			#relationship_registrations
 
+			// Register personal data models in PERSONAL_DATA_MODELS distributed slice
+			#personal_data_registration
+
+			// Register counter fields and named foreign keys feeding them
+			#counter_registrations
+
+			// Register retention policies in RETENTION_POLICIES distributed slice
+			#retention_registration
+
 			// Generate field selector struct for type-safe JOIN/GROUP BY/HAVING operations
 			#field_selector_struct
 	};
@@ -2460,6 +2904,21 @@ fn generate_field_metadata(
 			});
 		}
 
+		// Timestamps maintained by the manager
+		for (name, enabled) in [
+			("auto_now", config.auto_now),
+			("auto_now_add", config.auto_now_add),
+		] {
+			if enabled == Some(true) {
+				attrs.push(quote! {
+					attributes.insert(
+						#name.to_string(),
+						#orm_crate::fields::FieldKwarg::Bool(true)
+					);
+				});
+			}
+		}
+
 		// Add validator attributes
 		if let Some(email) = config.email
 			&& email
@@ -2763,6 +3222,7 @@ fn generate_registration_code(
 	struct_name: &syn::Ident,
 	app_label: &str,
 	table_name: &str,
+	model_config: &ModelConfig,
 	field_infos: &[FieldInfo],
 	fk_field_infos: &[ForeignKeyFieldInfo],
 	unique_constraint_names: &[String],
@@ -2891,6 +3351,11 @@ fn generate_registration_code(
 			params.push(quote! { .with_param("default", #serialized) });
 		}
 
+		// Doc comments become column comments (`COMMENT ON COLUMN`)
+		if let Some(ref doc) = field_info.doc {
+			params.push(quote! { .with_param("db_comment", #doc) });
+		}
+
 		// Generate ForeignKey information if present
 		let fk_registration = if let Some(fk_spec) = &config.foreign_key {
 			match fk_spec {
@@ -2973,24 +3438,44 @@ fn generate_registration_code(
 			.and_then(|r| r.related_name.as_ref())
 			.map(|r| quote! { Some(#r.to_string()) })
 			.unwrap_or(quote! { None });
-		let through = field_info
-			.rel
-			.as_ref()
-			.and_then(|r| r.through.as_ref())
-			.map(|t| quote! { Some(#t.to_string()) })
-			.unwrap_or(quote! { None });
-		let source_field = field_info
-			.rel
-			.as_ref()
-			.and_then(|r| r.source_field.as_ref())
-			.map(|s| quote! { Some(#s.to_string()) })
-			.unwrap_or(quote! { None });
-		let target_field = field_info
-			.rel
-			.as_ref()
-			.and_then(|r| r.target_field.as_ref())
-			.map(|t| quote! { Some(#t.to_string()) })
-			.unwrap_or(quote! { None });
+		let through_join = match &field_info.rel {
+			Some(rel) => resolve_through_model(struct_name, &field_info.ty, rel)?,
+			None => None,
+		};
+		let (through, source_field, target_field) = if let Some(join) = through_join {
+			// The through model owns the join table, so the autodetector finds
+			// it by table name and does not synthesize an intermediate table.
+			let ThroughModelJoin {
+				model,
+				source_field,
+				target_field,
+			} = join;
+			(
+				quote! { Some(<#model as #orm_crate::Model>::table_name().to_string()) },
+				quote! { Some(#source_field.to_string()) },
+				quote! { Some(#target_field.to_string()) },
+			)
+		} else {
+			let through = field_info
+				.rel
+				.as_ref()
+				.and_then(|r| r.through.as_ref())
+				.map(|t| quote! { Some(#t.to_string()) })
+				.unwrap_or(quote! { None });
+			let source_field = field_info
+				.rel
+				.as_ref()
+				.and_then(|r| r.source_field.as_ref())
+				.map(|s| quote! { Some(#s.to_string()) })
+				.unwrap_or(quote! { None });
+			let target_field = field_info
+				.rel
+				.as_ref()
+				.and_then(|r| r.target_field.as_ref())
+				.map(|t| quote! { Some(#t.to_string()) })
+				.unwrap_or(quote! { None });
+			(through, source_field, target_field)
+		};
 
 		m2m_registrations.push(quote! {
 			metadata.add_many_to_many(
@@ -3128,6 +3613,26 @@ fn generate_registration_code(
 		})
 		.collect();
 
+	// Unmanaged and proxy models are registered (for relationship resolution
+	// and the admin) but flagged so the autodetector generates no operations
+	let mut option_registrations = Vec::new();
+	if !model_config.managed {
+		option_registrations.push(quote! {
+			metadata.set_option("managed".to_string(), "false".to_string());
+		});
+	}
+	if model_config.proxy {
+		option_registrations.push(quote! {
+			metadata.set_option("proxy".to_string(), "true".to_string());
+		});
+	}
+	// The model's doc comment becomes the table comment (`COMMENT ON TABLE`)
+	if let Some(ref comment) = model_config.table_comment {
+		option_registrations.push(quote! {
+			metadata.set_option("db_table_comment".to_string(), #comment.to_string());
+		});
+	}
+
 	let code = quote! {
 		#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
 		#[::ctor::ctor]
@@ -3145,6 +3650,7 @@ fn generate_registration_code(
 			#(#fk_id_registrations)*
 			#(#m2m_registrations)*
 			#(#constraint_registrations)*
+			#(#option_registrations)*
 
 			#migrations_crate::model_registry::global_registry().register_model(metadata);
 
@@ -3163,74 +3669,442 @@ fn generate_registration_code(
 	Ok(code)
 }
 
-/// Generate relationship registration code for RELATIONSHIPS registry
+/// Generate personal data metadata and its PERSONAL_DATA_MODELS registration
 ///
-/// This function scans all fields in the model and detects relationship fields
-/// (ForeignKeyField, OneToOneField, ManyToManyField) automatically, then generates
-/// linkme distributed_slice registration code for each relationship.
-///
-/// For ForeignKey and OneToOne fields with `related_name`, this also generates
-/// reverse relationship registrations for building reverse accessors at runtime.
-///
-/// # Arguments
-///
-/// * `struct_name` - The name of the model struct
-/// * `app_label` - The app label for the model
-/// * `field_infos` - All field information including relationship fields
-/// * `fk_field_infos` - Extracted ForeignKey field information
-///
-/// # Returns
-///
-/// TokenStream containing linkme distributed_slice registrations for all relationships
-fn generate_relationship_registrations(
+/// Returns the `data_subject_field()` / `personal_data_fields()` overrides and,
+/// for non-generic models declaring `data_subject`, a linkme registration so
+/// that user data export and anonymization find the model.
+fn generate_personal_data(
 	struct_name: &syn::Ident,
-	app_label: &str,
+	generics: &syn::Generics,
+	model_config: &ModelConfig,
 	field_infos: &[FieldInfo],
-	fk_field_infos: &[ForeignKeyFieldInfo],
-) -> TokenStream {
-	let reinhardt = get_reinhardt_crate();
-	let _orm_crate = get_reinhardt_orm_crate();
-	// Fixes #793: Use dynamic crate path resolution instead of hardcoded ::linkme
+) -> Result<(TokenStream, TokenStream)> {
+	let orm_crate = get_reinhardt_orm_crate();
 	let linkme = get_linkme_crate();
-	let mut registrations = Vec::new();
-	let model_name = struct_name.to_string();
-
-	// Process ForeignKey and OneToOne fields
-	for fk_info in fk_field_infos {
-		let field_name = &fk_info.field_name;
-		let field_name_str = field_name.to_string();
-		let is_one_to_one = fk_info.is_one_to_one;
 
-		// Extract target model name from Type
-		let target_model_name = if let Type::Path(type_path) = &fk_info.target_type {
-			type_path
-				.path
-				.segments
-				.last()
-				.map(|seg| seg.ident.to_string())
-				.unwrap_or_else(|| "Unknown".to_string())
-		} else {
-			"Unknown".to_string()
+	let mut field_items = Vec::new();
+	for field_info in field_infos {
+		let Some(strategy) = &field_info.config.personal_data else {
+			continue;
 		};
-
-		// Get related_name from RelAttribute if present
-		let related_name_opt = fk_info.rel_attr.related_name.as_ref();
-		let related_name = related_name_opt
-			.map(|r| quote! { Some(#r) })
-			.unwrap_or(quote! { None });
-
-		// Get db_column from RelAttribute if present, otherwise use "{field_name}_id"
-		let db_column = fk_info
-			.rel_attr
+		let field = field_info.name.to_string();
+		let column = field_info
+			.config
 			.db_column
-			.as_ref()
-			.map(|c| quote! { Some(#c) })
-			.unwrap_or_else(|| {
-				let default_db_column = format!("{}_id", field_name_str);
-				quote! { Some(#default_db_column) }
-			});
-
-		// Determine relationship type
+			.clone()
+			.unwrap_or_else(|| field.clone());
+		let strategy = match strategy.as_str() {
+			"hash" => quote! { #orm_crate::personal_data::AnonymizeStrategy::Hash },
+			"fake" => quote! { #orm_crate::personal_data::AnonymizeStrategy::Fake },
+			_ => quote! { #orm_crate::personal_data::AnonymizeStrategy::Null },
+		};
+		let max_length = match field_info.config.max_length {
+			Some(max_length) => {
+				let max_length = max_length as usize;
+				quote! { Some(#max_length) }
+			}
+			None => quote! { None },
+		};
+		field_items.push(quote! {
+			#orm_crate::personal_data::PersonalDataField {
+				field: #field,
+				column: #column,
+				strategy: #strategy,
+				max_length: #max_length,
+			}
+		});
+	}
+
+	// `data_subject` names a field, its column, or the `_id` column of a
+	// foreign key field; the generated metadata always carries the column.
+	let subject_column = match &model_config.data_subject {
+		Some(subject) => Some(resolve_model_column(
+			struct_name,
+			field_infos,
+			"data_subject",
+			subject,
+		)?),
+		None if !field_items.is_empty() => {
+			return Err(syn::Error::new_spanned(
+				struct_name,
+				"personal_data fields require #[model(data_subject = \"...\")]",
+			));
+		}
+		None => None,
+	};
+
+	let Some(subject_column) = subject_column else {
+		return Ok((quote! {}, quote! {}));
+	};
+
+	let metadata = quote! {
+		fn data_subject_field() -> Option<&'static str> {
+			Some(#subject_column)
+		}
+
+		fn personal_data_fields() -> Vec<#orm_crate::personal_data::PersonalDataField> {
+			vec![#(#field_items),*]
+		}
+	};
+
+	if !generics.params.is_empty() {
+		return Ok((metadata, quote! {}));
+	}
+
+	let static_name = syn::Ident::new(
+		&format!("__PERSONAL_DATA_{}", struct_name.to_string().to_uppercase()),
+		struct_name.span(),
+	);
+	let registration = quote! {
+		#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+		#[#linkme::distributed_slice(#orm_crate::personal_data::PERSONAL_DATA_MODELS)]
+		static #static_name: #orm_crate::personal_data::PersonalDataProvider =
+			#orm_crate::personal_data::PersonalDataModel::of::<#struct_name>;
+	};
+
+	Ok((metadata, registration))
+}
+
+/// Generate retention metadata and its RETENTION_POLICIES registration
+///
+/// Returns the `retention()` override and, for non-generic models declaring
+/// `retain`, a linkme registration so that retention enforcement finds the
+/// model. The period, action and timestamp field are checked here so that
+/// mistakes fail at compile time.
+fn generate_retention(
+	struct_name: &syn::Ident,
+	generics: &syn::Generics,
+	model_config: &ModelConfig,
+	field_infos: &[FieldInfo],
+) -> Result<(TokenStream, TokenStream)> {
+	let orm_crate = get_reinhardt_orm_crate();
+	let linkme = get_linkme_crate();
+
+	let Some(retain) = &model_config.retain else {
+		if model_config.on_expire.is_some() || model_config.retain_by.is_some() {
+			return Err(syn::Error::new_spanned(
+				struct_name,
+				"on_expire and retain_by require #[model(retain = \"...\")]",
+			));
+		}
+		return Ok((quote! {}, quote! {}));
+	};
+
+	let seconds = parse_retention_seconds(retain).ok_or_else(|| {
+		syn::Error::new_spanned(
+			struct_name,
+			format!(
+				"invalid retain period `{}`: expected e.g. \"90d\" (units s, m, h, d, w, y)",
+				retain
+			),
+		)
+	})?;
+
+	let action = match model_config.on_expire.as_deref() {
+		None | Some("delete") => quote! { #orm_crate::retention::ExpireAction::Delete },
+		Some("anonymize") => {
+			if !field_infos.iter().any(|f| f.config.personal_data.is_some()) {
+				return Err(syn::Error::new_spanned(
+					struct_name,
+					"on_expire = anonymize requires #[field(personal_data)] fields",
+				));
+			}
+			quote! { #orm_crate::retention::ExpireAction::Anonymize }
+		}
+		Some(other) => {
+			return Err(syn::Error::new_spanned(
+				struct_name,
+				format!("on_expire must be `delete` or `anonymize`, not `{}`", other),
+			));
+		}
+	};
+
+	// The period starts from `retain_by`, or from the `auto_now_add` field.
+	let timestamp_column = match &model_config.retain_by {
+		Some(retain_by) => resolve_model_column(struct_name, field_infos, "retain_by", retain_by)?,
+		None => field_infos
+			.iter()
+			.find(|f| !f.config.skip && f.config.auto_now_add == Some(true))
+			.map(|f| {
+				f.config
+					.db_column
+					.clone()
+					.unwrap_or_else(|| f.name.to_string())
+			})
+			.ok_or_else(|| {
+				syn::Error::new_spanned(
+					struct_name,
+					"retain requires an auto_now_add field or #[model(retain_by = \"...\")]",
+				)
+			})?,
+	};
+
+	let metadata = quote! {
+		fn retention() -> Option<#orm_crate::retention::Retention> {
+			Some(#orm_crate::retention::Retention {
+				period: ::std::time::Duration::from_secs(#seconds),
+				timestamp_column: #timestamp_column,
+				action: #action,
+			})
+		}
+	};
+
+	if !generics.params.is_empty() {
+		return Ok((metadata, quote! {}));
+	}
+
+	let static_name = syn::Ident::new(
+		&format!(
+			"__RETENTION_POLICY_{}",
+			struct_name.to_string().to_uppercase()
+		),
+		struct_name.span(),
+	);
+	let registration = quote! {
+		#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+		#[#linkme::distributed_slice(#orm_crate::retention::RETENTION_POLICIES)]
+		static #static_name: #orm_crate::retention::RetentionProvider =
+			#orm_crate::retention::RetentionPolicy::of::<#struct_name>;
+	};
+
+	Ok((metadata, registration))
+}
+
+/// Seconds in a retention period such as `"90d"`
+///
+/// Mirrors `parse_retention_period` in the ORM's retention module.
+fn parse_retention_seconds(period: &str) -> Option<u64> {
+	let period = period.trim();
+	let split = period
+		.find(|c: char| !c.is_ascii_digit())
+		.unwrap_or(period.len());
+	let (amount, unit) = period.split_at(split);
+	let amount: u64 = amount.parse().ok().filter(|amount| *amount > 0)?;
+	let unit = match unit {
+		"s" => 1,
+		"m" => 60,
+		"h" => 3_600,
+		"d" => 86_400,
+		"w" => 604_800,
+		"y" => 31_536_000,
+		_ => return None,
+	};
+	Some(amount.saturating_mul(unit))
+}
+
+/// Generate counter field metadata and the registrations maintaining it
+///
+/// Returns the `counter_fields()` override and, for non-generic models, the
+/// COUNTER_MODELS registration of models with counter fields plus one
+/// COUNTED_RELATIONS registration per foreign key with a `related_name`, so
+/// that writes to this model can update the counters of the models it
+/// points to.
+fn generate_counters(
+	struct_name: &syn::Ident,
+	generics: &syn::Generics,
+	field_infos: &[FieldInfo],
+	fk_field_infos: &[ForeignKeyFieldInfo],
+) -> (TokenStream, TokenStream) {
+	let orm_crate = get_reinhardt_orm_crate();
+	let linkme = get_linkme_crate();
+
+	let mut counter_items = Vec::new();
+	for field_info in field_infos {
+		let Some(relation) = &field_info.config.counter_of else {
+			continue;
+		};
+		let field = field_info.name.to_string();
+		let column = field_info
+			.config
+			.db_column
+			.clone()
+			.unwrap_or_else(|| field.clone());
+		counter_items.push(quote! {
+			#orm_crate::counters::CounterField {
+				field: #field,
+				column: #column,
+				relation: #relation,
+			}
+		});
+	}
+
+	let metadata = if counter_items.is_empty() {
+		quote! {}
+	} else {
+		quote! {
+			fn counter_fields() -> Vec<#orm_crate::counters::CounterField> {
+				vec![#(#counter_items),*]
+			}
+		}
+	};
+
+	if !generics.params.is_empty() {
+		return (metadata, quote! {});
+	}
+
+	let model_upper = struct_name.to_string().to_uppercase();
+	let mut registrations = Vec::new();
+	if !counter_items.is_empty() {
+		let static_name = syn::Ident::new(
+			&format!("__COUNTER_MODEL_{}", model_upper),
+			struct_name.span(),
+		);
+		registrations.push(quote! {
+			#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+			#[#linkme::distributed_slice(#orm_crate::counters::COUNTER_MODELS)]
+			static #static_name: #orm_crate::counters::CounterProvider =
+				#orm_crate::counters::CounterModel::of::<#struct_name>;
+		});
+	}
+	for fk_info in fk_field_infos {
+		let Some(related_name) = &fk_info.related_name else {
+			continue;
+		};
+		let static_name = syn::Ident::new(
+			&format!(
+				"__COUNTED_RELATION_{}_{}",
+				model_upper,
+				fk_info.field_name.to_string().to_uppercase()
+			),
+			struct_name.span(),
+		);
+		let column = &fk_info.id_column_name;
+		let target_type = &fk_info.target_type;
+		registrations.push(quote! {
+			#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+			#[#linkme::distributed_slice(#orm_crate::counters::COUNTED_RELATIONS)]
+			static #static_name: #orm_crate::counters::CountedRelationProvider = || {
+				#orm_crate::counters::CountedRelation {
+					table: <#struct_name as #orm_crate::Model>::table_name(),
+					column: #column,
+					related_name: #related_name,
+					target_table: <#target_type as #orm_crate::Model>::table_name(),
+				}
+			};
+		});
+	}
+
+	(metadata, quote! { #(#registrations)* })
+}
+
+/// Resolve a column named by a model attribute such as `data_subject`
+///
+/// The attribute may name a field, its column, or the `_id` column of a
+/// foreign key field; the column is returned in every case.
+fn resolve_model_column(
+	struct_name: &syn::Ident,
+	field_infos: &[FieldInfo],
+	attr: &str,
+	value: &str,
+) -> Result<String> {
+	field_infos
+		.iter()
+		.filter(|f| !f.config.skip)
+		.find_map(|f| {
+			let name = f.name.to_string();
+			let column = f.config.db_column.clone().unwrap_or_else(|| name.clone());
+			if name == value || column == value {
+				Some(column)
+			} else if format!("{}_id", name) == value {
+				Some(value.to_string())
+			} else {
+				None
+			}
+		})
+		.ok_or_else(|| {
+			syn::Error::new_spanned(
+				struct_name,
+				format!("{} refers to unknown field `{}`", attr, value),
+			)
+		})
+}
+
+/// Generate the `tenant_field()` override for `#[model(tenant_field = "...")]`
+fn generate_tenant_field(
+	struct_name: &syn::Ident,
+	model_config: &ModelConfig,
+	field_infos: &[FieldInfo],
+) -> Result<TokenStream> {
+	let Some(tenant_field) = &model_config.tenant_field else {
+		return Ok(quote! {});
+	};
+	let column = resolve_model_column(struct_name, field_infos, "tenant_field", tenant_field)?;
+	Ok(quote! {
+		fn tenant_field() -> Option<&'static str> {
+			Some(#column)
+		}
+	})
+}
+
+/// Generate relationship registration code for RELATIONSHIPS registry
+///
+/// This function scans all fields in the model and detects relationship fields
+/// (ForeignKeyField, OneToOneField, ManyToManyField) automatically, then generates
+/// linkme distributed_slice registration code for each relationship.
+///
+/// For ForeignKey and OneToOne fields with `related_name`, this also generates
+/// reverse relationship registrations for building reverse accessors at runtime.
+///
+/// # Arguments
+///
+/// * `struct_name` - The name of the model struct
+/// * `app_label` - The app label for the model
+/// * `field_infos` - All field information including relationship fields
+/// * `fk_field_infos` - Extracted ForeignKey field information
+///
+/// # Returns
+///
+/// TokenStream containing linkme distributed_slice registrations for all relationships
+fn generate_relationship_registrations(
+	struct_name: &syn::Ident,
+	app_label: &str,
+	field_infos: &[FieldInfo],
+	fk_field_infos: &[ForeignKeyFieldInfo],
+) -> TokenStream {
+	let reinhardt = get_reinhardt_crate();
+	let _orm_crate = get_reinhardt_orm_crate();
+	// Fixes #793: Use dynamic crate path resolution instead of hardcoded ::linkme
+	let linkme = get_linkme_crate();
+	let mut registrations = Vec::new();
+	let model_name = struct_name.to_string();
+
+	// Process ForeignKey and OneToOne fields
+	for fk_info in fk_field_infos {
+		let field_name = &fk_info.field_name;
+		let field_name_str = field_name.to_string();
+		let is_one_to_one = fk_info.is_one_to_one;
+
+		// Extract target model name from Type
+		let target_model_name = if let Type::Path(type_path) = &fk_info.target_type {
+			type_path
+				.path
+				.segments
+				.last()
+				.map(|seg| seg.ident.to_string())
+				.unwrap_or_else(|| "Unknown".to_string())
+		} else {
+			"Unknown".to_string()
+		};
+
+		// Get related_name from RelAttribute if present
+		let related_name_opt = fk_info.rel_attr.related_name.as_ref();
+		let related_name = related_name_opt
+			.map(|r| quote! { Some(#r) })
+			.unwrap_or(quote! { None });
+
+		// Get db_column from RelAttribute if present, otherwise use "{field_name}_id"
+		let db_column = fk_info
+			.rel_attr
+			.db_column
+			.as_ref()
+			.map(|c| quote! { Some(#c) })
+			.unwrap_or_else(|| {
+				let default_db_column = format!("{}_id", field_name_str);
+				quote! { Some(#default_db_column) }
+			});
+
+		// Determine relationship type
 		let relationship_type = if is_one_to_one {
 			quote! { #reinhardt::apps::registry::RelationshipType::OneToOne }
 		} else {
@@ -3339,10 +4213,7 @@ fn generate_relationship_registrations(
 			let through_table = rel
 				.through
 				.as_ref()
-				.map(|t| {
-					let through_str = quote! { #t }.to_string();
-					quote! { Some(#through_str) }
-				})
+				.map(|t| quote! { Some(#t) })
 				.unwrap_or(quote! { None });
 
 			(related_name, through_table, related_name_str)
@@ -3551,24 +4422,24 @@ fn generate_composite_pk_type(struct_name: &syn::Ident, pk_fields: &[&FieldInfo]
 /// - `relationship_metadata()` for Model trait (returns `Vec<RelationInfo>`)
 /// - `__migration_relationships()` for migration system (returns `Vec<RelationshipMetadata>`)
 fn generate_relationship_metadata(
-	rel_fields: &[(Ident, RelAttribute)],
+	rel_fields: &[(Ident, Type, RelAttribute)],
 	_app_label: &str,
-	_struct_name: &Ident,
-) -> TokenStream {
+	struct_name: &Ident,
+) -> Result<TokenStream> {
 	use crate::rel::RelationType;
 	let orm_crate = get_reinhardt_orm_crate();
 
 	if rel_fields.is_empty() {
-		return quote! {
+		return Ok(quote! {
 			fn relationship_metadata() -> Vec<#orm_crate::inspection::RelationInfo> {
 				Vec::new()
 			}
-		};
+		});
 	}
 
 	let relation_info_items: Vec<TokenStream> = rel_fields
 		.iter()
-		.map(|(field_name, rel)| {
+		.map(|(field_name, ty, rel)| {
 			let field_name_str = field_name.to_string();
 
 			// Map RelationType to RelationshipType
@@ -3621,20 +4492,35 @@ fn generate_relationship_metadata(
 			};
 
 			// ManyToMany relationship fields
-			let through_table = rel
-				.through
-				.as_ref()
-				.map_or_else(|| quote! { None }, |t| quote! { Some(#t.to_string()) });
-			let source_field = rel
-				.source_field
-				.as_ref()
-				.map_or_else(|| quote! { None }, |s| quote! { Some(#s.to_string()) });
-			let target_field = rel
-				.target_field
-				.as_ref()
-				.map_or_else(|| quote! { None }, |t| quote! { Some(#t.to_string()) });
+			let (through_table, source_field, target_field) =
+				if let Some(join) = resolve_through_model(struct_name, ty, rel)? {
+					let ThroughModelJoin {
+						model,
+						source_field,
+						target_field,
+					} = join;
+					(
+						quote! { Some(<#model as #orm_crate::Model>::table_name().to_string()) },
+						quote! { Some(#source_field.to_string()) },
+						quote! { Some(#target_field.to_string()) },
+					)
+				} else {
+					let through_table = rel
+						.through
+						.as_ref()
+						.map_or_else(|| quote! { None }, |t| quote! { Some(#t.to_string()) });
+					let source_field = rel
+						.source_field
+						.as_ref()
+						.map_or_else(|| quote! { None }, |s| quote! { Some(#s.to_string()) });
+					let target_field = rel
+						.target_field
+						.as_ref()
+						.map_or_else(|| quote! { None }, |t| quote! { Some(#t.to_string()) });
+					(through_table, source_field, target_field)
+				};
 
-			quote! {
+			Ok(quote! {
 				#orm_crate::inspection::RelationInfo {
 					name: #field_name_str.to_string(),
 					relationship_type: #relationship_type,
@@ -3645,17 +4531,17 @@ fn generate_relationship_metadata(
 					source_field: #source_field,
 					target_field: #target_field,
 				}
-			}
+			})
 		})
-		.collect();
+		.collect::<Result<_>>()?;
 
-	quote! {
+	Ok(quote! {
 		fn relationship_metadata() -> Vec<#orm_crate::inspection::RelationInfo> {
 			vec![
 				#(#relation_info_items),*
 			]
 		}
-	}
+	})
 }
 
 /// Check if a type is Uuid or `Option<Uuid>`.
@@ -3778,6 +4664,94 @@ fn extract_m2m_target_type(ty: &Type) -> Option<&Type> {
 	None
 }
 
+/// Join columns of a ManyToMany relationship backed by a through model.
+struct ThroughModelJoin {
+	/// Path of the through model, e.g. `Membership`
+	model: syn::Path,
+	/// Column on the through table referencing the source model
+	source_field: String,
+	/// Column on the through table referencing the target model
+	target_field: String,
+}
+
+/// Convert a PascalCase model name to snake_case (`UserGroup` -> `user_group`).
+fn model_name_to_snake_case(name: &str) -> String {
+	let mut result = String::with_capacity(name.len() + 4);
+	for (i, ch) in name.chars().enumerate() {
+		if ch.is_ascii_uppercase() {
+			if i > 0 {
+				result.push('_');
+			}
+			result.push(ch.to_ascii_lowercase());
+		} else {
+			result.push(ch);
+		}
+	}
+	result
+}
+
+/// Resolve the through model and join columns of a ManyToMany field.
+///
+/// Returns `None` unless the field is a `ManyToManyField` whose `through`
+/// names a model. Join columns default to the foreign key columns a through
+/// model gets from `ForeignKeyField`s named after the source and target
+/// models (`Membership { user: ForeignKeyField<User>, group: ForeignKeyField<Group> }`
+/// yields `user_id` and `group_id`). Self-referential relationships must name
+/// both columns explicitly since the defaults would collide.
+fn resolve_through_model(
+	struct_name: &Ident,
+	ty: &Type,
+	rel: &RelAttribute,
+) -> Result<Option<ThroughModelJoin>> {
+	let Some(model) = rel.through_model() else {
+		return Ok(None);
+	};
+	let Some(Type::Path(target_path)) = extract_m2m_target_type(ty) else {
+		return Ok(None);
+	};
+	let Some(target_name) = target_path
+		.path
+		.segments
+		.last()
+		.map(|s| s.ident.to_string())
+	else {
+		return Ok(None);
+	};
+
+	let source_name = struct_name.to_string();
+	if source_name == target_name && (rel.source_field.is_none() || rel.target_field.is_none()) {
+		return Err(syn::Error::new(
+			rel.span,
+			"self-referential many_to_many with a through model requires both \
+			 `source_field` and `target_field`",
+		));
+	}
+
+	let source_field = rel
+		.source_field
+		.clone()
+		.unwrap_or_else(|| format!("{}_id", model_name_to_snake_case(&source_name)));
+	let target_field = rel
+		.target_field
+		.clone()
+		.unwrap_or_else(|| format!("{}_id", model_name_to_snake_case(&target_name)));
+	if source_field == target_field {
+		return Err(syn::Error::new(
+			rel.span,
+			format!(
+				"`source_field` and `target_field` of through model must differ (both are `{}`)",
+				source_field
+			),
+		));
+	}
+
+	Ok(Some(ThroughModelJoin {
+		model,
+		source_field,
+		target_field,
+	}))
+}
+
 /// Check if a type is a relationship field type (ForeignKeyField or OneToOneField)
 fn is_relationship_field_type(ty: &Type) -> bool {
 	is_foreign_key_field_type(ty) || is_one_to_one_field_type(ty)
@@ -5439,4 +6413,443 @@ mod tests {
 		assert!(!output_str.contains("pub fn set_id"));
 		assert!(!output_str.contains("pub fn set_created_at"));
 	}
+
+	#[test]
+	fn test_many_to_many_through_model_generates_through_accessor() {
+		let input = quote! {
+			#[model(app_label = "auth", table_name = "users")]
+			pub struct User {
+				#[field(primary_key = true)]
+				pub id: i64,
+				#[rel(many_to_many, through = "Membership")]
+				pub groups: ManyToManyField<User, Group>,
+			}
+		};
+
+		let output = model_derive_impl(syn::parse2(input).unwrap()).unwrap();
+		let output_str = output.to_string();
+
+		assert!(output_str.contains("ThroughAccessor < User , Group , Membership >"));
+		assert!(output_str.contains("< Membership as"));
+		assert!(output_str.contains("\"user_id\""));
+		assert!(output_str.contains("\"group_id\""));
+	}
+
+	#[test]
+	fn test_self_referential_through_model_requires_join_columns() {
+		let input = quote! {
+			#[model(app_label = "auth", table_name = "users")]
+			pub struct User {
+				#[field(primary_key = true)]
+				pub id: i64,
+				#[rel(many_to_many, through = "Friendship")]
+				pub friends: ManyToManyField<User, User>,
+			}
+		};
+
+		let error = model_derive_impl(syn::parse2(input).unwrap()).unwrap_err();
+
+		assert!(error.to_string().contains("source_field"));
+	}
+
+	#[test]
+	fn test_model_base_config_provides_defaults() {
+		let input: DeriveInput = syn::parse_quote! {
+			#[model_config(table_name = "posts")]
+			#[model_base_config(app_label = "blog", audited)]
+			#[model_base_config(app_label = "other")]
+			pub struct Post {
+				#[field(primary_key = true)]
+				pub id: i64,
+			}
+		};
+
+		let config = ModelConfig::from_attrs(&input.attrs, &input.ident).unwrap();
+
+		assert_eq!(config.app_label, "blog");
+		assert_eq!(config.table_name, "posts");
+		assert!(config.audited);
+	}
+
+	#[test]
+	fn test_model_base_config_does_not_override_model_config() {
+		let input: DeriveInput = syn::parse_quote! {
+			#[model_config(app_label = "news", table_name = "posts")]
+			#[model_base_config(app_label = "blog")]
+			pub struct Post {
+				#[field(primary_key = true)]
+				pub id: i64,
+			}
+		};
+
+		let config = ModelConfig::from_attrs(&input.attrs, &input.ident).unwrap();
+
+		assert_eq!(config.app_label, "news");
+	}
+
+	#[test]
+	fn test_managers_generate_queryset_entry_points() {
+		let input = quote! {
+			#[model(
+				app_label = "blog",
+				table_name = "posts",
+				managers(published = PublishedManager, drafts = managers::DraftManager)
+			)]
+			pub struct Post {
+				#[field(primary_key = true)]
+				pub id: i64,
+			}
+		};
+
+		let output = model_derive_impl(syn::parse2(input).unwrap()).unwrap();
+		let output_str = output.to_string();
+
+		assert!(output_str.contains("pub fn published ()"));
+		assert!(output_str.contains("< PublishedManager as"));
+		assert!(output_str.contains("pub fn drafts ()"));
+		assert!(output_str.contains("< managers :: DraftManager as"));
+	}
+
+	#[test]
+	fn test_managers_reject_objects_entry_point() {
+		let input = quote! {
+			#[model(app_label = "blog", table_name = "posts", managers(objects = PublishedManager))]
+			pub struct Post {
+				#[field(primary_key = true)]
+				pub id: i64,
+			}
+		};
+
+		let error = model_derive_impl(syn::parse2(input).unwrap()).unwrap_err();
+
+		assert!(error.to_string().contains("already defined"));
+	}
+
+	#[test]
+	fn test_unmanaged_and_proxy_models_register_options() {
+		let unmanaged = quote! {
+			#[model(app_label = "legacy", table_name = "customers", managed = false)]
+			pub struct Customer {
+				#[field(primary_key = true)]
+				pub id: i64,
+			}
+		};
+		let proxy = quote! {
+			#[model(app_label = "blog", table_name = "posts", proxy)]
+			pub struct PublishedPost {
+				#[field(primary_key = true)]
+				pub id: i64,
+			}
+		};
+
+		let unmanaged_str = model_derive_impl(syn::parse2(unmanaged).unwrap())
+			.unwrap()
+			.to_string();
+		let proxy_str = model_derive_impl(syn::parse2(proxy).unwrap())
+			.unwrap()
+			.to_string();
+
+		assert!(unmanaged_str.contains("\"managed\" . to_string () , \"false\" . to_string ()"));
+		assert!(!unmanaged_str.contains("\"proxy\" . to_string ()"));
+		assert!(proxy_str.contains("\"proxy\" . to_string () , \"true\" . to_string ()"));
+		assert!(!proxy_str.contains("\"managed\" . to_string ()"));
+	}
+
+	#[test]
+	fn test_slug_from_generates_slug_fields() {
+		let input = quote! {
+			#[model(app_label = "blog", table_name = "posts")]
+			pub struct Post {
+				#[field(primary_key = true)]
+				pub id: i64,
+				#[field(max_length = 200)]
+				pub title: String,
+				#[field(max_length = 50, slug_from = "title", unique)]
+				pub slug: String,
+			}
+		};
+
+		let output = model_derive_impl(syn::parse2(input).unwrap())
+			.unwrap()
+			.to_string();
+
+		assert!(output.contains("fn slug_fields ()"));
+		assert!(output.contains("source : \"title\""));
+		assert!(output.contains("unique : true"));
+		assert!(output.contains("max_length : Some (50usize)"));
+	}
+
+	#[test]
+	fn test_slug_from_rejects_unknown_field() {
+		let input = quote! {
+			#[model(app_label = "blog", table_name = "posts")]
+			pub struct Post {
+				#[field(primary_key = true)]
+				pub id: i64,
+				#[field(max_length = 50, slug_from = "headline")]
+				pub slug: String,
+			}
+		};
+
+		let error = model_derive_impl(syn::parse2(input).unwrap()).unwrap_err();
+
+		assert!(error.to_string().contains("unknown field `headline`"));
+	}
+
+	#[test]
+	fn test_personal_data_generates_metadata_and_registration() {
+		let input = quote! {
+			#[model(app_label = "blog", table_name = "comments", data_subject = "author_id")]
+			pub struct Comment {
+				#[field(primary_key = true)]
+				pub id: i64,
+				pub author_id: i64,
+				#[field(max_length = 2000, personal_data)]
+				pub body: String,
+				#[field(max_length = 254, personal_data = "hash", db_column = "email_address")]
+				pub email: String,
+			}
+		};
+
+		let output = model_derive_impl(syn::parse2(input).unwrap())
+			.unwrap()
+			.to_string();
+
+		assert!(output.contains("fn data_subject_field ()"));
+		assert!(output.contains("Some (\"author_id\")"));
+		assert!(output.contains("AnonymizeStrategy :: Null"));
+		assert!(output.contains("column : \"email_address\""));
+		assert!(output.contains("AnonymizeStrategy :: Hash"));
+		assert!(output.contains("PERSONAL_DATA_MODELS"));
+	}
+
+	#[test]
+	fn test_counter_of_generates_metadata_and_registration() {
+		let input = quote! {
+			#[model(app_label = "blog", table_name = "posts")]
+			pub struct Post {
+				#[field(primary_key = true)]
+				pub id: i64,
+				#[field(counter_of = "comments", db_column = "comments_total")]
+				pub comment_count: i64,
+			}
+		};
+
+		let output = model_derive_impl(syn::parse2(input).unwrap())
+			.unwrap()
+			.to_string();
+
+		assert!(output.contains("fn counter_fields ()"));
+		assert!(output.contains("column : \"comments_total\""));
+		assert!(output.contains("relation : \"comments\""));
+		assert!(output.contains("COUNTER_MODELS"));
+		assert!(!output.contains("COUNTED_RELATIONS"));
+	}
+
+	#[test]
+	fn test_named_foreign_key_registers_counted_relation() {
+		let input = quote! {
+			#[model(app_label = "blog", table_name = "comments")]
+			pub struct Comment {
+				#[field(primary_key = true)]
+				pub id: i64,
+				#[rel(foreign_key, related_name = "comments")]
+				pub post: ForeignKeyField<Post>,
+			}
+		};
+
+		let output = model_derive_impl(syn::parse2(input).unwrap())
+			.unwrap()
+			.to_string();
+
+		assert!(output.contains("COUNTED_RELATIONS"));
+		assert!(output.contains("column : \"post_id\""));
+		assert!(output.contains("< Post as"));
+		assert!(!output.contains("fn counter_fields ()"));
+	}
+
+	#[test]
+	fn test_tenant_field_generates_override() {
+		let input = quote! {
+			#[model(app_label = "billing", table_name = "invoices", tenant_field = "organization")]
+			pub struct Invoice {
+				#[field(primary_key = true)]
+				pub id: i64,
+				#[field(db_column = "organization_id")]
+				pub organization: i64,
+			}
+		};
+
+		let output: syn::File =
+			syn::parse2(model_derive_impl(syn::parse2(input).unwrap()).unwrap()).unwrap();
+		let tenant_field = output
+			.items
+			.iter()
+			.filter_map(|item| match item {
+				syn::Item::Impl(block) => Some(&block.items),
+				_ => None,
+			})
+			.flatten()
+			.find_map(|item| match item {
+				syn::ImplItem::Fn(method) if method.sig.ident == "tenant_field" => Some(method),
+				_ => None,
+			})
+			.unwrap();
+
+		assert_eq!(
+			quote!(#tenant_field).to_string(),
+			quote! {
+				fn tenant_field() -> Option<&'static str> {
+					Some("organization_id")
+				}
+			}
+			.to_string()
+		);
+	}
+
+	#[test]
+	fn test_tenant_field_rejects_unknown_field() {
+		let input = quote! {
+			#[model(app_label = "billing", table_name = "invoices", tenant_field = "tenant_id")]
+			pub struct Invoice {
+				#[field(primary_key = true)]
+				pub id: i64,
+			}
+		};
+
+		let error = model_derive_impl(syn::parse2(input).unwrap()).unwrap_err();
+
+		assert_eq!(
+			error.to_string(),
+			"tenant_field refers to unknown field `tenant_id`"
+		);
+	}
+
+	#[test]
+	fn test_doc_comments_register_database_comments() {
+		let input = quote! {
+			/// Customer invoices.
+			/// One row per billing period.
+			#[model(app_label = "billing", table_name = "invoices")]
+			pub struct Invoice {
+				#[field(primary_key = true)]
+				pub id: i64,
+				/// Total in cents
+				pub total: i64,
+			}
+		};
+
+		let output = model_derive_impl(syn::parse2(input).unwrap())
+			.unwrap()
+			.to_string();
+
+		assert!(output.contains(
+			"set_option (\"db_table_comment\" . to_string () , \"Customer invoices. One row per billing period.\" . to_string ())"
+		));
+		assert!(output.contains("with_param (\"db_comment\" , \"Total in cents\")"));
+	}
+
+	#[test]
+	fn test_personal_data_requires_data_subject() {
+		let input = quote! {
+			#[model(app_label = "blog", table_name = "comments")]
+			pub struct Comment {
+				#[field(primary_key = true)]
+				pub id: i64,
+				#[field(max_length = 2000, personal_data = "fake")]
+				pub body: String,
+			}
+		};
+
+		let error = model_derive_impl(syn::parse2(input).unwrap()).unwrap_err();
+
+		assert!(error.to_string().contains("data_subject"));
+	}
+
+	#[test]
+	fn test_retain_generates_metadata_and_registration() {
+		let input = quote! {
+			#[model(app_label = "shop", table_name = "orders", retain = "90d", on_expire = delete)]
+			pub struct Order {
+				#[field(primary_key = true)]
+				pub id: i64,
+				#[field(auto_now_add = true)]
+				pub created_at: chrono::DateTime<chrono::Utc>,
+			}
+		};
+
+		let output = model_derive_impl(syn::parse2(input).unwrap())
+			.unwrap()
+			.to_string();
+
+		assert!(output.contains("fn retention ()"));
+		assert!(output.contains("from_secs (7776000u64)"));
+		assert!(output.contains("timestamp_column : \"created_at\""));
+		assert!(output.contains("ExpireAction :: Delete"));
+		assert!(output.contains("RETENTION_POLICIES"));
+	}
+
+	#[test]
+	fn test_retain_anonymize_requires_personal_data() {
+		let input = quote! {
+			#[model(app_label = "shop", table_name = "orders", retain = "2y",
+				on_expire = anonymize, retain_by = "placed_at")]
+			pub struct Order {
+				#[field(primary_key = true)]
+				pub id: i64,
+				pub placed_at: chrono::DateTime<chrono::Utc>,
+			}
+		};
+
+		let error = model_derive_impl(syn::parse2(input).unwrap()).unwrap_err();
+
+		assert!(error.to_string().contains("personal_data"));
+	}
+
+	#[test]
+	fn test_retain_rejects_invalid_period() {
+		let input = quote! {
+			#[model(app_label = "shop", table_name = "orders", retain = "3 months")]
+			pub struct Order {
+				#[field(primary_key = true)]
+				pub id: i64,
+				#[field(auto_now_add = true)]
+				pub created_at: chrono::DateTime<chrono::Utc>,
+			}
+		};
+
+		let error = model_derive_impl(syn::parse2(input).unwrap()).unwrap_err();
+
+		assert!(error.to_string().contains("invalid retain period"));
+	}
+
+	#[test]
+	fn test_personal_data_rejects_unknown_strategy() {
+		let input = quote! {
+			#[model(app_label = "blog", table_name = "comments", data_subject = "id")]
+			pub struct Comment {
+				#[field(primary_key = true)]
+				pub id: i64,
+				#[field(max_length = 2000, personal_data = "shred")]
+				pub body: String,
+			}
+		};
+
+		let error = model_derive_impl(syn::parse2(input).unwrap()).unwrap_err();
+
+		assert!(error.to_string().contains("personal_data must be"));
+	}
+
+	#[test]
+	fn test_byte_vec_maps_to_binary_field() {
+		let config = FieldConfig::default();
+		for ty in [parse_quote!(Vec<u8>), parse_quote!(Option<Vec<u8>>)] {
+			let field_type = map_type_to_field_type(&ty, &config).unwrap().to_string();
+			assert!(field_type.ends_with("FieldType :: Binary"), "{field_type}");
+			assert_eq!(
+				field_type_to_metadata_string(&ty, &config).unwrap(),
+				"reinhardt.orm.models.BinaryField"
+			);
+		}
+	}
 }
@@ -117,6 +117,11 @@ pub(crate) fn receiver_impl(args: TokenStream, input: ItemFn) -> Result<TokenStr
 		quote! {}
 	};
 
+	// Record where the receiver is declared for signal introspection
+	let registration_site_setter = quote! {
+		.with_registration_site(::core::file!(), ::core::line!(), ::core::column!())
+	};
+
 	// Build sender type setter
 	let sender_type_setter = if let Some(sender_type) = &args.sender_type {
 		// Generate a function that returns TypeId for the sender type
@@ -208,12 +213,13 @@ pub(crate) fn receiver_impl(args: TokenStream, input: ItemFn) -> Result<TokenStr
 			#inventory::submit! {
 				#signals_crate::ReceiverRegistryEntry::new(
 					#signal_name,
-					#receiver_name,
+					::core::concat!(::core::module_path!(), "::", #receiver_name),
 					#factory_fn,
 				)
 				#dispatch_uid_setter
 				#priority_setter
 				#sender_type_setter
+				#registration_site_setter
 			}
 		};
 
@@ -245,12 +251,13 @@ pub(crate) fn receiver_impl(args: TokenStream, input: ItemFn) -> Result<TokenStr
 			#inventory::submit! {
 				#signals_crate::ReceiverRegistryEntry::new(
 					#signal_name,
-					#receiver_name,
+					::core::concat!(::core::module_path!(), "::", #receiver_name),
 					#factory_fn,
 				)
 				#dispatch_uid_setter
 				#priority_setter
 				#sender_type_setter
+				#registration_site_setter
 			}
 		};
 
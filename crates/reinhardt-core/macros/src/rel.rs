@@ -124,7 +124,10 @@ pub(crate) struct RelAttribute {
 	pub composite: Option<Path>,
 	/// Foreign key field name (for one_to_many)
 	pub foreign_key: Option<String>,
-	/// Through table name (for many_to_many)
+	/// Through model or table name (for many_to_many)
+	///
+	/// A PascalCase value (e.g. `"Membership"`) names a through model, see
+	/// [`RelAttribute::through_model`]; any other value is a raw table name.
 	pub through: Option<String>,
 	/// Source field name in through table (for many_to_many)
 	pub source_field: Option<String>,
@@ -329,6 +332,23 @@ impl RelAttribute {
 		}
 		Ok(())
 	}
+
+	/// Returns the through model named by `through`, if any.
+	///
+	/// `through = "Membership"` or `through = "crate::models::Membership"`
+	/// refers to a model that owns the join table, while a value whose last
+	/// segment is not PascalCase (e.g. `"auth_user_groups"`) is treated as a
+	/// raw table name.
+	pub(crate) fn through_model(&self) -> Option<Path> {
+		let path: Path = syn::parse_str(self.through.as_deref()?).ok()?;
+		let is_type_name = path
+			.segments
+			.last()?
+			.ident
+			.to_string()
+			.starts_with(|c: char| c.is_ascii_uppercase());
+		is_type_name.then_some(path)
+	}
 }
 
 /// Parse a string value from meta.
@@ -385,4 +405,19 @@ mod tests {
 			Some(CascadeAction::SetNull)
 		);
 	}
+
+	#[test]
+	fn test_through_model_distinguishes_models_from_tables() {
+		let rel = |through: &str| RelAttribute {
+			rel_type: RelationType::ManyToMany,
+			through: Some(through.to_string()),
+			..Default::default()
+		};
+
+		let model = rel("crate::models::Membership").through_model().unwrap();
+		assert_eq!(model.segments.last().unwrap().ident, "Membership");
+		assert!(rel("Membership").through_model().is_some());
+		assert!(rel("auth_user_groups").through_model().is_none());
+		assert!(RelAttribute::default().through_model().is_none());
+	}
 }
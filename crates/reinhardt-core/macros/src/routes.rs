@@ -30,11 +30,24 @@ struct RouteOptions {
 	/// (e.g., `Json<T>` derefs to `T`), as validation is performed on the dereferenced value.
 	/// Returns HTTP 400 with JSON error details on validation failure.
 	pre_validate: bool,
+	/// Skip CSRF validation with `csrf_exempt = true` or `#[csrf_exempt]`
+	csrf_exempt: bool,
+}
+
+/// HTTP method route macros that accept the `csrf_exempt` option
+const ROUTE_MACROS: &[&str] = &["get", "post", "put", "patch", "delete"];
+
+/// Returns `true` if the attribute path ends in `ident`
+fn attr_is(attr: &syn::Attribute, ident: &str) -> bool {
+	attr.path()
+		.segments
+		.last()
+		.is_some_and(|segment| segment.ident == ident)
 }
 
 /// Information about parameter extractors
 #[derive(Clone)]
-struct ExtractorInfo {
+pub(crate) struct ExtractorInfo {
 	pat: Box<Pat>,
 	ty: Box<Type>,
 	extractor_name: String,
@@ -70,8 +83,16 @@ fn fn_name_to_view_type(fn_name: &str) -> String {
 	format!("{}View", pascal_case)
 }
 
+/// Check if an attribute is `#[extract]`
+///
+/// Marks a parameter whose type implements `FromRequest` but is not one of
+/// the built-in extractors recognized by name.
+pub(crate) fn is_extract_attr(attr: &syn::Attribute) -> bool {
+	attr.path().is_ident("extract")
+}
+
 /// Detect whether parameters contain extractors
-fn detect_extractors(inputs: &Punctuated<FnArg, Token![,]>) -> Vec<ExtractorInfo> {
+pub(crate) fn detect_extractors(inputs: &Punctuated<FnArg, Token![,]>) -> Vec<ExtractorInfo> {
 	let mut extractors = Vec::new();
 
 	for input in inputs {
@@ -85,16 +106,17 @@ fn detect_extractors(inputs: &Punctuated<FnArg, Token![,]>) -> Vec<ExtractorInfo
 				&& let Some(segment) = type_path.path.segments.last()
 			{
 				let type_name = segment.ident.to_string();
-				if matches!(
-					type_name.as_str(),
-					"Path"
-						| "Json" | "Query" | "Header"
-						| "Cookie" | "Form"
-						| "Body" | "HeaderNamed"
-						| "CookieNamed" | "CookieStruct"
-						| "SessionValue" | "OptionalSessionValue"
-						| "SessionValueNamed"
-				) {
+				if pat_type.attrs.iter().any(is_extract_attr)
+					|| matches!(
+						type_name.as_str(),
+						"Path"
+							| "Json" | "Query" | "Header"
+							| "Cookie" | "Form" | "Body"
+							| "HeaderNamed" | "CookieNamed"
+							| "CookieStruct" | "SessionValue"
+							| "OptionalSessionValue"
+							| "SessionValueNamed" | "CurrentUser"
+					) {
 					extractors.push(ExtractorInfo {
 						pat: pat_type.pat.clone(),
 						ty: pat_type.ty.clone(),
@@ -178,7 +200,7 @@ pub(crate) fn detect_inject_params(inputs: &Punctuated<FnArg, Token![,]>) -> Vec
 }
 
 /// Validate duplication of body-consuming extractors
-fn validate_extractors(extractors: &[ExtractorInfo]) -> Result<()> {
+pub(crate) fn validate_extractors(extractors: &[ExtractorInfo]) -> Result<()> {
 	let body_consuming_types = ["Json", "Form", "Body"];
 	let body_extractors: Vec<_> = extractors
 		.iter()
@@ -358,11 +380,14 @@ fn auth_detection_to_tokens(
 }
 
 /// Generate wrapper function with both extractors and inject params
-fn generate_wrapper_with_both(
+///
+/// Returns the renamed original function and the wrapper body, which expects
+/// the incoming request in a binding named `req`.
+pub(crate) fn generate_wrapper_with_both(
 	original_fn: &ItemFn,
 	extractors: &[ExtractorInfo],
 	inject_params: &[InjectInfo],
-	options: &RouteOptions,
+	pre_validate: bool,
 ) -> (TokenStream, TokenStream) {
 	let di_crate = get_reinhardt_di_crate();
 	let core_crate = get_reinhardt_core_crate();
@@ -387,7 +412,9 @@ fn generate_wrapper_with_both(
 		.map(|arg| {
 			if let FnArg::Typed(pat_type) = arg {
 				let mut pat_type = pat_type.clone();
-				pat_type.attrs.retain(|attr| !is_inject_attr(attr));
+				pat_type
+					.attrs
+					.retain(|attr| !is_inject_attr(attr) && !is_extract_attr(attr));
 				FnArg::Typed(pat_type)
 			} else {
 				arg.clone()
@@ -444,7 +471,7 @@ fn generate_wrapper_with_both(
 		proc_macro2::TokenStream,
 		proc_macro2::TokenStream,
 		Vec<Box<Pat>>,
-	) = if options.pre_validate {
+	) = if pre_validate {
 		// Step 1: Extract into temporary variables
 		let temp_names: Vec<syn::Ident> = extractors
 			.iter()
@@ -603,9 +630,10 @@ fn generate_view_type(
 
 	// Generate wrapper parts
 	let (original_fn, wrapper_body) =
-		generate_wrapper_with_both(input, extractors, inject_params, options);
+		generate_wrapper_with_both(input, extractors, inject_params, options.pre_validate);
 
 	let route_doc = format!("Route: {} {}", method, path);
+	let csrf_exempt_fn = csrf_exempt_fn(options.csrf_exempt);
 
 	// Resolve the reverse name (carries the `!` exemption sigil) and the clean
 	// metadata name, and emit a compile-time kebab-case warning for an explicit
@@ -682,6 +710,8 @@ fn generate_view_type(
 			fn name() -> &'static str {
 				#name_method_value
 			}
+
+			#csrf_exempt_fn
 		}
 
 		#[#async_trait_crate::async_trait]
@@ -869,7 +899,40 @@ fn emit_non_kebab_name_warning(fn_name: &syn::Ident, name: &str) -> TokenStream
 	}
 }
 
-fn route_impl(method: &str, args: TokenStream, input: ItemFn) -> Result<TokenStream> {
+fn route_impl(method: &str, args: TokenStream, mut input: ItemFn) -> Result<TokenStream> {
+	// `#[throttle]` placed below the route macro is consumed here, before the
+	// handler is renamed to `<name>_original`
+	let throttle_submission = crate::throttle::take_throttle_attr(&mut input)?;
+
+	// Likewise `#[validate_request]` is expanded against the public name
+	if let Some(index) = input
+		.attrs
+		.iter()
+		.position(|attr| attr_is(attr, "validate_request"))
+	{
+		let attr = input.attrs.remove(index);
+		let Meta::List(list) = &attr.meta else {
+			return Err(Error::new_spanned(
+				&attr.meta,
+				"expected validate_request arguments, e.g. #[validate_request(body = CreatePost)]",
+			));
+		};
+		let expanded = crate::validate_request::validate_request_impl(list.tokens.clone(), input)?;
+		input = syn::parse2(expanded)?;
+	}
+
+	// View decorators wrap the validated body, so they run before validation
+	let input = crate::decorators::expand_decorator_attrs(input)?;
+
+	let route = expand_route(method, args, input)?;
+
+	Ok(quote! {
+		#throttle_submission
+		#route
+	})
+}
+
+fn expand_route(method: &str, args: TokenStream, mut input: ItemFn) -> Result<TokenStream> {
 	let reinhardt_crate = crate::crate_paths::get_reinhardt_crate();
 	let core_crate = get_reinhardt_core_crate();
 	let http_crate = get_reinhardt_http_crate();
@@ -927,6 +990,19 @@ fn route_impl(method: &str, args: TokenStream, input: ItemFn) -> Result<TokenStr
 										"pre_validate must be a boolean (true or false)",
 									));
 								}
+							} else if path_expr.path.is_ident("csrf_exempt") {
+								if let Expr::Lit(ExprLit {
+									lit: Lit::Bool(bool_lit),
+									..
+								}) = &*assign.right
+								{
+									options.csrf_exempt = bool_lit.value;
+								} else {
+									return Err(Error::new_spanned(
+										&assign.right,
+										"csrf_exempt must be a boolean (true or false)",
+									));
+								}
 							} else if path_expr.path.is_ident("name") {
 								if let Expr::Lit(ExprLit {
 									lit: Lit::Str(str_lit),
@@ -944,7 +1020,7 @@ fn route_impl(method: &str, args: TokenStream, input: ItemFn) -> Result<TokenStr
 								return Err(Error::new_spanned(
 									&path_expr.path,
 									format!(
-										"unknown route option `{}`, expected `use_inject`, `name`, or `pre_validate`",
+										"unknown route option `{}`, expected `use_inject`, `name`, `pre_validate`, or `csrf_exempt`",
 										path_expr.path.get_ident().map_or_else(
 											|| "unknown".to_string(),
 											|id| id.to_string()
@@ -996,6 +1072,12 @@ fn route_impl(method: &str, args: TokenStream, input: ItemFn) -> Result<TokenStr
 		}
 	}
 
+	// `#[csrf_exempt]` placed below the route macro is consumed here
+	if input.attrs.iter().any(|attr| attr_is(attr, "csrf_exempt")) {
+		options.csrf_exempt = true;
+		input.attrs.retain(|attr| !attr_is(attr, "csrf_exempt"));
+	}
+
 	// Detect extractors
 	let extractors = detect_extractors(&input.sig.inputs);
 
@@ -1064,6 +1146,7 @@ fn route_impl(method: &str, args: TokenStream, input: ItemFn) -> Result<TokenStr
 	let original_fn_name = quote::format_ident!("{}_original", fn_name);
 
 	let route_doc = format!("Route: {} {}", method, path_str);
+	let csrf_exempt_fn = csrf_exempt_fn(options.csrf_exempt);
 
 	// Determine if the original function takes a Request parameter
 	let has_request_param = !fn_inputs.is_empty();
@@ -1150,6 +1233,8 @@ fn route_impl(method: &str, args: TokenStream, input: ItemFn) -> Result<TokenStr
 			fn name() -> &'static str {
 				#name_method_value
 			}
+
+			#csrf_exempt_fn
 		}
 
 		#[#async_trait_crate::async_trait]
@@ -1176,6 +1261,55 @@ fn route_impl(method: &str, args: TokenStream, input: ItemFn) -> Result<TokenStr
 	})
 }
 
+/// `EndpointInfo::csrf_exempt` override for exempt routes
+fn csrf_exempt_fn(csrf_exempt: bool) -> TokenStream {
+	if csrf_exempt {
+		quote! {
+			fn csrf_exempt() -> bool {
+				true
+			}
+		}
+	} else {
+		quote! {}
+	}
+}
+
+/// Implementation of the `#[csrf_exempt]` attribute
+///
+/// When placed below a route macro the route macro consumes the attribute, so
+/// this only runs when `#[csrf_exempt]` sits above it. In that case the route
+/// macro's arguments are extended with `csrf_exempt = true`.
+pub(crate) fn csrf_exempt_impl(args: TokenStream, mut input: ItemFn) -> Result<TokenStream> {
+	if !args.is_empty() {
+		return Err(Error::new_spanned(
+			args,
+			"#[csrf_exempt] takes no arguments",
+		));
+	}
+
+	let Some(route_attr) = input
+		.attrs
+		.iter_mut()
+		.find(|attr| ROUTE_MACROS.iter().any(|name| attr_is(attr, name)))
+	else {
+		return Err(Error::new_spanned(
+			&input.sig.ident,
+			"#[csrf_exempt] must be combined with a route macro such as #[post(\"/path\")]",
+		));
+	};
+
+	let Meta::List(list) = &mut route_attr.meta else {
+		return Err(Error::new_spanned(
+			&route_attr.meta,
+			"expected route macro arguments, e.g. #[post(\"/path\")]",
+		));
+	};
+	let tokens = &list.tokens;
+	list.tokens = quote! { #tokens, csrf_exempt = true };
+
+	Ok(quote! { #input })
+}
+
 /// Implementation of GET route macro
 pub(crate) fn get_impl(args: TokenStream, input: ItemFn) -> Result<TokenStream> {
 	route_impl("GET", args, input)
@@ -1336,6 +1470,26 @@ mod url_resolver_tests {
 		assert_eq!(names, vec!["CookieStruct", "Query"]);
 	}
 
+	#[test]
+	fn detect_extractors_includes_current_user_and_marked_params() {
+		use syn::parse_quote;
+
+		let inputs: syn::punctuated::Punctuated<FnArg, Token![,]> = parse_quote! {
+			CurrentUser(user): CurrentUser<User>,
+			#[extract] tenant: Tenant,
+			#[inject] service: Service,
+			plain: Plain
+		};
+
+		let extractors = detect_extractors(&inputs);
+		let names: Vec<_> = extractors
+			.iter()
+			.map(|extractor| extractor.extractor_name.as_str())
+			.collect();
+
+		assert_eq!(names, vec!["CurrentUser", "Tenant"]);
+	}
+
 	#[test]
 	fn detect_auth_marks_current_user_as_protected() {
 		let detection = detect_auth_from_type_strings(&["CurrentUser < User >".to_string()]);
@@ -1346,4 +1500,104 @@ mod url_resolver_tests {
 		));
 		assert!(detection.guard_description.is_none());
 	}
+
+	#[test]
+	fn route_macro_consumes_csrf_exempt_attribute() {
+		let input: ItemFn = syn::parse_quote! {
+			#[csrf_exempt]
+			async fn webhook(req: Request) -> ViewResult<Response> {
+				todo!()
+			}
+		};
+
+		let output = route_impl("POST", quote!("/webhook/"), input)
+			.unwrap()
+			.to_string();
+
+		assert!(output.contains("fn csrf_exempt () -> bool { true }"));
+		assert!(!output.contains("# [csrf_exempt]"));
+	}
+
+	#[test]
+	fn route_macro_consumes_throttle_attribute() {
+		let input: ItemFn = syn::parse_quote! {
+			#[throttle(scope = "burst", rate = "10/min")]
+			async fn webhook(req: Request) -> ViewResult<Response> {
+				todo!()
+			}
+		};
+
+		let output = route_impl("POST", quote!("/webhook/"), input)
+			.unwrap()
+			.to_string();
+
+		assert!(output.contains("ThrottleScopeMetadata"));
+		assert!(output.contains("function_name : stringify ! (webhook)"));
+		assert!(!output.contains("# [throttle"));
+	}
+
+	#[test]
+	fn route_macro_consumes_validate_request_attribute() {
+		let input: ItemFn = syn::parse_quote! {
+			#[validate_request(body = CreatePost)]
+			async fn create_post(req: Request) -> ViewResult<Response> {
+				todo!()
+			}
+		};
+
+		let output = route_impl("POST", quote!("/posts/"), input)
+			.unwrap()
+			.to_string();
+
+		assert!(output.contains("RequestValidationMetadata"));
+		assert!(output.contains("function_name : stringify ! (create_post)"));
+		assert!(!output.contains("# [validate_request"));
+	}
+
+	#[test]
+	fn route_macro_consumes_decorator_attributes() {
+		let input: ItemFn = syn::parse_quote! {
+			#[login_required]
+			#[cache_control(max_age = 60)]
+			async fn account(req: Request) -> ViewResult<Response> {
+				todo!()
+			}
+		};
+
+		let output = route_impl("GET", quote!("/account/"), input)
+			.unwrap()
+			.to_string();
+
+		assert!(output.contains("decorators :: login_required"));
+		assert!(output.contains("patch_cache_control"));
+		assert!(!output.contains("# [login_required"));
+		assert!(!output.contains("# [cache_control"));
+	}
+
+	#[test]
+	fn csrf_exempt_above_route_macro_extends_route_args() {
+		let input: ItemFn = syn::parse_quote! {
+			#[post("/webhook/")]
+			async fn webhook(req: Request) -> ViewResult<Response> {
+				todo!()
+			}
+		};
+
+		let output = csrf_exempt_impl(TokenStream::new(), input)
+			.unwrap()
+			.to_string();
+
+		assert!(output.contains("# [post (\"/webhook/\" , csrf_exempt = true)]"));
+	}
+
+	#[test]
+	fn csrf_exempt_without_route_macro_is_rejected() {
+		let input: ItemFn = syn::parse_quote! {
+			async fn webhook() {}
+		};
+
+		let result = csrf_exempt_impl(TokenStream::new(), input);
+
+		assert!(result.is_err());
+	}
 }
@@ -207,7 +207,7 @@ fn is_option_type(ty: &Type) -> bool {
 }
 
 /// Extract documentation comments from attributes
-fn extract_doc_comment(attrs: &[Attribute]) -> Option<String> {
+pub(crate) fn extract_doc_comment(attrs: &[Attribute]) -> Option<String> {
 	let mut docs = Vec::new();
 
 	for attr in attrs {
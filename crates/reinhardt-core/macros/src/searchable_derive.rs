@@ -0,0 +1,247 @@
+//! Derive macro for search index schemas
+//!
+//! Implements `reinhardt_search::Searchable` from
+//! `#[searchable(index = "...", id = "...")]` on the struct and
+//! `#[search(text | keyword | number | boolean | date, facet, filterable,
+//! sortable, boost = 2.0)]` on the indexed fields.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, LitFloat, LitStr};
+
+use crate::crate_paths::get_reinhardt_search_crate;
+
+const KINDS: [(&str, &str); 5] = [
+	("text", "Text"),
+	("keyword", "Keyword"),
+	("number", "Number"),
+	("boolean", "Boolean"),
+	("date", "Date"),
+];
+
+#[derive(Default)]
+struct FieldOptions {
+	kind: Option<&'static str>,
+	facet: bool,
+	filterable: bool,
+	sortable: bool,
+	boost: Option<LitFloat>,
+}
+
+fn parse_field_options(attr: &syn::Attribute) -> syn::Result<FieldOptions> {
+	let mut options = FieldOptions::default();
+	attr.parse_nested_meta(|meta| {
+		if let Some((_, variant)) = KINDS.iter().find(|(name, _)| meta.path.is_ident(name)) {
+			if options.kind.replace(*variant).is_some() {
+				return Err(meta.error("a field can only have one kind"));
+			}
+			Ok(())
+		} else if meta.path.is_ident("facet") {
+			options.facet = true;
+			Ok(())
+		} else if meta.path.is_ident("filterable") {
+			options.filterable = true;
+			Ok(())
+		} else if meta.path.is_ident("sortable") {
+			options.sortable = true;
+			Ok(())
+		} else if meta.path.is_ident("boost") {
+			options.boost = Some(meta.value()?.parse()?);
+			Ok(())
+		} else {
+			Err(meta.error(
+				"expected `text`, `keyword`, `number`, `boolean`, `date`, `facet`, `filterable`, `sortable`, or `boost = ...`",
+			))
+		}
+	})?;
+	Ok(options)
+}
+
+pub(crate) fn derive_searchable_impl(input: DeriveInput) -> syn::Result<TokenStream> {
+	let name = &input.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+	let search_crate = get_reinhardt_search_crate();
+
+	let fields = match &input.data {
+		Data::Struct(data) => match &data.fields {
+			Fields::Named(fields) => &fields.named,
+			_ => {
+				return Err(syn::Error::new_spanned(
+					&input,
+					"#[derive(Searchable)] requires named fields",
+				));
+			}
+		},
+		_ => {
+			return Err(syn::Error::new_spanned(
+				&input,
+				"#[derive(Searchable)] can only be used on structs",
+			));
+		}
+	};
+
+	let mut index: Option<String> = None;
+	let mut id: Option<String> = None;
+	for attr in input
+		.attrs
+		.iter()
+		.filter(|attr| attr.path().is_ident("searchable"))
+	{
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("index") {
+				index = Some(meta.value()?.parse::<LitStr>()?.value());
+				Ok(())
+			} else if meta.path.is_ident("id") {
+				id = Some(meta.value()?.parse::<LitStr>()?.value());
+				Ok(())
+			} else {
+				Err(meta.error("expected `index = \"...\"` or `id = \"...\"`"))
+			}
+		})?;
+	}
+	let Some(index) = index else {
+		return Err(syn::Error::new_spanned(
+			&input,
+			"#[derive(Searchable)] requires #[searchable(index = \"...\")]",
+		));
+	};
+	let id = id.unwrap_or_else(|| "id".to_string());
+	let id_ident = fields
+		.iter()
+		.filter_map(|field| field.ident.as_ref())
+		.find(|ident| *ident == id.as_str())
+		.ok_or_else(|| {
+			syn::Error::new_spanned(
+				&input,
+				format!("#[derive(Searchable)] id field `{}` does not exist", id),
+			)
+		})?;
+
+	let schema = quote!(#search_crate::schema);
+	let mut search_fields = Vec::new();
+	for field in fields {
+		let Some(attr) = field
+			.attrs
+			.iter()
+			.find(|attr| attr.path().is_ident("search"))
+		else {
+			continue;
+		};
+		let options = parse_field_options(attr)?;
+		let Some(kind) = options.kind else {
+			return Err(syn::Error::new_spanned(
+				attr,
+				"#[search] requires a kind: `text`, `keyword`, `number`, `boolean`, or `date`",
+			));
+		};
+		let kind = syn::Ident::new(kind, proc_macro2::Span::call_site());
+		let key = field
+			.ident
+			.as_ref()
+			.expect("named field")
+			.to_string()
+			.trim_start_matches("r#")
+			.to_string();
+
+		let mut builder = quote! {
+			#schema::SearchField::new(#key, #schema::FieldKind::#kind)
+		};
+		if options.facet {
+			builder = quote!(#builder.facet());
+		}
+		if options.filterable {
+			builder = quote!(#builder.filterable());
+		}
+		if options.sortable {
+			builder = quote!(#builder.sortable());
+		}
+		if let Some(boost) = &options.boost {
+			builder = quote!(#builder.boost(#boost as f32));
+		}
+		search_fields.push(builder);
+	}
+
+	Ok(quote! {
+		impl #impl_generics #schema::Searchable for #name #ty_generics #where_clause {
+			fn schema() -> #schema::IndexSchema {
+				#schema::IndexSchema::new(#index, #id)
+					#(.field(#search_fields))*
+			}
+
+			fn document_id(&self) -> ::std::string::String {
+				::std::string::ToString::to_string(&self.#id_ident)
+			}
+		}
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	fn requires_index() {
+		// Arrange
+		let input: DeriveInput = syn::parse_quote! {
+			struct Article {
+				id: i64,
+			}
+		};
+
+		// Act
+		let result = derive_searchable_impl(input);
+
+		// Assert
+		assert!(result.is_err());
+	}
+
+	#[rstest]
+	fn builds_schema_from_annotated_fields() {
+		// Arrange
+		let input: DeriveInput = syn::parse_quote! {
+			#[searchable(index = "articles")]
+			struct Article {
+				id: i64,
+				#[search(text, boost = 2.0)]
+				title: String,
+				#[search(keyword, facet)]
+				category: String,
+				internal_notes: String,
+			}
+		};
+
+		// Act
+		let output = derive_searchable_impl(input).unwrap().to_string();
+
+		// Assert
+		assert!(output.contains("IndexSchema :: new (\"articles\" , \"id\")"));
+		assert!(output.contains("FieldKind :: Text"));
+		assert!(output.contains(". boost (2.0 as f32)"));
+		assert!(output.contains(". facet ()"));
+		assert!(!output.contains("internal_notes"));
+		assert!(output.contains("self . id"));
+	}
+
+	#[rstest]
+	#[case(quote::quote!(#[search(facet)]))]
+	#[case(quote::quote!(#[search(text, keyword)]))]
+	#[case(quote::quote!(#[search(text, ranked)]))]
+	fn rejects_invalid_field_options(#[case] attr: TokenStream) {
+		// Arrange
+		let input: DeriveInput = syn::parse_quote! {
+			#[searchable(index = "articles")]
+			struct Article {
+				id: i64,
+				#attr
+				title: String,
+			}
+		};
+
+		// Act
+		let result = derive_searchable_impl(input);
+
+		// Assert
+		assert!(result.is_err());
+	}
+}
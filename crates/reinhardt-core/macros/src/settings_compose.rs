@@ -19,13 +19,17 @@ const BUILTIN_FRAGMENTS: &[&str] = &[
 	"ContactSettings",
 	"CorsSettings",
 	"EmailSettings",
+	"FormsSettings",
 	"I18nSettings",
+	"JsonSettings",
 	"LoggingSettings",
 	"MediaSettings",
 	"SecuritySettings",
 	"SessionSettings",
+	"SitesSettings",
 	"StaticSettings",
 	"TemplateSettings",
+	"WellKnownSettings",
 ];
 
 /// Implementation for `#[settings(key: Type)]`.
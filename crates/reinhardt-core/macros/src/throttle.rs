@@ -0,0 +1,260 @@
+//! Throttle scope attribute macro
+
+use crate::crate_paths::{get_inventory_crate, get_reinhardt_core_crate};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+	Error, Expr, ExprLit, Ident, ItemFn, Lit, LitStr, Meta, Result, Token, parse::Parser,
+	punctuated::Punctuated,
+};
+
+/// Parsed `#[throttle(...)]` arguments
+struct ThrottleArgs {
+	scope: LitStr,
+	rate: LitStr,
+	anon_rate: Option<LitStr>,
+	user_rate: Option<LitStr>,
+}
+
+/// Validate a rate string such as `"10/min"` at compile time
+///
+/// Mirrors `reinhardt_throttling::parse_rate`, which cannot be used here
+/// because proc-macro crates cannot depend on runtime crates.
+fn validate_rate(lit: &LitStr) -> Result<()> {
+	let value = lit.value();
+	let valid = value.split_once('/').is_some_and(|(count, period)| {
+		count.trim().parse::<usize>().is_ok()
+			&& matches!(
+				period.trim().to_ascii_lowercase().trim_end_matches('s'),
+				"" | "sec" | "second" | "m" | "min" | "minute" | "h" | "hour" | "d" | "day"
+			)
+	});
+	if valid {
+		Ok(())
+	} else {
+		Err(Error::new(
+			lit.span(),
+			format!(
+				"invalid throttle rate '{}': expected '<count>/<sec|min|hour|day>', e.g. \"10/min\"",
+				value
+			),
+		))
+	}
+}
+
+fn parse_throttle_args(args: TokenStream) -> Result<ThrottleArgs> {
+	let span = proc_macro2::Span::call_site();
+	let meta_list = Punctuated::<Meta, Token![,]>::parse_terminated.parse2(args)?;
+
+	let mut scope = None;
+	let mut rate = None;
+	let mut anon_rate = None;
+	let mut user_rate = None;
+
+	for meta in meta_list {
+		let Meta::NameValue(nv) = &meta else {
+			return Err(Error::new_spanned(
+				&meta,
+				"expected `key = \"value\"` in throttle macro",
+			));
+		};
+		let Expr::Lit(ExprLit {
+			lit: Lit::Str(lit), ..
+		}) = &nv.value
+		else {
+			return Err(Error::new_spanned(
+				&nv.value,
+				"throttle arguments must be string literals",
+			));
+		};
+
+		let slot = if nv.path.is_ident("scope") {
+			&mut scope
+		} else if nv.path.is_ident("rate") {
+			&mut rate
+		} else if nv.path.is_ident("anon_rate") {
+			&mut anon_rate
+		} else if nv.path.is_ident("user_rate") {
+			&mut user_rate
+		} else {
+			return Err(Error::new_spanned(
+				&nv.path,
+				"unknown throttle option, expected `scope`, `rate`, `anon_rate`, or `user_rate`",
+			));
+		};
+		if slot.is_some() {
+			return Err(Error::new_spanned(&nv.path, "duplicate throttle option"));
+		}
+		*slot = Some(lit.clone());
+	}
+
+	let scope = scope.ok_or_else(|| Error::new(span, "throttle macro requires `scope`"))?;
+	let scope_value = scope.value();
+	if scope_value.is_empty()
+		|| scope_value.contains(':')
+		|| scope_value.chars().any(char::is_control)
+	{
+		return Err(Error::new(
+			scope.span(),
+			"throttle scope must be non-empty and must not contain ':' or control characters",
+		));
+	}
+
+	let rate = rate.ok_or_else(|| Error::new(span, "throttle macro requires `rate`"))?;
+	for lit in std::iter::once(&rate).chain(&anon_rate).chain(&user_rate) {
+		validate_rate(lit)?;
+	}
+
+	Ok(ThrottleArgs {
+		scope,
+		rate,
+		anon_rate,
+		user_rate,
+	})
+}
+
+/// Generate the inventory submission registering the handler's throttle scope
+fn throttle_submission(args: &ThrottleArgs, fn_name: &Ident) -> TokenStream {
+	let core_crate = get_reinhardt_core_crate();
+	let inventory_crate = get_inventory_crate();
+	let ThrottleArgs {
+		scope,
+		rate,
+		anon_rate,
+		user_rate,
+	} = args;
+	let anon_rate = match anon_rate {
+		Some(lit) => quote! { Some(#lit) },
+		None => quote! { None },
+	};
+	let user_rate = match user_rate {
+		Some(lit) => quote! { Some(#lit) },
+		None => quote! { None },
+	};
+
+	quote! {
+		#inventory_crate::submit! {
+			#core_crate::endpoint::ThrottleScopeMetadata {
+				scope: #scope,
+				rate: #rate,
+				anon_rate: #anon_rate,
+				user_rate: #user_rate,
+				function_name: stringify!(#fn_name),
+				module_path: module_path!(),
+			}
+		}
+	}
+}
+
+/// Consume a `#[throttle(...)]` attribute placed below a route macro
+///
+/// Route macros rename the handler they wrap, so the attribute is expanded
+/// here against the public handler name instead of being left on the
+/// renamed function. Returns the inventory submission, or nothing if the
+/// attribute is absent.
+pub(crate) fn take_throttle_attr(input: &mut ItemFn) -> Result<TokenStream> {
+	let Some(index) = input.attrs.iter().position(|attr| {
+		attr.path()
+			.segments
+			.last()
+			.is_some_and(|segment| segment.ident == "throttle")
+	}) else {
+		return Ok(quote! {});
+	};
+	let attr = input.attrs.remove(index);
+	let Meta::List(list) = &attr.meta else {
+		return Err(Error::new_spanned(
+			&attr.meta,
+			"expected throttle arguments, e.g. #[throttle(scope = \"burst\", rate = \"10/min\")]",
+		));
+	};
+	let args = parse_throttle_args(list.tokens.clone())?;
+	Ok(throttle_submission(&args, &input.sig.ident))
+}
+
+/// Implementation of the `throttle` attribute macro
+///
+/// Validates the scope and rates at compile time and registers a
+/// `ThrottleScopeMetadata` entry for the handler. The submission is placed
+/// inside the function body so the attribute also works on methods such as
+/// viewset actions, where items cannot be emitted next to the function.
+pub(crate) fn throttle_impl(args: TokenStream, mut input: ItemFn) -> Result<TokenStream> {
+	let args = parse_throttle_args(args)?;
+	let submission = throttle_submission(&args, &input.sig.ident);
+	input.block.stmts.insert(0, syn::parse2(submission)?);
+
+	Ok(quote! { #input })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use syn::parse_quote;
+
+	#[rstest]
+	fn test_throttle_registers_scope_inside_body() {
+		// Arrange
+		let input: ItemFn = parse_quote! {
+			pub async fn list_items(req: Request) -> ViewResult<Response> {
+				todo!()
+			}
+		};
+
+		// Act
+		let output = throttle_impl(
+			quote! { scope = "burst", rate = "10/min", anon_rate = "2/min" },
+			input,
+		)
+		.unwrap()
+		.to_string();
+
+		// Assert
+		assert!(output.contains("ThrottleScopeMetadata"));
+		assert!(output.contains("scope : \"burst\""));
+		assert!(output.contains("anon_rate : Some (\"2/min\")"));
+		assert!(output.contains("user_rate : None"));
+		assert!(output.contains("stringify ! (list_items)"));
+		assert!(output.starts_with("pub async fn list_items"));
+	}
+
+	#[rstest]
+	#[case::missing_rate(quote! { scope = "burst" }, "requires `rate`")]
+	#[case::missing_scope(quote! { rate = "10/min" }, "requires `scope`")]
+	#[case::bad_rate(quote! { scope = "burst", rate = "10/week" }, "invalid throttle rate")]
+	#[case::bad_override(
+		quote! { scope = "burst", rate = "10/min", user_rate = "many" },
+		"invalid throttle rate"
+	)]
+	#[case::bad_scope(quote! { scope = "a:b", rate = "10/min" }, "must not contain ':'")]
+	#[case::unknown_option(quote! { scope = "burst", rate = "10/min", burst = "5" }, "unknown throttle option")]
+	fn test_throttle_rejects_invalid_arguments(#[case] args: TokenStream, #[case] message: &str) {
+		// Arrange
+		let input: ItemFn = parse_quote! {
+			async fn handler() {}
+		};
+
+		// Act
+		let err = throttle_impl(args, input).unwrap_err();
+
+		// Assert
+		assert!(err.to_string().contains(message), "{}", err);
+	}
+
+	#[rstest]
+	fn test_take_throttle_attr_uses_public_handler_name() {
+		// Arrange
+		let mut input: ItemFn = parse_quote! {
+			#[throttle(scope = "upload", rate = "100/day")]
+			#[doc = "Upload a file"]
+			async fn upload_file() {}
+		};
+
+		// Act
+		let submission = take_throttle_attr(&mut input).unwrap().to_string();
+
+		// Assert
+		assert!(submission.contains("stringify ! (upload_file)"));
+		assert_eq!(input.attrs.len(), 1);
+	}
+}
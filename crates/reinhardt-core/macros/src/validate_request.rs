@@ -0,0 +1,246 @@
+//! Request validation attribute macro
+
+use crate::crate_paths::{
+	get_inventory_crate, get_reinhardt_core_crate, get_reinhardt_params_crate,
+};
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{
+	Error, FnArg, Ident, ItemFn, Meta, Pat, Result, Token, Type, parse::Parser,
+	punctuated::Punctuated,
+};
+
+/// Parsed `#[validate_request(...)]` arguments
+struct ValidateRequestArgs {
+	query: Option<Type>,
+	body: Option<Type>,
+}
+
+fn parse_validate_request_args(args: TokenStream) -> Result<ValidateRequestArgs> {
+	let meta_list = Punctuated::<Meta, Token![,]>::parse_terminated.parse2(args)?;
+
+	let mut query = None;
+	let mut body = None;
+
+	for meta in meta_list {
+		let Meta::NameValue(nv) = &meta else {
+			return Err(Error::new_spanned(
+				&meta,
+				"expected `query = Type` or `body = Type` in validate_request macro",
+			));
+		};
+		let slot = if nv.path.is_ident("query") {
+			&mut query
+		} else if nv.path.is_ident("body") {
+			&mut body
+		} else {
+			return Err(Error::new_spanned(
+				&nv.path,
+				"unknown validate_request option, expected `query` or `body`",
+			));
+		};
+		if slot.is_some() {
+			return Err(Error::new_spanned(
+				&nv.path,
+				"duplicate validate_request option",
+			));
+		}
+		*slot = Some(syn::parse2::<Type>(nv.value.to_token_stream())?);
+	}
+
+	if query.is_none() && body.is_none() {
+		return Err(Error::new(
+			proc_macro2::Span::call_site(),
+			"validate_request requires at least one of `query = Type` or `body = Type`",
+		));
+	}
+
+	Ok(ValidateRequestArgs { query, body })
+}
+
+/// Find the `Request` parameter of the handler by type name
+pub(crate) fn find_request_param(input: &ItemFn) -> Option<&Ident> {
+	input.sig.inputs.iter().find_map(|arg| {
+		if let FnArg::Typed(pat_type) = arg
+			&& let Pat::Ident(pat_ident) = &*pat_type.pat
+			&& let Type::Path(type_path) = &*pat_type.ty
+			&& type_path
+				.path
+				.segments
+				.last()
+				.is_some_and(|seg| seg.ident == "Request")
+		{
+			return Some(&pat_ident.ident);
+		}
+		None
+	})
+}
+
+/// Implementation of the `validate_request` attribute macro
+///
+/// Deserializes the declared query and body types from the handler's
+/// `Request` parameter, validates them with the `Validate` trait and binds
+/// them as `query` and `body` before the handler body runs. Validation
+/// failures return `Error::FieldValidation` (422). A
+/// `RequestValidationMetadata` entry is submitted so OpenAPI generation can
+/// document the validated types.
+pub(crate) fn validate_request_impl(args: TokenStream, mut input: ItemFn) -> Result<TokenStream> {
+	let args = parse_validate_request_args(args)?;
+
+	if input.sig.asyncness.is_none() {
+		return Err(Error::new_spanned(
+			&input.sig,
+			"#[validate_request] can only be applied to async functions",
+		));
+	}
+	let Some(request) = find_request_param(&input).cloned() else {
+		return Err(Error::new_spanned(
+			&input.sig,
+			"#[validate_request] requires a Request parameter to extract the query and body from. \
+			 Add a `req: Request` parameter to this function.",
+		));
+	};
+
+	let core_crate = get_reinhardt_core_crate();
+	let params_crate = get_reinhardt_params_crate();
+	let inventory_crate = get_inventory_crate();
+
+	let extract = |binding: &str, extractor: &str, ty: &Type| {
+		let binding = Ident::new(binding, proc_macro2::Span::call_site());
+		let extractor = Ident::new(extractor, proc_macro2::Span::call_site());
+		quote! {
+			let #binding: #ty = <#params_crate::#extractor<#ty> as #params_crate::FromRequest>::from_request(
+				&#request,
+				&__validate_ctx,
+			)
+			.await
+			.map_err(#core_crate::exception::Error::from)?
+			.into_inner();
+			#core_crate::validators::Validate::validate(&#binding)
+				.map_err(|e| #core_crate::exception::Error::field_validation(&e))?;
+		}
+	};
+	let query_extraction = args.query.as_ref().map(|ty| extract("query", "Query", ty));
+	let body_extraction = args.body.as_ref().map(|ty| extract("body", "Json", ty));
+
+	let type_name = |ty: &Option<Type>| match ty {
+		Some(ty) => {
+			let name = ty.to_token_stream().to_string();
+			quote! { Some(#name) }
+		}
+		None => quote! { None },
+	};
+	let query_type = type_name(&args.query);
+	let body_type = type_name(&args.body);
+	let fn_name = &input.sig.ident;
+
+	let fn_block = &input.block;
+	let block = quote! {
+		{
+			#inventory_crate::submit! {
+				#core_crate::endpoint::RequestValidationMetadata {
+					query_type: #query_type,
+					body_type: #body_type,
+					function_name: stringify!(#fn_name),
+					module_path: module_path!(),
+				}
+			}
+
+			let __validate_ctx = #params_crate::ParamContext::with_path_params(
+				#request.path_params.clone(),
+			);
+			#query_extraction
+			#body_extraction
+
+			#fn_block
+		}
+	};
+	input.block = Box::new(syn::parse2(block)?);
+
+	Ok(quote! { #input })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use syn::parse_quote;
+
+	#[rstest]
+	fn test_validate_request_binds_query_and_body() {
+		// Arrange
+		let input: ItemFn = parse_quote! {
+			pub async fn create_post(req: Request) -> ViewResult<Response> {
+				todo!()
+			}
+		};
+
+		// Act
+		let output = validate_request_impl(quote! { query = ListParams, body = CreatePost }, input)
+			.unwrap()
+			.to_string();
+
+		// Assert
+		assert!(output.contains("let query : ListParams"));
+		assert!(output.contains("Query < ListParams >"));
+		assert!(output.contains("let body : CreatePost"));
+		assert!(output.contains("Json < CreatePost >"));
+		assert!(output.contains("field_validation"));
+		assert!(output.contains("RequestValidationMetadata"));
+		assert!(output.contains("query_type : Some (\"ListParams\")"));
+		assert!(output.contains("& req"));
+	}
+
+	#[rstest]
+	fn test_validate_request_body_only_records_no_query() {
+		// Arrange
+		let input: ItemFn = parse_quote! {
+			async fn create_post(request: reinhardt::Request) -> ViewResult<Response> {
+				todo!()
+			}
+		};
+
+		// Act
+		let output = validate_request_impl(quote! { body = CreatePost }, input)
+			.unwrap()
+			.to_string();
+
+		// Assert
+		assert!(output.contains("query_type : None"));
+		assert!(!output.contains("let query"));
+		assert!(output.contains("& request"));
+	}
+
+	#[rstest]
+	#[case::no_request(
+		quote! { body = CreatePost },
+		parse_quote! { async fn handler(id: i64) -> ViewResult<Response> { todo!() } },
+		"requires a Request parameter"
+	)]
+	#[case::not_async(
+		quote! { body = CreatePost },
+		parse_quote! { fn handler(req: Request) -> ViewResult<Response> { todo!() } },
+		"async functions"
+	)]
+	#[case::no_types(
+		quote! {},
+		parse_quote! { async fn handler(req: Request) -> ViewResult<Response> { todo!() } },
+		"at least one of"
+	)]
+	#[case::unknown_option(
+		quote! { form = CreatePost },
+		parse_quote! { async fn handler(req: Request) -> ViewResult<Response> { todo!() } },
+		"unknown validate_request option"
+	)]
+	fn test_validate_request_rejects_invalid_usage(
+		#[case] args: TokenStream,
+		#[case] input: ItemFn,
+		#[case] message: &str,
+	) {
+		// Act
+		let err = validate_request_impl(args, input).unwrap_err();
+
+		// Assert
+		assert!(err.to_string().contains(message), "{}", err);
+	}
+}
@@ -0,0 +1,10 @@
+//! list_editable fields must appear in list_display
+
+use reinhardt_macros::admin;
+
+struct User;
+
+#[admin(model, for = User, name = "User", list_display = [id, username], list_editable = [email])]
+pub struct UserAdmin;
+
+fn main() {}
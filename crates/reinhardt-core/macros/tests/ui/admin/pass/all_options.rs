@@ -12,6 +12,7 @@ struct Profile;
 	list_filter = [created_at, updated_at],
 	ordering = [(created_at, desc)],
 	readonly_fields = [id, created_at, updated_at],
+	list_editable = [bio, location],
 	fields = [user_id, bio, avatar_url, location, website],
 	list_per_page = 50
 )]
@@ -0,0 +1,255 @@
+//! Machine values paired with human-readable labels.
+//!
+//! The [`Choices`] trait associates each variant of a fieldless enum with the
+//! value stored in the database or sent over the wire and with a label shown
+//! to users. Form fields, serializers and the admin build their choice lists
+//! from it, so the mapping is declared once.
+//!
+//! # Usage
+//!
+//! Use `#[derive(Choices)]` to generate the implementation. Values default to
+//! the snake_case variant name and labels to the variant name split into
+//! words:
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Clone, Copy, PartialEq, Choices)]
+//! enum Status {
+//!     Draft,
+//!     #[choice(label = "Under review")]
+//!     InReview,
+//!     #[choice(value = "pub")]
+//!     Published,
+//! }
+//!
+//! assert_eq!(Status::InReview.value(), "in_review");
+//! assert_eq!(Status::Published.label(), "Published");
+//! assert_eq!(Status::label_for("pub").as_deref(), Some("Published"));
+//! ```
+//!
+//! # Translation
+//!
+//! Labels are message IDs. [`Choices::display`] and the other label helpers
+//! pass them through the translator installed with
+//! [`set_label_translator`]; `reinhardt_i18n::install_choice_translator`
+//! installs one backed by the active translation catalog.
+
+use std::sync::OnceLock;
+
+/// Function translating a label into the active language.
+pub type LabelTranslator = fn(&str) -> String;
+
+static LABEL_TRANSLATOR: OnceLock<LabelTranslator> = OnceLock::new();
+
+/// Install the function used to translate choice labels.
+///
+/// Only the first call takes effect; returns `false` if a translator was
+/// already installed.
+pub fn set_label_translator(translator: LabelTranslator) -> bool {
+	LABEL_TRANSLATOR.set(translator).is_ok()
+}
+
+/// Translate `label` with the installed translator.
+///
+/// Returns the label unchanged when no translator is installed.
+pub fn translate_label(label: &str) -> String {
+	match LABEL_TRANSLATOR.get() {
+		Some(translate) => translate(label),
+		None => label.to_string(),
+	}
+}
+
+/// A fixed set of values with human-readable labels.
+///
+/// Usually derived with `#[derive(Choices)]`.
+pub trait Choices: Sized + 'static {
+	/// All choices, in declaration order.
+	fn choices() -> &'static [Self];
+
+	/// The machine value of this choice.
+	fn value(&self) -> &'static str;
+
+	/// The untranslated label of this choice.
+	fn label(&self) -> &'static str;
+
+	/// The choice whose machine value is `value`.
+	fn from_value(value: &str) -> Option<Self>;
+
+	/// The label of this choice in the active language.
+	fn display(&self) -> String {
+		translate_label(self.label())
+	}
+
+	/// The machine values of all choices.
+	fn values() -> Vec<&'static str> {
+		Self::choices().iter().map(Self::value).collect()
+	}
+
+	/// `(value, translated label)` pairs of all choices.
+	///
+	/// This is the shape expected by form, serializer and admin choice lists.
+	fn pairs() -> Vec<(String, String)> {
+		Self::choices()
+			.iter()
+			.map(|choice| (choice.value().to_string(), choice.display()))
+			.collect()
+	}
+
+	/// The translated label of the choice whose machine value is `value`.
+	///
+	/// Equivalent of Django's `get_FOO_display()` for stored raw values.
+	fn label_for(value: &str) -> Option<String> {
+		Self::choices()
+			.iter()
+			.find(|choice| choice.value() == value)
+			.map(Self::display)
+	}
+}
+
+/// Serialize a choice as its machine value.
+///
+/// Use with `#[serde(serialize_with = "reinhardt_core::choices::serialize_value")]`.
+#[cfg(feature = "serde")]
+pub fn serialize_value<C, S>(choice: &C, serializer: S) -> Result<S::Ok, S::Error>
+where
+	C: Choices,
+	S: serde::Serializer,
+{
+	serializer.serialize_str(choice.value())
+}
+
+/// Serialize a choice as its label in the active language.
+///
+/// Use on a display-only field, mirroring Django's `get_FOO_display()`:
+/// `#[serde(serialize_with = "reinhardt_core::choices::serialize_label")]`.
+#[cfg(feature = "serde")]
+pub fn serialize_label<C, S>(choice: &C, serializer: S) -> Result<S::Ok, S::Error>
+where
+	C: Choices,
+	S: serde::Serializer,
+{
+	serializer.serialize_str(&choice.display())
+}
+
+/// Deserialize a choice from its machine value.
+///
+/// Use with `#[serde(deserialize_with = "reinhardt_core::choices::deserialize_value")]`.
+#[cfg(feature = "serde")]
+pub fn deserialize_value<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+where
+	C: Choices,
+	D: serde::Deserializer<'de>,
+{
+	use serde::Deserialize;
+	use serde::de::Error;
+
+	let value = String::deserialize(deserializer)?;
+	C::from_value(&value).ok_or_else(|| {
+		D::Error::custom(format!(
+			"`{}` is not one of: {}",
+			value,
+			C::values().join(", ")
+		))
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[derive(Debug, PartialEq)]
+	enum Size {
+		Small,
+		Large,
+	}
+
+	impl Choices for Size {
+		fn choices() -> &'static [Self] {
+			&[Self::Small, Self::Large]
+		}
+
+		fn value(&self) -> &'static str {
+			match self {
+				Self::Small => "s",
+				Self::Large => "l",
+			}
+		}
+
+		fn label(&self) -> &'static str {
+			match self {
+				Self::Small => "Small",
+				Self::Large => "Large",
+			}
+		}
+
+		fn from_value(value: &str) -> Option<Self> {
+			match value {
+				"s" => Some(Self::Small),
+				"l" => Some(Self::Large),
+				_ => None,
+			}
+		}
+	}
+
+	#[rstest]
+	fn pairs_follow_declaration_order() {
+		// Act
+		let pairs = Size::pairs();
+
+		// Assert
+		assert_eq!(
+			pairs,
+			vec![
+				("s".to_string(), "Small".to_string()),
+				("l".to_string(), "Large".to_string()),
+			]
+		);
+	}
+
+	#[rstest]
+	#[case("l", Some("Large"))]
+	#[case("m", None)]
+	fn label_for_raw_value(#[case] value: &str, #[case] expected: Option<&str>) {
+		// Act
+		let label = Size::label_for(value);
+
+		// Assert
+		assert_eq!(label.as_deref(), expected);
+	}
+
+	#[rstest]
+	fn from_value_round_trips() {
+		// Act
+		let choice = Size::from_value(Size::Small.value());
+
+		// Assert
+		assert_eq!(choice, Some(Size::Small));
+		assert_eq!(Size::values(), vec!["s", "l"]);
+	}
+
+	#[cfg(feature = "serde")]
+	#[rstest]
+	fn serde_helpers_use_value_and_label() {
+		// Arrange
+		#[derive(serde::Serialize, serde::Deserialize)]
+		struct Shirt {
+			#[serde(
+				serialize_with = "serialize_value",
+				deserialize_with = "deserialize_value"
+			)]
+			size: Size,
+		}
+
+		// Act
+		let shirt: Shirt = serde_json::from_str(r#"{"size":"l"}"#).unwrap();
+		let invalid = serde_json::from_str::<Shirt>(r#"{"size":"xl"}"#);
+
+		// Assert
+		assert_eq!(shirt.size, Size::Large);
+		assert_eq!(serde_json::to_string(&shirt).unwrap(), r#"{"size":"l"}"#);
+		assert!(invalid.is_err());
+		let mut label = Vec::new();
+		serialize_label(&Size::Large, &mut serde_json::Serializer::new(&mut label)).unwrap();
+		assert_eq!(label, br#""Large""#);
+	}
+}
@@ -6,8 +6,14 @@
 //! (`#[get]`, `#[post]`, etc.) implement to provide route metadata.
 
 pub mod auth_protection;
+pub mod deprecation;
+pub mod throttle;
+pub mod validation;
 
 pub use auth_protection::{AuthProtection, validate_endpoint_security};
+pub use deprecation::{DeprecationMetadata, deprecation_for};
+pub use throttle::{ThrottleScopeMetadata, throttle_scope_for};
+pub use validation::{RequestValidationMetadata, request_validation_for};
 
 use hyper::Method;
 
@@ -135,4 +141,11 @@ pub trait EndpointInfo: Send + Sync {
 	///
 	/// Example: "get_user"
 	fn name() -> &'static str;
+
+	/// Returns `true` if the endpoint opts out of CSRF validation
+	///
+	/// Set by the `#[csrf_exempt]` attribute on route handlers.
+	fn csrf_exempt() -> bool {
+		false
+	}
 }
@@ -0,0 +1,74 @@
+#![cfg(native)]
+
+//! Per-view deprecation metadata
+//!
+//! The `#[deprecation(since = "...")]` attribute submits a
+//! [`DeprecationMetadata`] entry to the global inventory for every handler it
+//! decorates. The OpenAPI generator uses the entries to mark operations as
+//! deprecated and document their sunset date.
+
+/// Deprecation declared on a handler with `#[deprecation]`.
+///
+/// Dates use the `YYYY-MM-DD` format.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use reinhardt_core::endpoint::DeprecationMetadata;
+///
+/// for entry in inventory::iter::<DeprecationMetadata>() {
+///     println!("{}::{} deprecated since {}", entry.module_path, entry.function_name, entry.since);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeprecationMetadata {
+	/// Date the handler was deprecated (e.g., "2024-01-01").
+	pub since: &'static str,
+	/// Date after which the handler may be removed.
+	pub sunset: Option<&'static str>,
+	/// URL of the replacement endpoint.
+	pub link: Option<&'static str>,
+	/// Name of the handler function.
+	pub function_name: &'static str,
+	/// Module path where the handler is defined.
+	pub module_path: &'static str,
+}
+
+// Register DeprecationMetadata as a collectible type with inventory
+inventory::collect!(DeprecationMetadata);
+
+/// Returns the deprecation declared on the given handler, if any.
+pub fn deprecation_for(
+	module_path: &str,
+	function_name: &str,
+) -> Option<&'static DeprecationMetadata> {
+	inventory::iter::<DeprecationMetadata>()
+		.find(|m| m.module_path == module_path && m.function_name == function_name)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	inventory::submit! {
+		DeprecationMetadata {
+			since: "2024-01-01",
+			sunset: Some("2024-12-31"),
+			link: None,
+			function_name: "legacy_handler",
+			module_path: "app::views",
+		}
+	}
+
+	#[rstest]
+	#[case::registered("legacy_handler", Some("2024-01-01"))]
+	#[case::unknown("missing_handler", None)]
+	fn test_deprecation_for(#[case] function_name: &str, #[case] expected: Option<&str>) {
+		// Act
+		let found = deprecation_for("app::views", function_name);
+
+		// Assert
+		assert_eq!(found.map(|m| m.since), expected);
+	}
+}
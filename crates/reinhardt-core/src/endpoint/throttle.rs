@@ -0,0 +1,131 @@
+#![cfg(native)]
+
+//! Per-view throttle scope metadata
+//!
+//! The `#[throttle(scope = "...", rate = "...")]` attribute submits a
+//! [`ThrottleScopeMetadata`] entry to the global inventory for every handler
+//! it decorates. Throttle implementations use the entries to register scopes,
+//! and the OpenAPI generator uses them to document the limits of each
+//! operation.
+
+/// Throttle scope declared on a handler with `#[throttle]`.
+///
+/// Rates use the `"<count>/<period>"` notation, where the period is one of
+/// `sec`, `min`, `hour` or `day` (e.g. `"10/min"`).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use reinhardt_core::endpoint::ThrottleScopeMetadata;
+///
+/// for scope in inventory::iter::<ThrottleScopeMetadata>() {
+///     println!("{}::{} -> {} ({})", scope.module_path, scope.function_name, scope.scope, scope.rate);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThrottleScopeMetadata {
+	/// Name of the throttle scope (e.g., "burst").
+	pub scope: &'static str,
+	/// Default rate applied to every caller (e.g., "10/min").
+	pub rate: &'static str,
+	/// Rate override for anonymous callers.
+	pub anon_rate: Option<&'static str>,
+	/// Rate override for authenticated callers.
+	pub user_rate: Option<&'static str>,
+	/// Name of the handler function.
+	pub function_name: &'static str,
+	/// Module path where the handler is defined.
+	pub module_path: &'static str,
+}
+
+impl ThrottleScopeMetadata {
+	/// Returns the rate applicable to a caller, preferring the override for
+	/// its user class over the scope's default rate.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::endpoint::ThrottleScopeMetadata;
+	///
+	/// let scope = ThrottleScopeMetadata {
+	///     scope: "burst",
+	///     rate: "10/min",
+	///     anon_rate: Some("5/min"),
+	///     user_rate: None,
+	///     function_name: "list_items",
+	///     module_path: "app::views",
+	/// };
+	/// assert_eq!(scope.rate_for(false), "5/min");
+	/// assert_eq!(scope.rate_for(true), "10/min");
+	/// ```
+	pub fn rate_for(&self, authenticated: bool) -> &'static str {
+		let class_rate = if authenticated {
+			self.user_rate
+		} else {
+			self.anon_rate
+		};
+		class_rate.unwrap_or(self.rate)
+	}
+}
+
+// Register ThrottleScopeMetadata as a collectible type with inventory
+inventory::collect!(ThrottleScopeMetadata);
+
+/// Returns the throttle scope declared on the given handler, if any.
+pub fn throttle_scope_for(
+	module_path: &str,
+	function_name: &str,
+) -> Option<&'static ThrottleScopeMetadata> {
+	inventory::iter::<ThrottleScopeMetadata>()
+		.find(|m| m.module_path == module_path && m.function_name == function_name)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn metadata(
+		anon_rate: Option<&'static str>,
+		user_rate: Option<&'static str>,
+	) -> ThrottleScopeMetadata {
+		ThrottleScopeMetadata {
+			scope: "burst",
+			rate: "10/min",
+			anon_rate,
+			user_rate,
+			function_name: "handler",
+			module_path: "app::views",
+		}
+	}
+
+	#[rstest]
+	#[case::anon_override(Some("5/min"), None, false, "5/min")]
+	#[case::user_override(None, Some("50/min"), true, "50/min")]
+	#[case::anon_falls_back(None, Some("50/min"), false, "10/min")]
+	#[case::user_falls_back(Some("5/min"), None, true, "10/min")]
+	fn test_rate_for_prefers_user_class_override(
+		#[case] anon_rate: Option<&'static str>,
+		#[case] user_rate: Option<&'static str>,
+		#[case] authenticated: bool,
+		#[case] expected: &str,
+	) {
+		// Arrange
+		let scope = metadata(anon_rate, user_rate);
+
+		// Act
+		let rate = scope.rate_for(authenticated);
+
+		// Assert
+		assert_eq!(rate, expected);
+	}
+
+	#[rstest]
+	fn test_throttle_scope_for_unknown_handler() {
+		// Act
+		let found = throttle_scope_for("app::views", "missing_handler");
+
+		// Assert
+		assert!(found.is_none());
+	}
+}
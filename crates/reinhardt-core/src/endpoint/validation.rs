@@ -0,0 +1,47 @@
+#![cfg(native)]
+
+//! Request validation metadata
+//!
+//! The `#[validate_request(query = ..., body = ...)]` attribute submits a
+//! [`RequestValidationMetadata`] entry to the global inventory for every
+//! handler it decorates, so the OpenAPI generator can document the query
+//! parameters, request body and `422` response of the operation.
+
+/// Query and body types validated by `#[validate_request]` on a handler.
+///
+/// Type names are recorded as written in the attribute (e.g.
+/// `"ListParams"` or `"crate :: dto :: ListParams"`); consumers should
+/// compare the last path segment.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use reinhardt_core::endpoint::RequestValidationMetadata;
+///
+/// for entry in inventory::iter::<RequestValidationMetadata>() {
+///     println!("{}: body = {:?}", entry.function_name, entry.body_type);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestValidationMetadata {
+	/// Type deserialized from the query string, if any.
+	pub query_type: Option<&'static str>,
+	/// Type deserialized from the JSON request body, if any.
+	pub body_type: Option<&'static str>,
+	/// Name of the handler function.
+	pub function_name: &'static str,
+	/// Module path where the handler is defined.
+	pub module_path: &'static str,
+}
+
+// Register RequestValidationMetadata as a collectible type with inventory
+inventory::collect!(RequestValidationMetadata);
+
+/// Returns the request validation declared on the given handler, if any.
+pub fn request_validation_for(
+	module_path: &str,
+	function_name: &str,
+) -> Option<&'static RequestValidationMetadata> {
+	inventory::iter::<RequestValidationMetadata>()
+		.find(|m| m.module_path == module_path && m.function_name == function_name)
+}
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use thiserror::Error;
 
 pub mod param_error;
@@ -86,6 +87,26 @@ pub enum Error {
 	#[error("Validation error: {0}")]
 	Validation(String),
 
+	/// Per-field request validation errors (status code: 422)
+	///
+	/// Maps each field name to its error messages. Responses built from this
+	/// variant expose the map as an `errors` object so clients can attach the
+	/// messages to individual inputs.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::exception::Error;
+	/// use std::collections::BTreeMap;
+	///
+	/// let errors = BTreeMap::from([("title".to_string(), vec!["Too short".to_string()])]);
+	/// let error = Error::FieldValidation(errors);
+	/// assert_eq!(error.status_code(), 422);
+	/// assert!(error.to_string().contains("title: Too short"));
+	/// ```
+	#[error("Validation failed: {}", format_field_errors(.0))]
+	FieldValidation(BTreeMap<String, Vec<String>>),
+
 	/// Authentication errors (status code: 401)
 	///
 	/// # Examples
@@ -316,6 +337,14 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 pub use http::StatusCode;
 
+fn format_field_errors(errors: &BTreeMap<String, Vec<String>>) -> String {
+	errors
+		.iter()
+		.flat_map(|(field, messages)| messages.iter().map(move |m| format!("{}: {}", field, m)))
+		.collect::<Vec<_>>()
+		.join(", ")
+}
+
 /// Application-defined error contract for HTTP response mapping.
 ///
 /// Implementations must return client-safe messages. Server-side diagnostic
@@ -338,7 +367,7 @@ pub enum ErrorKind {
 	Database,
 	/// Serialization/deserialization errors (400).
 	Serialization,
-	/// Input validation errors (400).
+	/// Input validation errors (400, or 422 for per-field request validation).
 	Validation,
 	/// Authentication failures (401).
 	Authentication,
@@ -378,6 +407,7 @@ impl Error {
 	/// - `NotFound`, `TemplateNotFound`: 404 (Not Found)
 	/// - `MethodNotAllowed`: 405 (Method Not Allowed)
 	/// - `Conflict`: 409 (Conflict)
+	/// - `FieldValidation`: 422 (Unprocessable Entity)
 	/// - `Database`, `Internal`, `ImproperlyConfigured`, `Other`: 500 (Internal Server Error)
 	///
 	/// # Examples
@@ -418,6 +448,7 @@ impl Error {
 			Error::Database(_) => 500,
 			Error::Serialization(_) => 400,
 			Error::Validation(_) => 400,
+			Error::FieldValidation(_) => 422,
 			Error::Authentication(_) => 401,
 			Error::Authorization(_) => 403,
 			Error::NotFound(_) => 404,
@@ -445,6 +476,7 @@ impl Error {
 			Error::Database(_) => ErrorKind::Database,
 			Error::Serialization(_) => ErrorKind::Serialization,
 			Error::Validation(_) => ErrorKind::Validation,
+			Error::FieldValidation(_) => ErrorKind::Validation,
 			Error::Authentication(_) => ErrorKind::Authentication,
 			Error::Authorization(_) => ErrorKind::Authorization,
 			Error::NotFound(_) => ErrorKind::NotFound,
@@ -504,6 +536,31 @@ impl From<crate::validators::ValidationErrors> for Error {
 	}
 }
 
+#[cfg(feature = "validators")]
+impl Error {
+	/// Builds an [`Error::FieldValidation`] (422) from per-field validation errors.
+	///
+	/// Unlike the `From<ValidationErrors>` conversion, which flattens the
+	/// errors into a 400 message, this keeps the messages grouped by field.
+	/// Non-field errors are grouped under
+	/// [`NON_FIELD_ERRORS`](crate::validators::NON_FIELD_ERRORS).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::exception::Error;
+	/// use reinhardt_core::validators::{ValidationError, ValidationErrors};
+	///
+	/// let mut errors = ValidationErrors::new();
+	/// errors.add("email", ValidationError::InvalidEmail("bad".to_string()));
+	/// let error = Error::field_validation(&errors);
+	/// assert_eq!(error.status_code(), 422);
+	/// ```
+	pub fn field_validation(errors: &crate::validators::ValidationErrors) -> Self {
+		Error::FieldValidation(errors.messages())
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -705,4 +762,24 @@ mod tests {
 		assert!(error.to_string().contains("Missing parameter"));
 		assert!(error.to_string().contains("user_id"));
 	}
+
+	#[test]
+	fn test_field_validation_groups_messages_by_field() {
+		use crate::validators::{ValidationError, ValidationErrors};
+
+		let mut errors = ValidationErrors::new();
+		errors.add("email", ValidationError::InvalidEmail("bad".to_string()));
+		errors.add("name", ValidationError::TooShort { length: 0, min: 1 });
+
+		let error = Error::field_validation(&errors);
+
+		assert_eq!(error.status_code(), 422);
+		assert_eq!(error.kind(), ErrorKind::Validation);
+		let Error::FieldValidation(fields) = &error else {
+			panic!("expected FieldValidation, got {:?}", error);
+		};
+		assert_eq!(fields.len(), 2);
+		assert_eq!(fields["email"], vec!["Invalid email: bad".to_string()]);
+		assert!(error.to_string().starts_with("Validation failed: email: "));
+	}
 }
@@ -0,0 +1,227 @@
+//! CSS and JavaScript assets required by forms and widgets
+//!
+//! Mirrors Django's `Form.Media`: fields declare the stylesheets and scripts
+//! their widgets need (e.g. a date-picker script), and the form collects them
+//! into one deduplicated [`Media`] that the page injects into its `<head>`
+//! during SSR or loads on the client. Like [`form_renderer`], it is shared
+//! by `reinhardt-forms` and the `form!` macro of `reinhardt-pages`, so it is
+//! available on every target.
+//!
+//! [`form_renderer`]: crate::form_renderer
+
+use crate::form_renderer::escape;
+
+/// Stylesheets and scripts required by a form or widget
+///
+/// Assets keep their declaration order; adding an asset that is already
+/// present is a no-op, so merging the media of several fields never emits
+/// the same `<link>` or `<script>` twice.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_core::form_media::Media;
+///
+/// let mut media = Media::new();
+/// media.add_css("/static/css/datepicker.css");
+/// media.add_js("/static/js/datepicker.js");
+/// media.add_js("/static/js/datepicker.js");
+///
+/// assert_eq!(media.js().len(), 1);
+/// assert_eq!(
+///     media.render_js(),
+///     r#"<script src="/static/js/datepicker.js"></script>"#
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Media {
+	/// CSS resources
+	#[cfg_attr(feature = "serde", serde(default))]
+	css: Vec<String>,
+	/// JavaScript resources
+	#[cfg_attr(feature = "serde", serde(default))]
+	js: Vec<String>,
+}
+
+impl Media {
+	/// Create a new empty Media instance
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Add a CSS resource unless it is already present
+	pub fn add_css(&mut self, css: impl Into<String>) {
+		let css = css.into();
+		if !self.css.contains(&css) {
+			self.css.push(css);
+		}
+	}
+
+	/// Add a JavaScript resource unless it is already present
+	pub fn add_js(&mut self, js: impl Into<String>) {
+		let js = js.into();
+		if !self.js.contains(&js) {
+			self.js.push(js);
+		}
+	}
+
+	/// Builder variant of [`add_css`](Self::add_css)
+	pub fn with_css(mut self, css: impl Into<String>) -> Self {
+		self.add_css(css);
+		self
+	}
+
+	/// Builder variant of [`add_js`](Self::add_js)
+	pub fn with_js(mut self, js: impl Into<String>) -> Self {
+		self.add_js(js);
+		self
+	}
+
+	/// Get CSS resources
+	pub fn css(&self) -> &[String] {
+		&self.css
+	}
+
+	/// Get JavaScript resources
+	pub fn js(&self) -> &[String] {
+		&self.js
+	}
+
+	/// Check if media is empty
+	pub fn is_empty(&self) -> bool {
+		self.css.is_empty() && self.js.is_empty()
+	}
+
+	/// Merge another Media instance into this one, skipping duplicates
+	pub fn extend(&mut self, other: &Media) {
+		for css in &other.css {
+			self.add_css(css.as_str());
+		}
+		for js in &other.js {
+			self.add_js(js.as_str());
+		}
+	}
+
+	/// Render CSS link tags
+	///
+	/// All paths are HTML-escaped to prevent XSS attacks.
+	pub fn render_css(&self) -> String {
+		self.css
+			.iter()
+			.map(|path| format!(r#"<link rel="stylesheet" href="{}">"#, escape(path)))
+			.collect::<Vec<_>>()
+			.join("\n")
+	}
+
+	/// Render JavaScript script tags
+	///
+	/// All paths are HTML-escaped to prevent XSS attacks.
+	pub fn render_js(&self) -> String {
+		self.js
+			.iter()
+			.map(|path| format!(r#"<script src="{}"></script>"#, escape(path)))
+			.collect::<Vec<_>>()
+			.join("\n")
+	}
+
+	/// Render CSS link tags followed by script tags
+	pub fn render(&self) -> String {
+		[self.render_css(), self.render_js()]
+			.into_iter()
+			.filter(|html| !html.is_empty())
+			.collect::<Vec<_>>()
+			.join("\n")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_media_creation() {
+		let media = Media::new();
+		assert!(media.is_empty());
+	}
+
+	#[test]
+	fn test_media_add_resources() {
+		let mut media = Media::new();
+		media.add_css("/static/css/forms.css");
+		media.add_js("/static/js/forms.js");
+
+		assert!(!media.is_empty());
+		assert_eq!(media.css(), &["/static/css/forms.css".to_string()]);
+		assert_eq!(media.js(), &["/static/js/forms.js".to_string()]);
+	}
+
+	#[test]
+	fn test_media_render_css() {
+		let mut media = Media::new();
+		media.add_css("/static/css/forms.css");
+		media.add_css("/static/css/widgets.css");
+
+		let rendered = media.render_css();
+		assert!(rendered.contains(r#"<link rel="stylesheet" href="/static/css/forms.css">"#));
+		assert!(rendered.contains(r#"<link rel="stylesheet" href="/static/css/widgets.css">"#));
+	}
+
+	#[test]
+	fn test_media_render_js() {
+		let mut media = Media::new();
+		media.add_js("/static/js/forms.js");
+
+		let rendered = media.render_js();
+		assert_eq!(rendered, r#"<script src="/static/js/forms.js"></script>"#);
+	}
+
+	#[test]
+	fn test_media_extend() {
+		let mut media1 = Media::new();
+		media1.add_css("/static/css/base.css");
+
+		let mut media2 = Media::new();
+		media2.add_css("/static/css/extra.css");
+		media2.add_js("/static/js/extra.js");
+
+		media1.extend(&media2);
+
+		assert_eq!(media1.css().len(), 2);
+		assert_eq!(media1.js().len(), 1);
+	}
+
+	#[test]
+	fn test_media_extend_deduplicates_in_order() {
+		let mut media1 = Media::new()
+			.with_js("/static/js/jquery.js")
+			.with_js("/static/js/datepicker.js");
+		let media2 = Media::new()
+			.with_js("/static/js/jquery.js")
+			.with_js("/static/js/colorpicker.js");
+
+		media1.extend(&media2);
+
+		assert_eq!(
+			media1.js(),
+			&[
+				"/static/js/jquery.js".to_string(),
+				"/static/js/datepicker.js".to_string(),
+				"/static/js/colorpicker.js".to_string(),
+			]
+		);
+	}
+
+	#[test]
+	fn test_media_render_escapes_paths() {
+		let mut media = Media::new();
+		// Malicious paths that could break out of the href/src attributes
+		media.add_css("\"><script>alert('xss')</script>");
+		media.add_js("\"><script>alert('xss')</script>");
+
+		let rendered = media.render();
+		assert!(!rendered.contains("<script>alert"));
+		assert!(rendered.contains("&lt;script&gt;"));
+		assert!(rendered.contains("&quot;"));
+	}
+}
@@ -0,0 +1,383 @@
+//! Pluggable rendering of form fields.
+//!
+//! A [`FormRenderer`] decides which CSS classes each widget gets and how a
+//! field's label, input, help text and errors are laid out. Both the
+//! `form!` macro of `reinhardt-pages` and `reinhardt-forms` ask the renderer
+//! installed with [`set_form_renderer`], so a project switches every form
+//! from the default `reinhardt-*` classes to Bootstrap or Tailwind in one
+//! place.
+//!
+//! Widgets are identified by their `form!` name (`TextInput`, `Textarea`,
+//! `Select`, `CheckboxInput`, ...).
+//!
+//! # Usage
+//!
+//! ```
+//! use reinhardt_core::form_renderer::{BootstrapRenderer, FormRenderer};
+//!
+//! let classes = BootstrapRenderer.classes("Select");
+//! assert_eq!(classes.input, "form-select");
+//! assert_eq!(BootstrapRenderer.classes("CheckboxInput").input, "form-check-input");
+//! ```
+//!
+//! A project-specific renderer overrides [`FormRenderer::classes`] and,
+//! for a different layout, [`FormRenderer::render_field`]:
+//!
+//! ```rust,ignore
+//! struct MyRenderer;
+//!
+//! impl FormRenderer for MyRenderer {
+//!     fn name(&self) -> &'static str {
+//!         "my-renderer"
+//!     }
+//!
+//!     fn classes(&self, widget: &str) -> WidgetClasses {
+//!         WidgetClasses { input: "my-input", ..DefaultRenderer.classes(widget) }
+//!     }
+//! }
+//!
+//! reinhardt_core::form_renderer::set_form_renderer(Box::new(MyRenderer));
+//! ```
+
+use std::sync::OnceLock;
+
+/// CSS classes of the elements of a rendered field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WidgetClasses {
+	/// Element wrapping the label, input, help text and errors
+	pub wrapper: &'static str,
+	/// The `<label>` element
+	pub label: &'static str,
+	/// The input, select or textarea element
+	pub input: &'static str,
+	/// Element listing validation errors
+	pub error: &'static str,
+	/// Element holding the help text
+	pub help: &'static str,
+}
+
+/// A field ready to be laid out by [`FormRenderer::render_field`].
+///
+/// `input_html` is already rendered and escaped; the other values are plain
+/// text and escaped by the renderer.
+#[derive(Debug, Clone, Copy)]
+pub struct FieldContext<'a> {
+	/// Widget name, e.g. `TextInput`
+	pub widget: &'a str,
+	/// `id` attribute of the input, referenced by the label
+	pub id: &'a str,
+	/// Label text, if the field has one
+	pub label: Option<&'a str>,
+	/// Rendered input element
+	pub input_html: &'a str,
+	/// Help text, if any
+	pub help_text: Option<&'a str>,
+	/// Validation errors
+	pub errors: &'a [String],
+	/// Whether the field is required
+	pub required: bool,
+}
+
+/// Decides the classes and layout of rendered form fields.
+pub trait FormRenderer: Send + Sync {
+	/// Name under which the renderer is selected in settings
+	fn name(&self) -> &'static str;
+
+	/// Classes of the elements of a field using `widget`
+	fn classes(&self, widget: &str) -> WidgetClasses;
+
+	/// Class of the `<form>` element
+	fn form_class(&self) -> &'static str {
+		"reinhardt-form"
+	}
+
+	/// Lay out a field: label, input, help text and errors.
+	///
+	/// Hidden inputs are rendered without wrapper or label.
+	fn render_field(&self, field: &FieldContext<'_>) -> String {
+		render_field_layout(&self.classes(field.widget), field, false)
+	}
+}
+
+/// Renderer using the `reinhardt-*` classes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultRenderer;
+
+impl FormRenderer for DefaultRenderer {
+	fn name(&self) -> &'static str {
+		"default"
+	}
+
+	fn classes(&self, _widget: &str) -> WidgetClasses {
+		WidgetClasses {
+			wrapper: "reinhardt-field",
+			label: "reinhardt-label",
+			input: "reinhardt-input",
+			error: "reinhardt-error",
+			help: "reinhardt-help",
+		}
+	}
+}
+
+/// Renderer using Bootstrap 5 form classes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BootstrapRenderer;
+
+impl FormRenderer for BootstrapRenderer {
+	fn name(&self) -> &'static str {
+		"bootstrap"
+	}
+
+	fn classes(&self, widget: &str) -> WidgetClasses {
+		let base = WidgetClasses {
+			wrapper: "mb-3",
+			label: "form-label",
+			input: "form-control",
+			error: "invalid-feedback d-block",
+			help: "form-text",
+		};
+		match widget {
+			"CheckboxInput" | "RadioInput" | "RadioSelect" => WidgetClasses {
+				wrapper: "mb-3 form-check",
+				label: "form-check-label",
+				input: "form-check-input",
+				..base
+			},
+			"Select" | "SelectMultiple" => WidgetClasses {
+				input: "form-select",
+				..base
+			},
+			"RangeInput" => WidgetClasses {
+				input: "form-range",
+				..base
+			},
+			"ColorInput" => WidgetClasses {
+				input: "form-control form-control-color",
+				..base
+			},
+			_ => base,
+		}
+	}
+
+	fn form_class(&self) -> &'static str {
+		"needs-validation"
+	}
+
+	fn render_field(&self, field: &FieldContext<'_>) -> String {
+		render_field_layout(
+			&self.classes(field.widget),
+			field,
+			is_choice_input(field.widget),
+		)
+	}
+}
+
+/// Renderer using Tailwind CSS utility classes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TailwindRenderer;
+
+impl FormRenderer for TailwindRenderer {
+	fn name(&self) -> &'static str {
+		"tailwind"
+	}
+
+	fn classes(&self, widget: &str) -> WidgetClasses {
+		let base = WidgetClasses {
+			wrapper: "mb-4",
+			label: "block mb-1 text-sm font-medium text-gray-700",
+			input: "block w-full rounded-md border border-gray-300 px-3 py-2 shadow-sm \
+				focus:border-indigo-500 focus:outline-none focus:ring-1 focus:ring-indigo-500",
+			error: "mt-1 text-sm text-red-600",
+			help: "mt-1 text-sm text-gray-500",
+		};
+		if is_choice_input(widget) {
+			WidgetClasses {
+				wrapper: "mb-4 flex items-center gap-2",
+				label: "text-sm text-gray-700",
+				input: "h-4 w-4 rounded border-gray-300 text-indigo-600 focus:ring-indigo-500",
+				..base
+			}
+		} else {
+			base
+		}
+	}
+
+	fn form_class(&self) -> &'static str {
+		"space-y-4"
+	}
+
+	fn render_field(&self, field: &FieldContext<'_>) -> String {
+		render_field_layout(
+			&self.classes(field.widget),
+			field,
+			is_choice_input(field.widget),
+		)
+	}
+}
+
+/// Whether the widget is a checkbox or radio, laid out input first.
+fn is_choice_input(widget: &str) -> bool {
+	matches!(widget, "CheckboxInput" | "RadioInput" | "RadioSelect")
+}
+
+/// Built-in renderer named `name` (`default`, `bootstrap` or `tailwind`).
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_core::form_renderer::renderer_by_name;
+///
+/// assert_eq!(renderer_by_name("bootstrap").unwrap().name(), "bootstrap");
+/// assert!(renderer_by_name("foundation").is_none());
+/// ```
+pub fn renderer_by_name(name: &str) -> Option<Box<dyn FormRenderer>> {
+	match name.to_ascii_lowercase().as_str() {
+		"default" | "reinhardt" => Some(Box::new(DefaultRenderer)),
+		"bootstrap" => Some(Box::new(BootstrapRenderer)),
+		"tailwind" => Some(Box::new(TailwindRenderer)),
+		_ => None,
+	}
+}
+
+static FORM_RENDERER: OnceLock<Box<dyn FormRenderer>> = OnceLock::new();
+
+/// Install the renderer used by every form.
+///
+/// Only the first call takes effect; returns `false` if a renderer was
+/// already installed.
+pub fn set_form_renderer(renderer: Box<dyn FormRenderer>) -> bool {
+	FORM_RENDERER.set(renderer).is_ok()
+}
+
+/// The installed renderer, or [`DefaultRenderer`] when none was installed.
+pub fn form_renderer() -> &'static dyn FormRenderer {
+	match FORM_RENDERER.get() {
+		Some(renderer) => renderer.as_ref(),
+		None => &DefaultRenderer,
+	}
+}
+
+/// Lay out a field, with the input before the label when `input_first`.
+fn render_field_layout(
+	classes: &WidgetClasses,
+	field: &FieldContext<'_>,
+	input_first: bool,
+) -> String {
+	if field.widget == "HiddenInput" {
+		return field.input_html.to_string();
+	}
+
+	let label = field
+		.label
+		.map(|label| {
+			let marker = if field.required {
+				" <span aria-hidden=\"true\">*</span>"
+			} else {
+				""
+			};
+			format!(
+				"<label for=\"{}\" class=\"{}\">{}{}</label>",
+				escape(field.id),
+				classes.label,
+				escape(label),
+				marker
+			)
+		})
+		.unwrap_or_default();
+
+	let mut html = format!("<div class=\"{}\">", classes.wrapper);
+	if input_first {
+		html.push_str(field.input_html);
+		html.push_str(&label);
+	} else {
+		html.push_str(&label);
+		html.push_str(field.input_html);
+	}
+	if let Some(help_text) = field.help_text {
+		html.push_str(&format!(
+			"<div class=\"{}\">{}</div>",
+			classes.help,
+			escape(help_text)
+		));
+	}
+	if !field.errors.is_empty() {
+		html.push_str(&format!("<ul class=\"{}\">", classes.error));
+		for error in field.errors {
+			html.push_str(&format!("<li>{}</li>", escape(error)));
+		}
+		html.push_str("</ul>");
+	}
+	html.push_str("</div>");
+	html
+}
+
+pub(crate) fn escape(text: &str) -> String {
+	text.replace('&', "&amp;")
+		.replace('<', "&lt;")
+		.replace('>', "&gt;")
+		.replace('"', "&quot;")
+		.replace('\'', "&#x27;")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn field<'a>(widget: &'a str, errors: &'a [String]) -> FieldContext<'a> {
+		FieldContext {
+			widget,
+			id: "id_name",
+			label: Some("Name"),
+			input_html: "<input name=\"name\" />",
+			help_text: Some("Your <full> name"),
+			errors,
+			required: false,
+		}
+	}
+
+	#[rstest]
+	fn test_default_renderer_layout() {
+		// Arrange
+		let errors = vec!["Required".to_string()];
+
+		// Act
+		let html = DefaultRenderer.render_field(&field("TextInput", &errors));
+
+		// Assert
+		assert_eq!(
+			html,
+			"<div class=\"reinhardt-field\">\
+			<label for=\"id_name\" class=\"reinhardt-label\">Name</label>\
+			<input name=\"name\" />\
+			<div class=\"reinhardt-help\">Your &lt;full&gt; name</div>\
+			<ul class=\"reinhardt-error\"><li>Required</li></ul>\
+			</div>"
+		);
+	}
+
+	#[rstest]
+	#[case::text("TextInput", false)]
+	#[case::checkbox("CheckboxInput", true)]
+	fn test_bootstrap_renders_checkboxes_input_first(
+		#[case] widget: &str,
+		#[case] input_first: bool,
+	) {
+		// Act
+		let html = BootstrapRenderer.render_field(&field(widget, &[]));
+
+		// Assert
+		let input = html.find("<input").unwrap();
+		let label = html.find("<label").unwrap();
+		assert_eq!(input < label, input_first);
+	}
+
+	#[rstest]
+	fn test_hidden_inputs_have_no_wrapper() {
+		// Act
+		let html = TailwindRenderer.render_field(&field("HiddenInput", &[]));
+
+		// Assert
+		assert_eq!(html, "<input name=\"name\" />");
+	}
+}
@@ -39,7 +39,7 @@
 //! - [`exception`]: Typed error hierarchy for HTTP and application-level errors
 //! - [`types`]: Fundamental types (URL, money, phone number, color, coordinates)
 //! - [`signals`]: Django-style signal/slot system for decoupled event handling
-//! - [`security`]: CSRF, XSS prevention, security headers, HSTS, IP filtering, redirect validation, and resource limits
+//! - [`security`]: CSRF, XSS prevention, HTML sanitization, security headers, HSTS, IP filtering, redirect validation, resource limits, and upload scanning
 //! - [`validators`]: Comprehensive input validation (IP, IBAN, phone, credit card)
 //! - [`serializers`]: Data serialization and deserialization framework
 //! - `pagination`: Cursor, page number, and limit-offset pagination strategies
@@ -55,6 +55,7 @@
 //! | `signals` | enabled | Async signal/slot system |
 //! | `macros` | enabled | Procedural macros re-export |
 //! | `security` | enabled | CSRF, XSS prevention, headers, HSTS, IP filtering, redirects, and resource limits |
+//! | `clamav` | disabled | ClamAV (`clamd`) upload scanner |
 //! | `validators` | enabled | Comprehensive input validation |
 //! | `serializers` | enabled | Data serialization framework |
 //! | `parsers` | disabled | Request body parsers |
@@ -72,6 +73,11 @@
 
 pub mod apply_update;
 pub use apply_update::ApplyUpdate;
+pub mod choices;
+pub use choices::Choices;
+pub mod form_media;
+pub mod form_renderer;
+pub use form_renderer::FormRenderer;
 /// HTTP endpoint routing and handler registration.
 #[cfg(native)]
 pub mod endpoint;
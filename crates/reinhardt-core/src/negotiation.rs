@@ -6,6 +6,9 @@
 //! ## Features
 //!
 //! - **Content negotiation**: Automatic media type selection based on Accept headers
+//!   following RFC 7231 (quality factors, parameters, wildcard precedence)
+//! - **Vendor trees**: `application/vnd.<vendor>+json` types with API versions
+//!   matched against their suffix renderer
 //! - **Content-Type detection**: Automatic detection of request body format (JSON, XML, YAML, Form)
 //! - **Language negotiation**: Support for Accept-Language header with quality factors
 //! - **Encoding negotiation**: Support for Accept-Encoding header (Gzip, Brotli, Deflate, Identity)
@@ -18,11 +21,13 @@ pub mod encoding;
 pub mod language;
 pub mod media_type;
 pub mod negotiator;
+pub mod vendor;
 
 pub use media_type::MediaType;
 pub use negotiator::{
 	BaseContentNegotiation, BaseNegotiator, ContentNegotiator, NegotiationError, RendererInfo,
 };
+pub use vendor::{VendorMatch, VendorTree};
 
 /// Re-export commonly used types
 pub mod prelude {
@@ -32,4 +37,5 @@ pub mod prelude {
 	pub use super::language::*;
 	pub use super::media_type::*;
 	pub use super::negotiator::*;
+	pub use super::vendor::*;
 }
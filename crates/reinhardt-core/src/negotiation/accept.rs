@@ -1,11 +1,14 @@
 //! Accept header parsing
 
-use super::media_type::MediaType;
+use super::media_type::{MediaType, split_unquoted};
+use super::vendor::VendorTree;
 
 /// Represents an Accept header
 #[derive(Debug, Clone)]
 pub struct AcceptHeader {
 	/// Parsed media types sorted by quality factor (highest first).
+	///
+	/// Ranges with equal quality keep the order in which the client sent them.
 	pub media_types: Vec<MediaType>,
 }
 
@@ -28,8 +31,8 @@ impl AcceptHeader {
 	/// assert_eq!(complex.media_types[0].subtype, "html");
 	/// ```
 	pub fn parse(header: &str) -> Self {
-		let mut media_types: Vec<MediaType> = header
-			.split(',')
+		let mut media_types: Vec<MediaType> = split_unquoted(header, ',')
+			.into_iter()
 			.filter_map(|s| MediaType::parse(s.trim()))
 			.collect();
 
@@ -60,6 +63,12 @@ impl AcceptHeader {
 	}
 	/// Finds the best matching media type from available options
 	///
+	/// Each available type takes the quality of the most specific range that
+	/// matches it (RFC 7231 section 5.3.2), so `text/html;q=0` excludes HTML
+	/// even when `*/*` is also accepted. The type with the highest quality
+	/// wins; ties go to the range the client listed first, then to the
+	/// earlier available type.
+	///
 	/// # Examples
 	///
 	/// ```
@@ -78,16 +87,81 @@ impl AcceptHeader {
 	/// let no_match = AcceptHeader::parse("application/json");
 	/// let result = no_match.find_best_match(&available);
 	/// assert!(result.is_none());
+	///
+	/// let excluded = AcceptHeader::parse("*/*, text/html; q=0");
+	/// assert_eq!(excluded.find_best_match(&available).unwrap().subtype, "xml");
 	/// ```
 	pub fn find_best_match(&self, available: &[MediaType]) -> Option<MediaType> {
-		for accepted in &self.media_types {
-			for available_type in available {
-				if accepted.matches(available_type) {
-					return Some(available_type.clone());
-				}
-			}
-		}
-		None
+		self.best_match_with(available, &[])
+			.map(|(media_type, _)| media_type.clone())
+	}
+
+	/// Returns the quality the client assigns to `media_type`
+	///
+	/// The quality comes from the most specific matching range; `None` means
+	/// no range matches.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::negotiation::accept::AcceptHeader;
+	/// use reinhardt_core::negotiation::MediaType;
+	///
+	/// let accept = AcceptHeader::parse("text/*; q=0.3, text/html; q=0.7, */*; q=0.5");
+	/// assert_eq!(accept.quality_of(&MediaType::new("text", "html")), Some(0.7));
+	/// assert_eq!(accept.quality_of(&MediaType::new("text", "plain")), Some(0.3));
+	/// assert_eq!(accept.quality_of(&MediaType::new("image", "png")), Some(0.5));
+	/// ```
+	pub fn quality_of(&self, media_type: &MediaType) -> Option<f32> {
+		self.most_specific_range(media_type, &[])
+			.map(|(_, range)| range.quality)
+	}
+
+	/// Finds the best available type, resolving vendor tree ranges
+	///
+	/// Returns the chosen available type with the client range it matched.
+	/// Ranges in one of `vendor_trees` also match the type of their
+	/// structured syntax suffix, so `application/vnd.myapp+json` selects an
+	/// `application/json` renderer.
+	pub(crate) fn best_match_with<'a>(
+		&'a self,
+		available: &'a [MediaType],
+		vendor_trees: &[VendorTree],
+	) -> Option<(&'a MediaType, &'a MediaType)> {
+		available
+			.iter()
+			.enumerate()
+			.filter_map(|(server_index, media_type)| {
+				let (client_index, range) = self.most_specific_range(media_type, vendor_trees)?;
+				(range.quality > 0.0).then_some((server_index, client_index, media_type, range))
+			})
+			.min_by(|a, b| {
+				b.3.quality
+					.total_cmp(&a.3.quality)
+					.then(a.1.cmp(&b.1))
+					.then(a.0.cmp(&b.0))
+			})
+			.map(|(_, _, media_type, range)| (media_type, range))
+	}
+
+	fn most_specific_range(
+		&self,
+		media_type: &MediaType,
+		vendor_trees: &[VendorTree],
+	) -> Option<(usize, &MediaType)> {
+		self.media_types
+			.iter()
+			.enumerate()
+			.filter(|(_, range)| {
+				range.matches(media_type)
+					|| vendor_trees
+						.iter()
+						.filter_map(|tree| tree.resolve(range))
+						.any(|resolved| resolved.base.matches(media_type))
+			})
+			// max_by_key returns the last maximum; reverse so the first listed range wins
+			.rev()
+			.max_by_key(|(_, range)| (range.precedence(), range.parameters.len()))
 	}
 }
 
@@ -114,6 +188,41 @@ mod tests {
 		assert!(best.is_some());
 	}
 
+	#[rstest]
+	#[case::specific_exclusion("*/*, text/html; q=0", &["text/html", "application/json"], Some("application/json"))]
+	#[case::specific_beats_wildcard("text/*; q=0.2, text/plain", &["text/html", "text/plain"], Some("text/plain"))]
+	#[case::client_order("application/json, text/html", &["text/html", "application/json"], Some("application/json"))]
+	#[case::all_excluded("text/html; q=0", &["text/html"], None)]
+	#[case::parameters_match("application/json; version=2", &["application/json"], Some("application/json"))]
+	fn test_find_best_match_precedence(
+		#[case] header: &str,
+		#[case] available: &[&str],
+		#[case] expected: Option<&str>,
+	) {
+		// Arrange
+		let accept = AcceptHeader::parse(header);
+		let available: Vec<MediaType> = available
+			.iter()
+			.map(|s| MediaType::parse(s).unwrap())
+			.collect();
+
+		// Act
+		let best = accept.find_best_match(&available);
+
+		// Assert
+		assert_eq!(best.map(|mt| mt.to_string()).as_deref(), expected);
+	}
+
+	#[rstest]
+	fn test_parse_keeps_quoted_commas() {
+		// Act
+		let accept = AcceptHeader::parse(r#"text/plain; title="a, b", text/html"#);
+
+		// Assert
+		assert_eq!(accept.media_types.len(), 2);
+		assert_eq!(accept.media_types[0].parameter("title"), Some("a, b"));
+	}
+
 	#[rstest]
 	#[case("text/html;q=NaN", 0)]
 	#[case("text/html;q=NaN, application/json", 1)]
@@ -38,6 +38,11 @@ impl MediaType {
 	}
 	/// Parses a media type string into a MediaType struct
 	///
+	/// Follows RFC 7231: type, subtype and parameter names are
+	/// case-insensitive and normalized to lowercase, parameter values may be
+	/// quoted strings, and parameters after `q` are accept extensions, which
+	/// are ignored. A `*` type is only valid with a `*` subtype.
+	///
 	/// # Examples
 	///
 	/// ```
@@ -52,43 +57,97 @@ impl MediaType {
 	/// assert_eq!(mt_with_params.parameters.len(), 1);
 	/// assert_eq!(mt_with_params.parameters[0].0, "charset");
 	/// assert_eq!(mt_with_params.parameters[0].1, "utf-8");
+	///
+	/// let vendor = MediaType::parse(r#"Application/VND.MyApp+JSON; Version="2""#).unwrap();
+	/// assert_eq!(vendor.subtype, "vnd.myapp+json");
+	/// assert_eq!(vendor.parameter("version"), Some("2"));
 	/// ```
 	pub fn parse(s: &str) -> Option<Self> {
-		let parts: Vec<&str> = s.split(';').collect();
-		let mime_type = parts.first()?.trim();
-
-		let type_parts: Vec<&str> = mime_type.split('/').collect();
-		if type_parts.len() != 2 {
+		let mut parts = split_unquoted(s, ';').into_iter();
+		let (type_, subtype) = parts.next()?.trim().split_once('/')?;
+		let (type_, subtype) = (type_.trim(), subtype.trim());
+		if !is_token(type_) || !is_token(subtype) || (type_ == "*" && subtype != "*") {
 			return None;
 		}
 
-		let mut media_type = MediaType::new(type_parts[0], type_parts[1]);
+		let mut media_type =
+			MediaType::new(type_.to_ascii_lowercase(), subtype.to_ascii_lowercase());
 
 		// Parse parameters
-		for param in parts.iter().skip(1) {
-			let param = param.trim();
-			if let Some((key, value)) = param.split_once('=') {
-				let key = key.trim();
-				let value = value.trim();
-
-				if key == "q" {
-					if let Ok(q) = value.parse::<f32>() {
-						// Reject non-finite quality values (NaN, inf, -inf)
-						if !q.is_finite() {
-							return None;
-						}
-						media_type.quality = q.clamp(0.0, 1.0);
+		let mut seen_quality = false;
+		for param in parts {
+			let Some((key, value)) = param.trim().split_once('=') else {
+				continue;
+			};
+			let key = key.trim().to_ascii_lowercase();
+			let value = unquote(value.trim());
+
+			if seen_quality {
+				// Accept extensions follow the quality parameter
+				continue;
+			}
+			if key == "q" {
+				seen_quality = true;
+				if let Ok(q) = value.parse::<f32>() {
+					// Reject non-finite quality values (NaN, inf, -inf)
+					if !q.is_finite() {
+						return None;
 					}
-				} else {
-					media_type
-						.parameters
-						.push((key.to_string(), value.to_string()));
+					media_type.quality = q.clamp(0.0, 1.0);
 				}
+			} else {
+				media_type.parameters.push((key, value));
 			}
 		}
 
 		Some(media_type)
 	}
+
+	/// Returns the value of a parameter, matching the name case-insensitively
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::negotiation::MediaType;
+	///
+	/// let mt = MediaType::parse("text/html; charset=utf-8").unwrap();
+	/// assert_eq!(mt.parameter("Charset"), Some("utf-8"));
+	/// assert_eq!(mt.parameter("version"), None);
+	/// ```
+	pub fn parameter(&self, name: &str) -> Option<&str> {
+		self.parameters
+			.iter()
+			.find(|(key, _)| key.eq_ignore_ascii_case(name))
+			.map(|(_, value)| value.as_str())
+	}
+
+	/// Returns the structured syntax suffix of the subtype (RFC 6839)
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::negotiation::MediaType;
+	///
+	/// assert_eq!(MediaType::new("application", "vnd.myapp+json").suffix(), Some("json"));
+	/// assert_eq!(MediaType::new("application", "json").suffix(), None);
+	/// ```
+	pub fn suffix(&self) -> Option<&str> {
+		self.subtype.rsplit_once('+').map(|(_, suffix)| suffix)
+	}
+
+	/// Returns `true` if the subtype is in the vendor tree (`vnd.`)
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::negotiation::MediaType;
+	///
+	/// assert!(MediaType::new("application", "vnd.myapp+json").is_vendor());
+	/// assert!(!MediaType::new("application", "json").is_vendor());
+	/// ```
+	pub fn is_vendor(&self) -> bool {
+		self.subtype.starts_with("vnd.")
+	}
 	/// Checks if this media type matches another, supporting wildcards
 	///
 	/// # Examples
@@ -185,6 +244,57 @@ impl MediaType {
 	}
 }
 
+/// Splits `s` on `separator`, ignoring separators inside quoted strings
+pub(crate) fn split_unquoted(s: &str, separator: char) -> Vec<&str> {
+	let mut parts = Vec::new();
+	let mut start = 0;
+	let mut in_quotes = false;
+	let mut escaped = false;
+	for (index, c) in s.char_indices() {
+		if escaped {
+			escaped = false;
+		} else if in_quotes && c == '\\' {
+			escaped = true;
+		} else if c == '"' {
+			in_quotes = !in_quotes;
+		} else if c == separator && !in_quotes {
+			parts.push(&s[start..index]);
+			start = index + c.len_utf8();
+		}
+	}
+	parts.push(&s[start..]);
+	parts
+}
+
+/// Removes the quotes and escapes of an RFC 7230 quoted-string
+fn unquote(value: &str) -> String {
+	match value
+		.strip_prefix('"')
+		.and_then(|inner| inner.strip_suffix('"'))
+	{
+		Some(inner) => {
+			let mut unquoted = String::with_capacity(inner.len());
+			let mut chars = inner.chars();
+			while let Some(c) = chars.next() {
+				if c == '\\' {
+					unquoted.extend(chars.next());
+				} else {
+					unquoted.push(c);
+				}
+			}
+			unquoted
+		}
+		None => value.to_string(),
+	}
+}
+
+/// Returns `true` if `s` is a non-empty RFC 7230 token
+fn is_token(s: &str) -> bool {
+	!s.is_empty()
+		&& s.chars()
+			.all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c))
+}
+
 impl fmt::Display for MediaType {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		write!(f, "{}/{}", self.type_, self.subtype)
@@ -211,6 +321,47 @@ mod tests {
 		assert!(json.matches(&wildcard));
 	}
 
+	#[rstest]
+	#[case::quoted_separator(r#"text/plain; title="a;b""#, "title", "a;b")]
+	#[case::escaped_quote(r#"text/plain; title="say \"hi\"""#, "title", r#"say "hi""#)]
+	#[case::uppercase_name("text/plain; CHARSET=utf-8", "charset", "utf-8")]
+	fn test_parse_parameter_values(
+		#[case] input: &str,
+		#[case] name: &str,
+		#[case] expected: &str,
+	) {
+		// Act
+		let mt = MediaType::parse(input).unwrap();
+
+		// Assert
+		assert_eq!(mt.parameter(name), Some(expected));
+	}
+
+	#[rstest]
+	fn test_parse_ignores_accept_extensions() {
+		// Act
+		let mt = MediaType::parse("application/json; version=2; q=0.5; ext=1").unwrap();
+
+		// Assert
+		assert_eq!(mt.quality, 0.5);
+		assert_eq!(
+			mt.parameters,
+			vec![("version".to_string(), "2".to_string())]
+		);
+	}
+
+	#[rstest]
+	#[case("*/json")]
+	#[case("application/")]
+	#[case("text html/plain")]
+	fn test_parse_rejects_invalid_media_ranges(#[case] input: &str) {
+		// Act
+		let result = MediaType::parse(input);
+
+		// Assert
+		assert_eq!(result, None);
+	}
+
 	#[rstest]
 	#[case("text/html;q=NaN")]
 	#[case("application/json; q=NaN")]
@@ -2,6 +2,7 @@
 
 use super::accept::AcceptHeader;
 use super::media_type::MediaType;
+use super::vendor::VendorTree;
 
 /// Trait for renderers
 pub trait Renderer {
@@ -54,6 +55,7 @@ pub trait BaseContentNegotiation {
 #[derive(Debug, Clone)]
 pub struct ContentNegotiator {
 	default_media_type: MediaType,
+	vendor_trees: Vec<VendorTree>,
 }
 
 impl ContentNegotiator {
@@ -70,6 +72,7 @@ impl ContentNegotiator {
 	pub fn new() -> Self {
 		Self {
 			default_media_type: MediaType::new("application", "json"),
+			vendor_trees: Vec::new(),
 		}
 	}
 	/// Sets a custom default media type for the negotiator
@@ -90,6 +93,29 @@ impl ContentNegotiator {
 		self.default_media_type = media_type;
 		self
 	}
+	/// Registers a vendor tree whose types select the renderer of their suffix
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::negotiation::{ContentNegotiator, MediaType, VendorTree};
+	///
+	/// let negotiator = ContentNegotiator::new().with_vendor_tree(VendorTree::new("myapp"));
+	/// let renderers = vec![
+	///     MediaType::new("text", "html"),
+	///     MediaType::new("application", "json"),
+	/// ];
+	///
+	/// let (renderer, accepted) = negotiator
+	///     .select_renderer(Some("application/vnd.myapp+json; version=2"), &renderers)
+	///     .unwrap();
+	/// assert_eq!(renderer.subtype, "json");
+	/// assert_eq!(accepted, "application/vnd.myapp+json; version=2");
+	/// ```
+	pub fn with_vendor_tree(mut self, tree: VendorTree) -> Self {
+		self.vendor_trees.push(tree);
+		self
+	}
 	/// Negotiate the best media type based on Accept header
 	///
 	/// # Examples
@@ -113,7 +139,8 @@ impl ContentNegotiator {
 		let accept = AcceptHeader::parse(accept_header);
 
 		accept
-			.find_best_match(available)
+			.best_match_with(available, &self.vendor_trees)
+			.map(|(media_type, _)| media_type.clone())
 			.unwrap_or_else(|| self.default_media_type.clone())
 	}
 	/// Select renderer based on Accept header
@@ -162,22 +189,17 @@ impl ContentNegotiator {
 
 		let accept = AcceptHeader::parse(accept_str);
 
-		// Find best match considering parameters
-		for accepted in &accept.media_types {
-			for renderer in renderers {
-				if accepted.matches(renderer) {
-					// If client specifies parameters, include them in the result
-					let result_str = if !accepted.parameters.is_empty() {
-						accepted.full_string()
-					} else {
-						renderer.to_string()
-					};
-					return Ok((renderer.clone(), result_str));
-				}
-			}
-		}
+		let (renderer, accepted) = accept
+			.best_match_with(renderers, &self.vendor_trees)
+			.ok_or(NegotiationError::NoSuitableRenderer)?;
 
-		Err(NegotiationError::NoSuitableRenderer)
+		// Echo the client's parameters and vendor type in the result
+		let result_str = if !accepted.parameters.is_empty() || accepted.is_vendor() {
+			accepted.full_string()
+		} else {
+			renderer.to_string()
+		};
+		Ok((renderer.clone(), result_str))
 	}
 	/// Filter renderers by format
 	///
@@ -275,6 +297,7 @@ impl BaseContentNegotiation for BaseNegotiator {}
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use rstest::rstest;
 
 	#[test]
 	fn test_negotiate() {
@@ -300,4 +323,47 @@ mod tests {
 		assert!(result.is_some());
 		assert_eq!(result.unwrap().subtype, "json");
 	}
+
+	#[rstest]
+	#[case::quality("application/json; q=0.5, text/html", "html", "text/html")]
+	#[case::excluded("*/*, application/json; q=0", "html", "text/html")]
+	#[case::vendor(
+		"application/vnd.myapp.v2+json",
+		"json",
+		"application/vnd.myapp.v2+json"
+	)]
+	fn test_select_renderer_precedence(
+		#[case] accept: &str,
+		#[case] expected_subtype: &str,
+		#[case] expected_accepted: &str,
+	) {
+		// Arrange
+		let negotiator = ContentNegotiator::new().with_vendor_tree(VendorTree::new("myapp"));
+		let renderers = vec![
+			MediaType::new("application", "json"),
+			MediaType::new("text", "html"),
+		];
+
+		// Act
+		let (renderer, accepted) = negotiator
+			.select_renderer(Some(accept), &renderers)
+			.unwrap();
+
+		// Assert
+		assert_eq!(renderer.subtype, expected_subtype);
+		assert_eq!(accepted, expected_accepted);
+	}
+
+	#[rstest]
+	fn test_select_renderer_rejects_foreign_vendor() {
+		// Arrange
+		let negotiator = ContentNegotiator::new().with_vendor_tree(VendorTree::new("myapp"));
+		let renderers = vec![MediaType::new("application", "json")];
+
+		// Act
+		let result = negotiator.select_renderer(Some("application/vnd.other+json"), &renderers);
+
+		// Assert
+		assert_eq!(result, Err(NegotiationError::NoSuitableRenderer));
+	}
 }
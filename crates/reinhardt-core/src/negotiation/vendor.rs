@@ -0,0 +1,173 @@
+//! Vendor tree media types
+//!
+//! APIs often publish vendor-specific media types such as
+//! `application/vnd.myapp+json; version=2` or `application/vnd.myapp.v2+json`.
+//! A [`VendorTree`] maps such types onto the renderer for their structured
+//! syntax suffix (`application/json`) and extracts the requested API version,
+//! which the REST versioning module uses for Accept-header versioning.
+
+use super::accept::AcceptHeader;
+use super::media_type::MediaType;
+
+/// A vendor tree recognized during negotiation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VendorTree {
+	vendor: String,
+	version_param: String,
+}
+
+/// A media type resolved through a [`VendorTree`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct VendorMatch {
+	/// The suffix media type renderers are matched against (e.g. `application/json`)
+	pub base: MediaType,
+	/// The API version requested through a parameter or a `.v<version>` segment
+	pub version: Option<String>,
+}
+
+impl VendorTree {
+	/// Create a vendor tree for `application/vnd.<vendor>+<suffix>` types
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::negotiation::MediaType;
+	/// use reinhardt_core::negotiation::vendor::VendorTree;
+	///
+	/// let tree = VendorTree::new("myapp");
+	/// let resolved = tree
+	///     .resolve(&MediaType::parse("application/vnd.myapp.v2+json").unwrap())
+	///     .unwrap();
+	/// assert_eq!(resolved.base, MediaType::new("application", "json"));
+	/// assert_eq!(resolved.version.as_deref(), Some("2"));
+	/// ```
+	pub fn new(vendor: impl Into<String>) -> Self {
+		Self {
+			vendor: vendor.into().to_ascii_lowercase(),
+			version_param: "version".to_string(),
+		}
+	}
+
+	/// Set the media type parameter carrying the version (default: `version`)
+	pub fn with_version_param(mut self, param: impl Into<String>) -> Self {
+		self.version_param = param.into();
+		self
+	}
+
+	/// Returns the vendor name
+	pub fn vendor(&self) -> &str {
+		&self.vendor
+	}
+
+	/// Resolve a media type of this vendor tree
+	///
+	/// Returns `None` for types outside the tree and for vendor types without
+	/// a structured syntax suffix. The version parameter takes precedence
+	/// over a `.v<version>` subtype segment; it is removed from the base type's
+	/// parameters, while other parameters and the quality are kept.
+	pub fn resolve(&self, media_type: &MediaType) -> Option<VendorMatch> {
+		let (name, suffix) = media_type.subtype.strip_prefix("vnd.")?.rsplit_once('+')?;
+		let segment_version = match name.strip_prefix(self.vendor.as_str())? {
+			"" => None,
+			rest => Some(
+				rest.strip_prefix(".v")
+					.filter(|v| !v.is_empty())?
+					.to_string(),
+			),
+		};
+
+		let mut base = MediaType::new(media_type.type_.clone(), suffix);
+		base.quality = media_type.quality;
+		let mut param_version = None;
+		for (key, value) in &media_type.parameters {
+			if key.eq_ignore_ascii_case(&self.version_param) {
+				param_version = Some(value.clone());
+			} else {
+				base.parameters.push((key.clone(), value.clone()));
+			}
+		}
+
+		Some(VendorMatch {
+			base,
+			version: param_version.or(segment_version),
+		})
+	}
+
+	/// Extract the API version from an Accept header
+	///
+	/// Looks at the acceptable ranges of this vendor tree in order of
+	/// preference and returns the first version found.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::negotiation::vendor::VendorTree;
+	///
+	/// let tree = VendorTree::new("myapp");
+	/// let version = tree.version_from_accept(
+	///     "application/vnd.myapp+json; version=1; q=0.5, application/vnd.myapp.v3+json",
+	/// );
+	/// assert_eq!(version.as_deref(), Some("3"));
+	/// ```
+	pub fn version_from_accept(&self, header: &str) -> Option<String> {
+		AcceptHeader::parse(header)
+			.media_types
+			.iter()
+			.filter(|range| range.quality > 0.0)
+			.filter_map(|range| self.resolve(range))
+			.find_map(|resolved| resolved.version)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case::parameter("application/vnd.myapp+json; version=2", Some("2"))]
+	#[case::segment("application/vnd.myapp.v2+json", Some("2"))]
+	#[case::parameter_wins("application/vnd.myapp.v1+json; version=2", Some("2"))]
+	#[case::unversioned("application/vnd.myapp+json", None)]
+	fn resolve_extracts_version(#[case] input: &str, #[case] expected: Option<&str>) {
+		// Arrange
+		let tree = VendorTree::new("myapp");
+
+		// Act
+		let resolved = tree.resolve(&MediaType::parse(input).unwrap()).unwrap();
+
+		// Assert
+		assert_eq!(resolved.base.to_string(), "application/json");
+		assert!(resolved.base.parameter("version").is_none());
+		assert_eq!(resolved.version.as_deref(), expected);
+	}
+
+	#[rstest]
+	#[case::other_vendor("application/vnd.other+json")]
+	#[case::prefix_only("application/vnd.myappx+json")]
+	#[case::no_suffix("application/vnd.myapp")]
+	#[case::not_vendor("application/json")]
+	fn resolve_rejects_foreign_types(#[case] input: &str) {
+		// Arrange
+		let tree = VendorTree::new("myapp");
+
+		// Act
+		let resolved = tree.resolve(&MediaType::parse(input).unwrap());
+
+		// Assert
+		assert_eq!(resolved, None);
+	}
+
+	#[rstest]
+	fn version_from_accept_skips_unacceptable_ranges() {
+		// Arrange
+		let tree = VendorTree::new("myapp");
+
+		// Act
+		let version =
+			tree.version_from_accept("application/vnd.myapp.v3+json; q=0, application/json");
+
+		// Assert
+		assert_eq!(version, None);
+	}
+}
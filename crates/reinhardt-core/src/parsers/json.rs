@@ -3,7 +3,7 @@ use bytes::Bytes;
 use http::HeaderMap;
 use serde_json::Value;
 
-use super::parser::{ParseError, ParseResult, ParsedData, Parser};
+use super::parser::{BodyStream, ParseError, ParseResult, ParsedData, Parser, collect_body};
 
 /// JSON parser for application/json content type
 ///
@@ -29,6 +29,11 @@ pub struct JSONParser {
 	pub allow_empty: bool,
 	/// Whether to enforce strict JSON (reject Infinity, -Infinity, NaN)
 	pub strict: bool,
+	/// Maximum body size in bytes (`None` = unlimited)
+	///
+	/// Streamed bodies are rejected as soon as the limit is crossed, before
+	/// the rest of the body is read.
+	pub max_size: Option<usize>,
 }
 
 impl Default for JSONParser {
@@ -36,6 +41,7 @@ impl Default for JSONParser {
 		Self {
 			allow_empty: false,
 			strict: true, // Default to strict mode like DRF
+			max_size: None,
 		}
 	}
 }
@@ -81,6 +87,20 @@ impl JSONParser {
 		self.strict = strict;
 		self
 	}
+	/// Set the maximum body size in bytes.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::parsers::json::JSONParser;
+	///
+	/// let parser = JSONParser::new().max_size(1024 * 1024);
+	/// assert_eq!(parser.max_size, Some(1024 * 1024));
+	/// ```
+	pub fn max_size(mut self, size: usize) -> Self {
+		self.max_size = Some(size);
+		self
+	}
 }
 
 #[async_trait]
@@ -98,6 +118,15 @@ impl Parser for JSONParser {
 		body: Bytes,
 		_headers: &HeaderMap,
 	) -> ParseResult<ParsedData> {
+		if let Some(max_size) = self.max_size
+			&& body.len() > max_size
+		{
+			return Err(ParseError::ParseError(format!(
+				"Request body exceeds maximum size of {} bytes",
+				max_size
+			)));
+		}
+
 		if body.is_empty() {
 			if self.allow_empty {
 				return Ok(ParsedData::Json(Value::Null));
@@ -117,6 +146,16 @@ impl Parser for JSONParser {
 			Err(e) => Err(ParseError::ParseError(format!("Invalid JSON: {}", e))),
 		}
 	}
+
+	async fn parse_stream(
+		&self,
+		content_type: Option<&str>,
+		body: BodyStream,
+		headers: &HeaderMap,
+	) -> ParseResult<ParsedData> {
+		let body = collect_body(body, self.max_size).await?;
+		self.parse(content_type, body, headers).await
+	}
 }
 
 impl JSONParser {
@@ -387,4 +426,42 @@ mod tests {
 			"Infinity literal in array should be rejected even in non-strict mode"
 		);
 	}
+
+	#[tokio::test]
+	async fn test_json_parser_parse_stream() {
+		let parser = JSONParser::new();
+		let chunks: Vec<std::io::Result<Bytes>> = vec![
+			Ok(Bytes::from(r#"{"name": "#)),
+			Ok(Bytes::from(r#""test"}"#)),
+		];
+		let body: BodyStream = Box::pin(futures_util::stream::iter(chunks));
+		let headers = HeaderMap::new();
+
+		let result = parser
+			.parse_stream(Some("application/json"), body, &headers)
+			.await
+			.unwrap();
+
+		match result {
+			ParsedData::Json(value) => assert_eq!(value["name"], "test"),
+			_ => panic!("Expected JSON data"),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_json_parser_parse_stream_enforces_max_size() {
+		let parser = JSONParser::new().max_size(8);
+		let chunks: Vec<std::io::Result<Bytes>> = vec![
+			Ok(Bytes::from(r#"{"name": "#)),
+			Ok(Bytes::from(r#""test"}"#)),
+		];
+		let body: BodyStream = Box::pin(futures_util::stream::iter(chunks));
+		let headers = HeaderMap::new();
+
+		let result = parser
+			.parse_stream(Some("application/json"), body, &headers)
+			.await;
+
+		assert!(result.is_err());
+	}
 }
@@ -4,7 +4,9 @@ use http::HeaderMap;
 use multer::Multipart as MulterMultipart;
 use std::collections::HashMap;
 
-use super::parser::{MediaType, ParseError, ParseResult, ParsedData, Parser, UploadedFile};
+use super::parser::{
+	BodyStream, ChunkBuffer, MediaType, ParseError, ParseResult, ParsedData, Parser, UploadedFile,
+};
 
 /// Default maximum file size: 10 MB
 const DEFAULT_MAX_FILE_SIZE: usize = 10 * 1024 * 1024;
@@ -21,6 +23,10 @@ const DEFAULT_MAX_TOTAL_SIZE: usize = 50 * 1024 * 1024;
 ///
 /// Use the builder methods to override these limits, or
 /// [`MultiPartParser::unlimited()`] to remove all limits.
+///
+/// When given a streamed body through [`Parser::parse_stream`], fields are
+/// read chunk by chunk and the limits are enforced as data arrives, so an
+/// oversized upload is rejected without buffering the whole request.
 #[derive(Debug, Clone)]
 pub struct MultiPartParser {
 	/// Maximum file size in bytes (`None` = unlimited)
@@ -111,17 +117,14 @@ impl MultiPartParser {
 		self
 	}
 
-	async fn parse_multipart(&self, boundary: &str, body: Bytes) -> ParseResult<ParsedData> {
-		let mut multipart = MulterMultipart::new(
-			futures_util::stream::once(async move { Result::<_, std::io::Error>::Ok(body) }),
-			boundary,
-		);
+	async fn parse_multipart(&self, boundary: &str, body: BodyStream) -> ParseResult<ParsedData> {
+		let mut multipart = MulterMultipart::new(body, boundary);
 
 		let mut fields = HashMap::new();
 		let mut files = Vec::new();
 		let mut total_size = 0usize;
 
-		while let Some(field) = multipart
+		while let Some(mut field) = multipart
 			.next_field()
 			.await
 			.map_err(|e| ParseError::ParseError(format!("Multipart parse error: {}", e)))?
@@ -130,33 +133,21 @@ impl MultiPartParser {
 			let filename = field.file_name().map(|s| s.to_string());
 			let content_type = field.content_type().map(|m| m.to_string());
 
-			let data = field
-				.bytes()
+			// Read the field incrementally so limits are enforced before the
+			// remainder of an oversized field is received.
+			let mut buffer = ChunkBuffer::default();
+			let mut size = 0usize;
+			while let Some(chunk) = field
+				.chunk()
 				.await
-				.map_err(|e| ParseError::ParseError(format!("Failed to read field data: {}", e)))?;
-
-			let size = data.len();
-
-			// Check file size limit
-			if let Some(max_size) = self.max_file_size
-				&& size > max_size
+				.map_err(|e| ParseError::ParseError(format!("Failed to read field data: {}", e)))?
 			{
-				return Err(ParseError::ParseError(format!(
-					"File '{}' exceeds maximum size of {} bytes",
-					name, max_size
-				)));
+				size += chunk.len();
+				self.check_limits(&name, size, total_size + size)?;
+				buffer.push(chunk);
 			}
-
-			// Check total size limit
+			let data = buffer.freeze();
 			total_size += size;
-			if let Some(max_total) = self.max_total_size
-				&& total_size > max_total
-			{
-				return Err(ParseError::ParseError(format!(
-					"Total upload size exceeds maximum of {} bytes",
-					max_total
-				)));
-			}
 
 			if filename.is_some() {
 				// This is a file field
@@ -177,6 +168,42 @@ impl MultiPartParser {
 
 		Ok(ParsedData::MultiPart { fields, files })
 	}
+
+	/// Check the size of the current field and of the upload so far
+	fn check_limits(&self, name: &str, field_size: usize, total_size: usize) -> ParseResult<()> {
+		if let Some(max_size) = self.max_file_size
+			&& field_size > max_size
+		{
+			return Err(ParseError::ParseError(format!(
+				"File '{}' exceeds maximum size of {} bytes",
+				name, max_size
+			)));
+		}
+
+		if let Some(max_total) = self.max_total_size
+			&& total_size > max_total
+		{
+			return Err(ParseError::ParseError(format!(
+				"Total upload size exceeds maximum of {} bytes",
+				max_total
+			)));
+		}
+
+		Ok(())
+	}
+
+	/// Extract the `boundary` parameter from a multipart content type
+	fn boundary(content_type: Option<&str>) -> ParseResult<String> {
+		let content_type = content_type.ok_or(ParseError::MissingContentType)?;
+
+		let media_type = MediaType::parse(content_type)?;
+
+		media_type
+			.parameters
+			.get("boundary")
+			.cloned()
+			.ok_or_else(|| ParseError::ParseError("Missing boundary parameter".to_string()))
+	}
 }
 
 #[async_trait]
@@ -191,16 +218,21 @@ impl Parser for MultiPartParser {
 		body: Bytes,
 		_headers: &HeaderMap,
 	) -> ParseResult<ParsedData> {
-		let content_type = content_type.ok_or(ParseError::MissingContentType)?;
-
-		let media_type = MediaType::parse(content_type)?;
-
-		let boundary = media_type
-			.parameters
-			.get("boundary")
-			.ok_or_else(|| ParseError::ParseError("Missing boundary parameter".to_string()))?;
+		let boundary = Self::boundary(content_type)?;
+		let body: BodyStream = Box::pin(futures_util::stream::once(async move {
+			std::io::Result::Ok(body)
+		}));
+		self.parse_multipart(&boundary, body).await
+	}
 
-		self.parse_multipart(boundary, body).await
+	async fn parse_stream(
+		&self,
+		content_type: Option<&str>,
+		body: BodyStream,
+		_headers: &HeaderMap,
+	) -> ParseResult<ParsedData> {
+		let boundary = Self::boundary(content_type)?;
+		self.parse_multipart(&boundary, body).await
 	}
 }
 
@@ -267,6 +299,63 @@ mod tests {
 		assert!(result.is_err());
 	}
 
+	/// Split `body` into `chunk_size`-byte chunks
+	fn chunked(body: Bytes, chunk_size: usize) -> BodyStream {
+		let chunks: Vec<std::io::Result<Bytes>> = body
+			.chunks(chunk_size)
+			.map(|chunk| Ok(Bytes::copy_from_slice(chunk)))
+			.collect();
+		Box::pin(futures_util::stream::iter(chunks))
+	}
+
+	#[tokio::test]
+	async fn test_multipart_parser_parse_stream() {
+		let parser = MultiPartParser::new();
+		let boundary = "----WebKitFormBoundary7MA4YWxkTrZu0gW";
+		let body = chunked(create_multipart_body(boundary), 7);
+		let content_type = format!("multipart/form-data; boundary={}", boundary);
+		let headers = HeaderMap::new();
+
+		let result = parser
+			.parse_stream(Some(&content_type), body, &headers)
+			.await
+			.unwrap();
+
+		match result {
+			ParsedData::MultiPart { fields, files } => {
+				assert_eq!(fields.get("field1"), Some(&"value1".to_string()));
+				assert_eq!(files.len(), 1);
+				assert_eq!(files[0].data, Bytes::from("Hello, World!"));
+				assert_eq!(files[0].size, 13);
+			}
+			_ => panic!("Expected multipart data"),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_multipart_parser_parse_stream_stops_at_limit() {
+		// A stream that errors if read past the first chunks proves the
+		// parser gives up as soon as the file limit is crossed.
+		let parser = MultiPartParser::new().max_file_size(4);
+		let head = Bytes::from(format!(
+			"--XyZ\r\nContent-Disposition: form-data; name=\"file\"; filename=\"a.bin\"\r\n\r\n{}",
+			"0".repeat(64)
+		));
+		let chunks: Vec<std::io::Result<Bytes>> = vec![
+			Ok(head),
+			Err(std::io::Error::other("body read past the limit")),
+		];
+		let body: BodyStream = Box::pin(futures_util::stream::iter(chunks));
+		let headers = HeaderMap::new();
+
+		let result = parser
+			.parse_stream(Some("multipart/form-data; boundary=XyZ"), body, &headers)
+			.await;
+
+		let message = result.unwrap_err().to_string();
+		assert!(message.contains("exceeds maximum size"), "{}", message);
+	}
+
 	#[test]
 	fn test_multipart_parser_media_types() {
 		let parser = MultiPartParser::new();
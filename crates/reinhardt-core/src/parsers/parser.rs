@@ -1,15 +1,88 @@
 use crate::exception::{Error, Result};
 use async_trait::async_trait;
-use bytes::Bytes;
+use bytes::{Bytes, BytesMut};
+use futures_util::StreamExt;
+use futures_util::stream::Stream;
 use http::HeaderMap;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::pin::Pin;
 
 /// Type alias for parser errors, using the framework's `Error` type.
 pub type ParseError = Error;
 /// Type alias for parser results, using the framework's `Result` type.
 pub type ParseResult<T> = Result<T>;
 
+/// A request body delivered as a stream of chunks
+pub type BodyStream = Pin<Box<dyn Stream<Item = std::io::Result<Bytes>> + Send>>;
+
+/// Collect `body` into a single buffer, failing as soon as more than `limit`
+/// bytes have been received.
+///
+/// A body that arrives as a single chunk is returned without copying.
+///
+/// # Examples
+///
+/// ```
+/// use bytes::Bytes;
+/// use reinhardt_core::parsers::parser::{BodyStream, collect_body};
+///
+/// # tokio_test::block_on(async {
+/// let chunks = vec![Ok(Bytes::from("hello ")), Ok(Bytes::from("world"))];
+/// let body: BodyStream = Box::pin(futures_util::stream::iter(chunks));
+/// assert_eq!(collect_body(body, Some(64)).await.unwrap(), Bytes::from("hello world"));
+/// # });
+/// ```
+pub async fn collect_body(mut body: BodyStream, limit: Option<usize>) -> ParseResult<Bytes> {
+	let mut buffer = ChunkBuffer::default();
+	let mut received = 0usize;
+
+	while let Some(chunk) = body.next().await {
+		let chunk =
+			chunk.map_err(|e| Error::ParseError(format!("Failed to read request body: {}", e)))?;
+		received += chunk.len();
+		if let Some(limit) = limit
+			&& received > limit
+		{
+			return Err(Error::ParseError(format!(
+				"Request body exceeds maximum size of {} bytes",
+				limit
+			)));
+		}
+
+		buffer.push(chunk);
+	}
+
+	Ok(buffer.freeze())
+}
+
+/// Joins body chunks, copying only once a second non-empty chunk arrives
+#[derive(Default)]
+pub(crate) struct ChunkBuffer {
+	first: Option<Bytes>,
+	joined: BytesMut,
+}
+
+impl ChunkBuffer {
+	pub(crate) fn push(&mut self, chunk: Bytes) {
+		if chunk.is_empty() {
+			return;
+		}
+		match self.first.take() {
+			None if self.joined.is_empty() => self.first = Some(chunk),
+			None => self.joined.extend_from_slice(&chunk),
+			Some(first) => {
+				self.joined.extend_from_slice(&first);
+				self.joined.extend_from_slice(&chunk);
+			}
+		}
+	}
+
+	pub(crate) fn freeze(self) -> Bytes {
+		self.first.unwrap_or_else(|| self.joined.freeze())
+	}
+}
+
 /// Media type representation
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MediaType {
@@ -244,6 +317,22 @@ pub trait Parser: Send + Sync {
 		headers: &HeaderMap,
 	) -> ParseResult<ParsedData>;
 
+	/// Parse a request body delivered as a stream.
+	///
+	/// The default implementation collects the stream and delegates to
+	/// [`parse`](Self::parse). Parsers that can work incrementally, such as
+	/// [`MultiPartParser`](super::multipart::MultiPartParser), override this
+	/// to avoid buffering the whole body.
+	async fn parse_stream(
+		&self,
+		content_type: Option<&str>,
+		body: BodyStream,
+		headers: &HeaderMap,
+	) -> ParseResult<ParsedData> {
+		let body = collect_body(body, None).await?;
+		self.parse(content_type, body, headers).await
+	}
+
 	/// Check if this parser can handle the given content type
 	fn can_parse(&self, content_type: Option<&str>) -> bool {
 		if let Some(ct) = content_type
@@ -346,6 +435,40 @@ mod tests {
 		assert_eq!(mt.parameters.get("charset"), Some(&"utf-8".to_string()));
 	}
 
+	fn chunked(chunks: &[&'static str]) -> BodyStream {
+		let chunks: Vec<std::io::Result<Bytes>> = chunks
+			.iter()
+			.map(|chunk| Ok(Bytes::from_static(chunk.as_bytes())))
+			.collect();
+		Box::pin(futures_util::stream::iter(chunks))
+	}
+
+	#[tokio::test]
+	async fn test_collect_body_single_chunk_is_not_copied() {
+		let data = Bytes::from_static(b"payload");
+		let body: BodyStream = Box::pin(futures_util::stream::iter(vec![Ok(data.clone())]));
+
+		let collected = collect_body(body, None).await.unwrap();
+
+		assert_eq!(collected.as_ptr(), data.as_ptr());
+	}
+
+	#[tokio::test]
+	async fn test_collect_body_concatenates_chunks() {
+		let collected = collect_body(chunked(&["a", "bc", "", "def"]), Some(6))
+			.await
+			.unwrap();
+
+		assert_eq!(collected, Bytes::from("abcdef"));
+	}
+
+	#[tokio::test]
+	async fn test_collect_body_rejects_oversized_stream() {
+		let result = collect_body(chunked(&["abc", "def"]), Some(5)).await;
+
+		assert!(matches!(result, Err(Error::ParseError(_))));
+	}
+
 	#[test]
 	fn test_media_type_matches() {
 		let mt = MediaType::new("application", "json");
@@ -197,6 +197,12 @@ impl Runtime {
 		self.observer_stack.borrow_mut().push(observer);
 	}
 
+	/// Whether any node is waiting to be re-executed by
+	/// [`flush_updates`](Self::flush_updates)
+	pub fn has_pending_updates(&self) -> bool {
+		!self.pending_updates.borrow().is_empty()
+	}
+
 	/// Pop an observer from the stack
 	///
 	/// This should be called when finishing execution of an Effect or Memo.
@@ -574,6 +580,20 @@ mod tests {
 		assert_ne!(id1, id3);
 	}
 
+	#[test]
+	#[serial]
+	fn test_has_pending_updates() {
+		let runtime = Runtime::new();
+		*runtime.batch_depth.borrow_mut() = 1;
+
+		assert!(!runtime.has_pending_updates());
+		runtime.schedule_update(NodeId::new());
+		assert!(runtime.has_pending_updates());
+
+		runtime.pending_updates.borrow_mut().clear();
+		assert!(!runtime.has_pending_updates());
+	}
+
 	#[test]
 	#[serial]
 	fn test_runtime_observer_stack() {
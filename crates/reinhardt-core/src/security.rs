@@ -7,9 +7,11 @@
 //!
 //! - CSRF (Cross-Site Request Forgery) protection
 //! - XSS (Cross-Site Scripting) prevention helpers
+//! - Allowlist-based HTML sanitization for user-generated content
 //! - Security headers middleware
 //! - Content Security Policy (CSP)
 //! - Clickjacking protection
+//! - Upload scanning hooks (virus and file-type checks)
 //!
 //! ## Example
 //!
@@ -35,6 +37,8 @@ pub mod hsts;
 pub mod ip_filter;
 pub mod redirect;
 pub mod resource_limits;
+pub mod sanitize;
+pub mod upload_scan;
 pub mod utils;
 pub mod xss;
 
@@ -45,6 +49,8 @@ pub use hsts::{HstsConfig, HstsMiddleware};
 pub use ip_filter::{IpFilterConfig, IpFilterMiddleware, IpFilterMode};
 pub use redirect::{RedirectValidationError, is_safe_redirect, validate_redirect_url};
 pub use resource_limits::{LimitExceeded, ResourceLimits};
+pub use sanitize::{SanitizePolicy, SanitizedNode, sanitize};
+pub use upload_scan::{FileTypeScanner, ScanError, ScanPolicy, ScanVerdict, UploadScanner};
 // re-exporting deprecated `escape_html_content` for backward compatibility
 #[allow(deprecated)]
 pub use xss::{
@@ -90,5 +96,6 @@ pub use csrf::{CsrfMeta, check_origin, check_referer, is_same_domain};
 
 // Re-export HMAC-based CSRF functions (primary API)
 pub use csrf::{
-	check_token_hmac, generate_token_hmac, get_secret_bytes, get_token_hmac, verify_token_hmac,
+	check_token_hmac, generate_token_hmac, get_secret_bytes, get_token_hmac, mask_token,
+	unmask_token, verify_double_submit, verify_token_hmac,
 };
@@ -366,6 +366,84 @@ pub fn check_token_hmac(
 	Ok(())
 }
 
+/// Mask a CSRF token with a one-time pad to resist BREACH
+///
+/// Each call draws a fresh random pad, so the same token renders as a
+/// different string in every response. Compression side channels such as
+/// BREACH therefore cannot recover the token byte by byte. The result is the
+/// hex encoding of `pad || (pad XOR token)`.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_core::security::csrf::{mask_token, unmask_token};
+///
+/// let first = mask_token("session-token");
+/// let second = mask_token("session-token");
+/// assert_ne!(first, second);
+/// assert_eq!(unmask_token(&first).as_deref(), Some("session-token"));
+/// ```
+pub fn mask_token(token: &str) -> String {
+	let token = token.as_bytes();
+	let mut pad = vec![0u8; token.len()];
+	rand::rng().fill(&mut pad[..]);
+	let masked: Vec<u8> = pad.iter().zip(token).map(|(p, t)| p ^ t).collect();
+	pad.extend_from_slice(&masked);
+	hex::encode(pad)
+}
+
+/// Recover the token from a value produced by [`mask_token`]
+///
+/// Returns `None` if the value is not a well-formed masked token.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_core::security::csrf::{mask_token, unmask_token};
+///
+/// assert_eq!(unmask_token(&mask_token("abc")).as_deref(), Some("abc"));
+/// assert_eq!(unmask_token("not-hex"), None);
+/// ```
+pub fn unmask_token(masked: &str) -> Option<String> {
+	let bytes = hex::decode(masked).ok()?;
+	if bytes.is_empty() || bytes.len() % 2 != 0 {
+		return None;
+	}
+	let (pad, masked) = bytes.split_at(bytes.len() / 2);
+	let token: Vec<u8> = pad.iter().zip(masked).map(|(p, m)| p ^ m).collect();
+	String::from_utf8(token).ok()
+}
+
+/// Verify a double-submit CSRF token against the CSRF cookie
+///
+/// Used when there is no session to bind the token to. The submitted token may
+/// be masked (see [`mask_token`]) or the raw cookie value. The comparison runs
+/// in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_core::security::csrf::{mask_token, verify_double_submit};
+///
+/// let cookie = "0123456789abcdef0123456789abcdef";
+/// assert!(verify_double_submit(cookie, cookie));
+/// assert!(verify_double_submit(&mask_token(cookie), cookie));
+/// assert!(!verify_double_submit("forged", cookie));
+/// assert!(!verify_double_submit("", ""));
+/// ```
+pub fn verify_double_submit(request_token: &str, cookie_token: &str) -> bool {
+	if cookie_token.is_empty() {
+		return false;
+	}
+	let candidate = if request_token.len() == cookie_token.len() * 4 {
+		unmask_token(request_token).unwrap_or_default()
+	} else {
+		request_token.to_string()
+	};
+	let (a, b) = (candidate.as_bytes(), cookie_token.as_bytes());
+	a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 /// Check origin header
 pub fn check_origin(origin: &str, allowed_origins: &[String]) -> Result<(), RejectRequest> {
 	if !allowed_origins.iter().any(|o| o == origin) {
@@ -557,6 +635,65 @@ mod tests {
 		b"test-secret-key-at-least-32-bytes".to_vec()
 	}
 
+	#[rstest]
+	fn test_mask_token_round_trips_with_fresh_pad() {
+		// Arrange
+		let token = get_token_hmac(&test_secret(), "session-1");
+
+		// Act
+		let first = mask_token(&token);
+		let second = mask_token(&token);
+
+		// Assert
+		assert_eq!(first.len(), token.len() * 4);
+		assert_ne!(first, second);
+		assert_eq!(unmask_token(&first), Some(token.clone()));
+		assert_eq!(unmask_token(&second), Some(token));
+	}
+
+	#[rstest]
+	#[case::not_hex("zz")]
+	#[case::empty("")]
+	#[case::odd_byte_count("abcdef")]
+	fn test_unmask_token_rejects_malformed_input(#[case] input: &str) {
+		// Act
+		let result = unmask_token(input);
+
+		// Assert
+		assert_eq!(result, None);
+	}
+
+	#[rstest]
+	fn test_masked_token_passes_hmac_check_after_unmasking() {
+		// Arrange
+		let secret = test_secret();
+		let masked = mask_token(&get_token_hmac(&secret, "session-1"));
+
+		// Act
+		let unmasked = unmask_token(&masked).unwrap();
+
+		// Assert
+		assert!(check_token_hmac(&unmasked, &secret, "session-1").is_ok());
+		assert!(check_token_hmac(&masked, &secret, "session-1").is_err());
+	}
+
+	#[rstest]
+	#[case::raw("abcd1234", "abcd1234", true)]
+	#[case::mismatch("abcd1235", "abcd1234", false)]
+	#[case::length_mismatch("abcd", "abcd1234", false)]
+	#[case::missing_cookie("", "", false)]
+	fn test_verify_double_submit(
+		#[case] request_token: &str,
+		#[case] cookie_token: &str,
+		#[case] expected: bool,
+	) {
+		// Act
+		let result = verify_double_submit(request_token, cookie_token);
+
+		// Assert
+		assert_eq!(result, expected);
+	}
+
 	#[rstest]
 	fn test_verify_token_with_timestamp_valid_token() {
 		// Arrange
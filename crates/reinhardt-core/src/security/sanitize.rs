@@ -0,0 +1,867 @@
+//! Allowlist-based HTML sanitization
+//!
+//! [`SanitizePolicy`] cleans untrusted HTML such as comments or rich text
+//! editor output before it is stored or rendered. The input is parsed into
+//! a tree, everything outside the policy's allowlists is dropped, and the
+//! output is serialized from that tree with every text node and attribute
+//! value escaped, so malformed markup cannot leak through.
+//!
+//! - Tags that are not allowed are removed but their content is kept.
+//! - `script`, `style` and other raw-text elements are removed together
+//!   with their content.
+//! - Comments, doctypes and processing instructions are removed.
+//! - Event handler attributes (`on*`) are never kept.
+//! - URL attributes (`href`, `src`, ...) are kept only with an allowed
+//!   scheme or a relative URL.
+//!
+//! ## Example
+//!
+//! ```
+//! use reinhardt_core::security::SanitizePolicy;
+//!
+//! let policy = SanitizePolicy::basic();
+//! let html = r#"<p onclick="steal()">Hi <a href="javascript:alert(1)">there</a><script>alert(1)</script></p>"#;
+//! assert_eq!(policy.clean(html), "<p>Hi <a rel=\"noopener noreferrer\">there</a></p>");
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use super::xss::escape_html;
+
+/// Elements whose content is raw text; they are removed with their content
+const RAW_TEXT_TAGS: [&str; 9] = [
+	"script", "style", "textarea", "title", "xmp", "iframe", "noembed", "noframes", "noscript",
+];
+
+/// Elements that never have content or a closing tag
+const VOID_TAGS: [&str; 13] = [
+	"area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+	"wbr",
+];
+
+/// Attributes holding a URL, checked against the allowed schemes
+const URL_ATTRIBUTES: [&str; 7] = [
+	"href",
+	"src",
+	"cite",
+	"action",
+	"formaction",
+	"poster",
+	"background",
+];
+
+/// Attribute key applying to every tag
+const ANY_TAG: &str = "*";
+
+/// Tags allowed by [`SanitizePolicy::basic`]
+const BASIC_TAGS: [&str; 18] = [
+	"a",
+	"abbr",
+	"b",
+	"blockquote",
+	"br",
+	"code",
+	"em",
+	"i",
+	"li",
+	"ol",
+	"p",
+	"pre",
+	"s",
+	"strong",
+	"sub",
+	"sup",
+	"u",
+	"ul",
+];
+
+/// Tags added by [`SanitizePolicy::rich_text`]
+const RICH_TEXT_TAGS: [&str; 32] = [
+	"h1",
+	"h2",
+	"h3",
+	"h4",
+	"h5",
+	"h6",
+	"hr",
+	"img",
+	"span",
+	"div",
+	"table",
+	"thead",
+	"tbody",
+	"tfoot",
+	"tr",
+	"th",
+	"td",
+	"caption",
+	"figure",
+	"figcaption",
+	"dl",
+	"dt",
+	"dd",
+	"del",
+	"ins",
+	"mark",
+	"small",
+	"cite",
+	"q",
+	"kbd",
+	"samp",
+	"var",
+];
+
+/// `rel` set on links by the built-in policies
+const DEFAULT_LINK_REL: &str = "noopener noreferrer";
+
+/// A node of sanitized HTML
+///
+/// Text is stored unescaped; [`SanitizePolicy::clean`] escapes it when
+/// serializing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SanitizedNode {
+	/// An allowed element
+	Element {
+		/// Lowercase tag name
+		tag: String,
+		/// Allowed attributes, in source order
+		attrs: Vec<(String, String)>,
+		/// Child nodes
+		children: Vec<SanitizedNode>,
+	},
+	/// A text node
+	Text(String),
+}
+
+impl SanitizedNode {
+	/// Whether the element has no content or closing tag
+	pub fn is_void(&self) -> bool {
+		matches!(self, Self::Element { tag, .. } if VOID_TAGS.contains(&tag.as_str()))
+	}
+
+	fn write_html(&self, output: &mut String) {
+		match self {
+			Self::Text(text) => output.push_str(&escape_html(text)),
+			Self::Element {
+				tag,
+				attrs,
+				children,
+			} => {
+				output.push('<');
+				output.push_str(tag);
+				for (name, value) in attrs {
+					output.push(' ');
+					output.push_str(name);
+					output.push_str("=\"");
+					output.push_str(&escape_html(value));
+					output.push('"');
+				}
+				output.push('>');
+				if self.is_void() {
+					return;
+				}
+				for child in children {
+					child.write_html(output);
+				}
+				output.push_str("</");
+				output.push_str(tag);
+				output.push('>');
+			}
+		}
+	}
+}
+
+/// Allowlist of the tags, attributes and URL schemes kept by the sanitizer
+///
+/// Start from one of the built-in policies and extend it:
+///
+/// | Policy | Keeps |
+/// |---|---|
+/// | [`strict`](Self::strict) | text only |
+/// | [`basic`](Self::basic) | inline formatting, paragraphs, lists, quotes, code and links |
+/// | [`rich_text`](Self::rich_text) | `basic` plus headings, images, tables and `class` attributes |
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_core::security::SanitizePolicy;
+///
+/// let policy = SanitizePolicy::basic()
+///     .allow_tags(["span"])
+///     .allow_attributes("span", ["lang"]);
+/// assert_eq!(
+///     policy.clean(r#"<span lang="fr" style="color:red">Bonjour</span>"#),
+///     r#"<span lang="fr">Bonjour</span>"#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SanitizePolicy {
+	tags: HashSet<String>,
+	attributes: HashMap<String, HashSet<String>>,
+	url_schemes: HashSet<String>,
+	link_rel: Option<String>,
+}
+
+impl SanitizePolicy {
+	/// A policy keeping text only
+	///
+	/// Every tag is removed; text is escaped on output.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::security::SanitizePolicy;
+	///
+	/// assert_eq!(SanitizePolicy::strict().clean("<b>1 < 2</b>"), "1 &lt; 2");
+	/// ```
+	pub fn strict() -> Self {
+		Self {
+			tags: HashSet::new(),
+			attributes: HashMap::new(),
+			url_schemes: HashSet::new(),
+			link_rel: None,
+		}
+	}
+
+	/// A policy for comments and other short user content
+	///
+	/// Keeps inline formatting, paragraphs, lists, quotes, code and links
+	/// with `http`, `https` or `mailto` URLs. Links get
+	/// `rel="noopener noreferrer"`.
+	pub fn basic() -> Self {
+		Self::strict()
+			.allow_tags(BASIC_TAGS)
+			.allow_attributes("a", ["href", "title"])
+			.allow_attributes("abbr", ["title"])
+			.allow_attributes("blockquote", ["cite"])
+			.allow_attributes("ol", ["start"])
+			.allow_url_schemes(["http", "https", "mailto"])
+			.link_rel(Some(DEFAULT_LINK_REL))
+	}
+
+	/// A policy for the output of rich text editors
+	///
+	/// Extends [`basic`](Self::basic) with headings, images, tables,
+	/// definition lists and `class` attributes on every kept tag.
+	pub fn rich_text() -> Self {
+		Self::basic()
+			.allow_tags(RICH_TEXT_TAGS)
+			.allow_attributes(ANY_TAG, ["class"])
+			.allow_attributes("img", ["src", "alt", "title", "width", "height"])
+			.allow_attributes("th", ["colspan", "rowspan", "scope"])
+			.allow_attributes("td", ["colspan", "rowspan"])
+			.allow_attributes("q", ["cite"])
+	}
+
+	/// Returns the built-in policy with the given name
+	///
+	/// Accepts `"strict"`, `"basic"` and `"rich_text"`.
+	pub fn named(name: &str) -> Option<Self> {
+		match name {
+			"strict" => Some(Self::strict()),
+			"basic" => Some(Self::basic()),
+			"rich_text" => Some(Self::rich_text()),
+			_ => None,
+		}
+	}
+
+	/// Allows additional tags
+	///
+	/// Raw-text elements such as `script` and `style` are always removed,
+	/// even when listed here.
+	pub fn allow_tags<I, S>(mut self, tags: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: AsRef<str>,
+	{
+		self.tags.extend(
+			tags.into_iter()
+				.map(|tag| tag.as_ref().to_ascii_lowercase()),
+		);
+		self
+	}
+
+	/// Allows attributes on `tag`, or on every kept tag when `tag` is `"*"`
+	///
+	/// Event handler attributes (`on*`) are always removed, even when listed
+	/// here.
+	pub fn allow_attributes<I, S>(mut self, tag: &str, attributes: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: AsRef<str>,
+	{
+		self.attributes
+			.entry(tag.to_ascii_lowercase())
+			.or_default()
+			.extend(
+				attributes
+					.into_iter()
+					.map(|name| name.as_ref().to_ascii_lowercase()),
+			);
+		self
+	}
+
+	/// Allows additional URL schemes in URL attributes
+	///
+	/// Relative URLs are always allowed.
+	pub fn allow_url_schemes<I, S>(mut self, schemes: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: AsRef<str>,
+	{
+		self.url_schemes.extend(
+			schemes
+				.into_iter()
+				.map(|scheme| scheme.as_ref().to_ascii_lowercase()),
+		);
+		self
+	}
+
+	/// Sets the `rel` attribute forced on kept links, or `None` to keep the
+	/// link's own `rel` if it is allowed
+	pub fn link_rel(mut self, rel: Option<&str>) -> Self {
+		self.link_rel = rel.map(str::to_string);
+		self
+	}
+
+	/// Whether `tag` is kept by this policy
+	pub fn is_tag_allowed(&self, tag: &str) -> bool {
+		!RAW_TEXT_TAGS.contains(&tag) && self.tags.contains(tag)
+	}
+
+	/// Sanitizes `html` and serializes the result
+	pub fn clean(&self, html: &str) -> String {
+		let mut output = String::with_capacity(html.len());
+		for node in self.clean_nodes(html) {
+			node.write_html(&mut output);
+		}
+		output
+	}
+
+	/// Sanitizes `html` into a tree of nodes
+	///
+	/// Unclosed elements are closed at the end of their parent, and stray
+	/// closing tags are ignored.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::security::{SanitizePolicy, SanitizedNode};
+	///
+	/// let nodes = SanitizePolicy::basic().clean_nodes("<b>bold &amp; <i>italic");
+	/// assert_eq!(
+	///     nodes,
+	///     vec![SanitizedNode::Element {
+	///         tag: "b".into(),
+	///         attrs: vec![],
+	///         children: vec![
+	///             SanitizedNode::Text("bold & ".into()),
+	///             SanitizedNode::Element {
+	///                 tag: "i".into(),
+	///                 attrs: vec![],
+	///                 children: vec![SanitizedNode::Text("italic".into())],
+	///             },
+	///         ],
+	///     }]
+	/// );
+	/// ```
+	pub fn clean_nodes(&self, html: &str) -> Vec<SanitizedNode> {
+		let mut builder = TreeBuilder::default();
+		for token in Tokenizer::new(html) {
+			match token {
+				Token::Text(text) => builder.text(text),
+				Token::Start {
+					name,
+					attrs,
+					self_closing,
+				} => {
+					if !self.is_tag_allowed(&name) {
+						continue;
+					}
+					let attrs = self.clean_attributes(&name, attrs);
+					let void = VOID_TAGS.contains(&name.as_str());
+					builder.open(name, attrs);
+					if void || self_closing {
+						builder.close_current();
+					}
+				}
+				Token::End(name) => {
+					if self.is_tag_allowed(&name) {
+						builder.close(&name);
+					}
+				}
+			}
+		}
+		builder.finish()
+	}
+
+	fn clean_attributes(&self, tag: &str, attrs: Vec<(String, String)>) -> Vec<(String, String)> {
+		let allowed = |name: &str| {
+			[tag, ANY_TAG].iter().any(|key| {
+				self.attributes
+					.get(*key)
+					.is_some_and(|names| names.contains(name))
+			})
+		};
+		let mut kept: Vec<(String, String)> = Vec::new();
+		for (name, value) in attrs {
+			if name.starts_with("on")
+				|| !allowed(&name)
+				|| kept.iter().any(|(existing, _)| *existing == name)
+				|| (URL_ATTRIBUTES.contains(&name.as_str()) && !self.is_url_allowed(&value))
+			{
+				continue;
+			}
+			kept.push((name, value));
+		}
+		if tag == "a"
+			&& let Some(rel) = &self.link_rel
+		{
+			kept.retain(|(name, _)| name != "rel");
+			kept.push(("rel".to_string(), rel.clone()));
+		}
+		kept
+	}
+
+	/// Whether `url` is relative or uses an allowed scheme
+	fn is_url_allowed(&self, url: &str) -> bool {
+		// Browsers ignore whitespace and control characters inside URLs,
+		// so `java\tscript:` must be read as `javascript:`
+		let url: String = url
+			.chars()
+			.filter(|c| !c.is_ascii_whitespace() && !c.is_control())
+			.collect();
+		match url.find([':', '/', '?', '#']) {
+			Some(index) if url[index..].starts_with(':') => self
+				.url_schemes
+				.contains(&url[..index].to_ascii_lowercase()),
+			_ => true,
+		}
+	}
+}
+
+impl Default for SanitizePolicy {
+	/// Same as [`SanitizePolicy::basic`]
+	fn default() -> Self {
+		Self::basic()
+	}
+}
+
+/// Sanitizes `html` with `policy`
+///
+/// Shorthand for [`SanitizePolicy::clean`].
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_core::security::{SanitizePolicy, sanitize};
+///
+/// let html = r#"<img src="x" onerror="alert(1)"><b>Hello</b>"#;
+/// assert_eq!(sanitize(html, &SanitizePolicy::basic()), "<b>Hello</b>");
+/// ```
+pub fn sanitize(html: &str, policy: &SanitizePolicy) -> String {
+	policy.clean(html)
+}
+
+/// Token produced by [`Tokenizer`]
+#[derive(Debug, PartialEq, Eq)]
+enum Token {
+	Text(String),
+	Start {
+		name: String,
+		attrs: Vec<(String, String)>,
+		self_closing: bool,
+	},
+	End(String),
+}
+
+/// Lenient HTML tokenizer
+///
+/// Comments, doctypes and processing instructions are skipped, and
+/// raw-text elements are skipped together with their content.
+struct Tokenizer<'a> {
+	input: &'a str,
+	pos: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+	fn new(input: &'a str) -> Self {
+		Self { input, pos: 0 }
+	}
+
+	fn rest(&self) -> &'a str {
+		&self.input[self.pos..]
+	}
+
+	/// Advances past the next occurrence of `pattern`, or to the end
+	fn skip_past(&mut self, pattern: &str) {
+		self.pos = match self.rest().find(pattern) {
+			Some(index) => self.pos + index + pattern.len(),
+			None => self.input.len(),
+		};
+	}
+
+	/// Advances past the closing tag of the raw-text element `name`
+	fn skip_raw_text(&mut self, name: &str) {
+		let closing = format!("</{}", name);
+		let lower = self.rest().to_ascii_lowercase();
+		match lower.find(&closing) {
+			Some(index) => {
+				self.pos += index;
+				self.skip_past(">");
+			}
+			None => self.pos = self.input.len(),
+		}
+	}
+
+	fn tag_name(&mut self) -> String {
+		let rest = self.rest();
+		let end = rest
+			.find(|c: char| c.is_ascii_whitespace() || c == '/' || c == '>')
+			.unwrap_or(rest.len());
+		self.pos += end;
+		rest[..end].to_ascii_lowercase()
+	}
+
+	fn skip_whitespace(&mut self) {
+		let rest = self.rest();
+		self.pos += rest.len() - rest.trim_start().len();
+	}
+
+	/// Reads attributes up to and including the end of the tag
+	///
+	/// Returns the attributes and whether the tag was self-closing.
+	fn attributes(&mut self) -> (Vec<(String, String)>, bool) {
+		let mut attrs = Vec::new();
+		loop {
+			self.skip_whitespace();
+			let rest = self.rest();
+			if rest.is_empty() {
+				return (attrs, false);
+			}
+			if let Some(after) = rest.strip_prefix("/>") {
+				self.pos = self.input.len() - after.len();
+				return (attrs, true);
+			}
+			if let Some(after) = rest.strip_prefix('>') {
+				self.pos = self.input.len() - after.len();
+				return (attrs, false);
+			}
+			if rest.starts_with('/') {
+				self.pos += 1;
+				continue;
+			}
+
+			let end = rest
+				.find(|c: char| c.is_ascii_whitespace() || c == '=' || c == '>' || c == '/')
+				.unwrap_or(rest.len())
+				.max(1);
+			let name = rest[..end].to_ascii_lowercase();
+			self.pos += end;
+			self.skip_whitespace();
+
+			let mut value = String::new();
+			if self.rest().starts_with('=') {
+				self.pos += 1;
+				self.skip_whitespace();
+				let rest = self.rest();
+				let raw = match rest.chars().next() {
+					Some(quote @ ('"' | '\'')) => {
+						let inner = &rest[1..];
+						let len = inner.find(quote).unwrap_or(inner.len());
+						self.pos += 1 + len + usize::from(len < inner.len());
+						&inner[..len]
+					}
+					_ => {
+						let len = rest
+							.find(|c: char| c.is_ascii_whitespace() || c == '>')
+							.unwrap_or(rest.len());
+						self.pos += len;
+						&rest[..len]
+					}
+				};
+				value = decode_entities(raw);
+			}
+			attrs.push((name, value));
+		}
+	}
+}
+
+impl Iterator for Tokenizer<'_> {
+	type Item = Token;
+
+	fn next(&mut self) -> Option<Token> {
+		loop {
+			let rest = self.rest();
+			if rest.is_empty() {
+				return None;
+			}
+			if !rest.starts_with('<') {
+				let end = rest.find('<').unwrap_or(rest.len());
+				self.pos += end;
+				return Some(Token::Text(decode_entities(&rest[..end])));
+			}
+
+			let mut chars = rest[1..].chars();
+			match (chars.next(), chars.next()) {
+				(Some('!'), _) if rest.starts_with("<!--") => {
+					self.pos += 4;
+					self.skip_past("-->");
+				}
+				(Some('!' | '?'), _) => self.skip_past(">"),
+				(Some('/'), Some(c)) if c.is_ascii_alphabetic() => {
+					self.pos += 2;
+					let name = self.tag_name();
+					self.skip_past(">");
+					return Some(Token::End(name));
+				}
+				(Some(c), _) if c.is_ascii_alphabetic() => {
+					self.pos += 1;
+					let name = self.tag_name();
+					let (attrs, self_closing) = self.attributes();
+					if RAW_TEXT_TAGS.contains(&name.as_str()) {
+						// Browsers ignore `/>` on these elements, so the
+						// content always runs to the closing tag
+						self.skip_raw_text(&name);
+						continue;
+					}
+					return Some(Token::Start {
+						name,
+						attrs,
+						self_closing,
+					});
+				}
+				_ => {
+					// A `<` that does not start a tag is text
+					self.pos += 1;
+					return Some(Token::Text("<".to_string()));
+				}
+			}
+		}
+	}
+}
+
+/// Builds the node tree from the allowed tokens
+#[derive(Default)]
+struct TreeBuilder {
+	roots: Vec<SanitizedNode>,
+	open: Vec<(String, Vec<(String, String)>, Vec<SanitizedNode>)>,
+}
+
+impl TreeBuilder {
+	fn push(&mut self, node: SanitizedNode) {
+		let siblings = match self.open.last_mut() {
+			Some((_, _, children)) => children,
+			None => &mut self.roots,
+		};
+		if let (SanitizedNode::Text(text), Some(SanitizedNode::Text(previous))) =
+			(&node, siblings.last_mut())
+		{
+			previous.push_str(text);
+			return;
+		}
+		siblings.push(node);
+	}
+
+	fn text(&mut self, text: String) {
+		if !text.is_empty() {
+			self.push(SanitizedNode::Text(text));
+		}
+	}
+
+	fn open(&mut self, tag: String, attrs: Vec<(String, String)>) {
+		self.open.push((tag, attrs, Vec::new()));
+	}
+
+	fn close_current(&mut self) {
+		if let Some((tag, attrs, children)) = self.open.pop() {
+			self.push(SanitizedNode::Element {
+				tag,
+				attrs,
+				children,
+			});
+		}
+	}
+
+	/// Closes `tag` and every element opened inside it
+	fn close(&mut self, tag: &str) {
+		if let Some(index) = self.open.iter().rposition(|(open, _, _)| open == tag) {
+			while self.open.len() > index {
+				self.close_current();
+			}
+		}
+	}
+
+	fn finish(mut self) -> Vec<SanitizedNode> {
+		while !self.open.is_empty() {
+			self.close_current();
+		}
+		self.roots
+	}
+}
+
+/// Decodes character references in text and attribute values
+///
+/// Unknown named references are left as they are.
+fn decode_entities(input: &str) -> String {
+	let mut output = String::with_capacity(input.len());
+	let mut rest = input;
+	while let Some(index) = rest.find('&') {
+		output.push_str(&rest[..index]);
+		rest = &rest[index..];
+		let Some(end) = rest[1..]
+			.find(|c: char| !c.is_ascii_alphanumeric() && c != '#')
+			.map(|end| end + 1)
+			.filter(|end| rest[*end..].starts_with(';'))
+		else {
+			output.push('&');
+			rest = &rest[1..];
+			continue;
+		};
+		let name = &rest[1..end];
+		let decoded = match name {
+			"amp" => Some('&'),
+			"lt" => Some('<'),
+			"gt" => Some('>'),
+			"quot" => Some('"'),
+			"apos" => Some('\''),
+			"nbsp" => Some('\u{a0}'),
+			_ => name.strip_prefix('#').and_then(|number| {
+				let code = match number.strip_prefix(['x', 'X']) {
+					Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+					None => number.parse().ok()?,
+				};
+				Some(
+					char::from_u32(code)
+						.filter(|c| *c != '\0')
+						.unwrap_or('\u{fffd}'),
+				)
+			}),
+		};
+		match decoded {
+			Some(c) => {
+				output.push(c);
+				rest = &rest[end + 1..];
+			}
+			None => {
+				output.push('&');
+				rest = &rest[1..];
+			}
+		}
+	}
+	output.push_str(rest);
+	output
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case::script_removed_with_content("<p>a<script>alert(1)</script>b</p>", "<p>ab</p>")]
+	#[case::style_removed_with_content("<style>p{}</style>text", "text")]
+	#[case::uppercase_script("<SCRIPT>alert(1)</SCRIPT >ok", "ok")]
+	#[case::unclosed_script("ok<script>alert(1)", "ok")]
+	#[case::self_closing_script("<script/>alert(1)</script>ok", "ok")]
+	#[case::event_handler("<b onmouseover=\"x()\">b</b>", "<b>b</b>")]
+	#[case::disallowed_tag_unwrapped("<div><b>kept</b></div>", "<b>kept</b>")]
+	#[case::comment("a<!-- <script>x</script> -->b", "ab")]
+	#[case::javascript_url(
+		"<a href=\"javascript:alert(1)\">x</a>",
+		"<a rel=\"noopener noreferrer\">x</a>"
+	)]
+	#[case::obfuscated_url(
+		"<a href=\"java&#x09;script:alert(1)\">x</a>",
+		"<a rel=\"noopener noreferrer\">x</a>"
+	)]
+	#[case::relative_url(
+		"<a href=\"/docs/?q=1\">x</a>",
+		"<a href=\"/docs/?q=1\" rel=\"noopener noreferrer\">x</a>"
+	)]
+	#[case::attribute_quotes_escaped(
+		"<a title='say \"hi\"'>x</a>",
+		"<a title=\"say &quot;hi&quot;\" rel=\"noopener noreferrer\">x</a>"
+	)]
+	#[case::stray_closing_tag("</p>text</b>", "text")]
+	#[case::unclosed_tags("<b><i>x", "<b><i>x</i></b>")]
+	#[case::misnested_tags("<b><i>x</b>y</i>", "<b><i>x</i></b>y")]
+	#[case::lone_angle_bracket("1 < 2 & 3 > 2", "1 &lt; 2 &amp; 3 &gt; 2")]
+	#[case::entities_preserved("&lt;b&gt; &amp;amp;", "&lt;b&gt; &amp;amp;")]
+	#[case::void_tag("a<br/>b<br>c", "a<br>b<br>c")]
+	fn test_basic_policy(#[case] input: &str, #[case] expected: &str) {
+		// Act
+		let output = SanitizePolicy::basic().clean(input);
+
+		// Assert
+		assert_eq!(output, expected);
+	}
+
+	#[rstest]
+	fn test_rich_text_policy_keeps_images_and_classes() {
+		// Arrange
+		let input = r#"<h2 class="title" id="x">T</h2><img src="https://cdn.example.com/a.png" alt="A" onerror="x()"><img src="data:image/svg+xml,x">"#;
+
+		// Act
+		let output = SanitizePolicy::rich_text().clean(input);
+
+		// Assert
+		assert_eq!(
+			output,
+			r#"<h2 class="title">T</h2><img src="https://cdn.example.com/a.png" alt="A"><img>"#
+		);
+	}
+
+	#[rstest]
+	fn test_allow_tags_never_keeps_raw_text_elements() {
+		// Arrange
+		let policy = SanitizePolicy::basic().allow_tags(["script", "style"]);
+
+		// Act
+		let output = policy.clean("<script>alert(1)</script><style>*{}</style>ok");
+
+		// Assert
+		assert_eq!(output, "ok");
+	}
+
+	#[rstest]
+	fn test_link_rel_none_keeps_allowed_rel() {
+		// Arrange
+		let policy = SanitizePolicy::basic()
+			.link_rel(None)
+			.allow_attributes("a", ["rel"]);
+
+		// Act
+		let output = policy.clean(r#"<a href="https://example.com" rel="me">x</a>"#);
+
+		// Assert
+		assert_eq!(output, r#"<a href="https://example.com" rel="me">x</a>"#);
+	}
+
+	#[rstest]
+	#[case::named("&amp;&lt;&gt;&quot;&apos;", "&<>\"'")]
+	#[case::numeric("&#65;&#x42;", "AB")]
+	#[case::invalid_code_point("&#0;&#x110000;", "\u{fffd}\u{fffd}")]
+	#[case::unknown_named("&copy; & ;", "&copy; & ;")]
+	fn test_decode_entities(#[case] input: &str, #[case] expected: &str) {
+		// Act
+		let decoded = decode_entities(input);
+
+		// Assert
+		assert_eq!(decoded, expected);
+	}
+
+	#[rstest]
+	#[case::strict("strict", "x")]
+	#[case::basic("basic", "<b>x</b>")]
+	#[case::unknown("lenient", "")]
+	fn test_named_policy(#[case] name: &str, #[case] expected: &str) {
+		// Act
+		let output = SanitizePolicy::named(name)
+			.map(|policy| policy.clean("<b>x</b>"))
+			.unwrap_or_default();
+
+		// Assert
+		assert_eq!(output, expected);
+	}
+}
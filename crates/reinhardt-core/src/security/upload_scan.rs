@@ -0,0 +1,491 @@
+//! Upload scanning hooks
+//!
+//! Provides the [`UploadScanner`] trait invoked on user uploads before they
+//! are accepted: by form file fields, storage save helpers and the admin
+//! importer. A scanner inspects the raw bytes and returns a [`ScanVerdict`];
+//! what happens to a flagged file is decided by the [`ScanPolicy`].
+//!
+//! Two scanners are provided:
+//!
+//! - [`FileTypeScanner`]: flags executables and scripts by their magic bytes,
+//!   whatever extension they were uploaded with.
+//! - `ClamAvScanner` (feature `clamav`): streams the file to a `clamd` daemon
+//!   over TCP or a Unix socket using the `INSTREAM` command.
+//!
+//! Scanning fails closed: callers treat a [`ScanError`] like a flagged file,
+//! since an unreachable scanner must not let unchecked files through.
+//!
+//! # Example
+//!
+//! ```
+//! use reinhardt_core::security::upload_scan::{FileTypeScanner, ScanVerdict, UploadScanner};
+//!
+//! let scanner = FileTypeScanner::new();
+//! assert_eq!(scanner.scan("notes.txt", b"hello").unwrap(), ScanVerdict::Clean);
+//! assert!(scanner.scan("invoice.pdf", b"MZ\x90\x00").unwrap().is_flagged());
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Result of scanning an uploaded file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScanVerdict {
+	/// No threat was found.
+	Clean,
+	/// The file was flagged; `signature` names what matched.
+	Flagged {
+		/// Signature or rule that matched (e.g. `Eicar-Test-Signature`).
+		signature: String,
+	},
+}
+
+impl ScanVerdict {
+	/// Creates a flagged verdict for the given signature.
+	pub fn flagged(signature: impl Into<String>) -> Self {
+		Self::Flagged {
+			signature: signature.into(),
+		}
+	}
+
+	/// Whether the file was flagged.
+	pub fn is_flagged(&self) -> bool {
+		matches!(self, Self::Flagged { .. })
+	}
+}
+
+/// Errors raised when a file could not be scanned.
+#[non_exhaustive]
+#[derive(Debug, thiserror::Error)]
+pub enum ScanError {
+	/// The scanner could not be reached.
+	#[error("Upload scanner unavailable: {0}")]
+	Unavailable(String),
+
+	/// The scanner answered with something unexpected.
+	#[error("Upload scanner protocol error: {0}")]
+	Protocol(String),
+
+	/// The file exceeds what the scanner accepts.
+	#[error("File too large to scan")]
+	TooLarge,
+}
+
+/// Scanner invoked on uploaded file contents before they are stored.
+///
+/// Implementations are synchronous so they can run from form validation and
+/// blocking save paths alike; async callers should run them on a blocking
+/// thread when the scanner performs network I/O.
+pub trait UploadScanner: Send + Sync {
+	/// Scans `content`, uploaded under the client-supplied `name`.
+	fn scan(&self, name: &str, content: &[u8]) -> Result<ScanVerdict, ScanError>;
+}
+
+impl<S: UploadScanner + ?Sized> UploadScanner for std::sync::Arc<S> {
+	fn scan(&self, name: &str, content: &[u8]) -> Result<ScanVerdict, ScanError> {
+		(**self).scan(name, content)
+	}
+}
+
+/// What to do with a file the scanner flagged.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum ScanPolicy {
+	/// Refuse the upload; nothing is stored.
+	#[default]
+	Reject,
+	/// Store the file under the quarantine location instead of its
+	/// destination, and report the upload as refused.
+	Quarantine,
+}
+
+impl fmt::Display for ScanPolicy {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Reject => "reject",
+			Self::Quarantine => "quarantine",
+		})
+	}
+}
+
+impl FromStr for ScanPolicy {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.trim().to_ascii_lowercase().as_str() {
+			"reject" => Ok(Self::Reject),
+			"quarantine" => Ok(Self::Quarantine),
+			other => Err(format!(
+				"Unknown upload scan policy '{}': expected 'reject' or 'quarantine'",
+				other
+			)),
+		}
+	}
+}
+
+/// Magic bytes of executable and script formats rejected by
+/// [`FileTypeScanner`], with the signature reported for each.
+const EXECUTABLE_SIGNATURES: &[(&[u8], &str)] = &[
+	(b"MZ", "Executable.Windows.PE"),
+	(b"\x7fELF", "Executable.Linux.ELF"),
+	(b"\xfe\xed\xfa\xce", "Executable.MacOS.MachO"),
+	(b"\xfe\xed\xfa\xcf", "Executable.MacOS.MachO"),
+	(b"\xce\xfa\xed\xfe", "Executable.MacOS.MachO"),
+	(b"\xcf\xfa\xed\xfe", "Executable.MacOS.MachO"),
+	(b"\xca\xfe\xba\xbe", "Executable.MacOS.Universal"),
+	(b"#!", "Script.Shebang"),
+];
+
+/// Scanner flagging executables and scripts by their leading bytes.
+///
+/// The extension of the uploaded name is not trusted: a Windows executable
+/// renamed to `invoice.pdf` is still flagged. Signatures can be extended
+/// with [`with_signature`](Self::with_signature).
+#[derive(Debug, Clone)]
+pub struct FileTypeScanner {
+	signatures: Vec<(Vec<u8>, String)>,
+}
+
+impl FileTypeScanner {
+	/// Creates a scanner with the built-in executable signatures.
+	pub fn new() -> Self {
+		Self {
+			signatures: EXECUTABLE_SIGNATURES
+				.iter()
+				.map(|(magic, name)| (magic.to_vec(), name.to_string()))
+				.collect(),
+		}
+	}
+
+	/// Adds a signature flagging files that start with `magic`.
+	pub fn with_signature(mut self, magic: impl Into<Vec<u8>>, name: impl Into<String>) -> Self {
+		self.signatures.push((magic.into(), name.into()));
+		self
+	}
+}
+
+impl Default for FileTypeScanner {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl UploadScanner for FileTypeScanner {
+	fn scan(&self, _name: &str, content: &[u8]) -> Result<ScanVerdict, ScanError> {
+		Ok(self
+			.signatures
+			.iter()
+			.find(|(magic, _)| content.starts_with(magic))
+			.map(|(_, name)| ScanVerdict::flagged(name.clone()))
+			.unwrap_or(ScanVerdict::Clean))
+	}
+}
+
+#[cfg(feature = "clamav")]
+pub use clamav::{ClamAvAddress, ClamAvScanner};
+
+#[cfg(feature = "clamav")]
+mod clamav {
+	use super::{ScanError, ScanVerdict, UploadScanner};
+	use std::io::{Read, Write};
+	use std::net::TcpStream;
+	use std::path::PathBuf;
+	use std::str::FromStr;
+	use std::time::Duration;
+
+	/// Default size of the chunks streamed to `clamd`.
+	const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+	/// Largest reply read from `clamd`.
+	const MAX_REPLY_SIZE: u64 = 4096;
+
+	/// Address of a `clamd` daemon.
+	#[derive(Debug, Clone, PartialEq, Eq)]
+	pub enum ClamAvAddress {
+		/// TCP `host:port`.
+		Tcp(String),
+		/// Path of a Unix domain socket.
+		Unix(PathBuf),
+	}
+
+	impl FromStr for ClamAvAddress {
+		type Err = String;
+
+		/// Parses `unix:///path/to/clamd.sock`, `tcp://host:port` or a bare
+		/// `host:port`.
+		fn from_str(s: &str) -> Result<Self, Self::Err> {
+			if let Some(path) = s.strip_prefix("unix://") {
+				return Ok(Self::Unix(PathBuf::from(path)));
+			}
+			let address = s.strip_prefix("tcp://").unwrap_or(s);
+			if address
+				.rsplit_once(':')
+				.is_some_and(|(host, port)| !host.is_empty() && port.parse::<u16>().is_ok())
+			{
+				Ok(Self::Tcp(address.to_string()))
+			} else {
+				Err(format!("Invalid clamd address '{}'", s))
+			}
+		}
+	}
+
+	/// Scanner streaming uploads to a ClamAV `clamd` daemon.
+	///
+	/// Each scan opens a connection and sends the content with the
+	/// `INSTREAM` command. `clamd` rejects streams above its
+	/// `StreamMaxLength`, which is reported as [`ScanError::TooLarge`].
+	#[derive(Debug, Clone)]
+	pub struct ClamAvScanner {
+		address: ClamAvAddress,
+		timeout: Duration,
+		chunk_size: usize,
+	}
+
+	impl ClamAvScanner {
+		/// Creates a scanner for the `clamd` daemon at `address`.
+		pub fn new(address: ClamAvAddress) -> Self {
+			Self {
+				address,
+				timeout: Duration::from_secs(30),
+				chunk_size: DEFAULT_CHUNK_SIZE,
+			}
+		}
+
+		/// Sets the connect, read and write timeout (default: 30 seconds).
+		pub fn with_timeout(mut self, timeout: Duration) -> Self {
+			self.timeout = timeout;
+			self
+		}
+
+		/// Sets the size of the chunks streamed to `clamd` (default: 64 KiB).
+		pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+			self.chunk_size = chunk_size.max(1);
+			self
+		}
+
+		/// The daemon address.
+		pub fn address(&self) -> &ClamAvAddress {
+			&self.address
+		}
+
+		/// Checks that the daemon answers `PING`.
+		pub fn ping(&self) -> Result<(), ScanError> {
+			let reply = self.exchange(|stream| {
+				stream.write_all(b"zPING\0")?;
+				Ok(())
+			})?;
+			if reply == "PONG" {
+				Ok(())
+			} else {
+				Err(ScanError::Protocol(reply))
+			}
+		}
+
+		fn connect(&self) -> Result<Box<dyn ReadWrite>, ScanError> {
+			let unavailable = |e: std::io::Error| ScanError::Unavailable(e.to_string());
+			match &self.address {
+				ClamAvAddress::Tcp(address) => {
+					let stream = TcpStream::connect(address).map_err(unavailable)?;
+					stream
+						.set_read_timeout(Some(self.timeout))
+						.and_then(|_| stream.set_write_timeout(Some(self.timeout)))
+						.map_err(unavailable)?;
+					Ok(Box::new(stream))
+				}
+				#[cfg(unix)]
+				ClamAvAddress::Unix(path) => {
+					let stream =
+						std::os::unix::net::UnixStream::connect(path).map_err(unavailable)?;
+					stream
+						.set_read_timeout(Some(self.timeout))
+						.and_then(|_| stream.set_write_timeout(Some(self.timeout)))
+						.map_err(unavailable)?;
+					Ok(Box::new(stream))
+				}
+				#[cfg(not(unix))]
+				ClamAvAddress::Unix(path) => Err(ScanError::Unavailable(format!(
+					"Unix sockets are not supported on this platform: {}",
+					path.display()
+				))),
+			}
+		}
+
+		/// Sends a request written by `send` and reads the NUL-terminated reply.
+		fn exchange(
+			&self,
+			send: impl FnOnce(&mut dyn ReadWrite) -> std::io::Result<()>,
+		) -> Result<String, ScanError> {
+			let mut stream = self.connect()?;
+			send(stream.as_mut()).map_err(|e| ScanError::Unavailable(e.to_string()))?;
+			let mut reply = Vec::new();
+			stream
+				.take(MAX_REPLY_SIZE)
+				.read_to_end(&mut reply)
+				.map_err(|e| ScanError::Unavailable(e.to_string()))?;
+			Ok(String::from_utf8_lossy(&reply)
+				.trim_end_matches(['\0', '\n'])
+				.to_string())
+		}
+	}
+
+	impl UploadScanner for ClamAvScanner {
+		fn scan(&self, _name: &str, content: &[u8]) -> Result<ScanVerdict, ScanError> {
+			let reply = self.exchange(|stream| {
+				stream.write_all(b"zINSTREAM\0")?;
+				for chunk in content.chunks(self.chunk_size) {
+					stream.write_all(&(chunk.len() as u32).to_be_bytes())?;
+					stream.write_all(chunk)?;
+				}
+				stream.write_all(&0u32.to_be_bytes())?;
+				stream.flush()
+			})?;
+			parse_reply(&reply)
+		}
+	}
+
+	/// Parses an `INSTREAM` reply such as `stream: OK` or
+	/// `stream: Eicar-Test-Signature FOUND`.
+	pub(super) fn parse_reply(reply: &str) -> Result<ScanVerdict, ScanError> {
+		let result = reply.strip_prefix("stream:").unwrap_or(reply).trim();
+		if result == "OK" {
+			Ok(ScanVerdict::Clean)
+		} else if let Some(signature) = result.strip_suffix(" FOUND") {
+			Ok(ScanVerdict::flagged(signature.trim()))
+		} else if result.contains("size limit exceeded") {
+			Err(ScanError::TooLarge)
+		} else {
+			Err(ScanError::Protocol(reply.to_string()))
+		}
+	}
+
+	/// Byte stream to `clamd`, over TCP or a Unix socket.
+	trait ReadWrite: Read + Write {}
+
+	impl<T: Read + Write> ReadWrite for T {}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case(b"MZ\x90\x00\x03".as_slice(), Some("Executable.Windows.PE"))]
+	#[case(b"\x7fELF\x02\x01".as_slice(), Some("Executable.Linux.ELF"))]
+	#[case(b"#!/bin/sh\nrm -rf /".as_slice(), Some("Script.Shebang"))]
+	#[case(b"%PDF-1.7".as_slice(), None)]
+	#[case(b"".as_slice(), None)]
+	fn test_file_type_scanner(#[case] content: &[u8], #[case] expected: Option<&str>) {
+		// Arrange
+		let scanner = FileTypeScanner::new();
+
+		// Act
+		let verdict = scanner.scan("upload.pdf", content).unwrap();
+
+		// Assert
+		assert_eq!(
+			verdict,
+			expected.map_or(ScanVerdict::Clean, ScanVerdict::flagged)
+		);
+	}
+
+	#[rstest]
+	fn test_file_type_scanner_custom_signature() {
+		// Arrange
+		let scanner = FileTypeScanner::new().with_signature(b"PK\x03\x04".to_vec(), "Archive.Zip");
+
+		// Act
+		let verdict = scanner.scan("photo.jpg", b"PK\x03\x04rest").unwrap();
+
+		// Assert
+		assert_eq!(verdict, ScanVerdict::flagged("Archive.Zip"));
+	}
+
+	#[rstest]
+	#[case("reject", Ok(ScanPolicy::Reject))]
+	#[case(" Quarantine ", Ok(ScanPolicy::Quarantine))]
+	fn test_scan_policy_from_str(#[case] input: &str, #[case] expected: Result<ScanPolicy, ()>) {
+		// Act / Assert
+		assert_eq!(input.parse::<ScanPolicy>().map_err(|_| ()), expected);
+	}
+
+	#[rstest]
+	fn test_scan_policy_rejects_unknown_value() {
+		// Act
+		let result = "delete".parse::<ScanPolicy>();
+
+		// Assert
+		assert!(result.unwrap_err().contains("delete"));
+	}
+
+	#[cfg(feature = "clamav")]
+	#[rstest]
+	#[case("stream: OK", Some(ScanVerdict::Clean))]
+	#[case(
+		"stream: Eicar-Test-Signature FOUND",
+		Some(ScanVerdict::flagged("Eicar-Test-Signature"))
+	)]
+	#[case("INSTREAM size limit exceeded. ERROR", None)]
+	fn test_clamav_parse_reply(#[case] reply: &str, #[case] expected: Option<ScanVerdict>) {
+		// Act
+		let result = clamav::parse_reply(reply);
+
+		// Assert
+		match expected {
+			Some(verdict) => assert_eq!(result.unwrap(), verdict),
+			None => assert!(matches!(result, Err(ScanError::TooLarge))),
+		}
+	}
+
+	#[cfg(feature = "clamav")]
+	#[rstest]
+	#[case("unix:///run/clamav/clamd.sock", Some(ClamAvAddress::Unix("/run/clamav/clamd.sock".into())))]
+	#[case("tcp://clamav:3310", Some(ClamAvAddress::Tcp("clamav:3310".into())))]
+	#[case("127.0.0.1:3310", Some(ClamAvAddress::Tcp("127.0.0.1:3310".into())))]
+	#[case("clamav", None)]
+	fn test_clamav_address_from_str(#[case] input: &str, #[case] expected: Option<ClamAvAddress>) {
+		// Act / Assert
+		assert_eq!(input.parse::<ClamAvAddress>().ok(), expected);
+	}
+
+	#[cfg(feature = "clamav")]
+	#[rstest]
+	fn test_clamav_scan_streams_instream_chunks() {
+		// Arrange
+		let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+		let address = listener.local_addr().unwrap().to_string();
+		let server = std::thread::spawn(move || {
+			use std::io::{Read, Write};
+			let (mut stream, _) = listener.accept().unwrap();
+			let mut command = [0u8; 10];
+			stream.read_exact(&mut command).unwrap();
+			let mut received = Vec::new();
+			loop {
+				let mut length = [0u8; 4];
+				stream.read_exact(&mut length).unwrap();
+				let length = u32::from_be_bytes(length) as usize;
+				if length == 0 {
+					break;
+				}
+				let mut chunk = vec![0u8; length];
+				stream.read_exact(&mut chunk).unwrap();
+				received.extend(chunk);
+			}
+			stream
+				.write_all(b"stream: Eicar-Test-Signature FOUND\0")
+				.unwrap();
+			(command, received)
+		});
+		let scanner = ClamAvScanner::new(ClamAvAddress::Tcp(address)).with_chunk_size(4);
+
+		// Act
+		let verdict = scanner.scan("eicar.txt", b"X5O!P%@AP[4\\PZX").unwrap();
+
+		// Assert
+		let (command, received) = server.join().unwrap();
+		assert_eq!(&command, b"zINSTREAM\0");
+		assert_eq!(received, b"X5O!P%@AP[4\\PZX");
+		assert_eq!(verdict, ScanVerdict::flagged("Eicar-Test-Signature"));
+	}
+}
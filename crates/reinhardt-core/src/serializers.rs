@@ -12,6 +12,7 @@
 //! - **Validation**: Field-level and object-level validation support
 //! - **Recursive Serialization**: Depth tracking and circular reference detection
 //! - **Arena Allocation**: Memory-efficient serialization for deeply nested structures
+//! - **JSON Options**: camelCase keys, pretty printing, datetime format and strict float handling
 //!
 //! ## Feature Flags
 //!
@@ -38,11 +39,13 @@
 
 pub mod arena;
 pub mod fields;
+pub mod json_options;
 pub mod recursive;
 pub mod serializer;
 pub mod validator;
 
 // Re-export commonly used types
+pub use json_options::{DateTimeFormat, JsonOptions, KeyCase};
 pub use serializer::{Deserializer, JsonSerializer, Serializer, SerializerError, ValidatorError};
 pub use validator::{
 	FieldLevelValidation, FieldValidator, ObjectLevelValidation, ObjectValidator, ValidationError,
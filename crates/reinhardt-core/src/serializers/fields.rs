@@ -3,7 +3,7 @@
 //! This module provides Django REST Framework-inspired field types for data validation
 //! and transformation in serializers.
 
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
 use std::fmt;
 
 /// Errors that can occur during field validation
@@ -36,6 +36,8 @@ pub enum FieldError {
 	InvalidDate,
 	/// Invalid datetime format
 	InvalidDateTime,
+	/// Invalid base64 encoding
+	InvalidBase64,
 	/// Custom validation error with message
 	Custom(String),
 }
@@ -56,6 +58,7 @@ impl fmt::Display for FieldError {
 			FieldError::InvalidChoice => write!(f, "Invalid choice"),
 			FieldError::InvalidDate => write!(f, "Invalid date format"),
 			FieldError::InvalidDateTime => write!(f, "Invalid datetime format"),
+			FieldError::InvalidBase64 => write!(f, "Invalid base64 encoding"),
 			FieldError::Custom(msg) => write!(f, "{}", msg),
 		}
 	}
@@ -863,6 +866,18 @@ impl ChoiceField {
 		}
 	}
 
+	/// Create a ChoiceField accepting the machine values of `C`
+	///
+	/// # Example
+	///
+	/// ```rust,ignore
+	/// let field = ChoiceField::from_choices::<Status>();
+	/// assert!(field.validate("draft").is_ok());
+	/// ```
+	pub fn from_choices<C: crate::choices::Choices>() -> Self {
+		Self::new(C::values().into_iter().map(String::from).collect())
+	}
+
 	/// Set whether the field is required
 	pub fn required(mut self, required: bool) -> Self {
 		self.required = required;
@@ -1151,6 +1166,58 @@ impl DateTimeField {
 		NaiveDateTime::parse_from_str(value, &self.format).map_err(|_| FieldError::InvalidDateTime)
 	}
 
+	/// Parse a datetime string into an aware UTC datetime
+	///
+	/// Values carrying a UTC offset (RFC 3339) are converted directly; values
+	/// in the field format are naive and interpreted in `tz`, usually the
+	/// current time zone of the request.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use reinhardt_core::serializers::fields::DateTimeField;
+	/// use chrono::{FixedOffset, Timelike};
+	///
+	/// let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+	/// let field = DateTimeField::new();
+	/// assert_eq!(field.parse_aware("2024-01-15 09:00:00", &tokyo).unwrap().hour(), 0);
+	/// assert_eq!(field.parse_aware("2024-01-15T09:00:00Z", &tokyo).unwrap().hour(), 9);
+	/// ```
+	pub fn parse_aware<Tz: TimeZone>(
+		&self,
+		value: &str,
+		tz: &Tz,
+	) -> Result<DateTime<Utc>, FieldError> {
+		if let Ok(aware) = DateTime::parse_from_rfc3339(value) {
+			return Ok(aware.with_timezone(&Utc));
+		}
+		let naive = self.parse(value)?;
+		tz.from_local_datetime(&naive)
+			.earliest()
+			.map(|aware| aware.with_timezone(&Utc))
+			.ok_or(FieldError::InvalidDateTime)
+	}
+
+	/// Render a UTC datetime in `tz` as RFC 3339
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use reinhardt_core::serializers::fields::DateTimeField;
+	/// use chrono::{FixedOffset, TimeZone, Utc};
+	///
+	/// let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+	/// let dt = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+	/// let field = DateTimeField::new();
+	/// assert_eq!(field.to_representation(&dt, &tokyo), "2024-01-15T09:00:00+09:00");
+	/// ```
+	pub fn to_representation<Tz: TimeZone>(&self, value: &DateTime<Utc>, tz: &Tz) -> String
+	where
+		Tz::Offset: std::fmt::Display,
+	{
+		value.with_timezone(tz).to_rfc3339()
+	}
+
 	/// Validate a datetime string
 	///
 	/// # Example
@@ -1174,6 +1241,393 @@ impl Default for DateTimeField {
 	}
 }
 
+/// GeoJSON geometry field (RFC 7946)
+///
+/// Validates `Point` and `Polygon` geometry objects, including WGS 84
+/// coordinate bounds and closed polygon rings.
+///
+/// # Example
+///
+/// ```rust
+/// use reinhardt_core::serializers::fields::GeoJSONField;
+/// use serde_json::json;
+///
+/// let field = GeoJSONField::new().geometry_types(vec!["Point".to_string()]);
+/// assert!(field.validate(&json!({"type": "Point", "coordinates": [139.7, 35.68]})).is_ok());
+/// assert!(field.validate(&json!({"type": "Point", "coordinates": [200.0, 0.0]})).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct GeoJSONField {
+	/// Whether this field is required.
+	pub required: bool,
+	/// Whether null values are allowed.
+	pub allow_null: bool,
+	/// Accepted geometry types (e.g. `"Point"`). Empty accepts all supported types.
+	pub geometry_types: Vec<String>,
+}
+
+impl GeoJSONField {
+	/// Create a new GeoJSONField accepting `Point` and `Polygon` geometries
+	pub fn new() -> Self {
+		Self {
+			required: true,
+			allow_null: false,
+			geometry_types: Vec::new(),
+		}
+	}
+
+	/// Set whether the field is required
+	pub fn required(mut self, required: bool) -> Self {
+		self.required = required;
+		self
+	}
+
+	/// Set whether null values are allowed
+	pub fn allow_null(mut self, allow_null: bool) -> Self {
+		self.allow_null = allow_null;
+		self
+	}
+
+	/// Restrict the accepted geometry types
+	pub fn geometry_types(mut self, geometry_types: Vec<String>) -> Self {
+		self.geometry_types = geometry_types;
+		self
+	}
+
+	/// Validate a GeoJSON geometry object
+	pub fn validate(&self, value: &serde_json::Value) -> Result<(), FieldError> {
+		if value.is_null() {
+			return if self.allow_null {
+				Ok(())
+			} else {
+				Err(FieldError::Null)
+			};
+		}
+
+		let kind = value
+			.get("type")
+			.and_then(serde_json::Value::as_str)
+			.ok_or_else(|| FieldError::Custom("GeoJSON object must have a type".to_string()))?;
+		if !self.geometry_types.is_empty() && !self.geometry_types.iter().any(|t| t == kind) {
+			return Err(FieldError::Custom(format!(
+				"Geometry type {} is not allowed",
+				kind
+			)));
+		}
+
+		let coordinates = value.get("coordinates").ok_or_else(|| {
+			FieldError::Custom("GeoJSON geometry must have coordinates".to_string())
+		})?;
+		match kind {
+			"Point" => Self::validate_position(coordinates),
+			"Polygon" => {
+				let rings = coordinates
+					.as_array()
+					.filter(|rings| !rings.is_empty())
+					.ok_or_else(|| {
+						FieldError::Custom("Polygon must have at least one ring".to_string())
+					})?;
+				rings.iter().try_for_each(Self::validate_ring)
+			}
+			other => Err(FieldError::Custom(format!(
+				"Unsupported geometry type: {}",
+				other
+			))),
+		}
+	}
+
+	fn validate_position(value: &serde_json::Value) -> Result<(), FieldError> {
+		let invalid = || FieldError::Custom("Invalid GeoJSON position".to_string());
+		let position = value
+			.as_array()
+			.filter(|position| position.len() >= 2)
+			.ok_or_else(invalid)?;
+		let (lon, lat) = match (position[0].as_f64(), position[1].as_f64()) {
+			(Some(lon), Some(lat)) => (lon, lat),
+			_ => return Err(invalid()),
+		};
+		if !(-180.0..=180.0).contains(&lon) || !(-90.0..=90.0).contains(&lat) {
+			return Err(FieldError::Custom(
+				"Coordinates are outside WGS 84 bounds".to_string(),
+			));
+		}
+		Ok(())
+	}
+
+	fn validate_ring(value: &serde_json::Value) -> Result<(), FieldError> {
+		let positions = value
+			.as_array()
+			.filter(|positions| positions.len() >= 4)
+			.ok_or_else(|| {
+				FieldError::Custom("Linear ring must have at least four positions".to_string())
+			})?;
+		positions.iter().try_for_each(Self::validate_position)?;
+		if positions.first() != positions.last() {
+			return Err(FieldError::Custom("Linear ring must be closed".to_string()));
+		}
+		Ok(())
+	}
+}
+
+impl Default for GeoJSONField {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Binary field exchanged as base64 text
+///
+/// Decodes standard base64, with or without padding, and encodes with
+/// padding.
+///
+/// # Example
+///
+/// ```rust
+/// use reinhardt_core::serializers::fields::BinaryField;
+///
+/// let field = BinaryField::new().max_size(4);
+/// assert_eq!(field.parse("AAEC").unwrap(), vec![0, 1, 2]);
+/// assert_eq!(field.to_representation(&[0, 1, 2]), "AAEC");
+/// assert!(field.parse("AAECAwQ=").is_err()); // 5 bytes
+/// ```
+#[derive(Debug, Clone)]
+pub struct BinaryField {
+	/// Whether this field is required.
+	pub required: bool,
+	/// Whether null values are allowed.
+	pub allow_null: bool,
+	/// Maximum decoded size in bytes.
+	pub max_size: Option<usize>,
+}
+
+impl BinaryField {
+	/// Create a new BinaryField with default settings
+	pub fn new() -> Self {
+		Self {
+			required: true,
+			allow_null: false,
+			max_size: None,
+		}
+	}
+
+	/// Set whether the field is required
+	pub fn required(mut self, required: bool) -> Self {
+		self.required = required;
+		self
+	}
+
+	/// Set whether null values are allowed
+	pub fn allow_null(mut self, allow_null: bool) -> Self {
+		self.allow_null = allow_null;
+		self
+	}
+
+	/// Set maximum decoded size in bytes
+	pub fn max_size(mut self, max_size: usize) -> Self {
+		self.max_size = Some(max_size);
+		self
+	}
+
+	/// Decode a base64 string
+	pub fn parse(&self, value: &str) -> Result<Vec<u8>, FieldError> {
+		if value.is_empty() && self.required {
+			return Err(FieldError::Required);
+		}
+		if let Some(max_size) = self.max_size
+			&& value.len() / 4 * 3 > max_size + 2
+		{
+			// Reject before decoding to bound the allocation
+			return Err(FieldError::TooLong(max_size));
+		}
+		let bytes = base64_bytes::decode(value).map_err(|_| FieldError::InvalidBase64)?;
+		match self.max_size {
+			Some(max_size) if bytes.len() > max_size => Err(FieldError::TooLong(max_size)),
+			_ => Ok(bytes),
+		}
+	}
+
+	/// Validate a base64 string
+	pub fn validate(&self, value: &str) -> Result<(), FieldError> {
+		self.parse(value)?;
+		Ok(())
+	}
+
+	/// Encode bytes as base64
+	pub fn to_representation(&self, value: &[u8]) -> String {
+		base64_bytes::encode(value)
+	}
+}
+
+impl Default for BinaryField {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// HTML field cleaned with an allowlist sanitizer
+///
+/// Parsing returns the sanitized HTML, so user-generated content is safe to
+/// store and render. Length limits apply to the sanitized value.
+///
+/// # Example
+///
+/// ```rust
+/// use reinhardt_core::security::SanitizePolicy;
+/// use reinhardt_core::serializers::fields::HtmlField;
+///
+/// let field = HtmlField::new().policy(SanitizePolicy::basic());
+/// assert_eq!(
+///     field.parse("<b>Hi</b><script>alert(1)</script>").unwrap(),
+///     "<b>Hi</b>"
+/// );
+/// ```
+#[cfg(feature = "security")]
+#[derive(Debug, Clone)]
+pub struct HtmlField {
+	/// Whether this field is required.
+	pub required: bool,
+	/// Whether null values are allowed.
+	pub allow_null: bool,
+	/// Whether blank (empty) strings are allowed.
+	pub allow_blank: bool,
+	/// Maximum character length of the sanitized value.
+	pub max_length: Option<usize>,
+	/// Policy the value is sanitized with.
+	pub policy: crate::security::SanitizePolicy,
+}
+
+#[cfg(feature = "security")]
+impl HtmlField {
+	/// Create a new HtmlField using the basic sanitization policy
+	pub fn new() -> Self {
+		Self {
+			required: true,
+			allow_null: false,
+			allow_blank: false,
+			max_length: None,
+			policy: crate::security::SanitizePolicy::basic(),
+		}
+	}
+
+	/// Set whether the field is required
+	pub fn required(mut self, required: bool) -> Self {
+		self.required = required;
+		self
+	}
+
+	/// Set whether null values are allowed
+	pub fn allow_null(mut self, allow_null: bool) -> Self {
+		self.allow_null = allow_null;
+		self
+	}
+
+	/// Set whether blank strings are allowed
+	pub fn allow_blank(mut self, allow_blank: bool) -> Self {
+		self.allow_blank = allow_blank;
+		self
+	}
+
+	/// Set maximum length of the sanitized value
+	pub fn max_length(mut self, max_length: usize) -> Self {
+		self.max_length = Some(max_length);
+		self
+	}
+
+	/// Set the sanitization policy
+	pub fn policy(mut self, policy: crate::security::SanitizePolicy) -> Self {
+		self.policy = policy;
+		self
+	}
+
+	/// Sanitize an HTML string
+	///
+	/// Input that is empty after sanitizing, such as a lone `<script>`, is
+	/// treated as blank.
+	pub fn parse(&self, value: &str) -> Result<String, FieldError> {
+		let cleaned = self.policy.clean(value);
+		if cleaned.trim().is_empty() && !self.allow_blank {
+			return Err(FieldError::Required);
+		}
+		if let Some(max) = self.max_length
+			&& cleaned.chars().count() > max
+		{
+			return Err(FieldError::TooLong(max));
+		}
+		Ok(cleaned)
+	}
+
+	/// Validate an HTML string
+	pub fn validate(&self, value: &str) -> Result<(), FieldError> {
+		self.parse(value)?;
+		Ok(())
+	}
+}
+
+#[cfg(feature = "security")]
+impl Default for HtmlField {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Serde adapter storing `Vec<u8>` as base64 text
+///
+/// Use it on binary model fields so they serialize to JSON as a string
+/// instead of an array of numbers. Deserialization also accepts an array of
+/// numbers.
+///
+/// # Example
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Attachment {
+///     #[serde(with = "reinhardt_core::serializers::fields::base64_bytes")]
+///     data: Vec<u8>,
+/// }
+///
+/// let json = serde_json::to_string(&Attachment { data: vec![0, 1, 2] }).unwrap();
+/// assert_eq!(json, r#"{"data":"AAEC"}"#);
+/// ```
+pub mod base64_bytes {
+	use base64::Engine;
+	use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD};
+	use serde::{Deserialize, Deserializer, Serializer};
+
+	/// Encode bytes as padded standard base64
+	pub fn encode(bytes: &[u8]) -> String {
+		STANDARD.encode(bytes)
+	}
+
+	/// Decode standard base64, with or without padding
+	pub fn decode(text: &str) -> Result<Vec<u8>, base64::DecodeError> {
+		let text = text.trim();
+		STANDARD
+			.decode(text)
+			.or_else(|_| STANDARD_NO_PAD.decode(text))
+	}
+
+	/// Serialize bytes as base64
+	pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&encode(bytes))
+	}
+
+	/// Deserialize bytes from base64 or an array of numbers
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+		#[derive(Deserialize)]
+		#[serde(untagged)]
+		enum Repr {
+			Text(String),
+			Bytes(Vec<u8>),
+		}
+		match Repr::deserialize(deserializer)? {
+			Repr::Text(text) => decode(&text).map_err(serde::de::Error::custom),
+			Repr::Bytes(bytes) => Ok(bytes),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -1375,4 +1829,102 @@ mod tests {
 		assert_eq!(dt.hour(), 14);
 		assert_eq!(dt.minute(), 30);
 	}
+
+	#[rstest]
+	#[case(serde_json::json!({"type": "Point", "coordinates": [139.7, 35.68]}), true)]
+	#[case(serde_json::json!({"type": "Point", "coordinates": [0.0, 91.0]}), false)]
+	#[case(serde_json::json!({"type": "Polygon", "coordinates": [[[0, 0], [1, 0], [1, 1], [0, 0]]]}), true)]
+	#[case(serde_json::json!({"type": "Polygon", "coordinates": [[[0, 0], [1, 0], [1, 1], [0, 1]]]}), false)]
+	#[case(serde_json::json!({"type": "LineString", "coordinates": [[0, 0], [1, 1]]}), false)]
+	fn test_geojson_field_validation(#[case] value: serde_json::Value, #[case] valid: bool) {
+		// Arrange
+		let field = GeoJSONField::new();
+
+		// Act
+		let result = field.validate(&value);
+
+		// Assert
+		assert_eq!(result.is_ok(), valid);
+	}
+
+	#[rstest]
+	fn test_geojson_field_restricts_geometry_types() {
+		// Arrange
+		let field = GeoJSONField::new().geometry_types(vec!["Polygon".to_string()]);
+
+		// Act
+		let result =
+			field.validate(&serde_json::json!({"type": "Point", "coordinates": [1.0, 2.0]}));
+
+		// Assert
+		assert_eq!(
+			result,
+			Err(FieldError::Custom(
+				"Geometry type Point is not allowed".to_string()
+			))
+		);
+	}
+
+	#[rstest]
+	#[case::padded("AAECAw==", Ok(vec![0, 1, 2, 3]))]
+	#[case::unpadded("AAECAw", Ok(vec![0, 1, 2, 3]))]
+	#[case::invalid("not base64!", Err(FieldError::InvalidBase64))]
+	#[case::too_large("AAECAwQ=", Err(FieldError::TooLong(4)))]
+	#[case::empty("", Err(FieldError::Required))]
+	fn test_binary_field_parse(#[case] input: &str, #[case] expected: Result<Vec<u8>, FieldError>) {
+		// Arrange
+		let field = BinaryField::new().max_size(4);
+
+		// Act
+		let result = field.parse(input);
+
+		// Assert
+		assert_eq!(result, expected);
+	}
+
+	#[cfg(feature = "security")]
+	#[rstest]
+	#[case::sanitized("<p onclick=\"x()\">Hi</p>", Ok("<p>Hi</p>".to_string()))]
+	#[case::only_script("<script>alert(1)</script>", Err(FieldError::Required))]
+	#[case::too_long("<b>long text</b>", Err(FieldError::TooLong(12)))]
+	fn test_html_field_parse(#[case] input: &str, #[case] expected: Result<String, FieldError>) {
+		// Arrange
+		let field = HtmlField::new().max_length(12);
+
+		// Act
+		let result = field.parse(input);
+
+		// Assert
+		assert_eq!(result, expected);
+	}
+
+	#[rstest]
+	#[case::text(r#""AAEC""#)]
+	#[case::array("[0,1,2]")]
+	fn test_base64_bytes_deserializes_text_and_arrays(#[case] json: &str) {
+		// Arrange
+		#[derive(serde::Deserialize)]
+		struct Blob(#[serde(with = "base64_bytes")] Vec<u8>);
+
+		// Act
+		let blob: Blob = serde_json::from_str(json).unwrap();
+
+		// Assert
+		assert_eq!(blob.0, vec![0, 1, 2]);
+	}
+
+	#[rstest]
+	fn test_datetime_field_round_trips_through_local_time() {
+		// Arrange
+		let field = DateTimeField::new();
+		let paris = chrono::FixedOffset::east_opt(3600).unwrap();
+
+		// Act
+		let stored = field.parse_aware("2024-03-01 12:00:00", &paris).unwrap();
+		let rendered = field.to_representation(&stored, &paris);
+
+		// Assert
+		assert_eq!(stored.to_rfc3339(), "2024-03-01T11:00:00+00:00");
+		assert_eq!(rendered, "2024-03-01T12:00:00+01:00");
+	}
 }
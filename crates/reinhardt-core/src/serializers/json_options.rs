@@ -0,0 +1,638 @@
+//! Output options for JSON serialization
+//!
+//! [`JsonOptions`] controls how values are turned into JSON by
+//! [`JsonSerializer`](super::JsonSerializer) and the REST `JSONRenderer`:
+//!
+//! - **Key case**: rewrite `snake_case` object keys to `camelCase` on output
+//!   and back to `snake_case` when parsing, so Rust structs keep their
+//!   field names while JavaScript clients see idiomatic keys.
+//! - **Pretty printing**: indent output, typically only in `DEBUG`.
+//! - **Datetime format**: keep RFC 3339 strings or emit Unix timestamps.
+//! - **Strict floats**: reject `NaN` and infinities instead of silently
+//!   emitting `null`.
+//!
+//! A project-wide default can be installed once with [`JsonOptions::set_global`]
+//! (the `[json]` settings section does this at startup); individual views
+//! override it by building their own options.
+
+use super::SerializerError;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::sync::OnceLock;
+
+static GLOBAL_JSON_OPTIONS: OnceLock<JsonOptions> = OnceLock::new();
+
+/// How object keys are written to JSON.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyCase {
+	/// Keys are written exactly as serialized
+	#[default]
+	Preserve,
+	/// `snake_case` keys are written as `camelCase` and converted back on parse
+	CamelCase,
+}
+
+/// How datetime values are written to JSON.
+///
+/// Datetimes are recognised as RFC 3339 strings, which is how `chrono` types
+/// serialize. Any string value in that format is converted, so avoid the
+/// epoch formats for payloads carrying free-form text that may look like a
+/// timestamp.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DateTimeFormat {
+	/// RFC 3339 strings such as `2024-01-15T10:30:00Z`
+	#[default]
+	Rfc3339,
+	/// Whole seconds since the Unix epoch
+	EpochSeconds,
+	/// Milliseconds since the Unix epoch
+	EpochMillis,
+}
+
+/// Renderer-level options applied when producing and parsing JSON.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_core::serializers::json_options::{DateTimeFormat, JsonOptions, KeyCase};
+/// use serde_json::json;
+///
+/// let options = JsonOptions::new()
+///     .key_case(KeyCase::CamelCase)
+///     .datetime_format(DateTimeFormat::EpochSeconds);
+///
+/// let value = options
+///     .to_value(&json!({"created_at": "1970-01-01T00:01:00Z"}))
+///     .unwrap();
+/// assert_eq!(value, json!({"createdAt": 60}));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonOptions {
+	/// Case applied to object keys
+	#[serde(default)]
+	pub key_case: KeyCase,
+	/// Whether output is indented
+	#[serde(default)]
+	pub pretty: bool,
+	/// Format used for datetime values
+	#[serde(default)]
+	pub datetime_format: DateTimeFormat,
+	/// Whether `NaN` and infinite floats are rejected instead of written as `null`
+	#[serde(default)]
+	pub strict: bool,
+}
+
+impl JsonOptions {
+	/// Create options matching plain `serde_json` output.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Set the case applied to object keys.
+	pub fn key_case(mut self, key_case: KeyCase) -> Self {
+		self.key_case = key_case;
+		self
+	}
+
+	/// Set whether output is indented.
+	pub fn pretty(mut self, pretty: bool) -> Self {
+		self.pretty = pretty;
+		self
+	}
+
+	/// Set the format used for datetime values.
+	pub fn datetime_format(mut self, format: DateTimeFormat) -> Self {
+		self.datetime_format = format;
+		self
+	}
+
+	/// Set whether non-finite floats are rejected.
+	pub fn strict(mut self, strict: bool) -> Self {
+		self.strict = strict;
+		self
+	}
+
+	/// Install the project-wide default options.
+	///
+	/// Returns `false` if options were already installed.
+	pub fn set_global(options: JsonOptions) -> bool {
+		GLOBAL_JSON_OPTIONS.set(options).is_ok()
+	}
+
+	/// The project-wide default options, or plain defaults if none were installed.
+	pub fn global() -> JsonOptions {
+		GLOBAL_JSON_OPTIONS.get().cloned().unwrap_or_default()
+	}
+
+	/// Serialize `data` into a [`Value`] with these options applied.
+	pub fn to_value<T: Serialize + ?Sized>(&self, data: &T) -> Result<Value, SerializerError> {
+		if self.strict {
+			data.serialize(FiniteFloatCheck)
+				.map_err(|e| SerializerError::Serde {
+					message: format!("Serialization error: {}", e),
+				})?;
+		}
+		let value = serde_json::to_value(data).map_err(|e| SerializerError::Serde {
+			message: format!("Serialization error: {}", e),
+		})?;
+		Ok(self.transform_outgoing(value))
+	}
+
+	/// Serialize `data` into a JSON string with these options applied.
+	pub fn to_string<T: Serialize + ?Sized>(&self, data: &T) -> Result<String, SerializerError> {
+		let value = self.to_value(data)?;
+		let result = if self.pretty {
+			serde_json::to_string_pretty(&value)
+		} else {
+			serde_json::to_string(&value)
+		};
+		result.map_err(|e| SerializerError::Serde {
+			message: format!("Serialization error: {}", e),
+		})
+	}
+
+	/// Parse a JSON string, undoing the key case transformation first.
+	pub fn parse<T: DeserializeOwned>(&self, json: &str) -> Result<T, SerializerError> {
+		let value: Value = serde_json::from_str(json).map_err(|e| SerializerError::Serde {
+			message: format!("Deserialization error: {}", e),
+		})?;
+		serde_json::from_value(self.transform_incoming(value)).map_err(|e| SerializerError::Serde {
+			message: format!("Deserialization error: {}", e),
+		})
+	}
+
+	/// Apply key case and datetime formatting to an already serialized value.
+	pub fn transform_outgoing(&self, value: Value) -> Value {
+		match value {
+			Value::Object(map) => Value::Object(
+				map.into_iter()
+					.map(|(key, value)| {
+						let key = match self.key_case {
+							KeyCase::Preserve => key,
+							KeyCase::CamelCase => to_camel_case(&key),
+						};
+						(key, self.transform_outgoing(value))
+					})
+					.collect(),
+			),
+			Value::Array(items) => Value::Array(
+				items
+					.into_iter()
+					.map(|item| self.transform_outgoing(item))
+					.collect(),
+			),
+			Value::String(s) => self.format_datetime(s),
+			other => other,
+		}
+	}
+
+	/// Convert incoming object keys back to `snake_case` when camelCase output is enabled.
+	///
+	/// Use this on bodies parsed by `JSONParser` before deserializing them
+	/// into Rust types.
+	pub fn transform_incoming(&self, value: Value) -> Value {
+		if self.key_case == KeyCase::Preserve {
+			return value;
+		}
+		snake_case_keys(value)
+	}
+
+	fn format_datetime(&self, s: String) -> Value {
+		if self.datetime_format == DateTimeFormat::Rfc3339 {
+			return Value::String(s);
+		}
+		match chrono::DateTime::parse_from_rfc3339(&s) {
+			Ok(dt) => match self.datetime_format {
+				DateTimeFormat::EpochSeconds => Value::from(dt.timestamp()),
+				DateTimeFormat::EpochMillis => Value::from(dt.timestamp_millis()),
+				DateTimeFormat::Rfc3339 => Value::String(s),
+			},
+			Err(_) => Value::String(s),
+		}
+	}
+}
+
+fn snake_case_keys(value: Value) -> Value {
+	match value {
+		Value::Object(map) => Value::Object(
+			map.into_iter()
+				.map(|(key, value)| (to_snake_case(&key), snake_case_keys(value)))
+				.collect::<Map<_, _>>(),
+		),
+		Value::Array(items) => Value::Array(items.into_iter().map(snake_case_keys).collect()),
+		other => other,
+	}
+}
+
+/// Convert a `snake_case` key to `camelCase`.
+///
+/// Leading underscores are kept so private-looking keys such as `_links`
+/// stay distinguishable.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_core::serializers::json_options::to_camel_case;
+///
+/// assert_eq!(to_camel_case("created_at"), "createdAt");
+/// assert_eq!(to_camel_case("_links"), "_links");
+/// ```
+pub fn to_camel_case(key: &str) -> String {
+	let trimmed = key.trim_start_matches('_');
+	let mut result = String::with_capacity(key.len());
+	result.push_str(&key[..key.len() - trimmed.len()]);
+	let mut upper_next = false;
+	for c in trimmed.chars() {
+		if c == '_' {
+			upper_next = true;
+		} else if upper_next {
+			result.extend(c.to_uppercase());
+			upper_next = false;
+		} else {
+			result.push(c);
+		}
+	}
+	result
+}
+
+/// Convert a `camelCase` key to `snake_case`.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_core::serializers::json_options::to_snake_case;
+///
+/// assert_eq!(to_snake_case("createdAt"), "created_at");
+/// assert_eq!(to_snake_case("already_snake"), "already_snake");
+/// ```
+pub fn to_snake_case(key: &str) -> String {
+	let mut result = String::with_capacity(key.len() + 4);
+	for c in key.chars() {
+		if c.is_uppercase() {
+			if !result.is_empty() && !result.ends_with('_') {
+				result.push('_');
+			}
+			result.extend(c.to_lowercase());
+		} else {
+			result.push(c);
+		}
+	}
+	result
+}
+
+/// Error raised by [`FiniteFloatCheck`].
+#[derive(Debug)]
+struct NonFiniteFloat(String);
+
+impl std::fmt::Display for NonFiniteFloat {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(&self.0)
+	}
+}
+
+impl std::error::Error for NonFiniteFloat {}
+
+impl serde::ser::Error for NonFiniteFloat {
+	fn custom<T: std::fmt::Display>(msg: T) -> Self {
+		Self(msg.to_string())
+	}
+}
+
+/// Serializer that produces nothing and fails on the first non-finite float.
+///
+/// `serde_json` writes `NaN` and infinities as `null`, so they can no longer
+/// be told apart from real nulls once serialized; strict mode walks the
+/// input with this serializer first.
+struct FiniteFloatCheck;
+
+impl FiniteFloatCheck {
+	fn check(value: f64) -> Result<(), NonFiniteFloat> {
+		if value.is_finite() {
+			Ok(())
+		} else {
+			Err(NonFiniteFloat(format!(
+				"non-finite float value {} is not allowed in strict mode",
+				value
+			)))
+		}
+	}
+}
+
+macro_rules! accept_scalars {
+	($($method:ident($ty:ty)),* $(,)?) => {
+		$(
+			fn $method(self, _v: $ty) -> Result<(), NonFiniteFloat> {
+				Ok(())
+			}
+		)*
+	};
+}
+
+impl serde::Serializer for FiniteFloatCheck {
+	type Ok = ();
+	type Error = NonFiniteFloat;
+	type SerializeSeq = Self;
+	type SerializeTuple = Self;
+	type SerializeTupleStruct = Self;
+	type SerializeTupleVariant = Self;
+	type SerializeMap = Self;
+	type SerializeStruct = Self;
+	type SerializeStructVariant = Self;
+
+	accept_scalars!(
+		serialize_bool(bool),
+		serialize_i8(i8),
+		serialize_i16(i16),
+		serialize_i32(i32),
+		serialize_i64(i64),
+		serialize_i128(i128),
+		serialize_u8(u8),
+		serialize_u16(u16),
+		serialize_u32(u32),
+		serialize_u64(u64),
+		serialize_u128(u128),
+		serialize_char(char),
+		serialize_str(&str),
+		serialize_bytes(&[u8]),
+	);
+
+	fn serialize_f32(self, v: f32) -> Result<(), NonFiniteFloat> {
+		Self::check(f64::from(v))
+	}
+
+	fn serialize_f64(self, v: f64) -> Result<(), NonFiniteFloat> {
+		Self::check(v)
+	}
+
+	fn serialize_none(self) -> Result<(), NonFiniteFloat> {
+		Ok(())
+	}
+
+	fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), NonFiniteFloat> {
+		value.serialize(self)
+	}
+
+	fn serialize_unit(self) -> Result<(), NonFiniteFloat> {
+		Ok(())
+	}
+
+	fn serialize_unit_struct(self, _name: &'static str) -> Result<(), NonFiniteFloat> {
+		Ok(())
+	}
+
+	fn serialize_unit_variant(
+		self,
+		_name: &'static str,
+		_index: u32,
+		_variant: &'static str,
+	) -> Result<(), NonFiniteFloat> {
+		Ok(())
+	}
+
+	fn serialize_newtype_struct<T: Serialize + ?Sized>(
+		self,
+		_name: &'static str,
+		value: &T,
+	) -> Result<(), NonFiniteFloat> {
+		value.serialize(self)
+	}
+
+	fn serialize_newtype_variant<T: Serialize + ?Sized>(
+		self,
+		_name: &'static str,
+		_index: u32,
+		_variant: &'static str,
+		value: &T,
+	) -> Result<(), NonFiniteFloat> {
+		value.serialize(self)
+	}
+
+	fn serialize_seq(self, _len: Option<usize>) -> Result<Self, NonFiniteFloat> {
+		Ok(self)
+	}
+
+	fn serialize_tuple(self, _len: usize) -> Result<Self, NonFiniteFloat> {
+		Ok(self)
+	}
+
+	fn serialize_tuple_struct(
+		self,
+		_name: &'static str,
+		_len: usize,
+	) -> Result<Self, NonFiniteFloat> {
+		Ok(self)
+	}
+
+	fn serialize_tuple_variant(
+		self,
+		_name: &'static str,
+		_index: u32,
+		_variant: &'static str,
+		_len: usize,
+	) -> Result<Self, NonFiniteFloat> {
+		Ok(self)
+	}
+
+	fn serialize_map(self, _len: Option<usize>) -> Result<Self, NonFiniteFloat> {
+		Ok(self)
+	}
+
+	fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self, NonFiniteFloat> {
+		Ok(self)
+	}
+
+	fn serialize_struct_variant(
+		self,
+		_name: &'static str,
+		_index: u32,
+		_variant: &'static str,
+		_len: usize,
+	) -> Result<Self, NonFiniteFloat> {
+		Ok(self)
+	}
+}
+
+macro_rules! check_compound {
+	($($trait:ident :: $method:ident),* $(,)?) => {
+		$(
+			impl serde::ser::$trait for FiniteFloatCheck {
+				type Ok = ();
+				type Error = NonFiniteFloat;
+
+				fn $method<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), NonFiniteFloat> {
+					value.serialize(FiniteFloatCheck)
+				}
+
+				fn end(self) -> Result<(), NonFiniteFloat> {
+					Ok(())
+				}
+			}
+		)*
+	};
+}
+
+check_compound!(
+	SerializeSeq::serialize_element,
+	SerializeTuple::serialize_element,
+	SerializeTupleStruct::serialize_field,
+	SerializeTupleVariant::serialize_field,
+);
+
+impl serde::ser::SerializeMap for FiniteFloatCheck {
+	type Ok = ();
+	type Error = NonFiniteFloat;
+
+	fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), NonFiniteFloat> {
+		key.serialize(FiniteFloatCheck)
+	}
+
+	fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), NonFiniteFloat> {
+		value.serialize(FiniteFloatCheck)
+	}
+
+	fn end(self) -> Result<(), NonFiniteFloat> {
+		Ok(())
+	}
+}
+
+impl serde::ser::SerializeStruct for FiniteFloatCheck {
+	type Ok = ();
+	type Error = NonFiniteFloat;
+
+	fn serialize_field<T: Serialize + ?Sized>(
+		&mut self,
+		_key: &'static str,
+		value: &T,
+	) -> Result<(), NonFiniteFloat> {
+		value.serialize(FiniteFloatCheck)
+	}
+
+	fn end(self) -> Result<(), NonFiniteFloat> {
+		Ok(())
+	}
+}
+
+impl serde::ser::SerializeStructVariant for FiniteFloatCheck {
+	type Ok = ();
+	type Error = NonFiniteFloat;
+
+	fn serialize_field<T: Serialize + ?Sized>(
+		&mut self,
+		_key: &'static str,
+		value: &T,
+	) -> Result<(), NonFiniteFloat> {
+		value.serialize(FiniteFloatCheck)
+	}
+
+	fn end(self) -> Result<(), NonFiniteFloat> {
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use serde_json::json;
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Article {
+		article_id: i64,
+		published_at: String,
+		score: f64,
+	}
+
+	#[rstest]
+	#[case::simple("created_at", "createdAt")]
+	#[case::multiple("is_staff_member", "isStaffMember")]
+	#[case::leading_underscore("_links", "_links")]
+	#[case::no_underscore("name", "name")]
+	fn test_to_camel_case(#[case] input: &str, #[case] expected: &str) {
+		// Arrange / Act / Assert
+		assert_eq!(to_camel_case(input), expected);
+	}
+
+	#[rstest]
+	#[case::simple("createdAt", "created_at")]
+	#[case::multiple("isStaffMember", "is_staff_member")]
+	#[case::already_snake("page_size", "page_size")]
+	fn test_to_snake_case(#[case] input: &str, #[case] expected: &str) {
+		// Arrange / Act / Assert
+		assert_eq!(to_snake_case(input), expected);
+	}
+
+	#[rstest]
+	fn test_camel_case_round_trip() {
+		// Arrange
+		let options = JsonOptions::new().key_case(KeyCase::CamelCase);
+		let article = Article {
+			article_id: 7,
+			published_at: "2024-01-15T10:30:00Z".to_string(),
+			score: 1.5,
+		};
+
+		// Act
+		let json = options.to_string(&article).unwrap();
+		let parsed: Article = options.parse(&json).unwrap();
+
+		// Assert
+		assert_eq!(
+			serde_json::from_str::<Value>(&json).unwrap(),
+			json!({"articleId": 7, "publishedAt": "2024-01-15T10:30:00Z", "score": 1.5})
+		);
+		assert_eq!(parsed, article);
+	}
+
+	#[rstest]
+	#[case::rfc3339(DateTimeFormat::Rfc3339, json!("2024-01-15T10:30:00Z"))]
+	#[case::seconds(DateTimeFormat::EpochSeconds, json!(1705314600))]
+	#[case::millis(DateTimeFormat::EpochMillis, json!(1705314600000_i64))]
+	fn test_datetime_format(#[case] format: DateTimeFormat, #[case] expected: Value) {
+		// Arrange
+		let options = JsonOptions::new().datetime_format(format);
+
+		// Act
+		let value = options
+			.to_value(&json!({"at": "2024-01-15T10:30:00Z", "title": "Hello"}))
+			.unwrap();
+
+		// Assert
+		assert_eq!(value["at"], expected);
+		assert_eq!(value["title"], "Hello");
+	}
+
+	#[rstest]
+	#[case::nan(f64::NAN)]
+	#[case::infinity(f64::INFINITY)]
+	fn test_strict_rejects_non_finite_floats(#[case] score: f64) {
+		// Arrange
+		let article = Article {
+			article_id: 1,
+			published_at: String::new(),
+			score,
+		};
+
+		// Act
+		let lenient = JsonOptions::new().to_value(&article);
+		let strict = JsonOptions::new().strict(true).to_value(&article);
+
+		// Assert
+		assert_eq!(lenient.unwrap()["score"], Value::Null);
+		assert!(strict.is_err());
+	}
+
+	#[rstest]
+	fn test_pretty_output_is_indented() {
+		// Arrange
+		let options = JsonOptions::new().pretty(true);
+
+		// Act
+		let json = options.to_string(&json!({"a": 1})).unwrap();
+
+		// Assert
+		assert_eq!(json, "{\n  \"a\": 1\n}");
+	}
+}
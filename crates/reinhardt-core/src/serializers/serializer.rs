@@ -3,6 +3,7 @@
 //! Provides the foundational `Serializer` and `Deserializer` traits along with
 //! error types for serialization operations.
 
+use super::json_options::{JsonOptions, KeyCase};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -239,6 +240,64 @@ impl From<ValidatorError> for SerializerError {
 	}
 }
 
+/// Collects a serializer validation failure into the shared error structure.
+///
+/// Unique violations keep the `unique` code of
+/// [`ValidationError::NotUnique`](crate::validators::ValidationError::NotUnique);
+/// unique-together and database errors become non-field errors.
+#[cfg(feature = "validators")]
+impl From<ValidatorError> for crate::validators::ValidationErrors {
+	fn from(err: ValidatorError) -> Self {
+		use crate::validators::ValidationError;
+
+		let mut errors = Self::new();
+		match err {
+			ValidatorError::UniqueViolation {
+				field_name,
+				value,
+				message,
+			} => {
+				let error = ValidationError::coded("unique", message)
+					.with_param("field", field_name.clone())
+					.with_param("value", value);
+				errors.add(field_name, error);
+			}
+			ValidatorError::UniqueTogetherViolation {
+				field_names,
+				values,
+				message,
+			} => {
+				let mut error = ValidationError::coded("unique_together", message)
+					.with_param("fields", field_names.join(", "));
+				for (field, value) in values {
+					error = error.with_param(field, value);
+				}
+				errors.add_non_field(error);
+			}
+			ValidatorError::RequiredField {
+				field_name,
+				message,
+			} => errors.add(field_name, ValidationError::coded("required", message)),
+			ValidatorError::FieldValidation {
+				field_name,
+				value,
+				constraint,
+				message,
+			} => errors.add(
+				field_name,
+				ValidationError::coded(constraint, message).with_param("value", value),
+			),
+			ValidatorError::DatabaseError { message, .. } => {
+				errors.add_non_field(ValidationError::coded("database_error", message))
+			}
+			ValidatorError::Custom { message } => {
+				errors.add_non_field(ValidationError::coded("invalid", message))
+			}
+		}
+		errors
+	}
+}
+
 impl SerializerError {
 	/// Create a new generic serializer error
 	pub fn new(message: String) -> Self {
@@ -346,8 +405,26 @@ impl SerializerError {
 /// let deserialized = serializer.deserialize(&json).unwrap();
 /// assert_eq!(user.id, deserialized.id);
 /// ```
+///
+/// Output can be adjusted with [`JsonOptions`], e.g. to emit camelCase keys:
+///
+/// ```
+/// use reinhardt_core::serializers::{JsonOptions, JsonSerializer, KeyCase, Serializer};
+/// use serde::{Serialize, Deserialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct User { user_id: i64 }
+///
+/// let serializer = JsonSerializer::<User>::new()
+///     .with_options(JsonOptions::new().key_case(KeyCase::CamelCase));
+///
+/// let json = serializer.serialize(&User { user_id: 1 }).unwrap();
+/// assert_eq!(json, r#"{"userId":1}"#);
+/// assert_eq!(serializer.deserialize(&json).unwrap().user_id, 1);
+/// ```
 #[derive(Debug, Clone)]
 pub struct JsonSerializer<T> {
+	options: JsonOptions,
 	_phantom: std::marker::PhantomData<T>,
 }
 
@@ -355,9 +432,21 @@ impl<T> JsonSerializer<T> {
 	/// Create a new JSON serializer
 	pub fn new() -> Self {
 		Self {
+			options: JsonOptions::default(),
 			_phantom: std::marker::PhantomData,
 		}
 	}
+
+	/// Apply renderer-level [`JsonOptions`] to this serializer.
+	pub fn with_options(mut self, options: JsonOptions) -> Self {
+		self.options = options;
+		self
+	}
+
+	/// The options applied by this serializer.
+	pub fn options(&self) -> &JsonOptions {
+		&self.options
+	}
 }
 
 impl<T> Default for JsonSerializer<T> {
@@ -374,15 +463,21 @@ where
 	type Output = String;
 
 	fn serialize(&self, input: &Self::Input) -> Result<Self::Output, SerializerError> {
-		serde_json::to_string(input).map_err(|e| SerializerError::Serde {
-			message: format!("Serialization error: {}", e),
-		})
+		if self.options == JsonOptions::default() {
+			return serde_json::to_string(input).map_err(|e| SerializerError::Serde {
+				message: format!("Serialization error: {}", e),
+			});
+		}
+		self.options.to_string(input)
 	}
 
 	fn deserialize(&self, output: &Self::Output) -> Result<Self::Input, SerializerError> {
-		serde_json::from_str(output).map_err(|e| SerializerError::Serde {
-			message: format!("Deserialization error: {}", e),
-		})
+		if self.options.key_case == KeyCase::Preserve {
+			return serde_json::from_str(output).map_err(|e| SerializerError::Serde {
+				message: format!("Deserialization error: {}", e),
+			});
+		}
+		self.options.parse(output)
 	}
 }
 
@@ -497,4 +592,19 @@ mod tests {
 			_ => panic!("Expected Validation error"),
 		}
 	}
+
+	#[cfg(feature = "validators")]
+	#[test]
+	fn test_validator_error_into_validation_errors() {
+		let error = ValidatorError::UniqueViolation {
+			field_name: "email".to_string(),
+			value: "a@example.com".to_string(),
+			message: "Email already taken".to_string(),
+		};
+		let errors = crate::validators::ValidationErrors::from(error);
+		let email = &errors.get("email")[0];
+		assert_eq!(email.code(), "unique");
+		assert_eq!(email.params()["value"], "a@example.com");
+		assert_eq!(email.to_string(), "Email already taken");
+	}
 }
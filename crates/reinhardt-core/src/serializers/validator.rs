@@ -84,6 +84,33 @@ impl ValidationError {
 	}
 }
 
+/// Collects serializer validation errors into the shared error structure.
+///
+/// Field and object errors get the `invalid` code; object errors become
+/// non-field errors.
+#[cfg(feature = "validators")]
+impl From<ValidationError> for crate::validators::ValidationErrors {
+	fn from(err: ValidationError) -> Self {
+		use crate::validators::ValidationError as CoreValidationError;
+
+		let mut errors = Self::new();
+		match err {
+			ValidationError::FieldError { field, message } => {
+				errors.add(field, CoreValidationError::coded("invalid", message))
+			}
+			ValidationError::ObjectError(message) => {
+				errors.add_non_field(CoreValidationError::coded("invalid", message))
+			}
+			ValidationError::MultipleErrors(inner) => {
+				for error in inner {
+					errors.merge(error.into());
+				}
+			}
+		}
+		errors
+	}
+}
+
 /// Trait for field-level validators
 ///
 /// Implementors can validate individual field values.
@@ -335,6 +362,22 @@ mod tests {
 		}
 	}
 
+	#[cfg(feature = "validators")]
+	#[test]
+	fn test_into_validation_errors() {
+		let error = ValidationError::multiple(vec![
+			ValidationError::field_error("email", "Required"),
+			ValidationError::object_error("Passwords do not match"),
+		]);
+		let errors = crate::validators::ValidationErrors::from(error);
+		assert_eq!(errors.get("email")[0].code(), "invalid");
+		assert_eq!(errors.get("email")[0].to_string(), "Required");
+		assert_eq!(
+			errors.non_field_errors()[0].to_string(),
+			"Passwords do not match"
+		);
+	}
+
 	#[test]
 	fn test_email_validator_valid() {
 		let validator = EmailValidator;
@@ -48,6 +48,25 @@
 //! # }
 //! ```
 //!
+//! ## Isolated Dispatch
+//!
+//! [`Signal::dispatch`] runs every receiver even when some fail or panic and
+//! returns a [`DispatchReport`] listing the failures. Receivers run in
+//! priority order (higher first); with [`ExecutionMode::Concurrent`],
+//! receivers sharing a priority run concurrently. [`Signal::send_on_commit`]
+//! defers a dispatch until the surrounding
+//! [`TransactionSignals`](transaction::TransactionSignals) commits.
+//!
+//! ## Introspection and Debugging
+//!
+//! [`Signal::receivers`] lists the receivers connected to a signal with their
+//! function path, sender filter and registration site, and
+//! [`Signal::explain`] reports which receivers a dispatch would skip and why.
+//! [`introspection::connected_receivers`] covers every signal in the global
+//! registry. [`Signal::set_debug`] or [`introspection::set_debug_mode`] logs
+//! each dispatch, including per-receiver timing, under the
+//! `reinhardt::signals` tracing target.
+//!
 //! ## Implemented Integration Features
 //!
 //! - **ORM Integration**: Automatic signal dispatch from ORM operations
@@ -66,8 +85,10 @@ pub mod dispatch;
 pub mod dlq;
 pub mod doc_generator;
 pub mod error;
+pub mod execution;
 pub mod history;
 pub mod injectable_signal;
+pub mod introspection;
 mod lifecycle_events;
 mod middleware;
 mod model_signals;
@@ -93,7 +114,9 @@ pub mod websocket_integration;
 pub use context::{SignalContext, SignalMetrics};
 pub use core::{AsyncSignalDispatcher, ReceiverFn, SignalDispatcher, SignalName};
 pub use error::SignalError;
+pub use execution::{DispatchReport, ExecutionMode, ReceiverFailure};
 pub use injectable_signal::InjectableSignal;
+pub use introspection::{ReceiverDescription, SignalDescription};
 pub use middleware::{MiddlewareFn, SignalCall, SignalMiddleware, SignalSpy};
 pub use receiver_context::ReceiverContext;
 pub use receiver_registry::{ReceiverRegistryEntry, auto_connect_receivers};
@@ -486,4 +509,91 @@ mod tests {
 		let _ = before_delete();
 		let _ = after_delete();
 	}
+	#[tokio::test]
+	async fn test_dispatch_isolates_failures_and_panics() {
+		// Arrange
+		let signal = Signal::<i32>::new(SignalName::custom("test_dispatch_isolation"));
+		let calls = Arc::new(AtomicUsize::new(0));
+		signal.connect_with_options(
+			|_| async { Err(SignalError::new("smtp down")) },
+			None,
+			Some("send_email".to_string()),
+			10,
+		);
+		signal.connect_with_options(
+			|_| async { panic!("cache unavailable") },
+			None,
+			Some("warm_cache".to_string()),
+			5,
+		);
+		let c = calls.clone();
+		signal.connect(move |_| {
+			let c = c.clone();
+			async move {
+				c.fetch_add(1, Ordering::SeqCst);
+				Ok(())
+			}
+		});
+
+		// Act
+		let report = signal.dispatch(1).await;
+
+		// Assert
+		assert_eq!(calls.load(Ordering::SeqCst), 1);
+		assert_eq!(report.executed, 3);
+		assert_eq!(report.failures.len(), 2);
+		assert_eq!(
+			report.failures[0].dispatch_uid.as_deref(),
+			Some("send_email")
+		);
+		assert_eq!(
+			report.failures[1].error.message,
+			"receiver panicked: cache unavailable"
+		);
+	}
+
+	#[tokio::test]
+	async fn test_concurrent_dispatch_runs_priority_tiers_in_order() {
+		// Arrange
+		let signal = Signal::<i32>::new(SignalName::custom("test_concurrent_dispatch"));
+		signal.set_execution_mode(ExecutionMode::Concurrent);
+		let events = Arc::new(Mutex::new(Vec::new()));
+		let gate = Arc::new(tokio::sync::Barrier::new(2));
+		for name in ["a", "b"] {
+			let events = events.clone();
+			let gate = gate.clone();
+			signal.connect_with_priority(
+				move |_| {
+					let events = events.clone();
+					let gate = gate.clone();
+					async move {
+						// Both receivers must be running for the barrier to open
+						gate.wait().await;
+						events.lock().push(name);
+						Ok(())
+					}
+				},
+				10,
+			);
+		}
+		let e = events.clone();
+		signal.connect(move |_| {
+			let e = e.clone();
+			async move {
+				e.lock().push("low");
+				Ok(())
+			}
+		});
+
+		// Act
+		let report = tokio::time::timeout(std::time::Duration::from_secs(5), signal.dispatch(1))
+			.await
+			.unwrap();
+
+		// Assert
+		assert!(report.is_ok());
+		let events = events.lock();
+		assert_eq!(events.len(), 3);
+		assert_eq!(events[2], "low");
+	}
 }
@@ -0,0 +1,128 @@
+//! Receiver execution modes and isolated dispatch reports
+//!
+//! [`Signal::dispatch`](super::Signal::dispatch) runs every eligible receiver
+//! even when some of them fail, and returns a [`DispatchReport`] describing
+//! each failure. Receivers run in priority order; with
+//! [`ExecutionMode::Concurrent`], receivers sharing a priority run at the same
+//! time while priority tiers still run one after another.
+
+use super::error::SignalError;
+use std::fmt;
+
+/// How a signal runs receivers that share the same priority
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExecutionMode {
+	/// Run receivers one at a time, in priority order
+	#[default]
+	Sequential,
+	/// Run receivers of equal priority concurrently; higher priority tiers
+	/// complete before lower ones start
+	Concurrent,
+}
+
+/// A receiver that failed during an isolated dispatch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiverFailure {
+	/// The receiver's dispatch uid, if it was connected with one
+	pub dispatch_uid: Option<String>,
+	/// The receiver's priority
+	pub priority: i32,
+	/// The error returned by the receiver or its middleware, or a description
+	/// of the panic
+	pub error: SignalError,
+}
+
+impl fmt::Display for ReceiverFailure {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match &self.dispatch_uid {
+			Some(uid) => write!(f, "{}: {}", uid, self.error),
+			None => write!(f, "<anonymous, priority {}>: {}", self.priority, self.error),
+		}
+	}
+}
+
+/// Outcome of an isolated dispatch
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DispatchReport {
+	/// Name of the dispatched signal
+	pub signal: String,
+	/// Number of receivers that ran, including failed ones
+	pub executed: usize,
+	/// Receivers that returned an error or panicked, in completion order
+	pub failures: Vec<ReceiverFailure>,
+}
+
+impl DispatchReport {
+	pub(crate) fn new(signal: impl Into<String>) -> Self {
+		Self {
+			signal: signal.into(),
+			..Self::default()
+		}
+	}
+
+	/// Returns `true` if no receiver failed
+	pub fn is_ok(&self) -> bool {
+		self.failures.is_empty()
+	}
+
+	/// Converts the report into a single error listing every failure
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::signals::DispatchReport;
+	///
+	/// let report = DispatchReport::default();
+	/// assert!(report.into_result().is_ok());
+	/// ```
+	pub fn into_result(self) -> Result<(), SignalError> {
+		if self.failures.is_empty() {
+			return Ok(());
+		}
+		let details: Vec<String> = self.failures.iter().map(ToString::to_string).collect();
+		Err(SignalError::new(format!(
+			"{} of {} receiver(s) of signal '{}' failed: {}",
+			self.failures.len(),
+			self.executed,
+			self.signal,
+			details.join("; ")
+		)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	fn into_result_lists_every_failure() {
+		// Arrange
+		let report = DispatchReport {
+			signal: "user_created".to_string(),
+			executed: 3,
+			failures: vec![
+				ReceiverFailure {
+					dispatch_uid: Some("send_welcome".to_string()),
+					priority: 10,
+					error: SignalError::new("smtp down"),
+				},
+				ReceiverFailure {
+					dispatch_uid: None,
+					priority: 0,
+					error: SignalError::new("boom"),
+				},
+			],
+		};
+
+		// Act
+		let error = report.into_result().unwrap_err();
+
+		// Assert
+		assert_eq!(
+			error.message,
+			"2 of 3 receiver(s) of signal 'user_created' failed: \
+			 send_welcome: smtp down; <anonymous, priority 0>: boom"
+		);
+	}
+}
@@ -0,0 +1,246 @@
+//! Receiver introspection and dispatch debugging
+//!
+//! Lists the receivers connected to each signal, with the function that
+//! handles the signal, its sender filter and where it was connected, and
+//! explains which receivers a given dispatch would skip and why.
+//!
+//! With debug mode enabled (globally through [`set_debug_mode`] or per
+//! signal through [`Signal::set_debug`](super::Signal::set_debug)), every
+//! dispatch is logged at `INFO` level under the `reinhardt::signals` target,
+//! including skipped receivers and the time each receiver took.
+//!
+//! # Examples
+//!
+//! ```
+//! use reinhardt_core::signals::{Signal, SignalName};
+//! use reinhardt_core::signals::introspection::SkipReason;
+//!
+//! # #[derive(Debug)]
+//! # struct User { is_staff: bool }
+//! let signal = Signal::<User>::new(SignalName::custom("user_saved"));
+//! signal.connect_if(|_user| async { Ok(()) }, |user: &User| user.is_staff);
+//!
+//! for receiver in signal.receivers() {
+//!     println!("{receiver}");
+//! }
+//!
+//! let decisions = signal.explain(&User { is_staff: false }, None);
+//! assert_eq!(decisions[0].skipped, Some(SkipReason::PredicateRejected));
+//! ```
+
+use std::any::TypeId;
+use std::fmt;
+use std::panic::Location;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static DEBUG_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable dispatch logging for every signal
+pub fn set_debug_mode(enabled: bool) {
+	DEBUG_MODE.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns `true` if dispatch logging is enabled for every signal
+pub fn is_debug_mode() -> bool {
+	DEBUG_MODE.load(Ordering::Relaxed)
+}
+
+/// Source location where a receiver was connected or declared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RegistrationSite {
+	/// Source file
+	pub file: &'static str,
+	/// Line number
+	pub line: u32,
+	/// Column number
+	pub column: u32,
+}
+
+impl RegistrationSite {
+	/// Create a registration site from a file, line and column
+	pub const fn new(file: &'static str, line: u32, column: u32) -> Self {
+		Self { file, line, column }
+	}
+}
+
+impl From<&'static Location<'static>> for RegistrationSite {
+	fn from(location: &'static Location<'static>) -> Self {
+		Self::new(location.file(), location.line(), location.column())
+	}
+}
+
+impl fmt::Display for RegistrationSite {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}:{}:{}", self.file, self.line, self.column)
+	}
+}
+
+/// A receiver connected to a signal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiverDescription {
+	/// Path of the receiver function, or of the enclosing function for closures
+	pub function: &'static str,
+	/// Dispatch uid the receiver was connected with
+	pub dispatch_uid: Option<String>,
+	/// Execution priority (higher values execute first)
+	pub priority: i32,
+	/// Sender type the receiver is restricted to
+	pub sender_filter: Option<TypeId>,
+	/// Whether the receiver only runs when its predicate accepts the instance
+	pub has_predicate: bool,
+	/// Where the receiver was connected, or declared for `#[receiver]` functions
+	pub registered_at: RegistrationSite,
+}
+
+impl fmt::Display for ReceiverDescription {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} (priority {}", self.function, self.priority)?;
+		if let Some(uid) = &self.dispatch_uid {
+			write!(f, ", dispatch_uid {uid:?}")?;
+		}
+		if self.sender_filter.is_some() {
+			f.write_str(", sender filter")?;
+		}
+		if self.has_predicate {
+			f.write_str(", predicate")?;
+		}
+		write!(f, ") connected at {}", self.registered_at)
+	}
+}
+
+/// A signal in the global registry with its receivers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignalDescription {
+	/// Signal name
+	pub name: String,
+	/// Type of the instances the signal carries
+	///
+	/// Signals with the same name but different payload types are distinct,
+	/// so a receiver connected to `post_save::<Admin>()` never sees
+	/// `post_save::<User>()`.
+	pub payload_type: &'static str,
+	/// Connected receivers, in execution order
+	pub receivers: Vec<ReceiverDescription>,
+}
+
+/// Why a receiver would not run for a dispatch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+	/// The receiver is restricted to another sender type, or the dispatch
+	/// did not name a sender
+	SenderMismatch,
+	/// The receiver's predicate rejected the instance
+	PredicateRejected,
+}
+
+impl fmt::Display for SkipReason {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::SenderMismatch => f.write_str("sender mismatch"),
+			Self::PredicateRejected => f.write_str("predicate rejected"),
+		}
+	}
+}
+
+/// Whether a receiver would run for a given dispatch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiverDecision {
+	/// The receiver
+	pub receiver: ReceiverDescription,
+	/// Why the receiver would be skipped, or `None` if it would run
+	pub skipped: Option<SkipReason>,
+}
+
+/// List every signal in the global registry with its connected receivers
+///
+/// Signals are sorted by name, then payload type.
+pub fn connected_receivers() -> Vec<SignalDescription> {
+	let mut signals = super::registry::describe_registered();
+	signals.sort_by(|a, b| {
+		a.name
+			.cmp(&b.name)
+			.then_with(|| a.payload_type.cmp(b.payload_type))
+	});
+	signals
+}
+
+/// List the registered signals named `name`, one per payload type
+pub fn describe_signal(name: &str) -> Vec<SignalDescription> {
+	connected_receivers()
+		.into_iter()
+		.filter(|signal| signal.name == name)
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::super::{Signal, SignalName, get_signal};
+	use super::*;
+	use rstest::rstest;
+
+	struct Article;
+	struct Comment;
+
+	async fn index_article(_article: std::sync::Arc<u32>) -> Result<(), super::super::SignalError> {
+		Ok(())
+	}
+
+	#[rstest]
+	fn receivers_report_function_and_registration_site() {
+		// Arrange
+		let signal = Signal::<u32>::new(SignalName::custom("introspection_receivers"));
+		let line = line!() + 1;
+		signal.connect_with_options(index_article, None, Some("index".to_string()), 5);
+
+		// Act
+		let receivers = signal.receivers();
+
+		// Assert
+		assert_eq!(receivers.len(), 1);
+		assert!(receivers[0].function.ends_with("index_article"));
+		assert_eq!(receivers[0].dispatch_uid.as_deref(), Some("index"));
+		assert_eq!(receivers[0].priority, 5);
+		assert_eq!(receivers[0].registered_at.file, file!());
+		assert_eq!(receivers[0].registered_at.line, line);
+	}
+
+	#[rstest]
+	fn explain_reports_sender_mismatch() {
+		// Arrange
+		let signal = Signal::<u32>::new(SignalName::custom("introspection_explain"));
+		signal.connect_with_options(index_article, Some(TypeId::of::<Article>()), None, 0);
+		signal.connect(index_article);
+
+		// Act
+		let from_comment = signal.explain(&1, Some(TypeId::of::<Comment>()));
+		let from_article = signal.explain(&1, Some(TypeId::of::<Article>()));
+
+		// Assert
+		assert_eq!(from_comment[0].skipped, Some(SkipReason::SenderMismatch));
+		assert_eq!(from_comment[1].skipped, None);
+		assert_eq!(from_article[0].skipped, None);
+	}
+
+	#[rstest]
+	fn describe_signal_separates_payload_types() {
+		// Arrange
+		get_signal::<u32>(SignalName::custom("introspection_registry")).connect(index_article);
+		get_signal::<String>(SignalName::custom("introspection_registry"));
+
+		// Act
+		let signals = describe_signal("introspection_registry");
+
+		// Assert
+		assert_eq!(signals.len(), 2);
+		let with_receiver = signals
+			.iter()
+			.find(|signal| signal.payload_type == "u32")
+			.unwrap();
+		assert_eq!(with_receiver.receivers.len(), 1);
+		let without_receiver = signals
+			.iter()
+			.find(|signal| signal.payload_type.ends_with("String"))
+			.unwrap();
+		assert!(without_receiver.receivers.is_empty());
+	}
+}
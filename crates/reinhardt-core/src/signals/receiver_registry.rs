@@ -4,6 +4,7 @@
 //! register receiver functions and connect them to signals at runtime.
 
 use super::error::SignalError;
+use super::introspection::RegistrationSite;
 use std::any::TypeId;
 use std::future::Future;
 use std::pin::Pin;
@@ -24,7 +25,7 @@ pub struct ReceiverRegistryEntry {
 	/// Name of the signal to connect to
 	pub signal_name: &'static str,
 
-	/// Name of the receiver function (for debugging)
+	/// Path of the receiver function (for debugging and introspection)
 	pub receiver_name: &'static str,
 
 	/// Optional sender type filter (computed lazily)
@@ -38,6 +39,9 @@ pub struct ReceiverRegistryEntry {
 
 	/// Factory function to create the receiver
 	pub receiver_factory: fn() -> Arc<ReceiverFunction>,
+
+	/// Where the receiver is declared (reported by signal introspection)
+	pub registered_at: Option<RegistrationSite>,
 }
 
 impl ReceiverRegistryEntry {
@@ -54,6 +58,7 @@ impl ReceiverRegistryEntry {
 			dispatch_uid: None,
 			priority: 0,
 			receiver_factory,
+			registered_at: None,
 		}
 	}
 
@@ -74,6 +79,17 @@ impl ReceiverRegistryEntry {
 		self.priority = priority;
 		self
 	}
+
+	/// Set the source location where the receiver is declared
+	pub const fn with_registration_site(
+		mut self,
+		file: &'static str,
+		line: u32,
+		column: u32,
+	) -> Self {
+		self.registered_at = Some(RegistrationSite::new(file, line, column));
+		self
+	}
 }
 
 // Collect all ReceiverRegistryEntry instances via inventory
@@ -130,11 +146,18 @@ fn connect_receiver_to_signal(entry: &ReceiverRegistryEntry, receiver: Arc<Recei
 	// Compute sender type ID if filter function is provided
 	let sender_type_id = entry.sender_type_fn.map(|f| f());
 
-	// Connect with options (priority, dispatch_uid, sender_type)
-	signal.connect_with_options(
+	// Connect with options (priority, dispatch_uid, sender_type), keeping the
+	// declared function and site so introspection reports the user's code
+	// rather than this wrapper
+	signal.connect_described::<_, _, fn(&AnyData) -> bool>(
 		receiver_wrapper,
 		sender_type_id,
 		entry.dispatch_uid.map(|s| s.to_string()),
 		entry.priority,
+		None,
+		entry.receiver_name,
+		entry
+			.registered_at
+			.unwrap_or_else(|| std::panic::Location::caller().into()),
 	);
 }
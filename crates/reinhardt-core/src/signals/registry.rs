@@ -1,14 +1,31 @@
 //! Global signal registry
 
 use super::core::SignalName;
+use super::introspection::SignalDescription;
 use super::signal::Signal;
 use parking_lot::RwLock;
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
 
+/// A type-erased signal stored in the registry
+trait RegisteredSignal: Send + Sync {
+	fn as_any(&self) -> &dyn Any;
+	fn describe(&self) -> SignalDescription;
+}
+
+impl<T: Send + Sync + 'static> RegisteredSignal for Signal<T> {
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+
+	fn describe(&self) -> SignalDescription {
+		Signal::describe(self)
+	}
+}
+
 /// Global signal registry
 pub(crate) struct SignalRegistry {
-	signals: RwLock<HashMap<(TypeId, String), Box<dyn Any + Send + Sync>>>,
+	signals: RwLock<HashMap<(TypeId, String), Box<dyn RegisteredSignal>>>,
 }
 
 impl SignalRegistry {
@@ -27,7 +44,7 @@ impl SignalRegistry {
 		// This ensures no other thread can insert between our read check and write.
 		let signals = self.signals.upgradable_read();
 		if let Some(signal_any) = signals.get(&key)
-			&& let Some(signal) = signal_any.downcast_ref::<Signal<T>>()
+			&& let Some(signal) = signal_any.as_any().downcast_ref::<Signal<T>>()
 		{
 			return signal.clone();
 		}
@@ -53,7 +70,7 @@ impl SignalRegistry {
 		// This ensures no other thread can insert between our read check and write.
 		let signals = self.signals.upgradable_read();
 		if let Some(signal_any) = signals.get(&key)
-			&& let Some(signal) = signal_any.downcast_ref::<Signal<T>>()
+			&& let Some(signal) = signal_any.as_any().downcast_ref::<Signal<T>>()
 		{
 			return signal.clone();
 		}
@@ -64,6 +81,15 @@ impl SignalRegistry {
 		signals.insert(key, Box::new(signal.clone()));
 		signal
 	}
+
+	/// Describe every registered signal and its receivers
+	fn describe_all(&self) -> Vec<SignalDescription> {
+		self.signals
+			.read()
+			.values()
+			.map(|signal| signal.describe())
+			.collect()
+	}
 }
 
 // Global registry instance
@@ -81,6 +107,11 @@ pub fn get_signal_with_string<T: Send + Sync + 'static>(name: impl Into<String>)
 	GLOBAL_REGISTRY.get_or_create_with_string(name)
 }
 
+/// Describe every signal in the global registry
+pub(crate) fn describe_registered() -> Vec<SignalDescription> {
+	GLOBAL_REGISTRY.describe_all()
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
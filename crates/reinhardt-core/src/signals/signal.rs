@@ -3,13 +3,24 @@
 use super::context::{MetricsCollector, SignalContext, SignalMetrics};
 use super::core::{AsyncSignalDispatcher, ReceiverFn, SignalDispatcher, SignalName};
 use super::error::SignalError;
+use super::execution::{DispatchReport, ExecutionMode, ReceiverFailure};
+use super::introspection::{
+	self, ReceiverDecision, ReceiverDescription, RegistrationSite, SignalDescription, SkipReason,
+};
 use super::middleware::{MiddlewareFn, SignalMiddleware};
+use futures_util::FutureExt;
+use futures_util::future::join_all;
 use parking_lot::RwLock;
 use std::any::TypeId;
 use std::fmt;
 use std::future::Future;
+use std::panic::{AssertUnwindSafe, Location};
 use std::sync::Arc;
-use std::time::Instant;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Tracing target for dispatch logs emitted in debug mode
+const DEBUG_TARGET: &str = "reinhardt::signals";
 
 /// Type alias for predicate functions
 type PredicateFn<T> = Arc<dyn Fn(&T) -> bool + Send + Sync>;
@@ -21,6 +32,37 @@ pub(crate) struct ReceiverInfo<T: Send + Sync + 'static> {
 	pub(crate) dispatch_uid: Option<String>,
 	pub(crate) priority: i32,                     // Higher values execute first
 	pub(crate) predicate: Option<PredicateFn<T>>, // Optional condition for execution
+	pub(crate) function: &'static str,
+	pub(crate) registered_at: RegistrationSite,
+}
+
+impl<T: Send + Sync + 'static> ReceiverInfo<T> {
+	/// Returns why this receiver should not run for `instance` sent by
+	/// `sender_type_id`, or `None` if it should run
+	fn skip_reason(&self, instance: &T, sender_type_id: Option<TypeId>) -> Option<SkipReason> {
+		if let Some(expected_type_id) = self.sender_type_id
+			&& sender_type_id != Some(expected_type_id)
+		{
+			return Some(SkipReason::SenderMismatch);
+		}
+		if let Some(ref predicate) = self.predicate
+			&& !predicate(instance)
+		{
+			return Some(SkipReason::PredicateRejected);
+		}
+		None
+	}
+
+	fn describe(&self) -> ReceiverDescription {
+		ReceiverDescription {
+			function: self.function,
+			dispatch_uid: self.dispatch_uid.clone(),
+			priority: self.priority,
+			sender_filter: self.sender_type_id,
+			has_predicate: self.predicate.is_some(),
+			registered_at: self.registered_at,
+		}
+	}
 }
 
 impl<T: Send + Sync + 'static> Clone for ReceiverInfo<T> {
@@ -31,6 +73,8 @@ impl<T: Send + Sync + 'static> Clone for ReceiverInfo<T> {
 			dispatch_uid: self.dispatch_uid.clone(),
 			priority: self.priority,
 			predicate: self.predicate.clone(),
+			function: self.function,
+			registered_at: self.registered_at,
 		}
 	}
 }
@@ -41,6 +85,8 @@ pub struct Signal<T: Send + Sync + 'static> {
 	middlewares: Arc<RwLock<Vec<MiddlewareFn<T>>>>,
 	context: SignalContext,
 	metrics: Arc<MetricsCollector>,
+	execution_mode: Arc<RwLock<ExecutionMode>>,
+	debug: Arc<AtomicBool>,
 	name: String,
 }
 
@@ -64,6 +110,8 @@ impl<T: Send + Sync + 'static> Signal<T> {
 			middlewares: Arc::new(RwLock::new(Vec::new())),
 			context: SignalContext::new(),
 			metrics: Arc::new(MetricsCollector::new()),
+			execution_mode: Arc::new(RwLock::new(ExecutionMode::default())),
+			debug: Arc::new(AtomicBool::new(false)),
 			name: name.as_str().to_string(),
 		}
 	}
@@ -78,6 +126,8 @@ impl<T: Send + Sync + 'static> Signal<T> {
 			middlewares: Arc::new(RwLock::new(Vec::new())),
 			context: SignalContext::new(),
 			metrics: Arc::new(MetricsCollector::new()),
+			execution_mode: Arc::new(RwLock::new(ExecutionMode::default())),
+			debug: Arc::new(AtomicBool::new(false)),
 			name: name.into(),
 		}
 	}
@@ -106,6 +156,83 @@ impl<T: Send + Sync + 'static> Signal<T> {
 		&self.context
 	}
 
+	/// Set how [`dispatch`](Self::dispatch) runs receivers of equal priority
+	///
+	/// The mode is shared by all clones of this signal.
+	pub fn set_execution_mode(&self, mode: ExecutionMode) {
+		*self.execution_mode.write() = mode;
+	}
+
+	/// Get the current execution mode
+	pub fn execution_mode(&self) -> ExecutionMode {
+		*self.execution_mode.read()
+	}
+
+	/// Get the signal name
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Enable or disable dispatch logging for this signal
+	///
+	/// While enabled, each dispatch logs skipped receivers and the time every
+	/// receiver took under the `reinhardt::signals` tracing target. The flag
+	/// is shared by all clones of this signal; see
+	/// [`introspection::set_debug_mode`] to enable logging for every signal.
+	pub fn set_debug(&self, enabled: bool) {
+		self.debug.store(enabled, Ordering::Relaxed);
+	}
+
+	/// Returns `true` if dispatches of this signal are logged
+	pub fn is_debugging(&self) -> bool {
+		self.debug.load(Ordering::Relaxed) || introspection::is_debug_mode()
+	}
+
+	/// List the connected receivers in execution order
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::signals::{Signal, SignalName};
+	///
+	/// let signal = Signal::<String>::new(SignalName::custom("user_created"));
+	/// signal.connect(|_| async { Ok(()) });
+	///
+	/// let receivers = signal.receivers();
+	/// assert_eq!(receivers[0].registered_at.file, file!());
+	/// ```
+	pub fn receivers(&self) -> Vec<ReceiverDescription> {
+		self.receivers
+			.read()
+			.iter()
+			.map(ReceiverInfo::describe)
+			.collect()
+	}
+
+	/// Report, for each receiver, whether it would run for `instance` sent by
+	/// `sender_type_id`
+	///
+	/// Middleware decisions are not evaluated, since middleware hooks are
+	/// asynchronous and may have side effects.
+	pub fn explain(&self, instance: &T, sender_type_id: Option<TypeId>) -> Vec<ReceiverDecision> {
+		self.receivers
+			.read()
+			.iter()
+			.map(|receiver_info| ReceiverDecision {
+				receiver: receiver_info.describe(),
+				skipped: receiver_info.skip_reason(instance, sender_type_id),
+			})
+			.collect()
+	}
+
+	pub(crate) fn describe(&self) -> SignalDescription {
+		SignalDescription {
+			name: self.name.clone(),
+			payload_type: std::any::type_name::<T>(),
+			receivers: self.receivers(),
+		}
+	}
+
 	/// Connect a receiver function to this signal with full options
 	///
 	/// # Arguments
@@ -113,6 +240,7 @@ impl<T: Send + Sync + 'static> Signal<T> {
 	/// * `sender_type_id` - Optional TypeId to filter by sender type
 	/// * `dispatch_uid` - Optional unique identifier to prevent duplicate registration
 	/// * `priority` - Execution priority (higher values execute first, default: 0)
+	#[track_caller]
 	pub fn connect_with_options<F, Fut>(
 		&self,
 		receiver: F,
@@ -140,6 +268,7 @@ impl<T: Send + Sync + 'static> Signal<T> {
 	/// * `dispatch_uid` - Optional unique identifier to prevent duplicate registration
 	/// * `priority` - Execution priority (higher values execute first, default: 0)
 	/// * `predicate` - Optional condition that must be true for receiver to execute
+	#[track_caller]
 	pub fn connect_with_full_options<F, Fut, P>(
 		&self,
 		receiver: F,
@@ -151,6 +280,37 @@ impl<T: Send + Sync + 'static> Signal<T> {
 		F: Fn(Arc<T>) -> Fut + Send + Sync + 'static,
 		Fut: Future<Output = Result<(), SignalError>> + Send + 'static,
 		P: Fn(&T) -> bool + Send + Sync + 'static,
+	{
+		self.connect_described(
+			receiver,
+			sender_type_id,
+			dispatch_uid,
+			priority,
+			predicate,
+			std::any::type_name::<F>(),
+			Location::caller().into(),
+		);
+	}
+
+	/// Connect a receiver, recording the function and site reported by
+	/// [`receivers`](Self::receivers)
+	#[allow(
+		clippy::too_many_arguments,
+		reason = "mirrors connect_with_full_options plus introspection metadata"
+	)]
+	pub(crate) fn connect_described<F, Fut, P>(
+		&self,
+		receiver: F,
+		sender_type_id: Option<TypeId>,
+		dispatch_uid: Option<String>,
+		priority: i32,
+		predicate: Option<P>,
+		function: &'static str,
+		registered_at: RegistrationSite,
+	) where
+		F: Fn(Arc<T>) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Result<(), SignalError>> + Send + 'static,
+		P: Fn(&T) -> bool + Send + Sync + 'static,
 	{
 		let boxed: ReceiverFn<T> = Arc::new(move |instance| Box::pin(receiver(instance)));
 		let pred: Option<PredicateFn<T>> = predicate.map(|p| Arc::new(p) as PredicateFn<T>);
@@ -167,6 +327,8 @@ impl<T: Send + Sync + 'static> Signal<T> {
 			dispatch_uid,
 			priority,
 			predicate: pred,
+			function,
+			registered_at,
 		});
 
 		// Sort by priority (descending - higher priority first)
@@ -174,6 +336,7 @@ impl<T: Send + Sync + 'static> Signal<T> {
 	}
 
 	/// Connect a receiver function to this signal (simple version)
+	#[track_caller]
 	pub fn connect<F, Fut>(&self, receiver: F)
 	where
 		F: Fn(Arc<T>) -> Fut + Send + Sync + 'static,
@@ -183,6 +346,7 @@ impl<T: Send + Sync + 'static> Signal<T> {
 	}
 
 	/// Connect a receiver with priority
+	#[track_caller]
 	pub fn connect_with_priority<F, Fut>(&self, receiver: F, priority: i32)
 	where
 		F: Fn(Arc<T>) -> Fut + Send + Sync + 'static,
@@ -213,6 +377,7 @@ impl<T: Send + Sync + 'static> Signal<T> {
 	/// # Ok(())
 	/// # }
 	/// ```
+	#[track_caller]
 	pub fn chain(&self, next: &Signal<T>)
 	where
 		T: Clone,
@@ -248,6 +413,7 @@ impl<T: Send + Sync + 'static> Signal<T> {
 	///     NotificationPayload::from(user)
 	/// });
 	/// ```
+	#[track_caller]
 	pub fn chain_with<U, F>(&self, next: &Signal<U>, transform: F)
 	where
 		U: Send + Sync + 'static,
@@ -288,6 +454,7 @@ impl<T: Send + Sync + 'static> Signal<T> {
 	/// # Ok(())
 	/// # }
 	/// ```
+	#[track_caller]
 	pub fn merge(signals: Vec<&Signal<T>>) -> Signal<T>
 	where
 		T: Clone,
@@ -328,6 +495,7 @@ impl<T: Send + Sync + 'static> Signal<T> {
 	/// # Ok(())
 	/// # }
 	/// ```
+	#[track_caller]
 	pub fn filter<P>(&self, predicate: P) -> Signal<T>
 	where
 		P: Fn(&T) -> bool + Send + Sync + 'static,
@@ -373,6 +541,7 @@ impl<T: Send + Sync + 'static> Signal<T> {
 	/// # Ok(())
 	/// # }
 	/// ```
+	#[track_caller]
 	pub fn map<U, F>(&self, transform: F) -> Signal<U>
 	where
 		U: Send + Sync + 'static,
@@ -397,6 +566,7 @@ impl<T: Send + Sync + 'static> Signal<T> {
 	/// Connect a receiver with a predicate condition
 	///
 	/// The receiver will only execute if the predicate returns true for the instance
+	#[track_caller]
 	pub fn connect_if<F, Fut, P>(&self, receiver: F, predicate: P)
 	where
 		F: Fn(Arc<T>) -> Fut + Send + Sync + 'static,
@@ -430,11 +600,16 @@ impl<T: Send + Sync + 'static> Signal<T> {
 		let instance = Arc::new(instance);
 		let receivers = self.receivers.read().clone();
 		let middlewares = self.middlewares.read().clone();
+		let debug = self.is_debugging();
+		let dispatch_start = Instant::now();
 
 		// Execute before_send middleware hooks
 		for middleware in &middlewares {
 			let should_continue = middleware.before_send(&instance).await?;
 			if !should_continue {
+				if debug {
+					log_stopped(&self.name);
+				}
 				return Ok(()); // Middleware stopped signal propagation
 			}
 		}
@@ -442,22 +617,12 @@ impl<T: Send + Sync + 'static> Signal<T> {
 		let mut results = Vec::new();
 
 		for receiver_info in receivers {
-			// Check sender type match
-			if let Some(expected_type_id) = receiver_info.sender_type_id {
-				if let Some(actual_type_id) = sender_type_id {
-					if expected_type_id != actual_type_id {
-						continue; // Type mismatch, skip this receiver
-					}
-				} else {
-					continue; // Receiver expects a specific sender, but None was provided
+			// Check sender type match and predicate condition
+			if let Some(reason) = receiver_info.skip_reason(&instance, sender_type_id) {
+				if debug {
+					log_skipped(&self.name, &receiver_info, reason);
 				}
-			}
-
-			// Check predicate condition
-			if let Some(ref predicate) = receiver_info.predicate
-				&& !predicate(&instance)
-			{
-				continue; // Predicate failed, skip this receiver
+				continue;
 			}
 
 			// Execute before_receiver middleware hooks
@@ -474,6 +639,9 @@ impl<T: Send + Sync + 'static> Signal<T> {
 			}
 
 			if !should_execute {
+				if debug {
+					log_skipped(&self.name, &receiver_info, "skipped by middleware");
+				}
 				continue; // Middleware skipped this receiver
 			}
 
@@ -485,6 +653,9 @@ impl<T: Send + Sync + 'static> Signal<T> {
 			// Record metrics
 			self.metrics
 				.record_receiver_execution(duration, result.is_ok());
+			if debug {
+				log_executed(&self.name, &receiver_info, duration, &result);
+			}
 
 			// Execute after_receiver middleware hooks
 			for middleware in &middlewares {
@@ -503,6 +674,10 @@ impl<T: Send + Sync + 'static> Signal<T> {
 			middleware.after_send(&instance, &results).await?;
 		}
 
+		if debug {
+			log_dispatched(&self.name, results.len(), dispatch_start.elapsed());
+		}
+
 		Ok(())
 	}
 
@@ -523,6 +698,8 @@ impl<T: Send + Sync + 'static> Signal<T> {
 		let instance = Arc::new(instance);
 		let receivers = self.receivers.read().clone();
 		let middlewares = self.middlewares.read().clone();
+		let debug = self.is_debugging();
+		let dispatch_start = Instant::now();
 		let mut results = Vec::new();
 
 		// Execute before_send middleware hooks (ignore errors in robust mode)
@@ -530,27 +707,20 @@ impl<T: Send + Sync + 'static> Signal<T> {
 			if let Ok(should_continue) = middleware.before_send(&instance).await
 				&& !should_continue
 			{
+				if debug {
+					log_stopped(&self.name);
+				}
 				return results; // Middleware stopped signal propagation
 			}
 		}
 
 		for receiver_info in receivers {
-			// Check sender type match
-			if let Some(expected_type_id) = receiver_info.sender_type_id {
-				if let Some(actual_type_id) = sender_type_id {
-					if expected_type_id != actual_type_id {
-						continue; // Type mismatch, skip this receiver
-					}
-				} else {
-					continue; // Receiver expects a specific sender, but None was provided
+			// Check sender type match and predicate condition
+			if let Some(reason) = receiver_info.skip_reason(&instance, sender_type_id) {
+				if debug {
+					log_skipped(&self.name, &receiver_info, reason);
 				}
-			}
-
-			// Check predicate condition
-			if let Some(ref predicate) = receiver_info.predicate
-				&& !predicate(&instance)
-			{
-				continue; // Predicate failed, skip this receiver
+				continue;
 			}
 
 			// Execute before_receiver middleware hooks
@@ -567,6 +737,9 @@ impl<T: Send + Sync + 'static> Signal<T> {
 			}
 
 			if !should_execute {
+				if debug {
+					log_skipped(&self.name, &receiver_info, "skipped by middleware");
+				}
 				continue; // Middleware skipped this receiver
 			}
 
@@ -578,6 +751,9 @@ impl<T: Send + Sync + 'static> Signal<T> {
 			// Record metrics
 			self.metrics
 				.record_receiver_execution(duration, result.is_ok());
+			if debug {
+				log_executed(&self.name, &receiver_info, duration, &result);
+			}
 
 			// Execute after_receiver middleware hooks (ignore errors)
 			for middleware in &middlewares {
@@ -596,9 +772,211 @@ impl<T: Send + Sync + 'static> Signal<T> {
 			}
 		}
 
+		if debug {
+			log_dispatched(&self.name, results.len(), dispatch_start.elapsed());
+		}
+
 		results
 	}
 
+	/// Send signal to every receiver, isolating receiver failures
+	///
+	/// Unlike [`send`](Self::send), a failing or panicking receiver does not
+	/// stop the remaining receivers. Receivers run in priority order according
+	/// to the signal's [`ExecutionMode`], and every failure is collected into
+	/// the returned [`DispatchReport`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::signals::{ExecutionMode, Signal, SignalError, SignalName};
+	///
+	/// # #[tokio::main]
+	/// # async fn main() {
+	/// let signal = Signal::<String>::new(SignalName::custom("user_created"));
+	/// signal.set_execution_mode(ExecutionMode::Concurrent);
+	/// signal.connect(|_| async { Err(SignalError::new("smtp down")) });
+	/// signal.connect(|_| async { Ok(()) });
+	///
+	/// let report = signal.dispatch("alice".to_string()).await;
+	/// assert_eq!(report.executed, 2);
+	/// assert_eq!(report.failures.len(), 1);
+	/// # }
+	/// ```
+	pub async fn dispatch(&self, instance: T) -> DispatchReport {
+		self.dispatch_with_sender(instance, None).await
+	}
+
+	/// Send signal to every matching receiver, isolating receiver failures
+	///
+	/// See [`dispatch`](Self::dispatch).
+	pub async fn dispatch_with_sender(
+		&self,
+		instance: T,
+		sender_type_id: Option<TypeId>,
+	) -> DispatchReport {
+		self.metrics.record_send();
+
+		let mut report = DispatchReport::new(self.name.clone());
+		let instance = Arc::new(instance);
+		let receivers = self.receivers.read().clone();
+		let middlewares = self.middlewares.read().clone();
+		let mode = self.execution_mode();
+		let debug = self.is_debugging();
+		let dispatch_start = Instant::now();
+
+		// Execute before_send middleware hooks (ignore errors, as in robust mode)
+		for middleware in &middlewares {
+			if let Ok(should_continue) = middleware.before_send(&instance).await
+				&& !should_continue
+			{
+				if debug {
+					log_stopped(&self.name);
+				}
+				return report;
+			}
+		}
+
+		let eligible: Vec<_> = receivers
+			.into_iter()
+			.filter(
+				|receiver_info| match receiver_info.skip_reason(&instance, sender_type_id) {
+					Some(reason) => {
+						if debug {
+							log_skipped(&self.name, receiver_info, reason);
+						}
+						false
+					}
+					None => true,
+				},
+			)
+			.collect();
+
+		let mut results = Vec::with_capacity(eligible.len());
+		let mut start = 0;
+		while start < eligible.len() {
+			// Receivers are sorted by priority, so each tier is a contiguous run
+			let end = match mode {
+				ExecutionMode::Sequential => start + 1,
+				ExecutionMode::Concurrent => {
+					let priority = eligible[start].priority;
+					start
+						+ eligible[start..]
+							.iter()
+							.take_while(|receiver_info| receiver_info.priority == priority)
+							.count()
+				}
+			};
+			let tier = &eligible[start..end];
+			let outcomes =
+				join_all(tier.iter().map(|receiver_info| {
+					self.run_isolated(receiver_info, &instance, &middlewares)
+				}))
+				.await;
+
+			for (receiver_info, outcome) in tier.iter().zip(outcomes) {
+				let Some(result) = outcome else {
+					if debug {
+						log_skipped(&self.name, receiver_info, "skipped by middleware");
+					}
+					continue; // Middleware skipped this receiver
+				};
+				report.executed += 1;
+				if let Err(error) = &result {
+					report.failures.push(ReceiverFailure {
+						dispatch_uid: receiver_info.dispatch_uid.clone(),
+						priority: receiver_info.priority,
+						error: error.clone(),
+					});
+				}
+				results.push(result);
+			}
+			start = end;
+		}
+
+		// Execute after_send middleware hooks
+		for middleware in &middlewares {
+			if let Err(e) = middleware.after_send(&instance, &results).await {
+				tracing::warn!(signal = %self.name, error = %e, "signal after_send middleware failed");
+			}
+		}
+
+		if debug {
+			log_dispatched(&self.name, report.executed, dispatch_start.elapsed());
+		}
+
+		report
+	}
+
+	/// Run one receiver, turning middleware errors and panics into failures
+	///
+	/// Returns `None` when a middleware skipped the receiver.
+	async fn run_isolated(
+		&self,
+		receiver_info: &ReceiverInfo<T>,
+		instance: &Arc<T>,
+		middlewares: &[MiddlewareFn<T>],
+	) -> Option<Result<(), SignalError>> {
+		let dispatch_uid = receiver_info.dispatch_uid.as_deref();
+		for middleware in middlewares {
+			match middleware.before_receiver(instance, dispatch_uid).await {
+				Ok(true) => {}
+				Ok(false) => return None,
+				Err(e) => return Some(Err(e)),
+			}
+		}
+
+		let start = Instant::now();
+		let result =
+			AssertUnwindSafe(async { (receiver_info.receiver)(Arc::clone(instance)).await })
+				.catch_unwind()
+				.await
+				.unwrap_or_else(|panic| {
+					let message = panic
+						.downcast_ref::<&str>()
+						.map(|s| s.to_string())
+						.or_else(|| panic.downcast_ref::<String>().cloned())
+						.unwrap_or_else(|| "unknown panic".to_string());
+					Err(SignalError::new(format!("receiver panicked: {}", message)))
+				});
+		let duration = start.elapsed();
+		self.metrics
+			.record_receiver_execution(duration, result.is_ok());
+		if self.is_debugging() {
+			log_executed(&self.name, receiver_info, duration, &result);
+		}
+
+		// Execute after_receiver middleware hooks (ignore errors)
+		for middleware in middlewares {
+			let _ = middleware
+				.after_receiver(instance, dispatch_uid, &result)
+				.await;
+		}
+
+		Some(result)
+	}
+
+	/// Dispatch after the surrounding transaction commits
+	///
+	/// Inside [`TransactionSignals::scope`], the dispatch is queued and runs
+	/// when [`TransactionSignals::send_commit`] is called; a rollback discards
+	/// it. Outside a transaction scope the signal is dispatched immediately.
+	///
+	/// Returns the report of an immediate dispatch, or `None` when deferred.
+	///
+	/// [`TransactionSignals::scope`]: super::transaction::TransactionSignals::scope
+	/// [`TransactionSignals::send_commit`]: super::transaction::TransactionSignals::send_commit
+	#[cfg(native)]
+	pub async fn send_on_commit(&self, instance: T) -> Option<DispatchReport> {
+		let signal = self.clone();
+		let dispatch: super::transaction::DeferredDispatch =
+			Box::new(move || Box::pin(async move { signal.dispatch(instance).await }));
+		match super::transaction::defer_until_commit(dispatch) {
+			Ok(()) => None,
+			Err(dispatch) => Some(dispatch().await),
+		}
+	}
+
 	/// Send signal asynchronously (fire and forget)
 	#[cfg(native)]
 	pub fn send_async(&self, instance: T) {
@@ -606,6 +984,8 @@ impl<T: Send + Sync + 'static> Signal<T> {
 		let receivers = self.receivers.read().clone();
 		let middlewares = self.middlewares.read().clone();
 		let metrics = Arc::clone(&self.metrics);
+		let debug = self.is_debugging();
+		let name = self.name.clone();
 
 		// Record send event
 		metrics.record_send();
@@ -630,6 +1010,9 @@ impl<T: Send + Sync + 'static> Signal<T> {
 				if let Some(ref predicate) = receiver_info.predicate
 					&& !predicate(&instance)
 				{
+					if debug {
+						log_skipped(&name, &receiver_info, SkipReason::PredicateRejected);
+					}
 					continue;
 				}
 
@@ -647,6 +1030,9 @@ impl<T: Send + Sync + 'static> Signal<T> {
 				}
 
 				if !should_execute {
+					if debug {
+						log_skipped(&name, &receiver_info, "skipped by middleware");
+					}
 					continue;
 				}
 
@@ -657,6 +1043,9 @@ impl<T: Send + Sync + 'static> Signal<T> {
 
 				// Record metrics
 				metrics.record_receiver_execution(duration, result.is_ok());
+				if debug {
+					log_executed(&name, &receiver_info, duration, &result);
+				}
 
 				// Execute after_receiver middleware hooks (ignore errors)
 				for middleware in &middlewares {
@@ -686,6 +1075,60 @@ impl<T: Send + Sync + 'static> Signal<T> {
 	}
 }
 
+fn log_stopped(signal: &str) {
+	tracing::info!(target: DEBUG_TARGET, signal, "dispatch stopped by before_send middleware");
+}
+
+fn log_skipped<T: Send + Sync + 'static>(
+	signal: &str,
+	receiver_info: &ReceiverInfo<T>,
+	reason: impl fmt::Display,
+) {
+	tracing::info!(
+		target: DEBUG_TARGET,
+		signal,
+		receiver = receiver_info.function,
+		registered_at = %receiver_info.registered_at,
+		%reason,
+		"receiver skipped"
+	);
+}
+
+fn log_executed<T: Send + Sync + 'static>(
+	signal: &str,
+	receiver_info: &ReceiverInfo<T>,
+	duration: Duration,
+	result: &Result<(), SignalError>,
+) {
+	match result {
+		Ok(()) => tracing::info!(
+			target: DEBUG_TARGET,
+			signal,
+			receiver = receiver_info.function,
+			elapsed_us = duration.as_micros() as u64,
+			"receiver executed"
+		),
+		Err(error) => tracing::info!(
+			target: DEBUG_TARGET,
+			signal,
+			receiver = receiver_info.function,
+			elapsed_us = duration.as_micros() as u64,
+			%error,
+			"receiver failed"
+		),
+	}
+}
+
+fn log_dispatched(signal: &str, executed: usize, duration: Duration) {
+	tracing::info!(
+		target: DEBUG_TARGET,
+		signal,
+		executed,
+		elapsed_us = duration.as_micros() as u64,
+		"signal dispatched"
+	);
+}
+
 impl<T: Send + Sync + 'static> Clone for Signal<T> {
 	fn clone(&self) -> Self {
 		Self {
@@ -693,6 +1136,8 @@ impl<T: Send + Sync + 'static> Clone for Signal<T> {
 			middlewares: Arc::clone(&self.middlewares),
 			context: self.context.clone(),
 			metrics: Arc::clone(&self.metrics),
+			execution_mode: Arc::clone(&self.execution_mode),
+			debug: Arc::clone(&self.debug),
 			name: self.name.clone(),
 		}
 	}
@@ -23,12 +23,51 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! Signals derived from database writes can be deferred until the transaction
+//! commits with [`Signal::send_on_commit`] inside [`TransactionSignals::scope`].
 
 use super::core::SignalName;
 use super::error::SignalError;
+#[cfg(native)]
+use super::execution::DispatchReport;
 use super::registry::get_signal;
 use super::signal::Signal;
 use serde::{Deserialize, Serialize};
+#[cfg(native)]
+use std::future::Future;
+#[cfg(native)]
+use std::pin::Pin;
+#[cfg(native)]
+use std::sync::Arc;
+
+/// A signal dispatch queued by [`Signal::send_on_commit`]
+#[cfg(native)]
+pub(crate) type DeferredDispatch =
+	Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = DispatchReport> + Send>> + Send>;
+
+#[cfg(native)]
+type CommitQueue = Arc<parking_lot::Mutex<Vec<DeferredDispatch>>>;
+
+#[cfg(native)]
+tokio::task_local! {
+	/// Queue of the innermost [`TransactionSignals::scope`]
+	static COMMIT_QUEUE: CommitQueue;
+}
+
+/// Queue `dispatch` on the current transaction scope
+///
+/// Returns the dispatch back when no transaction scope is active.
+#[cfg(native)]
+pub(crate) fn defer_until_commit(dispatch: DeferredDispatch) -> Result<(), DeferredDispatch> {
+	match COMMIT_QUEUE.try_with(Arc::clone) {
+		Ok(queue) => {
+			queue.lock().push(dispatch);
+			Ok(())
+		}
+		Err(_) => Err(dispatch),
+	}
+}
 
 /// Transaction context passed to signal receivers
 ///
@@ -152,6 +191,8 @@ impl TransactionContext {
 /// ```
 pub struct TransactionSignals {
 	context: TransactionContext,
+	#[cfg(native)]
+	deferred: CommitQueue,
 }
 
 impl TransactionSignals {
@@ -167,6 +208,8 @@ impl TransactionSignals {
 	pub fn new(transaction_id: impl Into<String>) -> Self {
 		Self {
 			context: TransactionContext::new(transaction_id),
+			#[cfg(native)]
+			deferred: CommitQueue::default(),
 		}
 	}
 
@@ -186,6 +229,8 @@ impl TransactionSignals {
 	) -> Self {
 		Self {
 			context: TransactionContext::nested(transaction_id, depth, savepoint_name),
+			#[cfg(native)]
+			deferred: CommitQueue::default(),
 		}
 	}
 
@@ -237,7 +282,12 @@ impl TransactionSignals {
 	/// # }
 	/// ```
 	pub async fn send_commit(&self) -> Result<(), SignalError> {
-		on_commit().send(self.context.clone()).await
+		let committed = on_commit().send(self.context.clone()).await;
+		#[cfg(native)]
+		let deferred = self.run_deferred().await;
+		#[cfg(not(native))]
+		let deferred = Ok(());
+		committed.and(deferred)
 	}
 
 	/// Send transaction rollback signal
@@ -255,9 +305,72 @@ impl TransactionSignals {
 	/// # }
 	/// ```
 	pub async fn send_rollback(&self) -> Result<(), SignalError> {
+		#[cfg(native)]
+		self.deferred.lock().clear();
 		on_rollback().send(self.context.clone()).await
 	}
 
+	/// Run `future` with this transaction as the target of
+	/// [`Signal::send_on_commit`]
+	///
+	/// Dispatches queued inside the scope run after the commit signal when
+	/// [`send_commit`](Self::send_commit) is called, and are discarded by
+	/// [`send_rollback`](Self::send_rollback).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::signals::{Signal, SignalName};
+	/// use reinhardt_core::signals::transaction::TransactionSignals;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+	/// let order_placed = Signal::<u64>::new(SignalName::custom("order_placed"));
+	/// let tx = TransactionSignals::new("tx_001");
+	///
+	/// tx.scope(async {
+	///     // Queued until the transaction commits
+	///     assert!(order_placed.send_on_commit(42).await.is_none());
+	/// })
+	/// .await;
+	/// assert_eq!(tx.pending_on_commit(), 1);
+	///
+	/// tx.send_commit().await?;
+	/// assert_eq!(tx.pending_on_commit(), 0);
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[cfg(native)]
+	pub async fn scope<F: Future>(&self, future: F) -> F::Output {
+		COMMIT_QUEUE.scope(Arc::clone(&self.deferred), future).await
+	}
+
+	/// Number of dispatches waiting for this transaction to commit
+	#[cfg(native)]
+	pub fn pending_on_commit(&self) -> usize {
+		self.deferred.lock().len()
+	}
+
+	/// Run queued after-commit dispatches, in the order they were queued
+	///
+	/// Every dispatch runs even if an earlier one failed; failures are
+	/// combined into a single error.
+	#[cfg(native)]
+	async fn run_deferred(&self) -> Result<(), SignalError> {
+		let pending = std::mem::take(&mut *self.deferred.lock());
+		let mut failures = Vec::new();
+		for dispatch in pending {
+			if let Err(e) = dispatch().await.into_result() {
+				failures.push(e.message);
+			}
+		}
+		if failures.is_empty() {
+			Ok(())
+		} else {
+			Err(SignalError::new(failures.join("; ")))
+		}
+	}
+
 	/// Enter a savepoint and send signal
 	///
 	/// # Examples
@@ -558,4 +671,69 @@ mod tests {
 		// Clean up after test
 		on_savepoint().disconnect_all();
 	}
+	#[tokio::test]
+	#[serial_test::serial]
+	async fn test_send_on_commit_runs_after_commit() {
+		// Arrange
+		let signal = Signal::<u64>::new(SignalName::custom("test_order_placed"));
+		let received = Arc::new(Mutex::new(Vec::new()));
+		let r = received.clone();
+		signal.connect(move |id| {
+			let r = r.clone();
+			async move {
+				r.lock().push(*id);
+				Ok(())
+			}
+		});
+		let tx = TransactionSignals::new("tx_deferred");
+
+		// Act
+		let immediate = tx.scope(signal.send_on_commit(7)).await;
+		let before_commit = received.lock().len();
+		tx.send_commit().await.unwrap();
+
+		// Assert
+		assert!(immediate.is_none());
+		assert_eq!(before_commit, 0);
+		assert_eq!(*received.lock(), vec![7]);
+		assert_eq!(tx.pending_on_commit(), 0);
+	}
+
+	#[tokio::test]
+	#[serial_test::serial]
+	async fn test_send_on_commit_is_discarded_on_rollback() {
+		// Arrange
+		let signal = Signal::<u64>::new(SignalName::custom("test_order_cancelled"));
+		let calls = Arc::new(AtomicUsize::new(0));
+		let c = calls.clone();
+		signal.connect(move |_| {
+			let c = c.clone();
+			async move {
+				c.fetch_add(1, Ordering::SeqCst);
+				Ok(())
+			}
+		});
+		let tx = TransactionSignals::new("tx_rolled_back");
+		tx.scope(signal.send_on_commit(1)).await;
+
+		// Act
+		tx.send_rollback().await.unwrap();
+		tx.send_commit().await.unwrap();
+
+		// Assert
+		assert_eq!(calls.load(Ordering::SeqCst), 0);
+	}
+
+	#[tokio::test]
+	async fn test_send_on_commit_outside_transaction_dispatches_immediately() {
+		// Arrange
+		let signal = Signal::<u64>::new(SignalName::custom("test_order_shipped"));
+		signal.connect(|_| async { Err(SignalError::new("carrier offline")) });
+
+		// Act
+		let report = signal.send_on_commit(1).await.unwrap();
+
+		// Assert
+		assert_eq!(report.failures.len(), 1);
+	}
 }
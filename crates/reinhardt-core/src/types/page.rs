@@ -508,6 +508,41 @@ impl Page {
 		Self::Text(content.into())
 	}
 
+	/// Creates a view from untrusted HTML, keeping only what `policy` allows.
+	///
+	/// The HTML is sanitized into elements and text nodes, so it renders
+	/// the same way on the server and in the browser without inserting raw
+	/// markup.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use reinhardt_core::security::SanitizePolicy;
+	/// use reinhardt_core::types::page::Page;
+	///
+	/// let comment = "<b>Nice</b><script>alert(1)</script>";
+	/// let view = Page::sanitized_html(comment, &SanitizePolicy::basic());
+	/// assert_eq!(view.render_to_string(), "<b>Nice</b>");
+	/// ```
+	#[cfg(feature = "security")]
+	pub fn sanitized_html(html: &str, policy: &crate::security::SanitizePolicy) -> Self {
+		fn to_page(node: crate::security::SanitizedNode) -> Page {
+			match node {
+				crate::security::SanitizedNode::Text(text) => Page::Text(text.into()),
+				crate::security::SanitizedNode::Element {
+					tag,
+					attrs,
+					children,
+				} => PageElement::new(tag)
+					.with_attrs(attrs)
+					.children(children.into_iter().map(to_page))
+					.into_page(),
+			}
+		}
+
+		Self::Fragment(policy.clean_nodes(html).into_iter().map(to_page).collect())
+	}
+
 	/// Creates a fragment view.
 	pub fn fragment(children: impl IntoIterator<Item = impl IntoPage>) -> Self {
 		Self::Fragment(children.into_iter().map(|c| c.into_page()).collect())
@@ -96,7 +96,9 @@ pub use string::{
 pub use uniqueness::UniqueValidator;
 pub use url::UrlValidator;
 pub use validate::Validate;
-pub use validation_errors::ValidationErrors;
+pub use validation_errors::{
+	NON_FIELD_ERRORS, PROBLEM_JSON_CONTENT_TYPE, VALIDATION_PROBLEM_TYPE, ValidationErrors,
+};
 
 // Re-export the derive macro so `use reinhardt_core::validators::Validate` brings
 // both the trait and derive macro into scope (same pattern as serde/serde_derive).
@@ -1,5 +1,6 @@
 //! Validation error types
 
+use std::collections::BTreeMap;
 use thiserror::Error;
 
 /// Validation errors produced by validators.
@@ -272,6 +273,240 @@ pub enum ValidationError {
 	/// Custom validation error with user-defined message.
 	#[error("Custom validation error: {0}")]
 	Custom(String),
+
+	/// Validation failure identified by an application-defined code.
+	///
+	/// Used when errors from forms, serializers or the database are collected
+	/// into [`ValidationErrors`](super::ValidationErrors) without a dedicated
+	/// variant, e.g. `required`.
+	#[error("{message}")]
+	Coded {
+		/// Machine-readable error code.
+		code: String,
+		/// Human-readable error message.
+		message: String,
+		/// Values interpolated into the message.
+		params: BTreeMap<String, String>,
+	},
+}
+
+impl ValidationError {
+	/// Create a [`ValidationError::Coded`] error without parameters.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::validators::ValidationError;
+	///
+	/// let error = ValidationError::coded("required", "This field is required.")
+	///     .with_param("field", "email");
+	/// assert_eq!(error.code(), "required");
+	/// assert_eq!(error.params()["field"], "email");
+	/// assert_eq!(error.to_string(), "This field is required.");
+	/// ```
+	pub fn coded(code: impl Into<String>, message: impl Into<String>) -> Self {
+		Self::Coded {
+			code: code.into(),
+			message: message.into(),
+			params: BTreeMap::new(),
+		}
+	}
+
+	/// Add a parameter to a [`ValidationError::Coded`] error.
+	///
+	/// Other variants carry their parameters in their fields and are
+	/// returned unchanged.
+	pub fn with_param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+		if let Self::Coded { params, .. } = &mut self {
+			params.insert(name.into(), value.into());
+		}
+		self
+	}
+
+	/// Machine-readable code identifying the kind of failure.
+	///
+	/// Codes follow Django's naming where one exists (`min_length`,
+	/// `max_value`, `unique`, ...).
+	pub fn code(&self) -> &str {
+		match self {
+			Self::InvalidEmail(_) => "invalid_email",
+			Self::InvalidUrl(_) => "invalid_url",
+			Self::TooSmall { .. } => "min_value",
+			Self::TooLarge { .. } => "max_value",
+			Self::TooShort { .. } => "min_length",
+			Self::TooLong { .. } => "max_length",
+			Self::PatternMismatch(_) => "invalid",
+			Self::NotUnique { .. } => "unique",
+			Self::InvalidSlug(_) => "invalid_slug",
+			Self::InvalidUUID(_) => "invalid_uuid",
+			Self::InvalidIPAddress(_) => "invalid_ip_address",
+			Self::InvalidDate(_) => "invalid_date",
+			Self::InvalidTime(_) => "invalid_time",
+			Self::InvalidDateTime(_) => "invalid_datetime",
+			Self::InvalidJSON(_) => "invalid_json",
+			Self::InvalidCreditCard(_) => "invalid_credit_card",
+			Self::CardTypeNotAllowed { .. } => "card_type_not_allowed",
+			Self::InvalidPhoneNumber(_) => "invalid_phone_number",
+			Self::CountryCodeNotAllowed { .. } => "country_code_not_allowed",
+			Self::InvalidIBAN(_) => "invalid_iban",
+			Self::IBANCountryNotAllowed { .. } => "iban_country_not_allowed",
+			Self::InvalidFileExtension { .. } => "invalid_extension",
+			Self::InvalidMimeType { .. } => "invalid_mime_type",
+			Self::ForeignKeyNotFound { .. } => "does_not_exist",
+			Self::FileSizeTooSmall { .. } => "file_too_small",
+			Self::FileSizeTooLarge { .. } => "file_too_large",
+			Self::AllValidatorsFailed { .. } => "all_validators_failed",
+			Self::CompositeValidationFailed(_) => "composite_validation_failed",
+			Self::InvalidPostalCode { .. } => "invalid_postal_code",
+			Self::PostalCodeCountryNotRecognized { .. } => "postal_code_country_not_recognized",
+			Self::PostalCodeCountryNotAllowed { .. } => "postal_code_country_not_allowed",
+			Self::ImageWidthTooSmall { .. } => "image_width_too_small",
+			Self::ImageWidthTooLarge { .. } => "image_width_too_large",
+			Self::ImageHeightTooSmall { .. } => "image_height_too_small",
+			Self::ImageHeightTooLarge { .. } => "image_height_too_large",
+			Self::InvalidAspectRatio { .. } => "invalid_aspect_ratio",
+			Self::ImageReadError(_) => "invalid_image",
+			Self::Custom(_) => "custom",
+			Self::Coded { code, .. } => code,
+		}
+	}
+
+	/// Values describing the failure, keyed by parameter name.
+	///
+	/// Lets clients build their own (e.g. translated) message from
+	/// [`code`](Self::code) instead of parsing the English message.
+	pub fn params(&self) -> BTreeMap<String, String> {
+		fn map<const N: usize>(pairs: [(&str, String); N]) -> BTreeMap<String, String> {
+			pairs
+				.into_iter()
+				.map(|(name, value)| (name.to_string(), value))
+				.collect()
+		}
+
+		match self {
+			Self::InvalidEmail(value)
+			| Self::InvalidUrl(value)
+			| Self::InvalidSlug(value)
+			| Self::InvalidUUID(value)
+			| Self::InvalidIPAddress(value)
+			| Self::InvalidDate(value)
+			| Self::InvalidTime(value)
+			| Self::InvalidDateTime(value)
+			| Self::InvalidPhoneNumber(value)
+			| Self::InvalidIBAN(value) => map([("value", value.clone())]),
+			Self::TooSmall { value, min } => map([("value", value.clone()), ("min", min.clone())]),
+			Self::TooLarge { value, max } => map([("value", value.clone()), ("max", max.clone())]),
+			Self::TooShort { length, min } => {
+				map([("length", length.to_string()), ("min", min.to_string())])
+			}
+			Self::TooLong { length, max } => {
+				map([("length", length.to_string()), ("max", max.to_string())])
+			}
+			Self::NotUnique { field, value } => {
+				map([("field", field.clone()), ("value", value.clone())])
+			}
+			Self::CardTypeNotAllowed {
+				card_type,
+				allowed_types,
+			} => map([
+				("card_type", card_type.clone()),
+				("allowed", allowed_types.clone()),
+			]),
+			Self::CountryCodeNotAllowed {
+				country_code,
+				allowed_countries,
+			} => map([
+				("country", country_code.clone()),
+				("allowed", allowed_countries.clone()),
+			]),
+			Self::IBANCountryNotAllowed {
+				country_code,
+				allowed_codes,
+			} => map([
+				("country", country_code.clone()),
+				("allowed", allowed_codes.clone()),
+			]),
+			Self::InvalidFileExtension {
+				extension,
+				allowed_extensions,
+			} => map([
+				("extension", extension.clone()),
+				("allowed", allowed_extensions.clone()),
+			]),
+			Self::InvalidMimeType {
+				mime_type,
+				allowed_mime_types,
+			} => map([
+				("mime_type", mime_type.clone()),
+				("allowed", allowed_mime_types.clone()),
+			]),
+			Self::ForeignKeyNotFound {
+				field,
+				value,
+				table,
+			} => map([
+				("field", field.clone()),
+				("value", value.clone()),
+				("table", table.clone()),
+			]),
+			Self::FileSizeTooSmall {
+				size_bytes,
+				min_bytes,
+			} => map([
+				("size", size_bytes.to_string()),
+				("min", min_bytes.to_string()),
+			]),
+			Self::FileSizeTooLarge {
+				size_bytes,
+				max_bytes,
+			} => map([
+				("size", size_bytes.to_string()),
+				("max", max_bytes.to_string()),
+			]),
+			Self::InvalidPostalCode { postal_code }
+			| Self::PostalCodeCountryNotRecognized { postal_code } => map([("value", postal_code.clone())]),
+			Self::PostalCodeCountryNotAllowed {
+				country,
+				allowed_countries,
+			} => map([
+				("country", country.clone()),
+				("allowed", allowed_countries.clone()),
+			]),
+			Self::ImageWidthTooSmall { width, min_width } => {
+				map([("width", width.to_string()), ("min", min_width.to_string())])
+			}
+			Self::ImageWidthTooLarge { width, max_width } => {
+				map([("width", width.to_string()), ("max", max_width.to_string())])
+			}
+			Self::ImageHeightTooSmall { height, min_height } => map([
+				("height", height.to_string()),
+				("min", min_height.to_string()),
+			]),
+			Self::ImageHeightTooLarge { height, max_height } => map([
+				("height", height.to_string()),
+				("max", max_height.to_string()),
+			]),
+			Self::InvalidAspectRatio {
+				actual_width,
+				actual_height,
+				expected_width,
+				expected_height,
+			} => map([
+				("actual_width", actual_width.to_string()),
+				("actual_height", actual_height.to_string()),
+				("expected_width", expected_width.to_string()),
+				("expected_height", expected_height.to_string()),
+			]),
+			Self::PatternMismatch(_)
+			| Self::InvalidJSON(_)
+			| Self::InvalidCreditCard(_)
+			| Self::AllValidatorsFailed { .. }
+			| Self::CompositeValidationFailed(_)
+			| Self::ImageReadError(_)
+			| Self::Custom(_) => BTreeMap::new(),
+			Self::Coded { params, .. } => params.clone(),
+		}
+	}
 }
 
 /// Result type for validation operations.
@@ -367,6 +602,14 @@ mod tests {
 		assert!(result.is_err());
 	}
 
+	#[test]
+	fn test_code_and_params() {
+		let error = ValidationError::TooShort { length: 3, min: 5 };
+		assert_eq!(error.code(), "min_length");
+		assert_eq!(error.params()["min"], "5");
+		assert_eq!(error.params()["length"], "3");
+	}
+
 	#[test]
 	fn test_not_unique_error() {
 		let error = ValidationError::NotUnique {
@@ -435,6 +435,9 @@ impl ValidationMessages {
 			ValidationError::Custom(message) => {
 				self.format_with_value("validation-custom", "message", message)
 			}
+			ValidationError::Coded { message, .. } => {
+				self.format_with_value("validation-custom", "message", message)
+			}
 		}
 	}
 }
@@ -1,19 +1,58 @@
 //! Aggregate validation errors by field name
+//!
+//! [`ValidationErrors`] is the error structure shared by validators, the
+//! `Validate` derive, forms, serializers and unique-constraint violations
+//! mapped from database errors. Each error carries a machine-readable
+//! [`code`](ValidationError::code) and its [`params`](ValidationError::params)
+//! next to the message, and errors that concern no single field are kept as
+//! non-field errors.
+//!
+//! It converts to an RFC 9457 `application/problem+json` body with
+//! [`ValidationErrors::to_problem_json`], which `reinhardt-pages` forms read
+//! back into their field error signals.
 
 use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::fmt;
 
+use serde_json::{Value, json};
+
 use super::errors::ValidationError;
 
+/// Key under which non-field errors are reported, as in Django.
+pub const NON_FIELD_ERRORS: &str = "__all__";
+
+/// Content type of validation problem responses.
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
+
+/// Problem type URI of validation problem responses.
+pub const VALIDATION_PROBLEM_TYPE: &str = "urn:reinhardt:problem:validation";
+
 /// Aggregates validation errors by field name.
 ///
 /// Collects per-field [`ValidationError`]s and provides access
 /// to the accumulated errors for structured error responses.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_core::validators::{ValidationError, ValidationErrors};
+///
+/// let mut errors = ValidationErrors::new();
+/// errors.add("email", ValidationError::InvalidEmail("bad".to_string()));
+/// errors.add_non_field(ValidationError::coded("mismatch", "Passwords do not match."));
+///
+/// let problem = errors.to_problem_json();
+/// assert_eq!(problem["status"], 422);
+/// assert_eq!(problem["errors"]["email"][0]["code"], "invalid_email");
+/// assert_eq!(problem["non_field_errors"][0]["code"], "mismatch");
+/// ```
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ValidationErrors {
 	errors: BTreeMap<Cow<'static, str>, Vec<ValidationError>>,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+	non_field_errors: Vec<ValidationError>,
 }
 
 impl ValidationErrors {
@@ -21,12 +60,33 @@ impl ValidationErrors {
 	pub fn new() -> Self {
 		Self {
 			errors: BTreeMap::new(),
+			non_field_errors: Vec::new(),
 		}
 	}
 
 	/// Add a validation error for a specific field.
+	///
+	/// Errors added under [`NON_FIELD_ERRORS`] become non-field errors.
 	pub fn add(&mut self, field: impl Into<Cow<'static, str>>, error: ValidationError) {
-		self.errors.entry(field.into()).or_default().push(error);
+		let field = field.into();
+		if field == NON_FIELD_ERRORS {
+			self.non_field_errors.push(error);
+		} else {
+			self.errors.entry(field).or_default().push(error);
+		}
+	}
+
+	/// Add an error that concerns the object as a whole.
+	pub fn add_non_field(&mut self, error: ValidationError) {
+		self.non_field_errors.push(error);
+	}
+
+	/// Move every error of `other` into this collection.
+	pub fn merge(&mut self, other: ValidationErrors) {
+		for (field, errors) in other.errors {
+			self.errors.entry(field).or_default().extend(errors);
+		}
+		self.non_field_errors.extend(other.non_field_errors);
 	}
 
 	/// Get all field errors as a map.
@@ -34,10 +94,135 @@ impl ValidationErrors {
 		&self.errors
 	}
 
+	/// Errors of one field.
+	pub fn get(&self, field: &str) -> &[ValidationError] {
+		self.errors.get(field).map(Vec::as_slice).unwrap_or(&[])
+	}
+
+	/// Errors that concern no single field.
+	pub fn non_field_errors(&self) -> &[ValidationError] {
+		&self.non_field_errors
+	}
+
+	/// Total number of errors, field and non-field.
+	pub fn len(&self) -> usize {
+		self.errors.values().map(Vec::len).sum::<usize>() + self.non_field_errors.len()
+	}
+
 	/// Returns `true` if no errors have been added.
 	pub fn is_empty(&self) -> bool {
-		self.errors.is_empty()
+		self.errors.is_empty() && self.non_field_errors.is_empty()
+	}
+
+	/// Messages grouped by field, non-field errors under [`NON_FIELD_ERRORS`].
+	pub fn messages(&self) -> BTreeMap<String, Vec<String>> {
+		let mut messages: BTreeMap<String, Vec<String>> = self
+			.errors
+			.iter()
+			.map(|(field, errors)| {
+				(
+					field.to_string(),
+					errors.iter().map(ToString::to_string).collect(),
+				)
+			})
+			.collect();
+		if !self.non_field_errors.is_empty() {
+			messages.insert(
+				NON_FIELD_ERRORS.to_string(),
+				self.non_field_errors
+					.iter()
+					.map(ToString::to_string)
+					.collect(),
+			);
+		}
+		messages
+	}
+
+	/// Render as an RFC 9457 problem details object (status 422).
+	///
+	/// Every error is reported as `{"code", "message", "params"}`; field
+	/// errors under `errors`, the others under `non_field_errors`.
+	pub fn to_problem_json(&self) -> Value {
+		let errors: serde_json::Map<String, Value> = self
+			.errors
+			.iter()
+			.map(|(field, errors)| {
+				(
+					field.to_string(),
+					Value::Array(errors.iter().map(error_to_json).collect()),
+				)
+			})
+			.collect();
+		json!({
+			"type": VALIDATION_PROBLEM_TYPE,
+			"title": "Validation failed",
+			"status": 422,
+			"detail": format!("{} validation error(s)", self.len()),
+			"errors": errors,
+			"non_field_errors": self.non_field_errors.iter().map(error_to_json).collect::<Vec<_>>(),
+		})
+	}
+
+	/// Read a problem details object produced by
+	/// [`to_problem_json`](Self::to_problem_json).
+	///
+	/// Errors come back as [`ValidationError::Coded`]. Returns `None` when
+	/// `problem` is not a validation problem.
+	pub fn from_problem_json(problem: &Value) -> Option<Self> {
+		if problem.get("type").and_then(Value::as_str) != Some(VALIDATION_PROBLEM_TYPE) {
+			return None;
+		}
+
+		let mut errors = Self::new();
+		if let Some(fields) = problem.get("errors").and_then(Value::as_object) {
+			for (field, entries) in fields {
+				for entry in entries.as_array().into_iter().flatten() {
+					errors.add(field.clone(), error_from_json(entry));
+				}
+			}
+		}
+		for entry in problem
+			.get("non_field_errors")
+			.and_then(Value::as_array)
+			.into_iter()
+			.flatten()
+		{
+			errors.add_non_field(error_from_json(entry));
+		}
+		Some(errors)
+	}
+}
+
+fn error_to_json(error: &ValidationError) -> Value {
+	json!({
+		"code": error.code(),
+		"message": error.to_string(),
+		"params": error.params(),
+	})
+}
+
+fn error_from_json(entry: &Value) -> ValidationError {
+	let text = |key: &str| {
+		entry
+			.get(key)
+			.and_then(Value::as_str)
+			.unwrap_or_default()
+			.to_string()
+	};
+	let mut error = ValidationError::coded(text("code"), text("message"));
+	for (name, value) in entry
+		.get("params")
+		.and_then(Value::as_object)
+		.into_iter()
+		.flatten()
+	{
+		let value = match value {
+			Value::String(value) => value.clone(),
+			other => other.to_string(),
+		};
+		error = error.with_param(name.clone(), value);
 	}
+	error
 }
 
 impl Default for ValidationErrors {
@@ -46,6 +231,14 @@ impl Default for ValidationErrors {
 	}
 }
 
+impl From<(&'static str, ValidationError)> for ValidationErrors {
+	fn from((field, error): (&'static str, ValidationError)) -> Self {
+		let mut errors = Self::new();
+		errors.add(field, error);
+		errors
+	}
+}
+
 impl fmt::Display for ValidationErrors {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		let mut first = true;
@@ -58,6 +251,13 @@ impl fmt::Display for ValidationErrors {
 				first = false;
 			}
 		}
+		for error in &self.non_field_errors {
+			if !first {
+				write!(f, ", ")?;
+			}
+			write!(f, "{}", error)?;
+			first = false;
+		}
 		Ok(())
 	}
 }
@@ -115,6 +315,63 @@ mod tests {
 		assert!(display.contains("Invalid email"));
 	}
 
+	#[test]
+	fn test_non_field_errors() {
+		let mut errors = ValidationErrors::new();
+		errors.add_non_field(ValidationError::Custom("mismatch".to_string()));
+		errors.add(
+			NON_FIELD_ERRORS,
+			ValidationError::Custom("again".to_string()),
+		);
+		assert!(!errors.is_empty());
+		assert!(errors.field_errors().is_empty());
+		assert_eq!(errors.non_field_errors().len(), 2);
+		assert_eq!(errors.messages()[NON_FIELD_ERRORS].len(), 2);
+	}
+
+	#[test]
+	fn test_merge() {
+		let mut errors =
+			ValidationErrors::from(("name", ValidationError::TooShort { length: 0, min: 1 }));
+		let mut other = ValidationErrors::new();
+		other.add("name", ValidationError::Custom("taken".to_string()));
+		other.add_non_field(ValidationError::Custom("mismatch".to_string()));
+		errors.merge(other);
+		assert_eq!(errors.get("name").len(), 2);
+		assert_eq!(errors.len(), 3);
+	}
+
+	#[test]
+	fn test_problem_json_round_trip() {
+		let mut errors = ValidationErrors::new();
+		errors.add(
+			"name",
+			ValidationError::TooLong {
+				length: 200,
+				max: 100,
+			},
+		);
+		errors.add_non_field(ValidationError::coded(
+			"mismatch",
+			"Passwords do not match.",
+		));
+
+		let problem = errors.to_problem_json();
+		assert_eq!(problem["title"], "Validation failed");
+		assert_eq!(problem["errors"]["name"][0]["code"], "max_length");
+		assert_eq!(problem["errors"]["name"][0]["params"]["max"], "100");
+		assert_eq!(problem["detail"], "2 validation error(s)");
+
+		let parsed = ValidationErrors::from_problem_json(&problem).unwrap();
+		assert_eq!(parsed.get("name")[0].code(), "max_length");
+		assert_eq!(parsed.get("name")[0].params()["length"], "200");
+		assert_eq!(
+			parsed.non_field_errors()[0].to_string(),
+			"Passwords do not match."
+		);
+		assert!(ValidationErrors::from_problem_json(&json!({"type": "about:blank"})).is_none());
+	}
+
 	#[test]
 	fn test_default_is_empty() {
 		let errors = ValidationErrors::default();
@@ -166,7 +166,8 @@ pub use types::{
 // Re-export optimization features
 pub use optimization::{
 	BatchInsertBuilder, BatchOperations, BatchUpdateBuilder, CachedQuery, OptimizedPoolBuilder,
-	PoolOptimizationConfig, QueryCache, QueryCacheConfig,
+	PoolOptimizationConfig, QueryCache, QueryCacheConfig, StatementCacheConfig,
+	StatementCacheStats, StatementCacheTracker, statement_cache_stats,
 };
 
 // Re-export database-specific schema editors
@@ -215,7 +216,7 @@ pub mod sql_build_helpers {
 		prelude::{
 			AlterTableStatement, CreateIndexStatement, CreateTableStatement, DropIndexStatement,
 			DropTableStatement, MySqlQueryBuilder, PostgresQueryBuilder, QueryBuilder,
-			SqliteQueryBuilder,
+			QueryStatementBuilder, SqliteQueryBuilder,
 		},
 		query::{DeleteStatement, InsertStatement, SelectStatement, UpdateStatement},
 		value::Values,
@@ -355,4 +356,30 @@ pub mod sql_build_helpers {
 	pub fn build_drop_index_sql(db_type: DatabaseType, stmt: &DropIndexStatement) -> String {
 		build_drop_index(db_type, stmt).0
 	}
+
+	/// Build SQL with values inlined for any statement and the given database type
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_db::backends::sql_build_helpers::build_inline_sql;
+	/// use reinhardt_db::backends::types::DatabaseType;
+	/// use reinhardt_query::prelude::{Alias, Expr, ExprTrait, Query};
+	///
+	/// let stmt = Query::delete()
+	///     .from_table(Alias::new("sites"))
+	///     .and_where(Expr::col(Alias::new("id")).eq(1))
+	///     .to_owned();
+	/// assert_eq!(
+	///     build_inline_sql(DatabaseType::Sqlite, &stmt),
+	///     r#"DELETE FROM "sites" WHERE "id" = 1"#
+	/// );
+	/// ```
+	pub fn build_inline_sql(db_type: DatabaseType, stmt: &impl QueryStatementBuilder) -> String {
+		match db_type {
+			DatabaseType::Postgres => stmt.to_string(PostgresQueryBuilder),
+			DatabaseType::Mysql => stmt.to_string(MySqlQueryBuilder),
+			DatabaseType::Sqlite => stmt.to_string(SqliteQueryBuilder),
+		}
+	}
 }
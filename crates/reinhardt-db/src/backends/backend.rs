@@ -4,6 +4,7 @@ use async_trait::async_trait;
 
 use super::{
 	error::Result,
+	optimization::StatementCacheStats,
 	types::{DatabaseType, IsolationLevel, QueryResult, QueryValue, Row, TransactionExecutor},
 };
 
@@ -87,6 +88,13 @@ pub trait DatabaseBackend: Send + Sync {
 		self.begin().await
 	}
 
+	/// Returns the prepared statement cache hit/miss counters
+	///
+	/// `None` for backends that do not track their statement cache.
+	fn statement_cache_stats(&self) -> Option<StatementCacheStats> {
+		None
+	}
+
 	/// Returns self as &dyn std::any::Any for downcasting
 	fn as_any(&self) -> &dyn std::any::Any;
 }
@@ -5,6 +5,7 @@ use std::sync::Arc;
 use super::{
 	backend::DatabaseBackend,
 	error::Result,
+	optimization::StatementCacheStats,
 	query_builder::{DeleteBuilder, InsertBuilder, SelectBuilder, UpdateBuilder},
 };
 
@@ -219,10 +220,24 @@ impl DatabaseConnection {
 			.acquire_timeout(Duration::from_secs(10)) // Increased from 3s to 10s for busy pools
 			.idle_timeout(Some(Duration::from_secs(10))) // Close idle connections after 10s
 			.max_lifetime(Some(Duration::from_secs(30 * 60))) // Close connections after 30 minutes
-			.connect(url)
+			.connect_with(Self::postgres_connect_options(url)?)
 			.await
 	}
 
+	/// Parse connect options for `url`, sizing the prepared statement cache
+	/// from `DATABASE_STATEMENT_CACHE_CAPACITY` (0 disables it for PgBouncer
+	/// transaction pooling).
+	#[cfg(feature = "postgres")]
+	fn postgres_connect_options(
+		url: &str,
+	) -> std::result::Result<sqlx::postgres::PgConnectOptions, sqlx::Error> {
+		use sqlx::postgres::PgConnectOptions;
+		use std::str::FromStr;
+
+		let capacity = super::optimization::StatementCacheConfig::from_env().capacity;
+		Ok(PgConnectOptions::from_str(url)?.statement_cache_capacity(capacity))
+	}
+
 	/// Connect to PostgreSQL with automatic database creation and custom pool size.
 	///
 	/// See [`Self::connect_postgres_or_create`] for details on automatic database creation.
@@ -348,9 +363,14 @@ impl DatabaseConnection {
 		use std::path::Path;
 		use std::str::FromStr;
 
+		let statement_cache_capacity =
+			super::optimization::StatementCacheConfig::from_env().capacity;
+
 		// Handle in-memory database
 		if url == "sqlite::memory:" {
-			let pool = SqlitePool::connect(url).await?;
+			let options = SqliteConnectOptions::from_str(url)?
+				.statement_cache_capacity(statement_cache_capacity);
+			let pool = SqlitePool::connect_with(options).await?;
 			return Ok(Self {
 				backend: Arc::new(SqliteBackend::new(pool)),
 				is_cockroachdb: false,
@@ -448,7 +468,8 @@ impl DatabaseConnection {
 					absolute_url, e
 				))
 			})?
-			.create_if_missing(true);
+			.create_if_missing(true)
+			.statement_cache_capacity(statement_cache_capacity);
 
 		let pool = SqlitePool::connect_with(options).await?;
 
@@ -471,7 +492,13 @@ impl DatabaseConnection {
 	#[cfg(feature = "mysql")]
 	pub async fn connect_mysql(url: &str) -> Result<Self> {
 		use sqlx::MySqlPool;
-		let pool = MySqlPool::connect(url).await?;
+		use sqlx::mysql::MySqlConnectOptions;
+		use std::str::FromStr;
+
+		let options = MySqlConnectOptions::from_str(url)?.statement_cache_capacity(
+			super::optimization::StatementCacheConfig::from_env().capacity,
+		);
+		let pool = MySqlPool::connect_with(options).await?;
 		Ok(Self {
 			backend: Arc::new(MySqlBackend::new(pool)),
 			is_cockroachdb: false,
@@ -488,6 +515,13 @@ impl DatabaseConnection {
 		self.backend.database_type()
 	}
 
+	/// Prepared statement cache hit/miss counters of this connection's pool
+	///
+	/// `None` when the backend does not track its statement cache.
+	pub fn statement_cache_stats(&self) -> Option<StatementCacheStats> {
+		self.backend.statement_cache_stats()
+	}
+
 	/// Returns true when the underlying server is CockroachDB.
 	///
 	/// CockroachDB is wire-compatible with PostgreSQL and uses the same
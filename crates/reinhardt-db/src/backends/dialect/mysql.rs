@@ -7,6 +7,7 @@ use std::sync::Arc;
 use crate::backends::{
 	backend::DatabaseBackend,
 	error::Result,
+	optimization::{StatementCacheConfig, StatementCacheStats, StatementCacheTracker},
 	types::{
 		DatabaseType, IsolationLevel, QueryResult, QueryValue, Row, Savepoint, TransactionExecutor,
 	},
@@ -15,13 +16,24 @@ use crate::backends::{
 /// MySQL database backend
 pub struct MySqlBackend {
 	pool: Arc<MySqlPool>,
+	statement_cache: StatementCacheTracker,
 }
 
 impl MySqlBackend {
 	/// Creates a new MySQL backend with the given pool.
 	pub fn new(pool: MySqlPool) -> Self {
+		Self::with_statement_cache(pool, StatementCacheConfig::from_env())
+	}
+
+	/// Creates a backend whose pool caches prepared statements per `config`.
+	///
+	/// `config` must match the `statement_cache_capacity` the pool's
+	/// connections were opened with; a disabled config also stops queries
+	/// from being prepared as named statements.
+	pub fn with_statement_cache(pool: MySqlPool, config: StatementCacheConfig) -> Self {
 		Self {
 			pool: Arc::new(pool),
+			statement_cache: StatementCacheTracker::new(config),
 		}
 	}
 
@@ -113,7 +125,8 @@ impl DatabaseBackend for MySqlBackend {
 	}
 
 	async fn execute(&self, sql: &str, params: Vec<QueryValue>) -> Result<QueryResult> {
-		let mut query = sqlx::query(sql);
+		self.statement_cache.record(sql);
+		let mut query = sqlx::query(sql).persistent(self.statement_cache.persistent());
 		for param in &params {
 			query = Self::bind_value(query, param);
 		}
@@ -124,7 +137,8 @@ impl DatabaseBackend for MySqlBackend {
 	}
 
 	async fn fetch_one(&self, sql: &str, params: Vec<QueryValue>) -> Result<Row> {
-		let mut query = sqlx::query(sql);
+		self.statement_cache.record(sql);
+		let mut query = sqlx::query(sql).persistent(self.statement_cache.persistent());
 		for param in &params {
 			query = Self::bind_value(query, param);
 		}
@@ -133,7 +147,8 @@ impl DatabaseBackend for MySqlBackend {
 	}
 
 	async fn fetch_all(&self, sql: &str, params: Vec<QueryValue>) -> Result<Vec<Row>> {
-		let mut query = sqlx::query(sql);
+		self.statement_cache.record(sql);
+		let mut query = sqlx::query(sql).persistent(self.statement_cache.persistent());
 		for param in &params {
 			query = Self::bind_value(query, param);
 		}
@@ -142,7 +157,8 @@ impl DatabaseBackend for MySqlBackend {
 	}
 
 	async fn fetch_optional(&self, sql: &str, params: Vec<QueryValue>) -> Result<Option<Row>> {
-		let mut query = sqlx::query(sql);
+		self.statement_cache.record(sql);
+		let mut query = sqlx::query(sql).persistent(self.statement_cache.persistent());
 		for param in &params {
 			query = Self::bind_value(query, param);
 		}
@@ -178,6 +194,10 @@ impl DatabaseBackend for MySqlBackend {
 		Ok(Box::new(MySqlRawTransactionExecutor::new(conn)))
 	}
 
+	fn statement_cache_stats(&self) -> Option<StatementCacheStats> {
+		Some(self.statement_cache.stats())
+	}
+
 	fn as_any(&self) -> &dyn std::any::Any {
 		self
 	}
@@ -8,6 +8,7 @@ use uuid::Uuid;
 use crate::backends::{
 	backend::DatabaseBackend,
 	error::{DatabaseError, Result},
+	optimization::{StatementCacheConfig, StatementCacheStats, StatementCacheTracker},
 	types::{
 		DatabaseType, IsolationLevel, QueryResult, QueryValue, Row, Savepoint, TransactionExecutor,
 	},
@@ -16,13 +17,24 @@ use crate::backends::{
 /// PostgreSQL database backend
 pub struct PostgresBackend {
 	pool: Arc<PgPool>,
+	statement_cache: StatementCacheTracker,
 }
 
 impl PostgresBackend {
 	/// Creates a new instance.
 	pub fn new(pool: PgPool) -> Self {
+		Self::with_statement_cache(pool, StatementCacheConfig::from_env())
+	}
+
+	/// Creates a backend whose pool caches prepared statements per `config`.
+	///
+	/// `config` must match the `statement_cache_capacity` the pool's
+	/// connections were opened with; a disabled config also stops queries
+	/// from being prepared as named statements.
+	pub fn with_statement_cache(pool: PgPool, config: StatementCacheConfig) -> Self {
 		Self {
 			pool: Arc::new(pool),
+			statement_cache: StatementCacheTracker::new(config),
 		}
 	}
 
@@ -76,7 +88,8 @@ impl DatabaseBackend for PostgresBackend {
 	}
 
 	async fn execute(&self, sql: &str, params: Vec<QueryValue>) -> Result<QueryResult> {
-		let mut query = sqlx::query(sql);
+		self.statement_cache.record(sql);
+		let mut query = sqlx::query(sql).persistent(self.statement_cache.persistent());
 		for param in &params {
 			query = Self::bind_value(query, param);
 		}
@@ -87,7 +100,8 @@ impl DatabaseBackend for PostgresBackend {
 	}
 
 	async fn fetch_one(&self, sql: &str, params: Vec<QueryValue>) -> Result<Row> {
-		let mut query = sqlx::query(sql);
+		self.statement_cache.record(sql);
+		let mut query = sqlx::query(sql).persistent(self.statement_cache.persistent());
 		for param in &params {
 			query = Self::bind_value(query, param);
 		}
@@ -96,7 +110,8 @@ impl DatabaseBackend for PostgresBackend {
 	}
 
 	async fn fetch_all(&self, sql: &str, params: Vec<QueryValue>) -> Result<Vec<Row>> {
-		let mut query = sqlx::query(sql);
+		self.statement_cache.record(sql);
+		let mut query = sqlx::query(sql).persistent(self.statement_cache.persistent());
 		for param in &params {
 			query = Self::bind_value(query, param);
 		}
@@ -105,7 +120,8 @@ impl DatabaseBackend for PostgresBackend {
 	}
 
 	async fn fetch_optional(&self, sql: &str, params: Vec<QueryValue>) -> Result<Option<Row>> {
-		let mut query = sqlx::query(sql);
+		self.statement_cache.record(sql);
+		let mut query = sqlx::query(sql).persistent(self.statement_cache.persistent());
 		for param in &params {
 			query = Self::bind_value(query, param);
 		}
@@ -135,6 +151,10 @@ impl DatabaseBackend for PostgresBackend {
 		Ok(Box::new(PgTransactionExecutor::new(tx)))
 	}
 
+	fn statement_cache_stats(&self) -> Option<StatementCacheStats> {
+		Some(self.statement_cache.stats())
+	}
+
 	fn as_any(&self) -> &dyn std::any::Any {
 		self
 	}
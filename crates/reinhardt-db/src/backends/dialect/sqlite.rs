@@ -8,6 +8,7 @@ use tracing::warn;
 use crate::backends::{
 	backend::DatabaseBackend,
 	error::Result,
+	optimization::{StatementCacheConfig, StatementCacheStats, StatementCacheTracker},
 	types::{
 		DatabaseType, IsolationLevel, QueryResult, QueryValue, Row, Savepoint, TransactionExecutor,
 	},
@@ -16,13 +17,24 @@ use crate::backends::{
 /// SQLite database backend
 pub struct SqliteBackend {
 	pool: Arc<SqlitePool>,
+	statement_cache: StatementCacheTracker,
 }
 
 impl SqliteBackend {
 	/// Creates a new SQLite backend with the given pool.
 	pub fn new(pool: SqlitePool) -> Self {
+		Self::with_statement_cache(pool, StatementCacheConfig::from_env())
+	}
+
+	/// Creates a backend whose pool caches prepared statements per `config`.
+	///
+	/// `config` must match the `statement_cache_capacity` the pool's
+	/// connections were opened with; a disabled config also stops queries
+	/// from being prepared as named statements.
+	pub fn with_statement_cache(pool: SqlitePool, config: StatementCacheConfig) -> Self {
 		Self {
 			pool: Arc::new(pool),
+			statement_cache: StatementCacheTracker::new(config),
 		}
 	}
 
@@ -155,7 +167,8 @@ impl DatabaseBackend for SqliteBackend {
 	}
 
 	async fn execute(&self, sql: &str, params: Vec<QueryValue>) -> Result<QueryResult> {
-		let mut query = sqlx::query(sql);
+		self.statement_cache.record(sql);
+		let mut query = sqlx::query(sql).persistent(self.statement_cache.persistent());
 		for param in &params {
 			query = Self::bind_value(query, param);
 		}
@@ -166,7 +179,8 @@ impl DatabaseBackend for SqliteBackend {
 	}
 
 	async fn fetch_one(&self, sql: &str, params: Vec<QueryValue>) -> Result<Row> {
-		let mut query = sqlx::query(sql);
+		self.statement_cache.record(sql);
+		let mut query = sqlx::query(sql).persistent(self.statement_cache.persistent());
 		for param in &params {
 			query = Self::bind_value(query, param);
 		}
@@ -175,7 +189,8 @@ impl DatabaseBackend for SqliteBackend {
 	}
 
 	async fn fetch_all(&self, sql: &str, params: Vec<QueryValue>) -> Result<Vec<Row>> {
-		let mut query = sqlx::query(sql);
+		self.statement_cache.record(sql);
+		let mut query = sqlx::query(sql).persistent(self.statement_cache.persistent());
 		for param in &params {
 			query = Self::bind_value(query, param);
 		}
@@ -184,7 +199,8 @@ impl DatabaseBackend for SqliteBackend {
 	}
 
 	async fn fetch_optional(&self, sql: &str, params: Vec<QueryValue>) -> Result<Option<Row>> {
-		let mut query = sqlx::query(sql);
+		self.statement_cache.record(sql);
+		let mut query = sqlx::query(sql).persistent(self.statement_cache.persistent());
 		for param in &params {
 			query = Self::bind_value(query, param);
 		}
@@ -254,6 +270,10 @@ impl DatabaseBackend for SqliteBackend {
 		Ok(Box::new(SqliteTransactionExecutor::new(tx)))
 	}
 
+	fn statement_cache_stats(&self) -> Option<StatementCacheStats> {
+		Some(self.statement_cache.stats())
+	}
+
 	fn as_any(&self) -> &dyn std::any::Any {
 		self
 	}
@@ -54,6 +54,146 @@ pub enum DatabaseError {
 	/// Generic database error
 	#[error("Database error: {0}")]
 	Other(String),
+
+	/// Unique constraint violation
+	#[error("Unique constraint violation{}", describe_constraint(.constraint, .columns))]
+	UniqueViolation {
+		/// Name of the violated constraint, when the database reports it.
+		constraint: Option<String>,
+		/// Constrained columns, when the database reports them.
+		columns: Vec<String>,
+	},
+
+	/// Foreign key constraint violation
+	#[error("Foreign key constraint violation{}", describe_constraint(.constraint, .columns))]
+	ForeignKeyViolation {
+		/// Name of the violated constraint, when the database reports it.
+		constraint: Option<String>,
+		/// Referencing columns, when the database reports them.
+		columns: Vec<String>,
+	},
+}
+
+impl DatabaseError {
+	/// Recognize a constraint violation in a backend error message
+	///
+	/// Understands the unique and foreign key violation messages of
+	/// PostgreSQL (including the `Key (...)=(...)` detail), SQLite and MySQL.
+	/// Returns `None` for any other message.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_db::backends::DatabaseError;
+	///
+	/// let error = DatabaseError::constraint_violation(
+	///     "UNIQUE constraint failed: users.email",
+	/// ).unwrap();
+	/// assert!(error.is_unique_violation());
+	/// assert_eq!(error.columns(), ["email"]);
+	/// ```
+	pub fn constraint_violation(message: &str) -> Option<Self> {
+		if let Some(rest) = after(message, "UNIQUE constraint failed:") {
+			let columns = rest
+				.split(',')
+				.map(|column| {
+					let column = column.trim();
+					column.rsplit('.').next().unwrap_or(column).to_string()
+				})
+				.filter(|column| !column.is_empty())
+				.collect();
+			return Some(Self::UniqueViolation {
+				constraint: None,
+				columns,
+			});
+		}
+		if message.contains("FOREIGN KEY constraint failed") {
+			return Some(Self::ForeignKeyViolation {
+				constraint: None,
+				columns: Vec::new(),
+			});
+		}
+		if let Some(rest) = after(message, "violates unique constraint") {
+			return Some(Self::UniqueViolation {
+				constraint: quoted(rest, '"').map(str::to_string),
+				columns: key_columns(message),
+			});
+		}
+		if let Some(rest) = after(message, "violates foreign key constraint") {
+			return Some(Self::ForeignKeyViolation {
+				constraint: quoted(rest, '"').map(str::to_string),
+				columns: key_columns(message),
+			});
+		}
+		if let Some(rest) = after(message, "Duplicate entry") {
+			let constraint = after(rest, "for key")
+				.and_then(|key| quoted(key, '\''))
+				.map(|key| key.rsplit('.').next().unwrap_or(key).to_string());
+			return Some(Self::UniqueViolation {
+				constraint,
+				columns: Vec::new(),
+			});
+		}
+		if let Some(rest) = after(message, "a foreign key constraint fails") {
+			let columns = after(rest, "FOREIGN KEY (")
+				.and_then(|columns| columns.split_once(')'))
+				.map(|(columns, _)| {
+					columns
+						.split(',')
+						.map(|column| column.trim().trim_matches('`').to_string())
+						.collect()
+				})
+				.unwrap_or_default();
+			return Some(Self::ForeignKeyViolation {
+				constraint: after(rest, "CONSTRAINT")
+					.and_then(|name| quoted(name, '`'))
+					.map(str::to_string),
+				columns,
+			});
+		}
+		None
+	}
+
+	/// Whether this is a unique constraint violation
+	pub fn is_unique_violation(&self) -> bool {
+		matches!(self, Self::UniqueViolation { .. })
+	}
+
+	/// Whether this is a foreign key constraint violation
+	pub fn is_foreign_key_violation(&self) -> bool {
+		matches!(self, Self::ForeignKeyViolation { .. })
+	}
+
+	/// Name of the violated constraint, for constraint violations
+	pub fn constraint(&self) -> Option<&str> {
+		match self {
+			Self::UniqueViolation { constraint, .. }
+			| Self::ForeignKeyViolation { constraint, .. } => constraint.as_deref(),
+			_ => None,
+		}
+	}
+
+	/// Columns of the violated constraint, for constraint violations
+	pub fn columns(&self) -> &[String] {
+		match self {
+			Self::UniqueViolation { columns, .. } | Self::ForeignKeyViolation { columns, .. } => {
+				columns
+			}
+			_ => &[],
+		}
+	}
+}
+
+/// Unique violations map to 409 Conflict and foreign key violations to
+/// 400 Bad Request, so views can return them as-is.
+impl From<DatabaseError> for reinhardt_core::exception::Error {
+	fn from(err: DatabaseError) -> Self {
+		match err {
+			DatabaseError::UniqueViolation { .. } => Self::Conflict(err.to_string()),
+			DatabaseError::ForeignKeyViolation { .. } => Self::Validation(err.to_string()),
+			_ => Self::Database(err.to_string()),
+		}
+	}
 }
 
 /// Result type for database operations
@@ -70,7 +210,8 @@ impl From<sqlx::Error> for DatabaseError {
 		use sqlx::Error::*;
 		match err {
 			Configuration(msg) => DatabaseError::ConfigError(msg.to_string()),
-			Database(e) => DatabaseError::QueryError(e.to_string()),
+			Database(e) => constraint_violation_from_sqlx(e.as_ref())
+				.unwrap_or_else(|| DatabaseError::QueryError(e.to_string())),
 			Io(e) => DatabaseError::ConnectionError(e.to_string()),
 			Tls(e) => DatabaseError::ConnectionError(e.to_string()),
 			Protocol(msg) => DatabaseError::QueryError(msg),
@@ -97,3 +238,185 @@ impl From<sqlx::Error> for DatabaseError {
 		}
 	}
 }
+
+/// Typed constraint violation for a sqlx database error
+///
+/// The constraint name reported by the driver wins over the one parsed from
+/// the message.
+fn constraint_violation_from_sqlx(error: &dyn sqlx::error::DatabaseError) -> Option<DatabaseError> {
+	let kind = error.kind();
+	if !matches!(
+		kind,
+		sqlx::error::ErrorKind::UniqueViolation | sqlx::error::ErrorKind::ForeignKeyViolation
+	) {
+		return None;
+	}
+
+	let message = match postgres_detail(error) {
+		Some(detail) => format!("{} {}", error.message(), detail),
+		None => error.message().to_string(),
+	};
+	let parsed = DatabaseError::constraint_violation(&message);
+	let constraint = error.constraint().map(str::to_string).or_else(|| {
+		parsed
+			.as_ref()
+			.and_then(|e| e.constraint().map(str::to_string))
+	});
+	let columns = parsed.map(|e| e.columns().to_vec()).unwrap_or_default();
+
+	Some(match kind {
+		sqlx::error::ErrorKind::UniqueViolation => DatabaseError::UniqueViolation {
+			constraint,
+			columns,
+		},
+		_ => DatabaseError::ForeignKeyViolation {
+			constraint,
+			columns,
+		},
+	})
+}
+
+/// `DETAIL` of a PostgreSQL error, which names the offending columns
+#[cfg(feature = "postgres")]
+fn postgres_detail(error: &dyn sqlx::error::DatabaseError) -> Option<String> {
+	error
+		.try_downcast_ref::<sqlx::postgres::PgDatabaseError>()?
+		.detail()
+		.map(str::to_string)
+}
+
+#[cfg(not(feature = "postgres"))]
+fn postgres_detail(_error: &dyn sqlx::error::DatabaseError) -> Option<String> {
+	None
+}
+
+/// Columns of a PostgreSQL `Key (a, b)=(...)` detail
+fn key_columns(message: &str) -> Vec<String> {
+	after(message, "Key (")
+		.and_then(|detail| detail.split_once(")=("))
+		.map(|(columns, _)| columns.split(',').map(|c| c.trim().to_string()).collect())
+		.unwrap_or_default()
+}
+
+fn describe_constraint(constraint: &Option<String>, columns: &[String]) -> String {
+	match (constraint, columns.is_empty()) {
+		(Some(name), true) => format!(" on {}", name),
+		(Some(name), false) => format!(" on {} ({})", name, columns.join(", ")),
+		(None, false) => format!(" on ({})", columns.join(", ")),
+		(None, true) => String::new(),
+	}
+}
+
+/// Text after the first occurrence of `marker`
+fn after<'a>(text: &'a str, marker: &str) -> Option<&'a str> {
+	text.find(marker).map(|index| &text[index + marker.len()..])
+}
+
+/// First text enclosed in `quote`
+fn quoted(text: &str, quote: char) -> Option<&str> {
+	let start = text.find(quote)? + 1;
+	let len = text[start..].find(quote)?;
+	Some(&text[start..start + len])
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case::sqlite_unique(
+		"UNIQUE constraint failed: memberships.user_id, memberships.group_id",
+		DatabaseError::UniqueViolation {
+			constraint: None,
+			columns: vec!["user_id".to_string(), "group_id".to_string()],
+		}
+	)]
+	#[case::sqlite_foreign_key(
+		"FOREIGN KEY constraint failed",
+		DatabaseError::ForeignKeyViolation { constraint: None, columns: vec![] }
+	)]
+	#[case::postgres_unique(
+		"duplicate key value violates unique constraint \"users_email_key\" \
+		 Key (email)=(a@example.com) already exists.",
+		DatabaseError::UniqueViolation {
+			constraint: Some("users_email_key".to_string()),
+			columns: vec!["email".to_string()],
+		}
+	)]
+	#[case::postgres_foreign_key(
+		"insert or update on table \"posts\" violates foreign key constraint \"posts_author_id_fkey\" \
+		 Key (author_id)=(5) is not present in table \"users\".",
+		DatabaseError::ForeignKeyViolation {
+			constraint: Some("posts_author_id_fkey".to_string()),
+			columns: vec!["author_id".to_string()],
+		}
+	)]
+	#[case::mysql_unique(
+		"Duplicate entry 'a@example.com' for key 'users.email_unique'",
+		DatabaseError::UniqueViolation {
+			constraint: Some("email_unique".to_string()),
+			columns: vec![],
+		}
+	)]
+	#[case::mysql_foreign_key(
+		"Cannot add or update a child row: a foreign key constraint fails \
+		 (`app`.`posts`, CONSTRAINT `fk_author` FOREIGN KEY (`author_id`) REFERENCES `users` (`id`))",
+		DatabaseError::ForeignKeyViolation {
+			constraint: Some("fk_author".to_string()),
+			columns: vec!["author_id".to_string()],
+		}
+	)]
+	fn test_constraint_violation(#[case] message: &str, #[case] expected: DatabaseError) {
+		// Act
+		let error = DatabaseError::constraint_violation(message);
+
+		// Assert
+		assert_eq!(error, Some(expected));
+	}
+
+	#[rstest]
+	fn test_constraint_violation_ignores_other_messages() {
+		// Act / Assert
+		assert_eq!(
+			DatabaseError::constraint_violation("no such table: users"),
+			None
+		);
+	}
+
+	#[rstest]
+	#[case::unique(
+		DatabaseError::UniqueViolation {
+			constraint: Some("users_email_key".to_string()),
+			columns: vec!["email".to_string()],
+		},
+		409
+	)]
+	#[case::foreign_key(
+		DatabaseError::ForeignKeyViolation { constraint: None, columns: vec![] },
+		400
+	)]
+	#[case::other(DatabaseError::QueryError("syntax".to_string()), 500)]
+	fn test_exception_status(#[case] error: DatabaseError, #[case] expected: u16) {
+		// Act
+		let error: reinhardt_core::exception::Error = error.into();
+
+		// Assert
+		assert_eq!(error.status_code(), expected);
+	}
+
+	#[rstest]
+	fn test_display_names_constraint_and_columns() {
+		// Arrange
+		let error = DatabaseError::UniqueViolation {
+			constraint: Some("users_email_key".to_string()),
+			columns: vec!["email".to_string()],
+		};
+
+		// Act / Assert
+		assert_eq!(
+			error.to_string(),
+			"Unique constraint violation on users_email_key (email)"
+		);
+	}
+}
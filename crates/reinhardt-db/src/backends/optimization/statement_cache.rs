@@ -0,0 +1,262 @@
+//! Prepared statement caching
+//!
+//! Every pooled connection keeps an LRU cache of prepared statements keyed by
+//! SQL text, so statements generated over and over by QuerySets are parsed
+//! and planned once per connection instead of once per request.
+//!
+//! [`StatementCacheConfig`] sizes that cache. A capacity of 0 disables it and
+//! runs every query as an unnamed statement, which is required behind
+//! PgBouncer in transaction pooling mode: named prepared statements do not
+//! survive the server connection being handed to another client.
+//!
+//! [`StatementCacheTracker`] counts cache hits and misses. The counts are also
+//! summed process-wide and exposed through [`statement_cache_stats`] and the
+//! ORM [`Statistics`](crate::orm::instrumentation::Statistics).
+
+use indexmap::IndexSet;
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Environment variable overriding the statement cache capacity
+///
+/// Set it to `0` when connecting through PgBouncer in transaction pooling
+/// mode.
+pub const STATEMENT_CACHE_CAPACITY_ENV: &str = "DATABASE_STATEMENT_CACHE_CAPACITY";
+
+/// Default number of prepared statements cached per connection
+pub const DEFAULT_STATEMENT_CACHE_CAPACITY: usize = 100;
+
+static TOTAL_HITS: AtomicU64 = AtomicU64::new(0);
+static TOTAL_MISSES: AtomicU64 = AtomicU64::new(0);
+
+/// Prepared statement cache configuration
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_db::backends::optimization::StatementCacheConfig;
+///
+/// let config = StatementCacheConfig::with_capacity(250);
+/// assert!(config.is_enabled());
+///
+/// // Behind PgBouncer in transaction pooling mode
+/// let config = StatementCacheConfig::disabled();
+/// assert!(!config.is_enabled());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatementCacheConfig {
+	/// Maximum number of prepared statements cached per connection
+	///
+	/// `0` disables prepared statement reuse.
+	pub capacity: usize,
+}
+
+impl StatementCacheConfig {
+	/// Cache up to `capacity` statements per connection
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self { capacity }
+	}
+
+	/// Disable prepared statement reuse
+	pub fn disabled() -> Self {
+		Self::with_capacity(0)
+	}
+
+	/// Read the capacity from `DATABASE_STATEMENT_CACHE_CAPACITY`
+	///
+	/// Falls back to the default when the variable is unset or invalid.
+	pub fn from_env() -> Self {
+		std::env::var(STATEMENT_CACHE_CAPACITY_ENV)
+			.ok()
+			.and_then(|v| v.trim().parse::<usize>().ok())
+			.map(Self::with_capacity)
+			.unwrap_or_default()
+	}
+
+	/// Whether prepared statements are reused
+	pub fn is_enabled(&self) -> bool {
+		self.capacity > 0
+	}
+}
+
+impl Default for StatementCacheConfig {
+	fn default() -> Self {
+		Self::with_capacity(DEFAULT_STATEMENT_CACHE_CAPACITY)
+	}
+}
+
+/// Prepared statement cache hit/miss counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatementCacheStats {
+	/// Statements found in the cache
+	pub hits: u64,
+	/// Statements that had to be parsed and prepared
+	pub misses: u64,
+}
+
+impl StatementCacheStats {
+	/// Fraction of statements served from the cache, 0.0 when none ran yet
+	pub fn hit_ratio(&self) -> f64 {
+		let total = self.hits + self.misses;
+		if total == 0 {
+			0.0
+		} else {
+			self.hits as f64 / total as f64
+		}
+	}
+}
+
+/// Hit/miss tracker for a backend's prepared statement cache
+///
+/// Mirrors the per-connection LRU with a pool-wide LRU of the same capacity:
+/// a statement counts as a hit when it ran recently enough to still be
+/// cached, which is what each connection's cache sees once the pool is warm.
+/// When the cache is disabled every statement counts as a miss.
+#[derive(Debug)]
+pub struct StatementCacheTracker {
+	config: StatementCacheConfig,
+	recent: Mutex<IndexSet<String>>,
+	hits: AtomicU64,
+	misses: AtomicU64,
+}
+
+impl StatementCacheTracker {
+	/// Create a tracker for a cache configured with `config`
+	pub fn new(config: StatementCacheConfig) -> Self {
+		Self {
+			config,
+			recent: Mutex::new(IndexSet::with_capacity(config.capacity)),
+			hits: AtomicU64::new(0),
+			misses: AtomicU64::new(0),
+		}
+	}
+
+	/// The cache configuration
+	pub fn config(&self) -> StatementCacheConfig {
+		self.config
+	}
+
+	/// Whether queries should be run as persistent (cached) statements
+	pub fn persistent(&self) -> bool {
+		self.config.is_enabled()
+	}
+
+	/// Record a statement about to run, returning whether it was cached
+	pub fn record(&self, sql: &str) -> bool {
+		let hit = self.config.is_enabled() && self.touch(sql);
+		let (counter, total) = if hit {
+			(&self.hits, &TOTAL_HITS)
+		} else {
+			(&self.misses, &TOTAL_MISSES)
+		};
+		counter.fetch_add(1, Ordering::Relaxed);
+		total.fetch_add(1, Ordering::Relaxed);
+		hit
+	}
+
+	/// Move `sql` to the most recently used position, evicting the least
+	/// recently used statement when full
+	fn touch(&self, sql: &str) -> bool {
+		let mut recent = self.recent.lock();
+		if let Some(index) = recent.get_index_of(sql) {
+			let last = recent.len() - 1;
+			recent.move_index(index, last);
+			return true;
+		}
+		if recent.len() >= self.config.capacity {
+			recent.shift_remove_index(0);
+		}
+		recent.insert(sql.to_string());
+		false
+	}
+
+	/// Hits and misses recorded by this tracker
+	pub fn stats(&self) -> StatementCacheStats {
+		StatementCacheStats {
+			hits: self.hits.load(Ordering::Relaxed),
+			misses: self.misses.load(Ordering::Relaxed),
+		}
+	}
+}
+
+impl Default for StatementCacheTracker {
+	fn default() -> Self {
+		Self::new(StatementCacheConfig::default())
+	}
+}
+
+/// Hits and misses recorded by all backends of this process
+pub fn statement_cache_stats() -> StatementCacheStats {
+	StatementCacheStats {
+		hits: TOTAL_HITS.load(Ordering::Relaxed),
+		misses: TOTAL_MISSES.load(Ordering::Relaxed),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_tracker_counts_hits_and_misses() {
+		// Arrange
+		let tracker = StatementCacheTracker::new(StatementCacheConfig::with_capacity(2));
+
+		// Act
+		let first = tracker.record("SELECT * FROM users WHERE id = $1");
+		let second = tracker.record("SELECT * FROM users WHERE id = $1");
+
+		// Assert
+		assert!(!first);
+		assert!(second);
+		assert_eq!(tracker.stats(), StatementCacheStats { hits: 1, misses: 1 });
+		assert_eq!(tracker.stats().hit_ratio(), 0.5);
+	}
+
+	#[rstest]
+	fn test_tracker_evicts_least_recently_used() {
+		// Arrange
+		let tracker = StatementCacheTracker::new(StatementCacheConfig::with_capacity(2));
+		tracker.record("SELECT 1");
+		tracker.record("SELECT 2");
+		tracker.record("SELECT 1");
+
+		// Act
+		tracker.record("SELECT 3");
+
+		// Assert
+		assert!(tracker.record("SELECT 1"));
+		assert!(!tracker.record("SELECT 2"));
+	}
+
+	#[rstest]
+	fn test_disabled_cache_only_misses() {
+		// Arrange
+		let tracker = StatementCacheTracker::new(StatementCacheConfig::disabled());
+
+		// Act
+		tracker.record("SELECT 1");
+		tracker.record("SELECT 1");
+
+		// Assert
+		assert!(!tracker.persistent());
+		assert_eq!(tracker.stats(), StatementCacheStats { hits: 0, misses: 2 });
+	}
+
+	#[rstest]
+	fn test_global_stats_include_tracker_counts() {
+		// Arrange
+		let before = statement_cache_stats();
+		let tracker = StatementCacheTracker::default();
+
+		// Act
+		tracker.record("SELECT 1");
+		tracker.record("SELECT 1");
+
+		// Assert
+		let after = statement_cache_stats();
+		assert!(after.hits > before.hits);
+		assert!(after.misses > before.misses);
+	}
+}
@@ -9,8 +9,10 @@
 //!
 //! - **Multi-database support**: Manage content types across multiple databases
 //! - **ORM integration**: Seamless integration with reinhardt-orm
-//! - **Generic relations**: Type-safe polymorphic relationships
+//! - **Generic relations**: Type-safe polymorphic relationships, reverse
+//!   relations, cross-relation filtering and heterogeneous prefetching
 //! - **Database persistence**: Store content types in database with caching
+//! - **Attachments**: Files in storage attached to objects of any model
 //!
 //! ## Planned Features
 //!
@@ -26,7 +28,6 @@
 //!
 //! - Content type shortcuts (URL resolution for generic objects)
 //! - Content type view mixins
-//! - Admin interface integration for generic relations
 //! - Automatic content type cleanup on model deletion
 //! - Content type renaming and migration support
 //!
@@ -36,6 +37,8 @@
 //! - Content type synchronization commands
 //! - Content type inspection utilities
 
+#[cfg(feature = "orm")]
+pub mod attachments;
 pub mod cleanup;
 // Allow module_inception: Re-exporting contenttypes submodule from contenttypes.rs
 // is intentional for compatibility with existing imports (`reinhardt_db::contenttypes::ContentType`)
@@ -43,6 +46,8 @@ pub mod cleanup;
 /// Contenttypes module.
 pub mod contenttypes;
 pub mod generic_fk;
+#[cfg(feature = "orm")]
+pub mod generic_relations;
 pub mod inspect;
 pub mod migration;
 pub mod permissions;
@@ -64,6 +69,11 @@ pub use contenttypes::{
 
 pub use generic_fk::GenericForeignKeyField;
 
+#[cfg(feature = "orm")]
+pub use generic_relations::{
+	GenericPrefetch, GenericQuerySetExt, GenericRelation, GenericTargets, content_type_for,
+};
+
 pub use permissions::{ContentTypePermission, PermissionAction, PermissionContext};
 
 #[cfg(feature = "database")]
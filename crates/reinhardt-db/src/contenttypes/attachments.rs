@@ -0,0 +1,478 @@
+//! File attachments for any model
+//!
+//! An [`Attachment`] links a file kept in a storage backend to one object of
+//! any model through a generic foreign key: the id of the object's content
+//! type and the object's primary key, as text so that models with UUID or
+//! string keys can carry attachments too. The file itself is not stored in
+//! the database, only its storage path and metadata.
+//!
+//! reinhardt-rest provides the endpoints for uploading and detaching files,
+//! and reinhardt-admin an inline listing the attachments of the object being
+//! edited.
+//!
+//! # Examples
+//!
+//! ```
+//! use reinhardt_db::contenttypes::attachments::{
+//!     Attachment, AttachmentStore, InMemoryAttachmentStore,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() -> reinhardt_core::exception::Result<()> {
+//! let store = InMemoryAttachmentStore::new();
+//! let attachment = Attachment::new(3, "42", "attachments/blog/post/42/draft.pdf", "draft.pdf")
+//!     .mime_type("application/pdf")
+//!     .size(18_432)
+//!     .uploaded_by("7");
+//! store.create(&attachment).await?;
+//!
+//! let attached = store.for_object(3, "42").await?;
+//! assert_eq!(attached[0].original_name, "draft.pdf");
+//! # Ok(())
+//! # }
+//! ```
+
+use super::contenttypes::CONTENT_TYPE_REGISTRY;
+use super::generic_relations::content_type_for;
+use crate::backends::DatabaseConnection;
+use crate::backends::sql_build_helpers::build_inline_sql;
+use crate::orm::Model;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_query::prelude::{
+	Alias, ColumnDef, Cond, Condition, Expr, ExprTrait, IntoValue, Order, Query, Value,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Table storing the attachments of [`DatabaseAttachmentStore`]
+pub const ATTACHMENT_TABLE: &str = "attachments_attachment";
+
+/// Columns of [`ATTACHMENT_TABLE`], in insertion order
+const COLUMNS: [&str; 10] = [
+	"id",
+	"content_type_id",
+	"object_id",
+	"file",
+	"original_name",
+	"mime_type",
+	"size",
+	"metadata",
+	"uploaded_by",
+	"uploaded_at",
+];
+
+/// A file attached to one object of any model
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_db::contenttypes::attachments::Attachment;
+/// use serde_json::json;
+///
+/// let attachment = Attachment::new(3, "42", "attachments/blog/post/42/photo.jpg", "photo.jpg")
+///     .mime_type("image/jpeg")
+///     .metadata(json!({"width": 1024, "height": 768}));
+/// assert_eq!(attachment.object_id, "42");
+/// assert_eq!(attachment.uploaded_by, None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attachment {
+	/// Unique id, time-ordered
+	pub id: Uuid,
+	/// Id of the content type of the object the file is attached to
+	pub content_type_id: i64,
+	/// Primary key of the object the file is attached to
+	pub object_id: String,
+	/// Path of the file in the storage backend
+	pub file: String,
+	/// File name as uploaded
+	pub original_name: String,
+	/// MIME type of the file
+	pub mime_type: String,
+	/// Size of the file in bytes
+	pub size: i64,
+	/// Free-form metadata, e.g. image dimensions or a caption
+	pub metadata: serde_json::Value,
+	/// Who uploaded the file, usually a user id
+	pub uploaded_by: Option<String>,
+	/// When the file was attached
+	pub uploaded_at: DateTime<Utc>,
+}
+
+impl Attachment {
+	/// Attach the stored `file` to the object `object_id` of content type
+	/// `content_type_id`
+	pub fn new(
+		content_type_id: i64,
+		object_id: impl Into<String>,
+		file: impl Into<String>,
+		original_name: impl Into<String>,
+	) -> Self {
+		Self {
+			id: Uuid::now_v7(),
+			content_type_id,
+			object_id: object_id.into(),
+			file: file.into(),
+			original_name: original_name.into(),
+			mime_type: "application/octet-stream".to_string(),
+			size: 0,
+			metadata: serde_json::Value::Object(Default::default()),
+			uploaded_by: None,
+			uploaded_at: Utc::now(),
+		}
+	}
+
+	/// Attach the stored `file` to a saved `instance`
+	///
+	/// Returns `None` when `instance` has no primary key yet.
+	pub fn for_instance<M: Model>(
+		instance: &M,
+		file: impl Into<String>,
+		original_name: impl Into<String>,
+	) -> Option<Self> {
+		let object_id = instance.primary_key()?.to_string();
+		let content_type_id = content_type_for::<M>().id?;
+		Some(Self::new(content_type_id, object_id, file, original_name))
+	}
+
+	/// Set the MIME type
+	pub fn mime_type(mut self, mime_type: impl Into<String>) -> Self {
+		self.mime_type = mime_type.into();
+		self
+	}
+
+	/// Set the size in bytes
+	pub fn size(mut self, size: i64) -> Self {
+		self.size = size;
+		self
+	}
+
+	/// Set the metadata
+	pub fn metadata(mut self, metadata: serde_json::Value) -> Self {
+		self.metadata = metadata;
+		self
+	}
+
+	/// Set who uploaded the file
+	pub fn uploaded_by(mut self, uploaded_by: impl Into<String>) -> Self {
+		self.uploaded_by = Some(uploaded_by.into());
+		self
+	}
+}
+
+/// Id of the registered content type `app_label.model`
+///
+/// The model name is matched as given, then lowercased, like
+/// [`content_type_for`]. Unlike it, unknown content types are not created.
+pub fn content_type_id(app_label: &str, model: &str) -> Option<i64> {
+	CONTENT_TYPE_REGISTRY
+		.get(app_label, model)
+		.or_else(|| CONTENT_TYPE_REGISTRY.get(app_label, &model.to_lowercase()))
+		.and_then(|ct| ct.id)
+}
+
+/// Storage backend for attachment records
+#[async_trait]
+pub trait AttachmentStore: Send + Sync {
+	/// Persist a new attachment
+	async fn create(&self, attachment: &Attachment) -> Result<()>;
+
+	/// The attachment `id`, if any
+	async fn get(&self, id: Uuid) -> Result<Option<Attachment>>;
+
+	/// Attachments of one object, oldest first
+	async fn for_object(&self, content_type_id: i64, object_id: &str) -> Result<Vec<Attachment>>;
+
+	/// Delete the attachment record `id`
+	///
+	/// The stored file is left alone. Returns `false` when there is no
+	/// attachment `id`.
+	async fn delete(&self, id: Uuid) -> Result<bool>;
+}
+
+/// Attachments of a saved `instance`, oldest first
+///
+/// Returns no attachments when `instance` has no primary key yet.
+pub async fn attachments_of<M: Model>(
+	store: &dyn AttachmentStore,
+	instance: &M,
+) -> Result<Vec<Attachment>> {
+	let (Some(pk), Some(content_type_id)) = (instance.primary_key(), content_type_for::<M>().id)
+	else {
+		return Ok(Vec::new());
+	};
+	store.for_object(content_type_id, &pk.to_string()).await
+}
+
+/// Attachment store keeping records in memory
+///
+/// Suitable for tests and single-process deployments; records are lost on
+/// restart.
+#[derive(Debug, Default)]
+pub struct InMemoryAttachmentStore {
+	attachments: RwLock<Vec<Attachment>>,
+}
+
+impl InMemoryAttachmentStore {
+	/// Create an empty store
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+#[async_trait]
+impl AttachmentStore for InMemoryAttachmentStore {
+	async fn create(&self, attachment: &Attachment) -> Result<()> {
+		self.attachments.write().push(attachment.clone());
+		Ok(())
+	}
+
+	async fn get(&self, id: Uuid) -> Result<Option<Attachment>> {
+		Ok(self.attachments.read().iter().find(|a| a.id == id).cloned())
+	}
+
+	async fn for_object(&self, content_type_id: i64, object_id: &str) -> Result<Vec<Attachment>> {
+		let mut attached: Vec<_> = self
+			.attachments
+			.read()
+			.iter()
+			.filter(|a| a.content_type_id == content_type_id && a.object_id == object_id)
+			.cloned()
+			.collect();
+		attached.sort_by(|a, b| a.uploaded_at.cmp(&b.uploaded_at).then(a.id.cmp(&b.id)));
+		Ok(attached)
+	}
+
+	async fn delete(&self, id: Uuid) -> Result<bool> {
+		let mut attachments = self.attachments.write();
+		let before = attachments.len();
+		attachments.retain(|a| a.id != id);
+		Ok(attachments.len() != before)
+	}
+}
+
+/// Attachment store backed by the [`ATTACHMENT_TABLE`] table
+pub struct DatabaseAttachmentStore {
+	connection: DatabaseConnection,
+}
+
+impl DatabaseAttachmentStore {
+	/// Create a store using `connection`
+	pub fn new(connection: DatabaseConnection) -> Self {
+		Self { connection }
+	}
+
+	/// Create the [`ATTACHMENT_TABLE`] table if it does not exist
+	pub async fn create_table(&self) -> Result<()> {
+		let stmt = Query::create_table()
+			.table(Alias::new(ATTACHMENT_TABLE))
+			.if_not_exists()
+			.col(ColumnDef::new("id").uuid().not_null(true).primary_key(true))
+			.col(
+				ColumnDef::new("content_type_id")
+					.big_integer()
+					.not_null(true),
+			)
+			.col(ColumnDef::new("object_id").string_len(64).not_null(true))
+			.col(ColumnDef::new("file").string_len(1024).not_null(true))
+			.col(
+				ColumnDef::new("original_name")
+					.string_len(255)
+					.not_null(true),
+			)
+			.col(ColumnDef::new("mime_type").string_len(255).not_null(true))
+			.col(ColumnDef::new("size").big_integer().not_null(true))
+			.col(ColumnDef::new("metadata").text().not_null(true))
+			.col(ColumnDef::new("uploaded_by").string_len(255))
+			.col(
+				ColumnDef::new("uploaded_at")
+					.timestamp_with_time_zone()
+					.not_null(true),
+			)
+			.to_owned();
+		self.execute(&build_inline_sql(self.connection.database_type(), &stmt))
+			.await
+			.map(|_| ())
+	}
+
+	async fn execute(&self, sql: &str) -> Result<u64> {
+		self.connection
+			.execute(sql, vec![])
+			.await
+			.map(|result| result.rows_affected)
+			.map_err(|e| Error::Database(e.to_string()))
+	}
+
+	async fn select(&self, condition: Condition) -> Result<Vec<Attachment>> {
+		let stmt = Query::select()
+			.columns(COLUMNS.map(Alias::new))
+			.from(Alias::new(ATTACHMENT_TABLE))
+			.cond_where(condition)
+			.order_by(Alias::new("uploaded_at"), Order::Asc)
+			.order_by(Alias::new("id"), Order::Asc)
+			.to_owned();
+		let sql = build_inline_sql(self.connection.database_type(), &stmt);
+		let rows = self
+			.connection
+			.fetch_all(&sql, vec![])
+			.await
+			.map_err(|e| Error::Database(e.to_string()))?;
+		rows.iter().map(row_to_attachment).collect()
+	}
+}
+
+#[async_trait]
+impl AttachmentStore for DatabaseAttachmentStore {
+	async fn create(&self, attachment: &Attachment) -> Result<()> {
+		let stmt = Query::insert()
+			.into_table(Alias::new(ATTACHMENT_TABLE))
+			.columns(COLUMNS.map(Alias::new))
+			.values_panic([
+				Value::from(attachment.id),
+				Value::from(attachment.content_type_id),
+				Value::from(attachment.object_id.clone()),
+				Value::from(attachment.file.clone()),
+				Value::from(attachment.original_name.clone()),
+				Value::from(attachment.mime_type.clone()),
+				Value::from(attachment.size),
+				Value::from(attachment.metadata.to_string()),
+				attachment.uploaded_by.clone().into_value(),
+				Value::from(attachment.uploaded_at),
+			])
+			.to_owned();
+		self.execute(&build_inline_sql(self.connection.database_type(), &stmt))
+			.await
+			.map(|_| ())
+	}
+
+	async fn get(&self, id: Uuid) -> Result<Option<Attachment>> {
+		let condition = Cond::all().add(Expr::col(Alias::new("id")).eq(Value::from(id)));
+		Ok(self.select(condition).await?.into_iter().next())
+	}
+
+	async fn for_object(&self, content_type_id: i64, object_id: &str) -> Result<Vec<Attachment>> {
+		let condition = Cond::all()
+			.add(Expr::col(Alias::new("content_type_id")).eq(Value::from(content_type_id)))
+			.add(Expr::col(Alias::new("object_id")).eq(object_id.to_string()));
+		self.select(condition).await
+	}
+
+	async fn delete(&self, id: Uuid) -> Result<bool> {
+		let stmt = Query::delete()
+			.from_table(Alias::new(ATTACHMENT_TABLE))
+			.cond_where(Cond::all().add(Expr::col(Alias::new("id")).eq(Value::from(id))))
+			.to_owned();
+		Ok(self
+			.execute(&build_inline_sql(self.connection.database_type(), &stmt))
+			.await? > 0)
+	}
+}
+
+/// Read a timestamp column, stored as text by SQLite
+fn timestamp(row: &crate::backends::types::Row, column: &str) -> Result<DateTime<Utc>> {
+	if let Ok(value) = row.get::<DateTime<Utc>>(column) {
+		return Ok(value);
+	}
+	let text = row
+		.get::<String>(column)
+		.map_err(|e| Error::Database(e.to_string()))?;
+	DateTime::parse_from_rfc3339(&text)
+		.or_else(|_| DateTime::parse_from_str(&text, "%Y-%m-%d %H:%M:%S%.f %:z"))
+		.map(|value| value.with_timezone(&Utc))
+		.map_err(|e| Error::Database(format!("invalid {} `{}`: {}", column, text, e)))
+}
+
+/// Convert an [`ATTACHMENT_TABLE`] row
+fn row_to_attachment(row: &crate::backends::types::Row) -> Result<Attachment> {
+	let db_err = |e: crate::backends::error::DatabaseError| Error::Database(e.to_string());
+	let metadata = row.get::<String>("metadata").map_err(db_err)?;
+	Ok(Attachment {
+		id: row.get::<Uuid>("id").map_err(db_err)?,
+		content_type_id: row.get::<i64>("content_type_id").map_err(db_err)?,
+		object_id: row.get::<String>("object_id").map_err(db_err)?,
+		file: row.get::<String>("file").map_err(db_err)?,
+		original_name: row.get::<String>("original_name").map_err(db_err)?,
+		mime_type: row.get::<String>("mime_type").map_err(db_err)?,
+		size: row.get::<i64>("size").map_err(db_err)?,
+		metadata: serde_json::from_str(&metadata)
+			.map_err(|e| Error::Database(format!("invalid attachment metadata: {}", e)))?,
+		uploaded_by: row.get::<String>("uploaded_by").ok(),
+		uploaded_at: timestamp(row, "uploaded_at")?,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::contenttypes::ContentType;
+	use rstest::rstest;
+
+	#[rstest]
+	#[tokio::test]
+	async fn for_object_lists_only_that_object_oldest_first() {
+		// Arrange
+		let store = InMemoryAttachmentStore::new();
+		let first = Attachment::new(1, "42", "a/first.txt", "first.txt");
+		let mut second = Attachment::new(1, "42", "a/second.txt", "second.txt");
+		second.uploaded_at = first.uploaded_at + chrono::Duration::seconds(1);
+		store.create(&second).await.unwrap();
+		store.create(&first).await.unwrap();
+		store
+			.create(&Attachment::new(1, "7", "a/other.txt", "other.txt"))
+			.await
+			.unwrap();
+		store
+			.create(&Attachment::new(2, "42", "a/model.txt", "model.txt"))
+			.await
+			.unwrap();
+
+		// Act
+		let attached = store.for_object(1, "42").await.unwrap();
+
+		// Assert
+		assert_eq!(
+			attached
+				.iter()
+				.map(|a| a.original_name.as_str())
+				.collect::<Vec<_>>(),
+			["first.txt", "second.txt"]
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn delete_removes_record() {
+		// Arrange
+		let store = InMemoryAttachmentStore::new();
+		let attachment = Attachment::new(1, "42", "a/file.txt", "file.txt");
+		store.create(&attachment).await.unwrap();
+
+		// Act
+		let deleted = store.delete(attachment.id).await.unwrap();
+		let again = store.delete(attachment.id).await.unwrap();
+
+		// Assert
+		assert!(deleted);
+		assert!(!again);
+		assert_eq!(store.get(attachment.id).await.unwrap(), None);
+	}
+
+	#[rstest]
+	fn content_type_id_matches_lowercased_model() {
+		// Arrange
+		let ct = CONTENT_TYPE_REGISTRY.register(ContentType::new("attachments_test", "invoice"));
+
+		// Act
+		let exact = content_type_id("attachments_test", "invoice");
+		let capitalized = content_type_id("attachments_test", "Invoice");
+		let unknown = content_type_id("attachments_test", "receipt");
+
+		// Assert
+		assert_eq!(exact, ct.id);
+		assert_eq!(capitalized, ct.id);
+		assert_eq!(unknown, None);
+	}
+}
@@ -0,0 +1,673 @@
+//! ORM query support for generic relations
+//!
+//! Builds on [`GenericForeignKeyField`] to query generic relations end-to-end:
+//!
+//! - [`GenericRelation`] describes the content type / object id columns of a
+//!   model holding a generic foreign key and resolves the reverse side
+//!   ("all comments of this post") as a [`GenericRelationSet`].
+//! - [`GenericQuerySetExt`] filters the child model across its generic
+//!   foreign key, joining `django_content_type` when content types are
+//!   matched by natural key.
+//! - [`GenericPrefetch`] loads the heterogeneous targets of many generic
+//!   foreign keys with one query per content type.
+//!
+//! Content types of models are resolved through [`CONTENT_TYPE_REGISTRY`]
+//! using the model's app label and type name (see [`content_type_for`]).
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use reinhardt_db::contenttypes::generic_relations::{
+//!     GenericPrefetch, GenericQuerySetExt, GenericRelation,
+//! };
+//!
+//! let comments = GenericRelation::<Comment>::with_defaults();
+//!
+//! // Reverse relation: comments attached to a post
+//! let post_comments = comments.for_instance(&post).unwrap().all().await?;
+//!
+//! // Comments on published posts
+//! let published = Comment::objects()
+//!     .all()
+//!     .filter_generic_target(&comments, |posts: QuerySet<Post>| {
+//!         posts.filter(Filter::new("published", FilterOperator::Eq, FilterValue::Bool(true)))
+//!     })
+//!     .all()
+//!     .await?;
+//!
+//! // Targets of every comment, whatever their model
+//! let targets = GenericPrefetch::new()
+//!     .register::<Post>()
+//!     .register::<Photo>()
+//!     .load(published.iter().map(|c| &c.target))
+//!     .await?;
+//! let post: Option<&Post> = targets.get(&published[0].target);
+//! ```
+
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::persistence::ContentTypeModel;
+use super::{CONTENT_TYPE_REGISTRY, ContentType, GenericForeignKeyField};
+use crate::orm::Manager;
+use crate::orm::Model;
+use crate::orm::custom_manager::CustomManager;
+use crate::orm::model::FieldSelector;
+use crate::orm::query::{Filter, FilterOperator, FilterValue, QuerySet};
+use crate::orm::relations::GenericRelationSet;
+
+/// Name of the table storing persisted content types
+pub const CONTENT_TYPE_TABLE: &str = "django_content_type";
+
+/// Get the content type of a model, registering it on first use
+///
+/// The content type is identified by `M::app_label()` and the last segment of
+/// the model's type name. An existing registration with a lowercase model
+/// name (as created by `ContentTypeSynchronizer`) is reused.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let ct = content_type_for::<Post>();
+/// assert_eq!(ct.model, "Post");
+/// ```
+pub fn content_type_for<M: Model>() -> ContentType {
+	let app_label = M::app_label();
+	let model = model_name::<M>();
+	CONTENT_TYPE_REGISTRY
+		.get(app_label, model)
+		.or_else(|| CONTENT_TYPE_REGISTRY.get(app_label, &model.to_lowercase()))
+		.unwrap_or_else(|| CONTENT_TYPE_REGISTRY.get_or_create(app_label, model))
+}
+
+/// Describes the generic foreign key of model `T`
+///
+/// Holds the names of the content type and object id columns of `T` and is
+/// the entry point for the reverse relation ([`for_instance`](Self::for_instance)),
+/// for filtering owners by their generic children
+/// ([`filter_owners`](Self::filter_owners)) and for the filters of
+/// [`GenericQuerySetExt`].
+///
+/// # Example
+///
+/// ```rust
+/// use reinhardt_db::contenttypes::generic_relations::GenericRelation;
+///
+/// let relation: GenericRelation<()> = GenericRelation::new("target_ct_id", "target_id");
+/// assert_eq!(relation.ct_field(), "target_ct_id");
+/// assert_eq!(relation.fk_field(), "target_id");
+///
+/// let relation: GenericRelation<()> = GenericRelation::with_defaults();
+/// assert_eq!(relation.ct_field(), "content_type_id");
+/// assert_eq!(relation.fk_field(), "object_id");
+/// ```
+#[derive(Debug, Clone)]
+pub struct GenericRelation<T> {
+	ct_field: String,
+	fk_field: String,
+	_phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> GenericRelation<T> {
+	/// Create a relation using the given content type and object id columns
+	pub fn new(ct_field: impl Into<String>, fk_field: impl Into<String>) -> Self {
+		Self {
+			ct_field: ct_field.into(),
+			fk_field: fk_field.into(),
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Create a relation using the default `content_type_id` and `object_id` columns
+	pub fn with_defaults() -> Self {
+		Self::new("content_type_id", "object_id")
+	}
+
+	/// Get the content type column name
+	pub fn ct_field(&self) -> &str {
+		&self.ct_field
+	}
+
+	/// Get the object id column name
+	pub fn fk_field(&self) -> &str {
+		&self.fk_field
+	}
+
+	/// Get the related objects of `owner`
+	///
+	/// Returns `None` when `owner` has not been saved yet or its primary key
+	/// is not an integer.
+	pub fn for_instance<O: Model>(&self, owner: &O) -> Option<GenericRelationSet<T>> {
+		let object_id = object_id_of(owner)?;
+		Some(GenericRelationSet::new(
+			content_type_id::<O>(),
+			object_id,
+			self.ct_field.clone(),
+			self.fk_field.clone(),
+		))
+	}
+}
+
+impl<T: Model> GenericRelation<T> {
+	/// Keep owners having at least one related object matching `f`
+	///
+	/// Generates `pk IN (SELECT object_id FROM <T> WHERE content_type_id = ...)`.
+	///
+	/// # Example
+	///
+	/// ```rust,ignore
+	/// // Posts with at least one unapproved comment
+	/// let posts = comments.filter_owners(Post::objects().all(), |c| {
+	///     c.filter(Filter::new("approved", FilterOperator::Eq, FilterValue::Bool(false)))
+	/// });
+	/// ```
+	pub fn filter_owners<O, F>(&self, owners: QuerySet<O>, f: F) -> QuerySet<O>
+	where
+		O: Model,
+		F: FnOnce(QuerySet<T>) -> QuerySet<T>,
+	{
+		let ct_filter = integer_filter(&self.ct_field, content_type_id::<O>());
+		let fk_field = self.fk_field.clone();
+		owners.filter_in_subquery::<T, _>(O::primary_key_field(), move |related| {
+			f(related.filter(ct_filter)).values(&[fk_field.as_str()])
+		})
+	}
+}
+
+/// Generic foreign key filters for querysets
+///
+/// Implemented for every [`QuerySet`]; the [`GenericRelation`] names the
+/// generic foreign key columns of the queried model.
+pub trait GenericQuerySetExt<T: Model>: Sized {
+	/// Keep rows pointing at `target`
+	///
+	/// An unsaved `target` matches no rows.
+	fn filter_generic_object<O: Model>(self, relation: &GenericRelation<T>, target: &O) -> Self;
+
+	/// Keep rows pointing at any row of model `O`
+	fn filter_generic_model<O: Model>(self, relation: &GenericRelation<T>) -> Self;
+
+	/// Keep rows whose content type has the given natural key
+	///
+	/// The content type is looked up in `django_content_type` by a subquery,
+	/// so ids assigned by the database are honored. The model name is
+	/// compared case-insensitively.
+	fn filter_generic_content_type(
+		self,
+		relation: &GenericRelation<T>,
+		app_label: &str,
+		model: &str,
+	) -> Self;
+
+	/// Keep rows pointing at a row of model `O` matching `f`
+	///
+	/// # Example
+	///
+	/// ```rust,ignore
+	/// // Comments on posts by a given author
+	/// let comments = Comment::objects()
+	///     .all()
+	///     .filter_generic_target(&relation, |posts: QuerySet<Post>| {
+	///         posts.filter(Filter::new("author_id", FilterOperator::Eq, FilterValue::Int(7)))
+	///     });
+	/// ```
+	fn filter_generic_target<O, F>(self, relation: &GenericRelation<T>, f: F) -> Self
+	where
+		O: Model,
+		F: FnOnce(QuerySet<O>) -> QuerySet<O>;
+}
+
+impl<T: Model> GenericQuerySetExt<T> for QuerySet<T> {
+	fn filter_generic_object<O: Model>(self, relation: &GenericRelation<T>, target: &O) -> Self {
+		let qs = self.filter_generic_model::<O>(relation);
+		match object_id_of(target) {
+			Some(object_id) => qs.filter(integer_filter(&relation.fk_field, object_id)),
+			None => qs.filter(Filter::new(
+				relation.fk_field.clone(),
+				FilterOperator::IsNull,
+				FilterValue::Null,
+			)),
+		}
+	}
+
+	fn filter_generic_model<O: Model>(self, relation: &GenericRelation<T>) -> Self {
+		self.filter(integer_filter(&relation.ct_field, content_type_id::<O>()))
+	}
+
+	fn filter_generic_content_type(
+		self,
+		relation: &GenericRelation<T>,
+		app_label: &str,
+		model: &str,
+	) -> Self {
+		let app_label = app_label.to_string();
+		let model = model.to_string();
+		self.filter_in_subquery::<ContentTypeModel, _>(&relation.ct_field, move |content_types| {
+			content_types
+				.filter(Filter::new(
+					"app_label",
+					FilterOperator::Eq,
+					FilterValue::String(app_label),
+				))
+				.filter(Filter::new(
+					"model",
+					FilterOperator::IExact,
+					FilterValue::String(model),
+				))
+				.values(&["id"])
+		})
+	}
+
+	fn filter_generic_target<O, F>(self, relation: &GenericRelation<T>, f: F) -> Self
+	where
+		O: Model,
+		F: FnOnce(QuerySet<O>) -> QuerySet<O>,
+	{
+		self.filter_generic_model::<O>(relation)
+			.filter_in_subquery::<O, _>(&relation.fk_field, |targets| {
+				f(targets).values(&[O::primary_key_field()])
+			})
+	}
+}
+
+/// Loads the targets of generic foreign keys, one query per content type
+///
+/// Register every model that generic foreign keys may point at; keys whose
+/// content type is not registered are left unresolved.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let targets = GenericPrefetch::new()
+///     .register::<Post>()
+///     .register::<Photo>()
+///     .load(comments.iter().map(|c| &c.target))
+///     .await?;
+///
+/// for comment in &comments {
+///     if let Some(post) = targets.get::<Post>(&comment.target) {
+///         println!("comment on post {}", post.title);
+///     } else if let Some(photo) = targets.get::<Photo>(&comment.target) {
+///         println!("comment on photo {}", photo.caption);
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct GenericPrefetch {
+	loaders: HashMap<i64, Box<dyn TargetLoader>>,
+}
+
+impl GenericPrefetch {
+	/// Create a prefetcher without registered models
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Register a model generic foreign keys may point at
+	pub fn register<M: Model + 'static>(mut self) -> Self {
+		self.loaders.insert(
+			content_type_id::<M>(),
+			Box::new(ModelLoader::<M>(PhantomData)),
+		);
+		self
+	}
+
+	/// Load the targets of `keys`
+	///
+	/// Unset keys are skipped, and each registered content type is queried
+	/// once with all of its object ids.
+	pub async fn load<'a, I>(&self, keys: I) -> reinhardt_core::exception::Result<GenericTargets>
+	where
+		I: IntoIterator<Item = &'a GenericForeignKeyField>,
+	{
+		let mut targets = GenericTargets::default();
+		for (content_type_id, object_ids) in group_by_content_type(keys) {
+			let Some(loader) = self.loaders.get(&content_type_id) else {
+				continue;
+			};
+			for (object_id, object) in loader.load(object_ids).await? {
+				targets.objects.insert((content_type_id, object_id), object);
+			}
+		}
+		Ok(targets)
+	}
+}
+
+/// Targets loaded by [`GenericPrefetch`]
+#[derive(Default, Clone)]
+pub struct GenericTargets {
+	objects: HashMap<(i64, i64), Arc<dyn Any + Send + Sync>>,
+}
+
+impl GenericTargets {
+	/// Get the target of `key` if it was loaded and is a `M`
+	pub fn get<M: Model + 'static>(&self, key: &GenericForeignKeyField) -> Option<&M> {
+		let object = self
+			.objects
+			.get(&(key.content_type_id()?, key.object_id()?))?;
+		object.downcast_ref::<M>()
+	}
+
+	/// Check whether the target of `key` was loaded
+	pub fn contains(&self, key: &GenericForeignKeyField) -> bool {
+		match (key.content_type_id(), key.object_id()) {
+			(Some(ct), Some(id)) => self.objects.contains_key(&(ct, id)),
+			_ => false,
+		}
+	}
+
+	/// Number of loaded targets
+	pub fn len(&self) -> usize {
+		self.objects.len()
+	}
+
+	/// Check whether no targets were loaded
+	pub fn is_empty(&self) -> bool {
+		self.objects.is_empty()
+	}
+}
+
+#[async_trait]
+trait TargetLoader: Send + Sync {
+	async fn load(
+		&self,
+		object_ids: Vec<i64>,
+	) -> reinhardt_core::exception::Result<Vec<(i64, Arc<dyn Any + Send + Sync>)>>;
+}
+
+struct ModelLoader<M>(PhantomData<fn() -> M>);
+
+#[async_trait]
+impl<M: Model + 'static> TargetLoader for ModelLoader<M> {
+	async fn load(
+		&self,
+		object_ids: Vec<i64>,
+	) -> reinhardt_core::exception::Result<Vec<(i64, Arc<dyn Any + Send + Sync>)>> {
+		let ids = object_ids.into_iter().map(FilterValue::Integer).collect();
+		let queryset = M::objects().all().filter(Filter::new(
+			M::primary_key_field(),
+			FilterOperator::In,
+			FilterValue::List(ids),
+		));
+		let rows = queryset.all().await?;
+		Ok(rows
+			.into_iter()
+			.filter_map(|row| {
+				let object_id = object_id_of(&row)?;
+				Some((object_id, Arc::new(row) as Arc<dyn Any + Send + Sync>))
+			})
+			.collect())
+	}
+}
+
+/// Field selector for [`ContentTypeModel`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ContentTypeModelFields;
+
+impl FieldSelector for ContentTypeModelFields {
+	fn with_alias(self, _alias: &str) -> Self {
+		self
+	}
+}
+
+/// Lets querysets join `django_content_type` through subqueries
+impl Model for ContentTypeModel {
+	type PrimaryKey = i64;
+	type Fields = ContentTypeModelFields;
+	type Objects = Manager<Self>;
+
+	fn table_name() -> &'static str {
+		CONTENT_TYPE_TABLE
+	}
+
+	fn new_fields() -> Self::Fields {
+		ContentTypeModelFields
+	}
+
+	fn app_label() -> &'static str {
+		"contenttypes"
+	}
+
+	fn primary_key(&self) -> Option<Self::PrimaryKey> {
+		self.id
+	}
+
+	fn set_primary_key(&mut self, value: Self::PrimaryKey) {
+		self.id = Some(value);
+	}
+}
+
+/// Last segment of the model's type name (`blog::models::Post` -> `Post`)
+fn model_name<M>() -> &'static str {
+	std::any::type_name::<M>()
+		.rsplit("::")
+		.next()
+		.unwrap_or("Unknown")
+}
+
+fn content_type_id<M: Model>() -> i64 {
+	// The registry assigns an id to every registered content type
+	content_type_for::<M>().id.unwrap_or_default()
+}
+
+/// Integer primary key of a saved instance
+fn object_id_of<M: Model>(instance: &M) -> Option<i64> {
+	instance.primary_key()?.to_string().parse().ok()
+}
+
+fn integer_filter(field: &str, value: i64) -> Filter {
+	Filter::new(
+		field.to_string(),
+		FilterOperator::Eq,
+		FilterValue::Integer(value),
+	)
+}
+
+/// Distinct object ids of set keys, grouped by content type id
+fn group_by_content_type<'a, I>(keys: I) -> BTreeMap<i64, Vec<i64>>
+where
+	I: IntoIterator<Item = &'a GenericForeignKeyField>,
+{
+	let mut groups: BTreeMap<i64, Vec<i64>> = BTreeMap::new();
+	for key in keys {
+		if let (Some(ct), Some(id)) = (key.content_type_id(), key.object_id()) {
+			let ids = groups.entry(ct).or_default();
+			if !ids.contains(&id) {
+				ids.push(id);
+			}
+		}
+	}
+	groups
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use serde::{Deserialize, Serialize};
+
+	#[derive(Debug, Clone, Serialize, Deserialize)]
+	struct GrPost {
+		id: Option<i64>,
+	}
+
+	#[derive(Debug, Clone, Serialize, Deserialize)]
+	struct GrComment {
+		id: Option<i64>,
+	}
+
+	#[derive(Clone)]
+	struct NoFields;
+
+	impl FieldSelector for NoFields {
+		fn with_alias(self, _alias: &str) -> Self {
+			self
+		}
+	}
+
+	impl Model for GrPost {
+		type PrimaryKey = i64;
+		type Fields = NoFields;
+		type Objects = Manager<Self>;
+
+		fn table_name() -> &'static str {
+			"gr_posts"
+		}
+
+		fn new_fields() -> Self::Fields {
+			NoFields
+		}
+
+		fn app_label() -> &'static str {
+			"blog"
+		}
+
+		fn primary_key(&self) -> Option<Self::PrimaryKey> {
+			self.id
+		}
+
+		fn set_primary_key(&mut self, value: Self::PrimaryKey) {
+			self.id = Some(value);
+		}
+	}
+
+	impl Model for GrComment {
+		type PrimaryKey = i64;
+		type Fields = NoFields;
+		type Objects = Manager<Self>;
+
+		fn table_name() -> &'static str {
+			"gr_comments"
+		}
+
+		fn new_fields() -> Self::Fields {
+			NoFields
+		}
+
+		fn app_label() -> &'static str {
+			"blog"
+		}
+
+		fn primary_key(&self) -> Option<Self::PrimaryKey> {
+			self.id
+		}
+
+		fn set_primary_key(&mut self, value: Self::PrimaryKey) {
+			self.id = Some(value);
+		}
+	}
+
+	#[rstest]
+	fn test_content_type_for_uses_app_label_and_type_name() {
+		// Act
+		let ct = content_type_for::<GrPost>();
+
+		// Assert
+		assert_eq!(ct.app_label, "blog");
+		assert_eq!(ct.model, "GrPost");
+		assert!(ct.id.is_some());
+	}
+
+	#[rstest]
+	fn test_for_instance_builds_reverse_relation() {
+		// Arrange
+		let relation = GenericRelation::<GrComment>::with_defaults();
+		let post = GrPost { id: Some(42) };
+
+		// Act
+		let set = relation.for_instance(&post).unwrap();
+
+		// Assert
+		assert_eq!(set.object_id(), 42);
+		assert_eq!(set.content_type_id(), content_type_id::<GrPost>());
+		assert_eq!(set.ct_field(), "content_type_id");
+		assert!(relation.for_instance(&GrPost { id: None }).is_none());
+	}
+
+	#[rstest]
+	fn test_filter_generic_target_matches_target_subquery() {
+		// Arrange
+		let relation = GenericRelation::<GrComment>::new("target_ct", "target_id");
+
+		// Act
+		let sql = QuerySet::<GrComment>::new()
+			.filter_generic_target(&relation, |posts: QuerySet<GrPost>| posts)
+			.to_sql();
+
+		// Assert
+		assert!(sql.contains(&format!(r#""target_ct" = {}"#, content_type_id::<GrPost>())));
+		assert!(sql.contains(r#""target_id" IN (SELECT"#));
+		assert!(sql.contains(r#"FROM "gr_posts""#));
+	}
+
+	#[rstest]
+	fn test_filter_generic_content_type_joins_content_type_table() {
+		// Arrange
+		let relation = GenericRelation::<GrComment>::with_defaults();
+
+		// Act
+		let sql = QuerySet::<GrComment>::new()
+			.filter_generic_content_type(&relation, "blog", "post")
+			.to_sql();
+
+		// Assert
+		assert!(sql.contains(r#""content_type_id" IN (SELECT"#));
+		assert!(sql.contains(r#"FROM "django_content_type""#));
+		assert!(sql.contains("'blog'"));
+	}
+
+	#[rstest]
+	fn test_filter_owners_selects_object_ids_of_children() {
+		// Arrange
+		let relation = GenericRelation::<GrComment>::with_defaults();
+
+		// Act
+		let sql = relation
+			.filter_owners(QuerySet::<GrPost>::new(), |comments| comments)
+			.to_sql();
+
+		// Assert
+		assert!(sql.contains(r#""id" IN (SELECT"#));
+		assert!(sql.contains(r#"FROM "gr_comments""#));
+	}
+
+	#[rstest]
+	fn test_group_by_content_type_deduplicates_and_skips_unset_keys() {
+		// Arrange
+		let keys = [
+			GenericForeignKeyField::with_values(Some(2), Some(7)),
+			GenericForeignKeyField::with_values(Some(1), Some(3)),
+			GenericForeignKeyField::with_values(Some(2), Some(7)),
+			GenericForeignKeyField::with_values(Some(2), Some(8)),
+			GenericForeignKeyField::with_values(None, Some(9)),
+		];
+
+		// Act
+		let groups = group_by_content_type(keys.iter());
+
+		// Assert
+		assert_eq!(groups.len(), 2);
+		assert_eq!(groups[&1], vec![3]);
+		assert_eq!(groups[&2], vec![7, 8]);
+	}
+
+	#[rstest]
+	fn test_generic_targets_downcasts_by_model() {
+		// Arrange
+		let mut targets = GenericTargets::default();
+		targets
+			.objects
+			.insert((1, 5), Arc::new(GrPost { id: Some(5) }));
+		let key = GenericForeignKeyField::with_values(Some(1), Some(5));
+
+		// Act & Assert
+		assert!(targets.contains(&key));
+		assert_eq!(targets.get::<GrPost>(&key).unwrap().id, Some(5));
+		assert!(targets.get::<GrComment>(&key).is_none());
+		assert_eq!(targets.len(), 1);
+	}
+}
@@ -9,6 +9,7 @@
 //! - **AST-Based Entry Points**: Generates Rust 2024 Edition-compliant module files
 //! - **State Reconstruction**: Django-style `ProjectState` building from migration history
 //! - **Zero Downtime**: Support for safe schema changes in production
+//! - **Seed Data**: Idempotent per-app initial rows, skipped when unchanged
 //!
 //! ## AST-Based Entry Point Generation
 //!
@@ -48,6 +49,7 @@ pub mod introspection;
 pub mod migration;
 pub mod migration_namer;
 pub mod migration_numbering;
+pub mod model_graph;
 pub mod model_registry;
 pub mod operation_trait;
 pub mod operations;
@@ -57,6 +59,7 @@ pub mod registry;
 pub mod repository;
 pub mod schema_diff;
 pub mod schema_editor;
+pub mod seeds;
 pub mod service;
 pub mod source;
 #[cfg(feature = "sqlite")]
@@ -103,6 +106,7 @@ pub use graph::{MigrationGraph, MigrationKey, MigrationNode};
 pub use migration::Migration;
 pub use migration_namer::MigrationNamer;
 pub use migration_numbering::MigrationNumbering;
+pub use model_graph::{ModelGraph, ModelGraphFormat};
 pub use model_registry::{
 	FieldMetadata, ManyToManyMetadata, ModelMetadata, ModelRegistry, RelationshipMetadata,
 	global_registry,
@@ -138,6 +142,7 @@ pub use schema_diff::{
 	SchemaDiffResult, TableSchema,
 };
 pub use schema_editor::SchemaEditor;
+pub use seeds::{Seed, SeedReport, SeedRunner};
 pub use service::MigrationService;
 pub use source::{
 	MigrationSource, composite::CompositeSource, filesystem::FilesystemSource,
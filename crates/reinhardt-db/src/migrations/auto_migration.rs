@@ -270,6 +270,7 @@ impl AutoMigrationGenerator {
 
 				// Other operations - no rollback
 				Operation::AlterTableComment { .. }
+				| Operation::AlterColumnComment { .. }
 				| Operation::AlterUniqueTogether { .. }
 				| Operation::AlterModelOptions { .. }
 				| Operation::CreateInheritedTable { .. }
@@ -435,6 +435,39 @@ impl ModelState {
 		self.fields.contains_key(name)
 	}
 
+	/// Whether migrations manage this model's table.
+	///
+	/// `false` for models declared with `#[model(managed = false)]`, e.g.
+	/// legacy tables or database views maintained outside of migrations.
+	///
+	/// # Examples
+	///
+	/// ```rust,ignore
+	/// use reinhardt_db::migrations::ModelState;
+	///
+	/// let mut model = ModelState::new("legacy", "Report");
+	/// assert!(model.is_managed());
+	///
+	/// model.options.insert("managed".to_string(), "false".to_string());
+	/// assert!(!model.is_managed());
+	/// ```
+	pub fn is_managed(&self) -> bool {
+		self.options.get("managed").map(String::as_str) != Some("false")
+	}
+
+	/// Whether this is a proxy model (`#[model(proxy)]`) sharing the table
+	/// of another model.
+	pub fn is_proxy(&self) -> bool {
+		self.options.get("proxy").map(String::as_str) == Some("true")
+	}
+
+	/// Whether the autodetector generates operations for this model.
+	///
+	/// Unmanaged and proxy models have no schema of their own.
+	pub fn manages_schema(&self) -> bool {
+		self.is_managed() && !self.is_proxy()
+	}
+
 	/// Rename a field
 	///
 	/// # Examples
@@ -950,21 +983,35 @@ impl ProjectState {
 	pub fn from_global_registry() -> Self {
 		use super::model_registry::global_registry;
 
-		let registry = global_registry();
-		let models_metadata = registry.get_models();
+		Self::from_models_metadata(&global_registry().get_models())
+	}
 
+	/// Build a ProjectState from registered model metadata, synthesizing the
+	/// intermediate tables of ManyToMany relationships
+	fn from_models_metadata(models_metadata: &[super::model_registry::ModelMetadata]) -> Self {
 		let mut state = ProjectState::new();
 		let mut intermediate_tables = Vec::new();
 
 		// First, add all regular models
-		for metadata in &models_metadata {
+		for metadata in models_metadata {
 			let model_state = metadata.to_model_state();
 			state.add_model(model_state);
 		}
 
 		// Then, generate intermediate tables for ManyToMany relationships
-		for metadata in &models_metadata {
+		// (unmanaged and proxy models do not own any tables)
+		for metadata in models_metadata {
+			if !metadata.is_managed() || metadata.is_proxy() {
+				continue;
+			}
 			for m2m in &metadata.many_to_many_fields {
+				// A through model already owns the join table
+				if let Some(through) = &m2m.through
+					&& state.find_model_by_table(through).is_some()
+				{
+					continue;
+				}
+
 				// Generate intermediate table for this ManyToMany relationship
 				let intermediate_table = state.create_intermediate_table_for_m2m(
 					&metadata.app_label,
@@ -1175,6 +1222,7 @@ impl ProjectState {
 	/// - AlterColumn: Modifies a field
 	/// - RenameTable: Renames a model's table
 	/// - RenameColumn: Renames a field
+	/// - AlterTableComment / AlterColumnComment: Records the database comment
 	/// - Other operations are logged but not applied to state
 	pub fn apply_migration_operations(
 		&mut self,
@@ -1254,6 +1302,16 @@ impl ProjectState {
 
 					// If model exists, update the field
 					if let Some(model) = self.find_model_by_table_mut(table) {
+						// Column comments are altered by their own operation
+						if let Some(comment) = model
+							.fields
+							.get(column)
+							.and_then(|field| field.params.get("db_comment"))
+						{
+							updated_field
+								.params
+								.insert("db_comment".to_string(), comment.clone());
+						}
 						model.fields.insert(column.to_string(), updated_field);
 					} else {
 						// If model doesn't exist, create it and add the field
@@ -1311,6 +1369,41 @@ impl ProjectState {
 							.retain(|constraint| constraint.name != *constraint_name);
 					}
 				}
+				Operation::AlterTableComment { table, comment } => {
+					if let Some(model) = self.find_model_by_table_mut(table) {
+						match comment {
+							Some(comment) => {
+								model
+									.options
+									.insert("db_table_comment".to_string(), comment.clone());
+							}
+							None => {
+								model.options.remove("db_table_comment");
+							}
+						}
+					}
+				}
+				Operation::AlterColumnComment {
+					table,
+					column,
+					comment,
+				} => {
+					if let Some(field) = self
+						.find_model_by_table_mut(table)
+						.and_then(|model| model.fields.get_mut(column))
+					{
+						match comment {
+							Some(comment) => {
+								field
+									.params
+									.insert("db_comment".to_string(), comment.clone());
+							}
+							None => {
+								field.params.remove("db_comment");
+							}
+						}
+					}
+				}
 				// Other operations don't affect the schema state in ways we track.
 				_ => {
 					// Operations like CreateIndex, DropIndex, RunSQL, etc. are not
@@ -1875,6 +1968,10 @@ pub struct DetectedChanges {
 	pub removed_composite_primary_keys: Vec<(String, String, String)>,
 	/// Auto-increment sequence resets: (app_label, model_name, column_name, value)
 	pub auto_increment_resets: Vec<(String, String, String, i64)>,
+	/// Table comments that changed: (app_label, model_name, new_comment)
+	pub altered_table_comments: Vec<(String, String, Option<String>)>,
+	/// Column comments that changed: (app_label, model_name, field_name, new_comment)
+	pub altered_column_comments: Vec<(String, String, String, Option<String>)>,
 	/// Model dependencies for ordering operations
 	/// Maps (app_label, model_name) -> `Vec<(dependent_app, dependent_model)>`
 	/// A model depends on another if it has ForeignKey or ManyToMany fields pointing to it
@@ -3987,6 +4084,37 @@ impl MigrationAutodetector {
 		}
 	}
 
+	/// Restrict detection to models whose schema is managed by migrations.
+	///
+	/// Unmanaged (`#[model(managed = false)]`) and proxy (`#[model(proxy)]`)
+	/// models are removed from both states, so no operations are generated
+	/// for them, including when a previously managed model becomes unmanaged.
+	/// Returns `None` when every model in `to_state` is managed.
+	fn without_unmanaged_models(&self) -> Option<Self> {
+		let excluded: Vec<(String, String)> = self
+			.to_state
+			.models
+			.iter()
+			.filter(|(_, model)| !model.manages_schema())
+			.map(|(key, _)| key.clone())
+			.collect();
+		if excluded.is_empty() {
+			return None;
+		}
+
+		let mut from_state = self.from_state.clone();
+		let mut to_state = self.to_state.clone();
+		for key in &excluded {
+			from_state.models.remove(key);
+			to_state.models.remove(key);
+		}
+		Some(Self {
+			from_state,
+			to_state,
+			similarity_config: self.similarity_config.clone(),
+		})
+	}
+
 	/// Detect all changes between from_state and to_state
 	///
 	/// Django equivalent: `_detect_changes()` in django/db/migrations/autodetector.py
@@ -4027,6 +4155,10 @@ impl MigrationAutodetector {
 		&self,
 		strict_rename_ambiguity: bool,
 	) -> super::Result<DetectedChanges> {
+		if let Some(detector) = self.without_unmanaged_models() {
+			return detector.detect_changes_internal(strict_rename_ambiguity);
+		}
+
 		let mut changes = DetectedChanges::default();
 
 		// Detect model-level changes
@@ -4047,6 +4179,7 @@ impl MigrationAutodetector {
 		self.detect_removed_constraints(&mut changes);
 		self.detect_composite_pk_changes(&mut changes);
 		self.detect_auto_increment_resets(&mut changes);
+		self.detect_comment_changes(&mut changes);
 
 		// Detect ManyToMany intermediate tables
 		self.detect_created_many_to_many(&mut changes);
@@ -4078,6 +4211,8 @@ impl MigrationAutodetector {
 			.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
 		changes.removed_composite_primary_keys.sort();
 		changes.auto_increment_resets.sort();
+		changes.altered_table_comments.sort();
+		changes.altered_column_comments.sort();
 		changes
 			.created_many_to_many
 			.sort_by(|a, b| (&a.0, &a.1, &a.2).cmp(&(&b.0, &b.1, &b.2)));
@@ -5324,6 +5459,57 @@ impl MigrationAutodetector {
 		}
 	}
 
+	/// Detect changes to table and column comments.
+	///
+	/// Comments come from `ModelState.options["db_table_comment"]` and
+	/// `FieldState.params["db_comment"]`. New tables and columns are compared
+	/// against no comment, so only documented ones produce an operation.
+	fn detect_comment_changes(&self, changes: &mut DetectedChanges) {
+		let mut altered_table_comments = Vec::new();
+		let mut altered_column_comments = Vec::new();
+		for ((app_label, model_name), to_model) in &self.to_state.models {
+			let from_model =
+				self.matching_from_model_for_to_model(app_label, model_name, to_model, changes);
+
+			let to_comment = to_model.options.get("db_table_comment");
+			let from_comment = from_model.and_then(|m| m.options.get("db_table_comment"));
+			if to_comment != from_comment {
+				altered_table_comments.push((
+					app_label.clone(),
+					model_name.clone(),
+					to_comment.cloned(),
+				));
+			}
+
+			for (field_name, to_field) in &to_model.fields {
+				if matches!(to_field.field_type, super::FieldType::ManyToMany { .. }) {
+					continue;
+				}
+				let from_field_name = changes
+					.renamed_fields
+					.iter()
+					.find(|(app, model, _old, new)| {
+						app == app_label && model == model_name && new == field_name
+					})
+					.map_or(field_name, |(_app, _model, old, _new)| old);
+				let to_comment = to_field.params.get("db_comment");
+				let from_comment = from_model
+					.and_then(|m| m.fields.get(from_field_name))
+					.and_then(|f| f.params.get("db_comment"));
+				if to_comment != from_comment {
+					altered_column_comments.push((
+						app_label.clone(),
+						model_name.clone(),
+						field_name.clone(),
+						to_comment.cloned(),
+					));
+				}
+			}
+		}
+		changes.altered_table_comments = altered_table_comments;
+		changes.altered_column_comments = altered_column_comments;
+	}
+
 	/// Generate intermediate table operation for ManyToMany field
 	///
 	/// Creates a through table for ManyToMany relationships with:
@@ -5592,7 +5778,9 @@ impl MigrationAutodetector {
 			| super::Operation::CreateIndex { table, .. }
 			| super::Operation::DropIndex { table, .. }
 			| super::Operation::CreateCompositePrimaryKey { table, .. }
-			| super::Operation::SetAutoIncrementValue { table, .. } => table == table_name,
+			| super::Operation::SetAutoIncrementValue { table, .. }
+			| super::Operation::AlterTableComment { table, .. }
+			| super::Operation::AlterColumnComment { table, .. } => table == table_name,
 			super::Operation::CreateTable { name, .. } | super::Operation::DropTable { name } => {
 				name == table_name
 			}
@@ -6031,6 +6219,29 @@ impl MigrationAutodetector {
 				);
 			}
 		}
+
+		// AlterTableComment / AlterColumnComment for changed doc comments.
+		for (app_label, model_name, comment) in &changes.altered_table_comments {
+			if let Some(model) = self.to_state.get_model(app_label, model_name) {
+				by_app.entry(app_label.clone()).or_default().push(
+					super::Operation::AlterTableComment {
+						table: model.table_name.clone(),
+						comment: comment.clone(),
+					},
+				);
+			}
+		}
+		for (app_label, model_name, field_name, comment) in &changes.altered_column_comments {
+			if let Some(model) = self.to_state.get_model(app_label, model_name) {
+				by_app.entry(app_label.clone()).or_default().push(
+					super::Operation::AlterColumnComment {
+						table: model.table_name.clone(),
+						column: field_name.clone(),
+						comment: comment.clone(),
+					},
+				);
+			}
+		}
 	}
 
 	/// Generate migrations from detected changes
@@ -8584,6 +8795,121 @@ mod tests {
 		);
 	}
 
+	#[rstest]
+	fn detect_doc_comment_changes_emit_comment_operations() {
+		// Arrange
+		let id_field = FieldState::new("id", super::super::FieldType::BigInteger, false);
+		let mut title_field =
+			FieldState::new("title", super::super::FieldType::VarChar(200), false);
+		let from_model = build_model_state(
+			"blog",
+			"Post",
+			vec![id_field.clone(), title_field.clone()],
+			Vec::new(),
+			Vec::new(),
+		);
+		title_field.params.insert(
+			"db_comment".to_string(),
+			"Headline shown in listings".to_string(),
+		);
+		let mut to_model = build_model_state(
+			"blog",
+			"Post",
+			vec![id_field, title_field],
+			Vec::new(),
+			Vec::new(),
+		);
+		to_model.options.insert(
+			"db_table_comment".to_string(),
+			"Published articles".to_string(),
+		);
+
+		let from_state =
+			build_project_state(vec![(("blog".to_string(), "Post".to_string()), from_model)]);
+		let to_state =
+			build_project_state(vec![(("blog".to_string(), "Post".to_string()), to_model)]);
+		let detector = MigrationAutodetector::new(from_state, to_state);
+
+		// Act
+		let operations = detector.generate_operations();
+
+		// Assert
+		assert_eq!(
+			operations,
+			vec![
+				super::super::Operation::AlterTableComment {
+					table: "blog_post".to_string(),
+					comment: Some("Published articles".to_string()),
+				},
+				super::super::Operation::AlterColumnComment {
+					table: "blog_post".to_string(),
+					column: "title".to_string(),
+					comment: Some("Headline shown in listings".to_string()),
+				},
+			]
+		);
+	}
+
+	#[rstest]
+	fn replayed_comment_operations_leave_no_changes() {
+		// Arrange
+		let mut from_state = ProjectState::new();
+		from_state.apply_migration_operations(
+			&[
+				super::super::Operation::CreateTable {
+					name: "blog_post".to_string(),
+					columns: vec![super::super::ColumnDefinition::new(
+						"title",
+						super::super::FieldType::VarChar(200),
+					)],
+					constraints: Vec::new(),
+					without_rowid: None,
+					interleave_in_parent: None,
+					partition: None,
+				},
+				super::super::Operation::AlterTableComment {
+					table: "blog_post".to_string(),
+					comment: Some("Published articles".to_string()),
+				},
+				super::super::Operation::AlterColumnComment {
+					table: "blog_post".to_string(),
+					column: "title".to_string(),
+					comment: Some("Headline".to_string()),
+				},
+			],
+			"blog",
+		);
+		let to_state = from_state.clone();
+		let mut from_state_without_column_comment = from_state.clone();
+		from_state_without_column_comment.apply_migration_operations(
+			&[super::super::Operation::AlterColumnComment {
+				table: "blog_post".to_string(),
+				column: "title".to_string(),
+				comment: None,
+			}],
+			"blog",
+		);
+
+		// Act
+		let unchanged = MigrationAutodetector::new(from_state, to_state.clone()).detect_changes();
+		let restored = MigrationAutodetector::new(from_state_without_column_comment, to_state)
+			.detect_changes();
+
+		// Assert
+		assert!(unchanged.altered_table_comments.is_empty());
+		assert!(unchanged.altered_column_comments.is_empty());
+		assert!(restored.altered_table_comments.is_empty());
+		assert_eq!(
+			restored.altered_column_comments,
+			vec![(
+				"blog".to_string(),
+				"Post".to_string(),
+				"title".to_string(),
+				Some("Headline".to_string()),
+			)]
+		);
+	}
+
 	#[rstest]
 	fn detect_added_unique_together_emits_add_constraint() {
 		// Arrange — same model in both states, but to_state adds a UNIQUE
@@ -9217,6 +9543,57 @@ mod tests {
 		);
 	}
 
+	#[rstest]
+	fn through_model_owns_the_only_create_table_for_its_join_table() {
+		// Arrange
+		let pk = || {
+			super::super::model_registry::FieldMetadata::new(super::super::FieldType::BigInteger)
+				.with_param("primary_key", "true")
+				.with_param("auto_increment", "true")
+				.with_nullable(false)
+		};
+		let mut article =
+			super::super::model_registry::ModelMetadata::new("blog", "Article", "blog_article");
+		article.add_field("id".to_string(), pk());
+		article.add_many_to_many(
+			ManyToManyMetadata::new("tags", "Tag").with_through("blog_article_tag"),
+		);
+		let mut tag = super::super::model_registry::ModelMetadata::new("blog", "Tag", "blog_tag");
+		tag.add_field("id".to_string(), pk());
+		let mut article_tag = super::super::model_registry::ModelMetadata::new(
+			"blog",
+			"ArticleTag",
+			"blog_article_tag",
+		);
+		article_tag.add_field("id".to_string(), pk());
+		for column in ["article_id", "tag_id"] {
+			article_tag.add_field(
+				column.to_string(),
+				super::super::model_registry::FieldMetadata::new(
+					super::super::FieldType::BigInteger,
+				)
+				.with_nullable(false),
+			);
+		}
+		let to_state = ProjectState::from_models_metadata(&[article, tag, article_tag]);
+		let detector = MigrationAutodetector::new(ProjectState::new(), to_state);
+
+		// Act
+		let operations = detector.generate_operations();
+
+		// Assert
+		let join_tables = operations
+			.iter()
+			.filter(|op| {
+				matches!(
+					op,
+					super::super::Operation::CreateTable { name, .. } if name == "blog_article_tag"
+				)
+			})
+			.count();
+		assert_eq!(join_tables, 1, "operations: {:?}", operations);
+	}
+
 	#[rstest]
 	fn generate_operations_no_spurious_altercolumn_for_replayed_foreign_key_column() {
 		// Arrange — regression for the basis tutorial migration check.
@@ -10141,4 +10518,57 @@ mod tests {
 			remaining[0]
 		);
 	}
+
+	#[rstest]
+	#[case::unmanaged("managed", "false")]
+	#[case::proxy("proxy", "true")]
+	fn unmanaged_and_proxy_models_generate_no_operations(
+		#[case] option: &str,
+		#[case] value: &str,
+	) {
+		// Arrange
+		let id_field = FieldState::new("id", super::super::FieldType::Integer, false);
+		let mut report =
+			build_model_state("legacy", "Report", vec![id_field], Vec::new(), Vec::new());
+		report.options.insert(option.to_string(), value.to_string());
+		let to_state =
+			build_project_state(vec![(("legacy".to_string(), "Report".to_string()), report)]);
+		let detector = MigrationAutodetector::new(ProjectState::new(), to_state);
+
+		// Act
+		let changes = detector.detect_changes();
+
+		// Assert
+		assert!(changes.created_models.is_empty());
+		assert!(detector.generate_operations().is_empty());
+	}
+
+	#[rstest]
+	fn model_becoming_unmanaged_is_not_deleted() {
+		// Arrange
+		let id_field = FieldState::new("id", super::super::FieldType::Integer, false);
+		let managed = build_model_state(
+			"legacy",
+			"Report",
+			vec![id_field.clone()],
+			Vec::new(),
+			Vec::new(),
+		);
+		let mut unmanaged =
+			build_model_state("legacy", "Report", vec![id_field], Vec::new(), Vec::new());
+		unmanaged
+			.options
+			.insert("managed".to_string(), "false".to_string());
+		let key = ("legacy".to_string(), "Report".to_string());
+		let from_state = build_project_state(vec![(key.clone(), managed)]);
+		let to_state = build_project_state(vec![(key, unmanaged)]);
+		let detector = MigrationAutodetector::new(from_state, to_state);
+
+		// Act
+		let changes = detector.detect_changes();
+
+		// Assert
+		assert!(changes.deleted_models.is_empty());
+		assert!(detector.generate_operations().is_empty());
+	}
 }
@@ -8,13 +8,17 @@
 //! - **Schema Reading**: Uses `DatabaseIntrospector` to read existing database schemas
 //! - **Type Mapping**: Maps SQL types to Rust types with proper nullable handling
 //! - **Code Generation**: Generates `#[model(...)]` annotated Rust structs
-//! - **Relationship Detection**: Automatically detects FK relationships
+//! - **Relationship Detection**: Single-column foreign keys become `#[rel(...)]` fields
+//! - **Review Notes**: Ambiguous mappings (unknown types, composite keys and
+//!   indexes, CHECK constraints) are flagged with `TODO` doc comments
+//! - **Unmanaged by Default**: Models are emitted with `managed = false` so
+//!   migrations leave the existing tables alone
 //! - **Configuration**: TOML-based configuration for customization
 //!
 //! ## Usage
 //!
 //! ```bash
-//! cargo run --bin manage introspect -d postgres://localhost/mydb -o src/models/
+//! cargo run --bin manage inspectdb -d postgres://localhost/mydb -o src/models/
 //! ```
 //!
 //! ## Configuration
@@ -31,6 +35,7 @@
 //! [generation]
 //! app_label = "myapp"
 //! detect_relationships = true
+//! managed = false
 //!
 //! [tables]
 //! include = [".*"]
@@ -206,6 +206,13 @@ pub struct GenerationConfig {
 	#[serde(default = "default_true")]
 	pub detect_relationships: bool,
 
+	/// Let migrations manage the generated models.
+	///
+	/// Defaults to `false`: introspected tables already exist, so models are
+	/// emitted with `managed = false` until they are adopted explicitly.
+	#[serde(default)]
+	pub managed: bool,
+
 	/// Derive macros to add to generated structs
 	#[serde(default = "default_derives")]
 	pub derives: Vec<String>,
@@ -241,6 +248,7 @@ impl Default for GenerationConfig {
 		Self {
 			app_label: "app".to_string(),
 			detect_relationships: true,
+			managed: false,
 			derives: default_derives(),
 			include_column_comments: true,
 			struct_naming: NamingConventionConfig::default(),
@@ -381,6 +389,7 @@ mod tests {
 			PathBuf::from("src/models/generated")
 		);
 		assert!(config.generation.detect_relationships);
+		assert!(!config.generation.managed);
 		assert!(!config.output.single_file);
 	}
 
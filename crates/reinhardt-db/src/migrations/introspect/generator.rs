@@ -5,6 +5,7 @@
 use super::config::IntrospectConfig;
 use super::naming::{column_to_field_name, sanitize_identifier, table_to_struct_name};
 use super::type_mapping::TypeMapper;
+use crate::migrations::fields::FieldType;
 use crate::migrations::introspection::{ColumnInfo, DatabaseSchema, TableInfo};
 use crate::migrations::{MigrationError, Result};
 use chrono::Utc;
@@ -57,6 +58,22 @@ impl GeneratedFile {
 	}
 }
 
+/// A single-column foreign key rendered as a relation field.
+struct Relation {
+	/// Rust field name of the relation (without the `_id` suffix)
+	field_name: String,
+	/// Referenced table
+	target_table: String,
+	/// Struct name generated for the referenced table
+	target: String,
+	/// Reverse accessor name on the referenced model
+	related_name: String,
+	/// `on_delete` action, if the database declares one
+	on_delete: Option<&'static str>,
+	/// Whether the key is unique (rendered as `one_to_one`)
+	unique: bool,
+}
+
 /// Code generator for database models.
 pub struct SchemaCodeGenerator {
 	config: IntrospectConfig,
@@ -130,6 +147,11 @@ impl SchemaCodeGenerator {
 	) -> Result<GeneratedFile> {
 		let header = self.generate_header();
 		let imports = self.generate_imports();
+		let relation_imports = self.generate_relation_imports(tables.iter().any(|t| {
+			!self
+				.resolve_relations(t, table_to_struct, schema, &mut Vec::new())
+				.is_empty()
+		}));
 
 		let mut models = Vec::new();
 		for table in tables {
@@ -140,6 +162,7 @@ impl SchemaCodeGenerator {
 		let tokens = quote! {
 			#header
 			#imports
+			#relation_imports
 
 			#(#models)*
 		};
@@ -163,11 +186,30 @@ impl SchemaCodeGenerator {
 	) -> Result<GeneratedFile> {
 		let header = self.generate_header();
 		let imports = self.generate_imports();
+		let relation_imports = self.generate_relation_imports(
+			!self
+				.resolve_relations(table, table_to_struct, schema, &mut Vec::new())
+				.is_empty(),
+		);
+		// Related models live in sibling modules
+		let related_imports = self
+			.related_models(table, table_to_struct, schema)
+			.into_iter()
+			.map(|(related_table, struct_name)| {
+				let module = format_ident!(
+					"{}",
+					sanitize_identifier(&super::naming::to_snake_case(&related_table))
+				);
+				let struct_ident = format_ident!("{}", struct_name);
+				quote! { use super::#module::#struct_ident; }
+			});
 		let model = self.generate_model(table, table_to_struct, schema)?;
 
 		let tokens = quote! {
 			#header
 			#imports
+			#relation_imports
+			#(#related_imports)*
 
 			#model
 		};
@@ -216,6 +258,12 @@ impl SchemaCodeGenerator {
 		Ok(GeneratedFile::new(path, content))
 	}
 
+	/// Generate the relation marker imports when relation fields are emitted.
+	fn generate_relation_imports(&self, has_relations: bool) -> Option<TokenStream> {
+		has_relations
+			.then(|| quote! { use reinhardt::db::associations::{ForeignKeyField, OneToOneField}; })
+	}
+
 	/// Generate the file header comment.
 	fn generate_header(&self) -> TokenStream {
 		let timestamp = Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
@@ -225,12 +273,12 @@ impl SchemaCodeGenerator {
 		let display_url = mask_password_in_url(db_url);
 
 		// Build header comments as doc attributes
-		let comment1 = "Generated by `reinhardt introspect` - DO NOT EDIT";
-		let comment2 = format!("Source: {}", display_url);
-		let comment3 = format!("Generated at: {}", timestamp);
+		let comment1 = " Generated by `manage inspectdb`.";
+		let comment2 = format!(" Source: {}", display_url);
+		let comment3 = format!(" Generated at: {}", timestamp);
 		let comment4 = "";
-		let comment5 = "To regenerate, run:";
-		let comment6 = "  cargo run --bin manage introspect";
+		let comment5 = " Type mapping is best-effort: review the TODO notes before use.";
+		let comment6 = " To regenerate, run: cargo run --bin manage inspectdb";
 
 		quote! {
 			#![doc = #comment1]
@@ -268,8 +316,8 @@ impl SchemaCodeGenerator {
 	fn generate_model(
 		&self,
 		table: &TableInfo,
-		_table_to_struct: &HashMap<String, String>,
-		_schema: &DatabaseSchema,
+		table_to_struct: &HashMap<String, String>,
+		schema: &DatabaseSchema,
 	) -> Result<TokenStream> {
 		let struct_name = table_to_struct_name(
 			&table.name,
@@ -288,21 +336,99 @@ impl SchemaCodeGenerator {
 			.filter_map(|d| d.parse().ok())
 			.collect();
 
-		// Generate fields
-		let mut fields = Vec::new();
-		let columns: Vec<_> = table.columns.values().collect();
+		let mut notes = Vec::new();
+		match table.primary_key.len() {
+			0 => notes.push(format!(
+				"TODO: `{}` has no primary key; mark a unique column with `#[field(primary_key = true)]`",
+				table.name
+			)),
+			1 => {}
+			_ => notes.push(format!(
+				"TODO: composite primary key ({}); check that it matches how rows are addressed",
+				table.primary_key.join(", ")
+			)),
+		}
+
+		let relations = self.resolve_relations(table, table_to_struct, schema, &mut notes);
+
+		// Primary key columns first, then by name, so regenerated output is stable
+		let mut columns: Vec<_> = table.columns.values().collect();
+		columns.sort_by_key(|c| (!table.primary_key.contains(&c.name), c.name.clone()));
 
+		let mut fields = Vec::new();
 		for column in &columns {
-			let field = self.generate_field(table, column)?;
+			let field = match relations.get(&column.name) {
+				Some(relation) => self.generate_relation_field(table, column, relation),
+				None => self.generate_field(table, column)?,
+			};
 			fields.push(field);
 		}
 
-		// Generate doc comment
-		let doc_comment = format!("Represents the `{}` table", table_name);
+		// Multi-column UNIQUE constraints and indexes become model constraints
+		let mut unique_sets: Vec<(&str, &[String])> = table
+			.unique_constraints
+			.iter()
+			.filter(|c| c.columns.len() > 1)
+			.map(|c| (c.name.as_str(), c.columns.as_slice()))
+			.collect();
+		let mut indexes: Vec<_> = table.indexes.values().collect();
+		indexes.sort_by(|a, b| a.name.cmp(&b.name));
+		for index in indexes {
+			if index.columns.len() < 2 {
+				continue;
+			}
+			if index.unique {
+				if !unique_sets
+					.iter()
+					.any(|(_, cols)| *cols == index.columns.as_slice())
+				{
+					unique_sets.push((index.name.as_str(), index.columns.as_slice()));
+				}
+			} else {
+				notes.push(format!(
+					"TODO: composite index `{}` on ({}) is not declared on the model",
+					index.name,
+					index.columns.join(", ")
+				));
+			}
+		}
+		for check in &table.check_constraints {
+			let name = check.name.as_deref().unwrap_or("<unnamed>");
+			notes.push(format!(
+				"TODO: CHECK constraint `{}` ({}) is not declared on the model",
+				name, check.expression
+			));
+		}
+
+		let mut model_args = vec![
+			quote! { app_label = #app_label },
+			quote! { table_name = #table_name },
+		];
+		if !self.config.generation.managed {
+			model_args.push(quote! { managed = false });
+		}
+		if !unique_sets.is_empty() {
+			let constraints = unique_sets.iter().map(|(name, columns)| {
+				let columns = columns.iter();
+				quote! { unique(fields = [#(#columns),*], name = #name) }
+			});
+			model_args.push(quote! { constraints = [#(#constraints),*] });
+		}
+
+		// Generate doc comment, preferring the table comment stored in the database
+		let doc_comment = match &table.comment {
+			Some(comment) => comment_doc(comment),
+			None => {
+				let doc_comment = format!(" Represents the `{}` table", table_name);
+				quote! { #[doc = #doc_comment] }
+			}
+		};
+		let note_docs = doc_lines(&notes);
 
 		Ok(quote! {
-			#[doc = #doc_comment]
-			#[model(app_label = #app_label, table_name = #table_name)]
+			#doc_comment
+			#note_docs
+			#[model(#(#model_args),*)]
 			#[derive(#(#derives),*)]
 			pub struct #struct_ident {
 				#(#fields)*
@@ -310,6 +436,161 @@ impl SchemaCodeGenerator {
 		})
 	}
 
+	/// Resolve which foreign key columns become relation fields.
+	///
+	/// Only single-column foreign keys that reference the primary key of
+	/// another generated model are turned into `#[rel(...)]` fields; every
+	/// other foreign key stays a plain column and gets a TODO note.
+	fn resolve_relations(
+		&self,
+		table: &TableInfo,
+		table_to_struct: &HashMap<String, String>,
+		schema: &DatabaseSchema,
+		notes: &mut Vec<String>,
+	) -> HashMap<String, Relation> {
+		let mut relations = HashMap::new();
+		if !self.config.generation.detect_relationships {
+			return relations;
+		}
+
+		let mut foreign_keys: Vec<_> = table.foreign_keys.iter().collect();
+		foreign_keys.sort_by(|a, b| a.name.cmp(&b.name));
+
+		for fk in foreign_keys {
+			let references = format!(
+				"{}({})",
+				fk.referenced_table,
+				fk.referenced_columns.join(", ")
+			);
+			let [column] = fk.columns.as_slice() else {
+				notes.push(format!(
+					"TODO: composite foreign key `{}` ({}) -> {} is kept as plain columns",
+					fk.name,
+					fk.columns.join(", "),
+					references
+				));
+				continue;
+			};
+			let Some(target) = table_to_struct.get(&fk.referenced_table) else {
+				notes.push(format!(
+					"TODO: `{}` references {}, which was not introspected; kept as a plain column",
+					column, references
+				));
+				continue;
+			};
+			if table.primary_key.contains(column) {
+				notes.push(format!(
+					"TODO: primary key `{}` also references {}; kept as a plain column",
+					column, references
+				));
+				continue;
+			}
+			let targets_primary_key = schema
+				.tables
+				.get(&fk.referenced_table)
+				.is_some_and(|t| t.primary_key == fk.referenced_columns);
+			if !targets_primary_key {
+				notes.push(format!(
+					"TODO: `{}` references {}, which is not its primary key; kept as a plain column",
+					column, references
+				));
+				continue;
+			}
+
+			let field_name = column.strip_suffix("_id").unwrap_or(column);
+			let field_name =
+				column_to_field_name(field_name, self.config.generation.field_naming_convention());
+			// `{field}_id` is generated by the model macro, so it must not clash
+			// with another column
+			if table
+				.columns
+				.keys()
+				.any(|c| c != column && (*c == field_name || *c == format!("{}_id", field_name)))
+			{
+				notes.push(format!(
+					"TODO: `{}` references {}, but its relation field would clash with another column; kept as a plain column",
+					column, references
+				));
+				continue;
+			}
+
+			let unique = table
+				.unique_constraints
+				.iter()
+				.any(|c| c.columns == fk.columns)
+				|| table
+					.indexes
+					.values()
+					.any(|i| i.unique && i.columns == fk.columns);
+			// Disambiguate reverse accessors when several keys point at the same table
+			let siblings = table
+				.foreign_keys
+				.iter()
+				.filter(|other| other.referenced_table == fk.referenced_table)
+				.count();
+			let table_snake = super::naming::to_snake_case(&table.name);
+			let related_name = if siblings > 1 {
+				format!("{}_{}", table_snake, field_name.trim_start_matches("r#"))
+			} else {
+				table_snake
+			};
+
+			relations.insert(
+				column.clone(),
+				Relation {
+					field_name,
+					target_table: fk.referenced_table.clone(),
+					target: target.clone(),
+					related_name,
+					on_delete: fk.on_delete.as_deref().and_then(cascade_action),
+					unique,
+				},
+			);
+		}
+
+		relations
+	}
+
+	/// Generate a `#[rel(foreign_key)]` / `#[rel(one_to_one)]` field for a
+	/// foreign key column.
+	fn generate_relation_field(
+		&self,
+		table: &TableInfo,
+		column: &ColumnInfo,
+		relation: &Relation,
+	) -> TokenStream {
+		let field_ident = format_ident!("{}", relation.field_name);
+		let target = format_ident!("{}", relation.target);
+		let related_name = &relation.related_name;
+
+		let (kind, field_type) = if relation.unique {
+			(quote! { one_to_one }, quote! { OneToOneField<#target> })
+		} else {
+			(quote! { foreign_key }, quote! { ForeignKeyField<#target> })
+		};
+
+		let mut args = vec![kind, quote! { related_name = #related_name }];
+		if let Some(action) = relation.on_delete {
+			let action = format_ident!("{}", action);
+			args.push(quote! { on_delete = #action });
+		}
+		if column.nullable {
+			args.push(quote! { null = true });
+		}
+		if format!("{}_id", relation.field_name.trim_start_matches("r#")) != column.name {
+			let db_column = &column.name;
+			args.push(quote! { db_column = #db_column });
+		}
+
+		let doc = self.column_doc(column);
+
+		quote! {
+			#doc
+			#[rel(#(#args),*)]
+			pub #field_ident: #field_type,
+		}
+	}
+
 	/// Generate a field for a column.
 	fn generate_field(&self, table: &TableInfo, column: &ColumnInfo) -> Result<TokenStream> {
 		let field_name = column_to_field_name(
@@ -330,6 +611,7 @@ impl SchemaCodeGenerator {
 
 		// Generate field attributes
 		let mut attrs = Vec::new();
+		let mut notes = Vec::new();
 
 		// Primary key attribute
 		if table.primary_key.contains(&column.name) {
@@ -341,16 +623,36 @@ impl SchemaCodeGenerator {
 		}
 
 		// Unique attribute
+		let is_single_column = |columns: &[String]| columns.len() == 1 && columns[0] == column.name;
 		let is_unique = table
 			.unique_constraints
 			.iter()
-			.any(|c| c.columns.len() == 1 && c.columns.contains(&column.name));
+			.any(|c| is_single_column(&c.columns))
+			|| table
+				.indexes
+				.values()
+				.any(|i| i.unique && is_single_column(&i.columns));
 		if is_unique && !table.primary_key.contains(&column.name) {
 			attrs.push(quote! { #[field(unique = true)] });
 		}
 
+		// Plain (non-unique) single-column index
+		let is_indexed = table
+			.indexes
+			.values()
+			.any(|i| !i.unique && is_single_column(&i.columns));
+		if is_indexed && !is_unique {
+			attrs.push(quote! { #[field(index = true)] });
+		}
+
+		// Keep the database column name when the field had to be renamed
+		if field_name.trim_start_matches("r#") != column.name {
+			let db_column = &column.name;
+			attrs.push(quote! { #[field(db_column = #db_column)] });
+		}
+
 		// Max length for varchar
-		if let crate::migrations::fields::FieldType::VarChar(len) = &column.column_type {
+		if let FieldType::VarChar(len) = &column.column_type {
 			let len = *len;
 			attrs.push(quote! { #[field(max_length = #len)] });
 		}
@@ -364,21 +666,61 @@ impl SchemaCodeGenerator {
 			}
 		}
 
-		// Generate doc comment if enabled
-		let doc = if self.config.generation.include_column_comments {
-			let comment = format!("Column: `{}`", column.name);
-			Some(quote! { #[doc = #comment] })
-		} else {
-			None
-		};
+		if self
+			.type_mapper
+			.get_override(&table.name, &column.name)
+			.is_none()
+			&& let Some(note) = ambiguous_type_note(&column.column_type)
+		{
+			notes.push(note);
+		}
+
+		let doc = self.column_doc(column);
+		let note_docs = doc_lines(&notes);
 
 		Ok(quote! {
 			#doc
+			#note_docs
 			#(#attrs)*
 			pub #field_ident: #rust_type,
 		})
 	}
 
+	/// Generate the column doc comment if enabled.
+	///
+	/// Uses the column comment stored in the database when there is one.
+	fn column_doc(&self, column: &ColumnInfo) -> Option<TokenStream> {
+		if !self.config.generation.include_column_comments {
+			return None;
+		}
+		match &column.comment {
+			Some(comment) => Some(comment_doc(comment)),
+			None => {
+				let comment = format!(" Column: `{}`", column.name);
+				Some(quote! { #[doc = #comment] })
+			}
+		}
+	}
+
+	/// `(table, struct)` pairs referenced by relation fields of `table`,
+	/// excluding `table` itself.
+	fn related_models(
+		&self,
+		table: &TableInfo,
+		table_to_struct: &HashMap<String, String>,
+		schema: &DatabaseSchema,
+	) -> Vec<(String, String)> {
+		let mut related: Vec<(String, String)> = self
+			.resolve_relations(table, table_to_struct, schema, &mut Vec::new())
+			.into_values()
+			.filter(|relation| relation.target_table != table.name)
+			.map(|relation| (relation.target_table, relation.target))
+			.collect();
+		related.sort();
+		related.dedup();
+		related
+	}
+
 	/// Format TokenStream to pretty Rust code.
 	fn format_tokens(&self, tokens: TokenStream) -> Result<String> {
 		let syntax_tree = syn::parse2::<syn::File>(tokens).map_err(|e| {
@@ -389,6 +731,59 @@ impl SchemaCodeGenerator {
 	}
 }
 
+/// Render notes as doc comment lines.
+fn doc_lines(notes: &[String]) -> TokenStream {
+	let lines = notes.iter().map(|note| format!(" {}", note));
+	quote! { #(#[doc = #lines])* }
+}
+
+/// Doc attributes for a database comment, one per line.
+fn comment_doc(comment: &str) -> TokenStream {
+	let lines = comment.lines().map(|line| format!(" {}", line.trim_end()));
+	quote! { #(#[doc = #lines])* }
+}
+
+/// Map a referential action to the `#[rel(on_delete = ...)]` identifier.
+///
+/// `NO ACTION` is the relation default, so it maps to `None`.
+fn cascade_action(action: &str) -> Option<&'static str> {
+	match action.to_uppercase().as_str() {
+		"CASCADE" => Some("Cascade"),
+		"SET NULL" => Some("SetNull"),
+		"SET DEFAULT" => Some("SetDefault"),
+		"RESTRICT" => Some("Restrict"),
+		_ => None,
+	}
+}
+
+/// Describe a best-effort type mapping that needs a manual check.
+fn ambiguous_type_note(field_type: &FieldType) -> Option<String> {
+	match field_type {
+		FieldType::Custom(name) => Some(format!(
+			"TODO: unrecognized column type `{}`; verify the Rust type or add a type override",
+			name
+		)),
+		FieldType::Enum { values } | FieldType::Set { values } => Some(format!(
+			"TODO: values ({}) are mapped to strings; consider a Rust enum",
+			values.join(", ")
+		)),
+		FieldType::Int4Range
+		| FieldType::Int8Range
+		| FieldType::NumRange
+		| FieldType::DateRange
+		| FieldType::TsRange
+		| FieldType::TsTzRange => Some(
+			"TODO: range bounds are mapped to a tuple; inclusivity and unbounded ends are lost"
+				.to_string(),
+		),
+		FieldType::Array(inner) if matches!(**inner, FieldType::Custom(_)) => Some(format!(
+			"TODO: array of unrecognized type `{}`; verify the Rust type",
+			inner
+		)),
+		_ => None,
+	}
+}
+
 /// Check if a default value is auto-generated (e.g., NOW(), sequences).
 fn is_auto_default(default: &str) -> bool {
 	let upper = default.to_uppercase();
@@ -423,7 +818,11 @@ fn mask_password_in_url(url: &str) -> String {
 mod tests {
 	use super::*;
 	use crate::migrations::fields::FieldType;
-	use crate::migrations::introspection::{ColumnInfo, TableInfo, UniqueConstraintInfo};
+	use crate::migrations::introspection::{
+		ColumnInfo, ForeignKeyInfo, IndexInfo, TableInfo, UniqueConstraintInfo,
+	};
+	use quote::ToTokens;
+	use rstest::rstest;
 	use std::collections::HashMap;
 
 	fn create_test_table() -> TableInfo {
@@ -437,6 +836,7 @@ mod tests {
 				nullable: false,
 				default: None,
 				auto_increment: true,
+				comment: None,
 			},
 		);
 
@@ -448,6 +848,7 @@ mod tests {
 				nullable: false,
 				default: None,
 				auto_increment: false,
+				comment: None,
 			},
 		);
 
@@ -459,6 +860,7 @@ mod tests {
 				nullable: true,
 				default: None,
 				auto_increment: false,
+				comment: None,
 			},
 		);
 
@@ -473,33 +875,406 @@ mod tests {
 				columns: vec!["email".to_string()],
 			}],
 			check_constraints: vec![],
+			comment: None,
 		}
 	}
 
-	#[test]
-	fn test_generate_model() {
-		let config = IntrospectConfig::default().with_app_label("test");
-		let generator = SchemaCodeGenerator::new(config);
+	fn column(name: &str, column_type: FieldType, nullable: bool) -> ColumnInfo {
+		ColumnInfo {
+			name: name.to_string(),
+			column_type,
+			nullable,
+			default: None,
+			auto_increment: false,
+			comment: None,
+		}
+	}
 
-		let table = create_test_table();
-		let table_to_struct: HashMap<String, String> =
-			[("users".to_string(), "Users".to_string())].into();
+	/// Table with an `id` primary key followed by `columns`
+	fn table_with_columns(name: &str, columns: Vec<ColumnInfo>) -> TableInfo {
+		TableInfo {
+			name: name.to_string(),
+			columns: std::iter::once(column("id", FieldType::BigInteger, false))
+				.chain(columns)
+				.map(|c| (c.name.clone(), c))
+				.collect(),
+			indexes: HashMap::new(),
+			primary_key: vec!["id".to_string()],
+			foreign_keys: vec![],
+			unique_constraints: vec![],
+			check_constraints: vec![],
+			comment: None,
+		}
+	}
+
+	fn foreign_key(
+		column: &str,
+		referenced_table: &str,
+		on_delete: Option<&str>,
+	) -> ForeignKeyInfo {
+		ForeignKeyInfo {
+			name: format!("{}_fk", column),
+			columns: vec![column.to_string()],
+			referenced_table: referenced_table.to_string(),
+			referenced_columns: vec!["id".to_string()],
+			on_delete: on_delete.map(str::to_string),
+			on_update: None,
+		}
+	}
 
-		let mut schema = DatabaseSchema {
-			tables: HashMap::new(),
+	/// Generate the model of `table`, with `others` introspected alongside it
+	fn generate_code(table: &TableInfo, others: Vec<TableInfo>) -> String {
+		let generator =
+			SchemaCodeGenerator::new(IntrospectConfig::default().with_app_label("blog"));
+		let schema = DatabaseSchema {
+			tables: std::iter::once(table.clone())
+				.chain(others)
+				.map(|t| (t.name.clone(), t))
+				.collect(),
 		};
-		schema.tables.insert("users".to_string(), table.clone());
+		let table_to_struct: HashMap<String, String> = schema
+			.tables
+			.keys()
+			.map(|name| (name.clone(), table_to_struct_name(name, Default::default())))
+			.collect();
+		let tokens = generator
+			.generate_model(table, &table_to_struct, &schema)
+			.unwrap();
+		generator.format_tokens(tokens).unwrap()
+	}
+
+	/// Normalize `source` to the token string used by the helpers below
+	fn tokens(source: &str) -> String {
+		source.parse::<TokenStream>().unwrap().to_string()
+	}
 
-		let result = generator.generate_model(&table, &table_to_struct, &schema);
-		assert!(result.is_ok());
+	fn generated_struct(code: &str) -> syn::ItemStruct {
+		syn::parse_file(code)
+			.unwrap()
+			.items
+			.into_iter()
+			.find_map(|item| match item {
+				syn::Item::Struct(item) => Some(item),
+				_ => None,
+			})
+			.unwrap()
+	}
+
+	/// Attributes, visibility, name and type of field `name`, without doc comments
+	fn field_line(code: &str, name: &str) -> String {
+		let mut field = generated_struct(code)
+			.fields
+			.into_iter()
+			.find(|f| f.ident.as_ref().is_some_and(|i| i == name))
+			.unwrap();
+		field.attrs.retain(|attr| !attr.path().is_ident("doc"));
+		field.to_token_stream().to_string()
+	}
+
+	fn model_attribute(code: &str) -> String {
+		generated_struct(code)
+			.attrs
+			.iter()
+			.find(|attr| attr.path().is_ident("model"))
+			.unwrap()
+			.to_token_stream()
+			.to_string()
+	}
 
-		let tokens = result.unwrap();
-		let code = generator.format_tokens(tokens).unwrap();
+	fn struct_docs(code: &str) -> Vec<String> {
+		generated_struct(code)
+			.attrs
+			.iter()
+			.filter_map(|attr| match &attr.meta {
+				syn::Meta::NameValue(syn::MetaNameValue {
+					path,
+					value:
+						syn::Expr::Lit(syn::ExprLit {
+							lit: syn::Lit::Str(doc),
+							..
+						}),
+					..
+				}) if path.is_ident("doc") => Some(doc.value()),
+				_ => None,
+			})
+			.collect()
+	}
 
-		assert!(code.contains("pub struct Users"));
-		assert!(code.contains("pub id: i64"));
-		assert!(code.contains("pub name: String"));
-		assert!(code.contains("pub email: Option<String>"));
+	#[rstest]
+	fn test_generate_model() {
+		// Arrange
+		let table = create_test_table();
+
+		// Act
+		let code = generate_code(&table, vec![]);
+
+		// Assert
+		assert_eq!(generated_struct(&code).ident, "Users");
+		assert_eq!(
+			field_line(&code, "id"),
+			tokens("#[field(primary_key = true, auto_increment = true)] pub id: i64")
+		);
+		assert_eq!(
+			field_line(&code, "name"),
+			tokens("#[field(max_length = 255u32)] pub name: String")
+		);
+		assert_eq!(
+			field_line(&code, "email"),
+			tokens(
+				"#[field(unique = true)] #[field(max_length = 255u32)] pub email: Option<String>"
+			)
+		);
+	}
+
+	#[rstest]
+	fn test_generate_model_uses_database_comments() {
+		// Arrange
+		let mut table = create_test_table();
+		table.comment = Some("Registered accounts".to_string());
+		table.columns.get_mut("email").unwrap().comment =
+			Some("Primary contact address".to_string());
+
+		// Act
+		let code = generate_code(&table, vec![]);
+
+		// Assert
+		assert!(code.contains("/// Registered accounts"));
+		assert!(!code.contains("Represents the `users` table"));
+		assert!(code.contains("/// Primary contact address"));
+		assert!(code.contains("/// Column: `name`"));
+	}
+
+	#[rstest]
+	fn test_generate_model_with_relations_and_notes() {
+		// Arrange
+		let mut posts = table_with_columns(
+			"posts",
+			vec![
+				column("author_id", FieldType::BigInteger, true),
+				column("legacy_ref", FieldType::Integer, false),
+				column("mood", FieldType::Custom("mood_type".to_string()), false),
+			],
+		);
+		posts.foreign_keys = vec![
+			foreign_key("author_id", "users", Some("CASCADE")),
+			foreign_key("legacy_ref", "archive", None),
+		];
+
+		// Act
+		let code = generate_code(&posts, vec![create_test_table()]);
+
+		// Assert
+		assert_eq!(
+			model_attribute(&code),
+			tokens(r#"#[model(app_label = "blog", table_name = "posts", managed = false)]"#)
+		);
+		assert_eq!(
+			field_line(&code, "author"),
+			tokens(
+				r#"#[rel(foreign_key, related_name = "posts", on_delete = Cascade, null = true)]
+				pub author: ForeignKeyField<Users>"#
+			)
+		);
+		assert_eq!(
+			field_line(&code, "legacy_ref"),
+			tokens("pub legacy_ref: i32")
+		);
+		assert_eq!(
+			struct_docs(&code),
+			vec![
+				" Represents the `posts` table",
+				" TODO: `legacy_ref` references archive(id), which was not introspected; kept as a plain column",
+			]
+		);
+		assert!(code.contains("TODO: unrecognized column type `mood_type`"));
+	}
+
+	#[rstest]
+	#[case::unique_constraint(
+		vec![UniqueConstraintInfo {
+			name: "memberships_pair".to_string(),
+			columns: vec!["group_id".to_string(), "user_id".to_string()],
+		}],
+		vec![],
+		r#"#[model(app_label = "blog", table_name = "memberships", managed = false,
+			constraints = [unique(fields = ["group_id", "user_id"], name = "memberships_pair")])]"#,
+		vec![]
+	)]
+	#[case::unique_index(
+		vec![],
+		vec![IndexInfo {
+			name: "memberships_pair_idx".to_string(),
+			columns: vec!["group_id".to_string(), "user_id".to_string()],
+			unique: true,
+			index_type: None,
+		}],
+		r#"#[model(app_label = "blog", table_name = "memberships", managed = false,
+			constraints = [unique(fields = ["group_id", "user_id"], name = "memberships_pair_idx")])]"#,
+		vec![]
+	)]
+	#[case::non_unique_index(
+		vec![],
+		vec![IndexInfo {
+			name: "memberships_lookup".to_string(),
+			columns: vec!["group_id".to_string(), "user_id".to_string()],
+			unique: false,
+			index_type: None,
+		}],
+		r#"#[model(app_label = "blog", table_name = "memberships", managed = false)]"#,
+		vec![" TODO: composite index `memberships_lookup` on (group_id, user_id) is not declared on the model"]
+	)]
+	fn test_generate_model_composite_constraints(
+		#[case] unique_constraints: Vec<UniqueConstraintInfo>,
+		#[case] indexes: Vec<IndexInfo>,
+		#[case] expected_model: &str,
+		#[case] expected_notes: Vec<&str>,
+	) {
+		// Arrange
+		let mut memberships = table_with_columns(
+			"memberships",
+			vec![
+				column("group_id", FieldType::BigInteger, false),
+				column("user_id", FieldType::BigInteger, false),
+			],
+		);
+		memberships.unique_constraints = unique_constraints;
+		memberships.indexes = indexes.into_iter().map(|i| (i.name.clone(), i)).collect();
+
+		// Act
+		let code = generate_code(&memberships, vec![]);
+
+		// Assert
+		assert_eq!(model_attribute(&code), tokens(expected_model));
+		assert_eq!(struct_docs(&code)[1..], expected_notes);
+	}
+
+	#[rstest]
+	#[case::renamed_column(
+		column("userName", FieldType::Text, false),
+		vec![],
+		"user_name",
+		r#"#[field(db_column = "userName")] pub user_name: String"#
+	)]
+	#[case::renamed_relation_column(
+		column("owner", FieldType::BigInteger, false),
+		vec![foreign_key("owner", "users", None)],
+		"owner",
+		r#"#[rel(foreign_key, related_name = "posts", db_column = "owner")]
+		pub owner: ForeignKeyField<Users>"#
+	)]
+	fn test_generate_model_keeps_database_column_name(
+		#[case] renamed: ColumnInfo,
+		#[case] foreign_keys: Vec<ForeignKeyInfo>,
+		#[case] field: &str,
+		#[case] expected: &str,
+	) {
+		// Arrange
+		let mut posts = table_with_columns("posts", vec![renamed]);
+		posts.foreign_keys = foreign_keys;
+
+		// Act
+		let code = generate_code(&posts, vec![create_test_table()]);
+
+		// Assert
+		assert_eq!(field_line(&code, field), tokens(expected));
+	}
+
+	#[rstest]
+	#[case::cascade(Some("CASCADE"), "on_delete = Cascade,")]
+	#[case::set_null(Some("SET NULL"), "on_delete = SetNull,")]
+	#[case::set_default(Some("SET DEFAULT"), "on_delete = SetDefault,")]
+	#[case::restrict(Some("RESTRICT"), "on_delete = Restrict,")]
+	#[case::no_action(Some("NO ACTION"), "")]
+	#[case::unspecified(None, "")]
+	fn test_generate_model_maps_on_delete_action(
+		#[case] on_delete: Option<&str>,
+		#[case] expected_action: &str,
+	) {
+		// Arrange
+		let mut posts = table_with_columns(
+			"posts",
+			vec![column("author_id", FieldType::BigInteger, true)],
+		);
+		posts.foreign_keys = vec![foreign_key("author_id", "users", on_delete)];
+
+		// Act
+		let code = generate_code(&posts, vec![create_test_table()]);
+
+		// Assert
+		assert_eq!(
+			field_line(&code, "author"),
+			tokens(&format!(
+				r#"#[rel(foreign_key, related_name = "posts", {} null = true)]
+				pub author: ForeignKeyField<Users>"#,
+				expected_action
+			))
+		);
+	}
+
+	#[rstest]
+	fn test_generate_model_self_referential_foreign_key() {
+		// Arrange
+		let mut categories = table_with_columns(
+			"categories",
+			vec![column("parent_id", FieldType::BigInteger, true)],
+		);
+		categories.foreign_keys = vec![foreign_key("parent_id", "categories", Some("CASCADE"))];
+
+		// Act
+		let code = generate_code(&categories, vec![]);
+
+		// Assert
+		assert_eq!(
+			field_line(&code, "parent"),
+			tokens(
+				r#"#[rel(foreign_key, related_name = "categories", on_delete = Cascade, null = true)]
+				pub parent: ForeignKeyField<Categories>"#
+			)
+		);
+		assert_eq!(
+			struct_docs(&code),
+			vec![" Represents the `categories` table"]
+		);
+	}
+
+	#[rstest]
+	#[case::default_patterns(&[], &["legacy_orders.rs", "mod.rs", "users.rs"])]
+	#[case::custom_pattern(&["^legacy_"], &["mod.rs", "users.rs"])]
+	fn test_generate_excludes_filtered_tables(
+		#[case] extra_excludes: &[&str],
+		#[case] expected_files: &[&str],
+	) {
+		// Arrange
+		let mut config = IntrospectConfig::default().with_app_label("blog");
+		config
+			.tables
+			.exclude
+			.extend(extra_excludes.iter().map(|p| p.to_string()));
+		let generator = SchemaCodeGenerator::new(config);
+		let schema = DatabaseSchema {
+			tables: [
+				"users",
+				"legacy_orders",
+				"auth_user",
+				"django_session",
+				"pg_stats",
+			]
+			.into_iter()
+			.map(|name| (name.to_string(), table_with_columns(name, vec![])))
+			.collect(),
+		};
+
+		// Act
+		let output = generator.generate(&schema).unwrap();
+
+		// Assert
+		let mut files: Vec<_> = output
+			.files
+			.iter()
+			.map(|f| f.path.file_name().unwrap().to_string_lossy().into_owned())
+			.collect();
+		files.sort();
+		assert_eq!(files, expected_files);
 	}
 
 	#[test]
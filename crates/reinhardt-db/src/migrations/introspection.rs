@@ -34,6 +34,8 @@ pub struct TableInfo {
 	pub unique_constraints: Vec<UniqueConstraintInfo>,
 	/// CHECK constraints
 	pub check_constraints: Vec<CheckConstraintInfo>,
+	/// Table comment (None when unset or unsupported by the backend)
+	pub comment: Option<String>,
 }
 
 /// Column metadata
@@ -49,6 +51,8 @@ pub struct ColumnInfo {
 	pub default: Option<String>,
 	/// Whether this is an auto-increment column
 	pub auto_increment: bool,
+	/// Column comment (None when unset or unsupported by the backend)
+	pub comment: Option<String>,
 }
 
 /// Index metadata
@@ -278,7 +282,9 @@ impl PostgresIntrospector {
 		let col_query = r#"
 			SELECT column_name, udt_name, data_type, is_nullable, column_default,
 			       character_maximum_length, numeric_precision, numeric_scale,
-			       is_identity, identity_generation
+			       is_identity, identity_generation,
+			       col_description(format('%I.%I', table_schema, table_name)::regclass,
+			                       ordinal_position::int) AS column_comment
 			FROM information_schema.columns
 			WHERE table_schema = 'public' AND table_name = $1
 			ORDER BY ordinal_position
@@ -330,6 +336,9 @@ impl PostgresIntrospector {
 			let is_identity: String = row.try_get("is_identity").map_err(|e| {
 				MigrationError::IntrospectionError(format!("Failed to get is_identity: {}", e))
 			})?;
+			let column_comment: Option<String> = row.try_get("column_comment").map_err(|e| {
+				MigrationError::IntrospectionError(format!("Failed to get column_comment: {}", e))
+			})?;
 
 			// Detect auto-increment: nextval() in default or identity column
 			let is_auto = column_default
@@ -372,6 +381,7 @@ impl PostgresIntrospector {
 					nullable: is_nullable == "YES",
 					default: column_default,
 					auto_increment: is_auto || is_serial,
+					comment: column_comment,
 				},
 			);
 		}
@@ -509,6 +519,23 @@ impl PostgresIntrospector {
 		// CHECK constraints not yet implemented
 		let check_constraints: Vec<CheckConstraintInfo> = Vec::new();
 
+		// Fetch table comment
+		let comment_query = r#"
+			SELECT obj_description(format('public.%I', $1::text)::regclass, 'pg_class')
+			    AS table_comment
+		"#;
+		let comment: Option<String> = sqlx::query(comment_query)
+			.bind(table_name)
+			.fetch_one(&self.pool)
+			.await
+			.and_then(|row| row.try_get("table_comment"))
+			.map_err(|e| {
+				MigrationError::IntrospectionError(format!(
+					"Failed to fetch comment for table {}: {}",
+					table_name, e
+				))
+			})?;
+
 		Ok(TableInfo {
 			name: table_name.to_string(),
 			columns,
@@ -517,6 +544,7 @@ impl PostgresIntrospector {
 			foreign_keys,
 			unique_constraints,
 			check_constraints,
+			comment,
 		})
 	}
 
@@ -750,7 +778,8 @@ impl MySQLIntrospector {
 		// Fetch columns from information_schema
 		let col_query = r#"
 			SELECT column_name, data_type, column_type, is_nullable, column_default,
-			       column_key, extra, character_maximum_length, numeric_precision, numeric_scale
+			       column_key, extra, character_maximum_length, numeric_precision, numeric_scale,
+			       column_comment
 			FROM information_schema.columns
 			WHERE table_schema = DATABASE() AND table_name = ?
 			ORDER BY ordinal_position
@@ -807,6 +836,9 @@ impl MySQLIntrospector {
 			let numeric_scale: Option<i64> = row.try_get("numeric_scale").map_err(|e| {
 				MigrationError::IntrospectionError(format!("Failed to get numeric_scale: {}", e))
 			})?;
+			let column_comment: String = row.try_get("column_comment").map_err(|e| {
+				MigrationError::IntrospectionError(format!("Failed to get column_comment: {}", e))
+			})?;
 
 			// Primary key detection
 			if column_key == "PRI" {
@@ -832,6 +864,8 @@ impl MySQLIntrospector {
 					nullable: is_nullable == "YES",
 					default: column_default,
 					auto_increment: is_auto,
+					// MySQL reports an unset comment as an empty string
+					comment: (!column_comment.is_empty()).then_some(column_comment),
 				},
 			);
 		}
@@ -949,6 +983,24 @@ impl MySQLIntrospector {
 		// MySQL CHECK constraints not yet implemented
 		let check_constraints: Vec<CheckConstraintInfo> = Vec::new();
 
+		// Fetch table comment
+		let comment_query = r#"
+			SELECT table_comment FROM information_schema.tables
+			WHERE table_schema = DATABASE() AND table_name = ?
+		"#;
+		let comment: Option<String> = sqlx::query(comment_query)
+			.bind(table_name)
+			.fetch_optional(&self.pool)
+			.await
+			.map_err(|e| {
+				MigrationError::IntrospectionError(format!(
+					"Failed to fetch comment for table {}: {}",
+					table_name, e
+				))
+			})?
+			.and_then(|row| row.try_get::<String, _>("table_comment").ok())
+			.filter(|comment| !comment.is_empty());
+
 		Ok(TableInfo {
 			name: table_name.to_string(),
 			columns,
@@ -957,6 +1009,7 @@ impl MySQLIntrospector {
 			foreign_keys,
 			unique_constraints,
 			check_constraints,
+			comment,
 		})
 	}
 }
@@ -1618,6 +1671,7 @@ impl SQLiteIntrospector {
 					nullable,
 					default,
 					auto_increment: is_auto,
+					comment: None,
 				},
 			);
 		}
@@ -1634,6 +1688,7 @@ impl SQLiteIntrospector {
 		// Extract CHECK constraints using existing method
 		let check_constraints = Self::extract_check_constraints(&self.pool, table_name).await?;
 
+		// SQLite has no table or column comments
 		Ok(TableInfo {
 			name: table_name.to_string(),
 			columns,
@@ -1642,6 +1697,7 @@ impl SQLiteIntrospector {
 			foreign_keys,
 			unique_constraints,
 			check_constraints,
+			comment: None,
 		})
 	}
 }
@@ -0,0 +1,631 @@
+//! Model relationship diagrams and schema reference
+//!
+//! This module renders the models registered in the [`ModelRegistry`] as an
+//! entity-relationship diagram (Graphviz DOT or Mermaid) or as a markdown
+//! schema reference listing fields, types, constraints and indexes. It backs
+//! the `graph_models` management command.
+//!
+//! # Example
+//!
+//! ```rust
+//! use reinhardt_db::migrations::model_graph::{ModelGraph, ModelGraphFormat};
+//! use reinhardt_db::migrations::model_registry::{FieldMetadata, ModelMetadata};
+//! use reinhardt_db::migrations::FieldType;
+//!
+//! let mut post = ModelMetadata::new("blog", "Post", "blog_post");
+//! post.add_field(
+//!     "id".to_string(),
+//!     FieldMetadata::new(FieldType::BigInteger).with_param("primary_key", "true"),
+//! );
+//!
+//! let graph = ModelGraph::new(vec![post]);
+//! let diagram = graph.render(ModelGraphFormat::Mermaid);
+//! assert!(diagram.contains("erDiagram"));
+//! ```
+
+use super::model_registry::{FieldMetadata, ModelMetadata, ModelRegistry};
+use super::to_snake_case;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// Output format for model graphs
+///
+/// # Example
+///
+/// ```rust
+/// use reinhardt_db::migrations::model_graph::ModelGraphFormat;
+///
+/// let format: ModelGraphFormat = "mermaid".parse().unwrap();
+/// assert_eq!(format, ModelGraphFormat::Mermaid);
+/// assert_eq!(format.extension(), "mmd");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelGraphFormat {
+	/// DOT graph format (for Graphviz)
+	Dot,
+	/// Mermaid `erDiagram`
+	Mermaid,
+	/// Markdown schema reference
+	Markdown,
+}
+
+impl ModelGraphFormat {
+	/// Get file extension for this format
+	pub fn extension(&self) -> &str {
+		match self {
+			ModelGraphFormat::Dot => "dot",
+			ModelGraphFormat::Mermaid => "mmd",
+			ModelGraphFormat::Markdown => "md",
+		}
+	}
+}
+
+impl FromStr for ModelGraphFormat {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_lowercase().as_str() {
+			"dot" | "graphviz" => Ok(ModelGraphFormat::Dot),
+			"mermaid" | "mmd" => Ok(ModelGraphFormat::Mermaid),
+			"markdown" | "md" => Ok(ModelGraphFormat::Markdown),
+			other => Err(format!(
+				"Unknown format '{}'. Expected one of: dot, mermaid, markdown",
+				other
+			)),
+		}
+	}
+}
+
+/// Kind of relationship between two models
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelationKind {
+	/// Foreign key (many-to-one)
+	ForeignKey,
+	/// Unique foreign key (one-to-one)
+	OneToOne,
+	/// Many-to-many
+	ManyToMany,
+}
+
+impl RelationKind {
+	fn label(&self) -> &str {
+		match self {
+			RelationKind::ForeignKey => "FK",
+			RelationKind::OneToOne => "O2O",
+			RelationKind::ManyToMany => "M2M",
+		}
+	}
+}
+
+/// A relationship edge between two models
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelRelation {
+	/// Qualified name (`app.Model`) of the model declaring the relation
+	pub source: String,
+	/// Qualified name of the related model, or the raw table/model name when
+	/// the target is not registered
+	pub target: String,
+	/// Field declaring the relation
+	pub field_name: String,
+	/// Relationship kind
+	pub kind: RelationKind,
+	/// Whether the relation is optional (nullable foreign key)
+	pub nullable: bool,
+}
+
+/// Diagram and schema reference generator for registered models
+pub struct ModelGraph {
+	models: Vec<ModelMetadata>,
+}
+
+impl ModelGraph {
+	/// Create a graph from model metadata, ordered by app label and model name
+	pub fn new(mut models: Vec<ModelMetadata>) -> Self {
+		models.sort_by(|a, b| {
+			(a.app_label.as_str(), a.model_name.as_str())
+				.cmp(&(b.app_label.as_str(), b.model_name.as_str()))
+		});
+		Self { models }
+	}
+
+	/// Create a graph from every model in a registry
+	pub fn from_registry(registry: &ModelRegistry) -> Self {
+		Self::new(registry.get_models())
+	}
+
+	/// Keep only models of the given apps (no-op when `app_labels` is empty)
+	///
+	/// Relations pointing at models of other apps are still drawn, with the
+	/// target rendered as an external node.
+	pub fn with_app_labels(mut self, app_labels: &[String]) -> Self {
+		if !app_labels.is_empty() {
+			self.models.retain(|m| app_labels.contains(&m.app_label));
+		}
+		self
+	}
+
+	/// Models included in the graph
+	pub fn models(&self) -> &[ModelMetadata] {
+		&self.models
+	}
+
+	/// Render the graph in the given format
+	pub fn render(&self, format: ModelGraphFormat) -> String {
+		match format {
+			ModelGraphFormat::Dot => self.to_dot(),
+			ModelGraphFormat::Mermaid => self.to_mermaid(),
+			ModelGraphFormat::Markdown => self.to_markdown(),
+		}
+	}
+
+	/// All relations declared by the models in the graph
+	pub fn relations(&self) -> Vec<ModelRelation> {
+		let mut relations = Vec::new();
+		for model in &self.models {
+			for (name, field) in sorted_fields(model) {
+				let Some(fk) = &field.foreign_key else {
+					continue;
+				};
+				let kind = if param_is_true(field, "unique") || param_is_true(field, "primary_key")
+				{
+					RelationKind::OneToOne
+				} else {
+					RelationKind::ForeignKey
+				};
+				relations.push(ModelRelation {
+					source: qualified_name(model),
+					target: self
+						.resolve_table(&fk.referenced_table)
+						.map(qualified_name)
+						.unwrap_or_else(|| fk.referenced_table.clone()),
+					field_name: name.to_string(),
+					kind,
+					nullable: field.nullable,
+				});
+			}
+			for m2m in &model.many_to_many_fields {
+				relations.push(ModelRelation {
+					source: qualified_name(model),
+					target: self
+						.resolve_model(&m2m.to_model, &model.app_label)
+						.map(qualified_name)
+						.unwrap_or_else(|| m2m.to_model.clone()),
+					field_name: m2m.field_name.clone(),
+					kind: RelationKind::ManyToMany,
+					nullable: true,
+				});
+			}
+		}
+		relations
+	}
+
+	/// Generate DOT graph format (for Graphviz)
+	pub fn to_dot(&self) -> String {
+		let mut output = String::from("digraph models {\n");
+		output.push_str("\trankdir=LR;\n");
+		output.push_str("\tnode [shape=record, fontname=\"Helvetica\", fontsize=10];\n");
+		output.push_str("\tedge [fontname=\"Helvetica\", fontsize=9];\n");
+
+		for (app_label, models) in self.by_app() {
+			let _ = writeln!(output, "\n\tsubgraph \"cluster_{}\" {{", app_label);
+			let _ = writeln!(output, "\t\tlabel=\"{}\";", app_label);
+			for model in models {
+				let rows: Vec<String> = sorted_fields(model)
+					.into_iter()
+					.map(|(name, field)| {
+						let marker = if param_is_true(field, "primary_key") {
+							" (PK)"
+						} else if field.foreign_key.is_some() {
+							" (FK)"
+						} else {
+							""
+						};
+						escape_record(&format!("{}{}: {}", name, marker, field.field_type))
+					})
+					.collect();
+				let _ = writeln!(
+					output,
+					"\t\t\"{}\" [label=\"{{{}|{}\\l}}\"];",
+					qualified_name(model),
+					escape_record(&model.model_name),
+					rows.join("\\l")
+				);
+			}
+			output.push_str("\t}\n");
+		}
+
+		let relations = self.relations();
+		if !relations.is_empty() {
+			output.push('\n');
+		}
+		for relation in relations {
+			let style = if relation.kind == RelationKind::ManyToMany {
+				", dir=both"
+			} else if relation.nullable {
+				", style=dashed"
+			} else {
+				""
+			};
+			let _ = writeln!(
+				output,
+				"\t\"{}\" -> \"{}\" [label=\"{} ({})\"{}];",
+				relation.source,
+				relation.target,
+				relation.field_name,
+				relation.kind.label(),
+				style
+			);
+		}
+
+		output.push_str("}\n");
+		output
+	}
+
+	/// Generate a Mermaid `erDiagram`
+	pub fn to_mermaid(&self) -> String {
+		let mut output = String::from("erDiagram\n");
+
+		for model in &self.models {
+			let _ = writeln!(output, "\t{} {{", mermaid_entity(&qualified_name(model)));
+			for (name, field) in sorted_fields(model) {
+				let key = if param_is_true(field, "primary_key") {
+					" PK"
+				} else if field.foreign_key.is_some() {
+					" FK"
+				} else if param_is_true(field, "unique") {
+					" UK"
+				} else {
+					""
+				};
+				let _ = writeln!(
+					output,
+					"\t\t{} {}{}",
+					mermaid_type(&field.field_type.to_string()),
+					name,
+					key
+				);
+			}
+			output.push_str("\t}\n");
+		}
+
+		for relation in self.relations() {
+			// Cardinality is written from the related model's side
+			let (target, source) = (
+				mermaid_entity(&relation.target),
+				mermaid_entity(&relation.source),
+			);
+			let cardinality = match (relation.kind, relation.nullable) {
+				(RelationKind::ForeignKey, false) => "||--o{",
+				(RelationKind::ForeignKey, true) => "|o--o{",
+				(RelationKind::OneToOne, false) => "||--o|",
+				(RelationKind::OneToOne, true) => "|o--o|",
+				(RelationKind::ManyToMany, _) => "}o--o{",
+			};
+			let _ = writeln!(
+				output,
+				"\t{} {} {} : \"{}\"",
+				target, cardinality, source, relation.field_name
+			);
+		}
+
+		output
+	}
+
+	/// Generate a markdown schema reference
+	pub fn to_markdown(&self) -> String {
+		let mut output = String::from("# Schema Reference\n");
+
+		for (app_label, models) in self.by_app() {
+			let _ = writeln!(output, "\n## {}\n", app_label);
+			for model in models {
+				let _ = writeln!(output, "### {}\n", model.model_name);
+				let _ = write!(output, "Table: `{}`", model.table_name);
+				if model.is_proxy() {
+					output.push_str(" (proxy model)");
+				} else if !model.is_managed() {
+					output.push_str(" (not managed by migrations)");
+				}
+				output.push_str("\n\n");
+
+				output.push_str("| Field | Type | Null | Attributes |\n");
+				output.push_str("|-------|------|------|------------|\n");
+				for (name, field) in sorted_fields(model) {
+					let _ = writeln!(
+						output,
+						"| `{}` | `{}` | {} | {} |",
+						name,
+						field.field_type,
+						if field.nullable { "yes" } else { "no" },
+						field_attributes(field).join(", ")
+					);
+				}
+
+				if !model.constraints().is_empty() {
+					output.push_str("\n**Constraints**\n\n");
+					for constraint in model.constraints() {
+						let _ = write!(
+							output,
+							"- `{}`: {} ({})",
+							constraint.name,
+							constraint.constraint_type,
+							constraint.fields.join(", ")
+						);
+						if let Some(expression) = &constraint.expression {
+							let _ = write!(output, " `{}`", expression);
+						}
+						output.push('\n');
+					}
+				}
+
+				if !model.many_to_many_fields.is_empty() {
+					output.push_str("\n**Many-to-many**\n\n");
+					for m2m in &model.many_to_many_fields {
+						let _ = write!(output, "- `{}` → `{}`", m2m.field_name, m2m.to_model);
+						if let Some(through) = &m2m.through {
+							let _ = write!(output, " through `{}`", through);
+						}
+						output.push('\n');
+					}
+				}
+				output.push('\n');
+			}
+		}
+
+		output
+	}
+
+	fn by_app(&self) -> BTreeMap<&str, Vec<&ModelMetadata>> {
+		let mut apps: BTreeMap<&str, Vec<&ModelMetadata>> = BTreeMap::new();
+		for model in &self.models {
+			apps.entry(model.app_label.as_str())
+				.or_default()
+				.push(model);
+		}
+		apps
+	}
+
+	/// Resolve a foreign key's referenced table to a model.
+	///
+	/// The derive macro records either the real table name or the snake_case
+	/// model name, so both are accepted.
+	fn resolve_table(&self, referenced_table: &str) -> Option<&ModelMetadata> {
+		self.models
+			.iter()
+			.find(|m| m.table_name == referenced_table)
+			.or_else(|| {
+				let mut matches = self
+					.models
+					.iter()
+					.filter(|m| to_snake_case(&m.model_name) == referenced_table);
+				let first = matches.next()?;
+				matches.next().is_none().then_some(first)
+			})
+	}
+
+	/// Resolve `Model` or `app.Model`, preferring the source model's app.
+	fn resolve_model(&self, to_model: &str, source_app: &str) -> Option<&ModelMetadata> {
+		let (app_label, model_name) = match to_model.rsplit_once('.') {
+			Some((app, model)) => (app, model),
+			None => (source_app, to_model),
+		};
+		self.models
+			.iter()
+			.find(|m| m.app_label == app_label && m.model_name == model_name)
+			.or_else(|| self.models.iter().find(|m| m.model_name == model_name))
+	}
+}
+
+fn qualified_name(model: &ModelMetadata) -> String {
+	format!("{}.{}", model.app_label, model.model_name)
+}
+
+fn param_is_true(field: &FieldMetadata, key: &str) -> bool {
+	field.params.get(key).map(String::as_str) == Some("true")
+}
+
+/// Fields with primary keys first, then by name
+fn sorted_fields(model: &ModelMetadata) -> Vec<(&str, &FieldMetadata)> {
+	let mut fields: Vec<_> = model
+		.fields
+		.iter()
+		.map(|(name, field)| (name.as_str(), field))
+		.collect();
+	fields.sort_by_key(|(name, field)| (!param_is_true(field, "primary_key"), *name));
+	fields
+}
+
+fn field_attributes(field: &FieldMetadata) -> Vec<String> {
+	let mut attributes = Vec::new();
+	if param_is_true(field, "primary_key") {
+		attributes.push("primary key".to_string());
+	}
+	if param_is_true(field, "auto_increment") {
+		attributes.push("auto increment".to_string());
+	}
+	if param_is_true(field, "unique") {
+		attributes.push("unique".to_string());
+	}
+	if let Some(fk) = &field.foreign_key {
+		attributes.push(format!(
+			"FK → `{}.{}` (on delete {})",
+			fk.referenced_table,
+			fk.referenced_column,
+			fk.on_delete.to_sql_keyword()
+		));
+	}
+	if param_is_true(field, "db_index") || param_is_true(field, "index") {
+		attributes.push("indexed".to_string());
+	}
+	if let Some(default) = field.params.get("default") {
+		attributes.push(format!("default `{}`", default));
+	}
+	attributes
+}
+
+/// Escape characters with a meaning in DOT record labels
+fn escape_record(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+	for ch in text.chars() {
+		if matches!(ch, '{' | '}' | '|' | '<' | '>' | '"' | '\\') {
+			escaped.push('\\');
+		}
+		escaped.push(ch);
+	}
+	escaped
+}
+
+/// Mermaid entity names only allow word characters
+fn mermaid_entity(name: &str) -> String {
+	name.chars()
+		.map(|c| {
+			if c.is_alphanumeric() || c == '_' {
+				c
+			} else {
+				'_'
+			}
+		})
+		.collect()
+}
+
+/// Mermaid attribute types must be a single token
+fn mermaid_type(sql_type: &str) -> String {
+	sql_type
+		.chars()
+		.filter(|c| *c != ' ')
+		.map(|c| if c == ',' { '_' } else { c })
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::migrations::FieldType;
+	use crate::migrations::autodetector::{ForeignKeyAction, ForeignKeyInfo};
+	use crate::migrations::model_registry::ManyToManyMetadata;
+	use rstest::rstest;
+
+	fn build_models() -> Vec<ModelMetadata> {
+		let mut user = ModelMetadata::new("auth", "User", "auth_user");
+		user.add_field(
+			"id".to_string(),
+			FieldMetadata::new(FieldType::BigInteger).with_param("primary_key", "true"),
+		);
+
+		let mut post = ModelMetadata::new("blog", "Post", "blog_post");
+		post.add_field(
+			"id".to_string(),
+			FieldMetadata::new(FieldType::BigInteger).with_param("primary_key", "true"),
+		);
+		post.add_field(
+			"title".to_string(),
+			FieldMetadata::new(FieldType::VarChar(200)).with_param("unique", "true"),
+		);
+		post.add_field(
+			"author_id".to_string(),
+			FieldMetadata::new(FieldType::BigInteger)
+				.with_param("db_index", "true")
+				.with_foreign_key(ForeignKeyInfo {
+					// The derive macro records the snake_case model name
+					referenced_table: "user".to_string(),
+					referenced_column: "id".to_string(),
+					on_delete: ForeignKeyAction::Cascade,
+					on_update: ForeignKeyAction::Cascade,
+				}),
+		);
+		post.add_many_to_many(ManyToManyMetadata::new("tags", "Tag"));
+
+		let mut tag = ModelMetadata::new("blog", "Tag", "blog_tag");
+		tag.add_field(
+			"id".to_string(),
+			FieldMetadata::new(FieldType::BigInteger).with_param("primary_key", "true"),
+		);
+
+		vec![post, tag, user]
+	}
+
+	#[rstest]
+	fn relations_resolve_registered_targets() {
+		// Arrange
+		let graph = ModelGraph::new(build_models());
+
+		// Act
+		let relations = graph.relations();
+
+		// Assert
+		assert_eq!(
+			relations,
+			vec![
+				ModelRelation {
+					source: "blog.Post".to_string(),
+					target: "auth.User".to_string(),
+					field_name: "author_id".to_string(),
+					kind: RelationKind::ForeignKey,
+					nullable: false,
+				},
+				ModelRelation {
+					source: "blog.Post".to_string(),
+					target: "blog.Tag".to_string(),
+					field_name: "tags".to_string(),
+					kind: RelationKind::ManyToMany,
+					nullable: true,
+				},
+			]
+		);
+	}
+
+	#[rstest]
+	fn dot_groups_models_by_app() {
+		// Arrange
+		let graph = ModelGraph::new(build_models());
+
+		// Act
+		let dot = graph.to_dot();
+
+		// Assert
+		assert!(dot.starts_with("digraph models {"));
+		assert!(dot.contains("subgraph \"cluster_blog\""));
+		assert!(dot.contains("\"blog.Post\" -> \"auth.User\" [label=\"author_id (FK)\"];"));
+	}
+
+	#[rstest]
+	fn mermaid_renders_entities_and_cardinality() {
+		// Arrange
+		let graph = ModelGraph::new(build_models());
+
+		// Act
+		let mermaid = graph.to_mermaid();
+
+		// Assert
+		assert!(mermaid.contains("\tblog_Post {"));
+		assert!(mermaid.contains("\t\tVARCHAR(200) title UK"));
+		assert!(mermaid.contains("\tauth_User ||--o{ blog_Post : \"author_id\""));
+		assert!(mermaid.contains("\tblog_Tag }o--o{ blog_Post : \"tags\""));
+	}
+
+	#[rstest]
+	fn markdown_lists_fields_and_indexes() {
+		// Arrange
+		let graph = ModelGraph::new(build_models()).with_app_labels(&["blog".to_string()]);
+
+		// Act
+		let markdown = graph.to_markdown();
+
+		// Assert
+		assert!(markdown.contains("## blog"));
+		assert!(!markdown.contains("## auth"));
+		assert!(markdown.contains("Table: `blog_post`"));
+		assert!(markdown.contains(
+			"| `author_id` | `BIGINT` | no | FK → `user.id` (on delete CASCADE), indexed |"
+		));
+		assert!(markdown.contains("- `tags` → `Tag`"));
+	}
+
+	#[rstest]
+	#[case("dot", ModelGraphFormat::Dot)]
+	#[case("Mermaid", ModelGraphFormat::Mermaid)]
+	#[case("md", ModelGraphFormat::Markdown)]
+	fn format_parses_names(#[case] input: &str, #[case] expected: ModelGraphFormat) {
+		assert_eq!(input.parse::<ModelGraphFormat>().unwrap(), expected);
+	}
+}
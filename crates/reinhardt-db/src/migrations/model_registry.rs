@@ -115,6 +115,23 @@ impl ModelMetadata {
 		&self.constraints
 	}
 
+	/// Whether migrations manage this model's table (`false` for
+	/// `#[model(managed = false)]`).
+	pub fn is_managed(&self) -> bool {
+		self.options.get("managed").map(String::as_str) != Some("false")
+	}
+
+	/// Whether this is a proxy model (`#[model(proxy)]`) sharing the table
+	/// of another model.
+	pub fn is_proxy(&self) -> bool {
+		self.options.get("proxy").map(String::as_str) == Some("true")
+	}
+
+	/// Database comment of the table, taken from the model's doc comment.
+	pub fn table_comment(&self) -> Option<&str> {
+		self.options.get("db_table_comment").map(String::as_str)
+	}
+
 	/// Convert to ModelState for migrations
 	///
 	/// # Examples
@@ -266,6 +283,11 @@ impl FieldMetadata {
 		self.nullable
 	}
 
+	/// Database comment of the column, taken from the field's doc comment.
+	pub fn comment(&self) -> Option<&str> {
+		self.params.get("db_comment").map(String::as_str)
+	}
+
 	/// Sets the foreign key and returns self for chaining.
 	pub fn with_foreign_key(mut self, foreign_key: super::autodetector::ForeignKeyInfo) -> Self {
 		self.foreign_key = Some(foreign_key);
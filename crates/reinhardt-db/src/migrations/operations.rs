@@ -971,6 +971,15 @@ pub enum Operation {
 		/// The comment.
 		comment: Option<String>,
 	},
+	/// AlterColumnComment variant.
+	AlterColumnComment {
+		/// The table.
+		table: String,
+		/// The column.
+		column: String,
+		/// The comment.
+		comment: Option<String>,
+	},
 	/// AlterUniqueTogether variant.
 	AlterUniqueTogether {
 		/// The table.
@@ -1276,6 +1285,7 @@ impl Operation {
 			| Operation::RunSQL { .. }
 			| Operation::RunRust { .. }
 			| Operation::AlterTableComment { .. }
+			| Operation::AlterColumnComment { .. }
 			| Operation::AlterUniqueTogether { .. }
 			| Operation::AlterModelOptions { .. }
 			| Operation::SetAutoIncrementValue { .. }
@@ -1909,7 +1919,7 @@ impl Operation {
 						format!(
 							"COMMENT ON TABLE {} IS '{}';",
 							quote_identifier(table),
-							comment_text
+							comment_text.replace('\'', "''")
 						)
 					} else {
 						format!("COMMENT ON TABLE {} IS NULL;", quote_identifier(table))
@@ -1920,7 +1930,7 @@ impl Operation {
 						format!(
 							"ALTER TABLE {} COMMENT='{}';",
 							quote_identifier(table),
-							comment_text
+							comment_text.replace('\'', "''")
 						)
 					} else {
 						format!("ALTER TABLE {} COMMENT='';", quote_identifier(table))
@@ -1928,6 +1938,31 @@ impl Operation {
 				}
 				SqlDialect::Sqlite => String::new(),
 			},
+			Operation::AlterColumnComment {
+				table,
+				column,
+				comment,
+			} => match dialect {
+				SqlDialect::Postgres | SqlDialect::Cockroachdb => {
+					if let Some(comment_text) = comment {
+						format!(
+							"COMMENT ON COLUMN {}.{} IS '{}';",
+							quote_identifier(table),
+							quote_identifier(column),
+							comment_text.replace('\'', "''")
+						)
+					} else {
+						format!(
+							"COMMENT ON COLUMN {}.{} IS NULL;",
+							quote_identifier(table),
+							quote_identifier(column)
+						)
+					}
+				}
+				// MySQL can only set column comments by restating the whole
+				// column definition, and SQLite has no comments at all
+				SqlDialect::Mysql | SqlDialect::Sqlite => String::new(),
+			},
 			Operation::AlterUniqueTogether {
 				table,
 				unique_together,
@@ -3781,6 +3816,27 @@ impl Operation {
 					format!("COMMENT ON TABLE {} IS NULL", quote_identifier(table))
 				})
 			}
+			Operation::AlterColumnComment {
+				table,
+				column,
+				comment,
+			} => {
+				// PostgreSQL-specific COMMENT ON COLUMN
+				OperationStatement::RawSql(if let Some(comment_text) = comment {
+					format!(
+						"COMMENT ON COLUMN {}.{} IS '{}'",
+						quote_identifier(table),
+						quote_identifier(column),
+						comment_text.replace('\'', "''")
+					)
+				} else {
+					format!(
+						"COMMENT ON COLUMN {}.{} IS NULL",
+						quote_identifier(table),
+						quote_identifier(column)
+					)
+				})
+			}
 			Operation::AlterUniqueTogether {
 				table,
 				unique_together,
@@ -4193,9 +4249,11 @@ impl Operation {
 			FieldType::Json => col_def.json(),
 			FieldType::JsonBinary => col_def.json_binary(),
 			FieldType::Uuid => col_def.uuid(),
-			FieldType::Binary | FieldType::Bytea => col_def.binary(0),
-			FieldType::Blob | FieldType::TinyBlob | FieldType::MediumBlob | FieldType::LongBlob => {
-				col_def.binary(0)
+			// Unbounded binary: BYTEA on PostgreSQL, BLOB elsewhere. `BINARY(0)` would
+			// become a 255 byte TINYBLOB on MySQL.
+			FieldType::Binary | FieldType::Bytea | FieldType::Blob => col_def.blob(),
+			FieldType::TinyBlob | FieldType::MediumBlob | FieldType::LongBlob => {
+				col_def.custom(Alias::new(field_type.to_sql_string()))
 			}
 			FieldType::MediumInt => col_def.integer(),
 			FieldType::Year => col_def.small_integer(),
@@ -4393,6 +4451,11 @@ impl MigrationOperation for Operation {
 			Operation::AlterTableComment { table, .. } => {
 				Some(format!("alter_comment_{}", table.to_lowercase()))
 			}
+			Operation::AlterColumnComment { table, column, .. } => Some(format!(
+				"alter_comment_{}_{}",
+				table.to_lowercase(),
+				column.to_lowercase()
+			)),
 			Operation::AlterUniqueTogether { table, .. } => {
 				Some(format!("alter_unique_{}", table.to_lowercase()))
 			}
@@ -4494,6 +4557,14 @@ impl MigrationOperation for Operation {
 				Some(c) => format!("Set comment on {} to '{}'", table, c),
 				None => format!("Remove comment from {}", table),
 			},
+			Operation::AlterColumnComment {
+				table,
+				column,
+				comment,
+			} => match comment {
+				Some(c) => format!("Set comment on {}.{} to '{}'", table, column, c),
+				None => format!("Remove comment from {}.{}", table, column),
+			},
 			Operation::AlterUniqueTogether { table, .. } => {
 				format!("Alter unique_together on {}", table)
 			}
@@ -6378,6 +6449,44 @@ mod tests {
 		);
 	}
 
+	#[rstest]
+	#[case::postgres(
+		SqlDialect::Postgres,
+		"COMMENT ON COLUMN users.bio IS 'User''s biography';"
+	)]
+	#[case::mysql(SqlDialect::Mysql, "")]
+	#[case::sqlite(SqlDialect::Sqlite, "")]
+	fn test_alter_column_comment_to_sql(#[case] dialect: SqlDialect, #[case] expected: &str) {
+		// Arrange
+		let op = Operation::AlterColumnComment {
+			table: "users".to_string(),
+			column: "bio".to_string(),
+			comment: Some("User's biography".to_string()),
+		};
+
+		// Act
+		let sql = op.to_sql(&dialect);
+
+		// Assert
+		assert_eq!(sql, expected);
+	}
+
+	#[rstest]
+	fn test_alter_column_comment_null_to_sql() {
+		// Arrange
+		let op = Operation::AlterColumnComment {
+			table: "users".to_string(),
+			column: "bio".to_string(),
+			comment: None,
+		};
+
+		// Act
+		let sql = op.to_sql(&SqlDialect::Postgres);
+
+		// Assert
+		assert_eq!(sql, "COMMENT ON COLUMN users.bio IS NULL;");
+	}
+
 	#[test]
 	fn test_alter_table_comment_with_quotes() {
 		let op = Operation::AlterTableComment {
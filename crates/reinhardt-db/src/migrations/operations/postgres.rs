@@ -337,6 +337,81 @@ pub mod extensions {
 	}
 }
 
+/// Commonly used PostgreSQL collations
+pub mod collations {
+	use super::CreateCollation;
+
+	/// Create the ICU collation used by natural ordering
+	///
+	/// Numeric substrings compare by value (`"item2"` before `"item10"`); the
+	/// name matches `orm::functions::NATURAL_COLLATION`.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use reinhardt_db::migrations::operations::postgres::collations::natural;
+	///
+	/// let collation = natural();
+	/// assert_eq!(collation.name, "natural");
+	/// assert_eq!(collation.provider.as_deref(), Some("icu"));
+	/// ```
+	pub fn natural() -> CreateCollation {
+		CreateCollation::new("natural", "und-u-kn-true").with_provider("icu")
+	}
+}
+
+/// PostGIS column types and spatial indexes
+///
+/// Combine with [`extensions::postgis`] to store geometries and index them for
+/// the `within`, `distance_lte` and `bbcontains` lookups.
+///
+/// # Example
+///
+/// ```rust
+/// use reinhardt_db::migrations::operations::postgres::spatial;
+/// use reinhardt_db::migrations::FieldType;
+///
+/// assert_eq!(
+///     spatial::point_field(4326),
+///     FieldType::Custom("geometry(Point, 4326)".to_string())
+/// );
+/// let index = spatial::spatial_index("stores", "location");
+/// ```
+pub mod spatial {
+	use crate::migrations::FieldType;
+	use crate::migrations::operations::{IndexType, Operation};
+
+	/// Geometry column of the given PostGIS type (e.g. `"Point"`) and SRID
+	pub fn geometry_field(geometry_type: &str, srid: i32) -> FieldType {
+		FieldType::Custom(format!("geometry({}, {})", geometry_type, srid))
+	}
+
+	/// `geometry(Point, srid)` column
+	pub fn point_field(srid: i32) -> FieldType {
+		geometry_field("Point", srid)
+	}
+
+	/// `geometry(Polygon, srid)` column
+	pub fn polygon_field(srid: i32) -> FieldType {
+		geometry_field("Polygon", srid)
+	}
+
+	/// GiST index on a geometry column
+	pub fn spatial_index(table: impl Into<String>, column: impl Into<String>) -> Operation {
+		Operation::CreateIndex {
+			table: table.into(),
+			columns: vec![column.into()],
+			unique: false,
+			index_type: Some(IndexType::Gist),
+			where_clause: None,
+			concurrently: false,
+			expressions: None,
+			mysql_options: None,
+			operator_class: None,
+		}
+	}
+}
+
 // MigrationOperation trait implementation for Django-style naming
 use crate::migrations::operation_trait::MigrationOperation;
 
@@ -374,6 +449,35 @@ impl MigrationOperation for CreateCollation {
 mod tests {
 	use super::*;
 
+	#[test]
+	fn test_spatial_index_uses_gist() {
+		let op = spatial::spatial_index("stores", "location");
+		match op {
+			crate::migrations::operations::Operation::CreateIndex {
+				table,
+				columns,
+				index_type,
+				..
+			} => {
+				assert_eq!(table, "stores");
+				assert_eq!(columns, vec!["location".to_string()]);
+				assert_eq!(
+					index_type,
+					Some(crate::migrations::operations::IndexType::Gist)
+				);
+			}
+			other => panic!("expected CreateIndex, got {:?}", other),
+		}
+	}
+
+	#[test]
+	fn test_polygon_field_type() {
+		assert_eq!(
+			spatial::polygon_field(3857).to_sql_string(),
+			"geometry(Polygon, 3857)"
+		);
+	}
+
 	#[test]
 	fn test_create_extension_basic() {
 		let ext = CreateExtension::new("hstore");
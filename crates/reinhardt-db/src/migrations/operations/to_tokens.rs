@@ -618,6 +618,23 @@ impl ToTokens for Operation {
 					}
 				});
 			}
+			Operation::AlterColumnComment {
+				table,
+				column,
+				comment,
+			} => {
+				let comment_token = match comment {
+					Some(s) => quote! { Some(#s.to_string()) },
+					None => quote! { None },
+				};
+				tokens.extend(quote! {
+					Operation::AlterColumnComment {
+						table: #table.to_string(),
+						column: #column.to_string(),
+						comment: #comment_token,
+					}
+				});
+			}
 			Operation::AlterUniqueTogether {
 				table,
 				unique_together,
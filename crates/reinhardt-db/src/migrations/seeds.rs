@@ -0,0 +1,472 @@
+//! Declarative seed data
+//!
+//! Apps declare rows that must exist after migrations (default groups,
+//! permissions, site records) as [`Seed`]s. A [`SeedRunner`] upserts them
+//! idempotently and records a checksum of each seed in the
+//! `reinhardt_seeds` table, so seeds whose content has not changed since
+//! their last run are skipped.
+//!
+//! Seeds run through the `seed` management command and, after `migrate`,
+//! from a `post_migrate` receiver.
+//!
+//! # Declaring seeds
+//!
+//! ```rust,ignore
+//! use reinhardt_db::migrations::seeds::{SEED_PROVIDERS, Seed, SeedProvider};
+//! use serde_json::json;
+//!
+//! fn auth_seeds() -> Vec<Seed> {
+//!     vec![
+//!         Seed::new("auth", "default_groups", "auth_group")
+//!             .lookup(["name"])
+//!             .row(json!({ "name": "Editors" }))
+//!             .row(json!({ "name": "Reviewers" })),
+//!     ]
+//! }
+//!
+//! #[linkme::distributed_slice(SEED_PROVIDERS)]
+//! static AUTH_SEEDS: SeedProvider = auth_seeds;
+//! ```
+
+use crate::backends::DatabaseConnection;
+use crate::backends::sql_build_helpers::build_inline_sql;
+use crate::backends::types::TransactionExecutor;
+use linkme::distributed_slice;
+use once_cell::sync::Lazy;
+use reinhardt_query::prelude::{Alias, ColumnDef, Expr, ExprTrait, Query, Value};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::RwLock;
+
+/// Table recording the checksum of every applied seed
+pub const SEED_TABLE: &str = "reinhardt_seeds";
+
+/// Type for seed providers collected at compile-time
+pub type SeedProvider = fn() -> Vec<Seed>;
+
+/// Distributed slice for collecting seed providers at compile-time
+#[distributed_slice]
+pub static SEED_PROVIDERS: [SeedProvider];
+
+static RUNTIME_SEEDS: Lazy<RwLock<Vec<Seed>>> = Lazy::new(|| RwLock::new(Vec::new()));
+
+/// Register a seed at runtime (for tests and dynamically configured apps)
+pub fn register_seed(seed: Seed) {
+	RUNTIME_SEEDS
+		.write()
+		.unwrap_or_else(|e| e.into_inner())
+		.push(seed);
+}
+
+/// All declared seeds: compile-time providers followed by runtime registrations
+pub fn registered_seeds() -> Vec<Seed> {
+	let mut seeds = Vec::new();
+	// In test mode, do not access SEED_PROVIDERS to avoid "duplicate
+	// distributed_slice" errors, as for the migration registry.
+	#[cfg(not(test))]
+	for provider in SEED_PROVIDERS {
+		seeds.extend(provider());
+	}
+	seeds.extend(
+		RUNTIME_SEEDS
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.iter()
+			.cloned(),
+	);
+	seeds
+}
+
+/// Declared seeds of one app
+pub fn seeds_for_app(app_label: &str) -> Vec<Seed> {
+	registered_seeds()
+		.into_iter()
+		.filter(|seed| seed.app_label == app_label)
+		.collect()
+}
+
+/// Rows an app requires in one table
+///
+/// Each row is matched against existing rows by its lookup columns: matching
+/// rows are updated, missing rows are inserted, and rows not mentioned by the
+/// seed are left alone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Seed {
+	/// Label of the app declaring the seed
+	pub app_label: String,
+	/// Name of the seed, unique within its app
+	pub name: String,
+	/// Table the rows belong to
+	pub table: String,
+	/// Columns identifying an existing row; all columns of the row when empty
+	pub lookup: Vec<String>,
+	/// Column values of each row
+	pub rows: Vec<BTreeMap<String, serde_json::Value>>,
+}
+
+impl Seed {
+	/// Create an empty seed for `table`
+	pub fn new(
+		app_label: impl Into<String>,
+		name: impl Into<String>,
+		table: impl Into<String>,
+	) -> Self {
+		Self {
+			app_label: app_label.into(),
+			name: name.into(),
+			table: table.into(),
+			lookup: Vec::new(),
+			rows: Vec::new(),
+		}
+	}
+
+	/// Set the columns identifying an existing row
+	pub fn lookup<I, S>(mut self, columns: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: Into<String>,
+	{
+		self.lookup = columns.into_iter().map(Into::into).collect();
+		self
+	}
+
+	/// Add a row given as a JSON object of column values
+	///
+	/// # Panics
+	///
+	/// Panics if `row` is not a JSON object.
+	pub fn row(mut self, row: serde_json::Value) -> Self {
+		match row {
+			serde_json::Value::Object(map) => self.rows.push(map.into_iter().collect()),
+			other => panic!(
+				"seed `{}.{}`: row must be a JSON object, got {}",
+				self.app_label, self.name, other
+			),
+		}
+		self
+	}
+
+	/// Identifier of the seed (`app_label.name`)
+	pub fn id(&self) -> String {
+		format!("{}.{}", self.app_label, self.name)
+	}
+
+	/// SHA-256 of the seed content, as lowercase hex
+	///
+	/// Changes whenever the table, lookup columns or rows change.
+	pub fn checksum(&self) -> String {
+		let content = serde_json::json!({
+			"table": self.table,
+			"lookup": self.lookup,
+			"rows": self.rows,
+		});
+		format!("{:x}", Sha256::digest(content.to_string().as_bytes()))
+	}
+
+	/// Columns used to find the existing row for `row`
+	fn lookup_columns<'a>(&'a self, row: &'a BTreeMap<String, serde_json::Value>) -> Vec<&'a str> {
+		if self.lookup.is_empty() {
+			row.keys().map(String::as_str).collect()
+		} else {
+			self.lookup.iter().map(String::as_str).collect()
+		}
+	}
+}
+
+/// Outcome of a [`SeedRunner::run`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SeedReport {
+	/// Seeds whose rows were written
+	pub applied: Vec<String>,
+	/// Seeds skipped because their checksum was unchanged
+	pub skipped: Vec<String>,
+}
+
+/// Applies seeds and tracks their checksums
+pub struct SeedRunner {
+	connection: DatabaseConnection,
+	force: bool,
+}
+
+impl SeedRunner {
+	/// Create a runner writing through `connection`
+	pub fn new(connection: DatabaseConnection) -> Self {
+		Self {
+			connection,
+			force: false,
+		}
+	}
+
+	/// Re-apply seeds even when their checksum is unchanged
+	pub fn force(mut self, force: bool) -> Self {
+		self.force = force;
+		self
+	}
+
+	/// Create the checksum table if it does not exist
+	pub async fn ensure_schema_table(&self) -> super::Result<()> {
+		let stmt = Query::create_table()
+			.table(Alias::new(SEED_TABLE))
+			.if_not_exists()
+			.col(
+				ColumnDef::new("seed")
+					.string_len(255)
+					.not_null(true)
+					.primary_key(true),
+			)
+			.col(ColumnDef::new("checksum").string_len(64).not_null(true))
+			.col(
+				ColumnDef::new("applied")
+					.timestamp()
+					.not_null(true)
+					.default(Expr::current_timestamp().into_simple_expr()),
+			)
+			.to_owned();
+		let sql = build_inline_sql(self.connection.database_type(), &stmt);
+		self.connection.execute(&sql, vec![]).await?;
+		Ok(())
+	}
+
+	/// Checksums of applied seeds, keyed by seed id
+	pub async fn applied_checksums(&self) -> super::Result<HashMap<String, String>> {
+		let stmt = Query::select()
+			.columns([Alias::new("seed"), Alias::new("checksum")])
+			.from(Alias::new(SEED_TABLE))
+			.to_owned();
+		let sql = build_inline_sql(self.connection.database_type(), &stmt);
+		let rows = self.connection.fetch_all(&sql, vec![]).await?;
+		let mut checksums = HashMap::with_capacity(rows.len());
+		for row in rows {
+			let seed: String = row.get("seed")?;
+			let checksum: String = row.get("checksum")?;
+			checksums.insert(seed, checksum);
+		}
+		Ok(checksums)
+	}
+
+	/// Apply every seed whose checksum changed since its last run
+	///
+	/// Each seed is written in its own transaction together with its checksum.
+	pub async fn run(&self, seeds: &[Seed]) -> super::Result<SeedReport> {
+		self.ensure_schema_table().await?;
+		let checksums = self.applied_checksums().await?;
+
+		let mut report = SeedReport::default();
+		for seed in seeds {
+			let id = seed.id();
+			let checksum = seed.checksum();
+			if !self.force && checksums.get(&id) == Some(&checksum) {
+				report.skipped.push(id);
+				continue;
+			}
+
+			let mut tx = self.connection.begin().await?;
+			let result = self
+				.apply(tx.as_mut(), seed, &checksum, checksums.contains_key(&id))
+				.await;
+			match result {
+				Ok(()) => tx.commit().await?,
+				Err(e) => {
+					let _ = tx.rollback().await;
+					return Err(e);
+				}
+			}
+			report.applied.push(id);
+		}
+		Ok(report)
+	}
+
+	/// Upsert the rows of `seed` and record its checksum
+	async fn apply(
+		&self,
+		tx: &mut dyn TransactionExecutor,
+		seed: &Seed,
+		checksum: &str,
+		recorded: bool,
+	) -> super::Result<()> {
+		let db_type = self.connection.database_type();
+
+		for row in &seed.rows {
+			let lookup = seed.lookup_columns(row);
+			if lookup.is_empty() {
+				return Err(super::MigrationError::InvalidMigration(format!(
+					"seed `{}`: row has no columns",
+					seed.id()
+				)));
+			}
+			let mut conditions = Vec::with_capacity(lookup.len());
+			for column in &lookup {
+				let value = row.get(*column).ok_or_else(|| {
+					super::MigrationError::InvalidMigration(format!(
+						"seed `{}`: row is missing lookup column `{}`",
+						seed.id(),
+						column
+					))
+				})?;
+				conditions.push(match value {
+					serde_json::Value::Null => Expr::col(Alias::new(*column)).is_null(),
+					value => Expr::col(Alias::new(*column)).eq(json_to_value(value)),
+				});
+			}
+
+			let mut select = Query::select();
+			select
+				.column(Alias::new(lookup[0]))
+				.from(Alias::new(&seed.table))
+				.limit(1);
+			for condition in &conditions {
+				select.and_where(condition.clone());
+			}
+			let exists = tx
+				.fetch_optional(&build_inline_sql(db_type, &select), vec![])
+				.await?
+				.is_some();
+
+			if exists {
+				let mut update = Query::update();
+				update.table(Alias::new(&seed.table));
+				let mut changed = false;
+				for (column, value) in row {
+					if !lookup.contains(&column.as_str()) {
+						update.value(Alias::new(column), json_to_value(value));
+						changed = true;
+					}
+				}
+				if !changed {
+					continue;
+				}
+				for condition in conditions {
+					update.and_where(condition);
+				}
+				tx.execute(&build_inline_sql(db_type, &update), vec![])
+					.await?;
+			} else {
+				let insert = Query::insert()
+					.into_table(Alias::new(&seed.table))
+					.columns(row.keys().map(Alias::new))
+					.values_panic(row.values().map(json_to_value))
+					.to_owned();
+				tx.execute(&build_inline_sql(db_type, &insert), vec![])
+					.await?;
+			}
+		}
+
+		let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+		let sql = if recorded {
+			let stmt = Query::update()
+				.table(Alias::new(SEED_TABLE))
+				.value(Alias::new("checksum"), checksum.to_string())
+				.value(Alias::new("applied"), now)
+				.and_where(Expr::col(Alias::new("seed")).eq(seed.id()))
+				.to_owned();
+			build_inline_sql(db_type, &stmt)
+		} else {
+			let stmt = Query::insert()
+				.into_table(Alias::new(SEED_TABLE))
+				.columns([
+					Alias::new("seed"),
+					Alias::new("checksum"),
+					Alias::new("applied"),
+				])
+				.values_panic([seed.id(), checksum.to_string(), now])
+				.to_owned();
+			build_inline_sql(db_type, &stmt)
+		};
+		tx.execute(&sql, vec![]).await?;
+		Ok(())
+	}
+}
+
+/// Convert a JSON column value into a query value
+fn json_to_value(json: &serde_json::Value) -> Value {
+	match json {
+		serde_json::Value::Null => Value::String(None),
+		serde_json::Value::Bool(b) => Value::Bool(Some(*b)),
+		serde_json::Value::Number(n) => {
+			if let Some(i) = n.as_i64() {
+				Value::BigInt(Some(i))
+			} else if let Some(f) = n.as_f64() {
+				Value::Double(Some(f))
+			} else {
+				Value::String(Some(Box::new(n.to_string())))
+			}
+		}
+		serde_json::Value::String(s) => Value::String(Some(Box::new(s.clone()))),
+		serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
+			Value::String(Some(Box::new(json.to_string())))
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use serde_json::json;
+
+	fn groups() -> Seed {
+		Seed::new("auth", "default_groups", "auth_group")
+			.lookup(["name"])
+			.row(json!({ "name": "Editors", "level": 1 }))
+	}
+
+	#[rstest]
+	fn checksum_is_stable_for_equal_content() {
+		// Arrange
+		let reordered = Seed::new("auth", "default_groups", "auth_group")
+			.lookup(["name"])
+			.row(json!({ "level": 1, "name": "Editors" }));
+
+		// Act
+		let checksum = groups().checksum();
+
+		// Assert
+		assert_eq!(checksum, reordered.checksum());
+		assert_eq!(checksum.len(), 64);
+	}
+
+	#[rstest]
+	fn checksum_changes_with_rows() {
+		// Arrange
+		let changed = groups().row(json!({ "name": "Reviewers", "level": 2 }));
+
+		// Act
+		let checksum = changed.checksum();
+
+		// Assert
+		assert_ne!(checksum, groups().checksum());
+	}
+
+	#[rstest]
+	fn lookup_defaults_to_all_columns() {
+		// Arrange
+		let seed = Seed::new("sites", "default_site", "sites_site")
+			.row(json!({ "domain": "example.com", "name": "Example" }));
+
+		// Act
+		let columns = seed.lookup_columns(&seed.rows[0]);
+
+		// Assert
+		assert_eq!(columns, vec!["domain", "name"]);
+		assert_eq!(seed.id(), "sites.default_site");
+	}
+
+	#[rstest]
+	#[should_panic(expected = "row must be a JSON object")]
+	fn row_rejects_non_objects() {
+		groups().row(json!(["Editors"]));
+	}
+
+	#[rstest]
+	fn runtime_seeds_are_filtered_by_app() {
+		// Arrange
+		register_seed(Seed::new("seed_test_app", "flags", "flags_flag"));
+
+		// Act
+		let seeds = seeds_for_app("seed_test_app");
+
+		// Assert
+		assert_eq!(seeds.len(), 1);
+		assert_eq!(seeds[0].name, "flags");
+	}
+}
@@ -96,9 +96,11 @@
 pub mod aggregation;
 /// Annotation module.
 pub mod annotation;
+pub mod audit;
 pub mod bulk_update;
 pub mod connection;
 pub mod connection_ext; // reinhardt-query connection support
+pub mod counters;
 /// Constraints module.
 pub mod constraints;
 /// Expressions module.
@@ -111,18 +113,30 @@ pub mod hybrid_dml;
 /// Indexes module.
 pub mod indexes;
 pub mod inspection;
+pub mod large_objects;
 /// Into primary key module.
 pub mod into_primary_key;
 /// Model module.
 pub mod model;
+pub mod notifications;
+pub mod personal_data;
 pub mod query_fields;
 pub mod query_helpers; // Common query patterns using reinhardt-query
 pub mod query_types; // Type definitions for passing reinhardt-query objects
+pub mod redirects;
+pub mod retention;
 /// Set operations module.
 pub mod set_operations;
+pub mod sites;
+pub mod slug;
 pub mod sql_condition_parser;
+pub mod tenancy;
+pub mod tracking;
 pub mod transaction;
+pub mod translation;
 pub mod typed_join;
+/// Unique violation module.
+pub mod unique_violation;
 /// Validators module.
 pub mod validators;
 /// Window module.
@@ -209,11 +223,11 @@ pub use connection::{
 pub use constraints::{
 	CheckConstraint, Constraint, ForeignKeyConstraint, OnDelete, OnUpdate, UniqueConstraint,
 };
-pub use expressions::{Exists, F, FieldRef, OuterRef, Q, QOperator, Subquery};
+pub use expressions::{Exists, F, FieldRef, OrderBy, OuterRef, Q, QOperator, Subquery};
 pub use functions::{
-	Abs, Cast, Ceil, Concat, CurrentDate, CurrentTime, Extract, ExtractComponent, Floor, Greatest,
-	Least, Length, Lower, Mod, Now, NullIf, Power, Round, SqlType, Sqrt, Substr, Trim, TrimType,
-	Upper,
+	Abs, Cast, Ceil, Collate, Concat, CurrentDate, CurrentTime, Extract, ExtractComponent, Floor,
+	Greatest, Least, Length, Lower, Mod, NATURAL_COLLATION, Now, NullIf, Power, Round, SqlType,
+	Sqrt, Substr, Trim, TrimType, Upper, natural_cmp,
 };
 pub use indexes::{BTreeIndex, GinIndex, GistIndex, HashIndex, Index};
 pub use into_primary_key::IntoPrimaryKey;
@@ -300,7 +314,7 @@ pub use async_query::{AsyncQuery, AsyncSession};
 pub use engine::{Engine, EngineConfig, create_engine, create_engine_with_config};
 pub use fk_accessor::ForeignKeyAccessor;
 pub use many_to_many::{AssociationTable, ManyToMany, association_table};
-pub use many_to_many_accessor::ManyToManyAccessor;
+pub use many_to_many_accessor::{ManyToManyAccessor, ThroughAccessor};
 pub use n_plus_one::{
 	NPlusOneConfig, NPlusOneFinding, NPlusOneMode, NPlusOneReport, NPlusOneScope,
 };
@@ -1,7 +1,12 @@
 //! Aggregation functions for database queries
 //!
 //! This module provides Django-inspired aggregation functionality.
+//!
+//! Aggregates can be restricted to a subset of rows with [`Aggregate::filter`]
+//! (Django's `Count("books", filter=Q(...))`), and computed over a related
+//! model with [`QuerySet::aggregate_related`](super::query::QuerySet::aggregate_related).
 
+use crate::orm::expressions::Q;
 use reinhardt_query::prelude::{Alias, Iden};
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -47,6 +52,12 @@ pub struct Aggregate {
 	pub alias: Option<String>,
 	/// Whether this is a DISTINCT aggregation
 	pub distinct: bool,
+	/// Condition restricting the aggregated rows (`FILTER (WHERE ...)`)
+	#[serde(default)]
+	pub filter: Option<Q>,
+	/// Table the field belongs to, when aggregating over a related model
+	#[serde(default)]
+	pub table: Option<String>,
 }
 
 /// Validates an SQL identifier (column name, alias, etc.)
@@ -115,6 +126,8 @@ impl Aggregate {
 			field: field.map(|s| s.to_string()),
 			alias: None,
 			distinct: false,
+			filter: None,
+			table: None,
 		}
 	}
 
@@ -125,6 +138,8 @@ impl Aggregate {
 			field: None,
 			alias: None,
 			distinct: false,
+			filter: None,
+			table: None,
 		}
 	}
 
@@ -139,6 +154,8 @@ impl Aggregate {
 			field: Some(field.to_string()),
 			alias: None,
 			distinct: true,
+			filter: None,
+			table: None,
 		}
 	}
 
@@ -153,6 +170,8 @@ impl Aggregate {
 			field: Some(field.to_string()),
 			alias: None,
 			distinct: false,
+			filter: None,
+			table: None,
 		}
 	}
 
@@ -167,6 +186,8 @@ impl Aggregate {
 			field: Some(field.to_string()),
 			alias: None,
 			distinct: false,
+			filter: None,
+			table: None,
 		}
 	}
 
@@ -181,6 +202,8 @@ impl Aggregate {
 			field: Some(field.to_string()),
 			alias: None,
 			distinct: false,
+			filter: None,
+			table: None,
 		}
 	}
 
@@ -195,6 +218,8 @@ impl Aggregate {
 			field: Some(field.to_string()),
 			alias: None,
 			distinct: false,
+			filter: None,
+			table: None,
 		}
 	}
 
@@ -208,63 +233,108 @@ impl Aggregate {
 		self
 	}
 
-	/// Convert to SQL string using reinhardt-query for safe identifier escaping
-	pub fn to_sql(&self) -> String {
-		let mut parts = Vec::new();
-
-		// Build aggregate expression
-		parts.push(self.func.to_string());
-		parts.push("(".to_string());
-
-		if self.distinct && self.field.is_some() {
-			parts.push("DISTINCT ".to_string());
-		}
-
-		match &self.field {
-			Some(field) => {
-				// Use reinhardt-query's Alias to safely escape the identifier
-				let iden = Alias::new(field);
-				parts.push(iden.to_string());
-			}
-			None => parts.push("*".to_string()),
-		}
+	/// Restrict the aggregate to rows matching `condition`
+	///
+	/// Renders as `FILTER (WHERE ...)`; use [`Aggregate::to_case_sql_expr`]
+	/// on backends without `FILTER` support (MySQL).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_db::orm::aggregation::Aggregate;
+	/// use reinhardt_db::orm::Q;
+	///
+	/// let agg = Aggregate::count(Some("id")).filter(Q::new("rating", ">=", "4"));
+	/// assert_eq!(agg.to_sql_expr(), "COUNT(id) FILTER (WHERE rating >= 4)");
+	/// assert_eq!(agg.to_case_sql_expr(), "COUNT(CASE WHEN rating >= 4 THEN id END)");
+	/// ```
+	pub fn filter(mut self, condition: Q) -> Self {
+		self.filter = Some(condition);
+		self
+	}
 
-		parts.push(")".to_string());
+	/// Qualify the aggregated field with a table name or alias
+	///
+	/// # Panics
+	/// Panics if the table name contains invalid characters
+	pub fn with_table(mut self, table: &str) -> Self {
+		validate_identifier(table).expect("Invalid table name for aggregate");
+		self.table = Some(table.to_string());
+		self
+	}
 
-		if let Some(alias) = &self.alias {
-			parts.push(" AS ".to_string());
+	/// Convert to SQL string using reinhardt-query for safe identifier escaping
+	pub fn to_sql(&self) -> String {
+		match &self.alias {
 			// Safely escape the alias identifier
-			let alias_iden = Alias::new(alias);
-			parts.push(alias_iden.to_string());
+			Some(alias) => format!(
+				"{} AS {}",
+				self.to_sql_expr(),
+				Alias::new(alias).to_string()
+			),
+			None => self.to_sql_expr(),
 		}
-
-		parts.join("")
 	}
 
 	/// Convert to SQL string without alias (for use in SELECT expressions with expr_as)
 	/// Uses reinhardt-query for safe identifier escaping
 	pub fn to_sql_expr(&self) -> String {
-		let mut parts = Vec::new();
-
-		parts.push(self.func.to_string());
-		parts.push("(".to_string());
+		let expr = format!(
+			"{}({}{})",
+			self.func,
+			self.distinct_sql(),
+			self.argument_sql()
+		);
+		match &self.filter {
+			Some(condition) => format!("{} FILTER (WHERE {})", expr, condition.to_sql()),
+			None => expr,
+		}
+	}
 
+	/// Convert to SQL string, expressing any filter as a `CASE WHEN` argument
+	///
+	/// Equivalent to [`Aggregate::to_sql_expr`] for backends lacking the
+	/// `FILTER` clause: rows outside the condition become `NULL`, which all
+	/// aggregate functions ignore.
+	pub fn to_case_sql_expr(&self) -> String {
+		let Some(condition) = &self.filter else {
+			return self.to_sql_expr();
+		};
+		let then = match &self.field {
+			Some(_) => self.argument_sql(),
+			None => "1".to_string(),
+		};
+		format!(
+			"{}({}CASE WHEN {} THEN {} END)",
+			self.func,
+			self.distinct_sql(),
+			condition.to_sql(),
+			then
+		)
+	}
+
+	fn distinct_sql(&self) -> &'static str {
 		if self.distinct && self.field.is_some() {
-			parts.push("DISTINCT ".to_string());
+			"DISTINCT "
+		} else {
+			""
 		}
+	}
 
-		match &self.field {
-			Some(field) => {
-				// Use reinhardt-query's Alias to safely escape the identifier
-				let iden = Alias::new(field);
-				parts.push(iden.to_string());
+	fn argument_sql(&self) -> String {
+		// Use reinhardt-query's Alias to safely escape the identifiers
+		match (&self.table, &self.field) {
+			(Some(table), Some(field)) => {
+				format!(
+					"{}.{}",
+					Alias::new(table).to_string(),
+					Alias::new(field).to_string()
+				)
 			}
-			None => parts.push("*".to_string()),
+			(None, Some(field)) => Alias::new(field).to_string(),
+			(Some(table), None) => format!("{}.*", Alias::new(table).to_string()),
+			(None, None) => "*".to_string(),
 		}
-
-		parts.push(")".to_string());
-
-		parts.join("")
 	}
 }
 
@@ -314,6 +384,7 @@ impl Default for AggregateResult {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use rstest::rstest;
 
 	#[test]
 	fn test_validate_identifier_valid() {
@@ -416,4 +487,74 @@ mod tests {
 		let agg = Aggregate::sum("amount").with_alias("total_amount");
 		assert_eq!(agg.to_sql(), "SUM(amount) AS total_amount");
 	}
+
+	#[rstest]
+	#[case::count(Aggregate::count(Some("id")), "COUNT(id) FILTER (WHERE rating >= 4)")]
+	#[case::count_all(Aggregate::count_all(), "COUNT(*) FILTER (WHERE rating >= 4)")]
+	#[case::count_distinct(
+		Aggregate::count_distinct("user_id"),
+		"COUNT(DISTINCT user_id) FILTER (WHERE rating >= 4)"
+	)]
+	#[case::sum(Aggregate::sum("amount"), "SUM(amount) FILTER (WHERE rating >= 4)")]
+	fn test_filtered_aggregate_renders_filter_clause(
+		#[case] aggregate: Aggregate,
+		#[case] expected: &str,
+	) {
+		// Arrange
+		let aggregate = aggregate.filter(Q::new("rating", ">=", "4"));
+
+		// Act
+		let sql = aggregate.to_sql_expr();
+
+		// Assert
+		assert_eq!(sql, expected);
+	}
+
+	#[rstest]
+	#[case::count(
+		Aggregate::count(Some("id")),
+		"COUNT(CASE WHEN rating >= 4 THEN id END)"
+	)]
+	#[case::count_all(Aggregate::count_all(), "COUNT(CASE WHEN rating >= 4 THEN 1 END)")]
+	#[case::count_distinct(
+		Aggregate::count_distinct("user_id"),
+		"COUNT(DISTINCT CASE WHEN rating >= 4 THEN user_id END)"
+	)]
+	#[case::avg(Aggregate::avg("score"), "AVG(CASE WHEN rating >= 4 THEN score END)")]
+	fn test_filtered_aggregate_case_fallback(#[case] aggregate: Aggregate, #[case] expected: &str) {
+		// Arrange
+		let aggregate = aggregate.filter(Q::new("rating", ">=", "4"));
+
+		// Act
+		let sql = aggregate.to_case_sql_expr();
+
+		// Assert
+		assert_eq!(sql, expected);
+	}
+
+	#[rstest]
+	fn test_case_fallback_without_filter_matches_plain_expr() {
+		// Arrange
+		let aggregate = Aggregate::max("price");
+
+		// Act
+		let sql = aggregate.to_case_sql_expr();
+
+		// Assert
+		assert_eq!(sql, "MAX(price)");
+	}
+
+	#[rstest]
+	fn test_table_qualified_aggregate_with_alias() {
+		// Arrange
+		let aggregate = Aggregate::count(Some("id"))
+			.with_table("books")
+			.with_alias("num_books");
+
+		// Act
+		let sql = aggregate.to_sql();
+
+		// Assert
+		assert_eq!(sql, "COUNT(books.id) AS num_books");
+	}
 }
@@ -1,6 +1,8 @@
 use super::postgres_features::{ArrayAgg, JsonbAgg, JsonbBuildObject, StringAgg, TsRank};
 use crate::orm::aggregation::Aggregate;
+use crate::orm::connection::DatabaseBackend;
 use crate::orm::expressions::{F, Q};
+use crate::orm::functions::{Cast, SqlType};
 use crate::orm::query::quote_identifier;
 use serde::{Deserialize, Serialize};
 
@@ -79,6 +81,8 @@ pub enum Expression {
 	},
 	/// COALESCE(field1, field2, ...)
 	Coalesce(Vec<AnnotationValue>),
+	/// CAST(expression AS type), declaring the output type of an expression
+	Cast(Cast),
 }
 
 /// WHEN clause for CASE expressions
@@ -114,10 +118,15 @@ impl When {
 	/// Documentation for `to_sql`
 	///
 	pub fn to_sql(&self) -> String {
+		self.to_sql_for(DatabaseBackend::Postgres)
+	}
+
+	/// Generate SQL for the WHEN clause targeting a specific backend
+	pub fn to_sql_for(&self, backend: DatabaseBackend) -> String {
 		format!(
 			"WHEN {} THEN {}",
 			self.condition.to_sql(),
-			self.then.to_sql()
+			self.then.to_sql_for(backend)
 		)
 	}
 }
@@ -171,11 +180,19 @@ impl AnnotationValue {
 	/// Documentation for `to_sql`
 	///
 	pub fn to_sql(&self) -> String {
+		self.to_sql_for(DatabaseBackend::Postgres)
+	}
+
+	/// Generate SQL targeting a specific backend
+	///
+	/// Only type names inside `CAST` differ between backends; see
+	/// [`SqlType::to_sql_for`].
+	pub fn to_sql_for(&self, backend: DatabaseBackend) -> String {
 		match self {
 			AnnotationValue::Value(v) => v.to_sql(),
 			AnnotationValue::Field(f) => f.to_sql(),
 			AnnotationValue::Aggregate(a) => a.to_sql(),
-			AnnotationValue::Expression(e) => e.to_sql(),
+			AnnotationValue::Expression(e) => e.to_sql_for(backend),
 			AnnotationValue::Subquery(sql) => sql.clone(),
 			// PostgreSQL-specific aggregations
 			AnnotationValue::ArrayAgg(a) => a.to_sql(),
@@ -205,45 +222,122 @@ impl AnnotationValue {
 }
 
 impl Expression {
+	/// Build a `CASE WHEN ... ELSE ... END` expression
+	///
+	/// Usable both in `annotate()` and as an update value, e.g. to set a
+	/// column based on another one.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_db::orm::annotation::{AnnotationValue, Expression, Value, When};
+	/// use reinhardt_db::orm::functions::SqlType;
+	/// use reinhardt_db::orm::Q;
+	///
+	/// let tier = Expression::case(
+	///     vec![When::new(Q::new("points", ">=", "100"), AnnotationValue::Value(Value::String("gold".into())))],
+	///     Some(AnnotationValue::Value(Value::String("basic".into()))),
+	/// )
+	/// .output_type(SqlType::Varchar { length: Some(16) });
+	/// assert_eq!(
+	///     tier.to_sql(),
+	///     "CAST(CASE WHEN points >= 100 THEN 'gold' ELSE 'basic' END AS VARCHAR(16))"
+	/// );
+	/// ```
+	pub fn case(whens: Vec<When>, default: Option<AnnotationValue>) -> Self {
+		Expression::Case {
+			whens,
+			default: default.map(Box::new),
+		}
+	}
+
+	/// Build a `COALESCE(...)` expression returning the first non-NULL value
+	pub fn coalesce(values: Vec<AnnotationValue>) -> Self {
+		Expression::Coalesce(values)
+	}
+
+	/// Declare the output type of this expression by wrapping it in `CAST`
+	///
+	/// Corresponds to Django's `output_field` argument.
+	pub fn output_type(self, target_type: SqlType) -> Self {
+		Expression::Cast(Cast::new(AnnotationValue::Expression(self), target_type))
+	}
+
 	/// Documentation for `to_sql`
 	///
 	pub fn to_sql(&self) -> String {
+		self.to_sql_for(DatabaseBackend::Postgres)
+	}
+
+	/// Generate SQL targeting a specific backend
+	pub fn to_sql_for(&self, backend: DatabaseBackend) -> String {
 		match self {
 			Expression::Add(left, right) => {
-				format!("({} + {})", left.to_sql(), right.to_sql())
+				format!(
+					"({} + {})",
+					left.to_sql_for(backend),
+					right.to_sql_for(backend)
+				)
 			}
 			Expression::Subtract(left, right) => {
-				format!("({} - {})", left.to_sql(), right.to_sql())
+				format!(
+					"({} - {})",
+					left.to_sql_for(backend),
+					right.to_sql_for(backend)
+				)
 			}
 			Expression::Multiply(left, right) => {
-				format!("({} * {})", left.to_sql(), right.to_sql())
+				format!(
+					"({} * {})",
+					left.to_sql_for(backend),
+					right.to_sql_for(backend)
+				)
 			}
 			Expression::Divide(left, right) => {
-				format!("({} / {})", left.to_sql(), right.to_sql())
+				format!(
+					"({} / {})",
+					left.to_sql_for(backend),
+					right.to_sql_for(backend)
+				)
 			}
 			Expression::Case { whens, default } => {
 				let mut sql = String::from("CASE");
 				for when in whens {
 					sql.push(' ');
-					sql.push_str(&when.to_sql());
+					sql.push_str(&when.to_sql_for(backend));
 				}
 				if let Some(default_val) = default {
-					sql.push_str(&format!(" ELSE {}", default_val.to_sql()));
+					sql.push_str(&format!(" ELSE {}", default_val.to_sql_for(backend)));
 				}
 				sql.push_str(" END");
 				sql
 			}
 			Expression::Coalesce(values) => {
-				let values_sql: Vec<String> = values.iter().map(|v| v.to_sql()).collect();
+				let values_sql: Vec<String> =
+					values.iter().map(|v| v.to_sql_for(backend)).collect();
 				format!("COALESCE({})", values_sql.join(", "))
 			}
+			Expression::Cast(cast) => cast.to_sql_for(backend),
 		}
 	}
 }
 
+impl From<Expression> for AnnotationValue {
+	fn from(expression: Expression) -> Self {
+		AnnotationValue::Expression(expression)
+	}
+}
+
+impl From<Cast> for Expression {
+	fn from(cast: Cast) -> Self {
+		Expression::Cast(cast)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use rstest::rstest;
 
 	#[test]
 	fn test_value_annotation() {
@@ -328,6 +422,63 @@ mod tests {
 		);
 	}
 
+	#[rstest]
+	#[case::postgres(
+		DatabaseBackend::Postgres,
+		"CAST(CASE WHEN score >= 50 THEN \"score\" ELSE 0 END AS BIGINT)"
+	)]
+	#[case::mysql(
+		DatabaseBackend::MySql,
+		"CAST(CASE WHEN score >= 50 THEN \"score\" ELSE 0 END AS SIGNED)"
+	)]
+	#[case::sqlite(
+		DatabaseBackend::Sqlite,
+		"CAST(CASE WHEN score >= 50 THEN \"score\" ELSE 0 END AS INTEGER)"
+	)]
+	fn test_case_with_output_type_per_backend(
+		#[case] backend: DatabaseBackend,
+		#[case] expected: &str,
+	) {
+		// Arrange
+		let expr = Expression::case(
+			vec![When::new(
+				Q::new("score", ">=", "50"),
+				AnnotationValue::Field(F::new("score")),
+			)],
+			Some(AnnotationValue::Value(Value::Int(0))),
+		)
+		.output_type(SqlType::BigInt);
+
+		// Act
+		let sql = expr.to_sql_for(backend);
+
+		// Assert
+		assert_eq!(sql, expected);
+	}
+
+	#[rstest]
+	fn test_cast_nested_in_coalesce_annotation() {
+		// Arrange
+		let expr = Expression::coalesce(vec![
+			Expression::Cast(Cast::new(
+				AnnotationValue::Field(F::new("published_at")),
+				SqlType::Date,
+			))
+			.into(),
+			AnnotationValue::Value(Value::String("1970-01-01".into())),
+		]);
+		let annotation = Annotation::new("published_on", expr.into());
+
+		// Act
+		let sql = annotation.to_sql();
+
+		// Assert
+		assert_eq!(
+			sql,
+			"COALESCE(CAST(\"published_at\" AS DATE), '1970-01-01') AS \"published_on\""
+		);
+	}
+
 	#[test]
 	fn test_complex_arithmetic() {
 		// (price * quantity) + tax
@@ -0,0 +1,573 @@
+//! Automatic change capture for audited models
+//!
+//! Models declared with `#[model(audited)]` record every create, update and
+//! delete performed through [`Model::save`] and [`Model::delete`] into the
+//! installed [`AuditStore`]. Each [`AuditEntry`] carries the field-level
+//! diff of the serialized model and the acting user, taken from the
+//! surrounding [`with_actor`] scope (reinhardt-auth's `AuditActorMiddleware`
+//! opens one per request from the authenticated user).
+//!
+//! Nothing is recorded until a store is installed with [`set_audit_store`].
+//!
+//! # Examples
+//!
+//! ```
+//! use reinhardt_db::orm::audit::{
+//!     AuditQuery, AuditStore, InMemoryAuditStore, RetentionPolicy, set_audit_store,
+//! };
+//! use std::sync::Arc;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> reinhardt_core::exception::Result<()> {
+//! let store = Arc::new(InMemoryAuditStore::new());
+//! set_audit_store(store.clone());
+//!
+//! // ... save and delete audited models ...
+//!
+//! let history = store.query(&AuditQuery::new().object("articles", "42")).await?;
+//! for entry in history {
+//!     println!("{} by {:?}: {:?}", entry.action, entry.actor, entry.changes);
+//! }
+//!
+//! let policy = RetentionPolicy::new()
+//!     .keep_for(chrono::Duration::days(365))
+//!     .keep_table_for("payments", chrono::Duration::days(365 * 7));
+//! store.apply_retention(&policy).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::Model;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::RwLock;
+use reinhardt_core::exception::{Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Kind of change recorded in an audit entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+	/// The instance was inserted
+	Create,
+	/// The instance was updated
+	Update,
+	/// The instance was deleted
+	Delete,
+}
+
+impl fmt::Display for AuditAction {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Create => f.write_str("create"),
+			Self::Update => f.write_str("update"),
+			Self::Delete => f.write_str("delete"),
+		}
+	}
+}
+
+/// Change to a single field
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+	/// Field name as serialized
+	pub field: String,
+	/// Value before the change, `None` for creates
+	pub old: Option<JsonValue>,
+	/// Value after the change, `None` for deletes
+	pub new: Option<JsonValue>,
+}
+
+/// A recorded change to a model instance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+	/// Unique entry identifier
+	pub id: Uuid,
+	/// Application label of the model
+	pub app_label: String,
+	/// Table name of the model
+	pub table: String,
+	/// Primary key of the changed instance
+	pub object_id: String,
+	/// Kind of change
+	pub action: AuditAction,
+	/// Changed fields, sorted by name
+	pub changes: Vec<FieldChange>,
+	/// Identifier of the acting user, if the change ran inside [`with_actor`]
+	pub actor: Option<String>,
+	/// When the change was recorded
+	pub recorded_at: DateTime<Utc>,
+}
+
+/// Compute the field-level diff between two serialized model states
+///
+/// Either side may be `None` (a create or a delete), in which case every
+/// field of the other side is reported. Non-object values are compared as a
+/// whole under the empty field name.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_db::orm::audit::diff_fields;
+/// use serde_json::json;
+///
+/// let old = json!({"title": "Draft", "views": 3});
+/// let new = json!({"title": "Published", "views": 3});
+///
+/// let changes = diff_fields(Some(&old), Some(&new));
+/// assert_eq!(changes.len(), 1);
+/// assert_eq!(changes[0].field, "title");
+/// ```
+pub fn diff_fields(old: Option<&JsonValue>, new: Option<&JsonValue>) -> Vec<FieldChange> {
+	let old_fields = old.map(as_fields).unwrap_or_default();
+	let new_fields = new.map(as_fields).unwrap_or_default();
+	let names: BTreeSet<&str> = old_fields
+		.keys()
+		.chain(new_fields.keys())
+		.copied()
+		.collect();
+
+	names
+		.into_iter()
+		.filter_map(|name| {
+			let before = old_fields.get(name).copied();
+			let after = new_fields.get(name).copied();
+			(before != after).then(|| FieldChange {
+				field: name.to_string(),
+				old: before.cloned(),
+				new: after.cloned(),
+			})
+		})
+		.collect()
+}
+
+fn as_fields(value: &JsonValue) -> HashMap<&str, &JsonValue> {
+	match value {
+		JsonValue::Object(map) => map.iter().map(|(k, v)| (k.as_str(), v)).collect(),
+		other => HashMap::from([("", other)]),
+	}
+}
+
+/// Filter for [`AuditStore::query`]
+///
+/// Every criterion left unset matches all entries.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuditQuery {
+	/// Only entries for this table
+	pub table: Option<String>,
+	/// Only entries for this primary key
+	pub object_id: Option<String>,
+	/// Only entries recorded for this actor
+	pub actor: Option<String>,
+	/// Only entries of this kind
+	pub action: Option<AuditAction>,
+	/// Only entries recorded at or after this time
+	pub since: Option<DateTime<Utc>>,
+	/// Only entries recorded before this time
+	pub until: Option<DateTime<Utc>>,
+	/// Maximum number of entries to return
+	pub limit: Option<usize>,
+}
+
+impl AuditQuery {
+	/// Create a query matching every entry
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Restrict to a table
+	pub fn table(mut self, table: impl Into<String>) -> Self {
+		self.table = Some(table.into());
+		self
+	}
+
+	/// Restrict to one instance
+	pub fn object(mut self, table: impl Into<String>, object_id: impl Into<String>) -> Self {
+		self.table = Some(table.into());
+		self.object_id = Some(object_id.into());
+		self
+	}
+
+	/// Restrict to changes made by an actor
+	pub fn actor(mut self, actor: impl Into<String>) -> Self {
+		self.actor = Some(actor.into());
+		self
+	}
+
+	/// Restrict to one kind of change
+	pub fn action(mut self, action: AuditAction) -> Self {
+		self.action = Some(action);
+		self
+	}
+
+	/// Restrict to entries recorded at or after `since`
+	pub fn since(mut self, since: DateTime<Utc>) -> Self {
+		self.since = Some(since);
+		self
+	}
+
+	/// Restrict to entries recorded before `until`
+	pub fn until(mut self, until: DateTime<Utc>) -> Self {
+		self.until = Some(until);
+		self
+	}
+
+	/// Return at most `limit` entries
+	pub fn limit(mut self, limit: usize) -> Self {
+		self.limit = Some(limit);
+		self
+	}
+
+	/// Returns `true` if `entry` satisfies every criterion except the limit
+	pub fn matches(&self, entry: &AuditEntry) -> bool {
+		self.table.as_ref().is_none_or(|t| *t == entry.table)
+			&& self
+				.object_id
+				.as_ref()
+				.is_none_or(|id| *id == entry.object_id)
+			&& self
+				.actor
+				.as_ref()
+				.is_none_or(|a| entry.actor.as_ref() == Some(a))
+			&& self.action.is_none_or(|a| a == entry.action)
+			&& self.since.is_none_or(|s| entry.recorded_at >= s)
+			&& self.until.is_none_or(|u| entry.recorded_at < u)
+	}
+}
+
+/// How long audit entries are kept
+///
+/// Tables without their own period fall back to the default period; with no
+/// default, their entries are kept forever.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RetentionPolicy {
+	/// Retention period for tables without an override
+	pub default: Option<Duration>,
+	/// Retention periods per table
+	pub per_table: HashMap<String, Duration>,
+}
+
+impl RetentionPolicy {
+	/// Create a policy that keeps every entry
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Keep entries for `period` unless their table has its own period
+	pub fn keep_for(mut self, period: Duration) -> Self {
+		self.default = Some(period);
+		self
+	}
+
+	/// Keep entries of `table` for `period`
+	pub fn keep_table_for(mut self, table: impl Into<String>, period: Duration) -> Self {
+		self.per_table.insert(table.into(), period);
+		self
+	}
+
+	/// Oldest recording time kept for `table`, or `None` to keep everything
+	pub fn cutoff_for(&self, table: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+		self.per_table
+			.get(table)
+			.or(self.default.as_ref())
+			.map(|period| now - *period)
+	}
+}
+
+/// Storage backend for audit entries
+#[async_trait]
+pub trait AuditStore: Send + Sync {
+	/// Persist an entry
+	async fn record(&self, entry: AuditEntry) -> Result<()>;
+
+	/// Return matching entries, oldest first
+	async fn query(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>>;
+
+	/// Delete entries older than the policy allows, returning how many were removed
+	async fn apply_retention(&self, policy: &RetentionPolicy) -> Result<usize>;
+}
+
+/// Audit store keeping entries in memory
+///
+/// Suitable for tests and single-process deployments; entries are lost on
+/// restart.
+#[derive(Debug, Default)]
+pub struct InMemoryAuditStore {
+	entries: RwLock<Vec<AuditEntry>>,
+}
+
+impl InMemoryAuditStore {
+	/// Create an empty store
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Number of stored entries
+	pub fn len(&self) -> usize {
+		self.entries.read().len()
+	}
+
+	/// Returns `true` if no entry is stored
+	pub fn is_empty(&self) -> bool {
+		self.entries.read().is_empty()
+	}
+}
+
+#[async_trait]
+impl AuditStore for InMemoryAuditStore {
+	async fn record(&self, entry: AuditEntry) -> Result<()> {
+		self.entries.write().push(entry);
+		Ok(())
+	}
+
+	async fn query(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>> {
+		let entries = self.entries.read();
+		Ok(entries
+			.iter()
+			.filter(|entry| query.matches(entry))
+			.take(query.limit.unwrap_or(usize::MAX))
+			.cloned()
+			.collect())
+	}
+
+	async fn apply_retention(&self, policy: &RetentionPolicy) -> Result<usize> {
+		let now = Utc::now();
+		let mut entries = self.entries.write();
+		let before = entries.len();
+		entries.retain(|entry| {
+			policy
+				.cutoff_for(&entry.table, now)
+				.is_none_or(|cutoff| entry.recorded_at >= cutoff)
+		});
+		Ok(before - entries.len())
+	}
+}
+
+static AUDIT_STORE: RwLock<Option<Arc<dyn AuditStore>>> = RwLock::new(None);
+
+/// Install the store that receives entries for audited models
+pub fn set_audit_store(store: Arc<dyn AuditStore>) {
+	*AUDIT_STORE.write() = Some(store);
+}
+
+/// Remove the installed store, disabling change capture
+pub fn clear_audit_store() {
+	*AUDIT_STORE.write() = None;
+}
+
+/// The installed audit store, if any
+pub fn audit_store() -> Option<Arc<dyn AuditStore>> {
+	AUDIT_STORE.read().clone()
+}
+
+tokio::task_local! {
+	static CURRENT_ACTOR: Option<String>;
+}
+
+/// Run `future` with `actor` recorded as the user behind its changes
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_db::orm::audit::{current_actor, with_actor};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let actor = with_actor(Some("42".to_string()), async { current_actor() }).await;
+/// assert_eq!(actor.as_deref(), Some("42"));
+/// # }
+/// ```
+pub async fn with_actor<F>(actor: Option<String>, future: F) -> F::Output
+where
+	F: Future,
+{
+	CURRENT_ACTOR.scope(actor, future).await
+}
+
+/// The actor of the surrounding [`with_actor`] scope
+pub fn current_actor() -> Option<String> {
+	CURRENT_ACTOR.try_with(Clone::clone).ok().flatten()
+}
+
+/// Returns the installed store if changes to `M` should be captured
+pub(crate) fn store_for<M: Model>() -> Option<Arc<dyn AuditStore>> {
+	if M::audited() { audit_store() } else { None }
+}
+
+/// Serialize a model state for diffing
+pub(crate) fn snapshot<M: Model>(instance: &M) -> Result<JsonValue> {
+	serde_json::to_value(instance).map_err(|e| Error::Database(e.to_string()))
+}
+
+/// Record a change to an instance of `M`
+///
+/// Updates that change no field are not recorded.
+pub(crate) async fn record_change<M: Model>(
+	store: &dyn AuditStore,
+	action: AuditAction,
+	object_id: String,
+	old: Option<&JsonValue>,
+	new: Option<&JsonValue>,
+) -> Result<()> {
+	let changes = diff_fields(old, new);
+	if action == AuditAction::Update && changes.is_empty() {
+		return Ok(());
+	}
+	store
+		.record(AuditEntry {
+			id: Uuid::now_v7(),
+			app_label: M::app_label().to_string(),
+			table: M::table_name().to_string(),
+			object_id,
+			action,
+			changes,
+			actor: current_actor(),
+			recorded_at: Utc::now(),
+		})
+		.await
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use serde_json::json;
+
+	fn entry(table: &str, action: AuditAction, age: Duration) -> AuditEntry {
+		AuditEntry {
+			id: Uuid::now_v7(),
+			app_label: "blog".to_string(),
+			table: table.to_string(),
+			object_id: "1".to_string(),
+			action,
+			changes: Vec::new(),
+			actor: Some("alice".to_string()),
+			recorded_at: Utc::now() - age,
+		}
+	}
+
+	#[rstest]
+	fn diff_reports_changed_added_and_removed_fields() {
+		// Arrange
+		let old = json!({"title": "Draft", "views": 3, "slug": "draft"});
+		let new = json!({"title": "Published", "views": 3, "tags": ["rust"]});
+
+		// Act
+		let changes = diff_fields(Some(&old), Some(&new));
+
+		// Assert
+		assert_eq!(
+			changes,
+			vec![
+				FieldChange {
+					field: "slug".to_string(),
+					old: Some(json!("draft")),
+					new: None,
+				},
+				FieldChange {
+					field: "tags".to_string(),
+					old: None,
+					new: Some(json!(["rust"])),
+				},
+				FieldChange {
+					field: "title".to_string(),
+					old: Some(json!("Draft")),
+					new: Some(json!("Published")),
+				},
+			]
+		);
+	}
+
+	#[rstest]
+	fn diff_of_create_lists_every_field() {
+		// Arrange
+		let new = json!({"id": 1, "title": "Hello"});
+
+		// Act
+		let changes = diff_fields(None, Some(&new));
+
+		// Assert
+		assert_eq!(changes.len(), 2);
+		assert!(changes.iter().all(|change| change.old.is_none()));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn query_filters_by_object_and_action() {
+		// Arrange
+		let store = InMemoryAuditStore::new();
+		store
+			.record(entry("articles", AuditAction::Create, Duration::zero()))
+			.await
+			.unwrap();
+		store
+			.record(entry("articles", AuditAction::Update, Duration::zero()))
+			.await
+			.unwrap();
+		store
+			.record(entry("comments", AuditAction::Update, Duration::zero()))
+			.await
+			.unwrap();
+
+		// Act
+		let updates = store
+			.query(
+				&AuditQuery::new()
+					.object("articles", "1")
+					.action(AuditAction::Update),
+			)
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(updates.len(), 1);
+		assert_eq!(updates[0].table, "articles");
+		assert_eq!(updates[0].action, AuditAction::Update);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn retention_honours_table_overrides() {
+		// Arrange
+		let store = InMemoryAuditStore::new();
+		for table in ["articles", "payments"] {
+			store
+				.record(entry(table, AuditAction::Update, Duration::days(40)))
+				.await
+				.unwrap();
+			store
+				.record(entry(table, AuditAction::Update, Duration::days(1)))
+				.await
+				.unwrap();
+		}
+		let policy = RetentionPolicy::new()
+			.keep_for(Duration::days(30))
+			.keep_table_for("payments", Duration::days(365));
+
+		// Act
+		let removed = store.apply_retention(&policy).await.unwrap();
+
+		// Assert
+		assert_eq!(removed, 1);
+		let articles = store.query(&AuditQuery::new().table("articles")).await;
+		assert_eq!(articles.unwrap().len(), 1);
+		assert_eq!(store.len(), 3);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn actor_is_scoped_to_future() {
+		// Act
+		let inside = with_actor(Some("alice".to_string()), async { current_actor() }).await;
+		let outside = current_actor();
+
+		// Assert
+		assert_eq!(inside.as_deref(), Some("alice"));
+		assert_eq!(outside, None);
+	}
+}
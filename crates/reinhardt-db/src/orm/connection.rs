@@ -20,6 +20,16 @@ pub enum DatabaseBackend {
 	Sqlite,
 }
 
+impl From<DatabaseBackend> for crate::backends::types::DatabaseType {
+	fn from(backend: DatabaseBackend) -> Self {
+		match backend {
+			DatabaseBackend::Postgres => Self::Postgres,
+			DatabaseBackend::MySql => Self::Mysql,
+			DatabaseBackend::Sqlite => Self::Sqlite,
+		}
+	}
+}
+
 /// Query row wrapper for ORM compatibility
 #[derive(serde::Serialize)]
 pub struct QueryRow {
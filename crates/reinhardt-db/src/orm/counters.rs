@@ -0,0 +1,577 @@
+//! Denormalized counter fields
+//!
+//! A parent model declares a column counting its related rows with
+//! `#[field(counter_of = "comments")]`, where `comments` is the
+//! `related_name` of a foreign key on the child model. The counter is kept in
+//! sync by the child's writes:
+//!
+//! - `save()` of a new child increments the counter of its parent.
+//! - `save()` of a child moved to another parent decrements the old counter
+//!   and increments the new one.
+//! - `delete()` of a child decrements the counter of its parent.
+//! - `bulk_create()` increments each parent once by the number of its new
+//!   children.
+//!
+//! Every adjustment is a single `UPDATE ... SET count = count + n`, so
+//! concurrent writers never lose increments. Writes bypassing the ORM (raw
+//! SQL, queryset updates) are not tracked; [`recount`] and the `recount`
+//! management command repair counters that drifted.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! #[model(app_label = "blog", table_name = "posts")]
+//! pub struct Post {
+//!     #[field(primary_key = true)]
+//!     id: Option<i64>,
+//!     #[field(counter_of = "comments")]
+//!     comment_count: i64,
+//! }
+//!
+//! #[model(app_label = "blog", table_name = "comments")]
+//! pub struct Comment {
+//!     #[field(primary_key = true)]
+//!     id: Option<i64>,
+//!     #[rel(foreign_key, related_name = "comments")]
+//!     post: ForeignKeyField<Post>,
+//! }
+//!
+//! let report = recount(None).await?;
+//! println!("repaired {} counters", report.total_rows());
+//! ```
+
+use super::Model;
+use super::connection::DatabaseConnection;
+use super::personal_data::json_to_value;
+use crate::backends::sql_build_helpers::build_inline_sql;
+use linkme::distributed_slice;
+use once_cell::sync::Lazy;
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_query::prelude::{
+	Alias, Expr, ExprTrait, Func, Query, SimpleExpr, UpdateStatement, Value,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+/// Field declared with `#[field(counter_of = "...")]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CounterField {
+	/// Name of the field
+	pub field: &'static str,
+	/// Column storing the count
+	pub column: &'static str,
+	/// `related_name` of the foreign key whose rows are counted
+	pub relation: &'static str,
+}
+
+/// Model declaring counter fields
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CounterModel {
+	/// Label of the app declaring the model
+	pub app_label: &'static str,
+	/// Name of the model
+	pub model: &'static str,
+	/// Table storing the model
+	pub table: &'static str,
+	/// Primary key column
+	pub primary_key: &'static str,
+	/// Counter fields of the model
+	pub counters: Vec<CounterField>,
+}
+
+impl CounterModel {
+	/// Describe `M` from its generated metadata
+	pub fn of<M: Model>() -> Self {
+		Self {
+			app_label: M::app_label(),
+			model: std::any::type_name::<M>()
+				.rsplit("::")
+				.next()
+				.unwrap_or_default(),
+			table: M::table_name(),
+			primary_key: M::primary_key_field(),
+			counters: M::counter_fields(),
+		}
+	}
+
+	/// `app_label.Model` key used in reports
+	pub fn label(&self) -> String {
+		format!("{}.{}", self.app_label, self.model)
+	}
+}
+
+/// Foreign key that may feed a counter of the model it points to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CountedRelation {
+	/// Table holding the foreign key
+	pub table: &'static str,
+	/// Foreign key column
+	pub column: &'static str,
+	/// `related_name` of the foreign key
+	pub related_name: &'static str,
+	/// Table the foreign key points to
+	pub target_table: &'static str,
+}
+
+/// Type for counter model providers collected at compile-time
+pub type CounterProvider = fn() -> CounterModel;
+
+/// Type for counted relation providers collected at compile-time
+pub type CountedRelationProvider = fn() -> CountedRelation;
+
+/// Distributed slice for collecting models with counter fields at compile-time
+#[distributed_slice]
+pub static COUNTER_MODELS: [CounterProvider];
+
+/// Distributed slice for collecting named foreign keys at compile-time
+#[distributed_slice]
+pub static COUNTED_RELATIONS: [CountedRelationProvider];
+
+static RUNTIME_MODELS: Lazy<RwLock<Vec<CounterModel>>> = Lazy::new(|| RwLock::new(Vec::new()));
+static RUNTIME_RELATIONS: Lazy<RwLock<Vec<CountedRelation>>> =
+	Lazy::new(|| RwLock::new(Vec::new()));
+
+/// Register a model with counter fields at runtime (for tests and dynamic models)
+pub fn register_counter_model(model: CounterModel) {
+	RUNTIME_MODELS
+		.write()
+		.unwrap_or_else(|e| e.into_inner())
+		.push(model);
+}
+
+/// Register a named foreign key at runtime (for tests and dynamic models)
+pub fn register_counted_relation(relation: CountedRelation) {
+	RUNTIME_RELATIONS
+		.write()
+		.unwrap_or_else(|e| e.into_inner())
+		.push(relation);
+}
+
+/// All models with counter fields: compile-time registrations followed by runtime ones
+pub fn registered_counter_models() -> Vec<CounterModel> {
+	let mut models = Vec::new();
+	// In test mode, do not access COUNTER_MODELS to avoid "duplicate
+	// distributed_slice" errors, as for the migration registry.
+	#[cfg(not(test))]
+	for provider in COUNTER_MODELS {
+		models.push(provider());
+	}
+	models.extend(
+		RUNTIME_MODELS
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.iter()
+			.cloned(),
+	);
+	models
+}
+
+/// All named foreign keys: compile-time registrations followed by runtime ones
+pub fn registered_counted_relations() -> Vec<CountedRelation> {
+	let mut relations = Vec::new();
+	#[cfg(not(test))]
+	for provider in COUNTED_RELATIONS {
+		relations.push(provider());
+	}
+	relations.extend(
+		RUNTIME_RELATIONS
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.iter()
+			.copied(),
+	);
+	relations
+}
+
+/// Counter field joined with the foreign key whose rows it counts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter {
+	/// `app_label.Model.field` key used in reports
+	pub label: String,
+	/// Table holding the counter
+	pub table: &'static str,
+	/// Primary key column of that table
+	pub primary_key: &'static str,
+	/// Column storing the count
+	pub column: &'static str,
+	/// Table holding the counted rows
+	pub related_table: &'static str,
+	/// Foreign key column of the counted rows
+	pub related_column: &'static str,
+}
+
+/// Every counter field whose relation is known
+///
+/// Counter fields naming a `related_name` that no foreign key to their model
+/// declares are skipped.
+pub fn registered_counters() -> Vec<Counter> {
+	resolve_counters(
+		&registered_counter_models(),
+		&registered_counted_relations(),
+	)
+}
+
+fn resolve_counters(models: &[CounterModel], relations: &[CountedRelation]) -> Vec<Counter> {
+	let mut counters = Vec::new();
+	for model in models {
+		for field in &model.counters {
+			let Some(relation) = relations
+				.iter()
+				.find(|r| r.target_table == model.table && r.related_name == field.relation)
+			else {
+				continue;
+			};
+			counters.push(Counter {
+				label: format!("{}.{}", model.label(), field.field),
+				table: model.table,
+				primary_key: model.primary_key,
+				column: field.column,
+				related_table: relation.table,
+				related_column: relation.column,
+			});
+		}
+	}
+	counters
+}
+
+/// Counters maintained by writes to `table`
+pub fn counters_fed_by(table: &str) -> Vec<Counter> {
+	registered_counters()
+		.into_iter()
+		.filter(|counter| counter.related_table == table)
+		.collect()
+}
+
+/// `UPDATE table SET column = column + delta WHERE pk = parent`
+fn adjust_statement(counter: &Counter, parent: &JsonValue, delta: i64) -> UpdateStatement {
+	Query::update()
+		.table(Alias::new(counter.table))
+		.value_expr(
+			Alias::new(counter.column),
+			Expr::col(Alias::new(counter.column)).add(delta),
+		)
+		.and_where(Expr::col(Alias::new(counter.primary_key)).eq(json_to_value(parent)))
+		.to_owned()
+}
+
+/// Set the counter to the number of related rows where it differs
+///
+/// With `parents`, only the counters of those rows are recomputed.
+fn recount_statement(counter: &Counter, parents: Option<&[JsonValue]>) -> UpdateStatement {
+	let count = || -> SimpleExpr {
+		Expr::subquery(
+			Query::select()
+				.expr(Func::count(Expr::asterisk().into()))
+				.from(Alias::new(counter.related_table))
+				.and_where(
+					Expr::tbl(
+						Alias::new(counter.related_table),
+						Alias::new(counter.related_column),
+					)
+					.eq(Expr::tbl(
+						Alias::new(counter.table),
+						Alias::new(counter.primary_key),
+					)),
+				)
+				.to_owned(),
+		)
+		.into()
+	};
+	let mut stmt = Query::update();
+	stmt.table(Alias::new(counter.table))
+		.value_expr(Alias::new(counter.column), count())
+		.and_where(Expr::col(Alias::new(counter.column)).ne(count()));
+	if let Some(parents) = parents {
+		stmt.and_where(
+			Expr::col(Alias::new(counter.primary_key))
+				.is_in(parents.iter().map(json_to_value).collect::<Vec<Value>>()),
+		);
+	}
+	stmt
+}
+
+/// Net change of each parent's counter, keyed by the parent's JSON id
+fn deltas(
+	counter: &Counter,
+	removed: &[&JsonValue],
+	added: &[&JsonValue],
+) -> BTreeMap<String, (JsonValue, i64)> {
+	let mut deltas: BTreeMap<String, (JsonValue, i64)> = BTreeMap::new();
+	let changes = removed
+		.iter()
+		.map(|row| (*row, -1))
+		.chain(added.iter().map(|row| (*row, 1)));
+	for (row, step) in changes {
+		let Some(parent) = row
+			.get(counter.related_column)
+			.filter(|parent| !parent.is_null())
+		else {
+			continue;
+		};
+		deltas
+			.entry(parent.to_string())
+			.or_insert_with(|| (parent.clone(), 0))
+			.1 += step;
+	}
+	deltas
+}
+
+/// Apply the counter changes of rows removed from and added to `table`
+///
+/// An update is a removal of the stored row followed by an addition of the
+/// new one; it only touches counters whose foreign key changed.
+pub(crate) async fn apply_row_changes(
+	conn: &DatabaseConnection,
+	table: &str,
+	removed: &[&JsonValue],
+	added: &[&JsonValue],
+) -> Result<()> {
+	for counter in counters_fed_by(table) {
+		for (parent, delta) in deltas(&counter, removed, added).into_values() {
+			if delta == 0 {
+				continue;
+			}
+			let stmt = adjust_statement(&counter, &parent, delta);
+			conn.execute(&build_inline_sql(conn.backend().into(), &stmt), vec![])
+				.await
+				.map_err(|e| Error::Database(e.to_string()))?;
+		}
+	}
+	Ok(())
+}
+
+/// Recompute the counters fed by `table` for the parents of `rows`
+///
+/// Used when the rows actually written are unknown, such as inserts that
+/// skip conflicting rows.
+pub(crate) async fn recount_parents_of(
+	conn: &DatabaseConnection,
+	table: &str,
+	rows: &[&JsonValue],
+) -> Result<()> {
+	for counter in counters_fed_by(table) {
+		let parents: Vec<JsonValue> = deltas(&counter, &[], rows)
+			.into_values()
+			.map(|(parent, _)| parent)
+			.collect();
+		if parents.is_empty() {
+			continue;
+		}
+		let stmt = recount_statement(&counter, Some(&parents));
+		conn.execute(&build_inline_sql(conn.backend().into(), &stmt), vec![])
+			.await
+			.map_err(|e| Error::Database(e.to_string()))?;
+	}
+	Ok(())
+}
+
+/// Rows repaired by [`recount`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecountReport {
+	/// Repaired rows of each counter, keyed by `app_label.Model.field`
+	pub counters: BTreeMap<String, u64>,
+}
+
+impl RecountReport {
+	/// Number of repaired rows across all counters
+	pub fn total_rows(&self) -> u64 {
+		self.counters.values().sum()
+	}
+}
+
+/// Recompute counters using the global connection
+///
+/// `only` restricts the run to one model (`app_label.Model`) or one counter
+/// (`app_label.Model.field`).
+pub async fn recount(only: Option<&str>) -> Result<RecountReport> {
+	let conn = super::manager::get_connection().await?;
+	recount_with_conn(&conn, only).await
+}
+
+/// Recompute counters from the rows they count
+///
+/// Only rows whose counter differs are written; the report lists how many
+/// were repaired per counter.
+pub async fn recount_with_conn(
+	conn: &DatabaseConnection,
+	only: Option<&str>,
+) -> Result<RecountReport> {
+	let counters: Vec<Counter> = registered_counters()
+		.into_iter()
+		.filter(|counter| match only {
+			Some(only) => {
+				counter.label == only
+					|| counter
+						.label
+						.strip_prefix(only)
+						.is_some_and(|rest| rest.starts_with('.'))
+			}
+			None => true,
+		})
+		.collect();
+	if let Some(only) = only
+		&& counters.is_empty()
+	{
+		return Err(Error::NotFound(format!(
+			"No counter fields match `{}`",
+			only
+		)));
+	}
+
+	let mut report = RecountReport::default();
+	for counter in counters {
+		let stmt = recount_statement(&counter, None);
+		let rows = conn
+			.execute(&build_inline_sql(conn.backend().into(), &stmt), vec![])
+			.await
+			.map_err(|e| Error::Database(e.to_string()))?;
+		*report.counters.entry(counter.label).or_default() += rows;
+	}
+	Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::backends::types::DatabaseType;
+	use rstest::rstest;
+	use serde_json::json;
+
+	fn posts() -> CounterModel {
+		CounterModel {
+			app_label: "blog",
+			model: "Post",
+			table: "posts",
+			primary_key: "id",
+			counters: vec![CounterField {
+				field: "comment_count",
+				column: "comment_count",
+				relation: "comments",
+			}],
+		}
+	}
+
+	fn comments() -> CountedRelation {
+		CountedRelation {
+			table: "comments",
+			column: "post_id",
+			related_name: "comments",
+			target_table: "posts",
+		}
+	}
+
+	fn counter() -> Counter {
+		resolve_counters(&[posts()], &[comments()]).remove(0)
+	}
+
+	#[rstest]
+	fn resolves_counters_through_related_name() {
+		// Arrange
+		let unrelated = CountedRelation {
+			table: "likes",
+			column: "post_id",
+			related_name: "likes",
+			target_table: "posts",
+		};
+
+		// Act
+		let counters = resolve_counters(&[posts()], &[unrelated, comments()]);
+
+		// Assert
+		assert_eq!(counters.len(), 1);
+		assert_eq!(counters[0].label, "blog.Post.comment_count");
+		assert_eq!(counters[0].related_table, "comments");
+		assert_eq!(counters[0].related_column, "post_id");
+	}
+
+	#[rstest]
+	fn skips_counters_without_relation() {
+		// Act
+		let counters = resolve_counters(&[posts()], &[]);
+
+		// Assert
+		assert!(counters.is_empty());
+	}
+
+	#[rstest]
+	fn adjustment_is_relative_to_the_stored_count() {
+		// Act
+		let sql = build_inline_sql(
+			DatabaseType::Postgres,
+			&adjust_statement(&counter(), &json!(7), -2),
+		);
+
+		// Assert
+		assert!(sql.starts_with(r#"UPDATE "posts" SET "comment_count" = "comment_count" + "#));
+		assert!(sql.contains("-2"));
+		assert!(sql.ends_with(r#"WHERE "id" = 7"#));
+	}
+
+	#[rstest]
+	fn recount_only_writes_drifted_rows() {
+		// Act
+		let sql = build_inline_sql(
+			DatabaseType::Sqlite,
+			&recount_statement(&counter(), Some(&[json!(1), json!(2)])),
+		);
+
+		// Assert
+		assert!(sql.starts_with(r#"UPDATE "posts" SET "comment_count" = (SELECT COUNT(*)"#));
+		assert!(sql.contains(r#""comments"."post_id" = "posts"."id""#));
+		assert!(sql.contains(r#""comment_count" <> (SELECT COUNT(*)"#));
+		assert!(sql.contains(r#""id" IN (1, 2)"#), "{}", sql);
+	}
+
+	#[rstest]
+	fn deltas_net_out_per_parent() {
+		// Arrange
+		let moved_from = json!({"id": 1, "post_id": 10});
+		let moved_to = json!({"id": 1, "post_id": 11});
+		let added = json!({"id": 2, "post_id": 11});
+		let orphan = json!({"id": 3, "post_id": null});
+
+		// Act
+		let deltas = deltas(&counter(), &[&moved_from], &[&moved_to, &added, &orphan]);
+
+		// Assert
+		assert_eq!(deltas.len(), 2);
+		assert_eq!(deltas["10"], (json!(10), -1));
+		assert_eq!(deltas["11"], (json!(11), 2));
+	}
+
+	#[rstest]
+	fn unchanged_foreign_key_leaves_counter_alone() {
+		// Arrange
+		let before = json!({"id": 1, "post_id": 10, "body": "old"});
+		let after = json!({"id": 1, "post_id": 10, "body": "new"});
+
+		// Act
+		let deltas = deltas(&counter(), &[&before], &[&after]);
+
+		// Assert
+		assert_eq!(deltas["10"].1, 0);
+	}
+
+	#[rstest]
+	fn registered_counters_include_runtime_registrations() {
+		// Arrange
+		register_counter_model(CounterModel {
+			table: "runtime_posts",
+			..posts()
+		});
+		register_counted_relation(CountedRelation {
+			table: "runtime_comments",
+			target_table: "runtime_posts",
+			..comments()
+		});
+
+		// Act
+		let counters = counters_fed_by("runtime_comments");
+
+		// Assert
+		assert_eq!(counters.len(), 1);
+		assert_eq!(counters[0].table, "runtime_posts");
+	}
+}
@@ -67,6 +67,28 @@
 //! let manager = User::objects();
 //! ```
 //!
+//! # Default Queryset and Entry Points
+//!
+//! [`CustomManager::all`] is the manager's default queryset. The other
+//! builders (`filter`, `get`, `order_by`, ...) start from it, so a manager that
+//! overrides `all()` restricts every chained query. Generic views and the
+//! admin query through `Model::objects()`, so the manager set with
+//! `#[model(manager = ...)]` also acts as their default manager.
+//!
+//! Additional managers become alternative entry points with
+//! `#[model(managers(...))]`; each returns the default queryset of its
+//! manager, which chains with every [`QuerySet`] method:
+//!
+//! ```ignore
+//! #[reinhardt_macros::model(
+//!     table_name = "posts",
+//!     managers(published = PublishedManager),
+//! )]
+//! struct Post { /* ... */ }
+//!
+//! let recent = Post::published().order_by(&["-created_at"]).limit(10);
+//! ```
+//!
 //! # Blanket Implementation
 //!
 //! The blanket `impl<M: Model> CustomManager for Manager<M>` makes every
@@ -125,11 +147,17 @@ use super::query::{FilterCondition, QuerySet};
 /// Trait that exposes the full surface area of an object manager and provides
 /// extension hooks for custom behavior.
 ///
-/// All builder methods have default implementations that delegate to the
-/// canonical [`Manager<M>`] inherent methods, so implementing this trait only
-/// requires defining `type Model` and the `new` constructor; every other
-/// method may be left to the default to preserve standard behavior, or
-/// overridden to inject custom logic.
+/// All builder methods have default implementations that chain the
+/// canonical [`QuerySet`] methods onto [`CustomManager::all`], so implementing
+/// this trait only requires defining `type Model` and the `new` constructor;
+/// every other method may be left to the default to preserve standard
+/// behavior, or overridden to inject custom logic.
+///
+/// # Default Queryset
+///
+/// Overriding [`CustomManager::all`] changes the default queryset of the
+/// manager. Because the other builders start from it, the restriction
+/// carries over to every chained query.
 ///
 /// # Hooks
 ///
@@ -154,10 +182,15 @@ pub trait CustomManager: Sized + Send + Sync {
 	fn new() -> Self;
 
 	// =========================================================================
-	// QuerySet builders (28 methods) — default impls delegate to Manager<M>
+	// QuerySet builders (28 methods) — default impls start from `all()`
 	// =========================================================================
 
 	/// Get all records (Django: `Model.objects.all()`).
+	///
+	/// This is the manager's default queryset: every other builder starts
+	/// from it, so overriding `all()` (e.g. to exclude archived rows) applies
+	/// the restriction to `filter()`, `get()`, `order_by()`, ... as well, and
+	/// to generic views and the admin, which query through `Model::objects()`.
 	fn all(&self) -> QuerySet<Self::Model> {
 		Manager::<Self::Model>::new().all()
 	}
@@ -168,92 +201,93 @@ pub trait CustomManager: Sized + Send + Sync {
 	/// [`Manager::filter`] for the recommended fluent builder form
 	/// (`Model::field_x().eq(value)`) and composite conditions.
 	fn filter(&self, filter: impl Into<FilterCondition>) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().filter(filter)
+		self.all().filter(filter)
 	}
 
 	/// Get a single record by primary key (returns a `QuerySet` for chaining).
 	fn get(&self, pk: <Self::Model as Model>::PrimaryKey) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().get(pk)
+		self.all()
+			.filter(Manager::<Self::Model>::primary_key_filter(pk))
 	}
 
 	/// Set a `LIMIT` clause.
 	fn limit(&self, limit: usize) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().limit(limit)
+		self.all().limit(limit)
 	}
 
 	/// Set an `ORDER BY` clause; prefix a field with `-` for descending order.
 	fn order_by(&self, fields: &[&str]) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().order_by(fields)
+		self.all().order_by(fields)
 	}
 
 	/// Add an annotation (computed field) to the query.
 	fn annotate(&self, annotation: Annotation) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().annotate(annotation)
+		self.all().annotate(annotation)
 	}
 
 	/// Defer loading of the specified fields.
 	fn defer(&self, fields: &[&str]) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().defer(fields)
+		self.all().defer(fields)
 	}
 
 	/// Restrict loading to only the specified fields.
 	fn only(&self, fields: &[&str]) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().only(fields)
+		self.all().only(fields)
 	}
 
 	/// Project only the specified fields as values.
 	fn values(&self, fields: &[&str]) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().values(fields)
+		self.all().values(fields)
 	}
 
 	/// Eager-load related objects via SQL `JOIN`.
 	fn select_related(&self, fields: &[&str]) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().select_related(fields)
+		self.all().select_related(fields)
 	}
 
 	/// Set an `OFFSET` clause.
 	fn offset(&self, offset: usize) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().offset(offset)
+		self.all().offset(offset)
 	}
 
 	/// Paginate (1-indexed page, fixed page size).
 	fn paginate(&self, page: usize, page_size: usize) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().paginate(page, page_size)
+		self.all().paginate(page, page_size)
 	}
 
 	/// Pre-fetch related objects in separate queries.
 	fn prefetch_related(&self, fields: &[&str]) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().prefetch_related(fields)
+		self.all().prefetch_related(fields)
 	}
 
 	/// Project as tuples of values rather than full models.
 	fn values_list(&self, fields: &[&str]) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().values_list(fields)
+		self.all().values_list(fields)
 	}
 
 	/// PostgreSQL: filter by array overlap (`&&`).
 	fn filter_array_overlap(&self, field: &str, values: &[&str]) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().filter_array_overlap(field, values)
+		self.all().filter_array_overlap(field, values)
 	}
 
 	/// PostgreSQL: filter by array contains (`@>`).
 	fn filter_array_contains(&self, field: &str, values: &[&str]) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().filter_array_contains(field, values)
+		self.all().filter_array_contains(field, values)
 	}
 
 	/// PostgreSQL: filter by JSONB contains (`@>`).
 	fn filter_jsonb_contains(&self, field: &str, json: &str) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().filter_jsonb_contains(field, json)
+		self.all().filter_jsonb_contains(field, json)
 	}
 
 	/// PostgreSQL: filter by JSONB key existence (`?`).
 	fn filter_jsonb_key_exists(&self, field: &str, key: &str) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().filter_jsonb_key_exists(field, key)
+		self.all().filter_jsonb_key_exists(field, key)
 	}
 
 	/// PostgreSQL: filter where a range column contains a value.
 	fn filter_range_contains(&self, field: &str, value: &str) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().filter_range_contains(field, value)
+		self.all().filter_range_contains(field, value)
 	}
 
 	/// Filter where a field is `IN` the result of a sub-query.
@@ -261,7 +295,7 @@ pub trait CustomManager: Sized + Send + Sync {
 	where
 		F: FnOnce(QuerySet<R>) -> QuerySet<R>,
 	{
-		Manager::<Self::Model>::new().filter_in_subquery(field, subquery_fn)
+		self.all().filter_in_subquery(field, subquery_fn)
 	}
 
 	/// Filter where a field is `NOT IN` the result of a sub-query.
@@ -273,7 +307,7 @@ pub trait CustomManager: Sized + Send + Sync {
 	where
 		F: FnOnce(QuerySet<R>) -> QuerySet<R>,
 	{
-		Manager::<Self::Model>::new().filter_not_in_subquery(field, subquery_fn)
+		self.all().filter_not_in_subquery(field, subquery_fn)
 	}
 
 	/// Filter using a correlated `EXISTS (...)` sub-query.
@@ -281,7 +315,7 @@ pub trait CustomManager: Sized + Send + Sync {
 	where
 		F: FnOnce(QuerySet<R>) -> QuerySet<R>,
 	{
-		Manager::<Self::Model>::new().filter_exists(subquery_fn)
+		self.all().filter_exists(subquery_fn)
 	}
 
 	/// Filter using a correlated `NOT EXISTS (...)` sub-query.
@@ -289,17 +323,17 @@ pub trait CustomManager: Sized + Send + Sync {
 	where
 		F: FnOnce(QuerySet<R>) -> QuerySet<R>,
 	{
-		Manager::<Self::Model>::new().filter_not_exists(subquery_fn)
+		self.all().filter_not_exists(subquery_fn)
 	}
 
 	/// Add a Common Table Expression (`WITH ...`).
 	fn with_cte(&self, cte: CTE) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().with_cte(cte)
+		self.all().with_cte(cte)
 	}
 
 	/// PostgreSQL: full-text search using `to_tsvector` / `to_tsquery`.
 	fn full_text_search(&self, field: &str, query: &str) -> QuerySet<Self::Model> {
-		Manager::<Self::Model>::new().full_text_search(field, query)
+		self.all().full_text_search(field, query)
 	}
 
 	/// Annotate using a sub-query expression.
@@ -308,7 +342,7 @@ pub trait CustomManager: Sized + Send + Sync {
 		R: Model + 'static,
 		F: FnOnce(QuerySet<R>) -> QuerySet<R>,
 	{
-		Manager::<Self::Model>::new().annotate_subquery(name, builder)
+		self.all().annotate_subquery(name, builder)
 	}
 
 	// =========================================================================
@@ -480,12 +514,19 @@ pub trait CustomManager: Sized + Send + Sync {
 	// =========================================================================
 
 	/// Build the `INSERT` statement for a bulk-create call.
-	fn bulk_create_query(&self, models: &[Self::Model]) -> Option<InsertStatement> {
+	fn bulk_create_query(
+		&self,
+		models: &[Self::Model],
+	) -> reinhardt_core::exception::Result<Option<InsertStatement>> {
 		Manager::<Self::Model>::new().bulk_create_query(models)
 	}
 
 	/// Render the bulk-create SQL for a backend.
-	fn bulk_create_sql(&self, models: &[Self::Model], backend: DatabaseBackend) -> String {
+	fn bulk_create_sql(
+		&self,
+		models: &[Self::Model],
+		backend: DatabaseBackend,
+	) -> reinhardt_core::exception::Result<String> {
 		Manager::<Self::Model>::new().bulk_create_sql(models, backend)
 	}
 
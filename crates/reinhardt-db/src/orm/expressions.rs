@@ -2,8 +2,10 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::marker::PhantomData;
 
+use crate::orm::gis::{Distance, Geometry};
 use crate::orm::query::{
-	FieldAssignment, Filter, FilterOperator, FilterValue, UpdateValue, quote_identifier,
+	FieldAssignment, Filter, FilterOperator, FilterValue, UpdateValue, parse_column_reference,
+	quote_identifier,
 };
 
 /// F expression - represents a database field reference
@@ -508,6 +510,40 @@ impl<M, T> FieldRef<M, T> {
 		)
 	}
 
+	/// Create a PostGIS filter matching geometries that lie within `geometry`.
+	pub fn within(&self, geometry: &impl Geometry) -> Filter {
+		Filter::new(
+			self.name.to_string(),
+			FilterOperator::GeoWithin,
+			FilterValue::String(geometry.to_ewkt()),
+		)
+	}
+
+	/// Create a PostGIS filter matching geometries within `distance` of `geometry`.
+	///
+	/// The comparison uses the geography type, so the distance is measured in
+	/// meters on the spheroid regardless of the column's SRID units.
+	pub fn distance_lte(&self, geometry: &impl Geometry, distance: Distance) -> Filter {
+		Filter::new(
+			self.name.to_string(),
+			FilterOperator::GeoDistanceLte,
+			FilterValue::List(vec![
+				FilterValue::String(geometry.to_ewkt()),
+				FilterValue::Float(distance.to_meters()),
+			]),
+		)
+	}
+
+	/// Create a PostGIS filter matching geometries whose bounding box contains
+	/// the bounding box of `geometry` (`~`).
+	pub fn bbcontains(&self, geometry: &impl Geometry) -> Filter {
+		Filter::new(
+			self.name.to_string(),
+			FilterOperator::GeoBBContains,
+			FilterValue::String(geometry.to_ewkt()),
+		)
+	}
+
 	/// Transform a date/datetime field to its date component.
 	pub fn date(&self) -> TransformedFieldRef<M> {
 		self.transform("DATE({field})")
@@ -937,6 +973,133 @@ impl Exists {
 	}
 }
 
+/// OrderBy - a single ORDER BY term
+/// Similar to Django's OrderBy, produced by `Lower(...).desc()` or `Collate(...).asc()`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderBy {
+	target: OrderTarget,
+	descending: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum OrderTarget {
+	Field(String),
+	Expression(String),
+}
+
+impl OrderBy {
+	/// Order by a field, using the `order_by` convention of a leading `-` for descending
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_db::orm::expressions::OrderBy;
+	///
+	/// assert_eq!(OrderBy::field("-created_at").to_sql(), "\"created_at\" DESC");
+	/// ```
+	pub fn field(spec: &str) -> Self {
+		match spec.strip_prefix('-') {
+			Some(field) => Self {
+				target: OrderTarget::Field(field.to_string()),
+				descending: true,
+			},
+			None => Self {
+				target: OrderTarget::Field(spec.to_string()),
+				descending: false,
+			},
+		}
+	}
+
+	/// Order ascending by a raw SQL expression
+	pub fn expression(sql: impl Into<String>) -> Self {
+		Self {
+			target: OrderTarget::Expression(sql.into()),
+			descending: false,
+		}
+	}
+
+	/// Order by a field under an explicit collation
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_db::orm::expressions::OrderBy;
+	///
+	/// assert_eq!(OrderBy::collate("-name", "C").to_sql(), "\"name\" COLLATE \"C\" DESC");
+	/// ```
+	pub fn collate(spec: &str, collation: &str) -> Self {
+		let ordering = Self::field(spec);
+		let OrderTarget::Field(field) = &ordering.target else {
+			unreachable!("OrderBy::field always targets a field");
+		};
+		let collated = super::functions::Collate::new(
+			super::annotation::AnnotationValue::Field(F::new(field.as_str())),
+			collation,
+		);
+		if ordering.descending {
+			collated.desc()
+		} else {
+			collated.asc()
+		}
+	}
+
+	/// Order by a field in natural order (`"item2"` before `"item10"`)
+	///
+	/// Uses the ICU collation named by [`NATURAL_COLLATION`](super::functions::NATURAL_COLLATION),
+	/// which must exist in the database (PostgreSQL only).
+	pub fn natural(spec: &str) -> Self {
+		Self::collate(spec, super::functions::NATURAL_COLLATION)
+	}
+
+	/// Switch to ascending order
+	pub fn asc(mut self) -> Self {
+		self.descending = false;
+		self
+	}
+
+	/// Switch to descending order
+	pub fn desc(mut self) -> Self {
+		self.descending = true;
+		self
+	}
+
+	/// Whether this term sorts descending
+	pub fn is_descending(&self) -> bool {
+		self.descending
+	}
+
+	/// Generate the ORDER BY term SQL
+	pub fn to_sql(&self) -> String {
+		let target = match &self.target {
+			OrderTarget::Field(field) => quote_identifier(field),
+			OrderTarget::Expression(sql) => sql.clone(),
+		};
+		let direction = if self.descending { "DESC" } else { "ASC" };
+		format!("{} {}", target, direction)
+	}
+
+	/// Convert to a query-builder expression and direction
+	pub fn to_query_order(
+		&self,
+	) -> (
+		reinhardt_query::prelude::Expr,
+		reinhardt_query::prelude::Order,
+	) {
+		use reinhardt_query::prelude::{Expr, Order};
+
+		let expr = match &self.target {
+			OrderTarget::Field(field) => Expr::col(parse_column_reference(field)),
+			OrderTarget::Expression(sql) => Expr::cust(sql.clone()),
+		};
+		let order = if self.descending {
+			Order::Desc
+		} else {
+			Order::Asc
+		};
+		(expr, order)
+	}
+}
+
 /// Value expression - represents a literal value in a query
 /// Similar to Django's Value() for using literal values in expressions
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -2,10 +2,15 @@
 // Corresponds to Django's FileField and ImageField
 
 use super::fields::{BaseField, Field, FieldDeconstruction, FieldKwarg};
+use reinhardt_core::security::upload_scan::{ScanPolicy, ScanVerdict, UploadScanner};
 use std::fs;
 use std::io;
 use std::path::PathBuf;
 
+/// Directory, relative to the storage root, that flagged uploads are moved
+/// to under [`ScanPolicy::Quarantine`].
+pub const QUARANTINE_DIR: &str = "quarantine";
+
 /// Error types for file field operations
 #[non_exhaustive]
 #[derive(Debug)]
@@ -23,6 +28,20 @@ pub enum FileFieldError {
 		/// The actual.
 		actual: (u32, u32),
 	},
+	/// The upload scanner flagged the file and it was not stored.
+	Flagged {
+		/// The signature that matched.
+		signature: String,
+	},
+	/// The upload scanner flagged the file and it was quarantined.
+	Quarantined {
+		/// Path of the quarantined file, relative to the storage root.
+		path: String,
+		/// The signature that matched.
+		signature: String,
+	},
+	/// The upload scanner failed; the file was not stored.
+	ScanFailed(String),
 }
 
 impl std::fmt::Display for FileFieldError {
@@ -36,6 +55,15 @@ impl std::fmt::Display for FileFieldError {
 				"Invalid dimensions: expected {}x{}, got {}x{}",
 				expected.0, expected.1, actual.0, actual.1
 			),
+			FileFieldError::Flagged { signature } => {
+				write!(f, "File rejected by upload scanner: {}", signature)
+			}
+			FileFieldError::Quarantined { path, signature } => write!(
+				f,
+				"File quarantined by upload scanner as {}: {}",
+				path, signature
+			),
+			FileFieldError::ScanFailed(msg) => write!(f, "Upload scan failed: {}", msg),
 		}
 	}
 }
@@ -161,6 +189,62 @@ impl FileField {
 		Ok(relative_path.to_string_lossy().into_owned())
 	}
 
+	/// Scan file content, then save it to storage
+	///
+	/// Clean files are saved as with [`save`](Self::save). A flagged file is
+	/// never written to `upload_to`: with [`ScanPolicy::Reject`] it is
+	/// dropped, with [`ScanPolicy::Quarantine`] it is written under
+	/// [`QUARANTINE_DIR`] for review. Both cases, and scanner failures,
+	/// return an error.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use reinhardt_core::security::upload_scan::{FileTypeScanner, ScanPolicy};
+	/// use reinhardt_db::orm::file_fields::{FileField, FileFieldError};
+	///
+	/// let field = FileField::new("uploads/files");
+	/// let scanner = FileTypeScanner::new();
+	/// let result = field.save_scanned("setup.pdf", b"MZ\x90\x00", &scanner, ScanPolicy::Reject);
+	/// assert!(matches!(result, Err(FileFieldError::Flagged { .. })));
+	/// ```
+	pub fn save_scanned(
+		&self,
+		file_name: &str,
+		content: &[u8],
+		scanner: &dyn UploadScanner,
+		policy: ScanPolicy,
+	) -> Result<String, FileFieldError> {
+		let verdict = scanner
+			.scan(file_name, content)
+			.map_err(|e| FileFieldError::ScanFailed(e.to_string()))?;
+		let ScanVerdict::Flagged { signature } = verdict else {
+			return self.save(file_name, content);
+		};
+
+		match policy {
+			ScanPolicy::Quarantine => {
+				let relative_path = PathBuf::from(QUARANTINE_DIR)
+					.join(&self.upload_to)
+					.join(file_name);
+				let base_path = self
+					.storage_path
+					.clone()
+					.unwrap_or_else(|| PathBuf::from("."));
+				let file_path = base_path.join(&relative_path);
+				if let Some(parent) = file_path.parent() {
+					fs::create_dir_all(parent)?;
+				}
+				fs::write(&file_path, content)?;
+				Err(FileFieldError::Quarantined {
+					path: relative_path.to_string_lossy().into_owned(),
+					signature,
+				})
+			}
+			ScanPolicy::Reject => Err(FileFieldError::Flagged { signature }),
+		}
+	}
+
 	/// Generate URL for the file
 	///
 	/// # Examples
@@ -775,6 +859,47 @@ mod tests {
 		fs::remove_dir_all(temp_dir).ok();
 	}
 
+	#[test]
+	fn test_file_field_save_scanned_rejects_flagged_file() {
+		use reinhardt_core::security::upload_scan::FileTypeScanner;
+
+		let temp_dir = tempfile::tempdir().unwrap();
+		let field = FileField::with_storage("uploads/files", temp_dir.path().to_path_buf());
+		let scanner = FileTypeScanner::new();
+
+		let clean = field
+			.save_scanned("notes.txt", b"plain text", &scanner, ScanPolicy::Reject)
+			.unwrap();
+		assert!(field.exists(&clean));
+
+		let result = field.save_scanned("setup.pdf", b"MZ\x90\x00", &scanner, ScanPolicy::Reject);
+		assert!(matches!(result, Err(FileFieldError::Flagged { .. })));
+		assert!(!field.exists("uploads/files/setup.pdf"));
+	}
+
+	#[test]
+	fn test_file_field_save_scanned_quarantines_flagged_file() {
+		use reinhardt_core::security::upload_scan::FileTypeScanner;
+
+		let temp_dir = tempfile::tempdir().unwrap();
+		let field = FileField::with_storage("uploads/files", temp_dir.path().to_path_buf());
+
+		let result = field.save_scanned(
+			"setup.pdf",
+			b"MZ\x90\x00",
+			&FileTypeScanner::new(),
+			ScanPolicy::Quarantine,
+		);
+
+		let Err(FileFieldError::Quarantined { path, signature }) = result else {
+			panic!("expected quarantined file, got {:?}", result);
+		};
+		assert_eq!(signature, "Executable.Windows.PE");
+		assert!(path.starts_with(QUARANTINE_DIR));
+		assert!(field.exists(&path));
+		assert!(!field.exists("uploads/files/setup.pdf"));
+	}
+
 	// ImageField tests
 	#[test]
 	fn test_image_field_new() {
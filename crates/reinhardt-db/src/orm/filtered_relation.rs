@@ -30,6 +30,8 @@
 //! Copyright 2005-2025 SQLAlchemy authors and contributors
 //! Licensed under MIT License. See THIRD-PARTY-NOTICES for details.
 
+use crate::orm::connection::DatabaseBackend;
+use crate::orm::query_fields::custom::get_lookup;
 use crate::orm::query_fields::{LookupType, LookupValue};
 use std::collections::HashMap;
 
@@ -149,6 +151,11 @@ impl FilterCondition {
 			}
 			LookupType::Regex => format!("{} ~ '{}'", field_ref, self.extract_string()),
 			LookupType::IRegex => format!("{} ~* '{}'", field_ref, self.extract_string()),
+			LookupType::Custom(name) => {
+				let lookup = get_lookup(name)
+					.unwrap_or_else(|| panic!("no lookup registered as `{}`", name));
+				lookup.as_sql(DatabaseBackend::Postgres, &field_ref, &self.format_value())
+			}
 		}
 	}
 
@@ -1,5 +1,8 @@
 use crate::orm::annotation::AnnotationValue;
+use crate::orm::connection::DatabaseBackend;
+use crate::orm::expressions::OrderBy;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 
 /// Base database function trait
 pub trait DatabaseFunction {
@@ -104,6 +107,62 @@ impl SqlType {
 			SqlType::Json => "JSON".to_string(),
 		}
 	}
+
+	/// Generate the `CAST` target type name for a specific backend
+	///
+	/// PostgreSQL accepts the standard names from [`SqlType::to_sql`]. MySQL
+	/// only casts to a fixed set of targets (`SIGNED`, `CHAR`, `DATETIME`, ...),
+	/// and SQLite maps names to storage affinities, so textual and temporal
+	/// types are cast to `TEXT` there to keep their value intact.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_db::orm::connection::DatabaseBackend;
+	/// use reinhardt_db::orm::functions::SqlType;
+	///
+	/// assert_eq!(SqlType::Integer.to_sql_for(DatabaseBackend::MySql), "SIGNED");
+	/// assert_eq!(SqlType::Timestamp.to_sql_for(DatabaseBackend::MySql), "DATETIME");
+	/// assert_eq!(SqlType::Timestamp.to_sql_for(DatabaseBackend::Sqlite), "TEXT");
+	/// assert_eq!(SqlType::Integer.to_sql_for(DatabaseBackend::Postgres), "INTEGER");
+	/// ```
+	pub fn to_sql_for(&self, backend: DatabaseBackend) -> String {
+		match backend {
+			DatabaseBackend::Postgres => self.to_sql(),
+			DatabaseBackend::MySql => match self {
+				SqlType::Integer | SqlType::BigInt | SqlType::SmallInt | SqlType::Boolean => {
+					"SIGNED".to_string()
+				}
+				SqlType::Float | SqlType::Real => "FLOAT".to_string(),
+				SqlType::Double => "DOUBLE".to_string(),
+				SqlType::Text => "CHAR".to_string(),
+				SqlType::Varchar { length } => match length {
+					Some(len) => format!("CHAR({})", len),
+					None => "CHAR".to_string(),
+				},
+				SqlType::Timestamp => "DATETIME".to_string(),
+				SqlType::Decimal { .. }
+				| SqlType::Char { .. }
+				| SqlType::Date
+				| SqlType::Time
+				| SqlType::Json => self.to_sql(),
+			},
+			DatabaseBackend::Sqlite => match self {
+				SqlType::Integer | SqlType::BigInt | SqlType::SmallInt | SqlType::Boolean => {
+					"INTEGER".to_string()
+				}
+				SqlType::Float | SqlType::Real | SqlType::Double => "REAL".to_string(),
+				SqlType::Decimal { .. } => "NUMERIC".to_string(),
+				SqlType::Text
+				| SqlType::Varchar { .. }
+				| SqlType::Char { .. }
+				| SqlType::Date
+				| SqlType::Time
+				| SqlType::Timestamp
+				| SqlType::Json => "TEXT".to_string(),
+			},
+		}
+	}
 }
 
 impl Cast {
@@ -182,10 +241,27 @@ impl Cast {
 	/// assert_eq!(cast.to_sql(), "CAST(\"id\" AS VARCHAR(50))");
 	/// ```
 	pub fn to_sql(&self) -> String {
+		self.to_sql_for(DatabaseBackend::Postgres)
+	}
+
+	/// Generate SQL for CAST expression targeting a specific backend
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_db::orm::annotation::AnnotationValue;
+	/// use reinhardt_db::orm::connection::DatabaseBackend;
+	/// use reinhardt_db::orm::expressions::F;
+	/// use reinhardt_db::orm::functions::{Cast, SqlType};
+	///
+	/// let cast = Cast::new(AnnotationValue::Field(F::new("score")), SqlType::Integer);
+	/// assert_eq!(cast.to_sql_for(DatabaseBackend::MySql), "CAST(\"score\" AS SIGNED)");
+	/// ```
+	pub fn to_sql_for(&self, backend: DatabaseBackend) -> String {
 		format!(
 			"CAST({} AS {})",
-			self.expression.to_sql(),
-			self.target_type.to_sql()
+			self.expression.to_sql_for(backend),
+			self.target_type.to_sql_for(backend)
 		)
 	}
 }
@@ -449,6 +525,16 @@ impl Lower {
 		format!("LOWER({})", self.expression.to_sql())
 	}
 
+	/// Ascending case-insensitive ordering term for `order_by_expressions`
+	pub fn asc(&self) -> OrderBy {
+		OrderBy::expression(self.to_sql())
+	}
+
+	/// Descending case-insensitive ordering term for `order_by_expressions`
+	pub fn desc(&self) -> OrderBy {
+		OrderBy::expression(self.to_sql()).desc()
+	}
+
 	/// Get a reference to the expression
 	pub fn expression(&self) -> &AnnotationValue {
 		&self.expression
@@ -460,6 +546,121 @@ impl Lower {
 	}
 }
 
+/// Name of the ICU collation used for natural ordering (`"item2" < "item10"`).
+///
+/// Create it with `migrations::operations::postgres::collations::natural()`.
+pub const NATURAL_COLLATION: &str = "natural";
+
+/// Collate - apply an explicit collation to a string expression
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Collate {
+	expression: Box<AnnotationValue>,
+	collation: String,
+}
+
+impl Collate {
+	/// Create a new Collate expression
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_db::orm::functions::Collate;
+	/// use reinhardt_db::orm::annotation::AnnotationValue;
+	/// use reinhardt_db::orm::expressions::F;
+	///
+	/// let collate = Collate::new(AnnotationValue::Field(F::new("name")), "de-DE-x-icu");
+	/// assert_eq!(collate.to_sql(), "\"name\" COLLATE \"de-DE-x-icu\"");
+	/// ```
+	pub fn new(expression: AnnotationValue, collation: impl Into<String>) -> Self {
+		Self {
+			expression: Box::new(expression),
+			collation: collation.into(),
+		}
+	}
+
+	/// Documentation for `to_sql`
+	///
+	pub fn to_sql(&self) -> String {
+		format!(
+			"{} COLLATE \"{}\"",
+			self.expression.to_sql(),
+			self.collation.replace('"', "\"\"")
+		)
+	}
+
+	/// Get the collation name
+	pub fn collation(&self) -> &str {
+		&self.collation
+	}
+
+	/// Ascending collated ordering term for `order_by_expressions`
+	pub fn asc(&self) -> OrderBy {
+		OrderBy::expression(self.to_sql())
+	}
+
+	/// Descending collated ordering term for `order_by_expressions`
+	pub fn desc(&self) -> OrderBy {
+		OrderBy::expression(self.to_sql()).desc()
+	}
+}
+
+/// Compare two strings in natural order
+///
+/// Runs of ASCII digits are compared numerically and the remaining text
+/// case-insensitively, so `"file2"` sorts before `"File10"`. Ties fall back to
+/// a plain byte comparison to keep the order total.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_db::orm::functions::natural_cmp;
+///
+/// let mut names = vec!["item10", "Item2", "item1"];
+/// names.sort_by(|a, b| natural_cmp(a, b));
+/// assert_eq!(names, vec!["item1", "Item2", "item10"]);
+/// ```
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+	let mut left = a.chars().peekable();
+	let mut right = b.chars().peekable();
+
+	loop {
+		match (left.peek().copied(), right.peek().copied()) {
+			(None, None) => return a.cmp(b),
+			(None, Some(_)) => return Ordering::Less,
+			(Some(_), None) => return Ordering::Greater,
+			(Some(l), Some(r)) if l.is_ascii_digit() && r.is_ascii_digit() => {
+				let l_digits = take_digits(&mut left);
+				let r_digits = take_digits(&mut right);
+				let l_trimmed = l_digits.trim_start_matches('0');
+				let r_trimmed = r_digits.trim_start_matches('0');
+				let ordering = l_trimmed
+					.len()
+					.cmp(&r_trimmed.len())
+					.then_with(|| l_trimmed.cmp(r_trimmed));
+				if ordering != Ordering::Equal {
+					return ordering;
+				}
+			}
+			(Some(l), Some(r)) => {
+				let ordering = l.to_lowercase().cmp(r.to_lowercase());
+				if ordering != Ordering::Equal {
+					return ordering;
+				}
+				left.next();
+				right.next();
+			}
+		}
+	}
+}
+
+fn take_digits(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+	let mut digits = String::new();
+	while let Some(c) = chars.next_if(char::is_ascii_digit) {
+		digits.push(c);
+	}
+	digits
+}
+
 /// Length - return the length of a string
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Length {
@@ -1281,6 +1482,34 @@ mod tests {
 	use crate::orm::annotation::Value;
 	use crate::orm::expressions::F;
 
+	#[test]
+	fn test_collate_quotes_collation_name() {
+		let collate = Collate::new(AnnotationValue::Field(F::new("title")), "C");
+		assert_eq!(collate.to_sql(), "\"title\" COLLATE \"C\"");
+		assert_eq!(collate.desc().to_sql(), "\"title\" COLLATE \"C\" DESC");
+	}
+
+	#[test]
+	fn test_lower_ordering_terms() {
+		let lower = Lower::new(AnnotationValue::Field(F::new("name")));
+		assert_eq!(lower.asc().to_sql(), "LOWER(\"name\") ASC");
+		assert_eq!(lower.desc().to_sql(), "LOWER(\"name\") DESC");
+	}
+
+	#[test]
+	fn test_natural_cmp_orders_numbers_by_value() {
+		let mut names = vec!["v1.10", "v1.9", "v1.2", "V1.2a", "v01.2"];
+		names.sort_by(|a, b| natural_cmp(a, b));
+		assert_eq!(names, vec!["v01.2", "v1.2", "V1.2a", "v1.9", "v1.10"]);
+	}
+
+	#[test]
+	fn test_natural_cmp_is_case_insensitive_with_stable_tiebreak() {
+		assert_eq!(natural_cmp("apple", "Banana"), Ordering::Less);
+		assert_eq!(natural_cmp("Apple", "apple"), "Apple".cmp("apple"));
+		assert_eq!(natural_cmp("a", "a"), Ordering::Equal);
+	}
+
 	#[test]
 	fn test_cast_to_integer() {
 		let cast = Cast::new(AnnotationValue::Field(F::new("price")), SqlType::Integer);
@@ -54,6 +54,21 @@ impl Point {
 	pub fn with_srid(x: f64, y: f64, srid: i32) -> Self {
 		Self { x, y, srid }
 	}
+	/// Parse a GeoJSON `Point` geometry (SRID 4326, per RFC 7946)
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_db::orm::gis::Point;
+	/// use serde_json::json;
+	///
+	/// let point = Point::from_geojson(&json!({"type": "Point", "coordinates": [139.7, 35.68]})).unwrap();
+	/// assert_eq!(point.x, 139.7);
+	/// assert_eq!(point.srid, 4326);
+	/// ```
+	pub fn from_geojson(value: &serde_json::Value) -> reinhardt_core::exception::Result<Self> {
+		geojson_position(geojson_coordinates(value, GeometryType::Point)?)
+	}
 	/// Calculate distance to another point
 	/// Automatically selects appropriate method based on SRID:
 	/// - SRID 4326 (WGS84): Uses Haversine formula for geographic distance in meters
@@ -170,6 +185,90 @@ impl Polygon {
 	pub fn contains_point(&self, point: &Point) -> bool {
 		point_in_polygon(point, &self.exterior)
 	}
+	/// Parse a GeoJSON `Polygon` geometry (SRID 4326, per RFC 7946)
+	///
+	/// The first ring is the exterior; any further rings are holes.
+	pub fn from_geojson(value: &serde_json::Value) -> reinhardt_core::exception::Result<Self> {
+		let rings = geojson_coordinates(value, GeometryType::Polygon)?
+			.as_array()
+			.ok_or_else(|| geojson_error("polygon coordinates must be an array of rings"))?;
+		let mut rings = rings
+			.iter()
+			.map(geojson_ring)
+			.collect::<reinhardt_core::exception::Result<Vec<_>>>()?
+			.into_iter();
+		let exterior = rings
+			.next()
+			.ok_or_else(|| geojson_error("polygon must have an exterior ring"))?;
+		Ok(Self {
+			exterior,
+			interiors: rings.collect(),
+			srid: 4326,
+		})
+	}
+}
+
+impl Geometry for Point {
+	fn geometry_type(&self) -> GeometryType {
+		GeometryType::Point
+	}
+
+	fn srid(&self) -> i32 {
+		self.srid
+	}
+
+	fn to_wkt(&self) -> String {
+		format!("POINT({} {})", self.x, self.y)
+	}
+
+	fn to_geojson(&self) -> serde_json::Value {
+		serde_json::json!({"type": "Point", "coordinates": [self.x, self.y]})
+	}
+}
+
+impl Geometry for LineString {
+	fn geometry_type(&self) -> GeometryType {
+		GeometryType::LineString
+	}
+
+	fn srid(&self) -> i32 {
+		self.srid
+	}
+
+	fn to_wkt(&self) -> String {
+		format!("LINESTRING({})", wkt_coords(&self.points))
+	}
+
+	fn to_geojson(&self) -> serde_json::Value {
+		serde_json::json!({"type": "LineString", "coordinates": geojson_coords(&self.points)})
+	}
+}
+
+impl Geometry for Polygon {
+	fn geometry_type(&self) -> GeometryType {
+		GeometryType::Polygon
+	}
+
+	fn srid(&self) -> i32 {
+		self.srid
+	}
+
+	fn to_wkt(&self) -> String {
+		let rings = std::iter::once(&self.exterior)
+			.chain(&self.interiors)
+			.map(|ring| format!("({})", wkt_coords(ring)))
+			.collect::<Vec<_>>()
+			.join(", ");
+		format!("POLYGON({})", rings)
+	}
+
+	fn to_geojson(&self) -> serde_json::Value {
+		let rings: Vec<_> = std::iter::once(&self.exterior)
+			.chain(&self.interiors)
+			.map(|ring| geojson_coords(ring))
+			.collect();
+		serde_json::json!({"type": "Polygon", "coordinates": rings})
+	}
 }
 
 /// Calculate area of a ring using the shoelace formula
@@ -250,6 +349,144 @@ pub struct MultiPolygon {
 	pub srid: i32,
 }
 
+// ============= GEOMETRY ENCODING =============
+
+/// PostGIS geometry type names used for column definitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GeometryType {
+	/// `Point` geometry.
+	Point,
+	/// `LineString` geometry.
+	LineString,
+	/// `Polygon` geometry.
+	Polygon,
+	/// `MultiPoint` geometry.
+	MultiPoint,
+	/// `MultiLineString` geometry.
+	MultiLineString,
+	/// `MultiPolygon` geometry.
+	MultiPolygon,
+}
+
+impl GeometryType {
+	/// Type name as used by WKT, GeoJSON and PostGIS typmods.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			GeometryType::Point => "Point",
+			GeometryType::LineString => "LineString",
+			GeometryType::Polygon => "Polygon",
+			GeometryType::MultiPoint => "MultiPoint",
+			GeometryType::MultiLineString => "MultiLineString",
+			GeometryType::MultiPolygon => "MultiPolygon",
+		}
+	}
+
+	/// PostGIS column type for this geometry in the given SRID
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_db::orm::gis::GeometryType;
+	///
+	/// assert_eq!(GeometryType::Point.column_type(4326), "geometry(Point, 4326)");
+	/// ```
+	pub fn column_type(&self, srid: i32) -> String {
+		format!("geometry({}, {})", self.as_str(), srid)
+	}
+}
+
+/// Geometry values that can be bound to PostGIS queries and exchanged as GeoJSON.
+pub trait Geometry {
+	/// Geometry type of this value.
+	fn geometry_type(&self) -> GeometryType;
+	/// Spatial Reference System Identifier of this value.
+	fn srid(&self) -> i32;
+	/// Well-Known Text representation, e.g. `POINT(1 2)`.
+	fn to_wkt(&self) -> String;
+	/// GeoJSON geometry object (RFC 7946).
+	fn to_geojson(&self) -> serde_json::Value;
+
+	/// Extended WKT carrying the SRID, e.g. `SRID=4326;POINT(1 2)`.
+	///
+	/// This is the form passed to `ST_GeomFromEWKT` by the spatial lookups.
+	fn to_ewkt(&self) -> String {
+		format!("SRID={};{}", self.srid(), self.to_wkt())
+	}
+}
+
+fn wkt_coords(points: &[Point]) -> String {
+	points
+		.iter()
+		.map(|p| format!("{} {}", p.x, p.y))
+		.collect::<Vec<_>>()
+		.join(", ")
+}
+
+fn geojson_coords(points: &[Point]) -> serde_json::Value {
+	serde_json::Value::Array(
+		points
+			.iter()
+			.map(|p| serde_json::json!([p.x, p.y]))
+			.collect(),
+	)
+}
+
+fn geojson_error(message: impl Into<String>) -> reinhardt_core::exception::Error {
+	reinhardt_core::exception::Error::Validation(format!("Invalid GeoJSON: {}", message.into()))
+}
+
+/// Extract `coordinates` from a GeoJSON object after checking its `type`.
+fn geojson_coordinates<'a>(
+	value: &'a serde_json::Value,
+	expected: GeometryType,
+) -> reinhardt_core::exception::Result<&'a serde_json::Value> {
+	let kind = value
+		.get("type")
+		.and_then(serde_json::Value::as_str)
+		.ok_or_else(|| geojson_error("missing \"type\" member"))?;
+	if kind != expected.as_str() {
+		return Err(geojson_error(format!(
+			"expected {} geometry, got {}",
+			expected.as_str(),
+			kind
+		)));
+	}
+	value
+		.get("coordinates")
+		.ok_or_else(|| geojson_error("missing \"coordinates\" member"))
+}
+
+fn geojson_position(value: &serde_json::Value) -> reinhardt_core::exception::Result<Point> {
+	let position = value
+		.as_array()
+		.filter(|position| position.len() >= 2)
+		.ok_or_else(|| geojson_error("position must be an array of at least two numbers"))?;
+	match (position[0].as_f64(), position[1].as_f64()) {
+		(Some(x), Some(y)) => Ok(Point::new(x, y)),
+		_ => Err(geojson_error("position coordinates must be numbers")),
+	}
+}
+
+fn geojson_ring(value: &serde_json::Value) -> reinhardt_core::exception::Result<Vec<Point>> {
+	let positions = value
+		.as_array()
+		.ok_or_else(|| geojson_error("linear ring must be an array of positions"))?;
+	if positions.len() < 4 {
+		return Err(geojson_error(
+			"linear ring must have at least four positions",
+		));
+	}
+	let ring = positions
+		.iter()
+		.map(geojson_position)
+		.collect::<reinhardt_core::exception::Result<Vec<_>>>()?;
+	let (first, last) = (&ring[0], &ring[ring.len() - 1]);
+	if first.x != last.x || first.y != last.y {
+		return Err(geojson_error("linear ring must be closed"));
+	}
+	Ok(ring)
+}
+
 // ============= SPATIAL OPERATIONS =============
 
 /// Trait defining spatial ops behavior.
@@ -605,7 +842,7 @@ impl SpatialAggregate {
 }
 // - Measure operations (Area, Length, Perimeter)
 // - Simplify, Buffer, Centroid
-// - GeoJSON, WKT, WKB format support
+// - WKB format support
 
 #[cfg(test)]
 mod tests {
@@ -1111,4 +1348,85 @@ mod tests {
 		assert!(geodesic > 100000.0); // ~111km for 1 degree at equator
 		assert!(planar < geodesic); // planar is just 1.0
 	}
+
+	fn square() -> Polygon {
+		Polygon {
+			exterior: vec![
+				Point::new(0.0, 0.0),
+				Point::new(1.0, 0.0),
+				Point::new(1.0, 1.0),
+				Point::new(0.0, 1.0),
+				Point::new(0.0, 0.0),
+			],
+			interiors: vec![],
+			srid: 4326,
+		}
+	}
+
+	#[test]
+	fn test_geometry_column_type() {
+		assert_eq!(
+			GeometryType::Polygon.column_type(3857),
+			"geometry(Polygon, 3857)"
+		);
+	}
+
+	#[test]
+	fn test_point_wkt_and_ewkt() {
+		let point = Point::new(139.7, 35.68);
+		assert_eq!(point.to_wkt(), "POINT(139.7 35.68)");
+		assert_eq!(point.to_ewkt(), "SRID=4326;POINT(139.7 35.68)");
+	}
+
+	#[test]
+	fn test_polygon_wkt_with_hole() {
+		let mut polygon = square();
+		polygon.interiors.push(vec![
+			Point::new(0.2, 0.2),
+			Point::new(0.4, 0.2),
+			Point::new(0.4, 0.4),
+			Point::new(0.2, 0.2),
+		]);
+		assert_eq!(
+			polygon.to_wkt(),
+			"POLYGON((0 0, 1 0, 1 1, 0 1, 0 0), (0.2 0.2, 0.4 0.2, 0.4 0.4, 0.2 0.2))"
+		);
+	}
+
+	#[test]
+	fn test_point_geojson_round_trip() {
+		let point = Point::new(139.7, 35.68);
+		let json = point.to_geojson();
+		assert_eq!(
+			json,
+			serde_json::json!({"type": "Point", "coordinates": [139.7, 35.68]})
+		);
+		let parsed = Point::from_geojson(&json).unwrap();
+		assert_eq!((parsed.x, parsed.y, parsed.srid), (139.7, 35.68, 4326));
+	}
+
+	#[test]
+	fn test_polygon_geojson_round_trip() {
+		let json = square().to_geojson();
+		let parsed = Polygon::from_geojson(&json).unwrap();
+		assert_eq!(parsed.exterior.len(), 5);
+		assert!(parsed.interiors.is_empty());
+		assert_eq!(parsed.to_geojson(), json);
+	}
+
+	#[test]
+	fn test_geojson_rejects_wrong_type() {
+		let json = serde_json::json!({"type": "Polygon", "coordinates": []});
+		assert!(Point::from_geojson(&json).is_err());
+	}
+
+	#[test]
+	fn test_geojson_rejects_open_ring() {
+		let json = serde_json::json!({
+			"type": "Polygon",
+			"coordinates": [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]]
+		});
+		let err = Polygon::from_geojson(&json).unwrap_err();
+		assert!(err.to_string().contains("closed"));
+	}
 }
@@ -165,6 +165,10 @@ pub struct Statistics {
 	pub committed_transactions: usize,
 	/// Total number of rolled back transactions
 	pub rolled_back_transactions: usize,
+	/// Statements served from a prepared statement cache, across all backends
+	pub statement_cache_hits: u64,
+	/// Statements that had to be prepared, across all backends
+	pub statement_cache_misses: u64,
 }
 
 impl Statistics {
@@ -553,7 +557,12 @@ impl Instrumentation {
 	/// assert_eq!(stats.total_queries, 0);
 	/// ```
 	pub fn statistics(&self) -> Statistics {
-		self.statistics.read().clone()
+		let cache = crate::backends::optimization::statement_cache_stats();
+		Statistics {
+			statement_cache_hits: cache.hits,
+			statement_cache_misses: cache.misses,
+			..self.statistics.read().clone()
+		}
 	}
 
 	/// Clears all collected metrics
@@ -0,0 +1,429 @@
+//! Streaming access to large binary values
+//!
+//! Binary model fields (`Vec<u8>` with [`BinaryField`](super::fields::BinaryField))
+//! are loaded and saved whole with the rest of the row. Files kept in the
+//! database can be too large for that, so this module reads and writes them
+//! in chunks:
+//!
+//! - [`BinaryColumn`] streams a `bytea`/`BLOB` column of one row. Reads use
+//!   `SUBSTRING`, writes append one chunk per `UPDATE`. Works on every backend.
+//! - [`LargeObject`] streams a PostgreSQL large object, referenced from a
+//!   model by its OID in a `BigInteger` column. Large objects are not limited
+//!   to the 1 GB of a `bytea` value.
+//!
+//! Writes are not atomic: a failed upload leaves the bytes written so far.
+//! Run them in a transaction when that matters.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use futures::TryStreamExt;
+//! use reinhardt_db::orm::large_objects::{BinaryColumn, DEFAULT_CHUNK_SIZE};
+//!
+//! let column = BinaryColumn::of::<Document>("content", document.id.unwrap());
+//! column.write_chunks(&conn, upload_stream).await?;
+//!
+//! let chunks = column.read_chunks(&conn, DEFAULT_CHUNK_SIZE);
+//! let body: Vec<Vec<u8>> = chunks.try_collect().await?;
+//! ```
+
+use super::Model;
+use super::connection::{DatabaseBackend, DatabaseConnection, QueryValue};
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use reinhardt_core::exception::{Error, Result};
+use std::future::Future;
+
+/// Chunk size used when none is given: 256 KiB
+pub const DEFAULT_CHUNK_SIZE: usize = 256 * 1024;
+
+/// A binary column of one row, read and written in chunks
+#[derive(Debug, Clone)]
+pub struct BinaryColumn {
+	table: String,
+	column: String,
+	pk_column: String,
+	pk: QueryValue,
+}
+
+impl BinaryColumn {
+	/// Column `column` of the row of `table` whose `pk_column` equals `pk`
+	pub fn new(
+		table: impl Into<String>,
+		column: impl Into<String>,
+		pk_column: impl Into<String>,
+		pk: impl Into<QueryValue>,
+	) -> Self {
+		Self {
+			table: table.into(),
+			column: column.into(),
+			pk_column: pk_column.into(),
+			pk: pk.into(),
+		}
+	}
+
+	/// Column `column` of the `M` instance with primary key `pk`
+	pub fn of<M: Model>(column: impl Into<String>, pk: impl Into<QueryValue>) -> Self {
+		Self::new(M::table_name(), column, M::primary_key_field(), pk)
+	}
+
+	/// Size of the value in bytes, `None` if the row is missing or the value is
+	/// NULL
+	pub async fn len(&self, conn: &DatabaseConnection) -> Result<Option<u64>> {
+		let backend = conn.backend();
+		let length = match backend {
+			DatabaseBackend::Postgres => "octet_length",
+			DatabaseBackend::MySql | DatabaseBackend::Sqlite => "length",
+		};
+		let sql = format!(
+			"SELECT {length}({column}) AS size FROM {table} WHERE {pk_column} = {p1}",
+			column = quote(backend, &self.column),
+			table = quote_table(backend, &self.table),
+			pk_column = quote(backend, &self.pk_column),
+			p1 = placeholder(backend, 1),
+		);
+		let row = fetch_optional(conn, &sql, vec![self.pk.clone()]).await?;
+		Ok(row.and_then(|row| match row.data.get("size") {
+			Some(QueryValue::Int(size)) => u64::try_from(*size).ok(),
+			_ => None,
+		}))
+	}
+
+	/// Stream the value in chunks of at most `chunk_size` bytes
+	///
+	/// Each chunk is a separate query, so the whole value is never held in
+	/// memory. A missing row or NULL value yields an empty stream.
+	pub fn read_chunks<'a>(
+		&'a self,
+		conn: &'a DatabaseConnection,
+		chunk_size: usize,
+	) -> impl Stream<Item = Result<Vec<u8>>> + Send + 'a {
+		let backend = conn.backend();
+		let substring = match backend {
+			DatabaseBackend::Postgres => format!(
+				"substring({} FROM CAST($1 AS integer) FOR CAST($2 AS integer))",
+				quote(backend, &self.column)
+			),
+			DatabaseBackend::MySql => format!("SUBSTRING({}, ?, ?)", quote(backend, &self.column)),
+			DatabaseBackend::Sqlite => format!("substr({}, ?, ?)", quote(backend, &self.column)),
+		};
+		let sql = format!(
+			"SELECT {substring} AS chunk FROM {table} WHERE {pk_column} = {p3}",
+			table = quote_table(backend, &self.table),
+			pk_column = quote(backend, &self.pk_column),
+			p3 = placeholder(backend, 3),
+		);
+		read_in_chunks(chunk_size, move |offset, len| {
+			let sql = sql.clone();
+			// SUBSTRING positions start at 1
+			let params = vec![
+				QueryValue::Int(offset as i64 + 1),
+				QueryValue::Int(len as i64),
+				self.pk.clone(),
+			];
+			async move {
+				let row = fetch_optional(conn, &sql, params).await?;
+				Ok(row.and_then(|row| bytes(row.data.get("chunk"))))
+			}
+		})
+	}
+
+	/// Replace the value with the concatenated `chunks`
+	///
+	/// Returns the number of bytes written, or an error if the row does not
+	/// exist.
+	pub async fn write_chunks<S>(&self, conn: &DatabaseConnection, chunks: S) -> Result<u64>
+	where
+		S: Stream<Item = Result<Vec<u8>>> + Send,
+	{
+		let backend = conn.backend();
+		let column = quote(backend, &self.column);
+		let table = quote_table(backend, &self.table);
+		let pk_column = quote(backend, &self.pk_column);
+		let (p1, p2) = (placeholder(backend, 1), placeholder(backend, 2));
+
+		let clear = format!("UPDATE {table} SET {column} = {p1} WHERE {pk_column} = {p2}");
+		let updated = execute(
+			conn,
+			&clear,
+			vec![QueryValue::Bytes(Vec::new()), self.pk.clone()],
+		)
+		.await?;
+		if updated == 0 {
+			return Err(Error::NotFound(format!(
+				"No row in {} with {} = {:?}",
+				self.table, self.pk_column, self.pk
+			)));
+		}
+
+		let appended = match backend {
+			DatabaseBackend::Postgres => format!("{column} || {p1}"),
+			DatabaseBackend::MySql => format!("CONCAT({column}, {p1})"),
+			// `||` yields TEXT in SQLite; the cast restores the bytes unchanged
+			DatabaseBackend::Sqlite => format!("CAST({column} || {p1} AS BLOB)"),
+		};
+		let append = format!("UPDATE {table} SET {column} = {appended} WHERE {pk_column} = {p2}");
+		let mut written = 0;
+		let mut chunks =
+			std::pin::pin!(chunks.try_filter(|chunk| std::future::ready(!chunk.is_empty())));
+		while let Some(chunk) = chunks.try_next().await? {
+			written += chunk.len() as u64;
+			execute(
+				conn,
+				&append,
+				vec![QueryValue::Bytes(chunk), self.pk.clone()],
+			)
+			.await?;
+		}
+		Ok(written)
+	}
+}
+
+/// A PostgreSQL large object
+///
+/// Store [`oid`](Self::oid) in a `BigInteger` column to reference the object
+/// from a model. Deleting the row does not delete the object; call
+/// [`unlink`](Self::unlink), e.g. from a `pre_delete` signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LargeObject {
+	oid: i64,
+}
+
+impl LargeObject {
+	/// The large object with the given OID
+	pub fn from_oid(oid: i64) -> Self {
+		Self { oid }
+	}
+
+	/// Create an empty large object
+	pub async fn create(conn: &DatabaseConnection) -> Result<Self> {
+		require_postgres(conn)?;
+		let oid = fetch_optional(conn, "SELECT CAST(lo_create(0) AS bigint) AS oid", vec![])
+			.await?
+			.and_then(|row| match row.data.get("oid") {
+				Some(QueryValue::Int(oid)) => Some(*oid),
+				_ => None,
+			})
+			.ok_or_else(|| Error::Database("lo_create returned no OID".to_string()))?;
+		Ok(Self { oid })
+	}
+
+	/// Create a large object holding the concatenated `chunks`
+	pub async fn create_from<S>(conn: &DatabaseConnection, chunks: S) -> Result<Self>
+	where
+		S: Stream<Item = Result<Vec<u8>>> + Send,
+	{
+		let object = Self::create(conn).await?;
+		object.write_chunks(conn, chunks).await?;
+		Ok(object)
+	}
+
+	/// OID of the object
+	pub fn oid(&self) -> i64 {
+		self.oid
+	}
+
+	/// Stream the object in chunks of at most `chunk_size` bytes
+	pub fn read_chunks<'a>(
+		&'a self,
+		conn: &'a DatabaseConnection,
+		chunk_size: usize,
+	) -> impl Stream<Item = Result<Vec<u8>>> + Send + 'a {
+		if let Err(error) = require_postgres(conn) {
+			return stream::once(std::future::ready(Err(error))).left_stream();
+		}
+		let oid = self.oid;
+		read_in_chunks(chunk_size, move |offset, len| {
+			let params = vec![
+				QueryValue::Int(oid),
+				QueryValue::Int(offset as i64),
+				QueryValue::Int(len as i64),
+			];
+			async move {
+				let sql = "SELECT lo_get(CAST($1 AS oid), $2, CAST($3 AS integer)) AS chunk";
+				let row = fetch_optional(conn, sql, params).await?;
+				Ok(row.and_then(|row| bytes(row.data.get("chunk"))))
+			}
+		})
+		.right_stream()
+	}
+
+	/// Write the concatenated `chunks` from the start of the object
+	///
+	/// Existing bytes past the written range are kept, so write to a freshly
+	/// created object to replace content. Returns the number of bytes written.
+	pub async fn write_chunks<S>(&self, conn: &DatabaseConnection, chunks: S) -> Result<u64>
+	where
+		S: Stream<Item = Result<Vec<u8>>> + Send,
+	{
+		require_postgres(conn)?;
+		let mut written = 0;
+		let mut chunks = std::pin::pin!(chunks);
+		while let Some(chunk) = chunks.try_next().await? {
+			let len = chunk.len() as u64;
+			// lo_put returns void, so no row is read back
+			execute(
+				conn,
+				"SELECT lo_put(CAST($1 AS oid), $2, $3)",
+				vec![
+					QueryValue::Int(self.oid),
+					QueryValue::Int(written as i64),
+					QueryValue::Bytes(chunk),
+				],
+			)
+			.await?;
+			written += len;
+		}
+		Ok(written)
+	}
+
+	/// Delete the object
+	pub async fn unlink(self, conn: &DatabaseConnection) -> Result<()> {
+		require_postgres(conn)?;
+		execute(
+			conn,
+			"SELECT lo_unlink(CAST($1 AS oid))",
+			vec![QueryValue::Int(self.oid)],
+		)
+		.await?;
+		Ok(())
+	}
+}
+
+/// Stream chunks fetched with `fetch(offset, len)` until one comes back
+/// shorter than requested
+fn read_in_chunks<'a, F, Fut>(
+	chunk_size: usize,
+	fetch: F,
+) -> impl Stream<Item = Result<Vec<u8>>> + Send + 'a
+where
+	F: Fn(u64, usize) -> Fut + Send + 'a,
+	Fut: Future<Output = Result<Option<Vec<u8>>>> + Send + 'a,
+{
+	let chunk_size = chunk_size.max(1);
+	stream::try_unfold(
+		(0u64, false, fetch),
+		move |(offset, done, fetch)| async move {
+			if done {
+				return Ok(None);
+			}
+			let chunk = fetch(offset, chunk_size).await?.unwrap_or_default();
+			if chunk.is_empty() {
+				return Ok(None);
+			}
+			let next = (offset + chunk.len() as u64, chunk.len() < chunk_size, fetch);
+			Ok(Some((chunk, next)))
+		},
+	)
+}
+
+fn bytes(value: Option<&QueryValue>) -> Option<Vec<u8>> {
+	match value? {
+		QueryValue::Bytes(bytes) => Some(bytes.clone()),
+		QueryValue::String(text) => Some(text.clone().into_bytes()),
+		_ => None,
+	}
+}
+
+fn require_postgres(conn: &DatabaseConnection) -> Result<()> {
+	if conn.backend() == DatabaseBackend::Postgres {
+		Ok(())
+	} else {
+		Err(Error::Database(
+			"Large objects are only supported on PostgreSQL".to_string(),
+		))
+	}
+}
+
+async fn fetch_optional(
+	conn: &DatabaseConnection,
+	sql: &str,
+	params: Vec<QueryValue>,
+) -> Result<Option<super::connection::Row>> {
+	conn.inner()
+		.fetch_optional(sql, params)
+		.await
+		.map_err(|e| Error::Database(e.to_string()))
+}
+
+async fn execute(conn: &DatabaseConnection, sql: &str, params: Vec<QueryValue>) -> Result<u64> {
+	conn.execute(sql, params)
+		.await
+		.map_err(|e| Error::Database(e.to_string()))
+}
+
+fn placeholder(backend: DatabaseBackend, index: usize) -> String {
+	match backend {
+		DatabaseBackend::Postgres => format!("${index}"),
+		DatabaseBackend::MySql | DatabaseBackend::Sqlite => "?".to_string(),
+	}
+}
+
+fn quote(backend: DatabaseBackend, ident: &str) -> String {
+	match backend {
+		DatabaseBackend::MySql => format!("`{}`", ident.replace('`', "``")),
+		DatabaseBackend::Postgres | DatabaseBackend::Sqlite => {
+			format!("\"{}\"", ident.replace('"', "\"\""))
+		}
+	}
+}
+
+/// Quote a possibly schema-qualified table name
+fn quote_table(backend: DatabaseBackend, table: &str) -> String {
+	table
+		.split('.')
+		.map(|part| quote(backend, part))
+		.collect::<Vec<_>>()
+		.join(".")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use std::sync::{Arc, Mutex};
+
+	#[rstest]
+	#[case(10, 4, vec![4, 4, 2])]
+	#[case(8, 4, vec![4, 4])]
+	#[case(0, 4, vec![])]
+	#[tokio::test]
+	async fn test_read_in_chunks_stops_after_short_chunk(
+		#[case] size: usize,
+		#[case] chunk_size: usize,
+		#[case] expected: Vec<usize>,
+	) {
+		// Arrange
+		let value: Vec<u8> = (0..size as u8).collect();
+		let calls = Arc::new(Mutex::new(0));
+		let counter = calls.clone();
+
+		// Act
+		let chunks: Vec<Vec<u8>> = read_in_chunks(chunk_size, move |offset, len| {
+			*counter.lock().unwrap() += 1;
+			let start = (offset as usize).min(value.len());
+			let end = (start + len).min(value.len());
+			std::future::ready(Ok(Some(value[start..end].to_vec())))
+		})
+		.try_collect()
+		.await
+		.unwrap();
+
+		// Assert
+		let sizes: Vec<usize> = chunks.iter().map(Vec::len).collect();
+		assert_eq!(sizes, expected);
+		assert_eq!(chunks.concat(), (0..size as u8).collect::<Vec<_>>());
+		// An exact multiple needs one extra query to see the end
+		assert_eq!(*calls.lock().unwrap(), size / chunk_size + 1);
+	}
+
+	#[rstest]
+	#[case(DatabaseBackend::Postgres, "\"media\".\"files\"")]
+	#[case(DatabaseBackend::MySql, "`media`.`files`")]
+	fn test_quote_table_handles_schema(#[case] backend: DatabaseBackend, #[case] expected: &str) {
+		// Act
+		let quoted = quote_table(backend, "media.files");
+
+		// Assert
+		assert_eq!(quoted, expected);
+	}
+}
@@ -1,11 +1,13 @@
 use super::connection::{DatabaseBackend, DatabaseConnection};
 use super::{Model, QuerySet};
+use crate::backends::DatabaseError;
 use reinhardt_query::prelude::{
 	Alias, ColumnRef, DeleteStatement, Expr, ExprTrait, Func, InsertStatement, MySqlQueryBuilder,
 	PostgresQueryBuilder, Query, QueryBuilder, SelectStatement, SqliteQueryBuilder,
 	UpdateStatement, Values,
 };
-use std::collections::HashMap;
+use reinhardt_utils::utils_core::timezone;
+use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -167,6 +169,80 @@ pub async fn reinitialize_database_with_pool_size(
 	Ok(())
 }
 
+/// Map the failure of a write, surfacing constraint violations as typed errors
+///
+/// Unique violations become 409 Conflict and foreign key violations 400 Bad
+/// Request (see [`DatabaseError`]); any other failure is kept as is.
+fn write_error(err: anyhow::Error) -> reinhardt_core::exception::Error {
+	match err.downcast_ref::<DatabaseError>() {
+		Some(
+			db_error @ (DatabaseError::UniqueViolation { .. }
+			| DatabaseError::ForeignKeyViolation { .. }),
+		) => db_error.clone().into(),
+		_ => err.into(),
+	}
+}
+
+/// Fields whose values need schema-aware binding, from the model metadata
+struct FieldKinds {
+	/// `BinaryField`s
+	binary: HashSet<String>,
+	/// `DateTimeField`s
+	datetime: HashSet<String>,
+	/// `DateTimeField`s with `auto_now`, refreshed on every update
+	auto_now: HashSet<String>,
+}
+
+impl FieldKinds {
+	fn of<M: Model>() -> Self {
+		let mut kinds = Self {
+			binary: HashSet::new(),
+			datetime: HashSet::new(),
+			auto_now: HashSet::new(),
+		};
+		for field in M::field_metadata() {
+			if field.field_type.ends_with(".BinaryField") {
+				kinds.binary.insert(field.name);
+			} else if field.field_type.ends_with(".DateTimeField") {
+				if matches!(
+					field.attributes.get("auto_now"),
+					Some(super::fields::FieldKwarg::Bool(true))
+				) {
+					kinds.auto_now.insert(field.name.clone());
+				}
+				kinds.datetime.insert(field.name);
+			}
+		}
+		kinds
+	}
+}
+
+/// Parse a datetime string that carries no UTC offset
+fn parse_naive_datetime(text: &str) -> Option<chrono::NaiveDateTime> {
+	[
+		"%Y-%m-%dT%H:%M:%S%.f",
+		"%Y-%m-%d %H:%M:%S%.f",
+		"%Y-%m-%dT%H:%M",
+	]
+	.iter()
+	.find_map(|format| chrono::NaiveDateTime::parse_from_str(text, format).ok())
+}
+
+/// Decode the JSON value of a binary field: base64 text or an array of bytes
+fn json_to_bytes(value: &serde_json::Value) -> Option<Vec<u8>> {
+	use base64::Engine;
+	match value {
+		serde_json::Value::String(text) => {
+			base64::engine::general_purpose::STANDARD.decode(text).ok()
+		}
+		serde_json::Value::Array(items) => items
+			.iter()
+			.map(|item| item.as_u64().and_then(|byte| u8::try_from(byte).ok()))
+			.collect(),
+		_ => None,
+	}
+}
+
 /// Get a reference to the global database connection
 pub async fn get_connection() -> reinhardt_core::exception::Result<DatabaseConnection> {
 	let db = DB.get().ok_or_else(|| {
@@ -227,6 +303,11 @@ impl<M: Model> Manager<M> {
 	/// Get a single record by primary key
 	/// Returns a QuerySet filtered by the primary key field
 	pub fn get(&self, pk: M::PrimaryKey) -> QuerySet<M> {
+		QuerySet::new().filter(Self::primary_key_filter(pk))
+	}
+
+	/// Build the `pk = value` filter used by [`Manager::get`].
+	pub(crate) fn primary_key_filter(pk: M::PrimaryKey) -> super::query::Filter {
 		let pk_field = M::primary_key_field();
 		let pk_str = pk.to_string();
 
@@ -237,12 +318,11 @@ impl<M: Model> Manager<M> {
 			super::query::FilterValue::String(pk_str)
 		};
 
-		let filter = super::query::Filter::new(
+		super::query::Filter::new(
 			pk_field.to_string(),
 			super::query::FilterOperator::Eq,
 			pk_value,
-		);
-		QuerySet::new().filter(filter)
+		)
 	}
 
 	/// Set LIMIT clause
@@ -691,8 +771,9 @@ impl<M: Model> Manager<M> {
 		conn: &DatabaseConnection,
 		model: &M,
 	) -> reinhardt_core::exception::Result<M> {
-		let json = serde_json::to_value(model)
+		let mut json = serde_json::to_value(model)
 			.map_err(|e| reinhardt_core::exception::Error::Database(e.to_string()))?;
+		super::tenancy::stamp_tenant::<M>(&mut json)?;
 
 		// Extract fields and values from model
 		let obj = json.as_object().ok_or_else(|| {
@@ -705,6 +786,7 @@ impl<M: Model> Manager<M> {
 
 		// Get the primary key field name to filter out auto-increment fields
 		let pk_field = M::primary_key_field();
+		let kinds = FieldKinds::of::<M>();
 
 		// Filter out primary key fields and null datetime fields.
 		// Null datetime fields are skipped to let database DEFAULT apply
@@ -739,7 +821,7 @@ impl<M: Model> Manager<M> {
 				let value = if v.is_null() {
 					reinhardt_query::value::Value::Int(None)
 				} else {
-					Self::json_to_sea_value(v)
+					Self::json_to_field_value(&kinds, k, v)
 				};
 				(Alias::new(k.as_str()), value)
 			})
@@ -760,13 +842,47 @@ impl<M: Model> Manager<M> {
 			.map(Self::sea_value_to_query_value)
 			.collect();
 
-		let row = conn.query_one(&sql, values).await?;
+		let row = conn.query_one(&sql, values).await.map_err(write_error)?;
 
 		// row.data is already serde_json::Value::Object so deserialize directly
 		serde_json::from_value(row.data.clone())
 			.map_err(|e| reinhardt_core::exception::Error::Database(e.to_string()))
 	}
 
+	/// Convert the JSON value of field `name` for parameter binding
+	///
+	/// Binary fields serialize as base64 text (with the `base64_bytes` serde
+	/// adapter) or as an array of numbers; both are bound as bytes.
+	///
+	/// Datetime fields given without a UTC offset are naive. With time zone
+	/// support active they are interpreted in the default time zone, and the
+	/// field is reported by the `timezone.W001` system check.
+	fn json_to_field_value(
+		kinds: &FieldKinds,
+		name: &str,
+		v: &serde_json::Value,
+	) -> reinhardt_query::value::Value {
+		if kinds.binary.contains(name)
+			&& let Some(bytes) = json_to_bytes(v)
+		{
+			return reinhardt_query::value::Value::Bytes(Some(Box::new(bytes)));
+		}
+		if kinds.datetime.contains(name)
+			&& let Some(naive) = v.as_str().and_then(parse_naive_datetime)
+		{
+			if !timezone::use_tz() {
+				return reinhardt_query::value::Value::ChronoDateTime(Some(Box::new(naive)));
+			}
+			timezone::record_naive_datetime(&format!("{}.{}", M::table_name(), name));
+			if let Ok(aware) = timezone::make_aware(naive, timezone::get_default_timezone()) {
+				return reinhardt_query::value::Value::ChronoDateTimeUtc(Some(Box::new(
+					aware.with_timezone(&chrono::Utc),
+				)));
+			}
+		}
+		Self::json_to_sea_value(v)
+	}
+
 	/// Convert serde_json::Value to reinhardt_query::value::Value for parameter binding
 	fn json_to_sea_value(v: &serde_json::Value) -> reinhardt_query::value::Value {
 		match v {
@@ -957,33 +1073,82 @@ impl<M: Model> Manager<M> {
 		&self,
 		conn: &DatabaseConnection,
 		model: &M,
+	) -> reinhardt_core::exception::Result<M> {
+		self.update_columns_with_conn(conn, model, None).await
+	}
+
+	/// Update only the given columns of a record with an explicit database connection
+	///
+	/// Unlike [`update_with_conn`](Self::update_with_conn), columns not listed
+	/// in `fields` (including `auto_now` columns) are left untouched. Naming
+	/// the primary key or a field the model does not serialize is an error.
+	pub async fn update_fields_with_conn(
+		&self,
+		conn: &DatabaseConnection,
+		model: &M,
+		fields: &[&str],
+	) -> reinhardt_core::exception::Result<M> {
+		self.update_columns_with_conn(conn, model, Some(fields))
+			.await
+	}
+
+	async fn update_columns_with_conn(
+		&self,
+		conn: &DatabaseConnection,
+		model: &M,
+		fields: Option<&[&str]>,
 	) -> reinhardt_core::exception::Result<M> {
 		let pk = model.primary_key().ok_or_else(|| {
 			reinhardt_core::exception::Error::Database("Model must have primary key".to_string())
 		})?;
 
-		let json = serde_json::to_value(model)
+		let mut json = serde_json::to_value(model)
 			.map_err(|e| reinhardt_core::exception::Error::Database(e.to_string()))?;
+		super::tenancy::stamp_tenant::<M>(&mut json)?;
 
 		let obj = json.as_object().ok_or_else(|| {
 			reinhardt_core::exception::Error::Database("Model must serialize to object".to_string())
 		})?;
 
+		if let Some(fields) = fields
+			&& let Some(unknown) = fields
+				.iter()
+				.find(|f| **f == M::primary_key_field() || !obj.contains_key(**f))
+		{
+			return Err(reinhardt_core::exception::Error::Database(format!(
+				"Cannot update field '{}' of {}",
+				unknown,
+				M::table_name()
+			)));
+		}
+
 		// Build reinhardt-query UPDATE statement
 		let mut stmt = Query::update();
 		stmt.table(Alias::new(M::table_name()));
 
-		// Add SET clauses for all fields except primary key
-		for (k, v) in obj
-			.iter()
-			.filter(|(k, _)| k.as_str() != M::primary_key_field())
-		{
-			if v.is_null() {
+		// Add SET clauses for all (or the requested) fields except primary key
+		let kinds = FieldKinds::of::<M>();
+		for (k, v) in obj.iter().filter(|(k, _)| {
+			k.as_str() != M::primary_key_field()
+				&& fields.is_none_or(|fields| fields.contains(&k.as_str()))
+		}) {
+			if kinds.auto_now.contains(k) {
+				// auto_now fields are refreshed on every save
+				stmt.value(
+					Alias::new(k.as_str()),
+					reinhardt_query::value::Value::ChronoDateTimeUtc(Some(Box::new(
+						timezone::now(),
+					))),
+				);
+			} else if v.is_null() {
 				// Use untyped NULL to avoid PostgreSQL type mismatch errors
 				// (e.g., setting timestamp column to NULL would fail with Int(None))
 				stmt.value_expr(Alias::new(k.as_str()), Expr::cust("NULL"));
 			} else {
-				stmt.value(Alias::new(k.as_str()), Self::json_to_sea_value(v));
+				stmt.value(
+					Alias::new(k.as_str()),
+					Self::json_to_field_value(&kinds, k, v),
+				);
 			}
 		}
 
@@ -998,6 +1163,10 @@ impl<M: Model> Manager<M> {
 			reinhardt_query::value::Value::String(Some(Box::new(pk_str)))
 		};
 		stmt.and_where(Expr::col(Alias::new(M::primary_key_field())).eq(pk_value));
+		// Rows of other tenants are never matched
+		if let Some(predicate) = super::tenancy::tenant_write_predicate::<M>()? {
+			stmt.and_where(predicate);
+		}
 
 		// Add RETURNING clause with explicit column names from JSON object
 		// Note: Using Asterisk in columns() may not work correctly with reinhardt-query
@@ -1011,7 +1180,7 @@ impl<M: Model> Manager<M> {
 			.map(Self::sea_value_to_query_value)
 			.collect();
 
-		let row = conn.query_one(&sql, values).await?;
+		let row = conn.query_one(&sql, values).await.map_err(write_error)?;
 		// row.data is already serde_json::Value::Object so deserialize directly
 		serde_json::from_value(row.data.clone())
 			.map_err(|e| reinhardt_core::exception::Error::Database(e.to_string()))
@@ -1070,6 +1239,9 @@ impl<M: Model> Manager<M> {
 
 		stmt.from_table(Alias::new(M::table_name()))
 			.and_where(Expr::col(Alias::new(M::primary_key_field())).eq(pk_value));
+		if let Some(predicate) = super::tenancy::tenant_write_predicate::<M>()? {
+			stmt.and_where(predicate);
+		}
 
 		let (sql, values) = build_delete_sql(&stmt, conn.backend());
 		let values: Vec<_> = values
@@ -1078,7 +1250,7 @@ impl<M: Model> Manager<M> {
 			.map(Self::sea_value_to_query_value)
 			.collect();
 
-		conn.execute(&sql, values).await?;
+		conn.execute(&sql, values).await.map_err(write_error)?;
 		Ok(())
 	}
 
@@ -1119,10 +1291,13 @@ impl<M: Model> Manager<M> {
 		conn: &DatabaseConnection,
 	) -> reinhardt_core::exception::Result<i64> {
 		// Build reinhardt-query SELECT COUNT(*) statement with explicit alias
-		let stmt = Query::select()
+		let mut stmt = Query::select()
 			.from(Alias::new(M::table_name()))
 			.expr_as(Func::count(Expr::asterisk().into()), Alias::new("count"))
 			.to_owned();
+		if let Some(predicate) = super::tenancy::tenant_read_predicate::<M>() {
+			stmt.and_where(predicate);
+		}
 
 		let (sql, values) = build_select_sql(&stmt, conn.backend());
 		let values: Vec<_> = values
@@ -1138,23 +1313,31 @@ impl<M: Model> Manager<M> {
 	}
 
 	/// Bulk create multiple records using reinhardt-query (similar to Django's bulk_create())
-	pub fn bulk_create_query(&self, models: &[M]) -> Option<InsertStatement> {
+	///
+	/// Returns an error, like [`Manager::bulk_create`], when a model belongs
+	/// to another tenant than the current one.
+	pub fn bulk_create_query(
+		&self,
+		models: &[M],
+	) -> reinhardt_core::exception::Result<Option<InsertStatement>> {
 		if models.is_empty() {
-			return None;
+			return Ok(None);
 		}
 
 		// Convert all models to JSON and extract field names from first model
-		let json_values: Vec<serde_json::Value> = models
-			.iter()
-			.filter_map(|m| serde_json::to_value(m).ok())
-			.collect();
-
-		if json_values.is_empty() {
-			return None;
+		let mut json_values: Vec<serde_json::Value> = Vec::with_capacity(models.len());
+		for model in models {
+			let Ok(mut json) = serde_json::to_value(model) else {
+				continue;
+			};
+			super::tenancy::stamp_tenant::<M>(&mut json)?;
+			json_values.push(json);
 		}
 
 		// Get field names from first model
-		let first_obj = json_values[0].as_object()?;
+		let Some(first_obj) = json_values.first().and_then(|v| v.as_object()) else {
+			return Ok(None);
+		};
 
 		let fields: Vec<_> = first_obj.keys().map(|k| Alias::new(k.as_str())).collect();
 
@@ -1163,6 +1346,7 @@ impl<M: Model> Manager<M> {
 		stmt.into_table(Alias::new(M::table_name())).columns(fields);
 
 		// Add value rows for each model
+		let kinds = FieldKinds::of::<M>();
 		for val in &json_values {
 			if let Some(obj) = val.as_object() {
 				let values: Vec<reinhardt_query::value::Value> = first_obj
@@ -1174,7 +1358,7 @@ impl<M: Model> Manager<M> {
 									// Use untyped NULL to avoid PostgreSQL type mismatch errors
 									reinhardt_query::value::Value::Int(None)
 								} else {
-									Self::json_to_sea_value(v)
+									Self::json_to_field_value(&kinds, field, v)
 								}
 							})
 							// Use untyped NULL for missing fields
@@ -1185,7 +1369,7 @@ impl<M: Model> Manager<M> {
 			}
 		}
 
-		Some(stmt.to_owned())
+		Ok(Some(stmt.to_owned()))
 	}
 
 	/// Generate bulk create SQL (convenience method)
@@ -1194,12 +1378,15 @@ impl<M: Model> Manager<M> {
 	///
 	/// * `models` - Models to insert
 	/// * `backend` - Database backend to generate SQL for
-	pub fn bulk_create_sql(&self, models: &[M], backend: DatabaseBackend) -> String {
-		if let Some(stmt) = self.bulk_create_query(models) {
-			insert_to_string(&stmt, backend)
-		} else {
-			String::new()
-		}
+	pub fn bulk_create_sql(
+		&self,
+		models: &[M],
+		backend: DatabaseBackend,
+	) -> reinhardt_core::exception::Result<String> {
+		Ok(self
+			.bulk_create_query(models)?
+			.map(|stmt| insert_to_string(&stmt, backend))
+			.unwrap_or_default())
 	}
 
 	/// Generate UPDATE query for QuerySet
@@ -1240,6 +1427,18 @@ impl<M: Model> Manager<M> {
 		lookup_fields: HashMap<String, String>,
 		defaults: Option<HashMap<String, String>>,
 	) -> reinhardt_core::exception::Result<(M, bool)> {
+		// Both the lookup and the created record are bound to the current tenant
+		let mut lookup_fields = lookup_fields;
+		match super::tenancy::tenant_filter::<M>() {
+			super::tenancy::TenantFilter::Unrestricted => {}
+			super::tenancy::TenantFilter::Deny { .. } => {
+				return Err(super::tenancy::no_tenant_error::<M>());
+			}
+			super::tenancy::TenantFilter::Tenant { column, tenant } => {
+				lookup_fields.insert(column.to_string(), tenant.to_string());
+			}
+		}
+
 		let conn = get_connection().await?;
 
 		// Try to find existing record
@@ -1253,6 +1452,7 @@ impl<M: Model> Manager<M> {
 			// row.data is already serde_json::Value::Object so deserialize directly
 			let model: M = serde_json::from_value(row.data.clone())
 				.map_err(|e| reinhardt_core::exception::Error::Database(e.to_string()))?;
+			super::tracking::mark_clean(&model);
 			return Ok((model, false));
 		}
 
@@ -1272,7 +1472,10 @@ impl<M: Model> Manager<M> {
 			values.join(", ")
 		);
 
-		let row = conn.query_one(&insert_sql, vec![]).await?;
+		let row = conn
+			.query_one(&insert_sql, vec![])
+			.await
+			.map_err(write_error)?;
 		// row.data is already serde_json::Value::Object so deserialize directly
 		let model: M = serde_json::from_value(row.data.clone())
 			.map_err(|e| reinhardt_core::exception::Error::Database(e.to_string()))?;
@@ -1311,10 +1514,18 @@ impl<M: Model> Manager<M> {
 		let mut results = Vec::new();
 
 		for chunk in models.chunks(batch_size) {
+			let jsons = chunk
+				.iter()
+				.map(|model| {
+					let mut json = serde_json::to_value(model)
+						.map_err(|e| reinhardt_core::exception::Error::Database(e.to_string()))?;
+					super::tenancy::stamp_tenant::<M>(&mut json)?;
+					Ok(json)
+				})
+				.collect::<reinhardt_core::exception::Result<Vec<_>>>()?;
+
 			// Extract fields from first model
-			let json = serde_json::to_value(&chunk[0])
-				.map_err(|e| reinhardt_core::exception::Error::Database(e.to_string()))?;
-			let obj = json.as_object().ok_or_else(|| {
+			let obj = jsons[0].as_object().ok_or_else(|| {
 				reinhardt_core::exception::Error::Database(
 					"Model must serialize to object".to_string(),
 				)
@@ -1333,10 +1544,9 @@ impl<M: Model> Manager<M> {
 				.collect();
 
 			// Extract values for all models in chunk
-			let value_rows: Vec<Vec<serde_json::Value>> = chunk
+			let value_rows: Vec<Vec<serde_json::Value>> = jsons
 				.iter()
-				.map(|model| {
-					let json = serde_json::to_value(model).unwrap();
+				.map(|json| {
 					let obj = json.as_object().unwrap();
 					field_names.iter().map(|field| obj[field].clone()).collect()
 				})
@@ -1346,12 +1556,20 @@ impl<M: Model> Manager<M> {
 
 			// Execute and get results
 			if ignore_conflicts {
-				conn.execute(&sql, vec![]).await?;
+				conn.execute(&sql, vec![]).await.map_err(write_error)?;
 				// Note: Can't get RETURNING with DO NOTHING, skip results
 				// Return empty vec for ignored conflicts
+				// The inserted rows are unknown, so recount their parents
+				let rows: Vec<&serde_json::Value> = jsons.iter().collect();
+				super::counters::recount_parents_of(&conn, M::table_name(), &rows).await?;
 			} else {
 				let sql_with_returning = sql + " RETURNING *";
-				let rows = conn.query(&sql_with_returning, vec![]).await?;
+				let rows = conn
+					.query(&sql_with_returning, vec![])
+					.await
+					.map_err(write_error)?;
+				let inserted: Vec<&serde_json::Value> = rows.iter().map(|row| &row.data).collect();
+				super::counters::apply_row_changes(&conn, M::table_name(), &[], &inserted).await?;
 				for row in rows {
 					// row.data is already serde_json::Value::Object so deserialize directly
 					let model: M = serde_json::from_value(row.data.clone())
@@ -1384,6 +1602,9 @@ impl<M: Model> Manager<M> {
 		if models.is_empty() || fields.is_empty() {
 			return Ok(0);
 		}
+		if let super::tenancy::TenantFilter::Deny { .. } = super::tenancy::tenant_filter::<M>() {
+			return Err(super::tenancy::no_tenant_error::<M>());
+		}
 
 		let conn = get_connection().await?;
 		let batch_size = batch_size.unwrap_or(models.len());
@@ -1395,7 +1616,9 @@ impl<M: Model> Manager<M> {
 				.iter()
 				.filter_map(|model| {
 					let pk = model.primary_key()?.clone();
-					let json = serde_json::to_value(model).ok()?;
+					let mut json = serde_json::to_value(model).ok()?;
+					// Instances of other tenants are skipped
+					super::tenancy::stamp_tenant::<M>(&mut json).ok()?;
 					let obj = json.as_object()?;
 
 					let mut field_map = HashMap::new();
@@ -1411,7 +1634,7 @@ impl<M: Model> Manager<M> {
 
 			if !updates.is_empty() {
 				let sql = self.bulk_update_sql_detailed(&updates, &fields, conn.backend());
-				let rows_affected = conn.execute(&sql, vec![]).await?;
+				let rows_affected = conn.execute(&sql, vec![]).await.map_err(write_error)?;
 				total_updated += rows_affected as usize;
 			}
 		}
@@ -1582,11 +1805,22 @@ impl<M: Model> Manager<M> {
 			.map(|(pk, _)| format!("'{}'", pk.to_string().replace('\'', "''")))
 			.collect();
 
+		let tenant_clause = match super::tenancy::tenant_filter::<M>() {
+			super::tenancy::TenantFilter::Unrestricted => String::new(),
+			super::tenancy::TenantFilter::Deny { .. } => " AND FALSE".to_string(),
+			super::tenancy::TenantFilter::Tenant { column, tenant } => format!(
+				" AND \"{}\" = '{}'",
+				column,
+				tenant.to_string().replace('\'', "''")
+			),
+		};
+
 		format!(
-			"UPDATE \"{}\" SET {} WHERE \"id\" IN ({})",
+			"UPDATE \"{}\" SET {} WHERE \"id\" IN ({}){}",
 			table_name,
 			set_clauses.join(", "),
-			ids.join(", ")
+			ids.join(", "),
+			tenant_clause
 		)
 	}
 }
@@ -1684,6 +1918,15 @@ mod tests {
 		assert!(insert_sql.contains("name"));
 	}
 
+	#[test]
+	fn test_parse_naive_datetime_ignores_aware_values() {
+		assert!(super::parse_naive_datetime("2025-01-01T12:30:00").is_some());
+		assert!(super::parse_naive_datetime("2025-01-01 12:30:00.250").is_some());
+		assert!(super::parse_naive_datetime("2025-01-01T12:30").is_some());
+		assert!(super::parse_naive_datetime("2025-01-01T12:30:00Z").is_none());
+		assert!(super::parse_naive_datetime("2025-01-01T12:30:00+09:00").is_none());
+	}
+
 	#[test]
 	fn test_bulk_create_sql() {
 		use serde_json::json;
@@ -1946,4 +2189,119 @@ mod tests {
 
 		assert!(serialized.contains("123"));
 	}
+
+	#[derive(Debug, Clone, Serialize, Deserialize)]
+	struct TenantNote {
+		id: Option<i64>,
+		tenant_id: Option<i64>,
+		body: String,
+	}
+
+	impl Model for TenantNote {
+		type PrimaryKey = i64;
+		type Fields = TestUserFields;
+		type Objects = Manager<Self>;
+
+		fn table_name() -> &'static str {
+			"tenant_note"
+		}
+
+		fn primary_key(&self) -> Option<Self::PrimaryKey> {
+			self.id
+		}
+
+		fn set_primary_key(&mut self, value: Self::PrimaryKey) {
+			self.id = Some(value);
+		}
+
+		fn new_fields() -> Self::Fields {
+			TestUserFields
+		}
+
+		fn tenant_field() -> Option<&'static str> {
+			Some("tenant_id")
+		}
+	}
+
+	fn tenant_note(tenant_id: Option<i64>, body: &str) -> TenantNote {
+		TenantNote {
+			id: None,
+			tenant_id,
+			body: body.to_string(),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_bulk_create_query_stamps_current_tenant() {
+		use reinhardt_query::value::Value;
+		let manager = Manager::<TenantNote>::new();
+		let notes = vec![tenant_note(None, "first"), tenant_note(Some(7), "second")];
+
+		let stmt = crate::orm::tenancy::with_tenant(7, async { manager.bulk_create_query(&notes) })
+			.await
+			.unwrap()
+			.unwrap();
+		let (sql, values) = super::build_insert_sql(&stmt, DatabaseBackend::Postgres);
+
+		assert_eq!(
+			sql,
+			r#"INSERT INTO "tenant_note" ("body", "id", "tenant_id") VALUES ($1, $2, $3), ($4, $5, $6)"#
+		);
+		assert_eq!(
+			values.0,
+			vec![
+				Value::String(Some(Box::new("first".to_string()))),
+				Value::Int(None),
+				Value::BigInt(Some(7)),
+				Value::String(Some(Box::new("second".to_string()))),
+				Value::Int(None),
+				Value::BigInt(Some(7)),
+			]
+		);
+	}
+
+	#[tokio::test]
+	async fn test_bulk_create_query_rejects_other_tenant() {
+		let manager = Manager::<TenantNote>::new();
+		let notes = vec![tenant_note(None, "mine"), tenant_note(Some(8), "theirs")];
+
+		let result = crate::orm::tenancy::with_tenant(7, async {
+			manager.bulk_create_sql(&notes, DatabaseBackend::Postgres)
+		})
+		.await;
+
+		assert_eq!(
+			result.unwrap_err().to_string(),
+			reinhardt_core::exception::Error::Authorization(
+				"tenant_note instance belongs to tenant 8 but the current tenant is 7".to_string()
+			)
+			.to_string()
+		);
+	}
+
+	#[tokio::test]
+	async fn test_bulk_update_sql_is_restricted_to_current_tenant() {
+		use serde_json::json;
+		let manager = Manager::<TenantNote>::new();
+		let mut note_fields = HashMap::new();
+		note_fields.insert("body".to_string(), json!("edited"));
+		let updates = vec![(1i64, note_fields)];
+		let fields = vec!["body".to_string()];
+
+		let scoped = crate::orm::tenancy::with_tenant(7, async {
+			manager.bulk_update_sql_detailed(&updates, &fields, DatabaseBackend::Postgres)
+		})
+		.await;
+		let outside =
+			manager.bulk_update_sql_detailed(&updates, &fields, DatabaseBackend::Postgres);
+
+		assert_eq!(
+			scoped,
+			r#"UPDATE "tenant_note" SET "body" = CASE WHEN "id" = '1' THEN 'edited' END WHERE "id" IN ('1') AND "tenant_id" = '7'"#
+		);
+		assert_eq!(
+			outside,
+			r#"UPDATE "tenant_note" SET "body" = CASE WHEN "id" = '1' THEN 'edited' END WHERE "id" IN ('1') AND FALSE"#
+		);
+	}
 }
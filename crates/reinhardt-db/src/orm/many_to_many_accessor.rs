@@ -7,6 +7,10 @@
 //! - `all()` - Get all related records
 //! - `clear()` - Remove all relationships
 //! - `set()` - Replace all relationships
+//!
+//! Relationships declared with a through model
+//! (`#[rel(many_to_many, through = "Membership")]`) use [`ThroughAccessor`],
+//! which additionally reads and writes the through-model rows.
 
 use super::Manager;
 use super::connection::{DatabaseBackend, DatabaseConnection};
@@ -19,6 +23,7 @@ use reinhardt_query::prelude::{
 };
 use serde::{Serialize, de::DeserializeOwned};
 use std::marker::PhantomData;
+use std::ops::Deref;
 use std::time::Instant;
 
 /// Build SELECT SQL using the appropriate QueryBuilder for the given backend.
@@ -663,6 +668,207 @@ where
 	}
 }
 
+/// Convert a stringified primary key back into the JSON value a model
+/// serializes it as: integers become numbers, everything else a string.
+fn primary_key_json(id: &str) -> serde_json::Value {
+	id.parse::<i64>()
+		.map(serde_json::Value::from)
+		.unwrap_or_else(|_| serde_json::Value::String(id.to_string()))
+}
+
+/// Fill in the source and target foreign keys of a through-model row.
+fn link_through_row<M: Model>(
+	through: &M,
+	source_field: &str,
+	source_id: &str,
+	target_field: &str,
+	target_id: &str,
+) -> Result<M, String> {
+	let mut row = serde_json::to_value(through).map_err(|e| e.to_string())?;
+	let fields = row
+		.as_object_mut()
+		.ok_or_else(|| "Through model must serialize to an object".to_string())?;
+	fields.insert(source_field.to_string(), primary_key_json(source_id));
+	fields.insert(target_field.to_string(), primary_key_json(target_id));
+	serde_json::from_value(row).map_err(|e| e.to_string())
+}
+
+/// Django-style accessor for ManyToMany relationships with a through model.
+///
+/// Generated for fields declared with
+/// `#[rel(many_to_many, through = "Membership")]`. All plain relationship
+/// operations (`add()`, `remove()`, `all()`, `clear()`, `set()`) are
+/// available through [`Deref`] to the underlying [`ManyToManyAccessor`];
+/// this type adds access to the through-model rows so that extra columns on
+/// the join table can be written and read.
+///
+/// # Type Parameters
+///
+/// - `S`: Source model type (the model that owns the ManyToMany field)
+/// - `T`: Target model type (the related model)
+/// - `M`: Through model type (the model that owns the join table)
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let groups = user.groups_accessor(db.clone());
+///
+/// // Add a relationship together with the extra through-model columns
+/// groups
+///     .add_with(&group, &Membership { role: "admin".into(), ..Default::default() })
+///     .await?;
+///
+/// // Read the through-model rows
+/// for membership in groups.through_rows().await? {
+///     println!("{}", membership.role);
+/// }
+///
+/// // Plain relationship operations are still available
+/// groups.remove(&group).await?;
+/// ```
+pub struct ThroughAccessor<S, T, M>
+where
+	S: Model,
+	T: Model + Serialize + DeserializeOwned,
+	M: Model,
+{
+	inner: ManyToManyAccessor<S, T>,
+	_phantom_through: PhantomData<M>,
+}
+
+impl<S, T, M> ThroughAccessor<S, T, M>
+where
+	S: Model,
+	T: Model + Serialize + DeserializeOwned,
+	M: Model,
+{
+	/// Create a new ThroughAccessor.
+	///
+	/// The through table and its foreign key columns are resolved from the
+	/// source model's relationship metadata, as in [`ManyToManyAccessor::new`].
+	///
+	/// # Panics
+	///
+	/// Panics if the source model has no primary key.
+	pub fn new(source: &S, field_name: &str, db: DatabaseConnection) -> Self {
+		Self {
+			inner: ManyToManyAccessor::new(source, field_name, db),
+			_phantom_through: PhantomData,
+		}
+	}
+
+	/// Consume the accessor and return the underlying [`ManyToManyAccessor`],
+	/// e.g. to apply `limit()` or `offset()`.
+	pub fn into_inner(self) -> ManyToManyAccessor<S, T> {
+		self.inner
+	}
+
+	/// Add a relationship to the target model, storing the extra columns of
+	/// `through` in the join table.
+	///
+	/// The source and target foreign keys of `through` are overwritten with
+	/// the ids of the source instance and `target`.
+	///
+	/// # Errors
+	///
+	/// Returns an error if:
+	/// - The target model has no primary key
+	/// - The through model cannot be (de)serialized
+	/// - The database operation fails
+	///
+	/// # Examples
+	///
+	/// ```ignore
+	/// let membership = accessor.add_with(&group, &Membership::default()).await?;
+	/// ```
+	pub async fn add_with(&self, target: &T, through: &M) -> Result<M, String> {
+		let target_id = target
+			.primary_key()
+			.ok_or_else(|| "Target model has no primary key".to_string())?;
+
+		let row = link_through_row(
+			through,
+			&self.inner.source_field,
+			&self.inner.source_id.to_string(),
+			&self.inner.target_field,
+			&target_id.to_string(),
+		)?;
+
+		Manager::<M>::new()
+			.create_with_conn(&self.inner.db, &row)
+			.await
+			.map_err(|e| e.to_string())
+	}
+
+	/// Get the through-model rows of this source instance.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the database operation fails or a row cannot be
+	/// deserialized.
+	///
+	/// # Examples
+	///
+	/// ```ignore
+	/// let memberships = accessor.through_rows().await?;
+	/// ```
+	pub async fn through_rows(&self) -> Result<Vec<M>, String> {
+		let through_table = &self.inner.through_table;
+		let mut query = Query::select();
+		query.from(Alias::new(through_table));
+
+		let field_metadata = M::field_metadata();
+		if field_metadata.is_empty() {
+			query.column(ColumnRef::table_asterisk(Alias::new(through_table)));
+		} else {
+			for field in field_metadata {
+				query.column((Alias::new(through_table), Alias::new(&field.name)));
+			}
+		}
+
+		query.and_where(
+			Expr::col((
+				Alias::new(through_table),
+				Alias::new(&self.inner.source_field),
+			))
+			.binary(BinOper::Equal, Expr::val(self.inner.source_id.to_string())),
+		);
+
+		let query = query.to_owned();
+		let (sql, values) = build_select_sql(&query, self.inner.db.backend());
+		let params = value_samples(&values);
+		let started_at = Instant::now();
+		let query_result = self.inner.db.query(&sql, vec![]).await;
+		let duration = started_at.elapsed();
+		let rows = match query_result {
+			Ok(rows) => {
+				super::instrumentation::instrumentation()
+					.orm_query_end_with_params(&sql, &params, duration)
+					.await;
+				rows
+			}
+			Err(error) => return Err(error.to_string()),
+		};
+
+		rows.into_iter()
+			.map(|row| serde_json::from_value(row.data).map_err(|e| e.to_string()))
+			.collect()
+	}
+}
+
+impl<S, T, M> Deref for ThroughAccessor<S, T, M>
+where
+	S: Model,
+	T: Model + Serialize + DeserializeOwned,
+	M: Model,
+{
+	type Target = ManyToManyAccessor<S, T>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.inner
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -790,6 +996,26 @@ mod tests {
 		// instead of inline in the SQL string
 	}
 
+	#[test]
+	fn test_link_through_row_overwrites_foreign_keys() {
+		// Arrange
+		let membership = TestMembership {
+			id: None,
+			users_id: 0,
+			group_key: String::new(),
+			role: "admin".to_string(),
+		};
+
+		// Act
+		let row = link_through_row(&membership, "users_id", "7", "group_key", "g-1").unwrap();
+
+		// Assert
+		assert_eq!(row.users_id, 7);
+		assert_eq!(row.group_key, "g-1");
+		assert_eq!(row.role, "admin");
+		assert_eq!(row.id, None);
+	}
+
 	// Test models for SQL generation tests
 	#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 	struct TestUser {
@@ -875,4 +1101,42 @@ mod tests {
 			self.id = value;
 		}
 	}
+
+	#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+	struct TestMembership {
+		id: Option<i64>,
+		users_id: i64,
+		group_key: String,
+		role: String,
+	}
+
+	#[derive(Clone)]
+	struct TestMembershipFields;
+	impl crate::orm::model::FieldSelector for TestMembershipFields {
+		fn with_alias(self, _alias: &str) -> Self {
+			self
+		}
+	}
+
+	impl Model for TestMembership {
+		type PrimaryKey = i64;
+		type Fields = TestMembershipFields;
+		type Objects = Manager<Self>;
+
+		fn table_name() -> &'static str {
+			"memberships"
+		}
+
+		fn new_fields() -> Self::Fields {
+			TestMembershipFields
+		}
+
+		fn primary_key(&self) -> Option<Self::PrimaryKey> {
+			self.id
+		}
+
+		fn set_primary_key(&mut self, value: Self::PrimaryKey) {
+			self.id = Some(value);
+		}
+	}
 }
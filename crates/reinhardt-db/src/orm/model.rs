@@ -141,6 +141,66 @@ pub trait Model: Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone {
 		Vec::new()
 	}
 
+	/// Whether changes to this model are recorded in the audit store
+	///
+	/// Generated as `true` by `#[model(audited)]`. When enabled and a store is
+	/// installed, [`save`](Self::save) and [`delete`](Self::delete) record
+	/// each change with its field diff; see [`audit`](super::audit).
+	fn audited() -> bool {
+		false
+	}
+
+	/// Slug fields filled from another field on save
+	///
+	/// Generated by the `#[model(...)]` macro from `#[field(slug_from = "...")]`.
+	/// [`save`](Self::save) fills each empty slug before writing; see
+	/// [`slug`](super::slug).
+	fn slug_fields() -> Vec<super::slug::SlugField> {
+		Vec::new()
+	}
+
+	/// Column linking rows of this model to the user they describe
+	///
+	/// Generated from `#[model(data_subject = "...")]`. Models returning
+	/// `Some` take part in [`export_user_data`](super::personal_data::export_user_data)
+	/// and [`anonymize_user`](super::personal_data::anonymize_user).
+	fn data_subject_field() -> Option<&'static str> {
+		None
+	}
+
+	/// Fields holding personal data and how each is anonymized
+	///
+	/// Generated by the `#[model(...)]` macro from `#[field(personal_data)]`;
+	/// see [`personal_data`](super::personal_data).
+	fn personal_data_fields() -> Vec<super::personal_data::PersonalDataField> {
+		Vec::new()
+	}
+
+	/// How long rows are kept and what happens to them afterwards
+	///
+	/// Generated from `#[model(retain = "...", on_expire = ...)]`; see
+	/// [`retention`](super::retention).
+	fn retention() -> Option<super::retention::Retention> {
+		None
+	}
+
+	/// Fields counting the rows of a related model
+	///
+	/// Generated by the `#[model(...)]` macro from `#[field(counter_of = "...")]`;
+	/// see [`counters`](super::counters).
+	fn counter_fields() -> Vec<super::counters::CounterField> {
+		Vec::new()
+	}
+
+	/// Column holding the tenant each row belongs to
+	///
+	/// Generated from `#[model(tenant_field = "...")]`. Queries and writes on
+	/// models returning `Some` are restricted to the current tenant; see
+	/// [`tenancy`](super::tenancy).
+	fn tenant_field() -> Option<&'static str> {
+		None
+	}
+
 	/// Django-style objects manager accessor
 	///
 	/// Returns the configured manager for this model type. When a custom manager
@@ -191,6 +251,7 @@ pub trait Model: Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone {
 	/// If the primary key is Some, performs an UPDATE and dispatches before_update/after_update events.
 	///
 	/// Event listeners can veto the operation by returning `EventResult::Veto`.
+	/// Empty [slug fields](Self::slug_fields) are filled before either.
 	///
 	/// # Examples
 	///
@@ -242,6 +303,10 @@ pub trait Model: Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone {
 			let registry = get_active_registry();
 			let conn = get_connection().await?;
 			let manager = super::Manager::<Self>::new();
+			let audit_store = super::audit::store_for::<Self>();
+			let counted = super::counters::counters_fed_by(Self::table_name());
+
+			super::slug::populate_slugs(self).await?;
 
 			let json = serde_json::to_value(&*self)
 				.map_err(|e| reinhardt_core::exception::Error::Database(e.to_string()))?;
@@ -265,6 +330,29 @@ pub trait Model: Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone {
 				// Perform the INSERT
 				let created = manager.create_with_conn(&conn, self).await?;
 				*self = created;
+				super::tracking::mark_clean(&*self);
+
+				if !counted.is_empty() {
+					let after = super::audit::snapshot(&*self)?;
+					super::counters::apply_row_changes(&conn, Self::table_name(), &[], &[&after])
+						.await?;
+				}
+
+				if let Some(store) = &audit_store {
+					let object_id = self
+						.primary_key()
+						.map(|pk| pk.to_string())
+						.unwrap_or_default();
+					let after = super::audit::snapshot(&*self)?;
+					super::audit::record_change::<Self>(
+						store.as_ref(),
+						super::audit::AuditAction::Create,
+						object_id,
+						None,
+						Some(&after),
+					)
+					.await?;
+				}
 
 				// Dispatch after_insert event if registry is active
 				if let Some(ref reg) = registry {
@@ -300,9 +388,52 @@ pub trait Model: Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone {
 					}
 				}
 
+				// Capture the stored state for the audit diff and counter moves
+				let stored = match self.primary_key() {
+					Some(pk) if audit_store.is_some() || !counted.is_empty() => {
+						manager.get(pk).first_with_db(&conn).await?
+					}
+					_ => None,
+				};
+				let before = match &audit_store {
+					Some(_) => stored.as_ref().map(super::audit::snapshot).transpose()?,
+					None => None,
+				};
+
 				// Perform the UPDATE
 				let updated = manager.update_with_conn(&conn, self).await?;
 				*self = updated;
+				super::tracking::mark_clean(&*self);
+
+				if !counted.is_empty()
+					&& let Some(stored) = &stored
+				{
+					let previous = super::audit::snapshot(stored)?;
+					let after = super::audit::snapshot(&*self)?;
+					super::counters::apply_row_changes(
+						&conn,
+						Self::table_name(),
+						&[&previous],
+						&[&after],
+					)
+					.await?;
+				}
+
+				if let Some(store) = &audit_store {
+					let object_id = self
+						.primary_key()
+						.map(|pk| pk.to_string())
+						.unwrap_or_default();
+					let after = super::audit::snapshot(&*self)?;
+					super::audit::record_change::<Self>(
+						store.as_ref(),
+						super::audit::AuditAction::Update,
+						object_id,
+						before.as_ref(),
+						Some(&after),
+					)
+					.await?;
+				}
 
 				// Dispatch after_update event if registry is active
 				if let Some(ref reg) = registry {
@@ -315,6 +446,146 @@ pub trait Model: Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone {
 		}
 	}
 
+	/// Names of the fields changed since the instance was last loaded or saved
+	///
+	/// Every field is reported if the instance was never loaded, or if its
+	/// original state is no longer tracked (see [`tracking`](super::tracking)).
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// # use reinhardt_db::orm::Model;
+	/// # fn example<M: Model>(article: &M) {
+	/// if article.dirty_fields().iter().any(|field| field == "status") {
+	///     // notify subscribers
+	/// }
+	/// # }
+	/// ```
+	fn dirty_fields(&self) -> Vec<String>
+	where
+		Self: Sized,
+	{
+		self.diff().into_iter().map(|change| change.field).collect()
+	}
+
+	/// Field-level changes since the instance was last loaded or saved
+	///
+	/// Each [`FieldChange`](super::audit::FieldChange) carries the original
+	/// and current value. Every field is reported if the original state is not
+	/// tracked.
+	fn diff(&self) -> Vec<super::audit::FieldChange>
+	where
+		Self: Sized,
+	{
+		super::tracking::diff(self)
+	}
+
+	/// Save only the given fields of an existing instance
+	///
+	/// Fields whose value did not change since the instance was loaded are
+	/// skipped, and no query is made at all when none of them changed. The
+	/// UPDATE writes just the remaining columns, without re-reading the stored
+	/// row first. Update events and audit entries are still produced; the audit
+	/// diff is taken against the tracked original.
+	///
+	/// Falls back to a full [`save`](Self::save) for instances without a primary
+	/// key and for tables that feed counter fields.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// # use reinhardt_db::orm::Model;
+	/// # async fn example<M: Model>(article: &mut M) -> reinhardt_core::exception::Result<()> {
+	/// // Write only the columns that changed
+	/// let dirty = article.dirty_fields();
+	/// let fields: Vec<&str> = dirty.iter().map(String::as_str).collect();
+	/// article.save_update_fields(&fields).await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	fn save_update_fields(
+		&mut self,
+		fields: &[&str],
+	) -> impl std::future::Future<Output = reinhardt_core::exception::Result<()>> + Send
+	where
+		Self: Sized,
+	{
+		async move {
+			use super::events::{EventResult, get_active_registry};
+			use super::manager::get_connection;
+
+			if self.primary_key().is_none()
+				|| !super::counters::counters_fed_by(Self::table_name()).is_empty()
+			{
+				return self.save().await;
+			}
+
+			let original = super::tracking::original_values(&*self);
+			let dirty = self.dirty_fields();
+			let changed: Vec<&str> = fields
+				.iter()
+				.copied()
+				.filter(|field| dirty.iter().any(|d| d == field))
+				.collect();
+			if changed.is_empty() {
+				return Ok(());
+			}
+
+			let registry = get_active_registry();
+			let conn = get_connection().await?;
+			let manager = super::Manager::<Self>::new();
+			let instance_id = format!(
+				"{}-{}",
+				Self::table_name(),
+				self.primary_key()
+					.map(|pk| pk.to_string())
+					.unwrap_or_default()
+			);
+
+			if let Some(ref reg) = registry {
+				let json = serde_json::to_value(&*self)
+					.map_err(|e| reinhardt_core::exception::Error::Database(e.to_string()))?;
+				let result = reg
+					.dispatch_before_update(Self::table_name(), &instance_id, &json)
+					.await;
+				if result == EventResult::Veto {
+					return Err(reinhardt_core::exception::Error::Database(
+						"Update operation vetoed by event listener".to_string(),
+					));
+				}
+			}
+
+			let updated = manager
+				.update_fields_with_conn(&conn, self, &changed)
+				.await?;
+			*self = updated;
+			super::tracking::mark_clean(&*self);
+
+			if let Some(store) = super::audit::store_for::<Self>() {
+				let object_id = self
+					.primary_key()
+					.map(|pk| pk.to_string())
+					.unwrap_or_default();
+				let after = super::audit::snapshot(&*self)?;
+				super::audit::record_change::<Self>(
+					store.as_ref(),
+					super::audit::AuditAction::Update,
+					object_id,
+					original.as_ref(),
+					Some(&after),
+				)
+				.await?;
+			}
+
+			if let Some(ref reg) = registry {
+				reg.dispatch_after_update(Self::table_name(), &instance_id)
+					.await;
+			}
+
+			Ok(())
+		}
+	}
+
 	/// Delete the model instance from the database with event dispatching
 	///
 	/// Dispatches before_delete/after_delete events. Event listeners can veto
@@ -386,8 +657,41 @@ pub trait Model: Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone {
 				}
 			}
 
+			// Capture the stored state for the audit diff and counters
+			let audit_store = super::audit::store_for::<Self>();
+			let counted = super::counters::counters_fed_by(Self::table_name());
+			let stored = if audit_store.is_some() || !counted.is_empty() {
+				manager.get(pk.clone()).first_with_db(&conn).await?
+			} else {
+				None
+			};
+			let before = match &audit_store {
+				Some(_) => stored.as_ref().map(super::audit::snapshot).transpose()?,
+				None => None,
+			};
+
 			// Perform the DELETE
 			manager.delete_with_conn(&conn, pk.clone()).await?;
+			super::tracking::forget(self);
+
+			if !counted.is_empty()
+				&& let Some(stored) = &stored
+			{
+				let previous = super::audit::snapshot(stored)?;
+				super::counters::apply_row_changes(&conn, Self::table_name(), &[&previous], &[])
+					.await?;
+			}
+
+			if let Some(store) = &audit_store {
+				super::audit::record_change::<Self>(
+					store.as_ref(),
+					super::audit::AuditAction::Delete,
+					pk.to_string(),
+					before.as_ref(),
+					None,
+				)
+				.await?;
+			}
 
 			// Dispatch after_delete event if registry is available
 			if let Some(registry) = get_active_registry() {
@@ -447,7 +751,7 @@ impl Timestamps {
 	/// assert!(timestamps.updated_at <= chrono::Utc::now());
 	/// ```
 	pub fn now() -> Self {
-		let now = chrono::Utc::now();
+		let now = reinhardt_utils::utils_core::timezone::now();
 		Self {
 			created_at: now,
 			updated_at: now,
@@ -471,7 +775,7 @@ impl Timestamps {
 	/// assert!(timestamps.updated_at > old_updated);
 	/// ```
 	pub fn touch(&mut self) {
-		self.updated_at = chrono::Utc::now();
+		self.updated_at = reinhardt_utils::utils_core::timezone::now();
 	}
 }
 
@@ -511,7 +815,7 @@ impl SoftDelete {
 	/// assert!(soft_delete.deleted_at.is_some());
 	/// ```
 	pub fn delete(&mut self) {
-		self.deleted_at = Some(chrono::Utc::now());
+		self.deleted_at = Some(reinhardt_utils::utils_core::timezone::now());
 	}
 	/// Restores a soft-deleted record by clearing the deletion timestamp
 	///
@@ -0,0 +1,656 @@
+//! In-app notifications with read/unread state
+//!
+//! A [`Notification`] is addressed to one recipient (usually a user id) and
+//! stays unread until the recipient reads it. [`notify`] is the entry point
+//! for emitting notifications from views, signal receivers and background
+//! tasks: it persists the notification in the installed
+//! [`NotificationStore`] and hands it to every registered
+//! [`NotificationChannel`] for live delivery (reinhardt-websockets provides
+//! an SSE channel).
+//!
+//! Nothing can be emitted until a store is installed with
+//! [`set_notification_store`].
+//!
+//! # Examples
+//!
+//! ```
+//! use reinhardt_db::orm::notifications::{
+//!     InMemoryNotificationStore, Notification, NotificationLevel, NotificationStore, notify,
+//!     set_notification_store,
+//! };
+//! use std::sync::Arc;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> reinhardt_core::exception::Result<()> {
+//! let store = Arc::new(InMemoryNotificationStore::new());
+//! set_notification_store(store.clone());
+//!
+//! // e.g. from a post_save receiver or a task
+//! notify(
+//!     Notification::new("42", "Your export is ready")
+//!         .level(NotificationLevel::Success)
+//!         .link("/exports/7/"),
+//! )
+//! .await?;
+//!
+//! assert_eq!(store.unread_count("42").await?, 1);
+//! store.mark_all_read("42").await?;
+//! assert_eq!(store.unread_count("42").await?, 0);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::backends::DatabaseConnection;
+use crate::backends::sql_build_helpers::build_inline_sql;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_query::prelude::{
+	Alias, ColumnDef, Cond, Condition, Expr, ExprTrait, Func, IntoValue, Order, Query, Value,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Table storing the notifications of [`DatabaseNotificationStore`]
+pub const NOTIFICATION_TABLE: &str = "notifications_notification";
+
+/// Severity of a notification, used by clients to style it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationLevel {
+	/// Neutral information
+	#[default]
+	Info,
+	/// A completed operation
+	Success,
+	/// Something needing attention
+	Warning,
+	/// A failed operation
+	Error,
+}
+
+impl NotificationLevel {
+	/// Lowercase name of the level, as stored and serialized
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Info => "info",
+			Self::Success => "success",
+			Self::Warning => "warning",
+			Self::Error => "error",
+		}
+	}
+
+	/// Parse a level from its lowercase name
+	pub fn parse(value: &str) -> Option<Self> {
+		match value {
+			"info" => Some(Self::Info),
+			"success" => Some(Self::Success),
+			"warning" => Some(Self::Warning),
+			"error" => Some(Self::Error),
+			_ => None,
+		}
+	}
+}
+
+impl fmt::Display for NotificationLevel {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+/// A notification addressed to one recipient
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_db::orm::notifications::{Notification, NotificationLevel};
+///
+/// let notification = Notification::new("42", "New comment")
+///     .body("Alice replied to your post")
+///     .level(NotificationLevel::Info);
+/// assert!(!notification.is_read());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Notification {
+	/// Unique id, time-ordered
+	pub id: Uuid,
+	/// Recipient, usually the id of a user
+	pub recipient: String,
+	/// Severity
+	pub level: NotificationLevel,
+	/// Short title shown in toasts and the inbox
+	pub title: String,
+	/// Optional longer text
+	pub body: String,
+	/// Optional URL the notification points to
+	pub link: Option<String>,
+	/// When the notification was created
+	pub created_at: DateTime<Utc>,
+	/// When the recipient read the notification, `None` while unread
+	pub read_at: Option<DateTime<Utc>>,
+}
+
+impl Notification {
+	/// Create an unread notification for `recipient`
+	pub fn new(recipient: impl Into<String>, title: impl Into<String>) -> Self {
+		Self {
+			id: Uuid::now_v7(),
+			recipient: recipient.into(),
+			level: NotificationLevel::default(),
+			title: title.into(),
+			body: String::new(),
+			link: None,
+			created_at: Utc::now(),
+			read_at: None,
+		}
+	}
+
+	/// Set the severity
+	pub fn level(mut self, level: NotificationLevel) -> Self {
+		self.level = level;
+		self
+	}
+
+	/// Set the longer text
+	pub fn body(mut self, body: impl Into<String>) -> Self {
+		self.body = body.into();
+		self
+	}
+
+	/// Set the URL the notification points to
+	pub fn link(mut self, link: impl Into<String>) -> Self {
+		self.link = Some(link.into());
+		self
+	}
+
+	/// Whether the recipient has read the notification
+	pub fn is_read(&self) -> bool {
+		self.read_at.is_some()
+	}
+}
+
+/// Storage backend for notifications
+#[async_trait]
+pub trait NotificationStore: Send + Sync {
+	/// Persist a new notification
+	async fn create(&self, notification: &Notification) -> Result<()>;
+
+	/// Notifications of `recipient`, newest first
+	///
+	/// With `unread_only`, read notifications are skipped. At most `limit`
+	/// notifications are returned when given.
+	async fn inbox(
+		&self,
+		recipient: &str,
+		unread_only: bool,
+		limit: Option<usize>,
+	) -> Result<Vec<Notification>>;
+
+	/// Number of unread notifications of `recipient`
+	async fn unread_count(&self, recipient: &str) -> Result<usize>;
+
+	/// Mark the notification `id` of `recipient` as read
+	///
+	/// Returns `false` when `recipient` has no unread notification `id`.
+	async fn mark_read(&self, recipient: &str, id: Uuid) -> Result<bool>;
+
+	/// Mark every notification of `recipient` as read, returning how many
+	/// were unread
+	async fn mark_all_read(&self, recipient: &str) -> Result<usize>;
+
+	/// Delete the notification `id` of `recipient`
+	///
+	/// Returns `false` when `recipient` has no notification `id`.
+	async fn delete(&self, recipient: &str, id: Uuid) -> Result<bool>;
+}
+
+/// Notification store keeping notifications in memory
+///
+/// Suitable for tests and single-process deployments; notifications are
+/// lost on restart.
+#[derive(Debug, Default)]
+pub struct InMemoryNotificationStore {
+	notifications: RwLock<Vec<Notification>>,
+}
+
+impl InMemoryNotificationStore {
+	/// Create an empty store
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+#[async_trait]
+impl NotificationStore for InMemoryNotificationStore {
+	async fn create(&self, notification: &Notification) -> Result<()> {
+		self.notifications.write().push(notification.clone());
+		Ok(())
+	}
+
+	async fn inbox(
+		&self,
+		recipient: &str,
+		unread_only: bool,
+		limit: Option<usize>,
+	) -> Result<Vec<Notification>> {
+		let notifications = self.notifications.read();
+		let mut inbox: Vec<_> = notifications
+			.iter()
+			.filter(|n| n.recipient == recipient && !(unread_only && n.is_read()))
+			.cloned()
+			.collect();
+		inbox.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(b.id.cmp(&a.id)));
+		inbox.truncate(limit.unwrap_or(usize::MAX));
+		Ok(inbox)
+	}
+
+	async fn unread_count(&self, recipient: &str) -> Result<usize> {
+		Ok(self
+			.notifications
+			.read()
+			.iter()
+			.filter(|n| n.recipient == recipient && !n.is_read())
+			.count())
+	}
+
+	async fn mark_read(&self, recipient: &str, id: Uuid) -> Result<bool> {
+		let mut notifications = self.notifications.write();
+		let Some(notification) = notifications
+			.iter_mut()
+			.find(|n| n.id == id && n.recipient == recipient && !n.is_read())
+		else {
+			return Ok(false);
+		};
+		notification.read_at = Some(Utc::now());
+		Ok(true)
+	}
+
+	async fn mark_all_read(&self, recipient: &str) -> Result<usize> {
+		let now = Utc::now();
+		let mut marked = 0;
+		for notification in self
+			.notifications
+			.write()
+			.iter_mut()
+			.filter(|n| n.recipient == recipient && !n.is_read())
+		{
+			notification.read_at = Some(now);
+			marked += 1;
+		}
+		Ok(marked)
+	}
+
+	async fn delete(&self, recipient: &str, id: Uuid) -> Result<bool> {
+		let mut notifications = self.notifications.write();
+		let before = notifications.len();
+		notifications.retain(|n| !(n.id == id && n.recipient == recipient));
+		Ok(notifications.len() != before)
+	}
+}
+
+/// Notification store backed by the [`NOTIFICATION_TABLE`] table
+pub struct DatabaseNotificationStore {
+	connection: DatabaseConnection,
+}
+
+impl DatabaseNotificationStore {
+	/// Create a store using `connection`
+	pub fn new(connection: DatabaseConnection) -> Self {
+		Self { connection }
+	}
+
+	/// Create the [`NOTIFICATION_TABLE`] table if it does not exist
+	pub async fn create_table(&self) -> Result<()> {
+		let stmt = Query::create_table()
+			.table(Alias::new(NOTIFICATION_TABLE))
+			.if_not_exists()
+			.col(ColumnDef::new("id").uuid().not_null(true).primary_key(true))
+			.col(ColumnDef::new("recipient").string_len(255).not_null(true))
+			.col(ColumnDef::new("level").string_len(16).not_null(true))
+			.col(ColumnDef::new("title").string_len(255).not_null(true))
+			.col(ColumnDef::new("body").text().not_null(true))
+			.col(ColumnDef::new("link").string_len(2048))
+			.col(
+				ColumnDef::new("created_at")
+					.timestamp_with_time_zone()
+					.not_null(true),
+			)
+			.col(ColumnDef::new("read_at").timestamp_with_time_zone())
+			.to_owned();
+		self.execute(&build_inline_sql(self.connection.database_type(), &stmt))
+			.await
+			.map(|_| ())
+	}
+
+	async fn execute(&self, sql: &str) -> Result<u64> {
+		self.connection
+			.execute(sql, vec![])
+			.await
+			.map(|result| result.rows_affected)
+			.map_err(|e| Error::Database(e.to_string()))
+	}
+
+	async fn mark_read_where(&self, condition: Condition) -> Result<u64> {
+		let stmt = Query::update()
+			.table(Alias::new(NOTIFICATION_TABLE))
+			.value(Alias::new("read_at"), Utc::now())
+			.cond_where(condition.add(Expr::col(Alias::new("read_at")).is_null()))
+			.to_owned();
+		self.execute(&build_inline_sql(self.connection.database_type(), &stmt))
+			.await
+	}
+}
+
+#[async_trait]
+impl NotificationStore for DatabaseNotificationStore {
+	async fn create(&self, notification: &Notification) -> Result<()> {
+		let stmt = Query::insert()
+			.into_table(Alias::new(NOTIFICATION_TABLE))
+			.columns([
+				Alias::new("id"),
+				Alias::new("recipient"),
+				Alias::new("level"),
+				Alias::new("title"),
+				Alias::new("body"),
+				Alias::new("link"),
+				Alias::new("created_at"),
+				Alias::new("read_at"),
+			])
+			.values_panic([
+				Value::from(notification.id),
+				Value::from(notification.recipient.clone()),
+				Value::from(notification.level.as_str()),
+				Value::from(notification.title.clone()),
+				Value::from(notification.body.clone()),
+				notification.link.clone().into_value(),
+				Value::from(notification.created_at),
+				notification.read_at.into_value(),
+			])
+			.to_owned();
+		self.execute(&build_inline_sql(self.connection.database_type(), &stmt))
+			.await
+			.map(|_| ())
+	}
+
+	async fn inbox(
+		&self,
+		recipient: &str,
+		unread_only: bool,
+		limit: Option<usize>,
+	) -> Result<Vec<Notification>> {
+		let mut condition = recipient_condition(recipient);
+		if unread_only {
+			condition = condition.add(Expr::col(Alias::new("read_at")).is_null());
+		}
+		let mut stmt = Query::select();
+		stmt.columns([
+			Alias::new("id"),
+			Alias::new("recipient"),
+			Alias::new("level"),
+			Alias::new("title"),
+			Alias::new("body"),
+			Alias::new("link"),
+			Alias::new("created_at"),
+			Alias::new("read_at"),
+		])
+		.from(Alias::new(NOTIFICATION_TABLE))
+		.cond_where(condition)
+		.order_by(Alias::new("created_at"), Order::Desc)
+		.order_by(Alias::new("id"), Order::Desc);
+		if let Some(limit) = limit {
+			stmt.limit(limit as u64);
+		}
+		let sql = build_inline_sql(self.connection.database_type(), &stmt);
+		let rows = self
+			.connection
+			.fetch_all(&sql, vec![])
+			.await
+			.map_err(|e| Error::Database(e.to_string()))?;
+		rows.iter().map(row_to_notification).collect()
+	}
+
+	async fn unread_count(&self, recipient: &str) -> Result<usize> {
+		let stmt = Query::select()
+			.from(Alias::new(NOTIFICATION_TABLE))
+			.expr_as(Func::count(Expr::asterisk().into()), Alias::new("count"))
+			.cond_where(
+				recipient_condition(recipient).add(Expr::col(Alias::new("read_at")).is_null()),
+			)
+			.to_owned();
+		let sql = build_inline_sql(self.connection.database_type(), &stmt);
+		let row = self
+			.connection
+			.fetch_one(&sql, vec![])
+			.await
+			.map_err(|e| Error::Database(e.to_string()))?;
+		let count = row
+			.get::<i64>("count")
+			.map_err(|e| Error::Database(e.to_string()))?;
+		Ok(count as usize)
+	}
+
+	async fn mark_read(&self, recipient: &str, id: Uuid) -> Result<bool> {
+		let condition =
+			recipient_condition(recipient).add(Expr::col(Alias::new("id")).eq(Value::from(id)));
+		Ok(self.mark_read_where(condition).await? > 0)
+	}
+
+	async fn mark_all_read(&self, recipient: &str) -> Result<usize> {
+		Ok(self.mark_read_where(recipient_condition(recipient)).await? as usize)
+	}
+
+	async fn delete(&self, recipient: &str, id: Uuid) -> Result<bool> {
+		let stmt = Query::delete()
+			.from_table(Alias::new(NOTIFICATION_TABLE))
+			.cond_where(
+				recipient_condition(recipient).add(Expr::col(Alias::new("id")).eq(Value::from(id))),
+			)
+			.to_owned();
+		Ok(self
+			.execute(&build_inline_sql(self.connection.database_type(), &stmt))
+			.await? > 0)
+	}
+}
+
+/// Condition matching the notifications of `recipient`
+fn recipient_condition(recipient: &str) -> Condition {
+	Cond::all().add(Expr::col(Alias::new("recipient")).eq(recipient.to_string()))
+}
+
+/// Read a timestamp column, stored as text by SQLite
+fn timestamp(row: &crate::backends::types::Row, column: &str) -> Result<Option<DateTime<Utc>>> {
+	if let Ok(value) = row.get::<DateTime<Utc>>(column) {
+		return Ok(Some(value));
+	}
+	match row.get::<String>(column) {
+		Ok(text) => DateTime::parse_from_rfc3339(&text)
+			.or_else(|_| DateTime::parse_from_str(&text, "%Y-%m-%d %H:%M:%S%.f %:z"))
+			.map(|value| Some(value.with_timezone(&Utc)))
+			.map_err(|e| Error::Database(format!("invalid {} `{}`: {}", column, text, e))),
+		// NULL
+		Err(_) => Ok(None),
+	}
+}
+
+/// Convert a [`NOTIFICATION_TABLE`] row
+fn row_to_notification(row: &crate::backends::types::Row) -> Result<Notification> {
+	let db_err = |e: crate::backends::error::DatabaseError| Error::Database(e.to_string());
+	let level = row.get::<String>("level").map_err(db_err)?;
+	Ok(Notification {
+		id: row.get::<Uuid>("id").map_err(db_err)?,
+		recipient: row.get::<String>("recipient").map_err(db_err)?,
+		level: NotificationLevel::parse(&level)
+			.ok_or_else(|| Error::Database(format!("invalid notification level `{}`", level)))?,
+		title: row.get::<String>("title").map_err(db_err)?,
+		body: row.get::<String>("body").map_err(db_err)?,
+		link: row.get::<String>("link").ok(),
+		created_at: timestamp(row, "created_at")?
+			.ok_or_else(|| Error::Database("notification without created_at".to_string()))?,
+		read_at: timestamp(row, "read_at")?,
+	})
+}
+
+/// Live delivery of new notifications, e.g. over SSE or WebSockets
+#[async_trait]
+pub trait NotificationChannel: Send + Sync {
+	/// Push `notification` to its recipient's connected clients
+	async fn deliver(&self, notification: &Notification) -> Result<()>;
+}
+
+static NOTIFICATION_STORE: RwLock<Option<Arc<dyn NotificationStore>>> = RwLock::new(None);
+static NOTIFICATION_CHANNELS: RwLock<Vec<Arc<dyn NotificationChannel>>> = RwLock::new(Vec::new());
+
+/// Install the store that [`notify`] persists notifications in
+pub fn set_notification_store(store: Arc<dyn NotificationStore>) {
+	*NOTIFICATION_STORE.write() = Some(store);
+}
+
+/// Remove the installed store
+pub fn clear_notification_store() {
+	*NOTIFICATION_STORE.write() = None;
+}
+
+/// The installed notification store, if any
+pub fn notification_store() -> Option<Arc<dyn NotificationStore>> {
+	NOTIFICATION_STORE.read().clone()
+}
+
+/// Register a channel that [`notify`] delivers new notifications through
+pub fn add_notification_channel(channel: Arc<dyn NotificationChannel>) {
+	NOTIFICATION_CHANNELS.write().push(channel);
+}
+
+/// Remove every registered channel
+pub fn clear_notification_channels() {
+	NOTIFICATION_CHANNELS.write().clear();
+}
+
+/// Persist `notification` and deliver it through the registered channels
+///
+/// Fails when no store is installed or the store rejects the notification.
+/// Delivery failures are only logged: the notification is already stored
+/// and shows up in the recipient's inbox.
+pub async fn notify(notification: Notification) -> Result<Notification> {
+	let store = notification_store().ok_or_else(|| {
+		Error::ImproperlyConfigured("no notification store installed".to_string())
+	})?;
+	store.create(&notification).await?;
+	let channels = NOTIFICATION_CHANNELS.read().clone();
+	for channel in channels {
+		if let Err(e) = channel.deliver(&notification).await {
+			tracing::warn!(
+				notification = %notification.id,
+				recipient = %notification.recipient,
+				"Failed to deliver notification: {}",
+				e
+			);
+		}
+	}
+	Ok(notification)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use serial_test::serial;
+	use std::sync::Mutex;
+
+	#[derive(Default)]
+	struct RecordingChannel {
+		delivered: Mutex<Vec<Uuid>>,
+	}
+
+	#[async_trait]
+	impl NotificationChannel for RecordingChannel {
+		async fn deliver(&self, notification: &Notification) -> Result<()> {
+			self.delivered.lock().unwrap().push(notification.id);
+			Ok(())
+		}
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn inbox_is_newest_first_and_filters_unread() {
+		// Arrange
+		let store = InMemoryNotificationStore::new();
+		let first = Notification::new("42", "first");
+		let second = Notification::new("42", "second");
+		store.create(&first).await.unwrap();
+		store.create(&second).await.unwrap();
+		store
+			.create(&Notification::new("7", "someone else"))
+			.await
+			.unwrap();
+		store.mark_read("42", second.id).await.unwrap();
+
+		// Act
+		let all = store.inbox("42", false, None).await.unwrap();
+		let unread = store.inbox("42", true, None).await.unwrap();
+
+		// Assert
+		assert_eq!(
+			all.iter().map(|n| n.title.as_str()).collect::<Vec<_>>(),
+			["second", "first"]
+		);
+		assert_eq!(unread.len(), 1);
+		assert_eq!(unread[0].id, first.id);
+		assert_eq!(store.unread_count("42").await.unwrap(), 1);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn mark_read_is_scoped_to_recipient() {
+		// Arrange
+		let store = InMemoryNotificationStore::new();
+		let notification = Notification::new("42", "hello");
+		store.create(&notification).await.unwrap();
+
+		// Act
+		let by_other = store.mark_read("7", notification.id).await.unwrap();
+		let by_recipient = store.mark_read("42", notification.id).await.unwrap();
+		let again = store.mark_read("42", notification.id).await.unwrap();
+
+		// Assert
+		assert!(!by_other);
+		assert!(by_recipient);
+		assert!(!again);
+		assert_eq!(store.mark_all_read("42").await.unwrap(), 0);
+	}
+
+	#[rstest]
+	#[serial(notifications)]
+	#[tokio::test]
+	async fn notify_stores_and_delivers() {
+		// Arrange
+		let store = Arc::new(InMemoryNotificationStore::new());
+		let channel = Arc::new(RecordingChannel::default());
+		set_notification_store(store.clone());
+		add_notification_channel(channel.clone());
+
+		// Act
+		let sent =
+			notify(Notification::new("42", "Export ready").level(NotificationLevel::Success)).await;
+		clear_notification_channels();
+		clear_notification_store();
+
+		// Assert
+		let sent = sent.unwrap();
+		assert_eq!(store.unread_count("42").await.unwrap(), 1);
+		assert_eq!(*channel.delivered.lock().unwrap(), [sent.id]);
+	}
+
+	#[rstest]
+	#[serial(notifications)]
+	#[tokio::test]
+	async fn notify_requires_store() {
+		// Act
+		let result = notify(Notification::new("42", "hello")).await;
+
+		// Assert
+		assert!(matches!(result, Err(Error::ImproperlyConfigured(_))));
+	}
+}
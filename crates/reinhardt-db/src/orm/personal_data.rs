@@ -0,0 +1,607 @@
+//! Personal data export and anonymization
+//!
+//! Models declare which user their rows belong to with
+//! `#[model(data_subject = "...")]` and mark the fields holding personal data
+//! with `#[field(personal_data)]`. Every such model is registered in
+//! [`PERSONAL_DATA_MODELS`], which backs two operations:
+//!
+//! - [`export_user_data`] collects every row belonging to a user across all
+//!   registered models into a machine-readable [`DataExport`].
+//! - [`anonymize_user`] overwrites the personal fields of those rows in a
+//!   single transaction, following each field's [`AnonymizeStrategy`].
+//!
+//! Both are exposed by the `export_user_data` and `anonymize_user` management
+//! commands and by the matching admin actions.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! #[model(app_label = "blog", table_name = "comments", data_subject = "author_id")]
+//! pub struct Comment {
+//!     #[field(primary_key = true)]
+//!     id: Option<i64>,
+//!     author_id: i64,
+//!     #[field(max_length = 2000, personal_data)]
+//!     body: String,
+//!     #[field(max_length = 45, personal_data = "fake")]
+//!     ip_address: String,
+//!     #[field(max_length = 254, personal_data = "hash")]
+//!     email: String,
+//! }
+//!
+//! let export = export_user_data(42).await?;
+//! std::fs::write("user-42.json", export.to_json()?)?;
+//!
+//! let report = anonymize_user(42).await?;
+//! println!("anonymized {} rows", report.total_rows());
+//! ```
+
+use super::Model;
+use super::connection::{DatabaseConnection, QueryRow};
+use crate::backends::sql_build_helpers::build_inline_sql;
+use crate::backends::types::DatabaseType;
+use chrono::{DateTime, Utc};
+use linkme::distributed_slice;
+use once_cell::sync::Lazy;
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_query::prelude::{Alias, ColumnRef, Expr, ExprTrait, Query, Value};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+/// How a personal field is overwritten by [`anonymize_user`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnonymizeStrategy {
+	/// Set the column to `NULL`
+	#[default]
+	Null,
+	/// Replace the value with its SHA-256 digest, so equal values stay equal
+	Hash,
+	/// Replace the value with a placeholder of the same shape
+	Fake,
+}
+
+impl fmt::Display for AnonymizeStrategy {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Null => f.write_str("null"),
+			Self::Hash => f.write_str("hash"),
+			Self::Fake => f.write_str("fake"),
+		}
+	}
+}
+
+impl FromStr for AnonymizeStrategy {
+	type Err = String;
+
+	fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+		match s {
+			"null" => Ok(Self::Null),
+			"hash" => Ok(Self::Hash),
+			"fake" => Ok(Self::Fake),
+			other => Err(format!(
+				"unknown anonymize strategy `{}` (expected null, hash or fake)",
+				other
+			)),
+		}
+	}
+}
+
+/// Field declared with `#[field(personal_data)]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PersonalDataField {
+	/// Name of the field
+	pub field: &'static str,
+	/// Column storing the field
+	pub column: &'static str,
+	/// How the field is anonymized
+	pub strategy: AnonymizeStrategy,
+	/// Maximum length of replacement strings
+	pub max_length: Option<usize>,
+}
+
+/// Model holding rows that belong to a data subject
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersonalDataModel {
+	/// Label of the app declaring the model
+	pub app_label: &'static str,
+	/// Name of the model
+	pub model: &'static str,
+	/// Table storing the model
+	pub table: &'static str,
+	/// Column holding the id of the user a row belongs to
+	pub subject_column: &'static str,
+	/// Primary key column
+	pub primary_key: &'static str,
+	/// Personal fields and their strategies
+	pub fields: Vec<PersonalDataField>,
+}
+
+impl PersonalDataModel {
+	/// Describe `M` from its generated metadata
+	///
+	/// # Panics
+	///
+	/// Panics if `M` declares no `data_subject`.
+	pub fn of<M: Model>() -> Self {
+		Self {
+			app_label: M::app_label(),
+			model: std::any::type_name::<M>()
+				.rsplit("::")
+				.next()
+				.unwrap_or_default(),
+			table: M::table_name(),
+			subject_column: M::data_subject_field()
+				.expect("personal data models must declare `data_subject`"),
+			primary_key: M::primary_key_field(),
+			fields: M::personal_data_fields(),
+		}
+	}
+
+	/// `app_label.Model` key used in exports and reports
+	pub fn label(&self) -> String {
+		format!("{}.{}", self.app_label, self.model)
+	}
+}
+
+/// Type for personal data model providers collected at compile-time
+pub type PersonalDataProvider = fn() -> PersonalDataModel;
+
+/// Distributed slice for collecting personal data models at compile-time
+#[distributed_slice]
+pub static PERSONAL_DATA_MODELS: [PersonalDataProvider];
+
+static RUNTIME_MODELS: Lazy<RwLock<Vec<PersonalDataModel>>> = Lazy::new(|| RwLock::new(Vec::new()));
+
+/// Register a personal data model at runtime (for tests and dynamic models)
+pub fn register_personal_data(model: PersonalDataModel) {
+	RUNTIME_MODELS
+		.write()
+		.unwrap_or_else(|e| e.into_inner())
+		.push(model);
+}
+
+/// All personal data models: compile-time registrations followed by runtime ones
+pub fn registered_personal_data_models() -> Vec<PersonalDataModel> {
+	let mut models = Vec::new();
+	// In test mode, do not access PERSONAL_DATA_MODELS to avoid "duplicate
+	// distributed_slice" errors, as for the migration registry.
+	#[cfg(not(test))]
+	for provider in PERSONAL_DATA_MODELS {
+		models.push(provider());
+	}
+	models.extend(
+		RUNTIME_MODELS
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.iter()
+			.cloned(),
+	);
+	models
+}
+
+/// Every row belonging to one data subject
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataExport {
+	/// Id of the user the data belongs to
+	pub subject: JsonValue,
+	/// When the export was produced
+	pub generated_at: DateTime<Utc>,
+	/// Rows of each model, keyed by `app_label.Model`
+	pub models: BTreeMap<String, Vec<JsonValue>>,
+}
+
+impl DataExport {
+	/// Number of exported rows across all models
+	pub fn total_rows(&self) -> usize {
+		self.models.values().map(Vec::len).sum()
+	}
+
+	/// Serialize the export as pretty-printed JSON
+	pub fn to_json(&self) -> Result<String> {
+		serde_json::to_string_pretty(self).map_err(|e| Error::Serialization(e.to_string()))
+	}
+}
+
+/// Rows overwritten by [`anonymize_user`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnonymizationReport {
+	/// Anonymized rows of each model, keyed by `app_label.Model`
+	pub models: BTreeMap<String, usize>,
+}
+
+impl AnonymizationReport {
+	/// Number of anonymized rows across all models
+	pub fn total_rows(&self) -> usize {
+		self.models.values().sum()
+	}
+}
+
+/// Export every row belonging to `subject` using the global connection
+pub async fn export_user_data(subject: impl Into<JsonValue>) -> Result<DataExport> {
+	let conn = super::manager::get_connection().await?;
+	export_user_data_with_conn(&conn, subject).await
+}
+
+/// Export every row belonging to `subject` from all registered models
+///
+/// Rows contain every column, not only the personal fields, so the export
+/// reflects everything stored about the user.
+pub async fn export_user_data_with_conn(
+	conn: &DatabaseConnection,
+	subject: impl Into<JsonValue>,
+) -> Result<DataExport> {
+	let subject = subject.into();
+	let mut models = BTreeMap::new();
+	for model in registered_personal_data_models() {
+		let stmt = Query::select()
+			.column(ColumnRef::Asterisk)
+			.from(Alias::new(model.table))
+			.and_where(Expr::col(Alias::new(model.subject_column)).eq(json_to_value(&subject)))
+			.to_owned();
+		let rows = conn
+			.query(&build_inline_sql(conn.backend().into(), &stmt), vec![])
+			.await
+			.map_err(|e| Error::Database(e.to_string()))?;
+		models
+			.entry(model.label())
+			.or_insert_with(Vec::new)
+			.extend(rows.into_iter().map(|row| row.data));
+	}
+	Ok(DataExport {
+		subject,
+		generated_at: Utc::now(),
+		models,
+	})
+}
+
+/// Anonymize every row belonging to `subject` using the global connection
+///
+/// Hashes are unsalted; use [`Anonymizer`] to add a salt.
+pub async fn anonymize_user(subject: impl Into<JsonValue>) -> Result<AnonymizationReport> {
+	let conn = super::manager::get_connection().await?;
+	Anonymizer::new(&conn).run(subject).await
+}
+
+/// Overwrites the personal fields of a data subject's rows
+///
+/// All registered models are updated in one transaction: either every row
+/// is anonymized or, on error, none is.
+pub struct Anonymizer<'a> {
+	connection: &'a DatabaseConnection,
+	salt: String,
+}
+
+impl<'a> Anonymizer<'a> {
+	/// Create an anonymizer writing through `connection`
+	pub fn new(connection: &'a DatabaseConnection) -> Self {
+		Self {
+			connection,
+			salt: String::new(),
+		}
+	}
+
+	/// Salt mixed into [`AnonymizeStrategy::Hash`] digests and fake values
+	pub fn salt(mut self, salt: impl Into<String>) -> Self {
+		self.salt = salt.into();
+		self
+	}
+
+	/// Anonymize every row belonging to `subject`
+	pub async fn run(&self, subject: impl Into<JsonValue>) -> Result<AnonymizationReport> {
+		let subject = subject.into();
+		let mut tx = self
+			.connection
+			.begin()
+			.await
+			.map_err(|e| Error::Database(e.to_string()))?;
+		let mut report = AnonymizationReport::default();
+		for model in registered_personal_data_models() {
+			match self.anonymize_model(tx.as_mut(), &model, &subject).await {
+				Ok(rows) => *report.models.entry(model.label()).or_default() += rows,
+				Err(e) => {
+					let _ = tx.rollback().await;
+					return Err(e);
+				}
+			}
+		}
+		tx.commit()
+			.await
+			.map_err(|e| Error::Database(e.to_string()))?;
+		Ok(report)
+	}
+
+	/// Overwrite the personal fields of `subject`'s rows in one model
+	async fn anonymize_model(
+		&self,
+		tx: &mut dyn super::connection::TransactionExecutor,
+		model: &PersonalDataModel,
+		subject: &JsonValue,
+	) -> Result<usize> {
+		if model.fields.is_empty() {
+			return Ok(0);
+		}
+		let db_type = DatabaseType::from(self.connection.backend());
+		let select = Query::select()
+			.column(Alias::new(model.primary_key))
+			.columns(model.fields.iter().map(|f| Alias::new(f.column)))
+			.from(Alias::new(model.table))
+			.and_where(Expr::col(Alias::new(model.subject_column)).eq(json_to_value(subject)))
+			.to_owned();
+		let rows = tx
+			.fetch_all(&build_inline_sql(db_type, &select), vec![])
+			.await
+			.map_err(|e| Error::Database(e.to_string()))?;
+
+		let count = rows.len();
+		for row in rows {
+			let row = QueryRow::from_backend_row(row);
+			let pk = row.data.get(model.primary_key).cloned().ok_or_else(|| {
+				Error::Database(format!(
+					"{}: row without primary key `{}`",
+					model.label(),
+					model.primary_key
+				))
+			})?;
+			let mut update = Query::update();
+			update.table(Alias::new(model.table));
+			for field in &model.fields {
+				let current = row.data.get(field.column).unwrap_or(&JsonValue::Null);
+				let replacement = anonymized_value(field, current, &self.salt);
+				update.value(Alias::new(field.column), json_to_value(&replacement));
+			}
+			update.and_where(Expr::col(Alias::new(model.primary_key)).eq(json_to_value(&pk)));
+			tx.execute(&build_inline_sql(db_type, &update), vec![])
+				.await
+				.map_err(|e| Error::Database(e.to_string()))?;
+		}
+		Ok(count)
+	}
+}
+
+/// Replacement for `current` under the field's strategy
+///
+/// `NULL` values stay `NULL` whatever the strategy, so anonymizing twice
+/// yields the same result.
+pub fn anonymized_value(field: &PersonalDataField, current: &JsonValue, salt: &str) -> JsonValue {
+	if current.is_null() {
+		return JsonValue::Null;
+	}
+	let value = match field.strategy {
+		AnonymizeStrategy::Null => return JsonValue::Null,
+		AnonymizeStrategy::Hash => match current {
+			JsonValue::Number(_) => {
+				let digest = digest(current, salt);
+				let n = i64::from_str_radix(&digest[..8], 16).unwrap_or_default();
+				return JsonValue::from(n);
+			}
+			JsonValue::Bool(_) => return JsonValue::Bool(false),
+			_ => digest(current, salt),
+		},
+		AnonymizeStrategy::Fake => match current {
+			JsonValue::Number(_) => return JsonValue::from(0),
+			JsonValue::Bool(_) => return JsonValue::Bool(false),
+			_ => fake_value(field.column, &digest(current, salt)[..8]),
+		},
+	};
+	JsonValue::String(match field.max_length {
+		Some(max) => value.chars().take(max).collect(),
+		None => value,
+	})
+}
+
+/// Whether `current` already is a replacement produced by [`anonymized_value`]
+///
+/// Lets repeated runs, such as retention enforcement, skip rows anonymized
+/// earlier. Hashed numbers cannot be told apart from real ones and are never
+/// reported as anonymized.
+pub fn is_anonymized(field: &PersonalDataField, current: &JsonValue) -> bool {
+	match (field.strategy, current) {
+		(_, JsonValue::Null) => true,
+		(AnonymizeStrategy::Null, _) => false,
+		(_, JsonValue::Bool(b)) => !b,
+		(AnonymizeStrategy::Hash, JsonValue::String(s)) => {
+			let len = field.max_length.map_or(64, |max| max.min(64));
+			s.len() == len && s.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+		}
+		(AnonymizeStrategy::Fake, JsonValue::Number(n)) => n.as_i64() == Some(0),
+		(AnonymizeStrategy::Fake, JsonValue::String(s)) => {
+			// `None` marks the characters of the digest tag.
+			let pattern: Vec<Option<char>> = fake_value(field.column, &"\0".repeat(8))
+				.chars()
+				.map(|c| (c != '\0').then_some(c))
+				.collect();
+			let len = field
+				.max_length
+				.map_or(pattern.len(), |max| max.min(pattern.len()));
+			s.chars().count() == len
+				&& s.chars().zip(&pattern).all(|(c, expected)| match expected {
+					Some(expected) => c == *expected,
+					None => matches!(c, '0'..='9' | 'a'..='f'),
+				})
+		}
+		_ => false,
+	}
+}
+
+/// Placeholder shaped after the column name
+fn fake_value(column: &str, tag: &str) -> String {
+	let column = column.to_ascii_lowercase();
+	if column.contains("email") {
+		format!("user-{}@example.invalid", tag)
+	} else if column.contains("phone") || column.contains("mobile") {
+		"+10000000000".to_string()
+	} else if column.ends_with("ip") || column.contains("ip_address") {
+		"0.0.0.0".to_string()
+	} else if column.contains("name") {
+		"Anonymous".to_string()
+	} else {
+		format!("redacted-{}", tag)
+	}
+}
+
+/// Hex SHA-256 of the salted value
+fn digest(value: &JsonValue, salt: &str) -> String {
+	let text = match value {
+		JsonValue::String(s) => s.clone(),
+		other => other.to_string(),
+	};
+	let mut hasher = Sha256::new();
+	hasher.update(salt.as_bytes());
+	hasher.update(text.as_bytes());
+	format!("{:x}", hasher.finalize())
+}
+
+/// Convert a JSON column value into a query value
+pub(crate) fn json_to_value(json: &JsonValue) -> Value {
+	match json {
+		JsonValue::Null => Value::String(None),
+		JsonValue::Bool(b) => Value::Bool(Some(*b)),
+		JsonValue::Number(n) => {
+			if let Some(i) = n.as_i64() {
+				Value::BigInt(Some(i))
+			} else if let Some(f) = n.as_f64() {
+				Value::Double(Some(f))
+			} else {
+				Value::String(Some(Box::new(n.to_string())))
+			}
+		}
+		JsonValue::String(s) => Value::String(Some(Box::new(s.clone()))),
+		JsonValue::Array(_) | JsonValue::Object(_) => {
+			Value::String(Some(Box::new(json.to_string())))
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use serde_json::json;
+
+	fn field(column: &'static str, strategy: AnonymizeStrategy) -> PersonalDataField {
+		PersonalDataField {
+			field: column,
+			column,
+			strategy,
+			max_length: None,
+		}
+	}
+
+	#[rstest]
+	#[case("null", AnonymizeStrategy::Null)]
+	#[case("hash", AnonymizeStrategy::Hash)]
+	#[case("fake", AnonymizeStrategy::Fake)]
+	fn strategy_round_trips_through_strings(
+		#[case] text: &str,
+		#[case] strategy: AnonymizeStrategy,
+	) {
+		// Act
+		let parsed: AnonymizeStrategy = text.parse().unwrap();
+
+		// Assert
+		assert_eq!(parsed, strategy);
+		assert_eq!(strategy.to_string(), text);
+	}
+
+	#[rstest]
+	fn null_strategy_clears_value() {
+		// Act
+		let value = anonymized_value(&field("body", AnonymizeStrategy::Null), &json!("hello"), "");
+
+		// Assert
+		assert_eq!(value, JsonValue::Null);
+	}
+
+	#[rstest]
+	fn hash_strategy_is_stable_salted_and_truncated() {
+		// Arrange
+		let mut email = field("email", AnonymizeStrategy::Hash);
+		email.max_length = Some(16);
+
+		// Act
+		let first = anonymized_value(&email, &json!("alice@example.com"), "salt");
+		let second = anonymized_value(&email, &json!("alice@example.com"), "salt");
+		let other_salt = anonymized_value(&email, &json!("alice@example.com"), "pepper");
+
+		// Assert
+		assert_eq!(first, second);
+		assert_ne!(first, other_salt);
+		assert_eq!(first.as_str().unwrap().len(), 16);
+	}
+
+	#[rstest]
+	#[case("email", json!("a@b.c"), "@example.invalid")]
+	#[case("ip_address", json!("10.1.2.3"), "0.0.0.0")]
+	#[case("first_name", json!("Alice"), "Anonymous")]
+	#[case("bio", json!("Likes tea"), "redacted-")]
+	fn fake_strategy_follows_column_name(
+		#[case] column: &'static str,
+		#[case] current: JsonValue,
+		#[case] expected: &str,
+	) {
+		// Act
+		let value = anonymized_value(&field(column, AnonymizeStrategy::Fake), &current, "");
+
+		// Assert
+		assert!(value.as_str().unwrap().contains(expected), "{}", value);
+	}
+
+	#[rstest]
+	fn null_values_stay_null() {
+		// Act
+		let value = anonymized_value(
+			&field("email", AnonymizeStrategy::Fake),
+			&JsonValue::Null,
+			"",
+		);
+
+		// Assert
+		assert_eq!(value, JsonValue::Null);
+	}
+
+	#[rstest]
+	#[case("email", AnonymizeStrategy::Fake, json!("alice@example.com"))]
+	#[case("bio", AnonymizeStrategy::Fake, json!("Likes tea"))]
+	#[case("email", AnonymizeStrategy::Hash, json!("alice@example.com"))]
+	#[case("note", AnonymizeStrategy::Null, json!("hello"))]
+	fn replacements_are_recognized_as_anonymized(
+		#[case] column: &'static str,
+		#[case] strategy: AnonymizeStrategy,
+		#[case] current: JsonValue,
+	) {
+		// Arrange
+		let field = field(column, strategy);
+
+		// Act
+		let replacement = anonymized_value(&field, &current, "pepper");
+
+		// Assert
+		assert!(!is_anonymized(&field, &current));
+		assert!(is_anonymized(&field, &replacement), "{}", replacement);
+	}
+
+	#[rstest]
+	fn registered_models_include_runtime_registrations() {
+		// Arrange
+		register_personal_data(PersonalDataModel {
+			app_label: "blog",
+			model: "Comment",
+			table: "blog_comments",
+			subject_column: "author_id",
+			primary_key: "id",
+			fields: vec![field("body", AnonymizeStrategy::Null)],
+		});
+
+		// Act
+		let models = registered_personal_data_models();
+
+		// Assert
+		assert!(models.iter().any(|m| m.label() == "blog.Comment"));
+	}
+}
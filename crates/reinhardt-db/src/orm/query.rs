@@ -12,7 +12,7 @@ use crate::orm::query_fields::comparison::FieldComparison;
 use crate::orm::query_fields::compiler::QueryFieldCompiler;
 use reinhardt_query::prelude::{
 	Alias, BinOper, ColumnRef, Condition, Expr, ExprTrait, Func, JoinType as SeaJoinType,
-	MySqlQueryBuilder, Order, PostgresQueryBuilder, Query, QueryBuilder, QueryStatementBuilder,
+	MySqlQueryBuilder, PostgresQueryBuilder, Query, QueryBuilder, QueryStatementBuilder,
 	SelectStatement, SimpleExpr, SqliteQueryBuilder, UpdateStatement,
 };
 use reinhardt_query::types::PgBinOper;
@@ -97,6 +97,13 @@ pub enum FilterOperator {
 	RangeContainedBy,
 	/// Range overlaps (&&)
 	RangeOverlaps,
+	// PostGIS spatial lookups
+	/// Geometry lies within the given geometry (ST_Within)
+	GeoWithin,
+	/// Geometry is within a distance in meters of the given geometry (ST_DWithin)
+	GeoDistanceLte,
+	/// Bounding box contains the given geometry's bounding box (~)
+	GeoBBContains,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -284,6 +291,18 @@ impl From<Uuid> for UpdateValue {
 	}
 }
 
+impl From<super::expressions::F> for UpdateValue {
+	fn from(value: super::expressions::F) -> Self {
+		Self::FieldRef(value)
+	}
+}
+
+impl From<super::annotation::Expression> for UpdateValue {
+	fn from(value: super::annotation::Expression) -> Self {
+		Self::Expression(value)
+	}
+}
+
 impl<T> From<Option<T>> for UpdateValue
 where
 	T: Into<UpdateValue>,
@@ -628,6 +647,31 @@ enum HavingCondition {
 		operator: ComparisonOp,
 		value: AggregateValue,
 	},
+	/// Compare an annotated value, referenced by its alias
+	/// Example: HAVING COUNT(books.id) FILTER (WHERE rating >= 4) > 3
+	AnnotationCompare {
+		alias: String,
+		operator: ComparisonOp,
+		value: AggregateValue,
+	},
+}
+
+/// Applies a HAVING comparison to an aggregate expression
+fn compare_having(expr: SimpleExpr, operator: &ComparisonOp, value: &AggregateValue) -> SimpleExpr {
+	match (operator, value) {
+		(ComparisonOp::Eq, AggregateValue::Int(v)) => expr.eq(*v),
+		(ComparisonOp::Eq, AggregateValue::Float(v)) => expr.eq(*v),
+		(ComparisonOp::Ne, AggregateValue::Int(v)) => expr.ne(*v),
+		(ComparisonOp::Ne, AggregateValue::Float(v)) => expr.ne(*v),
+		(ComparisonOp::Gt, AggregateValue::Int(v)) => expr.gt(*v),
+		(ComparisonOp::Gt, AggregateValue::Float(v)) => expr.gt(*v),
+		(ComparisonOp::Gte, AggregateValue::Int(v)) => expr.gte(*v),
+		(ComparisonOp::Gte, AggregateValue::Float(v)) => expr.gte(*v),
+		(ComparisonOp::Lt, AggregateValue::Int(v)) => expr.lt(*v),
+		(ComparisonOp::Lt, AggregateValue::Float(v)) => expr.lt(*v),
+		(ComparisonOp::Lte, AggregateValue::Int(v)) => expr.lte(*v),
+		(ComparisonOp::Lte, AggregateValue::Float(v)) => expr.lte(*v),
+	}
 }
 
 /// Subquery condition specification for WHERE clause
@@ -658,7 +702,7 @@ where
 	filter_conditions: SmallVec<[FilterCondition; 4]>,
 	select_related_fields: Vec<String>,
 	prefetch_related_fields: Vec<String>,
-	order_by_fields: Vec<String>,
+	order_by_fields: Vec<super::expressions::OrderBy>,
 	distinct_enabled: bool,
 	selected_fields: Option<Vec<String>>,
 	deferred_fields: Vec<String>,
@@ -2311,6 +2355,107 @@ where
 		self
 	}
 
+	/// Add HAVING clause comparing an annotated value
+	///
+	/// Filters grouped rows on an annotation added with `annotate`, `aggregate`
+	/// or `aggregate_related`, referenced by its alias. The annotation's full
+	/// expression is repeated in the HAVING clause, since PostgreSQL does not
+	/// accept SELECT aliases there; unknown aliases are emitted as-is.
+	/// The closure receives a stand-in expression for the annotation.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use reinhardt_db::orm::Model;
+	/// # use reinhardt_db::orm::aggregation::Aggregate;
+	/// # use serde::{Serialize, Deserialize};
+	/// # #[derive(Clone, Serialize, Deserialize)]
+	/// # struct Author { id: Option<i64> }
+	/// # #[derive(Clone)]
+	/// # struct AuthorFields;
+	/// # impl reinhardt_db::orm::model::FieldSelector for AuthorFields {
+	/// #     fn with_alias(self, _alias: &str) -> Self { self }
+	/// # }
+	/// # impl Model for Author {
+	/// #     type PrimaryKey = i64;
+	/// #     type Fields = AuthorFields;
+	/// #     type Objects = reinhardt_db::orm::Manager<Self>;
+	/// #     fn table_name() -> &'static str { "authors" }
+	/// #     fn new_fields() -> Self::Fields { AuthorFields }
+	/// #     fn primary_key(&self) -> Option<Self::PrimaryKey> { self.id }
+	/// #     fn set_primary_key(&mut self, value: Self::PrimaryKey) { self.id = Some(value); }
+	/// # }
+	/// # #[derive(Clone, Serialize, Deserialize)]
+	/// # struct Book { id: Option<i64> }
+	/// # #[derive(Clone)]
+	/// # struct BookFields;
+	/// # impl reinhardt_db::orm::model::FieldSelector for BookFields {
+	/// #     fn with_alias(self, _alias: &str) -> Self { self }
+	/// # }
+	/// # impl Model for Book {
+	/// #     type PrimaryKey = i64;
+	/// #     type Fields = BookFields;
+	/// #     type Objects = reinhardt_db::orm::Manager<Self>;
+	/// #     fn table_name() -> &'static str { "books" }
+	/// #     fn new_fields() -> Self::Fields { BookFields }
+	/// #     fn primary_key(&self) -> Option<Self::PrimaryKey> { self.id }
+	/// #     fn set_primary_key(&mut self, value: Self::PrimaryKey) { self.id = Some(value); }
+	/// # }
+	/// // Authors with more than 3 books
+	/// let sql = Author::objects()
+	///     .all()
+	///     .aggregate_related::<Book>("author_id", Aggregate::count(Some("id")).with_alias("num_books"))
+	///     .having_annotation("num_books", |num_books| num_books.gt(3))
+	///     .to_sql();
+	/// assert!(sql.ends_with(r#"HAVING COUNT(books.id) > 3"#));
+	/// ```
+	pub fn having_annotation<F>(mut self, alias: &str, expr_fn: F) -> Self
+	where
+		F: FnOnce(AggregateExpr) -> ComparisonExpr,
+	{
+		let comparison = expr_fn(AggregateExpr::count(alias));
+
+		let operator = match comparison.op {
+			super::query_fields::comparison::ComparisonOperator::Eq => ComparisonOp::Eq,
+			super::query_fields::comparison::ComparisonOperator::Ne => ComparisonOp::Ne,
+			super::query_fields::comparison::ComparisonOperator::Gt => ComparisonOp::Gt,
+			super::query_fields::comparison::ComparisonOperator::Gte => ComparisonOp::Gte,
+			super::query_fields::comparison::ComparisonOperator::Lt => ComparisonOp::Lt,
+			super::query_fields::comparison::ComparisonOperator::Lte => ComparisonOp::Lte,
+		};
+
+		let value = match comparison.value {
+			super::query_fields::aggregate::ComparisonValue::Int(i) => AggregateValue::Int(i),
+			super::query_fields::aggregate::ComparisonValue::Float(f) => AggregateValue::Float(f),
+		};
+
+		self.having_conditions
+			.push(HavingCondition::AnnotationCompare {
+				alias: alias.to_string(),
+				operator,
+				value,
+			});
+		self
+	}
+
+	/// Resolves an annotation alias to the SQL expression used in HAVING
+	fn annotation_having_expr(&self, alias: &str) -> SimpleExpr {
+		match self.annotations.iter().find(|a| a.alias == alias) {
+			Some(annotation) => Expr::cust(annotation.value.to_sql_expr()).into_simple_expr(),
+			None => Expr::col(Alias::new(alias)).into_simple_expr(),
+		}
+	}
+
+	/// Returns `true` if any annotation aggregates over a joined related table
+	fn has_related_aggregates(&self) -> bool {
+		self.annotations.iter().any(|a| {
+			matches!(
+				&a.value,
+				super::annotation::AnnotationValue::Aggregate(agg) if agg.table.is_some()
+			)
+		})
+	}
+
 	/// Add WHERE IN (subquery) condition
 	///
 	/// Filters rows where the specified field's value is in the result set of a subquery.
@@ -2720,8 +2865,37 @@ where
 		self
 	}
 
-	/// Build WHERE condition using reinhardt-query from accumulated filters
+	/// Build WHERE condition from accumulated filters and the tenant scope
+	///
+	/// For tenant-bound models the current [`tenancy`](super::tenancy) scope
+	/// ANDs `tenant_column = tenant` onto the filter predicates, or `FALSE`
+	/// when no tenant is in scope, so that no query can read across tenants.
 	fn build_where_condition(&self) -> reinhardt_core::exception::Result<Option<Condition>> {
+		let predicates = self.build_filter_predicates()?;
+		let tenant_condition = match super::tenancy::tenant_filter::<T>() {
+			super::tenancy::TenantFilter::Unrestricted => return Ok(predicates),
+			super::tenancy::TenantFilter::Deny { .. } => Self::false_condition(),
+			super::tenancy::TenantFilter::Tenant { column, tenant } => Condition::all()
+				.add(Expr::col(self.tenant_column_ref(column)).eq(tenant.to_query_value())),
+		};
+
+		Ok(Some(match predicates {
+			Some(predicates) => Condition::all().add(predicates).add(tenant_condition),
+			None => tenant_condition,
+		}))
+	}
+
+	/// Tenant column reference, qualified when other tables are joined in
+	fn tenant_column_ref(&self, column: &str) -> reinhardt_query::prelude::ColumnRef {
+		if self.joins.is_empty() {
+			return parse_column_reference(column);
+		}
+		let owner = self.from_alias.as_deref().unwrap_or(T::table_name());
+		parse_column_reference(&format!("{}.{}", owner, column))
+	}
+
+	/// Build WHERE condition using reinhardt-query from accumulated filters
+	fn build_filter_predicates(&self) -> reinhardt_core::exception::Result<Option<Condition>> {
 		if !self.has_where_predicates() {
 			return Ok(None);
 		}
@@ -2775,22 +2949,40 @@ where
 				}
 				// Expression comparisons (F("a") * F("b") etc.)
 				(FilterOperator::Eq, FilterValue::Expression(expr)) => {
-					col.eq(Self::expression_to_query_expr(expr))
+					col.eq(Self::expression_to_query_expr(
+						expr,
+						super::connection::DatabaseBackend::Postgres,
+					))
 				}
 				(FilterOperator::Ne, FilterValue::Expression(expr)) => {
-					col.ne(Self::expression_to_query_expr(expr))
+					col.ne(Self::expression_to_query_expr(
+						expr,
+						super::connection::DatabaseBackend::Postgres,
+					))
 				}
 				(FilterOperator::Gt, FilterValue::Expression(expr)) => {
-					col.gt(Self::expression_to_query_expr(expr))
+					col.gt(Self::expression_to_query_expr(
+						expr,
+						super::connection::DatabaseBackend::Postgres,
+					))
 				}
 				(FilterOperator::Gte, FilterValue::Expression(expr)) => {
-					col.gte(Self::expression_to_query_expr(expr))
+					col.gte(Self::expression_to_query_expr(
+						expr,
+						super::connection::DatabaseBackend::Postgres,
+					))
 				}
 				(FilterOperator::Lt, FilterValue::Expression(expr)) => {
-					col.lt(Self::expression_to_query_expr(expr))
+					col.lt(Self::expression_to_query_expr(
+						expr,
+						super::connection::DatabaseBackend::Postgres,
+					))
 				}
 				(FilterOperator::Lte, FilterValue::Expression(expr)) => {
-					col.lte(Self::expression_to_query_expr(expr))
+					col.lte(Self::expression_to_query_expr(
+						expr,
+						super::connection::DatabaseBackend::Postgres,
+					))
 				}
 				// NULL checks
 				(FilterOperator::Eq, FilterValue::Null) => col.is_null(),
@@ -3106,6 +3298,37 @@ where
 					)
 					.into_simple_expr()
 				}
+				// PostGIS spatial lookups (geometry bound as EWKT)
+				(FilterOperator::GeoWithin, FilterValue::String(ewkt)) => {
+					// ST_Within(field, ST_GeomFromEWKT(?)) - parameterized
+					Expr::cust_with_values(
+						format!(
+							"ST_Within({}, ST_GeomFromEWKT(?))",
+							Self::filter_lhs_sql(filter)
+						),
+						[ewkt.clone()],
+					)
+					.into_simple_expr()
+				}
+				(FilterOperator::GeoDistanceLte, FilterValue::List(values)) => {
+					// Geography cast so the distance is measured in meters
+					Expr::cust_with_values(
+						format!(
+							"ST_DWithin({}::geography, ST_GeomFromEWKT(?)::geography, ?)",
+							Self::filter_lhs_sql(filter)
+						),
+						values.iter().map(Self::filter_value_to_sea_value),
+					)
+					.into_simple_expr()
+				}
+				(FilterOperator::GeoBBContains, FilterValue::String(ewkt)) => {
+					// field ~ ST_GeomFromEWKT(?) - parameterized
+					Expr::cust_with_values(
+						format!("{} ~ ST_GeomFromEWKT(?)", Self::filter_lhs_sql(filter)),
+						[ewkt.clone()],
+					)
+					.into_simple_expr()
+				}
 				// Fallback for unsupported combinations
 				_ => {
 					// Default to equality for unhandled cases
@@ -3169,7 +3392,7 @@ where
 			FilterCondition::Single(filter) => {
 				let mut queryset = Self::new();
 				queryset.filters.push(filter.clone());
-				queryset.build_where_condition()
+				queryset.build_filter_predicates()
 			}
 			FilterCondition::And(conditions) => {
 				let mut condition = Condition::all();
@@ -3217,62 +3440,11 @@ where
 	/// Uses Expr::cust() for arithmetic operations as reinhardt-query doesn't provide
 	/// multiply/divide/etc. methods. SQL injection risk is low since F() only
 	/// accepts field names.
-	fn expression_to_query_expr(expr: &super::annotation::Expression) -> Expr {
-		use crate::orm::annotation::Expression;
-
-		match expr {
-			Expression::Add(left, right) => {
-				let left_sql = Self::annotation_value_to_sql(left);
-				let right_sql = Self::annotation_value_to_sql(right);
-				Expr::cust(format!("({} + {})", left_sql, right_sql))
-			}
-			Expression::Subtract(left, right) => {
-				let left_sql = Self::annotation_value_to_sql(left);
-				let right_sql = Self::annotation_value_to_sql(right);
-				Expr::cust(format!("({} - {})", left_sql, right_sql))
-			}
-			Expression::Multiply(left, right) => {
-				let left_sql = Self::annotation_value_to_sql(left);
-				let right_sql = Self::annotation_value_to_sql(right);
-				Expr::cust(format!("({} * {})", left_sql, right_sql))
-			}
-			Expression::Divide(left, right) => {
-				let left_sql = Self::annotation_value_to_sql(left);
-				let right_sql = Self::annotation_value_to_sql(right);
-				Expr::cust(format!("({} / {})", left_sql, right_sql))
-			}
-			Expression::Case { whens, default } => {
-				let mut case_sql = "CASE".to_string();
-				for when in whens.iter() {
-					// Use When::to_sql() which generates "WHEN condition THEN value"
-					case_sql.push_str(&format!(" {}", when.to_sql()));
-				}
-				if let Some(default_val) = default {
-					case_sql.push_str(&format!(
-						" ELSE {}",
-						Self::annotation_value_to_sql(default_val)
-					));
-				}
-				case_sql.push_str(" END");
-				Expr::cust(case_sql)
-			}
-			Expression::Coalesce(values) => {
-				let value_sqls = values
-					.iter()
-					.map(|v| Self::annotation_value_to_sql(v))
-					.collect::<Vec<_>>()
-					.join(", ");
-				Expr::cust(format!("COALESCE({})", value_sqls))
-			}
-		}
-	}
-
-	/// Convert AnnotationValue to SQL string for custom expressions
-	///
-	/// Delegates to the `AnnotationValue::to_sql()` method which provides
-	/// complete SQL generation for all annotation value types.
-	fn annotation_value_to_sql(value: &super::annotation::AnnotationValue) -> String {
-		value.to_sql()
+	fn expression_to_query_expr(
+		expr: &super::annotation::Expression,
+		backend: super::connection::DatabaseBackend,
+	) -> Expr {
+		Expr::cust(expr.to_sql_for(backend))
 	}
 
 	fn filter_lhs_expr(filter: &Filter) -> Expr {
@@ -3673,54 +3845,23 @@ where
 						}
 					};
 
-					// Build comparison expression
-					let having_expr = match operator {
-						ComparisonOp::Eq => match value {
-							AggregateValue::Int(v) => agg_expr.eq(*v),
-							AggregateValue::Float(v) => agg_expr.eq(*v),
-						},
-						ComparisonOp::Ne => match value {
-							AggregateValue::Int(v) => agg_expr.ne(*v),
-							AggregateValue::Float(v) => agg_expr.ne(*v),
-						},
-						ComparisonOp::Gt => match value {
-							AggregateValue::Int(v) => agg_expr.gt(*v),
-							AggregateValue::Float(v) => agg_expr.gt(*v),
-						},
-						ComparisonOp::Gte => match value {
-							AggregateValue::Int(v) => agg_expr.gte(*v),
-							AggregateValue::Float(v) => agg_expr.gte(*v),
-						},
-						ComparisonOp::Lt => match value {
-							AggregateValue::Int(v) => agg_expr.lt(*v),
-							AggregateValue::Float(v) => agg_expr.lt(*v),
-						},
-						ComparisonOp::Lte => match value {
-							AggregateValue::Int(v) => agg_expr.lte(*v),
-							AggregateValue::Float(v) => agg_expr.lte(*v),
-						},
-					};
-
-					stmt.and_having(having_expr);
+					stmt.and_having(compare_having(agg_expr, operator, value));
+				}
+				HavingCondition::AnnotationCompare {
+					alias,
+					operator,
+					value,
+				} => {
+					let expr = self.annotation_having_expr(alias);
+					stmt.and_having(compare_having(expr, operator, value));
 				}
 			}
 		}
 
 		// Apply ORDER BY
-		for order_field in &self.order_by_fields {
-			let (field, is_desc) = if let Some(stripped) = order_field.strip_prefix('-') {
-				(stripped, true)
-			} else {
-				(order_field.as_str(), false)
-			};
-
-			let col_ref = parse_column_reference(field);
-			let expr = Expr::col(col_ref);
-			if is_desc {
-				stmt.order_by_expr(expr, Order::Desc);
-			} else {
-				stmt.order_by_expr(expr, Order::Asc);
-			}
+		for order_by in &self.order_by_fields {
+			let (expr, order) = order_by.to_query_order();
+			stmt.order_by_expr(expr, order);
 		}
 
 		// Apply LIMIT/OFFSET
@@ -4079,20 +4220,9 @@ where
 			}
 
 			// Apply ORDER BY clause
-			for order_field in &self.order_by_fields {
-				let (field, is_desc) = if let Some(stripped) = order_field.strip_prefix('-') {
-					(stripped, true)
-				} else {
-					(order_field.as_str(), false)
-				};
-
-				let col_ref = parse_column_reference(field);
-				let expr = Expr::col(col_ref);
-				if is_desc {
-					stmt.order_by_expr(expr, Order::Desc);
-				} else {
-					stmt.order_by_expr(expr, Order::Asc);
-				}
+			for order_by in &self.order_by_fields {
+				let (expr, order) = order_by.to_query_order();
+				stmt.order_by_expr(expr, order);
 			}
 
 			// Apply LIMIT/OFFSET
@@ -4146,6 +4276,11 @@ where
 					))
 				})
 			})
+			.inspect(|instance| {
+				if let Ok(instance) = instance {
+					super::tracking::mark_clean(instance);
+				}
+			})
 			.collect()
 	}
 
@@ -4337,20 +4472,9 @@ where
 			}
 
 			// Apply ORDER BY clause
-			for order_field in &self.order_by_fields {
-				let (field, is_desc) = if let Some(stripped) = order_field.strip_prefix('-') {
-					(stripped, true)
-				} else {
-					(order_field.as_str(), false)
-				};
-
-				let col_ref = parse_column_reference(field);
-				let expr = Expr::col(col_ref);
-				if is_desc {
-					stmt.order_by_expr(expr, Order::Desc);
-				} else {
-					stmt.order_by_expr(expr, Order::Asc);
-				}
+			for order_by in &self.order_by_fields {
+				let (expr, order) = order_by.to_query_order();
+				stmt.order_by_expr(expr, order);
 			}
 
 			// Apply LIMIT/OFFSET
@@ -4405,6 +4529,11 @@ where
 					))
 				})
 			})
+			.inspect(|instance| {
+				if let Ok(instance) = instance {
+					super::tracking::mark_clean(instance);
+				}
+			})
 			.collect()
 	}
 
@@ -4687,7 +4816,13 @@ where
 
 		// Add SET clauses
 		for (field, value) in updates {
-			stmt.value_expr(Alias::new(field), Self::update_value_to_query_expr(value));
+			stmt.value_expr(
+				Alias::new(field),
+				Self::update_value_to_query_expr(
+					value,
+					super::connection::DatabaseBackend::Postgres,
+				),
+			);
 		}
 
 		// Add WHERE conditions
@@ -4712,7 +4847,10 @@ where
 		A: Into<FieldAssignment>,
 	{
 		let assignments = Self::collect_field_assignments(values);
-		self.update_fields_query_from_assignments(&assignments)
+		self.update_fields_query_from_assignments(
+			&assignments,
+			super::connection::DatabaseBackend::Postgres,
+		)
 	}
 
 	/// Generate PostgreSQL UPDATE SQL for field assignments on this `QuerySet`.
@@ -4760,7 +4898,8 @@ where
 		I: IntoIterator<Item = A>,
 		A: Into<FieldAssignment>,
 	{
-		let stmt = self.update_fields_query(values)?;
+		let assignments = Self::collect_field_assignments(values);
+		let stmt = self.update_fields_query_from_assignments(&assignments, conn.backend())?;
 		let (sql, values) = Self::build_update_for_backend(&stmt, conn.backend());
 		let params = super::execution::convert_values(values);
 
@@ -4780,6 +4919,7 @@ where
 	fn update_fields_query_from_assignments(
 		&self,
 		assignments: &[FieldAssignment],
+		backend: super::connection::DatabaseBackend,
 	) -> reinhardt_core::exception::Result<UpdateStatement> {
 		Self::validate_update_fields(assignments)?;
 
@@ -4802,7 +4942,7 @@ where
 		for assignment in assignments {
 			stmt.value_expr(
 				Alias::new(assignment.field()),
-				Self::update_value_to_query_expr(assignment.value()),
+				Self::update_value_to_query_expr(assignment.value(), backend),
 			);
 		}
 
@@ -4843,7 +4983,10 @@ where
 		}
 	}
 
-	fn update_value_to_query_expr(value: &UpdateValue) -> Expr {
+	fn update_value_to_query_expr(
+		value: &UpdateValue,
+		backend: super::connection::DatabaseBackend,
+	) -> Expr {
 		match value {
 			UpdateValue::String(s) => Expr::val(s.clone()),
 			UpdateValue::Integer(i) => Expr::val(*i),
@@ -4857,7 +5000,7 @@ where
 				Expr::val(reinhardt_query::value::Value::Uuid(Some(Box::new(*uuid))))
 			}
 			UpdateValue::FieldRef(f) => Expr::col(Alias::new(&f.field)),
-			UpdateValue::Expression(expr) => Self::expression_to_query_expr(expr),
+			UpdateValue::Expression(expr) => Self::expression_to_query_expr(expr, backend),
 		}
 	}
 
@@ -5146,9 +5289,11 @@ where
 			reinhardt_core::exception::Error::Database(format!("Serialization error: {}", e))
 		})?;
 
-		serde_json::from_value(value).map_err(|e| {
+		let instance: T = serde_json::from_value(value).map_err(|e| {
 			reinhardt_core::exception::Error::Database(format!("Deserialization error: {}", e))
-		})
+		})?;
+		super::tracking::mark_clean(&instance);
+		Ok(instance)
 	}
 
 	/// Add an annotation to the QuerySet
@@ -5362,6 +5507,110 @@ where
 		self
 	}
 
+	/// Add an aggregate computed over a related model
+	///
+	/// Corresponds to Django's `Author.objects.annotate(Count("books"))`:
+	/// `R` is the related model whose `fk_field` points at this model's
+	/// primary key. The related table is LEFT JOINed (once, however many
+	/// aggregates use it), the aggregate's field is qualified with the related
+	/// table, and rows are grouped by this model's primary key so each
+	/// instance gets its own value. Instances without related rows are kept.
+	///
+	/// Combine with [`Aggregate::filter`](super::aggregation::Aggregate::filter)
+	/// for conditional counts and with [`QuerySet::having_annotation`] to
+	/// filter on the result.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use reinhardt_db::orm::{Model, Q};
+	/// # use reinhardt_db::orm::aggregation::Aggregate;
+	/// # use serde::{Serialize, Deserialize};
+	/// # #[derive(Clone, Serialize, Deserialize)]
+	/// # struct Author { id: Option<i64> }
+	/// # #[derive(Clone)]
+	/// # struct AuthorFields;
+	/// # impl reinhardt_db::orm::model::FieldSelector for AuthorFields {
+	/// #     fn with_alias(self, _alias: &str) -> Self { self }
+	/// # }
+	/// # impl Model for Author {
+	/// #     type PrimaryKey = i64;
+	/// #     type Fields = AuthorFields;
+	/// #     type Objects = reinhardt_db::orm::Manager<Self>;
+	/// #     fn table_name() -> &'static str { "authors" }
+	/// #     fn new_fields() -> Self::Fields { AuthorFields }
+	/// #     fn primary_key(&self) -> Option<Self::PrimaryKey> { self.id }
+	/// #     fn set_primary_key(&mut self, value: Self::PrimaryKey) { self.id = Some(value); }
+	/// # }
+	/// # #[derive(Clone, Serialize, Deserialize)]
+	/// # struct Book { id: Option<i64> }
+	/// # #[derive(Clone)]
+	/// # struct BookFields;
+	/// # impl reinhardt_db::orm::model::FieldSelector for BookFields {
+	/// #     fn with_alias(self, _alias: &str) -> Self { self }
+	/// # }
+	/// # impl Model for Book {
+	/// #     type PrimaryKey = i64;
+	/// #     type Fields = BookFields;
+	/// #     type Objects = reinhardt_db::orm::Manager<Self>;
+	/// #     fn table_name() -> &'static str { "books" }
+	/// #     fn new_fields() -> Self::Fields { BookFields }
+	/// #     fn primary_key(&self) -> Option<Self::PrimaryKey> { self.id }
+	/// #     fn set_primary_key(&mut self, value: Self::PrimaryKey) { self.id = Some(value); }
+	/// # }
+	/// let sql = Author::objects()
+	///     .all()
+	///     .aggregate_related::<Book>("author_id", Aggregate::count(Some("id")).with_alias("num_books"))
+	///     .aggregate_related::<Book>(
+	///         "author_id",
+	///         Aggregate::count(Some("id"))
+	///             .filter(Q::new("books.rating", ">=", "4"))
+	///             .with_alias("num_good_books"),
+	///     )
+	///     .to_sql();
+	/// assert_eq!(
+	///     sql,
+	///     r#"SELECT "authors".*, COUNT(books.id) AS "num_books", COUNT(books.id) FILTER (WHERE books.rating >= 4) AS "num_good_books" FROM "authors" LEFT JOIN "books" ON authors.id = books.author_id GROUP BY "authors"."id""#
+	/// );
+	/// ```
+	pub fn aggregate_related<R: super::Model>(
+		mut self,
+		fk_field: &str,
+		aggregate: super::aggregation::Aggregate,
+	) -> Self {
+		let owner = self
+			.from_alias
+			.clone()
+			.unwrap_or_else(|| T::table_name().to_string());
+		let related_table = R::table_name();
+
+		if !self
+			.joins
+			.iter()
+			.any(|join| join.target_table == related_table && join.target_alias.is_none())
+		{
+			self.joins.push(JoinClause {
+				join_type: super::sqlalchemy_query::JoinType::Left,
+				target_table: related_table.to_string(),
+				target_alias: None,
+				on_condition: format!(
+					"{}.{} = {}.{}",
+					owner,
+					T::primary_key_field(),
+					related_table,
+					fk_field
+				),
+			});
+		}
+
+		let group_key = format!("{}.{}", owner, T::primary_key_field());
+		if !self.group_by_fields.contains(&group_key) {
+			self.group_by_fields.push(group_key);
+		}
+
+		self.aggregate(aggregate.with_table(related_table))
+	}
+
 	/// Converts to sql.
 	pub fn to_sql(&self) -> String {
 		let mut stmt = if self.select_related_fields.is_empty() {
@@ -5401,6 +5650,10 @@ where
 						stmt.column(col_ref);
 					}
 				}
+			} else if self.has_related_aggregates() {
+				// Columns of joined related tables are aggregated, not selected
+				let owner = self.from_alias.as_deref().unwrap_or(T::table_name());
+				stmt.column(ColumnRef::table_asterisk(Alias::new(owner)));
 			} else {
 				stmt.column(ColumnRef::Asterisk);
 			}
@@ -5451,7 +5704,8 @@ where
 
 			// Apply GROUP BY
 			for group_field in &self.group_by_fields {
-				stmt.group_by_col(Alias::new(group_field));
+				let col_ref = parse_column_reference(group_field);
+				stmt.group_by_col(col_ref);
 			}
 
 			// Apply HAVING
@@ -5486,54 +5740,23 @@ where
 							}
 						};
 
-						// Build comparison expression
-						let having_expr = match operator {
-							ComparisonOp::Eq => match value {
-								AggregateValue::Int(v) => agg_expr.eq(*v),
-								AggregateValue::Float(v) => agg_expr.eq(*v),
-							},
-							ComparisonOp::Ne => match value {
-								AggregateValue::Int(v) => agg_expr.ne(*v),
-								AggregateValue::Float(v) => agg_expr.ne(*v),
-							},
-							ComparisonOp::Gt => match value {
-								AggregateValue::Int(v) => agg_expr.gt(*v),
-								AggregateValue::Float(v) => agg_expr.gt(*v),
-							},
-							ComparisonOp::Gte => match value {
-								AggregateValue::Int(v) => agg_expr.gte(*v),
-								AggregateValue::Float(v) => agg_expr.gte(*v),
-							},
-							ComparisonOp::Lt => match value {
-								AggregateValue::Int(v) => agg_expr.lt(*v),
-								AggregateValue::Float(v) => agg_expr.lt(*v),
-							},
-							ComparisonOp::Lte => match value {
-								AggregateValue::Int(v) => agg_expr.lte(*v),
-								AggregateValue::Float(v) => agg_expr.lte(*v),
-							},
-						};
-
-						stmt.and_having(having_expr);
+						stmt.and_having(compare_having(agg_expr, operator, value));
+					}
+					HavingCondition::AnnotationCompare {
+						alias,
+						operator,
+						value,
+					} => {
+						let expr = self.annotation_having_expr(alias);
+						stmt.and_having(compare_having(expr, operator, value));
 					}
 				}
 			}
 
 			// Apply ORDER BY
-			for order_field in &self.order_by_fields {
-				let (field, is_desc) = if let Some(stripped) = order_field.strip_prefix('-') {
-					(stripped, true)
-				} else {
-					(order_field.as_str(), false)
-				};
-
-				let col_ref = parse_column_reference(field);
-				let expr = Expr::col(col_ref);
-				if is_desc {
-					stmt.order_by_expr(expr, Order::Desc);
-				} else {
-					stmt.order_by_expr(expr, Order::Asc);
-				}
+			for order_by in &self.order_by_fields {
+				let (expr, order) = order_by.to_query_order();
+				stmt.order_by_expr(expr, order);
 			}
 
 			// Apply LIMIT/OFFSET
@@ -5731,25 +5954,24 @@ where
 	/// # }
 	/// ```
 	pub fn order_by(mut self, fields: &[&str]) -> Self {
-		self.order_by_fields = fields.iter().map(|s| s.to_string()).collect();
-		self
-	}
-
-	/// Return only distinct results
-	pub fn distinct(mut self) -> Self {
-		self.distinct_enabled = true;
+		self.order_by_fields = fields
+			.iter()
+			.map(|field| super::expressions::OrderBy::field(field))
+			.collect();
 		self
 	}
 
-	/// Set LIMIT clause
+	/// Order by expressions such as case-insensitive, collated or natural ordering
 	///
-	/// Limits the number of records returned by the query.
-	/// Corresponds to Django's QuerySet slicing `[:limit]`.
+	/// Replaces any previous ordering, like [`order_by`](Self::order_by).
 	///
 	/// # Examples
 	///
-	/// ```no_run
+	/// ```
 	/// # use reinhardt_db::orm::Model;
+	/// # use reinhardt_db::orm::annotation::AnnotationValue;
+	/// # use reinhardt_db::orm::expressions::{F, OrderBy};
+	/// # use reinhardt_db::orm::functions::Lower;
 	/// # use serde::{Serialize, Deserialize};
 	/// # #[derive(Clone, Serialize, Deserialize)]
 	/// # struct User { id: Option<i64> }
@@ -5767,7 +5989,59 @@ where
 	/// #     fn primary_key(&self) -> Option<Self::PrimaryKey> { self.id }
 	/// #     fn set_primary_key(&mut self, value: Self::PrimaryKey) { self.id = Some(value); }
 	/// # }
-	/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+	/// let sql = User::objects()
+	///     .all()
+	///     .order_by_expressions(vec![
+	///         Lower::new(AnnotationValue::Field(F::new("name"))).asc(),
+	///         OrderBy::collate("-title", "C"),
+	///         OrderBy::field("id"),
+	///     ])
+	///     .to_sql();
+	/// assert!(sql.ends_with(
+	///     r#"ORDER BY LOWER("name") ASC, "title" COLLATE "C" DESC, "id" ASC"#
+	/// ));
+	/// ```
+	pub fn order_by_expressions(
+		mut self,
+		ordering: impl IntoIterator<Item = super::expressions::OrderBy>,
+	) -> Self {
+		self.order_by_fields = ordering.into_iter().collect();
+		self
+	}
+
+	/// Return only distinct results
+	pub fn distinct(mut self) -> Self {
+		self.distinct_enabled = true;
+		self
+	}
+
+	/// Set LIMIT clause
+	///
+	/// Limits the number of records returned by the query.
+	/// Corresponds to Django's QuerySet slicing `[:limit]`.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use reinhardt_db::orm::Model;
+	/// # use serde::{Serialize, Deserialize};
+	/// # #[derive(Clone, Serialize, Deserialize)]
+	/// # struct User { id: Option<i64> }
+	/// # #[derive(Clone)]
+	/// # struct UserFields;
+	/// # impl reinhardt_db::orm::model::FieldSelector for UserFields {
+	/// #     fn with_alias(self, _alias: &str) -> Self { self }
+	/// # }
+	/// # impl Model for User {
+	/// #     type PrimaryKey = i64;
+	/// #     type Fields = UserFields;
+	/// #     type Objects = reinhardt_db::orm::Manager<Self>;
+	/// #     fn table_name() -> &'static str { "users" }
+	/// #     fn new_fields() -> Self::Fields { UserFields }
+	/// #     fn primary_key(&self) -> Option<Self::PrimaryKey> { self.id }
+	/// #     fn set_primary_key(&mut self, value: Self::PrimaryKey) { self.id = Some(value); }
+	/// # }
+	/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 	/// let users = User::objects()
 	///     .limit(10)
 	///     .all()
@@ -5860,6 +6134,94 @@ where
 		self.offset(offset).limit(page_size)
 	}
 
+	/// Slice the QuerySet with a Rust range
+	///
+	/// Translates the range into LIMIT/OFFSET without executing the query.
+	/// Corresponds to Django's QuerySet slicing `qs[10:20]`; any range form
+	/// is accepted (`10..20`, `..5`, `5..`, `5..=9`). Ordering is applied
+	/// before the slice, so `order_by` may be called before or after.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::Validation`](reinhardt_core::exception::Error::Validation)
+	/// if the QuerySet already has a LIMIT or OFFSET (from `limit`, `offset`,
+	/// `paginate` or a previous `slice`), or if the range start exceeds its end.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use reinhardt_db::orm::Model;
+	/// # use serde::{Serialize, Deserialize};
+	/// # #[derive(Clone, Serialize, Deserialize)]
+	/// # struct User { id: Option<i64> }
+	/// # #[derive(Clone)]
+	/// # struct UserFields;
+	/// # impl reinhardt_db::orm::model::FieldSelector for UserFields {
+	/// #     fn with_alias(self, _alias: &str) -> Self { self }
+	/// # }
+	/// # impl Model for User {
+	/// #     type PrimaryKey = i64;
+	/// #     type Fields = UserFields;
+	/// #     type Objects = reinhardt_db::orm::Manager<Self>;
+	/// #     fn table_name() -> &'static str { "users" }
+	/// #     fn new_fields() -> Self::Fields { UserFields }
+	/// #     fn primary_key(&self) -> Option<Self::PrimaryKey> { self.id }
+	/// #     fn set_primary_key(&mut self, value: Self::PrimaryKey) { self.id = Some(value); }
+	/// # }
+	/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+	/// // Rows 10 to 19 (OFFSET 10 LIMIT 10)
+	/// let users = User::objects()
+	///     .order_by(&["-created_at"])
+	///     .slice(10..20)?
+	///     .all()
+	///     .await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn slice<R>(mut self, range: R) -> reinhardt_core::exception::Result<Self>
+	where
+		R: std::ops::RangeBounds<usize>,
+	{
+		use std::ops::Bound;
+
+		if self.is_sliced() {
+			return Err(reinhardt_core::exception::Error::Validation(
+				"Cannot slice a QuerySet that already has a LIMIT or OFFSET".to_string(),
+			));
+		}
+
+		let overflow = || {
+			reinhardt_core::exception::Error::Validation("Slice bound overflows usize".to_string())
+		};
+		let start = match range.start_bound() {
+			Bound::Included(&start) => start,
+			Bound::Excluded(&start) => start.checked_add(1).ok_or_else(overflow)?,
+			Bound::Unbounded => 0,
+		};
+		let end = match range.end_bound() {
+			Bound::Included(&end) => Some(end.checked_add(1).ok_or_else(overflow)?),
+			Bound::Excluded(&end) => Some(end),
+			Bound::Unbounded => None,
+		};
+		if let Some(end) = end
+			&& end < start
+		{
+			return Err(reinhardt_core::exception::Error::Validation(format!(
+				"Slice start ({}) is greater than end ({})",
+				start, end
+			)));
+		}
+
+		self.offset = (start > 0).then_some(start);
+		self.limit = end.map(|end| end - start);
+		Ok(self)
+	}
+
+	/// Returns `true` if a LIMIT or OFFSET has been applied
+	pub fn is_sliced(&self) -> bool {
+		self.limit.is_some() || self.offset.is_some()
+	}
+
 	/// Convert QuerySet to a subquery
 	///
 	/// Returns the QuerySet as a SQL subquery wrapped in parentheses,
@@ -6373,7 +6735,7 @@ fn filter_lhs_sql(filter: &Filter) -> String {
 ///
 /// This function also detects raw SQL expressions (containing parentheses, like `COUNT(*)`,
 /// `AVG(price)`) and returns them wrapped in `Expr::cust()` instead of as column references.
-fn parse_column_reference(field: &str) -> reinhardt_query::prelude::ColumnRef {
+pub(crate) fn parse_column_reference(field: &str) -> reinhardt_query::prelude::ColumnRef {
 	use reinhardt_query::prelude::ColumnRef;
 
 	// Detect raw SQL expressions by checking for parentheses
@@ -6526,7 +6888,11 @@ mod tests {
 		FilterCondition, MAX_FILTER_CONDITION_DEPTH, build_select_statement,
 		render_select_statement,
 	};
+	use crate::orm::aggregation::Aggregate;
+	use crate::orm::annotation::{AnnotationValue, Expression, Value as AnnotationConst, When};
 	use crate::orm::connection::DatabaseBackend;
+	use crate::orm::expressions::{F, Q};
+	use crate::orm::functions::SqlType;
 	use crate::orm::query::{FieldAssignment, UpdateValue};
 	use crate::orm::{FilterOperator, FilterValue, Manager, Model, QuerySet, query::Filter};
 	use reinhardt_query::prelude::ExprTrait;
@@ -6760,6 +7126,59 @@ mod tests {
 		assert_eq!(params[1], "7");
 	}
 
+	#[rstest]
+	fn test_update_fields_sql_sets_column_from_case_expression() {
+		// Arrange
+		let status = Expression::case(
+			vec![When::new(
+				Q::new("email", "IS", "NULL"),
+				AnnotationValue::Value(AnnotationConst::String("pending".into())),
+			)],
+			Some(AnnotationValue::Value(AnnotationConst::String(
+				"verified".into(),
+			))),
+		);
+		let queryset = QuerySet::<TestUser>::new().filter(TestUser::field_id().gt(0));
+
+		// Act
+		let (sql, _) = queryset
+			.update_fields_sql([("status", status)])
+			.expect("update fields sql");
+
+		// Assert
+		assert_eq!(
+			sql,
+			"UPDATE \"test_users\" SET \"status\" = CASE WHEN email IS NULL THEN 'pending' ELSE 'verified' END WHERE \"id\" > $1"
+		);
+	}
+
+	#[rstest]
+	#[case::postgres(DatabaseBackend::Postgres, "CAST(COALESCE(\"score\", 0) AS INTEGER)")]
+	#[case::mysql(DatabaseBackend::MySql, "CAST(COALESCE(\"score\", 0) AS SIGNED)")]
+	#[case::sqlite(DatabaseBackend::Sqlite, "CAST(COALESCE(\"score\", 0) AS INTEGER)")]
+	fn test_update_with_typed_expression_renders_backend_cast(
+		#[case] backend: DatabaseBackend,
+		#[case] expected: &str,
+	) {
+		// Arrange
+		let score = Expression::coalesce(vec![
+			AnnotationValue::Field(F::new("score")),
+			AnnotationValue::Value(AnnotationConst::Int(0)),
+		])
+		.output_type(SqlType::Integer);
+		let queryset = QuerySet::<TestUser>::new().filter(TestUser::field_id().eq(1));
+		let assignments = [FieldAssignment::new("rank", score)];
+
+		// Act
+		let stmt = queryset
+			.update_fields_query_from_assignments(&assignments, backend)
+			.expect("update statement");
+		let (sql, _) = QuerySet::<TestUser>::build_update_for_backend(&stmt, backend);
+
+		// Assert
+		assert!(sql.contains(expected), "unexpected SQL: {sql}");
+	}
+
 	#[test]
 	fn test_update_fields_sql_rejects_empty_assignments() {
 		let queryset = QuerySet::<TestUser>::new().filter(TestUser::field_id().eq(7));
@@ -7965,6 +8384,199 @@ mod tests {
 		);
 	}
 
+	#[rstest]
+	#[case::bounded(10..20, " LIMIT 10 OFFSET 10")]
+	#[case::inclusive(5..=9, " LIMIT 5 OFFSET 5")]
+	#[case::from_zero(0..3, " LIMIT 3")]
+	#[case::empty(4..4, " LIMIT 0 OFFSET 4")]
+	fn test_slice_translates_range_to_limit_offset(
+		#[case] range: std::ops::Range<usize>,
+		#[case] expected_suffix: &str,
+	) {
+		// Arrange
+		let queryset = QuerySet::<TestUser>::new();
+
+		// Act
+		let sql = queryset.slice(range).unwrap().to_sql();
+
+		// Assert
+		assert!(sql.ends_with(expected_suffix), "unexpected SQL: {sql}");
+	}
+
+	#[rstest]
+	fn test_slice_accepts_open_ranges() {
+		// Arrange
+		let queryset = QuerySet::<TestUser>::new();
+
+		// Act
+		let head = queryset.clone().slice(..5).unwrap();
+		let tail = queryset.slice(5..).unwrap();
+
+		// Assert
+		assert_eq!((head.limit, head.offset), (Some(5), None));
+		assert_eq!((tail.limit, tail.offset), (None, Some(5)));
+	}
+
+	#[rstest]
+	fn test_slice_composes_with_ordering() {
+		// Arrange
+		let queryset = QuerySet::<TestUser>::new().order_by(&["-created_at"]);
+
+		// Act
+		let sql = queryset.slice(20..30).unwrap().to_sql();
+
+		// Assert
+		assert_eq!(
+			sql,
+			r#"SELECT * FROM "test_users" ORDER BY "created_at" DESC LIMIT 10 OFFSET 20"#
+		);
+	}
+
+	#[rstest]
+	#[case::sliced(QuerySet::<TestUser>::new().slice(0..10).unwrap())]
+	#[case::limited(QuerySet::<TestUser>::new().limit(10))]
+	#[case::paginated(QuerySet::<TestUser>::new().paginate(2, 10))]
+	fn test_slice_rejects_already_sliced_queryset(#[case] queryset: QuerySet<TestUser>) {
+		// Act
+		let result = queryset.slice(1..2);
+
+		// Assert
+		assert!(matches!(
+			result,
+			Err(reinhardt_core::exception::Error::Validation(msg)) if msg.contains("already has a LIMIT")
+		));
+	}
+
+	#[rstest]
+	fn test_slice_rejects_reversed_range() {
+		// Arrange
+		let queryset = QuerySet::<TestUser>::new();
+
+		// Act
+		#[allow(
+			clippy::reversed_empty_ranges,
+			reason = "testing reversed range rejection"
+		)]
+		let result = queryset.slice(10..5);
+
+		// Assert
+		assert!(matches!(
+			result,
+			Err(reinhardt_core::exception::Error::Validation(msg)) if msg.contains("greater than end")
+		));
+	}
+
+	#[derive(Debug, Clone, Serialize, Deserialize)]
+	struct TestPost {
+		id: Option<i64>,
+	}
+
+	impl Model for TestPost {
+		type PrimaryKey = i64;
+		type Fields = TestUserFields;
+		type Objects = Manager<Self>;
+
+		fn table_name() -> &'static str {
+			"test_posts"
+		}
+
+		fn primary_key(&self) -> Option<Self::PrimaryKey> {
+			self.id
+		}
+
+		fn set_primary_key(&mut self, value: Self::PrimaryKey) {
+			self.id = Some(value);
+		}
+
+		fn new_fields() -> Self::Fields {
+			TestUserFields
+		}
+	}
+
+	#[rstest]
+	fn test_aggregate_related_joins_and_groups_by_primary_key() {
+		// Arrange
+		let queryset = QuerySet::<TestUser>::new().aggregate_related::<TestPost>(
+			"author_id",
+			Aggregate::count(Some("id")).with_alias("num_posts"),
+		);
+
+		// Act
+		let sql = queryset.to_sql();
+
+		// Assert
+		assert_eq!(
+			sql,
+			r#"SELECT "test_users".*, COUNT(test_posts.id) AS "num_posts" FROM "test_users" LEFT JOIN "test_posts" ON test_users.id = test_posts.author_id GROUP BY "test_users"."id""#
+		);
+	}
+
+	#[rstest]
+	fn test_aggregate_related_reuses_join_for_multiple_aggregates() {
+		// Arrange
+		let queryset = QuerySet::<TestUser>::new()
+			.aggregate_related::<TestPost>(
+				"author_id",
+				Aggregate::count(Some("id")).with_alias("num_posts"),
+			)
+			.aggregate_related::<TestPost>(
+				"author_id",
+				Aggregate::count(Some("id"))
+					.filter(Q::new("test_posts.published", "=", "true"))
+					.with_alias("num_published"),
+			);
+
+		// Act
+		let sql = queryset.to_sql();
+
+		// Assert
+		assert_eq!(sql.matches("LEFT JOIN").count(), 1);
+		assert_eq!(sql.matches("GROUP BY").count(), 1);
+		assert!(sql.contains(
+			r#"COUNT(test_posts.id) FILTER (WHERE test_posts.published = true) AS "num_published""#
+		));
+	}
+
+	#[rstest]
+	fn test_having_annotation_repeats_annotation_expression() {
+		// Arrange
+		let queryset = QuerySet::<TestUser>::new()
+			.aggregate_related::<TestPost>(
+				"author_id",
+				Aggregate::count(Some("id"))
+					.filter(Q::new("test_posts.published", "=", "true"))
+					.with_alias("num_published"),
+			)
+			.having_annotation("num_published", |value| value.gte(2));
+
+		// Act
+		let sql = queryset.to_sql();
+
+		// Assert
+		assert!(
+			sql.ends_with(
+				r#"GROUP BY "test_users"."id" HAVING COUNT(test_posts.id) FILTER (WHERE test_posts.published = true) >= 2"#
+			),
+			"unexpected SQL: {sql}"
+		);
+	}
+
+	#[rstest]
+	fn test_having_annotation_falls_back_to_alias() {
+		// Arrange
+		let queryset =
+			QuerySet::<TestUser>::new().having_annotation("score", |value| value.gt(1.5));
+
+		// Act
+		let sql = queryset.to_sql();
+
+		// Assert
+		assert!(
+			sql.ends_with(r#"HAVING "score" > 1.5"#),
+			"unexpected SQL: {sql}"
+		);
+	}
+
 	#[rstest]
 	fn test_range_contains_filter_quotes_field() {
 		// Arrange
@@ -8021,4 +8633,236 @@ mod tests {
 			r#"SELECT * FROM "test_users" WHERE "age_range" && '[20, 30]'"#
 		);
 	}
+
+	#[rstest]
+	fn test_geo_within_filter_binds_ewkt() {
+		// Arrange
+		let area = crate::orm::gis::Polygon {
+			exterior: vec![
+				crate::orm::gis::Point::new(0.0, 0.0),
+				crate::orm::gis::Point::new(1.0, 0.0),
+				crate::orm::gis::Point::new(1.0, 1.0),
+				crate::orm::gis::Point::new(0.0, 0.0),
+			],
+			interiors: vec![],
+			srid: 4326,
+		};
+		let location =
+			crate::orm::expressions::FieldRef::<TestUser, crate::orm::gis::Point>::new("location");
+		let queryset = QuerySet::<TestUser>::new().filter(location.within(&area));
+
+		// Act
+		let sql = queryset.to_sql();
+
+		// Assert
+		assert_eq!(
+			sql,
+			r#"SELECT * FROM "test_users" WHERE ST_Within("location", ST_GeomFromEWKT('SRID=4326;POLYGON((0 0, 1 0, 1 1, 0 0))'))"#
+		);
+	}
+
+	#[rstest]
+	fn test_geo_distance_lte_filter_uses_geography_meters() {
+		// Arrange
+		let location =
+			crate::orm::expressions::FieldRef::<TestUser, crate::orm::gis::Point>::new("location");
+		let queryset = QuerySet::<TestUser>::new().filter(location.distance_lte(
+			&crate::orm::gis::Point::new(139.7, 35.68),
+			crate::orm::gis::Distance::km(2.5),
+		));
+
+		// Act
+		let sql = queryset.to_sql();
+
+		// Assert
+		assert!(sql.contains(
+			r#"ST_DWithin("location"::geography, ST_GeomFromEWKT('SRID=4326;POINT(139.7 35.68)')::geography, 2500"#
+		));
+	}
+
+	#[rstest]
+	fn test_geo_bbcontains_filter() {
+		// Arrange
+		let location =
+			crate::orm::expressions::FieldRef::<TestUser, crate::orm::gis::Point>::new("location");
+		let queryset = QuerySet::<TestUser>::new()
+			.filter(location.bbcontains(&crate::orm::gis::Point::new(1.0, 2.0)));
+
+		// Act
+		let sql = queryset.to_sql();
+
+		// Assert
+		assert_eq!(
+			sql,
+			r#"SELECT * FROM "test_users" WHERE "location" ~ ST_GeomFromEWKT('SRID=4326;POINT(1 2)')"#
+		);
+	}
+
+	#[rstest]
+	fn test_order_by_expressions_mixes_lower_collate_and_fields() {
+		// Arrange
+		let queryset = QuerySet::<TestUser>::new().order_by_expressions(vec![
+			crate::orm::functions::Lower::new(crate::orm::annotation::AnnotationValue::Field(
+				crate::orm::expressions::F::new("username"),
+			))
+			.desc(),
+			crate::orm::expressions::OrderBy::collate("email", "C"),
+			crate::orm::expressions::OrderBy::field("-id"),
+		]);
+
+		// Act
+		let sql = queryset.to_sql();
+
+		// Assert
+		assert_eq!(
+			sql,
+			r#"SELECT * FROM "test_users" ORDER BY LOWER("username") DESC, "email" COLLATE "C" ASC, "id" DESC"#
+		);
+	}
+
+	#[rstest]
+	fn test_order_by_natural_uses_natural_collation() {
+		// Arrange
+		let queryset = QuerySet::<TestUser>::new()
+			.order_by_expressions([crate::orm::expressions::OrderBy::natural("-username")]);
+
+		// Act
+		let sql = queryset.to_sql();
+
+		// Assert
+		assert_eq!(
+			sql,
+			r#"SELECT * FROM "test_users" ORDER BY "username" COLLATE "natural" DESC"#
+		);
+	}
+
+	#[derive(Debug, Clone, Serialize, Deserialize)]
+	struct TenantInvoice {
+		id: Option<i64>,
+		tenant_id: Option<i64>,
+		number: String,
+	}
+
+	impl Model for TenantInvoice {
+		type PrimaryKey = i64;
+		type Fields = TestUserFields;
+		type Objects = Manager<Self>;
+
+		fn table_name() -> &'static str {
+			"invoices"
+		}
+
+		fn new_fields() -> Self::Fields {
+			TestUserFields
+		}
+
+		fn primary_key(&self) -> Option<Self::PrimaryKey> {
+			self.id
+		}
+
+		fn set_primary_key(&mut self, value: Self::PrimaryKey) {
+			self.id = Some(value);
+		}
+
+		fn tenant_field() -> Option<&'static str> {
+			Some("tenant_id")
+		}
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_tenant_bound_queryset_filters_on_current_tenant() {
+		// Arrange
+		let queryset = QuerySet::<TenantInvoice>::new().filter(Filter::new(
+			"number".to_string(),
+			FilterOperator::Eq,
+			FilterValue::String("INV-1".to_string()),
+		));
+
+		// Act
+		let sql = crate::orm::tenancy::with_tenant(7, async { queryset.to_sql() }).await;
+
+		// Assert
+		assert_eq!(
+			sql,
+			r#"SELECT * FROM "invoices" WHERE ("number" = 'INV-1' AND "tenant_id" = 7)"#
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_tenant_condition_is_not_absorbed_by_or_filters() {
+		// Arrange
+		let condition = Filter::new(
+			"number".to_string(),
+			FilterOperator::Eq,
+			FilterValue::String("INV-1".to_string()),
+		)
+		.or(Filter::new(
+			"number".to_string(),
+			FilterOperator::Eq,
+			FilterValue::String("INV-2".to_string()),
+		));
+		let queryset = QuerySet::<TenantInvoice>::new().filter(condition);
+
+		// Act
+		let sql = crate::orm::tenancy::with_tenant(7, async { queryset.to_sql() }).await;
+
+		// Assert
+		assert_eq!(
+			sql,
+			r#"SELECT * FROM "invoices" WHERE (("number" = 'INV-1' OR "number" = 'INV-2') AND "tenant_id" = 7)"#
+		);
+	}
+
+	#[rstest]
+	fn test_tenant_bound_queryset_without_tenant_matches_nothing() {
+		// Arrange
+		let queryset = QuerySet::<TenantInvoice>::new();
+
+		// Act
+		let sql = queryset.to_sql();
+
+		// Assert
+		assert_eq!(sql, r#"SELECT * FROM "invoices" WHERE FALSE"#);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_unscoped_queryset_skips_tenant_filter() {
+		// Arrange
+		let queryset = QuerySet::<TenantInvoice>::new();
+
+		// Act
+		let sql = crate::orm::tenancy::with_tenant(
+			7,
+			crate::orm::tenancy::unscoped(async { queryset.to_sql() }),
+		)
+		.await;
+
+		// Assert
+		assert_eq!(sql, r#"SELECT * FROM "invoices""#);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_tenant_bound_delete_is_restricted_to_current_tenant() {
+		// Arrange
+		let queryset = QuerySet::<TenantInvoice>::new().filter(Filter::new(
+			"id".to_string(),
+			FilterOperator::Eq,
+			FilterValue::Integer(1),
+		));
+
+		// Act
+		let (sql, params) =
+			crate::orm::tenancy::with_tenant(7, async { queryset.delete_sql() }).await;
+
+		// Assert
+		assert_eq!(
+			sql,
+			r#"DELETE FROM "invoices" WHERE ("id" = $1 AND "tenant_id" = $2)"#
+		);
+		assert_eq!(params, vec!["1", "7"]);
+	}
 }
@@ -31,11 +31,13 @@
 pub mod aggregate;
 pub mod comparison;
 pub mod compiler;
+pub mod custom;
 mod field;
 mod lookup;
 mod traits;
 
 pub use compiler::QueryFieldCompiler;
+pub use custom::{CustomLookup, CustomTransform, register_lookup, register_transform};
 pub use field::Field;
 pub use lookup::{Lookup, LookupType, LookupValue};
 pub use traits::{Comparable, Date, DateTime, DateTimeType, NumericType, StringType};
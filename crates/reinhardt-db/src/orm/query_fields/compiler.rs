@@ -5,9 +5,18 @@
 
 use super::aggregate::{AggregateFunction, ComparisonExpr, ComparisonValue};
 use super::comparison::{ComparisonOperator, FieldComparison, FieldRef};
+use super::custom::{CustomTransform, get_lookup, get_transform};
 use super::lookup::{Lookup, LookupType, LookupValue};
 use crate::orm::Model;
+use crate::orm::connection::DatabaseBackend;
 use reinhardt_query::prelude::SimpleExpr;
+use std::sync::Arc;
+
+/// A transform segment resolved while compiling a field path
+enum Transform {
+	Builtin(&'static str),
+	Custom(Arc<dyn CustomTransform>),
+}
 
 /// Compiles field lookups into SQL
 pub struct QueryFieldCompiler;
@@ -31,28 +40,65 @@ impl QueryFieldCompiler {
 	/// Compile for SQLite (uses LIKE with LOWER() for case-insensitive)
 	///
 	pub fn compile_for_sqlite<M: Model>(lookup: &Lookup<M>) -> String {
+		Self::compile_for_backend(lookup, DatabaseBackend::Sqlite)
+	}
+
+	/// Compile for a specific backend
+	///
+	/// PostgreSQL uses `ILIKE` and `~`/`~*` natively; other backends fall back
+	/// to `LOWER()` wrapping. Custom lookups and transforms receive `backend`
+	/// so they can emit backend-specific SQL.
+	///
+	/// # Panics
+	///
+	/// Panics when the lookup or a transform in its path names a custom
+	/// lookup that was never registered.
+	pub fn compile_for_backend<M: Model>(lookup: &Lookup<M>, backend: DatabaseBackend) -> String {
 		let lookup_type = lookup.lookup_type();
+		let field_path = lookup.field_path();
+
+		if let LookupType::Custom(name) = lookup_type {
+			let custom =
+				get_lookup(name).unwrap_or_else(|| panic!("no lookup registered as `{}`", name));
+			return custom.as_sql(
+				backend,
+				&Self::compile_field_path(field_path, backend),
+				&Self::value_to_sql(lookup.value()),
+			);
+		}
+
+		let native_ilike = backend == DatabaseBackend::Postgres;
 
 		// For case-insensitive lookups, wrap field in LOWER()
 		let field_sql = match lookup_type {
 			LookupType::IExact
 			| LookupType::IContains
 			| LookupType::IStartsWith
-			| LookupType::IEndsWith => {
-				let field_path = lookup.field_path();
+			| LookupType::IEndsWith
+				if !native_ilike =>
+			{
 				format!("LOWER({})", Self::compile_field_path_raw(field_path))
 			}
-			_ => Self::compile_field_path(lookup.field_path()),
+			_ => Self::compile_field_path(field_path, backend),
 		};
 
-		let operator = Self::lookup_type_to_operator_sqlite(lookup_type);
-		let value_sql = Self::compile_value_sqlite(lookup.value(), lookup_type);
+		let (operator, value_sql) = if native_ilike {
+			(
+				Self::lookup_type_to_operator(lookup_type),
+				Self::compile_value(lookup.value(), lookup_type),
+			)
+		} else {
+			(
+				Self::lookup_type_to_operator_sqlite(lookup_type),
+				Self::compile_value_sqlite(lookup.value(), lookup_type),
+			)
+		};
 
 		match lookup_type {
 			LookupType::IsNull | LookupType::IsNotNull => {
 				format!(
 					"{} {}",
-					Self::compile_field_path(lookup.field_path()),
+					Self::compile_field_path(field_path, backend),
 					operator
 				)
 			}
@@ -62,7 +108,7 @@ impl QueryFieldCompiler {
 					let end_sql = Self::value_to_sql(end);
 					format!(
 						"{} BETWEEN {} AND {}",
-						Self::compile_field_path(lookup.field_path()),
+						Self::compile_field_path(field_path, backend),
 						start_sql,
 						end_sql
 					)
@@ -73,7 +119,7 @@ impl QueryFieldCompiler {
 			LookupType::In | LookupType::NotIn => {
 				format!(
 					"{} {} ({})",
-					Self::compile_field_path(lookup.field_path()),
+					Self::compile_field_path(field_path, backend),
 					operator,
 					value_sql
 				)
@@ -95,70 +141,70 @@ impl QueryFieldCompiler {
 
 	/// Check if a path segment is a transform
 	fn is_transform(segment: &str) -> bool {
-		matches!(
-			segment,
-			"lower"
-				| "upper" | "trim"
-				| "length" | "year"
-				| "month" | "day"
-				| "week" | "weekday"
-				| "quarter" | "hour"
-				| "minute" | "second"
-				| "date" | "abs"
-				| "ceil" | "floor"
-				| "round"
-		)
+		Self::builtin_transform(segment).is_some() || get_transform(segment).is_some()
+	}
+
+	/// SQL function for a built-in transform segment
+	fn builtin_transform(segment: &str) -> Option<&'static str> {
+		let function = match segment {
+			// String transforms
+			"lower" => "LOWER",
+			"upper" => "UPPER",
+			"trim" => "TRIM",
+			"length" => "LENGTH",
+
+			// DateTime transforms
+			"year" => "EXTRACT(YEAR FROM",
+			"month" => "EXTRACT(MONTH FROM",
+			"day" => "EXTRACT(DAY FROM",
+			"week" => "EXTRACT(WEEK FROM",
+			"weekday" => "EXTRACT(DOW FROM",
+			"quarter" => "EXTRACT(QUARTER FROM",
+			"hour" => "EXTRACT(HOUR FROM",
+			"minute" => "EXTRACT(MINUTE FROM",
+			"second" => "EXTRACT(SECOND FROM",
+			"date" => "DATE",
+
+			// Numeric transforms
+			"abs" => "ABS",
+			"ceil" => "CEIL",
+			"floor" => "FLOOR",
+			"round" => "ROUND",
+
+			_ => return None,
+		};
+		Some(function)
 	}
 
 	/// Compile field path into SQL
-	fn compile_field_path(path: &[String]) -> String {
+	fn compile_field_path(path: &[String], backend: DatabaseBackend) -> String {
 		let mut transforms = Vec::new();
 		let mut field_name = String::new();
 
 		for segment in path {
-			match segment.as_str() {
-				// String transforms
-				"lower" => transforms.push("LOWER"),
-				"upper" => transforms.push("UPPER"),
-				"trim" => transforms.push("TRIM"),
-				"length" => transforms.push("LENGTH"),
-
-				// DateTime transforms
-				"year" => transforms.push("EXTRACT(YEAR FROM"),
-				"month" => transforms.push("EXTRACT(MONTH FROM"),
-				"day" => transforms.push("EXTRACT(DAY FROM"),
-				"week" => transforms.push("EXTRACT(WEEK FROM"),
-				"weekday" => transforms.push("EXTRACT(DOW FROM"),
-				"quarter" => transforms.push("EXTRACT(QUARTER FROM"),
-				"hour" => transforms.push("EXTRACT(HOUR FROM"),
-				"minute" => transforms.push("EXTRACT(MINUTE FROM"),
-				"second" => transforms.push("EXTRACT(SECOND FROM"),
-				"date" => transforms.push("DATE"),
-
-				// Numeric transforms
-				"abs" => transforms.push("ABS"),
-				"ceil" => transforms.push("CEIL"),
-				"floor" => transforms.push("FLOOR"),
-				"round" => transforms.push("ROUND"),
-
+			if let Some(function) = Self::builtin_transform(segment) {
+				transforms.push(Transform::Builtin(function));
+			} else if let Some(custom) = get_transform(segment) {
+				transforms.push(Transform::Custom(custom));
+			} else {
 				// Otherwise, it's a field name or relation
-				_ => {
-					if !field_name.is_empty() {
-						field_name.push('.');
-					}
-					field_name.push_str(segment);
+				if !field_name.is_empty() {
+					field_name.push('.');
 				}
+				field_name.push_str(segment);
 			}
 		}
 
 		// Apply transforms from innermost to outermost
 		let mut result = field_name;
 		for transform in &transforms {
-			if transform.starts_with("EXTRACT") {
-				result = format!("{} {})", transform, result);
-			} else {
-				result = format!("{}({})", transform, result);
-			}
+			result = match transform {
+				Transform::Builtin(function) if function.starts_with("EXTRACT") => {
+					format!("{} {})", function, result)
+				}
+				Transform::Builtin(function) => format!("{}({})", function, result),
+				Transform::Custom(custom) => custom.as_sql(backend, &result),
+			};
 		}
 
 		result
@@ -183,12 +229,12 @@ impl QueryFieldCompiler {
 			LookupType::NotIn => "NOT IN",
 			LookupType::IsNull => "IS NULL",
 			LookupType::IsNotNull => "IS NOT NULL",
+			// Rendered by the registered lookup
+			LookupType::Custom(_) => "",
 		}
 	}
 
 	/// Convert lookup type to SQL operator (PostgreSQL)
-	// Allow dead_code: PostgreSQL-specific operator mapping used when PostgreSQL backend is active
-	#[allow(dead_code)]
 	fn lookup_type_to_operator(lookup_type: &LookupType) -> &'static str {
 		match lookup_type {
 			LookupType::Exact => "=",
@@ -207,6 +253,8 @@ impl QueryFieldCompiler {
 			LookupType::NotIn => "NOT IN",
 			LookupType::IsNull => "IS NULL",
 			LookupType::IsNotNull => "IS NOT NULL",
+			// Rendered by the registered lookup
+			LookupType::Custom(_) => "",
 		}
 	}
 
@@ -279,8 +327,6 @@ impl QueryFieldCompiler {
 	}
 
 	/// Compile lookup value to SQL
-	// Allow dead_code: lookup value compilation used by backend-specific query field compilers
-	#[allow(dead_code)]
 	fn compile_value(value: &LookupValue, lookup_type: &LookupType) -> String {
 		match lookup_type {
 			LookupType::Contains => {
@@ -465,6 +511,7 @@ impl QueryFieldCompiler {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::orm::query_fields::custom::{CustomLookup, register_lookup, register_transform};
 	use crate::orm::query_fields::field::Field;
 	use crate::orm::{Manager, Model};
 	use reinhardt_core::validators::TableName;
@@ -561,4 +608,64 @@ mod tests {
 		assert_eq!(sql, "email = 'test''; DROP TABLE users; --'");
 		assert!(sql.contains("''")); // Single quote is escaped
 	}
+
+	struct TrigramSimilar;
+
+	impl CustomLookup for TrigramSimilar {
+		fn name(&self) -> &'static str {
+			"test_trigram_similar"
+		}
+
+		fn as_sql(&self, backend: DatabaseBackend, lhs: &str, rhs: &str) -> String {
+			match backend {
+				DatabaseBackend::Postgres => format!("{} % {}", lhs, rhs),
+				_ => format!("{} LIKE {}", lhs, rhs),
+			}
+		}
+	}
+
+	struct Unaccent;
+
+	impl CustomTransform for Unaccent {
+		fn name(&self) -> &'static str {
+			"test_unaccent"
+		}
+
+		fn as_sql(&self, _backend: DatabaseBackend, expr: &str) -> String {
+			format!("unaccent({})", expr)
+		}
+	}
+
+	#[test]
+	fn test_compile_custom_lookup_per_backend() {
+		register_lookup(Arc::new(TrigramSimilar));
+		let lookup =
+			Field::<TestUser, String>::new(vec!["email"]).lookup("test_trigram_similar", "o'neil");
+		assert_eq!(
+			QueryFieldCompiler::compile_for_backend(&lookup, DatabaseBackend::Postgres),
+			"email % 'o''neil'"
+		);
+		assert_eq!(
+			QueryFieldCompiler::compile_for_backend(&lookup, DatabaseBackend::Sqlite),
+			"email LIKE 'o''neil'"
+		);
+	}
+
+	#[test]
+	fn test_compile_custom_transform_with_builtin_lookup() {
+		register_transform(Arc::new(Unaccent));
+		let lookup = Field::<TestUser, String>::new(vec!["email"])
+			.transform("test_unaccent")
+			.lower()
+			.eq("jose".to_string());
+		let sql = QueryFieldCompiler::compile(&lookup);
+		assert_eq!(sql, "LOWER(unaccent(email)) = 'jose'");
+	}
+
+	#[test]
+	fn test_compile_icontains_uses_ilike_on_postgres() {
+		let lookup = Field::<TestUser, String>::new(vec!["email"]).icontains("Example");
+		let sql = QueryFieldCompiler::compile_for_backend(&lookup, DatabaseBackend::Postgres);
+		assert_eq!(sql, "email ILIKE '%Example%'");
+	}
 }
@@ -0,0 +1,105 @@
+//! Custom lookups and transforms
+//!
+//! Third-party crates can extend the query builder with their own operators
+//! without touching reinhardt-db. A [`CustomLookup`] renders a complete
+//! condition (e.g. `name % 'term'` for `__trigram_similar`), while a
+//! [`CustomTransform`] wraps the field expression before a lookup is applied
+//! (e.g. `unaccent(name)` for `__unaccent`). Both receive the target
+//! [`DatabaseBackend`] so a single registration can emit backend-specific SQL.
+//!
+//! Registered names are resolved by [`Field::lookup`] and [`Field::transform`]
+//! and compiled by [`QueryFieldCompiler`]. Built-in transform names (`lower`,
+//! `year`, ...) always take precedence over registered ones.
+//!
+//! # Examples
+//!
+//! ```
+//! use reinhardt_db::orm::connection::DatabaseBackend;
+//! use reinhardt_db::orm::query_fields::custom::{
+//!     CustomLookup, CustomTransform, register_lookup, register_transform,
+//! };
+//! use std::sync::Arc;
+//!
+//! struct TrigramSimilar;
+//!
+//! impl CustomLookup for TrigramSimilar {
+//!     fn name(&self) -> &'static str {
+//!         "trigram_similar"
+//!     }
+//!
+//!     fn as_sql(&self, _backend: DatabaseBackend, lhs: &str, rhs: &str) -> String {
+//!         format!("{} % {}", lhs, rhs)
+//!     }
+//! }
+//!
+//! struct Unaccent;
+//!
+//! impl CustomTransform for Unaccent {
+//!     fn name(&self) -> &'static str {
+//!         "unaccent"
+//!     }
+//!
+//!     fn as_sql(&self, _backend: DatabaseBackend, expr: &str) -> String {
+//!         format!("unaccent({})", expr)
+//!     }
+//! }
+//!
+//! register_lookup(Arc::new(TrigramSimilar));
+//! register_transform(Arc::new(Unaccent));
+//! ```
+//!
+//! [`Field::lookup`]: super::Field::lookup
+//! [`Field::transform`]: super::Field::transform
+//! [`QueryFieldCompiler`]: super::QueryFieldCompiler
+
+use crate::orm::connection::DatabaseBackend;
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A lookup operator contributed from outside reinhardt-db
+pub trait CustomLookup: Send + Sync {
+	/// Name used in the lookup path, e.g. `trigram_similar`
+	fn name(&self) -> &'static str;
+
+	/// Render the condition for `backend`
+	///
+	/// `lhs` is the compiled field expression (transforms already applied)
+	/// and `rhs` the escaped SQL literal of the lookup value.
+	fn as_sql(&self, backend: DatabaseBackend, lhs: &str, rhs: &str) -> String;
+}
+
+/// A field transform contributed from outside reinhardt-db
+pub trait CustomTransform: Send + Sync {
+	/// Name used in the field path, e.g. `unaccent`
+	fn name(&self) -> &'static str;
+
+	/// Wrap the compiled expression `expr` for `backend`
+	fn as_sql(&self, backend: DatabaseBackend, expr: &str) -> String;
+}
+
+static LOOKUPS: Lazy<RwLock<HashMap<&'static str, Arc<dyn CustomLookup>>>> =
+	Lazy::new(|| RwLock::new(HashMap::new()));
+static TRANSFORMS: Lazy<RwLock<HashMap<&'static str, Arc<dyn CustomTransform>>>> =
+	Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Register `lookup`, replacing any lookup with the same name
+pub fn register_lookup(lookup: Arc<dyn CustomLookup>) {
+	LOOKUPS.write().insert(lookup.name(), lookup);
+}
+
+/// Register `transform`, replacing any transform with the same name
+pub fn register_transform(transform: Arc<dyn CustomTransform>) {
+	TRANSFORMS.write().insert(transform.name(), transform);
+}
+
+/// The registered lookup called `name`, if any
+pub fn get_lookup(name: &str) -> Option<Arc<dyn CustomLookup>> {
+	LOOKUPS.read().get(name).cloned()
+}
+
+/// The registered transform called `name`, if any
+pub fn get_transform(name: &str) -> Option<Arc<dyn CustomTransform>> {
+	TRANSFORMS.read().get(name).cloned()
+}
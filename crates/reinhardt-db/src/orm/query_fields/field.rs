@@ -1,7 +1,7 @@
 //! Type-safe field representation with transformation support
 
 use super::comparison::{ComparisonOperator, FieldComparison, FieldRef};
-use super::lookup::{Lookup, LookupType};
+use super::lookup::{Lookup, LookupType, LookupValue};
 use super::traits::{Comparable, Date, DateTime, NumericType};
 use crate::orm::Model;
 use std::marker::PhantomData;
@@ -91,6 +91,39 @@ impl<M: Model, T> Field<M, T> {
 		self
 	}
 
+	/// Apply a transform registered with
+	/// [`register_transform`](super::custom::register_transform)
+	///
+	/// # Examples
+	///
+	/// ```ignore
+	/// // unaccent(name) = 'Jose'
+	/// let lookup = User::name().transform("unaccent").eq("Jose".to_string());
+	/// ```
+	pub fn transform(mut self, name: &str) -> Self {
+		self.path.push(name.to_string());
+		self
+	}
+
+	/// Apply a lookup registered with
+	/// [`register_lookup`](super::custom::register_lookup)
+	///
+	/// Compiling the lookup panics when no lookup named `name` is registered.
+	///
+	/// # Examples
+	///
+	/// ```ignore
+	/// // name % 'jon'
+	/// let lookup = User::name().lookup("trigram_similar", "jon");
+	/// ```
+	pub fn lookup(self, name: &str, value: impl Into<LookupValue>) -> Lookup<M> {
+		Lookup::new(
+			self.path,
+			LookupType::Custom(name.to_string()),
+			value.into(),
+		)
+	}
+
 	/// Convert Field to FieldRef for comparison operations
 	pub(crate) fn to_field_ref(&self) -> FieldRef {
 		FieldRef::Field {
@@ -56,6 +56,10 @@ pub enum LookupType {
 	IsNull, // IS NULL
 	/// IsNotNull variant.
 	IsNotNull, // IS NOT NULL
+
+	// Extension
+	/// Lookup registered through [`register_lookup`](super::custom::register_lookup).
+	Custom(String),
 }
 
 /// Lookup value - the value to compare against
@@ -0,0 +1,352 @@
+//! Redirects from old URLs to new ones
+//!
+//! Equivalent of Django's `django.contrib.redirects` on the ORM side. Each
+//! row of [`REDIRECT_TABLE`] maps an old path on a site to a new path or URL;
+//! an empty new path means the page is gone for good. Rows are managed with
+//! [`RedirectStore`], through the admin, or imported in bulk from CSV with
+//! [`RedirectStore::import_csv`].
+//!
+//! The redirect middleware serves them: load the rows once at startup and
+//! hand them to its store.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use reinhardt_db::orm::redirects::{RedirectModel, RedirectStore};
+//!
+//! let store = RedirectStore::new(connection);
+//! store.create_table().await?;
+//! store.save(&RedirectModel::new(1, "/old/", "/new/")).await?;
+//!
+//! let csv = "old_path,new_path,permanent\n/blog/,/news/,true\n/jobs/,,true\n";
+//! let imported = store.import_csv(csv.as_bytes(), 1).await?;
+//! assert_eq!(imported, 2);
+//! ```
+
+use crate::backends::DatabaseConnection;
+use crate::backends::sql_build_helpers::build_inline_sql;
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_query::prelude::{
+	Alias, ColumnDef, Cond, Condition, Expr, ExprTrait, Order, Query, TableConstraint, Value,
+};
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+
+/// Table storing the redirects
+pub const REDIRECT_TABLE: &str = "redirects_redirect";
+
+/// Row of the [`REDIRECT_TABLE`] table
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_db::orm::redirects::RedirectModel;
+///
+/// let redirect = RedirectModel::new(1, "/old/", "/new/");
+/// assert!(redirect.permanent);
+/// assert!(!redirect.is_gone());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedirectModel {
+	/// Primary key, `None` until saved
+	pub id: Option<i64>,
+	/// Site the old path belongs to
+	pub site_id: i64,
+	/// Path being redirected, including the query string if any
+	pub old_path: String,
+	/// Target path or absolute URL; empty when the page is gone
+	pub new_path: String,
+	/// Whether the redirect is permanent (301) rather than temporary (302)
+	pub permanent: bool,
+}
+
+impl RedirectModel {
+	/// Create an unsaved permanent redirect
+	pub fn new(site_id: i64, old_path: impl Into<String>, new_path: impl Into<String>) -> Self {
+		Self {
+			id: None,
+			site_id,
+			old_path: old_path.into(),
+			new_path: new_path.into(),
+			permanent: true,
+		}
+	}
+
+	/// Make the redirect temporary (302)
+	pub fn temporary(mut self) -> Self {
+		self.permanent = false;
+		self
+	}
+
+	/// Whether the old path should answer `410 Gone`
+	pub fn is_gone(&self) -> bool {
+		self.new_path.is_empty()
+	}
+}
+
+/// One line of a redirects CSV file
+#[derive(Debug, Deserialize)]
+struct CsvRedirect {
+	old_path: String,
+	#[serde(default)]
+	new_path: String,
+	#[serde(default)]
+	site_id: Option<i64>,
+	#[serde(default)]
+	permanent: Option<bool>,
+}
+
+/// Parse redirects from CSV
+///
+/// The first line is a header naming the columns: `old_path` is required,
+/// `new_path`, `site_id` and `permanent` are optional. Rows without a
+/// `site_id` belong to `default_site_id`; rows without `permanent` are
+/// permanent.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_db::orm::redirects::parse_csv;
+///
+/// let csv = "old_path,new_path\n/a/,/b/\n";
+/// let redirects = parse_csv(csv.as_bytes(), 1).unwrap();
+/// assert_eq!(redirects[0].new_path, "/b/");
+/// ```
+pub fn parse_csv(reader: impl Read, default_site_id: i64) -> Result<Vec<RedirectModel>> {
+	let mut reader = csv::ReaderBuilder::new()
+		.trim(csv::Trim::All)
+		.from_reader(reader);
+	reader
+		.deserialize::<CsvRedirect>()
+		.enumerate()
+		.map(|(index, row)| {
+			// Line 1 is the header.
+			let row = row.map_err(|e| {
+				Error::Validation(format!("invalid redirect on line {}: {}", index + 2, e))
+			})?;
+			if !row.old_path.starts_with('/') {
+				return Err(Error::Validation(format!(
+					"invalid redirect on line {}: old path `{}` must start with `/`",
+					index + 2,
+					row.old_path
+				)));
+			}
+			Ok(RedirectModel {
+				id: None,
+				site_id: row.site_id.unwrap_or(default_site_id),
+				old_path: row.old_path,
+				new_path: row.new_path,
+				permanent: row.permanent.unwrap_or(true),
+			})
+		})
+		.collect()
+}
+
+/// Database access to the [`REDIRECT_TABLE`] table
+pub struct RedirectStore {
+	connection: DatabaseConnection,
+}
+
+impl RedirectStore {
+	/// Create a store using `connection`
+	pub fn new(connection: DatabaseConnection) -> Self {
+		Self { connection }
+	}
+
+	/// Create the [`REDIRECT_TABLE`] table if it does not exist
+	pub async fn create_table(&self) -> Result<()> {
+		let stmt = Query::create_table()
+			.table(Alias::new(REDIRECT_TABLE))
+			.if_not_exists()
+			.col(
+				ColumnDef::new("id")
+					.big_integer()
+					.not_null(true)
+					.auto_increment(true)
+					.primary_key(true),
+			)
+			.col(ColumnDef::new("site_id").big_integer().not_null(true))
+			.col(ColumnDef::new("old_path").string_len(200).not_null(true))
+			.col(ColumnDef::new("new_path").string_len(200).not_null(true))
+			.col(ColumnDef::new("permanent").boolean().not_null(true))
+			.constraint(TableConstraint::Unique {
+				name: None,
+				columns: vec!["site_id".into(), "old_path".into()],
+			})
+			.to_owned();
+		self.execute(&build_inline_sql(self.connection.database_type(), &stmt))
+			.await
+	}
+
+	/// All redirects, ordered by site and old path
+	pub async fn all(&self) -> Result<Vec<RedirectModel>> {
+		self.select(None).await
+	}
+
+	/// Redirects of the site with id `site_id`
+	pub async fn for_site(&self, site_id: i64) -> Result<Vec<RedirectModel>> {
+		self.select(Some(
+			Cond::all().add(Expr::col(Alias::new("site_id")).eq(site_id)),
+		))
+		.await
+	}
+
+	/// Redirect of `old_path` on the site with id `site_id`
+	pub async fn get(&self, site_id: i64, old_path: &str) -> Result<Option<RedirectModel>> {
+		Ok(self
+			.select(Some(key_condition(site_id, old_path)))
+			.await?
+			.into_iter()
+			.next())
+	}
+
+	/// Insert `redirect`, or update the existing one for the same site and
+	/// old path
+	pub async fn save(&self, redirect: &RedirectModel) -> Result<()> {
+		let db_type = self.connection.database_type();
+		if self
+			.get(redirect.site_id, &redirect.old_path)
+			.await?
+			.is_some()
+		{
+			let stmt = Query::update()
+				.table(Alias::new(REDIRECT_TABLE))
+				.value(Alias::new("new_path"), redirect.new_path.clone())
+				.value(Alias::new("permanent"), redirect.permanent)
+				.and_where(key_condition(redirect.site_id, &redirect.old_path))
+				.to_owned();
+			return self.execute(&build_inline_sql(db_type, &stmt)).await;
+		}
+		let stmt = Query::insert()
+			.into_table(Alias::new(REDIRECT_TABLE))
+			.columns([
+				Alias::new("site_id"),
+				Alias::new("old_path"),
+				Alias::new("new_path"),
+				Alias::new("permanent"),
+			])
+			.values_panic([
+				Value::from(redirect.site_id),
+				Value::from(redirect.old_path.clone()),
+				Value::from(redirect.new_path.clone()),
+				Value::from(redirect.permanent),
+			])
+			.to_owned();
+		self.execute(&build_inline_sql(db_type, &stmt)).await
+	}
+
+	/// Delete the redirect of `old_path` on the site with id `site_id`
+	pub async fn delete(&self, site_id: i64, old_path: &str) -> Result<()> {
+		let stmt = Query::delete()
+			.from_table(Alias::new(REDIRECT_TABLE))
+			.and_where(key_condition(site_id, old_path))
+			.to_owned();
+		self.execute(&build_inline_sql(self.connection.database_type(), &stmt))
+			.await
+	}
+
+	/// Save every redirect of a CSV file, returning how many were imported
+	///
+	/// The whole file is validated before anything is written; see
+	/// [`parse_csv`] for the format.
+	pub async fn import_csv(&self, reader: impl Read, default_site_id: i64) -> Result<usize> {
+		let redirects = parse_csv(reader, default_site_id)?;
+		for redirect in &redirects {
+			self.save(redirect).await?;
+		}
+		Ok(redirects.len())
+	}
+
+	async fn select(&self, condition: Option<Condition>) -> Result<Vec<RedirectModel>> {
+		let mut stmt = Query::select();
+		stmt.columns([
+			Alias::new("id"),
+			Alias::new("site_id"),
+			Alias::new("old_path"),
+			Alias::new("new_path"),
+			Alias::new("permanent"),
+		])
+		.from(Alias::new(REDIRECT_TABLE))
+		.order_by(Alias::new("site_id"), Order::Asc)
+		.order_by(Alias::new("old_path"), Order::Asc);
+		if let Some(condition) = condition {
+			stmt.cond_where(condition);
+		}
+		let sql = build_inline_sql(self.connection.database_type(), &stmt);
+		let rows = self
+			.connection
+			.fetch_all(&sql, vec![])
+			.await
+			.map_err(|e| Error::Database(e.to_string()))?;
+		rows.iter().map(row_to_redirect).collect()
+	}
+
+	async fn execute(&self, sql: &str) -> Result<()> {
+		self.connection
+			.execute(sql, vec![])
+			.await
+			.map(|_| ())
+			.map_err(|e| Error::Database(e.to_string()))
+	}
+}
+
+/// Condition matching the redirect of `old_path` on `site_id`
+fn key_condition(site_id: i64, old_path: &str) -> Condition {
+	Cond::all()
+		.add(Expr::col(Alias::new("site_id")).eq(site_id))
+		.add(Expr::col(Alias::new("old_path")).eq(old_path.to_string()))
+}
+
+/// Convert a [`REDIRECT_TABLE`] row
+fn row_to_redirect(row: &crate::backends::types::Row) -> Result<RedirectModel> {
+	let db_err = |e: crate::backends::error::DatabaseError| Error::Database(e.to_string());
+	// SQLite stores booleans as integers.
+	let permanent = match row.get::<bool>("permanent") {
+		Ok(permanent) => permanent,
+		Err(_) => row.get::<i64>("permanent").map_err(db_err)? != 0,
+	};
+	Ok(RedirectModel {
+		id: Some(row.get::<i64>("id").map_err(db_err)?),
+		site_id: row.get::<i64>("site_id").map_err(db_err)?,
+		old_path: row.get::<String>("old_path").map_err(db_err)?,
+		new_path: row.get::<String>("new_path").map_err(db_err)?,
+		permanent,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_parse_csv_applies_defaults() {
+		// Arrange
+		let csv = "old_path,new_path,site_id,permanent\n\
+			/blog/, /news/ ,,\n\
+			/jobs/,,2,false\n";
+
+		// Act
+		let redirects = parse_csv(csv.as_bytes(), 1).unwrap();
+
+		// Assert
+		assert_eq!(redirects.len(), 2);
+		assert_eq!(redirects[0], RedirectModel::new(1, "/blog/", "/news/"));
+		assert_eq!(
+			redirects[1],
+			RedirectModel::new(2, "/jobs/", "").temporary()
+		);
+		assert!(redirects[1].is_gone());
+	}
+
+	#[rstest]
+	#[case::relative_old_path("old_path,new_path\nblog/,/news/\n")]
+	#[case::bad_boolean("old_path,permanent\n/blog/,sometimes\n")]
+	fn test_parse_csv_rejects_invalid_rows(#[case] csv: &str) {
+		// Act
+		let result = parse_csv(csv.as_bytes(), 1);
+
+		// Assert
+		assert!(matches!(result, Err(Error::Validation(message)) if message.contains("line 2")));
+	}
+}
@@ -0,0 +1,641 @@
+//! Declarative data retention
+//!
+//! Models declare how long their rows are kept with
+//! `#[model(retain = "90d", on_expire = delete)]`. The age of a row is read
+//! from its `auto_now_add` timestamp, or from the field named by
+//! `retain_by = "..."`. Every such model is registered in
+//! [`RETENTION_POLICIES`], which backs:
+//!
+//! - [`RetentionEnforcer`], which deletes or anonymizes expired rows in
+//!   batches, logging its progress, optionally as a dry run. It is exposed by
+//!   the `enforce_retention` management command and is meant to be scheduled
+//!   as the `DataRetention` maintenance job.
+//! - [`upcoming_expirations`], which counts the rows expiring soon, as shown
+//!   in the admin.
+//!
+//! Expired rows are written with plain SQL statements: signals, audit
+//! records and counter fields are not updated. `on_expire = anonymize`
+//! overwrites the `#[field(personal_data)]` fields following their
+//! [`AnonymizeStrategy`](super::personal_data::AnonymizeStrategy) and keeps
+//! the row.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! #[model(app_label = "shop", table_name = "orders", retain = "2y", on_expire = anonymize,
+//!     data_subject = "customer_id")]
+//! pub struct Order {
+//!     #[field(primary_key = true)]
+//!     id: Option<i64>,
+//!     customer_id: i64,
+//!     #[field(max_length = 200, personal_data)]
+//!     shipping_address: String,
+//!     #[field(auto_now_add = true)]
+//!     created_at: DateTime<Utc>,
+//! }
+//!
+//! let report = RetentionEnforcer::new(&conn).dry_run(true).run().await?;
+//! println!("{} rows would expire", report.total_rows());
+//! ```
+
+use super::Model;
+use super::connection::{DatabaseConnection, QueryRow};
+use super::personal_data::{PersonalDataField, anonymized_value, is_anonymized, json_to_value};
+use crate::backends::sql_build_helpers::build_inline_sql;
+use crate::backends::types::DatabaseType;
+use chrono::{DateTime, Utc};
+use linkme::distributed_slice;
+use once_cell::sync::Lazy;
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_query::prelude::{Alias, Expr, ExprTrait, Func, Order, Query, SimpleExpr, Value};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::RwLock;
+use std::time::Duration;
+
+/// What happens to a row once its retention period is over
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExpireAction {
+	/// Delete the row
+	#[default]
+	Delete,
+	/// Anonymize the personal data fields and keep the row
+	Anonymize,
+}
+
+impl fmt::Display for ExpireAction {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Delete => "delete",
+			Self::Anonymize => "anonymize",
+		})
+	}
+}
+
+impl FromStr for ExpireAction {
+	type Err = String;
+
+	fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+		match s {
+			"delete" => Ok(Self::Delete),
+			"anonymize" => Ok(Self::Anonymize),
+			other => Err(format!("unknown expire action: {}", other)),
+		}
+	}
+}
+
+/// Retention declared with `#[model(retain = "...")]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Retention {
+	/// How long rows are kept
+	pub period: Duration,
+	/// Column holding the timestamp the period starts from
+	pub timestamp_column: &'static str,
+	/// What happens to expired rows
+	pub action: ExpireAction,
+}
+
+/// Model whose rows expire
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetentionPolicy {
+	/// Label of the app declaring the model
+	pub app_label: &'static str,
+	/// Name of the model
+	pub model: &'static str,
+	/// Table storing the model
+	pub table: &'static str,
+	/// Primary key column
+	pub primary_key: &'static str,
+	/// How long rows are kept, and what happens to them afterwards
+	pub retention: Retention,
+	/// Personal fields overwritten by [`ExpireAction::Anonymize`]
+	pub fields: Vec<PersonalDataField>,
+}
+
+impl RetentionPolicy {
+	/// Describe `M` from its generated metadata
+	///
+	/// # Panics
+	///
+	/// Panics if `M` declares no `retain` period.
+	pub fn of<M: Model>() -> Self {
+		Self {
+			app_label: M::app_label(),
+			model: std::any::type_name::<M>()
+				.rsplit("::")
+				.next()
+				.unwrap_or_default(),
+			table: M::table_name(),
+			primary_key: M::primary_key_field(),
+			retention: M::retention().expect("retention policies must declare `retain`"),
+			fields: M::personal_data_fields(),
+		}
+	}
+
+	/// `app_label.Model` key used in reports
+	pub fn label(&self) -> String {
+		format!("{}.{}", self.app_label, self.model)
+	}
+
+	/// Rows whose timestamp is older than this have expired at `now`
+	pub fn cutoff(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+		chrono::Duration::from_std(self.retention.period)
+			.ok()
+			.and_then(|period| now.checked_sub_signed(period))
+			.unwrap_or(DateTime::<Utc>::MIN_UTC)
+	}
+}
+
+/// Type for retention policy providers collected at compile-time
+pub type RetentionProvider = fn() -> RetentionPolicy;
+
+/// Distributed slice for collecting retention policies at compile-time
+#[distributed_slice]
+pub static RETENTION_POLICIES: [RetentionProvider];
+
+static RUNTIME_POLICIES: Lazy<RwLock<Vec<RetentionPolicy>>> = Lazy::new(|| RwLock::new(Vec::new()));
+
+/// Register a retention policy at runtime (for tests and dynamic models)
+pub fn register_retention_policy(policy: RetentionPolicy) {
+	RUNTIME_POLICIES
+		.write()
+		.unwrap_or_else(|e| e.into_inner())
+		.push(policy);
+}
+
+/// All retention policies: compile-time registrations followed by runtime ones
+pub fn registered_retention_policies() -> Vec<RetentionPolicy> {
+	let mut policies = Vec::new();
+	// In test mode, do not access RETENTION_POLICIES to avoid "duplicate
+	// distributed_slice" errors, as for the migration registry.
+	#[cfg(not(test))]
+	for provider in RETENTION_POLICIES {
+		policies.push(provider());
+	}
+	policies.extend(
+		RUNTIME_POLICIES
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.iter()
+			.cloned(),
+	);
+	policies
+}
+
+/// Parse a retention period such as `"90d"`
+///
+/// Accepts a positive integer followed by `s`, `m`, `h`, `d`, `w` or `y`
+/// (365 days).
+pub fn parse_retention_period(period: &str) -> Result<Duration> {
+	let period = period.trim();
+	let split = period
+		.find(|c: char| !c.is_ascii_digit())
+		.unwrap_or(period.len());
+	let (amount, unit) = period.split_at(split);
+	let amount: u64 = amount
+		.parse()
+		.ok()
+		.filter(|amount| *amount > 0)
+		.ok_or_else(|| Error::Validation(format!("invalid retention period: {}", period)))?;
+	let seconds = match unit {
+		"s" => 1,
+		"m" => 60,
+		"h" => 3_600,
+		"d" => 86_400,
+		"w" => 604_800,
+		"y" => 31_536_000,
+		_ => {
+			return Err(Error::Validation(format!(
+				"invalid retention period: {} (expected a unit of s, m, h, d, w or y)",
+				period
+			)));
+		}
+	};
+	Ok(Duration::from_secs(amount.saturating_mul(seconds)))
+}
+
+/// Expired rows of one model
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetentionOutcome {
+	/// What happened to the rows
+	pub action: ExpireAction,
+	/// Rows deleted or anonymized, or that would be in a dry run
+	pub rows: usize,
+}
+
+/// Rows processed by [`RetentionEnforcer::run`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetentionReport {
+	/// Whether nothing was written
+	pub dry_run: bool,
+	/// Outcome of each model, keyed by `app_label.Model`
+	pub models: BTreeMap<String, RetentionOutcome>,
+}
+
+impl RetentionReport {
+	/// Number of expired rows across all models
+	pub fn total_rows(&self) -> usize {
+		self.models.values().map(|outcome| outcome.rows).sum()
+	}
+}
+
+/// Enforce every retention policy using the global connection
+pub async fn enforce_retention() -> Result<RetentionReport> {
+	let conn = super::manager::get_connection().await?;
+	RetentionEnforcer::new(&conn).run().await
+}
+
+/// Deletes or anonymizes the rows of registered models whose retention
+/// period is over
+///
+/// Rows are processed in primary key order, `batch_size` at a time, each
+/// batch in its own transaction, so a large backlog neither holds long locks
+/// nor is lost entirely on error.
+pub struct RetentionEnforcer<'a> {
+	connection: &'a DatabaseConnection,
+	batch_size: usize,
+	dry_run: bool,
+	salt: String,
+	now: Option<DateTime<Utc>>,
+}
+
+impl<'a> RetentionEnforcer<'a> {
+	/// Create an enforcer writing through `connection`, 500 rows at a time
+	pub fn new(connection: &'a DatabaseConnection) -> Self {
+		Self {
+			connection,
+			batch_size: 500,
+			dry_run: false,
+			salt: String::new(),
+			now: None,
+		}
+	}
+
+	/// Number of rows processed per batch
+	pub fn batch_size(mut self, batch_size: usize) -> Self {
+		self.batch_size = batch_size.max(1);
+		self
+	}
+
+	/// Only count the expired rows, without deleting or anonymizing them
+	pub fn dry_run(mut self, dry_run: bool) -> Self {
+		self.dry_run = dry_run;
+		self
+	}
+
+	/// Salt mixed into hashed and fake values when anonymizing
+	pub fn salt(mut self, salt: impl Into<String>) -> Self {
+		self.salt = salt.into();
+		self
+	}
+
+	/// Evaluate expiry at `now` instead of the current time
+	pub fn now(mut self, now: DateTime<Utc>) -> Self {
+		self.now = Some(now);
+		self
+	}
+
+	/// Enforce every registered policy
+	pub async fn run(&self) -> Result<RetentionReport> {
+		let mut report = RetentionReport {
+			dry_run: self.dry_run,
+			models: BTreeMap::new(),
+		};
+		for policy in registered_retention_policies() {
+			let rows = self.enforce(&policy).await?;
+			report.models.insert(
+				policy.label(),
+				RetentionOutcome {
+					action: policy.retention.action,
+					rows,
+				},
+			);
+		}
+		Ok(report)
+	}
+
+	/// Enforce one policy, returning the number of expired rows
+	pub async fn enforce(&self, policy: &RetentionPolicy) -> Result<usize> {
+		if policy.retention.action == ExpireAction::Anonymize && policy.fields.is_empty() {
+			return Err(Error::Validation(format!(
+				"{}: on_expire = anonymize requires personal_data fields",
+				policy.label()
+			)));
+		}
+		let cutoff = policy.cutoff(self.now.unwrap_or_else(Utc::now));
+		let mut after: Option<JsonValue> = None;
+		let mut total = 0;
+		let mut batch = 0;
+		loop {
+			let rows = self.expired_batch(policy, cutoff, after.as_ref()).await?;
+			let Some(last) = rows.last() else {
+				break;
+			};
+			after = Some(primary_key(policy, last)?);
+			batch += 1;
+
+			let expired: Vec<&QueryRow> = match policy.retention.action {
+				ExpireAction::Delete => rows.iter().collect(),
+				// Rows anonymized by an earlier run stay past the cutoff.
+				ExpireAction::Anonymize => rows
+					.iter()
+					.filter(|row| {
+						policy.fields.iter().any(|field| {
+							!is_anonymized(
+								field,
+								row.data.get(field.column).unwrap_or(&JsonValue::Null),
+							)
+						})
+					})
+					.collect(),
+			};
+			if !self.dry_run && !expired.is_empty() {
+				self.expire(policy, &expired).await?;
+			}
+			total += expired.len();
+			tracing::info!(
+				model = %policy.label(),
+				action = %policy.retention.action,
+				batch,
+				rows = expired.len(),
+				total,
+				dry_run = self.dry_run,
+				"Retention batch processed"
+			);
+
+			if rows.len() < self.batch_size {
+				break;
+			}
+		}
+		Ok(total)
+	}
+
+	/// Next batch of expired rows after the primary key `after`
+	async fn expired_batch(
+		&self,
+		policy: &RetentionPolicy,
+		cutoff: DateTime<Utc>,
+		after: Option<&JsonValue>,
+	) -> Result<Vec<QueryRow>> {
+		let mut select = Query::select();
+		select
+			.column(Alias::new(policy.primary_key))
+			.from(Alias::new(policy.table))
+			.and_where(expired_before(policy, cutoff))
+			.order_by(Alias::new(policy.primary_key), Order::Asc)
+			.limit(self.batch_size as u64);
+		if policy.retention.action == ExpireAction::Anonymize {
+			select.columns(policy.fields.iter().map(|f| Alias::new(f.column)));
+		}
+		if let Some(after) = after {
+			select.and_where(Expr::col(Alias::new(policy.primary_key)).gt(json_to_value(after)));
+		}
+		self.connection
+			.query(
+				&build_inline_sql(self.connection.backend().into(), &select),
+				vec![],
+			)
+			.await
+			.map_err(|e| Error::Database(e.to_string()))
+	}
+
+	/// Delete or anonymize `rows` in one transaction
+	async fn expire(&self, policy: &RetentionPolicy, rows: &[&QueryRow]) -> Result<()> {
+		let db_type = DatabaseType::from(self.connection.backend());
+		let mut statements = Vec::new();
+		match policy.retention.action {
+			ExpireAction::Delete => {
+				let pks = rows
+					.iter()
+					.map(|row| primary_key(policy, row).map(|pk| json_to_value(&pk)))
+					.collect::<Result<Vec<Value>>>()?;
+				let delete = Query::delete()
+					.from_table(Alias::new(policy.table))
+					.and_where(Expr::col(Alias::new(policy.primary_key)).is_in(pks))
+					.to_owned();
+				statements.push(build_inline_sql(db_type, &delete));
+			}
+			ExpireAction::Anonymize => {
+				for row in rows {
+					let pk = primary_key(policy, row)?;
+					let mut update = Query::update();
+					update.table(Alias::new(policy.table));
+					for field in &policy.fields {
+						let current = row.data.get(field.column).unwrap_or(&JsonValue::Null);
+						let replacement = anonymized_value(field, current, &self.salt);
+						update.value(Alias::new(field.column), json_to_value(&replacement));
+					}
+					update.and_where(
+						Expr::col(Alias::new(policy.primary_key)).eq(json_to_value(&pk)),
+					);
+					statements.push(build_inline_sql(db_type, &update));
+				}
+			}
+		}
+
+		let mut tx = self
+			.connection
+			.begin()
+			.await
+			.map_err(|e| Error::Database(e.to_string()))?;
+		for sql in &statements {
+			if let Err(e) = tx.execute(sql, vec![]).await {
+				let _ = tx.rollback().await;
+				return Err(Error::Database(e.to_string()));
+			}
+		}
+		tx.commit()
+			.await
+			.map_err(|e| Error::Database(e.to_string()))
+	}
+}
+
+/// Rows of one model expiring soon
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpcomingExpiration {
+	/// `app_label.Model` key of the model
+	pub model: String,
+	/// What will happen to the rows
+	pub action: ExpireAction,
+	/// Retention period of the model
+	pub period: Duration,
+	/// Rows whose retention period ends within the window
+	pub rows: u64,
+}
+
+/// Count, for every registered policy, the rows expiring within `within`
+///
+/// Rows that have already expired but were not processed yet are not
+/// included; they go at the next enforcement run.
+pub async fn upcoming_expirations(
+	conn: &DatabaseConnection,
+	within: Duration,
+) -> Result<Vec<UpcomingExpiration>> {
+	let now = Utc::now();
+	let window = chrono::Duration::from_std(within).unwrap_or(chrono::Duration::MAX);
+	let mut upcoming = Vec::new();
+	for policy in registered_retention_policies() {
+		let start = policy.cutoff(now);
+		let select = Query::select()
+			.expr_as(Func::count(Expr::asterisk().into()), Alias::new("count"))
+			.from(Alias::new(policy.table))
+			.and_where(
+				Expr::col(Alias::new(policy.retention.timestamp_column))
+					.gte(timestamp_value(start)),
+			)
+			.and_where(expired_before(
+				&policy,
+				start
+					.checked_add_signed(window)
+					.unwrap_or(DateTime::<Utc>::MAX_UTC),
+			))
+			.to_owned();
+		let rows = conn
+			.query(&build_inline_sql(conn.backend().into(), &select), vec![])
+			.await
+			.map_err(|e| Error::Database(e.to_string()))?;
+		let count = rows
+			.first()
+			.and_then(|row| row.data.get("count"))
+			.and_then(|count| {
+				count
+					.as_u64()
+					.or_else(|| count.as_str().and_then(|s| s.parse().ok()))
+			})
+			.unwrap_or_default();
+		upcoming.push(UpcomingExpiration {
+			model: policy.label(),
+			action: policy.retention.action,
+			period: policy.retention.period,
+			rows: count,
+		});
+	}
+	Ok(upcoming)
+}
+
+/// `timestamp_column < cutoff`
+fn expired_before(policy: &RetentionPolicy, cutoff: DateTime<Utc>) -> SimpleExpr {
+	Expr::col(Alias::new(policy.retention.timestamp_column)).lt(timestamp_value(cutoff))
+}
+
+fn timestamp_value(timestamp: DateTime<Utc>) -> Value {
+	Value::ChronoDateTimeUtc(Some(Box::new(timestamp)))
+}
+
+fn primary_key(policy: &RetentionPolicy, row: &QueryRow) -> Result<JsonValue> {
+	row.data.get(policy.primary_key).cloned().ok_or_else(|| {
+		Error::Database(format!(
+			"{}: row without primary key `{}`",
+			policy.label(),
+			policy.primary_key
+		))
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn policy(action: ExpireAction) -> RetentionPolicy {
+		RetentionPolicy {
+			app_label: "shop",
+			model: "Order",
+			table: "shop_order",
+			primary_key: "id",
+			retention: Retention {
+				period: Duration::from_secs(90 * 86_400),
+				timestamp_column: "created_at",
+				action,
+			},
+			fields: Vec::new(),
+		}
+	}
+
+	#[rstest]
+	#[case("30s", 30)]
+	#[case("15m", 900)]
+	#[case("12h", 43_200)]
+	#[case("90d", 7_776_000)]
+	#[case("2w", 1_209_600)]
+	#[case(" 1y ", 31_536_000)]
+	fn test_parse_retention_period(#[case] period: &str, #[case] seconds: u64) {
+		// Act
+		let parsed = parse_retention_period(period).unwrap();
+
+		// Assert
+		assert_eq!(parsed, Duration::from_secs(seconds));
+	}
+
+	#[rstest]
+	#[case("")]
+	#[case("90")]
+	#[case("0d")]
+	#[case("d")]
+	#[case("3 months")]
+	fn test_parse_retention_period_rejects_invalid(#[case] period: &str) {
+		// Act & Assert
+		assert!(parse_retention_period(period).is_err());
+	}
+
+	#[rstest]
+	#[case("delete", ExpireAction::Delete)]
+	#[case("anonymize", ExpireAction::Anonymize)]
+	fn test_expire_action_round_trips(#[case] text: &str, #[case] action: ExpireAction) {
+		// Act & Assert
+		assert_eq!(text.parse::<ExpireAction>().unwrap(), action);
+		assert_eq!(action.to_string(), text);
+	}
+
+	#[rstest]
+	fn test_cutoff_subtracts_period() {
+		// Arrange
+		let now = DateTime::parse_from_rfc3339("2026-04-01T00:00:00Z")
+			.unwrap()
+			.with_timezone(&Utc);
+
+		// Act
+		let cutoff = policy(ExpireAction::Delete).cutoff(now);
+
+		// Assert
+		assert_eq!(cutoff.to_rfc3339(), "2026-01-01T00:00:00+00:00");
+	}
+
+	#[rstest]
+	fn test_registered_policies_include_runtime_registrations() {
+		// Arrange
+		register_retention_policy(RetentionPolicy {
+			model: "Visit",
+			table: "shop_visit",
+			..policy(ExpireAction::Delete)
+		});
+
+		// Act
+		let policies = registered_retention_policies();
+
+		// Assert
+		assert!(policies.iter().any(|p| p.label() == "shop.Visit"));
+	}
+
+	#[rstest]
+	fn test_report_totals_rows() {
+		// Arrange
+		let mut report = RetentionReport::default();
+		for (model, rows) in [("shop.Order", 3), ("shop.Visit", 4)] {
+			report.models.insert(
+				model.to_string(),
+				RetentionOutcome {
+					action: ExpireAction::Delete,
+					rows,
+				},
+			);
+		}
+
+		// Act & Assert
+		assert_eq!(report.total_rows(), 7);
+	}
+}
@@ -0,0 +1,345 @@
+//! Site-scoped models and site persistence
+//!
+//! Equivalent of Django's `django.contrib.sites` on the ORM side. Sites are
+//! stored in the [`SITE_TABLE`] table and looked up with [`SiteStore`], which
+//! caches them by id and domain. Models that belong to a site implement
+//! [`SiteScoped`] and are filtered with [`SiteQuerySetExt::on_site`].
+//!
+//! The current site of a request is resolved by the site middleware and read
+//! with `reinhardt_http::Site::current`; outside of requests, use the
+//! `site_id` of the `[sites]` settings section.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use reinhardt_db::orm::sites::{SiteQuerySetExt, SiteScoped};
+//!
+//! #[model(app_label = "blog", table_name = "articles")]
+//! pub struct Article {
+//!     #[field(primary_key = true)]
+//!     id: Option<i64>,
+//!     site_id: i64,
+//!     #[field(max_length = 200)]
+//!     title: String,
+//! }
+//!
+//! impl SiteScoped for Article {}
+//!
+//! let site = Site::current(&request).expect("site middleware is installed");
+//! let articles = Article::objects().all().on_site(site.id).all().await?;
+//! ```
+
+use super::Model;
+use super::query::{Filter, FilterOperator, FilterValue, QuerySet};
+use crate::backends::DatabaseConnection;
+use crate::backends::sql_build_helpers::build_inline_sql;
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_query::prelude::{Alias, ColumnDef, Expr, ExprTrait, Order, Query, SimpleExpr};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// Table storing the sites
+pub const SITE_TABLE: &str = "sites_site";
+
+/// Row of the [`SITE_TABLE`] table
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_db::orm::sites::SiteModel;
+///
+/// let site = SiteModel::new("example.com", "Example");
+/// assert!(site.id.is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SiteModel {
+	/// Primary key, `None` until saved
+	pub id: Option<i64>,
+	/// Domain name, optionally with a port
+	pub domain: String,
+	/// Human-readable site name
+	pub name: String,
+}
+
+impl SiteModel {
+	/// Create an unsaved site
+	pub fn new(domain: impl Into<String>, name: impl Into<String>) -> Self {
+		Self {
+			id: None,
+			domain: domain.into(),
+			name: name.into(),
+		}
+	}
+}
+
+/// Model whose rows belong to a site
+///
+/// Override [`site_field`](SiteScoped::site_field) when the foreign key to
+/// the site is not stored in `site_id`.
+pub trait SiteScoped: Model {
+	/// Column holding the id of the owning site
+	fn site_field() -> &'static str {
+		"site_id"
+	}
+}
+
+/// Site filters for querysets of [`SiteScoped`] models
+pub trait SiteQuerySetExt: Sized {
+	/// Keep rows belonging to the site with id `site_id`
+	fn on_site(self, site_id: u64) -> Self;
+}
+
+impl<T: SiteScoped> SiteQuerySetExt for QuerySet<T> {
+	fn on_site(self, site_id: u64) -> Self {
+		// Site ids beyond i64::MAX cannot be stored; they match no rows.
+		let value = i64::try_from(site_id)
+			.map(FilterValue::Integer)
+			.unwrap_or(FilterValue::Null);
+		self.filter(Filter::new(T::site_field(), FilterOperator::Eq, value))
+	}
+}
+
+/// Database access to the [`SITE_TABLE`] table
+///
+/// Lookups by id and domain are cached for the lifetime of the store, like
+/// Django's `SiteManager`; call [`clear_cache`](SiteStore::clear_cache) after
+/// changing sites through other connections.
+pub struct SiteStore {
+	connection: DatabaseConnection,
+	cache: RwLock<HashMap<String, SiteModel>>,
+}
+
+impl SiteStore {
+	/// Create a store using `connection`
+	pub fn new(connection: DatabaseConnection) -> Self {
+		Self {
+			connection,
+			cache: RwLock::new(HashMap::new()),
+		}
+	}
+
+	/// Create the [`SITE_TABLE`] table if it does not exist
+	pub async fn create_table(&self) -> Result<()> {
+		let stmt = Query::create_table()
+			.table(Alias::new(SITE_TABLE))
+			.if_not_exists()
+			.col(
+				ColumnDef::new("id")
+					.big_integer()
+					.not_null(true)
+					.auto_increment(true)
+					.primary_key(true),
+			)
+			.col(
+				ColumnDef::new("domain")
+					.string_len(100)
+					.not_null(true)
+					.unique(true),
+			)
+			.col(ColumnDef::new("name").string_len(50).not_null(true))
+			.to_owned();
+		self.execute(&build_inline_sql(self.connection.database_type(), &stmt))
+			.await
+	}
+
+	/// All sites, ordered by id
+	pub async fn all(&self) -> Result<Vec<SiteModel>> {
+		let stmt = Query::select()
+			.columns([Alias::new("id"), Alias::new("domain"), Alias::new("name")])
+			.from(Alias::new(SITE_TABLE))
+			.order_by(Alias::new("id"), Order::Asc)
+			.to_owned();
+		let sql = build_inline_sql(self.connection.database_type(), &stmt);
+		let rows = self
+			.connection
+			.fetch_all(&sql, vec![])
+			.await
+			.map_err(|e| Error::Database(e.to_string()))?;
+		rows.iter().map(row_to_site).collect()
+	}
+
+	/// Site with the given id
+	pub async fn get_by_id(&self, id: i64) -> Result<Option<SiteModel>> {
+		let key = format!("id:{}", id);
+		if let Some(site) = self.cached(&key) {
+			return Ok(Some(site));
+		}
+		self.fetch(Expr::col(Alias::new("id")).eq(id)).await
+	}
+
+	/// Site with the given domain
+	///
+	/// A port in `domain` is ignored when no site matches the full value.
+	pub async fn get_by_domain(&self, domain: &str) -> Result<Option<SiteModel>> {
+		let key = format!("domain:{}", domain);
+		if let Some(site) = self.cached(&key) {
+			return Ok(Some(site));
+		}
+		if let Some(site) = self
+			.fetch(Expr::col(Alias::new("domain")).eq(domain.to_string()))
+			.await?
+		{
+			return Ok(Some(site));
+		}
+		match domain.rsplit_once(':') {
+			Some((host, _)) => {
+				self.fetch(Expr::col(Alias::new("domain")).eq(host.to_string()))
+					.await
+			}
+			None => Ok(None),
+		}
+	}
+
+	/// Site with the given domain, created with `name` when missing
+	pub async fn get_or_create(&self, domain: &str, name: &str) -> Result<SiteModel> {
+		if let Some(site) = self.get_by_domain(domain).await? {
+			return Ok(site);
+		}
+		let stmt = Query::insert()
+			.into_table(Alias::new(SITE_TABLE))
+			.columns([Alias::new("domain"), Alias::new("name")])
+			.values_panic([domain.to_string(), name.to_string()])
+			.to_owned();
+		self.execute(&build_inline_sql(self.connection.database_type(), &stmt))
+			.await?;
+		self.fetch(Expr::col(Alias::new("domain")).eq(domain.to_string()))
+			.await?
+			.ok_or_else(|| Error::Database(format!("site `{}` was not created", domain)))
+	}
+
+	/// Forget cached sites
+	pub fn clear_cache(&self) {
+		self.cache
+			.write()
+			.unwrap_or_else(|e| e.into_inner())
+			.clear();
+	}
+
+	fn cached(&self, key: &str) -> Option<SiteModel> {
+		let cache = self.cache.read().unwrap_or_else(|e| e.into_inner());
+		cache.get(key).cloned()
+	}
+
+	/// Fetch the first site matching `condition` and cache it
+	async fn fetch(&self, condition: SimpleExpr) -> Result<Option<SiteModel>> {
+		let stmt = Query::select()
+			.columns([Alias::new("id"), Alias::new("domain"), Alias::new("name")])
+			.from(Alias::new(SITE_TABLE))
+			.and_where(condition)
+			.limit(1)
+			.to_owned();
+		let sql = build_inline_sql(self.connection.database_type(), &stmt);
+		let row = self
+			.connection
+			.fetch_optional(&sql, vec![])
+			.await
+			.map_err(|e| Error::Database(e.to_string()))?;
+		let Some(row) = row else {
+			return Ok(None);
+		};
+		let site = row_to_site(&row)?;
+		let mut cache = self.cache.write().unwrap_or_else(|e| e.into_inner());
+		if let Some(id) = site.id {
+			cache.insert(format!("id:{}", id), site.clone());
+		}
+		cache.insert(format!("domain:{}", site.domain), site.clone());
+		Ok(Some(site))
+	}
+
+	async fn execute(&self, sql: &str) -> Result<()> {
+		self.connection
+			.execute(sql, vec![])
+			.await
+			.map(|_| ())
+			.map_err(|e| Error::Database(e.to_string()))
+	}
+}
+
+/// Convert a [`SITE_TABLE`] row
+fn row_to_site(row: &crate::backends::types::Row) -> Result<SiteModel> {
+	let get = |column: &str| -> Result<String> {
+		row.get::<String>(column)
+			.map_err(|e| Error::Database(e.to_string()))
+	};
+	Ok(SiteModel {
+		id: Some(
+			row.get::<i64>("id")
+				.map_err(|e| Error::Database(e.to_string()))?,
+		),
+		domain: get("domain")?,
+		name: get("name")?,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_site_model_new_is_unsaved() {
+		// Act
+		let site = SiteModel::new("example.com", "Example");
+
+		// Assert
+		assert_eq!(site.id, None);
+		assert_eq!(site.domain, "example.com");
+		assert_eq!(site.name, "Example");
+	}
+
+	#[rstest]
+	fn test_on_site_filters_site_field() {
+		// Act
+		let qs = QuerySet::<Article>::new().on_site(3);
+
+		// Assert
+		let filter = &qs.filters()[0];
+		assert_eq!(filter.field, "owner_site_id");
+		assert!(matches!(filter.operator, FilterOperator::Eq));
+		assert!(matches!(filter.value, FilterValue::Integer(3)));
+	}
+
+	#[derive(Debug, Clone, Serialize, Deserialize)]
+	struct Article {
+		id: Option<i64>,
+		owner_site_id: i64,
+	}
+
+	#[derive(Clone)]
+	struct ArticleFields;
+
+	impl crate::orm::model::FieldSelector for ArticleFields {
+		fn with_alias(self, _alias: &str) -> Self {
+			self
+		}
+	}
+
+	impl Model for Article {
+		type PrimaryKey = i64;
+		type Fields = ArticleFields;
+		type Objects = crate::orm::Manager<Self>;
+
+		fn table_name() -> &'static str {
+			"articles"
+		}
+
+		fn new_fields() -> Self::Fields {
+			ArticleFields
+		}
+
+		fn primary_key(&self) -> Option<Self::PrimaryKey> {
+			self.id
+		}
+
+		fn set_primary_key(&mut self, key: Self::PrimaryKey) {
+			self.id = Some(key);
+		}
+	}
+
+	impl SiteScoped for Article {
+		fn site_field() -> &'static str {
+			"owner_site_id"
+		}
+	}
+}
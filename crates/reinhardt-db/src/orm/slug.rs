@@ -0,0 +1,213 @@
+//! Slug fields populated on save
+//!
+//! Fields declared with `#[field(slug_from = "title")]` are filled from their
+//! source field by [`Model::save`] whenever they are empty, using
+//! [`slugify`]. With `unique = true`, a numeric suffix (`-2`, `-3`, ...) is
+//! appended until no other row uses the slug; the search gives up after
+//! [`MAX_SLUG_ATTEMPTS`] candidates. The check is not atomic: concurrent
+//! saves can still pick the same slug, and the column's unique constraint
+//! rejects the later one.
+//!
+//! A slug that is already set is never regenerated, so URLs stay stable when
+//! the source field changes.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! #[model(app_label = "blog", table_name = "posts")]
+//! pub struct Post {
+//!     #[field(primary_key = true)]
+//!     id: Option<i64>,
+//!     #[field(max_length = 200)]
+//!     title: String,
+//!     #[field(max_length = 50, slug_from = "title", unique = true)]
+//!     slug: String,
+//! }
+//!
+//! let mut post = Post::new("Hello World".to_string(), String::new());
+//! post.save().await?; // slug = "hello-world"
+//!
+//! let mut other = Post::new("Hello, world!".to_string(), String::new());
+//! other.save().await?; // slug = "hello-world-2"
+//! ```
+
+use super::Model;
+use super::query::{Filter, FilterOperator, FilterValue};
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_utils::utils_core::encoding::slugify;
+use serde_json::Value as JsonValue;
+
+/// Maximum number of candidates tried for a unique slug
+pub const MAX_SLUG_ATTEMPTS: usize = 100;
+
+/// Slug field declared with `#[field(slug_from = "...")]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlugField {
+	/// Name of the slug field
+	pub field: &'static str,
+	/// Column storing the slug
+	pub column: &'static str,
+	/// Name of the field the slug is generated from
+	pub source: &'static str,
+	/// Whether the slug must not collide with other rows
+	pub unique: bool,
+	/// Maximum slug length, suffix included
+	pub max_length: Option<usize>,
+}
+
+/// Slug candidate for the given attempt
+///
+/// The first attempt is `base` itself; attempt `n` appends `-{n + 1}`. The
+/// base is shortened so the result fits in `max_length`.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_db::orm::slug::slug_candidate;
+///
+/// assert_eq!(slug_candidate("hello-world", 0, None), "hello-world");
+/// assert_eq!(slug_candidate("hello-world", 1, None), "hello-world-2");
+/// assert_eq!(slug_candidate("hello-world", 1, Some(8)), "hello-2");
+/// ```
+pub fn slug_candidate(base: &str, attempt: usize, max_length: Option<usize>) -> String {
+	let suffix = match attempt {
+		0 => String::new(),
+		n => format!("-{}", n + 1),
+	};
+	let mut base = base;
+	if let Some(max_length) = max_length {
+		let available = max_length.saturating_sub(suffix.len());
+		if base.len() > available {
+			// Slugs are ASCII, so byte offsets are character boundaries.
+			base = base[..available].trim_end_matches('-');
+		}
+	}
+	format!("{}{}", base, suffix)
+}
+
+/// Fill the empty slug fields of `instance` from their source fields
+pub(crate) async fn populate_slugs<M: Model>(instance: &mut M) -> Result<()> {
+	let slug_fields = M::slug_fields();
+	if slug_fields.is_empty() {
+		return Ok(());
+	}
+
+	let mut json = serde_json::to_value(&*instance).map_err(|e| Error::Database(e.to_string()))?;
+	let exclude = json
+		.get(M::primary_key_field())
+		.and_then(primary_key_value)
+		.map(|pk| Filter::new(M::primary_key_field(), FilterOperator::Ne, pk));
+
+	let mut changed = false;
+	for field in &slug_fields {
+		let is_empty = match json.get(field.field) {
+			Some(JsonValue::String(slug)) => slug.is_empty(),
+			Some(JsonValue::Null) | None => true,
+			Some(_) => false,
+		};
+		if !is_empty {
+			continue;
+		}
+		let base = match json.get(field.source).and_then(JsonValue::as_str) {
+			Some(source) => slugify(source),
+			None => continue,
+		};
+		if base.is_empty() {
+			continue;
+		}
+
+		let slug = if field.unique {
+			unique_slug::<M>(field, &base, exclude.as_ref()).await?
+		} else {
+			slug_candidate(&base, 0, field.max_length)
+		};
+		json[field.field] = JsonValue::String(slug);
+		changed = true;
+	}
+
+	if changed {
+		*instance = serde_json::from_value(json).map_err(|e| Error::Database(e.to_string()))?;
+	}
+	Ok(())
+}
+
+/// First slug candidate not used by another row
+async fn unique_slug<M: Model>(
+	field: &SlugField,
+	base: &str,
+	exclude: Option<&Filter>,
+) -> Result<String> {
+	for attempt in 0..MAX_SLUG_ATTEMPTS {
+		let candidate = slug_candidate(base, attempt, field.max_length);
+		let mut queryset = M::objects().all().filter(Filter::new(
+			field.column,
+			FilterOperator::Eq,
+			FilterValue::String(candidate.clone()),
+		));
+		if let Some(exclude) = exclude {
+			queryset = queryset.filter(exclude.clone());
+		}
+		if !queryset.exists().await? {
+			return Ok(candidate);
+		}
+	}
+	Err(Error::Validation(format!(
+		"could not find a unique value for {}.{} after {} attempts",
+		M::table_name(),
+		field.field,
+		MAX_SLUG_ATTEMPTS
+	)))
+}
+
+/// Filter value of a serialized primary key
+fn primary_key_value(value: &JsonValue) -> Option<FilterValue> {
+	match value {
+		JsonValue::Number(n) => n.as_i64().map(FilterValue::Integer),
+		JsonValue::String(s) => Some(match uuid::Uuid::parse_str(s) {
+			Ok(uuid) => FilterValue::Uuid(uuid),
+			Err(_) => FilterValue::String(s.clone()),
+		}),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case("hello-world", 0, None, "hello-world")]
+	#[case("hello-world", 1, None, "hello-world-2")]
+	#[case("hello-world", 9, None, "hello-world-10")]
+	#[case("hello-world", 0, Some(5), "hello")]
+	#[case("hello-world", 1, Some(8), "hello-2")]
+	#[case("hello-world", 9, Some(11), "hello-wo-10")]
+	fn test_slug_candidate(
+		#[case] base: &str,
+		#[case] attempt: usize,
+		#[case] max_length: Option<usize>,
+		#[case] expected: &str,
+	) {
+		// Act
+		let candidate = slug_candidate(base, attempt, max_length);
+
+		// Assert
+		assert_eq!(candidate, expected);
+	}
+
+	#[rstest]
+	#[case(serde_json::json!(42), Some("Integer(42)"))]
+	#[case(serde_json::json!("a1b2"), Some("String(\"a1b2\")"))]
+	#[case(serde_json::json!(null), None)]
+	fn test_primary_key_value(#[case] value: JsonValue, #[case] expected: Option<&str>) {
+		// Act
+		let filter_value = primary_key_value(&value);
+
+		// Assert
+		assert_eq!(
+			filter_value.map(|v| format!("{:?}", v)).as_deref(),
+			expected
+		);
+	}
+}
@@ -0,0 +1,435 @@
+//! Row-level multi-tenancy
+//!
+//! Models declared with `#[model(tenant_field = "tenant_id")]` are bound to
+//! the tenant of the surrounding [`with_tenant`] scope: every [`QuerySet`]
+//! over them is filtered on `tenant_id = <tenant>`, and creates, updates and
+//! deletes through the [`Manager`] are restricted to (and stamp) that tenant.
+//! reinhardt-auth's `TenantMiddleware` opens one scope per request.
+//!
+//! Tenant-bound models fail closed: outside of any scope, queries match no
+//! rows and writes are rejected. Code that legitimately works across tenants
+//! (migrations, maintenance commands, cross-tenant reports) runs inside
+//! [`unscoped`].
+//!
+//! This is an alternative to schema-per-tenant isolation: all tenants share
+//! the same tables and are separated by a discriminator column.
+//!
+//! # Examples
+//!
+//! ```
+//! use reinhardt_db::orm::tenancy::{TenantId, current_tenant, unscoped, with_tenant};
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let tenant = with_tenant(7, async { current_tenant() }).await;
+//! assert_eq!(tenant, Some(TenantId::Int(7)));
+//!
+//! let tenant = unscoped(async { current_tenant() }).await;
+//! assert_eq!(tenant, None);
+//! # }
+//! ```
+//!
+//! [`QuerySet`]: super::QuerySet
+//! [`Manager`]: super::Manager
+
+use super::Model;
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_query::prelude::{Alias, Expr, ExprTrait, SimpleExpr};
+use serde_json::Value as JsonValue;
+use std::fmt;
+use std::future::Future;
+use uuid::Uuid;
+
+/// Identifier of a tenant, as stored in the tenant column
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TenantId {
+	/// Integer tenant key
+	Int(i64),
+	/// String tenant key (slugs, UUIDs)
+	Str(String),
+}
+
+impl TenantId {
+	/// Parse a raw identifier, keeping integers numeric
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_db::orm::tenancy::TenantId;
+	///
+	/// assert_eq!(TenantId::parse("42"), TenantId::Int(42));
+	/// assert_eq!(TenantId::parse("acme"), TenantId::Str("acme".to_string()));
+	/// ```
+	pub fn parse(raw: &str) -> Self {
+		match raw.parse::<i64>() {
+			Ok(n) => Self::Int(n),
+			Err(_) => Self::Str(raw.to_string()),
+		}
+	}
+
+	/// The identifier as a JSON value
+	pub fn to_json(&self) -> JsonValue {
+		match self {
+			Self::Int(n) => JsonValue::from(*n),
+			Self::Str(s) => JsonValue::from(s.as_str()),
+		}
+	}
+
+	/// The identifier as a query parameter
+	pub(crate) fn to_query_value(&self) -> reinhardt_query::value::Value {
+		match self {
+			Self::Int(n) => reinhardt_query::value::Value::BigInt(Some(*n)),
+			Self::Str(s) => match Uuid::parse_str(s) {
+				Ok(uuid) => reinhardt_query::value::Value::Uuid(Some(Box::new(uuid))),
+				Err(_) => s.clone().into(),
+			},
+		}
+	}
+
+	/// Whether a serialized column value holds this identifier
+	fn matches(&self, value: &JsonValue) -> bool {
+		match (self, value) {
+			(Self::Int(n), JsonValue::Number(v)) => v.as_i64() == Some(*n),
+			(Self::Int(n), JsonValue::String(v)) => v.parse::<i64>().ok() == Some(*n),
+			(Self::Str(s), JsonValue::String(v)) => v == s,
+			_ => false,
+		}
+	}
+}
+
+impl fmt::Display for TenantId {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Int(n) => write!(f, "{}", n),
+			Self::Str(s) => f.write_str(s),
+		}
+	}
+}
+
+impl From<i64> for TenantId {
+	fn from(value: i64) -> Self {
+		Self::Int(value)
+	}
+}
+
+impl From<i32> for TenantId {
+	fn from(value: i32) -> Self {
+		Self::Int(value.into())
+	}
+}
+
+impl From<String> for TenantId {
+	fn from(value: String) -> Self {
+		Self::Str(value)
+	}
+}
+
+impl From<&str> for TenantId {
+	fn from(value: &str) -> Self {
+		Self::Str(value.to_string())
+	}
+}
+
+impl From<Uuid> for TenantId {
+	fn from(value: Uuid) -> Self {
+		Self::Str(value.to_string())
+	}
+}
+
+#[derive(Debug, Clone)]
+enum TenantScope {
+	Tenant(TenantId),
+	Unscoped,
+}
+
+tokio::task_local! {
+	static CURRENT_TENANT: TenantScope;
+}
+
+/// Run `future` with queries on tenant-bound models restricted to `tenant`
+///
+/// Scopes nest: an inner [`with_tenant`] or [`unscoped`] replaces the outer
+/// scope for its duration.
+pub async fn with_tenant<F>(tenant: impl Into<TenantId>, future: F) -> F::Output
+where
+	F: Future,
+{
+	CURRENT_TENANT
+		.scope(TenantScope::Tenant(tenant.into()), future)
+		.await
+}
+
+/// Run `future` with tenant filtering disabled
+///
+/// This is the escape hatch for code that must see every tenant's rows, such
+/// as migrations, management commands and cross-tenant reporting. Writes to
+/// tenant-bound models inside this scope are not stamped, so the tenant
+/// column must be set explicitly.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_db::orm::tenancy::{is_unscoped, unscoped, with_tenant};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let bypassed = with_tenant(7, unscoped(async { is_unscoped() })).await;
+/// assert!(bypassed);
+/// # }
+/// ```
+pub async fn unscoped<F>(future: F) -> F::Output
+where
+	F: Future,
+{
+	CURRENT_TENANT.scope(TenantScope::Unscoped, future).await
+}
+
+/// The tenant of the surrounding [`with_tenant`] scope
+pub fn current_tenant() -> Option<TenantId> {
+	CURRENT_TENANT
+		.try_with(|scope| match scope {
+			TenantScope::Tenant(tenant) => Some(tenant.clone()),
+			TenantScope::Unscoped => None,
+		})
+		.ok()
+		.flatten()
+}
+
+/// Whether the current task runs inside [`unscoped`]
+pub fn is_unscoped() -> bool {
+	CURRENT_TENANT
+		.try_with(|scope| matches!(scope, TenantScope::Unscoped))
+		.unwrap_or(false)
+}
+
+/// How queries on a model are restricted by the current tenant scope
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum TenantFilter {
+	/// The model is not tenant-bound or the scope is [`unscoped`]
+	Unrestricted,
+	/// Only rows whose `column` holds `tenant` are visible
+	Tenant {
+		column: &'static str,
+		tenant: TenantId,
+	},
+	/// The model is tenant-bound but no tenant is in scope
+	Deny { column: &'static str },
+}
+
+/// Restriction applying to `M` in the current task
+pub(crate) fn tenant_filter<M: Model>() -> TenantFilter {
+	let Some(column) = M::tenant_field() else {
+		return TenantFilter::Unrestricted;
+	};
+	match CURRENT_TENANT.try_with(Clone::clone) {
+		Ok(TenantScope::Unscoped) => TenantFilter::Unrestricted,
+		Ok(TenantScope::Tenant(tenant)) => TenantFilter::Tenant { column, tenant },
+		Err(_) => TenantFilter::Deny { column },
+	}
+}
+
+/// WHERE predicate restricting reads of `M` to the current tenant
+///
+/// `None` when `M` is not tenant-bound or the scope is [`unscoped`]; `FALSE`
+/// when `M` is tenant-bound and no tenant is in scope.
+pub(crate) fn tenant_read_predicate<M: Model>() -> Option<SimpleExpr> {
+	match tenant_filter::<M>() {
+		TenantFilter::Unrestricted => None,
+		TenantFilter::Deny { .. } => Some(Expr::cust("FALSE").into_simple_expr()),
+		TenantFilter::Tenant { column, tenant } => {
+			Some(Expr::col(Alias::new(column)).eq(tenant.to_query_value()))
+		}
+	}
+}
+
+/// WHERE predicate restricting writes to `M` to the current tenant
+///
+/// `None` when `M` is not tenant-bound or the scope is [`unscoped`]; an error
+/// when `M` is tenant-bound and no tenant is in scope.
+pub(crate) fn tenant_write_predicate<M: Model>() -> Result<Option<SimpleExpr>> {
+	match tenant_filter::<M>() {
+		TenantFilter::Unrestricted => Ok(None),
+		TenantFilter::Deny { .. } => Err(no_tenant_error::<M>()),
+		TenantFilter::Tenant { column, tenant } => Ok(Some(
+			Expr::col(Alias::new(column)).eq(tenant.to_query_value()),
+		)),
+	}
+}
+
+/// Stamp the current tenant on a serialized instance about to be written
+///
+/// A missing or null tenant column is filled in; a column already holding a
+/// different tenant is rejected, as is any write to a tenant-bound model
+/// outside of a tenant scope.
+pub(crate) fn stamp_tenant<M: Model>(json: &mut JsonValue) -> Result<()> {
+	let (column, tenant) = match tenant_filter::<M>() {
+		TenantFilter::Unrestricted => return Ok(()),
+		TenantFilter::Deny { .. } => return Err(no_tenant_error::<M>()),
+		TenantFilter::Tenant { column, tenant } => (column, tenant),
+	};
+	let obj = json
+		.as_object_mut()
+		.ok_or_else(|| Error::Database("Model must serialize to object".to_string()))?;
+	match obj.get(column) {
+		None | Some(JsonValue::Null) => {
+			obj.insert(column.to_string(), tenant.to_json());
+			Ok(())
+		}
+		Some(value) if tenant.matches(value) => Ok(()),
+		Some(value) => Err(Error::Authorization(format!(
+			"{} instance belongs to tenant {} but the current tenant is {}",
+			M::table_name(),
+			value,
+			tenant
+		))),
+	}
+}
+
+/// Error for writes to a tenant-bound model outside of a tenant scope
+pub(crate) fn no_tenant_error<M: Model>() -> Error {
+	Error::Authorization(format!(
+		"{} is tenant-bound and no tenant is in scope; use with_tenant() or unscoped()",
+		M::table_name()
+	))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::orm::FieldSelector;
+	use rstest::rstest;
+	use serde::{Deserialize, Serialize};
+	use serde_json::json;
+
+	#[derive(Debug, Clone, Serialize, Deserialize)]
+	struct Invoice {
+		id: Option<i64>,
+		tenant_id: Option<i64>,
+	}
+
+	#[derive(Clone)]
+	struct InvoiceFields;
+
+	impl FieldSelector for InvoiceFields {
+		fn with_alias(self, _alias: &str) -> Self {
+			self
+		}
+	}
+
+	impl Model for Invoice {
+		type PrimaryKey = i64;
+		type Fields = InvoiceFields;
+		type Objects = crate::orm::Manager<Self>;
+
+		fn table_name() -> &'static str {
+			"invoices"
+		}
+
+		fn new_fields() -> Self::Fields {
+			InvoiceFields
+		}
+
+		fn primary_key(&self) -> Option<Self::PrimaryKey> {
+			self.id
+		}
+
+		fn set_primary_key(&mut self, value: Self::PrimaryKey) {
+			self.id = Some(value);
+		}
+
+		fn tenant_field() -> Option<&'static str> {
+			Some("tenant_id")
+		}
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_tenant_filter_follows_scope() {
+		// Act
+		let outside = tenant_filter::<Invoice>();
+		let scoped = with_tenant(7, async { tenant_filter::<Invoice>() }).await;
+		let bypassed = with_tenant(7, unscoped(async { tenant_filter::<Invoice>() })).await;
+
+		// Assert
+		assert_eq!(
+			outside,
+			TenantFilter::Deny {
+				column: "tenant_id"
+			}
+		);
+		assert_eq!(
+			scoped,
+			TenantFilter::Tenant {
+				column: "tenant_id",
+				tenant: TenantId::Int(7)
+			}
+		);
+		assert_eq!(bypassed, TenantFilter::Unrestricted);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_nested_scope_replaces_outer_tenant() {
+		// Act
+		let tenant = with_tenant(7, with_tenant("acme", async { current_tenant() })).await;
+
+		// Assert
+		assert_eq!(tenant, Some(TenantId::Str("acme".to_string())));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_stamp_tenant_fills_missing_tenant() {
+		// Arrange
+		let mut json = json!({"id": null, "tenant_id": null});
+
+		// Act
+		with_tenant(7, async { stamp_tenant::<Invoice>(&mut json) })
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(json["tenant_id"], json!(7));
+	}
+
+	#[rstest]
+	#[case::same_tenant(json!(7), true)]
+	#[case::other_tenant(json!(8), false)]
+	#[tokio::test]
+	async fn test_stamp_tenant_rejects_cross_tenant_writes(
+		#[case] tenant_id: JsonValue,
+		#[case] allowed: bool,
+	) {
+		// Arrange
+		let mut json = json!({"id": 1, "tenant_id": tenant_id});
+
+		// Act
+		let result = with_tenant(7, async { stamp_tenant::<Invoice>(&mut json) }).await;
+
+		// Assert
+		assert_eq!(result.is_ok(), allowed);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_stamp_tenant_outside_scope_fails_closed() {
+		// Arrange
+		let mut json = json!({"id": null, "tenant_id": 7});
+
+		// Act
+		let outside = stamp_tenant::<Invoice>(&mut json);
+		let bypassed = unscoped(async { stamp_tenant::<Invoice>(&mut json) }).await;
+
+		// Assert
+		assert!(matches!(outside, Err(Error::Authorization(_))));
+		assert!(bypassed.is_ok());
+	}
+
+	#[rstest]
+	#[case("42", TenantId::Int(42))]
+	#[case("acme", TenantId::Str("acme".to_string()))]
+	fn test_tenant_id_parse(#[case] raw: &str, #[case] expected: TenantId) {
+		// Act & Assert
+		assert_eq!(TenantId::parse(raw), expected);
+	}
+}
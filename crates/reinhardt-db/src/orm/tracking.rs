@@ -0,0 +1,296 @@
+//! Dirty-field tracking for model instances
+//!
+//! Every instance loaded through a [`QuerySet`](super::QuerySet) or written by
+//! [`Model::save`] has its field values remembered as the *original* state,
+//! keyed by table and primary key. [`Model::dirty_fields`] and
+//! [`Model::diff`] compare an instance against that state, which makes them
+//! usable anywhere the instance is at hand — `pre_save` receivers, event
+//! listeners, audit hooks — and lets [`Model::save_update_fields`] write only
+//! the columns that actually changed.
+//!
+//! The originals live in a bounded, process-wide table; the oldest entries
+//! are evicted once [`set_tracking_capacity`] is exceeded. An instance whose
+//! original was evicted, or that was never loaded, reports every field as
+//! dirty, so callers always err on the side of writing too much.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use reinhardt_db::orm::Model;
+//! # async fn example<Article: Model>(mut article: Article) -> reinhardt_core::exception::Result<()> {
+//! // `article` was loaded with `Article::objects().get(pk).first().await?`
+//! for change in article.diff() {
+//!     println!("{}: {:?} -> {:?}", change.field, change.old, change.new);
+//! }
+//! article.save_update_fields(&["title"]).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::Model;
+use super::audit::{FieldChange, diff_fields};
+use parking_lot::RwLock;
+use serde_json::Value as JsonValue;
+use std::collections::{HashMap, VecDeque};
+use std::sync::LazyLock;
+
+/// Default number of originals kept before the oldest are evicted
+pub const DEFAULT_TRACKING_CAPACITY: usize = 10_000;
+
+type TrackingKey = (String, String);
+
+static ORIGINALS: LazyLock<RwLock<Originals>> =
+	LazyLock::new(|| RwLock::new(Originals::new(DEFAULT_TRACKING_CAPACITY)));
+
+struct Originals {
+	capacity: usize,
+	values: HashMap<TrackingKey, JsonValue>,
+	order: VecDeque<TrackingKey>,
+}
+
+impl Originals {
+	fn new(capacity: usize) -> Self {
+		Self {
+			capacity,
+			values: HashMap::new(),
+			order: VecDeque::new(),
+		}
+	}
+
+	fn insert(&mut self, key: TrackingKey, value: JsonValue) {
+		if self.capacity == 0 {
+			return;
+		}
+		if self.values.insert(key.clone(), value).is_none() {
+			self.order.push_back(key);
+		}
+		self.shrink_to_capacity();
+	}
+
+	fn remove(&mut self, key: &TrackingKey) {
+		if self.values.remove(key).is_some() {
+			self.order.retain(|k| k != key);
+		}
+	}
+
+	fn shrink_to_capacity(&mut self) {
+		while self.values.len() > self.capacity {
+			match self.order.pop_front() {
+				Some(oldest) => {
+					self.values.remove(&oldest);
+				}
+				None => break,
+			}
+		}
+	}
+}
+
+fn key_for<M: Model>(instance: &M) -> Option<TrackingKey> {
+	instance
+		.primary_key()
+		.map(|pk| (M::table_name().to_string(), pk.to_string()))
+}
+
+/// Set how many originals are kept before the oldest are evicted
+///
+/// A capacity of `0` disables tracking: every instance then reports all of
+/// its fields as dirty.
+pub fn set_tracking_capacity(capacity: usize) {
+	let mut originals = ORIGINALS.write();
+	originals.capacity = capacity;
+	originals.shrink_to_capacity();
+}
+
+/// Remember the current state of `instance` as its original state
+///
+/// Called whenever an instance is loaded from or written to the database.
+/// Instances without a primary key are ignored.
+pub fn mark_clean<M: Model>(instance: &M) {
+	let Some(key) = key_for(instance) else {
+		return;
+	};
+	if let Ok(value) = serde_json::to_value(instance) {
+		ORIGINALS.write().insert(key, value);
+	}
+}
+
+/// Forget the original state of `instance`, e.g. after it was deleted
+pub fn forget<M: Model>(instance: &M) {
+	if let Some(key) = key_for(instance) {
+		ORIGINALS.write().remove(&key);
+	}
+}
+
+/// The field values `instance` had when it was last loaded or saved
+///
+/// Returns `None` if the instance has no primary key or its original is not
+/// tracked.
+pub fn original_values<M: Model>(instance: &M) -> Option<JsonValue> {
+	let key = key_for(instance)?;
+	ORIGINALS.read().values.get(&key).cloned()
+}
+
+/// The original field values of a row identified by table and primary key
+///
+/// For event listeners that only receive the serialized instance.
+pub fn original_values_for(table: &str, pk: &str) -> Option<JsonValue> {
+	ORIGINALS
+		.read()
+		.values
+		.get(&(table.to_string(), pk.to_string()))
+		.cloned()
+}
+
+/// Field-level changes of `instance` since it was last loaded or saved
+///
+/// Every field is reported if the original is not tracked.
+pub fn diff<M: Model>(instance: &M) -> Vec<FieldChange> {
+	let current = serde_json::to_value(instance).ok();
+	diff_fields(original_values(instance).as_ref(), current.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::orm::Manager;
+	use rstest::rstest;
+	use serde::{Deserialize, Serialize};
+	use serde_json::json;
+
+	#[derive(Debug, Clone, Serialize, Deserialize)]
+	struct Article {
+		id: Option<i64>,
+		title: String,
+		views: i64,
+	}
+
+	#[derive(Clone)]
+	struct ArticleFields;
+	impl crate::orm::model::FieldSelector for ArticleFields {
+		fn with_alias(self, _alias: &str) -> Self {
+			self
+		}
+	}
+
+	impl Model for Article {
+		type PrimaryKey = i64;
+		type Fields = ArticleFields;
+		type Objects = Manager<Self>;
+
+		fn table_name() -> &'static str {
+			"tracking_test_articles"
+		}
+
+		fn new_fields() -> Self::Fields {
+			ArticleFields
+		}
+
+		fn primary_key(&self) -> Option<Self::PrimaryKey> {
+			self.id
+		}
+
+		fn set_primary_key(&mut self, value: Self::PrimaryKey) {
+			self.id = Some(value);
+		}
+	}
+
+	fn key(pk: &str) -> TrackingKey {
+		("t".to_string(), pk.to_string())
+	}
+
+	fn article(id: i64) -> Article {
+		Article {
+			id: Some(id),
+			title: "Draft".to_string(),
+			views: 0,
+		}
+	}
+
+	#[rstest]
+	fn test_dirty_fields_after_load() {
+		// Arrange
+		let mut instance = article(1);
+		mark_clean(&instance);
+
+		// Act
+		instance.title = "Published".to_string();
+
+		// Assert
+		assert_eq!(instance.dirty_fields(), vec!["title".to_string()]);
+		let changes = instance.diff();
+		assert_eq!(changes[0].old, Some(json!("Draft")));
+		assert_eq!(changes[0].new, Some(json!("Published")));
+		assert_eq!(
+			original_values_for("tracking_test_articles", "1"),
+			Some(json!({"id": 1, "title": "Draft", "views": 0}))
+		);
+	}
+
+	#[rstest]
+	fn test_clean_instance_has_no_dirty_fields() {
+		// Arrange
+		let instance = article(2);
+
+		// Act
+		mark_clean(&instance);
+
+		// Assert
+		assert!(instance.dirty_fields().is_empty());
+	}
+
+	#[rstest]
+	fn test_untracked_instance_reports_every_field() {
+		// Arrange
+		let instance = article(3);
+		mark_clean(&instance);
+
+		// Act
+		forget(&instance);
+
+		// Assert
+		assert_eq!(instance.dirty_fields(), vec!["id", "title", "views"]);
+	}
+
+	#[rstest]
+	fn test_originals_evict_oldest_beyond_capacity() {
+		// Arrange
+		let mut originals = Originals::new(2);
+
+		// Act
+		originals.insert(key("1"), json!(1));
+		originals.insert(key("2"), json!(2));
+		originals.insert(key("1"), json!(10));
+		originals.insert(key("3"), json!(3));
+
+		// Assert
+		assert_eq!(originals.values.len(), 2);
+		assert!(!originals.values.contains_key(&key("1")));
+		assert_eq!(originals.values[&key("3")], json!(3));
+	}
+
+	#[rstest]
+	fn test_originals_disabled_with_zero_capacity() {
+		// Arrange
+		let mut originals = Originals::new(0);
+
+		// Act
+		originals.insert(key("1"), json!(1));
+
+		// Assert
+		assert!(originals.values.is_empty());
+	}
+
+	#[rstest]
+	fn test_originals_remove_drops_order_entry() {
+		// Arrange
+		let mut originals = Originals::new(2);
+		originals.insert(key("1"), json!(1));
+
+		// Act
+		originals.remove(&key("1"));
+
+		// Assert
+		assert!(originals.values.is_empty());
+		assert!(originals.order.is_empty());
+	}
+}
@@ -0,0 +1,352 @@
+//! Per-language field values
+//!
+//! A [`TranslatedModel`] stores selected fields once per language, in extra
+//! columns next to the original one: the base column (`title`) holds the
+//! value in the default language and `title_ja`, `title_pt_br`, ... hold the
+//! other languages (see [`translation_column`]). The model struct declares
+//! those columns as optional fields, so they are created by the regular
+//! migrations.
+//!
+//! [`TranslatedModel::translated`] reads a field in the active language,
+//! falling back to the base language (`pt` for `pt-BR`) and then to the
+//! default language when a translation is missing. [`TranslatedQuerySetExt`]
+//! filters and orders querysets on the active language's column.
+//!
+//! The active language comes from the provider installed with
+//! [`set_language_provider`], typically `reinhardt_i18n::get_language`.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use reinhardt_db::orm::translation::{TranslatedModel, TranslatedQuerySetExt};
+//!
+//! #[model(app_label = "blog", table_name = "articles")]
+//! pub struct Article {
+//!     #[field(primary_key = true)]
+//!     id: Option<i64>,
+//!     #[field(max_length = 200)]
+//!     title: String,
+//!     #[field(max_length = 200, null = true)]
+//!     title_ja: Option<String>,
+//!     #[field(max_length = 200, null = true)]
+//!     title_fr: Option<String>,
+//! }
+//!
+//! impl TranslatedModel for Article {
+//!     fn translated_fields() -> &'static [&'static str] {
+//!         &["title"]
+//!     }
+//!
+//!     fn languages() -> &'static [&'static str] {
+//!         &["en", "ja", "fr"]
+//!     }
+//! }
+//!
+//! reinhardt_db::orm::translation::set_language_provider(reinhardt_i18n::get_language);
+//!
+//! let articles = Article::objects()
+//!     .all()
+//!     .order_by_translated(&["title"])
+//!     .all()
+//!     .await?;
+//! let title = articles[0].translated("title");
+//! ```
+
+use super::Model;
+use super::expressions::{F, OrderBy};
+use super::query::{Filter, FilterOperator, FilterValue, QuerySet};
+use serde::Serialize;
+use std::sync::OnceLock;
+
+/// Function returning the active language code, e.g. `pt-BR`
+pub type LanguageProvider = fn() -> String;
+
+static LANGUAGE_PROVIDER: OnceLock<LanguageProvider> = OnceLock::new();
+
+/// Install the function returning the active language.
+///
+/// Only the first call takes effect; returns `false` if a provider was
+/// already installed.
+pub fn set_language_provider(provider: LanguageProvider) -> bool {
+	LANGUAGE_PROVIDER.set(provider).is_ok()
+}
+
+/// The active language, if a provider is installed
+pub fn active_language() -> Option<String> {
+	LANGUAGE_PROVIDER.get().map(|provider| provider())
+}
+
+/// Column storing `field` in `language` (`title`, `pt-BR` → `title_pt_br`)
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_db::orm::translation::translation_column;
+///
+/// assert_eq!(translation_column("title", "ja"), "title_ja");
+/// assert_eq!(translation_column("title", "pt-BR"), "title_pt_br");
+/// ```
+pub fn translation_column(field: &str, language: &str) -> String {
+	format!("{}_{}", field, language.to_lowercase().replace('-', "_"))
+}
+
+/// Model with fields stored once per language
+///
+/// Implementors list the translated fields and the supported languages;
+/// the first language is the default one, stored in the base column.
+pub trait TranslatedModel: Model + Serialize {
+	/// Fields stored once per language
+	fn translated_fields() -> &'static [&'static str];
+
+	/// Supported language codes; the first one is the default language
+	fn languages() -> &'static [&'static str];
+
+	/// The default language
+	fn default_language() -> &'static str {
+		Self::languages().first().copied().unwrap_or("en")
+	}
+
+	/// The supported language matching `language`, if any
+	///
+	/// Matching ignores case, and a regional variant (`pt-BR`) matches its
+	/// base language (`pt`) when the variant itself is not supported.
+	fn resolve_language(language: &str) -> Option<&'static str> {
+		let find = |code: &str| {
+			Self::languages()
+				.iter()
+				.copied()
+				.find(|supported| supported.eq_ignore_ascii_case(code))
+		};
+		find(language).or_else(|| find(language.split(['-', '_']).next()?))
+	}
+
+	/// Column storing `field` in `language`
+	///
+	/// The default language is stored in the base column. Returns `None` for
+	/// unsupported languages.
+	fn column_for(field: &str, language: &str) -> Option<String> {
+		let language = Self::resolve_language(language)?;
+		if language == Self::default_language() {
+			Some(field.to_string())
+		} else {
+			Some(translation_column(field, language))
+		}
+	}
+
+	/// Languages tried, in order, when reading in `language`
+	///
+	/// Defaults to the language itself, its base language and the default
+	/// language.
+	fn fallback_languages(language: &str) -> Vec<&'static str> {
+		let mut languages = Vec::new();
+		let base = language.split(['-', '_']).next().unwrap_or(language);
+		let candidates = [
+			Self::resolve_language(language),
+			Self::resolve_language(base),
+			Some(Self::default_language()),
+		];
+		for candidate in candidates.into_iter().flatten() {
+			if !languages.contains(&candidate) {
+				languages.push(candidate);
+			}
+		}
+		languages
+	}
+
+	/// `field` in the active language, with fallbacks
+	///
+	/// Uses the default language when no language provider is installed.
+	fn translated(&self, field: &str) -> Option<String> {
+		let language = active_language().unwrap_or_else(|| Self::default_language().to_string());
+		self.translated_in(field, &language)
+	}
+
+	/// `field` in `language`, falling back as described by
+	/// [`fallback_languages`](TranslatedModel::fallback_languages)
+	///
+	/// Empty translations count as missing.
+	fn translated_in(&self, field: &str, language: &str) -> Option<String> {
+		let value = serde_json::to_value(self).ok()?;
+		Self::fallback_languages(language)
+			.into_iter()
+			.filter_map(|language| Self::column_for(field, language))
+			.find_map(|column| match value.get(&column)? {
+				serde_json::Value::String(text) if !text.is_empty() => Some(text.clone()),
+				serde_json::Value::Null | serde_json::Value::String(_) => None,
+				other => Some(other.to_string()),
+			})
+	}
+
+	/// Every column of every translated field, default language first
+	fn translation_columns() -> Vec<(&'static str, &'static str, String)> {
+		Self::translated_fields()
+			.iter()
+			.flat_map(|field| {
+				Self::languages().iter().filter_map(move |language| {
+					Self::column_for(field, language).map(|column| (*field, *language, column))
+				})
+			})
+			.collect()
+	}
+}
+
+/// Column of `field` in the active language
+fn active_column<T: TranslatedModel>(field: &str) -> String {
+	active_language()
+		.and_then(|language| T::column_for(field, &language))
+		.unwrap_or_else(|| field.to_string())
+}
+
+/// Filters and ordering on translated fields
+pub trait TranslatedQuerySetExt: Sized {
+	/// Filter on `field` in the active language
+	///
+	/// Untranslated fields are filtered as is.
+	fn filter_translated(self, field: &str, operator: FilterOperator, value: FilterValue) -> Self;
+
+	/// Order by translated fields in the active language
+	///
+	/// Like [`QuerySet::order_by`], a `-` prefix sorts descending. Missing
+	/// translations sort by the default language's value.
+	fn order_by_translated(self, fields: &[&str]) -> Self;
+}
+
+impl<T: TranslatedModel> TranslatedQuerySetExt for QuerySet<T> {
+	fn filter_translated(self, field: &str, operator: FilterOperator, value: FilterValue) -> Self {
+		let column = if T::translated_fields().contains(&field) {
+			active_column::<T>(field)
+		} else {
+			field.to_string()
+		};
+		self.filter(Filter::new(column, operator, value))
+	}
+
+	fn order_by_translated(self, fields: &[&str]) -> Self {
+		let ordering = fields.iter().map(|spec| {
+			let (field, descending) = match spec.strip_prefix('-') {
+				Some(field) => (field, true),
+				None => (*spec, false),
+			};
+			let column = active_column::<T>(field);
+			let order = if !T::translated_fields().contains(&field) || column == field {
+				OrderBy::field(field)
+			} else {
+				OrderBy::expression(format!(
+					"COALESCE(NULLIF({}, ''), {})",
+					F::new(column).to_sql(),
+					F::new(field).to_sql()
+				))
+			};
+			if descending { order.desc() } else { order }
+		});
+		self.order_by_expressions(ordering.collect::<Vec<_>>())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use serde::Deserialize;
+
+	#[derive(Debug, Clone, Serialize, Deserialize)]
+	struct Article {
+		id: Option<i64>,
+		title: String,
+		title_ja: Option<String>,
+		title_pt_br: Option<String>,
+		views: i64,
+	}
+
+	#[derive(Clone)]
+	struct ArticleFields;
+
+	impl crate::orm::model::FieldSelector for ArticleFields {
+		fn with_alias(self, _alias: &str) -> Self {
+			self
+		}
+	}
+
+	impl Model for Article {
+		type PrimaryKey = i64;
+		type Fields = ArticleFields;
+		type Objects = crate::orm::Manager<Self>;
+
+		fn table_name() -> &'static str {
+			"articles"
+		}
+
+		fn new_fields() -> Self::Fields {
+			ArticleFields
+		}
+
+		fn primary_key(&self) -> Option<Self::PrimaryKey> {
+			self.id
+		}
+
+		fn set_primary_key(&mut self, key: Self::PrimaryKey) {
+			self.id = Some(key);
+		}
+	}
+
+	impl TranslatedModel for Article {
+		fn translated_fields() -> &'static [&'static str] {
+			&["title"]
+		}
+
+		fn languages() -> &'static [&'static str] {
+			&["en", "ja", "pt-BR"]
+		}
+	}
+
+	fn article() -> Article {
+		Article {
+			id: Some(1),
+			title: "Hello".to_string(),
+			title_ja: Some("こんにちは".to_string()),
+			title_pt_br: Some(String::new()),
+			views: 3,
+		}
+	}
+
+	#[rstest]
+	#[case::exact("ja", Some("こんにちは"))]
+	#[case::regional_variant("ja-JP", Some("こんにちは"))]
+	#[case::empty_translation("pt-BR", Some("Hello"))]
+	#[case::unsupported("de", Some("Hello"))]
+	fn test_translated_in_falls_back(#[case] language: &str, #[case] expected: Option<&str>) {
+		// Act
+		let title = article().translated_in("title", language);
+
+		// Assert
+		assert_eq!(title.as_deref(), expected);
+	}
+
+	#[rstest]
+	fn test_translation_columns_cover_every_language() {
+		// Act
+		let columns = Article::translation_columns();
+
+		// Assert
+		assert_eq!(
+			columns,
+			vec![
+				("title", "en", "title".to_string()),
+				("title", "ja", "title_ja".to_string()),
+				("title", "pt-BR", "title_pt_br".to_string()),
+			]
+		);
+	}
+
+	#[rstest]
+	fn test_untranslated_fields_are_queried_as_is() {
+		// Act
+		let qs = QuerySet::<Article>::new()
+			.filter_translated("views", FilterOperator::Gt, FilterValue::Integer(2))
+			.order_by_translated(&["-views"]);
+
+		// Assert
+		assert_eq!(qs.filters()[0].field, "views");
+		assert!(qs.to_sql().ends_with(r#"ORDER BY "views" DESC"#));
+	}
+}
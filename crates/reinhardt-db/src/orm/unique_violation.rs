@@ -0,0 +1,281 @@
+//! Unique-constraint violations as validation errors
+//!
+//! Saving a model can fail on a unique constraint even after validation
+//! passed, e.g. when two requests race for the same username. This module
+//! recognizes the violation in the error reported by PostgreSQL, SQLite or
+//! MySQL and turns it into [`ValidationErrors`], so forms and serializers
+//! report it on the offending field like any other validation failure.
+//!
+//! ```
+//! use reinhardt_db::backends::DatabaseError;
+//! use reinhardt_db::orm::unique_violation::validation_errors_from_db_error;
+//!
+//! let error = DatabaseError::QueryError(
+//!     "UNIQUE constraint failed: users.email".to_string(),
+//! );
+//! let errors = validation_errors_from_db_error(&error, &[]).unwrap();
+//! assert_eq!(errors.get("email")[0].code(), "unique");
+//! ```
+
+use super::constraints::UniqueConstraint;
+use crate::backends::DatabaseError;
+use reinhardt_core::validators::{ValidationError, ValidationErrors};
+
+/// A unique-constraint violation recognized in a database error message
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UniqueViolation {
+	/// Name of the violated constraint, when the database reports it
+	pub constraint: Option<String>,
+	/// Columns of the constraint, when the database reports them
+	pub fields: Vec<String>,
+	/// Duplicate value, when the database reports it
+	pub value: Option<String>,
+}
+
+impl UniqueViolation {
+	/// Recognize a unique violation in a database error message
+	///
+	/// Understands the messages of PostgreSQL (`duplicate key value violates
+	/// unique constraint "..."`, with the optional `Key (...)=(...)` detail),
+	/// SQLite (`UNIQUE constraint failed: table.column, ...`) and MySQL
+	/// (`Duplicate entry '...' for key '...'`).
+	pub fn parse(message: &str) -> Option<Self> {
+		if let Some(rest) = after(message, "UNIQUE constraint failed:") {
+			let fields = rest
+				.split(',')
+				.map(|column| {
+					let column = column.trim();
+					column.rsplit('.').next().unwrap_or(column).to_string()
+				})
+				.filter(|column| !column.is_empty())
+				.collect();
+			return Some(Self {
+				fields,
+				..Self::default()
+			});
+		}
+
+		if let Some(rest) = after(message, "violates unique constraint") {
+			let mut violation = Self {
+				constraint: quoted(rest, '"').map(str::to_string),
+				..Self::default()
+			};
+			if let Some(detail) = after(rest, "Key (")
+				&& let Some((columns, values)) = detail.split_once(")=(")
+			{
+				violation.fields = columns.split(',').map(|c| c.trim().to_string()).collect();
+				violation.value = values.split_once(')').map(|(value, _)| value.to_string());
+			}
+			return Some(violation);
+		}
+
+		if let Some(rest) = after(message, "Duplicate entry") {
+			let value = quoted(rest, '\'').map(str::to_string);
+			let constraint = after(rest, "for key")
+				.and_then(|key| quoted(key, '\''))
+				.map(|key| key.rsplit('.').next().unwrap_or(key).to_string());
+			return Some(Self {
+				constraint,
+				value,
+				..Self::default()
+			});
+		}
+
+		None
+	}
+
+	/// Recognize a unique violation in a database error
+	///
+	/// Reads [`DatabaseError::UniqueViolation`] as is, and parses the
+	/// message of untyped query errors.
+	pub fn from_error(error: &DatabaseError) -> Option<Self> {
+		match error {
+			DatabaseError::UniqueViolation {
+				constraint,
+				columns,
+			} => Some(Self {
+				constraint: constraint.clone(),
+				fields: columns.clone(),
+				value: None,
+			}),
+			DatabaseError::QueryError(message) | DatabaseError::Other(message) => {
+				Self::parse(message)
+			}
+			_ => None,
+		}
+	}
+
+	/// Convert to validation errors
+	///
+	/// Columns missing from the message are looked up by constraint name in
+	/// `constraints`. A single column is reported as
+	/// [`ValidationError::NotUnique`] on that field; several columns as a
+	/// `unique_together` non-field error.
+	pub fn to_validation_errors(&self, constraints: &[UniqueConstraint]) -> ValidationErrors {
+		let fields = if self.fields.is_empty() {
+			self.constraint
+				.as_deref()
+				.and_then(|name| constraints.iter().find(|c| c.name == name))
+				.map(|constraint| constraint.fields.clone())
+				.unwrap_or_default()
+		} else {
+			self.fields.clone()
+		};
+
+		let mut errors = ValidationErrors::new();
+		match fields.as_slice() {
+			[field] => errors.add(
+				field.clone(),
+				ValidationError::NotUnique {
+					field: field.clone(),
+					value: self.value.clone().unwrap_or_default(),
+				},
+			),
+			[] => errors.add_non_field(ValidationError::coded(
+				"unique",
+				"A record with these values already exists.",
+			)),
+			fields => errors.add_non_field(
+				ValidationError::coded(
+					"unique_together",
+					format!("The combination of {} must be unique.", fields.join(", ")),
+				)
+				.with_param("fields", fields.join(", ")),
+			),
+		}
+		errors
+	}
+}
+
+/// Validation errors for a unique violation reported by the database
+///
+/// Returns `None` when `error` is not a unique violation.
+pub fn validation_errors_from_db_error(
+	error: &DatabaseError,
+	constraints: &[UniqueConstraint],
+) -> Option<ValidationErrors> {
+	UniqueViolation::from_error(error).map(|violation| violation.to_validation_errors(constraints))
+}
+
+/// Text after the first occurrence of `marker`
+fn after<'a>(text: &'a str, marker: &str) -> Option<&'a str> {
+	text.find(marker).map(|index| &text[index + marker.len()..])
+}
+
+/// First text enclosed in `quote`
+fn quoted(text: &str, quote: char) -> Option<&str> {
+	let start = text.find(quote)? + 1;
+	let len = text[start..].find(quote)?;
+	Some(&text[start..start + len])
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case::sqlite(
+		"UNIQUE constraint failed: users.email",
+		UniqueViolation { fields: vec!["email".to_string()], ..Default::default() }
+	)]
+	#[case::sqlite_composite(
+		"UNIQUE constraint failed: memberships.user_id, memberships.group_id",
+		UniqueViolation {
+			fields: vec!["user_id".to_string(), "group_id".to_string()],
+			..Default::default()
+		}
+	)]
+	#[case::postgres(
+		"duplicate key value violates unique constraint \"users_email_key\"",
+		UniqueViolation { constraint: Some("users_email_key".to_string()), ..Default::default() }
+	)]
+	#[case::postgres_detail(
+		"duplicate key value violates unique constraint \"users_email_key\" \
+		 DETAIL: Key (email)=(a@example.com) already exists.",
+		UniqueViolation {
+			constraint: Some("users_email_key".to_string()),
+			fields: vec!["email".to_string()],
+			value: Some("a@example.com".to_string()),
+		}
+	)]
+	#[case::mysql(
+		"Duplicate entry 'a@example.com' for key 'users.email_unique'",
+		UniqueViolation {
+			constraint: Some("email_unique".to_string()),
+			value: Some("a@example.com".to_string()),
+			..Default::default()
+		}
+	)]
+	fn test_parse(#[case] message: &str, #[case] expected: UniqueViolation) {
+		// Act
+		let violation = UniqueViolation::parse(message);
+
+		// Assert
+		assert_eq!(violation, Some(expected));
+	}
+
+	#[rstest]
+	fn test_parse_ignores_other_errors() {
+		// Act / Assert
+		assert_eq!(UniqueViolation::parse("no such table: users"), None);
+		assert!(
+			validation_errors_from_db_error(
+				&DatabaseError::ConnectionError("UNIQUE constraint failed: a.b".to_string()),
+				&[]
+			)
+			.is_none()
+		);
+	}
+
+	#[rstest]
+	fn test_typed_unique_violation() {
+		// Arrange
+		let error = DatabaseError::UniqueViolation {
+			constraint: Some("users_email_key".to_string()),
+			columns: vec!["email".to_string()],
+		};
+
+		// Act
+		let errors = validation_errors_from_db_error(&error, &[]).unwrap();
+
+		// Assert
+		assert_eq!(errors.get("email")[0].code(), "unique");
+	}
+
+	#[rstest]
+	fn test_constraint_name_resolves_fields() {
+		// Arrange
+		let constraints = [UniqueConstraint::new(
+			"email_unique",
+			vec!["email".to_string()],
+		)];
+		let error = DatabaseError::QueryError(
+			"Duplicate entry 'a@example.com' for key 'users.email_unique'".to_string(),
+		);
+
+		// Act
+		let errors = validation_errors_from_db_error(&error, &constraints).unwrap();
+
+		// Assert
+		let email = &errors.get("email")[0];
+		assert_eq!(email.code(), "unique");
+		assert_eq!(email.params()["value"], "a@example.com");
+	}
+
+	#[rstest]
+	fn test_composite_violation_is_non_field_error() {
+		// Arrange
+		let violation = UniqueViolation {
+			fields: vec!["user_id".to_string(), "group_id".to_string()],
+			..Default::default()
+		};
+
+		// Act
+		let errors = violation.to_validation_errors(&[]);
+
+		// Assert
+		assert!(errors.field_errors().is_empty());
+		assert_eq!(errors.non_field_errors()[0].code(), "unique_together");
+	}
+}
@@ -223,6 +223,29 @@ fn blanket_impl_get_returns_pk_filtered_queryset() {
 	assert_eq!(qs.filters()[0].field, "id");
 }
 
+#[rstest]
+fn default_queryset_is_kept_when_chaining_builders() {
+	// Arrange
+	let manager = ActiveArticleManager;
+
+	// Act
+	let filtered = manager.filter(Filter::new(
+		"title",
+		FilterOperator::Eq,
+		FilterValue::String("rust".into()),
+	));
+	let by_pk = manager.get(7_i64);
+	let ordered = manager.order_by(&["-id"]).limit(5);
+
+	// Assert: the `is_archived` restriction from `all()` comes first
+	let fields = |qs: &QuerySet<Article>| -> Vec<String> {
+		qs.filters().iter().map(|f| f.field.clone()).collect()
+	};
+	assert_eq!(fields(&filtered), vec!["is_archived", "title"]);
+	assert_eq!(fields(&by_pk), vec!["is_archived", "id"]);
+	assert_eq!(fields(&ordered), vec!["is_archived"]);
+}
+
 // -----------------------------------------------------------------------------
 // Tests: Model::objects() dispatch via type Objects
 // -----------------------------------------------------------------------------
@@ -474,8 +497,11 @@ fn bulk_create_sql_via_trait_matches_inherent_method() {
 	];
 
 	// Act
-	let inherent_sql = manager.bulk_create_sql(&models, DatabaseBackend::Postgres);
-	let trait_sql = CustomManager::bulk_create_sql(&manager, &models, DatabaseBackend::Postgres);
+	let inherent_sql = manager
+		.bulk_create_sql(&models, DatabaseBackend::Postgres)
+		.unwrap();
+	let trait_sql =
+		CustomManager::bulk_create_sql(&manager, &models, DatabaseBackend::Postgres).unwrap();
 
 	// Assert
 	assert_eq!(inherent_sql, trait_sql);
@@ -516,8 +542,8 @@ fn bulk_create_sql_parity_across_backends(#[case] backend: DatabaseBackend) {
 	}];
 
 	// Act
-	let inherent_sql = manager.bulk_create_sql(&models, backend);
-	let trait_sql = CustomManager::bulk_create_sql(&manager, &models, backend);
+	let inherent_sql = manager.bulk_create_sql(&models, backend).unwrap();
+	let trait_sql = CustomManager::bulk_create_sql(&manager, &models, backend).unwrap();
 
 	// Assert: trait path matches inherent path on every supported backend.
 	assert_eq!(inherent_sql, trait_sql);
@@ -596,8 +622,12 @@ fn user_defined_manager_inherits_sql_builders_via_default_impl() {
 	}];
 
 	// Act
-	let custom_sql = active.bulk_create_sql(&models, DatabaseBackend::Postgres);
-	let canonical_sql = manager.bulk_create_sql(&models, DatabaseBackend::Postgres);
+	let custom_sql = active
+		.bulk_create_sql(&models, DatabaseBackend::Postgres)
+		.unwrap();
+	let canonical_sql = manager
+		.bulk_create_sql(&models, DatabaseBackend::Postgres)
+		.unwrap();
 
 	// Assert: bit-exact SQL output regardless of dispatch path.
 	assert_eq!(custom_sql, canonical_sql);
@@ -1,7 +1,7 @@
 //! Injection context for dependency resolution
 
 use crate::function_handle::FunctionHandle;
-use crate::override_registry::OverrideRegistry;
+use crate::override_registry::{OverrideRegistry, ScopedOverride};
 use crate::scope::{RequestScope, SingletonScope};
 #[cfg(feature = "params")]
 use reinhardt_http::Request as HttpRequest;
@@ -187,8 +187,24 @@ impl InjectionContextBuilder {
 
 impl Clone for InjectionContext {
 	fn clone(&self) -> Self {
-		Self {
-			request_scope: self.request_scope.deep_clone(),
+		self.with_request_scope(self.request_scope.deep_clone())
+	}
+}
+
+impl InjectionContext {
+	/// Creates a handle to this context that shares its request scope.
+	///
+	/// Unlike [`Clone`], which gives the copy an independent request cache,
+	/// the returned context reads and writes the same request scope, so
+	/// request-scoped dependencies resolved through it are created at most
+	/// once per request. Used to hand an owned context to factories.
+	pub(crate) fn share_request_scope(&self) -> InjectionContext {
+		self.with_request_scope(self.request_scope.clone())
+	}
+
+	fn with_request_scope(&self, request_scope: RequestScope) -> InjectionContext {
+		InjectionContext {
+			request_scope,
 			singleton_scope: Arc::clone(&self.singleton_scope),
 			override_registry: Arc::clone(&self.override_registry),
 			registry: self.registry.clone(),
@@ -198,9 +214,7 @@ impl Clone for InjectionContext {
 			param_context: self.param_context.clone(),
 		}
 	}
-}
 
-impl InjectionContext {
 	/// Create a new `InjectionContextBuilder`.
 	///
 	/// This is the recommended way to construct an `InjectionContext`.
@@ -487,6 +501,59 @@ impl InjectionContext {
 		)
 	}
 
+	/// Returns the request scope of this context.
+	///
+	/// Each context produced by [`fork_for_request`](Self::fork_for_request)
+	/// or [`fork`](Self::fork) has its own request scope, which caches
+	/// request-scoped dependencies and holds the disposal hooks run when the
+	/// request finishes.
+	pub fn request_scope(&self) -> &RequestScope {
+		&self.request_scope
+	}
+
+	/// Registers a hook to run when the request served by this context
+	/// finishes.
+	///
+	/// Hooks run in reverse registration order when
+	/// [`end_request`](Self::end_request) is awaited, or otherwise on the
+	/// Tokio runtime once the last handle to the request scope is dropped.
+	/// Request-scoped factories use this to hand resources such as pooled
+	/// connections back when the request is done.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_di::{InjectionContext, SingletonScope};
+	/// use std::sync::Arc;
+	/// use std::sync::atomic::{AtomicBool, Ordering};
+	///
+	/// # #[tokio::main(flavor = "current_thread")]
+	/// # async fn main() {
+	/// let root = InjectionContext::builder(SingletonScope::new()).build();
+	/// let request_ctx = root.fork();
+	///
+	/// let returned = Arc::new(AtomicBool::new(false));
+	/// let flag = Arc::clone(&returned);
+	/// request_ctx.on_request_end(move || async move { flag.store(true, Ordering::SeqCst) });
+	///
+	/// request_ctx.end_request().await;
+	/// assert!(returned.load(Ordering::SeqCst));
+	/// # }
+	/// ```
+	pub fn on_request_end<F, Fut>(&self, hook: F)
+	where
+		F: FnOnce() -> Fut + Send + 'static,
+		Fut: std::future::Future<Output = ()> + Send + 'static,
+	{
+		self.request_scope.on_dispose(hook);
+	}
+
+	/// Runs the request's disposal hooks now instead of waiting for the
+	/// request scope to be dropped.
+	pub async fn end_request(&self) {
+		self.request_scope.dispose().await;
+	}
+
 	/// Returns a reference to the override registry.
 	///
 	/// The override registry stores function-level overrides that take
@@ -594,6 +661,53 @@ impl InjectionContext {
 		self.override_registry.clear();
 	}
 
+	/// Replaces every resolution of `T` with `value` while the returned guard
+	/// is alive.
+	///
+	/// Unlike [`dependency`](Self::dependency), which overrides a single
+	/// provider function, this overrides the type itself, regardless of how
+	/// it is registered. The override applies to this context and to every
+	/// context forked from it, so a context handed to a test client serves
+	/// the fake to each request. Overrides for the same type nest: dropping
+	/// the inner guard restores the outer override.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_di::{InjectionContext, SingletonScope};
+	///
+	/// # #[derive(Debug, PartialEq)]
+	/// # struct Mailer(&'static str);
+	/// # #[tokio::main(flavor = "current_thread")]
+	/// # async fn main() {
+	/// let ctx = InjectionContext::builder(SingletonScope::new()).build();
+	///
+	/// let mailer = ctx.override_with(Mailer("fake"));
+	/// let resolved = ctx.fork().resolve::<Mailer>().await.unwrap();
+	/// assert_eq!(*resolved, Mailer("fake"));
+	///
+	/// mailer.assert_consumed();
+	/// # }
+	/// ```
+	pub fn override_with<T: Any + Send + Sync + 'static>(&self, value: T) -> ScopedOverride {
+		self.override_registry.push_type(Arc::new(value))
+	}
+
+	/// Asserts that every active type override has been resolved at least once.
+	///
+	/// # Panics
+	///
+	/// Panics listing each overridden type that was never resolved.
+	#[track_caller]
+	pub fn assert_overrides_consumed(&self) {
+		let unconsumed = self.override_registry.unconsumed_type_overrides();
+		assert!(
+			unconsumed.is_empty(),
+			"overrides were never resolved: {}",
+			unconsumed.join(", ")
+		);
+	}
+
 	/// Layered registry lookup: per-context registry takes precedence, then
 	/// global. Returns the scope and which registry owns the type.
 	fn find_type_registration<T: Any + Send + Sync + 'static>(
@@ -645,11 +759,17 @@ impl InjectionContext {
 	/// ```
 	pub async fn resolve<T: Any + Send + Sync + 'static>(&self) -> crate::DiResult<Arc<T>> {
 		use crate::cycle_detection::{
-			begin_scoped_resolution, current_dependent_scope, current_dependent_type_name,
-			register_type_name, with_cycle_detection_scope,
+			begin_scoped_resolution, clear_failure, current_dependent_scope,
+			current_dependent_type_name, is_scope_active, record_failure, register_type_name,
+			with_cycle_detection_scope,
 		};
 		use crate::registry::DependencyScope;
 
+		if let Some(value) = self.override_registry.get_type::<T>() {
+			return Ok(value);
+		}
+
+		let outermost = !is_scope_active();
 		with_cycle_detection_scope(async {
 			let type_id = std::any::TypeId::of::<T>();
 			let type_name = std::any::type_name::<T>();
@@ -728,7 +848,17 @@ impl InjectionContext {
 				})?;
 
 			// Actual resolution processing (existing logic)
-			self.resolve_internal::<T>(scope, registry).await
+			let result = self.resolve_internal::<T>(scope, registry).await;
+			// Record failures while the guard is alive so the recorded path
+			// ends with this type
+			match &result {
+				Ok(_) => clear_failure(),
+				Err(e) => record_failure(e.to_string()),
+			}
+			if outermost {
+				return result.map_err(attach_resolution_chain);
+			}
+			result
 			// Guard is automatically cleaned up when dropped
 		})
 		.await
@@ -741,6 +871,9 @@ impl InjectionContext {
 		if use_cache {
 			return self.resolve::<T>().await;
 		}
+		if let Some(value) = self.override_registry.get_type::<T>() {
+			return Ok(value);
+		}
 
 		use crate::cycle_detection::{
 			begin_scoped_resolution, current_dependent_scope, current_dependent_type_name,
@@ -820,6 +953,10 @@ impl InjectionContext {
 		use crate::cycle_detection::{current_dependent_scope, current_dependent_type_name};
 		use crate::registry::DependencyScope;
 
+		if let Some(value) = self.override_registry.get_type::<T>() {
+			return Ok(value);
+		}
+
 		let type_name = std::any::type_name::<T>();
 		let (scope, registry) = match self.find_type_registration::<T>() {
 			Some(entry) => entry,
@@ -897,13 +1034,13 @@ impl InjectionContext {
 				Ok(instance)
 			}
 			DependencyScope::Request => {
-				// Create new instance
-				let instance = registry.create::<T>(self).await?;
-
-				// Cache the Arc directly in request scope without unwrapping.
-				// This avoids panics when the factory retains an Arc clone.
-				self.set_request_arc(Arc::clone(&instance));
-				Ok(instance)
+				// Create the instance at most once per request, even when
+				// several tasks resolve it concurrently. The Arc is cached
+				// directly, which avoids panics when the factory retains a
+				// clone.
+				self.request_scope
+					.get_or_try_init(|| registry.create::<T>(self))
+					.await
 			}
 			DependencyScope::Transient => {
 				// Never cache, always create new
@@ -913,6 +1050,31 @@ impl InjectionContext {
 	}
 }
 
+/// Wrap an error that reached the outermost [`InjectionContext::resolve`] call
+/// with the chain of dependencies it propagated through.
+///
+/// Only provider-level failures are wrapped. Errors that callers match on
+/// (authentication, missing registrations, scope violations, ...) keep their
+/// variant, and so does an error that a dependent replaced with its own.
+fn attach_resolution_chain(error: crate::DiError) -> crate::DiError {
+	let Some(failure) = crate::cycle_detection::take_failure() else {
+		return error;
+	};
+	let wrappable = matches!(
+		error,
+		crate::DiError::ProviderError(_)
+			| crate::DiError::Internal { .. }
+			| crate::DiError::TypeMismatch { .. }
+	);
+	if !wrappable || failure.path.len() < 2 || failure.message != error.to_string() {
+		return error;
+	}
+	crate::DiError::ResolutionFailed {
+		path: failure.path.into_iter().map(str::to_string).collect(),
+		source: Box::new(error),
+	}
+}
+
 /// Context for per-request dependency injection resolution.
 ///
 /// Wraps an `InjectionContext` with request-scoped lifetime management.
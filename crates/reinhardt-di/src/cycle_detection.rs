@@ -41,6 +41,18 @@ struct CycleDetectionState {
 	/// The top is the scope of the type whose factory is currently executing
 	/// (the "dependent" in scope-hierarchy checks).
 	scope_stack: Vec<DependencyScope>,
+	/// Innermost failed resolution, kept until an enclosing resolution
+	/// succeeds so the outermost caller can report the full chain
+	failure: Option<ResolutionFailure>,
+}
+
+/// The resolution path and error message of the innermost failed resolution
+pub(crate) struct ResolutionFailure {
+	/// Types being resolved when the failure happened, outermost first
+	pub(crate) path: Vec<&'static str>,
+	/// `Display` output of the error, used to check that the error reaching
+	/// the outermost caller is the one recorded here
+	pub(crate) message: String,
 }
 
 impl CycleDetectionState {
@@ -51,6 +63,7 @@ impl CycleDetectionState {
 			type_names: HashMap::new(),
 			resolution_path: Vec::new(),
 			scope_stack: Vec::new(),
+			failure: None,
 		}
 	}
 }
@@ -201,6 +214,39 @@ pub fn register_type_name<T: 'static>(name: &'static str) {
 	});
 }
 
+/// Whether a cycle detection scope is active, i.e. a resolution is already
+/// in progress on this task.
+pub(crate) fn is_scope_active() -> bool {
+	CYCLE_STATE.try_with(|_| ()).is_ok()
+}
+
+/// Record a failed resolution unless a deeper failure is already recorded.
+///
+/// Must be called while the failing type's guard is still alive so that it
+/// is the last entry of the recorded path.
+pub(crate) fn record_failure(message: String) {
+	let _ = CYCLE_STATE.try_with(|state| {
+		let mut s = state.borrow_mut();
+		if s.failure.is_none() {
+			let path = s.resolution_path.iter().map(|(_, name)| *name).collect();
+			s.failure = Some(ResolutionFailure { path, message });
+		}
+	});
+}
+
+/// Forget a recorded failure, e.g. because the dependent recovered from it.
+pub(crate) fn clear_failure() {
+	let _ = CYCLE_STATE.try_with(|state| state.borrow_mut().failure = None);
+}
+
+/// Take the recorded failure, if any.
+pub(crate) fn take_failure() -> Option<ResolutionFailure> {
+	CYCLE_STATE
+		.try_with(|state| state.borrow_mut().failure.take())
+		.ok()
+		.flatten()
+}
+
 /// Build circular path (internal, borrows state)
 fn build_cycle_path_inner(state: &CycleDetectionState, current_type_id: TypeId) -> String {
 	let type_name = state
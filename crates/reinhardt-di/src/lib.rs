@@ -10,8 +10,15 @@
 //! - **Composable**: Dependencies can depend on other dependencies
 //! - **Extensible wrappers**: Custom `#[inject]` wrappers can implement
 //!   [`InjectableType`] instead of relying on framework-defined type names
-//! - **Cache**: Automatic caching within request scope
+//! - **Cache**: Automatic caching within request scope; request-scoped
+//!   providers run at most once per request
+//! - **Request lifecycle**: Per-request child contexts with async disposal
+//!   hooks ([`InjectionContext::on_request_end`])
+//! - **Error chains**: Provider failures in nested dependencies report the
+//!   resolution path ([`DiError::ResolutionFailed`])
 //! - **Circular Dependency Detection**: Automatic runtime detection with optimized performance
+//! - **Modules**: Group providers with explicit imports and exports using
+//!   [`module!`], and validate the whole wiring at startup with [`ModuleSet`]
 //!
 //! ## Custom `#[inject]` Wrappers
 //!
@@ -302,6 +309,7 @@ pub mod injectable;
 pub mod injectable_key;
 pub mod injectable_type;
 pub mod injected;
+pub mod module;
 pub mod override_registry;
 pub mod provider;
 pub mod registration;
@@ -322,7 +330,7 @@ pub use cycle_detection::{
 pub use factory_output::FactoryOutput;
 pub use function_handle::FunctionHandle;
 pub use injectable_key::InjectableKey;
-pub use override_registry::OverrideRegistry;
+pub use override_registry::{OverrideRegistry, ScopedOverride};
 
 #[cfg(feature = "params")]
 pub use context::{ParamContext, Request};
@@ -335,6 +343,7 @@ pub use injectable_type::{
 	__InjectFallbackResolver, __InjectResolver, __InjectWrapperResolver,
 };
 pub use injected::{DependencyScope as InjectedScope, InjectionMetadata};
+pub use module::{DiModule, ModuleBinding, ModuleProvider, ModuleSet};
 pub use provider::{Provider, ProviderFn};
 pub use registration::DiRegistrationList;
 pub use registry::{
@@ -407,6 +416,18 @@ pub enum DiError {
 		message: String,
 	},
 
+	/// A dependency failed while resolving the dependencies of another one.
+	///
+	/// `path` lists the types being resolved, from the one requested by the
+	/// caller down to the one whose provider failed with `source`.
+	#[error("Failed to resolve {}: {source}", path.join(" -> "))]
+	ResolutionFailed {
+		/// Resolution chain, outermost first.
+		path: Vec<String>,
+		/// The error raised by the innermost dependency.
+		source: Box<DiError>,
+	},
+
 	/// An authorization error (insufficient permissions).
 	#[error("Authorization error: {0}")]
 	Authorization(String),
@@ -0,0 +1,717 @@
+//! DI container modules
+//!
+//! A module groups related providers (for example a `DatabaseModule`, a
+//! `CacheModule` and an `AuthModule`) and declares which of them are visible to
+//! other modules. Modules are declared with the [`module!`](crate::module!)
+//! macro and installed through a [`ModuleSet`], which validates the whole
+//! wiring before anything is registered:
+//!
+//! - every dependency of every provider must be provided by the same module,
+//!   exported by one of its imports, or already registered in the base registry
+//! - exported types must be provided by the exporting module
+//! - no type may be provided twice
+//! - module imports must not form a cycle
+//!
+//! All problems are collected and reported together as [`ValidationError`]s,
+//! so a misconfigured application fails at startup with the complete list of
+//! missing bindings instead of panicking on the first resolution.
+//!
+//! Exporting a type the module does not provide is rejected at compile time by
+//! the macro.
+
+use crate::DiResult;
+use crate::context::InjectionContext;
+use crate::registry::{DependencyRegistry, DependencyScope};
+use crate::validation::{RegistryValidator, ValidationError, ValidationErrorKind};
+use std::any::{Any, TypeId};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+
+/// Identifies a type bound inside a module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModuleBinding {
+	type_id: TypeId,
+	type_name: &'static str,
+}
+
+impl ModuleBinding {
+	/// Create the binding for `T`.
+	pub fn of<T: ?Sized + 'static>() -> Self {
+		Self {
+			type_id: TypeId::of::<T>(),
+			type_name: std::any::type_name::<T>(),
+		}
+	}
+
+	/// The `TypeId` of the bound type.
+	pub fn type_id(&self) -> TypeId {
+		self.type_id
+	}
+
+	/// The fully-qualified name of the bound type.
+	pub fn type_name(&self) -> &'static str {
+		self.type_name
+	}
+
+	/// The last path segment of the type name, used in reports.
+	pub fn short_name(&self) -> &'static str {
+		self.type_name.rsplit("::").next().unwrap_or(self.type_name)
+	}
+}
+
+impl fmt::Display for ModuleBinding {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.short_name())
+	}
+}
+
+type RegisterFn = Box<dyn FnOnce(&DependencyRegistry) + Send>;
+
+/// A provider declared by a module.
+///
+/// Holds the provided type, its scope, the types its factory resolves from
+/// the injection context and the deferred registration of the factory itself.
+pub struct ModuleProvider {
+	binding: ModuleBinding,
+	scope: DependencyScope,
+	dependencies: Vec<ModuleBinding>,
+	register: RegisterFn,
+}
+
+impl ModuleProvider {
+	/// Create a provider for `T` backed by an async factory.
+	///
+	/// `dependencies` lists every type the factory resolves; it is what the
+	/// wiring validation checks against the module's imports.
+	pub fn new<T, F, Fut>(
+		scope: DependencyScope,
+		dependencies: Vec<ModuleBinding>,
+		factory: F,
+	) -> Self
+	where
+		T: Any + Send + Sync + 'static,
+		F: Fn(Arc<InjectionContext>) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = DiResult<T>> + Send + 'static,
+	{
+		Self {
+			binding: ModuleBinding::of::<T>(),
+			scope,
+			dependencies,
+			register: Box::new(move |registry: &DependencyRegistry| {
+				registry.register_async::<T, _, _>(scope, factory);
+			}),
+		}
+	}
+
+	/// The provided type.
+	pub fn binding(&self) -> ModuleBinding {
+		self.binding
+	}
+
+	/// The scope the provider is registered with.
+	pub fn scope(&self) -> DependencyScope {
+		self.scope
+	}
+
+	/// The types the provider's factory depends on.
+	pub fn dependencies(&self) -> &[ModuleBinding] {
+		&self.dependencies
+	}
+}
+
+impl fmt::Debug for ModuleProvider {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ModuleProvider")
+			.field("binding", &self.binding)
+			.field("scope", &self.scope)
+			.field("dependencies", &self.dependencies)
+			.finish_non_exhaustive()
+	}
+}
+
+/// A group of providers with explicit imports and exports.
+///
+/// Usually implemented through the [`module!`](crate::module!) macro.
+pub trait DiModule: Send + Sync + 'static {
+	/// The module name used in reports and to deduplicate shared imports.
+	fn name(&self) -> &'static str;
+
+	/// Modules whose exports this module may depend on.
+	fn imports(&self) -> Vec<Box<dyn DiModule>> {
+		Vec::new()
+	}
+
+	/// The providers owned by this module.
+	fn providers(&self) -> Vec<ModuleProvider>;
+
+	/// The provided types visible to modules importing this one.
+	fn exports(&self) -> Vec<ModuleBinding> {
+		Vec::new()
+	}
+}
+
+/// Marker implemented by [`module!`](crate::module!) for every type a module
+/// provides, used to check exports at compile time.
+#[doc(hidden)]
+pub trait Provides<T: ?Sized> {}
+
+/// Compile-time assertion that module `M` provides `T`.
+#[doc(hidden)]
+pub fn assert_provides<M: Provides<T>, T: ?Sized>() {}
+
+/// Declare a DI module.
+///
+/// Generates a unit struct implementing [`DiModule`]. Each provider lists the
+/// types its factory resolves in brackets, followed by its scope
+/// (`Singleton`, `Request` or `Transient`) and an async factory taking
+/// `Arc<InjectionContext>`. The `imports` and `exports` sections are optional.
+///
+/// ```rust,no_run
+/// use reinhardt_di::{ModuleSet, module};
+/// use std::sync::Arc;
+///
+/// struct DatabaseUrl(String);
+/// struct DatabasePool { url: String }
+/// struct SessionStore { pool: Arc<DatabasePool> }
+///
+/// module! {
+///     /// Database connectivity.
+///     pub struct DatabaseModule {
+///         providers: {
+///             DatabaseUrl: Singleton [] => |_ctx| async {
+///                 Ok(DatabaseUrl("postgres://localhost/app".into()))
+///             },
+///             DatabasePool: Singleton [DatabaseUrl] => |ctx| async move {
+///                 let url = ctx.resolve::<DatabaseUrl>().await?;
+///                 Ok(DatabasePool { url: url.0.clone() })
+///             },
+///         },
+///         exports: [DatabasePool],
+///     }
+/// }
+///
+/// module! {
+///     /// Authentication.
+///     pub struct AuthModule {
+///         imports: [DatabaseModule],
+///         providers: {
+///             SessionStore: Request [DatabasePool] => |ctx| async move {
+///                 Ok(SessionStore { pool: ctx.resolve::<DatabasePool>().await? })
+///             },
+///         },
+///     }
+/// }
+///
+/// let registry = ModuleSet::new()
+///     .with_module(AuthModule)
+///     .build()
+///     .unwrap_or_else(|errors| panic!("{}", reinhardt_di::validation::format_validation_report(&errors)));
+/// ```
+#[macro_export]
+macro_rules! module {
+	(
+		$(#[$meta:meta])*
+		$vis:vis struct $name:ident {
+			$(imports: [$($import:ty),* $(,)?],)?
+			providers: {
+				$($provided:ty : $scope:ident [$($dep:ty),* $(,)?] => $factory:expr),* $(,)?
+			} $(,)?
+			$(exports: [$($export:ty),* $(,)?] $(,)?)?
+		}
+	) => {
+		$(#[$meta])*
+		#[derive(Debug, Clone, Copy, Default)]
+		$vis struct $name;
+
+		impl $crate::module::DiModule for $name {
+			fn name(&self) -> &'static str {
+				::std::stringify!($name)
+			}
+
+			fn imports(&self) -> ::std::vec::Vec<::std::boxed::Box<dyn $crate::module::DiModule>> {
+				::std::vec![$($(
+					::std::boxed::Box::new(<$import as ::std::default::Default>::default())
+						as ::std::boxed::Box<dyn $crate::module::DiModule>
+				),*)?]
+			}
+
+			fn providers(&self) -> ::std::vec::Vec<$crate::module::ModuleProvider> {
+				::std::vec![$(
+					$crate::module::ModuleProvider::new::<$provided, _, _>(
+						$crate::DependencyScope::$scope,
+						::std::vec![$($crate::module::ModuleBinding::of::<$dep>()),*],
+						$factory,
+					)
+				),*]
+			}
+
+			fn exports(&self) -> ::std::vec::Vec<$crate::module::ModuleBinding> {
+				::std::vec![$($($crate::module::ModuleBinding::of::<$export>()),*)?]
+			}
+		}
+
+		$(impl $crate::module::Provides<$provided> for $name {})*
+
+		const _: fn() = || {
+			$($($crate::module::assert_provides::<$name, $export>();)*)?
+		};
+	};
+}
+
+/// Collects modules, validates their wiring and installs their providers.
+///
+/// Imports are followed transitively, and a module imported from several
+/// places is installed once.
+pub struct ModuleSet {
+	modules: Vec<Box<dyn DiModule>>,
+	registry: Arc<DependencyRegistry>,
+}
+
+/// A module flattened for validation.
+struct CollectedModule {
+	name: &'static str,
+	imports: Vec<&'static str>,
+	providers: Vec<ModuleProvider>,
+	exports: Vec<ModuleBinding>,
+}
+
+impl ModuleSet {
+	/// Create an empty set that installs into a fresh registry.
+	pub fn new() -> Self {
+		Self::with_registry(Arc::new(DependencyRegistry::new()))
+	}
+
+	/// Create an empty set that installs into `registry`.
+	///
+	/// Types already registered there satisfy module dependencies, and module
+	/// providers for those types are reported as duplicates.
+	pub fn with_registry(registry: Arc<DependencyRegistry>) -> Self {
+		Self {
+			modules: Vec::new(),
+			registry,
+		}
+	}
+
+	/// Add a root module.
+	pub fn with_module(mut self, module: impl DiModule) -> Self {
+		self.modules.push(Box::new(module));
+		self
+	}
+
+	/// Validate the wiring of every module without registering anything.
+	///
+	/// Returns every problem found rather than stopping at the first one.
+	pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+		let (modules, mut errors) = self.collect();
+		self.check_wiring(&modules, &mut errors);
+
+		if errors.is_empty() {
+			Ok(())
+		} else {
+			Err(errors)
+		}
+	}
+
+	/// Validate the modules, register their providers and validate the
+	/// resulting registry.
+	///
+	/// Nothing is registered if the module wiring is invalid. Once the
+	/// providers are installed, the registry is checked by
+	/// [`RegistryValidator`] for scope incompatibilities and dependency cycles.
+	pub fn build(self) -> Result<Arc<DependencyRegistry>, Vec<ValidationError>> {
+		let (modules, mut errors) = self.collect();
+		self.check_wiring(&modules, &mut errors);
+		if !errors.is_empty() {
+			return Err(errors);
+		}
+
+		for module in modules {
+			for provider in module.providers {
+				let binding = provider.binding;
+				self.registry.register_dependencies(
+					binding.type_id,
+					provider
+						.dependencies
+						.iter()
+						.map(|dep| dep.type_id)
+						.collect::<Vec<_>>(),
+				);
+				self.registry
+					.register_type_name(binding.type_id, binding.short_name());
+				for dep in &provider.dependencies {
+					if self.registry.get_type_name(dep.type_id).is_none() {
+						self.registry
+							.register_type_name(dep.type_id, dep.short_name());
+					}
+				}
+				(provider.register)(&self.registry);
+			}
+		}
+
+		RegistryValidator::new(Arc::clone(&self.registry)).validate()?;
+		Ok(self.registry)
+	}
+
+	/// Flatten the module tree, reporting import cycles.
+	fn collect(&self) -> (Vec<CollectedModule>, Vec<ValidationError>) {
+		let mut modules = Vec::new();
+		let mut errors = Vec::new();
+		let mut seen = HashSet::new();
+		let mut stack = Vec::new();
+
+		for module in &self.modules {
+			Self::visit(
+				module.as_ref(),
+				&mut stack,
+				&mut seen,
+				&mut modules,
+				&mut errors,
+			);
+		}
+		(modules, errors)
+	}
+
+	fn visit(
+		module: &dyn DiModule,
+		stack: &mut Vec<&'static str>,
+		seen: &mut HashSet<&'static str>,
+		modules: &mut Vec<CollectedModule>,
+		errors: &mut Vec<ValidationError>,
+	) {
+		let name = module.name();
+		if let Some(start) = stack.iter().position(|entry| *entry == name) {
+			let mut cycle = stack[start..].to_vec();
+			cycle.push(name);
+			errors.push(ValidationError {
+				kind: ValidationErrorKind::CircularDependency,
+				type_name: name.to_string(),
+				type_id: TypeId::of::<ModuleSet>(),
+				message: format!("Module import cycle detected: {}", cycle.join(" -> ")),
+			});
+			return;
+		}
+		if !seen.insert(name) {
+			return;
+		}
+
+		let imports = module.imports();
+		stack.push(name);
+		for import in &imports {
+			Self::visit(import.as_ref(), stack, seen, modules, errors);
+		}
+		stack.pop();
+
+		modules.push(CollectedModule {
+			name,
+			imports: imports.iter().map(|import| import.name()).collect(),
+			providers: module.providers(),
+			exports: module.exports(),
+		});
+	}
+
+	fn check_wiring(&self, modules: &[CollectedModule], errors: &mut Vec<ValidationError>) {
+		let mut owners: HashMap<TypeId, &'static str> = HashMap::new();
+		for module in modules {
+			for provider in &module.providers {
+				let binding = provider.binding;
+				if let Some(owner) = owners.get(&binding.type_id) {
+					errors.push(ValidationError {
+						kind: ValidationErrorKind::DuplicateProvider,
+						type_name: binding.short_name().to_string(),
+						type_id: binding.type_id,
+						message: format!(
+							"'{}' is provided by both {} and {}",
+							binding, owner, module.name
+						),
+					});
+					continue;
+				}
+				if self.registry.is_registered_by_id(binding.type_id) {
+					errors.push(ValidationError {
+						kind: ValidationErrorKind::DuplicateProvider,
+						type_name: binding.short_name().to_string(),
+						type_id: binding.type_id,
+						message: format!(
+							"'{}' provided by {} is already registered in the base registry",
+							binding, module.name
+						),
+					});
+				}
+				owners.insert(binding.type_id, module.name);
+			}
+		}
+
+		let by_name: HashMap<&'static str, &CollectedModule> =
+			modules.iter().map(|module| (module.name, module)).collect();
+
+		for module in modules {
+			let own: HashSet<TypeId> = module
+				.providers
+				.iter()
+				.map(|provider| provider.binding.type_id)
+				.collect();
+
+			for export in &module.exports {
+				if !own.contains(&export.type_id) {
+					errors.push(ValidationError {
+						kind: ValidationErrorKind::MissingDependency,
+						type_name: export.short_name().to_string(),
+						type_id: export.type_id,
+						message: format!(
+							"{} exports '{}' but does not provide it",
+							module.name, export
+						),
+					});
+				}
+			}
+
+			let imported: HashSet<TypeId> = module
+				.imports
+				.iter()
+				.filter_map(|name| by_name.get(name))
+				.flat_map(|import| import.exports.iter().map(|export| export.type_id))
+				.collect();
+
+			for provider in &module.providers {
+				for dep in &provider.dependencies {
+					if own.contains(&dep.type_id)
+						|| imported.contains(&dep.type_id)
+						|| self.registry.is_registered_by_id(dep.type_id)
+					{
+						continue;
+					}
+
+					let message = match owners.get(&dep.type_id) {
+						Some(owner) if module.imports.contains(owner) => format!(
+							"'{}' in {} depends on '{}', which {} provides but does not export",
+							provider.binding, module.name, dep, owner
+						),
+						Some(owner) => format!(
+							"'{}' in {} depends on '{}', which is provided by {} but {} does not import it",
+							provider.binding, module.name, dep, owner, module.name
+						),
+						None => format!(
+							"'{}' in {} depends on '{}', which no module provides",
+							provider.binding, module.name, dep
+						),
+					};
+					let kind = if owners.contains_key(&dep.type_id) {
+						ValidationErrorKind::UnexportedDependency
+					} else {
+						ValidationErrorKind::MissingDependency
+					};
+					errors.push(ValidationError {
+						kind,
+						type_name: provider.binding.short_name().to_string(),
+						type_id: provider.binding.type_id,
+						message,
+					});
+				}
+			}
+		}
+	}
+}
+
+impl Default for ModuleSet {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl fmt::Debug for ModuleSet {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ModuleSet")
+			.field(
+				"modules",
+				&self
+					.modules
+					.iter()
+					.map(|module| module.name())
+					.collect::<Vec<_>>(),
+			)
+			.finish_non_exhaustive()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::*;
+
+	struct Config;
+	struct Pool;
+	struct Cache;
+	struct Session;
+
+	crate::module! {
+		struct ConfigModule {
+			providers: {
+				Config: Singleton [] => |_ctx| async { Ok(Config) },
+			},
+			exports: [Config],
+		}
+	}
+
+	crate::module! {
+		struct DatabaseModule {
+			imports: [ConfigModule],
+			providers: {
+				Pool: Singleton [Config] => |_ctx| async { Ok(Pool) },
+			},
+		}
+	}
+
+	crate::module! {
+		struct AuthModule {
+			imports: [DatabaseModule],
+			providers: {
+				Session: Request [Pool, Cache] => |_ctx| async { Ok(Session) },
+			},
+		}
+	}
+
+	#[rstest]
+	fn validate_reports_every_missing_binding() {
+		// Arrange
+		let set = ModuleSet::new().with_module(AuthModule);
+
+		// Act
+		let errors = set.validate().unwrap_err();
+
+		// Assert
+		assert_eq!(errors.len(), 2);
+		assert_eq!(errors[0].kind, ValidationErrorKind::UnexportedDependency);
+		assert!(
+			errors[0]
+				.message
+				.contains("which DatabaseModule provides but does not export")
+		);
+		assert_eq!(errors[1].kind, ValidationErrorKind::MissingDependency);
+		assert!(
+			errors[1]
+				.message
+				.contains("'Cache', which no module provides")
+		);
+	}
+
+	#[rstest]
+	fn validate_accepts_base_registry_bindings() {
+		// Arrange
+		let registry = Arc::new(DependencyRegistry::new());
+		registry
+			.register_async::<Cache, _, _>(DependencyScope::Singleton, |_ctx| async { Ok(Cache) });
+		registry
+			.register_async::<Pool, _, _>(DependencyScope::Singleton, |_ctx| async { Ok(Pool) });
+		let set = ModuleSet::with_registry(registry).with_module(AuthModule);
+
+		// Act
+		let errors = set.validate().unwrap_err();
+
+		// Assert
+		assert_eq!(errors.len(), 1);
+		assert_eq!(errors[0].kind, ValidationErrorKind::DuplicateProvider);
+		assert!(
+			errors[0]
+				.message
+				.contains("'Pool' provided by DatabaseModule")
+		);
+	}
+
+	#[rstest]
+	fn validate_reports_missing_import() {
+		// Arrange
+		crate::module! {
+			struct StandaloneModule {
+				providers: {
+					Pool: Singleton [Config] => |_ctx| async { Ok(Pool) },
+				},
+			}
+		}
+		let set = ModuleSet::new()
+			.with_module(ConfigModule)
+			.with_module(StandaloneModule);
+
+		// Act
+		let errors = set.validate().unwrap_err();
+
+		// Assert
+		assert_eq!(errors.len(), 1);
+		assert_eq!(errors[0].kind, ValidationErrorKind::UnexportedDependency);
+		assert!(
+			errors[0]
+				.message
+				.contains("provided by ConfigModule but StandaloneModule does not import it")
+		);
+	}
+
+	#[rstest]
+	fn validate_reports_import_cycle() {
+		// Arrange
+		struct Left;
+		struct Right;
+		impl DiModule for Left {
+			fn name(&self) -> &'static str {
+				"Left"
+			}
+			fn imports(&self) -> Vec<Box<dyn DiModule>> {
+				vec![Box::new(Right)]
+			}
+			fn providers(&self) -> Vec<ModuleProvider> {
+				Vec::new()
+			}
+		}
+		impl DiModule for Right {
+			fn name(&self) -> &'static str {
+				"Right"
+			}
+			fn imports(&self) -> Vec<Box<dyn DiModule>> {
+				vec![Box::new(Left)]
+			}
+			fn providers(&self) -> Vec<ModuleProvider> {
+				Vec::new()
+			}
+		}
+		let set = ModuleSet::new().with_module(Left);
+
+		// Act
+		let errors = set.validate().unwrap_err();
+
+		// Assert
+		assert_eq!(errors.len(), 1);
+		assert_eq!(errors[0].kind, ValidationErrorKind::CircularDependency);
+		assert_eq!(
+			errors[0].message,
+			"Module import cycle detected: Left -> Right -> Left"
+		);
+	}
+
+	#[rstest]
+	fn build_installs_shared_imports_once() {
+		// Arrange
+		crate::module! {
+			struct ReportingModule {
+				imports: [ConfigModule],
+				providers: {
+					Cache: Singleton [Config] => |_ctx| async { Ok(Cache) },
+				},
+			}
+		}
+		let set = ModuleSet::new()
+			.with_module(DatabaseModule)
+			.with_module(ReportingModule);
+
+		// Act
+		let registry = set.build().unwrap();
+
+		// Assert
+		assert!(registry.is_registered::<Config>());
+		assert!(registry.is_registered::<Pool>());
+		assert!(registry.is_registered::<Cache>());
+		assert_eq!(
+			registry.get_all_dependencies().get(&TypeId::of::<Pool>()),
+			Some(&vec![TypeId::of::<Config>()])
+		);
+	}
+}
@@ -3,10 +3,17 @@
 //! This module provides a registry for storing override values that take precedence
 //! over normal dependency resolution. Overrides are keyed by function pointer addresses,
 //! allowing specific injectable functions to be mocked in tests.
+//!
+//! Type-level overrides installed with [`InjectionContext::override_with`]
+//! replace every resolution of a type and are reverted when the returned
+//! [`ScopedOverride`] guard is dropped.
+//!
+//! [`InjectionContext::override_with`]: crate::InjectionContext::override_with
 
-use std::any::Any;
+use std::any::{Any, TypeId};
 use std::collections::HashMap;
-use std::sync::{Arc, PoisonError, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, PoisonError, RwLock, Weak};
 
 /// Override registry for dependency injection.
 ///
@@ -40,6 +47,16 @@ use std::sync::{Arc, PoisonError, RwLock};
 pub struct OverrideRegistry {
 	/// Function pointer address → Override value
 	overrides: RwLock<HashMap<usize, Arc<dyn Any + Send + Sync>>>,
+	/// Type → stack of active type-level overrides, innermost last
+	type_overrides: RwLock<HashMap<TypeId, Vec<TypeOverride>>>,
+}
+
+/// An active type-level override.
+struct TypeOverride {
+	value: Arc<dyn Any + Send + Sync>,
+	type_name: &'static str,
+	/// Resolution count, shared with the [`ScopedOverride`] guard
+	hits: Arc<AtomicUsize>,
 }
 
 impl OverrideRegistry {
@@ -55,6 +72,7 @@ impl OverrideRegistry {
 	pub fn new() -> Self {
 		Self {
 			overrides: RwLock::new(HashMap::new()),
+			type_overrides: RwLock::new(HashMap::new()),
 		}
 	}
 
@@ -231,6 +249,136 @@ impl OverrideRegistry {
 	}
 }
 
+impl OverrideRegistry {
+	/// Installs a type-level override, shadowing any earlier one for `T`.
+	pub(crate) fn push_type<T: Any + Send + Sync>(
+		self: &Arc<Self>,
+		value: Arc<T>,
+	) -> ScopedOverride {
+		let type_name = std::any::type_name::<T>();
+		let hits = Arc::new(AtomicUsize::new(0));
+		self.type_overrides
+			.write()
+			.unwrap_or_else(PoisonError::into_inner)
+			.entry(TypeId::of::<T>())
+			.or_default()
+			.push(TypeOverride {
+				value,
+				type_name,
+				hits: Arc::clone(&hits),
+			});
+		ScopedOverride {
+			type_id: TypeId::of::<T>(),
+			type_name,
+			hits,
+			registry: Arc::downgrade(self),
+		}
+	}
+
+	/// Returns the innermost type-level override for `T`, counting the hit.
+	pub(crate) fn get_type<T: Any + Send + Sync>(&self) -> Option<Arc<T>> {
+		let overrides = self
+			.type_overrides
+			.read()
+			.unwrap_or_else(PoisonError::into_inner);
+		let active = overrides.get(&TypeId::of::<T>())?.last()?;
+		let value = Arc::clone(&active.value).downcast::<T>().ok()?;
+		active.hits.fetch_add(1, Ordering::Relaxed);
+		Some(value)
+	}
+
+	/// Returns the names of active type-level overrides that were never resolved.
+	pub fn unconsumed_type_overrides(&self) -> Vec<&'static str> {
+		let mut names: Vec<_> = self
+			.type_overrides
+			.read()
+			.unwrap_or_else(PoisonError::into_inner)
+			.values()
+			.flatten()
+			.filter(|entry| entry.hits.load(Ordering::Relaxed) == 0)
+			.map(|entry| entry.type_name)
+			.collect();
+		names.sort_unstable();
+		names
+	}
+
+	fn remove_type(&self, type_id: TypeId, hits: &Arc<AtomicUsize>) {
+		let mut overrides = self
+			.type_overrides
+			.write()
+			.unwrap_or_else(PoisonError::into_inner);
+		if let Some(stack) = overrides.get_mut(&type_id) {
+			stack.retain(|entry| !Arc::ptr_eq(&entry.hits, hits));
+			if stack.is_empty() {
+				overrides.remove(&type_id);
+			}
+		}
+	}
+}
+
+/// Guard for a type-level override installed with
+/// [`InjectionContext::override_with`](crate::InjectionContext::override_with).
+///
+/// While the guard is alive every resolution of the type, in the context and
+/// in all contexts forked from it, returns the override. Dropping the guard
+/// reverts to the previous override or to normal resolution.
+#[must_use = "the override is removed when the guard is dropped"]
+pub struct ScopedOverride {
+	type_id: TypeId,
+	type_name: &'static str,
+	hits: Arc<AtomicUsize>,
+	registry: Weak<OverrideRegistry>,
+}
+
+impl ScopedOverride {
+	/// Returns the name of the overridden type.
+	pub fn type_name(&self) -> &'static str {
+		self.type_name
+	}
+
+	/// Returns how many times the override has been resolved.
+	pub fn hits(&self) -> usize {
+		self.hits.load(Ordering::Relaxed)
+	}
+
+	/// Returns `true` if the override has been resolved at least once.
+	pub fn is_consumed(&self) -> bool {
+		self.hits() > 0
+	}
+
+	/// Asserts that the override has been resolved at least once.
+	///
+	/// # Panics
+	///
+	/// Panics if the overridden type was never resolved, which usually means
+	/// the code under test does not depend on it anymore.
+	#[track_caller]
+	pub fn assert_consumed(&self) {
+		assert!(
+			self.is_consumed(),
+			"override for `{}` was never resolved",
+			self.type_name
+		);
+	}
+}
+
+impl Drop for ScopedOverride {
+	fn drop(&mut self) {
+		if let Some(registry) = self.registry.upgrade() {
+			registry.remove_type(self.type_id, &self.hits);
+		}
+	}
+}
+
+impl std::fmt::Debug for ScopedOverride {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("ScopedOverride")
+			.field("type_name", &self.type_name)
+			.field("hits", &self.hits())
+			.finish()
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
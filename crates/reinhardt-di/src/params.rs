@@ -401,6 +401,62 @@ impl ParamContext {
 		*guard = Some(bytes.clone());
 		Ok(bytes)
 	}
+
+	/// Read at most `limit` bytes of the request body, caching them like
+	/// [`read_body_cached`](Self::read_body_cached).
+	///
+	/// Unlike `read_body_cached`, this also reads streamed bodies, and stops
+	/// reading as soon as the limit is crossed.
+	///
+	/// Returns `ParamError::PayloadTooLarge` when the body exceeds `limit`
+	/// and `ParamError::BodyError` when it cannot be read.
+	pub async fn read_body_cached_limited(
+		&self,
+		request: &reinhardt_http::Request,
+		limit: usize,
+	) -> ParamResult<bytes::Bytes> {
+		let too_large = || {
+			ParamError::PayloadTooLarge(format!(
+				"Request body exceeds maximum allowed size of {} bytes",
+				limit
+			))
+		};
+
+		if let Some(bytes) = self.cached_body() {
+			return if bytes.len() > limit {
+				Err(too_large())
+			} else {
+				Ok(bytes)
+			};
+		}
+
+		// The lock is not held across the read; extractors of one request
+		// run sequentially, and a body consumed by another path falls back
+		// to the cache below.
+		match request.bytes_limited(limit).await {
+			Ok(bytes) => {
+				*self
+					.body_cache
+					.lock()
+					.expect("ParamContext body_cache mutex poisoned") = Some(bytes.clone());
+				Ok(bytes)
+			}
+			Err(reinhardt_http::BodyError::TooLarge { .. }) => Err(too_large()),
+			Err(reinhardt_http::BodyError::AlreadyConsumed) => {
+				self.cached_body().ok_or_else(|| {
+					ParamError::BodyError("Request body has already been consumed".to_string())
+				})
+			}
+			Err(e) => Err(ParamError::BodyError(e.to_string())),
+		}
+	}
+
+	fn cached_body(&self) -> Option<bytes::Bytes> {
+		self.body_cache
+			.lock()
+			.expect("ParamContext body_cache mutex poisoned")
+			.clone()
+	}
 }
 
 impl Default for ParamContext {
@@ -1,7 +1,7 @@
 //! Raw body extraction
 
 use async_trait::async_trait;
-use reinhardt_http::Request;
+use reinhardt_http::{BodyError, Request};
 
 use super::{ParamContext, ParamError, ParamResult, extract::FromRequest};
 
@@ -26,20 +26,24 @@ pub struct Body(pub Vec<u8>);
 #[async_trait]
 impl FromRequest for Body {
 	async fn from_request(req: &Request, _ctx: &ParamContext) -> ParamResult<Self> {
-		// Extract raw body bytes from request
+		// Enforce body size limit while reading to prevent memory exhaustion
 		let body_bytes = req
-			.read_body()
-			.map_err(|e| ParamError::BodyError(format!("Failed to read body: {}", e)))?;
-
-		// Enforce body size limit to prevent memory exhaustion
-		if body_bytes.len() > DEFAULT_MAX_BODY_SIZE {
-			return Err(ParamError::PayloadTooLarge(format!(
-				"Request body size {} bytes exceeds maximum allowed size of {} bytes",
-				body_bytes.len(),
-				DEFAULT_MAX_BODY_SIZE
-			)));
-		}
+			.bytes_limited(DEFAULT_MAX_BODY_SIZE)
+			.await
+			.map_err(|e| body_error("Request", e))?;
 
 		Ok(Body(body_bytes.to_vec()))
 	}
 }
+
+/// Map a [`BodyError`] to the matching [`ParamError`], naming the body
+/// `kind` ("JSON", "Form", ...) in the payload-too-large message
+pub(crate) fn body_error(kind: &str, error: BodyError) -> ParamError {
+	match error {
+		BodyError::TooLarge { limit } => ParamError::PayloadTooLarge(format!(
+			"{} body exceeds maximum allowed size of {} bytes",
+			kind, limit
+		)),
+		e => ParamError::BodyError(format!("Failed to read body: {}", e)),
+	}
+}
@@ -80,19 +80,12 @@ impl<T> Form<T> {
 			))
 		})?;
 
-		// Read body
+		// Read body, enforcing the size limit while reading to prevent
+		// memory exhaustion
 		let body = req
-			.read_body()
-			.map_err(|e| ParamError::BodyError(format!("Failed to read body: {}", e)))?;
-
-		// Enforce body size limit to prevent memory exhaustion
-		if body.len() > DEFAULT_MAX_FORM_BODY_SIZE {
-			return Err(ParamError::PayloadTooLarge(format!(
-				"Multipart form body size {} bytes exceeds maximum allowed size of {} bytes",
-				body.len(),
-				DEFAULT_MAX_FORM_BODY_SIZE
-			)));
-		}
+			.bytes_limited(DEFAULT_MAX_FORM_BODY_SIZE)
+			.await
+			.map_err(|e| super::body::body_error("Multipart form", e))?;
 
 		// Convert Bytes to Stream
 		let stream = once(ready(Ok::<_, std::io::Error>(body)));
@@ -186,18 +179,11 @@ where
 
 		// Parse the body as form data
 		if content_type.contains("application/x-www-form-urlencoded") {
+			// Enforce body size limit while reading to prevent memory exhaustion
 			let body_bytes = req
-				.read_body()
-				.map_err(|e| ParamError::BodyError(format!("Failed to read body: {}", e)))?;
-
-			// Enforce body size limit to prevent memory exhaustion
-			if body_bytes.len() > DEFAULT_MAX_FORM_BODY_SIZE {
-				return Err(ParamError::PayloadTooLarge(format!(
-					"Form body size {} bytes exceeds maximum allowed size of {} bytes",
-					body_bytes.len(),
-					DEFAULT_MAX_FORM_BODY_SIZE
-				)));
-			}
+				.bytes_limited(DEFAULT_MAX_FORM_BODY_SIZE)
+				.await
+				.map_err(|e| super::body::body_error("Form", e))?;
 
 			let body_str = std::str::from_utf8(&body_bytes)
 				.map_err(|e| ParamError::BodyError(format!("Invalid UTF-8 in body: {}", e)))?;
@@ -117,16 +117,12 @@ where
 		// dependencies that each carry a body parameter — see #4645)
 		// reuses the same bytes instead of failing with "body already
 		// consumed" on the second call.
-		let body_bytes = ctx.read_body_cached(req)?;
-
-		// Enforce body size limit to prevent memory exhaustion
-		if body_bytes.len() > DEFAULT_MAX_JSON_BODY_SIZE {
-			return Err(ParamError::PayloadTooLarge(format!(
-				"JSON body size {} bytes exceeds maximum allowed size of {} bytes",
-				body_bytes.len(),
-				DEFAULT_MAX_JSON_BODY_SIZE
-			)));
-		}
+		//
+		// The size limit is enforced while reading, so a streamed body is
+		// rejected without buffering more than the limit.
+		let body_bytes = ctx
+			.read_body_cached_limited(req, DEFAULT_MAX_JSON_BODY_SIZE)
+			.await?;
 
 		// Deserialize JSON from body bytes with detailed error context
 		serde_json::from_slice(&body_bytes).map(Json).map_err(|e| {
@@ -24,10 +24,6 @@ use super::{FromRequest, ParamContext, ParamError, ParamErrorContext, ParamResul
 #[cfg(feature = "multipart")]
 use async_trait::async_trait;
 #[cfg(feature = "multipart")]
-use futures_util::future::ready;
-#[cfg(feature = "multipart")]
-use futures_util::stream::once;
-#[cfg(feature = "multipart")]
 use reinhardt_http::Request;
 
 #[cfg(feature = "multipart")]
@@ -92,16 +88,14 @@ impl FromRequest for Multipart {
 			))
 		})?;
 
-		// Read body
+		// Hand the body to multer as a stream so fields of a streamed
+		// request are parsed as their chunks arrive
 		let body = req
-			.read_body()
+			.take_body()
 			.map_err(|e| ParamError::BodyError(format!("Failed to read body: {}", e)))?;
 
-		// Convert Bytes to Stream (multer expects a Stream)
-		let stream = once(ready(Ok::<_, std::io::Error>(body)));
-
 		// Create multipart parser
-		let multipart = MulterMultipart::new(stream, boundary);
+		let multipart = MulterMultipart::new(body, boundary);
 
 		Ok(Multipart(multipart))
 	}
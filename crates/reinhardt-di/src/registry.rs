@@ -84,7 +84,9 @@ where
 	T: Any + Send + Sync + 'static,
 {
 	async fn create(&self, ctx: &InjectionContext) -> DiResult<Arc<dyn Any + Send + Sync>> {
-		let ctx_arc = Arc::new(ctx.clone());
+		// Share the request scope so request-scoped dependencies the factory
+		// resolves are cached for the whole request
+		let ctx_arc = Arc::new(ctx.share_request_scope());
 		let instance = (self.factory)(ctx_arc).await?;
 		Ok(Arc::new(instance))
 	}
@@ -1,8 +1,15 @@
 //! Dependency scopes
 
+use crate::DiResult;
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
-use std::sync::{Arc, PoisonError, RwLock};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use tokio::sync::OnceCell;
+
+type AnyArc = Arc<dyn Any + Send + Sync>;
+type DisposeHook = Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = ()> + Send>> + Send>;
 
 /// Defines the lifetime scope of a dependency.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -13,10 +20,49 @@ pub enum Scope {
 	Singleton,
 }
 
+/// Disposal hooks of a request scope, run when the last handle is dropped
+/// unless [`RequestScope::dispose`] already ran them.
+#[derive(Default)]
+struct DisposeHooks(Mutex<Vec<DisposeHook>>);
+
+impl DisposeHooks {
+	fn take(&self) -> Vec<DisposeHook> {
+		std::mem::take(&mut *self.0.lock().unwrap_or_else(PoisonError::into_inner))
+	}
+}
+
+/// Run hooks in reverse registration order, like destructors
+async fn run_hooks(hooks: Vec<DisposeHook>) {
+	for hook in hooks.into_iter().rev() {
+		hook().await;
+	}
+}
+
+impl Drop for DisposeHooks {
+	fn drop(&mut self) {
+		let hooks = self.take();
+		if hooks.is_empty() {
+			return;
+		}
+		// Hooks are async, so they can only run on a runtime. Outside of one
+		// (e.g. a scope dropped after the runtime shut down) they are dropped.
+		if let Ok(handle) = tokio::runtime::Handle::try_current() {
+			handle.spawn(run_hooks(hooks));
+		}
+	}
+}
+
 /// Per-request dependency cache that stores resolved instances for the duration of a request.
+///
+/// Besides the cache, a request scope tracks in-flight initializations so that
+/// each request-scoped dependency is created at most once per request (see
+/// [`get_or_try_init`](Self::get_or_try_init)), and disposal hooks that run
+/// when the request finishes (see [`on_dispose`](Self::on_dispose)).
 #[derive(Clone)]
 pub struct RequestScope {
-	cache: Arc<RwLock<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>>,
+	cache: Arc<RwLock<HashMap<TypeId, AnyArc>>>,
+	pending: Arc<Mutex<HashMap<TypeId, Arc<OnceCell<AnyArc>>>>>,
+	dispose_hooks: Arc<DisposeHooks>,
 }
 
 impl RequestScope {
@@ -32,6 +78,8 @@ impl RequestScope {
 	pub fn new() -> Self {
 		Self {
 			cache: Arc::new(RwLock::new(HashMap::new())),
+			pending: Arc::new(Mutex::new(HashMap::new())),
+			dispose_hooks: Arc::new(DisposeHooks::default()),
 		}
 	}
 	/// Retrieves a value from the request scope cache by type.
@@ -101,6 +149,114 @@ impl RequestScope {
 		let type_id = TypeId::of::<T>();
 		cache.insert(type_id, value);
 	}
+
+	/// Returns the cached value of type `T`, creating it with `init` if this
+	/// is the first request for it in this scope.
+	///
+	/// Concurrent callers for the same type wait for the first initialization
+	/// instead of running `init` again, so the value is created at most once
+	/// per request. If `init` fails, the error is returned and the next caller
+	/// retries.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_di::RequestScope;
+	/// use std::sync::Arc;
+	///
+	/// # #[tokio::main(flavor = "current_thread")]
+	/// # async fn main() {
+	/// let scope = RequestScope::new();
+	/// let first = scope.get_or_try_init(|| async { Ok(Arc::new(1u8)) }).await.unwrap();
+	/// let second = scope.get_or_try_init(|| async { Ok(Arc::new(2u8)) }).await.unwrap();
+	/// assert!(Arc::ptr_eq(&first, &second));
+	/// # }
+	/// ```
+	pub async fn get_or_try_init<T, F, Fut>(&self, init: F) -> DiResult<Arc<T>>
+	where
+		T: Any + Send + Sync,
+		F: FnOnce() -> Fut,
+		Fut: Future<Output = DiResult<Arc<T>>>,
+	{
+		if let Some(cached) = self.get::<T>() {
+			return Ok(cached);
+		}
+
+		let type_id = TypeId::of::<T>();
+		let cell = Arc::clone(
+			self.pending
+				.lock()
+				.unwrap_or_else(PoisonError::into_inner)
+				.entry(type_id)
+				.or_default(),
+		);
+		let value = cell
+			.get_or_try_init(|| async { init().await.map(|value| value as AnyArc) })
+			.await?
+			.clone();
+
+		self.cache
+			.write()
+			.unwrap_or_else(PoisonError::into_inner)
+			.insert(type_id, Arc::clone(&value));
+		self.pending
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.remove(&type_id);
+
+		value
+			.downcast::<T>()
+			.map_err(|_| crate::DiError::TypeMismatch {
+				expected: std::any::type_name::<T>().to_string(),
+				actual: "<request-scoped value>".to_string(),
+			})
+	}
+
+	/// Registers a hook to run when the request finishes.
+	///
+	/// Hooks run in reverse registration order, either when [`dispose`](Self::dispose)
+	/// is awaited or, failing that, on the Tokio runtime once the last handle to
+	/// this scope is dropped. Use them to release per-request resources such as
+	/// pooled connections or open transactions.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_di::RequestScope;
+	/// use std::sync::Arc;
+	/// use std::sync::atomic::{AtomicBool, Ordering};
+	///
+	/// # #[tokio::main(flavor = "current_thread")]
+	/// # async fn main() {
+	/// let released = Arc::new(AtomicBool::new(false));
+	/// let scope = RequestScope::new();
+	///
+	/// let flag = Arc::clone(&released);
+	/// scope.on_dispose(move || async move { flag.store(true, Ordering::SeqCst) });
+	///
+	/// scope.dispose().await;
+	/// assert!(released.load(Ordering::SeqCst));
+	/// # }
+	/// ```
+	pub fn on_dispose<F, Fut>(&self, hook: F)
+	where
+		F: FnOnce() -> Fut + Send + 'static,
+		Fut: Future<Output = ()> + Send + 'static,
+	{
+		self.dispose_hooks
+			.0
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.push(Box::new(move || Box::pin(hook())));
+	}
+
+	/// Runs the registered disposal hooks now, in reverse registration order.
+	///
+	/// Each hook runs at most once; hooks registered afterwards run on the
+	/// next call or when the scope is dropped.
+	pub async fn dispose(&self) {
+		run_hooks(self.dispose_hooks.take()).await;
+	}
 }
 
 impl RequestScope {
@@ -108,10 +264,14 @@ impl RequestScope {
 	///
 	/// The cloned scope contains the same cached entries as the original,
 	/// but modifications to either scope will not affect the other.
+	/// Disposal hooks are shared, since the cached instances they release
+	/// are shared too; they run once both scopes are gone.
 	pub fn deep_clone(&self) -> Self {
 		let cache = self.cache.read().unwrap_or_else(PoisonError::into_inner);
 		Self {
 			cache: Arc::new(RwLock::new(cache.clone())),
+			pending: Arc::new(Mutex::new(HashMap::new())),
+			dispose_hooks: Arc::clone(&self.dispose_hooks),
 		}
 	}
 }
@@ -21,6 +21,11 @@ pub enum ValidationErrorKind {
 	CircularDependency,
 	/// A user-defined factory targets a framework-managed type.
 	FrameworkTypeOverride,
+	/// A dependency is provided by a module that does not export it to the
+	/// module that needs it.
+	UnexportedDependency,
+	/// More than one provider is registered for the same type.
+	DuplicateProvider,
 }
 
 /// A single validation error discovered during registry validation.
@@ -43,6 +48,8 @@ impl fmt::Display for ValidationError {
 			ValidationErrorKind::ScopeIncompatibility => "[SCOPE]",
 			ValidationErrorKind::CircularDependency => "[CYCLE]",
 			ValidationErrorKind::FrameworkTypeOverride => "[OVERRIDE]",
+			ValidationErrorKind::UnexportedDependency => "[UNEXPORTED]",
+			ValidationErrorKind::DuplicateProvider => "[DUPLICATE]",
 		};
 		write!(f, "{} {}", prefix, self.message)
 	}
@@ -333,6 +340,32 @@ pub fn format_validation_report(errors: &[ValidationError]) -> String {
 		report.push('\n');
 	}
 
+	let unexported: Vec<_> = errors
+		.iter()
+		.filter(|e| e.kind == ValidationErrorKind::UnexportedDependency)
+		.collect();
+
+	if !unexported.is_empty() {
+		report.push_str("Unexported Dependencies:\n");
+		for err in &unexported {
+			report.push_str(&format!("  - {}\n", err.message));
+		}
+		report.push('\n');
+	}
+
+	let duplicate: Vec<_> = errors
+		.iter()
+		.filter(|e| e.kind == ValidationErrorKind::DuplicateProvider)
+		.collect();
+
+	if !duplicate.is_empty() {
+		report.push_str("Duplicate Providers:\n");
+		for err in &duplicate {
+			report.push_str(&format!("  - {}\n", err.message));
+		}
+		report.push('\n');
+	}
+
 	report
 }
 
@@ -0,0 +1,164 @@
+//! Tests for request-scoped resolution: at-most-once creation, error chains
+//! and disposal hooks
+
+use reinhardt_di::{
+	DependencyRegistry, DependencyScope, DiError, InjectionContext, SingletonScope,
+};
+use rstest::rstest;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Debug)]
+struct Connection;
+
+#[derive(Debug)]
+struct UserRepository;
+
+#[derive(Debug)]
+struct OrderRepository;
+
+#[derive(Debug)]
+struct Checkout;
+
+fn root_context(registry: DependencyRegistry) -> InjectionContext {
+	InjectionContext::builder(SingletonScope::new())
+		.with_registry(Arc::new(registry))
+		.build()
+}
+
+#[rstest]
+#[tokio::test]
+async fn request_scoped_dependency_is_created_once_per_request() {
+	// Arrange
+	let created = Arc::new(AtomicUsize::new(0));
+	let registry = DependencyRegistry::new();
+	let counter = Arc::clone(&created);
+	registry.register_async::<Connection, _, _>(DependencyScope::Request, move |_ctx| {
+		let counter = Arc::clone(&counter);
+		async move {
+			counter.fetch_add(1, Ordering::SeqCst);
+			Ok(Connection)
+		}
+	});
+	registry.register_async::<UserRepository, _, _>(DependencyScope::Request, |ctx| async move {
+		ctx.resolve::<Connection>().await?;
+		Ok(UserRepository)
+	});
+	registry.register_async::<OrderRepository, _, _>(DependencyScope::Request, |ctx| async move {
+		ctx.resolve::<Connection>().await?;
+		Ok(OrderRepository)
+	});
+	let root = root_context(registry);
+
+	// Act
+	let first_request = root.fork();
+	first_request.resolve::<UserRepository>().await.unwrap();
+	first_request.resolve::<OrderRepository>().await.unwrap();
+	let second_request = root.fork();
+	second_request.resolve::<UserRepository>().await.unwrap();
+
+	// Assert
+	assert_eq!(created.load(Ordering::SeqCst), 2);
+}
+
+#[rstest]
+#[tokio::test]
+async fn nested_provider_failure_reports_resolution_chain() {
+	// Arrange
+	let registry = DependencyRegistry::new();
+	registry.register_async::<Connection, _, _>(DependencyScope::Request, |_ctx| async {
+		Err::<Connection, _>(DiError::ProviderError("pool exhausted".to_string()))
+	});
+	registry.register_async::<UserRepository, _, _>(DependencyScope::Request, |ctx| async move {
+		ctx.resolve::<Connection>().await?;
+		Ok(UserRepository)
+	});
+	registry.register_async::<Checkout, _, _>(DependencyScope::Request, |ctx| async move {
+		ctx.resolve::<UserRepository>().await?;
+		Ok(Checkout)
+	});
+	let request = root_context(registry).fork();
+
+	// Act
+	let error = request.resolve::<Checkout>().await.unwrap_err();
+
+	// Assert
+	let DiError::ResolutionFailed { path, source } = &error else {
+		panic!("expected ResolutionFailed, got {error:?}");
+	};
+	let short_names: Vec<&str> = path
+		.iter()
+		.map(|name| name.rsplit("::").next().unwrap())
+		.collect();
+	assert_eq!(
+		short_names,
+		vec!["Checkout", "UserRepository", "Connection"]
+	);
+	assert!(matches!(**source, DiError::ProviderError(ref msg) if msg == "pool exhausted"));
+	assert!(error.to_string().contains("pool exhausted"));
+}
+
+#[rstest]
+#[tokio::test]
+async fn direct_provider_failure_is_not_wrapped() {
+	// Arrange
+	let registry = DependencyRegistry::new();
+	registry.register_async::<Connection, _, _>(DependencyScope::Request, |_ctx| async {
+		Err::<Connection, _>(DiError::ProviderError("pool exhausted".to_string()))
+	});
+	let request = root_context(registry).fork();
+
+	// Act
+	let error = request.resolve::<Connection>().await.unwrap_err();
+
+	// Assert
+	assert!(matches!(error, DiError::ProviderError(_)));
+}
+
+#[rstest]
+#[tokio::test]
+async fn recovered_failure_is_not_reported() {
+	// Arrange
+	let registry = DependencyRegistry::new();
+	registry.register_async::<Connection, _, _>(DependencyScope::Request, |_ctx| async {
+		Err::<Connection, _>(DiError::ProviderError("pool exhausted".to_string()))
+	});
+	registry.register_async::<Checkout, _, _>(DependencyScope::Request, |ctx| async move {
+		// Fall back instead of propagating, then fail for another reason
+		let _ = ctx.resolve::<Connection>().await;
+		Err::<Checkout, _>(DiError::ProviderError("cart is empty".to_string()))
+	});
+	let request = root_context(registry).fork();
+
+	// Act
+	let error = request.resolve::<Checkout>().await.unwrap_err();
+
+	// Assert
+	assert!(matches!(error, DiError::ProviderError(ref msg) if msg == "cart is empty"));
+}
+
+#[rstest]
+#[tokio::test]
+async fn factory_disposal_hook_runs_when_request_ends() {
+	// Arrange
+	let released = Arc::new(AtomicUsize::new(0));
+	let registry = DependencyRegistry::new();
+	let counter = Arc::clone(&released);
+	registry.register_async::<Connection, _, _>(DependencyScope::Request, move |ctx| {
+		let counter = Arc::clone(&counter);
+		async move {
+			ctx.on_request_end(move || async move {
+				counter.fetch_add(1, Ordering::SeqCst);
+			});
+			Ok(Connection)
+		}
+	});
+	let request = root_context(registry).fork();
+	request.resolve::<Connection>().await.unwrap();
+
+	// Act
+	request.end_request().await;
+
+	// Assert
+	assert_eq!(released.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,99 @@
+//! Tests for type-level overrides installed with `InjectionContext::override_with`
+
+use reinhardt_di::{DependencyRegistry, DependencyScope, InjectionContext, SingletonScope};
+use rstest::rstest;
+use std::sync::Arc;
+
+#[derive(Debug, PartialEq)]
+struct EmailBackend(&'static str);
+
+#[derive(Debug, PartialEq)]
+struct Cache(&'static str);
+
+#[derive(Debug)]
+struct SignupService {
+	email: Arc<EmailBackend>,
+}
+
+fn root_context() -> InjectionContext {
+	let registry = DependencyRegistry::new();
+	registry.register_async::<EmailBackend, _, _>(DependencyScope::Singleton, |_ctx| async {
+		Ok(EmailBackend("smtp"))
+	});
+	registry.register_async::<Cache, _, _>(DependencyScope::Singleton, |_ctx| async {
+		Ok(Cache("redis"))
+	});
+	registry.register_async::<SignupService, _, _>(DependencyScope::Request, |ctx| async move {
+		Ok(SignupService {
+			email: ctx.resolve::<EmailBackend>().await?,
+		})
+	});
+	InjectionContext::builder(SingletonScope::new())
+		.with_registry(Arc::new(registry))
+		.build()
+}
+
+#[rstest]
+#[tokio::test]
+async fn override_replaces_nested_dependency_in_forked_contexts() {
+	// Arrange
+	let root = root_context();
+	let email = root.override_with(EmailBackend("fake"));
+
+	// Act
+	let service = root.fork().resolve::<SignupService>().await.unwrap();
+
+	// Assert
+	assert_eq!(*service.email, EmailBackend("fake"));
+	assert_eq!(email.hits(), 1);
+	email.assert_consumed();
+}
+
+#[rstest]
+#[tokio::test]
+async fn dropping_guard_restores_previous_resolution() {
+	// Arrange
+	let root = root_context();
+	let outer = root.override_with(EmailBackend("outer"));
+
+	// Act
+	let inner = root.override_with(EmailBackend("inner"));
+	let while_inner = root.fork().resolve::<EmailBackend>().await.unwrap();
+	drop(inner);
+	let while_outer = root.fork().resolve::<EmailBackend>().await.unwrap();
+	drop(outer);
+	let restored = root.fork().resolve::<EmailBackend>().await.unwrap();
+
+	// Assert
+	assert_eq!(*while_inner, EmailBackend("inner"));
+	assert_eq!(*while_outer, EmailBackend("outer"));
+	assert_eq!(*restored, EmailBackend("smtp"));
+}
+
+#[rstest]
+#[tokio::test]
+#[should_panic(expected = "overrides were never resolved")]
+async fn unconsumed_override_fails_assertion() {
+	// Arrange
+	let root = root_context();
+	let _email = root.override_with(EmailBackend("fake"));
+	let _cache = root.override_with(Cache("memory"));
+	root.fork().resolve::<SignupService>().await.unwrap();
+
+	// Act
+	root.assert_overrides_consumed();
+}
+
+#[rstest]
+fn unconsumed_override_reports_type() {
+	// Arrange
+	let root = root_context();
+	let cache = root.override_with(Cache("memory"));
+
+	// Act
+	let consumed = cache.is_consumed();
+
+	// Assert
+	assert!(!consumed);
+	assert!(cache.type_name().ends_with("Cache"));
+}
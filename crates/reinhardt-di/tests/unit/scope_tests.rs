@@ -170,3 +170,98 @@ fn request_scope_isolated_between_requests() {
 		retrieved2_again.as_ref().unwrap()
 	));
 }
+
+#[rstest]
+#[tokio::test]
+async fn request_scope_get_or_try_init_runs_init_once_for_concurrent_callers() {
+	// Arrange
+	let scope = RequestScope::new();
+	let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+	let init = |scope: RequestScope, calls: Arc<std::sync::atomic::AtomicUsize>| async move {
+		scope
+			.get_or_try_init(|| async move {
+				calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+				tokio::task::yield_now().await;
+				Ok(Arc::new(TestData {
+					value: "lazy".to_string(),
+				}))
+			})
+			.await
+	};
+
+	// Act
+	let (first, second) = tokio::join!(
+		tokio::spawn(init(scope.clone(), Arc::clone(&calls))),
+		tokio::spawn(init(scope.clone(), Arc::clone(&calls))),
+	);
+
+	// Assert
+	let first = first.unwrap().unwrap();
+	let second = second.unwrap().unwrap();
+	assert!(Arc::ptr_eq(&first, &second));
+	assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+	assert_eq!(scope.get::<TestData>().unwrap().value, "lazy");
+}
+
+#[rstest]
+#[tokio::test]
+async fn request_scope_get_or_try_init_retries_after_failure() {
+	// Arrange
+	let scope = RequestScope::new();
+	let failed = scope
+		.get_or_try_init::<TestData, _, _>(|| async {
+			Err(reinhardt_di::DiError::ProviderError(
+				"pool exhausted".to_string(),
+			))
+		})
+		.await;
+
+	// Act
+	let retried = scope
+		.get_or_try_init(|| async {
+			Ok(Arc::new(TestData {
+				value: "second try".to_string(),
+			}))
+		})
+		.await;
+
+	// Assert
+	assert!(failed.is_err());
+	assert_eq!(retried.unwrap().value, "second try");
+}
+
+#[rstest]
+#[tokio::test]
+async fn request_scope_dispose_runs_hooks_in_reverse_order_once() {
+	// Arrange
+	let scope = RequestScope::new();
+	let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+	for name in ["connection", "transaction"] {
+		let order = Arc::clone(&order);
+		scope.on_dispose(move || async move { order.lock().unwrap().push(name) });
+	}
+
+	// Act
+	scope.dispose().await;
+	scope.dispose().await;
+
+	// Assert
+	assert_eq!(*order.lock().unwrap(), vec!["transaction", "connection"]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn request_scope_drop_runs_pending_hooks() {
+	// Arrange
+	let scope = RequestScope::new();
+	let (tx, rx) = tokio::sync::oneshot::channel();
+	scope.on_dispose(move || async move {
+		let _ = tx.send("released");
+	});
+
+	// Act
+	drop(scope);
+
+	// Assert
+	assert_eq!(rx.await.unwrap(), "released");
+}
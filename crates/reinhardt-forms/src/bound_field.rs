@@ -1,4 +1,6 @@
 use crate::field::{FormField, Widget};
+use reinhardt_core::form_renderer::{FieldContext, FormRenderer, form_renderer};
+use std::collections::HashMap;
 
 /// BoundField represents a field bound to form data
 pub struct BoundField<'a> {
@@ -225,6 +227,66 @@ impl<'a> BoundField<'a> {
 	pub fn is_required(&self) -> bool {
 		self.field.required()
 	}
+	/// Render the label, widget, help text and errors with the installed
+	/// form renderer
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_forms::{BoundField, CharField, FormField};
+	///
+	/// let field: Box<dyn FormField> = Box::new(CharField::new("name".to_string()));
+	/// let bound = BoundField::new("form".to_string(), field.as_ref(), None, &[], "");
+	///
+	/// let html = bound.render();
+	/// assert!(html.contains("class=\"reinhardt-input\""));
+	/// assert!(html.contains("<label for=\"id_name\""));
+	/// ```
+	pub fn render(&self) -> String {
+		self.render_with(form_renderer())
+	}
+	/// Render the field with `renderer`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::form_renderer::BootstrapRenderer;
+	/// use reinhardt_forms::{BoundField, CharField, FormField};
+	///
+	/// let field: Box<dyn FormField> = Box::new(CharField::new("name".to_string()));
+	/// let bound = BoundField::new("form".to_string(), field.as_ref(), None, &[], "");
+	///
+	/// let html = bound.render_with(&BootstrapRenderer);
+	/// assert!(html.contains("class=\"form-control\""));
+	/// ```
+	pub fn render_with(&self, renderer: &dyn FormRenderer) -> String {
+		let widget = self.widget();
+		let id = self.id_for_label();
+		let mut attrs = HashMap::new();
+		attrs.insert(
+			"class".to_string(),
+			renderer.classes(widget.name()).input.to_string(),
+		);
+		// Radio buttons get one id per choice.
+		if !matches!(widget, Widget::RadioSelect { .. }) {
+			attrs.insert("id".to_string(), id.clone());
+		}
+		let value = self.value().and_then(|value| match value {
+			serde_json::Value::Null => None,
+			serde_json::Value::String(text) => Some(text.clone()),
+			other => Some(other.to_string()),
+		});
+		let input_html = widget.render_html(&self.html_name(), value.as_deref(), Some(&attrs));
+		renderer.render_field(&FieldContext {
+			widget: widget.name(),
+			id: &id,
+			label: Some(self.label().unwrap_or_else(|| self.name())),
+			input_html: &input_html,
+			help_text: self.help_text(),
+			errors: self.errors(),
+			required: self.is_required(),
+		})
+	}
 }
 
 #[cfg(test)]
@@ -288,4 +350,27 @@ mod tests {
 		assert!(bound.has_errors());
 		assert_eq!(bound.errors().len(), 1);
 	}
+
+	#[test]
+	fn test_bound_field_render_with_renderer() {
+		use reinhardt_core::form_renderer::BootstrapRenderer;
+
+		let field: Box<dyn FormField> = Box::new(CharField::new("name".to_string()));
+		let data = serde_json::json!("<John>");
+		let errors = vec!["Too short.".to_string()];
+
+		let bound = BoundField::new(
+			"test_form".to_string(),
+			field.as_ref(),
+			Some(&data),
+			&errors,
+			"",
+		);
+		let html = bound.render_with(&BootstrapRenderer);
+
+		assert!(html.starts_with("<div class=\"mb-3\">"));
+		assert!(html.contains("class=\"form-control\""));
+		assert!(html.contains("value=\"&lt;John&gt;\""));
+		assert!(html.contains("<li>Too short.</li>"));
+	}
 }
@@ -1,3 +1,4 @@
+use reinhardt_core::form_media::Media;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -80,6 +81,16 @@ pub enum FieldError {
 pub type FieldResult<T> = Result<T, FieldError>;
 
 impl FieldError {
+	/// Machine-readable code of the error: `required` or `invalid`
+	///
+	/// Reported as [`ValidationError::code`](reinhardt_core::validators::ValidationError::code)
+	/// by [`Form::validation_errors`](crate::Form::validation_errors).
+	pub fn code(&self) -> &'static str {
+		match self {
+			FieldError::Required(_) => "required",
+			FieldError::Invalid(_) | FieldError::Validation(_) => "invalid",
+		}
+	}
 	/// Creates a required field error
 	///
 	/// # Examples
@@ -166,6 +177,33 @@ pub enum Widget {
 }
 
 impl Widget {
+	/// Name of the widget, as used by `form!` and form renderers.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_forms::Widget;
+	///
+	/// assert_eq!(Widget::TextArea.name(), "Textarea");
+	/// assert_eq!(Widget::Select { choices: vec![] }.name(), "Select");
+	/// ```
+	pub fn name(&self) -> &'static str {
+		match self {
+			Widget::TextInput => "TextInput",
+			Widget::PasswordInput => "PasswordInput",
+			Widget::EmailInput => "EmailInput",
+			Widget::NumberInput => "NumberInput",
+			Widget::TextArea => "Textarea",
+			Widget::Select { .. } => "Select",
+			Widget::CheckboxInput => "CheckboxInput",
+			Widget::RadioSelect { .. } => "RadioSelect",
+			Widget::DateInput => "DateInput",
+			Widget::DateTimeInput => "DateTimeInput",
+			Widget::FileInput => "FileInput",
+			Widget::HiddenInput => "HiddenInput",
+		}
+	}
+
 	/// Renders the widget as HTML with XSS protection.
 	///
 	/// All user-controlled values (name, value, attributes, choices) are
@@ -427,6 +465,14 @@ pub trait FormField: Send + Sync {
 	fn error_messages(&self) -> HashMap<ErrorType, String> {
 		HashMap::new()
 	}
+
+	/// CSS and JavaScript assets required by this field's widget
+	///
+	/// Fields backed by a JavaScript widget (date pickers, rich text editors)
+	/// override this so [`Form::media`](crate::Form::media) can include them.
+	fn media(&self) -> Media {
+		Media::new()
+	}
 }
 
 #[cfg(test)]
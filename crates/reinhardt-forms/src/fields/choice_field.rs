@@ -1,4 +1,5 @@
 use crate::field::{FieldError, FieldResult, FormField, Widget};
+use reinhardt_core::choices::Choices;
 
 /// ChoiceField for selecting from predefined choices
 #[derive(Debug, Clone)]
@@ -44,6 +45,13 @@ impl ChoiceField {
 			choices,
 		}
 	}
+
+	/// Create a ChoiceField offering every choice of `C`
+	///
+	/// Labels are translated into the active language when the field is built.
+	pub fn from_choices<C: Choices>(name: String) -> Self {
+		Self::new(name, C::pairs())
+	}
 }
 
 impl FormField for ChoiceField {
@@ -138,6 +146,11 @@ impl MultipleChoiceField {
 			choices,
 		}
 	}
+
+	/// Creates a `MultipleChoiceField` offering every choice of `C`.
+	pub fn from_choices<C: Choices>(name: String) -> Self {
+		Self::new(name, C::pairs())
+	}
 }
 
 impl FormField for MultipleChoiceField {
@@ -205,6 +218,55 @@ impl FormField for MultipleChoiceField {
 mod tests {
 	use super::*;
 
+	#[derive(Debug, PartialEq)]
+	enum Color {
+		Red,
+		DarkBlue,
+	}
+
+	impl Choices for Color {
+		fn choices() -> &'static [Self] {
+			&[Self::Red, Self::DarkBlue]
+		}
+
+		fn value(&self) -> &'static str {
+			match self {
+				Self::Red => "red",
+				Self::DarkBlue => "dark_blue",
+			}
+		}
+
+		fn label(&self) -> &'static str {
+			match self {
+				Self::Red => "Red",
+				Self::DarkBlue => "Dark blue",
+			}
+		}
+
+		fn from_value(value: &str) -> Option<Self> {
+			match value {
+				"red" => Some(Self::Red),
+				"dark_blue" => Some(Self::DarkBlue),
+				_ => None,
+			}
+		}
+	}
+
+	#[test]
+	fn test_choicefield_from_choices() {
+		let field = ChoiceField::from_choices::<Color>("color".to_string());
+
+		assert_eq!(
+			field.choices,
+			vec![
+				("red".to_string(), "Red".to_string()),
+				("dark_blue".to_string(), "Dark blue".to_string()),
+			]
+		);
+		assert!(field.clean(Some(&serde_json::json!("dark_blue"))).is_ok());
+		assert!(field.clean(Some(&serde_json::json!("Dark blue"))).is_err());
+	}
+
 	#[test]
 	fn test_choicefield_valid() {
 		let choices = vec![
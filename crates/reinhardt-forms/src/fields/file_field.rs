@@ -1,4 +1,7 @@
 use crate::field::{FieldError, FieldResult, FormField, Widget};
+use base64::Engine;
+use reinhardt_core::security::upload_scan::UploadScanner;
+use std::sync::Arc;
 
 /// Default maximum file size: 10 MB
 const DEFAULT_FILE_MAX_SIZE: u64 = 10 * 1024 * 1024;
@@ -56,6 +59,34 @@ fn validate_filename_safety(filename: &str) -> FieldResult<()> {
 	Ok(())
 }
 
+/// Run `scanner` over the base64 `content` of an uploaded file value.
+///
+/// The content is required once a scanner is configured, and scanner errors
+/// reject the file: an unchecked upload must never be accepted.
+fn scan_upload(
+	scanner: &dyn UploadScanner,
+	filename: &str,
+	obj: &serde_json::Map<String, serde_json::Value>,
+) -> FieldResult<()> {
+	let encoded = obj
+		.get("content")
+		.and_then(|c| c.as_str())
+		.ok_or_else(|| FieldError::Invalid("Missing file content".to_string()))?;
+	let content = base64::engine::general_purpose::STANDARD
+		.decode(encoded)
+		.map_err(|_| FieldError::Invalid("File content is not valid base64".to_string()))?;
+
+	match scanner.scan(filename, &content) {
+		Ok(verdict) if !verdict.is_flagged() => Ok(()),
+		Ok(_) => Err(FieldError::Validation(
+			"The submitted file was rejected by the upload scanner".to_string(),
+		)),
+		Err(_) => Err(FieldError::Validation(
+			"The submitted file could not be scanned".to_string(),
+		)),
+	}
+}
+
 /// FileField for file upload
 pub struct FileField {
 	/// The field name used as the form data key.
@@ -76,6 +107,8 @@ pub struct FileField {
 	pub allow_empty_file: bool,
 	/// Maximum file size in bytes. Defaults to 10 MB.
 	pub max_size: u64,
+	/// Scanner run over the uploaded content before it is accepted.
+	pub scanner: Option<Arc<dyn UploadScanner>>,
 }
 
 impl FileField {
@@ -101,6 +134,7 @@ impl FileField {
 			max_length: None,
 			allow_empty_file: false,
 			max_size: DEFAULT_FILE_MAX_SIZE,
+			scanner: None,
 		}
 	}
 
@@ -118,6 +152,29 @@ impl FileField {
 		self.max_size = max_size;
 		self
 	}
+
+	/// Scan uploaded content with `scanner` during cleaning.
+	///
+	/// The bound value must then carry the file bytes as base64 under
+	/// `content`; files the scanner flags or cannot scan are rejected.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::security::upload_scan::FileTypeScanner;
+	/// use reinhardt_forms::FormField;
+	/// use reinhardt_forms::fields::FileField;
+	/// use std::sync::Arc;
+	///
+	/// let field = FileField::new("upload".to_string())
+	///     .with_scanner(Arc::new(FileTypeScanner::new()));
+	/// let exe = serde_json::json!({"filename": "invoice.pdf", "size": 4, "content": "TVqQAA=="});
+	/// assert!(field.clean(Some(&exe)).is_err());
+	/// ```
+	pub fn with_scanner(mut self, scanner: Arc<dyn UploadScanner>) -> Self {
+		self.scanner = Some(scanner);
+		self
+	}
 }
 
 impl FormField for FileField {
@@ -202,6 +259,10 @@ impl FormField for FileField {
 					));
 				}
 
+				if let Some(scanner) = &self.scanner {
+					scan_upload(scanner.as_ref(), filename, obj)?;
+				}
+
 				Ok(v.clone())
 			}
 		}
@@ -228,6 +289,8 @@ pub struct ImageField {
 	pub allow_empty_file: bool,
 	/// Maximum file size in bytes. Defaults to 5 MB.
 	pub max_size: u64,
+	/// Scanner run over the uploaded content before it is accepted.
+	pub scanner: Option<Arc<dyn UploadScanner>>,
 }
 
 impl ImageField {
@@ -253,6 +316,7 @@ impl ImageField {
 			max_length: None,
 			allow_empty_file: false,
 			max_size: DEFAULT_IMAGE_MAX_SIZE,
+			scanner: None,
 		}
 	}
 
@@ -271,6 +335,14 @@ impl ImageField {
 		self
 	}
 
+	/// Scan uploaded content with `scanner` during cleaning.
+	///
+	/// See [`FileField::with_scanner`].
+	pub fn with_scanner(mut self, scanner: Arc<dyn UploadScanner>) -> Self {
+		self.scanner = Some(scanner);
+		self
+	}
+
 	fn is_valid_image_extension(filename: &str) -> bool {
 		// NOTE: SVG is intentionally excluded due to Stored XSS risk.
 		// SVG files can contain arbitrary JavaScript that executes when served
@@ -373,6 +445,10 @@ impl FormField for ImageField {
 					));
 				}
 
+				if let Some(scanner) = &self.scanner {
+					scan_upload(scanner.as_ref(), filename, obj)?;
+				}
+
 				Ok(v.clone())
 			}
 		}
@@ -382,6 +458,7 @@ impl FormField for ImageField {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use reinhardt_core::security::upload_scan::FileTypeScanner;
 	use rstest::rstest;
 
 	// =========================================================================
@@ -663,6 +740,45 @@ mod tests {
 		assert_eq!(field.clean(Some(&file)).is_ok(), expected_ok);
 	}
 
+	// ---- Upload Scanning ----
+
+	#[rstest]
+	#[case("JVBERi0xLjc=", true)] // "%PDF-1.7"
+	#[case("TVqQAA==", false)] // Windows PE header
+	fn test_filefield_scanner_verdict(#[case] content: &str, #[case] expected_ok: bool) {
+		// Arrange
+		let field =
+			FileField::new("document".to_string()).with_scanner(Arc::new(FileTypeScanner::new()));
+		let file = serde_json::json!({
+			"filename": "invoice.pdf",
+			"size": 8,
+			"content": content
+		});
+
+		// Act
+		let result = field.clean(Some(&file));
+
+		// Assert
+		assert_eq!(result.is_ok(), expected_ok);
+	}
+
+	#[rstest]
+	fn test_filefield_scanner_requires_content() {
+		// Arrange
+		let field =
+			FileField::new("document".to_string()).with_scanner(Arc::new(FileTypeScanner::new()));
+		let file = serde_json::json!({
+			"filename": "invoice.pdf",
+			"size": 8
+		});
+
+		// Act
+		let result = field.clean(Some(&file));
+
+		// Assert
+		assert!(matches!(result, Err(FieldError::Invalid(ref msg)) if msg.contains("content")));
+	}
+
 	// =========================================================================
 	// ImageField Tests
 	// =========================================================================
@@ -1,6 +1,9 @@
 use crate::bound_field::BoundField;
 use crate::field::{FieldError, FormField};
 use crate::wasm_compat::ValidationRule;
+use reinhardt_core::form_media::Media;
+use reinhardt_core::form_renderer::{FormRenderer, form_renderer};
+use reinhardt_core::validators::{NON_FIELD_ERRORS, ValidationError, ValidationErrors};
 use std::collections::HashMap;
 use std::ops::Index;
 
@@ -51,12 +54,45 @@ type FieldCleanFunction =
 /// to follow Rust conventions for internal/private identifiers.
 pub const ALL_FIELDS_KEY: &str = "_all";
 
+/// Record an error message with its code.
+///
+/// [`NON_FIELD_ERRORS`] is stored under [`ALL_FIELDS_KEY`].
+fn record_error(
+	errors: &mut HashMap<String, Vec<String>>,
+	codes: &mut HashMap<String, Vec<String>>,
+	field: &str,
+	code: &str,
+	message: String,
+) {
+	let field = if field == NON_FIELD_ERRORS {
+		ALL_FIELDS_KEY
+	} else {
+		field
+	};
+	errors.entry(field.to_string()).or_default().push(message);
+	codes
+		.entry(field.to_string())
+		.or_default()
+		.push(code.to_string());
+}
+
+/// Code of an error returned by a clean function.
+fn form_error_code(error: &FormError) -> &'static str {
+	match error {
+		FormError::Field { error, .. } => error.code(),
+		FormError::Validation(_) => "invalid",
+		FormError::NoInstance => "no_instance",
+	}
+}
+
 /// Form data structure (Phase 2-A: Enhanced with client-side validation rules)
 pub struct Form {
 	fields: Vec<Box<dyn FormField>>,
 	data: HashMap<String, serde_json::Value>,
 	initial: HashMap<String, serde_json::Value>,
 	errors: HashMap<String, Vec<String>>,
+	/// Codes of `errors`, index for index
+	error_codes: HashMap<String, Vec<String>>,
 	is_bound: bool,
 	clean_functions: Vec<CleanFunction>,
 	field_clean_functions: HashMap<String, FieldCleanFunction>,
@@ -69,6 +105,8 @@ pub struct Form {
 	csrf_token: Option<String>,
 	/// Whether CSRF validation is enabled
 	csrf_enabled: bool,
+	/// Form-level assets, in addition to those declared by the fields
+	media: Media,
 }
 
 impl Form {
@@ -89,6 +127,7 @@ impl Form {
 			data: HashMap::new(),
 			initial: HashMap::new(),
 			errors: HashMap::new(),
+			error_codes: HashMap::new(),
 			is_bound: false,
 			clean_functions: vec![],
 			field_clean_functions: HashMap::new(),
@@ -96,6 +135,7 @@ impl Form {
 			validation_rules: vec![],
 			csrf_token: None,
 			csrf_enabled: false,
+			media: Media::new(),
 		}
 	}
 	/// Create a new form with initial data
@@ -119,6 +159,7 @@ impl Form {
 			data: HashMap::new(),
 			initial,
 			errors: HashMap::new(),
+			error_codes: HashMap::new(),
 			is_bound: false,
 			clean_functions: vec![],
 			field_clean_functions: HashMap::new(),
@@ -126,6 +167,7 @@ impl Form {
 			validation_rules: vec![],
 			csrf_token: None,
 			csrf_enabled: false,
+			media: Media::new(),
 		}
 	}
 	/// Create a new form with a field prefix
@@ -145,6 +187,7 @@ impl Form {
 			data: HashMap::new(),
 			initial: HashMap::new(),
 			errors: HashMap::new(),
+			error_codes: HashMap::new(),
 			is_bound: false,
 			clean_functions: vec![],
 			field_clean_functions: HashMap::new(),
@@ -152,6 +195,7 @@ impl Form {
 			validation_rules: vec![],
 			csrf_token: None,
 			csrf_enabled: false,
+			media: Media::new(),
 		}
 	}
 	/// Add a field to the form
@@ -215,13 +259,17 @@ impl Form {
 		}
 
 		self.errors.clear();
+		self.error_codes.clear();
 
 		// Validate CSRF token if enabled
 		if !self.validate_csrf() {
-			self.errors
-				.entry(ALL_FIELDS_KEY.to_string())
-				.or_default()
-				.push("CSRF token missing or incorrect.".to_string());
+			record_error(
+				&mut self.errors,
+				&mut self.error_codes,
+				ALL_FIELDS_KEY,
+				"csrf",
+				"CSRF token missing or incorrect.".to_string(),
+			);
 			return false;
 		}
 
@@ -237,10 +285,13 @@ impl Form {
 								cleaned = further_cleaned;
 							}
 							Err(e) => {
-								self.errors
-									.entry(field.name().to_string())
-									.or_default()
-									.push(e.to_string());
+								record_error(
+									&mut self.errors,
+									&mut self.error_codes,
+									field.name(),
+									form_error_code(&e),
+									e.to_string(),
+								);
 								continue;
 							}
 						}
@@ -248,10 +299,13 @@ impl Form {
 					self.data.insert(field.name().to_string(), cleaned);
 				}
 				Err(e) => {
-					self.errors
-						.entry(field.name().to_string())
-						.or_default()
-						.push(e.to_string());
+					record_error(
+						&mut self.errors,
+						&mut self.error_codes,
+						field.name(),
+						e.code(),
+						e.to_string(),
+					);
 				}
 			}
 		}
@@ -259,24 +313,34 @@ impl Form {
 		// Run custom clean functions
 		for clean_fn in &self.clean_functions {
 			if let Err(e) = clean_fn(&self.data) {
+				let code = form_error_code(&e);
 				match e {
 					FormError::Field { field, error } => {
-						self.errors
-							.entry(field)
-							.or_default()
-							.push(error.to_string());
+						record_error(
+							&mut self.errors,
+							&mut self.error_codes,
+							&field,
+							code,
+							error.to_string(),
+						);
 					}
 					FormError::Validation(msg) => {
-						self.errors
-							.entry(ALL_FIELDS_KEY.to_string())
-							.or_default()
-							.push(msg);
+						record_error(
+							&mut self.errors,
+							&mut self.error_codes,
+							ALL_FIELDS_KEY,
+							code,
+							msg,
+						);
 					}
 					FormError::NoInstance => {
-						self.errors
-							.entry(ALL_FIELDS_KEY.to_string())
-							.or_default()
-							.push(e.to_string());
+						record_error(
+							&mut self.errors,
+							&mut self.error_codes,
+							ALL_FIELDS_KEY,
+							code,
+							e.to_string(),
+						);
 					}
 				}
 			}
@@ -297,10 +361,76 @@ impl Form {
 	/// Use [`ALL_FIELDS_KEY`] for non-field (form-wide / cross-field) errors so
 	/// they are exposed through the same inspection API as per-field errors.
 	pub fn add_error(&mut self, field_name: impl Into<String>, message: impl Into<String>) {
-		self.errors
-			.entry(field_name.into())
-			.or_default()
-			.push(message.into());
+		record_error(
+			&mut self.errors,
+			&mut self.error_codes,
+			&field_name.into(),
+			"invalid",
+			message.into(),
+		);
+	}
+	/// Returns the current errors as the shared [`ValidationErrors`] structure.
+	///
+	/// Errors under [`ALL_FIELDS_KEY`] become non-field errors.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_forms::{CharField, Form};
+	/// use std::collections::HashMap;
+	///
+	/// let mut form = Form::new();
+	/// form.add_field(Box::new(CharField::new("username".to_string()).required()));
+	/// form.bind(HashMap::new());
+	/// assert!(!form.is_valid());
+	///
+	/// let errors = form.validation_errors();
+	/// assert_eq!(errors.get("username")[0].code(), "required");
+	/// ```
+	pub fn validation_errors(&self) -> ValidationErrors {
+		let mut result = ValidationErrors::new();
+		for (field, messages) in &self.errors {
+			let codes = self.error_codes.get(field);
+			for (index, message) in messages.iter().enumerate() {
+				let code = codes
+					.and_then(|codes| codes.get(index))
+					.map(String::as_str)
+					.unwrap_or("invalid");
+				let error = ValidationError::coded(code, message.clone());
+				if field == ALL_FIELDS_KEY {
+					result.add_non_field(error);
+				} else {
+					result.add(field.clone(), error);
+				}
+			}
+		}
+		result
+	}
+	/// Append errors reported elsewhere, e.g. unique-constraint violations
+	/// mapped from database errors after saving.
+	///
+	/// Non-field errors are added under [`ALL_FIELDS_KEY`].
+	pub fn add_validation_errors(&mut self, errors: &ValidationErrors) {
+		for (field, field_errors) in errors.field_errors() {
+			for error in field_errors {
+				record_error(
+					&mut self.errors,
+					&mut self.error_codes,
+					field,
+					error.code(),
+					error.to_string(),
+				);
+			}
+		}
+		for error in errors.non_field_errors() {
+			record_error(
+				&mut self.errors,
+				&mut self.error_codes,
+				ALL_FIELDS_KEY,
+				error.code(),
+				error.to_string(),
+			);
+		}
 	}
 	/// Returns whether the form has been bound with submitted data.
 	pub fn is_bound(&self) -> bool {
@@ -922,6 +1052,66 @@ impl Form {
 		html
 	}
 
+	/// Add form-level assets, in addition to those declared by the fields.
+	pub fn add_media(&mut self, media: &Media) {
+		self.media.extend(media);
+	}
+
+	/// Assets required by this form: the form-level media followed by the
+	/// media of each field, with duplicates removed.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_forms::{CharField, Form, Media};
+	///
+	/// let mut form = Form::new();
+	/// form.add_field(Box::new(CharField::new("name".to_string())));
+	/// form.add_media(&Media::new().with_css("/static/css/forms.css"));
+	///
+	/// let media = form.media();
+	/// assert_eq!(media.css(), &["/static/css/forms.css".to_string()]);
+	/// assert!(media.js().is_empty());
+	/// ```
+	pub fn media(&self) -> Media {
+		let mut media = self.media.clone();
+		for field in &self.fields {
+			media.extend(&field.media());
+		}
+		media
+	}
+
+	/// Render every field with the installed form renderer.
+	///
+	/// The renderer is chosen with
+	/// `reinhardt_core::form_renderer::set_form_renderer`, usually from the
+	/// `renderer` key of the `[forms]` settings section.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_forms::{CharField, Form};
+	///
+	/// let mut form = Form::new();
+	/// form.add_field(Box::new(CharField::new("name".to_string())));
+	///
+	/// let html = form.render();
+	/// assert!(html.contains("name=\"name\""));
+	/// assert!(html.contains("reinhardt-field"));
+	/// ```
+	pub fn render(&self) -> String {
+		self.render_with(form_renderer())
+	}
+
+	/// Render every field with `renderer`.
+	pub fn render_with(&self, renderer: &dyn FormRenderer) -> String {
+		self.fields
+			.iter()
+			.filter_map(|field| self.get_bound_field(field.name()))
+			.map(|bound| bound.render_with(renderer))
+			.collect()
+	}
+
 	/// Returns a `BoundField` with the field's submitted data and errors attached.
 	pub fn get_bound_field<'a>(&'a self, name: &str) -> Option<BoundField<'a>> {
 		let field = self.get_field(name)?;
@@ -1014,6 +1204,28 @@ mod tests {
 		assert!(!form.errors().is_empty());
 	}
 
+	#[test]
+	fn test_validation_errors_round_trip() {
+		let mut form = Form::new();
+		form.add_field(Box::new(CharField::new("email".to_string())));
+
+		let mut reported = ValidationErrors::new();
+		reported.add(
+			"email",
+			ValidationError::NotUnique {
+				field: "email".to_string(),
+				value: "a@example.com".to_string(),
+			},
+		);
+		reported.add_non_field(ValidationError::coded("mismatch", "Passwords differ."));
+		form.add_validation_errors(&reported);
+
+		assert_eq!(form.errors()[ALL_FIELDS_KEY], vec!["Passwords differ."]);
+		let errors = form.validation_errors();
+		assert_eq!(errors.get("email")[0].code(), "unique");
+		assert_eq!(errors.non_field_errors()[0].code(), "mismatch");
+	}
+
 	// Additional tests based on Django forms tests
 
 	#[test]
@@ -1377,4 +1589,63 @@ mod tests {
 			&serde_json::json!("JOHN DOE")
 		);
 	}
+
+	struct DatePickerField {
+		inner: CharField,
+	}
+
+	impl FormField for DatePickerField {
+		fn name(&self) -> &str {
+			self.inner.name()
+		}
+		fn label(&self) -> Option<&str> {
+			self.inner.label()
+		}
+		fn required(&self) -> bool {
+			self.inner.required()
+		}
+		fn help_text(&self) -> Option<&str> {
+			self.inner.help_text()
+		}
+		fn widget(&self) -> &crate::field::Widget {
+			self.inner.widget()
+		}
+		fn initial(&self) -> Option<&serde_json::Value> {
+			self.inner.initial()
+		}
+		fn clean(
+			&self,
+			value: Option<&serde_json::Value>,
+		) -> crate::field::FieldResult<serde_json::Value> {
+			self.inner.clean(value)
+		}
+		fn media(&self) -> Media {
+			Media::new()
+				.with_css("/static/css/datepicker.css")
+				.with_js("/static/js/datepicker.js")
+		}
+	}
+
+	#[test]
+	fn test_form_media_collects_field_media_once() {
+		let mut form = Form::new();
+		form.add_media(&Media::new().with_css("/static/css/forms.css"));
+		for name in ["start", "end"] {
+			form.add_field(Box::new(DatePickerField {
+				inner: CharField::new(name.to_string()),
+			}));
+		}
+		form.add_field(Box::new(CharField::new("note".to_string())));
+
+		let media = form.media();
+
+		assert_eq!(
+			media.css(),
+			&[
+				"/static/css/forms.css".to_string(),
+				"/static/css/datepicker.css".to_string(),
+			]
+		);
+		assert_eq!(media.js(), &["/static/js/datepicker.js".to_string()]);
+	}
 }
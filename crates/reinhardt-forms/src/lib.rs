@@ -170,3 +170,5 @@ pub use model_form::{FieldType, FormModel, ModelForm, ModelFormBuilder, ModelFor
 pub use model_formset::{ModelFormSet, ModelFormSetBuilder, ModelFormSetConfig};
 pub use validators::{SlugValidator, UrlValidator};
 pub use wizard::{FormWizard, WizardStep};
+// Re-export so fields and forms can name the media type directly
+pub use reinhardt_core::form_media::Media;
@@ -0,0 +1,342 @@
+//! `#[derive(GraphQLModel)]` implementation
+//!
+//! Generates a `GraphQLModel` impl listing the filterable fields of a model
+//! struct, a `{Model}Page` GraphQL object type wrapping `ModelPage<Model>`,
+//! a `{Model}Query` object with the list and get resolvers, and, when a
+//! serializer is given, a `{Model}Mutation` object with the create, update
+//! and delete resolvers.
+
+use crate::crate_paths::get_reinhardt_graphql_crate;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, LitInt, Path};
+
+/// Container-level options parsed from `#[graphql_model(...)]`
+#[derive(Default)]
+struct ModelOptions {
+	max_page_size: Option<LitInt>,
+	serializer: Option<Path>,
+}
+
+fn parse_model_options(input: &DeriveInput) -> syn::Result<ModelOptions> {
+	let mut options = ModelOptions::default();
+	for attr in &input.attrs {
+		if !attr.path().is_ident("graphql_model") {
+			continue;
+		}
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("max_page_size") {
+				options.max_page_size = Some(meta.value()?.parse()?);
+				Ok(())
+			} else if meta.path.is_ident("serializer") {
+				options.serializer = Some(meta.value()?.parse()?);
+				Ok(())
+			} else {
+				Err(meta.error(
+					"unsupported graphql_model option, expected `max_page_size` or `serializer`",
+				))
+			}
+		})?;
+	}
+	Ok(options)
+}
+
+/// Returns `true` when a field is marked `#[graphql_model(skip_filter)]`
+fn is_filter_skipped(field: &syn::Field) -> syn::Result<bool> {
+	let mut skipped = false;
+	for attr in &field.attrs {
+		if !attr.path().is_ident("graphql_model") {
+			continue;
+		}
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("skip_filter") {
+				skipped = true;
+				Ok(())
+			} else {
+				Err(meta.error("unsupported graphql_model field option, expected `skip_filter`"))
+			}
+		})?;
+	}
+	Ok(skipped)
+}
+
+/// Convert a struct name to the snake_case prefix of its resolver fields
+fn to_snake_case(name: &str) -> String {
+	let mut snake = String::with_capacity(name.len() + 4);
+	let chars: Vec<char> = name.chars().collect();
+	for (i, ch) in chars.iter().enumerate() {
+		if ch.is_ascii_uppercase() {
+			let after_lower = i > 0 && !chars[i - 1].is_ascii_uppercase();
+			let before_lower = i > 0 && chars.get(i + 1).is_some_and(|c| c.is_ascii_lowercase());
+			if after_lower || before_lower {
+				snake.push('_');
+			}
+			snake.push(ch.to_ascii_lowercase());
+		} else {
+			snake.push(*ch);
+		}
+	}
+	snake
+}
+
+pub(crate) fn expand_derive(input: DeriveInput) -> syn::Result<TokenStream> {
+	let graphql = get_reinhardt_graphql_crate()?;
+	expand(input, graphql)
+}
+
+fn expand(input: DeriveInput, graphql: TokenStream) -> syn::Result<TokenStream> {
+	let name = &input.ident;
+	let page_name = format_ident!("{}Page", name);
+	let query_name = format_ident!("{}Query", name);
+
+	if !input.generics.params.is_empty() {
+		return Err(syn::Error::new_spanned(
+			&input.generics,
+			"GraphQLModel cannot be derived for generic structs",
+		));
+	}
+
+	let Data::Struct(data) = &input.data else {
+		return Err(syn::Error::new_spanned(
+			&input.ident,
+			"GraphQLModel can only be derived for structs",
+		));
+	};
+	let Fields::Named(fields) = &data.fields else {
+		return Err(syn::Error::new_spanned(
+			&input.ident,
+			"GraphQLModel requires a struct with named fields",
+		));
+	};
+
+	let mut filterable = Vec::new();
+	for field in &fields.named {
+		if is_filter_skipped(field)? {
+			continue;
+		}
+		if let Some(ident) = &field.ident {
+			let name = ident.to_string();
+			filterable.push(name.strip_prefix("r#").unwrap_or(&name).to_string());
+		}
+	}
+
+	let options = parse_model_options(&input)?;
+	let max_page_size = options.max_page_size.map(|size| {
+		quote! {
+			fn max_page_size() -> usize {
+				#size
+			}
+		}
+	});
+
+	let page_doc = format!("A page of `{}` results", name);
+	// Point the SimpleObject derive and Object attribute at the re-exported
+	// async_graphql so users do not need a direct dependency on it.
+	let async_graphql_path = quote!(#graphql::async_graphql).to_string();
+
+	let snake = to_snake_case(&name.to_string());
+	let get_field = format_ident!("{}", snake);
+	let list_field = format_ident!("{}_list", snake);
+	let query_doc = format!(
+		"`{}` query fields, to merge into the schema's query root",
+		name
+	);
+	let get_doc = format!("Fetch a `{}` by primary key", name);
+	let list_doc = format!(
+		"List `{}` instances with filters, ordering and pagination",
+		name
+	);
+
+	let mutation = options.serializer.map(|serializer| {
+		let mutation_name = format_ident!("{}Mutation", name);
+		let create_field = format_ident!("create_{}", snake);
+		let update_field = format_ident!("update_{}", snake);
+		let delete_field = format_ident!("delete_{}", snake);
+		let mutation_doc = format!(
+			"`{}` mutation fields, to merge into the schema's mutation root",
+			name
+		);
+		let create_doc = format!("Create a `{}`", name);
+		let update_doc = format!("Update the `{}` identified by `pk`", name);
+		let delete_doc = format!("Delete the `{}` identified by `pk`", name);
+
+		quote! {
+			#[doc = #mutation_doc]
+			#[derive(Debug, Default, Clone, Copy)]
+			pub struct #mutation_name;
+
+			#[#graphql::async_graphql::Object(crate = #async_graphql_path)]
+			impl #mutation_name {
+				#[doc = #create_doc]
+				async fn #create_field(
+					&self,
+					input: #graphql::model::ModelInput,
+				) -> #graphql::async_graphql::Result<#name> {
+					#graphql::model::ModelMutation::<#serializer>::create(input.0).await
+				}
+
+				#[doc = #update_doc]
+				async fn #update_field(
+					&self,
+					pk: #graphql::model::PrimaryKeyOf<#name>,
+					input: #graphql::model::ModelInput,
+				) -> #graphql::async_graphql::Result<#name> {
+					#graphql::model::ModelMutation::<#serializer>::update(pk, input.0).await
+				}
+
+				#[doc = #delete_doc]
+				async fn #delete_field(
+					&self,
+					pk: #graphql::model::PrimaryKeyOf<#name>,
+				) -> #graphql::async_graphql::Result<bool> {
+					#graphql::model::ModelMutation::<#serializer>::delete(pk).await
+				}
+			}
+		}
+	});
+
+	Ok(quote! {
+		impl #graphql::model::GraphQLModel for #name {
+			fn filterable_fields() -> &'static [&'static str] {
+				&[#(#filterable),*]
+			}
+
+			#max_page_size
+		}
+
+		#[doc = #page_doc]
+		#[derive(#graphql::SimpleObject)]
+		#[graphql(crate = #async_graphql_path)]
+		pub struct #page_name {
+			/// Items in this page
+			pub items: ::std::vec::Vec<#name>,
+			/// Total number of items matching the filters
+			pub total_count: i64,
+			/// Applied limit
+			pub limit: i64,
+			/// Applied offset
+			pub offset: i64,
+			/// Whether more items exist after this page
+			pub has_next_page: bool,
+		}
+
+		impl ::std::convert::From<#graphql::model::ModelPage<#name>> for #page_name {
+			fn from(page: #graphql::model::ModelPage<#name>) -> Self {
+				let has_next_page = page.has_next_page();
+				Self {
+					items: page.items,
+					total_count: page.total_count as i64,
+					limit: page.limit as i64,
+					offset: page.offset as i64,
+					has_next_page,
+				}
+			}
+		}
+
+		#[doc = #query_doc]
+		#[derive(Debug, Default, Clone, Copy)]
+		pub struct #query_name;
+
+		#[#graphql::async_graphql::Object(crate = #async_graphql_path)]
+		impl #query_name {
+			#[doc = #get_doc]
+			async fn #get_field(
+				&self,
+				pk: #graphql::model::PrimaryKeyOf<#name>,
+			) -> #graphql::async_graphql::Result<::std::option::Option<#name>> {
+				#graphql::model::ModelQuery::<#name>::get(pk).await
+			}
+
+			#[doc = #list_doc]
+			async fn #list_field(
+				&self,
+				filters: ::std::option::Option<::std::vec::Vec<#graphql::model::FieldFilter>>,
+				order_by: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+				page: ::std::option::Option<#graphql::model::PageInput>,
+			) -> #graphql::async_graphql::Result<#page_name> {
+				::std::result::Result::Ok(
+					#graphql::model::ModelQuery::<#name>::list(filters, order_by, page)
+						.await?
+						.into(),
+				)
+			}
+		}
+
+		#mutation
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use quote::ToTokens;
+	use syn::parse_quote;
+
+	/// Names of the resolver methods generated on `object`
+	fn resolvers(output: TokenStream, object: &str) -> Vec<String> {
+		let file: syn::File = syn::parse2(output).unwrap();
+		file.items
+			.iter()
+			.filter_map(|item| match item {
+				syn::Item::Impl(block) if block.self_ty.to_token_stream().to_string() == object => {
+					Some(block)
+				}
+				_ => None,
+			})
+			.flat_map(|block| &block.items)
+			.filter_map(|item| match item {
+				syn::ImplItem::Fn(method) => Some(method.sig.ident.to_string()),
+				_ => None,
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_to_snake_case() {
+		assert_eq!(to_snake_case("Post"), "post");
+		assert_eq!(to_snake_case("BlogPost"), "blog_post");
+		assert_eq!(to_snake_case("HTTPRequest"), "http_request");
+	}
+
+	#[test]
+	fn test_query_object_generated() {
+		// Arrange
+		let input: DeriveInput = parse_quote! {
+			struct BlogPost {
+				id: i64,
+				title: String,
+			}
+		};
+
+		// Act
+		let output = expand(input, quote!(::reinhardt_graphql)).unwrap();
+
+		// Assert
+		assert_eq!(
+			resolvers(output.clone(), "BlogPostQuery"),
+			vec!["blog_post", "blog_post_list"]
+		);
+		assert!(resolvers(output, "BlogPostMutation").is_empty());
+	}
+
+	#[test]
+	fn test_mutation_object_generated_with_serializer() {
+		// Arrange
+		let input: DeriveInput = parse_quote! {
+			#[graphql_model(serializer = PostSerializer)]
+			struct Post {
+				id: i64,
+				title: String,
+			}
+		};
+
+		// Act
+		let output = expand(input, quote!(::reinhardt_graphql)).unwrap();
+
+		// Assert
+		assert_eq!(
+			resolvers(output, "PostMutation"),
+			vec!["create_post", "update_post", "delete_post"]
+		);
+	}
+}
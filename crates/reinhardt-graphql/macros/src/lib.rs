@@ -11,6 +11,7 @@ use syn::{DeriveInput, parse_macro_input};
 mod convert;
 mod crate_paths;
 mod graphql_handler;
+mod graphql_model;
 mod subscription;
 
 /// Generate automatic conversion between Protobuf and GraphQL types
@@ -38,6 +39,41 @@ pub fn derive_grpc_subscription(input: TokenStream) -> TokenStream {
 		.into()
 }
 
+/// Derive GraphQL query support for an ORM model
+///
+/// Implements `reinhardt_graphql::model::GraphQLModel` for a `#[model]`
+/// struct and generates:
+///
+/// - a `{Model}Page` GraphQL object used as the return type of paginated
+///   list queries;
+/// - a `{Model}Query` object with `{model}(pk)` and
+///   `{model}List(filters, orderBy, page)` fields;
+/// - with a `serializer`, a `{Model}Mutation` object with
+///   `create{Model}(input)`, `update{Model}(pk, input)` and
+///   `delete{Model}(pk)` fields.
+///
+/// The query and mutation objects are merged into the schema roots with
+/// `#[derive(MergedObject)]`. Every named field is filterable and orderable
+/// unless marked `#[graphql_model(skip_filter)]`.
+///
+/// The model must also derive `SimpleObject` (or implement `OutputType`).
+///
+/// # Attributes
+///
+/// - `#[graphql_model(max_page_size = 50)]` on the struct overrides the
+///   maximum page size accepted by list queries.
+/// - `#[graphql_model(serializer = PostSerializer)]` on the struct generates
+///   the mutation object, validating and saving through the serializer.
+/// - `#[graphql_model(skip_filter)]` on a field excludes it from filtering
+///   and ordering.
+#[proc_macro_derive(GraphQLModel, attributes(graphql_model))]
+pub fn derive_graphql_model(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	graphql_model::expand_derive(input)
+		.unwrap_or_else(|err| err.to_compile_error())
+		.into()
+}
+
 /// Attribute macro for GraphQL resolvers with dependency injection support
 ///
 /// This macro enables the use of `#[inject]` parameters in GraphQL resolver functions,
@@ -0,0 +1,253 @@
+//! Mountable HTTP handler for GraphQL schemas
+//!
+//! [`GraphQLHandler`] implements [`reinhardt_http::Handler`], so a schema can
+//! be mounted on any router path (conventionally `/graphql`):
+//!
+//! - `POST` executes a JSON-encoded GraphQL request (single or batched).
+//! - `GET` with a `query` parameter executes the query (queries only; mutations
+//!   are rejected to keep them out of caches and logs).
+//! - `GET` from a browser (`Accept: text/html`) serves GraphQL Playground when
+//!   enabled.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use reinhardt_graphql::handler::GraphQLHandler;
+//!
+//! let schema = Schema::build(Query, Mutation, EmptySubscription).finish();
+//! let handler = GraphQLHandler::new(schema).with_playground("/graphql");
+//! router.route("/graphql", handler);
+//! ```
+
+use async_graphql::http::{GraphQLPlaygroundConfig, playground_source};
+use async_graphql::parser::types::OperationType;
+use async_graphql::{BatchRequest, ObjectType, Schema, SubscriptionType};
+use async_trait::async_trait;
+use hyper::header::{ACCEPT, CONTENT_TYPE};
+use hyper::{Method, StatusCode};
+use reinhardt_http::{Handler, Request, Response, Result};
+
+/// HTTP handler executing requests against a GraphQL schema
+pub struct GraphQLHandler<Q, M, S> {
+	schema: Schema<Q, M, S>,
+	playground_endpoint: Option<String>,
+}
+
+impl<Q, M, S> GraphQLHandler<Q, M, S>
+where
+	Q: ObjectType + 'static,
+	M: ObjectType + 'static,
+	S: SubscriptionType + 'static,
+{
+	/// Create a handler for `schema` with the playground disabled
+	pub fn new(schema: Schema<Q, M, S>) -> Self {
+		Self {
+			schema,
+			playground_endpoint: None,
+		}
+	}
+
+	/// Serve GraphQL Playground to browsers, sending queries to `endpoint`
+	pub fn with_playground(mut self, endpoint: impl Into<String>) -> Self {
+		self.playground_endpoint = Some(endpoint.into());
+		self
+	}
+
+	/// Get the underlying schema
+	pub fn schema(&self) -> &Schema<Q, M, S> {
+		&self.schema
+	}
+
+	fn wants_html(request: &Request) -> bool {
+		request
+			.headers
+			.get(ACCEPT)
+			.and_then(|v| v.to_str().ok())
+			.is_some_and(|accept| accept.contains("text/html"))
+	}
+
+	fn playground(&self, endpoint: &str) -> Response {
+		Response::ok()
+			.with_header(CONTENT_TYPE.as_str(), "text/html; charset=utf-8")
+			.with_body(playground_source(GraphQLPlaygroundConfig::new(endpoint)))
+	}
+
+	async fn execute_get(&self, request: &Request) -> Result<Response> {
+		let Some(query) = request.query_params.get("query") else {
+			return Ok(Response::bad_request().with_body("Missing 'query' parameter"));
+		};
+
+		let mut gql_request = async_graphql::Request::new(query.clone());
+		if let Some(name) = request.query_params.get("operationName") {
+			gql_request = gql_request.operation_name(name.clone());
+		}
+		if let Some(variables) = request.query_params.get("variables") {
+			let variables: serde_json::Value = serde_json::from_str(variables)
+				.map_err(|e| reinhardt_http::Error::Validation(e.to_string()))?;
+			gql_request = gql_request.variables(async_graphql::Variables::from_json(variables));
+		}
+
+		// GET must not trigger side effects, so only queries are allowed.
+		if let Ok(document) = async_graphql::parser::parse_query(query)
+			&& document
+				.operations
+				.iter()
+				.any(|(_, operation)| operation.node.ty != OperationType::Query)
+		{
+			return Ok(Response::new(StatusCode::METHOD_NOT_ALLOWED)
+				.with_header("Allow", "POST")
+				.with_body("Mutations and subscriptions must use POST"));
+		}
+
+		let response = self.schema.execute(gql_request).await;
+		Response::ok().with_json(&response)
+	}
+
+	async fn execute_post(&self, request: &Request) -> Result<Response> {
+		let batch: BatchRequest = serde_json::from_slice(request.body())
+			.map_err(|e| reinhardt_http::Error::Validation(e.to_string()))?;
+		let response = self.schema.execute_batch(batch).await;
+		Response::ok().with_json(&response)
+	}
+}
+
+#[async_trait]
+impl<Q, M, S> Handler for GraphQLHandler<Q, M, S>
+where
+	Q: ObjectType + 'static,
+	M: ObjectType + 'static,
+	S: SubscriptionType + 'static,
+{
+	async fn handle(&self, request: Request) -> Result<Response> {
+		match request.method {
+			Method::GET => {
+				if let Some(endpoint) = &self.playground_endpoint
+					&& !request.query_params.contains_key("query")
+					&& Self::wants_html(&request)
+				{
+					return Ok(self.playground(endpoint));
+				}
+				self.execute_get(&request).await
+			}
+			Method::POST => self.execute_post(&request).await,
+			_ => {
+				Ok(Response::new(StatusCode::METHOD_NOT_ALLOWED).with_header("Allow", "GET, POST"))
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use async_graphql::{EmptyMutation, EmptySubscription, Object};
+	use rstest::rstest;
+
+	struct TestQuery;
+
+	#[Object]
+	impl TestQuery {
+		async fn greeting(&self) -> &str {
+			"hello"
+		}
+	}
+
+	fn handler() -> GraphQLHandler<TestQuery, EmptyMutation, EmptySubscription> {
+		let schema = Schema::build(TestQuery, EmptyMutation, EmptySubscription).finish();
+		GraphQLHandler::new(schema).with_playground("/graphql")
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_post_executes_query() {
+		// Arrange
+		let request = Request::builder()
+			.method(Method::POST)
+			.uri("/graphql")
+			.body(bytes::Bytes::from(r#"{"query":"{ greeting }"}"#))
+			.build()
+			.unwrap();
+
+		// Act
+		let response = handler().handle(request).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::OK);
+		let body: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+		assert_eq!(body, serde_json::json!({"data": {"greeting": "hello"}}));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_get_executes_query() {
+		// Arrange
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/graphql?query=%7B%20greeting%20%7D")
+			.build()
+			.unwrap();
+
+		// Act
+		let response = handler().handle(request).await.unwrap();
+
+		// Assert
+		let body: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+		assert_eq!(body, serde_json::json!({"data": {"greeting": "hello"}}));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_browser_get_serves_playground() {
+		// Arrange
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/graphql")
+			.header("Accept", "text/html")
+			.build()
+			.unwrap();
+
+		// Act
+		let response = handler().handle(request).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::OK);
+		assert_eq!(
+			response.headers.get(CONTENT_TYPE).unwrap(),
+			"text/html; charset=utf-8"
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_get_rejects_mutation() {
+		// Arrange
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/graphql?query=mutation%20%7B%20noop%20%7D")
+			.build()
+			.unwrap();
+
+		// Act
+		let response = handler().handle(request).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::METHOD_NOT_ALLOWED);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_unsupported_method() {
+		// Arrange
+		let request = Request::builder()
+			.method(Method::DELETE)
+			.uri("/graphql")
+			.build()
+			.unwrap();
+
+		// Act
+		let response = handler().handle(request).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::METHOD_NOT_ALLOWED);
+	}
+}
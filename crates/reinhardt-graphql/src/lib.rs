@@ -8,6 +8,11 @@
 //! - **graphql-grpc**: GraphQL facade over gRPC for Query/Mutation
 //! - **subscription**: gRPC-based Subscriptions (Rust 2024 compatible)
 //! - **di**: Dependency injection support for GraphQL resolvers
+//! - **orm**: Schema derivation from `#[model]` structs (filtering, pagination,
+//!   serializer-backed mutations, batched loading)
+//! - **server**: Mountable `/graphql` HTTP handler with GraphQL Playground
+//! - **websocket**: Subscriptions over WebSocket (`graphql-ws`), pushed by
+//!   model signals
 //! - **full**: All features enabled
 //!
 //! # Dependency Injection
@@ -84,6 +89,18 @@ pub mod types;
 #[cfg(feature = "di")]
 pub mod di;
 
+/// Mountable HTTP handler for GraphQL schemas.
+#[cfg(feature = "server")]
+pub mod handler;
+
+/// GraphQL schema derivation from ORM models.
+#[cfg(feature = "orm")]
+pub mod model;
+
+/// GraphQL subscriptions over WebSocket, fed by model signals.
+#[cfg(feature = "websocket")]
+pub mod websocket;
+
 /// GraphQL-over-gRPC service adapter.
 #[cfg(feature = "graphql-grpc")]
 pub mod grpc_service;
@@ -112,6 +129,28 @@ pub use di::{GraphQLContextExt, SchemaBuilderExt};
 #[cfg(feature = "di")]
 pub use reinhardt_graphql_macros::graphql_handler;
 
+#[cfg(feature = "server")]
+pub use handler::GraphQLHandler;
+
+#[cfg(feature = "websocket")]
+pub use websocket::{
+	GRAPHQL_WS_PROTOCOLS, GraphQLWsConsumer, bridge_stream, broadcast_post_delete,
+	broadcast_post_save,
+};
+
+#[cfg(feature = "orm")]
+pub use model::{
+	FieldFilter, FilterLookup, GraphQLModel, ModelLoader, ModelMutation, ModelPage, ModelQuery,
+	PageInput, model_loader,
+};
+
+#[cfg(feature = "orm")]
+pub use reinhardt_graphql_macros::GraphQLModel;
+
+/// Re-export of `async_graphql` for code generated by `#[derive(GraphQLModel)]`.
+#[doc(hidden)]
+pub use async_graphql;
+
 // Re-export async_graphql base types for facade pattern.
 // These types are commonly needed by user code to define GraphQL schemas,
 // resolvers, and error handling without depending on async_graphql directly.
@@ -0,0 +1,651 @@
+//! GraphQL schema derivation from ORM models
+//!
+//! This module bridges `#[model]` structs and GraphQL:
+//!
+//! - [`GraphQLModel`] describes which fields of a model may be filtered and
+//!   ordered from GraphQL. It is normally implemented with
+//!   `#[derive(GraphQLModel)]`, which also generates a `{Model}Page` object
+//!   type, a `{Model}Query` object with `{model}` and `{model}List` fields
+//!   and, with `#[graphql_model(serializer = ...)]`, a `{Model}Mutation`
+//!   object with `create{Model}`, `update{Model}` and `delete{Model}` fields.
+//!   Merge them into the schema roots with `MergedObject`.
+//! - [`ModelQuery`] builds a `QuerySet` from GraphQL filter, ordering and
+//!   pagination arguments and executes it.
+//! - [`ModelMutation`] maps create/update/delete mutations onto serializer
+//!   validation and save via [`SerializerSaveMixin`].
+//! - [`ModelLoader`] batches primary key lookups to avoid N+1 queries when
+//!   resolving relations.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use async_graphql::EmptySubscription;
+//! use reinhardt_graphql::{GraphQLModel, MergedObject, Schema, SimpleObject};
+//!
+//! #[model(app_label = "blog", table_name = "posts")]
+//! #[derive(Serialize, Deserialize, SimpleObject, GraphQLModel)]
+//! #[graphql_model(serializer = PostSerializer)]
+//! pub struct Post {
+//!     #[field(primary_key = true)]
+//!     pub id: i64,
+//!     #[field(max_length = 200)]
+//!     pub title: String,
+//!     #[graphql_model(skip_filter)]
+//!     #[field(max_length = 10000)]
+//!     pub body: String,
+//! }
+//!
+//! #[derive(MergedObject, Default)]
+//! struct Query(PostQuery, CommentQuery);
+//!
+//! #[derive(MergedObject, Default)]
+//! struct Mutation(PostMutation);
+//!
+//! let schema = Schema::build(Query::default(), Mutation::default(), EmptySubscription).finish();
+//! ```
+//!
+//! Hand-written resolvers can call [`ModelQuery`] and [`ModelMutation`]
+//! directly, e.g. to add permission checks before delegating.
+
+use async_graphql::{Enum, ErrorExtensions, InputObject, Json};
+use reinhardt_db::orm::custom_manager::CustomManager;
+use reinhardt_db::orm::query::{Filter, FilterOperator, FilterValue};
+use reinhardt_db::orm::{Manager, Model, QuerySet};
+use reinhardt_rest::serializers::{SerializerError, SerializerSaveMixin};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// Default maximum number of items returned by a single list query
+pub const DEFAULT_MAX_PAGE_SIZE: usize = 100;
+
+/// Default number of items returned when no limit is requested
+pub const DEFAULT_PAGE_SIZE: usize = 20;
+
+/// Primary key type of `M`, used as the `pk` argument of generated resolvers
+pub type PrimaryKeyOf<M> = <M as Model>::PrimaryKey;
+
+/// Input of generated create and update mutations
+///
+/// The JSON object is validated and saved by the model's serializer.
+pub type ModelInput = Json<Value>;
+
+/// A model that can be exposed through GraphQL
+///
+/// Implement with `#[derive(GraphQLModel)]`, which lists every named field
+/// as filterable and orderable unless it is marked
+/// `#[graphql_model(skip_filter)]`. The generated `{Model}Query` and
+/// `{Model}Mutation` objects take the primary key as argument, so it must be
+/// a GraphQL input type.
+pub trait GraphQLModel: Model + Serialize + DeserializeOwned + Send + Sync + 'static {
+	/// Fields that may be used in [`FieldFilter`] arguments
+	fn filterable_fields() -> &'static [&'static str];
+
+	/// Fields that may be used in `orderBy` arguments
+	fn orderable_fields() -> &'static [&'static str] {
+		Self::filterable_fields()
+	}
+
+	/// Maximum page size accepted by list queries
+	fn max_page_size() -> usize {
+		DEFAULT_MAX_PAGE_SIZE
+	}
+}
+
+/// Lookup applied by a [`FieldFilter`]
+#[derive(Enum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FilterLookup {
+	/// Exact match
+	#[default]
+	Exact,
+	/// Case-insensitive exact match
+	IExact,
+	/// Not equal
+	Ne,
+	/// Substring match
+	Contains,
+	/// Case-insensitive substring match
+	IContains,
+	/// Prefix match
+	StartsWith,
+	/// Suffix match
+	EndsWith,
+	/// Greater than
+	Gt,
+	/// Greater than or equal
+	Gte,
+	/// Less than
+	Lt,
+	/// Less than or equal
+	Lte,
+	/// Membership in a list of values
+	In,
+	/// Null check (`value` is a boolean)
+	IsNull,
+}
+
+impl FilterLookup {
+	fn operator(self) -> FilterOperator {
+		match self {
+			FilterLookup::Exact => FilterOperator::Eq,
+			FilterLookup::IExact => FilterOperator::IExact,
+			FilterLookup::Ne => FilterOperator::Ne,
+			FilterLookup::Contains => FilterOperator::Contains,
+			FilterLookup::IContains => FilterOperator::IContains,
+			FilterLookup::StartsWith => FilterOperator::StartsWith,
+			FilterLookup::EndsWith => FilterOperator::EndsWith,
+			FilterLookup::Gt => FilterOperator::Gt,
+			FilterLookup::Gte => FilterOperator::Gte,
+			FilterLookup::Lt => FilterOperator::Lt,
+			FilterLookup::Lte => FilterOperator::Lte,
+			FilterLookup::In => FilterOperator::In,
+			FilterLookup::IsNull => FilterOperator::IsNull,
+		}
+	}
+}
+
+/// A single field filter argument
+#[derive(InputObject, Debug, Clone)]
+pub struct FieldFilter {
+	/// Model field name
+	pub field: String,
+	/// Lookup to apply (defaults to `EXACT`)
+	#[graphql(default)]
+	pub lookup: FilterLookup,
+	/// Value to compare against
+	pub value: Option<Json<Value>>,
+}
+
+impl FieldFilter {
+	/// Create an exact-match filter
+	pub fn exact(field: impl Into<String>, value: Value) -> Self {
+		Self {
+			field: field.into(),
+			lookup: FilterLookup::Exact,
+			value: Some(Json(value)),
+		}
+	}
+
+	/// Set the lookup
+	pub fn with_lookup(mut self, lookup: FilterLookup) -> Self {
+		self.lookup = lookup;
+		self
+	}
+}
+
+/// Offset pagination argument
+#[derive(InputObject, Debug, Clone, Default)]
+pub struct PageInput {
+	/// Maximum number of items to return
+	pub limit: Option<i32>,
+	/// Number of items to skip
+	pub offset: Option<i32>,
+}
+
+/// A page of model instances returned by [`ModelQuery::list`]
+///
+/// `#[derive(GraphQLModel)]` generates a `{Model}Page` GraphQL object that
+/// implements `From<ModelPage<Model>>`.
+#[derive(Debug, Clone)]
+pub struct ModelPage<M> {
+	/// Items in this page
+	pub items: Vec<M>,
+	/// Total number of items matching the filters
+	pub total_count: usize,
+	/// Applied limit
+	pub limit: usize,
+	/// Applied offset
+	pub offset: usize,
+}
+
+impl<M> ModelPage<M> {
+	/// Whether more items exist after this page
+	pub fn has_next_page(&self) -> bool {
+		self.offset + self.items.len() < self.total_count
+	}
+}
+
+fn bad_input(message: impl Into<String>) -> async_graphql::Error {
+	async_graphql::Error::new(message).extend_with(|_, e| e.set("code", "BAD_USER_INPUT"))
+}
+
+fn internal_error(error: impl std::fmt::Display) -> async_graphql::Error {
+	tracing::error!(error = %error, "GraphQL model resolver failed");
+	async_graphql::Error::new("Internal server error")
+		.extend_with(|_, e| e.set("code", "INTERNAL_SERVER_ERROR"))
+}
+
+fn json_to_filter_value(value: &Value) -> Result<FilterValue, async_graphql::Error> {
+	match value {
+		Value::Null => Ok(FilterValue::Null),
+		Value::Bool(b) => Ok(FilterValue::Boolean(*b)),
+		Value::Number(n) => match n.as_i64() {
+			Some(i) => Ok(FilterValue::Integer(i)),
+			None => n
+				.as_f64()
+				.map(FilterValue::Float)
+				.ok_or_else(|| bad_input("Unsupported numeric filter value")),
+		},
+		Value::String(s) => Ok(FilterValue::String(s.clone())),
+		Value::Array(values) => values
+			.iter()
+			.map(json_to_filter_value)
+			.collect::<Result<Vec<_>, _>>()
+			.map(FilterValue::List),
+		Value::Object(_) => Err(bad_input("Object values are not supported in filters")),
+	}
+}
+
+impl FieldFilter {
+	/// Convert into an ORM [`Filter`], validating the field against `allowed`
+	pub fn to_orm_filter(&self, allowed: &[&str]) -> Result<Filter, async_graphql::Error> {
+		if !allowed.contains(&self.field.as_str()) {
+			return Err(bad_input(format!(
+				"Filtering on field '{}' is not allowed",
+				self.field
+			)));
+		}
+
+		if self.lookup == FilterLookup::IsNull {
+			let is_null = match self.value.as_ref().map(|v| &v.0) {
+				None | Some(Value::Bool(true)) => true,
+				Some(Value::Bool(false)) => false,
+				Some(_) => return Err(bad_input("IS_NULL lookup expects a boolean value")),
+			};
+			let operator = if is_null {
+				FilterOperator::IsNull
+			} else {
+				FilterOperator::IsNotNull
+			};
+			return Ok(Filter::new(self.field.clone(), operator, FilterValue::Null));
+		}
+
+		let value = self
+			.value
+			.as_ref()
+			.ok_or_else(|| bad_input(format!("Filter on '{}' requires a value", self.field)))?;
+		let value = json_to_filter_value(&value.0)?;
+
+		if self.lookup == FilterLookup::In && !matches!(value, FilterValue::List(_)) {
+			return Err(bad_input("IN lookup expects a list value"));
+		}
+
+		Ok(Filter::new(
+			self.field.clone(),
+			self.lookup.operator(),
+			value,
+		))
+	}
+}
+
+/// Validate `orderBy` arguments (`"field"` or `"-field"`) against `allowed`
+pub fn validate_ordering(
+	order_by: &[String],
+	allowed: &[&str],
+) -> Result<Vec<String>, async_graphql::Error> {
+	order_by
+		.iter()
+		.map(|term| {
+			let field = term.strip_prefix('-').unwrap_or(term);
+			if allowed.contains(&field) {
+				Ok(term.clone())
+			} else {
+				Err(bad_input(format!(
+					"Ordering by field '{}' is not allowed",
+					field
+				)))
+			}
+		})
+		.collect()
+}
+
+/// Resolve the effective `(limit, offset)` for a page argument
+pub fn resolve_page(page: Option<&PageInput>, max_page_size: usize) -> (usize, usize) {
+	let limit = page
+		.and_then(|p| p.limit)
+		.map_or(DEFAULT_PAGE_SIZE.min(max_page_size), |l| {
+			(l.max(1) as usize).min(max_page_size)
+		});
+	let offset = page.and_then(|p| p.offset).map_or(0, |o| o.max(0) as usize);
+	(limit, offset)
+}
+
+/// QuerySet-backed query resolvers for a [`GraphQLModel`]
+pub struct ModelQuery<M>(PhantomData<M>);
+
+impl<M> ModelQuery<M>
+where
+	M: GraphQLModel,
+{
+	/// Build a `QuerySet` from GraphQL filter and ordering arguments
+	///
+	/// The queryset starts from the model's default manager, so custom
+	/// managers that narrow the default queryset are respected.
+	pub fn build_queryset(
+		filters: &[FieldFilter],
+		order_by: &[String],
+	) -> Result<QuerySet<M>, async_graphql::Error> {
+		let mut queryset = M::objects().all();
+		for filter in filters {
+			queryset = queryset.filter(filter.to_orm_filter(M::filterable_fields())?);
+		}
+
+		let ordering = validate_ordering(order_by, M::orderable_fields())?;
+		if !ordering.is_empty() {
+			let ordering: Vec<&str> = ordering.iter().map(String::as_str).collect();
+			queryset = queryset.order_by(&ordering);
+		}
+
+		Ok(queryset)
+	}
+
+	/// Resolve a paginated list query
+	pub async fn list(
+		filters: Option<Vec<FieldFilter>>,
+		order_by: Option<Vec<String>>,
+		page: Option<PageInput>,
+	) -> async_graphql::Result<ModelPage<M>> {
+		let queryset =
+			Self::build_queryset(&filters.unwrap_or_default(), &order_by.unwrap_or_default())?;
+		let (limit, offset) = resolve_page(page.as_ref(), M::max_page_size());
+
+		let total_count = queryset.count().await.map_err(internal_error)?;
+		let items = queryset
+			.limit(limit)
+			.offset(offset)
+			.all()
+			.await
+			.map_err(internal_error)?;
+
+		Ok(ModelPage {
+			items,
+			total_count,
+			limit,
+			offset,
+		})
+	}
+
+	/// Resolve a single instance by primary key
+	pub async fn get(pk: M::PrimaryKey) -> async_graphql::Result<Option<M>> {
+		M::objects().get(pk).first().await.map_err(internal_error)
+	}
+}
+
+/// Convert a serializer error into a GraphQL error
+///
+/// Validation failures carry a `VALIDATION_ERROR` code and the offending
+/// field names in the `fields` extension.
+pub fn serializer_error(error: SerializerError) -> async_graphql::Error {
+	match &error {
+		SerializerError::Validation(validation) => {
+			let fields: Vec<String> = validation
+				.field_names()
+				.into_iter()
+				.map(str::to_string)
+				.collect();
+			async_graphql::Error::new(validation.to_string()).extend_with(|_, e| {
+				e.set("code", "VALIDATION_ERROR");
+				e.set("fields", fields.clone());
+			})
+		}
+		SerializerError::Serde { message } => bad_input(message.clone()),
+		_ => internal_error(error),
+	}
+}
+
+/// Serializer-backed mutation resolvers
+///
+/// Mutations validate input through the serializer's pre-save hooks and
+/// persist through [`SerializerSaveMixin::save`], so the same validation
+/// rules apply to REST and GraphQL writes.
+pub struct ModelMutation<S>(PhantomData<S>);
+
+impl<S> ModelMutation<S>
+where
+	S: SerializerSaveMixin + Send + Sync,
+	S::Model: GraphQLModel,
+{
+	/// Create an instance from GraphQL input
+	pub async fn create(input: Value) -> async_graphql::Result<S::Model> {
+		S::save(input, None).await.map_err(serializer_error)
+	}
+
+	/// Update the instance identified by `pk` with a partial input
+	pub async fn update(
+		pk: <S::Model as Model>::PrimaryKey,
+		input: Value,
+	) -> async_graphql::Result<S::Model> {
+		let instance = ModelQuery::<S::Model>::get(pk.clone())
+			.await?
+			.ok_or_else(|| not_found(&pk))?;
+		S::save(input, Some(instance))
+			.await
+			.map_err(serializer_error)
+	}
+
+	/// Delete the instance identified by `pk`
+	pub async fn delete(pk: <S::Model as Model>::PrimaryKey) -> async_graphql::Result<bool> {
+		if ModelQuery::<S::Model>::get(pk.clone()).await?.is_none() {
+			return Err(not_found(&pk));
+		}
+		Manager::<S::Model>::new()
+			.delete(pk)
+			.await
+			.map_err(internal_error)?;
+		Ok(true)
+	}
+}
+
+fn not_found(pk: &impl std::fmt::Display) -> async_graphql::Error {
+	async_graphql::Error::new(format!("Object with primary key '{}' not found", pk))
+		.extend_with(|_, e| e.set("code", "NOT_FOUND"))
+}
+
+/// Error returned by [`ModelLoader`]
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("Model loader error: {0}")]
+pub struct ModelLoaderError(Arc<str>);
+
+/// Batched primary key loader for a model
+///
+/// Register a [`model_loader`] in the schema data and resolve relations with
+/// `ctx.data_unchecked::<DataLoader<ModelLoader<Author>>>().load_one(id)`.
+/// All keys requested during one execution tick are fetched with a single
+/// `pk IN (...)` query.
+pub struct ModelLoader<M>(PhantomData<fn() -> M>);
+
+impl<M> Default for ModelLoader<M> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<M> async_graphql::dataloader::Loader<M::PrimaryKey> for ModelLoader<M>
+where
+	M: GraphQLModel,
+	M::PrimaryKey: Hash + Eq + Into<FilterValue> + 'static,
+{
+	type Value = M;
+	type Error = ModelLoaderError;
+
+	async fn load(
+		&self,
+		keys: &[M::PrimaryKey],
+	) -> Result<HashMap<M::PrimaryKey, Self::Value>, Self::Error> {
+		let values = keys.iter().cloned().map(Into::into).collect();
+		let items = M::objects()
+			.all()
+			.filter(Filter::new(
+				M::primary_key_field(),
+				FilterOperator::In,
+				FilterValue::List(values),
+			))
+			.all()
+			.await
+			.map_err(|e| ModelLoaderError(e.to_string().into()))?;
+
+		Ok(items
+			.into_iter()
+			.filter_map(|item| item.primary_key().map(|pk| (pk, item)))
+			.collect())
+	}
+}
+
+/// Create a Tokio-backed [`async_graphql::dataloader::DataLoader`] for `M`
+pub fn model_loader<M>() -> async_graphql::dataloader::DataLoader<ModelLoader<M>>
+where
+	M: GraphQLModel,
+	M::PrimaryKey: Hash + Eq + Into<FilterValue> + 'static,
+{
+	async_graphql::dataloader::DataLoader::new(ModelLoader::default(), tokio::spawn)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use serde_json::json;
+
+	const FIELDS: &[&str] = &["id", "title", "views"];
+
+	#[rstest]
+	fn test_filter_rejects_unknown_field() {
+		// Arrange
+		let filter = FieldFilter::exact("password", json!("x"));
+
+		// Act
+		let result = filter.to_orm_filter(FIELDS);
+
+		// Assert
+		assert_eq!(
+			result.unwrap_err().message,
+			"Filtering on field 'password' is not allowed"
+		);
+	}
+
+	#[rstest]
+	#[case(FilterLookup::Exact, json!("hello"))]
+	#[case(FilterLookup::Gte, json!(10))]
+	#[case(FilterLookup::In, json!([1, 2, 3]))]
+	fn test_filter_builds_orm_filter(#[case] lookup: FilterLookup, #[case] value: Value) {
+		// Arrange
+		let filter = FieldFilter::exact("views", value).with_lookup(lookup);
+
+		// Act
+		let orm_filter = filter.to_orm_filter(FIELDS).unwrap();
+
+		// Assert
+		assert_eq!(orm_filter.field, "views");
+		assert_eq!(
+			std::mem::discriminant(&orm_filter.operator),
+			std::mem::discriminant(&lookup.operator())
+		);
+	}
+
+	#[rstest]
+	fn test_filter_in_requires_list() {
+		// Arrange
+		let filter = FieldFilter::exact("id", json!(1)).with_lookup(FilterLookup::In);
+
+		// Act
+		let result = filter.to_orm_filter(FIELDS);
+
+		// Assert
+		assert_eq!(
+			result.unwrap_err().message,
+			"IN lookup expects a list value"
+		);
+	}
+
+	#[rstest]
+	#[case(None, FilterOperator::IsNull)]
+	#[case(Some(json!(true)), FilterOperator::IsNull)]
+	#[case(Some(json!(false)), FilterOperator::IsNotNull)]
+	fn test_is_null_lookup(#[case] value: Option<Value>, #[case] expected: FilterOperator) {
+		// Arrange
+		let filter = FieldFilter {
+			field: "title".to_string(),
+			lookup: FilterLookup::IsNull,
+			value: value.map(Json),
+		};
+
+		// Act
+		let orm_filter = filter.to_orm_filter(FIELDS).unwrap();
+
+		// Assert
+		assert_eq!(
+			std::mem::discriminant(&orm_filter.operator),
+			std::mem::discriminant(&expected)
+		);
+	}
+
+	#[rstest]
+	fn test_validate_ordering() {
+		// Arrange
+		let order_by = vec!["-views".to_string(), "title".to_string()];
+
+		// Act
+		let ordering = validate_ordering(&order_by, FIELDS).unwrap();
+
+		// Assert
+		assert_eq!(ordering, order_by);
+	}
+
+	#[rstest]
+	fn test_validate_ordering_rejects_unknown_field() {
+		// Arrange
+		let order_by = vec!["-secret".to_string()];
+
+		// Act
+		let result = validate_ordering(&order_by, FIELDS);
+
+		// Assert
+		assert_eq!(
+			result.unwrap_err().message,
+			"Ordering by field 'secret' is not allowed"
+		);
+	}
+
+	#[rstest]
+	#[case(None, 100, (20, 0))]
+	#[case(Some(PageInput { limit: Some(500), offset: Some(40) }), 100, (100, 40))]
+	#[case(Some(PageInput { limit: Some(0), offset: Some(-5) }), 100, (1, 0))]
+	#[case(None, 10, (10, 0))]
+	fn test_resolve_page(
+		#[case] page: Option<PageInput>,
+		#[case] max: usize,
+		#[case] expected: (usize, usize),
+	) {
+		// Act
+		let resolved = resolve_page(page.as_ref(), max);
+
+		// Assert
+		assert_eq!(resolved, expected);
+	}
+
+	#[rstest]
+	#[case(0, 2, 5, true)]
+	#[case(3, 2, 5, false)]
+	#[case(0, 0, 0, false)]
+	fn test_model_page_has_next_page(
+		#[case] offset: usize,
+		#[case] len: usize,
+		#[case] total: usize,
+		#[case] expected: bool,
+	) {
+		// Arrange
+		let page = ModelPage {
+			items: vec![(); len],
+			total_count: total,
+			limit: 2,
+			offset,
+		};
+
+		// Act & Assert
+		assert_eq!(page.has_next_page(), expected);
+	}
+}
@@ -0,0 +1,361 @@
+//! GraphQL subscriptions over WebSocket
+//!
+//! [`GraphQLWsConsumer`] serves a schema as a [`WebSocketConsumer`], speaking
+//! the `graphql-transport-ws` protocol of the `graphql-ws` client library or
+//! the legacy `graphql-ws` protocol of `subscriptions-transport-ws`, chosen by
+//! the negotiated subprotocol. Each connection multiplexes any number of
+//! operations by id; closing the connection stops all of them.
+//!
+//! Subscription resolvers read events from a [`GraphQLSubscriptionBridge`]
+//! stored in the schema data with [`bridge_stream`], and
+//! [`broadcast_post_save`] / [`broadcast_post_delete`] publish model signals
+//! to the bridge, so saving a model pushes the instance to its subscribers.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use reinhardt_graphql::websocket::{GraphQLWsConsumer, bridge_stream, broadcast_post_save};
+//!
+//! struct SubscriptionRoot;
+//!
+//! #[Subscription]
+//! impl SubscriptionRoot {
+//!     async fn article_saved<'ctx>(&self, ctx: &Context<'ctx>) -> impl Stream<Item = Article> + 'ctx {
+//!         bridge_stream(ctx, "articleSaved")
+//!     }
+//! }
+//!
+//! let bridge = GraphQLSubscriptionBridge::new();
+//! broadcast_post_save::<Article>(&bridge, "articleSaved").await;
+//!
+//! let schema = Schema::build(Query, EmptyMutation, SubscriptionRoot)
+//!     .data(bridge)
+//!     .finish();
+//! // Serve from the WebSocket endpoint, offering `GRAPHQL_WS_PROTOCOLS`
+//! let consumer = GraphQLWsConsumer::new(schema);
+//! ```
+
+use async_graphql::http::{WebSocket, WebSocketProtocols, WsMessage};
+use async_graphql::{Context, ObjectType, Schema, SubscriptionType};
+use async_trait::async_trait;
+use dashmap::DashMap;
+use futures_util::{Stream, StreamExt};
+use reinhardt_core::signals::graphql_integration::{GraphQLSubscriptionBridge, SubscriptionEvent};
+use reinhardt_core::signals::{post_delete, post_save};
+use reinhardt_websockets::consumers::{ConsumerContext, WebSocketConsumer};
+use reinhardt_websockets::{Message, WebSocketResult};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
+use tokio::sync::{broadcast, mpsc};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tracing::warn;
+
+/// WebSocket subprotocols understood by [`GraphQLWsConsumer`], preferred first
+pub use async_graphql::http::ALL_WEBSOCKET_PROTOCOLS as GRAPHQL_WS_PROTOCOLS;
+
+/// Stream of the events published to a subscription of the
+/// [`GraphQLSubscriptionBridge`] in the schema data
+///
+/// Yields the `data` of each [`SubscriptionEvent`]. Events that do not
+/// deserialize as `T` are skipped with a warning, as are events missed by a
+/// lagging subscriber. The stream is empty when the schema has no bridge.
+pub fn bridge_stream<T>(
+	ctx: &Context<'_>,
+	subscription_name: &str,
+) -> impl Stream<Item = T> + Send + use<T>
+where
+	T: DeserializeOwned + Send + 'static,
+{
+	let receiver = ctx
+		.data::<GraphQLSubscriptionBridge>()
+		.ok()
+		.map(|bridge| bridge.get_or_create_stream(subscription_name).subscribe());
+	let subscription_name = subscription_name.to_string();
+
+	async_stream::stream! {
+		let Some(mut receiver) = receiver else {
+			return;
+		};
+		loop {
+			match receiver.recv().await {
+				Ok(json) => match serde_json::from_str::<SubscriptionEvent<T>>(&json) {
+					Ok(event) => yield event.data,
+					Err(error) => warn!(
+						subscription = %subscription_name,
+						%error,
+						"skipping subscription event with unexpected payload"
+					),
+				},
+				Err(broadcast::error::RecvError::Lagged(skipped)) => {
+					warn!(
+						subscription = %subscription_name,
+						skipped,
+						"subscription receiver lagged, messages were dropped"
+					);
+				}
+				Err(broadcast::error::RecvError::Closed) => break,
+			}
+		}
+	}
+}
+
+/// Publish every saved `M` to the `subscription_name` subscription of `bridge`
+pub async fn broadcast_post_save<M>(
+	bridge: &GraphQLSubscriptionBridge,
+	subscription_name: impl Into<String>,
+) where
+	M: Serialize + Clone + Send + Sync + 'static,
+{
+	connect_model_signal(bridge, post_save::<M>(), subscription_name.into()).await;
+}
+
+/// Publish every deleted `M` to the `subscription_name` subscription of `bridge`
+pub async fn broadcast_post_delete<M>(
+	bridge: &GraphQLSubscriptionBridge,
+	subscription_name: impl Into<String>,
+) where
+	M: Serialize + Clone + Send + Sync + 'static,
+{
+	connect_model_signal(bridge, post_delete::<M>(), subscription_name.into()).await;
+}
+
+async fn connect_model_signal<M>(
+	bridge: &GraphQLSubscriptionBridge,
+	signal: reinhardt_core::signals::Signal<M>,
+	subscription_name: String,
+) where
+	M: Serialize + Clone + Send + Sync + 'static,
+{
+	let event_name = subscription_name.clone();
+	bridge
+		.connect_signal(signal, subscription_name, move |instance: Arc<M>| {
+			SubscriptionEvent::new(event_name.clone(), (*instance).clone())
+		})
+		.await;
+}
+
+/// WebSocket consumer executing GraphQL operations over `graphql-ws`
+///
+/// The protocol follows the connection's negotiated subprotocol (see
+/// [`GRAPHQL_WS_PROTOCOLS`]) and defaults to `graphql-transport-ws`.
+pub struct GraphQLWsConsumer<Q, M, S> {
+	schema: Schema<Q, M, S>,
+	connections: DashMap<String, mpsc::UnboundedSender<String>>,
+}
+
+impl<Q, M, S> GraphQLWsConsumer<Q, M, S>
+where
+	Q: ObjectType + 'static,
+	M: ObjectType + 'static,
+	S: SubscriptionType + 'static,
+{
+	/// Create a consumer serving `schema`
+	pub fn new(schema: Schema<Q, M, S>) -> Self {
+		Self {
+			schema,
+			connections: DashMap::new(),
+		}
+	}
+
+	/// Get the underlying schema
+	pub fn schema(&self) -> &Schema<Q, M, S> {
+		&self.schema
+	}
+
+	/// Number of open connections
+	pub fn connection_count(&self) -> usize {
+		self.connections.len()
+	}
+}
+
+#[async_trait]
+impl<Q, M, S> WebSocketConsumer for GraphQLWsConsumer<Q, M, S>
+where
+	Q: ObjectType + 'static,
+	M: ObjectType + 'static,
+	S: SubscriptionType + 'static,
+{
+	async fn on_connect(&self, context: &mut ConsumerContext) -> WebSocketResult<()> {
+		let protocol = context
+			.connection
+			.subprotocol()
+			.and_then(|subprotocol| subprotocol.parse::<WebSocketProtocols>().ok())
+			.unwrap_or(WebSocketProtocols::GraphQLWS);
+		let (tx, rx) = mpsc::unbounded_channel();
+		self.connections
+			.insert(context.connection.id().to_string(), tx);
+
+		// The protocol state machine runs until the client closes the
+		// connection, which drops the sender and ends every operation.
+		let connection = Arc::clone(&context.connection);
+		let schema = self.schema.clone();
+		tokio::spawn(async move {
+			let mut messages = std::pin::pin!(WebSocket::new(
+				schema,
+				UnboundedReceiverStream::new(rx),
+				protocol
+			));
+			while let Some(message) = messages.next().await {
+				match message {
+					WsMessage::Text(text) => {
+						if connection.send_text(text).await.is_err() {
+							break;
+						}
+					}
+					WsMessage::Close(code, reason) => {
+						let _ = connection.close_with_reason(code, reason).await;
+						break;
+					}
+				}
+			}
+		});
+		Ok(())
+	}
+
+	async fn on_message(
+		&self,
+		context: &mut ConsumerContext,
+		message: Message,
+	) -> WebSocketResult<()> {
+		let Message::Text { data } = message else {
+			return Ok(());
+		};
+		if let Some(tx) = self.connections.get(context.connection.id()) {
+			let _ = tx.send(data);
+		}
+		Ok(())
+	}
+
+	async fn on_disconnect(&self, context: &mut ConsumerContext) -> WebSocketResult<()> {
+		self.connections.remove(context.connection.id());
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use async_graphql::{EmptyMutation, Object, SimpleObject, Subscription};
+	use reinhardt_websockets::WebSocketConnection;
+	use rstest::rstest;
+	use serde::Deserialize;
+	use serde_json::{Value, json};
+	use std::time::Duration;
+
+	#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+	struct WsArticle {
+		id: i64,
+		title: String,
+	}
+
+	struct TestQuery;
+
+	#[Object]
+	impl TestQuery {
+		async fn ok(&self) -> bool {
+			true
+		}
+	}
+
+	struct TestSubscription;
+
+	#[Subscription]
+	impl TestSubscription {
+		async fn article_saved<'ctx>(
+			&self,
+			ctx: &Context<'ctx>,
+		) -> impl Stream<Item = WsArticle> + 'ctx {
+			bridge_stream(ctx, "articleSaved")
+		}
+	}
+
+	async fn next_json(rx: &mut mpsc::UnboundedReceiver<Message>) -> Value {
+		let message = tokio::time::timeout(Duration::from_secs(2), rx.recv())
+			.await
+			.expect("timed out waiting for a message")
+			.expect("connection closed");
+		match message {
+			Message::Text { data } => serde_json::from_str(&data).unwrap(),
+			other => panic!("Expected text message, got {:?}", other),
+		}
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_post_save_pushes_to_subscriber() {
+		// Arrange
+		let bridge = GraphQLSubscriptionBridge::new();
+		broadcast_post_save::<WsArticle>(&bridge, "articleSaved").await;
+		let schema = Schema::build(TestQuery, EmptyMutation, TestSubscription)
+			.data(bridge.clone())
+			.finish();
+		let consumer = GraphQLWsConsumer::new(schema);
+		let (tx, mut rx) = mpsc::unbounded_channel();
+		let connection = Arc::new(WebSocketConnection::with_subprotocol(
+			"gql_1".to_string(),
+			tx,
+			Some("graphql-transport-ws".to_string()),
+		));
+		let mut context = ConsumerContext::new(connection);
+		consumer.on_connect(&mut context).await.unwrap();
+		consumer
+			.on_message(
+				&mut context,
+				Message::text(json!({"type": "connection_init"}).to_string()),
+			)
+			.await
+			.unwrap();
+		assert_eq!(next_json(&mut rx).await["type"], "connection_ack");
+		consumer
+			.on_message(
+				&mut context,
+				Message::text(
+					json!({
+						"id": "1",
+						"type": "subscribe",
+						"payload": {"query": "subscription { articleSaved { id title } }"}
+					})
+					.to_string(),
+				),
+			)
+			.await
+			.unwrap();
+		while bridge.receiver_count("articleSaved") == 0 {
+			tokio::time::sleep(Duration::from_millis(10)).await;
+		}
+
+		// Act
+		post_save::<WsArticle>()
+			.send(WsArticle {
+				id: 7,
+				title: "Hello".to_string(),
+			})
+			.await
+			.unwrap();
+
+		// Assert
+		let next = next_json(&mut rx).await;
+		assert_eq!(next["type"], "next");
+		assert_eq!(next["id"], "1");
+		assert_eq!(next["payload"]["data"]["articleSaved"]["title"], "Hello");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_disconnect_forgets_connection() {
+		// Arrange
+		let schema = Schema::build(TestQuery, EmptyMutation, TestSubscription).finish();
+		let consumer = GraphQLWsConsumer::new(schema);
+		let (tx, _rx) = mpsc::unbounded_channel();
+		let mut context =
+			ConsumerContext::new(Arc::new(WebSocketConnection::new("gql_2".to_string(), tx)));
+		consumer.on_connect(&mut context).await.unwrap();
+
+		// Act
+		consumer.on_disconnect(&mut context).await.unwrap();
+
+		// Assert
+		assert_eq!(consumer.connection_count(), 0);
+	}
+}
@@ -0,0 +1,324 @@
+//! Request bodies that are either fully buffered or streamed.
+//!
+//! By default the server buffers every request body into a single [`Bytes`]
+//! before calling the handler. With streaming enabled it instead hands the
+//! handler a [`Body`] that yields chunks as they arrive, so large uploads can
+//! be parsed, size-checked or written to storage without being held in memory
+//! all at once.
+//!
+//! ## Example
+//!
+//! ```
+//! use bytes::Bytes;
+//! use futures::stream;
+//! use reinhardt_http::Request;
+//! use hyper::Method;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let chunks = vec![Ok(Bytes::from(r#"{"name":"#)), Ok(Bytes::from(r#""Alice"}"#))];
+//! let request = Request::builder()
+//!     .method(Method::POST)
+//!     .uri("/api/users")
+//!     .header("content-type", "application/json")
+//!     .body_stream(stream::iter(chunks))
+//!     .build()
+//!     .unwrap();
+//!
+//! let value: serde_json::Value = request.json_limited(1024).await.unwrap();
+//! assert_eq!(value["name"], "Alice");
+//! # }
+//! ```
+
+use bytes::{Bytes, BytesMut};
+use futures::stream::{Stream, StreamExt};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A request body delivered as a stream of chunks
+pub type BodyStream = Pin<Box<dyn Stream<Item = std::io::Result<Bytes>> + Send>>;
+
+/// Errors raised while reading a [`Body`]
+#[derive(Debug, thiserror::Error)]
+pub enum BodyError {
+	/// The body is larger than the allowed limit
+	#[error("Request body exceeds maximum size of {limit} bytes")]
+	TooLarge {
+		/// The limit that was exceeded, in bytes
+		limit: usize,
+	},
+	/// The body has already been read
+	#[error("Request body has already been consumed")]
+	AlreadyConsumed,
+	/// The underlying stream failed
+	#[error("Failed to read request body: {0}")]
+	Read(#[from] std::io::Error),
+	/// The body is not valid JSON for the requested type
+	#[error("Invalid JSON body: {0}")]
+	Json(#[from] serde_json::Error),
+}
+
+impl From<BodyError> for crate::Error {
+	fn from(error: BodyError) -> Self {
+		match error {
+			BodyError::Json(e) => crate::Error::Serialization(e.to_string()),
+			other => crate::Error::Http(other.to_string()),
+		}
+	}
+}
+
+enum Inner {
+	Full(Option<Bytes>),
+	Stream(BodyStream),
+}
+
+/// A request body, either fully buffered or streamed
+///
+/// `Body` implements [`Stream`], yielding the buffered bytes as a single chunk
+/// or the streamed chunks as they arrive.
+pub struct Body {
+	inner: Inner,
+}
+
+impl Body {
+	/// An empty body
+	pub fn empty() -> Self {
+		Self::from(Bytes::new())
+	}
+
+	/// A body that yields the chunks of `stream`
+	pub fn from_stream<S>(stream: S) -> Self
+	where
+		S: Stream<Item = std::io::Result<Bytes>> + Send + 'static,
+	{
+		Self {
+			inner: Inner::Stream(Box::pin(stream)),
+		}
+	}
+
+	/// Whether the body is streamed rather than buffered
+	pub fn is_streaming(&self) -> bool {
+		matches!(self.inner, Inner::Stream(_))
+	}
+
+	/// The body length when it is buffered
+	pub fn len_hint(&self) -> Option<usize> {
+		match &self.inner {
+			Inner::Full(bytes) => Some(bytes.as_ref().map_or(0, Bytes::len)),
+			Inner::Stream(_) => None,
+		}
+	}
+
+	/// Convert into a boxed chunk stream, e.g. for
+	/// `Parser::parse_stream` or `multer`
+	pub fn into_stream(self) -> BodyStream {
+		match self.inner {
+			Inner::Stream(stream) => stream,
+			full @ Inner::Full(_) => Box::pin(Self { inner: full }),
+		}
+	}
+
+	/// Read the whole body, failing as soon as more than `limit` bytes have
+	/// been received
+	///
+	/// Buffered bodies and streams that arrive in a single chunk are returned
+	/// without copying.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bytes::Bytes;
+	/// use futures::stream;
+	/// use reinhardt_http::body::{Body, BodyError};
+	///
+	/// # #[tokio::main]
+	/// # async fn main() {
+	/// let body = Body::from_stream(stream::iter(vec![
+	///     Ok(Bytes::from("hello ")),
+	///     Ok(Bytes::from("world")),
+	/// ]));
+	/// assert_eq!(body.bytes(64).await.unwrap(), Bytes::from("hello world"));
+	///
+	/// let body = Body::from(Bytes::from("too long"));
+	/// assert!(matches!(body.bytes(4).await, Err(BodyError::TooLarge { limit: 4 })));
+	/// # }
+	/// ```
+	pub async fn bytes(self, limit: usize) -> Result<Bytes, BodyError> {
+		let mut stream = match self.inner {
+			Inner::Full(bytes) => {
+				let bytes = bytes.unwrap_or_default();
+				if bytes.len() > limit {
+					return Err(BodyError::TooLarge { limit });
+				}
+				return Ok(bytes);
+			}
+			Inner::Stream(stream) => stream,
+		};
+
+		let mut first: Option<Bytes> = None;
+		let mut joined = BytesMut::new();
+		let mut received = 0usize;
+		while let Some(chunk) = stream.next().await {
+			let chunk = chunk?;
+			if chunk.is_empty() {
+				continue;
+			}
+			received += chunk.len();
+			if received > limit {
+				return Err(BodyError::TooLarge { limit });
+			}
+			match first.take() {
+				None if joined.is_empty() => first = Some(chunk),
+				None => joined.extend_from_slice(&chunk),
+				Some(previous) => {
+					joined.extend_from_slice(&previous);
+					joined.extend_from_slice(&chunk);
+				}
+			}
+		}
+		Ok(first.unwrap_or_else(|| joined.freeze()))
+	}
+
+	/// Read at most `limit` bytes and deserialize them as JSON
+	pub async fn json<T: serde::de::DeserializeOwned>(self, limit: usize) -> Result<T, BodyError> {
+		let bytes = self.bytes(limit).await?;
+		Ok(serde_json::from_slice(&bytes)?)
+	}
+}
+
+impl Default for Body {
+	fn default() -> Self {
+		Self::empty()
+	}
+}
+
+impl From<Bytes> for Body {
+	fn from(bytes: Bytes) -> Self {
+		Self {
+			inner: Inner::Full(Some(bytes)),
+		}
+	}
+}
+
+impl From<Vec<u8>> for Body {
+	fn from(bytes: Vec<u8>) -> Self {
+		Self::from(Bytes::from(bytes))
+	}
+}
+
+impl From<BodyStream> for Body {
+	fn from(stream: BodyStream) -> Self {
+		Self {
+			inner: Inner::Stream(stream),
+		}
+	}
+}
+
+impl Stream for Body {
+	type Item = std::io::Result<Bytes>;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		match &mut self.get_mut().inner {
+			Inner::Full(bytes) => Poll::Ready(bytes.take().filter(|b| !b.is_empty()).map(Ok)),
+			Inner::Stream(stream) => stream.as_mut().poll_next(cx),
+		}
+	}
+}
+
+impl std::fmt::Debug for Body {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match &self.inner {
+			Inner::Full(bytes) => f
+				.debug_struct("Body")
+				.field("len", &bytes.as_ref().map_or(0, Bytes::len))
+				.finish(),
+			Inner::Stream(_) => f.debug_struct("Body").field("streaming", &true).finish(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use futures::stream;
+	use rstest::rstest;
+
+	fn chunked(chunks: &[&'static str]) -> Body {
+		let chunks: Vec<std::io::Result<Bytes>> = chunks
+			.iter()
+			.map(|chunk| Ok(Bytes::from_static(chunk.as_bytes())))
+			.collect();
+		Body::from_stream(stream::iter(chunks))
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_full_body_is_returned_without_copying() {
+		// Arrange
+		let data = Bytes::from("payload");
+		let body = Body::from(data.clone());
+
+		// Act
+		let bytes = body.bytes(1024).await.unwrap();
+
+		// Assert
+		assert_eq!(bytes.as_ptr(), data.as_ptr());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_stream_is_concatenated() {
+		// Arrange
+		let body = chunked(&["ab", "", "cd", "e"]);
+
+		// Act
+		let bytes = body.bytes(5).await.unwrap();
+
+		// Assert
+		assert_eq!(bytes, Bytes::from("abcde"));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_stream_stops_at_limit() {
+		// Arrange - the stream fails if read past the second chunk
+		let chunks: Vec<std::io::Result<Bytes>> = vec![
+			Ok(Bytes::from("abc")),
+			Ok(Bytes::from("def")),
+			Err(std::io::Error::other("read past the limit")),
+		];
+		let body = Body::from_stream(stream::iter(chunks));
+
+		// Act
+		let result = body.bytes(4).await;
+
+		// Assert
+		assert!(matches!(result, Err(BodyError::TooLarge { limit: 4 })));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_json_from_stream() {
+		// Arrange
+		let body = chunked(&[r#"{"id":"#, "7}"]);
+
+		// Act
+		let value: serde_json::Value = body.json(64).await.unwrap();
+
+		// Assert
+		assert_eq!(value["id"], 7);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_full_body_streams_as_single_chunk() {
+		// Arrange
+		let body = Body::from(Bytes::from("once"));
+
+		// Act
+		let chunks: Vec<Bytes> = body.map(|chunk| chunk.unwrap()).collect().await;
+
+		// Assert
+		assert_eq!(chunks, vec![Bytes::from("once")]);
+	}
+}
@@ -0,0 +1,289 @@
+//! Client IP resolution behind trusted reverse proxies.
+//!
+//! [`ClientIpResolver`] is configured with the IP addresses or CIDR networks
+//! of the proxies deployed in front of the application. It reads the
+//! RFC 7239 `Forwarded` header (or `X-Forwarded-For` when absent) only when
+//! the peer is one of these proxies, and walks the hop chain from the right,
+//! skipping trusted hops, so a client cannot spoof its address by prepending
+//! entries.
+//!
+//! Install the resolver on requests with
+//! [`Request::set_client_ip_resolver`] (the `ClientIpMiddleware` of
+//! `reinhardt-middleware` does this) and read the result with
+//! [`Request::client_ip`]. Throttling, IP permissions, sessions and the admin
+//! audit log all use [`Request::client_ip`], so they agree on the address.
+
+use crate::Request;
+use std::fmt;
+use std::net::{IpAddr, SocketAddr};
+
+/// Client IP address resolved for a request
+///
+/// Cached in the request extensions by [`Request::client_ip`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClientIp(pub IpAddr);
+
+impl fmt::Display for ClientIp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+/// Error returned for a trusted proxy entry that is neither an IP address
+/// nor a CIDR network
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid trusted proxy '{0}': expected an IP address or CIDR network")]
+pub struct InvalidProxyError(pub String);
+
+/// Resolves the client IP of requests forwarded by trusted proxies
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_http::{ClientIpResolver, Request};
+/// use hyper::{HeaderMap, Method};
+/// use std::net::SocketAddr;
+///
+/// let resolver = ClientIpResolver::from_cidrs(&["10.0.0.0/8"]).unwrap();
+///
+/// let mut headers = HeaderMap::new();
+/// // The client prepended a spoofed address; 10.0.0.2 is an inner proxy.
+/// headers.insert("x-forwarded-for", "1.1.1.1, 203.0.113.7, 10.0.0.2".parse().unwrap());
+/// let request = Request::builder()
+///     .method(Method::GET)
+///     .uri("/")
+///     .headers(headers)
+///     .remote_addr(SocketAddr::new("10.0.0.1".parse().unwrap(), 443))
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(resolver.resolve(&request), Some("203.0.113.7".parse().unwrap()));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ClientIpResolver {
+	trusted_proxies: Vec<ipnet::IpNet>,
+}
+
+impl ClientIpResolver {
+	/// Create a resolver trusting the given networks
+	pub fn new(trusted_proxies: impl IntoIterator<Item = ipnet::IpNet>) -> Self {
+		Self {
+			trusted_proxies: trusted_proxies.into_iter().collect(),
+		}
+	}
+
+	/// Create a resolver from IP addresses or CIDR networks
+	///
+	/// Single addresses are trusted as host networks (`/32` or `/128`).
+	pub fn from_cidrs<S: AsRef<str>>(entries: &[S]) -> Result<Self, InvalidProxyError> {
+		entries
+			.iter()
+			.map(|entry| {
+				let entry = entry.as_ref().trim();
+				entry
+					.parse::<ipnet::IpNet>()
+					.or_else(|_| entry.parse::<IpAddr>().map(ipnet::IpNet::from))
+					.map_err(|_| InvalidProxyError(entry.to_string()))
+			})
+			.collect::<Result<Vec<_>, _>>()
+			.map(Self::new)
+	}
+
+	/// Trusted proxy networks
+	pub fn trusted_proxies(&self) -> &[ipnet::IpNet] {
+		&self.trusted_proxies
+	}
+
+	/// Check if an IP address belongs to a trusted proxy
+	pub fn is_trusted(&self, ip: &IpAddr) -> bool {
+		self.trusted_proxies
+			.iter()
+			.any(|network| network.contains(&normalize(*ip)))
+	}
+
+	/// Resolve the client IP of a request
+	///
+	/// 1. A peer that is not a trusted proxy is the client.
+	/// 2. Otherwise the `Forwarded` (or `X-Forwarded-For`) chain is walked
+	///    from the right, and the first hop that is not a trusted proxy is
+	///    the client. An unparsable hop stops the walk at the last trusted
+	///    hop; a chain of only trusted hops yields its leftmost entry.
+	/// 3. Without a chain, `X-Real-IP` is used, then the peer.
+	///
+	/// Returns `None` only when the request has no remote address.
+	pub fn resolve(&self, request: &Request) -> Option<IpAddr> {
+		let peer = request.remote_addr.map(|addr| normalize(addr.ip()))?;
+		if !self.is_trusted(&peer) {
+			return Some(peer);
+		}
+
+		let chain = forwarded_chain(request);
+		if !chain.is_empty() {
+			let mut client = peer;
+			for hop in chain.iter().rev() {
+				match hop {
+					Some(ip) if self.is_trusted(ip) => client = *ip,
+					Some(ip) => return Some(*ip),
+					None => return Some(client),
+				}
+			}
+			return Some(client);
+		}
+
+		if let Some(real_ip) = request.headers.get("x-real-ip")
+			&& let Ok(value) = real_ip.to_str()
+			&& let Some(ip) = parse_node(value)
+		{
+			return Some(ip);
+		}
+
+		Some(peer)
+	}
+}
+
+/// Hops of the `Forwarded` header, or of `X-Forwarded-For` when absent
+///
+/// Every header line is read in order. Unparsable or obfuscated hops are
+/// `None`.
+fn forwarded_chain(request: &Request) -> Vec<Option<IpAddr>> {
+	let forwarded: Vec<Option<IpAddr>> = request
+		.headers
+		.get_all("forwarded")
+		.iter()
+		.filter_map(|value| value.to_str().ok())
+		.flat_map(|value| value.split(','))
+		.map(|element| {
+			element
+				.split(';')
+				.filter_map(|pair| pair.split_once('='))
+				.find(|(key, _)| key.trim().eq_ignore_ascii_case("for"))
+				.and_then(|(_, node)| parse_node(node))
+		})
+		.collect();
+	if !forwarded.is_empty() {
+		return forwarded;
+	}
+
+	request
+		.headers
+		.get_all("x-forwarded-for")
+		.iter()
+		.filter_map(|value| value.to_str().ok())
+		.flat_map(|value| value.split(','))
+		.map(parse_node)
+		.collect()
+}
+
+/// Parse a forwarded node: `1.2.3.4`, `1.2.3.4:80`, `"[2001:db8::1]:80"`, `2001:db8::1`
+fn parse_node(node: &str) -> Option<IpAddr> {
+	let node = node.trim().trim_matches('"');
+	if let Ok(ip) = node.parse::<IpAddr>() {
+		return Some(normalize(ip));
+	}
+	if let Ok(addr) = node.parse::<SocketAddr>() {
+		return Some(normalize(addr.ip()));
+	}
+	node.strip_prefix('[')
+		.and_then(|rest| rest.split_once(']'))
+		.and_then(|(ip, _)| ip.parse::<IpAddr>().ok())
+		.map(normalize)
+}
+
+/// Map IPv4-mapped IPv6 addresses to IPv4
+fn normalize(ip: IpAddr) -> IpAddr {
+	match ip {
+		IpAddr::V6(v6) => v6
+			.to_ipv4_mapped()
+			.map(IpAddr::V4)
+			.unwrap_or(IpAddr::V6(v6)),
+		v4 => v4,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use hyper::{HeaderMap, Method, header::HeaderName};
+	use rstest::rstest;
+
+	fn request(peer: &str, headers: &[(&str, &str)]) -> Request {
+		let mut map = HeaderMap::new();
+		for (name, value) in headers {
+			map.append(
+				HeaderName::from_bytes(name.as_bytes()).unwrap(),
+				value.parse().unwrap(),
+			);
+		}
+		Request::builder()
+			.method(Method::GET)
+			.uri("/")
+			.headers(map)
+			.remote_addr(SocketAddr::new(peer.parse().unwrap(), 443))
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	#[case::untrusted_peer("203.0.113.9", &[("x-forwarded-for", "1.1.1.1")], "203.0.113.9")]
+	#[case::single_hop("10.0.0.1", &[("x-forwarded-for", "203.0.113.7")], "203.0.113.7")]
+	#[case::spoofed_prefix("10.0.0.1", &[("x-forwarded-for", "1.1.1.1, 203.0.113.7")], "203.0.113.7")]
+	#[case::inner_proxies("10.0.0.1", &[("x-forwarded-for", "203.0.113.7, 10.0.0.3, 10.0.0.2")], "203.0.113.7")]
+	#[case::all_trusted("10.0.0.1", &[("x-forwarded-for", "10.0.0.3, 10.0.0.2")], "10.0.0.3")]
+	#[case::garbage_hop("10.0.0.1", &[("x-forwarded-for", "203.0.113.7, nonsense, 10.0.0.2")], "10.0.0.2")]
+	#[case::multiple_lines("10.0.0.1", &[("x-forwarded-for", "1.1.1.1"), ("x-forwarded-for", "203.0.113.7")], "203.0.113.7")]
+	#[case::forwarded("10.0.0.1", &[("forwarded", "for=1.1.1.1, for=\"[2001:db8::7]:4711\";proto=https")], "2001:db8::7")]
+	#[case::forwarded_port("10.0.0.1", &[("forwarded", "for=203.0.113.7:8080")], "203.0.113.7")]
+	#[case::forwarded_unknown("10.0.0.1", &[("forwarded", "for=unknown, for=10.0.0.2")], "10.0.0.2")]
+	#[case::forwarded_preferred("10.0.0.1", &[("forwarded", "for=203.0.113.7"), ("x-forwarded-for", "1.1.1.1")], "203.0.113.7")]
+	#[case::real_ip("10.0.0.1", &[("x-real-ip", "203.0.113.7")], "203.0.113.7")]
+	#[case::no_headers("10.0.0.1", &[], "10.0.0.1")]
+	#[case::mapped_peer("::ffff:10.0.0.1", &[("x-forwarded-for", "203.0.113.7")], "203.0.113.7")]
+	fn test_resolve(#[case] peer: &str, #[case] headers: &[(&str, &str)], #[case] expected: &str) {
+		// Arrange
+		let resolver = ClientIpResolver::from_cidrs(&["10.0.0.0/8"]).unwrap();
+
+		// Act
+		let ip = resolver.resolve(&request(peer, headers));
+
+		// Assert
+		assert_eq!(ip, Some(expected.parse().unwrap()));
+	}
+
+	#[rstest]
+	fn test_from_cidrs_rejects_host_names() {
+		// Act
+		let result = ClientIpResolver::from_cidrs(&["10.0.0.1", "proxy.internal"]);
+
+		// Assert
+		assert_eq!(
+			result.unwrap_err(),
+			InvalidProxyError("proxy.internal".to_string())
+		);
+	}
+
+	#[rstest]
+	fn test_request_client_ip_uses_installed_resolver_and_caches() {
+		// Arrange
+		let request = request("10.0.0.1", &[("x-forwarded-for", "1.1.1.1, 203.0.113.7")]);
+		request.set_client_ip_resolver(ClientIpResolver::from_cidrs(&["10.0.0.1"]).unwrap());
+
+		// Act
+		let ip = request.client_ip();
+
+		// Assert
+		assert_eq!(ip, Some("203.0.113.7".parse().unwrap()));
+		assert_eq!(
+			request.extensions.get::<ClientIp>(),
+			Some(ClientIp("203.0.113.7".parse().unwrap()))
+		);
+	}
+
+	#[rstest]
+	fn test_request_client_ip_without_resolver_uses_peer() {
+		// Arrange
+		let request = request("203.0.113.9", &[("x-forwarded-for", "1.1.1.1")]);
+
+		// Act / Assert
+		assert_eq!(request.client_ip(), Some("203.0.113.9".parse().unwrap()));
+	}
+}
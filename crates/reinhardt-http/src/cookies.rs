@@ -0,0 +1,460 @@
+//! Typed cookies and cookie jars.
+//!
+//! [`Cookie`] builds `Set-Cookie` values with `Max-Age`, `SameSite`,
+//! `Secure` and `HttpOnly` attributes. [`CookieJar`] reads the cookies sent
+//! with a request and records the cookies to set or delete on the response.
+//! Cookies can be signed (tamper-evident) through [`CookieJar::signed`] or,
+//! with the `cookie-encryption` feature, encrypted through
+//! [`CookieJar::private`]. Both use a [`CookieKey`] derived from the
+//! application secret.
+//!
+//! # Examples
+//!
+//! ```
+//! use reinhardt_http::cookies::{Cookie, CookieKey, SameSite};
+//! use reinhardt_http::{Request, Response};
+//! use std::time::Duration;
+//!
+//! let key = CookieKey::derive(b"an application secret of at least 32 bytes").unwrap();
+//! let request = Request::builder()
+//!     .uri("/")
+//!     .header("cookie", "theme=dark")
+//!     .build()
+//!     .unwrap();
+//!
+//! let mut jar = request.cookies();
+//! assert_eq!(jar.get("theme"), Some("dark"));
+//!
+//! jar.add(
+//!     Cookie::new("lang", "en")
+//!         .with_max_age(Duration::from_secs(3600))
+//!         .with_same_site(SameSite::Lax),
+//! );
+//! jar.signed(&key).add(Cookie::new("user_id", "42"));
+//! jar.remove("theme");
+//!
+//! let response = Response::ok().with_cookie_jar(&jar);
+//! assert_eq!(response.headers.get_all("set-cookie").iter().count(), 3);
+//! ```
+
+#[cfg(feature = "cookie-encryption")]
+pub mod private;
+pub mod signed;
+
+#[cfg(feature = "cookie-encryption")]
+pub use private::PrivateJar;
+pub use signed::SignedJar;
+
+use hmac::{Hmac, Mac};
+use hyper::HeaderMap;
+use percent_encoding::percent_decode_str;
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+use thiserror::Error;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Minimum length of the secret a [`CookieKey`] is derived from
+pub const MIN_SECRET_LEN: usize = 32;
+
+/// Errors raised by the cookie API
+#[derive(Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CookieError {
+	/// The secret is too short to derive cookie keys from.
+	#[error("cookie secret must be at least {MIN_SECRET_LEN} bytes, got {0}")]
+	SecretTooShort(usize),
+}
+
+/// The `SameSite` cookie attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SameSite {
+	/// Sent only with same-site requests.
+	Strict,
+	/// Sent with same-site requests and top-level cross-site navigations.
+	Lax,
+	/// Sent with all requests; browsers require `Secure` with this value.
+	None,
+}
+
+impl fmt::Display for SameSite {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Strict => f.write_str("Strict"),
+			Self::Lax => f.write_str("Lax"),
+			Self::None => f.write_str("None"),
+		}
+	}
+}
+
+/// A cookie to set on a response
+///
+/// The [`Display`](fmt::Display) output is the `Set-Cookie` header value.
+/// Cookies default to `Path=/` and no other attributes.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_http::cookies::{Cookie, SameSite};
+/// use std::time::Duration;
+///
+/// let cookie = Cookie::new("session", "abc")
+///     .with_max_age(Duration::from_secs(60))
+///     .with_secure(true)
+///     .with_http_only(true)
+///     .with_same_site(SameSite::Strict);
+/// assert_eq!(
+///     cookie.to_string(),
+///     "session=abc; Path=/; Max-Age=60; Secure; HttpOnly; SameSite=Strict"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cookie {
+	name: String,
+	value: String,
+	path: Option<String>,
+	domain: Option<String>,
+	max_age: Option<Duration>,
+	secure: bool,
+	http_only: bool,
+	same_site: Option<SameSite>,
+}
+
+impl Cookie {
+	/// Create a cookie with `Path=/`
+	pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+		Self {
+			name: name.into(),
+			value: value.into(),
+			path: Some("/".to_string()),
+			domain: None,
+			max_age: None,
+			secure: false,
+			http_only: false,
+			same_site: None,
+		}
+	}
+
+	/// Create a cookie that deletes `name` on the client
+	///
+	/// The path and domain must match the ones the cookie was set with;
+	/// they default to `Path=/` and no domain.
+	pub fn removal(name: impl Into<String>) -> Self {
+		Self::new(name, "").with_max_age(Duration::ZERO)
+	}
+
+	/// Returns the cookie name
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Returns the cookie value
+	pub fn value(&self) -> &str {
+		&self.value
+	}
+
+	/// Returns `true` if this cookie deletes the client's cookie
+	pub fn is_removal(&self) -> bool {
+		self.max_age == Some(Duration::ZERO)
+	}
+
+	/// Set the `Path` attribute, or omit it with `None`
+	pub fn with_path(mut self, path: Option<impl Into<String>>) -> Self {
+		self.path = path.map(Into::into);
+		self
+	}
+
+	/// Set the `Domain` attribute
+	pub fn with_domain(mut self, domain: impl Into<String>) -> Self {
+		self.domain = Some(domain.into());
+		self
+	}
+
+	/// Set the `Max-Age` attribute (whole seconds)
+	pub fn with_max_age(mut self, max_age: Duration) -> Self {
+		self.max_age = Some(max_age);
+		self
+	}
+
+	/// Set the `Secure` attribute
+	pub fn with_secure(mut self, secure: bool) -> Self {
+		self.secure = secure;
+		self
+	}
+
+	/// Set the `HttpOnly` attribute
+	pub fn with_http_only(mut self, http_only: bool) -> Self {
+		self.http_only = http_only;
+		self
+	}
+
+	/// Set the `SameSite` attribute
+	///
+	/// `SameSite::None` also sets `Secure`, which browsers require.
+	pub fn with_same_site(mut self, same_site: SameSite) -> Self {
+		if same_site == SameSite::None {
+			self.secure = true;
+		}
+		self.same_site = Some(same_site);
+		self
+	}
+
+	pub(crate) fn with_value(mut self, value: String) -> Self {
+		self.value = value;
+		self
+	}
+}
+
+impl fmt::Display for Cookie {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}={}", self.name, self.value)?;
+		if let Some(path) = &self.path {
+			write!(f, "; Path={path}")?;
+		}
+		if let Some(domain) = &self.domain {
+			write!(f, "; Domain={domain}")?;
+		}
+		if let Some(max_age) = self.max_age {
+			write!(f, "; Max-Age={}", max_age.as_secs())?;
+		}
+		if self.secure {
+			f.write_str("; Secure")?;
+		}
+		if self.http_only {
+			f.write_str("; HttpOnly")?;
+		}
+		if let Some(same_site) = self.same_site {
+			write!(f, "; SameSite={same_site}")?;
+		}
+		Ok(())
+	}
+}
+
+/// Keys for signed and encrypted cookies
+///
+/// Separate signing and encryption keys are derived from one application
+/// secret, so the secret itself is never used directly.
+#[derive(Clone)]
+pub struct CookieKey {
+	signing: [u8; 32],
+	#[cfg_attr(
+		not(feature = "cookie-encryption"),
+		allow(dead_code, reason = "only read by encrypted cookies")
+	)]
+	encryption: [u8; 32],
+}
+
+impl CookieKey {
+	/// Derive cookie keys from an application secret
+	///
+	/// # Errors
+	///
+	/// Returns [`CookieError::SecretTooShort`] if the secret is shorter than
+	/// [`MIN_SECRET_LEN`] bytes.
+	pub fn derive(secret: &[u8]) -> Result<Self, CookieError> {
+		if secret.len() < MIN_SECRET_LEN {
+			return Err(CookieError::SecretTooShort(secret.len()));
+		}
+		Ok(Self {
+			signing: derive_subkey(secret, b"reinhardt.cookies.signing"),
+			encryption: derive_subkey(secret, b"reinhardt.cookies.encryption"),
+		})
+	}
+}
+
+impl fmt::Debug for CookieKey {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("CookieKey { .. }")
+	}
+}
+
+fn derive_subkey(secret: &[u8], label: &[u8]) -> [u8; 32] {
+	let mut mac =
+		<HmacSha256 as Mac>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+	mac.update(label);
+	mac.finalize().into_bytes().into()
+}
+
+/// Cookies received with a request and cookies to send back
+///
+/// Reads return the latest value: a cookie added to the jar shadows the
+/// request's value, and a removed cookie reads as absent.
+#[derive(Debug, Clone, Default)]
+pub struct CookieJar {
+	incoming: HashMap<String, String>,
+	delta: Vec<Cookie>,
+}
+
+impl CookieJar {
+	/// Create an empty jar
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Create a jar holding the cookies of every `Cookie` header
+	///
+	/// Values are percent-decoded. When a name repeats, the first value wins,
+	/// as browsers send the most specific cookie first.
+	pub fn from_headers(headers: &HeaderMap) -> Self {
+		let mut incoming = HashMap::new();
+		for header in headers.get_all(hyper::header::COOKIE) {
+			let Ok(header) = header.to_str() else {
+				continue;
+			};
+			for pair in header.split(';') {
+				if let Some((name, value)) = pair.trim().split_once('=') {
+					let value = value.trim().trim_matches('"');
+					let value = percent_decode_str(value).decode_utf8_lossy().into_owned();
+					incoming.entry(name.trim().to_string()).or_insert(value);
+				}
+			}
+		}
+		Self {
+			incoming,
+			delta: Vec::new(),
+		}
+	}
+
+	/// Returns the value of a cookie
+	pub fn get(&self, name: &str) -> Option<&str> {
+		match self.delta.iter().rev().find(|cookie| cookie.name == name) {
+			Some(cookie) if cookie.is_removal() => None,
+			Some(cookie) => Some(&cookie.value),
+			None => self.incoming.get(name).map(String::as_str),
+		}
+	}
+
+	/// Returns `true` if the jar holds a value for `name`
+	pub fn contains(&self, name: &str) -> bool {
+		self.get(name).is_some()
+	}
+
+	/// Set a cookie on the response
+	pub fn add(&mut self, cookie: Cookie) {
+		self.delta
+			.retain(|existing| !same_cookie(existing, &cookie));
+		self.delta.push(cookie);
+	}
+
+	/// Delete a cookie set with `Path=/` and no domain
+	pub fn remove(&mut self, name: impl Into<String>) {
+		self.add(Cookie::removal(name));
+	}
+
+	/// Delete a cookie, using `cookie`'s path and domain
+	///
+	/// Use this for cookies set with a custom path or domain.
+	pub fn remove_cookie(&mut self, cookie: Cookie) {
+		self.add(
+			cookie
+				.with_value(String::new())
+				.with_max_age(Duration::ZERO),
+		);
+	}
+
+	/// Returns the cookies added or removed since the jar was created
+	pub fn delta(&self) -> impl Iterator<Item = &Cookie> {
+		self.delta.iter()
+	}
+
+	/// Returns a view of the jar that signs and verifies cookie values
+	pub fn signed<'a>(&'a mut self, key: &'a CookieKey) -> SignedJar<'a> {
+		SignedJar::new(self, key)
+	}
+
+	/// Returns a view of the jar that encrypts and decrypts cookie values
+	#[cfg(feature = "cookie-encryption")]
+	pub fn private<'a>(&'a mut self, key: &'a CookieKey) -> PrivateJar<'a> {
+		PrivateJar::new(self, key)
+	}
+}
+
+fn same_cookie(a: &Cookie, b: &Cookie) -> bool {
+	a.name == b.name && a.path == b.path && a.domain == b.domain
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn headers(cookie: &str) -> HeaderMap {
+		let mut headers = HeaderMap::new();
+		headers.insert(hyper::header::COOKIE, cookie.parse().unwrap());
+		headers
+	}
+
+	#[rstest]
+	#[case::defaults(Cookie::new("a", "1"), "a=1; Path=/")]
+	#[case::no_path(Cookie::new("a", "1").with_path(None::<String>), "a=1")]
+	#[case::domain(Cookie::new("a", "1").with_domain("example.com"), "a=1; Path=/; Domain=example.com")]
+	#[case::same_site_none(Cookie::new("a", "1").with_same_site(SameSite::None), "a=1; Path=/; Secure; SameSite=None")]
+	#[case::removal(Cookie::removal("a"), "a=; Path=/; Max-Age=0")]
+	fn test_cookie_header_value(#[case] cookie: Cookie, #[case] expected: &str) {
+		// Act
+		let value = cookie.to_string();
+
+		// Assert
+		assert_eq!(value, expected);
+	}
+
+	#[rstest]
+	fn test_jar_parses_request_cookies() {
+		// Act
+		let jar = CookieJar::from_headers(&headers("a=1; b=hello%20world; a=2; c=\"q\""));
+
+		// Assert
+		assert_eq!(jar.get("a"), Some("1"));
+		assert_eq!(jar.get("b"), Some("hello world"));
+		assert_eq!(jar.get("c"), Some("q"));
+		assert_eq!(jar.get("missing"), None);
+	}
+
+	#[rstest]
+	fn test_jar_delta_shadows_incoming() {
+		// Arrange
+		let mut jar = CookieJar::from_headers(&headers("a=1; b=2"));
+
+		// Act
+		jar.add(Cookie::new("a", "changed"));
+		jar.add(Cookie::new("a", "latest"));
+		jar.remove("b");
+
+		// Assert
+		assert_eq!(jar.get("a"), Some("latest"));
+		assert!(!jar.contains("b"));
+		let delta: Vec<String> = jar.delta().map(ToString::to_string).collect();
+		assert_eq!(delta, vec!["a=latest; Path=/", "b=; Path=/; Max-Age=0"]);
+	}
+
+	#[rstest]
+	fn test_remove_cookie_keeps_path_and_domain() {
+		// Arrange
+		let mut jar = CookieJar::new();
+
+		// Act
+		jar.remove_cookie(
+			Cookie::new("a", "1")
+				.with_path(Some("/admin"))
+				.with_domain("example.com"),
+		);
+
+		// Assert
+		let delta: Vec<String> = jar.delta().map(ToString::to_string).collect();
+		assert_eq!(
+			delta,
+			vec!["a=; Path=/admin; Domain=example.com; Max-Age=0"]
+		);
+	}
+
+	#[rstest]
+	fn test_key_rejects_short_secret() {
+		// Act
+		let result = CookieKey::derive(b"short");
+
+		// Assert
+		assert_eq!(result.unwrap_err(), CookieError::SecretTooShort(5));
+	}
+}
@@ -0,0 +1,148 @@
+//! Encrypted cookies.
+//!
+//! A private cookie's value is encrypted with AES-256-GCM, with the cookie
+//! name as associated data. The client can neither read nor change it.
+
+use super::{Cookie, CookieJar, CookieKey};
+use aes_gcm::aead::{Aead, KeyInit, OsRng, Payload, rand_core::RngCore};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD as BASE64;
+
+/// Nonce size for AES-GCM (96 bits / 12 bytes)
+const NONCE_SIZE: usize = 12;
+
+/// A view of a [`CookieJar`] that encrypts and decrypts cookie values
+///
+/// Obtained through [`CookieJar::private`].
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_http::cookies::{Cookie, CookieJar, CookieKey};
+///
+/// let key = CookieKey::derive(b"an application secret of at least 32 bytes").unwrap();
+/// let mut jar = CookieJar::new();
+/// jar.private(&key).add(Cookie::new("cart", "3 items"));
+///
+/// assert_eq!(jar.private(&key).get("cart").as_deref(), Some("3 items"));
+/// assert!(!jar.get("cart").unwrap().contains("items"));
+/// ```
+#[derive(Debug)]
+pub struct PrivateJar<'a> {
+	jar: &'a mut CookieJar,
+	key: &'a CookieKey,
+}
+
+impl<'a> PrivateJar<'a> {
+	pub(super) fn new(jar: &'a mut CookieJar, key: &'a CookieKey) -> Self {
+		Self { jar, key }
+	}
+
+	/// Returns the decrypted value of a cookie
+	///
+	/// Returns `None` if the cookie is missing or cannot be decrypted.
+	pub fn get(&self, name: &str) -> Option<String> {
+		let sealed = BASE64.decode(self.jar.get(name)?).ok()?;
+		if sealed.len() < NONCE_SIZE {
+			return None;
+		}
+		let (nonce, ciphertext) = sealed.split_at(NONCE_SIZE);
+		let plaintext = self
+			.cipher()
+			.decrypt(
+				Nonce::from_slice(nonce),
+				Payload {
+					msg: ciphertext,
+					aad: name.as_bytes(),
+				},
+			)
+			.ok()?;
+		String::from_utf8(plaintext).ok()
+	}
+
+	/// Encrypt a cookie's value and set it on the response
+	pub fn add(&mut self, cookie: Cookie) {
+		let mut nonce = [0u8; NONCE_SIZE];
+		OsRng.fill_bytes(&mut nonce);
+		let ciphertext = self
+			.cipher()
+			.encrypt(
+				Nonce::from_slice(&nonce),
+				Payload {
+					msg: cookie.value().as_bytes(),
+					aad: cookie.name().as_bytes(),
+				},
+			)
+			.expect("AES-GCM encryption of an in-memory buffer cannot fail");
+		let mut sealed = nonce.to_vec();
+		sealed.extend_from_slice(&ciphertext);
+		let value = BASE64.encode(sealed);
+		self.jar.add(cookie.with_value(value));
+	}
+
+	/// Delete a cookie set with `Path=/` and no domain
+	pub fn remove(&mut self, name: impl Into<String>) {
+		self.jar.remove(name);
+	}
+
+	fn cipher(&self) -> Aes256Gcm {
+		Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&self.key.encryption))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn key() -> CookieKey {
+		CookieKey::derive(&[7; 32]).unwrap()
+	}
+
+	#[rstest]
+	fn test_private_values_use_fresh_nonces() {
+		// Arrange
+		let key = key();
+		let mut first = CookieJar::new();
+		let mut second = CookieJar::new();
+
+		// Act
+		first.private(&key).add(Cookie::new("cart", "1"));
+		second.private(&key).add(Cookie::new("cart", "1"));
+
+		// Assert
+		assert_ne!(first.get("cart"), second.get("cart"));
+		assert_eq!(second.private(&key).get("cart").as_deref(), Some("1"));
+	}
+
+	#[rstest]
+	fn test_private_value_is_bound_to_name() {
+		// Arrange
+		let key = key();
+		let mut jar = CookieJar::new();
+		jar.private(&key).add(Cookie::new("cart", "1"));
+		let sealed = jar.get("cart").unwrap().to_string();
+
+		// Act
+		jar.add(Cookie::new("wishlist", sealed));
+
+		// Assert
+		assert_eq!(jar.private(&key).get("wishlist"), None);
+	}
+
+	#[rstest]
+	#[case::plaintext("1")]
+	#[case::truncated("AAAA")]
+	fn test_private_rejects_foreign_values(#[case] value: &str) {
+		// Arrange
+		let key = key();
+		let mut jar = CookieJar::new();
+
+		// Act
+		jar.add(Cookie::new("cart", value));
+
+		// Assert
+		assert_eq!(jar.private(&key).get("cart"), None);
+	}
+}
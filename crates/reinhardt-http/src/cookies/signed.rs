@@ -0,0 +1,144 @@
+//! Signed cookies.
+//!
+//! A signed cookie's value carries an HMAC-SHA256 tag over its name and
+//! value. The client can read the value but any change invalidates it.
+
+use super::{Cookie, CookieJar, CookieKey, HmacSha256};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD as BASE64;
+use hmac::Mac;
+
+/// A view of a [`CookieJar`] that signs and verifies cookie values
+///
+/// Obtained through [`CookieJar::signed`].
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_http::cookies::{Cookie, CookieJar, CookieKey};
+///
+/// let key = CookieKey::derive(b"an application secret of at least 32 bytes").unwrap();
+/// let mut jar = CookieJar::new();
+/// jar.signed(&key).add(Cookie::new("user_id", "42"));
+///
+/// assert_eq!(jar.signed(&key).get("user_id").as_deref(), Some("42"));
+/// assert_ne!(jar.get("user_id"), Some("42"));
+/// ```
+#[derive(Debug)]
+pub struct SignedJar<'a> {
+	jar: &'a mut CookieJar,
+	key: &'a CookieKey,
+}
+
+impl<'a> SignedJar<'a> {
+	pub(super) fn new(jar: &'a mut CookieJar, key: &'a CookieKey) -> Self {
+		Self { jar, key }
+	}
+
+	/// Returns the verified value of a cookie
+	///
+	/// Returns `None` if the cookie is missing, unsigned or tampered with.
+	pub fn get(&self, name: &str) -> Option<String> {
+		let signed = self.jar.get(name)?;
+		let (value, tag) = signed.rsplit_once('.')?;
+		let tag = BASE64.decode(tag).ok()?;
+		self.mac(name, value).verify_slice(&tag).ok()?;
+		Some(value.to_string())
+	}
+
+	/// Sign a cookie's value and set it on the response
+	pub fn add(&mut self, cookie: Cookie) {
+		let tag = BASE64.encode(
+			self.mac(cookie.name(), cookie.value())
+				.finalize()
+				.into_bytes(),
+		);
+		let value = format!("{}.{tag}", cookie.value());
+		self.jar.add(cookie.with_value(value));
+	}
+
+	/// Delete a cookie set with `Path=/` and no domain
+	pub fn remove(&mut self, name: impl Into<String>) {
+		self.jar.remove(name);
+	}
+
+	fn mac(&self, name: &str, value: &str) -> HmacSha256 {
+		let mut mac = <HmacSha256 as Mac>::new_from_slice(&self.key.signing)
+			.expect("HMAC accepts keys of any length");
+		// Bind the tag to the name so a value cannot be moved to another cookie
+		mac.update(name.as_bytes());
+		mac.update(b"=");
+		mac.update(value.as_bytes());
+		mac
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn key() -> CookieKey {
+		CookieKey::derive(&[7; 32]).unwrap()
+	}
+
+	#[rstest]
+	fn test_signed_round_trip_with_dotted_value() {
+		// Arrange
+		let key = key();
+		let mut jar = CookieJar::new();
+
+		// Act
+		jar.signed(&key).add(Cookie::new("token", "a.b.c"));
+
+		// Assert
+		assert_eq!(jar.signed(&key).get("token").as_deref(), Some("a.b.c"));
+	}
+
+	#[rstest]
+	#[case::tampered_value(|signed: &str| signed.replacen("42", "43", 1))]
+	#[case::missing_tag(|_: &str| "42".to_string())]
+	#[case::bad_tag(|_: &str| "42.not-a-tag".to_string())]
+	fn test_signed_rejects_tampering(#[case] tamper: fn(&str) -> String) {
+		// Arrange
+		let key = key();
+		let mut jar = CookieJar::new();
+		jar.signed(&key).add(Cookie::new("user_id", "42"));
+		let tampered = tamper(jar.get("user_id").unwrap());
+
+		// Act
+		jar.add(Cookie::new("user_id", tampered));
+
+		// Assert
+		assert_eq!(jar.signed(&key).get("user_id"), None);
+	}
+
+	#[rstest]
+	fn test_signed_value_is_bound_to_name() {
+		// Arrange
+		let key = key();
+		let mut jar = CookieJar::new();
+		jar.signed(&key).add(Cookie::new("user_id", "42"));
+		let signed = jar.get("user_id").unwrap().to_string();
+
+		// Act
+		jar.add(Cookie::new("admin_id", signed));
+
+		// Assert
+		assert_eq!(jar.signed(&key).get("admin_id"), None);
+	}
+
+	#[rstest]
+	fn test_signed_rejects_other_key() {
+		// Arrange
+		let key = key();
+		let other = CookieKey::derive(&[8; 32]).unwrap();
+		let mut jar = CookieJar::new();
+
+		// Act
+		jar.signed(&key).add(Cookie::new("user_id", "42"));
+
+		// Assert
+		assert_eq!(jar.signed(&other).get("user_id"), None);
+	}
+}
@@ -0,0 +1,537 @@
+//! Runtime helpers behind the view decorator macros
+//!
+//! `#[require_http_methods]`, `#[login_required]`, `#[cache_control]` and
+//! `#[deprecation]` expand to calls into this module. The helpers can also be used directly
+//! from handlers that are not written with the macros.
+//!
+//! ## Example
+//!
+//! ```
+//! use reinhardt_http::Request;
+//! use reinhardt_http::decorators::require_http_methods;
+//! use hyper::{Method, StatusCode};
+//!
+//! let request = Request::builder()
+//!     .method(Method::DELETE)
+//!     .uri("/articles/")
+//!     .build()
+//!     .unwrap();
+//!
+//! let response = require_http_methods(&request, &["GET", "POST"]).unwrap();
+//! assert_eq!(response.status, StatusCode::METHOD_NOT_ALLOWED);
+//! assert_eq!(response.headers.get("allow").unwrap(), "GET, POST");
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+use chrono::NaiveDate;
+use hyper::StatusCode;
+use hyper::header::{CACHE_CONTROL, HeaderValue, LINK, USER_AGENT};
+
+use crate::redirect::login_redirect;
+use crate::{AuthState, Request, Response};
+
+/// Return value of a decorated handler
+///
+/// Lets the decorator macros short-circuit with a response and post-process
+/// the handler's response whether it returns `Response` or
+/// `Result<Response, E>`.
+pub trait ViewResponse: Sized {
+	/// Wraps a response produced by a decorator
+	fn from_response(response: Response) -> Self;
+
+	/// Applies `f` to the response, leaving errors untouched
+	fn map_response(self, f: impl FnOnce(Response) -> Response) -> Self;
+}
+
+impl ViewResponse for Response {
+	fn from_response(response: Response) -> Self {
+		response
+	}
+
+	fn map_response(self, f: impl FnOnce(Response) -> Response) -> Self {
+		f(self)
+	}
+}
+
+impl<E> ViewResponse for Result<Response, E> {
+	fn from_response(response: Response) -> Self {
+		Ok(response)
+	}
+
+	fn map_response(self, f: impl FnOnce(Response) -> Response) -> Self {
+		self.map(f)
+	}
+}
+
+/// Rejects requests whose method is not in `methods`
+///
+/// Returns a `405 Method Not Allowed` response listing the allowed methods
+/// in the `Allow` header, or `None` if the method is allowed. Methods are
+/// compared case-insensitively.
+pub fn require_http_methods(request: &Request, methods: &[&str]) -> Option<Response> {
+	if methods
+		.iter()
+		.any(|method| request.method.as_str().eq_ignore_ascii_case(method))
+	{
+		return None;
+	}
+	Some(Response::new(StatusCode::METHOD_NOT_ALLOWED).with_header("Allow", &methods.join(", ")))
+}
+
+/// Redirects anonymous users to `login_url`
+///
+/// The original path and query are passed in the `redirect_field_name`
+/// query parameter, as with [`login_redirect`]. Returns `None` if the
+/// request's [`AuthState`] is authenticated.
+pub fn login_required(
+	request: &Request,
+	login_url: &str,
+	redirect_field_name: &str,
+) -> Option<Response> {
+	let is_authenticated = request
+		.extensions
+		.get::<AuthState>()
+		.is_some_and(|state| state.is_authenticated());
+	if is_authenticated {
+		None
+	} else {
+		Some(login_redirect(login_url, redirect_field_name, request))
+	}
+}
+
+/// `Cache-Control` directives set by `#[cache_control]`
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_http::Response;
+/// use reinhardt_http::decorators::{CacheControl, patch_cache_control};
+///
+/// let directives = CacheControl {
+///     max_age: Some(300),
+///     public: true,
+///     ..Default::default()
+/// };
+/// let response = patch_cache_control(Response::ok(), &directives);
+/// assert_eq!(response.headers.get("cache-control").unwrap(), "public, max-age=300");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheControl {
+	/// `public`
+	pub public: bool,
+	/// `private`
+	pub private: bool,
+	/// `no-cache`
+	pub no_cache: bool,
+	/// `no-store`
+	pub no_store: bool,
+	/// `no-transform`
+	pub no_transform: bool,
+	/// `must-revalidate`
+	pub must_revalidate: bool,
+	/// `proxy-revalidate`
+	pub proxy_revalidate: bool,
+	/// `immutable`
+	pub immutable: bool,
+	/// `max-age`, in seconds
+	pub max_age: Option<u64>,
+	/// `s-maxage`, in seconds
+	pub s_maxage: Option<u64>,
+	/// `stale-while-revalidate`, in seconds
+	pub stale_while_revalidate: Option<u64>,
+	/// `stale-if-error`, in seconds
+	pub stale_if_error: Option<u64>,
+}
+
+impl CacheControl {
+	/// Directives in header order, as `(name, value)` pairs
+	fn directives(&self) -> Vec<(&'static str, Option<u64>)> {
+		let flags = [
+			(self.public, "public"),
+			(self.private, "private"),
+			(self.no_cache, "no-cache"),
+			(self.no_store, "no-store"),
+			(self.no_transform, "no-transform"),
+			(self.must_revalidate, "must-revalidate"),
+			(self.proxy_revalidate, "proxy-revalidate"),
+			(self.immutable, "immutable"),
+		];
+		let values = [
+			("max-age", self.max_age),
+			("s-maxage", self.s_maxage),
+			("stale-while-revalidate", self.stale_while_revalidate),
+			("stale-if-error", self.stale_if_error),
+		];
+		flags
+			.into_iter()
+			.filter(|(set, _)| *set)
+			.map(|(_, name)| (name, None))
+			.chain(
+				values
+					.into_iter()
+					.filter_map(|(name, value)| value.map(|v| (name, Some(v)))),
+			)
+			.collect()
+	}
+}
+
+/// Merges `directives` into the response's `Cache-Control` header
+///
+/// Directives already on the response are kept unless `directives` sets the
+/// same one. `public` and `private` replace each other, and an existing
+/// `max-age` is only ever lowered, so the most conservative value wins.
+pub fn patch_cache_control(mut response: Response, directives: &CacheControl) -> Response {
+	let mut merged: Vec<(String, Option<String>)> = response
+		.headers
+		.get_all(CACHE_CONTROL)
+		.iter()
+		.filter_map(|value| value.to_str().ok())
+		.flat_map(|value| value.split(','))
+		.filter_map(|directive| {
+			let directive = directive.trim();
+			if directive.is_empty() {
+				return None;
+			}
+			Some(match directive.split_once('=') {
+				Some((name, value)) => (
+					name.trim().to_ascii_lowercase(),
+					Some(value.trim().to_string()),
+				),
+				None => (directive.to_ascii_lowercase(), None),
+			})
+		})
+		.collect();
+
+	for (name, value) in directives.directives() {
+		let mut value = value;
+		if name == "max-age"
+			&& let Some(existing) = merged
+				.iter()
+				.find(|(n, _)| n == "max-age")
+				.and_then(|(_, v)| v.as_deref()?.parse::<u64>().ok())
+		{
+			value = value.map(|v| v.min(existing));
+		}
+		let conflicting = match name {
+			"public" => Some("private"),
+			"private" => Some("public"),
+			_ => None,
+		};
+		merged.retain(|(n, _)| n != name && Some(n.as_str()) != conflicting);
+		merged.push((name.to_string(), value.map(|v| v.to_string())));
+	}
+
+	let header = merged
+		.iter()
+		.map(|(name, value)| match value {
+			Some(value) => format!("{}={}", name, value),
+			None => name.clone(),
+		})
+		.collect::<Vec<_>>()
+		.join(", ");
+	response.headers.remove(CACHE_CONTROL);
+	response.with_header("Cache-Control", &header)
+}
+
+/// Number of calls to each deprecated endpoint since the process started
+static DEPRECATED_CALLS: LazyLock<Mutex<HashMap<&'static str, u64>>> =
+	LazyLock::new(Default::default);
+
+/// Deprecation declared on an endpoint with `#[deprecation]`
+///
+/// Dates use the `YYYY-MM-DD` format and are taken as midnight UTC.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_http::Response;
+/// use reinhardt_http::decorators::{Deprecation, patch_deprecation};
+///
+/// let deprecation = Deprecation {
+///     since: "2024-01-01",
+///     sunset: Some("2024-12-31"),
+///     link: Some("https://api.example.com/v2/articles/"),
+///     endpoint: "app::views::articles",
+///     log: false,
+/// };
+/// let response = patch_deprecation(Response::ok(), &deprecation);
+/// assert_eq!(response.headers.get("deprecation").unwrap(), "@1704067200");
+/// assert_eq!(response.headers.get("sunset").unwrap(), "Tue, 31 Dec 2024 00:00:00 GMT");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deprecation {
+	/// Date the endpoint was deprecated
+	pub since: &'static str,
+	/// Date after which the endpoint may be removed
+	pub sunset: Option<&'static str>,
+	/// URL of the replacement endpoint
+	pub link: Option<&'static str>,
+	/// Name the calls are counted under, usually the handler's path
+	pub endpoint: &'static str,
+	/// Whether each call is logged as a warning
+	pub log: bool,
+}
+
+/// Parses a `YYYY-MM-DD` date
+fn deprecation_date(date: &str) -> Option<NaiveDate> {
+	NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+/// Adds the `Deprecation`, `Sunset` and `Link` headers to the response
+///
+/// `Deprecation` carries the deprecation date as a structured-field date
+/// (RFC 9745), `Sunset` the removal date as an HTTP-date (RFC 8594), and the
+/// replacement is linked with `rel="successor-version"`. Dates that fail to
+/// parse are skipped; the macro rejects them at compile time.
+pub fn patch_deprecation(mut response: Response, deprecation: &Deprecation) -> Response {
+	let midnight = |date: NaiveDate| date.and_time(chrono::NaiveTime::MIN).and_utc();
+	if let Some(since) = deprecation_date(deprecation.since) {
+		let value = format!("@{}", midnight(since).timestamp());
+		response = response.with_header("Deprecation", &value);
+	}
+	if let Some(sunset) = deprecation.sunset.and_then(deprecation_date) {
+		let value = midnight(sunset)
+			.format("%a, %d %b %Y %H:%M:%S GMT")
+			.to_string();
+		response = response.with_header("Sunset", &value);
+	}
+	if let Some(link) = deprecation.link
+		&& let Ok(value) = HeaderValue::from_str(&format!("<{}>; rel=\"successor-version\"", link))
+	{
+		response.headers.append(LINK, value);
+	}
+	response
+}
+
+/// Counts a call to a deprecated endpoint
+///
+/// When [`Deprecation::log`] is set, the call is also logged as a warning
+/// with the request path and user agent, so remaining callers can be found
+/// before the sunset date.
+pub fn record_deprecated_call(deprecation: &Deprecation, request: Option<&Request>) {
+	*DEPRECATED_CALLS
+		.lock()
+		.unwrap_or_else(PoisonError::into_inner)
+		.entry(deprecation.endpoint)
+		.or_default() += 1;
+
+	if deprecation.log {
+		let path = request
+			.map(|request| request.uri.path())
+			.unwrap_or_default();
+		let user_agent = request
+			.and_then(|request| request.headers.get(USER_AGENT))
+			.and_then(|value| value.to_str().ok())
+			.unwrap_or_default();
+		tracing::warn!(
+			endpoint = deprecation.endpoint,
+			since = deprecation.since,
+			sunset = deprecation.sunset.unwrap_or_default(),
+			path,
+			user_agent,
+			"deprecated endpoint called"
+		);
+	}
+}
+
+/// Returns the number of calls to each deprecated endpoint
+///
+/// Keys are [`Deprecation::endpoint`] names; endpoints that have not been
+/// called are absent.
+pub fn deprecated_call_counts() -> HashMap<&'static str, u64> {
+	DEPRECATED_CALLS
+		.lock()
+		.unwrap_or_else(PoisonError::into_inner)
+		.clone()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::redirect::REDIRECT_FIELD_NAME;
+	use hyper::Method;
+	use rstest::rstest;
+
+	fn request(method: Method) -> Request {
+		Request::builder()
+			.method(method)
+			.uri("/articles/?page=2")
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	#[case::allowed(Method::GET, true)]
+	#[case::case_insensitive(Method::POST, true)]
+	#[case::rejected(Method::DELETE, false)]
+	fn test_require_http_methods(#[case] method: Method, #[case] allowed: bool) {
+		// Act
+		let response = require_http_methods(&request(method), &["GET", "post"]);
+
+		// Assert
+		assert_eq!(response.is_none(), allowed);
+		if let Some(response) = response {
+			assert_eq!(response.status, StatusCode::METHOD_NOT_ALLOWED);
+			assert_eq!(response.headers.get("allow").unwrap(), "GET, post");
+		}
+	}
+
+	#[rstest]
+	fn test_login_required_redirects_anonymous() {
+		// Act
+		let response =
+			login_required(&request(Method::GET), "/login/", REDIRECT_FIELD_NAME).unwrap();
+
+		// Assert
+		assert_eq!(
+			response.headers.get("location").unwrap(),
+			"/login/?next=/articles/%3Fpage%3D2"
+		);
+	}
+
+	#[rstest]
+	fn test_login_required_allows_authenticated() {
+		// Arrange
+		let request = request(Method::GET);
+		request
+			.extensions
+			.insert(AuthState::authenticated("1", false, true));
+
+		// Act
+		let response = login_required(&request, "/login/", REDIRECT_FIELD_NAME);
+
+		// Assert
+		assert!(response.is_none());
+	}
+
+	#[rstest]
+	#[case::empty(None, "no-cache, max-age=60")]
+	#[case::keeps_other_directives(Some("no-transform"), "no-transform, no-cache, max-age=60")]
+	#[case::lowers_max_age(Some("max-age=600"), "no-cache, max-age=60")]
+	#[case::keeps_lower_max_age(Some("max-age=30"), "no-cache, max-age=30")]
+	fn test_patch_cache_control(#[case] existing: Option<&str>, #[case] expected: &str) {
+		// Arrange
+		let mut response = Response::ok();
+		if let Some(existing) = existing {
+			response = response.with_header("Cache-Control", existing);
+		}
+		let directives = CacheControl {
+			no_cache: true,
+			max_age: Some(60),
+			..Default::default()
+		};
+
+		// Act
+		let response = patch_cache_control(response, &directives);
+
+		// Assert
+		assert_eq!(response.headers.get("cache-control").unwrap(), expected);
+	}
+
+	#[rstest]
+	fn test_patch_cache_control_public_replaces_private() {
+		// Arrange
+		let response = Response::ok().with_header("Cache-Control", "private, max-age=10");
+		let directives = CacheControl {
+			public: true,
+			..Default::default()
+		};
+
+		// Act
+		let response = patch_cache_control(response, &directives);
+
+		// Assert
+		assert_eq!(
+			response.headers.get("cache-control").unwrap(),
+			"max-age=10, public"
+		);
+	}
+
+	#[rstest]
+	fn test_patch_deprecation_sets_headers() {
+		// Arrange
+		let response = Response::ok().with_header("Link", "</docs/>; rel=\"help\"");
+		let deprecation = Deprecation {
+			since: "2024-01-01",
+			sunset: Some("2024-12-31"),
+			link: Some("/api/v2/articles/"),
+			endpoint: "tests::patch_deprecation",
+			log: false,
+		};
+
+		// Act
+		let response = patch_deprecation(response, &deprecation);
+
+		// Assert
+		assert_eq!(response.headers.get("deprecation").unwrap(), "@1704067200");
+		assert_eq!(
+			response.headers.get("sunset").unwrap(),
+			"Tue, 31 Dec 2024 00:00:00 GMT"
+		);
+		let links: Vec<_> = response.headers.get_all("link").iter().collect();
+		assert_eq!(
+			links,
+			[
+				"</docs/>; rel=\"help\"",
+				"</api/v2/articles/>; rel=\"successor-version\""
+			]
+		);
+	}
+
+	#[rstest]
+	fn test_patch_deprecation_without_sunset() {
+		// Arrange
+		let deprecation = Deprecation {
+			since: "2024-01-01",
+			sunset: None,
+			link: None,
+			endpoint: "tests::without_sunset",
+			log: false,
+		};
+
+		// Act
+		let response = patch_deprecation(Response::ok(), &deprecation);
+
+		// Assert
+		assert!(response.headers.contains_key("deprecation"));
+		assert!(!response.headers.contains_key("sunset"));
+		assert!(!response.headers.contains_key("link"));
+	}
+
+	#[rstest]
+	fn test_record_deprecated_call_counts_calls() {
+		// Arrange
+		let deprecation = Deprecation {
+			since: "2024-01-01",
+			sunset: None,
+			link: None,
+			endpoint: "tests::record_deprecated_call",
+			log: true,
+		};
+		let request = request(Method::GET);
+
+		// Act
+		record_deprecated_call(&deprecation, Some(&request));
+		record_deprecated_call(&deprecation, None);
+
+		// Assert
+		assert_eq!(
+			deprecated_call_counts().get("tests::record_deprecated_call"),
+			Some(&2)
+		);
+	}
+
+	#[rstest]
+	fn test_view_response_map_skips_errors() {
+		// Arrange
+		let result: std::result::Result<Response, &str> = Err("boom");
+
+		// Act
+		let mapped = result.map_response(|response| response.with_header("X-Test", "1"));
+
+		// Assert
+		assert_eq!(mapped.unwrap_err(), "boom");
+	}
+}
@@ -0,0 +1,342 @@
+//! Registry of exception handlers mapping errors to responses.
+//!
+//! Handlers are registered for specific error types, which are found in the
+//! cause chain of [`Error::Other`], or for whole [`ErrorKind`]s. Errors no
+//! handler claims are rendered as `application/problem+json`, or as an HTML
+//! traceback page in debug mode.
+//!
+//! A registry installed with [`ExceptionHandlers::install`] is used by the
+//! `From<Error>` conversion of [`Response`], so it applies to every error a
+//! handler or middleware returns. Routers can also carry a registry of their
+//! own, which takes precedence for the routes they serve.
+//!
+//! # Examples
+//!
+//! ```
+//! use reinhardt_core::exception::ErrorKind;
+//! use reinhardt_core::validators::ValidationErrors;
+//! use reinhardt_http::exception_handlers::ExceptionHandlers;
+//! use reinhardt_http::Response;
+//!
+//! ExceptionHandlers::new()
+//!     .debug(cfg!(debug_assertions))
+//!     .register::<ValidationErrors, _>(Response::validation_problem)
+//!     .register_kind(ErrorKind::Authorization, |_| {
+//!         Response::forbidden().with_body("Permission denied")
+//!     })
+//!     .install();
+//! ```
+
+use std::error::Error as StdError;
+use std::fmt::Write as _;
+use std::sync::{Arc, PoisonError, RwLock};
+
+use bytes::Bytes;
+use hyper::StatusCode;
+use hyper::header::{CONTENT_TYPE, HeaderValue};
+use reinhardt_core::exception::{Error, ErrorKind};
+use reinhardt_core::security::escape_html;
+use reinhardt_core::validators::PROBLEM_JSON_CONTENT_TYPE;
+
+use crate::response::{Response, safe_client_error_detail};
+
+type Converter = Arc<dyn Fn(&Error) -> Option<Response> + Send + Sync>;
+
+static GLOBAL_HANDLERS: RwLock<Option<Arc<ExceptionHandlers>>> = RwLock::new(None);
+
+/// Converters from errors to responses
+#[derive(Clone, Default)]
+pub struct ExceptionHandlers {
+	converters: Vec<Converter>,
+	debug: bool,
+}
+
+impl std::fmt::Debug for ExceptionHandlers {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("ExceptionHandlers")
+			.field("converters", &self.converters.len())
+			.field("debug", &self.debug)
+			.finish()
+	}
+}
+
+impl ExceptionHandlers {
+	/// Create an empty registry in production mode
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Render unhandled errors as HTML tracebacks instead of problem+json
+	///
+	/// WARNING: Only use in development environments. Tracebacks expose
+	/// internal error details.
+	pub fn debug(mut self, debug: bool) -> Self {
+		self.debug = debug;
+		self
+	}
+
+	/// Whether unhandled errors are rendered as tracebacks
+	pub fn is_debug(&self) -> bool {
+		self.debug
+	}
+
+	/// Convert errors of type `E` with `handler`
+	///
+	/// `E` is looked up in the cause chain of [`Error::Other`], so it is found
+	/// whether it was returned directly or wrapped with context. Handlers
+	/// registered later take precedence.
+	pub fn register<E, F>(mut self, handler: F) -> Self
+	where
+		E: StdError + Send + Sync + 'static,
+		F: Fn(&E) -> Response + Send + Sync + 'static,
+	{
+		self.converters.push(Arc::new(move |error| match error {
+			Error::Other(error) => error
+				.chain()
+				.find_map(|cause| cause.downcast_ref::<E>())
+				.map(&handler),
+			_ => None,
+		}));
+		self
+	}
+
+	/// Convert errors of `kind` with `handler`
+	///
+	/// Handlers registered later take precedence.
+	pub fn register_kind<F>(mut self, kind: ErrorKind, handler: F) -> Self
+	where
+		F: Fn(&Error) -> Response + Send + Sync + 'static,
+	{
+		self.converters.push(Arc::new(move |error| {
+			(error.kind() == kind).then(|| handler(error))
+		}));
+		self
+	}
+
+	/// Convert `error` with the most recently registered matching handler
+	pub fn handle(&self, error: &Error) -> Option<Response> {
+		self.converters
+			.iter()
+			.rev()
+			.find_map(|converter| converter(error))
+	}
+
+	/// Convert `error` to a response
+	///
+	/// Falls back to problem+json, or a traceback page in debug mode, when no
+	/// handler matches.
+	pub fn render(&self, error: Error) -> Response {
+		if let Some(response) = self.handle(&error) {
+			return response;
+		}
+		let status =
+			StatusCode::from_u16(error.status_code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+		tracing::error!(status = status.as_u16(), error = %error, "Request error");
+		if self.debug {
+			traceback_response(status, &error)
+		} else {
+			problem_response(status, &error)
+		}
+	}
+
+	/// Install as the global registry, replacing any previous one
+	pub fn install(self) {
+		*GLOBAL_HANDLERS
+			.write()
+			.unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(self));
+	}
+}
+
+/// The registry installed with [`ExceptionHandlers::install`], if any
+pub fn global_exception_handlers() -> Option<Arc<ExceptionHandlers>> {
+	GLOBAL_HANDLERS
+		.read()
+		.unwrap_or_else(PoisonError::into_inner)
+		.clone()
+}
+
+fn problem_response(status: StatusCode, error: &Error) -> Response {
+	let mut problem = serde_json::json!({
+		"type": "about:blank",
+		"title": status.canonical_reason().unwrap_or("Error"),
+		"status": status.as_u16(),
+	});
+	if status.is_client_error() {
+		if let Some(detail) = safe_client_error_detail(error) {
+			problem["detail"] = detail.into();
+		}
+		if let Error::FieldValidation(errors) = error {
+			problem["errors"] = serde_json::json!(errors);
+		}
+	}
+	let mut response = Response::new(status);
+	response.body = Bytes::from(problem.to_string());
+	response.headers.insert(
+		CONTENT_TYPE,
+		HeaderValue::from_static(PROBLEM_JSON_CONTENT_TYPE),
+	);
+	response
+}
+
+fn traceback_response(status: StatusCode, error: &Error) -> Response {
+	let title = format!(
+		"{} {}",
+		status.as_u16(),
+		status.canonical_reason().unwrap_or("Error")
+	);
+	let mut html = format!(
+		"<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{title}</title></head><body>\
+		 <h1>{title}</h1><p><strong>{kind:?}</strong>: {message}</p>",
+		kind = error.kind(),
+		message = escape_html(&error.to_string()),
+	);
+	if let Error::Other(error) = error {
+		let causes: Vec<_> = error.chain().skip(1).collect();
+		if !causes.is_empty() {
+			html.push_str("<h2>Caused by</h2><ol>");
+			for cause in causes {
+				let _ = write!(html, "<li>{}</li>", escape_html(&cause.to_string()));
+			}
+			html.push_str("</ol>");
+		}
+		let backtrace = error.backtrace();
+		if backtrace.status() == std::backtrace::BacktraceStatus::Captured {
+			let _ = write!(
+				html,
+				"<h2>Backtrace</h2><pre>{}</pre>",
+				escape_html(&backtrace.to_string())
+			);
+		}
+	}
+	let _ = write!(
+		html,
+		"<h2>Debug</h2><pre>{}</pre></body></html>",
+		escape_html(&format!("{error:#?}"))
+	);
+	Response::new(status).html(html)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reinhardt_core::validators::{ValidationError, ValidationErrors};
+	use rstest::rstest;
+
+	#[derive(Debug, thiserror::Error)]
+	enum OrmError {
+		#[error("object not found")]
+		NotFound,
+		#[error("connection lost")]
+		Connection,
+	}
+
+	fn handlers() -> ExceptionHandlers {
+		ExceptionHandlers::new().register::<OrmError, _>(|error| match error {
+			OrmError::NotFound => Response::not_found(),
+			OrmError::Connection => Response::service_unavailable(),
+		})
+	}
+
+	#[rstest]
+	#[case(OrmError::NotFound, StatusCode::NOT_FOUND)]
+	#[case(OrmError::Connection, StatusCode::SERVICE_UNAVAILABLE)]
+	fn test_registered_type_is_converted(
+		#[case] orm_error: OrmError,
+		#[case] expected: StatusCode,
+	) {
+		// Arrange
+		let error = Error::Other(orm_error.into());
+
+		// Act
+		let response = handlers().render(error);
+
+		// Assert
+		assert_eq!(response.status, expected);
+	}
+
+	#[rstest]
+	fn test_registered_type_is_found_behind_context() {
+		// Arrange
+		let error =
+			Error::Other(anyhow::Error::new(OrmError::NotFound).context("loading order 42"));
+
+		// Act
+		let response = handlers().handle(&error);
+
+		// Assert
+		assert_eq!(response.unwrap().status, StatusCode::NOT_FOUND);
+	}
+
+	#[rstest]
+	fn test_validation_errors_handler() {
+		// Arrange
+		let mut errors = ValidationErrors::new();
+		errors.add("email", ValidationError::InvalidEmail("bad".to_string()));
+		let handlers =
+			ExceptionHandlers::new().register::<ValidationErrors, _>(Response::validation_problem);
+
+		// Act
+		let response = handlers.render(Error::Other(errors.into()));
+
+		// Assert
+		assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+	}
+
+	#[rstest]
+	fn test_later_registrations_take_precedence() {
+		// Arrange
+		let handlers = ExceptionHandlers::new()
+			.register_kind(ErrorKind::Authorization, |_| Response::not_found())
+			.register_kind(ErrorKind::Authorization, |_| Response::forbidden());
+
+		// Act
+		let response = handlers.render(Error::Authorization("staff only".to_string()));
+
+		// Assert
+		assert_eq!(response.status, StatusCode::FORBIDDEN);
+	}
+
+	#[rstest]
+	fn test_unhandled_error_renders_problem_json() {
+		// Act
+		let response = handlers().render(Error::Conflict("slug taken".to_string()));
+
+		// Assert
+		let problem: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+		assert_eq!(response.status, StatusCode::CONFLICT);
+		assert_eq!(
+			response.headers.get(CONTENT_TYPE).unwrap(),
+			PROBLEM_JSON_CONTENT_TYPE
+		);
+		assert_eq!(problem["title"], "Conflict");
+		assert_eq!(problem["status"], 409);
+		assert_eq!(problem["detail"], "slug taken");
+	}
+
+	#[rstest]
+	fn test_problem_json_hides_server_error_details() {
+		// Act
+		let response = handlers().render(Error::Database("password=hunter2".to_string()));
+
+		// Assert
+		let body = String::from_utf8(response.body.to_vec()).unwrap();
+		assert_eq!(response.status, StatusCode::INTERNAL_SERVER_ERROR);
+		assert!(!body.contains("hunter2"));
+	}
+
+	#[rstest]
+	fn test_debug_mode_renders_traceback() {
+		// Arrange
+		let error =
+			Error::Other(anyhow::Error::new(OrmError::Connection).context("<loading> order"));
+
+		// Act
+		let response = ExceptionHandlers::new().debug(true).render(error);
+
+		// Assert
+		let body = String::from_utf8(response.body.to_vec()).unwrap();
+		assert_eq!(response.status, StatusCode::INTERNAL_SERVER_ERROR);
+		assert!(body.contains("&lt;loading&gt; order"));
+		assert!(body.contains("<h2>Caused by</h2><ol><li>connection lost</li></ol>"));
+	}
+}
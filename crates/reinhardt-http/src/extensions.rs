@@ -22,6 +22,11 @@ pub struct IsAdmin(pub bool);
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IsActive(pub bool);
 
+/// Marker inserted by the router when the matched handler is `#[csrf_exempt]`.
+/// CSRF middleware skips validation for requests carrying this marker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsrfExempt;
+
 /// Type-safe extension storage
 ///
 /// # Clone semantics
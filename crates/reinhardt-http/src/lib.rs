@@ -31,12 +31,16 @@
 //! Key modules in this crate:
 //!
 //! - [`request`]: Typed HTTP request wrapper with builder pattern and trusted proxy support
+//! - [`body`]: Buffered or streamed request bodies with size-limited readers
 //! - [`response`]: HTTP response with helpers for JSON, streaming, and error responses
 //! - [`middleware`]: Middleware trait and composition chain for request processing
 //! - [`auth_state`]: Authentication state extensions stored in request context
+//! - [`cookies`]: Typed cookies and cookie jars with signed and encrypted values
+//! - [`redirect`]: Open-redirect safe `?next=` handling and login redirects
 //! - [`upload`]: File upload handling (in-memory and temporary file backends)
 //! - [`chunked_upload`]: Resumable chunked upload session management
 //! - [`extensions`]: Typed request extension storage
+//! - [`sse`]: Server-Sent Events encoding and `text/event-stream` responses
 //!
 //! ## Feature Flags
 //!
@@ -44,6 +48,7 @@
 //! |---------|---------|-------------|
 //! | `parsers` | enabled | Request body parsing (JSON, Form, Multipart) |
 //! | `messages` | disabled | Flash message middleware for session-based notifications |
+//! | `cookie-encryption` | disabled | Encrypted cookies through `CookieJar::private` |
 //! | `full` | disabled | Enables all optional features |
 //!
 //! ## Request Construction
@@ -82,8 +87,18 @@
 
 /// Authentication state tracking for requests.
 pub mod auth_state;
+/// Buffered and streamed request bodies.
+pub mod body;
 /// Chunked file upload handling with progress tracking.
 pub mod chunked_upload;
+/// Client IP resolution behind trusted reverse proxies.
+pub mod client_ip;
+/// Typed cookies, cookie jars, and signed or encrypted cookie values.
+pub mod cookies;
+/// Runtime helpers for the view decorator macros.
+pub mod decorators;
+/// Exception handler registry mapping errors to responses.
+pub mod exception_handlers;
 /// Request extension storage for passing data between middleware.
 pub mod extensions;
 /// Flash messages middleware for one-time notifications.
@@ -93,10 +108,16 @@ pub mod messages_middleware;
 pub mod middleware;
 /// Ordered path parameter storage (`PathParams`).
 pub mod path_params;
+/// Open-redirect safe `?next=` validation and login redirects.
+pub mod redirect;
 /// HTTP request type and builder.
 pub mod request;
 /// HTTP response type and builder.
 pub mod response;
+/// Current site of multi-site deployments and absolute URL building.
+pub mod site;
+/// Server-Sent Events responses.
+pub mod sse;
 /// File upload handling and validation.
 pub mod upload;
 
@@ -104,10 +125,13 @@ pub mod upload;
 pub mod response_cookies;
 
 pub use auth_state::AuthState;
+pub use body::{Body, BodyError, BodyStream};
 pub use chunked_upload::{
 	ChunkedUploadError, ChunkedUploadManager, ChunkedUploadSession, UploadProgress,
 };
-pub use extensions::{Extensions, IsActive, IsAdmin, IsAuthenticated};
+pub use client_ip::{ClientIp, ClientIpResolver, InvalidProxyError};
+pub use exception_handlers::ExceptionHandlers;
+pub use extensions::{CsrfExempt, Extensions, IsActive, IsAdmin, IsAuthenticated};
 #[cfg(feature = "messages")]
 pub use messages_middleware::MessagesMiddleware;
 pub use middleware::{
@@ -117,6 +141,8 @@ pub use path_params::PathParams;
 pub use request::{Request, RequestBuilder, TrustedProxies};
 pub use response::{Response, SafeErrorResponse, StreamBody, StreamingResponse};
 pub use response_cookies::{ResponseCookies, SharedResponseCookies};
+pub use site::Site;
+pub use sse::{SseEvent, SseResponse};
 pub use upload::{FileUploadError, FileUploadHandler, MemoryFileUpload, TemporaryFileUpload};
 
 // Re-export error types from reinhardt-exception for consistency across the framework
@@ -0,0 +1,370 @@
+//! Safe redirect helpers
+//!
+//! Authentication flows commonly send users back to a `?next=` URL after
+//! login. Redirecting to that value unchecked is an open redirect: an attacker
+//! can craft a login link that lands the victim on a phishing site. The helpers
+//! here validate such targets and build redirects that preserve the original
+//! request's path and query.
+//!
+//! ## Example
+//!
+//! ```
+//! use reinhardt_http::redirect::{RedirectPolicy, is_safe_url};
+//!
+//! assert!(is_safe_url("/dashboard?tab=2", &[], false));
+//! assert!(!is_safe_url("https://evil.example/", &[], false));
+//! assert!(!is_safe_url("//evil.example/", &[], false));
+//!
+//! let policy = RedirectPolicy::new().with_allowed_host("app.example.com");
+//! assert!(policy.is_safe("https://app.example.com/home"));
+//! ```
+
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+
+use crate::{Request, Response};
+
+/// Default query parameter carrying the post-login redirect target
+pub const REDIRECT_FIELD_NAME: &str = "next";
+
+/// Default login page used by login redirects
+pub const DEFAULT_LOGIN_URL: &str = "/accounts/login/";
+
+/// Characters escaped in redirect query values; `/` stays readable
+const QUERY_VALUE: &AsciiSet = &NON_ALPHANUMERIC
+	.remove(b'/')
+	.remove(b'-')
+	.remove(b'_')
+	.remove(b'.')
+	.remove(b'~');
+
+/// Returns `true` if `url` is safe to redirect to
+///
+/// Relative URLs are safe. Absolute and scheme-relative URLs are safe only if
+/// their host (including any port) is in `allowed_hosts`, compared
+/// case-insensitively, and their scheme is `http` or `https`. With
+/// `require_https`, only `https` is accepted. Control characters and
+/// backslashes, which browsers normalise in surprising ways, are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_http::redirect::is_safe_url;
+///
+/// assert!(is_safe_url("/accounts/profile/", &[], false));
+/// assert!(is_safe_url("https://example.com/", &["example.com"], true));
+/// assert!(!is_safe_url("http://example.com/", &["example.com"], true));
+/// assert!(!is_safe_url("javascript:alert(1)", &[], false));
+/// assert!(!is_safe_url("/\\evil.example", &[], false));
+/// ```
+pub fn is_safe_url(url: &str, allowed_hosts: &[&str], require_https: bool) -> bool {
+	let url = url.trim();
+	if url.is_empty() {
+		return false;
+	}
+	// Browsers treat backslashes as forward slashes, so check both readings
+	is_safe_url_inner(url, allowed_hosts, require_https)
+		&& is_safe_url_inner(&url.replace('\\', "/"), allowed_hosts, require_https)
+}
+
+fn is_safe_url_inner(url: &str, allowed_hosts: &[&str], require_https: bool) -> bool {
+	// Browsers strip control characters, turning e.g. "/\t/evil" into "//evil"
+	if url.starts_with("///") || url.chars().any(char::is_control) {
+		return false;
+	}
+
+	let (scheme, rest) = split_scheme(url);
+	let netloc = rest
+		.strip_prefix("//")
+		.map(|rest| rest.split(['/', '?', '#']).next().unwrap_or_default());
+
+	match netloc {
+		Some(netloc) => {
+			if !allowed_hosts
+				.iter()
+				.any(|host| host.eq_ignore_ascii_case(netloc))
+			{
+				return false;
+			}
+		}
+		// A scheme without a host, e.g. "http:evil.example" or "javascript:..."
+		None if scheme.is_some() => return false,
+		None => {}
+	}
+
+	match scheme {
+		None => true,
+		Some(scheme) => {
+			scheme.eq_ignore_ascii_case("https")
+				|| (!require_https && scheme.eq_ignore_ascii_case("http"))
+		}
+	}
+}
+
+/// Splits an RFC 3986 scheme off `url`
+fn split_scheme(url: &str) -> (Option<&str>, &str) {
+	let Some((scheme, rest)) = url.split_once(':') else {
+		return (None, url);
+	};
+	let is_scheme = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+		&& scheme
+			.chars()
+			.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+	if is_scheme {
+		(Some(scheme), rest)
+	} else {
+		(None, url)
+	}
+}
+
+/// Appends a percent-encoded query parameter to `url`
+///
+/// Existing query parameters and any fragment are preserved.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_http::redirect::with_query_param;
+///
+/// assert_eq!(
+///     with_query_param("/login/", "next", "/orders?page=2"),
+///     "/login/?next=/orders%3Fpage%3D2"
+/// );
+/// assert_eq!(
+///     with_query_param("/login/?lang=en#form", "next", "/"),
+///     "/login/?lang=en&next=/#form"
+/// );
+/// ```
+pub fn with_query_param(url: &str, name: &str, value: &str) -> String {
+	let (base, fragment) = match url.split_once('#') {
+		Some((base, fragment)) => (base, Some(fragment)),
+		None => (url, None),
+	};
+	let separator = match base.find('?') {
+		None => "?",
+		Some(index) if index + 1 == base.len() || base.ends_with('&') => "",
+		Some(_) => "&",
+	};
+	let mut result = format!(
+		"{base}{separator}{}={}",
+		utf8_percent_encode(name, QUERY_VALUE),
+		utf8_percent_encode(value, QUERY_VALUE)
+	);
+	if let Some(fragment) = fragment {
+		result.push('#');
+		result.push_str(fragment);
+	}
+	result
+}
+
+/// Redirects to `login_url`, carrying the current path and query in `field`
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_http::Request;
+/// use reinhardt_http::redirect::{REDIRECT_FIELD_NAME, login_redirect};
+/// use hyper::Method;
+///
+/// let request = Request::builder()
+///     .method(Method::GET)
+///     .uri("/orders?page=2")
+///     .build()
+///     .unwrap();
+///
+/// let response = login_redirect("/accounts/login/", REDIRECT_FIELD_NAME, &request);
+/// assert_eq!(
+///     response.headers.get("location").unwrap(),
+///     "/accounts/login/?next=/orders%3Fpage%3D2"
+/// );
+/// ```
+pub fn login_redirect(login_url: &str, field: &str, request: &Request) -> Response {
+	Response::temporary_redirect(with_query_param(login_url, field, &request.full_path()))
+}
+
+/// Hosts and schemes a redirect target may point to
+///
+/// The default policy only accepts relative URLs. Use
+/// [`RedirectPolicy::for_request`] to also accept absolute URLs on the
+/// request's own host.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_http::Request;
+/// use reinhardt_http::redirect::{REDIRECT_FIELD_NAME, RedirectPolicy};
+/// use hyper::Method;
+///
+/// let request = Request::builder()
+///     .method(Method::POST)
+///     .uri("/accounts/login/?next=https://evil.example/")
+///     .build()
+///     .unwrap();
+///
+/// let policy = RedirectPolicy::for_request(&request);
+/// assert_eq!(policy.next_url(&request, REDIRECT_FIELD_NAME), None);
+///
+/// let response = policy.redirect_next(&request, REDIRECT_FIELD_NAME, "/");
+/// assert_eq!(response.headers.get("location").unwrap(), "/");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RedirectPolicy {
+	allowed_hosts: Vec<String>,
+	require_https: bool,
+}
+
+impl RedirectPolicy {
+	/// Creates a policy accepting only relative URLs
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Creates a policy accepting the request's `Host`, requiring HTTPS when
+	/// the request itself is secure
+	pub fn for_request(request: &Request) -> Self {
+		let host = request
+			.headers
+			.get(hyper::header::HOST)
+			.and_then(|value| value.to_str().ok());
+		Self {
+			allowed_hosts: host.map(str::to_string).into_iter().collect(),
+			require_https: request.is_secure(),
+		}
+	}
+
+	/// Accepts absolute URLs on `host` (including the port, if any)
+	pub fn with_allowed_host(mut self, host: impl Into<String>) -> Self {
+		self.allowed_hosts.push(host.into());
+		self
+	}
+
+	/// Rejects absolute `http` URLs
+	pub fn with_require_https(mut self, require_https: bool) -> Self {
+		self.require_https = require_https;
+		self
+	}
+
+	/// Returns `true` if `url` satisfies this policy
+	pub fn is_safe(&self, url: &str) -> bool {
+		let hosts: Vec<&str> = self.allowed_hosts.iter().map(String::as_str).collect();
+		is_safe_url(url, &hosts, self.require_https)
+	}
+
+	/// Reads the redirect target from the `field` query parameter
+	///
+	/// Returns `None` if the parameter is missing or unsafe.
+	pub fn next_url(&self, request: &Request, field: &str) -> Option<String> {
+		request
+			.decoded_query_params()
+			.remove(field)
+			.filter(|url| self.is_safe(url))
+	}
+
+	/// Redirects to the safe `field` target, or to `fallback`
+	pub fn redirect_next(&self, request: &Request, field: &str, fallback: &str) -> Response {
+		let target = self
+			.next_url(request, field)
+			.unwrap_or_else(|| fallback.to_string());
+		Response::temporary_redirect(target)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use hyper::Method;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case::relative_path("/dashboard", true)]
+	#[case::relative_with_query("/search?q=a&page=2", true)]
+	#[case::bare_path("dashboard", true)]
+	#[case::allowed_host("https://example.com/home", true)]
+	#[case::allowed_host_case("https://EXAMPLE.com/home", true)]
+	#[case::scheme_relative_allowed("//example.com/home", true)]
+	#[case::foreign_host("https://evil.example/", false)]
+	#[case::scheme_relative_foreign("//evil.example/", false)]
+	#[case::backslash("/\\evil.example", false)]
+	#[case::backslashes("\\\\evil.example", false)]
+	#[case::triple_slash("///evil.example", false)]
+	#[case::control_char("/\t/evil.example", false)]
+	#[case::javascript("javascript:alert(1)", false)]
+	#[case::scheme_without_host("http:evil.example", false)]
+	#[case::userinfo("https://example.com@evil.example/", false)]
+	#[case::ftp("ftp://example.com/", false)]
+	#[case::empty("", false)]
+	fn test_is_safe_url(#[case] url: &str, #[case] expected: bool) {
+		// Act
+		let result = is_safe_url(url, &["example.com"], false);
+
+		// Assert
+		assert_eq!(result, expected, "{url}");
+	}
+
+	#[rstest]
+	fn test_is_safe_url_require_https() {
+		// Act & Assert
+		assert!(!is_safe_url("http://example.com/", &["example.com"], true));
+		assert!(is_safe_url("https://example.com/", &["example.com"], true));
+		assert!(is_safe_url("/relative", &[], true));
+	}
+
+	#[rstest]
+	#[case::no_query("/login/", "/login/?next=/a%20b")]
+	#[case::existing_query("/login/?lang=en", "/login/?lang=en&next=/a%20b")]
+	#[case::trailing_question_mark("/login/?", "/login/?next=/a%20b")]
+	#[case::fragment("/login/#top", "/login/?next=/a%20b#top")]
+	fn test_with_query_param(#[case] url: &str, #[case] expected: &str) {
+		// Act
+		let result = with_query_param(url, "next", "/a b");
+
+		// Assert
+		assert_eq!(result, expected);
+	}
+
+	#[rstest]
+	fn test_next_url_round_trips_login_redirect() {
+		// Arrange
+		let original = Request::builder()
+			.method(Method::GET)
+			.uri("/orders?page=2&sort=date")
+			.build()
+			.unwrap();
+		let redirect = login_redirect("/login/", REDIRECT_FIELD_NAME, &original);
+		let location = redirect.headers.get("location").unwrap().to_str().unwrap();
+		let login_request = Request::builder()
+			.method(Method::POST)
+			.uri(location)
+			.build()
+			.unwrap();
+
+		// Act
+		let next = RedirectPolicy::new().next_url(&login_request, REDIRECT_FIELD_NAME);
+
+		// Assert
+		assert_eq!(next.as_deref(), Some("/orders?page=2&sort=date"));
+	}
+
+	#[rstest]
+	#[case::own_host("https://app.example.com/home", "https://app.example.com/home")]
+	#[case::foreign_host("https://evil.example/", "/")]
+	#[case::insecure_downgrade("http://app.example.com/home", "/")]
+	fn test_redirect_next_uses_request_host(#[case] next: &str, #[case] expected: &str) {
+		// Arrange
+		let mut headers = hyper::HeaderMap::new();
+		headers.insert(hyper::header::HOST, "app.example.com".parse().unwrap());
+		let request = Request::builder()
+			.method(Method::POST)
+			.uri(with_query_param("/login/", REDIRECT_FIELD_NAME, next))
+			.headers(headers)
+			.secure(true)
+			.build()
+			.unwrap();
+
+		// Act
+		let response =
+			RedirectPolicy::for_request(&request).redirect_next(&request, REDIRECT_FIELD_NAME, "/");
+
+		// Assert
+		assert_eq!(response.headers.get("location").unwrap(), expected);
+	}
+}
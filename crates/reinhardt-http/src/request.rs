@@ -2,6 +2,7 @@ mod body;
 mod methods;
 mod params;
 
+use crate::body::Body;
 use crate::extensions::Extensions;
 use crate::path_params::PathParams;
 use bytes::Bytes;
@@ -79,6 +80,10 @@ pub struct Request {
 	/// Cached parsed data (lazy parsing)
 	#[cfg(feature = "parsers")]
 	parsed_data: Mutex<Option<ParsedData>>,
+	/// Streamed body, present until taken (see [`Request::take_body`])
+	body_stream: Mutex<Option<Body>>,
+	/// Whether the request was built with a streamed body
+	streamed: bool,
 	/// Whether the body has been consumed
 	body_consumed: AtomicBool,
 	/// Extensions for storing arbitrary typed data
@@ -111,6 +116,7 @@ pub struct RequestBuilder {
 	version: Version,
 	headers: HeaderMap,
 	body: Bytes,
+	body_stream: Option<Body>,
 	is_secure: bool,
 	remote_addr: Option<SocketAddr>,
 	path_params: PathParams,
@@ -130,6 +136,7 @@ impl Default for RequestBuilder {
 			version: Version::HTTP_11,
 			headers: HeaderMap::new(),
 			body: Bytes::new(),
+			body_stream: None,
 			is_secure: false,
 			remote_addr: None,
 			path_params: PathParams::new(),
@@ -307,6 +314,41 @@ impl RequestBuilder {
 	/// ```
 	pub fn body(mut self, body: Bytes) -> Self {
 		self.body = body;
+		self.body_stream = None;
+		self
+	}
+
+	/// Set a streamed request body.
+	///
+	/// The chunks are not read until the handler asks for them, through
+	/// [`Request::take_body`], [`Request::bytes_limited`],
+	/// [`Request::json_limited`] or the configured parsers. [`Request::body`]
+	/// stays empty for streamed requests.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bytes::Bytes;
+	/// use futures::stream;
+	/// use reinhardt_http::Request;
+	/// use hyper::Method;
+	///
+	/// let request = Request::builder()
+	///     .method(Method::PUT)
+	///     .uri("/uploads/video.mp4")
+	///     .body_stream(stream::iter(vec![Ok(Bytes::from("chunk"))]))
+	///     .build()
+	///     .unwrap();
+	///
+	/// assert!(request.is_body_streamed());
+	/// assert!(request.body().is_empty());
+	/// ```
+	pub fn body_stream<S>(mut self, stream: S) -> Self
+	where
+		S: futures::Stream<Item = std::io::Result<Bytes>> + Send + 'static,
+	{
+		self.body = Bytes::new();
+		self.body_stream = Some(Body::from_stream(stream));
 		self
 	}
 
@@ -452,6 +494,8 @@ impl RequestBuilder {
 			version: self.version,
 			headers: self.headers,
 			body: self.body,
+			streamed: self.body_stream.is_some(),
+			body_stream: Mutex::new(self.body_stream),
 			path_params: self.path_params,
 			query_params,
 			is_secure: self.is_secure,
@@ -800,6 +844,55 @@ impl Request {
 		self.extensions.insert(proxies);
 	}
 
+	/// Resolve the client IP address of the request
+	///
+	/// Uses the [`ClientIpResolver`](crate::ClientIpResolver) installed with
+	/// [`set_client_ip_resolver`](Self::set_client_ip_resolver), falling back
+	/// to [`get_client_ip`](Self::get_client_ip) when none is installed. The
+	/// result is cached as [`ClientIp`](crate::ClientIp) in the request
+	/// extensions so every consumer sees the same address.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::{ClientIpResolver, Request};
+	/// use hyper::{HeaderMap, Method};
+	/// use std::net::SocketAddr;
+	///
+	/// let mut headers = HeaderMap::new();
+	/// headers.insert("forwarded", "for=203.0.113.7;proto=https".parse().unwrap());
+	/// let request = Request::builder()
+	///     .method(Method::GET)
+	///     .uri("/")
+	///     .headers(headers)
+	///     .remote_addr(SocketAddr::new("10.0.0.1".parse().unwrap(), 443))
+	///     .build()
+	///     .unwrap();
+	/// request.set_client_ip_resolver(ClientIpResolver::from_cidrs(&["10.0.0.0/8"]).unwrap());
+	///
+	/// assert_eq!(request.client_ip(), Some("203.0.113.7".parse().unwrap()));
+	/// ```
+	pub fn client_ip(&self) -> Option<IpAddr> {
+		if let Some(crate::ClientIp(ip)) = self.extensions.get::<crate::ClientIp>() {
+			return Some(ip);
+		}
+
+		let ip = match self.extensions.get::<crate::ClientIpResolver>() {
+			Some(resolver) => resolver.resolve(self),
+			None => self.get_client_ip(),
+		}?;
+		self.extensions.insert(crate::ClientIp(ip));
+		Some(ip)
+	}
+
+	/// Install the resolver used by [`client_ip`](Self::client_ip)
+	///
+	/// Clears a previously cached client IP.
+	pub fn set_client_ip_resolver(&self, resolver: crate::ClientIpResolver) {
+		self.extensions.remove::<crate::ClientIp>();
+		self.extensions.insert(resolver);
+	}
+
 	/// Validate Content-Type header
 	///
 	/// Checks if the Content-Type header matches the expected value.
@@ -973,6 +1066,8 @@ impl Request {
 			version: self.version,
 			headers: self.headers.clone(),
 			body: Bytes::new(),
+			body_stream: Mutex::new(None),
+			streamed: false,
 			path_params: self.path_params.clone(),
 			query_params: self.query_params.clone(),
 			is_secure: self.is_secure,
@@ -1257,4 +1352,85 @@ mod tests {
 			Some("from_clone".to_string())
 		);
 	}
+
+	fn streamed_request(chunks: &[&'static str]) -> Request {
+		let chunks: Vec<std::io::Result<Bytes>> = chunks
+			.iter()
+			.map(|chunk| Ok(Bytes::from_static(chunk.as_bytes())))
+			.collect();
+		Request::builder()
+			.method(Method::POST)
+			.uri("/upload")
+			.header(header::CONTENT_TYPE, "application/json")
+			.body_stream(futures::stream::iter(chunks))
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	fn test_streamed_body_is_not_readable_synchronously() {
+		// Arrange
+		let request = streamed_request(&["{}"]);
+
+		// Act
+		let result = request.read_body();
+
+		// Assert
+		assert!(request.is_body_streamed());
+		assert!(request.body().is_empty());
+		assert!(result.is_err());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_streamed_body_bytes_limited() {
+		// Arrange
+		let request = streamed_request(&["{\"a\":", "1}"]);
+
+		// Act
+		let bytes = request.bytes_limited(16).await.unwrap();
+
+		// Assert
+		assert_eq!(bytes, Bytes::from("{\"a\":1}"));
+		assert!(matches!(
+			request.take_body(),
+			Err(crate::body::BodyError::AlreadyConsumed)
+		));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_streamed_body_json_limited_rejects_oversized_body() {
+		// Arrange
+		let request = streamed_request(&["{\"a\":", "\"0123456789\"}"]);
+
+		// Act
+		let result = request.json_limited::<serde_json::Value>(8).await;
+
+		// Assert
+		assert!(result.is_err());
+	}
+
+	#[cfg(feature = "parsers")]
+	#[rstest]
+	#[tokio::test]
+	async fn test_streamed_body_is_parsed_by_parsers() {
+		// Arrange
+		use reinhardt_core::parsers::json::JSONParser;
+		let request = streamed_request(&["{\"name\":", "\"alice\"}"])
+			.with_parsers(vec![Box::new(JSONParser::new())]);
+
+		// Act
+		let first = request.data().await.unwrap();
+		let second = request.data().await.unwrap();
+
+		// Assert
+		match (first, second) {
+			(ParsedData::Json(first), ParsedData::Json(second)) => {
+				assert_eq!(first["name"], "alice");
+				assert_eq!(first, second);
+			}
+			_ => panic!("Expected JSON data"),
+		}
+	}
 }
@@ -1,4 +1,5 @@
 use super::Request;
+use crate::body::{Body, BodyError};
 use bytes::Bytes;
 #[cfg(feature = "parsers")]
 use reinhardt_core::parsers::parser::{ParsedData, Parser};
@@ -67,7 +68,14 @@ impl Request {
 	pub fn json<T: serde::de::DeserializeOwned>(&self) -> crate::Result<T> {
 		use crate::Error;
 
-		// Check Content-Type before parsing body
+		self.check_json_content_type()?;
+		serde_json::from_slice(&self.body).map_err(|e| Error::Serialization(e.to_string()))
+	}
+
+	/// Check Content-Type before parsing the body as JSON
+	fn check_json_content_type(&self) -> crate::Result<()> {
+		use crate::Error;
+
 		if let Some(content_type) = self
 			.headers
 			.get(hyper::header::CONTENT_TYPE)
@@ -79,13 +87,115 @@ impl Request {
 					content_type
 				)));
 			}
-		} else if !self.body.is_empty() {
+		} else if self.streamed || !self.body.is_empty() {
 			return Err(Error::Http(
 				"Missing Content-Type header: expected 'application/json'".to_string(),
 			));
 		}
+		Ok(())
+	}
 
-		serde_json::from_slice(&self.body).map_err(|e| Error::Serialization(e.to_string()))
+	/// Whether the request body is streamed rather than buffered
+	///
+	/// Streamed bodies are read asynchronously through [`take_body`],
+	/// [`bytes_limited`] or [`json_limited`]; [`body`] is empty and
+	/// [`read_body`] fails.
+	///
+	/// [`take_body`]: Self::take_body
+	/// [`bytes_limited`]: Self::bytes_limited
+	/// [`json_limited`]: Self::json_limited
+	/// [`body`]: Self::body
+	/// [`read_body`]: Self::read_body
+	pub fn is_body_streamed(&self) -> bool {
+		self.streamed
+	}
+
+	/// Take the request body for incremental reading
+	///
+	/// Returns the streamed body, or the buffered body as a single-chunk
+	/// [`Body`] without copying it. Marks the body as consumed.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bytes::Bytes;
+	/// use futures::StreamExt;
+	/// use reinhardt_http::Request;
+	/// use hyper::Method;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() {
+	/// let request = Request::builder()
+	///     .method(Method::POST)
+	///     .uri("/")
+	///     .body(Bytes::from("request body"))
+	///     .build()
+	///     .unwrap();
+	///
+	/// let mut body = request.take_body().unwrap();
+	/// assert_eq!(body.next().await.unwrap().unwrap(), Bytes::from("request body"));
+	///
+	/// // The body can only be taken once
+	/// assert!(request.take_body().is_err());
+	/// # }
+	/// ```
+	pub fn take_body(&self) -> Result<Body, BodyError> {
+		if self.body_consumed.swap(true, Ordering::SeqCst) {
+			return Err(BodyError::AlreadyConsumed);
+		}
+		if self.streamed {
+			return self
+				.body_stream
+				.lock()
+				.unwrap_or_else(|e| e.into_inner())
+				.take()
+				.ok_or(BodyError::AlreadyConsumed);
+		}
+		Ok(Body::from(self.body.clone()))
+	}
+
+	/// Read the whole body, rejecting it as soon as more than `limit` bytes
+	/// have been received
+	///
+	/// Works for both buffered and streamed bodies and marks the body as
+	/// consumed.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bytes::Bytes;
+	/// use futures::stream;
+	/// use reinhardt_http::Request;
+	/// use reinhardt_http::body::BodyError;
+	/// use hyper::Method;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() {
+	/// let request = Request::builder()
+	///     .method(Method::POST)
+	///     .uri("/")
+	///     .body_stream(stream::iter(vec![Ok(Bytes::from("0123")), Ok(Bytes::from("4567"))]))
+	///     .build()
+	///     .unwrap();
+	///
+	/// let result = request.bytes_limited(6).await;
+	/// assert!(matches!(result, Err(BodyError::TooLarge { limit: 6 })));
+	/// # }
+	/// ```
+	pub async fn bytes_limited(&self, limit: usize) -> Result<Bytes, BodyError> {
+		self.take_body()?.bytes(limit).await
+	}
+
+	/// Parse at most `limit` bytes of the body as JSON
+	///
+	/// Like [`json`](Self::json), but also accepts streamed bodies and stops
+	/// reading once the limit is crossed. Marks the body as consumed.
+	pub async fn json_limited<T: serde::de::DeserializeOwned>(
+		&self,
+		limit: usize,
+	) -> crate::Result<T> {
+		self.check_json_content_type()?;
+		Ok(self.take_body()?.json(limit).await?)
 	}
 
 	/// Set parsers for request body parsing
@@ -137,6 +247,11 @@ impl Request {
 	/// ```
 	pub fn read_body(&self) -> crate::Result<Bytes> {
 		use crate::Error;
+		if self.streamed {
+			return Err(Error::Http(
+				"Request body is streamed; read it with take_body() or bytes_limited()".to_string(),
+			));
+		}
 		if self.body_consumed.load(Ordering::SeqCst) {
 			return Err(Error::Http(
 				"Request body has already been consumed".to_string(),
@@ -256,10 +371,25 @@ impl Request {
 		// Try each parser
 		for parser in &self.parsers {
 			if parser.can_parse(content_type) {
-				match parser
-					.parse(content_type, self.body.clone(), &self.headers)
-					.await
-				{
+				let result = if self.streamed {
+					// A streamed body can be parsed once; the result is cached
+					let body = self
+						.body_stream
+						.lock()
+						.unwrap_or_else(|e| e.into_inner())
+						.take()
+						.ok_or_else(|| {
+							crate::Error::Http("Request body has already been consumed".to_string())
+						})?;
+					parser
+						.parse_stream(content_type, body.into_stream(), &self.headers)
+						.await
+				} else {
+					parser
+						.parse(content_type, self.body.clone(), &self.headers)
+						.await
+				};
+				match result {
 					Ok(data) => {
 						// Cache the result
 						let mut cache = self.parsed_data.lock().unwrap_or_else(|e| e.into_inner());
@@ -1,6 +1,36 @@
 use super::Request;
+use crate::cookies::CookieJar;
 
 impl Request {
+	/// Returns a cookie jar holding the cookies sent with the request
+	///
+	/// Cookies added to or removed from the jar are applied to a response
+	/// with [`Response::with_cookie_jar`](crate::Response::with_cookie_jar).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Request;
+	///
+	/// let request = Request::builder()
+	///     .uri("/")
+	///     .header("cookie", "theme=dark; lang=en")
+	///     .build()
+	///     .unwrap();
+	///
+	/// let jar = request.cookies();
+	/// assert_eq!(jar.get("theme"), Some("dark"));
+	/// assert_eq!(request.cookie("lang").as_deref(), Some("en"));
+	/// ```
+	pub fn cookies(&self) -> CookieJar {
+		CookieJar::from_headers(&self.headers)
+	}
+
+	/// Returns the value of a cookie sent with the request
+	pub fn cookie(&self, name: &str) -> Option<String> {
+		self.cookies().get(name).map(str::to_owned)
+	}
+
 	/// Returns true if the request was made over HTTPS
 	///
 	/// This can be determined either by:
@@ -119,6 +149,28 @@ impl Request {
 		format!("{}://{}{}", scheme, host, path)
 	}
 
+	/// Path and query string of the request, as sent by the client
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Request;
+	/// use hyper::Method;
+	///
+	/// let request = Request::builder()
+	///     .method(Method::GET)
+	///     .uri("/orders?page=2")
+	///     .build()
+	///     .unwrap();
+	/// assert_eq!(request.full_path(), "/orders?page=2");
+	/// ```
+	pub fn full_path(&self) -> String {
+		self.uri
+			.path_and_query()
+			.map(|path_and_query| path_and_query.as_str().to_string())
+			.unwrap_or_else(|| self.path().to_string())
+	}
+
 	/// Get the host from the request headers
 	fn get_host(&self) -> Option<String> {
 		self.headers
@@ -1,8 +1,10 @@
+use crate::cookies::{Cookie, CookieJar};
 use bytes::Bytes;
 use futures::stream::Stream;
 use hyper::{HeaderMap, StatusCode};
 use reinhardt_core::exception::HttpError;
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::pin::Pin;
 
 /// Returns a safe, client-facing error message based on the HTTP status code.
@@ -41,10 +43,11 @@ fn safe_error_message(status: StatusCode) -> &'static str {
 /// Returns `None` if no safe detail can be extracted.
 /// Only extracts details from error variants where the message is
 /// controlled by application code and safe for client exposure.
-fn safe_client_error_detail(error: &crate::Error) -> Option<String> {
+pub(crate) fn safe_client_error_detail(error: &crate::Error) -> Option<String> {
 	use crate::Error;
 	match error {
 		Error::Validation(msg) => Some(msg.clone()),
+		Error::FieldValidation(_) => Some("Validation failed".to_string()),
 		Error::Http(msg) => Some(msg.clone()),
 		Error::Serialization(msg) => Some(msg.clone()),
 		Error::ParseError(_) => Some("Invalid request format".to_string()),
@@ -86,6 +89,7 @@ fn safe_client_error_detail(error: &crate::Error) -> Option<String> {
 pub struct SafeErrorResponse {
 	status: StatusCode,
 	detail: Option<String>,
+	field_errors: Option<serde_json::Value>,
 	debug_info: Option<String>,
 	debug_mode: bool,
 }
@@ -96,6 +100,7 @@ impl SafeErrorResponse {
 		Self {
 			status,
 			detail: None,
+			field_errors: None,
 			debug_info: None,
 			debug_mode: false,
 		}
@@ -110,6 +115,14 @@ impl SafeErrorResponse {
 		self
 	}
 
+	/// Add per-field error messages, rendered as an `errors` object.
+	///
+	/// Only included for 4xx errors, like [`with_detail`](Self::with_detail).
+	pub fn with_field_errors(mut self, errors: &BTreeMap<String, Vec<String>>) -> Self {
+		self.field_errors = serde_json::to_value(errors).ok();
+		self
+	}
+
 	/// Add debug information (only included when debug_mode is true).
 	///
 	/// WARNING: Only use in development environments.
@@ -140,6 +153,11 @@ impl SafeErrorResponse {
 		{
 			body["detail"] = serde_json::Value::String(detail.clone());
 		}
+		if self.status.is_client_error()
+			&& let Some(errors) = &self.field_errors
+		{
+			body["errors"] = errors.clone();
+		}
 
 		// Include debug info only when explicitly enabled
 		if self.debug_mode {
@@ -195,6 +213,70 @@ pub fn truncate_for_log(input: &str, max_length: usize) -> String {
 	}
 }
 
+/// Infer the media type of a response body
+///
+/// Recognizes common binary formats by their signature, and JSON, HTML, XML
+/// and SVG documents by their first characters. Other UTF-8 bodies are
+/// `text/plain; charset=utf-8`; anything else is `application/octet-stream`.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_http::response::infer_content_type;
+///
+/// assert_eq!(infer_content_type(b"[1, 2]"), "application/json");
+/// assert_eq!(infer_content_type(b"<!DOCTYPE html><p>Hi</p>"), "text/html; charset=utf-8");
+/// assert_eq!(infer_content_type(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]), "image/png");
+/// assert_eq!(infer_content_type(b"hello"), "text/plain; charset=utf-8");
+/// ```
+pub fn infer_content_type(body: &[u8]) -> &'static str {
+	const SIGNATURES: &[(&[u8], &str)] = &[
+		(
+			&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A],
+			"image/png",
+		),
+		(&[0xFF, 0xD8, 0xFF], "image/jpeg"),
+		(b"GIF87a", "image/gif"),
+		(b"GIF89a", "image/gif"),
+		(b"%PDF-", "application/pdf"),
+		(&[b'P', b'K', 0x03, 0x04], "application/zip"),
+		(&[0x1F, 0x8B], "application/gzip"),
+		(b"wOF2", "font/woff2"),
+		(b"wOFF", "font/woff"),
+	];
+	if let Some((_, content_type)) = SIGNATURES
+		.iter()
+		.find(|(signature, _)| body.starts_with(signature))
+	{
+		return content_type;
+	}
+	if body.len() >= 12 && body.starts_with(b"RIFF") && &body[8..12] == b"WEBP" {
+		return "image/webp";
+	}
+
+	let Ok(text) = std::str::from_utf8(body) else {
+		return "application/octet-stream";
+	};
+	let text = text.trim_start_matches('\u{feff}').trim_start();
+	let starts_with = |prefix: &str| {
+		text.get(..prefix.len())
+			.is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+	};
+	if (text.starts_with('{') || text.starts_with('['))
+		&& serde_json::from_str::<serde::de::IgnoredAny>(text).is_ok()
+	{
+		"application/json"
+	} else if starts_with("<!doctype html") || starts_with("<html") {
+		"text/html; charset=utf-8"
+	} else if starts_with("<svg") {
+		"image/svg+xml"
+	} else if starts_with("<?xml") {
+		"application/xml"
+	} else {
+		"text/plain; charset=utf-8"
+	}
+}
+
 /// HTTP Response representation
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Response {
@@ -428,6 +510,167 @@ impl Response {
 	pub fn temporary_redirect_preserve_method(location: impl AsRef<str>) -> Self {
 		Self::new(StatusCode::TEMPORARY_REDIRECT).with_location(location.as_ref())
 	}
+	/// Create a Response with HTTP 308 Permanent Redirect (preserves HTTP method)
+	///
+	/// Unlike 301, this guarantees the request method and body are preserved,
+	/// e.g. when an API endpoint accepting `POST` has moved.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Response;
+	/// use hyper::StatusCode;
+	///
+	/// let response = Response::permanent_redirect_preserve_method("/api/v2/orders");
+	/// assert_eq!(response.status, StatusCode::PERMANENT_REDIRECT);
+	/// assert_eq!(response.headers.get("location").unwrap(), "/api/v2/orders");
+	/// ```
+	pub fn permanent_redirect_preserve_method(location: impl AsRef<str>) -> Self {
+		Self::new(StatusCode::PERMANENT_REDIRECT).with_location(location.as_ref())
+	}
+	/// Create a Response with HTTP 303 See Other
+	///
+	/// Tells the client to fetch `location` with `GET`, which is the
+	/// redirect to send after handling a form `POST`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Response;
+	/// use hyper::StatusCode;
+	///
+	/// let response = Response::see_other("/orders/42/");
+	/// assert_eq!(response.status, StatusCode::SEE_OTHER);
+	/// assert_eq!(response.headers.get("location").unwrap(), "/orders/42/");
+	/// ```
+	pub fn see_other(location: impl AsRef<str>) -> Self {
+		Self::new(StatusCode::SEE_OTHER).with_location(location.as_ref())
+	}
+	/// Create a Response with HTTP 201 Created pointing at the new resource
+	///
+	/// `location` is typically built with `reverse()` from the route name of
+	/// the resource's detail view.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Response;
+	/// use hyper::StatusCode;
+	///
+	/// let response = Response::created_at("/orders/42/");
+	/// assert_eq!(response.status, StatusCode::CREATED);
+	/// assert_eq!(response.headers.get("location").unwrap(), "/orders/42/");
+	/// ```
+	pub fn created_at(location: impl AsRef<str>) -> Self {
+		Self::created().with_location(location.as_ref())
+	}
+	/// Create a Response with HTTP 202 Accepted
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Response;
+	/// use hyper::StatusCode;
+	///
+	/// let response = Response::accepted();
+	/// assert_eq!(response.status, StatusCode::ACCEPTED);
+	/// ```
+	pub fn accepted() -> Self {
+		Self::new(StatusCode::ACCEPTED)
+	}
+	/// Create a Response with HTTP 304 Not Modified
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Response;
+	/// use hyper::StatusCode;
+	///
+	/// let response = Response::not_modified();
+	/// assert_eq!(response.status, StatusCode::NOT_MODIFIED);
+	/// ```
+	pub fn not_modified() -> Self {
+		Self::new(StatusCode::NOT_MODIFIED)
+	}
+	/// Create a Response with HTTP 405 Method Not Allowed and the `Allow` header
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Response;
+	/// use hyper::{Method, StatusCode};
+	///
+	/// let response = Response::method_not_allowed(&[Method::GET, Method::HEAD]);
+	/// assert_eq!(response.status, StatusCode::METHOD_NOT_ALLOWED);
+	/// assert_eq!(response.headers.get("allow").unwrap(), "GET, HEAD");
+	/// ```
+	pub fn method_not_allowed(allowed: &[hyper::Method]) -> Self {
+		let allow = allowed
+			.iter()
+			.map(hyper::Method::as_str)
+			.collect::<Vec<_>>()
+			.join(", ");
+		Self::new(StatusCode::METHOD_NOT_ALLOWED).with_header("Allow", &allow)
+	}
+	/// Create a Response with HTTP 409 Conflict
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Response;
+	/// use hyper::StatusCode;
+	///
+	/// let response = Response::conflict();
+	/// assert_eq!(response.status, StatusCode::CONFLICT);
+	/// ```
+	pub fn conflict() -> Self {
+		Self::new(StatusCode::CONFLICT)
+	}
+	/// Create a Response with HTTP 422 Unprocessable Entity
+	///
+	/// Use [`validation_problem`](Self::validation_problem) to report field
+	/// errors.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Response;
+	/// use hyper::StatusCode;
+	///
+	/// let response = Response::unprocessable_entity();
+	/// assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+	/// ```
+	pub fn unprocessable_entity() -> Self {
+		Self::new(StatusCode::UNPROCESSABLE_ENTITY)
+	}
+	/// Create a Response with HTTP 429 Too Many Requests
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Response;
+	/// use hyper::StatusCode;
+	///
+	/// let response = Response::too_many_requests();
+	/// assert_eq!(response.status, StatusCode::TOO_MANY_REQUESTS);
+	/// ```
+	pub fn too_many_requests() -> Self {
+		Self::new(StatusCode::TOO_MANY_REQUESTS)
+	}
+	/// Create a Response with HTTP 503 Service Unavailable
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Response;
+	/// use hyper::StatusCode;
+	///
+	/// let response = Response::service_unavailable();
+	/// assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+	/// ```
+	pub fn service_unavailable() -> Self {
+		Self::new(StatusCode::SERVICE_UNAVAILABLE)
+	}
 
 	/// Builds a safe HTTP error response from an application-defined error.
 	///
@@ -685,6 +928,31 @@ impl Response {
 		self
 	}
 
+	/// Add a `Set-Cookie` header for `cookie`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Response;
+	/// use reinhardt_http::cookies::{Cookie, SameSite};
+	///
+	/// let response = Response::ok()
+	///     .with_cookie(&Cookie::new("theme", "dark").with_same_site(SameSite::Lax))
+	///     .with_cookie(&Cookie::removal("legacy"));
+	/// let cookies: Vec<_> = response.headers.get_all("set-cookie").iter().collect();
+	/// assert_eq!(cookies[0], "theme=dark; Path=/; SameSite=Lax");
+	/// assert_eq!(cookies[1], "legacy=; Path=/; Max-Age=0");
+	/// ```
+	pub fn with_cookie(self, cookie: &Cookie) -> Self {
+		self.append_header("Set-Cookie", &cookie.to_string())
+	}
+
+	/// Add a `Set-Cookie` header for every cookie added to or removed from `jar`
+	pub fn with_cookie_jar(self, jar: &CookieJar) -> Self {
+		jar.delta()
+			.fold(self, |response, cookie| response.with_cookie(cookie))
+	}
+
 	/// Add a Location header to the response (typically used for redirects)
 	///
 	/// # Examples
@@ -731,6 +999,166 @@ impl Response {
 		);
 		Ok(self)
 	}
+	/// Set the response body to JSON
+	///
+	/// Shorthand for [`with_json`](Self::with_json), for chaining with the
+	/// other builder methods.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Response;
+	/// use serde_json::json;
+	///
+	/// let response = Response::created_at("/orders/42/")
+	///     .json(&json!({"id": 42}))?
+	///     .header("X-Request-Id", "abc123");
+	///
+	/// assert_eq!(response.headers.get("content-type").unwrap(), "application/json");
+	/// assert_eq!(response.headers.get("x-request-id").unwrap(), "abc123");
+	/// # Ok::<(), reinhardt_http::Error>(())
+	/// ```
+	pub fn json<T: Serialize>(self, data: &T) -> crate::Result<Self> {
+		self.with_json(data)
+	}
+	/// Set the response body to HTML with `text/html; charset=utf-8`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Response;
+	///
+	/// let response = Response::ok().html("<h1>Hello</h1>");
+	/// assert_eq!(
+	///     response.headers.get("content-type").unwrap(),
+	///     "text/html; charset=utf-8"
+	/// );
+	/// ```
+	pub fn html(self, html: impl Into<String>) -> Self {
+		self.with_body(html.into())
+			.content_type("text/html; charset=utf-8")
+	}
+	/// Set the response body to plain text with `text/plain; charset=utf-8`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Response;
+	///
+	/// let response = Response::ok().text("pong");
+	/// assert_eq!(
+	///     response.headers.get("content-type").unwrap(),
+	///     "text/plain; charset=utf-8"
+	/// );
+	/// ```
+	pub fn text(self, text: impl Into<String>) -> Self {
+		self.with_body(text.into())
+			.content_type("text/plain; charset=utf-8")
+	}
+	/// Set the response body, inferring its `Content-Type`
+	///
+	/// An existing `Content-Type` header is kept. Otherwise the type is
+	/// inferred from the body with [`infer_content_type`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Response;
+	///
+	/// let response = Response::ok().content(r#"{"ok": true}"#);
+	/// assert_eq!(response.headers.get("content-type").unwrap(), "application/json");
+	///
+	/// let response = Response::ok().content_type("text/csv").content("id,name\n");
+	/// assert_eq!(response.headers.get("content-type").unwrap(), "text/csv");
+	/// ```
+	pub fn content(self, body: impl Into<Bytes>) -> Self {
+		let response = self.with_body(body);
+		if response.body.is_empty() || response.headers.contains_key(hyper::header::CONTENT_TYPE) {
+			return response;
+		}
+		let content_type = infer_content_type(&response.body);
+		response.content_type(content_type)
+	}
+	/// Set the `Content-Type` header
+	///
+	/// Invalid values are ignored, as with [`with_header`](Self::with_header).
+	pub fn content_type(self, content_type: &str) -> Self {
+		self.header(hyper::header::CONTENT_TYPE, content_type)
+	}
+	/// Set a header, replacing any existing value
+	///
+	/// Accepts typed [`HeaderName`](hyper::header::HeaderName) and
+	/// [`HeaderValue`](hyper::header::HeaderValue) as well as strings. Invalid
+	/// names or values are logged and ignored; use
+	/// [`try_with_header`](Self::try_with_header) to handle them.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Response;
+	/// use hyper::header::{CACHE_CONTROL, HeaderValue};
+	///
+	/// let response = Response::ok()
+	///     .header(CACHE_CONTROL, HeaderValue::from_static("no-store"))
+	///     .header("X-Frame-Options", "DENY");
+	///
+	/// assert_eq!(response.headers.get("cache-control").unwrap(), "no-store");
+	/// assert_eq!(response.headers.get("x-frame-options").unwrap(), "DENY");
+	/// ```
+	pub fn header<K, V>(mut self, name: K, value: V) -> Self
+	where
+		K: TryInto<hyper::header::HeaderName>,
+		V: TryInto<hyper::header::HeaderValue>,
+	{
+		match (name.try_into(), value.try_into()) {
+			(Ok(name), Ok(value)) => {
+				self.headers.insert(name, value);
+			}
+			_ => tracing::warn!("Ignoring invalid response header"),
+		}
+		self
+	}
+	/// Add a `Set-Cookie` header for `cookie`
+	///
+	/// Shorthand for [`with_cookie`](Self::with_cookie).
+	pub fn cookie(self, cookie: &Cookie) -> Self {
+		self.with_cookie(cookie)
+	}
+	/// Create a 422 `application/problem+json` response from validation errors
+	///
+	/// The body is [`ValidationErrors::to_problem_json`], which forms of
+	/// `reinhardt-pages` map back onto their fields.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::Response;
+	/// use reinhardt_core::validators::{ValidationError, ValidationErrors};
+	/// use hyper::StatusCode;
+	///
+	/// let mut errors = ValidationErrors::new();
+	/// errors.add("email", ValidationError::InvalidEmail("bad".to_string()));
+	/// let response = Response::validation_problem(&errors);
+	///
+	/// assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+	/// assert_eq!(
+	///     response.headers.get("content-type").unwrap(),
+	///     "application/problem+json"
+	/// );
+	/// ```
+	///
+	/// [`ValidationErrors::to_problem_json`]: reinhardt_core::validators::ValidationErrors::to_problem_json
+	pub fn validation_problem(errors: &reinhardt_core::validators::ValidationErrors) -> Self {
+		let mut response = Self::new(StatusCode::UNPROCESSABLE_ENTITY);
+		response.body = Bytes::from(errors.to_problem_json().to_string());
+		response.headers.insert(
+			hyper::header::CONTENT_TYPE,
+			hyper::header::HeaderValue::from_static(
+				reinhardt_core::validators::PROBLEM_JSON_CONTENT_TYPE,
+			),
+		);
+		response
+	}
 	/// Add a custom header using typed HeaderName and HeaderValue
 	///
 	/// # Examples
@@ -813,6 +1241,10 @@ impl Response {
 
 impl From<crate::Error> for Response {
 	fn from(error: crate::Error) -> Self {
+		if let Some(handlers) = crate::exception_handlers::global_exception_handlers() {
+			return handlers.render(error);
+		}
+
 		let status =
 			StatusCode::from_u16(error.status_code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
 
@@ -832,6 +1264,9 @@ impl From<crate::Error> for Response {
 		{
 			response = response.with_detail(detail);
 		}
+		if let crate::Error::FieldValidation(errors) = &error {
+			response = response.with_field_errors(errors);
+		}
 
 		response.build()
 	}
@@ -1326,6 +1761,48 @@ mod tests {
 		assert_eq!(body["detail"], "Email format is invalid");
 	}
 
+	#[rstest]
+	fn test_from_error_field_validation_returns_422_with_errors() {
+		// Arrange
+		let error = crate::Error::FieldValidation(BTreeMap::from([(
+			"title".to_string(),
+			vec!["Length too short: 0 (minimum: 1)".to_string()],
+		)]));
+
+		// Act
+		let response: Response = error.into();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+		let body: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+		assert_eq!(body["error"], "Unprocessable Entity");
+		assert_eq!(body["detail"], "Validation failed");
+		assert_eq!(
+			body["errors"],
+			serde_json::json!({"title": ["Length too short: 0 (minimum: 1)"]})
+		);
+	}
+
+	#[rstest]
+	fn test_validation_problem_response() {
+		// Arrange
+		let mut errors = reinhardt_core::validators::ValidationErrors::new();
+		errors.add(
+			"title",
+			reinhardt_core::validators::ValidationError::TooShort { length: 0, min: 1 },
+		);
+
+		// Act
+		let response = Response::validation_problem(&errors);
+
+		// Assert
+		assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+		let body: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+		assert_eq!(body["status"], 422);
+		assert_eq!(body["errors"]["title"][0]["code"], "min_length");
+		assert_eq!(body["errors"]["title"][0]["params"]["min"], "1");
+	}
+
 	#[rstest]
 	fn test_from_error_produces_safe_output_for_4xx_parse() {
 		// Arrange
@@ -1499,4 +1976,107 @@ mod tests {
 		let cookies: Vec<_> = response.headers.get_all("set-cookie").iter().collect();
 		assert_eq!(cookies.len(), 2);
 	}
+
+	#[rstest]
+	#[case::created(Response::created_at("/orders/1/"), StatusCode::CREATED)]
+	#[case::moved_permanently(Response::permanent_redirect("/new/"), StatusCode::MOVED_PERMANENTLY)]
+	#[case::found(Response::temporary_redirect("/new/"), StatusCode::FOUND)]
+	#[case::see_other(Response::see_other("/new/"), StatusCode::SEE_OTHER)]
+	#[case::temporary(
+		Response::temporary_redirect_preserve_method("/new/"),
+		StatusCode::TEMPORARY_REDIRECT
+	)]
+	#[case::permanent(
+		Response::permanent_redirect_preserve_method("/new/"),
+		StatusCode::PERMANENT_REDIRECT
+	)]
+	fn test_location_helpers_set_status_and_location(
+		#[case] response: Response,
+		#[case] expected: StatusCode,
+	) {
+		// Assert
+		assert_eq!(response.status, expected);
+		assert!(response.headers.contains_key(hyper::header::LOCATION));
+	}
+
+	#[rstest]
+	fn test_method_not_allowed_lists_allowed_methods() {
+		// Act
+		let response = Response::method_not_allowed(&[hyper::Method::GET, hyper::Method::POST]);
+
+		// Assert
+		assert_eq!(response.status, StatusCode::METHOD_NOT_ALLOWED);
+		assert_eq!(response.headers.get("allow").unwrap(), "GET, POST");
+	}
+
+	#[rstest]
+	fn test_fluent_builder_chains_json_header_and_cookie() {
+		// Arrange
+		let cookie = Cookie::new("theme", "dark");
+
+		// Act
+		let response = Response::created_at("/orders/1/")
+			.json(&serde_json::json!({"id": 1}))
+			.unwrap()
+			.header("X-Request-Id", "abc")
+			.cookie(&cookie);
+
+		// Assert
+		assert_eq!(
+			response.headers.get("content-type").unwrap(),
+			"application/json"
+		);
+		assert_eq!(response.headers.get("x-request-id").unwrap(), "abc");
+		assert!(response.headers.contains_key("set-cookie"));
+		assert_eq!(response.body, Bytes::from(r#"{"id":1}"#));
+	}
+
+	#[rstest]
+	fn test_header_ignores_invalid_input() {
+		// Act
+		let response = Response::ok()
+			.header("X-Valid", "yes")
+			.header("Invalid Name", "value")
+			.header("X-Bad-Value", "line\nbreak");
+
+		// Assert
+		assert_eq!(response.headers.len(), 1);
+	}
+
+	#[rstest]
+	#[case::json_object(b"{\"a\": 1}".as_slice(), "application/json")]
+	#[case::json_array(b"  [1, 2]".as_slice(), "application/json")]
+	#[case::invalid_json(b"{not json".as_slice(), "text/plain; charset=utf-8")]
+	#[case::html(b"<!DOCTYPE html><html></html>".as_slice(), "text/html; charset=utf-8")]
+	#[case::html_tag(b"\n<HTML lang=\"en\">".as_slice(), "text/html; charset=utf-8")]
+	#[case::xml(b"<?xml version=\"1.0\"?><a/>".as_slice(), "application/xml")]
+	#[case::svg(b"<svg xmlns=\"http://www.w3.org/2000/svg\"/>".as_slice(), "image/svg+xml")]
+	#[case::png(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0x00], "image/png")]
+	#[case::jpeg(&[0xFF, 0xD8, 0xFF, 0xE0], "image/jpeg")]
+	#[case::pdf(b"%PDF-1.7".as_slice(), "application/pdf")]
+	#[case::gzip(&[0x1F, 0x8B, 0x08], "application/gzip")]
+	#[case::webp(b"RIFF\x00\x00\x00\x00WEBPVP8 ".as_slice(), "image/webp")]
+	#[case::text(b"hello".as_slice(), "text/plain; charset=utf-8")]
+	#[case::binary(&[0x00, 0xFF, 0xFE, 0x80], "application/octet-stream")]
+	fn test_infer_content_type(#[case] body: &[u8], #[case] expected: &str) {
+		// Act
+		let content_type = infer_content_type(body);
+
+		// Assert
+		assert_eq!(content_type, expected);
+	}
+
+	#[rstest]
+	fn test_content_keeps_explicit_content_type() {
+		// Act
+		let inferred = Response::ok().content("[1]");
+		let explicit = Response::ok().content_type("text/csv").content("[1]");
+
+		// Assert
+		assert_eq!(
+			inferred.headers.get("content-type").unwrap(),
+			"application/json"
+		);
+		assert_eq!(explicit.headers.get("content-type").unwrap(), "text/csv");
+	}
 }
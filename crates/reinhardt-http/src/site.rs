@@ -0,0 +1,161 @@
+//! Current site of a multi-site deployment.
+//!
+//! [`Site`] identifies one of the domains served by a project, like Django's
+//! `django.contrib.sites`. Site middleware resolves it from the `Host` header
+//! (or the configured default site) and stores it in the request extensions,
+//! where handlers, feeds and sitemaps read it with [`Site::current`].
+//!
+//! [`absolute_url`] turns a path into a full URL on the current site, falling
+//! back to the request's `Host` header when no site was resolved.
+
+use crate::Request;
+use crate::extensions::Extensions;
+
+/// A domain served by the project.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_http::Site;
+///
+/// let site = Site::new(1, "example.com".to_string(), "Example".to_string());
+/// assert_eq!(site.url("/about/", true), "https://example.com/about/");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Site {
+	/// Site ID
+	pub id: u64,
+	/// Domain name, optionally with a port
+	pub domain: String,
+	/// Human-readable site name
+	pub name: String,
+}
+
+impl Site {
+	/// Create a new site
+	pub fn new(id: u64, domain: String, name: String) -> Self {
+		Self { id, domain, name }
+	}
+
+	/// Site resolved for the request, if any
+	pub fn current(request: &Request) -> Option<Self> {
+		Self::from_extensions(&request.extensions)
+	}
+
+	/// Site stored in `extensions`, if any
+	pub fn from_extensions(extensions: &Extensions) -> Option<Self> {
+		extensions.get::<Self>()
+	}
+
+	/// Absolute URL of `url` on this site
+	///
+	/// See [`add_domain`].
+	pub fn url(&self, url: &str, secure: bool) -> String {
+		add_domain(&self.domain, url, secure)
+	}
+}
+
+/// Prefix `url` with a scheme and `domain` unless it is already absolute.
+///
+/// Protocol-relative URLs (`//cdn.example.com/x`) only get the scheme.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_http::site::add_domain;
+///
+/// assert_eq!(add_domain("example.com", "/a/", false), "http://example.com/a/");
+/// assert_eq!(add_domain("example.com", "a/", true), "https://example.com/a/");
+/// assert_eq!(add_domain("example.com", "//cdn.test/x.js", true), "https://cdn.test/x.js");
+/// assert_eq!(add_domain("example.com", "mailto:a@b.c", true), "mailto:a@b.c");
+/// ```
+pub fn add_domain(domain: &str, url: &str, secure: bool) -> String {
+	let scheme = if secure { "https" } else { "http" };
+	if url.starts_with("//") {
+		return format!("{}:{}", scheme, url);
+	}
+	if has_scheme(url) {
+		return url.to_string();
+	}
+	let domain = domain.trim_end_matches('/');
+	if url.starts_with('/') {
+		format!("{}://{}{}", scheme, domain, url)
+	} else {
+		format!("{}://{}/{}", scheme, domain, url)
+	}
+}
+
+/// Absolute URL of `url` on the request's site.
+///
+/// Uses the [current site](Site::current) when site middleware resolved one,
+/// otherwise the request's `Host` header; the scheme follows
+/// [`Request::is_secure`].
+pub fn absolute_url(request: &Request, url: &str) -> String {
+	let secure = request.is_secure();
+	match Site::current(request) {
+		Some(site) => site.url(url, secure),
+		None => {
+			let host = request
+				.headers
+				.get(hyper::header::HOST)
+				.and_then(|host| host.to_str().ok())
+				.unwrap_or("localhost");
+			add_domain(host, url, secure)
+		}
+	}
+}
+
+/// Whether `url` starts with a URI scheme such as `https:` or `mailto:`
+fn has_scheme(url: &str) -> bool {
+	match url.split_once(':') {
+		Some((scheme, _)) => {
+			!scheme.is_empty()
+				&& scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+				&& scheme
+					.chars()
+					.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+		}
+		None => false,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use hyper::Method;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case("/feed/", "http://example.com/feed/")]
+	#[case("https://other.test/x", "https://other.test/x")]
+	#[case("about/", "http://example.com/about/")]
+	fn test_add_domain(#[case] url: &str, #[case] expected: &str) {
+		// Act / Assert
+		assert_eq!(add_domain("example.com", url, false), expected);
+	}
+
+	#[rstest]
+	fn test_absolute_url_prefers_current_site() {
+		// Arrange
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/")
+			.header("host", "internal:8080")
+			.secure(true)
+			.build()
+			.unwrap();
+		let fallback = absolute_url(&request, "/a/");
+		request.extensions.insert(Site::new(
+			1,
+			"example.com".to_string(),
+			"Example".to_string(),
+		));
+
+		// Act
+		let url = absolute_url(&request, "/a/");
+
+		// Assert
+		assert_eq!(fallback, "https://internal:8080/a/");
+		assert_eq!(url, "https://example.com/a/");
+	}
+}
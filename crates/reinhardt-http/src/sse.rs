@@ -0,0 +1,479 @@
+//! Server-Sent Events (SSE) responses.
+//!
+//! This module implements the `text/event-stream` wire format described in the
+//! [HTML Living Standard](https://html.spec.whatwg.org/multipage/server-sent-events.html).
+//! Handlers build a stream of [`SseEvent`] values and wrap it in an
+//! [`SseResponse`], which takes care of encoding, keep-alive comments and the
+//! headers required to keep intermediaries from buffering the stream.
+//!
+//! ## Example
+//!
+//! ```
+//! use reinhardt_http::sse::{SseEvent, SseResponse};
+//! use futures::stream;
+//! use std::time::Duration;
+//!
+//! let events = stream::iter(vec![
+//!     SseEvent::new("first").id("1"),
+//!     SseEvent::new("second").id("2").event("update"),
+//! ]);
+//!
+//! let response = SseResponse::new(events)
+//!     .retry(Duration::from_secs(5))
+//!     .keep_alive(Duration::from_secs(15))
+//!     .into_streaming_response();
+//!
+//! assert_eq!(
+//!     response.headers.get(hyper::header::CONTENT_TYPE).unwrap(),
+//!     "text/event-stream"
+//! );
+//! ```
+
+use crate::request::Request;
+use crate::response::{StreamBody, StreamingResponse};
+use bytes::Bytes;
+use futures::stream::{self, Stream, StreamExt};
+use hyper::header::{CACHE_CONTROL, CONTENT_TYPE, HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Header sent by browsers when reconnecting to an event stream.
+pub const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+
+/// Query parameter used by EventSource polyfills that cannot set custom headers.
+pub const LAST_EVENT_ID_QUERY_PARAM: &str = "lastEventId";
+
+/// Default interval between keep-alive comments.
+pub const DEFAULT_KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// A single Server-Sent Event.
+///
+/// Multi-line `data` is split into multiple `data:` fields on encoding, so
+/// the client receives the original payload with newlines preserved.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_http::sse::SseEvent;
+///
+/// let event = SseEvent::new("hello\nworld").id("42").event("greeting");
+/// assert_eq!(
+///     event.encode(),
+///     "id: 42\nevent: greeting\ndata: hello\ndata: world\n\n"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SseEvent {
+	/// Event identifier, echoed back by the client as `Last-Event-ID`.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// Event type, dispatched to `addEventListener(<event>, ...)` on the client.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub event: Option<String>,
+	/// Event payload.
+	#[serde(default)]
+	pub data: String,
+	/// Reconnection delay the client should use, in milliseconds.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub retry: Option<u64>,
+	/// Comment line, ignored by clients but useful for debugging.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub comment: Option<String>,
+}
+
+impl SseEvent {
+	/// Create an event carrying the given data.
+	pub fn new(data: impl Into<String>) -> Self {
+		Self {
+			data: data.into(),
+			..Default::default()
+		}
+	}
+
+	/// Create an event whose data is the JSON encoding of `value`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_http::sse::SseEvent;
+	///
+	/// let event = SseEvent::json(&serde_json::json!({"count": 1})).unwrap();
+	/// assert_eq!(event.data, r#"{"count":1}"#);
+	/// ```
+	pub fn json<T: Serialize>(value: &T) -> crate::Result<Self> {
+		let data =
+			serde_json::to_string(value).map_err(|e| crate::Error::Serialization(e.to_string()))?;
+		Ok(Self::new(data))
+	}
+
+	/// Create a comment-only event, used for keep-alive pings.
+	pub fn comment(comment: impl Into<String>) -> Self {
+		Self {
+			comment: Some(comment.into()),
+			..Default::default()
+		}
+	}
+
+	/// Set the event identifier.
+	pub fn id(mut self, id: impl Into<String>) -> Self {
+		self.id = Some(id.into());
+		self
+	}
+
+	/// Set the event type.
+	pub fn event(mut self, event: impl Into<String>) -> Self {
+		self.event = Some(event.into());
+		self
+	}
+
+	/// Set the client reconnection delay.
+	pub fn retry(mut self, retry: Duration) -> Self {
+		self.retry = Some(retry.as_millis() as u64);
+		self
+	}
+
+	/// Returns `true` if this event only carries a comment.
+	pub fn is_comment(&self) -> bool {
+		self.comment.is_some()
+			&& self.id.is_none()
+			&& self.event.is_none()
+			&& self.retry.is_none()
+			&& self.data.is_empty()
+	}
+
+	/// Encode the event into the `text/event-stream` wire format.
+	///
+	/// Carriage returns and newlines inside `id` and `event` are stripped, as
+	/// they would otherwise terminate the field early and allow a payload to
+	/// inject arbitrary fields into the stream.
+	pub fn encode(&self) -> String {
+		let mut out = String::new();
+
+		if let Some(comment) = &self.comment {
+			for line in comment.lines() {
+				out.push_str(": ");
+				out.push_str(line);
+				out.push('\n');
+			}
+			if self.is_comment() {
+				out.push('\n');
+				return out;
+			}
+		}
+		if let Some(id) = &self.id {
+			out.push_str("id: ");
+			out.push_str(&strip_line_breaks(id));
+			out.push('\n');
+		}
+		if let Some(event) = &self.event {
+			out.push_str("event: ");
+			out.push_str(&strip_line_breaks(event));
+			out.push('\n');
+		}
+		if let Some(retry) = self.retry {
+			out.push_str("retry: ");
+			out.push_str(&retry.to_string());
+			out.push('\n');
+		}
+		// Events without data are not dispatched by clients, so an empty
+		// payload only carries the other fields (e.g. a standalone `retry:`).
+		if !self.data.is_empty() {
+			for line in self.data.split('\n') {
+				out.push_str("data: ");
+				out.push_str(line.strip_suffix('\r').unwrap_or(line));
+				out.push('\n');
+			}
+		}
+		out.push('\n');
+		out
+	}
+}
+
+fn strip_line_breaks(value: &str) -> String {
+	value.chars().filter(|c| *c != '\n' && *c != '\r').collect()
+}
+
+/// Extract the `Last-Event-ID` sent by a reconnecting client.
+///
+/// The header takes precedence; the `lastEventId` query parameter is used as a
+/// fallback for EventSource polyfills that cannot set request headers.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_http::Request;
+/// use reinhardt_http::sse::last_event_id;
+/// use hyper::Method;
+///
+/// let request = Request::builder()
+///     .method(Method::GET)
+///     .uri("/events")
+///     .header("Last-Event-ID", "17")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(last_event_id(&request).as_deref(), Some("17"));
+/// ```
+pub fn last_event_id(request: &Request) -> Option<String> {
+	request
+		.headers
+		.get(LAST_EVENT_ID_HEADER)
+		.and_then(|v| v.to_str().ok())
+		.map(str::to_owned)
+		.or_else(|| request.query_params.get(LAST_EVENT_ID_QUERY_PARAM).cloned())
+		.filter(|id| !id.is_empty())
+}
+
+/// Builder for `text/event-stream` responses.
+///
+/// The event stream is consumed lazily when the response body is polled. When
+/// keep-alive is enabled, a comment line is emitted whenever the interval
+/// elapses, which prevents proxies and load balancers from closing idle
+/// connections. The response ends when the event stream ends.
+pub struct SseResponse<S> {
+	events: S,
+	keep_alive: Option<Duration>,
+	retry: Option<Duration>,
+	headers: Vec<(HeaderName, HeaderValue)>,
+}
+
+impl<S> SseResponse<S>
+where
+	S: Stream<Item = SseEvent> + Send + 'static,
+{
+	/// Create a new SSE response from a stream of events.
+	pub fn new(events: S) -> Self {
+		Self {
+			events,
+			keep_alive: None,
+			retry: None,
+			headers: Vec::new(),
+		}
+	}
+
+	/// Emit a keep-alive comment every `interval`.
+	pub fn keep_alive(mut self, interval: Duration) -> Self {
+		self.keep_alive = Some(interval);
+		self
+	}
+
+	/// Send an initial `retry:` field telling the client how long to wait
+	/// before reconnecting.
+	pub fn retry(mut self, retry: Duration) -> Self {
+		self.retry = Some(retry);
+		self
+	}
+
+	/// Add an extra response header.
+	pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+		self.headers.push((name, value));
+		self
+	}
+
+	/// Convert into a [`StreamingResponse`] ready to be served.
+	pub fn into_streaming_response(mut self) -> StreamingResponse<StreamBody> {
+		let headers = std::mem::take(&mut self.headers);
+		let mut response = StreamingResponse::new(self.into_body())
+			.header(CONTENT_TYPE, HeaderValue::from_static("text/event-stream"))
+			.header(CACHE_CONTROL, HeaderValue::from_static("no-cache"))
+			// Disables response buffering in nginx so events are flushed immediately.
+			.header(
+				HeaderName::from_static("x-accel-buffering"),
+				HeaderValue::from_static("no"),
+			);
+		for (name, value) in headers {
+			response = response.header(name, value);
+		}
+		response
+	}
+
+	fn into_body(self) -> StreamBody {
+		let preamble = self.retry.map(|retry| SseEvent::default().retry(retry));
+		let events = stream::iter(preamble).chain(self.events);
+
+		let encoded: stream::BoxStream<'static, SseEvent> = match self.keep_alive {
+			Some(interval) => with_keep_alive(events, interval).boxed(),
+			None => events.boxed(),
+		};
+
+		Box::pin(encoded.map(|event| Ok(Bytes::from(event.encode()))))
+	}
+}
+
+impl<S> SseResponse<S> {
+	/// Returns the configured keep-alive interval.
+	pub fn keep_alive_interval(&self) -> Option<Duration> {
+		self.keep_alive
+	}
+}
+
+/// Interleave keep-alive comments into `events`, ending when `events` ends.
+fn with_keep_alive<S>(events: S, interval: Duration) -> impl Stream<Item = SseEvent> + Send
+where
+	S: Stream<Item = SseEvent> + Send + 'static,
+{
+	// `None` marks the end of the event stream so the never-ending ticker
+	// does not keep the merged stream alive.
+	let events = events.map(Some).chain(stream::once(async { None }));
+	let ticks = stream::unfold(
+		tokio::time::interval_at(tokio::time::Instant::now() + interval, interval),
+		|mut ticker| async move {
+			ticker.tick().await;
+			Some((Some(SseEvent::comment("keep-alive")), ticker))
+		},
+	);
+
+	stream::select(events, ticks)
+		.take_while(|item| futures::future::ready(item.is_some()))
+		.filter_map(futures::future::ready)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use hyper::Method;
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_encode_full_event() {
+		// Arrange
+		let event = SseEvent::new("payload")
+			.id("7")
+			.event("update")
+			.retry(Duration::from_millis(2500));
+
+		// Act
+		let encoded = event.encode();
+
+		// Assert
+		assert_eq!(
+			encoded,
+			"id: 7\nevent: update\nretry: 2500\ndata: payload\n\n"
+		);
+	}
+
+	#[rstest]
+	fn test_encode_strips_line_breaks_from_id_and_event() {
+		// Arrange
+		let event = SseEvent::new("x").id("1\nevent: evil").event("a\r\nb");
+
+		// Act
+		let encoded = event.encode();
+
+		// Assert
+		assert_eq!(encoded, "id: 1event: evil\nevent: ab\ndata: x\n\n");
+	}
+
+	#[rstest]
+	fn test_encode_comment_only() {
+		// Arrange
+		let event = SseEvent::comment("keep-alive");
+
+		// Act & Assert
+		assert!(event.is_comment());
+		assert_eq!(event.encode(), ": keep-alive\n\n");
+	}
+
+	#[rstest]
+	fn test_encode_without_data_omits_data_field() {
+		// Arrange
+		let event = SseEvent::default().retry(Duration::from_secs(1));
+
+		// Act & Assert
+		assert_eq!(event.encode(), "retry: 1000\n\n");
+	}
+
+	#[rstest]
+	#[case(Some("5"), None, Some("5"))]
+	#[case(None, Some("9"), Some("9"))]
+	#[case(Some("5"), Some("9"), Some("5"))]
+	#[case(None, None, None)]
+	fn test_last_event_id(
+		#[case] header: Option<&str>,
+		#[case] query: Option<&str>,
+		#[case] expected: Option<&str>,
+	) {
+		// Arrange
+		let uri = match query {
+			Some(id) => format!("/events?lastEventId={id}"),
+			None => "/events".to_owned(),
+		};
+		let mut builder = Request::builder().method(Method::GET).uri(uri.as_str());
+		if let Some(id) = header {
+			builder = builder.header("Last-Event-ID", id);
+		}
+		let request = builder.build().unwrap();
+
+		// Act
+		let id = last_event_id(&request);
+
+		// Assert
+		assert_eq!(id.as_deref(), expected);
+	}
+
+	#[tokio::test]
+	async fn test_sse_response_encodes_stream_with_retry_preamble() {
+		// Arrange
+		let events = stream::iter(vec![SseEvent::new("a").id("1"), SseEvent::new("b").id("2")]);
+
+		// Act
+		let response = SseResponse::new(events)
+			.retry(Duration::from_secs(3))
+			.into_streaming_response();
+		let chunks: Vec<Bytes> = response
+			.into_stream()
+			.map(|chunk| chunk.unwrap())
+			.collect()
+			.await;
+
+		// Assert
+		assert_eq!(
+			chunks,
+			vec![
+				Bytes::from("retry: 3000\n\n"),
+				Bytes::from("id: 1\ndata: a\n\n"),
+				Bytes::from("id: 2\ndata: b\n\n"),
+			]
+		);
+	}
+
+	#[tokio::test]
+	async fn test_sse_response_sets_streaming_headers() {
+		// Arrange
+		let events = stream::iter(Vec::<SseEvent>::new());
+
+		// Act
+		let response = SseResponse::new(events).into_streaming_response();
+
+		// Assert
+		assert_eq!(
+			response.headers.get(CONTENT_TYPE).unwrap(),
+			"text/event-stream"
+		);
+		assert_eq!(response.headers.get(CACHE_CONTROL).unwrap(), "no-cache");
+		assert_eq!(response.headers.get("x-accel-buffering").unwrap(), "no");
+	}
+
+	#[tokio::test(start_paused = true)]
+	async fn test_keep_alive_emits_comments_and_ends_with_events() {
+		// Arrange
+		let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<SseEvent>();
+		let events = stream::unfold(rx, |mut rx| async move { rx.recv().await.map(|e| (e, rx)) });
+		let mut body = SseResponse::new(events)
+			.keep_alive(Duration::from_secs(10))
+			.into_streaming_response()
+			.into_stream();
+
+		// Act
+		let first = body.next().await.unwrap().unwrap();
+		tx.send(SseEvent::new("done")).unwrap();
+		drop(tx);
+		let second = body.next().await.unwrap().unwrap();
+		let end = body.next().await;
+
+		// Assert
+		assert_eq!(first, Bytes::from(": keep-alive\n\n"));
+		assert_eq!(second, Bytes::from("data: done\n\n"));
+		assert!(end.is_none());
+	}
+}
@@ -141,6 +141,43 @@ impl MessageCatalog {
 		forms.get(index)
 	}
 
+	/// Iterate over the simple translations as `(message, translation)`
+	pub fn messages(&self) -> impl Iterator<Item = (&str, &str)> {
+		self.messages
+			.iter()
+			.map(|(message, translation)| (message.as_str(), translation.as_str()))
+	}
+
+	/// Iterate over the plural translations as `(singular, forms)`
+	pub fn plurals(&self) -> impl Iterator<Item = (&str, &[String])> {
+		self.plurals
+			.iter()
+			.map(|(singular, forms)| (singular.as_str(), forms.as_slice()))
+	}
+
+	/// Iterate over the contextual translations as `(context, message, translation)`
+	pub fn contexts(&self) -> impl Iterator<Item = (&str, &str, &str)> {
+		self.contexts
+			.iter()
+			.map(|((context, message), translation)| {
+				(context.as_str(), message.as_str(), translation.as_str())
+			})
+	}
+
+	/// Index of the plural form used for `count` in this catalog's locale
+	///
+	/// # Example
+	/// ```
+	/// use reinhardt_i18n::MessageCatalog;
+	///
+	/// assert_eq!(MessageCatalog::new("en").plural_index(1), 0);
+	/// assert_eq!(MessageCatalog::new("fr").plural_index(0), 0);
+	/// assert_eq!(MessageCatalog::new("ru").plural_index(5), 2);
+	/// ```
+	pub fn plural_index(&self, count: usize) -> usize {
+		self.plural_form(count)
+	}
+
 	/// Determine the plural form index for a given count
 	///
 	/// Uses language-specific plural rules based on locale. Supports:
@@ -0,0 +1,312 @@
+//! Locale-aware humanize helpers
+//!
+//! Wraps [`reinhardt_utils::humanize`] so that its output follows the active
+//! translation context: messages such as `"{count} minutes ago"` go through
+//! the active catalog, and number separators follow the active locale.
+//! Ordinals use Django's `ordinal N` message contexts, and the date format of
+//! [`naturalday`] is translated through the `%b %d, %Y` msgid.
+//!
+//! The functions return plain strings, so they can be called directly from
+//! `page!` templates (`{ naturaltime(&post.created_at) }`). With the `tera`
+//! feature, [`register_filters`] exposes them as Tera filters as well.
+//!
+//! # Example
+//!
+//! ```
+//! use reinhardt_i18n::humanize::{intcomma, ordinal};
+//! use reinhardt_i18n::{MessageCatalog, TranslationContext, set_active_translation};
+//! use std::sync::Arc;
+//!
+//! let mut ctx = TranslationContext::new("de", "en-US");
+//! let mut catalog = MessageCatalog::new("de");
+//! catalog.add_context("ordinal 1", "{count}st", "{count}.");
+//! ctx.add_catalog("de", catalog).unwrap();
+//! let _guard = set_active_translation(Arc::new(ctx));
+//!
+//! assert_eq!(intcomma(1_234_567), "1.234.567");
+//! assert_eq!(ordinal(1), "1.");
+//! ```
+
+use chrono::{DateTime, Utc};
+use reinhardt_utils::humanize::{self as base, COUNT_PLACEHOLDER, HumanizeLocale};
+
+use crate::{get_locale, gettext, ngettext, pgettext};
+
+/// Thousands and decimal separators per language
+const SEPARATORS: &[(&[&str], &str, &str)] = &[
+	(
+		&["de", "es", "it", "nl", "pt", "id", "tr", "da", "el"],
+		".",
+		",",
+	),
+	(
+		&["fr", "ru", "pl", "cs", "sv", "nb", "fi", "uk", "sk"],
+		"\u{a0}",
+		",",
+	),
+];
+
+/// [`HumanizeLocale`] backed by the active translation context
+///
+/// Captures the separators and date format of the locale active when it is
+/// created.
+#[derive(Debug, Clone)]
+pub struct ActiveLocale {
+	thousands_separator: &'static str,
+	decimal_separator: &'static str,
+	date_format: String,
+}
+
+impl ActiveLocale {
+	/// Create a locale for the currently active language
+	pub fn new() -> Self {
+		let locale = get_locale();
+		let language = locale
+			.split(['-', '_'])
+			.next()
+			.unwrap_or_default()
+			.to_ascii_lowercase();
+		let (thousands_separator, decimal_separator) = SEPARATORS
+			.iter()
+			.find(|(languages, ..)| languages.contains(&language.as_str()))
+			.map(|(_, thousands, decimal)| (*thousands, *decimal))
+			.unwrap_or((",", "."));
+		Self {
+			thousands_separator,
+			decimal_separator,
+			date_format: gettext("%b %d, %Y"),
+		}
+	}
+}
+
+impl Default for ActiveLocale {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl HumanizeLocale for ActiveLocale {
+	fn gettext(&self, message: &str) -> String {
+		gettext(message)
+	}
+
+	fn ngettext(&self, singular: &str, plural: &str, count: u64) -> String {
+		ngettext(singular, plural, count as usize)
+	}
+
+	fn thousands_separator(&self) -> &str {
+		self.thousands_separator
+	}
+
+	fn decimal_separator(&self) -> &str {
+		self.decimal_separator
+	}
+
+	fn date_format(&self) -> &str {
+		&self.date_format
+	}
+
+	fn ordinal(&self, n: i64) -> String {
+		// Same contexts as Django, so existing catalogs can be reused.
+		let (context, suffix) = match n.unsigned_abs() % 100 {
+			11..=13 => ("ordinal 11, 12, 13", "{count}th"),
+			last => match last % 10 {
+				1 => ("ordinal 1", "{count}st"),
+				2 => ("ordinal 2", "{count}nd"),
+				3 => ("ordinal 3", "{count}rd"),
+				_ => ("ordinal 0", "{count}th"),
+			},
+		};
+		pgettext(context, suffix).replace(COUNT_PLACEHOLDER, &n.to_string())
+	}
+}
+
+/// Format a datetime as natural language in the active locale
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Duration, Utc};
+/// use reinhardt_i18n::humanize::naturaltime;
+///
+/// assert_eq!(naturaltime(&(Utc::now() - Duration::hours(2))), "2 hours ago");
+/// ```
+pub fn naturaltime(dt: &DateTime<Utc>) -> String {
+	base::naturaltime_with(dt, &ActiveLocale::new())
+}
+
+/// Format a date as "today", "yesterday", "tomorrow", or the date in the active locale
+pub fn naturalday(dt: &DateTime<Utc>) -> String {
+	base::naturalday_with(dt, &ActiveLocale::new())
+}
+
+/// Add thousands separators to a number in the active locale
+pub fn intcomma(n: i64) -> String {
+	base::intcomma_with(n, &ActiveLocale::new())
+}
+
+/// Ordinal form of a number in the active locale
+pub fn ordinal(n: i64) -> String {
+	base::ordinal_with(n, &ActiveLocale::new())
+}
+
+/// Format a file size in the active locale
+pub fn filesizeformat(bytes: u64) -> String {
+	base::filesizeformat_with(bytes, &ActiveLocale::new())
+}
+
+/// Register the humanize filters on a Tera instance
+///
+/// Adds `naturaltime`, `naturalday`, `intcomma`, `ordinal` and
+/// `filesizeformat`. Date filters accept RFC 3339 strings or Unix timestamps.
+///
+/// # Example
+///
+/// ```
+/// use tera::{Context, Tera};
+///
+/// let mut tera = Tera::default();
+/// reinhardt_i18n::humanize::register_filters(&mut tera);
+/// tera.add_raw_template("size", "{{ bytes | filesizeformat }}").unwrap();
+///
+/// let mut context = Context::new();
+/// context.insert("bytes", &2048);
+/// assert_eq!(tera.render("size", &context).unwrap(), "2.0 KB");
+/// ```
+#[cfg(feature = "tera")]
+pub fn register_filters(tera: &mut tera::Tera) {
+	use std::collections::HashMap;
+	use tera::Value;
+
+	fn integer(name: &str, value: &Value) -> tera::Result<i64> {
+		value
+			.as_i64()
+			.ok_or_else(|| tera::Error::msg(format!("filter `{}` expects an integer", name)))
+	}
+
+	fn datetime(name: &str, value: &Value) -> tera::Result<DateTime<Utc>> {
+		let parsed = match value {
+			Value::String(s) => DateTime::parse_from_rfc3339(s)
+				.ok()
+				.map(|dt| dt.with_timezone(&Utc)),
+			Value::Number(n) => n.as_i64().and_then(|ts| DateTime::from_timestamp(ts, 0)),
+			_ => None,
+		};
+		parsed.ok_or_else(|| {
+			tera::Error::msg(format!(
+				"filter `{}` expects an RFC 3339 string or a Unix timestamp",
+				name
+			))
+		})
+	}
+
+	tera.register_filter(
+		"naturaltime",
+		|value: &Value, _: &HashMap<String, Value>| {
+			Ok(Value::String(naturaltime(&datetime("naturaltime", value)?)))
+		},
+	);
+	tera.register_filter("naturalday", |value: &Value, _: &HashMap<String, Value>| {
+		Ok(Value::String(naturalday(&datetime("naturalday", value)?)))
+	});
+	tera.register_filter("intcomma", |value: &Value, _: &HashMap<String, Value>| {
+		Ok(Value::String(intcomma(integer("intcomma", value)?)))
+	});
+	tera.register_filter("ordinal", |value: &Value, _: &HashMap<String, Value>| {
+		Ok(Value::String(ordinal(integer("ordinal", value)?)))
+	});
+	tera.register_filter(
+		"filesizeformat",
+		|value: &Value, _: &HashMap<String, Value>| {
+			let bytes = value.as_u64().ok_or_else(|| {
+				tera::Error::msg("filter `filesizeformat` expects a non-negative integer")
+			})?;
+			Ok(Value::String(filesizeformat(bytes)))
+		},
+	);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{MessageCatalog, TranslationContext, set_active_translation};
+	use chrono::Duration;
+	use rstest::rstest;
+	use std::sync::Arc;
+
+	fn activate_catalog(locale: &str, catalog: MessageCatalog) -> crate::TranslationGuard {
+		let mut ctx = TranslationContext::new(locale, "en-US");
+		ctx.add_catalog(locale, catalog).unwrap();
+		set_active_translation(Arc::new(ctx))
+	}
+
+	#[rstest]
+	#[case("en-US", "1,234,567", "1.5 KB")]
+	#[case("de-DE", "1.234.567", "1,5 KB")]
+	#[case("fr", "1\u{a0}234\u{a0}567", "1,5 KB")]
+	fn test_separators_follow_active_locale(
+		#[case] locale: &str,
+		#[case] expected_int: &str,
+		#[case] expected_size: &str,
+	) {
+		// Arrange
+		let _guard = activate_catalog(locale, MessageCatalog::new(locale));
+
+		// Act / Assert
+		assert_eq!(intcomma(1_234_567), expected_int);
+		assert_eq!(filesizeformat(1536), expected_size);
+	}
+
+	#[rstest]
+	#[case(1, "1st")]
+	#[case(2, "2nd")]
+	#[case(3, "3rd")]
+	#[case(11, "11th")]
+	#[case(112, "112th")]
+	#[case(21, "21st")]
+	fn test_ordinal_defaults_to_english(#[case] n: i64, #[case] expected: &str) {
+		// Act / Assert
+		assert_eq!(ordinal(n), expected);
+	}
+
+	#[rstest]
+	fn test_messages_use_active_catalog() {
+		// Arrange
+		let mut catalog = MessageCatalog::new("ja");
+		catalog.add_plural(
+			"{count} hour ago",
+			vec!["{count}時間前".to_string(), "{count}時間前".to_string()],
+		);
+		catalog.add("today", "今日");
+		catalog.add_context("ordinal 0", "{count}th", "第{count}");
+		let _guard = activate_catalog("ja", catalog);
+		let now = Utc::now();
+
+		// Act / Assert
+		assert_eq!(naturaltime(&(now - Duration::hours(3))), "3時間前");
+		assert_eq!(naturalday(&now), "今日");
+		assert_eq!(ordinal(4), "第4");
+	}
+
+	#[cfg(feature = "tera")]
+	#[rstest]
+	fn test_register_filters() {
+		// Arrange
+		let mut tera = tera::Tera::default();
+		register_filters(&mut tera);
+		tera.add_raw_template(
+			"t",
+			"{{ n | intcomma }} {{ n | ordinal }} {{ ts | naturalday }}",
+		)
+		.unwrap();
+		let mut context = tera::Context::new();
+		context.insert("n", &1001);
+		context.insert("ts", &Utc::now().timestamp());
+
+		// Act
+		let rendered = tera.render("t", &context).unwrap();
+
+		// Assert
+		assert_eq!(rendered, "1,001 1001st today");
+	}
+}
@@ -7,6 +7,7 @@
 //! - Context-aware translations
 //! - Lazy translation evaluation
 //! - Message catalog management
+//! - Locale-aware humanize helpers ([`humanize`])
 //!
 //! # Example
 //!
@@ -67,6 +68,7 @@ pub enum I18nError {
 }
 
 mod catalog;
+pub mod humanize;
 mod lazy;
 mod locale;
 /// PO (Portable Object) file parser for gettext catalogs.
@@ -79,7 +81,9 @@ pub use catalog::MessageCatalog;
 pub use lazy::LazyString;
 use locale::validate_locale;
 pub use locale::{activate, activate_with_catalog, deactivate, get_locale};
-pub use translation::{gettext, gettext_lazy, ngettext, ngettext_lazy, npgettext, pgettext};
+pub use translation::{
+	gettext, gettext_lazy, install_choice_translator, ngettext, ngettext_lazy, npgettext, pgettext,
+};
 
 // Re-export get_locale as get_language for compatibility
 pub use locale::get_locale as get_language;
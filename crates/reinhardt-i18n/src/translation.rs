@@ -212,6 +212,16 @@ pub fn ngettext_lazy(singular: &str, plural: &str, count: usize) -> LazyString {
 	LazyString::new_plural(singular.to_string(), plural.to_string(), count, None)
 }
 
+/// Translate choice labels with [`gettext`]
+///
+/// Installs the translator behind `Choices::display()` and the other label
+/// helpers of `reinhardt_core::choices`, so enum labels shown in forms,
+/// serializers and the admin follow the active language. Call once at
+/// startup; returns `false` if a translator was already installed.
+pub fn install_choice_translator() -> bool {
+	reinhardt_core::choices::set_label_translator(gettext)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
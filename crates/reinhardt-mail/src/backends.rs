@@ -15,6 +15,7 @@ use lettre::transport::smtp::client::{Tls, TlsParameters};
 use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
 use reinhardt_conf::settings::email::EmailSettings;
 use reinhardt_conf::settings::fragment::HasSettings;
+use reinhardt_utils::circuit_breaker::{CircuitBreaker, CircuitBreakerError};
 use std::path::Path;
 use std::time::Duration;
 use zeroize::Zeroize;
@@ -440,13 +441,26 @@ impl Drop for SmtpConfig {
 /// ```
 pub struct SmtpBackend {
 	config: SmtpConfig,
+	circuit_breaker: Option<CircuitBreaker>,
 }
 
 impl SmtpBackend {
 	/// Creates a new SMTP backend with the given configuration.
 	pub fn new(config: SmtpConfig) -> EmailResult<Self> {
 		config.validate()?;
-		Ok(Self { config })
+		Ok(Self {
+			config,
+			circuit_breaker: None,
+		})
+	}
+
+	/// Guards sends with a circuit breaker keyed by the SMTP host.
+	///
+	/// While the host's circuit is open, sending fails with
+	/// [`EmailError::CircuitOpen`] without connecting to the server.
+	pub fn with_circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> Self {
+		self.circuit_breaker = Some(circuit_breaker);
+		self
 	}
 
 	fn build_transport(&self) -> EmailResult<AsyncSmtpTransport<Tokio1Executor>> {
@@ -720,11 +734,25 @@ impl EmailBackend for SmtpBackend {
 		let mut sent_count = 0;
 		for email in messages {
 			let message = self.build_message(email)?;
+			let send = async {
+				transport
+					.send(message)
+					.await
+					.map_err(|e| EmailError::SmtpError(format!("Failed to send email: {}", e)))
+			};
 
-			transport
-				.send(message)
-				.await
-				.map_err(|e| EmailError::SmtpError(format!("Failed to send email: {}", e)))?;
+			match &self.circuit_breaker {
+				Some(circuit_breaker) => circuit_breaker
+					.call(&self.config.host, send)
+					.await
+					.map_err(|e| match e {
+						CircuitBreakerError::Open(open) => {
+							EmailError::CircuitOpen(open.to_string())
+						}
+						CircuitBreakerError::Inner(e) => e,
+					})?,
+				None => send.await?,
+			};
 
 			sent_count += 1;
 		}
@@ -759,4 +787,32 @@ mod tests {
 		assert!(matches!(config.security, SmtpSecurity::StartTls));
 		assert_eq!(config.timeout, Duration::from_secs(45));
 	}
+
+	#[tokio::test]
+	async fn smtp_send_fails_fast_when_circuit_open() {
+		// Arrange
+		use reinhardt_utils::circuit_breaker::CircuitBreakerPolicy;
+
+		let breaker = CircuitBreaker::in_memory(
+			"smtp",
+			CircuitBreakerPolicy::default().with_minimum_calls(1),
+		);
+		breaker.record_failure("smtp.example.com").await;
+		let backend = SmtpBackend::new(SmtpConfig::new("smtp.example.com", 587))
+			.unwrap()
+			.with_circuit_breaker(breaker);
+		let message = EmailMessage::builder()
+			.from("sender@example.com")
+			.to(vec!["recipient@example.com".to_string()])
+			.subject("Hello")
+			.body("Body")
+			.build()
+			.unwrap();
+
+		// Act
+		let result = backend.send_messages(&[message]).await;
+
+		// Assert
+		assert!(matches!(result, Err(EmailError::CircuitOpen(_))));
+	}
 }
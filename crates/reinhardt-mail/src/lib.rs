@@ -259,6 +259,10 @@ pub enum EmailError {
 	/// A header injection attack was detected in the input.
 	#[error("Header injection attempt detected: {0}")]
 	HeaderInjection(String),
+
+	/// The SMTP server's circuit breaker is open, so nothing was sent.
+	#[error("Circuit open: {0}")]
+	CircuitOpen(String),
 }
 
 impl EmailError {
@@ -269,7 +273,10 @@ impl EmailError {
 	/// are never considered transient and will always propagate even when
 	/// fail_silently is enabled.
 	pub fn is_transient(&self) -> bool {
-		matches!(self, EmailError::IoError(_) | EmailError::SmtpError(_))
+		matches!(
+			self,
+			EmailError::IoError(_) | EmailError::SmtpError(_) | EmailError::CircuitOpen(_)
+		)
 	}
 }
 
@@ -62,6 +62,12 @@ pub struct FormMacro {
 	pub method: Option<Ident>,
 	/// Form-level CSS class
 	pub class: Option<LitStr>,
+	/// HTML-over-the-wire target selector (`wire: "#selector"`)
+	///
+	/// Without WASM, the wire runtime submits the form with `fetch` and
+	/// applies the fragments of the response. An empty selector leaves the
+	/// targets to the server.
+	pub wire: Option<LitStr>,
 	/// UI state configuration (loading, error, success signals)
 	pub state: Option<FormState>,
 	/// Form submission callbacks
@@ -1301,6 +1307,7 @@ impl FormMacro {
 			action: FormAction::None,
 			method: None,
 			class: None,
+			wire: None,
 			state: None,
 			callbacks: FormCallbacks::new(),
 			watch: None,
@@ -90,6 +90,8 @@ pub struct TypedFormMacro {
 	pub method: FormMethod,
 	/// Form-level styling configuration
 	pub styling: TypedFormStyling,
+	/// HTML-over-the-wire target selector, rendered as `data-rh-wire`
+	pub wire: Option<String>,
 	/// UI state configuration (loading, error, success signals)
 	pub state: Option<TypedFormState>,
 	/// Validated form submission callbacks
@@ -622,6 +624,8 @@ pub struct TypedFormFieldDef {
 	pub choices_config: Option<TypedChoicesConfig>,
 	/// Static choice options for select-like widgets.
 	pub static_choices: Vec<TypedChoiceItem>,
+	/// Stylesheets and scripts the field's widget requires
+	pub media: TypedFieldMedia,
 	/// Span for error reporting
 	pub span: Span,
 }
@@ -1356,6 +1360,9 @@ pub struct TypedFieldNativeAttrs {
 /// | `wrapper_class` | `"reinhardt-field"` | The field container div |
 /// | `label_class` | `"reinhardt-label"` | The label element |
 /// | `error_class` | `"reinhardt-error"` | The error message element |
+///
+/// The defaults are those of the default form renderer; generated code asks
+/// the installed renderer for classes that are not set.
 #[derive(Debug, Clone, Default)]
 pub struct TypedFieldStyling {
 	/// CSS class for the input element
@@ -1390,6 +1397,28 @@ impl TypedFieldStyling {
 	}
 }
 
+/// CSS and JavaScript assets declared on a field.
+///
+/// Set with the `css` and `js` properties, each taking a string literal or an
+/// array of string literals:
+///
+/// ```text
+/// booked_on: DateField {
+///     css: "/static/css/datepicker.css",
+///     js: ["/static/js/jquery.js", "/static/js/datepicker.js"],
+/// }
+/// ```
+///
+/// The generated form merges the assets of all fields into one deduplicated
+/// `Media` value.
+#[derive(Debug, Clone, Default)]
+pub struct TypedFieldMedia {
+	/// Stylesheet URLs in declaration order
+	pub css: Vec<String>,
+	/// Script URLs in declaration order
+	pub js: Vec<String>,
+}
+
 /// Typed server-side validator for a specific field.
 #[derive(Debug)]
 pub struct TypedFormValidator {
@@ -1688,6 +1717,7 @@ impl TypedFormMacro {
 			action,
 			method: FormMethod::default(),
 			styling: TypedFormStyling::default(),
+			wire: None,
 			state: None,
 			callbacks: TypedFormCallbacks::new(),
 			watch: None,
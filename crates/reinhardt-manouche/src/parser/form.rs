@@ -117,6 +117,10 @@ impl Parse for FormMacro {
 					form.class = Some(input.parse()?);
 					parse_optional_comma(input)?;
 				}
+				"wire" => {
+					form.wire = Some(input.parse()?);
+					parse_optional_comma(input)?;
+				}
 				"state" => {
 					let content;
 					braced!(content in input);
@@ -263,7 +267,7 @@ impl Parse for FormMacro {
 					return Err(syn::Error::new(
 						key.span(),
 						format!(
-							"Unknown form property: '{}'. Expected: name, action, server_fn, method, class, state, on_submit, on_success, on_success_ref, on_error, on_loading, watch, redirect_on_success, success_url, initial_loader, choices_loader, slots, fields, validators, derived, ambient_arguments, strip_arguments",
+							"Unknown form property: '{}'. Expected: name, action, server_fn, method, class, wire, state, on_submit, on_success, on_success_ref, on_error, on_loading, watch, redirect_on_success, success_url, initial_loader, choices_loader, slots, fields, validators, derived, ambient_arguments, strip_arguments",
 							key
 						),
 					));
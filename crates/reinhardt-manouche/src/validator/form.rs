@@ -23,7 +23,7 @@ use crate::core::{
 	FormSlots, FormState, FormSubmitButtonDef, FormValidator, FormWatch, FormWidgetSpec, IconAttr,
 	IconChild, IconPosition, StripArgument, TypedButtonControlDef, TypedButtonKind,
 	TypedChoiceGroup, TypedChoiceItem, TypedChoiceOption, TypedChoicesConfig, TypedCustomAttr,
-	TypedCustomWidget, TypedDatalistDef, TypedDerivedItem, TypedFieldDisplay,
+	TypedCustomWidget, TypedDatalistDef, TypedDerivedItem, TypedFieldDisplay, TypedFieldMedia,
 	TypedFieldNativeAttrs, TypedFieldStyling, TypedFieldType, TypedFieldValidation,
 	TypedFormAction, TypedFormCallbacks, TypedFormDerived, TypedFormFieldCollection,
 	TypedFormFieldDef, TypedFormFieldEntry, TypedFormFieldGroup, TypedFormMacro, TypedFormSlots,
@@ -66,6 +66,9 @@ pub fn validate_form_with_ambient_arguments_source(
 	// Transform form-level styling
 	let styling = transform_form_styling(ast)?;
 
+	// Transform wire mode target
+	let wire = transform_wire(ast)?;
+
 	// Transform state configuration
 	let state = transform_state(&ast.state)?;
 
@@ -123,6 +126,7 @@ pub fn validate_form_with_ambient_arguments_source(
 		action,
 		method,
 		styling,
+		wire,
 		state,
 		callbacks,
 		watch,
@@ -560,6 +564,24 @@ fn transform_derived(derived: &Option<FormDerived>) -> Result<Option<TypedFormDe
 	}))
 }
 
+/// Transforms the `wire` target selector.
+///
+/// Wire mode submits the form to its action URL, so it cannot be combined
+/// with a `server_fn` action.
+fn transform_wire(ast: &FormMacro) -> Result<Option<String>> {
+	let Some(wire) = &ast.wire else {
+		return Ok(None);
+	};
+	if matches!(ast.action, FormAction::ServerFn(_)) {
+		return Err(Error::new(
+			wire.span(),
+			"form! `wire` submits the form to its action URL and cannot be combined with \
+			 `server_fn`; use `action: \"/path\"` instead",
+		));
+	}
+	Ok(Some(wire.value().trim().to_string()))
+}
+
 /// Transforms redirect_on_success configuration.
 ///
 /// Validates that the redirect path:
@@ -1298,10 +1320,12 @@ const ALLOWED_FIELD_PROPERTIES: &[&str] = &[
 	"autofocus",
 	"capture",
 	"class",
+	"css",
 	"disabled",
 	"error_class",
 	"help_text",
 	"initial",
+	"js",
 	"label",
 	"label_class",
 	"list",
@@ -1376,6 +1400,7 @@ fn transform_field(field: &FormFieldDef) -> Result<TypedFormFieldDef> {
 	let bind = extract_bind(&field.properties);
 	let initial_from = extract_initial_from(&field.properties);
 	let initial_expr = extract_initial_expr(&field.properties);
+	let media = extract_media(&field.properties).map_err(&annotate)?;
 	let choices_config = extract_choices_config(&field.properties);
 	let static_choices_source = extract_static_choices(&field.properties).map_err(&annotate)?;
 
@@ -1431,6 +1456,7 @@ fn transform_field(field: &FormFieldDef) -> Result<TypedFormFieldDef> {
 		initial_expr,
 		choices_config,
 		static_choices,
+		media,
 		span: field.span,
 	})
 }
@@ -2546,6 +2572,33 @@ fn extract_initial_from(properties: &[FormFieldProperty]) -> Option<String> {
 	None
 }
 
+/// Extracts the `css` and `js` asset URLs declared on a field.
+fn extract_media(properties: &[FormFieldProperty]) -> Result<TypedFieldMedia> {
+	let mut media = TypedFieldMedia::default();
+
+	for prop in properties {
+		let FormFieldProperty::Named { name, value, span } = prop else {
+			continue;
+		};
+		let prop_name = name.to_string();
+		let assets = match prop_name.as_str() {
+			"css" => &mut media.css,
+			"js" => &mut media.js,
+			_ => continue,
+		};
+		match value {
+			syn::Expr::Array(array) => {
+				for elem in &array.elems {
+					assets.push(extract_string_value_from_expr(elem, &prop_name, *span)?);
+				}
+			}
+			_ => assets.push(extract_string_value_from_expr(value, &prop_name, *span)?),
+		}
+	}
+
+	Ok(media)
+}
+
 /// Extracts dynamic choices configuration from field properties.
 ///
 /// For `ChoiceField` with dynamic options loaded from a `choices_loader` server_fn.
@@ -2974,6 +3027,51 @@ mod tests {
 		assert!(matches!(typed.action, TypedFormAction::ServerFn(_)));
 	}
 
+	#[rstest]
+	fn test_validate_wire_target() {
+		// Arrange
+		let input = quote! {
+			name: CommentForm,
+			action: "/comments",
+			wire: "#comments",
+
+			fields: {
+				body: CharField { required },
+			},
+		};
+
+		// Act
+		let typed = parse_and_validate(input).unwrap();
+
+		// Assert
+		assert_eq!(typed.wire.as_deref(), Some("#comments"));
+	}
+
+	#[rstest]
+	fn test_validate_wire_rejects_server_fn() {
+		// Arrange
+		let input = quote! {
+			name: VoteForm,
+			server_fn: submit_vote,
+			wire: "#votes",
+
+			fields: {
+				choice_id: IntegerField { required },
+			},
+		};
+
+		// Act
+		let result = parse_and_validate(input);
+
+		// Assert
+		assert!(
+			result
+				.unwrap_err()
+				.to_string()
+				.contains("cannot be combined")
+		);
+	}
+
 	#[rstest]
 	fn test_validate_duplicate_field_names() {
 		// Arrange
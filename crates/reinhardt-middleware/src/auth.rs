@@ -11,7 +11,7 @@ use reinhardt_http::{
 #[cfg(feature = "sessions")]
 use reinhardt_auth::session::{SESSION_KEY_USER_ID, SessionStore};
 #[cfg(feature = "sessions")]
-use reinhardt_auth::{AuthBackend, AuthIdentity};
+use reinhardt_auth::{AuthBackend, AuthIdentity, LazyUser};
 
 /// Authentication middleware
 /// Extracts user information from session and attaches it to request extensions
@@ -111,6 +111,7 @@ use reinhardt_auth::{AuthBackend, AuthIdentity};
 pub struct AuthenticationMiddleware<S: SessionStore, A: AuthBackend> {
 	session_store: Arc<S>,
 	auth_backend: Arc<A>,
+	lazy: bool,
 }
 
 #[cfg(feature = "sessions")]
@@ -162,9 +163,27 @@ impl<S: SessionStore, A: AuthBackend> AuthenticationMiddleware<S, A> {
 		Self {
 			session_store,
 			auth_backend,
+			lazy: false,
 		}
 	}
 
+	/// Defer the user lookup until a handler first asks for the user.
+	///
+	/// In lazy mode the middleware only stores a [`LazyUser`] in the request
+	/// extensions; the session and backend are queried the first time
+	/// `LazyUser`, `CurrentUser` or `AuthInfo` is resolved, and the result is
+	/// cached for the rest of the request. Requests that never look at the
+	/// user skip the lookup entirely.
+	///
+	/// `AuthState` and the individual `IsAuthenticated` / `IsAdmin` /
+	/// `IsActive` values are not inserted up front in this mode, so code that
+	/// reads them directly from the extensions should call
+	/// [`reinhardt_auth::resolve_auth_state`] instead.
+	pub fn lazy(mut self, lazy: bool) -> Self {
+		self.lazy = lazy;
+		self
+	}
+
 	/// Extract session ID from cookies.
 	///
 	/// Validates that the session ID is non-empty and well-formed
@@ -199,18 +218,23 @@ impl<S: SessionStore, A: AuthBackend> AuthenticationMiddleware<S, A> {
 		// Validate UUID format (xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx)
 		uuid::Uuid::parse_str(id).is_ok()
 	}
+}
 
-	/// Get user from session
-	async fn get_user_from_session(&self, session_id: &String) -> Option<Box<dyn AuthIdentity>> {
-		if let Some(session) = self.session_store.load(session_id).await
-			&& let Some(user_id_value) = session.get(SESSION_KEY_USER_ID)
-			&& let Some(user_id) = user_id_value.as_str()
-			&& let Ok(Some(user)) = self.auth_backend.get_user(user_id).await
-		{
-			return Some(user);
-		}
-		None
+/// Get user from session
+#[cfg(feature = "sessions")]
+async fn get_user_from_session<S: SessionStore, A: AuthBackend>(
+	session_store: &S,
+	auth_backend: &A,
+	session_id: &String,
+) -> Option<Box<dyn AuthIdentity>> {
+	if let Some(session) = session_store.load(session_id).await
+		&& let Some(user_id_value) = session.get(SESSION_KEY_USER_ID)
+		&& let Some(user_id) = user_id_value.as_str()
+		&& let Ok(Some(user)) = auth_backend.get_user(user_id).await
+	{
+		return Some(user);
 	}
+	None
 }
 
 #[cfg(feature = "sessions")]
@@ -219,12 +243,28 @@ impl<S: SessionStore + 'static, A: AuthBackend + 'static> Middleware
 	for AuthenticationMiddleware<S, A>
 {
 	async fn process(&self, request: Request, next: Arc<dyn Handler>) -> Result<Response> {
-		let user: Option<Box<dyn AuthIdentity>> =
-			if let Some(ref session_id) = self.extract_session_id(&request) {
-				self.get_user_from_session(session_id).await
-			} else {
-				None
+		let session_id = self.extract_session_id(&request);
+
+		if self.lazy {
+			let lazy_user = match session_id {
+				Some(session_id) => {
+					let session_store = Arc::clone(&self.session_store);
+					let auth_backend = Arc::clone(&self.auth_backend);
+					LazyUser::new(async move {
+						get_user_from_session(&*session_store, &*auth_backend, &session_id).await
+					})
+				}
+				None => LazyUser::anonymous(),
 			};
+			request.extensions.insert(lazy_user);
+			return next.handle(request).await;
+		}
+
+		let user: Option<Box<dyn AuthIdentity>> = if let Some(ref session_id) = session_id {
+			get_user_from_session(&*self.session_store, &*self.auth_backend, session_id).await
+		} else {
+			None
+		};
 
 		let (user_id, is_authenticated, is_admin, is_active) = if let Some(ref user) = user {
 			(
@@ -250,6 +290,7 @@ impl<S: SessionStore + 'static, A: AuthBackend + 'static> Middleware
 			AuthState::anonymous()
 		};
 		request.extensions.insert(auth_state);
+		request.extensions.insert(LazyUser::resolved(user));
 
 		next.handle(request).await
 	}
@@ -445,6 +486,138 @@ mod tests {
 		assert!(body_str.contains("\"is_authenticated\":false"));
 	}
 
+	struct CountingAuthBackend {
+		user: TestUser,
+		lookups: Arc<std::sync::atomic::AtomicUsize>,
+	}
+
+	#[async_trait::async_trait]
+	impl AuthBackend for CountingAuthBackend {
+		async fn authenticate(
+			&self,
+			_request: &Request,
+		) -> std::result::Result<Option<Box<dyn AuthIdentity>>, AuthenticationError> {
+			Ok(None)
+		}
+
+		async fn get_user(
+			&self,
+			_user_id: &str,
+		) -> std::result::Result<Option<Box<dyn AuthIdentity>>, AuthenticationError> {
+			self.lookups
+				.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+			Ok(Some(Box::new(self.user.clone())))
+		}
+	}
+
+	/// Handler that reads the lazy user `reads` times.
+	struct LazyReadingHandler {
+		reads: usize,
+	}
+
+	#[async_trait]
+	impl Handler for LazyReadingHandler {
+		async fn handle(&self, request: Request) -> Result<Response> {
+			let lazy_user: LazyUser = request.extensions.get().unwrap();
+			let mut user_id = None;
+			for _ in 0..self.reads {
+				user_id = lazy_user.get().await.map(|user| user.id());
+			}
+			Ok(Response::ok().with_json(&serde_json::json!({
+				"loaded": lazy_user.is_loaded(),
+				"has_auth_state": request.extensions.contains::<AuthState>(),
+				"user_id": user_id,
+			}))?)
+		}
+	}
+
+	#[rstest::rstest]
+	#[case::untouched(0, 0)]
+	#[case::read_once(1, 1)]
+	#[case::read_repeatedly(3, 1)]
+	#[tokio::test]
+	async fn test_lazy_auth_middleware_defers_and_caches_lookup(
+		#[case] reads: usize,
+		#[case] expected_lookups: usize,
+	) {
+		// Arrange
+		let session_store = Arc::new(InMemorySessionStore::new());
+		let user = TestUser {
+			id: Uuid::now_v7(),
+			is_admin: false,
+			is_active: true,
+		};
+		let lookups = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+		let auth_backend = Arc::new(CountingAuthBackend {
+			user: user.clone(),
+			lookups: lookups.clone(),
+		});
+		let session_id = session_store.create_session_id();
+		let mut session = Session::new();
+		session.set(SESSION_KEY_USER_ID, serde_json::json!("user123"));
+		session_store.save(&session_id, &session).await;
+		let middleware = AuthenticationMiddleware::new(session_store, auth_backend).lazy(true);
+		let mut headers = HeaderMap::new();
+		headers.insert(
+			"cookie",
+			format!("sessionid={}", session_id).parse().unwrap(),
+		);
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/test")
+			.version(Version::HTTP_11)
+			.headers(headers)
+			.body(Bytes::new())
+			.build()
+			.unwrap();
+
+		// Act
+		let response = middleware
+			.process(request, Arc::new(LazyReadingHandler { reads }))
+			.await
+			.unwrap();
+
+		// Assert
+		let body: serde_json::Value = serde_json::from_slice(response.body.as_ref()).unwrap();
+		assert_eq!(
+			lookups.load(std::sync::atomic::Ordering::SeqCst),
+			expected_lookups
+		);
+		assert_eq!(body["loaded"], reads > 0);
+		assert_eq!(body["has_auth_state"], false);
+		if reads > 0 {
+			assert_eq!(body["user_id"], user.id.to_string());
+		}
+	}
+
+	#[rstest::rstest]
+	#[tokio::test]
+	async fn test_eager_auth_middleware_stores_resolved_lazy_user() {
+		// Arrange
+		let session_store = Arc::new(InMemorySessionStore::new());
+		let auth_backend = Arc::new(TestAuthBackend { user: None });
+		let middleware = AuthenticationMiddleware::new(session_store, auth_backend);
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/test")
+			.version(Version::HTTP_11)
+			.headers(HeaderMap::new())
+			.body(Bytes::new())
+			.build()
+			.unwrap();
+
+		// Act
+		let response = middleware
+			.process(request, Arc::new(LazyReadingHandler { reads: 0 }))
+			.await
+			.unwrap();
+
+		// Assert
+		let body: serde_json::Value = serde_json::from_slice(response.body.as_ref()).unwrap();
+		assert_eq!(body["loaded"], true);
+		assert_eq!(body["has_auth_state"], true);
+	}
+
 	#[test]
 	fn test_auth_state_from_extensions() {
 		let extensions = reinhardt_http::Extensions::new();
@@ -3,6 +3,9 @@
 //! Compresses response content using Brotli encoding when the client supports it.
 //! Brotli typically provides better compression ratios than gzip while maintaining
 //! similar compression speeds.
+//!
+//! To negotiate between gzip, brotli and zstd, use
+//! [`CompressionMiddleware`](crate::CompressionMiddleware) instead.
 
 use async_trait::async_trait;
 use brotli::enc::BrotliEncoderParams;
@@ -23,7 +26,7 @@ pub enum BrotliQuality {
 }
 
 impl BrotliQuality {
-	fn to_value(self) -> u32 {
+	pub(crate) fn to_value(self) -> u32 {
 		match self {
 			BrotliQuality::Fast => 1,
 			BrotliQuality::Balanced => 6,
@@ -2,16 +2,112 @@
 //!
 //! Provides caching for HTTP responses.
 //! Supports various cache backends (memory, Redis, file).
-
+//!
+//! ## Full-page caching
+//!
+//! Cached pages are keyed by the request URL and by the values of the request
+//! headers listed in the response's `Vary` header, so one URL can hold several
+//! variants (e.g. per `Accept-Language` or `Cookie`). Keys can additionally be
+//! prefixed per site (`Host`) and per language (the `X-Locale` header set by
+//! [`LocaleMiddleware`](crate::locale::LocaleMiddleware)).
+//!
+//! A view opts out of caching with [`add_never_cache_headers`], and labels a
+//! page with [`add_cache_tags`] so it can later be purged with
+//! [`CacheStore::purge_tag`]. Both purge APIs are synchronous, so they can be
+//! called from signal receivers when content changes:
+//!
+//! ```rust,ignore
+//! let store = cache_middleware.store_arc();
+//! post_save::<Article>().connect(move |article: Arc<Article>| {
+//!     let store = Arc::clone(&store);
+//!     async move {
+//!         store.purge_url(&format!("/articles/{}/", article.id));
+//!         store.purge_tag("article-list");
+//!         Ok(())
+//!     }
+//! });
+//! ```
+
+use crate::locale::LOCALE_HEADER;
 use async_trait::async_trait;
-use hyper::StatusCode;
-use reinhardt_http::{Handler, Middleware, Request, Response, Result};
+use hyper::header::{AUTHORIZATION, CACHE_CONTROL, HOST, HeaderValue, SET_COOKIE, VARY};
+use hyper::{HeaderMap, StatusCode};
+use reinhardt_http::{AuthState, Handler, Middleware, Request, Response, Result};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 
+/// Response header listing the tags of a cached page, comma-separated
+///
+/// The header is consumed by [`CacheMiddleware`] and not sent to clients.
+pub const CACHE_TAGS_HEADER: &str = "X-Cache-Tags";
+
+/// Mark a response as never cacheable
+///
+/// Sets `Cache-Control: no-cache, no-store, must-revalidate, private`, which
+/// [`CacheMiddleware`] honors as a per-view opt-out.
+///
+/// # Examples
+///
+/// ```
+/// use hyper::StatusCode;
+/// use reinhardt_http::Response;
+/// use reinhardt_middleware::cache::add_never_cache_headers;
+///
+/// let mut response = Response::new(StatusCode::OK);
+/// add_never_cache_headers(&mut response);
+/// assert!(response.headers.contains_key("cache-control"));
+/// ```
+pub fn add_never_cache_headers(response: &mut Response) {
+	response.headers.insert(
+		CACHE_CONTROL,
+		HeaderValue::from_static("no-cache, no-store, must-revalidate, private"),
+	);
+}
+
+/// Tag a response so that its cached copy can be purged by tag
+///
+/// Tags are appended to any already set on the response.
+///
+/// # Examples
+///
+/// ```
+/// use hyper::StatusCode;
+/// use reinhardt_http::Response;
+/// use reinhardt_middleware::cache::{CACHE_TAGS_HEADER, add_cache_tags};
+///
+/// let mut response = Response::new(StatusCode::OK);
+/// add_cache_tags(&mut response, &["articles", "article-42"]);
+/// assert_eq!(response.headers.get(CACHE_TAGS_HEADER).unwrap(), "articles, article-42");
+/// ```
+pub fn add_cache_tags(response: &mut Response, tags: &[&str]) {
+	let mut all = response_tags(response);
+	for tag in tags {
+		if !all.iter().any(|t| t == tag) {
+			all.push(tag.to_string());
+		}
+	}
+	if let Ok(value) = HeaderValue::from_str(&all.join(", ")) {
+		response.headers.insert(CACHE_TAGS_HEADER, value);
+	}
+}
+
+/// Tags set on a response with [`add_cache_tags`]
+fn response_tags(response: &Response) -> Vec<String> {
+	response
+		.headers
+		.get_all(CACHE_TAGS_HEADER)
+		.iter()
+		.filter_map(|value| value.to_str().ok())
+		.flat_map(|value| value.split(','))
+		.map(str::trim)
+		.filter(|tag| !tag.is_empty())
+		.map(str::to_string)
+		.collect()
+}
+
 /// Cache Entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheEntry {
@@ -26,6 +122,12 @@ pub struct CacheEntry {
 	cached_at: Option<Instant>,
 	/// TTL (seconds)
 	ttl_secs: u64,
+	/// Request path the entry was cached for
+	#[serde(default)]
+	path: String,
+	/// Tags used for purging
+	#[serde(default)]
+	tags: Vec<String>,
 }
 
 impl CacheEntry {
@@ -44,9 +146,18 @@ impl CacheEntry {
 			body: response.body.to_vec(),
 			cached_at: Some(Instant::now()),
 			ttl_secs: ttl.as_secs(),
+			path: String::new(),
+			tags: Vec::new(),
 		}
 	}
 
+	/// Set the request path and tags used for purging
+	fn for_page(mut self, path: &str, tags: Vec<String>) -> Self {
+		self.path = path.to_string();
+		self.tags = tags;
+		self
+	}
+
 	/// Check if expired
 	fn is_expired(&self) -> bool {
 		if let Some(cached_at) = self.cached_at {
@@ -84,6 +195,8 @@ impl CacheEntry {
 pub struct CacheStore {
 	/// Entries
 	entries: RwLock<HashMap<String, CacheEntry>>,
+	/// Request headers named by the `Vary` header of each cached URL
+	vary: RwLock<HashMap<String, Vec<String>>>,
 }
 
 impl CacheStore {
@@ -120,6 +233,62 @@ impl CacheStore {
 	pub fn clear(&self) {
 		let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
 		entries.clear();
+		self.vary.write().unwrap_or_else(|e| e.into_inner()).clear();
+	}
+
+	/// Remove every cached variant of a URL
+	///
+	/// Any query string in `url` is ignored; variants cached for all query
+	/// strings, sites and languages are removed. Returns the number of
+	/// removed entries.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::cache::CacheStore;
+	///
+	/// let store = CacheStore::new();
+	/// assert_eq!(store.purge_url("/articles/"), 0);
+	/// ```
+	pub fn purge_url(&self, url: &str) -> usize {
+		let path = url.split(['?', '#']).next().unwrap_or(url);
+		self.purge_where(|entry| entry.path == path)
+	}
+
+	/// Remove every cached page tagged with `tag`
+	///
+	/// Returns the number of removed entries.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::cache::CacheStore;
+	///
+	/// let store = CacheStore::new();
+	/// assert_eq!(store.purge_tag("articles"), 0);
+	/// ```
+	pub fn purge_tag(&self, tag: &str) -> usize {
+		self.purge_where(|entry| entry.tags.iter().any(|t| t == tag))
+	}
+
+	/// Remove entries matching `predicate`, returning how many were removed
+	fn purge_where(&self, predicate: impl Fn(&CacheEntry) -> bool) -> usize {
+		let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+		let before = entries.len();
+		entries.retain(|_, entry| !predicate(entry));
+		before - entries.len()
+	}
+
+	/// Get the request headers a cached URL varies on
+	pub(crate) fn vary_headers(&self, base_key: &str) -> Option<Vec<String>> {
+		let vary = self.vary.read().unwrap_or_else(|e| e.into_inner());
+		vary.get(base_key).cloned()
+	}
+
+	/// Record the request headers a cached URL varies on
+	fn set_vary_headers(&self, base_key: String, headers: Vec<String>) {
+		let mut vary = self.vary.write().unwrap_or_else(|e| e.into_inner());
+		vary.insert(base_key, headers);
 	}
 
 	/// Get the number of entries
@@ -164,6 +333,17 @@ pub struct CacheConfig {
 	pub exclude_paths: Vec<String>,
 	/// Maximum cache size
 	pub max_entries: Option<usize>,
+	/// Prefix added to every cache key
+	pub key_prefix: String,
+	/// Separate cache entries per site (request `Host` header)
+	pub per_site: bool,
+	/// Separate cache entries per language (`X-Locale` request header)
+	pub per_language: bool,
+	/// Only cache pages for anonymous requests
+	///
+	/// Requests with an authenticated [`AuthState`] or an `Authorization`
+	/// header bypass the cache entirely.
+	pub anonymous_only: bool,
 }
 
 impl CacheConfig {
@@ -186,6 +366,10 @@ impl CacheConfig {
 			cacheable_status_codes: vec![200, 203, 204, 206, 300, 301, 404, 405, 410, 414, 501],
 			exclude_paths: Vec::new(),
 			max_entries: Some(1000),
+			key_prefix: String::new(),
+			per_site: false,
+			per_language: false,
+			anonymous_only: false,
 		}
 	}
 
@@ -236,6 +420,78 @@ impl CacheConfig {
 		self.max_entries = Some(max_entries);
 		self
 	}
+
+	/// Set the prefix added to every cache key
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use std::time::Duration;
+	/// use reinhardt_middleware::cache::{CacheConfig, CacheKeyStrategy};
+	///
+	/// let config = CacheConfig::new(Duration::from_secs(300), CacheKeyStrategy::UrlOnly)
+	///     .with_key_prefix("v2");
+	/// assert_eq!(config.key_prefix, "v2");
+	/// ```
+	pub fn with_key_prefix(mut self, prefix: impl Into<String>) -> Self {
+		self.key_prefix = prefix.into();
+		self
+	}
+
+	/// Keep separate cache entries per site
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use std::time::Duration;
+	/// use reinhardt_middleware::cache::{CacheConfig, CacheKeyStrategy};
+	///
+	/// let config = CacheConfig::new(Duration::from_secs(300), CacheKeyStrategy::UrlOnly)
+	///     .with_per_site();
+	/// assert!(config.per_site);
+	/// ```
+	pub fn with_per_site(mut self) -> Self {
+		self.per_site = true;
+		self
+	}
+
+	/// Keep separate cache entries per language
+	///
+	/// The language is read from the `X-Locale` header set by
+	/// [`LocaleMiddleware`](crate::locale::LocaleMiddleware), which must run
+	/// before this middleware.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use std::time::Duration;
+	/// use reinhardt_middleware::cache::{CacheConfig, CacheKeyStrategy};
+	///
+	/// let config = CacheConfig::new(Duration::from_secs(300), CacheKeyStrategy::UrlOnly)
+	///     .with_per_language();
+	/// assert!(config.per_language);
+	/// ```
+	pub fn with_per_language(mut self) -> Self {
+		self.per_language = true;
+		self
+	}
+
+	/// Only cache pages for anonymous requests
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use std::time::Duration;
+	/// use reinhardt_middleware::cache::{CacheConfig, CacheKeyStrategy};
+	///
+	/// let config = CacheConfig::new(Duration::from_secs(300), CacheKeyStrategy::UrlOnly)
+	///     .with_anonymous_only();
+	/// assert!(config.anonymous_only);
+	/// ```
+	pub fn with_anonymous_only(mut self) -> Self {
+		self.anonymous_only = true;
+		self
+	}
 }
 
 impl Default for CacheConfig {
@@ -347,6 +603,30 @@ impl CacheMiddleware {
 		Arc::clone(&self.store)
 	}
 
+	/// Get the cache configuration
+	pub fn config(&self) -> &CacheConfig {
+		&self.config
+	}
+
+	/// Check if the request must not be served from or stored in the cache
+	pub(crate) fn bypasses_cache(&self, request: &Request) -> bool {
+		// Skip excluded paths and non-cacheable methods
+		if self.should_exclude(request.uri.path())
+			|| !self.is_cacheable_method(request.method.as_str())
+		{
+			return true;
+		}
+		// Skip authenticated requests in anonymous-only mode
+		self.config.anonymous_only && Self::is_authenticated(request)
+	}
+
+	/// Cache key of the request, from the URL and the headers it is known to vary on
+	pub(crate) fn request_cache_key(&self, request: &Request) -> String {
+		let base_key = self.generate_base_key(request);
+		let known_vary = self.store.vary_headers(&base_key).unwrap_or_default();
+		Self::generate_cache_key(&request.headers, &base_key, &known_vary)
+	}
+
 	/// Check if path should be excluded
 	fn should_exclude(&self, path: &str) -> bool {
 		self.config
@@ -361,13 +641,110 @@ impl CacheMiddleware {
 	}
 
 	/// Check if status code is cacheable
-	fn is_cacheable_status(&self, status: u16) -> bool {
+	pub(crate) fn is_cacheable_status(&self, status: u16) -> bool {
 		self.config.cacheable_status_codes.contains(&status)
 	}
 
+	/// Check if the request comes from an authenticated client
+	fn is_authenticated(request: &Request) -> bool {
+		request.headers.contains_key(AUTHORIZATION)
+			|| AuthState::from_extensions(&request.extensions)
+				.is_some_and(|state| state.is_authenticated())
+	}
+
+	/// Check if a response opted out of caching via `Cache-Control`
+	pub(crate) fn is_cacheable_response(response: &Response) -> bool {
+		if response.headers.contains_key(SET_COOKIE) {
+			return false;
+		}
+		!response
+			.headers
+			.get_all(CACHE_CONTROL)
+			.iter()
+			.filter_map(|value| value.to_str().ok())
+			.flat_map(|value| value.split(','))
+			.map(|directive| directive.trim().to_ascii_lowercase())
+			.any(|directive| matches!(directive.as_str(), "no-cache" | "no-store" | "private"))
+	}
+
+	/// Request header names listed in the response's `Vary` header
+	///
+	/// Returns `None` for `Vary: *`, which makes the response uncacheable.
+	pub(crate) fn response_vary_headers(response: &Response) -> Option<Vec<String>> {
+		let mut headers: Vec<String> = Vec::new();
+		for name in response
+			.headers
+			.get_all(VARY)
+			.iter()
+			.filter_map(|value| value.to_str().ok())
+			.flat_map(|value| value.split(','))
+			.map(|name| name.trim().to_ascii_lowercase())
+			.filter(|name| !name.is_empty())
+		{
+			if name == "*" {
+				return None;
+			}
+			if !headers.contains(&name) {
+				headers.push(name);
+			}
+		}
+		headers.sort();
+		Some(headers)
+	}
+
+	/// Generate the URL-level cache key, before `Vary` headers are applied
+	fn generate_base_key(&self, request: &Request) -> String {
+		let mut parts: Vec<&str> = Vec::new();
+		if !self.config.key_prefix.is_empty() {
+			parts.push(&self.config.key_prefix);
+		}
+		if self.config.per_site {
+			parts.push(
+				request
+					.headers
+					.get(HOST)
+					.and_then(|value| value.to_str().ok())
+					.unwrap_or(""),
+			);
+		}
+		if self.config.per_language {
+			parts.push(
+				request
+					.headers
+					.get(LOCALE_HEADER)
+					.and_then(|value| value.to_str().ok())
+					.unwrap_or(""),
+			);
+		}
+
+		let url = self.generate_url_key(request);
+		if parts.is_empty() {
+			url
+		} else {
+			format!("{}|{}", parts.join("|"), url)
+		}
+	}
+
 	/// Generate cache key
-	fn generate_cache_key(&self, request: &Request) -> String {
-		let base = match self.config.key_strategy {
+	fn generate_cache_key(headers: &HeaderMap, base: &str, vary: &[String]) -> String {
+		let mut hasher = Sha256::new();
+		hasher.update(base.as_bytes());
+		for name in vary {
+			let value = headers
+				.get_all(name.as_str())
+				.iter()
+				.filter_map(|value| value.to_str().ok())
+				.collect::<Vec<_>>()
+				.join(",");
+			hasher.update(format!("|{}={}", name, value).as_bytes());
+		}
+		let result = hasher.finalize();
+		hex::encode(result)
+	}
+
+	/// Generate the strategy-specific part of the cache key
+	fn generate_url_key(&self, request: &Request) -> String {
+		match self.config.key_strategy {
 			CacheKeyStrategy::UrlOnly => request.uri.path().to_string(),
 			CacheKeyStrategy::UrlAndMethod => {
 				format!("{}:{}", request.method.as_str(), request.uri.path())
@@ -395,13 +772,7 @@ impl CacheMiddleware {
 					headers_str
 				)
 			}
-		};
-
-		// Hash with SHA256
-		let mut hasher = Sha256::new();
-		hasher.update(base.as_bytes());
-		let result = hasher.finalize();
-		hex::encode(result)
+		}
 	}
 }
 
@@ -414,21 +785,15 @@ impl Default for CacheMiddleware {
 #[async_trait]
 impl Middleware for CacheMiddleware {
 	async fn process(&self, request: Request, handler: Arc<dyn Handler>) -> Result<Response> {
-		let path = request.uri.path().to_string();
-		let method = request.method.as_str().to_string();
-
-		// Skip excluded paths
-		if self.should_exclude(&path) {
-			return handler.handle(request).await;
-		}
-
-		// Skip non-cacheable methods
-		if !self.is_cacheable_method(&method) {
+		if self.bypasses_cache(&request) {
 			return handler.handle(request).await;
 		}
+		let path = request.uri.path().to_string();
 
-		// Generate cache key
-		let cache_key = self.generate_cache_key(&request);
+		// Generate cache key from the URL and the headers it is known to vary on
+		let base_key = self.generate_base_key(&request);
+		let known_vary = self.store.vary_headers(&base_key).unwrap_or_default();
+		let cache_key = Self::generate_cache_key(&request.headers, &base_key, &known_vary);
 
 		// Check cache
 		if let Some(entry) = self.store.get(&cache_key) {
@@ -441,16 +806,30 @@ impl Middleware for CacheMiddleware {
 			}
 		}
 
+		// Keep the request headers needed to key a varying response
+		let request_headers = request.headers.clone();
+
 		// Convert errors to responses so post-processing always runs,
 		// even when invoked outside MiddlewareChain. (#3244)
-		let response = match handler.handle(request).await {
+		let mut response = match handler.handle(request).await {
 			Ok(resp) => resp,
 			Err(e) => Response::from(e),
 		};
-
-		// Save to cache if status code is cacheable
-		if self.is_cacheable_status(response.status.as_u16()) {
-			let entry = CacheEntry::new(&response, self.config.default_ttl);
+		let tags = response_tags(&response);
+		response.headers.remove(CACHE_TAGS_HEADER);
+
+		// Save to cache if status code is cacheable and the view did not opt out
+		if self.is_cacheable_status(response.status.as_u16())
+			&& Self::is_cacheable_response(&response)
+			&& let Some(vary) = Self::response_vary_headers(&response)
+		{
+			let cache_key = if vary == known_vary {
+				cache_key
+			} else {
+				Self::generate_cache_key(&request_headers, &base_key, &vary)
+			};
+			self.store.set_vary_headers(base_key, vary);
+			let entry = CacheEntry::new(&response, self.config.default_ttl).for_page(&path, tags);
 			self.store.set(cache_key, entry);
 
 			// Clean up expired entries if max entries exceeded
@@ -462,7 +841,6 @@ impl Middleware for CacheMiddleware {
 		}
 
 		// Add X-Cache header
-		let mut response = response;
 		response.headers.insert(
 			hyper::header::HeaderName::from_static("x-cache"),
 			hyper::header::HeaderValue::from_static("MISS"),
@@ -796,6 +1174,228 @@ mod tests {
 		assert_eq!(handler2.get_call_count(), 1);
 	}
 
+	/// Handler returning fixed response headers
+	struct HeaderHandler {
+		headers: Vec<(&'static str, &'static str)>,
+		call_count: Arc<RwLock<usize>>,
+	}
+
+	impl HeaderHandler {
+		fn new(headers: Vec<(&'static str, &'static str)>) -> Self {
+			Self {
+				headers,
+				call_count: Arc::new(RwLock::new(0)),
+			}
+		}
+
+		fn get_call_count(&self) -> usize {
+			*self.call_count.read().unwrap()
+		}
+	}
+
+	#[async_trait]
+	impl Handler for HeaderHandler {
+		async fn handle(&self, _request: Request) -> Result<Response> {
+			*self.call_count.write().unwrap() += 1;
+			let mut response = Response::new(StatusCode::OK).with_body(Bytes::from("OK"));
+			for (name, value) in &self.headers {
+				response
+					.headers
+					.append(*name, HeaderValue::from_static(value));
+			}
+			Ok(response)
+		}
+	}
+
+	fn get_request(uri: &str, headers: &[(&'static str, &'static str)]) -> Request {
+		let mut header_map = HeaderMap::new();
+		for (name, value) in headers {
+			header_map.insert(*name, HeaderValue::from_static(value));
+		}
+		Request::builder()
+			.method(Method::GET)
+			.uri(uri)
+			.version(Version::HTTP_11)
+			.headers(header_map)
+			.body(Bytes::new())
+			.build()
+			.unwrap()
+	}
+
+	#[rstest::rstest]
+	#[tokio::test]
+	async fn test_vary_header_separates_variants() {
+		// Arrange
+		let middleware = CacheMiddleware::with_defaults();
+		let handler = Arc::new(HeaderHandler::new(vec![("vary", "Accept-Language")]));
+
+		// Act
+		for language in ["en", "ja", "en"] {
+			middleware
+				.process(
+					get_request("/page", &[("accept-language", language)]),
+					handler.clone(),
+				)
+				.await
+				.unwrap();
+		}
+		let response = middleware
+			.process(
+				get_request("/page", &[("accept-language", "ja")]),
+				handler.clone(),
+			)
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.headers.get("x-cache").unwrap(), "HIT");
+		assert_eq!(handler.get_call_count(), 2);
+	}
+
+	#[rstest::rstest]
+	#[case("Cache-Control", "no-cache, no-store, must-revalidate, private")]
+	#[case("Cache-Control", "private")]
+	#[case("Vary", "*")]
+	#[case("Set-Cookie", "sessionid=abc")]
+	#[tokio::test]
+	async fn test_uncacheable_response_is_not_stored(
+		#[case] name: &'static str,
+		#[case] value: &'static str,
+	) {
+		// Arrange
+		let middleware = CacheMiddleware::with_defaults();
+		let handler = Arc::new(HeaderHandler::new(vec![(name, value)]));
+
+		// Act
+		for _ in 0..2 {
+			middleware
+				.process(get_request("/account", &[]), handler.clone())
+				.await
+				.unwrap();
+		}
+
+		// Assert
+		assert_eq!(handler.get_call_count(), 2);
+		assert!(middleware.store().is_empty());
+	}
+
+	#[rstest::rstest]
+	fn test_add_never_cache_headers_opts_out() {
+		// Arrange
+		let mut response = Response::new(StatusCode::OK);
+
+		// Act
+		add_never_cache_headers(&mut response);
+
+		// Assert
+		assert!(!CacheMiddleware::is_cacheable_response(&response));
+	}
+
+	#[rstest::rstest]
+	#[tokio::test]
+	async fn test_anonymous_only_bypasses_authenticated_requests() {
+		// Arrange
+		let config = CacheConfig::default().with_anonymous_only();
+		let middleware = CacheMiddleware::new(config);
+		let handler = Arc::new(TestHandler::new(StatusCode::OK));
+		let authenticated = get_request("/page", &[]);
+		authenticated
+			.extensions
+			.insert(AuthState::authenticated("42", false, true));
+
+		// Act
+		let response = middleware
+			.process(authenticated, handler.clone())
+			.await
+			.unwrap();
+		let anonymous = middleware
+			.process(get_request("/page", &[]), handler.clone())
+			.await
+			.unwrap();
+
+		// Assert
+		assert!(!response.headers.contains_key("x-cache"));
+		assert_eq!(anonymous.headers.get("x-cache").unwrap(), "MISS");
+		assert_eq!(middleware.store().len(), 1);
+	}
+
+	#[rstest::rstest]
+	#[tokio::test]
+	async fn test_per_site_and_language_keys() {
+		// Arrange
+		let config = CacheConfig::default()
+			.with_key_prefix("v1")
+			.with_per_site()
+			.with_per_language();
+		let middleware = CacheMiddleware::new(config);
+		let handler = Arc::new(TestHandler::new(StatusCode::OK));
+
+		// Act
+		for (host, locale) in [
+			("a.example.com", "en"),
+			("b.example.com", "en"),
+			("a.example.com", "ja"),
+			("a.example.com", "en"),
+		] {
+			middleware
+				.process(
+					get_request("/", &[("host", host), (LOCALE_HEADER, locale)]),
+					handler.clone(),
+				)
+				.await
+				.unwrap();
+		}
+
+		// Assert
+		assert_eq!(handler.get_call_count(), 3);
+		assert_eq!(middleware.store().len(), 3);
+	}
+
+	#[rstest::rstest]
+	#[tokio::test]
+	async fn test_purge_by_url_and_tag() {
+		// Arrange
+		let config = CacheConfig::new(Duration::from_secs(60), CacheKeyStrategy::UrlAndQuery);
+		let middleware = CacheMiddleware::new(config);
+		let tagged = Arc::new(HeaderHandler::new(vec![(CACHE_TAGS_HEADER, "articles")]));
+		let plain = Arc::new(TestHandler::new(StatusCode::OK));
+		let response = middleware
+			.process(get_request("/articles/", &[]), tagged.clone())
+			.await
+			.unwrap();
+		middleware
+			.process(get_request("/about/?page=1", &[]), plain.clone())
+			.await
+			.unwrap();
+		middleware
+			.process(get_request("/about/?page=2", &[]), plain.clone())
+			.await
+			.unwrap();
+
+		// Act
+		let by_tag = middleware.store().purge_tag("articles");
+		let by_url = middleware.store().purge_url("/about/?page=1");
+
+		// Assert
+		assert!(!response.headers.contains_key(CACHE_TAGS_HEADER));
+		assert_eq!(by_tag, 1);
+		assert_eq!(by_url, 2);
+		assert!(middleware.store().is_empty());
+	}
+
+	#[rstest::rstest]
+	fn test_add_cache_tags_merges_existing() {
+		// Arrange
+		let mut response = Response::new(StatusCode::OK);
+		add_cache_tags(&mut response, &["a", "b"]);
+
+		// Act
+		add_cache_tags(&mut response, &["b", "c"]);
+
+		// Assert
+		assert_eq!(response_tags(&response), vec!["a", "b", "c"]);
+	}
+
 	#[rstest::rstest]
 	fn test_rwlock_poison_recovery_cache_store() {
 		// Arrange
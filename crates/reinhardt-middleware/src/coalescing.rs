@@ -0,0 +1,398 @@
+//! Request Coalescing Middleware
+//!
+//! When a hot cached page expires, every request arriving before it is
+//! regenerated misses the cache and runs the view, hitting the database with
+//! the same queries at once. [`CoalescingMiddleware`] lets only the first of
+//! several concurrent identical requests run the handler; the others wait for
+//! its response. Requests are identical when they have the same cache key as
+//! computed by [`CacheMiddleware`], and only requests the cache would serve
+//! (cacheable method, not excluded, anonymous in anonymous-only mode) are
+//! coalesced.
+//!
+//! The response is shared only if [`CacheMiddleware`] would store it: a
+//! cacheable status, no `Set-Cookie`, no `no-cache`/`no-store`/`private`
+//! directive, and the same values as the waiting request for every header
+//! listed in `Vary`. Otherwise, or when the leading request fails or is
+//! cancelled, waiting requests run the handler themselves.
+//!
+//! Install it inside the cache middleware, sharing its store:
+//!
+//! ```
+//! use reinhardt_middleware::{CacheMiddleware, CoalescingMiddleware};
+//!
+//! let cache = CacheMiddleware::with_defaults();
+//! let coalescing = CoalescingMiddleware::for_cache(&cache);
+//! ```
+
+use crate::cache::{CacheConfig, CacheMiddleware};
+use async_trait::async_trait;
+use hyper::HeaderMap;
+use reinhardt_http::{Handler, Middleware, Request, Response, Result};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::watch;
+
+/// Outcome of the request running the handler, as seen by waiting requests
+#[derive(Debug, Clone)]
+enum Outcome {
+	/// A response waiting requests may reuse
+	Shared(Arc<SharedResponse>),
+	/// Waiting requests must run the handler themselves
+	NotShared,
+}
+
+#[derive(Debug)]
+struct SharedResponse {
+	response: Response,
+	/// Request headers the response varies on, as sent by the leading request
+	vary: Vec<(String, Vec<String>)>,
+}
+
+impl SharedResponse {
+	/// Check if the response is valid for a request with `headers`
+	fn matches(&self, headers: &HeaderMap) -> bool {
+		self.vary
+			.iter()
+			.all(|(name, values)| header_values(headers, name) == *values)
+	}
+}
+
+fn header_values(headers: &HeaderMap, name: &str) -> Vec<String> {
+	headers
+		.get_all(name)
+		.iter()
+		.filter_map(|value| value.to_str().ok())
+		.map(str::to_string)
+		.collect()
+}
+
+type InFlight = Arc<Mutex<HashMap<String, watch::Receiver<Option<Outcome>>>>>;
+
+/// Removes the in-flight entry of the leading request, even if it is cancelled
+struct InFlightGuard {
+	in_flight: InFlight,
+	key: String,
+}
+
+impl Drop for InFlightGuard {
+	fn drop(&mut self) {
+		self.in_flight
+			.lock()
+			.unwrap_or_else(|e| e.into_inner())
+			.remove(&self.key);
+	}
+}
+
+/// Middleware coalescing identical concurrent cacheable requests
+pub struct CoalescingMiddleware {
+	cache: CacheMiddleware,
+	in_flight: InFlight,
+	max_wait: Duration,
+}
+
+impl CoalescingMiddleware {
+	/// Default time a request waits for an identical one before running the handler itself
+	pub const DEFAULT_MAX_WAIT: Duration = Duration::from_secs(10);
+
+	/// Create a coalescing middleware keying requests like a cache with `config`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use std::time::Duration;
+	/// use reinhardt_middleware::cache::{CacheConfig, CacheKeyStrategy};
+	/// use reinhardt_middleware::CoalescingMiddleware;
+	///
+	/// let config = CacheConfig::new(Duration::from_secs(300), CacheKeyStrategy::UrlAndQuery);
+	/// let middleware = CoalescingMiddleware::new(config);
+	/// assert_eq!(middleware.in_flight(), 0);
+	/// ```
+	pub fn new(config: CacheConfig) -> Self {
+		Self {
+			cache: CacheMiddleware::new(config),
+			in_flight: Arc::default(),
+			max_wait: Self::DEFAULT_MAX_WAIT,
+		}
+	}
+
+	/// Create a coalescing middleware sharing the configuration and store of `cache`
+	///
+	/// Sharing the store lets requests be keyed on the headers cached pages
+	/// are known to vary on.
+	pub fn for_cache(cache: &CacheMiddleware) -> Self {
+		Self {
+			cache: CacheMiddleware::from_arc(cache.config().clone(), cache.store_arc()),
+			in_flight: Arc::default(),
+			max_wait: Self::DEFAULT_MAX_WAIT,
+		}
+	}
+
+	/// Set how long a request waits for an identical one before running the handler itself
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use std::time::Duration;
+	/// use reinhardt_middleware::{CacheMiddleware, CoalescingMiddleware};
+	///
+	/// let middleware = CoalescingMiddleware::for_cache(&CacheMiddleware::with_defaults())
+	///     .with_max_wait(Duration::from_secs(2));
+	/// ```
+	pub fn with_max_wait(mut self, max_wait: Duration) -> Self {
+		self.max_wait = max_wait;
+		self
+	}
+
+	/// Number of requests currently running the handler for waiting requests
+	pub fn in_flight(&self) -> usize {
+		self.in_flight
+			.lock()
+			.unwrap_or_else(|e| e.into_inner())
+			.len()
+	}
+
+	/// Decide whether other requests may reuse `response`
+	fn outcome(&self, response: &Response, request_headers: &HeaderMap) -> Outcome {
+		if !self.cache.is_cacheable_status(response.status.as_u16())
+			|| !CacheMiddleware::is_cacheable_response(response)
+		{
+			return Outcome::NotShared;
+		}
+		let Some(vary) = CacheMiddleware::response_vary_headers(response) else {
+			return Outcome::NotShared;
+		};
+		let vary = vary
+			.into_iter()
+			.map(|name| {
+				let values = header_values(request_headers, &name);
+				(name, values)
+			})
+			.collect();
+		Outcome::Shared(Arc::new(SharedResponse {
+			response: response.clone(),
+			vary,
+		}))
+	}
+
+	/// Wait for the request running the handler, or `None` if its response can't be reused
+	async fn wait_for(
+		&self,
+		mut receiver: watch::Receiver<Option<Outcome>>,
+		headers: &HeaderMap,
+	) -> Option<Response> {
+		let outcome = tokio::time::timeout(self.max_wait, receiver.wait_for(Option::is_some))
+			.await
+			.ok()?
+			.ok()?
+			.clone();
+		match outcome {
+			Some(Outcome::Shared(shared)) if shared.matches(headers) => {
+				Some(shared.response.clone())
+			}
+			_ => None,
+		}
+	}
+}
+
+#[async_trait]
+impl Middleware for CoalescingMiddleware {
+	async fn process(&self, request: Request, handler: Arc<dyn Handler>) -> Result<Response> {
+		if self.cache.bypasses_cache(&request) {
+			return handler.handle(request).await;
+		}
+		let key = self.cache.request_cache_key(&request);
+
+		let waiting = {
+			let mut in_flight = self.in_flight.lock().unwrap_or_else(|e| e.into_inner());
+			match in_flight.get(&key) {
+				Some(receiver) => Err(receiver.clone()),
+				None => {
+					let (sender, receiver) = watch::channel(None);
+					in_flight.insert(key.clone(), receiver);
+					Ok(sender)
+				}
+			}
+		};
+
+		let sender = match waiting {
+			Ok(sender) => sender,
+			Err(receiver) => {
+				if let Some(response) = self.wait_for(receiver, &request.headers).await {
+					return Ok(response);
+				}
+				return handler.handle(request).await;
+			}
+		};
+
+		let _guard = InFlightGuard {
+			in_flight: Arc::clone(&self.in_flight),
+			key,
+		};
+		let request_headers = request.headers.clone();
+		match handler.handle(request).await {
+			Ok(response) => {
+				// Fails only when no request is waiting for this key, which is expected.
+				let _ = sender.send(Some(self.outcome(&response, &request_headers)));
+				Ok(response)
+			}
+			Err(e) => {
+				// Fails only when no request is waiting for this key, which is expected.
+				let _ = sender.send(Some(Outcome::NotShared));
+				Err(e)
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bytes::Bytes;
+	use hyper::header::HeaderValue;
+	use hyper::{Method, Version};
+	use rstest::rstest;
+	use std::sync::atomic::{AtomicUsize, Ordering};
+
+	struct SlowHandler {
+		calls: AtomicUsize,
+		headers: Vec<(&'static str, &'static str)>,
+	}
+
+	impl SlowHandler {
+		fn new(headers: Vec<(&'static str, &'static str)>) -> Arc<Self> {
+			Arc::new(Self {
+				calls: AtomicUsize::new(0),
+				headers,
+			})
+		}
+	}
+
+	#[async_trait]
+	impl Handler for SlowHandler {
+		async fn handle(&self, _request: Request) -> Result<Response> {
+			let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+			tokio::time::sleep(Duration::from_millis(50)).await;
+			let mut response = Response::ok().with_body(format!("render {}", call));
+			for (name, value) in &self.headers {
+				response
+					.headers
+					.insert(*name, HeaderValue::from_static(value));
+			}
+			Ok(response)
+		}
+	}
+
+	fn request(method: Method, headers: &[(&'static str, &'static str)]) -> Request {
+		let mut header_map = HeaderMap::new();
+		for (name, value) in headers {
+			header_map.insert(*name, HeaderValue::from_static(value));
+		}
+		Request::builder()
+			.method(method)
+			.uri("/hot-page")
+			.version(Version::HTTP_11)
+			.headers(header_map)
+			.body(Bytes::new())
+			.build()
+			.unwrap()
+	}
+
+	async fn run_concurrently(
+		middleware: Arc<CoalescingMiddleware>,
+		handler: Arc<SlowHandler>,
+		requests: Vec<Request>,
+	) -> Vec<Response> {
+		let tasks: Vec<_> = requests
+			.into_iter()
+			.map(|request| {
+				let middleware = Arc::clone(&middleware);
+				let handler: Arc<dyn Handler> = handler.clone();
+				tokio::spawn(async move { middleware.process(request, handler).await })
+			})
+			.collect();
+		let mut responses = Vec::new();
+		for task in tasks {
+			responses.push(task.await.unwrap().unwrap());
+		}
+		responses
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_identical_requests_share_one_response() {
+		// Arrange
+		let middleware = Arc::new(CoalescingMiddleware::for_cache(
+			&CacheMiddleware::with_defaults(),
+		));
+		let handler = SlowHandler::new(vec![]);
+		let requests = (0..5).map(|_| request(Method::GET, &[])).collect();
+
+		// Act
+		let responses = run_concurrently(Arc::clone(&middleware), handler.clone(), requests).await;
+
+		// Assert
+		assert_eq!(handler.calls.load(Ordering::SeqCst), 1);
+		assert!(
+			responses
+				.iter()
+				.all(|response| response.body == Bytes::from("render 1"))
+		);
+		assert_eq!(middleware.in_flight(), 0);
+	}
+
+	#[rstest]
+	#[case(vec![("set-cookie", "sessionid=abc")])]
+	#[case(vec![("cache-control", "private")])]
+	#[tokio::test]
+	async fn test_uncacheable_response_is_not_shared(
+		#[case] headers: Vec<(&'static str, &'static str)>,
+	) {
+		// Arrange
+		let middleware = Arc::new(CoalescingMiddleware::new(CacheConfig::default()));
+		let handler = SlowHandler::new(headers);
+		let requests = (0..3).map(|_| request(Method::GET, &[])).collect();
+
+		// Act
+		run_concurrently(middleware, handler.clone(), requests).await;
+
+		// Assert
+		assert_eq!(handler.calls.load(Ordering::SeqCst), 3);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_vary_mismatch_runs_handler() {
+		// Arrange
+		let middleware = Arc::new(CoalescingMiddleware::new(CacheConfig::default()));
+		let handler = SlowHandler::new(vec![("vary", "Accept-Language")]);
+		let requests = vec![
+			request(Method::GET, &[("accept-language", "en")]),
+			request(Method::GET, &[("accept-language", "en")]),
+			request(Method::GET, &[("accept-language", "ja")]),
+		];
+
+		// Act
+		let responses = run_concurrently(middleware, handler.clone(), requests).await;
+
+		// Assert
+		assert_eq!(handler.calls.load(Ordering::SeqCst), 2);
+		assert_eq!(responses[0].body, responses[1].body);
+		assert_ne!(responses[0].body, responses[2].body);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_non_cacheable_method_is_not_coalesced() {
+		// Arrange
+		let middleware = Arc::new(CoalescingMiddleware::new(CacheConfig::default()));
+		let handler = SlowHandler::new(vec![]);
+		let requests = (0..3).map(|_| request(Method::POST, &[])).collect();
+
+		// Act
+		run_concurrently(middleware, handler.clone(), requests).await;
+
+		// Assert
+		assert_eq!(handler.calls.load(Ordering::SeqCst), 3);
+	}
+}
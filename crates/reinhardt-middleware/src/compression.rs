@@ -0,0 +1,630 @@
+//! Negotiated Response Compression Middleware
+//!
+//! Compresses responses with gzip, brotli or zstd, choosing the coding from the
+//! client's `Accept-Encoding` header (including quality values) and the
+//! server's preference order.
+//!
+//! Unlike [`GZipMiddleware`](crate::GZipMiddleware) and
+//! [`BrotliMiddleware`](crate::BrotliMiddleware), which each handle a single
+//! coding, [`CompressionMiddleware`]:
+//!
+//! - honours `q` values, `*` and `q=0` exclusions when picking a coding
+//! - encodes incrementally through a [`StreamingEncoder`], and can wrap a
+//!   [`StreamingResponse`] so streamed bodies are compressed chunk by chunk
+//!   instead of being buffered
+//! - skips responses that are too small, have a non-compressible content
+//!   type, are already encoded, carry `Cache-Control: no-transform` or have no
+//!   body (`1xx`, `204`, `304`)
+//! - adds `Vary: Accept-Encoding` to every response whose representation
+//!   depends on the request's `Accept-Encoding`, and weakens strong `ETag`s of
+//!   compressed responses, since the encoded bytes differ from the identity
+//!   representation
+
+mod encoder;
+mod negotiate;
+
+pub use encoder::{StreamingEncoder, compress_stream};
+pub use negotiate::{ContentEncoding, negotiate_encoding};
+
+use crate::brotli::BrotliQuality;
+use async_trait::async_trait;
+use hyper::StatusCode;
+use hyper::header::{
+	ACCEPT_ENCODING, CACHE_CONTROL, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_TYPE, ETAG,
+	HeaderMap, HeaderValue, VARY,
+};
+use reinhardt_http::{
+	Handler, Middleware, Request, Response, Result, StreamBody, StreamingResponse,
+};
+use std::sync::Arc;
+
+/// Negotiated compression middleware configuration
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct CompressionConfig {
+	/// Minimum response size to compress (in bytes)
+	pub min_length: usize,
+	/// Content types (or prefixes such as `text/`) that should be compressed
+	pub compressible_types: Vec<String>,
+	/// Codings offered to clients, most preferred first
+	pub encodings: Vec<ContentEncoding>,
+	/// gzip compression level (0-9)
+	pub gzip_level: u32,
+	/// Brotli compression quality
+	pub brotli_quality: BrotliQuality,
+	/// Brotli window size (10-24)
+	pub brotli_window_size: u32,
+	/// zstd compression level (1-22)
+	pub zstd_level: i32,
+}
+
+impl CompressionConfig {
+	/// Create a new configuration with default settings
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::compression::{CompressionConfig, ContentEncoding};
+	///
+	/// let config = CompressionConfig::new()
+	///     .with_min_length(1024)
+	///     .with_encodings(vec![ContentEncoding::Zstd, ContentEncoding::Gzip])
+	///     .with_zstd_level(6);
+	/// assert_eq!(config.encodings, vec![ContentEncoding::Zstd, ContentEncoding::Gzip]);
+	/// ```
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Set minimum response size
+	pub fn with_min_length(mut self, min_length: usize) -> Self {
+		self.min_length = min_length;
+		self
+	}
+
+	/// Add a compressible content type
+	pub fn with_compressible_type(mut self, content_type: impl Into<String>) -> Self {
+		self.compressible_types.push(content_type.into());
+		self
+	}
+
+	/// Set the offered codings, most preferred first
+	pub fn with_encodings(mut self, encodings: Vec<ContentEncoding>) -> Self {
+		self.encodings = encodings;
+		self
+	}
+
+	/// Set the gzip compression level
+	pub fn with_gzip_level(mut self, level: u32) -> Self {
+		self.gzip_level = level.min(9);
+		self
+	}
+
+	/// Set the Brotli compression quality
+	pub fn with_brotli_quality(mut self, quality: BrotliQuality) -> Self {
+		self.brotli_quality = quality;
+		self
+	}
+
+	/// Set the zstd compression level
+	pub fn with_zstd_level(mut self, level: i32) -> Self {
+		self.zstd_level = level.clamp(1, 22);
+		self
+	}
+}
+
+impl Default for CompressionConfig {
+	fn default() -> Self {
+		Self {
+			min_length: 200,
+			compressible_types: vec![
+				"text/".to_string(),
+				"application/json".to_string(),
+				"application/javascript".to_string(),
+				"application/xml".to_string(),
+				"application/xhtml+xml".to_string(),
+				"image/svg+xml".to_string(),
+			],
+			encodings: vec![
+				ContentEncoding::Brotli,
+				ContentEncoding::Zstd,
+				ContentEncoding::Gzip,
+			],
+			gzip_level: 6,
+			// Quality 11 is far too slow for on-the-fly compression
+			brotli_quality: BrotliQuality::Balanced,
+			brotli_window_size: 22,
+			zstd_level: 3,
+		}
+	}
+}
+
+/// Compression middleware negotiating gzip, brotli and zstd
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use reinhardt_middleware::CompressionMiddleware;
+/// use reinhardt_http::{Handler, Middleware, Request, Response};
+/// use hyper::{StatusCode, Method, HeaderMap};
+/// use bytes::Bytes;
+///
+/// struct TestHandler;
+///
+/// #[async_trait::async_trait]
+/// impl Handler for TestHandler {
+///     async fn handle(&self, _request: Request) -> reinhardt_core::exception::Result<Response> {
+///         let mut response = Response::new(StatusCode::OK).with_body(Bytes::from("hello ".repeat(100)));
+///         response.headers.insert(hyper::header::CONTENT_TYPE, "text/plain".parse().unwrap());
+///         Ok(response)
+///     }
+/// }
+///
+/// # tokio_test::block_on(async {
+/// let middleware = CompressionMiddleware::new();
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert(hyper::header::ACCEPT_ENCODING, "gzip;q=0.8, zstd".parse().unwrap());
+/// let request = Request::builder()
+///     .method(Method::GET)
+///     .uri("/page")
+///     .headers(headers)
+///     .body(Bytes::new())
+///     .build()
+///     .unwrap();
+///
+/// let response = middleware.process(request, Arc::new(TestHandler)).await.unwrap();
+/// assert_eq!(response.headers.get(hyper::header::CONTENT_ENCODING).unwrap(), "zstd");
+/// assert_eq!(response.headers.get(hyper::header::VARY).unwrap(), "Accept-Encoding");
+/// # });
+/// ```
+pub struct CompressionMiddleware {
+	config: CompressionConfig,
+}
+
+impl CompressionMiddleware {
+	/// Create a new CompressionMiddleware with default configuration
+	///
+	/// Responses of at least 200 bytes are compressed, preferring brotli,
+	/// then zstd, then gzip.
+	pub fn new() -> Self {
+		Self {
+			config: CompressionConfig::default(),
+		}
+	}
+
+	/// Create a new CompressionMiddleware with custom configuration
+	pub fn with_config(config: CompressionConfig) -> Self {
+		Self { config }
+	}
+
+	/// Choose the coding for `request` from its `Accept-Encoding` header
+	pub fn negotiate(&self, request: &Request) -> Option<ContentEncoding> {
+		let accept_encoding = request.headers.get(ACCEPT_ENCODING)?.to_str().ok()?;
+		negotiate_encoding(accept_encoding, &self.config.encodings)
+	}
+
+	/// Compress a streaming response for `request`
+	///
+	/// Middleware only sees buffered [`Response`]s, so handlers that return a
+	/// [`StreamingResponse`] call this to apply the same negotiation and
+	/// filters. The body is encoded chunk by chunk as it is produced.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bytes::Bytes;
+	/// use futures::stream;
+	/// use hyper::Method;
+	/// use reinhardt_http::{Request, StreamBody, StreamingResponse};
+	/// use reinhardt_middleware::CompressionMiddleware;
+	///
+	/// let request = Request::builder()
+	///     .method(Method::GET)
+	///     .uri("/events")
+	///     .header("accept-encoding", "br")
+	///     .build()
+	///     .unwrap();
+	///
+	/// let body: StreamBody = Box::pin(stream::iter(vec![Ok(Bytes::from("data: 1\n\n"))]));
+	/// let response = StreamingResponse::new(body).media_type("text/event-stream");
+	///
+	/// let response = CompressionMiddleware::new().compress_streaming(&request, response);
+	/// assert_eq!(response.headers.get("content-encoding").unwrap(), "br");
+	/// ```
+	pub fn compress_streaming(
+		&self,
+		request: &Request,
+		mut response: StreamingResponse<StreamBody>,
+	) -> StreamingResponse<StreamBody> {
+		if !self.is_compressible(response.status, &response.headers) {
+			return response;
+		}
+		if let Some(length) = content_length(&response.headers)
+			&& length < self.config.min_length
+		{
+			return response;
+		}
+		add_vary_accept_encoding(&mut response.headers);
+
+		let Some(encoding) = self.negotiate(request) else {
+			return response;
+		};
+		let Ok(encoder) = StreamingEncoder::new(encoding, &self.config) else {
+			return response;
+		};
+		// The compressed length is unknown until the stream ends
+		response.headers.remove(CONTENT_LENGTH);
+		mark_encoded(&mut response.headers, encoding);
+
+		StreamingResponse {
+			status: response.status,
+			headers: response.headers,
+			stream: compress_stream(response.stream, encoder),
+		}
+	}
+
+	/// Whether a response of this status and headers may be compressed
+	fn is_compressible(&self, status: StatusCode, headers: &HeaderMap) -> bool {
+		if status.is_informational()
+			|| status == StatusCode::NO_CONTENT
+			|| status == StatusCode::NOT_MODIFIED
+			|| headers.contains_key(CONTENT_ENCODING)
+		{
+			return false;
+		}
+
+		let no_transform = headers
+			.get_all(CACHE_CONTROL)
+			.iter()
+			.filter_map(|value| value.to_str().ok())
+			.flat_map(|value| value.split(','))
+			.any(|directive| directive.trim().eq_ignore_ascii_case("no-transform"));
+		if no_transform {
+			return false;
+		}
+
+		let content_type = headers
+			.get(CONTENT_TYPE)
+			.and_then(|ct| ct.to_str().ok())
+			.and_then(|ct| ct.split(';').next())
+			.map(|ct| ct.trim().to_ascii_lowercase())
+			.unwrap_or_default();
+		self.config
+			.compressible_types
+			.iter()
+			.any(|ct| content_type.starts_with(ct.as_str()))
+	}
+
+	/// Compress a buffered body
+	fn compress_body(&self, encoding: ContentEncoding, body: &[u8]) -> Result<bytes::Bytes> {
+		let internal =
+			|e: std::io::Error| reinhardt_core::exception::Error::Internal(e.to_string());
+		let mut encoder = StreamingEncoder::new(encoding, &self.config).map_err(internal)?;
+		encoder.write(body).map_err(internal)?;
+		encoder.finish().map_err(internal)
+	}
+}
+
+impl Default for CompressionMiddleware {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[async_trait]
+impl Middleware for CompressionMiddleware {
+	async fn process(&self, request: Request, handler: Arc<dyn Handler>) -> Result<Response> {
+		let encoding = self.negotiate(&request);
+
+		// Convert errors to responses so post-processing always runs,
+		// even when invoked outside MiddlewareChain. (#3244)
+		let mut response = match handler.handle(request).await {
+			Ok(resp) => resp,
+			Err(e) => Response::from(e),
+		};
+
+		if !self.is_compressible(response.status, &response.headers)
+			|| response.body.len() < self.config.min_length
+		{
+			return Ok(response);
+		}
+		// The representation now depends on Accept-Encoding, whether or not
+		// this particular client gets a compressed body
+		add_vary_accept_encoding(&mut response.headers);
+
+		let Some(encoding) = encoding else {
+			return Ok(response);
+		};
+		let compressed = self.compress_body(encoding, &response.body)?;
+
+		// Only use compressed version if it's actually smaller
+		if compressed.len() < response.body.len() {
+			response.body = compressed;
+			mark_encoded(&mut response.headers, encoding);
+			response
+				.headers
+				.insert(CONTENT_LENGTH, HeaderValue::from(response.body.len()));
+		}
+
+		Ok(response)
+	}
+}
+
+fn content_length(headers: &HeaderMap) -> Option<usize> {
+	headers.get(CONTENT_LENGTH)?.to_str().ok()?.parse().ok()
+}
+
+/// Add `Accept-Encoding` to `Vary` unless it is already covered
+fn add_vary_accept_encoding(headers: &mut HeaderMap) {
+	let covered = headers
+		.get_all(VARY)
+		.iter()
+		.filter_map(|value| value.to_str().ok())
+		.flat_map(|value| value.split(','))
+		.map(str::trim)
+		.any(|field| field == "*" || field.eq_ignore_ascii_case("accept-encoding"));
+	if !covered {
+		headers.append(VARY, HeaderValue::from_static("Accept-Encoding"));
+	}
+}
+
+/// Set `Content-Encoding` and weaken a strong `ETag`
+///
+/// A strong validator promises byte-for-byte identity, which no longer holds
+/// once the body is encoded, so `"abc"` becomes `W/"abc"`.
+fn mark_encoded(headers: &mut HeaderMap, encoding: ContentEncoding) {
+	headers.insert(
+		CONTENT_ENCODING,
+		HeaderValue::from_static(encoding.as_str()),
+	);
+	if let Some(etag) = headers.get(ETAG)
+		&& etag.as_bytes().starts_with(b"\"")
+		&& let Ok(weak) = HeaderValue::from_bytes(&[b"W/", etag.as_bytes()].concat())
+	{
+		headers.insert(ETAG, weak);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bytes::Bytes;
+	use futures::{StreamExt, stream};
+	use hyper::Method;
+	use rstest::rstest;
+	use std::io::Read;
+
+	struct TestHandler {
+		body: String,
+		headers: Vec<(&'static str, &'static str)>,
+		status: StatusCode,
+	}
+
+	#[async_trait]
+	impl Handler for TestHandler {
+		async fn handle(&self, _request: Request) -> Result<Response> {
+			let mut response = Response::new(self.status).with_body(self.body.clone());
+			for (name, value) in &self.headers {
+				response.headers.append(*name, value.parse().unwrap());
+			}
+			Ok(response)
+		}
+	}
+
+	fn handler(headers: Vec<(&'static str, &'static str)>) -> Arc<TestHandler> {
+		Arc::new(TestHandler {
+			body: "The quick brown fox jumps over the lazy dog. ".repeat(20),
+			headers,
+			status: StatusCode::OK,
+		})
+	}
+
+	fn request(accept_encoding: Option<&str>) -> Request {
+		let mut builder = Request::builder().method(Method::GET).uri("/");
+		if let Some(accept_encoding) = accept_encoding {
+			builder = builder.header("accept-encoding", accept_encoding);
+		}
+		builder.build().unwrap()
+	}
+
+	fn decode(encoding: ContentEncoding, body: &[u8]) -> String {
+		let mut decoded = String::new();
+		match encoding {
+			ContentEncoding::Gzip => {
+				flate2::read::GzDecoder::new(body)
+					.read_to_string(&mut decoded)
+					.unwrap();
+			}
+			ContentEncoding::Brotli => {
+				brotli::Decompressor::new(body, 4096)
+					.read_to_string(&mut decoded)
+					.unwrap();
+			}
+			ContentEncoding::Zstd => {
+				decoded = String::from_utf8(zstd::decode_all(body).unwrap()).unwrap();
+			}
+		}
+		decoded
+	}
+
+	#[rstest]
+	#[case("gzip", ContentEncoding::Gzip)]
+	#[case("br", ContentEncoding::Brotli)]
+	#[case("zstd", ContentEncoding::Zstd)]
+	#[case("gzip;q=0.5, zstd;q=0.9, br;q=0.1", ContentEncoding::Zstd)]
+	#[tokio::test]
+	async fn test_compresses_with_negotiated_encoding(
+		#[case] accept_encoding: &str,
+		#[case] expected: ContentEncoding,
+	) {
+		// Arrange
+		let middleware = CompressionMiddleware::new();
+		let handler = handler(vec![("content-type", "text/plain; charset=utf-8")]);
+		let original = handler.body.clone();
+
+		// Act
+		let response = middleware
+			.process(request(Some(accept_encoding)), handler)
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(
+			response.headers.get(CONTENT_ENCODING).unwrap(),
+			expected.as_str()
+		);
+		assert_eq!(
+			response.headers.get(CONTENT_LENGTH).unwrap(),
+			&response.body.len().to_string()
+		);
+		assert_eq!(decode(expected, &response.body), original);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_identity_still_varies_on_accept_encoding() {
+		// Arrange
+		let middleware = CompressionMiddleware::new();
+
+		// Act
+		let response = middleware
+			.process(request(None), handler(vec![("content-type", "text/html")]))
+			.await
+			.unwrap();
+
+		// Assert
+		assert!(!response.headers.contains_key(CONTENT_ENCODING));
+		assert_eq!(response.headers.get(VARY).unwrap(), "Accept-Encoding");
+	}
+
+	#[rstest]
+	#[case::image(vec![("content-type", "image/png")])]
+	#[case::already_encoded(vec![("content-type", "text/html"), ("content-encoding", "br")])]
+	#[case::no_transform(vec![("content-type", "text/html"), ("cache-control", "public, no-transform")])]
+	#[tokio::test]
+	async fn test_skips_ineligible_responses(#[case] headers: Vec<(&'static str, &'static str)>) {
+		// Arrange
+		let middleware = CompressionMiddleware::new();
+
+		// Act
+		let response = middleware
+			.process(request(Some("gzip")), handler(headers))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_ne!(
+			response.headers.get(CONTENT_ENCODING).map(|v| v.as_bytes()),
+			Some(&b"gzip"[..])
+		);
+		assert!(!response.headers.contains_key(VARY));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_skips_small_responses() {
+		// Arrange
+		let middleware =
+			CompressionMiddleware::with_config(CompressionConfig::new().with_min_length(10_000));
+
+		// Act
+		let response = middleware
+			.process(
+				request(Some("gzip")),
+				handler(vec![("content-type", "text/html")]),
+			)
+			.await
+			.unwrap();
+
+		// Assert
+		assert!(!response.headers.contains_key(CONTENT_ENCODING));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_appends_to_existing_vary_once() {
+		// Arrange
+		let middleware = CompressionMiddleware::new();
+		let headers = vec![("content-type", "text/html"), ("vary", "Cookie")];
+
+		// Act
+		let response = middleware
+			.process(request(Some("gzip")), handler(headers))
+			.await
+			.unwrap();
+
+		// Assert
+		let vary: Vec<_> = response.headers.get_all(VARY).iter().collect();
+		assert_eq!(vary, vec!["Cookie", "Accept-Encoding"]);
+	}
+
+	#[rstest]
+	#[case("\"abc\"", "W/\"abc\"")]
+	#[case("W/\"abc\"", "W/\"abc\"")]
+	#[tokio::test]
+	async fn test_strong_etag_is_weakened(#[case] etag: &'static str, #[case] expected: &str) {
+		// Arrange
+		let middleware = CompressionMiddleware::new();
+		let headers = vec![("content-type", "application/json"), ("etag", etag)];
+
+		// Act
+		let response = middleware
+			.process(request(Some("gzip")), handler(headers))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.headers.get(ETAG).unwrap(), expected);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_streaming_response_is_encoded_per_chunk() {
+		// Arrange
+		let middleware = CompressionMiddleware::new();
+		let chunks: Vec<std::result::Result<Bytes, Box<dyn std::error::Error + Send + Sync>>> = vec![
+			Ok(Bytes::from("data: one\n\n")),
+			Ok(Bytes::from("data: two\n\n")),
+		];
+		let body: StreamBody = Box::pin(stream::iter(chunks));
+		let response = StreamingResponse::new(body)
+			.media_type("text/event-stream")
+			.header(CONTENT_LENGTH, HeaderValue::from_static("22"));
+
+		// Act
+		let response = middleware.compress_streaming(&request(Some("gzip, zstd;q=0.5")), response);
+		let encoded: Vec<Bytes> = response.stream.map(|chunk| chunk.unwrap()).collect().await;
+
+		// Assert
+		assert_eq!(response.headers.get(CONTENT_ENCODING).unwrap(), "gzip");
+		assert!(!response.headers.contains_key(CONTENT_LENGTH));
+		// One flushed chunk per upstream chunk plus the gzip trailer
+		assert_eq!(encoded.len(), 3);
+		assert_eq!(
+			decode(ContentEncoding::Gzip, &encoded.concat()),
+			"data: one\n\ndata: two\n\n"
+		);
+	}
+
+	#[rstest]
+	#[case(ContentEncoding::Gzip)]
+	#[case(ContentEncoding::Brotli)]
+	#[case(ContentEncoding::Zstd)]
+	fn test_streaming_encoder_round_trip(#[case] encoding: ContentEncoding) {
+		// Arrange
+		let mut encoder = StreamingEncoder::new(encoding, &CompressionConfig::default()).unwrap();
+		let mut output = Vec::new();
+
+		// Act
+		for part in ["alpha ", "beta ", "gamma"] {
+			encoder.write(part.as_bytes()).unwrap();
+			output.extend_from_slice(&encoder.flush().unwrap());
+		}
+		output.extend_from_slice(&encoder.finish().unwrap());
+
+		// Assert
+		assert_eq!(decode(encoding, &output), "alpha beta gamma");
+	}
+}
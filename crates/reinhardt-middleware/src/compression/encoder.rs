@@ -0,0 +1,182 @@
+//! Incremental gzip, brotli and zstd encoders
+
+use super::{CompressionConfig, ContentEncoding};
+use bytes::Bytes;
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use futures::StreamExt;
+use reinhardt_http::StreamBody;
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Output buffer shared between an encoder and its owner, so produced bytes
+/// can be drained between writes without tearing the encoder down
+#[derive(Clone, Default)]
+struct SharedSink(Arc<Mutex<Vec<u8>>>);
+
+impl SharedSink {
+	fn take(&self) -> Bytes {
+		let mut buffer = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+		Bytes::from(std::mem::take(&mut *buffer))
+	}
+}
+
+impl Write for SharedSink {
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.0
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.extend_from_slice(buf);
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		Ok(())
+	}
+}
+
+enum Inner {
+	Gzip(GzEncoder<SharedSink>),
+	Brotli(Box<brotli::CompressorWriter<SharedSink>>),
+	Zstd(zstd::stream::write::Encoder<'static, SharedSink>),
+}
+
+/// An encoder that compresses a body piece by piece
+///
+/// Input is fed with [`write`](Self::write); compressed output is collected
+/// with [`flush`](Self::flush) whenever the caller wants to emit what has been
+/// produced so far, and with [`finish`](Self::finish) once the input ends.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_middleware::compression::{CompressionConfig, ContentEncoding, StreamingEncoder};
+///
+/// let config = CompressionConfig::default();
+/// let mut encoder = StreamingEncoder::new(ContentEncoding::Gzip, &config).unwrap();
+/// encoder.write(b"hello ").unwrap();
+/// let mut output = encoder.flush().unwrap().to_vec();
+/// encoder.write(b"world").unwrap();
+/// output.extend_from_slice(&encoder.finish().unwrap());
+///
+/// let mut decoded = String::new();
+/// std::io::Read::read_to_string(&mut flate2::read::GzDecoder::new(&output[..]), &mut decoded).unwrap();
+/// assert_eq!(decoded, "hello world");
+/// ```
+pub struct StreamingEncoder {
+	inner: Inner,
+	sink: SharedSink,
+}
+
+impl StreamingEncoder {
+	/// Create an encoder for `encoding` using the levels from `config`
+	pub fn new(encoding: ContentEncoding, config: &CompressionConfig) -> io::Result<Self> {
+		let sink = SharedSink::default();
+		let inner = match encoding {
+			ContentEncoding::Gzip => Inner::Gzip(GzEncoder::new(
+				sink.clone(),
+				Compression::new(config.gzip_level.min(9)),
+			)),
+			ContentEncoding::Brotli => Inner::Brotli(Box::new(brotli::CompressorWriter::new(
+				sink.clone(),
+				4096,
+				config.brotli_quality.to_value(),
+				config.brotli_window_size.clamp(10, 24),
+			))),
+			ContentEncoding::Zstd => Inner::Zstd(zstd::stream::write::Encoder::new(
+				sink.clone(),
+				config.zstd_level,
+			)?),
+		};
+		Ok(Self { inner, sink })
+	}
+
+	/// The content coding this encoder produces
+	pub fn encoding(&self) -> ContentEncoding {
+		match self.inner {
+			Inner::Gzip(_) => ContentEncoding::Gzip,
+			Inner::Brotli(_) => ContentEncoding::Brotli,
+			Inner::Zstd(_) => ContentEncoding::Zstd,
+		}
+	}
+
+	/// Feed `data` into the encoder
+	pub fn write(&mut self, data: &[u8]) -> io::Result<()> {
+		match &mut self.inner {
+			Inner::Gzip(encoder) => encoder.write_all(data),
+			Inner::Brotli(encoder) => encoder.write_all(data),
+			Inner::Zstd(encoder) => encoder.write_all(data),
+		}
+	}
+
+	/// Flush pending input and return the compressed bytes produced since the
+	/// last call
+	///
+	/// Flushing ends the current compression block, so flushing after every
+	/// small write costs some compression ratio in exchange for latency.
+	pub fn flush(&mut self) -> io::Result<Bytes> {
+		match &mut self.inner {
+			Inner::Gzip(encoder) => encoder.flush()?,
+			Inner::Brotli(encoder) => encoder.flush()?,
+			Inner::Zstd(encoder) => encoder.flush()?,
+		}
+		Ok(self.sink.take())
+	}
+
+	/// Finish the compressed stream and return the remaining bytes
+	pub fn finish(self) -> io::Result<Bytes> {
+		match self.inner {
+			Inner::Gzip(encoder) => {
+				encoder.finish()?;
+			}
+			Inner::Brotli(encoder) => {
+				// `into_inner` writes the final brotli block before handing
+				// the sink back
+				encoder.into_inner();
+			}
+			Inner::Zstd(encoder) => {
+				encoder.finish()?;
+			}
+		}
+		Ok(self.sink.take())
+	}
+}
+
+type StreamItem = Result<Bytes, Box<dyn std::error::Error + Send + Sync>>;
+
+/// Compress a streaming body chunk by chunk
+///
+/// Each upstream chunk is flushed through `encoder` and emitted as soon as it
+/// has been compressed, so the body is never buffered as a whole and
+/// long-lived streams (e.g. server-sent events) keep flowing.
+pub fn compress_stream(stream: StreamBody, encoder: StreamingEncoder) -> StreamBody {
+	Box::pin(futures::stream::unfold(
+		Some((stream, encoder)),
+		|state| async move {
+			let (stream, encoder) = state?;
+			next_chunk(stream, encoder).await
+		},
+	))
+}
+
+async fn next_chunk(
+	mut stream: StreamBody,
+	mut encoder: StreamingEncoder,
+) -> Option<(StreamItem, Option<(StreamBody, StreamingEncoder)>)> {
+	loop {
+		match stream.next().await {
+			Some(Ok(chunk)) => match encoder.write(&chunk).and_then(|()| encoder.flush()) {
+				Ok(output) if output.is_empty() => continue,
+				Ok(output) => return Some((Ok(output), Some((stream, encoder)))),
+				Err(e) => return Some((Err(e.into()), None)),
+			},
+			Some(Err(e)) => return Some((Err(e), None)),
+			None => {
+				return match encoder.finish() {
+					Ok(output) => Some((Ok(output), None)),
+					Err(e) => Some((Err(e.into()), None)),
+				};
+			}
+		}
+	}
+}
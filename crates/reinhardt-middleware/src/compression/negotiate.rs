@@ -0,0 +1,186 @@
+//! `Accept-Encoding` parsing and content-coding selection
+
+use std::fmt;
+
+/// A content coding supported by [`CompressionMiddleware`](super::CompressionMiddleware)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContentEncoding {
+	/// `gzip` (RFC 1952)
+	Gzip,
+	/// `br` (RFC 7932)
+	Brotli,
+	/// `zstd` (RFC 8878)
+	Zstd,
+}
+
+impl ContentEncoding {
+	/// The token used in `Accept-Encoding` and `Content-Encoding` headers
+	pub fn as_str(self) -> &'static str {
+		match self {
+			ContentEncoding::Gzip => "gzip",
+			ContentEncoding::Brotli => "br",
+			ContentEncoding::Zstd => "zstd",
+		}
+	}
+
+	fn matches(self, token: &str) -> bool {
+		token.eq_ignore_ascii_case(self.as_str())
+			|| (self == ContentEncoding::Gzip && token.eq_ignore_ascii_case("x-gzip"))
+	}
+}
+
+impl fmt::Display for ContentEncoding {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+/// Pick the content coding to use for a response
+///
+/// Quality values are honoured as described in RFC 9110 §12.5.3: a coding
+/// listed explicitly uses its own `q`, otherwise the `*` entry applies, and a
+/// `q` of zero means "not acceptable". Among the acceptable codings the one
+/// with the highest `q` wins; ties are broken by the order of `supported`.
+/// Entries with a malformed `q` are ignored.
+///
+/// Returns `None` when the response should be sent unencoded.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_middleware::compression::{ContentEncoding, negotiate_encoding};
+///
+/// let supported = [ContentEncoding::Brotli, ContentEncoding::Zstd, ContentEncoding::Gzip];
+///
+/// assert_eq!(negotiate_encoding("gzip, br", &supported), Some(ContentEncoding::Brotli));
+/// assert_eq!(negotiate_encoding("br;q=0.5, gzip", &supported), Some(ContentEncoding::Gzip));
+/// assert_eq!(negotiate_encoding("*;q=0, identity", &supported), None);
+/// ```
+pub fn negotiate_encoding(
+	accept_encoding: &str,
+	supported: &[ContentEncoding],
+) -> Option<ContentEncoding> {
+	let entries: Vec<(&str, u16)> = accept_encoding.split(',').filter_map(parse_entry).collect();
+	let wildcard = entries
+		.iter()
+		.find(|(coding, _)| *coding == "*")
+		.map(|(_, q)| *q);
+
+	let mut best: Option<(ContentEncoding, u16)> = None;
+	for &encoding in supported {
+		let q = entries
+			.iter()
+			.find(|(coding, _)| encoding.matches(coding))
+			.map(|(_, q)| *q)
+			.or(wildcard)
+			.unwrap_or(0);
+		if q > 0 && best.is_none_or(|(_, best_q)| q > best_q) {
+			best = Some((encoding, q));
+		}
+	}
+	best.map(|(encoding, _)| encoding)
+}
+
+/// Parse one `coding;q=x` entry, with `q` scaled to thousandths
+fn parse_entry(entry: &str) -> Option<(&str, u16)> {
+	let mut parts = entry.split(';');
+	let coding = parts.next()?.trim();
+	if coding.is_empty() {
+		return None;
+	}
+
+	let mut q = 1000;
+	for param in parts {
+		if let Some((name, value)) = param.split_once('=')
+			&& name.trim().eq_ignore_ascii_case("q")
+		{
+			q = parse_qvalue(value.trim())?;
+		}
+	}
+	Some((coding, q))
+}
+
+/// Parse a `qvalue` (`0`, `0.5`, `1.000`, ...) into thousandths
+fn parse_qvalue(value: &str) -> Option<u16> {
+	let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+	if fraction.len() > 3 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+		return None;
+	}
+	let whole = match whole {
+		"0" => 0,
+		"1" => 1000,
+		_ => return None,
+	};
+	let fraction = fraction
+		.bytes()
+		.zip([100, 10, 1])
+		.map(|(digit, scale)| u16::from(digit - b'0') * scale)
+		.sum::<u16>();
+	let q = whole + fraction;
+	(q <= 1000).then_some(q)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	const ALL: [ContentEncoding; 3] = [
+		ContentEncoding::Brotli,
+		ContentEncoding::Zstd,
+		ContentEncoding::Gzip,
+	];
+
+	#[rstest]
+	#[case("", None)]
+	#[case("identity", None)]
+	#[case("gzip", Some(ContentEncoding::Gzip))]
+	#[case("x-gzip", Some(ContentEncoding::Gzip))]
+	#[case("GZIP", Some(ContentEncoding::Gzip))]
+	#[case("gzip, deflate, br, zstd", Some(ContentEncoding::Brotli))]
+	#[case("gzip;q=1.0, br;q=0.8", Some(ContentEncoding::Gzip))]
+	#[case("zstd;q=0.9, br;q=0.9, gzip", Some(ContentEncoding::Gzip))]
+	#[case("zstd;q=0.9, br;q=0.9", Some(ContentEncoding::Brotli))]
+	#[case("*", Some(ContentEncoding::Brotli))]
+	#[case("br;q=0, *", Some(ContentEncoding::Zstd))]
+	#[case("*;q=0", None)]
+	#[case("gzip;q=0", None)]
+	#[case("gzip;q=1.5, br;q=abc, zstd;q=0.001", Some(ContentEncoding::Zstd))]
+	fn test_negotiate_encoding(#[case] header: &str, #[case] expected: Option<ContentEncoding>) {
+		// Act
+		let chosen = negotiate_encoding(header, &ALL);
+
+		// Assert
+		assert_eq!(chosen, expected);
+	}
+
+	#[rstest]
+	fn test_negotiate_respects_supported_list() {
+		// Arrange
+		let supported = [ContentEncoding::Gzip];
+
+		// Act
+		let chosen = negotiate_encoding("br, zstd", &supported);
+
+		// Assert
+		assert_eq!(chosen, None);
+	}
+
+	#[rstest]
+	#[case("0", Some(0))]
+	#[case("1", Some(1000))]
+	#[case("0.5", Some(500))]
+	#[case("0.25", Some(250))]
+	#[case("1.000", Some(1000))]
+	#[case("1.001", None)]
+	#[case("0.1234", None)]
+	#[case("2", None)]
+	#[case("", None)]
+	fn test_parse_qvalue(#[case] value: &str, #[case] expected: Option<u16>) {
+		// Act
+		let q = parse_qvalue(value);
+
+		// Assert
+		assert_eq!(q, expected);
+	}
+}
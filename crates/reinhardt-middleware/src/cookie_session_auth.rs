@@ -238,7 +238,7 @@ mod tests {
 	use std::sync::Mutex;
 	use std::time::{Duration, SystemTime};
 
-	use crate::session::SessionData;
+	use crate::session::{SessionData, SessionLifetime};
 
 	/// In-memory session backend for tests.
 	struct MockBackend {
@@ -363,6 +363,9 @@ mod tests {
 			created_at: now,
 			last_accessed: now,
 			expires_at: now + Duration::from_secs(3600),
+			lifetime: SessionLifetime::default(),
+			user_agent: None,
+			ip_address: None,
 			id_holder: None,
 		}
 	}
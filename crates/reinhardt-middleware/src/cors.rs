@@ -2,8 +2,10 @@
 // `Default`, bridge, and tests) during the compatibility window.
 #![allow(deprecated)]
 
+use crate::reloadable::ReloadableMiddleware;
 use async_trait::async_trait;
-use reinhardt_conf::CorsSettings;
+use reinhardt_conf::settings::SettingsSnapshot;
+use reinhardt_conf::{CorsSettings, HasSettings};
 use reinhardt_http::{Handler, Middleware, Request, Response, Result};
 use std::sync::Arc;
 
@@ -71,6 +73,21 @@ pub fn create_cors_middleware_from_settings(settings: &CorsSettings) -> CorsMidd
 	CorsMiddleware::new(CorsConfig::from(settings))
 }
 
+/// Build a [`CorsMiddleware`] that follows a shared settings snapshot.
+///
+/// The middleware is rebuilt from the `[cors]` fragment the first time a
+/// request arrives after `snapshot` is reloaded.
+pub fn create_reloadable_cors_middleware<S>(
+	snapshot: Arc<SettingsSnapshot<S>>,
+) -> ReloadableMiddleware<S, CorsMiddleware>
+where
+	S: HasSettings<CorsSettings> + Send + Sync + 'static,
+{
+	ReloadableMiddleware::new(snapshot, |settings: &S| {
+		create_cors_middleware_from_settings(settings.get_settings())
+	})
+}
+
 /// CORS middleware
 pub struct CorsMiddleware {
 	config: CorsConfig,
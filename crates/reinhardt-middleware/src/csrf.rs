@@ -3,13 +3,35 @@
 //! This module provides tower/hyper-based CSRF middleware with:
 //! - Automatic token generation and validation
 //! - Cookie and session-based token storage
-//! - Header and form-based token extraction
+//! - Header-based token extraction (`X-CSRFToken`) for fetch and server_fn calls
+//! - Double-submit cookie validation when no session is present
+//! - BREACH-resistant masked tokens exposed to handlers as [`CsrfToken`]
 //! - Configurable trusted origins
-//! - Exempt paths for specific endpoints
+//! - Exempt paths and `#[csrf_exempt]` handlers
+//!
+//! ## Token flow
+//!
+//! The `csrftoken` cookie holds the unmasked token. With a session, the token
+//! is an HMAC of the session ID, so it rotates whenever the session is cycled
+//! (e.g. on login). Without a session, a random token is issued once and then
+//! reused (double-submit cookie pattern).
+//!
+//! Before the handler runs, a freshly masked copy of the token is stored in
+//! the request extensions as [`CsrfToken`]. Embed that value in rendered pages;
+//! it differs on every response, which defeats compression side channels.
+//! Unsafe requests must echo the token, masked or not, in the `X-CSRFToken`
+//! header. The cookie alone is never accepted as proof.
+//!
+//! ## Exempt handlers
+//!
+//! Handlers annotated with `#[csrf_exempt]` are marked by the router with
+//! [`CsrfExempt`](reinhardt_http::CsrfExempt). The marker is set after route
+//! matching, so the middleware must be installed on the router (e.g. with
+//! `ServerRouter::with_middleware`) to see it.
 
 use async_trait::async_trait;
 use hyper::Method;
-use reinhardt_http::{Handler, Middleware, Request, Response, Result};
+use reinhardt_http::{CsrfExempt, Handler, Middleware, Request, Response, Result};
 use sha2::{Digest, Sha256};
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -22,7 +44,8 @@ pub use reinhardt_core::security::csrf::{
 	REASON_CSRF_TOKEN_MISSING, REASON_INCORRECT_LENGTH, REASON_INSECURE_REFERER,
 	REASON_INVALID_CHARACTERS, REASON_MALFORMED_REFERER, REASON_NO_CSRF_COOKIE, REASON_NO_REFERER,
 	RejectRequest, SameSite, check_origin, check_referer, check_token_hmac as check_token,
-	get_secret_bytes as get_secret, get_token_hmac as get_token, is_same_domain,
+	get_secret_bytes as get_secret, get_token_hmac as get_token, is_same_domain, mask_token,
+	unmask_token, verify_double_submit,
 };
 
 /// CSRF middleware configuration
@@ -88,28 +111,37 @@ pub struct CsrfMiddleware {
 }
 
 impl CsrfMiddleware {
-	/// Get session ID from request
+	/// Get the session ID the CSRF token is bound to, if any
 	///
 	/// Priority order:
 	/// 1. Session ID from extensions (set by session middleware)
 	/// 2. Session cookie
-	/// 3. Generated from request metadata
-	fn get_session_id(request: &Request) -> String {
+	fn session_id(request: &Request) -> Option<String> {
 		// Try to get session ID from extensions (set by session middleware)
 		if let Some(session_id) = request.extensions.get::<String>() {
-			return session_id.to_owned();
+			return Some(session_id);
 		}
 
 		// Try to get session ID from cookie
-		if let Some(cookie_header) = request.headers.get("Cookie")
-			&& let Ok(cookie_str) = cookie_header.to_str()
-		{
-			for cookie in cookie_str.split(';') {
-				let parts: Vec<&str> = cookie.trim().splitn(2, '=').collect();
-				if parts.len() == 2 && parts[0] == "sessionid" {
-					return parts[1].to_string();
-				}
-			}
+		Self::cookie_value(request, "sessionid")
+	}
+
+	/// Read a cookie value from the request
+	fn cookie_value(request: &Request, name: &str) -> Option<String> {
+		let cookie_header = request.headers.get("Cookie")?;
+		let cookie_str = cookie_header.to_str().ok()?;
+		cookie_str.split(';').find_map(|cookie| {
+			let (key, value) = cookie.trim().split_once('=')?;
+			(key == name).then(|| value.to_string())
+		})
+	}
+
+	/// Get session ID from request
+	///
+	/// Falls back to a random ID when the request has no session.
+	fn get_session_id(request: &Request) -> String {
+		if let Some(session_id) = Self::session_id(request) {
+			return session_id;
 		}
 
 		// Fallback: generate a cryptographically random session ID.
@@ -182,28 +214,37 @@ impl CsrfMiddleware {
 		}
 	}
 
-	/// Extract CSRF token from request
+	/// Extract the submitted CSRF token from the request header
+	///
+	/// The cookie is deliberately not consulted: browsers attach it to
+	/// cross-site requests, so only a value echoed by same-origin script
+	/// proves the request is legitimate.
 	fn extract_token(&self, request: &Request) -> Option<String> {
-		// Try header first
-		if let Some(header_value) = request.headers.get(&self.config.csrf_config.header_name)
-			&& let Ok(token) = header_value.to_str()
-		{
-			return Some(token.to_string());
-		}
+		request
+			.headers
+			.get(&self.config.csrf_config.header_name)
+			.and_then(|value| value.to_str().ok())
+			.map(str::to_string)
+	}
 
-		// Try cookie
-		if let Some(cookie_header) = request.headers.get("Cookie")
-			&& let Ok(cookies) = cookie_header.to_str()
-		{
-			for cookie in cookies.split(';') {
-				let parts: Vec<&str> = cookie.trim().splitn(2, '=').collect();
-				if parts.len() == 2 && parts[0] == self.config.csrf_config.cookie_name {
-					return Some(parts[1].to_string());
-				}
-			}
-		}
+	/// Read the CSRF cookie if it holds a well-formed token
+	fn cookie_token(&self, request: &Request) -> Option<String> {
+		Self::cookie_value(request, &self.config.csrf_config.cookie_name)
+			.filter(|token| token.len() == CSRF_TOKEN_LENGTH)
+			.filter(|token| token.chars().all(|c| c.is_ascii_hexdigit()))
+	}
 
-		None
+	/// Token stored in the CSRF cookie for this request
+	///
+	/// Session-bound requests use the HMAC of the session ID. Requests without
+	/// a session keep their existing cookie token, or get a fresh random one.
+	fn current_token(&self, request: &Request, secret: &[u8]) -> String {
+		match Self::session_id(request) {
+			Some(session_id) => get_token(secret, &session_id),
+			None => self
+				.cookie_token(request)
+				.unwrap_or_else(|| hex::encode(get_secret())),
+		}
 	}
 
 	/// Check if request is from secure connection
@@ -288,11 +329,28 @@ impl CsrfMiddleware {
 			reinhardt_core::exception::Error::Authorization(REASON_CSRF_TOKEN_MISSING.to_string())
 		})?;
 
-		// Get secret and session_id
-		let (secret, _) = self.get_or_create_secret(request);
-		let session_id = Self::get_session_id(request);
+		// Without a session, fall back to the double-submit cookie pattern
+		let Some(session_id) = Self::session_id(request) else {
+			let cookie_token = self.cookie_token(request).ok_or_else(|| {
+				reinhardt_core::exception::Error::Authorization(REASON_NO_CSRF_COOKIE.to_string())
+			})?;
+			if !verify_double_submit(&token, &cookie_token) {
+				return Err(reinhardt_core::exception::Error::Authorization(
+					"CSRF token validation failed: CSRF token mismatch".to_string(),
+				));
+			}
+			return Ok(());
+		};
+
+		// Accept masked tokens by unmasking them first
+		let token = if token.len() == CSRF_TOKEN_LENGTH * 4 {
+			unmask_token(&token).unwrap_or(token)
+		} else {
+			token
+		};
 
 		// Validate token using HMAC
+		let (secret, _) = self.get_or_create_secret(request);
 		check_token(&token, &secret, &session_id).map_err(|e| {
 			reinhardt_core::exception::Error::Authorization(format!(
 				"CSRF token validation failed: {}",
@@ -327,6 +385,11 @@ impl Middleware for CsrfMiddleware {
 			return handler.handle(request).await;
 		}
 
+		// Handlers marked `#[csrf_exempt]` by the router
+		if request.extensions.get::<CsrfExempt>().is_some() {
+			return handler.handle(request).await;
+		}
+
 		// Get or create CSRF secret
 		let (secret, _is_new) = self.get_or_create_secret(&request);
 
@@ -341,9 +404,11 @@ impl Middleware for CsrfMiddleware {
 			self.validate_csrf(&request)?;
 		}
 
-		// Generate HMAC-based token using session_id (before moving request)
-		let session_id = Self::get_session_id(&request);
-		let token = get_token(&secret, &session_id);
+		// Resolve the cookie token and expose a masked copy to the handler
+		let token = self.current_token(&request, &secret);
+		request
+			.extensions
+			.insert(CsrfToken::new(mask_token(&token)));
 
 		// Process request
 		// Convert errors to responses so post-processing (e.g., security headers)
@@ -631,7 +696,7 @@ mod tests {
 	}
 
 	#[tokio::test]
-	async fn test_extract_token_from_cookie() {
+	async fn test_extract_token_ignores_cookie() {
 		let middleware = CsrfMiddleware::new();
 
 		let mut headers = HeaderMap::new();
@@ -647,7 +712,7 @@ mod tests {
 			.unwrap();
 
 		let token = middleware.extract_token(&request);
-		assert_eq!(token, Some("cookie_token_value".to_string()));
+		assert_eq!(token, None);
 	}
 
 	#[tokio::test]
@@ -1053,4 +1118,179 @@ mod tests {
 			"CSRF Set-Cookie header should be appended"
 		);
 	}
+
+	/// Handler that echoes the masked token it received in extensions
+	struct TokenEchoHandler;
+
+	#[async_trait]
+	impl Handler for TokenEchoHandler {
+		async fn handle(&self, request: Request) -> Result<Response> {
+			let token = request.extensions.get::<CsrfToken>().unwrap();
+			Ok(Response::ok().with_body(token.0))
+		}
+	}
+
+	fn no_referer_middleware() -> CsrfMiddleware {
+		CsrfMiddleware::with_config(CsrfMiddlewareConfig {
+			check_referer_header: false,
+			..Default::default()
+		})
+	}
+
+	fn post_request(headers: HeaderMap) -> Request {
+		Request::builder()
+			.method(Method::POST)
+			.uri("/api/server_fn/save")
+			.version(Version::HTTP_11)
+			.headers(headers)
+			.body(Bytes::new())
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	#[case::raw(false)]
+	#[case::masked(true)]
+	#[tokio::test]
+	async fn test_double_submit_without_session_succeeds(#[case] masked: bool) {
+		// Arrange
+		let middleware = no_referer_middleware();
+		let cookie_token = hex::encode(get_secret());
+		let submitted = if masked {
+			mask_token(&cookie_token)
+		} else {
+			cookie_token.clone()
+		};
+		let mut headers = HeaderMap::new();
+		headers.insert(
+			"Cookie",
+			format!("csrftoken={}", cookie_token).parse().unwrap(),
+		);
+		headers.insert("X-CSRFToken", submitted.parse().unwrap());
+
+		// Act
+		let response = middleware
+			.process(post_request(headers), Arc::new(TestHandler))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::OK);
+	}
+
+	#[rstest]
+	#[case::cookie_only(None)]
+	#[case::mismatched_header(Some("0".repeat(CSRF_TOKEN_LENGTH)))]
+	#[tokio::test]
+	async fn test_double_submit_rejects_missing_or_mismatched_header(
+		#[case] header: Option<String>,
+	) {
+		// Arrange
+		let middleware = no_referer_middleware();
+		let mut headers = HeaderMap::new();
+		headers.insert(
+			"Cookie",
+			format!("csrftoken={}", hex::encode(get_secret()))
+				.parse()
+				.unwrap(),
+		);
+		if let Some(header) = header {
+			headers.insert("X-CSRFToken", header.parse().unwrap());
+		}
+
+		// Act
+		let result = middleware
+			.process(post_request(headers), Arc::new(TestHandler))
+			.await;
+
+		// Assert
+		assert!(result.is_err());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_masked_session_token_is_accepted() {
+		// Arrange
+		let secret = "abcdefghijklmnopqrstuvwxyz012345";
+		let mut middleware = no_referer_middleware();
+		middleware.test_secret = Some(secret.to_string());
+		let session_id = "test_session_id";
+		let masked = mask_token(&get_token(secret.as_bytes(), session_id));
+		let mut headers = HeaderMap::new();
+		headers.insert(
+			"Cookie",
+			format!("sessionid={}", session_id).parse().unwrap(),
+		);
+		headers.insert("X-CSRFToken", masked.parse().unwrap());
+
+		// Act
+		let response = middleware
+			.process(post_request(headers), Arc::new(TestHandler))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::OK);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_csrf_exempt_marker_skips_validation() {
+		// Arrange
+		let middleware = no_referer_middleware();
+		let request = post_request(HeaderMap::new());
+		request.extensions.insert(CsrfExempt);
+
+		// Act
+		let response = middleware
+			.process(request, Arc::new(TestHandler))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::OK);
+		assert!(!response.headers.contains_key("Set-Cookie"));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_handler_receives_masked_token_rotated_per_response() {
+		// Arrange
+		let middleware = CsrfMiddleware::new();
+		let cookie_token = hex::encode(get_secret());
+		let get_request = || {
+			let mut headers = HeaderMap::new();
+			headers.insert(
+				"Cookie",
+				format!("csrftoken={}", cookie_token).parse().unwrap(),
+			);
+			Request::builder()
+				.method(Method::GET)
+				.uri("/form")
+				.version(Version::HTTP_11)
+				.headers(headers)
+				.body(Bytes::new())
+				.build()
+				.unwrap()
+		};
+
+		// Act
+		let first = middleware
+			.process(get_request(), Arc::new(TokenEchoHandler))
+			.await
+			.unwrap();
+		let second = middleware
+			.process(get_request(), Arc::new(TokenEchoHandler))
+			.await
+			.unwrap();
+
+		// Assert
+		let first_masked = String::from_utf8(first.body.to_vec()).unwrap();
+		let second_masked = String::from_utf8(second.body.to_vec()).unwrap();
+		assert_ne!(first_masked, second_masked);
+		assert_eq!(unmask_token(&first_masked), Some(cookie_token.clone()));
+		assert_eq!(unmask_token(&second_masked), Some(cookie_token.clone()));
+		let set_cookie = first.headers.get("Set-Cookie").unwrap().to_str().unwrap();
+		assert!(set_cookie.contains(&format!("csrftoken={}", cookie_token)));
+	}
 }
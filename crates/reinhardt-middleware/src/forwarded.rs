@@ -0,0 +1,348 @@
+//! Proxied scheme detection and canonical host redirects
+//!
+//! Shared by [`HttpsRedirectMiddleware`](crate::HttpsRedirectMiddleware) and
+//! `SecurityMiddleware`:
+//!
+//! - [`ForwardedSchemeConfig`] decides whether a request reached the proxy
+//!   over HTTPS, reading configurable forwarded headers (`X-Forwarded-Proto`,
+//!   `Forwarded`, `X-Forwarded-Ssl`, ...) only from trusted proxies.
+//! - [`canonical_host_for`] maps the `www` / apex counterpart of a canonical
+//!   host to the canonical host, like Django's `PREPEND_WWW` in both
+//!   directions.
+//! - [`ClientIpMiddleware`] installs a [`ClientIpResolver`] on every request
+//!   so that [`Request::client_ip`] resolves `Forwarded` / `X-Forwarded-For`
+//!   chains the same way for throttling, IP permissions, sessions and the
+//!   admin audit log.
+
+use async_trait::async_trait;
+use reinhardt_http::{ClientIpResolver, Handler, Middleware, Request, Response, Result};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// Forwarded headers honored from trusted proxies
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_middleware::ForwardedSchemeConfig;
+///
+/// let config = ForwardedSchemeConfig::new()
+///     .with_headers(vec!["forwarded".to_string(), "x-forwarded-proto".to_string()])
+///     .with_trusted_proxies(vec!["10.0.0.0/8".to_string()]);
+/// assert!(config.is_trusted_proxy("10.1.2.3".parse().unwrap()));
+/// assert!(!config.is_trusted_proxy("192.168.0.1".parse().unwrap()));
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct ForwardedSchemeConfig {
+	/// Headers carrying the client scheme, checked in order
+	///
+	/// The first header present decides. `Forwarded` is read as RFC 7239
+	/// (`proto=https`); other headers must hold `https` or `on`. In a
+	/// comma-separated chain only the last entry, set by the trusted proxy,
+	/// is read.
+	pub headers: Vec<String>,
+	/// Trusted proxy IP addresses or CIDR networks
+	///
+	/// Forwarded headers from any other peer are ignored.
+	pub trusted_proxies: Vec<String>,
+}
+
+impl Default for ForwardedSchemeConfig {
+	fn default() -> Self {
+		Self {
+			headers: vec!["x-forwarded-proto".to_string()],
+			trusted_proxies: Vec::new(),
+		}
+	}
+}
+
+impl ForwardedSchemeConfig {
+	/// Create a configuration reading `X-Forwarded-Proto` from no proxy
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Set the headers carrying the client scheme
+	pub fn with_headers(mut self, headers: Vec<String>) -> Self {
+		self.headers = headers;
+		self
+	}
+
+	/// Set the trusted proxy IP addresses or CIDR networks
+	pub fn with_trusted_proxies(mut self, proxies: Vec<String>) -> Self {
+		self.trusted_proxies = proxies;
+		self
+	}
+
+	/// Check if an IP address belongs to a trusted proxy
+	pub fn is_trusted_proxy(&self, ip: IpAddr) -> bool {
+		self.trusted_proxies.iter().any(|proxy| {
+			// Try parsing as CIDR network
+			if let Ok(network) = proxy.parse::<ipnet::IpNet>() {
+				return network.contains(&ip);
+			}
+			// Try parsing as single IP
+			if let Ok(proxy_ip) = proxy.parse::<IpAddr>() {
+				return proxy_ip == ip;
+			}
+			false
+		})
+	}
+
+	/// Trusted proxy entries that are neither an IP address nor a CIDR network
+	pub fn invalid_proxies(&self) -> Vec<&str> {
+		self.trusted_proxies
+			.iter()
+			.map(String::as_str)
+			.filter(|proxy| {
+				proxy.parse::<ipnet::IpNet>().is_err() && proxy.parse::<IpAddr>().is_err()
+			})
+			.collect()
+	}
+
+	/// Check if the client reached the server (or its proxy) over HTTPS
+	///
+	/// Honors [`Request::is_secure`] first, then the configured headers when
+	/// the peer is a trusted proxy.
+	pub fn is_secure(&self, request: &Request) -> bool {
+		if request.is_secure() {
+			return true;
+		}
+
+		let Some(peer) = request.remote_addr else {
+			return false;
+		};
+		if !self.is_trusted_proxy(peer.ip()) {
+			return false;
+		}
+
+		self.headers
+			.iter()
+			.find_map(|name| {
+				let value = request.headers.get(name.as_str())?.to_str().ok()?;
+				Some(header_says_https(name, value))
+			})
+			.unwrap_or(false)
+	}
+}
+
+/// Middleware installing a [`ClientIpResolver`] on every request
+///
+/// Place it before any middleware reading [`Request::client_ip`].
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_middleware::ClientIpMiddleware;
+///
+/// let middleware = ClientIpMiddleware::from_cidrs(&["10.0.0.0/8", "fd00::/8"]).unwrap();
+/// assert_eq!(middleware.resolver().trusted_proxies().len(), 2);
+/// assert!(ClientIpMiddleware::from_cidrs(&["proxy.internal"]).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClientIpMiddleware {
+	resolver: ClientIpResolver,
+}
+
+impl ClientIpMiddleware {
+	/// Create the middleware with a resolver
+	pub fn new(resolver: ClientIpResolver) -> Self {
+		Self { resolver }
+	}
+
+	/// Create the middleware trusting IP addresses or CIDR networks
+	pub fn from_cidrs<S: AsRef<str>>(
+		trusted_proxies: &[S],
+	) -> std::result::Result<Self, reinhardt_http::InvalidProxyError> {
+		ClientIpResolver::from_cidrs(trusted_proxies).map(Self::new)
+	}
+
+	/// The installed resolver
+	pub fn resolver(&self) -> &ClientIpResolver {
+		&self.resolver
+	}
+}
+
+#[async_trait]
+impl Middleware for ClientIpMiddleware {
+	async fn process(&self, request: Request, handler: Arc<dyn Handler>) -> Result<Response> {
+		request.set_client_ip_resolver(self.resolver.clone());
+		handler.handle(request).await
+	}
+}
+
+/// Whether a forwarded header value names the HTTPS scheme
+///
+/// Only the last entry of a comma-separated list is read: it is the one
+/// appended by the trusted proxy, while earlier entries come from the client.
+fn header_says_https(name: &str, value: &str) -> bool {
+	let last = value.rsplit(',').next().unwrap_or("").trim();
+	if name.eq_ignore_ascii_case("forwarded") {
+		last.split(';')
+			.filter_map(|pair| pair.split_once('='))
+			.any(|(key, value)| {
+				key.trim().eq_ignore_ascii_case("proto")
+					&& value.trim().trim_matches('"').eq_ignore_ascii_case("https")
+			})
+	} else {
+		last.eq_ignore_ascii_case("https") || last.eq_ignore_ascii_case("on")
+	}
+}
+
+/// Host to redirect to when `host` is the `www` / apex counterpart of `canonical`
+///
+/// Returns `None` when `host` already is the canonical host or is unrelated
+/// to it, so that foreign `Host` headers never produce redirects. The port of
+/// `host` is kept.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_middleware::canonical_host_for;
+///
+/// assert_eq!(canonical_host_for("example.com", "www.example.com").as_deref(), Some("example.com"));
+/// assert_eq!(canonical_host_for("www.example.com", "example.com:8443").as_deref(), Some("www.example.com:8443"));
+/// assert_eq!(canonical_host_for("example.com", "example.com"), None);
+/// assert_eq!(canonical_host_for("example.com", "evil.com"), None);
+/// ```
+pub fn canonical_host_for(canonical: &str, host: &str) -> Option<String> {
+	let (name, port) = match host.rsplit_once(':') {
+		Some((name, port)) if !name.contains(':') => (name, Some(port)),
+		_ => (host, None),
+	};
+	let canonical = canonical.to_ascii_lowercase();
+	let name = name.to_ascii_lowercase();
+
+	let counterpart = match canonical.strip_prefix("www.") {
+		Some(apex) => apex.to_string(),
+		None => format!("www.{}", canonical),
+	};
+	if name != counterpart {
+		return None;
+	}
+
+	Some(match port {
+		Some(port) => format!("{}:{}", canonical, port),
+		None => canonical,
+	})
+}
+
+/// Whether `host` can be used as a canonical host name
+pub(crate) fn is_valid_canonical_host(host: &str) -> bool {
+	!host.is_empty()
+		&& host
+			.chars()
+			.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use hyper::{HeaderMap, Method};
+	use rstest::rstest;
+	use std::net::SocketAddr;
+
+	fn request(peer: &str, headers: &[(&str, &str)]) -> Request {
+		let mut map = HeaderMap::new();
+		for (name, value) in headers {
+			map.insert(
+				hyper::header::HeaderName::from_bytes(name.as_bytes()).unwrap(),
+				value.parse().unwrap(),
+			);
+		}
+		Request::builder()
+			.method(Method::GET)
+			.uri("/")
+			.headers(map)
+			.remote_addr(SocketAddr::new(peer.parse().unwrap(), 443))
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	#[case::forwarded_proto("x-forwarded-proto", "https", true)]
+	#[case::forwarded_proto_chain("x-forwarded-proto", "https, http", false)]
+	#[case::forwarded_proto_chain_proxy_https("x-forwarded-proto", "http, https", true)]
+	#[case::forwarded_proto_http("x-forwarded-proto", "http", false)]
+	#[case::forwarded_rfc7239("forwarded", "for=1.2.3.4;proto=https;by=10.0.0.1", true)]
+	#[case::forwarded_quoted("forwarded", "proto=\"HTTPS\"", true)]
+	#[case::forwarded_http("forwarded", "for=1.2.3.4;proto=http", false)]
+	#[case::forwarded_ssl("x-forwarded-ssl", "on", true)]
+	fn test_scheme_from_trusted_proxy(
+		#[case] header: &str,
+		#[case] value: &str,
+		#[case] expected: bool,
+	) {
+		// Arrange
+		let config = ForwardedSchemeConfig::new()
+			.with_headers(vec![
+				"forwarded".to_string(),
+				"x-forwarded-proto".to_string(),
+				"x-forwarded-ssl".to_string(),
+			])
+			.with_trusted_proxies(vec!["10.0.0.0/8".to_string()]);
+
+		// Act
+		let secure = config.is_secure(&request("10.0.0.1", &[(header, value)]));
+
+		// Assert
+		assert_eq!(secure, expected);
+	}
+
+	#[rstest]
+	fn test_scheme_headers_ignored_from_untrusted_peer() {
+		// Arrange
+		let config =
+			ForwardedSchemeConfig::new().with_trusted_proxies(vec!["10.0.0.1".to_string()]);
+
+		// Act
+		let secure = config.is_secure(&request("203.0.113.7", &[("x-forwarded-proto", "https")]));
+
+		// Assert
+		assert!(!secure);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_client_ip_middleware_installs_resolver() {
+		// Arrange
+		struct EchoIp;
+
+		#[async_trait]
+		impl Handler for EchoIp {
+			async fn handle(&self, request: Request) -> Result<Response> {
+				let ip = request
+					.client_ip()
+					.map(|ip| ip.to_string())
+					.unwrap_or_default();
+				Ok(Response::ok().with_body(ip))
+			}
+		}
+
+		let middleware = ClientIpMiddleware::from_cidrs(&["10.0.0.0/8"]).unwrap();
+		let request = request(
+			"10.0.0.1",
+			&[("x-forwarded-for", "1.1.1.1, 203.0.113.7, 10.0.0.2")],
+		);
+
+		// Act
+		let response = middleware.process(request, Arc::new(EchoIp)).await.unwrap();
+
+		// Assert
+		assert_eq!(response.body, "203.0.113.7");
+	}
+
+	#[rstest]
+	fn test_invalid_proxies() {
+		// Arrange
+		let config = ForwardedSchemeConfig::new().with_trusted_proxies(vec![
+			"10.0.0.1".to_string(),
+			"10.0.0.0/8".to_string(),
+			"proxy.internal".to_string(),
+		]);
+
+		// Act / Assert
+		assert_eq!(config.invalid_proxies(), vec!["proxy.internal"]);
+	}
+}
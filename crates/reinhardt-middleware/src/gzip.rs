@@ -1,6 +1,9 @@
 //! GZip Compression Middleware
 //!
 //! Compresses response content using gzip encoding when the client supports it.
+//!
+//! To negotiate between gzip, brotli and zstd, use
+//! [`CompressionMiddleware`](crate::CompressionMiddleware) instead.
 
 use async_trait::async_trait;
 use bytes::Bytes;
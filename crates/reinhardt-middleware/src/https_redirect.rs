@@ -2,7 +2,12 @@
 //!
 //! Automatically redirects HTTP requests to HTTPS.
 //! Similar to Django's SECURE_SSL_REDIRECT setting.
+//!
+//! Optionally redirects the `www` / apex counterpart of a canonical host to
+//! the canonical host in the same hop, and detects HTTPS behind proxies from
+//! configurable forwarded headers (see [`crate::forwarded`]).
 
+use crate::forwarded::{ForwardedSchemeConfig, canonical_host_for};
 use async_trait::async_trait;
 use hyper::StatusCode;
 use reinhardt_http::{Handler, Middleware, Request, Response, Result};
@@ -21,6 +26,10 @@ pub struct HttpsRedirectConfig {
 	/// Allowed host names for redirect (prevents host header injection).
 	/// If empty, all requests without a valid allowed host are rejected with 400 Bad Request.
 	pub allowed_hosts: Vec<String>,
+	/// Canonical host name; its `www` / apex counterpart is redirected to it
+	pub canonical_host: Option<String>,
+	/// Forwarded headers and trusted proxies used to detect HTTPS
+	pub forwarded_scheme: ForwardedSchemeConfig,
 }
 
 impl Default for HttpsRedirectConfig {
@@ -30,6 +39,8 @@ impl Default for HttpsRedirectConfig {
 			exempt_paths: vec![],
 			status_code: StatusCode::MOVED_PERMANENTLY, // 301
 			allowed_hosts: vec![],
+			canonical_host: None,
+			forwarded_scheme: ForwardedSchemeConfig::default(),
 		}
 	}
 }
@@ -190,8 +201,19 @@ impl Middleware for HttpsRedirectMiddleware {
 			return handler.handle(request).await;
 		}
 
-		// If request is already secure, pass through
-		if request.is_secure() {
+		let host_value = request
+			.headers
+			.get(hyper::header::HOST)
+			.and_then(|h| h.to_str().ok());
+		let canonical_host = self
+			.config
+			.canonical_host
+			.as_deref()
+			.zip(host_value)
+			.and_then(|(canonical, host)| canonical_host_for(canonical, host));
+
+		// If request is already secure on the canonical host, pass through
+		if canonical_host.is_none() && self.config.forwarded_scheme.is_secure(&request) {
 			return handler.handle(request).await;
 		}
 
@@ -200,24 +222,23 @@ impl Middleware for HttpsRedirectMiddleware {
 			return handler.handle(request).await;
 		}
 
-		// Validate host header against allowed hosts to prevent host header injection
-		let host_value = request
-			.headers
-			.get(hyper::header::HOST)
-			.and_then(|h| h.to_str().ok());
-
-		let validated_host = match self.validate_host(host_value) {
+		// The canonical host comes from configuration; any other host is
+		// validated against allowed hosts to prevent host header injection
+		let target_host = match canonical_host {
 			Some(host) => host,
-			None => {
-				// Reject requests with invalid or disallowed host headers
-				return Ok(Response::new(StatusCode::BAD_REQUEST));
-			}
+			None => match self.validate_host(host_value) {
+				Some(host) => host.to_string(),
+				None => {
+					// Reject requests with invalid or disallowed host headers
+					return Ok(Response::new(StatusCode::BAD_REQUEST));
+				}
+			},
 		};
 
 		// Build HTTPS redirect URL with validated host
 		let https_url = format!(
 			"https://{}{}",
-			validated_host,
+			target_host,
 			request
 				.uri
 				.path_and_query()
@@ -260,6 +281,8 @@ mod tests {
 			exempt_paths: vec![],
 			status_code: StatusCode::MOVED_PERMANENTLY,
 			allowed_hosts: hosts.into_iter().map(String::from).collect(),
+			canonical_host: None,
+			forwarded_scheme: ForwardedSchemeConfig::default(),
 		}
 	}
 
@@ -515,4 +538,87 @@ mod tests {
 		// Assert - host matching should be case-insensitive
 		assert_eq!(response.status, StatusCode::MOVED_PERMANENTLY);
 	}
+
+	#[rstest]
+	#[case::www_to_apex_over_https(
+		"example.com",
+		"www.example.com",
+		true,
+		"https://example.com/test?a=1"
+	)]
+	#[case::apex_to_www_over_http(
+		"www.example.com",
+		"example.com",
+		false,
+		"https://www.example.com/test?a=1"
+	)]
+	#[case::keeps_port(
+		"example.com",
+		"www.example.com:8443",
+		true,
+		"https://example.com:8443/test?a=1"
+	)]
+	#[tokio::test]
+	async fn test_canonical_host_redirect(
+		#[case] canonical: &str,
+		#[case] host: &str,
+		#[case] secure: bool,
+		#[case] expected_location: &str,
+	) {
+		// Arrange
+		let mut config = config_with_allowed_hosts(vec!["example.com", "www.example.com"]);
+		config.canonical_host = Some(canonical.to_string());
+		let middleware = HttpsRedirectMiddleware::new(config);
+
+		let mut headers = HeaderMap::new();
+		headers.insert(hyper::header::HOST, host.parse().unwrap());
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/test?a=1")
+			.headers(headers)
+			.secure(secure)
+			.build()
+			.unwrap();
+
+		// Act
+		let response = middleware
+			.process(request, Arc::new(TestHandler))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::MOVED_PERMANENTLY);
+		assert_eq!(response.headers.get("Location").unwrap(), expected_location);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_forwarded_header_from_trusted_proxy_is_secure() {
+		// Arrange
+		let mut config = config_with_allowed_hosts(vec!["example.com"]);
+		config.forwarded_scheme = ForwardedSchemeConfig::new()
+			.with_headers(vec!["forwarded".to_string()])
+			.with_trusted_proxies(vec!["10.0.0.0/8".to_string()]);
+		let middleware = HttpsRedirectMiddleware::new(config);
+
+		let mut headers = HeaderMap::new();
+		headers.insert(hyper::header::HOST, "example.com".parse().unwrap());
+		headers.insert("forwarded", "for=203.0.113.7;proto=https".parse().unwrap());
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/test")
+			.headers(headers)
+			.remote_addr("10.1.2.3:443".parse().unwrap())
+			.build()
+			.unwrap();
+
+		// Act
+		let response = middleware
+			.process(request, Arc::new(TestHandler))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::OK);
+	}
 }
@@ -34,9 +34,12 @@
 //!
 //! ### Performance & Caching
 //!
-//! - **[`CacheMiddleware`]**: HTTP response caching with configurable strategies
+//! - **[`CacheMiddleware`]**: Full-page HTTP response caching honoring `Vary`, with purge by URL or tag
+//! - **[`CoalescingMiddleware`]**: Runs the handler once for concurrent identical cacheable requests
 //! - **`GZipMiddleware`**: Gzip compression (requires `compression` feature)
 //! - **`BrotliMiddleware`**: Brotli compression (requires `compression` feature)
+//! - **`CompressionMiddleware`**: gzip, brotli or zstd negotiated from `Accept-Encoding`,
+//!   with streaming encoding (requires `compression` feature)
 //! - **[`ETagMiddleware`]**: ETag generation and validation for conditional requests
 //! - **[`ConditionalGetMiddleware`]**: Conditional GET support with Last-Modified headers
 //!
@@ -46,6 +49,10 @@
 //! - **[`TracingMiddleware`]**: Distributed tracing with trace/span ID propagation
 //! - **[`MetricsMiddleware`]**: Performance metrics collection
 //! - **[`RequestIdMiddleware`]**: Unique request ID generation
+//! - **`ProfilerMiddleware`**: Per-request flamegraph capture (requires `profiling` feature)
+//! - **[`RequestRecorderMiddleware`]**: Debug-only request/response recording with HAR export
+//! - **`SlowRequestMiddleware`**: Slow request and slow query alerts sent to log, webhook
+//!   or email sinks (requires `slow-alerts` feature)
 //!
 //! ### Rate Limiting & Resilience
 //!
@@ -59,6 +66,7 @@
 //! - **[`SessionMiddleware`]**: Session management with pluggable storage backends
 //! - **[`SiteMiddleware`]**: Multi-site support with site identification
 //! - **[`LocaleMiddleware`]**: Internationalization and locale detection
+//! - **[`TimezoneMiddleware`]**: Per-request time zone activation
 //!
 //! ### Utility
 //!
@@ -66,6 +74,7 @@
 //! - **[`BrokenLinkEmailsMiddleware`]**: Broken link notification via email
 //! - **[`FlatpagesMiddleware`]**: Static page serving from database
 //! - **[`RedirectFallbackMiddleware`]**: Fallback redirect handling
+//! - **[`RedirectsMiddleware`]**: Per-site old URL → new URL redirects served on 404
 //!
 //! ## Quick Start
 //!
@@ -103,9 +112,13 @@
 //! - [`logging`]: Structured request/response logging
 //! - [`metrics`]: Performance metrics collection and export
 //! - `rate_limit`: API rate limiting (requires `rate-limit` feature)
+//! - `profiler`: Per-request flamegraph capture served from a debug endpoint (requires `profiling` feature)
+//! - [`reloadable`]: Middleware rebuilt when a shared settings snapshot is reloaded
 //! - [`request_id`]: Unique request ID generation and propagation
+//! - [`request_recorder`]: Request/response recording, HAR export and replay for debugging
 //! - [`session`]: Session management with pluggable storage backends
 //! - [`timeout`]: Request timeout enforcement
+//! - [`timezone`]: Per-request time zone activation
 //! - [`tracing`]: Distributed tracing with trace/span ID propagation
 //! - [`xframe`]: X-Frame-Options clickjacking protection
 //!
@@ -114,9 +127,10 @@
 //! | Feature | Default | Description |
 //! |---------|---------|-------------|
 //! | `cors` | disabled | Cross-Origin Resource Sharing middleware |
-//! | `compression` | disabled | GZip and Brotli compression middleware |
+//! | `compression` | disabled | GZip, Brotli and negotiated gzip/brotli/zstd compression middleware |
 //! | `rate-limit` | disabled | API rate limiting middleware |
 //! | `security` | disabled | Combined security headers middleware |
+//! | `profiling` | disabled | Per-request flamegraph capture with `pprof` (Unix only) |
 //! | `sessions` | disabled | Session-based authentication middleware |
 //! | `auth-jwt` | disabled | JWT Bearer token authentication middleware |
 //! | `sqlx` | disabled | Database-backed session storage via SQLx |
@@ -128,16 +142,17 @@
 //! Middleware execution order matters. A typical recommended order:
 //!
 //! 1. `RequestIdMiddleware` - Generate request ID first
-//! 2. `LoggingMiddleware` - Log all requests
-//! 3. `TracingMiddleware` - Start tracing span
-//! 4. `SecurityMiddleware` - Apply security headers
-//! 5. `CorsMiddleware` - Handle CORS preflight
-//! 6. `SessionMiddleware` - Load session and populate `AuthState`
-//! 7. `AuthenticationMiddleware` or `JwtAuthMiddleware` - Authenticate non-session credentials
-//! 8. `LoginRequiredMiddleware` - Enforce login (optional)
-//! 9. `CsrfMiddleware` - Validate CSRF token
-//! 10. `RateLimitMiddleware` - Apply rate limits
-//! 11. Application handlers
+//! 2. `ClientIpMiddleware` - Resolve the client IP behind trusted proxies
+//! 3. `LoggingMiddleware` - Log all requests
+//! 4. `TracingMiddleware` - Start tracing span
+//! 5. `SecurityMiddleware` - Apply security headers
+//! 6. `CorsMiddleware` - Handle CORS preflight
+//! 7. `SessionMiddleware` - Load session and populate `AuthState`
+//! 8. `AuthenticationMiddleware` or `JwtAuthMiddleware` - Authenticate non-session credentials
+//! 9. `LoginRequiredMiddleware` - Enforce login (optional)
+//! 10. `CsrfMiddleware` - Validate CSRF token
+//! 11. `RateLimitMiddleware` - Apply rate limits
+//! 12. Application handlers
 
 #![warn(missing_docs)]
 pub mod allowed_hosts;
@@ -150,7 +165,10 @@ pub mod broken_link;
 pub mod brotli;
 pub mod cache;
 pub mod circuit_breaker;
+pub mod coalescing;
 pub mod common;
+#[cfg(feature = "compression")]
+pub mod compression;
 pub mod conditional;
 #[cfg(feature = "sessions")]
 /// Cookie-based session authentication middleware (requires `sessions` feature).
@@ -163,6 +181,7 @@ pub mod csp_helpers;
 pub mod csrf;
 pub mod etag;
 pub mod flatpages;
+pub mod forwarded;
 #[cfg(feature = "compression")]
 pub mod gzip;
 pub mod honeypot;
@@ -178,21 +197,32 @@ pub mod login_required;
 pub mod messages;
 pub mod metrics;
 pub mod origin_guard;
+/// Per-request flamegraph capture (requires `profiling` feature).
+#[cfg_attr(docsrs, doc(cfg(feature = "profiling")))]
+#[cfg(feature = "profiling")]
+pub mod profiler;
 #[cfg(feature = "rate-limit")]
 pub mod rate_limit;
 pub mod redirect_fallback;
+pub mod redirects;
 #[cfg(feature = "session-redis")]
 pub mod redis_session;
+pub mod reloadable;
 /// Reverse proxy remote user authentication middleware (requires `sessions` feature).
 #[cfg_attr(docsrs, doc(cfg(feature = "sessions")))]
 #[cfg(feature = "sessions")]
 pub mod remote_user;
 pub mod request_id;
+pub mod request_recorder;
 #[cfg(feature = "security")]
 pub mod security_middleware;
 pub mod session;
 pub mod site;
+#[cfg_attr(docsrs, doc(cfg(feature = "slow-alerts")))]
+#[cfg(feature = "slow-alerts")]
+pub mod slow_alerts;
 pub mod timeout;
+pub mod timezone;
 pub mod tracing;
 pub mod xframe;
 pub mod xss;
@@ -206,9 +236,15 @@ pub use auth::AuthenticationMiddleware;
 pub use broken_link::{BrokenLinkConfig, BrokenLinkEmailsMiddleware};
 #[cfg(feature = "compression")]
 pub use brotli::{BrotliConfig, BrotliMiddleware, BrotliQuality};
-pub use cache::{CacheConfig, CacheKeyStrategy, CacheMiddleware, CacheStore};
+pub use cache::{
+	CACHE_TAGS_HEADER, CacheConfig, CacheKeyStrategy, CacheMiddleware, CacheStore, add_cache_tags,
+	add_never_cache_headers,
+};
 pub use circuit_breaker::{CircuitBreakerConfig, CircuitBreakerMiddleware, CircuitState};
+pub use coalescing::CoalescingMiddleware;
 pub use common::{CommonConfig, CommonMiddleware};
+#[cfg(feature = "compression")]
+pub use compression::{CompressionConfig, CompressionMiddleware, ContentEncoding};
 pub use conditional::ConditionalGetMiddleware;
 #[cfg(feature = "sessions")]
 pub use cookie_session_auth::{CookieSessionAuthMiddleware, CookieSessionConfig};
@@ -216,7 +252,9 @@ pub use cookie_session_auth::{CookieSessionAuthMiddleware, CookieSessionConfig};
 #[allow(deprecated)]
 pub use cors::CorsConfig;
 #[cfg(feature = "cors")]
-pub use cors::{CorsMiddleware, create_cors_middleware_from_settings};
+pub use cors::{
+	CorsMiddleware, create_cors_middleware_from_settings, create_reloadable_cors_middleware,
+};
 pub use csp::{CspConfig, CspMiddleware, CspNonce};
 pub use csp_helpers::{csp_nonce_attr, get_csp_nonce};
 pub use csrf::{
@@ -229,6 +267,7 @@ pub use csrf::{
 };
 pub use etag::{ETagConfig, ETagMiddleware};
 pub use flatpages::{Flatpage, FlatpageStore, FlatpagesConfig, FlatpagesMiddleware};
+pub use forwarded::{ClientIpMiddleware, ForwardedSchemeConfig, canonical_host_for};
 #[cfg(feature = "compression")]
 pub use gzip::{GZipConfig, GZipMiddleware};
 pub use honeypot::{HoneypotError, HoneypotField};
@@ -243,19 +282,35 @@ pub use login_required::{
 pub use messages::{CookieStorage, Message, MessageLevel, MessageStorage, SessionStorage};
 pub use metrics::{MetricsConfig, MetricsMiddleware, MetricsStore};
 pub use origin_guard::OriginGuardMiddleware;
+#[cfg(feature = "profiling")]
+pub use profiler::{PROFILE_URL_HEADER, Profile, ProfileStore, ProfilerConfig, ProfilerMiddleware};
 #[cfg(feature = "rate-limit")]
 pub use rate_limit::{RateLimitConfig, RateLimitMiddleware, RateLimitStore, RateLimitStrategy};
 pub use redirect_fallback::{RedirectFallbackMiddleware, RedirectResponseConfig};
+pub use redirects::{Redirect, RedirectStore, RedirectsConfig, RedirectsMiddleware};
 #[cfg(feature = "session-redis")]
 pub use redis_session::RedisSessionBackend;
+pub use reloadable::ReloadableMiddleware;
 #[cfg(feature = "sessions")]
 pub use remote_user::{PersistentRemoteUserMiddleware, REMOTE_USER_HEADER, RemoteUserMiddleware};
 pub use request_id::{REQUEST_ID_HEADER, RequestIdConfig, RequestIdMiddleware};
+pub use request_recorder::{
+	REDACTED, RecordedBody, RecordedExchange, RecordedRequest, RecordedResponse, RecordingStore,
+	RequestRecorderConfig, RequestRecorderMiddleware,
+};
 #[cfg(feature = "security")]
-pub use security_middleware::SecurityMiddleware;
+pub use security_middleware::{
+	HSTS_PRELOAD_MIN_SECONDS, SecurityMiddleware, SecurityMiddlewareCheck,
+};
 pub use session::{SessionConfig, SessionData, SessionMiddleware, SessionStore};
 pub use site::{SITE_ID_HEADER, Site, SiteConfig, SiteMiddleware, SiteRegistry};
+#[cfg(feature = "slow-alerts")]
+pub use slow_alerts::{
+	AlertEvent, AlertKind, AlertSink, EmailAlertSink, LogAlertSink, SlowAlertConfig, SlowAlerts,
+	SlowQueryListener, SlowRequestMiddleware, WebhookAlertSink,
+};
 pub use timeout::{TimeoutConfig, TimeoutMiddleware};
+pub use timezone::{TIMEZONE_COOKIE_NAME, TIMEZONE_HEADER, TimezoneConfig, TimezoneMiddleware};
 pub use tracing::{
 	PARENT_SPAN_ID_HEADER, SPAN_ID_HEADER, Span, SpanStatus, TRACE_ID_HEADER, TraceStore,
 	TracingConfig, TracingMiddleware,
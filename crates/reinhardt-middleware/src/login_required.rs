@@ -1,13 +1,14 @@
 use async_trait::async_trait;
 use std::sync::Arc;
 
+use reinhardt_http::redirect::{REDIRECT_FIELD_NAME, login_redirect};
 use reinhardt_http::{AuthState, Handler, Middleware, Request, Response, Result};
 
 /// Default login URL for unauthenticated users.
-pub const DEFAULT_LOGIN_URL: &str = "/accounts/login/";
+pub const DEFAULT_LOGIN_URL: &str = reinhardt_http::redirect::DEFAULT_LOGIN_URL;
 
 /// Default query parameter name for the redirect URL.
-pub const DEFAULT_REDIRECT_FIELD_NAME: &str = "next";
+pub const DEFAULT_REDIRECT_FIELD_NAME: &str = REDIRECT_FIELD_NAME;
 
 /// Configuration for [`LoginRequiredMiddleware`].
 ///
@@ -113,7 +114,12 @@ impl LoginRequiredConfig {
 /// `JwtAuthMiddleware`). If the
 /// user is not authenticated and the path is not exempt, the middleware
 /// returns a 302 redirect to the configured login URL with the original
-/// path as a query parameter.
+/// path and query string, percent-encoded, as a query parameter.
+///
+/// After login, read the target back with
+/// [`RedirectPolicy::next_url`](reinhardt_http::redirect::RedirectPolicy::next_url)
+/// or [`RedirectPolicy::redirect_next`](reinhardt_http::redirect::RedirectPolicy::redirect_next),
+/// which reject off-site targets.
 ///
 /// # Path Exemption
 ///
@@ -159,14 +165,6 @@ impl LoginRequiredMiddleware {
 	pub fn new(config: LoginRequiredConfig) -> Self {
 		Self { config }
 	}
-
-	/// Builds the redirect URL with the original path as a query parameter.
-	fn build_redirect_url(&self, original_path: &str) -> String {
-		format!(
-			"{}?{}={}",
-			self.config.login_url, self.config.redirect_field_name, original_path
-		)
-	}
 }
 
 impl Default for LoginRequiredMiddleware {
@@ -178,10 +176,10 @@ impl Default for LoginRequiredMiddleware {
 #[async_trait]
 impl Middleware for LoginRequiredMiddleware {
 	async fn process(&self, request: Request, next: Arc<dyn Handler>) -> Result<Response> {
-		let path = request.uri.path().to_string();
+		let path = request.uri.path();
 
 		// Skip exempt paths
-		if self.config.is_exempt(&path) {
+		if self.config.is_exempt(path) {
 			return next.handle(request).await;
 		}
 
@@ -193,8 +191,11 @@ impl Middleware for LoginRequiredMiddleware {
 			.unwrap_or(false);
 
 		if !is_authenticated {
-			let redirect_url = self.build_redirect_url(&path);
-			return Ok(Response::temporary_redirect(&redirect_url));
+			return Ok(login_redirect(
+				&self.config.login_url,
+				&self.config.redirect_field_name,
+				&request,
+			));
 		}
 
 		next.handle(request).await
@@ -270,6 +271,25 @@ mod tests {
 		assert_eq!(location, "/accounts/login/?next=/dashboard");
 	}
 
+	#[rstest]
+	#[tokio::test]
+	async fn test_redirect_preserves_query_string() {
+		// Arrange
+		let middleware = LoginRequiredMiddleware::default();
+		let handler = Arc::new(TestHandler);
+		let request = create_request("/dashboard?tab=2&q=x", Some(AuthState::anonymous()));
+
+		// Act
+		let response = middleware.process(request, handler).await.unwrap();
+
+		// Assert
+		let location = response.headers.get("Location").unwrap().to_str().unwrap();
+		assert_eq!(
+			location,
+			"/accounts/login/?next=/dashboard%3Ftab%3D2%26q%3Dx"
+		);
+	}
+
 	#[rstest]
 	#[tokio::test]
 	async fn test_no_auth_state_gets_redirected() {
@@ -0,0 +1,671 @@
+//! Profiler middleware
+//!
+//! Samples the process with [`pprof`] while a request is handled and stores
+//! the resulting flamegraph keyed by request ID. Profiles are listed and
+//! served as SVG from a debug endpoint, which makes it possible to see where
+//! a slow endpoint spends its time in a staging environment.
+//!
+//! The sampler is process-wide: only one request is profiled at a time, and
+//! the flamegraph also contains work done concurrently by other tasks.
+//! Requests arriving while a profile is being captured are not profiled.
+//!
+//! | Request | Response |
+//! |---------|----------|
+//! | `GET {endpoint}` | Captured profiles (without flamegraphs), oldest first |
+//! | `GET {endpoint}/{request_id}` | Flamegraph of a request as SVG |
+//! | `DELETE {endpoint}` | Drop all captured profiles |
+//!
+//! Requires the `profiling` feature, and a Unix target.
+
+use crate::request_id::REQUEST_ID_HEADER;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hyper::{Method, StatusCode};
+use reinhardt_http::{Handler, Middleware, Request, Response, Result};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use tracing::warn;
+use uuid::Uuid;
+
+/// Response header carrying the URL of the captured flamegraph
+pub const PROFILE_URL_HEADER: &str = "X-Profile-URL";
+
+/// A flamegraph captured for one request
+#[derive(Debug, Clone, Serialize)]
+pub struct Profile {
+	/// ID of the profiled request
+	pub request_id: String,
+	/// HTTP method of the profiled request
+	pub method: String,
+	/// Path of the profiled request
+	pub path: String,
+	/// Response status code
+	pub status: u16,
+	/// When the request was received
+	pub captured_at: DateTime<Utc>,
+	/// Time spent handling the request, in milliseconds
+	pub duration_ms: f64,
+	/// Number of stack samples taken
+	pub samples: usize,
+	/// Flamegraph as an SVG document
+	#[serde(skip)]
+	pub flamegraph: String,
+}
+
+/// Bounded storage for captured profiles
+///
+/// When the buffer is full the oldest profile is dropped.
+#[derive(Debug)]
+pub struct ProfileStore {
+	profiles: RwLock<VecDeque<Profile>>,
+	capacity: usize,
+}
+
+impl ProfileStore {
+	/// Create a store keeping at most `capacity` profiles
+	pub fn new(capacity: usize) -> Self {
+		Self {
+			profiles: RwLock::new(VecDeque::with_capacity(capacity)),
+			capacity,
+		}
+	}
+
+	/// Add a profile, evicting the oldest one when the buffer is full
+	pub fn insert(&self, profile: Profile) {
+		if self.capacity == 0 {
+			return;
+		}
+		let mut profiles = self.profiles.write().unwrap_or_else(|e| e.into_inner());
+		while profiles.len() >= self.capacity {
+			profiles.pop_front();
+		}
+		profiles.push_back(profile);
+	}
+
+	/// All captured profiles, oldest first
+	pub fn profiles(&self) -> Vec<Profile> {
+		self.profiles
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.iter()
+			.cloned()
+			.collect()
+	}
+
+	/// The profile captured for `request_id`, if it is still buffered
+	pub fn get(&self, request_id: &str) -> Option<Profile> {
+		self.profiles
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.iter()
+			.find(|profile| profile.request_id == request_id)
+			.cloned()
+	}
+
+	/// Number of buffered profiles
+	pub fn len(&self) -> usize {
+		self.profiles
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.len()
+	}
+
+	/// Whether no profile is buffered
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Drop all buffered profiles
+	pub fn clear(&self) {
+		self.profiles
+			.write()
+			.unwrap_or_else(|e| e.into_inner())
+			.clear();
+	}
+}
+
+impl Default for ProfileStore {
+	fn default() -> Self {
+		Self::new(ProfilerConfig::DEFAULT_CAPACITY)
+	}
+}
+
+/// Configuration for the profiler middleware
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct ProfilerConfig {
+	/// Path of the debug endpoint (default: `/__debug__/profiles`)
+	pub endpoint: String,
+	/// Sampling frequency in Hz (default: 1000)
+	pub frequency: i32,
+	/// Fraction of requests to profile, from 0.0 to 1.0 (default: 1.0)
+	pub sample_rate: f64,
+	/// Only keep profiles of requests taking at least this long (default: 100ms)
+	pub min_duration: Duration,
+	/// Maximum number of buffered profiles (default: 50)
+	pub capacity: usize,
+	/// Path prefixes that are never profiled
+	pub exclude_paths: Vec<String>,
+}
+
+impl ProfilerConfig {
+	/// Default number of buffered profiles
+	pub const DEFAULT_CAPACITY: usize = 50;
+
+	/// Create a new default configuration
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::ProfilerConfig;
+	///
+	/// let config = ProfilerConfig::new();
+	/// assert_eq!(config.endpoint, "/__debug__/profiles");
+	/// assert_eq!(config.sample_rate, 1.0);
+	/// ```
+	pub fn new() -> Self {
+		Self {
+			endpoint: "/__debug__/profiles".to_string(),
+			frequency: 1000,
+			sample_rate: 1.0,
+			min_duration: Duration::from_millis(100),
+			capacity: Self::DEFAULT_CAPACITY,
+			exclude_paths: vec!["/static/".to_string(), "/health".to_string()],
+		}
+	}
+
+	/// Set a custom debug endpoint
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::ProfilerConfig;
+	///
+	/// let config = ProfilerConfig::new().with_endpoint("/_profiles".to_string());
+	/// assert_eq!(config.endpoint, "/_profiles");
+	/// ```
+	pub fn with_endpoint(mut self, endpoint: String) -> Self {
+		self.endpoint = endpoint;
+		self
+	}
+
+	/// Set the sampling frequency in Hz
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::ProfilerConfig;
+	///
+	/// let config = ProfilerConfig::new().with_frequency(99);
+	/// assert_eq!(config.frequency, 99);
+	/// ```
+	pub fn with_frequency(mut self, frequency: i32) -> Self {
+		self.frequency = frequency;
+		self
+	}
+
+	/// Set the fraction of requests to profile
+	///
+	/// Values are clamped to `0.0..=1.0`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::ProfilerConfig;
+	///
+	/// let config = ProfilerConfig::new().with_sample_rate(0.1);
+	/// assert_eq!(config.sample_rate, 0.1);
+	/// ```
+	pub fn with_sample_rate(mut self, sample_rate: f64) -> Self {
+		self.sample_rate = sample_rate.clamp(0.0, 1.0);
+		self
+	}
+
+	/// Only keep profiles of requests taking at least `min_duration`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use std::time::Duration;
+	/// use reinhardt_middleware::ProfilerConfig;
+	///
+	/// let config = ProfilerConfig::new().with_min_duration(Duration::from_millis(500));
+	/// assert_eq!(config.min_duration, Duration::from_millis(500));
+	/// ```
+	pub fn with_min_duration(mut self, min_duration: Duration) -> Self {
+		self.min_duration = min_duration;
+		self
+	}
+
+	/// Set the maximum number of buffered profiles
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::ProfilerConfig;
+	///
+	/// let config = ProfilerConfig::new().with_capacity(10);
+	/// assert_eq!(config.capacity, 10);
+	/// ```
+	pub fn with_capacity(mut self, capacity: usize) -> Self {
+		self.capacity = capacity;
+		self
+	}
+
+	/// Add path prefixes that are never profiled
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::ProfilerConfig;
+	///
+	/// let config = ProfilerConfig::new()
+	///     .with_excluded_paths(vec!["/admin".to_string()]);
+	/// assert!(config.exclude_paths.contains(&"/admin".to_string()));
+	/// ```
+	pub fn with_excluded_paths(mut self, paths: Vec<String>) -> Self {
+		self.exclude_paths.extend(paths);
+		self
+	}
+}
+
+impl Default for ProfilerConfig {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Set while a request is being profiled; the sampler is process-wide
+static PROFILING: AtomicBool = AtomicBool::new(false);
+
+/// Releases [`PROFILING`] when dropped, including on panic or cancellation
+struct ProfilingSlot;
+
+impl ProfilingSlot {
+	fn acquire() -> Option<Self> {
+		PROFILING
+			.compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+			.ok()
+			.map(|_| Self)
+	}
+}
+
+impl Drop for ProfilingSlot {
+	fn drop(&mut self) {
+		PROFILING.store(false, Ordering::Release);
+	}
+}
+
+/// Middleware capturing per-request flamegraphs
+///
+/// Profiled responses carry an `X-Profile-URL` header pointing at their
+/// flamegraph. The request ID is read from the `X-Request-ID` header, so
+/// place [`RequestIdMiddleware`](crate::RequestIdMiddleware) before this
+/// middleware to correlate profiles with logs; otherwise a random ID is used.
+///
+/// The debug endpoint exposes stack traces of the application and must not
+/// be reachable publicly.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use reinhardt_middleware::{ProfilerConfig, ProfilerMiddleware};
+///
+/// let middleware = ProfilerMiddleware::new(
+///     ProfilerConfig::new()
+///         .with_sample_rate(0.05)
+///         .with_min_duration(Duration::from_millis(250)),
+/// );
+/// assert!(middleware.store().is_empty());
+/// ```
+pub struct ProfilerMiddleware {
+	config: ProfilerConfig,
+	store: Arc<ProfileStore>,
+}
+
+impl ProfilerMiddleware {
+	/// Create a new ProfilerMiddleware with the given configuration
+	pub fn new(config: ProfilerConfig) -> Self {
+		let store = Arc::new(ProfileStore::new(config.capacity));
+		Self { config, store }
+	}
+
+	/// Create a new ProfilerMiddleware with default configuration
+	pub fn with_defaults() -> Self {
+		Self::new(ProfilerConfig::default())
+	}
+
+	/// Create a new ProfilerMiddleware storing profiles in a shared store
+	///
+	/// The store's own capacity applies instead of [`ProfilerConfig::capacity`].
+	pub fn from_arc(config: ProfilerConfig, store: Arc<ProfileStore>) -> Self {
+		Self { config, store }
+	}
+
+	/// Get a reference to the profile store
+	pub fn store(&self) -> &ProfileStore {
+		&self.store
+	}
+
+	/// Get a cloned Arc of the profile store
+	pub fn store_arc(&self) -> Arc<ProfileStore> {
+		Arc::clone(&self.store)
+	}
+
+	/// Check if path is served by the debug endpoint
+	fn is_debug_path(&self, path: &str) -> bool {
+		path.strip_prefix(self.config.endpoint.as_str())
+			.is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+	}
+
+	/// Whether the request is selected for profiling
+	fn should_profile(&self, path: &str) -> bool {
+		!self
+			.config
+			.exclude_paths
+			.iter()
+			.any(|p| path.starts_with(p))
+			&& self.config.sample_rate > 0.0
+			&& (self.config.sample_rate >= 1.0 || rand::random::<f64>() < self.config.sample_rate)
+	}
+
+	/// Handle a request to the debug endpoint
+	fn handle_debug_endpoint(&self, request: &Request) -> Result<Response> {
+		let path = request.uri.path();
+		let rest = path[self.config.endpoint.len()..].trim_matches('/');
+
+		match (&request.method, rest) {
+			(&Method::GET, "") => Response::ok().with_json(&self.store.profiles()),
+			(&Method::DELETE, "") => {
+				self.store.clear();
+				Ok(Response::new(StatusCode::NO_CONTENT))
+			}
+			(&Method::GET, request_id) => match self.store.get(request_id) {
+				Some(profile) => Ok(Response::ok()
+					.with_header("Content-Type", "image/svg+xml")
+					.with_body(profile.flamegraph)),
+				None => Ok(Response::not_found()),
+			},
+			_ => {
+				Ok(Response::new(StatusCode::METHOD_NOT_ALLOWED)
+					.with_header("Allow", "GET, DELETE"))
+			}
+		}
+	}
+
+	/// Start the sampler, or `None` if it could not be started
+	fn start_profiler(&self) -> Option<pprof::ProfilerGuard<'static>> {
+		pprof::ProfilerGuardBuilder::default()
+			.frequency(self.config.frequency)
+			.blocklist(&["libc", "libgcc", "pthread", "vdso"])
+			.build()
+			.inspect_err(|e| warn!(error = %e, "failed to start profiler"))
+			.ok()
+	}
+
+	/// Render the samples collected by `guard` as a flamegraph
+	fn render_flamegraph(
+		guard: &pprof::ProfilerGuard<'static>,
+	) -> std::result::Result<(String, usize), pprof::Error> {
+		let report = guard.report().build()?;
+		let samples = report.data.values().map(|count| *count as usize).sum();
+		let mut svg = Vec::new();
+		report.flamegraph(&mut svg)?;
+		Ok((String::from_utf8_lossy(&svg).into_owned(), samples))
+	}
+}
+
+impl Default for ProfilerMiddleware {
+	fn default() -> Self {
+		Self::with_defaults()
+	}
+}
+
+#[async_trait]
+impl Middleware for ProfilerMiddleware {
+	async fn process(&self, request: Request, handler: Arc<dyn Handler>) -> Result<Response> {
+		let path = request.uri.path().to_string();
+		if self.is_debug_path(&path) {
+			return self.handle_debug_endpoint(&request);
+		}
+		if !self.should_profile(&path) {
+			return handler.handle(request).await;
+		}
+		let Some(slot) = ProfilingSlot::acquire() else {
+			return handler.handle(request).await;
+		};
+		let Some(guard) = self.start_profiler() else {
+			return handler.handle(request).await;
+		};
+
+		let request_id = request
+			.headers
+			.get(REQUEST_ID_HEADER)
+			.and_then(|v| v.to_str().ok())
+			.map(str::to_string)
+			.unwrap_or_else(|| Uuid::new_v4().to_string());
+		let method = request.method.as_str().to_string();
+		let captured_at = Utc::now();
+		let start = Instant::now();
+
+		// Convert errors to responses so slow failing requests are profiled too
+		let mut response = match handler.handle(request).await {
+			Ok(resp) => resp,
+			Err(e) => Response::from(e),
+		};
+
+		let elapsed = start.elapsed();
+		if elapsed < self.config.min_duration {
+			return Ok(response);
+		}
+		let rendered = Self::render_flamegraph(&guard);
+		drop(guard);
+		drop(slot);
+
+		match rendered {
+			Ok((flamegraph, samples)) => {
+				let url = format!(
+					"{}/{}",
+					self.config.endpoint.trim_end_matches('/'),
+					request_id
+				);
+				if let Ok(value) = url.parse() {
+					response.headers.insert(PROFILE_URL_HEADER, value);
+				}
+				self.store.insert(Profile {
+					request_id,
+					method,
+					path,
+					status: response.status.as_u16(),
+					captured_at,
+					duration_ms: elapsed.as_secs_f64() * 1000.0,
+					samples,
+					flamegraph,
+				});
+			}
+			Err(e) => {
+				warn!(request_id = %request_id, error = %e, "failed to render flamegraph");
+			}
+		}
+
+		Ok(response)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bytes::Bytes;
+	use hyper::{HeaderMap, Version};
+	use rstest::rstest;
+	use serial_test::serial;
+
+	struct BusyHandler {
+		busy_for: Duration,
+	}
+
+	#[async_trait]
+	impl Handler for BusyHandler {
+		async fn handle(&self, _request: Request) -> Result<Response> {
+			let start = Instant::now();
+			let mut value = 0u64;
+			while start.elapsed() < self.busy_for {
+				value = std::hint::black_box(value.wrapping_mul(31).wrapping_add(7));
+			}
+			Ok(Response::ok().with_body(Bytes::from(value.to_string())))
+		}
+	}
+
+	fn request(method: Method, uri: &str) -> Request {
+		let mut headers = HeaderMap::new();
+		headers.insert(REQUEST_ID_HEADER, "req-1".parse().unwrap());
+		Request::builder()
+			.method(method)
+			.uri(uri)
+			.version(Version::HTTP_11)
+			.headers(headers)
+			.body(Bytes::new())
+			.build()
+			.unwrap()
+	}
+
+	fn profile(request_id: &str) -> Profile {
+		Profile {
+			request_id: request_id.to_string(),
+			method: "GET".to_string(),
+			path: "/slow".to_string(),
+			status: 200,
+			captured_at: Utc::now(),
+			duration_ms: 120.0,
+			samples: 3,
+			flamegraph: "<svg></svg>".to_string(),
+		}
+	}
+
+	#[rstest]
+	#[tokio::test]
+	#[serial(profiler)]
+	async fn test_profiles_slow_request() {
+		// Arrange
+		let middleware = ProfilerMiddleware::new(
+			ProfilerConfig::new().with_min_duration(Duration::from_millis(10)),
+		);
+		let handler = Arc::new(BusyHandler {
+			busy_for: Duration::from_millis(100),
+		});
+
+		// Act
+		let response = middleware
+			.process(request(Method::GET, "/slow"), handler)
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(
+			response.headers.get(PROFILE_URL_HEADER).unwrap(),
+			"/__debug__/profiles/req-1"
+		);
+		let profile = middleware.store().get("req-1").unwrap();
+		assert_eq!(profile.path, "/slow");
+		assert!(profile.flamegraph.contains("<svg"));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	#[serial(profiler)]
+	async fn test_fast_request_is_not_kept() {
+		// Arrange
+		let middleware = ProfilerMiddleware::with_defaults();
+		let handler = Arc::new(BusyHandler {
+			busy_for: Duration::ZERO,
+		});
+
+		// Act
+		let response = middleware
+			.process(request(Method::GET, "/fast"), handler)
+			.await
+			.unwrap();
+
+		// Assert
+		assert!(!response.headers.contains_key(PROFILE_URL_HEADER));
+		assert!(middleware.store().is_empty());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_zero_sample_rate_disables_profiling() {
+		// Arrange
+		let middleware = ProfilerMiddleware::new(
+			ProfilerConfig::new()
+				.with_sample_rate(0.0)
+				.with_min_duration(Duration::ZERO),
+		);
+		let handler = Arc::new(BusyHandler {
+			busy_for: Duration::ZERO,
+		});
+
+		// Act
+		middleware
+			.process(request(Method::GET, "/slow"), handler)
+			.await
+			.unwrap();
+
+		// Assert
+		assert!(middleware.store().is_empty());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_debug_endpoint_serves_flamegraph() {
+		// Arrange
+		let middleware = ProfilerMiddleware::with_defaults();
+		middleware.store().insert(profile("req-1"));
+		let handler = Arc::new(BusyHandler {
+			busy_for: Duration::ZERO,
+		});
+
+		// Act
+		let list = middleware
+			.process(request(Method::GET, "/__debug__/profiles"), handler.clone())
+			.await
+			.unwrap();
+		let svg = middleware
+			.process(
+				request(Method::GET, "/__debug__/profiles/req-1"),
+				handler.clone(),
+			)
+			.await
+			.unwrap();
+		let missing = middleware
+			.process(request(Method::GET, "/__debug__/profiles/other"), handler)
+			.await
+			.unwrap();
+
+		// Assert
+		let list: serde_json::Value = serde_json::from_slice(&list.body).unwrap();
+		assert_eq!(list[0]["request_id"], "req-1");
+		assert!(list[0].get("flamegraph").is_none());
+		assert_eq!(svg.headers.get("Content-Type").unwrap(), "image/svg+xml");
+		assert_eq!(svg.body, Bytes::from("<svg></svg>"));
+		assert_eq!(missing.status, StatusCode::NOT_FOUND);
+	}
+
+	#[rstest]
+	fn test_store_drops_oldest_profile() {
+		// Arrange
+		let store = ProfileStore::new(2);
+
+		// Act
+		for id in ["a", "b", "c"] {
+			store.insert(profile(id));
+		}
+
+		// Assert
+		let ids: Vec<String> = store.profiles().into_iter().map(|p| p.request_id).collect();
+		assert_eq!(ids, vec!["b", "c"]);
+	}
+}
@@ -475,7 +475,17 @@ impl RateLimitMiddleware {
 	///    b. X-Real-IP header
 	/// 2. remote_addr field from the request
 	/// 3. Falls back to 127.0.0.1 if none available
+	///
+	/// Without trusted proxies of its own, the request-wide
+	/// [`Request::client_ip`] resolution is used instead.
 	fn extract_client_ip(&self, request: &Request) -> String {
+		if self.config.trusted_proxies.is_empty() {
+			return request
+				.client_ip()
+				.map(|ip| ip.to_string())
+				.unwrap_or_else(|| "127.0.0.1".to_string());
+		}
+
 		let peer_ip = request.remote_addr.map(|addr| addr.ip());
 
 		// Only trust proxy headers if the direct connection is from a trusted proxy
@@ -0,0 +1,392 @@
+//! Redirects middleware
+//!
+//! Serves a table of old URL → new URL redirects, like Django's
+//! `RedirectFallbackMiddleware` from `django.contrib.redirects`. Redirects
+//! are keyed by site and old path; when the wrapped handler answers
+//! `404 Not Found`, the middleware looks up the request path on the current
+//! site (see [`crate::site`]) and answers `301`/`302` to the new path, or
+//! `410 Gone` when the redirect has no new path.
+//!
+//! Redirects are usually stored in the `redirects_redirect` table
+//! (`reinhardt_db::orm::redirects`) and loaded into the [`RedirectStore`] at
+//! startup with [`RedirectStore::replace_all`].
+
+use async_trait::async_trait;
+use hyper::StatusCode;
+use hyper::header::{HeaderValue, LOCATION};
+use reinhardt_http::{Handler, Middleware, Request, Response, Result, Site};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// A redirect from an old path to a new one
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_middleware::Redirect;
+///
+/// let redirect = Redirect::new(1, "/blog/", "/news/");
+/// assert!(redirect.permanent);
+///
+/// let gone = Redirect::new(1, "/jobs/", "");
+/// assert!(gone.is_gone());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redirect {
+	/// Site the old path belongs to
+	pub site_id: u64,
+	/// Path being redirected, including the query string if any
+	pub old_path: String,
+	/// Target path or absolute URL; empty when the page is gone
+	pub new_path: String,
+	/// Whether to answer `301 Moved Permanently` rather than `302 Found`
+	pub permanent: bool,
+}
+
+impl Redirect {
+	/// Create a permanent redirect
+	pub fn new(site_id: u64, old_path: impl Into<String>, new_path: impl Into<String>) -> Self {
+		Self {
+			site_id,
+			old_path: old_path.into(),
+			new_path: new_path.into(),
+			permanent: true,
+		}
+	}
+
+	/// Make the redirect temporary (`302 Found`)
+	pub fn temporary(mut self) -> Self {
+		self.permanent = false;
+		self
+	}
+
+	/// Whether the old path should answer `410 Gone`
+	pub fn is_gone(&self) -> bool {
+		self.new_path.is_empty()
+	}
+
+	/// Response for a request to the old path
+	fn response(&self) -> Response {
+		if self.is_gone() {
+			return Response::new(StatusCode::GONE);
+		}
+		let status = if self.permanent {
+			StatusCode::MOVED_PERMANENTLY
+		} else {
+			StatusCode::FOUND
+		};
+		let mut response = Response::new(status);
+		if let Ok(location) = HeaderValue::from_str(&self.new_path) {
+			response.headers.insert(LOCATION, location);
+		}
+		response
+	}
+}
+
+/// Redirect storage keyed by site and old path
+#[derive(Debug, Default)]
+pub struct RedirectStore {
+	redirects: RwLock<HashMap<(u64, String), Redirect>>,
+}
+
+impl RedirectStore {
+	/// Create an empty redirect store
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Register a redirect, replacing the one for the same site and old path
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::{Redirect, RedirectStore};
+	///
+	/// let store = RedirectStore::new();
+	/// store.insert(Redirect::new(1, "/old/", "/new/"));
+	/// assert_eq!(store.get(1, "/old/").unwrap().new_path, "/new/");
+	/// assert!(store.get(2, "/old/").is_none());
+	/// ```
+	pub fn insert(&self, redirect: Redirect) {
+		let key = (redirect.site_id, redirect.old_path.clone());
+		self.redirects
+			.write()
+			.unwrap_or_else(|e| e.into_inner())
+			.insert(key, redirect);
+	}
+
+	/// Get the redirect of `old_path` on the site with id `site_id`
+	pub fn get(&self, site_id: u64, old_path: &str) -> Option<Redirect> {
+		self.redirects
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.get(&(site_id, old_path.to_string()))
+			.cloned()
+	}
+
+	/// Remove the redirect of `old_path` on the site with id `site_id`
+	pub fn remove(&self, site_id: u64, old_path: &str) -> Option<Redirect> {
+		self.redirects
+			.write()
+			.unwrap_or_else(|e| e.into_inner())
+			.remove(&(site_id, old_path.to_string()))
+	}
+
+	/// Replace every redirect with `redirects`
+	///
+	/// Used to reload the store after redirects changed in the database.
+	pub fn replace_all(&self, redirects: impl IntoIterator<Item = Redirect>) {
+		let redirects = redirects
+			.into_iter()
+			.map(|redirect| ((redirect.site_id, redirect.old_path.clone()), redirect))
+			.collect();
+		*self.redirects.write().unwrap_or_else(|e| e.into_inner()) = redirects;
+	}
+
+	/// Number of registered redirects
+	pub fn len(&self) -> usize {
+		self.redirects
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.len()
+	}
+
+	/// Whether no redirect is registered
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+/// Redirects middleware configuration
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct RedirectsConfig {
+	/// Site used when no current site was resolved for the request
+	pub default_site_id: u64,
+	/// Whether to also look up the path with a trailing slash appended
+	pub append_slash: bool,
+}
+
+impl RedirectsConfig {
+	/// Create a new configuration with defaults
+	pub fn new() -> Self {
+		Self {
+			default_site_id: 1,
+			append_slash: false,
+		}
+	}
+
+	/// Set the site used when no current site was resolved
+	pub fn with_default_site_id(mut self, site_id: u64) -> Self {
+		self.default_site_id = site_id;
+		self
+	}
+
+	/// Also look up the path with a trailing slash appended
+	pub fn with_append_slash(mut self, append_slash: bool) -> Self {
+		self.append_slash = append_slash;
+		self
+	}
+}
+
+impl Default for RedirectsConfig {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Middleware redirecting old paths that would otherwise answer 404
+///
+/// Install it inside the site middleware so that the current site is known.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_middleware::{Redirect, RedirectsConfig, RedirectsMiddleware};
+///
+/// let middleware = RedirectsMiddleware::new(RedirectsConfig::new());
+/// middleware.store().insert(Redirect::new(1, "/blog/", "/news/"));
+/// middleware.store().insert(Redirect::new(1, "/promo/", "/sale/").temporary());
+/// ```
+pub struct RedirectsMiddleware {
+	config: RedirectsConfig,
+	store: Arc<RedirectStore>,
+}
+
+impl RedirectsMiddleware {
+	/// Create a new redirects middleware with an empty store
+	pub fn new(config: RedirectsConfig) -> Self {
+		Self::from_arc(config, Arc::new(RedirectStore::new()))
+	}
+
+	/// Create from an existing Arc-wrapped redirect store
+	pub fn from_arc(config: RedirectsConfig, store: Arc<RedirectStore>) -> Self {
+		Self { config, store }
+	}
+
+	/// Get a reference to the redirect store
+	pub fn store(&self) -> &RedirectStore {
+		&self.store
+	}
+
+	/// Get a cloned Arc of the store
+	pub fn store_arc(&self) -> Arc<RedirectStore> {
+		Arc::clone(&self.store)
+	}
+
+	/// Find the redirect for `full_path` (path and query) on `site_id`
+	///
+	/// The full path is tried first, then the path without its query string,
+	/// then (with `append_slash`) the path with a trailing slash.
+	fn find(&self, site_id: u64, full_path: &str) -> Option<Redirect> {
+		if let Some(redirect) = self.store.get(site_id, full_path) {
+			return Some(redirect);
+		}
+		let path = full_path.split('?').next().unwrap_or(full_path);
+		if path != full_path
+			&& let Some(redirect) = self.store.get(site_id, path)
+		{
+			return Some(redirect);
+		}
+		if self.config.append_slash && !path.ends_with('/') {
+			return self.store.get(site_id, &format!("{}/", path));
+		}
+		None
+	}
+}
+
+#[async_trait]
+impl Middleware for RedirectsMiddleware {
+	async fn process(&self, request: Request, handler: Arc<dyn Handler>) -> Result<Response> {
+		let full_path = request
+			.uri
+			.path_and_query()
+			.map(|pq| pq.as_str().to_string())
+			.unwrap_or_else(|| request.uri.path().to_string());
+		let site_id = Site::current(&request)
+			.map(|site| site.id)
+			.unwrap_or(self.config.default_site_id);
+
+		// Convert errors to responses so that 404 errors are redirected too.
+		let response = match handler.handle(request).await {
+			Ok(resp) => resp,
+			Err(e) => Response::from(e),
+		};
+		if response.status != StatusCode::NOT_FOUND {
+			return Ok(response);
+		}
+
+		Ok(match self.find(site_id, &full_path) {
+			Some(redirect) => redirect.response(),
+			None => response,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use hyper::Method;
+	use rstest::rstest;
+
+	struct NotFoundHandler;
+
+	#[async_trait]
+	impl Handler for NotFoundHandler {
+		async fn handle(&self, _request: Request) -> Result<Response> {
+			Ok(Response::new(StatusCode::NOT_FOUND))
+		}
+	}
+
+	fn middleware() -> RedirectsMiddleware {
+		let middleware = RedirectsMiddleware::new(RedirectsConfig::new().with_append_slash(true));
+		middleware.store().replace_all([
+			Redirect::new(1, "/blog/", "/news/"),
+			Redirect::new(1, "/promo/", "/sale/").temporary(),
+			Redirect::new(1, "/jobs/", ""),
+			Redirect::new(2, "/blog/", "https://blog.example.com/"),
+		]);
+		middleware
+	}
+
+	fn request(uri: &str, site: Option<u64>) -> Request {
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri(uri)
+			.build()
+			.unwrap();
+		if let Some(id) = site {
+			request.extensions.insert(Site::new(
+				id,
+				"example.com".to_string(),
+				"Example".to_string(),
+			));
+		}
+		request
+	}
+
+	#[rstest]
+	#[case::permanent("/blog/", None, StatusCode::MOVED_PERMANENTLY, Some("/news/"))]
+	#[case::temporary("/promo/", None, StatusCode::FOUND, Some("/sale/"))]
+	#[case::gone("/jobs/", None, StatusCode::GONE, None)]
+	#[case::query_string("/blog/?page=2", None, StatusCode::MOVED_PERMANENTLY, Some("/news/"))]
+	#[case::append_slash("/blog", None, StatusCode::MOVED_PERMANENTLY, Some("/news/"))]
+	#[case::other_site(
+		"/blog/",
+		Some(2),
+		StatusCode::MOVED_PERMANENTLY,
+		Some("https://blog.example.com/")
+	)]
+	#[case::unknown("/missing/", None, StatusCode::NOT_FOUND, None)]
+	#[case::unknown_on_site("/promo/", Some(2), StatusCode::NOT_FOUND, None)]
+	#[tokio::test]
+	async fn test_redirects_on_404(
+		#[case] uri: &str,
+		#[case] site: Option<u64>,
+		#[case] expected_status: StatusCode,
+		#[case] expected_location: Option<&str>,
+	) {
+		// Arrange
+		let middleware = middleware();
+
+		// Act
+		let response = middleware
+			.process(request(uri, site), Arc::new(NotFoundHandler))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status, expected_status);
+		assert_eq!(
+			response
+				.headers
+				.get(LOCATION)
+				.map(|location| location.to_str().unwrap()),
+			expected_location
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_existing_pages_are_not_redirected() {
+		// Arrange
+		struct OkHandler;
+
+		#[async_trait]
+		impl Handler for OkHandler {
+			async fn handle(&self, _request: Request) -> Result<Response> {
+				Ok(Response::ok())
+			}
+		}
+
+		// Act
+		let response = middleware()
+			.process(request("/blog/", None), Arc::new(OkHandler))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::OK);
+		assert!(!response.headers.contains_key(LOCATION));
+	}
+}
@@ -0,0 +1,186 @@
+//! Middleware rebuilt from a reloadable settings snapshot
+//!
+//! Middleware is normally constructed once from a settings fragment at
+//! startup. [`ReloadableMiddleware`] instead keeps a handle to a shared
+//! [`SettingsSnapshot`] and rebuilds the wrapped middleware the first time a
+//! request arrives after the settings were reloaded. Between reloads every
+//! request reuses the same instance, so nothing is cloned per request.
+
+use arc_swap::ArcSwap;
+use async_trait::async_trait;
+use reinhardt_conf::settings::SettingsSnapshot;
+use reinhardt_http::{Handler, Middleware, Request, Response, Result};
+use std::sync::Arc;
+
+type Builder<S, M> = dyn Fn(&S) -> M + Send + Sync;
+
+struct Built<M> {
+	generation: u64,
+	middleware: Arc<M>,
+}
+
+/// Middleware that follows a [`SettingsSnapshot`] across reloads
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_conf::SecuritySettings;
+/// use reinhardt_conf::settings::SettingsSnapshot;
+/// use reinhardt_middleware::{ReloadableMiddleware, XFrameOptionsMiddleware};
+/// use std::sync::Arc;
+///
+/// let snapshot = Arc::new(SettingsSnapshot::new(SecuritySettings::default()));
+/// let middleware = ReloadableMiddleware::new(Arc::clone(&snapshot), |_settings: &SecuritySettings| {
+///     XFrameOptionsMiddleware::deny()
+/// });
+///
+/// let before = middleware.current();
+/// snapshot.replace(SecuritySettings::default());
+/// assert!(!Arc::ptr_eq(&before, &middleware.current()));
+/// ```
+pub struct ReloadableMiddleware<S, M> {
+	snapshot: Arc<SettingsSnapshot<S>>,
+	build: Box<Builder<S, M>>,
+	built: ArcSwap<Built<M>>,
+}
+
+impl<S, M> ReloadableMiddleware<S, M> {
+	/// Build the middleware from the current settings, rebuilding it with
+	/// `build` whenever `snapshot` is reloaded
+	pub fn new<F>(snapshot: Arc<SettingsSnapshot<S>>, build: F) -> Self
+	where
+		F: Fn(&S) -> M + Send + Sync + 'static,
+	{
+		let built = Self::build_from(&snapshot, &build);
+		Self {
+			snapshot,
+			build: Box::new(build),
+			built: ArcSwap::from_pointee(built),
+		}
+	}
+
+	/// The middleware built from the current settings
+	pub fn current(&self) -> Arc<M> {
+		let built = self.built.load();
+		if built.generation == self.snapshot.generation() {
+			return Arc::clone(&built.middleware);
+		}
+		drop(built);
+
+		// Concurrent requests may each rebuild once after a reload; the last
+		// store wins and all of them see up-to-date settings.
+		let rebuilt = Self::build_from(&self.snapshot, &*self.build);
+		let middleware = Arc::clone(&rebuilt.middleware);
+		self.built.store(Arc::new(rebuilt));
+		middleware
+	}
+
+	fn build_from(snapshot: &SettingsSnapshot<S>, build: &Builder<S, M>) -> Built<M> {
+		// Read the generation first: if a reload races with the build, the
+		// stale generation makes the next request rebuild again.
+		let generation = snapshot.generation();
+		let middleware = Arc::new(build(&snapshot.load()));
+		Built {
+			generation,
+			middleware,
+		}
+	}
+}
+
+#[async_trait]
+impl<S, M> Middleware for ReloadableMiddleware<S, M>
+where
+	S: Send + Sync + 'static,
+	M: Middleware + 'static,
+{
+	async fn process(&self, request: Request, handler: Arc<dyn Handler>) -> Result<Response> {
+		self.current().process(request, handler).await
+	}
+
+	fn should_continue(&self, request: &Request) -> bool {
+		self.current().should_continue(request)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bytes::Bytes;
+	use hyper::{Method, StatusCode};
+	use rstest::rstest;
+
+	struct HeaderMiddleware {
+		value: String,
+	}
+
+	#[async_trait]
+	impl Middleware for HeaderMiddleware {
+		async fn process(&self, request: Request, handler: Arc<dyn Handler>) -> Result<Response> {
+			let mut response = handler.handle(request).await?;
+			response
+				.headers
+				.insert("x-setting", self.value.parse().unwrap());
+			Ok(response)
+		}
+	}
+
+	struct OkHandler;
+
+	#[async_trait]
+	impl Handler for OkHandler {
+		async fn handle(&self, _request: Request) -> Result<Response> {
+			Ok(Response::new(StatusCode::OK).with_body(Bytes::new()))
+		}
+	}
+
+	fn request() -> Request {
+		Request::builder()
+			.method(Method::GET)
+			.uri("/")
+			.build()
+			.unwrap()
+	}
+
+	fn reloadable(
+		snapshot: &Arc<SettingsSnapshot<String>>,
+	) -> ReloadableMiddleware<String, HeaderMiddleware> {
+		ReloadableMiddleware::new(Arc::clone(snapshot), |value: &String| HeaderMiddleware {
+			value: value.clone(),
+		})
+	}
+
+	#[rstest]
+	fn test_reuses_instance_between_reloads() {
+		// Arrange
+		let snapshot = Arc::new(SettingsSnapshot::new(String::from("v1")));
+		let middleware = reloadable(&snapshot);
+
+		// Act
+		let first = middleware.current();
+		let second = middleware.current();
+
+		// Assert
+		assert!(Arc::ptr_eq(&first, &second));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_rebuilds_after_reload() {
+		// Arrange
+		let snapshot = Arc::new(SettingsSnapshot::new(String::from("v1")));
+		let middleware = reloadable(&snapshot);
+		let handler: Arc<dyn Handler> = Arc::new(OkHandler);
+		let before = middleware
+			.process(request(), Arc::clone(&handler))
+			.await
+			.unwrap();
+
+		// Act
+		snapshot.replace(String::from("v2"));
+		let after = middleware.process(request(), handler).await.unwrap();
+
+		// Assert
+		assert_eq!(before.headers.get("x-setting").unwrap(), "v1");
+		assert_eq!(after.headers.get("x-setting").unwrap(), "v2");
+	}
+}
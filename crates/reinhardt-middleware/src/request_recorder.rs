@@ -0,0 +1,1144 @@
+//! Request recorder middleware
+//!
+//! Records full request/response pairs into a bounded ring buffer so that a
+//! misbehaving request can be inspected and reproduced. Captured exchanges
+//! are served from a debug-only endpoint as JSON or exported as a
+//! [HAR](http://www.softwareishard.com/blog/har-12-spec/) file, and a
+//! [`RecordedRequest`] can be replayed against an in-process test client
+//! (`reinhardt_testkit::test_client::TestClient::replay`).
+//!
+//! Sensitive headers, query parameters and JSON/form body fields are
+//! redacted before an exchange is stored; redacted headers are not replayed.
+//!
+//! | Request | Response |
+//! |---------|----------|
+//! | `GET {endpoint}` | All recorded exchanges, oldest first |
+//! | `GET {endpoint}/har` | HAR 1.2 export of all recorded exchanges |
+//! | `GET {endpoint}/{id}` | A single exchange |
+//! | `DELETE {endpoint}` | Clear the buffer |
+
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use hyper::header::{CONTENT_TYPE, HOST, HeaderName, HeaderValue};
+use hyper::{HeaderMap, Method, StatusCode, Version};
+use reinhardt_http::{Handler, Middleware, Request, Response, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::VecDeque;
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+use uuid::Uuid;
+
+/// Placeholder stored in place of redacted values
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Headers the replaying client sets itself
+const CLIENT_MANAGED_HEADERS: &[&str] = &["host", "content-length", "content-type", "cookie"];
+
+/// A captured request or response body
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedBody {
+	/// Body text, base64-encoded when [`base64`](Self::base64) is set
+	pub text: String,
+	/// Whether the body was not valid UTF-8 and is stored base64-encoded
+	pub base64: bool,
+	/// Whether the body exceeded the configured maximum and was cut off
+	pub truncated: bool,
+	/// Size of the original body in bytes
+	pub size: usize,
+}
+
+impl RecordedBody {
+	/// Capture `body`, keeping at most `max_size` bytes
+	fn capture(body: &[u8], max_size: usize) -> Self {
+		let truncated = body.len() > max_size;
+		let kept = &body[..body.len().min(max_size)];
+		let (text, base64) = match std::str::from_utf8(kept) {
+			Ok(text) => (text.to_string(), false),
+			Err(_) => (BASE64.encode(kept), true),
+		};
+		Self {
+			text,
+			base64,
+			truncated,
+			size: body.len(),
+		}
+	}
+
+	/// Whether the body is empty
+	pub fn is_empty(&self) -> bool {
+		self.size == 0
+	}
+
+	/// Decoded body bytes
+	///
+	/// Truncated bodies yield only the captured prefix.
+	pub fn to_bytes(&self) -> Bytes {
+		if self.base64 {
+			Bytes::from(BASE64.decode(&self.text).unwrap_or_default())
+		} else {
+			Bytes::from(self.text.clone())
+		}
+	}
+}
+
+/// A captured request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+	/// HTTP method
+	pub method: String,
+	/// Path and query string, with sensitive query parameters redacted
+	pub uri: String,
+	/// HTTP version, such as `HTTP/1.1`
+	pub version: String,
+	/// Headers in received order, with sensitive values redacted
+	pub headers: Vec<(String, String)>,
+	/// Request body, with sensitive fields redacted
+	pub body: RecordedBody,
+}
+
+impl RecordedRequest {
+	/// Value of the first header named `name` (case-insensitive)
+	pub fn header(&self, name: &str) -> Option<&str> {
+		find_header(&self.headers, name)
+	}
+
+	/// Headers to send when replaying the request
+	///
+	/// Redacted headers and headers the client sets itself (`Host`,
+	/// `Content-Length`, `Content-Type` and `Cookie`) are left out.
+	pub fn replay_headers(&self) -> Vec<(&str, &str)> {
+		self.headers
+			.iter()
+			.filter(|(name, value)| {
+				value != REDACTED
+					&& !CLIENT_MANAGED_HEADERS.contains(&name.to_ascii_lowercase().as_str())
+			})
+			.map(|(name, value)| (name.as_str(), value.as_str()))
+			.collect()
+	}
+
+	/// Rebuild a [`Request`] that can be passed to a handler
+	///
+	/// Only [`replay_headers`](Self::replay_headers) and `Content-Type` are
+	/// restored.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the recorded method, URI or a header is invalid.
+	pub fn to_request(&self) -> std::result::Result<Request, String> {
+		let method = Method::from_bytes(self.method.as_bytes())
+			.map_err(|e| format!("Invalid method: {}", e))?;
+		let content_type = self
+			.header("content-type")
+			.map(|value| ("content-type", value));
+		let mut headers = HeaderMap::new();
+		for (name, value) in self.replay_headers().into_iter().chain(content_type) {
+			let name = HeaderName::from_bytes(name.as_bytes())
+				.map_err(|e| format!("Invalid header name: {}", e))?;
+			let value =
+				HeaderValue::from_str(value).map_err(|e| format!("Invalid header value: {}", e))?;
+			headers.append(name, value);
+		}
+		Request::builder()
+			.method(method)
+			.uri(self.uri.as_str())
+			.version(parse_version(&self.version))
+			.headers(headers)
+			.body(self.body.to_bytes())
+			.build()
+	}
+}
+
+/// A captured response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+	/// HTTP status code
+	pub status: u16,
+	/// Headers, with sensitive values redacted
+	pub headers: Vec<(String, String)>,
+	/// Response body
+	pub body: RecordedBody,
+}
+
+impl RecordedResponse {
+	/// Value of the first header named `name` (case-insensitive)
+	pub fn header(&self, name: &str) -> Option<&str> {
+		find_header(&self.headers, name)
+	}
+}
+
+/// A recorded request/response pair
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedExchange {
+	/// Unique exchange ID, used to fetch it from the debug endpoint
+	pub id: Uuid,
+	/// When the request was received
+	pub started_at: DateTime<Utc>,
+	/// Time spent in the inner handler, in milliseconds
+	pub duration_ms: f64,
+	/// The captured request
+	pub request: RecordedRequest,
+	/// The captured response
+	pub response: RecordedResponse,
+}
+
+impl RecordedExchange {
+	/// Convert the exchange into a HAR 1.2 `entry` object
+	pub fn to_har_entry(&self) -> Value {
+		let request = &self.request;
+		let response = &self.response;
+
+		let url = match request.header("host") {
+			Some(host) if request.uri.starts_with('/') => format!("http://{}{}", host, request.uri),
+			_ => request.uri.clone(),
+		};
+		let query_string: Vec<Value> = request
+			.uri
+			.split_once('?')
+			.map(|(_, query)| {
+				url::form_urlencoded::parse(query.as_bytes())
+					.map(|(name, value)| json!({"name": name, "value": value}))
+					.collect()
+			})
+			.unwrap_or_default();
+
+		let mut har_request = json!({
+			"method": request.method,
+			"url": url,
+			"httpVersion": request.version,
+			"cookies": [],
+			"headers": har_headers(&request.headers),
+			"queryString": query_string,
+			"headersSize": -1,
+			"bodySize": request.body.size,
+		});
+		if !request.body.is_empty() {
+			har_request["postData"] = json!({
+				"mimeType": request.header("content-type").unwrap_or(""),
+				"text": request.body.text,
+			});
+		}
+
+		let mut content = json!({
+			"size": response.body.size,
+			"mimeType": response.header("content-type").unwrap_or(""),
+			"text": response.body.text,
+		});
+		if response.body.base64 {
+			content["encoding"] = json!("base64");
+		}
+		let status_text = StatusCode::from_u16(response.status)
+			.ok()
+			.and_then(|status| status.canonical_reason())
+			.unwrap_or("");
+
+		json!({
+			"startedDateTime": self.started_at.to_rfc3339(),
+			"time": self.duration_ms,
+			"request": har_request,
+			"response": {
+				"status": response.status,
+				"statusText": status_text,
+				"httpVersion": request.version,
+				"cookies": [],
+				"headers": har_headers(&response.headers),
+				"content": content,
+				"redirectURL": response.header("location").unwrap_or(""),
+				"headersSize": -1,
+				"bodySize": response.body.size,
+			},
+			"cache": {},
+			"timings": {
+				"send": 0,
+				"wait": self.duration_ms,
+				"receive": 0,
+			},
+		})
+	}
+}
+
+/// Bounded storage for recorded exchanges
+///
+/// When the buffer is full the oldest exchange is dropped.
+#[derive(Debug)]
+pub struct RecordingStore {
+	exchanges: RwLock<VecDeque<RecordedExchange>>,
+	capacity: usize,
+}
+
+impl RecordingStore {
+	/// Create a store keeping at most `capacity` exchanges
+	pub fn new(capacity: usize) -> Self {
+		Self {
+			exchanges: RwLock::new(VecDeque::with_capacity(capacity)),
+			capacity,
+		}
+	}
+
+	/// Add an exchange, evicting the oldest one when the buffer is full
+	pub fn record(&self, exchange: RecordedExchange) {
+		if self.capacity == 0 {
+			return;
+		}
+		let mut exchanges = self.exchanges.write().unwrap_or_else(|e| e.into_inner());
+		while exchanges.len() >= self.capacity {
+			exchanges.pop_front();
+		}
+		exchanges.push_back(exchange);
+	}
+
+	/// All recorded exchanges, oldest first
+	pub fn exchanges(&self) -> Vec<RecordedExchange> {
+		self.exchanges
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.iter()
+			.cloned()
+			.collect()
+	}
+
+	/// The exchange with the given ID, if it is still buffered
+	pub fn get(&self, id: Uuid) -> Option<RecordedExchange> {
+		self.exchanges
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.iter()
+			.find(|exchange| exchange.id == id)
+			.cloned()
+	}
+
+	/// The most recently recorded exchange
+	pub fn last(&self) -> Option<RecordedExchange> {
+		self.exchanges
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.back()
+			.cloned()
+	}
+
+	/// Number of buffered exchanges
+	pub fn len(&self) -> usize {
+		self.exchanges
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.len()
+	}
+
+	/// Whether no exchange is buffered
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Drop all buffered exchanges
+	pub fn clear(&self) {
+		self.exchanges
+			.write()
+			.unwrap_or_else(|e| e.into_inner())
+			.clear();
+	}
+
+	/// Export all buffered exchanges as a HAR 1.2 document
+	pub fn to_har(&self) -> Value {
+		let entries: Vec<Value> = self
+			.exchanges
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.iter()
+			.map(RecordedExchange::to_har_entry)
+			.collect();
+		json!({
+			"log": {
+				"version": "1.2",
+				"creator": {
+					"name": "reinhardt",
+					"version": env!("CARGO_PKG_VERSION"),
+				},
+				"pages": [],
+				"entries": entries,
+			}
+		})
+	}
+}
+
+impl Default for RecordingStore {
+	fn default() -> Self {
+		Self::new(RequestRecorderConfig::DEFAULT_CAPACITY)
+	}
+}
+
+/// Configuration for the request recorder middleware
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct RequestRecorderConfig {
+	/// Whether requests are recorded and the debug endpoint is served
+	/// (default: enabled in debug builds only)
+	pub enabled: bool,
+	/// Path of the debug endpoint (default: `/__debug__/requests`)
+	pub endpoint: String,
+	/// Maximum number of buffered exchanges (default: 100)
+	pub capacity: usize,
+	/// Maximum number of body bytes stored per request or response (default: 64 KiB)
+	pub max_body_size: usize,
+	/// Header names whose values are redacted (case-insensitive)
+	pub redact_headers: Vec<String>,
+	/// Query parameter and JSON/form body field names whose values are
+	/// redacted (case-insensitive)
+	pub redact_fields: Vec<String>,
+	/// Path prefixes that are not recorded
+	pub exclude_paths: Vec<String>,
+}
+
+impl RequestRecorderConfig {
+	/// Default number of buffered exchanges
+	pub const DEFAULT_CAPACITY: usize = 100;
+
+	/// Create a new default configuration
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::RequestRecorderConfig;
+	///
+	/// let config = RequestRecorderConfig::new();
+	/// assert_eq!(config.endpoint, "/__debug__/requests");
+	/// assert_eq!(config.capacity, 100);
+	/// assert!(config.redact_headers.contains(&"authorization".to_string()));
+	/// ```
+	pub fn new() -> Self {
+		Self {
+			enabled: cfg!(debug_assertions),
+			endpoint: "/__debug__/requests".to_string(),
+			capacity: Self::DEFAULT_CAPACITY,
+			max_body_size: 64 * 1024,
+			redact_headers: [
+				"authorization",
+				"proxy-authorization",
+				"cookie",
+				"set-cookie",
+				"x-csrftoken",
+				"x-api-key",
+			]
+			.into_iter()
+			.map(String::from)
+			.collect(),
+			redact_fields: [
+				"password",
+				"token",
+				"secret",
+				"api_key",
+				"csrfmiddlewaretoken",
+			]
+			.into_iter()
+			.map(String::from)
+			.collect(),
+			exclude_paths: vec!["/static/".to_string()],
+		}
+	}
+
+	/// Enable or disable recording and the debug endpoint
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::RequestRecorderConfig;
+	///
+	/// let config = RequestRecorderConfig::new().with_enabled(true);
+	/// assert!(config.enabled);
+	/// ```
+	pub fn with_enabled(mut self, enabled: bool) -> Self {
+		self.enabled = enabled;
+		self
+	}
+
+	/// Set a custom debug endpoint
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::RequestRecorderConfig;
+	///
+	/// let config = RequestRecorderConfig::new().with_endpoint("/_requests".to_string());
+	/// assert_eq!(config.endpoint, "/_requests");
+	/// ```
+	pub fn with_endpoint(mut self, endpoint: String) -> Self {
+		self.endpoint = endpoint;
+		self
+	}
+
+	/// Set the maximum number of buffered exchanges
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::RequestRecorderConfig;
+	///
+	/// let config = RequestRecorderConfig::new().with_capacity(20);
+	/// assert_eq!(config.capacity, 20);
+	/// ```
+	pub fn with_capacity(mut self, capacity: usize) -> Self {
+		self.capacity = capacity;
+		self
+	}
+
+	/// Set the maximum number of body bytes stored per request or response
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::RequestRecorderConfig;
+	///
+	/// let config = RequestRecorderConfig::new().with_max_body_size(1024);
+	/// assert_eq!(config.max_body_size, 1024);
+	/// ```
+	pub fn with_max_body_size(mut self, max_body_size: usize) -> Self {
+		self.max_body_size = max_body_size;
+		self
+	}
+
+	/// Add header names to redact
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::RequestRecorderConfig;
+	///
+	/// let config = RequestRecorderConfig::new()
+	///     .with_redacted_headers(vec!["x-signature".to_string()]);
+	/// assert!(config.redact_headers.contains(&"x-signature".to_string()));
+	/// ```
+	pub fn with_redacted_headers(mut self, headers: Vec<String>) -> Self {
+		self.redact_headers.extend(headers);
+		self
+	}
+
+	/// Add query parameter and body field names to redact
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::RequestRecorderConfig;
+	///
+	/// let config = RequestRecorderConfig::new()
+	///     .with_redacted_fields(vec!["card_number".to_string()]);
+	/// assert!(config.redact_fields.contains(&"card_number".to_string()));
+	/// ```
+	pub fn with_redacted_fields(mut self, fields: Vec<String>) -> Self {
+		self.redact_fields.extend(fields);
+		self
+	}
+
+	/// Add path prefixes that are not recorded
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::RequestRecorderConfig;
+	///
+	/// let config = RequestRecorderConfig::new()
+	///     .with_excluded_paths(vec!["/health".to_string()]);
+	/// assert!(config.exclude_paths.contains(&"/health".to_string()));
+	/// ```
+	pub fn with_excluded_paths(mut self, paths: Vec<String>) -> Self {
+		self.exclude_paths.extend(paths);
+		self
+	}
+
+	fn is_redacted_header(&self, name: &str) -> bool {
+		self.redact_headers
+			.iter()
+			.any(|header| header.eq_ignore_ascii_case(name))
+	}
+
+	fn is_redacted_field(&self, name: &str) -> bool {
+		self.redact_fields
+			.iter()
+			.any(|field| field.eq_ignore_ascii_case(name))
+	}
+}
+
+impl Default for RequestRecorderConfig {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Middleware recording request/response pairs for debugging
+///
+/// Disabled in release builds unless explicitly enabled with
+/// [`RequestRecorderConfig::with_enabled`]; the debug endpoint exposes
+/// request data and must never be reachable in production. Place it after
+/// authentication middleware only if the recorded requests should include
+/// the effects of earlier middleware.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use reinhardt_middleware::{RequestRecorderConfig, RequestRecorderMiddleware};
+/// use reinhardt_http::{Handler, Middleware, Request, Response};
+/// use hyper::{HeaderMap, Method, StatusCode, Version};
+/// use bytes::Bytes;
+///
+/// struct TestHandler;
+///
+/// #[async_trait::async_trait]
+/// impl Handler for TestHandler {
+///     async fn handle(&self, _request: Request) -> reinhardt_core::exception::Result<Response> {
+///         Ok(Response::new(StatusCode::OK).with_body(Bytes::from("OK")))
+///     }
+/// }
+///
+/// # tokio_test::block_on(async {
+/// let middleware = RequestRecorderMiddleware::new(RequestRecorderConfig::new().with_enabled(true));
+/// let handler = Arc::new(TestHandler);
+///
+/// let request = Request::builder()
+///     .method(Method::GET)
+///     .uri("/api/users?token=abc")
+///     .version(Version::HTTP_11)
+///     .headers(HeaderMap::new())
+///     .body(Bytes::new())
+///     .build()
+///     .unwrap();
+///
+/// middleware.process(request, handler).await.unwrap();
+///
+/// let exchange = middleware.store().last().unwrap();
+/// assert_eq!(exchange.request.uri, "/api/users?token=%5BREDACTED%5D");
+/// assert_eq!(exchange.response.body.text, "OK");
+/// # });
+/// ```
+pub struct RequestRecorderMiddleware {
+	config: RequestRecorderConfig,
+	store: Arc<RecordingStore>,
+}
+
+impl RequestRecorderMiddleware {
+	/// Create a new RequestRecorderMiddleware with the given configuration
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::{RequestRecorderConfig, RequestRecorderMiddleware};
+	///
+	/// let middleware = RequestRecorderMiddleware::new(RequestRecorderConfig::new());
+	/// ```
+	pub fn new(config: RequestRecorderConfig) -> Self {
+		let store = Arc::new(RecordingStore::new(config.capacity));
+		Self { config, store }
+	}
+
+	/// Create a new RequestRecorderMiddleware with default configuration
+	pub fn with_defaults() -> Self {
+		Self::new(RequestRecorderConfig::default())
+	}
+
+	/// Create a new RequestRecorderMiddleware recording into a shared store
+	///
+	/// The store's own capacity applies instead of
+	/// [`RequestRecorderConfig::capacity`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use std::sync::Arc;
+	/// use reinhardt_middleware::{RecordingStore, RequestRecorderConfig, RequestRecorderMiddleware};
+	///
+	/// let store = Arc::new(RecordingStore::new(10));
+	/// let middleware = RequestRecorderMiddleware::from_arc(RequestRecorderConfig::new(), store);
+	/// ```
+	pub fn from_arc(config: RequestRecorderConfig, store: Arc<RecordingStore>) -> Self {
+		Self { config, store }
+	}
+
+	/// Get a reference to the recording store
+	pub fn store(&self) -> &RecordingStore {
+		&self.store
+	}
+
+	/// Get a cloned Arc of the recording store
+	pub fn store_arc(&self) -> Arc<RecordingStore> {
+		Arc::clone(&self.store)
+	}
+
+	/// Check if path should not be recorded
+	fn should_exclude(&self, path: &str) -> bool {
+		self.config
+			.exclude_paths
+			.iter()
+			.any(|p| path.starts_with(p))
+	}
+
+	/// Check if path is served by the debug endpoint
+	fn is_debug_path(&self, path: &str) -> bool {
+		path.strip_prefix(self.config.endpoint.as_str())
+			.is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+	}
+
+	/// Handle a request to the debug endpoint
+	fn handle_debug_endpoint(&self, request: &Request) -> Result<Response> {
+		let path = request.uri.path();
+		let rest = path[self.config.endpoint.len()..].trim_matches('/');
+
+		match (&request.method, rest) {
+			(&Method::GET, "") => Response::ok().with_json(&self.store.exchanges()),
+			(&Method::DELETE, "") => {
+				self.store.clear();
+				Ok(Response::new(StatusCode::NO_CONTENT))
+			}
+			(&Method::GET, "har") => Ok(Response::ok()
+				.with_header("Content-Type", "application/json")
+				.with_header(
+					"Content-Disposition",
+					"attachment; filename=\"requests.har\"",
+				)
+				.with_body(self.store.to_har().to_string())),
+			(&Method::GET, id) => {
+				match Uuid::parse_str(id).ok().and_then(|id| self.store.get(id)) {
+					Some(exchange) => Response::ok().with_json(&exchange),
+					None => Ok(Response::not_found()),
+				}
+			}
+			_ => {
+				Ok(Response::new(StatusCode::METHOD_NOT_ALLOWED)
+					.with_header("Allow", "GET, DELETE"))
+			}
+		}
+	}
+
+	/// Capture headers, redacting configured names
+	fn capture_headers(&self, headers: &HeaderMap) -> Vec<(String, String)> {
+		headers
+			.iter()
+			.map(|(name, value)| {
+				let value = if self.config.is_redacted_header(name.as_str()) {
+					REDACTED.to_string()
+				} else {
+					String::from_utf8_lossy(value.as_bytes()).into_owned()
+				};
+				(name.as_str().to_string(), value)
+			})
+			.collect()
+	}
+
+	/// Capture a body, redacting configured JSON and form fields
+	fn capture_body(&self, body: &[u8], content_type: Option<&str>) -> RecordedBody {
+		let mime = content_type
+			.and_then(|ct| ct.split(';').next())
+			.map(|ct| ct.trim().to_ascii_lowercase());
+		let redacted = match mime.as_deref() {
+			Some(mime) if mime == "application/json" || mime.ends_with("+json") => {
+				serde_json::from_slice::<Value>(body)
+					.ok()
+					.and_then(|mut value| {
+						self.redact_json(&mut value)
+							.then(|| value.to_string().into_bytes())
+					})
+			}
+			Some("application/x-www-form-urlencoded") => {
+				self.redact_form(body).map(String::into_bytes)
+			}
+			_ => None,
+		};
+		match redacted {
+			Some(bytes) => {
+				let mut captured = RecordedBody::capture(&bytes, self.config.max_body_size);
+				captured.size = body.len();
+				captured
+			}
+			None => RecordedBody::capture(body, self.config.max_body_size),
+		}
+	}
+
+	/// Redact configured fields in place, returning whether any was found
+	fn redact_json(&self, value: &mut Value) -> bool {
+		let mut redacted = false;
+		match value {
+			Value::Object(map) => {
+				for (key, field) in map.iter_mut() {
+					if self.config.is_redacted_field(key) {
+						*field = Value::String(REDACTED.to_string());
+						redacted = true;
+					} else {
+						redacted |= self.redact_json(field);
+					}
+				}
+			}
+			Value::Array(items) => {
+				for item in items {
+					redacted |= self.redact_json(item);
+				}
+			}
+			_ => {}
+		}
+		redacted
+	}
+
+	/// Re-encode a URL-encoded form with configured fields redacted
+	///
+	/// Returns `None` when no field needs redacting, so the original
+	/// encoding is kept.
+	fn redact_form(&self, form: &[u8]) -> Option<String> {
+		if !url::form_urlencoded::parse(form).any(|(name, _)| self.config.is_redacted_field(&name))
+		{
+			return None;
+		}
+		let redacted = url::form_urlencoded::Serializer::new(String::new())
+			.extend_pairs(url::form_urlencoded::parse(form).map(|(name, value)| {
+				if self.config.is_redacted_field(&name) {
+					(name, REDACTED.into())
+				} else {
+					(name, value)
+				}
+			}))
+			.finish();
+		Some(redacted)
+	}
+
+	fn capture_request(&self, request: &Request) -> RecordedRequest {
+		let path = request.uri.path();
+		let uri = match request.uri.query() {
+			Some(query) => {
+				let query = self
+					.redact_form(query.as_bytes())
+					.unwrap_or_else(|| query.to_string());
+				format!("{}?{}", path, query)
+			}
+			None => path.to_string(),
+		};
+		let content_type = request
+			.headers
+			.get(CONTENT_TYPE)
+			.and_then(|v| v.to_str().ok());
+		let mut headers = self.capture_headers(&request.headers);
+		if !headers.iter().any(|(name, _)| name == HOST.as_str())
+			&& let Some(host) = request.uri.authority()
+		{
+			headers.insert(0, (HOST.as_str().to_string(), host.to_string()));
+		}
+		RecordedRequest {
+			method: request.method.as_str().to_string(),
+			uri,
+			version: format!("{:?}", request.version),
+			headers,
+			body: self.capture_body(request.body(), content_type),
+		}
+	}
+
+	fn capture_response(&self, response: &Response) -> RecordedResponse {
+		let content_type = response
+			.headers
+			.get(CONTENT_TYPE)
+			.and_then(|v| v.to_str().ok());
+		RecordedResponse {
+			status: response.status.as_u16(),
+			headers: self.capture_headers(&response.headers),
+			body: self.capture_body(&response.body, content_type),
+		}
+	}
+}
+
+impl Default for RequestRecorderMiddleware {
+	fn default() -> Self {
+		Self::with_defaults()
+	}
+}
+
+#[async_trait]
+impl Middleware for RequestRecorderMiddleware {
+	async fn process(&self, request: Request, handler: Arc<dyn Handler>) -> Result<Response> {
+		if !self.config.enabled {
+			return handler.handle(request).await;
+		}
+
+		let path = request.uri.path();
+		if self.is_debug_path(path) {
+			return self.handle_debug_endpoint(&request);
+		}
+		if self.should_exclude(path) {
+			return handler.handle(request).await;
+		}
+
+		let recorded_request = self.capture_request(&request);
+		let started_at = Utc::now();
+		let start = Instant::now();
+
+		// Convert errors to responses so failing requests are recorded too
+		let response = match handler.handle(request).await {
+			Ok(resp) => resp,
+			Err(e) => Response::from(e),
+		};
+
+		let duration_ms = start.elapsed().as_secs_f64() * 1000.0;
+		self.store.record(RecordedExchange {
+			id: Uuid::new_v4(),
+			started_at,
+			duration_ms,
+			request: recorded_request,
+			response: self.capture_response(&response),
+		});
+
+		Ok(response)
+	}
+}
+
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+	headers
+		.iter()
+		.find(|(header, _)| header.eq_ignore_ascii_case(name))
+		.map(|(_, value)| value.as_str())
+}
+
+fn har_headers(headers: &[(String, String)]) -> Vec<Value> {
+	headers
+		.iter()
+		.map(|(name, value)| json!({"name": name, "value": value}))
+		.collect()
+}
+
+fn parse_version(version: &str) -> Version {
+	match version {
+		"HTTP/0.9" => Version::HTTP_09,
+		"HTTP/1.0" => Version::HTTP_10,
+		"HTTP/2.0" => Version::HTTP_2,
+		"HTTP/3.0" => Version::HTTP_3,
+		_ => Version::HTTP_11,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	struct EchoHandler;
+
+	#[async_trait]
+	impl Handler for EchoHandler {
+		async fn handle(&self, request: Request) -> Result<Response> {
+			Ok(Response::ok()
+				.with_header("Content-Type", "application/json")
+				.with_header("Set-Cookie", "sessionid=abc")
+				.with_body(request.body().clone()))
+		}
+	}
+
+	fn middleware(config: RequestRecorderConfig) -> RequestRecorderMiddleware {
+		RequestRecorderMiddleware::new(config.with_enabled(true))
+	}
+
+	fn request(method: Method, uri: &str, body: &str) -> Request {
+		Request::builder()
+			.method(method)
+			.uri(uri)
+			.version(Version::HTTP_11)
+			.header("content-type", "application/json")
+			.header("authorization", "Bearer secret")
+			.header("x-trace", "1")
+			.body(Bytes::from(body.to_string()))
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_records_redacted_exchange() {
+		// Arrange
+		let middleware = middleware(RequestRecorderConfig::new());
+		let request = request(
+			Method::POST,
+			"/login?next=/home&token=t1",
+			r#"{"username":"alice","password":"hunter2","nested":{"api_key":"k"}}"#,
+		);
+
+		// Act
+		middleware
+			.process(request, Arc::new(EchoHandler))
+			.await
+			.unwrap();
+
+		// Assert
+		let exchange = middleware.store().last().unwrap();
+		assert_eq!(exchange.request.method, "POST");
+		assert_eq!(
+			exchange.request.uri,
+			"/login?next=%2Fhome&token=%5BREDACTED%5D"
+		);
+		assert_eq!(exchange.request.header("authorization"), Some(REDACTED));
+		assert_eq!(exchange.request.header("x-trace"), Some("1"));
+		let body: Value = serde_json::from_str(&exchange.request.body.text).unwrap();
+		assert_eq!(body["username"], "alice");
+		assert_eq!(body["password"], REDACTED);
+		assert_eq!(body["nested"]["api_key"], REDACTED);
+		assert_eq!(exchange.response.status, 200);
+		assert_eq!(exchange.response.header("set-cookie"), Some(REDACTED));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_ring_buffer_drops_oldest() {
+		// Arrange
+		let middleware = middleware(RequestRecorderConfig::new().with_capacity(2));
+
+		// Act
+		for path in ["/a", "/b", "/c"] {
+			middleware
+				.process(request(Method::GET, path, ""), Arc::new(EchoHandler))
+				.await
+				.unwrap();
+		}
+
+		// Assert
+		let uris: Vec<String> = middleware
+			.store()
+			.exchanges()
+			.into_iter()
+			.map(|exchange| exchange.request.uri)
+			.collect();
+		assert_eq!(uris, vec!["/b", "/c"]);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_disabled_recorder_passes_through() {
+		// Arrange
+		let middleware =
+			RequestRecorderMiddleware::new(RequestRecorderConfig::new().with_enabled(false));
+
+		// Act
+		let response = middleware
+			.process(
+				request(Method::GET, "/__debug__/requests", ""),
+				Arc::new(EchoHandler),
+			)
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::OK);
+		assert!(middleware.store().is_empty());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_debug_endpoint_serves_exchanges_and_har() {
+		// Arrange
+		let middleware = middleware(RequestRecorderConfig::new());
+		middleware
+			.process(
+				request(Method::POST, "/items?page=2", r#"{"name":"x"}"#),
+				Arc::new(EchoHandler),
+			)
+			.await
+			.unwrap();
+		let id = middleware.store().last().unwrap().id;
+
+		// Act
+		let list = middleware
+			.process(
+				request(Method::GET, "/__debug__/requests", ""),
+				Arc::new(EchoHandler),
+			)
+			.await
+			.unwrap();
+		let single = middleware
+			.process(
+				request(Method::GET, &format!("/__debug__/requests/{}", id), ""),
+				Arc::new(EchoHandler),
+			)
+			.await
+			.unwrap();
+		let har = middleware
+			.process(
+				request(Method::GET, "/__debug__/requests/har", ""),
+				Arc::new(EchoHandler),
+			)
+			.await
+			.unwrap();
+
+		// Assert
+		let list: Vec<RecordedExchange> = serde_json::from_slice(&list.body).unwrap();
+		assert_eq!(list.len(), 1);
+		let single: RecordedExchange = serde_json::from_slice(&single.body).unwrap();
+		assert_eq!(single.id, id);
+		let har: Value = serde_json::from_slice(&har.body).unwrap();
+		let entry = &har["log"]["entries"][0];
+		assert_eq!(har["log"]["version"], "1.2");
+		assert_eq!(entry["request"]["method"], "POST");
+		assert_eq!(entry["request"]["queryString"][0]["name"], "page");
+		assert_eq!(entry["request"]["postData"]["text"], r#"{"name":"x"}"#);
+		assert_eq!(entry["response"]["status"], 200);
+		assert_eq!(middleware.store().len(), 1);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_debug_endpoint_clear() {
+		// Arrange
+		let middleware = middleware(RequestRecorderConfig::new());
+		middleware
+			.process(request(Method::GET, "/a", ""), Arc::new(EchoHandler))
+			.await
+			.unwrap();
+
+		// Act
+		let response = middleware
+			.process(
+				request(Method::DELETE, "/__debug__/requests", ""),
+				Arc::new(EchoHandler),
+			)
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::NO_CONTENT);
+		assert!(middleware.store().is_empty());
+	}
+
+	#[rstest]
+	fn test_body_capture_truncates_and_encodes_binary() {
+		// Act
+		let text = RecordedBody::capture(b"abcdef", 3);
+		let binary = RecordedBody::capture(&[0xff, 0xfe], 10);
+
+		// Assert
+		assert_eq!(text.text, "abc");
+		assert!(text.truncated);
+		assert_eq!(text.size, 6);
+		assert!(binary.base64);
+		assert_eq!(binary.to_bytes().as_ref(), &[0xff, 0xfe]);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_recorded_request_rebuilds_request_without_redacted_headers() {
+		// Arrange
+		let middleware = middleware(RequestRecorderConfig::new());
+		middleware
+			.process(
+				request(Method::PUT, "/items/1", r#"{"name":"y"}"#),
+				Arc::new(EchoHandler),
+			)
+			.await
+			.unwrap();
+		let recorded = middleware.store().last().unwrap().request;
+
+		// Act
+		let rebuilt = recorded.to_request().unwrap();
+
+		// Assert
+		assert_eq!(rebuilt.method, Method::PUT);
+		assert_eq!(rebuilt.uri.path(), "/items/1");
+		assert!(rebuilt.headers.get("authorization").is_none());
+		assert_eq!(rebuilt.headers.get("x-trace").unwrap(), "1");
+		assert_eq!(
+			rebuilt.headers.get("content-type").unwrap(),
+			"application/json"
+		);
+		assert_eq!(rebuilt.body().as_ref(), br#"{"name":"y"}"#);
+	}
+}
@@ -7,14 +7,25 @@
 //! - X-Frame-Options
 //! - Referrer-Policy
 //! - Cross-Origin-Opener-Policy (COOP)
+//! - Canonical host (`www` / apex) redirects
+//!
+//! [`SecurityMiddleware::system_check`] validates the HSTS and redirect
+//! configuration through the system check framework (`security.*`).
 
+use crate::forwarded::{ForwardedSchemeConfig, canonical_host_for, is_valid_canonical_host};
+use crate::reloadable::ReloadableMiddleware;
 use async_trait::async_trait;
 use hyper::StatusCode;
 use hyper::header::{HeaderValue, LOCATION};
-use reinhardt_conf::SecuritySettings;
+use reinhardt_conf::settings::SettingsSnapshot;
+use reinhardt_conf::{HasSettings, SecuritySettings};
 use reinhardt_http::{Handler, Middleware, Request, Response, Result};
+use reinhardt_utils::utils_core::checks::{Check, CheckMessage};
 use std::sync::Arc;
 
+/// Minimum HSTS max-age accepted by the browsers' preload list (1 year)
+pub const HSTS_PRELOAD_MIN_SECONDS: u32 = 31536000;
+
 /// Security middleware for HTTP security headers and redirects
 ///
 /// # Construction
@@ -56,6 +67,10 @@ pub struct SecurityMiddleware {
 	pub x_frame_options: Option<String>,
 	/// Proxy SSL header name and expected value for identifying secure requests
 	pub secure_proxy_ssl_header: Option<(String, String)>,
+	/// Canonical host name; its `www` / apex counterpart is redirected to it
+	pub canonical_host: Option<String>,
+	/// Forwarded headers and trusted proxies used to detect HTTPS
+	pub forwarded_scheme: ForwardedSchemeConfig,
 }
 
 impl Default for SecurityMiddleware {
@@ -71,6 +86,8 @@ impl Default for SecurityMiddleware {
 			cross_origin_opener_policy: None,
 			x_frame_options: Some("DENY".to_string()),
 			secure_proxy_ssl_header: None,
+			canonical_host: None,
+			forwarded_scheme: ForwardedSchemeConfig::default(),
 		}
 	}
 }
@@ -157,6 +174,32 @@ impl SecurityMiddleware {
 		}
 	}
 
+	/// Create a SecurityMiddleware that follows a shared settings snapshot
+	///
+	/// The middleware is rebuilt from the [`SecuritySettings`] fragment the
+	/// first time a request arrives after `snapshot` is reloaded, instead of
+	/// being fixed at startup.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_conf::SecuritySettings;
+	/// use reinhardt_conf::settings::SettingsSnapshot;
+	/// use reinhardt_middleware::SecurityMiddleware;
+	/// use std::sync::Arc;
+	///
+	/// let snapshot = Arc::new(SettingsSnapshot::new(SecuritySettings::default()));
+	/// let middleware = SecurityMiddleware::reloadable(snapshot);
+	/// ```
+	pub fn reloadable<S>(snapshot: Arc<SettingsSnapshot<S>>) -> ReloadableMiddleware<S, Self>
+	where
+		S: HasSettings<SecuritySettings> + Send + Sync + 'static,
+	{
+		ReloadableMiddleware::new(snapshot, |settings: &S| {
+			Self::from_security_settings(settings.get_settings())
+		})
+	}
+
 	/// Set whether HSTS is enabled
 	pub fn with_hsts(mut self, enabled: bool) -> Self {
 		self.hsts_enabled = enabled;
@@ -245,11 +288,55 @@ impl SecurityMiddleware {
 		self
 	}
 
+	/// Set the canonical host name
+	///
+	/// Requests to its `www` / apex counterpart are redirected to it, e.g.
+	/// `www.example.com` to `example.com` for a canonical `example.com`.
+	pub fn with_canonical_host(mut self, host: impl Into<String>) -> Self {
+		self.canonical_host = Some(host.into());
+		self
+	}
+
+	/// Remove the canonical host redirect
+	pub fn without_canonical_host(mut self) -> Self {
+		self.canonical_host = None;
+		self
+	}
+
+	/// Set the forwarded headers and trusted proxies used to detect HTTPS
+	pub fn with_forwarded_scheme(mut self, config: ForwardedSchemeConfig) -> Self {
+		self.forwarded_scheme = config;
+		self
+	}
+
+	/// Create the system check validating this configuration
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::SecurityMiddleware;
+	/// use reinhardt_utils::utils_core::checks::Check;
+	///
+	/// let middleware = SecurityMiddleware::new()
+	///     .with_ssl_redirect(true)
+	///     .with_hsts_seconds(300)
+	///     .with_hsts_preload(true);
+	///
+	/// let messages = middleware.system_check().check();
+	/// assert!(messages.iter().any(|m| m.id == "security.E021"));
+	/// ```
+	pub fn system_check(&self) -> SecurityMiddlewareCheck {
+		SecurityMiddlewareCheck {
+			middleware: self.clone(),
+		}
+	}
+
 	/// Check if request is secure (HTTPS)
 	///
 	/// Delegates to `Request::is_secure()` which already validates trusted proxies
-	/// before honoring X-Forwarded-Proto headers. If a custom `secure_proxy_ssl_header`
-	/// is configured, it is only trusted when the request comes from a trusted proxy.
+	/// before honoring X-Forwarded-Proto headers, then to the configured
+	/// forwarded headers. If a custom `secure_proxy_ssl_header` is configured,
+	/// it is only trusted when the request comes from a trusted proxy.
 	fn is_secure(&self, request: &Request) -> bool {
 		// Check configured proxy SSL header (only from trusted proxies)
 		if let Some((ref header_name, ref header_value)) = self.secure_proxy_ssl_header
@@ -263,10 +350,11 @@ impl SecurityMiddleware {
 			// Untrusted source: ignore the spoofable header
 		}
 
-		// Delegate to Request::is_secure() which checks:
+		// Delegate to the forwarded scheme configuration which checks:
 		// 1. Actual TLS connection (is_secure flag)
-		// 2. X-Forwarded-Proto only from trusted proxies
-		request.is_secure()
+		// 2. X-Forwarded-Proto only from request-level trusted proxies
+		// 3. The configured forwarded headers only from its trusted proxies
+		self.forwarded_scheme.is_secure(request)
 	}
 
 	/// Build HSTS header value
@@ -284,21 +372,15 @@ impl SecurityMiddleware {
 		parts.join("; ")
 	}
 
-	/// Build redirect URL for HTTPS
-	fn build_https_url(&self, request: &Request) -> String {
-		let host = request
-			.headers
-			.get(hyper::header::HOST)
-			.and_then(|h| h.to_str().ok())
-			.unwrap_or("localhost");
-
+	/// Build redirect URL for `scheme` and `host`, keeping the path and query
+	fn build_redirect_url(&self, request: &Request, scheme: &str, host: &str) -> String {
 		let path_and_query = request
 			.uri
 			.path_and_query()
 			.map(|pq| pq.as_str())
 			.unwrap_or("/");
 
-		format!("https://{}{}", host, path_and_query)
+		format!("{}://{}{}", scheme, host, path_and_query)
 	}
 
 	/// Add security headers to response
@@ -350,10 +432,28 @@ impl SecurityMiddleware {
 impl Middleware for SecurityMiddleware {
 	async fn process(&self, request: Request, handler: Arc<dyn Handler>) -> Result<Response> {
 		let is_secure = self.is_secure(&request);
-
-		// SSL redirect for all HTTP methods
-		if self.ssl_redirect && !is_secure {
-			let redirect_url = self.build_https_url(&request);
+		let host = request
+			.headers
+			.get(hyper::header::HOST)
+			.and_then(|h| h.to_str().ok());
+		let canonical_host = self
+			.canonical_host
+			.as_deref()
+			.zip(host)
+			.and_then(|(canonical, host)| canonical_host_for(canonical, host));
+
+		// SSL and canonical host redirects for all HTTP methods, in one hop
+		if (self.ssl_redirect && !is_secure) || canonical_host.is_some() {
+			let scheme = if self.ssl_redirect || is_secure {
+				"https"
+			} else {
+				"http"
+			};
+			let redirect_url = self.build_redirect_url(
+				&request,
+				scheme,
+				canonical_host.as_deref().or(host).unwrap_or("localhost"),
+			);
 			let mut response = Response::new(StatusCode::PERMANENT_REDIRECT);
 			response.headers.insert(
 				LOCATION,
@@ -378,6 +478,100 @@ impl Middleware for SecurityMiddleware {
 	}
 }
 
+/// System check (`security.*`) for a [`SecurityMiddleware`] configuration
+///
+/// Follows the HSTS preload list requirements: a max-age of at least one
+/// year, `includeSubDomains` and HTTPS redirects.
+pub struct SecurityMiddlewareCheck {
+	middleware: SecurityMiddleware,
+}
+
+impl Check for SecurityMiddlewareCheck {
+	fn tags(&self) -> Vec<String> {
+		vec!["security".to_string()]
+	}
+
+	fn check(&self) -> Vec<CheckMessage> {
+		let config = &self.middleware;
+		let mut messages = Vec::new();
+		let hsts_active = config.hsts_enabled && config.hsts_seconds > 0;
+
+		if !hsts_active {
+			messages.push(
+				CheckMessage::warning("security.W004", "HSTS is not enabled").with_hint(
+					"Set a non-zero HSTS max-age once the site is served only over HTTPS",
+				),
+			);
+		} else if !config.hsts_include_subdomains {
+			messages.push(
+				CheckMessage::warning(
+					"security.W005",
+					"HSTS does not include subdomains (includeSubDomains)",
+				)
+				.with_hint("Enable includeSubDomains if every subdomain is served over HTTPS"),
+			);
+		}
+
+		if !config.ssl_redirect {
+			messages.push(
+				CheckMessage::warning("security.W008", "HTTP requests are not redirected to HTTPS")
+					.with_hint("Enable the SSL redirect unless a proxy already redirects to HTTPS"),
+			);
+		}
+
+		if config.hsts_preload {
+			let mut problems = Vec::new();
+			if !hsts_active {
+				problems.push("HSTS must be enabled".to_string());
+			} else if config.hsts_seconds < HSTS_PRELOAD_MIN_SECONDS {
+				problems.push(format!(
+					"max-age must be at least {} seconds (is {})",
+					HSTS_PRELOAD_MIN_SECONDS, config.hsts_seconds
+				));
+			}
+			if !config.hsts_include_subdomains {
+				problems.push("includeSubDomains must be set".to_string());
+			}
+			if !config.ssl_redirect {
+				problems.push("HTTP must redirect to HTTPS".to_string());
+			}
+			if !problems.is_empty() {
+				messages.push(CheckMessage::error(
+					"security.E021",
+					format!(
+						"HSTS preload is enabled but the site is not eligible: {}",
+						problems.join("; ")
+					),
+				));
+			}
+		}
+
+		if let Some(ref host) = config.canonical_host
+			&& !is_valid_canonical_host(host)
+		{
+			messages.push(
+				CheckMessage::error(
+					"security.E022",
+					format!("Canonical host '{}' is not a valid host name", host),
+				)
+				.with_hint("Use a bare host name without scheme, port or path"),
+			);
+		}
+
+		for proxy in config.forwarded_scheme.invalid_proxies() {
+			messages.push(CheckMessage::error(
+				"security.E023",
+				format!(
+					"Trusted proxy '{}' is neither an IP address nor a CIDR network",
+					proxy
+				),
+			));
+		}
+
+		messages
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -981,4 +1175,146 @@ mod tests {
 			"nosniff"
 		);
 	}
+
+	#[rstest]
+	#[case::www_to_apex_keeps_https(
+		"example.com",
+		"www.example.com",
+		true,
+		false,
+		"https://example.com/a?b=1"
+	)]
+	#[case::apex_to_www_keeps_http(
+		"www.example.com",
+		"example.com",
+		false,
+		false,
+		"http://www.example.com/a?b=1"
+	)]
+	#[case::combined_with_ssl_redirect(
+		"example.com",
+		"www.example.com",
+		false,
+		true,
+		"https://example.com/a?b=1"
+	)]
+	#[tokio::test]
+	async fn test_canonical_host_redirect(
+		#[case] canonical: &str,
+		#[case] host: &str,
+		#[case] secure: bool,
+		#[case] ssl_redirect: bool,
+		#[case] expected_location: &str,
+	) {
+		// Arrange
+		let middleware = SecurityMiddleware::new()
+			.with_canonical_host(canonical)
+			.with_ssl_redirect(ssl_redirect);
+		let mut headers = HeaderMap::new();
+		headers.insert(hyper::header::HOST, host.parse().unwrap());
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/a?b=1")
+			.headers(headers)
+			.secure(secure)
+			.build()
+			.unwrap();
+
+		// Act
+		let response = middleware
+			.process(request, Arc::new(TestHandler))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::PERMANENT_REDIRECT);
+		assert_eq!(response.headers.get(LOCATION).unwrap(), expected_location);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_forwarded_scheme_from_trusted_proxy_gets_hsts() {
+		// Arrange
+		let middleware = SecurityMiddleware::new()
+			.with_ssl_redirect(true)
+			.with_forwarded_scheme(
+				ForwardedSchemeConfig::new()
+					.with_headers(vec!["x-forwarded-ssl".to_string()])
+					.with_trusted_proxies(vec!["10.0.0.0/8".to_string()]),
+			);
+		let mut headers = HeaderMap::new();
+		headers.insert("x-forwarded-ssl", "on".parse().unwrap());
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/")
+			.headers(headers)
+			.remote_addr(SocketAddr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 5)), 443))
+			.build()
+			.unwrap();
+
+		// Act
+		let response = middleware
+			.process(request, Arc::new(TestHandler))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::OK);
+		assert!(response.headers.contains_key("Strict-Transport-Security"));
+	}
+
+	#[rstest]
+	fn test_system_check_accepts_preload_ready_configuration() {
+		// Arrange
+		let middleware = SecurityMiddleware::new()
+			.with_ssl_redirect(true)
+			.with_hsts_seconds(HSTS_PRELOAD_MIN_SECONDS)
+			.with_hsts_include_subdomains(true)
+			.with_hsts_preload(true)
+			.with_canonical_host("example.com");
+
+		// Act
+		let messages = middleware.system_check().check();
+
+		// Assert
+		assert!(messages.is_empty(), "{:?}", messages);
+	}
+
+	#[rstest]
+	fn test_system_check_reports_invalid_configuration() {
+		// Arrange
+		let middleware = SecurityMiddleware::new()
+			.with_hsts_seconds(3600)
+			.with_hsts_preload(true)
+			.with_canonical_host("https://example.com/")
+			.with_forwarded_scheme(
+				ForwardedSchemeConfig::new().with_trusted_proxies(vec!["lb".to_string()]),
+			);
+
+		// Act
+		let messages = middleware.system_check().check();
+
+		// Assert
+		let ids: Vec<&str> = messages.iter().map(|m| m.id.as_str()).collect();
+		assert_eq!(
+			ids,
+			vec![
+				"security.W005",
+				"security.W008",
+				"security.E021",
+				"security.E022",
+				"security.E023"
+			]
+		);
+		assert!(
+			messages[2]
+				.message
+				.contains("max-age must be at least 31536000")
+		);
+		assert!(
+			messages[2]
+				.message
+				.contains("includeSubDomains must be set")
+		);
+	}
 }
@@ -7,9 +7,10 @@
 //!
 //! - `id` — session-ID newtypes and the request-scoped active ID holder
 //! - `data` — the `SessionData` payload, read/write/rotate helpers
-//! - `store` — in-memory `SessionStore` with lazy eviction
+//! - `store` — in-memory `SessionStore` with lazy eviction, the per-user
+//!   session registry, and the concurrent session cap
 //! - `backend` — pluggable `AsyncSessionBackend` trait
-//! - `config` — `SessionConfig` cookie/TTL knobs
+//! - `config` — `SessionConfig` cookie/TTL knobs and `SessionLifetime`
 //! - `middleware` — `SessionMiddleware` that wires it all together
 //! - `injectable` — DI integration (`Injectable` impl for `SessionData`).
 //!   Handlers that want the store directly use
@@ -41,11 +42,11 @@ pub mod test_support;
 
 pub use auth_ext::SessionAuthExt;
 pub use backend::AsyncSessionBackend;
-pub use config::SessionConfig;
+pub use config::{DEFAULT_REMEMBER_ME_TTL, SessionConfig, SessionLifetime};
 pub use data::{SessionData, USER_ID_SESSION_KEY};
 pub use id::{ActiveSessionId, SessionCookieName, SessionId};
 pub use middleware::SessionMiddleware;
-pub use store::{SessionStore, SessionStoreKey};
+pub use store::{SessionInfo, SessionStore, SessionStoreKey};
 pub use value::{
 	OptionalSessionValue, OptionalSessionValueNamed, SessionKey, SessionValue, SessionValueNamed,
 	UserIdKey,
@@ -955,4 +956,180 @@ mod async_backend_tests {
 		backend.destroy(&id).await.unwrap();
 		assert!(backend.load(&id).await.unwrap().is_none());
 	}
+
+	/// Handler that logs in `user-42` with the configured lifetime
+	struct LifetimeLoginHandler {
+		store: Arc<SessionStore>,
+		lifetime: SessionLifetime,
+	}
+
+	#[async_trait]
+	impl Handler for LifetimeLoginHandler {
+		async fn handle(&self, request: Request) -> Result<Response> {
+			let active_id = request.extensions.get::<ActiveSessionId>().unwrap();
+			let mut session = self.store.get(&active_id.get()).unwrap();
+			session.id_holder = Some(active_id);
+			session.login_with_lifetime(&self.store, "user-42", self.lifetime)?;
+			Ok(Response::ok())
+		}
+	}
+
+	fn user_session(user_id: &str, last_accessed_secs_ago: u64) -> SessionData {
+		let mut session = SessionData::new(Duration::from_secs(3600));
+		session
+			.set(USER_ID_SESSION_KEY.to_string(), user_id)
+			.unwrap();
+		session.last_accessed = SystemTime::now() - Duration::from_secs(last_accessed_secs_ago);
+		session
+	}
+
+	#[rstest::rstest]
+	#[case::standard(SessionLifetime::Standard, Some("Max-Age=3600"))]
+	#[case::short(SessionLifetime::Short, None)]
+	#[case::persistent(SessionLifetime::Persistent, Some("Max-Age=1209600"))]
+	#[tokio::test]
+	async fn test_login_lifetime_controls_cookie_and_expiry(
+		#[case] lifetime: SessionLifetime,
+		#[case] expected_max_age: Option<&str>,
+	) {
+		// Arrange
+		let config = SessionConfig::new("sessionid".to_string(), Duration::from_secs(3600));
+		let store = Arc::new(SessionStore::new());
+		let middleware = SessionMiddleware::from_arc(config.clone(), Arc::clone(&store));
+		let handler = Arc::new(LifetimeLoginHandler {
+			store: Arc::clone(&store),
+			lifetime,
+		});
+		let request = Request::builder()
+			.method(Method::POST)
+			.uri("/login")
+			.version(Version::HTTP_11)
+			.headers(HeaderMap::new())
+			.body(Bytes::new())
+			.build()
+			.unwrap();
+
+		// Act
+		let response = middleware.process(request, handler).await.unwrap();
+
+		// Assert
+		let cookie = response
+			.headers
+			.get("set-cookie")
+			.unwrap()
+			.to_str()
+			.unwrap();
+		match expected_max_age {
+			Some(max_age) => assert!(cookie.contains(max_age), "{cookie}"),
+			None => assert!(!cookie.contains("Max-Age"), "{cookie}"),
+		}
+		let session_id = cookie.split(';').next().unwrap().split('=').nth(1).unwrap();
+		let stored = store.get(session_id).unwrap();
+		assert_eq!(stored.lifetime, lifetime);
+		let remaining = stored.expires_at.duration_since(SystemTime::now()).unwrap();
+		assert!(remaining > config.ttl_for(lifetime) - Duration::from_secs(60));
+	}
+
+	#[rstest::rstest]
+	#[tokio::test]
+	async fn test_session_registry_records_client_metadata() {
+		// Arrange
+		let store = Arc::new(SessionStore::new());
+		let middleware = SessionMiddleware::from_arc(SessionConfig::default(), Arc::clone(&store));
+		let session = user_session("user-42", 0);
+		let session_id = session.id.clone();
+		store.save(session);
+		store.save(user_session("user-7", 0));
+		let mut headers = HeaderMap::new();
+		headers.insert(
+			hyper::header::COOKIE,
+			format!("sessionid={}", session_id).parse().unwrap(),
+		);
+		headers.insert(hyper::header::USER_AGENT, "Firefox/130".parse().unwrap());
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/")
+			.version(Version::HTTP_11)
+			.headers(headers)
+			.remote_addr("203.0.113.9:4000".parse().unwrap())
+			.body(Bytes::new())
+			.build()
+			.unwrap();
+
+		// Act
+		middleware
+			.process(request, Arc::new(TestHandler))
+			.await
+			.unwrap();
+		let sessions = store.sessions_for_user("user-42");
+
+		// Assert
+		assert_eq!(sessions.len(), 1);
+		assert_eq!(sessions[0].id, session_id);
+		assert_eq!(sessions[0].user_agent.as_deref(), Some("Firefox/130"));
+		assert_eq!(sessions[0].ip_address.as_deref(), Some("203.0.113.9"));
+	}
+
+	#[rstest::rstest]
+	fn test_revoke_other_sessions_keeps_current_and_other_users() {
+		// Arrange
+		let store = SessionStore::new();
+		let current = user_session("user-42", 0);
+		store.save(current.clone());
+		store.save(user_session("user-42", 10));
+		store.save(user_session("user-42", 20));
+		store.save(user_session("user-7", 0));
+
+		// Act
+		let revoked = current.logout_other_sessions(&store);
+
+		// Assert
+		assert_eq!(revoked, 2);
+		let remaining = store.sessions_for_user("user-42");
+		assert_eq!(remaining.len(), 1);
+		assert_eq!(remaining[0].id, current.id);
+		assert_eq!(store.sessions_for_user("user-7").len(), 1);
+	}
+
+	#[rstest::rstest]
+	#[case::own_session("user-42", true)]
+	#[case::foreign_session("user-7", false)]
+	fn test_revoke_session_checks_ownership(#[case] user_id: &str, #[case] expected: bool) {
+		// Arrange
+		let store = SessionStore::new();
+		let session = user_session("user-42", 0);
+		store.save(session.clone());
+
+		// Act
+		let revoked = store.revoke_session(user_id, &session.id);
+
+		// Assert
+		assert_eq!(revoked, expected);
+		assert_eq!(store.get(&session.id).is_none(), expected);
+	}
+
+	#[rstest::rstest]
+	fn test_login_evicts_least_recently_used_sessions_over_cap() {
+		// Arrange
+		let store = SessionStore::new();
+		store.set_max_sessions_per_user(Some(2));
+		let recent = user_session("user-42", 10);
+		let stale = user_session("user-42", 100);
+		store.save(recent.clone());
+		store.save(stale.clone());
+		let mut session = SessionData::new(Duration::from_secs(3600));
+		store.save(session.clone());
+
+		// Act
+		session.login(&store, "user-42").unwrap();
+
+		// Assert
+		let ids: Vec<String> = store
+			.sessions_for_user("user-42")
+			.into_iter()
+			.map(|info| info.id)
+			.collect();
+		assert_eq!(ids, vec![session.id.clone(), recent.id.clone()]);
+		assert!(store.get(&stale.id).is_none());
+	}
 }
@@ -9,6 +9,7 @@
 use reinhardt_http::Result;
 use serde::Serialize;
 
+use super::config::SessionLifetime;
 use super::data::{SessionData, USER_ID_SESSION_KEY};
 use super::store::SessionStore;
 
@@ -70,8 +71,33 @@ pub trait SessionAuthExt {
 	/// Returns a [`reinhardt_http::Result`] so the serialisation failure
 	/// inside [`SessionData::set`] propagates with the same error type as
 	/// the rest of the session API.
-	fn login<V: Serialize + Send + Sync>(&mut self, store: &SessionStore, user_id: V)
-	-> Result<()>;
+	///
+	/// If the store caps concurrent sessions per user, the least recently
+	/// used sessions beyond the cap are revoked.
+	fn login<V: Serialize + Send + Sync>(
+		&mut self,
+		store: &SessionStore,
+		user_id: V,
+	) -> Result<()> {
+		self.login_with_lifetime(store, user_id, SessionLifetime::Standard)
+	}
+
+	/// Like [`login`](SessionAuthExt::login), selecting the session lifetime.
+	///
+	/// Pass [`SessionLifetime::Persistent`] for a "remember me" login, or
+	/// [`SessionLifetime::Short`] for a session that ends with the browser.
+	/// `SessionMiddleware` applies the matching TTL and cookie `Max-Age`.
+	fn login_with_lifetime<V: Serialize + Send + Sync>(
+		&mut self,
+		store: &SessionStore,
+		user_id: V,
+		lifetime: SessionLifetime,
+	) -> Result<()>;
+
+	/// Revoke every other session of the logged-in user.
+	///
+	/// Returns the number of sessions removed, or `0` for anonymous sessions.
+	fn logout_other_sessions(&self, store: &SessionStore) -> usize;
 
 	/// Clear the authenticated-user reference from the current session.
 	///
@@ -84,21 +110,30 @@ pub trait SessionAuthExt {
 }
 
 impl SessionAuthExt for SessionData {
-	fn login<V: Serialize + Send + Sync>(
+	fn login_with_lifetime<V: Serialize + Send + Sync>(
 		&mut self,
 		store: &SessionStore,
 		user_id: V,
+		lifetime: SessionLifetime,
 	) -> Result<()> {
 		let old_id = self.regenerate_id();
 		self.set(USER_ID_SESSION_KEY.to_string(), user_id)?;
+		self.lifetime = lifetime;
 		store.delete(&old_id);
 		store.save(self.clone());
+		store.enforce_session_limit(self);
 		Ok(())
 	}
 
+	fn logout_other_sessions(&self, store: &SessionStore) -> usize {
+		self.user_id()
+			.map_or(0, |user_id| store.revoke_other_sessions(&user_id, &self.id))
+	}
+
 	fn logout(&mut self, store: &SessionStore) {
 		let old_id = self.regenerate_id();
 		self.delete(USER_ID_SESSION_KEY);
+		self.lifetime = SessionLifetime::Standard;
 		store.delete(&old_id);
 		store.save(self.clone());
 	}
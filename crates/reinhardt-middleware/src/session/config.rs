@@ -1,7 +1,27 @@
 //! `SessionConfig`: cookie name, TTL, and cookie-attribute knobs.
 
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
+/// Default lifetime of a "remember me" session: 14 days
+pub const DEFAULT_REMEMBER_ME_TTL: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+
+/// Lifetime selected for a session at login
+///
+/// Chosen per login through
+/// [`SessionAuthExt::login_with_lifetime`](super::SessionAuthExt::login_with_lifetime).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SessionLifetime {
+	/// Expires after [`SessionConfig::ttl`]; the cookie carries a matching `Max-Age`
+	#[default]
+	Standard,
+	/// Expires after [`SessionConfig::ttl`] or when the browser closes, whichever
+	/// comes first; the cookie is sent without `Max-Age`
+	Short,
+	/// "Remember me": expires after [`SessionConfig::remember_me_ttl`]
+	Persistent,
+}
+
 /// Session configuration
 #[non_exhaustive]
 #[derive(Debug, Clone)]
@@ -20,6 +40,13 @@ pub struct SessionConfig {
 	pub domain: Option<String>,
 	/// Path
 	pub path: String,
+	/// TTL of sessions created with [`SessionLifetime::Persistent`]
+	pub remember_me_ttl: Duration,
+	/// Maximum number of concurrent sessions per user (`None` for unlimited)
+	///
+	/// When a login exceeds the cap, the least recently used sessions of that
+	/// user are revoked.
+	pub max_sessions_per_user: Option<usize>,
 }
 
 impl SessionConfig {
@@ -44,6 +71,8 @@ impl SessionConfig {
 			same_site: Some("Lax".to_string()),
 			domain: None,
 			path: "/".to_string(),
+			remember_me_ttl: DEFAULT_REMEMBER_ME_TTL,
+			max_sessions_per_user: None,
 		}
 	}
 
@@ -129,6 +158,57 @@ impl SessionConfig {
 		self.path = path;
 		self
 	}
+
+	/// Set the TTL of "remember me" sessions
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use std::time::Duration;
+	/// use reinhardt_middleware::session::SessionConfig;
+	///
+	/// let config = SessionConfig::default()
+	///     .with_remember_me_ttl(Duration::from_secs(30 * 24 * 60 * 60));
+	/// assert_eq!(config.remember_me_ttl.as_secs(), 2_592_000);
+	/// ```
+	pub fn with_remember_me_ttl(mut self, ttl: Duration) -> Self {
+		self.remember_me_ttl = ttl;
+		self
+	}
+
+	/// Cap the number of concurrent sessions per user
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::session::SessionConfig;
+	///
+	/// let config = SessionConfig::default().with_max_sessions_per_user(3);
+	/// assert_eq!(config.max_sessions_per_user, Some(3));
+	/// ```
+	pub fn with_max_sessions_per_user(mut self, max: usize) -> Self {
+		self.max_sessions_per_user = Some(max);
+		self
+	}
+
+	/// TTL applied to a session with the given lifetime
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use std::time::Duration;
+	/// use reinhardt_middleware::session::{SessionConfig, SessionLifetime};
+	///
+	/// let config = SessionConfig::new("sessionid".to_string(), Duration::from_secs(3600));
+	/// assert_eq!(config.ttl_for(SessionLifetime::Short), Duration::from_secs(3600));
+	/// assert_eq!(config.ttl_for(SessionLifetime::Persistent), config.remember_me_ttl);
+	/// ```
+	pub fn ttl_for(&self, lifetime: SessionLifetime) -> Duration {
+		match lifetime {
+			SessionLifetime::Standard | SessionLifetime::Short => self.ttl,
+			SessionLifetime::Persistent => self.remember_me_ttl,
+		}
+	}
 }
 
 impl Default for SessionConfig {
@@ -6,6 +6,7 @@ use std::collections::HashMap;
 use std::time::{Duration, SystemTime};
 use uuid::Uuid;
 
+use super::config::SessionLifetime;
 use super::id::ActiveSessionId;
 
 /// Canonical session-store key used by Reinhardt examples to persist the
@@ -35,6 +36,15 @@ pub struct SessionData {
 	pub last_accessed: SystemTime,
 	/// Expiration timestamp
 	pub expires_at: SystemTime,
+	/// Lifetime selected at login
+	#[serde(default)]
+	pub lifetime: SessionLifetime,
+	/// `User-Agent` of the most recent request
+	#[serde(default)]
+	pub user_agent: Option<String>,
+	/// Client IP address of the most recent request
+	#[serde(default)]
+	pub ip_address: Option<String>,
 	/// Back-reference to the request-scoped active session ID holder.
 	///
 	/// Populated by `SessionData::inject` from the request extensions; used by
@@ -59,6 +69,9 @@ impl SessionData {
 			created_at: now,
 			last_accessed: now,
 			expires_at: now + ttl,
+			lifetime: SessionLifetime::default(),
+			user_agent: None,
+			ip_address: None,
 			id_holder: None,
 		}
 	}
@@ -92,6 +105,20 @@ impl SessionData {
 		self.expires_at = now + ttl;
 	}
 
+	/// Primary key of the authenticated user, if any
+	///
+	/// Reads [`USER_ID_SESSION_KEY`], rendering numbers and booleans as
+	/// strings. An empty user id counts as anonymous.
+	pub fn user_id(&self) -> Option<String> {
+		let user_id = match self.data.get(USER_ID_SESSION_KEY)? {
+			serde_json::Value::String(s) => s.clone(),
+			serde_json::Value::Number(n) => n.to_string(),
+			serde_json::Value::Bool(b) => b.to_string(),
+			_ => return None,
+		};
+		(!user_id.is_empty()).then_some(user_id)
+	}
+
 	/// Get a value
 	pub fn get<T>(&self, key: &str) -> Option<T>
 	where
@@ -9,8 +9,9 @@ use std::any::TypeId;
 use std::sync::Arc;
 
 use super::config::SessionConfig;
+use super::config::SessionLifetime;
 use super::cookie::find_cookie_value;
-use super::data::{SessionData, USER_ID_SESSION_KEY};
+use super::data::SessionData;
 use super::id::{ActiveSessionId, SessionCookieName, SessionId};
 use super::store::{SessionStore, SessionStoreKey};
 
@@ -71,10 +72,7 @@ impl SessionMiddleware {
 	/// let middleware = SessionMiddleware::new(config);
 	/// ```
 	pub fn new(config: SessionConfig) -> Self {
-		Self {
-			config,
-			store: Arc::new(SessionStore::new()),
-		}
+		Self::from_arc(config, Arc::new(SessionStore::new()))
 	}
 
 	/// Create with default configuration
@@ -86,7 +84,11 @@ impl SessionMiddleware {
 	///
 	/// This is provided for cases where you already have an `Arc<SessionStore>`.
 	/// In most cases, you should use `new()` instead, which creates the store internally.
+	///
+	/// The store adopts the configured
+	/// [`max_sessions_per_user`](SessionConfig::max_sessions_per_user) cap.
 	pub fn from_arc(config: SessionConfig, store: Arc<SessionStore>) -> Self {
+		store.set_max_sessions_per_user(config.max_sessions_per_user);
 		Self { config, store }
 	}
 
@@ -126,7 +128,10 @@ impl SessionMiddleware {
 	}
 
 	/// Build Set-Cookie header
-	fn build_cookie_header(&self, session_id: &str) -> String {
+	///
+	/// [`SessionLifetime::Short`] sessions get a browser-session cookie
+	/// without `Max-Age`.
+	fn build_cookie_header(&self, session_id: &str, lifetime: SessionLifetime) -> String {
 		let mut parts = vec![format!("{}={}", self.config.cookie_name, session_id)];
 
 		parts.push(format!("Path={}", self.config.path));
@@ -147,33 +152,22 @@ impl SessionMiddleware {
 			parts.push(format!("SameSite={}", same_site));
 		}
 
-		parts.push(format!("Max-Age={}", self.config.ttl.as_secs()));
+		if lifetime != SessionLifetime::Short {
+			parts.push(format!(
+				"Max-Age={}",
+				self.config.ttl_for(lifetime).as_secs()
+			));
+		}
 
 		parts.join("; ")
 	}
 
-	fn user_id_from_session(session: &SessionData) -> Option<String> {
-		let value = session.data.get(USER_ID_SESSION_KEY)?;
-		let user_id = match value {
-			serde_json::Value::String(s) => s.clone(),
-			serde_json::Value::Number(n) => n.to_string(),
-			serde_json::Value::Bool(b) => b.to_string(),
-			_ => return None,
-		};
-
-		if user_id.is_empty() {
-			None
-		} else {
-			Some(user_id)
-		}
-	}
-
 	fn populate_auth_extensions(request: &Request, session: &SessionData) {
 		if request.extensions.contains::<AuthState>() {
 			return;
 		}
 
-		let Some(user_id) = Self::user_id_from_session(session) else {
+		let Some(user_id) = session.user_id() else {
 			request.extensions.insert(IsAuthenticated(false));
 			request.extensions.insert(IsAdmin(false));
 			request.extensions.insert(IsActive(false));
@@ -240,8 +234,20 @@ impl Middleware for SessionMiddleware {
 			SessionData::new(self.config.ttl)
 		};
 
-		// Touch the session
-		session.touch(self.config.ttl);
+		let initial_lifetime = session.lifetime;
+
+		// Touch the session and record the client for the session registry
+		session.touch(self.config.ttl_for(session.lifetime));
+		if let Some(user_agent) = request
+			.headers
+			.get(hyper::header::USER_AGENT)
+			.and_then(|value| value.to_str().ok())
+		{
+			session.user_agent = Some(user_agent.to_string());
+		}
+		if let Some(ip) = request.client_ip() {
+			session.ip_address = Some(ip.to_string());
+		}
 
 		// Save the session
 		self.store.save(session.clone());
@@ -273,7 +279,19 @@ impl Middleware for SessionMiddleware {
 		// local `session` clone, since handlers may have rotated the ID via
 		// `SessionData::regenerate_id`. See #3827.
 		let final_id = active_id.get();
-		let cookie = self.build_cookie_header(&final_id);
+		let final_session = self.store.get(&final_id);
+		let lifetime = final_session
+			.as_ref()
+			.map(|session| session.lifetime)
+			.unwrap_or_default();
+		// A login may have switched the lifetime; re-apply the matching TTL
+		if let Some(mut session) = final_session
+			&& lifetime != initial_lifetime
+		{
+			session.touch(self.config.ttl_for(lifetime));
+			self.store.save(session);
+		}
+		let cookie = self.build_cookie_header(&final_id, lifetime);
 		response.headers.append(
 			hyper::header::SET_COOKIE,
 			hyper::header::HeaderValue::from_str(&cookie).map_err(|e| {
@@ -291,6 +309,7 @@ impl Middleware for SessionMiddleware {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::session::USER_ID_SESSION_KEY;
 	use bytes::Bytes;
 	use hyper::{HeaderMap, Method, Version};
 	use reinhardt_http::{AuthState, Handler, Request};
@@ -1,11 +1,51 @@
 //! In-memory `SessionStore` with lazy eviction of expired sessions.
 
+use serde::Serialize;
 use std::collections::HashMap;
 use std::sync::RwLock;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::SystemTime;
 
+use super::config::SessionLifetime;
 use super::data::SessionData;
 
+/// Summary of an active session, as listed by [`SessionStore::sessions_for_user`]
+///
+/// Carries the metadata needed for an "active devices" page without exposing
+/// the session payload.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct SessionInfo {
+	/// Session ID
+	pub id: String,
+	/// Creation timestamp
+	pub created_at: SystemTime,
+	/// Last access timestamp
+	pub last_accessed: SystemTime,
+	/// Expiration timestamp
+	pub expires_at: SystemTime,
+	/// Lifetime selected at login
+	pub lifetime: SessionLifetime,
+	/// `User-Agent` of the most recent request
+	pub user_agent: Option<String>,
+	/// Client IP address of the most recent request
+	pub ip_address: Option<String>,
+}
+
+impl From<&SessionData> for SessionInfo {
+	fn from(session: &SessionData) -> Self {
+		Self {
+			id: session.id.clone(),
+			created_at: session.created_at,
+			last_accessed: session.last_accessed,
+			expires_at: session.expires_at,
+			lifetime: session.lifetime,
+			user_agent: session.user_agent.clone(),
+			ip_address: session.ip_address.clone(),
+		}
+	}
+}
+
 /// DI key for resolving the middleware-owned session store through
 /// `Depends<SessionStoreKey, Arc<SessionStore>>`.
 #[derive(Debug, Clone, Copy)]
@@ -30,6 +70,8 @@ pub struct SessionStore {
 	max_sessions_before_cleanup: AtomicUsize,
 	/// Next session count at which `save` should perform cleanup.
 	next_cleanup_session_count: AtomicUsize,
+	/// Concurrent session cap per user; `0` means unlimited
+	max_sessions_per_user: AtomicUsize,
 }
 
 impl Default for SessionStore {
@@ -57,6 +99,7 @@ impl SessionStore {
 			sessions: RwLock::new(HashMap::new()),
 			max_sessions_before_cleanup: AtomicUsize::new(threshold),
 			next_cleanup_session_count: AtomicUsize::new(threshold),
+			max_sessions_per_user: AtomicUsize::new(0),
 		}
 	}
 
@@ -68,6 +111,94 @@ impl SessionStore {
 			.store(threshold, Ordering::Relaxed);
 	}
 
+	/// Set the concurrent session cap per user (`None` for unlimited).
+	///
+	/// The cap is enforced by [`SessionStore::enforce_session_limit`], which
+	/// [`SessionAuthExt::login`](super::SessionAuthExt::login) calls.
+	pub fn set_max_sessions_per_user(&self, max: Option<usize>) {
+		self.max_sessions_per_user
+			.store(max.unwrap_or(0), Ordering::Relaxed);
+	}
+
+	/// Get the concurrent session cap per user
+	pub fn max_sessions_per_user(&self) -> Option<usize> {
+		match self.max_sessions_per_user.load(Ordering::Relaxed) {
+			0 => None,
+			max => Some(max),
+		}
+	}
+
+	/// List the active sessions of a user, most recently used first
+	pub fn sessions_for_user(&self, user_id: &str) -> Vec<SessionInfo> {
+		let sessions = self.sessions.read().unwrap_or_else(|e| e.into_inner());
+		let mut infos: Vec<SessionInfo> = sessions
+			.values()
+			.filter(|session| session.is_valid() && session.user_id().as_deref() == Some(user_id))
+			.map(SessionInfo::from)
+			.collect();
+		infos.sort_by(|a, b| b.last_accessed.cmp(&a.last_accessed));
+		infos
+	}
+
+	/// Revoke one session, only if it belongs to `user_id`
+	///
+	/// Returns `true` if a session was removed. The ownership check lets
+	/// handlers expose revocation to end users without further validation.
+	pub fn revoke_session(&self, user_id: &str, session_id: &str) -> bool {
+		let mut sessions = self.sessions.write().unwrap_or_else(|e| e.into_inner());
+		let owned = sessions
+			.get(session_id)
+			.is_some_and(|session| session.user_id().as_deref() == Some(user_id));
+		owned && sessions.remove(session_id).is_some()
+	}
+
+	/// Revoke every session of `user_id` except `current_id`
+	///
+	/// Returns the number of sessions removed.
+	pub fn revoke_other_sessions(&self, user_id: &str, current_id: &str) -> usize {
+		self.remove_user_sessions(user_id, |session| session.id != current_id)
+	}
+
+	/// Revoke every session of `user_id`
+	///
+	/// Returns the number of sessions removed.
+	pub fn revoke_user_sessions(&self, user_id: &str) -> usize {
+		self.remove_user_sessions(user_id, |_| true)
+	}
+
+	/// Enforce the concurrent session cap for the user owning `session`
+	///
+	/// Keeps `session` and the most recently used other sessions, and revokes
+	/// the rest. Returns the number of sessions removed.
+	pub fn enforce_session_limit(&self, session: &SessionData) -> usize {
+		let (Some(max), Some(user_id)) = (self.max_sessions_per_user(), session.user_id()) else {
+			return 0;
+		};
+		let mut others: Vec<(String, SystemTime)> = self
+			.sessions_for_user(&user_id)
+			.into_iter()
+			.filter(|info| info.id != session.id)
+			.map(|info| (info.id, info.last_accessed))
+			.collect();
+		others.sort_by(|a, b| b.1.cmp(&a.1));
+
+		let mut sessions = self.sessions.write().unwrap_or_else(|e| e.into_inner());
+		others
+			.into_iter()
+			.skip(max.saturating_sub(1))
+			.filter(|(id, _)| sessions.remove(id).is_some())
+			.count()
+	}
+
+	fn remove_user_sessions(&self, user_id: &str, filter: impl Fn(&SessionData) -> bool) -> usize {
+		let mut sessions = self.sessions.write().unwrap_or_else(|e| e.into_inner());
+		let before = sessions.len();
+		sessions.retain(|_, session| {
+			!(session.user_id().as_deref() == Some(user_id) && filter(session))
+		});
+		before - sessions.len()
+	}
+
 	/// Get a session
 	pub fn get(&self, id: &str) -> Option<SessionData> {
 		let sessions = self.sessions.read().unwrap_or_else(|e| e.into_inner());
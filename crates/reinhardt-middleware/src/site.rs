@@ -2,40 +2,20 @@
 //!
 //! Enables multi-site support by identifying and setting the current site
 //! based on the incoming request's host header.
+//!
+//! The resolved [`Site`] is stored in the request extensions, where handlers
+//! read it with [`Site::current`].
 
 use async_trait::async_trait;
 use hyper::header::HeaderName;
+use reinhardt_conf::SitesSettings;
 use reinhardt_http::{Handler, Middleware, Request, Response, Result};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
-/// Site information
-#[derive(Debug, Clone, PartialEq)]
-pub struct Site {
-	/// Site ID
-	pub id: u64,
-	/// Domain name
-	pub domain: String,
-	/// Site name
-	pub name: String,
-}
-
-impl Site {
-	/// Create a new site
-	///
-	/// # Examples
-	///
-	/// ```
-	/// use reinhardt_middleware::Site;
-	///
-	/// let site = Site::new(1, "example.com".to_string(), "Example Site".to_string());
-	/// assert_eq!(site.id, 1);
-	/// assert_eq!(site.domain, "example.com");
-	/// ```
-	pub fn new(id: u64, domain: String, name: String) -> Self {
-		Self { id, domain, name }
-	}
-}
+// Re-export Site from reinhardt-http so that handlers, feeds and sitemaps
+// can read the current site without depending on this crate.
+pub use reinhardt_http::Site;
 
 /// Site registry for managing multiple sites
 #[derive(Debug, Default)]
@@ -113,6 +93,30 @@ impl SiteRegistry {
 		None
 	}
 
+	/// Get site by ID
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::{Site, SiteRegistry};
+	///
+	/// let registry = SiteRegistry::new();
+	/// registry.register(Site::new(2, "example.org".to_string(), "Org".to_string()));
+	/// assert_eq!(registry.get_by_id(2).unwrap().domain, "example.org");
+	/// ```
+	pub fn get_by_id(&self, id: u64) -> Option<Site> {
+		let default_site = self.default_site();
+		if let Some(site) = default_site.filter(|site| site.id == id) {
+			return Some(site);
+		}
+		self.sites
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.values()
+			.find(|site| site.id == id)
+			.cloned()
+	}
+
 	/// Get default site
 	pub fn default_site(&self) -> Option<Site> {
 		self.default_site
@@ -283,6 +287,47 @@ impl SiteMiddleware {
 		Self::new(SiteConfig::default())
 	}
 
+	/// Create a SiteMiddleware whose default site comes from settings
+	///
+	/// When `settings.domain` is set, the configured site is registered and
+	/// used for requests whose host matches no other registered site.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_conf::SitesSettings;
+	/// use reinhardt_middleware::SiteMiddleware;
+	///
+	/// let settings = SitesSettings {
+	///     site_id: 1,
+	///     domain: Some("example.com".to_string()),
+	///     name: Some("Example".to_string()),
+	/// };
+	/// let middleware = SiteMiddleware::from_settings(&settings);
+	/// assert_eq!(middleware.registry.default_site().unwrap().id, 1);
+	/// ```
+	pub fn from_settings(settings: &SitesSettings) -> Self {
+		let middleware = Self::with_defaults();
+		if let Some(domain) = &settings.domain {
+			let name = settings.name.clone().unwrap_or_else(|| domain.clone());
+			let site = Site::new(settings.site_id, domain.clone(), name);
+			middleware.registry.register(site.clone());
+			middleware.registry.set_default(site);
+		}
+		middleware
+	}
+
+	/// Resolve the site for a request
+	fn resolve_site(&self, request: &Request) -> Option<Site> {
+		let site = self
+			.get_host(request)
+			.and_then(|host| self.registry.get_by_domain(&host));
+		if site.is_none() && self.config.fallback_enabled {
+			return self.registry.default_site();
+		}
+		site
+	}
+
 	/// Get host from request
 	fn get_host(&self, request: &Request) -> Option<String> {
 		request
@@ -310,44 +355,10 @@ impl Middleware for SiteMiddleware {
 			return handler.handle(request).await;
 		}
 
-		// Get host from request
-		let host = match self.get_host(&request) {
-			Some(h) => h,
-			None => {
-				// No host header, use default site if fallback enabled
-				if self.config.fallback_enabled {
-					let default_site = self
-						.registry
-						.default_site
-						.read()
-						.unwrap_or_else(|e| e.into_inner())
-						.clone();
-					if let Some(site) = default_site {
-						// Convert errors to responses so post-processing always runs,
-						// even when invoked outside MiddlewareChain. (#3244)
-						let mut response = match handler.handle(request).await {
-							Ok(resp) => resp,
-							Err(e) => Response::from(e),
-						};
-						if let (Ok(header_name), Ok(header_value)) = (
-							SITE_ID_HEADER.parse::<HeaderName>(),
-							site.id.to_string().parse(),
-						) {
-							response.headers.insert(header_name, header_value);
-						}
-						return Ok(response);
-					}
-				}
-				return handler.handle(request).await;
-			}
-		};
-
-		// Get site for this host
-		let mut site = self.registry.get_by_domain(&host);
-
-		// If no site found, try default site if fallback enabled
-		if site.is_none() && self.config.fallback_enabled {
-			site = self.registry.default_site();
+		// Resolve the site from the host, falling back to the default site
+		let site = self.resolve_site(&request);
+		if let Some(site) = &site {
+			request.extensions.insert(site.clone());
 		}
 
 		// Convert errors to responses so post-processing always runs,
@@ -663,6 +674,56 @@ mod tests {
 		assert_eq!(response.status, StatusCode::OK);
 	}
 
+	struct CurrentSiteHandler;
+
+	#[async_trait]
+	impl Handler for CurrentSiteHandler {
+		async fn handle(&self, request: Request) -> Result<Response> {
+			let domain = Site::current(&request)
+				.map(|site| site.domain)
+				.unwrap_or_default();
+			Ok(Response::new(StatusCode::OK).with_body(Bytes::from(domain)))
+		}
+	}
+
+	#[rstest::rstest]
+	#[case("blog.example.com", "blog.example.com")]
+	#[case("unknown.com", "example.com")]
+	#[tokio::test]
+	async fn test_current_site_from_host_or_settings(#[case] host: &str, #[case] expected: &str) {
+		// Arrange
+		let settings = SitesSettings {
+			site_id: 1,
+			domain: Some("example.com".to_string()),
+			name: None,
+		};
+		let middleware = SiteMiddleware::from_settings(&settings);
+		middleware.registry.register(Site::new(
+			2,
+			"blog.example.com".to_string(),
+			"Blog".to_string(),
+		));
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/test")
+			.header(hyper::header::HOST, host)
+			.build()
+			.unwrap();
+
+		// Act
+		let response = middleware
+			.process(request, Arc::new(CurrentSiteHandler))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.body, Bytes::from(expected.to_string()));
+		assert_eq!(
+			middleware.registry.get_by_id(1).unwrap().name,
+			"example.com"
+		);
+	}
+
 	#[rstest::rstest]
 	fn test_rwlock_poison_recovery_site_registry() {
 		// Arrange
@@ -0,0 +1,1047 @@
+//! Slow request and slow query alerting
+//!
+//! [`SlowRequestMiddleware`] times every request, and [`SlowQueryListener`]
+//! times every SQL query executed through the ORM. When one of them exceeds
+//! its configured threshold, an [`AlertEvent`] carrying the request ID, the
+//! route, the SQL and a timing breakdown (time spent in the database versus
+//! in application code, and the slowest queries of the request) is sent to
+//! every registered [`AlertSink`]:
+//!
+//! - [`LogAlertSink`]: a structured warning in the application log
+//! - [`WebhookAlertSink`]: a JSON `POST` to an HTTP endpoint
+//! - [`EmailAlertSink`]: a plain text email through a mail backend
+//!
+//! To avoid alert storms, only a fraction of the events is sent
+//! ([`SlowAlertConfig::sample_rate`]), and an event is dropped if another one
+//! with the same key (method and route of a slow request, or SQL shape of a
+//! slow query) was sent less than [`SlowAlertConfig::cooldown`] ago. The next
+//! event sent for the key reports how many were dropped.
+//!
+//! Queries are attributed to the request whose task executed them, so the
+//! query listener only sees the request context when the middleware is also
+//! installed.
+//!
+//! Requires the `slow-alerts` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::time::Duration;
+//! use reinhardt_middleware::slow_alerts::{
+//!     LogAlertSink, SlowAlertConfig, SlowAlerts, SlowRequestMiddleware,
+//! };
+//!
+//! let alerts = SlowAlerts::new(
+//!     SlowAlertConfig::new()
+//!         .with_request_threshold(Duration::from_millis(500))
+//!         .with_query_threshold(Duration::from_millis(100)),
+//! )
+//! .with_sink(LogAlertSink::new());
+//!
+//! // Times the SQL queries executed by the ORM
+//! alerts.install_query_listener();
+//! let middleware = SlowRequestMiddleware::new(alerts);
+//! ```
+
+use crate::request_id::REQUEST_ID_HEADER;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reinhardt_db::orm::instrumentation::{EventListener, instrumentation};
+use reinhardt_http::{Handler, Middleware, Request, Response, Result};
+use reinhardt_mail::EmailBackend;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Identifier of the query listener registered by [`SlowAlerts::install_query_listener`]
+pub const SLOW_QUERY_LISTENER_ID: &str = "reinhardt.slow_alerts";
+
+/// Cooldown entries kept before expired ones are pruned
+const MAX_TRACKED_KEYS: usize = 1024;
+
+tokio::task_local! {
+	static CURRENT_REQUEST: Arc<Mutex<RequestTimings>>;
+}
+
+/// Configuration for slow request and slow query alerts
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct SlowAlertConfig {
+	/// Alert on requests taking at least this long (default: 1s, `None` disables)
+	pub request_threshold: Option<Duration>,
+	/// Alert on queries taking at least this long (default: 250ms, `None` disables)
+	pub query_threshold: Option<Duration>,
+	/// Fraction of alerts to send, from 0.0 to 1.0 (default: 1.0)
+	pub sample_rate: f64,
+	/// Minimum time between two alerts with the same key (default: 60s)
+	pub cooldown: Duration,
+	/// Number of slowest queries included in the timing breakdown (default: 5)
+	pub max_queries: usize,
+}
+
+impl SlowAlertConfig {
+	/// Create a new default configuration
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use std::time::Duration;
+	/// use reinhardt_middleware::slow_alerts::SlowAlertConfig;
+	///
+	/// let config = SlowAlertConfig::new();
+	/// assert_eq!(config.request_threshold, Some(Duration::from_secs(1)));
+	/// assert_eq!(config.query_threshold, Some(Duration::from_millis(250)));
+	/// ```
+	pub fn new() -> Self {
+		Self {
+			request_threshold: Some(Duration::from_secs(1)),
+			query_threshold: Some(Duration::from_millis(250)),
+			sample_rate: 1.0,
+			cooldown: Duration::from_secs(60),
+			max_queries: 5,
+		}
+	}
+
+	/// Alert on requests taking at least `threshold`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use std::time::Duration;
+	/// use reinhardt_middleware::slow_alerts::SlowAlertConfig;
+	///
+	/// let config = SlowAlertConfig::new().with_request_threshold(Duration::from_millis(300));
+	/// assert_eq!(config.request_threshold, Some(Duration::from_millis(300)));
+	/// ```
+	pub fn with_request_threshold(mut self, threshold: Duration) -> Self {
+		self.request_threshold = Some(threshold);
+		self
+	}
+
+	/// Alert on queries taking at least `threshold`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use std::time::Duration;
+	/// use reinhardt_middleware::slow_alerts::SlowAlertConfig;
+	///
+	/// let config = SlowAlertConfig::new().with_query_threshold(Duration::from_millis(50));
+	/// assert_eq!(config.query_threshold, Some(Duration::from_millis(50)));
+	/// ```
+	pub fn with_query_threshold(mut self, threshold: Duration) -> Self {
+		self.query_threshold = Some(threshold);
+		self
+	}
+
+	/// Never alert on slow requests
+	///
+	/// Queries are still attributed to their request.
+	pub fn without_request_alerts(mut self) -> Self {
+		self.request_threshold = None;
+		self
+	}
+
+	/// Never alert on slow queries
+	///
+	/// Queries still show up in the timing breakdown of slow requests.
+	pub fn without_query_alerts(mut self) -> Self {
+		self.query_threshold = None;
+		self
+	}
+
+	/// Set the fraction of alerts to send
+	///
+	/// Values are clamped to `0.0..=1.0`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::slow_alerts::SlowAlertConfig;
+	///
+	/// let config = SlowAlertConfig::new().with_sample_rate(0.25);
+	/// assert_eq!(config.sample_rate, 0.25);
+	/// ```
+	pub fn with_sample_rate(mut self, sample_rate: f64) -> Self {
+		self.sample_rate = sample_rate.clamp(0.0, 1.0);
+		self
+	}
+
+	/// Set the minimum time between two alerts with the same key
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use std::time::Duration;
+	/// use reinhardt_middleware::slow_alerts::SlowAlertConfig;
+	///
+	/// let config = SlowAlertConfig::new().with_cooldown(Duration::from_secs(300));
+	/// assert_eq!(config.cooldown, Duration::from_secs(300));
+	/// ```
+	pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+		self.cooldown = cooldown;
+		self
+	}
+
+	/// Set the number of slowest queries included in the timing breakdown
+	pub fn with_max_queries(mut self, max_queries: usize) -> Self {
+		self.max_queries = max_queries;
+		self
+	}
+}
+
+impl Default for SlowAlertConfig {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// What exceeded its threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertKind {
+	/// A whole request
+	SlowRequest,
+	/// A single SQL query
+	SlowQuery,
+}
+
+/// Execution time of one SQL query
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct QueryTiming {
+	/// The SQL that was executed
+	pub sql: String,
+	/// Execution time in milliseconds
+	pub duration_ms: f64,
+}
+
+/// Where the time of a request went
+///
+/// For a slow query alert, the breakdown covers the request up to the end of
+/// the slow query.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TimingBreakdown {
+	/// Time since the request was received, in milliseconds
+	pub total_ms: f64,
+	/// Time spent executing SQL queries, in milliseconds
+	pub db_ms: f64,
+	/// Time spent outside of SQL queries, in milliseconds
+	pub app_ms: f64,
+	/// Number of SQL queries executed
+	pub query_count: usize,
+	/// Slowest queries, slowest first
+	pub slowest_queries: Vec<QueryTiming>,
+}
+
+/// A structured slow request or slow query alert
+#[derive(Debug, Clone, Serialize)]
+pub struct AlertEvent {
+	/// What exceeded its threshold
+	pub kind: AlertKind,
+	/// When the alert was raised
+	pub occurred_at: DateTime<Utc>,
+	/// `X-Request-ID` of the request, if any
+	pub request_id: Option<String>,
+	/// HTTP method of the request
+	pub method: Option<String>,
+	/// Path of the request
+	pub route: Option<String>,
+	/// The slow SQL query, for [`AlertKind::SlowQuery`]
+	pub sql: Option<String>,
+	/// Duration of the request or query, in milliseconds
+	pub duration_ms: f64,
+	/// The exceeded threshold, in milliseconds
+	pub threshold_ms: f64,
+	/// Timing breakdown of the request
+	pub timing: TimingBreakdown,
+	/// Alerts with the same key dropped by sampling since the previous one
+	pub suppressed: u64,
+}
+
+impl AlertEvent {
+	/// One-line human readable description, used as log message and email subject
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use chrono::Utc;
+	/// use reinhardt_middleware::slow_alerts::{AlertEvent, AlertKind, TimingBreakdown};
+	///
+	/// let event = AlertEvent {
+	///     kind: AlertKind::SlowRequest,
+	///     occurred_at: Utc::now(),
+	///     request_id: Some("req-1".to_string()),
+	///     method: Some("GET".to_string()),
+	///     route: Some("/reports".to_string()),
+	///     sql: None,
+	///     duration_ms: 1520.0,
+	///     threshold_ms: 1000.0,
+	///     timing: TimingBreakdown::default(),
+	///     suppressed: 0,
+	/// };
+	/// assert_eq!(event.summary(), "Slow request GET /reports: 1520ms (threshold 1000ms)");
+	/// ```
+	pub fn summary(&self) -> String {
+		let subject = match self.kind {
+			AlertKind::SlowRequest => format!(
+				"Slow request {} {}",
+				self.method.as_deref().unwrap_or("-"),
+				self.route.as_deref().unwrap_or("-")
+			),
+			AlertKind::SlowQuery => format!(
+				"Slow query on {}",
+				self.route.as_deref().unwrap_or("<no request>")
+			),
+		};
+		format!(
+			"{}: {:.0}ms (threshold {:.0}ms)",
+			subject, self.duration_ms, self.threshold_ms
+		)
+	}
+
+	/// Key under which alerts are rate limited
+	fn key(&self) -> String {
+		match self.kind {
+			AlertKind::SlowRequest => format!(
+				"request:{} {}",
+				self.method.as_deref().unwrap_or_default(),
+				self.route.as_deref().unwrap_or_default()
+			),
+			AlertKind::SlowQuery => {
+				format!(
+					"query:{}",
+					sql_fingerprint(self.sql.as_deref().unwrap_or_default())
+				)
+			}
+		}
+	}
+}
+
+/// Error returned by an [`AlertSink`]
+#[derive(Debug, thiserror::Error)]
+pub enum AlertSinkError {
+	/// The webhook could not be called or did not accept the alert
+	#[error("webhook delivery failed: {0}")]
+	Webhook(String),
+	/// The alert email could not be sent
+	#[error("alert email could not be sent: {0}")]
+	Email(String),
+	/// The event could not be serialized
+	#[error("alert serialization failed: {0}")]
+	Serialization(#[from] serde_json::Error),
+}
+
+/// Destination of alert events
+#[async_trait]
+pub trait AlertSink: Send + Sync {
+	/// Deliver one event
+	async fn send(&self, event: &AlertEvent) -> std::result::Result<(), AlertSinkError>;
+}
+
+/// Writes alerts to the application log as warnings
+#[derive(Debug, Clone, Default)]
+pub struct LogAlertSink;
+
+impl LogAlertSink {
+	/// Create a log sink
+	pub fn new() -> Self {
+		Self
+	}
+}
+
+#[async_trait]
+impl AlertSink for LogAlertSink {
+	async fn send(&self, event: &AlertEvent) -> std::result::Result<(), AlertSinkError> {
+		let details = serde_json::to_string(event)?;
+		log::warn!(target: "reinhardt::slow_alerts", "{} {}", event.summary(), details);
+		Ok(())
+	}
+}
+
+/// Posts alerts as JSON to an HTTP endpoint
+#[derive(Debug, Clone)]
+pub struct WebhookAlertSink {
+	url: String,
+	client: reqwest::Client,
+	headers: Vec<(String, String)>,
+}
+
+impl WebhookAlertSink {
+	/// Create a sink posting to `url`
+	pub fn new(url: impl Into<String>) -> Self {
+		let client = reqwest::Client::builder()
+			.timeout(Duration::from_secs(10))
+			.build()
+			.unwrap_or_default();
+		Self {
+			url: url.into(),
+			client,
+			headers: Vec::new(),
+		}
+	}
+
+	/// Add a header to every webhook request, e.g. an authorization token
+	pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+		self.headers.push((name.into(), value.into()));
+		self
+	}
+}
+
+#[async_trait]
+impl AlertSink for WebhookAlertSink {
+	async fn send(&self, event: &AlertEvent) -> std::result::Result<(), AlertSinkError> {
+		let mut request = self.client.post(&self.url).json(event);
+		for (name, value) in &self.headers {
+			request = request.header(name, value);
+		}
+		let response = request
+			.send()
+			.await
+			.map_err(|e| AlertSinkError::Webhook(e.to_string()))?;
+		if !response.status().is_success() {
+			return Err(AlertSinkError::Webhook(format!(
+				"{} responded with {}",
+				self.url,
+				response.status()
+			)));
+		}
+		Ok(())
+	}
+}
+
+/// Emails alerts through a mail backend
+pub struct EmailAlertSink {
+	backend: Arc<dyn EmailBackend>,
+	from_email: String,
+	recipients: Vec<String>,
+}
+
+impl EmailAlertSink {
+	/// Create a sink emailing `recipients` from `from_email`
+	pub fn new(
+		backend: Arc<dyn EmailBackend>,
+		from_email: impl Into<String>,
+		recipients: Vec<String>,
+	) -> Self {
+		Self {
+			backend,
+			from_email: from_email.into(),
+			recipients,
+		}
+	}
+}
+
+impl std::fmt::Debug for EmailAlertSink {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("EmailAlertSink")
+			.field("from_email", &self.from_email)
+			.field("recipients", &self.recipients)
+			.finish()
+	}
+}
+
+#[async_trait]
+impl AlertSink for EmailAlertSink {
+	async fn send(&self, event: &AlertEvent) -> std::result::Result<(), AlertSinkError> {
+		if self.recipients.is_empty() {
+			return Ok(());
+		}
+		let body = serde_json::to_string_pretty(event)?;
+		reinhardt_mail::send_mail_with_backend(
+			format!("[Alert] {}", event.summary()),
+			body,
+			self.from_email.clone(),
+			self.recipients.clone(),
+			None,
+			self.backend.as_ref(),
+		)
+		.await
+		.map_err(|e| AlertSinkError::Email(e.to_string()))
+	}
+}
+
+/// Decides which alerts are sent
+///
+/// An alert is sent if it is sampled in and no alert with the same key was
+/// sent during the cooldown. Dropped alerts are counted per key.
+#[derive(Debug)]
+pub struct AlertSampler {
+	sample_rate: f64,
+	cooldown: Duration,
+	keys: Mutex<HashMap<String, KeyState>>,
+}
+
+#[derive(Debug)]
+struct KeyState {
+	last_sent: Option<Instant>,
+	suppressed: u64,
+}
+
+impl AlertSampler {
+	/// Create a sampler sending `sample_rate` of the alerts, at most one per key per `cooldown`
+	pub fn new(sample_rate: f64, cooldown: Duration) -> Self {
+		Self {
+			sample_rate: sample_rate.clamp(0.0, 1.0),
+			cooldown,
+			keys: Mutex::new(HashMap::new()),
+		}
+	}
+
+	/// Whether an alert for `key` may be sent now
+	///
+	/// Returns the number of alerts dropped for `key` since the previous one
+	/// was sent, or `None` if this one must be dropped too.
+	pub fn admit(&self, key: &str) -> Option<u64> {
+		let now = Instant::now();
+		let mut keys = self.keys.lock().unwrap_or_else(|e| e.into_inner());
+		if keys.len() >= MAX_TRACKED_KEYS {
+			keys.retain(|_, state| {
+				state
+					.last_sent
+					.is_some_and(|sent| now.duration_since(sent) < self.cooldown)
+			});
+		}
+		let state = keys.entry(key.to_string()).or_insert(KeyState {
+			last_sent: None,
+			suppressed: 0,
+		});
+		let cooling_down = state
+			.last_sent
+			.is_some_and(|sent| now.duration_since(sent) < self.cooldown);
+		let sampled_out = self.sample_rate < 1.0 && rand::random::<f64>() >= self.sample_rate;
+		if cooling_down || sampled_out {
+			state.suppressed += 1;
+			return None;
+		}
+		state.last_sent = Some(now);
+		Some(std::mem::take(&mut state.suppressed))
+	}
+}
+
+/// Normalize `sql` so that queries differing only in literal values share a key
+fn sql_fingerprint(sql: &str) -> String {
+	let mut fingerprint = String::with_capacity(sql.len());
+	let mut chars = sql.chars().peekable();
+	let mut previous = ' ';
+	while let Some(c) = chars.next() {
+		if c == '\'' {
+			// Skip the string literal, including doubled quotes
+			while let Some(next) = chars.next() {
+				if next == '\'' {
+					if chars.peek() == Some(&'\'') {
+						chars.next();
+					} else {
+						break;
+					}
+				}
+			}
+			fingerprint.push('?');
+			previous = '?';
+		} else if c.is_ascii_digit() && !(previous.is_alphanumeric() || previous == '_') {
+			while chars
+				.peek()
+				.is_some_and(|next| next.is_ascii_digit() || *next == '.')
+			{
+				chars.next();
+			}
+			fingerprint.push('?');
+			previous = '?';
+		} else if c.is_whitespace() {
+			if previous != ' ' {
+				fingerprint.push(' ');
+			}
+			previous = ' ';
+		} else {
+			fingerprint.push(c);
+			previous = c;
+		}
+	}
+	fingerprint.trim_end().to_string()
+}
+
+/// Timings of the request being handled by the current task
+#[derive(Debug)]
+struct RequestTimings {
+	request_id: Option<String>,
+	method: String,
+	route: String,
+	started: Instant,
+	db_time: Duration,
+	query_count: usize,
+	slowest_queries: Vec<(String, Duration)>,
+	max_queries: usize,
+}
+
+impl RequestTimings {
+	fn new(request: &Request, max_queries: usize) -> Self {
+		Self {
+			request_id: request
+				.headers
+				.get(REQUEST_ID_HEADER)
+				.and_then(|v| v.to_str().ok())
+				.map(str::to_string),
+			method: request.method.as_str().to_string(),
+			route: request.uri.path().to_string(),
+			started: Instant::now(),
+			db_time: Duration::ZERO,
+			query_count: 0,
+			slowest_queries: Vec::new(),
+			max_queries,
+		}
+	}
+
+	fn record_query(&mut self, sql: &str, duration: Duration) {
+		self.db_time += duration;
+		self.query_count += 1;
+		if self.max_queries == 0 {
+			return;
+		}
+		if self.slowest_queries.len() == self.max_queries
+			&& self
+				.slowest_queries
+				.last()
+				.is_some_and(|(_, slowest)| *slowest >= duration)
+		{
+			return;
+		}
+		let position = self
+			.slowest_queries
+			.partition_point(|(_, slower)| *slower >= duration);
+		self.slowest_queries
+			.insert(position, (sql.to_string(), duration));
+		self.slowest_queries.truncate(self.max_queries);
+	}
+
+	fn breakdown(&self) -> TimingBreakdown {
+		let total = self.started.elapsed();
+		TimingBreakdown {
+			total_ms: as_millis(total),
+			db_ms: as_millis(self.db_time),
+			app_ms: as_millis(total.saturating_sub(self.db_time)),
+			query_count: self.query_count,
+			slowest_queries: self
+				.slowest_queries
+				.iter()
+				.map(|(sql, duration)| QueryTiming {
+					sql: sql.clone(),
+					duration_ms: as_millis(*duration),
+				})
+				.collect(),
+		}
+	}
+
+	fn event(
+		&self,
+		kind: AlertKind,
+		sql: Option<&str>,
+		duration: Duration,
+		threshold: Duration,
+	) -> AlertEvent {
+		AlertEvent {
+			kind,
+			occurred_at: Utc::now(),
+			request_id: self.request_id.clone(),
+			method: Some(self.method.clone()),
+			route: Some(self.route.clone()),
+			sql: sql.map(str::to_string),
+			duration_ms: as_millis(duration),
+			threshold_ms: as_millis(threshold),
+			timing: self.breakdown(),
+			suppressed: 0,
+		}
+	}
+}
+
+fn as_millis(duration: Duration) -> f64 {
+	duration.as_nanos() as f64 / 1_000_000.0
+}
+
+#[derive(Clone)]
+struct SlowAlertsInner {
+	config: SlowAlertConfig,
+	sampler: Arc<AlertSampler>,
+	sinks: Vec<Arc<dyn AlertSink>>,
+}
+
+/// Shared alerting state of [`SlowRequestMiddleware`] and [`SlowQueryListener`]
+///
+/// Cloning is cheap; clones share the sampler, so the cooldown applies
+/// across the middleware and the query listener.
+#[derive(Clone)]
+pub struct SlowAlerts {
+	inner: Arc<SlowAlertsInner>,
+}
+
+impl SlowAlerts {
+	/// Create an alerting layer without sinks
+	pub fn new(config: SlowAlertConfig) -> Self {
+		let sampler = Arc::new(AlertSampler::new(config.sample_rate, config.cooldown));
+		Self {
+			inner: Arc::new(SlowAlertsInner {
+				config,
+				sampler,
+				sinks: Vec::new(),
+			}),
+		}
+	}
+
+	/// Send alerts to `sink` as well
+	pub fn with_sink(self, sink: impl AlertSink + 'static) -> Self {
+		self.with_shared_sink(Arc::new(sink))
+	}
+
+	/// Send alerts to an already shared `sink` as well
+	pub fn with_shared_sink(mut self, sink: Arc<dyn AlertSink>) -> Self {
+		Arc::make_mut(&mut self.inner).sinks.push(sink);
+		self
+	}
+
+	/// The alerting configuration
+	pub fn config(&self) -> &SlowAlertConfig {
+		&self.inner.config
+	}
+
+	/// A listener raising slow query alerts, to register with the ORM instrumentation
+	pub fn query_listener(&self) -> SlowQueryListener {
+		SlowQueryListener {
+			alerts: self.clone(),
+		}
+	}
+
+	/// Register [`Self::query_listener`] with the global ORM instrumentation
+	///
+	/// Replaces a listener installed earlier.
+	pub fn install_query_listener(&self) {
+		instrumentation().add_listener(
+			SLOW_QUERY_LISTENER_ID.to_string(),
+			Arc::new(self.query_listener()),
+		);
+	}
+
+	/// Send `event` to every sink unless the sampler drops it
+	///
+	/// Sinks are called on a separate task so alerting never delays the
+	/// request. Returns the handle of that task, or `None` if the event was
+	/// dropped.
+	pub fn dispatch(&self, mut event: AlertEvent) -> Option<tokio::task::JoinHandle<()>> {
+		if self.inner.sinks.is_empty() {
+			return None;
+		}
+		event.suppressed = self.inner.sampler.admit(&event.key())?;
+		let sinks = self.inner.sinks.clone();
+		Some(tokio::spawn(async move {
+			for sink in sinks {
+				if let Err(e) = sink.send(&event).await {
+					log::error!(target: "reinhardt::slow_alerts", "Failed to send alert: {}", e);
+				}
+			}
+		}))
+	}
+}
+
+impl std::fmt::Debug for SlowAlerts {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("SlowAlerts")
+			.field("config", &self.inner.config)
+			.field("sinks", &self.inner.sinks.len())
+			.finish()
+	}
+}
+
+/// Middleware raising alerts for slow requests
+///
+/// It also scopes the timings of the SQL queries executed while the request
+/// is handled, so that slow query alerts carry the request context.
+#[derive(Debug, Clone)]
+pub struct SlowRequestMiddleware {
+	alerts: SlowAlerts,
+}
+
+impl SlowRequestMiddleware {
+	/// Create the middleware
+	pub fn new(alerts: SlowAlerts) -> Self {
+		Self { alerts }
+	}
+}
+
+#[async_trait]
+impl Middleware for SlowRequestMiddleware {
+	async fn process(&self, request: Request, next: Arc<dyn Handler>) -> Result<Response> {
+		let timings = Arc::new(Mutex::new(RequestTimings::new(
+			&request,
+			self.alerts.config().max_queries,
+		)));
+		let result = CURRENT_REQUEST
+			.scope(timings.clone(), next.handle(request))
+			.await;
+
+		if let Some(threshold) = self.alerts.config().request_threshold {
+			let timings = timings.lock().unwrap_or_else(|e| e.into_inner());
+			let elapsed = timings.started.elapsed();
+			if elapsed >= threshold {
+				let event = timings.event(AlertKind::SlowRequest, None, elapsed, threshold);
+				drop(timings);
+				self.alerts.dispatch(event);
+			}
+		}
+		result
+	}
+}
+
+/// ORM event listener raising alerts for slow queries
+///
+/// Created by [`SlowAlerts::query_listener`].
+#[derive(Debug, Clone)]
+pub struct SlowQueryListener {
+	alerts: SlowAlerts,
+}
+
+#[async_trait]
+impl EventListener for SlowQueryListener {
+	async fn on_query_end(&self, query: &str, duration: Duration) {
+		let timings = CURRENT_REQUEST.try_with(Arc::clone).ok();
+		if let Some(timings) = &timings {
+			timings
+				.lock()
+				.unwrap_or_else(|e| e.into_inner())
+				.record_query(query, duration);
+		}
+
+		let Some(threshold) = self.alerts.config().query_threshold else {
+			return;
+		};
+		if duration < threshold {
+			return;
+		}
+		let event = match timings {
+			Some(timings) => timings.lock().unwrap_or_else(|e| e.into_inner()).event(
+				AlertKind::SlowQuery,
+				Some(query),
+				duration,
+				threshold,
+			),
+			None => AlertEvent {
+				kind: AlertKind::SlowQuery,
+				occurred_at: Utc::now(),
+				request_id: None,
+				method: None,
+				route: None,
+				sql: Some(query.to_string()),
+				duration_ms: as_millis(duration),
+				threshold_ms: as_millis(threshold),
+				timing: TimingBreakdown::default(),
+				suppressed: 0,
+			},
+		};
+		self.alerts.dispatch(event);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bytes::Bytes;
+	use hyper::{HeaderMap, Method, Version};
+	use rstest::rstest;
+	use tokio::sync::mpsc;
+
+	struct RecordingSink {
+		events: mpsc::UnboundedSender<AlertEvent>,
+	}
+
+	#[async_trait]
+	impl AlertSink for RecordingSink {
+		async fn send(&self, event: &AlertEvent) -> std::result::Result<(), AlertSinkError> {
+			let _ = self.events.send(event.clone());
+			Ok(())
+		}
+	}
+
+	/// Handler running two queries through the listener, then sleeping
+	struct QueryingHandler {
+		listener: SlowQueryListener,
+		sleep: Duration,
+	}
+
+	#[async_trait]
+	impl Handler for QueryingHandler {
+		async fn handle(&self, _request: Request) -> Result<Response> {
+			self.listener
+				.on_query_end(
+					"SELECT * FROM orders WHERE id = 1",
+					Duration::from_millis(5),
+				)
+				.await;
+			self.listener
+				.on_query_end(
+					"SELECT * FROM reports WHERE year = 2024",
+					Duration::from_millis(80),
+				)
+				.await;
+			tokio::time::sleep(self.sleep).await;
+			Ok(Response::ok())
+		}
+	}
+
+	fn alerts(config: SlowAlertConfig) -> (SlowAlerts, mpsc::UnboundedReceiver<AlertEvent>) {
+		let (tx, rx) = mpsc::unbounded_channel();
+		let alerts = SlowAlerts::new(config).with_sink(RecordingSink { events: tx });
+		(alerts, rx)
+	}
+
+	fn request() -> Request {
+		let mut headers = HeaderMap::new();
+		headers.insert(REQUEST_ID_HEADER, "req-1".parse().unwrap());
+		Request::builder()
+			.method(Method::GET)
+			.uri("/reports?year=2024")
+			.version(Version::HTTP_11)
+			.headers(headers)
+			.body(Bytes::new())
+			.build()
+			.unwrap()
+	}
+
+	async fn next_event(rx: &mut mpsc::UnboundedReceiver<AlertEvent>) -> AlertEvent {
+		tokio::time::timeout(Duration::from_secs(1), rx.recv())
+			.await
+			.unwrap()
+			.unwrap()
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_slow_request_alert_carries_timing_breakdown() {
+		// Arrange
+		let (alerts, mut rx) = alerts(
+			SlowAlertConfig::new()
+				.with_request_threshold(Duration::from_millis(20))
+				.without_query_alerts(),
+		);
+		let middleware = SlowRequestMiddleware::new(alerts.clone());
+		let handler = Arc::new(QueryingHandler {
+			listener: alerts.query_listener(),
+			sleep: Duration::from_millis(30),
+		});
+
+		// Act
+		let response = middleware.process(request(), handler).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status.as_u16(), 200);
+		let event = next_event(&mut rx).await;
+		assert_eq!(event.kind, AlertKind::SlowRequest);
+		assert_eq!(event.request_id.as_deref(), Some("req-1"));
+		assert_eq!(event.route.as_deref(), Some("/reports"));
+		assert!(event.duration_ms >= 30.0);
+		assert_eq!(event.timing.query_count, 2);
+		assert_eq!(event.timing.db_ms, 85.0);
+		assert_eq!(
+			event.timing.slowest_queries[0].sql,
+			"SELECT * FROM reports WHERE year = 2024"
+		);
+		assert!(rx.try_recv().is_err());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_slow_query_alert_within_request() {
+		// Arrange
+		let (alerts, mut rx) = alerts(
+			SlowAlertConfig::new()
+				.without_request_alerts()
+				.with_query_threshold(Duration::from_millis(50)),
+		);
+		let middleware = SlowRequestMiddleware::new(alerts.clone());
+		let handler = Arc::new(QueryingHandler {
+			listener: alerts.query_listener(),
+			sleep: Duration::ZERO,
+		});
+
+		// Act
+		middleware.process(request(), handler).await.unwrap();
+
+		// Assert
+		let event = next_event(&mut rx).await;
+		assert_eq!(event.kind, AlertKind::SlowQuery);
+		assert_eq!(
+			event.sql.as_deref(),
+			Some("SELECT * FROM reports WHERE year = 2024")
+		);
+		assert_eq!(event.request_id.as_deref(), Some("req-1"));
+		assert_eq!(event.duration_ms, 80.0);
+		assert_eq!(event.timing.query_count, 2);
+		assert!(rx.try_recv().is_err());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_fast_request_raises_no_alert() {
+		// Arrange
+		let (alerts, mut rx) = alerts(SlowAlertConfig::new());
+		let middleware = SlowRequestMiddleware::new(alerts.clone());
+		let handler = Arc::new(QueryingHandler {
+			listener: alerts.query_listener(),
+			sleep: Duration::ZERO,
+		});
+
+		// Act
+		middleware.process(request(), handler).await.unwrap();
+
+		// Assert
+		tokio::task::yield_now().await;
+		assert!(rx.try_recv().is_err());
+	}
+
+	#[rstest]
+	fn test_sampler_cooldown_counts_suppressed_alerts() {
+		// Arrange
+		let sampler = AlertSampler::new(1.0, Duration::from_millis(50));
+
+		// Act
+		let first = sampler.admit("request:GET /reports");
+		let second = sampler.admit("request:GET /reports");
+		let third = sampler.admit("request:GET /reports");
+		let other = sampler.admit("request:GET /orders");
+		std::thread::sleep(Duration::from_millis(60));
+		let after_cooldown = sampler.admit("request:GET /reports");
+
+		// Assert
+		assert_eq!(first, Some(0));
+		assert_eq!(second, None);
+		assert_eq!(third, None);
+		assert_eq!(other, Some(0));
+		assert_eq!(after_cooldown, Some(2));
+	}
+
+	#[rstest]
+	fn test_sampler_zero_rate_drops_everything() {
+		// Arrange
+		let sampler = AlertSampler::new(0.0, Duration::ZERO);
+
+		// Act
+		let admitted = (0..10).filter_map(|_| sampler.admit("query:x")).count();
+
+		// Assert
+		assert_eq!(admitted, 0);
+	}
+
+	#[rstest]
+	#[case(
+		"SELECT * FROM users WHERE id = 42",
+		"SELECT * FROM users WHERE id = ?"
+	)]
+	#[case(
+		"SELECT  *\n FROM users WHERE name = 'O''Brien' AND age > 3.5",
+		"SELECT * FROM users WHERE name = ? AND age > ?"
+	)]
+	#[case("SELECT col1 FROM t2", "SELECT col1 FROM t2")]
+	fn test_sql_fingerprint(#[case] sql: &str, #[case] expected: &str) {
+		// Act
+		let fingerprint = sql_fingerprint(sql);
+
+		// Assert
+		assert_eq!(fingerprint, expected);
+	}
+}
@@ -0,0 +1,193 @@
+//! Per-request time zone activation
+//!
+//! Activates the time zone chosen by the user for the duration of the
+//! request, so that [`get_current_timezone`] and [`to_current_timezone`]
+//! render datetimes in that zone. The time zone name (e.g. `Asia/Tokyo`) is
+//! read from a cookie, then from the `X-Timezone` header. Requests without a
+//! valid name use the default time zone (`TIME_ZONE` setting).
+//!
+//! [`get_current_timezone`]: reinhardt_utils::utils_core::timezone::get_current_timezone
+//! [`to_current_timezone`]: reinhardt_utils::utils_core::timezone::to_current_timezone
+
+use async_trait::async_trait;
+use hyper::header::COOKIE;
+use reinhardt_http::{Handler, Middleware, Request, Response, Result};
+use reinhardt_utils::utils_core::timezone::{
+	activate_timezone, get_default_timezone, parse_timezone,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Header carrying the client's IANA time zone name
+pub const TIMEZONE_HEADER: &str = "X-Timezone";
+/// Cookie name for the time zone preference
+pub const TIMEZONE_COOKIE_NAME: &str = "django_timezone";
+
+/// Time zone middleware configuration
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimezoneConfig {
+	/// Cookie name for storing the time zone preference
+	pub cookie_name: String,
+	/// Read the time zone from the `X-Timezone` header when no cookie is set
+	pub check_header: bool,
+}
+
+impl TimezoneConfig {
+	/// Create a new TimezoneConfig with default settings
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_middleware::timezone::TimezoneConfig;
+	///
+	/// let config = TimezoneConfig::new();
+	/// assert_eq!(config.cookie_name, "django_timezone");
+	/// assert!(config.check_header);
+	/// ```
+	pub fn new() -> Self {
+		Self {
+			cookie_name: TIMEZONE_COOKIE_NAME.to_string(),
+			check_header: true,
+		}
+	}
+}
+
+impl Default for TimezoneConfig {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Time zone activation middleware
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_middleware::timezone::{TimezoneConfig, TimezoneMiddleware};
+///
+/// let middleware = TimezoneMiddleware::with_config(TimezoneConfig::new());
+/// ```
+pub struct TimezoneMiddleware {
+	config: TimezoneConfig,
+}
+
+impl TimezoneMiddleware {
+	/// Create a new TimezoneMiddleware with default configuration
+	pub fn new() -> Self {
+		Self::with_config(TimezoneConfig::default())
+	}
+
+	/// Create a new TimezoneMiddleware with custom configuration
+	pub fn with_config(config: TimezoneConfig) -> Self {
+		Self { config }
+	}
+
+	/// Time zone name from the configured cookie
+	fn timezone_from_cookie(&self, request: &Request) -> Option<String> {
+		let cookie_header = request.headers.get(COOKIE)?.to_str().ok()?;
+		cookie_header.split(';').find_map(|cookie| {
+			let (name, value) = cookie.trim().split_once('=')?;
+			(name == self.config.cookie_name).then(|| value.replace("%2F", "/"))
+		})
+	}
+
+	/// Time zone name from the `X-Timezone` header
+	fn timezone_from_header(&self, request: &Request) -> Option<String> {
+		if !self.config.check_header {
+			return None;
+		}
+		request
+			.headers
+			.get(TIMEZONE_HEADER)?
+			.to_str()
+			.ok()
+			.map(|value| value.trim().to_string())
+	}
+}
+
+impl Default for TimezoneMiddleware {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[async_trait]
+impl Middleware for TimezoneMiddleware {
+	async fn process(&self, request: Request, handler: Arc<dyn Handler>) -> Result<Response> {
+		let tz = [
+			self.timezone_from_cookie(&request),
+			self.timezone_from_header(&request),
+		]
+		.into_iter()
+		.flatten()
+		.find_map(|name| parse_timezone(&name).ok())
+		.unwrap_or_else(get_default_timezone);
+
+		activate_timezone(tz, handler.handle(request)).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bytes::Bytes;
+	use hyper::{HeaderMap, Method, StatusCode, Version};
+	use reinhardt_utils::utils_core::timezone::get_current_timezone;
+	use rstest::rstest;
+
+	struct TimezoneEchoHandler;
+
+	#[async_trait]
+	impl Handler for TimezoneEchoHandler {
+		async fn handle(&self, _request: Request) -> Result<Response> {
+			let name = get_current_timezone().name();
+			Ok(Response::new(StatusCode::OK).with_body(Bytes::from(name)))
+		}
+	}
+
+	async fn current_timezone_for(headers: HeaderMap) -> String {
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/page")
+			.version(Version::HTTP_11)
+			.headers(headers)
+			.body(Bytes::new())
+			.build()
+			.unwrap();
+		let response = TimezoneMiddleware::new()
+			.process(request, Arc::new(TimezoneEchoHandler))
+			.await
+			.unwrap();
+		String::from_utf8(response.body.to_vec()).unwrap()
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_cookie_takes_precedence_over_header() {
+		// Arrange
+		let mut headers = HeaderMap::new();
+		headers.insert(COOKIE, "django_timezone=Asia%2FTokyo".parse().unwrap());
+		headers.insert(TIMEZONE_HEADER, "Europe/Paris".parse().unwrap());
+
+		// Act
+		let name = current_timezone_for(headers).await;
+
+		// Assert
+		assert_eq!(name, "Asia/Tokyo");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_invalid_timezone_falls_back_to_default() {
+		// Arrange
+		let mut headers = HeaderMap::new();
+		headers.insert(TIMEZONE_HEADER, "Mars/Olympus".parse().unwrap());
+
+		// Act
+		let name = current_timezone_for(headers).await;
+
+		// Assert
+		assert_eq!(name, get_default_timezone().name());
+	}
+}
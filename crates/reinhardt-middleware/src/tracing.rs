@@ -6,6 +6,7 @@
 use async_trait::async_trait;
 use hyper::header::HeaderName;
 use reinhardt_http::{Handler, Middleware, Request, Response, Result};
+use reinhardt_utils::http_client::TraceContext;
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 use std::time::Instant;
@@ -471,8 +472,10 @@ impl Middleware for TracingMiddleware {
 		self.store
 			.add_span_tag(&span_id, "http.path".to_string(), path.to_string());
 
-		// Call handler
-		let result = handler.handle(request).await;
+		// Call handler, exposing the trace to outbound HTTP calls it makes
+		let result = TraceContext::new(trace_id.clone(), span_id.clone())
+			.scope(handler.handle(request))
+			.await;
 
 		// End span
 		match &result {
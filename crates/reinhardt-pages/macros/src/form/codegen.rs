@@ -405,6 +405,10 @@ fn generate_form_runtime_contract(
 		.iter()
 		.map(|field| field_variant_ident(&field.name))
 		.collect();
+	let field_names: Vec<String> = all_fields
+		.iter()
+		.map(|field| field.name.to_string())
+		.collect();
 	let field_accessor_methods: Vec<TokenStream> = all_fields
 		.iter()
 		.zip(field_variants.iter())
@@ -1715,6 +1719,13 @@ fn generate_form_runtime_contract(
 				&[#(#field_ident::#field_variants),*]
 			}
 
+			fn runtime_field_by_name(&self, name: &str) -> ::core::option::Option<Self::Field> {
+				match name {
+					#(#field_names => ::core::option::Option::Some(#field_ident::#field_variants),)*
+					_ => ::core::option::Option::None,
+				}
+			}
+
 			fn runtime_set_focus(&self, field: Self::Field) -> ::core::result::Result<(), #pages_crate::FocusError> {
 				#[cfg(all(target_family = "wasm", target_os = "unknown"))]
 				{
@@ -3064,11 +3075,10 @@ fn generate_metadata_function(
 		FormMethod::Delete => "DELETE",
 	};
 
-	let form_class = macro_ast
-		.styling
-		.class
-		.as_deref()
-		.unwrap_or("reinhardt-form");
+	let form_class = match macro_ast.styling.class.as_deref() {
+		Some(class) => quote! { #class },
+		None => quote! { #pages_crate::form_renderer::form_renderer().form_class() },
+	};
 
 	// Collect all fields (including those in groups) for metadata
 	let all_fields = collect_scalar_fields(&macro_ast.fields);
@@ -3082,10 +3092,10 @@ fn generate_metadata_function(
 			// Use name variable instead of creating new temporary to avoid E0716
 			let label = field.display.label.as_deref().unwrap_or(&name);
 			let placeholder = field.display.placeholder.as_deref().unwrap_or("");
-			let input_class = field.styling.input_class();
-			let wrapper_class = field.styling.wrapper_class();
-			let label_class = field.styling.label_class();
-			let error_class = field.styling.error_class();
+			let input_class = field_class(field, FieldPart::Input, pages_crate);
+			let wrapper_class = field_class(field, FieldPart::Wrapper, pages_crate);
+			let label_class = field_class(field, FieldPart::Label, pages_crate);
+			let error_class = field_class(field, FieldPart::Error, pages_crate);
 
 			quote! {
 				#pages_crate::form_generated::StaticFieldMetadata {
@@ -3104,6 +3114,20 @@ fn generate_metadata_function(
 		})
 		.collect();
 
+	// Fold the `css` / `js` assets of every field into one deduplicated Media
+	let media_assets: Vec<TokenStream> = all_fields
+		.iter()
+		.flat_map(|field| {
+			let css = field
+				.media
+				.css
+				.iter()
+				.map(|href| quote! { .with_css(#href) });
+			let js = field.media.js.iter().map(|src| quote! { .with_js(#src) });
+			css.chain(js)
+		})
+		.collect();
+
 	quote! {
 		pub fn metadata(&self) -> #pages_crate::form_generated::StaticFormMetadata {
 			#pages_crate::form_generated::StaticFormMetadata {
@@ -3114,6 +3138,14 @@ fn generate_metadata_function(
 				fields: vec![#(#field_metadata),*],
 			}
 		}
+
+		/// Stylesheets and scripts declared by the form's fields.
+		///
+		/// Merge `media_head(&form.media())` into the page head to emit them
+		/// during SSR; in the browser, `into_page` loads any that are missing.
+		pub fn media(&self) -> #pages_crate::form_generated::Media {
+			#pages_crate::form_generated::Media::new()#(#media_assets)*
+		}
 	}
 }
 
@@ -3144,6 +3176,9 @@ fn generate_into_page(macro_ast: &TypedFormMacro, pages_crate: &TokenStream) ->
 
 			#(#signal_bindings)*
 
+			#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+			#pages_crate::form_generated::load_media(&self.media());
+
 			#onsubmit_handler
 
 			form_element.into_page()
@@ -3167,11 +3202,16 @@ fn generate_onsubmit_handler(macro_ast: &TypedFormMacro, pages_crate: &TokenStre
 		FormMethod::Delete => "delete",
 	};
 
-	let form_class = macro_ast
-		.styling
-		.class
+	let form_class = match macro_ast.styling.class.as_deref() {
+		Some(class) => quote! { #class },
+		None => quote! { #pages_crate::form_renderer::form_renderer().form_class() },
+	};
+
+	// Mark the form for the HTML-over-the-wire runtime
+	let wire_attr = macro_ast
+		.wire
 		.as_deref()
-		.unwrap_or("reinhardt-form");
+		.map(|target| quote! { .attr("data-rh-wire", #target) });
 
 	// Collect all fields for use in onsubmit handler
 	let all_fields = collect_scalar_fields(&macro_ast.fields);
@@ -3428,6 +3468,7 @@ fn generate_onsubmit_handler(macro_ast: &TypedFormMacro, pages_crate: &TokenStre
 					.attr("action", #action_str)
 					.attr("method", #method_str)
 					.attr("class", #form_class)
+					#wire_attr
 					#before_fields_slot
 					#csrf_injection
 					#(.child(#field_views))*
@@ -3495,6 +3536,7 @@ fn generate_onsubmit_handler(macro_ast: &TypedFormMacro, pages_crate: &TokenStre
 					.attr("action", #action_str)
 					.attr("method", #method_str)
 					.attr("class", #form_class)
+					#wire_attr
 					#before_fields_slot
 					#csrf_injection
 					#(.child(#field_views))*
@@ -3955,9 +3997,9 @@ fn generate_collection_field_view(
 	let autocomplete_attr = field.display.autocomplete.as_deref().map(|val| {
 		quote! { .attr("autocomplete", #val) }
 	});
-	let wrapper_class = field.styling.wrapper_class();
-	let label_class = field.styling.label_class();
-	let input_class = field.styling.input_class();
+	let wrapper_class = field_class(field, FieldPart::Wrapper, pages_crate);
+	let label_class = field_class(field, FieldPart::Label, pages_crate);
+	let input_class = field_class(field, FieldPart::Input, pages_crate);
 	let custom_attrs = generate_custom_attrs(&field.custom_attrs);
 	let listener = generate_collection_bind_listener(field, pages_crate, collection_name);
 	let field_value = collection_field_value_expr(field);
@@ -4068,7 +4110,7 @@ fn generate_collection_field_view(
 			}
 		}
 	} else {
-		let wrapper_attrs = generate_wrapper_attrs(&field.wrapper, wrapper_class);
+		let wrapper_attrs = generate_wrapper_attrs(&field.wrapper, &wrapper_class);
 		let wrapper_tag = field
 			.wrapper
 			.as_ref()
@@ -4399,9 +4441,9 @@ fn generate_field_view(
 		quote! { .attr("autocomplete", #val) }
 	});
 
-	let wrapper_class = field.styling.wrapper_class();
-	let label_class = field.styling.label_class();
-	let input_class = field.styling.input_class();
+	let wrapper_class = field_class(field, FieldPart::Wrapper, pages_crate);
+	let label_class = field_class(field, FieldPart::Label, pages_crate);
+	let input_class = field_class(field, FieldPart::Input, pages_crate);
 
 	// Generate custom attributes (aria-*, data-*)
 	let custom_attrs = generate_custom_attrs(&field.custom_attrs);
@@ -4802,7 +4844,7 @@ fn generate_field_view(
 		input_element
 	} else {
 		// Use custom wrapper if specified, otherwise default to div
-		let wrapper_attrs = generate_wrapper_attrs(&field.wrapper, wrapper_class);
+		let wrapper_attrs = generate_wrapper_attrs(&field.wrapper, &wrapper_class);
 		let wrapper_tag = field
 			.wrapper
 			.as_ref()
@@ -4820,11 +4862,50 @@ fn generate_field_view(
 	}
 }
 
+/// Element of a field whose CSS class comes from the form renderer.
+#[derive(Clone, Copy)]
+enum FieldPart {
+	Wrapper,
+	Label,
+	Input,
+	Error,
+}
+
+/// Generates the CSS class expression of a field element.
+///
+/// Classes set in the macro are used as is; otherwise the class is looked up
+/// at runtime from the installed form renderer, so that projects can switch
+/// every form's styling (e.g. to Bootstrap) in one place.
+fn field_class(
+	field: &TypedFormFieldDef,
+	part: FieldPart,
+	pages_crate: &TokenStream,
+) -> TokenStream {
+	let (explicit, member) = match part {
+		FieldPart::Wrapper => (&field.styling.wrapper_class, quote!(wrapper)),
+		FieldPart::Label => (&field.styling.label_class, quote!(label)),
+		FieldPart::Input => (&field.styling.class, quote!(input)),
+		FieldPart::Error => (&field.styling.error_class, quote!(error)),
+	};
+	match explicit {
+		Some(class) => quote! { #class },
+		None => {
+			let widget = widget_to_string(&field.widget);
+			quote! {
+				#pages_crate::form_renderer::form_renderer().classes(#widget).#member
+			}
+		}
+	}
+}
+
 /// Generates wrapper element attributes.
 ///
 /// If a custom wrapper is specified, uses its attributes (merging with default class if needed).
 /// Otherwise, uses the default wrapper_class.
-fn generate_wrapper_attrs(wrapper: &Option<TypedWrapper>, default_class: &str) -> TokenStream {
+fn generate_wrapper_attrs(
+	wrapper: &Option<TypedWrapper>,
+	default_class: &TokenStream,
+) -> TokenStream {
 	match wrapper {
 		Some(w) => {
 			// Generate attributes from custom wrapper
@@ -6921,8 +7002,8 @@ mod tests {
 
 		// Should use custom tag but default class
 		assert!(output_str.contains("PageElement :: new (\"section\")"));
-		// Should have default reinhardt-field class
-		assert!(output_str.contains("reinhardt-field"));
+		// Should take the wrapper class from the form renderer
+		assert!(output_str.contains("classes (\"TextInput\") . wrapper"));
 	}
 
 	#[rstest::rstest]
@@ -6941,8 +7022,8 @@ mod tests {
 
 		// Should use default div wrapper
 		assert!(output_str.contains("PageElement :: new (\"div\")"));
-		// Should have default reinhardt-field class
-		assert!(output_str.contains("reinhardt-field"));
+		// Should take the wrapper class from the form renderer
+		assert!(output_str.contains("classes (\"TextInput\") . wrapper"));
 	}
 
 	// =========================================================================
@@ -7878,6 +7959,26 @@ mod tests {
 		assert!(!output_str.contains("__watch_closure"));
 	}
 
+	#[rstest::rstest]
+	fn test_generate_runtime_field_by_name_maps_source_names() {
+		let input = quote! {
+			name: SignupForm,
+			action: "/signup",
+
+			fields: {
+				display_name: CharField {},
+			},
+		};
+
+		let output = parse_validate_generate(input);
+		let output_str = output.to_string();
+
+		assert!(output_str.contains("fn runtime_field_by_name"));
+		assert!(output_str.contains(
+			"\"display_name\" => :: core :: option :: Option :: Some (SignupFormField :: DisplayName)"
+		));
+	}
+
 	#[rstest::rstest]
 	fn test_generate_empty_fields_focus_omits_unreachable_dom_path() {
 		let input = quote! {
@@ -7895,6 +7996,24 @@ mod tests {
 		assert!(!output_str.contains("let field_id = match field { }"));
 	}
 
+	#[rstest::rstest]
+	fn test_generate_wire_target_attribute() {
+		let input = quote! {
+			name: CommentForm,
+			action: "/comments",
+			wire: "#comments",
+
+			fields: {
+				body: CharField { required },
+			},
+		};
+
+		let output = parse_validate_generate(input);
+		let output_str = output.to_string();
+
+		assert!(output_str.contains(". attr (\"data-rh-wire\" , \"#comments\")"));
+	}
+
 	// ===== Redirect Code Generation Tests =====
 
 	#[rstest::rstest]
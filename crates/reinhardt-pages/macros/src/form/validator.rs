@@ -23,7 +23,7 @@ use reinhardt_manouche::core::{
 	FormSlots, FormState, FormSubmitButtonDef, FormValidator, FormWatch, FormWidgetSpec,
 	IconPosition, StripArgument, TypedButtonControlDef, TypedButtonKind, TypedChoiceGroup,
 	TypedChoiceItem, TypedChoiceOption, TypedChoicesConfig, TypedCustomAttr, TypedCustomWidget,
-	TypedDatalistDef, TypedDerivedItem, TypedFieldDisplay, TypedFieldNativeAttrs,
+	TypedDatalistDef, TypedDerivedItem, TypedFieldDisplay, TypedFieldMedia, TypedFieldNativeAttrs,
 	TypedFieldStyling, TypedFieldType, TypedFieldValidation, TypedFormAction, TypedFormCallbacks,
 	TypedFormDerived, TypedFormFieldCollection, TypedFormFieldDef, TypedFormFieldEntry,
 	TypedFormFieldGroup, TypedFormMacro, TypedFormSlots, TypedFormState, TypedFormStyling,
@@ -1430,10 +1430,12 @@ const ALLOWED_FIELD_PROPERTIES: &[&str] = &[
 	"autofocus",
 	"capture",
 	"class",
+	"css",
 	"disabled",
 	"error_class",
 	"help_text",
 	"initial",
+	"js",
 	"label",
 	"label_class",
 	"list",
@@ -1493,6 +1495,7 @@ fn transform_field(field: &FormFieldDef) -> Result<TypedFormFieldDef> {
 	let bind = extract_bind(&field.properties);
 	let initial_from = extract_initial_from(&field.properties);
 	let initial_expr = extract_initial_expr(&field.properties);
+	let media = extract_media(&field.properties)?;
 	let choices_config = extract_choices_config(&field.properties);
 	let static_choices_source = extract_static_choices(&field.properties)?;
 
@@ -1547,6 +1550,7 @@ fn transform_field(field: &FormFieldDef) -> Result<TypedFormFieldDef> {
 		initial_expr,
 		choices_config,
 		static_choices,
+		media,
 		span: field.span,
 	})
 }
@@ -2687,6 +2691,33 @@ fn extract_initial_from(properties: &[FormFieldProperty]) -> Option<String> {
 	None
 }
 
+/// Extracts the `css` and `js` asset URLs declared on a field.
+fn extract_media(properties: &[FormFieldProperty]) -> Result<TypedFieldMedia> {
+	let mut media = TypedFieldMedia::default();
+
+	for prop in properties {
+		let FormFieldProperty::Named { name, value, span } = prop else {
+			continue;
+		};
+		let prop_name = name.to_string();
+		let assets = match prop_name.as_str() {
+			"css" => &mut media.css,
+			"js" => &mut media.js,
+			_ => continue,
+		};
+		match value {
+			syn::Expr::Array(array) => {
+				for elem in &array.elems {
+					assets.push(extract_string_value_from_expr(elem, &prop_name, *span)?);
+				}
+			}
+			_ => assets.push(extract_string_value_from_expr(value, &prop_name, *span)?),
+		}
+	}
+
+	Ok(media)
+}
+
 /// Extracts the initial signal value expression from field properties.
 ///
 /// When `initial: <expr>` is specified on a field, the expression is used as
@@ -5923,6 +5954,57 @@ mod tests {
 		assert!(matches!(field.widget, TypedWidget::FileInput));
 	}
 
+	#[rstest::rstest]
+	fn test_validate_field_media() {
+		// Arrange
+		let input = quote! {
+			name: BookingForm,
+			action: "/book",
+
+			fields: {
+				booked_on: DateField {
+					css: "/static/css/datepicker.css",
+					js: ["/static/js/jquery.js", "/static/js/datepicker.js"],
+				},
+			},
+		};
+
+		// Act
+		let typed = parse_and_validate(input).unwrap();
+
+		// Assert
+		let field = typed.fields[0].as_field().unwrap();
+		assert_eq!(field.media.css, vec!["/static/css/datepicker.css"]);
+		assert_eq!(
+			field.media.js,
+			vec!["/static/js/jquery.js", "/static/js/datepicker.js"]
+		);
+	}
+
+	#[rstest::rstest]
+	fn test_validate_field_media_rejects_non_string() {
+		// Arrange
+		let input = quote! {
+			name: BookingForm,
+			action: "/book",
+
+			fields: {
+				booked_on: DateField {
+					js: [42],
+				},
+			},
+		};
+
+		// Act
+		let result = parse_and_validate(input);
+
+		// Assert
+		assert_eq!(
+			result.unwrap_err().to_string(),
+			"'js' must be a string literal value"
+		);
+	}
+
 	#[rstest::rstest]
 	fn test_validate_size_rejects_zero() {
 		// Arrange
@@ -942,7 +942,7 @@ pub fn head(input: TokenStream) -> TokenStream {
 /// | `action` | String | One of action/server_fn | URL endpoint for form submission |
 /// | `server_fn` | Path | One of action/server_fn | Server function for type-safe RPC |
 /// | `method` | Method | No | HTTP method (default: `Post`) |
-/// | `class` | String | No | Form CSS class (default: `"reinhardt-form"`) |
+/// | `class` | String | No | Form CSS class (default: the form renderer's, `"reinhardt-form"`) |
 /// | `initial_loader` | Path | No | Server function for loading initial values |
 /// | `redirect_on_success` | String | No | URL to redirect to after successful submission |
 ///
@@ -1098,6 +1098,11 @@ pub fn head(input: TokenStream) -> TokenStream {
 /// | `label_class` | String | `"reinhardt-label"` | Label CSS class |
 /// | `error_class` | String | `"reinhardt-error"` | Error message CSS class |
 ///
+/// Unset classes come from the form renderer installed with
+/// `reinhardt_core::form_renderer::set_form_renderer`; the defaults above are
+/// those of the default renderer. Installing `BootstrapRenderer` or
+/// `TailwindRenderer` restyles every form without changing the macros.
+///
 /// ### Widget Override
 ///
 /// ```text
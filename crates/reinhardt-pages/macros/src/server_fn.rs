@@ -188,6 +188,28 @@ pub(crate) struct ServerFnOptions {
 	/// }
 	/// ```
 	pub pre_validate: bool,
+
+	/// Expose the function to anonymous visitors with `public`
+	///
+	/// Requests are throttled per client IP and must carry a form token
+	/// rendered with `form_token_tag` and, when configured, a CAPTCHA
+	/// response. Requires the `public-forms` feature of reinhardt-pages on the
+	/// server.
+	///
+	/// # Example
+	///
+	/// ```ignore
+	/// #[server_fn(public)]
+	/// async fn contact(email: String, message: String) -> Result<(), ServerFnError> {
+	///     // ...
+	/// }
+	/// ```
+	pub public: bool,
+
+	/// Rate of a `public` function per client IP, such as `throttle = "3/min"`
+	///
+	/// Defaults to the rate of the installed `PublicFormConfig`.
+	pub throttle: Option<String>,
 }
 
 fn default_codec() -> String {
@@ -202,6 +224,8 @@ impl Default for ServerFnOptions {
 			codec: default_codec(),
 			no_csrf: false,
 			pre_validate: false,
+			public: false,
+			throttle: None,
 		}
 	}
 }
@@ -334,6 +358,31 @@ fn is_inject_attr(attr: &syn::Attribute) -> bool {
 	false
 }
 
+/// Reject a `ServerContext` parameter that is not marked `#[inject]`.
+///
+/// Without `#[inject]` the parameter would become a client-supplied argument,
+/// but the context is server-only and cannot be serialized from the client.
+fn check_server_context_params(inputs: &Punctuated<FnArg, Token![,]>) -> syn::Result<()> {
+	for input in inputs {
+		if let FnArg::Typed(pat_type) = input
+			&& !pat_type.attrs.iter().any(is_inject_attr)
+			&& let syn::Type::Path(type_path) = &*pat_type.ty
+			&& type_path
+				.path
+				.segments
+				.last()
+				.is_some_and(|seg| seg.ident == "ServerContext")
+		{
+			return Err(syn::Error::new_spanned(
+				&pat_type.ty,
+				"`ServerContext` is only available on the server; \
+				 declare it as `#[inject] ctx: ServerContext`",
+			));
+		}
+	}
+	Ok(())
+}
+
 /// Detect parameters for dependency injection
 ///
 /// This function scans function parameters and identifies those that should be
@@ -428,6 +477,11 @@ impl ServerFnInfo {
 		if let Some(ref endpoint) = options.endpoint {
 			validate_endpoint_path(endpoint)?;
 		}
+		if options.throttle.is_some() && !options.public {
+			return Err(darling::Error::custom(
+				"`throttle` only applies to `public` server functions",
+			));
+		}
 
 		Ok(Self { func, options })
 	}
@@ -523,6 +577,10 @@ pub(crate) fn server_fn_impl(args: TokenStream, input: TokenStream) -> TokenStre
 	// Parse function
 	let func = parse_macro_input!(input as ItemFn);
 
+	if let Err(e) = check_server_context_params(&func.sig.inputs) {
+		return e.to_compile_error().into();
+	}
+
 	// Parse metadata
 	let info = match ServerFnInfo::parse(attr_args, func) {
 		Ok(info) => info,
@@ -720,6 +778,20 @@ fn generate_client_stub(
 		}
 	};
 
+	// Generate form token and CAPTCHA header injection for public functions
+	let public_injection_code = if info.options.public {
+		let name_str = info.name().to_string();
+		quote! {
+			for (__public_header_name, __public_header_value) in
+				#pages_crate::server_fn::public::public_form_headers(#name_str)
+			{
+				__headers.push((__public_header_name.to_string(), __public_header_value));
+			}
+		}
+	} else {
+		quote! {}
+	};
+
 	// Generate codec-specific serialization and deserialization code
 	let (content_type, serialize_code, deserialize_code) = match codec {
 		"json" => (
@@ -798,6 +870,7 @@ fn generate_client_stub(
 
 			#csrf_injection_code
 			#auth_injection_code
+			#public_injection_code
 
 			// Send request with credentials for cookie-backed server function sessions.
 			let __response = #pages_crate::__private::fetch::request_with_credentials(
@@ -1184,6 +1257,26 @@ fn generate_server_handler(
 			},
 		}
 	};
+	// Reject throttled, unsigned or unverified submissions to public functions
+	// before reading the body
+	let public_guard_code = if info.options.public {
+		let name_str = name.to_string();
+		let throttle = match &info.options.throttle {
+			Some(rate) => quote! { ::std::option::Option::Some(#rate) },
+			None => quote! { ::std::option::Option::None },
+		};
+		quote! {
+			#pages_crate::server_fn::public::guard(&__req, #name_str, #throttle)
+				.await
+				.map_err(|e| {
+					let server_err = #pages_crate::server_fn::ServerFnError::server(e.status(), e.to_string());
+					::serde_json::to_string(&server_err)
+						.unwrap_or_else(|_| "Internal server error".to_string())
+				})?;
+		}
+	} else {
+		quote! {}
+	};
 	let wrapper_body_extraction = quote! {};
 	let wrapper_call_args = vec![quote! { req }];
 
@@ -1368,6 +1461,9 @@ fn generate_server_handler(
 				#(#regular_param_names: #regular_param_types),*
 			}
 
+			// Check throttling, form token and CAPTCHA (when public = true)
+			#public_guard_code
+
 			// Extract body and deserialize Args only when there are regular (non-extractor) params.
 			// When all params are extractors, skip body reading to avoid consuming the body
 			// before extractor resolution.
@@ -1555,6 +1651,40 @@ mod tests {
 		assert_eq!(options.codec, "json");
 	}
 
+	#[test]
+	fn test_server_fn_options_parse_public() {
+		use darling::FromMeta;
+		use darling::ast::NestedMeta;
+		use syn::parse_quote;
+
+		let attr: syn::Attribute = parse_quote!(#[server_fn(public, throttle = "3/min")]);
+		let meta_list = attr.meta.require_list().unwrap();
+		let nested: Vec<NestedMeta> = NestedMeta::parse_meta_list(meta_list.tokens.clone())
+			.unwrap()
+			.into_iter()
+			.collect();
+		let options = ServerFnOptions::from_list(&nested).unwrap();
+
+		assert!(options.public);
+		assert_eq!(options.throttle, Some("3/min".to_string()));
+	}
+
+	#[test]
+	fn test_throttle_requires_public() {
+		use syn::parse_quote;
+
+		let func: ItemFn = parse_quote! {
+			async fn contact(message: String) -> Result<(), ServerFnError> {
+				Ok(())
+			}
+		};
+		let args: Vec<Meta> = vec![parse_quote!(throttle = "3/min")];
+
+		let result = ServerFnInfo::parse(args, func);
+
+		assert!(result.is_err());
+	}
+
 	#[test]
 	fn test_validate_endpoint_valid_path() {
 		assert!(validate_endpoint_path("/api/users").is_ok());
@@ -1764,4 +1894,21 @@ mod tests {
 			.count();
 		assert_eq!(regular_count, 0, "All params should be extractors");
 	}
+
+	/// Tests that `ServerContext` must be an `#[inject]` parameter
+	#[test]
+	fn test_server_context_requires_inject() {
+		use syn::parse_quote;
+
+		let injected: syn::ItemFn = parse_quote! {
+			async fn whoami(#[inject] ctx: ServerContext) -> Result<(), ServerFnError> {}
+		};
+		let plain: syn::ItemFn = parse_quote! {
+			async fn whoami(ctx: reinhardt::pages::server_fn::ServerContext) -> Result<(), ServerFnError> {}
+		};
+
+		assert!(check_server_context_params(&injected.sig.inputs).is_ok());
+		let err = check_server_context_params(&plain.sig.inputs).unwrap_err();
+		assert!(err.to_string().contains("#[inject] ctx: ServerContext"));
+	}
 }
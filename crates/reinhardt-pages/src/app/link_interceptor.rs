@@ -69,6 +69,12 @@ pub(super) fn install_link_interceptor(
 	use wasm_bindgen::closure::Closure;
 
 	let closure = Closure::wrap(Box::new(move |event: web_sys::MouseEvent| {
+		// Clicks already handled elsewhere (e.g. by a `Link` component's own
+		// click handler) must not navigate twice.
+		if event.default_prevented() {
+			return;
+		}
+
 		// Walk up the DOM looking for the closest <a> ancestor.
 		//
 		// `event.target()` may be a `Node` that is not itself an `Element`
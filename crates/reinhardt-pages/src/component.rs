@@ -10,6 +10,10 @@
 //! - **Page enum**: Unified representation of DOM elements, text, and fragments
 //! - **Props system**: Type-safe component properties
 //! - **Boundaries**: Suspense, error, activity, and view-transition components
+//! - **List navigation**: Breadcrumbs, pagination, page-size, ordering and filter
+//!   components, also available as Tera functions with the `tera` feature
+//! - **Feeds**: Infinite scrolling and virtualized lists over paginated data
+//! - **Notifications**: Toasts, inbox and unread badge fed by server push
 //!
 //! ## Usage
 //!
@@ -30,17 +34,26 @@
 //! ```
 
 pub mod activity;
+pub mod breadcrumbs;
 pub mod error_boundary;
+pub mod infinite_list;
 mod into_page;
+pub mod notifications;
+pub mod pagination;
 mod props;
 pub(crate) mod reactive_if;
 pub mod suspense;
+#[cfg(all(native, feature = "tera"))]
+mod template_tags;
 mod r#trait;
 pub mod view_transition;
+pub mod virtual_list;
 
 // Re-export Page types (originally from into_page, now from reinhardt-types via into_page)
 pub use activity::{ActivityBoundary, ActivityMode};
+pub use breadcrumbs::Breadcrumbs;
 pub use error_boundary::{BoundaryError, ErrorBoundary, ErrorTracker};
+pub use infinite_list::{FeedStatus, InfiniteList, ListChunk, ListFeed};
 #[cfg(native)]
 pub use into_page::DummyEvent;
 pub use into_page::PageExt;
@@ -48,11 +61,18 @@ pub use into_page::{
 	Head, IntoPage, LinkTag, MetaTag, MountError, Page, PageElement, PageEventHandler, Reactive,
 	ReactiveIf, ScriptTag, StyleTag,
 };
+pub use notifications::{NotificationCenter, NotificationInbox, ToastStack, UnreadBadge};
+pub use pagination::{FilterForm, ListQuery, OrderingToggle, PageSizeSelector, Paginator};
 pub use props::Props;
 #[cfg(wasm)]
-pub use reactive_if::{ReactiveIfNode, ReactiveNode, cleanup_reactive_nodes, store_reactive_node};
+pub use reactive_if::{
+	ReactiveIfNode, ReactiveNode, ReactiveNodeScope, cleanup_reactive_nodes, store_reactive_node,
+};
 pub use suspense::{ResourceTracker, SuspenseBoundary};
 pub use r#trait::Component;
+#[cfg(all(native, feature = "tera"))]
+pub use template_tags::{register_filters, register_functions};
 pub use view_transition::{
 	ViewTransitionBoundary, ViewTransitionHandle, ViewTransitionStatus, start_view_transition,
 };
+pub use virtual_list::VirtualList;
@@ -0,0 +1,99 @@
+//! Breadcrumb navigation component.
+
+use crate::component::{Component, IntoPage, Page, PageElement};
+
+/// A breadcrumb trail rendered as an ordered list inside a `<nav>`.
+///
+/// Every item except the last is a link; the last one is the current page
+/// and is marked with `aria-current="page"`.
+///
+/// # Example
+///
+/// ```
+/// use reinhardt_pages::component::{Breadcrumbs, Component};
+///
+/// let trail = Breadcrumbs::new()
+///     .item("Home", "/")
+///     .item("Articles", "/articles/")
+///     .current("Editing");
+/// let html = trail.render().render_to_string();
+/// assert!(html.contains("<a href=\"/articles/\">Articles</a>"));
+/// assert!(html.contains("<span aria-current=\"page\">Editing</span>"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Breadcrumbs {
+	/// The trail, as `(label, url)` pairs.
+	items: Vec<(String, Option<String>)>,
+	/// CSS class of the list.
+	class: Option<String>,
+}
+
+impl Breadcrumbs {
+	/// Creates an empty breadcrumb trail.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Appends a linked item.
+	pub fn item(mut self, label: impl Into<String>, url: impl Into<String>) -> Self {
+		self.items.push((label.into(), Some(url.into())));
+		self
+	}
+
+	/// Appends the current page, which is rendered without a link.
+	pub fn current(mut self, label: impl Into<String>) -> Self {
+		self.items.push((label.into(), None));
+		self
+	}
+
+	/// Sets the CSS class of the list (default: `breadcrumb`).
+	pub fn class(mut self, class: impl Into<String>) -> Self {
+		self.class = Some(class.into());
+		self
+	}
+
+	/// Returns the items as `(label, url)` pairs.
+	pub fn items(&self) -> &[(String, Option<String>)] {
+		&self.items
+	}
+}
+
+impl Component for Breadcrumbs {
+	fn render(&self) -> Page {
+		let last = self.items.len().saturating_sub(1);
+		let items = self.items.iter().enumerate().map(|(index, (label, url))| {
+			let content = match url {
+				Some(url) if index != last => PageElement::new("a")
+					.attr("href", url.clone())
+					.child(label.clone()),
+				_ => PageElement::new("span")
+					.attr("aria-current", "page")
+					.child(label.clone()),
+			};
+			PageElement::new("li").child(content)
+		});
+
+		let list = PageElement::new("ol")
+			.attr(
+				"class",
+				self.class
+					.clone()
+					.unwrap_or_else(|| "breadcrumb".to_string()),
+			)
+			.children(items);
+		PageElement::new("nav")
+			.attr("aria-label", "Breadcrumb")
+			.child(list)
+			.into_page()
+	}
+
+	fn name() -> &'static str {
+		"Breadcrumbs"
+	}
+}
+
+impl IntoPage for Breadcrumbs {
+	fn into_page(self) -> Page {
+		self.render()
+	}
+}
@@ -0,0 +1,458 @@
+//! Infinite scrolling over paginated data.
+//!
+//! A [`ListFeed`] loads a paginated or cursor-based source chunk by chunk:
+//! its loader receives the cursor of the next chunk (`None` for the first
+//! one) and returns a [`ListChunk`] with the items and the following cursor.
+//! The `next` URL of a paginated API response or the cursor returned by a
+//! `#[server_fn]` both work as cursors.
+//!
+//! [`InfiniteList`] renders every loaded row and loads the next chunk when
+//! the end of the list scrolls into view. For long feeds where rendering all
+//! rows is too expensive, see [`VirtualList`](super::virtual_list::VirtualList).
+//!
+//! # Example
+//!
+//! ```ignore
+//! use reinhardt_pages::component::infinite_list::{InfiniteList, ListChunk, ListFeed};
+//!
+//! let feed = ListFeed::new(|cursor: Option<String>| async move {
+//!     let page = list_posts(cursor).await?;
+//!     Ok::<_, ServerFnError>(ListChunk::new(page.results, page.next))
+//! });
+//!
+//! InfiniteList::new(feed, |post: &Post| PageElement::new("article").child(post.title.clone()).into_page())
+//!     .loading(|| Page::text("Loading more posts..."))
+//!     .into_page()
+//! ```
+
+use crate::component::{Component, IntoPage, Page, PageElement};
+use crate::reactive::Signal;
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static LIST_ID: AtomicUsize = AtomicUsize::new(0);
+
+type Loader<T> =
+	Rc<dyn Fn(Option<String>) -> Pin<Box<dyn Future<Output = Result<ListChunk<T>, String>>>>>;
+
+/// One chunk of a paginated source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListChunk<T> {
+	/// Items of the chunk.
+	pub items: Vec<T>,
+	/// Cursor of the next chunk, `None` on the last chunk.
+	pub next: Option<String>,
+}
+
+impl<T> ListChunk<T> {
+	/// Creates a chunk followed by the chunk at `next`.
+	pub fn new(items: Vec<T>, next: Option<String>) -> Self {
+		Self { items, next }
+	}
+
+	/// Creates the last chunk of the source.
+	pub fn last(items: Vec<T>) -> Self {
+		Self::new(items, None)
+	}
+}
+
+/// Loading state of a [`ListFeed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeedStatus {
+	/// Waiting for the next chunk to be requested.
+	Idle,
+	/// A chunk is being loaded.
+	Loading,
+	/// Loading the last requested chunk failed.
+	Error(String),
+	/// Every chunk has been loaded.
+	Done,
+}
+
+/// Items of a paginated source, loaded chunk by chunk.
+///
+/// Cloning a feed shares its items and state.
+pub struct ListFeed<T: Clone + 'static> {
+	items: Signal<Vec<T>>,
+	status: Signal<FeedStatus>,
+	cursor: Rc<RefCell<Option<String>>>,
+	loader: Loader<T>,
+}
+
+impl<T: Clone + 'static> Clone for ListFeed<T> {
+	fn clone(&self) -> Self {
+		Self {
+			items: self.items.clone(),
+			status: self.status.clone(),
+			cursor: Rc::clone(&self.cursor),
+			loader: Rc::clone(&self.loader),
+		}
+	}
+}
+
+impl<T: Clone + 'static> ListFeed<T> {
+	/// Creates a feed loading chunks with `loader`.
+	///
+	/// Nothing is loaded until [`load_more`](Self::load_more) is called,
+	/// which the list components do once they are mounted.
+	pub fn new<F, Fut, E>(loader: F) -> Self
+	where
+		F: Fn(Option<String>) -> Fut + 'static,
+		Fut: Future<Output = Result<ListChunk<T>, E>> + 'static,
+		E: Display,
+	{
+		let loader = Rc::new(loader);
+		Self {
+			items: Signal::new(Vec::new()),
+			status: Signal::new(FeedStatus::Idle),
+			cursor: Rc::new(RefCell::new(None)),
+			loader: Rc::new(move |cursor: Option<String>| {
+				let fut = loader(cursor);
+				Box::pin(async move { fut.await.map_err(|e| e.to_string()) })
+					as Pin<Box<dyn Future<Output = Result<ListChunk<T>, String>>>>
+			}),
+		}
+	}
+
+	/// Starts the feed with an already loaded first chunk.
+	///
+	/// Used to render the first chunk during SSR, where loaders never run.
+	pub fn with_initial(self, chunk: ListChunk<T>) -> Self {
+		self.apply(Ok(chunk));
+		self
+	}
+
+	/// Signal of the loaded items.
+	pub fn items(&self) -> Signal<Vec<T>> {
+		self.items.clone()
+	}
+
+	/// Signal of the loading state.
+	pub fn status(&self) -> Signal<FeedStatus> {
+		self.status.clone()
+	}
+
+	/// Returns `true` while a chunk is loading.
+	pub fn is_loading(&self) -> bool {
+		self.status.get_untracked() == FeedStatus::Loading
+	}
+
+	/// Returns `true` once every chunk has been loaded.
+	pub fn is_done(&self) -> bool {
+		self.status.get_untracked() == FeedStatus::Done
+	}
+
+	/// Loads the next chunk.
+	///
+	/// Does nothing while a chunk is loading or after the last chunk. After a
+	/// failure, retries the failed chunk.
+	pub fn load_more(&self) {
+		let Some(cursor) = self.begin() else {
+			return;
+		};
+		let feed = self.clone();
+		crate::platform::spawn_task(async move {
+			let result = (feed.loader)(cursor).await;
+			feed.apply(result);
+		});
+	}
+
+	/// Marks the feed as loading, returning the cursor to load.
+	fn begin(&self) -> Option<Option<String>> {
+		match self.status.get_untracked() {
+			FeedStatus::Loading | FeedStatus::Done => None,
+			FeedStatus::Idle | FeedStatus::Error(_) => {
+				self.status.set(FeedStatus::Loading);
+				Some(self.cursor.borrow().clone())
+			}
+		}
+	}
+
+	/// Applies the result of loading a chunk.
+	fn apply(&self, result: Result<ListChunk<T>, String>) {
+		match result {
+			Ok(chunk) => {
+				let done = chunk.next.is_none();
+				*self.cursor.borrow_mut() = chunk.next;
+				self.items.update(|items| items.extend(chunk.items));
+				self.status.set(if done {
+					FeedStatus::Done
+				} else {
+					FeedStatus::Idle
+				});
+			}
+			Err(error) => self.status.set(FeedStatus::Error(error)),
+		}
+	}
+}
+
+/// Views shared by the list components for the feed states.
+#[derive(Clone)]
+pub(super) struct StatusViews {
+	pub(super) loading: Rc<dyn Fn() -> Page>,
+	pub(super) error: Rc<dyn Fn(&str) -> Page>,
+	pub(super) end: Rc<dyn Fn() -> Page>,
+}
+
+impl Default for StatusViews {
+	fn default() -> Self {
+		Self {
+			loading: Rc::new(|| Page::text("Loading...")),
+			error: Rc::new(|error: &str| Page::text(error.to_string())),
+			end: Rc::new(|| Page::Empty),
+		}
+	}
+}
+
+impl StatusViews {
+	/// Renders the view of `status`, with `aria-busy` while loading.
+	pub(super) fn render(&self, status: &FeedStatus) -> Page {
+		let (state, view) = match status {
+			FeedStatus::Idle => ("idle", Page::Empty),
+			FeedStatus::Loading => ("loading", (self.loading)()),
+			FeedStatus::Error(error) => ("error", (self.error)(error)),
+			FeedStatus::Done => ("done", (self.end)()),
+		};
+		let mut el = PageElement::new("div")
+			.attr("class", "list-status")
+			.attr("data-state", state);
+		if matches!(status, FeedStatus::Loading) {
+			el = el.attr("aria-busy", "true");
+		}
+		if matches!(status, FeedStatus::Error(_)) {
+			el = el.attr("role", "alert");
+		}
+		el.child(view).into_page()
+	}
+}
+
+/// Returns a new element id with `prefix`.
+pub(super) fn next_list_id(prefix: &str) -> String {
+	format!("{}-{}", prefix, LIST_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+/// A list rendering every loaded row of a [`ListFeed`], loading the next
+/// chunk when its end scrolls into view.
+pub struct InfiniteList<T: Clone + 'static> {
+	feed: ListFeed<T>,
+	row: Rc<dyn Fn(&T) -> Page>,
+	views: StatusViews,
+	id: String,
+}
+
+impl<T: Clone + 'static> InfiniteList<T> {
+	/// Creates a list over `feed` rendering each item with `row`.
+	pub fn new(feed: ListFeed<T>, row: impl Fn(&T) -> Page + 'static) -> Self {
+		Self {
+			feed,
+			row: Rc::new(row),
+			views: StatusViews::default(),
+			id: next_list_id("infinite-list"),
+		}
+	}
+
+	/// Sets the view shown while a chunk loads.
+	pub fn loading(mut self, view: impl Fn() -> Page + 'static) -> Self {
+		self.views.loading = Rc::new(view);
+		self
+	}
+
+	/// Sets the view shown when loading a chunk failed.
+	pub fn error(mut self, view: impl Fn(&str) -> Page + 'static) -> Self {
+		self.views.error = Rc::new(view);
+		self
+	}
+
+	/// Sets the view shown after the last chunk.
+	pub fn end(mut self, view: impl Fn() -> Page + 'static) -> Self {
+		self.views.end = Rc::new(view);
+		self
+	}
+
+	/// Returns the feed backing the list.
+	pub fn feed(&self) -> &ListFeed<T> {
+		&self.feed
+	}
+
+	#[cfg(wasm)]
+	fn observe_sentinel(&self) {
+		let feed = self.feed.clone();
+		let id = self.id.clone();
+		crate::platform::spawn_task(async move {
+			crate::platform::defer_yield().await;
+			observe_sentinel(&id, feed);
+		});
+	}
+}
+
+impl<T: Clone + 'static> Component for InfiniteList<T> {
+	fn render(&self) -> Page {
+		let items = self.feed.items();
+		let row = Rc::clone(&self.row);
+		let rows = Page::reactive(move || Page::fragment(items.get().iter().map(|item| row(item))));
+
+		let status = self.feed.status();
+		let views = self.views.clone();
+		let status_view = Page::reactive(move || views.render(&status.get()));
+
+		#[cfg(wasm)]
+		self.observe_sentinel();
+
+		PageElement::new("div")
+			.attr("id", self.id.clone())
+			.attr("class", "infinite-list")
+			.child(rows)
+			.child(status_view)
+			.child(
+				PageElement::new("div")
+					.attr("data-infinite-sentinel", "")
+					.attr("aria-hidden", "true"),
+			)
+			.into_page()
+	}
+
+	fn name() -> &'static str {
+		"InfiniteList"
+	}
+}
+
+impl<T: Clone + 'static> IntoPage for InfiniteList<T> {
+	fn into_page(self) -> Page {
+		self.render()
+	}
+}
+
+/// Loads the next chunk of `feed` whenever the sentinel of list `id` is in
+/// view.
+///
+/// After each chunk the sentinel is observed again, which reports its
+/// current intersection: a chunk too short to fill the viewport leaves it
+/// in view, so loading continues until the viewport is full.
+#[cfg(wasm)]
+fn observe_sentinel<T: Clone + 'static>(id: &str, feed: ListFeed<T>) {
+	use crate::reactive::Effect;
+	use wasm_bindgen::JsCast;
+	use wasm_bindgen::closure::Closure;
+
+	let Some(sentinel) = web_sys::window().and_then(|w| w.document()).and_then(|d| {
+		d.query_selector(&format!("#{} [data-infinite-sentinel]", id))
+			.ok()
+			.flatten()
+	}) else {
+		return;
+	};
+
+	let callback = Closure::<dyn FnMut(js_sys::Array)>::new({
+		let feed = feed.clone();
+		move |entries: js_sys::Array| {
+			let intersecting = entries.iter().any(|entry| {
+				entry
+					.unchecked_into::<web_sys::IntersectionObserverEntry>()
+					.is_intersecting()
+			});
+			if intersecting {
+				feed.load_more();
+			}
+		}
+	});
+	let Ok(observer) = web_sys::IntersectionObserver::new(callback.as_ref().unchecked_ref()) else {
+		return;
+	};
+	callback.forget();
+	observer.observe(&sentinel);
+
+	let status = feed.status();
+	let effect = Effect::new(move || match status.get() {
+		FeedStatus::Idle => {
+			observer.unobserve(&sentinel);
+			observer.observe(&sentinel);
+		}
+		FeedStatus::Done => observer.disconnect(),
+		FeedStatus::Loading | FeedStatus::Error(_) => {}
+	});
+	// The observer follows the feed for the lifetime of the page.
+	std::mem::forget(effect);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn feed() -> ListFeed<u32> {
+		ListFeed::new(|_cursor: Option<String>| async {
+			Ok::<_, String>(ListChunk::last(Vec::new()))
+		})
+	}
+
+	#[rstest]
+	fn test_feed_appends_chunks_and_advances_cursor() {
+		// Arrange
+		let feed = feed();
+
+		// Act
+		let first = feed.begin();
+		feed.apply(Ok(ListChunk::new(vec![1, 2], Some("c2".to_string()))));
+		let second = feed.begin();
+		feed.apply(Ok(ListChunk::last(vec![3])));
+
+		// Assert
+		assert_eq!(first, Some(None));
+		assert_eq!(second, Some(Some("c2".to_string())));
+		assert_eq!(feed.items().get(), vec![1, 2, 3]);
+		assert!(feed.is_done());
+		assert_eq!(feed.begin(), None);
+	}
+
+	#[rstest]
+	fn test_feed_does_not_load_twice_concurrently() {
+		// Arrange
+		let feed = feed();
+		feed.begin();
+
+		// Act
+		let second = feed.begin();
+
+		// Assert
+		assert_eq!(second, None);
+		assert!(feed.is_loading());
+	}
+
+	#[rstest]
+	fn test_feed_retries_failed_chunk() {
+		// Arrange
+		let feed = feed().with_initial(ListChunk::new(vec![1], Some("c2".to_string())));
+		feed.begin();
+		feed.apply(Err("timeout".to_string()));
+
+		// Act
+		let retry = feed.begin();
+
+		// Assert
+		assert_eq!(retry, Some(Some("c2".to_string())));
+		assert_eq!(feed.items().get(), vec![1]);
+	}
+
+	#[rstest]
+	fn test_infinite_list_renders_rows_and_status() {
+		// Arrange
+		let feed = feed().with_initial(ListChunk::new(vec![1, 2], Some("c2".to_string())));
+		feed.begin();
+		feed.apply(Err("timeout".to_string()));
+		let list = InfiniteList::new(feed, |n: &u32| Page::text(format!("[row {}]", n)))
+			.error(|error| Page::text(format!("failed: {}", error)));
+
+		// Act
+		let html = list.render().render_to_string();
+
+		// Assert
+		assert!(html.contains("[row 1][row 2]"));
+		assert!(html.contains("role=\"alert\""));
+		assert!(html.contains("failed: timeout"));
+		assert!(html.contains("data-infinite-sentinel"));
+	}
+}
@@ -0,0 +1,631 @@
+//! Client side of in-app notifications.
+//!
+//! A [`NotificationCenter`] holds the inbox of the current user, the queue
+//! of toasts shown for newly received notifications and a signal-backed
+//! unread counter. On WASM, [`NotificationCenter::connect`] subscribes to a
+//! Server-Sent Events endpoint streaming `notification` events, as published
+//! by the `SseNotificationChannel` of reinhardt-websockets.
+//!
+//! [`ToastStack`], [`NotificationInbox`] and [`UnreadBadge`] render the
+//! center; they share its state, so marking a notification read in the inbox
+//! updates the badge.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use reinhardt_pages::component::notifications::{
+//!     NotificationCenter, NotificationInbox, ToastStack, UnreadBadge,
+//! };
+//!
+//! let center = NotificationCenter::new()
+//!     .with_initial(inbox_from_server)
+//!     .on_read(|ids| {
+//!         let ids = ids.to_vec();
+//!         spawn_task(async move {
+//!             let _ = mark_notifications_read(ids).await;
+//!         });
+//!     });
+//! center.connect("/notifications/stream/");
+//!
+//! page!(|| {
+//!     header { UnreadBadge::new(center.clone()) }
+//!     NotificationInbox::new(center.clone())
+//!     ToastStack::new(center.clone())
+//! })
+//! ```
+
+use crate::component::{Component, IntoPage, Page, PageElement};
+use crate::reactive::{Memo, Signal};
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+use std::time::Duration;
+
+/// Event name of notification events on the SSE stream.
+pub const NOTIFICATION_EVENT: &str = "notification";
+
+/// Toasts shown at once by default.
+const DEFAULT_MAX_TOASTS: usize = 5;
+
+/// How long a toast is shown by default.
+const DEFAULT_TOAST_DURATION: Duration = Duration::from_secs(5);
+
+/// A notification as sent by the server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationItem {
+	/// Unique id.
+	pub id: String,
+	/// Severity: `info`, `success`, `warning` or `error`.
+	#[serde(default = "default_level")]
+	pub level: String,
+	/// Short title.
+	pub title: String,
+	/// Optional longer text.
+	#[serde(default)]
+	pub body: String,
+	/// Optional URL the notification points to.
+	#[serde(default)]
+	pub link: Option<String>,
+	/// Creation time, as RFC 3339.
+	#[serde(default)]
+	pub created_at: String,
+	/// Read time, as RFC 3339; `None` while unread.
+	#[serde(default)]
+	pub read_at: Option<String>,
+}
+
+fn default_level() -> String {
+	"info".to_string()
+}
+
+impl NotificationItem {
+	/// Whether the notification has been read.
+	pub fn is_read(&self) -> bool {
+		self.read_at.is_some()
+	}
+}
+
+/// Inbox, toast queue and unread counter of the current user.
+///
+/// Cloning a center shares its state.
+#[derive(Clone)]
+pub struct NotificationCenter {
+	items: Signal<Vec<NotificationItem>>,
+	toasts: Signal<Vec<NotificationItem>>,
+	unread: Memo<usize>,
+	max_toasts: usize,
+	#[cfg_attr(not(wasm), allow(dead_code))]
+	toast_duration: Option<Duration>,
+	on_read: Option<Rc<dyn Fn(&[String])>>,
+	#[cfg(wasm)]
+	source: Rc<std::cell::RefCell<Option<web_sys::EventSource>>>,
+}
+
+impl Default for NotificationCenter {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl NotificationCenter {
+	/// Creates an empty center.
+	pub fn new() -> Self {
+		let items = Signal::new(Vec::<NotificationItem>::new());
+		let unread = Memo::new({
+			let items = items.clone();
+			move || items.get().iter().filter(|n| !n.is_read()).count()
+		});
+		Self {
+			items,
+			toasts: Signal::new(Vec::new()),
+			unread,
+			max_toasts: DEFAULT_MAX_TOASTS,
+			toast_duration: Some(DEFAULT_TOAST_DURATION),
+			on_read: None,
+			#[cfg(wasm)]
+			source: Rc::new(std::cell::RefCell::new(None)),
+		}
+	}
+
+	/// Starts with an inbox loaded by the server, newest first.
+	///
+	/// No toast is shown for these notifications.
+	pub fn with_initial(self, items: Vec<NotificationItem>) -> Self {
+		self.items.set(items);
+		self
+	}
+
+	/// Sets how many toasts are shown at once; older toasts are dropped.
+	pub fn max_toasts(mut self, max_toasts: usize) -> Self {
+		self.max_toasts = max_toasts;
+		self
+	}
+
+	/// Sets how long toasts are shown on WASM, or `None` to keep them until
+	/// dismissed.
+	pub fn toast_duration(mut self, duration: Option<Duration>) -> Self {
+		self.toast_duration = duration;
+		self
+	}
+
+	/// Sets the callback receiving the ids of notifications marked read, to
+	/// persist the read state on the server.
+	pub fn on_read(mut self, callback: impl Fn(&[String]) + 'static) -> Self {
+		self.on_read = Some(Rc::new(callback));
+		self
+	}
+
+	/// Signal of the inbox, newest first.
+	pub fn items(&self) -> Signal<Vec<NotificationItem>> {
+		self.items.clone()
+	}
+
+	/// Signal of the toasts currently shown, oldest first.
+	pub fn toasts(&self) -> Signal<Vec<NotificationItem>> {
+		self.toasts.clone()
+	}
+
+	/// Number of unread notifications.
+	pub fn unread_count(&self) -> Memo<usize> {
+		self.unread.clone()
+	}
+
+	/// Adds a newly received notification to the inbox and shows its toast.
+	///
+	/// Notifications already in the inbox are ignored, so replays after a
+	/// reconnect do not show duplicates.
+	pub fn receive(&self, item: NotificationItem) {
+		if self
+			.items
+			.get_untracked()
+			.iter()
+			.any(|known| known.id == item.id)
+		{
+			return;
+		}
+		self.items.update(|items| items.insert(0, item.clone()));
+		if self.max_toasts == 0 {
+			return;
+		}
+		#[cfg(wasm)]
+		if let Some(duration) = self.toast_duration {
+			self.dismiss_after(item.id.clone(), duration);
+		}
+		self.toasts.update(|toasts| {
+			toasts.push(item);
+			let excess = toasts.len().saturating_sub(self.max_toasts);
+			toasts.drain(..excess);
+		});
+	}
+
+	/// Hides the toast of notification `id`.
+	pub fn dismiss(&self, id: &str) {
+		self.toasts
+			.update(|toasts| toasts.retain(|toast| toast.id != id));
+	}
+
+	/// Marks notification `id` read.
+	pub fn mark_read(&self, id: &str) {
+		self.mark(|item| item.id == id);
+	}
+
+	/// Marks every notification read.
+	pub fn mark_all_read(&self) {
+		self.mark(|_| true);
+	}
+
+	/// Marks the unread notifications matching `filter` read and reports
+	/// them to the `on_read` callback.
+	fn mark(&self, filter: impl Fn(&NotificationItem) -> bool) {
+		let mut marked = Vec::new();
+		self.items.update(|items| {
+			for item in items
+				.iter_mut()
+				.filter(|item| !item.is_read() && filter(item))
+			{
+				item.read_at = Some(now());
+				marked.push(item.id.clone());
+			}
+		});
+		if !marked.is_empty()
+			&& let Some(on_read) = &self.on_read
+		{
+			on_read(&marked);
+		}
+	}
+
+	/// Subscribes to the SSE endpoint at `url`.
+	///
+	/// Replaces the previous subscription, if any. The browser reconnects on
+	/// its own after network errors and sends `Last-Event-ID`, so missed
+	/// notifications are replayed.
+	#[cfg(wasm)]
+	pub fn connect(&self, url: &str) -> Result<(), String> {
+		use wasm_bindgen::JsCast;
+		use wasm_bindgen::closure::Closure;
+
+		self.disconnect();
+		let init = web_sys::EventSourceInit::new();
+		init.set_with_credentials(true);
+		let source = web_sys::EventSource::new_with_event_source_init_dict(url, &init)
+			.map_err(|e| format!("{:?}", e))?;
+		let center = self.clone();
+		let callback = Closure::<dyn FnMut(web_sys::MessageEvent)>::new(
+			move |event: web_sys::MessageEvent| {
+				let Some(data) = event.data().as_string() else {
+					return;
+				};
+				match serde_json::from_str::<NotificationItem>(&data) {
+					Ok(item) => center.receive(item),
+					Err(e) => {
+						web_sys::console::warn_1(&format!("Invalid notification: {}", e).into())
+					}
+				}
+			},
+		);
+		source
+			.add_event_listener_with_callback(NOTIFICATION_EVENT, callback.as_ref().unchecked_ref())
+			.map_err(|e| format!("{:?}", e))?;
+		// The listener lives as long as the event source.
+		callback.forget();
+		*self.source.borrow_mut() = Some(source);
+		Ok(())
+	}
+
+	/// Subscribes to the SSE endpoint at `url` (no-op outside WASM).
+	#[cfg(native)]
+	pub fn connect(&self, _url: &str) -> Result<(), String> {
+		Ok(())
+	}
+
+	/// Closes the SSE subscription, if any.
+	pub fn disconnect(&self) {
+		#[cfg(wasm)]
+		if let Some(source) = self.source.borrow_mut().take() {
+			source.close();
+		}
+	}
+
+	#[cfg(wasm)]
+	fn dismiss_after(&self, id: String, duration: Duration) {
+		use wasm_bindgen::JsCast;
+		use wasm_bindgen::closure::Closure;
+
+		let Some(window) = web_sys::window() else {
+			return;
+		};
+		let center = self.clone();
+		let callback = Closure::once_into_js(move || center.dismiss(&id));
+		let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+			callback.unchecked_ref(),
+			duration.as_millis().min(i32::MAX as u128) as i32,
+		);
+	}
+}
+
+/// Current time as RFC 3339.
+#[cfg(wasm)]
+fn now() -> String {
+	js_sys::Date::new_0().to_iso_string().into()
+}
+
+/// Current time as RFC 3339.
+#[cfg(native)]
+fn now() -> String {
+	let secs = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.map_or(0, |d| d.as_secs());
+	// Civil date from days since the epoch (Howard Hinnant's algorithm).
+	let (days, rem) = ((secs / 86_400) as i64, secs % 86_400);
+	let z = days + 719_468;
+	let era = z.div_euclid(146_097);
+	let doe = z - era * 146_097;
+	let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let day = doy - (153 * mp + 2) / 5 + 1;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 };
+	let year = yoe + era * 400 + i64::from(month <= 2);
+	format!(
+		"{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+		year,
+		month,
+		day,
+		rem / 3_600,
+		rem % 3_600 / 60,
+		rem % 60
+	)
+}
+
+/// Renders the title of `item`, as a link when it has one.
+fn title(item: &NotificationItem) -> Page {
+	match &item.link {
+		Some(link) => PageElement::new("a")
+			.attr("href", link.clone())
+			.attr("class", "notification-title")
+			.child(item.title.clone())
+			.into_page(),
+		None => PageElement::new("strong")
+			.attr("class", "notification-title")
+			.child(item.title.clone())
+			.into_page(),
+	}
+}
+
+/// Toasts of newly received notifications.
+pub struct ToastStack {
+	center: NotificationCenter,
+}
+
+impl ToastStack {
+	/// Creates the toast stack of `center`.
+	pub fn new(center: NotificationCenter) -> Self {
+		Self { center }
+	}
+}
+
+impl Component for ToastStack {
+	fn render(&self) -> Page {
+		let center = self.center.clone();
+		let toasts = Page::reactive(move || {
+			Page::fragment(center.toasts.get().into_iter().map(|item| {
+				let dismiss = {
+					let center = center.clone();
+					let id = item.id.clone();
+					PageElement::new("button")
+						.attr("type", "button")
+						.attr("class", "toast-dismiss")
+						.attr("aria-label", "Dismiss")
+						.listener("click", move |_| center.dismiss(&id))
+						.child("×")
+				};
+				let mut toast = PageElement::new("div")
+					.attr("class", format!("toast toast-{}", item.level))
+					.attr("role", "status")
+					.attr("data-notification-id", item.id.clone())
+					.child(title(&item));
+				if !item.body.is_empty() {
+					toast = toast.child(
+						PageElement::new("p")
+							.attr("class", "toast-body")
+							.child(item.body.clone()),
+					);
+				}
+				toast.child(dismiss).into_page()
+			}))
+		});
+		PageElement::new("div")
+			.attr("class", "toast-stack")
+			.attr("aria-live", "polite")
+			.child(toasts)
+			.into_page()
+	}
+
+	fn name() -> &'static str {
+		"ToastStack"
+	}
+}
+
+impl IntoPage for ToastStack {
+	fn into_page(self) -> Page {
+		self.render()
+	}
+}
+
+/// List of the notifications of the inbox.
+pub struct NotificationInbox {
+	center: NotificationCenter,
+	empty: Rc<dyn Fn() -> Page>,
+}
+
+impl NotificationInbox {
+	/// Creates the inbox of `center`.
+	pub fn new(center: NotificationCenter) -> Self {
+		Self {
+			center,
+			empty: Rc::new(|| Page::text("No notifications")),
+		}
+	}
+
+	/// Sets the view shown when the inbox is empty.
+	pub fn empty(mut self, view: impl Fn() -> Page + 'static) -> Self {
+		self.empty = Rc::new(view);
+		self
+	}
+}
+
+impl Component for NotificationInbox {
+	fn render(&self) -> Page {
+		let mark_all = {
+			let center = self.center.clone();
+			PageElement::new("button")
+				.attr("type", "button")
+				.attr("class", "notifications-mark-all")
+				.listener("click", move |_| center.mark_all_read())
+				.child("Mark all as read")
+		};
+
+		let center = self.center.clone();
+		let empty = Rc::clone(&self.empty);
+		let list = Page::reactive(move || {
+			let items = center.items.get();
+			if items.is_empty() {
+				return empty();
+			}
+			PageElement::new("ul")
+				.attr("class", "notification-list")
+				.child(Page::fragment(items.iter().map(|item| {
+					let state = if item.is_read() { "read" } else { "unread" };
+					let center = center.clone();
+					let id = item.id.clone();
+					let mut entry = PageElement::new("li")
+						.attr(
+							"class",
+							format!("notification notification-{} {}", item.level, state),
+						)
+						.attr("data-notification-id", item.id.clone())
+						.listener("click", move |_| center.mark_read(&id))
+						.child(title(item));
+					if !item.body.is_empty() {
+						entry = entry.child(
+							PageElement::new("p")
+								.attr("class", "notification-body")
+								.child(item.body.clone()),
+						);
+					}
+					entry.into_page()
+				})))
+				.into_page()
+		});
+
+		PageElement::new("section")
+			.attr("class", "notification-inbox")
+			.child(mark_all)
+			.child(list)
+			.into_page()
+	}
+
+	fn name() -> &'static str {
+		"NotificationInbox"
+	}
+}
+
+impl IntoPage for NotificationInbox {
+	fn into_page(self) -> Page {
+		self.render()
+	}
+}
+
+/// Count of unread notifications, hidden when there are none.
+pub struct UnreadBadge {
+	center: NotificationCenter,
+}
+
+impl UnreadBadge {
+	/// Creates the unread badge of `center`.
+	pub fn new(center: NotificationCenter) -> Self {
+		Self { center }
+	}
+}
+
+impl Component for UnreadBadge {
+	fn render(&self) -> Page {
+		let unread = self.center.unread_count();
+		Page::reactive(move || {
+			let count = unread.get();
+			let mut badge = PageElement::new("span")
+				.attr("class", "unread-badge")
+				.attr("data-count", count.to_string())
+				.attr("aria-label", format!("{} unread notifications", count));
+			if count == 0 {
+				badge = badge.attr("hidden", "");
+			}
+			badge.child(count.to_string()).into_page()
+		})
+	}
+
+	fn name() -> &'static str {
+		"UnreadBadge"
+	}
+}
+
+impl IntoPage for UnreadBadge {
+	fn into_page(self) -> Page {
+		self.render()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use std::cell::RefCell;
+
+	fn item(id: &str) -> NotificationItem {
+		serde_json::from_value(serde_json::json!({
+			"id": id,
+			"level": "success",
+			"title": format!("Notification {}", id),
+			"body": "",
+			"link": null,
+			"created_at": "2026-01-01T00:00:00Z",
+			"read_at": null,
+		}))
+		.unwrap()
+	}
+
+	#[rstest]
+	fn test_receive_updates_inbox_toasts_and_unread_count() {
+		// Arrange
+		let center = NotificationCenter::new().max_toasts(2);
+
+		// Act
+		center.receive(item("1"));
+		center.receive(item("2"));
+		center.receive(item("3"));
+		center.receive(item("2"));
+
+		// Assert
+		let ids =
+			|items: Vec<NotificationItem>| items.into_iter().map(|n| n.id).collect::<Vec<_>>();
+		assert_eq!(ids(center.items().get()), ["3", "2", "1"]);
+		assert_eq!(ids(center.toasts().get()), ["2", "3"]);
+		assert_eq!(center.unread_count().get(), 3);
+	}
+
+	#[rstest]
+	fn test_mark_read_reports_ids_once() {
+		// Arrange
+		let reported = Rc::new(RefCell::new(Vec::new()));
+		let center = NotificationCenter::new()
+			.with_initial(vec![item("1"), item("2")])
+			.on_read({
+				let reported = Rc::clone(&reported);
+				move |ids| reported.borrow_mut().push(ids.to_vec())
+			});
+
+		// Act
+		center.mark_read("1");
+		center.mark_all_read();
+		center.mark_all_read();
+
+		// Assert
+		assert_eq!(
+			*reported.borrow(),
+			[vec!["1".to_string()], vec!["2".to_string()]]
+		);
+		assert_eq!(center.unread_count().get(), 0);
+	}
+
+	#[rstest]
+	fn test_dismiss_keeps_inbox_entry() {
+		// Arrange
+		let center = NotificationCenter::new();
+		center.receive(item("1"));
+
+		// Act
+		center.dismiss("1");
+
+		// Assert
+		assert!(center.toasts().get().is_empty());
+		assert_eq!(center.items().get().len(), 1);
+	}
+
+	#[rstest]
+	fn test_components_render_unread_state() {
+		// Arrange
+		let center = NotificationCenter::new().with_initial(vec![item("1"), item("2")]);
+		center.mark_read("2");
+
+		// Act
+		let inbox = NotificationInbox::new(center.clone())
+			.render()
+			.render_to_string();
+		let badge = UnreadBadge::new(center).render().render_to_string();
+
+		// Assert
+		assert!(inbox.contains("notification notification-success unread"));
+		assert!(inbox.contains("notification notification-success read"));
+		assert!(badge.contains("data-count=\"1\""));
+		assert!(!badge.contains("hidden"));
+	}
+}
@@ -0,0 +1,734 @@
+//! Pagination, ordering and filtering components for list pages.
+//!
+//! These components render the navigation around a paginated list: page
+//! links ([`Paginator`]), a page-size selector ([`PageSizeSelector`]),
+//! sortable column headers ([`OrderingToggle`]) and a `GET` filter form
+//! ([`FilterForm`]). They all build their links from the current
+//! [`ListQuery`], so choosing a page keeps the active ordering and filters,
+//! while changing the ordering, page size or filters goes back to the first
+//! page.
+//!
+//! Query parameter names follow the list views and paginators: `page`,
+//! `page_size` and `ordering` by default.
+//!
+//! # Example
+//!
+//! ```
+//! use reinhardt_pages::component::{Component, ListQuery, Paginator};
+//!
+//! let query = ListQuery::parse("?page=10&ordering=-created_at");
+//! // `page_range` usually comes from `Page::get_elided_page_range(2, 2)`
+//! let paginator = Paginator::new(10, 20)
+//!     .page_range(vec![Some(1), Some(2), None, Some(9), Some(10), Some(11), None, Some(19), Some(20)])
+//!     .query(query);
+//!
+//! let html = paginator.render().render_to_string();
+//! assert!(html.contains("href=\"?page=11&amp;ordering=-created_at\""));
+//! assert!(html.contains("<span aria-current=\"page\">10</span>"));
+//! ```
+
+use crate::component::{Component, IntoPage, Page, PageElement};
+use crate::tables::SortDirection;
+
+/// Default query parameter for the page number.
+pub const PAGE_PARAM: &str = "page";
+/// Default query parameter for the page size.
+pub const PAGE_SIZE_PARAM: &str = "page_size";
+/// Default query parameter for the ordering.
+pub const ORDERING_PARAM: &str = "ordering";
+
+/// The query parameters of the current list page.
+///
+/// Parameters keep their order; [`ListQuery::url`] replaces values in place
+/// and appends new ones at the end.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListQuery {
+	/// Decoded `(name, value)` pairs.
+	params: Vec<(String, String)>,
+}
+
+impl ListQuery {
+	/// Creates an empty query.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Parses a query string, with or without the leading `?`.
+	pub fn parse(query: &str) -> Self {
+		let decode = |raw: &str| {
+			let raw = raw.replace('+', " ");
+			urlencoding::decode(&raw)
+				.map(|value| value.into_owned())
+				.unwrap_or(raw)
+		};
+		let params = query
+			.trim_start_matches('?')
+			.split('&')
+			.filter(|pair| !pair.is_empty())
+			.map(|pair| {
+				let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+				(decode(name), decode(value))
+			})
+			.collect();
+		Self { params }
+	}
+
+	/// Adds a parameter.
+	pub fn param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+		self.params.push((name.into(), value.into()));
+		self
+	}
+
+	/// Returns the first value of `name`.
+	pub fn get(&self, name: &str) -> Option<&str> {
+		self.params
+			.iter()
+			.find(|(n, _)| n == name)
+			.map(|(_, value)| value.as_str())
+	}
+
+	/// Returns the decoded parameters.
+	pub fn params(&self) -> &[(String, String)] {
+		&self.params
+	}
+
+	/// Builds a relative URL (`?...`) with `changes` applied.
+	///
+	/// A `Some` value replaces every value of the parameter, `None` removes
+	/// it.
+	pub fn url(&self, changes: &[(&str, Option<&str>)]) -> String {
+		let mut params = self.params.clone();
+		for (name, value) in changes {
+			let position = params.iter().position(|(n, _)| n == name);
+			params.retain(|(n, _)| n != name);
+			if let Some(value) = value {
+				let entry = (name.to_string(), value.to_string());
+				match position {
+					Some(position) => params.insert(position.min(params.len()), entry),
+					None => params.push(entry),
+				}
+			}
+		}
+
+		let encoded = params
+			.iter()
+			.map(|(name, value)| {
+				format!(
+					"{}={}",
+					urlencoding::encode(name),
+					urlencoding::encode(value)
+				)
+			})
+			.collect::<Vec<_>>()
+			.join("&");
+		format!("?{}", encoded)
+	}
+
+	/// Renders the parameters not in `exclude` as hidden inputs.
+	///
+	/// Used by `GET` forms so that submitting them keeps the rest of the
+	/// query.
+	pub fn hidden_inputs(&self, exclude: &[&str]) -> Vec<PageElement> {
+		self.params
+			.iter()
+			.filter(|(name, _)| !exclude.contains(&name.as_str()))
+			.map(|(name, value)| {
+				PageElement::new("input")
+					.attr("type", "hidden")
+					.attr("name", name.clone())
+					.attr("value", value.clone())
+			})
+			.collect()
+	}
+}
+
+/// Page links for a paginated list.
+///
+/// Renders previous/next links and one link per page. Pass the result of
+/// `Page::get_elided_page_range` to [`Paginator::page_range`] to elide long
+/// ranges; `None` entries are rendered as an ellipsis. Nothing is rendered
+/// when there is only one page.
+#[derive(Debug, Clone)]
+pub struct Paginator {
+	/// Current page number (1-indexed).
+	number: usize,
+	/// Total number of pages.
+	num_pages: usize,
+	/// Pages to link, `None` for an ellipsis.
+	page_range: Option<Vec<Option<usize>>>,
+	/// Current query parameters.
+	query: ListQuery,
+	/// Query parameter for the page number.
+	page_param: String,
+	/// Labels of the previous and next links.
+	labels: (String, String),
+	/// CSS class of the list.
+	class: Option<String>,
+}
+
+impl Paginator {
+	/// Creates page links for page `number` of `num_pages`.
+	pub fn new(number: usize, num_pages: usize) -> Self {
+		Self {
+			number,
+			num_pages,
+			page_range: None,
+			query: ListQuery::new(),
+			page_param: PAGE_PARAM.to_string(),
+			labels: ("Previous".to_string(), "Next".to_string()),
+			class: None,
+		}
+	}
+
+	/// Sets the pages to link (default: every page).
+	pub fn page_range(mut self, page_range: Vec<Option<usize>>) -> Self {
+		self.page_range = Some(page_range);
+		self
+	}
+
+	/// Sets the current query, which the page links keep.
+	pub fn query(mut self, query: ListQuery) -> Self {
+		self.query = query;
+		self
+	}
+
+	/// Sets the query parameter for the page number.
+	pub fn page_param(mut self, name: impl Into<String>) -> Self {
+		self.page_param = name.into();
+		self
+	}
+
+	/// Sets the labels of the previous and next links.
+	pub fn labels(mut self, previous: impl Into<String>, next: impl Into<String>) -> Self {
+		self.labels = (previous.into(), next.into());
+		self
+	}
+
+	/// Sets the CSS class of the list (default: `pagination`).
+	pub fn class(mut self, class: impl Into<String>) -> Self {
+		self.class = Some(class.into());
+		self
+	}
+
+	/// Returns the URL of `page`.
+	///
+	/// The first page is linked without a page parameter.
+	pub fn page_url(&self, page: usize) -> String {
+		let value = page.to_string();
+		let value = (page > 1).then_some(value.as_str());
+		self.query.url(&[(self.page_param.as_str(), value)])
+	}
+
+	fn step(&self, label: &str, page: Option<usize>, rel: &'static str) -> PageElement {
+		let content = match page {
+			Some(page) => PageElement::new("a")
+				.attr("href", self.page_url(page))
+				.attr("rel", rel)
+				.child(label.to_string()),
+			None => PageElement::new("span")
+				.attr("aria-disabled", "true")
+				.child(label.to_string()),
+		};
+		let class = if page.is_some() { rel } else { "disabled" };
+		PageElement::new("li").attr("class", class).child(content)
+	}
+}
+
+impl Component for Paginator {
+	fn render(&self) -> Page {
+		if self.num_pages <= 1 {
+			return Page::empty();
+		}
+
+		let range = self
+			.page_range
+			.clone()
+			.unwrap_or_else(|| (1..=self.num_pages).map(Some).collect());
+		let pages = range.into_iter().map(|page| match page {
+			Some(page) if page == self.number => {
+				PageElement::new("li").attr("class", "active").child(
+					PageElement::new("span")
+						.attr("aria-current", "page")
+						.child(page.to_string()),
+				)
+			}
+			Some(page) => PageElement::new("li").child(
+				PageElement::new("a")
+					.attr("href", self.page_url(page))
+					.child(page.to_string()),
+			),
+			None => PageElement::new("li")
+				.attr("class", "ellipsis")
+				.child(PageElement::new("span").child("…")),
+		});
+
+		let previous = (self.number > 1).then(|| self.number - 1);
+		let next = (self.number < self.num_pages).then(|| self.number + 1);
+		let list = PageElement::new("ul")
+			.attr(
+				"class",
+				self.class
+					.clone()
+					.unwrap_or_else(|| "pagination".to_string()),
+			)
+			.child(self.step(&self.labels.0, previous, "prev"))
+			.children(pages)
+			.child(self.step(&self.labels.1, next, "next"));
+		PageElement::new("nav")
+			.attr("aria-label", "Pagination")
+			.child(list)
+			.into_page()
+	}
+
+	fn name() -> &'static str {
+		"Paginator"
+	}
+}
+
+impl IntoPage for Paginator {
+	fn into_page(self) -> Page {
+		self.render()
+	}
+}
+
+/// A `GET` form for choosing the number of items per page.
+///
+/// Submitting the form keeps the other query parameters and returns to
+/// the first page.
+#[derive(Debug, Clone)]
+pub struct PageSizeSelector {
+	/// Currently selected page size.
+	current: usize,
+	/// Page sizes to offer.
+	sizes: Vec<usize>,
+	/// Current query parameters.
+	query: ListQuery,
+	/// Query parameter for the page number.
+	page_param: String,
+	/// Query parameter for the page size.
+	page_size_param: String,
+	/// Label of the select and of the submit button.
+	labels: (String, String),
+}
+
+impl PageSizeSelector {
+	/// Creates a selector offering `sizes`, with `current` selected.
+	pub fn new(current: usize, sizes: impl IntoIterator<Item = usize>) -> Self {
+		Self {
+			current,
+			sizes: sizes.into_iter().collect(),
+			query: ListQuery::new(),
+			page_param: PAGE_PARAM.to_string(),
+			page_size_param: PAGE_SIZE_PARAM.to_string(),
+			labels: ("Per page".to_string(), "Apply".to_string()),
+		}
+	}
+
+	/// Sets the current query, which the form keeps.
+	pub fn query(mut self, query: ListQuery) -> Self {
+		self.query = query;
+		self
+	}
+
+	/// Sets the query parameter for the page number.
+	pub fn page_param(mut self, name: impl Into<String>) -> Self {
+		self.page_param = name.into();
+		self
+	}
+
+	/// Sets the query parameter for the page size.
+	pub fn page_size_param(mut self, name: impl Into<String>) -> Self {
+		self.page_size_param = name.into();
+		self
+	}
+
+	/// Sets the labels of the select and of the submit button.
+	pub fn labels(mut self, label: impl Into<String>, submit: impl Into<String>) -> Self {
+		self.labels = (label.into(), submit.into());
+		self
+	}
+}
+
+impl Component for PageSizeSelector {
+	fn render(&self) -> Page {
+		let id = format!("{}-select", self.page_size_param);
+		let options = self.sizes.iter().map(|size| {
+			PageElement::new("option")
+				.attr("value", size.to_string())
+				.bool_attr("selected", *size == self.current)
+				.child(size.to_string())
+		});
+
+		PageElement::new("form")
+			.attr("method", "get")
+			.attr("class", "page-size-selector")
+			.children(
+				self.query
+					.hidden_inputs(&[self.page_param.as_str(), self.page_size_param.as_str()]),
+			)
+			.child(
+				PageElement::new("label")
+					.attr("for", id.clone())
+					.child(self.labels.0.clone()),
+			)
+			.child(
+				PageElement::new("select")
+					.attr("id", id)
+					.attr("name", self.page_size_param.clone())
+					.children(options),
+			)
+			.child(
+				PageElement::new("button")
+					.attr("type", "submit")
+					.child(self.labels.1.clone()),
+			)
+			.into_page()
+	}
+
+	fn name() -> &'static str {
+		"PageSizeSelector"
+	}
+}
+
+impl IntoPage for PageSizeSelector {
+	fn into_page(self) -> Page {
+		self.render()
+	}
+}
+
+/// A sortable column header link.
+///
+/// Links to the list ordered by `field`, ascending first and toggling the
+/// direction when the list is already ordered by it. Only the first field
+/// of a comma-separated ordering is taken into account.
+#[derive(Debug, Clone)]
+pub struct OrderingToggle {
+	/// Field to order by.
+	field: String,
+	/// Link text.
+	label: String,
+	/// Current query parameters.
+	query: ListQuery,
+	/// Query parameter for the page number.
+	page_param: String,
+	/// Query parameter for the ordering.
+	ordering_param: String,
+}
+
+impl OrderingToggle {
+	/// Creates a toggle ordering by `field`.
+	pub fn new(field: impl Into<String>, label: impl Into<String>) -> Self {
+		Self {
+			field: field.into(),
+			label: label.into(),
+			query: ListQuery::new(),
+			page_param: PAGE_PARAM.to_string(),
+			ordering_param: ORDERING_PARAM.to_string(),
+		}
+	}
+
+	/// Sets the current query, which the link keeps.
+	pub fn query(mut self, query: ListQuery) -> Self {
+		self.query = query;
+		self
+	}
+
+	/// Sets the query parameter for the page number.
+	pub fn page_param(mut self, name: impl Into<String>) -> Self {
+		self.page_param = name.into();
+		self
+	}
+
+	/// Sets the query parameter for the ordering.
+	pub fn ordering_param(mut self, name: impl Into<String>) -> Self {
+		self.ordering_param = name.into();
+		self
+	}
+
+	/// Returns the direction the list is currently ordered by this field in.
+	pub fn direction(&self) -> Option<SortDirection> {
+		let ordering = self.query.get(&self.ordering_param)?;
+		let first = ordering.split(',').next()?.trim();
+		let (direction, field) = SortDirection::parse_from_query(first);
+		(field == self.field).then_some(direction)
+	}
+
+	/// Returns the URL the toggle links to.
+	pub fn url(&self) -> String {
+		let ordering = match self.direction() {
+			Some(SortDirection::Ascending) => format!("-{}", self.field),
+			_ => self.field.clone(),
+		};
+		self.query.url(&[
+			(self.ordering_param.as_str(), Some(ordering.as_str())),
+			(self.page_param.as_str(), None),
+		])
+	}
+}
+
+impl Component for OrderingToggle {
+	fn render(&self) -> Page {
+		let mut link = PageElement::new("a").attr("href", self.url());
+		let indicator = match self.direction() {
+			Some(SortDirection::Ascending) => {
+				link = link.attr("class", "sort-link sorted-asc");
+				Some("▲")
+			}
+			Some(SortDirection::Descending) => {
+				link = link.attr("class", "sort-link sorted-desc");
+				Some("▼")
+			}
+			None => {
+				link = link.attr("class", "sort-link");
+				None
+			}
+		};
+
+		link.child(self.label.clone())
+			.child(indicator.map(|indicator| {
+				PageElement::new("span")
+					.attr("aria-hidden", "true")
+					.child(indicator)
+			}))
+			.into_page()
+	}
+
+	fn name() -> &'static str {
+		"OrderingToggle"
+	}
+}
+
+impl IntoPage for OrderingToggle {
+	fn into_page(self) -> Page {
+		self.render()
+	}
+}
+
+/// A `GET` form wrapping the filter inputs of a list.
+///
+/// The inputs are provided by the caller; their parameter names are
+/// declared with [`FilterForm::filter_params`] so the form can keep the
+/// rest of the query (ordering, page size, ...) as hidden inputs and build
+/// a link clearing the filters. Submitting the form returns to the first
+/// page.
+#[derive(Debug, Clone)]
+pub struct FilterForm {
+	/// Filter inputs.
+	fields: Vec<Page>,
+	/// Query parameters set by the filter inputs.
+	filter_params: Vec<String>,
+	/// Current query parameters.
+	query: ListQuery,
+	/// Query parameter for the page number.
+	page_param: String,
+	/// Labels of the submit button and of the clear link.
+	labels: (String, String),
+}
+
+impl FilterForm {
+	/// Creates a filter form for the current query.
+	pub fn new(query: ListQuery) -> Self {
+		Self {
+			fields: Vec::new(),
+			filter_params: Vec::new(),
+			query,
+			page_param: PAGE_PARAM.to_string(),
+			labels: ("Filter".to_string(), "Clear".to_string()),
+		}
+	}
+
+	/// Adds a filter input.
+	pub fn field(mut self, field: impl IntoPage) -> Self {
+		self.fields.push(field.into_page());
+		self
+	}
+
+	/// Declares the query parameters set by the filter inputs.
+	pub fn filter_params<I, S>(mut self, names: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: Into<String>,
+	{
+		self.filter_params.extend(names.into_iter().map(Into::into));
+		self
+	}
+
+	/// Sets the query parameter for the page number.
+	pub fn page_param(mut self, name: impl Into<String>) -> Self {
+		self.page_param = name.into();
+		self
+	}
+
+	/// Sets the labels of the submit button and of the clear link.
+	pub fn labels(mut self, submit: impl Into<String>, clear: impl Into<String>) -> Self {
+		self.labels = (submit.into(), clear.into());
+		self
+	}
+
+	/// Returns the URL with the filters and the page number removed.
+	pub fn clear_url(&self) -> String {
+		let changes: Vec<(&str, Option<&str>)> = self
+			.filter_params
+			.iter()
+			.map(String::as_str)
+			.chain([self.page_param.as_str()])
+			.map(|name| (name, None))
+			.collect();
+		self.query.url(&changes)
+	}
+}
+
+impl Component for FilterForm {
+	fn render(&self) -> Page {
+		let mut exclude: Vec<&str> = self.filter_params.iter().map(String::as_str).collect();
+		exclude.push(self.page_param.as_str());
+
+		PageElement::new("form")
+			.attr("method", "get")
+			.attr("class", "filter-form")
+			.children(self.fields.clone())
+			.children(self.query.hidden_inputs(&exclude))
+			.child(
+				PageElement::new("button")
+					.attr("type", "submit")
+					.child(self.labels.0.clone()),
+			)
+			.child(
+				PageElement::new("a")
+					.attr("href", self.clear_url())
+					.attr("class", "filter-clear")
+					.child(self.labels.1.clone()),
+			)
+			.into_page()
+	}
+
+	fn name() -> &'static str {
+		"FilterForm"
+	}
+}
+
+impl IntoPage for FilterForm {
+	fn into_page(self) -> Page {
+		self.render()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case::replaces_in_place(&[("page", Some("3"))], "?q=a%20b&page=3&ordering=name")]
+	#[case::removes(&[("page", None)], "?q=a%20b&ordering=name")]
+	#[case::appends(&[("page_size", Some("50"))], "?q=a%20b&page=2&ordering=name&page_size=50")]
+	fn test_list_query_url(#[case] changes: &[(&str, Option<&str>)], #[case] expected: &str) {
+		// Arrange
+		let query = ListQuery::parse("?q=a+b&page=2&ordering=name");
+
+		// Act
+		let url = query.url(changes);
+
+		// Assert
+		assert_eq!(url, expected);
+	}
+
+	#[rstest]
+	fn test_paginator_renders_elided_range() {
+		// Arrange
+		let paginator = Paginator::new(1, 20).page_range(vec![Some(1), Some(2), None, Some(20)]);
+
+		// Act
+		let html = paginator.render().render_to_string();
+
+		// Assert
+		assert!(
+			html.contains(
+				"<li class=\"disabled\"><span aria-disabled=\"true\">Previous</span></li>"
+			)
+		);
+		assert!(html.contains("<li class=\"ellipsis\"><span>…</span></li>"));
+		assert!(html.contains("<a href=\"?page=20\">20</a>"));
+		assert!(html.contains("<a href=\"?page=2\" rel=\"next\">Next</a>"));
+	}
+
+	#[rstest]
+	fn test_paginator_links_first_page_without_page_param() {
+		// Arrange
+		let paginator = Paginator::new(2, 3).query(ListQuery::parse("page=2&q=x"));
+
+		// Act
+		let url = paginator.page_url(1);
+
+		// Assert
+		assert_eq!(url, "?q=x");
+	}
+
+	#[rstest]
+	fn test_paginator_single_page_renders_nothing() {
+		// Act
+		let html = Paginator::new(1, 1).render().render_to_string();
+
+		// Assert
+		assert!(html.is_empty());
+	}
+
+	#[rstest]
+	fn test_page_size_selector_keeps_query_except_page() {
+		// Arrange
+		let selector = PageSizeSelector::new(25, [10, 25, 50])
+			.query(ListQuery::parse("page=4&page_size=25&q=x"));
+
+		// Act
+		let html = selector.render().render_to_string();
+
+		// Assert
+		assert!(html.contains("<input type=\"hidden\" name=\"q\" value=\"x\" />"));
+		assert!(!html.contains("name=\"page\""));
+		assert!(html.contains("<option value=\"25\" selected=\"selected\">25</option>"));
+		assert!(html.contains("<option value=\"50\">50</option>"));
+	}
+
+	#[rstest]
+	#[case::unordered("", None, "?ordering=name")]
+	#[case::ascending(
+		"ordering=name&page=3",
+		Some(SortDirection::Ascending),
+		"?ordering=-name"
+	)]
+	#[case::descending("ordering=-name,id", Some(SortDirection::Descending), "?ordering=name")]
+	#[case::other_field("ordering=-id", None, "?ordering=name")]
+	fn test_ordering_toggle(
+		#[case] query: &str,
+		#[case] direction: Option<SortDirection>,
+		#[case] url: &str,
+	) {
+		// Arrange
+		let toggle = OrderingToggle::new("name", "Name").query(ListQuery::parse(query));
+
+		// Act & Assert
+		assert_eq!(toggle.direction(), direction);
+		assert_eq!(toggle.url(), url);
+	}
+
+	#[rstest]
+	fn test_filter_form_keeps_ordering_and_clears_filters() {
+		// Arrange
+		let form = FilterForm::new(ListQuery::parse("q=old&ordering=-id&page=2"))
+			.filter_params(["q"])
+			.field(
+				PageElement::new("input")
+					.attr("name", "q")
+					.attr("value", "old"),
+			);
+
+		// Act
+		let html = form.render().render_to_string();
+
+		// Assert
+		assert!(html.contains("<input type=\"hidden\" name=\"ordering\" value=\"-id\" />"));
+		assert!(!html.contains("type=\"hidden\" name=\"q\""));
+		assert!(!html.contains("name=\"page\""));
+		assert_eq!(form.clear_url(), "?ordering=-id");
+	}
+}
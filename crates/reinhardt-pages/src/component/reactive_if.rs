@@ -80,6 +80,55 @@ pub fn store_reactive_node<T: 'static>(node: T) {
 		.push(Box::new(node));
 }
 
+/// Owner of the reactive nodes stored while mounting one view.
+///
+/// Nodes stored inside [`ReactiveNodeScope::enter`] are kept alive by this
+/// scope rather than by the global store, and are released when the scope is
+/// dropped. Nodes of other mounted views are left untouched.
+#[cfg(wasm)]
+pub struct ReactiveNodeScope {
+	store: ReactiveNodeStore,
+}
+
+#[cfg(wasm)]
+impl ReactiveNodeScope {
+	/// Creates an empty scope.
+	pub fn new() -> Self {
+		Self {
+			store: new_reactive_node_store(),
+		}
+	}
+
+	/// Runs `f`, storing every reactive node it creates in this scope.
+	pub fn enter<R>(&self, f: impl FnOnce() -> R) -> R {
+		with_reactive_node_store(&self.store, f)
+	}
+
+	/// Number of reactive nodes held by this scope.
+	pub fn len(&self) -> usize {
+		self.store.borrow().len()
+	}
+
+	/// Returns true when this scope holds no reactive nodes.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+#[cfg(wasm)]
+impl Default for ReactiveNodeScope {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(wasm)]
+impl Drop for ReactiveNodeScope {
+	fn drop(&mut self) {
+		clear_reactive_node_store(&self.store);
+	}
+}
+
 /// Cleanup function to release all reactive nodes.
 ///
 /// This should be called when the application is being torn down or
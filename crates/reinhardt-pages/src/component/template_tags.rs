@@ -0,0 +1,302 @@
+//! Tera functions rendering the list navigation components.
+//!
+//! [`register_functions`] exposes [`Paginator`], [`PageSizeSelector`],
+//! [`OrderingToggle`] and [`Breadcrumbs`] to server-rendered templates, so
+//! templates and `page!` components share the same markup.
+//! [`register_filters`] adds the `sanitize` filter, which cleans untrusted
+//! HTML the same way as [`Page::sanitized_html`](crate::component::Page::sanitized_html).
+
+use std::collections::HashMap;
+
+use reinhardt_core::security::SanitizePolicy;
+use tera::Value;
+
+use super::breadcrumbs::Breadcrumbs;
+use super::pagination::{ListQuery, OrderingToggle, PageSizeSelector, Paginator};
+use crate::component::Component;
+
+/// A Tera function whose HTML output is not escaped.
+struct SafeFunction<F>(F);
+
+impl<F> tera::Function for SafeFunction<F>
+where
+	F: Fn(&HashMap<String, Value>) -> tera::Result<String> + Send + Sync,
+{
+	fn call(&self, args: &HashMap<String, Value>) -> tera::Result<Value> {
+		(self.0)(args).map(Value::String)
+	}
+
+	fn is_safe(&self) -> bool {
+		true
+	}
+}
+
+/// A Tera filter whose HTML output is not escaped.
+struct SafeFilter<F>(F);
+
+impl<F> tera::Filter for SafeFilter<F>
+where
+	F: Fn(&Value, &HashMap<String, Value>) -> tera::Result<String> + Send + Sync,
+{
+	fn filter(&self, value: &Value, args: &HashMap<String, Value>) -> tera::Result<Value> {
+		(self.0)(value, args).map(Value::String)
+	}
+
+	fn is_safe(&self) -> bool {
+		true
+	}
+}
+
+fn usize_arg(function: &str, args: &HashMap<String, Value>, name: &str) -> tera::Result<usize> {
+	args.get(name)
+		.and_then(Value::as_u64)
+		.map(|value| value as usize)
+		.ok_or_else(|| {
+			tera::Error::msg(format!(
+				"function `{}` expects a non-negative integer `{}` argument",
+				function, name
+			))
+		})
+}
+
+fn str_arg<'a>(args: &'a HashMap<String, Value>, name: &str) -> Option<&'a str> {
+	args.get(name).and_then(Value::as_str)
+}
+
+fn query_arg(args: &HashMap<String, Value>) -> ListQuery {
+	str_arg(args, "query")
+		.map(ListQuery::parse)
+		.unwrap_or_default()
+}
+
+/// Registers the list navigation functions on `tera`.
+///
+/// | Function | Arguments |
+/// |---|---|
+/// | `paginator` | `number`, `num_pages`, optional `page_range` (integers, `null` for an ellipsis), `query`, `page_param` |
+/// | `page_size_selector` | `current`, `sizes`, optional `query`, `page_param`, `page_size_param` |
+/// | `ordering_toggle` | `field`, `label`, optional `query`, `ordering_param`, `page_param` |
+/// | `breadcrumbs` | `items` (objects with `label` and optional `url`) |
+///
+/// `query` is the raw query string of the current request.
+///
+/// # Example
+///
+/// ```
+/// let mut tera = tera::Tera::default();
+/// reinhardt_pages::component::register_functions(&mut tera);
+/// tera.add_raw_template(
+///     "list.html",
+///     "{{ paginator(number=2, num_pages=3, query=\"q=rust\") }}",
+/// )
+/// .unwrap();
+///
+/// let html = tera.render("list.html", &tera::Context::new()).unwrap();
+/// assert!(html.contains("<a href=\"?q=rust&amp;page=3\" rel=\"next\">Next</a>"));
+/// ```
+pub fn register_functions(tera: &mut tera::Tera) {
+	tera.register_function(
+		"paginator",
+		SafeFunction(|args: &HashMap<String, Value>| -> tera::Result<String> {
+			let mut paginator = Paginator::new(
+				usize_arg("paginator", args, "number")?,
+				usize_arg("paginator", args, "num_pages")?,
+			)
+			.query(query_arg(args));
+			if let Some(range) = args.get("page_range").and_then(Value::as_array) {
+				paginator = paginator.page_range(
+					range
+						.iter()
+						.map(|page| page.as_u64().map(|page| page as usize))
+						.collect(),
+				);
+			}
+			if let Some(page_param) = str_arg(args, "page_param") {
+				paginator = paginator.page_param(page_param);
+			}
+			Ok(paginator.render().render_to_string())
+		}),
+	);
+
+	tera.register_function(
+		"page_size_selector",
+		SafeFunction(|args: &HashMap<String, Value>| -> tera::Result<String> {
+			let sizes = args
+				.get("sizes")
+				.and_then(Value::as_array)
+				.ok_or_else(|| {
+					tera::Error::msg("function `page_size_selector` expects a `sizes` array")
+				})?
+				.iter()
+				.filter_map(|size| size.as_u64().map(|size| size as usize));
+			let mut selector =
+				PageSizeSelector::new(usize_arg("page_size_selector", args, "current")?, sizes)
+					.query(query_arg(args));
+			if let Some(page_param) = str_arg(args, "page_param") {
+				selector = selector.page_param(page_param);
+			}
+			if let Some(page_size_param) = str_arg(args, "page_size_param") {
+				selector = selector.page_size_param(page_size_param);
+			}
+			Ok(selector.render().render_to_string())
+		}),
+	);
+
+	tera.register_function(
+		"ordering_toggle",
+		SafeFunction(|args: &HashMap<String, Value>| -> tera::Result<String> {
+			let field = str_arg(args, "field").ok_or_else(|| {
+				tera::Error::msg("function `ordering_toggle` expects a `field` argument")
+			})?;
+			let label = str_arg(args, "label").unwrap_or(field);
+			let mut toggle = OrderingToggle::new(field, label).query(query_arg(args));
+			if let Some(ordering_param) = str_arg(args, "ordering_param") {
+				toggle = toggle.ordering_param(ordering_param);
+			}
+			if let Some(page_param) = str_arg(args, "page_param") {
+				toggle = toggle.page_param(page_param);
+			}
+			Ok(toggle.render().render_to_string())
+		}),
+	);
+
+	tera.register_function(
+		"breadcrumbs",
+		SafeFunction(|args: &HashMap<String, Value>| -> tera::Result<String> {
+			let items = args.get("items").and_then(Value::as_array).ok_or_else(|| {
+				tera::Error::msg("function `breadcrumbs` expects an `items` array")
+			})?;
+			let mut trail = Breadcrumbs::new();
+			for item in items {
+				let label = item.get("label").and_then(Value::as_str).ok_or_else(|| {
+					tera::Error::msg("function `breadcrumbs` expects items with a `label`")
+				})?;
+				trail = match item.get("url").and_then(Value::as_str) {
+					Some(url) => trail.item(label, url),
+					None => trail.current(label),
+				};
+			}
+			Ok(trail.render().render_to_string())
+		}),
+	);
+}
+
+/// Registers the HTML filters on `tera`.
+///
+/// `sanitize` cleans untrusted HTML with an allowlist policy and marks the
+/// result safe, so it is not escaped again. The optional `policy` argument
+/// names a built-in policy: `strict`, `basic` (the default) or `rich_text`.
+///
+/// # Example
+///
+/// ```
+/// let mut tera = tera::Tera::default();
+/// reinhardt_pages::component::register_filters(&mut tera);
+/// tera.add_raw_template("comment.html", "{{ body | sanitize }}")
+///     .unwrap();
+///
+/// let mut context = tera::Context::new();
+/// context.insert("body", "<b>Hi</b><script>alert(1)</script>");
+/// assert_eq!(tera.render("comment.html", &context).unwrap(), "<b>Hi</b>");
+/// ```
+pub fn register_filters(tera: &mut tera::Tera) {
+	tera.register_filter(
+		"sanitize",
+		SafeFilter(
+			|value: &Value, args: &HashMap<String, Value>| -> tera::Result<String> {
+				let html = value
+					.as_str()
+					.ok_or_else(|| tera::Error::msg("filter `sanitize` expects a string"))?;
+				let policy = match str_arg(args, "policy") {
+					Some(name) => SanitizePolicy::named(name).ok_or_else(|| {
+						tera::Error::msg(format!(
+							"filter `sanitize` got unknown policy `{}`, expected `strict`, `basic` or `rich_text`",
+							name
+						))
+					})?,
+					None => SanitizePolicy::basic(),
+				};
+				Ok(policy.clean(html))
+			},
+		),
+	);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn render(template: &str, context: &tera::Context) -> String {
+		let mut tera = tera::Tera::default();
+		register_functions(&mut tera);
+		register_filters(&mut tera);
+		tera.add_raw_template("t.html", template).unwrap();
+		tera.render("t.html", context).unwrap()
+	}
+
+	#[rstest]
+	fn test_paginator_function_uses_elided_range() {
+		// Arrange
+		let mut context = tera::Context::new();
+		context.insert("range", &vec![Some(1), None, Some(9), Some(10)]);
+
+		// Act
+		let html = render(
+			"{{ paginator(number=10, num_pages=10, page_range=range) }}",
+			&context,
+		);
+
+		// Assert
+		assert!(html.contains("<li class=\"ellipsis\"><span>…</span></li>"));
+		assert!(html.contains("<a href=\"?page=9\" rel=\"prev\">Previous</a>"));
+	}
+
+	#[rstest]
+	fn test_ordering_toggle_function_is_not_escaped() {
+		// Act
+		let html = render(
+			"{{ ordering_toggle(field=\"name\", label=\"Name\", query=\"ordering=name\") }}",
+			&tera::Context::new(),
+		);
+
+		// Assert
+		assert!(html.starts_with("<a href=\"?ordering=-name\""), "{}", html);
+	}
+
+	#[rstest]
+	#[case::default_policy("{{ body | sanitize }}", "<b>x</b>")]
+	#[case::named_policy("{{ body | sanitize(policy=\"rich_text\") }}", "<h2>T</h2><b>x</b>")]
+	#[case::strict_policy("{{ body | sanitize(policy=\"strict\") }}", "Tx")]
+	fn test_sanitize_filter(#[case] template: &str, #[case] expected: &str) {
+		// Arrange
+		let mut context = tera::Context::new();
+		context.insert(
+			"body",
+			"<h2>T</h2><b onclick=\"x()\">x</b><script>y</script>",
+		);
+
+		// Act
+		let html = render(template, &context);
+
+		// Assert
+		assert_eq!(html, expected);
+	}
+
+	#[rstest]
+	fn test_breadcrumbs_function() {
+		// Arrange
+		let mut context = tera::Context::new();
+		context.insert(
+			"trail",
+			&serde_json::json!([{"label": "Home", "url": "/"}, {"label": "Articles"}]),
+		);
+
+		// Act
+		let html = render("{{ breadcrumbs(items=trail) }}", &context);
+
+		// Assert
+		assert!(html.contains("<a href=\"/\">Home</a>"));
+		assert!(html.contains("<span aria-current=\"page\">Articles</span>"));
+	}
+}
@@ -1,6 +1,7 @@
 //! Component trait definition.
 
 use super::into_page::{IntoPage, Page};
+use crate::ssr::HydrationTrigger;
 
 /// Trait for reusable UI components.
 ///
@@ -37,6 +38,18 @@ pub trait Component: 'static {
 	fn name() -> &'static str
 	where
 		Self: Sized;
+
+	/// When the component's SSR output is hydrated on the client.
+	///
+	/// Return [`HydrationTrigger::Visible`], [`HydrationTrigger::Idle`] or
+	/// [`HydrationTrigger::Interaction`] to render the component as a lazily
+	/// hydrated island, e.g. for below-the-fold widgets.
+	fn hydration_trigger() -> HydrationTrigger
+	where
+		Self: Sized,
+	{
+		HydrationTrigger::Load
+	}
 }
 
 // Note: Blanket implementation of IntoPage for Component was removed
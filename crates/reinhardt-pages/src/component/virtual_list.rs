@@ -0,0 +1,319 @@
+//! Virtualized rendering of long feeds.
+//!
+//! [`VirtualList`] renders a [`ListFeed`] inside a fixed-height scroll
+//! container, but only the rows in view (plus a few overscan rows) are in
+//! the DOM. Spacer elements above and below the rendered rows keep the
+//! scrollbar sized for the whole list. Rows must have a fixed height.
+//!
+//! The next chunk is loaded once the viewport is within one viewport height
+//! of the end of the loaded rows.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use reinhardt_pages::component::infinite_list::ListFeed;
+//! use reinhardt_pages::component::virtual_list::VirtualList;
+//!
+//! VirtualList::new(feed, 48.0, 600.0, |log: &LogLine| Page::text(log.message.clone()))
+//!     .overscan(5)
+//!     .into_page()
+//! ```
+
+use super::infinite_list::{ListFeed, StatusViews, next_list_id};
+use crate::component::{Component, IntoPage, Page, PageElement};
+use crate::reactive::Signal;
+use std::rc::Rc;
+
+/// Rows rendered above and below the viewport by default.
+const DEFAULT_OVERSCAN: usize = 3;
+
+/// Returns the half-open range of rows to render.
+///
+/// `total` rows of `row_height` pixels are shown in a viewport of
+/// `viewport_height` pixels scrolled down by `scroll_top` pixels; `overscan`
+/// extra rows are kept on each side.
+pub fn visible_range(
+	total: usize,
+	row_height: f64,
+	viewport_height: f64,
+	scroll_top: f64,
+	overscan: usize,
+) -> (usize, usize) {
+	if total == 0 || row_height <= 0.0 {
+		return (0, 0);
+	}
+	let first = (scroll_top.max(0.0) / row_height).floor() as usize;
+	let visible = (viewport_height.max(0.0) / row_height).ceil() as usize + 1;
+	let start = first.saturating_sub(overscan).min(total);
+	let end = first
+		.saturating_add(visible)
+		.saturating_add(overscan)
+		.min(total);
+	(start, end)
+}
+
+/// A fixed-height scroll container rendering only the visible rows of a
+/// [`ListFeed`].
+pub struct VirtualList<T: Clone + 'static> {
+	feed: ListFeed<T>,
+	row: Rc<dyn Fn(&T) -> Page>,
+	row_height: f64,
+	viewport_height: f64,
+	overscan: usize,
+	views: StatusViews,
+	scroll_top: Signal<f64>,
+	id: String,
+}
+
+impl<T: Clone + 'static> Clone for VirtualList<T> {
+	fn clone(&self) -> Self {
+		Self {
+			feed: self.feed.clone(),
+			row: Rc::clone(&self.row),
+			row_height: self.row_height,
+			viewport_height: self.viewport_height,
+			overscan: self.overscan,
+			views: self.views.clone(),
+			scroll_top: self.scroll_top.clone(),
+			id: self.id.clone(),
+		}
+	}
+}
+
+impl<T: Clone + 'static> VirtualList<T> {
+	/// Creates a list over `feed` with rows of `row_height` pixels in a
+	/// viewport of `viewport_height` pixels, rendering each item with `row`.
+	pub fn new(
+		feed: ListFeed<T>,
+		row_height: f64,
+		viewport_height: f64,
+		row: impl Fn(&T) -> Page + 'static,
+	) -> Self {
+		Self {
+			feed,
+			row: Rc::new(row),
+			row_height,
+			viewport_height,
+			overscan: DEFAULT_OVERSCAN,
+			views: StatusViews::default(),
+			scroll_top: Signal::new(0.0),
+			id: next_list_id("virtual-list"),
+		}
+	}
+
+	/// Sets the number of rows rendered beyond each edge of the viewport.
+	pub fn overscan(mut self, rows: usize) -> Self {
+		self.overscan = rows;
+		self
+	}
+
+	/// Sets the view shown while a chunk loads.
+	pub fn loading(mut self, view: impl Fn() -> Page + 'static) -> Self {
+		self.views.loading = Rc::new(view);
+		self
+	}
+
+	/// Sets the view shown when loading a chunk failed.
+	pub fn error(mut self, view: impl Fn(&str) -> Page + 'static) -> Self {
+		self.views.error = Rc::new(view);
+		self
+	}
+
+	/// Sets the view shown after the last chunk.
+	pub fn end(mut self, view: impl Fn() -> Page + 'static) -> Self {
+		self.views.end = Rc::new(view);
+		self
+	}
+
+	/// Returns the feed backing the list.
+	pub fn feed(&self) -> &ListFeed<T> {
+		&self.feed
+	}
+
+	/// Returns `true` when the viewport is within one viewport height of the
+	/// end of the loaded rows.
+	#[cfg(any(wasm, test))]
+	fn near_end(&self, scroll_top: f64) -> bool {
+		let loaded = self.feed.items().get_untracked().len() as f64 * self.row_height;
+		scroll_top + 2.0 * self.viewport_height >= loaded
+	}
+
+	/// Loads the first chunk once the list is mounted, if the feed has not
+	/// started yet.
+	#[cfg(wasm)]
+	fn load_initial(&self) {
+		use super::infinite_list::FeedStatus;
+
+		let feed = self.feed.clone();
+		crate::platform::spawn_task(async move {
+			crate::platform::defer_yield().await;
+			if feed.status().get_untracked() == FeedStatus::Idle
+				&& feed.items().get_untracked().is_empty()
+			{
+				feed.load_more();
+			}
+		});
+	}
+}
+
+impl<T: Clone + 'static> Component for VirtualList<T> {
+	fn render(&self) -> Page {
+		let items = self.feed.items();
+		let row = Rc::clone(&self.row);
+		let scroll_top = self.scroll_top.clone();
+		let (row_height, viewport_height, overscan) =
+			(self.row_height, self.viewport_height, self.overscan);
+		let rows = Page::reactive(move || {
+			let items = items.get();
+			let (start, end) = visible_range(
+				items.len(),
+				row_height,
+				viewport_height,
+				scroll_top.get(),
+				overscan,
+			);
+			let spacer = |rows: usize| {
+				PageElement::new("div")
+					.attr("class", "virtual-list-spacer")
+					.attr("aria-hidden", "true")
+					.attr("style", format!("height: {}px", rows as f64 * row_height))
+					.into_page()
+			};
+			let visible = items[start..end].iter().enumerate().map(|(offset, item)| {
+				PageElement::new("div")
+					.attr("class", "virtual-list-row")
+					.attr("data-index", (start + offset).to_string())
+					.attr("style", format!("height: {}px", row_height))
+					.child(row(item))
+					.into_page()
+			});
+			Page::fragment(
+				std::iter::once(spacer(start))
+					.chain(visible)
+					.chain(std::iter::once(spacer(items.len() - end))),
+			)
+		});
+
+		let status = self.feed.status();
+		let views = self.views.clone();
+		let status_view = Page::reactive(move || views.render(&status.get()));
+
+		#[cfg(wasm)]
+		self.load_initial();
+
+		let el = PageElement::new("div")
+			.attr("id", self.id.clone())
+			.attr("class", "virtual-list")
+			.attr(
+				"style",
+				format!("overflow-y: auto; height: {}px", self.viewport_height),
+			);
+		#[cfg(wasm)]
+		let el = {
+			use wasm_bindgen::JsCast;
+
+			let list = self.clone();
+			el.listener("scroll", move |event: web_sys::Event| {
+				let Some(target) = event
+					.target()
+					.and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+				else {
+					return;
+				};
+				let top = f64::from(target.scroll_top());
+				list.scroll_top.set(top);
+				if list.near_end(top) {
+					list.feed.load_more();
+				}
+			})
+		};
+		el.child(rows).child(status_view).into_page()
+	}
+
+	fn name() -> &'static str {
+		"VirtualList"
+	}
+}
+
+impl<T: Clone + 'static> IntoPage for VirtualList<T> {
+	fn into_page(self) -> Page {
+		self.render()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::component::infinite_list::ListChunk;
+	use rstest::rstest;
+
+	fn feed(items: Vec<u32>) -> ListFeed<u32> {
+		ListFeed::new(|_cursor: Option<String>| async {
+			Ok::<_, String>(ListChunk::last(Vec::new()))
+		})
+		.with_initial(ListChunk::new(items, Some("c2".to_string())))
+	}
+
+	#[rstest]
+	#[case(100, 0.0, (0, 6))]
+	#[case(100, 200.0, (10, 16))]
+	#[case(100, 1900.0, (95, 100))]
+	#[case(100, 5000.0, (100, 100))]
+	#[case(0, 0.0, (0, 0))]
+	fn test_visible_range(
+		#[case] total: usize,
+		#[case] scroll_top: f64,
+		#[case] expected: (usize, usize),
+	) {
+		// Act
+		let range = visible_range(total, 20.0, 100.0, scroll_top, 0);
+
+		// Assert
+		assert_eq!(range, expected);
+	}
+
+	#[rstest]
+	fn test_visible_range_includes_overscan() {
+		// Act
+		let range = visible_range(100, 20.0, 100.0, 400.0, 3);
+
+		// Assert
+		assert_eq!(range, (17, 29));
+	}
+
+	#[rstest]
+	fn test_virtual_list_renders_visible_rows_between_spacers() {
+		// Arrange
+		let list = VirtualList::new(feed((0..50).collect()), 20.0, 100.0, |n: &u32| {
+			Page::text(format!("[row {}]", n))
+		})
+		.overscan(2);
+
+		// Act
+		let html = list.render().render_to_string();
+
+		// Assert
+		assert!(html.contains("[row 0]"));
+		assert!(html.contains("[row 7]"));
+		assert!(!html.contains("[row 8]"));
+		assert!(html.contains("height: 0px"));
+		assert!(html.contains("height: 840px"));
+		assert!(html.contains("overflow-y: auto; height: 100px"));
+	}
+
+	#[rstest]
+	fn test_virtual_list_loads_near_end() {
+		// Arrange
+		let list = VirtualList::new(feed((0..50).collect()), 20.0, 100.0, |n: &u32| {
+			Page::text(n.to_string())
+		});
+
+		// Act
+		let far = list.near_end(0.0);
+		let near = list.near_end(800.0);
+
+		// Assert
+		assert!(!far);
+		assert!(near);
+	}
+}
@@ -67,15 +67,41 @@ impl FetchResponse {
 /// Requests use the browser default-equivalent `same-origin` credentials mode.
 /// Use [`request_with_credentials`] when a generated client must explicitly
 /// include cross-origin credentials.
+///
+/// Unsafe methods (anything but GET, HEAD, OPTIONS and TRACE) automatically
+/// carry the `X-CSRFToken` header when a CSRF token is available and the
+/// caller did not set one.
 pub async fn request(
 	method: &str,
 	url: &str,
 	body: Option<&str>,
 	headers: Vec<(String, String)>,
 ) -> Result<FetchResponse, ServerFnError> {
+	let headers = with_csrf_header(method, headers, crate::csrf::get_csrf_token);
 	request_with_credentials(method, url, body, headers, FetchCredentials::default()).await
 }
 
+/// Adds the CSRF header for unsafe methods unless the caller already set it.
+pub(crate) fn with_csrf_header(
+	method: &str,
+	mut headers: Vec<(String, String)>,
+	token: impl FnOnce() -> Option<String>,
+) -> Vec<(String, String)> {
+	let is_safe = ["GET", "HEAD", "OPTIONS", "TRACE"]
+		.iter()
+		.any(|safe| method.eq_ignore_ascii_case(safe));
+	let has_header = headers
+		.iter()
+		.any(|(name, _)| name.eq_ignore_ascii_case(crate::csrf::CSRF_HEADER_NAME));
+	if !is_safe
+		&& !has_header
+		&& let Some(token) = token()
+	{
+		headers.push((crate::csrf::CSRF_HEADER_NAME.to_string(), token));
+	}
+	headers
+}
+
 /// Sends an HTTP request through the browser Fetch API with an explicit credentials mode.
 #[cfg(wasm)]
 pub async fn request_with_credentials(
@@ -143,16 +169,43 @@ pub async fn request_with_credentials(
 }
 
 #[cfg(wasm)]
-fn js_error_message(value: wasm_bindgen::JsValue) -> String {
+pub(crate) fn js_error_message(value: wasm_bindgen::JsValue) -> String {
 	value.as_string().unwrap_or_else(|| format!("{value:?}"))
 }
 
 #[cfg(test)]
 mod tests {
-	use super::FetchCredentials;
+	use super::{FetchCredentials, with_csrf_header};
+	use rstest::rstest;
 
 	#[test]
 	fn default_credentials_mode_is_same_origin() {
 		assert_eq!(FetchCredentials::default(), FetchCredentials::SameOrigin);
 	}
+
+	#[rstest]
+	#[case::post("POST", vec![], true)]
+	#[case::lowercase_delete("delete", vec![], true)]
+	#[case::get("GET", vec![], false)]
+	#[case::caller_header(
+		"POST",
+		vec![("x-csrftoken".to_string(), "explicit".to_string())],
+		false
+	)]
+	fn csrf_header_is_added_only_for_unsafe_methods(
+		#[case] method: &str,
+		#[case] headers: Vec<(String, String)>,
+		#[case] injected: bool,
+	) {
+		// Act
+		let headers = with_csrf_header(method, headers, || Some("token".to_string()));
+
+		// Assert
+		assert_eq!(
+			headers
+				.iter()
+				.any(|(name, value)| name == "X-CSRFToken" && value == "token"),
+			injected
+		);
+	}
 }
@@ -70,7 +70,7 @@ pub use reinhardt_forms::wasm_compat::{FieldMetadata, FormMetadata};
 
 // Server-side only exports
 #[cfg(native)]
-pub use media::{Media, MediaDefiningWidget};
+pub use media::{Media, MediaDefiningWidget, media_head};
 #[cfg(native)]
 pub use rendering::{
 	BootstrapRenderer, CheckboxInput, CheckboxSelectMultiple, CssFramework, DateInput, FileInput,
@@ -1,7 +1,7 @@
 //! Static Metadata Types for form! Macro Generated Code
 //!
 //! This module is always available (on both WASM and server) because it only
-//! depends on `serde`. It provides metadata structures specifically designed
+//! depends on `serde` and the target-independent [`Media`] type. It provides metadata structures specifically designed
 //! for the form! macro.
 //!
 //! Unlike `FormMetadata` from `reinhardt-forms::wasm_compat` which is extracted from
@@ -39,6 +39,8 @@
 
 use serde::{Deserialize, Serialize};
 
+pub use reinhardt_core::form_media::Media;
+
 /// Static form metadata for macro-generated forms.
 ///
 /// This structure contains all information needed to render a form
@@ -195,6 +197,50 @@ impl StaticFieldMetadata {
 	}
 }
 
+/// Append the assets of `media` to `document.head`, skipping any stylesheet
+/// or script that is already present (e.g. because SSR emitted it)
+///
+/// Scripts are inserted with `async = false`, so they execute in declaration
+/// order like parser-inserted scripts.
+#[cfg(wasm)]
+pub fn load_media(media: &Media) {
+	let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+		return;
+	};
+	let Some(head) = document.head() else {
+		return;
+	};
+
+	let present = |tag: &str, attr: &str, value: &str| {
+		let elements = document.get_elements_by_tag_name(tag);
+		(0..elements.length())
+			.filter_map(|i| elements.item(i))
+			.any(|element| element.get_attribute(attr).as_deref() == Some(value))
+	};
+
+	for href in media.css() {
+		if present("link", "href", href) {
+			continue;
+		}
+		if let Ok(link) = document.create_element("link") {
+			let _ = link.set_attribute("rel", "stylesheet");
+			let _ = link.set_attribute("href", href);
+			let _ = head.append_child(&link);
+		}
+	}
+
+	for src in media.js() {
+		if present("script", "src", src) {
+			continue;
+		}
+		if let Ok(script) = document.create_element("script") {
+			let _ = js_sys::Reflect::set(&script, &"async".into(), &false.into());
+			let _ = script.set_attribute("src", src);
+			let _ = head.append_child(&script);
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -10,6 +10,9 @@ use std::rc::{Rc, Weak};
 
 use crate::reactive::{Effect, EffectTiming, Signal};
 
+/// Problem type of server validation errors rendered as problem+json.
+const VALIDATION_PROBLEM_TYPE: &str = "urn:reinhardt:problem:validation";
+
 /// Default reset behavior when runtime dependencies change.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ResetOnDeps {
@@ -348,6 +351,51 @@ where
 			&& self.details.path_errors.is_empty()
 			&& self.form_error.is_none()
 	}
+
+	/// Builds a validation error from a server problem+json validation body.
+	///
+	/// Reads the `errors` and `non_field_errors` members written by the
+	/// server-side `ValidationErrors::to_problem_json`. The first message of
+	/// each field is kept; messages of fields unknown to `field_by_name`,
+	/// and non-field messages, become the form-level error. Returns `None`
+	/// when `problem` is not a validation problem.
+	pub fn from_problem_json(
+		problem: &serde_json::Value,
+		field_by_name: impl Fn(&str) -> Option<Field>,
+	) -> Option<Self> {
+		if problem.get("type").and_then(serde_json::Value::as_str) != Some(VALIDATION_PROBLEM_TYPE)
+		{
+			return None;
+		}
+
+		fn first_message(entries: &serde_json::Value) -> Option<&str> {
+			entries
+				.as_array()?
+				.iter()
+				.find_map(|entry| entry.get("message")?.as_str())
+		}
+
+		let mut error = Self::new();
+		let mut form_messages = Vec::new();
+		if let Some(fields) = problem.get("errors").and_then(serde_json::Value::as_object) {
+			for (name, entries) in fields {
+				let Some(message) = first_message(entries) else {
+					continue;
+				};
+				match field_by_name(name) {
+					Some(field) => error.add_field_error(field, message),
+					None => form_messages.push(message.to_string()),
+				}
+			}
+		}
+		if let Some(message) = problem.get("non_field_errors").and_then(first_message) {
+			form_messages.insert(0, message.to_string());
+		}
+		if let Some(message) = form_messages.into_iter().next() {
+			error.set_form_error(message);
+		}
+		Some(error)
+	}
 }
 
 impl<Field> Default for FormValidationError<Field>
@@ -576,6 +624,11 @@ pub trait FormRuntimeSource: Clone + 'static {
 
 	/// Returns generated field tokens in source order.
 	fn runtime_fields(&self) -> &'static [Self::Field];
+
+	/// Returns the generated field token for a field's source name.
+	fn runtime_field_by_name(&self, _name: &str) -> Option<Self::Field> {
+		None
+	}
 }
 
 /// Trait implemented by `form!` generated forms that expose runtime collections.
@@ -1260,6 +1313,23 @@ where
 		self.sync_first_error();
 	}
 
+	/// Shows validation errors returned by the server.
+	///
+	/// `problem` is a problem+json validation body, as produced by
+	/// `Response::validation_problem` on the server. Field errors are routed
+	/// to the matching field signals and other errors to the form error.
+	/// Returns `false`, leaving the errors unchanged, when `problem` is not a
+	/// validation problem.
+	pub fn apply_server_errors(&self, problem: &serde_json::Value) -> bool {
+		let Some(error) = FormValidationError::from_problem_json(problem, |name| {
+			self.form.runtime_field_by_name(name)
+		}) else {
+			return false;
+		};
+		self.apply_validation_result(&Err(error));
+		true
+	}
+
 	/// Clears all validation and submit errors.
 	pub fn clear_errors(&self) {
 		for field in self.form.runtime_fields() {
@@ -1833,7 +1903,10 @@ where
 
 #[cfg(test)]
 mod tests {
-	use super::{CollectionItem, CollectionItemKey, CollectionState, FieldError, FieldPathState};
+	use super::{
+		CollectionItem, CollectionItemKey, CollectionState, FieldError, FieldPathState,
+		FormValidationError,
+	};
 
 	#[test]
 	fn collection_item_key_is_opaque_and_stable() {
@@ -1892,4 +1965,47 @@ mod tests {
 		assert!(field_path_state.is_touched);
 		assert_eq!(field_path_state.error, Some(error));
 	}
+
+	#[test]
+	fn validation_error_from_problem_json_routes_fields() {
+		#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+		enum Field {
+			Email,
+		}
+
+		let problem = serde_json::json!({
+			"type": "urn:reinhardt:problem:validation",
+			"status": 422,
+			"errors": {
+				"email": [
+					{"code": "unique", "message": "email is taken", "params": {}},
+					{"code": "invalid_email", "message": "bad email", "params": {}}
+				],
+				"nickname": [{"code": "invalid", "message": "bad nickname", "params": {}}]
+			},
+			"non_field_errors": []
+		});
+
+		let error = FormValidationError::from_problem_json(&problem, |name| match name {
+			"email" => Some(Field::Email),
+			_ => None,
+		})
+		.unwrap();
+
+		assert_eq!(
+			error
+				.field_errors()
+				.get(&Field::Email)
+				.map(FieldError::message),
+			Some("email is taken")
+		);
+		assert_eq!(error.form_error(), Some("bad nickname"));
+		assert!(
+			FormValidationError::<Field>::from_problem_json(
+				&serde_json::json!({"type": "about:blank"}),
+				|_| None
+			)
+			.is_none()
+		);
+	}
 }
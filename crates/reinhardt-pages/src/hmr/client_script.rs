@@ -3,7 +3,8 @@
 /// JavaScript source for the HMR client.
 ///
 /// This script establishes a WebSocket connection to the HMR server and handles
-/// incoming messages to apply CSS hot updates or trigger full page reloads.
+/// incoming messages to apply CSS hot updates, show build errors, or trigger
+/// full page reloads.
 pub const HMR_CLIENT_SCRIPT: &str = r#"
 (function() {
   "use strict";
@@ -45,6 +46,9 @@ pub const HMR_CLIENT_SCRIPT: &str = r#"
           console.log("[HMR] Full reload:", msg.reason);
           window.location.reload();
           break;
+        case "build_error":
+          showBuildError(msg.message);
+          break;
         case "connected":
           console.log("[HMR] Server acknowledged connection");
           break;
@@ -87,6 +91,27 @@ pub const HMR_CLIENT_SCRIPT: &str = r#"
     }
   }
 
+  function showBuildError(message) {
+    var overlay = document.getElementById("reinhardt-hmr-error");
+    if (!overlay) {
+      overlay = document.createElement("pre");
+      overlay.id = "reinhardt-hmr-error";
+      overlay.setAttribute(
+        "style",
+        "position:fixed;inset:0;z-index:2147483647;margin:0;padding:24px;overflow:auto;" +
+          "background:rgba(24,24,24,0.94);color:#ff8a8a;font:13px/1.5 monospace;white-space:pre-wrap;"
+      );
+      overlay.title = "Click to dismiss";
+      overlay.addEventListener("click", function() {
+        overlay.remove();
+      });
+      document.body.appendChild(overlay);
+    }
+    // textContent keeps compiler output from being parsed as HTML.
+    overlay.textContent = "Build failed - fix the error and save to rebuild.\n\n" + message;
+    console.error("[HMR] Build failed:\n" + message);
+  }
+
   function replaceHtml(selector, html) {
     var target;
     try {
@@ -176,6 +201,14 @@ mod tests {
 		assert!(HMR_CLIENT_SCRIPT.contains("stylesheet"));
 	}
 
+	#[rstest]
+	fn test_hmr_client_script_has_build_error_overlay() {
+		// Assert
+		assert!(HMR_CLIENT_SCRIPT.contains("build_error"));
+		assert!(HMR_CLIENT_SCRIPT.contains("showBuildError"));
+		assert!(HMR_CLIENT_SCRIPT.contains("textContent"));
+	}
+
 	#[rstest]
 	fn test_hmr_client_script_has_html_replace() {
 		// Assert
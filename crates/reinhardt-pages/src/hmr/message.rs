@@ -23,6 +23,11 @@ pub enum HmrMessage {
 		/// Human-readable reason for the reload.
 		reason: String,
 	},
+	/// A rebuild failed - the client should show the diagnostics over the page.
+	BuildError {
+		/// Compiler output describing the failure.
+		message: String,
+	},
 	/// Initial connection acknowledgment.
 	Connected,
 }
@@ -98,6 +103,23 @@ mod tests {
 		assert_eq!(value["html"], "<div id=\"app\">Updated</div>");
 	}
 
+	#[rstest]
+	fn test_build_error_serialization() {
+		// Arrange
+		let msg = HmrMessage::BuildError {
+			message: "error[E0308]: mismatched types".to_string(),
+		};
+
+		// Act
+		let json = msg.to_json().unwrap();
+		let deserialized = HmrMessage::from_json(&json).unwrap();
+
+		// Assert
+		assert_eq!(msg, deserialized);
+		assert!(json.contains("\"type\":\"build_error\""));
+		assert!(json.contains("\"message\":\"error[E0308]: mismatched types\""));
+	}
+
 	#[rstest]
 	fn test_connected_serialization() {
 		// Arrange
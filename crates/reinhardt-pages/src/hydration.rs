@@ -10,6 +10,8 @@
 //! - **Event Attachment**: Connect event handlers to existing DOM elements
 //! - **DOM Reconciliation**: Verify SSR output matches expected component structure
 //! - **Incremental Hydration**: Support partial page hydration (islands architecture)
+//! - **Lazy Hydration**: Hydrate islands on load, when visible, when idle or on
+//!   first interaction (see [`schedule_islands`])
 //!
 //! ## Usage
 //!
@@ -29,6 +31,7 @@ pub use islands::{IslandDetector, IslandNode};
 mod islands;
 mod reconcile;
 mod runtime;
+mod scheduler;
 
 pub use events::{
 	AttachOptions, EventBinding, EventRegistry, attach_event, attach_events_recursive,
@@ -40,6 +43,10 @@ pub use runtime::{
 	HydrationContext, HydrationError, hydrate, hydrate_root, init_hydration_state,
 	is_hydration_complete, on_hydration_complete,
 };
+pub use scheduler::{
+	IslandState, island_state, pending_islands, register_island, register_island_with,
+	schedule_islands,
+};
 
 #[cfg(wasm)]
 pub use runtime::{attach_events_to_mounted_view, mark_hydration_complete};
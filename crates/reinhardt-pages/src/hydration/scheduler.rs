@@ -0,0 +1,372 @@
+//! Lazy Island Hydration Scheduler
+//!
+//! Islands rendered with a deferred [`HydrationTrigger`] carry a
+//! `data-rh-hydrate` attribute. [`schedule_islands`] scans the document for
+//! islands and hydrates each one when its trigger fires, so below-the-fold or
+//! rarely used components don't block the initial wasm execution:
+//!
+//! - `load`: immediately
+//! - `visible`: when the island first intersects the viewport
+//!   (`IntersectionObserver`)
+//! - `idle`: from `requestIdleCallback`, or a short timeout where the browser
+//!   does not support it
+//! - `interaction`: on the first `pointerdown`, `keydown`, `focusin` or
+//!   `touchstart` inside the island. The listeners run in the capture phase,
+//!   so the island is hydrated before the event reaches its target and the
+//!   triggering event is handled by the freshly attached handlers.
+//!
+//! Islands are matched to components by their `data-rh-component` name, so
+//! every lazily hydrated component must be registered first:
+//!
+//! ```ignore
+//! use reinhardt_pages::hydration::{register_island, schedule_islands};
+//!
+//! #[wasm_bindgen(start)]
+//! pub fn main() {
+//!     register_island::<Comments>();
+//!     register_island::<NewsletterSignup>();
+//!     schedule_islands();
+//! }
+//! ```
+
+use crate::component::{Component, Page};
+use crate::ssr::HydrationTrigger;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[cfg(wasm)]
+use super::events::EventRegistry;
+#[cfg(wasm)]
+use crate::ssr::{HYDRATION_ATTR_ID, HYDRATION_ATTR_TRIGGER};
+
+/// Events that hydrate an island with [`HydrationTrigger::Interaction`].
+#[cfg(wasm)]
+const INTERACTION_EVENTS: &[&str] = &["pointerdown", "keydown", "focusin", "touchstart"];
+
+/// Delay used for [`HydrationTrigger::Idle`] when `requestIdleCallback` is unavailable.
+#[cfg(wasm)]
+const IDLE_FALLBACK_DELAY_MS: i32 = 200;
+
+type IslandFactory = Rc<dyn Fn() -> Page>;
+
+/// Hydration state of a scheduled island.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IslandState {
+	/// Waiting for its trigger.
+	Pending(HydrationTrigger),
+	/// Hydrated (or hydration was attempted and failed).
+	Hydrated,
+}
+
+thread_local! {
+	static ISLAND_FACTORIES: RefCell<HashMap<String, IslandFactory>> = RefCell::new(HashMap::new());
+	static ISLAND_STATES: RefCell<HashMap<String, IslandState>> = RefCell::new(HashMap::new());
+	#[cfg(wasm)]
+	static ISLAND_EVENTS: RefCell<Vec<EventRegistry>> = const { RefCell::new(Vec::new()) };
+	#[cfg(wasm)]
+	static VISIBLE_OBSERVER: RefCell<Option<web_sys::IntersectionObserver>> = const { RefCell::new(None) };
+}
+
+/// Registers `C` for island hydration under [`Component::name`].
+pub fn register_island<C: Component + Default>() {
+	register_island_with(C::name(), || C::default().render());
+}
+
+/// Registers a view factory for islands whose `data-rh-component` is `name`.
+///
+/// The factory must produce the same view the server rendered.
+pub fn register_island_with(name: impl Into<String>, factory: impl Fn() -> Page + 'static) {
+	ISLAND_FACTORIES.with(|factories| {
+		factories.borrow_mut().insert(name.into(), Rc::new(factory));
+	});
+}
+
+/// Returns the hydration state of the island with hydration ID `id`.
+///
+/// Returns `None` if the island has not been scheduled.
+pub fn island_state(id: &str) -> Option<IslandState> {
+	ISLAND_STATES.with(|states| states.borrow().get(id).copied())
+}
+
+/// Returns the hydration IDs of islands still waiting for their trigger.
+pub fn pending_islands() -> Vec<String> {
+	ISLAND_STATES.with(|states| {
+		let mut pending: Vec<String> = states
+			.borrow()
+			.iter()
+			.filter(|(_, state)| matches!(state, IslandState::Pending(_)))
+			.map(|(id, _)| id.clone())
+			.collect();
+		pending.sort();
+		pending
+	})
+}
+
+/// Records `id` as waiting for `trigger`.
+///
+/// Returns `false` if the island was already scheduled.
+fn mark_pending(id: &str, trigger: HydrationTrigger) -> bool {
+	ISLAND_STATES.with(|states| {
+		let mut states = states.borrow_mut();
+		if states.contains_key(id) {
+			return false;
+		}
+		states.insert(id.to_string(), IslandState::Pending(trigger));
+		true
+	})
+}
+
+/// Marks `id` as hydrated.
+///
+/// Returns `false` if it already was, so that an island whose triggers fire
+/// more than once is hydrated only once.
+fn begin_hydration(id: &str) -> bool {
+	ISLAND_STATES.with(|states| {
+		states
+			.borrow_mut()
+			.insert(id.to_string(), IslandState::Hydrated)
+			!= Some(IslandState::Hydrated)
+	})
+}
+
+/// Renders the registered view for component `name`.
+fn island_view(name: &str) -> Option<Page> {
+	let factory = ISLAND_FACTORIES.with(|factories| factories.borrow().get(name).cloned())?;
+	Some(factory())
+}
+
+/// Schedules hydration of every island in the document.
+///
+/// Returns the number of newly scheduled islands. Calling it again, e.g.
+/// after client-side navigation, only schedules islands not seen before.
+#[cfg(wasm)]
+pub fn schedule_islands() -> usize {
+	use super::islands::IslandDetector;
+
+	let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+		return 0;
+	};
+
+	let mut scheduled = 0;
+	for island in IslandDetector::new(document).find_islands() {
+		let trigger = island
+			.element
+			.get_attribute(HYDRATION_ATTR_TRIGGER)
+			.and_then(|value| value.parse().ok())
+			.unwrap_or_default();
+		if !mark_pending(&island.hydration_id, trigger) {
+			continue;
+		}
+		scheduled += 1;
+		match trigger {
+			HydrationTrigger::Load => hydrate_island(&island.element),
+			HydrationTrigger::Visible => hydrate_when_visible(&island.element),
+			HydrationTrigger::Idle => hydrate_when_idle(island.element.clone()),
+			HydrationTrigger::Interaction => hydrate_on_interaction(&island.element),
+		}
+	}
+	scheduled
+}
+
+/// Non-WASM version for testing.
+#[cfg(native)]
+pub fn schedule_islands() -> usize {
+	0
+}
+
+/// Reconciles an island with its registered view and attaches its events.
+#[cfg(wasm)]
+fn hydrate_island(element: &web_sys::Element) {
+	use super::reconcile::reconcile;
+	use super::runtime::attach_events_recursive;
+	use crate::dom::Element;
+
+	let (Some(id), Some(name)) = (
+		element.get_attribute(HYDRATION_ATTR_ID),
+		element.get_attribute("data-rh-component"),
+	) else {
+		return;
+	};
+	if !begin_hydration(&id) {
+		return;
+	}
+	let Some(view) = island_view(&name) else {
+		crate::warn_log!("[Hydration] No island registered for component {}", name);
+		return;
+	};
+
+	// The SSR marker wraps the component's markup in a `<div>`.
+	let wrapper = Element::new(element.clone());
+	let root = match view {
+		Page::Element(_) => match wrapper.children().into_iter().next() {
+			Some(child) => child,
+			None => {
+				crate::warn_log!("[Hydration] Island {} ({}) is empty", id, name);
+				return;
+			}
+		},
+		_ => wrapper,
+	};
+	if let Err(e) = reconcile(&root, &view) {
+		crate::warn_log!(
+			"[Hydration] Island {} ({}) does not match its SSR output: {}",
+			id,
+			name,
+			e
+		);
+		return;
+	}
+
+	let mut registry = EventRegistry::new();
+	if let Err(e) = attach_events_recursive(&root, &view, &mut registry) {
+		crate::warn_log!("[Hydration] Island {} ({}): {}", id, name, e);
+	}
+	// Dropping the registry would remove the listeners again.
+	ISLAND_EVENTS.with(|events| events.borrow_mut().push(registry));
+	let _ = element.set_attribute("data-rh-hydrated", "true");
+}
+
+#[cfg(wasm)]
+fn hydrate_when_visible(element: &web_sys::Element) {
+	use wasm_bindgen::JsCast;
+	use wasm_bindgen::closure::Closure;
+
+	VISIBLE_OBSERVER.with(|slot| {
+		let mut slot = slot.borrow_mut();
+		if slot.is_none() {
+			let callback = Closure::<dyn FnMut(js_sys::Array, web_sys::IntersectionObserver)>::new(
+				|entries: js_sys::Array, observer: web_sys::IntersectionObserver| {
+					for entry in entries.iter() {
+						let entry: web_sys::IntersectionObserverEntry = entry.unchecked_into();
+						if !entry.is_intersecting() {
+							continue;
+						}
+						let target = entry.target();
+						observer.unobserve(&target);
+						hydrate_island(&target);
+					}
+				},
+			);
+			*slot = web_sys::IntersectionObserver::new(callback.as_ref().unchecked_ref()).ok();
+			// The observer lives for the whole page, like the router's
+			// viewport prefetch observer.
+			callback.forget();
+		}
+		match slot.as_ref() {
+			Some(observer) => observer.observe(element),
+			// Without IntersectionObserver the island is hydrated right away.
+			None => hydrate_island(element),
+		}
+	});
+}
+
+#[cfg(wasm)]
+fn hydrate_when_idle(element: web_sys::Element) {
+	use wasm_bindgen::JsCast;
+	use wasm_bindgen::closure::Closure;
+
+	let Some(window) = web_sys::window() else {
+		return;
+	};
+	let callback = Closure::once_into_js(move || hydrate_island(&element));
+	let function: &js_sys::Function = callback.unchecked_ref();
+	if window.request_idle_callback(function).is_err() {
+		let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+			function,
+			IDLE_FALLBACK_DELAY_MS,
+		);
+	}
+}
+
+#[cfg(wasm)]
+fn hydrate_on_interaction(element: &web_sys::Element) {
+	use wasm_bindgen::JsCast;
+	use wasm_bindgen::closure::Closure;
+
+	let listener: Rc<RefCell<Option<js_sys::Function>>> = Rc::default();
+	let callback = Closure::<dyn FnMut(web_sys::Event)>::new({
+		let element = element.clone();
+		let listener = listener.clone();
+		move |_event: web_sys::Event| {
+			if let Some(function) = listener.borrow_mut().take() {
+				for event in INTERACTION_EVENTS {
+					let _ = element
+						.remove_event_listener_with_callback_and_bool(event, &function, true);
+				}
+			}
+			hydrate_island(&element);
+		}
+	});
+	let function: js_sys::Function = callback
+		.as_ref()
+		.unchecked_ref::<js_sys::Function>()
+		.clone();
+	for event in INTERACTION_EVENTS {
+		let _ = element.add_event_listener_with_callback_and_bool(event, &function, true);
+	}
+	*listener.borrow_mut() = Some(function);
+	// Removed from the element on first use; the closure itself is small.
+	callback.forget();
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::component::{IntoPage, PageElement};
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_island_hydrates_only_once() {
+		// Arrange
+		mark_pending("rh-once", HydrationTrigger::Interaction);
+
+		// Act
+		let first = begin_hydration("rh-once");
+		let second = begin_hydration("rh-once");
+
+		// Assert
+		assert!(first);
+		assert!(!second);
+		assert_eq!(island_state("rh-once"), Some(IslandState::Hydrated));
+	}
+
+	#[rstest]
+	fn test_pending_islands_tracks_triggers() {
+		// Arrange
+		mark_pending("rh-b", HydrationTrigger::Visible);
+		mark_pending("rh-a", HydrationTrigger::Idle);
+
+		// Act
+		let rescheduled = mark_pending("rh-a", HydrationTrigger::Load);
+		begin_hydration("rh-b");
+
+		// Assert
+		assert!(!rescheduled);
+		assert_eq!(
+			island_state("rh-a"),
+			Some(IslandState::Pending(HydrationTrigger::Idle))
+		);
+		assert!(pending_islands().contains(&"rh-a".to_string()));
+		assert!(!pending_islands().contains(&"rh-b".to_string()));
+	}
+
+	#[rstest]
+	fn test_registered_island_view() {
+		// Arrange
+		register_island_with("Comments", || {
+			PageElement::new("section").child("Comments").into_page()
+		});
+
+		// Act
+		let view = island_view("Comments");
+		let missing = island_view("Unregistered");
+
+		// Assert
+		assert_eq!(
+			view.unwrap().render_to_string(),
+			"<section>Comments</section>"
+		);
+		assert!(missing.is_none());
+	}
+}
@@ -0,0 +1,611 @@
+//! Client-side Translations for WASM Pages
+//!
+//! This module delivers `reinhardt-i18n` message catalogs to the browser as
+//! per-locale [`MessageBundle`]s and exposes the active locale as a reactive
+//! [`Signal`], so text rendered through [`t`], [`tn`] and [`tp`] inside
+//! `page!` re-renders when the user switches language.
+//!
+//! Only the active locale's bundle is shipped with the page:
+//!
+//! 1. [`compile_bundles`] writes one `{locale}.json` bundle per locale at
+//!    build time (server-side only).
+//! 2. During SSR, [`MessageBundle::to_script_tag`] embeds the active locale's
+//!    bundle in a `<script id="i18n-bundle">` element, which
+//!    [`I18nState::init_from_page`] reads on hydration.
+//! 3. [`I18nState::set_locale`] fetches `{bundle_url}/{locale}.json` the first
+//!    time a locale is selected, then updates the locale signal.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use reinhardt_pages::i18n::{i18n_state, t, tn, use_locale};
+//!
+//! i18n_state().init_from_page();
+//!
+//! let locale = use_locale();
+//! page!(|| {
+//!     div {
+//!         h1 { { t("Welcome") } }
+//!         p { { tn("{} item", "{} items", 3) } }
+//!         button {
+//!             @click: move |_| {
+//!                 spawn_task(async { let _ = i18n_state().set_locale("ja").await; });
+//!             },
+//!             { locale.get() }
+//!         }
+//!     }
+//! })
+//! ```
+
+use crate::reactive::Signal;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Id of the `<script>` element holding the embedded bundle.
+pub const BUNDLE_ELEMENT_ID: &str = "i18n-bundle";
+
+/// Default URL prefix of the compiled bundles.
+pub const DEFAULT_BUNDLE_URL: &str = "/static/i18n";
+
+/// Number of precomputed plural indices stored in a bundle.
+///
+/// Plural rules only depend on small counts and on `count % 100`, so counts
+/// from 200 on reuse the indices of `100 + count % 100`.
+const PLURAL_TABLE_SIZE: usize = 200;
+
+thread_local! {
+	/// Global translation state instance.
+	static I18N_STATE: RefCell<Option<I18nState>> = const { RefCell::new(None) };
+}
+
+/// Returns the global translation state.
+///
+/// This creates the state on first access and returns the same instance
+/// for subsequent calls within the same thread.
+pub fn i18n_state() -> I18nState {
+	I18N_STATE.with(|state| {
+		let mut state = state.borrow_mut();
+		if state.is_none() {
+			*state = Some(I18nState::new());
+		}
+		state.clone().unwrap()
+	})
+}
+
+/// Returns the Signal holding the active locale.
+///
+/// Reading it inside a reactive closure re-runs the closure when the
+/// locale changes.
+pub fn use_locale() -> Signal<String> {
+	i18n_state().locale_signal()
+}
+
+/// Translates `message` in the active locale.
+///
+/// Returns `message` itself when the active bundle has no translation.
+pub fn t(message: &str) -> String {
+	i18n_state().gettext(message)
+}
+
+/// Translates a message with plural forms in the active locale.
+pub fn tn(singular: &str, plural: &str, count: usize) -> String {
+	i18n_state().ngettext(singular, plural, count)
+}
+
+/// Translates `message` within `context` in the active locale.
+pub fn tp(context: &str, message: &str) -> String {
+	i18n_state().pgettext(context, message)
+}
+
+/// Translations of a single locale, as sent to the client.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageBundle {
+	/// Locale of the translations, e.g. `ja` or `pt-BR`.
+	pub locale: String,
+	/// Simple translations keyed by message.
+	#[serde(default)]
+	pub messages: HashMap<String, String>,
+	/// Plural forms keyed by singular message.
+	#[serde(default)]
+	pub plurals: HashMap<String, Vec<String>>,
+	/// Contextual translations keyed by context, then message.
+	#[serde(default)]
+	pub contexts: HashMap<String, HashMap<String, String>>,
+	/// Plural form index for each count below 200.
+	///
+	/// Empty when the bundle uses the default `1` / other rule.
+	#[serde(default)]
+	pub plural_indices: Vec<u8>,
+}
+
+impl MessageBundle {
+	/// Creates an empty bundle for `locale`.
+	pub fn new(locale: impl Into<String>) -> Self {
+		Self {
+			locale: locale.into(),
+			..Default::default()
+		}
+	}
+
+	/// Creates a bundle holding every translation of `catalog`.
+	#[cfg(native)]
+	pub fn from_catalog(catalog: &reinhardt_i18n::MessageCatalog) -> Self {
+		let mut contexts: HashMap<String, HashMap<String, String>> = HashMap::new();
+		for (context, message, translation) in catalog.contexts() {
+			contexts
+				.entry(context.to_string())
+				.or_default()
+				.insert(message.to_string(), translation.to_string());
+		}
+		Self {
+			locale: catalog.locale().to_string(),
+			messages: catalog
+				.messages()
+				.map(|(message, translation)| (message.to_string(), translation.to_string()))
+				.collect(),
+			plurals: catalog
+				.plurals()
+				.map(|(singular, forms)| (singular.to_string(), forms.to_vec()))
+				.collect(),
+			contexts,
+			plural_indices: (0..PLURAL_TABLE_SIZE)
+				.map(|count| catalog.plural_index(count) as u8)
+				.collect(),
+		}
+	}
+
+	/// Returns the translation of `message`, if any.
+	pub fn gettext(&self, message: &str) -> Option<&str> {
+		self.messages.get(message).map(String::as_str)
+	}
+
+	/// Returns the plural form of `singular` used for `count`, if any.
+	pub fn ngettext(&self, singular: &str, count: usize) -> Option<&str> {
+		let forms = self.plurals.get(singular)?;
+		forms.get(self.plural_index(count)).map(String::as_str)
+	}
+
+	/// Returns the translation of `message` within `context`, if any.
+	pub fn pgettext(&self, context: &str, message: &str) -> Option<&str> {
+		self.contexts.get(context)?.get(message).map(String::as_str)
+	}
+
+	/// Index of the plural form used for `count`.
+	pub fn plural_index(&self, count: usize) -> usize {
+		let slot = if count < PLURAL_TABLE_SIZE {
+			count
+		} else {
+			100 + count % 100
+		};
+		match self.plural_indices.get(slot) {
+			Some(index) => *index as usize,
+			None => usize::from(count != 1),
+		}
+	}
+
+	/// Renders the `<script>` element embedding this bundle in SSR output.
+	///
+	/// `</` is escaped so that translations cannot close the element.
+	#[cfg(native)]
+	pub fn to_script_tag(&self) -> String {
+		let json = serde_json::to_string(self)
+			.unwrap_or_default()
+			.replace("</", "<\\/");
+		format!(
+			"<script id=\"{}\" type=\"application/json\">{}</script>",
+			BUNDLE_ELEMENT_ID, json
+		)
+	}
+}
+
+/// Compiles the catalogs of `locales` into `{out_dir}/{locale}.json` bundles.
+///
+/// Serve `out_dir` under the URL configured with
+/// [`I18nState::set_bundle_url`] so that the client can lazy-load the
+/// bundles. Returns the paths of the written files.
+#[cfg(native)]
+pub fn compile_bundles(
+	loader: &reinhardt_i18n::CatalogLoader,
+	locales: &[&str],
+	out_dir: &std::path::Path,
+) -> Result<Vec<std::path::PathBuf>, I18nError> {
+	std::fs::create_dir_all(out_dir).map_err(|e| I18nError::Io(e.to_string()))?;
+	let mut paths = Vec::with_capacity(locales.len());
+	for locale in locales {
+		let catalog = loader
+			.load(locale)
+			.map_err(|e| I18nError::Catalog(e.to_string()))?;
+		let json = serde_json::to_string(&MessageBundle::from_catalog(&catalog))
+			.map_err(|e| I18nError::Parse(e.to_string()))?;
+		let path = out_dir.join(format!("{}.json", locale));
+		std::fs::write(&path, json).map_err(|e| I18nError::Io(e.to_string()))?;
+		paths.push(path);
+	}
+	Ok(paths)
+}
+
+/// Reactive translation state for client-side applications.
+///
+/// Holds the active locale signal and the bundles loaded so far.
+#[derive(Debug, Clone)]
+pub struct I18nState {
+	/// The active locale.
+	locale: Signal<String>,
+	/// Bundles loaded so far, keyed by locale.
+	bundles: Rc<RefCell<HashMap<String, MessageBundle>>>,
+	/// URL prefix the bundles are fetched from.
+	bundle_url: Rc<RefCell<String>>,
+}
+
+impl Default for I18nState {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl I18nState {
+	/// Creates a state without bundles, using the `en` locale.
+	pub fn new() -> Self {
+		Self {
+			locale: Signal::new("en".to_string()),
+			bundles: Rc::new(RefCell::new(HashMap::new())),
+			bundle_url: Rc::new(RefCell::new(DEFAULT_BUNDLE_URL.to_string())),
+		}
+	}
+
+	/// Returns the active locale.
+	pub fn locale(&self) -> String {
+		self.locale.get()
+	}
+
+	/// Returns the Signal for the active locale.
+	///
+	/// Use this for reactive UI updates.
+	pub fn locale_signal(&self) -> Signal<String> {
+		self.locale.clone()
+	}
+
+	/// Sets the URL prefix the bundles are fetched from.
+	///
+	/// Defaults to [`DEFAULT_BUNDLE_URL`].
+	pub fn set_bundle_url(&self, url: impl Into<String>) {
+		*self.bundle_url.borrow_mut() = url.into();
+	}
+
+	/// Returns the URL of the bundle of `locale`.
+	pub fn bundle_url(&self, locale: &str) -> String {
+		format!(
+			"{}/{}.json",
+			self.bundle_url.borrow().trim_end_matches('/'),
+			locale
+		)
+	}
+
+	/// Returns whether the bundle of `locale` is loaded.
+	pub fn has_bundle(&self, locale: &str) -> bool {
+		self.bundles.borrow().contains_key(locale)
+	}
+
+	/// Adds a bundle, replacing the one of the same locale.
+	///
+	/// The active locale does not change.
+	pub fn add_bundle(&self, bundle: MessageBundle) {
+		self.bundles
+			.borrow_mut()
+			.insert(bundle.locale.clone(), bundle);
+	}
+
+	/// Switches to `locale` if its bundle is loaded.
+	///
+	/// Returns `false` without changing the locale otherwise.
+	pub fn activate(&self, locale: &str) -> bool {
+		if !self.has_bundle(locale) {
+			return false;
+		}
+		if self.locale.get_untracked() != locale {
+			self.locale.set(locale.to_string());
+		}
+		true
+	}
+
+	/// Translates `message` in the active locale.
+	pub fn gettext(&self, message: &str) -> String {
+		self.lookup(|bundle| bundle.gettext(message))
+			.unwrap_or_else(|| message.to_string())
+	}
+
+	/// Translates a message with plural forms in the active locale.
+	///
+	/// Falls back to `singular` when `count` is 1 and to `plural` otherwise.
+	pub fn ngettext(&self, singular: &str, plural: &str, count: usize) -> String {
+		self.lookup(|bundle| bundle.ngettext(singular, count))
+			.unwrap_or_else(|| {
+				if count == 1 {
+					singular.to_string()
+				} else {
+					plural.to_string()
+				}
+			})
+	}
+
+	/// Translates `message` within `context` in the active locale.
+	pub fn pgettext(&self, context: &str, message: &str) -> String {
+		self.lookup(|bundle| bundle.pgettext(context, message))
+			.unwrap_or_else(|| message.to_string())
+	}
+
+	/// Looks up a translation in the active locale's bundle.
+	///
+	/// Reads the locale signal, so reactive callers re-run on locale changes.
+	fn lookup<F>(&self, find: F) -> Option<String>
+	where
+		F: for<'a> FnOnce(&'a MessageBundle) -> Option<&'a str>,
+	{
+		let locale = self.locale.get();
+		let bundles = self.bundles.borrow();
+		bundles.get(&locale).and_then(find).map(str::to_string)
+	}
+
+	/// Switches to `locale`, fetching its bundle on first use.
+	///
+	/// The locale signal only changes once the bundle is loaded, so the page
+	/// re-renders directly in the new language.
+	#[cfg(wasm)]
+	pub async fn set_locale(&self, locale: &str) -> Result<(), I18nError> {
+		use crate::fetch;
+
+		if !is_valid_locale(locale) {
+			return Err(I18nError::InvalidLocale(locale.to_string()));
+		}
+
+		if !self.has_bundle(locale) {
+			let response = fetch::request("GET", &self.bundle_url(locale), None, Vec::new())
+				.await
+				.map_err(|e| I18nError::Network(e.to_string()))?;
+
+			if !response.is_success() {
+				return Err(I18nError::Server {
+					status: response.status(),
+					message: response.into_text(),
+				});
+			}
+
+			let bundle: MessageBundle = response
+				.json()
+				.map_err(|e| I18nError::Parse(e.to_string()))?;
+			self.add_bundle(MessageBundle {
+				locale: locale.to_string(),
+				..bundle
+			});
+		}
+
+		self.activate(locale);
+		set_document_lang(locale);
+		Ok(())
+	}
+
+	/// Switches to `locale` (non-WASM stub).
+	///
+	/// Bundles are not fetched on the server; the locale changes when its
+	/// bundle was added with [`I18nState::add_bundle`].
+	#[cfg(native)]
+	pub async fn set_locale(&self, locale: &str) -> Result<(), I18nError> {
+		if !is_valid_locale(locale) {
+			return Err(I18nError::InvalidLocale(locale.to_string()));
+		}
+		if self.activate(locale) {
+			Ok(())
+		} else {
+			Err(I18nError::BundleNotFound(locale.to_string()))
+		}
+	}
+
+	/// Initializes the state from the bundle embedded in the page.
+	///
+	/// This looks for a `<script id="i18n-bundle">` element containing the
+	/// JSON-encoded bundle of the locale the page was rendered in.
+	#[cfg(wasm)]
+	pub fn init_from_page(&self) {
+		use web_sys::window;
+
+		let Some(window) = window() else { return };
+		let Some(document) = window.document() else {
+			return;
+		};
+
+		let selector = format!("#{}", BUNDLE_ELEMENT_ID);
+		let Ok(Some(element)) = document.query_selector(&selector) else {
+			return;
+		};
+
+		let Some(json_str) = element.text_content() else {
+			return;
+		};
+
+		if let Ok(bundle) = serde_json::from_str::<MessageBundle>(&json_str) {
+			let locale = bundle.locale.clone();
+			self.add_bundle(bundle);
+			self.activate(&locale);
+		}
+	}
+
+	/// Initializes the state (non-WASM stub).
+	#[cfg(native)]
+	pub fn init_from_page(&self) {
+		// No-op on non-WASM targets
+	}
+}
+
+/// Returns whether `locale` only contains ASCII alphanumerics, `-` and `_`.
+fn is_valid_locale(locale: &str) -> bool {
+	!locale.is_empty()
+		&& locale
+			.chars()
+			.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Updates the `lang` attribute of the `<html>` element.
+#[cfg(wasm)]
+fn set_document_lang(locale: &str) {
+	if let Some(element) = web_sys::window()
+		.and_then(|window| window.document())
+		.and_then(|document| document.document_element())
+	{
+		let _ = element.set_attribute("lang", locale);
+	}
+}
+
+/// Errors that can occur while loading translations.
+#[derive(Debug, Clone)]
+pub enum I18nError {
+	/// The locale contains characters other than alphanumerics, `-` and `_`.
+	InvalidLocale(String),
+	/// No bundle is loaded for the locale.
+	BundleNotFound(String),
+	/// Network error during request.
+	Network(String),
+	/// Server returned an error response.
+	Server {
+		/// HTTP status code.
+		status: u16,
+		/// Error message.
+		message: String,
+	},
+	/// Failed to parse or serialize a bundle.
+	Parse(String),
+	/// Failed to load a message catalog.
+	Catalog(String),
+	/// Failed to write a compiled bundle.
+	Io(String),
+}
+
+impl std::fmt::Display for I18nError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			I18nError::InvalidLocale(locale) => write!(f, "Invalid locale: {}", locale),
+			I18nError::BundleNotFound(locale) => write!(f, "Bundle not found: {}", locale),
+			I18nError::Network(msg) => write!(f, "Network error: {}", msg),
+			I18nError::Server { status, message } => {
+				write!(f, "Server error ({}): {}", status, message)
+			}
+			I18nError::Parse(msg) => write!(f, "Parse error: {}", msg),
+			I18nError::Catalog(msg) => write!(f, "Catalog error: {}", msg),
+			I18nError::Io(msg) => write!(f, "I/O error: {}", msg),
+		}
+	}
+}
+
+impl std::error::Error for I18nError {}
+
+#[cfg(all(test, native))]
+mod tests {
+	use super::*;
+	use reinhardt_i18n::MessageCatalog;
+
+	fn japanese() -> MessageBundle {
+		let mut catalog = MessageCatalog::new("ja");
+		catalog.add_translation("Hello", "こんにちは");
+		catalog.add_plural_str("item", "items", vec!["個"]);
+		catalog.add_context_str("menu", "File", "ファイル");
+		MessageBundle::from_catalog(&catalog)
+	}
+
+	#[test]
+	fn test_bundle_from_catalog() {
+		let bundle = japanese();
+
+		assert_eq!(bundle.locale, "ja");
+		assert_eq!(bundle.gettext("Hello"), Some("こんにちは"));
+		assert_eq!(bundle.ngettext("item", 5), Some("個"));
+		assert_eq!(bundle.pgettext("menu", "File"), Some("ファイル"));
+		assert_eq!(bundle.plural_indices.len(), PLURAL_TABLE_SIZE);
+	}
+
+	#[test]
+	fn test_plural_index_reuses_table_for_large_counts() {
+		let bundle = MessageBundle::from_catalog(&MessageCatalog::new("ru"));
+
+		assert_eq!(bundle.plural_index(1), 0);
+		assert_eq!(bundle.plural_index(11), 2);
+		assert_eq!(bundle.plural_index(221), 0);
+		assert_eq!(bundle.plural_index(1_012), 2);
+		assert_eq!(MessageBundle::new("en").plural_index(2), 1);
+	}
+
+	#[test]
+	fn test_script_tag_escapes_closing_tags() {
+		let mut bundle = MessageBundle::new("en");
+		bundle
+			.messages
+			.insert("x".to_string(), "</script><b>".to_string());
+
+		let html = bundle.to_script_tag();
+
+		assert!(html.starts_with("<script id=\"i18n-bundle\" type=\"application/json\">"));
+		assert_eq!(html.matches("</script>").count(), 1);
+	}
+
+	#[test]
+	fn test_translations_follow_active_locale() {
+		let state = I18nState::new();
+		state.add_bundle(japanese());
+
+		assert_eq!(state.gettext("Hello"), "Hello");
+		assert_eq!(state.ngettext("item", "items", 2), "items");
+
+		assert!(state.activate("ja"));
+		assert_eq!(state.locale(), "ja");
+		assert_eq!(state.gettext("Hello"), "こんにちは");
+		assert_eq!(state.ngettext("item", "items", 2), "個");
+		assert_eq!(state.pgettext("menu", "File"), "ファイル");
+		assert_eq!(state.pgettext("menu", "Edit"), "Edit");
+	}
+
+	#[test]
+	fn test_activate_requires_loaded_bundle() {
+		let state = I18nState::new();
+
+		assert!(!state.activate("fr"));
+		assert_eq!(state.locale(), "en");
+	}
+
+	#[tokio::test]
+	async fn test_set_locale_non_wasm() {
+		let state = I18nState::new();
+		state.add_bundle(japanese());
+
+		assert!(matches!(
+			state.set_locale("../etc").await,
+			Err(I18nError::InvalidLocale(_))
+		));
+		assert!(matches!(
+			state.set_locale("fr").await,
+			Err(I18nError::BundleNotFound(_))
+		));
+		state.set_locale("ja").await.unwrap();
+		assert_eq!(state.locale(), "ja");
+	}
+
+	#[test]
+	fn test_bundle_url() {
+		let state = I18nState::new();
+		assert_eq!(state.bundle_url("ja"), "/static/i18n/ja.json");
+
+		state.set_bundle_url("https://cdn.example.com/i18n/");
+		assert_eq!(
+			state.bundle_url("pt-BR"),
+			"https://cdn.example.com/i18n/pt-BR.json"
+		);
+	}
+
+	#[test]
+	fn test_use_locale_is_shared() {
+		let state = i18n_state();
+		state.add_bundle(MessageBundle::new("de"));
+		state.activate("de");
+
+		assert_eq!(use_locale().get(), "de");
+	}
+}
@@ -58,11 +58,14 @@
 //! - [`auth`]: Authentication integration
 //! - [`api`]: API client with Django QuerySet-like interface
 //! - [`server_fn`]: Server Functions (RPC)
+//! - [`upload`]: Resumable file uploads with progress and retry
 //! - [`ssr`]: Server-side rendering with Head support
+//! - [`wire`]: Server-driven partial page updates (HTML over the wire)
 //! - [`hydration`]: Client-side hydration
 //! - [`router`]: Client-side routing (reinhardt-urls compatible)
 //! - [`portal`]: Explicit portal mounting into existing DOM targets
 //! - [`static_resolver`]: Static file URL resolution (collectstatic support)
+//! - `telemetry`: Client telemetry with error reporting (feature `telemetry`)
 //!
 //! ## Forms
 //!
@@ -298,6 +301,8 @@ pub mod component;
 // Form and security
 pub mod auth;
 pub mod csrf;
+// Client-side translations
+pub mod i18n;
 #[doc(hidden)]
 mod fetch;
 // Static form metadata types for form! macro (WASM-compatible)
@@ -312,10 +317,15 @@ pub mod form;
 // API and communication
 pub mod api;
 pub mod server_fn;
+// Resumable (tus) file uploads
+pub mod upload;
 
 // Server-side rendering
 pub mod ssr;
 
+// Server-driven partial page updates (HTML over the wire)
+pub mod wire;
+
 // Client-side hydration
 pub mod hydration;
 
@@ -342,6 +352,10 @@ pub mod static_resolver;
 #[cfg(all(native, feature = "hmr"))]
 pub mod hmr;
 
+// Client telemetry (feature-gated)
+#[cfg(feature = "telemetry")]
+pub mod telemetry;
+
 // Table utilities (django-tables2 equivalent)
 pub mod tables;
 
@@ -366,6 +380,7 @@ pub use component::{
 	start_view_transition,
 };
 pub use csrf::{CsrfManager, get_csrf_token};
+pub use i18n::{I18nError, I18nState, MessageBundle, i18n_state, t, tn, tp, use_locale};
 pub use dom::{CustomEventOptions, Document, Element, EventHandle, EventType, document};
 #[cfg(native)]
 pub use form::{FormBinding, FormComponent};
@@ -399,6 +414,8 @@ pub use reinhardt_forms::{
 	Widget,
 	wasm_compat::{FieldMetadata, FormMetadata},
 };
+// Form renderer selecting field classes (always available, used by form! macro)
+pub use reinhardt_core::form_renderer;
 pub use router::Link;
 // Imperative SPA navigation API (Issue #4610). `navigate` is the free
 // function; `use_router` returns a `RouterHandle` for use inside hooks /
@@ -408,8 +425,9 @@ pub use router::{NavigationType, navigate};
 pub use router::{Path, Query};
 pub use server_fn::{ServerFn, ServerFnError, parse_server_error_message};
 pub use ssr::SsrState;
+pub use wire::{Fragment, FragmentResponse, Swap};
 #[cfg(native)]
-pub use ssr::{SsrOptions, SsrRenderer};
+pub use ssr::{SsrOptions, SsrRenderer, SsrStream};
 pub use static_resolver::{init_static_resolver, is_initialized, resolve_static};
 
 // Re-export procedural macros
@@ -144,6 +144,7 @@ pub use crate::server_fn::{ServerFn, ServerFnError};
 
 pub use crate::auth::{AuthData, AuthError, AuthState, auth_state};
 pub use crate::csrf::{CsrfManager, get_csrf_token};
+pub use crate::i18n::{I18nState, i18n_state, use_locale};
 
 // ============================================================================
 // SSR and Hydration
@@ -158,7 +159,7 @@ pub use crate::hydration::{
 pub use crate::hydration::mark_hydration_complete;
 pub use crate::ssr::SsrState;
 #[cfg(native)]
-pub use crate::ssr::{SsrOptions, SsrRenderer};
+pub use crate::ssr::{SsrOptions, SsrRenderer, SsrStream};
 
 // ============================================================================
 // Static File URL Resolver
@@ -53,6 +53,7 @@
 //! - [`use_id`] - Generate unique IDs
 //! - [`use_sync_external_store`] - Subscribe to external stores
 //! - [`use_websocket`] - WebSocket connections (WASM only)
+//! - [`use_presence`] - Members present in a room (WASM only)
 //! - [`use_optimistic`] - Optimistic UI updates
 //! - [`use_debug_value`] - DevTools labels
 //!
@@ -97,6 +98,7 @@ pub mod debug;
 pub mod effect;
 pub mod id;
 pub mod memo;
+pub mod presence;
 pub mod refs;
 pub mod router;
 pub mod state;
@@ -114,6 +116,7 @@ pub use debug::use_debug_value;
 pub use effect::{use_effect, use_layout_effect};
 pub use id::use_id;
 pub use memo::{use_callback, use_callback_with, use_memo};
+pub use presence::{PresenceMember, UsePresenceOptions, use_presence, use_presence_with};
 pub use refs::{Ref, use_ref};
 pub use router::{NavigateError, RouterHandle, use_router};
 pub use state::{
@@ -122,5 +125,6 @@ pub use state::{
 pub use sync::{SignalWithSubscription, SubscriptionHandle, use_sync_external_store};
 pub use transition::{TransitionState, use_deferred_value, use_transition};
 pub use websocket::{
-	ConnectionState, UseWebSocketOptions, WebSocketHandle, WebSocketMessage, use_websocket,
+	ConnectionState, UseWebSocketOptions, WebSocketHandle, WebSocketMessage, WebSocketTransport,
+	use_websocket,
 };
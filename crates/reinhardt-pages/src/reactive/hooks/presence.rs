@@ -0,0 +1,178 @@
+//! Presence hook: use_presence
+//!
+//! Subscribes to the presence stream of a room, as served by the
+//! `PresenceTracker` of reinhardt-websockets, and exposes the members of the
+//! room as a signal. The subscription itself makes the current user present:
+//! the server joins the user when the stream opens and removes them when it
+//! closes.
+
+use crate::reactive::Signal;
+use serde::{Deserialize, Serialize};
+
+/// Event name of presence events on the SSE stream.
+pub const PRESENCE_EVENT: &str = "presence";
+
+/// A user present in a room, as sent by the server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PresenceMember {
+	/// Id of the user.
+	pub user_id: String,
+	/// Data the server attached to the user, such as a display name.
+	#[serde(default)]
+	pub metadata: serde_json::Value,
+	/// When the user joined, as RFC 3339.
+	#[serde(default)]
+	pub joined_at: String,
+	/// Last heartbeat of the user, as RFC 3339.
+	#[serde(default)]
+	pub last_seen: String,
+}
+
+/// Payload of presence events; only the member list is used.
+#[derive(Deserialize)]
+#[cfg_attr(not(wasm), allow(dead_code))]
+struct PresenceEvent {
+	members: Vec<PresenceMember>,
+}
+
+/// Options for [`use_presence_with`]
+#[derive(Debug, Clone)]
+pub struct UsePresenceOptions {
+	/// URL of the presence stream; `{room}` is replaced by the room name.
+	pub url: String,
+}
+
+impl Default for UsePresenceOptions {
+	fn default() -> Self {
+		Self {
+			url: "/presence/{room}/".to_string(),
+		}
+	}
+}
+
+/// Members currently present in `room`
+///
+/// Uses the stream at `/presence/{room}/`; see [`use_presence_with`] to
+/// change it.
+///
+/// # Example
+///
+/// ```ignore
+/// use reinhardt_pages::reactive::hooks::use_presence;
+///
+/// let members = use_presence("lobby");
+///
+/// page!(|| {
+///     ul {
+///         for member in members.get() {
+///             li { member.user_id }
+///         }
+///     }
+/// })
+/// ```
+pub fn use_presence(room: &str) -> Signal<Vec<PresenceMember>> {
+	use_presence_with(room, UsePresenceOptions::default())
+}
+
+/// Members currently present in `room`, with custom options
+///
+/// The signal is replaced with the member list carried by every presence
+/// event. Outside WASM the signal stays empty.
+pub fn use_presence_with(room: &str, options: UsePresenceOptions) -> Signal<Vec<PresenceMember>> {
+	let members = Signal::new(Vec::new());
+	let url = options.url.replace("{room}", &encode_room(room));
+
+	#[cfg(wasm)]
+	if let Err(e) = subscribe(&url, members.clone()) {
+		web_sys::console::warn_1(&format!("Presence subscription failed: {}", e).into());
+	}
+	#[cfg(native)]
+	let _ = url;
+
+	members
+}
+
+#[cfg(wasm)]
+fn subscribe(url: &str, members: Signal<Vec<PresenceMember>>) -> Result<(), String> {
+	use wasm_bindgen::JsCast;
+	use wasm_bindgen::closure::Closure;
+
+	let init = web_sys::EventSourceInit::new();
+	init.set_with_credentials(true);
+	let source = web_sys::EventSource::new_with_event_source_init_dict(url, &init)
+		.map_err(|e| format!("{:?}", e))?;
+	let callback =
+		Closure::<dyn FnMut(web_sys::MessageEvent)>::new(move |event: web_sys::MessageEvent| {
+			let Some(data) = event.data().as_string() else {
+				return;
+			};
+			match serde_json::from_str::<PresenceEvent>(&data) {
+				Ok(event) => members.set(event.members),
+				Err(e) => {
+					web_sys::console::warn_1(&format!("Invalid presence event: {}", e).into())
+				}
+			}
+		});
+	source
+		.add_event_listener_with_callback(PRESENCE_EVENT, callback.as_ref().unchecked_ref())
+		.map_err(|e| format!("{:?}", e))?;
+	// The listener and the event source live as long as the page.
+	callback.forget();
+	Ok(())
+}
+
+/// Percent-encode the characters of `room` that are not allowed in a path
+/// segment.
+fn encode_room(room: &str) -> String {
+	let mut encoded = String::with_capacity(room.len());
+	for byte in room.bytes() {
+		if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+			encoded.push(byte as char);
+		} else {
+			encoded.push_str(&format!("%{:02X}", byte));
+		}
+	}
+	encoded
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case("lobby", "lobby")]
+	#[case("team chat", "team%20chat")]
+	#[case("docs/42", "docs%2F42")]
+	fn test_encode_room(#[case] room: &str, #[case] expected: &str) {
+		// Act
+		let encoded = encode_room(room);
+
+		// Assert
+		assert_eq!(encoded, expected);
+	}
+
+	#[rstest]
+	fn test_use_presence_starts_empty_outside_wasm() {
+		// Act
+		let members = use_presence("lobby");
+
+		// Assert
+		assert!(members.get_untracked().is_empty());
+	}
+
+	#[rstest]
+	fn test_presence_event_decodes_members() {
+		// Arrange
+		let data = r#"{"change":"join","room":"lobby","user_id":"1","members":[
+			{"user_id":"1","metadata":{"name":"Alice"},"joined_at":"2026-01-01T00:00:00Z","last_seen":"2026-01-01T00:00:05Z"}
+		]}"#;
+
+		// Act
+		let event: PresenceEvent = serde_json::from_str(data).unwrap();
+
+		// Assert
+		assert_eq!(event.members.len(), 1);
+		assert_eq!(event.members[0].metadata["name"], "Alice");
+	}
+}
@@ -3,8 +3,14 @@
 //! This hook provides a way to establish and manage WebSocket connections
 //! in a reactive manner, integrating seamlessly with reinhardt-pages'
 //! fine-grained reactivity system.
+//!
+//! Where WebSockets are blocked, the hook falls back to HTTP long polling
+//! against a `LongPollHub` endpoint of reinhardt-websockets (see
+//! [`UseWebSocketOptions::long_poll_url`]). The handle behaves the same on
+//! both transports.
 
 use crate::reactive::Signal;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
 /// WebSocket connection state
@@ -31,6 +37,19 @@ pub enum WebSocketMessage {
 	Binary(Vec<u8>),
 }
 
+/// Transport used by [`use_websocket`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WebSocketTransport {
+	/// Try a WebSocket first and fall back to long polling if it cannot be
+	/// opened and a [`UseWebSocketOptions::long_poll_url`] is set
+	#[default]
+	Auto,
+	/// WebSocket only
+	WebSocket,
+	/// HTTP long polling only
+	LongPolling,
+}
+
 /// Options for configuring WebSocket behavior
 pub struct UseWebSocketOptions {
 	/// Enable automatic reconnection on disconnect
@@ -45,6 +64,11 @@ pub struct UseWebSocketOptions {
 	pub on_close: Option<Rc<dyn Fn()>>,
 	/// Callback when error occurs
 	pub on_error: Option<Rc<dyn Fn(String)>>,
+	/// Transport selection
+	pub transport: WebSocketTransport,
+	/// Long-polling endpoint served by a `LongPollHub`
+	/// (e.g. "/realtime/poll"); required for long polling
+	pub long_poll_url: Option<String>,
 }
 
 impl Default for UseWebSocketOptions {
@@ -56,10 +80,68 @@ impl Default for UseWebSocketOptions {
 			on_open: None,
 			on_close: None,
 			on_error: None,
+			transport: WebSocketTransport::Auto,
+			long_poll_url: None,
 		}
 	}
 }
 
+/// Message as exchanged with a `LongPollHub` (same JSON as its `Message`)
+#[cfg_attr(not(wasm), allow(dead_code))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+enum WireMessage {
+	Text { data: String },
+	Binary { data: Vec<u8> },
+	Ping,
+	Pong,
+	Close { code: u16, reason: String },
+}
+
+impl From<WebSocketMessage> for WireMessage {
+	fn from(message: WebSocketMessage) -> Self {
+		match message {
+			WebSocketMessage::Text(data) => Self::Text { data },
+			WebSocketMessage::Binary(data) => Self::Binary { data },
+		}
+	}
+}
+
+/// Response of opening a long-polling session
+#[cfg_attr(not(wasm), allow(dead_code))]
+#[derive(Debug, Deserialize)]
+struct PollHandshake {
+	token: String,
+}
+
+/// Response of a long poll
+#[cfg_attr(not(wasm), allow(dead_code))]
+#[derive(Debug, Deserialize)]
+struct PollBatch {
+	token: String,
+	cursor: u64,
+	messages: Vec<WireMessage>,
+}
+
+/// URL of the long-polling endpoint for a session request
+///
+/// `cursor` acknowledges the messages of the previous batch.
+#[cfg_attr(not(wasm), allow(dead_code))]
+fn long_poll_request_url(base: &str, token: &str, cursor: Option<u64>) -> String {
+	let separator = if base.contains('?') { '&' } else { '?' };
+	let mut url = format!("{}{}token={}", base, separator, urlencoding::encode(token));
+	if let Some(cursor) = cursor {
+		url.push_str(&format!("&ack={}", cursor));
+	}
+	url
+}
+
+/// Whether a WebSocket that closes before opening is replaced by long polling
+#[cfg_attr(not(wasm), allow(dead_code))]
+fn can_fall_back(options: &UseWebSocketOptions) -> bool {
+	options.transport == WebSocketTransport::Auto && options.long_poll_url.is_some()
+}
+
 /// Stored event listener closures for proper lifecycle management.
 ///
 /// On WASM targets, this holds the actual `Closure` instances so they remain
@@ -83,6 +165,7 @@ struct WsClosures {
 /// dropped, preventing memory leaks in long-running single-page applications.
 pub struct WebSocketHandle {
 	connection_state: Signal<ConnectionState>,
+	transport: Signal<WebSocketTransport>,
 	latest_message: Signal<Option<WebSocketMessage>>,
 	send_fn: Rc<dyn Fn(WebSocketMessage) -> Result<(), String>>,
 	close_fn: Rc<dyn Fn()>,
@@ -98,6 +181,13 @@ impl WebSocketHandle {
 		&self.connection_state
 	}
 
+	/// Get a reference to the signal of the transport in use
+	///
+	/// `WebSocket` until the hook falls back to `LongPolling`; never `Auto`.
+	pub fn transport(&self) -> &Signal<WebSocketTransport> {
+		&self.transport
+	}
+
 	/// Get a reference to the latest message signal
 	pub fn latest_message(&self) -> &Signal<Option<WebSocketMessage>> {
 		&self.latest_message
@@ -148,6 +238,7 @@ impl Clone for WebSocketHandle {
 	fn clone(&self) -> Self {
 		Self {
 			connection_state: self.connection_state.clone(),
+			transport: self.transport.clone(),
 			latest_message: self.latest_message.clone(),
 			send_fn: Rc::clone(&self.send_fn),
 			close_fn: Rc::clone(&self.close_fn),
@@ -163,11 +254,189 @@ impl Clone for WebSocketHandle {
 
 #[cfg(wasm)]
 use {
-	std::cell::RefCell,
+	std::cell::{Cell, RefCell},
 	wasm_bindgen::{JsCast, JsValue, closure::Closure},
 	web_sys::{CloseEvent, ErrorEvent, MessageEvent, WebSocket},
 };
 
+/// Long-polling session with a `LongPollHub`
+///
+/// The resumption token survives failed requests, and every poll
+/// acknowledges the previous batch, so the server redelivers messages whose
+/// response was lost. A new session is only opened when the server answers
+/// `410 Gone`.
+#[cfg(wasm)]
+struct LongPollClient {
+	url: String,
+	token: RefCell<Option<String>>,
+	cursor: Cell<Option<u64>>,
+	closed: Cell<bool>,
+	connection_state: Signal<ConnectionState>,
+	latest_message: Signal<Option<WebSocketMessage>>,
+	on_open: Option<Rc<dyn Fn()>>,
+	on_close: Option<Rc<dyn Fn()>>,
+	on_error: Option<Rc<dyn Fn(String)>>,
+	/// Consecutive failed requests tolerated before giving up
+	max_failures: usize,
+	retry_delay: u32,
+}
+
+#[cfg(wasm)]
+impl LongPollClient {
+	fn start(self: &Rc<Self>) {
+		let client = Rc::clone(self);
+		wasm_bindgen_futures::spawn_local(async move { client.run().await });
+	}
+
+	async fn run(&self) {
+		let mut failures = 0;
+		while !self.closed.get() {
+			let result = if self.token.borrow().is_none() {
+				self.open().await
+			} else {
+				self.poll().await
+			};
+			match result {
+				Ok(()) => failures = 0,
+				Err(error) => {
+					failures += 1;
+					self.connection_state
+						.set(ConnectionState::Error(error.clone()));
+					if let Some(cb) = &self.on_error {
+						cb(error);
+					}
+					if failures > self.max_failures {
+						self.closed.set(true);
+						return;
+					}
+					sleep_ms(self.retry_delay).await;
+				}
+			}
+		}
+	}
+
+	async fn open(&self) -> Result<(), String> {
+		let response = crate::fetch::request("POST", &self.url, None, Vec::new())
+			.await
+			.map_err(|e| format!("Long-polling handshake failed: {}", e))?;
+		if !response.is_success() {
+			return Err(format!(
+				"Long-polling handshake failed with HTTP {}",
+				response.status()
+			));
+		}
+		let handshake: PollHandshake = response.json().map_err(|e| e.to_string())?;
+		*self.token.borrow_mut() = Some(handshake.token);
+		self.cursor.set(None);
+		self.connection_state.set(ConnectionState::Open);
+		if let Some(cb) = &self.on_open {
+			cb();
+		}
+		Ok(())
+	}
+
+	async fn poll(&self) -> Result<(), String> {
+		let Some(token) = self.token.borrow().clone() else {
+			return Ok(());
+		};
+		let url = long_poll_request_url(&self.url, &token, self.cursor.get());
+		let response = crate::fetch::request("GET", &url, None, Vec::new())
+			.await
+			.map_err(|e| format!("Long poll failed: {}", e))?;
+		if self.closed.get() {
+			return Ok(());
+		}
+		if response.status() == 410 {
+			// The session expired on the server; open a new one.
+			*self.token.borrow_mut() = None;
+			self.connection_state.set(ConnectionState::Connecting);
+			return Ok(());
+		}
+		if !response.is_success() {
+			return Err(format!("Long poll failed with HTTP {}", response.status()));
+		}
+		let batch: PollBatch = response.json().map_err(|e| e.to_string())?;
+		*self.token.borrow_mut() = Some(batch.token);
+		self.cursor.set(Some(batch.cursor));
+		if self.connection_state.get_untracked() != ConnectionState::Open {
+			self.connection_state.set(ConnectionState::Open);
+		}
+		for message in batch.messages {
+			match message {
+				WireMessage::Text { data } => {
+					self.latest_message.set(Some(WebSocketMessage::Text(data)))
+				}
+				WireMessage::Binary { data } => self
+					.latest_message
+					.set(Some(WebSocketMessage::Binary(data))),
+				WireMessage::Close { .. } => {
+					self.finish();
+					return Ok(());
+				}
+				WireMessage::Ping | WireMessage::Pong => {}
+			}
+		}
+		Ok(())
+	}
+
+	fn send(&self, message: WebSocketMessage) -> Result<(), String> {
+		let token = self
+			.token
+			.borrow()
+			.clone()
+			.ok_or("Long-polling session not open")?;
+		let body = serde_json::to_string(&WireMessage::from(message))
+			.map_err(|e| format!("JSON serialization error: {}", e))?;
+		let url = long_poll_request_url(&self.url, &token, None);
+		let on_error = self.on_error.clone();
+		wasm_bindgen_futures::spawn_local(async move {
+			let headers = vec![("Content-Type".to_string(), "application/json".to_string())];
+			let error = match crate::fetch::request("POST", &url, Some(&body), headers).await {
+				Ok(response) if response.is_success() => return,
+				Ok(response) => format!("Failed to send message: HTTP {}", response.status()),
+				Err(e) => format!("Failed to send message: {}", e),
+			};
+			if let Some(cb) = &on_error {
+				cb(error);
+			}
+		});
+		Ok(())
+	}
+
+	fn close(&self) {
+		if self.closed.get() {
+			return;
+		}
+		let token = self.token.borrow_mut().take();
+		self.finish();
+		if let Some(token) = token {
+			let url = long_poll_request_url(&self.url, &token, None);
+			wasm_bindgen_futures::spawn_local(async move {
+				let _ = crate::fetch::request("DELETE", &url, None, Vec::new()).await;
+			});
+		}
+	}
+
+	fn finish(&self) {
+		self.closed.set(true);
+		self.connection_state.set(ConnectionState::Closed);
+		if let Some(cb) = &self.on_close {
+			cb();
+		}
+	}
+}
+
+#[cfg(wasm)]
+async fn sleep_ms(ms: u32) {
+	let promise = js_sys::Promise::new(&mut |resolve, _| {
+		if let Some(window) = web_sys::window() {
+			let _ =
+				window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms as i32);
+		}
+	});
+	let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
 /// Establish and manage a WebSocket connection (WASM implementation)
 ///
 /// This hook creates a reactive WebSocket connection that integrates with
@@ -240,6 +509,55 @@ pub fn use_websocket(url: &str, options: UseWebSocketOptions) -> WebSocketHandle
 	// State signals
 	let connection_state = Signal::new(ConnectionState::Connecting);
 	let latest_message = Signal::new(None);
+	let transport = Signal::new(match options.transport {
+		WebSocketTransport::LongPolling => WebSocketTransport::LongPolling,
+		_ => WebSocketTransport::WebSocket,
+	});
+
+	// Long-polling session, once the hook uses that transport
+	let long_poll: Rc<RefCell<Option<Rc<LongPollClient>>>> = Rc::new(RefCell::new(None));
+	let start_long_poll: Rc<dyn Fn()> = {
+		let long_poll = Rc::clone(&long_poll);
+		let connection_state = connection_state.clone();
+		let latest_message = latest_message.clone();
+		let transport = transport.clone();
+		let long_poll_url = options.long_poll_url.clone();
+		let on_open = options.on_open.clone();
+		let on_close = options.on_close.clone();
+		let on_error = options.on_error.clone();
+		let max_failures = if options.auto_reconnect {
+			options.max_reconnect_attempts
+		} else {
+			0
+		};
+		let retry_delay = options.reconnect_delay;
+
+		Rc::new(move || {
+			let Some(url) = long_poll_url.clone() else {
+				connection_state.set(ConnectionState::Error(
+					"Long polling requires UseWebSocketOptions::long_poll_url".to_string(),
+				));
+				return;
+			};
+			let client = Rc::new(LongPollClient {
+				url,
+				token: RefCell::new(None),
+				cursor: Cell::new(None),
+				closed: Cell::new(false),
+				connection_state: connection_state.clone(),
+				latest_message: latest_message.clone(),
+				on_open: on_open.clone(),
+				on_close: on_close.clone(),
+				on_error: on_error.clone(),
+				max_failures,
+				retry_delay,
+			});
+			transport.set(WebSocketTransport::LongPolling);
+			connection_state.set(ConnectionState::Connecting);
+			client.start();
+			*long_poll.borrow_mut() = Some(client);
+		})
+	};
 
 	// Connection function
 	let connect = {
@@ -251,11 +569,17 @@ pub fn use_websocket(url: &str, options: UseWebSocketOptions) -> WebSocketHandle
 		let on_open = options.on_open.clone();
 		let on_close = options.on_close.clone();
 		let on_error = options.on_error.clone();
+		let can_fall_back = can_fall_back(&options);
+		let start_long_poll = Rc::clone(&start_long_poll);
 
 		move || {
 			// Create WebSocket connection
 			let ws = match WebSocket::new(&url) {
 				Ok(ws) => ws,
+				Err(_) if can_fall_back => {
+					start_long_poll();
+					return;
+				}
 				Err(e) => {
 					connection_state.set(ConnectionState::Error(format!(
 						"Failed to create WebSocket: {:?}",
@@ -268,10 +592,16 @@ pub fn use_websocket(url: &str, options: UseWebSocketOptions) -> WebSocketHandle
 			// Set binary type to arraybuffer for binary message support
 			ws.set_binary_type(web_sys::BinaryType::Arraybuffer);
 
+			// Whether the socket ever opened; one that did not may fall back
+			// to long polling
+			let opened = Rc::new(Cell::new(false));
+
 			// onopen handler
 			let connection_state_open = connection_state.clone();
 			let on_open_cb = on_open.clone();
+			let opened_open = Rc::clone(&opened);
 			let onopen = Closure::wrap(Box::new(move |_: JsValue| {
+				opened_open.set(true);
 				connection_state_open.set(ConnectionState::Open);
 				if let Some(cb) = &on_open_cb {
 					cb();
@@ -299,7 +629,13 @@ pub fn use_websocket(url: &str, options: UseWebSocketOptions) -> WebSocketHandle
 			// onclose handler
 			let connection_state_close = connection_state.clone();
 			let on_close_cb = on_close.clone();
+			let opened_close = Rc::clone(&opened);
+			let start_long_poll_close = Rc::clone(&start_long_poll);
 			let onclose = Closure::wrap(Box::new(move |_: CloseEvent| {
+				if can_fall_back && !opened_close.get() {
+					start_long_poll_close();
+					return;
+				}
 				connection_state_close.set(ConnectionState::Closed);
 				if let Some(cb) = &on_close_cb {
 					cb();
@@ -310,7 +646,12 @@ pub fn use_websocket(url: &str, options: UseWebSocketOptions) -> WebSocketHandle
 			// onerror handler
 			let connection_state_error = connection_state.clone();
 			let on_error_cb = on_error.clone();
+			let opened_error = Rc::clone(&opened);
 			let onerror = Closure::wrap(Box::new(move |_: ErrorEvent| {
+				// The close event that follows starts the fallback.
+				if can_fall_back && !opened_error.get() {
+					return;
+				}
 				let error_msg = "WebSocket error occurred".to_string();
 				connection_state_error.set(ConnectionState::Error(error_msg.clone()));
 				if let Some(cb) = &on_error_cb {
@@ -332,12 +673,20 @@ pub fn use_websocket(url: &str, options: UseWebSocketOptions) -> WebSocketHandle
 	};
 
 	// Initial connection
-	connect();
+	if options.transport == WebSocketTransport::LongPolling {
+		start_long_poll();
+	} else {
+		connect();
+	}
 
 	// Send function
 	let send_fn = {
 		let ws_ref = Rc::clone(&ws_ref);
+		let long_poll = Rc::clone(&long_poll);
 		Rc::new(move |message: WebSocketMessage| {
+			if let Some(client) = long_poll.borrow().as_ref() {
+				return client.send(message);
+			}
 			let ws = ws_ref.borrow();
 			let ws = ws.as_ref().ok_or("WebSocket not initialized")?;
 
@@ -355,8 +704,11 @@ pub fn use_websocket(url: &str, options: UseWebSocketOptions) -> WebSocketHandle
 	// Close function
 	let close_fn = {
 		let ws_ref = Rc::clone(&ws_ref);
+		let long_poll = Rc::clone(&long_poll);
 		Rc::new(move || {
-			if let Some(ws) = ws_ref.borrow().as_ref() {
+			if let Some(client) = long_poll.borrow().as_ref() {
+				client.close();
+			} else if let Some(ws) = ws_ref.borrow().as_ref() {
 				let _ = ws.close();
 			}
 		})
@@ -364,6 +716,7 @@ pub fn use_websocket(url: &str, options: UseWebSocketOptions) -> WebSocketHandle
 
 	WebSocketHandle {
 		connection_state,
+		transport,
 		latest_message,
 		send_fn,
 		close_fn,
@@ -393,6 +746,7 @@ pub fn use_websocket(url: &str, options: UseWebSocketOptions) -> WebSocketHandle
 pub fn use_websocket(_url: &str, _options: UseWebSocketOptions) -> WebSocketHandle {
 	WebSocketHandle {
 		connection_state: Signal::new(ConnectionState::Closed),
+		transport: Signal::new(WebSocketTransport::WebSocket),
 		latest_message: Signal::new(None),
 		send_fn: Rc::new(|_| Err("WebSocket not available on server".to_string())),
 		close_fn: Rc::new(|| {}),
@@ -446,4 +800,56 @@ mod tests {
 		assert!(options.on_close.is_none());
 		assert!(options.on_error.is_none());
 	}
+
+	#[test]
+	fn test_fall_back_requires_auto_transport_and_url() {
+		let mut options = UseWebSocketOptions::default();
+		assert!(!can_fall_back(&options));
+
+		options.long_poll_url = Some("/realtime/poll".to_string());
+		assert!(can_fall_back(&options));
+
+		options.transport = WebSocketTransport::WebSocket;
+		assert!(!can_fall_back(&options));
+	}
+
+	#[test]
+	fn test_long_poll_request_url() {
+		assert_eq!(
+			long_poll_request_url("/realtime/poll", "abc", Some(3)),
+			"/realtime/poll?token=abc&ack=3"
+		);
+		assert_eq!(
+			long_poll_request_url("/realtime/poll?room=1", "a b", None),
+			"/realtime/poll?room=1&token=a%20b"
+		);
+	}
+
+	#[test]
+	fn test_poll_batch_wire_format() {
+		let json = r#"{"token":"t","cursor":2,"messages":[{"type":"Text","data":"hi"},{"type":"Binary","data":[1,2]},{"type":"Close","code":1000,"reason":"bye"}]}"#;
+
+		let batch: PollBatch = serde_json::from_str(json).unwrap();
+
+		assert_eq!(batch.token, "t");
+		assert_eq!(batch.cursor, 2);
+		assert_eq!(
+			batch.messages,
+			vec![
+				WireMessage::Text {
+					data: "hi".to_string()
+				},
+				WireMessage::Binary { data: vec![1, 2] },
+				WireMessage::Close {
+					code: 1000,
+					reason: "bye".to_string()
+				},
+			]
+		);
+		assert_eq!(
+			serde_json::to_string(&WireMessage::from(WebSocketMessage::Text("yo".to_string())))
+				.unwrap(),
+			r#"{"type":"Text","data":"yo"}"#
+		);
+	}
 }
@@ -10,6 +10,7 @@
 //! - **Named Routes**: Reverse URL lookup by route name
 //! - **Reactive Navigation**: Signal-based current route tracking
 //! - **Route Guards**: Optional authentication/authorization checks
+//! - **Prefetching**: Route data loaded ahead of navigation by [`Link`]
 //!
 //! ## Usage
 //!
@@ -63,6 +64,7 @@
 mod components;
 mod history;
 mod navigate;
+mod prefetch;
 
 /// Manouche DSL v2 spec §4.3 `FromRequest`-based page handlers.
 ///
@@ -105,6 +107,7 @@ pub mod request {
 pub use components::{Link, Redirect, RouterOutlet, guard, guard_or};
 pub use history::{HistoryState, NavigationType};
 pub use navigate::navigate;
+pub use prefetch::{clear_prefetched, prefetch, register_prefetch};
 pub use reinhardt_urls::routers::ClientRouter;
 pub use reinhardt_urls::routers::client_router::Path;
 // `setup_popstate_listener` is wasm-only — see `history` module docs.
@@ -3,12 +3,17 @@
 //! This module provides Link and RouterOutlet components for
 //! declarative navigation in component trees.
 
+use crate::app::try_with_spa_router;
 use crate::component::{Component, IntoPage, Page, PageElement};
+use crate::reactive::{Memo, Signal};
 use reinhardt_urls::routers::ClientRouter;
 
 /// A link component that navigates without full page reload.
 ///
 /// Similar to HTML `<a>` but intercepts clicks to use the History API.
+/// While the SPA router's current path matches the destination, the anchor
+/// carries `aria-current="page"` (and the [`active_class`](Self::active_class),
+/// if set); see [`active`](Self::active) for the underlying signal.
 ///
 /// # Example
 ///
@@ -16,6 +21,10 @@ use reinhardt_urls::routers::ClientRouter;
 /// use reinhardt_pages::router::Link;
 ///
 /// let link = Link::new("/users/42/", "View User");
+/// let nav = Link::new("/users/", "Users")
+///     .exact(false)
+///     .active_class("is-active")
+///     .prefetch(true);
 /// ```
 #[derive(Debug, Clone)]
 pub struct Link {
@@ -25,6 +34,12 @@ pub struct Link {
 	content: String,
 	/// Additional CSS classes.
 	class: Option<String>,
+	/// CSS class added while the link is active.
+	active_class: Option<String>,
+	/// Whether only the exact destination path makes the link active.
+	exact: bool,
+	/// Whether to prefetch the destination route's data.
+	prefetch: bool,
 	/// Whether to replace the current history entry.
 	replace: bool,
 	/// Whether to open in a new tab (disables SPA navigation).
@@ -40,6 +55,9 @@ impl Link {
 			to: to.into(),
 			content: content.into(),
 			class: None,
+			active_class: None,
+			exact: true,
+			prefetch: false,
 			replace: false,
 			external: false,
 			attrs: Vec::new(),
@@ -52,6 +70,32 @@ impl Link {
 		self
 	}
 
+	/// Sets the CSS class added while the link is active.
+	pub fn active_class(mut self, class: impl Into<String>) -> Self {
+		self.active_class = Some(class.into());
+		self
+	}
+
+	/// Sets whether only the exact destination path makes the link active.
+	///
+	/// Defaults to `true`. With `false`, paths below the destination are
+	/// active too (`/users/42/` activates a link to `/users/`).
+	pub fn exact(mut self, exact: bool) -> Self {
+		self.exact = exact;
+		self
+	}
+
+	/// Sets whether to prefetch the destination route's data.
+	///
+	/// Prefetching runs the prefetcher registered with
+	/// [`register_prefetch`](super::register_prefetch) when the pointer
+	/// enters the link, when it receives focus, or when it scrolls into the
+	/// viewport.
+	pub fn prefetch(mut self, prefetch: bool) -> Self {
+		self.prefetch = prefetch;
+		self
+	}
+
 	/// Sets whether to replace the current history entry.
 	pub fn replace(mut self, replace: bool) -> Self {
 		self.replace = replace;
@@ -89,14 +133,50 @@ impl Link {
 	pub fn is_external(&self) -> bool {
 		self.external
 	}
-}
 
-impl Component for Link {
-	fn render(&self) -> Page {
+	/// Returns whether prefetching is enabled.
+	pub fn is_prefetch(&self) -> bool {
+		self.prefetch
+	}
+
+	/// Returns a signal tracking whether the link is active.
+	///
+	/// Follows the installed SPA router's current path. Always `false` for
+	/// external links and when no SPA router is installed (e.g. during SSR).
+	pub fn active(&self) -> Memo<bool> {
+		let current = self.current_path();
+		let to = self.to.clone();
+		let exact = self.exact;
+		Memo::new(move || {
+			current
+				.as_ref()
+				.is_some_and(|path| is_active_path(&path.get(), &to, exact))
+		})
+	}
+
+	fn current_path(&self) -> Option<Signal<String>> {
+		if self.external {
+			return None;
+		}
+		try_with_spa_router(|r| r.current_path().clone())
+	}
+
+	fn anchor(&self, active: bool) -> PageElement {
 		let mut el = PageElement::new("a").attr("href", self.to.clone());
 
-		if let Some(ref class) = self.class {
-			el = el.attr("class", class.clone());
+		let class = [
+			self.class.as_deref(),
+			self.active_class.as_deref().filter(|_| active),
+		]
+		.into_iter()
+		.flatten()
+		.collect::<Vec<_>>()
+		.join(" ");
+		if !class.is_empty() {
+			el = el.attr("class", class);
+		}
+		if active {
+			el = el.attr("aria-current", "page");
 		}
 
 		// Add data attributes for JS handling
@@ -105,6 +185,9 @@ impl Component for Link {
 			if self.replace {
 				el = el.attr("data-replace", "true");
 			}
+			if self.prefetch {
+				el = el.attr("data-prefetch", "true");
+			}
 		} else {
 			el = el.attr("target", "_blank");
 			el = el.attr("rel", "noopener noreferrer");
@@ -115,7 +198,99 @@ impl Component for Link {
 			el = el.attr(name.clone(), value.clone());
 		}
 
-		el.child(self.content.clone()).into_page()
+		if !self.external {
+			el = self.with_handlers(el);
+		}
+
+		el.child(self.content.clone())
+	}
+
+	fn with_handlers(&self, mut el: PageElement) -> PageElement {
+		#[cfg(wasm)]
+		{
+			use crate::router::{NavigationType, navigate};
+
+			let to = self.to.clone();
+			let nav = if self.replace {
+				NavigationType::Replace
+			} else {
+				NavigationType::Push
+			};
+			el = el.listener("click", move |event| {
+				// Without an SPA router (or when it rejects the path) the
+				// browser performs a regular navigation.
+				if is_plain_click(&event) && navigate(to.clone(), nav).is_ok() {
+					event.prevent_default();
+				}
+			});
+		}
+
+		if self.prefetch {
+			for event in ["mouseenter", "focus"] {
+				let to = self.to.clone();
+				el = el.listener(event, move |_| {
+					super::prefetch::prefetch(&to);
+				});
+			}
+			// Start observing viewport entry once the anchor is mounted.
+			#[cfg(wasm)]
+			crate::platform::spawn_task(async {
+				crate::platform::defer_yield().await;
+				super::prefetch::observe_viewport_links();
+			});
+		}
+
+		el
+	}
+}
+
+/// Whether `event` is an unmodified primary-button click.
+///
+/// Modified and non-primary clicks (open in new tab, download, ...) are left
+/// to the browser.
+#[cfg(wasm)]
+fn is_plain_click(event: &web_sys::Event) -> bool {
+	use wasm_bindgen::JsCast;
+
+	event.dyn_ref::<web_sys::MouseEvent>().is_none_or(|mouse| {
+		mouse.button() == 0
+			&& !(mouse.ctrl_key() || mouse.meta_key() || mouse.shift_key() || mouse.alt_key())
+	})
+}
+
+/// Whether `current` activates a link to `to`, ignoring query and fragment.
+fn is_active_path(current: &str, to: &str, exact: bool) -> bool {
+	let strip = |path: &str| {
+		path.split(['?', '#'])
+			.next()
+			.unwrap_or_default()
+			.to_string()
+	};
+	let current = strip(current);
+	let to = strip(to);
+	if exact || to == "/" {
+		return current == to;
+	}
+	match current.strip_prefix(to.trim_end_matches('/')) {
+		Some(rest) => rest.is_empty() || rest.starts_with('/'),
+		None => false,
+	}
+}
+
+impl Component for Link {
+	fn render(&self) -> Page {
+		match self.current_path() {
+			// Re-render the anchor when the current path changes so that
+			// `aria-current` and the active class follow navigation.
+			Some(current) => {
+				let link = self.clone();
+				Page::reactive(move || {
+					let active = is_active_path(&current.get(), &link.to, link.exact);
+					link.anchor(active).into_page()
+				})
+			}
+			None => self.anchor(false).into_page(),
+		}
 	}
 
 	fn name() -> &'static str {
@@ -377,4 +552,76 @@ mod tests {
 	fn test_redirect_component_name() {
 		assert_eq!(Redirect::name(), "Redirect");
 	}
+
+	fn install_router() {
+		crate::app::__install_client_router_for_test(
+			ClientRouter::new()
+				.route("home", "/", || Page::text("Home"))
+				.route("user_list", "/users/", || Page::text("Users"))
+				.route("user_detail", "/users/{id}/", || Page::text("User")),
+		);
+	}
+
+	#[test]
+	fn test_link_active_follows_current_path() {
+		install_router();
+		let link = Link::new("/users/", "Users").active_class("is-active");
+		let active = link.active();
+
+		crate::router::navigate("/users/", crate::router::NavigationType::Push).unwrap();
+		assert!(active.get());
+		let html = link.render().render_to_string();
+		assert!(html.contains("aria-current=\"page\""));
+		assert!(html.contains("class=\"is-active\""));
+
+		crate::router::navigate("/users/42/", crate::router::NavigationType::Push).unwrap();
+		assert!(!active.get());
+		assert!(!link.render().render_to_string().contains("aria-current"));
+		crate::app::__clear_spa_router_for_test();
+	}
+
+	#[test]
+	fn test_link_inactive_without_router() {
+		let link = Link::new("/users/", "Users").class("nav-link");
+
+		assert!(!link.active().get());
+		let html = link.render().render_to_string();
+		assert!(html.contains("class=\"nav-link\""));
+		assert!(!html.contains("aria-current"));
+	}
+
+	#[test]
+	fn test_is_active_path() {
+		assert!(is_active_path("/users/", "/users/", true));
+		assert!(is_active_path("/users/?page=2", "/users/", true));
+		assert!(!is_active_path("/users/42/", "/users/", true));
+		assert!(is_active_path("/users/42/", "/users/", false));
+		assert!(!is_active_path("/usersettings/", "/users", false));
+		assert!(!is_active_path("/users/", "/", false));
+	}
+
+	#[test]
+	fn test_link_prefetch_runs_registered_prefetcher_once() {
+		install_router();
+		let fetched = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+		crate::router::register_prefetch("user_detail", {
+			let fetched = fetched.clone();
+			move |params| fetched.borrow_mut().push(params["id"].clone())
+		});
+		let link = Link::new("/users/42/", "User").prefetch(true);
+
+		assert!(
+			link.render()
+				.render_to_string()
+				.contains("data-prefetch=\"true\"")
+		);
+		assert!(crate::router::prefetch(link.to()));
+		assert!(!crate::router::prefetch(link.to()));
+		assert!(!crate::router::prefetch("/users/"));
+		assert_eq!(*fetched.borrow(), vec!["42".to_string()]);
+
+		crate::router::clear_prefetched();
+		assert!(crate::router::prefetch(link.to()));
+		crate::app::__clear_spa_router_for_test();
+	}
 }
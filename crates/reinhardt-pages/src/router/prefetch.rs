@@ -0,0 +1,138 @@
+//! Route data prefetching for [`Link`](super::Link).
+//!
+//! Applications register a prefetcher per named route, typically a server
+//! function call that warms a client-side cache. A `Link` with prefetching
+//! enabled runs the prefetcher of its target route when the pointer enters
+//! the link, when it receives focus, or when it scrolls into the viewport.
+//!
+//! ```ignore
+//! use reinhardt_pages::router::{Link, register_prefetch};
+//!
+//! register_prefetch("user_detail", |params| {
+//!     let id = params.get("id").cloned().unwrap_or_default();
+//!     spawn_task(async move {
+//!         let _ = user_cache().load(&id).await;
+//!     });
+//! });
+//!
+//! let link = Link::new("/users/42/", "View User").prefetch(true);
+//! ```
+//!
+//! Each path is prefetched at most once until [`clear_prefetched`] is
+//! called.
+
+use crate::app::try_with_spa_router;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+type Prefetcher = Rc<dyn Fn(&HashMap<String, String>)>;
+
+thread_local! {
+	static PREFETCHERS: RefCell<HashMap<String, Prefetcher>> = RefCell::new(HashMap::new());
+	static PREFETCHED: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+}
+
+/// Registers the prefetcher of the route named `route_name`.
+///
+/// The prefetcher receives the path parameters of the prefetched path.
+/// Registering again for the same route replaces the previous prefetcher.
+pub fn register_prefetch<F>(route_name: impl Into<String>, prefetcher: F)
+where
+	F: Fn(&HashMap<String, String>) + 'static,
+{
+	PREFETCHERS.with(|p| {
+		p.borrow_mut()
+			.insert(route_name.into(), Rc::new(prefetcher));
+	});
+}
+
+/// Prefetches the data of the route matching `path`.
+///
+/// Returns `true` when a prefetcher ran. Returns `false` when `path` was
+/// already prefetched, no SPA router is installed, no route matches, or the
+/// matched route has no prefetcher.
+pub fn prefetch(path: &str) -> bool {
+	if PREFETCHED.with(|p| p.borrow().contains(path)) {
+		return false;
+	}
+	let Some((name, params)) = try_with_spa_router(|r| r.match_path(path))
+		.flatten()
+		.and_then(|m| Some((m.name?, m.params)))
+	else {
+		return false;
+	};
+	// Clone the prefetcher out of the registry so it may register other
+	// prefetchers without a re-entrant borrow.
+	let Some(prefetcher) = PREFETCHERS.with(|p| p.borrow().get(&name).cloned()) else {
+		return false;
+	};
+	PREFETCHED.with(|p| p.borrow_mut().insert(path.to_string()));
+	prefetcher(&params);
+	true
+}
+
+/// Forgets which paths were prefetched, so the next [`prefetch`] of each
+/// path runs its prefetcher again.
+pub fn clear_prefetched() {
+	PREFETCHED.with(|p| p.borrow_mut().clear());
+}
+
+#[cfg(wasm)]
+thread_local! {
+	static VIEWPORT_OBSERVER: RefCell<Option<web_sys::IntersectionObserver>> =
+		const { RefCell::new(None) };
+}
+
+/// Observes every not yet observed `<a data-prefetch>` element and prefetches
+/// its `href` once it enters the viewport.
+#[cfg(wasm)]
+pub(super) fn observe_viewport_links() {
+	use wasm_bindgen::JsCast;
+	use wasm_bindgen::closure::Closure;
+
+	let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+		return;
+	};
+	let Ok(links) = document.query_selector_all("a[data-prefetch]:not([data-prefetch-observed])")
+	else {
+		return;
+	};
+
+	VIEWPORT_OBSERVER.with(|slot| {
+		let mut slot = slot.borrow_mut();
+		if slot.is_none() {
+			let callback = Closure::<dyn FnMut(js_sys::Array, web_sys::IntersectionObserver)>::new(
+				|entries: js_sys::Array, observer: web_sys::IntersectionObserver| {
+					for entry in entries.iter() {
+						let entry: web_sys::IntersectionObserverEntry = entry.unchecked_into();
+						if !entry.is_intersecting() {
+							continue;
+						}
+						let target = entry.target();
+						observer.unobserve(&target);
+						if let Some(href) = target.get_attribute("href") {
+							prefetch(&href);
+						}
+					}
+				},
+			);
+			*slot = web_sys::IntersectionObserver::new(callback.as_ref().unchecked_ref()).ok();
+			// The observer lives for the whole WASM module lifetime, same
+			// posture as the document-level link interceptor.
+			callback.forget();
+		}
+		let Some(observer) = slot.as_ref() else {
+			return;
+		};
+		for index in 0..links.length() {
+			if let Some(link) = links
+				.item(index)
+				.and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+			{
+				let _ = link.set_attribute("data-prefetch-observed", "");
+				observer.observe(&link);
+			}
+		}
+	});
+}
@@ -39,7 +39,11 @@
 //! - **Automatic CSRF protection**: Tokens automatically injected on client side
 //! - **Session propagation**: Cookie-based sessions automatically work
 //! - **Dependency Injection**: `#[inject]` parameters resolved on server side
+//! - **Request context**: `#[inject] ctx: ServerContext` for headers, cookies,
+//!   session, current user and client IP (see [`context`])
 //! - **Multiple codecs**: JSON (default), URL encoding, MessagePack
+//! - **Public endpoints**: `#[server_fn(public)]` adds per-IP throttling,
+//!   signed form tokens and CAPTCHA verification (see [`public`])
 //!
 //! ## Codec Selection (Week 4 Day 3)
 //!
@@ -72,6 +76,7 @@
 //! ```
 
 pub mod codec;
+pub mod context;
 #[cfg(native)]
 pub mod injectable;
 pub mod metadata;
@@ -79,6 +84,7 @@ pub mod metadata;
 pub mod mockable;
 #[cfg(native)]
 pub mod negotiation;
+pub mod public;
 #[cfg(native)]
 pub mod registration;
 #[cfg(native)]
@@ -91,6 +97,7 @@ pub mod server_fn_trait;
 #[cfg(feature = "msgpack")]
 pub use codec::MessagePackCodec;
 pub use codec::{Codec, JsonCodec, UrlCodec};
+pub use context::ServerContext;
 #[cfg(native)]
 pub use injectable::{ServerFnBody, ServerFnRequest};
 pub use metadata::ServerFnMetadata;
@@ -0,0 +1,219 @@
+//! Typed request environment for server functions
+//!
+//! [`ServerContext`] bundles what a server function body usually needs from
+//! the HTTP request — headers, cookies, the session, the current user and the
+//! client IP — behind one `#[inject]` parameter:
+//!
+//! ```ignore
+//! use reinhardt_pages::server_fn::{ServerContext, ServerFnError};
+//!
+//! #[server_fn]
+//! pub async fn whoami(#[inject] ctx: ServerContext) -> Result<String, ServerFnError> {
+//!     if !ctx.user().is_authenticated() {
+//!         return Err(ServerFnError::server(401, "Not logged in"));
+//!     }
+//!     Ok(ctx.user().user_id().to_string())
+//! }
+//! ```
+//!
+//! The context only exists on the server. On WASM the type is an empty
+//! placeholder so that `use` statements shared between client and server
+//! compile, but it has no constructor and no methods: any use outside an
+//! `#[inject]` parameter of a `#[server_fn]` fails to compile, and
+//! `#[server_fn]` rejects a `ServerContext` parameter without `#[inject]`.
+
+#[cfg(native)]
+pub use native::ServerContext;
+
+/// Server-only request environment; see the [module documentation](self).
+///
+/// This client-side placeholder cannot be constructed or used.
+#[cfg(wasm)]
+pub struct ServerContext {
+	_server_only: (),
+}
+
+#[cfg(native)]
+mod native {
+	use super::super::injectable::ServerFnRequest;
+	use async_trait::async_trait;
+	use hyper::HeaderMap;
+	use reinhardt_di::{DiError, DiResult, Injectable, InjectionContext};
+	use reinhardt_http::cookies::CookieJar;
+	use reinhardt_http::{AuthState, Request};
+	use reinhardt_middleware::session::SessionData;
+	use std::net::IpAddr;
+	use std::sync::Arc;
+
+	/// Request environment of a server function call.
+	///
+	/// Resolved once per call when declared as `#[inject] ctx: ServerContext`.
+	#[derive(Clone)]
+	pub struct ServerContext {
+		request: ServerFnRequest,
+		cookies: CookieJar,
+		session: Option<SessionData>,
+		user: AuthState,
+		client_ip: Option<IpAddr>,
+	}
+
+	impl ServerContext {
+		/// Build the context for `request`, without a session.
+		///
+		/// The user is read from the [`AuthState`] stored by the authentication
+		/// middleware and is anonymous if there is none.
+		pub fn new(request: Arc<Request>) -> Self {
+			let cookies = request.cookies();
+			let user = AuthState::from_extensions(&request.extensions)
+				.unwrap_or_else(AuthState::anonymous);
+			let client_ip = request.get_client_ip();
+			Self {
+				request: ServerFnRequest(request),
+				cookies,
+				session: None,
+				user,
+				client_ip,
+			}
+		}
+
+		/// Attach the session of the request.
+		pub fn with_session(mut self, session: SessionData) -> Self {
+			self.session = Some(session);
+			self
+		}
+
+		/// The underlying HTTP request.
+		pub fn request(&self) -> &Request {
+			self.request.inner()
+		}
+
+		/// Request headers.
+		pub fn headers(&self) -> &HeaderMap {
+			&self.request().headers
+		}
+
+		/// Value of the header `name`, if present and valid UTF-8.
+		pub fn header(&self, name: &str) -> Option<&str> {
+			self.headers().get(name).and_then(|v| v.to_str().ok())
+		}
+
+		/// Cookies sent with the request.
+		pub fn cookies(&self) -> &CookieJar {
+			&self.cookies
+		}
+
+		/// Value of the cookie `name`.
+		pub fn cookie(&self, name: &str) -> Option<&str> {
+			self.cookies.get(name)
+		}
+
+		/// Add a `Set-Cookie` header value to the response.
+		///
+		/// See [`ServerFnRequest::add_response_cookie`].
+		pub fn add_response_cookie(&self, cookie: String) {
+			self.request.add_response_cookie(cookie);
+		}
+
+		/// The session of the request, if `SessionMiddleware` loaded a valid one.
+		pub fn session(&self) -> Option<&SessionData> {
+			self.session.as_ref()
+		}
+
+		/// The current user; anonymous if the request is not authenticated.
+		pub fn user(&self) -> &AuthState {
+			&self.user
+		}
+
+		/// The client IP, honouring `X-Forwarded-For` from trusted proxies.
+		pub fn client_ip(&self) -> Option<IpAddr> {
+			self.client_ip
+		}
+	}
+
+	#[async_trait]
+	impl Injectable for ServerContext {
+		async fn inject(ctx: &InjectionContext) -> DiResult<Self> {
+			let request = ctx.get_request::<Request>().ok_or_else(|| {
+				DiError::NotFound(
+					"ServerContext: Request not found in InjectionContext. \
+					 Ensure the server function is invoked through the server_fn router."
+						.to_string(),
+				)
+			})?;
+			// Requests without a session cookie or session middleware simply have
+			// no session.
+			let context = Self::new(request);
+			Ok(match SessionData::inject(ctx).await {
+				Ok(session) => context.with_session(session),
+				Err(_) => context,
+			})
+		}
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+		use rstest::rstest;
+		use std::net::SocketAddr;
+		use std::time::Duration;
+
+		fn request() -> Request {
+			Request::builder()
+				.uri("/api/server_fn/whoami")
+				.header("cookie", "theme=dark")
+				.header("x-client", "pages")
+				.remote_addr(SocketAddr::from(([192, 0, 2, 7], 4000)))
+				.build()
+				.unwrap()
+		}
+
+		#[rstest]
+		fn test_context_reads_request_environment() {
+			// Arrange
+			let request = request();
+			request
+				.extensions
+				.insert(AuthState::authenticated("42", false, true));
+
+			// Act
+			let ctx = ServerContext::new(Arc::new(request));
+
+			// Assert
+			assert_eq!(ctx.header("x-client"), Some("pages"));
+			assert_eq!(ctx.cookie("theme"), Some("dark"));
+			assert_eq!(ctx.user().user_id(), "42");
+			assert_eq!(ctx.client_ip(), Some(IpAddr::from([192, 0, 2, 7])));
+			assert!(ctx.session().is_none());
+		}
+
+		#[rstest]
+		fn test_context_without_auth_state_is_anonymous() {
+			// Arrange
+			let request = request();
+
+			// Act
+			let ctx = ServerContext::new(Arc::new(request));
+
+			// Assert
+			assert!(ctx.user().is_anonymous());
+		}
+
+		#[rstest]
+		fn test_context_with_session() {
+			// Arrange
+			let mut session = SessionData::new(Duration::from_secs(60));
+			session
+				.data
+				.insert("cart".to_string(), serde_json::json!([1, 2]));
+
+			// Act
+			let ctx = ServerContext::new(Arc::new(request())).with_session(session);
+
+			// Assert
+			assert_eq!(
+				ctx.session().unwrap().data.get("cart"),
+				Some(&serde_json::json!([1, 2]))
+			);
+		}
+	}
+}
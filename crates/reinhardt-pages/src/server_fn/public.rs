@@ -0,0 +1,110 @@
+//! Public server functions
+//!
+//! `#[server_fn(public)]` exposes a server function to anonymous visitors,
+//! such as a contact form, behind three protections checked before the
+//! arguments are deserialized:
+//!
+//! - **Per-IP throttling**: at most `throttle = "5/min"` submissions per
+//!   client IP and function (the configured default otherwise).
+//! - **Signed form tokens**: the page rendering the form embeds a token
+//!   signed for the function with [`form_token_tag`]. A submission is only
+//!   accepted between `min_age` (bots submit instantly) and `max_age` after
+//!   the token was issued.
+//! - **CAPTCHA**: when a [`CaptchaVerifier`] is configured, the response of
+//!   the hCaptcha or Turnstile widget on the page is verified server-side.
+//!
+//! The generated client stub reads the token and the widget response from the
+//! page and sends them as the [`FORM_TOKEN_HEADER`] and [`CAPTCHA_HEADER`]
+//! headers.
+//!
+//! The server side requires the `public-forms` feature.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! use reinhardt_pages::server_fn::public::{
+//!     PublicFormConfig, SiteVerifyCaptcha, form_token_tag, set_public_form_config,
+//! };
+//!
+//! set_public_form_config(
+//!     PublicFormConfig::new(settings.secret_key.as_bytes())
+//!         .with_captcha(Arc::new(SiteVerifyCaptcha::turnstile(turnstile_secret))),
+//! );
+//!
+//! #[server_fn(public, throttle = "3/min")]
+//! async fn contact(email: String, message: String) -> Result<(), ServerFnError> {
+//!     // ...
+//! }
+//!
+//! // In the page rendering the form:
+//! head.child(form_token_tag("contact"));
+//! ```
+
+#[cfg(all(native, feature = "public-forms"))]
+mod guard;
+
+#[cfg(all(native, feature = "public-forms"))]
+pub use guard::{
+	CaptchaProvider, CaptchaVerifier, PublicFormConfig, PublicFormError, SiteVerifyCaptcha,
+	form_token_tag, guard, issue_form_token, public_form_config, set_public_form_config,
+	verify_form_token,
+};
+
+/// Header carrying the signed form token.
+pub const FORM_TOKEN_HEADER: &str = "X-Form-Token";
+
+/// Header carrying the response of the CAPTCHA widget.
+pub const CAPTCHA_HEADER: &str = "X-Captcha-Response";
+
+/// Name of the `<meta>` tags holding form tokens; the function name is in
+/// their `data-form` attribute.
+pub const FORM_TOKEN_META: &str = "form-token";
+
+/// Names of the fields the hCaptcha and Turnstile widgets write their
+/// response to.
+pub const CAPTCHA_RESPONSE_FIELDS: [&str; 2] = ["h-captcha-response", "cf-turnstile-response"];
+
+/// Headers sent with a call to the public server function `form`
+///
+/// Contains the form token rendered for `form` and the response of the
+/// CAPTCHA widget on the page, when present.
+#[cfg(wasm)]
+pub fn public_form_headers(form: &str) -> Vec<(&'static str, String)> {
+	let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+		return Vec::new();
+	};
+	let mut headers = Vec::new();
+
+	let selector = format!("meta[name=\"{}\"][data-form=\"{}\"]", FORM_TOKEN_META, form);
+	if let Some(token) = document
+		.query_selector(&selector)
+		.ok()
+		.flatten()
+		.and_then(|meta| meta.get_attribute("content"))
+	{
+		headers.push((FORM_TOKEN_HEADER, token));
+	}
+
+	for field in CAPTCHA_RESPONSE_FIELDS {
+		let selector = format!("[name=\"{}\"]", field);
+		let response = document
+			.query_selector(&selector)
+			.ok()
+			.flatten()
+			.and_then(|element| js_sys::Reflect::get(&element, &"value".into()).ok())
+			.and_then(|value| value.as_string())
+			.filter(|value| !value.is_empty());
+		if let Some(response) = response {
+			headers.push((CAPTCHA_HEADER, response));
+			break;
+		}
+	}
+
+	headers
+}
+
+/// Headers sent with a call to a public server function (non-WASM stub).
+#[cfg(native)]
+pub fn public_form_headers(_form: &str) -> Vec<(&'static str, String)> {
+	Vec::new()
+}
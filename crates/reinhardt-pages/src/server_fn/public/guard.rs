@@ -0,0 +1,523 @@
+//! Server-side checks of public server functions
+
+use super::{CAPTCHA_HEADER, FORM_TOKEN_HEADER, FORM_TOKEN_META};
+use crate::component::{IntoPage, Page, PageElement};
+use async_trait::async_trait;
+use reinhardt_core::security::csrf::{generate_token_hmac, get_secret_bytes, verify_token_hmac};
+use reinhardt_http::Request;
+use reinhardt_throttling::{MemoryBackend, ThrottleBackend, parse_rate};
+use serde::Deserialize;
+use std::net::IpAddr;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+static CONFIG: RwLock<Option<Arc<PublicFormConfig>>> = RwLock::new(None);
+
+/// Reason a submission to a public server function was rejected
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PublicFormError {
+	/// The client IP exceeded the rate of the function.
+	#[error("Too many submissions, try again later")]
+	Throttled,
+	/// The request carries no form token.
+	#[error("Missing form token")]
+	MissingToken,
+	/// The form token is malformed or was signed for another function.
+	#[error("Invalid form token")]
+	InvalidToken,
+	/// The form was submitted sooner than `min_age` after it was rendered.
+	#[error("Form submitted too quickly")]
+	TooFast,
+	/// The form was submitted later than `max_age` after it was rendered.
+	#[error("Form expired, reload the page and try again")]
+	Expired,
+	/// A CAPTCHA is configured but the request carries no response.
+	#[error("CAPTCHA response required")]
+	CaptchaRequired,
+	/// The CAPTCHA provider rejected the response.
+	#[error("CAPTCHA verification failed")]
+	CaptchaFailed,
+	/// The throttle backend or the CAPTCHA provider could not be reached, or
+	/// the configured rate is invalid. Details are logged.
+	#[error("Submissions are temporarily unavailable")]
+	Unavailable,
+}
+
+impl PublicFormError {
+	/// HTTP status returned to the client
+	pub fn status(&self) -> u16 {
+		match self {
+			Self::Throttled => 429,
+			Self::Unavailable => 503,
+			_ => 403,
+		}
+	}
+}
+
+/// Verifies CAPTCHA responses with the provider
+#[async_trait]
+pub trait CaptchaVerifier: Send + Sync {
+	/// Whether `response`, sent by the client at `remote_ip`, is a solved
+	/// challenge. `Err` is returned when the provider cannot be reached.
+	async fn verify(&self, response: &str, remote_ip: Option<IpAddr>) -> Result<bool, String>;
+}
+
+/// CAPTCHA providers with a `siteverify` API
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptchaProvider {
+	/// hCaptcha
+	HCaptcha,
+	/// Cloudflare Turnstile
+	Turnstile,
+}
+
+impl CaptchaProvider {
+	/// Verification endpoint of the provider
+	pub fn verify_url(&self) -> &'static str {
+		match self {
+			Self::HCaptcha => "https://api.hcaptcha.com/siteverify",
+			Self::Turnstile => "https://challenges.cloudflare.com/turnstile/v0/siteverify",
+		}
+	}
+}
+
+/// [`CaptchaVerifier`] posting responses to the `siteverify` API of hCaptcha
+/// or Turnstile
+pub struct SiteVerifyCaptcha {
+	provider: CaptchaProvider,
+	secret: String,
+	client: reqwest::Client,
+}
+
+#[derive(Deserialize)]
+struct SiteVerifyResponse {
+	success: bool,
+}
+
+impl SiteVerifyCaptcha {
+	/// Verifier for `provider` with the secret key of the site
+	pub fn new(provider: CaptchaProvider, secret: impl Into<String>) -> Self {
+		Self {
+			provider,
+			secret: secret.into(),
+			client: reqwest::Client::new(),
+		}
+	}
+
+	/// hCaptcha verifier
+	pub fn hcaptcha(secret: impl Into<String>) -> Self {
+		Self::new(CaptchaProvider::HCaptcha, secret)
+	}
+
+	/// Turnstile verifier
+	pub fn turnstile(secret: impl Into<String>) -> Self {
+		Self::new(CaptchaProvider::Turnstile, secret)
+	}
+
+	/// Provider the responses are verified with
+	pub fn provider(&self) -> CaptchaProvider {
+		self.provider
+	}
+}
+
+impl std::fmt::Debug for SiteVerifyCaptcha {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("SiteVerifyCaptcha")
+			.field("provider", &self.provider)
+			.finish_non_exhaustive()
+	}
+}
+
+#[async_trait]
+impl CaptchaVerifier for SiteVerifyCaptcha {
+	async fn verify(&self, response: &str, remote_ip: Option<IpAddr>) -> Result<bool, String> {
+		let mut params = vec![
+			("secret", self.secret.clone()),
+			("response", response.to_string()),
+		];
+		if let Some(ip) = remote_ip {
+			params.push(("remoteip", ip.to_string()));
+		}
+		let verification = self
+			.client
+			.post(self.provider.verify_url())
+			.form(&params)
+			.send()
+			.await
+			.map_err(|e| e.to_string())?
+			.error_for_status()
+			.map_err(|e| e.to_string())?
+			.json::<SiteVerifyResponse>()
+			.await
+			.map_err(|e| e.to_string())?;
+		Ok(verification.success)
+	}
+}
+
+/// Protections applied to public server functions
+pub struct PublicFormConfig {
+	secret: Vec<u8>,
+	rate: String,
+	min_age: Duration,
+	max_age: Duration,
+	captcha: Option<Arc<dyn CaptchaVerifier>>,
+	backend: Arc<dyn ThrottleBackend>,
+}
+
+impl PublicFormConfig {
+	/// Configuration signing form tokens with `secret`
+	///
+	/// Defaults to 5 submissions per minute and IP, tokens accepted from 2
+	/// seconds to 1 hour after they were issued, no CAPTCHA and an in-memory
+	/// throttle backend.
+	pub fn new(secret: impl AsRef<[u8]>) -> Self {
+		Self {
+			secret: secret.as_ref().to_vec(),
+			rate: "5/min".to_string(),
+			min_age: Duration::from_secs(2),
+			max_age: Duration::from_secs(3_600),
+			captcha: None,
+			backend: Arc::new(MemoryBackend::new()),
+		}
+	}
+
+	/// Default rate of public server functions, such as `"5/min"`
+	pub fn with_rate(mut self, rate: impl Into<String>) -> Self {
+		self.rate = rate.into();
+		self
+	}
+
+	/// Minimum time between rendering a form and submitting it
+	pub fn with_min_age(mut self, min_age: Duration) -> Self {
+		self.min_age = min_age;
+		self
+	}
+
+	/// Maximum time between rendering a form and submitting it
+	pub fn with_max_age(mut self, max_age: Duration) -> Self {
+		self.max_age = max_age;
+		self
+	}
+
+	/// Require a CAPTCHA verified by `verifier`
+	pub fn with_captcha(mut self, verifier: Arc<dyn CaptchaVerifier>) -> Self {
+		self.captcha = Some(verifier);
+		self
+	}
+
+	/// Store submission counts in `backend`, such as Redis when several
+	/// servers share the load
+	pub fn with_throttle_backend(mut self, backend: Arc<dyn ThrottleBackend>) -> Self {
+		self.backend = backend;
+		self
+	}
+
+	/// Default rate of public server functions
+	pub fn rate(&self) -> &str {
+		&self.rate
+	}
+
+	/// Whether a CAPTCHA is required
+	pub fn requires_captcha(&self) -> bool {
+		self.captcha.is_some()
+	}
+
+	fn sign(&self, form: &str, issued_at: u64) -> String {
+		let signature = generate_token_hmac(&self.secret, &format!("{}:{}", form, issued_at));
+		format!("{}.{}", issued_at, signature)
+	}
+
+	fn verify(&self, form: &str, token: &str, now: u64) -> Result<(), PublicFormError> {
+		let (issued_at, signature) = token.split_once('.').ok_or(PublicFormError::InvalidToken)?;
+		let issued_at: u64 = issued_at
+			.parse()
+			.map_err(|_| PublicFormError::InvalidToken)?;
+		if !verify_token_hmac(signature, &self.secret, &format!("{}:{}", form, issued_at)) {
+			return Err(PublicFormError::InvalidToken);
+		}
+		let Some(age) = now.checked_sub(issued_at) else {
+			return Err(PublicFormError::InvalidToken);
+		};
+		if age < self.min_age.as_secs() {
+			return Err(PublicFormError::TooFast);
+		}
+		if age > self.max_age.as_secs() {
+			return Err(PublicFormError::Expired);
+		}
+		Ok(())
+	}
+}
+
+impl Default for PublicFormConfig {
+	/// Configuration with a random secret; tokens are only valid in the
+	/// process that issued them.
+	fn default() -> Self {
+		Self::new(get_secret_bytes())
+	}
+}
+
+impl std::fmt::Debug for PublicFormConfig {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("PublicFormConfig")
+			.field("rate", &self.rate)
+			.field("min_age", &self.min_age)
+			.field("max_age", &self.max_age)
+			.field("requires_captcha", &self.requires_captcha())
+			.finish_non_exhaustive()
+	}
+}
+
+/// Install the configuration of public server functions
+pub fn set_public_form_config(config: PublicFormConfig) {
+	*CONFIG.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(config));
+}
+
+/// Configuration of public server functions
+///
+/// Falls back to [`PublicFormConfig::default`] when none was installed.
+pub fn public_form_config() -> Arc<PublicFormConfig> {
+	if let Some(config) = CONFIG.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+		return Arc::clone(config);
+	}
+	let mut config = CONFIG.write().unwrap_or_else(|e| e.into_inner());
+	Arc::clone(config.get_or_insert_with(|| Arc::new(PublicFormConfig::default())))
+}
+
+fn now() -> u64 {
+	SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map(|elapsed| elapsed.as_secs())
+		.unwrap_or_default()
+}
+
+/// Issue a form token for the public server function `form`
+pub fn issue_form_token(form: &str) -> String {
+	public_form_config().sign(form, now())
+}
+
+/// Check that `token` was issued for `form` within the submission window
+pub fn verify_form_token(form: &str, token: &str) -> Result<(), PublicFormError> {
+	public_form_config().verify(form, token, now())
+}
+
+/// `<meta>` tag carrying a fresh form token for the public server function
+/// `form`, read by its client stub
+///
+/// Render it in the page holding the form.
+pub fn form_token_tag(form: &str) -> Page {
+	PageElement::new("meta")
+		.attr("name", FORM_TOKEN_META)
+		.attr("data-form", form.to_string())
+		.attr("content", issue_form_token(form))
+		.into_page()
+}
+
+/// Check a request to the public server function `form`
+///
+/// Called by the handlers of `#[server_fn(public)]` before the arguments are
+/// deserialized. `rate` overrides the configured rate for this function.
+pub async fn guard(req: &Request, form: &str, rate: Option<&str>) -> Result<(), PublicFormError> {
+	let config = public_form_config();
+	let ip = req.get_client_ip();
+
+	let rate = rate.unwrap_or(&config.rate);
+	let (limit, window) = parse_rate(rate).map_err(|e| {
+		tracing::error!(error = %e, form, "Invalid public server function rate");
+		PublicFormError::Unavailable
+	})?;
+	let client = ip.map_or_else(|| "unknown".to_string(), |ip| ip.to_string());
+	let count = config
+		.backend
+		.increment(&format!("public_form:{}:{}", form, client), window)
+		.await
+		.map_err(|e| {
+			tracing::error!(error = %e, form, "Throttle backend failed");
+			PublicFormError::Unavailable
+		})?;
+	if count > limit {
+		return Err(PublicFormError::Throttled);
+	}
+
+	let token = header(req, FORM_TOKEN_HEADER).ok_or(PublicFormError::MissingToken)?;
+	config.verify(form, token, now())?;
+
+	if let Some(verifier) = &config.captcha {
+		let response = header(req, CAPTCHA_HEADER).ok_or(PublicFormError::CaptchaRequired)?;
+		let solved = verifier.verify(response, ip).await.map_err(|e| {
+			tracing::error!(error = %e, form, "CAPTCHA verification failed");
+			PublicFormError::Unavailable
+		})?;
+		if !solved {
+			return Err(PublicFormError::CaptchaFailed);
+		}
+	}
+	Ok(())
+}
+
+fn header<'a>(req: &'a Request, name: &str) -> Option<&'a str> {
+	req.headers
+		.get(name)
+		.and_then(|value| value.to_str().ok())
+		.filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use serial_test::serial;
+	use std::net::SocketAddr;
+
+	struct FixedCaptcha(bool);
+
+	#[async_trait]
+	impl CaptchaVerifier for FixedCaptcha {
+		async fn verify(
+			&self,
+			_response: &str,
+			_remote_ip: Option<IpAddr>,
+		) -> Result<bool, String> {
+			Ok(self.0)
+		}
+	}
+
+	fn config() -> PublicFormConfig {
+		PublicFormConfig::new(b"public-form-test-secret")
+	}
+
+	fn request(token: Option<&str>, captcha: Option<&str>) -> Request {
+		let mut builder = Request::builder()
+			.uri("/api/server_fn/contact")
+			.remote_addr(SocketAddr::from(([203, 0, 113, 7], 4000)));
+		if let Some(token) = token {
+			builder = builder.header("x-form-token", token);
+		}
+		if let Some(captcha) = captcha {
+			builder = builder.header("x-captcha-response", captcha);
+		}
+		builder.build().unwrap()
+	}
+
+	#[rstest]
+	#[case(10, Ok(()))]
+	#[case(1, Err(PublicFormError::TooFast))]
+	#[case(7_200, Err(PublicFormError::Expired))]
+	fn test_token_submission_window(
+		#[case] age: u64,
+		#[case] expected: Result<(), PublicFormError>,
+	) {
+		// Arrange
+		let config = config();
+		let token = config.sign("contact", 1_000_000);
+
+		// Act
+		let result = config.verify("contact", &token, 1_000_000 + age);
+
+		// Assert
+		assert_eq!(result, expected);
+	}
+
+	#[rstest]
+	#[case("newsletter")]
+	#[case("contact.extra")]
+	fn test_token_is_bound_to_its_form(#[case] other_form: &str) {
+		// Arrange
+		let config = config();
+		let token = config.sign("contact", 1_000_000);
+
+		// Act
+		let result = config.verify(other_form, &token, 1_000_010);
+
+		// Assert
+		assert_eq!(result, Err(PublicFormError::InvalidToken));
+	}
+
+	#[rstest]
+	#[case("not-a-token")]
+	#[case("1000000.deadbeef")]
+	fn test_malformed_token_is_invalid(#[case] token: &str) {
+		// Act
+		let result = config().verify("contact", token, 1_000_010);
+
+		// Assert
+		assert_eq!(result, Err(PublicFormError::InvalidToken));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	#[serial(public_form_config)]
+	async fn test_guard_throttles_per_ip() {
+		// Arrange
+		set_public_form_config(config().with_min_age(Duration::ZERO));
+		let token = issue_form_token("throttled");
+
+		// Act
+		let first = guard(&request(Some(&token), None), "throttled", Some("2/min")).await;
+		let second = guard(&request(Some(&token), None), "throttled", Some("2/min")).await;
+		let third = guard(&request(Some(&token), None), "throttled", Some("2/min")).await;
+
+		// Assert
+		assert_eq!(first, Ok(()));
+		assert_eq!(second, Ok(()));
+		assert_eq!(third, Err(PublicFormError::Throttled));
+		assert_eq!(PublicFormError::Throttled.status(), 429);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	#[serial(public_form_config)]
+	async fn test_guard_requires_token() {
+		// Arrange
+		set_public_form_config(config());
+
+		// Act
+		let result = guard(&request(None, None), "tokenless", None).await;
+
+		// Assert
+		assert_eq!(result, Err(PublicFormError::MissingToken));
+	}
+
+	#[rstest]
+	#[case(None, Err(PublicFormError::CaptchaRequired))]
+	#[case(Some("solved"), Ok(()))]
+	#[tokio::test]
+	#[serial(public_form_config)]
+	async fn test_guard_verifies_captcha(
+		#[case] captcha: Option<&str>,
+		#[case] expected: Result<(), PublicFormError>,
+	) {
+		// Arrange
+		set_public_form_config(
+			config()
+				.with_min_age(Duration::ZERO)
+				.with_captcha(Arc::new(FixedCaptcha(true))),
+		);
+		let token = issue_form_token("captcha");
+
+		// Act
+		let result = guard(&request(Some(&token), captcha), "captcha", Some("100/min")).await;
+
+		// Assert
+		assert_eq!(result, expected);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	#[serial(public_form_config)]
+	async fn test_guard_rejects_failed_captcha() {
+		// Arrange
+		set_public_form_config(
+			config()
+				.with_min_age(Duration::ZERO)
+				.with_captcha(Arc::new(FixedCaptcha(false))),
+		);
+		let token = issue_form_token("captcha");
+
+		// Act
+		let result = guard(&request(Some(&token), Some("bot")), "captcha", None).await;
+
+		// Assert
+		assert_eq!(result, Err(PublicFormError::CaptchaFailed));
+	}
+}
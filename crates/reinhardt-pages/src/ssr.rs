@@ -10,6 +10,8 @@
 //! - **Hydration Markers**: Automatically embed markers for client-side hydration
 //! - **State Serialization**: Serialize reactive state for client restoration
 //! - **Layout Support**: Wrap rendered content in HTML layouts
+//! - **Streaming**: Send the head and above-the-fold content before slow
+//!   sections resolve, see [`SsrStream`]
 //!
 //! ## Usage
 //!
@@ -30,17 +32,31 @@
 //!     "My Page Title",
 //!     Some("<!DOCTYPE html>...")
 //! );
+//!
+//! // Streaming rendering
+//! let response = SsrStream::new(SsrOptions::default())
+//!     .shell(header_view)
+//!     .deferred(async move { orders_view(load_orders().await) })
+//!     .into_streaming_response();
 //! ```
 
+mod csp;
 mod markers;
 #[cfg(native)]
 mod renderer;
 mod state;
+#[cfg(native)]
+mod streaming;
 
+#[cfg(wasm)]
+pub use csp::audit_document;
+pub use csp::{CspReport, CspViolation, CspViolationKind, audit_csp};
 pub use markers::{
-	HYDRATION_ATTR_ID, HYDRATION_ATTR_PROPS, HydrationMarker, HydrationMarkerBuilder,
-	HydrationStrategy,
+	HYDRATION_ATTR_ID, HYDRATION_ATTR_PROPS, HYDRATION_ATTR_TRIGGER, HydrationMarker,
+	HydrationMarkerBuilder, HydrationStrategy, HydrationTrigger,
 };
 #[cfg(native)]
 pub use renderer::{SsrOptions, SsrRenderer};
 pub use state::{SsrState, StateEntry};
+#[cfg(native)]
+pub use streaming::SsrStream;
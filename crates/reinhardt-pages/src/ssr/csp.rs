@@ -0,0 +1,483 @@
+//! Content Security Policy audit for rendered markup.
+//!
+//! Deployments with a strict CSP (no `'unsafe-inline'` in `script-src` or
+//! `style-src`) break when a page contains inline event handlers, inline
+//! scripts or styles, `style` attributes or `javascript:` URLs. [`audit_csp`]
+//! scans rendered HTML for those constructs and returns a [`CspReport`]
+//! listing each violation together with the component it was rendered by
+//! (taken from the `data-rh-component` hydration marker).
+//!
+//! JSON data blocks (`<script type="application/json">`), such as the SSR
+//! state and auth data, are not executed and are therefore allowed. Inline
+//! scripts and styles carrying a `nonce` attribute are allowed as well,
+//! since a nonce-based policy permits them.
+//!
+//! The audit runs on the server through `SsrOptions::csp_audit`, in tests
+//! through [`CspReport::assert_clean`], and in the browser after hydration
+//! through `audit_document`.
+//!
+//! ## Usage
+//!
+//! ```
+//! use reinhardt_pages::ssr::{CspViolationKind, audit_csp};
+//!
+//! let report = audit_csp(
+//!     r#"<div data-rh-component="Counter"><button onclick="inc()">+</button></div>"#,
+//! );
+//!
+//! assert!(!report.is_clean());
+//! assert_eq!(report.violations[0].kind, CspViolationKind::InlineEventHandler);
+//! assert_eq!(report.offending_components(), vec!["Counter"]);
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Attribute naming the component that rendered an element.
+const COMPONENT_ATTR: &str = "data-rh-component";
+
+/// Maximum length of the markup excerpt stored in a violation.
+const SNIPPET_LEN: usize = 80;
+
+/// Elements without a closing tag.
+const VOID_ELEMENTS: &[&str] = &[
+	"area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+	"wbr",
+];
+
+/// Markup constructs that require `'unsafe-inline'`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CspViolationKind {
+	/// An `on*` event handler attribute, e.g. `onclick`.
+	InlineEventHandler,
+	/// An executable `<script>` element without `src` or `nonce`.
+	InlineScript,
+	/// A `<style>` element without `nonce`.
+	InlineStyle,
+	/// A `style` attribute.
+	StyleAttribute,
+	/// A `javascript:` URL in `href`, `src`, `action` or `formaction`.
+	JavascriptUrl,
+}
+
+impl CspViolationKind {
+	/// The CSP directive the construct violates.
+	pub fn directive(&self) -> &'static str {
+		match self {
+			Self::InlineEventHandler | Self::InlineScript | Self::JavascriptUrl => "script-src",
+			Self::InlineStyle | Self::StyleAttribute => "style-src",
+		}
+	}
+}
+
+impl fmt::Display for CspViolationKind {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let description = match self {
+			Self::InlineEventHandler => "inline event handler",
+			Self::InlineScript => "inline script",
+			Self::InlineStyle => "inline style element",
+			Self::StyleAttribute => "style attribute",
+			Self::JavascriptUrl => "javascript: URL",
+		};
+		write!(f, "{} ({})", description, self.directive())
+	}
+}
+
+/// A construct of the markup that requires `'unsafe-inline'`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CspViolation {
+	/// What kind of construct was found.
+	pub kind: CspViolationKind,
+	/// Component that rendered the element, if known.
+	pub component: Option<String>,
+	/// Name of the offending element.
+	pub element: String,
+	/// Excerpt of the offending markup.
+	pub snippet: String,
+}
+
+impl fmt::Display for CspViolation {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} on <{}>: {}", self.kind, self.element, self.snippet)
+	}
+}
+
+/// Result of a CSP audit.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CspReport {
+	/// Violations, in document order.
+	pub violations: Vec<CspViolation>,
+}
+
+impl CspReport {
+	/// Returns whether the markup works without `'unsafe-inline'`.
+	pub fn is_clean(&self) -> bool {
+		self.violations.is_empty()
+	}
+
+	/// Returns the names of the components with violations, sorted.
+	pub fn offending_components(&self) -> Vec<&str> {
+		let mut components: Vec<&str> = self
+			.violations
+			.iter()
+			.filter_map(|violation| violation.component.as_deref())
+			.collect();
+		components.sort_unstable();
+		components.dedup();
+		components
+	}
+
+	/// Groups the violations by component.
+	///
+	/// Violations outside any marked component are grouped under `None`.
+	pub fn by_component(&self) -> BTreeMap<Option<&str>, Vec<&CspViolation>> {
+		let mut groups: BTreeMap<Option<&str>, Vec<&CspViolation>> = BTreeMap::new();
+		for violation in &self.violations {
+			groups
+				.entry(violation.component.as_deref())
+				.or_default()
+				.push(violation);
+		}
+		groups
+	}
+
+	/// Panics with the report if the markup has violations.
+	///
+	/// Intended for tests asserting that pages work under a strict CSP.
+	#[track_caller]
+	pub fn assert_clean(&self) {
+		assert!(self.is_clean(), "{}", self);
+	}
+}
+
+impl fmt::Display for CspReport {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.is_clean() {
+			return write!(f, "No CSP violations");
+		}
+		writeln!(
+			f,
+			"{} CSP violation(s) requiring 'unsafe-inline':",
+			self.violations.len()
+		)?;
+		for (component, violations) in self.by_component() {
+			writeln!(f, "  {}:", component.unwrap_or("<page>"))?;
+			for violation in violations {
+				writeln!(f, "    - {}", violation)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Scans `html` for constructs requiring `'unsafe-inline'`.
+pub fn audit_csp(html: &str) -> CspReport {
+	let mut report = CspReport::default();
+	// Open elements with the component they belong to.
+	let mut stack: Vec<(String, Option<String>)> = Vec::new();
+	let mut pos = 0;
+
+	while let Some(offset) = html[pos..].find('<') {
+		let start = pos + offset;
+		let rest = &html[start + 1..];
+
+		if rest.starts_with("!--") {
+			pos = match rest.find("-->") {
+				Some(end) => start + 1 + end + 3,
+				None => html.len(),
+			};
+			continue;
+		}
+		if rest.starts_with('!') || rest.starts_with('?') {
+			pos = skip_past(html, start, ">");
+			continue;
+		}
+		if let Some(closing) = rest.strip_prefix('/') {
+			let name = tag_name(closing);
+			if let Some(index) = stack.iter().rposition(|(open, _)| *open == name) {
+				stack.truncate(index);
+			}
+			pos = skip_past(html, start, ">");
+			continue;
+		}
+
+		let name = tag_name(rest);
+		if name.is_empty() {
+			pos = start + 1;
+			continue;
+		}
+		let Some(tag_len) = tag_length(rest) else {
+			break;
+		};
+		let tag = &rest[..tag_len];
+		let attrs = parse_attrs(&tag[name.len()..]);
+		let self_closing = tag.trim_end().ends_with('/');
+		pos = start + 1 + tag_len + 1;
+
+		let component = attr(&attrs, COMPONENT_ATTR)
+			.map(str::to_string)
+			.or_else(|| stack.iter().rev().find_map(|(_, c)| c.clone()));
+		let mut record = |kind: CspViolationKind, snippet: &str| {
+			report.violations.push(CspViolation {
+				kind,
+				component: component.clone(),
+				element: name.clone(),
+				snippet: excerpt(snippet),
+			});
+		};
+
+		for (attr_name, value) in &attrs {
+			let value = value.as_deref().unwrap_or("");
+			if attr_name.starts_with("on") && attr_name.len() > 2 {
+				record(
+					CspViolationKind::InlineEventHandler,
+					&format!("{}=\"{}\"", attr_name, value),
+				);
+			} else if attr_name == "style" {
+				record(
+					CspViolationKind::StyleAttribute,
+					&format!("style=\"{}\"", value),
+				);
+			} else if matches!(attr_name.as_str(), "href" | "src" | "action" | "formaction")
+				&& value
+					.trim_start()
+					.to_ascii_lowercase()
+					.starts_with("javascript:")
+			{
+				record(
+					CspViolationKind::JavascriptUrl,
+					&format!("{}=\"{}\"", attr_name, value),
+				);
+			}
+		}
+
+		if name == "script" || name == "style" {
+			// Raw text element: its content runs until the closing tag.
+			let close = format!("</{}", name);
+			let end = find_ignore_case(&html[pos..], &close)
+				.map(|index| pos + index)
+				.unwrap_or(html.len());
+			let content = html[pos..end].trim();
+			let has_nonce = attr(&attrs, "nonce").is_some();
+
+			if name == "script"
+				&& !content.is_empty()
+				&& !has_nonce
+				&& attr(&attrs, "src").is_none()
+				&& is_executable_script(attr(&attrs, "type"))
+			{
+				record(CspViolationKind::InlineScript, content);
+			} else if name == "style" && !has_nonce {
+				record(CspViolationKind::InlineStyle, content);
+			}
+			pos = skip_past(html, end, ">");
+			continue;
+		}
+
+		if !self_closing && !VOID_ELEMENTS.contains(&name.as_str()) {
+			stack.push((name, component));
+		}
+	}
+
+	report
+}
+
+/// Scans the live document of the page for constructs requiring `'unsafe-inline'`.
+///
+/// Run it after hydration to also catch markup created on the client.
+#[cfg(wasm)]
+pub fn audit_document() -> CspReport {
+	let html = web_sys::window()
+		.and_then(|window| window.document())
+		.and_then(|document| document.document_element())
+		.map(|element| element.outer_html())
+		.unwrap_or_default();
+	audit_csp(&html)
+}
+
+/// Whether a script `type` is executed by the browser.
+fn is_executable_script(type_attr: Option<&str>) -> bool {
+	match type_attr.map(|t| t.trim().to_ascii_lowercase()) {
+		None => true,
+		Some(t) => matches!(
+			t.as_str(),
+			"" | "module" | "text/javascript" | "application/javascript" | "text/ecmascript"
+		),
+	}
+}
+
+/// Lowercase tag name at the start of `s`.
+fn tag_name(s: &str) -> String {
+	s.chars()
+		.take_while(|c| c.is_ascii_alphanumeric() || *c == '-')
+		.collect::<String>()
+		.to_ascii_lowercase()
+}
+
+/// Length of the tag body up to (excluding) the `>` ending it, skipping quoted values.
+fn tag_length(s: &str) -> Option<usize> {
+	let mut quote = None;
+	for (index, c) in s.char_indices() {
+		match (quote, c) {
+			(None, '"' | '\'') => quote = Some(c),
+			(Some(q), _) if c == q => quote = None,
+			(None, '>') => return Some(index),
+			_ => {}
+		}
+	}
+	None
+}
+
+/// Parses the attributes of a tag body, lowercasing names.
+fn parse_attrs(s: &str) -> Vec<(String, Option<String>)> {
+	let mut attrs = Vec::new();
+	let mut chars = s.trim_end_matches('/').chars().peekable();
+
+	loop {
+		while chars.next_if(|c| c.is_whitespace() || *c == '/').is_some() {}
+		let name: String =
+			std::iter::from_fn(|| chars.next_if(|c| !c.is_whitespace() && *c != '=' && *c != '/'))
+				.collect();
+		if name.is_empty() {
+			break;
+		}
+		while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+		let value = if chars.next_if_eq(&'=').is_some() {
+			while chars.next_if(|c| c.is_whitespace()).is_some() {}
+			let value: String = match chars.next_if(|c| *c == '"' || *c == '\'') {
+				Some(quote) => {
+					let value = std::iter::from_fn(|| chars.next_if(|c| *c != quote)).collect();
+					chars.next();
+					value
+				}
+				None => std::iter::from_fn(|| chars.next_if(|c| !c.is_whitespace())).collect(),
+			};
+			Some(value)
+		} else {
+			None
+		};
+		attrs.push((name.to_ascii_lowercase(), value));
+	}
+
+	attrs
+}
+
+/// Value of the attribute `name`, if present.
+fn attr<'a>(attrs: &'a [(String, Option<String>)], name: &str) -> Option<&'a str> {
+	attrs
+		.iter()
+		.find(|(attr_name, _)| attr_name == name)
+		.map(|(_, value)| value.as_deref().unwrap_or(""))
+}
+
+/// Position right after the first `needle` found from `from`, or the end of `html`.
+fn skip_past(html: &str, from: usize, needle: &str) -> usize {
+	html[from..]
+		.find(needle)
+		.map(|index| from + index + needle.len())
+		.unwrap_or(html.len())
+}
+
+/// Byte offset of `needle` (ASCII) in `haystack`, ignoring ASCII case.
+fn find_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+	haystack
+		.as_bytes()
+		.windows(needle.len())
+		.position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+/// Shortens `s` to at most [`SNIPPET_LEN`] characters.
+fn excerpt(s: &str) -> String {
+	if s.chars().count() <= SNIPPET_LEN {
+		s.to_string()
+	} else {
+		let mut excerpt: String = s.chars().take(SNIPPET_LEN).collect();
+		excerpt.push('…');
+		excerpt
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case::event_handler(
+		r#"<button onclick="go()">Go</button>"#,
+		CspViolationKind::InlineEventHandler
+	)]
+	#[case::style_attribute(r#"<p style="color: red">Hi</p>"#, CspViolationKind::StyleAttribute)]
+	#[case::inline_script("<script>alert(1)</script>", CspViolationKind::InlineScript)]
+	#[case::module_script(
+		r#"<script type="module">import "x";</script>"#,
+		CspViolationKind::InlineScript
+	)]
+	#[case::inline_style("<style>p { color: red }</style>", CspViolationKind::InlineStyle)]
+	#[case::javascript_url(
+		r#"<a href=" JavaScript:void(0)">x</a>"#,
+		CspViolationKind::JavascriptUrl
+	)]
+	fn test_detects_unsafe_inline_constructs(#[case] html: &str, #[case] kind: CspViolationKind) {
+		// Act
+		let report = audit_csp(html);
+
+		// Assert
+		assert_eq!(report.violations.len(), 1);
+		assert_eq!(report.violations[0].kind, kind);
+	}
+
+	#[rstest]
+	#[case::json_data(r#"<script id="ssr-state" type="application/json">{"a":"<b>"}</script>"#)]
+	#[case::external_script(r#"<script src="/app.js" defer></script>"#)]
+	#[case::nonce_script(r#"<script nonce="abc">init()</script>"#)]
+	#[case::nonce_style(r#"<style nonce="abc">p {}</style>"#)]
+	#[case::data_attribute(r#"<div data-onclick="x" class="online">ok</div>"#)]
+	#[case::comment("<!-- <p style=\"x\"> -->")]
+	#[case::text("<p>if a <b and c> d</p>")]
+	fn test_allows_strict_csp_markup(#[case] html: &str) {
+		// Act
+		let report = audit_csp(html);
+
+		// Assert
+		report.assert_clean();
+	}
+
+	#[rstest]
+	fn test_violations_are_attributed_to_components() {
+		// Arrange
+		let html = concat!(
+			r#"<div data-rh-id="rh-0" data-rh-component="Counter">"#,
+			r#"<span><button onclick="inc()">+</button></span>"#,
+			r#"<input type="text" style="width: 2em" />"#,
+			"</div>",
+			r#"<section data-rh-component="Chart"><svg style="x"></svg></section>"#,
+			r#"<p onmouseover='hint()'>?</p>"#,
+		);
+
+		// Act
+		let report = audit_csp(html);
+
+		// Assert
+		assert_eq!(report.offending_components(), vec!["Chart", "Counter"]);
+		let groups = report.by_component();
+		assert_eq!(groups[&Some("Counter")].len(), 2);
+		assert_eq!(groups[&Some("Chart")].len(), 1);
+		assert_eq!(groups[&None][0].element, "p");
+		assert_eq!(groups[&None][0].snippet, "onmouseover=\"hint()\"");
+	}
+
+	#[rstest]
+	fn test_report_display_lists_components() {
+		// Arrange
+		let report = audit_csp(r#"<div data-rh-component="Legacy"><b style="x">!</b></div>"#);
+
+		// Act
+		let text = report.to_string();
+
+		// Assert
+		assert!(text.starts_with("1 CSP violation(s)"));
+		assert!(text.contains("  Legacy:\n    - style attribute (style-src) on <b>: style=\"x\""));
+	}
+}
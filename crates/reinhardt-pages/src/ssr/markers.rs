@@ -19,6 +19,9 @@ pub const HYDRATION_ATTR_ID: &str = "data-rh-id";
 /// The attribute name for serialized props.
 pub const HYDRATION_ATTR_PROPS: &str = "data-rh-props";
 
+/// The attribute name for deferred hydration triggers.
+pub const HYDRATION_ATTR_TRIGGER: &str = "data-rh-hydrate";
+
 /// Global counter for generating unique hydration IDs.
 static HYDRATION_COUNTER: AtomicU64 = AtomicU64::new(0);
 
@@ -48,6 +51,54 @@ pub enum HydrationStrategy {
 	Static,
 }
 
+/// When an island is hydrated on the client.
+///
+/// Deferring below-the-fold or rarely used components keeps them from
+/// competing with the rest of the page for the initial wasm execution.
+/// The trigger is written as `data-rh-hydrate` and honoured by the
+/// island scheduler (see `hydration::schedule_islands`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HydrationTrigger {
+	/// Hydrate as soon as the island scheduler runs (default).
+	#[default]
+	Load,
+	/// Hydrate when the island first enters the viewport.
+	Visible,
+	/// Hydrate once the browser is idle.
+	Idle,
+	/// Hydrate on the first pointer, keyboard or focus event inside the island.
+	Interaction,
+}
+
+impl HydrationTrigger {
+	/// The `data-rh-hydrate` attribute value for this trigger.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Load => "load",
+			Self::Visible => "visible",
+			Self::Idle => "idle",
+			Self::Interaction => "interaction",
+		}
+	}
+}
+
+impl std::str::FromStr for HydrationTrigger {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"load" => Ok(Self::Load),
+			"visible" => Ok(Self::Visible),
+			"idle" => Ok(Self::Idle),
+			"interaction" => Ok(Self::Interaction),
+			other => Err(format!(
+				"unknown hydration trigger `{}` (expected load, visible, idle or interaction)",
+				other
+			)),
+		}
+	}
+}
+
 /// Represents a hydration marker embedded in SSR output.
 #[derive(Debug, Clone)]
 pub struct HydrationMarker {
@@ -59,6 +110,8 @@ pub struct HydrationMarker {
 	pub props: Option<String>,
 	/// Hydration strategy (Phase 2-B).
 	pub strategy: HydrationStrategy,
+	/// When the island is hydrated on the client.
+	pub trigger: HydrationTrigger,
 }
 
 impl HydrationMarker {
@@ -69,6 +122,7 @@ impl HydrationMarker {
 			component_name: None,
 			props: None,
 			strategy: HydrationStrategy::default(),
+			trigger: HydrationTrigger::default(),
 		}
 	}
 
@@ -79,6 +133,7 @@ impl HydrationMarker {
 			component_name: Some(name.into()),
 			props: None,
 			strategy: HydrationStrategy::default(),
+			trigger: HydrationTrigger::default(),
 		}
 	}
 
@@ -94,6 +149,19 @@ impl HydrationMarker {
 		self
 	}
 
+	/// Sets when the island is hydrated on the client.
+	///
+	/// A deferred trigger only makes sense for islands, so any trigger other
+	/// than [`HydrationTrigger::Load`] also switches the strategy to
+	/// [`HydrationStrategy::Island`].
+	pub fn with_trigger(mut self, trigger: HydrationTrigger) -> Self {
+		self.trigger = trigger;
+		if trigger != HydrationTrigger::Load {
+			self.strategy = HydrationStrategy::Island;
+		}
+		self
+	}
+
 	/// Creates an island marker (interactive component).
 	pub fn island() -> Self {
 		Self::new().with_strategy(HydrationStrategy::Island)
@@ -112,6 +180,12 @@ impl HydrationMarker {
 		match self.strategy {
 			HydrationStrategy::Island => {
 				attrs.push(("data-rh-island".to_string(), "true".to_string()));
+				if self.trigger != HydrationTrigger::Load {
+					attrs.push((
+						HYDRATION_ATTR_TRIGGER.to_string(),
+						self.trigger.as_str().to_string(),
+					));
+				}
 			}
 			HydrationStrategy::Static => {
 				attrs.push(("data-rh-static".to_string(), "true".to_string()));
@@ -188,6 +262,7 @@ pub struct HydrationMarkerBuilder {
 	component_name: Option<String>,
 	props: Option<String>,
 	strategy: Option<HydrationStrategy>,
+	trigger: Option<HydrationTrigger>,
 }
 
 impl HydrationMarkerBuilder {
@@ -224,6 +299,12 @@ impl HydrationMarkerBuilder {
 		self.strategy(HydrationStrategy::Static)
 	}
 
+	/// Sets when the island is hydrated on the client.
+	pub fn hydrate(mut self, trigger: HydrationTrigger) -> Self {
+		self.trigger = Some(trigger);
+		self
+	}
+
 	/// Builds the HydrationMarker.
 	pub fn build(self) -> HydrationMarker {
 		let mut marker = HydrationMarker::new();
@@ -240,12 +321,17 @@ impl HydrationMarkerBuilder {
 			marker.strategy = strategy;
 		}
 
+		if let Some(trigger) = self.trigger {
+			marker = marker.with_trigger(trigger);
+		}
+
 		marker
 	}
 }
 
 #[cfg(test)]
 mod tests {
+	use rstest::rstest;
 	use serial_test::serial;
 
 	use super::*;
@@ -437,4 +523,64 @@ mod tests {
 		assert!(attrs.contains(&("data-rh-component".to_string(), "TodoList".to_string())));
 		assert!(attrs.contains(&("data-rh-props".to_string(), r#"{"items": []}"#.to_string())));
 	}
+
+	#[rstest]
+	#[case::load("load", HydrationTrigger::Load)]
+	#[case::visible("visible", HydrationTrigger::Visible)]
+	#[case::idle("idle", HydrationTrigger::Idle)]
+	#[case::interaction("interaction", HydrationTrigger::Interaction)]
+	fn test_hydration_trigger_round_trip(#[case] value: &str, #[case] trigger: HydrationTrigger) {
+		// Act
+		let parsed: HydrationTrigger = value.parse().unwrap();
+
+		// Assert
+		assert_eq!(parsed, trigger);
+		assert_eq!(trigger.as_str(), value);
+	}
+
+	#[rstest]
+	fn test_hydration_trigger_rejects_unknown() {
+		// Act
+		let result = "hover".parse::<HydrationTrigger>();
+
+		// Assert
+		assert!(result.unwrap_err().contains("unknown hydration trigger"));
+	}
+
+	#[rstest]
+	#[serial(hydration_counter)]
+	fn test_hydration_marker_deferred_trigger_makes_island() {
+		// Arrange
+		reset_hydration_counter();
+
+		// Act
+		let marker = HydrationMarkerBuilder::new()
+			.component_name("Comments")
+			.hydrate(HydrationTrigger::Visible)
+			.build();
+
+		// Assert
+		assert_eq!(marker.strategy, HydrationStrategy::Island);
+		let attrs = marker.to_attrs();
+		assert!(attrs.contains(&("data-rh-island".to_string(), "true".to_string())));
+		assert!(attrs.contains(&("data-rh-hydrate".to_string(), "visible".to_string())));
+	}
+
+	#[rstest]
+	#[serial(hydration_counter)]
+	fn test_hydration_marker_load_trigger_has_no_attr() {
+		// Arrange
+		reset_hydration_counter();
+
+		// Act
+		let marker = HydrationMarker::island().with_trigger(HydrationTrigger::Load);
+
+		// Assert
+		assert!(
+			!marker
+				.to_attrs()
+				.iter()
+				.any(|(k, _)| k == "data-rh-hydrate")
+		);
+	}
 }
@@ -2,7 +2,8 @@
 
 use std::collections::BTreeSet;
 
-use super::markers::{HydrationMarker, HydrationStrategy};
+use super::csp::audit_csp;
+use super::markers::{HydrationMarker, HydrationStrategy, HydrationTrigger};
 use super::state::SsrState;
 use crate::auth::AuthData;
 use crate::component::{Component, Head, IntoPage, Page};
@@ -34,6 +35,11 @@ pub struct SsrOptions {
 	/// - `Island`: Mark as interactive islands
 	/// - `Static`: Mark as static content (no hydration)
 	pub default_hydration_strategy: HydrationStrategy,
+	/// Whether to audit rendered pages for markup requiring `'unsafe-inline'`.
+	///
+	/// Violations are logged with the offending components; see
+	/// [`audit_csp`](super::audit_csp).
+	pub csp_audit: bool,
 }
 
 impl Default for SsrOptions {
@@ -47,6 +53,7 @@ impl Default for SsrOptions {
 			auth_data: None,
 			enable_partial_hydration: false,
 			default_hydration_strategy: HydrationStrategy::Full,
+			csp_audit: false,
 		}
 	}
 }
@@ -134,6 +141,16 @@ impl SsrOptions {
 		self.default_hydration_strategy = HydrationStrategy::Island;
 		self
 	}
+
+	/// Enables the strict CSP audit of rendered pages.
+	///
+	/// Each rendered page is scanned for inline event handlers, inline
+	/// scripts and styles, `style` attributes and `javascript:` URLs, and a
+	/// report listing the offending components is logged.
+	pub fn csp_audit(mut self) -> Self {
+		self.csp_audit = true;
+		self
+	}
 }
 
 /// The main SSR renderer.
@@ -168,6 +185,11 @@ impl SsrRenderer {
 		}
 	}
 
+	/// Returns the rendering options.
+	pub fn options(&self) -> &SsrOptions {
+		&self.options
+	}
+
 	/// Returns a reference to the SSR state.
 	pub fn state(&self) -> &SsrState {
 		&self.state
@@ -178,6 +200,13 @@ impl SsrRenderer {
 		&mut self.state
 	}
 
+	/// Converts the renderer into a streaming page renderer.
+	///
+	/// The options and SSR state are kept; see [`SsrStream`](super::SsrStream).
+	pub fn into_stream(self) -> super::SsrStream {
+		super::SsrStream::from_renderer(self)
+	}
+
 	/// Renders a component to an HTML string.
 	pub fn render<C: Component>(&mut self, component: &C) -> String {
 		let view = component.render();
@@ -261,9 +290,18 @@ impl SsrRenderer {
 	/// * `content` - The rendered body content
 	/// * `view_head` - Optional head extracted from a View
 	fn wrap_in_html_with_head(&self, content: &str, view_head: Option<&Head>) -> String {
+		let mut html = self.document_start(view_head);
+		html.push_str(content);
+		html.push_str(&self.document_end());
+		finish_markup(&self.options, html)
+	}
+
+	/// Renders the document up to and including the opening of the app
+	/// container.
+	pub(super) fn document_start(&self, view_head: Option<&Head>) -> String {
 		let view_head = view_head.map(Head::deduplicated);
 		let mut seen_head_entries = BTreeSet::new();
-		let mut html = String::with_capacity(content.len() + 1024);
+		let mut html = String::with_capacity(1024);
 
 		// DOCTYPE and html opening
 		html.push_str("<!DOCTYPE html>\n");
@@ -326,8 +364,13 @@ impl SsrRenderer {
 		// Body section
 		html.push_str("<body>\n");
 		html.push_str("<div id=\"app\">");
-		html.push_str(content);
-		html.push_str("</div>\n");
+		html
+	}
+
+	/// Renders the document from the closing of the app container, including
+	/// the auth data and SSR state scripts.
+	pub(super) fn document_end(&self) -> String {
+		let mut html = String::from("</div>\n");
 
 		// Auth data script (if provided)
 		// Note: We escape </script> sequences to prevent XSS attacks where
@@ -350,12 +393,7 @@ impl SsrRenderer {
 
 		html.push_str("</body>\n");
 		html.push_str("</html>");
-
-		if self.options.minify {
-			minify_html(&html)
-		} else {
-			html
-		}
+		html
 	}
 
 	/// Wraps content in a full HTML document.
@@ -364,71 +402,26 @@ impl SsrRenderer {
 	/// Use `render_page_with_view_head` with the `head!` macro for pages
 	/// that require title, meta tags, CSS, or JS.
 	pub fn wrap_in_html(&self, content: &str) -> String {
-		let mut html = String::with_capacity(content.len() + 1024);
-
-		// DOCTYPE and html opening
-		html.push_str("<!DOCTYPE html>\n");
-		html.push_str(&format!(
-			"<html lang=\"{}\">\n",
-			html_escape(&self.options.lang)
-		));
-
-		// Head section
-		html.push_str("<head>\n");
-		html.push_str("<meta charset=\"UTF-8\">\n");
-		html.push_str(
-			"<meta name=\"viewport\" content=\"width=device-width, initial-scale=1.0\">\n",
-		);
-
-		// CSRF token meta tag
-		if let Some(ref token) = self.options.csrf_token {
-			html.push_str(&format!(
-				"<meta name=\"csrf-token\" content=\"{}\">\n",
-				html_escape(token)
-			));
-		}
-
-		html.push_str("</head>\n");
-
-		// Body section
-		html.push_str("<body>\n");
-
-		// Main content
-		html.push_str("<div id=\"app\">");
-		html.push_str(content);
-		html.push_str("</div>\n");
-
-		// Auth data script (if provided)
-		// Note: We escape </script> sequences to prevent XSS attacks where
-		// user-controlled data (like username) could break out of the script context
-		if let Some(ref auth_data) = self.options.auth_data
-			&& let Ok(json) = serde_json::to_string(auth_data)
-		{
-			let safe_json = escape_json_for_script(&json);
-			html.push_str(&format!(
-				"<script id=\"auth-data\" type=\"application/json\">{}</script>\n",
-				safe_json
-			));
-		}
-
-		// SSR state script (if enabled)
-		if self.options.include_state_script && !self.state.is_empty() {
-			html.push_str(&self.state.to_script_tag());
-			html.push('\n');
-		}
-
-		html.push_str("</body>\n");
-		html.push_str("</html>");
-
-		if self.options.minify {
-			minify_html(&html)
-		} else {
-			html
-		}
+		self.wrap_in_html_with_head(content, None)
 	}
 
 	/// Renders a component with hydration marker.
+	///
+	/// The marker carries the component's
+	/// [`hydration_trigger`](Component::hydration_trigger).
 	pub fn render_with_marker<C: Component>(&mut self, component: &C) -> String {
+		self.render_island(component, C::hydration_trigger())
+	}
+
+	/// Renders a component with a hydration marker for `trigger`.
+	///
+	/// Overrides the component's own trigger, so the same component can be
+	/// hydrated eagerly above the fold and lazily further down the page.
+	pub fn render_island<C: Component>(
+		&mut self,
+		component: &C,
+		trigger: HydrationTrigger,
+	) -> String {
 		let view = component.render();
 		let content = view.render_to_string();
 
@@ -438,7 +431,9 @@ impl SsrRenderer {
 				component_name: Some(C::name().to_string()),
 				props: None,
 				strategy: HydrationStrategy::default(),
-			};
+				trigger: HydrationTrigger::default(),
+			}
+			.with_trigger(trigger);
 			format!("<div {}>{}</div>", marker.to_attr_string(), content)
 		} else {
 			content
@@ -446,6 +441,25 @@ impl SsrRenderer {
 	}
 }
 
+/// Audits and, if enabled, minifies rendered markup.
+///
+/// Violations found by the CSP audit are logged with the offending
+/// components.
+pub(super) fn finish_markup(options: &SsrOptions, html: String) -> String {
+	if options.csp_audit {
+		let report = audit_csp(&html);
+		if !report.is_clean() {
+			crate::warn_log!("{}", report);
+		}
+	}
+
+	if options.minify {
+		minify_html(&html)
+	} else {
+		html
+	}
+}
+
 /// Simple HTML escape function.
 fn html_escape(s: &str) -> String {
 	s.replace('&', "&amp;")
@@ -701,6 +715,19 @@ mod tests {
 		assert!(html.contains("data-rh-component=\"TestComponent\""));
 	}
 
+	#[test]
+	fn test_ssr_renderer_island_with_trigger() {
+		let component = TestComponent {
+			message: "Later".to_string(),
+		};
+		let mut renderer = SsrRenderer::new();
+		let html = renderer.render_island(&component, HydrationTrigger::Idle);
+
+		assert!(html.contains("data-rh-island=\"true\""));
+		assert!(html.contains("data-rh-hydrate=\"idle\""));
+		assert!(html.contains("Later"));
+	}
+
 	#[test]
 	fn test_render_helper() {
 		let component = TestComponent {
@@ -883,4 +910,24 @@ mod tests {
 		assert!(html.contains("&lt;script&gt;"));
 		assert!(!html.contains("<html lang=\"<script>"));
 	}
+
+	#[test]
+	fn test_rendered_page_passes_strict_csp_audit() {
+		// Arrange
+		let component = TestComponent {
+			message: "Hello".to_string(),
+		};
+		let opts = SsrOptions::new()
+			.csrf("token")
+			.auth(AuthData::authenticated("1", "</script><b style=\"x\">"))
+			.csp_audit();
+		let mut renderer = SsrRenderer::with_options(opts);
+		renderer.state_mut().add_signal("count", 1);
+
+		// Act
+		let html = renderer.render_page(&component);
+
+		// Assert
+		audit_csp(&html).assert_clean();
+	}
 }
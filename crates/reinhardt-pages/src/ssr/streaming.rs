@@ -0,0 +1,238 @@
+//! Streaming server-side rendering.
+//!
+//! [`SsrStream`] renders a page in chunks. The document head and the
+//! above-the-fold sections are sent right away, and sections that depend on
+//! slow data are written as their futures resolve. Browsers can start
+//! loading stylesheets and painting the page before all of its data is
+//! available.
+//!
+//! Deferred sections are resolved concurrently but written in document order,
+//! so the page has the same markup as one rendered by
+//! [`SsrRenderer::render_page_with_view_head`].
+
+use std::future::Future;
+
+use futures::future::{self, BoxFuture, FutureExt};
+use futures::stream::{self, Stream, StreamExt};
+use hyper::body::Bytes;
+use hyper::header::{CONTENT_TYPE, HeaderName, HeaderValue};
+use reinhardt_http::{StreamBody, StreamingResponse};
+
+use super::renderer::{SsrOptions, SsrRenderer, finish_markup};
+use crate::component::{Head, IntoPage};
+
+enum Section {
+	Ready(String),
+	Deferred(BoxFuture<'static, String>),
+}
+
+/// Renders a page as a stream of HTML chunks.
+///
+/// # Example
+///
+/// ```ignore
+/// use reinhardt_pages::ssr::{SsrOptions, SsrStream};
+///
+/// let response = SsrStream::new(SsrOptions::new().csrf(token))
+///     .shell(page!(|| { header { h1 { "Orders" } } })().with_head(head))
+///     .deferred(async move {
+///         let orders = load_orders().await;
+///         order_table(&orders)
+///     })
+///     .shell(page!(|| { footer { "Acme" } })())
+///     .into_streaming_response();
+/// ```
+pub struct SsrStream {
+	renderer: SsrRenderer,
+	head: Option<Head>,
+	sections: Vec<Section>,
+}
+
+impl SsrStream {
+	/// Creates a streaming renderer with the given options.
+	pub fn new(options: SsrOptions) -> Self {
+		Self::from_renderer(SsrRenderer::with_options(options))
+	}
+
+	pub(super) fn from_renderer(renderer: SsrRenderer) -> Self {
+		Self {
+			renderer,
+			head: None,
+			sections: Vec::new(),
+		}
+	}
+
+	/// Sets the document head.
+	///
+	/// Without it, the head attached to the first section added with
+	/// [`shell`](Self::shell) is used, if any.
+	pub fn head(mut self, head: Head) -> Self {
+		self.head = Some(head);
+		self
+	}
+
+	/// Appends a section that is rendered immediately.
+	pub fn shell<V: IntoPage>(mut self, view: V) -> Self {
+		let page = view.into_page();
+		if self.head.is_none() {
+			self.head = page.find_topmost_head().cloned();
+		}
+		self.sections
+			.push(Section::Ready(self.renderer.render_view(&page)));
+		self
+	}
+
+	/// Appends a section rendered once `view` resolves.
+	///
+	/// The future starts running when the stream is first polled, together
+	/// with the other deferred sections.
+	pub fn deferred<F>(mut self, view: F) -> Self
+	where
+		F: Future + Send + 'static,
+		F::Output: IntoPage,
+	{
+		self.sections.push(Section::Deferred(
+			async move { view.await.into_page().render_to_string() }.boxed(),
+		));
+		self
+	}
+
+	/// Converts into a stream of HTML chunks.
+	///
+	/// The first chunk holds the document head and the opening of the app
+	/// container, the last one the auth data and SSR state scripts.
+	pub fn into_chunks(self) -> impl Stream<Item = String> + Send {
+		let options = self.renderer.options().clone();
+		let start = finish_markup(&options, self.renderer.document_start(self.head.as_ref()));
+		let end = finish_markup(&options, self.renderer.document_end());
+		let sections = self.sections.into_iter().map(|section| match section {
+			Section::Ready(html) => future::ready(html).boxed(),
+			Section::Deferred(view) => view,
+		});
+
+		stream::once(future::ready(start))
+			.chain(
+				stream::iter(sections)
+					.buffered(usize::MAX)
+					.map(move |html| finish_markup(&options, html)),
+			)
+			.chain(stream::once(future::ready(end)))
+	}
+
+	/// Converts into a streaming body.
+	pub fn into_body(self) -> StreamBody {
+		Box::pin(self.into_chunks().map(|chunk| Ok(Bytes::from(chunk))))
+	}
+
+	/// Converts into a [`StreamingResponse`] ready to be served.
+	pub fn into_streaming_response(self) -> StreamingResponse<StreamBody> {
+		StreamingResponse::new(self.into_body())
+			.header(
+				CONTENT_TYPE,
+				HeaderValue::from_static("text/html; charset=utf-8"),
+			)
+			// Disables response buffering in nginx so chunks are flushed immediately.
+			.header(
+				HeaderName::from_static("x-accel-buffering"),
+				HeaderValue::from_static("no"),
+			)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::component::{Page, PageElement};
+	use rstest::rstest;
+
+	fn paragraph(text: &str) -> Page {
+		PageElement::new("p").child(text.to_string()).into_page()
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_stream_matches_buffered_page() {
+		// Arrange
+		let options = SsrOptions::new().csrf("token");
+		let expected = SsrRenderer::with_options(options.clone())
+			.wrap_in_html("<p>above</p><p>slow</p><p>below</p>");
+
+		// Act
+		let chunks: Vec<String> = SsrStream::new(options)
+			.shell(paragraph("above"))
+			.deferred(async { paragraph("slow") })
+			.shell(paragraph("below"))
+			.into_chunks()
+			.collect()
+			.await;
+
+		// Assert
+		assert_eq!(chunks.len(), 5);
+		assert!(chunks[0].ends_with("<div id=\"app\">"));
+		assert_eq!(chunks.concat(), expected);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_shell_is_flushed_before_deferred_sections_resolve() {
+		// Arrange
+		let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+		let mut chunks = Box::pin(
+			SsrStream::new(SsrOptions::default())
+				.shell(paragraph("above"))
+				.deferred(async move {
+					rx.await.ok();
+					paragraph("slow")
+				})
+				.into_chunks(),
+		);
+
+		// Act
+		let head = chunks.next().await.unwrap();
+		let shell = chunks.next().await.unwrap();
+		let pending = futures::poll!(chunks.next());
+		tx.send(()).unwrap();
+		let slow = chunks.next().await.unwrap();
+
+		// Assert
+		assert!(head.starts_with("<!DOCTYPE html>"));
+		assert_eq!(shell, "<p>above</p>");
+		assert!(pending.is_pending());
+		assert_eq!(slow, "<p>slow</p>");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_deferred_sections_run_concurrently_in_document_order() {
+		// Arrange
+		let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+		let stream = SsrStream::new(SsrOptions::default())
+			.deferred(async move {
+				rx.await.ok();
+				paragraph("first")
+			})
+			.deferred(async move {
+				tx.send(()).ok();
+				paragraph("second")
+			});
+
+		// Act
+		let chunks: Vec<String> = stream.into_chunks().collect().await;
+
+		// Assert
+		assert_eq!(chunks[1..3], ["<p>first</p>", "<p>second</p>"]);
+	}
+
+	#[rstest]
+	fn test_streaming_response_sets_html_headers() {
+		// Act
+		let response = SsrStream::new(SsrOptions::default()).into_streaming_response();
+
+		// Assert
+		assert_eq!(
+			response.headers.get(CONTENT_TYPE).unwrap(),
+			"text/html; charset=utf-8"
+		);
+		assert_eq!(response.headers.get("x-accel-buffering").unwrap(), "no");
+	}
+}
@@ -0,0 +1,54 @@
+//! Client telemetry (feature `telemetry`).
+//!
+//! The browser client records route changes, Core Web Vitals (LCP, CLS, INP,
+//! FCP, TTFB), uncaught JS errors, unhandled promise rejections and Rust
+//! panics. Events are batched and posted to [`TelemetryEndpoint`] once the
+//! browser is idle; whatever is still queued when the page is hidden or a
+//! panic occurs is sent with `keepalive` requests.
+//!
+//! Only a sampled fraction of sessions report (see
+//! [`TelemetryConfig::sample_rate`]), and by default nothing is recorded
+//! before [`grant_consent`] is called. Revoking consent discards the events
+//! not sent yet.
+//!
+//! On the server, [`TelemetryEndpoint`] hands received batches to a
+//! [`TelemetrySink`]. With a [`SourceMapSymbolicator`] the stack frames of
+//! error reports are mapped back to original sources using the source maps
+//! next to the served scripts.
+//!
+//! # Example
+//!
+//! ```ignore
+//! // Client (WASM)
+//! use reinhardt_pages::telemetry::{self, TelemetryConfig};
+//!
+//! telemetry::install(TelemetryConfig::builder().sample_rate(0.1).build());
+//! telemetry::track_router(&router);
+//! // once the user accepts the analytics banner
+//! telemetry::grant_consent();
+//!
+//! // Server
+//! use reinhardt_pages::telemetry::{SourceMapSymbolicator, TelemetryEndpoint, TelemetryRouterExt};
+//!
+//! let router = ServerRouter::new().telemetry(
+//!     TelemetryEndpoint::default()
+//!         .symbolicator(SourceMapSymbolicator::new("staticfiles", "/static/")),
+//! );
+//! ```
+
+mod client;
+mod config;
+mod event;
+mod queue;
+#[cfg(native)]
+mod server;
+
+pub use client::{grant_consent, install, report_error, revoke_consent, track_router};
+pub use config::{DEFAULT_TELEMETRY_ENDPOINT, TelemetryConfig, TelemetryConfigBuilder};
+pub use event::{ErrorKind, ErrorReport, StackFrame, TelemetryBatch, TelemetryEvent, WebVital};
+pub use queue::EventQueue;
+#[cfg(native)]
+pub use server::{
+	SourceMapSymbolicator, Symbolicator, TelemetryEndpoint, TelemetryRouterExt, TelemetrySink,
+	TracingSink,
+};
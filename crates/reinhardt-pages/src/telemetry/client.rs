@@ -0,0 +1,15 @@
+//! Browser telemetry client.
+//!
+//! The browser-WASM implementation lives in the `wasm` submodule and the
+//! native no-op stubs in the `native` submodule; this file only performs
+//! `#[cfg]` dispatch between them.
+
+#[cfg(wasm)]
+mod wasm;
+#[cfg(wasm)]
+pub use wasm::*;
+
+#[cfg(native)]
+mod native;
+#[cfg(native)]
+pub use native::*;
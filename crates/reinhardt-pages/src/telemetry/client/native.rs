@@ -0,0 +1,24 @@
+//! Telemetry client stubs for native targets.
+//!
+//! Compiled only under `#[cfg(native)]` via the dispatch in
+//! `crate::telemetry::client`. There is no browser to observe during
+//! server-side rendering, so every function is a no-op; they exist so that
+//! cross-target application code compiles without `#[cfg]` guards.
+
+use crate::telemetry::config::TelemetryConfig;
+use reinhardt_urls::routers::ClientRouter;
+
+/// Installs the telemetry client (no-op on native).
+pub fn install(_config: TelemetryConfig) {}
+
+/// Records route changes of `router` (no-op on native).
+pub fn track_router(_router: &ClientRouter) {}
+
+/// Grants consent (no-op on native).
+pub fn grant_consent() {}
+
+/// Revokes consent (no-op on native).
+pub fn revoke_consent() {}
+
+/// Records an error reported by application code (no-op on native).
+pub fn report_error(_message: &str, _stack: Option<String>) {}
@@ -0,0 +1,472 @@
+//! Telemetry client for browser-WASM targets.
+//!
+//! Compiled only under `#[cfg(wasm)]` via the dispatch in
+//! `crate::telemetry::client`. Browser hooks feed a thread-local
+//! [`EventQueue`]; batches are posted once the browser is idle, and drained
+//! with `keepalive` requests when the page is hidden or a panic occurs.
+
+use crate::telemetry::config::TelemetryConfig;
+use crate::telemetry::event::{ErrorKind, ErrorReport, TelemetryEvent, WebVital};
+use crate::telemetry::queue::EventQueue;
+use js_sys::{Array, Function, Object, Reflect};
+use reinhardt_urls::routers::{ClientRouter, NavigationSubscription};
+use std::cell::RefCell;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::prelude::*;
+
+/// Web Vitals that are only final once the page is hidden.
+#[derive(Default)]
+struct PendingVitals {
+	lcp: Option<f64>,
+	cls: f64,
+	inp: Option<f64>,
+}
+
+struct State {
+	config: TelemetryConfig,
+	queue: EventQueue,
+	flush_scheduled: bool,
+	last_path: Option<String>,
+	vitals: PendingVitals,
+	subscriptions: Vec<NavigationSubscription>,
+}
+
+thread_local! {
+	static STATE: RefCell<Option<State>> = const { RefCell::new(None) };
+}
+
+/// Installs the telemetry client.
+///
+/// Registers the browser hooks enabled in `config` and records the initial
+/// route. Calling it again has no effect.
+pub fn install(config: TelemetryConfig) {
+	if STATE.with(|state| state.borrow().is_some()) {
+		return;
+	}
+
+	let session_id = uuid::Uuid::now_v7().to_string();
+	let queue = EventQueue::new(&config, session_id, js_sys::Math::random());
+	let sampled = queue.is_sampled();
+	let state = State {
+		config: config.clone(),
+		queue,
+		flush_scheduled: false,
+		last_path: None,
+		vitals: PendingVitals::default(),
+		subscriptions: Vec::new(),
+	};
+	STATE.with(|cell| *cell.borrow_mut() = Some(state));
+
+	// Sessions left out by sampling never record anything.
+	if !sampled {
+		return;
+	}
+
+	listen_visibility();
+	if config.errors {
+		listen_errors();
+	}
+	if config.panics {
+		install_panic_hook();
+	}
+	if config.web_vitals {
+		observe_web_vitals();
+	}
+	if config.route_changes
+		&& let Some(path) = location_path()
+	{
+		record_route(&path);
+	}
+}
+
+/// Records route changes of `router`.
+///
+/// The subscription lives as long as the telemetry client.
+pub fn track_router(router: &ClientRouter) {
+	let enabled = with_state(|state| state.config.route_changes && state.queue.is_sampled());
+	if enabled != Some(true) {
+		return;
+	}
+	let subscription = router.on_navigate(|path, _params| record_route(path));
+	with_state(|state| state.subscriptions.push(subscription));
+}
+
+/// Grants consent; events are recorded from now on.
+pub fn grant_consent() {
+	with_state(|state| state.queue.set_consent(true));
+}
+
+/// Revokes consent and discards the events not sent yet.
+pub fn revoke_consent() {
+	with_state(|state| {
+		state.queue.set_consent(false);
+		state.vitals = PendingVitals::default();
+	});
+}
+
+/// Records an error reported by application code.
+pub fn report_error(message: &str, stack: Option<String>) {
+	let report = ErrorReport::new(ErrorKind::Error, message, stack, current_path());
+	record(TelemetryEvent::Error {
+		report,
+		timestamp_ms: now(),
+	});
+}
+
+fn with_state<R>(f: impl FnOnce(&mut State) -> R) -> Option<R> {
+	STATE.with(|cell| {
+		// A panic raised while the state is borrowed must not panic again.
+		let mut state = cell.try_borrow_mut().ok()?;
+		state.as_mut().map(f)
+	})
+}
+
+fn record(event: TelemetryEvent) {
+	let flush_now = with_state(|state| state.queue.push(event)).unwrap_or(false);
+	if flush_now {
+		flush();
+	} else {
+		schedule_flush();
+	}
+}
+
+fn record_route(path: &str) {
+	let from = with_state(|state| state.last_path.replace(path.to_string())).flatten();
+	if from.as_deref() == Some(path) {
+		return;
+	}
+	record(TelemetryEvent::RouteChange {
+		from,
+		to: path.to_string(),
+		timestamp_ms: now(),
+	});
+}
+
+fn record_vital(name: WebVital, value: f64) {
+	record(TelemetryEvent::WebVital {
+		name,
+		value,
+		path: current_path(),
+		timestamp_ms: now(),
+	});
+}
+
+/// Flushes after the configured delay, once the browser is idle.
+fn schedule_flush() {
+	let delay = with_state(|state| {
+		if state.flush_scheduled || state.queue.is_empty() {
+			return None;
+		}
+		state.flush_scheduled = true;
+		Some(state.config.flush_interval_ms)
+	})
+	.flatten();
+	let (Some(delay), Some(window)) = (delay, web_sys::window()) else {
+		return;
+	};
+
+	let callback = Closure::once_into_js(move || when_idle(delay, flush));
+	let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+		callback.unchecked_ref(),
+		delay.min(i32::MAX as u32) as i32,
+	);
+}
+
+/// Runs `f` from `requestIdleCallback`, or right away where it is unsupported.
+fn when_idle(timeout_ms: u32, f: fn()) {
+	let request_idle = web_sys::window()
+		.and_then(|window| Reflect::get(&window, &"requestIdleCallback".into()).ok())
+		.and_then(|value| value.dyn_into::<Function>().ok());
+	let Some(request_idle) = request_idle else {
+		f();
+		return;
+	};
+	let options = Object::new();
+	let _ = Reflect::set(&options, &"timeout".into(), &timeout_ms.into());
+	let callback = Closure::once_into_js(f);
+	if request_idle
+		.call2(&JsValue::UNDEFINED, &callback, &options)
+		.is_err()
+	{
+		f();
+	}
+}
+
+/// Posts every queued batch, putting back the ones that fail.
+fn flush() {
+	let Some((endpoint, batches)) = with_state(|state| {
+		state.flush_scheduled = false;
+		(state.config.endpoint.clone(), drain(&mut state.queue))
+	}) else {
+		return;
+	};
+	if batches.is_empty() {
+		return;
+	}
+
+	crate::platform::spawn_task(async move {
+		for batch in batches {
+			let Ok(body) = serde_json::to_string(&batch) else {
+				continue;
+			};
+			let headers = vec![("Content-Type".to_string(), "application/json".to_string())];
+			let sent = crate::fetch::request("POST", &endpoint, Some(&body), headers).await;
+			if !sent.is_ok_and(|response| response.is_success()) {
+				with_state(|state| state.queue.requeue(batch));
+			}
+		}
+	});
+}
+
+/// Sends every queued batch with `keepalive` requests that outlive the page.
+fn flush_keepalive() {
+	let Some((endpoint, batches)) =
+		with_state(|state| (state.config.endpoint.clone(), drain(&mut state.queue)))
+	else {
+		return;
+	};
+	let Some(window) = web_sys::window() else {
+		return;
+	};
+	for batch in batches {
+		let Ok(body) = serde_json::to_string(&batch) else {
+			continue;
+		};
+		let init = web_sys::RequestInit::new();
+		init.set_method("POST");
+		init.set_keepalive(true);
+		init.set_body(&JsValue::from_str(&body));
+		let Ok(request) = web_sys::Request::new_with_str_and_init(&endpoint, &init) else {
+			continue;
+		};
+		let headers = request.headers();
+		let _ = headers.set("Content-Type", "application/json");
+		if let Some(token) = crate::csrf::get_csrf_token() {
+			let _ = headers.set(crate::csrf::CSRF_HEADER_NAME, &token);
+		}
+		let _ = window.fetch_with_request(&request);
+	}
+}
+
+fn drain(queue: &mut EventQueue) -> Vec<crate::telemetry::event::TelemetryBatch> {
+	std::iter::from_fn(|| queue.take_batch()).collect()
+}
+
+/// Reports the final Web Vitals and drains the queue when the page is hidden.
+fn listen_visibility() {
+	let Some(window) = web_sys::window() else {
+		return;
+	};
+	let Some(document) = window.document() else {
+		return;
+	};
+	let target = document.clone();
+	let closure = Closure::wrap(Box::new(move |_event: web_sys::Event| {
+		let hidden = Reflect::get(&target, &"visibilityState".into())
+			.ok()
+			.and_then(|state| state.as_string())
+			.is_some_and(|state| state == "hidden");
+		if !hidden {
+			return;
+		}
+		let vitals = with_state(|state| std::mem::take(&mut state.vitals)).unwrap_or_default();
+		if let Some(lcp) = vitals.lcp {
+			record_vital(WebVital::Lcp, lcp);
+		}
+		if vitals.cls > 0.0 {
+			record_vital(WebVital::Cls, vitals.cls);
+		}
+		if let Some(inp) = vitals.inp {
+			record_vital(WebVital::Inp, inp);
+		}
+		flush_keepalive();
+	}) as Box<dyn FnMut(_)>);
+	let _ = document
+		.add_event_listener_with_callback("visibilitychange", closure.as_ref().unchecked_ref());
+	closure.forget();
+}
+
+/// Captures uncaught errors and unhandled promise rejections.
+fn listen_errors() {
+	let Some(window) = web_sys::window() else {
+		return;
+	};
+
+	let on_error = Closure::wrap(Box::new(move |event: web_sys::ErrorEvent| {
+		let report = ErrorReport::new(
+			ErrorKind::Error,
+			event.message(),
+			stack_of(&event.error()),
+			current_path(),
+		);
+		record(TelemetryEvent::Error {
+			report,
+			timestamp_ms: now(),
+		});
+	}) as Box<dyn FnMut(_)>);
+	let _ = window.add_event_listener_with_callback("error", on_error.as_ref().unchecked_ref());
+	on_error.forget();
+
+	let on_rejection = Closure::wrap(Box::new(move |event: web_sys::Event| {
+		let reason = Reflect::get(&event, &"reason".into()).unwrap_or(JsValue::UNDEFINED);
+		let message = match reason.dyn_ref::<js_sys::Error>() {
+			Some(error) => String::from(error.message()),
+			None => reason
+				.as_string()
+				.unwrap_or_else(|| format!("{:?}", reason)),
+		};
+		let report = ErrorReport::new(
+			ErrorKind::UnhandledRejection,
+			message,
+			stack_of(&reason),
+			current_path(),
+		);
+		record(TelemetryEvent::Error {
+			report,
+			timestamp_ms: now(),
+		});
+	}) as Box<dyn FnMut(_)>);
+	let _ = window.add_event_listener_with_callback(
+		"unhandledrejection",
+		on_rejection.as_ref().unchecked_ref(),
+	);
+	on_rejection.forget();
+}
+
+/// Records panics, then calls the previously installed hook.
+///
+/// The queue is drained right away because the WASM instance is unusable
+/// after a panic.
+fn install_panic_hook() {
+	let previous = std::panic::take_hook();
+	std::panic::set_hook(Box::new(move |info| {
+		let stack = stack_of(&js_sys::Error::new("panic").into());
+		let report = ErrorReport::new(ErrorKind::Panic, info.to_string(), stack, current_path());
+		with_state(|state| {
+			state.queue.push(TelemetryEvent::Error {
+				report,
+				timestamp_ms: now(),
+			})
+		});
+		flush_keepalive();
+		previous(info);
+	}));
+}
+
+/// Observes the performance entries behind the Core Web Vitals.
+fn observe_web_vitals() {
+	if let Some(ttfb) = navigation_response_start() {
+		record_vital(WebVital::Ttfb, ttfb);
+	}
+
+	observe("paint", |entry| {
+		if string_field(entry, "name").as_deref() == Some("first-contentful-paint")
+			&& let Some(start) = number_field(entry, "startTime")
+		{
+			record_vital(WebVital::Fcp, start);
+		}
+	});
+	observe("largest-contentful-paint", |entry| {
+		if let Some(start) = number_field(entry, "startTime") {
+			with_state(|state| state.vitals.lcp = Some(start));
+		}
+	});
+	observe("layout-shift", |entry| {
+		let recent_input = Reflect::get(entry, &"hadRecentInput".into())
+			.ok()
+			.and_then(|value| value.as_bool())
+			.unwrap_or(false);
+		if !recent_input && let Some(value) = number_field(entry, "value") {
+			with_state(|state| state.vitals.cls += value);
+		}
+	});
+	observe("event", |entry| {
+		let interaction = number_field(entry, "interactionId").unwrap_or(0.0);
+		if interaction > 0.0
+			&& let Some(duration) = number_field(entry, "duration")
+		{
+			with_state(|state| {
+				state.vitals.inp = Some(state.vitals.inp.map_or(duration, |inp| inp.max(duration)));
+			});
+		}
+	});
+}
+
+/// Calls `on_entry` for every buffered and future entry of `entry_type`.
+///
+/// Does nothing where `PerformanceObserver` or the entry type is unsupported.
+fn observe(entry_type: &str, on_entry: fn(&JsValue)) {
+	let constructor = Reflect::get(&js_sys::global(), &"PerformanceObserver".into())
+		.ok()
+		.and_then(|value| value.dyn_into::<Function>().ok());
+	let Some(constructor) = constructor else {
+		return;
+	};
+
+	let callback = Closure::wrap(Box::new(move |list: JsValue| {
+		let entries = Reflect::get(&list, &"getEntries".into())
+			.ok()
+			.and_then(|value| value.dyn_into::<Function>().ok())
+			.and_then(|get_entries| get_entries.call0(&list).ok());
+		if let Some(entries) = entries {
+			Array::from(&entries).for_each(&mut |entry, _, _| on_entry(&entry));
+		}
+	}) as Box<dyn FnMut(JsValue)>);
+
+	let Ok(observer) = Reflect::construct(&constructor, &Array::of1(callback.as_ref())) else {
+		return;
+	};
+	let options = Object::new();
+	let _ = Reflect::set(&options, &"type".into(), &entry_type.into());
+	let _ = Reflect::set(&options, &"buffered".into(), &JsValue::TRUE);
+	if entry_type == "event" {
+		let _ = Reflect::set(&options, &"durationThreshold".into(), &40.into());
+	}
+	let observed = Reflect::get(&observer, &"observe".into())
+		.ok()
+		.and_then(|value| value.dyn_into::<Function>().ok())
+		.is_some_and(|observe| observe.call1(&observer, &options).is_ok());
+	if observed {
+		callback.forget();
+	}
+}
+
+fn navigation_response_start() -> Option<f64> {
+	let performance = web_sys::window()?.performance()?;
+	let entry = performance.get_entries_by_type("navigation").get(0);
+	number_field(&entry, "responseStart").filter(|start| *start > 0.0)
+}
+
+fn number_field(value: &JsValue, name: &str) -> Option<f64> {
+	Reflect::get(value, &name.into()).ok()?.as_f64()
+}
+
+fn string_field(value: &JsValue, name: &str) -> Option<String> {
+	Reflect::get(value, &name.into()).ok()?.as_string()
+}
+
+fn stack_of(error: &JsValue) -> Option<String> {
+	if error.is_undefined() || error.is_null() {
+		return None;
+	}
+	string_field(error, "stack")
+}
+
+fn location_path() -> Option<String> {
+	web_sys::window()?.location().pathname().ok()
+}
+
+fn current_path() -> String {
+	with_state(|state| state.last_path.clone())
+		.flatten()
+		.or_else(location_path)
+		.unwrap_or_default()
+}
+
+/// Milliseconds since the page was loaded.
+fn now() -> f64 {
+	web_sys::window()
+		.and_then(|window| window.performance())
+		.map(|performance| performance.now())
+		.unwrap_or_default()
+}
@@ -0,0 +1,179 @@
+//! Telemetry configuration.
+
+/// Default path of the telemetry collection endpoint.
+pub const DEFAULT_TELEMETRY_ENDPOINT: &str = "/__reinhardt/telemetry/";
+
+/// Default number of events sent in one request.
+const DEFAULT_MAX_BATCH_SIZE: usize = 20;
+
+/// Default delay before queued events are flushed, in milliseconds.
+const DEFAULT_FLUSH_INTERVAL_MS: u32 = 5_000;
+
+/// Configuration for client telemetry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TelemetryConfig {
+	/// URL the batches are posted to.
+	pub endpoint: String,
+	/// Fraction of sessions that report, between `0.0` and `1.0`.
+	pub sample_rate: f64,
+	/// Number of queued events that triggers an immediate flush.
+	pub max_batch_size: usize,
+	/// Delay before queued events are flushed while the browser is idle.
+	pub flush_interval_ms: u32,
+	/// Whether nothing is recorded until consent is granted.
+	pub require_consent: bool,
+	/// Whether route changes are recorded.
+	pub route_changes: bool,
+	/// Whether Core Web Vitals are recorded.
+	pub web_vitals: bool,
+	/// Whether uncaught JS errors and unhandled rejections are recorded.
+	pub errors: bool,
+	/// Whether Rust panics are recorded.
+	pub panics: bool,
+}
+
+impl Default for TelemetryConfig {
+	fn default() -> Self {
+		Self {
+			endpoint: DEFAULT_TELEMETRY_ENDPOINT.to_string(),
+			sample_rate: 1.0,
+			max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+			flush_interval_ms: DEFAULT_FLUSH_INTERVAL_MS,
+			require_consent: true,
+			route_changes: true,
+			web_vitals: true,
+			errors: true,
+			panics: true,
+		}
+	}
+}
+
+impl TelemetryConfig {
+	/// Creates a builder for `TelemetryConfig`.
+	pub fn builder() -> TelemetryConfigBuilder {
+		TelemetryConfigBuilder {
+			config: TelemetryConfig::default(),
+		}
+	}
+}
+
+/// Builder for `TelemetryConfig`.
+#[derive(Debug, Clone)]
+pub struct TelemetryConfigBuilder {
+	config: TelemetryConfig,
+}
+
+impl TelemetryConfigBuilder {
+	/// Sets the URL the batches are posted to.
+	pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+		self.config.endpoint = endpoint.into();
+		self
+	}
+
+	/// Sets the fraction of sessions that report, clamped to `0.0..=1.0`.
+	pub fn sample_rate(mut self, rate: f64) -> Self {
+		self.config.sample_rate = if rate.is_nan() {
+			0.0
+		} else {
+			rate.clamp(0.0, 1.0)
+		};
+		self
+	}
+
+	/// Sets the number of queued events that triggers an immediate flush.
+	pub fn max_batch_size(mut self, size: usize) -> Self {
+		self.config.max_batch_size = size.max(1);
+		self
+	}
+
+	/// Sets the idle flush delay in milliseconds.
+	pub fn flush_interval_ms(mut self, ms: u32) -> Self {
+		self.config.flush_interval_ms = ms;
+		self
+	}
+
+	/// Sets whether nothing is recorded until consent is granted.
+	pub fn require_consent(mut self, required: bool) -> Self {
+		self.config.require_consent = required;
+		self
+	}
+
+	/// Enables or disables route change events.
+	pub fn route_changes(mut self, enabled: bool) -> Self {
+		self.config.route_changes = enabled;
+		self
+	}
+
+	/// Enables or disables Core Web Vitals events.
+	pub fn web_vitals(mut self, enabled: bool) -> Self {
+		self.config.web_vitals = enabled;
+		self
+	}
+
+	/// Enables or disables JS error capture.
+	pub fn errors(mut self, enabled: bool) -> Self {
+		self.config.errors = enabled;
+		self
+	}
+
+	/// Enables or disables panic capture.
+	pub fn panics(mut self, enabled: bool) -> Self {
+		self.config.panics = enabled;
+		self
+	}
+
+	/// Builds the `TelemetryConfig`.
+	pub fn build(self) -> TelemetryConfig {
+		self.config
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_default_config() {
+		// Arrange & Act
+		let config = TelemetryConfig::default();
+
+		// Assert
+		assert_eq!(config.endpoint, DEFAULT_TELEMETRY_ENDPOINT);
+		assert_eq!(config.sample_rate, 1.0);
+		assert!(config.require_consent);
+		assert!(config.route_changes && config.web_vitals && config.errors && config.panics);
+	}
+
+	#[rstest]
+	#[case(0.25, 0.25)]
+	#[case(-1.0, 0.0)]
+	#[case(3.0, 1.0)]
+	#[case(f64::NAN, 0.0)]
+	fn test_builder_clamps_sample_rate(#[case] rate: f64, #[case] expected: f64) {
+		// Arrange & Act
+		let config = TelemetryConfig::builder().sample_rate(rate).build();
+
+		// Assert
+		assert_eq!(config.sample_rate, expected);
+	}
+
+	#[rstest]
+	fn test_builder_customization() {
+		// Arrange & Act
+		let config = TelemetryConfig::builder()
+			.endpoint("/telemetry/")
+			.max_batch_size(0)
+			.flush_interval_ms(1_000)
+			.require_consent(false)
+			.web_vitals(false)
+			.build();
+
+		// Assert
+		assert_eq!(config.endpoint, "/telemetry/");
+		assert_eq!(config.max_batch_size, 1);
+		assert_eq!(config.flush_interval_ms, 1_000);
+		assert!(!config.require_consent);
+		assert!(!config.web_vitals);
+	}
+}
@@ -0,0 +1,237 @@
+//! Telemetry events and the batch wire format.
+
+use serde::{Deserialize, Serialize};
+
+/// Core Web Vital metric.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum WebVital {
+	/// Largest Contentful Paint, in milliseconds.
+	Lcp,
+	/// Cumulative Layout Shift, unitless.
+	Cls,
+	/// Interaction to Next Paint, in milliseconds.
+	Inp,
+	/// First Contentful Paint, in milliseconds.
+	Fcp,
+	/// Time to First Byte, in milliseconds.
+	Ttfb,
+}
+
+/// Origin of a captured error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+	/// Uncaught JS error (`window.onerror`).
+	Error,
+	/// Rejected promise without a handler.
+	UnhandledRejection,
+	/// Rust panic.
+	Panic,
+}
+
+/// One frame of a JS stack trace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StackFrame {
+	/// Function name, when the engine reports one.
+	pub function: Option<String>,
+	/// Script URL.
+	pub file: String,
+	/// 1-based line number.
+	pub line: u32,
+	/// 1-based column number.
+	pub column: u32,
+}
+
+impl StackFrame {
+	/// Parses a V8 (`at fn (file:1:2)`) or SpiderMonkey/JavaScriptCore
+	/// (`fn@file:1:2`) stack trace, skipping lines without a location.
+	pub fn parse_stack(stack: &str) -> Vec<StackFrame> {
+		stack.lines().filter_map(Self::parse_line).collect()
+	}
+
+	fn parse_line(line: &str) -> Option<StackFrame> {
+		let line = line.trim();
+		let (function, location) = if let Some(rest) = line.strip_prefix("at ") {
+			match rest.strip_suffix(')').and_then(|r| r.rsplit_once(" (")) {
+				Some((function, location)) => (Some(function), location),
+				None => (None, rest),
+			}
+		} else if let Some((function, location)) = line.split_once('@') {
+			(Some(function).filter(|f| !f.is_empty()), location)
+		} else {
+			return None;
+		};
+
+		let (rest, column) = location.rsplit_once(':')?;
+		let (file, line) = rest.rsplit_once(':')?;
+		Some(StackFrame {
+			function: function.map(str::to_string),
+			file: file.to_string(),
+			line: line.parse().ok()?,
+			column: column.parse().ok()?,
+		})
+	}
+}
+
+/// Captured client error.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorReport {
+	/// Origin of the error.
+	pub kind: ErrorKind,
+	/// Error message.
+	pub message: String,
+	/// Raw stack trace as reported by the browser.
+	#[serde(default)]
+	pub stack: Option<String>,
+	/// Parsed stack frames, rewritten to original sources by the server's
+	/// symbolicator.
+	#[serde(default)]
+	pub frames: Vec<StackFrame>,
+	/// Path of the page the error happened on.
+	pub path: String,
+}
+
+impl ErrorReport {
+	/// Creates a report, parsing the stack trace when there is one.
+	pub fn new(
+		kind: ErrorKind,
+		message: impl Into<String>,
+		stack: Option<String>,
+		path: impl Into<String>,
+	) -> Self {
+		let frames = stack
+			.as_deref()
+			.map(StackFrame::parse_stack)
+			.unwrap_or_default();
+		Self {
+			kind,
+			message: message.into(),
+			stack,
+			frames,
+			path: path.into(),
+		}
+	}
+}
+
+/// Event recorded by the telemetry client.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TelemetryEvent {
+	/// Client-side navigation between two routes.
+	RouteChange {
+		/// Previous path; `None` for the initial page.
+		from: Option<String>,
+		/// New path.
+		to: String,
+		/// Milliseconds since the page was loaded.
+		timestamp_ms: f64,
+	},
+	/// Core Web Vital measurement.
+	WebVital {
+		/// Metric name.
+		name: WebVital,
+		/// Metric value.
+		value: f64,
+		/// Path of the page the metric belongs to.
+		path: String,
+		/// Milliseconds since the page was loaded.
+		timestamp_ms: f64,
+	},
+	/// Captured JS error or panic.
+	Error {
+		/// Error details.
+		#[serde(flatten)]
+		report: ErrorReport,
+		/// Milliseconds since the page was loaded.
+		timestamp_ms: f64,
+	},
+}
+
+/// Events posted to the telemetry endpoint in one request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TelemetryBatch {
+	/// Random identifier of the browser session.
+	pub session_id: String,
+	/// Recorded events, oldest first.
+	pub events: Vec<TelemetryEvent>,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case::v8(
+		"Error: boom\n    at render (https://example.com/app.js:10:5)\n    at https://example.com/app.js:20:1",
+		vec![
+			StackFrame {
+				function: Some("render".to_string()),
+				file: "https://example.com/app.js".to_string(),
+				line: 10,
+				column: 5,
+			},
+			StackFrame {
+				function: None,
+				file: "https://example.com/app.js".to_string(),
+				line: 20,
+				column: 1,
+			},
+		]
+	)]
+	#[case::spidermonkey(
+		"render@https://example.com/app.js:10:5\n@https://example.com/app.js:20:1",
+		vec![
+			StackFrame {
+				function: Some("render".to_string()),
+				file: "https://example.com/app.js".to_string(),
+				line: 10,
+				column: 5,
+			},
+			StackFrame {
+				function: None,
+				file: "https://example.com/app.js".to_string(),
+				line: 20,
+				column: 1,
+			},
+		]
+	)]
+	fn test_parse_stack(#[case] stack: &str, #[case] expected: Vec<StackFrame>) {
+		// Act
+		let frames = StackFrame::parse_stack(stack);
+
+		// Assert
+		assert_eq!(frames, expected);
+	}
+
+	#[rstest]
+	fn test_event_wire_format() {
+		// Arrange
+		let event = TelemetryEvent::Error {
+			report: ErrorReport::new(ErrorKind::Panic, "boom", None, "/users/"),
+			timestamp_ms: 12.5,
+		};
+
+		// Act
+		let json = serde_json::to_value(&event).unwrap();
+
+		// Assert
+		assert_eq!(json["type"], "error");
+		assert_eq!(json["kind"], "panic");
+		assert_eq!(json["path"], "/users/");
+		assert_eq!(
+			serde_json::from_value::<TelemetryEvent>(json).unwrap(),
+			event
+		);
+	}
+
+	#[rstest]
+	fn test_web_vital_names() {
+		// Act
+		let json = serde_json::to_string(&WebVital::Lcp).unwrap();
+
+		// Assert
+		assert_eq!(json, "\"LCP\"");
+	}
+}
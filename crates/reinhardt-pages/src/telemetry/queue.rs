@@ -0,0 +1,223 @@
+//! Event queue with sampling, consent gating and batching.
+
+use super::config::TelemetryConfig;
+use super::event::{TelemetryBatch, TelemetryEvent};
+use std::collections::VecDeque;
+
+/// Maximum number of events kept while the endpoint is unreachable.
+///
+/// The oldest events are dropped first.
+const MAX_QUEUED_EVENTS: usize = 500;
+
+/// Queue of events waiting to be sent.
+///
+/// Platform independent: the browser client feeds it and drains it, the
+/// decisions about what to record and when to send live here.
+#[derive(Debug)]
+pub struct EventQueue {
+	session_id: String,
+	sampled: bool,
+	require_consent: bool,
+	consent: bool,
+	max_batch_size: usize,
+	events: VecDeque<TelemetryEvent>,
+}
+
+impl EventQueue {
+	/// Creates a queue for one session.
+	///
+	/// `roll` is a uniform random number in `0.0..1.0`; the session reports
+	/// when it falls below the configured sample rate.
+	pub fn new(config: &TelemetryConfig, session_id: impl Into<String>, roll: f64) -> Self {
+		Self {
+			session_id: session_id.into(),
+			sampled: roll < config.sample_rate,
+			require_consent: config.require_consent,
+			consent: false,
+			max_batch_size: config.max_batch_size.max(1),
+			events: VecDeque::new(),
+		}
+	}
+
+	/// Session identifier sent with every batch.
+	pub fn session_id(&self) -> &str {
+		&self.session_id
+	}
+
+	/// Whether this session was selected by sampling.
+	pub fn is_sampled(&self) -> bool {
+		self.sampled
+	}
+
+	/// Grants or revokes consent; revoking discards queued events.
+	pub fn set_consent(&mut self, granted: bool) {
+		self.consent = granted;
+		if !granted {
+			self.events.clear();
+		}
+	}
+
+	/// Whether new events are currently recorded.
+	pub fn is_recording(&self) -> bool {
+		self.sampled && (self.consent || !self.require_consent)
+	}
+
+	/// Records an event.
+	///
+	/// Returns `true` when a full batch is waiting and should be sent now.
+	pub fn push(&mut self, event: TelemetryEvent) -> bool {
+		if !self.is_recording() {
+			return false;
+		}
+		if self.events.len() == MAX_QUEUED_EVENTS {
+			self.events.pop_front();
+		}
+		self.events.push_back(event);
+		self.events.len() >= self.max_batch_size
+	}
+
+	/// Removes up to one batch of events, oldest first.
+	pub fn take_batch(&mut self) -> Option<TelemetryBatch> {
+		if self.events.is_empty() || !self.is_recording() {
+			return None;
+		}
+		let count = self.events.len().min(self.max_batch_size);
+		Some(TelemetryBatch {
+			session_id: self.session_id.clone(),
+			events: self.events.drain(..count).collect(),
+		})
+	}
+
+	/// Puts a batch that could not be sent back at the front of the queue.
+	pub fn requeue(&mut self, batch: TelemetryBatch) {
+		for event in batch.events.into_iter().rev() {
+			if self.events.len() == MAX_QUEUED_EVENTS {
+				break;
+			}
+			self.events.push_front(event);
+		}
+	}
+
+	/// Number of queued events.
+	pub fn len(&self) -> usize {
+		self.events.len()
+	}
+
+	/// Whether no event is queued.
+	pub fn is_empty(&self) -> bool {
+		self.events.is_empty()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn route(to: &str) -> TelemetryEvent {
+		TelemetryEvent::RouteChange {
+			from: None,
+			to: to.to_string(),
+			timestamp_ms: 0.0,
+		}
+	}
+
+	fn config(sample_rate: f64, require_consent: bool) -> TelemetryConfig {
+		TelemetryConfig::builder()
+			.sample_rate(sample_rate)
+			.require_consent(require_consent)
+			.max_batch_size(2)
+			.build()
+	}
+
+	#[rstest]
+	#[case::sampled_with_consent(0.5, 0.4, true, true)]
+	#[case::not_sampled(0.5, 0.6, true, false)]
+	#[case::sample_rate_zero(0.0, 0.0, true, false)]
+	#[case::consent_missing(1.0, 0.0, false, false)]
+	fn test_is_recording(
+		#[case] sample_rate: f64,
+		#[case] roll: f64,
+		#[case] consent: bool,
+		#[case] expected: bool,
+	) {
+		// Arrange
+		let mut queue = EventQueue::new(&config(sample_rate, true), "s", roll);
+
+		// Act
+		queue.set_consent(consent);
+
+		// Assert
+		assert_eq!(queue.is_recording(), expected);
+	}
+
+	#[rstest]
+	fn test_events_before_consent_are_dropped() {
+		// Arrange
+		let mut queue = EventQueue::new(&config(1.0, true), "s", 0.0);
+
+		// Act
+		queue.push(route("/a/"));
+		queue.set_consent(true);
+		queue.push(route("/b/"));
+
+		// Assert
+		let batch = queue.take_batch().unwrap();
+		assert_eq!(batch.events, vec![route("/b/")]);
+	}
+
+	#[rstest]
+	fn test_push_signals_full_batch() {
+		// Arrange
+		let mut queue = EventQueue::new(&config(1.0, false), "s", 0.0);
+
+		// Act
+		let first = queue.push(route("/a/"));
+		let second = queue.push(route("/b/"));
+		queue.push(route("/c/"));
+
+		// Assert
+		assert!(!first);
+		assert!(second);
+		let batch = queue.take_batch().unwrap();
+		assert_eq!(batch.session_id, "s");
+		assert_eq!(batch.events, vec![route("/a/"), route("/b/")]);
+		assert_eq!(queue.len(), 1);
+	}
+
+	#[rstest]
+	fn test_requeue_keeps_order() {
+		// Arrange
+		let mut queue = EventQueue::new(&config(1.0, false), "s", 0.0);
+		queue.push(route("/a/"));
+		queue.push(route("/b/"));
+		let batch = queue.take_batch().unwrap();
+		queue.push(route("/c/"));
+
+		// Act
+		queue.requeue(batch);
+
+		// Assert
+		assert_eq!(
+			queue.take_batch().unwrap().events,
+			vec![route("/a/"), route("/b/")]
+		);
+		assert_eq!(queue.take_batch().unwrap().events, vec![route("/c/")]);
+		assert!(queue.is_empty());
+	}
+
+	#[rstest]
+	fn test_revoking_consent_discards_queue() {
+		// Arrange
+		let mut queue = EventQueue::new(&config(1.0, true), "s", 0.0);
+		queue.set_consent(true);
+		queue.push(route("/a/"));
+
+		// Act
+		queue.set_consent(false);
+
+		// Assert
+		assert!(queue.is_empty());
+		assert!(queue.take_batch().is_none());
+	}
+}
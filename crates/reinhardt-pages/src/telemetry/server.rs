@@ -0,0 +1,355 @@
+//! Telemetry collection endpoint.
+//!
+//! Receives the batches posted by the browser client, rewrites error stack
+//! frames to original sources with an optional [`Symbolicator`], and hands
+//! the batch to a [`TelemetrySink`].
+
+use super::config::DEFAULT_TELEMETRY_ENDPOINT;
+use super::event::{StackFrame, TelemetryBatch, TelemetryEvent};
+use async_trait::async_trait;
+use hyper::{Method, StatusCode};
+use reinhardt_http::{Handler, Request, Response, Result};
+use reinhardt_urls::routers::ServerRouter;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// Maximum accepted batch size in bytes.
+const MAX_BATCH_BYTES: usize = 64 * 1024;
+
+/// Destination of received telemetry batches.
+#[async_trait]
+pub trait TelemetrySink: Send + Sync {
+	/// Stores or forwards one batch.
+	async fn record(&self, batch: TelemetryBatch);
+}
+
+/// Sink logging every event through `tracing`.
+///
+/// Errors are logged at `WARN`, navigation and Web Vitals at `DEBUG`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingSink;
+
+#[async_trait]
+impl TelemetrySink for TracingSink {
+	async fn record(&self, batch: TelemetryBatch) {
+		for event in &batch.events {
+			match event {
+				TelemetryEvent::Error { report, .. } => tracing::warn!(
+					session_id = %batch.session_id,
+					kind = ?report.kind,
+					path = %report.path,
+					frames = ?report.frames,
+					"client error: {}",
+					report.message
+				),
+				TelemetryEvent::RouteChange { from, to, .. } => tracing::debug!(
+					session_id = %batch.session_id,
+					from = ?from,
+					to = %to,
+					"client route change"
+				),
+				TelemetryEvent::WebVital {
+					name, value, path, ..
+				} => tracing::debug!(
+					session_id = %batch.session_id,
+					name = ?name,
+					value = *value,
+					path = %path,
+					"client web vital"
+				),
+			}
+		}
+	}
+}
+
+/// Maps minified stack frames back to original sources.
+pub trait Symbolicator: Send + Sync {
+	/// Returns the original location of `frame`, or `None` to keep it as is.
+	fn symbolicate(&self, frame: &StackFrame) -> Option<StackFrame>;
+}
+
+/// Symbolicator reading `<script>.map` files from the static files root.
+///
+/// A frame in `https://example.com/static/js/app.js` with the URL prefix
+/// `/static/` is looked up in `<root>/js/app.js.map`. Parsed maps are cached.
+pub struct SourceMapSymbolicator {
+	root: PathBuf,
+	url_prefix: String,
+	maps: Mutex<HashMap<PathBuf, Option<Arc<sourcemap::SourceMap>>>>,
+}
+
+impl SourceMapSymbolicator {
+	/// Creates a symbolicator serving maps from `root` for scripts under `url_prefix`.
+	pub fn new(root: impl Into<PathBuf>, url_prefix: impl Into<String>) -> Self {
+		Self {
+			root: root.into(),
+			url_prefix: url_prefix.into(),
+			maps: Mutex::new(HashMap::new()),
+		}
+	}
+
+	/// Path of the source map for a script URL.
+	fn map_path(&self, file: &str) -> Option<PathBuf> {
+		let path = match file.split_once("://") {
+			Some((_, rest)) => &rest[rest.find('/')?..],
+			None => file,
+		};
+		let path = path.split(['?', '#']).next()?;
+		let relative = path.strip_prefix(self.url_prefix.as_str())?;
+		reinhardt_utils::safe_path_join(&self.root, &format!("{}.map", relative)).ok()
+	}
+
+	fn load(&self, path: PathBuf) -> Option<Arc<sourcemap::SourceMap>> {
+		let mut maps = self.maps.lock().unwrap_or_else(|e| e.into_inner());
+		maps.entry(path)
+			.or_insert_with_key(|path| {
+				let file = std::fs::File::open(path).ok()?;
+				sourcemap::SourceMap::from_reader(file).ok().map(Arc::new)
+			})
+			.clone()
+	}
+}
+
+impl Symbolicator for SourceMapSymbolicator {
+	fn symbolicate(&self, frame: &StackFrame) -> Option<StackFrame> {
+		let map = self.load(self.map_path(&frame.file)?)?;
+		let token = map.lookup_token(frame.line.checked_sub(1)?, frame.column.checked_sub(1)?)?;
+		Some(StackFrame {
+			function: token
+				.get_name()
+				.map(str::to_string)
+				.or_else(|| frame.function.clone()),
+			file: token.get_source()?.to_string(),
+			line: token.get_src_line() + 1,
+			column: token.get_src_col() + 1,
+		})
+	}
+}
+
+/// Handler receiving telemetry batches.
+pub struct TelemetryEndpoint {
+	path: String,
+	sink: Arc<dyn TelemetrySink>,
+	symbolicator: Option<Arc<dyn Symbolicator>>,
+}
+
+impl Default for TelemetryEndpoint {
+	fn default() -> Self {
+		Self::new(TracingSink)
+	}
+}
+
+impl TelemetryEndpoint {
+	/// Creates an endpoint at [`DEFAULT_TELEMETRY_ENDPOINT`] recording into `sink`.
+	pub fn new(sink: impl TelemetrySink + 'static) -> Self {
+		Self {
+			path: DEFAULT_TELEMETRY_ENDPOINT.to_string(),
+			sink: Arc::new(sink),
+			symbolicator: None,
+		}
+	}
+
+	/// Sets the path; must match the client's `TelemetryConfig::endpoint`.
+	pub fn path(mut self, path: impl Into<String>) -> Self {
+		self.path = path.into();
+		self
+	}
+
+	/// Sets the symbolicator applied to error stack frames.
+	pub fn symbolicator(mut self, symbolicator: impl Symbolicator + 'static) -> Self {
+		self.symbolicator = Some(Arc::new(symbolicator));
+		self
+	}
+
+	fn symbolicate(&self, batch: &mut TelemetryBatch) {
+		let Some(symbolicator) = &self.symbolicator else {
+			return;
+		};
+		for event in &mut batch.events {
+			if let TelemetryEvent::Error { report, .. } = event {
+				for frame in &mut report.frames {
+					if let Some(original) = symbolicator.symbolicate(frame) {
+						*frame = original;
+					}
+				}
+			}
+		}
+	}
+}
+
+#[async_trait]
+impl Handler for TelemetryEndpoint {
+	async fn handle(&self, request: Request) -> Result<Response> {
+		if request.method != Method::POST {
+			return Ok(Response::new(StatusCode::METHOD_NOT_ALLOWED).with_header("Allow", "POST"));
+		}
+
+		let mut batch: TelemetryBatch = request.json_limited(MAX_BATCH_BYTES).await?;
+		self.symbolicate(&mut batch);
+		self.sink.record(batch).await;
+		Ok(Response::no_content())
+	}
+}
+
+/// Extension trait for registering the telemetry endpoint with `ServerRouter`.
+///
+/// # Example
+///
+/// ```ignore
+/// use reinhardt::pages::telemetry::{SourceMapSymbolicator, TelemetryEndpoint, TelemetryRouterExt};
+///
+/// let router = ServerRouter::new().telemetry(
+///     TelemetryEndpoint::default()
+///         .symbolicator(SourceMapSymbolicator::new("staticfiles", "/static/")),
+/// );
+/// ```
+pub trait TelemetryRouterExt {
+	/// Register the telemetry endpoint at its path.
+	fn telemetry(self, endpoint: TelemetryEndpoint) -> Self;
+}
+
+impl TelemetryRouterExt for ServerRouter {
+	fn telemetry(self, endpoint: TelemetryEndpoint) -> Self {
+		let path = endpoint.path.clone();
+		self.handler(&path, endpoint)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::telemetry::event::{ErrorKind, ErrorReport};
+	use bytes::Bytes;
+	use rstest::rstest;
+
+	#[derive(Default)]
+	struct MemorySink(Mutex<Vec<TelemetryBatch>>);
+
+	#[async_trait]
+	impl TelemetrySink for Arc<MemorySink> {
+		async fn record(&self, batch: TelemetryBatch) {
+			self.0.lock().unwrap().push(batch);
+		}
+	}
+
+	struct Renaming;
+
+	impl Symbolicator for Renaming {
+		fn symbolicate(&self, frame: &StackFrame) -> Option<StackFrame> {
+			Some(StackFrame {
+				file: "src/app.rs".to_string(),
+				..frame.clone()
+			})
+		}
+	}
+
+	fn post(body: &TelemetryBatch) -> Request {
+		let mut headers = hyper::HeaderMap::new();
+		headers.insert(
+			hyper::header::CONTENT_TYPE,
+			"application/json".parse().unwrap(),
+		);
+		Request::builder()
+			.method(Method::POST)
+			.uri(DEFAULT_TELEMETRY_ENDPOINT)
+			.headers(headers)
+			.body(Bytes::from(serde_json::to_vec(body).unwrap()))
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_endpoint_symbolicates_and_records() {
+		// Arrange
+		let sink = Arc::new(MemorySink::default());
+		let endpoint = TelemetryEndpoint::new(sink.clone()).symbolicator(Renaming);
+		let batch = TelemetryBatch {
+			session_id: "s".to_string(),
+			events: vec![TelemetryEvent::Error {
+				report: ErrorReport::new(
+					ErrorKind::Error,
+					"boom",
+					Some("at f (https://example.com/static/app.js:1:2)".to_string()),
+					"/",
+				),
+				timestamp_ms: 1.0,
+			}],
+		};
+
+		// Act
+		let response = endpoint.handle(post(&batch)).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::NO_CONTENT);
+		let recorded = sink.0.lock().unwrap();
+		let TelemetryEvent::Error { report, .. } = &recorded[0].events[0] else {
+			panic!("expected an error event");
+		};
+		assert_eq!(report.frames[0].file, "src/app.rs");
+		assert_eq!(report.frames[0].function.as_deref(), Some("f"));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_endpoint_rejects_get() {
+		// Arrange
+		let endpoint = TelemetryEndpoint::default();
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri(DEFAULT_TELEMETRY_ENDPOINT)
+			.build()
+			.unwrap();
+
+		// Act
+		let response = endpoint.handle(request).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::METHOD_NOT_ALLOWED);
+	}
+
+	#[rstest]
+	fn test_source_map_symbolicator() {
+		// Arrange
+		let dir = tempfile::tempdir().unwrap();
+		std::fs::write(
+			dir.path().join("app.js.map"),
+			r#"{"version":3,"sources":["src/app.rs"],"names":["render"],"mappings":"AAAAA"}"#,
+		)
+		.unwrap();
+		let symbolicator = SourceMapSymbolicator::new(dir.path(), "/static/");
+		let frame = StackFrame {
+			function: Some("a".to_string()),
+			file: "https://example.com/static/app.js".to_string(),
+			line: 1,
+			column: 1,
+		};
+
+		// Act
+		let original = symbolicator.symbolicate(&frame).unwrap();
+
+		// Assert
+		assert_eq!(original.function.as_deref(), Some("render"));
+		assert_eq!(original.file, "src/app.rs");
+		assert_eq!((original.line, original.column), (1, 1));
+	}
+
+	#[rstest]
+	#[case("https://example.com/static/js/app.js", Some("js/app.js.map"))]
+	#[case("/static/app.js?v=3", Some("app.js.map"))]
+	#[case("https://example.com/other/app.js", None)]
+	#[case("https://example.com/static/../secret.js", None)]
+	fn test_source_map_path(#[case] file: &str, #[case] expected: Option<&str>) {
+		// Arrange
+		let dir = tempfile::tempdir().unwrap();
+		let root = dir.path().canonicalize().unwrap();
+		let symbolicator = SourceMapSymbolicator::new(&root, "/static/");
+
+		// Act
+		let path = symbolicator.map_path(file);
+
+		// Assert
+		assert_eq!(path, expected.map(|p| root.join(p)));
+	}
+}
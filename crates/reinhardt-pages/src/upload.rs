@@ -0,0 +1,467 @@
+//! Resumable file uploads from the browser
+//!
+//! Client side of the tus 1.0 endpoint served by
+//! `reinhardt_utils::resumable_upload::TusHandler`. [`ResumableUpload`]
+//! slices a `File` into chunks, sends them one at a time with
+//! `Upload-Offset`, reports progress after each chunk and retries failed
+//! chunks with exponential backoff. After a page reload the upload continues
+//! from the offset the server reports for [`ResumableUpload::upload_url`].
+//!
+//! ```ignore
+//! use reinhardt_pages::upload::{ResumableUpload, UploadOptions};
+//!
+//! let upload = ResumableUpload::new(file, UploadOptions::new("/api/uploads"))
+//!     .on_progress(|progress| set_percent.set(progress.percentage()));
+//! let outcome = upload.start().await?;
+//! // outcome.stored_path is the path in the server's storage
+//! ```
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+/// Protocol version sent in `Tus-Resumable`
+pub const TUS_VERSION: &str = "1.0.0";
+
+/// Options of a resumable upload.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UploadOptions {
+	/// Creation endpoint of the upload server
+	pub endpoint: String,
+	/// Bytes sent per `PATCH`
+	pub chunk_size: u64,
+	/// Retries of a failed request before giving up
+	pub max_retries: u32,
+	/// Delay before the first retry, doubled for each further retry
+	pub retry_delay_ms: u32,
+	/// Extra `Upload-Metadata` entries; `filename` is added from the file
+	pub metadata: Vec<(String, String)>,
+}
+
+impl UploadOptions {
+	/// Options for uploading to `endpoint` with 5 MiB chunks and 5 retries.
+	pub fn new(endpoint: impl Into<String>) -> Self {
+		Self {
+			endpoint: endpoint.into(),
+			chunk_size: 5 * 1024 * 1024,
+			max_retries: 5,
+			retry_delay_ms: 500,
+			metadata: Vec::new(),
+		}
+	}
+
+	/// Sets the bytes sent per request.
+	pub fn with_chunk_size(mut self, chunk_size: u64) -> Self {
+		self.chunk_size = chunk_size.max(1);
+		self
+	}
+
+	/// Sets the retries of a failed request.
+	pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+		self.max_retries = max_retries;
+		self
+	}
+
+	/// Sets the delay before the first retry.
+	pub fn with_retry_delay_ms(mut self, retry_delay_ms: u32) -> Self {
+		self.retry_delay_ms = retry_delay_ms;
+		self
+	}
+
+	/// Adds an `Upload-Metadata` entry, e.g. the expected `sha256` of the file.
+	pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+		self.metadata.push((key.into(), value.into()));
+		self
+	}
+
+	/// Delay before retry number `attempt` (starting at 1), capped at 30 seconds.
+	pub fn retry_delay(&self, attempt: u32) -> u32 {
+		let factor = 1u32 << attempt.saturating_sub(1).min(16);
+		self.retry_delay_ms.saturating_mul(factor).min(30_000)
+	}
+}
+
+/// Progress of a resumable upload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadProgress {
+	/// Bytes acknowledged by the server
+	pub bytes_uploaded: u64,
+	/// Size of the file
+	pub bytes_total: u64,
+}
+
+impl UploadProgress {
+	/// Uploaded share of the file, from 0.0 to 100.0.
+	pub fn percentage(&self) -> f64 {
+		if self.bytes_total == 0 {
+			100.0
+		} else {
+			self.bytes_uploaded as f64 / self.bytes_total as f64 * 100.0
+		}
+	}
+}
+
+/// Result of a finished upload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadOutcome {
+	/// URL of the upload on the server
+	pub upload_url: String,
+	/// Path of the assembled file in the server's storage
+	pub stored_path: Option<String>,
+}
+
+/// Errors of resumable uploads.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum UploadError {
+	/// The request could not be sent, after all retries
+	#[error("Network error: {0}")]
+	Network(String),
+	/// The server rejected the upload
+	#[error("Upload rejected with status {status}: {message}")]
+	Rejected {
+		/// HTTP status code
+		status: u16,
+		/// Response body
+		message: String,
+	},
+	/// The server answered without a required protocol header
+	#[error("Invalid server response: {0}")]
+	Protocol(String),
+}
+
+impl UploadError {
+	/// Whether retrying the same request can succeed.
+	///
+	/// Network failures, timeouts, rate limits, checksum mismatches (460)
+	/// and server errors are retried; other rejections are final.
+	pub fn is_retryable(&self) -> bool {
+		match self {
+			Self::Network(_) => true,
+			Self::Rejected { status, .. } => {
+				matches!(status, 408 | 423 | 429 | 460) || *status >= 500
+			}
+			Self::Protocol(_) => false,
+		}
+	}
+}
+
+/// Encodes an `Upload-Metadata` header value.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_pages::upload::encode_upload_metadata;
+///
+/// let header = encode_upload_metadata(&[("filename".to_string(), "a.txt".to_string())]);
+/// assert_eq!(header, "filename YS50eHQ=");
+/// ```
+pub fn encode_upload_metadata(metadata: &[(String, String)]) -> String {
+	metadata
+		.iter()
+		.map(|(key, value)| format!("{} {}", key, STANDARD.encode(value)))
+		.collect::<Vec<_>>()
+		.join(",")
+}
+
+/// Byte range `[start, end)` of the chunk starting at `offset`, or `None` when
+/// the upload is complete.
+pub fn next_chunk(offset: u64, total: u64, chunk_size: u64) -> Option<(u64, u64)> {
+	(offset < total).then(|| (offset, offset.saturating_add(chunk_size.max(1)).min(total)))
+}
+
+/// A resumable upload of one browser `File`.
+#[cfg(wasm)]
+pub struct ResumableUpload {
+	file: web_sys::File,
+	options: UploadOptions,
+	upload_url: std::cell::RefCell<Option<String>>,
+	on_progress: Option<Box<dyn Fn(UploadProgress)>>,
+}
+
+#[cfg(wasm)]
+impl ResumableUpload {
+	/// Prepares an upload of `file`; nothing is sent until [`start`](Self::start).
+	pub fn new(file: web_sys::File, options: UploadOptions) -> Self {
+		Self {
+			file,
+			options,
+			upload_url: std::cell::RefCell::new(None),
+			on_progress: None,
+		}
+	}
+
+	/// Continues an upload created earlier, e.g. before a page reload.
+	pub fn resume_from(self, upload_url: impl Into<String>) -> Self {
+		*self.upload_url.borrow_mut() = Some(upload_url.into());
+		self
+	}
+
+	/// Calls `callback` after every acknowledged chunk.
+	pub fn on_progress(mut self, callback: impl Fn(UploadProgress) + 'static) -> Self {
+		self.on_progress = Some(Box::new(callback));
+		self
+	}
+
+	/// URL of the upload once created; persist it to resume later.
+	pub fn upload_url(&self) -> Option<String> {
+		self.upload_url.borrow().clone()
+	}
+
+	/// Uploads the file, resuming from the server's offset when possible.
+	pub async fn start(&self) -> Result<UploadOutcome, UploadError> {
+		let total = self.file.size() as u64;
+		let mut offset = match self.upload_url() {
+			Some(url) => match self.with_retries(|| self.head(&url)).await {
+				Ok(offset) => offset,
+				// The upload expired or was removed; start over.
+				Err(UploadError::Rejected {
+					status: 404 | 410, ..
+				}) => self.create(total).await?,
+				Err(error) => return Err(error),
+			},
+			None => self.create(total).await?,
+		};
+		let url = self
+			.upload_url()
+			.ok_or_else(|| UploadError::Protocol("missing upload URL".to_string()))?;
+		self.report(offset, total);
+
+		let mut stored_path = None;
+		while let Some((start, end)) = next_chunk(offset, total, self.options.chunk_size) {
+			let (next, path) = self.with_retries(|| self.patch(&url, start, end)).await?;
+			offset = next;
+			stored_path = path.or(stored_path);
+			self.report(offset, total);
+		}
+
+		Ok(UploadOutcome {
+			upload_url: url,
+			stored_path,
+		})
+	}
+
+	async fn create(&self, total: u64) -> Result<u64, UploadError> {
+		let mut metadata = vec![("filename".to_string(), self.file.name())];
+		metadata.extend(self.options.metadata.iter().cloned());
+		let headers = vec![
+			("Upload-Length".to_string(), total.to_string()),
+			(
+				"Upload-Metadata".to_string(),
+				encode_upload_metadata(&metadata),
+			),
+		];
+		let response = self
+			.with_retries(|| send("POST", &self.options.endpoint, None, headers.clone()))
+			.await?;
+		let location = response
+			.headers()
+			.get("Location")
+			.ok()
+			.flatten()
+			.ok_or_else(|| UploadError::Protocol("missing Location header".to_string()))?;
+		let url = web_sys::Url::new_with_base(&location, &self.options.endpoint_base())
+			.map(|url| url.href())
+			.unwrap_or(location);
+		*self.upload_url.borrow_mut() = Some(url);
+		Ok(0)
+	}
+
+	async fn head(&self, url: &str) -> Result<u64, UploadError> {
+		let response = send("HEAD", url, None, Vec::new()).await?;
+		upload_offset(&response)
+	}
+
+	async fn patch(
+		&self,
+		url: &str,
+		start: u64,
+		end: u64,
+	) -> Result<(u64, Option<String>), UploadError> {
+		let chunk = self
+			.file
+			.slice_with_f64_and_f64(start as f64, end as f64)
+			.map_err(|e| UploadError::Network(crate::fetch::js_error_message(e)))?;
+		let headers = vec![
+			(
+				"Content-Type".to_string(),
+				"application/offset+octet-stream".to_string(),
+			),
+			("Upload-Offset".to_string(), start.to_string()),
+		];
+		let response = match send("PATCH", url, Some(&chunk), headers).await {
+			// The previous attempt may have reached the server before failing;
+			// continue from the offset it acknowledged.
+			Err(UploadError::Rejected { status: 409, .. }) => {
+				return Ok((self.head(url).await?, None));
+			}
+			result => result?,
+		};
+		let stored_path = response.headers().get("Upload-Stored-Path").ok().flatten();
+		Ok((upload_offset(&response)?, stored_path))
+	}
+
+	async fn with_retries<T, F, Fut>(&self, mut request: F) -> Result<T, UploadError>
+	where
+		F: FnMut() -> Fut,
+		Fut: std::future::Future<Output = Result<T, UploadError>>,
+	{
+		let mut attempt = 0;
+		loop {
+			match request().await {
+				Err(error) if error.is_retryable() && attempt < self.options.max_retries => {
+					attempt += 1;
+					sleep_ms(self.options.retry_delay(attempt)).await;
+				}
+				result => return result,
+			}
+		}
+	}
+
+	fn report(&self, bytes_uploaded: u64, bytes_total: u64) {
+		if let Some(callback) = &self.on_progress {
+			callback(UploadProgress {
+				bytes_uploaded,
+				bytes_total,
+			});
+		}
+	}
+}
+
+#[cfg(wasm)]
+impl UploadOptions {
+	fn endpoint_base(&self) -> String {
+		web_sys::window()
+			.and_then(|window| window.location().href().ok())
+			.and_then(|page| web_sys::Url::new_with_base(&self.endpoint, &page).ok())
+			.map(|url| url.href())
+			.unwrap_or_else(|| self.endpoint.clone())
+	}
+}
+
+#[cfg(wasm)]
+async fn send(
+	method: &str,
+	url: &str,
+	body: Option<&web_sys::Blob>,
+	headers: Vec<(String, String)>,
+) -> Result<web_sys::Response, UploadError> {
+	use wasm_bindgen::JsCast;
+	use wasm_bindgen_futures::JsFuture;
+	use web_sys::{Request, RequestInit};
+
+	let network = |e| UploadError::Network(crate::fetch::js_error_message(e));
+	let init = RequestInit::new();
+	init.set_method(method);
+	init.set_credentials(web_sys::RequestCredentials::SameOrigin);
+	if let Some(body) = body {
+		init.set_body(body);
+	}
+	let request = Request::new_with_str_and_init(url, &init).map_err(network)?;
+	let mut headers = crate::fetch::with_csrf_header(method, headers, crate::csrf::get_csrf_token);
+	headers.push(("Tus-Resumable".to_string(), TUS_VERSION.to_string()));
+	for (name, value) in headers {
+		request.headers().set(&name, &value).map_err(network)?;
+	}
+
+	let window =
+		web_sys::window().ok_or_else(|| UploadError::Network("window is unavailable".into()))?;
+	let response: web_sys::Response = JsFuture::from(window.fetch_with_request(&request))
+		.await
+		.map_err(network)?
+		.dyn_into()
+		.map_err(network)?;
+	if response.ok() {
+		return Ok(response);
+	}
+	let message = match response.text() {
+		Ok(promise) => JsFuture::from(promise)
+			.await
+			.ok()
+			.and_then(|text| text.as_string())
+			.unwrap_or_default(),
+		Err(_) => String::new(),
+	};
+	Err(UploadError::Rejected {
+		status: response.status(),
+		message,
+	})
+}
+
+#[cfg(wasm)]
+fn upload_offset(response: &web_sys::Response) -> Result<u64, UploadError> {
+	response
+		.headers()
+		.get("Upload-Offset")
+		.ok()
+		.flatten()
+		.and_then(|offset| offset.parse().ok())
+		.ok_or_else(|| UploadError::Protocol("missing Upload-Offset header".to_string()))
+}
+
+#[cfg(wasm)]
+async fn sleep_ms(ms: u32) {
+	let promise = js_sys::Promise::new(&mut |resolve, _| {
+		if let Some(window) = web_sys::window() {
+			let _ =
+				window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms as i32);
+		}
+	});
+	let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case(0, 10, 4, Some((0, 4)))]
+	#[case(8, 10, 4, Some((8, 10)))]
+	#[case(10, 10, 4, None)]
+	#[case(0, 0, 4, None)]
+	fn test_next_chunk(
+		#[case] offset: u64,
+		#[case] total: u64,
+		#[case] chunk_size: u64,
+		#[case] expected: Option<(u64, u64)>,
+	) {
+		// Act / Assert
+		assert_eq!(next_chunk(offset, total, chunk_size), expected);
+	}
+
+	#[rstest]
+	fn test_retry_delay_doubles_up_to_cap() {
+		// Arrange
+		let options = UploadOptions::new("/uploads").with_retry_delay_ms(500);
+
+		// Act / Assert
+		assert_eq!(options.retry_delay(1), 500);
+		assert_eq!(options.retry_delay(3), 2000);
+		assert_eq!(options.retry_delay(20), 30_000);
+	}
+
+	#[rstest]
+	#[case(UploadError::Network("offline".to_string()), true)]
+	#[case(UploadError::Rejected { status: 503, message: String::new() }, true)]
+	#[case(UploadError::Rejected { status: 460, message: String::new() }, true)]
+	#[case(UploadError::Rejected { status: 413, message: String::new() }, false)]
+	#[case(UploadError::Protocol("no offset".to_string()), false)]
+	fn test_is_retryable(#[case] error: UploadError, #[case] expected: bool) {
+		// Act / Assert
+		assert_eq!(error.is_retryable(), expected);
+	}
+
+	#[rstest]
+	fn test_encode_upload_metadata_joins_pairs() {
+		// Arrange
+		let metadata = vec![
+			("filename".to_string(), "report.pdf".to_string()),
+			("sha256".to_string(), "ab".to_string()),
+		];
+
+		// Act
+		let header = encode_upload_metadata(&metadata);
+
+		// Assert
+		assert_eq!(header, "filename cmVwb3J0LnBkZg==,sha256 YWI=");
+	}
+}
@@ -0,0 +1,510 @@
+//! Server-driven partial page updates (HTML over the wire)
+//!
+//! For pages served without WASM, a small JavaScript runtime
+//! ([`WIRE_CLIENT_SCRIPT`]) turns links and forms marked with `data-rh-wire`
+//! into `fetch` requests, and the server answers with view fragments that
+//! replace parts of the current page instead of a full document:
+//!
+//! 1. The runtime sends the request with the [`WIRE_REQUEST_HEADER`] header
+//!    and, when the element's `data-rh-wire` attribute holds a CSS selector,
+//!    that selector in [`WIRE_TARGET_HEADER`].
+//! 2. The handler renders the changed parts with the same `page!` components
+//!    used for SSR and returns a [`FragmentResponse`].
+//! 3. The runtime applies each [`Fragment`] to the elements matching its
+//!    target selector, following its [`Swap`] mode.
+//!
+//! Handlers that return a regular page keep working: the runtime falls back
+//! to a full navigation, and without JavaScript the links and forms behave as
+//! plain HTML. `form!` opts in with `wire: "#selector"`.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use reinhardt_pages::wire::{FragmentResponse, is_wire_request};
+//!
+//! async fn add_comment(request: Request) -> Result<Response> {
+//!     let comment = save_comment(&request).await?;
+//!     if is_wire_request(&request) {
+//!         return Ok(FragmentResponse::new()
+//!             .append("#comments", comment_item(&comment))
+//!             .update("#comment-form", comment_form())
+//!             .into_response());
+//!     }
+//!     Ok(Response::temporary_redirect("/comments"))
+//! }
+//! ```
+
+use crate::component::IntoPage;
+use std::fmt;
+
+/// Request header set by the runtime on every wire request.
+pub const WIRE_REQUEST_HEADER: &str = "x-reinhardt-wire";
+
+/// Request header carrying the `data-rh-wire` selector of the element.
+pub const WIRE_TARGET_HEADER: &str = "x-reinhardt-wire-target";
+
+/// Response header asking the runtime to navigate to another URL.
+pub const WIRE_REDIRECT_HEADER: &str = "x-reinhardt-wire-redirect";
+
+/// Media type of a [`FragmentResponse`] body.
+pub const WIRE_CONTENT_TYPE: &str = "text/vnd.reinhardt-wire.html";
+
+/// How a fragment is applied to its target elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Swap {
+	/// Replace the children of the target.
+	#[default]
+	Inner,
+	/// Replace the target itself.
+	Outer,
+	/// Insert after the last child of the target.
+	Append,
+	/// Insert before the first child of the target.
+	Prepend,
+	/// Insert before the target.
+	Before,
+	/// Insert after the target.
+	After,
+	/// Remove the target; the fragment content is ignored.
+	Remove,
+}
+
+impl Swap {
+	/// Returns the `data-rh-swap` value understood by the runtime.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Inner => "inner",
+			Self::Outer => "outer",
+			Self::Append => "append",
+			Self::Prepend => "prepend",
+			Self::Before => "before",
+			Self::After => "after",
+			Self::Remove => "remove",
+		}
+	}
+}
+
+impl fmt::Display for Swap {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+/// A rendered view fragment and where to put it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fragment {
+	/// CSS selector of the target elements.
+	pub target: String,
+	/// How the fragment is applied.
+	pub swap: Swap,
+	/// Rendered HTML of the fragment.
+	pub html: String,
+}
+
+impl Fragment {
+	/// Creates a fragment by rendering `view`.
+	pub fn new(target: impl Into<String>, swap: Swap, view: impl IntoPage) -> Self {
+		Self {
+			target: target.into(),
+			swap,
+			html: view.into_page().render_to_string(),
+		}
+	}
+
+	/// Renders the fragment as a `<template>` element.
+	pub fn render(&self) -> String {
+		format!(
+			"<template data-rh-target=\"{}\" data-rh-swap=\"{}\">{}</template>",
+			escape_attr(&self.target),
+			self.swap,
+			self.html
+		)
+	}
+}
+
+/// A response made of view fragments, applied in order by the runtime.
+///
+/// # Example
+///
+/// ```
+/// use reinhardt_pages::component::PageElement;
+/// use reinhardt_pages::wire::FragmentResponse;
+///
+/// let response = FragmentResponse::new()
+///     .append("#todos", PageElement::new("li").child("Buy milk"))
+///     .remove("#empty-state");
+/// assert_eq!(
+///     response.render(),
+///     "<template data-rh-target=\"#todos\" data-rh-swap=\"append\"><li>Buy milk</li></template>\
+///      <template data-rh-target=\"#empty-state\" data-rh-swap=\"remove\"></template>"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FragmentResponse {
+	fragments: Vec<Fragment>,
+	redirect: Option<String>,
+}
+
+impl FragmentResponse {
+	/// Creates an empty response.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds a fragment rendered from `view`.
+	pub fn fragment(mut self, target: impl Into<String>, swap: Swap, view: impl IntoPage) -> Self {
+		self.fragments.push(Fragment::new(target, swap, view));
+		self
+	}
+
+	/// Replaces the children of `target` with `view`.
+	pub fn update(self, target: impl Into<String>, view: impl IntoPage) -> Self {
+		self.fragment(target, Swap::Inner, view)
+	}
+
+	/// Replaces `target` itself with `view`.
+	pub fn replace(self, target: impl Into<String>, view: impl IntoPage) -> Self {
+		self.fragment(target, Swap::Outer, view)
+	}
+
+	/// Appends `view` to the children of `target`.
+	pub fn append(self, target: impl Into<String>, view: impl IntoPage) -> Self {
+		self.fragment(target, Swap::Append, view)
+	}
+
+	/// Prepends `view` to the children of `target`.
+	pub fn prepend(self, target: impl Into<String>, view: impl IntoPage) -> Self {
+		self.fragment(target, Swap::Prepend, view)
+	}
+
+	/// Removes `target` from the page.
+	pub fn remove(mut self, target: impl Into<String>) -> Self {
+		self.fragments.push(Fragment {
+			target: target.into(),
+			swap: Swap::Remove,
+			html: String::new(),
+		});
+		self
+	}
+
+	/// Navigates to `url` instead of applying fragments.
+	pub fn redirect(mut self, url: impl Into<String>) -> Self {
+		self.redirect = Some(url.into());
+		self
+	}
+
+	/// Returns the fragments in application order.
+	pub fn fragments(&self) -> &[Fragment] {
+		&self.fragments
+	}
+
+	/// Returns the redirect URL, if any.
+	pub fn redirect_url(&self) -> Option<&str> {
+		self.redirect.as_deref()
+	}
+
+	/// Renders the response body.
+	pub fn render(&self) -> String {
+		self.fragments.iter().map(Fragment::render).collect()
+	}
+
+	/// Converts into an HTTP response.
+	#[cfg(native)]
+	pub fn into_response(self) -> reinhardt_http::Response {
+		let mut response = reinhardt_http::Response::ok().with_header(
+			hyper::header::CONTENT_TYPE.as_str(),
+			&format!("{}; charset=utf-8", WIRE_CONTENT_TYPE),
+		);
+		if let Some(url) = &self.redirect {
+			response = response.with_header(WIRE_REDIRECT_HEADER, url);
+		}
+		response.with_body(self.render())
+	}
+}
+
+#[cfg(native)]
+impl From<FragmentResponse> for reinhardt_http::Response {
+	fn from(response: FragmentResponse) -> Self {
+		response.into_response()
+	}
+}
+
+/// Returns true if the request was sent by the wire runtime.
+#[cfg(native)]
+pub fn is_wire_request(request: &reinhardt_http::Request) -> bool {
+	request.headers.contains_key(WIRE_REQUEST_HEADER)
+}
+
+/// Returns the `data-rh-wire` selector of a wire request.
+///
+/// Returns `None` for regular requests and for elements without a selector,
+/// which leave the choice of targets to the handler.
+#[cfg(native)]
+pub fn wire_target(request: &reinhardt_http::Request) -> Option<String> {
+	if !is_wire_request(request) {
+		return None;
+	}
+	request
+		.headers
+		.get(WIRE_TARGET_HEADER)
+		.and_then(|value| value.to_str().ok())
+		.map(str::trim)
+		.filter(|selector| !selector.is_empty())
+		.map(str::to_string)
+}
+
+/// Escapes a string for use in an HTML attribute value.
+fn escape_attr(s: &str) -> String {
+	s.replace('&', "&amp;")
+		.replace('"', "&quot;")
+		.replace('<', "&lt;")
+		.replace('>', "&gt;")
+}
+
+/// JavaScript source of the wire runtime.
+///
+/// Intercepts clicks on `a[data-rh-wire]` and submissions of
+/// `form[data-rh-wire]` that nothing else handled (e.g. a hydrated `form!`),
+/// and applies the fragments of the response. Scripts inside fragments are
+/// not executed. A `rh:wire:load` event is dispatched on `document` after
+/// each update.
+pub const WIRE_CLIENT_SCRIPT: &str = r#"
+(function() {
+  "use strict";
+
+  if (window.__reinhardtWire) {
+    return;
+  }
+  window.__reinhardtWire = true;
+
+  var CONTENT_TYPE = "text/vnd.reinhardt-wire.html";
+
+  function csrfToken() {
+    var meta = document.querySelector('meta[name="csrf-token"]');
+    return meta ? meta.getAttribute("content") : null;
+  }
+
+  function swap(target, mode, content) {
+    switch (mode) {
+      case "inner": target.replaceChildren(content); break;
+      case "outer": target.replaceWith(content); break;
+      case "append": target.append(content); break;
+      case "prepend": target.prepend(content); break;
+      case "before": target.before(content); break;
+      case "after": target.after(content); break;
+      case "remove": target.remove(); break;
+      default: console.warn("[wire] Unknown swap mode:", mode);
+    }
+  }
+
+  function applyFragments(html) {
+    var container = document.createElement("template");
+    container.innerHTML = html;
+    var fragments = container.content.children;
+    for (var i = 0; i < fragments.length; i++) {
+      var fragment = fragments[i];
+      var selector = fragment.getAttribute("data-rh-target");
+      if (fragment.tagName !== "TEMPLATE" || !selector) {
+        continue;
+      }
+      var targets;
+      try {
+        targets = document.querySelectorAll(selector);
+      } catch (e) {
+        console.warn("[wire] Invalid target selector:", selector);
+        continue;
+      }
+      var mode = fragment.getAttribute("data-rh-swap") || "inner";
+      for (var j = 0; j < targets.length; j++) {
+        swap(targets[j], mode, fragment.content.cloneNode(true));
+      }
+    }
+    document.dispatchEvent(new CustomEvent("rh:wire:load"));
+  }
+
+  function send(element, url, method, body, fallback) {
+    var headers = {
+      "X-Reinhardt-Wire": "true",
+      "Accept": CONTENT_TYPE + ", text/html;q=0.9"
+    };
+    var target = element.getAttribute("data-rh-wire");
+    if (target) {
+      headers["X-Reinhardt-Wire-Target"] = target;
+    }
+    var token = method !== "GET" && csrfToken();
+    if (token) {
+      headers["X-CSRFToken"] = token;
+    }
+
+    element.setAttribute("aria-busy", "true");
+    fetch(url, { method: method, headers: headers, body: body, credentials: "same-origin" })
+      .then(function(response) {
+        var redirect = response.headers.get("X-Reinhardt-Wire-Redirect");
+        if (redirect) {
+          window.location.assign(redirect);
+          return;
+        }
+        var type = response.headers.get("Content-Type") || "";
+        return response.text().then(function(html) {
+          if (type.indexOf(CONTENT_TYPE) === 0) {
+            applyFragments(html);
+          } else if (method === "GET" || response.redirected) {
+            // The handler answered with a full page: navigate to it.
+            window.location.assign(response.url);
+          } else {
+            document.open();
+            document.write(html);
+            document.close();
+          }
+        });
+      })
+      .catch(function(e) {
+        console.warn("[wire] Request failed, falling back to a full request:", e);
+        fallback();
+      })
+      .finally(function() {
+        element.removeAttribute("aria-busy");
+      });
+  }
+
+  document.addEventListener("click", function(event) {
+    if (event.defaultPrevented || event.button !== 0 || event.metaKey || event.ctrlKey ||
+        event.shiftKey || event.altKey || !event.target.closest) {
+      return;
+    }
+    var link = event.target.closest("a[data-rh-wire]");
+    if (!link || (link.target && link.target !== "_self") ||
+        link.origin !== window.location.origin) {
+      return;
+    }
+    event.preventDefault();
+    send(link, link.href, "GET", null, function() {
+      window.location.assign(link.href);
+    });
+  });
+
+  document.addEventListener("submit", function(event) {
+    var form = event.target;
+    if (event.defaultPrevented || !(form instanceof HTMLFormElement) ||
+        !form.hasAttribute("data-rh-wire")) {
+      return;
+    }
+    event.preventDefault();
+    var method = (form.getAttribute("method") || "GET").toUpperCase();
+    var data = new FormData(form, event.submitter || undefined);
+    var url = form.action;
+    var body = data;
+    if (method === "GET") {
+      var query = new URL(url, window.location.href);
+      query.search = new URLSearchParams(data).toString();
+      url = query.toString();
+      body = null;
+    }
+    send(form, url, method, body, function() {
+      form.submit();
+    });
+  });
+})();
+"#;
+
+/// Generates a `<script>` tag containing the wire runtime.
+///
+/// Pass the CSP nonce of the response when the page uses a nonce-based
+/// Content Security Policy.
+pub fn wire_script_tag(nonce: Option<&str>) -> String {
+	match nonce {
+		Some(nonce) => format!(
+			"<script nonce=\"{}\">{}</script>",
+			escape_attr(nonce),
+			WIRE_CLIENT_SCRIPT
+		),
+		None => format!("<script>{}</script>", WIRE_CLIENT_SCRIPT),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::component::PageElement;
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_fragment_escapes_target() {
+		// Arrange
+		let fragment = Fragment::new(
+			"[data-id=\"1\"]",
+			Swap::Outer,
+			PageElement::new("p").child("Saved"),
+		);
+
+		// Act
+		let html = fragment.render();
+
+		// Assert
+		assert_eq!(
+			html,
+			"<template data-rh-target=\"[data-id=&quot;1&quot;]\" data-rh-swap=\"outer\"><p>Saved</p></template>"
+		);
+	}
+
+	#[rstest]
+	fn test_script_tag_with_nonce() {
+		// Act
+		let tag = wire_script_tag(Some("abc"));
+
+		// Assert
+		assert!(tag.starts_with("<script nonce=\"abc\">"));
+		assert!(tag.contains("X-Reinhardt-Wire"));
+	}
+
+	#[cfg(native)]
+	#[rstest]
+	fn test_into_response_sets_headers() {
+		// Arrange
+		let response = FragmentResponse::new()
+			.update("#count", "3")
+			.redirect("/done");
+
+		// Act
+		let response = response.into_response();
+
+		// Assert
+		assert_eq!(
+			response.headers.get("content-type").unwrap(),
+			"text/vnd.reinhardt-wire.html; charset=utf-8"
+		);
+		assert_eq!(response.headers.get(WIRE_REDIRECT_HEADER).unwrap(), "/done");
+		assert_eq!(
+			String::from_utf8_lossy(&response.body),
+			"<template data-rh-target=\"#count\" data-rh-swap=\"inner\">3</template>"
+		);
+	}
+
+	#[cfg(native)]
+	#[rstest]
+	#[case::with_target(&[(WIRE_REQUEST_HEADER, "true"), (WIRE_TARGET_HEADER, "#list")], Some("#list"))]
+	#[case::without_target(&[(WIRE_REQUEST_HEADER, "true")], None)]
+	#[case::regular_request(&[(WIRE_TARGET_HEADER, "#list")], None)]
+	fn test_wire_target(#[case] headers: &[(&str, &str)], #[case] expected: Option<&str>) {
+		// Arrange
+		let mut map = hyper::HeaderMap::new();
+		for (name, value) in headers {
+			map.insert(
+				hyper::header::HeaderName::from_bytes(name.as_bytes()).unwrap(),
+				value.parse().unwrap(),
+			);
+		}
+		let request = reinhardt_http::Request::builder()
+			.method(hyper::Method::GET)
+			.uri("/")
+			.headers(map)
+			.build()
+			.unwrap();
+
+		// Act
+		let target = wire_target(&request);
+
+		// Assert
+		assert_eq!(target.as_deref(), expected);
+	}
+}
@@ -42,6 +42,8 @@ fn test_server_fn_macro_ui() {
 	t.pass("tests/ui/server_fn/no_msw_feature.rs");
 	// Issue #3858: verify FromRequest extractor params work in #[server_fn]
 	t.pass("tests/ui/server_fn/with_extractors.rs");
+	// `#[inject] ctx: ServerContext` gives server functions the request environment
+	t.pass("tests/ui/server_fn/with_server_context.rs");
 }
 
 #[test]
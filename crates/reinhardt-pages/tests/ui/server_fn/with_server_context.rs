@@ -0,0 +1,29 @@
+//! Test: Server function with an injected `ServerContext`
+//!
+//! This test verifies that `#[inject] ctx: ServerContext` compiles and that
+//! the context is excluded from the client Args struct.
+
+use reinhardt_pages::server_fn::{ServerContext, ServerFnError};
+use reinhardt_pages_macros::server_fn;
+
+#[server_fn]
+async fn whoami(#[inject] ctx: ServerContext) -> Result<String, ServerFnError> {
+	if !ctx.user().is_authenticated() {
+		return Err(ServerFnError::server(401, "Not logged in"));
+	}
+	Ok(format!(
+		"{} from {:?}",
+		ctx.user().user_id(),
+		ctx.client_ip()
+	))
+}
+
+#[server_fn]
+async fn greet(name: String, #[inject] ctx: ServerContext) -> Result<String, ServerFnError> {
+	let greeting = ctx.cookie("greeting").unwrap_or("Hello");
+	Ok(format!("{}, {}", greeting, name))
+}
+
+fn main() {
+	// This test file is used by trybuild to verify macro expansion.
+}
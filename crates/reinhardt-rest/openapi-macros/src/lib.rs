@@ -35,8 +35,9 @@ use serde_attrs::{
 /// - `#[schema(example = "...")]` - Example value for the entire type
 /// - `#[schema(deprecated)]` - Mark the entire type as deprecated
 /// - `#[schema(nullable)]` - Allow null values
+/// - `#[schema(rename = "...")]` - Override the component name (default: type name)
 ///
-/// ## Field Attributes (for structs)
+/// ## Field Attributes (for structs and struct variants)
 ///
 /// - `#[schema(description = "...")]` - Field description (also reads doc comments)
 /// - `#[schema(example = "...")]` - Example value for this field
@@ -45,8 +46,8 @@ use serde_attrs::{
 /// - `#[schema(read_only)]` - Field is read-only (GET responses only)
 /// - `#[schema(write_only)]` - Field is write-only (POST/PUT requests only)
 /// - `#[schema(format = "...")]` - OpenAPI format (e.g., "email", "uri", "date-time")
-/// - `#[schema(minimum = N)]` - Minimum value for numbers (inclusive)
-/// - `#[schema(maximum = N)]` - Maximum value for numbers (inclusive)
+/// - `#[schema(minimum = N)]` / `#[schema(min = N)]` - Minimum value for numbers (inclusive)
+/// - `#[schema(maximum = N)]` / `#[schema(max = N)]` - Maximum value for numbers (inclusive)
 /// - `#[schema(exclusive_minimum = N)]` - Minimum value for numbers (exclusive)
 /// - `#[schema(exclusive_maximum = N)]` - Maximum value for numbers (exclusive)
 /// - `#[schema(multiple_of = N)]` - Value must be a multiple of N
@@ -59,6 +60,20 @@ use serde_attrs::{
 /// - `#[schema(nullable)]` - Field allows null values
 /// - `#[schema(default_value = "...")]` - Default value (JSON string)
 /// - `#[schema(title = "...")]` - Field-level title override
+/// - `#[schema(rename = "...")]` - Property name override (takes precedence over serde)
+///
+/// # Generic Types
+///
+/// Every type parameter receives a `ToSchema` bound, and the schema name is
+/// suffixed with the names of the type arguments (e.g. `Page<User>` becomes
+/// `Page_User`). Generic types are not registered automatically because they
+/// have no concrete instantiation at compile time.
+///
+/// # Recursive Types
+///
+/// Fields referring back to the type being derived, directly or through
+/// `Box`, `Option`, `Arc`, `Rc` or `Vec`, are emitted as `$ref`s to the
+/// type's component instead of being expanded inline.
 ///
 /// # Enum Support
 ///
@@ -69,6 +84,10 @@ use serde_attrs::{
 /// - **Adjacent**: `#[serde(tag = "t", content = "c")]` -> `{"t": "VariantName", "c": {...}}`
 /// - **Untagged**: `#[serde(untagged)]` -> `{...}` (no discriminator)
 ///
+/// Variant names follow `#[serde(rename)]` and `#[serde(rename_all)]`, and
+/// struct variant fields follow `#[serde(rename_all_fields)]` on the enum or
+/// `#[serde(rename_all)]` on the variant.
+///
 /// ## Variant Types
 ///
 /// - **Unit variants**: Become string enum values
@@ -92,7 +111,8 @@ pub fn derive_schema(input: TokenStream) -> TokenStream {
 /// Generate schema for struct types
 fn derive_struct_schema(input: &DeriveInput, data: &syn::DataStruct) -> TokenStream {
 	let name = &input.ident;
-	let generics = &input.generics;
+	let openapi_crate = get_reinhardt_openapi_crate();
+	let generics = add_schema_bounds(&input.generics, &openapi_crate);
 	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
 	let fields = match &data.fields {
@@ -108,11 +128,14 @@ fn derive_struct_schema(input: &DeriveInput, data: &syn::DataStruct) -> TokenStr
 	};
 
 	// Extract container-level attributes
-	let struct_name = name.to_string();
 	let container_attrs = match extract_container_attributes(&input.attrs) {
 		Ok(attrs) => attrs,
 		Err(err) => return err.to_compile_error().into(),
 	};
+	let struct_name = container_attrs
+		.rename
+		.clone()
+		.unwrap_or_else(|| name.to_string());
 
 	// Extract container-level rename_all for field name transformation
 	// Fixes #835
@@ -152,7 +175,7 @@ fn derive_struct_schema(input: &DeriveInput, data: &syn::DataStruct) -> TokenStr
 
 		// Fixes #839: Handle flattened fields separately
 		if attrs.flatten {
-			let schema_builder = build_field_schema(field_type, &attrs);
+			let schema_builder = build_field_schema(field_type, &attrs, name);
 			flatten_schemas.push(schema_builder);
 			continue;
 		}
@@ -172,7 +195,7 @@ fn derive_struct_schema(input: &DeriveInput, data: &syn::DataStruct) -> TokenStr
 		}
 
 		// Build field schema with attributes
-		let schema_builder = build_field_schema(field_type, &attrs);
+		let schema_builder = build_field_schema(field_type, &attrs, name);
 
 		field_schemas.push(quote! {
 			builder = builder.property(#property_name, #schema_builder);
@@ -188,9 +211,6 @@ fn derive_struct_schema(input: &DeriveInput, data: &syn::DataStruct) -> TokenStr
 		quote! {}
 	};
 
-	// Get dynamic crate path
-	let openapi_crate = get_reinhardt_openapi_crate();
-
 	// Generate container attribute modifications
 	let container_mods = generate_container_modifications(&container_attrs, &openapi_crate);
 
@@ -237,7 +257,7 @@ fn derive_struct_schema(input: &DeriveInput, data: &syn::DataStruct) -> TokenStr
 
 	// Generate inventory registration only for non-generic types
 	// Generic types cannot be registered at compile time since they don't have a concrete type
-	let inventory_registration = if generics.params.is_empty() {
+	let inventory_registration = if input.generics.params.is_empty() {
 		quote! {
 			// Automatic schema registration via inventory
 			// This allows the framework to discover all schemas at compile time
@@ -252,6 +272,8 @@ fn derive_struct_schema(input: &DeriveInput, data: &syn::DataStruct) -> TokenStr
 		quote! {}
 	};
 
+	let schema_name_body = generate_schema_name(&struct_name, &generics, &openapi_crate);
+
 	let expanded = quote! {
 		impl #impl_generics #openapi_crate::ToSchema for #name #ty_generics #where_clause {
 			fn schema() -> #openapi_crate::Schema {
@@ -259,7 +281,7 @@ fn derive_struct_schema(input: &DeriveInput, data: &syn::DataStruct) -> TokenStr
 			}
 
 			fn schema_name() -> Option<String> {
-				Some(#struct_name.to_string())
+				#schema_name_body
 			}
 		}
 
@@ -272,23 +294,24 @@ fn derive_struct_schema(input: &DeriveInput, data: &syn::DataStruct) -> TokenStr
 /// Generate schema for enum types
 fn derive_enum_schema(input: &DeriveInput, data: &syn::DataEnum) -> TokenStream {
 	let name = &input.ident;
-	let generics = &input.generics;
+	let openapi_crate = get_reinhardt_openapi_crate();
+	let generics = add_schema_bounds(&input.generics, &openapi_crate);
 	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-	let enum_name = name.to_string();
 
 	// Extract container-level attributes
 	let container_attrs = match extract_container_attributes(&input.attrs) {
 		Ok(attrs) => attrs,
 		Err(err) => return err.to_compile_error().into(),
 	};
+	let enum_name = container_attrs
+		.rename
+		.clone()
+		.unwrap_or_else(|| name.to_string());
 
 	// Extract serde enum attributes for tagging strategy
 	let serde_attrs = extract_serde_enum_attrs(&input.attrs);
 	let tagging = serde_attrs.tagging_strategy();
 
-	// Get dynamic crate path
-	let openapi_crate = get_reinhardt_openapi_crate();
-
 	// Check if all variants are unit variants (simple string enum)
 	let all_unit_variants = data
 		.variants
@@ -308,7 +331,16 @@ fn derive_enum_schema(input: &DeriveInput, data: &syn::DataEnum) -> TokenStream
 		}
 	} else {
 		// Complex enum: use EnumSchemaBuilder
-		let base = generate_complex_enum_schema(data, &openapi_crate, &enum_name, &tagging);
+		let base = match generate_complex_enum_schema(
+			data,
+			&openapi_crate,
+			&enum_name,
+			&serde_attrs,
+			name,
+		) {
+			Ok(base) => base,
+			Err(err) => return err.to_compile_error().into(),
+		};
 		quote! {
 			let mut schema = { #base };
 			#container_mods
@@ -317,7 +349,7 @@ fn derive_enum_schema(input: &DeriveInput, data: &syn::DataEnum) -> TokenStream
 	};
 
 	// Generate inventory registration only for non-generic types
-	let inventory_registration = if generics.params.is_empty() {
+	let inventory_registration = if input.generics.params.is_empty() {
 		quote! {
 			::inventory::submit! {
 				#openapi_crate::SchemaRegistration::new(
@@ -330,6 +362,8 @@ fn derive_enum_schema(input: &DeriveInput, data: &syn::DataEnum) -> TokenStream
 		quote! {}
 	};
 
+	let schema_name_body = generate_schema_name(&enum_name, &generics, &openapi_crate);
+
 	let expanded = quote! {
 		impl #impl_generics #openapi_crate::ToSchema for #name #ty_generics #where_clause {
 			fn schema() -> #openapi_crate::Schema {
@@ -337,7 +371,7 @@ fn derive_enum_schema(input: &DeriveInput, data: &syn::DataEnum) -> TokenStream
 			}
 
 			fn schema_name() -> Option<String> {
-				Some(#enum_name.to_string())
+				#schema_name_body
 			}
 		}
 
@@ -347,6 +381,48 @@ fn derive_enum_schema(input: &DeriveInput, data: &syn::DataEnum) -> TokenStream
 	TokenStream::from(expanded)
 }
 
+/// Add a `ToSchema` bound for every type parameter of the derived type
+fn add_schema_bounds(
+	generics: &syn::Generics,
+	openapi_crate: &proc_macro2::TokenStream,
+) -> syn::Generics {
+	let mut generics = generics.clone();
+	let params: Vec<syn::Ident> = generics.type_params().map(|p| p.ident.clone()).collect();
+	let where_clause = generics.make_where_clause();
+	for param in params {
+		where_clause
+			.predicates
+			.push(syn::parse_quote!(#param: #openapi_crate::ToSchema));
+	}
+	generics
+}
+
+/// Generate the body of `ToSchema::schema_name`
+///
+/// Generic types append the schema name of each type argument so that every
+/// instantiation gets a distinct component name (e.g. `Page_User`).
+fn generate_schema_name(
+	base_name: &str,
+	generics: &syn::Generics,
+	openapi_crate: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+	let params: Vec<&syn::Ident> = generics.type_params().map(|p| &p.ident).collect();
+	if params.is_empty() {
+		return quote! {
+			Some(#base_name.to_string())
+		};
+	}
+
+	quote! {
+		let mut name = String::from(#base_name);
+		#(
+			name.push('_');
+			name.push_str(&#openapi_crate::type_schema_name::<#params>());
+		)*
+		Some(name)
+	}
+}
+
 /// Generate code to apply container-level attributes to a schema
 ///
 /// Handles both `Schema::Object` and `Schema::AllOf` variants, so container
@@ -508,10 +584,11 @@ fn generate_complex_enum_schema(
 	data: &syn::DataEnum,
 	openapi_crate: &proc_macro2::TokenStream,
 	enum_name: &str,
-	tagging: &TaggingStrategy,
-) -> proc_macro2::TokenStream {
+	serde_attrs: &serde_attrs::SerdeEnumAttrs,
+	self_ident: &syn::Ident,
+) -> syn::Result<proc_macro2::TokenStream> {
 	// Generate tagging strategy expression
-	let tagging_expr = match tagging {
+	let tagging_expr = match serde_attrs.tagging_strategy() {
 		TaggingStrategy::External => quote! {
 			#openapi_crate::EnumTagging::External
 		},
@@ -530,29 +607,34 @@ fn generate_complex_enum_schema(
 	};
 
 	// Generate variant schemas
-	let variant_additions: Vec<proc_macro2::TokenStream> = data
-		.variants
-		.iter()
-		.filter_map(|variant| {
-			let variant_attrs = extract_serde_variant_attrs(&variant.attrs);
-			if variant_attrs.skip {
-				return None;
-			}
+	let mut variant_additions = Vec::new();
+	for variant in &data.variants {
+		let variant_attrs = extract_serde_variant_attrs(&variant.attrs);
+		if variant_attrs.skip {
+			continue;
+		}
 
-			let variant_name = variant_attrs
-				.rename
-				.clone()
-				.unwrap_or_else(|| variant.ident.to_string());
+		let variant_name = variant_attrs.rename.clone().unwrap_or_else(|| {
+			apply_rename_all(
+				&variant.ident.to_string(),
+				serde_attrs.rename_all.as_deref(),
+			)
+		});
 
-			let variant_schema = generate_variant_schema(&variant.fields, openapi_crate);
+		// Variant-level rename_all takes precedence over the enum's rename_all_fields
+		let field_rename_all = variant_attrs
+			.rename_all
+			.as_deref()
+			.or(serde_attrs.rename_all_fields.as_deref());
+		let variant_schema =
+			generate_variant_schema(&variant.fields, openapi_crate, self_ident, field_rename_all)?;
 
-			Some(quote! {
-				builder = builder.variant(#variant_name, #variant_schema);
-			})
-		})
-		.collect();
+		variant_additions.push(quote! {
+			builder = builder.variant(#variant_name, #variant_schema);
+		});
+	}
 
-	quote! {
+	Ok(quote! {
 		use #openapi_crate::{EnumSchemaBuilder, Schema, SchemaExt};
 
 		let mut builder = EnumSchemaBuilder::new(#enum_name)
@@ -561,15 +643,17 @@ fn generate_complex_enum_schema(
 		#(#variant_additions)*
 
 		builder.build()
-	}
+	})
 }
 
 /// Generate schema for a single variant's fields
 fn generate_variant_schema(
 	fields: &Fields,
 	openapi_crate: &proc_macro2::TokenStream,
-) -> proc_macro2::TokenStream {
-	match fields {
+	self_ident: &syn::Ident,
+	rename_all: Option<&str>,
+) -> syn::Result<proc_macro2::TokenStream> {
+	let schema = match fields {
 		Fields::Unit => {
 			// Unit variant: empty object or null
 			quote! {
@@ -579,9 +663,7 @@ fn generate_variant_schema(
 		Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
 			// Newtype variant: use inner type's schema
 			let inner_type = &fields.unnamed.first().unwrap().ty;
-			quote! {
-				<#inner_type as #openapi_crate::ToSchema>::schema()
-			}
+			build_field_schema(inner_type, &FieldAttributes::default(), self_ident)
 		}
 		Fields::Unnamed(fields) => {
 			// Tuple variant: array of inner types
@@ -589,9 +671,9 @@ fn generate_variant_schema(
 				.unnamed
 				.iter()
 				.map(|f| {
-					let ty = &f.ty;
+					let schema = build_field_schema(&f.ty, &FieldAttributes::default(), self_ident);
 					quote! {
-						#openapi_crate::RefOr::T(<#ty as #openapi_crate::ToSchema>::schema())
+						#openapi_crate::RefOr::T(#schema)
 					}
 				})
 				.collect();
@@ -618,23 +700,36 @@ fn generate_variant_schema(
 				let field_name_str = field_name.to_string();
 				let field_type = &field.ty;
 
-				// Check for serde rename on field
-				let field_attrs = extract_field_attributes(&field.attrs).unwrap_or_default();
-				let property_name = field_attrs.rename.unwrap_or(field_name_str);
+				let field_attrs = extract_field_attributes(&field.attrs)?;
+				if field_attrs.read_only && field_attrs.write_only {
+					return Err(syn::Error::new_spanned(
+						field,
+						"A field cannot be both read_only and write_only",
+					));
+				}
+				if field_attrs.skip
+					|| field_attrs.skip_serializing
+					|| field_attrs.skip_deserializing
+				{
+					continue;
+				}
+
+				let property_name = field_attrs
+					.rename
+					.clone()
+					.unwrap_or_else(|| apply_rename_all(&field_name_str, rename_all));
 
 				// Check if required
 				let is_option = is_option_type(field_type);
-				if !is_option {
+				if !is_option && !field_attrs.default {
 					required_additions.push(quote! {
 						builder = builder.required(#property_name);
 					});
 				}
 
+				let schema_builder = build_field_schema(field_type, &field_attrs, self_ident);
 				property_additions.push(quote! {
-					builder = builder.property(
-						#property_name,
-						<#field_type as #openapi_crate::ToSchema>::schema()
-					);
+					builder = builder.property(#property_name, #schema_builder);
 				});
 			}
 
@@ -649,7 +744,8 @@ fn generate_variant_schema(
 				}
 			}
 		}
-	}
+	};
+	Ok(schema)
 }
 
 /// Apply rename_all transformation to a variant name
@@ -723,11 +819,72 @@ fn is_option_type(ty: &syn::Type) -> bool {
 	false
 }
 
+/// Build a `$ref` to the derived type for fields that refer back to it
+///
+/// Expanding such fields with `ToSchema::schema()` would recurse forever, so
+/// they reference the type's component instead. `Box`, `Option`, `Arc` and
+/// `Rc` are looked through, and `Vec` becomes an array of references. The
+/// returned expression evaluates to a `RefOr<Schema>`.
+fn self_reference_schema(
+	ty: &syn::Type,
+	self_ident: &syn::Ident,
+	openapi_crate: &proc_macro2::TokenStream,
+) -> Option<proc_macro2::TokenStream> {
+	let syn::Type::Path(type_path) = ty else {
+		return None;
+	};
+	let segment = type_path.path.segments.last()?;
+	if type_path.qself.is_none() && (segment.ident == *self_ident || segment.ident == "Self") {
+		return Some(quote! {
+			#openapi_crate::RefOr::Ref(#openapi_crate::utoipa::openapi::Ref::from_schema_name(
+				<Self as #openapi_crate::ToSchema>::schema_name().unwrap_or_default(),
+			))
+		});
+	}
+
+	let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+		return None;
+	};
+	let Some(syn::GenericArgument::Type(inner)) = args.args.first() else {
+		return None;
+	};
+	match segment.ident.to_string().as_str() {
+		"Box" | "Option" | "Arc" | "Rc" => self_reference_schema(inner, self_ident, openapi_crate),
+		"Vec" => {
+			let item = self_reference_schema(inner, self_ident, openapi_crate)?;
+			Some(quote! {
+				#openapi_crate::RefOr::T(#openapi_crate::Schema::Array(
+					#openapi_crate::utoipa::openapi::schema::Array::new(#item),
+				))
+			})
+		}
+		_ => None,
+	}
+}
+
 /// Build schema for a field type with attributes
-fn build_field_schema(field_type: &syn::Type, attrs: &FieldAttributes) -> proc_macro2::TokenStream {
+fn build_field_schema(
+	field_type: &syn::Type,
+	attrs: &FieldAttributes,
+	self_ident: &syn::Ident,
+) -> proc_macro2::TokenStream {
 	let openapi_crate = get_reinhardt_openapi_crate();
-	let base_schema = quote! {
-		<#field_type as #openapi_crate::ToSchema>::schema()
+	let base_schema = match self_reference_schema(field_type, self_ident, &openapi_crate) {
+		// A bare `$ref` is wrapped in a single-item allOf so that field-level
+		// attributes such as `description` can sit next to it.
+		Some(reference) => quote! {
+			match #reference {
+				#openapi_crate::RefOr::T(schema) => schema,
+				reference => #openapi_crate::Schema::AllOf(
+					#openapi_crate::utoipa::openapi::schema::AllOfBuilder::new()
+						.item(reference)
+						.build(),
+				),
+			}
+		},
+		None => quote! {
+			<#field_type as #openapi_crate::ToSchema>::schema()
+		},
 	};
 
 	// If no attributes, return base schema
@@ -748,8 +905,14 @@ fn build_field_schema(field_type: &syn::Type, attrs: &FieldAttributes) -> proc_m
 
 	if let Some(ref description) = attrs.description {
 		modifications.push(quote! {
-			if let Schema::Object(ref mut obj) = schema {
-				obj.description = Some(#description.to_string());
+			match schema {
+				Schema::Object(ref mut obj) => {
+					obj.description = Some(#description.to_string());
+				}
+				Schema::AllOf(ref mut all_of) => {
+					all_of.description = Some(#description.to_string());
+				}
+				_ => {}
 			}
 		});
 	}
@@ -16,6 +16,8 @@ pub(crate) struct ContainerAttributes {
 	pub deprecated: bool,
 	/// Allow null values (adds Type::Null to schema_type)
 	pub nullable: bool,
+	/// Override the component name used for registration and `$ref`s
+	pub rename: Option<String>,
 }
 
 /// Field-level schema attributes
@@ -179,6 +181,15 @@ pub(crate) fn extract_container_attributes(
 									container_attrs.example = Some(lit_str.value());
 								}
 							}
+							"rename" => {
+								if let syn::Expr::Lit(syn::ExprLit {
+									lit: Lit::Str(lit_str),
+									..
+								}) = nv.value
+								{
+									container_attrs.rename = Some(lit_str.value());
+								}
+							}
 							_ => {}
 						}
 					}
@@ -331,7 +342,7 @@ pub(crate) fn extract_field_attributes(attrs: &[Attribute]) -> Result<FieldAttri
 									field_attrs.format = Some(lit_str.value());
 								}
 							}
-							"minimum" => {
+							"minimum" | "min" => {
 								if let syn::Expr::Lit(syn::ExprLit {
 									lit: Lit::Int(lit_int),
 									..
@@ -340,7 +351,7 @@ pub(crate) fn extract_field_attributes(attrs: &[Attribute]) -> Result<FieldAttri
 									field_attrs.minimum = Some(lit_int.base10_parse()?);
 								}
 							}
-							"maximum" => {
+							"maximum" | "max" => {
 								if let syn::Expr::Lit(syn::ExprLit {
 									lit: Lit::Int(lit_int),
 									..
@@ -18,6 +18,8 @@ pub(crate) struct SerdeEnumAttrs {
 	pub rename: Option<String>,
 	/// Rename all strategy (from `#[serde(rename_all = "...")]`)
 	pub rename_all: Option<String>,
+	/// Field rename strategy for struct variants (from `#[serde(rename_all_fields = "...")]`)
+	pub rename_all_fields: Option<String>,
 }
 
 impl SerdeEnumAttrs {
@@ -66,6 +68,7 @@ pub(crate) enum TaggingStrategy {
 /// - `untagged` - Untagged representation
 /// - `rename = "..."` - Container rename
 /// - `rename_all = "..."` - Variant name transformation
+/// - `rename_all_fields = "..."` - Struct variant field name transformation
 pub(crate) fn extract_serde_enum_attrs(attrs: &[Attribute]) -> SerdeEnumAttrs {
 	let mut result = SerdeEnumAttrs::default();
 
@@ -109,6 +112,8 @@ pub(crate) fn extract_serde_enum_attrs(attrs: &[Attribute]) -> SerdeEnumAttrs {
 						result.rename = Some(value);
 					} else if path.is_ident("rename_all") {
 						result.rename_all = Some(value);
+					} else if path.is_ident("rename_all_fields") {
+						result.rename_all_fields = Some(value);
 					}
 				}
 				_ => {}
@@ -167,6 +172,8 @@ pub(crate) struct SerdeVariantAttrs {
 	pub skip: bool,
 	/// Alias names from `#[serde(alias = "...")]`
 	pub aliases: Vec<String>,
+	/// Field rename strategy for a struct variant (from `#[serde(rename_all = "...")]`)
+	pub rename_all: Option<String>,
 }
 
 /// Extract serde variant attributes from a list of attributes
@@ -209,6 +216,8 @@ pub(crate) fn extract_serde_variant_attrs(attrs: &[Attribute]) -> SerdeVariantAt
 						result.rename = Some(value);
 					} else if path.is_ident("alias") {
 						result.aliases.push(value);
+					} else if path.is_ident("rename_all") {
+						result.rename_all = Some(value);
 					}
 				}
 				_ => {}
@@ -267,4 +276,20 @@ mod tests {
 			}
 		);
 	}
+
+	#[test]
+	fn test_rename_all_fields_and_variant_rename_all() {
+		let enum_attrs: Vec<Attribute> = vec![syn::parse_quote!(
+			#[serde(tag = "kind", rename_all_fields = "camelCase")]
+		)];
+		let variant_attrs: Vec<Attribute> =
+			vec![syn::parse_quote!(#[serde(rename_all = "kebab-case")])];
+
+		let enum_attrs = extract_serde_enum_attrs(&enum_attrs);
+		let variant_attrs = extract_serde_variant_attrs(&variant_attrs);
+
+		assert_eq!(enum_attrs.rename_all_fields.as_deref(), Some("camelCase"));
+		assert_eq!(enum_attrs.rename_all, None);
+		assert_eq!(variant_attrs.rename_all.as_deref(), Some("kebab-case"));
+	}
 }
@@ -0,0 +1,775 @@
+//! REST endpoints and serializer field for model attachments
+//!
+//! Files attached to model instances through
+//! [`reinhardt_db::contenttypes::attachments`] are managed by an
+//! [`AttachmentManager`], which keeps the attachment records and the stored
+//! files in step. On top of it:
+//!
+//! - [`AttachmentsHandler`] exposes upload, listing and detaching over HTTP
+//! - [`AttachmentsField`] represents the attachments of an instance in a
+//!   serializer and applies `attach`/`detach` changes from request data
+//!
+//! # Endpoints
+//!
+//! Mounted at `base_path` (e.g. `/api/attachments`):
+//!
+//! | Method | Path | Action |
+//! |--------|------|--------|
+//! | `GET` | `{base_path}/{app_label}/{model}/{object_id}` | List the attachments of an object |
+//! | `POST` | `{base_path}/{app_label}/{model}/{object_id}` | Upload the request body as a new attachment |
+//! | `DELETE` | `{base_path}/{id}` | Detach an attachment and delete its file |
+//!
+//! Uploads send the raw file as the body, with its name in a
+//! `Content-Disposition: attachment; filename="..."` header and its type in
+//! `Content-Type`. Uploading and detaching require an authenticated user.
+
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use hyper::StatusCode;
+use reinhardt_core::parsers::file::FileUploadParser;
+use reinhardt_db::contenttypes::attachments::{Attachment, AttachmentStore, content_type_id};
+use reinhardt_db::contenttypes::content_type_for;
+use reinhardt_db::orm::Model;
+use reinhardt_http::{AuthState, BodyError, Handler, Request, Response};
+use reinhardt_utils::is_safe_filename_component;
+use reinhardt_utils::storage::Storage;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Errors of attachment operations
+#[derive(Debug, thiserror::Error)]
+pub enum AttachmentError {
+	/// No attachment or content type matches the request
+	#[error("not found: {0}")]
+	NotFound(String),
+	/// The upload exceeds the configured maximum size
+	#[error("file of {size} bytes exceeds the maximum of {max} bytes")]
+	TooLarge {
+		/// Size of the upload in bytes
+		size: usize,
+		/// Maximum accepted size in bytes
+		max: usize,
+	},
+	/// The request or serializer input is malformed
+	#[error("invalid request: {0}")]
+	InvalidRequest(String),
+	/// The request requires an authenticated user
+	#[error("authentication required")]
+	Unauthorized,
+	/// The storage backend failed
+	#[error("storage error: {0}")]
+	Storage(String),
+	/// The attachment store failed
+	#[error("attachment store error: {0}")]
+	Store(String),
+}
+
+/// Result type of attachment operations
+pub type AttachmentResult<T> = std::result::Result<T, AttachmentError>;
+
+/// The object attachments belong to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttachmentTarget {
+	/// Id of the object's content type
+	pub content_type_id: i64,
+	/// Primary key of the object, as text
+	pub object_id: String,
+}
+
+impl AttachmentTarget {
+	/// Target the object `object_id` of content type `content_type_id`
+	pub fn new(content_type_id: i64, object_id: impl Into<String>) -> Self {
+		Self {
+			content_type_id,
+			object_id: object_id.into(),
+		}
+	}
+
+	/// Target a saved `instance`, `None` when it has no primary key yet
+	pub fn of<M: Model>(instance: &M) -> Option<Self> {
+		let object_id = instance.primary_key()?.to_string();
+		Some(Self::new(content_type_for::<M>().id?, object_id))
+	}
+
+	/// Target the object `object_id` of the registered model `app_label.model`
+	pub fn lookup(app_label: &str, model: &str, object_id: &str) -> AttachmentResult<Self> {
+		let content_type_id = content_type_id(app_label, model).ok_or_else(|| {
+			AttachmentError::NotFound(format!("content type {}.{}", app_label, model))
+		})?;
+		Ok(Self::new(content_type_id, object_id))
+	}
+}
+
+/// Keeps attachment records and their stored files in step
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_db::contenttypes::attachments::InMemoryAttachmentStore;
+/// use reinhardt_rest::attachments::{AttachmentManager, AttachmentTarget};
+/// use reinhardt_utils::storage::InMemoryStorage;
+/// use std::sync::Arc;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let manager = AttachmentManager::new(
+///     Arc::new(InMemoryAttachmentStore::new()),
+///     Arc::new(InMemoryStorage::new("media", "/media/")),
+/// );
+/// let target = AttachmentTarget::new(3, "42");
+/// let attachment = manager
+///     .attach(&target, "notes.txt", b"hello", Some("text/plain"), Some("7"))
+///     .await?;
+///
+/// assert_eq!(manager.list(&target).await?.len(), 1);
+/// manager.detach(attachment.id).await?;
+/// assert!(manager.list(&target).await?.is_empty());
+/// # Ok(())
+/// # }
+/// ```
+pub struct AttachmentManager {
+	store: Arc<dyn AttachmentStore>,
+	storage: Arc<dyn Storage>,
+	directory: String,
+	max_size: usize,
+}
+
+impl AttachmentManager {
+	/// Create a manager recording attachments in `store` and files in `storage`
+	pub fn new(store: Arc<dyn AttachmentStore>, storage: Arc<dyn Storage>) -> Self {
+		Self {
+			store,
+			storage,
+			directory: "attachments".to_string(),
+			max_size: 10 * 1024 * 1024,
+		}
+	}
+
+	/// Set the storage directory of attached files, `attachments` by default
+	pub fn with_directory(mut self, directory: impl Into<String>) -> Self {
+		self.directory = directory.into();
+		self
+	}
+
+	/// Set the largest file accepted, 10 MiB by default
+	pub fn with_max_size(mut self, max_size: usize) -> Self {
+		self.max_size = max_size;
+		self
+	}
+
+	/// Largest file accepted, in bytes
+	pub fn max_size(&self) -> usize {
+		self.max_size
+	}
+
+	/// Store `content` and attach it to `target`
+	pub async fn attach(
+		&self,
+		target: &AttachmentTarget,
+		filename: &str,
+		content: &[u8],
+		mime_type: Option<&str>,
+		uploaded_by: Option<&str>,
+	) -> AttachmentResult<Attachment> {
+		if content.len() > self.max_size {
+			return Err(AttachmentError::TooLarge {
+				size: content.len(),
+				max: self.max_size,
+			});
+		}
+
+		let mut attachment = Attachment::new(
+			target.content_type_id,
+			target.object_id.clone(),
+			String::new(),
+			filename,
+		)
+		.size(content.len() as i64);
+		if let Some(mime_type) = mime_type {
+			attachment = attachment.mime_type(mime_type);
+		}
+		if let Some(uploaded_by) = uploaded_by {
+			attachment = attachment.uploaded_by(uploaded_by);
+		}
+
+		// One directory per attachment keeps same-named uploads apart
+		let path = format!(
+			"{}/{}/{}/{}",
+			self.directory.trim_matches('/'),
+			target.content_type_id,
+			attachment.id,
+			safe_filename(filename)
+		);
+		let stored = self
+			.storage
+			.save(path.trim_start_matches('/'), content)
+			.await
+			.map_err(|e| AttachmentError::Storage(e.to_string()))?;
+		attachment.file = stored.path;
+
+		if let Err(error) = self.store.create(&attachment).await {
+			self.delete_file(&attachment).await;
+			return Err(AttachmentError::Store(error.to_string()));
+		}
+		Ok(attachment)
+	}
+
+	/// Attachments of `target`, oldest first
+	pub async fn list(&self, target: &AttachmentTarget) -> AttachmentResult<Vec<Attachment>> {
+		self.store
+			.for_object(target.content_type_id, &target.object_id)
+			.await
+			.map_err(|e| AttachmentError::Store(e.to_string()))
+	}
+
+	/// Detach the attachment `id` and delete its file
+	pub async fn detach(&self, id: Uuid) -> AttachmentResult<Attachment> {
+		let attachment = self
+			.store
+			.get(id)
+			.await
+			.map_err(|e| AttachmentError::Store(e.to_string()))?
+			.ok_or_else(|| AttachmentError::NotFound(format!("attachment {}", id)))?;
+		self.store
+			.delete(id)
+			.await
+			.map_err(|e| AttachmentError::Store(e.to_string()))?;
+		self.delete_file(&attachment).await;
+		Ok(attachment)
+	}
+
+	/// JSON representation of `attachment`, with the URL of its file
+	pub fn represent(&self, attachment: &Attachment) -> Value {
+		json!({
+			"id": attachment.id,
+			"name": attachment.original_name,
+			"url": self.storage.url(&attachment.file),
+			"mime_type": attachment.mime_type,
+			"size": attachment.size,
+			"metadata": attachment.metadata,
+			"uploaded_by": attachment.uploaded_by,
+			"uploaded_at": attachment.uploaded_at,
+		})
+	}
+
+	async fn delete_file(&self, attachment: &Attachment) {
+		// The record is gone, so a leftover file is only wasted space
+		if let Err(error) = self.storage.delete(&attachment.file).await {
+			tracing::warn!(attachment = %attachment.id, file = %attachment.file, %error, "Failed to delete attached file");
+		}
+	}
+}
+
+/// HTTP endpoint for uploading, listing and detaching attachments
+///
+/// See the [module documentation](self) for the routes.
+pub struct AttachmentsHandler {
+	manager: Arc<AttachmentManager>,
+	base_path: String,
+}
+
+impl AttachmentsHandler {
+	/// Create an endpoint at `base_path` backed by `manager`
+	pub fn new(manager: Arc<AttachmentManager>, base_path: impl Into<String>) -> Self {
+		Self {
+			manager,
+			base_path: base_path.into().trim_end_matches('/').to_string(),
+		}
+	}
+
+	async fn dispatch(&self, request: &Request) -> AttachmentResult<Response> {
+		let path = request.uri.path().trim_end_matches('/');
+		let segments: Vec<&str> = path
+			.strip_prefix(self.base_path.as_str())
+			.and_then(|rest| rest.strip_prefix('/'))
+			.ok_or_else(|| AttachmentError::NotFound(path.to_string()))?
+			.split('/')
+			.collect();
+
+		match (request.method.as_str(), segments.as_slice()) {
+			("GET", [app_label, model, object_id]) => {
+				let target = AttachmentTarget::lookup(app_label, model, object_id)?;
+				let attachments = self.manager.list(&target).await?;
+				let body: Vec<Value> = attachments
+					.iter()
+					.map(|a| self.manager.represent(a))
+					.collect();
+				json_response(StatusCode::OK, &Value::Array(body))
+			}
+			("POST", [app_label, model, object_id]) => {
+				let uploaded_by = uploader(request)?;
+				let target = AttachmentTarget::lookup(app_label, model, object_id)?;
+				self.upload(request, &target, &uploaded_by).await
+			}
+			("DELETE", [id]) => {
+				uploader(request)?;
+				let id = Uuid::parse_str(id)
+					.map_err(|_| AttachmentError::NotFound(format!("attachment {}", id)))?;
+				self.manager.detach(id).await?;
+				Ok(Response::no_content())
+			}
+			(method, _) => Err(AttachmentError::InvalidRequest(format!(
+				"{} is not allowed here",
+				method
+			))),
+		}
+	}
+
+	async fn upload(
+		&self,
+		request: &Request,
+		target: &AttachmentTarget,
+		uploaded_by: &str,
+	) -> AttachmentResult<Response> {
+		let filename = FileUploadParser::new("file")
+			.get_filename(header(request, "Content-Disposition").as_deref())
+			.map_err(|e| AttachmentError::InvalidRequest(e.to_string()))?;
+		let content = request
+			.bytes_limited(self.manager.max_size())
+			.await
+			.map_err(|error| match error {
+				BodyError::TooLarge { limit } => AttachmentError::TooLarge {
+					size: limit + 1,
+					max: limit,
+				},
+				error => AttachmentError::InvalidRequest(error.to_string()),
+			})?;
+		let mime_type = header(request, "Content-Type");
+		let attachment = self
+			.manager
+			.attach(
+				target,
+				&filename,
+				&content,
+				mime_type.as_deref(),
+				Some(uploaded_by),
+			)
+			.await?;
+		json_response(StatusCode::CREATED, &self.manager.represent(&attachment))
+	}
+}
+
+#[async_trait]
+impl Handler for AttachmentsHandler {
+	async fn handle(&self, request: Request) -> reinhardt_http::Result<Response> {
+		match self.dispatch(&request).await {
+			Ok(response) => Ok(response),
+			Err(error) => {
+				let status = match &error {
+					AttachmentError::NotFound(_) => StatusCode::NOT_FOUND,
+					AttachmentError::TooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+					AttachmentError::InvalidRequest(_) => StatusCode::BAD_REQUEST,
+					AttachmentError::Unauthorized => StatusCode::UNAUTHORIZED,
+					AttachmentError::Storage(_) | AttachmentError::Store(_) => {
+						tracing::error!(%error, "Attachment request failed");
+						StatusCode::INTERNAL_SERVER_ERROR
+					}
+				};
+				Ok(
+					json_response(status, &json!({ "detail": error.to_string() }))
+						.unwrap_or_else(|_| Response::new(status)),
+				)
+			}
+		}
+	}
+}
+
+/// Serializer field for the attachments of an instance
+///
+/// The field reads as a list of attachment representations (see
+/// [`AttachmentManager::represent`]) and accepts changes shaped as:
+///
+/// ```json
+/// {
+///   "attach": [{"name": "notes.txt", "content": "<base64>", "mime_type": "text/plain"}],
+///   "detach": ["<attachment id>"]
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_db::contenttypes::attachments::InMemoryAttachmentStore;
+/// use reinhardt_rest::attachments::{AttachmentManager, AttachmentTarget, AttachmentsField};
+/// use reinhardt_utils::storage::InMemoryStorage;
+/// use serde_json::json;
+/// use std::sync::Arc;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let field = AttachmentsField::new(Arc::new(AttachmentManager::new(
+///     Arc::new(InMemoryAttachmentStore::new()),
+///     Arc::new(InMemoryStorage::new("media", "/media/")),
+/// )));
+/// let target = AttachmentTarget::new(3, "42");
+///
+/// let changes = json!({"attach": [{"name": "notes.txt", "content": "aGVsbG8="}]});
+/// let attachments = field.apply(&target, &changes, Some("7")).await?;
+///
+/// assert_eq!(attachments[0]["name"], "notes.txt");
+/// assert_eq!(attachments[0]["size"], 5);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AttachmentsField {
+	manager: Arc<AttachmentManager>,
+	read_only: bool,
+}
+
+#[derive(Deserialize)]
+struct AttachmentChanges {
+	#[serde(default)]
+	attach: Vec<NewAttachment>,
+	#[serde(default)]
+	detach: Vec<Uuid>,
+}
+
+#[derive(Deserialize)]
+struct NewAttachment {
+	name: String,
+	content: String,
+	mime_type: Option<String>,
+}
+
+impl AttachmentsField {
+	/// Create a field backed by `manager`
+	pub fn new(manager: Arc<AttachmentManager>) -> Self {
+		Self {
+			manager,
+			read_only: false,
+		}
+	}
+
+	/// Reject changes, only representing the attachments
+	pub fn read_only(mut self) -> Self {
+		self.read_only = true;
+		self
+	}
+
+	/// Whether the field rejects changes
+	pub fn is_read_only(&self) -> bool {
+		self.read_only
+	}
+
+	/// Representation of the attachments of `target`
+	pub async fn to_representation(&self, target: &AttachmentTarget) -> AttachmentResult<Value> {
+		let attachments = self.manager.list(target).await?;
+		Ok(Value::Array(
+			attachments
+				.iter()
+				.map(|a| self.manager.represent(a))
+				.collect(),
+		))
+	}
+
+	/// Apply `changes` to the attachments of `target`, returning their new
+	/// representation
+	///
+	/// Every change is validated before any is applied. Only attachments of
+	/// `target` can be detached.
+	pub async fn apply(
+		&self,
+		target: &AttachmentTarget,
+		changes: &Value,
+		uploaded_by: Option<&str>,
+	) -> AttachmentResult<Value> {
+		if self.read_only {
+			return Err(AttachmentError::InvalidRequest(
+				"attachments are read-only".to_string(),
+			));
+		}
+		let changes = AttachmentChanges::deserialize(changes)
+			.map_err(|e| AttachmentError::InvalidRequest(e.to_string()))?;
+
+		let files = changes
+			.attach
+			.iter()
+			.map(|file| {
+				STANDARD
+					.decode(&file.content)
+					.map_err(|e| {
+						AttachmentError::InvalidRequest(format!(
+							"invalid content of {}: {}",
+							file.name, e
+						))
+					})
+					.map(|content| (file, content))
+			})
+			.collect::<AttachmentResult<Vec<_>>>()?;
+		let existing = self.manager.list(target).await?;
+		if let Some(id) = changes
+			.detach
+			.iter()
+			.find(|id| !existing.iter().any(|a| a.id == **id))
+		{
+			return Err(AttachmentError::NotFound(format!("attachment {}", id)));
+		}
+
+		for id in changes.detach {
+			self.manager.detach(id).await?;
+		}
+		for (file, content) in files {
+			self.manager
+				.attach(
+					target,
+					&file.name,
+					&content,
+					file.mime_type.as_deref(),
+					uploaded_by,
+				)
+				.await?;
+		}
+		self.to_representation(target).await
+	}
+}
+
+/// Id of the authenticated user making `request`
+fn uploader(request: &Request) -> AttachmentResult<String> {
+	AuthState::from_extensions(&request.extensions)
+		.filter(|state| state.is_authenticated())
+		.map(|state| state.user_id().to_string())
+		.ok_or(AttachmentError::Unauthorized)
+}
+
+/// File name safe to use as a storage path component
+fn safe_filename(filename: &str) -> String {
+	let name: String = filename
+		.rsplit(['/', '\\'])
+		.next()
+		.unwrap_or_default()
+		.chars()
+		.map(|c| {
+			if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' {
+				c
+			} else {
+				'_'
+			}
+		})
+		.collect();
+	if is_safe_filename_component(&name) && !name.starts_with('.') {
+		name
+	} else {
+		"attachment".to_string()
+	}
+}
+
+fn header(request: &Request, name: &str) -> Option<String> {
+	request
+		.headers
+		.get(name)
+		.and_then(|value| value.to_str().ok())
+		.map(|value| value.trim().to_string())
+}
+
+fn json_response(status: StatusCode, body: &Value) -> AttachmentResult<Response> {
+	Response::new(status)
+		.with_json(body)
+		.map_err(|e| AttachmentError::InvalidRequest(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bytes::Bytes;
+	use hyper::Method;
+	use hyper::header::HeaderMap;
+	use reinhardt_db::contenttypes::attachments::InMemoryAttachmentStore;
+	use reinhardt_db::contenttypes::{CONTENT_TYPE_REGISTRY, ContentType};
+	use reinhardt_utils::storage::InMemoryStorage;
+	use rstest::rstest;
+
+	fn manager(storage: Arc<InMemoryStorage>) -> Arc<AttachmentManager> {
+		Arc::new(
+			AttachmentManager::new(Arc::new(InMemoryAttachmentStore::new()), storage)
+				.with_max_size(16),
+		)
+	}
+
+	fn request(method: Method, uri: &str, headers: &[(&str, &str)], body: &[u8]) -> Request {
+		let mut map = HeaderMap::new();
+		for (name, value) in headers {
+			map.insert(
+				hyper::header::HeaderName::from_bytes(name.as_bytes()).unwrap(),
+				value.parse().unwrap(),
+			);
+		}
+		Request::builder()
+			.method(method)
+			.uri(uri)
+			.headers(map)
+			.body(Bytes::copy_from_slice(body))
+			.build()
+			.unwrap()
+	}
+
+	fn authenticated(request: Request) -> Request {
+		request
+			.extensions
+			.insert(AuthState::authenticated("7", false, true));
+		request
+	}
+
+	fn body(response: &Response) -> Value {
+		serde_json::from_slice(&response.body).unwrap()
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn upload_list_and_detach_round_trip() {
+		// Arrange
+		CONTENT_TYPE_REGISTRY.register(ContentType::new("attachments_api", "document"));
+		let storage = Arc::new(InMemoryStorage::new("media", "/media/"));
+		let handler = AttachmentsHandler::new(manager(storage.clone()), "/api/attachments/");
+		let upload = authenticated(request(
+			Method::POST,
+			"/api/attachments/attachments_api/Document/42",
+			&[
+				(
+					"Content-Disposition",
+					"attachment; filename=\"../q1 report.pdf\"",
+				),
+				("Content-Type", "application/pdf"),
+			],
+			b"%PDF-1.7",
+		));
+
+		// Act
+		let created = handler.handle(upload).await.unwrap();
+		let created_body = body(&created);
+		let listed = handler
+			.handle(request(
+				Method::GET,
+				"/api/attachments/attachments_api/document/42",
+				&[],
+				b"",
+			))
+			.await
+			.unwrap();
+		let id = created_body["id"].as_str().unwrap();
+		let detached = handler
+			.handle(authenticated(request(
+				Method::DELETE,
+				&format!("/api/attachments/{}", id),
+				&[],
+				b"",
+			)))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(created.status, StatusCode::CREATED);
+		assert_eq!(created_body["name"], "../q1 report.pdf");
+		assert_eq!(created_body["mime_type"], "application/pdf");
+		assert_eq!(created_body["uploaded_by"], "7");
+		let url = created_body["url"].as_str().unwrap();
+		assert!(url.starts_with("/media/attachments/"));
+		assert!(url.ends_with("/q1_report.pdf"));
+		assert_eq!(body(&listed).as_array().unwrap().len(), 1);
+		assert_eq!(detached.status, StatusCode::NO_CONTENT);
+		let path = url.trim_start_matches("/media/");
+		assert!(!storage.exists(path).await.unwrap());
+	}
+
+	#[rstest]
+	#[case::anonymous_upload(
+		Method::POST,
+		"/api/attachments/attachments_api/document/1",
+		StatusCode::UNAUTHORIZED
+	)]
+	#[case::unknown_model(
+		Method::GET,
+		"/api/attachments/attachments_api/missing/1",
+		StatusCode::NOT_FOUND
+	)]
+	#[case::unknown_route(Method::PUT, "/api/attachments/1", StatusCode::BAD_REQUEST)]
+	#[tokio::test]
+	async fn rejected_requests(
+		#[case] method: Method,
+		#[case] uri: &str,
+		#[case] expected: StatusCode,
+	) {
+		// Arrange
+		CONTENT_TYPE_REGISTRY.register(ContentType::new("attachments_api", "document"));
+		let storage = Arc::new(InMemoryStorage::new("media", "/media/"));
+		let handler = AttachmentsHandler::new(manager(storage), "/api/attachments");
+
+		// Act
+		let response = handler
+			.handle(request(method, uri, &[], b""))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status, expected);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn upload_over_max_size_is_rejected() {
+		// Arrange
+		let storage = Arc::new(InMemoryStorage::new("media", "/media/"));
+		let manager = manager(storage);
+		let target = AttachmentTarget::new(1, "42");
+
+		// Act
+		let result = manager
+			.attach(&target, "big.bin", &[0; 17], None, None)
+			.await;
+
+		// Assert
+		assert!(matches!(
+			result,
+			Err(AttachmentError::TooLarge { size: 17, max: 16 })
+		));
+		assert!(manager.list(&target).await.unwrap().is_empty());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn field_only_detaches_attachments_of_target() {
+		// Arrange
+		let storage = Arc::new(InMemoryStorage::new("media", "/media/"));
+		let manager = manager(storage);
+		let field = AttachmentsField::new(manager.clone());
+		let target = AttachmentTarget::new(1, "42");
+		let other = manager
+			.attach(
+				&AttachmentTarget::new(1, "7"),
+				"other.txt",
+				b"x",
+				None,
+				None,
+			)
+			.await
+			.unwrap();
+		let own = manager
+			.attach(&target, "own.txt", b"x", None, None)
+			.await
+			.unwrap();
+
+		// Act
+		let foreign = field
+			.apply(&target, &json!({"detach": [other.id]}), None)
+			.await;
+		let detached = field
+			.apply(&target, &json!({"detach": [own.id]}), None)
+			.await
+			.unwrap();
+
+		// Assert
+		assert!(matches!(foreign, Err(AttachmentError::NotFound(_))));
+		assert_eq!(detached, json!([]));
+		assert_eq!(
+			manager
+				.list(&AttachmentTarget::new(1, "7"))
+				.await
+				.unwrap()
+				.len(),
+			1
+		);
+	}
+}
@@ -28,6 +28,7 @@
 //!
 //! Key modules in this crate:
 //!
+//! - `attachments`: Upload endpoints and serializer field for model attachments (requires `attachments` feature)
 //! - [`browsable_api`]: HTML interface for interactive API exploration
 //! - [`filters`]: Query parameter filtering for list endpoints
 //! - [`metadata`]: API metadata and schema introspection utilities
@@ -35,6 +36,7 @@
 //! - [`throttling`]: Request rate limiting and throttle policies
 //! - [`versioning`]: API versioning strategies (URL path, header, query)
 //! - [`authentication`]: REST authentication backends (JWT, Token, Session, Basic)
+//! - [`renderers`]: JSON response rendering with camelCase, pretty-print and datetime options
 //! - [`response`]: Typed API response wrappers and pagination support
 //! - `schema`: OpenAPI schema generation (requires `openapi` feature)
 //!
@@ -52,6 +54,7 @@
 //! | `pagination` | disabled | Cursor and page-number pagination |
 //! | `browsable-api` | disabled | HTML browsable API interface |
 //! | `openapi` | disabled | OpenAPI/Swagger schema generation and UI |
+//! | `attachments` | disabled | File attachments for any model (REST endpoints and serializer field) |
 //! | `rest-full` | disabled | Enables all REST features |
 //!
 //! ## Testing
@@ -59,6 +62,8 @@
 //! This crate contains unit tests for the integrated modules.
 //! Integration tests are located in `tests/integration/`.
 
+#[cfg(feature = "attachments")]
+pub mod attachments;
 #[cfg(feature = "browsable-api")]
 pub mod browsable_api;
 pub mod filters;
@@ -81,6 +86,7 @@ pub use reinhardt_core::pagination;
 
 // Core modules (merged from rest-core)
 pub mod authentication;
+pub mod renderers;
 pub mod response;
 // NOTE: routers module removed to avoid circular dependency with reinhardt-urls
 // Use reinhardt-urls::routers directly instead
@@ -98,6 +104,9 @@ pub use authentication::{
 #[cfg(feature = "jwt")]
 pub use authentication::{Claims, JwtAuth};
 
+// Re-export renderer types
+pub use renderers::JSONRenderer;
+
 // Re-export response types
 pub use response::{ApiResponse, IntoApiResponse, PaginatedResponse, ResponseBuilder};
 
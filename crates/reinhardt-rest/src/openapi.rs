@@ -17,6 +17,7 @@
 //! - **YAML/JSON**: Export schemas in both formats
 //! - **Schema Registry**: Centralized schema management with `$ref` references
 //! - **Enum Support**: Tagged, adjacently tagged, and untagged enum handling
+//! - **Mock Server**: Serve responses generated from the schema for routes without handlers
 //! - **Serde Integration**: Support for `#[serde(rename)]`, `#[serde(skip)]`, and more
 //!
 //! ## Quick Start
@@ -151,6 +152,7 @@ pub mod endpoint_inspector;
 pub mod endpoints;
 pub mod enum_schema;
 pub mod generator;
+pub mod mock;
 // Allow module_inception: Re-exporting openapi submodule from openapi.rs
 // is intentional for compatibility with existing imports (`reinhardt_rest::openapi::OpenAPI`)
 #[allow(clippy::module_inception)]
@@ -163,11 +165,12 @@ pub mod swagger;
 
 use thiserror::Error;
 
-pub use auto_schema::{SchemaObject, ToSchema};
+pub use auto_schema::{SchemaObject, ToSchema, type_schema_name};
 pub use endpoint_inspector::EndpointInspector;
 pub use endpoints::generate_openapi_schema;
 pub use enum_schema::{EnumSchemaBuilder, EnumTagging};
 pub use generator::SchemaGenerator;
+pub use mock::{MockMode, MockServerMiddleware};
 pub use openapi::{
 	ArrayBuilder, Components, ComponentsExt, Header, Info, MediaType, ObjectBuilder, OpenApiSchema,
 	OpenApiSchemaExt, Operation, OperationExt, Parameter, ParameterExt,
@@ -233,6 +233,83 @@ impl<T: ToSchema> ToSchema for Vec<T> {
 	}
 }
 
+// Smart pointers are transparent: they share the pointee's schema and name,
+// which also makes `Box<Self>` fields usable in recursive types.
+impl<T: ToSchema + ?Sized> ToSchema for Box<T> {
+	fn schema() -> Schema {
+		T::schema()
+	}
+
+	fn schema_name() -> Option<String> {
+		T::schema_name()
+	}
+}
+
+impl<T: ToSchema + ?Sized> ToSchema for std::sync::Arc<T> {
+	fn schema() -> Schema {
+		T::schema()
+	}
+
+	fn schema_name() -> Option<String> {
+		T::schema_name()
+	}
+}
+
+impl<T: ToSchema + ?Sized> ToSchema for std::rc::Rc<T> {
+	fn schema() -> Schema {
+		T::schema()
+	}
+
+	fn schema_name() -> Option<String> {
+		T::schema_name()
+	}
+}
+
+/// Returns the name used for `T` in generic schema names.
+///
+/// Uses [`ToSchema::schema_name`] when available, falling back to the Rust
+/// type name with module paths stripped and generic arguments joined by `_`
+/// (e.g. `Vec<i64>` becomes `Vec_i64`). `#[derive(Schema)]` uses this to name
+/// instantiations of generic types such as `Page_User`.
+///
+/// # Example
+///
+/// ```rust
+/// use reinhardt_rest::openapi::type_schema_name;
+///
+/// assert_eq!(type_schema_name::<i64>(), "i64");
+/// assert_eq!(type_schema_name::<Vec<i64>>(), "Vec_i64");
+/// ```
+pub fn type_schema_name<T: ToSchema + ?Sized>() -> String {
+	if let Some(name) = T::schema_name() {
+		return name;
+	}
+
+	let mut name = String::new();
+	let mut segment = String::new();
+	for c in std::any::type_name::<T>().chars() {
+		if c.is_alphanumeric() || c == '_' {
+			segment.push(c);
+		} else if c == ':' {
+			// Drop module path segments
+			segment.clear();
+		} else if !segment.is_empty() {
+			if !name.is_empty() {
+				name.push('_');
+			}
+			name.push_str(&segment);
+			segment.clear();
+		}
+	}
+	if !segment.is_empty() {
+		if !name.is_empty() {
+			name.push('_');
+		}
+		name.push_str(&segment);
+	}
+	name
+}
+
 /// `HashMap<String, V>` support for OpenAPI schema generation
 ///
 /// Generates an OpenAPI schema with `additionalProperties` for dictionary-like structures.
@@ -661,4 +738,28 @@ mod tests {
 			_ => panic!("Expected Object schema"),
 		}
 	}
+
+	#[rstest]
+	fn test_box_schema_delegates_to_inner_type() {
+		// Arrange / Act
+		let schema = Box::<User>::schema();
+
+		// Assert
+		assert_eq!(Box::<User>::schema_name(), Some("User".to_string()));
+		match schema {
+			Schema::Object(obj) => assert!(obj.properties.contains_key("name")),
+			_ => panic!("Expected Object schema"),
+		}
+	}
+
+	#[rstest]
+	#[case::named(type_schema_name::<User>(), "User")]
+	#[case::primitive(type_schema_name::<i64>(), "i64")]
+	#[case::std_path(type_schema_name::<String>(), "String")]
+	#[case::generic(type_schema_name::<Vec<i64>>(), "Vec_i64")]
+	#[case::named_generic(type_schema_name::<Vec<User>>(), "Array_User")]
+	fn test_type_schema_name(#[case] name: String, #[case] expected: &str) {
+		// Assert
+		assert_eq!(name, expected);
+	}
 }
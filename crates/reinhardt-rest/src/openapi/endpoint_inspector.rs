@@ -6,9 +6,12 @@
 use super::SchemaError;
 use indexmap::IndexMap;
 use regex::Regex;
-use reinhardt_core::endpoint::{AuthProtection, EndpointMetadata};
+use reinhardt_core::endpoint::{
+	AuthProtection, DeprecationMetadata, EndpointMetadata, ThrottleScopeMetadata, deprecation_for,
+	request_validation_for, throttle_scope_for,
+};
 use utoipa::openapi::{
-	HttpMethod, PathItem, ResponseBuilder,
+	Deprecated, HttpMethod, PathItem, ResponseBuilder,
 	content::ContentBuilder,
 	extensions::Extensions,
 	header::HeaderBuilder,
@@ -43,6 +46,33 @@ impl Default for InspectorConfig {
 	}
 }
 
+/// Build the `x-throttle` extension value documenting a handler's throttle scope.
+fn throttle_extension(throttle: &ThrottleScopeMetadata) -> serde_json::Value {
+	let mut value = serde_json::json!({
+		"scope": throttle.scope,
+		"rate": throttle.rate,
+	});
+	if let Some(rate) = throttle.anon_rate {
+		value["anon_rate"] = serde_json::json!(rate);
+	}
+	if let Some(rate) = throttle.user_rate {
+		value["user_rate"] = serde_json::json!(rate);
+	}
+	value
+}
+
+/// Build the `x-sunset` extension value for a deprecated operation
+fn sunset_extension(deprecation: &DeprecationMetadata) -> serde_json::Value {
+	let mut value = serde_json::json!({ "since": deprecation.since });
+	if let Some(sunset) = deprecation.sunset {
+		value["sunset"] = serde_json::json!(sunset);
+	}
+	if let Some(link) = deprecation.link {
+		value["link"] = serde_json::json!(link);
+	}
+	value
+}
+
 /// Normalize a type name by extracting the last path segment.
 ///
 /// Handles fully-qualified paths like `"crate :: models :: CreateUserRequest"`
@@ -99,7 +129,8 @@ impl EndpointInspector {
 			let mut builder = PathItemBuilder::new();
 
 			for metadata in endpoints {
-				let parameters = self.extract_path_parameters(metadata.path)?;
+				let mut parameters = self.extract_path_parameters(metadata.path)?;
+				parameters.extend(self.extract_query_parameters(metadata));
 				let operation = self.create_operation(metadata, parameters);
 				let http_method = self.metadata_method_to_http_method(metadata.method)?;
 
@@ -149,8 +180,16 @@ impl EndpointInspector {
 			return None;
 		}
 
-		let body_type = metadata.request_body_type?;
-		let content_type = metadata.request_content_type?;
+		// Prefer the extractor-detected body, then a `#[validate_request]` body
+		let (body_type, content_type) =
+			match (metadata.request_body_type, metadata.request_content_type) {
+				(Some(body_type), Some(content_type)) => (body_type, content_type),
+				_ => (
+					request_validation_for(metadata.module_path, metadata.function_name)?
+						.body_type?,
+					"application/json",
+				),
+			};
 
 		// Normalize the type name to handle fully-qualified paths from quote!()
 		// e.g., "crate :: models :: CreateUserRequest" → "CreateUserRequest"
@@ -221,6 +260,16 @@ impl EndpointInspector {
 		}
 		builder = builder.response("200", default_resp_builder.build());
 
+		// Document the structured 422 returned by `#[validate_request]`
+		if request_validation_for(metadata.module_path, metadata.function_name).is_some() {
+			builder = builder.response(
+				"422",
+				ResponseBuilder::new()
+					.description("Validation failed; `errors` maps each field to its messages")
+					.build(),
+			);
+		}
+
 		// Add custom responses from metadata
 		for response in metadata.responses {
 			let mut resp_builder =
@@ -284,10 +333,25 @@ impl EndpointInspector {
 			}
 		}
 
+		let mut exts = Extensions::default();
+
 		// Add x-guard extension when a guard description is available
 		if let Some(desc) = &metadata.guard_description {
-			let mut exts = Extensions::default();
 			exts.insert("x-guard".to_string(), serde_json::json!(desc));
+		}
+
+		// Add x-throttle extension when the handler declares a throttle scope
+		if let Some(throttle) = throttle_scope_for(metadata.module_path, metadata.function_name) {
+			exts.insert("x-throttle".to_string(), throttle_extension(throttle));
+		}
+
+		// Mark the operation deprecated and add x-sunset for `#[deprecation]`
+		if let Some(deprecation) = deprecation_for(metadata.module_path, metadata.function_name) {
+			builder = builder.deprecated(Some(Deprecated::True));
+			exts.insert("x-sunset".to_string(), sunset_extension(deprecation));
+		}
+
+		if !exts.is_empty() {
 			builder = builder.extensions(Some(exts));
 		}
 
@@ -334,6 +398,41 @@ impl EndpointInspector {
 		Ok(parameters)
 	}
 
+	/// Extract query parameters declared with `#[validate_request(query = T)]`
+	///
+	/// Each property of the registered schema for `T` becomes a query
+	/// parameter. Types without a registered object schema yield none.
+	fn extract_query_parameters(&self, metadata: &EndpointMetadata) -> Vec<Parameter> {
+		let Some(query_type) = request_validation_for(metadata.module_path, metadata.function_name)
+			.and_then(|validation| validation.query_type)
+		else {
+			return Vec::new();
+		};
+		let Some(Schema::Object(object)) =
+			super::registry::get_all_schemas().get(normalize_type_name(query_type))
+		else {
+			return Vec::new();
+		};
+
+		object
+			.properties
+			.iter()
+			.map(|(name, schema)| {
+				let required = if object.required.contains(name) {
+					utoipa::openapi::Required::True
+				} else {
+					utoipa::openapi::Required::False
+				};
+				ParameterBuilder::new()
+					.name(name)
+					.parameter_in(ParameterIn::Query)
+					.required(required)
+					.schema(Some(schema.clone()))
+					.build()
+			})
+			.collect()
+	}
+
 	/// Convert Django type specifier to OpenAPI type and format
 	///
 	/// Mappings:
@@ -1006,4 +1105,130 @@ mod tests {
 			"No guard_description should produce no x-guard extension"
 		);
 	}
+
+	inventory::submit! {
+		ThrottleScopeMetadata {
+			scope: "burst",
+			rate: "10/min",
+			anon_rate: Some("2/min"),
+			user_rate: None,
+			function_name: "throttled_for_openapi_test",
+			module_path: "app::views",
+		}
+	}
+
+	#[rstest::rstest]
+	fn test_create_operation_throttle_scope_adds_x_throttle_extension() {
+		// Arrange
+		let inspector = EndpointInspector::new();
+		let metadata = EndpointMetadata {
+			path: "/api/throttled",
+			method: "GET",
+			name: Some("throttled"),
+			function_name: "throttled_for_openapi_test",
+			module_path: "app::views",
+			request_body_type: None,
+			request_content_type: None,
+			responses: &[],
+			headers: &[],
+			security: &[],
+			auth_protection: AuthProtection::Public,
+			guard_description: Some("IsStaff"),
+		};
+
+		// Act
+		let operation = inspector.create_operation(&metadata, vec![]);
+		let json = serde_json::to_value(&operation).unwrap();
+
+		// Assert: x-throttle documents the scope alongside x-guard
+		assert_eq!(
+			json["x-throttle"],
+			serde_json::json!({"scope": "burst", "rate": "10/min", "anon_rate": "2/min"})
+		);
+		assert_eq!(json["x-guard"].as_str(), Some("IsStaff"));
+	}
+
+	inventory::submit! {
+		reinhardt_core::endpoint::DeprecationMetadata {
+			since: "2024-01-01",
+			sunset: Some("2024-12-31"),
+			link: Some("/api/v2/legacy/"),
+			function_name: "deprecated_for_openapi_test",
+			module_path: "app::views",
+		}
+	}
+
+	#[rstest::rstest]
+	fn test_create_operation_deprecation_marks_operation_deprecated() {
+		// Arrange
+		let inspector = EndpointInspector::new();
+		let metadata = EndpointMetadata {
+			path: "/api/legacy",
+			method: "GET",
+			name: Some("legacy"),
+			function_name: "deprecated_for_openapi_test",
+			module_path: "app::views",
+			request_body_type: None,
+			request_content_type: None,
+			responses: &[],
+			headers: &[],
+			security: &[],
+			auth_protection: AuthProtection::Public,
+			guard_description: None,
+		};
+
+		// Act
+		let operation = inspector.create_operation(&metadata, vec![]);
+		let json = serde_json::to_value(&operation).unwrap();
+
+		// Assert
+		assert_eq!(json["deprecated"], serde_json::json!(true));
+		assert_eq!(
+			json["x-sunset"],
+			serde_json::json!({"since": "2024-01-01", "sunset": "2024-12-31", "link": "/api/v2/legacy/"})
+		);
+	}
+
+	inventory::submit! {
+		reinhardt_core::endpoint::RequestValidationMetadata {
+			query_type: Some("QualifiedPathTestSchema"),
+			body_type: Some("crate :: dto :: QualifiedPathTestSchema"),
+			function_name: "validated_for_openapi_test",
+			module_path: "app::views",
+		}
+	}
+
+	#[rstest::rstest]
+	fn test_validate_request_feeds_query_parameters_body_and_422() {
+		// Arrange
+		let inspector = EndpointInspector::new();
+		let metadata = EndpointMetadata {
+			path: "/api/validated",
+			method: "POST",
+			name: Some("validated"),
+			function_name: "validated_for_openapi_test",
+			module_path: "app::views",
+			request_body_type: None,
+			request_content_type: None,
+			responses: &[],
+			headers: &[],
+			security: &[],
+			auth_protection: AuthProtection::Public,
+			guard_description: None,
+		};
+
+		// Act
+		let parameters = inspector.extract_query_parameters(&metadata);
+		let request_body = inspector.create_request_body(&metadata);
+		let operation = inspector.create_operation(&metadata, parameters);
+		let json = serde_json::to_value(&operation).unwrap();
+
+		// Assert
+		assert_eq!(json["parameters"][0]["name"], "test_field");
+		assert_eq!(json["parameters"][0]["in"], "query");
+		assert_eq!(json["parameters"][0]["required"], true);
+		let request_body = request_body.expect("validated body should be documented");
+		assert!(request_body.content.contains_key("application/json"));
+		assert!(json["responses"]["422"].is_object());
+	}
 }
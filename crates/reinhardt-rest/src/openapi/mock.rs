@@ -0,0 +1,690 @@
+//! Schema-first mock server
+//!
+//! [`MockServerMiddleware`] answers requests from the OpenAPI document
+//! instead of (or in place of missing) handlers, so that a frontend can be
+//! developed against the API contract before the backend exists. Each route
+//! prefix is switched on separately with a [`MockMode`]:
+//!
+//! - [`MockMode::Always`]: every documented operation under the prefix is
+//!   mocked, whether or not a handler exists
+//! - [`MockMode::Fallback`]: the request goes to the real handler first, and
+//!   is only mocked when it answers `404 Not Found` or `501 Not Implemented`
+//! - [`MockMode::Off`]: the prefix is never mocked, e.g. to exclude a
+//!   sub-tree of a mocked prefix
+//!
+//! The longest matching prefix decides. The body of a mocked response comes
+//! from, in order: a factory registered for the operation, the `example` or
+//! first `examples` entry of its success response, or data generated from
+//! the response schema (honouring `example`, `default`, `enum`, `format` and
+//! `$ref`). Generated data is seeded from the request path, so the same URL
+//! always returns the same body. Mocked responses carry an `X-Mock-Response`
+//! header.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use reinhardt_rest::openapi::mock::{MockMode, MockServerMiddleware};
+//! use serde_json::json;
+//!
+//! let spec = json!({
+//!     "openapi": "3.0.3",
+//!     "info": { "title": "Shop", "version": "1.0.0" },
+//!     "paths": {
+//!         "/api/orders/{id}/": {
+//!             "get": {
+//!                 "responses": {
+//!                     "200": {
+//!                         "description": "An order",
+//!                         "content": { "application/json": { "schema": {
+//!                             "type": "object",
+//!                             "properties": {
+//!                                 "id": { "type": "integer" },
+//!                                 "status": { "type": "string", "enum": ["pending", "paid"] }
+//!                             }
+//!                         } } }
+//!                     }
+//!                 }
+//!             }
+//!         }
+//!     }
+//! });
+//!
+//! let mock = MockServerMiddleware::from_json(spec)
+//!     .mock_prefix("/api/", MockMode::Fallback)
+//!     .mock_prefix("/api/orders/", MockMode::Always);
+//! assert_eq!(mock.mode_for("/api/orders/7/"), MockMode::Always);
+//! assert_eq!(mock.mode_for("/admin/"), MockMode::Off);
+//! ```
+
+use super::{OpenApiSchema, SchemaResult};
+use async_trait::async_trait;
+use hyper::StatusCode;
+use reinhardt_core::exception::Result;
+use reinhardt_http::{Handler, Middleware, Request, Response};
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Header set on every mocked response
+pub const MOCK_RESPONSE_HEADER: &str = "X-Mock-Response";
+
+/// Deepest `$ref` chain followed while generating data
+const MAX_DEPTH: usize = 8;
+
+/// Number of items generated for arrays without size constraints
+const DEFAULT_ARRAY_LEN: u64 = 3;
+
+const METHODS: [&str; 8] = [
+	"get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// Whether requests under a route prefix are mocked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MockMode {
+	/// Never mock (default for paths outside every prefix)
+	#[default]
+	Off,
+	/// Mock only when the real handler answers 404 or 501
+	Fallback,
+	/// Always mock documented operations
+	Always,
+}
+
+/// Request details passed to mock factories
+#[derive(Debug, Clone)]
+pub struct MockContext {
+	/// HTTP method, uppercase
+	pub method: String,
+	/// Request path
+	pub path: String,
+	/// Path template of the matched operation, e.g. `/api/orders/{id}/`
+	pub template: String,
+	/// Values of the template's path parameters
+	pub params: HashMap<String, String>,
+}
+
+type MockFactory = Arc<dyn Fn(&MockContext) -> Value + Send + Sync>;
+
+/// A documented operation
+struct MockOperation {
+	method: String,
+	template: String,
+	segments: Vec<String>,
+	status: StatusCode,
+	example: Option<Value>,
+	schema: Option<Value>,
+}
+
+impl MockOperation {
+	/// Path parameters of `path`, or `None` when the template does not match
+	fn match_path(&self, path: &str) -> Option<HashMap<String, String>> {
+		let segments: Vec<&str> = split_path(path).collect();
+		if segments.len() != self.segments.len() {
+			return None;
+		}
+		let mut params = HashMap::new();
+		for (template, segment) in self.segments.iter().zip(segments) {
+			match template
+				.strip_prefix('{')
+				.and_then(|name| name.strip_suffix('}'))
+			{
+				Some(name) => {
+					params.insert(name.to_string(), segment.to_string());
+				}
+				None if template == segment => {}
+				None => return None,
+			}
+		}
+		Some(params)
+	}
+
+	/// Literal segments rank before parameters, so `/users/me` beats `/users/{id}`
+	fn specificity(&self) -> usize {
+		self.segments.iter().filter(|s| !s.starts_with('{')).count()
+	}
+}
+
+/// Middleware serving mock responses generated from an OpenAPI document
+///
+/// See the [module documentation](self) for how responses are built.
+pub struct MockServerMiddleware {
+	spec: Arc<Value>,
+	operations: Vec<MockOperation>,
+	prefixes: Vec<(String, MockMode)>,
+	factories: HashMap<(String, String), MockFactory>,
+}
+
+impl MockServerMiddleware {
+	/// Create a mock server for the operations of `schema`
+	///
+	/// No prefix is mocked until enabled with [`mock_prefix`](Self::mock_prefix).
+	pub fn new(schema: &OpenApiSchema) -> SchemaResult<Self> {
+		Ok(Self::from_json(serde_json::to_value(schema)?))
+	}
+
+	/// Create a mock server from an OpenAPI document in JSON form
+	pub fn from_json(spec: Value) -> Self {
+		let operations = collect_operations(&spec);
+		Self {
+			spec: Arc::new(spec),
+			operations,
+			prefixes: Vec::new(),
+			factories: HashMap::new(),
+		}
+	}
+
+	/// Set the mode of requests whose path starts with `prefix`
+	pub fn mock_prefix(mut self, prefix: impl Into<String>, mode: MockMode) -> Self {
+		let prefix = prefix.into();
+		self.prefixes.retain(|(existing, _)| *existing != prefix);
+		self.prefixes.push((prefix, mode));
+		self
+	}
+
+	/// Build the body of the operation `method path_template` with `factory`
+	///
+	/// `path_template` is the path as written in the OpenAPI document, e.g.
+	/// `/api/orders/{id}/`.
+	pub fn with_factory<F>(
+		mut self,
+		method: &str,
+		path_template: impl Into<String>,
+		factory: F,
+	) -> Self
+	where
+		F: Fn(&MockContext) -> Value + Send + Sync + 'static,
+	{
+		self.factories.insert(
+			(method.to_uppercase(), path_template.into()),
+			Arc::new(factory),
+		);
+		self
+	}
+
+	/// Mode of requests to `path`, decided by the longest matching prefix
+	pub fn mode_for(&self, path: &str) -> MockMode {
+		self.prefixes
+			.iter()
+			.filter(|(prefix, _)| path.starts_with(prefix.as_str()))
+			.max_by_key(|(prefix, _)| prefix.len())
+			.map(|(_, mode)| *mode)
+			.unwrap_or_default()
+	}
+
+	/// Mock response for `method path`, `None` when no operation is documented
+	pub fn mock_response(&self, method: &str, path: &str) -> Option<Response> {
+		let method = method.to_uppercase();
+		let (operation, params) = self
+			.operations
+			.iter()
+			.filter(|operation| operation.method == method)
+			.filter_map(|operation| Some((operation, operation.match_path(path)?)))
+			.max_by_key(|(operation, _)| operation.specificity())?;
+
+		let context = MockContext {
+			method,
+			path: path.to_string(),
+			template: operation.template.clone(),
+			params,
+		};
+		let body = match self
+			.factories
+			.get(&(context.method.clone(), operation.template.clone()))
+		{
+			Some(factory) => Some(factory(&context)),
+			None => operation.example.clone().or_else(|| {
+				operation.schema.as_ref().map(|schema| {
+					MockGenerator::new(&self.spec, &context).generate(schema, None, 0)
+				})
+			}),
+		};
+
+		let response = Response::new(operation.status).with_header(MOCK_RESPONSE_HEADER, "true");
+		Some(match body {
+			Some(body) => response
+				.with_json(&body)
+				.unwrap_or_else(|_| Response::internal_server_error()),
+			None => response,
+		})
+	}
+}
+
+#[async_trait]
+impl Middleware for MockServerMiddleware {
+	async fn process(&self, request: Request, next: Arc<dyn Handler>) -> Result<Response> {
+		let path = request.uri.path().to_string();
+		let method = request.method.as_str().to_string();
+		match self.mode_for(&path) {
+			MockMode::Off => next.handle(request).await,
+			MockMode::Always => match self.mock_response(&method, &path) {
+				Some(response) => Ok(response),
+				None => next.handle(request).await,
+			},
+			MockMode::Fallback => {
+				let result = next.handle(request).await;
+				let status = match &result {
+					Ok(response) => response.status.as_u16(),
+					Err(error) => error.status_code(),
+				};
+				if status != 404 && status != 501 {
+					return result;
+				}
+				match self.mock_response(&method, &path) {
+					Some(response) => Ok(response),
+					None => result,
+				}
+			}
+		}
+	}
+}
+
+/// Documented operations of `spec`, with their success response
+fn collect_operations(spec: &Value) -> Vec<MockOperation> {
+	let Some(paths) = spec.get("paths").and_then(Value::as_object) else {
+		return Vec::new();
+	};
+	let mut operations = Vec::new();
+	for (template, item) in paths {
+		for method in METHODS {
+			let Some(operation) = item.get(method) else {
+				continue;
+			};
+			let (status, response) = success_response(spec, operation);
+			let content = response
+				.and_then(|r| r.get("content"))
+				.and_then(Value::as_object)
+				.and_then(|content| {
+					content
+						.get("application/json")
+						.or_else(|| content.values().next())
+				});
+			let example = content.and_then(|media| {
+				media.get("example").cloned().or_else(|| {
+					media
+						.get("examples")
+						.and_then(Value::as_object)
+						.and_then(|examples| examples.values().next())
+						.map(|example| resolve(spec, example))
+						.and_then(|example| example.get("value").cloned())
+				})
+			});
+			operations.push(MockOperation {
+				method: method.to_uppercase(),
+				template: template.clone(),
+				segments: split_path(template).map(str::to_string).collect(),
+				status,
+				example,
+				schema: content.and_then(|media| media.get("schema").cloned()),
+			});
+		}
+	}
+	operations
+}
+
+/// Lowest documented 2xx response of `operation`, `200 OK` when there is none
+fn success_response<'a>(spec: &'a Value, operation: &'a Value) -> (StatusCode, Option<&'a Value>) {
+	operation
+		.get("responses")
+		.and_then(Value::as_object)
+		.and_then(|responses| {
+			responses
+				.iter()
+				.filter_map(|(code, response)| {
+					let status = StatusCode::from_bytes(code.as_bytes()).ok()?;
+					status
+						.is_success()
+						.then(|| (status, resolve(spec, response)))
+				})
+				.min_by_key(|(status, _)| *status)
+		})
+		.map_or((StatusCode::OK, None), |(status, response)| {
+			(status, Some(response))
+		})
+}
+
+/// Follow a local `$ref`, returning `value` itself when it is not a reference
+fn resolve<'a>(spec: &'a Value, value: &'a Value) -> &'a Value {
+	value
+		.get("$ref")
+		.and_then(Value::as_str)
+		.and_then(|reference| reference.strip_prefix('#'))
+		.and_then(|pointer| spec.pointer(pointer))
+		.unwrap_or(value)
+}
+
+fn split_path(path: &str) -> impl Iterator<Item = &str> {
+	path.split('/').filter(|segment| !segment.is_empty())
+}
+
+/// Deterministic data generator for JSON schemas
+struct MockGenerator<'a> {
+	spec: &'a Value,
+	params: &'a HashMap<String, String>,
+	state: u64,
+}
+
+impl<'a> MockGenerator<'a> {
+	fn new(spec: &'a Value, context: &'a MockContext) -> Self {
+		// FNV-1a of the path, so the same URL always gets the same data
+		let state = context
+			.path
+			.bytes()
+			.fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+				(hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+			});
+		Self {
+			spec,
+			params: &context.params,
+			state,
+		}
+	}
+
+	/// Next pseudo-random number (splitmix64)
+	fn next(&mut self) -> u64 {
+		self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+		let mut z = self.state;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+		z ^ (z >> 31)
+	}
+
+	/// Generate a value for `schema`, `name` being the property it fills
+	fn generate(&mut self, schema: &Value, name: Option<&str>, depth: usize) -> Value {
+		if depth > MAX_DEPTH {
+			return Value::Null;
+		}
+		let schema = resolve(self.spec, schema);
+		for keyword in ["example", "default"] {
+			if let Some(value) = schema.get(keyword) {
+				return value.clone();
+			}
+		}
+		if let Some(first) = schema
+			.get("enum")
+			.and_then(Value::as_array)
+			.and_then(|values| values.first())
+		{
+			return first.clone();
+		}
+		if let Some(parts) = schema.get("allOf").and_then(Value::as_array) {
+			let mut merged = Map::new();
+			for part in parts {
+				if let Value::Object(object) = self.generate(part, name, depth + 1) {
+					merged.extend(object);
+				}
+			}
+			return Value::Object(merged);
+		}
+		for keyword in ["oneOf", "anyOf"] {
+			if let Some(first) = schema
+				.get(keyword)
+				.and_then(Value::as_array)
+				.and_then(|variants| variants.first())
+			{
+				return self.generate(first, name, depth + 1);
+			}
+		}
+
+		match schema_type(schema) {
+			Some("object") => self.object(schema, depth),
+			Some("array") => self.array(schema, name, depth),
+			Some("string") => self.string(schema, name),
+			Some("integer") => self.integer(schema, name),
+			Some("number") => {
+				let whole = self.integer(schema, name).as_i64().unwrap_or_default();
+				json!(whole as f64 + 0.5)
+			}
+			Some("boolean") => Value::Bool(self.next() % 2 == 0),
+			_ => Value::Null,
+		}
+	}
+
+	fn object(&mut self, schema: &Value, depth: usize) -> Value {
+		let mut object = Map::new();
+		if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+			for (property, property_schema) in properties {
+				let value = self.generate(property_schema, Some(property), depth + 1);
+				object.insert(property.clone(), value);
+			}
+		}
+		Value::Object(object)
+	}
+
+	fn array(&mut self, schema: &Value, name: Option<&str>, depth: usize) -> Value {
+		let min = schema.get("minItems").and_then(Value::as_u64).unwrap_or(0);
+		let max = schema
+			.get("maxItems")
+			.and_then(Value::as_u64)
+			.unwrap_or(u64::MAX);
+		let len = DEFAULT_ARRAY_LEN.max(min).min(max);
+		let items = schema.get("items").cloned().unwrap_or(Value::Null);
+		Value::Array(
+			(0..len)
+				.map(|_| self.generate(&items, name, depth + 1))
+				.collect(),
+		)
+	}
+
+	fn string(&mut self, schema: &Value, name: Option<&str>) -> Value {
+		if let Some(value) = name.and_then(|name| self.params.get(name)) {
+			return Value::String(value.clone());
+		}
+		let n = self.next() % 1000 + 1;
+		let value = match schema.get("format").and_then(Value::as_str) {
+			Some("date-time") => format!("2024-01-{:02}T12:00:00Z", n % 28 + 1),
+			Some("date") => format!("2024-01-{:02}", n % 28 + 1),
+			Some("time") => format!("{:02}:00:00", n % 24),
+			Some("email") => format!("user{}@example.com", n),
+			Some("uri") | Some("url") => format!("https://example.com/{}", n),
+			Some("uuid") => {
+				let bytes = (u128::from(self.next()) << 64) | u128::from(self.next());
+				uuid::Builder::from_random_bytes(bytes.to_be_bytes())
+					.into_uuid()
+					.to_string()
+			}
+			_ => format!("{} {}", name.unwrap_or("string"), n),
+		};
+		let min = schema.get("minLength").and_then(Value::as_u64).unwrap_or(0) as usize;
+		let max = schema
+			.get("maxLength")
+			.and_then(Value::as_u64)
+			.map_or(usize::MAX, |max| max as usize);
+		let mut value: String = value.chars().take(max).collect();
+		while value.chars().count() < min {
+			value.push('x');
+		}
+		Value::String(value)
+	}
+
+	fn integer(&mut self, schema: &Value, name: Option<&str>) -> Value {
+		if let Some(value) = name
+			.and_then(|name| self.params.get(name))
+			.and_then(|value| value.parse::<i64>().ok())
+		{
+			return json!(value);
+		}
+		let min = schema.get("minimum").and_then(Value::as_i64).unwrap_or(1);
+		let max = schema
+			.get("maximum")
+			.and_then(Value::as_i64)
+			.unwrap_or(min.saturating_add(999))
+			.max(min);
+		let span = max.abs_diff(min).saturating_add(1);
+		json!(min.saturating_add_unsigned(self.next() % span))
+	}
+}
+
+/// The `type` of `schema`, skipping `null` in OpenAPI 3.1 type lists
+fn schema_type(schema: &Value) -> Option<&str> {
+	match schema.get("type") {
+		Some(Value::String(schema_type)) => Some(schema_type),
+		Some(Value::Array(types)) => types
+			.iter()
+			.filter_map(Value::as_str)
+			.find(|schema_type| *schema_type != "null"),
+		_ if schema.get("properties").is_some() => Some("object"),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bytes::Bytes;
+	use hyper::Method;
+	use rstest::rstest;
+
+	fn spec() -> Value {
+		json!({
+			"openapi": "3.0.3",
+			"info": { "title": "Shop", "version": "1.0.0" },
+			"paths": {
+				"/api/orders/": {
+					"post": {
+						"responses": {
+							"201": {
+								"description": "Created",
+								"content": { "application/json": {
+									"example": { "id": 1, "status": "pending" }
+								} }
+							},
+							"400": { "description": "Invalid" }
+						}
+					}
+				},
+				"/api/orders/{id}/": {
+					"get": {
+						"responses": {
+							"200": {
+								"description": "An order",
+								"content": { "application/json": {
+									"schema": { "$ref": "#/components/schemas/Order" }
+								} }
+							}
+						}
+					}
+				}
+			},
+			"components": {
+				"schemas": {
+					"Order": {
+						"type": "object",
+						"properties": {
+							"id": { "type": "integer" },
+							"status": { "type": "string", "enum": ["pending", "paid"] },
+							"email": { "type": "string", "format": "email" },
+							"lines": {
+								"type": "array",
+								"maxItems": 2,
+								"items": { "$ref": "#/components/schemas/Line" }
+							}
+						}
+					},
+					"Line": {
+						"type": "object",
+						"properties": { "sku": { "type": "string", "example": "SKU-1" } }
+					}
+				}
+			}
+		})
+	}
+
+	struct StatusHandler(StatusCode);
+
+	#[async_trait]
+	impl Handler for StatusHandler {
+		async fn handle(&self, _request: Request) -> Result<Response> {
+			Ok(Response::new(self.0).with_body("real"))
+		}
+	}
+
+	fn request(method: Method, path: &str) -> Request {
+		Request::builder()
+			.method(method)
+			.uri(path)
+			.body(Bytes::new())
+			.build()
+			.unwrap()
+	}
+
+	fn body(response: &Response) -> Value {
+		serde_json::from_slice(&response.body).unwrap()
+	}
+
+	#[rstest]
+	fn test_generates_body_from_schema() {
+		// Arrange
+		let mock = MockServerMiddleware::from_json(spec());
+
+		// Act
+		let response = mock.mock_response("GET", "/api/orders/42/").unwrap();
+		let again = mock.mock_response("GET", "/api/orders/42/").unwrap();
+
+		// Assert
+		let order = body(&response);
+		assert_eq!(response.status, StatusCode::OK);
+		assert_eq!(order["id"], 42);
+		assert_eq!(order["status"], "pending");
+		assert!(order["email"].as_str().unwrap().ends_with("@example.com"));
+		assert_eq!(order["lines"], json!([{"sku": "SKU-1"}, {"sku": "SKU-1"}]));
+		assert_eq!(body(&again), order);
+	}
+
+	#[rstest]
+	fn test_uses_example_and_success_status() {
+		// Arrange
+		let mock = MockServerMiddleware::from_json(spec());
+
+		// Act
+		let response = mock.mock_response("POST", "/api/orders/").unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::CREATED);
+		assert_eq!(body(&response), json!({"id": 1, "status": "pending"}));
+		assert_eq!(response.headers.get(MOCK_RESPONSE_HEADER).unwrap(), "true");
+	}
+
+	#[rstest]
+	fn test_factory_overrides_schema() {
+		// Arrange
+		let mock = MockServerMiddleware::from_json(spec()).with_factory(
+			"get",
+			"/api/orders/{id}/",
+			|context| json!({"id": context.params["id"], "status": "paid"}),
+		);
+
+		// Act
+		let response = mock.mock_response("GET", "/api/orders/7/").unwrap();
+
+		// Assert
+		assert_eq!(body(&response), json!({"id": "7", "status": "paid"}));
+	}
+
+	#[rstest]
+	#[case::fallback_replaces_missing_handler(MockMode::Fallback, StatusCode::NOT_FOUND, true)]
+	#[case::fallback_keeps_implemented_handler(MockMode::Fallback, StatusCode::OK, false)]
+	#[case::always_skips_handler(MockMode::Always, StatusCode::OK, true)]
+	#[case::off_keeps_missing_handler(MockMode::Off, StatusCode::NOT_FOUND, false)]
+	#[tokio::test]
+	async fn test_modes(
+		#[case] mode: MockMode,
+		#[case] handler_status: StatusCode,
+		#[case] mocked: bool,
+	) {
+		// Arrange
+		let mock = MockServerMiddleware::from_json(spec())
+			.mock_prefix("/api/", mode)
+			.mock_prefix("/api/payments/", MockMode::Off);
+		let next: Arc<dyn Handler> = Arc::new(StatusHandler(handler_status));
+
+		// Act
+		let response = mock
+			.process(request(Method::GET, "/api/orders/3/"), next)
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.headers.contains_key(MOCK_RESPONSE_HEADER), mocked);
+		assert_eq!(mock.mode_for("/api/payments/1/"), MockMode::Off);
+	}
+}
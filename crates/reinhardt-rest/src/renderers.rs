@@ -0,0 +1,227 @@
+//! Response renderers
+//!
+//! [`JSONRenderer`] turns view data into an `application/json` response,
+//! applying the renderer-level [`JsonOptions`]: camelCase keys, pretty
+//! printing, datetime format and strict float handling. A renderer created
+//! with [`JSONRenderer::new`] starts from the project-wide options installed
+//! from the `[json]` settings section; a view can override any of them, and
+//! clients can ask for indented output with an `indent` media type parameter
+//! (`Accept: application/json; indent=4`), as in Django REST Framework.
+
+use reinhardt_core::serializers::SerializerError;
+use reinhardt_core::serializers::json_options::{DateTimeFormat, JsonOptions, KeyCase};
+use reinhardt_http::{Error, Request, Response};
+use serde::Serialize;
+
+/// Largest indent honoured from the `Accept` header.
+const MAX_INDENT: usize = 8;
+
+/// Renderer producing `application/json` responses.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_rest::renderers::JSONRenderer;
+/// use serde_json::json;
+///
+/// let renderer = JSONRenderer::new().camel_case(true);
+/// let body = renderer.render(&json!({"first_name": "Alice"}), None).unwrap();
+/// assert_eq!(body, r#"{"firstName":"Alice"}"#);
+/// ```
+#[derive(Debug, Clone)]
+pub struct JSONRenderer {
+	options: JsonOptions,
+}
+
+impl Default for JSONRenderer {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl JSONRenderer {
+	/// Create a renderer using the project-wide [`JsonOptions`].
+	pub fn new() -> Self {
+		Self::with_options(JsonOptions::global())
+	}
+
+	/// Create a renderer with explicit options, ignoring the project-wide defaults.
+	pub fn with_options(options: JsonOptions) -> Self {
+		Self { options }
+	}
+
+	/// Write object keys as camelCase.
+	pub fn camel_case(mut self, enabled: bool) -> Self {
+		self.options.key_case = if enabled {
+			KeyCase::CamelCase
+		} else {
+			KeyCase::Preserve
+		};
+		self
+	}
+
+	/// Indent the output.
+	pub fn pretty(mut self, pretty: bool) -> Self {
+		self.options.pretty = pretty;
+		self
+	}
+
+	/// Set the format used for datetime values.
+	pub fn datetime_format(mut self, format: DateTimeFormat) -> Self {
+		self.options.datetime_format = format;
+		self
+	}
+
+	/// Reject `NaN` and infinite floats instead of writing them as `null`.
+	pub fn strict(mut self, strict: bool) -> Self {
+		self.options.strict = strict;
+		self
+	}
+
+	/// The options applied by this renderer.
+	pub fn options(&self) -> &JsonOptions {
+		&self.options
+	}
+
+	/// The media type produced by this renderer.
+	pub fn media_type(&self) -> &'static str {
+		"application/json"
+	}
+
+	/// Render `data` to a JSON string.
+	///
+	/// `accepted_media_type` is the client's `Accept` header; an `indent`
+	/// parameter on its JSON entry overrides the `pretty` option.
+	pub fn render<T: Serialize + ?Sized>(
+		&self,
+		data: &T,
+		accepted_media_type: Option<&str>,
+	) -> Result<String, SerializerError> {
+		let indent = accepted_media_type
+			.and_then(requested_indent)
+			.or(self.options.pretty.then_some(2));
+		let value = self.options.to_value(data)?;
+		let bytes = match indent {
+			Some(width) => {
+				let indent = " ".repeat(width);
+				let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+				let mut out = Vec::new();
+				let mut serializer = serde_json::Serializer::with_formatter(&mut out, formatter);
+				value.serialize(&mut serializer).map(|()| out)
+			}
+			None => serde_json::to_vec(&value),
+		}
+		.map_err(|e| SerializerError::Serde {
+			message: format!("Serialization error: {}", e),
+		})?;
+		String::from_utf8(bytes).map_err(|e| SerializerError::Other {
+			message: e.to_string(),
+		})
+	}
+
+	/// Render `data` as a `200 OK` JSON response for `request`.
+	pub fn render_response<T: Serialize + ?Sized>(
+		&self,
+		data: &T,
+		request: &Request,
+	) -> reinhardt_http::Result<Response> {
+		let accept = request
+			.headers
+			.get(hyper::header::ACCEPT)
+			.and_then(|value| value.to_str().ok());
+		let body = self
+			.render(data, accept)
+			.map_err(|e| Error::Internal(e.to_string()))?;
+		Ok(Response::ok()
+			.with_body(body)
+			.content_type(self.media_type()))
+	}
+}
+
+/// Reads the `indent` parameter of the `application/json` entry in an
+/// `Accept` header.
+fn requested_indent(accept: &str) -> Option<usize> {
+	accept.split(',').find_map(|entry| {
+		let mut parts = entry.split(';').map(str::trim);
+		if !parts.next()?.eq_ignore_ascii_case("application/json") {
+			return None;
+		}
+		parts.find_map(|param| {
+			let (name, value) = param.split_once('=')?;
+			if !name.trim().eq_ignore_ascii_case("indent") {
+				return None;
+			}
+			value
+				.trim()
+				.parse::<usize>()
+				.ok()
+				.map(|n| n.min(MAX_INDENT))
+		})
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use serde_json::json;
+
+	#[rstest]
+	#[case::none("application/json", None)]
+	#[case::indent("application/json; indent=4", Some(4))]
+	#[case::capped("application/json; indent=100", Some(MAX_INDENT))]
+	#[case::other_type("text/html; indent=4, application/json", None)]
+	#[case::listed("text/html, application/json; q=0.9; indent=2", Some(2))]
+	fn test_requested_indent(#[case] accept: &str, #[case] expected: Option<usize>) {
+		// Arrange / Act / Assert
+		assert_eq!(requested_indent(accept), expected);
+	}
+
+	#[rstest]
+	fn test_render_indent_from_accept_overrides_compact() {
+		// Arrange
+		let renderer = JSONRenderer::with_options(JsonOptions::new());
+
+		// Act
+		let body = renderer
+			.render(&json!({"a": 1}), Some("application/json; indent=4"))
+			.unwrap();
+
+		// Assert
+		assert_eq!(body, "{\n    \"a\": 1\n}");
+	}
+
+	#[rstest]
+	fn test_render_applies_view_options() {
+		// Arrange
+		let renderer = JSONRenderer::with_options(JsonOptions::new())
+			.camel_case(true)
+			.datetime_format(DateTimeFormat::EpochSeconds);
+
+		// Act
+		let body = renderer
+			.render(
+				&json!({"updated_at": "1970-01-01T00:00:10Z", "tags": [{"tag_name": "x"}]}),
+				None,
+			)
+			.unwrap();
+
+		// Assert
+		assert_eq!(
+			serde_json::from_str::<serde_json::Value>(&body).unwrap(),
+			json!({"updatedAt": 10, "tags": [{"tagName": "x"}]})
+		);
+	}
+
+	#[rstest]
+	fn test_render_strict_rejects_nan() {
+		// Arrange
+		let renderer = JSONRenderer::with_options(JsonOptions::new()).strict(true);
+
+		// Act
+		let result = renderer.render(&[1.0, f64::NAN], None);
+
+		// Assert
+		assert!(result.is_err());
+	}
+}
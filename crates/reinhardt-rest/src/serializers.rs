@@ -15,6 +15,7 @@
 //! - **[`HyperlinkedModelSerializer`]**: Serializers with hyperlinked relationships
 //! - **Field Types**: CharField, IntegerField, DateTimeField, etc.
 //! - **Validators**: UniqueValidator, custom validation functions
+//! - **Field Visibility**: Omit or redact fields per requester ([`FieldVisibility`])
 //! - **Performance**: Query caching, N+1 detection, batch validation
 //! - **Content Negotiation**: JSON, XML, and custom parsers
 //!
@@ -197,8 +198,9 @@ pub use reinhardt_core::serializers::{
 	arena::{FieldValue, SerializationArena, SerializedValue},
 	fields::{
 		BooleanField, CharField, ChoiceField, DateField, DateTimeField, EmailField, FieldError,
-		FloatField, IntegerField, URLField,
+		FloatField, GeoJSONField, IntegerField, URLField,
 	},
+	json_options::{DateTimeFormat, JsonOptions, KeyCase},
 	recursive::{RecursiveError, RecursiveResult, SerializationContext},
 	serializer::{Deserializer, JsonSerializer, Serializer, SerializerError, ValidatorError},
 	validator::{
@@ -244,6 +246,8 @@ pub mod relations;
 pub mod validator_config;
 /// Database-backed validators (unique, unique-together).
 pub mod validators;
+/// Per-requester field visibility and redaction.
+pub mod visibility;
 
 // Re-export REST-specific types
 pub use cache_invalidation::{CacheInvalidator, InvalidationStrategy};
@@ -275,3 +279,4 @@ pub use relations::{
 };
 pub use validator_config::{ModelLevelValidator, ValidatorConfig};
 pub use validators::{DatabaseValidatorError, UniqueTogetherValidator, UniqueValidator};
+pub use visibility::{FieldVisibility, Masking, VisibilityContext};
@@ -8,6 +8,7 @@ use super::meta::MetaConfig;
 use super::nested_config::{NestedFieldConfig, NestedSerializerConfig};
 use super::validator_config::{ModelLevelValidator, ValidatorConfig};
 use super::validators::{UniqueTogetherValidator, UniqueValidator};
+use super::visibility::{FieldVisibility, VisibilityContext};
 use super::{Serializer, SerializerError, ValidatorError};
 use reinhardt_db::backends::DatabaseConnection;
 use reinhardt_db::orm::Model;
@@ -78,6 +79,7 @@ where
 	introspector: Option<FieldIntrospector>,
 	nested_config: NestedSerializerConfig,
 	validator_config: ValidatorConfig<M>,
+	visibility: FieldVisibility,
 	_phantom: PhantomData<M>,
 }
 
@@ -123,6 +125,7 @@ where
 			introspector: None,
 			nested_config: NestedSerializerConfig::new(),
 			validator_config: ValidatorConfig::new(),
+			visibility: FieldVisibility::new(),
 			_phantom: PhantomData,
 		}
 	}
@@ -284,6 +287,83 @@ where
 		&self.meta
 	}
 
+	/// Set the per-requester visibility of fields
+	///
+	/// [`Self::serialize_for`] and [`Self::serialize_many_for`] mask fields
+	/// for the given requester; [`Serializer::serialize`] has no requester
+	/// and masks them as for an anonymous one.
+	///
+	/// # Examples
+	///
+	/// ```ignore
+	/// use reinhardt_rest::serializers::{FieldVisibility, ModelSerializer, VisibilityContext};
+	///
+	/// let serializer = ModelSerializer::<User>::new().with_field_visibility(
+	///     FieldVisibility::new()
+	///         .visible_if("email", |ctx| ctx.user.is_admin())
+	///         .redact_unless("internal_notes", "[redacted]", |ctx| ctx.user.is_admin()),
+	/// );
+	/// let json = serializer.serialize_for(&user, &VisibilityContext::from_request(&request))?;
+	/// ```
+	pub fn with_field_visibility(mut self, visibility: FieldVisibility) -> Self {
+		self.visibility = visibility;
+		self
+	}
+
+	/// Get the per-requester field visibility
+	pub fn field_visibility(&self) -> &FieldVisibility {
+		&self.visibility
+	}
+
+	/// Serialize `instance` for the requester described by `ctx`
+	pub fn serialize_for(
+		&self,
+		instance: &M,
+		ctx: &VisibilityContext,
+	) -> Result<String, SerializerError> {
+		let value = self.to_output_value(instance, ctx)?;
+		serde_json::to_string(&value).map_err(|e| SerializerError::Serde {
+			message: format!("Serialization error: {}", e),
+		})
+	}
+
+	/// Serialize `instances` as a JSON array for the requester described by `ctx`
+	pub fn serialize_many_for(
+		&self,
+		instances: &[M],
+		ctx: &VisibilityContext,
+	) -> Result<String, SerializerError> {
+		let values = instances
+			.iter()
+			.map(|instance| self.to_output_value(instance, ctx))
+			.collect::<Result<Vec<_>, _>>()?;
+		serde_json::to_string(&values).map_err(|e| SerializerError::Serde {
+			message: format!("Serialization error: {}", e),
+		})
+	}
+
+	// Convert `instance` to the JSON value sent to the requester: meta
+	// filters (fields / exclude / write_only) first, then field visibility.
+	fn to_output_value(
+		&self,
+		instance: &M,
+		ctx: &VisibilityContext,
+	) -> Result<serde_json::Value, SerializerError> {
+		let mut value = serde_json::to_value(instance).map_err(|e| SerializerError::Serde {
+			message: format!("Serialization error: {}", e),
+		})?;
+
+		let introspector_names = self.introspector.as_ref().map(|i| i.field_names());
+		apply_meta_filter(
+			&mut value,
+			&self.meta,
+			introspector_names.as_deref(),
+			FilterDirection::Output,
+		);
+		self.visibility.apply(&mut value, ctx);
+		Ok(value)
+	}
+
 	/// Add a nested field configuration
 	///
 	/// # Examples
@@ -1010,21 +1090,9 @@ where
 		// exclude / write_only) can be applied before emitting the final
 		// string. Backward compatible: an unconfigured serializer retains
 		// every key because `MetaConfig::default()` excludes nothing.
-		let mut value = serde_json::to_value(input).map_err(|e| SerializerError::Serde {
-			message: format!("Serialization error: {}", e),
-		})?;
-
-		let introspector_names = self.introspector.as_ref().map(|i| i.field_names());
-		apply_meta_filter(
-			&mut value,
-			&self.meta,
-			introspector_names.as_deref(),
-			FilterDirection::Output,
-		);
-
-		serde_json::to_string(&value).map_err(|e| SerializerError::Serde {
-			message: format!("Serialization error: {}", e),
-		})
+		// Without a requester, conditional fields are masked as for an
+		// anonymous one.
+		self.serialize_for(input, &VisibilityContext::anonymous())
 	}
 
 	fn deserialize(&self, output: &Self::Output) -> Result<Self::Input, SerializerError> {
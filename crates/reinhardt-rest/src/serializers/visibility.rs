@@ -0,0 +1,340 @@
+//! Per-requester field visibility
+//!
+//! [`FieldVisibility`] declares which serializer fields only some requesters
+//! may see. A field whose rule does not hold for the current
+//! [`VisibilityContext`] is either omitted from the output or replaced by a
+//! redaction value. The same rules drive the OpenAPI schema (conditional
+//! fields are marked with `x-visibility: conditional` and are never
+//! required) and the browsable API request form.
+//!
+//! # Examples
+//!
+//! ```
+//! use reinhardt_http::AuthState;
+//! use reinhardt_rest::serializers::visibility::{FieldVisibility, VisibilityContext};
+//! use serde_json::json;
+//!
+//! let visibility = FieldVisibility::new()
+//!     .visible_if("email", |ctx| ctx.user.is_admin())
+//!     .redact_unless("internal_notes", "[redacted]", |ctx| ctx.user.is_admin());
+//!
+//! let mut user = json!({"username": "alice", "email": "a@example.com", "internal_notes": "VIP"});
+//! visibility.apply(&mut user, &VisibilityContext::new(AuthState::authenticated("7", false, true)));
+//! assert_eq!(user, json!({"username": "alice", "internal_notes": "[redacted]"}));
+//! ```
+
+use reinhardt_http::{AuthState, Request};
+use serde_json::Value;
+use std::fmt;
+use std::sync::Arc;
+
+/// Requester a response is serialized for
+#[derive(Debug, Clone)]
+pub struct VisibilityContext {
+	/// Authentication state of the requester
+	pub user: AuthState,
+}
+
+impl VisibilityContext {
+	/// Context for `user`
+	pub fn new(user: AuthState) -> Self {
+		Self { user }
+	}
+
+	/// Context for an unauthenticated requester
+	pub fn anonymous() -> Self {
+		Self::new(AuthState::anonymous())
+	}
+
+	/// Context for the user authenticated on `request`; anonymous if there is none
+	pub fn from_request(request: &Request) -> Self {
+		Self::new(
+			AuthState::from_extensions(&request.extensions).unwrap_or_else(AuthState::anonymous),
+		)
+	}
+}
+
+type VisibilityPredicate = Arc<dyn Fn(&VisibilityContext) -> bool + Send + Sync>;
+
+/// What happens to a field the requester may not see
+#[derive(Debug, Clone, PartialEq)]
+pub enum Masking {
+	/// Remove the field from the output
+	Omit,
+	/// Keep the field but replace its value
+	Redact(Value),
+}
+
+#[derive(Clone)]
+struct FieldRule {
+	field: String,
+	predicate: VisibilityPredicate,
+	masking: Masking,
+}
+
+/// Visibility rules of a serializer's fields
+///
+/// Fields without a rule are always visible. A field with several rules is
+/// visible only if all of them hold; the masking of the first failing rule
+/// applies.
+#[derive(Clone, Default)]
+pub struct FieldVisibility {
+	rules: Vec<FieldRule>,
+}
+
+impl fmt::Debug for FieldVisibility {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("FieldVisibility")
+			.field("conditional_fields", &self.conditional_fields())
+			.finish()
+	}
+}
+
+impl FieldVisibility {
+	/// Create an empty rule set
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Omit `field` unless `predicate` holds for the requester
+	pub fn visible_if<F>(self, field: impl Into<String>, predicate: F) -> Self
+	where
+		F: Fn(&VisibilityContext) -> bool + Send + Sync + 'static,
+	{
+		self.rule(field.into(), Arc::new(predicate), Masking::Omit)
+	}
+
+	/// Replace the value of `field` with `replacement` unless `predicate` holds
+	pub fn redact_unless<F>(
+		self,
+		field: impl Into<String>,
+		replacement: impl Into<Value>,
+		predicate: F,
+	) -> Self
+	where
+		F: Fn(&VisibilityContext) -> bool + Send + Sync + 'static,
+	{
+		self.rule(
+			field.into(),
+			Arc::new(predicate),
+			Masking::Redact(replacement.into()),
+		)
+	}
+
+	fn rule(mut self, field: String, predicate: VisibilityPredicate, masking: Masking) -> Self {
+		self.rules.push(FieldRule {
+			field,
+			predicate,
+			masking,
+		});
+		self
+	}
+
+	/// Whether no field has a rule
+	pub fn is_empty(&self) -> bool {
+		self.rules.is_empty()
+	}
+
+	/// Whether `field` has a visibility rule
+	pub fn is_conditional(&self, field: &str) -> bool {
+		self.rules.iter().any(|rule| rule.field == field)
+	}
+
+	/// Names of the fields having a visibility rule, in declaration order
+	pub fn conditional_fields(&self) -> Vec<&str> {
+		let mut fields: Vec<&str> = Vec::new();
+		for rule in &self.rules {
+			if !fields.contains(&rule.field.as_str()) {
+				fields.push(&rule.field);
+			}
+		}
+		fields
+	}
+
+	/// How `field` must be masked for the requester, or `None` if it is visible
+	pub fn masking_for(&self, field: &str, ctx: &VisibilityContext) -> Option<&Masking> {
+		self.rules
+			.iter()
+			.filter(|rule| rule.field == field)
+			.find(|rule| !(rule.predicate)(ctx))
+			.map(|rule| &rule.masking)
+	}
+
+	/// Whether the requester may see the value of `field`
+	pub fn is_visible(&self, field: &str, ctx: &VisibilityContext) -> bool {
+		self.masking_for(field, ctx).is_none()
+	}
+
+	/// Mask the fields of `value` the requester may not see
+	///
+	/// `value` is a serialized object or an array of them (list responses);
+	/// anything else is left untouched.
+	pub fn apply(&self, value: &mut Value, ctx: &VisibilityContext) {
+		if self.rules.is_empty() {
+			return;
+		}
+		match value {
+			Value::Array(items) => {
+				for item in items {
+					self.apply(item, ctx);
+				}
+			}
+			Value::Object(map) => {
+				for field in self.conditional_fields() {
+					if !map.contains_key(field) {
+						continue;
+					}
+					match self.masking_for(field, ctx) {
+						Some(Masking::Omit) => {
+							map.remove(field);
+						}
+						Some(Masking::Redact(replacement)) => {
+							map.insert(field.to_string(), replacement.clone());
+						}
+						None => {}
+					}
+				}
+			}
+			_ => {}
+		}
+	}
+
+	/// Mark the conditional properties of an object schema
+	///
+	/// Conditional properties get an `x-visibility: conditional` extension
+	/// and are removed from `required`, since some requesters never receive
+	/// them.
+	#[cfg(feature = "openapi")]
+	pub fn mark_schema(&self, schema: &mut crate::openapi::Schema) {
+		use crate::openapi::{RefOr, Schema};
+		use utoipa::openapi::extensions::Extensions;
+
+		let Schema::Object(object) = schema else {
+			return;
+		};
+		for field in self.conditional_fields() {
+			object.required.retain(|required| required != field);
+			if let Some(RefOr::T(Schema::Object(property))) = object.properties.get_mut(field) {
+				property
+					.extensions
+					.get_or_insert_with(Extensions::default)
+					.insert("x-visibility".to_string(), Value::from("conditional"));
+			}
+		}
+	}
+
+	/// Remove the browsable API form fields the requester may not see
+	#[cfg(feature = "browsable-api")]
+	pub fn filter_form_fields(
+		&self,
+		fields: &mut Vec<crate::browsable_api::FormField>,
+		ctx: &VisibilityContext,
+	) {
+		fields.retain(|field| self.is_visible(&field.name, ctx));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use serde_json::json;
+
+	fn visibility() -> FieldVisibility {
+		FieldVisibility::new()
+			.visible_if("email", |ctx| ctx.user.is_authenticated())
+			.redact_unless("notes", "***", |ctx| ctx.user.is_admin())
+	}
+
+	fn staff() -> VisibilityContext {
+		VisibilityContext::new(AuthState::authenticated("1", true, true))
+	}
+
+	#[rstest]
+	fn test_apply_masks_fields_for_anonymous() {
+		// Arrange
+		let mut value = json!({"id": 1, "email": "a@example.com", "notes": "VIP"});
+
+		// Act
+		visibility().apply(&mut value, &VisibilityContext::anonymous());
+
+		// Assert
+		assert_eq!(value, json!({"id": 1, "notes": "***"}));
+	}
+
+	#[rstest]
+	fn test_apply_keeps_fields_for_admin() {
+		// Arrange
+		let mut value = json!({"id": 1, "email": "a@example.com", "notes": "VIP"});
+
+		// Act
+		visibility().apply(&mut value, &staff());
+
+		// Assert
+		assert_eq!(
+			value,
+			json!({"id": 1, "email": "a@example.com", "notes": "VIP"})
+		);
+	}
+
+	#[rstest]
+	fn test_apply_masks_every_item_of_a_list() {
+		// Arrange
+		let mut value =
+			json!([{"id": 1, "email": "a@example.com"}, {"id": 2, "email": "b@example.com"}]);
+
+		// Act
+		visibility().apply(&mut value, &VisibilityContext::anonymous());
+
+		// Assert
+		assert_eq!(value, json!([{"id": 1}, {"id": 2}]));
+	}
+
+	#[rstest]
+	fn test_all_rules_of_a_field_must_hold() {
+		// Arrange
+		let visibility = visibility().visible_if("email", |ctx| ctx.user.is_admin());
+		let user = VisibilityContext::new(AuthState::authenticated("2", false, true));
+
+		// Act
+		let masking = visibility.masking_for("email", &user);
+
+		// Assert
+		assert_eq!(masking, Some(&Masking::Omit));
+		assert_eq!(visibility.conditional_fields(), vec!["email", "notes"]);
+		assert!(visibility.is_visible("id", &user));
+	}
+
+	#[cfg(feature = "openapi")]
+	#[rstest]
+	fn test_mark_schema_flags_conditional_properties() {
+		// Arrange
+		use crate::openapi::Schema;
+		use utoipa::openapi::schema::{ObjectBuilder, SchemaType, Type};
+		let mut schema = Schema::Object(
+			ObjectBuilder::new()
+				.property(
+					"id",
+					ObjectBuilder::new().schema_type(SchemaType::Type(Type::Integer)),
+				)
+				.property(
+					"email",
+					ObjectBuilder::new().schema_type(SchemaType::Type(Type::String)),
+				)
+				.required("id")
+				.required("email")
+				.build(),
+		);
+
+		// Act
+		visibility().mark_schema(&mut schema);
+
+		// Assert
+		let Schema::Object(object) = schema else {
+			unreachable!()
+		};
+		assert_eq!(object.required, vec!["id".to_string()]);
+		let value = serde_json::to_value(&object.properties["email"]).unwrap();
+		assert_eq!(value["x-visibility"], json!("conditional"));
+	}
+}
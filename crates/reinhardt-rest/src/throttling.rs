@@ -5,3 +5,132 @@
 //! See `reinhardt-throttling` documentation for detailed usage.
 
 pub use reinhardt_throttling::*;
+
+use reinhardt_core::endpoint::ThrottleScopeMetadata;
+use std::collections::HashMap;
+
+/// Build a [`ScopedRateThrottle`] from every scope declared with `#[throttle]`.
+///
+/// Each declared scope is registered with its default rate, and its
+/// `anon_rate` / `user_rate` values are registered as [`UserClass`]
+/// overrides. Check requests with [`ScopedRateThrottle::allow_request_as`]
+/// to apply the overrides.
+///
+/// # Errors
+///
+/// Returns [`ThrottleError::InvalidConfig`] if two handlers declare the same
+/// scope with different rates, and propagates rate or scope name validation
+/// errors.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reinhardt_rest::throttling::registered_scoped_throttle;
+///
+/// let throttle = registered_scoped_throttle().unwrap();
+/// println!("{} throttle scopes registered", throttle.scopes.len());
+/// ```
+pub fn registered_scoped_throttle() -> ThrottleResult<ScopedRateThrottle> {
+	scoped_throttle_from(inventory::iter::<ThrottleScopeMetadata>())
+}
+
+fn scoped_throttle_from<'a>(
+	declarations: impl IntoIterator<Item = &'a ThrottleScopeMetadata>,
+) -> ThrottleResult<ScopedRateThrottle> {
+	let mut throttle = ScopedRateThrottle::new();
+	let mut declared: HashMap<&str, &ThrottleScopeMetadata> = HashMap::new();
+
+	for decl in declarations {
+		if let Some(previous) = declared.get(decl.scope) {
+			if (previous.rate, previous.anon_rate, previous.user_rate)
+				!= (decl.rate, decl.anon_rate, decl.user_rate)
+			{
+				return Err(ThrottleError::InvalidConfig(format!(
+					"throttle scope '{}' declared with conflicting rates on {}::{} and {}::{}",
+					decl.scope,
+					previous.module_path,
+					previous.function_name,
+					decl.module_path,
+					decl.function_name,
+				)));
+			}
+			continue;
+		}
+		declared.insert(decl.scope, decl);
+
+		throttle = throttle.add_scope_rate(decl.scope, decl.rate)?;
+		for (class, rate) in [
+			(UserClass::Anon, decl.anon_rate),
+			(UserClass::Authenticated, decl.user_rate),
+		] {
+			if let Some(rate) = rate {
+				let (rate, window) = parse_rate(rate)?;
+				throttle = throttle.override_scope(decl.scope, class, rate, window)?;
+			}
+		}
+	}
+
+	Ok(throttle)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn decl(
+		scope: &'static str,
+		rate: &'static str,
+		anon_rate: Option<&'static str>,
+		function_name: &'static str,
+	) -> ThrottleScopeMetadata {
+		ThrottleScopeMetadata {
+			scope,
+			rate,
+			anon_rate,
+			user_rate: None,
+			function_name,
+			module_path: "app::views",
+		}
+	}
+
+	#[rstest]
+	fn test_scoped_throttle_from_registers_scopes_and_overrides() {
+		// Arrange
+		let decls = [
+			decl("burst", "10/min", Some("2/min"), "list_items"),
+			decl("burst", "10/min", Some("2/min"), "get_item"),
+			decl("upload", "100/day", None, "upload_file"),
+		];
+
+		// Act
+		let throttle = scoped_throttle_from(&decls).unwrap();
+
+		// Assert
+		assert_eq!(throttle.scopes.len(), 2);
+		assert_eq!(throttle.rate_for("burst", UserClass::Anon), Some((2, 60)));
+		assert_eq!(
+			throttle.rate_for("burst", UserClass::Authenticated),
+			Some((10, 60))
+		);
+		assert_eq!(
+			throttle.rate_for("upload", UserClass::Anon),
+			Some((100, 86_400))
+		);
+	}
+
+	#[rstest]
+	fn test_scoped_throttle_from_rejects_conflicting_rates() {
+		// Arrange
+		let decls = [
+			decl("burst", "10/min", None, "list_items"),
+			decl("burst", "20/min", None, "get_item"),
+		];
+
+		// Act
+		let result = scoped_throttle_from(&decls);
+
+		// Assert
+		assert!(matches!(result, Err(ThrottleError::InvalidConfig(_))));
+	}
+}
@@ -4,7 +4,8 @@
 //!
 //! ## Features
 //!
-//! - **AcceptHeaderVersioning**: Version from Accept header (e.g., `Accept: application/json; version=1.0`)
+//! - **AcceptHeaderVersioning**: Version from Accept header (e.g., `Accept: application/json; version=1.0`),
+//!   including vendor types such as `application/vnd.myapp.v2+json`
 //! - **URLPathVersioning**: Version from URL path (e.g., `/v1/users/`)
 //! - **NamespaceVersioning**: Version from URL namespace
 //! - **HostNameVersioning**: Version from subdomain (e.g., `v1.api.example.com`)
@@ -46,6 +47,8 @@ pub use handler::{
 pub use middleware::{ApiVersion, RequestVersionExt, VersioningMiddleware};
 use regex::Regex;
 use reinhardt_core::exception::{Error, Result};
+use reinhardt_core::negotiation::VendorTree;
+use reinhardt_core::negotiation::accept::AcceptHeader;
 use reinhardt_http::Request;
 pub use reverse::{
 	ApiDocFormat, ApiDocUrlBuilder, UrlReverseManager, VersionedUrlBuilder,
@@ -116,6 +119,10 @@ pub trait BaseVersioning: Send + Sync {
 /// Accept header versioning
 ///
 /// Example: `Accept: application/json; version=1.0`
+///
+/// Media ranges are read in the client's order of preference and ranges with
+/// `q=0` are ignored. With a [`VendorTree`] configured, the version can also
+/// come from a vendor type such as `application/vnd.myapp.v2+json`.
 #[derive(Debug, Clone)]
 pub struct AcceptHeaderVersioning {
 	/// The fallback version when no version is specified in the Accept header.
@@ -124,6 +131,8 @@ pub struct AcceptHeaderVersioning {
 	pub allowed_versions: HashSet<String>,
 	/// The parameter name to look for in the Accept header (default: `"version"`).
 	pub version_param: String,
+	/// Vendor tree whose media types carry the version.
+	pub vendor_tree: Option<VendorTree>,
 }
 
 impl AcceptHeaderVersioning {
@@ -143,6 +152,7 @@ impl AcceptHeaderVersioning {
 			default_version: None,
 			allowed_versions: HashSet::new(),
 			version_param: "version".to_string(),
+			vendor_tree: None,
 		}
 	}
 	/// Set the default version to use when no version is specified
@@ -192,6 +202,25 @@ impl AcceptHeaderVersioning {
 		self.version_param = param.into();
 		self
 	}
+	/// Read versions from the media types of a vendor tree
+	///
+	/// Both `application/vnd.<vendor>+json; version=2` and
+	/// `application/vnd.<vendor>.v2+json` are recognized.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::negotiation::VendorTree;
+	/// use reinhardt_rest::versioning::AcceptHeaderVersioning;
+	///
+	/// let versioning = AcceptHeaderVersioning::new()
+	///     .with_vendor_tree(VendorTree::new("myapp"));
+	/// assert!(versioning.vendor_tree.is_some());
+	/// ```
+	pub fn with_vendor_tree(mut self, tree: VendorTree) -> Self {
+		self.vendor_tree = Some(tree);
+		self
+	}
 }
 
 impl Default for AcceptHeaderVersioning {
@@ -209,24 +238,26 @@ impl BaseVersioning for AcceptHeaderVersioning {
 				.to_str()
 				.map_err(|_| Error::Validation(VersioningError::InvalidAcceptHeader.to_string()))?;
 
-			// Parse media type parameters
-			if let Some(params_start) = accept_str.find(';') {
-				let params = &accept_str[params_start + 1..];
-				for param in params.split(';') {
-					let param = param.trim();
-					if let Some((key, value)) = param.split_once('=')
-						&& key.trim() == self.version_param
-					{
-						let version = value.trim().trim_matches('"');
-						if self.is_allowed_version(version) {
-							return Ok(version.to_owned());
-						} else {
-							// Avoid intermediate String allocation from VersionNotAllowed(String).to_string()
-							return Err(Error::Validation(format!(
-								"Version not allowed: {version}"
-							)));
-						}
-					}
+			// Ranges are sorted by preference; q=0 ranges are not acceptable
+			let accept = AcceptHeader::parse(accept_str);
+			let requested = accept
+				.media_types
+				.iter()
+				.filter(|range| range.quality > 0.0)
+				.find_map(|range| {
+					self.vendor_tree
+						.as_ref()
+						.and_then(|tree| tree.resolve(range))
+						.and_then(|resolved| resolved.version)
+						.or_else(|| range.parameter(&self.version_param).map(str::to_owned))
+				});
+
+			if let Some(version) = requested {
+				if self.is_allowed_version(&version) {
+					return Ok(version);
+				} else {
+					// Avoid intermediate String allocation from VersionNotAllowed(String).to_string()
+					return Err(Error::Validation(format!("Version not allowed: {version}")));
 				}
 			}
 		}
@@ -1052,6 +1083,7 @@ pub mod test_utils {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use rstest::rstest;
 	use test_utils::create_test_request;
 
 	#[tokio::test]
@@ -1080,6 +1112,34 @@ mod tests {
 		assert_eq!(version, "1.0");
 	}
 
+	#[rstest]
+	#[case::vendor_segment("application/vnd.myapp.v2+json", "2")]
+	#[case::vendor_parameter("application/vnd.myapp+json; version=2", "2")]
+	#[case::preferred_range(
+		"application/json; version=1; q=0.5, application/vnd.myapp.v2+json",
+		"2"
+	)]
+	#[case::excluded_range("application/vnd.myapp.v2+json; q=0, application/json", "1")]
+	#[tokio::test]
+	async fn test_accept_header_versioning_vendor_tree(
+		#[case] accept: &str,
+		#[case] expected: &str,
+	) {
+		// Arrange
+		let versioning = AcceptHeaderVersioning::new()
+			.with_default_version("1")
+			.with_allowed_versions(vec!["1", "2"])
+			.with_vendor_tree(VendorTree::new("myapp"));
+		let request =
+			create_test_request("/users/", vec![("accept".to_string(), accept.to_string())]);
+
+		// Act
+		let version = versioning.determine_version(&request).await.unwrap();
+
+		// Assert
+		assert_eq!(version, expected);
+	}
+
 	#[tokio::test]
 	async fn test_url_path_versioning() {
 		let versioning = URLPathVersioning::new()
@@ -5,19 +5,20 @@
 // - Data conversion and serialization (JsonSerializer)
 // - Serializer error types
 // - Nested serializer configuration
-// - ModelSerializer meta configuration
+// - ModelSerializer meta configuration and field visibility
 // - Relation fields
 // - Validator types (UniqueValidator, UniqueTogetherValidator)
 // - SerializerMethodField and MethodFieldRegistry
 // - DatabaseValidatorError conversion
 
 use reinhardt_db::orm::{FieldSelector, Model};
+use reinhardt_http::AuthState;
 use reinhardt_rest::serializers::{
-	BooleanField, CharField, EmailField, FieldError, FloatField, HyperlinkedRelatedField,
-	IntegerField, JsonSerializer, ManyRelatedField, ModelLevelValidator, ModelSerializer,
-	PrimaryKeyRelatedField, RelationField, Serializer, SerializerError, SerializerMethodField,
-	SlugRelatedField, StringRelatedField, URLField, UniqueTogetherValidator, UniqueValidator,
-	ValidationError, ValidatorError, WritableNestedSerializer,
+	BooleanField, CharField, EmailField, FieldError, FieldVisibility, FloatField,
+	HyperlinkedRelatedField, IntegerField, JsonSerializer, ManyRelatedField, ModelLevelValidator,
+	ModelSerializer, PrimaryKeyRelatedField, RelationField, Serializer, SerializerError,
+	SerializerMethodField, SlugRelatedField, StringRelatedField, URLField, UniqueTogetherValidator,
+	UniqueValidator, ValidationError, ValidatorError, VisibilityContext, WritableNestedSerializer,
 	introspection::{FieldInfo, FieldIntrospector},
 	meta::{DefaultMeta, MetaConfig, SerializerMeta},
 	method_field::{MethodFieldError, MethodFieldRegistry},
@@ -807,6 +808,34 @@ fn model_serializer_field_names_from_introspector() {
 	assert_eq!(fields.len(), 3);
 }
 
+#[rstest]
+fn model_serializer_masks_fields_per_requester() {
+	// Arrange
+	let serializer = ModelSerializer::<TestUser>::new().with_field_visibility(
+		FieldVisibility::new().visible_if("email", |ctx| ctx.user.is_admin()),
+	);
+	let users = vec![TestUser {
+		id: Some(1),
+		username: "alice".to_string(),
+		email: "alice@example.com".to_string(),
+	}];
+	let admin = VisibilityContext::new(AuthState::authenticated("9", true, true));
+
+	// Act
+	let for_admin = serializer.serialize_many_for(&users, &admin).unwrap();
+	let for_anonymous = serializer.serialize(&users[0]).unwrap();
+
+	// Assert
+	assert_eq!(
+		serde_json::from_str::<Value>(&for_admin).unwrap(),
+		json!([{"id": 1, "username": "alice", "email": "alice@example.com"}])
+	);
+	assert_eq!(
+		serde_json::from_str::<Value>(&for_anonymous).unwrap(),
+		json!({"id": 1, "username": "alice"})
+	);
+}
+
 // ============================================================
 // UniqueValidator tests
 // ============================================================
@@ -0,0 +1,69 @@
+//! Search engine abstraction
+
+use crate::query::{SearchQuery, SearchResults};
+use crate::schema::{IndexSchema, SearchDocument};
+use async_trait::async_trait;
+use thiserror::Error;
+
+/// Result type of search operations
+pub type SearchResult<T> = Result<T, SearchError>;
+
+/// Errors raised by search operations
+#[derive(Debug, Error)]
+pub enum SearchError {
+	/// No search backend is installed.
+	#[error("No search backend is configured")]
+	NotConfigured,
+
+	/// The query uses a field in a way the schema does not allow.
+	#[error("Invalid search query: {0}")]
+	InvalidQuery(String),
+
+	/// A model could not be turned into a document.
+	#[error("Invalid search document: {0}")]
+	InvalidDocument(String),
+
+	/// The search engine could not be reached.
+	#[error("Search engine request failed: {0}")]
+	Http(String),
+
+	/// The search engine rejected a request.
+	#[error("Search engine returned status {status}: {message}")]
+	Backend {
+		/// HTTP status code
+		status: u16,
+		/// Error reported by the engine
+		message: String,
+	},
+
+	/// A document or response could not be (de)serialized.
+	#[error("Serialization error: {0}")]
+	Serialization(#[from] serde_json::Error),
+}
+
+/// A search engine storing documents in indexes
+#[async_trait]
+pub trait SearchBackend: Send + Sync {
+	/// Creates the index of `schema` or updates its settings.
+	async fn ensure_index(&self, schema: &IndexSchema) -> SearchResult<()>;
+
+	/// Adds or replaces documents.
+	async fn upsert(
+		&self,
+		schema: &IndexSchema,
+		documents: Vec<SearchDocument>,
+	) -> SearchResult<()>;
+
+	/// Removes the documents with the given ids.
+	async fn delete(&self, schema: &IndexSchema, ids: Vec<String>) -> SearchResult<()>;
+
+	/// Runs a query against the index of `schema`.
+	async fn search(
+		&self,
+		schema: &IndexSchema,
+		query: &SearchQuery,
+	) -> SearchResult<SearchResults>;
+
+	/// Returns the name of the backend.
+	fn backend_name(&self) -> &str;
+}
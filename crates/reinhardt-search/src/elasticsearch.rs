@@ -0,0 +1,444 @@
+//! Elasticsearch backend
+//!
+//! Text fields that are facets or sortable get a `raw` keyword sub-field,
+//! which aggregations and sorting use. Documents are written with the bulk
+//! API; a response reporting item errors fails the whole call.
+
+use crate::backend::{SearchBackend, SearchError, SearchResult};
+use crate::http::{join_url, send_json};
+use crate::query::{Filter, SearchHit, SearchQuery, SearchResults, facet_key};
+use crate::schema::{FieldKind, IndexSchema, SearchDocument, SearchField};
+use async_trait::async_trait;
+use serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
+
+/// Search backend talking to an Elasticsearch cluster
+///
+/// # Example
+///
+/// ```
+/// use reinhardt_search::elasticsearch::ElasticsearchBackend;
+///
+/// let backend = ElasticsearchBackend::new("http://localhost:9200").basic_auth("elastic", "secret");
+/// ```
+#[derive(Clone)]
+pub struct ElasticsearchBackend {
+	client: reqwest::Client,
+	url: String,
+	credentials: Option<(String, String)>,
+}
+
+impl ElasticsearchBackend {
+	/// Creates a backend for the cluster at `url`.
+	pub fn new(url: impl Into<String>) -> Self {
+		Self {
+			client: reqwest::Client::new(),
+			url: url.into(),
+			credentials: None,
+		}
+	}
+
+	/// Authenticates requests with HTTP basic authentication.
+	pub fn basic_auth(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+		self.credentials = Some((username.into(), password.into()));
+		self
+	}
+
+	fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+		let request = self.client.request(method, join_url(&self.url, path));
+		match &self.credentials {
+			Some((username, password)) => request.basic_auth(username, Some(password)),
+			None => request,
+		}
+	}
+
+	async fn bulk(&self, body: String) -> SearchResult<()> {
+		let response = send_json(
+			self.request(reqwest::Method::POST, "_bulk")
+				.header("Content-Type", "application/x-ndjson")
+				.body(body),
+		)
+		.await?;
+		if response["errors"].as_bool() == Some(true) {
+			return Err(SearchError::Backend {
+				status: 200,
+				message: bulk_error(&response),
+			});
+		}
+		Ok(())
+	}
+}
+
+fn bulk_error(response: &Value) -> String {
+	response["items"]
+		.as_array()
+		.into_iter()
+		.flatten()
+		.filter_map(|item| item.as_object()?.values().next()?.get("error"))
+		.map(|error| error.to_string())
+		.next()
+		.unwrap_or_else(|| "bulk request failed".to_string())
+}
+
+fn has_raw(field: &SearchField) -> bool {
+	field.kind == FieldKind::Text && (field.facet || field.sortable)
+}
+
+/// Returns the name of the un-analyzed variant of `field`, used for
+/// aggregations, sorting and exact filters.
+fn exact_field(schema: &IndexSchema, field: &str) -> String {
+	match schema.get(field) {
+		Some(f) if has_raw(f) => format!("{}.raw", field),
+		_ => field.to_string(),
+	}
+}
+
+/// Builds the index creation body of `schema`.
+pub fn index_mappings(schema: &IndexSchema) -> Value {
+	let mut properties = Map::new();
+	properties.insert(schema.primary_key.clone(), json!({ "type": "keyword" }));
+	for field in &schema.fields {
+		let kind = match field.kind {
+			FieldKind::Text => "text",
+			FieldKind::Keyword => "keyword",
+			FieldKind::Number => "double",
+			FieldKind::Boolean => "boolean",
+			FieldKind::Date => "date",
+		};
+		let mut mapping = json!({ "type": kind });
+		if has_raw(field) {
+			mapping["fields"] = json!({ "raw": { "type": "keyword" } });
+		}
+		properties.insert(field.name.clone(), mapping);
+	}
+	json!({ "mappings": { "properties": properties } })
+}
+
+/// Builds a bulk request body indexing `documents`.
+pub fn bulk_upsert_body(schema: &IndexSchema, documents: &[SearchDocument]) -> String {
+	let mut body = String::new();
+	for document in documents {
+		let id = document
+			.get(&schema.primary_key)
+			.map(facet_key)
+			.unwrap_or_default();
+		body.push_str(&json!({ "index": { "_index": schema.index, "_id": id } }).to_string());
+		body.push('\n');
+		body.push_str(&Value::Object(document.clone()).to_string());
+		body.push('\n');
+	}
+	body
+}
+
+/// Builds a bulk request body deleting `ids`.
+pub fn bulk_delete_body(schema: &IndexSchema, ids: &[String]) -> String {
+	ids.iter()
+		.map(|id| json!({ "delete": { "_index": schema.index, "_id": id } }).to_string() + "\n")
+		.collect()
+}
+
+/// Builds the body of a search request.
+pub fn search_body(schema: &IndexSchema, query: &SearchQuery) -> Value {
+	let must = if query.text.trim().is_empty() {
+		json!({ "match_all": {} })
+	} else {
+		let fields: Vec<String> = schema
+			.searchable_fields()
+			.into_iter()
+			.map(|field| format!("{}^{}", field.name, field.boost))
+			.collect();
+		json!({ "multi_match": { "query": query.text, "fields": fields } })
+	};
+	let filters: Vec<Value> = query
+		.filters
+		.iter()
+		.map(|filter| match filter {
+			Filter::Eq { field, value } => {
+				json!({ "term": { exact_field(schema, field): value } })
+			}
+			Filter::Range { field, min, max } => {
+				let mut bounds = Map::new();
+				if let Some(min) = min {
+					bounds.insert("gte".to_string(), json!(min));
+				}
+				if let Some(max) = max {
+					bounds.insert("lte".to_string(), json!(max));
+				}
+				json!({ "range": { field.clone(): bounds } })
+			}
+		})
+		.collect();
+	let aggs: Map<String, Value> = query
+		.facets
+		.iter()
+		.map(|field| {
+			(
+				field.clone(),
+				json!({ "terms": { "field": exact_field(schema, field) } }),
+			)
+		})
+		.collect();
+	let highlight: Map<String, Value> = query
+		.highlight
+		.iter()
+		.map(|field| (field.clone(), json!({})))
+		.collect();
+	let mut sort: Vec<Value> = query
+		.sort
+		.iter()
+		.map(|(field, order)| json!({ exact_field(schema, field): order.as_str() }))
+		.collect();
+	sort.push(json!("_score"));
+
+	json!({
+		"from": query.offset,
+		"size": query.limit,
+		"track_total_hits": true,
+		"query": { "bool": { "must": must, "filter": filters } },
+		"aggs": aggs,
+		"highlight": {
+			"pre_tags": ["<em>"],
+			"post_tags": ["</em>"],
+			"fields": highlight,
+		},
+		"sort": sort,
+	})
+}
+
+/// Converts a search response into [`SearchResults`].
+pub fn parse_results(body: &Value) -> SearchResults {
+	let hits = body["hits"]["hits"]
+		.as_array()
+		.into_iter()
+		.flatten()
+		.filter_map(|hit| {
+			let highlights = hit["highlight"]
+				.as_object()
+				.into_iter()
+				.flatten()
+				.map(|(field, fragments)| {
+					let fragments: Vec<&str> = fragments
+						.as_array()
+						.into_iter()
+						.flatten()
+						.filter_map(Value::as_str)
+						.collect();
+					(field.clone(), fragments.join(" … "))
+				})
+				.collect();
+			Some(SearchHit {
+				id: hit["_id"].as_str()?.to_string(),
+				score: hit["_score"].as_f64(),
+				document: hit["_source"].as_object().cloned().unwrap_or_default(),
+				highlights,
+			})
+		})
+		.collect::<Vec<_>>();
+	let total = body["hits"]["total"]["value"]
+		.as_u64()
+		.map_or(hits.len(), |total| total as usize);
+	let facets = body["aggregations"]
+		.as_object()
+		.into_iter()
+		.flatten()
+		.map(|(field, aggregation)| {
+			let counts = aggregation["buckets"]
+				.as_array()
+				.into_iter()
+				.flatten()
+				.map(|bucket| {
+					let key = bucket.get("key_as_string").unwrap_or(&bucket["key"]);
+					(
+						facet_key(key),
+						bucket["doc_count"].as_u64().unwrap_or(0) as usize,
+					)
+				})
+				.collect::<BTreeMap<_, _>>();
+			(field.clone(), counts)
+		})
+		.collect();
+	SearchResults {
+		hits,
+		total,
+		facets,
+	}
+}
+
+#[async_trait]
+impl SearchBackend for ElasticsearchBackend {
+	async fn ensure_index(&self, schema: &IndexSchema) -> SearchResult<()> {
+		let exists = self
+			.request(reqwest::Method::HEAD, &schema.index)
+			.send()
+			.await
+			.map_err(|e| SearchError::Http(e.to_string()))?
+			.status()
+			.is_success();
+		if exists {
+			// Adding fields to an existing mapping is allowed, changing
+			// them is not.
+			let properties = index_mappings(schema)["mappings"].clone();
+			send_json(
+				self.request(reqwest::Method::PUT, &format!("{}/_mapping", schema.index))
+					.json(&properties),
+			)
+			.await?;
+		} else {
+			send_json(
+				self.request(reqwest::Method::PUT, &schema.index)
+					.json(&index_mappings(schema)),
+			)
+			.await?;
+		}
+		Ok(())
+	}
+
+	async fn upsert(
+		&self,
+		schema: &IndexSchema,
+		documents: Vec<SearchDocument>,
+	) -> SearchResult<()> {
+		if documents.is_empty() {
+			return Ok(());
+		}
+		self.bulk(bulk_upsert_body(schema, &documents)).await
+	}
+
+	async fn delete(&self, schema: &IndexSchema, ids: Vec<String>) -> SearchResult<()> {
+		if ids.is_empty() {
+			return Ok(());
+		}
+		self.bulk(bulk_delete_body(schema, &ids)).await
+	}
+
+	async fn search(
+		&self,
+		schema: &IndexSchema,
+		query: &SearchQuery,
+	) -> SearchResult<SearchResults> {
+		query.validate(schema)?;
+		let body = send_json(
+			self.request(reqwest::Method::POST, &format!("{}/_search", schema.index))
+				.json(&search_body(schema, query)),
+		)
+		.await?;
+		Ok(parse_results(&body))
+	}
+
+	fn backend_name(&self) -> &str {
+		"elasticsearch"
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::query::SortOrder;
+	use rstest::rstest;
+
+	fn schema() -> IndexSchema {
+		IndexSchema::new("articles", "id")
+			.field(SearchField::new("title", FieldKind::Text).boost(2.0))
+			.field(SearchField::new("author", FieldKind::Text).facet())
+			.field(
+				SearchField::new("views", FieldKind::Number)
+					.filterable()
+					.sortable(),
+			)
+	}
+
+	#[rstest]
+	fn test_index_mappings_adds_raw_sub_field_to_faceted_text() {
+		// Act
+		let mappings = index_mappings(&schema());
+
+		// Assert
+		let properties = &mappings["mappings"]["properties"];
+		assert_eq!(properties["id"]["type"], "keyword");
+		assert!(properties["title"].get("fields").is_none());
+		assert_eq!(properties["author"]["fields"]["raw"]["type"], "keyword");
+		assert_eq!(properties["views"]["type"], "double");
+	}
+
+	#[rstest]
+	fn test_bulk_upsert_body_is_ndjson() {
+		// Arrange
+		let document = json!({ "id": "7", "title": "Rust" })
+			.as_object()
+			.unwrap()
+			.clone();
+
+		// Act
+		let body = bulk_upsert_body(&schema(), &[document]);
+
+		// Assert
+		let lines: Vec<Value> = body
+			.lines()
+			.map(|line| serde_json::from_str(line).unwrap())
+			.collect();
+		assert_eq!(
+			lines[0],
+			json!({ "index": { "_index": "articles", "_id": "7" } })
+		);
+		assert_eq!(lines[1]["title"], "Rust");
+		assert!(body.ends_with('\n'));
+	}
+
+	#[rstest]
+	fn test_search_body_boosts_fields_and_uses_raw_for_facets() {
+		// Arrange
+		let query = SearchQuery::new("rust")
+			.filter_eq("author", "Ferris")
+			.filter_range("views", Some(10.0), None)
+			.facet("author")
+			.sort("views", SortOrder::Desc);
+
+		// Act
+		let body = search_body(&schema(), &query);
+
+		// Assert
+		let bool_query = &body["query"]["bool"];
+		assert_eq!(
+			bool_query["must"]["multi_match"]["fields"],
+			json!(["title^2", "author^1"])
+		);
+		assert_eq!(
+			bool_query["filter"],
+			json!([
+				{ "term": { "author.raw": "Ferris" } },
+				{ "range": { "views": { "gte": 10.0 } } }
+			])
+		);
+		assert_eq!(body["aggs"]["author"]["terms"]["field"], "author.raw");
+		assert_eq!(body["sort"], json!([{ "views": "desc" }, "_score"]));
+	}
+
+	#[rstest]
+	fn test_parse_results_reads_hits_highlights_and_aggregations() {
+		// Arrange
+		let body = json!({
+			"hits": {
+				"total": { "value": 3, "relation": "eq" },
+				"hits": [{
+					"_id": "7",
+					"_score": 1.5,
+					"_source": { "id": "7", "title": "Async Rust" },
+					"highlight": { "title": ["Async <em>Rust</em>"] }
+				}]
+			},
+			"aggregations": {
+				"author": { "buckets": [{ "key": "Ferris", "doc_count": 2 }] }
+			}
+		});
+
+		// Act
+		let results = parse_results(&body);
+
+		// Assert
+		assert_eq!(results.total, 3);
+		assert_eq!(results.hits[0].id, "7");
+		assert_eq!(results.hits[0].score, Some(1.5));
+		assert_eq!(results.hits[0].highlights["title"], "Async <em>Rust</em>");
+		assert_eq!(results.facets["author"]["Ferris"], 2);
+	}
+}
@@ -0,0 +1,37 @@
+//! HTTP helpers shared by the remote backends
+
+use crate::backend::{SearchError, SearchResult};
+use serde_json::Value;
+
+/// Sends `request` and returns its JSON body, turning error statuses into
+/// [`SearchError::Backend`].
+pub(crate) async fn send_json(request: reqwest::RequestBuilder) -> SearchResult<Value> {
+	let response = request
+		.send()
+		.await
+		.map_err(|e| SearchError::Http(e.to_string()))?;
+	let status = response.status();
+	let body = response
+		.text()
+		.await
+		.map_err(|e| SearchError::Http(e.to_string()))?;
+	if !status.is_success() {
+		return Err(SearchError::Backend {
+			status: status.as_u16(),
+			message: body,
+		});
+	}
+	if body.is_empty() {
+		return Ok(Value::Null);
+	}
+	Ok(serde_json::from_str(&body)?)
+}
+
+/// Joins `base` and `path` with exactly one slash.
+pub(crate) fn join_url(base: &str, path: &str) -> String {
+	format!(
+		"{}/{}",
+		base.trim_end_matches('/'),
+		path.trim_start_matches('/')
+	)
+}
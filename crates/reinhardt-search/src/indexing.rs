@@ -0,0 +1,397 @@
+//! Keeping indexes in sync with models
+//!
+//! [`connect_index_updates`] connects `post_save` and `post_delete` receivers
+//! for a [`Searchable`] model. Each receiver buffers an index operation and
+//! enqueues an [`IndexUpdateTask`] on the task backend; the worker executing
+//! the task flushes the buffer to the installed [`SearchBackend`] in batches.
+//!
+//! Task backends only carry the task name, so the buffer lives in the process
+//! that saved the model: run the worker in the same process, or call
+//! [`flush_index_updates`] from a scheduled job.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! use reinhardt_search::indexing::{connect_index_updates, register_index_task, set_search_backend};
+//! use reinhardt_search::meilisearch::MeilisearchBackend;
+//!
+//! set_search_backend(Arc::new(MeilisearchBackend::new("http://localhost:7700")));
+//! register_index_task(&registry).await;
+//! connect_index_updates::<Article>(task_backend.clone());
+//! ```
+
+use crate::backend::{SearchBackend, SearchError, SearchResult};
+use crate::query::{SearchQuery, SearchResults};
+use crate::schema::{IndexSchema, SearchDocument, Searchable};
+use async_trait::async_trait;
+use parking_lot::{Mutex, RwLock};
+use reinhardt_core::signals::{SignalError, post_delete, post_save};
+use reinhardt_tasks::{
+	Task, TaskBackend, TaskError, TaskExecutor, TaskFactory, TaskId, TaskRegistry, TaskResult,
+};
+use std::sync::Arc;
+
+/// Name under which [`IndexUpdateTask`] is registered.
+pub const INDEX_UPDATE_TASK: &str = "search.index_updates";
+
+static SEARCH_BACKEND: RwLock<Option<Arc<dyn SearchBackend>>> = RwLock::new(None);
+static PENDING: Mutex<Vec<IndexOperation>> = Mutex::new(Vec::new());
+
+/// Install the backend used for indexing and [`search`]
+pub fn set_search_backend(backend: Arc<dyn SearchBackend>) {
+	*SEARCH_BACKEND.write() = Some(backend);
+}
+
+/// Remove the installed search backend
+pub fn clear_search_backend() {
+	*SEARCH_BACKEND.write() = None;
+}
+
+/// Get the installed search backend
+pub fn search_backend() -> Option<Arc<dyn SearchBackend>> {
+	SEARCH_BACKEND.read().clone()
+}
+
+fn installed_backend() -> SearchResult<Arc<dyn SearchBackend>> {
+	search_backend().ok_or(SearchError::NotConfigured)
+}
+
+/// A buffered change to an index
+#[derive(Debug, Clone, PartialEq)]
+enum IndexOperation {
+	Upsert {
+		schema: IndexSchema,
+		id: String,
+		document: SearchDocument,
+	},
+	Delete {
+		schema: IndexSchema,
+		id: String,
+	},
+}
+
+impl IndexOperation {
+	fn schema(&self) -> &IndexSchema {
+		match self {
+			Self::Upsert { schema, .. } | Self::Delete { schema, .. } => schema,
+		}
+	}
+
+	fn id(&self) -> &str {
+		match self {
+			Self::Upsert { id, .. } | Self::Delete { id, .. } => id,
+		}
+	}
+}
+
+/// Buffer an update of the document of `instance`
+pub fn queue_index_update<M: Searchable>(instance: &M) -> SearchResult<()> {
+	PENDING.lock().push(IndexOperation::Upsert {
+		schema: M::schema(),
+		id: instance.document_id(),
+		document: instance.to_document()?,
+	});
+	Ok(())
+}
+
+/// Buffer a removal of the document of `instance`
+pub fn queue_index_delete<M: Searchable>(instance: &M) {
+	PENDING.lock().push(IndexOperation::Delete {
+		schema: M::schema(),
+		id: instance.document_id(),
+	});
+}
+
+/// Number of buffered index operations
+pub fn pending_index_updates() -> usize {
+	PENDING.lock().len()
+}
+
+/// Send the buffered operations to the installed backend
+///
+/// Only the last operation per document is sent. On failure the operations
+/// not yet applied are put back in front of the buffer. Returns the number of
+/// documents updated or removed.
+pub async fn flush_index_updates() -> SearchResult<usize> {
+	let operations = std::mem::take(&mut *PENDING.lock());
+	if operations.is_empty() {
+		return Ok(0);
+	}
+	let backend = match installed_backend() {
+		Ok(backend) => backend,
+		Err(e) => {
+			requeue(operations);
+			return Err(e);
+		}
+	};
+
+	// Keep the last operation per document, in order of first appearance
+	// of its index.
+	let mut batches: Vec<(IndexSchema, Vec<IndexOperation>)> = Vec::new();
+	for operation in operations {
+		let position = match batches
+			.iter()
+			.position(|(schema, _)| schema.index == operation.schema().index)
+		{
+			Some(position) => position,
+			None => {
+				batches.push((operation.schema().clone(), Vec::new()));
+				batches.len() - 1
+			}
+		};
+		let batch = &mut batches[position].1;
+		batch.retain(|queued| queued.id() != operation.id());
+		batch.push(operation);
+	}
+
+	let mut applied = 0;
+	let mut remaining = batches.into_iter();
+	while let Some((schema, batch)) = remaining.next() {
+		let count = batch.len();
+		let (documents, ids): (Vec<_>, Vec<_>) = batch
+			.iter()
+			.cloned()
+			.partition(|operation| matches!(operation, IndexOperation::Upsert { .. }));
+		let documents = documents
+			.into_iter()
+			.filter_map(|operation| match operation {
+				IndexOperation::Upsert { document, .. } => Some(document),
+				IndexOperation::Delete { .. } => None,
+			})
+			.collect();
+		let ids = ids
+			.into_iter()
+			.map(|operation| operation.id().to_string())
+			.collect();
+		let result = match backend.upsert(&schema, documents).await {
+			Ok(()) => backend.delete(&schema, ids).await,
+			Err(e) => Err(e),
+		};
+		if let Err(e) = result {
+			requeue(
+				batch
+					.into_iter()
+					.chain(remaining.flat_map(|(_, batch)| batch))
+					.collect(),
+			);
+			return Err(e);
+		}
+		applied += count;
+	}
+	Ok(applied)
+}
+
+fn requeue(operations: Vec<IndexOperation>) {
+	let mut pending = PENDING.lock();
+	let newer = std::mem::replace(&mut *pending, operations);
+	pending.extend(newer);
+}
+
+/// Task flushing the buffered index operations
+pub struct IndexUpdateTask {
+	id: TaskId,
+}
+
+impl IndexUpdateTask {
+	/// Create a new flush task
+	pub fn new() -> Self {
+		Self { id: TaskId::new() }
+	}
+}
+
+impl Default for IndexUpdateTask {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Task for IndexUpdateTask {
+	fn id(&self) -> TaskId {
+		self.id
+	}
+
+	fn name(&self) -> &str {
+		INDEX_UPDATE_TASK
+	}
+}
+
+#[async_trait]
+impl TaskExecutor for IndexUpdateTask {
+	async fn execute(&self) -> TaskResult<()> {
+		flush_index_updates()
+			.await
+			.map(|_| ())
+			.map_err(|e| TaskError::ExecutionFailed(e.to_string()))
+	}
+}
+
+/// Factory recreating [`IndexUpdateTask`]s in workers
+pub struct IndexUpdateTaskFactory;
+
+#[async_trait]
+impl TaskFactory for IndexUpdateTaskFactory {
+	async fn create(&self, _data: &str) -> TaskResult<Box<dyn TaskExecutor>> {
+		Ok(Box::new(IndexUpdateTask::new()))
+	}
+}
+
+/// Register [`IndexUpdateTask`] with a worker's task registry
+pub async fn register_index_task(registry: &TaskRegistry) {
+	registry
+		.register(
+			INDEX_UPDATE_TASK.to_string(),
+			Arc::new(IndexUpdateTaskFactory),
+		)
+		.await;
+}
+
+/// Keep the index of `M` in sync with saves and deletes
+///
+/// Index operations are buffered and flushed by an [`IndexUpdateTask`]
+/// enqueued on `tasks` for every change.
+pub fn connect_index_updates<M: Searchable>(tasks: Arc<dyn TaskBackend>) {
+	let save_tasks = Arc::clone(&tasks);
+	post_save::<M>().connect(move |instance: Arc<M>| {
+		let tasks = Arc::clone(&save_tasks);
+		async move {
+			queue_index_update(instance.as_ref()).map_err(|e| SignalError::new(e.to_string()))?;
+			enqueue_flush(tasks.as_ref()).await
+		}
+	});
+	post_delete::<M>().connect(move |instance: Arc<M>| {
+		let tasks = Arc::clone(&tasks);
+		async move {
+			queue_index_delete(instance.as_ref());
+			enqueue_flush(tasks.as_ref()).await
+		}
+	});
+}
+
+async fn enqueue_flush(tasks: &dyn TaskBackend) -> Result<(), SignalError> {
+	tasks
+		.enqueue(Box::new(IndexUpdateTask::new()))
+		.await
+		.map(|_| ())
+		.map_err(|e| SignalError::new(e.to_string()))
+}
+
+/// Create or update the index of `M` on the installed backend
+pub async fn ensure_index<M: Searchable>() -> SearchResult<()> {
+	installed_backend()?.ensure_index(&M::schema()).await
+}
+
+/// Index `instances` right away, bypassing the buffer
+pub async fn reindex<M: Searchable>(instances: &[M]) -> SearchResult<usize> {
+	let documents = instances
+		.iter()
+		.map(Searchable::to_document)
+		.collect::<SearchResult<Vec<_>>>()?;
+	let count = documents.len();
+	installed_backend()?.upsert(&M::schema(), documents).await?;
+	Ok(count)
+}
+
+/// Run `query` against the index of `M`
+pub async fn search<M: Searchable>(query: &SearchQuery) -> SearchResult<SearchResults> {
+	installed_backend()?.search(&M::schema(), query).await
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::memory::InMemorySearchBackend;
+	use crate::schema::{FieldKind, SearchField};
+	use reinhardt_tasks::DummyBackend;
+	use rstest::rstest;
+	use serde::Serialize;
+	use serial_test::serial;
+
+	#[derive(Serialize)]
+	struct Article {
+		id: i64,
+		title: String,
+	}
+
+	impl Searchable for Article {
+		fn schema() -> IndexSchema {
+			IndexSchema::new("indexing_articles", "id")
+				.field(SearchField::new("title", FieldKind::Text))
+		}
+
+		fn document_id(&self) -> String {
+			self.id.to_string()
+		}
+	}
+
+	fn article(id: i64, title: &str) -> Article {
+		Article {
+			id,
+			title: title.to_string(),
+		}
+	}
+
+	#[rstest]
+	#[tokio::test]
+	#[serial(search_index)]
+	async fn test_flush_keeps_last_operation_per_document() {
+		// Arrange
+		let backend = Arc::new(InMemorySearchBackend::new());
+		set_search_backend(backend.clone());
+		queue_index_update(&article(1, "Draft")).unwrap();
+		queue_index_update(&article(2, "Gone")).unwrap();
+		queue_index_update(&article(1, "Published")).unwrap();
+		queue_index_delete(&article(2, "Gone"));
+
+		// Act
+		let applied = flush_index_updates().await.unwrap();
+
+		// Assert
+		assert_eq!(applied, 2);
+		assert_eq!(pending_index_updates(), 0);
+		let document = backend.get("indexing_articles", "1").unwrap();
+		assert_eq!(document["title"], "Published");
+		assert!(backend.get("indexing_articles", "2").is_none());
+		clear_search_backend();
+	}
+
+	#[rstest]
+	#[tokio::test]
+	#[serial(search_index)]
+	async fn test_flush_without_backend_keeps_operations() {
+		// Arrange
+		clear_search_backend();
+		queue_index_update(&article(3, "Kept")).unwrap();
+
+		// Act
+		let result = flush_index_updates().await;
+
+		// Assert
+		assert!(matches!(result, Err(SearchError::NotConfigured)));
+		assert_eq!(pending_index_updates(), 1);
+		PENDING.lock().clear();
+	}
+
+	#[rstest]
+	#[tokio::test]
+	#[serial(search_index)]
+	async fn test_post_save_buffers_update_and_task_flushes_it() {
+		// Arrange
+		let backend = Arc::new(InMemorySearchBackend::new());
+		set_search_backend(backend.clone());
+		connect_index_updates::<Article>(Arc::new(DummyBackend::new()));
+
+		// Act
+		post_save::<Article>()
+			.send(article(4, "Saved"))
+			.await
+			.unwrap();
+		let buffered = pending_index_updates();
+		IndexUpdateTask::new().execute().await.unwrap();
+
+		// Assert
+		assert_eq!(buffered, 1);
+		assert!(backend.get("indexing_articles", "4").is_some());
+		post_save::<Article>().disconnect_all();
+		clear_search_backend();
+	}
+}
@@ -0,0 +1,56 @@
+//! # Reinhardt Search
+//!
+//! Full-text search for Reinhardt models with pluggable engines.
+//!
+//! ## Features
+//!
+//! - **Schemas**: `#[derive(Searchable)]` describes the index of a model:
+//!   which fields are searched, faceted, filtered and sorted, and how
+//!   matches are weighted
+//! - **Index Updates**: [`indexing::connect_index_updates`] keeps an index
+//!   in sync with `post_save` / `post_delete` through the task queue
+//! - **Queries**: [`SearchQuery`] with filters, facets, highlighting,
+//!   sorting and paging
+//! - **Backends**: [`InMemorySearchBackend`] for tests, and Meilisearch
+//!   (`meilisearch` feature) and Elasticsearch (`elasticsearch` feature)
+//!
+//! ## Example
+//!
+//! ```ignore
+//! use reinhardt_search::{SearchQuery, Searchable, indexing};
+//!
+//! #[derive(Serialize, Searchable)]
+//! #[searchable(index = "articles")]
+//! pub struct Article {
+//!     pub id: i64,
+//!     #[search(text, boost = 2.0)]
+//!     pub title: String,
+//!     #[search(text)]
+//!     pub body: String,
+//!     #[search(keyword, facet)]
+//!     pub category: String,
+//! }
+//!
+//! let results = indexing::search::<Article>(
+//!     &SearchQuery::new("async rust").facet("category").highlight("title"),
+//! )
+//! .await?;
+//! ```
+
+pub mod backend;
+#[cfg(feature = "elasticsearch")]
+pub mod elasticsearch;
+#[cfg(any(feature = "meilisearch", feature = "elasticsearch"))]
+mod http;
+pub mod indexing;
+#[cfg(feature = "meilisearch")]
+pub mod meilisearch;
+pub mod memory;
+pub mod query;
+pub mod schema;
+
+pub use backend::{SearchBackend, SearchError, SearchResult};
+pub use memory::InMemorySearchBackend;
+pub use query::{Filter, SearchHit, SearchQuery, SearchResults, SortOrder};
+pub use reinhardt_macros::Searchable;
+pub use schema::{FieldKind, IndexSchema, SearchDocument, SearchField, Searchable};
@@ -0,0 +1,326 @@
+//! Meilisearch backend
+//!
+//! Searchable fields are ranked in boost order, since Meilisearch weighs
+//! attributes by their position rather than by numeric boosts. Facet,
+//! filterable and sortable fields are configured by
+//! [`SearchBackend::ensure_index`].
+
+use crate::backend::{SearchBackend, SearchResult};
+use crate::http::{join_url, send_json};
+use crate::query::{Filter, SearchHit, SearchQuery, SearchResults, facet_key};
+use crate::schema::{IndexSchema, SearchDocument};
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+
+/// Search backend talking to a Meilisearch server
+///
+/// # Example
+///
+/// ```
+/// use reinhardt_search::meilisearch::MeilisearchBackend;
+///
+/// let backend = MeilisearchBackend::new("http://localhost:7700").api_key("master-key");
+/// ```
+#[derive(Clone)]
+pub struct MeilisearchBackend {
+	client: reqwest::Client,
+	url: String,
+	api_key: Option<String>,
+}
+
+impl MeilisearchBackend {
+	/// Creates a backend for the server at `url`.
+	pub fn new(url: impl Into<String>) -> Self {
+		Self {
+			client: reqwest::Client::new(),
+			url: url.into(),
+			api_key: None,
+		}
+	}
+
+	/// Authenticates requests with `key`.
+	pub fn api_key(mut self, key: impl Into<String>) -> Self {
+		self.api_key = Some(key.into());
+		self
+	}
+
+	fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+		let request = self.client.request(method, join_url(&self.url, path));
+		match &self.api_key {
+			Some(key) => request.bearer_auth(key),
+			None => request,
+		}
+	}
+}
+
+/// Builds the index settings of `schema`.
+pub fn index_settings(schema: &IndexSchema) -> Value {
+	let names = |fields: Vec<&crate::schema::SearchField>| {
+		fields
+			.into_iter()
+			.map(|field| field.name.clone())
+			.collect::<Vec<_>>()
+	};
+	json!({
+		"searchableAttributes": names(schema.searchable_fields()),
+		"filterableAttributes": names(schema.filterable_fields().collect()),
+		"sortableAttributes": names(schema.sortable_fields().collect()),
+	})
+}
+
+fn filter_value(value: &Value) -> String {
+	match value {
+		Value::String(s) => format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\"")),
+		other => other.to_string(),
+	}
+}
+
+/// Builds the body of a search request.
+pub fn search_body(query: &SearchQuery) -> Value {
+	let filters: Vec<String> = query
+		.filters
+		.iter()
+		.flat_map(|filter| match filter {
+			Filter::Eq { field, value } => vec![format!("{} = {}", field, filter_value(value))],
+			Filter::Range { field, min, max } => min
+				.map(|min| format!("{} >= {}", field, min))
+				.into_iter()
+				.chain(max.map(|max| format!("{} <= {}", field, max)))
+				.collect(),
+		})
+		.collect();
+	let sort: Vec<String> = query
+		.sort
+		.iter()
+		.map(|(field, order)| format!("{}:{}", field, order.as_str()))
+		.collect();
+	json!({
+		"q": query.text,
+		"offset": query.offset,
+		"limit": query.limit,
+		"filter": filters,
+		"facets": query.facets,
+		"attributesToHighlight": query.highlight,
+		"highlightPreTag": "<em>",
+		"highlightPostTag": "</em>",
+		"sort": sort,
+		"showRankingScore": true,
+	})
+}
+
+/// Converts a search response into [`SearchResults`].
+pub fn parse_results(schema: &IndexSchema, query: &SearchQuery, body: &Value) -> SearchResults {
+	let hits = body["hits"]
+		.as_array()
+		.into_iter()
+		.flatten()
+		.filter_map(|hit| {
+			let mut document = hit.as_object()?.clone();
+			let formatted = document.remove("_formatted");
+			let score = document.remove("_rankingScore").and_then(|s| s.as_f64());
+			let id = document.get(&schema.primary_key).map(facet_key)?;
+			let highlights = query
+				.highlight
+				.iter()
+				.filter_map(|field| {
+					let value = formatted.as_ref()?.get(field)?.as_str()?;
+					value
+						.contains("<em>")
+						.then(|| (field.clone(), value.to_string()))
+				})
+				.collect();
+			Some(SearchHit {
+				id,
+				score,
+				document,
+				highlights,
+			})
+		})
+		.collect::<Vec<_>>();
+	let total = body["estimatedTotalHits"]
+		.as_u64()
+		.or_else(|| body["totalHits"].as_u64())
+		.map_or(hits.len(), |total| total as usize);
+	let facets = body["facetDistribution"]
+		.as_object()
+		.into_iter()
+		.flatten()
+		.map(|(field, counts)| {
+			let counts = counts
+				.as_object()
+				.into_iter()
+				.flatten()
+				.map(|(value, count)| (value.clone(), count.as_u64().unwrap_or(0) as usize))
+				.collect::<BTreeMap<_, _>>();
+			(field.clone(), counts)
+		})
+		.collect();
+	SearchResults {
+		hits,
+		total,
+		facets,
+	}
+}
+
+#[async_trait]
+impl SearchBackend for MeilisearchBackend {
+	async fn ensure_index(&self, schema: &IndexSchema) -> SearchResult<()> {
+		// Creation is queued by the server and fails there, not here, when
+		// the index already exists.
+		send_json(
+			self.request(reqwest::Method::POST, "indexes")
+				.json(&json!({ "uid": schema.index, "primaryKey": schema.primary_key })),
+		)
+		.await?;
+		send_json(
+			self.request(
+				reqwest::Method::PATCH,
+				&format!("indexes/{}/settings", schema.index),
+			)
+			.json(&index_settings(schema)),
+		)
+		.await?;
+		Ok(())
+	}
+
+	async fn upsert(
+		&self,
+		schema: &IndexSchema,
+		documents: Vec<SearchDocument>,
+	) -> SearchResult<()> {
+		if documents.is_empty() {
+			return Ok(());
+		}
+		send_json(
+			self.request(
+				reqwest::Method::POST,
+				&format!(
+					"indexes/{}/documents?primaryKey={}",
+					schema.index, schema.primary_key
+				),
+			)
+			.json(&documents),
+		)
+		.await?;
+		Ok(())
+	}
+
+	async fn delete(&self, schema: &IndexSchema, ids: Vec<String>) -> SearchResult<()> {
+		if ids.is_empty() {
+			return Ok(());
+		}
+		send_json(
+			self.request(
+				reqwest::Method::POST,
+				&format!("indexes/{}/documents/delete-batch", schema.index),
+			)
+			.json(&ids),
+		)
+		.await?;
+		Ok(())
+	}
+
+	async fn search(
+		&self,
+		schema: &IndexSchema,
+		query: &SearchQuery,
+	) -> SearchResult<SearchResults> {
+		query.validate(schema)?;
+		let body = send_json(
+			self.request(
+				reqwest::Method::POST,
+				&format!("indexes/{}/search", schema.index),
+			)
+			.json(&search_body(query)),
+		)
+		.await?;
+		Ok(parse_results(schema, query, &body))
+	}
+
+	fn backend_name(&self) -> &str {
+		"meilisearch"
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::query::SortOrder;
+	use crate::schema::{FieldKind, SearchField};
+	use rstest::rstest;
+
+	fn schema() -> IndexSchema {
+		IndexSchema::new("articles", "id")
+			.field(SearchField::new("body", FieldKind::Text))
+			.field(SearchField::new("title", FieldKind::Text).boost(2.0))
+			.field(SearchField::new("category", FieldKind::Keyword).facet())
+			.field(SearchField::new("views", FieldKind::Number).sortable())
+	}
+
+	#[rstest]
+	fn test_index_settings_orders_attributes_by_boost() {
+		// Act
+		let settings = index_settings(&schema());
+
+		// Assert
+		assert_eq!(
+			settings["searchableAttributes"],
+			json!(["title", "body", "category"])
+		);
+		assert_eq!(settings["filterableAttributes"], json!(["category"]));
+		assert_eq!(settings["sortableAttributes"], json!(["views"]));
+	}
+
+	#[rstest]
+	fn test_search_body_renders_filters_and_sort() {
+		// Arrange
+		let query = SearchQuery::new("rust")
+			.filter_eq("category", "say \"hi\"")
+			.filter_range("views", Some(10.0), Some(20.5))
+			.facet("category")
+			.sort("views", SortOrder::Desc);
+
+		// Act
+		let body = search_body(&query);
+
+		// Assert
+		assert_eq!(
+			body["filter"],
+			json!([
+				"category = \"say \\\"hi\\\"\"",
+				"views >= 10",
+				"views <= 20.5"
+			])
+		);
+		assert_eq!(body["sort"], json!(["views:desc"]));
+		assert_eq!(body["facets"], json!(["category"]));
+	}
+
+	#[rstest]
+	fn test_parse_results_reads_hits_highlights_and_facets() {
+		// Arrange
+		let query = SearchQuery::new("rust").highlight("title");
+		let body = json!({
+			"hits": [{
+				"id": "1",
+				"title": "Async Rust",
+				"_rankingScore": 0.9,
+				"_formatted": { "id": "1", "title": "Async <em>Rust</em>" }
+			}],
+			"estimatedTotalHits": 12,
+			"facetDistribution": { "category": { "guides": 8, "news": 4 } }
+		});
+
+		// Act
+		let results = parse_results(&schema(), &query, &body);
+
+		// Assert
+		assert_eq!(results.total, 12);
+		assert_eq!(results.hits[0].id, "1");
+		assert_eq!(results.hits[0].score, Some(0.9));
+		assert_eq!(results.hits[0].highlights["title"], "Async <em>Rust</em>");
+		assert!(!results.hits[0].document.contains_key("_formatted"));
+		assert_eq!(results.facets["category"]["news"], 4);
+	}
+}
@@ -0,0 +1,355 @@
+//! In-process search backend for tests and development
+
+use crate::backend::{SearchBackend, SearchResult};
+use crate::query::{Filter, SearchHit, SearchQuery, SearchResults, SortOrder, facet_key};
+use crate::schema::{IndexSchema, SearchDocument};
+use async_trait::async_trait;
+use parking_lot::RwLock;
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+
+/// Search backend keeping documents in memory
+///
+/// Matching is a case-insensitive comparison of whitespace separated terms;
+/// there is no stemming or typo tolerance.
+#[derive(Default)]
+pub struct InMemorySearchBackend {
+	indexes: RwLock<HashMap<String, BTreeMap<String, SearchDocument>>>,
+}
+
+impl InMemorySearchBackend {
+	/// Creates an empty backend.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the number of documents in `index`.
+	pub fn document_count(&self, index: &str) -> usize {
+		self.indexes.read().get(index).map_or(0, BTreeMap::len)
+	}
+
+	/// Returns the document `id` of `index`.
+	pub fn get(&self, index: &str, id: &str) -> Option<SearchDocument> {
+		self.indexes.read().get(index)?.get(id).cloned()
+	}
+}
+
+fn terms(text: &str) -> Vec<String> {
+	text.split_whitespace().map(normalize).collect()
+}
+
+fn normalize(word: &str) -> String {
+	word.trim_matches(|c: char| !c.is_alphanumeric())
+		.to_lowercase()
+}
+
+fn field_text(value: &Value) -> String {
+	match value {
+		Value::String(s) => s.clone(),
+		Value::Array(items) => items.iter().map(field_text).collect::<Vec<_>>().join(" "),
+		Value::Null => String::new(),
+		other => other.to_string(),
+	}
+}
+
+fn score(schema: &IndexSchema, document: &SearchDocument, query_terms: &[String]) -> f64 {
+	schema
+		.searchable_fields()
+		.into_iter()
+		.map(|field| {
+			let words = document
+				.get(&field.name)
+				.map_or_else(Vec::new, |value| terms(&field_text(value)));
+			let matches = query_terms
+				.iter()
+				.filter(|term| words.contains(term))
+				.count();
+			matches as f64 * f64::from(field.boost)
+		})
+		.sum()
+}
+
+fn matches_filter(document: &SearchDocument, filter: &Filter) -> bool {
+	let value = document.get(filter.field());
+	match filter {
+		Filter::Eq {
+			value: expected, ..
+		} => match value {
+			Some(Value::Array(items)) => items.contains(expected),
+			Some(value) => value == expected,
+			None => false,
+		},
+		Filter::Range { min, max, .. } => match value.and_then(Value::as_f64) {
+			Some(n) => min.is_none_or(|min| n >= min) && max.is_none_or(|max| n <= max),
+			None => false,
+		},
+	}
+}
+
+fn compare_values(a: Option<&Value>, b: Option<&Value>) -> Ordering {
+	match (a, b) {
+		(Some(Value::Number(a)), Some(Value::Number(b))) => a
+			.as_f64()
+			.unwrap_or_default()
+			.total_cmp(&b.as_f64().unwrap_or_default()),
+		(Some(a), Some(b)) => field_text(a).cmp(&field_text(b)),
+		(Some(_), None) => Ordering::Less,
+		(None, Some(_)) => Ordering::Greater,
+		(None, None) => Ordering::Equal,
+	}
+}
+
+fn highlight(text: &str, query_terms: &[String]) -> Option<String> {
+	let mut found = false;
+	let highlighted = text
+		.split(' ')
+		.map(|word| {
+			if !word.is_empty() && query_terms.contains(&normalize(word)) {
+				found = true;
+				format!("<em>{}</em>", word)
+			} else {
+				word.to_string()
+			}
+		})
+		.collect::<Vec<_>>()
+		.join(" ");
+	found.then_some(highlighted)
+}
+
+#[async_trait]
+impl SearchBackend for InMemorySearchBackend {
+	async fn ensure_index(&self, schema: &IndexSchema) -> SearchResult<()> {
+		self.indexes
+			.write()
+			.entry(schema.index.clone())
+			.or_default();
+		Ok(())
+	}
+
+	async fn upsert(
+		&self,
+		schema: &IndexSchema,
+		documents: Vec<SearchDocument>,
+	) -> SearchResult<()> {
+		let mut indexes = self.indexes.write();
+		let index = indexes.entry(schema.index.clone()).or_default();
+		for document in documents {
+			let id = document
+				.get(&schema.primary_key)
+				.map(facet_key)
+				.unwrap_or_default();
+			index.insert(id, document);
+		}
+		Ok(())
+	}
+
+	async fn delete(&self, schema: &IndexSchema, ids: Vec<String>) -> SearchResult<()> {
+		if let Some(index) = self.indexes.write().get_mut(&schema.index) {
+			for id in ids {
+				index.remove(&id);
+			}
+		}
+		Ok(())
+	}
+
+	async fn search(
+		&self,
+		schema: &IndexSchema,
+		query: &SearchQuery,
+	) -> SearchResult<SearchResults> {
+		query.validate(schema)?;
+		let query_terms = terms(&query.text);
+		let indexes = self.indexes.read();
+		let Some(index) = indexes.get(&schema.index) else {
+			return Ok(SearchResults::default());
+		};
+
+		let mut matched: Vec<(String, f64, &SearchDocument)> = index
+			.iter()
+			.filter(|(_, document)| query.filters.iter().all(|f| matches_filter(document, f)))
+			.map(|(id, document)| (id.clone(), score(schema, document, &query_terms), document))
+			.filter(|(_, score, _)| query_terms.is_empty() || *score > 0.0)
+			.collect();
+
+		let mut facets = BTreeMap::new();
+		for field in &query.facets {
+			let mut counts = BTreeMap::new();
+			for (_, _, document) in &matched {
+				let values = match document.get(field) {
+					Some(Value::Array(items)) => items.iter().collect(),
+					Some(Value::Null) | None => Vec::new(),
+					Some(value) => vec![value],
+				};
+				for value in values {
+					*counts.entry(facet_key(value)).or_insert(0) += 1;
+				}
+			}
+			facets.insert(field.clone(), counts);
+		}
+
+		matched.sort_by(|a, b| {
+			query
+				.sort
+				.iter()
+				.map(|(field, order)| {
+					let ordering = compare_values(a.2.get(field), b.2.get(field));
+					match order {
+						SortOrder::Asc => ordering,
+						SortOrder::Desc => ordering.reverse(),
+					}
+				})
+				.find(|ordering| ordering.is_ne())
+				.unwrap_or_else(|| b.1.total_cmp(&a.1))
+		});
+
+		let total = matched.len();
+		let hits = matched
+			.into_iter()
+			.skip(query.offset)
+			.take(query.limit)
+			.map(|(id, score, document)| {
+				let highlights = query
+					.highlight
+					.iter()
+					.filter_map(|field| {
+						let text = field_text(document.get(field)?);
+						Some((field.clone(), highlight(&text, &query_terms)?))
+					})
+					.collect();
+				SearchHit {
+					id,
+					score: Some(score),
+					document: document.clone(),
+					highlights,
+				}
+			})
+			.collect();
+
+		Ok(SearchResults {
+			hits,
+			total,
+			facets,
+		})
+	}
+
+	fn backend_name(&self) -> &str {
+		"memory"
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::schema::{FieldKind, SearchField};
+	use rstest::rstest;
+	use serde_json::json;
+
+	fn schema() -> IndexSchema {
+		IndexSchema::new("articles", "id")
+			.field(SearchField::new("title", FieldKind::Text).boost(2.0))
+			.field(SearchField::new("body", FieldKind::Text))
+			.field(SearchField::new("category", FieldKind::Keyword).facet())
+			.field(
+				SearchField::new("views", FieldKind::Number)
+					.filterable()
+					.sortable(),
+			)
+	}
+
+	fn document(id: &str, title: &str, body: &str, category: &str, views: i64) -> SearchDocument {
+		json!({ "id": id, "title": title, "body": body, "category": category, "views": views })
+			.as_object()
+			.unwrap()
+			.clone()
+	}
+
+	async fn backend() -> InMemorySearchBackend {
+		let backend = InMemorySearchBackend::new();
+		backend
+			.upsert(
+				&schema(),
+				vec![
+					document("1", "Async Rust", "Futures explained", "guides", 10),
+					document("2", "Rust macros", "Write derive macros", "guides", 50),
+					document("3", "Release notes", "Async traits in Rust", "news", 30),
+				],
+			)
+			.await
+			.unwrap();
+		backend
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_search_ranks_boosted_fields_higher() {
+		// Arrange
+		let backend = backend().await;
+
+		// Act
+		let results = backend
+			.search(&schema(), &SearchQuery::new("async"))
+			.await
+			.unwrap();
+
+		// Assert
+		let ids: Vec<_> = results.hits.iter().map(|hit| hit.id.as_str()).collect();
+		assert_eq!(ids, ["1", "3"]);
+		assert_eq!(results.total, 2);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_search_counts_facets_and_highlights() {
+		// Arrange
+		let backend = backend().await;
+		let query = SearchQuery::new("rust")
+			.facet("category")
+			.highlight("title");
+
+		// Act
+		let results = backend.search(&schema(), &query).await.unwrap();
+
+		// Assert
+		assert_eq!(results.facets["category"]["guides"], 2);
+		assert_eq!(results.facets["category"]["news"], 1);
+		let first = results.hits.iter().find(|hit| hit.id == "2").unwrap();
+		assert_eq!(first.highlights["title"], "<em>Rust</em> macros");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_search_filters_sorts_and_pages() {
+		// Arrange
+		let backend = backend().await;
+		let query = SearchQuery::new("")
+			.filter_range("views", Some(20.0), None)
+			.sort("views", SortOrder::Desc)
+			.limit(1);
+
+		// Act
+		let results = backend.search(&schema(), &query).await.unwrap();
+
+		// Assert
+		assert_eq!(results.total, 2);
+		assert_eq!(results.hits.len(), 1);
+		assert_eq!(results.hits[0].id, "2");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_delete_removes_documents() {
+		// Arrange
+		let backend = backend().await;
+
+		// Act
+		backend
+			.delete(&schema(), vec!["1".to_string()])
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(backend.document_count("articles"), 2);
+		assert!(backend.get("articles", "1").is_none());
+	}
+}
@@ -0,0 +1,289 @@
+//! Search queries and results
+
+use crate::backend::{SearchError, SearchResult};
+use crate::schema::{IndexSchema, SearchDocument};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+/// Results per page when no limit is set.
+pub const DEFAULT_LIMIT: usize = 20;
+
+/// A filter on a field
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Filter {
+	/// The field equals the value, or contains it when it is an array.
+	Eq {
+		/// Filtered field
+		field: String,
+		/// Expected value
+		value: Value,
+	},
+	/// The field lies within the inclusive bounds.
+	Range {
+		/// Filtered field
+		field: String,
+		/// Lower bound
+		min: Option<f64>,
+		/// Upper bound
+		max: Option<f64>,
+	},
+}
+
+impl Filter {
+	/// Returns the filtered field.
+	pub fn field(&self) -> &str {
+		match self {
+			Self::Eq { field, .. } | Self::Range { field, .. } => field,
+		}
+	}
+}
+
+/// Sort direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+	/// Smallest first
+	Asc,
+	/// Largest first
+	Desc,
+}
+
+impl SortOrder {
+	/// Returns `"asc"` or `"desc"`.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Asc => "asc",
+			Self::Desc => "desc",
+		}
+	}
+}
+
+/// A full-text query with filters, facets, highlighting and sorting
+///
+/// # Example
+///
+/// ```
+/// use reinhardt_search::{SearchQuery, SortOrder};
+///
+/// let query = SearchQuery::new("rust async")
+///     .filter_eq("category", "guides")
+///     .filter_range("views", Some(100.0), None)
+///     .facet("category")
+///     .highlight("title")
+///     .sort("published_at", SortOrder::Desc)
+///     .limit(10);
+/// assert_eq!(query.limit, 10);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchQuery {
+	/// Query text; empty matches every document
+	pub text: String,
+	/// Filters all hits must satisfy
+	pub filters: Vec<Filter>,
+	/// Fields to count values of
+	pub facets: Vec<String>,
+	/// Fields to highlight matches in
+	pub highlight: Vec<String>,
+	/// Sort keys, relevance is used when empty
+	pub sort: Vec<(String, SortOrder)>,
+	/// Hits to skip
+	pub offset: usize,
+	/// Maximum number of hits
+	pub limit: usize,
+}
+
+impl SearchQuery {
+	/// Creates a query for `text`.
+	pub fn new(text: impl Into<String>) -> Self {
+		Self {
+			text: text.into(),
+			filters: Vec::new(),
+			facets: Vec::new(),
+			highlight: Vec::new(),
+			sort: Vec::new(),
+			offset: 0,
+			limit: DEFAULT_LIMIT,
+		}
+	}
+
+	/// Keeps hits whose `field` equals `value`.
+	pub fn filter_eq(mut self, field: impl Into<String>, value: impl Into<Value>) -> Self {
+		self.filters.push(Filter::Eq {
+			field: field.into(),
+			value: value.into(),
+		});
+		self
+	}
+
+	/// Keeps hits whose `field` lies within `min..=max`.
+	pub fn filter_range(
+		mut self,
+		field: impl Into<String>,
+		min: Option<f64>,
+		max: Option<f64>,
+	) -> Self {
+		self.filters.push(Filter::Range {
+			field: field.into(),
+			min,
+			max,
+		});
+		self
+	}
+
+	/// Requests value counts of `field` over all hits.
+	pub fn facet(mut self, field: impl Into<String>) -> Self {
+		self.facets.push(field.into());
+		self
+	}
+
+	/// Requests highlighted matches in `field`.
+	pub fn highlight(mut self, field: impl Into<String>) -> Self {
+		self.highlight.push(field.into());
+		self
+	}
+
+	/// Sorts hits by `field`.
+	pub fn sort(mut self, field: impl Into<String>, order: SortOrder) -> Self {
+		self.sort.push((field.into(), order));
+		self
+	}
+
+	/// Skips the first `offset` hits.
+	pub fn offset(mut self, offset: usize) -> Self {
+		self.offset = offset;
+		self
+	}
+
+	/// Returns at most `limit` hits.
+	pub fn limit(mut self, limit: usize) -> Self {
+		self.limit = limit;
+		self
+	}
+
+	/// Checks that filters, facets and sort keys use fields the schema
+	/// allows them on.
+	pub fn validate(&self, schema: &IndexSchema) -> SearchResult<()> {
+		let check = |field: &str, allowed: bool, usage: &str| {
+			if allowed {
+				Ok(())
+			} else {
+				Err(SearchError::InvalidQuery(format!(
+					"field `{}` of index `{}` is not {}",
+					field, schema.index, usage
+				)))
+			}
+		};
+		for filter in &self.filters {
+			let field = filter.field();
+			check(
+				field,
+				schema.get(field).is_some_and(|f| f.filterable),
+				"filterable",
+			)?;
+		}
+		for field in &self.facets {
+			check(field, schema.get(field).is_some_and(|f| f.facet), "a facet")?;
+		}
+		for (field, _) in &self.sort {
+			check(
+				field,
+				schema.get(field).is_some_and(|f| f.sortable),
+				"sortable",
+			)?;
+		}
+		for field in &self.highlight {
+			check(
+				field,
+				schema.get(field).is_some_and(|f| f.kind.is_searchable()),
+				"searchable",
+			)?;
+		}
+		Ok(())
+	}
+}
+
+/// A matching document
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchHit {
+	/// Document id
+	pub id: String,
+	/// Relevance score, comparable only within one result set
+	pub score: Option<f64>,
+	/// The stored document
+	pub document: SearchDocument,
+	/// Highlighted field values, matches wrapped in `<em>`
+	pub highlights: HashMap<String, String>,
+}
+
+/// The results of a [`SearchQuery`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SearchResults {
+	/// Hits of the requested page
+	pub hits: Vec<SearchHit>,
+	/// Number of matching documents, possibly estimated by the engine
+	pub total: usize,
+	/// Value counts per requested facet
+	pub facets: BTreeMap<String, BTreeMap<String, usize>>,
+}
+
+impl SearchResults {
+	/// Deserializes the documents of the hits.
+	pub fn documents<T: serde::de::DeserializeOwned>(&self) -> SearchResult<Vec<T>> {
+		self.hits
+			.iter()
+			.map(|hit| Ok(serde_json::from_value(Value::Object(hit.document.clone()))?))
+			.collect()
+	}
+}
+
+/// Renders a facet value as a map key.
+pub(crate) fn facet_key(value: &Value) -> String {
+	match value {
+		Value::String(s) => s.clone(),
+		other => other.to_string(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::schema::{FieldKind, SearchField};
+	use rstest::rstest;
+
+	fn schema() -> IndexSchema {
+		IndexSchema::new("articles", "id")
+			.field(SearchField::new("title", FieldKind::Text))
+			.field(SearchField::new("category", FieldKind::Keyword).facet())
+			.field(SearchField::new("views", FieldKind::Number).sortable())
+	}
+
+	#[rstest]
+	fn test_validate_accepts_declared_usages() {
+		// Arrange
+		let query = SearchQuery::new("rust")
+			.filter_eq("category", "guides")
+			.facet("category")
+			.sort("views", SortOrder::Desc)
+			.highlight("title");
+
+		// Act
+		let result = query.validate(&schema());
+
+		// Assert
+		assert!(result.is_ok());
+	}
+
+	#[rstest]
+	#[case(SearchQuery::new("").filter_range("views", Some(1.0), None))]
+	#[case(SearchQuery::new("").facet("title"))]
+	#[case(SearchQuery::new("").sort("category", SortOrder::Asc))]
+	#[case(SearchQuery::new("").highlight("views"))]
+	fn test_validate_rejects_undeclared_usages(#[case] query: SearchQuery) {
+		// Act
+		let result = query.validate(&schema());
+
+		// Assert
+		assert!(matches!(result, Err(SearchError::InvalidQuery(_))));
+	}
+}
@@ -0,0 +1,275 @@
+//! Index schemas and the [`Searchable`] trait
+
+use crate::backend::{SearchError, SearchResult};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A document as sent to a search engine
+pub type SearchDocument = serde_json::Map<String, Value>;
+
+/// How a field is indexed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldKind {
+	/// Analyzed full text
+	Text,
+	/// Exact string, matched as a whole
+	Keyword,
+	/// Integer or floating point number
+	Number,
+	/// `true` or `false`
+	Boolean,
+	/// RFC 3339 date or date-time
+	Date,
+}
+
+impl FieldKind {
+	/// Returns `true` for kinds matched by the query text.
+	pub fn is_searchable(self) -> bool {
+		matches!(self, Self::Text | Self::Keyword)
+	}
+}
+
+/// A field of an [`IndexSchema`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchField {
+	/// Field name in the document
+	pub name: String,
+	/// How the field is indexed
+	pub kind: FieldKind,
+	/// Whether facet counts can be requested for the field
+	pub facet: bool,
+	/// Whether the field can be used in filters
+	pub filterable: bool,
+	/// Whether results can be sorted by the field
+	pub sortable: bool,
+	/// Relevance weight of matches in the field
+	pub boost: f32,
+}
+
+impl SearchField {
+	/// Creates a field of `kind` with a boost of 1.
+	pub fn new(name: impl Into<String>, kind: FieldKind) -> Self {
+		Self {
+			name: name.into(),
+			kind,
+			facet: false,
+			filterable: false,
+			sortable: false,
+			boost: 1.0,
+		}
+	}
+
+	/// Enables facet counts for the field. Facets are filterable too.
+	pub fn facet(mut self) -> Self {
+		self.facet = true;
+		self.filterable = true;
+		self
+	}
+
+	/// Allows filtering on the field.
+	pub fn filterable(mut self) -> Self {
+		self.filterable = true;
+		self
+	}
+
+	/// Allows sorting by the field.
+	pub fn sortable(mut self) -> Self {
+		self.sortable = true;
+		self
+	}
+
+	/// Sets the relevance weight of matches in the field.
+	pub fn boost(mut self, boost: f32) -> Self {
+		self.boost = boost;
+		self
+	}
+}
+
+/// Describes the index of a model
+///
+/// # Example
+///
+/// ```
+/// use reinhardt_search::{FieldKind, IndexSchema, SearchField};
+///
+/// let schema = IndexSchema::new("articles", "id")
+///     .field(SearchField::new("title", FieldKind::Text).boost(2.0))
+///     .field(SearchField::new("category", FieldKind::Keyword).facet());
+/// assert_eq!(schema.facet_fields().count(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexSchema {
+	/// Index name
+	pub index: String,
+	/// Document field holding the document id
+	pub primary_key: String,
+	/// Indexed fields
+	pub fields: Vec<SearchField>,
+}
+
+impl IndexSchema {
+	/// Creates a schema without fields.
+	pub fn new(index: impl Into<String>, primary_key: impl Into<String>) -> Self {
+		Self {
+			index: index.into(),
+			primary_key: primary_key.into(),
+			fields: Vec::new(),
+		}
+	}
+
+	/// Adds a field.
+	pub fn field(mut self, field: SearchField) -> Self {
+		self.fields.push(field);
+		self
+	}
+
+	/// Returns the field called `name`.
+	pub fn get(&self, name: &str) -> Option<&SearchField> {
+		self.fields.iter().find(|field| field.name == name)
+	}
+
+	/// Fields matched by the query text, most boosted first.
+	pub fn searchable_fields(&self) -> Vec<&SearchField> {
+		let mut fields: Vec<_> = self
+			.fields
+			.iter()
+			.filter(|field| field.kind.is_searchable())
+			.collect();
+		fields.sort_by(|a, b| b.boost.total_cmp(&a.boost));
+		fields
+	}
+
+	/// Fields with facet counts.
+	pub fn facet_fields(&self) -> impl Iterator<Item = &SearchField> {
+		self.fields.iter().filter(|field| field.facet)
+	}
+
+	/// Fields usable in filters.
+	pub fn filterable_fields(&self) -> impl Iterator<Item = &SearchField> {
+		self.fields.iter().filter(|field| field.filterable)
+	}
+
+	/// Fields usable for sorting.
+	pub fn sortable_fields(&self) -> impl Iterator<Item = &SearchField> {
+		self.fields.iter().filter(|field| field.sortable)
+	}
+}
+
+/// A model stored in a search index
+///
+/// Usually derived:
+///
+/// ```ignore
+/// use reinhardt_search::Searchable;
+///
+/// #[derive(Serialize, Searchable)]
+/// #[searchable(index = "articles", id = "id")]
+/// pub struct Article {
+///     pub id: i64,
+///     #[search(text, boost = 2.0)]
+///     pub title: String,
+///     #[search(text)]
+///     pub body: String,
+///     #[search(keyword, facet)]
+///     pub category: String,
+///     #[search(date, sortable)]
+///     pub published_at: String,
+/// }
+/// ```
+///
+/// Only annotated fields are indexed.
+pub trait Searchable: Serialize + Send + Sync + 'static {
+	/// Schema of the index.
+	fn schema() -> IndexSchema;
+
+	/// Id of this document in the index.
+	fn document_id(&self) -> String;
+
+	/// Name of the index.
+	fn index_name() -> String {
+		Self::schema().index
+	}
+
+	/// Builds the indexed document: the schema fields and the id.
+	fn to_document(&self) -> SearchResult<SearchDocument> {
+		let schema = Self::schema();
+		let Value::Object(mut values) = serde_json::to_value(self)? else {
+			return Err(SearchError::InvalidDocument(format!(
+				"documents of index `{}` must serialize to an object",
+				schema.index
+			)));
+		};
+		let mut document = SearchDocument::new();
+		document.insert(
+			schema.primary_key.clone(),
+			Value::String(self.document_id()),
+		);
+		for field in &schema.fields {
+			if let Some(value) = values.remove(&field.name) {
+				document.insert(field.name.clone(), value);
+			}
+		}
+		Ok(document)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[derive(Serialize)]
+	struct Article {
+		id: i64,
+		title: String,
+		secret: String,
+	}
+
+	impl Searchable for Article {
+		fn schema() -> IndexSchema {
+			IndexSchema::new("articles", "id").field(SearchField::new("title", FieldKind::Text))
+		}
+
+		fn document_id(&self) -> String {
+			self.id.to_string()
+		}
+	}
+
+	#[rstest]
+	fn test_to_document_keeps_schema_fields_and_id() {
+		// Arrange
+		let article = Article {
+			id: 7,
+			title: "Rust".to_string(),
+			secret: "hidden".to_string(),
+		};
+
+		// Act
+		let document = article.to_document().unwrap();
+
+		// Assert
+		assert_eq!(document.get("id"), Some(&Value::from("7")));
+		assert_eq!(document.get("title"), Some(&Value::from("Rust")));
+		assert!(!document.contains_key("secret"));
+	}
+
+	#[rstest]
+	fn test_searchable_fields_are_ordered_by_boost() {
+		// Arrange
+		let schema = IndexSchema::new("articles", "id")
+			.field(SearchField::new("body", FieldKind::Text))
+			.field(SearchField::new("views", FieldKind::Number).sortable())
+			.field(SearchField::new("title", FieldKind::Text).boost(3.0));
+
+		// Act
+		let names: Vec<_> = schema
+			.searchable_fields()
+			.iter()
+			.map(|field| field.name.as_str())
+			.collect();
+
+		// Assert
+		assert_eq!(names, ["title", "body"]);
+	}
+}
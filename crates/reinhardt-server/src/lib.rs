@@ -2,7 +2,11 @@
 //! HTTP server implementation for Reinhardt framework
 //!
 //! This crate provides HTTP server capabilities with support for:
-//! - Hyper-based HTTP/1.1 and HTTP/2
+//! - Hyper-based HTTP/1.1 and HTTP/2 (ALPN over TLS, optional h2c)
+//! - rustls TLS termination with certificate reload (`tls` feature)
+//! - TCP (with `SO_REUSEPORT`), Unix domain socket and systemd socket-activated listeners
+//! - Connection limits, keep-alive tuning and graceful shutdown with
+//!   connection draining and shutdown hooks
 //! - GraphQL (optional)
 //! - WebSocket (optional)
 
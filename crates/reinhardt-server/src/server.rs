@@ -37,15 +37,23 @@
 pub mod http;
 /// HTTP/2 server implementation with TLS support.
 pub mod http2;
+/// TCP, Unix domain socket and socket-activated listeners.
+pub mod listener;
 /// Rate limiting handler for controlling request throughput.
 pub mod rate_limit;
 /// Settings-first configuration fragment for rate limiting.
 pub mod rate_limit_settings;
+/// Settings-first configuration fragment for the built-in server.
+pub mod server_settings;
 /// Graceful shutdown coordination for server instances.
 pub mod shutdown;
 /// Request timeout handler for enforcing maximum execution time.
 pub mod timeout;
 
+#[cfg(feature = "tls")]
+/// rustls-based TLS termination with certificate reload (requires `tls` feature).
+pub mod tls;
+
 #[cfg(feature = "graphql")]
 /// GraphQL request handler integration (requires `graphql` feature).
 pub mod graphql;
@@ -56,6 +64,7 @@ pub mod websocket;
 
 pub use http::{HttpServer, serve, serve_with_shutdown};
 pub use http2::{Http2Server, serve_http2, serve_http2_with_shutdown};
+pub use listener::ServerListener;
 #[allow(deprecated)] // Re-export keeps the compatibility API discoverable during the 0.2 line.
 pub use rate_limit::RateLimitConfig;
 pub use rate_limit::{RateLimitHandler, RateLimitStrategy};
@@ -63,9 +72,15 @@ pub use rate_limit::{RateLimitHandler, RateLimitStrategy};
 pub use rate_limit_settings::{
 	RateLimitSettings, RateLimitStrategyKind, create_rate_limit_handler_from_settings,
 };
-pub use shutdown::{ShutdownCoordinator, shutdown_signal, with_shutdown};
+pub use server_settings::{ServerSettings, TlsSettings, UnixSocketSettings};
+pub use shutdown::{
+	ConnectionGuard, ShutdownCoordinator, ShutdownHookFuture, shutdown_signal, with_shutdown,
+};
 pub use timeout::TimeoutHandler;
 
+#[cfg(feature = "tls")]
+pub use tls::ReloadableTlsAcceptor;
+
 #[cfg(feature = "graphql")]
 pub use graphql::{GraphQLHandler, graphql_handler};
 
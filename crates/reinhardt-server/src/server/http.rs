@@ -4,7 +4,8 @@ use hyper::StatusCode;
 use hyper::body::Incoming;
 use hyper::server::conn::http1;
 use hyper::service::Service;
-use hyper_util::rt::TokioIo;
+use hyper_util::rt::{TokioExecutor, TokioIo, TokioTimer};
+use hyper_util::server::conn::auto;
 use reinhardt_di::InjectionContext;
 use reinhardt_http::{Handler, Middleware, MiddlewareChain};
 use reinhardt_http::{Request, Response};
@@ -12,15 +13,25 @@ use std::future::Future;
 use std::net::SocketAddr;
 use std::pin::Pin;
 use std::sync::Arc;
-use tokio::net::{TcpListener, TcpStream};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpStream;
+use tokio::sync::{Semaphore, broadcast};
 
+use crate::listener::{AcceptedStream, ServerListener};
+use crate::server_settings::ServerSettings;
 use crate::shutdown::ShutdownCoordinator;
+#[cfg(feature = "tls")]
+use crate::tls::ReloadableTlsAcceptor;
 
 /// HTTP Server with middleware support
+///
+/// Protocol selection, TLS, connection limits and keep-alive behaviour are
+/// driven by [`ServerSettings`]; see [`HttpServer::with_settings`].
 pub struct HttpServer {
 	handler: Arc<dyn Handler>,
 	pub(crate) middlewares: Vec<Arc<dyn Middleware>>,
 	di_context: Option<Arc<InjectionContext>>,
+	settings: ServerSettings,
 }
 
 impl HttpServer {
@@ -49,6 +60,7 @@ impl HttpServer {
 			handler: Arc::new(handler),
 			middlewares: Vec::new(),
 			di_context: None,
+			settings: ServerSettings::default(),
 		}
 	}
 
@@ -120,6 +132,45 @@ impl HttpServer {
 		self
 	}
 
+	/// Configure the server from a [`ServerSettings`] fragment
+	///
+	/// Settings control HTTP/2 and h2c support, TLS termination (requires the
+	/// `tls` feature), the concurrent connection limit, keep-alive tuning and
+	/// the maximum request body size.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_server::server::{HttpServer, ServerSettings};
+	/// use reinhardt_http::Handler;
+	/// use reinhardt_http::{Request, Response};
+	///
+	/// struct MyHandler;
+	///
+	/// #[async_trait::async_trait]
+	/// impl Handler for MyHandler {
+	///     async fn handle(&self, _req: Request) -> reinhardt_core::exception::Result<Response> {
+	///         Ok(Response::ok())
+	///     }
+	/// }
+	///
+	/// let mut settings = ServerSettings::default();
+	/// settings.h2c = true;
+	/// settings.max_connections = Some(1024);
+	///
+	/// let server = HttpServer::new(MyHandler).with_settings(settings);
+	/// assert!(server.settings().serves_http2());
+	/// ```
+	pub fn with_settings(mut self, settings: ServerSettings) -> Self {
+		self.settings = settings;
+		self
+	}
+
+	/// Get the server settings
+	pub fn settings(&self) -> &ServerSettings {
+		&self.settings
+	}
+
 	/// Get a clone of the handler
 	///
 	/// This is useful for test utilities that need access to the handler.
@@ -172,32 +223,54 @@ impl HttpServer {
 	/// # }
 	/// ```
 	pub async fn listen(self, addr: SocketAddr) -> Result<(), Box<dyn std::error::Error>> {
-		let listener = TcpListener::bind(addr).await?;
-
-		// Build the handler with middleware chain
-		let handler = self.build_handler();
-		let di_context = self.di_context.clone();
-
-		loop {
-			let (stream, socket_addr) = listener.accept().await?;
-			let handler = handler.clone();
-			let di_context = di_context.clone();
+		let listener = ServerListener::from_settings(addr, &self.settings)?;
+		self.run(listener, None).await
+	}
 
-			tokio::task::spawn(async move {
-				if let Err(err) =
-					Self::handle_connection(stream, socket_addr, handler, di_context).await
-				{
-					eprintln!("Error handling connection: {:?}", err);
-				}
-			});
-		}
+	/// Start the server on an already-created listener
+	///
+	/// Use this to serve a Unix domain socket, a socket inherited from systemd
+	/// socket activation, or a TCP listener bound with `SO_REUSEPORT`.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use reinhardt_server::server::{HttpServer, ServerListener};
+	/// use reinhardt_http::Handler;
+	/// use reinhardt_http::{Request, Response};
+	///
+	/// struct MyHandler;
+	///
+	/// #[async_trait::async_trait]
+	/// impl Handler for MyHandler {
+	///     async fn handle(&self, _req: Request) -> reinhardt_core::exception::Result<Response> {
+	///         Ok(Response::ok())
+	///     }
+	/// }
+	///
+	/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+	/// let listener = ServerListener::bind_unix("/run/myapp/app.sock", Some(0o660), true)?;
+	/// HttpServer::new(MyHandler).listen_on(listener).await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub async fn listen_on(
+		self,
+		listener: ServerListener,
+	) -> Result<(), Box<dyn std::error::Error>> {
+		self.run(listener, None).await
 	}
 
 	/// Start the server with graceful shutdown support
 	///
 	/// This method starts the server and listens for shutdown signals.
-	/// When a shutdown signal is received, it stops accepting new connections
-	/// and waits for existing connections to complete.
+	/// When a shutdown signal is received, it stops accepting new connections,
+	/// asks open connections to finish their in-flight requests, waits for them
+	/// to drain, runs the coordinator's shutdown hooks and finally notifies
+	/// shutdown completion.
+	///
+	/// Draining and each hook are bounded by the coordinator's timeout, or by
+	/// [`ServerSettings::shutdown_timeout_secs`] when that setting is present.
 	///
 	/// # Examples
 	///
@@ -230,50 +303,153 @@ impl HttpServer {
 		addr: SocketAddr,
 		coordinator: ShutdownCoordinator,
 	) -> Result<(), Box<dyn std::error::Error>> {
-		let listener = TcpListener::bind(addr).await?;
+		let listener = ServerListener::from_settings(addr, &self.settings)?;
+		self.run(listener, Some(coordinator)).await
+	}
+
+	/// Start the server on an already-created listener with graceful shutdown
+	///
+	/// See [`HttpServer::listen_on`] and [`HttpServer::listen_with_shutdown`].
+	pub async fn listen_on_with_shutdown(
+		self,
+		listener: ServerListener,
+		coordinator: ShutdownCoordinator,
+	) -> Result<(), Box<dyn std::error::Error>> {
+		self.run(listener, Some(coordinator)).await
+	}
 
+	/// Accept connections on `listener` until an error occurs or, when a
+	/// coordinator is given, until shutdown is signalled.
+	async fn run(
+		self,
+		listener: ServerListener,
+		coordinator: Option<ShutdownCoordinator>,
+	) -> Result<(), Box<dyn std::error::Error>> {
 		// Build the handler with middleware chain
 		let handler = self.build_handler();
 		let di_context = self.di_context.clone();
+		let max_body_size = self.settings.max_body_size;
+		let stream_bodies = self.settings.stream_request_bodies;
+		let builder = connection_builder(&self.settings);
+		let limiter = self
+			.settings
+			.max_connections
+			.map(|max| Arc::new(Semaphore::new(max)));
 
-		let mut shutdown_rx = coordinator.subscribe();
+		#[cfg(feature = "tls")]
+		let (tls, _reload_task) = match &self.settings.tls {
+			Some(tls_settings) => {
+				let acceptor =
+					ReloadableTlsAcceptor::from_settings(tls_settings, self.settings.http2)?;
+				let reload_task = tls_settings
+					.reload_interval_secs
+					.map(|secs| acceptor.spawn_reload_task(std::time::Duration::from_secs(secs)))
+					.map(AbortOnDrop);
+				(Some(acceptor), reload_task)
+			}
+			None => (None, None),
+		};
+		#[cfg(not(feature = "tls"))]
+		if self.settings.tls.is_some() {
+			return Err(
+				"TLS is configured but reinhardt-server was built without the `tls` feature".into(),
+			);
+		}
+
+		let connections = Arc::new(ConnectionContext {
+			builder,
+			#[cfg(feature = "tls")]
+			tls,
+		});
+
+		let coordinator = coordinator.map(|coordinator| match self.settings.shutdown_timeout() {
+			Some(timeout) => coordinator.with_timeout(timeout),
+			None => coordinator,
+		});
+		let mut shutdown_rx = coordinator.as_ref().map(ShutdownCoordinator::subscribe);
 
 		loop {
-			tokio::select! {
-				// Accept new connection
-				result = listener.accept() => {
-					let (stream, socket_addr) = result?;
-					let handler = handler.clone();
-					let di_context = di_context.clone();
-					let mut conn_shutdown = coordinator.subscribe();
-
-					tokio::task::spawn(async move {
-						// Handle connection with shutdown support
-						tokio::select! {
-							result = Self::handle_connection(stream, socket_addr, handler, di_context) => {
-								if let Err(err) = result {
-									eprintln!("Error handling connection: {:?}", err);
-								}
-							}
-							_ = conn_shutdown.recv() => {
-								// Connection interrupted by shutdown
-							}
-						}
-					});
-				}
-				// Shutdown signal received
-				_ = shutdown_rx.recv() => {
-					println!("Shutdown signal received, stopping server...");
-					break;
+			let accept = async {
+				// Wait for a free connection slot before accepting so that excess
+				// clients queue in the listen backlog instead of being dropped.
+				let permit = match &limiter {
+					Some(limiter) => Some(
+						limiter
+							.clone()
+							.acquire_owned()
+							.await
+							.expect("connection limiter is never closed"),
+					),
+					None => None,
+				};
+				let stream = listener.accept().await?;
+				Ok::<_, std::io::Error>((stream, permit))
+			};
+
+			let (stream, permit) = match shutdown_rx.as_mut() {
+				Some(shutdown_rx) => tokio::select! {
+					result = accept => result?,
+					_ = shutdown_rx.recv() => {
+						println!("Shutdown signal received, stopping server...");
+						break;
+					}
+				},
+				None => accept.await?,
+			};
+
+			let remote_addr = match &stream {
+				AcceptedStream::Tcp(_, addr) => Some(*addr),
+				#[cfg(unix)]
+				AcceptedStream::Unix(_) => None,
+			};
+			let service = RequestService {
+				handler: handler.clone(),
+				remote_addr,
+				di_context: di_context.clone(),
+				max_body_size,
+				stream_bodies,
+			};
+			let connections = connections.clone();
+			let connection_guard = coordinator
+				.as_ref()
+				.map(ShutdownCoordinator::track_connection);
+			let conn_shutdown = coordinator.as_ref().map(ShutdownCoordinator::subscribe);
+
+			tokio::task::spawn(async move {
+				// Released when the connection closes
+				let _permit = permit;
+				let _connection_guard = connection_guard;
+
+				let result = match stream {
+					AcceptedStream::Tcp(stream, _) => {
+						connections.serve(stream, service, conn_shutdown).await
+					}
+					#[cfg(unix)]
+					AcceptedStream::Unix(stream) => connections.serve(stream, service, conn_shutdown).await,
+				};
+
+				if let Err(err) = result {
+					eprintln!("Error handling connection: {:?}", err);
 				}
-			}
+			});
 		}
 
-		// Notify that server has stopped accepting connections
-		coordinator.notify_shutdown_complete();
+		if let Some(coordinator) = coordinator {
+			if !coordinator.drain().await {
+				eprintln!(
+					"Shutdown timeout after {:?} with {} connection(s) still open",
+					coordinator.timeout_duration(),
+					coordinator.active_connections()
+				);
+			}
+			coordinator.run_hooks().await;
+			// Notify that the server has drained and cleaned up
+			coordinator.notify_shutdown_complete();
+		}
 
 		Ok(())
 	}
+
 	/// Handle a single TCP connection by processing HTTP requests
 	///
 	/// This is an internal method used by the server to process individual connections.
@@ -314,9 +490,10 @@ impl HttpServer {
 		let io = TokioIo::new(stream);
 		let service = RequestService {
 			handler,
-			remote_addr: socket_addr,
+			remote_addr: Some(socket_addr),
 			di_context,
 			max_body_size: DEFAULT_MAX_BODY_SIZE,
+			stream_bodies: false,
 		};
 
 		http1::Builder::new().serve_connection(io, service).await?;
@@ -328,12 +505,101 @@ impl HttpServer {
 /// Default maximum request body size (10 MB)
 const DEFAULT_MAX_BODY_SIZE: u64 = 10 * 1024 * 1024;
 
+/// Build a protocol-detecting connection builder from server settings
+fn connection_builder(settings: &ServerSettings) -> auto::Builder<TokioExecutor> {
+	let mut builder = auto::Builder::new(TokioExecutor::new());
+	builder
+		.http1()
+		.timer(TokioTimer::new())
+		.keep_alive(settings.keep_alive)
+		.header_read_timeout(settings.header_read_timeout());
+	builder
+		.http2()
+		.timer(TokioTimer::new())
+		.keep_alive_interval(settings.keep_alive_interval());
+
+	if settings.serves_http2() {
+		builder
+	} else {
+		builder.http1_only()
+	}
+}
+
+/// Per-server state shared by all connection tasks
+struct ConnectionContext {
+	builder: auto::Builder<TokioExecutor>,
+	#[cfg(feature = "tls")]
+	tls: Option<ReloadableTlsAcceptor>,
+}
+
+impl ConnectionContext {
+	/// Serve a single connection, terminating TLS when configured
+	async fn serve<I>(
+		&self,
+		io: I,
+		service: RequestService,
+		shutdown_rx: Option<broadcast::Receiver<()>>,
+	) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+	where
+		I: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+	{
+		#[cfg(feature = "tls")]
+		if let Some(tls) = &self.tls {
+			let io = tls.acceptor().accept(io).await?;
+			return self.serve_http(io, service, shutdown_rx).await;
+		}
+		self.serve_http(io, service, shutdown_rx).await
+	}
+
+	/// Serve HTTP on `io`, finishing in-flight requests before closing when
+	/// shutdown is signalled
+	async fn serve_http<I>(
+		&self,
+		io: I,
+		service: RequestService,
+		shutdown_rx: Option<broadcast::Receiver<()>>,
+	) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+	where
+		I: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+	{
+		let connection = self.builder.serve_connection(TokioIo::new(io), service);
+		tokio::pin!(connection);
+
+		match shutdown_rx {
+			Some(mut shutdown_rx) => {
+				tokio::select! {
+					result = connection.as_mut() => result,
+					_ = shutdown_rx.recv() => {
+						connection.as_mut().graceful_shutdown();
+						connection.await
+					}
+				}
+			}
+			None => connection.await,
+		}
+	}
+}
+
+/// Aborts a background task when dropped
+#[cfg(feature = "tls")]
+struct AbortOnDrop(tokio::task::JoinHandle<()>);
+
+#[cfg(feature = "tls")]
+impl Drop for AbortOnDrop {
+	fn drop(&mut self) {
+		self.0.abort();
+	}
+}
+
 /// Service implementation for hyper
 struct RequestService {
 	handler: Arc<dyn Handler>,
-	remote_addr: SocketAddr,
+	/// Peer address; `None` for Unix domain socket connections
+	remote_addr: Option<SocketAddr>,
 	di_context: Option<Arc<InjectionContext>>,
 	max_body_size: u64,
+	/// Pass bodies to the handler as streams instead of buffering them
+	stream_bodies: bool,
 }
 
 impl Service<hyper::Request<Incoming>> for RequestService {
@@ -347,6 +613,7 @@ impl Service<hyper::Request<Incoming>> for RequestService {
 		let remote_addr = self.remote_addr;
 		let di_context = self.di_context.clone();
 		let max_body_size = self.max_body_size;
+		let stream_bodies = self.stream_bodies;
 
 		Box::pin(async move {
 			// Check Content-Length before reading body
@@ -364,28 +631,39 @@ impl Service<hyper::Request<Incoming>> for RequestService {
 			// Extract request parts
 			let (parts, body) = req.into_parts();
 
-			// Read body with size limit
-			let body_bytes = http_body_util::Limited::new(body, max_body_size as usize)
-				.collect()
-				.await
-				.map_err(|_| {
-					Box::new(std::io::Error::new(
-						std::io::ErrorKind::InvalidData,
-						"Request body exceeds size limit",
-					)) as Box<dyn std::error::Error + Send + Sync>
-				})?
-				.to_bytes();
+			let limited = http_body_util::Limited::new(body, max_body_size as usize);
 
 			// Create reinhardt Request
-			let mut request = Request::builder()
+			let mut builder = Request::builder()
 				.method(parts.method)
 				.uri(parts.uri)
 				.version(parts.version)
-				.headers(parts.headers)
-				.body(body_bytes)
-				.remote_addr(remote_addr)
-				.build()
-				.expect("Failed to build request");
+				.headers(parts.headers);
+			builder = if stream_bodies {
+				// The limit is enforced as the handler reads the stream
+				builder.body_stream(
+					limited
+						.map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+						.into_data_stream(),
+				)
+			} else {
+				// Read body with size limit
+				let body_bytes = limited
+					.collect()
+					.await
+					.map_err(|_| {
+						Box::new(std::io::Error::new(
+							std::io::ErrorKind::InvalidData,
+							"Request body exceeds size limit",
+						)) as Box<dyn std::error::Error + Send + Sync>
+					})?
+					.to_bytes();
+				builder.body(body_bytes)
+			};
+			if let Some(remote_addr) = remote_addr {
+				builder = builder.remote_addr(remote_addr);
+			}
+			let mut request = builder.build().expect("Failed to build request");
 
 			// Set DI context if available
 			if let Some(ctx) = di_context {
@@ -585,6 +863,133 @@ mod tests {
 		assert_eq!(server.middlewares.len(), 2);
 	}
 
+	#[rstest]
+	#[tokio::test]
+	async fn test_graceful_shutdown_drains_and_runs_hooks() {
+		// Arrange
+		let listener = ServerListener::bind_tcp("127.0.0.1:0".parse().unwrap(), false).unwrap();
+		let addr = listener.local_addr().unwrap();
+		let coordinator = ShutdownCoordinator::new(std::time::Duration::from_secs(5));
+		let hook_ran = Arc::new(std::sync::atomic::AtomicBool::new(false));
+		let flag = hook_ran.clone();
+		coordinator.register_hook("flag", move || async move {
+			flag.store(true, std::sync::atomic::Ordering::SeqCst);
+		});
+		let server = HttpServer::new(TestHandler);
+		let server_coordinator = coordinator.clone();
+		let server_task =
+			tokio::spawn(
+				async move { server.run(listener, Some(server_coordinator)).await.is_ok() },
+			);
+		let body = reqwest::get(format!("http://{addr}/"))
+			.await
+			.unwrap()
+			.text()
+			.await
+			.unwrap();
+
+		// Act
+		coordinator.shutdown();
+		coordinator.wait_for_shutdown().await;
+
+		// Assert
+		assert_eq!(body, "Hello, World!");
+		assert!(server_task.await.unwrap());
+		assert!(hook_ran.load(std::sync::atomic::Ordering::SeqCst));
+		assert_eq!(coordinator.active_connections(), 0);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_shutdown_timeout_setting_cuts_drain_short() {
+		// Arrange
+		struct SlowHandler(Arc<tokio::sync::Notify>);
+
+		#[async_trait::async_trait]
+		impl Handler for SlowHandler {
+			async fn handle(
+				&self,
+				_request: Request,
+			) -> reinhardt_core::exception::Result<Response> {
+				self.0.notify_one();
+				tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+				Ok(Response::ok())
+			}
+		}
+
+		let listener = ServerListener::bind_tcp("127.0.0.1:0".parse().unwrap(), false).unwrap();
+		let addr = listener.local_addr().unwrap();
+		let coordinator = ShutdownCoordinator::new(std::time::Duration::from_secs(60));
+		let started = Arc::new(tokio::sync::Notify::new());
+		let server = HttpServer::new(SlowHandler(started.clone())).with_settings(ServerSettings {
+			shutdown_timeout_secs: Some(1),
+			..ServerSettings::default()
+		});
+		let server_coordinator = coordinator.clone();
+		let server_task =
+			tokio::spawn(
+				async move { server.run(listener, Some(server_coordinator)).await.is_ok() },
+			);
+		let _request = tokio::spawn(reqwest::get(format!("http://{addr}/")));
+		started.notified().await;
+
+		// Act
+		coordinator.shutdown();
+		let stopped = tokio::time::timeout(std::time::Duration::from_secs(10), server_task).await;
+
+		// Assert
+		assert!(
+			stopped
+				.expect("drain should stop at the settings timeout")
+				.unwrap()
+		);
+		assert_eq!(coordinator.active_connections(), 1);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_coordinator_timeout_kept_without_shutdown_setting() {
+		// Arrange
+		struct SlowHandler(Arc<tokio::sync::Notify>);
+
+		#[async_trait::async_trait]
+		impl Handler for SlowHandler {
+			async fn handle(
+				&self,
+				_request: Request,
+			) -> reinhardt_core::exception::Result<Response> {
+				self.0.notify_one();
+				tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+				Ok(Response::ok())
+			}
+		}
+
+		let listener = ServerListener::bind_tcp("127.0.0.1:0".parse().unwrap(), false).unwrap();
+		let addr = listener.local_addr().unwrap();
+		let coordinator = ShutdownCoordinator::new(std::time::Duration::from_secs(1));
+		let started = Arc::new(tokio::sync::Notify::new());
+		let server = HttpServer::new(SlowHandler(started.clone()));
+		let server_coordinator = coordinator.clone();
+		let server_task =
+			tokio::spawn(
+				async move { server.run(listener, Some(server_coordinator)).await.is_ok() },
+			);
+		let _request = tokio::spawn(reqwest::get(format!("http://{addr}/")));
+		started.notified().await;
+
+		// Act
+		coordinator.shutdown();
+		let stopped = tokio::time::timeout(std::time::Duration::from_secs(10), server_task).await;
+
+		// Assert
+		assert!(
+			stopped
+				.expect("drain should stop at the coordinator timeout")
+				.unwrap()
+		);
+		assert_eq!(coordinator.active_connections(), 1);
+	}
+
 	#[tokio::test]
 	async fn test_middleware_chain_execution() {
 		use bytes::Bytes;
@@ -0,0 +1,399 @@
+//! Listening sockets for the built-in server.
+//!
+//! [`ServerListener`] abstracts over the ways a deployment can hand the server
+//! a socket:
+//!
+//! - a TCP address, optionally with `SO_REUSEPORT` so several worker processes
+//!   can share one port,
+//! - a Unix domain socket path with configurable permissions (for nginx or
+//!   another reverse proxy on the same host),
+//! - a pre-opened socket inherited from systemd socket activation
+//!   (`LISTEN_FDS`/`LISTEN_PID`).
+//!
+//! [`ServerListener::from_settings`] picks among them based on
+//! [`ServerSettings`].
+
+use std::io;
+use std::net::SocketAddr;
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
+
+use socket2::{Domain, Protocol, Socket, Type};
+use tokio::net::{TcpListener, TcpStream};
+#[cfg(unix)]
+use tokio::net::{UnixListener, UnixStream};
+
+use super::server_settings::ServerSettings;
+
+/// Backlog used for sockets bound by the server
+const LISTEN_BACKLOG: i32 = 1024;
+
+/// First file descriptor passed by systemd socket activation (`SD_LISTEN_FDS_START`)
+#[cfg(unix)]
+const SD_LISTEN_FDS_START: std::os::fd::RawFd = 3;
+
+enum ListenerKind {
+	Tcp(TcpListener),
+	#[cfg(unix)]
+	Unix(UnixListener),
+}
+
+/// A connection accepted from a [`ServerListener`]
+pub(crate) enum AcceptedStream {
+	Tcp(TcpStream, SocketAddr),
+	#[cfg(unix)]
+	Unix(UnixStream),
+}
+
+/// Listening socket the server accepts connections from
+///
+/// A Unix socket file bound through [`ServerListener::bind_unix`] is removed
+/// when the listener is dropped. Inherited sockets are left untouched.
+///
+/// # Examples
+///
+/// ```no_run
+/// use reinhardt_server::server::ServerListener;
+///
+/// # async fn example() -> std::io::Result<()> {
+/// // Several workers can bind the same port with SO_REUSEPORT
+/// let listener = ServerListener::bind_tcp("0.0.0.0:8000".parse().unwrap(), true)?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ServerListener {
+	inner: ListenerKind,
+	#[cfg(unix)]
+	unix_path: Option<PathBuf>,
+}
+
+impl ServerListener {
+	fn new(inner: ListenerKind) -> Self {
+		Self {
+			inner,
+			#[cfg(unix)]
+			unix_path: None,
+		}
+	}
+
+	/// Bind a TCP listener on `addr`
+	///
+	/// With `reuse_port`, `SO_REUSEPORT` is set so that multiple processes can
+	/// bind the same address and the kernel load-balances connections between
+	/// them. The flag is ignored on platforms without `SO_REUSEPORT`.
+	pub fn bind_tcp(addr: SocketAddr, reuse_port: bool) -> io::Result<Self> {
+		let socket = Socket::new(Domain::for_address(addr), Type::STREAM, Some(Protocol::TCP))?;
+		socket.set_reuse_address(true)?;
+		#[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+		if reuse_port {
+			socket.set_reuse_port(true)?;
+		}
+		#[cfg(not(all(unix, not(any(target_os = "solaris", target_os = "illumos")))))]
+		let _ = reuse_port;
+		socket.set_nonblocking(true)?;
+		socket.bind(&addr.into())?;
+		socket.listen(LISTEN_BACKLOG)?;
+
+		Ok(Self::new(ListenerKind::Tcp(TcpListener::from_std(
+			socket.into(),
+		)?)))
+	}
+
+	/// Bind a Unix domain socket at `path`
+	///
+	/// `mode` sets the socket file permissions (e.g. `0o660` to let a reverse
+	/// proxy in the same group connect). With `remove_existing`, a stale socket
+	/// left by a previous run is removed first; other file types are never
+	/// removed.
+	#[cfg(unix)]
+	pub fn bind_unix(
+		path: impl AsRef<Path>,
+		mode: Option<u32>,
+		remove_existing: bool,
+	) -> io::Result<Self> {
+		use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+
+		let path = path.as_ref();
+		if remove_existing
+			&& let Ok(metadata) = std::fs::symlink_metadata(path)
+			&& metadata.file_type().is_socket()
+		{
+			std::fs::remove_file(path)?;
+		}
+
+		let listener = UnixListener::bind(path)?;
+		if let Some(mode) = mode {
+			std::fs::set_permissions(path, std::fs::Permissions::from_mode(mode))?;
+		}
+
+		Ok(Self {
+			inner: ListenerKind::Unix(listener),
+			unix_path: Some(path.to_path_buf()),
+		})
+	}
+
+	/// Inherit the first socket passed by systemd socket activation
+	///
+	/// Returns `Ok(None)` when the process was not socket-activated, i.e. when
+	/// `LISTEN_PID` does not match this process or `LISTEN_FDS` is absent.
+	#[cfg(unix)]
+	pub fn from_systemd() -> io::Result<Option<Self>> {
+		let Some(count) = listen_fds(
+			std::env::var("LISTEN_PID").ok().as_deref(),
+			std::env::var("LISTEN_FDS").ok().as_deref(),
+			std::process::id(),
+		) else {
+			return Ok(None);
+		};
+		if count > 1 {
+			eprintln!(
+				"systemd passed {} sockets; only the first one is used",
+				count
+			);
+		}
+
+		// SAFETY: systemd guarantees that, when LISTEN_PID names this process,
+		// descriptors SD_LISTEN_FDS_START..SD_LISTEN_FDS_START + LISTEN_FDS are
+		// open listening sockets owned by the process. Nothing else in the
+		// server takes ownership of this descriptor.
+		let socket = unsafe {
+			use std::os::fd::FromRawFd;
+			Socket::from_raw_fd(SD_LISTEN_FDS_START)
+		};
+		Self::from_socket(socket).map(Some)
+	}
+
+	/// Wrap an already-listening socket (TCP or Unix)
+	///
+	/// Useful for process managers that hand over descriptors themselves.
+	pub fn from_socket(socket: Socket) -> io::Result<Self> {
+		socket.set_nonblocking(true)?;
+		if socket.local_addr()?.as_socket().is_some() {
+			return Ok(Self::new(ListenerKind::Tcp(TcpListener::from_std(
+				socket.into(),
+			)?)));
+		}
+
+		#[cfg(unix)]
+		{
+			use std::os::fd::{FromRawFd, IntoRawFd};
+			// SAFETY: the descriptor comes from `socket`, which is consumed here,
+			// so ownership is transferred exactly once.
+			let listener =
+				unsafe { std::os::unix::net::UnixListener::from_raw_fd(socket.into_raw_fd()) };
+			Ok(Self::new(ListenerKind::Unix(UnixListener::from_std(
+				listener,
+			)?)))
+		}
+		#[cfg(not(unix))]
+		Err(io::Error::new(
+			io::ErrorKind::Unsupported,
+			"only TCP sockets are supported on this platform",
+		))
+	}
+
+	/// Create the listener described by `settings`
+	///
+	/// Precedence: an inherited systemd socket (when `socket_activation` is
+	/// enabled and the process was activated), then `unix_socket`, then a TCP
+	/// listener on `addr`.
+	pub fn from_settings(addr: SocketAddr, settings: &ServerSettings) -> io::Result<Self> {
+		#[cfg(unix)]
+		{
+			if settings.socket_activation
+				&& let Some(listener) = Self::from_systemd()?
+			{
+				return Ok(listener);
+			}
+			if let Some(unix) = &settings.unix_socket {
+				return Self::bind_unix(&unix.path, unix.mode, unix.remove_existing);
+			}
+		}
+		#[cfg(not(unix))]
+		if settings.unix_socket.is_some() || settings.socket_activation {
+			return Err(io::Error::new(
+				io::ErrorKind::Unsupported,
+				"Unix sockets and socket activation require a Unix platform",
+			));
+		}
+
+		Self::bind_tcp(addr, settings.reuse_port)
+	}
+
+	/// Local TCP address, or `None` for Unix sockets
+	pub fn local_addr(&self) -> Option<SocketAddr> {
+		match &self.inner {
+			ListenerKind::Tcp(listener) => listener.local_addr().ok(),
+			#[cfg(unix)]
+			ListenerKind::Unix(_) => None,
+		}
+	}
+
+	/// Path of the bound Unix socket, if any
+	#[cfg(unix)]
+	pub fn unix_path(&self) -> Option<&Path> {
+		self.unix_path.as_deref()
+	}
+
+	pub(crate) async fn accept(&self) -> io::Result<AcceptedStream> {
+		match &self.inner {
+			ListenerKind::Tcp(listener) => {
+				let (stream, addr) = listener.accept().await?;
+				Ok(AcceptedStream::Tcp(stream, addr))
+			}
+			#[cfg(unix)]
+			ListenerKind::Unix(listener) => {
+				let (stream, _) = listener.accept().await?;
+				Ok(AcceptedStream::Unix(stream))
+			}
+		}
+	}
+}
+
+impl From<TcpListener> for ServerListener {
+	fn from(listener: TcpListener) -> Self {
+		Self::new(ListenerKind::Tcp(listener))
+	}
+}
+
+#[cfg(unix)]
+impl From<UnixListener> for ServerListener {
+	fn from(listener: UnixListener) -> Self {
+		Self::new(ListenerKind::Unix(listener))
+	}
+}
+
+#[cfg(unix)]
+impl Drop for ServerListener {
+	fn drop(&mut self) {
+		if let Some(path) = &self.unix_path {
+			let _ = std::fs::remove_file(path);
+		}
+	}
+}
+
+/// Number of sockets passed by systemd, if they are meant for process `pid`
+#[cfg(unix)]
+fn listen_fds(listen_pid: Option<&str>, listen_fds: Option<&str>, pid: u32) -> Option<usize> {
+	if listen_pid?.trim().parse::<u32>().ok()? != pid {
+		return None;
+	}
+	listen_fds?.trim().parse::<usize>().ok().filter(|&n| n > 0)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use std::os::unix::fs::PermissionsExt;
+	use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+	#[rstest]
+	#[case::matching(Some("42"), Some("2"), Some(2))]
+	#[case::other_process(Some("7"), Some("2"), None)]
+	#[case::missing_pid(None, Some("1"), None)]
+	#[case::missing_fds(Some("42"), None, None)]
+	#[case::zero_fds(Some("42"), Some("0"), None)]
+	fn test_listen_fds(
+		#[case] pid: Option<&str>,
+		#[case] fds: Option<&str>,
+		#[case] expected: Option<usize>,
+	) {
+		// Arrange / Act
+		let count = listen_fds(pid, fds, 42);
+
+		// Assert
+		assert_eq!(count, expected);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_bind_unix_sets_mode_and_cleans_up() {
+		// Arrange
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("app.sock");
+
+		// Act
+		let listener = ServerListener::bind_unix(&path, Some(0o660), true).unwrap();
+		let mode = std::fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+		drop(listener);
+
+		// Assert
+		assert_eq!(mode, 0o660);
+		assert!(!path.exists());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_bind_unix_replaces_stale_socket() {
+		// Arrange
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("app.sock");
+		let stale = std::os::unix::net::UnixListener::bind(&path).unwrap();
+		drop(stale);
+
+		// Act
+		let listener = ServerListener::bind_unix(&path, None, true);
+
+		// Assert
+		assert!(listener.is_ok());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_bind_unix_keeps_regular_file() {
+		// Arrange
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("not-a-socket");
+		std::fs::write(&path, b"data").unwrap();
+
+		// Act
+		let result = ServerListener::bind_unix(&path, None, true);
+
+		// Assert
+		assert!(result.is_err());
+		assert_eq!(std::fs::read(&path).unwrap(), b"data");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_unix_listener_accepts_connections() {
+		// Arrange
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("app.sock");
+		let listener = ServerListener::bind_unix(&path, None, true).unwrap();
+		let client = tokio::spawn({
+			let path = path.clone();
+			async move {
+				let mut stream = UnixStream::connect(path).await.unwrap();
+				stream.write_all(b"ping").await.unwrap();
+			}
+		});
+
+		// Act
+		let accepted = listener.accept().await.unwrap();
+
+		// Assert
+		let AcceptedStream::Unix(mut stream) = accepted else {
+			panic!("expected a Unix stream");
+		};
+		let mut buf = [0u8; 4];
+		stream.read_exact(&mut buf).await.unwrap();
+		assert_eq!(&buf, b"ping");
+		client.await.unwrap();
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_reuse_port_allows_two_listeners() {
+		// Arrange
+		let first = ServerListener::bind_tcp("127.0.0.1:0".parse().unwrap(), true).unwrap();
+		let addr = first.local_addr().unwrap();
+
+		// Act
+		let second = ServerListener::bind_tcp(addr, true);
+
+		// Assert
+		assert_eq!(second.unwrap().local_addr(), Some(addr));
+	}
+}
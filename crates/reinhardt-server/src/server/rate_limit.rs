@@ -296,7 +296,16 @@ impl RateLimitHandler {
 	/// Only trusts proxy headers (X-Forwarded-For, X-Real-IP) when the request
 	/// originates from a configured trusted proxy address. Otherwise, uses the
 	/// direct connection IP (remote_addr) or falls back to localhost.
+	///
+	/// Without trusted proxies of its own, the request-wide
+	/// [`Request::client_ip`] resolution is used instead.
 	fn extract_client_ip(&self, request: &Request) -> IpAddr {
+		if self.config.trusted_proxies.is_empty() {
+			return request
+				.client_ip()
+				.unwrap_or_else(|| "127.0.0.1".parse().unwrap());
+		}
+
 		let peer_ip = request.remote_addr.map(|addr| addr.ip());
 
 		// Only trust proxy headers if the direct connection is from a trusted proxy
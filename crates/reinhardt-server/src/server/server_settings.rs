@@ -0,0 +1,291 @@
+//! Settings-first configuration fragment for the built-in server.
+//!
+//! [`ServerSettings`] maps to the `[server]` TOML section and controls protocol
+//! selection (HTTP/1.1, HTTP/2, h2c), TLS termination, connection limits,
+//! keep-alive tuning and the graceful shutdown deadline. Apply it to a server
+//! with [`HttpServer::with_settings`](super::HttpServer::with_settings).
+//!
+//! ```toml
+//! [server]
+//! http2 = true
+//! max_connections = 10000
+//! shutdown_timeout_secs = 30
+//!
+//! [server.tls]
+//! cert_path = "/etc/reinhardt/cert.pem"
+//! key_path = "/etc/reinhardt/key.pem"
+//! reload_interval_secs = 3600
+//! ```
+//!
+//! Behind nginx on the same host, bind a Unix socket instead of a TCP port;
+//! under systemd, inherit the socket-activated listener:
+//!
+//! ```toml
+//! [server]
+//! socket_activation = true
+//!
+//! [server.unix_socket]
+//! path = "/run/myapp/app.sock"
+//! mode = 0o660
+//! ```
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use reinhardt_core::macros::settings;
+use serde::{Deserialize, Serialize};
+
+// --- defaults -------------------------------------------------------------
+
+fn default_http2() -> bool {
+	true
+}
+
+fn default_keep_alive() -> bool {
+	true
+}
+
+fn default_header_read_timeout_secs() -> u64 {
+	30
+}
+
+fn default_max_body_size() -> u64 {
+	10 * 1024 * 1024
+}
+
+fn default_remove_existing() -> bool {
+	true
+}
+
+/// Unix domain socket binding settings.
+///
+/// Value object nested inside [`ServerSettings`] as `[server.unix_socket]`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnixSocketSettings {
+	/// Filesystem path of the socket.
+	pub path: PathBuf,
+	/// Permission bits applied to the socket file, e.g. `0o660`.
+	#[serde(default)]
+	pub mode: Option<u32>,
+	/// Remove a stale socket file left by a previous run before binding.
+	#[serde(default = "default_remove_existing")]
+	pub remove_existing: bool,
+}
+
+/// TLS termination settings.
+///
+/// Value object nested inside [`ServerSettings`] as `[server.tls]`. It is not an
+/// independently loadable section.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TlsSettings {
+	/// Path to the PEM-encoded certificate chain.
+	pub cert_path: PathBuf,
+	/// Path to the PEM-encoded private key.
+	pub key_path: PathBuf,
+	/// How often to re-read the certificate and key from disk, in seconds.
+	///
+	/// `None` disables automatic reloading; certificates can still be reloaded
+	/// explicitly through [`ReloadableTlsAcceptor::reload`](super::tls::ReloadableTlsAcceptor::reload).
+	#[serde(default)]
+	pub reload_interval_secs: Option<u64>,
+}
+
+/// Built-in server settings fragment.
+///
+/// Maps to the `[server]` section.
+#[settings(fragment = true, section = "server")]
+#[non_exhaustive]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ServerSettings {
+	/// Enable HTTP/2. Negotiated through ALPN when TLS is configured.
+	#[serde(default = "default_http2")]
+	pub http2: bool,
+	/// Accept HTTP/2 over cleartext (prior knowledge h2c) on plain TCP listeners.
+	#[serde(default)]
+	pub h2c: bool,
+	/// Maximum number of concurrent connections. `None` means unlimited.
+	///
+	/// When the limit is reached the server stops accepting until a connection
+	/// closes, leaving new clients queued in the listen backlog.
+	#[serde(default)]
+	pub max_connections: Option<usize>,
+	/// Keep HTTP/1.1 connections alive between requests.
+	#[serde(default = "default_keep_alive")]
+	pub keep_alive: bool,
+	/// Interval between HTTP/2 keep-alive pings, in seconds. `None` disables pings.
+	#[serde(default)]
+	pub keep_alive_interval_secs: Option<u64>,
+	/// Maximum time allowed for a client to send request headers, in seconds.
+	#[serde(default = "default_header_read_timeout_secs")]
+	pub header_read_timeout_secs: u64,
+	/// Deadline for draining in-flight requests and running shutdown hooks, in seconds.
+	///
+	/// `None` keeps the timeout the [`ShutdownCoordinator`](super::ShutdownCoordinator)
+	/// was created with.
+	#[serde(default)]
+	pub shutdown_timeout_secs: Option<u64>,
+	/// Maximum accepted request body size, in bytes.
+	#[serde(default = "default_max_body_size")]
+	pub max_body_size: u64,
+	/// Hand request bodies to handlers as streams instead of buffering them.
+	///
+	/// Streamed bodies are read through `Request::take_body`,
+	/// `Request::bytes_limited`, body extractors and parsers; the synchronous
+	/// `Request::body` is empty. `max_body_size` still applies while the
+	/// stream is read.
+	#[serde(default)]
+	pub stream_request_bodies: bool,
+	/// TLS termination. Plain TCP is used when absent.
+	#[serde(default)]
+	pub tls: Option<TlsSettings>,
+	/// Listen on a Unix domain socket instead of a TCP address.
+	#[serde(default)]
+	pub unix_socket: Option<UnixSocketSettings>,
+	/// Use a listener inherited through systemd socket activation when present.
+	///
+	/// Takes precedence over `unix_socket` and the TCP address; falls back to
+	/// them when the process was not socket-activated.
+	#[serde(default)]
+	pub socket_activation: bool,
+	/// Set `SO_REUSEPORT` on the TCP listener so multiple worker processes can
+	/// share the same port.
+	#[serde(default)]
+	pub reuse_port: bool,
+}
+
+impl Default for ServerSettings {
+	fn default() -> Self {
+		Self {
+			http2: default_http2(),
+			h2c: false,
+			max_connections: None,
+			keep_alive: default_keep_alive(),
+			keep_alive_interval_secs: None,
+			header_read_timeout_secs: default_header_read_timeout_secs(),
+			shutdown_timeout_secs: None,
+			max_body_size: default_max_body_size(),
+			stream_request_bodies: false,
+			tls: None,
+			unix_socket: None,
+			socket_activation: false,
+			reuse_port: false,
+		}
+	}
+}
+
+impl ServerSettings {
+	/// Whether connections may be upgraded to HTTP/2.
+	///
+	/// HTTP/2 is only served over TLS, or over cleartext when `h2c` is enabled.
+	pub fn serves_http2(&self) -> bool {
+		self.http2 && (self.tls.is_some() || self.h2c)
+	}
+
+	/// Header read timeout as a [`Duration`].
+	pub fn header_read_timeout(&self) -> Duration {
+		Duration::from_secs(self.header_read_timeout_secs)
+	}
+
+	/// HTTP/2 keep-alive ping interval as a [`Duration`].
+	pub fn keep_alive_interval(&self) -> Option<Duration> {
+		self.keep_alive_interval_secs.map(Duration::from_secs)
+	}
+
+	/// Graceful shutdown deadline as a [`Duration`], if one is configured.
+	pub fn shutdown_timeout(&self) -> Option<Duration> {
+		self.shutdown_timeout_secs.map(Duration::from_secs)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reinhardt_conf::settings::fragment::SettingsFragment;
+	use rstest::rstest;
+
+	#[rstest]
+	fn section_name() {
+		// Arrange / Act / Assert
+		assert_eq!(ServerSettings::section(), "server");
+	}
+
+	#[rstest]
+	fn deserializes_with_defaults() {
+		// Arrange
+		let json = r#"{ "max_connections": 512 }"#;
+
+		// Act
+		let settings: ServerSettings = serde_json::from_str(json).unwrap();
+
+		// Assert
+		assert_eq!(settings.max_connections, Some(512));
+		assert!(settings.http2);
+		assert!(!settings.h2c);
+		assert!(settings.keep_alive);
+		assert_eq!(settings.header_read_timeout(), Duration::from_secs(30));
+		assert_eq!(settings.shutdown_timeout(), None);
+		assert_eq!(settings.max_body_size, 10 * 1024 * 1024);
+		assert!(!settings.stream_request_bodies);
+		assert_eq!(settings.tls, None);
+	}
+
+	#[rstest]
+	fn deserializes_nested_tls() {
+		// Arrange
+		let json = r#"{ "tls": { "cert_path": "cert.pem", "key_path": "key.pem" } }"#;
+
+		// Act
+		let settings: ServerSettings = serde_json::from_str(json).unwrap();
+
+		// Assert
+		let tls = settings.tls.unwrap();
+		assert_eq!(tls.cert_path, PathBuf::from("cert.pem"));
+		assert_eq!(tls.key_path, PathBuf::from("key.pem"));
+		assert_eq!(tls.reload_interval_secs, None);
+	}
+
+	#[rstest]
+	fn deserializes_unix_socket_with_defaults() {
+		// Arrange
+		let json = r#"{ "unix_socket": { "path": "/run/app.sock", "mode": 432 } }"#;
+
+		// Act
+		let settings: ServerSettings = serde_json::from_str(json).unwrap();
+
+		// Assert
+		assert_eq!(
+			settings.unix_socket,
+			Some(UnixSocketSettings {
+				path: PathBuf::from("/run/app.sock"),
+				mode: Some(0o660),
+				remove_existing: true,
+			})
+		);
+		assert!(!settings.socket_activation);
+		assert!(!settings.reuse_port);
+	}
+
+	#[rstest]
+	#[case::plain_default(false, false, false)]
+	#[case::plain_h2c(false, true, true)]
+	#[case::tls(true, false, true)]
+	fn serves_http2_requires_tls_or_h2c(
+		#[case] tls: bool,
+		#[case] h2c: bool,
+		#[case] expected: bool,
+	) {
+		// Arrange
+		let settings = ServerSettings {
+			h2c,
+			tls: tls.then(|| TlsSettings {
+				cert_path: "cert.pem".into(),
+				key_path: "key.pem".into(),
+				reload_interval_secs: None,
+			}),
+			..ServerSettings::default()
+		};
+
+		// Act / Assert
+		assert_eq!(settings.serves_http2(), expected);
+	}
+}
@@ -1,14 +1,29 @@
 use std::future::Future;
-use std::sync::Arc;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::sync::Notify;
 use tokio::sync::broadcast;
 use tokio::time::timeout;
 
+/// Boxed future returned by a shutdown hook
+pub type ShutdownHookFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+type ShutdownHook = Box<dyn FnOnce() -> ShutdownHookFuture + Send + 'static>;
+
 /// Shutdown coordinator that manages graceful server shutdown
 ///
 /// Handles signal listening, connection tracking, and graceful shutdown with timeout.
 ///
+/// A full graceful shutdown ([`ShutdownCoordinator::graceful_shutdown`]) runs in
+/// three phases:
+///
+/// 1. Listeners stop accepting new connections.
+/// 2. In-flight connections are drained until they finish or the timeout elapses.
+/// 3. Registered shutdown hooks (pool close, task flush, ...) run in
+///    registration order.
+///
 /// # Examples
 ///
 /// ```
@@ -34,6 +49,29 @@ pub struct ShutdownCoordinator {
 	shutdown_complete: Arc<Notify>,
 	/// Shutdown timeout duration
 	timeout_duration: Duration,
+	/// Number of connections currently being served
+	active_connections: Arc<AtomicUsize>,
+	/// Notification fired whenever the last active connection closes
+	connections_drained: Arc<Notify>,
+	/// Hooks executed once connections are drained
+	hooks: Arc<Mutex<Vec<(String, ShutdownHook)>>>,
+}
+
+/// RAII guard marking a connection as in-flight
+///
+/// Created by [`ShutdownCoordinator::track_connection`]. The connection is
+/// considered drained when the guard is dropped.
+pub struct ConnectionGuard {
+	active_connections: Arc<AtomicUsize>,
+	connections_drained: Arc<Notify>,
+}
+
+impl Drop for ConnectionGuard {
+	fn drop(&mut self) {
+		if self.active_connections.fetch_sub(1, Ordering::AcqRel) == 1 {
+			self.connections_drained.notify_waiters();
+		}
+	}
 }
 
 impl ShutdownCoordinator {
@@ -59,9 +97,32 @@ impl ShutdownCoordinator {
 			shutdown_tx,
 			shutdown_complete,
 			timeout_duration,
+			active_connections: Arc::new(AtomicUsize::new(0)),
+			connections_drained: Arc::new(Notify::new()),
+			hooks: Arc::new(Mutex::new(Vec::new())),
 		}
 	}
 
+	/// Replace the shutdown timeout
+	///
+	/// The returned coordinator still shares the shutdown signal, connection
+	/// tracking and hooks with `self`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_server::server::shutdown::ShutdownCoordinator;
+	/// use std::time::Duration;
+	///
+	/// let coordinator = ShutdownCoordinator::new(Duration::from_secs(30))
+	///     .with_timeout(Duration::from_secs(5));
+	/// assert_eq!(coordinator.timeout_duration(), Duration::from_secs(5));
+	/// ```
+	pub fn with_timeout(mut self, timeout_duration: Duration) -> Self {
+		self.timeout_duration = timeout_duration;
+		self
+	}
+
 	/// Subscribe to shutdown signal
 	///
 	/// Returns a receiver that will be notified when shutdown is initiated.
@@ -171,6 +232,137 @@ impl ShutdownCoordinator {
 	pub fn timeout_duration(&self) -> Duration {
 		self.timeout_duration
 	}
+
+	/// Mark a connection as in-flight until the returned guard is dropped
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_server::server::shutdown::ShutdownCoordinator;
+	/// use std::time::Duration;
+	///
+	/// let coordinator = ShutdownCoordinator::new(Duration::from_secs(30));
+	/// let guard = coordinator.track_connection();
+	/// assert_eq!(coordinator.active_connections(), 1);
+	/// drop(guard);
+	/// assert_eq!(coordinator.active_connections(), 0);
+	/// ```
+	pub fn track_connection(&self) -> ConnectionGuard {
+		self.active_connections.fetch_add(1, Ordering::AcqRel);
+		ConnectionGuard {
+			active_connections: self.active_connections.clone(),
+			connections_drained: self.connections_drained.clone(),
+		}
+	}
+
+	/// Number of connections currently in flight
+	pub fn active_connections(&self) -> usize {
+		self.active_connections.load(Ordering::Acquire)
+	}
+
+	/// Wait until all tracked connections have closed
+	///
+	/// Returns `true` if every connection drained before the shutdown timeout,
+	/// `false` if the deadline elapsed with connections still open.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_server::server::shutdown::ShutdownCoordinator;
+	/// use std::time::Duration;
+	///
+	/// # async fn example() {
+	/// let coordinator = ShutdownCoordinator::new(Duration::from_secs(30));
+	/// assert!(coordinator.drain().await);
+	/// # }
+	/// ```
+	pub async fn drain(&self) -> bool {
+		let wait = async {
+			loop {
+				let notified = self.connections_drained.notified();
+				if self.active_connections() == 0 {
+					return;
+				}
+				notified.await;
+			}
+		};
+		timeout(self.timeout_duration, wait).await.is_ok()
+	}
+
+	/// Register a hook to run after connections are drained
+	///
+	/// Hooks run once, in registration order, during
+	/// [`ShutdownCoordinator::graceful_shutdown`] or
+	/// [`ShutdownCoordinator::run_hooks`]. Use them to close database pools,
+	/// flush background task queues and similar cleanup.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_server::server::shutdown::ShutdownCoordinator;
+	/// use std::time::Duration;
+	///
+	/// let coordinator = ShutdownCoordinator::new(Duration::from_secs(30));
+	/// coordinator.register_hook("close-pool", || async {
+	///     // pool.close().await;
+	/// });
+	/// ```
+	pub fn register_hook<F, Fut>(&self, name: impl Into<String>, hook: F)
+	where
+		F: FnOnce() -> Fut + Send + 'static,
+		Fut: Future<Output = ()> + Send + 'static,
+	{
+		let hook: ShutdownHook = Box::new(move || Box::pin(hook()));
+		self.hooks
+			.lock()
+			.unwrap_or_else(|e| e.into_inner())
+			.push((name.into(), hook));
+	}
+
+	/// Run all registered shutdown hooks
+	///
+	/// Each hook is bounded by the shutdown timeout so that a stuck hook cannot
+	/// block process exit. Hooks are consumed; calling this twice runs nothing
+	/// the second time.
+	pub async fn run_hooks(&self) {
+		let hooks = std::mem::take(&mut *self.hooks.lock().unwrap_or_else(|e| e.into_inner()));
+		for (name, hook) in hooks {
+			if timeout(self.timeout_duration, hook()).await.is_err() {
+				eprintln!(
+					"Shutdown hook '{}' did not finish within {:?}",
+					name, self.timeout_duration
+				);
+			}
+		}
+	}
+
+	/// Perform a full graceful shutdown
+	///
+	/// Signals listeners to stop accepting, drains in-flight connections with
+	/// the configured deadline, then runs the registered shutdown hooks.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_server::server::shutdown::ShutdownCoordinator;
+	/// use std::time::Duration;
+	///
+	/// # async fn example() {
+	/// let coordinator = ShutdownCoordinator::new(Duration::from_secs(30));
+	/// coordinator.graceful_shutdown().await;
+	/// # }
+	/// ```
+	pub async fn graceful_shutdown(&self) {
+		self.shutdown();
+		if !self.drain().await {
+			eprintln!(
+				"Shutdown timeout after {:?} with {} connection(s) still open",
+				self.timeout_duration,
+				self.active_connections()
+			);
+		}
+		self.run_hooks().await;
+	}
 }
 
 /// Listen for OS shutdown signals (SIGTERM, SIGINT)
@@ -346,4 +538,58 @@ mod tests {
 		let result = with_shutdown(work, shutdown_rx).await;
 		assert_eq!(result, None);
 	}
+
+	#[tokio::test]
+	async fn test_drain_waits_for_connections() {
+		// Arrange
+		let coordinator = ShutdownCoordinator::new(Duration::from_secs(1));
+		let guard = coordinator.track_connection();
+		tokio::spawn(async move {
+			tokio::time::sleep(Duration::from_millis(50)).await;
+			drop(guard);
+		});
+
+		// Act
+		let drained = coordinator.drain().await;
+
+		// Assert
+		assert!(drained);
+		assert_eq!(coordinator.active_connections(), 0);
+	}
+
+	#[tokio::test]
+	async fn test_drain_times_out_with_open_connection() {
+		// Arrange
+		let coordinator = ShutdownCoordinator::new(Duration::from_millis(50));
+		let _guard = coordinator.track_connection();
+
+		// Act
+		let drained = coordinator.drain().await;
+
+		// Assert
+		assert!(!drained);
+		assert_eq!(coordinator.active_connections(), 1);
+	}
+
+	#[tokio::test]
+	async fn test_graceful_shutdown_runs_hooks_in_order() {
+		// Arrange
+		let coordinator = ShutdownCoordinator::new(Duration::from_secs(1));
+		let order = Arc::new(Mutex::new(Vec::new()));
+		for name in ["flush-tasks", "close-pool"] {
+			let order = order.clone();
+			coordinator.register_hook(name, move || async move {
+				order.lock().unwrap().push(name);
+			});
+		}
+		let mut rx = coordinator.subscribe();
+
+		// Act
+		coordinator.graceful_shutdown().await;
+		coordinator.run_hooks().await;
+
+		// Assert
+		assert!(rx.recv().await.is_ok());
+		assert_eq!(*order.lock().unwrap(), vec!["flush-tasks", "close-pool"]);
+	}
 }
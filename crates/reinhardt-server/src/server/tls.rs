@@ -0,0 +1,202 @@
+//! rustls-based TLS termination with certificate reload.
+//!
+//! [`ReloadableTlsAcceptor`] loads a PEM certificate chain and private key
+//! from disk and can swap them at runtime without restarting the server,
+//! either explicitly via [`ReloadableTlsAcceptor::reload`] or periodically via
+//! [`ReloadableTlsAcceptor::spawn_reload_task`]. Connections that are already
+//! established keep the certificate they were accepted with.
+
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+use tokio_rustls::TlsAcceptor;
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer};
+
+use super::server_settings::TlsSettings;
+
+/// ALPN protocol identifier for HTTP/2
+const ALPN_H2: &[u8] = b"h2";
+/// ALPN protocol identifier for HTTP/1.1
+const ALPN_HTTP11: &[u8] = b"http/1.1";
+
+/// TLS acceptor whose certificate can be reloaded at runtime
+///
+/// Cloning is cheap and clones share the same certificate state.
+///
+/// # Examples
+///
+/// ```no_run
+/// use reinhardt_server::server::TlsSettings;
+/// use reinhardt_server::server::tls::ReloadableTlsAcceptor;
+///
+/// let settings = TlsSettings {
+///     cert_path: "cert.pem".into(),
+///     key_path: "key.pem".into(),
+///     reload_interval_secs: Some(3600),
+/// };
+/// let acceptor = ReloadableTlsAcceptor::from_settings(&settings, true)?;
+/// // After renewing the certificate on disk:
+/// acceptor.reload()?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone)]
+pub struct ReloadableTlsAcceptor {
+	settings: TlsSettings,
+	alpn_protocols: Vec<Vec<u8>>,
+	current: Arc<RwLock<TlsAcceptor>>,
+}
+
+impl ReloadableTlsAcceptor {
+	/// Load the certificate and key described by `settings`
+	///
+	/// When `http2` is `true`, `h2` is advertised through ALPN ahead of
+	/// `http/1.1`.
+	pub fn from_settings(settings: &TlsSettings, http2: bool) -> io::Result<Self> {
+		let alpn_protocols = if http2 {
+			vec![ALPN_H2.to_vec(), ALPN_HTTP11.to_vec()]
+		} else {
+			vec![ALPN_HTTP11.to_vec()]
+		};
+		let acceptor = build_acceptor(settings, &alpn_protocols)?;
+
+		Ok(Self {
+			settings: settings.clone(),
+			alpn_protocols,
+			current: Arc::new(RwLock::new(acceptor)),
+		})
+	}
+
+	/// Re-read the certificate and key from disk
+	///
+	/// On error the previously loaded certificate stays active.
+	pub fn reload(&self) -> io::Result<()> {
+		let acceptor = build_acceptor(&self.settings, &self.alpn_protocols)?;
+		*self.current.write().unwrap_or_else(|e| e.into_inner()) = acceptor;
+		Ok(())
+	}
+
+	/// Get the acceptor for the currently loaded certificate
+	pub fn acceptor(&self) -> TlsAcceptor {
+		self.current
+			.read()
+			.unwrap_or_else(|e| e.into_inner())
+			.clone()
+	}
+
+	/// ALPN protocols advertised to clients
+	pub fn alpn_protocols(&self) -> &[Vec<u8>] {
+		&self.alpn_protocols
+	}
+
+	/// Spawn a background task reloading the certificate every `interval`
+	///
+	/// Reload failures are logged and the previous certificate is kept. Abort
+	/// the returned handle to stop reloading.
+	pub fn spawn_reload_task(&self, interval: Duration) -> JoinHandle<()> {
+		let this = self.clone();
+		tokio::spawn(async move {
+			let mut ticker = tokio::time::interval(interval);
+			// The first tick completes immediately; the certificate was just loaded.
+			ticker.tick().await;
+			loop {
+				ticker.tick().await;
+				if let Err(err) = this.reload() {
+					eprintln!(
+						"Failed to reload TLS certificate from {}: {}",
+						this.settings.cert_path.display(),
+						err
+					);
+				}
+			}
+		})
+	}
+}
+
+fn build_acceptor(settings: &TlsSettings, alpn_protocols: &[Vec<u8>]) -> io::Result<TlsAcceptor> {
+	let certs = load_certs(&settings.cert_path)?;
+	let key = load_private_key(&settings.key_path)?;
+
+	let mut config = ServerConfig::builder()
+		.with_no_client_auth()
+		.with_single_cert(certs, key)
+		.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+	config.alpn_protocols = alpn_protocols.to_vec();
+
+	Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+fn load_certs(path: &Path) -> io::Result<Vec<CertificateDer<'static>>> {
+	let mut reader = BufReader::new(File::open(path)?);
+	let certs = rustls_pemfile::certs(&mut reader).collect::<io::Result<Vec<_>>>()?;
+	if certs.is_empty() {
+		return Err(io::Error::new(
+			io::ErrorKind::InvalidData,
+			format!("no certificates found in {}", path.display()),
+		));
+	}
+	Ok(certs)
+}
+
+fn load_private_key(path: &Path) -> io::Result<PrivateKeyDer<'static>> {
+	let mut reader = BufReader::new(File::open(path)?);
+	rustls_pemfile::private_key(&mut reader)?.ok_or_else(|| {
+		io::Error::new(
+			io::ErrorKind::InvalidData,
+			format!("no private key found in {}", path.display()),
+		)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use std::io::Write;
+
+	#[rstest]
+	fn test_missing_certificate_file_is_not_found() {
+		// Arrange
+		let settings = TlsSettings {
+			cert_path: "/nonexistent/cert.pem".into(),
+			key_path: "/nonexistent/key.pem".into(),
+			reload_interval_secs: None,
+		};
+
+		// Act
+		let result = ReloadableTlsAcceptor::from_settings(&settings, true);
+
+		// Assert
+		assert_eq!(result.err().unwrap().kind(), io::ErrorKind::NotFound);
+	}
+
+	#[rstest]
+	fn test_pem_without_certificates_is_invalid() {
+		// Arrange
+		let mut file = tempfile::NamedTempFile::new().unwrap();
+		writeln!(file, "not a certificate").unwrap();
+
+		// Act
+		let result = load_certs(file.path());
+
+		// Assert
+		assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[rstest]
+	fn test_pem_without_private_key_is_invalid() {
+		// Arrange
+		let mut file = tempfile::NamedTempFile::new().unwrap();
+		writeln!(file, "not a key").unwrap();
+
+		// Act
+		let result = load_private_key(file.path());
+
+		// Assert
+		assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+	}
+}
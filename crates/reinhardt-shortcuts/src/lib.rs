@@ -93,6 +93,7 @@ pub mod get_or_404;
 pub mod redirect;
 /// Response rendering utilities (JSON, HTML, text).
 pub mod render;
+pub mod route;
 /// Common security header helpers.
 pub mod security_headers;
 /// URL validation and manipulation utilities.
@@ -111,6 +112,7 @@ pub use redirect::{redirect, redirect_permanent, redirect_permanent_to, redirect
 pub use reinhardt_core::security::escape_html;
 pub use reinhardt_core::security::redirect::RedirectValidationError;
 pub use render::{render_html, render_html_safe, render_json, render_json_pretty, render_text};
+pub use route::{created_at_route, see_other_route};
 pub use security_headers::security_headers;
 pub use url::{Url, UrlError};
 
@@ -0,0 +1,103 @@
+//! Route-based response shortcuts
+//!
+//! Builds responses whose `Location` header is resolved from a route name
+//! with [`UrlReverser`], so handlers don't hard-code URLs.
+
+use reinhardt_http::Response;
+use reinhardt_urls::routers::{ReverseResult, UrlReverser};
+
+/// Create an HTTP 201 Created response pointing at a named route.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_shortcuts::created_at_route;
+/// use reinhardt_urls::routers::UrlReverser;
+///
+/// let mut reverser = UrlReverser::new();
+/// reverser.register_path("order-detail", "/orders/{id}/").unwrap();
+///
+/// let response = created_at_route(&reverser, "order-detail", &[("id", "42")]).unwrap();
+/// assert_eq!(response.status, 201);
+/// assert_eq!(response.headers.get("location").unwrap(), "/orders/42/");
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the route is unknown or a parameter is missing.
+pub fn created_at_route<S: AsRef<str>>(
+	reverser: &UrlReverser,
+	name: &str,
+	params: &[(S, S)],
+) -> ReverseResult<Response> {
+	Ok(Response::created_at(reverser.reverse_with(name, params)?))
+}
+
+/// Create an HTTP 303 See Other redirect to a named route.
+///
+/// This is the redirect to send after handling a form submission.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_shortcuts::see_other_route;
+/// use reinhardt_urls::routers::UrlReverser;
+///
+/// let mut reverser = UrlReverser::new();
+/// reverser.register_path("order-list", "/orders/").unwrap();
+///
+/// let response = see_other_route::<&str>(&reverser, "order-list", &[]).unwrap();
+/// assert_eq!(response.status, 303);
+/// assert_eq!(response.headers.get("location").unwrap(), "/orders/");
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the route is unknown or a parameter is missing.
+pub fn see_other_route<S: AsRef<str>>(
+	reverser: &UrlReverser,
+	name: &str,
+	params: &[(S, S)],
+) -> ReverseResult<Response> {
+	Ok(Response::see_other(reverser.reverse_with(name, params)?))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use hyper::StatusCode;
+	use rstest::rstest;
+
+	fn reverser() -> UrlReverser {
+		let mut reverser = UrlReverser::new();
+		reverser
+			.register_path("order-detail", "/orders/{id}/")
+			.unwrap();
+		reverser
+	}
+
+	#[rstest]
+	fn test_created_at_route_sets_location() {
+		// Arrange
+		let reverser = reverser();
+
+		// Act
+		let response = created_at_route(&reverser, "order-detail", &[("id", "7")]).unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::CREATED);
+		assert_eq!(response.headers.get("location").unwrap(), "/orders/7/");
+	}
+
+	#[rstest]
+	fn test_see_other_route_rejects_unknown_route() {
+		// Arrange
+		let reverser = reverser();
+
+		// Act
+		let result = see_other_route::<&str>(&reverser, "missing", &[]);
+
+		// Assert
+		assert!(result.is_err());
+	}
+}
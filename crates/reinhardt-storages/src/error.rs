@@ -33,6 +33,27 @@ pub enum StorageError {
 	#[error("I/O error: {0}")]
 	IoError(#[from] std::io::Error),
 
+	/// The upload scanner flagged the file and it was not stored.
+	#[error("File rejected by upload scanner: {signature}")]
+	Flagged {
+		/// The signature that matched.
+		signature: String,
+	},
+
+	/// The upload scanner flagged the file and it was stored under the
+	/// quarantine prefix instead.
+	#[error("File quarantined by upload scanner as {path}: {signature}")]
+	Quarantined {
+		/// Name the file was quarantined under.
+		path: String,
+		/// The signature that matched.
+		signature: String,
+	},
+
+	/// The upload scanner failed; the file was not stored.
+	#[error("Upload scan failed: {0}")]
+	ScanFailed(String),
+
 	/// Other errors not covered by specific variants.
 	#[error("Storage error: {0}")]
 	Other(String),
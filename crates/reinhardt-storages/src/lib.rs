@@ -14,6 +14,9 @@
 //! - **Feature Flags**: Enable only the backends you need
 //! - **Temporary URLs**: Generate S3 presigned URLs, GCS V4 signed URLs, and
 //!   Azure SAS URLs for secure file sharing
+//! - **Upload scanning**: `save_scanned` runs an `UploadScanner` (such as
+//!   ClamAV with the `clamav` feature) before saving, and rejects or
+//!   quarantines flagged files per `UploadScanSettings`
 //! - **Provider boundary**: S3 uses `reinhardt-providers` for minimal HTTP and
 //!   SigV4 support instead of depending on the full AWS SDK
 //!
@@ -51,6 +54,7 @@ pub mod backends;
 pub mod config;
 pub mod error;
 pub mod factory;
+pub mod scan;
 pub mod settings;
 
 pub use backend::StorageBackend;
@@ -66,4 +70,6 @@ pub use settings::GcsStorageSettings;
 pub use settings::LocalStorageSettings;
 #[cfg(feature = "s3")]
 pub use settings::S3StorageSettings;
+pub use scan::save_scanned;
 pub use settings::StorageSettings;
+pub use settings::UploadScanSettings;
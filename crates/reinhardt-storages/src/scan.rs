@@ -0,0 +1,65 @@
+//! Upload scanning for storage saves.
+//!
+//! [`save_scanned`] runs an [`UploadScanner`] over the content before handing
+//! it to a [`StorageBackend`], and applies the [`UploadScanSettings`] policy to
+//! flagged files. Scanners are synchronous and may perform network I/O (as
+//! `clamd` does), so they run on Tokio's blocking thread pool.
+
+use crate::settings::UploadScanSettings;
+use crate::{Result, StorageBackend, StorageError};
+use reinhardt_core::security::upload_scan::{ScanPolicy, ScanVerdict, UploadScanner};
+use std::sync::Arc;
+
+/// Scan `content` and save it as `name` if the scanner finds it clean.
+///
+/// Flagged files are never saved under `name`: with
+/// [`ScanPolicy::Reject`] nothing is stored and [`StorageError::Flagged`] is
+/// returned; with [`ScanPolicy::Quarantine`] the file is saved under the
+/// configured quarantine prefix and [`StorageError::Quarantined`] is
+/// returned. Scanner failures return [`StorageError::ScanFailed`] without
+/// storing anything.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reinhardt_core::security::upload_scan::FileTypeScanner;
+/// use reinhardt_storages::scan::save_scanned;
+/// use reinhardt_storages::{StorageBackend, UploadScanSettings};
+/// use std::sync::Arc;
+///
+/// async fn upload(storage: &dyn StorageBackend, content: Vec<u8>) -> reinhardt_storages::Result<String> {
+///     let scanner = Arc::new(FileTypeScanner::new());
+///     save_scanned(storage, scanner, &UploadScanSettings::default(), "report.pdf", content).await
+/// }
+/// ```
+pub async fn save_scanned(
+	storage: &dyn StorageBackend,
+	scanner: Arc<dyn UploadScanner>,
+	settings: &UploadScanSettings,
+	name: &str,
+	content: Vec<u8>,
+) -> Result<String> {
+	let scan_name = name.to_string();
+	let (verdict, content) = tokio::task::spawn_blocking(move || {
+		let verdict = scanner.scan(&scan_name, &content);
+		(verdict, content)
+	})
+	.await
+	.map_err(|e| StorageError::ScanFailed(e.to_string()))?;
+
+	match verdict.map_err(|e| StorageError::ScanFailed(e.to_string()))? {
+		ScanVerdict::Clean => storage.save(name, &content).await,
+		ScanVerdict::Flagged { signature } => match settings.policy {
+			ScanPolicy::Reject => Err(StorageError::Flagged { signature }),
+			ScanPolicy::Quarantine => {
+				let quarantined = format!(
+					"{}/{}",
+					settings.quarantine_prefix.trim_end_matches('/'),
+					name.trim_start_matches('/')
+				);
+				let path = storage.save(&quarantined, &content).await?;
+				Err(StorageError::Quarantined { path, signature })
+			}
+		},
+	}
+}
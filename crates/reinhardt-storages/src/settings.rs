@@ -12,6 +12,7 @@ use reinhardt_conf::settings::{
 	validation::{ValidationError, ValidationResult},
 };
 use reinhardt_core::macros::settings;
+use reinhardt_core::security::upload_scan::ScanPolicy;
 use serde::{Deserialize, Serialize};
 
 fn default_backend() -> BackendType {
@@ -266,6 +267,75 @@ impl StorageSettings {
 	}
 }
 
+fn default_quarantine_prefix() -> String {
+	"quarantine/".to_string()
+}
+
+fn default_clamav_timeout_secs() -> u64 {
+	30
+}
+
+/// Upload scanning settings fragment.
+///
+/// Maps to the `[upload_scan]` section and configures how
+/// [`save_scanned`](crate::scan::save_scanned) treats flagged uploads.
+///
+/// ```toml
+/// [upload_scan]
+/// policy = "quarantine"
+/// quarantine_prefix = "quarantine/"
+/// clamav_address = "unix:///run/clamav/clamd.ctl"
+/// ```
+#[settings(fragment = true, section = "upload_scan")]
+#[non_exhaustive]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UploadScanSettings {
+	/// Whether flagged uploads are rejected or quarantined.
+	#[serde(default)]
+	pub policy: ScanPolicy,
+	/// Storage prefix quarantined files are saved under.
+	#[serde(default = "default_quarantine_prefix")]
+	pub quarantine_prefix: String,
+	/// Address of the `clamd` daemon (`unix:///path` or `host:port`).
+	#[serde(default)]
+	pub clamav_address: Option<String>,
+	/// Timeout for `clamd` connections, in seconds.
+	#[serde(default = "default_clamav_timeout_secs")]
+	pub clamav_timeout_secs: u64,
+}
+
+impl Default for UploadScanSettings {
+	fn default() -> Self {
+		Self {
+			policy: ScanPolicy::default(),
+			quarantine_prefix: default_quarantine_prefix(),
+			clamav_address: None,
+			clamav_timeout_secs: default_clamav_timeout_secs(),
+		}
+	}
+}
+
+impl UploadScanSettings {
+	/// Build the ClamAV scanner configured by `clamav_address`, if any.
+	#[cfg(feature = "clamav")]
+	pub fn clamav_scanner(
+		&self,
+	) -> Result<Option<reinhardt_core::security::upload_scan::ClamAvScanner>> {
+		use reinhardt_core::security::upload_scan::{ClamAvAddress, ClamAvScanner};
+
+		self.clamav_address
+			.as_deref()
+			.map(|address| {
+				let address = address
+					.parse::<ClamAvAddress>()
+					.map_err(StorageError::ConfigError)?;
+				Ok(ClamAvScanner::new(address)
+					.with_timeout(std::time::Duration::from_secs(self.clamav_timeout_secs)))
+			})
+			.transpose()
+	}
+}
+
 fn missing_section(section: &str) -> StorageError {
 	StorageError::ConfigError(format!("Selected backend requires [{section}] settings"))
 }
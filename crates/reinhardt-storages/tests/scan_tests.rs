@@ -0,0 +1,110 @@
+//! Tests for scanned storage saves.
+
+#![cfg(feature = "local")]
+
+use reinhardt_core::security::upload_scan::{FileTypeScanner, ScanPolicy};
+use reinhardt_storages::{
+	StorageBackend, StorageError, StorageSettings, UploadScanSettings,
+	create_storage_from_settings, save_scanned,
+};
+use rstest::rstest;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+async fn local_storage(dir: &TempDir) -> Arc<dyn StorageBackend> {
+	let settings: StorageSettings = toml::from_str(&format!(
+		"backend = \"local\"\n\n[local]\nbase_path = {:?}\n",
+		dir.path().to_str().unwrap()
+	))
+	.unwrap();
+	create_storage_from_settings(&settings).await.unwrap()
+}
+
+fn scan_settings(policy: &str) -> UploadScanSettings {
+	toml::from_str(&format!("policy = \"{}\"", policy)).unwrap()
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_save_scanned_stores_clean_file() {
+	// Arrange
+	let dir = TempDir::new().unwrap();
+	let storage = local_storage(&dir).await;
+
+	// Act
+	let path = save_scanned(
+		storage.as_ref(),
+		Arc::new(FileTypeScanner::new()),
+		&UploadScanSettings::default(),
+		"reports/q3.pdf",
+		b"%PDF-1.7".to_vec(),
+	)
+	.await
+	.unwrap();
+
+	// Assert
+	assert_eq!(storage.open(&path).await.unwrap(), b"%PDF-1.7");
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_save_scanned_rejects_flagged_file() {
+	// Arrange
+	let dir = TempDir::new().unwrap();
+	let storage = local_storage(&dir).await;
+
+	// Act
+	let result = save_scanned(
+		storage.as_ref(),
+		Arc::new(FileTypeScanner::new()),
+		&scan_settings("reject"),
+		"reports/q3.pdf",
+		b"MZ\x90\x00".to_vec(),
+	)
+	.await;
+
+	// Assert
+	assert!(
+		matches!(result, Err(StorageError::Flagged { ref signature }) if signature == "Executable.Windows.PE")
+	);
+	assert!(!storage.exists("reports/q3.pdf").await.unwrap());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_save_scanned_quarantines_flagged_file() {
+	// Arrange
+	let dir = TempDir::new().unwrap();
+	let storage = local_storage(&dir).await;
+	let settings = scan_settings("quarantine");
+	assert_eq!(settings.policy, ScanPolicy::Quarantine);
+
+	// Act
+	let result = save_scanned(
+		storage.as_ref(),
+		Arc::new(FileTypeScanner::new()),
+		&settings,
+		"reports/q3.pdf",
+		b"MZ\x90\x00".to_vec(),
+	)
+	.await;
+
+	// Assert
+	let Err(StorageError::Quarantined { path, .. }) = result else {
+		panic!("expected quarantined file, got {:?}", result);
+	};
+	assert_eq!(path, "quarantine/reports/q3.pdf");
+	assert!(storage.exists(&path).await.unwrap());
+	assert!(!storage.exists("reports/q3.pdf").await.unwrap());
+}
+
+#[rstest]
+fn test_upload_scan_settings_defaults() {
+	// Act
+	let settings: UploadScanSettings = toml::from_str("").unwrap();
+
+	// Assert
+	assert_eq!(settings.policy, ScanPolicy::Reject);
+	assert_eq!(settings.quarantine_prefix, "quarantine/");
+	assert_eq!(settings.clamav_address, None);
+}
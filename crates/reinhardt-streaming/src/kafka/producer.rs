@@ -70,6 +70,46 @@ impl KafkaProducer {
 			)));
 		}
 
+		self.produce(
+			topic,
+			partition,
+			Record {
+				key: None,
+				value: Some(payload),
+				headers: BTreeMap::new(),
+				timestamp: Utc::now(),
+			},
+		)
+		.await
+	}
+
+	/// Publish raw bytes with a record key and headers to `topic` (partition 0).
+	pub async fn send_record(
+		&self,
+		topic: &str,
+		key: Option<Vec<u8>>,
+		headers: BTreeMap<String, Vec<u8>>,
+		payload: Vec<u8>,
+	) -> Result<(), StreamingError> {
+		self.produce(
+			topic,
+			0,
+			Record {
+				key,
+				value: Some(payload),
+				headers,
+				timestamp: Utc::now(),
+			},
+		)
+		.await
+	}
+
+	async fn produce(
+		&self,
+		topic: &str,
+		partition: i32,
+		record: Record,
+	) -> Result<(), StreamingError> {
 		let partition_client = self
 			.client
 			.partition_client(topic, partition, UnknownTopicHandling::Retry)
@@ -77,15 +117,7 @@ impl KafkaProducer {
 			.map_err(|e| StreamingError::Backend(e.to_string()))?;
 
 		partition_client
-			.produce(
-				vec![Record {
-					key: None,
-					value: Some(payload),
-					headers: BTreeMap::new(),
-					timestamp: Utc::now(),
-				}],
-				Default::default(),
-			)
+			.produce(vec![record], Default::default())
 			.await
 			.map_err(|e| StreamingError::Backend(e.to_string()))?;
 
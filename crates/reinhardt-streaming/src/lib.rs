@@ -3,6 +3,9 @@
 //! # Features
 //!
 //! - `kafka` — Kafka backend using rskafka (pure Rust, no C bindings)
+//! - `signals` — transactional outbox bridge between signals and brokers
+//!   ([`signal_bridge`])
+//! - `nats` / `rabbitmq` — NATS and RabbitMQ publishers for the signal bridge
 //!
 //! The Kafka-backed `TaskBackend` lives in `reinhardt-tasks` behind its
 //! `kafka-backend` feature (not here).
@@ -43,6 +46,11 @@ pub mod global;
 #[cfg(feature = "kafka")]
 pub use global::{global_producer, set_global_producer};
 
+#[cfg(feature = "signals")]
+/// Outbox-based bridge publishing signals to brokers and dispatching
+/// consumed events on signals.
+pub mod signal_bridge;
+
 /// Streaming DSL helper macros.
 pub mod macros;
 /// Router registration types for streaming handlers.
@@ -0,0 +1,57 @@
+//! Transactional outbox bridge between signals and message brokers.
+//!
+//! Requires feature `signals`; the broker integrations additionally require
+//! `kafka`, `nats` or `rabbitmq`.
+//!
+//! Outgoing events are recorded in an [`OutboxStore`] — ideally within the
+//! database transaction that changed the data they describe — as
+//! [`EventEnvelope`]s tagged with a [`SchemaTag`]. An [`OutboxRelay`]
+//! publishes them through an [`EventPublisher`] using per-signal
+//! [`TopicRouting`]. On the receiving side a [`SignalConsumer`] reads
+//! envelopes from an [`EventSource`] and dispatches them on local signals.
+//!
+//! ```rust,ignore
+//! use reinhardt_streaming::signal_bridge::{
+//!     InMemoryOutboxStore, Outbox, OutboxRelay, SchemaTag, TopicRoute, TopicRouting,
+//!     kafka::KafkaEventPublisher,
+//! };
+//!
+//! let store = Arc::new(InMemoryOutboxStore::new());
+//! Outbox::new(store.clone())
+//!     .with_source("shop")
+//!     .bridge(&order_created, SchemaTag::new("orders.created", 1));
+//!
+//! let relay = OutboxRelay::new(
+//!     store,
+//!     Arc::new(KafkaEventPublisher::new(producer)),
+//!     TopicRouting::new().route("order_created", TopicRoute::new("orders").with_key_field("id")),
+//! );
+//! relay.run(Duration::from_secs(1), shutdown).await;
+//! ```
+
+/// Signal consumer runtime and event sources.
+pub mod consumer;
+/// Schema-tagged event envelopes.
+pub mod envelope;
+/// Outbox storage and recording.
+pub mod outbox;
+/// Broker publisher abstraction.
+pub mod publisher;
+/// Relay publishing outbox events.
+pub mod relay;
+/// Per-signal topic routing.
+pub mod routing;
+
+#[cfg(feature = "kafka")]
+pub mod kafka;
+#[cfg(feature = "nats")]
+pub mod nats;
+#[cfg(feature = "rabbitmq")]
+pub mod rabbitmq;
+
+pub use consumer::{BackendSource, EventSource, SignalConsumer};
+pub use envelope::{EventEnvelope, SchemaTag};
+pub use outbox::{InMemoryOutboxStore, Outbox, OutboxEntry, OutboxStore};
+pub use publisher::{BackendPublisher, EventPublisher, OutboundMessage};
+pub use relay::{OutboxRelay, RelayReport};
+pub use routing::{ResolvedRoute, TopicRoute, TopicRouting};
@@ -0,0 +1,262 @@
+use super::envelope::{EventEnvelope, SchemaTag};
+use crate::{StreamingBackend, StreamingError};
+use async_trait::async_trait;
+use reinhardt_core::signals::{DispatchReport, Signal};
+use serde::de::DeserializeOwned;
+use std::{collections::HashMap, future::Future, pin::Pin, sync::Arc, time::Duration};
+
+/// A stream of encoded events received from a broker.
+#[async_trait]
+pub trait EventSource: Send + Sync {
+	/// Return the next event, or `None` if none is available right now.
+	async fn next(&self) -> Result<Option<Vec<u8>>, StreamingError>;
+}
+
+/// Reads events from one topic of a [`StreamingBackend`].
+pub struct BackendSource {
+	backend: Arc<dyn StreamingBackend>,
+	topic: String,
+}
+
+impl BackendSource {
+	/// Read events from `topic` of `backend`.
+	pub fn new(backend: Arc<dyn StreamingBackend>, topic: impl Into<String>) -> Self {
+		Self {
+			backend,
+			topic: topic.into(),
+		}
+	}
+}
+
+#[async_trait]
+impl EventSource for BackendSource {
+	async fn next(&self) -> Result<Option<Vec<u8>>, StreamingError> {
+		self.backend.poll(&self.topic).await
+	}
+}
+
+type DispatchFuture = Pin<Box<dyn Future<Output = Result<DispatchReport, StreamingError>> + Send>>;
+type DispatchFn = Arc<dyn Fn(EventEnvelope) -> DispatchFuture + Send + Sync>;
+
+struct Registration {
+	schema: SchemaTag,
+	dispatch: DispatchFn,
+}
+
+/// Dispatches events received from brokers to local signals.
+///
+/// Each registered signal accepts events whose schema has the registered
+/// name and a version up to the registered one; newer versions are
+/// rejected rather than decoded with an outdated type.
+///
+/// ```
+/// use reinhardt_core::signals::{Signal, SignalName};
+/// use reinhardt_streaming::signal_bridge::{SchemaTag, SignalConsumer};
+///
+/// let order_created = Signal::<serde_json::Value>::new(SignalName::custom("order_created"));
+/// let consumer = SignalConsumer::new()
+///     .register(order_created, SchemaTag::new("orders.created", 2))
+///     .ignore_source("billing");
+/// ```
+pub struct SignalConsumer {
+	registrations: HashMap<String, Registration>,
+	ignored_source: Option<String>,
+	poll_interval: Duration,
+}
+
+impl Default for SignalConsumer {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl SignalConsumer {
+	/// Create a consumer without registered signals.
+	pub fn new() -> Self {
+		Self {
+			registrations: HashMap::new(),
+			ignored_source: None,
+			poll_interval: Duration::from_millis(100),
+		}
+	}
+
+	/// Dispatch events named after `signal` on it.
+	pub fn register<T>(mut self, signal: Signal<T>, schema: SchemaTag) -> Self
+	where
+		T: DeserializeOwned + Send + Sync + 'static,
+	{
+		let name = signal.name().to_owned();
+		let dispatch: DispatchFn = Arc::new(move |envelope: EventEnvelope| {
+			let signal = signal.clone();
+			Box::pin(async move {
+				let payload: T = envelope.payload_as()?;
+				Ok::<_, StreamingError>(signal.dispatch(payload).await)
+			}) as DispatchFuture
+		});
+		self.registrations
+			.insert(name, Registration { schema, dispatch });
+		self
+	}
+
+	/// Skip events produced by `source`, typically this service, so that
+	/// bridged signals are not dispatched twice.
+	pub fn ignore_source(mut self, source: impl Into<String>) -> Self {
+		self.ignored_source = Some(source.into());
+		self
+	}
+
+	/// Set how long [`run`](Self::run) waits when no event is available
+	/// (default: 100ms).
+	pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+		self.poll_interval = interval;
+		self
+	}
+
+	/// Decode an event and dispatch it on its signal.
+	///
+	/// Returns `None` for events from the ignored source.
+	pub async fn handle(&self, bytes: &[u8]) -> Result<Option<DispatchReport>, StreamingError> {
+		let envelope = EventEnvelope::decode(bytes)?;
+		if self.ignored_source.is_some() && envelope.source == self.ignored_source {
+			return Ok(None);
+		}
+
+		let registration = self.registrations.get(&envelope.signal).ok_or_else(|| {
+			StreamingError::Fatal(format!(
+				"no signal registered for event `{}`",
+				envelope.signal
+			))
+		})?;
+		let expected = &registration.schema;
+		if envelope.schema.name != expected.name || envelope.schema.version > expected.version {
+			return Err(StreamingError::Fatal(format!(
+				"event `{}` has schema {}, expected {} or older",
+				envelope.id, envelope.schema, expected
+			)));
+		}
+		(registration.dispatch)(envelope).await.map(Some)
+	}
+
+	/// Dispatch events from `source` until `shutdown` completes.
+	///
+	/// Events that cannot be decoded or dispatched, and receiver failures,
+	/// are logged and skipped.
+	pub async fn run(&self, source: &dyn EventSource, shutdown: impl Future<Output = ()>) {
+		tokio::pin!(shutdown);
+		loop {
+			let wait = match source.next().await {
+				Ok(Some(bytes)) => {
+					match self.handle(&bytes).await {
+						Ok(Some(report)) if !report.is_ok() => {
+							tracing::warn!(
+								signal = %report.signal,
+								failures = report.failures.len(),
+								"receivers failed for consumed event"
+							);
+						}
+						Ok(_) => {}
+						Err(error) => {
+							tracing::warn!(error = %error, "skipping consumed event");
+						}
+					}
+					Duration::ZERO
+				}
+				Ok(None) => self.poll_interval,
+				Err(error) => {
+					tracing::warn!(error = %error, "failed to receive event");
+					self.poll_interval
+				}
+			};
+			tokio::select! {
+				_ = &mut shutdown => return,
+				_ = tokio::time::sleep(wait) => {}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reinhardt_core::signals::SignalName;
+	use rstest::*;
+	use std::sync::atomic::{AtomicU64, Ordering};
+
+	fn encoded(signal: &str, schema: SchemaTag, source: &str) -> Vec<u8> {
+		EventEnvelope::new(signal, schema, &7u64)
+			.unwrap()
+			.with_source(source)
+			.encode()
+			.unwrap()
+	}
+
+	fn consumer(received: Arc<AtomicU64>) -> SignalConsumer {
+		let signal = Signal::<u64>::new(SignalName::custom("order_paid"));
+		signal.connect(move |value: Arc<u64>| {
+			let received = received.clone();
+			async move {
+				received.fetch_add(*value, Ordering::SeqCst);
+				Ok(())
+			}
+		});
+		SignalConsumer::new()
+			.register(signal, SchemaTag::new("orders.paid", 2))
+			.ignore_source("self")
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn handle_dispatches_on_signal() {
+		// Arrange
+		let received = Arc::new(AtomicU64::new(0));
+		let consumer = consumer(received.clone());
+
+		// Act
+		let report = consumer
+			.handle(&encoded(
+				"order_paid",
+				SchemaTag::new("orders.paid", 1),
+				"shop",
+			))
+			.await
+			.unwrap()
+			.unwrap();
+
+		// Assert
+		assert!(report.is_ok());
+		assert_eq!(received.load(Ordering::SeqCst), 7);
+	}
+
+	#[rstest]
+	#[case::newer_version(SchemaTag::new("orders.paid", 3))]
+	#[case::other_schema(SchemaTag::new("orders.refunded", 1))]
+	#[tokio::test]
+	async fn handle_rejects_unknown_schema(#[case] schema: SchemaTag) {
+		let consumer = consumer(Arc::new(AtomicU64::new(0)));
+
+		let result = consumer
+			.handle(&encoded("order_paid", schema, "shop"))
+			.await;
+
+		assert!(matches!(result, Err(StreamingError::Fatal(_))));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn handle_skips_ignored_source() {
+		let received = Arc::new(AtomicU64::new(0));
+		let consumer = consumer(received.clone());
+
+		let result = consumer
+			.handle(&encoded(
+				"order_paid",
+				SchemaTag::new("orders.paid", 2),
+				"self",
+			))
+			.await
+			.unwrap();
+
+		assert!(result.is_none());
+		assert_eq!(received.load(Ordering::SeqCst), 0);
+	}
+}
@@ -0,0 +1,174 @@
+use crate::StreamingError;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::{
+	collections::BTreeMap,
+	fmt,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Header carrying the envelope ID.
+pub const EVENT_ID_HEADER: &str = "x-reinhardt-event-id";
+/// Header carrying the signal name.
+pub const SIGNAL_HEADER: &str = "x-reinhardt-signal";
+/// Header carrying the schema tag (`name@version`).
+pub const SCHEMA_HEADER: &str = "x-reinhardt-schema";
+
+/// Name and version of the schema an event payload conforms to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SchemaTag {
+	/// Schema name, e.g. `orders.created`.
+	pub name: String,
+	/// Schema version. Consumers accept payloads up to the version they know.
+	pub version: u32,
+}
+
+impl SchemaTag {
+	/// Create a schema tag.
+	pub fn new(name: impl Into<String>, version: u32) -> Self {
+		Self {
+			name: name.into(),
+			version,
+		}
+	}
+}
+
+impl fmt::Display for SchemaTag {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}@{}", self.name, self.version)
+	}
+}
+
+/// A signal event as stored in the outbox and sent to brokers.
+///
+/// The envelope is serialized as JSON and is self-describing: consumers
+/// read the signal name and schema tag from it before decoding the payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventEnvelope {
+	/// Unique event ID (UUIDv7), usable for deduplication.
+	pub id: String,
+	/// Name of the signal the event was sent on.
+	pub signal: String,
+	/// Schema of `payload`.
+	pub schema: SchemaTag,
+	/// Service that produced the event.
+	pub source: Option<String>,
+	/// Time the event was recorded, in milliseconds since the Unix epoch.
+	pub occurred_at: u64,
+	/// Partitioning key. Overrides the key field of the topic route.
+	pub key: Option<String>,
+	/// Additional broker headers.
+	pub headers: BTreeMap<String, String>,
+	/// Event payload.
+	pub payload: serde_json::Value,
+}
+
+impl EventEnvelope {
+	/// Wrap `payload` in an envelope for `signal`.
+	pub fn new<T: Serialize>(
+		signal: impl Into<String>,
+		schema: SchemaTag,
+		payload: &T,
+	) -> Result<Self, StreamingError> {
+		let payload = serde_json::to_value(payload)
+			.map_err(|e| StreamingError::Serialization(e.to_string()))?;
+		let occurred_at = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.unwrap_or_default()
+			.as_millis() as u64;
+		Ok(Self {
+			id: uuid::Uuid::now_v7().to_string(),
+			signal: signal.into(),
+			schema,
+			source: None,
+			occurred_at,
+			key: None,
+			headers: BTreeMap::new(),
+			payload,
+		})
+	}
+
+	/// Set the producing service.
+	pub fn with_source(mut self, source: impl Into<String>) -> Self {
+		self.source = Some(source.into());
+		self
+	}
+
+	/// Set the partitioning key.
+	pub fn with_key(mut self, key: impl Into<String>) -> Self {
+		self.key = Some(key.into());
+		self
+	}
+
+	/// Add a broker header.
+	pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+		self.headers.insert(name.into(), value.into());
+		self
+	}
+
+	/// Serialize the envelope to JSON bytes.
+	pub fn encode(&self) -> Result<Vec<u8>, StreamingError> {
+		serde_json::to_vec(self).map_err(|e| StreamingError::Serialization(e.to_string()))
+	}
+
+	/// Deserialize an envelope from JSON bytes.
+	pub fn decode(bytes: &[u8]) -> Result<Self, StreamingError> {
+		serde_json::from_slice(bytes).map_err(|e| StreamingError::Serialization(e.to_string()))
+	}
+
+	/// Deserialize the payload.
+	pub fn payload_as<T: DeserializeOwned>(&self) -> Result<T, StreamingError> {
+		T::deserialize(&self.payload).map_err(|e| StreamingError::Serialization(e.to_string()))
+	}
+
+	/// Headers sent with the event: the envelope headers plus the event ID,
+	/// signal name and schema tag.
+	pub fn broker_headers(&self) -> Vec<(String, String)> {
+		let mut headers: Vec<(String, String)> = self
+			.headers
+			.iter()
+			.map(|(name, value)| (name.clone(), value.clone()))
+			.collect();
+		headers.push((EVENT_ID_HEADER.to_owned(), self.id.clone()));
+		headers.push((SIGNAL_HEADER.to_owned(), self.signal.clone()));
+		headers.push((SCHEMA_HEADER.to_owned(), self.schema.to_string()));
+		headers
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::*;
+	use serde_json::json;
+
+	#[rstest]
+	fn envelope_roundtrips_json() {
+		// Arrange
+		let envelope = EventEnvelope::new(
+			"order_created",
+			SchemaTag::new("orders.created", 2),
+			&json!({"id": 7}),
+		)
+		.unwrap()
+		.with_source("shop");
+
+		// Act
+		let decoded = EventEnvelope::decode(&envelope.encode().unwrap()).unwrap();
+
+		// Assert
+		assert_eq!(decoded, envelope);
+		assert_eq!(decoded.payload_as::<serde_json::Value>().unwrap()["id"], 7);
+	}
+
+	#[rstest]
+	fn broker_headers_include_schema_tag() {
+		let envelope = EventEnvelope::new("s", SchemaTag::new("orders.created", 2), &1)
+			.unwrap()
+			.with_header("tenant", "acme");
+
+		let headers = envelope.broker_headers();
+
+		assert!(headers.contains(&("tenant".to_owned(), "acme".to_owned())));
+		assert!(headers.contains(&(SCHEMA_HEADER.to_owned(), "orders.created@2".to_owned())));
+	}
+}
@@ -0,0 +1,73 @@
+//! Kafka publisher and source for the signal bridge.
+//!
+//! Requires features `signals` and `kafka`.
+
+use super::{
+	consumer::EventSource,
+	publisher::{EventPublisher, OutboundMessage},
+};
+use crate::{
+	StreamingError,
+	kafka::{KafkaConsumer, KafkaProducer},
+};
+use async_trait::async_trait;
+
+/// Publishes outbox events as Kafka records.
+///
+/// The route key becomes the record key and headers become record headers.
+pub struct KafkaEventPublisher {
+	producer: KafkaProducer,
+}
+
+impl KafkaEventPublisher {
+	/// Publish through `producer`.
+	pub fn new(producer: KafkaProducer) -> Self {
+		Self { producer }
+	}
+}
+
+#[async_trait]
+impl EventPublisher for KafkaEventPublisher {
+	async fn publish(&self, message: &OutboundMessage) -> Result<(), StreamingError> {
+		let headers = message
+			.headers
+			.iter()
+			.map(|(name, value)| (name.clone(), value.clone().into_bytes()))
+			.collect();
+		self.producer
+			.send_record(
+				&message.topic,
+				message.key.clone().map(String::into_bytes),
+				headers,
+				message.payload.clone(),
+			)
+			.await
+	}
+}
+
+/// Reads events from a Kafka topic.
+pub struct KafkaEventSource {
+	consumer: KafkaConsumer,
+	topic: String,
+}
+
+impl KafkaEventSource {
+	/// Read events from `topic` with `consumer`.
+	pub fn new(consumer: KafkaConsumer, topic: impl Into<String>) -> Self {
+		Self {
+			consumer,
+			topic: topic.into(),
+		}
+	}
+}
+
+#[async_trait]
+impl EventSource for KafkaEventSource {
+	async fn next(&self) -> Result<Option<Vec<u8>>, StreamingError> {
+		Ok(self
+			.consumer
+			.receive_raw(&self.topic)
+			.await?
+			.map(|(bytes, _offset)| bytes))
+	}
+}
@@ -0,0 +1,107 @@
+//! NATS publisher and source for the signal bridge.
+//!
+//! Requires feature `nats`.
+
+use super::{
+	consumer::EventSource,
+	publisher::{EventPublisher, OutboundMessage},
+};
+use crate::StreamingError;
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+/// Header carrying the route key; NATS subjects have no partitioning key.
+pub const KEY_HEADER: &str = "x-reinhardt-key";
+
+/// Publishes outbox events to NATS subjects.
+pub struct NatsEventPublisher {
+	client: async_nats::Client,
+}
+
+impl NatsEventPublisher {
+	/// Connect to the NATS server at `url`.
+	pub async fn connect(url: &str) -> Result<Self, StreamingError> {
+		let client = async_nats::connect(url)
+			.await
+			.map_err(|e| StreamingError::Connection(e.to_string()))?;
+		Ok(Self::new(client))
+	}
+
+	/// Publish through an existing client.
+	pub fn new(client: async_nats::Client) -> Self {
+		Self { client }
+	}
+}
+
+#[async_trait]
+impl EventPublisher for NatsEventPublisher {
+	async fn publish(&self, message: &OutboundMessage) -> Result<(), StreamingError> {
+		let mut headers = async_nats::HeaderMap::new();
+		for (name, value) in &message.headers {
+			headers.insert(name.as_str(), value.as_str());
+		}
+		if let Some(key) = &message.key {
+			headers.insert(KEY_HEADER, key.as_str());
+		}
+
+		self.client
+			.publish_with_headers(
+				message.topic.clone(),
+				headers,
+				message.payload.clone().into(),
+			)
+			.await
+			.map_err(|e| StreamingError::Backend(e.to_string()))?;
+		// Publishing only buffers the message; flush so that a successful
+		// return means the server received it
+		self.client
+			.flush()
+			.await
+			.map_err(|e| StreamingError::Retryable(e.to_string()))
+	}
+}
+
+/// Reads events from a NATS subscription.
+pub struct NatsEventSource {
+	subscriber: Mutex<async_nats::Subscriber>,
+	wait: Duration,
+}
+
+impl NatsEventSource {
+	/// Subscribe to `subject`.
+	pub async fn subscribe(
+		client: &async_nats::Client,
+		subject: impl Into<String>,
+	) -> Result<Self, StreamingError> {
+		let subscriber = client
+			.subscribe(subject.into())
+			.await
+			.map_err(|e| StreamingError::Connection(e.to_string()))?;
+		Ok(Self {
+			subscriber: Mutex::new(subscriber),
+			wait: Duration::from_millis(100),
+		})
+	}
+
+	/// Set how long [`EventSource::next`] waits for a message (default: 100ms).
+	pub fn with_wait(mut self, wait: Duration) -> Self {
+		self.wait = wait;
+		self
+	}
+}
+
+#[async_trait]
+impl EventSource for NatsEventSource {
+	async fn next(&self) -> Result<Option<Vec<u8>>, StreamingError> {
+		let mut subscriber = self.subscriber.lock().await;
+		match tokio::time::timeout(self.wait, subscriber.next()).await {
+			Ok(Some(message)) => Ok(Some(message.payload.to_vec())),
+			Ok(None) => Err(StreamingError::Connection(
+				"NATS subscription closed".to_owned(),
+			)),
+			Err(_) => Ok(None),
+		}
+	}
+}
@@ -0,0 +1,294 @@
+use super::envelope::{EventEnvelope, SchemaTag};
+use crate::StreamingError;
+use async_trait::async_trait;
+use reinhardt_core::signals::Signal;
+use serde::Serialize;
+use std::{
+	collections::VecDeque,
+	sync::{Arc, Mutex},
+};
+
+/// An event waiting in the outbox.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutboxEntry {
+	/// The event.
+	pub envelope: EventEnvelope,
+	/// Number of failed publish attempts.
+	pub attempts: u32,
+	/// Error of the last failed attempt.
+	pub last_error: Option<String>,
+}
+
+/// Storage for events waiting to be published.
+///
+/// For a transactional outbox, implement this over a table of the
+/// application database and append events with the same transaction that
+/// writes the data they describe, so that an event is stored if and only if
+/// the transaction commits. [`OutboxRelay`](super::OutboxRelay) then
+/// publishes stored events at least once.
+#[async_trait]
+pub trait OutboxStore: Send + Sync {
+	/// Append an event.
+	async fn append(&self, envelope: EventEnvelope) -> Result<(), StreamingError>;
+
+	/// Return up to `limit` pending events, oldest first.
+	async fn pending(&self, limit: usize) -> Result<Vec<OutboxEntry>, StreamingError>;
+
+	/// Remove a published event.
+	async fn mark_published(&self, id: &str) -> Result<(), StreamingError>;
+
+	/// Record a failed publish attempt; the event stays pending.
+	async fn mark_failed(&self, id: &str, error: &str) -> Result<(), StreamingError>;
+
+	/// Stop retrying an event.
+	async fn mark_dead(&self, id: &str, error: &str) -> Result<(), StreamingError>;
+}
+
+#[derive(Default)]
+struct MemoryState {
+	pending: VecDeque<OutboxEntry>,
+	dead: Vec<OutboxEntry>,
+}
+
+/// In-memory outbox store for tests and single-process setups.
+///
+/// Events are lost when the process exits, so this store does not give the
+/// delivery guarantees of a database-backed outbox.
+#[derive(Default)]
+pub struct InMemoryOutboxStore {
+	state: Mutex<MemoryState>,
+}
+
+impl InMemoryOutboxStore {
+	/// Create an empty store.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Return the events that were given up on.
+	pub fn dead_letters(&self) -> Vec<OutboxEntry> {
+		self.state.lock().unwrap().dead.clone()
+	}
+
+	fn update(
+		&self,
+		id: &str,
+		apply: impl FnOnce(&mut MemoryState, usize),
+	) -> Result<(), StreamingError> {
+		let mut state = self
+			.state
+			.lock()
+			.map_err(|e| StreamingError::Backend(e.to_string()))?;
+		let index = state
+			.pending
+			.iter()
+			.position(|entry| entry.envelope.id == id)
+			.ok_or_else(|| StreamingError::Backend(format!("outbox event `{id}` not found")))?;
+		apply(&mut state, index);
+		Ok(())
+	}
+}
+
+#[async_trait]
+impl OutboxStore for InMemoryOutboxStore {
+	async fn append(&self, envelope: EventEnvelope) -> Result<(), StreamingError> {
+		self.state
+			.lock()
+			.map_err(|e| StreamingError::Backend(e.to_string()))?
+			.pending
+			.push_back(OutboxEntry {
+				envelope,
+				attempts: 0,
+				last_error: None,
+			});
+		Ok(())
+	}
+
+	async fn pending(&self, limit: usize) -> Result<Vec<OutboxEntry>, StreamingError> {
+		Ok(self
+			.state
+			.lock()
+			.map_err(|e| StreamingError::Backend(e.to_string()))?
+			.pending
+			.iter()
+			.take(limit)
+			.cloned()
+			.collect())
+	}
+
+	async fn mark_published(&self, id: &str) -> Result<(), StreamingError> {
+		self.update(id, |state, index| {
+			state.pending.remove(index);
+		})
+	}
+
+	async fn mark_failed(&self, id: &str, error: &str) -> Result<(), StreamingError> {
+		self.update(id, |state, index| {
+			let entry = &mut state.pending[index];
+			entry.attempts += 1;
+			entry.last_error = Some(error.to_owned());
+		})
+	}
+
+	async fn mark_dead(&self, id: &str, error: &str) -> Result<(), StreamingError> {
+		self.update(id, |state, index| {
+			if let Some(mut entry) = state.pending.remove(index) {
+				entry.attempts += 1;
+				entry.last_error = Some(error.to_owned());
+				state.dead.push(entry);
+			}
+		})
+	}
+}
+
+/// Records signal events in an [`OutboxStore`].
+///
+/// Use [`Outbox::record`] with a store bound to the current database
+/// transaction, or [`Outbox::bridge`] to record every instance sent on a
+/// signal. Bridged signals sent with
+/// [`Signal::send_on_commit`] inside a
+/// [`TransactionSignals::scope`](reinhardt_core::signals::transaction::TransactionSignals::scope)
+/// are only recorded once the transaction commits.
+#[derive(Clone)]
+pub struct Outbox {
+	store: Arc<dyn OutboxStore>,
+	source: Option<String>,
+}
+
+impl Outbox {
+	/// Create an outbox writing to `store`.
+	pub fn new(store: Arc<dyn OutboxStore>) -> Self {
+		Self {
+			store,
+			source: None,
+		}
+	}
+
+	/// Tag recorded events with the producing service.
+	pub fn with_source(mut self, source: impl Into<String>) -> Self {
+		self.source = Some(source.into());
+		self
+	}
+
+	/// Return the underlying store.
+	pub fn store(&self) -> Arc<dyn OutboxStore> {
+		Arc::clone(&self.store)
+	}
+
+	/// Append an envelope, tagging it with the outbox source.
+	pub async fn stage(&self, mut envelope: EventEnvelope) -> Result<(), StreamingError> {
+		if envelope.source.is_none() {
+			envelope.source = self.source.clone();
+		}
+		self.store.append(envelope).await
+	}
+
+	/// Record `payload` as an event of `signal`.
+	pub async fn record<T: Serialize>(
+		&self,
+		signal: &str,
+		schema: SchemaTag,
+		payload: &T,
+	) -> Result<(), StreamingError> {
+		self.stage(EventEnvelope::new(signal, schema, payload)?)
+			.await
+	}
+
+	/// Record every instance sent on `signal`.
+	///
+	/// Connects a receiver that appends an event tagged with `schema`;
+	/// storage failures are reported as receiver failures.
+	pub fn bridge<T>(&self, signal: &Signal<T>, schema: SchemaTag)
+	where
+		T: Serialize + Send + Sync + 'static,
+	{
+		let name = signal.name().to_owned();
+		let outbox = self.clone();
+		signal.connect(move |instance: Arc<T>| {
+			let outbox = outbox.clone();
+			let name = name.clone();
+			let schema = schema.clone();
+			async move {
+				outbox
+					.record(&name, schema, instance.as_ref())
+					.await
+					.map_err(|e| reinhardt_core::signals::SignalError::new(e.to_string()))
+			}
+		});
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reinhardt_core::signals::SignalName;
+	use reinhardt_core::signals::transaction::TransactionSignals;
+	use rstest::*;
+
+	#[fixture]
+	fn store() -> Arc<InMemoryOutboxStore> {
+		Arc::new(InMemoryOutboxStore::new())
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn bridged_signal_is_recorded(store: Arc<InMemoryOutboxStore>) {
+		// Arrange
+		let outbox = Outbox::new(store.clone()).with_source("shop");
+		let signal = Signal::<u64>::new(SignalName::custom("order_paid"));
+		outbox.bridge(&signal, SchemaTag::new("orders.paid", 1));
+
+		// Act
+		signal.send(42).await.unwrap();
+
+		// Assert
+		let pending = store.pending(10).await.unwrap();
+		assert_eq!(pending.len(), 1);
+		assert_eq!(pending[0].envelope.signal, "order_paid");
+		assert_eq!(pending[0].envelope.source.as_deref(), Some("shop"));
+		assert_eq!(pending[0].envelope.payload, 42);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn send_on_commit_records_after_commit(store: Arc<InMemoryOutboxStore>) {
+		// Arrange
+		let outbox = Outbox::new(store.clone());
+		let signal = Signal::<u64>::new(SignalName::custom("order_shipped"));
+		outbox.bridge(&signal, SchemaTag::new("orders.shipped", 1));
+		let tx = TransactionSignals::new("tx_outbox");
+
+		// Act
+		tx.scope(async {
+			signal.send_on_commit(1).await;
+		})
+		.await;
+		let before_commit = store.pending(10).await.unwrap().len();
+		tx.send_commit().await.unwrap();
+
+		// Assert
+		assert_eq!(before_commit, 0);
+		assert_eq!(store.pending(10).await.unwrap().len(), 1);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn failed_and_dead_entries(store: Arc<InMemoryOutboxStore>) {
+		// Arrange
+		let envelope = EventEnvelope::new("s", SchemaTag::new("s", 1), &()).unwrap();
+		let id = envelope.id.clone();
+		store.append(envelope).await.unwrap();
+
+		// Act
+		store.mark_failed(&id, "timeout").await.unwrap();
+		let pending = store.pending(10).await.unwrap();
+		store.mark_dead(&id, "refused").await.unwrap();
+
+		// Assert
+		assert_eq!(pending[0].attempts, 1);
+		assert!(store.pending(10).await.unwrap().is_empty());
+		let dead = store.dead_letters();
+		assert_eq!(dead[0].attempts, 2);
+		assert_eq!(dead[0].last_error.as_deref(), Some("refused"));
+	}
+}
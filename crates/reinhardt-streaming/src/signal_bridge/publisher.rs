@@ -0,0 +1,51 @@
+use crate::{StreamingBackend, StreamingError};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// A message ready to be sent to a broker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboundMessage {
+	/// Topic, subject or routing key.
+	pub topic: String,
+	/// Partitioning key, if any.
+	pub key: Option<String>,
+	/// Broker headers.
+	pub headers: Vec<(String, String)>,
+	/// Encoded [`EventEnvelope`](super::EventEnvelope).
+	pub payload: Vec<u8>,
+}
+
+/// Publishes outbox events to a message broker.
+///
+/// Implemented for Kafka, NATS and RabbitMQ behind the `kafka`, `nats` and
+/// `rabbitmq` features, and for any [`StreamingBackend`] through
+/// [`BackendPublisher`].
+#[async_trait]
+pub trait EventPublisher: Send + Sync {
+	/// Publish `message`. Errors leave the event in the outbox for a retry.
+	async fn publish(&self, message: &OutboundMessage) -> Result<(), StreamingError>;
+}
+
+/// Publishes through a [`StreamingBackend`].
+///
+/// Backends carry only the payload; the key and headers remain available in
+/// the envelope itself.
+pub struct BackendPublisher {
+	backend: Arc<dyn StreamingBackend>,
+}
+
+impl BackendPublisher {
+	/// Publish through `backend`.
+	pub fn new(backend: Arc<dyn StreamingBackend>) -> Self {
+		Self { backend }
+	}
+}
+
+#[async_trait]
+impl EventPublisher for BackendPublisher {
+	async fn publish(&self, message: &OutboundMessage) -> Result<(), StreamingError> {
+		self.backend
+			.publish(&message.topic, message.payload.clone())
+			.await
+	}
+}
@@ -0,0 +1,130 @@
+//! RabbitMQ publisher and source for the signal bridge.
+//!
+//! Requires feature `rabbitmq`.
+
+use super::{
+	consumer::EventSource,
+	publisher::{EventPublisher, OutboundMessage},
+};
+use crate::StreamingError;
+use async_trait::async_trait;
+use lapin::{
+	BasicProperties, Channel, Connection, ConnectionProperties,
+	options::{BasicAckOptions, BasicGetOptions, BasicPublishOptions},
+	types::{AMQPValue, FieldTable},
+};
+
+/// Header carrying the route key; the route topic is used as the AMQP
+/// routing key.
+pub const KEY_HEADER: &str = "x-reinhardt-key";
+
+async fn open_channel(url: &str) -> Result<(Connection, Channel), StreamingError> {
+	let connection = Connection::connect(url, ConnectionProperties::default())
+		.await
+		.map_err(|e| StreamingError::Connection(e.to_string()))?;
+	let channel = connection
+		.create_channel()
+		.await
+		.map_err(|e| StreamingError::Connection(e.to_string()))?;
+	Ok((connection, channel))
+}
+
+/// Publishes outbox events to a RabbitMQ exchange.
+///
+/// The route topic is used as the routing key; messages are persistent.
+pub struct RabbitMqEventPublisher {
+	// Kept alive for the lifetime of the channel
+	_connection: Connection,
+	channel: Channel,
+	exchange: String,
+}
+
+impl RabbitMqEventPublisher {
+	/// Connect to the broker at `url` and publish to `exchange`.
+	pub async fn connect(url: &str, exchange: impl Into<String>) -> Result<Self, StreamingError> {
+		let (connection, channel) = open_channel(url).await?;
+		Ok(Self {
+			_connection: connection,
+			channel,
+			exchange: exchange.into(),
+		})
+	}
+}
+
+#[async_trait]
+impl EventPublisher for RabbitMqEventPublisher {
+	async fn publish(&self, message: &OutboundMessage) -> Result<(), StreamingError> {
+		let mut headers = FieldTable::default();
+		for (name, value) in &message.headers {
+			headers.insert(
+				name.clone().into(),
+				AMQPValue::LongString(value.clone().into()),
+			);
+		}
+		if let Some(key) = &message.key {
+			headers.insert(
+				KEY_HEADER.to_owned().into(),
+				AMQPValue::LongString(key.clone().into()),
+			);
+		}
+
+		self.channel
+			.basic_publish(
+				&self.exchange,
+				&message.topic,
+				BasicPublishOptions::default(),
+				&message.payload,
+				BasicProperties::default()
+					.with_delivery_mode(2) // Persistent
+					.with_headers(headers),
+			)
+			.await
+			.map_err(|e| StreamingError::Backend(e.to_string()))?
+			.await
+			.map_err(|e| StreamingError::Retryable(e.to_string()))?;
+		Ok(())
+	}
+}
+
+/// Reads events from a RabbitMQ queue.
+///
+/// Messages are acknowledged once read.
+pub struct RabbitMqEventSource {
+	_connection: Connection,
+	channel: Channel,
+	queue: String,
+}
+
+impl RabbitMqEventSource {
+	/// Connect to the broker at `url` and read from `queue`.
+	pub async fn connect(url: &str, queue: impl Into<String>) -> Result<Self, StreamingError> {
+		let (connection, channel) = open_channel(url).await?;
+		Ok(Self {
+			_connection: connection,
+			channel,
+			queue: queue.into(),
+		})
+	}
+}
+
+#[async_trait]
+impl EventSource for RabbitMqEventSource {
+	async fn next(&self) -> Result<Option<Vec<u8>>, StreamingError> {
+		let delivery = self
+			.channel
+			.basic_get(&self.queue, BasicGetOptions { no_ack: false })
+			.await
+			.map_err(|e| StreamingError::Backend(e.to_string()))?;
+
+		match delivery {
+			Some(delivery) => {
+				delivery
+					.ack(BasicAckOptions::default())
+					.await
+					.map_err(|e| StreamingError::Backend(e.to_string()))?;
+				Ok(Some(delivery.data.clone()))
+			}
+			None => Ok(None),
+		}
+	}
+}
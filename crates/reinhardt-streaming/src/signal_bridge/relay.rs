@@ -0,0 +1,226 @@
+use super::{
+	outbox::OutboxStore,
+	publisher::{EventPublisher, OutboundMessage},
+	routing::TopicRouting,
+};
+use crate::StreamingError;
+use std::{future::Future, sync::Arc, time::Duration};
+
+/// Outcome of one relay pass.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RelayReport {
+	/// Events published and removed from the outbox.
+	pub published: usize,
+	/// Events that failed and will be retried.
+	pub failed: usize,
+	/// Events given up on after too many attempts or without a route.
+	pub dead: usize,
+}
+
+/// Publishes pending outbox events to a broker.
+///
+/// Each pass reads a batch of pending events, resolves their topic with the
+/// [`TopicRouting`] and publishes them. Delivery is at-least-once: an event
+/// is removed from the outbox only after the broker accepted it, so
+/// consumers should deduplicate on the envelope ID.
+pub struct OutboxRelay {
+	store: Arc<dyn OutboxStore>,
+	publisher: Arc<dyn EventPublisher>,
+	routing: TopicRouting,
+	batch_size: usize,
+	max_attempts: u32,
+}
+
+impl OutboxRelay {
+	/// Create a relay publishing events of `store` through `publisher`.
+	pub fn new(
+		store: Arc<dyn OutboxStore>,
+		publisher: Arc<dyn EventPublisher>,
+		routing: TopicRouting,
+	) -> Self {
+		Self {
+			store,
+			publisher,
+			routing,
+			batch_size: 100,
+			max_attempts: 5,
+		}
+	}
+
+	/// Set the number of events read per pass (default: 100).
+	pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+		self.batch_size = batch_size;
+		self
+	}
+
+	/// Set the number of attempts before an event is given up on (default: 5).
+	pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+		self.max_attempts = max_attempts;
+		self
+	}
+
+	/// Publish one batch of pending events.
+	pub async fn relay_once(&self) -> Result<RelayReport, StreamingError> {
+		let mut report = RelayReport::default();
+		for entry in self.store.pending(self.batch_size).await? {
+			let envelope = &entry.envelope;
+			let Some(route) = self.routing.resolve(envelope) else {
+				self.store
+					.mark_dead(&envelope.id, "no topic route for signal")
+					.await?;
+				report.dead += 1;
+				continue;
+			};
+
+			let message = OutboundMessage {
+				topic: route.topic,
+				key: route.key,
+				headers: envelope.broker_headers(),
+				payload: envelope.encode()?,
+			};
+			match self.publisher.publish(&message).await {
+				Ok(()) => {
+					self.store.mark_published(&envelope.id).await?;
+					report.published += 1;
+				}
+				Err(error) if entry.attempts + 1 >= self.max_attempts => {
+					tracing::error!(
+						event_id = %envelope.id,
+						signal = %envelope.signal,
+						error = %error,
+						"giving up on outbox event"
+					);
+					self.store
+						.mark_dead(&envelope.id, &error.to_string())
+						.await?;
+					report.dead += 1;
+				}
+				Err(error) => {
+					tracing::warn!(
+						event_id = %envelope.id,
+						signal = %envelope.signal,
+						error = %error,
+						"failed to publish outbox event"
+					);
+					self.store
+						.mark_failed(&envelope.id, &error.to_string())
+						.await?;
+					report.failed += 1;
+				}
+			}
+		}
+		Ok(report)
+	}
+
+	/// Relay events every `interval` until `shutdown` completes.
+	///
+	/// Full batches are followed immediately by the next pass.
+	pub async fn run(&self, interval: Duration, shutdown: impl Future<Output = ()>) {
+		tokio::pin!(shutdown);
+		loop {
+			let wait = match self.relay_once().await {
+				Ok(report) if report.published + report.failed + report.dead >= self.batch_size => {
+					Duration::ZERO
+				}
+				Ok(_) => interval,
+				Err(error) => {
+					tracing::warn!(error = %error, "outbox relay pass failed");
+					interval
+				}
+			};
+			tokio::select! {
+				_ = &mut shutdown => return,
+				_ = tokio::time::sleep(wait) => {}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::signal_bridge::{
+		BackendPublisher, EventEnvelope, InMemoryOutboxStore, SchemaTag, TopicRoute,
+	};
+	use crate::{InMemoryStreamingBackend, StreamingBackend};
+	use async_trait::async_trait;
+	use rstest::*;
+
+	struct FailingPublisher;
+
+	#[async_trait]
+	impl EventPublisher for FailingPublisher {
+		async fn publish(&self, _message: &OutboundMessage) -> Result<(), StreamingError> {
+			Err(StreamingError::Retryable("broker down".to_owned()))
+		}
+	}
+
+	async fn store_with(signal: &str) -> Arc<InMemoryOutboxStore> {
+		let store = Arc::new(InMemoryOutboxStore::new());
+		store
+			.append(EventEnvelope::new(signal, SchemaTag::new(signal, 1), &5).unwrap())
+			.await
+			.unwrap();
+		store
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn relay_publishes_routed_events() {
+		// Arrange
+		let store = store_with("order_created").await;
+		let backend = Arc::new(InMemoryStreamingBackend::new());
+		let relay = OutboxRelay::new(
+			store.clone(),
+			Arc::new(BackendPublisher::new(backend.clone())),
+			TopicRouting::new().route("order_created", TopicRoute::new("orders")),
+		);
+
+		// Act
+		let report = relay.relay_once().await.unwrap();
+
+		// Assert
+		assert_eq!(report.published, 1);
+		assert!(store.pending(10).await.unwrap().is_empty());
+		let bytes = backend.poll("orders").await.unwrap().unwrap();
+		assert_eq!(EventEnvelope::decode(&bytes).unwrap().payload, 5);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn relay_gives_up_after_max_attempts() {
+		// Arrange
+		let store = store_with("order_created").await;
+		let relay = OutboxRelay::new(
+			store.clone(),
+			Arc::new(FailingPublisher),
+			TopicRouting::new().default_topic("{signal}"),
+		)
+		.with_max_attempts(2);
+
+		// Act
+		let first = relay.relay_once().await.unwrap();
+		let second = relay.relay_once().await.unwrap();
+
+		// Assert
+		assert_eq!(first.failed, 1);
+		assert_eq!(second.dead, 1);
+		assert_eq!(store.dead_letters()[0].attempts, 2);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn unrouted_events_are_dead_lettered() {
+		let store = store_with("unknown").await;
+		let relay = OutboxRelay::new(
+			store.clone(),
+			Arc::new(FailingPublisher),
+			TopicRouting::new(),
+		);
+
+		let report = relay.relay_once().await.unwrap();
+
+		assert_eq!(report.dead, 1);
+		assert!(store.pending(10).await.unwrap().is_empty());
+	}
+}
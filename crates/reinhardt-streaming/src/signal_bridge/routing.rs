@@ -0,0 +1,151 @@
+use super::envelope::EventEnvelope;
+use std::collections::HashMap;
+
+/// Where events of one signal are published.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopicRoute {
+	/// Topic, subject or routing key the events are published to.
+	pub topic: String,
+	/// Top-level payload field used as the partitioning key.
+	pub key_field: Option<String>,
+}
+
+impl TopicRoute {
+	/// Route to `topic` without a partitioning key.
+	pub fn new(topic: impl Into<String>) -> Self {
+		Self {
+			topic: topic.into(),
+			key_field: None,
+		}
+	}
+
+	/// Use the payload field `field` as the partitioning key.
+	pub fn with_key_field(mut self, field: impl Into<String>) -> Self {
+		self.key_field = Some(field.into());
+		self
+	}
+}
+
+/// The resolved destination of an event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedRoute {
+	/// Topic to publish to.
+	pub topic: String,
+	/// Partitioning key, if any.
+	pub key: Option<String>,
+}
+
+/// Per-signal topic routing.
+///
+/// Signals without a route use the default topic template, in which
+/// `{signal}` is replaced by the signal name. Events of signals with
+/// neither are not published.
+///
+/// ```
+/// use reinhardt_streaming::signal_bridge::{TopicRoute, TopicRouting};
+///
+/// let routing = TopicRouting::new()
+///     .route("order_created", TopicRoute::new("orders").with_key_field("order_id"))
+///     .default_topic("events.{signal}");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TopicRouting {
+	routes: HashMap<String, TopicRoute>,
+	default_topic: Option<String>,
+}
+
+impl TopicRouting {
+	/// Create a routing table without routes.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Route events of `signal`.
+	pub fn route(mut self, signal: impl Into<String>, route: TopicRoute) -> Self {
+		self.routes.insert(signal.into(), route);
+		self
+	}
+
+	/// Set the topic template for signals without a route.
+	pub fn default_topic(mut self, template: impl Into<String>) -> Self {
+		self.default_topic = Some(template.into());
+		self
+	}
+
+	/// Resolve the destination of `envelope`.
+	///
+	/// The envelope key takes precedence over the key field of the route.
+	pub fn resolve(&self, envelope: &EventEnvelope) -> Option<ResolvedRoute> {
+		let (topic, key_field) = match self.routes.get(&envelope.signal) {
+			Some(route) => (route.topic.clone(), route.key_field.as_deref()),
+			None => (
+				self.default_topic
+					.as_ref()?
+					.replace("{signal}", &envelope.signal),
+				None,
+			),
+		};
+		let key = envelope.key.clone().or_else(|| {
+			let value = envelope.payload.get(key_field?)?;
+			match value {
+				serde_json::Value::String(s) => Some(s.clone()),
+				serde_json::Value::Null => None,
+				other => Some(other.to_string()),
+			}
+		});
+		Some(ResolvedRoute { topic, key })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::signal_bridge::SchemaTag;
+	use rstest::*;
+	use serde_json::json;
+
+	fn envelope(signal: &str) -> EventEnvelope {
+		EventEnvelope::new(signal, SchemaTag::new(signal, 1), &json!({"order_id": 12})).unwrap()
+	}
+
+	#[rstest]
+	fn route_uses_key_field() {
+		let routing = TopicRouting::new().route(
+			"order_created",
+			TopicRoute::new("orders").with_key_field("order_id"),
+		);
+
+		let resolved = routing.resolve(&envelope("order_created")).unwrap();
+
+		assert_eq!(resolved.topic, "orders");
+		assert_eq!(resolved.key.as_deref(), Some("12"));
+	}
+
+	#[rstest]
+	fn envelope_key_overrides_key_field() {
+		let routing = TopicRouting::new().route(
+			"order_created",
+			TopicRoute::new("orders").with_key_field("order_id"),
+		);
+
+		let resolved = routing
+			.resolve(&envelope("order_created").with_key("tenant-1"))
+			.unwrap();
+
+		assert_eq!(resolved.key.as_deref(), Some("tenant-1"));
+	}
+
+	#[rstest]
+	#[case::default_template(Some("events.{signal}"), Some("events.user_joined"))]
+	#[case::unrouted(None, None)]
+	fn default_topic(#[case] template: Option<&str>, #[case] expected: Option<&str>) {
+		let mut routing = TopicRouting::new();
+		if let Some(template) = template {
+			routing = routing.default_topic(template);
+		}
+
+		let resolved = routing.resolve(&envelope("user_joined"));
+
+		assert_eq!(resolved.map(|r| r.topic).as_deref(), expected);
+	}
+}
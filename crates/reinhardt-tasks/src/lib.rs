@@ -80,6 +80,8 @@ pub mod dag;
 pub mod load_balancer;
 /// Distributed task locking to prevent duplicate execution.
 pub mod locking;
+/// Built-in maintenance task pack.
+pub mod maintenance;
 /// Task execution metrics and monitoring.
 pub mod metrics;
 /// Priority-based task queue.
@@ -129,6 +131,10 @@ pub use chain::{ChainStatus, TaskChain, TaskChainBuilder};
 pub use dag::{TaskDAG, TaskNode, TaskNodeStatus};
 pub use load_balancer::{LoadBalancer, LoadBalancingStrategy, WorkerId, WorkerInfo, WorkerMetrics};
 pub use locking::{LockToken, MemoryTaskLock, TaskLock};
+pub use maintenance::{
+	MaintenanceAction, MaintenanceJob, MaintenancePack, MaintenanceTask, POSTGRES_ANALYZE_HINT_SQL,
+	postgres_analyze_statement,
+};
 
 #[cfg(feature = "redis-backend")]
 pub use locking::RedisTaskLock;
@@ -170,8 +176,9 @@ pub use worker::WorkerConfig;
 
 // Settings-first configuration API (preferred over the deprecated `*Config` types).
 pub use settings::{
-	QueueSettings, WebhookRetrySettings, WebhookSettings, WorkerSettings,
-	create_webhook_sender_from_settings, create_worker_from_settings,
+	MaintenanceJobSettings, MaintenanceSettings, QueueSettings, WebhookRetrySettings,
+	WebhookSettings, WorkerSettings, create_webhook_sender_from_settings,
+	create_worker_from_settings,
 };
 #[cfg(feature = "rabbitmq-backend")]
 pub use settings::{RabbitMQSettings, create_rabbitmq_backend_from_settings};
@@ -253,4 +260,17 @@ pub enum TaskError {
 	/// ```
 	#[error("Max retries exceeded")]
 	MaxRetriesExceeded,
+
+	/// Invalid cron schedule expression
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use reinhardt_tasks::TaskError;
+	///
+	/// let error = TaskError::InvalidSchedule("every tuesday".to_string());
+	/// assert_eq!(error.to_string(), "Invalid schedule: every tuesday");
+	/// ```
+	#[error("Invalid schedule: {0}")]
+	InvalidSchedule(String),
 }
@@ -0,0 +1,390 @@
+//! Built-in maintenance task pack
+//!
+//! Periodic housekeeping jobs registered with a [`Scheduler`]:
+//!
+//! | Job | Default schedule |
+//! |-----|------------------|
+//! | [`MaintenanceJob::SessionPurge`] | hourly |
+//! | [`MaintenanceJob::AuditRetention`] | daily at 03:30 UTC |
+//! | [`MaintenanceJob::CacheSweep`] | every ten minutes |
+//! | [`MaintenanceJob::PostgresAnalyze`] | Sundays at 04:00 UTC |
+//! | [`MaintenanceJob::DataRetention`] | daily at 02:30 UTC |
+//! | [`MaintenanceJob::Backup`] | daily at 01:30 UTC |
+//!
+//! Each job is toggled and rescheduled through [`MaintenanceSettings`]
+//! (`[tasks_maintenance]`). The work itself belongs to the crates owning the
+//! data, so the application supplies one [`MaintenanceAction`] per job, e.g.
+//! the session backend's `cleanup_expired`, the audit store's
+//! `apply_retention`, the ORM's `enforce_retention` or the `backup`
+//! command's `BackupRunner::run`. Jobs without an action are not registered.
+//!
+//! # Example
+//!
+//! ```rust
+//! use reinhardt_tasks::{MaintenancePack, MaintenanceSettings, Scheduler, TaskResult};
+//!
+//! let mut scheduler = Scheduler::new();
+//! let registered = MaintenancePack::new(MaintenanceSettings::default())
+//!     .with_session_purge(|| async {
+//!         // e.g. `session_backend.cleanup_expired().await`
+//!         TaskResult::Ok(0)
+//!     })
+//!     .with_cache_sweep(|| async { TaskResult::Ok(0) })
+//!     .register(&mut scheduler)
+//!     .unwrap();
+//! assert_eq!(registered, 2);
+//! ```
+
+use crate::scheduler::{CronSchedule, Scheduler};
+use crate::settings::MaintenanceSettings;
+use crate::{Task, TaskExecutor, TaskId, TaskResult};
+use async_trait::async_trait;
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+
+/// Query listing PostgreSQL tables whose planner statistics are stale
+///
+/// Returns `schemaname` and `relname` of tables modified by more than 10% of
+/// their rows (and at least 50 rows) since their last analyze, most modified
+/// first. Pass each row to [`postgres_analyze_statement`].
+pub const POSTGRES_ANALYZE_HINT_SQL: &str = "SELECT schemaname, relname \
+	FROM pg_stat_user_tables \
+	WHERE n_mod_since_analyze > GREATEST(50, 0.1 * n_live_tup) \
+	ORDER BY n_mod_since_analyze DESC";
+
+/// `ANALYZE` statement for one table, with quoted identifiers
+///
+/// # Example
+///
+/// ```rust
+/// use reinhardt_tasks::postgres_analyze_statement;
+///
+/// assert_eq!(
+///     postgres_analyze_statement("public", "orders"),
+///     r#"ANALYZE "public"."orders""#
+/// );
+/// ```
+pub fn postgres_analyze_statement(schema: &str, table: &str) -> String {
+	fn quote(identifier: &str) -> String {
+		format!("\"{}\"", identifier.replace('"', "\"\""))
+	}
+	format!("ANALYZE {}.{}", quote(schema), quote(table))
+}
+
+/// Built-in maintenance jobs
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MaintenanceJob {
+	/// Delete expired sessions
+	SessionPurge,
+	/// Delete audit log entries older than the retention policy allows
+	AuditRetention,
+	/// Evict expired cache entries
+	CacheSweep,
+	/// `ANALYZE` PostgreSQL tables with stale statistics
+	PostgresAnalyze,
+	/// Delete or anonymize rows past their model's retention period
+	DataRetention,
+	/// Back up the database and media storage
+	Backup,
+}
+
+impl MaintenanceJob {
+	/// Every built-in job
+	pub const ALL: [MaintenanceJob; 6] = [
+		Self::SessionPurge,
+		Self::AuditRetention,
+		Self::CacheSweep,
+		Self::PostgresAnalyze,
+		Self::DataRetention,
+		Self::Backup,
+	];
+
+	/// Task name reported to the scheduler and in logs
+	pub fn name(&self) -> &'static str {
+		match self {
+			Self::SessionPurge => "maintenance.session_purge",
+			Self::AuditRetention => "maintenance.audit_retention",
+			Self::CacheSweep => "maintenance.cache_sweep",
+			Self::PostgresAnalyze => "maintenance.postgres_analyze",
+			Self::DataRetention => "maintenance.data_retention",
+			Self::Backup => "maintenance.backup",
+		}
+	}
+
+	/// Default cron schedule, with a seconds field
+	pub fn default_schedule(&self) -> &'static str {
+		match self {
+			Self::SessionPurge => "0 0 * * * *",
+			Self::AuditRetention => "0 30 3 * * *",
+			Self::CacheSweep => "0 */10 * * * *",
+			Self::PostgresAnalyze => "0 0 4 * * Sun",
+			Self::DataRetention => "0 30 2 * * *",
+			Self::Backup => "0 30 1 * * *",
+		}
+	}
+}
+
+impl fmt::Display for MaintenanceJob {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.name())
+	}
+}
+
+/// Work performed by a maintenance job
+///
+/// Implemented for async closures returning the number of affected items.
+#[async_trait]
+pub trait MaintenanceAction: Send + Sync {
+	/// Run the job once, returning how many items it removed or touched
+	async fn run(&self) -> TaskResult<u64>;
+}
+
+#[async_trait]
+impl<F, Fut> MaintenanceAction for F
+where
+	F: Fn() -> Fut + Send + Sync,
+	Fut: Future<Output = TaskResult<u64>> + Send + 'static,
+{
+	async fn run(&self) -> TaskResult<u64> {
+		self().await
+	}
+}
+
+/// Scheduled task running one maintenance job
+pub struct MaintenanceTask {
+	id: TaskId,
+	job: MaintenanceJob,
+	action: Arc<dyn MaintenanceAction>,
+}
+
+impl MaintenanceTask {
+	/// Create a task running `action` for `job`
+	pub fn new(job: MaintenanceJob, action: Arc<dyn MaintenanceAction>) -> Self {
+		Self {
+			id: TaskId::new(),
+			job,
+			action,
+		}
+	}
+
+	/// The job this task runs
+	pub fn job(&self) -> MaintenanceJob {
+		self.job
+	}
+}
+
+impl fmt::Debug for MaintenanceTask {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("MaintenanceTask")
+			.field("id", &self.id)
+			.field("job", &self.job)
+			.finish()
+	}
+}
+
+impl Task for MaintenanceTask {
+	fn id(&self) -> TaskId {
+		self.id
+	}
+
+	fn name(&self) -> &str {
+		self.job.name()
+	}
+}
+
+#[async_trait]
+impl TaskExecutor for MaintenanceTask {
+	async fn execute(&self) -> TaskResult<()> {
+		let affected = self.action.run().await?;
+		tracing::info!(job = %self.job, affected, "Maintenance job finished");
+		Ok(())
+	}
+}
+
+/// The built-in maintenance jobs, ready to register with a [`Scheduler`]
+pub struct MaintenancePack {
+	settings: MaintenanceSettings,
+	actions: Vec<(MaintenanceJob, Arc<dyn MaintenanceAction>)>,
+}
+
+impl MaintenancePack {
+	/// Create a pack configured by `settings`, with no actions yet
+	pub fn new(settings: MaintenanceSettings) -> Self {
+		Self {
+			settings,
+			actions: Vec::new(),
+		}
+	}
+
+	/// Set the action of a job, replacing any previous one
+	pub fn with_action(
+		mut self,
+		job: MaintenanceJob,
+		action: impl MaintenanceAction + 'static,
+	) -> Self {
+		self.actions.retain(|(existing, _)| *existing != job);
+		self.actions.push((job, Arc::new(action)));
+		self
+	}
+
+	/// Set the expired session purge, e.g. a session backend's `cleanup_expired`
+	pub fn with_session_purge(self, action: impl MaintenanceAction + 'static) -> Self {
+		self.with_action(MaintenanceJob::SessionPurge, action)
+	}
+
+	/// Set the audit log trimming, e.g. an audit store's `apply_retention`
+	pub fn with_audit_retention(self, action: impl MaintenanceAction + 'static) -> Self {
+		self.with_action(MaintenanceJob::AuditRetention, action)
+	}
+
+	/// Set the cache sweep, e.g. a cache's `cleanup_expired`
+	pub fn with_cache_sweep(self, action: impl MaintenanceAction + 'static) -> Self {
+		self.with_action(MaintenanceJob::CacheSweep, action)
+	}
+
+	/// Set the PostgreSQL analyze, typically running
+	/// [`POSTGRES_ANALYZE_HINT_SQL`] and [`postgres_analyze_statement`]
+	pub fn with_postgres_analyze(self, action: impl MaintenanceAction + 'static) -> Self {
+		self.with_action(MaintenanceJob::PostgresAnalyze, action)
+	}
+
+	/// Set the data retention enforcement, e.g. the ORM's `enforce_retention`
+	pub fn with_data_retention(self, action: impl MaintenanceAction + 'static) -> Self {
+		self.with_action(MaintenanceJob::DataRetention, action)
+	}
+
+	/// Set the database and media backup, e.g. the `backup` command's
+	/// `BackupRunner::run`
+	pub fn with_backup(self, action: impl MaintenanceAction + 'static) -> Self {
+		self.with_action(MaintenanceJob::Backup, action)
+	}
+
+	/// Jobs that are enabled in the settings and have an action
+	pub fn enabled_jobs(&self) -> Vec<MaintenanceJob> {
+		MaintenanceJob::ALL
+			.into_iter()
+			.filter(|job| self.settings.job(*job).enabled && self.action(*job).is_some())
+			.collect()
+	}
+
+	/// Register the enabled jobs with `scheduler`, returning how many were added
+	///
+	/// Every schedule is checked first, so nothing is registered when one of
+	/// them is not a valid cron expression.
+	pub fn register(self, scheduler: &mut Scheduler) -> TaskResult<usize> {
+		let mut tasks = Vec::new();
+		for job in self.enabled_jobs() {
+			let schedule = CronSchedule::new(self.settings.schedule_for(job).to_string());
+			if schedule.next_run().is_none() {
+				return Err(crate::TaskError::InvalidSchedule(format!(
+					"{}: {}",
+					job, schedule.expression
+				)));
+			}
+			let action = self.action(job).expect("enabled jobs have an action");
+			tasks.push((MaintenanceTask::new(job, action), schedule));
+		}
+
+		let registered = tasks.len();
+		for (task, schedule) in tasks {
+			scheduler.add_task(Arc::new(task), Box::new(schedule));
+		}
+		Ok(registered)
+	}
+
+	fn action(&self, job: MaintenanceJob) -> Option<Arc<dyn MaintenanceAction>> {
+		self.actions
+			.iter()
+			.find(|(existing, _)| *existing == job)
+			.map(|(_, action)| Arc::clone(action))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::settings::MaintenanceJobSettings;
+	use rstest::rstest;
+	use std::sync::atomic::{AtomicU64, Ordering};
+
+	#[rstest]
+	fn test_default_schedules_are_valid() {
+		for job in MaintenanceJob::ALL {
+			// Act
+			let next = CronSchedule::new(job.default_schedule().to_string()).next_run();
+
+			// Assert
+			assert!(next.is_some(), "{} has an invalid default schedule", job);
+		}
+	}
+
+	#[rstest]
+	fn test_register_skips_disabled_jobs_and_jobs_without_action() {
+		// Arrange
+		let mut settings = MaintenanceSettings::default();
+		settings.cache_sweep.enabled = false;
+		let pack = MaintenancePack::new(settings)
+			.with_session_purge(|| async { Ok(0) })
+			.with_cache_sweep(|| async { Ok(0) });
+		let mut scheduler = Scheduler::new();
+
+		// Act
+		let jobs = pack.enabled_jobs();
+		let registered = pack.register(&mut scheduler).unwrap();
+
+		// Assert
+		assert_eq!(jobs, vec![MaintenanceJob::SessionPurge]);
+		assert_eq!(registered, 1);
+	}
+
+	#[rstest]
+	fn test_register_rejects_invalid_schedule() {
+		// Arrange
+		let mut settings = MaintenanceSettings::default();
+		settings.audit_retention = MaintenanceJobSettings {
+			enabled: true,
+			schedule: "every night".to_string(),
+		};
+		let pack = MaintenancePack::new(settings).with_audit_retention(|| async { Ok(0) });
+
+		// Act
+		let result = pack.register(&mut Scheduler::new());
+
+		// Assert
+		assert!(matches!(
+			result,
+			Err(crate::TaskError::InvalidSchedule(message)) if message.contains("audit_retention")
+		));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_task_runs_action() {
+		// Arrange
+		let runs = Arc::new(AtomicU64::new(0));
+		let counter = Arc::clone(&runs);
+		let action: Arc<dyn MaintenanceAction> = Arc::new(move || {
+			let counter = Arc::clone(&counter);
+			async move { Ok(counter.fetch_add(1, Ordering::SeqCst)) }
+		});
+		let task = MaintenanceTask::new(MaintenanceJob::CacheSweep, action);
+
+		// Act
+		task.execute().await.unwrap();
+
+		// Assert
+		assert_eq!(runs.load(Ordering::SeqCst), 1);
+		assert_eq!(task.name(), "maintenance.cache_sweep");
+	}
+
+	#[rstest]
+	fn test_postgres_analyze_statement_quotes_identifiers() {
+		// Act / Assert
+		assert_eq!(
+			postgres_analyze_statement("public", "odd\"name"),
+			"ANALYZE \"public\".\"odd\"\"name\""
+		);
+	}
+}
@@ -15,6 +15,7 @@ use std::time::Duration;
 use reinhardt_core::macros::settings;
 use serde::{Deserialize, Serialize};
 
+use crate::maintenance::MaintenanceJob;
 use crate::webhook::{HttpWebhookSender, RetryConfig, WebhookConfig};
 use crate::worker::{Worker, WorkerConfig};
 
@@ -53,6 +54,27 @@ fn default_retry_max_backoff_ms() -> u64 {
 fn default_retry_backoff_multiplier() -> f64 {
 	2.0
 }
+fn default_maintenance_enabled() -> bool {
+	true
+}
+fn default_session_purge() -> MaintenanceJobSettings {
+	MaintenanceJobSettings::for_job(MaintenanceJob::SessionPurge)
+}
+fn default_audit_retention() -> MaintenanceJobSettings {
+	MaintenanceJobSettings::for_job(MaintenanceJob::AuditRetention)
+}
+fn default_cache_sweep() -> MaintenanceJobSettings {
+	MaintenanceJobSettings::for_job(MaintenanceJob::CacheSweep)
+}
+fn default_postgres_analyze() -> MaintenanceJobSettings {
+	MaintenanceJobSettings::for_job(MaintenanceJob::PostgresAnalyze)
+}
+fn default_data_retention() -> MaintenanceJobSettings {
+	MaintenanceJobSettings::for_job(MaintenanceJob::DataRetention)
+}
+fn default_backup() -> MaintenanceJobSettings {
+	MaintenanceJobSettings::for_job(MaintenanceJob::Backup)
+}
 
 // --- queue ----------------------------------------------------------------
 
@@ -183,6 +205,113 @@ pub fn create_webhook_sender_from_settings(settings: &WebhookSettings) -> HttpWe
 	HttpWebhookSender::new(WebhookConfig::from(settings))
 }
 
+// --- maintenance ----------------------------------------------------------
+
+/// Toggle and schedule of one built-in maintenance job.
+///
+/// Nested under `[tasks_maintenance.<job>]`.
+#[settings(fragment = true)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MaintenanceJobSettings {
+	/// Whether the job is registered with the scheduler.
+	#[serde(default = "default_maintenance_enabled")]
+	pub enabled: bool,
+	/// Cron expression with a seconds field (e.g. `"0 0 * * * *"` for hourly).
+	#[serde(default)]
+	pub schedule: String,
+}
+
+impl MaintenanceJobSettings {
+	/// Enabled settings with the default schedule of `job`.
+	pub fn for_job(job: MaintenanceJob) -> Self {
+		Self {
+			enabled: true,
+			schedule: job.default_schedule().to_string(),
+		}
+	}
+}
+
+/// Built-in maintenance task pack settings fragment.
+///
+/// Maps to the `[tasks_maintenance]` section. Every job is enabled by
+/// default; a job only runs once the application supplies its action to
+/// [`MaintenancePack`](crate::MaintenancePack).
+///
+/// ```toml
+/// [tasks_maintenance.cache_sweep]
+/// schedule = "0 */5 * * * *"
+///
+/// [tasks_maintenance.postgres_analyze]
+/// enabled = false
+/// ```
+#[settings(fragment = true, section = "tasks_maintenance")]
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MaintenanceSettings {
+	/// Expired session purge. Hourly by default.
+	#[setting(node)]
+	#[serde(default = "default_session_purge")]
+	pub session_purge: MaintenanceJobSettings,
+	/// Audit log retention trimming. Daily at 03:30 UTC by default.
+	#[setting(node)]
+	#[serde(default = "default_audit_retention")]
+	pub audit_retention: MaintenanceJobSettings,
+	/// Expired cache entry sweep. Every ten minutes by default.
+	#[setting(node)]
+	#[serde(default = "default_cache_sweep")]
+	pub cache_sweep: MaintenanceJobSettings,
+	/// PostgreSQL `ANALYZE` of tables with stale statistics. Sundays at
+	/// 04:00 UTC by default.
+	#[setting(node)]
+	#[serde(default = "default_postgres_analyze")]
+	pub postgres_analyze: MaintenanceJobSettings,
+	/// Model data retention enforcement. Daily at 02:30 UTC by default.
+	#[setting(node)]
+	#[serde(default = "default_data_retention")]
+	pub data_retention: MaintenanceJobSettings,
+	/// Database and media backup. Daily at 01:30 UTC by default.
+	#[setting(node)]
+	#[serde(default = "default_backup")]
+	pub backup: MaintenanceJobSettings,
+}
+
+impl Default for MaintenanceSettings {
+	fn default() -> Self {
+		Self {
+			session_purge: default_session_purge(),
+			audit_retention: default_audit_retention(),
+			cache_sweep: default_cache_sweep(),
+			postgres_analyze: default_postgres_analyze(),
+			data_retention: default_data_retention(),
+			backup: default_backup(),
+		}
+	}
+}
+
+impl MaintenanceSettings {
+	/// Settings of one job.
+	pub fn job(&self, job: MaintenanceJob) -> &MaintenanceJobSettings {
+		match job {
+			MaintenanceJob::SessionPurge => &self.session_purge,
+			MaintenanceJob::AuditRetention => &self.audit_retention,
+			MaintenanceJob::CacheSweep => &self.cache_sweep,
+			MaintenanceJob::PostgresAnalyze => &self.postgres_analyze,
+			MaintenanceJob::DataRetention => &self.data_retention,
+			MaintenanceJob::Backup => &self.backup,
+		}
+	}
+
+	/// Schedule of one job, falling back to its default when left empty.
+	pub fn schedule_for(&self, job: MaintenanceJob) -> &str {
+		let schedule = self.job(job).schedule.trim();
+		if schedule.is_empty() {
+			job.default_schedule()
+		} else {
+			schedule
+		}
+	}
+}
+
 // --- worker ---------------------------------------------------------------
 
 /// Task worker settings fragment.
@@ -388,6 +517,7 @@ mod tests {
 		assert_eq!(QueueSettings::section(), "tasks_queue");
 		assert_eq!(WorkerSettings::section(), "tasks_worker");
 		assert_eq!(WebhookSettings::section(), "tasks_webhook");
+		assert_eq!(MaintenanceSettings::section(), "tasks_maintenance");
 	}
 
 	#[rstest::rstest]
@@ -506,4 +636,30 @@ mod tests {
 		assert_eq!(config.queue_name, "reinhardt_tasks");
 		assert_eq!(config.routing_key, "reinhardt_tasks");
 	}
+
+	#[rstest::rstest]
+	fn maintenance_settings_toggle_jobs_and_keep_default_schedules() {
+		// Arrange
+		let settings: MaintenanceSettings = serde_json::from_value(serde_json::json!({
+			"cache_sweep": { "schedule": "0 */5 * * * *" },
+			"postgres_analyze": { "enabled": false },
+		}))
+		.unwrap();
+
+		// Act / Assert
+		assert!(settings.session_purge.enabled);
+		assert_eq!(
+			settings.schedule_for(MaintenanceJob::SessionPurge),
+			"0 0 * * * *"
+		);
+		assert_eq!(
+			settings.schedule_for(MaintenanceJob::CacheSweep),
+			"0 */5 * * * *"
+		);
+		assert!(!settings.postgres_analyze.enabled);
+		assert_eq!(
+			settings.schedule_for(MaintenanceJob::PostgresAnalyze),
+			MaintenanceJob::PostgresAnalyze.default_schedule()
+		);
+	}
 }
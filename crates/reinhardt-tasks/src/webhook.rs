@@ -64,6 +64,8 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use ipnet::IpNet;
 use rand::Rng;
+use reinhardt_utils::circuit_breaker::{CircuitBreaker, CircuitBreakerError};
+use reinhardt_utils::http_client::HttpClient;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::net::IpAddr;
@@ -169,6 +171,18 @@ pub enum WebhookError {
 	/// ```
 	#[error("DNS resolution failed for webhook URL host: {0}")]
 	DnsResolutionFailed(String),
+
+	/// Delivery skipped because the endpoint's circuit breaker is open
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use reinhardt_tasks::webhook::WebhookError;
+	///
+	/// let error = WebhookError::CircuitOpen("hooks.example.com".to_string());
+	/// ```
+	#[error("Circuit breaker open for webhook host: {0}")]
+	CircuitOpen(String),
 }
 
 /// SSRF protection: blocked IP address ranges
@@ -575,13 +589,17 @@ pub trait WebhookSender: Send + Sync {
 /// let sender = HttpWebhookSender::new(config);
 /// ```
 pub struct HttpWebhookSender {
-	client: reqwest::Client,
+	client: HttpClient,
 	config: WebhookConfig,
+	circuit_breaker: Option<CircuitBreaker>,
 }
 
 impl HttpWebhookSender {
 	/// Create a new HTTP webhook sender
 	///
+	/// Requests go through the [global](HttpClient::global) HTTP client, so
+	/// they share its middleware.
+	///
 	/// # Example
 	///
 	/// ```rust
@@ -590,12 +608,50 @@ impl HttpWebhookSender {
 	/// let sender = HttpWebhookSender::new(WebhookConfig::default());
 	/// ```
 	pub fn new(config: WebhookConfig) -> Self {
-		let client = reqwest::Client::builder()
-			.timeout(config.timeout)
-			.build()
-			.unwrap_or_else(|_| reqwest::Client::new());
+		Self {
+			client: HttpClient::global(),
+			config,
+			circuit_breaker: None,
+		}
+	}
 
-		Self { client, config }
+	/// Send requests through `client` instead of the global HTTP client
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use reinhardt_tasks::webhook::{HttpWebhookSender, WebhookConfig};
+	/// use reinhardt_utils::http_client::{HttpClient, TracePropagation};
+	///
+	/// let client = HttpClient::builder()
+	///     .middleware(TracePropagation::default())
+	///     .build()
+	///     .unwrap();
+	/// let sender = HttpWebhookSender::new(WebhookConfig::default()).with_http_client(client);
+	/// ```
+	pub fn with_http_client(mut self, client: HttpClient) -> Self {
+		self.client = client;
+		self
+	}
+
+	/// Guard deliveries with a circuit breaker keyed by the endpoint host
+	///
+	/// A delivery (including its retries) counts as one outcome. While the
+	/// host's circuit is open, [`send`](WebhookSender::send) fails fast with
+	/// [`WebhookError::CircuitOpen`].
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use reinhardt_tasks::webhook::{HttpWebhookSender, WebhookConfig};
+	/// use reinhardt_utils::circuit_breaker::{CircuitBreaker, CircuitBreakerPolicy};
+	///
+	/// let sender = HttpWebhookSender::new(WebhookConfig::default())
+	///     .with_circuit_breaker(CircuitBreaker::in_memory("webhooks", CircuitBreakerPolicy::default()));
+	/// ```
+	pub fn with_circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> Self {
+		self.circuit_breaker = Some(circuit_breaker);
+		self
 	}
 
 	/// Calculate backoff duration with exponential backoff and jitter
@@ -684,10 +740,13 @@ impl HttpWebhookSender {
 		}
 
 		// Send request
-		let response = request
+		let request = request
 			.header("Content-Type", "application/json")
-			.body(json_body)
-			.send()
+			.timeout(self.config.timeout)
+			.body(json_body);
+		let response = self
+			.client
+			.send(request)
 			.await
 			.map_err(|e| WebhookError::RequestFailed(e.to_string()))?;
 
@@ -714,7 +773,17 @@ impl WebhookSender for HttpWebhookSender {
 		let validated_url = validate_webhook_url(&self.config.url)?;
 		validate_resolved_ips(&validated_url).await?;
 
-		self.send_with_retry(event).await
+		let Some(circuit_breaker) = &self.circuit_breaker else {
+			return self.send_with_retry(event).await;
+		};
+		let host = validated_url.host_str().unwrap_or_default();
+		circuit_breaker
+			.call(host, self.send_with_retry(event))
+			.await
+			.map_err(|error| match error {
+				CircuitBreakerError::Open(open) => WebhookError::CircuitOpen(open.target),
+				CircuitBreakerError::Inner(error) => error,
+			})
 	}
 }
 
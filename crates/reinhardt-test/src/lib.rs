@@ -16,6 +16,8 @@
 //! ## Features
 //!
 //! - **[`APIClient`]**: HTTP client for making test API requests
+//! - **[`TestClient`]**: In-process client with cookie persistence, CSRF
+//!   handling, `force_login` and multipart uploads
 //! - **[`APIRequestFactory`]**: Factory for creating mock HTTP requests
 //! - **[`APITestCase`]**: Base test case with common assertions
 //! - **Response Assertions**: Status, header, and body assertions
@@ -34,20 +36,25 @@
 //!   `window.fetch`; native targets start a loopback mock HTTP server for
 //!   explicit endpoint injection.
 //! - **`server-fn-test`**: Enable server function testing utilities
-//! - **`tasks`**: Enable task queue testing utilities
+//! - **`bench`**: Enable the synthetic request generator used by benchmarks
+//! - **`tasks`**: Enable the in-memory task broker and mock webhook sender
+//! - **`mail`**: Enable the captured email backend
 //! - **`admin`**: Enable admin panel testing utilities
 //! - **`e2e`**: Enable E2E browser testing utilities via fantoccini/WebDriver
 
 // Re-export modules from reinhardt-testkit for backward-compatible module paths
 #[cfg(native)]
 pub use reinhardt_testkit::{
-	assertions, client, debug, factory, http, logging, mock, resource, response, server, testcase,
-	views, websocket,
+	assertions, client, debug, factory, http, logging, mock, model_factory, resource, response,
+	server, test_client, testcase, views, websocket,
 };
 
 #[cfg(all(native, feature = "messages"))]
 pub use reinhardt_testkit::messages;
 
+#[cfg(all(native, feature = "bench"))]
+pub use reinhardt_testkit::bench;
+
 #[cfg(all(native, feature = "viewsets"))]
 pub use reinhardt_testkit::viewsets;
 
@@ -80,6 +87,12 @@ pub mod msw;
 #[cfg(feature = "server-fn-test")]
 pub mod server_fn;
 
+#[cfg(all(native, feature = "tasks"))]
+pub mod tasks;
+
+#[cfg(all(native, feature = "mail"))]
+pub mod mail;
+
 // Re-exports for impl_test_model! macro
 #[cfg(native)]
 #[doc(hidden)]
@@ -111,12 +124,12 @@ pub use reinhardt_testkit::{
 	APIClient, APIClientBuilder, APIRequestFactory, APITestCase, AsyncTeardownGuard,
 	AsyncTestResource, BodyEchoHandler, CallRecord, ClientError, DebugEntry, DebugPanel,
 	DebugToolbar, DelayedHandler, EchoPathHandler, ErrorKind, HttpVersion, LargeResponseHandler,
-	MethodEchoHandler, MockFunction, RequestBuilder, ResponseExt, RouterHandler, SimpleHandler,
-	Spy, SqlQuery, StatusCodeHandler, SuiteGuard, SuiteResource, TeardownGuard, TestResource,
-	TestResponse, TimingInfo, WebSocketTestClient, acquire_suite, assert_has_header,
-	assert_header_contains, assert_header_equals, assert_no_header, assert_status,
-	create_api_test_objects, create_insecure_request, create_json_request,
-	create_large_test_objects, create_request, create_request_with_headers,
+	MethodEchoHandler, MockFunction, ModelFactory, MultipartForm, RequestBuilder, ResponseExt,
+	RouterHandler, SimpleHandler, Spy, SqlQuery, StatusCodeHandler, SuiteGuard, SuiteResource,
+	TeardownGuard, TestClient, TestResource, TestResponse, TimingInfo, WebSocketTestClient,
+	acquire_suite, assert_has_header, assert_header_contains, assert_header_equals,
+	assert_no_header, assert_status, create_api_test_objects, create_insecure_request,
+	create_json_request, create_large_test_objects, create_request, create_request_with_headers,
 	create_request_with_path_params, create_response_with_headers, create_response_with_status,
 	create_secure_request, create_test_objects, create_test_request, create_test_response,
 	extract_json, get_header, has_header, header_contains, header_equals, init_test_logging,
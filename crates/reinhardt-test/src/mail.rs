@@ -0,0 +1,230 @@
+//! Captured email backend for tests
+//!
+//! [`CapturedEmailBackend`] implements [`EmailBackend`] by storing every sent
+//! message in an in-memory outbox instead of delivering it. Clones share the
+//! same outbox, so a test can hand one clone to the code under test and keep
+//! another for assertions:
+//!
+//! ```rust,no_run
+//! use reinhardt_test::mail::CapturedEmailBackend;
+//! use reinhardt_mail::{EmailBackend, EmailMessage};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let backend = CapturedEmailBackend::new();
+//!
+//! let message = EmailMessage::builder()
+//!     .subject("Welcome")
+//!     .body("Hello!")
+//!     .from("noreply@example.com")
+//!     .to(vec!["alice@example.com".to_string()])
+//!     .build()?;
+//! backend.clone().send_messages(&[message]).await?;
+//!
+//! backend.assert_sent_count(1);
+//! let welcome = backend.assert_sent_to("alice@example.com");
+//! assert_eq!(welcome.subject(), "Welcome");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use reinhardt_mail::{EmailBackend, EmailMessage, EmailResult};
+
+/// Email backend that records messages instead of sending them
+#[derive(Debug, Clone, Default)]
+pub struct CapturedEmailBackend {
+	outbox: Arc<Mutex<Vec<EmailMessage>>>,
+}
+
+impl CapturedEmailBackend {
+	/// Create a backend with an empty outbox
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	fn lock(&self) -> MutexGuard<'_, Vec<EmailMessage>> {
+		// A panicking assertion in another test thread must not hide the outbox
+		self.outbox.lock().unwrap_or_else(|e| e.into_inner())
+	}
+
+	/// All messages sent so far, in send order
+	pub fn outbox(&self) -> Vec<EmailMessage> {
+		self.lock().clone()
+	}
+
+	/// Number of messages sent so far
+	pub fn len(&self) -> usize {
+		self.lock().len()
+	}
+
+	/// Whether no message has been sent
+	pub fn is_empty(&self) -> bool {
+		self.lock().is_empty()
+	}
+
+	/// Remove all captured messages
+	pub fn clear(&self) {
+		self.lock().clear();
+	}
+
+	/// First captured message matching `predicate`
+	pub fn find(&self, predicate: impl Fn(&EmailMessage) -> bool) -> Option<EmailMessage> {
+		self.lock().iter().find(|m| predicate(m)).cloned()
+	}
+
+	/// Assert that exactly `expected` messages were sent
+	///
+	/// # Panics
+	///
+	/// Panics with the captured subjects when the count differs.
+	pub fn assert_sent_count(&self, expected: usize) {
+		let outbox = self.lock();
+		assert_eq!(
+			outbox.len(),
+			expected,
+			"expected {} email(s) to be sent, got {}: {:?}",
+			expected,
+			outbox.len(),
+			outbox.iter().map(|m| m.subject()).collect::<Vec<_>>()
+		);
+	}
+
+	/// Assert that no message was sent
+	///
+	/// # Panics
+	///
+	/// Panics when the outbox is not empty.
+	pub fn assert_none_sent(&self) {
+		self.assert_sent_count(0);
+	}
+
+	/// Assert that a message was sent to `address` and return it
+	///
+	/// `to`, `cc` and `bcc` recipients are all considered.
+	///
+	/// # Panics
+	///
+	/// Panics with the captured recipients when no message matches.
+	pub fn assert_sent_to(&self, address: &str) -> EmailMessage {
+		self.find(|m| {
+			m.to()
+				.iter()
+				.chain(m.cc())
+				.chain(m.bcc())
+				.any(|recipient| recipient == address)
+		})
+		.unwrap_or_else(|| {
+			panic!(
+				"expected an email to be sent to {:?}, recipients were: {:?}",
+				address,
+				self.lock()
+					.iter()
+					.map(|m| m.to().to_vec())
+					.collect::<Vec<_>>()
+			)
+		})
+	}
+
+	/// Assert that a message whose subject contains `fragment` was sent and
+	/// return it
+	///
+	/// # Panics
+	///
+	/// Panics with the captured subjects when no message matches.
+	pub fn assert_subject_contains(&self, fragment: &str) -> EmailMessage {
+		self.find(|m| m.subject().contains(fragment))
+			.unwrap_or_else(|| {
+				panic!(
+					"expected an email with subject containing {:?}, subjects were: {:?}",
+					fragment,
+					self.lock()
+						.iter()
+						.map(|m| m.subject().to_string())
+						.collect::<Vec<_>>()
+				)
+			})
+	}
+}
+
+#[async_trait]
+impl EmailBackend for CapturedEmailBackend {
+	async fn send_messages(&self, messages: &[EmailMessage]) -> EmailResult<usize> {
+		self.lock().extend_from_slice(messages);
+		Ok(messages.len())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn message(subject: &str, to: &str) -> EmailMessage {
+		EmailMessage::builder()
+			.subject(subject)
+			.body("body")
+			.from("noreply@example.com")
+			.to(vec![to.to_string()])
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_clones_share_outbox() {
+		// Arrange
+		let backend = CapturedEmailBackend::new();
+		let sender = backend.clone();
+
+		// Act
+		let sent = sender
+			.send_messages(&[
+				message("Welcome", "alice@example.com"),
+				message("Reset password", "bob@example.com"),
+			])
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(sent, 2);
+		backend.assert_sent_count(2);
+		assert_eq!(
+			backend.assert_sent_to("bob@example.com").subject(),
+			"Reset password"
+		);
+		assert_eq!(
+			backend.assert_subject_contains("Welc").to(),
+			["alice@example.com".to_string()]
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_clear_empties_outbox() {
+		// Arrange
+		let backend = CapturedEmailBackend::new();
+		backend
+			.send_messages(&[message("Hi", "alice@example.com")])
+			.await
+			.unwrap();
+
+		// Act
+		backend.clear();
+
+		// Assert
+		backend.assert_none_sent();
+		assert!(backend.is_empty());
+	}
+
+	#[rstest]
+	#[should_panic(expected = "expected an email to be sent to")]
+	fn test_assert_sent_to_panics_without_match() {
+		// Arrange
+		let backend = CapturedEmailBackend::new();
+
+		// Act / Assert
+		backend.assert_sent_to("nobody@example.com");
+	}
+}
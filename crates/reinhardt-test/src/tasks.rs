@@ -0,0 +1,436 @@
+//! In-memory task broker and webhook transport for tests
+//!
+//! [`InMemoryTaskBroker`] implements [`TaskBackend`] without any external
+//! service. It records every enqueued job and can either execute jobs eagerly
+//! at enqueue time or leave them queued until the test calls
+//! [`run_pending`](InMemoryTaskBroker::run_pending):
+//!
+//! ```rust,no_run
+//! use std::sync::Arc;
+//! use reinhardt_tasks::{TaskBackend, TaskRegistry};
+//! use reinhardt_test::tasks::InMemoryTaskBroker;
+//!
+//! # async fn example(task: Box<dyn reinhardt_tasks::Task>) {
+//! let registry = Arc::new(TaskRegistry::new());
+//! // registry.register("send_welcome_email".into(), Arc::new(WelcomeFactory)).await;
+//!
+//! let broker = InMemoryTaskBroker::eager(registry);
+//! broker.enqueue(task).await.unwrap();
+//!
+//! broker.assert_enqueued("send_welcome_email");
+//! broker.assert_executed("send_welcome_email");
+//! # }
+//! ```
+//!
+//! [`MockWebhookSender`] replaces the HTTP webhook transport with one that
+//! records events and answers with canned responses. Outbound `server_fn`
+//! calls are mocked by `MockServiceWorker` from the `msw` feature.
+
+mod webhook;
+
+pub use webhook::MockWebhookSender;
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use reinhardt_tasks::{
+	SerializedTask, Task, TaskBackend, TaskExecutionError, TaskId, TaskRegistry, TaskStatus,
+};
+
+/// A job accepted by [`InMemoryTaskBroker::enqueue`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnqueuedJob {
+	/// Task identifier
+	pub id: TaskId,
+	/// Registered task name
+	pub name: String,
+}
+
+/// A job run by the broker, eagerly or through
+/// [`run_pending`](InMemoryTaskBroker::run_pending)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutedJob {
+	/// Task identifier
+	pub id: TaskId,
+	/// Registered task name
+	pub name: String,
+	/// Final status, either [`TaskStatus::Success`] or [`TaskStatus::Failure`]
+	pub status: TaskStatus,
+	/// Error message when the job failed
+	pub error: Option<String>,
+}
+
+#[derive(Default)]
+struct BrokerState {
+	queue: VecDeque<TaskId>,
+	names: HashMap<TaskId, String>,
+	statuses: HashMap<TaskId, TaskStatus>,
+	enqueued: Vec<EnqueuedJob>,
+	executed: Vec<ExecutedJob>,
+}
+
+/// Task backend that keeps jobs in memory and exposes them to assertions
+///
+/// Clones share the same state.
+#[derive(Clone, Default)]
+pub struct InMemoryTaskBroker {
+	state: Arc<Mutex<BrokerState>>,
+	eager: Option<Arc<TaskRegistry>>,
+}
+
+impl InMemoryTaskBroker {
+	/// Create a broker that queues jobs without running them
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Create a broker that runs every job through `registry` as soon as it is
+	/// enqueued
+	pub fn eager(registry: Arc<TaskRegistry>) -> Self {
+		Self {
+			state: Arc::default(),
+			eager: Some(registry),
+		}
+	}
+
+	fn lock(&self) -> MutexGuard<'_, BrokerState> {
+		// A panicking assertion in another test thread must not hide the jobs
+		self.state.lock().unwrap_or_else(|e| e.into_inner())
+	}
+
+	/// All jobs enqueued so far, in enqueue order
+	pub fn enqueued(&self) -> Vec<EnqueuedJob> {
+		self.lock().enqueued.clone()
+	}
+
+	/// All jobs executed so far, in execution order
+	pub fn executed(&self) -> Vec<ExecutedJob> {
+		self.lock().executed.clone()
+	}
+
+	/// Number of jobs still waiting in the queue
+	pub fn pending_count(&self) -> usize {
+		self.lock().queue.len()
+	}
+
+	/// Forget all queued, enqueued and executed jobs
+	pub fn clear(&self) {
+		*self.lock() = BrokerState::default();
+	}
+
+	/// Run every queued job through `registry` in FIFO order
+	///
+	/// Returns the executed jobs. Jobs enqueued while running are executed in
+	/// the same call.
+	pub async fn run_pending(&self, registry: &TaskRegistry) -> Vec<ExecutedJob> {
+		let mut executed = Vec::new();
+		loop {
+			let next = {
+				let mut state = self.lock();
+				state.queue.pop_front().map(|id| {
+					let name = state.names.get(&id).cloned().unwrap_or_default();
+					(id, name)
+				})
+			};
+			let Some((id, name)) = next else {
+				break;
+			};
+			executed.push(self.execute(registry, id, name).await);
+		}
+		executed
+	}
+
+	async fn execute(&self, registry: &TaskRegistry, id: TaskId, name: String) -> ExecutedJob {
+		self.lock().statuses.insert(id, TaskStatus::Running);
+
+		let outcome = match registry.create(&name, "{}").await {
+			Ok(executor) => executor.execute().await.map_err(|e| e.to_string()),
+			Err(e) => Err(e.to_string()),
+		};
+		let job = ExecutedJob {
+			id,
+			name,
+			status: if outcome.is_ok() {
+				TaskStatus::Success
+			} else {
+				TaskStatus::Failure
+			},
+			error: outcome.err(),
+		};
+
+		let mut state = self.lock();
+		state.statuses.insert(id, job.status);
+		state.executed.push(job.clone());
+		job
+	}
+
+	/// Assert that a job named `name` was enqueued and return the first match
+	///
+	/// # Panics
+	///
+	/// Panics with the enqueued job names when no job matches.
+	pub fn assert_enqueued(&self, name: &str) -> EnqueuedJob {
+		let state = self.lock();
+		state
+			.enqueued
+			.iter()
+			.find(|job| job.name == name)
+			.cloned()
+			.unwrap_or_else(|| {
+				panic!(
+					"expected task {:?} to be enqueued, enqueued tasks were: {:?}",
+					name,
+					state.enqueued.iter().map(|j| &j.name).collect::<Vec<_>>()
+				)
+			})
+	}
+
+	/// Assert that no job named `name` was enqueued
+	///
+	/// # Panics
+	///
+	/// Panics when a matching job exists.
+	pub fn assert_not_enqueued(&self, name: &str) {
+		let state = self.lock();
+		assert!(
+			state.enqueued.iter().all(|job| job.name != name),
+			"expected task {:?} not to be enqueued",
+			name
+		);
+	}
+
+	/// Assert that a job named `name` executed successfully and return the
+	/// first match
+	///
+	/// # Panics
+	///
+	/// Panics with the executed jobs when no successful job matches.
+	pub fn assert_executed(&self, name: &str) -> ExecutedJob {
+		let state = self.lock();
+		state
+			.executed
+			.iter()
+			.find(|job| job.name == name && job.status == TaskStatus::Success)
+			.cloned()
+			.unwrap_or_else(|| {
+				panic!(
+					"expected task {:?} to execute successfully, executed tasks were: {:?}",
+					name, state.executed
+				)
+			})
+	}
+}
+
+#[async_trait]
+impl TaskBackend for InMemoryTaskBroker {
+	async fn enqueue(&self, task: Box<dyn Task>) -> Result<TaskId, TaskExecutionError> {
+		let id = task.id();
+		let name = task.name().to_string();
+		{
+			let mut state = self.lock();
+			state.names.insert(id, name.clone());
+			state.statuses.insert(id, TaskStatus::Pending);
+			state.enqueued.push(EnqueuedJob {
+				id,
+				name: name.clone(),
+			});
+			if self.eager.is_none() {
+				state.queue.push_back(id);
+			}
+		}
+
+		if let Some(registry) = &self.eager {
+			self.execute(registry, id, name).await;
+		}
+		Ok(id)
+	}
+
+	async fn dequeue(&self) -> Result<Option<TaskId>, TaskExecutionError> {
+		Ok(self.lock().queue.pop_front())
+	}
+
+	async fn get_status(&self, task_id: TaskId) -> Result<TaskStatus, TaskExecutionError> {
+		self.lock()
+			.statuses
+			.get(&task_id)
+			.copied()
+			.ok_or(TaskExecutionError::NotFound(task_id))
+	}
+
+	async fn update_status(
+		&self,
+		task_id: TaskId,
+		status: TaskStatus,
+	) -> Result<(), TaskExecutionError> {
+		let mut state = self.lock();
+		match state.statuses.get_mut(&task_id) {
+			Some(current) => {
+				*current = status;
+				Ok(())
+			}
+			None => Err(TaskExecutionError::NotFound(task_id)),
+		}
+	}
+
+	async fn get_task_data(
+		&self,
+		task_id: TaskId,
+	) -> Result<Option<SerializedTask>, TaskExecutionError> {
+		Ok(self
+			.lock()
+			.names
+			.get(&task_id)
+			.map(|name| SerializedTask::new(name.clone(), "{}".to_string())))
+	}
+
+	fn backend_name(&self) -> &str {
+		"in-memory-test"
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reinhardt_tasks::{TaskError, TaskExecutor, TaskFactory, TaskResult};
+	use rstest::rstest;
+	use std::sync::atomic::{AtomicUsize, Ordering};
+
+	struct CountingTask {
+		id: TaskId,
+		name: &'static str,
+		runs: Arc<AtomicUsize>,
+	}
+
+	impl Task for CountingTask {
+		fn id(&self) -> TaskId {
+			self.id
+		}
+
+		fn name(&self) -> &str {
+			self.name
+		}
+	}
+
+	#[async_trait]
+	impl TaskExecutor for CountingTask {
+		async fn execute(&self) -> TaskResult<()> {
+			self.runs.fetch_add(1, Ordering::SeqCst);
+			if self.name == "failing" {
+				return Err(TaskError::ExecutionFailed("boom".to_string()));
+			}
+			Ok(())
+		}
+	}
+
+	struct CountingFactory {
+		name: &'static str,
+		runs: Arc<AtomicUsize>,
+	}
+
+	#[async_trait]
+	impl TaskFactory for CountingFactory {
+		async fn create(&self, _data: &str) -> TaskResult<Box<dyn TaskExecutor>> {
+			Ok(Box::new(CountingTask {
+				id: TaskId::new(),
+				name: self.name,
+				runs: self.runs.clone(),
+			}))
+		}
+	}
+
+	fn job(name: &'static str) -> Box<dyn Task> {
+		Box::new(CountingTask {
+			id: TaskId::new(),
+			name,
+			runs: Arc::default(),
+		})
+	}
+
+	async fn registry(names: &[&'static str]) -> (Arc<TaskRegistry>, Arc<AtomicUsize>) {
+		let registry = Arc::new(TaskRegistry::new());
+		let runs = Arc::new(AtomicUsize::new(0));
+		for &name in names {
+			registry
+				.register(
+					name.to_string(),
+					Arc::new(CountingFactory {
+						name,
+						runs: runs.clone(),
+					}),
+				)
+				.await;
+		}
+		(registry, runs)
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_eager_broker_executes_on_enqueue() {
+		// Arrange
+		let (registry, runs) = registry(&["send_email"]).await;
+		let broker = InMemoryTaskBroker::eager(registry);
+
+		// Act
+		let id = broker.enqueue(job("send_email")).await.unwrap();
+
+		// Assert
+		assert_eq!(runs.load(Ordering::SeqCst), 1);
+		assert_eq!(broker.assert_executed("send_email").id, id);
+		assert_eq!(broker.get_status(id).await.unwrap(), TaskStatus::Success);
+		assert_eq!(broker.pending_count(), 0);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_lazy_broker_runs_pending_in_order() {
+		// Arrange
+		let (registry, runs) = registry(&["first", "second"]).await;
+		let broker = InMemoryTaskBroker::new();
+		broker.enqueue(job("first")).await.unwrap();
+		broker.enqueue(job("second")).await.unwrap();
+		assert!(broker.executed().is_empty());
+
+		// Act
+		let executed = broker.run_pending(&registry).await;
+
+		// Assert
+		assert_eq!(runs.load(Ordering::SeqCst), 2);
+		assert_eq!(
+			executed.iter().map(|j| j.name.as_str()).collect::<Vec<_>>(),
+			vec!["first", "second"]
+		);
+		broker.assert_not_enqueued("third");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_failures_are_recorded() {
+		// Arrange
+		let (registry, _) = registry(&["failing"]).await;
+		let broker = InMemoryTaskBroker::eager(registry);
+
+		// Act
+		let id = broker.enqueue(job("failing")).await.unwrap();
+
+		// Assert
+		let executed = broker.executed();
+		assert_eq!(executed[0].status, TaskStatus::Failure);
+		assert!(executed[0].error.as_deref().unwrap().contains("boom"));
+		assert_eq!(broker.get_status(id).await.unwrap(), TaskStatus::Failure);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_unregistered_task_fails() {
+		// Arrange
+		let broker = InMemoryTaskBroker::new();
+		broker.enqueue(job("unknown")).await.unwrap();
+
+		// Act
+		let executed = broker.run_pending(&TaskRegistry::new()).await;
+
+		// Assert
+		assert_eq!(executed[0].status, TaskStatus::Failure);
+	}
+}
@@ -0,0 +1,187 @@
+//! Recording webhook transport.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use reinhardt_tasks::{WebhookError, WebhookEvent, WebhookSender};
+
+/// Canned response: `Ok(())` or the error message of a failed request
+type CannedResponse = Result<(), String>;
+
+#[derive(Default)]
+struct SenderState {
+	sent: Vec<WebhookEvent>,
+	queued: VecDeque<CannedResponse>,
+	by_task: HashMap<String, CannedResponse>,
+}
+
+/// Webhook transport that records events instead of sending HTTP requests
+///
+/// Every call to [`send`](WebhookSender::send) is recorded. The response is
+/// taken, in order of precedence, from the queue filled by
+/// [`respond_with`](Self::respond_with), then from a per-task response set by
+/// [`respond_for_task`](Self::respond_for_task), and otherwise succeeds.
+/// Clones share the same state.
+///
+/// ```rust,no_run
+/// use reinhardt_tasks::WebhookSender;
+/// use reinhardt_test::tasks::MockWebhookSender;
+///
+/// # async fn example(event: reinhardt_tasks::WebhookEvent) {
+/// let sender = MockWebhookSender::new();
+/// sender.respond_with(Err("503 Service Unavailable".to_string()));
+///
+/// assert!(sender.send(&event).await.is_err());
+/// assert!(sender.send(&event).await.is_ok());
+/// sender.assert_sent_count(2);
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct MockWebhookSender {
+	state: Arc<Mutex<SenderState>>,
+}
+
+impl MockWebhookSender {
+	/// Create a sender that accepts every event
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	fn lock(&self) -> MutexGuard<'_, SenderState> {
+		// A panicking assertion in another test thread must not hide the events
+		self.state.lock().unwrap_or_else(|e| e.into_inner())
+	}
+
+	/// Queue a response for the next call
+	///
+	/// `Err(message)` makes the call fail with
+	/// [`WebhookError::RequestFailed`].
+	pub fn respond_with(&self, response: Result<(), String>) -> &Self {
+		self.lock().queued.push_back(response);
+		self
+	}
+
+	/// Respond to every event for `task_name` with `response` once the queue
+	/// is exhausted
+	pub fn respond_for_task(
+		&self,
+		task_name: impl Into<String>,
+		response: Result<(), String>,
+	) -> &Self {
+		self.lock().by_task.insert(task_name.into(), response);
+		self
+	}
+
+	/// All events sent so far, in send order
+	pub fn sent(&self) -> Vec<WebhookEvent> {
+		self.lock().sent.clone()
+	}
+
+	/// Events sent for `task_name`
+	pub fn sent_for_task(&self, task_name: &str) -> Vec<WebhookEvent> {
+		self.lock()
+			.sent
+			.iter()
+			.filter(|event| event.task_name == task_name)
+			.cloned()
+			.collect()
+	}
+
+	/// Forget recorded events and canned responses
+	pub fn clear(&self) {
+		*self.lock() = SenderState::default();
+	}
+
+	/// Assert that exactly `expected` events were sent
+	///
+	/// # Panics
+	///
+	/// Panics with the sent task names when the count differs.
+	pub fn assert_sent_count(&self, expected: usize) {
+		let state = self.lock();
+		assert_eq!(
+			state.sent.len(),
+			expected,
+			"expected {} webhook(s) to be sent, got {}: {:?}",
+			expected,
+			state.sent.len(),
+			state.sent.iter().map(|e| &e.task_name).collect::<Vec<_>>()
+		);
+	}
+}
+
+#[async_trait]
+impl WebhookSender for MockWebhookSender {
+	async fn send(&self, event: &WebhookEvent) -> Result<(), WebhookError> {
+		let mut state = self.lock();
+		state.sent.push(event.clone());
+		let response = match state.queued.pop_front() {
+			Some(response) => response,
+			None => state
+				.by_task
+				.get(&event.task_name)
+				.cloned()
+				.unwrap_or(Ok(())),
+		};
+		response.map_err(WebhookError::RequestFailed)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chrono::Utc;
+	use reinhardt_tasks::{TaskId, WebhookTaskStatus};
+	use rstest::rstest;
+
+	fn event(task_name: &str) -> WebhookEvent {
+		let now = Utc::now();
+		WebhookEvent {
+			task_id: TaskId::new(),
+			task_name: task_name.to_string(),
+			status: WebhookTaskStatus::Success,
+			result: None,
+			error: None,
+			started_at: now,
+			completed_at: now,
+			duration_ms: 0,
+		}
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_records_events_and_succeeds_by_default() {
+		// Arrange
+		let sender = MockWebhookSender::new();
+
+		// Act
+		let result = sender.send(&event("report")).await;
+
+		// Assert
+		assert!(result.is_ok());
+		sender.assert_sent_count(1);
+		assert_eq!(sender.sent_for_task("report").len(), 1);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_queued_responses_take_precedence() {
+		// Arrange
+		let sender = MockWebhookSender::new();
+		sender
+			.respond_for_task("report", Err("410 Gone".to_string()))
+			.respond_with(Ok(()));
+
+		// Act
+		let first = sender.send(&event("report")).await;
+		let second = sender.send(&event("report")).await;
+
+		// Assert
+		assert!(first.is_ok());
+		assert!(matches!(
+			second,
+			Err(WebhookError::RequestFailed(message)) if message == "410 Gone"
+		));
+	}
+}
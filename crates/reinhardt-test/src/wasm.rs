@@ -10,6 +10,8 @@
 //! - **Async Utilities**: Wait helpers (`wait_for`, `sleep`, `flush_effects`)
 //! - **Assertions**: DOM state assertions (`should_be_visible`, `should_have_text`)
 //! - **Mock Infrastructure**: Server function and browser API mocking
+//! - **Component Harness**: [`render`] mounts a view into an isolated container;
+//!   its interaction helpers flush the reactive scheduler synchronously
 //!
 //! # Example
 //!
@@ -43,6 +45,7 @@ mod assertions;
 mod events;
 mod mock;
 mod query;
+mod render;
 mod wait;
 
 // Re-export all public items
@@ -50,6 +53,7 @@ pub use assertions::*;
 pub use events::*;
 pub use mock::*;
 pub use query::*;
+pub use render::*;
 pub use wait::*;
 
 // Re-export wasm-bindgen-test for convenience
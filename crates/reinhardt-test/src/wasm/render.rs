@@ -0,0 +1,201 @@
+#![cfg(wasm)]
+
+//! Component rendering harness for WASM tests.
+//!
+//! [`render`] mounts a reinhardt-pages view into a fresh container attached to
+//! the test document and returns a [`RenderResult`] scoped to it. Interaction
+//! helpers on the result dispatch synthetic events and then flush the reactive
+//! scheduler synchronously, so assertions can run immediately without
+//! timers or `wait_for` polling.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use reinhardt_test::wasm::render;
+//! use wasm_bindgen_test::*;
+//!
+//! wasm_bindgen_test_configure!(run_in_browser);
+//!
+//! #[wasm_bindgen_test]
+//! fn test_counter_increments() {
+//!     let rendered = render(counter());
+//!
+//!     rendered.click(&rendered.get_by_role_with_name("button", "Increment").get());
+//!
+//!     assert_eq!(rendered.get_by_role("status").get().text_content().unwrap(), "1");
+//! }
+//! ```
+
+use reinhardt_pages::component::{IntoPage, PageExt, ReactiveNodeScope};
+use reinhardt_pages::reactive::with_runtime;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlInputElement, HtmlTextAreaElement};
+
+use super::events::{UserEvent, fire_event};
+use super::query::{QueryResult, Screen};
+
+/// Attribute marking containers created by [`render`]
+pub const TEST_ROOT_ATTRIBUTE: &str = "data-reinhardt-test-root";
+
+/// Maximum number of flush rounds before [`flush_sync`] assumes an update
+/// cycle
+const MAX_FLUSH_ROUNDS: usize = 100;
+
+/// Run every scheduled effect until the reactive runtime is idle.
+///
+/// Effects that write to signals schedule further updates; those are run in
+/// the same call.
+///
+/// # Panics
+///
+/// Panics when updates are still pending after 100 rounds, which indicates
+/// effects that keep re-triggering each other.
+pub fn flush_sync() {
+	for _ in 0..MAX_FLUSH_ROUNDS {
+		if !with_runtime(|rt| rt.has_pending_updates()) {
+			return;
+		}
+		with_runtime(|rt| rt.flush_updates());
+	}
+	panic!(
+		"reactive updates did not settle after {} flush rounds; check for effects that write to signals they read",
+		MAX_FLUSH_ROUNDS
+	);
+}
+
+/// Run `f`, then flush the reactive scheduler.
+///
+/// Use this around signal writes made directly by a test.
+pub fn act<R>(f: impl FnOnce() -> R) -> R {
+	let result = f();
+	flush_sync();
+	result
+}
+
+/// Mount `view` into a new container appended to `document.body`.
+///
+/// The container is removed, and the reactive nodes created by mounting this
+/// view are released, when the returned [`RenderResult`] is dropped. Other
+/// live renders keep their nodes, so several results may coexist.
+///
+/// # Panics
+///
+/// Panics when no document is available or mounting fails.
+pub fn render(view: impl IntoPage) -> RenderResult {
+	let document = web_sys::window()
+		.and_then(|w| w.document())
+		.expect("render requires a browser document");
+	let container = document
+		.create_element("div")
+		.expect("failed to create test container");
+	let _ = container.set_attribute(TEST_ROOT_ATTRIBUTE, "");
+	document
+		.body()
+		.expect("render requires document.body")
+		.append_child(&container)
+		.expect("failed to attach test container");
+
+	let reactive_nodes = ReactiveNodeScope::new();
+	reactive_nodes
+		.enter(|| {
+			view.into_page()
+				.mount(&reinhardt_pages::dom::Element::new(container.clone()))
+		})
+		.unwrap_or_else(|e| panic!("failed to mount view: {:?}", e));
+	flush_sync();
+
+	RenderResult {
+		screen: Screen::within(&container),
+		container,
+		reactive_nodes,
+	}
+}
+
+/// A mounted view returned by [`render`]
+///
+/// Dereferences to a [`Screen`] scoped to the container, so every query
+/// (`get_by_role`, `get_by_text`, `query_selector`, ...) only sees the
+/// rendered view.
+pub struct RenderResult {
+	container: Element,
+	screen: Screen,
+	reactive_nodes: ReactiveNodeScope,
+}
+
+impl RenderResult {
+	/// The container element the view was mounted into
+	pub fn container(&self) -> &Element {
+		&self.container
+	}
+
+	/// Reactive nodes kept alive by the mounted view
+	pub fn reactive_nodes(&self) -> &ReactiveNodeScope {
+		&self.reactive_nodes
+	}
+
+	/// Inner HTML of the container, useful for snapshot-style assertions
+	pub fn html(&self) -> String {
+		self.container.inner_html()
+	}
+
+	/// A query scoped to the container matching `selector`
+	pub fn select(&self, selector: &str) -> QueryResult {
+		self.screen.query_selector(selector)
+	}
+
+	/// Click `element`, then flush reactive updates
+	pub fn click(&self, element: &Element) {
+		act(|| UserEvent::click(element));
+	}
+
+	/// Replace the value of an `<input>` or `<textarea>`, firing `input` and
+	/// `change` events, then flush reactive updates
+	///
+	/// # Panics
+	///
+	/// Panics when `element` is neither an input nor a textarea.
+	pub fn input(&self, element: &Element, value: &str) {
+		act(|| {
+			if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+				UserEvent::type_text(input, value);
+			} else if let Some(textarea) = element.dyn_ref::<HtmlTextAreaElement>() {
+				UserEvent::type_textarea(textarea, value);
+			} else {
+				panic!(
+					"input() requires an <input> or <textarea>, got <{}>",
+					element.tag_name()
+				);
+			}
+		});
+	}
+
+	/// Press `key` on `element`, then flush reactive updates
+	pub fn key(&self, element: &Element, key: &str) {
+		act(|| UserEvent::keyboard_press(element, key));
+	}
+
+	/// Dispatch an arbitrary event to `element`, then flush reactive updates
+	pub fn dispatch(&self, element: &Element, event: &web_sys::Event) {
+		act(|| fire_event::dispatch(element, event));
+	}
+
+	/// Remove the container from the document
+	///
+	/// Equivalent to dropping the result.
+	pub fn unmount(self) {}
+}
+
+impl std::ops::Deref for RenderResult {
+	type Target = Screen;
+
+	fn deref(&self) -> &Screen {
+		&self.screen
+	}
+}
+
+impl Drop for RenderResult {
+	// `reactive_nodes` is dropped right after, releasing this view's nodes
+	fn drop(&mut self) {
+		self.container.remove();
+	}
+}
@@ -0,0 +1,146 @@
+//! Integration tests for the WASM component render harness.
+
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use reinhardt_pages::Signal;
+use reinhardt_pages::component::{IntoPage, Page, PageElement};
+use reinhardt_test::wasm::{TEST_ROOT_ATTRIBUTE, act, flush_sync, render};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlInputElement;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Number of render containers attached to the document
+fn mounted_roots() -> u32 {
+	web_sys::window()
+		.and_then(|w| w.document())
+		.unwrap()
+		.query_selector_all(&format!("[{}]", TEST_ROOT_ATTRIBUTE))
+		.unwrap()
+		.length()
+}
+
+/// `<output>` showing `value`, re-rendered when it changes
+fn output(value: Signal<String>) -> Page {
+	Page::reactive(move || PageElement::new("output").child(value.get()).into_page())
+}
+
+fn counter(count: Signal<i32>) -> Page {
+	let increment = count.clone();
+	PageElement::new("div")
+		.child(
+			PageElement::new("button")
+				.child("Increment")
+				.listener("click", move |_| increment.update(|n| *n += 1)),
+		)
+		.child(Page::reactive(move || {
+			PageElement::new("span")
+				.attr("role", "status")
+				.child(count.get().to_string())
+				.into_page()
+		}))
+		.into_page()
+}
+
+#[wasm_bindgen_test]
+fn test_render_mounts_view_and_drop_removes_container() {
+	// Arrange
+	let before = mounted_roots();
+
+	// Act
+	let rendered = render(PageElement::new("p").child("hello").into_page());
+	let html = rendered.html();
+	let while_mounted = mounted_roots();
+	drop(rendered);
+
+	// Assert
+	assert_eq!(html, "<p>hello</p>");
+	assert_eq!(while_mounted, before + 1);
+	assert_eq!(mounted_roots(), before);
+}
+
+#[wasm_bindgen_test]
+fn test_click_flushes_reactive_updates() {
+	// Arrange
+	let count = Signal::new(0);
+	let rendered = render(counter(count.clone()));
+
+	// Act
+	rendered.click(&rendered.get_by_role_with_name("button", "Increment").get());
+
+	// Assert
+	assert_eq!(count.get_untracked(), 1);
+	assert_eq!(
+		rendered.get_by_role("status").get().text_content().unwrap(),
+		"1"
+	);
+}
+
+#[wasm_bindgen_test]
+fn test_input_updates_value_and_flushes() {
+	// Arrange
+	let value = Signal::new(String::new());
+	let on_input = value.clone();
+	let rendered = render(
+		PageElement::new("div")
+			.child(
+				PageElement::new("input")
+					.attr("type", "text")
+					.listener("input", move |event| {
+						let input: HtmlInputElement = event.target().unwrap().unchecked_into();
+						on_input.set(input.value());
+					}),
+			)
+			.child(output(value.clone()))
+			.into_page(),
+	);
+
+	// Act
+	rendered.input(&rendered.select("input").get(), "reinhardt");
+
+	// Assert
+	assert_eq!(value.get_untracked(), "reinhardt");
+	assert_eq!(
+		rendered.select("output").get().text_content().unwrap(),
+		"reinhardt"
+	);
+}
+
+#[wasm_bindgen_test]
+fn test_flush_sync_applies_direct_signal_writes() {
+	// Arrange
+	let value = Signal::new("before".to_string());
+	let rendered = render(output(value.clone()));
+
+	// Act
+	value.set("after".to_string());
+	flush_sync();
+
+	// Assert
+	assert_eq!(
+		rendered.select("output").get().text_content().unwrap(),
+		"after"
+	);
+}
+
+#[wasm_bindgen_test]
+fn test_dropping_one_render_keeps_other_views_reactive() {
+	// Arrange
+	let first = render(output(Signal::new("first".to_string())));
+	let value = Signal::new("second".to_string());
+	let second = render(output(value.clone()));
+	let held = second.reactive_nodes().len();
+
+	// Act
+	drop(first);
+	act(|| value.set("updated".to_string()));
+
+	// Assert
+	assert!(held > 0);
+	assert_eq!(second.reactive_nodes().len(), held);
+	assert_eq!(
+		second.select("output").get().text_content().unwrap(),
+		"updated"
+	);
+}
@@ -0,0 +1,359 @@
+//! Implementation of `#[derive(Factory)]`.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+	Data, DeriveInput, Expr, Fields, Ident, LitStr, Path, Result, Type, parse2, spanned::Spanned,
+};
+
+/// How a field value is generated when the test does not override it
+enum Generator {
+	Faker(Ident),
+	Sequence(Option<LitStr>),
+	Default(Expr),
+	SubFactory(Path),
+	Skip,
+	Inferred,
+}
+
+struct FactoryField {
+	ident: Ident,
+	ty: Type,
+	generator: Generator,
+}
+
+pub(crate) fn expand(input: TokenStream) -> Result<TokenStream> {
+	let input: DeriveInput = parse2(input)?;
+	if !input.generics.params.is_empty() {
+		return Err(syn::Error::new(
+			input.generics.span(),
+			"#[derive(Factory)] does not support generic models",
+		));
+	}
+
+	let model = &input.ident;
+	let vis = &input.vis;
+	let factory = factory_name(&input)?;
+
+	let fields = match &input.data {
+		Data::Struct(data) => match &data.fields {
+			Fields::Named(named) => &named.named,
+			_ => {
+				return Err(syn::Error::new(
+					input.span(),
+					"#[derive(Factory)] requires a struct with named fields",
+				));
+			}
+		},
+		_ => {
+			return Err(syn::Error::new(
+				input.span(),
+				"#[derive(Factory)] can only be used on structs",
+			));
+		}
+	};
+
+	let fields = fields
+		.iter()
+		.map(|field| {
+			Ok(FactoryField {
+				ident: field.ident.clone().expect("named field"),
+				ty: field.ty.clone(),
+				generator: parse_generator(&field.attrs)?,
+			})
+		})
+		.collect::<Result<Vec<_>>>()?;
+
+	let idents: Vec<_> = fields.iter().map(|f| &f.ident).collect();
+	let types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+	let generated = fields.iter().map(generate_value);
+	let relations = fields.iter().filter_map(|field| match &field.generator {
+		Generator::SubFactory(sub) => {
+			let ident = &field.ident;
+			Some(quote! {
+				if self.#ident.is_none() {
+					let __related = <#sub as ::reinhardt_testkit::model_factory::ModelFactory>::create(
+						&<#sub as ::core::default::Default>::default(),
+					)
+					.await?;
+					__instance.#ident =
+						::reinhardt_testkit::model_factory::primary_key_of(&__related)?.into();
+				}
+			})
+		}
+		_ => None,
+	});
+	let setter_docs = idents
+		.iter()
+		.map(|ident| format!("Override the `{}` field", ident));
+	let factory_doc = format!("Factory for [`{}`] test instances", model);
+
+	Ok(quote! {
+		#[doc = #factory_doc]
+		#[derive(Clone)]
+		#vis struct #factory {
+			#( #idents: ::core::option::Option<#types>, )*
+		}
+
+		impl ::core::default::Default for #factory {
+			fn default() -> Self {
+				Self {
+					#( #idents: ::core::option::Option::None, )*
+				}
+			}
+		}
+
+		impl #factory {
+			/// Create a factory with no overridden fields
+			pub fn new() -> Self {
+				::core::default::Default::default()
+			}
+
+			#(
+				#[doc = #setter_docs]
+				pub fn #idents(mut self, value: impl ::core::convert::Into<#types>) -> Self {
+					self.#idents = ::core::option::Option::Some(value.into());
+					self
+				}
+			)*
+		}
+
+		#[::reinhardt_testkit::model_factory::__async_trait]
+		impl ::reinhardt_testkit::model_factory::ModelFactory for #factory {
+			type Model = #model;
+
+			fn sequence() -> &'static ::reinhardt_testkit::model_factory::Sequence {
+				static SEQUENCE: ::reinhardt_testkit::model_factory::Sequence =
+					::reinhardt_testkit::model_factory::Sequence::new();
+				&SEQUENCE
+			}
+
+			fn build(&self) -> #model {
+				let __n = <Self as ::reinhardt_testkit::model_factory::ModelFactory>::sequence().next();
+				let _ = __n;
+				#model {
+					#(
+						#idents: match &self.#idents {
+							::core::option::Option::Some(value) => ::core::clone::Clone::clone(value),
+							::core::option::Option::None => #generated,
+						},
+					)*
+				}
+			}
+
+			async fn build_with_relations(
+				&self,
+			) -> ::reinhardt_testkit::model_factory::FactoryResult<#model> {
+				let mut __instance =
+					<Self as ::reinhardt_testkit::model_factory::ModelFactory>::build(self);
+				#( #relations )*
+				::core::result::Result::Ok(__instance)
+			}
+		}
+	})
+}
+
+/// Read `#[factory(name = "...")]` from the struct, defaulting to `{Model}Factory`
+fn factory_name(input: &DeriveInput) -> Result<Ident> {
+	let mut name = None;
+	for attr in input.attrs.iter().filter(|a| a.path().is_ident("factory")) {
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("name") {
+				let lit: LitStr = meta.value()?.parse()?;
+				name = Some(Ident::new(&lit.value(), lit.span()));
+				Ok(())
+			} else {
+				Err(meta.error("unknown #[factory] struct attribute; expected `name`"))
+			}
+		})?;
+	}
+	Ok(name.unwrap_or_else(|| format_ident!("{}Factory", input.ident)))
+}
+
+fn parse_generator(attrs: &[syn::Attribute]) -> Result<Generator> {
+	let mut generator = Generator::Inferred;
+	for attr in attrs.iter().filter(|a| a.path().is_ident("factory")) {
+		attr.parse_nested_meta(|meta| {
+			if !matches!(generator, Generator::Inferred) {
+				return Err(meta.error("only one #[factory] generator is allowed per field"));
+			}
+			if meta.path.is_ident("faker") {
+				let lit: LitStr = meta.value()?.parse()?;
+				generator = Generator::Faker(Ident::new(&lit.value(), lit.span()));
+			} else if meta.path.is_ident("sequence") {
+				let format = if meta.input.peek(syn::Token![=]) {
+					Some(meta.value()?.parse()?)
+				} else {
+					None
+				};
+				generator = Generator::Sequence(format);
+			} else if meta.path.is_ident("default") {
+				generator = Generator::Default(meta.value()?.parse()?);
+			} else if meta.path.is_ident("subfactory") {
+				generator = Generator::SubFactory(meta.value()?.parse()?);
+			} else if meta.path.is_ident("skip") {
+				generator = Generator::Skip;
+			} else {
+				return Err(meta.error(
+					"unknown #[factory] field attribute; expected `faker`, `sequence`, \
+					 `default`, `subfactory` or `skip`",
+				));
+			}
+			Ok(())
+		})?;
+	}
+	Ok(generator)
+}
+
+fn fake_call(name: &str) -> TokenStream {
+	let ident = Ident::new(name, Span::call_site());
+	quote! { ::reinhardt_testkit::model_factory::fake::#ident() }
+}
+
+fn generate_value(field: &FactoryField) -> TokenStream {
+	match &field.generator {
+		Generator::Faker(name) => {
+			quote! { ::core::convert::Into::into(::reinhardt_testkit::model_factory::fake::#name()) }
+		}
+		Generator::Sequence(Some(format)) => quote! {
+			::core::convert::Into::into(
+				::reinhardt_testkit::model_factory::format_sequence(#format, __n)
+			)
+		},
+		Generator::Sequence(None) => quote! { __n as _ },
+		Generator::Default(expr) => quote! { ::core::convert::Into::into(#expr) },
+		Generator::SubFactory(_) | Generator::Skip => {
+			quote! { ::core::default::Default::default() }
+		}
+		Generator::Inferred => infer_value(&field.ident, &field.ty),
+	}
+}
+
+/// Pick a generator from the field's name and type
+fn infer_value(ident: &Ident, ty: &Type) -> TokenStream {
+	let type_name = match ty {
+		Type::Path(path) => path
+			.path
+			.segments
+			.last()
+			.map(|segment| segment.ident.to_string())
+			.unwrap_or_default(),
+		_ => String::new(),
+	};
+
+	match type_name.as_str() {
+		"String" => {
+			let field = ident.to_string();
+			let generator = match field.as_str() {
+				"email" | "email_address" => "email",
+				"username" | "login" => "username",
+				"first_name" | "given_name" => "first_name",
+				"last_name" | "family_name" | "surname" => "last_name",
+				"name" | "full_name" | "display_name" => "name",
+				"title" | "headline" => "title",
+				"slug" => "slug",
+				"url" | "website" | "homepage" => "url",
+				"description" | "body" | "content" | "bio" | "text" => "paragraph",
+				_ => "word",
+			};
+			fake_call(generator)
+		}
+		"DateTime" => fake_call("datetime"),
+		"NaiveDateTime" => {
+			let call = fake_call("datetime");
+			quote! { #call.naive_utc() }
+		}
+		_ => quote! { ::core::default::Default::default() },
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn expand_str(input: TokenStream) -> String {
+		expand(input).unwrap().to_string()
+	}
+
+	#[rstest]
+	fn test_generates_named_factory_with_setters() {
+		// Arrange
+		let input = quote! {
+			pub struct User {
+				pub id: Option<i64>,
+				pub email: String,
+			}
+		};
+
+		// Act
+		let output = expand_str(input);
+
+		// Assert
+		assert!(output.contains("pub struct UserFactory"));
+		assert!(output.contains("pub fn email"));
+		assert!(output.contains("fake :: email ()"));
+	}
+
+	#[rstest]
+	fn test_custom_factory_name() {
+		// Arrange
+		let input = quote! {
+			#[factory(name = "AdminFactory")]
+			struct User { id: Option<i64> }
+		};
+
+		// Act
+		let output = expand_str(input);
+
+		// Assert
+		assert!(output.contains("struct AdminFactory"));
+	}
+
+	#[rstest]
+	fn test_subfactory_creates_related_instance() {
+		// Arrange
+		let input = quote! {
+			struct Post {
+				#[factory(subfactory = UserFactory)]
+				author_id: i64,
+			}
+		};
+
+		// Act
+		let output = expand_str(input);
+
+		// Assert
+		assert!(output.contains("primary_key_of"));
+		assert!(output.contains("UserFactory as"));
+	}
+
+	#[rstest]
+	fn test_rejects_multiple_generators() {
+		// Arrange
+		let input = quote! {
+			struct User {
+				#[factory(faker = "email", sequence = "u{n}")]
+				email: String,
+			}
+		};
+
+		// Act
+		let result = expand(input);
+
+		// Assert
+		assert!(result.is_err());
+	}
+
+	#[rstest]
+	fn test_rejects_tuple_structs() {
+		// Arrange
+		let input = quote! { struct User(i64); };
+
+		// Act
+		let result = expand(input);
+
+		// Assert
+		assert!(result.is_err());
+	}
+}
@@ -5,6 +5,7 @@
 
 #![warn(missing_docs)]
 
+mod factory;
 mod with_di_overrides;
 
 use proc_macro::TokenStream;
@@ -17,3 +18,15 @@ pub fn with_di_overrides(input: TokenStream) -> TokenStream {
 		.unwrap_or_else(|err| err.to_compile_error())
 		.into()
 }
+
+/// Derives a model factory named `{Model}Factory`.
+///
+/// The factory has a setter per field for overriding values and implements
+/// `reinhardt_testkit::model_factory::ModelFactory`. See that module for the
+/// supported `#[factory(...)]` attributes.
+#[proc_macro_derive(Factory, attributes(factory))]
+pub fn derive_factory(input: TokenStream) -> TokenStream {
+	factory::expand(input.into())
+		.unwrap_or_else(|err| err.to_compile_error())
+		.into()
+}
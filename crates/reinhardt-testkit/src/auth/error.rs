@@ -18,6 +18,9 @@ pub enum TestAuthError {
 	/// The user is not registered with the MFA manager.
 	#[error("MFA user not registered: {0}")]
 	MfaUserNotRegistered(String),
+	/// `force_login` was called on a `TestClient` without a session backend.
+	#[error("no session backend configured")]
+	NoSessionBackend,
 	/// An error occurred on the APIClient (e.g., setting a header or cookie).
 	#[error("client error: {0}")]
 	ClientError(String),
@@ -33,6 +36,7 @@ mod tests {
 	#[case(TestAuthError::JwtError("bad key".into()), "JWT signing error: bad key")]
 	#[case(TestAuthError::NoPrimaryAuth, "no primary auth configured")]
 	#[case(TestAuthError::MfaUserNotRegistered("alice".into()), "MFA user not registered: alice")]
+	#[case(TestAuthError::NoSessionBackend, "no session backend configured")]
 	#[case(TestAuthError::ClientError("header fail".into()), "client error: header fail")]
 	fn display_formats(#[case] error: TestAuthError, #[case] expected: &str) {
 		assert_eq!(error.to_string(), expected);
@@ -0,0 +1,305 @@
+//! Synthetic request generation for benchmarks
+//!
+//! [`SyntheticRequestGenerator`] produces a reproducible stream of
+//! [`Request`]s over a configurable route table, so router, middleware and
+//! serializer benchmarks measure the same traffic on every run. Register
+//! [`routes`](SyntheticRequestGenerator::routes) with the router under test,
+//! then feed it the generated requests:
+//!
+//! ```rust,no_run
+//! use reinhardt_testkit::bench::SyntheticRequestGenerator;
+//! use reinhardt_urls::routers::{PathMatcher, PathPattern};
+//!
+//! let mut generator = SyntheticRequestGenerator::new(42).with_generated_routes(200);
+//!
+//! let mut matcher = PathMatcher::new();
+//! for (index, route) in generator.routes().iter().enumerate() {
+//!     let pattern = PathPattern::new(route.pattern.clone()).unwrap();
+//!     matcher.add_pattern(pattern, format!("route{index}")).unwrap();
+//! }
+//!
+//! let paths = generator.paths(1_000);
+//! for path in &paths {
+//!     assert!(matcher.match_path(path).is_some());
+//! }
+//! ```
+
+use bytes::Bytes;
+use hyper::Method;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use reinhardt_http::Request;
+
+/// A route the generator can produce requests for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntheticRoute {
+	/// HTTP method of generated requests
+	pub method: Method,
+	/// Route pattern using `{name}` placeholders, e.g. `/api/users/{id}/`
+	pub pattern: String,
+}
+
+impl SyntheticRoute {
+	/// Create a route
+	pub fn new(method: Method, pattern: impl Into<String>) -> Self {
+		Self {
+			method,
+			pattern: pattern.into(),
+		}
+	}
+
+	/// Whether generated requests for this route carry a body
+	pub fn has_body(&self) -> bool {
+		matches!(self.method, Method::POST | Method::PUT | Method::PATCH)
+	}
+}
+
+/// Routes of a small REST API used when no route table is configured
+pub fn default_routes() -> Vec<SyntheticRoute> {
+	vec![
+		SyntheticRoute::new(Method::GET, "/"),
+		SyntheticRoute::new(Method::GET, "/api/users/"),
+		SyntheticRoute::new(Method::POST, "/api/users/"),
+		SyntheticRoute::new(Method::GET, "/api/users/{id}/"),
+		SyntheticRoute::new(Method::PATCH, "/api/users/{id}/"),
+		SyntheticRoute::new(Method::DELETE, "/api/users/{id}/"),
+		SyntheticRoute::new(Method::GET, "/api/users/{user_id}/posts/"),
+		SyntheticRoute::new(Method::GET, "/api/users/{user_id}/posts/{post_id}/"),
+		SyntheticRoute::new(Method::PUT, "/api/users/{user_id}/posts/{post_id}/"),
+		SyntheticRoute::new(Method::GET, "/api/tags/{slug}/"),
+		SyntheticRoute::new(Method::GET, "/health/"),
+	]
+}
+
+/// Reproducible generator of benchmark requests
+///
+/// The same seed and configuration always yield the same sequence of
+/// requests.
+#[derive(Debug, Clone)]
+pub struct SyntheticRequestGenerator {
+	rng: StdRng,
+	routes: Vec<SyntheticRoute>,
+	body_size: usize,
+	extra_headers: usize,
+	query_params: usize,
+}
+
+impl SyntheticRequestGenerator {
+	/// Create a generator over [`default_routes`] seeded with `seed`
+	pub fn new(seed: u64) -> Self {
+		Self {
+			rng: StdRng::seed_from_u64(seed),
+			routes: default_routes(),
+			body_size: 256,
+			extra_headers: 4,
+			query_params: 0,
+		}
+	}
+
+	/// Replace the route table
+	///
+	/// # Panics
+	///
+	/// Panics when `routes` is empty.
+	pub fn with_routes(mut self, routes: Vec<SyntheticRoute>) -> Self {
+		assert!(!routes.is_empty(), "route table must not be empty");
+		self.routes = routes;
+		self
+	}
+
+	/// Replace the route table with `count` list and detail routes spread
+	/// over `count / 2` resources, for measuring how routing scales
+	pub fn with_generated_routes(mut self, count: usize) -> Self {
+		let resources = count.div_ceil(2).max(1);
+		self.routes = (0..resources)
+			.flat_map(|i| {
+				[
+					SyntheticRoute::new(Method::GET, format!("/api/resource{i}/")),
+					SyntheticRoute::new(Method::GET, format!("/api/resource{i}/{{id}}/")),
+				]
+			})
+			.take(count.max(1))
+			.collect();
+		self
+	}
+
+	/// Size in bytes of the JSON body sent with POST, PUT and PATCH requests
+	pub fn with_body_size(mut self, bytes: usize) -> Self {
+		self.body_size = bytes;
+		self
+	}
+
+	/// Number of `X-Bench-*` headers added to every request
+	pub fn with_extra_headers(mut self, count: usize) -> Self {
+		self.extra_headers = count;
+		self
+	}
+
+	/// Number of query parameters appended to every request path
+	pub fn with_query_params(mut self, count: usize) -> Self {
+		self.query_params = count;
+		self
+	}
+
+	/// The route table requests are drawn from
+	pub fn routes(&self) -> &[SyntheticRoute] {
+		&self.routes
+	}
+
+	fn next_route(&mut self) -> SyntheticRoute {
+		let index = self.rng.random_range(0..self.routes.len());
+		self.routes[index].clone()
+	}
+
+	/// Substitute every `{name}` placeholder in `pattern` with a random value
+	fn concrete_path(&mut self, pattern: &str) -> String {
+		let mut path = String::with_capacity(pattern.len() + 8);
+		let mut rest = pattern;
+		while let Some(start) = rest.find('{') {
+			path.push_str(&rest[..start]);
+			let Some(end) = rest[start..].find('}') else {
+				break;
+			};
+			let name = &rest[start + 1..start + end];
+			if name.contains("slug") {
+				path.push_str(&format!("tag-{}", self.rng.random_range(0..10_000u32)));
+			} else {
+				path.push_str(&self.rng.random_range(1..1_000_000u64).to_string());
+			}
+			rest = &rest[start + end + 1..];
+		}
+		path.push_str(rest);
+
+		for i in 0..self.query_params {
+			path.push(if i == 0 { '?' } else { '&' });
+			path.push_str(&format!("q{}={}", i, self.rng.random_range(0..1000u32)));
+		}
+		path
+	}
+
+	/// JSON body of `body_size` bytes
+	fn body(&self) -> Bytes {
+		const WRAPPER: &str = r#"{"data":""}"#;
+		let padding = self.body_size.saturating_sub(WRAPPER.len());
+		Bytes::from(format!(r#"{{"data":"{}"}}"#, "x".repeat(padding)))
+	}
+
+	/// The next request path, including query parameters
+	pub fn next_path(&mut self) -> String {
+		let route = self.next_route();
+		self.concrete_path(&route.pattern)
+	}
+
+	/// `count` request paths
+	pub fn paths(&mut self, count: usize) -> Vec<String> {
+		(0..count).map(|_| self.next_path()).collect()
+	}
+
+	/// The next request
+	pub fn next_request(&mut self) -> Request {
+		let route = self.next_route();
+		let path = self.concrete_path(&route.pattern);
+
+		let mut builder = Request::builder()
+			.method(route.method.clone())
+			.uri(path.as_str())
+			.header(hyper::header::HOST, "bench.example.com")
+			.header(hyper::header::ACCEPT, "application/json")
+			.header(hyper::header::USER_AGENT, "reinhardt-bench/1.0");
+		for i in 0..self.extra_headers {
+			builder = builder.header(
+				hyper::header::HeaderName::try_from(format!("x-bench-{i}"))
+					.expect("generated header names are valid"),
+				format!("value-{i}"),
+			);
+		}
+		if route.has_body() {
+			builder = builder
+				.header(hyper::header::CONTENT_TYPE, "application/json")
+				.body(self.body());
+		}
+		builder
+			.build()
+			.expect("synthetic requests are always well-formed")
+	}
+
+	/// `count` requests
+	pub fn requests(&mut self, count: usize) -> Vec<Request> {
+		(0..count).map(|_| self.next_request()).collect()
+	}
+}
+
+impl Iterator for SyntheticRequestGenerator {
+	type Item = Request;
+
+	fn next(&mut self) -> Option<Request> {
+		Some(self.next_request())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_same_seed_yields_same_paths() {
+		// Arrange
+		let mut first = SyntheticRequestGenerator::new(7);
+		let mut second = SyntheticRequestGenerator::new(7);
+
+		// Act
+		let a = first.paths(50);
+		let b = second.paths(50);
+
+		// Assert
+		assert_eq!(a, b);
+	}
+
+	#[rstest]
+	fn test_placeholders_are_substituted() {
+		// Arrange
+		let mut generator = SyntheticRequestGenerator::new(1)
+			.with_routes(vec![SyntheticRoute::new(
+				Method::GET,
+				"/api/users/{user_id}/tags/{slug}/",
+			)])
+			.with_query_params(2);
+
+		// Act
+		let path = generator.next_path();
+
+		// Assert
+		assert!(path.starts_with("/api/users/"));
+		assert!(!path.contains('{'));
+		assert!(path.contains("/tags/tag-"));
+		assert!(path.contains("?q0=") && path.contains("&q1="));
+	}
+
+	#[rstest]
+	fn test_generated_routes_count() {
+		// Arrange / Act
+		let generator = SyntheticRequestGenerator::new(1).with_generated_routes(7);
+
+		// Assert
+		assert_eq!(generator.routes().len(), 7);
+		assert_eq!(generator.routes()[1].pattern, "/api/resource0/{id}/");
+	}
+
+	#[rstest]
+	fn test_requests_with_body_have_requested_size() {
+		// Arrange
+		let mut generator = SyntheticRequestGenerator::new(3)
+			.with_routes(vec![SyntheticRoute::new(Method::POST, "/api/users/")])
+			.with_body_size(512)
+			.with_extra_headers(2);
+
+		// Act
+		let request = generator.next_request();
+
+		// Assert
+		assert_eq!(request.method, Method::POST);
+		assert_eq!(request.body().len(), 512);
+		assert!(request.headers.contains_key("x-bench-1"));
+	}
+}
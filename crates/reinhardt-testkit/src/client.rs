@@ -373,6 +373,10 @@ impl APIClient {
 	pub fn base_url(&self) -> &str {
 		&self.base_url
 	}
+	/// Get the DI context injected into in-process handler requests, if any.
+	pub fn di_context(&self) -> Option<&Arc<InjectionContext>> {
+		self.handler_di_context.as_ref()
+	}
 	/// Set a request handler for testing
 	///
 	/// # Examples
@@ -479,6 +483,11 @@ impl APIClient {
 		Ok(())
 	}
 
+	/// Get the value of a cookie that will be sent with subsequent requests.
+	pub async fn cookie(&self, name: &str) -> Option<String> {
+		self.cookies.read().await.get(name).cloned()
+	}
+
 	/// Remove a specific cookie.
 	pub async fn remove_cookie(&self, name: &str) -> ClientResult<()> {
 		let mut cookies = self.cookies.write().await;
@@ -794,7 +803,7 @@ impl APIClient {
 	///
 	/// This method is similar to `request()` but allows adding extra headers
 	/// that are specific to this request only, without modifying the default headers.
-	async fn request_with_extra_headers(
+	pub(crate) async fn request_with_extra_headers(
 		&self,
 		method: Method,
 		path: &str,
@@ -62,6 +62,9 @@ pub mod mock;
 /// Test server fixtures and builder utilities.
 pub mod server;
 
+#[cfg(feature = "testcontainers")]
+/// Per-test transactions and migrated template databases.
+pub mod isolation;
 #[cfg(feature = "testcontainers")]
 /// Suite-wide shared resources with automatic lifecycle management.
 pub mod resources;
@@ -143,6 +146,13 @@ pub use shared_postgres::{
 	get_test_pool_with_orm, get_test_pool_with_table, shared_db_pool,
 };
 
+// From isolation module (conditional on feature)
+#[cfg(feature = "testcontainers")]
+pub use isolation::{
+	IsolatedDatabase, IsolationError, MigratedTemplate, TestTransaction, reset_sequences,
+	shared_test_database, test_transaction,
+};
+
 // From resources module (conditional on feature)
 #[cfg(feature = "testcontainers")]
 pub use resources::{MySqlSuiteResource, PostgresSuiteResource, mysql_suite, postgres_suite};
@@ -0,0 +1,516 @@
+//! Database isolation strategies for tests
+//!
+//! Two strategies are provided, both backed by the shared PostgreSQL container
+//! from [`shared_postgres`](super::shared_postgres):
+//!
+//! - **Per-test transactions (fast path)**: [`TestTransaction`] opens a
+//!   transaction on a database shared by every test in the process and rolls
+//!   it back when the test ends. Nothing a test writes is ever visible to
+//!   another test. Use it for tests that do not need to commit.
+//! - **Template databases**: [`MigratedTemplate`] applies a set of migrations
+//!   once into a PostgreSQL template database and then clones a fresh database
+//!   for each test (~10-40ms). Use it for tests that commit, open several
+//!   connections, or exercise transaction behavior themselves.
+//!
+//! Sequences are not transactional in PostgreSQL, so [`TestTransaction`]
+//! restarts every sequence at the beginning of the transaction; the restart is
+//! rolled back with the test. Cloned databases start with the template's fresh
+//! sequences.
+//!
+//! Database names embed the process id and a UUIDv7, and template creation is
+//! serialized across processes with a file lock, so tests can run in parallel
+//! under both `cargo test` and nextest.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use reinhardt_testkit::fixtures::{TestTransaction, test_transaction};
+//! use rstest::*;
+//!
+//! #[rstest]
+//! #[tokio::test]
+//! async fn test_insert_is_rolled_back(#[future] test_transaction: TestTransaction) {
+//!     let mut tx = test_transaction.await;
+//!     sqlx::query("CREATE TABLE notes (id SERIAL PRIMARY KEY, body TEXT)")
+//!         .execute(&mut *tx)
+//!         .await
+//!         .unwrap();
+//!     // Dropping `tx` rolls back the table and its rows.
+//! }
+//! ```
+//!
+//! ```rust,ignore
+//! use reinhardt_testkit::fixtures::MigratedTemplate;
+//!
+//! #[tokio::test]
+//! async fn test_with_commits() {
+//!     let template = MigratedTemplate::for_provider::<MyappMigrations>().await.unwrap();
+//!     let db = template.clone_database().await.unwrap();
+//!     // `db.pool()` points at a private, fully migrated database.
+//!     db.drop_database().await.unwrap();
+//! }
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, DerefMut};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use reinhardt_db::migrations::{Migration, MigrationProvider};
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+use tokio::sync::OnceCell;
+use uuid::Uuid;
+
+use super::shared_postgres::get_shared_postgres;
+use super::testcontainers::FileLockGuard;
+
+/// Error type for isolation fixtures
+pub type IsolationError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Database shared by all transactional tests of this process
+static SHARED_DATABASE: OnceCell<PgPool> = OnceCell::const_new();
+
+/// Names of templates already built or verified by this process
+static TEMPLATES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Generate a database name unique across processes and tests
+fn unique_database_name(prefix: &str) -> String {
+	format!(
+		"{}_{}_{}",
+		prefix,
+		std::process::id(),
+		Uuid::now_v7().simple()
+	)
+}
+
+/// Quote a PostgreSQL identifier
+fn quote_ident(ident: &str) -> String {
+	format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+/// Connect to `database` on the shared server
+async fn connect(database: &str, max_connections: u32) -> sqlx::Result<PgPool> {
+	let pg = get_shared_postgres().await;
+	// Same settings as `get_test_pool`; see the sqlx issues referenced there.
+	sqlx::postgres::PgPoolOptions::new()
+		.max_connections(max_connections)
+		.acquire_timeout(Duration::from_secs(10))
+		.test_before_acquire(false)
+		.idle_timeout(Some(Duration::from_secs(30)))
+		.connect(&database_url(&pg.base_url, database))
+		.await
+}
+
+fn database_url(base_url: &str, database: &str) -> String {
+	format!("{}/{}?sslmode=disable", base_url, database)
+}
+
+/// Clone `template` into a new database named `name`
+async fn create_from_template(name: &str, template: &str) -> sqlx::Result<()> {
+	let admin = connect("postgres", 1).await?;
+	sqlx::query(&format!(
+		"CREATE DATABASE {} TEMPLATE {}",
+		quote_ident(name),
+		quote_ident(template)
+	))
+	.execute(&admin)
+	.await?;
+	admin.close().await;
+	Ok(())
+}
+
+/// Restart every sequence in the current schema
+///
+/// Runs inside the caller's transaction when one is open, in which case the
+/// restart is rolled back together with it. Sequences are locked in name
+/// order, so concurrent callers on the same database serialize instead of
+/// deadlocking.
+pub async fn reset_sequences(conn: &mut PgConnection) -> sqlx::Result<()> {
+	let sequences: Vec<(String, String)> = sqlx::query_as(
+		"SELECT schemaname::text, sequencename::text FROM pg_sequences \
+		 WHERE schemaname = current_schema() ORDER BY sequencename",
+	)
+	.fetch_all(&mut *conn)
+	.await?;
+
+	for (schema, sequence) in sequences {
+		sqlx::query(&format!(
+			"ALTER SEQUENCE {}.{} RESTART",
+			quote_ident(&schema),
+			quote_ident(&sequence)
+		))
+		.execute(&mut *conn)
+		.await?;
+	}
+	Ok(())
+}
+
+/// Get the database shared by transactional tests in this process
+///
+/// The database is cloned from the shared server's `test_template` the first
+/// time it is requested.
+pub async fn shared_test_database() -> &'static PgPool {
+	SHARED_DATABASE
+		.get_or_init(|| async {
+			let name = unique_database_name("test_shared");
+			create_from_template(&name, "test_template")
+				.await
+				.expect("Failed to create shared test database");
+			connect(&name, 10)
+				.await
+				.expect("Failed to connect to shared test database")
+		})
+		.await
+}
+
+/// A transaction that is rolled back when the test ends
+///
+/// Dereferences to [`PgConnection`], so it can be passed to sqlx queries as
+/// `&mut *tx`. Dropping it rolls the transaction back.
+pub struct TestTransaction {
+	tx: Transaction<'static, Postgres>,
+}
+
+impl TestTransaction {
+	/// Begin a transaction on `pool` and restart its sequences
+	///
+	/// The sequence restart holds a lock until the transaction ends, so
+	/// concurrent transactions on the same database that share sequences run
+	/// one after another. Separate processes use separate shared databases.
+	pub async fn begin(pool: &PgPool) -> sqlx::Result<Self> {
+		let mut tx = pool.begin().await?;
+		reset_sequences(&mut tx).await?;
+		Ok(Self { tx })
+	}
+
+	/// Roll the transaction back explicitly
+	///
+	/// Dropping the transaction also rolls it back; call this to observe
+	/// rollback errors.
+	pub async fn rollback(self) -> sqlx::Result<()> {
+		self.tx.rollback().await
+	}
+}
+
+impl Deref for TestTransaction {
+	type Target = PgConnection;
+
+	fn deref(&self) -> &Self::Target {
+		&self.tx
+	}
+}
+
+impl DerefMut for TestTransaction {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.tx
+	}
+}
+
+/// rstest fixture providing a rolled-back transaction on the shared database
+///
+/// See the [module documentation](self) for an example.
+#[rstest::fixture]
+pub async fn test_transaction() -> TestTransaction {
+	TestTransaction::begin(shared_test_database().await)
+		.await
+		.expect("Failed to begin test transaction")
+}
+
+/// A PostgreSQL template database with migrations applied
+///
+/// The template name is derived from the migrations it contains and their
+/// operations, so changing the migration set or editing a migration builds a
+/// new template instead of reusing a stale one.
+#[derive(Debug, Clone)]
+pub struct MigratedTemplate {
+	name: String,
+}
+
+impl MigratedTemplate {
+	/// Get or build a template with the migrations of provider `P`
+	pub async fn for_provider<P: MigrationProvider>() -> Result<Self, IsolationError> {
+		Self::from_migrations(P::migrations()).await
+	}
+
+	/// Get or build a template with `migrations` applied in order
+	///
+	/// The template is built once per server: other processes wait on a file
+	/// lock and then reuse it. A template left half-built by a crashed process
+	/// is rebuilt.
+	pub async fn from_migrations(migrations: Vec<Migration>) -> Result<Self, IsolationError> {
+		let name = template_name(&migrations);
+		let built = TEMPLATES
+			.lock()
+			.unwrap_or_else(|e| e.into_inner())
+			.contains(&name);
+		if built {
+			return Ok(Self { name });
+		}
+
+		let lock_path = std::env::temp_dir().join(format!("reinhardt_{}.lock", name));
+		let _lock = tokio::task::spawn_blocking(move || FileLockGuard::new(lock_path)).await??;
+
+		let admin = connect("postgres", 1).await?;
+		let existing: Option<(bool,)> =
+			sqlx::query_as("SELECT datistemplate FROM pg_database WHERE datname = $1")
+				.bind(&name)
+				.fetch_optional(&admin)
+				.await?;
+
+		match existing {
+			Some((true,)) => {}
+			partial => {
+				if partial.is_some() {
+					sqlx::query(&format!(
+						"DROP DATABASE {} WITH (FORCE)",
+						quote_ident(&name)
+					))
+					.execute(&admin)
+					.await?;
+				}
+				sqlx::query(&format!(
+					"CREATE DATABASE {} TEMPLATE test_template",
+					quote_ident(&name)
+				))
+				.execute(&admin)
+				.await?;
+				apply_migrations(&name, &migrations).await?;
+
+				// Cloning requires that nobody else is connected to the template.
+				sqlx::query(
+					"SELECT pg_terminate_backend(pid) FROM pg_stat_activity \
+					 WHERE datname = $1 AND pid <> pg_backend_pid()",
+				)
+				.bind(&name)
+				.execute(&admin)
+				.await?;
+				sqlx::query(&format!(
+					"ALTER DATABASE {} IS_TEMPLATE true",
+					quote_ident(&name)
+				))
+				.execute(&admin)
+				.await?;
+			}
+		}
+		admin.close().await;
+
+		TEMPLATES
+			.lock()
+			.unwrap_or_else(|e| e.into_inner())
+			.push(name.clone());
+		Ok(Self { name })
+	}
+
+	/// Name of the template database
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Create a new database cloned from this template
+	pub async fn clone_database(&self) -> Result<IsolatedDatabase, IsolationError> {
+		let name = unique_database_name("test");
+		create_from_template(&name, &self.name).await?;
+		let pool = connect(&name, 5).await?;
+		let url = database_url(&get_shared_postgres().await.base_url, &name);
+		Ok(IsolatedDatabase { name, url, pool })
+	}
+}
+
+/// Derive a stable template name from the migrations and their operations
+///
+/// Editing the operations of an existing migration yields a new name, so a
+/// template built from the old body is never reused.
+fn template_name(migrations: &[Migration]) -> String {
+	let mut hasher = DefaultHasher::new();
+	for migration in migrations {
+		migration.app_label.hash(&mut hasher);
+		migration.name.hash(&mut hasher);
+		match serde_json::to_value(&migration.operations) {
+			Ok(operations) => hash_json(&operations, &mut hasher),
+			Err(_) => format!("{:?}", migration.operations).hash(&mut hasher),
+		}
+	}
+	format!("template_{:016x}", hasher.finish())
+}
+
+/// Hash a JSON value with object keys in sorted order
+///
+/// Operations may hold hash maps, whose serialization order differs between
+/// processes.
+fn hash_json(value: &serde_json::Value, hasher: &mut impl Hasher) {
+	use serde_json::Value;
+
+	std::mem::discriminant(value).hash(hasher);
+	match value {
+		Value::Null => {}
+		Value::Bool(b) => b.hash(hasher),
+		Value::Number(n) => n.to_string().hash(hasher),
+		Value::String(s) => s.hash(hasher),
+		Value::Array(items) => {
+			items.len().hash(hasher);
+			for item in items {
+				hash_json(item, hasher);
+			}
+		}
+		Value::Object(map) => {
+			let mut entries: Vec<_> = map.iter().collect();
+			entries.sort_by_key(|(key, _)| *key);
+			entries.len().hash(hasher);
+			for (key, item) in entries {
+				key.hash(hasher);
+				hash_json(item, hasher);
+			}
+		}
+	}
+}
+
+async fn apply_migrations(database: &str, migrations: &[Migration]) -> Result<(), IsolationError> {
+	use reinhardt_db::DatabaseConnection;
+	use reinhardt_db::migrations::executor::DatabaseMigrationExecutor;
+
+	if migrations.is_empty() {
+		return Ok(());
+	}
+	let url = database_url(&get_shared_postgres().await.base_url, database);
+	let connection = DatabaseConnection::connect_postgres(&url)
+		.await
+		.map_err(|e| format!("Failed to connect to template database: {}", e))?;
+	let mut executor = DatabaseMigrationExecutor::new(connection.inner().clone());
+	executor
+		.apply_migrations(migrations)
+		.await
+		.map_err(|e| format!("Failed to apply migrations to template: {}", e))?;
+	Ok(())
+}
+
+/// A private database cloned from a [`MigratedTemplate`]
+pub struct IsolatedDatabase {
+	name: String,
+	url: String,
+	pool: PgPool,
+}
+
+impl IsolatedDatabase {
+	/// Name of the database
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Connection URL of the database
+	pub fn url(&self) -> &str {
+		&self.url
+	}
+
+	/// Connection pool for the database
+	pub fn pool(&self) -> &PgPool {
+		&self.pool
+	}
+
+	/// Point the global ORM connection at this database
+	pub async fn init_orm(&self) -> Result<(), IsolationError> {
+		reinhardt_db::orm::reinitialize_database(&self.url)
+			.await
+			.map_err(|e| format!("Failed to reinitialize ORM database: {}", e))?;
+		Ok(())
+	}
+
+	/// Close all connections and drop the database
+	pub async fn drop_database(self) -> Result<(), IsolationError> {
+		self.pool.close().await;
+		let admin = connect("postgres", 1).await?;
+		sqlx::query(&format!(
+			"DROP DATABASE IF EXISTS {} WITH (FORCE)",
+			quote_ident(&self.name)
+		))
+		.execute(&admin)
+		.await?;
+		admin.close().await;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reinhardt_db::migrations::Operation;
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_unique_database_names_embed_pid() {
+		// Arrange / Act
+		let first = unique_database_name("test");
+		let second = unique_database_name("test");
+
+		// Assert
+		assert_ne!(first, second);
+		assert!(first.starts_with(&format!("test_{}_", std::process::id())));
+	}
+
+	#[rstest]
+	#[case::plain("users_id_seq", "\"users_id_seq\"")]
+	#[case::embedded_quote("we\"ird", "\"we\"\"ird\"")]
+	fn test_quote_ident(#[case] ident: &str, #[case] expected: &str) {
+		// Arrange / Act / Assert
+		assert_eq!(quote_ident(ident), expected);
+	}
+
+	#[rstest]
+	fn test_template_name_tracks_migration_set() {
+		// Arrange
+		let first = vec![Migration::new("0001_initial", "blog")];
+		let second = vec![
+			Migration::new("0001_initial", "blog"),
+			Migration::new("0002_tags", "blog"),
+		];
+
+		// Act / Assert
+		assert_eq!(template_name(&first), template_name(&first));
+		assert_ne!(template_name(&first), template_name(&second));
+	}
+
+	#[rstest]
+	fn test_template_name_tracks_edited_operations() {
+		// Arrange
+		let original =
+			vec![
+				Migration::new("0001_initial", "blog").add_operation(Operation::DropTable {
+					name: "posts".to_string(),
+				}),
+			];
+		let edited =
+			vec![
+				Migration::new("0001_initial", "blog").add_operation(Operation::DropTable {
+					name: "articles".to_string(),
+				}),
+			];
+
+		// Act / Assert
+		assert_eq!(template_name(&original), template_name(&original));
+		assert_ne!(template_name(&original), template_name(&edited));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_transaction_changes_are_rolled_back() {
+		// Arrange
+		let pool = shared_test_database().await;
+		let table = format!("rollback_{}", Uuid::now_v7().simple());
+		let mut tx = TestTransaction::begin(pool).await.unwrap();
+		sqlx::query(&format!("CREATE TABLE {} (id SERIAL PRIMARY KEY)", table))
+			.execute(&mut *tx)
+			.await
+			.unwrap();
+
+		// Act
+		tx.rollback().await.unwrap();
+
+		// Assert
+		let exists: (bool,) = sqlx::query_as("SELECT to_regclass($1) IS NOT NULL")
+			.bind(&table)
+			.fetch_one(pool)
+			.await
+			.unwrap();
+		assert!(!exists.0);
+	}
+}
@@ -16,10 +16,14 @@
 //! ## Features
 //!
 //! - **[`APIClient`]**: HTTP client for making test API requests
+//! - **[`TestClient`]**: In-process client with cookie persistence, CSRF
+//!   handling, `force_login` and multipart uploads
 //! - **[`APIRequestFactory`]**: Factory for creating mock HTTP requests
 //! - **[`APITestCase`]**: Base test case with common assertions
 //! - **Response Assertions**: Status, header, and body assertions
 //! - **[`Factory`]**: Model factory for generating test data
+//! - **[`model_factory`]**: `#[derive(Factory)]` model factories with fake data,
+//!   sequences, sub-factories and `create_batch`
 //! - **[`DebugToolbar`]**: Debug panel for inspecting queries and timing
 //! - **[`WebSocketTestClient`]**: WebSocket connection testing
 //! - **TestContainers**: Database containers (PostgreSQL, MySQL, Redis) integration
@@ -34,11 +38,15 @@
 //! - **`viewsets`**: Enable viewset testing utilities
 //! - **`admin`**: Enable admin panel testing utilities
 //! - **`messages`**: Enable message framework testing utilities
+//! - **`bench`**: Enable the synthetic request generator used by benchmarks
 //! - **`full`**: Enable all features above
 #![warn(missing_docs)]
 
 /// Assertion helpers for common test patterns.
 pub mod assertions;
+/// Synthetic request generation for benchmarks.
+#[cfg(feature = "bench")]
+pub mod bench;
 /// HTTP client for making test API requests.
 pub mod client;
 /// Debug toolbar for inspecting queries, timing, and cache.
@@ -56,12 +64,17 @@ pub mod logging;
 pub mod messages;
 /// Mock function and spy utilities for testing.
 pub mod mock;
+/// Model factories generating fake test data persisted via the ORM.
+pub mod model_factory;
 /// Test resource lifecycle management (setup/teardown).
 pub mod resource;
 /// Response wrapper with assertion methods.
 pub mod response;
 /// Test server spawning and management.
 pub mod server;
+/// In-process test client with cookie, CSRF and login handling.
+#[cfg(native)]
+pub mod test_client;
 /// Base test case with common assertions.
 pub mod testcase;
 /// Test view implementations for integration testing.
@@ -138,16 +151,19 @@ pub use messages::{
 	MessagesTestMixin, assert_message_count, assert_message_exists, assert_message_level,
 	assert_message_tags, assert_messages,
 };
-pub use mock::{CallRecord, MockFunction, SimpleHandler, Spy};
+pub use mock::{CallRecord, MockFunction, SimpleHandler, Spy, cassette_client};
+pub use model_factory::{ModelFactory, Sequence};
 pub use resource::{
 	AsyncTeardownGuard, AsyncTestResource, SuiteGuard, SuiteResource, TeardownGuard, TestResource,
 	acquire_suite,
 };
-pub use response::{ResponseExt, TestResponse};
+pub use response::{ResponseExt, TestResponse, json_contains};
 pub use server::{
 	BodyEchoHandler, DelayedHandler, EchoPathHandler, LargeResponseHandler, MethodEchoHandler,
 	RouterHandler, StatusCodeHandler, shutdown_test_server, spawn_test_server,
 };
+#[cfg(native)]
+pub use test_client::{MultipartForm, TestClient};
 pub use testcase::APITestCase;
 pub use views::{
 	ApiTestModel, ErrorKind, ErrorTestView, SimpleTestView, TestModel, create_api_test_objects,
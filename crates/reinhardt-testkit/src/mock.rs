@@ -494,6 +494,52 @@ where
 	}
 }
 
+/// Environment variable forcing [`cassette_client`] to re-record
+pub const RECORD_ENV_VAR: &str = "REINHARDT_RECORD_HTTP";
+
+/// HTTP client double backed by the cassette file at `path`
+///
+/// Records real responses to the file when it does not exist yet or when
+/// [`RECORD_ENV_VAR`] is set, and replays them otherwise, so tests of code
+/// calling external services run offline after the first recording.
+///
+/// # Examples
+///
+/// ```no_run
+/// use reinhardt_testkit::mock::cassette_client;
+///
+/// # async fn example() {
+/// let client = cassette_client("tests/cassettes/github_user.json").unwrap();
+/// let user = client
+///     .send(client.get("https://api.github.com/users/octocat"))
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+pub fn cassette_client(
+	path: impl AsRef<std::path::Path>,
+) -> reinhardt_utils::http_client::HttpClientResult<reinhardt_utils::http_client::HttpClient> {
+	use reinhardt_utils::http_client::{Cassette, HttpClient};
+
+	let path = path.as_ref();
+	let record = std::env::var_os(RECORD_ENV_VAR).is_some() || !path.exists();
+	if record && path.exists() {
+		std::fs::remove_file(path).map_err(|error| {
+			reinhardt_utils::http_client::HttpClientError::Cassette {
+				path: path.to_path_buf(),
+				message: error.to_string(),
+			}
+		})?;
+	}
+	let cassette = Arc::new(Cassette::open(path)?);
+	let builder = HttpClient::builder();
+	if record {
+		builder.record(cassette).build()
+	} else {
+		builder.replay(cassette).build()
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
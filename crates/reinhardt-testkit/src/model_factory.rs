@@ -0,0 +1,206 @@
+//! Model factories for generating test data
+//!
+//! A model factory builds model instances filled with sensible fake data and
+//! can persist them through the ORM. Derive one with
+//! [`#[derive(Factory)]`](reinhardt_testkit_macros::Factory) and override only
+//! the fields a test cares about:
+//!
+//! ```rust,ignore
+//! use reinhardt_testkit::model_factory::{Factory, ModelFactory};
+//!
+//! #[model(app_label = "blog", table_name = "authors")]
+//! #[derive(Factory)]
+//! pub struct Author {
+//!     #[field(primary_key = true)]
+//!     pub id: Option<i64>,
+//!     #[factory(faker = "name")]
+//!     pub name: String,
+//!     #[factory(sequence = "author{n}@example.com")]
+//!     pub email: String,
+//! }
+//!
+//! #[model(app_label = "blog", table_name = "posts")]
+//! #[derive(Factory)]
+//! pub struct Post {
+//!     #[field(primary_key = true)]
+//!     pub id: Option<i64>,
+//!     #[factory(faker = "title")]
+//!     pub title: String,
+//!     #[factory(subfactory = AuthorFactory)]
+//!     pub author_id: i64,
+//!     #[factory(default = true)]
+//!     pub published: bool,
+//! }
+//!
+//! let post = PostFactory::new().title("Hello").create().await?;
+//! let drafts = PostFactory::new().published(false).create_batch(5).await?;
+//! ```
+//!
+//! # Field attributes
+//!
+//! | Attribute | Generated value |
+//! |-----------|-----------------|
+//! | `#[factory(faker = "email")]` | A generator from [`fake`] |
+//! | `#[factory(sequence = "user{n}")]` | The format string with `{n}` replaced by the next sequence number |
+//! | `#[factory(sequence)]` | The next sequence number |
+//! | `#[factory(default = expr)]` | `expr` |
+//! | `#[factory(subfactory = OtherFactory)]` | On `create`, the primary key of a related instance created by `OtherFactory`; `build` leaves the default |
+//! | `#[factory(skip)]` | `Default::default()` |
+//!
+//! Fields without an attribute are inferred from their name and type: string
+//! fields named like `email`, `username`, `first_name`, `last_name`, `name`,
+//! `title`, `slug` or `url` get matching fake values, other strings get a
+//! random word, timestamps get a recent date, and everything else — including
+//! the primary key — uses `Default::default()`.
+
+pub mod fake;
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use async_trait::async_trait;
+use reinhardt_core::exception::Error;
+use reinhardt_db::orm::{Manager, Model};
+
+pub use reinhardt_testkit_macros::Factory;
+
+#[doc(hidden)]
+pub use async_trait::async_trait as __async_trait;
+
+/// Monotonic counter used for `#[factory(sequence)]` fields
+///
+/// Every factory type owns one sequence, shared by all of its instances and
+/// starting at 1.
+#[derive(Debug)]
+pub struct Sequence(AtomicU64);
+
+impl Sequence {
+	/// Create a sequence starting at 1
+	pub const fn new() -> Self {
+		Self(AtomicU64::new(1))
+	}
+
+	/// Return the next number
+	pub fn next(&self) -> u64 {
+		self.0.fetch_add(1, Ordering::Relaxed)
+	}
+
+	/// Restart the sequence at 1
+	pub fn reset(&self) {
+		self.0.store(1, Ordering::Relaxed);
+	}
+}
+
+impl Default for Sequence {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Result type returned by factory operations that touch the database
+pub type FactoryResult<T> = reinhardt_core::exception::Result<T>;
+
+/// Substitute `{n}` in a sequence format string
+#[doc(hidden)]
+pub fn format_sequence(template: &str, n: u64) -> String {
+	template.replace("{n}", &n.to_string())
+}
+
+/// Primary key of a persisted related instance
+#[doc(hidden)]
+pub fn primary_key_of<M: Model>(instance: &M) -> FactoryResult<M::PrimaryKey> {
+	instance.primary_key().ok_or_else(|| {
+		Error::Database(format!(
+			"sub-factory instance of `{}` has no primary key after create",
+			M::table_name()
+		))
+	})
+}
+
+/// A factory producing instances of a model
+///
+/// Implemented by `#[derive(Factory)]`. [`build`](Self::build) creates
+/// unsaved instances; [`create`](Self::create) persists them through the
+/// model's [`Manager`], creating related instances first for sub-factory
+/// fields.
+#[async_trait]
+pub trait ModelFactory: Send + Sync {
+	/// The model type produced by this factory
+	type Model: Model + Clone + Send + Sync;
+
+	/// The sequence shared by all instances of this factory
+	fn sequence() -> &'static Sequence
+	where
+		Self: Sized;
+
+	/// Build an unsaved instance
+	fn build(&self) -> Self::Model;
+
+	/// Build `count` unsaved instances
+	fn build_batch(&self, count: usize) -> Vec<Self::Model> {
+		(0..count).map(|_| self.build()).collect()
+	}
+
+	/// Create related instances required by this model and return an unsaved
+	/// instance referencing them
+	///
+	/// The default implementation has no relations and delegates to
+	/// [`build`](Self::build).
+	async fn build_with_relations(&self) -> FactoryResult<Self::Model> {
+		Ok(self.build())
+	}
+
+	/// Create and persist an instance
+	async fn create(&self) -> FactoryResult<Self::Model> {
+		let instance = self.build_with_relations().await?;
+		Manager::<Self::Model>::new().create(&instance).await
+	}
+
+	/// Create and persist `count` instances
+	async fn create_batch(&self, count: usize) -> FactoryResult<Vec<Self::Model>> {
+		let mut created = Vec::with_capacity(count);
+		for _ in 0..count {
+			created.push(self.create().await?);
+		}
+		Ok(created)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_sequence_increments_from_one() {
+		// Arrange
+		let sequence = Sequence::new();
+
+		// Act
+		let values: Vec<u64> = (0..3).map(|_| sequence.next()).collect();
+
+		// Assert
+		assert_eq!(values, vec![1, 2, 3]);
+	}
+
+	#[rstest]
+	fn test_sequence_reset() {
+		// Arrange
+		let sequence = Sequence::new();
+		sequence.next();
+		sequence.next();
+
+		// Act
+		sequence.reset();
+
+		// Assert
+		assert_eq!(sequence.next(), 1);
+	}
+
+	#[rstest]
+	#[case("user{n}@example.com", 7, "user7@example.com")]
+	#[case("no placeholder", 7, "no placeholder")]
+	fn test_format_sequence(#[case] template: &str, #[case] n: u64, #[case] expected: &str) {
+		// Arrange / Act / Assert
+		assert_eq!(format_sequence(template, n), expected);
+	}
+}
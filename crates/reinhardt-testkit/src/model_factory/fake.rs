@@ -0,0 +1,164 @@
+//! Fake data generators used by model factories.
+//!
+//! Each generator returns a fresh random value. Generators that produce
+//! identifiers (`email`, `username`, `slug`) append a random suffix so that
+//! values stay unique across a test run.
+
+use chrono::{DateTime, Duration, Utc};
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+const FIRST_NAMES: &[&str] = &[
+	"Alice", "Bruno", "Chiara", "Daniel", "Emma", "Farid", "Grace", "Hiro", "Ingrid", "Jonas",
+	"Keiko", "Liam", "Maya", "Noah", "Olivia", "Pablo", "Quinn", "Rosa", "Sven", "Yuki",
+];
+
+const LAST_NAMES: &[&str] = &[
+	"Anderson", "Becker", "Costa", "Dubois", "Evans", "Fischer", "Garcia", "Hansen", "Ito",
+	"Jensen", "Kowalski", "Larsen", "Martin", "Nakamura", "Okafor", "Peters", "Rossi", "Schmidt",
+	"Tanaka", "Weber",
+];
+
+const WORDS: &[&str] = &[
+	"alpha", "bright", "cedar", "delta", "ember", "forest", "granite", "harbor", "island", "jade",
+	"kernel", "lumen", "meadow", "nova", "orbit", "prism", "quartz", "river", "summit", "timber",
+];
+
+const DOMAINS: &[&str] = &["example.com", "example.org", "example.net"];
+
+fn pick(values: &[&'static str]) -> &'static str {
+	values.choose(&mut rand::rng()).copied().unwrap_or_default()
+}
+
+fn suffix() -> u32 {
+	rand::rng().random_range(1000..1_000_000)
+}
+
+/// A random first name
+pub fn first_name() -> String {
+	pick(FIRST_NAMES).to_string()
+}
+
+/// A random last name
+pub fn last_name() -> String {
+	pick(LAST_NAMES).to_string()
+}
+
+/// A random full name
+pub fn name() -> String {
+	format!("{} {}", pick(FIRST_NAMES), pick(LAST_NAMES))
+}
+
+/// A unique lowercase username
+pub fn username() -> String {
+	format!("{}{}", pick(FIRST_NAMES).to_lowercase(), suffix())
+}
+
+/// A unique email address on a reserved example domain
+pub fn email() -> String {
+	format!("{}@{}", username(), pick(DOMAINS))
+}
+
+/// A random lowercase word
+pub fn word() -> String {
+	pick(WORDS).to_string()
+}
+
+/// A capitalized sentence of 4-9 words ending in a period
+pub fn sentence() -> String {
+	let count = rand::rng().random_range(4..10);
+	let words: Vec<&str> = (0..count).map(|_| pick(WORDS)).collect();
+	let mut sentence = words.join(" ");
+	if let Some(first) = sentence.get_mut(0..1) {
+		first.make_ascii_uppercase();
+	}
+	sentence.push('.');
+	sentence
+}
+
+/// A paragraph of 3-5 sentences
+pub fn paragraph() -> String {
+	let count = rand::rng().random_range(3..6);
+	(0..count).map(|_| sentence()).collect::<Vec<_>>().join(" ")
+}
+
+/// A short title of 2-4 capitalized words
+pub fn title() -> String {
+	let count = rand::rng().random_range(2..5);
+	(0..count)
+		.map(|_| {
+			let word = pick(WORDS);
+			format!("{}{}", word[..1].to_ascii_uppercase(), &word[1..])
+		})
+		.collect::<Vec<_>>()
+		.join(" ")
+}
+
+/// A unique URL slug
+pub fn slug() -> String {
+	format!("{}-{}-{}", pick(WORDS), pick(WORDS), suffix())
+}
+
+/// A URL on a reserved example domain
+pub fn url() -> String {
+	format!("https://{}/{}", pick(DOMAINS), slug())
+}
+
+/// A random integer in `0..1000`
+pub fn int() -> i64 {
+	rand::rng().random_range(0..1000)
+}
+
+/// A random boolean
+pub fn boolean() -> bool {
+	rand::rng().random_bool(0.5)
+}
+
+/// A timestamp within the past 30 days
+pub fn datetime() -> DateTime<Utc> {
+	let seconds = rand::rng().random_range(0..30 * 24 * 60 * 60);
+	Utc::now() - Duration::seconds(seconds)
+}
+
+/// A timestamp within the next 30 days
+pub fn future_datetime() -> DateTime<Utc> {
+	let seconds = rand::rng().random_range(1..30 * 24 * 60 * 60);
+	Utc::now() + Duration::seconds(seconds)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_email_is_well_formed() {
+		// Arrange / Act
+		let email = email();
+
+		// Assert
+		let (local, domain) = email.split_once('@').unwrap();
+		assert!(!local.is_empty());
+		assert!(DOMAINS.contains(&domain));
+	}
+
+	#[rstest]
+	fn test_sentence_is_capitalized_and_terminated() {
+		// Arrange / Act
+		let sentence = sentence();
+
+		// Assert
+		assert!(sentence.chars().next().unwrap().is_ascii_uppercase());
+		assert!(sentence.ends_with('.'));
+	}
+
+	#[rstest]
+	fn test_datetime_is_in_the_past() {
+		// Arrange / Act
+		let value = datetime();
+
+		// Assert
+		assert!(value <= Utc::now());
+		assert!(future_datetime() > Utc::now());
+	}
+}
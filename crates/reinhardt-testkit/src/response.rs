@@ -178,6 +178,33 @@ pub trait ResponseExt {
 	fn assert_forbidden(&self) -> &Self;
 	/// Assert that the response status is 404 Not Found.
 	fn assert_not_found(&self) -> &Self;
+
+	/// Assert that the JSON body contains `expected`
+	///
+	/// Objects match when every key of `expected` is present with a matching
+	/// value; extra keys in the body are ignored. Arrays match element-wise
+	/// against the leading elements of the body's array. Scalars must be equal.
+	fn assert_json_contains(&self, expected: &Value) -> &Self;
+}
+
+/// Whether `actual` contains `expected` as described by
+/// [`ResponseExt::assert_json_contains`]
+pub fn json_contains(actual: &Value, expected: &Value) -> bool {
+	match (actual, expected) {
+		(Value::Object(actual), Value::Object(expected)) => expected.iter().all(|(key, value)| {
+			actual
+				.get(key)
+				.is_some_and(|actual| json_contains(actual, value))
+		}),
+		(Value::Array(actual), Value::Array(expected)) => {
+			expected.len() <= actual.len()
+				&& actual
+					.iter()
+					.zip(expected)
+					.all(|(actual, expected)| json_contains(actual, expected))
+		}
+		_ => actual == expected,
+	}
 }
 
 impl ResponseExt for TestResponse {
@@ -250,6 +277,23 @@ impl ResponseExt for TestResponse {
 	fn assert_not_found(&self) -> &Self {
 		self.assert_status(StatusCode::NOT_FOUND)
 	}
+
+	fn assert_json_contains(&self, expected: &Value) -> &Self {
+		let actual = self.json_value().unwrap_or_else(|e| {
+			panic!(
+				"Expected a JSON body, got parse error: {}. Body: {}",
+				e,
+				self.text()
+			)
+		});
+		assert!(
+			json_contains(&actual, expected),
+			"Expected JSON body to contain {}, got {}",
+			expected,
+			actual
+		);
+		self
+	}
 }
 
 #[cfg(test)]
@@ -671,4 +715,32 @@ mod tests {
 		// Act (should panic)
 		resp.assert_status(StatusCode::NOT_FOUND);
 	}
+	#[rstest]
+	#[case::subset_object(r#"{"id": 1, "name": "a"}"#, r#"{"name": "a"}"#, true)]
+	#[case::nested(
+		r#"{"user": {"id": 1, "tags": ["x", "y"]}}"#,
+		r#"{"user": {"tags": ["x"]}}"#,
+		true
+	)]
+	#[case::value_mismatch(r#"{"name": "a"}"#, r#"{"name": "b"}"#, false)]
+	#[case::missing_key(r#"{"name": "a"}"#, r#"{"id": 1}"#, false)]
+	#[case::longer_array(r#"[1]"#, r#"[1, 2]"#, false)]
+	fn test_json_contains(#[case] actual: &str, #[case] expected: &str, #[case] contains: bool) {
+		// Arrange
+		let actual: Value = serde_json::from_str(actual).unwrap();
+		let expected: Value = serde_json::from_str(expected).unwrap();
+
+		// Act / Assert
+		assert_eq!(json_contains(&actual, &expected), contains);
+	}
+
+	#[rstest]
+	#[should_panic(expected = "Expected JSON body to contain")]
+	fn test_assert_json_contains_mismatch() {
+		// Arrange
+		let resp = make_response(200, br#"{"name": "a"}"#);
+
+		// Act (should panic)
+		resp.assert_json_contains(&serde_json::json!({"name": "b"}));
+	}
 }
@@ -0,0 +1,612 @@
+//! In-process test client
+//!
+//! [`TestClient`] dispatches requests straight into a [`Handler`] — typically
+//! the application's router wrapped in its full middleware chain — without
+//! opening a socket. On top of [`APIClient`]'s in-process dispatch it behaves
+//! like a browser session:
+//!
+//! - `Set-Cookie` headers from responses are stored and sent back on
+//!   subsequent requests; expired cookies are dropped.
+//! - The `csrftoken` cookie is echoed as `X-CSRFToken` on unsafe methods.
+//! - [`TestClient::force_login`] creates a real session in the configured
+//!   session backend and installs the `sessionid` cookie.
+//! - [`TestClient::override_with`] replaces an injected service with a fake
+//!   for the requests sent while its guard is alive.
+//!
+//! ```rust,ignore
+//! use reinhardt_testkit::test_client::{MultipartForm, TestClient};
+//! use reinhardt_testkit::ResponseExt;
+//!
+//! let client = TestClient::new(app).with_session_backend(sessions);
+//! client.force_login(&user).await?;
+//!
+//! client.get("/profile/").await?.assert_ok();
+//! client
+//!     .post_json("/api/posts/", &json!({"title": "Hello"}))
+//!     .await?
+//!     .assert_created()
+//!     .assert_json_contains(&json!({"title": "Hello"}));
+//!
+//! let form = MultipartForm::new().file("avatar", "me.png", "image/png", png_bytes);
+//! client.post_multipart("/profile/avatar/", &form).await?.assert_ok();
+//! ```
+
+mod multipart;
+
+pub use multipart::MultipartForm;
+
+use std::any::Any;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use cookie::Cookie;
+use cookie::time::{Duration as CookieDuration, OffsetDateTime};
+use http::Method;
+use reinhardt_di::{InjectionContext, ScopedOverride};
+use reinhardt_http::Handler;
+use reinhardt_middleware::request_recorder::RecordedRequest;
+use reinhardt_middleware::session::AsyncSessionBackend;
+use serde::Serialize;
+
+use crate::auth::{ForceLoginUser, TestAuthError};
+use crate::client::{APIClient, ClientResult};
+use crate::response::TestResponse;
+
+/// Cookie holding the CSRF token
+const CSRF_COOKIE: &str = "csrftoken";
+/// Header the CSRF middleware reads the token from
+const CSRF_HEADER: &str = "X-CSRFToken";
+/// Cookie holding the session id
+const SESSION_COOKIE: &str = "sessionid";
+
+/// In-process client that persists cookies and handles CSRF and login
+///
+/// See the [module documentation](self) for an overview.
+pub struct TestClient {
+	client: APIClient,
+	session_backend: Option<Arc<dyn AsyncSessionBackend>>,
+	send_csrf_token: bool,
+}
+
+impl TestClient {
+	/// Create a client dispatching to `handler`
+	pub fn new(handler: impl Handler + 'static) -> Self {
+		Self::from_client(APIClient::from_handler(handler))
+	}
+
+	/// Wrap an existing in-process [`APIClient`]
+	///
+	/// Use this when the client needs builder options such as a DI context.
+	pub fn from_client(client: APIClient) -> Self {
+		Self {
+			client,
+			session_backend: None,
+			send_csrf_token: true,
+		}
+	}
+
+	/// Use `backend` to store sessions created by [`force_login`](Self::force_login)
+	///
+	/// This must be the same backend the application's session middleware reads.
+	pub fn with_session_backend(mut self, backend: Arc<dyn AsyncSessionBackend>) -> Self {
+		self.session_backend = Some(backend);
+		self
+	}
+
+	/// Stop echoing the CSRF cookie as a header
+	///
+	/// Use this to test that unsafe requests without a token are rejected.
+	pub fn without_csrf_token(mut self) -> Self {
+		self.send_csrf_token = false;
+		self
+	}
+
+	/// The underlying [`APIClient`]
+	pub fn client(&self) -> &APIClient {
+		&self.client
+	}
+
+	/// Log `user` in by creating a session and setting the `sessionid` cookie
+	///
+	/// # Errors
+	///
+	/// Returns [`TestAuthError::NoSessionBackend`] when no backend was
+	/// configured with [`with_session_backend`](Self::with_session_backend).
+	pub async fn force_login(&self, user: &impl ForceLoginUser) -> Result<(), TestAuthError> {
+		let backend = self
+			.session_backend
+			.clone()
+			.ok_or(TestAuthError::NoSessionBackend)?;
+		self.client.auth().session(user, backend).apply().await
+	}
+
+	/// Log out, destroying the current session when a backend is configured
+	pub async fn logout(&self) -> ClientResult<()> {
+		if let (Some(backend), Some(session_id)) = (
+			&self.session_backend,
+			self.client.cookie(SESSION_COOKIE).await,
+		) {
+			// The session may already be gone; logging out is still complete.
+			let _ = backend.destroy(&session_id).await;
+		}
+		self.client.logout().await
+	}
+
+	/// Serve `value` for every resolution of `T` while the returned guard is alive
+	///
+	/// The override is installed on the client's DI context, so requests sent
+	/// through this client receive the fake (database, cache, email backend,
+	/// ...) instead of the registered service. Call
+	/// [`ScopedOverride::assert_consumed`] or
+	/// [`assert_overrides_consumed`](Self::assert_overrides_consumed) to check
+	/// that the code under test actually used it.
+	///
+	/// # Panics
+	///
+	/// Panics if the underlying [`APIClient`] was built without a DI context.
+	#[track_caller]
+	pub fn override_with<T: Any + Send + Sync + 'static>(&self, value: T) -> ScopedOverride {
+		self.injection_context().override_with(value)
+	}
+
+	/// Assert that every override installed on the client's DI context was resolved
+	///
+	/// # Panics
+	///
+	/// Panics if an override was never resolved, or if the underlying
+	/// [`APIClient`] was built without a DI context.
+	#[track_caller]
+	pub fn assert_overrides_consumed(&self) {
+		self.injection_context().assert_overrides_consumed();
+	}
+
+	#[track_caller]
+	fn injection_context(&self) -> &InjectionContext {
+		self.client
+			.di_context()
+			.expect("TestClient overrides require an APIClient built with a DI context")
+	}
+
+	/// Current value of cookie `name`
+	pub async fn cookie(&self, name: &str) -> Option<String> {
+		self.client.cookie(name).await
+	}
+
+	/// Current CSRF token, if the application has issued one
+	pub async fn csrf_token(&self) -> Option<String> {
+		self.cookie(CSRF_COOKIE).await
+	}
+
+	/// Send a GET request
+	pub async fn get(&self, path: &str) -> ClientResult<TestResponse> {
+		self.request(Method::GET, path, None, None).await
+	}
+
+	/// Send a HEAD request
+	pub async fn head(&self, path: &str) -> ClientResult<TestResponse> {
+		self.request(Method::HEAD, path, None, None).await
+	}
+
+	/// Send an OPTIONS request
+	pub async fn options(&self, path: &str) -> ClientResult<TestResponse> {
+		self.request(Method::OPTIONS, path, None, None).await
+	}
+
+	/// Send a DELETE request
+	pub async fn delete(&self, path: &str) -> ClientResult<TestResponse> {
+		self.request(Method::DELETE, path, None, None).await
+	}
+
+	/// Send a POST request with a JSON body
+	pub async fn post_json<T: Serialize>(
+		&self,
+		path: &str,
+		data: &T,
+	) -> ClientResult<TestResponse> {
+		self.json_request(Method::POST, path, data).await
+	}
+
+	/// Send a PUT request with a JSON body
+	pub async fn put_json<T: Serialize>(&self, path: &str, data: &T) -> ClientResult<TestResponse> {
+		self.json_request(Method::PUT, path, data).await
+	}
+
+	/// Send a PATCH request with a JSON body
+	pub async fn patch_json<T: Serialize>(
+		&self,
+		path: &str,
+		data: &T,
+	) -> ClientResult<TestResponse> {
+		self.json_request(Method::PATCH, path, data).await
+	}
+
+	/// Send a POST request with a URL-encoded form body
+	pub async fn post_form<T: Serialize>(
+		&self,
+		path: &str,
+		data: &T,
+	) -> ClientResult<TestResponse> {
+		let body = serde_urlencoded::to_string(data)
+			.map_err(|e| crate::client::ClientError::RequestFailed(e.to_string()))?;
+		self.request(
+			Method::POST,
+			path,
+			Some(Bytes::from(body)),
+			Some("application/x-www-form-urlencoded"),
+		)
+		.await
+	}
+
+	/// Send a POST request with a `multipart/form-data` body
+	pub async fn post_multipart(
+		&self,
+		path: &str,
+		form: &MultipartForm,
+	) -> ClientResult<TestResponse> {
+		self.request(
+			Method::POST,
+			path,
+			Some(form.to_bytes()),
+			Some(&form.content_type()),
+		)
+		.await
+	}
+
+	/// Send a request with an arbitrary method and body
+	pub async fn request(
+		&self,
+		method: Method,
+		path: &str,
+		body: Option<Bytes>,
+		content_type: Option<&str>,
+	) -> ClientResult<TestResponse> {
+		self.send(method, path, body, content_type, &[]).await
+	}
+
+	/// Replay a request captured by the request recorder middleware
+	///
+	/// The recorded method, path, non-redacted headers and body are sent
+	/// with this client's cookies and CSRF token, so a request seen in
+	/// development can be reproduced in a test:
+	///
+	/// ```rust,ignore
+	/// let exchange = recorder.store().get(id).unwrap();
+	/// client.replay(&exchange.request).await?.assert_ok();
+	/// ```
+	pub async fn replay(&self, recorded: &RecordedRequest) -> ClientResult<TestResponse> {
+		let method = Method::from_bytes(recorded.method.as_bytes())
+			.map_err(|e| crate::client::ClientError::RequestFailed(e.to_string()))?;
+		let body = (!recorded.body.is_empty()).then(|| recorded.body.to_bytes());
+		self.send(
+			method,
+			&recorded.uri,
+			body,
+			recorded.header("content-type"),
+			&recorded.replay_headers(),
+		)
+		.await
+	}
+
+	async fn send(
+		&self,
+		method: Method,
+		path: &str,
+		body: Option<Bytes>,
+		content_type: Option<&str>,
+		headers: &[(&str, &str)],
+	) -> ClientResult<TestResponse> {
+		let csrf_token = if self.send_csrf_token && !is_safe_method(&method) {
+			self.csrf_token().await
+		} else {
+			None
+		};
+		let extra_headers: Vec<(&str, &str)> = headers
+			.iter()
+			.copied()
+			.chain(csrf_token.as_deref().map(|token| (CSRF_HEADER, token)))
+			.collect();
+
+		let response = self
+			.client
+			.request_with_extra_headers(method, path, body, content_type, &extra_headers)
+			.await?;
+		self.store_cookies(&response).await?;
+		Ok(response)
+	}
+
+	async fn json_request<T: Serialize>(
+		&self,
+		method: Method,
+		path: &str,
+		data: &T,
+	) -> ClientResult<TestResponse> {
+		let body = serde_json::to_vec(data)?;
+		self.request(
+			method,
+			path,
+			Some(Bytes::from(body)),
+			Some("application/json"),
+		)
+		.await
+	}
+
+	/// Apply the response's `Set-Cookie` headers to the cookie jar
+	async fn store_cookies(&self, response: &TestResponse) -> ClientResult<()> {
+		for value in response.headers().get_all(http::header::SET_COOKIE) {
+			let Some(cookie) = value
+				.to_str()
+				.ok()
+				.and_then(|raw| Cookie::parse(raw.to_string()).ok())
+			else {
+				continue;
+			};
+			if is_removal(&cookie) {
+				self.client.remove_cookie(cookie.name()).await?;
+			} else {
+				self.client
+					.set_cookie(cookie.name(), cookie.value())
+					.await?;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Methods the CSRF middleware does not check
+fn is_safe_method(method: &Method) -> bool {
+	matches!(
+		*method,
+		Method::GET | Method::HEAD | Method::OPTIONS | Method::TRACE
+	)
+}
+
+/// Whether a `Set-Cookie` instructs the client to delete the cookie
+fn is_removal(cookie: &Cookie<'_>) -> bool {
+	cookie
+		.max_age()
+		.is_some_and(|age| age <= CookieDuration::ZERO)
+		|| cookie
+			.expires_datetime()
+			.is_some_and(|expires| expires <= OffsetDateTime::now_utc())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::response::ResponseExt;
+	use async_trait::async_trait;
+	use reinhardt_http::{Request, Response, Result};
+	use rstest::rstest;
+	use serde_json::json;
+
+	/// Issues a CSRF token on GET, rejects unsafe requests whose header does not
+	/// match it, and echoes the request's cookies and body otherwise.
+	struct CsrfApp;
+
+	#[async_trait]
+	impl Handler for CsrfApp {
+		async fn handle(&self, request: Request) -> Result<Response> {
+			let cookies = request
+				.headers
+				.get("cookie")
+				.and_then(|v| v.to_str().ok())
+				.unwrap_or("")
+				.to_string();
+			if request.method == Method::GET {
+				return Ok(Response::ok()
+					.append_header("Set-Cookie", "csrftoken=tok123; Path=/")
+					.append_header("Set-Cookie", "theme=dark; Path=/")
+					.with_body(cookies));
+			}
+			if request.uri.path() == "/logout/" {
+				return Ok(Response::ok().with_header("Set-Cookie", "theme=; Max-Age=0; Path=/"));
+			}
+			let token = request
+				.headers
+				.get(CSRF_HEADER)
+				.and_then(|v| v.to_str().ok());
+			if token != Some("tok123") {
+				return Ok(Response::new(http::StatusCode::FORBIDDEN));
+			}
+			Ok(Response::ok()
+				.with_header(
+					"X-Content-Type",
+					request
+						.headers
+						.get("content-type")
+						.and_then(|v| v.to_str().ok())
+						.unwrap_or(""),
+				)
+				.with_body(request.body().clone()))
+		}
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_persists_cookies_across_requests() {
+		// Arrange
+		let client = TestClient::new(CsrfApp);
+		client.get("/").await.unwrap();
+
+		// Act
+		let response = client.get("/").await.unwrap();
+
+		// Assert
+		let sent = response.text();
+		assert!(sent.contains("csrftoken=tok123"));
+		assert!(sent.contains("theme=dark"));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_expired_cookie_is_removed() {
+		// Arrange
+		let client = TestClient::new(CsrfApp);
+		client.get("/").await.unwrap();
+
+		// Act
+		client.post_json("/logout/", &json!({})).await.unwrap();
+
+		// Assert
+		assert_eq!(client.cookie("theme").await, None);
+		assert_eq!(client.csrf_token().await.as_deref(), Some("tok123"));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_sends_csrf_token_on_unsafe_methods() {
+		// Arrange
+		let client = TestClient::new(CsrfApp);
+		client.get("/form/").await.unwrap();
+
+		// Act
+		let response = client
+			.post_json("/api/items/", &json!({"name": "widget", "qty": 2}))
+			.await
+			.unwrap();
+
+		// Assert
+		response
+			.assert_ok()
+			.assert_json_contains(&json!({"name": "widget"}));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_without_csrf_token_is_rejected() {
+		// Arrange
+		let client = TestClient::new(CsrfApp).without_csrf_token();
+		client.get("/form/").await.unwrap();
+
+		// Act
+		let response = client.post_json("/api/items/", &json!({})).await.unwrap();
+
+		// Assert
+		response.assert_forbidden();
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_post_multipart_sets_content_type() {
+		// Arrange
+		let client = TestClient::new(CsrfApp);
+		client.get("/").await.unwrap();
+		let form =
+			MultipartForm::with_boundary("BOUNDARY").file("upload", "a.txt", "text/plain", "hi");
+
+		// Act
+		let response = client.post_multipart("/upload/", &form).await.unwrap();
+
+		// Assert
+		response.assert_ok();
+		assert_eq!(
+			response.header("X-Content-Type"),
+			Some("multipart/form-data; boundary=BOUNDARY")
+		);
+		assert_eq!(response.body(), &form.to_bytes());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_replay_sends_recorded_request_with_csrf_token() {
+		// Arrange
+		let client = TestClient::new(CsrfApp);
+		client.get("/").await.unwrap();
+		let recorded = RecordedRequest {
+			method: "POST".to_string(),
+			uri: "/api/items/".to_string(),
+			version: "HTTP/1.1".to_string(),
+			headers: vec![
+				("content-type".to_string(), "application/json".to_string()),
+				(
+					"authorization".to_string(),
+					reinhardt_middleware::REDACTED.to_string(),
+				),
+			],
+			body: reinhardt_middleware::RecordedBody {
+				text: r#"{"title":"Hello"}"#.to_string(),
+				base64: false,
+				truncated: false,
+				size: 17,
+			},
+		};
+
+		// Act
+		let response = client.replay(&recorded).await.unwrap();
+
+		// Assert
+		response.assert_ok();
+		assert_eq!(response.header("X-Content-Type"), Some("application/json"));
+		assert_eq!(response.text(), r#"{"title":"Hello"}"#);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_force_login_requires_session_backend() {
+		// Arrange
+		struct User;
+		impl ForceLoginUser for User {
+			fn session_user_id(&self) -> String {
+				"1".to_string()
+			}
+		}
+		let client = TestClient::new(CsrfApp);
+
+		// Act
+		let result = client.force_login(&User).await;
+
+		// Assert
+		assert!(matches!(result, Err(TestAuthError::NoSessionBackend)));
+	}
+
+	#[derive(Debug)]
+	struct Mailer(&'static str);
+
+	/// Resolves [`Mailer`] from the request's DI context and echoes its name.
+	struct MailerApp;
+
+	#[async_trait]
+	impl Handler for MailerApp {
+		async fn handle(&self, request: Request) -> Result<Response> {
+			let ctx = request
+				.get_di_context::<InjectionContext>()
+				.expect("DI context is set");
+			let mailer = ctx.fork().resolve::<Mailer>().await?;
+			Ok(Response::ok().with_body(mailer.0))
+		}
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_override_with_serves_fake_to_requests() {
+		// Arrange
+		let ctx = Arc::new(InjectionContext::builder(reinhardt_di::SingletonScope::new()).build());
+		let client = TestClient::from_client(
+			APIClient::builder()
+				.handler(MailerApp)
+				.di_context(ctx)
+				.build(),
+		);
+		let mailer = client.override_with(Mailer("fake"));
+
+		// Act
+		let response = client.get("/").await.unwrap();
+
+		// Assert
+		assert_eq!(response.text(), "fake");
+		mailer.assert_consumed();
+		client.assert_overrides_consumed();
+	}
+
+	#[rstest]
+	#[case::max_age_zero("a=; Max-Age=0", true)]
+	#[case::expired("a=b; Expires=Thu, 01 Jan 1970 00:00:00 GMT", true)]
+	#[case::session("a=b; Path=/", false)]
+	fn test_is_removal(#[case] raw: &str, #[case] expected: bool) {
+		// Arrange
+		let cookie = Cookie::parse(raw).unwrap();
+
+		// Act / Assert
+		assert_eq!(is_removal(&cookie), expected);
+	}
+}
@@ -0,0 +1,188 @@
+//! `multipart/form-data` body builder for upload tests.
+
+use bytes::{BufMut, Bytes, BytesMut};
+use uuid::Uuid;
+
+/// A single part of a multipart body
+#[derive(Debug, Clone)]
+enum Part {
+	Text {
+		name: String,
+		value: String,
+	},
+	File {
+		name: String,
+		filename: String,
+		content_type: String,
+		data: Bytes,
+	},
+}
+
+/// Builder for `multipart/form-data` request bodies
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_testkit::test_client::MultipartForm;
+///
+/// let form = MultipartForm::new()
+///     .text("title", "Quarterly report")
+///     .file("attachment", "report.csv", "text/csv", "a,b\n1,2\n");
+/// assert!(form.content_type().starts_with("multipart/form-data; boundary="));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultipartForm {
+	boundary: String,
+	parts: Vec<Part>,
+}
+
+impl MultipartForm {
+	/// Create an empty form with a random boundary
+	pub fn new() -> Self {
+		Self::with_boundary(format!("reinhardt-test-{}", Uuid::new_v4().simple()))
+	}
+
+	/// Create an empty form with a fixed boundary
+	pub fn with_boundary(boundary: impl Into<String>) -> Self {
+		Self {
+			boundary: boundary.into(),
+			parts: Vec::new(),
+		}
+	}
+
+	/// Add a text field
+	pub fn text(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+		self.parts.push(Part::Text {
+			name: name.into(),
+			value: value.into(),
+		});
+		self
+	}
+
+	/// Add a file field
+	pub fn file(
+		mut self,
+		name: impl Into<String>,
+		filename: impl Into<String>,
+		content_type: impl Into<String>,
+		data: impl Into<Bytes>,
+	) -> Self {
+		self.parts.push(Part::File {
+			name: name.into(),
+			filename: filename.into(),
+			content_type: content_type.into(),
+			data: data.into(),
+		});
+		self
+	}
+
+	/// The boundary separating parts
+	pub fn boundary(&self) -> &str {
+		&self.boundary
+	}
+
+	/// Value for the `Content-Type` request header
+	pub fn content_type(&self) -> String {
+		format!("multipart/form-data; boundary={}", self.boundary)
+	}
+
+	/// Encode the form into a request body
+	pub fn to_bytes(&self) -> Bytes {
+		let mut body = BytesMut::new();
+		for part in &self.parts {
+			body.put_slice(format!("--{}\r\n", self.boundary).as_bytes());
+			match part {
+				Part::Text { name, value } => {
+					body.put_slice(
+						format!(
+							"Content-Disposition: form-data; name=\"{}\"\r\n\r\n",
+							escape_quoted(name)
+						)
+						.as_bytes(),
+					);
+					body.put_slice(value.as_bytes());
+				}
+				Part::File {
+					name,
+					filename,
+					content_type,
+					data,
+				} => {
+					body.put_slice(
+						format!(
+							"Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n\
+							 Content-Type: {}\r\n\r\n",
+							escape_quoted(name),
+							escape_quoted(filename),
+							content_type
+						)
+						.as_bytes(),
+					);
+					body.put_slice(data);
+				}
+			}
+			body.put_slice(b"\r\n");
+		}
+		body.put_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+		body.freeze()
+	}
+}
+
+impl Default for MultipartForm {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Percent-encode characters that would terminate a quoted header parameter
+fn escape_quoted(value: &str) -> String {
+	value
+		.replace('"', "%22")
+		.replace('\r', "%0D")
+		.replace('\n', "%0A")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_encodes_text_and_file_parts() {
+		// Arrange
+		let form = MultipartForm::with_boundary("XYZ")
+			.text("title", "hello")
+			.file("upload", "a.txt", "text/plain", "content");
+
+		// Act
+		let body = form.to_bytes();
+
+		// Assert
+		let expected = "--XYZ\r\n\
+			Content-Disposition: form-data; name=\"title\"\r\n\r\n\
+			hello\r\n\
+			--XYZ\r\n\
+			Content-Disposition: form-data; name=\"upload\"; filename=\"a.txt\"\r\n\
+			Content-Type: text/plain\r\n\r\n\
+			content\r\n\
+			--XYZ--\r\n";
+		assert_eq!(std::str::from_utf8(&body).unwrap(), expected);
+		assert_eq!(form.content_type(), "multipart/form-data; boundary=XYZ");
+	}
+
+	#[rstest]
+	fn test_escapes_quotes_in_filenames() {
+		// Arrange
+		let form = MultipartForm::with_boundary("B").file("f", "a\"b.txt", "text/plain", "");
+
+		// Act
+		let body = form.to_bytes();
+
+		// Assert
+		assert!(
+			std::str::from_utf8(&body)
+				.unwrap()
+				.contains("filename=\"a%22b.txt\"")
+		);
+	}
+}
@@ -13,6 +13,8 @@
 //! - **Adaptive Throttling**: Dynamically adjusts rates based on system load
 //! - **Geo-based Limiting**: Different rates per geographic region
 //! - **Time-of-day Limiting**: Different rates for peak/off-peak hours
+//! - **Scoped Limiting**: Per-view scopes with anonymous/authenticated overrides,
+//!   declared with `#[throttle(scope = "burst", rate = "10/min")]`
 //!
 //! ## Backends
 //!
@@ -33,6 +35,8 @@ pub mod geo;
 pub mod key_validation;
 /// Leaky bucket rate limiting algorithm.
 pub mod leaky_bucket;
+/// Rate string parsing (e.g. `"10/min"`).
+pub mod rate;
 /// Scope-based throttle for per-view rate limits.
 pub mod scoped;
 /// Core throttle trait and error types.
@@ -54,7 +58,8 @@ pub use backend::{MemoryBackend, ThrottleBackend};
 pub use burst::BurstRateThrottle;
 pub use geo::{GeoRateConfig, GeoRateThrottle};
 pub use leaky_bucket::{LeakyBucketConfig, LeakyBucketThrottle};
-pub use scoped::ScopedRateThrottle;
+pub use rate::parse_rate;
+pub use scoped::{ScopedRateThrottle, UserClass};
 pub use throttle::{Throttle, ThrottleError, ThrottleResult};
 pub use tiered::{Tier, TieredRateThrottle};
 pub use time_of_day::{TimeOfDayConfig, TimeOfDayThrottle, TimeRange};
@@ -0,0 +1,80 @@
+use super::{ThrottleError, ThrottleResult};
+
+/// Parse a rate string such as `"10/min"` into `(max_requests, window_secs)`.
+///
+/// The period accepts `s`/`sec`/`second`, `m`/`min`/`minute`,
+/// `h`/`hour` and `d`/`day`, optionally pluralized.
+///
+/// # Errors
+///
+/// Returns [`ThrottleError::InvalidConfig`] if the string is not in
+/// `"<count>/<period>"` form or the period is unknown.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_throttling::parse_rate;
+///
+/// assert_eq!(parse_rate("10/min").unwrap(), (10, 60));
+/// assert_eq!(parse_rate("1000/day").unwrap(), (1000, 86_400));
+/// assert!(parse_rate("10 per minute").is_err());
+/// ```
+pub fn parse_rate(rate: &str) -> ThrottleResult<(usize, u64)> {
+	let invalid = || {
+		ThrottleError::InvalidConfig(format!(
+			"invalid rate '{}': expected '<count>/<sec|min|hour|day>'",
+			rate
+		))
+	};
+
+	let (count, period) = rate.split_once('/').ok_or_else(invalid)?;
+	let count = count.trim().parse::<usize>().map_err(|_| invalid())?;
+	let window = period_seconds(period.trim()).ok_or_else(invalid)?;
+	Ok((count, window))
+}
+
+fn period_seconds(period: &str) -> Option<u64> {
+	match period.to_ascii_lowercase().trim_end_matches('s') {
+		"" | "sec" | "second" => Some(1),
+		"m" | "min" | "minute" => Some(60),
+		"h" | "hour" => Some(3_600),
+		"d" | "day" => Some(86_400),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case("5/s", (5, 1))]
+	#[case("5/sec", (5, 1))]
+	#[case("10/min", (10, 60))]
+	#[case("10/minutes", (10, 60))]
+	#[case("100/hour", (100, 3_600))]
+	#[case("1000/day", (1000, 86_400))]
+	#[case(" 3 / MIN ", (3, 60))]
+	fn test_parse_rate_accepts_known_periods(#[case] input: &str, #[case] expected: (usize, u64)) {
+		// Act
+		let parsed = parse_rate(input);
+
+		// Assert
+		assert_eq!(parsed.unwrap(), expected);
+	}
+
+	#[rstest]
+	#[case("10")]
+	#[case("ten/min")]
+	#[case("10/week")]
+	#[case("-1/min")]
+	#[case("/min")]
+	fn test_parse_rate_rejects_malformed_input(#[case] input: &str) {
+		// Act
+		let result = parse_rate(input);
+
+		// Assert
+		assert!(matches!(result, Err(ThrottleError::InvalidConfig(_))));
+	}
+}
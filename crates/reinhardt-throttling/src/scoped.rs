@@ -1,9 +1,19 @@
 use super::backend::{MemoryBackend, ThrottleBackend};
 use super::key_validation::{validate_key_component, validate_scope_key};
+use super::rate::parse_rate;
 use super::{Throttle, ThrottleResult};
 use async_trait::async_trait;
 use std::collections::HashMap;
 
+/// Class of caller a scope rate override applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UserClass {
+	/// Unauthenticated caller, usually identified by IP address.
+	Anon,
+	/// Authenticated caller, usually identified by user ID.
+	Authenticated,
+}
+
 /// Scope-based rate throttle with per-scope rate limits.
 ///
 /// Different API scopes (e.g., "api", "upload") can have independent
@@ -11,6 +21,8 @@ use std::collections::HashMap;
 pub struct ScopedRateThrottle<B: ThrottleBackend = MemoryBackend> {
 	/// Mapping of scope names to their rate limit configuration (rate, window_secs).
 	pub scopes: HashMap<String, (usize, u64)>,
+	/// Per-user-class overrides of scope rate limits (rate, window_secs).
+	pub overrides: HashMap<(String, UserClass), (usize, u64)>,
 	backend: B,
 }
 
@@ -28,6 +40,7 @@ impl ScopedRateThrottle<MemoryBackend> {
 	pub fn new() -> Self {
 		Self {
 			scopes: HashMap::new(),
+			overrides: HashMap::new(),
 			backend: MemoryBackend::new(),
 		}
 	}
@@ -54,6 +67,7 @@ impl<B: ThrottleBackend> ScopedRateThrottle<B> {
 	pub fn with_backend(backend: B) -> Self {
 		Self {
 			scopes: HashMap::new(),
+			overrides: HashMap::new(),
 			backend,
 		}
 	}
@@ -90,14 +104,105 @@ impl<B: ThrottleBackend> ScopedRateThrottle<B> {
 		self.scopes.insert(scope, (rate, window));
 		Ok(self)
 	}
-}
 
-#[async_trait]
-impl<B: ThrottleBackend> Throttle for ScopedRateThrottle<B> {
-	async fn allow_request(&self, scope_key: &str) -> ThrottleResult<bool> {
+	/// Add a scope whose rate limit is given as a rate string like `"10/min"`.
+	///
+	/// # Errors
+	///
+	/// Returns [`crate::ThrottleError::InvalidKey`] if the scope name fails
+	/// validation, or [`crate::ThrottleError::InvalidConfig`] if the rate
+	/// cannot be parsed.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_throttling::ScopedRateThrottle;
+	///
+	/// let throttle = ScopedRateThrottle::new().add_scope_rate("burst", "10/min").unwrap();
+	/// assert_eq!(throttle.scopes.get("burst"), Some(&(10, 60)));
+	/// ```
+	pub fn add_scope_rate(self, scope: impl Into<String>, rate: &str) -> ThrottleResult<Self> {
+		let (rate, window) = parse_rate(rate)?;
+		self.add_scope(scope, rate, window)
+	}
+
+	/// Override a scope's rate limit for one class of caller.
+	///
+	/// The override takes effect for requests checked with
+	/// [`allow_request_as`](Self::allow_request_as); other callers keep the
+	/// scope's default rate.
+	///
+	/// # Errors
+	///
+	/// Returns [`crate::ThrottleError::InvalidKey`] if the scope name fails validation.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_throttling::{ScopedRateThrottle, UserClass};
+	///
+	/// let throttle = ScopedRateThrottle::new()
+	///     .add_scope("burst", 10, 60).unwrap()
+	///     .override_scope("burst", UserClass::Anon, 5, 60).unwrap();
+	/// assert_eq!(throttle.rate_for("burst", UserClass::Anon), Some((5, 60)));
+	/// assert_eq!(throttle.rate_for("burst", UserClass::Authenticated), Some((10, 60)));
+	/// ```
+	pub fn override_scope(
+		mut self,
+		scope: impl Into<String>,
+		class: UserClass,
+		rate: usize,
+		window: u64,
+	) -> ThrottleResult<Self> {
+		let scope = scope.into();
+		validate_key_component(&scope)?;
+		self.overrides.insert((scope, class), (rate, window));
+		Ok(self)
+	}
+
+	/// Returns the rate limit applied to `class` callers of `scope`.
+	///
+	/// Falls back to the scope's default rate when no override is
+	/// registered for the class.
+	pub fn rate_for(&self, scope: &str, class: UserClass) -> Option<(usize, u64)> {
+		self.overrides
+			.get(&(scope.to_string(), class))
+			.or_else(|| self.scopes.get(scope))
+			.copied()
+	}
+
+	/// Check a `"scope:identifier"` key on behalf of a caller of the given class.
+	///
+	/// Unlike [`Throttle::allow_request`], this honours the per-class
+	/// overrides registered with [`override_scope`](Self::override_scope).
+	pub async fn allow_request_as(
+		&self,
+		scope_key: &str,
+		class: UserClass,
+	) -> ThrottleResult<bool> {
+		let (scope, identifier) = validate_scope_key(scope_key)?;
+		self.check(scope, identifier, self.rate_for(scope, class))
+			.await
+	}
+
+	/// Seconds a caller of the given class must wait before retrying.
+	pub async fn wait_time_as(
+		&self,
+		scope_key: &str,
+		class: UserClass,
+	) -> ThrottleResult<Option<u64>> {
 		let (scope, identifier) = validate_scope_key(scope_key)?;
+		self.wait(scope, identifier, self.rate_for(scope, class))
+			.await
+	}
 
-		if let Some(&(rate, window)) = self.scopes.get(scope) {
+	async fn check(
+		&self,
+		scope: &str,
+		identifier: &str,
+		limit: Option<(usize, u64)>,
+	) -> ThrottleResult<bool> {
+		if let Some((rate, window)) = limit {
 			let key = format!("throttle:scope:{}:{}", scope, identifier);
 			let count = self
 				.backend
@@ -110,10 +215,13 @@ impl<B: ThrottleBackend> Throttle for ScopedRateThrottle<B> {
 		}
 	}
 
-	async fn wait_time(&self, scope_key: &str) -> ThrottleResult<Option<u64>> {
-		let (scope, identifier) = validate_scope_key(scope_key)?;
-
-		if let Some(&(rate, window)) = self.scopes.get(scope) {
+	async fn wait(
+		&self,
+		scope: &str,
+		identifier: &str,
+		limit: Option<(usize, u64)>,
+	) -> ThrottleResult<Option<u64>> {
+		if let Some((rate, window)) = limit {
 			let key = format!("throttle:scope:{}:{}", scope, identifier);
 			let count = self
 				.backend
@@ -129,6 +237,21 @@ impl<B: ThrottleBackend> Throttle for ScopedRateThrottle<B> {
 			Ok(None)
 		}
 	}
+}
+
+#[async_trait]
+impl<B: ThrottleBackend> Throttle for ScopedRateThrottle<B> {
+	async fn allow_request(&self, scope_key: &str) -> ThrottleResult<bool> {
+		let (scope, identifier) = validate_scope_key(scope_key)?;
+		self.check(scope, identifier, self.scopes.get(scope).copied())
+			.await
+	}
+
+	async fn wait_time(&self, scope_key: &str) -> ThrottleResult<Option<u64>> {
+		let (scope, identifier) = validate_scope_key(scope_key)?;
+		self.wait(scope, identifier, self.scopes.get(scope).copied())
+			.await
+	}
 
 	fn get_rate(&self) -> (usize, u64) {
 		(0, 0)
@@ -219,6 +342,66 @@ mod tests {
 		assert!(!throttle.allow_request("api:user1").await.unwrap());
 	}
 
+	#[rstest]
+	#[tokio::test]
+	async fn test_allow_request_as_applies_class_override() {
+		// Arrange
+		let throttle = ScopedRateThrottle::new()
+			.add_scope_rate("burst", "3/min")
+			.unwrap()
+			.override_scope("burst", UserClass::Anon, 1, 60)
+			.unwrap();
+
+		// Act & Assert - anonymous callers get the stricter override
+		assert!(
+			throttle
+				.allow_request_as("burst:10.0.0.1", UserClass::Anon)
+				.await
+				.unwrap()
+		);
+		assert!(
+			!throttle
+				.allow_request_as("burst:10.0.0.1", UserClass::Anon)
+				.await
+				.unwrap()
+		);
+		assert_eq!(
+			throttle
+				.wait_time_as("burst:10.0.0.1", UserClass::Anon)
+				.await
+				.unwrap(),
+			Some(60)
+		);
+
+		// Authenticated callers fall back to the scope default
+		for _ in 0..3 {
+			assert!(
+				throttle
+					.allow_request_as("burst:user1", UserClass::Authenticated)
+					.await
+					.unwrap()
+			);
+		}
+		assert!(
+			!throttle
+				.allow_request_as("burst:user1", UserClass::Authenticated)
+				.await
+				.unwrap()
+		);
+	}
+
+	#[rstest]
+	fn test_add_scope_rate_rejects_malformed_rate() {
+		// Arrange & Act
+		let result = ScopedRateThrottle::new().add_scope_rate("burst", "10 per minute");
+
+		// Assert
+		assert!(matches!(
+			result.err(),
+			Some(super::super::ThrottleError::InvalidConfig(_))
+		));
+	}
+
 	#[rstest]
 	#[tokio::test]
 	async fn test_unscoped_view_not_throttled() {
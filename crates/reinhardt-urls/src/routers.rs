@@ -134,6 +134,9 @@ pub mod client_router;
 /// Route matching result cache for repeated lookups.
 #[cfg(native)]
 pub mod cache;
+/// System checks for route names and URL namespaces.
+#[cfg(native)]
+pub mod checks;
 /// Path parameter type converters (integer, UUID, slug, date, etc.).
 #[cfg(native)]
 pub mod converters;
@@ -195,6 +198,9 @@ pub mod versioned;
 /// Route map visualization in multiple formats (tree, DOT, Markdown).
 #[cfg(native)]
 pub mod visualization;
+/// `robots.txt` and `security.txt` handlers driven by settings.
+#[cfg(native)]
+pub mod well_known;
 
 // Re-export the path! macro for compile-time path validation.
 #[cfg(all(native, feature = "routers-macros"))]
@@ -203,6 +209,8 @@ pub use reinhardt_routers_macros::path;
 #[cfg(native)]
 pub use cache::RouteCache;
 #[cfg(native)]
+pub use checks::UrlConfCheck;
+#[cfg(native)]
 pub use converters::{
 	Converter, ConverterError, ConverterResult, DateConverter, FloatConverter, IntegerConverter,
 	PathConverter, SlugConverter, UuidConverter,
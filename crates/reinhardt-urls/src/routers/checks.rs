@@ -0,0 +1,162 @@
+//! System checks (`urls.*`) for the URL configuration.
+//!
+//! [`UrlConfCheck`] reports route names registered more than once, which
+//! make one of the routes unreachable through reverse(), and instance
+//! namespaces shared by several mounts, which make reverse() ambiguous.
+//!
+//! # Examples
+//!
+//! ```
+//! use reinhardt_urls::routers::UrlConfCheck;
+//! use reinhardt_utils::utils_core::checks::CheckRegistry;
+//!
+//! CheckRegistry::global()
+//!     .lock()
+//!     .unwrap()
+//!     .register(Box::new(UrlConfCheck::global()));
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use reinhardt_utils::utils_core::checks::{Check, CheckMessage};
+
+use super::UrlReverser;
+
+/// System check for the route names and namespaces of a [`UrlReverser`]
+pub struct UrlConfCheck {
+	reverser: Option<Arc<UrlReverser>>,
+}
+
+impl UrlConfCheck {
+	/// Check `reverser`
+	pub fn new(reverser: Arc<UrlReverser>) -> Self {
+		Self {
+			reverser: Some(reverser),
+		}
+	}
+
+	/// Check the global reverser populated by
+	/// [`register_router()`](crate::routers::register_router)
+	///
+	/// The reverser is looked up when the check runs, so the check can be
+	/// registered before the router is.
+	pub fn global() -> Self {
+		Self { reverser: None }
+	}
+}
+
+impl Check for UrlConfCheck {
+	fn tags(&self) -> Vec<String> {
+		vec!["urls".to_string()]
+	}
+
+	fn check(&self) -> Vec<CheckMessage> {
+		let Some(reverser) = self.reverser.clone().or_else(UrlReverser::try_from_global) else {
+			return Vec::new();
+		};
+		let mut messages = Vec::new();
+
+		for conflict in reverser.conflicts() {
+			messages.push(
+				CheckMessage::error("urls.E001", conflict.clone()).with_hint(
+					"Give the routes distinct names, or mount the apps under distinct namespaces",
+				),
+			);
+		}
+
+		let mut apps_by_instance: HashMap<&str, Vec<&str>> = HashMap::new();
+		for (app, instances) in reverser.app_namespaces() {
+			for instance in instances {
+				apps_by_instance
+					.entry(instance.as_str())
+					.or_default()
+					.push(app);
+			}
+		}
+		let mut duplicates: Vec<_> = apps_by_instance
+			.into_iter()
+			.filter(|(_, apps)| apps.len() > 1)
+			.collect();
+		duplicates.sort();
+		for (instance, mut apps) in duplicates {
+			apps.sort();
+			apps.dedup();
+			messages.push(
+				CheckMessage::warning(
+					"urls.W005",
+					format!(
+						"URL namespace '{}' isn't unique (used by {}). You may not be able to reverse all URLs in this namespace",
+						instance,
+						apps.join(", ")
+					),
+				)
+				.with_hint("Give each mount of an app its own namespace with with_namespace()"),
+			);
+		}
+
+		messages
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reinhardt_utils::utils_core::checks::CheckLevel;
+	use rstest::rstest;
+
+	#[rstest]
+	fn test_unique_names_pass() {
+		// Arrange
+		let mut reverser = UrlReverser::new();
+		reverser
+			.register_path("polls:detail", "/polls/{id}/")
+			.unwrap();
+		reverser
+			.register_path("staff-polls:detail", "/staff/{id}/")
+			.unwrap();
+		reverser.register_app_instance("polls", "polls");
+		reverser.register_app_instance("polls", "staff-polls");
+
+		// Act
+		let messages = UrlConfCheck::new(Arc::new(reverser)).check();
+
+		// Assert
+		assert!(messages.is_empty());
+	}
+
+	#[rstest]
+	fn test_route_name_collision_is_an_error() {
+		// Arrange
+		let mut reverser = UrlReverser::new();
+		reverser
+			.register_path("polls:detail", "/polls/{id}/")
+			.unwrap();
+		let _ = reverser.register_path("polls:detail", "/staff/{id}/");
+
+		// Act
+		let messages = UrlConfCheck::new(Arc::new(reverser)).check();
+
+		// Assert
+		assert_eq!(messages.len(), 1);
+		assert_eq!(messages[0].id, "urls.E001");
+		assert_eq!(messages[0].level, CheckLevel::Error);
+		assert!(messages[0].message.contains("'polls:detail'"));
+	}
+
+	#[rstest]
+	fn test_instance_namespace_mounted_twice_is_a_warning() {
+		// Arrange
+		let mut reverser = UrlReverser::new();
+		reverser.register_app_instance("polls", "polls");
+		reverser.register_app_instance("polls", "polls");
+
+		// Act
+		let messages = UrlConfCheck::new(Arc::new(reverser)).check();
+
+		// Assert
+		assert_eq!(messages.len(), 1);
+		assert_eq!(messages[0].id, "urls.W005");
+		assert!(messages[0].message.contains("'polls'"));
+	}
+}
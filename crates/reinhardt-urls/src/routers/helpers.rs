@@ -245,8 +245,10 @@ pub struct IncludedRouter {
 	pub prefix: String,
 	/// Routes included under this prefix.
 	pub routes: Vec<Route>,
-	/// Optional namespace for reverse URL resolution.
+	/// Optional instance namespace for reverse URL resolution.
 	pub namespace: Option<String>,
+	/// Optional application namespace shared by every instance of the app.
+	pub app_namespace: Option<String>,
 }
 
 impl IncludedRouter {
@@ -256,14 +258,33 @@ impl IncludedRouter {
 			prefix: prefix.into(),
 			routes,
 			namespace: None,
+			app_namespace: None,
 		}
 	}
 
 	/// Set the namespace for this included router.
+	///
+	/// With an application namespace, this is the instance namespace that
+	/// tells apart several mounts of the same app.
 	pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
 		self.namespace = Some(namespace.into());
 		self
 	}
+
+	/// Set the application namespace for this included router.
+	///
+	/// `reverse("<app>:<name>")` resolves to one of the instances of the app;
+	/// see [`UrlReverser::reverse_in_app`](crate::routers::UrlReverser::reverse_in_app).
+	pub fn with_app_namespace(mut self, app_namespace: impl Into<String>) -> Self {
+		self.app_namespace = Some(app_namespace.into());
+		self
+	}
+
+	/// The namespace the routes are registered under: the instance namespace,
+	/// or the application namespace when no instance namespace is set.
+	pub fn instance_namespace(&self) -> Option<&str> {
+		self.namespace.as_deref().or(self.app_namespace.as_deref())
+	}
 }
 
 /// Create an IncludedRouter from a list of routes
@@ -302,6 +323,9 @@ impl IncludedRouter {
 /// assert_eq!(included.prefix, "/users");
 /// assert_eq!(included.namespace, Some("users".to_string()));
 /// ```
+///
+/// The same app can be included several times under distinct instance
+/// namespaces; see [`DefaultRouter::include`](crate::routers::DefaultRouter::include).
 pub fn include_routes(prefix: impl Into<String>, routes: Vec<Route>) -> IncludedRouter {
 	IncludedRouter::new(prefix, routes)
 }
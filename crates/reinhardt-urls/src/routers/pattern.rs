@@ -5,7 +5,9 @@
 //! - `validation`: shared length/segment limits and parameter validators
 //! - `path_pattern`: [`PathPattern`] — the parsed, reversible URL pattern
 //! - `matcher`: [`PathMatcher`] / [`MatchingMode`] — pattern dispatch
-//! - `radix`: [`RadixRouter`] / [`RadixRouterError`] — radix-tree routing
+//! - `trie`: compiled segment trie backing [`PathMatcher`]'s radix mode
+//! - `radix`: [`RadixRouter`] / [`RadixRouterError`] — standalone radix-tree
+//!   routing
 //!
 //! The top-level re-exports below preserve the public API surface that was
 //! available when this module was a single file.
@@ -13,6 +15,7 @@
 mod matcher;
 mod path_pattern;
 mod radix;
+mod trie;
 mod validation;
 
 #[cfg(test)]
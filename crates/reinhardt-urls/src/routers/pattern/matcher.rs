@@ -1,45 +1,57 @@
 use super::path_pattern::PathPattern;
-use super::radix::{RadixRouter, RadixRouterError};
+use super::radix::RadixRouterError;
+use super::trie::{RouteTrie, TrieInsertError};
 use super::validation::validate_path_param;
 use reinhardt_http::PathParams;
 
 /// Matching mode for PathMatcher
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MatchingMode {
-	/// Linear O(n) matching through all patterns (default)
+	/// Linear O(n) matching through all patterns, first registered wins
 	Linear,
-	/// Radix Tree O(m) matching using matchit (recommended for >100 routes)
+	/// Compiled segment trie with O(m) matching independent of route count
+	/// (default)
 	RadixTree,
 }
 
 /// Path matcher - uses composition to match paths
 ///
 /// Supports two matching modes:
-/// - **Linear** (default): O(n) search through patterns, suitable for <100 routes
-/// - **RadixTree**: O(m) matching using radix tree, recommended for >100 routes
+/// - **RadixTree** (default): patterns are compiled into a segment trie with
+///   static, parameter and wildcard nodes. Matching cost depends on the path
+///   length, not the route count. Static segments win over parameters, which
+///   win over `path` wildcards; parameters at the same position are tried in
+///   registration order. Conflicting patterns are rejected by
+///   [`add_pattern`](Self::add_pattern).
+/// - **Linear**: O(n) regex scan through patterns in registration order.
+///
+/// Patterns the trie cannot express, such as a `path` parameter sharing a
+/// segment with literal text, are matched linearly after the trie misses.
 pub struct PathMatcher {
 	patterns: Vec<(PathPattern, String)>, // (pattern, handler_id)
-	radix_router: Option<RadixRouter>,
+	trie: Option<RouteTrie>,
+	/// Indices of patterns the trie cannot express, in registration order
+	fallback: Vec<usize>,
 	mode: MatchingMode,
 }
 
 impl PathMatcher {
-	/// Create a new PathMatcher with linear matching (default)
+	/// Create a new PathMatcher with radix tree matching (default)
+	///
+	/// Earlier releases defaulted to [`MatchingMode::Linear`]; use
+	/// [`with_mode`](Self::with_mode) to keep first-registered-wins matching.
 	///
 	/// # Examples
 	///
 	/// ```
-	/// use reinhardt_urls::routers::PathMatcher;
+	/// use reinhardt_urls::routers::{MatchingMode, PathMatcher};
 	///
 	/// let matcher = PathMatcher::new();
+	/// assert_eq!(matcher.mode(), MatchingMode::RadixTree);
 	/// assert_eq!(matcher.match_path("/users/"), None);
 	/// ```
 	pub fn new() -> Self {
-		Self {
-			patterns: Vec::new(),
-			radix_router: None,
-			mode: MatchingMode::Linear,
-		}
+		Self::with_mode(MatchingMode::RadixTree)
 	}
 
 	/// Create a new PathMatcher with specified matching mode
@@ -54,26 +66,23 @@ impl PathMatcher {
 	pub fn with_mode(mode: MatchingMode) -> Self {
 		Self {
 			patterns: Vec::new(),
-			radix_router: if mode == MatchingMode::RadixTree {
-				Some(RadixRouter::new())
-			} else {
-				None
-			},
+			trie: (mode == MatchingMode::RadixTree).then(RouteTrie::new),
+			fallback: Vec::new(),
 			mode,
 		}
 	}
 
 	/// Enable radix tree matching mode
 	///
-	/// Rebuilds the radix router from existing patterns.
-	/// Recommended when route count exceeds 100.
+	/// Rebuilds the trie from existing patterns. Only needed for matchers
+	/// created with [`MatchingMode::Linear`].
 	///
 	/// # Examples
 	///
 	/// ```
-	/// use reinhardt_urls::routers::{PathMatcher, PathPattern, path};
+	/// use reinhardt_urls::routers::{MatchingMode, PathMatcher, PathPattern, path};
 	///
-	/// let mut matcher = PathMatcher::new();
+	/// let mut matcher = PathMatcher::with_mode(MatchingMode::Linear);
 	/// let pattern = PathPattern::new(path!("/users/")).unwrap();
 	/// matcher.add_pattern(pattern, "users_list".to_string()).unwrap();
 	///
@@ -87,7 +96,7 @@ impl PathMatcher {
 	/// # Errors
 	///
 	/// Returns `RadixRouterError` if any of the existing patterns conflicts with
-	/// or is rejected by the underlying radix router. When this happens the
+	/// another one once compiled into the trie. When this happens the
 	/// matcher is left in `Linear` mode (the original state) so callers can
 	/// recover or surface the error.
 	pub fn enable_radix_tree(&mut self) -> Result<(), RadixRouterError> {
@@ -95,26 +104,46 @@ impl PathMatcher {
 			return Ok(()); // Already enabled
 		}
 
-		let mut radix_router = RadixRouter::new();
+		let mut trie = RouteTrie::new();
+		let mut fallback = Vec::new();
 
-		// Rebuild radix router from existing patterns. Propagate failures so
+		// Rebuild the trie from existing patterns. Propagate conflicts so
 		// Linear and RadixTree modes cannot silently diverge.
-		for (pattern, handler_id) in &self.patterns {
-			radix_router.add_route(&pattern.to_matchit_pattern(), handler_id.clone())?;
+		for (index, (pattern, _)) in self.patterns.iter().enumerate() {
+			if Self::insert_into_trie(&mut trie, pattern, index)? {
+				fallback.push(index);
+			}
 		}
 
 		self.mode = MatchingMode::RadixTree;
-		self.radix_router = Some(radix_router);
+		self.trie = Some(trie);
+		self.fallback = fallback;
 		Ok(())
 	}
 
+	/// Insert `pattern` into `trie`, returning `true` when the trie cannot
+	/// express it and it must be matched linearly instead
+	fn insert_into_trie(
+		trie: &mut RouteTrie,
+		pattern: &PathPattern,
+		index: usize,
+	) -> Result<bool, RadixRouterError> {
+		match trie.insert(pattern.pattern(), index) {
+			Ok(()) => Ok(false),
+			Err(TrieInsertError::Unsupported(_)) => Ok(true),
+			Err(TrieInsertError::Conflict(message)) => {
+				Err(RadixRouterError::InsertionFailed(message))
+			}
+		}
+	}
+
 	/// Get current matching mode
 	pub fn mode(&self) -> MatchingMode {
 		self.mode
 	}
 	/// Add a pattern to the matcher
 	///
-	/// If radix tree mode is enabled, also adds to the radix router.
+	/// In radix tree mode the pattern is also compiled into the trie.
 	///
 	/// # Examples
 	///
@@ -131,23 +160,23 @@ impl PathMatcher {
 	///
 	/// # Errors
 	///
-	/// Returns `RadixRouterError` when radix tree mode is active and the
-	/// underlying `RadixRouter::add_route` rejects the pattern (e.g., conflict
-	/// or invalid syntax). When this happens the pattern is **not** appended
-	/// to the linear list either, so `Linear` and `RadixTree` modes remain in
-	/// sync.
+	/// Returns `RadixRouterError::InsertionFailed` when radix tree mode is
+	/// active and the pattern conflicts with an existing one (same route, or
+	/// a parameter with the same type but a different name at the same
+	/// position). When this happens the pattern is **not** appended to the
+	/// linear list either, so `Linear` and `RadixTree` modes remain in sync.
 	pub fn add_pattern(
 		&mut self,
 		pattern: PathPattern,
 		handler_id: String,
 	) -> Result<(), RadixRouterError> {
-		let matchit_pattern = pattern.to_matchit_pattern();
-
-		// If radix tree mode is enabled, insert into the radix router first
-		// so a failure does not leave the linear list and the radix tree out
-		// of sync.
-		if let Some(ref mut radix_router) = self.radix_router {
-			radix_router.add_route(&matchit_pattern, handler_id.clone())?;
+		// Insert into the trie first so a conflict does not leave the linear
+		// list and the trie out of sync.
+		let index = self.patterns.len();
+		if let Some(ref mut trie) = self.trie
+			&& Self::insert_into_trie(trie, &pattern, index)?
+		{
+			self.fallback.push(index);
 		}
 
 		self.patterns.push((pattern, handler_id));
@@ -156,8 +185,8 @@ impl PathMatcher {
 	/// Match a path and extract parameters
 	///
 	/// Uses the configured matching mode:
-	/// - **Linear**: O(n) search through patterns (default)
-	/// - **RadixTree**: O(m) radix tree matching where m = path length
+	/// - **RadixTree**: O(m) trie matching where m = path length (default)
+	/// - **Linear**: O(n) search through patterns
 	///
 	/// # Performance Notes
 	///
@@ -165,8 +194,7 @@ impl PathMatcher {
 	///   - Suitable for <100 routes
 	///   - Benefits from RouteCache for O(1) on cache hits
 	/// - **RadixTree mode**: O(m) where m = path length
-	///   - Recommended for >100 routes
-	///   - 3-5x faster for large route sets
+	///   - Independent of route count
 	///
 	/// # Examples
 	///
@@ -186,28 +214,28 @@ impl PathMatcher {
 	pub fn match_path(&self, path: &str) -> Option<(String, PathParams)> {
 		match self.mode {
 			MatchingMode::RadixTree => {
-				// Use radix tree for O(m) matching
-				if let Some(ref radix_router) = self.radix_router {
-					let (handler_id, params) = radix_router.match_path(path)?;
-
-					// Validate path-type parameters against directory traversal
-					if let Some((pattern, _)) =
-						self.patterns.iter().find(|(_, id)| *id == handler_id)
-					{
-						for (name, value) in params.iter() {
-							if pattern.path_type_params.contains(name)
-								&& !validate_path_param(value)
-							{
-								return None;
-							}
+				let Some(ref trie) = self.trie else {
+					// Fallback to linear if the trie is not initialized
+					return self.match_path_linear(path);
+				};
+
+				if let Some((index, captured)) = trie.lookup(path) {
+					let (pattern, handler_id) = &self.patterns[index];
+					let mut params = PathParams::new();
+					for (name, value) in captured {
+						// Validate path-type parameters against directory traversal
+						if pattern.path_type_params.contains(name) && !validate_path_param(value) {
+							return None;
 						}
+						params.insert(name.to_string(), value.to_string());
 					}
-
-					Some((handler_id, params))
-				} else {
-					// Fallback to linear if radix router not initialized
-					self.match_path_linear(path)
+					return Some((handler_id.clone(), params));
 				}
+
+				self.fallback.iter().find_map(|&index| {
+					let (pattern, handler_id) = &self.patterns[index];
+					Self::match_pattern(pattern, path).map(|params| (handler_id.clone(), params))
+				})
 			}
 			MatchingMode::Linear => {
 				// Use linear O(n) matching
@@ -218,29 +246,31 @@ impl PathMatcher {
 
 	/// Linear pattern matching (O(n))
 	fn match_path_linear(&self, path: &str) -> Option<(String, PathParams)> {
-		'outer: for (pattern, handler_id) in &self.patterns {
-			if let Some(captures) = pattern.regex.captures(path) {
-				// Use ordered `PathParams` so tuple extractors see params in
-				// URL pattern declaration order (issue #4013). `param_names()`
-				// already yields names in the order they appear in the pattern.
-				let mut params = PathParams::new();
-
-				for name in pattern.param_names() {
-					if let Some(value) = captures.name(name) {
-						let val = value.as_str();
-						// Validate path-type parameters against directory traversal
-						if pattern.path_type_params.contains(name) && !validate_path_param(val) {
-							continue 'outer;
-						}
-						params.insert(name.clone(), val.to_string());
-					}
-				}
+		self.patterns.iter().find_map(|(pattern, handler_id)| {
+			Self::match_pattern(pattern, path).map(|params| (handler_id.clone(), params))
+		})
+	}
+
+	/// Match `path` against a single pattern's regex
+	fn match_pattern(pattern: &PathPattern, path: &str) -> Option<PathParams> {
+		let captures = pattern.regex.captures(path)?;
+		// Use ordered `PathParams` so tuple extractors see params in
+		// URL pattern declaration order (issue #4013). `param_names()`
+		// already yields names in the order they appear in the pattern.
+		let mut params = PathParams::new();
 
-				return Some((handler_id.clone(), params));
+		for name in pattern.param_names() {
+			if let Some(value) = captures.name(name) {
+				let val = value.as_str();
+				// Validate path-type parameters against directory traversal
+				if pattern.path_type_params.contains(name) && !validate_path_param(val) {
+					return None;
+				}
+				params.insert(name.clone(), val.to_string());
 			}
 		}
 
-		None
+		Some(params)
 	}
 }
 
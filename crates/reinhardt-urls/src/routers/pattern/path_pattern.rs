@@ -198,53 +198,6 @@ impl PathPattern {
 		&self.pattern
 	}
 
-	/// Convert pattern to matchit-compatible format
-	///
-	/// Transforms path-type parameters from `{<path:name>}` to `{*name}`
-	/// for use with the matchit radix router. Non-path parameters remain
-	/// as `{name}`.
-	pub(crate) fn to_matchit_pattern(&self) -> String {
-		let mut result = String::new();
-		let mut chars = self.pattern.chars().peekable();
-
-		while let Some(ch) = chars.next() {
-			if ch == '{' {
-				let mut param_content = String::new();
-				while let Some(&next_ch) = chars.peek() {
-					if next_ch == '}' {
-						chars.next();
-						break;
-					}
-					param_content.push(chars.next().unwrap());
-				}
-
-				// Check for typed parameter: {<type:name>}
-				if param_content.starts_with('<') && param_content.ends_with('>') {
-					let inner = &param_content[1..param_content.len() - 1];
-					if let Some(colon_pos) = inner.find(':') {
-						let type_spec = &inner[..colon_pos];
-						let name = &inner[colon_pos + 1..];
-						if type_spec == "path" {
-							// Convert path type to matchit catch-all: {*name}
-							result.push_str(&format!("{{*{}}}", name));
-						} else {
-							// Other typed params use simple {name}
-							result.push_str(&format!("{{{}}}", name));
-						}
-					} else {
-						result.push_str(&format!("{{{}}}", param_content));
-					}
-				} else {
-					// Simple {name} parameter
-					result.push_str(&format!("{{{}}}", param_content));
-				}
-			} else {
-				result.push(ch);
-			}
-		}
-
-		result
-	}
 	/// Get the list of parameter names in the pattern
 	///
 	/// # Examples
@@ -348,7 +348,7 @@ fn test_path_matcher_radix_tree_mode() {
 
 #[test]
 fn test_path_matcher_enable_radix_tree() {
-	let mut matcher = PathMatcher::new();
+	let mut matcher = PathMatcher::with_mode(MatchingMode::Linear);
 	matcher
 		.add_pattern(
 			PathPattern::new(reinhardt_routers_macros::path!("/users/")).unwrap(),
@@ -371,7 +371,7 @@ fn test_path_matcher_enable_radix_tree() {
 #[test]
 fn test_path_matcher_linear_vs_radix() {
 	// Create two matchers with same routes
-	let mut linear_matcher = PathMatcher::new();
+	let mut linear_matcher = PathMatcher::with_mode(MatchingMode::Linear);
 	let mut radix_matcher = PathMatcher::with_mode(MatchingMode::RadixTree);
 
 	for i in 1..=10 {
@@ -849,8 +849,8 @@ fn test_pattern_accepts_at_segment_boundary() {
 
 #[test]
 fn test_add_pattern_returns_err_on_radix_conflict() {
-	// Arrange — RadixTree mode so the second insert hits matchit's conflict
-	// detection (matchit rejects two identical parameterized routes).
+	// Arrange — RadixTree mode so the second insert hits the trie's conflict
+	// detection (two routes ending on the same node).
 	let mut matcher = PathMatcher::with_mode(MatchingMode::RadixTree);
 	matcher
 		.add_pattern(
@@ -859,7 +859,7 @@ fn test_add_pattern_returns_err_on_radix_conflict() {
 		)
 		.expect("first insert must succeed");
 
-	// Act — register a different name on the same matchit pattern. matchit
+	// Act — register a different handler on the same pattern. The trie
 	// treats the two as a conflict and rejects the insertion.
 	let result = matcher.add_pattern(
 		PathPattern::new(reinhardt_routers_macros::path!("/items/{id}/")).unwrap(),
@@ -874,7 +874,7 @@ fn test_add_pattern_returns_err_on_radix_conflict() {
 fn test_enable_radix_tree_returns_err_on_conflict() {
 	// Arrange — register two conflicting patterns in Linear mode (linear
 	// mode does not enforce uniqueness, so this succeeds).
-	let mut matcher = PathMatcher::new();
+	let mut matcher = PathMatcher::with_mode(MatchingMode::Linear);
 	matcher
 		.add_pattern(
 			PathPattern::new(reinhardt_routers_macros::path!("/items/{id}/")).unwrap(),
@@ -888,7 +888,7 @@ fn test_enable_radix_tree_returns_err_on_conflict() {
 		)
 		.unwrap();
 
-	// Act — switching to RadixTree mode must rebuild the radix router from
+	// Act — switching to RadixTree mode must rebuild the trie from
 	// the existing patterns; the duplicate triggers a conflict.
 	let result = matcher.enable_radix_tree();
 
@@ -901,3 +901,165 @@ fn test_enable_radix_tree_returns_err_on_conflict() {
 		"matcher must remain in Linear mode after a failed upgrade"
 	);
 }
+
+#[test]
+fn test_enable_radix_tree_is_noop_for_default_mode() {
+	// Arrange
+	let mut matcher = PathMatcher::new();
+	matcher
+		.add_pattern(
+			PathPattern::new(reinhardt_routers_macros::path!("/users/")).unwrap(),
+			"users_list".to_string(),
+		)
+		.unwrap();
+
+	// Act
+	let result = matcher.enable_radix_tree();
+
+	// Assert
+	assert!(result.is_ok());
+	assert_eq!(matcher.mode(), MatchingMode::RadixTree);
+	assert_eq!(
+		matcher
+			.match_path("/users/")
+			.map(|(handler_id, _)| handler_id),
+		Some("users_list".to_string())
+	);
+}
+
+#[test]
+fn test_add_pattern_rejects_conflict_in_default_mode() {
+	// Arrange
+	let mut matcher = PathMatcher::new();
+	matcher
+		.add_pattern(
+			PathPattern::new(reinhardt_routers_macros::path!("/items/{id}/")).unwrap(),
+			"first".to_string(),
+		)
+		.unwrap();
+
+	// Act
+	let result = matcher.add_pattern(
+		PathPattern::new(reinhardt_routers_macros::path!("/items/{id}/")).unwrap(),
+		"second".to_string(),
+	);
+
+	// Assert
+	assert!(result.is_err(), "expected trie conflict, got Ok");
+	assert_eq!(
+		matcher
+			.match_path("/items/7/")
+			.map(|(handler_id, _)| handler_id),
+		Some("first".to_string())
+	);
+}
+
+// ===================================================================
+// Compiled trie matching
+// ===================================================================
+
+#[test]
+fn test_path_matcher_defaults_to_radix_tree() {
+	// Arrange / Act
+	let matcher = PathMatcher::new();
+
+	// Assert
+	assert_eq!(matcher.mode(), MatchingMode::RadixTree);
+}
+
+#[test]
+fn test_radix_tree_mode_enforces_typed_params() {
+	// Arrange
+	let mut matcher = PathMatcher::new();
+	matcher
+		.add_pattern(
+			PathPattern::new("/posts/{<int:id>}/").unwrap(),
+			"post_by_id".to_string(),
+		)
+		.unwrap();
+	matcher
+		.add_pattern(
+			PathPattern::new("/posts/{<slug:slug>}/").unwrap(),
+			"post_by_slug".to_string(),
+		)
+		.unwrap();
+
+	// Act
+	let by_id = matcher.match_path("/posts/42/").unwrap();
+	let by_slug = matcher.match_path("/posts/hello-world/").unwrap();
+
+	// Assert
+	assert_eq!(by_id.0, "post_by_id");
+	assert_eq!(by_id.1.get("id"), Some(&"42".to_string()));
+	assert_eq!(by_slug.0, "post_by_slug");
+	assert_eq!(by_slug.1.get("slug"), Some(&"hello-world".to_string()));
+}
+
+#[test]
+fn test_radix_tree_mode_prefers_static_segments() {
+	// Arrange — the parameterized route is registered first
+	let mut matcher = PathMatcher::new();
+	matcher
+		.add_pattern(
+			PathPattern::new("/users/{id}/").unwrap(),
+			"users_detail".to_string(),
+		)
+		.unwrap();
+	matcher
+		.add_pattern(
+			PathPattern::new("/users/me/").unwrap(),
+			"users_me".to_string(),
+		)
+		.unwrap();
+
+	// Act
+	let result = matcher.match_path("/users/me/").unwrap();
+
+	// Assert
+	assert_eq!(result.0, "users_me");
+	assert!(result.1.is_empty());
+}
+
+#[test]
+fn test_radix_tree_mode_keeps_params_in_pattern_order() {
+	// Arrange
+	let mut matcher = PathMatcher::new();
+	matcher
+		.add_pattern(
+			PathPattern::new("/users/{user_id}/posts/{post_id}/").unwrap(),
+			"post".to_string(),
+		)
+		.unwrap();
+
+	// Act
+	let (_, params) = matcher.match_path("/users/7/posts/9/").unwrap();
+
+	// Assert
+	let names: Vec<&str> = params.iter().map(|(name, _)| name.as_str()).collect();
+	assert_eq!(names, vec!["user_id", "post_id"]);
+}
+
+#[test]
+fn test_radix_tree_mode_matches_unsupported_patterns_linearly() {
+	// Arrange — a `path` parameter sharing a segment with literal text
+	// cannot be expressed in the trie
+	let mut matcher = PathMatcher::new();
+	matcher
+		.add_pattern(
+			PathPattern::new("/assets/v{<path:rest>}").unwrap(),
+			"assets".to_string(),
+		)
+		.unwrap();
+	matcher
+		.add_pattern(PathPattern::new("/health/").unwrap(), "health".to_string())
+		.unwrap();
+
+	// Act
+	let assets = matcher.match_path("/assets/v2/app.js").unwrap();
+	let health = matcher.match_path("/health/").unwrap();
+
+	// Assert
+	assert_eq!(assets.0, "assets");
+	assert_eq!(assets.1.get("rest"), Some(&"2/app.js".to_string()));
+	assert_eq!(health.0, "health");
+}
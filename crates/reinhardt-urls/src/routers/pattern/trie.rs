@@ -0,0 +1,514 @@
+//! Compiled segment trie used by [`PathMatcher`](super::PathMatcher).
+//!
+//! Patterns are split on `/` and each segment becomes a node:
+//!
+//! - **static** segments (`users`) are children looked up by exact text,
+//! - **parameter** segments (`{id}`, `{<int:id>}`, `v{<int:version>}`) are
+//!   children tested against the segment, in registration order,
+//! - a **wildcard** segment (`{<path:rest>}`) consumes one or more segments.
+//!
+//! Matching walks the path once, preferring static over parameter over
+//! wildcard children and backtracking only when a branch dead-ends, so lookup
+//! cost depends on the path length rather than the number of routes.
+//!
+//! Conflicts are rejected at insertion: two routes ending on the same node,
+//! or two parameter segments with the same shape but different names at the
+//! same position, can never both be reachable.
+
+use super::validation::{MAX_REGEX_SIZE, type_spec_to_regex};
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Why a pattern could not be inserted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum TrieInsertError {
+	/// The pattern would shadow or be shadowed by an existing route
+	Conflict(String),
+	/// The pattern cannot be expressed as trie segments (e.g. a `path`
+	/// parameter sharing a segment with literal text)
+	Unsupported(String),
+}
+
+/// One parsed pattern segment
+enum Segment {
+	Static(String),
+	Param(ParamSegment),
+	Wildcard(String),
+}
+
+/// A segment containing at least one non-`path` parameter
+struct ParamSegment {
+	/// Segment with parameter names replaced by their regex, used to detect
+	/// equivalent segments registered under different names
+	shape: String,
+	names: Vec<String>,
+	/// `None` for a bare `{name}` segment, which matches any non-empty text
+	regex: Option<Regex>,
+}
+
+impl ParamSegment {
+	fn capture<'p>(&self, segment: &'p str, out: &mut Vec<(usize, &'p str)>) -> bool {
+		match &self.regex {
+			None => {
+				if segment.is_empty() {
+					return false;
+				}
+				out.push((0, segment));
+				true
+			}
+			Some(regex) => {
+				let Some(captures) = regex.captures(segment) else {
+					return false;
+				};
+				for (index, name) in self.names.iter().enumerate() {
+					match captures.name(name) {
+						Some(value) => out.push((index, value.as_str())),
+						None => return false,
+					}
+				}
+				true
+			}
+		}
+	}
+}
+
+struct ParamChild {
+	segment: ParamSegment,
+	node: Node,
+}
+
+struct WildcardChild {
+	name: String,
+	/// Continuation after the wildcard
+	node: Node,
+}
+
+#[derive(Default)]
+struct Node {
+	statics: HashMap<String, Node>,
+	params: Vec<ParamChild>,
+	wildcard: Option<Box<WildcardChild>>,
+	route: Option<usize>,
+}
+
+/// A matched route and its parameters in pattern order
+type TrieMatch<'t, 'p> = (usize, Vec<(&'t str, &'p str)>);
+
+/// Segment trie mapping URL patterns to route indices
+#[derive(Default)]
+pub(super) struct RouteTrie {
+	root: Node,
+	/// Original pattern per route index, for conflict messages
+	labels: HashMap<usize, String>,
+}
+
+impl RouteTrie {
+	pub(super) fn new() -> Self {
+		Self::default()
+	}
+
+	/// Insert `pattern` as route `route`
+	///
+	/// The trie is left unchanged when an error is returned.
+	pub(super) fn insert(&mut self, pattern: &str, route: usize) -> Result<(), TrieInsertError> {
+		let segments = parse_segments(pattern)?;
+		if let Some(existing) = self.find_conflict(&segments) {
+			return Err(TrieInsertError::Conflict(format!(
+				"pattern `{}` conflicts with `{}`",
+				pattern, existing
+			)));
+		}
+
+		let mut node = &mut self.root;
+		for segment in segments {
+			node = match segment {
+				Segment::Static(text) => node.statics.entry(text).or_default(),
+				Segment::Param(param) => {
+					let position = node
+						.params
+						.iter()
+						.position(|child| child.segment.shape == param.shape);
+					match position {
+						Some(index) => &mut node.params[index].node,
+						None => {
+							node.params.push(ParamChild {
+								segment: param,
+								node: Node::default(),
+							});
+							&mut node.params.last_mut().expect("just pushed").node
+						}
+					}
+				}
+				Segment::Wildcard(name) => {
+					&mut node
+						.wildcard
+						.get_or_insert_with(|| {
+							Box::new(WildcardChild {
+								name,
+								node: Node::default(),
+							})
+						})
+						.node
+				}
+			};
+		}
+		node.route = Some(route);
+		self.labels.insert(route, pattern.to_string());
+		Ok(())
+	}
+
+	/// Label of the route `segments` would collide with, if any
+	fn find_conflict(&self, segments: &[Segment]) -> Option<String> {
+		let mut node = &self.root;
+		for segment in segments {
+			let next = match segment {
+				Segment::Static(text) => node.statics.get(text),
+				Segment::Param(param) => {
+					match node
+						.params
+						.iter()
+						.find(|child| child.segment.shape == param.shape)
+					{
+						Some(child) if child.segment.names != param.names => {
+							return Some(self.first_route_label(&child.node));
+						}
+						Some(child) => Some(&child.node),
+						None => None,
+					}
+				}
+				Segment::Wildcard(name) => match &node.wildcard {
+					Some(child) if &child.name != name => {
+						return Some(self.first_route_label(&child.node));
+					}
+					Some(child) => Some(&child.node),
+					None => None,
+				},
+			};
+			match next {
+				Some(next) => node = next,
+				None => return None,
+			}
+		}
+		node.route.map(|route| self.label(route))
+	}
+
+	fn label(&self, route: usize) -> String {
+		self.labels.get(&route).cloned().unwrap_or_default()
+	}
+
+	/// Label of any route registered below `node`
+	fn first_route_label(&self, node: &Node) -> String {
+		if let Some(route) = node.route {
+			return self.label(route);
+		}
+		node.statics
+			.values()
+			.chain(node.params.iter().map(|child| &child.node))
+			.chain(node.wildcard.iter().map(|child| &child.node))
+			.map(|child| self.first_route_label(child))
+			.find(|label| !label.is_empty())
+			.unwrap_or_default()
+	}
+
+	/// Match `path`, returning the route index and its parameters in pattern
+	/// order
+	pub(super) fn lookup<'t, 'p>(&'t self, path: &'p str) -> Option<TrieMatch<'t, 'p>> {
+		let segments: Vec<&'p str> = path.split('/').collect();
+		let mut params = Vec::new();
+		let route = lookup_node(&self.root, path, &segments, 0, &mut params)?;
+		Some((route, params))
+	}
+}
+
+fn lookup_node<'t, 'p>(
+	node: &'t Node,
+	path: &'p str,
+	segments: &[&'p str],
+	depth: usize,
+	params: &mut Vec<(&'t str, &'p str)>,
+) -> Option<usize> {
+	let Some(&segment) = segments.get(depth) else {
+		return node.route;
+	};
+
+	if let Some(child) = node.statics.get(segment)
+		&& let Some(route) = lookup_node(child, path, segments, depth + 1, params)
+	{
+		return Some(route);
+	}
+
+	let mut captured = Vec::new();
+	for child in &node.params {
+		captured.clear();
+		if !child.segment.capture(segment, &mut captured) {
+			continue;
+		}
+		let mark = params.len();
+		params.extend(
+			captured
+				.iter()
+				.map(|&(index, value)| (child.segment.names[index].as_str(), value)),
+		);
+		if let Some(route) = lookup_node(&child.node, path, segments, depth + 1, params) {
+			return Some(route);
+		}
+		params.truncate(mark);
+	}
+
+	if let Some(wildcard) = &node.wildcard {
+		// Greedy, like the `.+` regex used by linear matching: try the longest
+		// span first.
+		let start = segment_offset(path, segments, depth);
+		for end in (depth + 1..=segments.len()).rev() {
+			let stop = segment_offset(path, segments, end - 1) + segments[end - 1].len();
+			let value = &path[start..stop];
+			if value.is_empty() {
+				continue;
+			}
+			let mark = params.len();
+			params.push((wildcard.name.as_str(), value));
+			if let Some(route) = lookup_node(&wildcard.node, path, segments, end, params) {
+				return Some(route);
+			}
+			params.truncate(mark);
+		}
+	}
+
+	None
+}
+
+/// Byte offset of `segments[index]` within `path`
+fn segment_offset(path: &str, segments: &[&str], index: usize) -> usize {
+	segments[index].as_ptr() as usize - path.as_ptr() as usize
+}
+
+/// Split a pattern into trie segments
+fn parse_segments(pattern: &str) -> Result<Vec<Segment>, TrieInsertError> {
+	pattern.split('/').map(parse_segment).collect()
+}
+
+/// Pieces of one segment: literal text or `(type, name)` parameters
+enum Piece<'a> {
+	Literal(&'a str),
+	Param(&'a str, &'a str),
+}
+
+fn parse_segment(segment: &str) -> Result<Segment, TrieInsertError> {
+	let mut pieces = Vec::new();
+	let mut rest = segment;
+	while let Some(open) = rest.find('{') {
+		if open > 0 {
+			pieces.push(Piece::Literal(&rest[..open]));
+		}
+		let close = rest[open..].find('}').ok_or_else(|| {
+			TrieInsertError::Unsupported(format!("unclosed parameter in segment `{}`", segment))
+		})? + open;
+		let content = &rest[open + 1..close];
+		let (type_spec, name) = match content
+			.strip_prefix('<')
+			.and_then(|inner| inner.strip_suffix('>'))
+		{
+			Some(inner) => inner.split_once(':').unwrap_or(("str", inner)),
+			None => ("str", content),
+		};
+		pieces.push(Piece::Param(type_spec, name));
+		rest = &rest[close + 1..];
+	}
+	if !rest.is_empty() {
+		pieces.push(Piece::Literal(rest));
+	}
+
+	match pieces.as_slice() {
+		[] => Ok(Segment::Static(String::new())),
+		[Piece::Literal(text)] => Ok(Segment::Static((*text).to_string())),
+		[Piece::Param("path", name)] => Ok(Segment::Wildcard((*name).to_string())),
+		_ => {
+			let mut shape = String::new();
+			let mut regex = String::from("^");
+			let mut names = Vec::new();
+			for piece in &pieces {
+				match piece {
+					Piece::Literal(text) => {
+						shape.push_str(text);
+						regex.push_str(&regex::escape(text));
+					}
+					Piece::Param("path", _) => {
+						return Err(TrieInsertError::Unsupported(format!(
+							"`path` parameter must occupy a whole segment in `{}`",
+							segment
+						)));
+					}
+					Piece::Param(type_spec, name) => {
+						let fragment = type_spec_to_regex(type_spec);
+						shape.push('{');
+						shape.push_str(fragment);
+						shape.push('}');
+						regex.push_str(&format!("(?P<{}>{})", name, fragment));
+						names.push((*name).to_string());
+					}
+				}
+			}
+			regex.push('$');
+
+			let bare = matches!(pieces.as_slice(), [Piece::Param(_, _)])
+				&& shape == format!("{{{}}}", type_spec_to_regex("str"));
+			let regex = if bare {
+				None
+			} else {
+				Some(
+					regex::RegexBuilder::new(&regex)
+						.size_limit(MAX_REGEX_SIZE)
+						.build()
+						.map_err(|e| TrieInsertError::Unsupported(e.to_string()))?,
+				)
+			};
+			Ok(Segment::Param(ParamSegment {
+				shape,
+				names,
+				regex,
+			}))
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn trie(patterns: &[&str]) -> RouteTrie {
+		let mut trie = RouteTrie::new();
+		for (index, pattern) in patterns.iter().enumerate() {
+			trie.insert(pattern, index).unwrap();
+		}
+		trie
+	}
+
+	fn lookup(trie: &RouteTrie, path: &str) -> Option<(usize, Vec<(String, String)>)> {
+		trie.lookup(path).map(|(route, params)| {
+			(
+				route,
+				params
+					.into_iter()
+					.map(|(k, v)| (k.to_string(), v.to_string()))
+					.collect(),
+			)
+		})
+	}
+
+	#[rstest]
+	fn test_static_routes_take_priority_over_parameters() {
+		// Arrange
+		let trie = trie(&["/users/{id}/", "/users/new/"]);
+
+		// Act / Assert
+		assert_eq!(lookup(&trie, "/users/new/").unwrap().0, 1);
+		assert_eq!(lookup(&trie, "/users/42/").unwrap().0, 0);
+	}
+
+	#[rstest]
+	fn test_typed_parameters_are_tried_in_registration_order() {
+		// Arrange
+		let trie = trie(&["/items/{<int:id>}/", "/items/{<slug:slug>}/"]);
+
+		// Act
+		let by_id = lookup(&trie, "/items/7/").unwrap();
+		let by_slug = lookup(&trie, "/items/blue-shoe/").unwrap();
+
+		// Assert
+		assert_eq!(by_id, (0, vec![("id".to_string(), "7".to_string())]));
+		assert_eq!(
+			by_slug,
+			(1, vec![("slug".to_string(), "blue-shoe".to_string())])
+		);
+		assert!(lookup(&trie, "/items/Not_A_Slug/").is_none());
+	}
+
+	#[rstest]
+	fn test_mixed_segment_captures_every_parameter() {
+		// Arrange
+		let trie = trie(&["/files/{name}.{<str:ext>}"]);
+
+		// Act
+		let (_, params) = lookup(&trie, "/files/report.pdf").unwrap();
+
+		// Assert
+		assert_eq!(
+			params,
+			vec![
+				("name".to_string(), "report".to_string()),
+				("ext".to_string(), "pdf".to_string())
+			]
+		);
+	}
+
+	#[rstest]
+	fn test_wildcard_backtracks_to_match_suffix() {
+		// Arrange
+		let trie = trie(&["/repo/{<path:file>}/raw/"]);
+
+		// Act
+		let (_, params) = lookup(&trie, "/repo/src/lib.rs/raw/").unwrap();
+
+		// Assert
+		assert_eq!(params, vec![("file".to_string(), "src/lib.rs".to_string())]);
+	}
+
+	#[rstest]
+	fn test_backtracks_from_dead_end_static_branch() {
+		// Arrange
+		let trie = trie(&["/a/static/x/", "/a/{p}/y/"]);
+
+		// Act / Assert
+		assert_eq!(lookup(&trie, "/a/static/y/").unwrap().0, 1);
+	}
+
+	#[rstest]
+	#[case(&["/items/{id}/", "/items/{id}/"])]
+	#[case(&["/items/{id}/", "/items/{pk}/"])]
+	#[case(&["/items/{id}/edit/", "/items/{pk}/"])]
+	#[case(&["/files/{<path:a>}", "/files/{<path:b>}"])]
+	fn test_conflicts_are_rejected(#[case] patterns: &[&str]) {
+		// Arrange
+		let mut trie = trie(&patterns[..1]);
+
+		// Act
+		let result = trie.insert(patterns[1], 1);
+
+		// Assert
+		assert!(matches!(result, Err(TrieInsertError::Conflict(_))));
+	}
+
+	#[rstest]
+	fn test_failed_insert_leaves_trie_unchanged() {
+		// Arrange
+		let mut trie = trie(&["/items/{id}/"]);
+
+		// Act
+		let _ = trie.insert("/items/{pk}/edit/", 1);
+
+		// Assert
+		assert!(lookup(&trie, "/items/1/edit/").is_none());
+	}
+
+	#[rstest]
+	fn test_path_parameter_inside_segment_is_unsupported() {
+		// Arrange
+		let mut trie = RouteTrie::new();
+
+		// Act
+		let result = trie.insert("/files/{<path:p>}.txt", 0);
+
+		// Assert
+		assert!(matches!(result, Err(TrieInsertError::Unsupported(_))));
+	}
+
+	#[rstest]
+	fn test_empty_parameter_segment_does_not_match() {
+		// Arrange
+		let trie = trie(&["/users/{id}/"]);
+
+		// Act / Assert
+		assert!(lookup(&trie, "/users//").is_none());
+	}
+}
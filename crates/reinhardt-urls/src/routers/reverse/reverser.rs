@@ -10,6 +10,7 @@ use super::super::pattern::PathPattern;
 use super::runtime::ReverseResult;
 use once_cell::sync::OnceCell;
 use reinhardt_core::exception::Error;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::PoisonError;
@@ -26,6 +27,10 @@ pub struct UrlReverser {
 	/// Alias map: alias name → canonical name.
 	/// Used for backward compatibility when route names change format.
 	aliases: HashMap<String, String>,
+	/// Application namespace → instance namespaces, in registration order.
+	app_instances: HashMap<String, Vec<String>>,
+	/// Duplicate route name registrations that were rejected.
+	conflicts: Vec<String>,
 }
 
 impl UrlReverser {
@@ -34,6 +39,8 @@ impl UrlReverser {
 		Self {
 			routes: HashMap::new(),
 			aliases: HashMap::new(),
+			app_instances: HashMap::new(),
+			conflicts: Vec::new(),
 		}
 	}
 
@@ -51,12 +58,11 @@ impl UrlReverser {
 		if let Some(full_name) = route.full_name() {
 			use std::collections::hash_map::Entry;
 			match self.routes.entry(full_name.clone()) {
-				Entry::Occupied(existing) => Err(format!(
-					"Duplicate route name '{}': path '{}' conflicts with existing path '{}'",
-					full_name,
-					route.path,
-					existing.get().path
-				)),
+				Entry::Occupied(existing) => {
+					let error = duplicate_name_error(&full_name, &route.path, &existing.get().path);
+					self.conflicts.push(error.clone());
+					Err(error)
+				}
 				Entry::Vacant(entry) => {
 					entry.insert(route);
 					Ok(())
@@ -156,12 +162,11 @@ impl UrlReverser {
 
 		use std::collections::hash_map::Entry;
 		match self.routes.entry(qualified_name.clone()) {
-			Entry::Occupied(existing) => Err(format!(
-				"Duplicate route name '{}': path '{}' conflicts with existing path '{}'",
-				qualified_name,
-				path,
-				existing.get().path
-			)),
+			Entry::Occupied(existing) => {
+				let error = duplicate_name_error(&qualified_name, path, &existing.get().path);
+				self.conflicts.push(error.clone());
+				Err(error)
+			}
 			Entry::Vacant(entry) => {
 				entry.insert(route);
 				Ok(())
@@ -208,10 +213,59 @@ impl UrlReverser {
 	/// assert_eq!(url, "/users/123/");
 	/// ```
 	pub fn reverse(&self, name: &str, params: &HashMap<String, String>) -> ReverseResult<String> {
+		self.reverse_in(name, params, None)
+	}
+
+	/// Reverse a URL name from within the application instance `current_app`
+	/// Similar to the `current_app` argument of Django's reverse()
+	///
+	/// When the first segment of `name` is an application namespace (see
+	/// [`register_app_instance`](Self::register_app_instance)), it is resolved
+	/// to an instance namespace: `current_app` if it is an instance of that
+	/// application, otherwise the default instance (named like the
+	/// application), otherwise the most recently registered instance.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_urls::routers::UrlReverser;
+	/// use std::collections::HashMap;
+	///
+	/// let mut reverser = UrlReverser::new();
+	/// reverser.register_path("polls:detail", "/polls/{id}/").unwrap();
+	/// reverser.register_path("staff-polls:detail", "/staff/polls/{id}/").unwrap();
+	/// reverser.register_app_instance("polls", "polls");
+	/// reverser.register_app_instance("polls", "staff-polls");
+	///
+	/// let mut params = HashMap::new();
+	/// params.insert("id".to_string(), "7".to_string());
+	///
+	/// let url = reverser.reverse_in_app("polls:detail", &params, "staff-polls").unwrap();
+	/// assert_eq!(url, "/staff/polls/7/");
+	/// let url = reverser.reverse("polls:detail", &params).unwrap();
+	/// assert_eq!(url, "/polls/7/");
+	/// ```
+	pub fn reverse_in_app(
+		&self,
+		name: &str,
+		params: &HashMap<String, String>,
+		current_app: &str,
+	) -> ReverseResult<String> {
+		self.reverse_in(name, params, Some(current_app))
+	}
+
+	fn reverse_in(
+		&self,
+		name: &str,
+		params: &HashMap<String, String>,
+		current_app: Option<&str>,
+	) -> ReverseResult<String> {
+		let resolved = self.resolve_app_namespace(name, current_app);
+
 		// Prefer canonical route name; fall back to alias resolution only
 		// when the direct lookup misses. This prevents an alias entry from
 		// shadowing a real route with the same key.
-		let route = if let Some(r) = self.routes.get(name) {
+		let route = if let Some(r) = self.routes.get(resolved.as_ref()) {
 			r
 		} else {
 			let resolved_name = self.aliases.get(name).map(|s| s.as_str()).unwrap_or(name);
@@ -271,6 +325,57 @@ impl UrlReverser {
 		self.reverse(name, &params_map)
 	}
 
+	/// Replace the application namespace at the start of `name`, if any,
+	/// with the instance namespace it resolves to
+	fn resolve_app_namespace<'a>(&self, name: &'a str, current_app: Option<&str>) -> Cow<'a, str> {
+		let Some((namespace, rest)) = name.split_once(':') else {
+			return Cow::Borrowed(name);
+		};
+		let Some(instances) = self.app_instances.get(namespace) else {
+			return Cow::Borrowed(name);
+		};
+		let instance = current_app
+			.and_then(|current| instances.iter().find(|instance| *instance == current))
+			.or_else(|| instances.iter().find(|instance| *instance == namespace))
+			.or_else(|| instances.last());
+		match instance {
+			Some(instance) => Cow::Owned(format!("{instance}:{rest}")),
+			None => Cow::Borrowed(name),
+		}
+	}
+
+	/// Record `instance` as a deployment of the application namespace `app`
+	///
+	/// Routes of the instance must be registered under the instance namespace
+	/// (e.g. `"staff-polls:detail"`); `reverse("polls:detail")` then resolves
+	/// through the application namespace. Mounting the same application
+	/// several times registers one instance per mount.
+	pub fn register_app_instance(&mut self, app: &str, instance: &str) {
+		self.app_instances
+			.entry(app.to_string())
+			.or_default()
+			.push(instance.to_string());
+	}
+
+	/// Instance namespaces registered for the application namespace `app`
+	pub fn app_instances(&self, app: &str) -> &[String] {
+		self.app_instances.get(app).map_or(&[], Vec::as_slice)
+	}
+
+	/// All application namespaces with their instance namespaces
+	pub fn app_namespaces(&self) -> impl Iterator<Item = (&str, &[String])> {
+		self.app_instances
+			.iter()
+			.map(|(app, instances)| (app.as_str(), instances.as_slice()))
+	}
+
+	/// Duplicate route name registrations rejected so far
+	///
+	/// Reported as errors by [`UrlConfCheck`](crate::routers::UrlConfCheck).
+	pub fn conflicts(&self) -> &[String] {
+		&self.conflicts
+	}
+
 	/// Check if a route name is registered
 	pub fn has_route(&self, name: &str) -> bool {
 		self.routes.contains_key(name)
@@ -371,6 +476,13 @@ pub fn reverse(
 	reverser.reverse(name, params)
 }
 
+fn duplicate_name_error(name: &str, path: &str, existing_path: &str) -> String {
+	format!(
+		"Duplicate route name '{}': path '{}' conflicts with existing path '{}'",
+		name, path, existing_path
+	)
+}
+
 /// Returns `true` if `segment` follows the kebab-case convention.
 ///
 /// A segment is kebab-case when it contains no underscores and no ASCII
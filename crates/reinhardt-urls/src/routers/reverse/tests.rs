@@ -981,3 +981,70 @@ fn register_route_strips_optout_sigil_from_name() {
 	assert!(!reverser.has_route("!user_detail"));
 	assert_eq!(url, "/users/3/");
 }
+
+fn polls_reverser(instances: &[&str]) -> UrlReverser {
+	let mut reverser = UrlReverser::new();
+	for instance in instances {
+		reverser
+			.register_path(
+				&format!("{instance}:detail"),
+				&format!("/{instance}/{{id}}/"),
+			)
+			.unwrap();
+		reverser.register_app_instance("polls", instance);
+	}
+	reverser
+}
+
+#[rstest]
+#[case(&["polls", "staff-polls"], None, "/polls/7/")]
+#[case(&["author-polls", "staff-polls"], None, "/staff-polls/7/")]
+#[case(&["polls", "staff-polls"], Some("staff-polls"), "/staff-polls/7/")]
+#[case(&["polls", "staff-polls"], Some("blog"), "/polls/7/")]
+fn reverse_resolves_app_namespace_to_instance(
+	#[case] instances: &[&str],
+	#[case] current_app: Option<&str>,
+	#[case] expected: &str,
+) {
+	// Arrange
+	let reverser = polls_reverser(instances);
+	let params = HashMap::from([("id".to_string(), "7".to_string())]);
+
+	// Act
+	let url = match current_app {
+		Some(current_app) => reverser.reverse_in_app("polls:detail", &params, current_app),
+		None => reverser.reverse("polls:detail", &params),
+	};
+
+	// Assert
+	assert_eq!(url.unwrap(), expected);
+}
+
+#[rstest]
+fn reverse_by_instance_namespace_ignores_current_app() {
+	// Arrange
+	let reverser = polls_reverser(&["polls", "staff-polls"]);
+	let params = HashMap::from([("id".to_string(), "7".to_string())]);
+
+	// Act
+	let url = reverser.reverse_in_app("staff-polls:detail", &params, "polls");
+
+	// Assert
+	assert_eq!(url.unwrap(), "/staff-polls/7/");
+}
+
+#[rstest]
+fn duplicate_route_names_are_recorded_as_conflicts() {
+	// Arrange
+	let mut reverser = UrlReverser::new();
+	reverser
+		.register_path("polls:detail", "/polls/{id}/")
+		.unwrap();
+
+	// Act
+	let result = reverser.register_path("polls:detail", "/questions/{id}/");
+
+	// Assert
+	assert!(result.is_err());
+	assert_eq!(reverser.conflicts(), [result.unwrap_err()]);
+}
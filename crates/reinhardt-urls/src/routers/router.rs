@@ -1,4 +1,4 @@
-use super::{PathMatcher, PathPattern, Route};
+use super::{IncludedRouter, PathMatcher, PathPattern, Route};
 use async_trait::async_trait;
 use reinhardt_http::{Handler, Request, Response, Result};
 #[cfg(feature = "viewsets")]
@@ -139,6 +139,70 @@ impl DefaultRouter {
 	) -> super::reverse::ReverseResult<String> {
 		self.reverser.reverse_with(name, params)
 	}
+
+	/// Reverse a URL name from within the application instance `current_app`
+	///
+	/// See [`UrlReverser::reverse_in_app`](super::reverse::UrlReverser::reverse_in_app).
+	pub fn reverse_in_app(
+		&self,
+		name: &str,
+		params: &std::collections::HashMap<String, String>,
+		current_app: &str,
+	) -> super::reverse::ReverseResult<String> {
+		self.reverser.reverse_in_app(name, params, current_app)
+	}
+
+	/// Mount the routes of an [`IncludedRouter`] under its prefix
+	/// Similar to Django's include() with `app_name` and `namespace`
+	///
+	/// Routes are registered under the instance namespace. When an
+	/// application namespace is set, the instance is recorded for it so the
+	/// same app can be mounted several times and still be reversed as
+	/// `"<app>:<name>"`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_urls::routers::{DefaultRouter, include_routes, path};
+	/// use std::collections::HashMap;
+	///
+	/// # use async_trait::async_trait;
+	/// # use reinhardt_http::{Handler, Request, Response, Result};
+	/// # struct DummyHandler;
+	/// # #[async_trait]
+	/// # impl Handler for DummyHandler {
+	/// #     async fn handle(&self, _req: Request) -> Result<Response> {
+	/// #         Ok(Response::ok())
+	/// #     }
+	/// # }
+	/// fn polls_routes() -> Vec<reinhardt_urls::routers::Route> {
+	///     let mut detail = path("/{id}/", DummyHandler);
+	///     detail.name = Some("detail".to_string());
+	///     vec![detail]
+	/// }
+	///
+	/// let mut router = DefaultRouter::new();
+	/// router.include(include_routes("/polls", polls_routes()).with_app_namespace("polls"));
+	/// router.include(
+	///     include_routes("/staff/polls", polls_routes())
+	///         .with_app_namespace("polls")
+	///         .with_namespace("staff-polls"),
+	/// );
+	///
+	/// let params = HashMap::from([("id".to_string(), "7".to_string())]);
+	/// assert_eq!(router.reverse("polls:detail", &params).unwrap(), "/polls/7/");
+	/// assert_eq!(
+	///     router.reverse_in_app("polls:detail", &params, "staff-polls").unwrap(),
+	///     "/staff/polls/7/"
+	/// );
+	/// ```
+	pub fn include(&mut self, included: IncludedRouter) {
+		let instance = included.instance_namespace().map(str::to_string);
+		if let (Some(app), Some(instance)) = (&included.app_namespace, &instance) {
+			self.reverser.register_app_instance(app, instance);
+		}
+		self.mount(&included.prefix, included.routes, instance);
+	}
 	/// Register a ViewSet with automatic URL pattern generation
 	/// Similar to DRF's router.register()
 	///
@@ -724,4 +788,42 @@ mod tests {
 		assert!(versions.contains(&"2".to_string()));
 		assert_eq!(versions.len(), 2);
 	}
+
+	#[test]
+	fn test_include_same_app_twice() {
+		let handler = std::sync::Arc::new(DummyHandler);
+		let polls_routes = || {
+			let mut detail = path(path_macro!("/{id}/"), handler.clone());
+			detail.name = Some("detail".to_string());
+			vec![detail]
+		};
+		let mut router = DefaultRouter::new();
+
+		router.include(
+			crate::routers::include_routes("/polls", polls_routes()).with_app_namespace("polls"),
+		);
+		router.include(
+			crate::routers::include_routes("/staff/polls", polls_routes())
+				.with_app_namespace("polls")
+				.with_namespace("staff-polls"),
+		);
+
+		let params = std::collections::HashMap::from([("id".to_string(), "7".to_string())]);
+		assert_eq!(router.get_routes().len(), 2);
+		assert_eq!(
+			router.reverser().app_instances("polls"),
+			["polls", "staff-polls"]
+		);
+		assert_eq!(
+			router.reverse("polls:detail", &params).unwrap(),
+			"/polls/7/"
+		);
+		assert_eq!(
+			router
+				.reverse_in_app("polls:detail", &params, "staff-polls")
+				.unwrap(),
+			"/staff/polls/7/"
+		);
+		assert!(router.reverser().conflicts().is_empty());
+	}
 }
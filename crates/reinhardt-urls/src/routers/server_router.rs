@@ -31,8 +31,8 @@
 //! - `types`    — `MiddlewareInfo`, `RouteInfo`, `FunctionRoute`, `ViewRoute`,
 //!   `RouteHandler`, `RouteMatch`, and the `join_path` helper
 //! - `builder` — constructors and builder-style configuration
-//!   (`new`, `with_prefix`, `with_namespace`, `with_di_context`,
-//!   `with_middleware`, `exclude`, `mount`, `group`)
+//!   (`new`, `with_prefix`, `with_namespace`, `with_app_namespace`, `with_di_context`,
+//!   `with_exception_handlers`, `with_middleware`, `exclude`, `mount`, `group`)
 //! - `registration` — route registration entry points
 //!   (`endpoint`, `handler`, `viewset`, `view`, `with_route_middleware`)
 //! - `compile` — matchit compilation and `validate_*` helpers
@@ -47,6 +47,7 @@
 use crate::routers::UrlReverser;
 use matchit::Router as MatchitRouter;
 use reinhardt_di::InjectionContext;
+use reinhardt_http::ExceptionHandlers;
 use reinhardt_middleware::Middleware;
 #[cfg(feature = "viewsets")]
 use std::collections::HashMap;
@@ -146,6 +147,9 @@ pub struct ServerRouter {
 	/// Namespace for URL reversal
 	pub(crate) namespace: Option<String>,
 
+	/// Application namespace shared by every mount of the same app
+	pub(crate) app_namespace: Option<String>,
+
 	/// Routes defined in this router
 	pub(crate) routes: Vec<crate::routers::Route>,
 
@@ -175,6 +179,10 @@ pub struct ServerRouter {
 	/// described in #4426.
 	pub(crate) pending_middleware_di: reinhardt_di::DiRegistrationList,
 
+	/// Exception handlers for errors returned by this router's routes,
+	/// inherited by child routers that have none of their own
+	pub(crate) exception_handlers: Option<Arc<ExceptionHandlers>>,
+
 	/// Middleware stack
 	pub(crate) middleware: Vec<Arc<dyn Middleware>>,
 
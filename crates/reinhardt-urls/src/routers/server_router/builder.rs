@@ -8,7 +8,7 @@ use super::types::MiddlewareInfo;
 use crate::routers::UrlReverser;
 use matchit::Router as MatchitRouter;
 use reinhardt_di::InjectionContext;
-use reinhardt_http::ExcludeMiddleware;
+use reinhardt_http::{ExceptionHandlers, ExcludeMiddleware};
 use reinhardt_middleware::Middleware;
 #[cfg(feature = "viewsets")]
 use std::collections::HashMap;
@@ -83,6 +83,7 @@ impl ServerRouter {
 		Self {
 			prefix: String::new(),
 			namespace: None,
+			app_namespace: None,
 			routes: Vec::new(),
 			#[cfg(feature = "viewsets")]
 			viewsets: HashMap::new(),
@@ -91,6 +92,7 @@ impl ServerRouter {
 			children: Vec::new(),
 			di_context: None,
 			pending_middleware_di: reinhardt_di::DiRegistrationList::new(),
+			exception_handlers: None,
 			middleware: Vec::new(),
 			middleware_names: Vec::new(),
 			middleware_exclusions: Vec::new(),
@@ -136,6 +138,29 @@ impl ServerRouter {
 		self
 	}
 
+	/// Set the application namespace for this router
+	///
+	/// Mount the same app several times with distinct namespaces (instance
+	/// namespaces) and a shared application namespace; `reverse("polls:detail")`
+	/// then resolves to the instance given as current app, or to the default
+	/// instance. Without [`with_namespace`](Self::with_namespace), the
+	/// application namespace is also used as the instance namespace.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_urls::routers::ServerRouter;
+	///
+	/// let router = ServerRouter::new()
+	///     .with_app_namespace("polls")
+	///     .with_namespace("staff-polls");
+	/// assert_eq!(router.app_namespace(), Some("polls"));
+	/// ```
+	pub fn with_app_namespace(mut self, app_namespace: impl Into<String>) -> Self {
+		self.app_namespace = Some(app_namespace.into());
+		self
+	}
+
 	/// Set the DI context for this router
 	///
 	/// # Examples
@@ -196,6 +221,29 @@ impl ServerRouter {
 		}
 	}
 
+	/// Set the exception handlers for errors returned by this router's routes
+	///
+	/// They apply to the routes of child routers too, unless a child has
+	/// handlers of its own. Errors none of them handle are passed on to the
+	/// global handlers.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_core::exception::ErrorKind;
+	/// use reinhardt_http::{ExceptionHandlers, Response};
+	/// use reinhardt_urls::routers::ServerRouter;
+	///
+	/// let router = ServerRouter::new().with_exception_handlers(
+	///     ExceptionHandlers::new()
+	///         .register_kind(ErrorKind::Authentication, |_| Response::see_other("/login/")),
+	/// );
+	/// ```
+	pub fn with_exception_handlers(mut self, handlers: ExceptionHandlers) -> Self {
+		self.exception_handlers = Some(Arc::new(handlers));
+		self
+	}
+
 	/// Returns a reference to the DI context, if set.
 	pub(crate) fn di_context(&self) -> Option<&Arc<InjectionContext>> {
 		self.di_context.as_ref()
@@ -42,6 +42,7 @@ impl ServerRouter {
 			let route_handler = RouteHandler {
 				handler: func_route.handler.clone(),
 				middleware: func_route.middleware.clone(),
+				csrf_exempt: func_route.csrf_exempt,
 			};
 
 			// Strip prefix from route path to avoid double-prefix matching.
@@ -81,6 +82,7 @@ impl ServerRouter {
 			let route_handler = RouteHandler {
 				handler: view_route.handler.clone(),
 				middleware: view_route.middleware.clone(),
+				csrf_exempt: false,
 			};
 
 			// Strip prefix from route path (same reason as endpoint routes above)
@@ -114,6 +116,7 @@ impl ServerRouter {
 			let route_handler = RouteHandler {
 				handler: route.handler_arc(),
 				middleware: route.middleware.clone(),
+				csrf_exempt: false,
 			};
 
 			// Strip prefix from route path (same reason as endpoint routes above)
@@ -169,6 +172,7 @@ impl ServerRouter {
 					action: Action::list(),
 				}),
 				middleware: Vec::new(),
+				csrf_exempt: false,
 			};
 			if let Err(e) = self
 				.get_router
@@ -188,6 +192,7 @@ impl ServerRouter {
 					action: Action::create(),
 				}),
 				middleware: Vec::new(),
+				csrf_exempt: false,
 			};
 			if let Err(e) = self
 				.post_router
@@ -210,6 +215,7 @@ impl ServerRouter {
 					action: Action::retrieve(),
 				}),
 				middleware: Vec::new(),
+				csrf_exempt: false,
 			};
 			if let Err(e) = self
 				.get_router
@@ -229,6 +235,7 @@ impl ServerRouter {
 					action: Action::update(),
 				}),
 				middleware: Vec::new(),
+				csrf_exempt: false,
 			};
 			if let Err(e) = self
 				.put_router
@@ -248,6 +255,7 @@ impl ServerRouter {
 					action: Action::destroy(),
 				}),
 				middleware: Vec::new(),
+				csrf_exempt: false,
 			};
 			if let Err(e) = self
 				.delete_router
@@ -7,7 +7,7 @@ use super::ServerRouter;
 use super::types::RouteMatch;
 use hyper::Method;
 use reinhardt_di::InjectionContext;
-use reinhardt_http::PathParams;
+use reinhardt_http::{ExceptionHandlers, PathParams};
 use reinhardt_middleware::Middleware;
 use std::borrow::Cow;
 use std::sync::{Arc, PoisonError};
@@ -59,9 +59,13 @@ impl ServerRouter {
 		// 2. Try child routers first
 		let own_middleware = self.build_middleware_with_exclusions();
 		for child in &self.children {
-			if let Some(route_match) =
-				child.resolve_internal(&remaining_path, method, &own_middleware, &self.di_context)
-			{
+			if let Some(route_match) = child.resolve_internal(
+				&remaining_path,
+				method,
+				&own_middleware,
+				&self.di_context,
+				&self.exception_handlers,
+			) {
 				return Some(route_match);
 			}
 		}
@@ -72,16 +76,19 @@ impl ServerRouter {
 			method,
 			own_middleware,
 			self.di_context.clone(),
+			self.exception_handlers.clone(),
 		)
 	}
 
-	/// Internal route resolution with middleware and DI context inheritance
+	/// Internal route resolution with middleware, DI context and exception
+	/// handler inheritance
 	pub(crate) fn resolve_internal(
 		&self,
 		path: &str,
 		method: &Method,
 		parent_middleware: &[Arc<dyn Middleware>],
 		parent_di: &Option<Arc<InjectionContext>>,
+		parent_exception_handlers: &Option<Arc<ExceptionHandlers>>,
 	) -> Option<RouteMatch> {
 		// Check prefix and normalize remaining path (ensures leading `/`)
 		let remaining_path = Self::strip_prefix_normalized(&self.prefix, path)?;
@@ -92,18 +99,32 @@ impl ServerRouter {
 
 		// Inherit DI context
 		let di_context = self.di_context.clone().or_else(|| parent_di.clone());
+		let exception_handlers = self
+			.exception_handlers
+			.clone()
+			.or_else(|| parent_exception_handlers.clone());
 
 		// Try child routers
 		for child in &self.children {
-			if let Some(route_match) =
-				child.resolve_internal(&remaining_path, method, &middleware_stack, &di_context)
-			{
+			if let Some(route_match) = child.resolve_internal(
+				&remaining_path,
+				method,
+				&middleware_stack,
+				&di_context,
+				&exception_handlers,
+			) {
 				return Some(route_match);
 			}
 		}
 
 		// Try own routes
-		self.match_own_routes_with_context(&remaining_path, method, middleware_stack, di_context)
+		self.match_own_routes_with_context(
+			&remaining_path,
+			method,
+			middleware_stack,
+			di_context,
+			exception_handlers,
+		)
 	}
 
 	/// Match routes in this router (without context)
@@ -114,6 +135,7 @@ impl ServerRouter {
 			method,
 			self.build_middleware_with_exclusions(),
 			self.di_context.clone(),
+			self.exception_handlers.clone(),
 		)
 	}
 
@@ -127,6 +149,7 @@ impl ServerRouter {
 		method: &Method,
 		middleware_stack: Vec<Arc<dyn Middleware>>,
 		di_context: Option<Arc<InjectionContext>>,
+		exception_handlers: Option<Arc<ExceptionHandlers>>,
 	) -> Option<RouteMatch> {
 		// Compile routes on first use (lazy compilation with interior mutability)
 		self.compile_routes();
@@ -176,6 +199,8 @@ impl ServerRouter {
 					params,
 					middleware_stack: combined_middleware,
 					di_context,
+					exception_handlers,
+					csrf_exempt: route_handler.csrf_exempt,
 				});
 			}};
 		}
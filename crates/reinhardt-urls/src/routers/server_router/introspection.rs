@@ -24,6 +24,17 @@ impl ServerRouter {
 		self.namespace.as_deref()
 	}
 
+	/// Get the application namespace of this router
+	pub fn app_namespace(&self) -> Option<&str> {
+		self.app_namespace.as_deref()
+	}
+
+	/// The namespace routes are registered under: the instance namespace, or
+	/// the application namespace when only that one is set
+	fn instance_namespace(&self) -> Option<&str> {
+		self.namespace.as_deref().or(self.app_namespace.as_deref())
+	}
+
 	/// Get the number of child routers
 	pub fn children_count(&self) -> usize {
 		self.children.len()
@@ -64,6 +75,7 @@ impl ServerRouter {
 	/// ```
 	pub fn get_all_routes(&self) -> RouteInfo {
 		let mut routes = Vec::new();
+		let namespace = self.instance_namespace().map(str::to_string);
 
 		// Collect routes from this router
 		for route in &self.routes {
@@ -76,7 +88,7 @@ impl ServerRouter {
 			routes.push((
 				full_path,
 				introspection_route_name(&route.name),
-				route.namespace.clone().or_else(|| self.namespace.clone()),
+				route.namespace.clone().or_else(|| namespace.clone()),
 				vec![], // Method-agnostic handlers accept all HTTP methods (shown as "ALL" in showurls)
 			));
 		}
@@ -92,7 +104,7 @@ impl ServerRouter {
 			routes.push((
 				full_path,
 				introspection_route_name(&func_route.name),
-				self.namespace.clone(), // Use router's namespace
+				namespace.clone(), // Use router's namespace
 				vec![func_route.method.clone()],
 			));
 		}
@@ -107,9 +119,9 @@ impl ServerRouter {
 
 			routes.push((
 				full_path,
-				None,                   // View routes don't have names
-				self.namespace.clone(), // Use router's namespace
-				vec![], // Class-based views handle method dispatch internally (accepts all methods)
+				None,              // View routes don't have names
+				namespace.clone(), // Use router's namespace
+				vec![],            // Class-based views handle method dispatch internally (accepts all methods)
 			));
 		}
 
@@ -137,8 +149,8 @@ impl ServerRouter {
 			for (path, methods) in viewset_routes {
 				routes.push((
 					path,
-					None,                   // ViewSet routes don't have individual names
-					self.namespace.clone(), // Use router's namespace
+					None,              // ViewSet routes don't have individual names
+					namespace.clone(), // Use router's namespace
 					methods,
 				));
 			}
@@ -160,9 +172,9 @@ impl ServerRouter {
 				};
 
 				// Combine namespaces (parent:child)
-				let combined_namespace = match (self.namespace.as_ref(), namespace.as_ref()) {
+				let combined_namespace = match (self.instance_namespace(), namespace.as_ref()) {
 					(Some(parent), Some(child)) => Some(format!("{}:{}", parent, child)),
-					(Some(parent), None) => Some(parent.clone()),
+					(Some(parent), None) => Some(parent.to_string()),
 					(None, Some(child)) => Some(child.clone()),
 					(None, None) => None,
 				};
@@ -192,7 +204,7 @@ impl ServerRouter {
 	/// assert_eq!(router.get_full_namespace(None), Some("users".to_string()));
 	/// ```
 	pub fn get_full_namespace(&self, parent_namespace: Option<&str>) -> Option<String> {
-		match (parent_namespace, self.namespace.as_deref()) {
+		match (parent_namespace, self.instance_namespace()) {
 			(Some(parent), Some(child)) => Some(format!("{}:{}", parent, child)),
 			(Some(parent), None) => Some(parent.to_string()),
 			(None, Some(child)) => Some(child.to_string()),
@@ -231,9 +243,29 @@ impl ServerRouter {
 				errors.push(e);
 			}
 		}
+		for (app, instance) in self.collect_app_instances_recursive(None) {
+			self.reverser.register_app_instance(&app, &instance);
+		}
 		errors
 	}
 
+	/// Recursively collect `(app_namespace, full_instance_namespace)` pairs
+	/// for this router and its children.
+	fn collect_app_instances_recursive(
+		&self,
+		parent_namespace: Option<&str>,
+	) -> Vec<(String, String)> {
+		let full_namespace = self.get_full_namespace(parent_namespace);
+		let mut instances = Vec::new();
+		if let (Some(app), Some(instance)) = (&self.app_namespace, &full_namespace) {
+			instances.push((app.clone(), instance.clone()));
+		}
+		for child in &self.children {
+			instances.extend(child.collect_app_instances_recursive(full_namespace.as_deref()));
+		}
+		instances
+	}
+
 	/// Recursively drain middleware-contributed DI registrations that were
 	/// staged before a context could be attached, pushing them to the global
 	/// deferred list when the owning subtree has no `InjectionContext`. See
@@ -450,6 +482,37 @@ impl ServerRouter {
 
 		None
 	}
+
+	/// Reverse a URL by route name from within the application instance
+	/// `current_app`
+	///
+	/// Application namespaces set with
+	/// [`with_app_namespace`](Self::with_app_namespace) resolve to
+	/// `current_app` when it is one of their instances. See
+	/// [`UrlReverser::reverse_in_app`](crate::routers::UrlReverser::reverse_in_app).
+	///
+	/// # Examples
+	///
+	/// ```ignore
+	/// let url = router
+	///     .reverse_in_app("polls:detail", &[("id", "7")], "staff-polls")
+	///     .unwrap();
+	/// assert_eq!(url, "/staff/polls/7/");
+	/// ```
+	pub fn reverse_in_app(
+		&self,
+		name: &str,
+		params: &[(&str, &str)],
+		current_app: &str,
+	) -> Option<String> {
+		let params = params
+			.iter()
+			.map(|(key, value)| (key.to_string(), value.to_string()))
+			.collect();
+		self.reverser
+			.reverse_in_app(name, &params, current_app)
+			.ok()
+	}
 }
 
 #[cfg(all(test, feature = "viewsets"))]
@@ -133,6 +133,7 @@ impl ServerRouter {
 			handler: Arc::new(view),
 			name: Some(name),
 			middleware: Vec::new(),
+			csrf_exempt: E::csrf_exempt(),
 		});
 		self
 	}
@@ -8,7 +8,7 @@ use super::ServerRouter;
 #[cfg(feature = "viewsets")]
 use super::types::ViewRoute;
 use async_trait::async_trait;
-use reinhardt_http::{Error, Handler, MiddlewareChain, Request, Response, Result};
+use reinhardt_http::{CsrfExempt, Error, Handler, MiddlewareChain, Request, Response, Result};
 use std::sync::Arc;
 
 impl std::fmt::Debug for ServerRouter {
@@ -88,13 +88,18 @@ impl Handler for ServerRouter {
 
 		req.path_params = route_match.params;
 
+		// Let CSRF middleware skip validation for `#[csrf_exempt]` handlers
+		if route_match.csrf_exempt {
+			req.extensions.insert(CsrfExempt);
+		}
+
 		// Set DI context if available
 		if let Some(di_ctx) = &route_match.di_context {
 			req.set_di_context(di_ctx.clone());
 		}
 
 		// Apply middleware stack using MiddlewareChain
-		if route_match.middleware_stack.is_empty() {
+		let result = if route_match.middleware_stack.is_empty() {
 			// No middleware, execute handler directly
 			route_match.handler.handle(req).await
 		} else {
@@ -105,6 +110,12 @@ impl Handler for ServerRouter {
 
 			// Execute chain
 			chain.handle(req).await
+		};
+
+		// Errors the scoped handlers don't claim fall through to the global ones
+		match (result, &route_match.exception_handlers) {
+			(Err(error), Some(handlers)) => handlers.handle(&error).ok_or(error),
+			(result, _) => result,
 		}
 	}
 }
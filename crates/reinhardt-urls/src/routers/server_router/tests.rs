@@ -294,6 +294,36 @@ fn test_nested_namespace_registration() {
 	assert_eq!(url.unwrap(), "/api/v1/users/list");
 }
 
+#[rstest]
+fn test_app_namespace_mounted_twice() {
+	// Arrange
+	let polls = || {
+		ServerRouter::new()
+			.with_app_namespace("polls")
+			.endpoint(|| TestEndpoint::<2>)
+	};
+	let mut router = ServerRouter::new()
+		.mount("/polls/", polls())
+		.mount("/staff/", polls().with_namespace("staff-polls"));
+
+	// Act
+	let errors = router.register_all_routes();
+
+	// Assert
+	assert!(errors.is_empty());
+	assert_eq!(
+		router.reverser().app_instances("polls"),
+		["polls", "staff-polls"]
+	);
+	assert_eq!(router.reverse("polls:list", &[]).unwrap(), "/polls/list");
+	assert_eq!(
+		router
+			.reverse_in_app("polls:list", &[], "staff-polls")
+			.unwrap(),
+		"/staff/list"
+	);
+}
+
 #[rstest]
 fn test_mount_prefix_inheritance() {
 	// Arrange
@@ -405,6 +435,52 @@ async fn test_route_matching_preserves_url_pattern_order_issue_4013() {
 	);
 }
 
+struct WebhookEndpoint;
+
+impl EndpointInfo for WebhookEndpoint {
+	fn path() -> &'static str {
+		"/hooks/"
+	}
+
+	fn method() -> Method {
+		Method::POST
+	}
+
+	fn name() -> &'static str {
+		"webhook"
+	}
+
+	fn csrf_exempt() -> bool {
+		true
+	}
+}
+
+#[async_trait::async_trait]
+impl Handler for WebhookEndpoint {
+	async fn handle(&self, _req: Request) -> Result<Response> {
+		Ok(Response::ok())
+	}
+}
+
+#[rstest]
+#[case::exempt("/hooks/", true)]
+#[case::not_exempt("/posts", false)]
+fn test_route_match_carries_csrf_exemption(#[case] path: &str, #[case] expected: bool) {
+	// Arrange
+	let router = ServerRouter::new()
+		.endpoint(|| WebhookEndpoint)
+		.endpoint(|| TestEndpoint::<8>);
+	router.compile_routes();
+
+	// Act
+	let route_match = router
+		.match_own_routes(path, &Method::POST)
+		.expect("route should match");
+
+	// Assert
+	assert_eq!(route_match.csrf_exempt, expected);
+}
+
 #[tokio::test]
 async fn test_route_matching_different_methods() {
 	// Arrange
@@ -1284,3 +1360,46 @@ fn test_validate_routes_includes_name_errors() {
 	let errors = result.unwrap_err();
 	assert!(errors.iter().any(|e| e.contains("Duplicate route name")));
 }
+
+// --- ServerRouter::with_exception_handlers() tests ---
+
+struct FailingHandler;
+
+#[async_trait::async_trait]
+impl Handler for FailingHandler {
+	async fn handle(&self, request: Request) -> Result<Response> {
+		match request.uri.path() {
+			"/api/private/" => Err(reinhardt_http::Error::Authorization(
+				"staff only".to_string(),
+			)),
+			_ => Err(reinhardt_http::Error::Conflict("duplicate".to_string())),
+		}
+	}
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_exception_handlers_apply_to_mounted_routes() {
+	// Arrange
+	let router = ServerRouter::new()
+		.with_exception_handlers(
+			reinhardt_http::ExceptionHandlers::new()
+				.register_kind(reinhardt_core::exception::ErrorKind::Authorization, |_| {
+					Response::see_other("/login/")
+				}),
+		)
+		.mount(
+			"/api/",
+			ServerRouter::new()
+				.handler("/private/", FailingHandler)
+				.handler("/items/", FailingHandler),
+		);
+
+	// Act
+	let handled = router.handle(create_test_request("/api/private/")).await;
+	let unhandled = router.handle(create_test_request("/api/items/")).await;
+
+	// Assert
+	assert_eq!(handled.unwrap().status, hyper::StatusCode::SEE_OTHER);
+	assert!(matches!(unhandled, Err(reinhardt_http::Error::Conflict(_))));
+}
@@ -7,7 +7,7 @@
 
 use hyper::Method;
 use reinhardt_di::InjectionContext;
-use reinhardt_http::{Handler, PathParams};
+use reinhardt_http::{ExceptionHandlers, Handler, PathParams};
 use reinhardt_middleware::Middleware;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -94,6 +94,9 @@ pub(crate) struct RouteHandler {
 
 	/// Route-level middleware
 	pub(crate) middleware: Vec<Arc<dyn Middleware>>,
+
+	/// Whether the handler opted out of CSRF validation
+	pub(crate) csrf_exempt: bool,
 }
 
 /// Route match result with metadata
@@ -113,6 +116,12 @@ pub(crate) struct RouteMatch {
 
 	/// DI context
 	pub di_context: Option<Arc<InjectionContext>>,
+
+	/// Exception handlers of the innermost router that has any
+	pub exception_handlers: Option<Arc<ExceptionHandlers>>,
+
+	/// Whether the matched handler opted out of CSRF validation
+	pub csrf_exempt: bool,
 }
 
 impl RouteMatch {
@@ -139,6 +148,8 @@ pub(crate) struct FunctionRoute {
 	pub name: Option<String>,
 	/// Middleware stack for this route
 	pub middleware: Vec<Arc<dyn Middleware>>,
+	/// Whether the endpoint is `#[csrf_exempt]`
+	pub csrf_exempt: bool,
 }
 
 /// Class-based view route
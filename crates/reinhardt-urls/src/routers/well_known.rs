@@ -0,0 +1,363 @@
+//! `robots.txt` and `security.txt` handlers
+//!
+//! Serves the crawler policy at `/robots.txt` and the vulnerability
+//! disclosure contacts at `/.well-known/security.txt` (RFC 9116), both built
+//! from [`WellKnownSettings`]. The bodies are rendered once, when the router
+//! is built.
+//!
+//! Without explicit configuration, `robots.txt` disallows the whole site in
+//! every profile but production, and `security.txt` is not served until a
+//! contact is configured.
+//!
+//! Either file can be overridden with a template file
+//! (`well_known.robots.template`, `well_known.security_txt.template`); the
+//! `{{ default }}` placeholder expands to the body that would otherwise be
+//! generated. Relative template paths resolve against the working directory.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use reinhardt_conf::WellKnownSettings;
+//! use reinhardt_conf::settings::profile::Profile;
+//! use reinhardt_urls::routers::ServerRouter;
+//!
+//! let settings = WellKnownSettings::default();
+//! let router = ServerRouter::new()
+//!     .with_well_known(&settings, Profile::Staging)
+//!     .unwrap();
+//! ```
+
+use std::io;
+use std::path::Path;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::SecondsFormat;
+use hyper::{Method, StatusCode};
+use reinhardt_conf::WellKnownSettings;
+use reinhardt_conf::settings::profile::Profile;
+use reinhardt_conf::settings::well_known::{RobotsSettings, SecurityTxtSettings};
+use reinhardt_http::{Handler, Request, Response, Result};
+
+use super::ServerRouter;
+
+/// Path of the crawler policy
+pub const ROBOTS_TXT_PATH: &str = "/robots.txt";
+
+/// Path of the security contacts file
+pub const SECURITY_TXT_PATH: &str = "/.well-known/security.txt";
+
+/// Placeholder expanded to the generated body in template files
+pub const DEFAULT_PLACEHOLDER: &str = "{{ default }}";
+
+/// `Cache-Control` header sent with well-known files
+const CACHE_CONTROL: &str = "public, max-age=86400";
+
+/// Render the `robots.txt` rules for `profile`.
+pub fn render_robots_txt(settings: &RobotsSettings, profile: &Profile) -> String {
+	let mut lines = vec!["User-agent: *".to_string()];
+	if settings.disallows_all(profile) {
+		lines.push("Disallow: /".to_string());
+		return lines.join("\n") + "\n";
+	}
+
+	lines.extend(settings.allow.iter().map(|path| format!("Allow: {}", path)));
+	if settings.disallow.is_empty() {
+		// An empty Disallow line allows everything.
+		lines.push("Disallow:".to_string());
+	} else {
+		lines.extend(
+			settings
+				.disallow
+				.iter()
+				.map(|path| format!("Disallow: {}", path)),
+		);
+	}
+	if !settings.sitemaps.is_empty() {
+		lines.push(String::new());
+		lines.extend(
+			settings
+				.sitemaps
+				.iter()
+				.map(|url| format!("Sitemap: {}", url)),
+		);
+	}
+	lines.join("\n") + "\n"
+}
+
+/// Render the `security.txt` fields.
+pub fn render_security_txt(settings: &SecurityTxtSettings) -> String {
+	let mut lines: Vec<String> = settings
+		.contact
+		.iter()
+		.map(|contact| format!("Contact: {}", contact))
+		.collect();
+	match (settings.expires_at(), &settings.expires) {
+		(Some(expires), _) => lines.push(format!(
+			"Expires: {}",
+			expires.to_rfc3339_opts(SecondsFormat::Secs, true)
+		)),
+		(None, Some(expires)) => lines.push(format!("Expires: {}", expires)),
+		(None, None) => {}
+	}
+	for (field, values) in [
+		("Encryption", &settings.encryption),
+		("Acknowledgments", &settings.acknowledgments),
+		("Canonical", &settings.canonical),
+		("Policy", &settings.policy),
+		("Hiring", &settings.hiring),
+	] {
+		lines.extend(values.iter().map(|value| format!("{}: {}", field, value)));
+	}
+	if !settings.preferred_languages.is_empty() {
+		lines.push(format!(
+			"Preferred-Languages: {}",
+			settings.preferred_languages.join(", ")
+		));
+	}
+	lines.join("\n") + "\n"
+}
+
+/// Read `template` and expand [`DEFAULT_PLACEHOLDER`] to `default`.
+fn apply_template(template: Option<&Path>, default: String) -> io::Result<String> {
+	match template {
+		Some(path) => Ok(std::fs::read_to_string(path)?.replace(DEFAULT_PLACEHOLDER, &default)),
+		None => Ok(default),
+	}
+}
+
+/// Handler serving a pre-rendered plain-text well-known file
+#[derive(Debug, Clone)]
+pub struct WellKnownFileHandler {
+	body: Bytes,
+}
+
+impl WellKnownFileHandler {
+	/// Serve `body` verbatim
+	pub fn new(body: impl Into<String>) -> Self {
+		Self {
+			body: Bytes::from(body.into()),
+		}
+	}
+
+	/// `robots.txt` handler for `profile`
+	///
+	/// # Errors
+	///
+	/// Returns an error if the configured template cannot be read.
+	pub fn robots_txt(settings: &RobotsSettings, profile: &Profile) -> io::Result<Self> {
+		let body = apply_template(
+			settings.template.as_deref(),
+			render_robots_txt(settings, profile),
+		)?;
+		Ok(Self::new(body))
+	}
+
+	/// `security.txt` handler
+	///
+	/// # Errors
+	///
+	/// Returns an error if the configured template cannot be read.
+	pub fn security_txt(settings: &SecurityTxtSettings) -> io::Result<Self> {
+		let body = apply_template(settings.template.as_deref(), render_security_txt(settings))?;
+		Ok(Self::new(body))
+	}
+
+	/// Served body
+	pub fn body(&self) -> &str {
+		std::str::from_utf8(&self.body)
+			.expect("body was built from a String and cannot be invalid UTF-8")
+	}
+}
+
+#[async_trait]
+impl Handler for WellKnownFileHandler {
+	async fn handle(&self, request: Request) -> Result<Response> {
+		if request.method != Method::GET && request.method != Method::HEAD {
+			return Ok(
+				Response::new(StatusCode::METHOD_NOT_ALLOWED).with_header("Allow", "GET, HEAD")
+			);
+		}
+
+		let response = Response::ok()
+			.with_header("Content-Type", "text/plain; charset=utf-8")
+			.with_header("Cache-Control", CACHE_CONTROL);
+		if request.method == Method::HEAD {
+			return Ok(response);
+		}
+		Ok(response.with_body(self.body.clone()))
+	}
+}
+
+impl ServerRouter {
+	/// Register `/robots.txt` and `/.well-known/security.txt` from settings
+	///
+	/// `robots.txt` is registered unless `well_known.robots.enabled` is
+	/// false; `security.txt` only once a contact or template is configured.
+	///
+	/// # Errors
+	///
+	/// Returns an error if a configured template cannot be read.
+	pub fn with_well_known(
+		self,
+		settings: &WellKnownSettings,
+		profile: Profile,
+	) -> io::Result<Self> {
+		let mut router = self;
+		if settings.robots.enabled {
+			router = router.handler(
+				ROBOTS_TXT_PATH,
+				WellKnownFileHandler::robots_txt(&settings.robots, &profile)?,
+			);
+		}
+		if settings.security_txt.is_configured() {
+			router = router.handler(
+				SECURITY_TXT_PATH,
+				WellKnownFileHandler::security_txt(&settings.security_txt)?,
+			);
+		}
+		Ok(router)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+	use std::io::Write;
+
+	fn request(method: Method) -> Request {
+		Request::builder()
+			.method(method)
+			.uri(ROBOTS_TXT_PATH)
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	#[case::staging(Profile::Staging, "User-agent: *\nDisallow: /\n")]
+	#[case::production(
+		Profile::Production,
+		"User-agent: *\nAllow: /admin/login/\nDisallow: /admin/\n\nSitemap: https://example.com/sitemap.xml\n"
+	)]
+	fn test_render_robots_txt_per_profile(#[case] profile: Profile, #[case] expected: &str) {
+		// Arrange
+		let settings = RobotsSettings {
+			allow: vec!["/admin/login/".to_string()],
+			disallow: vec!["/admin/".to_string()],
+			sitemaps: vec!["https://example.com/sitemap.xml".to_string()],
+			..Default::default()
+		};
+
+		// Act
+		let body = render_robots_txt(&settings, &profile);
+
+		// Assert
+		assert_eq!(body, expected);
+	}
+
+	#[rstest]
+	fn test_render_robots_txt_allows_everything_by_default_in_production() {
+		// Act
+		let body = render_robots_txt(&RobotsSettings::default(), &Profile::Production);
+
+		// Assert
+		assert_eq!(body, "User-agent: *\nDisallow:\n");
+	}
+
+	#[rstest]
+	fn test_render_security_txt() {
+		// Arrange
+		let settings = SecurityTxtSettings {
+			contact: vec!["mailto:security@example.com".to_string()],
+			expires: Some("2030-01-01T09:00:00+09:00".to_string()),
+			policy: vec!["https://example.com/security-policy".to_string()],
+			preferred_languages: vec!["en".to_string(), "ja".to_string()],
+			..Default::default()
+		};
+
+		// Act
+		let body = render_security_txt(&settings);
+
+		// Assert
+		assert_eq!(
+			body,
+			"Contact: mailto:security@example.com\n\
+			 Expires: 2030-01-01T00:00:00Z\n\
+			 Policy: https://example.com/security-policy\n\
+			 Preferred-Languages: en, ja\n"
+		);
+	}
+
+	#[rstest]
+	fn test_template_expands_default_placeholder() {
+		// Arrange
+		let mut template = tempfile::NamedTempFile::new().unwrap();
+		write!(
+			template,
+			"# Crawlers welcome\n{{{{ default }}}}User-agent: Bad\nDisallow: /\n"
+		)
+		.unwrap();
+		let settings = RobotsSettings {
+			disallow_all: Some(false),
+			template: Some(template.path().to_path_buf()),
+			..Default::default()
+		};
+
+		// Act
+		let handler = WellKnownFileHandler::robots_txt(&settings, &Profile::Staging).unwrap();
+
+		// Assert
+		assert_eq!(
+			handler.body(),
+			"# Crawlers welcome\nUser-agent: *\nDisallow:\nUser-agent: Bad\nDisallow: /\n"
+		);
+	}
+
+	#[rstest]
+	#[case::get(Method::GET, StatusCode::OK, false)]
+	#[case::head(Method::HEAD, StatusCode::OK, true)]
+	#[case::post(Method::POST, StatusCode::METHOD_NOT_ALLOWED, true)]
+	#[tokio::test]
+	async fn test_handler_serves_plain_text(
+		#[case] method: Method,
+		#[case] expected: StatusCode,
+		#[case] empty_body: bool,
+	) {
+		// Arrange
+		let handler = WellKnownFileHandler::new("User-agent: *\nDisallow: /\n");
+
+		// Act
+		let response = handler.handle(request(method)).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, expected);
+		assert_eq!(response.body.is_empty(), empty_body);
+		if expected == StatusCode::OK {
+			assert_eq!(
+				response.headers["content-type"],
+				"text/plain; charset=utf-8"
+			);
+			assert_eq!(response.headers["cache-control"], CACHE_CONTROL);
+		}
+	}
+
+	#[rstest]
+	fn test_with_well_known_skips_unconfigured_security_txt() {
+		// Arrange
+		let settings = WellKnownSettings::default();
+
+		// Act
+		let router = ServerRouter::new()
+			.with_well_known(&settings, Profile::Staging)
+			.unwrap();
+
+		// Assert
+		let paths: Vec<String> = router
+			.get_all_routes()
+			.into_iter()
+			.map(|(path, ..)| path)
+			.collect();
+		assert_eq!(paths, vec![ROBOTS_TXT_PATH.to_string()]);
+	}
+}
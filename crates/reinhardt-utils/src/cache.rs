@@ -16,6 +16,8 @@
 //! - **Pub/Sub**: Cache invalidation via Redis channels (requires redis-backend feature)
 //! - **Cache Warming**: Pre-populate cache on startup
 //! - **Cache Tags**: Tag-based invalidation for related entries
+//! - **VersionedCache**: Versioned value envelopes with upgraders, so values cached
+//!   by a previous release read as misses instead of deserialization errors
 //! - TTL support for automatic expiration
 //! - Async-first API
 //!
@@ -95,6 +97,7 @@ mod statistics;
 
 pub mod file_backend;
 pub mod tags;
+pub mod versioned;
 pub mod warming;
 
 #[cfg(feature = "redis-backend")]
@@ -143,3 +146,6 @@ pub use warming::{BatchWarmer, CacheWarmer, FunctionWarmer, ParallelWarmer};
 
 // Re-export cache tags
 pub use tags::{TaggedCache, TaggedCacheWrapper};
+
+// Re-export versioned values
+pub use versioned::{StaleValuePolicy, VersionedCache};
@@ -0,0 +1,372 @@
+//! Versioned cache values
+//!
+//! When a struct stored in the cache changes shape between deploys, values
+//! written by the previous release no longer deserialize. [`VersionedCache`]
+//! wraps every value in an envelope recording the cache schema version it
+//! was written with. On read, a value written with an older version is
+//! passed through the registered upgraders, and a value that still cannot be
+//! decoded (or was written by a newer release, as happens during a rolling
+//! deploy) is handled according to the [`StaleValuePolicy`]: by default it is
+//! treated as a cache miss, so the caller recomputes and overwrites it.
+
+use super::cache_trait::Cache;
+use async_trait::async_trait;
+use reinhardt_core::exception::{Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Envelope field holding the schema version of a cached value
+pub const VERSION_FIELD: &str = "__cache_version";
+
+/// Envelope field holding the cached value itself
+const DATA_FIELD: &str = "data";
+
+type Upgrader = Arc<dyn Fn(&str, Value) -> Option<Value> + Send + Sync>;
+
+/// What a read does with a value that cannot be decoded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StaleValuePolicy {
+	/// Report a cache miss (default)
+	#[default]
+	Miss,
+	/// Return a serialization error, like an unversioned cache
+	Error,
+}
+
+/// Cache wrapper storing values in versioned envelopes
+///
+/// Version `0` stands for values written without an envelope, e.g. before
+/// the wrapper was introduced, so the first versioned schema should be `1`.
+///
+/// Increments go through `get` and `set`, so [`Cache::incr`] is not atomic
+/// even when the wrapped backend supports atomic increments.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_utils::cache::{Cache, InMemoryCache, VersionedCache};
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::sync::Arc;
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Profile {
+///     name: String,
+///     locale: String,
+/// }
+///
+/// # async fn example() -> reinhardt_core::exception::Result<()> {
+/// let backend = Arc::new(InMemoryCache::new());
+///
+/// // The previous release cached profiles without a locale
+/// let v1 = VersionedCache::new(backend.clone(), 1);
+/// v1.set("profile:1", &json!({"name": "Alice"}), None).await?;
+///
+/// // This release adds the field and upgrades old entries on read
+/// let v2 = VersionedCache::new(backend, 2).with_upgrader(1, |_key, mut value| {
+///     value["locale"] = json!("en");
+///     Some(value)
+/// });
+/// let profile: Option<Profile> = v2.get("profile:1").await?;
+/// assert_eq!(profile.unwrap().locale, "en");
+/// # Ok(())
+/// # }
+/// ```
+pub struct VersionedCache<C: Cache> {
+	cache: Arc<C>,
+	version: u32,
+	upgraders: BTreeMap<u32, Upgrader>,
+	policy: StaleValuePolicy,
+	stale_reads: AtomicU64,
+	upgraded_reads: AtomicU64,
+}
+
+impl<C: Cache> VersionedCache<C> {
+	/// Wrap `cache`, writing values with schema `version`
+	pub fn new(cache: Arc<C>, version: u32) -> Self {
+		Self {
+			cache,
+			version,
+			upgraders: BTreeMap::new(),
+			policy: StaleValuePolicy::default(),
+			stale_reads: AtomicU64::new(0),
+			upgraded_reads: AtomicU64::new(0),
+		}
+	}
+
+	/// Register the migration of values from schema `from_version` to the next one
+	///
+	/// The upgrader receives the key and the stored value, and returns the
+	/// value in the `from_version + 1` shape, or `None` to give up (the read
+	/// is then handled by the [`StaleValuePolicy`]). Values older than the
+	/// current version go through every upgrader in turn.
+	pub fn with_upgrader<F>(mut self, from_version: u32, upgrader: F) -> Self
+	where
+		F: Fn(&str, Value) -> Option<Value> + Send + Sync + 'static,
+	{
+		self.upgraders.insert(from_version, Arc::new(upgrader));
+		self
+	}
+
+	/// Set what reads do with values that cannot be decoded
+	pub fn with_policy(mut self, policy: StaleValuePolicy) -> Self {
+		self.policy = policy;
+		self
+	}
+
+	/// Schema version values are written with
+	pub fn version(&self) -> u32 {
+		self.version
+	}
+
+	/// The wrapped cache
+	pub fn inner(&self) -> &Arc<C> {
+		&self.cache
+	}
+
+	/// Number of reads that found a value that could not be decoded
+	pub fn stale_reads(&self) -> u64 {
+		self.stale_reads.load(Ordering::Relaxed)
+	}
+
+	/// Number of reads that decoded a value through the upgraders
+	pub fn upgraded_reads(&self) -> u64 {
+		self.upgraded_reads.load(Ordering::Relaxed)
+	}
+
+	/// Bring `data` written with schema `from` to the current version
+	fn upgrade(&self, key: &str, from: u32, mut data: Value) -> Option<Value> {
+		for version in from..self.version {
+			let upgrader = self.upgraders.get(&version)?;
+			data = upgrader(key, data)?;
+		}
+		Some(data)
+	}
+
+	fn stale<T>(&self, key: &str, stored: Option<u32>, reason: &str) -> Result<Option<T>> {
+		self.stale_reads.fetch_add(1, Ordering::Relaxed);
+		let message = match stored {
+			Some(stored) => format!(
+				"cached value for key '{}' (schema version {}, current {}) could not be read: {}",
+				key, stored, self.version, reason
+			),
+			None => format!(
+				"cached value for key '{}' could not be read: {}",
+				key, reason
+			),
+		};
+		match self.policy {
+			StaleValuePolicy::Miss => {
+				tracing::debug!("{}; treating as a cache miss", message);
+				Ok(None)
+			}
+			StaleValuePolicy::Error => Err(Error::Serialization(message)),
+		}
+	}
+}
+
+/// Split a stored value into its schema version and data
+///
+/// Values without an envelope are reported as version `0`.
+fn open_envelope(raw: Value) -> (u32, Value) {
+	if let Value::Object(mut map) = raw {
+		let version = map
+			.get(VERSION_FIELD)
+			.and_then(Value::as_u64)
+			.and_then(|v| u32::try_from(v).ok());
+		if let (Some(version), true) = (version, map.contains_key(DATA_FIELD)) {
+			let data = map.remove(DATA_FIELD).unwrap_or(Value::Null);
+			return (version, data);
+		}
+		return (0, Value::Object(map));
+	}
+	(0, raw)
+}
+
+#[async_trait]
+impl<C: Cache> Cache for VersionedCache<C> {
+	async fn get<T>(&self, key: &str) -> Result<Option<T>>
+	where
+		T: for<'de> Deserialize<'de> + Serialize + Send + Sync,
+	{
+		let raw = match self.cache.get::<Value>(key).await {
+			Ok(Some(raw)) => raw,
+			Ok(None) => return Ok(None),
+			Err(Error::Serialization(reason)) => return self.stale(key, None, &reason),
+			Err(e) => return Err(e),
+		};
+
+		let (stored, data) = open_envelope(raw);
+		let data = if stored == self.version {
+			data
+		} else if stored > self.version {
+			// Written by a newer release during a rolling deploy
+			return self.stale(key, Some(stored), "written by a newer schema");
+		} else {
+			match self.upgrade(key, stored, data) {
+				Some(data) => data,
+				None => return self.stale(key, Some(stored), "no upgrader to the current schema"),
+			}
+		};
+
+		match serde_json::from_value(data) {
+			Ok(value) => {
+				if stored != self.version {
+					self.upgraded_reads.fetch_add(1, Ordering::Relaxed);
+				}
+				Ok(Some(value))
+			}
+			Err(e) => self.stale(key, Some(stored), &e.to_string()),
+		}
+	}
+
+	async fn set<T>(&self, key: &str, value: &T, ttl: Option<Duration>) -> Result<()>
+	where
+		T: Serialize + Send + Sync,
+	{
+		let data = serde_json::to_value(value).map_err(|e| Error::Serialization(e.to_string()))?;
+		let mut envelope = serde_json::Map::new();
+		envelope.insert(VERSION_FIELD.to_string(), Value::from(self.version));
+		envelope.insert(DATA_FIELD.to_string(), data);
+		self.cache.set(key, &Value::Object(envelope), ttl).await
+	}
+
+	async fn delete(&self, key: &str) -> Result<()> {
+		self.cache.delete(key).await
+	}
+
+	async fn has_key(&self, key: &str) -> Result<bool> {
+		self.cache.has_key(key).await
+	}
+
+	async fn clear(&self) -> Result<()> {
+		self.cache.clear().await
+	}
+
+	async fn delete_many(&self, keys: &[&str]) -> Result<()> {
+		self.cache.delete_many(keys).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::cache::InMemoryCache;
+	use rstest::rstest;
+	use serde_json::json;
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Profile {
+		name: String,
+		locale: String,
+	}
+
+	fn profile() -> Profile {
+		Profile {
+			name: "Alice".to_string(),
+			locale: "fr".to_string(),
+		}
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_round_trip_stores_envelope() {
+		// Arrange
+		let backend = Arc::new(InMemoryCache::new());
+		let cache = VersionedCache::new(backend.clone(), 3);
+
+		// Act
+		cache.set("profile:1", &profile(), None).await.unwrap();
+		let value: Option<Profile> = cache.get("profile:1").await.unwrap();
+		let raw: Option<Value> = backend.get("profile:1").await.unwrap();
+
+		// Assert
+		assert_eq!(value, Some(profile()));
+		assert_eq!(
+			raw,
+			Some(json!({VERSION_FIELD: 3, "data": {"name": "Alice", "locale": "fr"}}))
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_changed_shape_is_a_miss() {
+		// Arrange
+		let backend = Arc::new(InMemoryCache::new());
+		VersionedCache::new(backend.clone(), 1)
+			.set("profile:1", &json!({"name": "Alice"}), None)
+			.await
+			.unwrap();
+		let cache = VersionedCache::new(backend, 2);
+
+		// Act
+		let value: Option<Profile> = cache.get("profile:1").await.unwrap();
+
+		// Assert
+		assert_eq!(value, None);
+		assert_eq!(cache.stale_reads(), 1);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_upgraders_are_chained() {
+		// Arrange
+		let backend = Arc::new(InMemoryCache::new());
+		backend
+			.set("profile:1", &json!({"username": "Alice"}), None)
+			.await
+			.unwrap();
+		let cache = VersionedCache::new(backend, 2)
+			.with_upgrader(0, |_, value| Some(json!({"name": value["username"]})))
+			.with_upgrader(1, |_, mut value| {
+				value["locale"] = json!("fr");
+				Some(value)
+			});
+
+		// Act
+		let value: Option<Profile> = cache.get("profile:1").await.unwrap();
+
+		// Assert
+		assert_eq!(value, Some(profile()));
+		assert_eq!(cache.upgraded_reads(), 1);
+		assert_eq!(cache.stale_reads(), 0);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_value_from_newer_schema_is_a_miss() {
+		// Arrange
+		let backend = Arc::new(InMemoryCache::new());
+		VersionedCache::new(backend.clone(), 5)
+			.set("profile:1", &profile(), None)
+			.await
+			.unwrap();
+		let cache = VersionedCache::new(backend.clone(), 4);
+
+		// Act
+		let value: Option<Profile> = cache.get("profile:1").await.unwrap();
+
+		// Assert
+		assert_eq!(value, None);
+		assert!(backend.has_key("profile:1").await.unwrap());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_error_policy_reports_stale_value() {
+		// Arrange
+		let backend = Arc::new(InMemoryCache::new());
+		backend.set("profile:1", &"Alice", None).await.unwrap();
+		let cache = VersionedCache::new(backend, 0).with_policy(StaleValuePolicy::Error);
+
+		// Act
+		let result = cache.get::<Profile>("profile:1").await;
+
+		// Assert
+		assert!(matches!(result, Err(Error::Serialization(_))));
+	}
+}
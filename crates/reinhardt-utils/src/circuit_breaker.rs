@@ -0,0 +1,547 @@
+//! Circuit breaker for outbound calls
+//!
+//! [`CircuitBreaker`] guards calls to external services (webhook endpoints,
+//! OAuth token endpoints, SMTP relays, ...). Each target, e.g. a host name,
+//! has its own circuit:
+//!
+//! - **Closed**: calls go through and their outcomes are counted in a fixed
+//!   window. Once the window holds at least `minimum_calls` outcomes and the
+//!   failure rate reaches `failure_rate_threshold`, the circuit opens.
+//! - **Open**: calls fail fast with [`CircuitOpenError`] until
+//!   `open_duration` has elapsed.
+//! - **Half-open**: up to `half_open_probes` calls are let through as probes.
+//!   As many successful probes close the circuit; one failed probe opens it
+//!   again.
+//!
+//! Circuit state is kept in a [`Cache`], so every process sharing a cache
+//! (e.g. Redis) sees the same circuits. Updates are read-modify-write and not
+//! atomic; concurrent callers may record a few extra outcomes, which only
+//! shifts when a circuit trips. Cache failures never block calls.
+//!
+//! # Example
+//!
+//! ```rust
+//! use reinhardt_utils::circuit_breaker::{CircuitBreaker, CircuitBreakerError, CircuitBreakerPolicy};
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let breaker = CircuitBreaker::in_memory("webhooks", CircuitBreakerPolicy::default());
+//!
+//! let result: Result<u16, CircuitBreakerError<String>> = breaker
+//!     .call("hooks.example.com", async { Ok(204) })
+//!     .await;
+//! assert_eq!(result.unwrap(), 204);
+//! # }
+//! ```
+
+use crate::cache::{Cache, InMemoryCache};
+use async_trait::async_trait;
+use chrono::Utc;
+use reinhardt_core::exception::Result;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// State of one circuit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+	/// Calls go through
+	#[default]
+	Closed,
+	/// Calls fail fast
+	Open,
+	/// Probe calls go through to test recovery
+	HalfOpen,
+}
+
+/// When circuits open and how they recover
+///
+/// # Example
+///
+/// ```rust
+/// use reinhardt_utils::circuit_breaker::CircuitBreakerPolicy;
+/// use std::time::Duration;
+///
+/// let policy = CircuitBreakerPolicy::default()
+///     .with_failure_rate_threshold(0.25)
+///     .with_open_duration(Duration::from_secs(60));
+/// assert_eq!(policy.minimum_calls, 10);
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CircuitBreakerPolicy {
+	/// Failure rate (0.0 - 1.0) at which a closed circuit opens
+	pub failure_rate_threshold: f64,
+	/// Outcomes required in the window before the failure rate is considered
+	pub minimum_calls: u32,
+	/// Length of the window in which outcomes are counted
+	pub window: Duration,
+	/// How long an open circuit rejects calls before probing
+	pub open_duration: Duration,
+	/// Probe calls allowed, and successes required to close, when half-open
+	pub half_open_probes: u32,
+}
+
+impl Default for CircuitBreakerPolicy {
+	fn default() -> Self {
+		Self {
+			failure_rate_threshold: 0.5,
+			minimum_calls: 10,
+			window: Duration::from_secs(60),
+			open_duration: Duration::from_secs(30),
+			half_open_probes: 1,
+		}
+	}
+}
+
+impl CircuitBreakerPolicy {
+	/// Set the failure rate at which a closed circuit opens
+	pub fn with_failure_rate_threshold(mut self, threshold: f64) -> Self {
+		self.failure_rate_threshold = threshold.clamp(0.0, 1.0);
+		self
+	}
+
+	/// Set the outcomes required before the failure rate is considered
+	pub fn with_minimum_calls(mut self, minimum_calls: u32) -> Self {
+		self.minimum_calls = minimum_calls.max(1);
+		self
+	}
+
+	/// Set the window in which outcomes are counted
+	pub fn with_window(mut self, window: Duration) -> Self {
+		self.window = window;
+		self
+	}
+
+	/// Set how long an open circuit rejects calls
+	pub fn with_open_duration(mut self, open_duration: Duration) -> Self {
+		self.open_duration = open_duration;
+		self
+	}
+
+	/// Set the probe calls allowed when half-open
+	pub fn with_half_open_probes(mut self, probes: u32) -> Self {
+		self.half_open_probes = probes.max(1);
+		self
+	}
+}
+
+/// Stored state of one circuit
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CircuitSnapshot {
+	/// Current state
+	pub state: CircuitState,
+	/// Start of the counting window (Unix milliseconds)
+	pub window_started_at: i64,
+	/// Successful calls in the window
+	pub successes: u32,
+	/// Failed calls in the window
+	pub failures: u32,
+	/// When the circuit opened, or started probing when half-open (Unix milliseconds)
+	pub opened_at: Option<i64>,
+	/// Probes let through since the circuit became half-open
+	pub probes: u32,
+	/// Successful probes since the circuit became half-open
+	pub probe_successes: u32,
+}
+
+/// Storage for circuit snapshots
+#[async_trait]
+pub trait CircuitStateStore: Send + Sync {
+	/// Load the snapshot stored under `key`
+	async fn load(&self, key: &str) -> Result<Option<CircuitSnapshot>>;
+
+	/// Store a snapshot under `key` for `ttl`
+	async fn save(&self, key: &str, snapshot: &CircuitSnapshot, ttl: Duration) -> Result<()>;
+}
+
+/// [`CircuitStateStore`] backed by any [`Cache`]
+pub struct CacheCircuitStore<C> {
+	cache: Arc<C>,
+}
+
+impl<C: Cache> CacheCircuitStore<C> {
+	/// Store snapshots in `cache`
+	pub fn new(cache: Arc<C>) -> Self {
+		Self { cache }
+	}
+}
+
+#[async_trait]
+impl<C: Cache + 'static> CircuitStateStore for CacheCircuitStore<C> {
+	async fn load(&self, key: &str) -> Result<Option<CircuitSnapshot>> {
+		self.cache.get(key).await
+	}
+
+	async fn save(&self, key: &str, snapshot: &CircuitSnapshot, ttl: Duration) -> Result<()> {
+		self.cache.set(key, snapshot, Some(ttl)).await
+	}
+}
+
+/// Returned instead of calling a target whose circuit is open
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("circuit '{target}' is open; retry after {retry_after:?}")]
+pub struct CircuitOpenError {
+	/// The rejected target
+	pub target: String,
+	/// Time until the circuit lets a probe through
+	pub retry_after: Duration,
+}
+
+/// Failure of a call guarded by a [`CircuitBreaker`]
+#[derive(Debug, thiserror::Error)]
+pub enum CircuitBreakerError<E> {
+	/// The circuit was open and the call was not made
+	#[error(transparent)]
+	Open(CircuitOpenError),
+	/// The call was made and failed
+	#[error("{0}")]
+	Inner(E),
+}
+
+/// Circuit breaker with per-target circuits stored in a cache
+#[derive(Clone)]
+pub struct CircuitBreaker {
+	name: String,
+	policy: CircuitBreakerPolicy,
+	store: Arc<dyn CircuitStateStore>,
+}
+
+impl fmt::Debug for CircuitBreaker {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("CircuitBreaker")
+			.field("name", &self.name)
+			.field("policy", &self.policy)
+			.finish()
+	}
+}
+
+impl CircuitBreaker {
+	/// Create a breaker storing circuits in `store`
+	///
+	/// `name` namespaces the cache keys, so that breakers for different
+	/// integrations keep separate circuits for the same target.
+	pub fn new(
+		name: impl Into<String>,
+		policy: CircuitBreakerPolicy,
+		store: Arc<dyn CircuitStateStore>,
+	) -> Self {
+		Self {
+			name: name.into(),
+			policy,
+			store,
+		}
+	}
+
+	/// Create a breaker storing circuits in a cache
+	pub fn with_cache<C: Cache + 'static>(
+		name: impl Into<String>,
+		policy: CircuitBreakerPolicy,
+		cache: Arc<C>,
+	) -> Self {
+		Self::new(name, policy, Arc::new(CacheCircuitStore::new(cache)))
+	}
+
+	/// Create a breaker storing circuits in a process-local cache
+	pub fn in_memory(name: impl Into<String>, policy: CircuitBreakerPolicy) -> Self {
+		Self::with_cache(name, policy, Arc::new(InMemoryCache::new()))
+	}
+
+	/// The breaker's policy
+	pub fn policy(&self) -> &CircuitBreakerPolicy {
+		&self.policy
+	}
+
+	/// Current state of a target's circuit
+	pub async fn state(&self, target: &str) -> CircuitState {
+		self.load(target).await.state
+	}
+
+	/// Run `call` unless the target's circuit is open, recording its outcome
+	pub async fn call<T, E, Fut>(
+		&self,
+		target: &str,
+		call: Fut,
+	) -> std::result::Result<T, CircuitBreakerError<E>>
+	where
+		Fut: Future<Output = std::result::Result<T, E>>,
+	{
+		self.check(target)
+			.await
+			.map_err(CircuitBreakerError::Open)?;
+		match call.await {
+			Ok(value) => {
+				self.record_success(target).await;
+				Ok(value)
+			}
+			Err(error) => {
+				self.record_failure(target).await;
+				Err(CircuitBreakerError::Inner(error))
+			}
+		}
+	}
+
+	/// Check whether a call to `target` may proceed
+	///
+	/// Moves an open circuit whose `open_duration` has elapsed to half-open
+	/// and counts the call as a probe. Callers must report the outcome with
+	/// [`record_success`](Self::record_success) or
+	/// [`record_failure`](Self::record_failure).
+	pub async fn check(&self, target: &str) -> std::result::Result<(), CircuitOpenError> {
+		let now = now_millis();
+		let mut snapshot = self.load(target).await;
+		let open_for = self.elapsed_since_open(&snapshot, now);
+
+		match snapshot.state {
+			CircuitState::Closed => return Ok(()),
+			CircuitState::Open if open_for < self.policy.open_duration => {
+				return Err(self.open_error(target, open_for));
+			}
+			CircuitState::Open => {
+				snapshot.state = CircuitState::HalfOpen;
+				snapshot.opened_at = Some(now);
+				snapshot.probes = 0;
+				snapshot.probe_successes = 0;
+			}
+			CircuitState::HalfOpen if snapshot.probes >= self.policy.half_open_probes => {
+				// Probes that never reported back must not wedge the circuit.
+				if open_for < self.policy.open_duration {
+					return Err(self.open_error(target, open_for));
+				}
+				snapshot.opened_at = Some(now);
+				snapshot.probes = 0;
+				snapshot.probe_successes = 0;
+			}
+			CircuitState::HalfOpen => {}
+		}
+
+		snapshot.probes += 1;
+		self.save(target, &snapshot).await;
+		Ok(())
+	}
+
+	/// Record a successful call to `target`
+	pub async fn record_success(&self, target: &str) {
+		let now = now_millis();
+		let mut snapshot = self.load(target).await;
+		match snapshot.state {
+			CircuitState::Closed => {
+				self.roll_window(&mut snapshot, now);
+				snapshot.successes = snapshot.successes.saturating_add(1);
+			}
+			CircuitState::HalfOpen => {
+				snapshot.probe_successes += 1;
+				if snapshot.probe_successes >= self.policy.half_open_probes {
+					tracing::info!(breaker = %self.name, target, "Circuit closed");
+					snapshot = CircuitSnapshot {
+						window_started_at: now,
+						..CircuitSnapshot::default()
+					};
+				}
+			}
+			CircuitState::Open => return,
+		}
+		self.save(target, &snapshot).await;
+	}
+
+	/// Record a failed call to `target`
+	pub async fn record_failure(&self, target: &str) {
+		let now = now_millis();
+		let mut snapshot = self.load(target).await;
+		match snapshot.state {
+			CircuitState::Closed => {
+				self.roll_window(&mut snapshot, now);
+				snapshot.failures = snapshot.failures.saturating_add(1);
+				let total = snapshot.successes + snapshot.failures;
+				let failure_rate = f64::from(snapshot.failures) / f64::from(total);
+				if total >= self.policy.minimum_calls
+					&& failure_rate >= self.policy.failure_rate_threshold
+				{
+					tracing::warn!(
+						breaker = %self.name,
+						target,
+						failures = snapshot.failures,
+						total,
+						"Circuit opened"
+					);
+					snapshot = self.opened(now);
+				}
+			}
+			CircuitState::HalfOpen => {
+				tracing::warn!(breaker = %self.name, target, "Circuit probe failed, reopening");
+				snapshot = self.opened(now);
+			}
+			CircuitState::Open => return,
+		}
+		self.save(target, &snapshot).await;
+	}
+
+	/// Close a target's circuit and forget its outcomes
+	pub async fn reset(&self, target: &str) {
+		let snapshot = CircuitSnapshot {
+			window_started_at: now_millis(),
+			..CircuitSnapshot::default()
+		};
+		self.save(target, &snapshot).await;
+	}
+
+	fn key(&self, target: &str) -> String {
+		format!("circuit_breaker:{}:{}", self.name, target)
+	}
+
+	async fn load(&self, target: &str) -> CircuitSnapshot {
+		match self.store.load(&self.key(target)).await {
+			Ok(snapshot) => snapshot.unwrap_or_default(),
+			Err(error) => {
+				tracing::warn!(breaker = %self.name, target, %error, "Failed to load circuit state");
+				CircuitSnapshot::default()
+			}
+		}
+	}
+
+	async fn save(&self, target: &str, snapshot: &CircuitSnapshot) {
+		let ttl = self.policy.window.max(self.policy.open_duration) * 2;
+		if let Err(error) = self.store.save(&self.key(target), snapshot, ttl).await {
+			tracing::warn!(breaker = %self.name, target, %error, "Failed to store circuit state");
+		}
+	}
+
+	fn roll_window(&self, snapshot: &mut CircuitSnapshot, now: i64) {
+		if now - snapshot.window_started_at >= millis(self.policy.window) {
+			snapshot.window_started_at = now;
+			snapshot.successes = 0;
+			snapshot.failures = 0;
+		}
+	}
+
+	fn opened(&self, now: i64) -> CircuitSnapshot {
+		CircuitSnapshot {
+			state: CircuitState::Open,
+			window_started_at: now,
+			opened_at: Some(now),
+			..CircuitSnapshot::default()
+		}
+	}
+
+	fn elapsed_since_open(&self, snapshot: &CircuitSnapshot, now: i64) -> Duration {
+		let opened_at = snapshot.opened_at.unwrap_or(now);
+		Duration::from_millis(u64::try_from(now - opened_at).unwrap_or(0))
+	}
+
+	fn open_error(&self, target: &str, open_for: Duration) -> CircuitOpenError {
+		CircuitOpenError {
+			target: target.to_string(),
+			retry_after: self.policy.open_duration.saturating_sub(open_for),
+		}
+	}
+}
+
+fn now_millis() -> i64 {
+	Utc::now().timestamp_millis()
+}
+
+fn millis(duration: Duration) -> i64 {
+	i64::try_from(duration.as_millis()).unwrap_or(i64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn breaker(open_duration: Duration) -> CircuitBreaker {
+		CircuitBreaker::in_memory(
+			"test",
+			CircuitBreakerPolicy::default()
+				.with_minimum_calls(4)
+				.with_failure_rate_threshold(0.5)
+				.with_open_duration(open_duration),
+		)
+	}
+
+	async fn fail(breaker: &CircuitBreaker, target: &str) {
+		let _ = breaker.call(target, async { Err::<(), _>("boom") }).await;
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_opens_at_failure_rate_after_minimum_calls() {
+		// Arrange
+		let breaker = breaker(Duration::from_secs(30));
+		breaker.record_success("a").await;
+		breaker.record_success("a").await;
+		fail(&breaker, "a").await;
+
+		// Act
+		let before = breaker.state("a").await;
+		fail(&breaker, "a").await;
+		let result = breaker.call("a", async { Ok::<_, ()>(1) }).await;
+
+		// Assert
+		assert_eq!(before, CircuitState::Closed);
+		assert_eq!(breaker.state("a").await, CircuitState::Open);
+		assert!(matches!(result, Err(CircuitBreakerError::Open(error)) if error.target == "a"));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_circuits_are_per_target() {
+		// Arrange
+		let breaker = breaker(Duration::from_secs(30));
+		for _ in 0..4 {
+			fail(&breaker, "down.example.com").await;
+		}
+
+		// Act
+		let result = breaker
+			.call("up.example.com", async { Ok::<_, ()>("ok") })
+			.await;
+
+		// Assert
+		assert_eq!(result.unwrap(), "ok");
+		assert_eq!(breaker.state("down.example.com").await, CircuitState::Open);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_half_open_probe_closes_or_reopens() {
+		// Arrange
+		let breaker = breaker(Duration::from_millis(20));
+		for _ in 0..4 {
+			fail(&breaker, "a").await;
+			fail(&breaker, "b").await;
+		}
+		tokio::time::sleep(Duration::from_millis(30)).await;
+
+		// Act
+		breaker.check("a").await.unwrap();
+		let second_probe = breaker.check("a").await;
+		breaker.record_success("a").await;
+		fail(&breaker, "b").await;
+
+		// Assert
+		assert!(second_probe.is_err());
+		assert_eq!(breaker.state("a").await, CircuitState::Closed);
+		assert_eq!(breaker.state("b").await, CircuitState::Open);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_reset_closes_circuit() {
+		// Arrange
+		let breaker = breaker(Duration::from_secs(30));
+		for _ in 0..4 {
+			fail(&breaker, "a").await;
+		}
+
+		// Act
+		breaker.reset("a").await;
+
+		// Assert
+		assert_eq!(breaker.state("a").await, CircuitState::Closed);
+		assert!(breaker.check("a").await.is_ok());
+	}
+}
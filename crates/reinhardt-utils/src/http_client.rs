@@ -0,0 +1,1045 @@
+//! Outbound HTTP client
+//!
+//! [`HttpClient`] is a thin wrapper around [`reqwest::Client`] that runs
+//! every request through a chain of [`ClientMiddleware`]. Framework features
+//! that call external services (webhook deliveries, OAuth token exchanges,
+//! ...) and application code can share one client, so all outbound calls get
+//! the same authentication, retries and tracing.
+//!
+//! Bundled middleware:
+//!
+//! - [`AuthHeader`]: injects an `Authorization` header, optionally only for
+//!   given hosts
+//! - [`Retry`]: retries transient failures with exponential backoff and full
+//!   jitter, honoring `Retry-After`
+//! - [`TracePropagation`]: logs each call in a `tracing` span and forwards
+//!   the current [`TraceContext`] to the called service
+//!
+//! A client can also record its interactions to a [`Cassette`], or replay
+//! them without touching the network. Test doubles use replay mode to serve
+//! canned responses.
+//!
+//! # Example
+//!
+//! ```rust
+//! use reinhardt_utils::http_client::{AuthHeader, HttpClient, Retry};
+//! use std::time::Duration;
+//!
+//! let client = HttpClient::builder()
+//!     .timeout(Duration::from_secs(10))
+//!     .middleware(Retry::default())
+//!     .middleware(AuthHeader::bearer("s3cr3t").unwrap().for_host("api.example.com"))
+//!     .build()
+//!     .unwrap();
+//!
+//! // Share it with the framework, e.g. webhook senders
+//! client.install();
+//! ```
+
+use async_trait::async_trait;
+use rand::Rng;
+use reqwest::header::{AUTHORIZATION, HeaderName, HeaderValue, InvalidHeaderValue, RETRY_AFTER};
+use reqwest::{Method, Request, RequestBuilder, Response, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::time::{Duration, Instant};
+use tracing::Instrument;
+
+static GLOBAL_CLIENT: RwLock<Option<HttpClient>> = RwLock::new(None);
+
+tokio::task_local! {
+	static CURRENT_TRACE: TraceContext;
+}
+
+/// Errors returned by [`HttpClient`]
+#[derive(Debug, thiserror::Error)]
+pub enum HttpClientError {
+	/// The request could not be built or sent
+	#[error("HTTP request failed: {0}")]
+	Request(#[from] reqwest::Error),
+	/// A replaying client has no recorded response for the request
+	#[error("No recorded response for {method} {url}")]
+	NotRecorded {
+		/// Request method
+		method: Method,
+		/// Request URL
+		url: String,
+	},
+	/// A cassette file could not be read or written
+	#[error("Cassette {}: {message}", path.display())]
+	Cassette {
+		/// Cassette file
+		path: PathBuf,
+		/// What went wrong
+		message: String,
+	},
+}
+
+/// Result type for [`HttpClient`] calls
+pub type HttpClientResult<T> = std::result::Result<T, HttpClientError>;
+
+/// Middleware wrapping the requests of an [`HttpClient`]
+///
+/// Implementations may change the request, call `next` zero or more times and
+/// change the response.
+///
+/// # Example
+///
+/// ```rust
+/// use async_trait::async_trait;
+/// use reinhardt_utils::http_client::{ClientMiddleware, HttpClientResult, Next};
+/// use reqwest::header::{HeaderValue, USER_AGENT};
+/// use reqwest::{Request, Response};
+///
+/// struct UserAgent;
+///
+/// #[async_trait]
+/// impl ClientMiddleware for UserAgent {
+///     async fn handle(&self, mut request: Request, next: Next<'_>) -> HttpClientResult<Response> {
+///         request
+///             .headers_mut()
+///             .insert(USER_AGENT, HeaderValue::from_static("acme/1.0"));
+///         next.run(request).await
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait ClientMiddleware: Send + Sync {
+	/// Handle `request`, passing it on with `next`
+	async fn handle(&self, request: Request, next: Next<'_>) -> HttpClientResult<Response>;
+}
+
+/// The rest of the middleware chain
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+	client: &'a HttpClient,
+	middleware: &'a [Arc<dyn ClientMiddleware>],
+}
+
+impl Next<'_> {
+	/// Run the remaining middleware and send `request`
+	pub async fn run(self, request: Request) -> HttpClientResult<Response> {
+		match self.middleware.split_first() {
+			Some((middleware, rest)) => {
+				let next = Next {
+					client: self.client,
+					middleware: rest,
+				};
+				middleware.handle(request, next).await
+			}
+			None => self.client.transport(request).await,
+		}
+	}
+}
+
+#[derive(Clone)]
+enum Playback {
+	Record(Arc<Cassette>),
+	Replay(Arc<Cassette>),
+}
+
+/// Outbound HTTP client with middleware
+///
+/// Cloning is cheap; clones share the connection pool.
+#[derive(Clone)]
+pub struct HttpClient {
+	inner: reqwest::Client,
+	middleware: Arc<[Arc<dyn ClientMiddleware>]>,
+	playback: Option<Playback>,
+}
+
+impl std::fmt::Debug for HttpClient {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("HttpClient")
+			.field("middleware", &self.middleware.len())
+			.field(
+				"playback",
+				&match self.playback {
+					None => "off",
+					Some(Playback::Record(_)) => "record",
+					Some(Playback::Replay(_)) => "replay",
+				},
+			)
+			.finish()
+	}
+}
+
+impl Default for HttpClient {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl HttpClient {
+	/// Create a client without middleware
+	pub fn new() -> Self {
+		Self::from_reqwest(reqwest::Client::new())
+	}
+
+	/// Wrap an existing [`reqwest::Client`]
+	pub fn from_reqwest(client: reqwest::Client) -> Self {
+		Self {
+			inner: client,
+			middleware: Arc::new([]),
+			playback: None,
+		}
+	}
+
+	/// Start building a client
+	pub fn builder() -> HttpClientBuilder {
+		HttpClientBuilder::default()
+	}
+
+	/// Start a request; send it with [`send`](Self::send)
+	pub fn request(&self, method: Method, url: impl reqwest::IntoUrl) -> RequestBuilder {
+		self.inner.request(method, url)
+	}
+
+	/// Start a `GET` request
+	pub fn get(&self, url: impl reqwest::IntoUrl) -> RequestBuilder {
+		self.request(Method::GET, url)
+	}
+
+	/// Start a `POST` request
+	pub fn post(&self, url: impl reqwest::IntoUrl) -> RequestBuilder {
+		self.request(Method::POST, url)
+	}
+
+	/// Start a `PUT` request
+	pub fn put(&self, url: impl reqwest::IntoUrl) -> RequestBuilder {
+		self.request(Method::PUT, url)
+	}
+
+	/// Start a `PATCH` request
+	pub fn patch(&self, url: impl reqwest::IntoUrl) -> RequestBuilder {
+		self.request(Method::PATCH, url)
+	}
+
+	/// Start a `DELETE` request
+	pub fn delete(&self, url: impl reqwest::IntoUrl) -> RequestBuilder {
+		self.request(Method::DELETE, url)
+	}
+
+	/// Build and send a request through the middleware chain
+	pub async fn send(&self, request: RequestBuilder) -> HttpClientResult<Response> {
+		self.execute(request.build()?).await
+	}
+
+	/// Send a request through the middleware chain
+	pub async fn execute(&self, request: Request) -> HttpClientResult<Response> {
+		Next {
+			client: self,
+			middleware: &self.middleware,
+		}
+		.run(request)
+		.await
+	}
+
+	/// Install as the process-wide client returned by [`global`](Self::global),
+	/// replacing any previous one
+	pub fn install(self) {
+		*GLOBAL_CLIENT
+			.write()
+			.unwrap_or_else(PoisonError::into_inner) = Some(self);
+	}
+
+	/// The process-wide client
+	///
+	/// Returns the client set with [`install`](Self::install), or a client
+	/// without middleware if none was installed.
+	pub fn global() -> Self {
+		if let Some(client) = GLOBAL_CLIENT
+			.read()
+			.unwrap_or_else(PoisonError::into_inner)
+			.clone()
+		{
+			return client;
+		}
+		GLOBAL_CLIENT
+			.write()
+			.unwrap_or_else(PoisonError::into_inner)
+			.get_or_insert_with(Self::new)
+			.clone()
+	}
+
+	async fn transport(&self, request: Request) -> HttpClientResult<Response> {
+		match &self.playback {
+			None => Ok(self.inner.execute(request).await?),
+			Some(Playback::Replay(cassette)) => cassette.replay(&request),
+			Some(Playback::Record(cassette)) => {
+				let method = request.method().clone();
+				let url = request.url().to_string();
+				let request_body = request_body(&request);
+				let response = self.inner.execute(request).await?;
+				let status = response.status();
+				let headers = response.headers().clone();
+				let body = response.bytes().await?;
+				cassette.record(Interaction {
+					method: method.to_string(),
+					url,
+					request_body,
+					status: status.as_u16(),
+					headers: headers
+						.iter()
+						.filter_map(|(name, value)| {
+							Some((name.to_string(), value.to_str().ok()?.to_string()))
+						})
+						.collect(),
+					body: String::from_utf8_lossy(&body).into_owned(),
+				})?;
+				let mut response = http::Response::new(body.to_vec());
+				*response.status_mut() = status;
+				*response.headers_mut() = headers;
+				Ok(response.into())
+			}
+		}
+	}
+}
+
+/// Builder for [`HttpClient`]
+#[derive(Default)]
+pub struct HttpClientBuilder {
+	timeout: Option<Duration>,
+	user_agent: Option<String>,
+	middleware: Vec<Arc<dyn ClientMiddleware>>,
+	playback: Option<Playback>,
+}
+
+impl HttpClientBuilder {
+	/// Total timeout of each request, including retries' individual attempts
+	pub fn timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = Some(timeout);
+		self
+	}
+
+	/// `User-Agent` header sent with every request
+	pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+		self.user_agent = Some(user_agent.into());
+		self
+	}
+
+	/// Append a middleware
+	///
+	/// Middleware added first is outermost: it sees the request first and the
+	/// response last.
+	pub fn middleware<M: ClientMiddleware + 'static>(mut self, middleware: M) -> Self {
+		self.middleware.push(Arc::new(middleware));
+		self
+	}
+
+	/// Send requests and record the interactions to `cassette`
+	pub fn record(mut self, cassette: Arc<Cassette>) -> Self {
+		self.playback = Some(Playback::Record(cassette));
+		self
+	}
+
+	/// Answer requests from the interactions in `cassette` instead of sending
+	/// them
+	pub fn replay(mut self, cassette: Arc<Cassette>) -> Self {
+		self.playback = Some(Playback::Replay(cassette));
+		self
+	}
+
+	/// Build the client
+	pub fn build(self) -> HttpClientResult<HttpClient> {
+		let mut inner = reqwest::Client::builder();
+		if let Some(timeout) = self.timeout {
+			inner = inner.timeout(timeout);
+		}
+		if let Some(user_agent) = self.user_agent {
+			inner = inner.user_agent(user_agent);
+		}
+		Ok(HttpClient {
+			inner: inner.build()?,
+			middleware: self.middleware.into(),
+			playback: self.playback,
+		})
+	}
+}
+
+/// Injects an `Authorization` header into requests that have none
+///
+/// The header is marked sensitive so it is not logged.
+#[derive(Debug, Clone)]
+pub struct AuthHeader {
+	value: HeaderValue,
+	hosts: Vec<String>,
+}
+
+impl AuthHeader {
+	/// Send `value` as the `Authorization` header
+	pub fn new(mut value: HeaderValue) -> Self {
+		value.set_sensitive(true);
+		Self {
+			value,
+			hosts: Vec::new(),
+		}
+	}
+
+	/// Send `Bearer <token>`
+	pub fn bearer(token: &str) -> Result<Self, InvalidHeaderValue> {
+		Ok(Self::new(HeaderValue::try_from(format!("Bearer {token}"))?))
+	}
+
+	/// Send HTTP Basic credentials
+	pub fn basic(username: &str, password: &str) -> Self {
+		use base64::Engine;
+		let credentials =
+			base64::engine::general_purpose::STANDARD.encode(format!("{username}:{password}"));
+		Self::new(
+			HeaderValue::try_from(format!("Basic {credentials}"))
+				.expect("base64 is a valid header value"),
+		)
+	}
+
+	/// Only send the header to `host`
+	///
+	/// Can be called several times. Without any host, the header is sent to
+	/// every host, which leaks the credentials if the client is also used
+	/// for other services.
+	pub fn for_host(mut self, host: impl Into<String>) -> Self {
+		self.hosts.push(host.into().to_ascii_lowercase());
+		self
+	}
+
+	fn applies_to(&self, url: &Url) -> bool {
+		self.hosts.is_empty()
+			|| url
+				.host_str()
+				.is_some_and(|host| self.hosts.iter().any(|allowed| allowed == host))
+	}
+}
+
+#[async_trait]
+impl ClientMiddleware for AuthHeader {
+	async fn handle(&self, mut request: Request, next: Next<'_>) -> HttpClientResult<Response> {
+		if self.applies_to(request.url()) && !request.headers().contains_key(AUTHORIZATION) {
+			request
+				.headers_mut()
+				.insert(AUTHORIZATION, self.value.clone());
+		}
+		next.run(request).await
+	}
+}
+
+/// Retries transient failures with exponential backoff and full jitter
+///
+/// Connection errors, timeouts and `429`, `502`, `503` and `504` responses
+/// are retried. The delay before retry `n` is random between zero and
+/// `base_delay * 2^n`, capped at `max_delay`; a `Retry-After` header given in
+/// seconds raises it, within the same cap. Requests with streaming bodies
+/// cannot be retried and are sent once.
+///
+/// Only idempotent methods are retried unless
+/// [`retry_non_idempotent`](Self::retry_non_idempotent) is set.
+#[derive(Debug, Clone)]
+pub struct Retry {
+	max_retries: u32,
+	base_delay: Duration,
+	max_delay: Duration,
+	retry_non_idempotent: bool,
+}
+
+impl Default for Retry {
+	fn default() -> Self {
+		Self {
+			max_retries: 3,
+			base_delay: Duration::from_millis(100),
+			max_delay: Duration::from_secs(10),
+			retry_non_idempotent: false,
+		}
+	}
+}
+
+impl Retry {
+	/// Retries after the first attempt (default: 3)
+	pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+		self.max_retries = max_retries;
+		self
+	}
+
+	/// Upper bound of the first delay, doubled for every retry (default: 100ms)
+	pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+		self.base_delay = base_delay;
+		self
+	}
+
+	/// Cap of every delay (default: 10s)
+	pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+		self.max_delay = max_delay;
+		self
+	}
+
+	/// Also retry `POST`, `PATCH` and other non-idempotent requests
+	///
+	/// Only enable this when the called service deduplicates requests, e.g.
+	/// with an idempotency key.
+	pub fn retry_non_idempotent(mut self, retry: bool) -> Self {
+		self.retry_non_idempotent = retry;
+		self
+	}
+
+	/// Delay before retry number `attempt` (zero-based)
+	pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+		let ceiling = self
+			.base_delay
+			.saturating_mul(2u32.saturating_pow(attempt))
+			.min(self.max_delay);
+		let jittered = rand::rng().random_range(Duration::ZERO..=ceiling);
+		match retry_after {
+			Some(retry_after) => jittered.max(retry_after).min(self.max_delay),
+			None => jittered,
+		}
+	}
+
+	fn retries(&self, method: &Method) -> bool {
+		self.retry_non_idempotent
+			|| matches!(
+				*method,
+				Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+			)
+	}
+}
+
+fn is_transient(result: &HttpClientResult<Response>) -> bool {
+	match result {
+		Ok(response) => matches!(
+			response.status(),
+			StatusCode::TOO_MANY_REQUESTS
+				| StatusCode::BAD_GATEWAY
+				| StatusCode::SERVICE_UNAVAILABLE
+				| StatusCode::GATEWAY_TIMEOUT
+		),
+		Err(HttpClientError::Request(error)) => error.is_connect() || error.is_timeout(),
+		Err(_) => false,
+	}
+}
+
+fn retry_after(result: &HttpClientResult<Response>) -> Option<Duration> {
+	let response = result.as_ref().ok()?;
+	let seconds = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+	seconds.trim().parse().ok().map(Duration::from_secs)
+}
+
+#[async_trait]
+impl ClientMiddleware for Retry {
+	async fn handle(&self, mut request: Request, next: Next<'_>) -> HttpClientResult<Response> {
+		if !self.retries(request.method()) {
+			return next.run(request).await;
+		}
+		let mut attempt = 0;
+		loop {
+			let retry = (attempt < self.max_retries)
+				.then(|| request.try_clone())
+				.flatten();
+			let Some(retry) = retry else {
+				return next.run(request).await;
+			};
+			let result = next.run(request).await;
+			if !is_transient(&result) {
+				return result;
+			}
+			let delay = self.delay(attempt, retry_after(&result));
+			tracing::debug!(
+				attempt = attempt + 1,
+				delay = ?delay,
+				url = %retry.url(),
+				"Retrying outbound HTTP request"
+			);
+			tokio::time::sleep(delay).await;
+			request = retry;
+			attempt += 1;
+		}
+	}
+}
+
+/// Trace and span of the work that makes outbound calls
+///
+/// Servers set it around request handling; [`TracePropagation`] sends it with
+/// outbound requests so the called service joins the same trace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceContext {
+	/// Trace ID shared by all spans of the trace
+	pub trace_id: String,
+	/// ID of the current span
+	pub span_id: String,
+}
+
+impl TraceContext {
+	/// Create a trace context
+	pub fn new(trace_id: impl Into<String>, span_id: impl Into<String>) -> Self {
+		Self {
+			trace_id: trace_id.into(),
+			span_id: span_id.into(),
+		}
+	}
+
+	/// The trace context of the current task, if any
+	pub fn current() -> Option<Self> {
+		CURRENT_TRACE.try_with(Clone::clone).ok()
+	}
+
+	/// Run `future` with this trace context as the current one
+	pub async fn scope<F: Future>(self, future: F) -> F::Output {
+		CURRENT_TRACE.scope(self, future).await
+	}
+}
+
+/// Logs outbound requests and propagates the current [`TraceContext`]
+///
+/// Each request runs in an `http.client` span that records the method, URL,
+/// status and duration. When a trace context is current, its trace ID is sent
+/// as `X-Trace-ID` and its span ID as `X-Parent-Span-ID`, matching what the
+/// server-side tracing middleware reads.
+#[derive(Debug, Clone)]
+pub struct TracePropagation {
+	trace_id_header: HeaderName,
+	parent_span_id_header: HeaderName,
+}
+
+impl Default for TracePropagation {
+	fn default() -> Self {
+		Self {
+			trace_id_header: HeaderName::from_static("x-trace-id"),
+			parent_span_id_header: HeaderName::from_static("x-parent-span-id"),
+		}
+	}
+}
+
+impl TracePropagation {
+	/// Use custom header names
+	pub fn with_headers(trace_id_header: HeaderName, parent_span_id_header: HeaderName) -> Self {
+		Self {
+			trace_id_header,
+			parent_span_id_header,
+		}
+	}
+}
+
+#[async_trait]
+impl ClientMiddleware for TracePropagation {
+	async fn handle(&self, mut request: Request, next: Next<'_>) -> HttpClientResult<Response> {
+		let trace = TraceContext::current();
+		if let Some(trace) = &trace {
+			let headers = request.headers_mut();
+			for (name, value) in [
+				(&self.trace_id_header, &trace.trace_id),
+				(&self.parent_span_id_header, &trace.span_id),
+			] {
+				if let Ok(value) = HeaderValue::try_from(value.as_str()) {
+					headers.insert(name.clone(), value);
+				}
+			}
+		}
+
+		let span = tracing::info_span!(
+			"http.client",
+			http.method = %request.method(),
+			http.url = %redacted_url(request.url()),
+			trace_id = trace.as_ref().map(|trace| trace.trace_id.as_str()),
+			http.status_code = tracing::field::Empty,
+		);
+		let started = Instant::now();
+		let result = next.run(request).instrument(span.clone()).await;
+		let elapsed = started.elapsed();
+		match &result {
+			Ok(response) => {
+				span.record("http.status_code", response.status().as_u16());
+				tracing::debug!(parent: &span, elapsed = ?elapsed, "Outbound HTTP request completed");
+			}
+			Err(error) => {
+				tracing::warn!(parent: &span, elapsed = ?elapsed, error = %error, "Outbound HTTP request failed");
+			}
+		}
+		result
+	}
+}
+
+/// URL without credentials and query string, which may hold secrets
+fn redacted_url(url: &Url) -> String {
+	let mut url = url.clone();
+	let _ = url.set_username("");
+	let _ = url.set_password(None);
+	url.set_query(None);
+	url.to_string()
+}
+
+fn request_body(request: &Request) -> Option<String> {
+	request
+		.body()
+		.and_then(|body| body.as_bytes())
+		.filter(|bytes| !bytes.is_empty())
+		.map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+}
+
+/// One recorded request and its response
+///
+/// Request headers are not recorded, so credentials stay out of cassettes.
+/// Bodies are stored as text; binary bodies are not preserved.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+	/// Request method
+	pub method: String,
+	/// Request URL
+	pub url: String,
+	/// Request body; when set, replay only matches requests with this body
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub request_body: Option<String>,
+	/// Response status code
+	pub status: u16,
+	/// Response headers
+	#[serde(default)]
+	pub headers: Vec<(String, String)>,
+	/// Response body
+	#[serde(default)]
+	pub body: String,
+}
+
+impl Interaction {
+	/// A response to `method url`
+	pub fn new(
+		method: Method,
+		url: impl Into<String>,
+		status: StatusCode,
+		body: impl Into<String>,
+	) -> Self {
+		Self {
+			method: method.to_string(),
+			url: url.into(),
+			request_body: None,
+			status: status.as_u16(),
+			headers: Vec::new(),
+			body: body.into(),
+		}
+	}
+
+	/// Add a response header
+	pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+		self.headers.push((name.into(), value.into()));
+		self
+	}
+
+	/// Only match requests with `body`
+	pub fn with_request_body(mut self, body: impl Into<String>) -> Self {
+		self.request_body = Some(body.into());
+		self
+	}
+
+	fn matches(&self, request: &Request) -> bool {
+		self.method == request.method().as_str()
+			&& self.url == request.url().as_str()
+			&& self
+				.request_body
+				.as_ref()
+				.is_none_or(|body| Some(body) == request_body(request).as_ref())
+	}
+
+	fn to_response(&self) -> Response {
+		let mut response = http::Response::new(self.body.clone().into_bytes());
+		*response.status_mut() =
+			StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+		for (name, value) in &self.headers {
+			if let (Ok(name), Ok(value)) = (
+				HeaderName::from_bytes(name.as_bytes()),
+				HeaderValue::try_from(value.as_str()),
+			) {
+				response.headers_mut().append(name, value);
+			}
+		}
+		response.into()
+	}
+}
+
+/// Recorded interactions, optionally backed by a JSON file
+///
+/// Replay serves each interaction once, in recorded order, to the first
+/// request matching its method, URL and request body.
+#[derive(Debug, Default)]
+pub struct Cassette {
+	path: Option<PathBuf>,
+	interactions: Mutex<Vec<(Interaction, bool)>>,
+}
+
+impl Cassette {
+	/// An empty in-memory cassette
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// A cassette backed by the JSON file at `path`
+	///
+	/// Loads the interactions if the file exists. Recorded interactions are
+	/// written back to it.
+	pub fn open(path: impl AsRef<Path>) -> HttpClientResult<Self> {
+		let path = path.as_ref().to_path_buf();
+		let interactions = match std::fs::read_to_string(&path) {
+			Ok(json) => serde_json::from_str::<Vec<Interaction>>(&json).map_err(|error| {
+				HttpClientError::Cassette {
+					path: path.clone(),
+					message: error.to_string(),
+				}
+			})?,
+			Err(error) if error.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+			Err(error) => {
+				return Err(HttpClientError::Cassette {
+					path,
+					message: error.to_string(),
+				});
+			}
+		};
+		Ok(Self {
+			path: Some(path),
+			interactions: Mutex::new(interactions.into_iter().map(|i| (i, false)).collect()),
+		})
+	}
+
+	/// Add an interaction, e.g. a canned response for a test
+	pub fn with_interaction(self, interaction: Interaction) -> Self {
+		self.lock().push((interaction, false));
+		self
+	}
+
+	/// The file backing this cassette, if any
+	pub fn path(&self) -> Option<&Path> {
+		self.path.as_deref()
+	}
+
+	/// All interactions, in recorded order
+	pub fn interactions(&self) -> Vec<Interaction> {
+		self.lock()
+			.iter()
+			.map(|(interaction, _)| interaction.clone())
+			.collect()
+	}
+
+	/// Whether every interaction has been replayed
+	pub fn is_exhausted(&self) -> bool {
+		self.lock().iter().all(|(_, played)| *played)
+	}
+
+	fn lock(&self) -> std::sync::MutexGuard<'_, Vec<(Interaction, bool)>> {
+		self.interactions
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+	}
+
+	fn record(&self, interaction: Interaction) -> HttpClientResult<()> {
+		let mut interactions = self.lock();
+		interactions.push((interaction, true));
+		let Some(path) = &self.path else {
+			return Ok(());
+		};
+		let recorded: Vec<_> = interactions
+			.iter()
+			.map(|(interaction, _)| interaction)
+			.collect();
+		let json = serde_json::to_string_pretty(&recorded).expect("interactions are serializable");
+		std::fs::write(path, json).map_err(|error| HttpClientError::Cassette {
+			path: path.clone(),
+			message: error.to_string(),
+		})
+	}
+
+	fn replay(&self, request: &Request) -> HttpClientResult<Response> {
+		let mut interactions = self.lock();
+		let (interaction, played) = interactions
+			.iter_mut()
+			.find(|(interaction, played)| !played && interaction.matches(request))
+			.ok_or_else(|| HttpClientError::NotRecorded {
+				method: request.method().clone(),
+				url: request.url().to_string(),
+			})?;
+		*played = true;
+		Ok(interaction.to_response())
+	}
+}
+
+/// Dependency injection support for [`HttpClient`]
+///
+/// Injects the client registered as a singleton, or the [global](HttpClient::global)
+/// client.
+#[cfg(feature = "di")]
+mod di_support {
+	use super::HttpClient;
+	use async_trait::async_trait;
+	use reinhardt_di::{DiResult, Injectable, InjectionContext};
+
+	#[async_trait]
+	impl Injectable for HttpClient {
+		async fn inject(ctx: &InjectionContext) -> DiResult<Self> {
+			if let Some(client) = ctx.get_singleton::<HttpClient>() {
+				return Ok((*client).clone());
+			}
+			Ok(HttpClient::global())
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case(0, Duration::from_millis(100))]
+	#[case(3, Duration::from_millis(800))]
+	#[case(10, Duration::from_secs(10))]
+	fn test_retry_delay_is_capped(#[case] attempt: u32, #[case] ceiling: Duration) {
+		// Arrange
+		let retry = Retry::default();
+
+		// Act
+		let delays: Vec<_> = (0..50).map(|_| retry.delay(attempt, None)).collect();
+
+		// Assert
+		assert!(delays.iter().all(|delay| *delay <= ceiling));
+	}
+
+	#[rstest]
+	fn test_retry_after_raises_delay_within_cap() {
+		// Arrange
+		let retry = Retry::default().with_max_delay(Duration::from_secs(5));
+
+		// Act
+		let short = retry.delay(0, Some(Duration::from_secs(2)));
+		let long = retry.delay(0, Some(Duration::from_secs(60)));
+
+		// Assert
+		assert_eq!(short, Duration::from_secs(2));
+		assert_eq!(long, Duration::from_secs(5));
+	}
+
+	#[rstest]
+	#[case(Method::GET, false, true)]
+	#[case(Method::DELETE, false, true)]
+	#[case(Method::POST, false, false)]
+	#[case(Method::POST, true, true)]
+	fn test_retry_only_idempotent_methods_by_default(
+		#[case] method: Method,
+		#[case] non_idempotent: bool,
+		#[case] expected: bool,
+	) {
+		// Act
+		let retries = Retry::default()
+			.retry_non_idempotent(non_idempotent)
+			.retries(&method);
+
+		// Assert
+		assert_eq!(retries, expected);
+	}
+
+	#[rstest]
+	#[case("https://api.example.com/v1", true)]
+	#[case("https://API.example.com/v1", true)]
+	#[case("https://evil.example.net/v1", false)]
+	fn test_auth_header_is_restricted_to_hosts(#[case] url: &str, #[case] expected: bool) {
+		// Arrange
+		let auth = AuthHeader::basic("user", "pass").for_host("api.example.com");
+
+		// Act
+		let applies = auth.applies_to(&Url::parse(url).unwrap());
+
+		// Assert
+		assert_eq!(applies, expected);
+	}
+
+	#[rstest]
+	fn test_redacted_url_drops_credentials_and_query() {
+		// Act
+		let url = redacted_url(&Url::parse("https://user:pw@example.com/cb?code=secret").unwrap());
+
+		// Assert
+		assert_eq!(url, "https://example.com/cb");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_replay_serves_each_interaction_once() {
+		// Arrange
+		let cassette = Arc::new(
+			Cassette::new()
+				.with_interaction(Interaction::new(
+					Method::GET,
+					"https://example.com/items",
+					StatusCode::OK,
+					"[1]",
+				))
+				.with_interaction(Interaction::new(
+					Method::GET,
+					"https://example.com/items",
+					StatusCode::OK,
+					"[1,2]",
+				)),
+		);
+		let client = HttpClient::builder()
+			.replay(cassette.clone())
+			.build()
+			.unwrap();
+
+		// Act
+		let first = client.send(client.get("https://example.com/items")).await;
+		let second = client.send(client.get("https://example.com/items")).await;
+		let third = client.send(client.get("https://example.com/items")).await;
+
+		// Assert
+		assert_eq!(first.unwrap().text().await.unwrap(), "[1]");
+		assert_eq!(second.unwrap().text().await.unwrap(), "[1,2]");
+		assert!(matches!(third, Err(HttpClientError::NotRecorded { .. })));
+		assert!(cassette.is_exhausted());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_replay_matches_request_body() {
+		// Arrange
+		let cassette = Arc::new(
+			Cassette::new().with_interaction(
+				Interaction::new(
+					Method::POST,
+					"https://example.com/token",
+					StatusCode::OK,
+					"{}",
+				)
+				.with_request_body("grant_type=client_credentials"),
+			),
+		);
+		let client = HttpClient::builder().replay(cassette).build().unwrap();
+
+		// Act
+		let wrong = client
+			.send(
+				client
+					.post("https://example.com/token")
+					.body("grant_type=password"),
+			)
+			.await;
+		let right = client
+			.send(
+				client
+					.post("https://example.com/token")
+					.body("grant_type=client_credentials"),
+			)
+			.await;
+
+		// Assert
+		assert!(wrong.is_err());
+		assert_eq!(right.unwrap().status(), StatusCode::OK);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_trace_context_is_scoped() {
+		// Arrange
+		let trace = TraceContext::new("trace-1", "span-1");
+
+		// Act
+		let inside = trace.clone().scope(async { TraceContext::current() }).await;
+		let outside = TraceContext::current();
+
+		// Assert
+		assert_eq!(inside, Some(trace));
+		assert_eq!(outside, None);
+	}
+}
@@ -15,6 +15,17 @@
 //! - `ordinal` - Convert numbers to ordinals (e.g., "1st", "2nd", "3rd")
 //! - `pluralize` - Pluralize words based on count
 //!
+//! ## Localization
+//!
+//! The functions above produce English output. Each user-facing one has a
+//! `*_with` variant taking a [`HumanizeLocale`], which supplies translated
+//! messages and number separators; `reinhardt_i18n::humanize` implements it
+//! on top of the active translation catalog and locale.
+//!
+//! Messages are gettext-style msgids with a `{count}` placeholder, e.g.
+//! `"{count} minute ago"` / `"{count} minutes ago"`, so catalogs can
+//! translate them like any other string.
+//!
 //! ## Usage
 //!
 //! ```rust
@@ -35,6 +46,62 @@ use chrono::{DateTime, Duration, Utc};
 
 pub use crate::text::{intcomma, ordinal, pluralize};
 
+/// Placeholder replaced with the number in humanize messages
+pub const COUNT_PLACEHOLDER: &str = "{count}";
+
+/// Locale used by the `*_with` humanize functions
+///
+/// Every method has an English default, so an implementation only overrides
+/// what its locale changes.
+pub trait HumanizeLocale {
+	/// Translate a message
+	fn gettext(&self, message: &str) -> String {
+		message.to_string()
+	}
+
+	/// Translate a message with singular and plural forms
+	fn ngettext(&self, singular: &str, plural: &str, count: u64) -> String {
+		if count == 1 {
+			singular.to_string()
+		} else {
+			plural.to_string()
+		}
+	}
+
+	/// Separator between groups of thousands
+	fn thousands_separator(&self) -> &str {
+		","
+	}
+
+	/// Separator between the integer and fractional parts
+	fn decimal_separator(&self) -> &str {
+		"."
+	}
+
+	/// `strftime` format used by [`naturalday_with`] for other days
+	fn date_format(&self) -> &str {
+		"%b %d, %Y"
+	}
+
+	/// Ordinal form of a number, e.g. `1st`
+	fn ordinal(&self, n: i64) -> String {
+		ordinal(n)
+	}
+}
+
+/// English humanize locale
+#[derive(Debug, Clone, Copy, Default)]
+pub struct English;
+
+impl HumanizeLocale for English {}
+
+/// Translate a plural message and fill in its count
+fn count_message(locale: &dyn HumanizeLocale, singular: &str, plural: &str, count: i64) -> String {
+	locale
+		.ngettext(singular, plural, count.unsigned_abs())
+		.replace(COUNT_PLACEHOLDER, &count.to_string())
+}
+
 /// Convert a large number to a word representation
 ///
 /// # Examples
@@ -79,6 +146,38 @@ pub fn intword(n: i64) -> String {
 	}
 }
 
+/// Add thousands separators to a number using `locale`
+///
+/// # Examples
+///
+/// ```rust
+/// use reinhardt_utils::humanize::{English, HumanizeLocale, intcomma_with};
+///
+/// struct German;
+/// impl HumanizeLocale for German {
+///     fn thousands_separator(&self) -> &str { "." }
+/// }
+///
+/// assert_eq!(intcomma_with(1_234_567, &English), "1,234,567");
+/// assert_eq!(intcomma_with(-1_234_567, &German), "-1.234.567");
+/// ```
+pub fn intcomma_with(n: i64, locale: &dyn HumanizeLocale) -> String {
+	intcomma(n).replace(',', locale.thousands_separator())
+}
+
+/// Ordinal form of a number using `locale`
+///
+/// # Examples
+///
+/// ```rust
+/// use reinhardt_utils::humanize::{English, ordinal_with};
+///
+/// assert_eq!(ordinal_with(22, &English), "22nd");
+/// ```
+pub fn ordinal_with(n: i64, locale: &dyn HumanizeLocale) -> String {
+	locale.ordinal(n)
+}
+
 /// Format file size in human-readable format
 ///
 /// # Examples
@@ -90,23 +189,43 @@ pub fn intword(n: i64) -> String {
 /// assert_eq!(filesizeformat(1024), "1.0 KB");
 /// ```
 pub fn filesizeformat(bytes: u64) -> String {
+	filesizeformat_with(bytes, &English)
+}
+
+/// Format file size in human-readable format using `locale`
+///
+/// # Examples
+///
+/// ```rust
+/// use reinhardt_utils::humanize::{HumanizeLocale, filesizeformat_with};
+///
+/// struct French;
+/// impl HumanizeLocale for French {
+///     fn decimal_separator(&self) -> &str { "," }
+/// }
+///
+/// assert_eq!(filesizeformat_with(1_234_567, &French), "1,2 MB");
+/// ```
+pub fn filesizeformat_with(bytes: u64, locale: &dyn HumanizeLocale) -> String {
 	const KB: u64 = 1024;
 	const MB: u64 = KB * 1024;
 	const GB: u64 = MB * 1024;
 
-	if bytes == 0 {
-		"0 bytes".to_string()
-	} else if bytes == 1 {
-		"1 byte".to_string()
-	} else if bytes < KB {
-		format!("{} bytes", bytes)
-	} else if bytes < MB {
-		format!("{:.1} KB", bytes as f64 / KB as f64)
+	if bytes < KB {
+		return locale
+			.ngettext("{count} byte", "{count} bytes", bytes)
+			.replace(COUNT_PLACEHOLDER, &bytes.to_string());
+	}
+
+	let (size, unit) = if bytes < MB {
+		(bytes as f64 / KB as f64, "KB")
 	} else if bytes < GB {
-		format!("{:.1} MB", bytes as f64 / MB as f64)
+		(bytes as f64 / MB as f64, "MB")
 	} else {
-		format!("{:.1} GB", bytes as f64 / GB as f64)
-	}
+		(bytes as f64 / GB as f64, "GB")
+	};
+	let size = format!("{:.1}", size).replace('.', locale.decimal_separator());
+	format!("{} {}", size, unit)
 }
 
 /// Format a date as "today", "yesterday", "tomorrow", or the actual date
@@ -121,18 +240,32 @@ pub fn filesizeformat(bytes: u64) -> String {
 /// assert_eq!(naturalday(&now), "today");
 /// ```
 pub fn naturalday(dt: &DateTime<Utc>) -> String {
-	let now = Utc::now();
-	let today = now.date_naive();
+	naturalday_with(dt, &English)
+}
+
+/// Format a date as "today", "yesterday", "tomorrow", or the actual date using `locale`
+///
+/// # Examples
+///
+/// ```rust
+/// use reinhardt_utils::humanize::{English, naturalday_with};
+/// use chrono::{Duration, Utc};
+///
+/// let yesterday = Utc::now() - Duration::days(1);
+/// assert_eq!(naturalday_with(&yesterday, &English), "yesterday");
+/// ```
+pub fn naturalday_with(dt: &DateTime<Utc>, locale: &dyn HumanizeLocale) -> String {
+	let today = Utc::now().date_naive();
 	let dt_date = dt.date_naive();
 
 	if dt_date == today {
-		"today".to_string()
+		locale.gettext("today")
 	} else if dt_date == today - Duration::days(1) {
-		"yesterday".to_string()
+		locale.gettext("yesterday")
 	} else if dt_date == today + Duration::days(1) {
-		"tomorrow".to_string()
+		locale.gettext("tomorrow")
 	} else {
-		dt.format("%b %d, %Y").to_string()
+		dt.format(locale.date_format()).to_string()
 	}
 }
 
@@ -150,32 +283,82 @@ pub fn naturalday(dt: &DateTime<Utc>) -> String {
 /// assert!(result.contains("seconds ago"));
 /// ```
 pub fn naturaltime(dt: &DateTime<Utc>) -> String {
+	naturaltime_with(dt, &English)
+}
+
+/// Format a datetime as natural language using `locale`
+///
+/// # Examples
+///
+/// ```rust
+/// use reinhardt_utils::humanize::{English, naturaltime_with};
+/// use chrono::{Duration, Utc};
+///
+/// let past = Utc::now() - Duration::hours(2);
+/// assert_eq!(naturaltime_with(&past, &English), "2 hours ago");
+/// ```
+pub fn naturaltime_with(dt: &DateTime<Utc>, locale: &dyn HumanizeLocale) -> String {
 	let now = Utc::now();
 	let diff = now.signed_duration_since(*dt);
 
 	if diff.num_seconds() < 60 && diff.num_seconds() >= 0 {
 		if diff.num_seconds() < 10 {
-			"a few seconds ago".to_string()
+			locale.gettext("a few seconds ago")
 		} else {
-			format!("{} seconds ago", diff.num_seconds())
+			count_message(
+				locale,
+				"{count} second ago",
+				"{count} seconds ago",
+				diff.num_seconds(),
+			)
 		}
 	} else if diff.num_seconds() < 0 {
 		let future_diff = dt.signed_duration_since(now);
 		if future_diff.num_seconds() < 60 {
-			"a few seconds from now".to_string()
+			locale.gettext("a few seconds from now")
 		} else if future_diff.num_minutes() < 60 {
-			format!("{} minutes from now", future_diff.num_minutes())
+			count_message(
+				locale,
+				"{count} minute from now",
+				"{count} minutes from now",
+				future_diff.num_minutes(),
+			)
 		} else if future_diff.num_hours() < 24 {
-			format!("{} hours from now", future_diff.num_hours())
+			count_message(
+				locale,
+				"{count} hour from now",
+				"{count} hours from now",
+				future_diff.num_hours(),
+			)
 		} else {
-			format!("{} days from now", future_diff.num_days())
+			count_message(
+				locale,
+				"{count} day from now",
+				"{count} days from now",
+				future_diff.num_days(),
+			)
 		}
 	} else if diff.num_minutes() < 60 {
-		format!("{} minutes ago", diff.num_minutes())
+		count_message(
+			locale,
+			"{count} minute ago",
+			"{count} minutes ago",
+			diff.num_minutes(),
+		)
 	} else if diff.num_hours() < 24 {
-		format!("{} hours ago", diff.num_hours())
+		count_message(
+			locale,
+			"{count} hour ago",
+			"{count} hours ago",
+			diff.num_hours(),
+		)
 	} else {
-		format!("{} days ago", diff.num_days())
+		count_message(
+			locale,
+			"{count} day ago",
+			"{count} days ago",
+			diff.num_days(),
+		)
 	}
 }
 
@@ -13,7 +13,10 @@
 //! - `humanize`: Human-friendly formatting utilities
 //! - `logging`: Logging utilities (feature: `logging`)
 //! - `cache`: Caching utilities (feature: `cache`)
+//! - `circuit_breaker`: Circuit breaker for outbound calls, with circuits stored in a cache
+//! - `http_client`: Outbound HTTP client with middleware and record/replay
 //! - `storage`: Storage utilities (feature: `storage`)
+//! - `resumable_upload`: Resumable (tus) chunked uploads assembled into storage
 //! - `staticfiles`: Static file serving utilities (feature: `staticfiles`)
 //!
 //! ## Example
@@ -36,7 +39,10 @@
 //! ```
 
 pub mod cache;
+pub mod circuit_breaker;
+pub mod http_client;
 pub mod logging;
+pub mod resumable_upload;
 pub mod staticfiles;
 pub mod storage;
 pub mod utils_core;
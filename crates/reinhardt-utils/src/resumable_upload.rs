@@ -0,0 +1,1127 @@
+//! Resumable uploads
+//!
+//! Large files are uploaded in chunks that can be resumed after a dropped
+//! connection, following the [tus 1.0](https://tus.io/protocols/resumable-upload)
+//! protocol so that off-the-shelf clients (tus-js-client, Uppy, ...) work as
+//! well as the `reinhardt_pages::upload` helpers:
+//!
+//! 1. `POST` to the endpoint with `Upload-Length` creates an upload and
+//!    returns its URL in `Location`.
+//! 2. `PATCH` to that URL with `Upload-Offset` appends a chunk. The offset
+//!    must equal the bytes received so far.
+//! 3. After an interruption, `HEAD` returns the current `Upload-Offset` to
+//!    resume from.
+//! 4. When the last byte arrives, the chunks are assembled into the
+//!    destination [`Storage`] and the upload's checksum is verified.
+//!
+//! [`Storage::save`] takes the whole file, so assembly holds it in memory.
+//! [`ResumableUploads::with_max_size`] bounds that buffer; it defaults to
+//! 512 MiB and should only be raised as far as the server's memory allows.
+//!
+//! Upload state (offset, received parts, expected checksum) is kept in an
+//! [`UploadStateStore`], by default a [`Cache`], so every app server sharing
+//! the cache and the staging storage can accept the next chunk. Chunks are
+//! staged as separate objects of the staging storage until assembly.
+//! Requests for one upload are serialized per [`ResumableUploads`]: while a
+//! chunk is appended or the upload assembled, another request for the same
+//! id fails with [`ResumableUploadError::Locked`] (`409 Conflict` from
+//! [`TusHandler`]). State updates are read-modify-write, so app servers
+//! sharing the store rely on clients not sending chunks of one upload
+//! concurrently, which the protocol forbids anyway.
+//!
+//! # Example
+//!
+//! ```rust
+//! use reinhardt_utils::resumable_upload::ResumableUploads;
+//! use reinhardt_utils::storage::{InMemoryStorage, Storage};
+//! use std::collections::HashMap;
+//! use std::sync::Arc;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let staging = Arc::new(InMemoryStorage::new("staging", "/staging/"));
+//! let media = InMemoryStorage::new("media", "/media/");
+//! let uploads = ResumableUploads::in_memory(staging);
+//!
+//! let upload = uploads.create(11, Some("hello.txt".to_string()), HashMap::new(), None).await.unwrap();
+//! uploads.append(&upload.id, 0, b"hello ", None).await.unwrap();
+//! let upload = uploads.append(&upload.id, 6, b"world", None).await.unwrap();
+//! assert!(upload.is_complete());
+//!
+//! let stored = uploads.assemble(&upload.id, &media, "videos").await.unwrap();
+//! assert_eq!(media.read(&stored.path).await.unwrap().content, b"hello world");
+//! # }
+//! ```
+
+use crate::cache::{Cache, InMemoryCache};
+use crate::storage::{FileMetadata, Storage, StorageError};
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chrono::Utc;
+use hyper::StatusCode;
+use reinhardt_http::{BodyError, Handler, Request, Response};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+/// Protocol version sent in `Tus-Resumable` and `Tus-Version`
+pub const TUS_VERSION: &str = "1.0.0";
+
+/// Protocol extensions supported by [`TusHandler`]
+pub const TUS_EXTENSIONS: &str = "creation,termination,checksum,expiration";
+
+/// Content type of `PATCH` request bodies
+pub const OFFSET_CONTENT_TYPE: &str = "application/offset+octet-stream";
+
+/// `Upload-Metadata` key holding the expected SHA-256 (hex) of the whole file
+pub const CHECKSUM_METADATA_KEY: &str = "sha256";
+
+/// Default largest upload, bounded by the memory assembly needs
+pub const DEFAULT_MAX_SIZE: u64 = 512 * 1024 * 1024;
+
+/// Status returned when a chunk fails its `Upload-Checksum` (tus checksum extension)
+const CHECKSUM_MISMATCH_STATUS: u16 = 460;
+
+/// Errors of resumable uploads
+#[non_exhaustive]
+#[derive(Debug, thiserror::Error)]
+pub enum ResumableUploadError {
+	/// No upload with this id, or it expired
+	#[error("Upload not found: {0}")]
+	NotFound(String),
+	/// Another request for this upload is in progress
+	#[error("Upload is locked by another request: {0}")]
+	Locked(String),
+	/// A chunk did not start at the current offset
+	#[error("Offset mismatch: expected {expected}, got {actual}")]
+	OffsetMismatch {
+		/// Bytes received so far
+		expected: u64,
+		/// Offset sent by the client
+		actual: u64,
+	},
+	/// The upload or chunk is larger than allowed
+	#[error("Upload too large: {size} bytes exceeds {max}")]
+	TooLarge {
+		/// Size of the upload or the upload after the chunk
+		size: u64,
+		/// Maximum allowed size
+		max: u64,
+	},
+	/// The upload was assembled before all bytes arrived
+	#[error("Upload incomplete: {offset} of {length} bytes received")]
+	Incomplete {
+		/// Bytes received so far
+		offset: u64,
+		/// Declared upload length
+		length: u64,
+	},
+	/// A chunk or the assembled file did not match its checksum
+	#[error("Checksum mismatch")]
+	ChecksumMismatch,
+	/// The request was malformed
+	#[error("Invalid request: {0}")]
+	InvalidRequest(String),
+	/// The staging or destination storage failed
+	#[error("Storage error: {0}")]
+	Storage(#[from] StorageError),
+	/// The upload state store failed
+	#[error("State store error: {0}")]
+	State(String),
+}
+
+/// Result type of resumable uploads
+pub type ResumableUploadResult<T> = Result<T, ResumableUploadError>;
+
+/// Stored state of one upload
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UploadState {
+	/// Upload id, used in the upload URL
+	pub id: String,
+	/// Declared length in bytes
+	pub length: u64,
+	/// Bytes received so far
+	pub offset: u64,
+	/// Original file name, when the client sent one
+	pub filename: Option<String>,
+	/// Client metadata (`Upload-Metadata`)
+	pub metadata: HashMap<String, String>,
+	/// Expected SHA-256 of the whole file (lowercase hex)
+	pub checksum: Option<String>,
+	/// Start offsets of the staged chunks, in order
+	pub parts: Vec<u64>,
+	/// Destination path once assembled
+	pub stored_path: Option<String>,
+	/// When the upload was created (Unix milliseconds)
+	pub created_at: i64,
+	/// When the upload expires (Unix milliseconds)
+	pub expires_at: i64,
+}
+
+impl UploadState {
+	/// Whether every byte has been received
+	pub fn is_complete(&self) -> bool {
+		self.offset >= self.length
+	}
+}
+
+/// Storage for upload states
+#[async_trait]
+pub trait UploadStateStore: Send + Sync {
+	/// Load the state stored under `key`
+	async fn load(&self, key: &str) -> reinhardt_core::exception::Result<Option<UploadState>>;
+
+	/// Store a state under `key` for `ttl`
+	async fn save(
+		&self,
+		key: &str,
+		state: &UploadState,
+		ttl: Duration,
+	) -> reinhardt_core::exception::Result<()>;
+
+	/// Remove the state stored under `key`
+	async fn delete(&self, key: &str) -> reinhardt_core::exception::Result<()>;
+}
+
+/// [`UploadStateStore`] backed by any [`Cache`]
+pub struct CacheUploadStore<C> {
+	cache: Arc<C>,
+}
+
+impl<C: Cache> CacheUploadStore<C> {
+	/// Store upload states in `cache`
+	pub fn new(cache: Arc<C>) -> Self {
+		Self { cache }
+	}
+}
+
+#[async_trait]
+impl<C: Cache + 'static> UploadStateStore for CacheUploadStore<C> {
+	async fn load(&self, key: &str) -> reinhardt_core::exception::Result<Option<UploadState>> {
+		self.cache.get(key).await
+	}
+
+	async fn save(
+		&self,
+		key: &str,
+		state: &UploadState,
+		ttl: Duration,
+	) -> reinhardt_core::exception::Result<()> {
+		self.cache.set(key, state, Some(ttl)).await
+	}
+
+	async fn delete(&self, key: &str) -> reinhardt_core::exception::Result<()> {
+		self.cache.delete(key).await
+	}
+}
+
+/// Resumable upload manager
+///
+/// Tracks upload offsets in an [`UploadStateStore`], stages chunks in a
+/// [`Storage`] and assembles them into a destination storage.
+/// Assembly buffers the whole file, so [`max_size`](Self::max_size) is also
+/// the memory needed per upload being assembled.
+#[derive(Clone)]
+pub struct ResumableUploads {
+	staging: Arc<dyn Storage>,
+	store: Arc<dyn UploadStateStore>,
+	staging_prefix: String,
+	max_size: u64,
+	expiry: Duration,
+	in_progress: Arc<Mutex<HashSet<String>>>,
+}
+
+impl fmt::Debug for ResumableUploads {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ResumableUploads")
+			.field("staging_prefix", &self.staging_prefix)
+			.field("max_size", &self.max_size)
+			.field("expiry", &self.expiry)
+			.finish()
+	}
+}
+
+impl ResumableUploads {
+	/// Create a manager staging chunks in `staging` and tracking state in `store`
+	pub fn new(staging: Arc<dyn Storage>, store: Arc<dyn UploadStateStore>) -> Self {
+		Self {
+			staging,
+			store,
+			staging_prefix: "resumable".to_string(),
+			max_size: DEFAULT_MAX_SIZE,
+			expiry: Duration::from_secs(24 * 60 * 60),
+			in_progress: Arc::new(Mutex::new(HashSet::new())),
+		}
+	}
+
+	/// Create a manager tracking state in a cache
+	pub fn with_cache<C: Cache + 'static>(staging: Arc<dyn Storage>, cache: Arc<C>) -> Self {
+		Self::new(staging, Arc::new(CacheUploadStore::new(cache)))
+	}
+
+	/// Create a manager tracking state in a process-local cache
+	pub fn in_memory(staging: Arc<dyn Storage>) -> Self {
+		Self::with_cache(staging, Arc::new(InMemoryCache::new()))
+	}
+
+	/// Set the largest upload accepted, 512 MiB by default
+	///
+	/// Assembly holds the whole file in memory, so keep this within what
+	/// the server can buffer for each concurrent assembly.
+	pub fn with_max_size(mut self, max_size: u64) -> Self {
+		self.max_size = max_size;
+		self
+	}
+
+	/// Set how long an unfinished upload is kept, 24 hours by default
+	pub fn with_expiry(mut self, expiry: Duration) -> Self {
+		self.expiry = expiry;
+		self
+	}
+
+	/// Set the staging storage directory of chunks, `resumable` by default
+	pub fn with_staging_prefix(mut self, prefix: impl Into<String>) -> Self {
+		self.staging_prefix = prefix.into().trim_matches('/').to_string();
+		self
+	}
+
+	/// Largest upload accepted
+	pub fn max_size(&self) -> u64 {
+		self.max_size
+	}
+
+	/// Start an upload of `length` bytes
+	///
+	/// `checksum` is the expected SHA-256 of the whole file in hex, verified
+	/// on assembly.
+	pub async fn create(
+		&self,
+		length: u64,
+		filename: Option<String>,
+		metadata: HashMap<String, String>,
+		checksum: Option<String>,
+	) -> ResumableUploadResult<UploadState> {
+		if length > self.max_size {
+			return Err(ResumableUploadError::TooLarge {
+				size: length,
+				max: self.max_size,
+			});
+		}
+		if let Some(checksum) = &checksum
+			&& (checksum.len() != 64 || !checksum.chars().all(|c| c.is_ascii_hexdigit()))
+		{
+			return Err(ResumableUploadError::InvalidRequest(
+				"checksum must be a hex SHA-256 digest".to_string(),
+			));
+		}
+
+		let now = Utc::now().timestamp_millis();
+		let state = UploadState {
+			id: uuid::Uuid::new_v4().simple().to_string(),
+			length,
+			offset: 0,
+			filename,
+			metadata,
+			checksum: checksum.map(|checksum| checksum.to_ascii_lowercase()),
+			parts: Vec::new(),
+			stored_path: None,
+			created_at: now,
+			expires_at: now + i64::try_from(self.expiry.as_millis()).unwrap_or(i64::MAX),
+		};
+		self.save(&state).await?;
+		Ok(state)
+	}
+
+	/// Current state of an upload
+	pub async fn status(&self, id: &str) -> ResumableUploadResult<UploadState> {
+		if !crate::is_safe_filename_component(id) {
+			return Err(ResumableUploadError::NotFound(id.to_string()));
+		}
+		let state = self
+			.store
+			.load(&self.key(id))
+			.await
+			.map_err(|e| ResumableUploadError::State(e.to_string()))?
+			.ok_or_else(|| ResumableUploadError::NotFound(id.to_string()))?;
+		if state.expires_at <= Utc::now().timestamp_millis() {
+			return Err(ResumableUploadError::NotFound(id.to_string()));
+		}
+		Ok(state)
+	}
+
+	/// Append a chunk starting at `offset`
+	///
+	/// `chunk_checksum` is the SHA-256 of the chunk, base64 encoded as in
+	/// the tus `Upload-Checksum` header. Fails with
+	/// [`ResumableUploadError::Locked`] while another request for the upload
+	/// is in progress.
+	pub async fn append(
+		&self,
+		id: &str,
+		offset: u64,
+		data: &[u8],
+		chunk_checksum: Option<&str>,
+	) -> ResumableUploadResult<UploadState> {
+		let _lock = self.lock(id)?;
+		let mut state = self.status(id).await?;
+		if offset != state.offset {
+			return Err(ResumableUploadError::OffsetMismatch {
+				expected: state.offset,
+				actual: offset,
+			});
+		}
+		let end = offset + data.len() as u64;
+		if end > state.length {
+			return Err(ResumableUploadError::TooLarge {
+				size: end,
+				max: state.length,
+			});
+		}
+		if let Some(expected) = chunk_checksum
+			&& STANDARD.encode(Sha256::digest(data)) != expected
+		{
+			return Err(ResumableUploadError::ChecksumMismatch);
+		}
+		if data.is_empty() {
+			return Ok(state);
+		}
+
+		self.staging.save(&self.part_path(id, offset), data).await?;
+		state.parts.push(offset);
+		state.offset = end;
+		self.save(&state).await?;
+		Ok(state)
+	}
+
+	/// Assemble a complete upload into `destination` under `directory`
+	///
+	/// The file is stored as `<directory>/<id>/<filename>` and its SHA-256
+	/// checked against the expected checksum. Staged chunks are removed; on
+	/// a checksum mismatch the whole upload is discarded, since resuming
+	/// cannot repair it. The assembled file, at most
+	/// [`max_size`](Self::max_size) bytes, is held in memory while saving.
+	/// Fails with [`ResumableUploadError::Locked`] while another request for
+	/// the upload is in progress.
+	pub async fn assemble(
+		&self,
+		id: &str,
+		destination: &dyn Storage,
+		directory: &str,
+	) -> ResumableUploadResult<FileMetadata> {
+		let _lock = self.lock(id)?;
+		let mut state = self.status(id).await?;
+		if !state.is_complete() {
+			return Err(ResumableUploadError::Incomplete {
+				offset: state.offset,
+				length: state.length,
+			});
+		}
+		if let Some(path) = &state.stored_path {
+			return Ok(destination.metadata(path).await?);
+		}
+
+		let mut content = Vec::with_capacity(usize::try_from(state.length).unwrap_or(0));
+		let mut hasher = Sha256::new();
+		for offset in &state.parts {
+			let part = self.staging.read(&self.part_path(id, *offset)).await?;
+			hasher.update(&part.content);
+			content.extend_from_slice(&part.content);
+		}
+		let digest = hex::encode(hasher.finalize());
+		if content.len() as u64 != state.length
+			|| state
+				.checksum
+				.as_ref()
+				.is_some_and(|expected| *expected != digest)
+		{
+			self.abort(id).await?;
+			return Err(ResumableUploadError::ChecksumMismatch);
+		}
+
+		let directory = directory.trim_matches('/');
+		let filename = safe_filename(state.filename.as_deref());
+		let path = if directory.is_empty() {
+			format!("{}/{}", id, filename)
+		} else {
+			format!("{}/{}/{}", directory, id, filename)
+		};
+		let stored = destination
+			.save(&path, &content)
+			.await?
+			.with_checksum(digest);
+
+		self.delete_parts(&state).await;
+		state.parts.clear();
+		state.stored_path = Some(stored.path.clone());
+		self.save(&state).await?;
+		Ok(stored)
+	}
+
+	/// Discard an upload and its staged chunks
+	pub async fn abort(&self, id: &str) -> ResumableUploadResult<()> {
+		let state = self.status(id).await?;
+		self.delete_parts(&state).await;
+		self.store
+			.delete(&self.key(id))
+			.await
+			.map_err(|e| ResumableUploadError::State(e.to_string()))
+	}
+
+	async fn delete_parts(&self, state: &UploadState) {
+		for offset in &state.parts {
+			let path = self.part_path(&state.id, *offset);
+			if let Err(error) = self.staging.delete(&path).await {
+				tracing::warn!(upload = %state.id, %path, %error, "Failed to delete staged chunk");
+			}
+		}
+	}
+
+	fn lock(&self, id: &str) -> ResumableUploadResult<UploadLock> {
+		let inserted = self
+			.in_progress
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.insert(id.to_string());
+		if !inserted {
+			return Err(ResumableUploadError::Locked(id.to_string()));
+		}
+		Ok(UploadLock {
+			in_progress: self.in_progress.clone(),
+			id: id.to_string(),
+		})
+	}
+
+	async fn save(&self, state: &UploadState) -> ResumableUploadResult<()> {
+		let remaining = state.expires_at - Utc::now().timestamp_millis();
+		let ttl = Duration::from_millis(u64::try_from(remaining).unwrap_or(0).max(1));
+		self.store
+			.save(&self.key(&state.id), state, ttl)
+			.await
+			.map_err(|e| ResumableUploadError::State(e.to_string()))
+	}
+
+	fn key(&self, id: &str) -> String {
+		format!("resumable_upload:{}", id)
+	}
+
+	fn part_path(&self, id: &str, offset: u64) -> String {
+		format!("{}/{}/{:020}.part", self.staging_prefix, id, offset)
+	}
+}
+
+/// Marks an upload as in progress until dropped
+struct UploadLock {
+	in_progress: Arc<Mutex<HashSet<String>>>,
+	id: String,
+}
+
+impl Drop for UploadLock {
+	fn drop(&mut self) {
+		self.in_progress
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.remove(&self.id);
+	}
+}
+
+/// File name safe to use as a storage path component
+fn safe_filename(filename: Option<&str>) -> String {
+	let name: String = filename
+		.unwrap_or_default()
+		.rsplit(['/', '\\'])
+		.next()
+		.unwrap_or_default()
+		.chars()
+		.map(|c| {
+			if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' {
+				c
+			} else {
+				'_'
+			}
+		})
+		.collect();
+	if crate::is_safe_filename_component(&name) && !name.starts_with('.') {
+		name
+	} else {
+		"upload".to_string()
+	}
+}
+
+/// HTTP endpoint speaking the tus 1.0 protocol
+///
+/// Mount it at `base_path` for the creation endpoint and `base_path/{id}`
+/// for uploads. Completed uploads are assembled into `destination` under
+/// `directory`, and the stored path is returned in the
+/// `Upload-Stored-Path` header of the final `PATCH` and later `HEAD`s.
+pub struct TusHandler {
+	uploads: Arc<ResumableUploads>,
+	destination: Arc<dyn Storage>,
+	base_path: String,
+	directory: String,
+	max_chunk_size: usize,
+}
+
+impl TusHandler {
+	/// Create an endpoint at `base_path` assembling into `destination`
+	pub fn new(
+		uploads: Arc<ResumableUploads>,
+		destination: Arc<dyn Storage>,
+		base_path: impl Into<String>,
+	) -> Self {
+		Self {
+			uploads,
+			destination,
+			base_path: base_path.into().trim_end_matches('/').to_string(),
+			directory: "uploads".to_string(),
+			max_chunk_size: 16 * 1024 * 1024,
+		}
+	}
+
+	/// Set the destination directory of assembled files, `uploads` by default
+	pub fn with_directory(mut self, directory: impl Into<String>) -> Self {
+		self.directory = directory.into();
+		self
+	}
+
+	/// Set the largest chunk accepted per `PATCH`, 16 MiB by default
+	pub fn with_max_chunk_size(mut self, max_chunk_size: usize) -> Self {
+		self.max_chunk_size = max_chunk_size;
+		self
+	}
+
+	async fn dispatch(&self, request: &Request) -> ResumableUploadResult<Response> {
+		let path = request.uri.path().trim_end_matches('/');
+		let id = match path.strip_prefix(self.base_path.as_str()) {
+			Some("") => None,
+			Some(rest) => Some(
+				rest.strip_prefix('/')
+					.filter(|id| !id.contains('/'))
+					.ok_or_else(|| ResumableUploadError::NotFound(path.to_string()))?,
+			),
+			None => return Err(ResumableUploadError::NotFound(path.to_string())),
+		};
+
+		match (request.method.as_str(), id) {
+			("OPTIONS", _) => Ok(Response::no_content()
+				.with_header("Tus-Version", TUS_VERSION)
+				.with_header("Tus-Extension", TUS_EXTENSIONS)
+				.with_header("Tus-Checksum-Algorithm", "sha256")
+				.with_header("Tus-Max-Size", &self.uploads.max_size().to_string())),
+			("POST", None) => self.create(request).await,
+			("HEAD", Some(id)) => {
+				let state = self.uploads.status(id).await?;
+				let mut response = Response::ok()
+					.with_header("Upload-Offset", &state.offset.to_string())
+					.with_header("Upload-Length", &state.length.to_string())
+					.with_header("Cache-Control", "no-store");
+				if let Some(path) = &state.stored_path {
+					response = response.with_header("Upload-Stored-Path", path);
+				}
+				Ok(response)
+			}
+			("PATCH", Some(id)) => self.patch(request, id).await,
+			("DELETE", Some(id)) => {
+				self.uploads.abort(id).await?;
+				Ok(Response::no_content())
+			}
+			(method, _) => Err(ResumableUploadError::InvalidRequest(format!(
+				"{} is not allowed here",
+				method
+			))),
+		}
+	}
+
+	async fn create(&self, request: &Request) -> ResumableUploadResult<Response> {
+		let length = header(request, "Upload-Length")
+			.and_then(|value| value.parse::<u64>().ok())
+			.ok_or_else(|| {
+				ResumableUploadError::InvalidRequest("missing Upload-Length".to_string())
+			})?;
+		let mut metadata = header(request, "Upload-Metadata")
+			.map(|value| parse_upload_metadata(&value))
+			.transpose()?
+			.unwrap_or_default();
+		let filename = metadata
+			.get("filename")
+			.or_else(|| metadata.get("name"))
+			.cloned();
+		let checksum = metadata.remove(CHECKSUM_METADATA_KEY);
+
+		let state = self
+			.uploads
+			.create(length, filename, metadata, checksum)
+			.await?;
+		let location = format!("{}/{}", self.base_path, state.id);
+		Ok(Response::created()
+			.with_header("Location", &location)
+			.with_header("Upload-Expires", &expires_header(state.expires_at)))
+	}
+
+	async fn patch(&self, request: &Request, id: &str) -> ResumableUploadResult<Response> {
+		if header(request, "Content-Type").as_deref() != Some(OFFSET_CONTENT_TYPE) {
+			return Err(ResumableUploadError::InvalidRequest(format!(
+				"Content-Type must be {}",
+				OFFSET_CONTENT_TYPE
+			)));
+		}
+		let offset = header(request, "Upload-Offset")
+			.and_then(|value| value.parse::<u64>().ok())
+			.ok_or_else(|| {
+				ResumableUploadError::InvalidRequest("missing Upload-Offset".to_string())
+			})?;
+		let chunk_checksum = match header(request, "Upload-Checksum") {
+			Some(value) => match value.split_once(' ') {
+				Some(("sha256", digest)) => Some(digest.trim().to_string()),
+				_ => {
+					return Err(ResumableUploadError::InvalidRequest(
+						"unsupported Upload-Checksum algorithm".to_string(),
+					));
+				}
+			},
+			None => None,
+		};
+		let data =
+			request
+				.bytes_limited(self.max_chunk_size)
+				.await
+				.map_err(|error| match error {
+					BodyError::TooLarge { limit } => ResumableUploadError::TooLarge {
+						size: limit as u64 + 1,
+						max: limit as u64,
+					},
+					error => ResumableUploadError::InvalidRequest(error.to_string()),
+				})?;
+
+		let mut state = self
+			.uploads
+			.append(id, offset, &data, chunk_checksum.as_deref())
+			.await?;
+		if state.is_complete() && state.stored_path.is_none() {
+			let stored = self
+				.uploads
+				.assemble(id, self.destination.as_ref(), &self.directory)
+				.await?;
+			state.stored_path = Some(stored.path);
+		}
+
+		let mut response = Response::no_content()
+			.with_header("Upload-Offset", &state.offset.to_string())
+			.with_header("Upload-Expires", &expires_header(state.expires_at));
+		if let Some(path) = &state.stored_path {
+			response = response.with_header("Upload-Stored-Path", path);
+		}
+		Ok(response)
+	}
+}
+
+#[async_trait]
+impl Handler for TusHandler {
+	async fn handle(&self, request: Request) -> reinhardt_http::Result<Response> {
+		let is_options = request.method == hyper::Method::OPTIONS;
+		if !is_options && header(&request, "Tus-Resumable").as_deref() != Some(TUS_VERSION) {
+			return Ok(Response::new(StatusCode::PRECONDITION_FAILED)
+				.with_header("Tus-Version", TUS_VERSION));
+		}
+
+		let response = match self.dispatch(&request).await {
+			Ok(response) => response,
+			Err(error) => {
+				let status = match &error {
+					ResumableUploadError::NotFound(_) => StatusCode::NOT_FOUND,
+					ResumableUploadError::Locked(_)
+					| ResumableUploadError::OffsetMismatch { .. } => StatusCode::CONFLICT,
+					ResumableUploadError::TooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+					ResumableUploadError::Incomplete { .. }
+					| ResumableUploadError::InvalidRequest(_) => StatusCode::BAD_REQUEST,
+					ResumableUploadError::ChecksumMismatch => {
+						StatusCode::from_u16(CHECKSUM_MISMATCH_STATUS)
+							.expect("460 is a valid status code")
+					}
+					ResumableUploadError::Storage(_) | ResumableUploadError::State(_) => {
+						tracing::error!(%error, "Resumable upload failed");
+						StatusCode::INTERNAL_SERVER_ERROR
+					}
+				};
+				Response::new(status).with_body(error.to_string())
+			}
+		};
+		Ok(response.with_header("Tus-Resumable", TUS_VERSION))
+	}
+}
+
+/// Parse an `Upload-Metadata` header: comma separated `key base64value` pairs
+pub fn parse_upload_metadata(header: &str) -> ResumableUploadResult<HashMap<String, String>> {
+	let mut metadata = HashMap::new();
+	for pair in header
+		.split(',')
+		.map(str::trim)
+		.filter(|pair| !pair.is_empty())
+	{
+		let (key, value) = pair.split_once(' ').unwrap_or((pair, ""));
+		let value = STANDARD
+			.decode(value.trim())
+			.ok()
+			.and_then(|bytes| String::from_utf8(bytes).ok())
+			.ok_or_else(|| {
+				ResumableUploadError::InvalidRequest(format!("invalid metadata value for {}", key))
+			})?;
+		metadata.insert(key.to_string(), value);
+	}
+	Ok(metadata)
+}
+
+fn header(request: &Request, name: &str) -> Option<String> {
+	request
+		.headers
+		.get(name)
+		.and_then(|value| value.to_str().ok())
+		.map(|value| value.trim().to_string())
+}
+
+fn expires_header(expires_at: i64) -> String {
+	chrono::DateTime::from_timestamp_millis(expires_at)
+		.unwrap_or_default()
+		.format("%a, %d %b %Y %H:%M:%S GMT")
+		.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::storage::InMemoryStorage;
+	use bytes::Bytes;
+	use hyper::header::{HeaderName, HeaderValue};
+	use hyper::{HeaderMap, Method};
+	use rstest::rstest;
+
+	fn uploads() -> ResumableUploads {
+		ResumableUploads::in_memory(Arc::new(InMemoryStorage::new("staging", "/staging/")))
+	}
+
+	fn tus_request(method: Method, uri: &str, headers: &[(&str, &str)], body: &[u8]) -> Request {
+		let mut map = HeaderMap::new();
+		map.insert("tus-resumable", HeaderValue::from_static(TUS_VERSION));
+		for (name, value) in headers {
+			map.insert(
+				HeaderName::from_bytes(name.as_bytes()).unwrap(),
+				HeaderValue::from_str(value).unwrap(),
+			);
+		}
+		Request::builder()
+			.method(method)
+			.uri(uri)
+			.headers(map)
+			.body(Bytes::copy_from_slice(body))
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_append_rejects_wrong_offset() {
+		// Arrange
+		let uploads = uploads();
+		let upload = uploads
+			.create(10, None, HashMap::new(), None)
+			.await
+			.unwrap();
+		uploads.append(&upload.id, 0, b"hello", None).await.unwrap();
+
+		// Act
+		let result = uploads.append(&upload.id, 0, b"hello", None).await;
+
+		// Assert
+		assert!(matches!(
+			result,
+			Err(ResumableUploadError::OffsetMismatch {
+				expected: 5,
+				actual: 0
+			})
+		));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_create_rejects_upload_over_default_max_size() {
+		// Arrange
+		let uploads = uploads();
+
+		// Act
+		let result = uploads
+			.create(DEFAULT_MAX_SIZE + 1, None, HashMap::new(), None)
+			.await;
+
+		// Assert
+		assert!(matches!(
+			result,
+			Err(ResumableUploadError::TooLarge { size, max: DEFAULT_MAX_SIZE })
+				if size == DEFAULT_MAX_SIZE + 1
+		));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_append_rejects_concurrent_request() {
+		// Arrange
+		let uploads = uploads();
+		let upload = uploads
+			.create(10, None, HashMap::new(), None)
+			.await
+			.unwrap();
+		let _lock = uploads.lock(&upload.id).unwrap();
+
+		// Act
+		let result = uploads.append(&upload.id, 0, b"hello", None).await;
+
+		// Assert
+		assert!(matches!(result, Err(ResumableUploadError::Locked(id)) if id == upload.id));
+		assert_eq!(uploads.status(&upload.id).await.unwrap().offset, 0);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_append_releases_lock() {
+		// Arrange
+		let uploads = uploads();
+		let upload = uploads
+			.create(10, None, HashMap::new(), None)
+			.await
+			.unwrap();
+		uploads.append(&upload.id, 0, b"hello", None).await.unwrap();
+
+		// Act
+		let state = uploads.append(&upload.id, 5, b"world", None).await.unwrap();
+
+		// Assert
+		assert_eq!(state.offset, 10);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_assemble_verifies_checksum() {
+		// Arrange
+		let uploads = uploads();
+		let destination = InMemoryStorage::new("media", "/media/");
+		let wrong = hex::encode(Sha256::digest(b"other"));
+		let upload = uploads
+			.create(5, None, HashMap::new(), Some(wrong))
+			.await
+			.unwrap();
+		uploads.append(&upload.id, 0, b"hello", None).await.unwrap();
+
+		// Act
+		let result = uploads.assemble(&upload.id, &destination, "files").await;
+
+		// Assert
+		assert!(matches!(
+			result,
+			Err(ResumableUploadError::ChecksumMismatch)
+		));
+		assert!(matches!(
+			uploads.status(&upload.id).await,
+			Err(ResumableUploadError::NotFound(_))
+		));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_assemble_stores_file_and_removes_parts() {
+		// Arrange
+		let staging = Arc::new(InMemoryStorage::new("staging", "/staging/"));
+		let uploads = ResumableUploads::in_memory(staging.clone());
+		let destination = InMemoryStorage::new("media", "/media/");
+		let checksum = hex::encode(Sha256::digest(b"hello world"));
+		let upload = uploads
+			.create(
+				11,
+				Some("../My Video.mp4".to_string()),
+				HashMap::new(),
+				Some(checksum.clone()),
+			)
+			.await
+			.unwrap();
+		uploads
+			.append(&upload.id, 0, b"hello ", None)
+			.await
+			.unwrap();
+		uploads.append(&upload.id, 6, b"world", None).await.unwrap();
+
+		// Act
+		let stored = uploads
+			.assemble(&upload.id, &destination, "videos")
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(stored.path, format!("videos/{}/My_Video.mp4", upload.id));
+		assert_eq!(stored.checksum, Some(checksum));
+		assert_eq!(
+			destination.read(&stored.path).await.unwrap().content,
+			b"hello world"
+		);
+		assert!(
+			!staging
+				.exists(&format!("resumable/{}/{:020}.part", upload.id, 0))
+				.await
+				.unwrap()
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_tus_protocol_round_trip() {
+		// Arrange
+		let destination = Arc::new(InMemoryStorage::new("media", "/media/"));
+		let handler = TusHandler::new(Arc::new(uploads()), destination.clone(), "/uploads/");
+		let metadata = format!("filename {}", STANDARD.encode("notes.txt"));
+
+		// Act
+		let created = handler
+			.handle(tus_request(
+				Method::POST,
+				"/uploads",
+				&[("upload-length", "11"), ("upload-metadata", &metadata)],
+				b"",
+			))
+			.await
+			.unwrap();
+		let location = created.headers["location"].to_str().unwrap().to_string();
+		let first = handler
+			.handle(tus_request(
+				Method::PATCH,
+				&location,
+				&[
+					("content-type", OFFSET_CONTENT_TYPE),
+					("upload-offset", "0"),
+				],
+				b"hello ",
+			))
+			.await
+			.unwrap();
+		let head = handler
+			.handle(tus_request(Method::HEAD, &location, &[], b""))
+			.await
+			.unwrap();
+		let chunk_checksum = format!("sha256 {}", STANDARD.encode(Sha256::digest(b"world")));
+		let last = handler
+			.handle(tus_request(
+				Method::PATCH,
+				&location,
+				&[
+					("content-type", OFFSET_CONTENT_TYPE),
+					("upload-offset", "6"),
+					("upload-checksum", &chunk_checksum),
+				],
+				b"world",
+			))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(created.status, StatusCode::CREATED);
+		assert_eq!(first.status, StatusCode::NO_CONTENT);
+		assert_eq!(head.headers["upload-offset"], "6");
+		assert_eq!(last.headers["upload-offset"], "11");
+		let stored_path = last.headers["upload-stored-path"].to_str().unwrap();
+		assert!(stored_path.ends_with("/notes.txt"));
+		assert_eq!(
+			destination.read(stored_path).await.unwrap().content,
+			b"hello world"
+		);
+	}
+
+	#[rstest]
+	#[case::wrong_offset(&[("content-type", OFFSET_CONTENT_TYPE), ("upload-offset", "3")], 409)]
+	#[case::wrong_content_type(&[("content-type", "text/plain"), ("upload-offset", "0")], 400)]
+	#[case::bad_checksum(
+		&[
+			("content-type", OFFSET_CONTENT_TYPE),
+			("upload-offset", "0"),
+			("upload-checksum", "sha256 AAAA"),
+		],
+		460
+	)]
+	#[tokio::test]
+	async fn test_tus_patch_errors(#[case] headers: &[(&str, &str)], #[case] status: u16) {
+		// Arrange
+		let uploads = Arc::new(uploads());
+		let upload = uploads.create(5, None, HashMap::new(), None).await.unwrap();
+		let handler = TusHandler::new(
+			uploads,
+			Arc::new(InMemoryStorage::new("media", "/media/")),
+			"/uploads",
+		);
+
+		// Act
+		let response = handler
+			.handle(tus_request(
+				Method::PATCH,
+				&format!("/uploads/{}", upload.id),
+				headers,
+				b"hello",
+			))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status.as_u16(), status);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_tus_patch_conflicts_with_request_in_progress() {
+		// Arrange
+		let uploads = Arc::new(uploads());
+		let upload = uploads.create(5, None, HashMap::new(), None).await.unwrap();
+		let _lock = uploads.lock(&upload.id).unwrap();
+		let handler = TusHandler::new(
+			uploads.clone(),
+			Arc::new(InMemoryStorage::new("media", "/media/")),
+			"/uploads",
+		);
+
+		// Act
+		let response = handler
+			.handle(tus_request(
+				Method::PATCH,
+				&format!("/uploads/{}", upload.id),
+				&[
+					("content-type", OFFSET_CONTENT_TYPE),
+					("upload-offset", "0"),
+				],
+				b"hello",
+			))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::CONFLICT);
+		assert_eq!(uploads.status(&upload.id).await.unwrap().offset, 0);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_tus_requires_protocol_header() {
+		// Arrange
+		let handler = TusHandler::new(
+			Arc::new(uploads()),
+			Arc::new(InMemoryStorage::new("media", "/media/")),
+			"/uploads",
+		);
+		let request = Request::builder()
+			.method(Method::POST)
+			.uri("/uploads")
+			.build()
+			.unwrap();
+
+		// Act
+		let response = handler.handle(request).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::PRECONDITION_FAILED);
+	}
+}
@@ -107,6 +107,9 @@ pub fn escapejs(text: &str) -> String {
 }
 /// Convert string to slug (URL-friendly format)
 ///
+/// Non-ASCII characters are transliterated to their closest ASCII
+/// representation first, so accented and non-Latin text keeps its words.
+///
 /// # Examples
 ///
 /// ```
@@ -116,9 +119,11 @@ pub fn escapejs(text: &str) -> String {
 /// assert_eq!(slugify("Hello  World"), "hello-world");
 /// assert_eq!(slugify("Test 123"), "test-123");
 /// assert_eq!(slugify("Special!@#Characters"), "special-characters");
+/// assert_eq!(slugify("Crème brûlée"), "creme-brulee");
 /// ```
 pub fn slugify(text: &str) -> String {
-	text.to_lowercase()
+	deunicode::deunicode(text)
+		.to_lowercase()
 		.chars()
 		.map(|ch| match ch {
 			'a'..='z' | '0'..='9' => ch,
@@ -479,8 +484,10 @@ mod tests {
 
 	#[test]
 	fn test_slugify_unicode() {
-		// Unicode characters are converted to dashes, then consecutive dashes are collapsed
-		assert_eq!(slugify("Hello 世界"), "hello");
+		// Unicode characters are transliterated before slugifying
+		assert_eq!(slugify("Hello 世界"), "hello-shi-jie");
+		assert_eq!(slugify("Ærøskøbing Straße"), "aeroskobing-strasse");
+		assert_eq!(slugify("Привет, мир"), "privet-mir");
 	}
 
 	#[test]
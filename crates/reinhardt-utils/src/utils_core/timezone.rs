@@ -1,11 +1,37 @@
 //! Timezone utilities
 //!
 //! Provides timezone-aware datetime handling similar to Django's timezone utilities.
+//!
+//! # Time zone policy
+//!
+//! With `USE_TZ` enabled (the default), datetimes are stored in UTC and only
+//! converted to a local time zone for display. [`set_timezone_policy`] installs
+//! the `USE_TZ` flag and the default time zone (`TIME_ZONE`) from settings;
+//! [`activate_timezone`] overrides the time zone for the duration of a request.
+//!
+//! ```
+//! use reinhardt_utils::utils_core::timezone::{
+//!     activate_timezone, get_current_timezone, parse_timezone,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let tokyo = parse_timezone("Asia/Tokyo").unwrap();
+//! let current = activate_timezone(tokyo, async { get_current_timezone() }).await;
+//! assert_eq!(current, tokyo);
+//! # }
+//! ```
 
 use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, RwLock};
 
 use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
 use chrono_tz::Tz;
+
+use super::checks::{Check, CheckMessage};
 /// Get the current time in UTC
 ///
 /// # Examples
@@ -246,6 +272,175 @@ pub fn format_datetime(dt: &DateTime<Utc>) -> String {
 	dt.to_rfc3339()
 }
 
+static USE_TZ: AtomicBool = AtomicBool::new(true);
+static DEFAULT_TIMEZONE: RwLock<Tz> = RwLock::new(Tz::UTC);
+static NAIVE_DATETIME_FIELDS: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+tokio::task_local! {
+	static ACTIVE_TIMEZONE: Tz;
+}
+
+/// Parse an IANA time zone name
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_utils::utils_core::timezone::parse_timezone;
+///
+/// assert!(parse_timezone("Europe/Paris").is_ok());
+/// assert!(parse_timezone("Mars/Olympus").is_err());
+/// ```
+pub fn parse_timezone(name: &str) -> Result<Tz, String> {
+	name.parse::<Tz>()
+		.map_err(|e| format!("Invalid timezone '{}': {}", name, e))
+}
+
+/// Install the time zone policy (`USE_TZ` and `TIME_ZONE` settings)
+///
+/// # Errors
+///
+/// Returns an error if `time_zone` is not a valid IANA name; the current
+/// policy is left unchanged in that case.
+pub fn set_timezone_policy(use_tz: bool, time_zone: &str) -> Result<(), String> {
+	let tz = parse_timezone(time_zone)?;
+	*DEFAULT_TIMEZONE
+		.write()
+		.unwrap_or_else(|poisoned| poisoned.into_inner()) = tz;
+	USE_TZ.store(use_tz, Ordering::Relaxed);
+	Ok(())
+}
+
+/// Whether time zone support (`USE_TZ`) is enabled
+pub fn use_tz() -> bool {
+	USE_TZ.load(Ordering::Relaxed)
+}
+
+/// The default time zone (`TIME_ZONE` setting), UTC unless configured
+pub fn get_default_timezone() -> Tz {
+	*DEFAULT_TIMEZONE
+		.read()
+		.unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// The time zone active for the current task
+///
+/// Falls back to the default time zone outside [`activate_timezone`].
+pub fn get_current_timezone() -> Tz {
+	ACTIVE_TIMEZONE
+		.try_with(|tz| *tz)
+		.unwrap_or_else(|_| get_default_timezone())
+}
+
+/// Run `future` with `tz` as the current time zone
+///
+/// Typically called once per request by a middleware, with the time zone
+/// chosen by the user.
+pub async fn activate_timezone<F: Future>(tz: Tz, future: F) -> F::Output {
+	ACTIVE_TIMEZONE.scope(tz, future).await
+}
+
+/// Make a naive datetime aware in the given time zone
+///
+/// For ambiguous datetimes (fall-back), the earlier interpretation is used.
+///
+/// # Errors
+///
+/// Returns an error if the datetime falls in a DST gap of `tz`.
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_utils::utils_core::timezone::{make_aware, parse_timezone};
+/// use chrono::{NaiveDateTime, Timelike, Utc};
+///
+/// let tokyo = parse_timezone("Asia/Tokyo").unwrap();
+/// let naive = NaiveDateTime::parse_from_str("2025-01-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let aware = make_aware(naive, tokyo).unwrap();
+/// assert_eq!(aware.with_timezone(&Utc).hour(), 0);
+/// ```
+pub fn make_aware(dt: NaiveDateTime, tz: Tz) -> Result<DateTime<Tz>, String> {
+	tz.from_local_datetime(&dt).earliest().ok_or_else(|| {
+		format!(
+			"datetime {} falls in a DST gap and does not exist in {}",
+			dt, tz
+		)
+	})
+}
+
+/// Convert a UTC datetime to the current time zone for display
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_utils::utils_core::timezone::{now, to_current_timezone};
+///
+/// let dt = now();
+/// assert_eq!(to_current_timezone(&dt), dt);
+/// ```
+pub fn to_current_timezone(dt: &DateTime<Utc>) -> DateTime<Tz> {
+	dt.with_timezone(&get_current_timezone())
+}
+
+/// Record that `field` received a naive datetime while time zone support is active
+///
+/// The value should still be made aware (in the default time zone) by the
+/// caller. Recorded fields are reported by [`NaiveDateTimeCheck`].
+pub fn record_naive_datetime(field: &str) {
+	let first = NAIVE_DATETIME_FIELDS
+		.lock()
+		.unwrap_or_else(|poisoned| poisoned.into_inner())
+		.insert(field.to_string());
+	if first {
+		tracing::warn!(
+			field,
+			"received a naive datetime while time zone support is active"
+		);
+	}
+}
+
+/// System check (`timezone.W001`) for fields that received naive datetimes
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_utils::utils_core::checks::CheckRegistry;
+/// use reinhardt_utils::utils_core::timezone::NaiveDateTimeCheck;
+///
+/// CheckRegistry::global()
+///     .lock()
+///     .unwrap()
+///     .register(Box::new(NaiveDateTimeCheck));
+/// ```
+pub struct NaiveDateTimeCheck;
+
+impl Check for NaiveDateTimeCheck {
+	fn tags(&self) -> Vec<String> {
+		vec!["timezone".to_string(), "models".to_string()]
+	}
+
+	fn check(&self) -> Vec<CheckMessage> {
+		NAIVE_DATETIME_FIELDS
+			.lock()
+			.unwrap_or_else(|poisoned| poisoned.into_inner())
+			.iter()
+			.map(|field| {
+				CheckMessage::warning(
+					"timezone.W001",
+					format!(
+						"{} received a naive datetime while time zone support is active",
+						field
+					),
+				)
+				.with_hint(format!(
+					"Pass timezone-aware values (e.g. `timezone::now()`); naive values are \
+					 interpreted in the default time zone ({}).",
+					get_default_timezone()
+				))
+			})
+			.collect()
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -435,6 +630,62 @@ mod tests {
 		assert_eq!(aware.naive_local(), naive);
 	}
 
+	#[rstest]
+	fn test_make_aware_rejects_dst_gap() {
+		// Arrange
+		let new_york = parse_timezone("America/New_York").unwrap();
+		let gap = NaiveDateTime::from_str("2025-03-09T02:30:00").unwrap();
+		let ambiguous = NaiveDateTime::from_str("2025-11-02T01:30:00").unwrap();
+
+		// Act
+		let gap_result = make_aware(gap, new_york);
+		let ambiguous_result = make_aware(ambiguous, new_york).unwrap();
+
+		// Assert
+		assert!(gap_result.unwrap_err().contains("DST gap"));
+		assert_eq!(
+			ambiguous_result.with_timezone(&Utc).to_rfc3339(),
+			"2025-11-02T05:30:00+00:00"
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_activate_timezone_scopes_current_timezone() {
+		// Arrange
+		let tokyo = parse_timezone("Asia/Tokyo").unwrap();
+		let dt = parse_datetime("2025-01-01T00:00:00Z").unwrap();
+
+		// Act
+		let inside = activate_timezone(tokyo, async move {
+			(
+				get_current_timezone(),
+				to_current_timezone(&dt).to_rfc3339(),
+			)
+		})
+		.await;
+
+		// Assert
+		assert_eq!(inside, (tokyo, "2025-01-01T09:00:00+09:00".to_string()));
+		assert_eq!(get_current_timezone(), get_default_timezone());
+	}
+
+	#[rstest]
+	fn test_naive_datetime_check_reports_recorded_fields() {
+		// Arrange
+		record_naive_datetime("blog_post.published_at");
+
+		// Act
+		let messages = NaiveDateTimeCheck.check();
+
+		// Assert
+		assert!(messages.iter().any(|m| {
+			m.id == "timezone.W001"
+				&& m.message
+					.starts_with("blog_post.published_at received a naive datetime")
+		}));
+	}
+
 	#[rstest]
 	fn test_make_aware_local_error_message_contains_datetime() {
 		// Arrange
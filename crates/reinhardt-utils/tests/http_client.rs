@@ -0,0 +1,154 @@
+//! Outbound HTTP client tests against a mock HTTP server.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use reinhardt_utils::http_client::{
+	AuthHeader, Cassette, HttpClient, Retry, TraceContext, TracePropagation,
+};
+use rstest::rstest;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn auth_header_is_injected() {
+	// Arrange
+	let server = MockServer::start().await;
+	Mock::given(method("GET"))
+		.and(path("/me"))
+		.and(header("authorization", "Bearer s3cr3t"))
+		.respond_with(ResponseTemplate::new(200))
+		.mount(&server)
+		.await;
+	let client = HttpClient::builder()
+		.middleware(AuthHeader::bearer("s3cr3t").unwrap())
+		.build()
+		.unwrap();
+
+	// Act
+	let response = client
+		.send(client.get(format!("{}/me", server.uri())))
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), 200);
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn transient_failures_are_retried() {
+	// Arrange
+	let server = MockServer::start().await;
+	Mock::given(method("GET"))
+		.respond_with(ResponseTemplate::new(503))
+		.up_to_n_times(2)
+		.expect(2)
+		.mount(&server)
+		.await;
+	Mock::given(method("GET"))
+		.respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+		.mount(&server)
+		.await;
+	let client = HttpClient::builder()
+		.middleware(Retry::default().with_base_delay(Duration::from_millis(1)))
+		.build()
+		.unwrap();
+
+	// Act
+	let response = client.send(client.get(server.uri())).await.unwrap();
+
+	// Assert
+	assert_eq!(response.text().await.unwrap(), "ok");
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn non_idempotent_requests_are_not_retried() {
+	// Arrange
+	let server = MockServer::start().await;
+	Mock::given(method("POST"))
+		.respond_with(ResponseTemplate::new(503))
+		.expect(1)
+		.mount(&server)
+		.await;
+	let client = HttpClient::builder()
+		.middleware(Retry::default().with_base_delay(Duration::from_millis(1)))
+		.build()
+		.unwrap();
+
+	// Act
+	let response = client.send(client.post(server.uri())).await.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), 503);
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn trace_context_is_propagated() {
+	// Arrange
+	let server = MockServer::start().await;
+	Mock::given(method("GET"))
+		.and(header("x-trace-id", "trace-1"))
+		.and(header("x-parent-span-id", "span-1"))
+		.respond_with(ResponseTemplate::new(204))
+		.mount(&server)
+		.await;
+	let client = HttpClient::builder()
+		.middleware(TracePropagation::default())
+		.build()
+		.unwrap();
+
+	// Act
+	let response = TraceContext::new("trace-1", "span-1")
+		.scope(client.send(client.get(server.uri())))
+		.await
+		.unwrap();
+
+	// Assert
+	assert_eq!(response.status(), 204);
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn recorded_interactions_replay_without_network() {
+	// Arrange
+	let server = MockServer::start().await;
+	Mock::given(method("GET"))
+		.and(path("/items"))
+		.respond_with(
+			ResponseTemplate::new(200)
+				.insert_header("content-type", "application/json")
+				.set_body_string("[1,2]"),
+		)
+		.expect(1)
+		.mount(&server)
+		.await;
+	let dir = tempfile::tempdir().unwrap();
+	let file = dir.path().join("items.json");
+	let url = format!("{}/items", server.uri());
+	let recorder = HttpClient::builder()
+		.middleware(AuthHeader::bearer("s3cr3t").unwrap())
+		.record(Arc::new(Cassette::open(&file).unwrap()))
+		.build()
+		.unwrap();
+	recorder.send(recorder.get(&url)).await.unwrap();
+
+	// Act
+	let player = HttpClient::builder()
+		.replay(Arc::new(Cassette::open(&file).unwrap()))
+		.build()
+		.unwrap();
+	let response = player.send(player.get(&url)).await.unwrap();
+
+	// Assert
+	let recorded = std::fs::read_to_string(&file).unwrap();
+	assert!(!recorded.contains("s3cr3t"));
+	assert_eq!(
+		response.headers().get("content-type").unwrap(),
+		"application/json"
+	);
+	assert_eq!(response.text().await.unwrap(), "[1,2]");
+}
@@ -149,3 +149,68 @@ fn test_negative_numbers() {
 	assert_eq!(humanize::intcomma(-5000), "-5,000");
 	assert_eq!(humanize::intword(-2000000), "-2 million");
 }
+
+/// Locale translating into a pseudo-language and using continental separators
+struct Pseudo;
+
+impl humanize::HumanizeLocale for Pseudo {
+	fn gettext(&self, message: &str) -> String {
+		format!("[{}]", message)
+	}
+
+	fn ngettext(&self, singular: &str, plural: &str, count: u64) -> String {
+		self.gettext(if count == 1 { singular } else { plural })
+	}
+
+	fn thousands_separator(&self) -> &str {
+		"."
+	}
+
+	fn decimal_separator(&self) -> &str {
+		","
+	}
+}
+
+#[test]
+fn test_naturaltime_singular() {
+	let now = Utc::now();
+	assert_eq!(
+		humanize::naturaltime(&(now - Duration::minutes(1))),
+		"1 minute ago"
+	);
+	assert_eq!(
+		humanize::naturaltime(&(now - Duration::hours(1))),
+		"1 hour ago"
+	);
+	assert_eq!(
+		humanize::naturaltime(&(now - Duration::days(1))),
+		"1 day ago"
+	);
+}
+
+#[test]
+fn test_humanize_with_locale() {
+	let now = Utc::now();
+	assert_eq!(
+		humanize::naturaltime_with(&(now - Duration::hours(2)), &Pseudo),
+		"[2 hours ago]"
+	);
+	assert_eq!(humanize::naturalday_with(&now, &Pseudo), "[today]");
+	assert_eq!(humanize::intcomma_with(-1234567, &Pseudo), "-1.234.567");
+	assert_eq!(humanize::filesizeformat_with(1536, &Pseudo), "1,5 KB");
+	assert_eq!(humanize::filesizeformat_with(1, &Pseudo), "[1 byte]");
+	assert_eq!(humanize::ordinal_with(3, &Pseudo), "3rd");
+}
+
+#[test]
+fn test_english_locale_matches_defaults() {
+	let past = Utc::now() - Duration::minutes(5);
+	assert_eq!(
+		humanize::naturaltime_with(&past, &humanize::English),
+		humanize::naturaltime(&past)
+	);
+	assert_eq!(
+		humanize::filesizeformat_with(1_234_567, &humanize::English),
+		"1.2 MB"
+	);
+}
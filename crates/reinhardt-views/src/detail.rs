@@ -1,13 +1,10 @@
 //! DetailView implementation for displaying a single object.
 
 use crate::core::View;
-use crate::mixins::SingleObjectMixin;
+use crate::mixins::{SingleObjectMixin, lookup_object};
 use async_trait::async_trait;
 use reinhardt_core::exception::{Error, Result};
-use reinhardt_db::orm::{
-	Model, QuerySet,
-	query::{Filter, FilterOperator, FilterValue},
-};
+use reinhardt_db::orm::{Model, QuerySet};
 use reinhardt_http::{Request, Response};
 use reinhardt_rest::serializers::{JsonSerializer, Serializer};
 use serde::{Deserialize, Serialize};
@@ -428,51 +425,7 @@ where
 			)
 		})?;
 
-		// Try to extract pk or slug from URL parameters
-		let pk_kwarg = self.pk_url_kwarg();
-		let slug_kwarg = self.slug_url_kwarg();
-
-		// Try to get pk from URL parameters
-		if let Some(pk_value) = request.path_params.get(pk_kwarg) {
-			let pk_field = T::primary_key_field();
-
-			// Try to parse as i64 first (most common primary key type)
-			let filter_value = if let Ok(int_pk) = pk_value.parse::<i64>() {
-				FilterValue::Integer(int_pk)
-			} else {
-				// Fallback to string if not a valid integer
-				FilterValue::String(pk_value.clone())
-			};
-
-			let results = queryset
-				.clone()
-				.filter(Filter::new(pk_field, FilterOperator::Eq, filter_value))
-				.all()
-				.await?;
-
-			return results.into_iter().next().ok_or_else(|| {
-				Error::NotFound(format!("Object with pk='{}' not found", pk_value))
-			});
-		}
-
-		// Try to get slug from URL parameters
-		if let Some(slug_value) = request.path_params.get(slug_kwarg) {
-			let results = queryset
-				.clone()
-				.filter(Filter::new(
-					self.get_slug_field(),
-					FilterOperator::Eq,
-					FilterValue::String(slug_value.clone()),
-				))
-				.all()
-				.await?;
-
-			return results.into_iter().next().ok_or_else(|| {
-				Error::NotFound(format!("Object with slug='{}' not found", slug_value))
-			});
-		}
-
-		Err(Error::NotFound("No pk or slug provided in URL".to_string()))
+		lookup_object(self, queryset, request).await
 	}
 
 	fn get_slug_field(&self) -> &str {
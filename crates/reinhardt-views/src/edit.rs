@@ -0,0 +1,372 @@
+//! Generic editing views: CreateView, UpdateView, DeleteView and FormView.
+//!
+//! The views mirror Django's editing class-based views. Each one can render
+//! either JSON (the default) or an HTML template through a
+//! [`TemplateRenderer`], selected with [`RenderMode`]:
+//!
+//! - In JSON mode, successful writes return the serialized object (or
+//!   `204 No Content` for deletes) and failures surface as errors.
+//! - In template mode, `GET` renders the form or confirmation page, invalid
+//!   submissions re-render the template with `errors`, and successful
+//!   submissions redirect to the configured success URL.
+//!
+//! Success URLs may reference fields of the saved object, e.g.
+//! `"/articles/{id}/"`. Permission classes added with `add_permission` are
+//! checked before any other work is done.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use reinhardt_views::{CreateView, RenderMode};
+//!
+//! let view = CreateView::<Article>::new()
+//!     .with_success_url("/articles/{id}/")
+//!     .with_render_mode(RenderMode::template("articles/form.html", renderer));
+//! ```
+
+mod create;
+mod delete;
+mod form;
+mod update;
+
+pub use create::CreateView;
+pub use delete::DeleteView;
+pub use form::{FormHandler, FormView};
+pub use update::UpdateView;
+
+use std::sync::Arc;
+
+use reinhardt_auth::{Permission, PermissionContext};
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_http::{AuthState, Request, Response};
+use reinhardt_rest::serializers::{Serializer, SerializerError};
+use serde_json::{Map, Value};
+
+use crate::core::Context;
+
+/// Renders a named template with a view context
+///
+/// Implement this for the template engine used by the project to enable
+/// [`RenderMode::Template`].
+pub trait TemplateRenderer: Send + Sync {
+	/// Renders `template_name` with `context` into an HTML string
+	fn render(&self, template_name: &str, context: &Context) -> Result<String>;
+}
+
+/// How an editing view renders its responses
+#[derive(Clone, Default)]
+pub enum RenderMode {
+	/// Serialize objects and errors as JSON
+	#[default]
+	Json,
+	/// Render HTML with a template and redirect on success
+	Template {
+		/// Name of the template passed to the renderer
+		template_name: String,
+		/// Renderer for the template
+		renderer: Arc<dyn TemplateRenderer>,
+	},
+}
+
+impl RenderMode {
+	/// Creates a template render mode
+	pub fn template(template_name: impl Into<String>, renderer: Arc<dyn TemplateRenderer>) -> Self {
+		Self::Template {
+			template_name: template_name.into(),
+			renderer,
+		}
+	}
+
+	/// Returns `true` for [`RenderMode::Template`]
+	pub fn is_template(&self) -> bool {
+		matches!(self, Self::Template { .. })
+	}
+
+	/// Renders the template with `context`
+	///
+	/// Returns `None` in JSON mode.
+	pub(crate) fn render(&self, context: &Context) -> Option<Result<Response>> {
+		let Self::Template {
+			template_name,
+			renderer,
+		} = self
+		else {
+			return None;
+		};
+		Some(renderer.render(template_name, context).map(|html| {
+			Response::ok()
+				.with_body(html)
+				.with_header("Content-Type", "text/html; charset=utf-8")
+		}))
+	}
+}
+
+/// Runs the permission classes against the request
+pub(crate) async fn check_permissions(
+	permissions: &[Arc<dyn Permission>],
+	request: &Request,
+) -> Result<()> {
+	if permissions.is_empty() {
+		return Ok(());
+	}
+
+	let auth_state = AuthState::from_extensions(&request.extensions);
+	let context = PermissionContext {
+		request,
+		is_authenticated: auth_state
+			.as_ref()
+			.is_some_and(|state| state.is_authenticated()),
+		is_admin: auth_state.as_ref().is_some_and(|state| state.is_admin()),
+		is_active: auth_state.as_ref().is_some_and(|state| state.is_active()),
+		user: None,
+	};
+
+	for permission in permissions {
+		if !permission.has_permission(&context).await {
+			return Err(Error::Authorization(format!(
+				"Permission denied by {}",
+				std::any::type_name_of_val(&**permission)
+			)));
+		}
+	}
+	Ok(())
+}
+
+/// Reads the submitted fields from a JSON or form-encoded body
+///
+/// Form-encoded values are kept as strings; repeated keys keep the last
+/// value.
+pub(crate) fn request_data(request: &Request) -> Result<Map<String, Value>> {
+	let is_form = request
+		.headers
+		.get(hyper::header::CONTENT_TYPE)
+		.and_then(|value| value.to_str().ok())
+		.is_some_and(|value| value.starts_with("application/x-www-form-urlencoded"));
+
+	if is_form {
+		let body = request.read_body()?;
+		return Ok(parse_form_body(&body));
+	}
+
+	match request
+		.json::<Value>()
+		.map_err(|e| Error::Validation(format!("Invalid request body: {}", e)))?
+	{
+		Value::Object(data) => Ok(data),
+		_ => Err(Error::Validation(
+			"Request body must be a JSON object".to_string(),
+		)),
+	}
+}
+
+/// Parses an `application/x-www-form-urlencoded` body
+fn parse_form_body(body: &[u8]) -> Map<String, Value> {
+	let decode = |raw: &str| {
+		let raw = raw.replace('+', " ");
+		urlencoding::decode(&raw)
+			.map(|value| value.into_owned())
+			.unwrap_or(raw)
+	};
+
+	String::from_utf8_lossy(body)
+		.split('&')
+		.filter(|pair| !pair.is_empty())
+		.map(|pair| {
+			let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+			(decode(key), Value::String(decode(value)))
+		})
+		.collect()
+}
+
+/// Fills `{field}` placeholders in a success URL from the saved object
+pub(crate) fn resolve_success_url(success_url: &str, object: &Value) -> Result<String> {
+	let mut resolved = String::with_capacity(success_url.len());
+	let mut rest = success_url;
+
+	while let Some(start) = rest.find('{') {
+		let Some(end) = rest[start..].find('}') else {
+			break;
+		};
+		let field = &rest[start + 1..start + end];
+		let value = match object.get(field) {
+			Some(Value::String(value)) => urlencoding::encode(value).into_owned(),
+			Some(Value::Null) | None => {
+				return Err(Error::ImproperlyConfigured(format!(
+					"success_url references '{}', which the object does not provide",
+					field
+				)));
+			}
+			Some(value) => value.to_string(),
+		};
+		resolved.push_str(&rest[..start]);
+		resolved.push_str(&value);
+		rest = &rest[start + end + 1..];
+	}
+	resolved.push_str(rest);
+
+	Ok(resolved)
+}
+
+/// Redirects to the success URL, which template mode requires
+pub(crate) fn success_redirect(success_url: Option<&str>, object: &Value) -> Result<Response> {
+	let success_url = success_url.ok_or_else(|| {
+		Error::ImproperlyConfigured(
+			"No URL to redirect to. Configure one with with_success_url().".to_string(),
+		)
+	})?;
+	Ok(Response::temporary_redirect(resolve_success_url(
+		success_url,
+		object,
+	)?))
+}
+
+/// Serializes an object to a JSON value with the view's serializer
+pub(crate) fn serialize_object<T>(
+	serializer: &(dyn Serializer<Input = T, Output = String> + Send + Sync),
+	object: &T,
+) -> Result<Value> {
+	let serialized = serializer.serialize(object).map_err(serializer_error)?;
+	serde_json::from_str(&serialized).map_err(|e| Error::Serialization(e.to_string()))
+}
+
+/// Deserializes submitted fields with the view's serializer
+pub(crate) fn deserialize_object<T>(
+	serializer: &(dyn Serializer<Input = T, Output = String> + Send + Sync),
+	data: &Value,
+) -> Result<T> {
+	serializer
+		.deserialize(&data.to_string())
+		.map_err(|e| match e {
+			SerializerError::Validation(v) => Error::Validation(v.to_string()),
+			other => Error::Validation(other.to_string()),
+		})
+}
+
+fn serializer_error(e: SerializerError) -> Error {
+	match e {
+		SerializerError::Validation(v) => Error::Validation(v.to_string()),
+		SerializerError::Serde { message } => Error::Serialization(message),
+		SerializerError::Other { message } => Error::Serialization(message),
+		_ => Error::Serialization(e.to_string()),
+	}
+}
+
+/// Re-renders the template with the submitted data and validation errors
+///
+/// Returns `None` in JSON mode, where the caller propagates the error.
+pub(crate) fn render_invalid(
+	render_mode: &RenderMode,
+	mut context: Context,
+	data: Value,
+	error: &Error,
+) -> Option<Result<Response>> {
+	let errors = match error {
+		Error::FieldValidation(errors) => serde_json::to_value(errors).unwrap_or_default(),
+		Error::Validation(message) => serde_json::json!({ "_all": [message] }),
+		_ => return None,
+	};
+	context.insert("data".to_string(), data);
+	context.insert("errors".to_string(), errors);
+	render_mode.render(&context)
+}
+
+/// Responds to `OPTIONS` with the allowed methods
+pub(crate) fn options_response(methods: &[&str]) -> Response {
+	Response::ok().with_header("Allow", &methods.join(", "))
+}
+
+/// Rejects a method the view does not handle
+pub(crate) fn method_not_allowed(request: &Request) -> Error {
+	Error::MethodNotAllowed(format!("Method {} not allowed", request.method))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bytes::Bytes;
+	use hyper::Method;
+	use rstest::rstest;
+	use serde_json::json;
+
+	pub(super) struct EchoRenderer;
+
+	impl TemplateRenderer for EchoRenderer {
+		fn render(&self, template_name: &str, context: &Context) -> Result<String> {
+			let context: std::collections::BTreeMap<_, _> = context.iter().collect();
+			Ok(format!(
+				"{}:{}",
+				template_name,
+				serde_json::to_string(&context).unwrap()
+			))
+		}
+	}
+
+	pub(super) fn template_mode() -> RenderMode {
+		RenderMode::template("form.html", Arc::new(EchoRenderer))
+	}
+
+	#[rstest]
+	#[case::integer("/articles/{id}/", json!({"id": 7}), "/articles/7/")]
+	#[case::string("/tags/{slug}/", json!({"slug": "a b"}), "/tags/a%20b/")]
+	#[case::static_url("/articles/", json!({"id": 7}), "/articles/")]
+	#[case::unclosed("/articles/{id", json!({"id": 7}), "/articles/{id")]
+	fn test_resolve_success_url(
+		#[case] success_url: &str,
+		#[case] object: Value,
+		#[case] expected: &str,
+	) {
+		// Act
+		let resolved = resolve_success_url(success_url, &object).unwrap();
+
+		// Assert
+		assert_eq!(resolved, expected);
+	}
+
+	#[rstest]
+	fn test_resolve_success_url_rejects_missing_field() {
+		// Act
+		let result = resolve_success_url("/articles/{id}/", &json!({"id": null}));
+
+		// Assert
+		assert!(matches!(result, Err(Error::ImproperlyConfigured(_))));
+	}
+
+	#[rstest]
+	fn test_request_data_parses_form_body() {
+		// Arrange
+		let request = Request::builder()
+			.method(Method::POST)
+			.uri("/articles/new/")
+			.header("Content-Type", "application/x-www-form-urlencoded")
+			.body(Bytes::from("title=Hello+world&body=a%26b&flag"))
+			.build()
+			.unwrap();
+
+		// Act
+		let data = request_data(&request).unwrap();
+
+		// Assert
+		assert_eq!(
+			Value::Object(data),
+			json!({"title": "Hello world", "body": "a&b", "flag": ""})
+		);
+	}
+
+	#[rstest]
+	fn test_request_data_rejects_non_object_json() {
+		// Arrange
+		let request = Request::builder()
+			.method(Method::POST)
+			.uri("/articles/new/")
+			.header("Content-Type", "application/json")
+			.body(Bytes::from("[1, 2]"))
+			.build()
+			.unwrap();
+
+		// Act
+		let result = request_data(&request);
+
+		// Assert
+		assert!(matches!(result, Err(Error::Validation(_))));
+	}
+}
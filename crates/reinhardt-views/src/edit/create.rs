@@ -0,0 +1,297 @@
+//! CreateView implementation for creating objects from submitted data.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reinhardt_auth::Permission;
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_db::orm::{CustomManager, Model, QuerySet};
+use reinhardt_http::{Request, Response};
+use reinhardt_rest::serializers::{JsonSerializer, Serializer};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{
+	RenderMode, check_permissions, deserialize_object, method_not_allowed, options_response,
+	render_invalid, request_data, serialize_object, success_redirect,
+};
+use crate::core::{Context, View};
+
+/// CreateView for creating a new object
+///
+/// `POST` deserializes the submitted JSON or form-encoded fields with the
+/// view's serializer and saves the object. In template mode, `GET` renders
+/// the empty form and a successful `POST` redirects to the success URL.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reinhardt_views::CreateView;
+/// # use reinhardt_db::orm::Model;
+/// # use serde::{Serialize, Deserialize};
+/// # #[derive(Debug, Clone, Serialize, Deserialize)]
+/// # struct Article { id: Option<i64>, title: String }
+/// # #[derive(Clone)]
+/// # struct ArticleFields;
+/// # impl reinhardt_db::orm::FieldSelector for ArticleFields {
+/// #     fn with_alias(self, _alias: &str) -> Self { self }
+/// # }
+/// # impl Model for Article {
+/// #     type PrimaryKey = i64;
+/// #     type Fields = ArticleFields;
+/// #     type Objects = reinhardt_db::orm::Manager<Self>;
+/// #     fn table_name() -> &'static str { "articles" }
+/// #     fn primary_key(&self) -> Option<Self::PrimaryKey> { self.id }
+/// #     fn set_primary_key(&mut self, value: Self::PrimaryKey) { self.id = Some(value); }
+/// #     fn new_fields() -> Self::Fields { ArticleFields }
+/// # }
+///
+/// let view = CreateView::<Article>::new().with_success_url("/articles/{id}/");
+/// ```
+pub struct CreateView<T>
+where
+	T: Model + Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone,
+{
+	queryset: Option<QuerySet<T>>,
+	success_url: Option<String>,
+	render_mode: RenderMode,
+	permissions: Vec<Arc<dyn Permission>>,
+	serializer: Box<dyn Serializer<Input = T, Output = String> + Send + Sync>,
+}
+
+impl<T> Default for CreateView<T>
+where
+	T: Model + Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone + 'static,
+{
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T> CreateView<T>
+where
+	T: Model + Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone + 'static,
+{
+	/// Creates a new `CreateView` rendering JSON with a `JsonSerializer`
+	pub fn new() -> Self {
+		Self {
+			queryset: None,
+			success_url: None,
+			render_mode: RenderMode::Json,
+			permissions: Vec::new(),
+			serializer: Box::new(JsonSerializer::<T>::new()),
+		}
+	}
+
+	/// Sets the queryset objects are created through
+	pub fn with_queryset(mut self, queryset: QuerySet<T>) -> Self {
+		self.queryset = Some(queryset);
+		self
+	}
+
+	/// Sets the URL to redirect to after a successful create
+	///
+	/// `{field}` placeholders are filled from the created object.
+	pub fn with_success_url(mut self, success_url: impl Into<String>) -> Self {
+		self.success_url = Some(success_url.into());
+		self
+	}
+
+	/// Sets how responses are rendered
+	pub fn with_render_mode(mut self, render_mode: RenderMode) -> Self {
+		self.render_mode = render_mode;
+		self
+	}
+
+	/// Adds a permission class checked before handling the request
+	pub fn add_permission(mut self, permission: Arc<dyn Permission>) -> Self {
+		self.permissions.push(permission);
+		self
+	}
+
+	/// Sets the serializer used to read and write objects
+	pub fn with_serializer<S>(mut self, serializer: S) -> Self
+	where
+		S: Serializer<Input = T, Output = String> + Send + Sync + 'static,
+	{
+		self.serializer = Box::new(serializer);
+		self
+	}
+
+	/// Gets the queryset, creating a default one if not set
+	fn get_queryset(&self) -> QuerySet<T> {
+		self.queryset.clone().unwrap_or_else(|| T::objects().all())
+	}
+
+	/// Returns the template context for the empty form
+	pub fn get_context_data(&self) -> Context {
+		Context::new()
+	}
+
+	/// Deserializes and saves the submitted object
+	async fn perform_create(&self, data: &Value) -> Result<T> {
+		let object = deserialize_object(&*self.serializer, data)?;
+		self.get_queryset().create(object).await
+	}
+
+	async fn post(&self, request: &Request) -> Result<Response> {
+		let data = Value::Object(request_data(request)?);
+		let object = match self.perform_create(&data).await {
+			Ok(object) => object,
+			Err(error) => {
+				return render_invalid(&self.render_mode, self.get_context_data(), data, &error)
+					.unwrap_or(Err(error));
+			}
+		};
+		let serialized = serialize_object(&*self.serializer, &object)?;
+
+		if self.render_mode.is_template() {
+			return success_redirect(self.success_url.as_deref(), &serialized);
+		}
+		let mut response = Response::created()
+			.with_json(&serialized)
+			.map_err(|e| Error::Serialization(e.to_string()))?;
+		if let Some(success_url) = &self.success_url {
+			let location = super::resolve_success_url(success_url, &serialized)?;
+			response = response.with_location(&location);
+		}
+		Ok(response)
+	}
+}
+
+#[async_trait]
+impl<T> View for CreateView<T>
+where
+	T: Model + Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone + 'static,
+{
+	async fn dispatch(&self, request: Request) -> Result<Response> {
+		if request.method == "OPTIONS" {
+			return Ok(options_response(&self.allowed_methods()));
+		}
+		check_permissions(&self.permissions, &request).await?;
+
+		match request.method.as_str() {
+			"GET" if self.render_mode.is_template() => self
+				.render_mode
+				.render(&self.get_context_data())
+				.unwrap_or_else(|| Err(method_not_allowed(&request))),
+			"POST" => self.post(&request).await,
+			_ => Err(method_not_allowed(&request)),
+		}
+	}
+
+	fn allowed_methods(&self) -> Vec<&'static str> {
+		if self.render_mode.is_template() {
+			vec!["GET", "POST", "OPTIONS"]
+		} else {
+			vec!["POST", "OPTIONS"]
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::edit::tests::template_mode;
+	use crate::generic::test_support::ManagedArticle;
+	use bytes::Bytes;
+	use hyper::{Method, StatusCode};
+	use reinhardt_auth::IsAuthenticated;
+	use rstest::rstest;
+
+	fn post_request(body: &'static str) -> Request {
+		Request::builder()
+			.method(Method::POST)
+			.uri("/articles/new/")
+			.header("Content-Type", "application/json")
+			.body(Bytes::from(body))
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_get_renders_template() {
+		// Arrange
+		let view = CreateView::<ManagedArticle>::new().with_render_mode(template_mode());
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/articles/new/")
+			.build()
+			.unwrap();
+
+		// Act
+		let response = view.dispatch(request).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::OK);
+		assert_eq!(
+			response.headers.get("content-type").unwrap(),
+			"text/html; charset=utf-8"
+		);
+		assert_eq!(response.body, Bytes::from("form.html:{}"));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_get_is_not_allowed_in_json_mode() {
+		// Arrange
+		let view = CreateView::<ManagedArticle>::new();
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/articles/new/")
+			.build()
+			.unwrap();
+
+		// Act
+		let result = view.dispatch(request).await;
+
+		// Assert
+		assert!(matches!(result, Err(Error::MethodNotAllowed(_))));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_invalid_json_submission_is_a_validation_error() {
+		// Arrange
+		let view = CreateView::<ManagedArticle>::new();
+
+		// Act
+		let result = view.dispatch(post_request(r#"{"title": 1}"#)).await;
+
+		// Assert
+		assert!(matches!(result, Err(Error::Validation(_))));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_invalid_template_submission_rerenders_form() {
+		// Arrange
+		let view = CreateView::<ManagedArticle>::new().with_render_mode(template_mode());
+
+		// Act
+		let response = view
+			.dispatch(post_request(r#"{"title": "Draft"}"#))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::OK);
+		let body = String::from_utf8(response.body.to_vec()).unwrap();
+		assert!(body.contains(r#""data":{"title":"Draft"}"#), "{}", body);
+		assert!(body.contains(r#""errors":{"_all":["#), "{}", body);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_permission_denied_before_reading_body() {
+		// Arrange
+		let view = CreateView::<ManagedArticle>::new().add_permission(Arc::new(IsAuthenticated));
+
+		// Act
+		let result = view.dispatch(post_request("not json")).await;
+
+		// Assert
+		assert!(matches!(result, Err(Error::Authorization(_))));
+	}
+}
@@ -0,0 +1,323 @@
+//! DeleteView implementation for deleting an existing object.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reinhardt_auth::Permission;
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_db::orm::{CustomManager, Model, QuerySet};
+use reinhardt_http::{Request, Response};
+use reinhardt_rest::serializers::{JsonSerializer, Serializer};
+use serde::{Deserialize, Serialize};
+
+use super::{
+	RenderMode, check_permissions, method_not_allowed, options_response, serialize_object,
+	success_redirect,
+};
+use crate::core::View;
+use crate::mixins::{SingleObjectMixin, lookup_object};
+
+/// DeleteView for deleting a single object
+///
+/// The object is looked up from the `pk` or `slug` URL parameter as in
+/// [`DetailView`](crate::DetailView). `POST` and `DELETE` delete it. In
+/// template mode, `GET` renders a confirmation page with the object in the
+/// context and a successful delete redirects to the success URL; in JSON
+/// mode the view responds with `204 No Content`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reinhardt_views::DeleteView;
+/// # use reinhardt_db::orm::Model;
+/// # use serde::{Serialize, Deserialize};
+/// # #[derive(Debug, Clone, Serialize, Deserialize)]
+/// # struct Article { id: Option<i64>, title: String }
+/// # #[derive(Clone)]
+/// # struct ArticleFields;
+/// # impl reinhardt_db::orm::FieldSelector for ArticleFields {
+/// #     fn with_alias(self, _alias: &str) -> Self { self }
+/// # }
+/// # impl Model for Article {
+/// #     type PrimaryKey = i64;
+/// #     type Fields = ArticleFields;
+/// #     type Objects = reinhardt_db::orm::Manager<Self>;
+/// #     fn table_name() -> &'static str { "articles" }
+/// #     fn primary_key(&self) -> Option<Self::PrimaryKey> { self.id }
+/// #     fn set_primary_key(&mut self, value: Self::PrimaryKey) { self.id = Some(value); }
+/// #     fn new_fields() -> Self::Fields { ArticleFields }
+/// # }
+///
+/// let view = DeleteView::<Article>::new().with_success_url("/articles/");
+/// ```
+pub struct DeleteView<T>
+where
+	T: Model + Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone,
+{
+	object: Option<T>,
+	queryset: Option<QuerySet<T>>,
+	slug_field: String,
+	pk_url_kwarg_name: String,
+	slug_url_kwarg_name: String,
+	context_object_name: Option<String>,
+	success_url: Option<String>,
+	render_mode: RenderMode,
+	permissions: Vec<Arc<dyn Permission>>,
+	serializer: Box<dyn Serializer<Input = T, Output = String> + Send + Sync>,
+}
+
+impl<T> Default for DeleteView<T>
+where
+	T: Model + Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone + 'static,
+{
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T> DeleteView<T>
+where
+	T: Model + Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone + 'static,
+{
+	/// Creates a new `DeleteView` rendering JSON
+	pub fn new() -> Self {
+		Self {
+			object: None,
+			queryset: None,
+			slug_field: "slug".to_string(),
+			pk_url_kwarg_name: "pk".to_string(),
+			slug_url_kwarg_name: "slug".to_string(),
+			context_object_name: None,
+			success_url: None,
+			render_mode: RenderMode::Json,
+			permissions: Vec::new(),
+			serializer: Box::new(JsonSerializer::<T>::new()),
+		}
+	}
+
+	/// Sets the object to delete instead of looking it up
+	pub fn with_object(mut self, object: T) -> Self {
+		self.object = Some(object);
+		self
+	}
+
+	/// Sets the queryset the object is looked up in
+	pub fn with_queryset(mut self, queryset: QuerySet<T>) -> Self {
+		self.queryset = Some(queryset);
+		self
+	}
+
+	/// Sets the model field matched against the slug URL parameter
+	pub fn with_slug_field(mut self, slug_field: impl Into<String>) -> Self {
+		self.slug_field = slug_field.into();
+		self
+	}
+
+	/// Sets the name of the primary key URL parameter
+	pub fn with_pk_url_kwarg(mut self, pk_url_kwarg: impl Into<String>) -> Self {
+		self.pk_url_kwarg_name = pk_url_kwarg.into();
+		self
+	}
+
+	/// Sets the name of the slug URL parameter
+	pub fn with_slug_url_kwarg(mut self, slug_url_kwarg: impl Into<String>) -> Self {
+		self.slug_url_kwarg_name = slug_url_kwarg.into();
+		self
+	}
+
+	/// Sets an additional context name for the object
+	pub fn with_context_object_name(mut self, name: impl Into<String>) -> Self {
+		self.context_object_name = Some(name.into());
+		self
+	}
+
+	/// Sets the URL to redirect to after a successful delete
+	///
+	/// `{field}` placeholders are filled from the deleted object.
+	pub fn with_success_url(mut self, success_url: impl Into<String>) -> Self {
+		self.success_url = Some(success_url.into());
+		self
+	}
+
+	/// Sets how responses are rendered
+	pub fn with_render_mode(mut self, render_mode: RenderMode) -> Self {
+		self.render_mode = render_mode;
+		self
+	}
+
+	/// Adds a permission class checked before handling the request
+	pub fn add_permission(mut self, permission: Arc<dyn Permission>) -> Self {
+		self.permissions.push(permission);
+		self
+	}
+
+	/// Sets the serializer used to fill success URL placeholders
+	pub fn with_serializer<S>(mut self, serializer: S) -> Self
+	where
+		S: Serializer<Input = T, Output = String> + Send + Sync + 'static,
+	{
+		self.serializer = Box::new(serializer);
+		self
+	}
+
+	/// Deletes the object by primary key
+	async fn perform_destroy(&self, object: &T) -> Result<()> {
+		let pk = object
+			.primary_key()
+			.ok_or_else(|| Error::Validation("Object has no primary key".to_string()))?;
+
+		let manager = T::objects();
+		manager.delete(pk).await
+	}
+}
+
+#[async_trait]
+impl<T> SingleObjectMixin<T> for DeleteView<T>
+where
+	T: Model + Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone + 'static,
+{
+	async fn get_object(&self, request: &Request) -> Result<T> {
+		if let Some(ref object) = self.object {
+			return Ok(object.clone());
+		}
+		let queryset = self.queryset.clone().unwrap_or_else(|| T::objects().all());
+		lookup_object(self, &queryset, request).await
+	}
+
+	fn get_slug_field(&self) -> &str {
+		&self.slug_field
+	}
+
+	fn pk_url_kwarg(&self) -> &str {
+		&self.pk_url_kwarg_name
+	}
+
+	fn slug_url_kwarg(&self) -> &str {
+		&self.slug_url_kwarg_name
+	}
+
+	fn get_context_object_name(&self) -> Option<&str> {
+		self.context_object_name.as_deref()
+	}
+}
+
+#[async_trait]
+impl<T> View for DeleteView<T>
+where
+	T: Model + Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone + 'static,
+{
+	async fn dispatch(&self, request: Request) -> Result<Response> {
+		if request.method == "OPTIONS" {
+			return Ok(options_response(&self.allowed_methods()));
+		}
+		check_permissions(&self.permissions, &request).await?;
+
+		match request.method.as_str() {
+			"GET" if self.render_mode.is_template() => {
+				let object = self.get_object(&request).await?;
+				self.render_mode
+					.render(&self.get_context_data(object)?)
+					.unwrap_or_else(|| Err(method_not_allowed(&request)))
+			}
+			"POST" | "DELETE" => {
+				let object = self.get_object(&request).await?;
+				// Resolve the success URL first; placeholders refer to the
+				// object, which no longer exists afterwards
+				let redirect = if self.render_mode.is_template() {
+					let serialized = serialize_object(&*self.serializer, &object)?;
+					Some(success_redirect(self.success_url.as_deref(), &serialized)?)
+				} else {
+					None
+				};
+				self.perform_destroy(&object).await?;
+				Ok(redirect.unwrap_or_else(Response::no_content))
+			}
+			_ => Err(method_not_allowed(&request)),
+		}
+	}
+
+	fn allowed_methods(&self) -> Vec<&'static str> {
+		if self.render_mode.is_template() {
+			vec!["GET", "POST", "DELETE", "OPTIONS"]
+		} else {
+			vec!["POST", "DELETE", "OPTIONS"]
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::edit::tests::template_mode;
+	use crate::generic::test_support::ManagedArticle;
+	use hyper::{Method, StatusCode};
+	use rstest::rstest;
+
+	fn article() -> ManagedArticle {
+		ManagedArticle {
+			id: Some(3),
+			title: "Hello".to_string(),
+			is_archived: false,
+			tenant_id: 1,
+		}
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_get_renders_confirmation_template() {
+		// Arrange
+		let view = DeleteView::<ManagedArticle>::new()
+			.with_object(article())
+			.with_render_mode(template_mode());
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/articles/3/delete/")
+			.build()
+			.unwrap();
+
+		// Act
+		let response = view.dispatch(request).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::OK);
+		let body = String::from_utf8(response.body.to_vec()).unwrap();
+		assert!(body.contains(r#""object":{"id":3"#), "{}", body);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_template_delete_requires_success_url() {
+		// Arrange
+		let view = DeleteView::<ManagedArticle>::new()
+			.with_object(article())
+			.with_render_mode(template_mode());
+		let request = Request::builder()
+			.method(Method::POST)
+			.uri("/articles/3/delete/")
+			.build()
+			.unwrap();
+
+		// Act
+		let result = view.dispatch(request).await;
+
+		// Assert
+		assert!(matches!(result, Err(Error::ImproperlyConfigured(_))));
+	}
+
+	#[rstest]
+	#[case::json(false, vec!["POST", "DELETE", "OPTIONS"])]
+	#[case::template(true, vec!["GET", "POST", "DELETE", "OPTIONS"])]
+	fn test_allowed_methods(#[case] template: bool, #[case] expected: Vec<&str>) {
+		// Arrange
+		let mut view = DeleteView::<ManagedArticle>::new();
+		if template {
+			view = view.with_render_mode(template_mode());
+		}
+
+		// Act
+		let methods = view.allowed_methods();
+
+		// Assert
+		assert_eq!(methods, expected);
+	}
+}
@@ -0,0 +1,318 @@
+//! FormView implementation for handling a `reinhardt_forms::Form`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reinhardt_auth::Permission;
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_forms::Form;
+use reinhardt_forms::form::ALL_FIELDS_KEY;
+use reinhardt_http::{Request, Response};
+use serde_json::{Value, json};
+
+use super::{
+	RenderMode, check_permissions, method_not_allowed, options_response, request_data,
+	success_redirect,
+};
+use crate::core::{Context, View};
+
+/// Builds and processes the form of a [`FormView`]
+#[async_trait]
+pub trait FormHandler: Send + Sync {
+	/// Builds a fresh, unbound form for the request
+	fn get_form(&self, request: &Request) -> Form;
+
+	/// Handles a submission whose form passed validation
+	///
+	/// Errors added to `form` with [`Form::add_error`] reject the
+	/// submission as if validation had failed.
+	async fn form_valid(&self, request: &Request, form: &mut Form) -> Result<()>;
+}
+
+/// FormView for displaying and processing a form
+///
+/// `POST` binds the submitted JSON or form-encoded fields to the form built
+/// by the [`FormHandler`] and validates it. Valid forms are passed to
+/// [`FormHandler::form_valid`]. In template mode the form is available to
+/// the template as `form`, invalid submissions re-render it with errors and
+/// successful ones redirect to the success URL; in JSON mode validation
+/// errors are returned as `Error::FieldValidation` and successful
+/// submissions respond with the cleaned data.
+///
+/// # Examples
+///
+/// ```
+/// use async_trait::async_trait;
+/// use reinhardt_core::exception::Result;
+/// use reinhardt_forms::{CharField, Form};
+/// use reinhardt_http::Request;
+/// use reinhardt_views::{FormHandler, FormView};
+///
+/// struct ContactForm;
+///
+/// #[async_trait]
+/// impl FormHandler for ContactForm {
+///     fn get_form(&self, _request: &Request) -> Form {
+///         let mut form = Form::new();
+///         form.add_field(Box::new(CharField::new("message".to_string())));
+///         form
+///     }
+///
+///     async fn form_valid(&self, _request: &Request, _form: &mut Form) -> Result<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// let view = FormView::new(ContactForm).with_success_url("/contact/thanks/");
+/// ```
+pub struct FormView<H: FormHandler> {
+	handler: H,
+	success_url: Option<String>,
+	render_mode: RenderMode,
+	permissions: Vec<Arc<dyn Permission>>,
+}
+
+impl<H: FormHandler> FormView<H> {
+	/// Creates a new `FormView` rendering JSON
+	pub fn new(handler: H) -> Self {
+		Self {
+			handler,
+			success_url: None,
+			render_mode: RenderMode::Json,
+			permissions: Vec::new(),
+		}
+	}
+
+	/// Sets the URL to redirect to after a successful submission
+	///
+	/// `{field}` placeholders are filled from the cleaned data.
+	pub fn with_success_url(mut self, success_url: impl Into<String>) -> Self {
+		self.success_url = Some(success_url.into());
+		self
+	}
+
+	/// Sets how responses are rendered
+	pub fn with_render_mode(mut self, render_mode: RenderMode) -> Self {
+		self.render_mode = render_mode;
+		self
+	}
+
+	/// Adds a permission class checked before handling the request
+	pub fn add_permission(mut self, permission: Arc<dyn Permission>) -> Self {
+		self.permissions.push(permission);
+		self
+	}
+
+	/// Returns the template context for `form`
+	///
+	/// The context holds a `form` entry with the form's fields (name, label,
+	/// value, errors and rendered widget) and its non-field errors.
+	pub fn get_context_data(&self, form: &Form) -> Context {
+		let values = if form.is_bound() {
+			form.cleaned_data()
+		} else {
+			form.initial()
+		};
+		let fields: Vec<Value> = form
+			.fields()
+			.iter()
+			.map(|field| {
+				let value = values.get(field.name()).or_else(|| field.initial());
+				let widget_value = value.and_then(|value| match value {
+					Value::Null => None,
+					Value::String(value) => Some(value.clone()),
+					other => Some(other.to_string()),
+				});
+				json!({
+					"name": field.name(),
+					"label": field.label(),
+					"required": field.required(),
+					"help_text": field.help_text(),
+					"value": value,
+					"errors": form.errors().get(field.name()).cloned().unwrap_or_default(),
+					"html": field.widget().render_html(field.name(), widget_value.as_deref(), None),
+				})
+			})
+			.collect();
+
+		let mut context = Context::new();
+		context.insert(
+			"form".to_string(),
+			json!({
+				"fields": fields,
+				"errors": form.errors().get(ALL_FIELDS_KEY).cloned().unwrap_or_default(),
+				"is_bound": form.is_bound(),
+			}),
+		);
+		context
+	}
+
+	fn render_form(&self, form: &Form) -> Result<Response> {
+		let context = self.get_context_data(form);
+		match self.render_mode.render(&context) {
+			Some(response) => response,
+			None => Response::ok()
+				.with_json(&context)
+				.map_err(|e| Error::Serialization(e.to_string())),
+		}
+	}
+
+	async fn post(&self, request: &Request) -> Result<Response> {
+		let data: HashMap<String, Value> = request_data(request)?.into_iter().collect();
+		let mut form = self.handler.get_form(request);
+		form.bind(data);
+
+		if form.is_valid() {
+			self.handler.form_valid(request, &mut form).await?;
+		}
+
+		if !form.errors().is_empty() {
+			if self.render_mode.is_template() {
+				return self.render_form(&form);
+			}
+			let errors: BTreeMap<String, Vec<String>> = form
+				.errors()
+				.iter()
+				.map(|(field, messages)| (field.clone(), messages.clone()))
+				.collect();
+			return Err(Error::FieldValidation(errors));
+		}
+
+		let cleaned = serde_json::to_value(form.cleaned_data())
+			.map_err(|e| Error::Serialization(e.to_string()))?;
+		if self.render_mode.is_template() {
+			return success_redirect(self.success_url.as_deref(), &cleaned);
+		}
+		Response::ok()
+			.with_json(&json!({ "data": cleaned }))
+			.map_err(|e| Error::Serialization(e.to_string()))
+	}
+}
+
+#[async_trait]
+impl<H: FormHandler> View for FormView<H> {
+	async fn dispatch(&self, request: Request) -> Result<Response> {
+		if request.method == "OPTIONS" {
+			return Ok(options_response(&self.allowed_methods()));
+		}
+		check_permissions(&self.permissions, &request).await?;
+
+		match request.method.as_str() {
+			"GET" => self.render_form(&self.handler.get_form(&request)),
+			"POST" => self.post(&request).await,
+			_ => Err(method_not_allowed(&request)),
+		}
+	}
+
+	fn allowed_methods(&self) -> Vec<&'static str> {
+		vec!["GET", "POST", "OPTIONS"]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::edit::tests::template_mode;
+	use bytes::Bytes;
+	use hyper::{Method, StatusCode};
+	use reinhardt_forms::CharField;
+	use rstest::rstest;
+
+	struct ContactForm;
+
+	#[async_trait]
+	impl FormHandler for ContactForm {
+		fn get_form(&self, _request: &Request) -> Form {
+			let mut form = Form::new();
+			form.add_field(Box::new(CharField::new("name".to_string()).required()));
+			form
+		}
+
+		async fn form_valid(&self, _request: &Request, form: &mut Form) -> Result<()> {
+			if form.cleaned_data().get("name") == Some(&json!("spam")) {
+				form.add_error(ALL_FIELDS_KEY, "Rejected");
+			}
+			Ok(())
+		}
+	}
+
+	fn post_request(body: &'static str) -> Request {
+		Request::builder()
+			.method(Method::POST)
+			.uri("/contact/")
+			.header("Content-Type", "application/x-www-form-urlencoded")
+			.body(Bytes::from(body))
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_get_returns_form_fields_as_json() {
+		// Arrange
+		let view = FormView::new(ContactForm);
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/contact/")
+			.build()
+			.unwrap();
+
+		// Act
+		let response = view.dispatch(request).await.unwrap();
+
+		// Assert
+		let body: Value = serde_json::from_slice(&response.body).unwrap();
+		assert_eq!(body["form"]["fields"][0]["name"], "name");
+		assert_eq!(body["form"]["is_bound"], false);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_valid_submission_redirects_in_template_mode() {
+		// Arrange
+		let view = FormView::new(ContactForm)
+			.with_render_mode(template_mode())
+			.with_success_url("/thanks/{name}/");
+
+		// Act
+		let response = view.dispatch(post_request("name=Ada")).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::FOUND);
+		assert_eq!(response.headers.get("location").unwrap(), "/thanks/Ada/");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_invalid_submission_is_field_validation_error_in_json_mode() {
+		// Arrange
+		let view = FormView::new(ContactForm);
+
+		// Act
+		let result = view.dispatch(post_request("other=1")).await;
+
+		// Assert
+		let Err(Error::FieldValidation(errors)) = result else {
+			panic!("expected a field validation error");
+		};
+		assert!(errors.contains_key("name"));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_errors_added_by_handler_rerender_form() {
+		// Arrange
+		let view = FormView::new(ContactForm)
+			.with_render_mode(template_mode())
+			.with_success_url("/thanks/");
+
+		// Act
+		let response = view.dispatch(post_request("name=spam")).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::OK);
+		let body = String::from_utf8(response.body.to_vec()).unwrap();
+		assert!(body.contains(r#""errors":["Rejected"]"#), "{}", body);
+	}
+}
@@ -0,0 +1,389 @@
+//! UpdateView implementation for editing an existing object.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reinhardt_auth::Permission;
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_db::orm::{CustomManager, Model, QuerySet};
+use reinhardt_http::{Request, Response};
+use reinhardt_rest::serializers::{JsonSerializer, Serializer};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{
+	RenderMode, check_permissions, deserialize_object, method_not_allowed, options_response,
+	render_invalid, request_data, serialize_object, success_redirect,
+};
+use crate::core::View;
+use crate::generic::patch_utils::merge_patch_object_into;
+use crate::mixins::{SingleObjectMixin, lookup_object};
+
+/// UpdateView for editing a single object
+///
+/// The object is looked up from the `pk` or `slug` URL parameter as in
+/// [`DetailView`](crate::DetailView). `POST` and `PUT` replace the object's
+/// fields with the submitted ones and `PATCH` merges them; the primary key
+/// is always kept. In template mode, `GET` renders the form with the object
+/// in the context and a successful submission redirects to the success URL.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use reinhardt_views::UpdateView;
+/// # use reinhardt_db::orm::Model;
+/// # use serde::{Serialize, Deserialize};
+/// # #[derive(Debug, Clone, Serialize, Deserialize)]
+/// # struct Article { id: Option<i64>, title: String }
+/// # #[derive(Clone)]
+/// # struct ArticleFields;
+/// # impl reinhardt_db::orm::FieldSelector for ArticleFields {
+/// #     fn with_alias(self, _alias: &str) -> Self { self }
+/// # }
+/// # impl Model for Article {
+/// #     type PrimaryKey = i64;
+/// #     type Fields = ArticleFields;
+/// #     type Objects = reinhardt_db::orm::Manager<Self>;
+/// #     fn table_name() -> &'static str { "articles" }
+/// #     fn primary_key(&self) -> Option<Self::PrimaryKey> { self.id }
+/// #     fn set_primary_key(&mut self, value: Self::PrimaryKey) { self.id = Some(value); }
+/// #     fn new_fields() -> Self::Fields { ArticleFields }
+/// # }
+///
+/// let view = UpdateView::<Article>::new()
+///     .with_context_object_name("article")
+///     .with_success_url("/articles/{id}/");
+/// ```
+pub struct UpdateView<T>
+where
+	T: Model + Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone,
+{
+	object: Option<T>,
+	queryset: Option<QuerySet<T>>,
+	slug_field: String,
+	pk_url_kwarg_name: String,
+	slug_url_kwarg_name: String,
+	context_object_name: Option<String>,
+	success_url: Option<String>,
+	render_mode: RenderMode,
+	permissions: Vec<Arc<dyn Permission>>,
+	serializer: Box<dyn Serializer<Input = T, Output = String> + Send + Sync>,
+}
+
+impl<T> Default for UpdateView<T>
+where
+	T: Model + Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone + 'static,
+{
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T> UpdateView<T>
+where
+	T: Model + Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone + 'static,
+{
+	/// Creates a new `UpdateView` rendering JSON with a `JsonSerializer`
+	pub fn new() -> Self {
+		Self {
+			object: None,
+			queryset: None,
+			slug_field: "slug".to_string(),
+			pk_url_kwarg_name: "pk".to_string(),
+			slug_url_kwarg_name: "slug".to_string(),
+			context_object_name: None,
+			success_url: None,
+			render_mode: RenderMode::Json,
+			permissions: Vec::new(),
+			serializer: Box::new(JsonSerializer::<T>::new()),
+		}
+	}
+
+	/// Sets the object to edit instead of looking it up
+	pub fn with_object(mut self, object: T) -> Self {
+		self.object = Some(object);
+		self
+	}
+
+	/// Sets the queryset the object is looked up in
+	pub fn with_queryset(mut self, queryset: QuerySet<T>) -> Self {
+		self.queryset = Some(queryset);
+		self
+	}
+
+	/// Sets the model field matched against the slug URL parameter
+	pub fn with_slug_field(mut self, slug_field: impl Into<String>) -> Self {
+		self.slug_field = slug_field.into();
+		self
+	}
+
+	/// Sets the name of the primary key URL parameter
+	pub fn with_pk_url_kwarg(mut self, pk_url_kwarg: impl Into<String>) -> Self {
+		self.pk_url_kwarg_name = pk_url_kwarg.into();
+		self
+	}
+
+	/// Sets the name of the slug URL parameter
+	pub fn with_slug_url_kwarg(mut self, slug_url_kwarg: impl Into<String>) -> Self {
+		self.slug_url_kwarg_name = slug_url_kwarg.into();
+		self
+	}
+
+	/// Sets an additional context name for the object
+	pub fn with_context_object_name(mut self, name: impl Into<String>) -> Self {
+		self.context_object_name = Some(name.into());
+		self
+	}
+
+	/// Sets the URL to redirect to after a successful update
+	///
+	/// `{field}` placeholders are filled from the updated object.
+	pub fn with_success_url(mut self, success_url: impl Into<String>) -> Self {
+		self.success_url = Some(success_url.into());
+		self
+	}
+
+	/// Sets how responses are rendered
+	pub fn with_render_mode(mut self, render_mode: RenderMode) -> Self {
+		self.render_mode = render_mode;
+		self
+	}
+
+	/// Adds a permission class checked before handling the request
+	pub fn add_permission(mut self, permission: Arc<dyn Permission>) -> Self {
+		self.permissions.push(permission);
+		self
+	}
+
+	/// Sets the serializer used to read and write objects
+	pub fn with_serializer<S>(mut self, serializer: S) -> Self
+	where
+		S: Serializer<Input = T, Output = String> + Send + Sync + 'static,
+	{
+		self.serializer = Box::new(serializer);
+		self
+	}
+
+	/// Applies the submitted fields to `object` and saves it
+	async fn perform_update(
+		&self,
+		object: &T,
+		current: &Value,
+		data: &Value,
+		partial: bool,
+	) -> Result<T> {
+		let pk = object
+			.primary_key()
+			.ok_or_else(|| Error::Validation("Object has no primary key".to_string()))?;
+
+		let mut updated = if partial {
+			let mut merged = current.clone();
+			merge_patch_object_into(&mut merged, data).map_err(Error::Validation)?;
+			deserialize_object(&*self.serializer, &merged)?
+		} else {
+			deserialize_object(&*self.serializer, data)?
+		};
+		updated.set_primary_key(pk);
+
+		let manager = T::objects();
+		manager.update(&updated).await
+	}
+
+	async fn submit(&self, request: &Request, object: T) -> Result<Response> {
+		let current = serialize_object(&*self.serializer, &object)?;
+		let data = Value::Object(request_data(request)?);
+		let partial = request.method == "PATCH";
+
+		let updated = match self.perform_update(&object, &current, &data, partial).await {
+			Ok(updated) => updated,
+			Err(error) => {
+				return render_invalid(
+					&self.render_mode,
+					self.get_context_data(object)?,
+					data,
+					&error,
+				)
+				.unwrap_or(Err(error));
+			}
+		};
+		let serialized = serialize_object(&*self.serializer, &updated)?;
+
+		if self.render_mode.is_template() {
+			return success_redirect(self.success_url.as_deref(), &serialized);
+		}
+		Response::ok()
+			.with_json(&serialized)
+			.map_err(|e| Error::Serialization(e.to_string()))
+	}
+}
+
+#[async_trait]
+impl<T> SingleObjectMixin<T> for UpdateView<T>
+where
+	T: Model + Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone + 'static,
+{
+	async fn get_object(&self, request: &Request) -> Result<T> {
+		if let Some(ref object) = self.object {
+			return Ok(object.clone());
+		}
+		let queryset = self.queryset.clone().unwrap_or_else(|| T::objects().all());
+		lookup_object(self, &queryset, request).await
+	}
+
+	fn get_slug_field(&self) -> &str {
+		&self.slug_field
+	}
+
+	fn pk_url_kwarg(&self) -> &str {
+		&self.pk_url_kwarg_name
+	}
+
+	fn slug_url_kwarg(&self) -> &str {
+		&self.slug_url_kwarg_name
+	}
+
+	fn get_context_object_name(&self) -> Option<&str> {
+		self.context_object_name.as_deref()
+	}
+}
+
+#[async_trait]
+impl<T> View for UpdateView<T>
+where
+	T: Model + Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone + 'static,
+{
+	async fn dispatch(&self, request: Request) -> Result<Response> {
+		if request.method == "OPTIONS" {
+			return Ok(options_response(&self.allowed_methods()));
+		}
+		check_permissions(&self.permissions, &request).await?;
+
+		match request.method.as_str() {
+			"GET" => {
+				let object = self.get_object(&request).await?;
+				match self
+					.render_mode
+					.render(&self.get_context_data(object.clone())?)
+				{
+					Some(response) => response,
+					None => Response::ok()
+						.with_json(&serialize_object(&*self.serializer, &object)?)
+						.map_err(|e| Error::Serialization(e.to_string())),
+				}
+			}
+			"POST" | "PUT" | "PATCH" => {
+				let object = self.get_object(&request).await?;
+				self.submit(&request, object).await
+			}
+			_ => Err(method_not_allowed(&request)),
+		}
+	}
+
+	fn allowed_methods(&self) -> Vec<&'static str> {
+		vec!["GET", "POST", "PUT", "PATCH", "OPTIONS"]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::edit::tests::template_mode;
+	use crate::generic::test_support::ManagedArticle;
+	use bytes::Bytes;
+	use hyper::{Method, StatusCode};
+	use rstest::rstest;
+
+	fn article() -> ManagedArticle {
+		ManagedArticle {
+			id: Some(3),
+			title: "Hello".to_string(),
+			is_archived: false,
+			tenant_id: 1,
+		}
+	}
+
+	fn get_request() -> Request {
+		Request::builder()
+			.method(Method::GET)
+			.uri("/articles/3/edit/")
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_get_returns_object_as_json() {
+		// Arrange
+		let view = UpdateView::<ManagedArticle>::new().with_object(article());
+
+		// Act
+		let response = view.dispatch(get_request()).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::OK);
+		let body: Value = serde_json::from_slice(&response.body).unwrap();
+		assert_eq!(body["title"], "Hello");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_get_renders_template_with_object_context() {
+		// Arrange
+		let view = UpdateView::<ManagedArticle>::new()
+			.with_object(article())
+			.with_context_object_name("article")
+			.with_render_mode(template_mode());
+
+		// Act
+		let response = view.dispatch(get_request()).await.unwrap();
+
+		// Assert
+		let body = String::from_utf8(response.body.to_vec()).unwrap();
+		assert!(body.starts_with("form.html:"));
+		assert!(body.contains(r#""article":{"id":3"#), "{}", body);
+		assert!(body.contains(r#""object":{"id":3"#), "{}", body);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_invalid_patch_rerenders_form_with_errors() {
+		// Arrange
+		let view = UpdateView::<ManagedArticle>::new()
+			.with_object(article())
+			.with_render_mode(template_mode());
+		let request = Request::builder()
+			.method(Method::PATCH)
+			.uri("/articles/3/edit/")
+			.header("Content-Type", "application/json")
+			.body(Bytes::from(r#"{"tenant_id": "one"}"#))
+			.build()
+			.unwrap();
+
+		// Act
+		let response = view.dispatch(request).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::OK);
+		let body = String::from_utf8(response.body.to_vec()).unwrap();
+		assert!(body.contains(r#""data":{"tenant_id":"one"}"#), "{}", body);
+		assert!(body.contains(r#""errors":{"_all":["#), "{}", body);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_delete_is_not_allowed() {
+		// Arrange
+		let view = UpdateView::<ManagedArticle>::new().with_object(article());
+		let request = Request::builder()
+			.method(Method::DELETE)
+			.uri("/articles/3/edit/")
+			.build()
+			.unwrap();
+
+		// Act
+		let result = view.dispatch(request).await;
+
+		// Assert
+		assert!(matches!(result, Err(Error::MethodNotAllowed(_))));
+	}
+}
@@ -0,0 +1,745 @@
+//! Syndication feeds
+//!
+//! A [`Feed`] describes a channel (title, link, description) and the items it
+//! publishes, usually the latest rows of a QuerySet. [`FeedDocument`] collects
+//! them once and renders either RSS 2.0 or Atom 1.0; [`FeedHandler`] serves
+//! the result with the matching content type and caching headers.
+//!
+//! Each item gets a GUID (the item link unless [`Feed::item_guid`] says
+//! otherwise) and may carry enclosures for podcasts and other media. The
+//! handler sets `Last-Modified` from the newest item date, so mounting
+//! `ConditionalGetMiddleware` in front of it answers conditional requests
+//! with `304 Not Modified`.
+//!
+//! Links may be given as paths: the handler makes them absolute on the
+//! current site (see [`reinhardt_http::site`]), falling back to the request's
+//! `Host` header when no site middleware is installed.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use reinhardt_views::feeds::{Enclosure, Feed, FeedHandler};
+//! use reinhardt_http::Result;
+//! use chrono::{DateTime, Utc};
+//!
+//! struct LatestEpisodes;
+//!
+//! #[async_trait::async_trait]
+//! impl Feed for LatestEpisodes {
+//!     type Item = Episode;
+//!
+//!     fn title(&self) -> String {
+//!         "Latest episodes".to_string()
+//!     }
+//!
+//!     fn link(&self) -> String {
+//!         "https://example.com/episodes/".to_string()
+//!     }
+//!
+//!     async fn items(&self) -> Result<Vec<Episode>> {
+//!         Ok(Episode::objects()
+//!             .all()
+//!             .order_by(&["-published_at"])
+//!             .limit(20)
+//!             .all()
+//!             .await?)
+//!     }
+//!
+//!     fn item_title(&self, item: &Episode) -> String {
+//!         item.title.clone()
+//!     }
+//!
+//!     fn item_link(&self, item: &Episode) -> String {
+//!         format!("https://example.com/episodes/{}/", item.slug)
+//!     }
+//!
+//!     fn item_pubdate(&self, item: &Episode) -> Option<DateTime<Utc>> {
+//!         Some(item.published_at)
+//!     }
+//!
+//!     fn item_enclosures(&self, item: &Episode) -> Vec<Enclosure> {
+//!         vec![Enclosure::new(&item.audio_url, item.audio_size, "audio/mpeg")]
+//!     }
+//! }
+//!
+//! let router = ServerRouter::new()
+//!     .handler("/episodes/rss/", FeedHandler::rss(LatestEpisodes))
+//!     .handler("/episodes/atom/", FeedHandler::atom(LatestEpisodes));
+//! ```
+
+use std::fmt::Write as _;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hyper::{Method, StatusCode};
+use quick_xml::escape::escape;
+use reinhardt_http::site::absolute_url;
+use reinhardt_http::{Handler, Request, Response, Result};
+
+/// Default `Cache-Control` max-age for feed responses
+pub const DEFAULT_FEED_MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
+/// Output format of a feed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedFormat {
+	/// RSS 2.0
+	Rss,
+	/// Atom 1.0
+	Atom,
+}
+
+impl FeedFormat {
+	/// `Content-Type` header value for this format
+	pub fn content_type(&self) -> &'static str {
+		match self {
+			FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+			FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+		}
+	}
+}
+
+/// Media file attached to a feed item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Enclosure {
+	/// Absolute URL of the file
+	pub url: String,
+	/// Size of the file in bytes
+	pub length: u64,
+	/// MIME type of the file
+	pub mime_type: String,
+}
+
+impl Enclosure {
+	/// Create an enclosure
+	pub fn new(url: impl Into<String>, length: u64, mime_type: impl Into<String>) -> Self {
+		Self {
+			url: url.into(),
+			length,
+			mime_type: mime_type.into(),
+		}
+	}
+}
+
+/// A syndication feed
+///
+/// Only the channel title and link, the item source and the item title and
+/// link are required; everything else is optional. Relative links are made
+/// absolute on the current site by [`FeedHandler`], since feed readers resolve
+/// them outside the site.
+#[async_trait]
+pub trait Feed: Send + Sync {
+	/// Objects published by the feed
+	type Item: Send + Sync;
+
+	/// Channel title
+	fn title(&self) -> String;
+
+	/// Link to the HTML page the feed belongs to
+	fn link(&self) -> String;
+
+	/// Channel description (`<description>` in RSS, `<subtitle>` in Atom)
+	fn description(&self) -> String {
+		String::new()
+	}
+
+	/// URL of the feed itself, advertised as the `self` link
+	fn feed_url(&self) -> Option<String> {
+		None
+	}
+
+	/// Channel language, e.g. `en-us`
+	fn language(&self) -> Option<String> {
+		None
+	}
+
+	/// Channel author name
+	fn author_name(&self) -> Option<String> {
+		None
+	}
+
+	/// `Cache-Control` max-age for the feed response
+	fn max_age(&self) -> Duration {
+		DEFAULT_FEED_MAX_AGE
+	}
+
+	/// Items to publish, newest first
+	async fn items(&self) -> Result<Vec<Self::Item>>;
+
+	/// Item title
+	fn item_title(&self, item: &Self::Item) -> String;
+
+	/// Link to the item's HTML page
+	fn item_link(&self, item: &Self::Item) -> String;
+
+	/// Item summary
+	fn item_description(&self, _item: &Self::Item) -> Option<String> {
+		None
+	}
+
+	/// Globally unique identifier of the item; defaults to its link
+	fn item_guid(&self, item: &Self::Item) -> String {
+		self.item_link(item)
+	}
+
+	/// Whether the GUID is a URL pointing at the item
+	///
+	/// Defaults to `true` when the GUID is the item link.
+	fn item_guid_is_permalink(&self, item: &Self::Item) -> bool {
+		self.item_guid(item) == self.item_link(item)
+	}
+
+	/// Publication date of the item
+	fn item_pubdate(&self, _item: &Self::Item) -> Option<DateTime<Utc>> {
+		None
+	}
+
+	/// Last update of the item; defaults to the publication date
+	fn item_updateddate(&self, item: &Self::Item) -> Option<DateTime<Utc>> {
+		self.item_pubdate(item)
+	}
+
+	/// Author name of the item
+	fn item_author_name(&self, _item: &Self::Item) -> Option<String> {
+		None
+	}
+
+	/// Categories of the item
+	fn item_categories(&self, _item: &Self::Item) -> Vec<String> {
+		Vec::new()
+	}
+
+	/// Media files attached to the item
+	fn item_enclosures(&self, _item: &Self::Item) -> Vec<Enclosure> {
+		Vec::new()
+	}
+}
+
+/// A feed item with all its fields resolved
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedEntry {
+	/// Item title
+	pub title: String,
+	/// Link to the item's HTML page
+	pub link: String,
+	/// Item summary
+	pub description: Option<String>,
+	/// Globally unique identifier
+	pub guid: String,
+	/// Whether `guid` is a URL pointing at the item
+	pub guid_is_permalink: bool,
+	/// Publication date
+	pub pubdate: Option<DateTime<Utc>>,
+	/// Last update
+	pub updated: Option<DateTime<Utc>>,
+	/// Author name
+	pub author_name: Option<String>,
+	/// Categories
+	pub categories: Vec<String>,
+	/// Attached media files
+	pub enclosures: Vec<Enclosure>,
+}
+
+/// A feed with its items resolved, ready to render
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedDocument {
+	/// Channel title
+	pub title: String,
+	/// Link to the HTML page the feed belongs to
+	pub link: String,
+	/// Channel description
+	pub description: String,
+	/// URL of the feed itself
+	pub feed_url: Option<String>,
+	/// Channel language
+	pub language: Option<String>,
+	/// Channel author name
+	pub author_name: Option<String>,
+	/// Items, in the order returned by [`Feed::items`]
+	pub entries: Vec<FeedEntry>,
+}
+
+impl FeedDocument {
+	/// Collect the channel fields and items of `feed`
+	pub async fn from_feed<F: Feed + ?Sized>(feed: &F) -> Result<Self> {
+		let entries = feed
+			.items()
+			.await?
+			.iter()
+			.map(|item| FeedEntry {
+				title: feed.item_title(item),
+				link: feed.item_link(item),
+				description: feed.item_description(item),
+				guid: feed.item_guid(item),
+				guid_is_permalink: feed.item_guid_is_permalink(item),
+				pubdate: feed.item_pubdate(item),
+				updated: feed.item_updateddate(item),
+				author_name: feed.item_author_name(item),
+				categories: feed.item_categories(item),
+				enclosures: feed.item_enclosures(item),
+			})
+			.collect();
+
+		Ok(Self {
+			title: feed.title(),
+			link: feed.link(),
+			description: feed.description(),
+			feed_url: feed.feed_url(),
+			language: feed.language(),
+			author_name: feed.author_name(),
+			entries,
+		})
+	}
+
+	/// Make relative links absolute on the site serving `request`
+	///
+	/// Covers the channel and feed links, item links, permalink GUIDs and
+	/// enclosure URLs; already absolute URLs are left untouched.
+	pub fn with_absolute_urls(mut self, request: &Request) -> Self {
+		self.link = absolute_url(request, &self.link);
+		self.feed_url = self
+			.feed_url
+			.map(|feed_url| absolute_url(request, &feed_url));
+		for entry in &mut self.entries {
+			entry.link = absolute_url(request, &entry.link);
+			if entry.guid_is_permalink {
+				entry.guid = absolute_url(request, &entry.guid);
+			}
+			for enclosure in &mut entry.enclosures {
+				enclosure.url = absolute_url(request, &enclosure.url);
+			}
+		}
+		self
+	}
+
+	/// Newest publication or update date among the items
+	pub fn last_modified(&self) -> Option<DateTime<Utc>> {
+		self.entries
+			.iter()
+			.filter_map(|entry| entry.updated.or(entry.pubdate))
+			.max()
+	}
+
+	/// Render in the given format
+	pub fn render(&self, format: FeedFormat) -> String {
+		match format {
+			FeedFormat::Rss => self.to_rss(),
+			FeedFormat::Atom => self.to_atom(),
+		}
+	}
+
+	/// Render as RSS 2.0
+	pub fn to_rss(&self) -> String {
+		let mut xml = String::from(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+		xml.push_str(r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">"#);
+		xml.push_str("<channel>");
+		push_element(&mut xml, "title", &self.title);
+		push_element(&mut xml, "link", &self.link);
+		push_element(&mut xml, "description", &self.description);
+		if let Some(feed_url) = &self.feed_url {
+			let _ = write!(
+				xml,
+				r#"<atom:link href="{}" rel="self"/>"#,
+				escape(feed_url.as_str())
+			);
+		}
+		if let Some(language) = &self.language {
+			push_element(&mut xml, "language", language);
+		}
+		if let Some(last_modified) = self.last_modified() {
+			push_element(&mut xml, "lastBuildDate", &last_modified.to_rfc2822());
+		}
+
+		for entry in &self.entries {
+			xml.push_str("<item>");
+			push_element(&mut xml, "title", &entry.title);
+			push_element(&mut xml, "link", &entry.link);
+			if let Some(description) = &entry.description {
+				push_element(&mut xml, "description", description);
+			}
+			if let Some(author_name) = &entry.author_name {
+				push_element(&mut xml, "author", author_name);
+			}
+			if let Some(pubdate) = entry.pubdate {
+				push_element(&mut xml, "pubDate", &pubdate.to_rfc2822());
+			}
+			let _ = write!(
+				xml,
+				r#"<guid isPermaLink="{}">{}</guid>"#,
+				entry.guid_is_permalink,
+				escape(entry.guid.as_str())
+			);
+			for category in &entry.categories {
+				push_element(&mut xml, "category", category);
+			}
+			// RSS allows a single enclosure per item.
+			if let Some(enclosure) = entry.enclosures.first() {
+				let _ = write!(
+					xml,
+					r#"<enclosure url="{}" length="{}" type="{}"/>"#,
+					escape(enclosure.url.as_str()),
+					enclosure.length,
+					escape(enclosure.mime_type.as_str())
+				);
+			}
+			xml.push_str("</item>");
+		}
+
+		xml.push_str("</channel></rss>");
+		xml
+	}
+
+	/// Render as Atom 1.0
+	pub fn to_atom(&self) -> String {
+		let mut xml = String::from(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+		match &self.language {
+			Some(language) => {
+				let _ = write!(
+					xml,
+					r#"<feed xmlns="http://www.w3.org/2005/Atom" xml:lang="{}">"#,
+					escape(language.as_str())
+				);
+			}
+			None => xml.push_str(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#),
+		}
+		push_element(&mut xml, "title", &self.title);
+		push_link(&mut xml, &self.link, "alternate");
+		if let Some(feed_url) = &self.feed_url {
+			push_link(&mut xml, feed_url, "self");
+		}
+		push_element(
+			&mut xml,
+			"id",
+			self.feed_url.as_deref().unwrap_or(&self.link),
+		);
+		// Atom requires <updated>; an empty feed falls back to the Unix epoch
+		// so that the document stays valid and stable across requests.
+		let updated = self.last_modified().unwrap_or(DateTime::UNIX_EPOCH);
+		push_element(&mut xml, "updated", &updated.to_rfc3339());
+		if !self.description.is_empty() {
+			push_element(&mut xml, "subtitle", &self.description);
+		}
+		if let Some(author_name) = &self.author_name {
+			push_author(&mut xml, author_name);
+		}
+
+		for entry in &self.entries {
+			xml.push_str("<entry>");
+			push_element(&mut xml, "title", &entry.title);
+			push_link(&mut xml, &entry.link, "alternate");
+			push_element(&mut xml, "id", &entry.guid);
+			let updated = entry.updated.or(entry.pubdate).unwrap_or(updated);
+			push_element(&mut xml, "updated", &updated.to_rfc3339());
+			if let Some(pubdate) = entry.pubdate {
+				push_element(&mut xml, "published", &pubdate.to_rfc3339());
+			}
+			if let Some(author_name) = &entry.author_name {
+				push_author(&mut xml, author_name);
+			}
+			if let Some(description) = &entry.description {
+				let _ = write!(
+					xml,
+					r#"<summary type="html">{}</summary>"#,
+					escape(description.as_str())
+				);
+			}
+			for category in &entry.categories {
+				let _ = write!(xml, r#"<category term="{}"/>"#, escape(category.as_str()));
+			}
+			for enclosure in &entry.enclosures {
+				let _ = write!(
+					xml,
+					r#"<link rel="enclosure" href="{}" length="{}" type="{}"/>"#,
+					escape(enclosure.url.as_str()),
+					enclosure.length,
+					escape(enclosure.mime_type.as_str())
+				);
+			}
+			xml.push_str("</entry>");
+		}
+
+		xml.push_str("</feed>");
+		xml
+	}
+}
+
+fn push_element(xml: &mut String, name: &str, text: &str) {
+	let _ = write!(xml, "<{name}>{}</{name}>", escape(text));
+}
+
+fn push_link(xml: &mut String, href: &str, rel: &str) {
+	let _ = write!(xml, r#"<link href="{}" rel="{}"/>"#, escape(href), rel);
+}
+
+fn push_author(xml: &mut String, name: &str) {
+	let _ = write!(xml, "<author><name>{}</name></author>", escape(name));
+}
+
+/// Format a date as an HTTP-date (RFC 7231 IMF-fixdate)
+fn http_date(date: DateTime<Utc>) -> String {
+	date.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// HTTP handler serving a [`Feed`] as RSS or Atom
+pub struct FeedHandler<F> {
+	feed: F,
+	format: FeedFormat,
+}
+
+impl<F: Feed> FeedHandler<F> {
+	/// Serve `feed` in the given format
+	pub fn new(feed: F, format: FeedFormat) -> Self {
+		Self { feed, format }
+	}
+
+	/// Serve `feed` as RSS 2.0
+	pub fn rss(feed: F) -> Self {
+		Self::new(feed, FeedFormat::Rss)
+	}
+
+	/// Serve `feed` as Atom 1.0
+	pub fn atom(feed: F) -> Self {
+		Self::new(feed, FeedFormat::Atom)
+	}
+
+	/// Format served by this handler
+	pub fn format(&self) -> FeedFormat {
+		self.format
+	}
+}
+
+#[async_trait]
+impl<F: Feed + 'static> Handler for FeedHandler<F> {
+	async fn handle(&self, request: Request) -> Result<Response> {
+		if request.method != Method::GET && request.method != Method::HEAD {
+			return Ok(
+				Response::new(StatusCode::METHOD_NOT_ALLOWED).with_header("Allow", "GET, HEAD")
+			);
+		}
+
+		let document = FeedDocument::from_feed(&self.feed)
+			.await?
+			.with_absolute_urls(&request);
+		let cache_control = format!("public, max-age={}", self.feed.max_age().as_secs());
+		let mut response = Response::ok()
+			.with_header("Content-Type", self.format.content_type())
+			.with_header("Cache-Control", &cache_control);
+		if let Some(last_modified) = document.last_modified() {
+			response = response.with_header("Last-Modified", &http_date(last_modified));
+		}
+		if request.method == Method::GET {
+			response = response.with_body(document.render(self.format));
+		}
+		Ok(response)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chrono::TimeZone;
+	use rstest::rstest;
+
+	struct Episode {
+		slug: &'static str,
+		title: &'static str,
+		published_at: DateTime<Utc>,
+	}
+
+	struct Episodes;
+
+	#[async_trait]
+	impl Feed for Episodes {
+		type Item = Episode;
+
+		fn title(&self) -> String {
+			"Tea & Rust".to_string()
+		}
+
+		fn link(&self) -> String {
+			"https://example.com/episodes/".to_string()
+		}
+
+		fn feed_url(&self) -> Option<String> {
+			Some("https://example.com/episodes/feed/".to_string())
+		}
+
+		async fn items(&self) -> Result<Vec<Episode>> {
+			Ok(vec![
+				Episode {
+					slug: "two",
+					title: "Lifetimes <explained>",
+					published_at: Utc.with_ymd_and_hms(2024, 3, 2, 9, 0, 0).unwrap(),
+				},
+				Episode {
+					slug: "one",
+					title: "Hello",
+					published_at: Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap(),
+				},
+			])
+		}
+
+		fn item_title(&self, item: &Episode) -> String {
+			item.title.to_string()
+		}
+
+		fn item_link(&self, item: &Episode) -> String {
+			format!("https://example.com/episodes/{}/", item.slug)
+		}
+
+		fn item_guid(&self, item: &Episode) -> String {
+			format!("urn:episode:{}", item.slug)
+		}
+
+		fn item_pubdate(&self, item: &Episode) -> Option<DateTime<Utc>> {
+			Some(item.published_at)
+		}
+
+		fn item_enclosures(&self, item: &Episode) -> Vec<Enclosure> {
+			vec![Enclosure::new(
+				format!("https://cdn.example.com/{}.mp3", item.slug),
+				1024,
+				"audio/mpeg",
+			)]
+		}
+	}
+
+	fn request(method: Method) -> Request {
+		Request::builder()
+			.method(method)
+			.uri("/episodes/feed/")
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_rss_renders_items_with_guid_and_enclosure() {
+		// Arrange
+		let document = FeedDocument::from_feed(&Episodes).await.unwrap();
+
+		// Act
+		let xml = document.to_rss();
+
+		// Assert
+		assert!(xml.contains("<title>Tea &amp; Rust</title>"));
+		assert!(xml.contains("<title>Lifetimes &lt;explained&gt;</title>"));
+		assert!(xml.contains(r#"<guid isPermaLink="false">urn:episode:two</guid>"#));
+		assert!(xml.contains(
+			r#"<enclosure url="https://cdn.example.com/two.mp3" length="1024" type="audio/mpeg"/>"#
+		));
+		assert!(xml.contains("<pubDate>Sat, 2 Mar 2024 09:00:00 +0000</pubDate>"));
+		assert_eq!(xml.matches("<item>").count(), 2);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_atom_renders_ids_and_updated() {
+		// Arrange
+		let document = FeedDocument::from_feed(&Episodes).await.unwrap();
+
+		// Act
+		let xml = document.to_atom();
+
+		// Assert
+		assert!(xml.contains(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#));
+		assert!(xml.contains("<id>https://example.com/episodes/feed/</id>"));
+		assert!(xml.contains(r#"<link href="https://example.com/episodes/feed/" rel="self"/>"#));
+		assert!(xml.contains("<id>urn:episode:one</id>"));
+		assert!(xml.contains("<updated>2024-03-02T09:00:00+00:00</updated>"));
+		assert!(xml.contains(r#"<link rel="enclosure" href="https://cdn.example.com/one.mp3""#));
+		assert_eq!(xml.matches("<entry>").count(), 2);
+	}
+
+	#[rstest]
+	#[case::rss(FeedFormat::Rss, "application/rss+xml; charset=utf-8")]
+	#[case::atom(FeedFormat::Atom, "application/atom+xml; charset=utf-8")]
+	#[tokio::test]
+	async fn test_handler_sets_content_type_and_caching_headers(
+		#[case] format: FeedFormat,
+		#[case] content_type: &str,
+	) {
+		// Arrange
+		let handler = FeedHandler::new(Episodes, format);
+
+		// Act
+		let response = handler.handle(request(Method::GET)).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::OK);
+		assert_eq!(response.headers["content-type"], content_type);
+		assert_eq!(response.headers["cache-control"], "public, max-age=900");
+		assert_eq!(
+			response.headers["last-modified"],
+			"Sat, 02 Mar 2024 09:00:00 GMT"
+		);
+		assert!(!response.body.is_empty());
+	}
+
+	struct RelativeLinks;
+
+	#[async_trait]
+	impl Feed for RelativeLinks {
+		type Item = &'static str;
+
+		fn title(&self) -> String {
+			"News".to_string()
+		}
+
+		fn link(&self) -> String {
+			"/news/".to_string()
+		}
+
+		async fn items(&self) -> Result<Vec<&'static str>> {
+			Ok(vec!["launch"])
+		}
+
+		fn item_title(&self, item: &&'static str) -> String {
+			item.to_string()
+		}
+
+		fn item_link(&self, item: &&'static str) -> String {
+			format!("/news/{}/", item)
+		}
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_handler_makes_links_absolute_on_current_site() {
+		// Arrange
+		let handler = FeedHandler::rss(RelativeLinks);
+		let request = request(Method::GET);
+		request.extensions.insert(reinhardt_http::Site::new(
+			2,
+			"news.example.com".to_string(),
+			"News".to_string(),
+		));
+
+		// Act
+		let response = handler.handle(request).await.unwrap();
+
+		// Assert
+		let xml = String::from_utf8(response.body.to_vec()).unwrap();
+		assert!(xml.contains("<link>http://news.example.com/news/</link>"));
+		assert!(xml.contains("<link>http://news.example.com/news/launch/</link>"));
+		assert!(
+			xml.contains(r#"<guid isPermaLink="true">http://news.example.com/news/launch/</guid>"#)
+		);
+	}
+
+	#[rstest]
+	#[case::head(Method::HEAD, StatusCode::OK)]
+	#[case::post(Method::POST, StatusCode::METHOD_NOT_ALLOWED)]
+	#[tokio::test]
+	async fn test_handler_without_body(#[case] method: Method, #[case] expected: StatusCode) {
+		// Arrange
+		let handler = FeedHandler::rss(Episodes);
+
+		// Act
+		let response = handler.handle(request(method)).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, expected);
+		assert!(response.body.is_empty());
+	}
+}
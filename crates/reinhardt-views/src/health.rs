@@ -0,0 +1,500 @@
+//! Health and readiness endpoints
+//!
+//! [`HealthProbes`] collects named probes implementing
+//! [`HealthCheck`] and serves them through two handlers:
+//!
+//! - `/healthz` ([`HealthProbes::healthz`]) runs liveness probes. It should only
+//!   fail when the process itself is broken and needs a restart.
+//! - `/readyz` ([`HealthProbes::readyz`]) runs readiness probes such as the
+//!   database, cache and migration checks in [`probes`]. Load balancers stop
+//!   routing traffic while it fails.
+//!
+//! Probes run concurrently, each bounded by a timeout. The response is a JSON
+//! report with the aggregated status and per-probe latency. A `degraded` probe
+//! keeps the endpoint at `200 OK` (the service still works, with reduced
+//! quality), while an `unhealthy` probe turns it into `503 Service Unavailable`.
+//!
+//! ```json
+//! {
+//!   "status": "degraded",
+//!   "latency_ms": 12.4,
+//!   "checks": [
+//!     { "name": "database", "status": "healthy", "latency_ms": 3.1, "message": null, "metadata": {} },
+//!     { "name": "disk", "status": "degraded", "latency_ms": 0.2, "message": "1.2 GiB free", "metadata": {} }
+//!   ]
+//! }
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use reinhardt_views::health::HealthProbes;
+//! use reinhardt_views::health::probes::{DatabaseProbe, DiskSpaceProbe};
+//! use std::sync::Arc;
+//!
+//! let probes = Arc::new(
+//!     HealthProbes::new()
+//!         .readiness("database", DatabaseProbe::new(connection))
+//!         .readiness("disk", DiskSpaceProbe::new("/var/lib/myapp", 512 * 1024 * 1024)),
+//! );
+//!
+//! let router = ServerRouter::new()
+//!     .handler("/healthz", probes.healthz())
+//!     .handler("/readyz", probes.readyz());
+//! ```
+
+pub mod probes;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use hyper::{Method, StatusCode};
+use reinhardt_http::{Handler, Request, Response, Result};
+use reinhardt_utils::staticfiles::health::{HealthCheck, HealthCheckResult, HealthStatus};
+use reinhardt_utils::utils_core::checks::{Check, CheckMessage};
+use serde::{Serialize, Serializer};
+
+/// Default per-probe timeout
+pub const DEFAULT_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+fn serialize_status<S: Serializer>(
+	status: &HealthStatus,
+	serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+	serializer.collect_str(status)
+}
+
+fn millis(duration: Duration) -> f64 {
+	duration.as_secs_f64() * 1000.0
+}
+
+/// Which endpoint a probe belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProbeKind {
+	/// Probes served by `/healthz`
+	Liveness,
+	/// Probes served by `/readyz`
+	Readiness,
+}
+
+/// Outcome of a single probe run
+#[derive(Debug, Clone, Serialize)]
+pub struct ProbeOutcome {
+	/// Probe name
+	pub name: String,
+	/// Probe status
+	#[serde(serialize_with = "serialize_status")]
+	pub status: HealthStatus,
+	/// Time spent running the probe, in milliseconds
+	pub latency_ms: f64,
+	/// Optional details reported by the probe
+	pub message: Option<String>,
+	/// Additional metadata reported by the probe
+	pub metadata: HashMap<String, String>,
+}
+
+/// Aggregated result of running a set of probes
+#[derive(Debug, Clone, Serialize)]
+pub struct ProbeReport {
+	/// Worst status among all probes (`healthy` when no probes are registered)
+	#[serde(serialize_with = "serialize_status")]
+	pub status: HealthStatus,
+	/// Wall-clock time for the whole run, in milliseconds
+	pub latency_ms: f64,
+	/// Individual probe outcomes, in registration order
+	pub checks: Vec<ProbeOutcome>,
+}
+
+impl ProbeReport {
+	/// HTTP status code for this report
+	///
+	/// `degraded` is still served with `200 OK`; only `unhealthy` maps to
+	/// `503 Service Unavailable`.
+	pub fn status_code(&self) -> StatusCode {
+		match self.status {
+			HealthStatus::Healthy | HealthStatus::Degraded => StatusCode::OK,
+			HealthStatus::Unhealthy => StatusCode::SERVICE_UNAVAILABLE,
+		}
+	}
+}
+
+struct RegisteredProbe {
+	name: String,
+	kind: ProbeKind,
+	check: Arc<dyn HealthCheck>,
+}
+
+/// Registry of liveness and readiness probes
+pub struct HealthProbes {
+	probes: Vec<RegisteredProbe>,
+	timeout: Duration,
+}
+
+impl Default for HealthProbes {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl HealthProbes {
+	/// Create an empty registry with the default probe timeout
+	pub fn new() -> Self {
+		Self {
+			probes: Vec::new(),
+			timeout: DEFAULT_PROBE_TIMEOUT,
+		}
+	}
+
+	/// Set the per-probe timeout; probes exceeding it are reported unhealthy
+	pub fn with_timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = timeout;
+		self
+	}
+
+	/// Register a probe for `kind`
+	pub fn register(
+		mut self,
+		kind: ProbeKind,
+		name: impl Into<String>,
+		check: impl HealthCheck + 'static,
+	) -> Self {
+		self.probes.push(RegisteredProbe {
+			name: name.into(),
+			kind,
+			check: Arc::new(check),
+		});
+		self
+	}
+
+	/// Register a liveness probe served by `/healthz`
+	pub fn liveness(self, name: impl Into<String>, check: impl HealthCheck + 'static) -> Self {
+		self.register(ProbeKind::Liveness, name, check)
+	}
+
+	/// Register a readiness probe served by `/readyz`
+	pub fn readiness(self, name: impl Into<String>, check: impl HealthCheck + 'static) -> Self {
+		self.register(ProbeKind::Readiness, name, check)
+	}
+
+	/// Names of the probes registered for `kind`
+	pub fn probe_names(&self, kind: ProbeKind) -> Vec<&str> {
+		self.probes
+			.iter()
+			.filter(|probe| probe.kind == kind)
+			.map(|probe| probe.name.as_str())
+			.collect()
+	}
+
+	/// Run all probes for `kind` concurrently and aggregate the results
+	pub async fn run(&self, kind: ProbeKind) -> ProbeReport {
+		let started = Instant::now();
+		let runs = self
+			.probes
+			.iter()
+			.filter(|probe| probe.kind == kind)
+			.map(|probe| self.run_probe(probe));
+		let checks = futures::future::join_all(runs).await;
+
+		let status = checks
+			.iter()
+			.map(|outcome| outcome.status)
+			.max_by_key(|status| severity(*status))
+			.unwrap_or(HealthStatus::Healthy);
+
+		ProbeReport {
+			status,
+			latency_ms: millis(started.elapsed()),
+			checks,
+		}
+	}
+
+	async fn run_probe(&self, probe: &RegisteredProbe) -> ProbeOutcome {
+		let started = Instant::now();
+		let result = tokio::time::timeout(self.timeout, probe.check.check())
+			.await
+			.unwrap_or_else(|_| {
+				HealthCheckResult::unhealthy(
+					probe.name.clone(),
+					format!("probe timed out after {:?}", self.timeout),
+				)
+			});
+
+		ProbeOutcome {
+			name: probe.name.clone(),
+			status: result.status,
+			latency_ms: millis(started.elapsed()),
+			message: result.message,
+			metadata: result.metadata,
+		}
+	}
+
+	/// Handler serving the liveness report (mount at `/healthz`)
+	pub fn healthz(self: &Arc<Self>) -> HealthHandler {
+		HealthHandler {
+			probes: Arc::clone(self),
+			kind: ProbeKind::Liveness,
+		}
+	}
+
+	/// Handler serving the readiness report (mount at `/readyz`)
+	pub fn readyz(self: &Arc<Self>) -> HealthHandler {
+		HealthHandler {
+			probes: Arc::clone(self),
+			kind: ProbeKind::Readiness,
+		}
+	}
+
+	/// System check validating the probe configuration
+	///
+	/// Register it with [`CheckRegistry`](reinhardt_utils::utils_core::checks::CheckRegistry)
+	/// so that `check` reports missing readiness probes and duplicate names.
+	pub fn system_check(self: &Arc<Self>) -> HealthProbesCheck {
+		HealthProbesCheck {
+			probes: Arc::clone(self),
+		}
+	}
+}
+
+fn severity(status: HealthStatus) -> u8 {
+	match status {
+		HealthStatus::Healthy => 0,
+		HealthStatus::Degraded => 1,
+		HealthStatus::Unhealthy => 2,
+	}
+}
+
+/// HTTP handler serving a [`ProbeReport`] as JSON
+pub struct HealthHandler {
+	probes: Arc<HealthProbes>,
+	kind: ProbeKind,
+}
+
+#[async_trait]
+impl Handler for HealthHandler {
+	async fn handle(&self, request: Request) -> Result<Response> {
+		if request.method != Method::GET && request.method != Method::HEAD {
+			return Ok(
+				Response::new(StatusCode::METHOD_NOT_ALLOWED).with_header("Allow", "GET, HEAD")
+			);
+		}
+
+		let report = self.probes.run(self.kind).await;
+		let response = Response::new(report.status_code())
+			.with_header("Cache-Control", "no-store")
+			.with_json(&report)?;
+		Ok(response)
+	}
+}
+
+/// System check (`health.*`) for a [`HealthProbes`] registry
+pub struct HealthProbesCheck {
+	probes: Arc<HealthProbes>,
+}
+
+impl Check for HealthProbesCheck {
+	fn tags(&self) -> Vec<String> {
+		vec!["health".to_string()]
+	}
+
+	fn check(&self) -> Vec<CheckMessage> {
+		let mut messages = Vec::new();
+
+		for kind in [ProbeKind::Liveness, ProbeKind::Readiness] {
+			let names = self.probes.probe_names(kind);
+			let mut seen = std::collections::HashSet::new();
+			for name in names {
+				if !seen.insert(name) {
+					messages.push(CheckMessage::error(
+						"health.E001",
+						format!(
+							"Probe '{}' is registered more than once for {:?}",
+							name, kind
+						),
+					));
+				}
+			}
+		}
+
+		if self.probes.probe_names(ProbeKind::Readiness).is_empty() {
+			messages.push(
+				CheckMessage::warning("health.W001", "No readiness probes are registered")
+					.with_hint(
+						"Register a DatabaseProbe so /readyz reflects database availability",
+					),
+			);
+		}
+
+		messages
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	struct FixedProbe {
+		status: HealthStatus,
+		delay: Duration,
+	}
+
+	#[async_trait]
+	impl HealthCheck for FixedProbe {
+		async fn check(&self) -> HealthCheckResult {
+			tokio::time::sleep(self.delay).await;
+			match self.status {
+				HealthStatus::Healthy => HealthCheckResult::healthy("fixed"),
+				HealthStatus::Degraded => HealthCheckResult::degraded("fixed", "slow"),
+				HealthStatus::Unhealthy => HealthCheckResult::unhealthy("fixed", "down"),
+			}
+		}
+	}
+
+	fn probe(status: HealthStatus) -> FixedProbe {
+		FixedProbe {
+			status,
+			delay: Duration::ZERO,
+		}
+	}
+
+	fn get(path: &str) -> Request {
+		Request::builder()
+			.method(Method::GET)
+			.uri(path)
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	#[case::all_healthy(
+		HealthStatus::Healthy,
+		HealthStatus::Healthy,
+		StatusCode::OK,
+		"healthy"
+	)]
+	#[case::degraded(
+		HealthStatus::Healthy,
+		HealthStatus::Degraded,
+		StatusCode::OK,
+		"degraded"
+	)]
+	#[case::down(
+		HealthStatus::Degraded,
+		HealthStatus::Unhealthy,
+		StatusCode::SERVICE_UNAVAILABLE,
+		"unhealthy"
+	)]
+	#[tokio::test]
+	async fn test_readyz_aggregates_worst_status(
+		#[case] first: HealthStatus,
+		#[case] second: HealthStatus,
+		#[case] expected_code: StatusCode,
+		#[case] expected_status: &str,
+	) {
+		// Arrange
+		let probes = Arc::new(
+			HealthProbes::new()
+				.readiness("database", probe(first))
+				.readiness("cache", probe(second)),
+		);
+
+		// Act
+		let response = probes.readyz().handle(get("/readyz")).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, expected_code);
+		let body: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+		assert_eq!(body["status"], expected_status);
+		assert_eq!(body["checks"][0]["name"], "database");
+		assert_eq!(body["checks"][1]["name"], "cache");
+		assert!(body["checks"][0]["latency_ms"].is_number());
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_healthz_ignores_readiness_probes() {
+		// Arrange
+		let probes = Arc::new(
+			HealthProbes::new()
+				.liveness("process", probe(HealthStatus::Healthy))
+				.readiness("database", probe(HealthStatus::Unhealthy)),
+		);
+
+		// Act
+		let report = probes.run(ProbeKind::Liveness).await;
+
+		// Assert
+		assert_eq!(report.status, HealthStatus::Healthy);
+		assert_eq!(report.checks.len(), 1);
+		assert_eq!(report.checks[0].name, "process");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_probe_timeout_is_unhealthy() {
+		// Arrange
+		let probes = HealthProbes::new()
+			.with_timeout(Duration::from_millis(10))
+			.readiness(
+				"slow",
+				FixedProbe {
+					status: HealthStatus::Healthy,
+					delay: Duration::from_secs(5),
+				},
+			);
+
+		// Act
+		let report = probes.run(ProbeKind::Readiness).await;
+
+		// Assert
+		assert_eq!(report.status, HealthStatus::Unhealthy);
+		assert!(
+			report.checks[0]
+				.message
+				.as_deref()
+				.unwrap()
+				.contains("timed out")
+		);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_rejects_non_get_methods() {
+		// Arrange
+		let probes = Arc::new(HealthProbes::new());
+		let request = Request::builder()
+			.method(Method::POST)
+			.uri("/healthz")
+			.build()
+			.unwrap();
+
+		// Act
+		let response = probes.healthz().handle(request).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, StatusCode::METHOD_NOT_ALLOWED);
+	}
+
+	#[rstest]
+	fn test_system_check_reports_duplicates_and_missing_readiness() {
+		// Arrange
+		let probes = Arc::new(
+			HealthProbes::new()
+				.liveness("process", probe(HealthStatus::Healthy))
+				.liveness("process", probe(HealthStatus::Healthy)),
+		);
+
+		// Act
+		let ids: Vec<String> = probes
+			.system_check()
+			.check()
+			.into_iter()
+			.map(|message| message.id)
+			.collect();
+
+		// Assert
+		assert_eq!(ids, vec!["health.E001", "health.W001"]);
+	}
+}
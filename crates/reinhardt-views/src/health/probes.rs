@@ -0,0 +1,318 @@
+//! Built-in dependency probes
+//!
+//! Each probe implements [`HealthCheck`] and can be registered with
+//! [`HealthProbes`](super::HealthProbes). Probes that measure latency report
+//! `degraded` when a configurable threshold is exceeded and `unhealthy` when
+//! the dependency fails outright.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use reinhardt_db::DatabaseConnection;
+use reinhardt_utils::cache::Cache;
+use reinhardt_utils::staticfiles::health::{HealthCheck, HealthCheckResult};
+
+/// Classify a successful round-trip by latency
+fn latency_result(
+	component: &str,
+	elapsed: Duration,
+	degraded_after: Duration,
+) -> HealthCheckResult {
+	let result = if elapsed > degraded_after {
+		HealthCheckResult::degraded(
+			component,
+			format!(
+				"responded in {:?} (threshold {:?})",
+				elapsed, degraded_after
+			),
+		)
+	} else {
+		HealthCheckResult::healthy(component)
+	};
+	result.with_metadata("round_trip_ms", elapsed.as_millis().to_string())
+}
+
+/// Pings the database with `SELECT 1`
+pub struct DatabaseProbe {
+	connection: DatabaseConnection,
+	degraded_after: Duration,
+}
+
+impl DatabaseProbe {
+	/// Create a probe for `connection`, degraded after 500ms
+	pub fn new(connection: DatabaseConnection) -> Self {
+		Self {
+			connection,
+			degraded_after: Duration::from_millis(500),
+		}
+	}
+
+	/// Report `degraded` when the ping takes longer than `threshold`
+	pub fn degraded_after(mut self, threshold: Duration) -> Self {
+		self.degraded_after = threshold;
+		self
+	}
+}
+
+#[async_trait]
+impl HealthCheck for DatabaseProbe {
+	async fn check(&self) -> HealthCheckResult {
+		let started = Instant::now();
+		match self.connection.execute("SELECT 1", Vec::new()).await {
+			Ok(_) => latency_result("database", started.elapsed(), self.degraded_after),
+			Err(err) => HealthCheckResult::unhealthy("database", err.to_string()),
+		}
+	}
+}
+
+/// Writes, reads back and deletes a sentinel key in a cache backend
+pub struct CacheProbe<C> {
+	cache: C,
+	key: String,
+	degraded_after: Duration,
+}
+
+impl<C: Cache> CacheProbe<C> {
+	/// Create a probe for `cache`, degraded after 200ms
+	pub fn new(cache: C) -> Self {
+		Self {
+			cache,
+			key: "reinhardt:health:probe".to_string(),
+			degraded_after: Duration::from_millis(200),
+		}
+	}
+
+	/// Use `key` as the sentinel key
+	pub fn with_key(mut self, key: impl Into<String>) -> Self {
+		self.key = key.into();
+		self
+	}
+
+	/// Report `degraded` when the round-trip takes longer than `threshold`
+	pub fn degraded_after(mut self, threshold: Duration) -> Self {
+		self.degraded_after = threshold;
+		self
+	}
+
+	async fn round_trip(&self) -> reinhardt_utils::cache::Result<bool> {
+		let token = uuid::Uuid::new_v4().to_string();
+		self.cache
+			.set(&self.key, &token, Some(Duration::from_secs(60)))
+			.await?;
+		let read = self.cache.get::<String>(&self.key).await?;
+		self.cache.delete(&self.key).await?;
+		Ok(read.as_deref() == Some(token.as_str()))
+	}
+}
+
+#[async_trait]
+impl<C: Cache> HealthCheck for CacheProbe<C> {
+	async fn check(&self) -> HealthCheckResult {
+		let started = Instant::now();
+		match self.round_trip().await {
+			Ok(true) => latency_result("cache", started.elapsed(), self.degraded_after),
+			Ok(false) => HealthCheckResult::unhealthy("cache", "sentinel value was not read back"),
+			Err(err) => HealthCheckResult::unhealthy("cache", err.to_string()),
+		}
+	}
+}
+
+/// Verifies that every registered migration has been applied
+///
+/// Compares the global migration registry against the migrations recorded in
+/// the database. Pending migrations make the service unready so that a new
+/// release does not receive traffic before `migrate` has run.
+#[cfg(feature = "migrations")]
+pub struct MigrationsProbe {
+	connection: DatabaseConnection,
+}
+
+#[cfg(feature = "migrations")]
+impl MigrationsProbe {
+	/// Create a probe reading the migration history through `connection`
+	pub fn new(connection: DatabaseConnection) -> Self {
+		Self { connection }
+	}
+}
+
+#[cfg(feature = "migrations")]
+#[async_trait]
+impl HealthCheck for MigrationsProbe {
+	async fn check(&self) -> HealthCheckResult {
+		use reinhardt_db::migrations::DatabaseMigrationRecorder;
+		use reinhardt_db::migrations::registry::{MigrationRegistry, global_registry};
+
+		let recorder = DatabaseMigrationRecorder::new(self.connection.inner().clone());
+		let applied = match recorder.get_applied_migrations().await {
+			Ok(applied) => applied,
+			Err(err) => return HealthCheckResult::unhealthy("migrations", err.to_string()),
+		};
+		let applied: std::collections::HashSet<(String, String)> = applied
+			.into_iter()
+			.map(|record| (record.app, record.name))
+			.collect();
+
+		let mut pending: Vec<String> = global_registry()
+			.all_migrations()
+			.into_iter()
+			.filter(|migration| {
+				!applied.contains(&(migration.app_label.clone(), migration.name.clone()))
+			})
+			.map(|migration| format!("{}.{}", migration.app_label, migration.name))
+			.collect();
+		pending.sort();
+
+		if pending.is_empty() {
+			HealthCheckResult::healthy("migrations")
+		} else {
+			HealthCheckResult::unhealthy(
+				"migrations",
+				format!(
+					"{} unapplied migration(s): {}",
+					pending.len(),
+					pending.join(", ")
+				),
+			)
+			.with_metadata("pending", pending.len().to_string())
+		}
+	}
+}
+
+/// Checks free space on the filesystem holding `path`
+///
+/// Reports `unhealthy` below `min_free_bytes` and `degraded` below the warning
+/// threshold (twice the minimum by default).
+pub struct DiskSpaceProbe {
+	path: PathBuf,
+	min_free_bytes: u64,
+	warn_free_bytes: u64,
+}
+
+impl DiskSpaceProbe {
+	/// Create a probe for the filesystem containing `path`
+	pub fn new(path: impl Into<PathBuf>, min_free_bytes: u64) -> Self {
+		Self {
+			path: path.into(),
+			min_free_bytes,
+			warn_free_bytes: min_free_bytes.saturating_mul(2),
+		}
+	}
+
+	/// Report `degraded` when free space drops below `bytes`
+	pub fn warn_below(mut self, bytes: u64) -> Self {
+		self.warn_free_bytes = bytes;
+		self
+	}
+
+	fn classify(&self, free: u64) -> HealthCheckResult {
+		let result = if free < self.min_free_bytes {
+			HealthCheckResult::unhealthy(
+				"disk",
+				format!("{} bytes free on {}", free, self.path.display()),
+			)
+		} else if free < self.warn_free_bytes {
+			HealthCheckResult::degraded(
+				"disk",
+				format!("{} bytes free on {}", free, self.path.display()),
+			)
+		} else {
+			HealthCheckResult::healthy("disk")
+		};
+		result.with_metadata("free_bytes", free.to_string())
+	}
+}
+
+#[cfg(unix)]
+fn available_bytes(path: &std::path::Path) -> std::io::Result<u64> {
+	let stat = nix::sys::statvfs::statvfs(path).map_err(std::io::Error::from)?;
+	// Field widths differ between platforms (u32 on some BSDs, u64 on Linux).
+	#[allow(clippy::unnecessary_cast)]
+	let free = stat.blocks_available() as u64 * stat.fragment_size() as u64;
+	Ok(free)
+}
+
+#[cfg(not(unix))]
+fn available_bytes(_path: &std::path::Path) -> std::io::Result<u64> {
+	Err(std::io::Error::new(
+		std::io::ErrorKind::Unsupported,
+		"disk space probing is only supported on Unix platforms",
+	))
+}
+
+#[async_trait]
+impl HealthCheck for DiskSpaceProbe {
+	async fn check(&self) -> HealthCheckResult {
+		let path = self.path.clone();
+		let free = tokio::task::spawn_blocking(move || available_bytes(&path)).await;
+		match free {
+			Ok(Ok(free)) => self.classify(free),
+			Ok(Err(err)) => HealthCheckResult::unhealthy("disk", err.to_string()),
+			Err(err) => HealthCheckResult::unhealthy("disk", err.to_string()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reinhardt_utils::cache::InMemoryCache;
+	use reinhardt_utils::staticfiles::health::HealthStatus;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case::plenty(10_000, HealthStatus::Healthy)]
+	#[case::low(1_500, HealthStatus::Degraded)]
+	#[case::critical(500, HealthStatus::Unhealthy)]
+	fn test_disk_space_thresholds(#[case] free: u64, #[case] expected: HealthStatus) {
+		// Arrange
+		let probe = DiskSpaceProbe::new("/", 1_000);
+
+		// Act
+		let result = probe.classify(free);
+
+		// Assert
+		assert_eq!(result.status, expected);
+		assert_eq!(result.metadata["free_bytes"], free.to_string());
+	}
+
+	#[cfg(unix)]
+	#[rstest]
+	#[tokio::test]
+	async fn test_disk_space_probe_reads_filesystem() {
+		// Arrange
+		let probe = DiskSpaceProbe::new(std::env::temp_dir(), 0);
+
+		// Act
+		let result = probe.check().await;
+
+		// Assert
+		assert_ne!(result.status, HealthStatus::Unhealthy);
+		assert!(result.metadata.contains_key("free_bytes"));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_cache_probe_round_trip() {
+		// Arrange
+		let probe = CacheProbe::new(InMemoryCache::new());
+
+		// Act
+		let result = probe.check().await;
+
+		// Assert
+		assert_eq!(result.status, HealthStatus::Healthy);
+		assert!(result.metadata.contains_key("round_trip_ms"));
+	}
+
+	#[rstest]
+	#[case::fast(Duration::from_millis(5), HealthStatus::Healthy)]
+	#[case::slow(Duration::from_millis(900), HealthStatus::Degraded)]
+	fn test_latency_classification(#[case] elapsed: Duration, #[case] expected: HealthStatus) {
+		// Arrange / Act
+		let result = latency_result("database", elapsed, Duration::from_millis(500));
+
+		// Assert
+		assert_eq!(result.status, expected);
+	}
+}
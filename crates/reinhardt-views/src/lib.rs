@@ -9,10 +9,14 @@
 //! - **CreateView**: Handle object creation
 //! - **UpdateView**: Handle object updates
 //! - **DeleteView**: Handle object deletion
+//! - **FormView**: Display and process a form, rendered as JSON or through a template
 //! - **Browsable API**: HTML rendering for interactive API exploration
 //! - **Interactive Docs**: Swagger UI-like documentation interface
 //! - **Form Generation**: Automatic form generation for POST/PUT/PATCH methods
 //! - **Syntax Highlighting**: JSON response highlighting with customizable color schemes
+//! - **Health Endpoints**: `/healthz` and `/readyz` handlers backed by dependency probes
+//! - **Syndication Feeds**: RSS 2.0 and Atom feeds generated from a `Feed` implementation
+//! - **Sitemaps**: `sitemap.xml` generated from `Sitemap` implementations on the current site
 //!
 //! ## Example
 //!
@@ -54,21 +58,28 @@ pub mod viewsets;
 pub mod admin;
 #[cfg(feature = "browsable-api")]
 pub mod browsable_api;
+pub mod feeds;
 pub mod generic;
+pub mod health;
 #[cfg(feature = "openapi")]
 pub mod openapi;
 #[cfg(feature = "openapi")]
 pub mod openapi_inspector;
+pub mod sitemaps;
 
 // Module declarations
 mod core;
 mod detail;
+mod edit;
 mod list;
 mod mixins;
 
 // Re-export public API
 pub use core::{Context, View};
 pub use detail::DetailView;
+pub use edit::{
+	CreateView, DeleteView, FormHandler, FormView, RenderMode, TemplateRenderer, UpdateView,
+};
 pub use list::ListView;
 pub use mixins::{MultipleObjectMixin, SingleObjectMixin};
 
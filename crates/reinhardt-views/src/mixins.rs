@@ -1,10 +1,13 @@
 //! Mixins for common view patterns.
 
 use async_trait::async_trait;
-use reinhardt_core::exception::Result;
-use reinhardt_db::orm::Model;
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_db::orm::{
+	Model, QuerySet,
+	query::{Filter, FilterOperator, FilterValue},
+};
 use reinhardt_http::Request;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 use crate::core::Context;
@@ -92,3 +95,64 @@ where
 		Ok(context)
 	}
 }
+
+/// Looks up the object named by the `pk` or `slug` URL parameter
+///
+/// Shared by the single-object views; the parameter names and slug field
+/// come from `view`.
+pub(crate) async fn lookup_object<T, V>(
+	view: &V,
+	queryset: &QuerySet<T>,
+	request: &Request,
+) -> Result<T>
+where
+	T: Model + Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone + 'static,
+	V: SingleObjectMixin<T> + ?Sized,
+{
+	// Try to extract pk or slug from URL parameters
+	let pk_kwarg = view.pk_url_kwarg();
+	let slug_kwarg = view.slug_url_kwarg();
+
+	// Try to get pk from URL parameters
+	if let Some(pk_value) = request.path_params.get(pk_kwarg) {
+		let pk_field = T::primary_key_field();
+
+		// Try to parse as i64 first (most common primary key type)
+		let filter_value = if let Ok(int_pk) = pk_value.parse::<i64>() {
+			FilterValue::Integer(int_pk)
+		} else {
+			// Fallback to string if not a valid integer
+			FilterValue::String(pk_value.clone())
+		};
+
+		let results = queryset
+			.clone()
+			.filter(Filter::new(pk_field, FilterOperator::Eq, filter_value))
+			.all()
+			.await?;
+
+		return results
+			.into_iter()
+			.next()
+			.ok_or_else(|| Error::NotFound(format!("Object with pk='{}' not found", pk_value)));
+	}
+
+	// Try to get slug from URL parameters
+	if let Some(slug_value) = request.path_params.get(slug_kwarg) {
+		let results = queryset
+			.clone()
+			.filter(Filter::new(
+				view.get_slug_field(),
+				FilterOperator::Eq,
+				FilterValue::String(slug_value.clone()),
+			))
+			.all()
+			.await?;
+
+		return results.into_iter().next().ok_or_else(|| {
+			Error::NotFound(format!("Object with slug='{}' not found", slug_value))
+		});
+	}
+
+	Err(Error::NotFound("No pk or slug provided in URL".to_string()))
+}
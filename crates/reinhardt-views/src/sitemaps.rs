@@ -0,0 +1,328 @@
+//! XML sitemaps
+//!
+//! A [`Sitemap`] lists the pages of one section of a site, usually the
+//! detail pages of a QuerySet. [`SitemapHandler`] renders one or more
+//! sitemaps as a single `<urlset>` document following the
+//! [sitemaps.org protocol](https://www.sitemaps.org/protocol.html).
+//!
+//! Locations are paths; the handler makes them absolute on the current site
+//! (see [`reinhardt_http::site`]), so the same sitemap serves every domain of
+//! a multi-site deployment.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use reinhardt_views::sitemaps::{ChangeFrequency, Sitemap, SitemapHandler};
+//! use reinhardt_http::Result;
+//!
+//! struct ArticleSitemap;
+//!
+//! #[async_trait::async_trait]
+//! impl Sitemap for ArticleSitemap {
+//!     type Item = Article;
+//!
+//!     async fn items(&self) -> Result<Vec<Article>> {
+//!         Ok(Article::objects().all().all().await?)
+//!     }
+//!
+//!     fn location(&self, item: &Article) -> String {
+//!         format!("/articles/{}/", item.slug)
+//!     }
+//!
+//!     fn lastmod(&self, item: &Article) -> Option<DateTime<Utc>> {
+//!         Some(item.updated_at)
+//!     }
+//!
+//!     fn changefreq(&self, _item: &Article) -> Option<ChangeFrequency> {
+//!         Some(ChangeFrequency::Weekly)
+//!     }
+//! }
+//!
+//! let router = ServerRouter::new()
+//!     .handler("/sitemap.xml", SitemapHandler::new().with_sitemap(ArticleSitemap));
+//! ```
+
+use std::fmt::Write as _;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hyper::{Method, StatusCode};
+use quick_xml::escape::escape;
+use reinhardt_http::site::absolute_url;
+use reinhardt_http::{Handler, Request, Response, Result};
+
+/// `Content-Type` header value of sitemap responses
+pub const SITEMAP_CONTENT_TYPE: &str = "application/xml; charset=utf-8";
+
+/// How often a page is expected to change
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeFrequency {
+	/// Changes on every access
+	Always,
+	/// Hourly
+	Hourly,
+	/// Daily
+	Daily,
+	/// Weekly
+	Weekly,
+	/// Monthly
+	Monthly,
+	/// Yearly
+	Yearly,
+	/// Archived page that no longer changes
+	Never,
+}
+
+impl ChangeFrequency {
+	/// Value of the `<changefreq>` element
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			ChangeFrequency::Always => "always",
+			ChangeFrequency::Hourly => "hourly",
+			ChangeFrequency::Daily => "daily",
+			ChangeFrequency::Weekly => "weekly",
+			ChangeFrequency::Monthly => "monthly",
+			ChangeFrequency::Yearly => "yearly",
+			ChangeFrequency::Never => "never",
+		}
+	}
+}
+
+/// A section of a sitemap
+///
+/// Only the item source and the item location are required.
+#[async_trait]
+pub trait Sitemap: Send + Sync {
+	/// Objects listed by the sitemap
+	type Item: Send + Sync;
+
+	/// Items to list
+	async fn items(&self) -> Result<Vec<Self::Item>>;
+
+	/// Path (or absolute URL) of the item's page
+	fn location(&self, item: &Self::Item) -> String;
+
+	/// Last modification of the item
+	fn lastmod(&self, _item: &Self::Item) -> Option<DateTime<Utc>> {
+		None
+	}
+
+	/// Expected change frequency of the item
+	fn changefreq(&self, _item: &Self::Item) -> Option<ChangeFrequency> {
+		None
+	}
+
+	/// Priority of the item relative to other pages, between 0.0 and 1.0
+	fn priority(&self, _item: &Self::Item) -> Option<f32> {
+		None
+	}
+}
+
+/// A sitemap entry with all its fields resolved
+#[derive(Debug, Clone, PartialEq)]
+pub struct SitemapEntry {
+	/// Absolute URL of the page
+	pub location: String,
+	/// Last modification
+	pub lastmod: Option<DateTime<Utc>>,
+	/// Expected change frequency
+	pub changefreq: Option<ChangeFrequency>,
+	/// Relative priority
+	pub priority: Option<f32>,
+}
+
+/// Object-safe view of a [`Sitemap`], used to combine sections
+#[async_trait]
+trait SitemapSection: Send + Sync {
+	async fn entries(&self, request: &Request) -> Result<Vec<SitemapEntry>>;
+}
+
+#[async_trait]
+impl<S: Sitemap> SitemapSection for S {
+	async fn entries(&self, request: &Request) -> Result<Vec<SitemapEntry>> {
+		Ok(self
+			.items()
+			.await?
+			.iter()
+			.map(|item| SitemapEntry {
+				location: absolute_url(request, &self.location(item)),
+				lastmod: self.lastmod(item),
+				changefreq: self.changefreq(item),
+				priority: self.priority(item).map(|p| p.clamp(0.0, 1.0)),
+			})
+			.collect())
+	}
+}
+
+/// Render `entries` as a `<urlset>` document
+pub fn render_urlset(entries: &[SitemapEntry]) -> String {
+	let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+	xml.push_str(r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#);
+	for entry in entries {
+		xml.push_str("<url>");
+		let _ = write!(xml, "<loc>{}</loc>", escape(entry.location.as_str()));
+		if let Some(lastmod) = entry.lastmod {
+			let _ = write!(xml, "<lastmod>{}</lastmod>", lastmod.format("%Y-%m-%d"));
+		}
+		if let Some(changefreq) = entry.changefreq {
+			let _ = write!(xml, "<changefreq>{}</changefreq>", changefreq.as_str());
+		}
+		if let Some(priority) = entry.priority {
+			let _ = write!(xml, "<priority>{:.1}</priority>", priority);
+		}
+		xml.push_str("</url>");
+	}
+	xml.push_str("</urlset>");
+	xml
+}
+
+/// HTTP handler serving one or more [`Sitemap`]s as a single document
+#[derive(Default)]
+pub struct SitemapHandler {
+	sections: Vec<Box<dyn SitemapSection>>,
+}
+
+impl SitemapHandler {
+	/// Create a handler without sections
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Append the entries of `sitemap`
+	pub fn with_sitemap<S: Sitemap + 'static>(mut self, sitemap: S) -> Self {
+		self.sections.push(Box::new(sitemap));
+		self
+	}
+
+	/// Entries of all sections for `request`, in registration order
+	pub async fn entries(&self, request: &Request) -> Result<Vec<SitemapEntry>> {
+		let mut entries = Vec::new();
+		for section in &self.sections {
+			entries.extend(section.entries(request).await?);
+		}
+		Ok(entries)
+	}
+}
+
+#[async_trait]
+impl Handler for SitemapHandler {
+	async fn handle(&self, request: Request) -> Result<Response> {
+		if request.method != Method::GET && request.method != Method::HEAD {
+			return Ok(
+				Response::new(StatusCode::METHOD_NOT_ALLOWED).with_header("Allow", "GET, HEAD")
+			);
+		}
+
+		let entries = self.entries(&request).await?;
+		let mut response = Response::ok().with_header("Content-Type", SITEMAP_CONTENT_TYPE);
+		if request.method == Method::GET {
+			response = response.with_body(render_urlset(&entries));
+		}
+		Ok(response)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chrono::TimeZone;
+	use reinhardt_http::Site;
+	use rstest::rstest;
+
+	struct Pages;
+
+	#[async_trait]
+	impl Sitemap for Pages {
+		type Item = (&'static str, DateTime<Utc>);
+
+		async fn items(&self) -> Result<Vec<Self::Item>> {
+			Ok(vec![
+				("/", Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap()),
+				(
+					"/search/?q=a&b",
+					Utc.with_ymd_and_hms(2024, 5, 2, 12, 0, 0).unwrap(),
+				),
+			])
+		}
+
+		fn location(&self, item: &Self::Item) -> String {
+			item.0.to_string()
+		}
+
+		fn lastmod(&self, item: &Self::Item) -> Option<DateTime<Utc>> {
+			Some(item.1)
+		}
+
+		fn changefreq(&self, _item: &Self::Item) -> Option<ChangeFrequency> {
+			Some(ChangeFrequency::Daily)
+		}
+
+		fn priority(&self, _item: &Self::Item) -> Option<f32> {
+			Some(1.5)
+		}
+	}
+
+	fn request(method: Method) -> Request {
+		Request::builder()
+			.method(method)
+			.uri("/sitemap.xml")
+			.header("host", "fallback.test")
+			.build()
+			.unwrap()
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_handler_renders_urlset_on_current_site() {
+		// Arrange
+		let handler = SitemapHandler::new().with_sitemap(Pages);
+		let request = request(Method::GET);
+		request.extensions.insert(Site::new(
+			1,
+			"example.com".to_string(),
+			"Example".to_string(),
+		));
+
+		// Act
+		let response = handler.handle(request).await.unwrap();
+
+		// Assert
+		let xml = String::from_utf8(response.body.to_vec()).unwrap();
+		assert_eq!(response.headers["content-type"], SITEMAP_CONTENT_TYPE);
+		assert!(xml.contains(
+			"<url><loc>http://example.com/</loc><lastmod>2024-05-01</lastmod>\
+			 <changefreq>daily</changefreq><priority>1.0</priority></url>"
+		));
+		assert!(xml.contains("<loc>http://example.com/search/?q=a&amp;b</loc>"));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_entries_fall_back_to_host_header() {
+		// Arrange
+		let handler = SitemapHandler::new().with_sitemap(Pages);
+
+		// Act
+		let entries = handler.entries(&request(Method::GET)).await.unwrap();
+
+		// Assert
+		assert_eq!(entries.len(), 2);
+		assert_eq!(entries[0].location, "http://fallback.test/");
+	}
+
+	#[rstest]
+	#[case::head(Method::HEAD, StatusCode::OK)]
+	#[case::post(Method::POST, StatusCode::METHOD_NOT_ALLOWED)]
+	#[tokio::test]
+	async fn test_handler_without_body(#[case] method: Method, #[case] expected: StatusCode) {
+		// Arrange
+		let handler = SitemapHandler::new().with_sitemap(Pages);
+
+		// Act
+		let response = handler.handle(request(method)).await.unwrap();
+
+		// Assert
+		assert_eq!(response.status, expected);
+		assert!(response.body.is_empty());
+	}
+}
@@ -14,6 +14,10 @@
 //! - **WebSocket Routing**: URL-based WebSocket endpoint registration
 //! - **Channel Layers**: Distributed messaging for multi-instance deployments
 //! - **Consumer Classes**: Django Channels-inspired message handling patterns
+//! - **Server-Sent Events**: Topic-based SSE broadcast hub over channel layers (`sse` feature)
+//! - **Notifications**: SSE delivery of in-app notifications (`notifications` feature)
+//! - **Presence**: Cache-backed room membership with heartbeats and join/leave events (`presence` feature)
+//! - **Long Polling**: HTTP fallback transport with resumable sessions for networks that block WebSockets (`long-polling` feature)
 //!
 //! ## Basic Usage
 //!
@@ -163,12 +167,21 @@ pub mod handler;
 /// Integration with reinhardt-pages for cookie/session-based auth.
 #[cfg(feature = "pages-integration")]
 pub mod integration;
+/// Long-polling fallback transport with resumable sessions.
+#[cfg(feature = "long-polling")]
+pub mod long_polling;
 /// WebSocket connection and message metrics.
 pub mod metrics;
 /// WebSocket middleware for pre/post-processing.
 pub mod middleware;
+/// SSE delivery of in-app notifications.
+#[cfg(feature = "notifications")]
+pub mod notifications;
 /// Origin validation for WebSocket handshake requests.
 pub mod origin;
+/// Room presence tracking with heartbeat expiry.
+#[cfg(feature = "presence")]
+pub mod presence;
 /// WebSocket protocol frame handling.
 pub mod protocol;
 /// Automatic reconnection with exponential backoff.
@@ -182,6 +195,9 @@ pub mod room;
 pub mod routing;
 /// Settings-first configuration fragments.
 pub mod settings;
+/// Server-Sent Events broadcast hub backed by channel layers.
+#[cfg(feature = "sse")]
+pub mod sse;
 /// Connection and message rate limiting.
 pub mod throttling;
 
@@ -212,6 +228,8 @@ pub use endpoint::{WebSocketEndpointInfo, WebSocketEndpointMetadata, substitute_
 pub use handler::WebSocketHandler;
 #[cfg(feature = "pages-integration")]
 pub use integration::pages::{PagesAuthUser, PagesAuthenticator};
+#[cfg(feature = "long-polling")]
+pub use long_polling::{LongPollConfig, LongPollHub, PollBatch, Transport};
 #[cfg(feature = "metrics")]
 pub use metrics::MetricsExporter;
 pub use metrics::{MetricsCollector, MetricsSnapshot, PeriodicReporter, WebSocketMetrics};
@@ -220,10 +238,19 @@ pub use middleware::{
 	MessageMiddleware, MessageSizeLimitMiddleware, MiddlewareChain, MiddlewareError,
 	MiddlewareResult,
 };
+#[cfg(feature = "notifications")]
+pub use notifications::{
+	NOTIFICATION_EVENT, SseNotificationChannel, notification_topic, subscribe_notifications,
+};
 #[allow(deprecated)]
 // `OriginValidationConfig` is deprecated in favor of `OriginValidationSettings`.
 pub use origin::OriginValidationConfig;
 pub use origin::{OriginPolicy, OriginValidationMiddleware, validate_origin};
+#[cfg(feature = "presence")]
+pub use presence::{
+	PRESENCE_EVENT, PresenceChange, PresenceConfig, PresenceEvent, PresenceMember,
+	PresenceSession, PresenceTracker, presence_topic,
+};
 pub use protocol::{
 	DEFAULT_MAX_FRAME_SIZE, DEFAULT_MAX_MESSAGE_SIZE, default_websocket_config,
 	websocket_config_with_limits,
@@ -249,6 +276,8 @@ pub use settings::{
 };
 #[cfg(feature = "redis-channel")]
 pub use settings::{RedisChannelSettings, create_redis_config_from_settings};
+#[cfg(feature = "sse")]
+pub use sse::{SseBroadcastHub, SseHubConfig, SseSubscription};
 #[allow(deprecated)]
 // `WebSocketRateLimitConfig` is deprecated in favor of `RateLimitSettings`.
 pub use throttling::WebSocketRateLimitConfig;
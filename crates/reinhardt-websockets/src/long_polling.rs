@@ -0,0 +1,631 @@
+//! Long-polling fallback transport
+//!
+//! Some proxies and corporate networks block WebSocket upgrades.
+//! [`LongPollHub`] serves the same [`WebSocketConsumer`] over plain HTTP
+//! requests instead: each client session gets a [`WebSocketConnection`] and a
+//! channel in the [`ChannelLayer`], exactly like a WebSocket connection, so
+//! consumers, group broadcasts and SSE hub topics reach it unchanged. Messages sent to the session are buffered until the
+//! client's next poll.
+//!
+//! Sessions are identified by a resumption token. Every poll acknowledges the
+//! messages received so far with the cursor of the previous batch; unacknowledged
+//! messages are delivered again, so a poll response dropped by a proxy loses
+//! nothing. A client keeps its token across failed requests and only opens a
+//! new session once the old one expired after [`LongPollConfig::session_ttl`].
+//!
+//! ## Protocol
+//!
+//! The endpoint is served by a view of the application, usually next to the
+//! WebSocket route:
+//!
+//! | Request                                    | Hub method              | Response              |
+//! |--------------------------------------------|-------------------------|-----------------------|
+//! | `POST {url}`                               | [`LongPollHub::open`]   | `{"token": "..."}`    |
+//! | `GET {url}?token=T&ack=N`                  | [`LongPollHub::poll`]   | [`PollBatch`] as JSON |
+//! | `POST {url}?token=T` with [`Message`] JSON | [`LongPollHub::send`]   | `204 No Content`      |
+//! | `DELETE {url}?token=T`                     | [`LongPollHub::close`]  | `204 No Content`      |
+//!
+//! Requests with an unknown or expired token should be answered with
+//! `410 Gone`, which makes the `use_websocket` hook of reinhardt-pages open a
+//! new session. [`Transport::negotiate`] picks the transport from the list the
+//! client offers.
+//!
+//! ## Example
+//!
+//! ```
+//! use reinhardt_websockets::channels::InMemoryChannelLayer;
+//! use reinhardt_websockets::consumers::EchoConsumer;
+//! use reinhardt_websockets::long_polling::LongPollHub;
+//! use reinhardt_websockets::Message;
+//! use std::collections::HashMap;
+//! use std::sync::Arc;
+//!
+//! # tokio_test::block_on(async {
+//! let hub = LongPollHub::new(
+//!     Arc::new(InMemoryChannelLayer::new()),
+//!     Arc::new(EchoConsumer::new()),
+//! );
+//!
+//! let token = hub.open(HashMap::new()).await.unwrap();
+//! hub.send(&token, Message::text("hi".to_string())).await.unwrap();
+//!
+//! let batch = hub.poll(&token, None).await.unwrap();
+//! assert_eq!(
+//!     batch.messages,
+//!     vec![
+//!         Message::text("Echo: Connection established".to_string()),
+//!         Message::text("Echo: hi".to_string()),
+//!     ]
+//! );
+//! # });
+//! ```
+
+use crate::channels::{ChannelError, ChannelLayer, ChannelResult};
+use crate::connection::{Message, WebSocketConnection};
+use crate::consumers::{ConsumerContext, WebSocketConsumer};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, RwLock, mpsc};
+use tokio::time::Instant;
+
+/// Transport used between a client and the realtime layer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Transport {
+	/// A WebSocket connection.
+	WebSocket,
+	/// HTTP long polling through a [`LongPollHub`].
+	LongPolling,
+}
+
+impl Transport {
+	/// Name of the transport on the wire (`websocket` or `long-polling`)
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::WebSocket => "websocket",
+			Self::LongPolling => "long-polling",
+		}
+	}
+
+	/// Pick the transport for a client
+	///
+	/// `offered` is the comma-separated list of transports the client
+	/// supports, in order of preference (e.g. `websocket,long-polling`).
+	/// Returns the first one the server can serve; WebSocket is skipped when
+	/// `websocket_available` is `false`, e.g. because the request came
+	/// through a proxy that strips the `Upgrade` header.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use reinhardt_websockets::long_polling::Transport;
+	///
+	/// assert_eq!(
+	///     Transport::negotiate("websocket,long-polling", false),
+	///     Some(Transport::LongPolling)
+	/// );
+	/// assert_eq!(Transport::negotiate("carrier-pigeon", true), None);
+	/// ```
+	pub fn negotiate(offered: &str, websocket_available: bool) -> Option<Self> {
+		offered
+			.split(',')
+			.filter_map(|name| name.trim().parse().ok())
+			.find(|transport| *transport != Self::WebSocket || websocket_available)
+	}
+}
+
+impl fmt::Display for Transport {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl FromStr for Transport {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"websocket" => Ok(Self::WebSocket),
+			"long-polling" => Ok(Self::LongPolling),
+			other => Err(format!("Unknown transport: {}", other)),
+		}
+	}
+}
+
+/// Configuration for [`LongPollHub`]
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_websockets::long_polling::LongPollConfig;
+/// use std::time::Duration;
+///
+/// let config = LongPollConfig::default()
+///     .with_poll_timeout(Duration::from_secs(20))
+///     .with_max_batch(50);
+///
+/// assert_eq!(config.max_batch, 50);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LongPollConfig {
+	/// How long a poll waits for messages before returning an empty batch.
+	///
+	/// Keep it below the idle timeout of the proxies in front of the server.
+	pub poll_timeout: Duration,
+	/// How long a session survives without any request before it expires.
+	pub session_ttl: Duration,
+	/// Delay between channel layer polls while a poll is waiting.
+	pub poll_interval: Duration,
+	/// Maximum number of messages returned by a single poll.
+	pub max_batch: usize,
+	/// Maximum number of unacknowledged messages kept per session.
+	///
+	/// The oldest messages are dropped when a client falls further behind.
+	pub buffer_size: usize,
+	/// Prefix used to build channel layer channel names for sessions.
+	pub channel_prefix: String,
+}
+
+impl Default for LongPollConfig {
+	fn default() -> Self {
+		Self {
+			poll_timeout: Duration::from_secs(25),
+			session_ttl: Duration::from_secs(60),
+			poll_interval: Duration::from_millis(50),
+			max_batch: 100,
+			buffer_size: 1000,
+			channel_prefix: "longpoll".to_string(),
+		}
+	}
+}
+
+impl LongPollConfig {
+	/// Set the poll timeout
+	pub fn with_poll_timeout(mut self, poll_timeout: Duration) -> Self {
+		self.poll_timeout = poll_timeout;
+		self
+	}
+
+	/// Set the idle session lifetime
+	pub fn with_session_ttl(mut self, session_ttl: Duration) -> Self {
+		self.session_ttl = session_ttl;
+		self
+	}
+
+	/// Set the channel layer poll interval
+	pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+		self.poll_interval = poll_interval;
+		self
+	}
+
+	/// Set the maximum batch size
+	pub fn with_max_batch(mut self, max_batch: usize) -> Self {
+		self.max_batch = max_batch;
+		self
+	}
+
+	/// Set the per-session buffer size
+	pub fn with_buffer_size(mut self, buffer_size: usize) -> Self {
+		self.buffer_size = buffer_size;
+		self
+	}
+
+	/// Set the channel name prefix
+	pub fn with_channel_prefix(mut self, channel_prefix: impl Into<String>) -> Self {
+		self.channel_prefix = channel_prefix.into();
+		self
+	}
+}
+
+/// Messages returned by a poll
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PollBatch {
+	/// Resumption token of the session.
+	pub token: String,
+	/// Cursor to acknowledge this batch with in the next poll.
+	pub cursor: u64,
+	/// Messages in delivery order; empty when the poll timed out.
+	pub messages: Vec<Message>,
+}
+
+struct LongPollSession {
+	context: ConsumerContext,
+	channel: String,
+	groups: HashSet<String>,
+	outbox: mpsc::UnboundedReceiver<Message>,
+	pending: VecDeque<(u64, Message)>,
+	next_seq: u64,
+	last_seen: Instant,
+}
+
+impl LongPollSession {
+	fn push(&mut self, message: Message, buffer_size: usize) {
+		self.next_seq += 1;
+		self.pending.push_back((self.next_seq, message));
+		while self.pending.len() > buffer_size {
+			self.pending.pop_front();
+		}
+	}
+
+	fn acknowledge(&mut self, ack: u64) {
+		while self.pending.front().is_some_and(|(seq, _)| *seq <= ack) {
+			self.pending.pop_front();
+		}
+	}
+}
+
+/// Long-polling sessions served by a [`WebSocketConsumer`]
+pub struct LongPollHub {
+	layer: Arc<dyn ChannelLayer>,
+	consumer: Arc<dyn WebSocketConsumer>,
+	config: LongPollConfig,
+	sessions: RwLock<HashMap<String, Arc<Mutex<LongPollSession>>>>,
+}
+
+impl LongPollHub {
+	/// Create a hub with the default configuration
+	pub fn new(layer: Arc<dyn ChannelLayer>, consumer: Arc<dyn WebSocketConsumer>) -> Self {
+		Self::with_config(layer, consumer, LongPollConfig::default())
+	}
+
+	/// Create a hub with a custom configuration
+	pub fn with_config(
+		layer: Arc<dyn ChannelLayer>,
+		consumer: Arc<dyn WebSocketConsumer>,
+		config: LongPollConfig,
+	) -> Self {
+		Self {
+			layer,
+			consumer,
+			config,
+			sessions: RwLock::new(HashMap::new()),
+		}
+	}
+
+	/// Get the hub configuration
+	pub fn config(&self) -> &LongPollConfig {
+		&self.config
+	}
+
+	async fn session(&self, token: &str) -> ChannelResult<Arc<Mutex<LongPollSession>>> {
+		self.sessions
+			.read()
+			.await
+			.get(token)
+			.cloned()
+			.ok_or_else(|| ChannelError::ChannelNotFound(token.to_string()))
+	}
+
+	/// Open a session and run the consumer's `on_connect`
+	///
+	/// `headers` are the headers of the opening request, exposed to the
+	/// consumer as [`ConsumerContext::headers`] for cookie or token
+	/// authentication. Returns the session's resumption token. Idle sessions
+	/// are expired first.
+	pub async fn open(&self, headers: HashMap<String, String>) -> ChannelResult<String> {
+		self.expire_idle().await;
+
+		let token = uuid::Uuid::new_v4().simple().to_string();
+		let (tx, outbox) = mpsc::unbounded_channel();
+		let connection = Arc::new(WebSocketConnection::new(token.clone(), tx));
+		let mut context = ConsumerContext::new(connection);
+		context.headers = headers;
+		self.consumer
+			.on_connect(&mut context)
+			.await
+			.map_err(|e| ChannelError::SendError(e.to_string()))?;
+
+		let session = LongPollSession {
+			context,
+			channel: format!("{}!{}", self.config.channel_prefix, token),
+			groups: HashSet::new(),
+			outbox,
+			pending: VecDeque::new(),
+			next_seq: 0,
+			last_seen: Instant::now(),
+		};
+		self.sessions
+			.write()
+			.await
+			.insert(token.clone(), Arc::new(Mutex::new(session)));
+		Ok(token)
+	}
+
+	/// Whether `token` still identifies a live session
+	///
+	/// A successful check keeps the session alive.
+	pub async fn resume(&self, token: &str) -> bool {
+		match self.session(token).await {
+			Ok(session) => {
+				session.lock().await.last_seen = Instant::now();
+				true
+			}
+			Err(_) => false,
+		}
+	}
+
+	/// Channel layer channel of the session, for direct `ChannelLayer::send`
+	pub async fn channel_name(&self, token: &str) -> ChannelResult<String> {
+		Ok(self.session(token).await?.lock().await.channel.clone())
+	}
+
+	/// Add the session to a channel layer group
+	pub async fn group_add(&self, token: &str, group: &str) -> ChannelResult<()> {
+		let session = self.session(token).await?;
+		let mut session = session.lock().await;
+		self.layer.group_add(group, &session.channel).await?;
+		session.groups.insert(group.to_string());
+		Ok(())
+	}
+
+	/// Remove the session from a channel layer group
+	pub async fn group_discard(&self, token: &str, group: &str) -> ChannelResult<()> {
+		let session = self.session(token).await?;
+		let mut session = session.lock().await;
+		self.layer.group_discard(group, &session.channel).await?;
+		session.groups.remove(group);
+		Ok(())
+	}
+
+	/// Deliver a message from the client to the consumer's `on_message`
+	pub async fn send(&self, token: &str, message: Message) -> ChannelResult<()> {
+		let session = self.session(token).await?;
+		let mut session = session.lock().await;
+		session.last_seen = Instant::now();
+		self.consumer
+			.on_message(&mut session.context, message)
+			.await
+			.map_err(|e| ChannelError::SendError(e.to_string()))
+	}
+
+	/// Wait for the messages of a session
+	///
+	/// `ack` is the cursor of the last batch the client received; the
+	/// messages up to it are discarded and everything after it is returned,
+	/// including messages of an earlier batch that never reached the client.
+	/// Returns an empty batch when nothing arrives within
+	/// [`LongPollConfig::poll_timeout`].
+	pub async fn poll(&self, token: &str, ack: Option<u64>) -> ChannelResult<PollBatch> {
+		let session = self.session(token).await?;
+		let deadline = Instant::now() + self.config.poll_timeout;
+		loop {
+			{
+				let mut session = session.lock().await;
+				session.last_seen = Instant::now();
+				if let Some(ack) = ack {
+					session.acknowledge(ack);
+				}
+				self.collect(&mut session).await?;
+				if !session.pending.is_empty() || Instant::now() >= deadline {
+					let batch: Vec<(u64, Message)> = session
+						.pending
+						.iter()
+						.take(self.config.max_batch)
+						.cloned()
+						.collect();
+					let cursor = batch.last().map_or(session.next_seq, |(seq, _)| *seq);
+					return Ok(PollBatch {
+						token: token.to_string(),
+						cursor,
+						messages: batch.into_iter().map(|(_, message)| message).collect(),
+					});
+				}
+			}
+			tokio::time::sleep(self.config.poll_interval).await;
+		}
+	}
+
+	/// Move the messages sent to the session into its buffer
+	async fn collect(&self, session: &mut LongPollSession) -> ChannelResult<()> {
+		while let Ok(message) = session.outbox.try_recv() {
+			session.push(message, self.config.buffer_size);
+		}
+		while let Some(message) = self.layer.receive(&session.channel).await? {
+			session.push(message.payload().clone(), self.config.buffer_size);
+		}
+		Ok(())
+	}
+
+	/// Close a session and run the consumer's `on_disconnect`
+	pub async fn close(&self, token: &str) -> ChannelResult<()> {
+		let session = self
+			.sessions
+			.write()
+			.await
+			.remove(token)
+			.ok_or_else(|| ChannelError::ChannelNotFound(token.to_string()))?;
+		self.teardown(&session).await;
+		Ok(())
+	}
+
+	async fn teardown(&self, session: &Mutex<LongPollSession>) {
+		let mut session = session.lock().await;
+		for group in std::mem::take(&mut session.groups) {
+			if let Err(e) = self.layer.group_discard(&group, &session.channel).await {
+				tracing::debug!(%group, channel = %session.channel, error = %e, "Failed to discard long-poll session");
+			}
+		}
+		if let Err(e) = self.consumer.on_disconnect(&mut session.context).await {
+			tracing::warn!(channel = %session.channel, error = %e, "Long-poll consumer disconnect failed");
+		}
+	}
+
+	/// Close every session idle for longer than [`LongPollConfig::session_ttl`]
+	///
+	/// Returns the number of expired sessions. Sessions in the middle of a
+	/// poll are never idle.
+	pub async fn expire_idle(&self) -> usize {
+		let expired: Vec<Arc<Mutex<LongPollSession>>> = {
+			let mut sessions = self.sessions.write().await;
+			let mut idle = Vec::new();
+			for (token, session) in sessions.iter() {
+				// A locked session is being polled.
+				if let Ok(guard) = session.try_lock()
+					&& guard.last_seen.elapsed() > self.config.session_ttl
+				{
+					idle.push(token.clone());
+				}
+			}
+			idle.iter()
+				.filter_map(|token| sessions.remove(token))
+				.collect()
+		};
+		for session in &expired {
+			self.teardown(session).await;
+		}
+		expired.len()
+	}
+
+	/// Number of open sessions
+	pub async fn session_count(&self) -> usize {
+		self.sessions.read().await.len()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::channels::{ChannelMessage, InMemoryChannelLayer};
+	use crate::consumers::EchoConsumer;
+	use rstest::rstest;
+
+	fn hub() -> (Arc<InMemoryChannelLayer>, LongPollHub) {
+		let layer = Arc::new(InMemoryChannelLayer::new());
+		let config = LongPollConfig::default()
+			.with_poll_timeout(Duration::from_millis(20))
+			.with_poll_interval(Duration::from_millis(1));
+		let hub = LongPollHub::with_config(layer.clone(), Arc::new(EchoConsumer::new()), config);
+		(layer, hub)
+	}
+
+	#[rstest]
+	#[case("websocket,long-polling", true, Some(Transport::WebSocket))]
+	#[case("websocket, long-polling", false, Some(Transport::LongPolling))]
+	#[case("websocket", false, None)]
+	#[case("", true, None)]
+	fn test_negotiate(
+		#[case] offered: &str,
+		#[case] websocket_available: bool,
+		#[case] expected: Option<Transport>,
+	) {
+		// Act
+		let transport = Transport::negotiate(offered, websocket_available);
+
+		// Assert
+		assert_eq!(transport, expected);
+	}
+
+	#[tokio::test]
+	async fn test_consumer_replies_are_polled() {
+		// Arrange
+		let (_, hub) = hub();
+		let token = hub.open(HashMap::new()).await.unwrap();
+
+		// Act
+		hub.send(&token, Message::text("a".to_string()))
+			.await
+			.unwrap();
+		let batch = hub.poll(&token, None).await.unwrap();
+
+		// Assert
+		assert_eq!(batch.token, token);
+		assert_eq!(batch.cursor, 2);
+		assert_eq!(
+			batch.messages,
+			vec![
+				Message::text("Echo: Connection established".to_string()),
+				Message::text("Echo: a".to_string()),
+			]
+		);
+	}
+
+	#[tokio::test]
+	async fn test_group_messages_reach_session() {
+		// Arrange
+		let (layer, hub) = hub();
+		let token = hub.open(HashMap::new()).await.unwrap();
+		hub.group_add(&token, "room").await.unwrap();
+
+		// Act
+		layer
+			.group_send(
+				"room",
+				ChannelMessage::new("server".to_string(), Message::text("news".to_string())),
+			)
+			.await
+			.unwrap();
+		let batch = hub.poll(&token, None).await.unwrap();
+
+		// Assert
+		assert_eq!(
+			batch.messages.last(),
+			Some(&Message::text("news".to_string()))
+		);
+	}
+
+	#[tokio::test]
+	async fn test_unacknowledged_messages_are_redelivered() {
+		// Arrange
+		let (_, hub) = hub();
+		let token = hub.open(HashMap::new()).await.unwrap();
+		hub.send(&token, Message::text("a".to_string()))
+			.await
+			.unwrap();
+		let lost = hub.poll(&token, None).await.unwrap();
+		hub.send(&token, Message::text("b".to_string()))
+			.await
+			.unwrap();
+
+		// Act
+		let retried = hub.poll(&token, None).await.unwrap();
+		let acknowledged = hub.poll(&token, Some(lost.cursor)).await.unwrap();
+
+		// Assert
+		assert_eq!(retried.messages.len(), 3);
+		assert_eq!(
+			acknowledged.messages,
+			vec![Message::text("Echo: b".to_string())]
+		);
+		assert_eq!(acknowledged.cursor, 3);
+	}
+
+	#[tokio::test]
+	async fn test_poll_times_out_with_empty_batch() {
+		// Arrange
+		let (_, hub) = hub();
+		let token = hub.open(HashMap::new()).await.unwrap();
+		let greeting = hub.poll(&token, None).await.unwrap();
+
+		// Act
+		let batch = hub.poll(&token, Some(greeting.cursor)).await.unwrap();
+
+		// Assert
+		assert!(batch.messages.is_empty());
+		assert_eq!(batch.cursor, 1);
+	}
+
+	#[tokio::test]
+	async fn test_idle_sessions_expire() {
+		// Arrange
+		let layer = Arc::new(InMemoryChannelLayer::new());
+		let config = LongPollConfig::default().with_session_ttl(Duration::ZERO);
+		let hub = LongPollHub::with_config(layer, Arc::new(EchoConsumer::new()), config);
+		let token = hub.open(HashMap::new()).await.unwrap();
+		tokio::time::sleep(Duration::from_millis(2)).await;
+
+		// Act
+		let expired = hub.expire_idle().await;
+
+		// Assert
+		assert_eq!(expired, 1);
+		assert!(!hub.resume(&token).await);
+		assert!(matches!(
+			hub.poll(&token, None).await,
+			Err(ChannelError::ChannelNotFound(_))
+		));
+	}
+}
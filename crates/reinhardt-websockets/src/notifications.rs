@@ -0,0 +1,119 @@
+//! Live delivery of in-app notifications over Server-Sent Events
+//!
+//! [`SseNotificationChannel`] publishes every notification emitted with
+//! `reinhardt_db::orm::notifications::notify` to the topic of its recipient
+//! on an [`SseBroadcastHub`]. Each event is named [`NOTIFICATION_EVENT`],
+//! uses the notification id as event id and carries the notification as
+//! JSON, which is what the `NotificationCenter` of reinhardt-pages expects.
+//!
+//! ## Example
+//!
+//! ```
+//! use reinhardt_db::orm::notifications::add_notification_channel;
+//! use reinhardt_websockets::channels::InMemoryChannelLayer;
+//! use reinhardt_websockets::notifications::{SseNotificationChannel, subscribe_notifications};
+//! use reinhardt_websockets::sse::SseBroadcastHub;
+//! use reinhardt_http::sse::SseResponse;
+//! use std::sync::Arc;
+//!
+//! # tokio_test::block_on(async {
+//! let hub = Arc::new(SseBroadcastHub::new(Arc::new(InMemoryChannelLayer::new())));
+//! add_notification_channel(Arc::new(SseNotificationChannel::new(hub.clone())));
+//!
+//! // In the SSE view of the authenticated user "42":
+//! let subscription = subscribe_notifications(&hub, "42", None).await.unwrap();
+//! let response = SseResponse::new(subscription.into_stream()).into_streaming_response();
+//! # let _ = response;
+//! # });
+//! ```
+
+use crate::channels::ChannelResult;
+use crate::sse::{SseBroadcastHub, SseSubscription};
+use async_trait::async_trait;
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_db::orm::notifications::{Notification, NotificationChannel};
+use reinhardt_http::sse::SseEvent;
+use std::sync::Arc;
+
+/// Event name of notification events.
+pub const NOTIFICATION_EVENT: &str = "notification";
+
+/// Hub topic of the notifications of `recipient`.
+pub fn notification_topic(recipient: &str) -> String {
+	format!("notifications.{}", recipient)
+}
+
+/// Subscribe to the notifications of `recipient`
+///
+/// Pass the `Last-Event-ID` of a reconnecting client as `last_event_id` to
+/// replay the notifications it missed.
+pub async fn subscribe_notifications(
+	hub: &Arc<SseBroadcastHub>,
+	recipient: &str,
+	last_event_id: Option<&str>,
+) -> ChannelResult<SseSubscription> {
+	hub.subscribe(&notification_topic(recipient), last_event_id)
+		.await
+}
+
+/// Notification channel publishing to an [`SseBroadcastHub`]
+pub struct SseNotificationChannel {
+	hub: Arc<SseBroadcastHub>,
+}
+
+impl SseNotificationChannel {
+	/// Create a channel publishing to `hub`
+	pub fn new(hub: Arc<SseBroadcastHub>) -> Self {
+		Self { hub }
+	}
+}
+
+#[async_trait]
+impl NotificationChannel for SseNotificationChannel {
+	async fn deliver(&self, notification: &Notification) -> Result<()> {
+		let event = SseEvent::json(notification)?
+			.event(NOTIFICATION_EVENT)
+			.id(notification.id.to_string());
+		self.hub
+			.publish(&notification_topic(&notification.recipient), event)
+			.await
+			.map(|_| ())
+			.map_err(|e| Error::Internal(e.to_string()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::channels::InMemoryChannelLayer;
+	use crate::sse::SseHubConfig;
+	use std::time::Duration;
+
+	fn hub() -> Arc<SseBroadcastHub> {
+		let config = SseHubConfig::default().with_poll_interval(Duration::from_millis(1));
+		Arc::new(SseBroadcastHub::with_config(
+			Arc::new(InMemoryChannelLayer::new()),
+			config,
+		))
+	}
+
+	#[tokio::test]
+	async fn test_deliver_publishes_to_recipient_topic() {
+		// Arrange
+		let hub = hub();
+		let channel = SseNotificationChannel::new(hub.clone());
+		let mut recipient = subscribe_notifications(&hub, "42", None).await.unwrap();
+		let notification = Notification::new("42", "Export ready");
+
+		// Act
+		channel.deliver(&notification).await.unwrap();
+
+		// Assert
+		let event = recipient.recv().await.unwrap();
+		assert_eq!(event.event.as_deref(), Some(NOTIFICATION_EVENT));
+		assert_eq!(event.id, Some(notification.id.to_string()));
+		let received: Notification = serde_json::from_str(&event.data).unwrap();
+		assert_eq!(received, notification);
+		assert_eq!(hub.history_len(&notification_topic("7")).await, 0);
+	}
+}
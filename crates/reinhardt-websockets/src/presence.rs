@@ -0,0 +1,598 @@
+//! Presence tracking for rooms and topics
+//!
+//! [`PresenceTracker`] records which users are connected to each room in a
+//! [`Cache`]. Every member has its own entry, which expires after
+//! [`PresenceConfig::ttl`] unless a heartbeat refreshes it, and each room
+//! keeps an index of its member ids. Joins and leaves are published as
+//! [`PRESENCE_EVENT`] events on an [`SseBroadcastHub`] topic. Each event
+//! carries the member list after the change, which is what `use_presence` of
+//! reinhardt-pages expects.
+//!
+//! [`PresenceTracker::connect`] ties presence to an SSE response: the user
+//! joins when the stream opens, heartbeats while it stays open and leaves
+//! when the client disconnects. Members whose process died without leaving
+//! expire and are announced as leaving by the next [`PresenceTracker::members`]
+//! call.
+//!
+//! The room index is updated with a read-modify-write, so two instances
+//! joining the same room at the same instant may drop one id from the index
+//! until that member's next heartbeat.
+//!
+//! ## Example
+//!
+//! ```
+//! use reinhardt_utils::cache::InMemoryCache;
+//! use reinhardt_websockets::channels::InMemoryChannelLayer;
+//! use reinhardt_websockets::presence::PresenceTracker;
+//! use reinhardt_websockets::sse::SseBroadcastHub;
+//! use reinhardt_http::sse::SseResponse;
+//! use std::sync::Arc;
+//!
+//! # tokio_test::block_on(async {
+//! let hub = Arc::new(SseBroadcastHub::new(Arc::new(InMemoryChannelLayer::new())));
+//! let tracker = Arc::new(PresenceTracker::new(Arc::new(InMemoryCache::new()), hub));
+//!
+//! // In the SSE view of room "lobby" for the authenticated user "42":
+//! let session = tracker
+//!     .connect("lobby", "42", serde_json::json!({"name": "Alice"}))
+//!     .await
+//!     .unwrap();
+//! let response = SseResponse::new(session.into_stream()).into_streaming_response();
+//! # let _ = response;
+//! # });
+//! ```
+
+use crate::sse::{SseBroadcastHub, SseSubscription};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{self, Stream};
+use reinhardt_core::exception::{Error, Result};
+use reinhardt_http::sse::SseEvent;
+use reinhardt_utils::cache::Cache;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Event name of presence events.
+pub const PRESENCE_EVENT: &str = "presence";
+
+/// Hub topic of the presence events of `room`.
+pub fn presence_topic(room: &str) -> String {
+	format!("presence.{}", room)
+}
+
+/// A user connected to a room
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PresenceMember {
+	/// Id of the user
+	pub user_id: String,
+	/// Data shown next to the user, such as a display name
+	#[serde(default)]
+	pub metadata: JsonValue,
+	/// When the user joined
+	pub joined_at: DateTime<Utc>,
+	/// Last heartbeat of the user
+	pub last_seen: DateTime<Utc>,
+}
+
+/// What a [`PresenceEvent`] reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PresenceChange {
+	/// Current members, sent when a client connects
+	Sync,
+	/// A user joined
+	Join,
+	/// A user left or expired
+	Leave,
+}
+
+/// Payload of [`PRESENCE_EVENT`] events
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PresenceEvent {
+	/// What happened
+	pub change: PresenceChange,
+	/// Room the event belongs to
+	pub room: String,
+	/// User who joined or left; `None` for [`PresenceChange::Sync`]
+	pub user_id: Option<String>,
+	/// Members of the room after the change
+	pub members: Vec<PresenceMember>,
+}
+
+/// Configuration for [`PresenceTracker`]
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_websockets::presence::PresenceConfig;
+/// use std::time::Duration;
+///
+/// let config = PresenceConfig::default()
+///     .with_ttl(Duration::from_secs(60))
+///     .with_heartbeat_interval(Duration::from_secs(20));
+///
+/// assert_eq!(config.ttl, Duration::from_secs(60));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PresenceConfig {
+	/// How long a member stays present without a heartbeat.
+	pub ttl: Duration,
+	/// Delay between heartbeats of a [`PresenceSession`]; keep it well below `ttl`.
+	pub heartbeat_interval: Duration,
+	/// Prefix of the cache keys.
+	pub key_prefix: String,
+}
+
+impl Default for PresenceConfig {
+	fn default() -> Self {
+		Self {
+			ttl: Duration::from_secs(30),
+			heartbeat_interval: Duration::from_secs(10),
+			key_prefix: "presence".to_string(),
+		}
+	}
+}
+
+impl PresenceConfig {
+	/// Set how long members stay present without a heartbeat
+	pub fn with_ttl(mut self, ttl: Duration) -> Self {
+		self.ttl = ttl;
+		self
+	}
+
+	/// Set the delay between session heartbeats
+	pub fn with_heartbeat_interval(mut self, heartbeat_interval: Duration) -> Self {
+		self.heartbeat_interval = heartbeat_interval;
+		self
+	}
+
+	/// Set the cache key prefix
+	pub fn with_key_prefix(mut self, key_prefix: impl Into<String>) -> Self {
+		self.key_prefix = key_prefix.into();
+		self
+	}
+}
+
+/// Tracks the members of rooms in a cache and publishes their changes
+pub struct PresenceTracker<C: Cache> {
+	cache: Arc<C>,
+	hub: Arc<SseBroadcastHub>,
+	config: PresenceConfig,
+}
+
+impl<C: Cache + 'static> PresenceTracker<C> {
+	/// Create a tracker with the default configuration
+	pub fn new(cache: Arc<C>, hub: Arc<SseBroadcastHub>) -> Self {
+		Self::with_config(cache, hub, PresenceConfig::default())
+	}
+
+	/// Create a tracker with a custom configuration
+	pub fn with_config(cache: Arc<C>, hub: Arc<SseBroadcastHub>, config: PresenceConfig) -> Self {
+		Self { cache, hub, config }
+	}
+
+	/// Get the tracker configuration
+	pub fn config(&self) -> &PresenceConfig {
+		&self.config
+	}
+
+	fn index_key(&self, room: &str) -> String {
+		format!("{}:{}", self.config.key_prefix, room)
+	}
+
+	fn member_key(&self, room: &str, user_id: &str) -> String {
+		format!("{}:{}:{}", self.config.key_prefix, room, user_id)
+	}
+
+	/// Mark `user_id` as present in `room`
+	///
+	/// Joining again refreshes the member and replaces its metadata without
+	/// publishing a second join. Returns the members of the room.
+	pub async fn join(
+		&self,
+		room: &str,
+		user_id: &str,
+		metadata: JsonValue,
+	) -> Result<Vec<PresenceMember>> {
+		let key = self.member_key(room, user_id);
+		let now = Utc::now();
+		let existing: Option<PresenceMember> = self.cache.get(&key).await?;
+		let joined = existing.is_none();
+		let member = match existing {
+			Some(member) => PresenceMember {
+				metadata,
+				last_seen: now,
+				..member
+			},
+			None => PresenceMember {
+				user_id: user_id.to_string(),
+				metadata,
+				joined_at: now,
+				last_seen: now,
+			},
+		};
+		self.cache.set(&key, &member, Some(self.config.ttl)).await?;
+		self.update_index(room, |ids| {
+			if !ids.iter().any(|id| id == user_id) {
+				ids.push(user_id.to_string());
+			}
+		})
+		.await?;
+
+		let members = self.members(room).await?;
+		if joined {
+			self.publish(room, PresenceChange::Join, Some(user_id), &members)
+				.await?;
+		}
+		Ok(members)
+	}
+
+	/// Keep `user_id` present in `room` for another TTL
+	///
+	/// Returns `false` when the member had already expired; join again to
+	/// become present.
+	pub async fn heartbeat(&self, room: &str, user_id: &str) -> Result<bool> {
+		let key = self.member_key(room, user_id);
+		let Some(mut member) = self.cache.get::<PresenceMember>(&key).await? else {
+			return Ok(false);
+		};
+		member.last_seen = Utc::now();
+		self.cache.set(&key, &member, Some(self.config.ttl)).await?;
+		// Restore the id if a concurrent index update dropped it
+		self.update_index(room, |ids| {
+			if !ids.iter().any(|id| id == user_id) {
+				ids.push(user_id.to_string());
+			}
+		})
+		.await?;
+		Ok(true)
+	}
+
+	/// Remove `user_id` from `room`
+	pub async fn leave(&self, room: &str, user_id: &str) -> Result<()> {
+		let key = self.member_key(room, user_id);
+		let was_present = self.cache.has_key(&key).await?;
+		self.cache.delete(&key).await?;
+		self.update_index(room, |ids| ids.retain(|id| id != user_id))
+			.await?;
+		if was_present {
+			let members = self.members(room).await?;
+			self.publish(room, PresenceChange::Leave, Some(user_id), &members)
+				.await?;
+		}
+		Ok(())
+	}
+
+	/// Current members of `room`, in order of arrival
+	///
+	/// Members whose entry expired are removed from the room and announced as
+	/// leaving.
+	pub async fn members(&self, room: &str) -> Result<Vec<PresenceMember>> {
+		let ids: Vec<String> = self
+			.cache
+			.get(&self.index_key(room))
+			.await?
+			.unwrap_or_default();
+		let mut members = Vec::with_capacity(ids.len());
+		let mut expired = Vec::new();
+		for id in ids {
+			match self
+				.cache
+				.get::<PresenceMember>(&self.member_key(room, &id))
+				.await?
+			{
+				Some(member) => members.push(member),
+				None => expired.push(id),
+			}
+		}
+		members.sort_by(|a, b| {
+			a.joined_at
+				.cmp(&b.joined_at)
+				.then_with(|| a.user_id.cmp(&b.user_id))
+		});
+
+		if !expired.is_empty() {
+			self.update_index(room, |ids| ids.retain(|id| !expired.contains(id)))
+				.await?;
+			for id in &expired {
+				self.publish(room, PresenceChange::Leave, Some(id), &members)
+					.await?;
+			}
+		}
+		Ok(members)
+	}
+
+	/// Subscribe to the presence events of `room`
+	pub async fn subscribe(&self, room: &str) -> Result<SseSubscription> {
+		self.hub
+			.subscribe(&presence_topic(room), None)
+			.await
+			.map_err(|e| Error::Internal(e.to_string()))
+	}
+
+	/// Join `room` for the lifetime of the returned session
+	///
+	/// The session first yields a [`PresenceChange::Sync`] event with the
+	/// current members, then every presence event of the room, heartbeating
+	/// in between. Dropping it leaves the room.
+	pub async fn connect(
+		self: &Arc<Self>,
+		room: &str,
+		user_id: &str,
+		metadata: JsonValue,
+	) -> Result<PresenceSession<C>> {
+		// Subscribe before joining so the session sees its own join
+		let subscription = self.subscribe(room).await?;
+		let members = self.join(room, user_id, metadata.clone()).await?;
+		let snapshot = presence_event(&PresenceEvent {
+			change: PresenceChange::Sync,
+			room: room.to_string(),
+			user_id: None,
+			members,
+		})?;
+		Ok(PresenceSession {
+			tracker: Arc::clone(self),
+			room: room.to_string(),
+			user_id: user_id.to_string(),
+			metadata,
+			subscription,
+			snapshot: Some(snapshot),
+			next_heartbeat: Instant::now() + self.config.heartbeat_interval,
+			left: false,
+		})
+	}
+
+	async fn update_index(&self, room: &str, update: impl FnOnce(&mut Vec<String>)) -> Result<()> {
+		let key = self.index_key(room);
+		let mut ids: Vec<String> = self.cache.get(&key).await?.unwrap_or_default();
+		update(&mut ids);
+		if ids.is_empty() {
+			self.cache.delete(&key).await
+		} else {
+			self.cache.set(&key, &ids, None).await
+		}
+	}
+
+	async fn publish(
+		&self,
+		room: &str,
+		change: PresenceChange,
+		user_id: Option<&str>,
+		members: &[PresenceMember],
+	) -> Result<()> {
+		let event = presence_event(&PresenceEvent {
+			change,
+			room: room.to_string(),
+			user_id: user_id.map(str::to_string),
+			members: members.to_vec(),
+		})?;
+		self.hub
+			.publish(&presence_topic(room), event)
+			.await
+			.map(|_| ())
+			.map_err(|e| Error::Internal(e.to_string()))
+	}
+}
+
+fn presence_event(event: &PresenceEvent) -> Result<SseEvent> {
+	Ok(SseEvent::json(event)?.event(PRESENCE_EVENT))
+}
+
+/// A user's presence in a room, kept alive while the session is read
+///
+/// Dropping the session (e.g. when the SSE client disconnects) leaves the
+/// room.
+pub struct PresenceSession<C: Cache + 'static> {
+	tracker: Arc<PresenceTracker<C>>,
+	room: String,
+	user_id: String,
+	metadata: JsonValue,
+	subscription: SseSubscription,
+	snapshot: Option<SseEvent>,
+	next_heartbeat: Instant,
+	left: bool,
+}
+
+impl<C: Cache + 'static> PresenceSession<C> {
+	/// Room of the session
+	pub fn room(&self) -> &str {
+		&self.room
+	}
+
+	/// User of the session
+	pub fn user_id(&self) -> &str {
+		&self.user_id
+	}
+
+	/// Receive the next presence event, heartbeating while waiting
+	///
+	/// Returns `None` when the channel layer fails.
+	pub async fn recv(&mut self) -> Option<SseEvent> {
+		if let Some(snapshot) = self.snapshot.take() {
+			return Some(snapshot);
+		}
+		loop {
+			match tokio::time::timeout_at(self.next_heartbeat, self.subscription.recv()).await {
+				Ok(event) => return event,
+				Err(_) => self.beat().await,
+			}
+		}
+	}
+
+	async fn beat(&mut self) {
+		self.next_heartbeat = Instant::now() + self.tracker.config.heartbeat_interval;
+		let result = match self.tracker.heartbeat(&self.room, &self.user_id).await {
+			Ok(true) => Ok(()),
+			// Expired while the client was still connected (e.g. a stalled
+			// worker): become present again
+			Ok(false) => self
+				.tracker
+				.join(&self.room, &self.user_id, self.metadata.clone())
+				.await
+				.map(|_| ()),
+			Err(e) => Err(e),
+		};
+		if let Err(e) = result {
+			tracing::warn!(room = %self.room, user = %self.user_id, error = %e, "Presence heartbeat failed");
+		}
+	}
+
+	/// Leave the room now instead of when the session is dropped
+	pub async fn leave(mut self) -> Result<()> {
+		self.left = true;
+		self.tracker.leave(&self.room, &self.user_id).await
+	}
+
+	/// Convert the session into a stream suitable for `SseResponse`
+	pub fn into_stream(self) -> impl Stream<Item = SseEvent> + Send + 'static {
+		stream::unfold(self, |mut session| async move {
+			session.recv().await.map(|event| (event, session))
+		})
+	}
+}
+
+impl<C: Cache + 'static> Drop for PresenceSession<C> {
+	fn drop(&mut self) {
+		if self.left {
+			return;
+		}
+		let Ok(handle) = tokio::runtime::Handle::try_current() else {
+			return;
+		};
+		let tracker = Arc::clone(&self.tracker);
+		let room = std::mem::take(&mut self.room);
+		let user_id = std::mem::take(&mut self.user_id);
+		handle.spawn(async move {
+			if let Err(e) = tracker.leave(&room, &user_id).await {
+				tracing::debug!(%room, user = %user_id, error = %e, "Failed to leave presence room");
+			}
+		});
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::channels::InMemoryChannelLayer;
+	use crate::sse::SseHubConfig;
+	use reinhardt_utils::cache::InMemoryCache;
+	use rstest::rstest;
+	use serde_json::json;
+
+	fn tracker(config: PresenceConfig) -> Arc<PresenceTracker<InMemoryCache>> {
+		let hub = Arc::new(SseBroadcastHub::with_config(
+			Arc::new(InMemoryChannelLayer::new()),
+			SseHubConfig::default().with_poll_interval(Duration::from_millis(1)),
+		));
+		Arc::new(PresenceTracker::with_config(
+			Arc::new(InMemoryCache::new()),
+			hub,
+			config,
+		))
+	}
+
+	fn decode(event: &SseEvent) -> PresenceEvent {
+		assert_eq!(event.event.as_deref(), Some(PRESENCE_EVENT));
+		serde_json::from_str(&event.data).unwrap()
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_join_publishes_once_and_lists_members() {
+		// Arrange
+		let tracker = tracker(PresenceConfig::default());
+		let mut subscriber = tracker.subscribe("lobby").await.unwrap();
+
+		// Act
+		tracker
+			.join("lobby", "1", json!({"name": "Alice"}))
+			.await
+			.unwrap();
+		tracker.join("lobby", "2", JsonValue::Null).await.unwrap();
+		let members = tracker
+			.join("lobby", "1", json!({"name": "Al"}))
+			.await
+			.unwrap();
+
+		// Assert
+		let ids: Vec<_> = members.iter().map(|m| m.user_id.as_str()).collect();
+		assert_eq!(ids, ["1", "2"]);
+		assert_eq!(members[0].metadata, json!({"name": "Al"}));
+		let first = decode(&subscriber.recv().await.unwrap());
+		assert_eq!(first.change, PresenceChange::Join);
+		assert_eq!(first.user_id.as_deref(), Some("1"));
+		assert_eq!(decode(&subscriber.recv().await.unwrap()).members.len(), 2);
+		assert_eq!(tracker.hub.history_len(&presence_topic("lobby")).await, 2);
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_expired_members_are_announced_as_leaving() {
+		// Arrange
+		let tracker = tracker(PresenceConfig::default().with_ttl(Duration::from_millis(20)));
+		tracker.join("lobby", "1", JsonValue::Null).await.unwrap();
+		let mut subscriber = tracker.subscribe("lobby").await.unwrap();
+		tokio::time::sleep(Duration::from_millis(40)).await;
+
+		// Act
+		let members = tracker.members("lobby").await.unwrap();
+		let renewed = tracker.heartbeat("lobby", "1").await.unwrap();
+
+		// Assert
+		assert!(members.is_empty());
+		assert!(!renewed);
+		let event = decode(&subscriber.recv().await.unwrap());
+		assert_eq!(event.change, PresenceChange::Leave);
+		assert_eq!(event.user_id.as_deref(), Some("1"));
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_session_syncs_then_leaves() {
+		// Arrange
+		let tracker = tracker(PresenceConfig::default());
+		tracker.join("lobby", "1", JsonValue::Null).await.unwrap();
+
+		// Act
+		let mut session = tracker
+			.connect("lobby", "2", JsonValue::Null)
+			.await
+			.unwrap();
+		let snapshot = decode(&session.recv().await.unwrap());
+		let own_join = decode(&session.recv().await.unwrap());
+		session.leave().await.unwrap();
+
+		// Assert
+		assert_eq!(snapshot.change, PresenceChange::Sync);
+		assert_eq!(snapshot.members.len(), 2);
+		assert_eq!(own_join.user_id.as_deref(), Some("2"));
+		let members = tracker.members("lobby").await.unwrap();
+		assert_eq!(members.len(), 1);
+		assert_eq!(members[0].user_id, "1");
+	}
+
+	#[rstest]
+	#[tokio::test]
+	async fn test_session_heartbeats_while_waiting() {
+		// Arrange
+		let config = PresenceConfig::default()
+			.with_ttl(Duration::from_millis(60))
+			.with_heartbeat_interval(Duration::from_millis(10));
+		let tracker = tracker(config);
+		let mut session = tracker
+			.connect("lobby", "1", JsonValue::Null)
+			.await
+			.unwrap();
+		session.recv().await.unwrap();
+		session.recv().await.unwrap();
+
+		// Act
+		let waited = tokio::time::timeout(Duration::from_millis(120), session.recv()).await;
+
+		// Assert
+		assert!(waited.is_err());
+		assert_eq!(tracker.members("lobby").await.unwrap().len(), 1);
+	}
+}
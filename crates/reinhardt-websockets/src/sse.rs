@@ -0,0 +1,432 @@
+//! Topic-based broadcast hub for Server-Sent Events
+//!
+//! [`SseBroadcastHub`] lets handlers and background tasks push
+//! [`SseEvent`]s to every client subscribed to a topic. Delivery goes through a
+//! [`ChannelLayer`], so with a distributed layer (e.g. Redis) events published
+//! on one instance reach clients connected to any other instance.
+//!
+//! Each subscription registers its own channel in the topic group. The hub
+//! also keeps a bounded per-topic history so reconnecting clients that send
+//! `Last-Event-ID` receive the events they missed.
+//!
+//! ## Multiple instances
+//!
+//! Event ids and the replay history are kept in the hub, not in the channel
+//! layer, so each process has its own id counter and its own history. A
+//! client that reconnects to a different instance with `Last-Event-ID` is
+//! matched against that instance's history: missed events may be skipped or
+//! delivered twice. When several instances share a distributed layer, route
+//! reconnecting clients to the same instance (sticky sessions), or set
+//! globally unique ids on published events and treat replay as best effort.
+//!
+//! ## Example
+//!
+//! ```
+//! use reinhardt_websockets::channels::InMemoryChannelLayer;
+//! use reinhardt_websockets::sse::SseBroadcastHub;
+//! use reinhardt_http::sse::{SseEvent, SseResponse};
+//! use std::sync::Arc;
+//!
+//! # tokio_test::block_on(async {
+//! let hub = Arc::new(SseBroadcastHub::new(Arc::new(InMemoryChannelLayer::new())));
+//!
+//! let subscription = hub.subscribe("orders", None).await.unwrap();
+//! hub.publish("orders", SseEvent::new("order #1 shipped")).await.unwrap();
+//!
+//! let response = SseResponse::new(subscription.into_stream()).into_streaming_response();
+//! # let _ = response;
+//! # });
+//! ```
+
+use crate::channels::{ChannelError, ChannelLayer, ChannelMessage, ChannelResult};
+use crate::connection::Message;
+use futures_util::stream::{self, Stream};
+use reinhardt_http::sse::SseEvent;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+/// Sender name attached to channel messages published by the hub.
+const HUB_SENDER: &str = "sse-hub";
+
+/// Configuration for [`SseBroadcastHub`]
+///
+/// # Examples
+///
+/// ```
+/// use reinhardt_websockets::sse::SseHubConfig;
+/// use std::time::Duration;
+///
+/// let config = SseHubConfig::default()
+///     .with_history_size(50)
+///     .with_poll_interval(Duration::from_millis(20));
+///
+/// assert_eq!(config.history_size, 50);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SseHubConfig {
+	/// Number of recent events kept per topic for `Last-Event-ID` replay.
+	pub history_size: usize,
+	/// Delay between channel layer polls when a subscription has no pending events.
+	pub poll_interval: Duration,
+	/// Prefix used to build channel layer group names from topics.
+	pub group_prefix: String,
+}
+
+impl Default for SseHubConfig {
+	fn default() -> Self {
+		Self {
+			history_size: 100,
+			poll_interval: Duration::from_millis(50),
+			group_prefix: "sse".to_string(),
+		}
+	}
+}
+
+impl SseHubConfig {
+	/// Set the per-topic replay history size
+	///
+	/// The history is kept in process memory: it only contains events
+	/// published through this hub, and replay does not work across instances
+	/// sharing a distributed channel layer. See the
+	/// [module documentation](self#multiple-instances).
+	pub fn with_history_size(mut self, history_size: usize) -> Self {
+		self.history_size = history_size;
+		self
+	}
+
+	/// Set the channel layer poll interval
+	pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+		self.poll_interval = poll_interval;
+		self
+	}
+
+	/// Set the group name prefix
+	pub fn with_group_prefix(mut self, group_prefix: impl Into<String>) -> Self {
+		self.group_prefix = group_prefix.into();
+		self
+	}
+}
+
+/// Topic-based SSE broadcast hub backed by a channel layer
+pub struct SseBroadcastHub {
+	layer: Arc<dyn ChannelLayer>,
+	config: SseHubConfig,
+	history: RwLock<HashMap<String, VecDeque<SseEvent>>>,
+	next_subscriber: AtomicU64,
+	next_event_id: AtomicU64,
+}
+
+impl SseBroadcastHub {
+	/// Create a hub with the default configuration
+	pub fn new(layer: Arc<dyn ChannelLayer>) -> Self {
+		Self::with_config(layer, SseHubConfig::default())
+	}
+
+	/// Create a hub with a custom configuration
+	pub fn with_config(layer: Arc<dyn ChannelLayer>, config: SseHubConfig) -> Self {
+		Self {
+			layer,
+			config,
+			history: RwLock::new(HashMap::new()),
+			next_subscriber: AtomicU64::new(1),
+			next_event_id: AtomicU64::new(1),
+		}
+	}
+
+	/// Get the hub configuration
+	pub fn config(&self) -> &SseHubConfig {
+		&self.config
+	}
+
+	fn group_name(&self, topic: &str) -> String {
+		format!("{}.{}", self.config.group_prefix, topic)
+	}
+
+	/// Publish an event to every subscriber of `topic`
+	///
+	/// Events without an `id` are assigned a hub-wide monotonically increasing
+	/// identifier so clients can resume with `Last-Event-ID`. The counter is
+	/// per process, so ids from different instances overlap. Returns the
+	/// identifier of the published event. Publishing to a topic without
+	/// subscribers only records the event in the replay history.
+	pub async fn publish(&self, topic: &str, mut event: SseEvent) -> ChannelResult<String> {
+		let id = match &event.id {
+			Some(id) => id.clone(),
+			None => {
+				let id = self
+					.next_event_id
+					.fetch_add(1, Ordering::Relaxed)
+					.to_string();
+				event.id = Some(id.clone());
+				id
+			}
+		};
+
+		if self.config.history_size > 0 {
+			let mut history = self.history.write().await;
+			let entries = history.entry(topic.to_string()).or_default();
+			entries.push_back(event.clone());
+			while entries.len() > self.config.history_size {
+				entries.pop_front();
+			}
+		}
+
+		let payload = serde_json::to_string(&event)
+			.map_err(|e| ChannelError::SerializationError(e.to_string()))?;
+		let message = ChannelMessage::new(HUB_SENDER.to_string(), Message::text(payload));
+
+		match self
+			.layer
+			.group_send(&self.group_name(topic), message)
+			.await
+		{
+			Ok(()) | Err(ChannelError::GroupNotFound(_)) => Ok(id),
+			Err(e) => Err(e),
+		}
+	}
+
+	/// Subscribe to `topic`
+	///
+	/// When `last_event_id` is given and still present in the replay history,
+	/// the events published after it are delivered first.
+	pub async fn subscribe(
+		self: &Arc<Self>,
+		topic: &str,
+		last_event_id: Option<&str>,
+	) -> ChannelResult<SseSubscription> {
+		let subscriber = self.next_subscriber.fetch_add(1, Ordering::Relaxed);
+		let channel = format!("{}.{}!{}", self.config.group_prefix, topic, subscriber);
+		let group = self.group_name(topic);
+
+		self.layer.group_add(&group, &channel).await?;
+
+		let backlog = match last_event_id {
+			Some(last_id) => self.replay_after(topic, last_id).await,
+			None => VecDeque::new(),
+		};
+
+		Ok(SseSubscription {
+			hub: Arc::clone(self),
+			group,
+			channel,
+			backlog,
+		})
+	}
+
+	async fn replay_after(&self, topic: &str, last_id: &str) -> VecDeque<SseEvent> {
+		let history = self.history.read().await;
+		let Some(entries) = history.get(topic) else {
+			return VecDeque::new();
+		};
+		match entries
+			.iter()
+			.position(|event| event.id.as_deref() == Some(last_id))
+		{
+			Some(pos) => entries.iter().skip(pos + 1).cloned().collect(),
+			None => VecDeque::new(),
+		}
+	}
+
+	/// Number of events currently retained for `topic`
+	pub async fn history_len(&self, topic: &str) -> usize {
+		self.history
+			.read()
+			.await
+			.get(topic)
+			.map_or(0, VecDeque::len)
+	}
+}
+
+/// A client subscription to a hub topic
+///
+/// The subscription leaves the topic group when dropped, so dropping the
+/// stream returned by [`SseSubscription::into_stream`] (e.g. when the client
+/// disconnects) cleans up the channel layer registration.
+pub struct SseSubscription {
+	hub: Arc<SseBroadcastHub>,
+	group: String,
+	channel: String,
+	backlog: VecDeque<SseEvent>,
+}
+
+impl SseSubscription {
+	/// Channel name registered in the channel layer for this subscription
+	pub fn channel(&self) -> &str {
+		&self.channel
+	}
+
+	/// Receive the next event, waiting until one is available
+	///
+	/// Returns `None` when the channel layer fails.
+	pub async fn recv(&mut self) -> Option<SseEvent> {
+		if let Some(event) = self.backlog.pop_front() {
+			return Some(event);
+		}
+		loop {
+			match self.hub.layer.receive(&self.channel).await {
+				Ok(Some(message)) => {
+					if let Some(event) = decode_event(&message) {
+						return Some(event);
+					}
+				}
+				Ok(None) => tokio::time::sleep(self.hub.config.poll_interval).await,
+				Err(e) => {
+					tracing::warn!(channel = %self.channel, error = %e, "SSE subscription receive failed");
+					return None;
+				}
+			}
+		}
+	}
+
+	/// Convert the subscription into a stream suitable for `SseResponse`
+	pub fn into_stream(self) -> impl Stream<Item = SseEvent> + Send + 'static {
+		stream::unfold(self, |mut subscription| async move {
+			subscription.recv().await.map(|event| (event, subscription))
+		})
+	}
+}
+
+impl Drop for SseSubscription {
+	fn drop(&mut self) {
+		let Ok(handle) = tokio::runtime::Handle::try_current() else {
+			return;
+		};
+		let layer = Arc::clone(&self.hub.layer);
+		let group = std::mem::take(&mut self.group);
+		let channel = std::mem::take(&mut self.channel);
+		handle.spawn(async move {
+			if let Err(e) = layer.group_discard(&group, &channel).await {
+				tracing::debug!(%group, %channel, error = %e, "Failed to discard SSE subscription");
+			}
+		});
+	}
+}
+
+fn decode_event(message: &ChannelMessage) -> Option<SseEvent> {
+	match message.payload() {
+		Message::Text { data } => serde_json::from_str(data).ok(),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::channels::InMemoryChannelLayer;
+	use futures_util::StreamExt;
+
+	fn hub() -> Arc<SseBroadcastHub> {
+		let config = SseHubConfig::default()
+			.with_history_size(3)
+			.with_poll_interval(Duration::from_millis(1));
+		Arc::new(SseBroadcastHub::with_config(
+			Arc::new(InMemoryChannelLayer::new()),
+			config,
+		))
+	}
+
+	#[tokio::test]
+	async fn test_publish_reaches_subscribers() {
+		// Arrange
+		let hub = hub();
+		let mut first = hub.subscribe("news", None).await.unwrap();
+		let mut second = hub.subscribe("news", None).await.unwrap();
+
+		// Act
+		let id = hub.publish("news", SseEvent::new("hello")).await.unwrap();
+
+		// Assert
+		let expected = SseEvent::new("hello").id(id);
+		assert_eq!(first.recv().await, Some(expected.clone()));
+		assert_eq!(second.recv().await, Some(expected));
+	}
+
+	#[tokio::test]
+	async fn test_publish_without_subscribers_records_history() {
+		// Arrange
+		let hub = hub();
+
+		// Act
+		let result = hub.publish("empty", SseEvent::new("nobody")).await;
+
+		// Assert
+		assert_eq!(result.unwrap(), "1");
+		assert_eq!(hub.history_len("empty").await, 1);
+	}
+
+	#[tokio::test]
+	async fn test_topics_are_isolated() {
+		// Arrange
+		let hub = hub();
+		let mut other = hub.subscribe("other", None).await.unwrap();
+		let mut news = hub.subscribe("news", None).await.unwrap();
+
+		// Act
+		hub.publish("news", SseEvent::new("a")).await.unwrap();
+		hub.publish("other", SseEvent::new("b")).await.unwrap();
+
+		// Assert
+		assert_eq!(news.recv().await.unwrap().data, "a");
+		assert_eq!(other.recv().await.unwrap().data, "b");
+	}
+
+	#[tokio::test]
+	async fn test_subscribe_replays_events_after_last_event_id() {
+		// Arrange
+		let hub = hub();
+		for data in ["a", "b", "c"] {
+			hub.publish("feed", SseEvent::new(data)).await.unwrap();
+		}
+
+		// Act
+		let replayed: Vec<String> = hub
+			.subscribe("feed", Some("1"))
+			.await
+			.unwrap()
+			.into_stream()
+			.take(2)
+			.map(|event| event.data)
+			.collect()
+			.await;
+
+		// Assert
+		assert_eq!(replayed, vec!["b".to_string(), "c".to_string()]);
+	}
+
+	#[tokio::test]
+	async fn test_history_is_bounded() {
+		// Arrange
+		let hub = hub();
+
+		// Act
+		for data in ["a", "b", "c", "d"] {
+			hub.publish("feed", SseEvent::new(data)).await.unwrap();
+		}
+
+		// Assert
+		assert_eq!(hub.history_len("feed").await, 3);
+	}
+
+	#[tokio::test]
+	async fn test_explicit_event_id_is_preserved() {
+		// Arrange
+		let hub = hub();
+		let mut subscription = hub.subscribe("feed", None).await.unwrap();
+
+		// Act
+		let id = hub
+			.publish("feed", SseEvent::new("x").id("custom-1"))
+			.await
+			.unwrap();
+
+		// Assert
+		assert_eq!(id, "custom-1");
+		assert_eq!(
+			subscription.recv().await.unwrap().id.as_deref(),
+			Some("custom-1")
+		);
+	}
+}
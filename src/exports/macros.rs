@@ -8,6 +8,11 @@ pub use reinhardt_macros::{AppConfig, app_config, installed_apps};
 #[cfg(all(feature = "conf", native))]
 pub use reinhardt_macros::settings;
 
+#[cfg(all(feature = "conf", native))]
+pub use reinhardt_conf::settings::app_settings::AppSettings;
+#[cfg(all(feature = "conf", native))]
+pub use reinhardt_macros::AppSettings;
+
 pub use reinhardt_macros::HttpError;
 pub use reinhardt_macros::{Model, model};
 
@@ -18,7 +23,10 @@ pub use reinhardt_macros::user;
 pub use reinhardt_macros::collect_migrations;
 
 #[cfg(native)]
-pub use reinhardt_macros::{api_view, delete, get, patch, post, put};
+pub use reinhardt_macros::{
+	api_view, cache_control, csrf_exempt, delete, deprecation, get, login_required, patch, post,
+	put, require_http_methods, throttle, validate_request,
+};
 
 #[cfg(native)]
 pub use reinhardt_macros::flatten_imports;
@@ -32,3 +40,6 @@ pub use reinhardt_macros::admin;
 pub use reinhardt_core::apply_update::ApplyUpdate;
 #[cfg(native)]
 pub use reinhardt_macros::{ApplyUpdate as DeriveApplyUpdate, apply_update};
+
+pub use reinhardt_core::choices::Choices;
+pub use reinhardt_macros::Choices;
@@ -7,7 +7,11 @@
 	feature = "standard",
 	feature = "full"
 ))]
-pub use reinhardt_rest::serializers::{Deserializer, JsonSerializer, Serializer};
+pub use reinhardt_rest::serializers::{
+	DateTimeFormat, Deserializer, JsonOptions, JsonSerializer, KeyCase, Serializer,
+};
+
+pub use reinhardt_rest::renderers::JSONRenderer;
 
 pub use reinhardt_rest::pagination::{
 	CursorPagination, LimitOffsetPagination, PageNumberPagination, PaginatedResponse, Paginator,
@@ -0,0 +1,147 @@
+//! Routing, middleware and serializer hot-path benchmarks
+//!
+//! Traffic comes from `SyntheticRequestGenerator` with fixed seeds, so runs are
+//! comparable across commits. Benchmark IDs match the keys in
+//! `tests/bench/budgets.toml`; run `check_bench_budgets` after this suite to
+//! fail on regressions.
+
+use async_trait::async_trait;
+use criterion::{BatchSize, BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use reinhardt_http::{Handler, Request, Response, Result};
+use reinhardt_middleware::{Middleware, MiddlewareChain};
+use reinhardt_rest::serializers::{JsonSerializer, Serializer};
+use reinhardt_test::bench::SyntheticRequestGenerator;
+use reinhardt_urls::routers::{MatchingMode, PathMatcher, PathPattern};
+use serde::{Deserialize, Serialize};
+use std::hint::black_box;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+const SEED: u64 = 0x5eed;
+const SAMPLE_PATHS: usize = 1_024;
+
+struct EmptyHandler;
+
+#[async_trait]
+impl Handler for EmptyHandler {
+	async fn handle(&self, _request: Request) -> Result<Response> {
+		Ok(Response::ok())
+	}
+}
+
+/// Middleware that reads a header, like most real middleware does
+struct HeaderReadingMiddleware;
+
+#[async_trait]
+impl Middleware for HeaderReadingMiddleware {
+	async fn process(&self, request: Request, next: Arc<dyn Handler>) -> Result<Response> {
+		black_box(request.headers.get("x-bench-0"));
+		next.handle(request).await
+	}
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Article {
+	id: i64,
+	title: String,
+	body: String,
+	tags: Vec<String>,
+	published: bool,
+}
+
+fn matcher(generator: &SyntheticRequestGenerator, mode: MatchingMode) -> PathMatcher {
+	let mut matcher = PathMatcher::with_mode(mode);
+	for (index, route) in generator.routes().iter().enumerate() {
+		let pattern = PathPattern::new(route.pattern.clone()).expect("valid synthetic pattern");
+		matcher
+			.add_pattern(pattern, format!("route{index}"))
+			.expect("non-conflicting synthetic routes");
+	}
+	matcher
+}
+
+fn benchmark_path_matcher(c: &mut Criterion) {
+	let mut group = c.benchmark_group("path_matcher");
+	group.throughput(Throughput::Elements(1));
+
+	for route_count in [10, 200, 1_000] {
+		let mut generator = SyntheticRequestGenerator::new(SEED).with_generated_routes(route_count);
+		let paths = generator.paths(SAMPLE_PATHS);
+
+		for (label, mode) in [
+			("linear", MatchingMode::Linear),
+			("radix", MatchingMode::RadixTree),
+		] {
+			let matcher = matcher(&generator, mode);
+			group.bench_with_input(BenchmarkId::new(label, route_count), &paths, |b, paths| {
+				let mut index = 0;
+				b.iter(|| {
+					index = (index + 1) % paths.len();
+					black_box(matcher.match_path(&paths[index]))
+				});
+			});
+		}
+	}
+	group.finish();
+}
+
+fn benchmark_middleware_chain(c: &mut Criterion) {
+	let rt = Runtime::new().unwrap();
+	let mut group = c.benchmark_group("middleware_chain");
+	group.throughput(Throughput::Elements(1));
+
+	for depth in [0, 5, 20] {
+		let middlewares: Vec<Arc<dyn Middleware>> = (0..depth)
+			.map(|_| Arc::new(HeaderReadingMiddleware) as Arc<dyn Middleware>)
+			.collect();
+		let chain = MiddlewareChain::with_middlewares(Arc::new(EmptyHandler), middlewares);
+		let mut generator = SyntheticRequestGenerator::new(SEED);
+
+		group.bench_function(BenchmarkId::new("depth", depth), |b| {
+			b.iter_batched(
+				|| generator.next_request(),
+				|request| rt.block_on(chain.handle(request)),
+				BatchSize::SmallInput,
+			);
+		});
+	}
+	group.finish();
+}
+
+fn benchmark_serializer(c: &mut Criterion) {
+	let mut group = c.benchmark_group("serializer");
+	let serializer = JsonSerializer::<Article>::new();
+
+	for body_len in [64, 4_096] {
+		let article = Article {
+			id: 42,
+			title: "Benchmarking the serializer".to_string(),
+			body: "x".repeat(body_len),
+			tags: vec!["rust".to_string(), "web".to_string()],
+			published: true,
+		};
+		let json = serializer.serialize(&article).unwrap();
+		group.throughput(Throughput::Bytes(json.len() as u64));
+
+		group.bench_with_input(
+			BenchmarkId::new("json_serialize", body_len),
+			&article,
+			|b, article| b.iter(|| black_box(serializer.serialize(article))),
+		);
+		group.bench_with_input(
+			BenchmarkId::new("json_deserialize", body_len),
+			&json,
+			|b, json| b.iter(|| black_box(serializer.deserialize(json))),
+		);
+	}
+	group.finish();
+}
+
+criterion_group!(
+	benches,
+	benchmark_path_matcher,
+	benchmark_middleware_chain,
+	benchmark_serializer
+);
+
+criterion_main!(benches);
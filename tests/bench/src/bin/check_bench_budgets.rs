@@ -0,0 +1,115 @@
+//! Compare the latest Criterion estimates against `budgets.toml`.
+//!
+//! Usage:
+//!
+//! ```bash
+//! cargo bench -p reinhardt-benchmarks --bench router_middleware_benchmarks
+//! cargo run -p reinhardt-benchmarks --bin check_bench_budgets [budgets.toml] [criterion-dir]
+//! ```
+//!
+//! The criterion directory defaults to `$CARGO_TARGET_DIR/criterion`, or
+//! `target/criterion` at the workspace root. Exits with status 1 when a
+//! benchmark exceeds its budget or has no estimate.
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[derive(Deserialize)]
+struct BudgetFile {
+	budgets: BTreeMap<String, f64>,
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+	mean: Estimate,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+	point_estimate: f64,
+}
+
+enum Outcome {
+	Within { mean_ns: f64 },
+	Exceeded { mean_ns: f64 },
+	Missing(String),
+}
+
+fn default_criterion_dir() -> PathBuf {
+	match std::env::var_os("CARGO_TARGET_DIR") {
+		Some(dir) => PathBuf::from(dir).join("criterion"),
+		None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../../target/criterion"),
+	}
+}
+
+fn check(criterion_dir: &Path, id: &str, budget_ns: f64) -> Outcome {
+	let path = criterion_dir.join(id).join("new/estimates.json");
+	let contents = match std::fs::read_to_string(&path) {
+		Ok(contents) => contents,
+		Err(e) => return Outcome::Missing(format!("{}: {}", path.display(), e)),
+	};
+	match serde_json::from_str::<Estimates>(&contents) {
+		Ok(estimates) if estimates.mean.point_estimate <= budget_ns => Outcome::Within {
+			mean_ns: estimates.mean.point_estimate,
+		},
+		Ok(estimates) => Outcome::Exceeded {
+			mean_ns: estimates.mean.point_estimate,
+		},
+		Err(e) => Outcome::Missing(format!("{}: {}", path.display(), e)),
+	}
+}
+
+fn main() -> ExitCode {
+	let mut args = std::env::args().skip(1);
+	let budgets_path = args
+		.next()
+		.map(PathBuf::from)
+		.unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("budgets.toml"));
+	let criterion_dir = args
+		.next()
+		.map(PathBuf::from)
+		.unwrap_or_else(default_criterion_dir);
+
+	let budgets: BudgetFile = match std::fs::read_to_string(&budgets_path)
+		.map_err(|e| e.to_string())
+		.and_then(|contents| toml::from_str(&contents).map_err(|e| e.to_string()))
+	{
+		Ok(budgets) => budgets,
+		Err(e) => {
+			eprintln!("failed to read {}: {}", budgets_path.display(), e);
+			return ExitCode::FAILURE;
+		}
+	};
+
+	let mut failures = 0;
+	for (id, budget_ns) in &budgets.budgets {
+		match check(&criterion_dir, id, *budget_ns) {
+			Outcome::Within { mean_ns } => {
+				println!("ok       {id}: mean_ns={mean_ns:.1} budget_ns={budget_ns:.0}");
+			}
+			Outcome::Exceeded { mean_ns } => {
+				failures += 1;
+				println!(
+					"EXCEEDED {id}: mean_ns={mean_ns:.1} budget_ns={budget_ns:.0} ({:+.0}%)",
+					(mean_ns / budget_ns - 1.0) * 100.0
+				);
+			}
+			Outcome::Missing(reason) => {
+				failures += 1;
+				println!("MISSING  {id}: {reason}");
+			}
+		}
+	}
+
+	if failures > 0 {
+		eprintln!(
+			"{failures} of {} benchmark budget(s) failed",
+			budgets.budgets.len()
+		);
+		ExitCode::FAILURE
+	} else {
+		ExitCode::SUCCESS
+	}
+}
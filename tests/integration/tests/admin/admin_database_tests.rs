@@ -12,7 +12,7 @@ use reinhardt_db::backends::{
 	types::{QueryValue, Row},
 };
 use reinhardt_db::orm::annotation::Expression;
-use reinhardt_db::orm::expressions::{F, OuterRef};
+use reinhardt_db::orm::expressions::{F, OrderBy, OuterRef};
 use reinhardt_db::orm::{
 	DatabaseBackend, DatabaseConnection, Filter, FilterCondition, FilterOperator, FilterValue,
 };
@@ -548,7 +548,14 @@ async fn test_list_with_condition_and_count_uses_single_query_for_non_empty_page
 
 	// Act
 	let (rows, count) = db
-		.list_with_condition_and_count::<User>("users", None, vec![], Some("-id"), 0, 50)
+		.list_with_condition_and_count::<User>(
+			"users",
+			None,
+			vec![],
+			Some(&OrderBy::field("-id")),
+			0,
+			50,
+		)
 		.await
 		.unwrap();
 
@@ -560,6 +567,32 @@ async fn test_list_with_condition_and_count_uses_single_query_for_non_empty_page
 	assert!(!rows[0].contains_key("password_hash"));
 }
 
+#[tokio::test]
+async fn test_list_with_condition_and_count_natural_ordering_uses_collation() {
+	// Arrange
+	let mut mock = MockDatabaseBackend::new();
+	mock.expect_fetch_all()
+		.withf(|sql, _| sql.contains(r#"ORDER BY "name" COLLATE "natural" DESC"#))
+		.times(1)
+		.returning(|_, _| Ok(Vec::new()));
+	let db = admin_database_from_mock(mock);
+
+	// Act
+	let result = db
+		.list_with_condition_and_count::<User>(
+			"users",
+			None,
+			vec![],
+			Some(&OrderBy::natural("-name")),
+			0,
+			50,
+		)
+		.await;
+
+	// Assert
+	assert_eq!(result.unwrap().1, 0);
+}
+
 #[tokio::test]
 async fn test_list_with_condition_and_count_empty_first_page_uses_single_query() {
 	// Arrange
@@ -1,6 +1,9 @@
 // Auto-generated module file for macros integration tests
 // Each test file in macros/ subdirectory is explicitly included with #[path] attribute
 
+#[path = "macros/choices_integration.rs"]
+mod choices_integration;
+
 #[path = "macros/composite_pk_integration.rs"]
 mod composite_pk_integration;
 
@@ -0,0 +1,49 @@
+use reinhardt::Choices;
+use rstest::rstest;
+
+#[derive(Debug, Clone, Copy, PartialEq, Choices)]
+enum Status {
+	Draft,
+	#[choice(label = "Under review")]
+	InReview,
+	#[choice(value = "pub")]
+	Published,
+}
+
+#[rstest]
+fn derive_uses_defaults_and_overrides() {
+	// Act
+	let pairs = Status::pairs();
+
+	// Assert
+	assert_eq!(
+		pairs,
+		vec![
+			("draft".to_string(), "Draft".to_string()),
+			("in_review".to_string(), "Under review".to_string()),
+			("pub".to_string(), "Published".to_string()),
+		]
+	);
+}
+
+#[rstest]
+#[case("draft", Some(Status::Draft))]
+#[case("pub", Some(Status::Published))]
+#[case("published", None)]
+fn derive_parses_machine_values(#[case] value: &str, #[case] expected: Option<Status>) {
+	// Act
+	let choice = Status::from_value(value);
+
+	// Assert
+	assert_eq!(choice, expected);
+}
+
+#[rstest]
+fn label_for_displays_stored_value() {
+	// Act
+	let label = Status::label_for("in_review");
+
+	// Assert
+	assert_eq!(label.as_deref(), Some("Under review"));
+	assert_eq!(Status::InReview.value(), "in_review");
+}
@@ -1380,3 +1380,150 @@ fn test_enum_container_attributes() {
 		_ => panic!("Expected Object schema for simple enum with container attrs"),
 	}
 }
+
+// ============================================================================
+// Generic, Recursive and Renamed Type Tests
+// ============================================================================
+
+#[rstest]
+fn test_generic_struct_schema_name_includes_type_arguments() {
+	// Arrange
+	// Allow: compile-time-only test fixture for derive macro verification
+	#[allow(dead_code)]
+	#[derive(Schema)]
+	struct Author {
+		name: String,
+	}
+
+	// Allow: compile-time-only test fixture for derive macro verification
+	#[allow(dead_code)]
+	#[derive(Schema)]
+	struct Page<T> {
+		items: Vec<T>,
+		total: i64,
+	}
+
+	// Act
+	let schema = Page::<Author>::schema();
+
+	// Assert
+	assert_eq!(
+		Page::<Author>::schema_name(),
+		Some("Page_Author".to_string())
+	);
+	assert_eq!(Page::<i64>::schema_name(), Some("Page_i64".to_string()));
+	let json = serde_json::to_value(schema).unwrap();
+	assert_eq!(json["properties"]["items"]["type"], "array");
+	assert_eq!(
+		json["properties"]["items"]["items"]["properties"]["name"]["type"],
+		"string"
+	);
+}
+
+#[rstest]
+fn test_recursive_struct_fields_use_refs() {
+	// Arrange
+	// Allow: compile-time-only test fixture for derive macro verification
+	#[allow(dead_code)]
+	#[derive(Schema)]
+	struct TreeNode {
+		value: i64,
+		children: Vec<TreeNode>,
+		/// Parent node
+		parent: Option<Box<TreeNode>>,
+	}
+
+	// Act
+	let json = serde_json::to_value(TreeNode::schema()).unwrap();
+
+	// Assert
+	let properties = &json["properties"];
+	assert_eq!(properties["children"]["type"], "array");
+	assert_eq!(
+		properties["children"]["items"]["$ref"],
+		"#/components/schemas/TreeNode"
+	);
+	assert_eq!(
+		properties["parent"]["allOf"][0]["$ref"],
+		"#/components/schemas/TreeNode"
+	);
+	assert_eq!(properties["parent"]["description"], "Parent node");
+	let required = json["required"].as_array().unwrap();
+	assert!(required.contains(&serde_json::json!("children")));
+	assert!(!required.contains(&serde_json::json!("parent")));
+}
+
+#[rstest]
+fn test_recursive_enum_variants_use_refs() {
+	// Arrange
+	// Allow: compile-time-only test fixture for derive macro verification
+	#[allow(dead_code)]
+	#[derive(Schema, Serialize, Deserialize)]
+	enum Expr {
+		Literal(i64),
+		Neg(Box<Expr>),
+		Add { left: Box<Expr>, right: Box<Expr> },
+	}
+
+	// Act
+	let json = serde_json::to_string(&Expr::schema()).unwrap();
+
+	// Assert
+	assert!(json.contains("\"$ref\":\"#/components/schemas/Expr\""));
+}
+
+#[rstest]
+fn test_container_rename_and_min_max_aliases() {
+	// Arrange
+	// Allow: compile-time-only test fixture for derive macro verification
+	#[allow(dead_code)]
+	#[derive(Schema)]
+	#[schema(rename = "Account")]
+	struct AccountRecord {
+		#[schema(min = 1, max = 10)]
+		level: i32,
+	}
+
+	// Act
+	let json = serde_json::to_value(AccountRecord::schema()).unwrap();
+
+	// Assert
+	assert_eq!(AccountRecord::schema_name(), Some("Account".to_string()));
+	assert_eq!(json["properties"]["level"]["minimum"], 1.0);
+	assert_eq!(json["properties"]["level"]["maximum"], 10.0);
+}
+
+#[rstest]
+fn test_complex_enum_applies_serde_rename_strategies() {
+	// Arrange
+	// Allow: compile-time-only test fixture for derive macro verification
+	#[allow(dead_code)]
+	#[derive(Schema, Serialize, Deserialize)]
+	#[serde(
+		tag = "kind",
+		rename_all = "snake_case",
+		rename_all_fields = "UPPERCASE"
+	)]
+	enum AuditEvent {
+		UserCreated {
+			user_id: i64,
+		},
+		#[serde(rename_all = "kebab-case")]
+		UserRenamed {
+			old_name: String,
+			#[schema(max_length = 50)]
+			new_name: String,
+		},
+	}
+
+	// Act
+	let json = serde_json::to_string(&AuditEvent::schema()).unwrap();
+
+	// Assert
+	assert!(json.contains("\"user_created\""));
+	assert!(json.contains("\"user_renamed\""));
+	assert!(json.contains("\"USER_ID\""));
+	assert!(json.contains("\"old-name\""));
+	assert!(json.contains("\"maxLength\":50"));
+	assert!(!json.contains("\"UserCreated\""));
+}